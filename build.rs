@@ -3,6 +3,7 @@
 //! This script handles:
 //! - gRPC protobuf compilation using tonic-build
 //! - SBE message generation using IronSBE codegen
+//! - Embedding the SQL migrations
 
 use std::path::PathBuf;
 
@@ -10,6 +11,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Rerun if proto files change
     println!("cargo:rerun-if-changed=proto/");
     println!("cargo:rerun-if-changed=schemas/sbe/");
+    println!("cargo:rerun-if-changed=migrations/");
 
     // Compile gRPC protobufs
     compile_protos()?;
@@ -17,6 +19,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Generate SBE codecs
     generate_sbe_codecs()?;
 
+    // Embed SQL migrations
+    embed_migrations()?;
+
     Ok(())
 }
 
@@ -38,10 +43,13 @@ fn compile_protos() -> Result<(), Box<dyn std::error::Error>> {
         return Ok(());
     }
 
-    // Compile all proto files using tonic_prost_build with proper include path
+    // Compile all proto files using tonic_prost_build with proper include path,
+    // keeping the descriptor set for the gRPC reflection service
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR")?);
     tonic_prost_build::configure()
         .build_server(true)
         .build_client(true)
+        .file_descriptor_set_path(out_dir.join("otc_rfq_descriptor.bin"))
         .compile_protos(&proto_files, &[proto_dir])?;
 
     Ok(())
@@ -97,3 +105,56 @@ fn generate_sbe_codecs() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+/// Embed the SQL migrations as `(version, description, sql)` entries.
+///
+/// File names must follow `V{version}__{description}.sql`; anything else in
+/// the directory fails the build so a misnamed migration is never skipped.
+fn embed_migrations() -> Result<(), Box<dyn std::error::Error>> {
+    let migrations_dir = std::fs::canonicalize("migrations")?;
+    let mut migrations = Vec::new();
+
+    for entry in std::fs::read_dir(&migrations_dir)? {
+        let path = entry?.path();
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or("non UTF-8 migration file name")?
+            .to_string();
+        let (version, description) = file_name
+            .strip_prefix('V')
+            .and_then(|rest| rest.strip_suffix(".sql"))
+            .and_then(|rest| rest.split_once("__"))
+            .ok_or_else(|| {
+                format!("migration {file_name} is not named V{{version}}__{{description}}.sql")
+            })?;
+        let version: i64 = version
+            .parse()
+            .map_err(|_| format!("migration {file_name} has an invalid version"))?;
+        migrations.push((version, description.replace('_', " "), path));
+    }
+
+    migrations.sort_by_key(|(version, _, _)| *version);
+    for pair in migrations.windows(2) {
+        if let [(earlier, _, _), (later, _, _)] = pair
+            && earlier == later
+        {
+            return Err(format!("duplicate migration version {earlier}").into());
+        }
+    }
+
+    let mut generated = String::from("/// Embedded migrations, in version order.\n");
+    generated.push_str("const EMBEDDED: &[(i64, &str, &str)] = &[\n");
+    for (version, description, path) in &migrations {
+        generated.push_str(&format!(
+            "    ({version}, {description:?}, include_str!({:?})),\n",
+            path.display().to_string()
+        ));
+    }
+    generated.push_str("];\n");
+
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR")?);
+    std::fs::write(out_dir.join("migrations.rs"), generated)?;
+
+    Ok(())
+}
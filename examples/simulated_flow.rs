@@ -0,0 +1,311 @@
+//! # Simulated RFQ Flow
+//!
+//! Runs the full RFQ lifecycle against simulated venues, without any
+//! external connectivity:
+//!
+//! 1. Create an RFQ
+//! 2. Aggregate quotes from three seeded simulated venues
+//! 3. Select the best quote by price
+//! 4. Execute the trade
+//!
+//! ```text
+//! cargo run --example simulated_flow
+//! ```
+//!
+//! Venue seeds are fixed, so every run produces the same quotes.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use otc_rfq::application::dto::CreateRfqRequest;
+use otc_rfq::application::error::{ApplicationError, ApplicationResult};
+use otc_rfq::application::services::{BestPriceStrategy, RankingStrategy};
+use otc_rfq::application::use_cases::collect_quotes::{CollectQuotesUseCase, QuoteEventPublisher};
+use otc_rfq::application::use_cases::create_rfq::{
+    ClientRepository, ComplianceService, CreateRfqUseCase, EventPublisher, InstrumentRegistry,
+    RfqRepository,
+};
+use otc_rfq::application::use_cases::execute_trade::{
+    ExecuteTradeRequest, ExecuteTradeUseCase, TradeEventPublisher, TradeRepository,
+};
+use otc_rfq::domain::entities::rfq::{ComplianceResult, Rfq};
+use otc_rfq::domain::entities::trade::Trade;
+use otc_rfq::domain::events::rfq_events::{ExecutionStarted, QuoteReceived, RfqCreated};
+use otc_rfq::domain::events::trade_events::TradeExecuted;
+use otc_rfq::domain::events::{ComplianceEvent, PositionUpdated};
+use otc_rfq::domain::value_objects::{
+    CounterpartyId, FailureReason, OrderSide, Price, QuoteId, RfqId, TradeId,
+};
+use otc_rfq::infrastructure::persistence::in_memory::{
+    InMemoryRfqRepository, InMemoryTradeRepository,
+};
+use otc_rfq::infrastructure::persistence::traits::{
+    RfqRepository as RfqStore, TradeRepository as TradeStore,
+};
+use otc_rfq::infrastructure::persistence::{PageCursor, RfqListFilter};
+use otc_rfq::infrastructure::venues::{SimulatedVenueAdapter, SimulatedVenueConfig, VenueRegistry};
+use std::sync::Arc;
+
+/// Adapts the in-memory RFQ repository to the use-case port.
+#[derive(Debug, Default)]
+struct Rfqs(InMemoryRfqRepository);
+
+#[async_trait]
+impl RfqRepository for Rfqs {
+    async fn save(&self, rfq: &Rfq) -> Result<(), String> {
+        RfqStore::save(&self.0, rfq)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn find_by_id(&self, id: RfqId) -> Result<Option<Rfq>, String> {
+        RfqStore::get(&self.0, id).await.map_err(|e| e.to_string())
+    }
+
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> Result<Vec<Rfq>, String> {
+        RfqStore::list_after(&self.0, cursor, limit, filter)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn list_offset(
+        &self,
+        offset: usize,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> Result<Vec<Rfq>, String> {
+        RfqStore::list_offset(&self.0, offset, limit, filter)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn count_matching(&self, filter: &RfqListFilter) -> Result<u64, String> {
+        RfqStore::count_matching(&self.0, filter)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Adapts the in-memory trade repository to the use-case port.
+#[derive(Debug, Default)]
+struct Trades(InMemoryTradeRepository);
+
+#[async_trait]
+impl TradeRepository for Trades {
+    async fn save(&self, trade: &Trade) -> ApplicationResult<()> {
+        TradeStore::save(&self.0, trade)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))
+    }
+
+    async fn find_by_id(&self, id: TradeId) -> ApplicationResult<Option<Trade>> {
+        TradeStore::get(&self.0, id)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))
+    }
+
+    async fn find_by_rfq_id(&self, rfq_id: RfqId) -> ApplicationResult<Vec<Trade>> {
+        TradeStore::get_by_rfq(&self.0, rfq_id)
+            .await
+            .map(|trade| trade.into_iter().collect())
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))
+    }
+}
+
+/// Prints domain events instead of publishing them.
+#[derive(Debug)]
+struct PrintEvents;
+
+#[async_trait]
+impl EventPublisher for PrintEvents {
+    async fn publish_rfq_created(&self, event: RfqCreated) -> Result<(), String> {
+        println!("  event: RFQ created for {}", event.client_id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl QuoteEventPublisher for PrintEvents {
+    async fn publish_quote_received(&self, event: QuoteReceived) -> Result<(), String> {
+        println!("  event: quote {} received", event.quote_id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TradeEventPublisher for PrintEvents {
+    async fn publish_execution_started(&self, event: ExecutionStarted) -> ApplicationResult<()> {
+        println!("  event: execution of quote {} started", event.quote_id);
+        Ok(())
+    }
+
+    async fn publish_trade_executed(&self, event: TradeExecuted) -> ApplicationResult<()> {
+        println!("  event: trade {} executed", event.trade_id);
+        Ok(())
+    }
+
+    async fn publish_execution_failed(
+        &self,
+        rfq_id: RfqId,
+        quote_id: QuoteId,
+        reason: &FailureReason,
+    ) -> ApplicationResult<()> {
+        println!("  event: RFQ {rfq_id} quote {quote_id} failed: {reason}");
+        Ok(())
+    }
+
+    async fn publish_position_updated(&self, _event: PositionUpdated) -> ApplicationResult<()> {
+        Ok(())
+    }
+
+    async fn publish_compliance_check(&self, _event: ComplianceEvent) -> ApplicationResult<()> {
+        Ok(())
+    }
+}
+
+/// Accepts every client, instrument and compliance check.
+#[derive(Debug)]
+struct AllowAll;
+
+#[async_trait]
+impl ComplianceService for AllowAll {
+    async fn pre_check(
+        &self,
+        _client_id: &CounterpartyId,
+        _base_asset: &str,
+        _quote_asset: &str,
+        _quantity: f64,
+    ) -> Result<ComplianceResult, String> {
+        Ok(ComplianceResult::passed())
+    }
+}
+
+#[async_trait]
+impl ClientRepository for AllowAll {
+    async fn exists(&self, _client_id: &str) -> Result<bool, String> {
+        Ok(true)
+    }
+
+    async fn is_active(&self, _client_id: &str) -> Result<bool, String> {
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl InstrumentRegistry for AllowAll {
+    async fn is_supported(&self, _base_asset: &str, _quote_asset: &str) -> Result<bool, String> {
+        Ok(true)
+    }
+}
+
+/// Builds a registry of three simulated venues around `reference`.
+async fn simulated_venues(reference: Price) -> Arc<VenueRegistry> {
+    let registry = Arc::new(VenueRegistry::new().with_simulated_venues(true));
+    let venues = [
+        SimulatedVenueConfig::new(reference)
+            .with_venue_id("sim-tight")
+            .with_spread_bps(10)
+            .with_seed(1),
+        SimulatedVenueConfig::new(reference)
+            .with_venue_id("sim-wide")
+            .with_spread_bps(40)
+            .with_seed(2),
+        SimulatedVenueConfig::new(reference)
+            .with_venue_id("sim-flaky")
+            .with_spread_bps(15)
+            .with_reject_probability(0.5)
+            .with_seed(3),
+    ];
+    for config in venues {
+        registry
+            .register(Arc::new(SimulatedVenueAdapter::new(config)))
+            .await;
+    }
+    registry
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let reference = Price::new(65_000.0)?;
+    let venues = simulated_venues(reference).await;
+    let rfqs = Arc::new(Rfqs::default());
+    let publisher = Arc::new(PrintEvents);
+
+    // 1. Create
+    let create = CreateRfqUseCase::new(
+        Arc::clone(&rfqs) as _,
+        Arc::clone(&publisher) as _,
+        Arc::new(AllowAll),
+        Arc::new(AllowAll),
+        Arc::new(AllowAll),
+    );
+    let created = create
+        .execute(CreateRfqRequest::new(
+            "demo-client",
+            "BTC",
+            "USD",
+            OrderSide::Buy,
+            2.0,
+            300,
+        ))
+        .await?;
+    println!("created RFQ {} ({})", created.rfq_id, created.state);
+
+    // 2. Aggregate
+    let collect = CollectQuotesUseCase::with_defaults(
+        Arc::clone(&rfqs) as _,
+        Arc::clone(&publisher) as _,
+        Arc::clone(&venues) as _,
+    );
+    let collected = collect.execute(created.rfq_id).await?;
+    for quote in &collected.quotes {
+        println!(
+            "  quote {} from {} @ {}",
+            quote.id(),
+            quote.venue_id(),
+            quote.price()
+        );
+    }
+    for failure in &collected.failures {
+        println!(
+            "  no quote from {}: {}",
+            failure.venue_id,
+            failure.error.as_deref().unwrap_or("unknown")
+        );
+    }
+
+    // 3. Select
+    let best = BestPriceStrategy::new()
+        .rank(&collected.quotes, OrderSide::Buy)
+        .into_iter()
+        .next()
+        .context("no simulated venue returned a quote")?;
+    println!(
+        "selected {} @ {}",
+        best.quote.venue_id(),
+        best.quote.price()
+    );
+
+    // 4. Execute
+    let execute = ExecuteTradeUseCase::new(
+        Arc::clone(&rfqs) as _,
+        Arc::new(Trades::default()),
+        Arc::clone(&publisher) as _,
+        Arc::clone(&venues) as _,
+    );
+    let executed = execute
+        .execute(ExecuteTradeRequest::new(created.rfq_id, best.quote.id()))
+        .await?;
+    println!(
+        "executed trade {} for {} @ {} in {}ms",
+        executed.trade_id(),
+        executed.trade.quantity(),
+        executed.trade.price(),
+        executed.execution_time_ms
+    );
+    Ok(())
+}
@@ -0,0 +1,10 @@
+-- Add covering indexes for cursor pagination
+-- Migration: V004
+-- Description: Keyset indexes on (created_at, id) so that cursor-paginated
+-- listings of RFQs and trades are served by an index scan instead of a sort.
+
+CREATE INDEX IF NOT EXISTS idx_rfqs_created_at_id
+ON rfqs (created_at DESC, id DESC);
+
+CREATE INDEX IF NOT EXISTS idx_trades_created_at_id
+ON trades (created_at DESC, id DESC);
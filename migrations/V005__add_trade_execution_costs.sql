@@ -0,0 +1,19 @@
+-- Add execution cost tracking to trades
+-- Migration: V005
+-- Description: Stores the reference price observed at execution and an
+-- itemized fee breakdown per trade so that transaction cost analysis can be
+-- run from our own data.
+
+ALTER TABLE trades ADD COLUMN reference_price_at_execution DECIMAL(38, 18);
+
+COMMENT ON COLUMN trades.reference_price_at_execution IS 'Reference price captured from the reference price provider at execution time';
+
+-- Itemized fees; position preserves the order in which fees were recorded
+CREATE TABLE IF NOT EXISTS trade_fees (
+    trade_id VARCHAR(36) NOT NULL REFERENCES trades(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    kind VARCHAR(50) NOT NULL,
+    amount DECIMAL(38, 18) NOT NULL,
+    currency VARCHAR(50) NOT NULL,
+    PRIMARY KEY (trade_id, position)
+);
@@ -0,0 +1,15 @@
+-- Add settlement retry tracking to trades
+-- Migration: V006
+-- Description: Tracks retry attempts and the next retry time for failed
+-- settlements. The last settlement error is kept in failure_reason.
+-- Trades whose retries are exhausted move to the DEAD_LETTERED state.
+
+ALTER TABLE trades ADD COLUMN settlement_attempts INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE trades ADD COLUMN next_settlement_retry_at BIGINT;
+
+COMMENT ON COLUMN trades.settlement_attempts IS 'Number of settlement retries made after a failure';
+COMMENT ON COLUMN trades.next_settlement_retry_at IS 'When the next settlement retry is due (epoch millis)';
+
+-- Retry worker scan: failed trades ordered by due time
+CREATE INDEX IF NOT EXISTS idx_trades_settlement_retry
+ON trades (settlement_state, next_settlement_retry_at);
@@ -0,0 +1,19 @@
+-- Add instrument reference data
+-- Migration: V007
+-- Description: Per-symbol tick size, lot size, order size limits, and
+-- contract multiplier used to validate RFQ quantities and prices before they
+-- are sent to venues.
+
+CREATE TABLE IF NOT EXISTS instrument_reference_data (
+    symbol VARCHAR(50) PRIMARY KEY,
+    tick_size DECIMAL(38, 18) NOT NULL CHECK (tick_size > 0),
+    lot_size DECIMAL(38, 18) NOT NULL CHECK (lot_size > 0),
+    min_order_size DECIMAL(38, 18) NOT NULL DEFAULT 0 CHECK (min_order_size >= 0),
+    max_order_size DECIMAL(38, 18) CHECK (max_order_size >= min_order_size),
+    contract_multiplier DECIMAL(38, 18) NOT NULL DEFAULT 1 CHECK (contract_multiplier > 0)
+);
+
+COMMENT ON TABLE instrument_reference_data IS 'Trading constraints per instrument symbol';
+COMMENT ON COLUMN instrument_reference_data.tick_size IS 'Minimum price increment';
+COMMENT ON COLUMN instrument_reference_data.lot_size IS 'Minimum quantity increment';
+COMMENT ON COLUMN instrument_reference_data.max_order_size IS 'Maximum order size; NULL for no limit';
@@ -0,0 +1,15 @@
+-- V008__add_internal_crossing.sql
+-- Add opt-in flag for internal crossing of client RFQs
+--
+-- RFQs that opt in may be matched against an opposing client RFQ on the
+-- same instrument before quotes are requested from external venues.
+-- Existing RFQs default to not crossing.
+
+ALTER TABLE rfqs ADD COLUMN allow_internal_crossing BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Index for crossing candidate lookups by instrument and side
+CREATE INDEX idx_rfqs_crossing_candidates
+    ON rfqs ((instrument->>'symbol'), side, expires_at)
+    WHERE allow_internal_crossing;
+
+COMMENT ON COLUMN rfqs.allow_internal_crossing IS 'Whether the RFQ may be crossed against an opposing client RFQ';
@@ -0,0 +1,10 @@
+-- V009__add_rfq_strategy.sql
+-- Add multi-leg strategy support to RFQs
+--
+-- A multi-leg RFQ stores its full strategy (type, legs, underlying) as
+-- JSONB. The instrument column keeps the first leg's instrument so symbol
+-- filters continue to work. Single-instrument RFQs leave strategy NULL.
+
+ALTER TABLE rfqs ADD COLUMN strategy JSONB;
+
+COMMENT ON COLUMN rfqs.strategy IS 'Multi-leg strategy definition; NULL for single-instrument RFQs';
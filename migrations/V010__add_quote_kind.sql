@@ -0,0 +1,15 @@
+-- V010__add_quote_kind.sql
+-- Add net-premium strategy quotes
+--
+-- Quotes are persisted as JSONB in rfqs.quotes, where the serialized
+-- `kind` carries the net premium and per-leg prices. The normalized quotes
+-- table gains matching columns so it can hold the same data. The price
+-- column keeps the premium's magnitude; net_premium is signed.
+
+ALTER TABLE quotes ADD COLUMN kind VARCHAR(20) NOT NULL DEFAULT 'OUTRIGHT'
+    CHECK (kind IN ('OUTRIGHT', 'NET_PREMIUM'));
+ALTER TABLE quotes ADD COLUMN net_premium DECIMAL(38, 18);
+ALTER TABLE quotes ADD COLUMN leg_prices JSONB;
+
+COMMENT ON COLUMN quotes.net_premium IS 'Signed net premium (negative = credit); NULL for outright quotes';
+COMMENT ON COLUMN quotes.leg_prices IS 'Per-leg prices of a net-premium quote';
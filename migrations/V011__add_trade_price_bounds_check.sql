@@ -0,0 +1,10 @@
+-- V011__add_trade_price_bounds_check.sql
+-- Record the pre-execution price bounds check on trades
+--
+-- Holds the reference price, source, and deviation when the check passed,
+-- or the overriding administrator and failure reason when it was
+-- overridden. NULL for trades executed without a price bounds validator.
+
+ALTER TABLE trades ADD COLUMN price_bounds_check JSONB;
+
+COMMENT ON COLUMN trades.price_bounds_check IS 'Pre-execution price bounds check outcome; NULL if not checked';
@@ -0,0 +1,20 @@
+-- Venue configuration history
+--
+-- One row per venue update, holding the settings before and after the
+-- change. Rollbacks are recorded like any other update and reference the
+-- entry they reverted.
+
+CREATE TABLE IF NOT EXISTS venue_config_history (
+    id BIGSERIAL PRIMARY KEY,
+    venue_id VARCHAR(255) NOT NULL REFERENCES venues(id),
+    old_config JSONB NOT NULL,
+    new_config JSONB NOT NULL,
+    changed_by VARCHAR(255) NOT NULL,
+    changed_at BIGINT NOT NULL,
+    rollback_of BIGINT REFERENCES venue_config_history(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_venue_config_history_venue ON venue_config_history(venue_id, id DESC);
+
+COMMENT ON TABLE venue_config_history IS 'Audit trail of venue configuration changes';
+COMMENT ON COLUMN venue_config_history.rollback_of IS 'History entry reverted by this change; NULL for regular updates';
@@ -0,0 +1,11 @@
+-- V013__add_quote_firmness.sql
+-- Add indicative quotes
+--
+-- Quotes are persisted as JSONB in rfqs.quotes, where the serialized
+-- `firmness` marks quotes that must be firmed up before execution. The
+-- normalized quotes table gains a matching column.
+
+ALTER TABLE quotes ADD COLUMN firmness VARCHAR(20) NOT NULL DEFAULT 'FIRM'
+    CHECK (firmness IN ('FIRM', 'INDICATIVE'));
+
+COMMENT ON COLUMN quotes.firmness IS 'FIRM, or INDICATIVE if the quote must be firmed up before execution';
@@ -0,0 +1,14 @@
+-- V014__add_rfq_size_mode.sql
+-- Persist the size negotiation mode of an RFQ
+--
+-- The mode decides how the requested quantity may be filled. The
+-- MIN_QUANTITY threshold is kept in the existing min_quantity column.
+-- Existing rows with a min_quantity were created as MIN_QUANTITY RFQs;
+-- all others default to ALL_OR_NOTHING.
+
+ALTER TABLE rfqs ADD COLUMN size_mode VARCHAR(20) NOT NULL DEFAULT 'ALL_OR_NOTHING'
+    CHECK (size_mode IN ('ALL_OR_NOTHING', 'FILL_OR_KILL', 'MIN_QUANTITY', 'BEST_EFFORT'));
+
+UPDATE rfqs SET size_mode = 'MIN_QUANTITY' WHERE min_quantity IS NOT NULL;
+
+COMMENT ON COLUMN rfqs.size_mode IS 'Size negotiation mode; MIN_QUANTITY uses min_quantity as its threshold';
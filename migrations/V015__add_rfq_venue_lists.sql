@@ -0,0 +1,12 @@
+-- V015__add_rfq_venue_lists.sql
+-- Persist per-RFQ venue allowlist and blocklist
+--
+-- Both are JSONB arrays of venue IDs. A NULL allowlist means every venue
+-- may quote; when an allowlist is present it takes precedence over the
+-- blocklist.
+
+ALTER TABLE rfqs ADD COLUMN venue_allowlist JSONB;
+ALTER TABLE rfqs ADD COLUMN venue_blocklist JSONB NOT NULL DEFAULT '[]';
+
+COMMENT ON COLUMN rfqs.venue_allowlist IS 'Venue IDs this RFQ may be sent to; NULL allows every venue';
+COMMENT ON COLUMN rfqs.venue_blocklist IS 'Venue IDs this RFQ must not be sent to; ignored when an allowlist is set';
@@ -0,0 +1,26 @@
+-- Add RFQ templates
+-- Migration: V016
+-- Description: Saved RFQ parameters a counterparty can reuse. The subject
+-- (instrument or strategy) and size mode are stored as JSONB in the same
+-- shape as the domain types.
+
+CREATE TABLE IF NOT EXISTS rfq_templates (
+    id UUID PRIMARY KEY,
+    owner_id VARCHAR(255) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    subject JSONB NOT NULL,
+    side VARCHAR(10) NOT NULL CHECK (side IN ('BUY', 'SELL')),
+    quantity DECIMAL(38, 18) NOT NULL CHECK (quantity > 0),
+    size_mode JSONB NOT NULL,
+    venue_allowlist JSONB,
+    venue_blocklist JSONB NOT NULL DEFAULT '[]',
+    default_ttl_secs BIGINT NOT NULL CHECK (default_ttl_secs > 0),
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_rfq_templates_owner_id ON rfq_templates(owner_id, name);
+
+COMMENT ON TABLE rfq_templates IS 'Saved RFQ parameters owned by a counterparty';
+COMMENT ON COLUMN rfq_templates.venue_allowlist IS 'Venue IDs RFQs from this template may be sent to; NULL allows every venue';
+COMMENT ON COLUMN rfq_templates.default_ttl_secs IS 'Expiry of instantiated RFQs, in seconds from creation';
@@ -0,0 +1,15 @@
+-- V017__add_rfq_activate_at.sql
+-- Add optional activation time for scheduled RFQs
+--
+-- A scheduled RFQ is created immediately but stays in CREATED until its
+-- activation time, when quote collection starts. NULL activates on
+-- creation, which is the behaviour of every existing RFQ.
+
+ALTER TABLE rfqs ADD COLUMN activate_at BIGINT;
+
+-- Index for the activation sweep over RFQs still waiting to start
+CREATE INDEX idx_rfqs_pending_activation
+    ON rfqs (activate_at)
+    WHERE state = 'CREATED' AND activate_at IS NOT NULL;
+
+COMMENT ON COLUMN rfqs.activate_at IS 'When quote collection may start (Unix millis); NULL starts on creation';
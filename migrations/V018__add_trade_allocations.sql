@@ -0,0 +1,22 @@
+-- V018__add_trade_allocations.sql
+-- Persist multi-MM fill allocations on trades
+--
+-- A trade filled across several market makers keeps one row per
+-- allocation; position preserves the fill strategy's order. Single-venue
+-- trades have no rows.
+
+CREATE TABLE IF NOT EXISTS trade_allocations (
+    trade_id VARCHAR(36) NOT NULL REFERENCES trades(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    venue_id VARCHAR(255) NOT NULL,
+    quote_id VARCHAR(36) NOT NULL,
+    quantity DECIMAL(38, 18) NOT NULL,
+    price DECIMAL(38, 18) NOT NULL,
+    scheduled_at BIGINT NOT NULL,
+    status VARCHAR(20) NOT NULL,
+    PRIMARY KEY (trade_id, position)
+);
+
+COMMENT ON TABLE trade_allocations IS 'Per-venue allocations of a multi-MM fill';
+COMMENT ON COLUMN trade_allocations.scheduled_at IS 'When the allocation was scheduled (Unix millis)';
+COMMENT ON COLUMN trade_allocations.status IS 'SCHEDULED, EXECUTED or ROLLED_BACK';
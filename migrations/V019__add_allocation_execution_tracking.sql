@@ -0,0 +1,29 @@
+-- V019__add_allocation_execution_tracking.sql
+-- Track each venue leg of a multi-MM fill and how partial failures are
+-- compensated
+--
+-- Allocation statuses become PENDING, EXECUTING, FILLED or FAILED, with the
+-- failure reason kept alongside. Each RFQ chooses a compensation policy at
+-- creation; unwind trades reverse filled legs and link back to the trade
+-- they unwind.
+
+UPDATE trade_allocations SET status = 'PENDING' WHERE status = 'SCHEDULED';
+UPDATE trade_allocations SET status = 'FILLED' WHERE status = 'EXECUTED';
+UPDATE trade_allocations SET status = 'FAILED' WHERE status = 'ROLLED_BACK';
+
+ALTER TABLE trade_allocations ADD COLUMN failure_reason TEXT;
+
+ALTER TABLE rfqs
+    ADD COLUMN compensation_policy VARCHAR(20) NOT NULL DEFAULT 'REOFFER_REMAINDER';
+
+ALTER TABLE trades
+    ADD COLUMN unwinds_trade_id VARCHAR(36) REFERENCES trades(id);
+
+CREATE INDEX idx_trades_unwinds_trade_id
+    ON trades (unwinds_trade_id)
+    WHERE unwinds_trade_id IS NOT NULL;
+
+COMMENT ON COLUMN trade_allocations.status IS 'PENDING, EXECUTING, FILLED or FAILED';
+COMMENT ON COLUMN trade_allocations.failure_reason IS 'Why the venue leg failed; set only when FAILED';
+COMMENT ON COLUMN rfqs.compensation_policy IS 'REOFFER_REMAINDER, ACCEPT_PARTIAL or UNWIND_ALL';
+COMMENT ON COLUMN trades.unwinds_trade_id IS 'Trade whose filled leg this unwind trade reverses';
@@ -0,0 +1,28 @@
+-- Add negotiations
+-- Migration: V020
+-- Description: Counter-quote negotiations between a requester and a market
+-- maker. Rounds are stored as JSONB in the same shape as the domain type.
+-- Completed negotiations are queried by completion time for analytics.
+
+CREATE TABLE IF NOT EXISTS negotiations (
+    id UUID PRIMARY KEY,
+    rfq_id UUID NOT NULL,
+    requester_id VARCHAR(255) NOT NULL,
+    mm_account_id VARCHAR(255) NOT NULL,
+    side VARCHAR(10) NOT NULL CHECK (side IN ('BUY', 'SELL')),
+    rounds JSONB NOT NULL DEFAULT '[]',
+    max_rounds SMALLINT NOT NULL CHECK (max_rounds > 0),
+    state VARCHAR(20) NOT NULL CHECK (
+        state IN ('OPEN', 'COUNTER_PENDING', 'ACCEPTED', 'REJECTED', 'EXPIRED')
+    ),
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_negotiations_rfq_id ON negotiations(rfq_id);
+CREATE INDEX IF NOT EXISTS idx_negotiations_completed
+    ON negotiations(mm_account_id, updated_at)
+    WHERE state IN ('ACCEPTED', 'REJECTED', 'EXPIRED');
+
+COMMENT ON TABLE negotiations IS 'Counter-quote negotiations between a requester and a market maker';
+COMMENT ON COLUMN negotiations.updated_at IS 'Last update; the completion time once the state is terminal';
@@ -0,0 +1,26 @@
+-- Add raw venue exchange log
+-- Migration: V021
+-- Description: Raw request and response payloads exchanged with RFQ
+-- protocol venues, kept as evidence for dispute resolution. Secret fields
+-- are redacted before they are written. Each row carries the expiry set
+-- by the retention policy in force when it was recorded.
+
+CREATE TABLE IF NOT EXISTS venue_raw_exchanges (
+    id BIGSERIAL PRIMARY KEY,
+    venue_id VARCHAR(255) NOT NULL,
+    rfq_id UUID NOT NULL,
+    direction VARCHAR(10) NOT NULL CHECK (direction IN ('REQUEST', 'RESPONSE')),
+    payload JSONB NOT NULL,
+    http_status INTEGER,
+    latency_ms BIGINT CHECK (latency_ms >= 0),
+    recorded_at BIGINT NOT NULL,
+    expires_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_venue_raw_exchanges_rfq_id
+    ON venue_raw_exchanges(rfq_id, recorded_at, id);
+CREATE INDEX IF NOT EXISTS idx_venue_raw_exchanges_expires_at
+    ON venue_raw_exchanges(expires_at);
+
+COMMENT ON TABLE venue_raw_exchanges IS 'Raw venue request/response payloads for dispute resolution';
+COMMENT ON COLUMN venue_raw_exchanges.expires_at IS 'Retention policy: the row is purged once this time passes';
@@ -0,0 +1,31 @@
+-- Add RFQ summary read model
+-- Migration: V022
+-- Description: One denormalized row per RFQ for the ops dashboard, folded
+-- from the domain events by the RFQ summary projection. The table holds
+-- derived data only and can be truncated and rebuilt from domain_events.
+
+CREATE TABLE IF NOT EXISTS rfq_summary (
+    rfq_id UUID PRIMARY KEY,
+    client_id VARCHAR(255) NOT NULL,
+    symbol VARCHAR(50) NOT NULL,
+    side VARCHAR(10) NOT NULL CHECK (side IN ('BUY', 'SELL')),
+    quantity DECIMAL(38, 18) NOT NULL,
+    state VARCHAR(20) NOT NULL,
+    quote_count INTEGER NOT NULL DEFAULT 0 CHECK (quote_count >= 0),
+    best_quote_price DECIMAL(38, 18),
+    selected_venue_id VARCHAR(255),
+    trade_id UUID,
+    execution_price DECIMAL(38, 18),
+    failure_reason TEXT,
+    created_at BIGINT NOT NULL,
+    last_event_at BIGINT NOT NULL,
+    last_sequence BIGINT NOT NULL CHECK (last_sequence >= 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_rfq_summary_created_at_id
+    ON rfq_summary(created_at DESC, rfq_id DESC);
+CREATE INDEX IF NOT EXISTS idx_rfq_summary_client_id ON rfq_summary(client_id);
+CREATE INDEX IF NOT EXISTS idx_rfq_summary_state ON rfq_summary(state);
+
+COMMENT ON TABLE rfq_summary IS 'RFQ dashboard read model maintained from domain events';
+COMMENT ON COLUMN rfq_summary.last_sequence IS 'Sequence of the last applied event; older writes are ignored';
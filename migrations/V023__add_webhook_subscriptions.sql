@@ -0,0 +1,41 @@
+-- Add webhook subscriptions and delivery log
+-- Migration: V023
+-- Description: Downstream endpoints that receive signed event webhooks,
+-- and one row per event sent to them. A delivery row is updated after
+-- every attempt and keeps the exact payload so it can be redelivered.
+
+CREATE TABLE IF NOT EXISTS webhook_subscriptions (
+    id UUID PRIMARY KEY,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    event_filters JSONB NOT NULL,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    consecutive_failures INTEGER NOT NULL DEFAULT 0 CHECK (consecutive_failures >= 0),
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_subscriptions_active
+    ON webhook_subscriptions(created_at, id) WHERE active;
+
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    id UUID PRIMARY KEY,
+    subscription_id UUID NOT NULL REFERENCES webhook_subscriptions(id) ON DELETE CASCADE,
+    event_id UUID NOT NULL,
+    event_name VARCHAR(100) NOT NULL,
+    payload JSONB NOT NULL,
+    status VARCHAR(20) NOT NULL CHECK (status IN ('PENDING', 'SUCCEEDED', 'FAILED')),
+    attempts INTEGER NOT NULL CHECK (attempts >= 0),
+    response_status INTEGER,
+    last_error TEXT,
+    redelivery_of UUID,
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_subscription
+    ON webhook_deliveries(subscription_id, created_at DESC, id DESC);
+
+COMMENT ON TABLE webhook_subscriptions IS 'Downstream endpoints receiving HMAC-signed event webhooks';
+COMMENT ON COLUMN webhook_subscriptions.consecutive_failures IS 'Failed deliveries since the last success; the subscription is deactivated at 50';
+COMMENT ON TABLE webhook_deliveries IS 'Outbound webhook deliveries with their latest attempt outcome';
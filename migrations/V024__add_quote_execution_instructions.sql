@@ -0,0 +1,11 @@
+-- V024__add_quote_execution_instructions.sql
+-- Add typed execution instructions to quotes
+--
+-- Quotes are persisted as JSONB in rfqs.quotes, where the serialized
+-- metadata now carries `execution_instructions`: the signed payload a venue
+-- needs back at execution, tagged by `protocol`. The normalized quotes
+-- table gains a matching column.
+
+ALTER TABLE quotes ADD COLUMN execution_instructions JSONB;
+
+COMMENT ON COLUMN quotes.execution_instructions IS 'Venue execution payload tagged by protocol (HASHFLOW_SIGNED, BEBOP_ORDER, AIRSWAP_ORDER or GENERIC), or NULL if the venue needs none';
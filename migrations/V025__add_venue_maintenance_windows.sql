@@ -0,0 +1,10 @@
+-- V025__add_venue_maintenance_windows.sql
+-- Add venue maintenance windows
+--
+-- Venues carry scheduled maintenance windows during which RFQs are not
+-- routed to them. Windows are stored as a JSONB array of
+-- {start, end, reason} objects, sorted by start and never overlapping.
+
+ALTER TABLE venues ADD COLUMN maintenance_windows JSONB NOT NULL DEFAULT '[]';
+
+COMMENT ON COLUMN venues.maintenance_windows IS 'Scheduled maintenance windows (start inclusive, end exclusive) during which the venue receives no RFQs';
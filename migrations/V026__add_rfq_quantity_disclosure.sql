@@ -0,0 +1,11 @@
+-- V026__add_rfq_quantity_disclosure.sql
+-- Let clients mask RFQ size from quoting venues
+--
+-- EXACT shows venues the requested quantity, BUCKETED rounds it up to a
+-- bucket boundary, and HIDDEN shows instrument and side only. Existing RFQs
+-- keep disclosing their exact size.
+
+ALTER TABLE rfqs
+    ADD COLUMN quantity_disclosure JSONB NOT NULL DEFAULT '{"type": "EXACT"}';
+
+COMMENT ON COLUMN rfqs.quantity_disclosure IS 'How much of the quantity venues are shown: {"type": "EXACT" | "HIDDEN"} or {"type": "BUCKETED", "bucket": "<size>"}';
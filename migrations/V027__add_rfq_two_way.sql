@@ -0,0 +1,11 @@
+-- V027__add_rfq_two_way.sql
+-- Let clients request both sides of a market in one RFQ
+--
+-- A two-way RFQ collects bid and offer quotes until the client selects a
+-- side; selecting clears the flag and fixes the side column. Quote sides
+-- are stored on the quotes themselves. Existing RFQs are one-way.
+
+ALTER TABLE rfqs
+    ADD COLUMN two_way BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN rfqs.two_way IS 'True while both sides are quoted and no side has been selected';
@@ -0,0 +1,15 @@
+-- V028__add_trade_netting_batch.sql
+-- Track which netting batch a pending trade is reserved for
+--
+-- Trades with the same client, venue, instrument and chain executed in the
+-- same settlement window can be settled together by one net transaction.
+-- A reserved trade stays PENDING until its batch confirms, when it moves to
+-- SETTLED_VIA_NETTING; cancelling or failing the batch clears the column.
+
+ALTER TABLE trades ADD COLUMN netting_batch_id VARCHAR(36);
+
+CREATE INDEX IF NOT EXISTS idx_trades_netting_batch_id
+ON trades (netting_batch_id)
+WHERE netting_batch_id IS NOT NULL;
+
+COMMENT ON COLUMN trades.netting_batch_id IS 'Netting batch the trade is reserved for; NULL when settled individually';
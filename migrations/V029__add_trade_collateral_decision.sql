@@ -0,0 +1,11 @@
+-- V029__add_trade_collateral_decision.sql
+-- Record the pre-execution collateral check on trades
+--
+-- Holds the decision returned by the margin system for derivatives RFQs:
+-- approved with the available collateral, or unavailable when the margin
+-- system could not be consulted and the check ran in monitor mode. NULL for
+-- trades executed without a collateral check.
+
+ALTER TABLE trades ADD COLUMN collateral_decision JSONB;
+
+COMMENT ON COLUMN trades.collateral_decision IS 'Pre-execution collateral check decision; NULL if not checked';
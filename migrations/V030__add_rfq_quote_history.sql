@@ -0,0 +1,15 @@
+-- V030__add_rfq_quote_history.sql
+-- Keep superseded and expired quotes, and collection ranks, for the
+-- client quote history
+--
+-- Quotes live in the rfqs.quotes JSONB column rather than their own table,
+-- so retired quotes get a sibling column. Each entry carries the quote, its
+-- status (SUPERSEDED or EXPIRED), the replacing quote ID and when it was
+-- retired. Ranks are recorded per side when quote collection completes.
+
+ALTER TABLE rfqs
+    ADD COLUMN retired_quotes JSONB NOT NULL DEFAULT '[]',
+    ADD COLUMN quote_ranks JSONB NOT NULL DEFAULT '[]';
+
+COMMENT ON COLUMN rfqs.retired_quotes IS 'Quotes no longer live: superseded by a later quote from the same venue, or expired on arrival';
+COMMENT ON COLUMN rfqs.quote_ranks IS 'Per-side quote ranks recorded when quote collection completed';
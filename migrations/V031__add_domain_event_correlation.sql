@@ -0,0 +1,24 @@
+-- V031__add_domain_event_correlation.sql
+-- Correlate events across RFQ and trade streams, and serve time-range
+-- queries across all aggregates
+--
+-- Events of an RFQ, including its trade, settlement and compliance events,
+-- share a correlation ID. Chains opened by an RFQ use the RFQ's ID, so
+-- existing events are backfilled from rfq_id.
+
+ALTER TABLE domain_events
+    ADD COLUMN correlation_id VARCHAR(36);
+
+UPDATE domain_events
+SET correlation_id = COALESCE(payload->'metadata'->>'correlation_id', rfq_id)
+WHERE correlation_id IS NULL;
+
+-- Keyset pagination of time-range queries, oldest first
+CREATE INDEX IF NOT EXISTS idx_domain_events_timestamp_event_id
+    ON domain_events(timestamp, event_id);
+
+CREATE INDEX IF NOT EXISTS idx_domain_events_correlation
+    ON domain_events(correlation_id, timestamp, event_id)
+    WHERE correlation_id IS NOT NULL;
+
+COMMENT ON COLUMN domain_events.correlation_id IS 'Event chain the event belongs to, shared from RFQ creation through settlement';
@@ -0,0 +1,11 @@
+-- V032__add_counterparty_settlement_addresses.sql
+-- Per-chain, optionally per-token settlement addresses of counterparties
+--
+-- Stored as JSONB next to wallet_addresses. Each entry carries its chain,
+-- token (null for the chain default), wallet and whether the counterparty
+-- proved control of the wallet; settlement only routes to verified entries.
+
+ALTER TABLE counterparties
+    ADD COLUMN settlement_addresses JSONB NOT NULL DEFAULT '[]'::jsonb;
+
+COMMENT ON COLUMN counterparties.settlement_addresses IS 'Settlement addresses per chain and token, with verification status';
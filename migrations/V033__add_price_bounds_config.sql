@@ -0,0 +1,24 @@
+-- Add price bounds configuration and its audit trail
+-- Migration: V033
+-- Description: Price bounds tolerances that administrators change at
+-- runtime, per liquidity tier with per-symbol overrides. The settings in
+-- force are a single row; every change appends who made it, when, and the
+-- settings before and after.
+
+CREATE TABLE IF NOT EXISTS price_bounds_config (
+    id SMALLINT PRIMARY KEY CHECK (id = 1),
+    settings JSONB NOT NULL,
+    updated_by TEXT NOT NULL,
+    updated_at BIGINT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS price_bounds_config_audit (
+    id BIGSERIAL PRIMARY KEY,
+    changed_by TEXT NOT NULL,
+    changed_at BIGINT NOT NULL,
+    previous JSONB NOT NULL,
+    current JSONB NOT NULL
+);
+
+COMMENT ON TABLE price_bounds_config IS 'Price bounds tolerances in force; at most one row';
+COMMENT ON TABLE price_bounds_config_audit IS 'Append-only record of price bounds configuration changes';
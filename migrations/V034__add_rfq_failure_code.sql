@@ -0,0 +1,18 @@
+-- Add structured failure codes to RFQs
+-- Migration: V034
+-- Description: Failed RFQs carry a category from a fixed taxonomy next to
+-- the free-text reason, so failures can be aggregated and filtered. RFQs
+-- that failed before codes existed are backfilled as OTHER.
+
+ALTER TABLE rfqs
+    ADD COLUMN IF NOT EXISTS failure_code TEXT;
+
+UPDATE rfqs
+SET failure_code = 'OTHER'
+WHERE failure_reason IS NOT NULL AND failure_code IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_rfqs_failure_code
+    ON rfqs(failure_code)
+    WHERE failure_code IS NOT NULL;
+
+COMMENT ON COLUMN rfqs.failure_code IS 'Failure category (VENUE_TIMEOUT, VENUE_REJECTED, ...), set when the RFQ failed';
@@ -0,0 +1,14 @@
+-- Add per-venue quote aggregation reports
+-- Migration: V035
+-- Description: For each RFQ, what happened with every venue considered
+-- during its last successful quote aggregation: responded (with latency
+-- and first quote), timed out, errored, filtered, or excluded. Stored as a
+-- JSONB array of outcomes for post-trade review.
+
+CREATE TABLE IF NOT EXISTS rfq_aggregation_reports (
+    rfq_id UUID PRIMARY KEY,
+    venue_outcomes JSONB NOT NULL DEFAULT '[]',
+    generated_at BIGINT NOT NULL
+);
+
+COMMENT ON COLUMN rfq_aggregation_reports.venue_outcomes IS 'One outcome per venue, tagged by status (RESPONDED, TIMEOUT, ERROR, FILTERED_EXPIRED, FILTERED_SHORT_TTL, EXCLUDED)';
@@ -0,0 +1,10 @@
+-- V036__add_trade_settlement_plan.sql
+-- Record the settlement terms agreed in negotiation on trades
+--
+-- Holds the settlement chain, window and note attached to the accepted
+-- counter-quote when a trade follows a negotiation. NULL for trades whose
+-- quote was not negotiated or whose accepted counter set no terms.
+
+ALTER TABLE trades ADD COLUMN settlement_plan JSONB;
+
+COMMENT ON COLUMN trades.settlement_plan IS 'Settlement chain and window agreed in negotiation; NULL if none';
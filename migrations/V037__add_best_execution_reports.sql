@@ -0,0 +1,16 @@
+-- Add best execution report snapshots
+-- Migration: V037
+-- Description: Best execution reports per client and period, stored the
+-- first time each is generated so later requests for the same period
+-- return the same figures. Only periods that have ended are stored.
+
+CREATE TABLE IF NOT EXISTS best_execution_reports (
+    client_id VARCHAR(255) NOT NULL,
+    period_from BIGINT NOT NULL,
+    period_to BIGINT NOT NULL,
+    report JSONB NOT NULL,
+    generated_at BIGINT NOT NULL,
+    PRIMARY KEY (client_id, period_from, period_to)
+);
+
+COMMENT ON COLUMN best_execution_reports.report IS 'Serialized BestExecutionReport: RFQ counts, venues contacted, best-price rate, improvement vs reference and exceptions';
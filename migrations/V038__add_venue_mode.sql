@@ -0,0 +1,15 @@
+-- Add venue participation mode
+-- Migration: V038
+-- Description: Replaces the enabled flag with a mode. SHADOW venues receive
+-- RFQs and have their quotes measured, but are never shown to clients or
+-- selectable. The enabled column is kept for older readers and stays true
+-- for LIVE and SHADOW venues.
+
+ALTER TABLE venues ADD COLUMN IF NOT EXISTS mode VARCHAR(20) NOT NULL DEFAULT 'LIVE';
+
+UPDATE venues SET mode = 'DISABLED' WHERE NOT enabled;
+
+ALTER TABLE venues ADD CONSTRAINT venues_mode_check
+    CHECK (mode IN ('LIVE', 'SHADOW', 'DISABLED'));
+
+COMMENT ON COLUMN venues.mode IS 'LIVE, SHADOW (quotes measured but hidden from clients) or DISABLED';
@@ -0,0 +1,24 @@
+-- Add dead letters
+-- Migration: V039
+-- Description: Events an event consumer failed to handle after its retries,
+-- parked per consumer so the consumer can move on and an operator can
+-- re-drive them once the cause is fixed.
+
+CREATE TABLE IF NOT EXISTS dead_letters (
+    consumer VARCHAR(255) NOT NULL,
+    event_id UUID NOT NULL,
+    event_name VARCHAR(255) NOT NULL,
+    payload JSONB NOT NULL,
+    last_error TEXT NOT NULL,
+    attempts INTEGER NOT NULL CHECK (attempts > 0),
+    status VARCHAR(16) NOT NULL CHECK (status IN ('PARKED', 'RESOLVED')),
+    first_seen_at BIGINT NOT NULL,
+    last_seen_at BIGINT NOT NULL,
+    PRIMARY KEY (consumer, event_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_dead_letters_parked
+    ON dead_letters (first_seen_at, event_id)
+    WHERE status = 'PARKED';
+
+COMMENT ON COLUMN dead_letters.payload IS 'Serialized StoredEvent, replayed as-is on re-drive';
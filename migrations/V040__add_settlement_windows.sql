@@ -0,0 +1,17 @@
+-- V040__add_settlement_windows.sql
+-- Schedule trade settlement by window
+--
+-- Counterparties may set a default settlement window (instant, same day or
+-- next day at a timezone-aware cutoff). Trades record the window they
+-- settle in and when they are released to the settlement workers. NULL
+-- windows fall back to the platform default; trades with no due time are
+-- released immediately, as before.
+
+ALTER TABLE counterparties ADD COLUMN settlement_window JSONB;
+
+ALTER TABLE trades ADD COLUMN settlement_window JSONB;
+ALTER TABLE trades ADD COLUMN settlement_due_at BIGINT;
+
+COMMENT ON COLUMN counterparties.settlement_window IS 'Default settlement window of the counterparty; NULL for the platform default';
+COMMENT ON COLUMN trades.settlement_window IS 'Settlement window the trade settles in; NULL if unscheduled';
+COMMENT ON COLUMN trades.settlement_due_at IS 'When the trade is released for settlement (epoch millis); NULL if unscheduled';
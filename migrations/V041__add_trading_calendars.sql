@@ -0,0 +1,15 @@
+-- Add named trading calendars
+-- Migration: V041
+-- Description: Trading days of tradfi-settled markets. A calendar is closed
+-- on the listed holidays and, optionally, on weekends, judged by the local
+-- date in its timezone. The 24/7 crypto calendar is built in and not stored.
+
+CREATE TABLE IF NOT EXISTS trading_calendars (
+    name TEXT PRIMARY KEY,
+    timezone TEXT NOT NULL,
+    weekends_closed BOOLEAN NOT NULL DEFAULT TRUE,
+    holidays DATE[] NOT NULL DEFAULT '{}'
+);
+
+COMMENT ON TABLE trading_calendars IS 'Named trading calendars with their holidays';
+COMMENT ON COLUMN trading_calendars.timezone IS 'IANA timezone local dates are judged in';
@@ -0,0 +1,15 @@
+-- Add execution deadlines to RFQs
+-- Migration: V042
+-- Description: An RFQ entering EXECUTING is stamped with the time its
+-- execution must have finished by, so executions left behind by a crashed
+-- instance can be found and failed. RFQs executed before deadlines existed
+-- keep NULL.
+
+ALTER TABLE rfqs
+    ADD COLUMN IF NOT EXISTS execution_deadline BIGINT;
+
+CREATE INDEX IF NOT EXISTS idx_rfqs_execution_deadline
+    ON rfqs(execution_deadline)
+    WHERE state = 'EXECUTING';
+
+COMMENT ON COLUMN rfqs.execution_deadline IS 'Unix millis by which the current execution must finish, set when the RFQ starts executing';
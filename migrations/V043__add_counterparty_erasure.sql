@@ -0,0 +1,42 @@
+-- Add counterparty erasure requests and event encryption keys
+-- Migration: V043
+-- Description: Erasure requests record the review of a legal request to
+-- erase a departed counterparty's personal identifiers. Counterparty keys
+-- encrypt those identifiers inside stored event payloads; erasure shreds a
+-- key by deleting its material and replacing the counterparty with its
+-- pseudonym, so the events it encrypted can no longer be decrypted.
+
+CREATE TABLE IF NOT EXISTS erasure_requests (
+    id UUID PRIMARY KEY,
+    counterparty_id VARCHAR(255) NOT NULL,
+    requested_by VARCHAR(255) NOT NULL,
+    reason TEXT NOT NULL,
+    status VARCHAR(20) NOT NULL,
+    approved_by VARCHAR(255),
+    approved_at BIGINT,
+    executed_at BIGINT,
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_erasure_requests_counterparty_id
+    ON erasure_requests(counterparty_id);
+
+CREATE TABLE IF NOT EXISTS counterparty_keys (
+    key_id UUID PRIMARY KEY,
+    counterparty_id VARCHAR(255) NOT NULL,
+    key_material BYTEA,
+    shredded_at BIGINT,
+    created_at BIGINT NOT NULL,
+    CHECK ((key_material IS NULL) = (shredded_at IS NOT NULL))
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_counterparty_keys_active
+    ON counterparty_keys(counterparty_id)
+    WHERE key_material IS NOT NULL;
+
+COMMENT ON TABLE erasure_requests IS 'Reviewed requests to erase the personal identifiers of a counterparty';
+COMMENT ON COLUMN erasure_requests.counterparty_id IS 'Counterparty to erase; its pseudonym once executed';
+COMMENT ON TABLE counterparty_keys IS 'Per-counterparty keys encrypting identifiers in stored events';
+COMMENT ON COLUMN counterparty_keys.counterparty_id IS 'Owner of the key; its pseudonym once shredded';
+COMMENT ON COLUMN counterparty_keys.key_material IS 'AES-256 key; NULL once shredded';
@@ -0,0 +1,21 @@
+-- Encrypt counterparty personal data at rest
+-- Migration: V044
+-- Description: Counterparty names, notification emails and wallet and
+-- settlement addresses may be stored as AES-GCM envelopes
+-- ("pii:v1:<key_id>:<base64>") naming the key that encrypted them. Names
+-- and emails outgrow their VARCHAR limits once encrypted. The address lists
+-- are then stored as a JSON string holding the envelope. The blind index
+-- (a keyed hash of the normalized name) allows exact name lookups without
+-- decrypting every row.
+
+ALTER TABLE counterparties ALTER COLUMN name TYPE TEXT;
+ALTER TABLE counterparties ALTER COLUMN notification_email TYPE TEXT;
+
+ALTER TABLE counterparties ADD COLUMN IF NOT EXISTS name_index VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS idx_counterparties_name_index
+    ON counterparties(name_index);
+
+COMMENT ON COLUMN counterparties.name IS 'Legal name, in clear or as an encrypted envelope';
+COMMENT ON COLUMN counterparties.notification_email IS 'Notification email, in clear or as an encrypted envelope';
+COMMENT ON COLUMN counterparties.name_index IS 'HMAC-SHA256 blind index of the lowercased name, when encrypted';
@@ -0,0 +1,18 @@
+-- Link trades to the negotiations they conclude
+-- Migration: V045
+-- Description: Accepting a negotiation executes its agreed terms as a
+-- bilateral trade between the requester and the market maker. The trade
+-- records the negotiation it concludes, so a repeated acceptance finds it
+-- instead of trading twice. An acceptance that fails the pre-trade checks
+-- returns the negotiation to COUNTER_PENDING with the reason recorded.
+
+ALTER TABLE trades ADD COLUMN negotiation_id VARCHAR(36);
+
+CREATE INDEX IF NOT EXISTS idx_trades_negotiation_id
+ON trades (negotiation_id)
+WHERE negotiation_id IS NOT NULL;
+
+ALTER TABLE negotiations ADD COLUMN conclusion_failure TEXT;
+
+COMMENT ON COLUMN trades.negotiation_id IS 'Negotiation whose accepted terms the trade executes; NULL for venue executions';
+COMMENT ON COLUMN negotiations.conclusion_failure IS 'Why the last acceptance failed its pre-trade checks, if it was reverted';
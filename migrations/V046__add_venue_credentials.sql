@@ -0,0 +1,20 @@
+-- Add per-counterparty venue credentials
+-- Migration: V046
+-- Description: Clients with their own relationship with a venue can have
+-- their RFQs quoted there on their own account. Their API key and secret
+-- are stored as AES-GCM envelopes ("pii:v1:<key_id>:<base64>"); values in
+-- clear are never written.
+
+CREATE TABLE IF NOT EXISTS venue_credentials (
+    counterparty_id VARCHAR(255) NOT NULL,
+    venue_id VARCHAR(255) NOT NULL,
+    api_key TEXT NOT NULL,
+    api_secret TEXT,
+    created_at BIGINT NOT NULL,
+    updated_at BIGINT NOT NULL,
+    PRIMARY KEY (counterparty_id, venue_id)
+);
+
+COMMENT ON TABLE venue_credentials IS 'Counterparties'' own API credentials for venues';
+COMMENT ON COLUMN venue_credentials.api_key IS 'API key as an encrypted envelope';
+COMMENT ON COLUMN venue_credentials.api_secret IS 'API secret as an encrypted envelope, for venues that sign requests';
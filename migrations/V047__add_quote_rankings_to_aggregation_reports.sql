@@ -0,0 +1,11 @@
+-- Add quote rankings to aggregation reports
+-- Migration: V047
+-- Description: Keeps how each quote of an RFQ was ranked alongside the
+-- venue outcomes, so a ranking can be explained when a client or market
+-- maker disputes it: rank, score, and the weighted factors the score was
+-- made of. Reports written before this migration have no rankings.
+
+ALTER TABLE rfq_aggregation_reports
+    ADD COLUMN IF NOT EXISTS quote_rankings JSONB NOT NULL DEFAULT '[]';
+
+COMMENT ON COLUMN rfq_aggregation_reports.quote_rankings IS 'Ranked quotes, best first, each with its score breakdown (factor, raw_value, weight, contribution)';
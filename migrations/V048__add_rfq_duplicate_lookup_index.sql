@@ -0,0 +1,12 @@
+-- Index open RFQs by client, instrument and side
+-- Migration: V048
+-- Description: A new RFQ is checked against the client's open RFQs for the
+-- same instrument and side, to warn about (or refuse) near-duplicates. The
+-- partial index covers only the non-terminal states the check looks at.
+
+CREATE INDEX IF NOT EXISTS idx_rfqs_client_symbol_side_open
+    ON rfqs(client_id, (instrument->>'symbol'), side, state)
+    WHERE state IN (
+        'CREATED', 'QUOTE_REQUESTING', 'QUOTES_RECEIVED',
+        'NEGOTIATING', 'CLIENT_SELECTING', 'EXECUTING'
+    );
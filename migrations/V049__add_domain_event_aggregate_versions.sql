@@ -0,0 +1,57 @@
+-- Add per-aggregate versions to domain events
+-- Migration: V049
+-- Description: Every versioned event records the aggregate (RFQ, trade or
+-- negotiation) that emitted it and that aggregate's version, so consumers
+-- can de-duplicate redeliveries on (aggregate_id, aggregate_version).
+-- Aggregates also change without emitting events, so versions may skip;
+-- each versioned event is therefore numbered in its aggregate's stream as
+-- well, consecutively from 1, and consumers detect gaps on that sequence.
+-- Appends must follow the aggregate's last stored sequence number; the
+-- unique index rejects concurrent appends of one sequence number.
+--
+-- Existing events only identify their RFQ, so they are backfilled as the
+-- RFQ's stream: numbered from 1 in timestamp order, ties broken by sequence
+-- and insertion order. Events without an RFQ stay unversioned (version 0).
+
+ALTER TABLE domain_events
+    ADD COLUMN IF NOT EXISTS aggregate_id VARCHAR(36),
+    ADD COLUMN IF NOT EXISTS aggregate_version BIGINT NOT NULL DEFAULT 0
+        CHECK (aggregate_version >= 0),
+    ADD COLUMN IF NOT EXISTS aggregate_sequence BIGINT NOT NULL DEFAULT 0
+        CHECK (aggregate_sequence >= 0);
+
+WITH numbered AS (
+    SELECT id,
+           ROW_NUMBER() OVER (
+               PARTITION BY rfq_id
+               ORDER BY timestamp ASC, sequence ASC, id ASC
+           ) AS version
+    FROM domain_events
+    WHERE rfq_id IS NOT NULL AND aggregate_id IS NULL
+)
+UPDATE domain_events
+SET aggregate_id = domain_events.rfq_id,
+    aggregate_version = numbered.version,
+    aggregate_sequence = numbered.version
+FROM numbered
+WHERE domain_events.id = numbered.id;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_domain_events_aggregate_sequence
+    ON domain_events(aggregate_id, aggregate_sequence)
+    WHERE aggregate_id IS NOT NULL AND aggregate_version > 0;
+
+-- Negotiations now carry a version like RFQs and trades; existing rows
+-- start at 1.
+ALTER TABLE negotiations
+    ADD COLUMN IF NOT EXISTS version BIGINT NOT NULL DEFAULT 1;
+
+-- The RFQ summary projection skips redelivered events it already folded
+-- in. Existing rows start without versions and still skip on sequence.
+ALTER TABLE rfq_summary
+    ADD COLUMN IF NOT EXISTS aggregate_versions JSONB NOT NULL DEFAULT '{}';
+
+COMMENT ON COLUMN domain_events.aggregate_id IS 'Aggregate that emitted the event; NULL for unversioned events';
+COMMENT ON COLUMN domain_events.aggregate_version IS 'Version of the emitting aggregate at emission time; 0 for unversioned events';
+COMMENT ON COLUMN domain_events.aggregate_sequence IS 'Position in the emitting aggregate''s events, consecutive from 1 per aggregate; 0 for unversioned events';
+COMMENT ON COLUMN negotiations.version IS 'Version incremented on every change, stamped on emitted events';
+COMMENT ON COLUMN rfq_summary.aggregate_versions IS 'Last folded version per aggregate ID, for skipping redelivered events';
@@ -22,16 +22,22 @@
 //! ```
 
 use crate::api::grpc::proto;
+use crate::application::services::rfq_broadcast::RfqBroadcast;
+use crate::domain::entities::allocation::{
+    AllocationStatus as DomainAllocationStatus, TradeAllocation as DomainTradeAllocation,
+};
 use crate::domain::entities::quote::Quote as DomainQuote;
 use crate::domain::entities::rfq::Rfq as DomainRfq;
-use crate::domain::entities::trade::Trade as DomainTrade;
+use crate::domain::entities::trade::{
+    FeeComponent as DomainFeeComponent, FeeKind as DomainFeeKind, Trade as DomainTrade,
+};
 use crate::domain::value_objects::enums::{
     AssetClass as DomainAssetClass, VenueType as DomainVenueType,
 };
 use crate::domain::value_objects::timestamp::Timestamp as DomainTimestamp;
 use crate::domain::value_objects::{
-    Instrument as DomainInstrument, OrderSide as DomainOrderSide, Price, Quantity, QuoteId, RfqId,
-    RfqState as DomainRfqState, TradeId,
+    Instrument as DomainInstrument, OrderSide as DomainOrderSide, Price, Quantity,
+    QuantityDisclosure, QuoteId, RfqId, RfqState as DomainRfqState, SizeNegotiationMode, TradeId,
 };
 use rust_decimal::Decimal;
 use std::str::FromStr;
@@ -220,6 +226,27 @@ pub fn proto_order_side_to_domain(value: i32) -> Result<DomainOrderSide, Convers
     }
 }
 
+/// Converts a proto OrderSide i32 value to an optional domain OrderSide.
+///
+/// Unspecified maps to `None`.
+///
+/// # Errors
+///
+/// Returns `ConversionError::InvalidEnum` if the value is invalid.
+pub fn proto_optional_order_side_to_domain(
+    value: i32,
+) -> Result<Option<DomainOrderSide>, ConversionError> {
+    if value == proto::OrderSide::Unspecified as i32 {
+        return Ok(None);
+    }
+    proto_order_side_to_domain(value).map(Some)
+}
+
+/// Encodes an optional domain OrderSide, with `None` as unspecified.
+fn optional_order_side_to_proto(side: Option<DomainOrderSide>) -> i32 {
+    side.map_or(proto::OrderSide::Unspecified as i32, i32::from)
+}
+
 impl From<DomainRfqState> for proto::RfqState {
     fn from(state: DomainRfqState) -> Self {
         match state {
@@ -267,6 +294,97 @@ pub fn proto_rfq_state_to_domain(value: i32) -> Result<DomainRfqState, Conversio
     }
 }
 
+impl From<&SizeNegotiationMode> for proto::SizeNegotiation {
+    fn from(mode: &SizeNegotiationMode) -> Self {
+        let proto_mode = match mode {
+            SizeNegotiationMode::AllOrNothing => proto::SizeMode::AllOrNothing,
+            SizeNegotiationMode::FillOrKill => proto::SizeMode::FillOrKill,
+            SizeNegotiationMode::MinQuantity(_) => proto::SizeMode::MinQuantity,
+            SizeNegotiationMode::BestEffort => proto::SizeMode::BestEffort,
+        };
+        Self {
+            mode: proto_mode as i32,
+            min_quantity: mode.min_quantity().map(proto::Decimal::from),
+        }
+    }
+}
+
+/// Converts an optional proto SizeNegotiation to a domain size mode.
+///
+/// An absent message yields the default mode.
+///
+/// # Errors
+///
+/// Returns `ConversionError::InvalidEnum` if the mode is invalid or unspecified.
+/// Returns `ConversionError::MissingField` if `SIZE_MODE_MIN_QUANTITY` has no
+/// `min_quantity`, or `ConversionError::InvalidDecimal`/`InvalidValue` if it
+/// cannot be converted to a Quantity.
+pub fn proto_size_mode_to_domain(
+    size_mode: Option<proto::SizeNegotiation>,
+) -> Result<SizeNegotiationMode, ConversionError> {
+    let Some(size_mode) = size_mode else {
+        return Ok(SizeNegotiationMode::default());
+    };
+    match proto::SizeMode::try_from(size_mode.mode) {
+        Ok(proto::SizeMode::AllOrNothing) => Ok(SizeNegotiationMode::AllOrNothing),
+        Ok(proto::SizeMode::FillOrKill) => Ok(SizeNegotiationMode::FillOrKill),
+        Ok(proto::SizeMode::MinQuantity) => Ok(SizeNegotiationMode::MinQuantity(
+            proto_decimal_to_quantity(size_mode.min_quantity, "size_mode.min_quantity")?,
+        )),
+        Ok(proto::SizeMode::BestEffort) => Ok(SizeNegotiationMode::BestEffort),
+        Ok(proto::SizeMode::Unspecified) | Err(_) => Err(ConversionError::InvalidEnum {
+            enum_name: "SizeMode",
+            value: size_mode.mode,
+        }),
+    }
+}
+
+impl From<QuantityDisclosure> for proto::QuantityDisclosure {
+    fn from(disclosure: QuantityDisclosure) -> Self {
+        let (mode, bucket) = match disclosure {
+            QuantityDisclosure::Exact => (proto::DisclosureMode::Exact, None),
+            QuantityDisclosure::Bucketed { bucket } => (
+                proto::DisclosureMode::Bucketed,
+                Some(proto::Decimal::from(bucket)),
+            ),
+            QuantityDisclosure::Hidden => (proto::DisclosureMode::Hidden, None),
+        };
+        Self {
+            mode: mode as i32,
+            bucket,
+        }
+    }
+}
+
+/// Converts an optional proto QuantityDisclosure to a domain disclosure.
+///
+/// An absent message yields the default disclosure.
+///
+/// # Errors
+///
+/// Returns `ConversionError::InvalidEnum` if the mode is invalid or unspecified.
+/// Returns `ConversionError::MissingField` if `DISCLOSURE_MODE_BUCKETED` has no
+/// `bucket`, or `ConversionError::InvalidDecimal`/`InvalidValue` if it cannot
+/// be converted to a Quantity.
+pub fn proto_quantity_disclosure_to_domain(
+    disclosure: Option<proto::QuantityDisclosure>,
+) -> Result<QuantityDisclosure, ConversionError> {
+    let Some(disclosure) = disclosure else {
+        return Ok(QuantityDisclosure::default());
+    };
+    match proto::DisclosureMode::try_from(disclosure.mode) {
+        Ok(proto::DisclosureMode::Exact) => Ok(QuantityDisclosure::Exact),
+        Ok(proto::DisclosureMode::Bucketed) => Ok(QuantityDisclosure::Bucketed {
+            bucket: proto_decimal_to_quantity(disclosure.bucket, "quantity_disclosure.bucket")?,
+        }),
+        Ok(proto::DisclosureMode::Hidden) => Ok(QuantityDisclosure::Hidden),
+        Ok(proto::DisclosureMode::Unspecified) | Err(_) => Err(ConversionError::InvalidEnum {
+            enum_name: "DisclosureMode",
+            value: disclosure.mode,
+        }),
+    }
+}
+
 impl From<DomainAssetClass> for proto::AssetClass {
     fn from(ac: DomainAssetClass) -> Self {
         match ac {
@@ -275,6 +393,7 @@ impl From<DomainAssetClass> for proto::AssetClass {
             DomainAssetClass::Stock => proto::AssetClass::Stock,
             DomainAssetClass::Forex => proto::AssetClass::Forex,
             DomainAssetClass::Commodity => proto::AssetClass::Commodity,
+            DomainAssetClass::CryptoOptions => proto::AssetClass::CryptoOptions,
         }
     }
 }
@@ -293,6 +412,7 @@ impl From<DomainVenueType> for proto::VenueType {
             DomainVenueType::DexAggregator => proto::VenueType::DexAggregator,
             DomainVenueType::Protocol => proto::VenueType::Protocol,
             DomainVenueType::RfqProtocol => proto::VenueType::RfqProtocol,
+            DomainVenueType::Simulated => proto::VenueType::Simulated,
         }
     }
 }
@@ -328,6 +448,21 @@ impl From<DomainInstrument> for proto::Instrument {
 // Quote Conversions
 // ============================================================================
 
+impl From<&RfqBroadcast> for proto::RfqBroadcast {
+    fn from(broadcast: &RfqBroadcast) -> Self {
+        Self {
+            rfq_id: Some(proto::Uuid::from(broadcast.rfq_id)),
+            instrument: Some(proto::Instrument::from(&broadcast.instrument)),
+            side: i32::from(broadcast.side),
+            quantity: broadcast.quantity.map(proto::Decimal::from),
+            expires_at: Some(proto::Timestamp::from(broadcast.expires_at)),
+            requester: broadcast.requester.clone(),
+            anonymous: broadcast.anonymous,
+        }
+    }
+}
+
+#[allow(deprecated)]
 impl From<&DomainQuote> for proto::Quote {
     fn from(quote: &DomainQuote) -> Self {
         Self {
@@ -340,6 +475,7 @@ impl From<&DomainQuote> for proto::Quote {
             commission: quote.commission().map(proto::Decimal::from),
             valid_until: Some(proto::Timestamp::from(quote.valid_until())),
             created_at: Some(proto::Timestamp::from(quote.created_at())),
+            side: optional_order_side_to_proto(quote.side()),
         }
     }
 }
@@ -354,6 +490,68 @@ impl From<DomainQuote> for proto::Quote {
 // Trade Conversions
 // ============================================================================
 
+impl From<DomainFeeKind> for proto::FeeKind {
+    fn from(kind: DomainFeeKind) -> Self {
+        match kind {
+            DomainFeeKind::Venue => proto::FeeKind::Venue,
+            DomainFeeKind::Gas => proto::FeeKind::Gas,
+            DomainFeeKind::Platform => proto::FeeKind::Platform,
+        }
+    }
+}
+
+impl From<DomainFeeKind> for i32 {
+    fn from(kind: DomainFeeKind) -> Self {
+        proto::FeeKind::from(kind) as i32
+    }
+}
+
+impl From<&DomainFeeComponent> for proto::FeeComponent {
+    fn from(fee: &DomainFeeComponent) -> Self {
+        Self {
+            kind: i32::from(fee.kind()),
+            amount: Some(proto::Decimal::from(fee.amount())),
+            currency: fee.currency().to_string(),
+        }
+    }
+}
+
+impl From<&DomainAllocationStatus> for proto::AllocationStatus {
+    fn from(status: &DomainAllocationStatus) -> Self {
+        match status {
+            DomainAllocationStatus::Pending => proto::AllocationStatus::Pending,
+            DomainAllocationStatus::Executing => proto::AllocationStatus::Executing,
+            DomainAllocationStatus::Filled => proto::AllocationStatus::Filled,
+            DomainAllocationStatus::Failed { .. } => proto::AllocationStatus::Failed,
+        }
+    }
+}
+
+impl From<&DomainAllocationStatus> for i32 {
+    fn from(status: &DomainAllocationStatus) -> Self {
+        proto::AllocationStatus::from(status) as i32
+    }
+}
+
+impl From<&DomainTradeAllocation> for proto::TradeAllocation {
+    fn from(recorded: &DomainTradeAllocation) -> Self {
+        let allocation = recorded.allocation();
+        Self {
+            venue_id: allocation.venue_id().to_string(),
+            quote_id: Some(proto::Uuid::from(allocation.quote_id())),
+            quantity: Some(proto::Decimal::from(allocation.allocated_quantity())),
+            price: Some(proto::Decimal::from(allocation.price())),
+            scheduled_at: Some(proto::Timestamp::from(recorded.scheduled_at())),
+            status: i32::from(recorded.status()),
+            failure_reason: recorded
+                .status()
+                .failure_reason()
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+}
+
 impl From<&DomainTrade> for proto::Trade {
     fn from(trade: &DomainTrade) -> Self {
         Self {
@@ -365,6 +563,16 @@ impl From<&DomainTrade> for proto::Trade {
             quantity: Some(proto::Decimal::from(trade.quantity())),
             venue_execution_ref: trade.venue_execution_ref().unwrap_or_default().to_string(),
             created_at: Some(proto::Timestamp::from(trade.created_at())),
+            fees: trade.fees().iter().map(proto::FeeComponent::from).collect(),
+            reference_price_at_execution: trade
+                .reference_price_at_execution()
+                .map(proto::Decimal::from),
+            allocations: trade
+                .allocations()
+                .iter()
+                .map(proto::TradeAllocation::from)
+                .collect(),
+            allocation_vwap: trade.allocation_vwap().map(proto::Decimal::from),
         }
     }
 }
@@ -379,13 +587,14 @@ impl From<DomainTrade> for proto::Trade {
 // RFQ Conversions
 // ============================================================================
 
+#[allow(deprecated)]
 impl From<&DomainRfq> for proto::Rfq {
     fn from(rfq: &DomainRfq) -> Self {
         Self {
             id: Some(proto::Uuid::from(rfq.id())),
             client_id: rfq.client_id().to_string(),
             instrument: Some(proto::Instrument::from(rfq.instrument())),
-            side: i32::from(rfq.side()),
+            side: optional_order_side_to_proto(rfq.direction().side()),
             quantity: Some(proto::Decimal::from(rfq.quantity())),
             state: i32::from(rfq.state()),
             expires_at: Some(proto::Timestamp::from(rfq.expires_at())),
@@ -393,6 +602,9 @@ impl From<&DomainRfq> for proto::Rfq {
             selected_quote_id: rfq.selected_quote_id().map(proto::Uuid::from),
             created_at: Some(proto::Timestamp::from(rfq.created_at())),
             updated_at: Some(proto::Timestamp::from(rfq.updated_at())),
+            size_mode: Some(proto::SizeNegotiation::from(rfq.size_mode())),
+            quantity_disclosure: Some(proto::QuantityDisclosure::from(rfq.quantity_disclosure())),
+            two_way: rfq.is_two_way(),
         }
     }
 }
@@ -486,6 +698,75 @@ mod tests {
         assert_eq!(original, back);
     }
 
+    #[test]
+    fn trade_conversion_includes_execution_costs() {
+        use crate::domain::value_objects::VenueId;
+
+        let mut trade = DomainTrade::new(
+            RfqId::new_v4(),
+            QuoteId::new_v4(),
+            VenueId::new("venue-1"),
+            Price::new(101.0).unwrap(),
+            Quantity::new(2.0).unwrap(),
+        );
+        trade.set_reference_price_at_execution(Price::new(100.0).unwrap());
+        trade.add_fee(DomainFeeComponent::new(
+            DomainFeeKind::Gas,
+            Decimal::from_str("0.00042").unwrap(),
+            "ETH",
+        ));
+
+        let proto_trade = proto::Trade::from(&trade);
+        assert_eq!(proto_trade.fees.len(), 1);
+        let fee = proto_trade.fees.first().unwrap();
+        assert_eq!(fee.kind, proto::FeeKind::Gas as i32);
+        assert_eq!(fee.amount.as_ref().unwrap().value, "0.00042");
+        assert_eq!(fee.currency, "ETH");
+        assert_eq!(
+            proto_trade.reference_price_at_execution.unwrap().value,
+            "100"
+        );
+        assert!(proto_trade.allocations.is_empty());
+        assert!(proto_trade.allocation_vwap.is_none());
+    }
+
+    #[test]
+    fn trade_conversion_includes_allocations() {
+        use crate::domain::entities::allocation::Allocation;
+        use crate::domain::value_objects::VenueId;
+
+        let mut trade = DomainTrade::new(
+            RfqId::new_v4(),
+            QuoteId::new_v4(),
+            VenueId::new("venue-1"),
+            Price::new(102.0).unwrap(),
+            Quantity::new(10.0).unwrap(),
+        );
+        for (venue, quantity, price) in [("mm-a", 6.0, 100.0), ("mm-b", 4.0, 105.0)] {
+            let allocation = Allocation::new(
+                VenueId::new(venue),
+                QuoteId::new_v4(),
+                Quantity::new(quantity).unwrap(),
+                Price::new(price).unwrap(),
+            )
+            .unwrap();
+            trade.add_allocation(DomainTradeAllocation::scheduled(
+                allocation,
+                DomainTimestamp::now(),
+            ));
+        }
+
+        let proto_trade = proto::Trade::from(&trade);
+        assert_eq!(proto_trade.allocations.len(), 2);
+        let first = proto_trade.allocations.first().unwrap();
+        assert_eq!(first.venue_id, "mm-a");
+        assert_eq!(first.quantity.as_ref().unwrap().value, "6");
+        assert_eq!(first.status, proto::AllocationStatus::Pending as i32);
+        assert!(first.failure_reason.is_empty());
+        let vwap = Decimal::from_str(&proto_trade.allocation_vwap.unwrap().value).unwrap();
+        assert_eq!(vwap, Decimal::from(102));
+    }
+
     #[test]
     fn invalid_uuid_returns_error() {
         let proto_uuid = proto::Uuid {
@@ -581,6 +862,81 @@ mod tests {
         }
     }
 
+    #[test]
+    fn size_mode_all_variants_roundtrip() {
+        let modes = [
+            SizeNegotiationMode::AllOrNothing,
+            SizeNegotiationMode::FillOrKill,
+            SizeNegotiationMode::MinQuantity(Quantity::new(2.5).unwrap()),
+            SizeNegotiationMode::BestEffort,
+        ];
+        for mode in modes {
+            let proto_mode = proto::SizeNegotiation::from(&mode);
+            assert_eq!(
+                proto_mode.min_quantity.is_some(),
+                mode.min_quantity().is_some()
+            );
+
+            let back = proto_size_mode_to_domain(Some(proto_mode)).unwrap();
+            assert_eq!(back, mode);
+        }
+    }
+
+    #[test]
+    fn quantity_disclosure_roundtrip_and_missing_bucket() {
+        let disclosures = [
+            QuantityDisclosure::Exact,
+            QuantityDisclosure::Bucketed {
+                bucket: Quantity::new(50.0).unwrap(),
+            },
+            QuantityDisclosure::Hidden,
+        ];
+        for disclosure in disclosures {
+            let proto_disclosure = proto::QuantityDisclosure::from(disclosure);
+            let back = proto_quantity_disclosure_to_domain(Some(proto_disclosure)).unwrap();
+            assert_eq!(back, disclosure);
+        }
+        assert_eq!(
+            proto_quantity_disclosure_to_domain(None).unwrap(),
+            QuantityDisclosure::Exact
+        );
+
+        let missing_bucket = proto::QuantityDisclosure {
+            mode: proto::DisclosureMode::Bucketed as i32,
+            bucket: None,
+        };
+        assert!(matches!(
+            proto_quantity_disclosure_to_domain(Some(missing_bucket)),
+            Err(ConversionError::MissingField(_))
+        ));
+    }
+
+    #[test]
+    fn size_mode_absent_defaults_and_invalid_errors() {
+        assert_eq!(
+            proto_size_mode_to_domain(None).unwrap(),
+            SizeNegotiationMode::AllOrNothing
+        );
+
+        let unspecified = proto::SizeNegotiation {
+            mode: proto::SizeMode::Unspecified as i32,
+            min_quantity: None,
+        };
+        assert!(matches!(
+            proto_size_mode_to_domain(Some(unspecified)),
+            Err(ConversionError::InvalidEnum { .. })
+        ));
+
+        let missing_min = proto::SizeNegotiation {
+            mode: proto::SizeMode::MinQuantity as i32,
+            min_quantity: None,
+        };
+        assert!(matches!(
+            proto_size_mode_to_domain(Some(missing_min)),
+            Err(ConversionError::MissingField(_))
+        ));
+    }
+
     #[test]
     fn asset_class_conversion() {
         let domain = DomainAssetClass::CryptoSpot;
@@ -632,6 +988,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn rfq_conversion() {
         use crate::domain::value_objects::Symbol;
         use crate::domain::value_objects::enums::AssetClass;
@@ -655,6 +1012,10 @@ mod tests {
         assert_eq!(proto_rfq.side, proto::OrderSide::Buy as i32);
         assert!(proto_rfq.quantity.is_some());
         assert_eq!(proto_rfq.state, proto::RfqState::Created as i32);
+        assert_eq!(
+            proto_rfq.size_mode.unwrap().mode,
+            proto::SizeMode::AllOrNothing as i32
+        );
     }
 
     #[test]
@@ -0,0 +1,683 @@
+//! # gRPC v2 Conversions
+//!
+//! Conversions between domain types and the `otc.rfq.v2` Protocol Buffer
+//! messages.
+//!
+//! v2 is the canonical wire format: the service builds v2 messages from the
+//! domain and the v1 API is derived from them by
+//! [`versioning`](crate::api::grpc::versioning). The helpers mirror those in
+//! [`conversions`](crate::api::grpc::conversions) and share its
+//! [`ConversionError`].
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::api::grpc::conversions_v2::proto_direction_to_domain;
+//! use otc_rfq::api::grpc::proto::otc_rfq_v2 as v2;
+//! use otc_rfq::domain::value_objects::RfqDirection;
+//!
+//! let direction = proto_direction_to_domain(v2::RfqDirection::TwoWay as i32).unwrap();
+//! assert_eq!(direction, RfqDirection::TwoWay);
+//! assert!(proto_direction_to_domain(v2::RfqDirection::Unspecified as i32).is_err());
+//! ```
+
+use crate::api::grpc::conversions::{ConversionError, require_field};
+use crate::api::grpc::proto::otc_rfq_v2 as v2;
+use crate::application::services::ranking_strategy::RankedQuote;
+use crate::application::services::rfq_broadcast::RfqBroadcast;
+use crate::domain::entities::allocation::{
+    AllocationStatus as DomainAllocationStatus, TradeAllocation as DomainTradeAllocation,
+};
+use crate::domain::entities::negotiation::Negotiation;
+use crate::domain::entities::quote::{Quote as DomainQuote, QuoteFirmness};
+use crate::domain::entities::rfq::Rfq as DomainRfq;
+use crate::domain::entities::trade::{
+    FeeComponent as DomainFeeComponent, FeeKind as DomainFeeKind, Trade as DomainTrade,
+};
+use crate::domain::value_objects::enums::AssetClass as DomainAssetClass;
+use crate::domain::value_objects::timestamp::Timestamp as DomainTimestamp;
+use crate::domain::value_objects::{
+    Instrument as DomainInstrument, NegotiationId, NegotiationState as DomainNegotiationState,
+    OrderSide as DomainOrderSide, Price, Quantity, QuantityDisclosure, QuoteId, RfqDirection,
+    RfqId, RfqState as DomainRfqState, SizeNegotiationMode, TradeId,
+};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+// ============================================================================
+// UUID Conversions
+// ============================================================================
+
+impl From<RfqId> for v2::Uuid {
+    fn from(id: RfqId) -> Self {
+        Self {
+            value: id.to_string(),
+        }
+    }
+}
+
+impl TryFrom<v2::Uuid> for RfqId {
+    type Error = ConversionError;
+
+    fn try_from(proto: v2::Uuid) -> Result<Self, Self::Error> {
+        uuid::Uuid::parse_str(&proto.value)
+            .map(RfqId::from)
+            .map_err(|_| ConversionError::InvalidUuid(proto.value))
+    }
+}
+
+impl From<QuoteId> for v2::Uuid {
+    fn from(id: QuoteId) -> Self {
+        Self {
+            value: id.to_string(),
+        }
+    }
+}
+
+impl TryFrom<v2::Uuid> for QuoteId {
+    type Error = ConversionError;
+
+    fn try_from(proto: v2::Uuid) -> Result<Self, Self::Error> {
+        uuid::Uuid::parse_str(&proto.value)
+            .map(QuoteId::from)
+            .map_err(|_| ConversionError::InvalidUuid(proto.value))
+    }
+}
+
+impl From<TradeId> for v2::Uuid {
+    fn from(id: TradeId) -> Self {
+        Self {
+            value: id.to_string(),
+        }
+    }
+}
+
+impl From<NegotiationId> for v2::Uuid {
+    fn from(id: NegotiationId) -> Self {
+        Self {
+            value: id.to_string(),
+        }
+    }
+}
+
+// ============================================================================
+// Decimal and Timestamp Conversions
+// ============================================================================
+
+impl From<Decimal> for v2::Decimal {
+    fn from(d: Decimal) -> Self {
+        Self {
+            value: d.to_string(),
+        }
+    }
+}
+
+impl TryFrom<v2::Decimal> for Decimal {
+    type Error = ConversionError;
+
+    fn try_from(proto: v2::Decimal) -> Result<Self, Self::Error> {
+        Decimal::from_str(&proto.value).map_err(|_| ConversionError::InvalidDecimal(proto.value))
+    }
+}
+
+impl From<Price> for v2::Decimal {
+    fn from(p: Price) -> Self {
+        Self {
+            value: p.to_string(),
+        }
+    }
+}
+
+impl From<Quantity> for v2::Decimal {
+    fn from(q: Quantity) -> Self {
+        Self {
+            value: q.to_string(),
+        }
+    }
+}
+
+impl From<DomainTimestamp> for v2::Timestamp {
+    fn from(ts: DomainTimestamp) -> Self {
+        let millis = ts.timestamp_millis();
+        let seconds = millis / 1000;
+        let nanos = ((millis % 1000) * 1_000_000) as i32;
+        Self { seconds, nanos }
+    }
+}
+
+impl From<v2::Timestamp> for DomainTimestamp {
+    fn from(proto: v2::Timestamp) -> Self {
+        let millis = proto.seconds * 1000 + i64::from(proto.nanos) / 1_000_000;
+        DomainTimestamp::from_millis(millis).unwrap_or_else(DomainTimestamp::now)
+    }
+}
+
+// ============================================================================
+// Enum Conversions
+// ============================================================================
+
+impl From<DomainOrderSide> for v2::OrderSide {
+    fn from(side: DomainOrderSide) -> Self {
+        match side {
+            DomainOrderSide::Buy => v2::OrderSide::Buy,
+            DomainOrderSide::Sell => v2::OrderSide::Sell,
+        }
+    }
+}
+
+/// Converts a v2 OrderSide i32 value to an optional domain OrderSide.
+///
+/// Unspecified maps to `None`.
+///
+/// # Errors
+///
+/// Returns `ConversionError::InvalidEnum` if the value is invalid.
+pub fn proto_optional_order_side_to_domain(
+    value: i32,
+) -> Result<Option<DomainOrderSide>, ConversionError> {
+    match v2::OrderSide::try_from(value) {
+        Ok(v2::OrderSide::Unspecified) => Ok(None),
+        Ok(v2::OrderSide::Buy) => Ok(Some(DomainOrderSide::Buy)),
+        Ok(v2::OrderSide::Sell) => Ok(Some(DomainOrderSide::Sell)),
+        Err(_) => Err(ConversionError::InvalidEnum {
+            enum_name: "OrderSide",
+            value,
+        }),
+    }
+}
+
+/// Encodes an optional domain OrderSide, with `None` as unspecified.
+fn optional_order_side_to_proto(side: Option<DomainOrderSide>) -> i32 {
+    side.map_or(v2::OrderSide::Unspecified, v2::OrderSide::from) as i32
+}
+
+impl From<RfqDirection> for v2::RfqDirection {
+    fn from(direction: RfqDirection) -> Self {
+        match direction {
+            RfqDirection::Buy => v2::RfqDirection::Buy,
+            RfqDirection::Sell => v2::RfqDirection::Sell,
+            RfqDirection::TwoWay => v2::RfqDirection::TwoWay,
+        }
+    }
+}
+
+/// Converts a v2 RfqDirection i32 value to a domain RfqDirection.
+///
+/// # Errors
+///
+/// Returns `ConversionError::InvalidEnum` if the value is invalid or unspecified.
+pub fn proto_direction_to_domain(value: i32) -> Result<RfqDirection, ConversionError> {
+    match v2::RfqDirection::try_from(value) {
+        Ok(v2::RfqDirection::Buy) => Ok(RfqDirection::Buy),
+        Ok(v2::RfqDirection::Sell) => Ok(RfqDirection::Sell),
+        Ok(v2::RfqDirection::TwoWay) => Ok(RfqDirection::TwoWay),
+        Ok(v2::RfqDirection::Unspecified) | Err(_) => Err(ConversionError::InvalidEnum {
+            enum_name: "RfqDirection",
+            value,
+        }),
+    }
+}
+
+impl From<DomainRfqState> for v2::RfqState {
+    fn from(state: DomainRfqState) -> Self {
+        match state {
+            DomainRfqState::Created => v2::RfqState::Created,
+            DomainRfqState::QuoteRequesting => v2::RfqState::QuoteRequesting,
+            DomainRfqState::QuotesReceived => v2::RfqState::QuotesReceived,
+            DomainRfqState::ClientSelecting => v2::RfqState::ClientSelecting,
+            DomainRfqState::Executing => v2::RfqState::Executing,
+            DomainRfqState::Executed => v2::RfqState::Executed,
+            DomainRfqState::Failed => v2::RfqState::Failed,
+            DomainRfqState::Cancelled => v2::RfqState::Cancelled,
+            DomainRfqState::Expired => v2::RfqState::Expired,
+            DomainRfqState::Negotiating => v2::RfqState::Negotiating,
+        }
+    }
+}
+
+/// Converts a v2 RfqState value to a domain RfqState.
+///
+/// # Errors
+///
+/// Returns `ConversionError::InvalidEnum` if the value is invalid or unspecified.
+pub fn proto_rfq_state_to_domain(value: i32) -> Result<DomainRfqState, ConversionError> {
+    match v2::RfqState::try_from(value) {
+        Ok(v2::RfqState::Created) => Ok(DomainRfqState::Created),
+        Ok(v2::RfqState::QuoteRequesting) => Ok(DomainRfqState::QuoteRequesting),
+        Ok(v2::RfqState::QuotesReceived) => Ok(DomainRfqState::QuotesReceived),
+        Ok(v2::RfqState::ClientSelecting) => Ok(DomainRfqState::ClientSelecting),
+        Ok(v2::RfqState::Executing) => Ok(DomainRfqState::Executing),
+        Ok(v2::RfqState::Executed) => Ok(DomainRfqState::Executed),
+        Ok(v2::RfqState::Failed) => Ok(DomainRfqState::Failed),
+        Ok(v2::RfqState::Cancelled) => Ok(DomainRfqState::Cancelled),
+        Ok(v2::RfqState::Expired) => Ok(DomainRfqState::Expired),
+        Ok(v2::RfqState::Negotiating) => Ok(DomainRfqState::Negotiating),
+        Ok(v2::RfqState::Unspecified) | Err(_) => Err(ConversionError::InvalidEnum {
+            enum_name: "RfqState",
+            value,
+        }),
+    }
+}
+
+impl From<&SizeNegotiationMode> for v2::SizeNegotiation {
+    fn from(mode: &SizeNegotiationMode) -> Self {
+        let proto_mode = match mode {
+            SizeNegotiationMode::AllOrNothing => v2::SizeMode::AllOrNothing,
+            SizeNegotiationMode::FillOrKill => v2::SizeMode::FillOrKill,
+            SizeNegotiationMode::MinQuantity(_) => v2::SizeMode::MinQuantity,
+            SizeNegotiationMode::BestEffort => v2::SizeMode::BestEffort,
+        };
+        Self {
+            mode: proto_mode as i32,
+            min_quantity: mode.min_quantity().map(v2::Decimal::from),
+        }
+    }
+}
+
+/// Converts an optional v2 SizeNegotiation to a domain size mode.
+///
+/// An absent message yields the default mode.
+///
+/// # Errors
+///
+/// Returns `ConversionError::InvalidEnum` if the mode is invalid or unspecified.
+/// Returns `ConversionError::MissingField` if `SIZE_MODE_MIN_QUANTITY` has no
+/// `min_quantity`, or `ConversionError::InvalidDecimal`/`InvalidValue` if it
+/// cannot be converted to a Quantity.
+pub fn proto_size_mode_to_domain(
+    size_mode: Option<v2::SizeNegotiation>,
+) -> Result<SizeNegotiationMode, ConversionError> {
+    let Some(size_mode) = size_mode else {
+        return Ok(SizeNegotiationMode::default());
+    };
+    match v2::SizeMode::try_from(size_mode.mode) {
+        Ok(v2::SizeMode::AllOrNothing) => Ok(SizeNegotiationMode::AllOrNothing),
+        Ok(v2::SizeMode::FillOrKill) => Ok(SizeNegotiationMode::FillOrKill),
+        Ok(v2::SizeMode::MinQuantity) => Ok(SizeNegotiationMode::MinQuantity(
+            proto_decimal_to_quantity(size_mode.min_quantity, "size_mode.min_quantity")?,
+        )),
+        Ok(v2::SizeMode::BestEffort) => Ok(SizeNegotiationMode::BestEffort),
+        Ok(v2::SizeMode::Unspecified) | Err(_) => Err(ConversionError::InvalidEnum {
+            enum_name: "SizeMode",
+            value: size_mode.mode,
+        }),
+    }
+}
+
+impl From<QuantityDisclosure> for v2::QuantityDisclosure {
+    fn from(disclosure: QuantityDisclosure) -> Self {
+        let (mode, bucket) = match disclosure {
+            QuantityDisclosure::Exact => (v2::DisclosureMode::Exact, None),
+            QuantityDisclosure::Bucketed { bucket } => (
+                v2::DisclosureMode::Bucketed,
+                Some(v2::Decimal::from(bucket)),
+            ),
+            QuantityDisclosure::Hidden => (v2::DisclosureMode::Hidden, None),
+        };
+        Self {
+            mode: mode as i32,
+            bucket,
+        }
+    }
+}
+
+/// Converts an optional v2 QuantityDisclosure to a domain disclosure.
+///
+/// An absent message yields the default disclosure.
+///
+/// # Errors
+///
+/// Returns `ConversionError::InvalidEnum` if the mode is invalid or unspecified.
+/// Returns `ConversionError::MissingField` if `DISCLOSURE_MODE_BUCKETED` has no
+/// `bucket`, or `ConversionError::InvalidDecimal`/`InvalidValue` if it cannot
+/// be converted to a Quantity.
+pub fn proto_quantity_disclosure_to_domain(
+    disclosure: Option<v2::QuantityDisclosure>,
+) -> Result<QuantityDisclosure, ConversionError> {
+    let Some(disclosure) = disclosure else {
+        return Ok(QuantityDisclosure::default());
+    };
+    match v2::DisclosureMode::try_from(disclosure.mode) {
+        Ok(v2::DisclosureMode::Exact) => Ok(QuantityDisclosure::Exact),
+        Ok(v2::DisclosureMode::Bucketed) => Ok(QuantityDisclosure::Bucketed {
+            bucket: proto_decimal_to_quantity(disclosure.bucket, "quantity_disclosure.bucket")?,
+        }),
+        Ok(v2::DisclosureMode::Hidden) => Ok(QuantityDisclosure::Hidden),
+        Ok(v2::DisclosureMode::Unspecified) | Err(_) => Err(ConversionError::InvalidEnum {
+            enum_name: "DisclosureMode",
+            value: disclosure.mode,
+        }),
+    }
+}
+
+impl From<DomainAssetClass> for v2::AssetClass {
+    fn from(ac: DomainAssetClass) -> Self {
+        match ac {
+            DomainAssetClass::CryptoSpot => v2::AssetClass::CryptoSpot,
+            DomainAssetClass::CryptoDerivs => v2::AssetClass::CryptoDerivs,
+            DomainAssetClass::Stock => v2::AssetClass::Stock,
+            DomainAssetClass::Forex => v2::AssetClass::Forex,
+            DomainAssetClass::Commodity => v2::AssetClass::Commodity,
+            DomainAssetClass::CryptoOptions => v2::AssetClass::CryptoOptions,
+        }
+    }
+}
+
+impl From<QuoteFirmness> for v2::QuoteFirmness {
+    fn from(firmness: QuoteFirmness) -> Self {
+        match firmness {
+            QuoteFirmness::Firm => v2::QuoteFirmness::Firm,
+            QuoteFirmness::Indicative => v2::QuoteFirmness::Indicative,
+        }
+    }
+}
+
+impl From<DomainNegotiationState> for v2::NegotiationState {
+    fn from(state: DomainNegotiationState) -> Self {
+        match state {
+            DomainNegotiationState::Open => v2::NegotiationState::Open,
+            DomainNegotiationState::CounterPending => v2::NegotiationState::CounterPending,
+            DomainNegotiationState::Accepted => v2::NegotiationState::Accepted,
+            DomainNegotiationState::Rejected => v2::NegotiationState::Rejected,
+            DomainNegotiationState::Expired => v2::NegotiationState::Expired,
+        }
+    }
+}
+
+// ============================================================================
+// Message Conversions
+// ============================================================================
+
+impl From<&DomainInstrument> for v2::Instrument {
+    fn from(inst: &DomainInstrument) -> Self {
+        Self {
+            symbol: inst.symbol().to_string(),
+            asset_class: v2::AssetClass::from(inst.asset_class()) as i32,
+            base_asset: inst.symbol().base_asset().to_string(),
+            quote_asset: inst.symbol().quote_asset().to_string(),
+        }
+    }
+}
+
+impl From<&RfqBroadcast> for v2::RfqBroadcast {
+    fn from(broadcast: &RfqBroadcast) -> Self {
+        Self {
+            rfq_id: Some(v2::Uuid::from(broadcast.rfq_id)),
+            instrument: Some(v2::Instrument::from(&broadcast.instrument)),
+            side: v2::OrderSide::from(broadcast.side) as i32,
+            quantity: broadcast.quantity.map(v2::Decimal::from),
+            expires_at: Some(v2::Timestamp::from(broadcast.expires_at)),
+            requester: broadcast.requester.clone(),
+            anonymous: broadcast.anonymous,
+        }
+    }
+}
+
+impl From<&DomainQuote> for v2::Quote {
+    fn from(quote: &DomainQuote) -> Self {
+        Self {
+            id: Some(v2::Uuid::from(quote.id())),
+            rfq_id: Some(v2::Uuid::from(quote.rfq_id())),
+            venue_id: quote.venue_id().to_string(),
+            price: Some(v2::Decimal::from(quote.price())),
+            quantity: Some(v2::Decimal::from(quote.quantity())),
+            commission: quote.commission().map(v2::Decimal::from),
+            valid_until: Some(v2::Timestamp::from(quote.valid_until())),
+            created_at: Some(v2::Timestamp::from(quote.created_at())),
+            side: optional_order_side_to_proto(quote.side()),
+            firmness: v2::QuoteFirmness::from(quote.firmness()) as i32,
+            received_at: Some(v2::Timestamp::from(quote.received_at())),
+        }
+    }
+}
+
+impl From<&RankedQuote> for v2::RankedQuote {
+    fn from(ranked: &RankedQuote) -> Self {
+        Self {
+            quote: Some(v2::Quote::from(&ranked.quote)),
+            rank: u32::try_from(ranked.rank).unwrap_or(u32::MAX),
+            score: ranked.score,
+            decided_by: ranked
+                .decided_by
+                .map(|rule| rule.to_string())
+                .unwrap_or_default(),
+            all_in_price: ranked
+                .quote
+                .all_in_cost()
+                .map(|_| v2::Decimal::from(ranked.all_in_price())),
+        }
+    }
+}
+
+impl From<DomainFeeKind> for v2::FeeKind {
+    fn from(kind: DomainFeeKind) -> Self {
+        match kind {
+            DomainFeeKind::Venue => v2::FeeKind::Venue,
+            DomainFeeKind::Gas => v2::FeeKind::Gas,
+            DomainFeeKind::Platform => v2::FeeKind::Platform,
+        }
+    }
+}
+
+impl From<&DomainFeeComponent> for v2::FeeComponent {
+    fn from(fee: &DomainFeeComponent) -> Self {
+        Self {
+            kind: v2::FeeKind::from(fee.kind()) as i32,
+            amount: Some(v2::Decimal::from(fee.amount())),
+            currency: fee.currency().to_string(),
+        }
+    }
+}
+
+impl From<&DomainAllocationStatus> for v2::AllocationStatus {
+    fn from(status: &DomainAllocationStatus) -> Self {
+        match status {
+            DomainAllocationStatus::Pending => v2::AllocationStatus::Pending,
+            DomainAllocationStatus::Executing => v2::AllocationStatus::Executing,
+            DomainAllocationStatus::Filled => v2::AllocationStatus::Filled,
+            DomainAllocationStatus::Failed { .. } => v2::AllocationStatus::Failed,
+        }
+    }
+}
+
+impl From<&DomainTradeAllocation> for v2::TradeAllocation {
+    fn from(recorded: &DomainTradeAllocation) -> Self {
+        let allocation = recorded.allocation();
+        Self {
+            venue_id: allocation.venue_id().to_string(),
+            quote_id: Some(v2::Uuid::from(allocation.quote_id())),
+            quantity: Some(v2::Decimal::from(allocation.allocated_quantity())),
+            price: Some(v2::Decimal::from(allocation.price())),
+            scheduled_at: Some(v2::Timestamp::from(recorded.scheduled_at())),
+            status: v2::AllocationStatus::from(recorded.status()) as i32,
+            failure_reason: recorded
+                .status()
+                .failure_reason()
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+}
+
+impl From<&DomainTrade> for v2::Trade {
+    fn from(trade: &DomainTrade) -> Self {
+        Self {
+            id: Some(v2::Uuid::from(trade.id())),
+            rfq_id: Some(v2::Uuid::from(trade.rfq_id())),
+            quote_id: Some(v2::Uuid::from(trade.quote_id())),
+            venue_id: trade.venue_id().to_string(),
+            price: Some(v2::Decimal::from(trade.price())),
+            quantity: Some(v2::Decimal::from(trade.quantity())),
+            venue_execution_ref: trade.venue_execution_ref().unwrap_or_default().to_string(),
+            created_at: Some(v2::Timestamp::from(trade.created_at())),
+            fees: trade.fees().iter().map(v2::FeeComponent::from).collect(),
+            reference_price_at_execution: trade
+                .reference_price_at_execution()
+                .map(v2::Decimal::from),
+            allocations: trade
+                .allocations()
+                .iter()
+                .map(v2::TradeAllocation::from)
+                .collect(),
+            allocation_vwap: trade.allocation_vwap().map(v2::Decimal::from),
+        }
+    }
+}
+
+impl From<&Negotiation> for v2::NegotiationRef {
+    fn from(negotiation: &Negotiation) -> Self {
+        Self {
+            id: Some(v2::Uuid::from(negotiation.id())),
+            mm_account: negotiation.mm_account().to_string(),
+            state: v2::NegotiationState::from(negotiation.state()) as i32,
+            round_count: u32::try_from(negotiation.round_count()).unwrap_or(u32::MAX),
+            latest_price: negotiation.latest_price().map(v2::Decimal::from),
+            updated_at: Some(v2::Timestamp::from(negotiation.updated_at())),
+        }
+    }
+}
+
+/// Converts an RFQ without negotiation references; the service attaches them
+/// when a negotiation repository is configured.
+impl From<&DomainRfq> for v2::Rfq {
+    fn from(rfq: &DomainRfq) -> Self {
+        Self {
+            id: Some(v2::Uuid::from(rfq.id())),
+            client_id: rfq.client_id().to_string(),
+            instrument: Some(v2::Instrument::from(rfq.instrument())),
+            direction: v2::RfqDirection::from(rfq.direction()) as i32,
+            quantity: Some(v2::Decimal::from(rfq.quantity())),
+            state: v2::RfqState::from(rfq.state()) as i32,
+            expires_at: Some(v2::Timestamp::from(rfq.expires_at())),
+            quotes: rfq.quotes().iter().map(v2::Quote::from).collect(),
+            selected_quote_id: rfq.selected_quote_id().map(v2::Uuid::from),
+            created_at: Some(v2::Timestamp::from(rfq.created_at())),
+            updated_at: Some(v2::Timestamp::from(rfq.updated_at())),
+            size_mode: Some(v2::SizeNegotiation::from(rfq.size_mode())),
+            quantity_disclosure: Some(v2::QuantityDisclosure::from(rfq.quantity_disclosure())),
+            activate_at: rfq.activate_at().map(v2::Timestamp::from),
+            negotiations: Vec::new(),
+            failure_reason: rfq
+                .failure_reason()
+                .map(|r| r.detail().to_string())
+                .unwrap_or_default(),
+            version: rfq.version(),
+        }
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Converts a v2 Decimal to a domain Price.
+///
+/// # Errors
+///
+/// Returns `ConversionError::MissingField` if the proto is None.
+/// Returns `ConversionError::InvalidDecimal` if the decimal string is invalid.
+/// Returns `ConversionError::InvalidValue` if the value cannot be converted to Price.
+pub fn proto_decimal_to_price(
+    proto: Option<v2::Decimal>,
+    field_name: &'static str,
+) -> Result<Price, ConversionError> {
+    let decimal = require_field(proto, field_name)?;
+    let value = Decimal::try_from(decimal)?;
+    Price::try_from(value).map_err(|e| ConversionError::InvalidValue {
+        field: field_name,
+        message: e.to_string(),
+    })
+}
+
+/// Converts a v2 Decimal to a domain Quantity.
+///
+/// # Errors
+///
+/// Returns `ConversionError::MissingField` if the proto is None.
+/// Returns `ConversionError::InvalidDecimal` if the decimal string is invalid.
+/// Returns `ConversionError::InvalidValue` if the value cannot be converted to Quantity.
+pub fn proto_decimal_to_quantity(
+    proto: Option<v2::Decimal>,
+    field_name: &'static str,
+) -> Result<Quantity, ConversionError> {
+    let decimal = require_field(proto, field_name)?;
+    let value = Decimal::try_from(decimal)?;
+    Quantity::try_from(value).map_err(|e| ConversionError::InvalidValue {
+        field: field_name,
+        message: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::value_objects::{CounterpartyId, Symbol};
+
+    fn rfq(direction: RfqDirection) -> DomainRfq {
+        let instrument = DomainInstrument::builder(
+            Symbol::new("BTC/USD").unwrap(),
+            DomainAssetClass::CryptoSpot,
+        )
+        .build();
+        RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            instrument,
+            direction,
+            Quantity::new(2.0).unwrap(),
+            DomainTimestamp::now().add_secs(300),
+        )
+        .build()
+    }
+
+    #[test]
+    fn rfq_carries_direction_and_version() {
+        let domain = rfq(RfqDirection::TwoWay);
+        let proto = v2::Rfq::from(&domain);
+        assert_eq!(proto.direction(), v2::RfqDirection::TwoWay);
+        assert_eq!(proto.version, domain.version());
+        assert!(proto.failure_reason.is_empty());
+        assert!(proto.negotiations.is_empty());
+        assert_eq!(
+            proto.instrument.unwrap().asset_class(),
+            v2::AssetClass::CryptoSpot
+        );
+    }
+
+    #[test]
+    fn direction_roundtrip() {
+        for direction in [RfqDirection::Buy, RfqDirection::Sell, RfqDirection::TwoWay] {
+            let value = v2::RfqDirection::from(direction) as i32;
+            assert_eq!(proto_direction_to_domain(value).unwrap(), direction);
+        }
+        assert!(proto_direction_to_domain(42).is_err());
+    }
+
+    #[test]
+    fn rfq_state_roundtrip() {
+        for state in [
+            DomainRfqState::Created,
+            DomainRfqState::QuotesReceived,
+            DomainRfqState::Negotiating,
+            DomainRfqState::Cancelled,
+        ] {
+            let value = v2::RfqState::from(state) as i32;
+            assert_eq!(proto_rfq_state_to_domain(value).unwrap(), state);
+        }
+        assert!(proto_rfq_state_to_domain(v2::RfqState::Unspecified as i32).is_err());
+    }
+
+    #[test]
+    fn optional_side_accepts_unspecified() {
+        assert_eq!(
+            proto_optional_order_side_to_domain(v2::OrderSide::Unspecified as i32).unwrap(),
+            None
+        );
+        assert_eq!(
+            proto_optional_order_side_to_domain(v2::OrderSide::Sell as i32).unwrap(),
+            Some(DomainOrderSide::Sell)
+        );
+        assert!(proto_optional_order_side_to_domain(7).is_err());
+    }
+}
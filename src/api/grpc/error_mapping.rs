@@ -0,0 +1,679 @@
+//! # gRPC Error Mapping
+//!
+//! Converts application and domain errors to gRPC [`Status`] values.
+//!
+//! Every [`DomainError`] variant maps to the `tonic::Code` a client can act
+//! on (`InvalidArgument`, `FailedPrecondition`, `NotFound`,
+//! `ResourceExhausted`, ...) instead of `Internal`. The status also carries
+//! a `google.rpc.Status` details payload holding one `google.rpc.ErrorInfo`:
+//! its `reason` names the variant in `UPPER_SNAKE_CASE` and its `metadata`
+//! holds the variant's structured fields (states, quantities, prices).
+//!
+//! # Client Usage
+//!
+//! Clients in any language decode the payload with the standard
+//! `google.rpc` error details support. Rust clients can use
+//! [`error_info`]:
+//!
+//! ```ignore
+//! if let Some(info) = error_mapping::error_info(&status) {
+//!     if info.reason == "INVALID_STATE_TRANSITION" {
+//!         let from = &info.metadata["from"];
+//!     }
+//! }
+//! ```
+
+use crate::api::grpc::conversions::ConversionError;
+use crate::application::error::ApplicationError;
+use crate::domain::errors::DomainError;
+use bytes::Bytes;
+use prost::Message;
+use std::collections::HashMap;
+use tonic::{Code, Status};
+
+/// `ErrorInfo.domain` of errors raised by this service.
+pub const ERROR_DOMAIN: &str = "otc-rfq";
+
+/// Type URL of the `google.rpc.ErrorInfo` details message.
+pub const ERROR_INFO_TYPE_URL: &str = "type.googleapis.com/google.rpc.ErrorInfo";
+
+/// Wire-compatible `google.rpc.ErrorInfo`.
+#[derive(Clone, PartialEq, Message)]
+pub struct ErrorInfo {
+    /// Variant of the error, in `UPPER_SNAKE_CASE`.
+    #[prost(string, tag = "1")]
+    pub reason: String,
+    /// Service that raised the error ([`ERROR_DOMAIN`]).
+    #[prost(string, tag = "2")]
+    pub domain: String,
+    /// Structured fields of the error.
+    #[prost(map = "string, string", tag = "3")]
+    pub metadata: HashMap<String, String>,
+}
+
+/// Wire-compatible `google.protobuf.Any`.
+#[derive(Clone, PartialEq, Message)]
+struct Any {
+    #[prost(string, tag = "1")]
+    type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    value: Vec<u8>,
+}
+
+/// Wire-compatible `google.rpc.Status`, carried in `grpc-status-details-bin`.
+#[derive(Clone, PartialEq, Message)]
+struct RpcStatus {
+    #[prost(int32, tag = "1")]
+    code: i32,
+    #[prost(string, tag = "2")]
+    message: String,
+    #[prost(message, repeated, tag = "3")]
+    details: Vec<Any>,
+}
+
+/// Returns the gRPC code and `ErrorInfo` reason of a domain error.
+#[must_use]
+pub fn classify(err: &DomainError) -> (Code, &'static str) {
+    use DomainError as E;
+
+    match err {
+        E::InvalidQuantity(_) => (Code::InvalidArgument, "INVALID_QUANTITY"),
+        E::InvalidPrice(_) => (Code::InvalidArgument, "INVALID_PRICE"),
+        E::ValidationError(_) => (Code::InvalidArgument, "VALIDATION_ERROR"),
+        E::QuoteExpired(_) => (Code::FailedPrecondition, "QUOTE_EXPIRED"),
+        E::QuoteNotFound(_) => (Code::NotFound, "QUOTE_NOT_FOUND"),
+        E::InsufficientLiquidity { .. } => (Code::ResourceExhausted, "INSUFFICIENT_LIQUIDITY"),
+        E::MinQuantityNotMet { .. } => (Code::FailedPrecondition, "MIN_QUANTITY_NOT_MET"),
+        E::InvalidMinQuantity(_) => (Code::InvalidArgument, "INVALID_MIN_QUANTITY"),
+        E::NoEligibleVenues(_) => (Code::FailedPrecondition, "NO_ELIGIBLE_VENUES"),
+        E::AllocationMismatch { .. } => (Code::InvalidArgument, "ALLOCATION_MISMATCH"),
+        E::NoReferencePrice => (Code::Unavailable, "NO_REFERENCE_PRICE"),
+        E::DivisionByZero => (Code::InvalidArgument, "DIVISION_BY_ZERO"),
+        E::PriceOutOfBounds { .. } => (Code::InvalidArgument, "PRICE_OUT_OF_BOUNDS"),
+        E::InvalidLotSize { .. } => (Code::InvalidArgument, "INVALID_LOT_SIZE"),
+        E::InvalidTickSize { .. } => (Code::InvalidArgument, "INVALID_TICK_SIZE"),
+        E::InvalidStateTransition { .. } | E::GenericStateTransitionError { .. } => {
+            (Code::FailedPrecondition, "INVALID_STATE_TRANSITION")
+        }
+        E::InvalidState(_) => (Code::FailedPrecondition, "INVALID_STATE"),
+        E::OperationNotAllowed(_) => (Code::FailedPrecondition, "OPERATION_NOT_ALLOWED"),
+        E::InvalidTradeStateForExecution { .. } => (
+            Code::FailedPrecondition,
+            "INVALID_TRADE_STATE_FOR_EXECUTION",
+        ),
+        E::QuoteLocked(_) => (Code::Aborted, "QUOTE_LOCKED"),
+        E::LockAcquisitionFailed(_) => (Code::Aborted, "LOCK_ACQUISITION_FAILED"),
+        E::ConflictDetected(_) => (Code::Aborted, "CONFLICT_DETECTED"),
+        E::ExecutionInProgress(_) => (Code::Aborted, "EXECUTION_IN_PROGRESS"),
+        E::RiskCheckFailed(_) => (Code::FailedPrecondition, "RISK_CHECK_FAILED"),
+        E::UnauthorizedCounterparty(_) => (Code::PermissionDenied, "UNAUTHORIZED_COUNTERPARTY"),
+        E::ValidationFailed(_) => (Code::InvalidArgument, "VALIDATION_FAILED"),
+        E::InvalidNegotiationStateTransition { .. } => (
+            Code::FailedPrecondition,
+            "INVALID_NEGOTIATION_STATE_TRANSITION",
+        ),
+        E::MaxNegotiationRoundsReached { .. } => {
+            (Code::FailedPrecondition, "MAX_NEGOTIATION_ROUNDS_REACHED")
+        }
+        E::NoPriceImprovement { .. } => (Code::InvalidArgument, "NO_PRICE_IMPROVEMENT"),
+        E::LastLookRejected(_) => (Code::Aborted, "LAST_LOOK_REJECTED"),
+        E::LastLookTimeout(_) => (Code::DeadlineExceeded, "LAST_LOOK_TIMEOUT"),
+        E::AcceptanceTimeout(_) => (Code::DeadlineExceeded, "ACCEPTANCE_TIMEOUT"),
+        E::FirmUpPriceMoved { .. } => (Code::Aborted, "FIRM_UP_PRICE_MOVED"),
+        E::QuorumNotMet(_) => (Code::FailedPrecondition, "QUORUM_NOT_MET"),
+        E::CollateralLockFailed(_) => (Code::Unavailable, "COLLATERAL_LOCK_FAILED"),
+        E::InsufficientCollateral { .. } => (Code::FailedPrecondition, "INSUFFICIENT_COLLATERAL"),
+        E::CollateralUnavailable(_) => (Code::Unavailable, "COLLATERAL_UNAVAILABLE"),
+        E::SettlementFailed(_) => (Code::Internal, "SETTLEMENT_FAILED"),
+        E::PositionUpdateFailed(_) => (Code::Internal, "POSITION_UPDATE_FAILED"),
+        E::PriceBoundsVerificationFailed(_) => {
+            (Code::FailedPrecondition, "PRICE_BOUNDS_VERIFICATION_FAILED")
+        }
+        E::InvalidPackageQuote(_) => (Code::InvalidArgument, "INVALID_PACKAGE_QUOTE"),
+        E::InconsistentLegPrices { .. } => (Code::InvalidArgument, "INCONSISTENT_LEG_PRICES"),
+        E::MultiLegExecutionFailed { .. } => (Code::Aborted, "MULTI_LEG_EXECUTION_FAILED"),
+        E::RollbackFailed { .. } => (Code::Internal, "ROLLBACK_FAILED"),
+        E::LegExecutionTimeout { .. } => (Code::DeadlineExceeded, "LEG_EXECUTION_TIMEOUT"),
+        E::CapacityExceeded { .. } => (Code::ResourceExhausted, "CAPACITY_EXCEEDED"),
+        E::ReservationNotFound { .. } => (Code::NotFound, "RESERVATION_NOT_FOUND"),
+        E::CapacityRepositoryError { .. } => (Code::Internal, "CAPACITY_REPOSITORY_ERROR"),
+        E::CapacityOverflow { .. } => (Code::Internal, "CAPACITY_OVERFLOW"),
+        E::CapacityUnderflow { .. } => (Code::Internal, "CAPACITY_UNDERFLOW"),
+        E::FeeCalculationFailed { .. } => (Code::Internal, "FEE_CALCULATION_FAILED"),
+        E::ConfirmationFailed { .. } => (Code::Unavailable, "CONFIRMATION_FAILED"),
+        E::InvalidNotificationPreferences { .. } => {
+            (Code::InvalidArgument, "INVALID_NOTIFICATION_PREFERENCES")
+        }
+        E::SchemaNotFound { .. } => (Code::NotFound, "SCHEMA_NOT_FOUND"),
+        E::SchemaAlreadyRegistered { .. } => (Code::AlreadyExists, "SCHEMA_ALREADY_REGISTERED"),
+        E::SchemaGenerationFailed { .. } => (Code::Internal, "SCHEMA_GENERATION_FAILED"),
+    }
+}
+
+/// Returns the structured fields of a domain error.
+///
+/// Variants carrying only a message have no metadata; the message is the
+/// status message.
+#[must_use]
+pub fn metadata(err: &DomainError) -> HashMap<String, String> {
+    use DomainError as E;
+
+    let fields: Vec<(&str, String)> = match err {
+        E::InsufficientLiquidity {
+            requested,
+            available,
+        } => vec![
+            ("requested", requested.to_string()),
+            ("available", available.to_string()),
+        ],
+        E::MinQuantityNotMet { filled, minimum } => vec![
+            ("filled", filled.to_string()),
+            ("minimum", minimum.to_string()),
+        ],
+        E::AllocationMismatch { allocated, target } => vec![
+            ("allocated", allocated.to_string()),
+            ("target", target.to_string()),
+        ],
+        E::PriceOutOfBounds {
+            proposed,
+            reference,
+            deviation_pct,
+            max_tolerance_pct,
+        } => vec![
+            ("proposed", proposed.to_string()),
+            ("reference", reference.to_string()),
+            ("deviation_pct", deviation_pct.to_string()),
+            ("max_tolerance_pct", max_tolerance_pct.to_string()),
+        ],
+        E::InvalidLotSize {
+            quantity,
+            lot_size,
+            nearest_valid,
+        } => vec![
+            ("quantity", quantity.to_string()),
+            ("lot_size", lot_size.to_string()),
+            ("nearest_valid", nearest_valid.to_string()),
+        ],
+        E::InvalidTickSize {
+            price,
+            tick_size,
+            nearest_valid,
+        } => vec![
+            ("price", price.to_string()),
+            ("tick_size", tick_size.to_string()),
+            ("nearest_valid", nearest_valid.to_string()),
+        ],
+        E::InvalidStateTransition { from, to } => {
+            vec![("from", from.to_string()), ("to", to.to_string())]
+        }
+        E::GenericStateTransitionError { from, to } => {
+            vec![("from", from.clone()), ("to", to.clone())]
+        }
+        E::InvalidTradeStateForExecution { expected, actual } => {
+            vec![("expected", expected.clone()), ("actual", actual.clone())]
+        }
+        E::InvalidNegotiationStateTransition { from, to } => {
+            vec![("from", from.to_string()), ("to", to.to_string())]
+        }
+        E::MaxNegotiationRoundsReached { max_rounds } => {
+            vec![("max_rounds", max_rounds.to_string())]
+        }
+        E::NoPriceImprovement { previous, proposed } => vec![
+            ("previous", previous.to_string()),
+            ("proposed", proposed.to_string()),
+        ],
+        E::FirmUpPriceMoved {
+            indicative,
+            firm,
+            deviation_pct,
+            max_tolerance_pct,
+        } => vec![
+            ("indicative", indicative.to_string()),
+            ("firm", firm.to_string()),
+            ("deviation_pct", deviation_pct.to_string()),
+            ("max_tolerance_pct", max_tolerance_pct.to_string()),
+        ],
+        E::QuorumNotMet(shortfall) => vec![
+            ("notional", shortfall.notional.to_string()),
+            ("min_quotes", shortfall.required.min_quotes.to_string()),
+            ("min_venues", shortfall.required.min_venues.to_string()),
+            ("quotes", shortfall.quotes.to_string()),
+            ("venues", shortfall.venues.to_string()),
+        ],
+        E::InsufficientCollateral {
+            required,
+            available,
+        } => vec![
+            ("required", required.to_string()),
+            ("available", available.to_string()),
+        ],
+        E::InconsistentLegPrices { leg_index, reason } => vec![
+            ("leg_index", leg_index.to_string()),
+            ("reason", reason.clone()),
+        ],
+        E::MultiLegExecutionFailed {
+            failed_leg_index,
+            failed_leg_instrument,
+            reason,
+            rolled_back_count,
+        } => vec![
+            ("failed_leg_index", failed_leg_index.to_string()),
+            ("failed_leg_instrument", failed_leg_instrument.clone()),
+            ("reason", reason.clone()),
+            ("rolled_back_count", rolled_back_count.to_string()),
+        ],
+        E::RollbackFailed {
+            original_failure,
+            rollback_failure,
+            partially_rolled_back,
+        } => vec![
+            ("original_failure", original_failure.clone()),
+            ("rollback_failure", rollback_failure.clone()),
+            ("partially_rolled_back", partially_rolled_back.to_string()),
+        ],
+        E::LegExecutionTimeout {
+            leg_index,
+            instrument,
+            timeout_ms,
+        } => vec![
+            ("leg_index", leg_index.to_string()),
+            ("instrument", instrument.clone()),
+            ("timeout_ms", timeout_ms.to_string()),
+        ],
+        E::CapacityExceeded { mm_id, reason } => {
+            vec![("mm_id", mm_id.clone()), ("reason", reason.clone())]
+        }
+        E::ReservationNotFound { mm_id, rfq_id } => {
+            vec![("mm_id", mm_id.clone()), ("rfq_id", rfq_id.clone())]
+        }
+        E::CapacityOverflow { field } | E::CapacityUnderflow { field } => {
+            vec![("field", field.clone())]
+        }
+        E::ConfirmationFailed { channel, reason } => {
+            vec![("channel", channel.clone()), ("reason", reason.clone())]
+        }
+        E::SchemaNotFound {
+            event_type,
+            version,
+        }
+        | E::SchemaAlreadyRegistered {
+            event_type,
+            version,
+        } => vec![
+            ("event_type", event_type.clone()),
+            ("version", version.clone()),
+        ],
+        _ => Vec::new(),
+    };
+
+    fields
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect()
+}
+
+/// Converts a domain error to a status with an `ErrorInfo` details payload.
+#[must_use]
+pub fn domain_status(err: &DomainError) -> Status {
+    let (code, reason) = classify(err);
+    let message = err.to_string();
+    let info = ErrorInfo {
+        reason: reason.to_string(),
+        domain: ERROR_DOMAIN.to_string(),
+        metadata: metadata(err),
+    };
+    let details = RpcStatus {
+        code: code as i32,
+        message: message.clone(),
+        details: vec![Any {
+            type_url: ERROR_INFO_TYPE_URL.to_string(),
+            value: info.encode_to_vec(),
+        }],
+    };
+    Status::with_details(code, message, Bytes::from(details.encode_to_vec()))
+}
+
+/// Extracts the `ErrorInfo` details payload of a status, if it has one.
+#[must_use]
+pub fn error_info(status: &Status) -> Option<ErrorInfo> {
+    let details = RpcStatus::decode(status.details()).ok()?;
+    details
+        .details
+        .into_iter()
+        .find(|any| any.type_url == ERROR_INFO_TYPE_URL)
+        .and_then(|any| ErrorInfo::decode(any.value.as_slice()).ok())
+}
+
+/// Converts a DomainError to a gRPC Status.
+impl From<DomainError> for Status {
+    fn from(err: DomainError) -> Self {
+        domain_status(&err)
+    }
+}
+
+/// Converts an ApplicationError to a gRPC Status.
+impl From<ApplicationError> for Status {
+    fn from(err: ApplicationError) -> Self {
+        match &err {
+            ApplicationError::Domain(domain) => domain_status(domain),
+            ApplicationError::Validation(_) | ApplicationError::SettlementTokenUnavailable(_) => {
+                Status::invalid_argument(err.to_string())
+            }
+            ApplicationError::NotFound { .. }
+            | ApplicationError::ClientNotFound(_)
+            | ApplicationError::RfqNotFound(_)
+            | ApplicationError::QuoteNotFound(_) => Status::not_found(err.to_string()),
+            ApplicationError::Unauthorized => Status::unauthenticated(err.to_string()),
+            ApplicationError::QuoteExpired(_) | ApplicationError::InvalidState(_) => {
+                Status::failed_precondition(err.to_string())
+            }
+            ApplicationError::ComplianceFailed(_) => Status::permission_denied(err.to_string()),
+            ApplicationError::ShuttingDown => Status::unavailable(err.to_string()),
+            _ => Status::internal(err.to_string()),
+        }
+    }
+}
+
+/// Converts a ConversionError to a gRPC Status.
+impl From<ConversionError> for Status {
+    fn from(err: ConversionError) -> Self {
+        Status::invalid_argument(err.to_string())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{
+        NegotiationState, Price, Quantity, QuorumRequirement, QuorumShortfall, RfqState,
+    };
+    use rust_decimal::Decimal;
+    use std::collections::HashSet;
+
+    fn qty(value: f64) -> Quantity {
+        Quantity::new(value).unwrap()
+    }
+
+    fn price(value: f64) -> Price {
+        Price::new(value).unwrap()
+    }
+
+    /// One instance of every `DomainError` variant.
+    fn every_variant() -> Vec<DomainError> {
+        let text = || "detail".to_string();
+        vec![
+            DomainError::InvalidQuantity(text()),
+            DomainError::InvalidPrice(text()),
+            DomainError::ValidationError(text()),
+            DomainError::QuoteExpired(text()),
+            DomainError::QuoteNotFound(text()),
+            DomainError::InsufficientLiquidity {
+                requested: qty(2.0),
+                available: qty(1.0),
+            },
+            DomainError::MinQuantityNotMet {
+                filled: qty(1.0),
+                minimum: qty(2.0),
+            },
+            DomainError::InvalidMinQuantity(text()),
+            DomainError::NoEligibleVenues(text()),
+            DomainError::AllocationMismatch {
+                allocated: qty(1.0),
+                target: qty(2.0),
+            },
+            DomainError::NoReferencePrice,
+            DomainError::DivisionByZero,
+            DomainError::PriceOutOfBounds {
+                proposed: price(110.0),
+                reference: price(100.0),
+                deviation_pct: Decimal::TEN,
+                max_tolerance_pct: Decimal::ONE,
+            },
+            DomainError::InvalidLotSize {
+                quantity: qty(1.5),
+                lot_size: Decimal::ONE,
+                nearest_valid: qty(2.0),
+            },
+            DomainError::InvalidTickSize {
+                price: price(100.5),
+                tick_size: Decimal::ONE,
+                nearest_valid: price(101.0),
+            },
+            DomainError::InvalidStateTransition {
+                from: RfqState::Cancelled,
+                to: RfqState::Cancelled,
+            },
+            DomainError::GenericStateTransitionError {
+                from: "a".to_string(),
+                to: "b".to_string(),
+            },
+            DomainError::InvalidState(text()),
+            DomainError::OperationNotAllowed(text()),
+            DomainError::InvalidTradeStateForExecution {
+                expected: "a".to_string(),
+                actual: "b".to_string(),
+            },
+            DomainError::QuoteLocked(text()),
+            DomainError::LockAcquisitionFailed(text()),
+            DomainError::ConflictDetected(text()),
+            DomainError::ExecutionInProgress(text()),
+            DomainError::RiskCheckFailed(text()),
+            DomainError::UnauthorizedCounterparty(text()),
+            DomainError::ValidationFailed(text()),
+            DomainError::InvalidNegotiationStateTransition {
+                from: NegotiationState::Open,
+                to: NegotiationState::Open,
+            },
+            DomainError::MaxNegotiationRoundsReached { max_rounds: 3 },
+            DomainError::NoPriceImprovement {
+                previous: price(100.0),
+                proposed: price(101.0),
+            },
+            DomainError::LastLookRejected(text()),
+            DomainError::LastLookTimeout(text()),
+            DomainError::AcceptanceTimeout(text()),
+            DomainError::FirmUpPriceMoved {
+                indicative: price(100.0),
+                firm: price(105.0),
+                deviation_pct: Decimal::new(5, 0),
+                max_tolerance_pct: Decimal::ONE,
+            },
+            DomainError::QuorumNotMet(QuorumShortfall {
+                notional: Decimal::ONE_THOUSAND,
+                required: QuorumRequirement {
+                    min_quotes: 3,
+                    min_venues: 2,
+                },
+                quotes: 1,
+                venues: 1,
+            }),
+            DomainError::CollateralLockFailed(text()),
+            DomainError::InsufficientCollateral {
+                required: Decimal::TEN,
+                available: Decimal::ONE,
+            },
+            DomainError::CollateralUnavailable(text()),
+            DomainError::SettlementFailed(text()),
+            DomainError::PositionUpdateFailed(text()),
+            DomainError::PriceBoundsVerificationFailed(text()),
+            DomainError::InvalidPackageQuote(text()),
+            DomainError::InconsistentLegPrices {
+                leg_index: 1,
+                reason: text(),
+            },
+            DomainError::MultiLegExecutionFailed {
+                failed_leg_index: 1,
+                failed_leg_instrument: "BTC/USD".to_string(),
+                reason: text(),
+                rolled_back_count: 1,
+            },
+            DomainError::RollbackFailed {
+                original_failure: text(),
+                rollback_failure: text(),
+                partially_rolled_back: 1,
+            },
+            DomainError::LegExecutionTimeout {
+                leg_index: 1,
+                instrument: "BTC/USD".to_string(),
+                timeout_ms: 500,
+            },
+            DomainError::CapacityExceeded {
+                mm_id: "mm-1".to_string(),
+                reason: text(),
+            },
+            DomainError::ReservationNotFound {
+                mm_id: "mm-1".to_string(),
+                rfq_id: "rfq-1".to_string(),
+            },
+            DomainError::CapacityRepositoryError { message: text() },
+            DomainError::CapacityOverflow { field: text() },
+            DomainError::CapacityUnderflow { field: text() },
+            DomainError::FeeCalculationFailed { reason: text() },
+            DomainError::ConfirmationFailed {
+                channel: "email".to_string(),
+                reason: text(),
+            },
+            DomainError::InvalidNotificationPreferences { reason: text() },
+            DomainError::SchemaNotFound {
+                event_type: "RfqCreated".to_string(),
+                version: "1".to_string(),
+            },
+            DomainError::SchemaAlreadyRegistered {
+                event_type: "RfqCreated".to_string(),
+                version: "1".to_string(),
+            },
+            DomainError::SchemaGenerationFailed { reason: text() },
+        ]
+    }
+
+    /// Round-trips a status through its HTTP/2 trailer encoding, as a
+    /// remote client would receive it.
+    fn over_the_wire(status: Status) -> Status {
+        Status::from_header_map(status.into_http::<()>().headers()).unwrap()
+    }
+
+    #[test]
+    fn every_variant_maps_to_an_actionable_code() {
+        let variants = every_variant();
+        let mut reasons = HashSet::new();
+
+        for err in &variants {
+            let (code, reason) = classify(err);
+            assert!(
+                !matches!(code, Code::Ok | Code::Unknown),
+                "{err:?} maps to {code:?}"
+            );
+            assert!(!reason.is_empty());
+            reasons.insert(reason);
+        }
+
+        // The two state-transition variants share a reason.
+        assert_eq!(reasons.len(), variants.len() - 1);
+    }
+
+    #[test]
+    fn clients_can_decode_the_error_info() {
+        for err in every_variant() {
+            let (code, reason) = classify(&err);
+
+            let status = over_the_wire(domain_status(&err));
+
+            assert_eq!(status.code(), code);
+            assert_eq!(status.message(), err.to_string());
+            let info = error_info(&status).unwrap();
+            assert_eq!(info.reason, reason);
+            assert_eq!(info.domain, ERROR_DOMAIN);
+            assert_eq!(info.metadata, metadata(&err));
+        }
+    }
+
+    #[cfg(feature = "grpc-client")]
+    #[test]
+    fn client_knows_every_error_code() {
+        use crate::client::ErrorCode;
+
+        for err in every_variant() {
+            let (_, reason) = classify(&err);
+            let code = ErrorCode::from_reason(reason);
+            assert!(
+                !matches!(code, ErrorCode::Unknown(_)),
+                "{reason} is unknown"
+            );
+            assert_eq!(code.as_str(), reason);
+        }
+    }
+
+    #[test]
+    fn state_transition_carries_from_and_to() {
+        let status: Status = DomainError::InvalidStateTransition {
+            from: RfqState::Cancelled,
+            to: RfqState::Executing,
+        }
+        .into();
+
+        let info = error_info(&over_the_wire(status.clone())).unwrap();
+
+        assert_eq!(status.code(), Code::FailedPrecondition);
+        assert_eq!(info.reason, "INVALID_STATE_TRANSITION");
+        assert_eq!(info.metadata["from"], RfqState::Cancelled.to_string());
+        assert_eq!(info.metadata["to"], RfqState::Executing.to_string());
+    }
+
+    #[test]
+    fn insufficient_liquidity_carries_quantities() {
+        let status: Status = DomainError::InsufficientLiquidity {
+            requested: qty(5.0),
+            available: qty(2.0),
+        }
+        .into();
+
+        let info = error_info(&status).unwrap();
+
+        assert_eq!(status.code(), Code::ResourceExhausted);
+        assert_eq!(info.metadata["requested"], qty(5.0).to_string());
+        assert_eq!(info.metadata["available"], qty(2.0).to_string());
+    }
+
+    #[test]
+    fn application_domain_errors_keep_their_details() {
+        let status: Status =
+            ApplicationError::Domain(DomainError::QuoteExpired("q-1".into())).into();
+
+        assert_eq!(status.code(), Code::FailedPrecondition);
+        assert_eq!(error_info(&status).unwrap().reason, "QUOTE_EXPIRED");
+    }
+
+    #[test]
+    fn statuses_without_details_have_no_error_info() {
+        assert!(error_info(&Status::internal("boom")).is_none());
+    }
+
+    #[test]
+    fn application_error_to_status_validation() {
+        let err = ApplicationError::validation("invalid input");
+        let status: Status = err.into();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[test]
+    fn application_error_to_status_not_found() {
+        let err = ApplicationError::not_found("RFQ", "123");
+        let status: Status = err.into();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+    }
+
+    #[test]
+    fn application_error_to_status_unauthorized() {
+        let err = ApplicationError::unauthorized();
+        let status: Status = err.into();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[test]
+    fn conversion_error_to_status() {
+        let err = ConversionError::MissingField("test");
+        let status: Status = err.into();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+}
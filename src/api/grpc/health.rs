@@ -0,0 +1,184 @@
+//! # gRPC Health and Reflection
+//!
+//! Standard gRPC health protocol and server reflection for the tonic server.
+//!
+//! [`GrpcHealthMonitor`] runs the same [`ReadinessChecker`] as the REST
+//! readiness probe and reports the result through `grpc.health.v1.Health`:
+//! both `RfqService` versions and the overall server (empty service name)
+//! are `SERVING`
+//! while the critical dependencies are up and `NOT_SERVING` once one fails.
+//!
+//! [`reflection_service`] serves the compiled descriptor set so tools such
+//! as `grpcurl` can discover the API.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::api::grpc::health::GrpcHealthMonitor;
+//! use otc_rfq::application::services::ReadinessChecker;
+//! use std::sync::Arc;
+//!
+//! # tokio_test::block_on(async {
+//! let (reporter, _service) = tonic_health::server::health_reporter();
+//! let monitor = GrpcHealthMonitor::new(Arc::new(ReadinessChecker::new()), reporter);
+//! assert!(monitor.refresh().await.is_ready());
+//! # });
+//! ```
+
+use crate::api::grpc::proto::FILE_DESCRIPTOR_SET;
+use crate::api::grpc::proto::otc_rfq_v2::rfq_service_server::RfqServiceServer as RfqServiceV2Server;
+use crate::api::grpc::proto::rfq_service_server::RfqServiceServer;
+use crate::api::grpc::{RfqServiceImpl, RfqServiceV2Impl};
+use crate::application::services::{ReadinessChecker, ReadinessReport};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tonic::server::NamedService;
+use tonic_health::ServingStatus;
+use tonic_health::server::HealthReporter;
+use tonic_reflection::server::v1::{ServerReflection, ServerReflectionServer};
+use tracing::warn;
+
+/// Default interval between health refreshes.
+pub const DEFAULT_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Name the v1 RFQ service is reported under in the health protocol.
+pub const RFQ_SERVICE_NAME: &str = <RfqServiceServer<RfqServiceImpl> as NamedService>::NAME;
+
+/// Name the v2 RFQ service is reported under in the health protocol.
+pub const RFQ_SERVICE_V2_NAME: &str = <RfqServiceV2Server<RfqServiceV2Impl> as NamedService>::NAME;
+
+/// Builds the gRPC reflection service from the compiled descriptor set.
+///
+/// # Errors
+///
+/// Returns an error if the descriptor set cannot be decoded.
+pub fn reflection_service()
+-> Result<ServerReflectionServer<impl ServerReflection>, tonic_reflection::server::Error> {
+    tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+        .build_v1()
+}
+
+/// Keeps gRPC health statuses in line with the readiness checks.
+#[derive(Debug, Clone)]
+pub struct GrpcHealthMonitor {
+    readiness: Arc<ReadinessChecker>,
+    reporter: HealthReporter,
+    interval: Duration,
+}
+
+impl GrpcHealthMonitor {
+    /// Creates a monitor reporting through `reporter`.
+    #[must_use]
+    pub fn new(readiness: Arc<ReadinessChecker>, reporter: HealthReporter) -> Self {
+        Self {
+            readiness,
+            reporter,
+            interval: DEFAULT_HEALTH_CHECK_INTERVAL,
+        }
+    }
+
+    /// Sets the interval between refreshes.
+    #[must_use]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Runs the readiness checks once and publishes the resulting statuses.
+    pub async fn refresh(&self) -> ReadinessReport {
+        let report = self.readiness.check().await;
+        let status = if report.is_ready() {
+            ServingStatus::Serving
+        } else {
+            warn!(status = ?report.status, "gRPC health check failed");
+            ServingStatus::NotServing
+        };
+        for service_name in [RFQ_SERVICE_NAME, RFQ_SERVICE_V2_NAME, ""] {
+            self.reporter.set_service_status(service_name, status).await;
+        }
+        report
+    }
+
+    /// Refreshes the statuses every interval until `shutdown` fires.
+    pub async fn run(self, mut shutdown: watch::Receiver<bool>) {
+        let mut ticker = tokio::time::interval(self.interval);
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    self.refresh().await;
+                }
+                _ = shutdown.changed() => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::application::services::DependencyCheck;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use tonic_health::pb::HealthCheckRequest;
+    use tonic_health::pb::health_check_response::ServingStatus as ProtoStatus;
+    use tonic_health::pb::health_server::Health;
+    use tonic_health::server::HealthService;
+
+    #[derive(Debug, Default)]
+    struct Toggle(AtomicBool);
+
+    #[async_trait]
+    impl DependencyCheck for Toggle {
+        fn name(&self) -> &str {
+            "database"
+        }
+
+        async fn check(&self) -> Result<(), String> {
+            if self.0.load(Ordering::SeqCst) {
+                Err("connection refused".to_string())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn rfq_service_name_is_fully_qualified() {
+        assert_eq!(RFQ_SERVICE_NAME, "otc.rfq.v1.RfqService");
+        assert_eq!(RFQ_SERVICE_V2_NAME, "otc.rfq.v2.RfqService");
+    }
+
+    #[tokio::test]
+    async fn refresh_follows_dependency_status() {
+        let toggle = Arc::new(Toggle::default());
+        let readiness = ReadinessChecker::new().with_dependency(toggle.clone());
+        let reporter = HealthReporter::new();
+        let service = HealthService::from_health_reporter(reporter.clone());
+        let monitor = GrpcHealthMonitor::new(Arc::new(readiness), reporter);
+        let status = |service_name: &str| {
+            let request = tonic::Request::new(HealthCheckRequest {
+                service: service_name.to_string(),
+            });
+            let service = &service;
+            async move { service.check(request).await.unwrap().into_inner().status() }
+        };
+
+        monitor.refresh().await;
+        assert_eq!(status(RFQ_SERVICE_NAME).await, ProtoStatus::Serving);
+
+        toggle.0.store(true, Ordering::SeqCst);
+        assert!(!monitor.refresh().await.is_ready());
+        assert_eq!(status(RFQ_SERVICE_NAME).await, ProtoStatus::NotServing);
+        assert_eq!(status(RFQ_SERVICE_V2_NAME).await, ProtoStatus::NotServing);
+        assert_eq!(status("").await, ProtoStatus::NotServing);
+    }
+
+    #[test]
+    fn reflection_service_builds() {
+        assert!(reflection_service().is_ok());
+    }
+}
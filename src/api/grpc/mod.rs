@@ -5,8 +5,13 @@
 //! # Modules
 //!
 //! - [`proto`]: Generated protobuf types and gRPC service definitions
-//! - [`conversions`]: Conversions between domain types and protobuf messages
-//! - [`service`]: gRPC service implementation
+//! - [`conversions`]: Conversions between domain types and v1 protobuf messages
+//! - [`conversions_v2`]: Conversions between domain types and v2 protobuf messages
+//! - [`versioning`]: Up/down-conversion shims between the v1 and v2 APIs
+//! - [`error_mapping`]: Domain and application errors to gRPC statuses with error details
+//! - [`service`]: v1 gRPC service, served through the v2 implementation
+//! - [`service_v2`]: v2 gRPC service implementation
+//! - [`health`]: gRPC health protocol and server reflection
 //!
 //! # Usage
 //!
@@ -22,9 +27,16 @@
 //! ```
 
 pub mod conversions;
+pub mod conversions_v2;
+pub mod error_mapping;
+pub mod health;
 pub mod proto;
 pub mod service;
+pub mod service_v2;
+pub mod versioning;
 
 pub use conversions::ConversionError;
-pub use proto::otc_rfq_v1;
+pub use health::{GrpcHealthMonitor, reflection_service};
+pub use proto::{otc_rfq_v1, otc_rfq_v2};
 pub use service::RfqServiceImpl;
+pub use service_v2::RfqServiceV2Impl;
@@ -1,7 +1,16 @@
 //! # Generated Protobuf Types
 //!
 //! This module exposes the generated Protocol Buffer types and gRPC service
-//! definitions compiled from `proto/otc/rfq/v1/*.proto`.
+//! definitions compiled from `proto/otc/rfq/v1/*.proto` and
+//! `proto/otc/rfq/v2/*.proto`.
+//!
+//! # Versions
+//!
+//! [`otc_rfq_v2`] is the canonical API. [`otc_rfq_v1`] stays available for
+//! existing clients and is re-exported at module level; its deprecated
+//! fields (`Rfq.side`, `Rfq.two_way`, `CreateRfqRequest.side`,
+//! `CreateRfqRequest.two_way`, `Quote.venue_type`) are marked with
+//! `[deprecated = true]` and carry `#[deprecated]` in the generated code.
 //!
 //! # Structure
 //!
@@ -34,9 +43,27 @@ pub mod otc_rfq_v1 {
     tonic::include_proto!("otc.rfq.v1");
 }
 
+/// Generated protobuf types for OTC RFQ v2 API.
+///
+/// Generated from the proto files in `proto/otc/rfq/v2/`. v2 replaces
+/// `side`/`two_way` with `RfqDirection` and adds quote firmness, scheduled
+/// activation, failure reasons and negotiation references.
+pub mod otc_rfq_v2 {
+    #![allow(missing_docs)]
+    #![allow(clippy::all)]
+    #![allow(clippy::pedantic)]
+    #![allow(clippy::nursery)]
+    #![allow(clippy::clone_on_ref_ptr)]
+    tonic::include_proto!("otc.rfq.v2");
+}
+
 // Re-export commonly used types at module level for convenience
 pub use otc_rfq_v1::*;
 
+/// Encoded descriptor set of the compiled proto files, served by the
+/// reflection service.
+pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("otc_rfq_descriptor");
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,6 +97,7 @@ mod tests {
         assert_eq!(VenueType::DexAggregator as i32, 3);
         assert_eq!(VenueType::Protocol as i32, 4);
         assert_eq!(VenueType::RfqProtocol as i32, 5);
+        assert_eq!(VenueType::Simulated as i32, 6);
     }
 
     #[test]
@@ -136,6 +164,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn create_rfq_request_creation() {
         let request = CreateRfqRequest {
             client_id: "client-123".to_string(),
@@ -150,6 +179,9 @@ mod tests {
                 value: "10.5".to_string(),
             }),
             timeout_seconds: 300,
+            size_mode: None,
+            quantity_disclosure: None,
+            two_way: false,
         };
         assert_eq!(request.client_id, "client-123");
         assert!(request.instrument.is_some());
@@ -157,6 +189,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn quote_message_creation() {
         let quote = Quote {
             id: Some(Uuid {
@@ -178,6 +211,7 @@ mod tests {
             }),
             valid_until: None,
             created_at: None,
+            side: OrderSide::Unspecified as i32,
         };
         assert_eq!(quote.venue_id, "venue-1");
         assert!(quote.price.is_some());
@@ -204,11 +238,16 @@ mod tests {
             }),
             venue_execution_ref: "exec-ref-123".to_string(),
             created_at: None,
+            fees: vec![],
+            reference_price_at_execution: None,
+            allocations: vec![],
+            allocation_vwap: None,
         };
         assert_eq!(trade.venue_execution_ref, "exec-ref-123");
     }
 
     #[test]
+    #[allow(deprecated)]
     fn rfq_message_creation() {
         let rfq = Rfq {
             id: Some(Uuid {
@@ -231,6 +270,9 @@ mod tests {
             selected_quote_id: None,
             created_at: None,
             updated_at: None,
+            size_mode: None,
+            quantity_disclosure: None,
+            two_way: false,
         };
         assert_eq!(rfq.client_id, "client-123");
         assert_eq!(rfq.state, RfqState::Created as i32);
@@ -1,15 +1,20 @@
 //! # gRPC RFQ Service
 //!
-//! gRPC service implementation using tonic.
+//! gRPC service implementation for the `otc.rfq.v1` API.
 //!
 //! This module provides the [`RfqServiceImpl`] which implements the gRPC
-//! `RfqService` trait generated from the protobuf definitions.
+//! `RfqService` trait generated from the v1 protobuf definitions. It is a
+//! compatibility layer over [`RfqServiceV2Impl`]: each v1 request is
+//! up-converted to v2, handled by the v2 service and the response is
+//! down-converted to v1 (see [`versioning`](crate::api::grpc::versioning)).
 //!
 //! # Features
 //!
 //! - Request validation and error mapping
 //! - DTO conversion between proto and domain types
 //! - Streaming support for `GetQuotes`
+//! - Direct quote submission by market makers (`SubmitQuote`)
+//! - RFQ broadcasts streamed to market makers (`SubscribeRfqs`)
 //! - Tracing integration for request logging
 //! - Proper error responses with gRPC status codes
 //!
@@ -26,458 +31,175 @@
 //!     .await?;
 //! ```
 
-use crate::api::grpc::conversions::ConversionError;
+use crate::api::grpc::proto::otc_rfq_v2::rfq_service_server::RfqService as RfqServiceV2;
 use crate::api::grpc::proto::{
     self, CancelRfqRequest, CancelRfqResponse, CreateRfqRequest, CreateRfqResponse,
     ExecuteTradeRequest, ExecuteTradeResponse, GetQuotesRequest, GetQuotesResponse, GetRfqRequest,
-    GetRfqResponse, StreamRfqStatusRequest, StreamRfqStatusResponse,
-    rfq_service_server::RfqService,
+    GetRfqResponse, StreamRfqStatusRequest, StreamRfqStatusResponse, SubmitQuoteRequest,
+    SubmitQuoteResponse, SubscribeRfqsRequest, rfq_service_server::RfqService,
+};
+use crate::api::grpc::service_v2::RfqServiceV2Impl;
+use crate::application::services::{
+    RfqCancellationService, RfqSubscriptionHub, ShutdownCoordinator,
 };
-use crate::application::error::ApplicationError;
 use crate::application::use_cases::create_rfq::RfqRepository;
-use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{CounterpartyId, Instrument, OrderSide, Quantity, RfqId};
+use crate::application::use_cases::submit_quote::SubmitQuoteUseCase;
 use std::pin::Pin;
 use std::sync::Arc;
-use tokio::sync::mpsc;
-use tokio_stream::{Stream, wrappers::ReceiverStream};
+use tokio_stream::{Stream, StreamExt};
 use tonic::{Request, Response, Status};
-use tracing::{error, info, instrument, warn};
 
 /// gRPC RFQ Service implementation.
 ///
-/// Implements the `RfqService` trait generated from protobuf definitions,
-/// providing all RPC methods for RFQ lifecycle management.
-#[derive(Debug)]
+/// Implements the v1 `RfqService` trait by delegating to a
+/// [`RfqServiceV2Impl`], providing all RPC methods for RFQ lifecycle
+/// management to clients pinned to v1.
+#[derive(Debug, Clone)]
 pub struct RfqServiceImpl {
-    rfq_repository: Arc<dyn RfqRepository>,
+    inner: RfqServiceV2Impl,
 }
 
 impl RfqServiceImpl {
     /// Creates a new RFQ service with the given repository.
     #[must_use]
     pub fn new(rfq_repository: Arc<dyn RfqRepository>) -> Self {
-        Self { rfq_repository }
+        Self::from(RfqServiceV2Impl::new(rfq_repository))
     }
 
-    /// Validates a CreateRfqRequest and returns domain types.
-    fn validate_create_request(
-        &self,
-        request: &CreateRfqRequest,
-    ) -> Result<(CounterpartyId, Instrument, OrderSide, Quantity, Timestamp), Status> {
-        // Validate client_id
-        if request.client_id.is_empty() {
-            return Err(Status::invalid_argument("client_id cannot be empty"));
-        }
-
-        // Validate and convert instrument
-        let instrument = request
-            .instrument
-            .as_ref()
-            .ok_or_else(|| Status::invalid_argument("instrument is required"))?;
-
-        if instrument.base_asset.is_empty() {
-            return Err(Status::invalid_argument("base_asset cannot be empty"));
-        }
-        if instrument.quote_asset.is_empty() {
-            return Err(Status::invalid_argument("quote_asset cannot be empty"));
-        }
-
-        let symbol_str = format!("{}/{}", instrument.base_asset, instrument.quote_asset);
-        let symbol = crate::domain::value_objects::Symbol::new(&symbol_str)
-            .map_err(|e| Status::invalid_argument(format!("invalid symbol: {e}")))?;
-        let domain_instrument = Instrument::builder(
-            symbol,
-            crate::domain::value_objects::enums::AssetClass::CryptoSpot,
-        )
-        .build();
-
-        // Validate and convert side
-        let side: OrderSide =
-            crate::api::grpc::conversions::proto_order_side_to_domain(request.side)
-                .map_err(|_| Status::invalid_argument("invalid order side"))?;
+    /// Refuses RFQ creation once the coordinator starts draining.
+    #[must_use]
+    pub fn with_shutdown(self, shutdown: ShutdownCoordinator) -> Self {
+        Self::from(self.inner.with_shutdown(shutdown))
+    }
 
-        // Validate and convert quantity
-        let quantity_decimal = request
-            .quantity
-            .as_ref()
-            .ok_or_else(|| Status::invalid_argument("quantity is required"))?;
+    /// Enables `SubmitQuote`.
+    #[must_use]
+    pub fn with_quote_submission(self, use_case: Arc<SubmitQuoteUseCase>) -> Self {
+        Self::from(self.inner.with_quote_submission(use_case))
+    }
 
-        let quantity_value: rust_decimal::Decimal = quantity_decimal
-            .clone()
-            .try_into()
-            .map_err(|e: ConversionError| Status::invalid_argument(e.to_string()))?;
+    /// Enables `SubscribeRfqs`, streaming broadcasts from the hub.
+    #[must_use]
+    pub fn with_rfq_subscriptions(self, hub: Arc<RfqSubscriptionHub>) -> Self {
+        Self::from(self.inner.with_rfq_subscriptions(hub))
+    }
 
-        let quantity = Quantity::try_from(quantity_value)
-            .map_err(|e| Status::invalid_argument(format!("invalid quantity: {e}")))?;
+    /// Sends cancel notices to the venues pricing an RFQ cancelled through
+    /// `CancelRfq`.
+    #[must_use]
+    pub fn with_rfq_cancellations(self, cancellations: Arc<RfqCancellationService>) -> Self {
+        Self::from(self.inner.with_rfq_cancellations(cancellations))
+    }
 
-        // Validate timeout
-        if request.timeout_seconds <= 0 {
-            return Err(Status::invalid_argument("timeout_seconds must be positive"));
-        }
+    /// Sets the `(mm_id, api_key)` credentials accepted from market makers.
+    #[must_use]
+    pub fn with_mm_api_keys(self, mm_api_keys: impl IntoIterator<Item = (String, String)>) -> Self {
+        Self::from(self.inner.with_mm_api_keys(mm_api_keys))
+    }
 
-        let expires_at = Timestamp::now().add_secs(request.timeout_seconds);
+    /// Returns the v2 service handling the requests.
+    #[must_use]
+    pub fn v2(&self) -> &RfqServiceV2Impl {
+        &self.inner
+    }
+}
 
-        Ok((
-            CounterpartyId::new(&request.client_id),
-            domain_instrument,
-            side,
-            quantity,
-            expires_at,
-        ))
+/// Serves v1 from an already configured v2 service.
+impl From<RfqServiceV2Impl> for RfqServiceImpl {
+    fn from(inner: RfqServiceV2Impl) -> Self {
+        Self { inner }
     }
 }
 
 #[tonic::async_trait]
 impl RfqService for RfqServiceImpl {
     /// Creates a new RFQ.
-    #[instrument(skip(self, request), fields(client_id))]
     async fn create_rfq(
         &self,
         request: Request<CreateRfqRequest>,
     ) -> Result<Response<CreateRfqResponse>, Status> {
-        let req = request.into_inner();
-        tracing::Span::current().record("client_id", &req.client_id);
-
-        info!("Creating RFQ for client: {}", req.client_id);
-
-        // Validate request
-        let (client_id, instrument, side, quantity, expires_at) =
-            self.validate_create_request(&req)?;
-
-        // Build RFQ
-        let rfq = crate::domain::entities::rfq::RfqBuilder::new(
-            client_id, instrument, side, quantity, expires_at,
-        )
-        .build();
-
-        // Save to repository
-        self.rfq_repository.save(&rfq).await.map_err(|e| {
-            error!("Failed to save RFQ: {}", e);
-            Status::internal(format!("failed to save RFQ: {e}"))
-        })?;
-
-        info!("Created RFQ: {}", rfq.id());
-
-        // Convert to proto response
-        let response = CreateRfqResponse {
-            rfq: Some(proto::Rfq::from(&rfq)),
-        };
-
-        Ok(Response::new(response))
+        let response = self.inner.create_rfq(request.map(Into::into)).await?;
+        Ok(response.map(Into::into))
     }
 
     /// Gets an RFQ by ID.
-    #[instrument(skip(self, request), fields(rfq_id))]
     async fn get_rfq(
         &self,
         request: Request<GetRfqRequest>,
     ) -> Result<Response<GetRfqResponse>, Status> {
-        let req = request.into_inner();
-
-        let rfq_id: RfqId = req
-            .rfq_id
-            .ok_or_else(|| Status::invalid_argument("rfq_id is required"))?
-            .try_into()
-            .map_err(|e: ConversionError| Status::invalid_argument(e.to_string()))?;
-
-        tracing::Span::current().record("rfq_id", rfq_id.to_string());
-
-        info!("Getting RFQ: {}", rfq_id);
-
-        let rfq = self
-            .rfq_repository
-            .find_by_id(rfq_id)
-            .await
-            .map_err(|e| {
-                error!("Failed to find RFQ: {}", e);
-                Status::internal(format!("failed to find RFQ: {e}"))
-            })?
-            .ok_or_else(|| {
-                warn!("RFQ not found: {}", rfq_id);
-                Status::not_found(format!("RFQ not found: {rfq_id}"))
-            })?;
-
-        let response = GetRfqResponse {
-            rfq: Some(proto::Rfq::from(&rfq)),
-        };
-
-        Ok(Response::new(response))
+        let response = self.inner.get_rfq(request.map(Into::into)).await?;
+        Ok(response.map(Into::into))
     }
 
     type GetQuotesStream = Pin<Box<dyn Stream<Item = Result<GetQuotesResponse, Status>> + Send>>;
 
     /// Streams quotes for an RFQ.
-    #[instrument(skip(self, request), fields(rfq_id))]
     async fn get_quotes(
         &self,
         request: Request<GetQuotesRequest>,
     ) -> Result<Response<Self::GetQuotesStream>, Status> {
-        let req = request.into_inner();
-
-        let rfq_id: RfqId = req
-            .rfq_id
-            .ok_or_else(|| Status::invalid_argument("rfq_id is required"))?
-            .try_into()
-            .map_err(|e: ConversionError| Status::invalid_argument(e.to_string()))?;
-
-        tracing::Span::current().record("rfq_id", rfq_id.to_string());
-
-        info!("Streaming quotes for RFQ: {}", rfq_id);
-
-        // Get the RFQ to access its quotes
-        let rfq = self
-            .rfq_repository
-            .find_by_id(rfq_id)
-            .await
-            .map_err(|e| {
-                error!("Failed to find RFQ: {}", e);
-                Status::internal(format!("failed to find RFQ: {e}"))
-            })?
-            .ok_or_else(|| {
-                warn!("RFQ not found: {}", rfq_id);
-                Status::not_found(format!("RFQ not found: {rfq_id}"))
-            })?;
-
-        // Create a channel for streaming
-        let (tx, rx) = mpsc::channel(32);
-
-        // Spawn a task to send quotes
-        let quotes = rfq.quotes().to_vec();
-        tokio::spawn(async move {
-            for (i, quote) in quotes.iter().enumerate() {
-                let is_final = i == quotes.len() - 1;
-                let response = GetQuotesResponse {
-                    quote: Some(proto::Quote::from(quote)),
-                    is_final,
-                };
-
-                if tx.send(Ok(response)).await.is_err() {
-                    // Receiver dropped
-                    break;
-                }
-            }
-
-            // If no quotes, send a final empty response
-            if quotes.is_empty() {
-                let _ = tx
-                    .send(Ok(GetQuotesResponse {
-                        quote: None,
-                        is_final: true,
-                    }))
-                    .await;
-            }
-        });
-
-        let stream = ReceiverStream::new(rx);
-        Ok(Response::new(Box::pin(stream)))
+        let response = self.inner.get_quotes(request.map(Into::into)).await?;
+        Ok(response.map(|stream| -> Self::GetQuotesStream {
+            Box::pin(stream.map(|item| item.map(Into::into)))
+        }))
     }
 
     /// Executes a trade for a selected quote.
-    #[instrument(skip(self, request), fields(rfq_id, quote_id))]
     async fn execute_trade(
         &self,
         request: Request<ExecuteTradeRequest>,
     ) -> Result<Response<ExecuteTradeResponse>, Status> {
-        let req = request.into_inner();
-
-        let rfq_id: RfqId = req
-            .rfq_id
-            .ok_or_else(|| Status::invalid_argument("rfq_id is required"))?
-            .try_into()
-            .map_err(|e: ConversionError| Status::invalid_argument(e.to_string()))?;
-
-        let quote_id: crate::domain::value_objects::QuoteId = req
-            .quote_id
-            .ok_or_else(|| Status::invalid_argument("quote_id is required"))?
-            .try_into()
-            .map_err(|e: ConversionError| Status::invalid_argument(e.to_string()))?;
-
-        tracing::Span::current().record("rfq_id", rfq_id.to_string());
-        tracing::Span::current().record("quote_id", quote_id.to_string());
-
-        info!("Executing trade for RFQ: {}, Quote: {}", rfq_id, quote_id);
-
-        // Get the RFQ
-        let rfq = self
-            .rfq_repository
-            .find_by_id(rfq_id)
-            .await
-            .map_err(|e| {
-                error!("Failed to find RFQ: {}", e);
-                Status::internal(format!("failed to find RFQ: {e}"))
-            })?
-            .ok_or_else(|| {
-                warn!("RFQ not found: {}", rfq_id);
-                Status::not_found(format!("RFQ not found: {rfq_id}"))
-            })?;
-
-        // Find the quote
-        let quote = rfq
-            .quotes()
-            .iter()
-            .find(|q| q.id() == quote_id)
-            .ok_or_else(|| {
-                warn!("Quote not found: {}", quote_id);
-                Status::not_found(format!("Quote not found: {quote_id}"))
-            })?;
-
-        // Check if quote is expired
-        if quote.is_expired() {
-            return Err(Status::failed_precondition("quote has expired"));
-        }
-
-        // Create a trade from the quote
-        let trade = crate::domain::entities::trade::Trade::new(
-            rfq_id,
-            quote_id,
-            quote.venue_id().clone(),
-            quote.price(),
-            quote.quantity(),
-        );
-
-        info!("Created trade: {}", trade.id());
-
-        let response = ExecuteTradeResponse {
-            trade: Some(proto::Trade::from(&trade)),
-        };
-
-        Ok(Response::new(response))
+        let response = self.inner.execute_trade(request.map(Into::into)).await?;
+        Ok(response.map(Into::into))
     }
 
     /// Cancels an RFQ.
-    #[instrument(skip(self, request), fields(rfq_id))]
     async fn cancel_rfq(
         &self,
         request: Request<CancelRfqRequest>,
     ) -> Result<Response<CancelRfqResponse>, Status> {
-        let req = request.into_inner();
-
-        let rfq_id: RfqId = req
-            .rfq_id
-            .ok_or_else(|| Status::invalid_argument("rfq_id is required"))?
-            .try_into()
-            .map_err(|e: ConversionError| Status::invalid_argument(e.to_string()))?;
-
-        tracing::Span::current().record("rfq_id", rfq_id.to_string());
-
-        info!("Cancelling RFQ: {}, reason: {}", rfq_id, req.reason);
-
-        // Get the RFQ
-        let mut rfq = self
-            .rfq_repository
-            .find_by_id(rfq_id)
-            .await
-            .map_err(|e| {
-                error!("Failed to find RFQ: {}", e);
-                Status::internal(format!("failed to find RFQ: {e}"))
-            })?
-            .ok_or_else(|| {
-                warn!("RFQ not found: {}", rfq_id);
-                Status::not_found(format!("RFQ not found: {rfq_id}"))
-            })?;
-
-        // Cancel the RFQ
-        rfq.cancel().map_err(|e| {
-            warn!("Cannot cancel RFQ: {}", e);
-            Status::failed_precondition(format!("cannot cancel RFQ: {e}"))
-        })?;
-
-        // Save the updated RFQ
-        self.rfq_repository.save(&rfq).await.map_err(|e| {
-            error!("Failed to save cancelled RFQ: {}", e);
-            Status::internal(format!("failed to save RFQ: {e}"))
-        })?;
-
-        info!("Cancelled RFQ: {}", rfq_id);
-
-        let response = CancelRfqResponse {
-            rfq: Some(proto::Rfq::from(&rfq)),
-        };
-
-        Ok(Response::new(response))
+        let response = self.inner.cancel_rfq(request.map(Into::into)).await?;
+        Ok(response.map(Into::into))
     }
 
     type StreamRfqStatusStream =
         Pin<Box<dyn Stream<Item = Result<StreamRfqStatusResponse, Status>> + Send>>;
 
     /// Streams RFQ status updates.
-    #[instrument(skip(self, request), fields(rfq_id))]
     async fn stream_rfq_status(
         &self,
         request: Request<StreamRfqStatusRequest>,
     ) -> Result<Response<Self::StreamRfqStatusStream>, Status> {
-        let req = request.into_inner();
-
-        let rfq_id: RfqId = req
-            .rfq_id
-            .ok_or_else(|| Status::invalid_argument("rfq_id is required"))?
-            .try_into()
-            .map_err(|e: ConversionError| Status::invalid_argument(e.to_string()))?;
-
-        tracing::Span::current().record("rfq_id", rfq_id.to_string());
-
-        info!("Streaming status for RFQ: {}", rfq_id);
-
-        // Get the current RFQ state
-        let rfq = self
-            .rfq_repository
-            .find_by_id(rfq_id)
-            .await
-            .map_err(|e| {
-                error!("Failed to find RFQ: {}", e);
-                Status::internal(format!("failed to find RFQ: {e}"))
-            })?
-            .ok_or_else(|| {
-                warn!("RFQ not found: {}", rfq_id);
-                Status::not_found(format!("RFQ not found: {rfq_id}"))
-            })?;
-
-        // Create a channel for streaming
-        let (tx, rx) = mpsc::channel(32);
-
-        // Send initial state
-        let initial_response = StreamRfqStatusResponse {
-            rfq_id: Some(proto::Uuid::from(rfq_id)),
-            previous_state: proto::RfqState::Unspecified as i32,
-            current_state: i32::from(rfq.state()),
-            message: "Initial state".to_string(),
-            timestamp: Some(proto::Timestamp::from(Timestamp::now())),
-        };
-
-        let _ = tx.send(Ok(initial_response)).await;
-
-        // Note: In a real implementation, this would subscribe to state change events
-        // For now, we just send the initial state and close the stream
-
-        let stream = ReceiverStream::new(rx);
-        Ok(Response::new(Box::pin(stream)))
+        let response = self
+            .inner
+            .stream_rfq_status(request.map(Into::into))
+            .await?;
+        Ok(response.map(|stream| -> Self::StreamRfqStatusStream {
+            Box::pin(stream.map(|item| item.map(Into::into)))
+        }))
     }
-}
 
-/// Converts an ApplicationError to a gRPC Status.
-impl From<ApplicationError> for Status {
-    fn from(err: ApplicationError) -> Self {
-        match &err {
-            ApplicationError::Validation(_) => Status::invalid_argument(err.to_string()),
-            ApplicationError::NotFound { .. }
-            | ApplicationError::ClientNotFound(_)
-            | ApplicationError::RfqNotFound(_)
-            | ApplicationError::QuoteNotFound(_) => Status::not_found(err.to_string()),
-            ApplicationError::Unauthorized => Status::unauthenticated(err.to_string()),
-            ApplicationError::QuoteExpired(_) | ApplicationError::InvalidState(_) => {
-                Status::failed_precondition(err.to_string())
-            }
-            ApplicationError::ComplianceFailed(_) => Status::permission_denied(err.to_string()),
-            _ => Status::internal(err.to_string()),
-        }
+    /// Submits a quote on behalf of an authenticated market maker.
+    async fn submit_quote(
+        &self,
+        request: Request<SubmitQuoteRequest>,
+    ) -> Result<Response<SubmitQuoteResponse>, Status> {
+        let response = self.inner.submit_quote(request.map(Into::into)).await?;
+        Ok(response.map(Into::into))
     }
-}
 
-/// Converts a ConversionError to a gRPC Status.
-impl From<ConversionError> for Status {
-    fn from(err: ConversionError) -> Self {
-        Status::invalid_argument(err.to_string())
+    type SubscribeRfqsStream =
+        Pin<Box<dyn Stream<Item = Result<proto::RfqBroadcast, Status>> + Send>>;
+
+    /// Streams RFQs broadcast to an authenticated market maker.
+    async fn subscribe_rfqs(
+        &self,
+        request: Request<SubscribeRfqsRequest>,
+    ) -> Result<Response<Self::SubscribeRfqsStream>, Status> {
+        let response = self.inner.subscribe_rfqs(request.map(Into::into)).await?;
+        Ok(response.map(|stream| -> Self::SubscribeRfqsStream {
+            Box::pin(stream.map(|item| item.map(Into::into)))
+        }))
     }
 }
 
@@ -489,7 +211,15 @@ mod tests {
     use std::collections::HashMap;
     use std::sync::RwLock;
 
+    use crate::application::use_cases::collect_quotes::QuoteEventPublisher;
     use crate::domain::entities::rfq::Rfq;
+    use crate::domain::events::rfq_events::QuoteReceived;
+    use crate::domain::value_objects::timestamp::Timestamp;
+    use crate::domain::value_objects::{
+        CounterpartyId, Instrument, OrderSide, Quantity, RfqId, VenueId,
+    };
+    use crate::infrastructure::persistence::cursor::{paginate, paginate_offset};
+    use crate::infrastructure::persistence::{PageCursor, RfqListFilter};
 
     /// Mock RFQ repository for testing.
     #[derive(Debug, Default)]
@@ -507,12 +237,120 @@ mod tests {
         async fn find_by_id(&self, id: RfqId) -> Result<Option<Rfq>, String> {
             Ok(self.rfqs.read().unwrap().get(&id).cloned())
         }
+
+        async fn list_after(
+            &self,
+            cursor: Option<&PageCursor>,
+            limit: usize,
+            filter: &RfqListFilter,
+        ) -> Result<Vec<Rfq>, String> {
+            let rfqs = self
+                .rfqs
+                .read()
+                .unwrap()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .cloned()
+                .collect();
+            Ok(paginate(rfqs, cursor, limit, PageCursor::from_rfq))
+        }
+
+        async fn list_offset(
+            &self,
+            offset: usize,
+            limit: usize,
+            filter: &RfqListFilter,
+        ) -> Result<Vec<Rfq>, String> {
+            let rfqs = self
+                .rfqs
+                .read()
+                .unwrap()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .cloned()
+                .collect();
+            Ok(paginate_offset(rfqs, offset, limit, PageCursor::from_rfq))
+        }
+
+        async fn count_matching(&self, filter: &RfqListFilter) -> Result<u64, String> {
+            let count = self
+                .rfqs
+                .read()
+                .unwrap()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .count();
+            Ok(count as u64)
+        }
+    }
+
+    /// Quote event publisher that discards events.
+    #[derive(Debug)]
+    struct NoopQuoteEventPublisher;
+
+    #[async_trait]
+    impl QuoteEventPublisher for NoopQuoteEventPublisher {
+        async fn publish_quote_received(&self, _event: QuoteReceived) -> Result<(), String> {
+            Ok(())
+        }
     }
 
     fn create_service() -> RfqServiceImpl {
         RfqServiceImpl::new(Arc::new(MockRfqRepository::default()))
     }
 
+    /// Creates a service accepting quotes from `mm-1` and holding `rfq`.
+    fn create_quoting_service(rfq: Option<Rfq>) -> RfqServiceImpl {
+        let repo = Arc::new(MockRfqRepository::default());
+        if let Some(rfq) = rfq {
+            repo.rfqs.write().unwrap().insert(rfq.id(), rfq);
+        }
+        let use_case = SubmitQuoteUseCase::new(repo.clone(), Arc::new(NoopQuoteEventPublisher));
+        RfqServiceImpl::new(repo)
+            .with_quote_submission(Arc::new(use_case))
+            .with_mm_api_keys([("mm-1".to_string(), "secret".to_string())])
+    }
+
+    fn create_test_rfq(start_collection: bool) -> Rfq {
+        let mut rfq = crate::domain::entities::rfq::RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            Instrument::builder(
+                crate::domain::value_objects::Symbol::new("BTC/USD").unwrap(),
+                crate::domain::value_objects::AssetClass::CryptoSpot,
+            )
+            .build(),
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build();
+        if start_collection {
+            rfq.start_quote_collection().unwrap();
+        }
+        rfq
+    }
+
+    fn submit_quote_request(rfq_id: RfqId, api_key: &str, valid_secs: i64) -> SubmitQuoteRequest {
+        SubmitQuoteRequest {
+            rfq_id: Some(proto::Uuid::from(rfq_id)),
+            price: Some(proto::Decimal {
+                value: "50000".to_string(),
+            }),
+            quantity: Some(proto::Decimal {
+                value: "1".to_string(),
+            }),
+            valid_until: Some(proto::Timestamp::from(
+                Timestamp::now().add_secs(valid_secs),
+            )),
+            credentials: Some(proto::MmCredentials {
+                mm_id: "mm-1".to_string(),
+                api_key: api_key.to_string(),
+            }),
+            side: proto::OrderSide::Unspecified as i32,
+        }
+    }
+
+    #[allow(deprecated)]
     fn create_valid_request() -> CreateRfqRequest {
         CreateRfqRequest {
             client_id: "client-123".to_string(),
@@ -527,6 +365,9 @@ mod tests {
                 value: "1.5".to_string(),
             }),
             timeout_seconds: 300,
+            size_mode: None,
+            quantity_disclosure: None,
+            two_way: false,
         }
     }
 
@@ -543,6 +384,95 @@ mod tests {
         assert_eq!(rfq.state, proto::RfqState::Created as i32);
     }
 
+    #[tokio::test]
+    async fn create_rfq_refused_while_shutting_down() {
+        let shutdown = ShutdownCoordinator::default();
+        let service = create_service().with_shutdown(shutdown.clone());
+        shutdown.drain().await;
+
+        let response = service
+            .create_rfq(Request::new(create_valid_request()))
+            .await;
+        assert_eq!(response.unwrap_err().code(), tonic::Code::Unavailable);
+    }
+
+    #[tokio::test]
+    async fn create_rfq_applies_size_mode() {
+        let service = create_service();
+        let mut req = create_valid_request();
+        req.size_mode = Some(proto::SizeNegotiation {
+            mode: proto::SizeMode::MinQuantity as i32,
+            min_quantity: Some(proto::Decimal {
+                value: "0.5".to_string(),
+            }),
+        });
+
+        let rfq = service
+            .create_rfq(Request::new(req))
+            .await
+            .unwrap()
+            .into_inner()
+            .rfq
+            .unwrap();
+        let size_mode = rfq.size_mode.unwrap();
+        assert_eq!(size_mode.mode, proto::SizeMode::MinQuantity as i32);
+        assert_eq!(size_mode.min_quantity.unwrap().value, "0.5");
+    }
+
+    #[tokio::test]
+    async fn create_rfq_applies_quantity_disclosure() {
+        let service = create_service();
+        let mut req = create_valid_request();
+        req.quantity_disclosure = Some(proto::QuantityDisclosure {
+            mode: proto::DisclosureMode::Hidden as i32,
+            bucket: None,
+        });
+
+        let rfq = service
+            .create_rfq(Request::new(req))
+            .await
+            .unwrap()
+            .into_inner()
+            .rfq
+            .unwrap();
+        let disclosure = rfq.quantity_disclosure.unwrap();
+        assert_eq!(disclosure.mode, proto::DisclosureMode::Hidden as i32);
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn create_rfq_two_way_ignores_side() {
+        let service = create_service();
+        let mut req = create_valid_request();
+        req.two_way = true;
+        req.side = proto::OrderSide::Unspecified as i32;
+
+        let rfq = service
+            .create_rfq(Request::new(req))
+            .await
+            .unwrap()
+            .into_inner()
+            .rfq
+            .unwrap();
+        assert!(rfq.two_way);
+        assert_eq!(rfq.side, proto::OrderSide::Unspecified as i32);
+    }
+
+    #[tokio::test]
+    async fn create_rfq_rejects_min_quantity_above_quantity() {
+        let service = create_service();
+        let mut req = create_valid_request();
+        req.size_mode = Some(proto::SizeNegotiation {
+            mode: proto::SizeMode::MinQuantity as i32,
+            min_quantity: Some(proto::Decimal {
+                value: "2".to_string(),
+            }),
+        });
+
+        let response = service.create_rfq(Request::new(req)).await;
+        assert_eq!(response.unwrap_err().code(), tonic::Code::InvalidArgument);
+    }
+
     #[tokio::test]
     async fn create_rfq_empty_client_id() {
         let service = create_service();
@@ -566,6 +496,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[allow(deprecated)]
     async fn create_rfq_invalid_side() {
         let service = create_service();
         let mut req = create_valid_request();
@@ -677,31 +608,147 @@ mod tests {
         assert_eq!(response.unwrap_err().code(), tonic::Code::NotFound);
     }
 
-    #[test]
-    fn application_error_to_status_validation() {
-        let err = ApplicationError::validation("invalid input");
-        let status: Status = err.into();
-        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    #[tokio::test]
+    async fn submit_quote_accepted() {
+        let rfq = create_test_rfq(true);
+        let rfq_id = rfq.id();
+        let service = create_quoting_service(Some(rfq));
+
+        let response = service
+            .submit_quote(Request::new(submit_quote_request(rfq_id, "secret", 60)))
+            .await
+            .unwrap();
+
+        let quote = response.into_inner().quote.unwrap();
+        assert_eq!(quote.venue_id, "mm-1");
+        let stored = service
+            .v2()
+            .rfq_repository()
+            .find_by_id(rfq_id)
+            .await
+            .unwrap();
+        assert_eq!(stored.unwrap().quotes().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn submit_quote_bad_credentials() {
+        let rfq = create_test_rfq(true);
+        let rfq_id = rfq.id();
+        let service = create_quoting_service(Some(rfq));
+
+        let response = service
+            .submit_quote(Request::new(submit_quote_request(rfq_id, "wrong", 60)))
+            .await;
+        assert_eq!(response.unwrap_err().code(), tonic::Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn submit_quote_unknown_rfq() {
+        let service = create_quoting_service(None);
+
+        let response = service
+            .submit_quote(Request::new(submit_quote_request(
+                RfqId::new_v4(),
+                "secret",
+                60,
+            )))
+            .await;
+        assert_eq!(response.unwrap_err().code(), tonic::Code::NotFound);
+    }
+
+    #[tokio::test]
+    async fn submit_quote_wrong_state() {
+        let rfq = create_test_rfq(false);
+        let rfq_id = rfq.id();
+        let service = create_quoting_service(Some(rfq));
+
+        let response = service
+            .submit_quote(Request::new(submit_quote_request(rfq_id, "secret", 60)))
+            .await;
+        assert_eq!(
+            response.unwrap_err().code(),
+            tonic::Code::FailedPrecondition
+        );
     }
 
-    #[test]
-    fn application_error_to_status_not_found() {
-        let err = ApplicationError::not_found("RFQ", "123");
-        let status: Status = err.into();
-        assert_eq!(status.code(), tonic::Code::NotFound);
+    #[tokio::test]
+    async fn submit_quote_expired_quote() {
+        let rfq = create_test_rfq(true);
+        let rfq_id = rfq.id();
+        let service = create_quoting_service(Some(rfq));
+
+        let response = service
+            .submit_quote(Request::new(submit_quote_request(rfq_id, "secret", -1)))
+            .await;
+        assert_eq!(
+            response.unwrap_err().code(),
+            tonic::Code::FailedPrecondition
+        );
     }
 
-    #[test]
-    fn application_error_to_status_unauthorized() {
-        let err = ApplicationError::unauthorized();
-        let status: Status = err.into();
-        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    #[tokio::test]
+    async fn submit_quote_not_enabled() {
+        let service = create_service();
+
+        let response = service
+            .submit_quote(Request::new(submit_quote_request(
+                RfqId::new_v4(),
+                "secret",
+                60,
+            )))
+            .await;
+        assert_eq!(response.unwrap_err().code(), tonic::Code::Unimplemented);
+    }
+
+    #[tokio::test]
+    async fn subscribe_rfqs_streams_broadcasts_to_each_mm() {
+        let hub = Arc::new(RfqSubscriptionHub::new());
+        let service = create_service()
+            .with_rfq_subscriptions(hub.clone())
+            .with_mm_api_keys([
+                ("mm-1".to_string(), "secret-1".to_string()),
+                ("mm-2".to_string(), "secret-2".to_string()),
+            ]);
+        let subscribe = |mm_id: &str, api_key: &str| {
+            Request::new(SubscribeRfqsRequest {
+                credentials: Some(proto::MmCredentials {
+                    mm_id: mm_id.to_string(),
+                    api_key: api_key.to_string(),
+                }),
+            })
+        };
+        let mut mm1 = service
+            .subscribe_rfqs(subscribe("mm-1", "secret-1"))
+            .await
+            .unwrap()
+            .into_inner();
+        let mut mm2 = service
+            .subscribe_rfqs(subscribe("mm-2", "secret-2"))
+            .await
+            .unwrap()
+            .into_inner();
+
+        let rfq = create_test_rfq(true);
+        let broadcast = crate::application::services::RfqBroadcast::from_rfq(&rfq);
+        assert_eq!(hub.publish(&VenueId::new("mm-1"), &broadcast), 1);
+        assert_eq!(hub.publish(&VenueId::new("mm-2"), &broadcast), 1);
+
+        for stream in [&mut mm1, &mut mm2] {
+            let received = stream.next().await.unwrap().unwrap();
+            assert_eq!(received.rfq_id, Some(proto::Uuid::from(rfq.id())));
+            assert_eq!(received.requester, "client-1");
+        }
     }
 
-    #[test]
-    fn conversion_error_to_status() {
-        let err = ConversionError::MissingField("test");
-        let status: Status = err.into();
-        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    #[tokio::test]
+    async fn subscribe_rfqs_bad_credentials() {
+        let service = create_service()
+            .with_rfq_subscriptions(Arc::new(RfqSubscriptionHub::new()))
+            .with_mm_api_keys([("mm-1".to_string(), "secret".to_string())]);
+
+        let response = service
+            .subscribe_rfqs(Request::new(SubscribeRfqsRequest { credentials: None }))
+            .await;
+        assert_eq!(response.err().unwrap().code(), tonic::Code::Unauthenticated);
     }
 }
@@ -0,0 +1,1161 @@
+//! # gRPC RFQ Service (v2)
+//!
+//! Canonical gRPC service implementation for the `otc.rfq.v2` API.
+//!
+//! [`RfqServiceV2Impl`] holds all RFQ request handling. The v1
+//! [`RfqServiceImpl`](crate::api::grpc::RfqServiceImpl) delegates to it
+//! through the [`versioning`](crate::api::grpc::versioning) shims, so both
+//! versions share validation, persistence and error mapping.
+//!
+//! Compared to v1, responses carry the RFQ direction, quote firmness,
+//! activation time, failure reason, version and, when a negotiation
+//! repository is configured, references to the RFQ's negotiations.
+//! `GetQuoteBook` exists only in v2: it returns an RFQ's ranked quotes with
+//! their scores and tie-break rules to the client that created the RFQ.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use otc_rfq::api::grpc::RfqServiceV2Impl;
+//! use otc_rfq::api::grpc::proto::otc_rfq_v2::rfq_service_server::RfqServiceServer;
+//!
+//! let service = RfqServiceV2Impl::new(rfq_repository).with_negotiations(negotiations);
+//! Server::builder()
+//!     .add_service(RfqServiceServer::new(service))
+//!     .serve(addr)
+//!     .await?;
+//! ```
+
+use crate::api::grpc::conversions::{self, ConversionError};
+use crate::api::grpc::conversions_v2;
+use crate::api::grpc::proto::otc_rfq_v2::{
+    self as v2, CancelRfqRequest, CancelRfqResponse, CreateRfqRequest, CreateRfqResponse,
+    ExecuteTradeRequest, ExecuteTradeResponse, GetQuoteBookRequest, GetQuotesRequest,
+    GetQuotesResponse, GetRfqRequest, GetRfqResponse, QuoteBook, StreamRfqStatusRequest,
+    StreamRfqStatusResponse, SubmitQuoteRequest, SubmitQuoteResponse, SubscribeRfqsRequest,
+    rfq_service_server::RfqService,
+};
+use crate::application::error::ApplicationError;
+use crate::application::services::ranking_strategy::{BestPriceStrategy, RankingStrategy};
+use crate::application::services::{
+    RfqCancellationService, RfqSubscriptionHub, ShutdownCoordinator,
+};
+use crate::application::use_cases::create_rfq::RfqRepository;
+use crate::application::use_cases::submit_quote::{self, SubmitQuoteUseCase};
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    CounterpartyId, Instrument, Quantity, QuantityDisclosure, RfqDirection, RfqId, RfqState,
+    SizeNegotiationMode, VenueId,
+};
+use crate::infrastructure::persistence::traits::NegotiationRepository;
+use crate::infrastructure::{metrics, telemetry};
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_stream::{Stream, StreamExt, wrappers::ReceiverStream};
+use tonic::{Request, Response, Status};
+use tracing::{error, info, instrument, warn};
+
+/// Domain values extracted from a valid `CreateRfqRequest`.
+type ValidatedCreateRfq = (
+    CounterpartyId,
+    Instrument,
+    RfqDirection,
+    Quantity,
+    SizeNegotiationMode,
+    QuantityDisclosure,
+    Timestamp,
+);
+
+/// gRPC RFQ Service v2 implementation.
+///
+/// Implements the v2 `RfqService` trait generated from protobuf definitions,
+/// providing all RPC methods for RFQ lifecycle management. Cloning is cheap
+/// and clones share their dependencies.
+#[derive(Debug, Clone)]
+pub struct RfqServiceV2Impl {
+    rfq_repository: Arc<dyn RfqRepository>,
+    negotiations: Option<Arc<dyn NegotiationRepository>>,
+    shutdown: Option<ShutdownCoordinator>,
+    submit_quote: Option<Arc<SubmitQuoteUseCase>>,
+    rfq_subscriptions: Option<Arc<RfqSubscriptionHub>>,
+    rfq_cancellations: Option<Arc<RfqCancellationService>>,
+    mm_api_keys: HashMap<String, String>,
+}
+
+impl RfqServiceV2Impl {
+    /// Creates a new RFQ service with the given repository.
+    #[must_use]
+    pub fn new(rfq_repository: Arc<dyn RfqRepository>) -> Self {
+        Self {
+            rfq_repository,
+            negotiations: None,
+            shutdown: None,
+            submit_quote: None,
+            rfq_subscriptions: None,
+            rfq_cancellations: None,
+            mm_api_keys: HashMap::new(),
+        }
+    }
+
+    /// Attaches negotiation references to the RFQs returned by `GetRfq` and
+    /// `CancelRfq`.
+    #[must_use]
+    pub fn with_negotiations(mut self, negotiations: Arc<dyn NegotiationRepository>) -> Self {
+        self.negotiations = Some(negotiations);
+        self
+    }
+
+    /// Refuses RFQ creation once the coordinator starts draining.
+    #[must_use]
+    pub fn with_shutdown(mut self, shutdown: ShutdownCoordinator) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    /// Enables `SubmitQuote`.
+    #[must_use]
+    pub fn with_quote_submission(mut self, use_case: Arc<SubmitQuoteUseCase>) -> Self {
+        self.submit_quote = Some(use_case);
+        self
+    }
+
+    /// Enables `SubscribeRfqs`, streaming broadcasts from the hub.
+    #[must_use]
+    pub fn with_rfq_subscriptions(mut self, hub: Arc<RfqSubscriptionHub>) -> Self {
+        self.rfq_subscriptions = Some(hub);
+        self
+    }
+
+    /// Sends cancel notices to the venues pricing an RFQ cancelled through
+    /// `CancelRfq`.
+    #[must_use]
+    pub fn with_rfq_cancellations(mut self, cancellations: Arc<RfqCancellationService>) -> Self {
+        self.rfq_cancellations = Some(cancellations);
+        self
+    }
+
+    /// Sets the `(mm_id, api_key)` credentials accepted from market makers.
+    #[must_use]
+    pub fn with_mm_api_keys(
+        mut self,
+        mm_api_keys: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.mm_api_keys = mm_api_keys.into_iter().collect();
+        self
+    }
+
+    /// Returns the RFQ repository.
+    #[must_use]
+    pub fn rfq_repository(&self) -> &Arc<dyn RfqRepository> {
+        &self.rfq_repository
+    }
+
+    /// Converts an RFQ, attaching its negotiation references if configured.
+    async fn rfq_message(&self, rfq: &Rfq) -> Result<v2::Rfq, Status> {
+        let mut message = v2::Rfq::from(rfq);
+        if let Some(negotiations) = &self.negotiations {
+            message.negotiations = negotiations
+                .find_by_rfq(rfq.id())
+                .await
+                .map_err(|e| {
+                    error!("Failed to find negotiations: {}", e);
+                    Status::internal(format!("failed to find negotiations: {e}"))
+                })?
+                .iter()
+                .map(v2::NegotiationRef::from)
+                .collect();
+        }
+        Ok(message)
+    }
+
+    /// Authenticates market maker credentials, returning the MM's identity.
+    fn authenticate_mm(
+        &self,
+        credentials: Option<&v2::MmCredentials>,
+    ) -> Result<CounterpartyId, Status> {
+        let credentials = credentials.ok_or(ApplicationError::Unauthorized)?;
+        match self.mm_api_keys.get(&credentials.mm_id) {
+            Some(api_key) if !api_key.is_empty() && *api_key == credentials.api_key => {
+                Ok(CounterpartyId::new(&credentials.mm_id))
+            }
+            _ => Err(ApplicationError::Unauthorized.into()),
+        }
+    }
+
+    /// Validates a CreateRfqRequest and returns domain types.
+    fn validate_create_request(
+        &self,
+        request: &CreateRfqRequest,
+    ) -> Result<ValidatedCreateRfq, Status> {
+        // Validate client_id
+        if request.client_id.is_empty() {
+            return Err(Status::invalid_argument("client_id cannot be empty"));
+        }
+
+        // Validate and convert instrument
+        let instrument = request
+            .instrument
+            .as_ref()
+            .ok_or_else(|| Status::invalid_argument("instrument is required"))?;
+
+        if instrument.base_asset.is_empty() {
+            return Err(Status::invalid_argument("base_asset cannot be empty"));
+        }
+        if instrument.quote_asset.is_empty() {
+            return Err(Status::invalid_argument("quote_asset cannot be empty"));
+        }
+
+        let symbol_str = format!("{}/{}", instrument.base_asset, instrument.quote_asset);
+        let symbol = crate::domain::value_objects::Symbol::new(&symbol_str)
+            .map_err(|e| Status::invalid_argument(format!("invalid symbol: {e}")))?;
+        let domain_instrument = Instrument::builder(
+            symbol,
+            crate::domain::value_objects::enums::AssetClass::CryptoSpot,
+        )
+        .build();
+
+        // Validate and convert direction
+        let direction: RfqDirection = conversions_v2::proto_direction_to_domain(request.direction)
+            .map_err(|_| Status::invalid_argument("invalid direction"))?;
+
+        // Validate and convert quantity
+        let quantity_decimal = request
+            .quantity
+            .as_ref()
+            .ok_or_else(|| Status::invalid_argument("quantity is required"))?;
+
+        let quantity_value: rust_decimal::Decimal = quantity_decimal
+            .clone()
+            .try_into()
+            .map_err(|e: ConversionError| Status::invalid_argument(e.to_string()))?;
+
+        let quantity = Quantity::try_from(quantity_value)
+            .map_err(|e| Status::invalid_argument(format!("invalid quantity: {e}")))?;
+
+        // Validate and convert size mode
+        let size_mode = conversions_v2::proto_size_mode_to_domain(request.size_mode.clone())
+            .map_err(|e| Status::invalid_argument(format!("invalid size_mode: {e}")))?;
+        size_mode.validate_for(quantity)?;
+
+        // Validate and convert quantity disclosure
+        let quantity_disclosure = conversions_v2::proto_quantity_disclosure_to_domain(
+            request.quantity_disclosure.clone(),
+        )
+        .map_err(|e| Status::invalid_argument(format!("invalid quantity_disclosure: {e}")))?;
+        quantity_disclosure.validate_for(quantity)?;
+
+        // Validate timeout
+        if request.timeout_seconds <= 0 {
+            return Err(Status::invalid_argument("timeout_seconds must be positive"));
+        }
+
+        let expires_at = Timestamp::now().add_secs(request.timeout_seconds);
+
+        Ok((
+            CounterpartyId::new(&request.client_id),
+            domain_instrument,
+            direction,
+            quantity,
+            size_mode,
+            quantity_disclosure,
+            expires_at,
+        ))
+    }
+}
+
+/// Ranks the live quotes of `rfq` by price on each side it is quoted in.
+///
+/// Uses the ranking the quote collection records, so ranks agree with the
+/// RFQ's quote history. Collection counts as complete once those ranks are
+/// recorded or the RFQ has moved past receiving quotes.
+fn quote_book(rfq: &Rfq) -> QuoteBook {
+    let strategy = BestPriceStrategy::new();
+    let quotes = rfq
+        .direction()
+        .sides()
+        .iter()
+        .flat_map(|&side| {
+            let quotes: Vec<_> = rfq.quotes_on(side).cloned().collect();
+            strategy.rank(&quotes, side)
+        })
+        .map(|ranked| v2::RankedQuote::from(&ranked))
+        .collect();
+    let collecting = matches!(
+        rfq.state(),
+        RfqState::Created | RfqState::QuoteRequesting | RfqState::QuotesReceived
+    ) && rfq.quote_ranks().is_empty();
+    let status = if collecting {
+        v2::AggregationStatus::Collecting
+    } else {
+        v2::AggregationStatus::Complete
+    };
+
+    QuoteBook {
+        rfq_id: Some(v2::Uuid::from(rfq.id())),
+        quotes,
+        status: status as i32,
+    }
+}
+
+/// Continues the caller's trace from the request's `traceparent` metadata.
+fn continue_trace<T>(request: &Request<T>) {
+    let headers = request.metadata().clone().into_headers();
+    telemetry::set_remote_parent(&tracing::Span::current(), &headers);
+}
+
+#[tonic::async_trait]
+impl RfqService for RfqServiceV2Impl {
+    /// Creates a new RFQ.
+    #[instrument(skip(self, request), fields(client_id, rfq_id))]
+    async fn create_rfq(
+        &self,
+        request: Request<CreateRfqRequest>,
+    ) -> Result<Response<CreateRfqResponse>, Status> {
+        continue_trace(&request);
+        let req = request.into_inner();
+        tracing::Span::current().record("client_id", &req.client_id);
+
+        info!("Creating RFQ for client: {}", req.client_id);
+
+        if let Some(shutdown) = &self.shutdown
+            && !shutdown.is_accepting()
+        {
+            return Err(ApplicationError::ShuttingDown.into());
+        }
+
+        // Validate request
+        let (
+            client_id,
+            instrument,
+            direction,
+            quantity,
+            size_mode,
+            quantity_disclosure,
+            expires_at,
+        ) = self.validate_create_request(&req)?;
+
+        // Build RFQ
+        let rfq = crate::domain::entities::rfq::RfqBuilder::new(
+            client_id, instrument, direction, quantity, expires_at,
+        )
+        .size_mode(size_mode)
+        .quantity_disclosure(quantity_disclosure)
+        .build();
+        tracing::Span::current().record("rfq_id", tracing::field::display(rfq.id()));
+
+        // Save to repository
+        self.rfq_repository.save(&rfq).await.map_err(|e| {
+            error!("Failed to save RFQ: {}", e);
+            Status::internal(format!("failed to save RFQ: {e}"))
+        })?;
+
+        metrics::record_rfq_created();
+        info!("Created RFQ: {}", rfq.id());
+
+        // Convert to proto response
+        let response = CreateRfqResponse {
+            rfq: Some(v2::Rfq::from(&rfq)),
+        };
+
+        Ok(Response::new(response))
+    }
+
+    /// Gets an RFQ by ID.
+    #[instrument(skip(self, request), fields(rfq_id))]
+    async fn get_rfq(
+        &self,
+        request: Request<GetRfqRequest>,
+    ) -> Result<Response<GetRfqResponse>, Status> {
+        continue_trace(&request);
+        let req = request.into_inner();
+
+        let rfq_id: RfqId = req
+            .rfq_id
+            .ok_or_else(|| Status::invalid_argument("rfq_id is required"))?
+            .try_into()
+            .map_err(|e: ConversionError| Status::invalid_argument(e.to_string()))?;
+
+        tracing::Span::current().record("rfq_id", rfq_id.to_string());
+
+        info!("Getting RFQ: {}", rfq_id);
+
+        let rfq = self
+            .rfq_repository
+            .find_by_id(rfq_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to find RFQ: {}", e);
+                Status::internal(format!("failed to find RFQ: {e}"))
+            })?
+            .ok_or_else(|| {
+                warn!("RFQ not found: {}", rfq_id);
+                Status::not_found(format!("RFQ not found: {rfq_id}"))
+            })?;
+
+        let response = GetRfqResponse {
+            rfq: Some(self.rfq_message(&rfq).await?),
+        };
+
+        Ok(Response::new(response))
+    }
+
+    type GetQuotesStream = Pin<Box<dyn Stream<Item = Result<GetQuotesResponse, Status>> + Send>>;
+
+    /// Streams quotes for an RFQ.
+    #[instrument(skip(self, request), fields(rfq_id))]
+    async fn get_quotes(
+        &self,
+        request: Request<GetQuotesRequest>,
+    ) -> Result<Response<Self::GetQuotesStream>, Status> {
+        continue_trace(&request);
+        let req = request.into_inner();
+
+        let rfq_id: RfqId = req
+            .rfq_id
+            .ok_or_else(|| Status::invalid_argument("rfq_id is required"))?
+            .try_into()
+            .map_err(|e: ConversionError| Status::invalid_argument(e.to_string()))?;
+
+        tracing::Span::current().record("rfq_id", rfq_id.to_string());
+
+        info!("Streaming quotes for RFQ: {}", rfq_id);
+
+        // Get the RFQ to access its quotes
+        let rfq = self
+            .rfq_repository
+            .find_by_id(rfq_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to find RFQ: {}", e);
+                Status::internal(format!("failed to find RFQ: {e}"))
+            })?
+            .ok_or_else(|| {
+                warn!("RFQ not found: {}", rfq_id);
+                Status::not_found(format!("RFQ not found: {rfq_id}"))
+            })?;
+
+        // Create a channel for streaming
+        let (tx, rx) = mpsc::channel(32);
+
+        // Spawn a task to send quotes
+        let quotes = rfq.quotes().to_vec();
+        tokio::spawn(async move {
+            for (i, quote) in quotes.iter().enumerate() {
+                let is_final = i == quotes.len() - 1;
+                let response = GetQuotesResponse {
+                    quote: Some(v2::Quote::from(quote)),
+                    is_final,
+                };
+
+                if tx.send(Ok(response)).await.is_err() {
+                    // Receiver dropped
+                    break;
+                }
+            }
+
+            // If no quotes, send a final empty response
+            if quotes.is_empty() {
+                let _ = tx
+                    .send(Ok(GetQuotesResponse {
+                        quote: None,
+                        is_final: true,
+                    }))
+                    .await;
+            }
+        });
+
+        let stream = ReceiverStream::new(rx);
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    /// Gets the ranked quotes of an RFQ for its owner.
+    #[instrument(skip(self, request), fields(rfq_id, client_id))]
+    async fn get_quote_book(
+        &self,
+        request: Request<GetQuoteBookRequest>,
+    ) -> Result<Response<QuoteBook>, Status> {
+        continue_trace(&request);
+        let req = request.into_inner();
+
+        let rfq_id: RfqId = conversions::require_field(req.rfq_id, "rfq_id")?.try_into()?;
+        if req.client_id.is_empty() {
+            return Err(Status::invalid_argument("client_id cannot be empty"));
+        }
+        tracing::Span::current().record("rfq_id", rfq_id.to_string());
+        tracing::Span::current().record("client_id", &req.client_id);
+
+        let rfq = self
+            .rfq_repository
+            .find_by_id(rfq_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to find RFQ: {}", e);
+                Status::internal(format!("failed to find RFQ: {e}"))
+            })?
+            .ok_or_else(|| {
+                warn!("RFQ not found: {}", rfq_id);
+                Status::not_found(format!("RFQ not found: {rfq_id}"))
+            })?;
+
+        if rfq.client_id().as_str() != req.client_id {
+            warn!("Denied quote book of RFQ {} to {}", rfq_id, req.client_id);
+            return Err(DomainError::UnauthorizedCounterparty(
+                "RFQ belongs to another counterparty".to_string(),
+            )
+            .into());
+        }
+
+        Ok(Response::new(quote_book(&rfq)))
+    }
+
+    /// Executes a trade for a selected quote.
+    #[instrument(skip(self, request), fields(rfq_id, quote_id))]
+    async fn execute_trade(
+        &self,
+        request: Request<ExecuteTradeRequest>,
+    ) -> Result<Response<ExecuteTradeResponse>, Status> {
+        continue_trace(&request);
+        let req = request.into_inner();
+
+        let rfq_id: RfqId = req
+            .rfq_id
+            .ok_or_else(|| Status::invalid_argument("rfq_id is required"))?
+            .try_into()
+            .map_err(|e: ConversionError| Status::invalid_argument(e.to_string()))?;
+
+        let quote_id: crate::domain::value_objects::QuoteId = req
+            .quote_id
+            .ok_or_else(|| Status::invalid_argument("quote_id is required"))?
+            .try_into()
+            .map_err(|e: ConversionError| Status::invalid_argument(e.to_string()))?;
+
+        tracing::Span::current().record("rfq_id", rfq_id.to_string());
+        tracing::Span::current().record("quote_id", quote_id.to_string());
+
+        info!("Executing trade for RFQ: {}, Quote: {}", rfq_id, quote_id);
+
+        // Get the RFQ
+        let rfq = self
+            .rfq_repository
+            .find_by_id(rfq_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to find RFQ: {}", e);
+                Status::internal(format!("failed to find RFQ: {e}"))
+            })?
+            .ok_or_else(|| {
+                warn!("RFQ not found: {}", rfq_id);
+                Status::not_found(format!("RFQ not found: {rfq_id}"))
+            })?;
+
+        // Find the quote
+        let quote = rfq
+            .quotes()
+            .iter()
+            .find(|q| q.id() == quote_id)
+            .ok_or_else(|| {
+                warn!("Quote not found: {}", quote_id);
+                Status::not_found(format!("Quote not found: {quote_id}"))
+            })?;
+
+        // Check if quote is expired
+        if quote.is_expired() {
+            return Err(DomainError::QuoteExpired(quote_id.to_string()).into());
+        }
+
+        // Create a trade from the quote
+        let trade = crate::domain::entities::trade::Trade::new(
+            rfq_id,
+            quote_id,
+            quote.venue_id().clone(),
+            quote.price(),
+            quote.quantity(),
+        );
+
+        info!("Created trade: {}", trade.id());
+
+        let response = ExecuteTradeResponse {
+            trade: Some(v2::Trade::from(&trade)),
+        };
+
+        Ok(Response::new(response))
+    }
+
+    /// Cancels an RFQ.
+    #[instrument(skip(self, request), fields(rfq_id))]
+    async fn cancel_rfq(
+        &self,
+        request: Request<CancelRfqRequest>,
+    ) -> Result<Response<CancelRfqResponse>, Status> {
+        continue_trace(&request);
+        let req = request.into_inner();
+
+        let rfq_id: RfqId = req
+            .rfq_id
+            .ok_or_else(|| Status::invalid_argument("rfq_id is required"))?
+            .try_into()
+            .map_err(|e: ConversionError| Status::invalid_argument(e.to_string()))?;
+
+        tracing::Span::current().record("rfq_id", rfq_id.to_string());
+
+        info!("Cancelling RFQ: {}, reason: {}", rfq_id, req.reason);
+
+        // Get the RFQ
+        let mut rfq = self
+            .rfq_repository
+            .find_by_id(rfq_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to find RFQ: {}", e);
+                Status::internal(format!("failed to find RFQ: {e}"))
+            })?
+            .ok_or_else(|| {
+                warn!("RFQ not found: {}", rfq_id);
+                Status::not_found(format!("RFQ not found: {rfq_id}"))
+            })?;
+
+        // Cancel the RFQ
+        rfq.cancel().map_err(|e| {
+            warn!("Cannot cancel RFQ: {}", e);
+            Status::from(e)
+        })?;
+
+        // Save the updated RFQ
+        self.rfq_repository.save(&rfq).await.map_err(|e| {
+            error!("Failed to save cancelled RFQ: {}", e);
+            Status::internal(format!("failed to save RFQ: {e}"))
+        })?;
+        metrics::record_rfq_terminal(rfq.state());
+
+        if let Some(cancellations) = &self.rfq_cancellations {
+            let notices = cancellations.notify_cancelled(&rfq).await;
+            info!(
+                "Sent cancel notices for RFQ {} to {} venues",
+                rfq_id,
+                notices.len()
+            );
+        }
+
+        info!("Cancelled RFQ: {}", rfq_id);
+
+        let response = CancelRfqResponse {
+            rfq: Some(self.rfq_message(&rfq).await?),
+        };
+
+        Ok(Response::new(response))
+    }
+
+    type StreamRfqStatusStream =
+        Pin<Box<dyn Stream<Item = Result<StreamRfqStatusResponse, Status>> + Send>>;
+
+    /// Streams RFQ status updates.
+    #[instrument(skip(self, request), fields(rfq_id))]
+    async fn stream_rfq_status(
+        &self,
+        request: Request<StreamRfqStatusRequest>,
+    ) -> Result<Response<Self::StreamRfqStatusStream>, Status> {
+        continue_trace(&request);
+        let req = request.into_inner();
+
+        let rfq_id: RfqId = req
+            .rfq_id
+            .ok_or_else(|| Status::invalid_argument("rfq_id is required"))?
+            .try_into()
+            .map_err(|e: ConversionError| Status::invalid_argument(e.to_string()))?;
+
+        tracing::Span::current().record("rfq_id", rfq_id.to_string());
+
+        info!("Streaming status for RFQ: {}", rfq_id);
+
+        // Get the current RFQ state
+        let rfq = self
+            .rfq_repository
+            .find_by_id(rfq_id)
+            .await
+            .map_err(|e| {
+                error!("Failed to find RFQ: {}", e);
+                Status::internal(format!("failed to find RFQ: {e}"))
+            })?
+            .ok_or_else(|| {
+                warn!("RFQ not found: {}", rfq_id);
+                Status::not_found(format!("RFQ not found: {rfq_id}"))
+            })?;
+
+        // Create a channel for streaming
+        let (tx, rx) = mpsc::channel(32);
+
+        // Send the current state; a resuming client gets the transition from
+        // the last state it saw, or nothing if the state has not changed
+        let current_state = v2::RfqState::from(rfq.state());
+        let initial_response = match v2::RfqState::try_from(req.resume_from) {
+            Ok(v2::RfqState::Unspecified) | Err(_) => Some(StreamRfqStatusResponse {
+                rfq_id: Some(v2::Uuid::from(rfq_id)),
+                previous_state: v2::RfqState::Unspecified as i32,
+                current_state: current_state as i32,
+                message: "Initial state".to_string(),
+                timestamp: Some(v2::Timestamp::from(Timestamp::now())),
+            }),
+            Ok(resume_from) if resume_from == current_state => None,
+            Ok(resume_from) => Some(StreamRfqStatusResponse {
+                rfq_id: Some(v2::Uuid::from(rfq_id)),
+                previous_state: resume_from as i32,
+                current_state: current_state as i32,
+                message: "Resumed".to_string(),
+                timestamp: Some(v2::Timestamp::from(Timestamp::now())),
+            }),
+        };
+
+        if let Some(response) = initial_response {
+            let _ = tx.send(Ok(response)).await;
+        }
+
+        // Note: In a real implementation, this would subscribe to state change events
+        // For now, we just send the initial state and close the stream
+
+        let stream = ReceiverStream::new(rx);
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    /// Submits a quote on behalf of an authenticated market maker.
+    #[instrument(skip(self, request), fields(rfq_id, mm_id))]
+    async fn submit_quote(
+        &self,
+        request: Request<SubmitQuoteRequest>,
+    ) -> Result<Response<SubmitQuoteResponse>, Status> {
+        continue_trace(&request);
+        let req = request.into_inner();
+
+        let use_case = self
+            .submit_quote
+            .as_ref()
+            .ok_or_else(|| Status::unimplemented("quote submission is not enabled"))?;
+
+        let mm_id = self.authenticate_mm(req.credentials.as_ref())?;
+        tracing::Span::current().record("mm_id", mm_id.as_str());
+
+        let rfq_id: RfqId = conversions::require_field(req.rfq_id, "rfq_id")?.try_into()?;
+        tracing::Span::current().record("rfq_id", rfq_id.to_string());
+
+        let price = conversions_v2::proto_decimal_to_price(req.price, "price")?;
+        let quantity = conversions_v2::proto_decimal_to_quantity(req.quantity, "quantity")?;
+        let valid_until: Timestamp =
+            conversions::require_field(req.valid_until, "valid_until")?.into();
+        let side = conversions_v2::proto_optional_order_side_to_domain(req.side)?;
+
+        info!(
+            "Market maker {} submitting quote for RFQ: {}",
+            mm_id, rfq_id
+        );
+
+        let quote = use_case
+            .execute(submit_quote::SubmitQuoteRequest {
+                rfq_id,
+                mm_id,
+                price,
+                quantity,
+                valid_until,
+                side,
+            })
+            .await
+            .map_err(|e| {
+                warn!("Quote submission rejected: {}", e);
+                Status::from(e)
+            })?;
+
+        Ok(Response::new(SubmitQuoteResponse {
+            quote: Some(v2::Quote::from(&quote)),
+        }))
+    }
+
+    type SubscribeRfqsStream = Pin<Box<dyn Stream<Item = Result<v2::RfqBroadcast, Status>> + Send>>;
+
+    /// Streams RFQs broadcast to an authenticated market maker.
+    #[instrument(skip(self, request), fields(mm_id))]
+    async fn subscribe_rfqs(
+        &self,
+        request: Request<SubscribeRfqsRequest>,
+    ) -> Result<Response<Self::SubscribeRfqsStream>, Status> {
+        continue_trace(&request);
+        let req = request.into_inner();
+
+        let hub = self
+            .rfq_subscriptions
+            .as_ref()
+            .ok_or_else(|| Status::unimplemented("RFQ subscriptions are not enabled"))?;
+
+        let mm_id = self.authenticate_mm(req.credentials.as_ref())?;
+        tracing::Span::current().record("mm_id", mm_id.as_str());
+
+        info!("Market maker {} subscribed to RFQs", mm_id);
+
+        let rx = hub.subscribe(VenueId::new(mm_id.as_str()));
+        let stream =
+            ReceiverStream::new(rx).map(|broadcast| Ok(v2::RfqBroadcast::from(&broadcast)));
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::negotiation::Negotiation;
+    use crate::domain::value_objects::{OrderSide, RfqState};
+    use crate::infrastructure::persistence::cursor::{paginate, paginate_offset};
+    use crate::infrastructure::persistence::in_memory::InMemoryNegotiationRepository;
+    use crate::infrastructure::persistence::{PageCursor, RfqListFilter};
+    use async_trait::async_trait;
+    use std::sync::RwLock;
+
+    /// Mock RFQ repository for testing.
+    #[derive(Debug, Default)]
+    struct MockRfqRepository {
+        rfqs: RwLock<HashMap<RfqId, Rfq>>,
+    }
+
+    #[async_trait]
+    impl RfqRepository for MockRfqRepository {
+        async fn save(&self, rfq: &Rfq) -> Result<(), String> {
+            self.rfqs.write().unwrap().insert(rfq.id(), rfq.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: RfqId) -> Result<Option<Rfq>, String> {
+            Ok(self.rfqs.read().unwrap().get(&id).cloned())
+        }
+
+        async fn list_after(
+            &self,
+            cursor: Option<&PageCursor>,
+            limit: usize,
+            filter: &RfqListFilter,
+        ) -> Result<Vec<Rfq>, String> {
+            let rfqs = self
+                .rfqs
+                .read()
+                .unwrap()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .cloned()
+                .collect();
+            Ok(paginate(rfqs, cursor, limit, PageCursor::from_rfq))
+        }
+
+        async fn list_offset(
+            &self,
+            offset: usize,
+            limit: usize,
+            filter: &RfqListFilter,
+        ) -> Result<Vec<Rfq>, String> {
+            let rfqs = self
+                .rfqs
+                .read()
+                .unwrap()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .cloned()
+                .collect();
+            Ok(paginate_offset(rfqs, offset, limit, PageCursor::from_rfq))
+        }
+
+        async fn count_matching(&self, filter: &RfqListFilter) -> Result<u64, String> {
+            let count = self
+                .rfqs
+                .read()
+                .unwrap()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .count();
+            Ok(count as u64)
+        }
+    }
+
+    fn create_request(direction: v2::RfqDirection) -> CreateRfqRequest {
+        CreateRfqRequest {
+            client_id: "client-123".to_string(),
+            instrument: Some(v2::Instrument {
+                symbol: "BTC/USD".to_string(),
+                asset_class: v2::AssetClass::CryptoSpot as i32,
+                base_asset: "BTC".to_string(),
+                quote_asset: "USD".to_string(),
+            }),
+            direction: direction as i32,
+            quantity: Some(v2::Decimal {
+                value: "1.5".to_string(),
+            }),
+            timeout_seconds: 300,
+            size_mode: None,
+            quantity_disclosure: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn create_rfq_two_way_direction() {
+        let service = RfqServiceV2Impl::new(Arc::new(MockRfqRepository::default()));
+
+        let rfq = service
+            .create_rfq(Request::new(create_request(v2::RfqDirection::TwoWay)))
+            .await
+            .unwrap()
+            .into_inner()
+            .rfq
+            .unwrap();
+        assert_eq!(rfq.direction(), v2::RfqDirection::TwoWay);
+        assert_eq!(rfq.state(), v2::RfqState::Created);
+        assert!(rfq.failure_reason.is_empty());
+    }
+
+    #[tokio::test]
+    async fn create_rfq_requires_direction() {
+        let service = RfqServiceV2Impl::new(Arc::new(MockRfqRepository::default()));
+
+        let response = service
+            .create_rfq(Request::new(create_request(v2::RfqDirection::Unspecified)))
+            .await;
+        assert_eq!(response.unwrap_err().code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn get_rfq_references_negotiations() {
+        let negotiations = Arc::new(InMemoryNegotiationRepository::new());
+        let service = RfqServiceV2Impl::new(Arc::new(MockRfqRepository::default()))
+            .with_negotiations(negotiations.clone());
+        let rfq = service
+            .create_rfq(Request::new(create_request(v2::RfqDirection::Buy)))
+            .await
+            .unwrap()
+            .into_inner()
+            .rfq
+            .unwrap();
+        let rfq_id = RfqId::try_from(rfq.id.clone().unwrap()).unwrap();
+        let negotiation = Negotiation::new(
+            rfq_id,
+            CounterpartyId::new("client-123"),
+            CounterpartyId::new("mm-1"),
+            OrderSide::Buy,
+            3,
+        )
+        .unwrap();
+        negotiations.save(&negotiation).await.unwrap();
+
+        let fetched = service
+            .get_rfq(Request::new(GetRfqRequest { rfq_id: rfq.id }))
+            .await
+            .unwrap()
+            .into_inner()
+            .rfq
+            .unwrap();
+        let reference = fetched.negotiations.first().unwrap();
+        assert_eq!(fetched.negotiations.len(), 1);
+        assert_eq!(reference.mm_account, "mm-1");
+        assert_eq!(reference.state(), v2::NegotiationState::Open);
+        assert_eq!(
+            reference.id.as_ref().unwrap().value,
+            negotiation.id().to_string()
+        );
+    }
+
+    /// A buy RFQ of `client-123` with three quotes, two tied on price.
+    fn rfq_with_quotes() -> Rfq {
+        use crate::domain::entities::quote::QuoteBuilder;
+        use crate::domain::entities::rfq::RfqBuilder;
+        use crate::domain::value_objects::{Price, Symbol};
+        use rust_decimal::Decimal;
+        use std::str::FromStr;
+
+        let instrument = Instrument::builder(
+            Symbol::new("BTC/USD").unwrap(),
+            crate::domain::value_objects::enums::AssetClass::CryptoSpot,
+        )
+        .build();
+        let mut rfq = RfqBuilder::new(
+            CounterpartyId::new("client-123"),
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(2.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build();
+        rfq.start_quote_collection().unwrap();
+        for (venue, price, quantity) in [
+            ("venue-a", "100.5", 1.0),
+            ("venue-b", "100.12345678901234567", 1.0),
+            ("venue-c", "100.5", 2.0),
+        ] {
+            let quote = QuoteBuilder::new(
+                rfq.id(),
+                VenueId::new(venue),
+                Price::try_from(Decimal::from_str(price).unwrap()).unwrap(),
+                Quantity::new(quantity).unwrap(),
+                Timestamp::now().add_secs(60),
+            )
+            .build();
+            rfq.receive_quote(quote).unwrap();
+        }
+        rfq
+    }
+
+    fn quote_book_request(rfq: &Rfq, client_id: &str) -> Request<GetQuoteBookRequest> {
+        Request::new(GetQuoteBookRequest {
+            rfq_id: Some(v2::Uuid::from(rfq.id())),
+            client_id: client_id.to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn quote_book_order_matches_rest_quote_history() {
+        use crate::api::rest::handlers::QuoteHistoryResponse;
+        use crate::application::use_cases::collect_quotes::rank_quotes;
+
+        let repo = Arc::new(MockRfqRepository::default());
+        let service = RfqServiceV2Impl::new(repo.clone());
+        let mut rfq = rfq_with_quotes();
+        repo.save(&rfq).await.unwrap();
+
+        let collecting = service
+            .get_quote_book(quote_book_request(&rfq, "client-123"))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(collecting.status(), v2::AggregationStatus::Collecting);
+
+        rfq.record_quote_ranks(rank_quotes(&rfq));
+        repo.save(&rfq).await.unwrap();
+        let book = service
+            .get_quote_book(quote_book_request(&rfq, "client-123"))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(book.status(), v2::AggregationStatus::Complete);
+
+        let history = serde_json::to_value(QuoteHistoryResponse::from(&rfq)).unwrap();
+        let mut rows: Vec<(u64, String)> = history["quotes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|row| {
+                (
+                    row["rank"].as_u64().unwrap(),
+                    row["quote_id"].as_str().unwrap().to_string(),
+                )
+            })
+            .collect();
+        rows.sort();
+        let listed: Vec<(u64, String)> = book
+            .quotes
+            .iter()
+            .map(|ranked| {
+                let id = ranked.quote.as_ref().unwrap().id.as_ref().unwrap();
+                (u64::from(ranked.rank), id.value.clone())
+            })
+            .collect();
+        assert_eq!(listed, rows);
+
+        let venues: Vec<&str> = book
+            .quotes
+            .iter()
+            .map(|ranked| ranked.quote.as_ref().unwrap().venue_id.as_str())
+            .collect();
+        assert_eq!(venues, ["venue-b", "venue-c", "venue-a"]);
+        assert!(book.quotes[1].decided_by.is_empty());
+        assert_eq!(book.quotes[2].decided_by, "LARGER_QUANTITY");
+        assert!(book.quotes[0].score > book.quotes[1].score);
+        assert!(
+            book.quotes
+                .iter()
+                .all(|ranked| ranked.all_in_price.is_none())
+        );
+    }
+
+    #[tokio::test]
+    async fn quote_book_prices_round_trip_exactly() {
+        use rust_decimal::Decimal;
+
+        let repo = Arc::new(MockRfqRepository::default());
+        let service = RfqServiceV2Impl::new(repo.clone());
+        let rfq = rfq_with_quotes();
+        repo.save(&rfq).await.unwrap();
+
+        let book = service
+            .get_quote_book(quote_book_request(&rfq, "client-123"))
+            .await
+            .unwrap()
+            .into_inner();
+
+        for ranked in &book.quotes {
+            let proto = ranked.quote.as_ref().unwrap();
+            let id = proto.id.clone().unwrap().value;
+            let quote = rfq
+                .quotes()
+                .iter()
+                .find(|q| q.id().to_string() == id)
+                .unwrap();
+            let price = proto.price.clone().unwrap();
+            assert_eq!(price.value, quote.price().get().to_string());
+            assert_eq!(Decimal::try_from(price).unwrap(), quote.price().get());
+        }
+        assert_eq!(
+            book.quotes[0]
+                .quote
+                .as_ref()
+                .unwrap()
+                .price
+                .as_ref()
+                .unwrap()
+                .value,
+            "100.12345678901234567"
+        );
+    }
+
+    #[tokio::test]
+    async fn quote_book_denied_to_other_clients() {
+        use crate::api::grpc::error_mapping;
+
+        let repo = Arc::new(MockRfqRepository::default());
+        let service = RfqServiceV2Impl::new(repo.clone());
+        let rfq = rfq_with_quotes();
+        repo.save(&rfq).await.unwrap();
+
+        let status = service
+            .get_quote_book(quote_book_request(&rfq, "client-999"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        let info = error_mapping::error_info(&status).unwrap();
+        assert_eq!(info.reason, "UNAUTHORIZED_COUNTERPARTY");
+    }
+
+    #[tokio::test]
+    async fn cancel_rfq_reports_invalid_transition_details() {
+        use crate::api::grpc::error_mapping;
+
+        let service = RfqServiceV2Impl::new(Arc::new(MockRfqRepository::default()));
+        let rfq = service
+            .create_rfq(Request::new(create_request(v2::RfqDirection::Buy)))
+            .await
+            .unwrap()
+            .into_inner()
+            .rfq
+            .unwrap();
+        let cancel = || {
+            Request::new(CancelRfqRequest {
+                rfq_id: rfq.id.clone(),
+                reason: "client request".to_string(),
+            })
+        };
+        service.cancel_rfq(cancel()).await.unwrap();
+
+        let status = service.cancel_rfq(cancel()).await.unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+        let info = error_mapping::error_info(&status).unwrap();
+        assert_eq!(info.reason, "INVALID_STATE_TRANSITION");
+        assert_eq!(info.metadata["from"], RfqState::Cancelled.to_string());
+        assert_eq!(info.metadata["to"], RfqState::Cancelled.to_string());
+    }
+}
@@ -0,0 +1,531 @@
+//! # gRPC API Versioning
+//!
+//! Conversion shims between `otc.rfq.v1` and `otc.rfq.v2` messages.
+//!
+//! The v2 service is the only implementation. The v1 service up-converts
+//! each request to v2 and down-converts each response back to v1:
+//!
+//! - v1 `side` + `two_way` become the v2 `RfqDirection` and back again; a
+//!   two-way RFQ is reported to v1 as `two_way = true` with no side.
+//! - v2-only fields (quote firmness and receipt time, RFQ activation time,
+//!   negotiation references, failure reason, version) are dropped.
+//! - Enum values v1 does not know are reported as `UNSPECIFIED`.
+//!
+//! Shared enums use the same numbering in both packages, so their values
+//! pass through unchanged once checked against the target enum.
+//!
+//! # Examples
+//!
+//! ```
+//! # #![allow(deprecated)]
+//! use otc_rfq::api::grpc::proto::{otc_rfq_v1 as v1, otc_rfq_v2 as v2};
+//!
+//! let request = v1::CreateRfqRequest {
+//!     two_way: true,
+//!     side: v1::OrderSide::Buy as i32,
+//!     ..Default::default()
+//! };
+//! let upgraded = v2::CreateRfqRequest::from(request);
+//! assert_eq!(upgraded.direction(), v2::RfqDirection::TwoWay);
+//! ```
+
+// This module is where the deprecated v1 fields are read and written.
+#![allow(deprecated)]
+
+use crate::api::grpc::proto::{otc_rfq_v1 as v1, otc_rfq_v2 as v2};
+
+/// Returns `value` if it is a known variant of `E`, otherwise `UNSPECIFIED`.
+fn known<E: TryFrom<i32>>(value: i32) -> i32 {
+    if E::try_from(value).is_ok() { value } else { 0 }
+}
+
+// ============================================================================
+// Shared Messages
+// ============================================================================
+
+impl From<v1::Uuid> for v2::Uuid {
+    fn from(id: v1::Uuid) -> Self {
+        Self { value: id.value }
+    }
+}
+
+impl From<v2::Uuid> for v1::Uuid {
+    fn from(id: v2::Uuid) -> Self {
+        Self { value: id.value }
+    }
+}
+
+impl From<v1::Decimal> for v2::Decimal {
+    fn from(d: v1::Decimal) -> Self {
+        Self { value: d.value }
+    }
+}
+
+impl From<v2::Decimal> for v1::Decimal {
+    fn from(d: v2::Decimal) -> Self {
+        Self { value: d.value }
+    }
+}
+
+impl From<v1::Timestamp> for v2::Timestamp {
+    fn from(ts: v1::Timestamp) -> Self {
+        Self {
+            seconds: ts.seconds,
+            nanos: ts.nanos,
+        }
+    }
+}
+
+impl From<v2::Timestamp> for v1::Timestamp {
+    fn from(ts: v2::Timestamp) -> Self {
+        Self {
+            seconds: ts.seconds,
+            nanos: ts.nanos,
+        }
+    }
+}
+
+impl From<v1::Instrument> for v2::Instrument {
+    fn from(inst: v1::Instrument) -> Self {
+        Self {
+            symbol: inst.symbol,
+            asset_class: known::<v2::AssetClass>(inst.asset_class),
+            base_asset: inst.base_asset,
+            quote_asset: inst.quote_asset,
+        }
+    }
+}
+
+impl From<v2::Instrument> for v1::Instrument {
+    fn from(inst: v2::Instrument) -> Self {
+        Self {
+            symbol: inst.symbol,
+            asset_class: known::<v1::AssetClass>(inst.asset_class),
+            base_asset: inst.base_asset,
+            quote_asset: inst.quote_asset,
+        }
+    }
+}
+
+impl From<v1::SizeNegotiation> for v2::SizeNegotiation {
+    fn from(size_mode: v1::SizeNegotiation) -> Self {
+        Self {
+            mode: known::<v2::SizeMode>(size_mode.mode),
+            min_quantity: size_mode.min_quantity.map(Into::into),
+        }
+    }
+}
+
+impl From<v2::SizeNegotiation> for v1::SizeNegotiation {
+    fn from(size_mode: v2::SizeNegotiation) -> Self {
+        Self {
+            mode: known::<v1::SizeMode>(size_mode.mode),
+            min_quantity: size_mode.min_quantity.map(Into::into),
+        }
+    }
+}
+
+impl From<v1::QuantityDisclosure> for v2::QuantityDisclosure {
+    fn from(disclosure: v1::QuantityDisclosure) -> Self {
+        Self {
+            mode: known::<v2::DisclosureMode>(disclosure.mode),
+            bucket: disclosure.bucket.map(Into::into),
+        }
+    }
+}
+
+impl From<v2::QuantityDisclosure> for v1::QuantityDisclosure {
+    fn from(disclosure: v2::QuantityDisclosure) -> Self {
+        Self {
+            mode: known::<v1::DisclosureMode>(disclosure.mode),
+            bucket: disclosure.bucket.map(Into::into),
+        }
+    }
+}
+
+impl From<v1::MmCredentials> for v2::MmCredentials {
+    fn from(credentials: v1::MmCredentials) -> Self {
+        Self {
+            mm_id: credentials.mm_id,
+            api_key: credentials.api_key,
+        }
+    }
+}
+
+/// Drops quote firmness and receipt time.
+impl From<v2::Quote> for v1::Quote {
+    fn from(quote: v2::Quote) -> Self {
+        Self {
+            id: quote.id.map(Into::into),
+            rfq_id: quote.rfq_id.map(Into::into),
+            venue_id: quote.venue_id,
+            venue_type: v1::VenueType::Unspecified as i32,
+            price: quote.price.map(Into::into),
+            quantity: quote.quantity.map(Into::into),
+            commission: quote.commission.map(Into::into),
+            valid_until: quote.valid_until.map(Into::into),
+            created_at: quote.created_at.map(Into::into),
+            side: known::<v1::OrderSide>(quote.side),
+        }
+    }
+}
+
+impl From<v2::FeeComponent> for v1::FeeComponent {
+    fn from(fee: v2::FeeComponent) -> Self {
+        Self {
+            kind: known::<v1::FeeKind>(fee.kind),
+            amount: fee.amount.map(Into::into),
+            currency: fee.currency,
+        }
+    }
+}
+
+impl From<v2::TradeAllocation> for v1::TradeAllocation {
+    fn from(allocation: v2::TradeAllocation) -> Self {
+        Self {
+            venue_id: allocation.venue_id,
+            quote_id: allocation.quote_id.map(Into::into),
+            quantity: allocation.quantity.map(Into::into),
+            price: allocation.price.map(Into::into),
+            scheduled_at: allocation.scheduled_at.map(Into::into),
+            status: known::<v1::AllocationStatus>(allocation.status),
+            failure_reason: allocation.failure_reason,
+        }
+    }
+}
+
+impl From<v2::Trade> for v1::Trade {
+    fn from(trade: v2::Trade) -> Self {
+        Self {
+            id: trade.id.map(Into::into),
+            rfq_id: trade.rfq_id.map(Into::into),
+            quote_id: trade.quote_id.map(Into::into),
+            venue_id: trade.venue_id,
+            price: trade.price.map(Into::into),
+            quantity: trade.quantity.map(Into::into),
+            venue_execution_ref: trade.venue_execution_ref,
+            created_at: trade.created_at.map(Into::into),
+            fees: trade.fees.into_iter().map(Into::into).collect(),
+            reference_price_at_execution: trade.reference_price_at_execution.map(Into::into),
+            allocations: trade.allocations.into_iter().map(Into::into).collect(),
+            allocation_vwap: trade.allocation_vwap.map(Into::into),
+        }
+    }
+}
+
+/// Splits the direction into `side` and `two_way` and drops the activation
+/// time, negotiation references, failure reason and version.
+impl From<v2::Rfq> for v1::Rfq {
+    fn from(rfq: v2::Rfq) -> Self {
+        let (side, two_way) = match rfq.direction() {
+            v2::RfqDirection::Buy => (v1::OrderSide::Buy, false),
+            v2::RfqDirection::Sell => (v1::OrderSide::Sell, false),
+            v2::RfqDirection::TwoWay => (v1::OrderSide::Unspecified, true),
+            v2::RfqDirection::Unspecified => (v1::OrderSide::Unspecified, false),
+        };
+        Self {
+            id: rfq.id.map(Into::into),
+            client_id: rfq.client_id,
+            instrument: rfq.instrument.map(Into::into),
+            side: side as i32,
+            quantity: rfq.quantity.map(Into::into),
+            state: known::<v1::RfqState>(rfq.state),
+            expires_at: rfq.expires_at.map(Into::into),
+            quotes: rfq.quotes.into_iter().map(Into::into).collect(),
+            selected_quote_id: rfq.selected_quote_id.map(Into::into),
+            created_at: rfq.created_at.map(Into::into),
+            updated_at: rfq.updated_at.map(Into::into),
+            size_mode: rfq.size_mode.map(Into::into),
+            quantity_disclosure: rfq.quantity_disclosure.map(Into::into),
+            two_way,
+        }
+    }
+}
+
+impl From<v2::RfqBroadcast> for v1::RfqBroadcast {
+    fn from(broadcast: v2::RfqBroadcast) -> Self {
+        Self {
+            rfq_id: broadcast.rfq_id.map(Into::into),
+            instrument: broadcast.instrument.map(Into::into),
+            side: known::<v1::OrderSide>(broadcast.side),
+            quantity: broadcast.quantity.map(Into::into),
+            expires_at: broadcast.expires_at.map(Into::into),
+            requester: broadcast.requester,
+            anonymous: broadcast.anonymous,
+        }
+    }
+}
+
+// ============================================================================
+// Requests (v1 -> v2)
+// ============================================================================
+
+/// Folds `side` and `two_way` into a direction; `two_way` wins over `side`.
+impl From<v1::CreateRfqRequest> for v2::CreateRfqRequest {
+    fn from(request: v1::CreateRfqRequest) -> Self {
+        let direction = if request.two_way {
+            v2::RfqDirection::TwoWay
+        } else {
+            match v1::OrderSide::try_from(request.side) {
+                Ok(v1::OrderSide::Buy) => v2::RfqDirection::Buy,
+                Ok(v1::OrderSide::Sell) => v2::RfqDirection::Sell,
+                Ok(v1::OrderSide::Unspecified) | Err(_) => v2::RfqDirection::Unspecified,
+            }
+        };
+        Self {
+            client_id: request.client_id,
+            instrument: request.instrument.map(Into::into),
+            direction: direction as i32,
+            quantity: request.quantity.map(Into::into),
+            timeout_seconds: request.timeout_seconds,
+            size_mode: request.size_mode.map(Into::into),
+            quantity_disclosure: request.quantity_disclosure.map(Into::into),
+        }
+    }
+}
+
+impl From<v1::GetRfqRequest> for v2::GetRfqRequest {
+    fn from(request: v1::GetRfqRequest) -> Self {
+        Self {
+            rfq_id: request.rfq_id.map(Into::into),
+        }
+    }
+}
+
+impl From<v1::GetQuotesRequest> for v2::GetQuotesRequest {
+    fn from(request: v1::GetQuotesRequest) -> Self {
+        Self {
+            rfq_id: request.rfq_id.map(Into::into),
+        }
+    }
+}
+
+impl From<v1::ExecuteTradeRequest> for v2::ExecuteTradeRequest {
+    fn from(request: v1::ExecuteTradeRequest) -> Self {
+        Self {
+            rfq_id: request.rfq_id.map(Into::into),
+            quote_id: request.quote_id.map(Into::into),
+        }
+    }
+}
+
+impl From<v1::CancelRfqRequest> for v2::CancelRfqRequest {
+    fn from(request: v1::CancelRfqRequest) -> Self {
+        Self {
+            rfq_id: request.rfq_id.map(Into::into),
+            reason: request.reason,
+        }
+    }
+}
+
+impl From<v1::StreamRfqStatusRequest> for v2::StreamRfqStatusRequest {
+    fn from(request: v1::StreamRfqStatusRequest) -> Self {
+        Self {
+            rfq_id: request.rfq_id.map(Into::into),
+            resume_from: v2::RfqState::Unspecified as i32,
+        }
+    }
+}
+
+/// Keeps an unknown side value so v2 rejects it rather than treating it as
+/// unspecified.
+impl From<v1::SubmitQuoteRequest> for v2::SubmitQuoteRequest {
+    fn from(request: v1::SubmitQuoteRequest) -> Self {
+        Self {
+            rfq_id: request.rfq_id.map(Into::into),
+            price: request.price.map(Into::into),
+            quantity: request.quantity.map(Into::into),
+            valid_until: request.valid_until.map(Into::into),
+            credentials: request.credentials.map(Into::into),
+            side: request.side,
+        }
+    }
+}
+
+impl From<v1::SubscribeRfqsRequest> for v2::SubscribeRfqsRequest {
+    fn from(request: v1::SubscribeRfqsRequest) -> Self {
+        Self {
+            credentials: request.credentials.map(Into::into),
+        }
+    }
+}
+
+// ============================================================================
+// Responses (v2 -> v1)
+// ============================================================================
+
+impl From<v2::CreateRfqResponse> for v1::CreateRfqResponse {
+    fn from(response: v2::CreateRfqResponse) -> Self {
+        Self {
+            rfq: response.rfq.map(Into::into),
+        }
+    }
+}
+
+impl From<v2::GetRfqResponse> for v1::GetRfqResponse {
+    fn from(response: v2::GetRfqResponse) -> Self {
+        Self {
+            rfq: response.rfq.map(Into::into),
+        }
+    }
+}
+
+impl From<v2::GetQuotesResponse> for v1::GetQuotesResponse {
+    fn from(response: v2::GetQuotesResponse) -> Self {
+        Self {
+            quote: response.quote.map(Into::into),
+            is_final: response.is_final,
+        }
+    }
+}
+
+impl From<v2::ExecuteTradeResponse> for v1::ExecuteTradeResponse {
+    fn from(response: v2::ExecuteTradeResponse) -> Self {
+        Self {
+            trade: response.trade.map(Into::into),
+        }
+    }
+}
+
+impl From<v2::CancelRfqResponse> for v1::CancelRfqResponse {
+    fn from(response: v2::CancelRfqResponse) -> Self {
+        Self {
+            rfq: response.rfq.map(Into::into),
+        }
+    }
+}
+
+impl From<v2::StreamRfqStatusResponse> for v1::StreamRfqStatusResponse {
+    fn from(response: v2::StreamRfqStatusResponse) -> Self {
+        Self {
+            rfq_id: response.rfq_id.map(Into::into),
+            previous_state: known::<v1::RfqState>(response.previous_state),
+            current_state: known::<v1::RfqState>(response.current_state),
+            message: response.message,
+            timestamp: response.timestamp.map(Into::into),
+        }
+    }
+}
+
+impl From<v2::SubmitQuoteResponse> for v1::SubmitQuoteResponse {
+    fn from(response: v2::SubmitQuoteResponse) -> Self {
+        Self {
+            quote: response.quote.map(Into::into),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    /// Shared enums must keep identical numbering for the pass-through.
+    #[test]
+    fn shared_enums_use_the_same_numbering() {
+        for value in 0..16 {
+            let names = |a: Option<&str>, b: Option<&str>| assert_eq!(a, b, "value {value}");
+            names(
+                v1::RfqState::try_from(value).ok().map(|e| e.as_str_name()),
+                v2::RfqState::try_from(value).ok().map(|e| e.as_str_name()),
+            );
+            names(
+                v1::OrderSide::try_from(value).ok().map(|e| e.as_str_name()),
+                v2::OrderSide::try_from(value).ok().map(|e| e.as_str_name()),
+            );
+            names(
+                v1::AssetClass::try_from(value)
+                    .ok()
+                    .map(|e| e.as_str_name()),
+                v2::AssetClass::try_from(value)
+                    .ok()
+                    .map(|e| e.as_str_name()),
+            );
+            names(
+                v1::SizeMode::try_from(value).ok().map(|e| e.as_str_name()),
+                v2::SizeMode::try_from(value).ok().map(|e| e.as_str_name()),
+            );
+            names(
+                v1::DisclosureMode::try_from(value)
+                    .ok()
+                    .map(|e| e.as_str_name()),
+                v2::DisclosureMode::try_from(value)
+                    .ok()
+                    .map(|e| e.as_str_name()),
+            );
+            names(
+                v1::FeeKind::try_from(value).ok().map(|e| e.as_str_name()),
+                v2::FeeKind::try_from(value).ok().map(|e| e.as_str_name()),
+            );
+            names(
+                v1::AllocationStatus::try_from(value)
+                    .ok()
+                    .map(|e| e.as_str_name()),
+                v2::AllocationStatus::try_from(value)
+                    .ok()
+                    .map(|e| e.as_str_name()),
+            );
+        }
+    }
+
+    #[test]
+    fn create_request_folds_side_and_two_way_into_direction() {
+        let request = |side: v1::OrderSide, two_way: bool| {
+            v2::CreateRfqRequest::from(v1::CreateRfqRequest {
+                side: side as i32,
+                two_way,
+                ..Default::default()
+            })
+            .direction()
+        };
+        assert_eq!(request(v1::OrderSide::Buy, false), v2::RfqDirection::Buy);
+        assert_eq!(request(v1::OrderSide::Sell, false), v2::RfqDirection::Sell);
+        assert_eq!(request(v1::OrderSide::Sell, true), v2::RfqDirection::TwoWay);
+        assert_eq!(
+            request(v1::OrderSide::Unspecified, false),
+            v2::RfqDirection::Unspecified
+        );
+    }
+
+    #[test]
+    fn rfq_downgrade_splits_direction_and_drops_v2_fields() {
+        let rfq = v2::Rfq {
+            direction: v2::RfqDirection::TwoWay as i32,
+            state: v2::RfqState::Failed as i32,
+            failure_reason: "venue rejected".to_string(),
+            version: 7,
+            negotiations: vec![v2::NegotiationRef::default()],
+            quotes: vec![v2::Quote {
+                side: v2::OrderSide::Sell as i32,
+                firmness: v2::QuoteFirmness::Indicative as i32,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let downgraded = v1::Rfq::from(rfq);
+        assert!(downgraded.two_way);
+        assert_eq!(downgraded.side(), v1::OrderSide::Unspecified);
+        assert_eq!(downgraded.state(), v1::RfqState::Failed);
+        assert_eq!(
+            downgraded.quotes.first().unwrap().side(),
+            v1::OrderSide::Sell
+        );
+
+        let one_way = v1::Rfq::from(v2::Rfq {
+            direction: v2::RfqDirection::Buy as i32,
+            ..Default::default()
+        });
+        assert!(!one_way.two_way);
+        assert_eq!(one_way.side(), v1::OrderSide::Buy);
+    }
+
+    #[test]
+    fn unknown_enum_values_downgrade_to_unspecified() {
+        let status = v1::StreamRfqStatusResponse::from(v2::StreamRfqStatusResponse {
+            current_state: 99,
+            ..Default::default()
+        });
+        assert_eq!(status.current_state, v1::RfqState::Unspecified as i32);
+    }
+}
@@ -0,0 +1,223 @@
+//! # Best Execution Report
+//!
+//! REST representation of a client's best execution report.
+//!
+//! [`BestExecutionReportResponse`] is the JSON body of the report endpoint;
+//! [`to_csv`] renders the same report as a single RFC 4180 table with one
+//! `SUMMARY` row followed by one `EXCEPTION` row per trade that did not take
+//! the best price. Columns that do not apply to a row are left empty.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::api::rest::best_execution::to_csv;
+//! use otc_rfq::domain::value_objects::{BestExecutionReport, CounterpartyId, Timestamp};
+//!
+//! let report = BestExecutionReport {
+//!     client_id: CounterpartyId::new("client-1"),
+//!     from: Timestamp::from_millis(0).unwrap(),
+//!     to: Timestamp::from_millis(86_400_000).unwrap(),
+//!     rfq_count: 0,
+//!     executed_count: 0,
+//!     average_venues_contacted: None,
+//!     best_price_selected_pct: None,
+//!     average_improvement_bps: None,
+//!     exceptions: Vec::new(),
+//!     traded_notional: None,
+//!     fx_rates: Vec::new(),
+//!     generated_at: Timestamp::from_millis(86_400_000).unwrap(),
+//! };
+//!
+//! let csv = to_csv(&report);
+//! assert!(csv.starts_with("record,client_id,from,to,"));
+//! assert_eq!(csv.lines().count(), 2);
+//! ```
+
+use crate::api::rest::timeline::push_csv_row;
+use crate::domain::value_objects::{
+    BestExecutionException, BestExecutionReport, MissedBestPriceReason,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+/// CSV header row, in column order.
+const CSV_HEADER: [&str; 17] = [
+    "record",
+    "client_id",
+    "from",
+    "to",
+    "rfq_count",
+    "executed_count",
+    "average_venues_contacted",
+    "best_price_selected_pct",
+    "average_improvement_bps",
+    "rfq_id",
+    "trade_id",
+    "side",
+    "selected_quote_id",
+    "selected_price",
+    "best_quote_id",
+    "best_price",
+    "reason",
+];
+
+/// Output format of the best execution report endpoint.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BestExecutionFormat {
+    /// JSON report.
+    #[default]
+    Json,
+    /// RFC 4180 CSV export.
+    Csv,
+}
+
+/// Query parameters of the best execution report endpoint.
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BestExecutionParams {
+    /// Client to report on; defaults to the caller. Only admins may name
+    /// another client.
+    pub client_id: Option<String>,
+    /// Start of the period, inclusive (RFC 3339).
+    pub from: String,
+    /// End of the period, exclusive (RFC 3339).
+    pub to: String,
+    /// Output format (`json` or `csv`, default `json`).
+    #[serde(default)]
+    pub format: BestExecutionFormat,
+}
+
+/// A trade that did not take the best price available.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BestExecutionExceptionResponse {
+    /// RFQ ID (UUID).
+    pub rfq_id: String,
+    /// Trade ID (UUID).
+    pub trade_id: String,
+    /// Side the client traded (`BUY` or `SELL`).
+    pub side: String,
+    /// ID of the quote traded.
+    pub selected_quote_id: String,
+    /// Price of the quote traded.
+    pub selected_price: String,
+    /// ID of the best-priced quote received before execution.
+    pub best_quote_id: String,
+    /// Price of the best quote.
+    pub best_price: String,
+    /// Why the best quote was not traded.
+    pub reason: MissedBestPriceReason,
+}
+
+impl From<&BestExecutionException> for BestExecutionExceptionResponse {
+    fn from(exception: &BestExecutionException) -> Self {
+        Self {
+            rfq_id: exception.rfq_id.to_string(),
+            trade_id: exception.trade_id.to_string(),
+            side: exception.side.to_string(),
+            selected_quote_id: exception.selected_quote_id.to_string(),
+            selected_price: exception.selected_price.to_string(),
+            best_quote_id: exception.best_quote_id.to_string(),
+            best_price: exception.best_price.to_string(),
+            reason: exception.reason,
+        }
+    }
+}
+
+/// Best execution report of one client over a period.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BestExecutionReportResponse {
+    /// Client reported on.
+    pub client_id: String,
+    /// Start of the period, inclusive (RFC 3339).
+    pub from: String,
+    /// End of the period, exclusive (RFC 3339).
+    pub to: String,
+    /// RFQs the client created in the period.
+    pub rfq_count: u64,
+    /// Those RFQs that were traded.
+    pub executed_count: u64,
+    /// Average number of venues asked to quote.
+    pub average_venues_contacted: Option<f64>,
+    /// Percentage of traded RFQs that took the best price available.
+    pub best_price_selected_pct: Option<f64>,
+    /// Average improvement on the reference price at execution, in bps.
+    pub average_improvement_bps: Option<f64>,
+    /// Trades that did not take the best price.
+    pub exceptions: Vec<BestExecutionExceptionResponse>,
+    /// Total notional traded in the base currency (e.g. `1250000 USD`),
+    /// if notionals are normalized.
+    pub traded_notional: Option<String>,
+    /// When the report was generated (RFC 3339).
+    pub generated_at: String,
+}
+
+impl From<&BestExecutionReport> for BestExecutionReportResponse {
+    fn from(report: &BestExecutionReport) -> Self {
+        Self {
+            client_id: report.client_id.to_string(),
+            from: report.from.to_string(),
+            to: report.to.to_string(),
+            rfq_count: report.rfq_count,
+            executed_count: report.executed_count,
+            average_venues_contacted: report.average_venues_contacted,
+            best_price_selected_pct: report.best_price_selected_pct,
+            average_improvement_bps: report.average_improvement_bps,
+            exceptions: report
+                .exceptions
+                .iter()
+                .map(BestExecutionExceptionResponse::from)
+                .collect(),
+            traded_notional: report.traded_notional.as_ref().map(ToString::to_string),
+            generated_at: report.generated_at.to_string(),
+        }
+    }
+}
+
+/// Renders a report as RFC 4180 CSV: a `SUMMARY` row, then one
+/// `EXCEPTION` row per exception.
+#[must_use]
+pub fn to_csv(report: &BestExecutionReport) -> String {
+    let optional = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+
+    let mut out = String::new();
+    push_csv_row(&mut out, CSV_HEADER);
+    let summary = [
+        "SUMMARY".to_string(),
+        report.client_id.to_string(),
+        report.from.to_string(),
+        report.to.to_string(),
+        report.rfq_count.to_string(),
+        report.executed_count.to_string(),
+        optional(report.average_venues_contacted),
+        optional(report.best_price_selected_pct),
+        optional(report.average_improvement_bps),
+    ];
+    push_csv_row(
+        &mut out,
+        summary
+            .iter()
+            .map(String::as_str)
+            .chain(std::iter::repeat_n("", CSV_HEADER.len() - summary.len())),
+    );
+    for exception in &report.exceptions {
+        let row = BestExecutionExceptionResponse::from(exception);
+        let fields = [
+            row.rfq_id,
+            row.trade_id,
+            row.side,
+            row.selected_quote_id,
+            row.selected_price,
+            row.best_quote_id,
+            row.best_price,
+            row.reason.to_string(),
+        ];
+        push_csv_row(
+            &mut out,
+            std::iter::once("EXCEPTION")
+                .chain(std::iter::repeat_n("", CSV_HEADER.len() - fields.len() - 1))
+                .chain(fields.iter().map(String::as_str)),
+        );
+    }
+    out
+}
@@ -0,0 +1,168 @@
+//! # Decimal Wire Format
+//!
+//! Serde helpers for prices and quantities in REST payloads.
+//!
+//! Decimals travel as JSON strings such as `"50000.123456789012345678"`.
+//! JavaScript clients parse JSON numbers as doubles, which lose precision
+//! above 2^53 and on 18-decimal token quantities. DTO fields opt in with
+//! `#[serde(with = "decimal")]` (or `decimal::option`); the domain types
+//! keep their own serde, so event store and database payloads are
+//! unaffected.
+//!
+//! Requests still accept JSON numbers for one deprecation cycle. A number
+//! is converted exactly as a float would have been before strings were
+//! accepted, so it may already have lost precision in transit.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::api::rest::decimal;
+//! use rust_decimal::Decimal;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Order {
+//!     #[serde(with = "decimal")]
+//!     quantity: Decimal,
+//! }
+//!
+//! let order: Order = serde_json::from_str(r#"{"quantity":"0.000000000000000001"}"#).unwrap();
+//! assert_eq!(
+//!     serde_json::to_string(&order).unwrap(),
+//!     r#"{"quantity":"0.000000000000000001"}"#,
+//! );
+//!
+//! // Deprecated, but still accepted
+//! let order: Order = serde_json::from_str(r#"{"quantity":1.5}"#).unwrap();
+//! assert_eq!(order.quantity, Decimal::new(15, 1));
+//! ```
+
+use rust_decimal::Decimal;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// A decimal as it may arrive in a request.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DecimalInput {
+    Text(String),
+    Number(f64),
+}
+
+impl DecimalInput {
+    fn into_decimal<E: serde::de::Error>(self) -> Result<Decimal, E> {
+        match self {
+            // Exact parsing refuses to silently round excess digits
+            Self::Text(text) => Decimal::from_str_exact(text.trim())
+                .map_err(|e| E::custom(format!("invalid decimal {text:?}: {e}"))),
+            Self::Number(number) => Decimal::try_from(number)
+                .map_err(|e| E::custom(format!("invalid decimal {number}: {e}"))),
+        }
+    }
+}
+
+/// Serializes `value` as a JSON string.
+///
+/// # Errors
+///
+/// Returns the serializer's error.
+pub fn serialize<S: Serializer>(value: &Decimal, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+/// Deserializes a decimal from a JSON string or, deprecated, a number.
+///
+/// # Errors
+///
+/// Returns an error if the value is neither, or is not a valid decimal.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+    DecimalInput::deserialize(deserializer)
+        .map_err(|_| D::Error::custom("expected a decimal string"))?
+        .into_decimal()
+}
+
+/// The same format for optional decimals; `null` is `None`.
+pub mod option {
+    use super::DecimalInput;
+    use rust_decimal::Decimal;
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes `value` as a JSON string, or `null`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer's error.
+    pub fn serialize<S: Serializer>(
+        value: &Option<Decimal>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => super::serialize(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes an optional decimal from a JSON string, a number
+    /// (deprecated) or `null`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not `null` or a valid decimal.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Decimal>, D::Error> {
+        Option::<DecimalInput>::deserialize(deserializer)
+            .map_err(|_| D::Error::custom("expected a decimal string"))?
+            .map(DecimalInput::into_decimal)
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+    use std::str::FromStr;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Amounts {
+        #[serde(with = "super")]
+        quantity: Decimal,
+        #[serde(default, with = "super::option")]
+        price: Option<Decimal>,
+    }
+
+    #[test]
+    fn eighteen_decimals_round_trip_as_strings() {
+        let json = r#"{"quantity":"1234567.123456789012345678","price":null}"#;
+
+        let amounts: Amounts = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            amounts.quantity,
+            Decimal::from_str("1234567.123456789012345678").unwrap()
+        );
+        assert_eq!(serde_json::to_string(&amounts).unwrap(), json);
+    }
+
+    #[test]
+    fn numbers_are_still_accepted() {
+        let amounts: Amounts = serde_json::from_str(r#"{"quantity":2,"price":50000.25}"#).unwrap();
+
+        assert_eq!(amounts.quantity, Decimal::TWO);
+        assert_eq!(amounts.price, Some(Decimal::new(5_000_025, 2)));
+    }
+
+    #[test]
+    fn rejects_malformed_and_overlong_decimals() {
+        for json in [
+            r#"{"quantity":"abc"}"#,
+            r#"{"quantity":true}"#,
+            r#"{"quantity":"0.00000000000000000000000000001"}"#,
+        ] {
+            assert!(serde_json::from_str::<Amounts>(json).is_err(), "{json}");
+        }
+    }
+}
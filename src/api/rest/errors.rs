@@ -0,0 +1,1136 @@
+//! # REST Error Mapping
+//!
+//! Stable error code taxonomy for REST error responses.
+//!
+//! Every error surfaced by the REST API carries a machine-readable
+//! [`ErrorCode`] so clients can branch on `code` instead of matching on the
+//! human-readable `message`. This module is the single place where
+//! [`DomainError`], [`RepositoryError`], and [`ApplicationError`] are
+//! translated into an HTTP status, a code, and an optional structured
+//! `details` object.
+//!
+//! # Codes
+//!
+//! | Code | Status |
+//! |------|--------|
+//! | `VALIDATION_ERROR`, `INVALID_QUANTITY`, `INVALID_PRICE`, `INVALID_LOT_SIZE`, `INVALID_TICK_SIZE`, `INVALID_CURSOR` | 400 |
+//! | `UNAUTHORIZED` | 401 |
+//! | `COMPLIANCE_FAILED`, `UNAUTHORIZED_COUNTERPARTY` | 403 |
+//! | `NOT_FOUND`, `RFQ_NOT_FOUND`, `QUOTE_NOT_FOUND` | 404 |
+//! | `RFQ_INVALID_STATE`, `QUOTE_EXPIRED`, `VERSION_CONFLICT`, `FIRM_UP_PRICE_MOVED`, `EXECUTION_IN_PROGRESS` | 409 |
+//! | `INSUFFICIENT_LIQUIDITY`, `MIN_QUANTITY_NOT_MET`, `INVALID_MIN_QUANTITY`, `NO_ELIGIBLE_VENUES`, `QUORUM_NOT_MET`, `LIMIT_EXCEEDED`, `RFQ_TTL_OUT_OF_RANGE` | 422 |
+//! | `INTERNAL_ERROR` | 500 |
+//!
+//! See [`ErrorCode`] for the full list.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::api::rest::errors::{ErrorCode, domain_error_code};
+//! use otc_rfq::domain::errors::DomainError;
+//!
+//! let err = DomainError::QuoteExpired("quote-1".to_string());
+//! assert_eq!(domain_error_code(&err), ErrorCode::QuoteExpired);
+//! assert_eq!(ErrorCode::QuoteExpired.as_str(), "QUOTE_EXPIRED");
+//! ```
+
+use crate::api::rest::handlers::ErrorResponse;
+use crate::application::error::{ApplicationError, InfrastructureError};
+use crate::domain::errors::DomainError;
+use crate::infrastructure::metrics;
+use crate::infrastructure::persistence::{ConstraintKind, RepositoryError};
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{FromRequest, Request};
+use axum::{Json, http::StatusCode};
+use serde_json::json;
+use std::fmt;
+
+/// Error type returned by REST handlers.
+pub type ApiError = (StatusCode, Json<ErrorResponse>);
+
+/// Stable, machine-readable error codes for REST responses.
+///
+/// The string form returned by [`ErrorCode::as_str`] is part of the public
+/// API contract and must not change once released.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    // 400
+    /// Request failed validation.
+    ValidationError,
+    /// Quantity is invalid.
+    InvalidQuantity,
+    /// Price is invalid.
+    InvalidPrice,
+    /// Quantity is not a multiple of the instrument's lot size.
+    InvalidLotSize,
+    /// Price is not a multiple of the instrument's tick size.
+    InvalidTickSize,
+    /// Package quote is malformed or inconsistent.
+    InvalidPackageQuote,
+    /// Instrument is not supported.
+    InstrumentNotSupported,
+    /// Token cannot be settled on the instrument's chain.
+    SettlementTokenUnavailable,
+    /// Pagination cursor is malformed or was tampered with.
+    InvalidCursor,
+
+    // 401 / 403
+    /// Authentication required or failed.
+    Unauthorized,
+    /// Caller lacks the role required for the operation.
+    Forbidden,
+    /// Compliance check rejected the request.
+    ComplianceFailed,
+    /// Counterparty is not authorized for the operation.
+    UnauthorizedCounterparty,
+    /// Client account is not active.
+    ClientNotActive,
+
+    // 404
+    /// Generic resource not found.
+    NotFound,
+    /// RFQ not found.
+    RfqNotFound,
+    /// Quote not found.
+    QuoteNotFound,
+
+    // 409
+    /// RFQ is not in a state that allows the operation.
+    RfqInvalidState,
+    /// Entity is not in a state that allows the operation.
+    InvalidState,
+    /// Negotiation is not in a state that allows the operation.
+    NegotiationInvalidState,
+    /// Operation not allowed in the current state.
+    OperationNotAllowed,
+    /// Quote has expired.
+    QuoteExpired,
+    /// Quote is locked by another operation.
+    QuoteLocked,
+    /// Lock could not be acquired.
+    LockAcquisitionFailed,
+    /// Concurrent modification detected.
+    Conflict,
+    /// The RFQ is already being executed.
+    ExecutionInProgress,
+    /// Entity already exists.
+    Duplicate,
+    /// Optimistic locking conflict.
+    VersionConflict,
+    /// Maximum negotiation rounds reached.
+    MaxNegotiationRounds,
+    /// Market maker rejected during last-look.
+    LastLookRejected,
+    /// Firm-up moved the quoted price beyond tolerance.
+    FirmUpPriceMoved,
+
+    // 422
+    /// Not enough liquidity to fill the requested quantity.
+    InsufficientLiquidity,
+    /// Fill quantity is below the required minimum.
+    MinQuantityNotMet,
+    /// Minimum fill quantity is not positive or exceeds the quantity.
+    InvalidMinQuantity,
+    /// The RFQ's venue allowlist and blocklist leave no venue to query.
+    NoEligibleVenues,
+    /// Too few competing quotes to select one without an override.
+    QuorumNotMet,
+    /// Allocations do not sum to the target quantity.
+    AllocationMismatch,
+    /// No reference price is available.
+    NoReferencePrice,
+    /// Price deviates too far from the reference.
+    PriceOutOfBounds,
+    /// Risk check rejected the request.
+    RiskCheckFailed,
+    /// Counter-quote does not improve on the previous price.
+    NoPriceImprovement,
+    /// A capacity or exposure limit would be exceeded.
+    LimitExceeded,
+    /// Collateral could not be locked.
+    CollateralLockFailed,
+    /// The client holds too little collateral for the trade.
+    InsufficientCollateral,
+    /// The requested RFQ time-to-live is outside its asset class's limits.
+    RfqTtlOutOfRange,
+
+    // 5xx
+    /// Unexpected internal failure.
+    InternalError,
+    /// Arithmetic overflow, underflow, or division by zero.
+    ArithmeticError,
+    /// Trade execution failed.
+    ExecutionFailed,
+    /// Settlement failed.
+    SettlementFailed,
+    /// Rollback of a partial execution failed.
+    RollbackFailed,
+    /// Fee calculation failed.
+    FeeCalculationFailed,
+    /// Feature is not configured on this deployment.
+    NotImplemented,
+    /// Upstream venue returned an error.
+    VenueError,
+    /// Confirmation could not be delivered.
+    ConfirmationFailed,
+    /// A backing service is unavailable.
+    ServiceUnavailable,
+    /// The venue is not available.
+    VenueUnavailable,
+    /// The service is shutting down and refuses new work.
+    ShuttingDown,
+    /// Operation timed out.
+    Timeout,
+}
+
+impl ErrorCode {
+    /// Returns the stable string form of this code.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ValidationError => "VALIDATION_ERROR",
+            Self::InvalidQuantity => "INVALID_QUANTITY",
+            Self::InvalidPrice => "INVALID_PRICE",
+            Self::InvalidLotSize => "INVALID_LOT_SIZE",
+            Self::InvalidTickSize => "INVALID_TICK_SIZE",
+            Self::InvalidPackageQuote => "INVALID_PACKAGE_QUOTE",
+            Self::InstrumentNotSupported => "INSTRUMENT_NOT_SUPPORTED",
+            Self::SettlementTokenUnavailable => "SETTLEMENT_TOKEN_UNAVAILABLE",
+            Self::InvalidCursor => "INVALID_CURSOR",
+            Self::Unauthorized => "UNAUTHORIZED",
+            Self::Forbidden => "FORBIDDEN",
+            Self::ComplianceFailed => "COMPLIANCE_FAILED",
+            Self::UnauthorizedCounterparty => "UNAUTHORIZED_COUNTERPARTY",
+            Self::ClientNotActive => "CLIENT_NOT_ACTIVE",
+            Self::NotFound => "NOT_FOUND",
+            Self::RfqNotFound => "RFQ_NOT_FOUND",
+            Self::QuoteNotFound => "QUOTE_NOT_FOUND",
+            Self::RfqInvalidState => "RFQ_INVALID_STATE",
+            Self::InvalidState => "INVALID_STATE",
+            Self::NegotiationInvalidState => "NEGOTIATION_INVALID_STATE",
+            Self::OperationNotAllowed => "OPERATION_NOT_ALLOWED",
+            Self::QuoteExpired => "QUOTE_EXPIRED",
+            Self::QuoteLocked => "QUOTE_LOCKED",
+            Self::LockAcquisitionFailed => "LOCK_ACQUISITION_FAILED",
+            Self::Conflict => "CONFLICT",
+            Self::ExecutionInProgress => "EXECUTION_IN_PROGRESS",
+            Self::Duplicate => "DUPLICATE",
+            Self::VersionConflict => "VERSION_CONFLICT",
+            Self::MaxNegotiationRounds => "MAX_NEGOTIATION_ROUNDS",
+            Self::LastLookRejected => "LAST_LOOK_REJECTED",
+            Self::FirmUpPriceMoved => "FIRM_UP_PRICE_MOVED",
+            Self::InsufficientLiquidity => "INSUFFICIENT_LIQUIDITY",
+            Self::MinQuantityNotMet => "MIN_QUANTITY_NOT_MET",
+            Self::InvalidMinQuantity => "INVALID_MIN_QUANTITY",
+            Self::NoEligibleVenues => "NO_ELIGIBLE_VENUES",
+            Self::QuorumNotMet => "QUORUM_NOT_MET",
+            Self::AllocationMismatch => "ALLOCATION_MISMATCH",
+            Self::NoReferencePrice => "NO_REFERENCE_PRICE",
+            Self::PriceOutOfBounds => "PRICE_OUT_OF_BOUNDS",
+            Self::RiskCheckFailed => "RISK_CHECK_FAILED",
+            Self::NoPriceImprovement => "NO_PRICE_IMPROVEMENT",
+            Self::LimitExceeded => "LIMIT_EXCEEDED",
+            Self::CollateralLockFailed => "COLLATERAL_LOCK_FAILED",
+            Self::InsufficientCollateral => "INSUFFICIENT_COLLATERAL",
+            Self::RfqTtlOutOfRange => "RFQ_TTL_OUT_OF_RANGE",
+            Self::InternalError => "INTERNAL_ERROR",
+            Self::ArithmeticError => "ARITHMETIC_ERROR",
+            Self::ExecutionFailed => "EXECUTION_FAILED",
+            Self::SettlementFailed => "SETTLEMENT_FAILED",
+            Self::RollbackFailed => "ROLLBACK_FAILED",
+            Self::FeeCalculationFailed => "FEE_CALCULATION_FAILED",
+            Self::NotImplemented => "NOT_IMPLEMENTED",
+            Self::VenueError => "VENUE_ERROR",
+            Self::ConfirmationFailed => "CONFIRMATION_FAILED",
+            Self::ServiceUnavailable => "SERVICE_UNAVAILABLE",
+            Self::ShuttingDown => "SHUTTING_DOWN",
+            Self::VenueUnavailable => "VENUE_UNAVAILABLE",
+            Self::Timeout => "TIMEOUT",
+        }
+    }
+
+    /// Returns the HTTP status associated with this code.
+    #[must_use]
+    pub const fn status(self) -> StatusCode {
+        match self {
+            Self::ValidationError
+            | Self::InvalidQuantity
+            | Self::InvalidPrice
+            | Self::InvalidLotSize
+            | Self::InvalidTickSize
+            | Self::InvalidPackageQuote
+            | Self::InstrumentNotSupported
+            | Self::SettlementTokenUnavailable
+            | Self::InvalidCursor => StatusCode::BAD_REQUEST,
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::Forbidden
+            | Self::ComplianceFailed
+            | Self::UnauthorizedCounterparty
+            | Self::ClientNotActive => StatusCode::FORBIDDEN,
+            Self::NotFound | Self::RfqNotFound | Self::QuoteNotFound => StatusCode::NOT_FOUND,
+            Self::RfqInvalidState
+            | Self::InvalidState
+            | Self::NegotiationInvalidState
+            | Self::OperationNotAllowed
+            | Self::QuoteExpired
+            | Self::QuoteLocked
+            | Self::LockAcquisitionFailed
+            | Self::Conflict
+            | Self::ExecutionInProgress
+            | Self::Duplicate
+            | Self::VersionConflict
+            | Self::MaxNegotiationRounds
+            | Self::LastLookRejected
+            | Self::FirmUpPriceMoved => StatusCode::CONFLICT,
+            Self::InsufficientLiquidity
+            | Self::MinQuantityNotMet
+            | Self::InvalidMinQuantity
+            | Self::NoEligibleVenues
+            | Self::QuorumNotMet
+            | Self::AllocationMismatch
+            | Self::NoReferencePrice
+            | Self::PriceOutOfBounds
+            | Self::RiskCheckFailed
+            | Self::NoPriceImprovement
+            | Self::LimitExceeded
+            | Self::CollateralLockFailed
+            | Self::InsufficientCollateral
+            | Self::RfqTtlOutOfRange => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::InternalError
+            | Self::ArithmeticError
+            | Self::ExecutionFailed
+            | Self::SettlementFailed
+            | Self::RollbackFailed
+            | Self::FeeCalculationFailed => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::NotImplemented => StatusCode::NOT_IMPLEMENTED,
+            Self::VenueError | Self::ConfirmationFailed => StatusCode::BAD_GATEWAY,
+            Self::ServiceUnavailable | Self::VenueUnavailable | Self::ShuttingDown => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
+            Self::Timeout => StatusCode::GATEWAY_TIMEOUT,
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// ============================================================================
+// Constructors
+// ============================================================================
+
+/// Builds an [`ApiError`] for the given code and message.
+pub fn api_error(code: ErrorCode, message: impl Into<String>) -> ApiError {
+    (
+        code.status(),
+        Json(ErrorResponse::new(code.as_str(), message)),
+    )
+}
+
+/// Builds an [`ApiError`] for the given code, message, and details.
+pub fn api_error_with_details(
+    code: ErrorCode,
+    message: impl Into<String>,
+    details: Option<serde_json::Value>,
+) -> ApiError {
+    let response = match details {
+        Some(details) => ErrorResponse::with_details(code.as_str(), message, details),
+        None => ErrorResponse::new(code.as_str(), message),
+    };
+    (code.status(), Json(response))
+}
+
+// ============================================================================
+// Request bodies
+// ============================================================================
+
+/// JSON body extractor whose rejections are [`ApiError`]s.
+///
+/// Unlike [`Json`], a body that is malformed or does not match the request
+/// type is rejected with a `VALIDATION_ERROR` [`ErrorResponse`] rather than
+/// a plain-text response.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ApiJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ApiJson<T>
+where
+    Json<T>: FromRequest<S, Rejection = JsonRejection>,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(|rejection| api_error(ErrorCode::ValidationError, rejection.body_text()))?;
+        Ok(Self(value))
+    }
+}
+
+// ============================================================================
+// DomainError
+// ============================================================================
+
+/// Maps a [`DomainError`] to its stable [`ErrorCode`].
+///
+/// The match is exhaustive so that adding a domain error variant fails to
+/// compile until it is given a code.
+#[must_use]
+pub fn domain_error_code(err: &DomainError) -> ErrorCode {
+    match err {
+        DomainError::InvalidQuantity(_) => ErrorCode::InvalidQuantity,
+        DomainError::InvalidPrice(_) => ErrorCode::InvalidPrice,
+        DomainError::InvalidLotSize { .. } => ErrorCode::InvalidLotSize,
+        DomainError::InvalidTickSize { .. } => ErrorCode::InvalidTickSize,
+        DomainError::ValidationError(_)
+        | DomainError::ValidationFailed(_)
+        | DomainError::InvalidNotificationPreferences { .. } => ErrorCode::ValidationError,
+        DomainError::QuoteExpired(_) => ErrorCode::QuoteExpired,
+        DomainError::QuoteNotFound(_) => ErrorCode::QuoteNotFound,
+        DomainError::InsufficientLiquidity { .. } => ErrorCode::InsufficientLiquidity,
+        DomainError::MinQuantityNotMet { .. } => ErrorCode::MinQuantityNotMet,
+        DomainError::InvalidMinQuantity(_) => ErrorCode::InvalidMinQuantity,
+        DomainError::NoEligibleVenues(_) => ErrorCode::NoEligibleVenues,
+        DomainError::QuorumNotMet(_) => ErrorCode::QuorumNotMet,
+        DomainError::AllocationMismatch { .. } => ErrorCode::AllocationMismatch,
+        DomainError::NoReferencePrice => ErrorCode::NoReferencePrice,
+        DomainError::DivisionByZero
+        | DomainError::CapacityOverflow { .. }
+        | DomainError::CapacityUnderflow { .. } => ErrorCode::ArithmeticError,
+        DomainError::PriceOutOfBounds { .. } | DomainError::PriceBoundsVerificationFailed(_) => {
+            ErrorCode::PriceOutOfBounds
+        }
+        DomainError::InvalidStateTransition { .. } => ErrorCode::RfqInvalidState,
+        DomainError::GenericStateTransitionError { .. }
+        | DomainError::InvalidState(_)
+        | DomainError::InvalidTradeStateForExecution { .. } => ErrorCode::InvalidState,
+        DomainError::OperationNotAllowed(_) => ErrorCode::OperationNotAllowed,
+        DomainError::QuoteLocked(_) => ErrorCode::QuoteLocked,
+        DomainError::LockAcquisitionFailed(_) => ErrorCode::LockAcquisitionFailed,
+        DomainError::ConflictDetected(_) => ErrorCode::Conflict,
+        DomainError::ExecutionInProgress(_) => ErrorCode::ExecutionInProgress,
+        DomainError::RiskCheckFailed(_) => ErrorCode::RiskCheckFailed,
+        DomainError::UnauthorizedCounterparty(_) => ErrorCode::UnauthorizedCounterparty,
+        DomainError::InvalidNegotiationStateTransition { .. } => ErrorCode::NegotiationInvalidState,
+        DomainError::MaxNegotiationRoundsReached { .. } => ErrorCode::MaxNegotiationRounds,
+        DomainError::NoPriceImprovement { .. } => ErrorCode::NoPriceImprovement,
+        DomainError::LastLookRejected(_) => ErrorCode::LastLookRejected,
+        DomainError::FirmUpPriceMoved { .. } => ErrorCode::FirmUpPriceMoved,
+        DomainError::LastLookTimeout(_)
+        | DomainError::AcceptanceTimeout(_)
+        | DomainError::LegExecutionTimeout { .. } => ErrorCode::Timeout,
+        DomainError::CollateralLockFailed(_) => ErrorCode::CollateralLockFailed,
+        DomainError::InsufficientCollateral { .. } => ErrorCode::InsufficientCollateral,
+        DomainError::CollateralUnavailable(_) => ErrorCode::ServiceUnavailable,
+        DomainError::SettlementFailed(_) => ErrorCode::SettlementFailed,
+        DomainError::PositionUpdateFailed(_) | DomainError::MultiLegExecutionFailed { .. } => {
+            ErrorCode::ExecutionFailed
+        }
+        DomainError::RollbackFailed { .. } => ErrorCode::RollbackFailed,
+        DomainError::InvalidPackageQuote(_) | DomainError::InconsistentLegPrices { .. } => {
+            ErrorCode::InvalidPackageQuote
+        }
+        DomainError::CapacityExceeded { .. } => ErrorCode::LimitExceeded,
+        DomainError::ReservationNotFound { .. } | DomainError::SchemaNotFound { .. } => {
+            ErrorCode::NotFound
+        }
+        DomainError::SchemaAlreadyRegistered { .. } => ErrorCode::Duplicate,
+        DomainError::CapacityRepositoryError { .. }
+        | DomainError::SchemaGenerationFailed { .. } => ErrorCode::InternalError,
+        DomainError::FeeCalculationFailed { .. } => ErrorCode::FeeCalculationFailed,
+        DomainError::ConfirmationFailed { .. } => ErrorCode::ConfirmationFailed,
+    }
+}
+
+/// Returns the structured details for a [`DomainError`], if it carries any.
+#[must_use]
+pub fn domain_error_details(err: &DomainError) -> Option<serde_json::Value> {
+    match err {
+        DomainError::InsufficientLiquidity {
+            requested,
+            available,
+        } => Some(json!({
+            "requested": requested.to_string(),
+            "available": available.to_string(),
+        })),
+        DomainError::InsufficientCollateral {
+            required,
+            available,
+        } => Some(json!({
+            "required": required.to_string(),
+            "available": available.to_string(),
+        })),
+        DomainError::MinQuantityNotMet { filled, minimum } => Some(json!({
+            "filled": filled.to_string(),
+            "minimum": minimum.to_string(),
+        })),
+        DomainError::AllocationMismatch { allocated, target } => Some(json!({
+            "allocated": allocated.to_string(),
+            "target": target.to_string(),
+        })),
+        DomainError::PriceOutOfBounds {
+            proposed,
+            reference,
+            deviation_pct,
+            max_tolerance_pct,
+        } => Some(json!({
+            "proposed": proposed.to_string(),
+            "reference": reference.to_string(),
+            "deviation_pct": deviation_pct.to_string(),
+            "max_tolerance_pct": max_tolerance_pct.to_string(),
+        })),
+        DomainError::FirmUpPriceMoved {
+            indicative,
+            firm,
+            deviation_pct,
+            max_tolerance_pct,
+        } => Some(json!({
+            "indicative": indicative.to_string(),
+            "firm": firm.to_string(),
+            "deviation_pct": deviation_pct.to_string(),
+            "max_tolerance_pct": max_tolerance_pct.to_string(),
+        })),
+        DomainError::QuorumNotMet(shortfall) => Some(json!({
+            "notional": shortfall.notional.to_string(),
+            "quotes": shortfall.quotes,
+            "venues": shortfall.venues,
+            "min_quotes": shortfall.required.min_quotes,
+            "min_venues": shortfall.required.min_venues,
+        })),
+        DomainError::InvalidLotSize {
+            quantity,
+            lot_size,
+            nearest_valid,
+        } => Some(json!({
+            "quantity": quantity.to_string(),
+            "lot_size": lot_size.to_string(),
+            "nearest_valid": nearest_valid.to_string(),
+        })),
+        DomainError::InvalidTickSize {
+            price,
+            tick_size,
+            nearest_valid,
+        } => Some(json!({
+            "price": price.to_string(),
+            "tick_size": tick_size.to_string(),
+            "nearest_valid": nearest_valid.to_string(),
+        })),
+        DomainError::InvalidStateTransition { from, to } => Some(json!({
+            "from": from,
+            "to": to,
+        })),
+        DomainError::InvalidNegotiationStateTransition { from, to } => Some(json!({
+            "from": from,
+            "to": to,
+        })),
+        DomainError::GenericStateTransitionError { from, to } => Some(json!({
+            "from": from,
+            "to": to,
+        })),
+        DomainError::InvalidTradeStateForExecution { expected, actual } => Some(json!({
+            "expected": expected,
+            "actual": actual,
+        })),
+        DomainError::MaxNegotiationRoundsReached { max_rounds } => Some(json!({
+            "max_rounds": max_rounds,
+        })),
+        DomainError::NoPriceImprovement { previous, proposed } => Some(json!({
+            "previous": previous.to_string(),
+            "proposed": proposed.to_string(),
+        })),
+        DomainError::CapacityExceeded { mm_id, reason } => Some(json!({
+            "mm_id": mm_id,
+            "reason": reason,
+        })),
+        DomainError::LegExecutionTimeout {
+            leg_index,
+            instrument,
+            timeout_ms,
+        } => Some(json!({
+            "leg_index": leg_index,
+            "instrument": instrument,
+            "timeout_ms": timeout_ms,
+        })),
+        DomainError::MultiLegExecutionFailed {
+            failed_leg_index,
+            failed_leg_instrument,
+            rolled_back_count,
+            ..
+        } => Some(json!({
+            "failed_leg_index": failed_leg_index,
+            "failed_leg_instrument": failed_leg_instrument,
+            "rolled_back_count": rolled_back_count,
+        })),
+        _ => None,
+    }
+}
+
+/// Converts a [`DomainError`] into an [`ApiError`].
+pub fn from_domain_error(err: &DomainError) -> ApiError {
+    let code = domain_error_code(err);
+    metrics::record_domain_error(code.as_str());
+    api_error_with_details(code, err.to_string(), domain_error_details(err))
+}
+
+// ============================================================================
+// RepositoryError
+// ============================================================================
+
+/// Maps a [`RepositoryError`] to its stable [`ErrorCode`].
+#[must_use]
+pub fn repository_error_code(err: &RepositoryError) -> ErrorCode {
+    match err {
+        RepositoryError::NotFound { .. } => ErrorCode::NotFound,
+        RepositoryError::Duplicate { .. } => ErrorCode::Duplicate,
+        RepositoryError::VersionConflict { .. } => ErrorCode::VersionConflict,
+        RepositoryError::ConstraintViolation { kind, .. } => match kind {
+            ConstraintKind::Unique => ErrorCode::Duplicate,
+            ConstraintKind::ForeignKey | ConstraintKind::Check | ConstraintKind::Exclusion => {
+                ErrorCode::Conflict
+            }
+            ConstraintKind::NotNull => ErrorCode::InternalError,
+        },
+        RepositoryError::TransactionConflict(_) => ErrorCode::Conflict,
+        RepositoryError::ConnectionLost(_) | RepositoryError::Timeout(_) => {
+            ErrorCode::ServiceUnavailable
+        }
+        RepositoryError::Query(_)
+        | RepositoryError::Serialization(_)
+        | RepositoryError::Internal(_) => ErrorCode::InternalError,
+    }
+}
+
+/// Returns the structured details for a [`RepositoryError`], if it carries any.
+#[must_use]
+pub fn repository_error_details(err: &RepositoryError) -> Option<serde_json::Value> {
+    match err {
+        RepositoryError::NotFound { entity_type, id }
+        | RepositoryError::Duplicate { entity_type, id } => Some(json!({
+            "entity_type": entity_type,
+            "id": id,
+        })),
+        RepositoryError::VersionConflict {
+            entity_type,
+            id,
+            expected,
+            actual,
+        } => Some(json!({
+            "entity_type": entity_type,
+            "id": id,
+            "expected_version": expected,
+            "actual_version": actual,
+        })),
+        RepositoryError::ConstraintViolation { constraint, kind } => Some(json!({
+            "constraint": constraint,
+            "constraint_kind": kind.as_str(),
+        })),
+        _ => None,
+    }
+}
+
+/// Converts a [`RepositoryError`] into an [`ApiError`].
+pub fn from_repository_error(err: &RepositoryError) -> ApiError {
+    api_error_with_details(
+        repository_error_code(err),
+        err.to_string(),
+        repository_error_details(err),
+    )
+}
+
+// ============================================================================
+// ApplicationError
+// ============================================================================
+
+/// Converts an [`ApplicationError`] into an [`ApiError`].
+///
+/// Wrapped domain and repository errors are delegated to
+/// [`from_domain_error`] and [`from_repository_error`].
+pub fn from_application_error(err: &ApplicationError) -> ApiError {
+    let code = match err {
+        ApplicationError::Domain(e) => return from_domain_error(e),
+        ApplicationError::Infrastructure(InfrastructureError::Repository(e)) => {
+            return from_repository_error(e);
+        }
+        ApplicationError::Infrastructure(e) => match e {
+            InfrastructureError::Timeout(_) => ErrorCode::Timeout,
+            InfrastructureError::Network(_)
+            | InfrastructureError::Database(_)
+            | InfrastructureError::MessageQueue(_)
+            | InfrastructureError::Cache(_)
+            | InfrastructureError::ExternalService { .. } => ErrorCode::ServiceUnavailable,
+            InfrastructureError::Configuration(_)
+            | InfrastructureError::Serialization(_)
+            | InfrastructureError::Repository(_) => ErrorCode::InternalError,
+        },
+        ApplicationError::Venue(_) => ErrorCode::VenueError,
+        ApplicationError::Validation(_) => ErrorCode::ValidationError,
+        ApplicationError::NotFound { .. } | ApplicationError::ClientNotFound(_) => {
+            ErrorCode::NotFound
+        }
+        ApplicationError::RfqNotFound(_) => ErrorCode::RfqNotFound,
+        ApplicationError::QuoteNotFound(_) => ErrorCode::QuoteNotFound,
+        ApplicationError::Unauthorized => ErrorCode::Unauthorized,
+        ApplicationError::ClientNotActive(_) => ErrorCode::ClientNotActive,
+        ApplicationError::InstrumentNotSupported(_) => ErrorCode::InstrumentNotSupported,
+        ApplicationError::SettlementTokenUnavailable(_) => ErrorCode::SettlementTokenUnavailable,
+        ApplicationError::ComplianceFailed(_) => ErrorCode::ComplianceFailed,
+        ApplicationError::QuoteExpired(_) => ErrorCode::QuoteExpired,
+        ApplicationError::InvalidState(_) => ErrorCode::InvalidState,
+        ApplicationError::VenueNotAvailable(_) => ErrorCode::VenueUnavailable,
+        ApplicationError::ExecutionFailed(_) => ErrorCode::ExecutionFailed,
+        ApplicationError::ShuttingDown => ErrorCode::ShuttingDown,
+        ApplicationError::RepositoryError(_)
+        | ApplicationError::EventPublishError(_)
+        | ApplicationError::Internal(_) => ErrorCode::InternalError,
+    };
+    api_error(code, err.to_string())
+}
+
+impl From<ApplicationError> for (StatusCode, Json<ErrorResponse>) {
+    fn from(err: ApplicationError) -> Self {
+        from_application_error(&err)
+    }
+}
+
+impl From<DomainError> for (StatusCode, Json<ErrorResponse>) {
+    fn from(err: DomainError) -> Self {
+        from_domain_error(&err)
+    }
+}
+
+impl From<RepositoryError> for (StatusCode, Json<ErrorResponse>) {
+    fn from(err: RepositoryError) -> Self {
+        from_repository_error(&err)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{
+        NegotiationState, Price, Quantity, QuorumRequirement, QuorumShortfall, RfqState,
+    };
+    use rust_decimal::Decimal;
+
+    /// One instance of every [`DomainError`] variant.
+    ///
+    /// The `exhaustive` match below has no wildcard arm, so adding a variant
+    /// without extending this list fails to compile.
+    fn all_domain_errors() -> Vec<DomainError> {
+        let qty = Quantity::new(1.0).unwrap();
+        let price = Price::new(100.0).unwrap();
+        let s = || "x".to_string();
+
+        let errors = vec![
+            DomainError::InvalidQuantity(s()),
+            DomainError::InvalidPrice(s()),
+            DomainError::ValidationError(s()),
+            DomainError::QuoteExpired(s()),
+            DomainError::QuoteNotFound(s()),
+            DomainError::InsufficientLiquidity {
+                requested: qty,
+                available: qty,
+            },
+            DomainError::MinQuantityNotMet {
+                filled: qty,
+                minimum: qty,
+            },
+            DomainError::InvalidMinQuantity(s()),
+            DomainError::NoEligibleVenues(s()),
+            DomainError::AllocationMismatch {
+                allocated: qty,
+                target: qty,
+            },
+            DomainError::NoReferencePrice,
+            DomainError::DivisionByZero,
+            DomainError::PriceOutOfBounds {
+                proposed: price,
+                reference: price,
+                deviation_pct: Decimal::ONE,
+                max_tolerance_pct: Decimal::ONE,
+            },
+            DomainError::InvalidLotSize {
+                quantity: qty,
+                lot_size: Decimal::ONE,
+                nearest_valid: qty,
+            },
+            DomainError::InvalidTickSize {
+                price,
+                tick_size: Decimal::ONE,
+                nearest_valid: price,
+            },
+            DomainError::InvalidStateTransition {
+                from: RfqState::Created,
+                to: RfqState::Executed,
+            },
+            DomainError::GenericStateTransitionError { from: s(), to: s() },
+            DomainError::InvalidState(s()),
+            DomainError::OperationNotAllowed(s()),
+            DomainError::InvalidTradeStateForExecution {
+                expected: s(),
+                actual: s(),
+            },
+            DomainError::QuoteLocked(s()),
+            DomainError::LockAcquisitionFailed(s()),
+            DomainError::ConflictDetected(s()),
+            DomainError::ExecutionInProgress(s()),
+            DomainError::RiskCheckFailed(s()),
+            DomainError::UnauthorizedCounterparty(s()),
+            DomainError::ValidationFailed(s()),
+            DomainError::InvalidNegotiationStateTransition {
+                from: NegotiationState::Open,
+                to: NegotiationState::Open,
+            },
+            DomainError::MaxNegotiationRoundsReached { max_rounds: 3 },
+            DomainError::NoPriceImprovement {
+                previous: price,
+                proposed: price,
+            },
+            DomainError::LastLookRejected(s()),
+            DomainError::LastLookTimeout(s()),
+            DomainError::AcceptanceTimeout(s()),
+            DomainError::FirmUpPriceMoved {
+                indicative: price,
+                firm: price,
+                deviation_pct: Decimal::ONE,
+                max_tolerance_pct: Decimal::ONE,
+            },
+            DomainError::QuorumNotMet(QuorumShortfall {
+                notional: Decimal::ONE,
+                required: QuorumRequirement::new(3, 2),
+                quotes: 1,
+                venues: 1,
+            }),
+            DomainError::CollateralLockFailed(s()),
+            DomainError::InsufficientCollateral {
+                required: Decimal::ONE,
+                available: Decimal::ZERO,
+            },
+            DomainError::CollateralUnavailable(s()),
+            DomainError::SettlementFailed(s()),
+            DomainError::PositionUpdateFailed(s()),
+            DomainError::PriceBoundsVerificationFailed(s()),
+            DomainError::InvalidPackageQuote(s()),
+            DomainError::InconsistentLegPrices {
+                leg_index: 0,
+                reason: s(),
+            },
+            DomainError::MultiLegExecutionFailed {
+                failed_leg_index: 0,
+                failed_leg_instrument: s(),
+                reason: s(),
+                rolled_back_count: 0,
+            },
+            DomainError::RollbackFailed {
+                original_failure: s(),
+                rollback_failure: s(),
+                partially_rolled_back: 0,
+            },
+            DomainError::LegExecutionTimeout {
+                leg_index: 0,
+                instrument: s(),
+                timeout_ms: 100,
+            },
+            DomainError::CapacityExceeded {
+                mm_id: s(),
+                reason: s(),
+            },
+            DomainError::ReservationNotFound {
+                mm_id: s(),
+                rfq_id: s(),
+            },
+            DomainError::CapacityRepositoryError { message: s() },
+            DomainError::CapacityOverflow { field: s() },
+            DomainError::CapacityUnderflow { field: s() },
+            DomainError::FeeCalculationFailed { reason: s() },
+            DomainError::ConfirmationFailed {
+                channel: s(),
+                reason: s(),
+            },
+            DomainError::InvalidNotificationPreferences { reason: s() },
+            DomainError::SchemaNotFound {
+                event_type: s(),
+                version: s(),
+            },
+            DomainError::SchemaAlreadyRegistered {
+                event_type: s(),
+                version: s(),
+            },
+            DomainError::SchemaGenerationFailed { reason: s() },
+        ];
+
+        for err in &errors {
+            exhaustive(err);
+        }
+        errors
+    }
+
+    fn exhaustive(err: &DomainError) {
+        match err {
+            DomainError::InvalidQuantity(_)
+            | DomainError::InvalidPrice(_)
+            | DomainError::ValidationError(_)
+            | DomainError::QuoteExpired(_)
+            | DomainError::QuoteNotFound(_)
+            | DomainError::InsufficientLiquidity { .. }
+            | DomainError::MinQuantityNotMet { .. }
+            | DomainError::InvalidMinQuantity(_)
+            | DomainError::NoEligibleVenues(_)
+            | DomainError::AllocationMismatch { .. }
+            | DomainError::NoReferencePrice
+            | DomainError::DivisionByZero
+            | DomainError::PriceOutOfBounds { .. }
+            | DomainError::InvalidLotSize { .. }
+            | DomainError::InvalidTickSize { .. }
+            | DomainError::InvalidStateTransition { .. }
+            | DomainError::GenericStateTransitionError { .. }
+            | DomainError::InvalidState(_)
+            | DomainError::OperationNotAllowed(_)
+            | DomainError::InvalidTradeStateForExecution { .. }
+            | DomainError::QuoteLocked(_)
+            | DomainError::LockAcquisitionFailed(_)
+            | DomainError::ConflictDetected(_)
+            | DomainError::ExecutionInProgress(_)
+            | DomainError::RiskCheckFailed(_)
+            | DomainError::UnauthorizedCounterparty(_)
+            | DomainError::ValidationFailed(_)
+            | DomainError::InvalidNegotiationStateTransition { .. }
+            | DomainError::MaxNegotiationRoundsReached { .. }
+            | DomainError::NoPriceImprovement { .. }
+            | DomainError::LastLookRejected(_)
+            | DomainError::LastLookTimeout(_)
+            | DomainError::AcceptanceTimeout(_)
+            | DomainError::FirmUpPriceMoved { .. }
+            | DomainError::QuorumNotMet(_)
+            | DomainError::CollateralLockFailed(_)
+            | DomainError::InsufficientCollateral { .. }
+            | DomainError::CollateralUnavailable(_)
+            | DomainError::SettlementFailed(_)
+            | DomainError::PositionUpdateFailed(_)
+            | DomainError::PriceBoundsVerificationFailed(_)
+            | DomainError::InvalidPackageQuote(_)
+            | DomainError::InconsistentLegPrices { .. }
+            | DomainError::MultiLegExecutionFailed { .. }
+            | DomainError::RollbackFailed { .. }
+            | DomainError::LegExecutionTimeout { .. }
+            | DomainError::CapacityExceeded { .. }
+            | DomainError::ReservationNotFound { .. }
+            | DomainError::CapacityRepositoryError { .. }
+            | DomainError::CapacityOverflow { .. }
+            | DomainError::CapacityUnderflow { .. }
+            | DomainError::FeeCalculationFailed { .. }
+            | DomainError::ConfirmationFailed { .. }
+            | DomainError::InvalidNotificationPreferences { .. }
+            | DomainError::SchemaNotFound { .. }
+            | DomainError::SchemaAlreadyRegistered { .. }
+            | DomainError::SchemaGenerationFailed { .. } => {}
+        }
+    }
+
+    #[test]
+    fn every_domain_error_maps_to_a_code() {
+        for err in all_domain_errors() {
+            let (status, Json(body)) = from_domain_error(&err);
+            let code = domain_error_code(&err);
+            assert_eq!(status, code.status(), "{err:?}");
+            assert_eq!(body.code, code.as_str(), "{err:?}");
+            assert!(!body.message.is_empty());
+        }
+    }
+
+    #[test]
+    fn requested_codes_are_stable() {
+        let qty = Quantity::new(1.0).unwrap();
+        let cases = [
+            (
+                DomainError::InvalidStateTransition {
+                    from: RfqState::Cancelled,
+                    to: RfqState::Cancelled,
+                },
+                "RFQ_INVALID_STATE",
+            ),
+            (DomainError::QuoteExpired("q".to_string()), "QUOTE_EXPIRED"),
+            (
+                DomainError::InsufficientLiquidity {
+                    requested: qty,
+                    available: qty,
+                },
+                "INSUFFICIENT_LIQUIDITY",
+            ),
+            (
+                DomainError::MinQuantityNotMet {
+                    filled: qty,
+                    minimum: qty,
+                },
+                "MIN_QUANTITY_NOT_MET",
+            ),
+            (
+                DomainError::CapacityExceeded {
+                    mm_id: "mm".to_string(),
+                    reason: "max".to_string(),
+                },
+                "LIMIT_EXCEEDED",
+            ),
+            (
+                DomainError::InvalidLotSize {
+                    quantity: qty,
+                    lot_size: Decimal::ONE,
+                    nearest_valid: qty,
+                },
+                "INVALID_LOT_SIZE",
+            ),
+        ];
+        for (err, expected) in cases {
+            assert_eq!(domain_error_code(&err).as_str(), expected);
+        }
+        assert_eq!(
+            repository_error_code(&RepositoryError::version_conflict("Rfq", "1", 1, 2)).as_str(),
+            "VERSION_CONFLICT"
+        );
+    }
+
+    #[test]
+    fn insufficient_liquidity_details() {
+        let err = DomainError::InsufficientLiquidity {
+            requested: Quantity::new(10.0).unwrap(),
+            available: Quantity::new(4.0).unwrap(),
+        };
+        let (status, Json(body)) = from_domain_error(&err);
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let details = body.details.unwrap();
+        assert_eq!(details["requested"], "10");
+        assert_eq!(details["available"], "4");
+    }
+
+    #[test]
+    fn insufficient_collateral_details() {
+        let err = DomainError::InsufficientCollateral {
+            required: Decimal::from(5_000),
+            available: Decimal::from(1_200),
+        };
+        let (status, Json(body)) = from_domain_error(&err);
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body.code, "INSUFFICIENT_COLLATERAL");
+        let details = body.details.unwrap();
+        assert_eq!(details["required"], "5000");
+        assert_eq!(details["available"], "1200");
+    }
+
+    #[test]
+    fn quorum_not_met_details() {
+        let err = DomainError::QuorumNotMet(QuorumShortfall {
+            notional: Decimal::from(2_000_000),
+            required: QuorumRequirement::new(3, 2),
+            quotes: 1,
+            venues: 1,
+        });
+        let (status, Json(body)) = from_domain_error(&err);
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body.code, "QUORUM_NOT_MET");
+        let details = body.details.unwrap();
+        assert_eq!(details["notional"], "2000000");
+        assert_eq!(details["quotes"], 1);
+        assert_eq!(details["min_quotes"], 3);
+    }
+
+    #[test]
+    fn invalid_lot_size_details() {
+        let err = DomainError::InvalidLotSize {
+            quantity: "1.234".parse().unwrap(),
+            lot_size: Decimal::new(1, 2),
+            nearest_valid: "1.23".parse().unwrap(),
+        };
+        let (status, Json(body)) = from_domain_error(&err);
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body.code, "INVALID_LOT_SIZE");
+        let details = body.details.unwrap();
+        assert_eq!(details["quantity"], "1.234");
+        assert_eq!(details["lot_size"], "0.01");
+        assert_eq!(details["nearest_valid"], "1.23");
+    }
+
+    #[test]
+    fn state_transition_details() {
+        let err = DomainError::InvalidStateTransition {
+            from: RfqState::Executed,
+            to: RfqState::Cancelled,
+        };
+        let (_, Json(body)) = from_domain_error(&err);
+        let details = body.details.unwrap();
+        assert_eq!(details["from"], "EXECUTED");
+        assert_eq!(details["to"], "CANCELLED");
+    }
+
+    #[test]
+    fn version_conflict_details() {
+        let err = RepositoryError::version_conflict("Rfq", "rfq-1", 3, 4);
+        let (status, Json(body)) = from_repository_error(&err);
+        assert_eq!(status, StatusCode::CONFLICT);
+        let details = body.details.unwrap();
+        assert_eq!(details["expected_version"], 3);
+        assert_eq!(details["actual_version"], 4);
+    }
+
+    #[test]
+    fn application_error_delegates_to_domain() {
+        let err = ApplicationError::Domain(DomainError::QuoteExpired("q".to_string()));
+        let (status, Json(body)) = from_application_error(&err);
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body.code, "QUOTE_EXPIRED");
+    }
+
+    #[test]
+    fn application_error_price_out_of_bounds_details() {
+        let err = ApplicationError::Domain(DomainError::PriceOutOfBounds {
+            proposed: Price::new(105.01).unwrap(),
+            reference: Price::new(100.0).unwrap(),
+            deviation_pct: Decimal::new(5001, 5),
+            max_tolerance_pct: Decimal::new(5, 2),
+        });
+        let (status, Json(body)) = from_application_error(&err);
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body.code, "PRICE_OUT_OF_BOUNDS");
+        let details = body.details.unwrap();
+        assert_eq!(details["deviation_pct"], "0.05001");
+        assert_eq!(details["max_tolerance_pct"], "0.05");
+    }
+
+    #[test]
+    fn application_error_delegates_to_repository() {
+        let err = ApplicationError::Infrastructure(InfrastructureError::Repository(
+            RepositoryError::connection_lost("refused"),
+        ));
+        let (status, Json(body)) = from_application_error(&err);
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body.code, "SERVICE_UNAVAILABLE");
+    }
+
+    #[test]
+    fn unique_violation_is_duplicate_with_constraint() {
+        let err = RepositoryError::constraint_violation(
+            "rfqs_idempotency_key_key",
+            ConstraintKind::Unique,
+        );
+        let (status, Json(body)) = from_repository_error(&err);
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body.code, "DUPLICATE");
+        let details = body.details.unwrap();
+        assert_eq!(details["constraint"], "rfqs_idempotency_key_key");
+        assert_eq!(details["constraint_kind"], "unique");
+    }
+
+    #[test]
+    fn transient_repository_errors_are_unavailable() {
+        let (status, _) = from_repository_error(&RepositoryError::timeout("pool timed out"));
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        let (status, Json(body)) = from_repository_error(&RepositoryError::transaction_conflict(
+            "could not serialize",
+        ));
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body.code, "CONFLICT");
+    }
+}
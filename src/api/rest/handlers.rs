@@ -12,36 +12,164 @@
 //! - `GET /api/v1/rfqs/{id}` - Get RFQ by ID
 //! - `POST /api/v1/rfqs` - Create RFQ
 //! - `DELETE /api/v1/rfqs/{id}` - Cancel RFQ
+//! - `POST /api/v1/rfqs/{id}/select` - Select a quote, firming it up if indicative
+//! - `GET /api/v1/rfqs/{id}/timeline` - Audit timeline of an RFQ (JSON or CSV)
+//! - `GET /api/v1/rfqs/{id}/quotes/{quote_id}/ranking-explanation` - Explain a quote's rank
 //!
 //! ## Venues
 //! - `GET /api/v1/venues` - List venues
 //! - `PUT /api/v1/venues/{id}` - Update venue config
+//! - `GET /api/v1/venues/{id}/history` - Venue config change history
+//! - `POST /api/v1/venues/{id}/rollback/{history_id}` - Roll back a config change
+//!
+//! ## Instruments (admin)
+//! - `GET /api/v1/instruments` - List instrument reference data
+//! - `GET /api/v1/instruments/{base}/{quote}` - Get reference data
+//! - `PUT /api/v1/instruments/{base}/{quote}` - Create or replace reference data
+//! - `DELETE /api/v1/instruments/{base}/{quote}` - Delete reference data
+//!
+//! ## RFQ Templates
+//! - `GET /api/v1/rfq-templates` - List the caller's templates
+//! - `POST /api/v1/rfq-templates` - Create template
+//! - `GET /api/v1/rfq-templates/{id}` - Get template
+//! - `PUT /api/v1/rfq-templates/{id}` - Replace template
+//! - `DELETE /api/v1/rfq-templates/{id}` - Delete template
+//! - `POST /api/v1/rfqs/from-template/{template_id}` - Create RFQ from template
 //!
 //! ## Trades
 //! - `GET /api/v1/trades` - List trades
 //! - `GET /api/v1/trades/{id}` - Get trade by ID
-
-use crate::application::error::ApplicationError;
+//! - `GET /api/v1/trades/export` - Export trades as CSV or Parquet
+//!
+//! ## Platform Fees (admin)
+//! - `GET /api/v1/fees/platform-schedules` - List platform fee schedules
+//! - `GET /api/v1/fees/platform-schedules/{tier}` - Get a tier's schedule
+//! - `PUT /api/v1/fees/platform-schedules/{tier}` - Create or replace a tier's schedule
+//! - `DELETE /api/v1/fees/platform-schedules/{tier}` - Delete a tier's schedule
+//! - `GET /api/v1/fees/waivers` - List fee waivers
+//! - `GET /api/v1/fees/waivers/{counterparty_id}` - Get a counterparty's waiver
+//! - `PUT /api/v1/fees/waivers/{counterparty_id}` - Create or replace a waiver
+//! - `DELETE /api/v1/fees/waivers/{counterparty_id}` - Delete a waiver
+//!
+//! ## Settlement Batches (admin)
+//! - `GET /api/v1/settlement-batches` - List netting batches, newest first
+//! - `GET /api/v1/settlement-batches/{id}` - Get a netting batch
+//! - `POST /api/v1/settlement-batches/{id}/cancel` - Cancel an unsubmitted batch
+//!
+//! ## Settlement Addresses (own counterparty or admin)
+//! - `GET /api/v1/counterparties/{id}/addresses` - List settlement addresses
+//! - `POST /api/v1/counterparties/{id}/addresses` - Register an address
+//! - `DELETE /api/v1/counterparties/{id}/addresses/{chain}` - Remove an address
+//! - `POST /api/v1/counterparties/{id}/addresses/{chain}/challenge` - Issue a verification challenge
+//! - `POST /api/v1/counterparties/{id}/addresses/{chain}/verify` - Verify with a signed challenge
+//!
+//! ## Counterparty Erasure (admin)
+//! - `POST /api/v1/counterparties/{id}/erasure-requests` - Request an erasure
+//! - `GET /api/v1/erasure-requests/{id}` - Get an erasure request
+//! - `POST /api/v1/erasure-requests/{id}/approve` - Approve, by someone other than the requester
+//! - `POST /api/v1/erasure-requests/{id}/execute` - Pseudonymize the counterparty
+//!
+//! ## Client Venue Credentials
+//! - `GET /api/v1/counterparties/{id}/venue-credentials` - List venues with client credentials
+//! - `PUT /api/v1/counterparties/{id}/venue-credentials/{venue_id}` - Store or rotate credentials
+//! - `DELETE /api/v1/counterparties/{id}/venue-credentials/{venue_id}` - Remove credentials
+//!
+//! ## Webhooks (admin)
+//! - `GET /api/v1/webhooks` - List webhook subscriptions
+//! - `POST /api/v1/webhooks` - Create subscription
+//! - `GET /api/v1/webhooks/{id}` - Get subscription
+//! - `PUT /api/v1/webhooks/{id}` - Update subscription
+//! - `DELETE /api/v1/webhooks/{id}` - Delete subscription
+//! - `GET /api/v1/webhooks/{id}/deliveries` - Delivery log, newest first
+//! - `POST /api/v1/webhooks/{id}/deliveries/{delivery_id}/redeliver` - Redeliver
+
+use crate::api::middleware::{AuthenticatedUser, Claims, OptionalUser, require_role};
+use crate::api::rest::best_execution::{
+    BestExecutionFormat, BestExecutionParams, BestExecutionReportResponse,
+};
+use crate::api::rest::decimal;
+use crate::api::rest::errors::{
+    ApiError, ApiJson, ErrorCode, api_error, api_error_with_details, from_application_error,
+    from_domain_error, from_repository_error,
+};
+use crate::api::rest::timeline::{
+    TimelineEntry, TimelineFormat, TimelineParams, build_timeline, linked_trade_id, to_csv,
+};
+use crate::api::rest::trade_export::{self, TradeExportParams};
+use crate::application::services::settlement_addresses::AddressChallenge;
+use crate::application::services::{
+    BestExecutionReportService, CheckStatus, CircuitBreaker, CircuitBreakerRegistry,
+    ComplianceExportService, CounterpartyErasureService, DeadLetterQueue, FirmUpService,
+    ForcedTransitionService, LiquidityAssessment, LiquidityClassifier, NettingService,
+    PriceBoundsConfigStore, ReadinessChecker, ReadinessReport, RfqCancellationService,
+    RfqTtlConfigStore, SettlementAddressService, ShutdownCoordinator, TradingCalendarService,
+    VenueProbeResult, VenueProber, VenueRequestGate, VenueSelector, WebhookDeliveryService,
+};
 use crate::application::use_cases::create_rfq::RfqRepository;
+use crate::domain::entities::allocation::TradeAllocation;
+use crate::domain::entities::counter_quote::CounterQuoteBuilder;
+use crate::domain::entities::counterparty::SettlementAddress;
+use crate::domain::entities::erasure_request::{ErasureRequest, ErasureRequestStatus};
 use crate::domain::entities::mm_performance::MmPerformanceMetrics;
-use crate::domain::entities::rfq::Rfq;
-use crate::domain::entities::trade::Trade;
-use crate::domain::entities::venue::{Venue, VenueHealth};
+use crate::domain::entities::negotiation::{Negotiation, NegotiationRound};
+use crate::domain::entities::netting_batch::{NettingBatch, NettingBatchStatus};
+use crate::domain::entities::platform_fee::{
+    AssetClassFeeRate, FeeBand, FeeWaiver, PlatformFeeSchedule,
+};
+use crate::domain::entities::quote::{Quote, QuoteKind};
+use crate::domain::entities::quote_history::{BestPricePoint, QuoteHistoryEntry, QuoteStatus};
+use crate::domain::entities::rfq::{Rfq, RfqBuilder, RfqSubject};
+use crate::domain::entities::rfq_template::{RfqTemplate, RfqTemplateBuilder};
+use crate::domain::entities::trade::{FeeComponent, FeeKind, SettlementState, Trade};
+use crate::domain::entities::venue::{MaintenanceWindow, Venue, VenueHealth, VenueMode};
+use crate::domain::entities::venue_config_change::{VenueConfigChange, VenueSettings};
+use crate::domain::entities::venue_credential_set::{VenueCredentialSet, VenueCredentials};
+use crate::domain::entities::webhook_subscription::WebhookSubscription;
+use crate::domain::errors::DomainError;
+use crate::domain::events::admin_events::ForcedStateTransition;
+use crate::domain::events::domain_event::EventType;
 use crate::domain::services::fee_engine::{FeeEngine, FeeSchedule};
 use crate::domain::services::mm_incentive_service::{MmIncentiveError, MmIncentiveService};
 use crate::domain::services::mm_performance::MmPerformanceTracker;
+use crate::domain::services::negotiation_analytics::{
+    NegotiationAnalytics, NegotiationAnalyticsReport,
+};
+use crate::domain::value_objects::strategy::{Strategy, StrategyLeg, StrategyType};
 use crate::domain::value_objects::timestamp::Timestamp;
 use crate::domain::value_objects::{
-    CounterpartyId, Instrument, OrderSide, Quantity, RfqId, RfqState, TradeId, VenueId, VenueType,
+    AggregationReport, AssetClass, Blockchain, CompensationPolicy, CounterConditions,
+    CounterpartyId, DuplicateRfqPolicy, ErasureRequestId, EventId, FailureCode, FailureReason,
+    Instrument, InstrumentReferenceData, NegotiationId, NettingBatchId, OrderSide, Price,
+    PriceBoundsConfig, PriceBoundsSettings, Quantity, QuantityDisclosure, QuoteId, QuoteRanking,
+    RfqDirection, RfqId, RfqState, RfqTemplateId, RfqTtlPolicy, ScoreComponent, SettlementWindow,
+    SizeNegotiationMode, Symbol, TradeId, TradingCalendar, TtlLimits, VenueId, VenueOutcome,
+    VenueOutcomeKind, VenueType, WebhookDeliveryId, WebhookSubscriptionId,
+};
+use crate::infrastructure::blockchain::{
+    ChainId, SharedTokenRegistry, TokenEntry, TokenError, TokenInfo,
+};
+use crate::infrastructure::metrics;
+use crate::infrastructure::persistence::{
+    AggregationReportRepository, DeadLetter, EventStore, FeeWaiverRepository,
+    InstrumentReferenceDataRepository, NegotiationRepository, PageCursor,
+    PlatformFeeScheduleRepository, RawExchange, RawExchangeLog, RfqListFilter, RfqSummary,
+    RfqSummaryStore, RfqTemplateRepository, VenueCredentialRepository, WebhookDelivery,
 };
 use axum::{
     Json,
+    body::Body,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
 };
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
 use std::sync::Arc;
 use tracing::{error, info, instrument, warn};
+use utoipa::{IntoParams, ToSchema};
 
 // ============================================================================
 // Application State
@@ -62,6 +190,79 @@ pub struct AppState {
     pub mm_incentive_service: Option<Arc<MmIncentiveService>>,
     /// Fee engine (optional — `None` disables fee schedule endpoints).
     pub fee_engine: Option<Arc<FeeEngine>>,
+    /// Instrument reference data (optional — `None` disables lot size
+    /// validation on RFQ creation and the instrument endpoints).
+    pub instrument_reference_data: Option<Arc<dyn InstrumentReferenceDataRepository>>,
+    /// Domain event store (optional — `None` disables the RFQ timeline endpoint).
+    pub event_store: Option<Arc<dyn EventStore>>,
+    /// Shutdown coordinator (optional — `None` never refuses RFQ creation).
+    pub shutdown: Option<ShutdownCoordinator>,
+    /// Readiness checker (optional — `None` reports ready without checking dependencies).
+    pub readiness: Option<Arc<ReadinessChecker>>,
+    /// Quote firm-up service (optional — `None` disables quote selection).
+    pub firm_up: Option<Arc<FirmUpService>>,
+    /// RFQ template repository (optional — `None` disables the template endpoints).
+    pub rfq_templates: Option<Arc<dyn RfqTemplateRepository>>,
+    /// Negotiation repository (optional — `None` disables negotiation analytics).
+    pub negotiations: Option<Arc<dyn NegotiationRepository>>,
+    /// Raw venue exchange log (optional — `None` disables the venue exchange endpoint).
+    pub venue_exchanges: Option<Arc<dyn RawExchangeLog>>,
+    /// Venue circuit breakers (optional — `None` disables the circuit control endpoint).
+    pub circuit_breakers: Option<Arc<CircuitBreakerRegistry>>,
+    /// RFQ summary read model (optional — `None` disables the RFQ summary endpoint).
+    pub rfq_summaries: Option<Arc<dyn RfqSummaryStore>>,
+    /// Webhook delivery service (optional — `None` disables the webhook endpoints).
+    pub webhooks: Option<Arc<WebhookDeliveryService>>,
+    /// Platform fee schedules (optional — `None` disables the platform schedule endpoints).
+    pub platform_fee_schedules: Option<Arc<dyn PlatformFeeScheduleRepository>>,
+    /// Fee waivers (optional — `None` disables the fee waiver endpoints).
+    pub fee_waivers: Option<Arc<dyn FeeWaiverRepository>>,
+    /// Settlement netting service (optional).
+    pub netting: Option<Arc<NettingService>>,
+    /// Venue health prober (optional — `None` disables on-demand probes).
+    pub venue_prober: Option<Arc<VenueProber>>,
+    /// Compliance export service (optional — `None` disables the regulator export endpoint).
+    pub compliance_export: Option<Arc<ComplianceExportService>>,
+    /// RFQ cancel notice fan-out (optional — `None` leaves venues to let quotes expire).
+    pub rfq_cancellations: Option<Arc<RfqCancellationService>>,
+    /// Per-venue concurrency limits (optional — `None` leaves venue requests ungated).
+    pub venue_request_gate: Option<Arc<VenueRequestGate>>,
+    /// Settlement address management (optional — `None` disables the address endpoints).
+    pub settlement_addresses: Option<Arc<SettlementAddressService>>,
+    /// Token registry (optional — `None` disables the token endpoints).
+    pub tokens: Option<SharedTokenRegistry>,
+    /// Live price bounds tolerances (optional — `None` disables the price
+    /// bounds endpoints).
+    pub price_bounds: Option<Arc<PriceBoundsConfigStore>>,
+    /// Liquidity tiers derived from recent activity (optional — `None`
+    /// disables the liquidity endpoint).
+    pub liquidity: Option<Arc<LiquidityClassifier>>,
+    /// Per-venue quote aggregation reports (optional — `None` disables the
+    /// aggregation report endpoint).
+    pub aggregation_reports: Option<Arc<dyn AggregationReportRepository>>,
+    /// Best execution reports (optional — `None` disables the report endpoint).
+    pub best_execution: Option<Arc<BestExecutionReportService>>,
+    /// Live RFQ TTL limits (optional — `None` applies the default TTLs
+    /// without enforcing limits, and disables the TTL limits endpoints).
+    pub rfq_ttl: Option<Arc<RfqTtlConfigStore>>,
+    /// Dead letter queue of the event consumers (optional — `None`
+    /// disables the dead letter endpoints).
+    pub dead_letters: Option<Arc<DeadLetterQueue>>,
+    /// Trading calendars (optional — `None` disables the calendar
+    /// endpoints).
+    pub trading_calendars: Option<Arc<TradingCalendarService>>,
+    /// Counterparty erasure workflow (optional — `None` disables the
+    /// erasure endpoints).
+    pub counterparty_erasure: Option<Arc<CounterpartyErasureService>>,
+    /// Clients' own venue credentials (optional — `None` disables the venue
+    /// credential endpoints).
+    pub venue_credentials: Option<Arc<dyn VenueCredentialRepository>>,
+    /// Near-duplicate RFQ detection (optional — `None` skips the check on
+    /// RFQ creation).
+    pub duplicate_rfqs: Option<Arc<DuplicateRfqPolicy>>,
+    /// Admin rescue of stuck RFQs and settlements (optional — `None`
+    /// disables the force-state endpoints).
+    pub forced_transitions: Option<Arc<ForcedTransitionService>>,
 }
 
 /// Repository for venue persistence.
@@ -75,6 +276,21 @@ pub trait VenueRepository: Send + Sync + std::fmt::Debug {
 
     /// Saves a venue.
     async fn save(&self, venue: &Venue) -> Result<(), String>;
+
+    /// Saves an existing venue and records the change in its history.
+    ///
+    /// The previous settings are read from storage, so the venue and its
+    /// history entry are written together. `rollback_of` names the history
+    /// entry this update reverts, if any.
+    async fn save_with_history(
+        &self,
+        venue: &Venue,
+        changed_by: &str,
+        rollback_of: Option<u64>,
+    ) -> Result<VenueConfigChange, String>;
+
+    /// Returns the configuration history of a venue, newest first.
+    async fn history(&self, id: &VenueId) -> Result<Vec<VenueConfigChange>, String>;
 }
 
 /// Repository for trade persistence.
@@ -83,8 +299,24 @@ pub trait TradeRepository: Send + Sync + std::fmt::Debug {
     /// Returns all trades with optional filtering.
     async fn find_all(&self, filter: &TradeFilter) -> Result<Vec<Trade>, String>;
 
+    /// Lists trades newest first, starting after `cursor`.
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &TradeFilter,
+    ) -> Result<Vec<Trade>, String>;
+
     /// Finds a trade by ID.
     async fn find_by_id(&self, id: TradeId) -> Result<Option<Trade>, String>;
+
+    /// Counts trades matching `filter` created within `[from, to]`.
+    async fn count(
+        &self,
+        filter: &TradeFilter,
+        from: Option<Timestamp>,
+        to: Option<Timestamp>,
+    ) -> Result<u64, String>;
 }
 
 // ============================================================================
@@ -92,13 +324,13 @@ pub trait TradeRepository: Send + Sync + std::fmt::Debug {
 // ============================================================================
 
 /// Standard error response format.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ErrorResponse {
-    /// Error code.
+    /// Stable error code (see [`ErrorCode`]).
     pub code: String,
     /// Human-readable error message.
     pub message: String,
-    /// Additional error details.
+    /// Structured error details (e.g. requested/available quantities).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<serde_json::Value>,
 }
@@ -129,32 +361,13 @@ impl ErrorResponse {
     }
 }
 
-impl From<ApplicationError> for (StatusCode, Json<ErrorResponse>) {
-    fn from(err: ApplicationError) -> Self {
-        let (status, code) = match &err {
-            ApplicationError::Validation(_) => (StatusCode::BAD_REQUEST, "VALIDATION_ERROR"),
-            ApplicationError::NotFound { .. }
-            | ApplicationError::ClientNotFound(_)
-            | ApplicationError::RfqNotFound(_)
-            | ApplicationError::QuoteNotFound(_) => (StatusCode::NOT_FOUND, "NOT_FOUND"),
-            ApplicationError::Unauthorized => (StatusCode::UNAUTHORIZED, "UNAUTHORIZED"),
-            ApplicationError::QuoteExpired(_) | ApplicationError::InvalidState(_) => {
-                (StatusCode::CONFLICT, "CONFLICT")
-            }
-            ApplicationError::ComplianceFailed(_) => (StatusCode::FORBIDDEN, "FORBIDDEN"),
-            _ => (StatusCode::INTERNAL_SERVER_ERROR, "INTERNAL_ERROR"),
-        };
-
-        (status, Json(ErrorResponse::new(code, err.to_string())))
-    }
-}
-
 // ============================================================================
 // Pagination
 // ============================================================================
 
 /// Pagination parameters.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct PaginationParams {
     /// Page number (1-indexed).
     #[serde(default = "default_page")]
@@ -172,31 +385,131 @@ fn default_page_size() -> u32 {
     20
 }
 
+/// Maximum page size for both offset and cursor pagination.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Maximum offset accepted by offset pagination.
+///
+/// Deeper pages must be fetched with a cursor.
+pub const MAX_OFFSET: u32 = 10_000;
+
 impl PaginationParams {
     /// Returns the offset for database queries.
     #[must_use]
     pub fn offset(&self) -> u32 {
-        (self.page.saturating_sub(1)) * self.page_size
+        (self.page.saturating_sub(1)).saturating_mul(self.page_size)
     }
 
     /// Returns the limit for database queries.
     #[must_use]
     pub fn limit(&self) -> u32 {
-        self.page_size.min(100)
+        self.page_size.min(MAX_PAGE_SIZE)
+    }
+
+    /// Validates that the offset does not exceed [`MAX_OFFSET`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `VALIDATION_ERROR` if the offset is too deep.
+    pub fn validate(&self) -> Result<(), ApiError> {
+        let offset = self.offset();
+        if offset > MAX_OFFSET {
+            return Err(api_error_with_details(
+                ErrorCode::ValidationError,
+                format!("offset {offset} exceeds maximum of {MAX_OFFSET}; use cursor pagination"),
+                Some(serde_json::json!({ "offset": offset, "max_offset": MAX_OFFSET })),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Cursor pagination parameters.
+///
+/// Supplying either field switches a list endpoint from offset to cursor
+/// pagination.
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CursorParams {
+    /// Opaque cursor from the previous page's `next_cursor`.
+    pub cursor: Option<String>,
+    /// Maximum number of items to return.
+    pub limit: Option<u32>,
+}
+
+impl CursorParams {
+    /// Returns true if the client requested cursor pagination.
+    #[must_use]
+    pub fn is_requested(&self) -> bool {
+        self.cursor.is_some() || self.limit.is_some()
+    }
+
+    /// Returns the page size, clamped to `1..=MAX_PAGE_SIZE`.
+    #[must_use]
+    pub fn page_limit(&self) -> usize {
+        self.limit
+            .unwrap_or_else(default_page_size)
+            .clamp(1, MAX_PAGE_SIZE) as usize
+    }
+
+    /// Decodes the cursor, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns `INVALID_CURSOR` if the cursor is malformed or tampered with.
+    pub fn decode(&self) -> Result<Option<PageCursor>, ApiError> {
+        self.cursor
+            .as_deref()
+            .map(|c| {
+                PageCursor::decode(c).map_err(|e| {
+                    api_error_with_details(
+                        ErrorCode::InvalidCursor,
+                        format!("Invalid cursor: {e}"),
+                        Some(serde_json::json!({ "cursor": c })),
+                    )
+                })
+            })
+            .transpose()
     }
 }
 
 /// Paginated response wrapper.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct PaginatedResponse<T> {
     /// The data items.
     pub data: Vec<T>,
-    /// Pagination metadata.
-    pub pagination: PaginationMeta,
+    /// Pagination metadata (offset pagination only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<PaginationMeta>,
+    /// Cursor for the next page (cursor pagination only; absent on the last page).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+impl<T> PaginatedResponse<T> {
+    /// Creates an offset-paginated response.
+    #[must_use]
+    pub fn with_offset(data: Vec<T>, pagination: PaginationMeta) -> Self {
+        Self {
+            data,
+            pagination: Some(pagination),
+            next_cursor: None,
+        }
+    }
+
+    /// Creates a cursor-paginated response.
+    #[must_use]
+    pub fn with_cursor(data: Vec<T>, next_cursor: Option<PageCursor>) -> Self {
+        Self {
+            data,
+            pagination: None,
+            next_cursor: next_cursor.map(|c| c.encode()),
+        }
+    }
 }
 
 /// Pagination metadata.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct PaginationMeta {
     /// Current page number.
     pub page: u32,
@@ -232,37 +545,342 @@ impl PaginationMeta {
 // ============================================================================
 
 /// Request to create a new RFQ.
-#[derive(Debug, Clone, Deserialize)]
+///
+/// Quotes either a single instrument (`base_asset`/`quote_asset`) or a
+/// multi-leg `strategy`, never both.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct CreateRfqRequest {
     /// The client ID requesting the quote.
     pub client_id: String,
+    /// Base asset symbol (e.g., "BTC"). Omit when quoting a strategy.
+    #[serde(default)]
+    pub base_asset: String,
+    /// Quote asset symbol (e.g., "USD"). Omit when quoting a strategy.
+    #[serde(default)]
+    pub quote_asset: String,
+    /// Multi-leg strategy to quote as a package.
+    #[serde(default)]
+    pub strategy: Option<StrategyRequest>,
+    /// Buy or sell side, or `TWO_WAY` to quote both sides.
+    pub side: RfqDirection,
+    /// Requested quantity, as a decimal string.
+    #[serde(with = "decimal")]
+    #[schema(value_type = String, example = "1.5")]
+    pub quantity: Decimal,
+    /// Expiry duration in seconds from now. Omit to apply the default TTL
+    /// of the instrument's asset class, counted from activation.
+    #[serde(default)]
+    pub expiry_seconds: Option<u64>,
+    /// How the quantity may be filled. Defaults to `ALL_OR_NOTHING`.
+    #[serde(default)]
+    pub size_mode: Option<SizeModeRequest>,
+    /// Only these venues may quote. Takes precedence over the blocklist.
+    #[serde(default)]
+    pub venue_allowlist: Option<Vec<String>>,
+    /// These venues must not see the RFQ.
+    #[serde(default)]
+    pub venue_blocklist: Vec<String>,
+    /// When to start collecting quotes (RFC 3339). Omit to start
+    /// immediately; the expiry must fall after this time.
+    #[serde(default)]
+    pub activate_at: Option<String>,
+    /// How a partially failed multi-venue fill is compensated. Defaults to
+    /// `REOFFER_REMAINDER`; `ACCEPT_PARTIAL` requires a size mode that
+    /// allows partial fills.
+    #[serde(default)]
+    pub compensation_policy: Option<CompensationPolicy>,
+    /// How much of the quantity venues are shown. Defaults to `EXACT`.
+    #[serde(default)]
+    pub quantity_disclosure: Option<QuantityDisclosureRequest>,
+}
+
+/// Size negotiation mode in a create-RFQ request.
+#[derive(Debug, Clone, PartialEq, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SizeModeRequest {
+    /// Reject unless the full quantity can be filled.
+    AllOrNothing,
+    /// Fill the full quantity immediately or cancel.
+    FillOrKill,
+    /// Reject if the total fill is below `min_quantity`.
+    MinQuantity {
+        /// Minimum acceptable fill; positive and at most the RFQ quantity.
+        #[serde(with = "decimal")]
+        #[schema(value_type = String, example = "0.5")]
+        min_quantity: Decimal,
+    },
+    /// Fill as much as possible.
+    BestEffort,
+}
+
+impl SizeModeRequest {
+    /// Converts the request into a domain size mode valid for `quantity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `INVALID_MIN_QUANTITY` if `min_quantity` is not positive or
+    /// exceeds `quantity`.
+    pub fn to_size_mode(&self, quantity: Quantity) -> Result<SizeNegotiationMode, ApiError> {
+        let mode = match self {
+            Self::AllOrNothing => SizeNegotiationMode::AllOrNothing,
+            Self::FillOrKill => SizeNegotiationMode::FillOrKill,
+            Self::MinQuantity { min_quantity } => {
+                let min = Quantity::from_decimal(*min_quantity).map_err(|e| {
+                    from_domain_error(&DomainError::InvalidMinQuantity(e.to_string()))
+                })?;
+                SizeNegotiationMode::MinQuantity(min)
+            }
+            Self::BestEffort => SizeNegotiationMode::BestEffort,
+        };
+        mode.validate_for(quantity)
+            .map_err(|e| from_domain_error(&e))?;
+        Ok(mode)
+    }
+}
+
+/// Quantity disclosure in a create-RFQ request.
+#[derive(Debug, Clone, PartialEq, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum QuantityDisclosureRequest {
+    /// Venues see the requested quantity.
+    Exact,
+    /// Venues see the quantity rounded up to a multiple of `bucket`.
+    Bucketed {
+        /// Bucket size; positive.
+        #[serde(with = "decimal")]
+        #[schema(value_type = String, example = "10")]
+        bucket: Decimal,
+    },
+    /// Venues see no quantity and quote indicatively.
+    Hidden,
+}
+
+impl QuantityDisclosureRequest {
+    /// Converts the request into a domain disclosure valid for `quantity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VALIDATION_ERROR` if the bucket is not positive or
+    /// `quantity` cannot be rounded up to it.
+    pub fn to_disclosure(&self, quantity: Quantity) -> Result<QuantityDisclosure, ApiError> {
+        let disclosure = match self {
+            Self::Exact => QuantityDisclosure::Exact,
+            Self::Bucketed { bucket } => QuantityDisclosure::Bucketed {
+                bucket: Quantity::from_decimal(*bucket)
+                    .map_err(|e| validation_error(&format!("invalid disclosure bucket: {e}")))?,
+            },
+            Self::Hidden => QuantityDisclosure::Hidden,
+        };
+        disclosure
+            .validate_for(quantity)
+            .map_err(|e| from_domain_error(&e))?;
+        Ok(disclosure)
+    }
+}
+
+/// Multi-leg strategy in a create-RFQ request.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct StrategyRequest {
+    /// Strategy type (e.g., "STRADDLE", "SPREAD", "CUSTOM").
+    pub strategy_type: String,
+    /// Underlying asset shared by every leg (e.g., "BTC").
+    pub underlying: String,
+    /// The legs composing the strategy.
+    pub legs: Vec<StrategyLegRequest>,
+    /// Optional human-readable description.
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// A single leg of a [`StrategyRequest`].
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct StrategyLegRequest {
     /// Base asset symbol (e.g., "BTC").
     pub base_asset: String,
     /// Quote asset symbol (e.g., "USD").
     pub quote_asset: String,
-    /// Buy or sell side.
+    /// Buy or sell direction of this leg.
     pub side: OrderSide,
-    /// Requested quantity.
-    pub quantity: f64,
-    /// Expiry duration in seconds from now.
-    pub expiry_seconds: u64,
+    /// Quantity multiplier relative to the RFQ quantity.
+    pub ratio: u32,
+}
+
+/// Request to select a quote for execution.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct SelectQuoteRequest {
+    /// ID of the quote to select.
+    pub quote_id: String,
+    /// Reason for selecting even though the RFQ drew fewer competing quotes
+    /// than its quorum requires. Recorded in the audit trail.
+    #[serde(default)]
+    pub quorum_override_reason: Option<String>,
+    /// Side being hit. Required on a two-way RFQ, where it fixes the trade
+    /// direction.
+    #[serde(default)]
+    pub side: Option<OrderSide>,
+}
+
+impl StrategyRequest {
+    /// Converts the request into a validated domain strategy.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VALIDATION_ERROR` if the strategy type or a leg symbol is
+    /// invalid, or if the legs fail strategy validation.
+    pub fn to_strategy(&self) -> Result<Strategy, ApiError> {
+        let strategy_type: StrategyType = self
+            .strategy_type
+            .parse()
+            .map_err(|e| validation_error(&format!("invalid strategy_type: {e}")))?;
+        let legs = self
+            .legs
+            .iter()
+            .map(|leg| {
+                let symbol = Symbol::new(format!("{}/{}", leg.base_asset, leg.quote_asset))
+                    .map_err(|e| validation_error(&format!("invalid leg symbol: {e}")))?;
+                let instrument = Instrument::builder(symbol, AssetClass::CryptoDerivs).build();
+                StrategyLeg::new(instrument, leg.side, leg.ratio).map_err(|e| from_domain_error(&e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Strategy::new(
+            strategy_type,
+            legs,
+            self.underlying.clone(),
+            self.description.clone(),
+        )
+        .map_err(|e| from_domain_error(&e))
+    }
 }
 
 /// RFQ filter parameters.
-#[derive(Debug, Clone, Deserialize, Default)]
+///
+/// All filters are applied by the repository, not in the handler.
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct RfqFilter {
     /// Filter by client ID.
     pub client_id: Option<String>,
-    /// Filter by state.
-    pub state: Option<RfqState>,
+    /// Filter by state; a comma-separated list matches any of them
+    /// (e.g. `EXECUTED,FAILED`).
+    pub state: Option<String>,
+    /// Filter by instrument symbol (e.g. `BTC/USD`).
+    pub symbol: Option<String>,
     /// Filter by base asset.
     pub base_asset: Option<String>,
     /// Filter by quote asset.
     pub quote_asset: Option<String>,
+    /// Only RFQs created at or after this time (RFC 3339).
+    pub created_from: Option<String>,
+    /// Only RFQs created at or before this time (RFC 3339).
+    pub created_to: Option<String>,
+    /// Filter by lifecycle status. Only `scheduled` is supported: RFQs
+    /// waiting for their activation time.
+    pub status: Option<String>,
+    /// Only failed RFQs with this failure code (e.g. `VENUE_TIMEOUT`).
+    pub failure_code: Option<String>,
+}
+
+impl RfqFilter {
+    /// Parses and validates the query parameters into a repository filter.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VALIDATION_ERROR` echoing the offending field and value if a
+    /// state name, status, failure code, symbol, or timestamp is invalid, or if
+    /// `created_from` is after `created_to`.
+    pub fn parse(&self) -> Result<RfqListFilter, ApiError> {
+        let states = match &self.state {
+            Some(list) => list
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(|s| {
+                    s.parse::<RfqState>()
+                        .map_err(|e| invalid_filter("state", s, &e.to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            None => Vec::new(),
+        };
+
+        let symbol = self
+            .symbol
+            .as_deref()
+            .map(|s| Symbol::new(s).map_err(|e| invalid_filter("symbol", s, &e.to_string())))
+            .transpose()?;
+
+        let created_from = parse_filter_timestamp("created_from", self.created_from.as_deref())?;
+        let created_to = parse_filter_timestamp("created_to", self.created_to.as_deref())?;
+        if let (Some(from), Some(to)) = (created_from, created_to)
+            && from > to
+        {
+            return Err(api_error_with_details(
+                ErrorCode::ValidationError,
+                format!("created_from {from} is after created_to {to}"),
+                Some(serde_json::json!({
+                    "field": "created_from",
+                    "created_from": self.created_from,
+                    "created_to": self.created_to,
+                })),
+            ));
+        }
+
+        let scheduled_as_of = match self.status.as_deref().map(str::trim) {
+            None => None,
+            Some(status) if status.eq_ignore_ascii_case("scheduled") => Some(Timestamp::now()),
+            Some(status) => {
+                return Err(invalid_filter(
+                    "status",
+                    status,
+                    "expected one of: scheduled",
+                ));
+            }
+        };
+
+        let failure_code = self
+            .failure_code
+            .as_deref()
+            .map(str::trim)
+            .map(|code| {
+                code.parse::<FailureCode>()
+                    .map_err(|e| invalid_filter("failure_code", code, &e.to_string()))
+            })
+            .transpose()?;
+
+        Ok(RfqListFilter {
+            client_id: self.client_id.as_deref().map(CounterpartyId::new),
+            states,
+            symbol,
+            base_asset: self.base_asset.clone(),
+            quote_asset: self.quote_asset.clone(),
+            created_from,
+            created_to,
+            scheduled_as_of,
+            failure_code,
+            side: None,
+        })
+    }
+}
+
+fn parse_filter_timestamp(field: &str, value: Option<&str>) -> Result<Option<Timestamp>, ApiError> {
+    value.map(|v| parse_timestamp(field, v)).transpose()
+}
+
+fn parse_timestamp(field: &str, value: &str) -> Result<Timestamp, ApiError> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .map(|dt| Timestamp::from(dt.with_timezone(&chrono::Utc)))
+        .map_err(|e| invalid_filter(field, value, &e.to_string()))
+}
+
+fn invalid_filter(field: &str, value: &str, reason: &str) -> ApiError {
+    api_error_with_details(
+        ErrorCode::ValidationError,
+        format!("Invalid {field} '{value}': {reason}"),
+        Some(serde_json::json!({ "field": field, "value": value })),
+    )
 }
 
 /// RFQ response DTO.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct RfqResponse {
     /// RFQ ID.
     pub id: String,
@@ -270,20 +888,359 @@ pub struct RfqResponse {
     pub client_id: String,
     /// Instrument symbol.
     pub symbol: String,
-    /// Order side.
-    pub side: OrderSide,
+    /// Order side, or `TWO_WAY` until a side is selected.
+    pub side: RfqDirection,
     /// Quantity.
     pub quantity: String,
     /// Current state.
     pub state: RfqState,
     /// Expiry timestamp (ISO 8601).
     pub expires_at: String,
+    /// When quote collection starts (ISO 8601), if scheduled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activate_at: Option<String>,
     /// Number of quotes received.
     pub quote_count: usize,
+    /// Quotes received.
+    pub quotes: Vec<QuoteResponse>,
+    /// ID of the selected quote, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_quote_id: Option<String>,
+    /// When the execution must finish (ISO 8601), once it has started.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_deadline: Option<String>,
+    /// Multi-leg strategy, if this RFQ quotes a package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<StrategyResponse>,
+    /// How the quantity may be filled.
+    pub size_mode: SizeModeResponse,
+    /// How a partially failed multi-venue fill is compensated.
+    pub compensation_policy: CompensationPolicy,
+    /// How much of the quantity venues are shown.
+    pub quantity_disclosure: QuantityDisclosureResponse,
+    /// Venues this RFQ may be sent to, if restricted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub venue_allowlist: Option<Vec<String>>,
+    /// Venues this RFQ must not be sent to.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub venue_blocklist: Vec<String>,
+    /// Failure category, if the RFQ failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_code: Option<FailureCode>,
+    /// Why the RFQ failed, if it did.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
     /// Created timestamp (ISO 8601).
     pub created_at: String,
     /// Updated timestamp (ISO 8601).
     pub updated_at: String,
+    /// Non-blocking warnings about the request; only set on creation.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<RfqWarningResponse>,
+}
+
+/// A non-blocking warning about a newly created RFQ.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RfqWarningResponse {
+    /// `POSSIBLE_DUPLICATE`.
+    pub code: String,
+    /// Human-readable explanation.
+    pub message: String,
+    /// The open RFQ the warning is about.
+    pub rfq_id: String,
+}
+
+impl RfqWarningResponse {
+    /// Warns that the new RFQ nearly duplicates the open RFQ `open`.
+    fn possible_duplicate(open: &Rfq) -> Self {
+        Self {
+            code: "POSSIBLE_DUPLICATE".to_string(),
+            message: format!(
+                "client already has an open {} {} RFQ for {}",
+                open.side(),
+                open.instrument().symbol(),
+                open.quantity()
+            ),
+            rfq_id: open.id().to_string(),
+        }
+    }
+}
+
+/// Size negotiation mode response DTO.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SizeModeResponse {
+    /// Reject unless the full quantity can be filled.
+    AllOrNothing,
+    /// Fill the full quantity immediately or cancel.
+    FillOrKill,
+    /// Reject if the total fill is below `min_quantity`.
+    MinQuantity {
+        /// Minimum acceptable fill.
+        min_quantity: String,
+    },
+    /// Fill as much as possible.
+    BestEffort,
+}
+
+impl From<&SizeNegotiationMode> for SizeModeResponse {
+    fn from(mode: &SizeNegotiationMode) -> Self {
+        match mode {
+            SizeNegotiationMode::AllOrNothing => Self::AllOrNothing,
+            SizeNegotiationMode::FillOrKill => Self::FillOrKill,
+            SizeNegotiationMode::MinQuantity(min) => Self::MinQuantity {
+                min_quantity: min.to_string(),
+            },
+            SizeNegotiationMode::BestEffort => Self::BestEffort,
+        }
+    }
+}
+
+/// Quantity disclosure response DTO.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum QuantityDisclosureResponse {
+    /// Venues see the requested quantity.
+    Exact,
+    /// Venues see the quantity rounded up to a multiple of `bucket`.
+    Bucketed {
+        /// Bucket size.
+        bucket: String,
+    },
+    /// Venues see no quantity.
+    Hidden,
+}
+
+impl From<QuantityDisclosure> for QuantityDisclosureResponse {
+    fn from(disclosure: QuantityDisclosure) -> Self {
+        match disclosure {
+            QuantityDisclosure::Exact => Self::Exact,
+            QuantityDisclosure::Bucketed { bucket } => Self::Bucketed {
+                bucket: bucket.to_string(),
+            },
+            QuantityDisclosure::Hidden => Self::Hidden,
+        }
+    }
+}
+
+/// Multi-leg strategy response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct StrategyResponse {
+    /// Strategy type.
+    pub strategy_type: String,
+    /// Underlying asset.
+    pub underlying: String,
+    /// Strategy legs.
+    pub legs: Vec<StrategyLegResponse>,
+}
+
+/// Strategy leg response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct StrategyLegResponse {
+    /// Instrument symbol.
+    pub symbol: String,
+    /// Leg direction.
+    pub side: OrderSide,
+    /// Quantity multiplier.
+    pub ratio: u32,
+}
+
+impl From<&Strategy> for StrategyResponse {
+    fn from(strategy: &Strategy) -> Self {
+        Self {
+            strategy_type: strategy.strategy_type().to_string(),
+            underlying: strategy.underlying().to_string(),
+            legs: strategy
+                .legs()
+                .iter()
+                .map(|leg| StrategyLegResponse {
+                    symbol: leg.instrument().symbol().to_string(),
+                    side: leg.side(),
+                    ratio: leg.ratio(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Quote response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct QuoteResponse {
+    /// Quote ID.
+    pub id: String,
+    /// Quoting venue.
+    pub venue_id: String,
+    /// Headline price (magnitude of the net premium for strategy quotes).
+    pub price: String,
+    /// Signed price: net premium for strategy quotes, negative for credits.
+    pub net_premium: String,
+    /// Net premium including settlement gas for DeFi quotes; equal to
+    /// `net_premium` for quotes that were not costed.
+    pub all_in_price: String,
+    /// Settlement gas in the quote currency, when estimated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_cost: Option<String>,
+    /// Whether the quote needs gas that could not be estimated, so
+    /// `all_in_price` is the raw price.
+    pub gas_estimate_missing: bool,
+    /// Pricing kind (`OUTRIGHT` or `NET_PREMIUM`).
+    pub kind: String,
+    /// Firmness (`FIRM` or `INDICATIVE`).
+    pub firmness: String,
+    /// Per-leg breakdown for strategy quotes.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub legs: Vec<QuoteLegPriceResponse>,
+    /// Quoted quantity.
+    pub quantity: String,
+    /// Side the client trades at this price, on two-way RFQs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<OrderSide>,
+    /// Expiry timestamp (ISO 8601).
+    pub valid_until: String,
+}
+
+/// Per-leg price of a strategy quote.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct QuoteLegPriceResponse {
+    /// Index of the leg in the RFQ strategy.
+    pub leg_index: usize,
+    /// Leg price, if disclosed by the venue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+}
+
+impl From<&Quote> for QuoteResponse {
+    fn from(quote: &Quote) -> Self {
+        let kind = match quote.kind() {
+            QuoteKind::Outright => "OUTRIGHT",
+            QuoteKind::NetPremium { .. } => "NET_PREMIUM",
+        };
+        Self {
+            id: quote.id().to_string(),
+            venue_id: quote.venue_id().to_string(),
+            price: quote.price().to_string(),
+            net_premium: quote.net_premium().to_string(),
+            all_in_price: quote.all_in_price().to_string(),
+            gas_cost: quote
+                .all_in_cost()
+                .and_then(|cost| cost.gas_cost())
+                .map(|gas| gas.to_string()),
+            gas_estimate_missing: quote.all_in_cost().is_some_and(|cost| !cost.is_estimated()),
+            kind: kind.to_string(),
+            firmness: quote.firmness().to_string(),
+            legs: quote
+                .leg_prices()
+                .iter()
+                .map(|leg| QuoteLegPriceResponse {
+                    leg_index: leg.leg_index(),
+                    price: leg.price().map(|p| p.to_string()),
+                })
+                .collect(),
+            quantity: quote.quantity().to_string(),
+            side: quote.side(),
+            valid_until: quote.valid_until().to_string(),
+        }
+    }
+}
+
+/// Quote history response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct QuoteHistoryResponse {
+    /// RFQ ID.
+    pub rfq_id: String,
+    /// Every quote the RFQ has seen, live quotes first.
+    pub quotes: Vec<QuoteHistoryItem>,
+    /// Points at which the best price on a side improved, oldest first.
+    pub best_price_timeline: Vec<BestPricePointResponse>,
+}
+
+/// A quote in an RFQ's history.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct QuoteHistoryItem {
+    /// Quote ID.
+    pub quote_id: String,
+    /// Quoting venue.
+    pub venue_id: String,
+    /// Side the client trades at this price.
+    pub side: OrderSide,
+    /// Quoted price.
+    pub price: String,
+    /// Quoted quantity.
+    pub quantity: String,
+    /// Quote status.
+    pub status: QuoteStatus,
+    /// Quote that replaced this one, if superseded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub superseded_by: Option<String>,
+    /// Rank on its side when quote collection completed (1 = best).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rank: Option<u32>,
+    /// Receipt timestamp (ISO 8601).
+    pub received_at: String,
+    /// Expiry timestamp (ISO 8601).
+    pub valid_until: String,
+}
+
+/// A best-price improvement.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BestPricePointResponse {
+    /// When the improving quote was received (ISO 8601).
+    pub at: String,
+    /// Side the client trades at this price.
+    pub side: OrderSide,
+    /// Quote that set the new best price.
+    pub quote_id: String,
+    /// Venue that quoted it.
+    pub venue_id: String,
+    /// New best price.
+    pub price: String,
+}
+
+impl From<&QuoteHistoryEntry<'_>> for QuoteHistoryItem {
+    fn from(entry: &QuoteHistoryEntry<'_>) -> Self {
+        Self {
+            quote_id: entry.quote.id().to_string(),
+            venue_id: entry.quote.venue_id().to_string(),
+            side: entry.side,
+            price: entry.quote.price().to_string(),
+            quantity: entry.quote.quantity().to_string(),
+            status: entry.status,
+            superseded_by: entry.superseded_by.map(|id| id.to_string()),
+            rank: entry.rank,
+            received_at: entry.quote.received_at().to_string(),
+            valid_until: entry.quote.valid_until().to_string(),
+        }
+    }
+}
+
+impl From<&BestPricePoint> for BestPricePointResponse {
+    fn from(point: &BestPricePoint) -> Self {
+        Self {
+            at: point.at.to_string(),
+            side: point.side,
+            quote_id: point.quote_id.to_string(),
+            venue_id: point.venue_id.to_string(),
+            price: point.price.to_string(),
+        }
+    }
+}
+
+impl From<&Rfq> for QuoteHistoryResponse {
+    fn from(rfq: &Rfq) -> Self {
+        Self {
+            rfq_id: rfq.id().to_string(),
+            quotes: rfq
+                .quote_history()
+                .iter()
+                .map(QuoteHistoryItem::from)
+                .collect(),
+            best_price_timeline: rfq
+                .best_price_timeline()
+                .iter()
+                .map(BestPricePointResponse::from)
+                .collect(),
+        }
+    }
 }
 
 impl From<&Rfq> for RfqResponse {
@@ -292,13 +1249,32 @@ impl From<&Rfq> for RfqResponse {
             id: rfq.id().to_string(),
             client_id: rfq.client_id().to_string(),
             symbol: rfq.instrument().symbol().to_string(),
-            side: rfq.side(),
+            side: rfq.direction(),
             quantity: rfq.quantity().to_string(),
             state: rfq.state(),
             expires_at: rfq.expires_at().to_string(),
+            activate_at: rfq.activate_at().map(|t| t.to_string()),
             quote_count: rfq.quotes().len(),
+            quotes: rfq.quotes().iter().map(QuoteResponse::from).collect(),
+            selected_quote_id: rfq.selected_quote_id().map(|id| id.to_string()),
+            execution_deadline: rfq.execution_deadline().map(|t| t.to_string()),
+            strategy: rfq.strategy().map(StrategyResponse::from),
+            size_mode: SizeModeResponse::from(rfq.size_mode()),
+            compensation_policy: rfq.compensation_policy(),
+            quantity_disclosure: QuantityDisclosureResponse::from(rfq.quantity_disclosure()),
+            venue_allowlist: rfq
+                .venue_allowlist()
+                .map(|venues| venues.iter().map(ToString::to_string).collect()),
+            venue_blocklist: rfq
+                .venue_blocklist()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            failure_code: rfq.failure_reason().map(FailureReason::code),
+            failure_reason: rfq.failure_reason().map(|r| r.detail().to_string()),
             created_at: rfq.created_at().to_string(),
             updated_at: rfq.updated_at().to_string(),
+            warnings: Vec::new(),
         }
     }
 }
@@ -308,7 +1284,7 @@ impl From<&Rfq> for RfqResponse {
 // ============================================================================
 
 /// Venue response DTO.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct VenueResponse {
     /// Venue ID.
     pub id: String,
@@ -316,830 +1292,8150 @@ pub struct VenueResponse {
     pub name: String,
     /// Venue type.
     pub venue_type: VenueType,
-    /// Whether the venue is enabled.
+    /// Whether the venue is sent RFQs (live or shadow).
     pub enabled: bool,
+    /// How the venue takes part in the RFQ flow.
+    pub mode: VenueMode,
     /// Health status.
     pub health: VenueHealth,
     /// Supported instruments count.
     pub supported_instruments: usize,
+    /// Average request latency in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_latency_ms: Option<u64>,
+    /// Median request latency in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_p50_ms: Option<u64>,
+    /// 90th percentile request latency in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_p90_ms: Option<u64>,
+    /// 99th percentile request latency in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_p99_ms: Option<u64>,
 }
 
 impl From<&Venue> for VenueResponse {
     fn from(venue: &Venue) -> Self {
+        let metrics = venue.metrics();
         Self {
             id: venue.id().to_string(),
             name: venue.name().to_string(),
             venue_type: venue.venue_type(),
             enabled: venue.is_enabled(),
+            mode: venue.mode(),
             health: venue.health(),
             supported_instruments: venue.supported_instruments().len(),
+            average_latency_ms: metrics.average_latency_ms(),
+            latency_p50_ms: metrics.latency_p50(),
+            latency_p90_ms: metrics.latency_p90(),
+            latency_p99_ms: metrics.latency_p99(),
         }
     }
 }
 
 /// Request to update venue configuration.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct UpdateVenueRequest {
-    /// Whether the venue is enabled.
+    /// Whether the venue is enabled: `true` sets `LIVE`, `false` sets
+    /// `DISABLED`. Ignored when `mode` is given.
     pub enabled: Option<bool>,
+    /// How the venue takes part in the RFQ flow.
+    #[serde(default)]
+    pub mode: Option<VenueMode>,
     /// Priority (lower is higher priority).
     pub priority: Option<u32>,
+    /// Maximum concurrent quote requests to the venue.
+    pub max_concurrent_requests: Option<u32>,
 }
 
-// ============================================================================
-// Trade DTOs
-// ============================================================================
+/// Venue settings snapshot DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VenueSettingsResponse {
+    /// Whether the venue is sent RFQs (live or shadow).
+    pub enabled: bool,
+    /// How the venue takes part in the RFQ flow.
+    pub mode: VenueMode,
+    /// Request timeout in milliseconds.
+    pub timeout_ms: u64,
+    /// Maximum concurrent requests.
+    pub max_concurrent_requests: u32,
+    /// Whether TLS is used.
+    pub use_tls: bool,
+    /// Venue-specific settings.
+    pub settings: HashMap<String, String>,
+}
 
-/// Trade filter parameters.
-#[derive(Debug, Clone, Deserialize, Default)]
-pub struct TradeFilter {
-    /// Filter by RFQ ID.
-    pub rfq_id: Option<String>,
-    /// Filter by venue ID.
-    pub venue_id: Option<String>,
+impl From<&VenueSettings> for VenueSettingsResponse {
+    fn from(settings: &VenueSettings) -> Self {
+        Self {
+            enabled: settings.mode().receives_rfqs(),
+            mode: settings.mode(),
+            timeout_ms: settings.config().timeout_ms(),
+            max_concurrent_requests: settings.config().max_concurrent_requests(),
+            use_tls: settings.config().use_tls(),
+            settings: settings.config().settings().clone(),
+        }
+    }
 }
 
-/// Trade response DTO.
-#[derive(Debug, Clone, Serialize)]
-pub struct TradeResponse {
-    /// Trade ID.
-    pub id: String,
-    /// RFQ ID.
-    pub rfq_id: String,
-    /// Quote ID.
-    pub quote_id: String,
+/// Venue configuration history entry DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VenueConfigChangeResponse {
+    /// History entry ID.
+    pub id: u64,
     /// Venue ID.
     pub venue_id: String,
-    /// Execution price.
-    pub price: String,
-    /// Executed quantity.
-    pub quantity: String,
-    /// Settlement state.
-    pub settlement_state: String,
-    /// Created timestamp (ISO 8601).
-    pub created_at: String,
+    /// Settings before the change.
+    pub old_config: VenueSettingsResponse,
+    /// Settings after the change.
+    pub new_config: VenueSettingsResponse,
+    /// Fields that differ between the old and new settings.
+    pub changed_fields: Vec<String>,
+    /// Subject that made the change.
+    pub changed_by: String,
+    /// When the change was made.
+    pub changed_at: String,
+    /// History entry this change rolled back, if it was a rollback.
+    pub rollback_of: Option<u64>,
 }
 
-impl From<&Trade> for TradeResponse {
-    fn from(trade: &Trade) -> Self {
+impl From<&VenueConfigChange> for VenueConfigChangeResponse {
+    fn from(change: &VenueConfigChange) -> Self {
         Self {
-            id: trade.id().to_string(),
-            rfq_id: trade.rfq_id().to_string(),
-            quote_id: trade.quote_id().to_string(),
-            venue_id: trade.venue_id().to_string(),
-            price: trade.price().to_string(),
-            quantity: trade.quantity().to_string(),
-            settlement_state: trade.settlement_state().to_string(),
-            created_at: trade.created_at().to_string(),
+            id: change.id(),
+            venue_id: change.venue_id().to_string(),
+            old_config: VenueSettingsResponse::from(change.old_settings()),
+            new_config: VenueSettingsResponse::from(change.new_settings()),
+            changed_fields: change.changed_fields(),
+            changed_by: change.changed_by().to_string(),
+            changed_at: change.changed_at().to_string(),
+            rollback_of: change.rollback_of(),
         }
     }
 }
 
-// ============================================================================
-// RFQ Handlers
-// ============================================================================
-
-/// List RFQs with filtering and pagination.
-///
-/// # Errors
+/// How a venue's quotes compared with the live quotes, over the MM
+/// performance window.
 ///
-/// Returns an error response if the repository query fails.
+/// For a venue in shadow mode, `would_have_won` counts the quotes that
+/// would have ranked best had the venue been live.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ShadowVenueReportResponse {
+    /// Venue ID.
+    pub venue_id: String,
+    /// Current mode of the venue.
+    pub mode: VenueMode,
+    /// Market maker behind the venue.
+    pub mm_id: String,
+    /// RFQs sent to the venue.
+    pub rfqs_received: u64,
+    /// Quotes the venue returned.
+    pub quotes_provided: u64,
+    /// Quotes that ranked best among the live quotes.
+    pub would_have_won: u64,
+    /// `would_have_won` as a percentage of quotes provided.
+    pub win_rate_pct: Option<f64>,
+    /// Average rank of the venue's quotes (lower = better).
+    pub competitiveness_score: Option<f64>,
+    /// Start of the window (ISO 8601).
+    pub window_start: String,
+    /// End of the window (ISO 8601).
+    pub window_end: String,
+}
+
+// ============================================================================
+// Instrument Reference Data DTOs
+// ============================================================================
+
+/// Instrument reference data response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct InstrumentReferenceDataResponse {
+    /// Instrument symbol (e.g. `BTC/USD`).
+    pub symbol: String,
+    /// Minimum price increment.
+    pub tick_size: String,
+    /// Minimum quantity increment.
+    pub lot_size: String,
+    /// Minimum order size.
+    pub min_order_size: String,
+    /// Maximum order size, if limited.
+    pub max_order_size: Option<String>,
+    /// Contract multiplier.
+    pub contract_multiplier: String,
+}
+
+impl From<&InstrumentReferenceData> for InstrumentReferenceDataResponse {
+    fn from(data: &InstrumentReferenceData) -> Self {
+        Self {
+            symbol: data.symbol().to_string(),
+            tick_size: data.tick_size().to_string(),
+            lot_size: data.lot_size().to_string(),
+            min_order_size: data.min_order_size().to_string(),
+            max_order_size: data.max_order_size().map(|q| q.to_string()),
+            contract_multiplier: data.contract_multiplier().to_string(),
+        }
+    }
+}
+
+/// Derived liquidity tier response DTO.
+///
+/// Decimal values are strings to avoid floating point rounding.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LiquidityResponse {
+    /// Instrument symbol (e.g. `BTC/USD`).
+    pub symbol: String,
+    /// Derived tier: `Liquid`, `SemiLiquid` or `Illiquid`.
+    pub classification: String,
+    /// Executed notional over the classification window.
+    pub volume: String,
+    /// RFQs created over the classification window.
+    pub rfq_count: u64,
+    /// Average number of quotes per RFQ.
+    pub avg_quotes_per_rfq: String,
+    /// Average top-of-book spread in basis points, if the instrument has an
+    /// order book.
+    pub avg_spread_bps: Option<String>,
+    /// When the tier was computed.
+    pub computed_at: String,
+}
+
+impl From<&LiquidityAssessment> for LiquidityResponse {
+    fn from(assessment: &LiquidityAssessment) -> Self {
+        let metrics = &assessment.metrics;
+        Self {
+            symbol: assessment.symbol.to_string(),
+            classification: assessment.classification.to_string(),
+            volume: metrics.volume.normalize().to_string(),
+            rfq_count: metrics.rfq_count,
+            avg_quotes_per_rfq: metrics.avg_quotes_per_rfq.normalize().to_string(),
+            avg_spread_bps: metrics.avg_spread_bps.map(|s| s.normalize().to_string()),
+            computed_at: assessment.computed_at.to_string(),
+        }
+    }
+}
+
+/// Per-venue outcome report of an RFQ's quote aggregation.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AggregationReportResponse {
+    /// RFQ ID.
+    pub rfq_id: String,
+    /// Number of venues per outcome.
+    pub counts: VenueOutcomeCountsResponse,
+    /// One outcome per venue, ordered by venue ID.
+    pub venues: Vec<VenueOutcomeResponse>,
+    /// When aggregation finished (ISO 8601).
+    pub generated_at: String,
+}
+
+/// Number of venues per aggregation outcome.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VenueOutcomeCountsResponse {
+    /// Venues that returned a usable quote.
+    pub responded: u32,
+    /// Venues that timed out.
+    pub timed_out: u32,
+    /// Venues that answered with an error.
+    pub errored: u32,
+    /// Venues whose quotes were all filtered out.
+    pub filtered: u32,
+    /// Venues that were not asked.
+    pub excluded: u32,
+}
+
+/// What happened with one venue during quote aggregation.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VenueOutcomeResponse {
+    /// Venue ID.
+    pub venue_id: String,
+    /// `RESPONDED`, `TIMEOUT`, `ERROR`, `FILTERED_EXPIRED`,
+    /// `FILTERED_SHORT_TTL`, `FILTERED_PRICE_DEVIATION` or `EXCLUDED`.
+    pub status: String,
+    /// Response latency in milliseconds, if measured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    /// ID of the venue's first quote, if it responded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_id: Option<String>,
+    /// Error message or exclusion reason.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl From<&AggregationReport> for AggregationReportResponse {
+    fn from(report: &AggregationReport) -> Self {
+        let counts = report.counts();
+        Self {
+            rfq_id: report.rfq_id.to_string(),
+            counts: VenueOutcomeCountsResponse {
+                responded: counts.responded,
+                timed_out: counts.timed_out,
+                errored: counts.errored,
+                filtered: counts.filtered,
+                excluded: counts.excluded,
+            },
+            venues: report
+                .venue_outcomes
+                .iter()
+                .map(VenueOutcomeResponse::from)
+                .collect(),
+            generated_at: report.generated_at.to_string(),
+        }
+    }
+}
+
+/// How a quote was ranked and what its score was made of.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RankingExplanationResponse {
+    /// RFQ ID.
+    pub rfq_id: String,
+    /// Quote ID.
+    pub quote_id: String,
+    /// Venue that quoted.
+    pub venue_id: String,
+    /// Rank among the RFQ's quotes (1 = best).
+    pub rank: usize,
+    /// Score the quote was ranked on (higher = better).
+    pub score: String,
+    /// Factors that made up the score; their contributions add up to it.
+    /// Empty if the ranking strategy does not break its scores down.
+    pub score_breakdown: Vec<ScoreComponentResponse>,
+    /// When the quotes were ranked (ISO 8601).
+    pub generated_at: String,
+}
+
+/// One factor's part in a quote's score.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ScoreComponentResponse {
+    /// `PRICE`, `ALL_IN_PRICE`, `SIZE`, `RELIABILITY` or `FRESHNESS`.
+    pub factor: String,
+    /// Factor value for the quote, before weighting.
+    pub raw_value: String,
+    /// Weight applied to the factor; negative where lower is better.
+    pub weight: String,
+    /// What the factor adds to the score.
+    pub contribution: String,
+}
+
+impl RankingExplanationResponse {
+    fn new(report: &AggregationReport, ranking: &QuoteRanking) -> Self {
+        Self {
+            rfq_id: report.rfq_id.to_string(),
+            quote_id: ranking.quote_id.to_string(),
+            venue_id: ranking.venue_id.to_string(),
+            rank: ranking.rank,
+            score: ranking.score.normalize().to_string(),
+            score_breakdown: ranking
+                .score_breakdown
+                .iter()
+                .map(ScoreComponentResponse::from)
+                .collect(),
+            generated_at: report.generated_at.to_string(),
+        }
+    }
+}
+
+impl From<&ScoreComponent> for ScoreComponentResponse {
+    fn from(component: &ScoreComponent) -> Self {
+        Self {
+            factor: component.factor.to_string(),
+            raw_value: component.raw_value.normalize().to_string(),
+            weight: component.weight.normalize().to_string(),
+            contribution: component.contribution.normalize().to_string(),
+        }
+    }
+}
+
+impl From<&VenueOutcome> for VenueOutcomeResponse {
+    fn from(outcome: &VenueOutcome) -> Self {
+        let (status, latency_ms, quote_id, reason) = match &outcome.outcome {
+            VenueOutcomeKind::Responded {
+                latency_ms,
+                quote_id,
+            } => ("RESPONDED", *latency_ms, Some(quote_id.clone()), None),
+            VenueOutcomeKind::Timeout => ("TIMEOUT", None, None, None),
+            VenueOutcomeKind::Error { reason } => ("ERROR", None, None, Some(reason.clone())),
+            VenueOutcomeKind::FilteredExpired => ("FILTERED_EXPIRED", None, None, None),
+            VenueOutcomeKind::FilteredShortTtl => ("FILTERED_SHORT_TTL", None, None, None),
+            VenueOutcomeKind::FilteredPriceDeviation {
+                basis,
+                deviation_pct,
+            } => (
+                "FILTERED_PRICE_DEVIATION",
+                None,
+                None,
+                Some(format!("deviated {} from the {}", deviation_pct, basis)),
+            ),
+            VenueOutcomeKind::Excluded { reason } => {
+                ("EXCLUDED", None, None, Some(reason.to_string()))
+            }
+        };
+        Self {
+            venue_id: outcome.venue_id.to_string(),
+            status: status.to_string(),
+            latency_ms,
+            quote_id,
+            reason,
+        }
+    }
+}
+
+/// Request to create or replace instrument reference data.
+///
+/// Decimal values are strings to avoid floating point rounding.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct InstrumentReferenceDataRequest {
+    /// Minimum price increment.
+    pub tick_size: String,
+    /// Minimum quantity increment.
+    pub lot_size: String,
+    /// Minimum order size (defaults to zero).
+    pub min_order_size: Option<String>,
+    /// Maximum order size (defaults to unlimited).
+    pub max_order_size: Option<String>,
+    /// Contract multiplier (defaults to one).
+    pub contract_multiplier: Option<String>,
+}
+
+impl InstrumentReferenceDataRequest {
+    /// Validates the request into reference data for `symbol`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if a value is malformed or violates the
+    /// reference data invariants.
+    pub fn into_reference_data(self, symbol: Symbol) -> Result<InstrumentReferenceData, ApiError> {
+        let tick_size = parse_decimal("tick_size", &self.tick_size)?;
+        let lot_size = parse_decimal("lot_size", &self.lot_size)?;
+        let min = self
+            .min_order_size
+            .map(|v| parse_quantity("min_order_size", &v))
+            .transpose()?
+            .unwrap_or(Quantity::ZERO);
+        let max = self
+            .max_order_size
+            .map(|v| parse_quantity("max_order_size", &v))
+            .transpose()?;
+        let multiplier = self
+            .contract_multiplier
+            .map(|v| parse_decimal("contract_multiplier", &v))
+            .transpose()?
+            .unwrap_or(Decimal::ONE);
+
+        InstrumentReferenceData::new(symbol, tick_size, lot_size)
+            .and_then(|data| data.with_order_size_limits(min, max))
+            .and_then(|data| data.with_contract_multiplier(multiplier))
+            .map_err(|e| from_domain_error(&e))
+    }
+}
+
+// ============================================================================
+// Platform Fee DTOs
+// ============================================================================
+
+/// A notional band of a platform fee rate.
+///
+/// Decimal values are strings to avoid floating point rounding.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FeeBandDto {
+    /// Lowest notional (inclusive) charged at this band's rate.
+    pub min_notional: String,
+    /// Fee in basis points of notional.
+    pub bps: String,
+}
+
+/// Platform fee rate for one asset class.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AssetClassFeeRateDto {
+    /// Asset class (e.g. `CRYPTO_SPOT`).
+    pub asset_class: String,
+    /// Notional bands, lowest first; the first starts at zero.
+    pub bands: Vec<FeeBandDto>,
+    /// Minimum fee charged, in the quote asset.
+    pub min_fee: Option<String>,
+    /// Maximum fee charged, in the quote asset.
+    pub max_fee: Option<String>,
+}
+
+impl From<&AssetClassFeeRate> for AssetClassFeeRateDto {
+    fn from(rate: &AssetClassFeeRate) -> Self {
+        Self {
+            asset_class: rate.asset_class().to_string(),
+            bands: rate
+                .bands()
+                .iter()
+                .map(|band| FeeBandDto {
+                    min_notional: band.min_notional.to_string(),
+                    bps: band.bps.to_string(),
+                })
+                .collect(),
+            min_fee: rate.min_fee().map(|fee| fee.to_string()),
+            max_fee: rate.max_fee().map(|fee| fee.to_string()),
+        }
+    }
+}
+
+impl AssetClassFeeRateDto {
+    fn into_rate(self) -> Result<AssetClassFeeRate, ApiError> {
+        let asset_class = AssetClass::from_str(&self.asset_class)
+            .map_err(|_| validation_error(&format!("invalid asset_class: {}", self.asset_class)))?;
+        let bands = self
+            .bands
+            .iter()
+            .map(|band| {
+                Ok(FeeBand::new(
+                    parse_decimal("min_notional", &band.min_notional)?,
+                    parse_decimal("bps", &band.bps)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, ApiError>>()?;
+        let min_fee = self
+            .min_fee
+            .map(|v| parse_decimal("min_fee", &v))
+            .transpose()?;
+        let max_fee = self
+            .max_fee
+            .map(|v| parse_decimal("max_fee", &v))
+            .transpose()?;
+
+        AssetClassFeeRate::new(asset_class, bands, min_fee, max_fee)
+            .map_err(|e| from_domain_error(&e))
+    }
+}
+
+/// Platform fee schedule response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PlatformFeeScheduleResponse {
+    /// Counterparty tier; `default` applies to unassigned counterparties.
+    pub tier: String,
+    /// Counterparties assigned to the tier.
+    pub counterparties: Vec<String>,
+    /// Per-asset-class rates.
+    pub rates: Vec<AssetClassFeeRateDto>,
+    /// Creation time.
+    pub created_at: String,
+    /// Last update time.
+    pub updated_at: String,
+}
+
+impl From<&PlatformFeeSchedule> for PlatformFeeScheduleResponse {
+    fn from(schedule: &PlatformFeeSchedule) -> Self {
+        Self {
+            tier: schedule.tier().to_string(),
+            counterparties: schedule
+                .counterparties()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            rates: schedule
+                .rates()
+                .iter()
+                .map(AssetClassFeeRateDto::from)
+                .collect(),
+            created_at: schedule.created_at().to_string(),
+            updated_at: schedule.updated_at().to_string(),
+        }
+    }
+}
+
+/// Request to create or replace a tier's platform fee schedule.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct PlatformFeeScheduleRequest {
+    /// Counterparties assigned to the tier.
+    #[serde(default)]
+    pub counterparties: Vec<String>,
+    /// Per-asset-class rates; asset classes without a rate are not charged.
+    pub rates: Vec<AssetClassFeeRateDto>,
+}
+
+impl PlatformFeeScheduleRequest {
+    /// Validates the request into a schedule for `tier`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if a value is malformed or violates the
+    /// fee schedule invariants.
+    pub fn into_schedule(self, tier: String) -> Result<PlatformFeeSchedule, ApiError> {
+        let rates = self
+            .rates
+            .into_iter()
+            .map(AssetClassFeeRateDto::into_rate)
+            .collect::<Result<Vec<_>, ApiError>>()?;
+        let counterparties = self
+            .counterparties
+            .into_iter()
+            .map(CounterpartyId::new)
+            .collect();
+
+        PlatformFeeSchedule::new(tier, counterparties, rates).map_err(|e| from_domain_error(&e))
+    }
+}
+
+/// Fee waiver response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct FeeWaiverResponse {
+    /// Counterparty the waiver belongs to.
+    pub counterparty_id: String,
+    /// Discount on the platform fee, in percent.
+    pub discount_pct: String,
+    /// When the waiver expires, if it does.
+    pub expires_at: Option<String>,
+    /// Whether the waiver applies now.
+    pub active: bool,
+}
+
+impl From<&FeeWaiver> for FeeWaiverResponse {
+    fn from(waiver: &FeeWaiver) -> Self {
+        Self {
+            counterparty_id: waiver.counterparty_id().to_string(),
+            discount_pct: waiver.discount_pct().to_string(),
+            expires_at: waiver.expires_at().map(|t| t.to_string()),
+            active: waiver.is_active_at(Timestamp::now()),
+        }
+    }
+}
+
+/// Request to create or replace a counterparty's fee waiver.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct FeeWaiverRequest {
+    /// Discount on the platform fee, in percent (0 to 100).
+    pub discount_pct: String,
+    /// When the waiver expires (RFC 3339); omit for no expiry.
+    pub expires_at: Option<String>,
+}
+
+// ============================================================================
+// Settlement Batch DTOs
+// ============================================================================
+
+/// Settlement batch filter parameters.
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SettlementBatchFilter {
+    /// Filter by batch status.
+    pub status: Option<NettingBatchStatus>,
+}
+
+/// Settlement netting batch response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SettlementBatchResponse {
+    /// Batch ID.
+    pub id: String,
+    /// Client whose trades are netted.
+    pub client_id: String,
+    /// Market maker venue on the other side of the trades.
+    pub venue_id: String,
+    /// Instrument symbol.
+    pub symbol: String,
+    /// Chain the batch settles on.
+    pub blockchain: String,
+    /// Constituent trades.
+    pub trade_ids: Vec<String>,
+    /// Net base amount delivered to the client (negative when the client delivers).
+    pub net_base: String,
+    /// Net quote amount delivered to the client (negative when the client delivers).
+    pub net_quote: String,
+    /// Start of the settlement window (ISO 8601, inclusive).
+    pub window_start: String,
+    /// End of the settlement window (ISO 8601, exclusive).
+    pub window_end: String,
+    /// Batch status.
+    pub status: NettingBatchStatus,
+    /// Net settlement transaction, once submitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settlement_tx_ref: Option<String>,
+    /// Why the batch failed, if it did.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
+    /// Created timestamp (ISO 8601).
+    pub created_at: String,
+    /// Last update timestamp (ISO 8601).
+    pub updated_at: String,
+}
+
+impl From<&NettingBatch> for SettlementBatchResponse {
+    fn from(batch: &NettingBatch) -> Self {
+        Self {
+            id: batch.id().to_string(),
+            client_id: batch.client_id().to_string(),
+            venue_id: batch.venue_id().to_string(),
+            symbol: batch.symbol().to_string(),
+            blockchain: batch.blockchain().to_string(),
+            trade_ids: batch.trade_ids().iter().map(ToString::to_string).collect(),
+            net_base: batch.net_base().to_string(),
+            net_quote: batch.net_quote().to_string(),
+            window_start: batch.window_start().to_string(),
+            window_end: batch.window_end().to_string(),
+            status: batch.status(),
+            settlement_tx_ref: batch.settlement_tx_ref().map(str::to_string),
+            failure_reason: batch.failure_reason().map(str::to_string),
+            created_at: batch.created_at().to_string(),
+            updated_at: batch.updated_at().to_string(),
+        }
+    }
+}
+
+// ============================================================================
+// Settlement Address DTOs
+// ============================================================================
+
+/// Request to register a settlement address.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct SettlementAddressRequest {
+    /// Chain name (e.g. "POLYGON", "ARBITRUM").
+    pub chain: String,
+    /// Token symbol the address is for; omit for the chain default.
+    pub token: Option<String>,
+    /// EVM address (0x-prefixed hex).
+    pub address: String,
+    /// Optional label.
+    pub label: Option<String>,
+}
+
+/// Token selecting a chain's settlement address.
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SettlementAddressParams {
+    /// Token symbol; omit for the chain default address.
+    pub token: Option<String>,
+}
+
+/// Settlement address response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SettlementAddressResponse {
+    /// Chain name.
+    pub chain: String,
+    /// EVM chain ID.
+    pub chain_id: u64,
+    /// Token symbol, or null for the chain default.
+    pub token: Option<String>,
+    /// Receiving address.
+    pub address: String,
+    /// Label, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Whether the counterparty proved control of the address.
+    pub verified: bool,
+}
+
+impl From<&SettlementAddress> for SettlementAddressResponse {
+    fn from(address: &SettlementAddress) -> Self {
+        Self {
+            chain: address.chain().to_string(),
+            chain_id: address.chain().chain_id(),
+            token: address.token().map(str::to_string),
+            address: address.address().address().to_string(),
+            label: address.address().label().map(str::to_string),
+            verified: address.is_verified(),
+        }
+    }
+}
+
+/// Request to register or replace a token.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct TokenRequest {
+    /// Token name (e.g. "USD Coin").
+    pub name: String,
+    /// Token decimals.
+    pub decimals: u8,
+    /// Contract address per chain name (e.g. "POLYGON").
+    pub addresses: HashMap<String, String>,
+    /// Whether trades may settle in the token; defaults to true.
+    pub enabled_for_settlement: Option<bool>,
+}
+
+/// Request to enable or disable settlement in a token.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct TokenSettlementRequest {
+    /// Whether trades may settle in the token.
+    pub enabled: bool,
+}
+
+/// Chain filter for the token list.
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TokenListParams {
+    /// Chain name; omit for tokens on every chain.
+    pub chain: Option<String>,
+}
+
+/// Token deployment response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TokenEntryResponse {
+    /// Token symbol.
+    pub symbol: String,
+    /// Chain name.
+    pub chain: String,
+    /// EVM chain ID.
+    pub chain_id: u64,
+    /// Contract address on the chain.
+    pub address: String,
+    /// Token decimals.
+    pub decimals: u8,
+    /// Whether trades may settle in the token.
+    pub enabled_for_settlement: bool,
+}
+
+impl From<&TokenEntry> for TokenEntryResponse {
+    fn from(entry: &TokenEntry) -> Self {
+        Self {
+            symbol: entry.symbol.clone(),
+            chain: Blockchain::from(entry.chain).to_string(),
+            chain_id: entry.chain.as_u64(),
+            address: entry.address.clone(),
+            decimals: entry.decimals,
+            enabled_for_settlement: entry.enabled_for_settlement,
+        }
+    }
+}
+
+// ============================================================================
+// Counterparty Erasure DTOs
+// ============================================================================
+
+/// Request to erase a counterparty's personal identifiers.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CreateErasureRequest {
+    /// Legal basis of the erasure.
+    pub reason: String,
+}
+
+/// Counterparty erasure request response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ErasureRequestResponse {
+    /// Request ID.
+    pub id: String,
+    /// Counterparty to erase, or its pseudonym once executed.
+    pub counterparty_id: String,
+    /// Who requested the erasure.
+    pub requested_by: String,
+    /// Legal basis of the erasure.
+    pub reason: String,
+    /// Review state.
+    pub status: ErasureRequestStatus,
+    /// Who approved the erasure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approved_by: Option<String>,
+    /// Approval timestamp (ISO 8601).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approved_at: Option<String>,
+    /// Execution timestamp (ISO 8601).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub executed_at: Option<String>,
+    /// Created timestamp (ISO 8601).
+    pub created_at: String,
+    /// Last update timestamp (ISO 8601).
+    pub updated_at: String,
+}
+
+impl From<&ErasureRequest> for ErasureRequestResponse {
+    fn from(request: &ErasureRequest) -> Self {
+        Self {
+            id: request.id().to_string(),
+            counterparty_id: request.counterparty_id().to_string(),
+            requested_by: request.requested_by().to_string(),
+            reason: request.reason().to_string(),
+            status: request.status(),
+            approved_by: request.approved_by().map(str::to_string),
+            approved_at: request.approved_at().map(|t| t.to_string()),
+            executed_at: request.executed_at().map(|t| t.to_string()),
+            created_at: request.created_at().to_string(),
+            updated_at: request.updated_at().to_string(),
+        }
+    }
+}
+
+// ============================================================================
+// Client Venue Credential DTOs
+// ============================================================================
+
+/// Request to store a counterparty's own credentials for a venue.
+#[derive(Clone, Deserialize, ToSchema)]
+pub struct VenueCredentialsRequest {
+    /// API key issued to the counterparty by the venue.
+    pub api_key: String,
+    /// API secret, for venues that sign requests.
+    pub api_secret: Option<String>,
+}
+
+impl fmt::Debug for VenueCredentialsRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VenueCredentialsRequest")
+            .field("api_key", &"<redacted>")
+            .field(
+                "api_secret",
+                &self.api_secret.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+/// Client venue credentials response DTO. Never includes the secrets.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VenueCredentialSetResponse {
+    /// Counterparty the credentials belong to.
+    pub counterparty_id: String,
+    /// Venue the credentials are for.
+    pub venue_id: String,
+    /// Whether an API secret is stored.
+    pub has_api_secret: bool,
+    /// Created timestamp (ISO 8601).
+    pub created_at: String,
+    /// Last rotation timestamp (ISO 8601).
+    pub updated_at: String,
+}
+
+impl From<&VenueCredentialSet> for VenueCredentialSetResponse {
+    fn from(set: &VenueCredentialSet) -> Self {
+        Self {
+            counterparty_id: set.counterparty_id().to_string(),
+            venue_id: set.venue_id().to_string(),
+            has_api_secret: set.credentials().api_secret().is_some(),
+            created_at: set.created_at().to_string(),
+            updated_at: set.updated_at().to_string(),
+        }
+    }
+}
+
+// ============================================================================
+// Price Bounds DTOs
+// ============================================================================
+
+/// Price bounds tolerances request and response DTO.
+///
+/// Tolerances are fractions of the reference price (e.g. "0.05" = ±5%).
+/// Decimal values are strings to avoid floating point rounding.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PriceBoundsSettingsDto {
+    /// Tolerance for liquid instruments.
+    pub liquid_tolerance_pct: String,
+    /// Tolerance for semi-liquid instruments.
+    pub semi_liquid_tolerance_pct: String,
+    /// Tolerance for illiquid instruments.
+    pub illiquid_tolerance_pct: String,
+    /// Whether an administrator may execute despite a failed check.
+    #[serde(default)]
+    pub allow_admin_override: bool,
+    /// Tolerance by instrument symbol (e.g. "BTC/USD"), replacing the tier
+    /// tolerance.
+    #[serde(default)]
+    pub overrides: BTreeMap<String, String>,
+}
+
+impl From<&PriceBoundsSettings> for PriceBoundsSettingsDto {
+    fn from(settings: &PriceBoundsSettings) -> Self {
+        let defaults = settings.defaults();
+        Self {
+            liquid_tolerance_pct: defaults.liquid_tolerance_pct().normalize().to_string(),
+            semi_liquid_tolerance_pct: defaults.semi_liquid_tolerance_pct().normalize().to_string(),
+            illiquid_tolerance_pct: defaults.illiquid_tolerance_pct().normalize().to_string(),
+            allow_admin_override: defaults.allows_admin_override(),
+            overrides: settings
+                .overrides()
+                .iter()
+                .map(|(symbol, tolerance)| (symbol.clone(), tolerance.normalize().to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl PriceBoundsSettingsDto {
+    /// Validates the DTO into price bounds settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if a tolerance is malformed or negative,
+    /// or an override symbol is invalid.
+    pub fn into_settings(self) -> Result<PriceBoundsSettings, ApiError> {
+        let defaults = PriceBoundsConfig::new(
+            parse_decimal("liquid_tolerance_pct", &self.liquid_tolerance_pct)?,
+            parse_decimal("semi_liquid_tolerance_pct", &self.semi_liquid_tolerance_pct)?,
+            parse_decimal("illiquid_tolerance_pct", &self.illiquid_tolerance_pct)?,
+        )
+        .ok_or_else(|| validation_error("tolerances must not be negative"))?
+        .with_admin_override(self.allow_admin_override);
+
+        let mut settings = PriceBoundsSettings::new(defaults);
+        for (symbol, tolerance) in self.overrides {
+            let symbol = Symbol::new(&symbol)
+                .map_err(|e| validation_error(&format!("invalid override symbol {symbol}: {e}")))?;
+            let tolerance = parse_decimal("override tolerance", &tolerance)?;
+            settings = settings
+                .with_override(symbol.as_str(), tolerance)
+                .ok_or_else(|| {
+                    validation_error(&format!("tolerance for {symbol} must not be negative"))
+                })?;
+        }
+        Ok(settings)
+    }
+}
+
+// ============================================================================
+// RFQ TTL DTOs
+// ============================================================================
+
+/// Allowed range and default of an RFQ's time-to-live, in seconds.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+pub struct TtlLimitsDto {
+    /// Shortest TTL allowed.
+    pub min_secs: u64,
+    /// TTL applied when a request omits `expiry_seconds`.
+    pub default_secs: u64,
+    /// Longest TTL allowed.
+    pub max_secs: u64,
+}
+
+impl From<TtlLimits> for TtlLimitsDto {
+    fn from(limits: TtlLimits) -> Self {
+        Self {
+            min_secs: limits.min_secs,
+            default_secs: limits.default_secs,
+            max_secs: limits.max_secs,
+        }
+    }
+}
+
+impl From<TtlLimitsDto> for TtlLimits {
+    fn from(dto: TtlLimitsDto) -> Self {
+        Self::new(dto.min_secs, dto.default_secs, dto.max_secs)
+    }
+}
+
+/// RFQ TTL limits request and response DTO.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RfqTtlPolicyDto {
+    /// Limits of asset classes not listed in `by_asset_class`.
+    pub fallback: TtlLimitsDto,
+    /// Limits by asset class (e.g. "CRYPTO_SPOT").
+    #[serde(default)]
+    pub by_asset_class: BTreeMap<String, TtlLimitsDto>,
+}
+
+impl From<&RfqTtlPolicy> for RfqTtlPolicyDto {
+    fn from(policy: &RfqTtlPolicy) -> Self {
+        Self {
+            fallback: policy.fallback.into(),
+            by_asset_class: policy
+                .by_asset_class
+                .iter()
+                .map(|(asset_class, limits)| (asset_class.to_string(), (*limits).into()))
+                .collect(),
+        }
+    }
+}
+
+impl RfqTtlPolicyDto {
+    /// Converts the DTO into an RFQ TTL policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if an asset class is unknown.
+    pub fn into_policy(self) -> Result<RfqTtlPolicy, ApiError> {
+        let mut policy = RfqTtlPolicy::new(self.fallback.into());
+        for (asset_class, limits) in self.by_asset_class {
+            let asset_class = asset_class
+                .parse::<AssetClass>()
+                .map_err(|_| validation_error(&format!("unknown asset class: {asset_class}")))?;
+            policy = policy.with_asset_class(asset_class, limits.into());
+        }
+        Ok(policy)
+    }
+}
+
+/// Verification challenge response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AddressChallengeResponse {
+    /// Chain name.
+    pub chain: String,
+    /// Token symbol, or null for the chain default.
+    pub token: Option<String>,
+    /// Address that must sign.
+    pub address: String,
+    /// Single-use nonce.
+    pub nonce: String,
+    /// Exact message to sign with `personal_sign`.
+    pub message: String,
+    /// When the challenge expires (ISO 8601).
+    pub expires_at: String,
+}
+
+impl From<&AddressChallenge> for AddressChallengeResponse {
+    fn from(challenge: &AddressChallenge) -> Self {
+        Self {
+            chain: challenge.chain.to_string(),
+            token: challenge.token.clone(),
+            address: challenge.address.clone(),
+            nonce: challenge.nonce.clone(),
+            message: challenge.message.clone(),
+            expires_at: challenge.expires_at.to_string(),
+        }
+    }
+}
+
+/// Request to verify a settlement address.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct VerifyAddressRequest {
+    /// Hex `personal_sign` signature of the challenge message.
+    pub signature: String,
+}
+
+// ============================================================================
+// RFQ Template DTOs
+// ============================================================================
+
+/// Request to create or replace an RFQ template.
+///
+/// Names either a single instrument (`base_asset`/`quote_asset`) or a
+/// multi-leg `strategy`, never both.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct RfqTemplateRequest {
+    /// Template name.
+    pub name: String,
+    /// Base asset symbol (e.g., "BTC"). Omit when quoting a strategy.
+    #[serde(default)]
+    pub base_asset: String,
+    /// Quote asset symbol (e.g., "USD"). Omit when quoting a strategy.
+    #[serde(default)]
+    pub quote_asset: String,
+    /// Multi-leg strategy to quote as a package.
+    #[serde(default)]
+    pub strategy: Option<StrategyRequest>,
+    /// Buy or sell side.
+    pub side: OrderSide,
+    /// Default quantity, as a decimal string.
+    #[serde(with = "decimal")]
+    #[schema(value_type = String, example = "1.5")]
+    pub quantity: Decimal,
+    /// How the quantity may be filled. Defaults to `ALL_OR_NOTHING`.
+    #[serde(default)]
+    pub size_mode: Option<SizeModeRequest>,
+    /// Only these venues may quote. Takes precedence over the blocklist.
+    #[serde(default)]
+    pub venue_allowlist: Option<Vec<String>>,
+    /// These venues must not see RFQs from the template.
+    #[serde(default)]
+    pub venue_blocklist: Vec<String>,
+    /// Expiry of instantiated RFQs, in seconds from creation.
+    pub default_ttl_seconds: u32,
+}
+
+impl RfqTemplateRequest {
+    /// Converts the request into a validated template owned by `owner`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VALIDATION_ERROR` if the request is malformed, or the
+    /// error an RFQ with these parameters would be rejected with.
+    pub fn to_template(&self, owner: CounterpartyId) -> Result<RfqTemplate, ApiError> {
+        validate_subject(&self.base_asset, &self.quote_asset, self.strategy.as_ref())?;
+        let subject = build_subject(&self.base_asset, &self.quote_asset, self.strategy.as_ref())?;
+        let quantity = Quantity::from_decimal(self.quantity)
+            .map_err(|e| validation_error(&format!("invalid quantity: {e}")))?;
+        let size_mode = match &self.size_mode {
+            Some(mode) => mode.to_size_mode(quantity)?,
+            None => SizeNegotiationMode::default(),
+        };
+
+        let mut builder = RfqTemplateBuilder::new(
+            owner,
+            self.name.clone(),
+            subject,
+            self.side,
+            quantity,
+            self.default_ttl_seconds,
+        )
+        .size_mode(size_mode)
+        .venue_blocklist(self.venue_blocklist.iter().map(VenueId::new).collect());
+        if let Some(allowlist) = &self.venue_allowlist {
+            builder = builder.venue_allowlist(allowlist.iter().map(VenueId::new).collect());
+        }
+        builder.try_build().map_err(|e| from_domain_error(&e))
+    }
+}
+
+/// Overrides applied when creating an RFQ from a template.
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+pub struct CreateRfqFromTemplateRequest {
+    /// Quantity to request instead of the template's, as a decimal string.
+    #[serde(default, with = "decimal::option")]
+    #[schema(value_type = Option<String>, example = "2.5")]
+    pub quantity: Option<Decimal>,
+    /// Expiry in seconds from now instead of the template's default TTL.
+    #[serde(default)]
+    pub expiry_seconds: Option<u64>,
+}
+
+/// RFQ template response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RfqTemplateResponse {
+    /// Template ID.
+    pub id: String,
+    /// Counterparty that owns the template.
+    pub owner: String,
+    /// Template name.
+    pub name: String,
+    /// Instrument symbol; the primary leg's for a strategy.
+    pub symbol: String,
+    /// Multi-leg strategy, if the template quotes a package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<StrategyResponse>,
+    /// Order side.
+    pub side: OrderSide,
+    /// Default quantity.
+    pub quantity: String,
+    /// How the quantity may be filled.
+    pub size_mode: SizeModeResponse,
+    /// Venues RFQs from the template may be sent to, if restricted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub venue_allowlist: Option<Vec<String>>,
+    /// Venues RFQs from the template must not be sent to.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub venue_blocklist: Vec<String>,
+    /// Expiry of instantiated RFQs, in seconds from creation.
+    pub default_ttl_seconds: u32,
+    /// Created timestamp (ISO 8601).
+    pub created_at: String,
+    /// Updated timestamp (ISO 8601).
+    pub updated_at: String,
+}
+
+impl From<&RfqTemplate> for RfqTemplateResponse {
+    fn from(template: &RfqTemplate) -> Self {
+        Self {
+            id: template.id().to_string(),
+            owner: template.owner().to_string(),
+            name: template.name().to_string(),
+            symbol: template.subject().primary_instrument().symbol().to_string(),
+            strategy: template.subject().strategy().map(StrategyResponse::from),
+            side: template.side(),
+            quantity: template.quantity().to_string(),
+            size_mode: SizeModeResponse::from(template.size_mode()),
+            venue_allowlist: template
+                .venue_allowlist()
+                .map(|venues| venues.iter().map(ToString::to_string).collect()),
+            venue_blocklist: template
+                .venue_blocklist()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            default_ttl_seconds: template.default_ttl_secs(),
+            created_at: template.created_at().to_string(),
+            updated_at: template.updated_at().to_string(),
+        }
+    }
+}
+
+// ============================================================================
+// Trade DTOs
+// ============================================================================
+
+/// Trade filter parameters.
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TradeFilter {
+    /// Filter by RFQ ID.
+    pub rfq_id: Option<String>,
+    /// Filter by venue ID.
+    pub venue_id: Option<String>,
+}
+
+impl TradeFilter {
+    /// Returns true if the trade satisfies every set field.
+    #[must_use]
+    pub fn matches(&self, trade: &Trade) -> bool {
+        self.rfq_id
+            .as_ref()
+            .is_none_or(|id| trade.rfq_id().to_string() == *id)
+            && self
+                .venue_id
+                .as_ref()
+                .is_none_or(|id| trade.venue_id().as_str() == id)
+    }
+}
+
+/// Trade response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TradeResponse {
+    /// Trade ID.
+    pub id: String,
+    /// RFQ ID.
+    pub rfq_id: String,
+    /// Quote ID.
+    pub quote_id: String,
+    /// Venue ID.
+    pub venue_id: String,
+    /// Execution price.
+    pub price: String,
+    /// Executed quantity.
+    pub quantity: String,
+    /// Settlement state.
+    pub settlement_state: SettlementState,
+    /// Number of settlement retries made after a failure.
+    pub settlement_attempts: u32,
+    /// Last settlement error, if settlement failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_settlement_error: Option<String>,
+    /// When the next settlement retry is due (ISO 8601).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_settlement_retry_at: Option<String>,
+    /// Settlement window the trade settles in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settlement_window: Option<SettlementWindow>,
+    /// When the trade is released for settlement (ISO 8601).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settlement_due_at: Option<String>,
+    /// Itemized execution and settlement costs.
+    pub fees: Vec<FeeComponentResponse>,
+    /// Reference price observed at execution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_price_at_execution: Option<String>,
+    /// How a multi-MM fill was split across venues; empty for single-venue trades.
+    pub allocations: Vec<TradeAllocationResponse>,
+    /// Volume-weighted average price across allocations, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocation_vwap: Option<String>,
+    /// Trade whose filled leg this trade reverses, for unwind trades.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unwinds_trade_id: Option<String>,
+    /// Netting batch the trade is reserved for or was settled by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub netting_batch_id: Option<String>,
+    /// Created timestamp (ISO 8601).
+    pub created_at: String,
+}
+
+/// Trade allocation DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TradeAllocationResponse {
+    /// Venue the quantity was allocated to.
+    pub venue_id: String,
+    /// Quote the allocation was filled against.
+    pub quote_id: String,
+    /// Allocated quantity.
+    pub quantity: String,
+    /// Execution price of the allocation.
+    pub price: String,
+    /// When the allocation was scheduled (ISO 8601).
+    pub scheduled_at: String,
+    /// Execution status: `PENDING`, `EXECUTING`, `FILLED` or `FAILED`.
+    pub status: String,
+    /// Why the venue leg failed, if it did.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
+}
+
+impl From<&TradeAllocation> for TradeAllocationResponse {
+    fn from(recorded: &TradeAllocation) -> Self {
+        let allocation = recorded.allocation();
+        Self {
+            venue_id: allocation.venue_id().to_string(),
+            quote_id: allocation.quote_id().to_string(),
+            quantity: allocation.allocated_quantity().to_string(),
+            price: allocation.price().to_string(),
+            scheduled_at: recorded.scheduled_at().to_string(),
+            status: recorded.status().to_string(),
+            failure_reason: recorded.status().failure_reason().map(str::to_string),
+        }
+    }
+}
+
+/// Trade fee component DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct FeeComponentResponse {
+    /// Fee category.
+    pub kind: FeeKind,
+    /// Fee amount (negative for rebates).
+    pub amount: String,
+    /// Currency the fee is denominated in.
+    pub currency: String,
+}
+
+impl From<&FeeComponent> for FeeComponentResponse {
+    fn from(fee: &FeeComponent) -> Self {
+        Self {
+            kind: fee.kind(),
+            amount: fee.amount().to_string(),
+            currency: fee.currency().to_string(),
+        }
+    }
+}
+
+impl From<&Trade> for TradeResponse {
+    fn from(trade: &Trade) -> Self {
+        Self {
+            id: trade.id().to_string(),
+            rfq_id: trade.rfq_id().to_string(),
+            quote_id: trade.quote_id().to_string(),
+            venue_id: trade.venue_id().to_string(),
+            price: trade.price().to_string(),
+            quantity: trade.quantity().to_string(),
+            settlement_state: trade.settlement_state(),
+            settlement_attempts: trade.settlement_attempts(),
+            last_settlement_error: trade.failure_reason().map(str::to_string),
+            next_settlement_retry_at: trade.next_settlement_retry_at().map(|t| t.to_string()),
+            settlement_window: trade.settlement_window().copied(),
+            settlement_due_at: trade.settlement_due_at().map(|t| t.to_string()),
+            fees: trade
+                .fees()
+                .iter()
+                .map(FeeComponentResponse::from)
+                .collect(),
+            reference_price_at_execution: trade
+                .reference_price_at_execution()
+                .map(|p| p.to_string()),
+            allocations: trade
+                .allocations()
+                .iter()
+                .map(TradeAllocationResponse::from)
+                .collect(),
+            allocation_vwap: trade.allocation_vwap().map(|p| p.to_string()),
+            unwinds_trade_id: trade.unwinds().map(|id| id.to_string()),
+            netting_batch_id: trade.netting_batch_id().map(|id| id.to_string()),
+            created_at: trade.created_at().to_string(),
+        }
+    }
+}
+
+/// RFQ dashboard summary response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RfqSummaryResponse {
+    /// RFQ ID.
+    pub rfq_id: String,
+    /// Client ID.
+    pub client_id: String,
+    /// Instrument symbol.
+    pub symbol: String,
+    /// Order side.
+    pub side: OrderSide,
+    /// Quantity.
+    pub quantity: String,
+    /// State as of the last projected event.
+    pub state: RfqState,
+    /// Number of quotes received.
+    pub quote_count: u32,
+    /// Best quote price for the client's side, if any quote was received.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub best_quote_price: Option<String>,
+    /// Venue of the selected quote.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_venue_id: Option<String>,
+    /// Trade booked on execution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade_id: Option<String>,
+    /// Executed price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_price: Option<String>,
+    /// Why execution failed, if it did.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
+    /// Created timestamp (ISO 8601).
+    pub created_at: String,
+    /// Timestamp of the last projected event (ISO 8601).
+    pub last_event_at: String,
+}
+
+impl From<&RfqSummary> for RfqSummaryResponse {
+    fn from(summary: &RfqSummary) -> Self {
+        Self {
+            rfq_id: summary.rfq_id.to_string(),
+            client_id: summary.client_id.to_string(),
+            symbol: summary.symbol.to_string(),
+            side: summary.side,
+            quantity: summary.quantity.to_string(),
+            state: summary.state,
+            quote_count: summary.quote_count,
+            best_quote_price: summary.best_quote_price.map(|p| p.to_string()),
+            selected_venue_id: summary.selected_venue_id.as_ref().map(ToString::to_string),
+            trade_id: summary.trade_id.map(|id| id.to_string()),
+            execution_price: summary.execution_price.map(|p| p.to_string()),
+            failure_reason: summary.failure_reason.clone(),
+            created_at: summary.created_at.to_string(),
+            last_event_at: summary.last_event_at.to_string(),
+        }
+    }
+}
+
+// ============================================================================
+// RFQ Handlers
+// ============================================================================
+
+/// List RFQs with filtering and pagination.
+///
+/// # Errors
+///
+/// Returns an error response if the repository query fails.
+#[utoipa::path(
+    get,
+    path = "/api/v1/rfqs",
+    tag = "rfqs",
+    params(PaginationParams, CursorParams, RfqFilter),
+    responses(
+        (status = 200, description = "Page of RFQs", body = PaginatedResponse<RfqResponse>),
+        (status = 400, description = "Invalid cursor or offset too deep", body = ErrorResponse),
+        (status = 500, description = "Repository failure", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn list_rfqs(
+    State(state): State<Arc<AppState>>,
+    Query(pagination): Query<PaginationParams>,
+    Query(cursor): Query<CursorParams>,
+    Query(filter): Query<RfqFilter>,
+) -> Result<Json<PaginatedResponse<RfqResponse>>, ApiError> {
+    info!("Listing RFQs with filter: {:?}", filter);
+
+    let filter = filter.parse()?;
+
+    if cursor.is_requested() {
+        let after = cursor.decode()?;
+        let limit = cursor.page_limit();
+        let mut rfqs = state
+            .rfq_repository
+            .list_after(after.as_ref(), limit.saturating_add(1), &filter)
+            .await
+            .map_err(|e| {
+                error!("Failed to list RFQs: {}", e);
+                internal_error(&e)
+            })?;
+
+        let next_cursor = if rfqs.len() > limit {
+            rfqs.truncate(limit);
+            rfqs.last().map(PageCursor::from_rfq)
+        } else {
+            None
+        };
+
+        return Ok(Json(PaginatedResponse::with_cursor(
+            rfqs.iter().map(RfqResponse::from).collect(),
+            next_cursor,
+        )));
+    }
+
+    pagination.validate()?;
+
+    let page = state
+        .rfq_repository
+        .list_offset(
+            pagination.offset() as usize,
+            pagination.limit() as usize,
+            &filter,
+        )
+        .await
+        .map_err(|e| {
+            error!("Failed to list RFQs: {}", e);
+            internal_error(&e)
+        })?;
+    let total_items = state
+        .rfq_repository
+        .count_matching(&filter)
+        .await
+        .map_err(|e| {
+            error!("Failed to count RFQs: {}", e);
+            internal_error(&e)
+        })?;
+
+    let page_data: Vec<RfqResponse> = page.iter().map(RfqResponse::from).collect();
+
+    Ok(Json(PaginatedResponse::with_offset(
+        page_data,
+        PaginationMeta::new(pagination.page, pagination.limit(), total_items),
+    )))
+}
+
+/// List RFQ dashboard summaries with filtering and pagination.
+///
+/// Served from the denormalized RFQ summary read model, one row per RFQ
+/// with its best quote, selected venue and execution price. Filters and
+/// pagination match `GET /api/v1/rfqs`, except `status`: activation times
+/// are not part of the read model.
+///
+/// # Errors
+///
+/// Returns `VALIDATION_ERROR` for an invalid filter or a `status` filter.
+/// Returns `NOT_IMPLEMENTED` if the read model is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/rfqs/summary",
+    tag = "rfqs",
+    params(PaginationParams, CursorParams, RfqFilter),
+    responses(
+        (status = 200, description = "Page of RFQ summaries", body = PaginatedResponse<RfqSummaryResponse>),
+        (status = 400, description = "Invalid filter, cursor or offset too deep", body = ErrorResponse),
+        (status = 500, description = "Repository failure", body = ErrorResponse),
+        (status = 501, description = "RFQ summary read model not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn list_rfq_summaries(
+    State(state): State<Arc<AppState>>,
+    Query(pagination): Query<PaginationParams>,
+    Query(cursor): Query<CursorParams>,
+    Query(filter): Query<RfqFilter>,
+) -> Result<Json<PaginatedResponse<RfqSummaryResponse>>, ApiError> {
+    if let Some(status) = filter.status.as_deref() {
+        return Err(invalid_filter(
+            "status",
+            status,
+            "not supported for RFQ summaries",
+        ));
+    }
+    if let Some(code) = filter.failure_code.as_deref() {
+        return Err(invalid_filter(
+            "failure_code",
+            code,
+            "not supported for RFQ summaries",
+        ));
+    }
+    let filter = filter.parse()?;
+    let store = state
+        .rfq_summaries
+        .as_ref()
+        .ok_or_else(|| not_implemented("RFQ summary read model not configured"))?;
+
+    if cursor.is_requested() {
+        let after = cursor.decode()?;
+        let limit = cursor.page_limit();
+        let mut summaries = store
+            .list_after(after.as_ref(), limit.saturating_add(1), &filter)
+            .await
+            .map_err(|e| from_repository_error(&e))?;
+
+        let next_cursor = if summaries.len() > limit {
+            summaries.truncate(limit);
+            summaries.last().map(RfqSummary::cursor)
+        } else {
+            None
+        };
+
+        return Ok(Json(PaginatedResponse::with_cursor(
+            summaries.iter().map(RfqSummaryResponse::from).collect(),
+            next_cursor,
+        )));
+    }
+
+    pagination.validate()?;
+
+    let page = store
+        .list_offset(
+            pagination.offset() as usize,
+            pagination.limit() as usize,
+            &filter,
+        )
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+    let total_items = store
+        .count_matching(&filter)
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    let page_data: Vec<RfqSummaryResponse> = page.iter().map(RfqSummaryResponse::from).collect();
+
+    Ok(Json(PaginatedResponse::with_offset(
+        page_data,
+        PaginationMeta::new(pagination.page, pagination.limit(), total_items),
+    )))
+}
+
+/// Get RFQ by ID.
+///
+/// # Errors
+///
+/// Returns `RFQ_NOT_FOUND` if the RFQ does not exist.
+/// Returns `VALIDATION_ERROR` if the ID is not a valid UUID.
+#[utoipa::path(
+    get,
+    path = "/api/v1/rfqs/{id}",
+    tag = "rfqs",
+    params(("id" = String, Path, description = "RFQ ID (UUID)")),
+    responses(
+        (status = 200, description = "RFQ found", body = RfqResponse),
+        (status = 400, description = "Invalid RFQ ID", body = ErrorResponse),
+        (status = 404, description = "RFQ not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, id), fields(rfq_id = %id))]
+pub async fn get_rfq(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<RfqResponse>, ApiError> {
+    info!("Getting RFQ: {}", id);
+
+    let rfq_id = parse_rfq_id(&id)?;
+
+    let rfq = state
+        .rfq_repository
+        .find_by_id(rfq_id)
+        .await
+        .map_err(|e| {
+            error!("Failed to find RFQ: {}", e);
+            internal_error(&e)
+        })?
+        .ok_or_else(|| rfq_not_found(&id))?;
+
+    Ok(Json(RfqResponse::from(&rfq)))
+}
+
+/// Get the quote history of an RFQ.
+///
+/// Lists every quote the RFQ has seen, including superseded quotes and
+/// quotes that arrived expired, with their ranks at the end of quote
+/// collection and the best-price timeline. Clients may only read their own
+/// RFQs; admins may read any.
+///
+/// # Errors
+///
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `VALIDATION_ERROR` if the ID is not a valid UUID.
+/// Returns `UNAUTHORIZED_COUNTERPARTY` if the RFQ belongs to another client.
+/// Returns `RFQ_NOT_FOUND` if the RFQ does not exist.
+#[utoipa::path(
+    get,
+    path = "/api/v1/rfqs/{id}/quotes",
+    tag = "rfqs",
+    params(("id" = String, Path, description = "RFQ ID (UUID)")),
+    responses(
+        (status = 200, description = "Quote history", body = QuoteHistoryResponse),
+        (status = 400, description = "Invalid RFQ ID", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "RFQ belongs to another client", body = ErrorResponse),
+        (status = 404, description = "RFQ not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, id), fields(rfq_id = %id))]
+pub async fn get_rfq_quote_history(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<Json<QuoteHistoryResponse>, ApiError> {
+    let rfq_id = parse_rfq_id(&id)?;
+
+    let rfq = state
+        .rfq_repository
+        .find_by_id(rfq_id)
+        .await
+        .map_err(|e| {
+            error!("Failed to find RFQ: {}", e);
+            internal_error(&e)
+        })?
+        .ok_or_else(|| rfq_not_found(&id))?;
+
+    if !user.has_role("admin") && *rfq.client_id() != requesting_counterparty(&user) {
+        warn!("Denied quote history of RFQ {} to {}", id, user.sub);
+        return Err(api_error(
+            ErrorCode::UnauthorizedCounterparty,
+            "RFQ belongs to another counterparty",
+        ));
+    }
+
+    Ok(Json(QuoteHistoryResponse::from(&rfq)))
+}
+
+/// Create a new RFQ.
+///
+/// # Errors
+///
+/// Returns `VALIDATION_ERROR` if the request is invalid.
+/// Returns `INVALID_MIN_QUANTITY` if the size mode's minimum is not positive
+/// or exceeds the quantity.
+/// Returns `NO_ELIGIBLE_VENUES` if the venue allowlist/blocklist leaves no
+/// enabled venue.
+/// Returns `DUPLICATE` if the client refuses near-duplicate RFQs and has
+/// one open; otherwise near-duplicates are listed in `warnings`.
+/// Returns `SHUTTING_DOWN` if the service is draining for shutdown.
+/// Returns `INTERNAL_ERROR` if the repository save fails.
+#[utoipa::path(
+    post,
+    path = "/api/v1/rfqs",
+    tag = "rfqs",
+    request_body = CreateRfqRequest,
+    responses(
+        (status = 201, description = "RFQ created", body = RfqResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 409, description = "Near-duplicate of an open RFQ", body = ErrorResponse),
+        (status = 422, description = "Business rule violation", body = ErrorResponse),
+        (status = 500, description = "Repository failure", body = ErrorResponse),
+        (status = 503, description = "Service is shutting down", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, request), fields(rfq_id = tracing::field::Empty))]
+pub async fn create_rfq(
+    State(state): State<Arc<AppState>>,
+    ApiJson(request): ApiJson<CreateRfqRequest>,
+) -> Result<(StatusCode, Json<RfqResponse>), ApiError> {
+    info!("Creating RFQ for client: {}", request.client_id);
+
+    ensure_accepting_rfqs(&state)?;
+
+    // Validate request
+    validate_create_rfq_request(&request)?;
+
+    // Build instrument or strategy
+    let subject = build_subject(
+        &request.base_asset,
+        &request.quote_asset,
+        request.strategy.as_ref(),
+    )?;
+
+    // Build quantity
+    let quantity = Quantity::from_decimal(request.quantity)
+        .map_err(|e| validation_error(&format!("invalid quantity: {e}")))?;
+
+    // Build size mode
+    let size_mode = match &request.size_mode {
+        Some(mode) => mode.to_size_mode(quantity)?,
+        None => SizeNegotiationMode::default(),
+    };
+    let quantity_disclosure = match &request.quantity_disclosure {
+        Some(disclosure) => disclosure.to_disclosure(quantity)?,
+        None => QuantityDisclosure::default(),
+    };
+
+    // Build optional activation time and expiry
+    let activate_at = parse_filter_timestamp("activate_at", request.activate_at.as_deref())?;
+    let expires_at = match request.expiry_seconds {
+        Some(seconds) => Timestamp::now().add_secs(seconds as i64),
+        None => {
+            let asset_class = subject.primary_instrument().asset_class();
+            let default_secs = rfq_ttl_policy(&state).limits_for(asset_class).default_secs;
+            activate_at
+                .unwrap_or_else(Timestamp::now)
+                .add_secs(default_secs as i64)
+        }
+    };
+
+    // Create RFQ
+    let mut builder = RfqBuilder::new(
+        CounterpartyId::new(&request.client_id),
+        subject,
+        request.side,
+        quantity,
+        expires_at,
+    )
+    .size_mode(size_mode)
+    .quantity_disclosure(quantity_disclosure)
+    .venue_blocklist(request.venue_blocklist.iter().map(VenueId::new).collect());
+    if let Some(allowlist) = &request.venue_allowlist {
+        builder = builder.venue_allowlist(allowlist.iter().map(VenueId::new).collect());
+    }
+    if let Some(activate_at) = activate_at {
+        builder = builder.activate_at(activate_at);
+    }
+    if let Some(policy) = request.compensation_policy {
+        builder = builder.compensation_policy(policy);
+    }
+    let response = save_new_rfq(&state, builder).await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Refuses new RFQs while the service is draining for shutdown.
+fn ensure_accepting_rfqs(state: &AppState) -> Result<(), ApiError> {
+    if let Some(shutdown) = &state.shutdown
+        && !shutdown.is_accepting()
+    {
+        return Err(api_error(
+            ErrorCode::ShuttingDown,
+            "service is shutting down; RFQ creation is disabled",
+        ));
+    }
+    Ok(())
+}
+
+/// Checks `quantity` against the lot size and order size limits of the
+/// subject's primary instrument, if reference data is configured for it.
+async fn validate_against_reference_data(
+    state: &AppState,
+    subject: &RfqSubject,
+    quantity: Quantity,
+) -> Result<(), ApiError> {
+    if let Some(repository) = &state.instrument_reference_data
+        && let Some(data) = repository
+            .get(subject.primary_instrument().symbol())
+            .await
+            .map_err(|e| from_repository_error(&e))?
+    {
+        data.validate_quantity(quantity)
+            .map_err(|e| from_domain_error(&e))?;
+    }
+    Ok(())
+}
+
+/// Returns the RFQ TTL policy in force, or the default policy if no TTL
+/// limits store is configured.
+fn rfq_ttl_policy(state: &AppState) -> Arc<RfqTtlPolicy> {
+    state.rfq_ttl.as_ref().map_or_else(
+        || Arc::new(RfqTtlPolicy::default()),
+        |store| store.current(),
+    )
+}
+
+/// Checks how long `rfq` stays open, from activation (or now) to expiry,
+/// against the TTL limits of its asset class, if TTL limits are configured.
+fn validate_rfq_ttl(state: &AppState, rfq: &Rfq) -> Result<(), ApiError> {
+    let Some(store) = &state.rfq_ttl else {
+        return Ok(());
+    };
+    let now = Timestamp::now();
+    let opens_at = rfq
+        .activate_at()
+        .filter(|at| at.is_after(&now))
+        .unwrap_or(now);
+    let ttl = opens_at.duration_until(&rfq.expires_at());
+    let ttl_secs = ttl.as_secs() + u64::from(ttl.subsec_nanos() > 0);
+
+    store
+        .current()
+        .check(rfq.subject().primary_instrument().asset_class(), ttl_secs)
+        .map_err(|err| {
+            api_error_with_details(
+                ErrorCode::RfqTtlOutOfRange,
+                err.to_string(),
+                Some(serde_json::json!({
+                    "asset_class": err.asset_class.to_string(),
+                    "requested_secs": err.requested_secs,
+                    "min_secs": err.limits.min_secs,
+                    "max_secs": err.limits.max_secs,
+                })),
+            )
+        })
+}
+
+/// Validates and saves a new RFQ, warning about near-duplicates of it.
+///
+/// Direct creation and template instantiation both go through here, so an
+/// RFQ is held to the same rules however it was requested.
+async fn save_new_rfq(state: &AppState, builder: RfqBuilder) -> Result<RfqResponse, ApiError> {
+    let rfq = builder.try_build().map_err(|e| from_domain_error(&e))?;
+    tracing::Span::current().record("rfq_id", tracing::field::display(rfq.id()));
+
+    // Validate lot size and order size limits
+    validate_against_reference_data(state, rfq.subject(), rfq.quantity()).await?;
+
+    // Validate the TTL against the limits of the asset class
+    validate_rfq_ttl(state, &rfq)?;
+
+    // Reject venue lists that leave no enabled venue to quote
+    if rfq.venue_allowlist().is_some() || !rfq.venue_blocklist().is_empty() {
+        let venues = state.venue_repository.find_all().await.map_err(|e| {
+            error!("Failed to load venues: {}", e);
+            internal_error(&e)
+        })?;
+        let enabled: Vec<Venue> = venues.into_iter().filter(Venue::is_enabled).collect();
+        VenueSelector
+            .select(&rfq, enabled, Venue::id)
+            .map_err(|e| from_domain_error(&e))?;
+    }
+
+    // Warn about, or refuse, near-duplicates of the client's open RFQs
+    let duplicates = find_duplicate_rfqs(state, &rfq).await?;
+
+    // Save to repository
+    state.rfq_repository.save(&rfq).await.map_err(|e| {
+        error!("Failed to save RFQ: {}", e);
+        internal_error(&e)
+    })?;
+
+    metrics::record_rfq_created();
+    info!("Created RFQ: {}", rfq.id());
+
+    let mut response = RfqResponse::from(&rfq);
+    response.warnings = duplicates
+        .iter()
+        .map(RfqWarningResponse::possible_duplicate)
+        .collect();
+    Ok(response)
+}
+
+/// Most open RFQs of a client examined for near-duplicates of a new one.
+const DUPLICATE_RFQ_SCAN_LIMIT: usize = 100;
+
+/// Finds the client's open RFQs that `rfq` nearly duplicates: same
+/// instrument, same side, and a quantity within the similarity band.
+///
+/// Two-way and multi-leg RFQs are not checked.
+///
+/// # Errors
+///
+/// Returns `DUPLICATE`, with the conflicting RFQ IDs, if the client opted
+/// into refusing near-duplicates and there are any.
+async fn find_duplicate_rfqs(state: &AppState, rfq: &Rfq) -> Result<Vec<Rfq>, ApiError> {
+    let Some(policy) = &state.duplicate_rfqs else {
+        return Ok(Vec::new());
+    };
+    if rfq.is_two_way() || rfq.is_multi_leg() {
+        return Ok(Vec::new());
+    }
+
+    let filter = RfqListFilter {
+        client_id: Some(rfq.client_id().clone()),
+        states: [
+            RfqState::Created,
+            RfqState::QuoteRequesting,
+            RfqState::QuotesReceived,
+            RfqState::Negotiating,
+            RfqState::ClientSelecting,
+            RfqState::Executing,
+        ]
+        .to_vec(),
+        symbol: Some(rfq.instrument().symbol().clone()),
+        side: Some(rfq.side()),
+        ..RfqListFilter::default()
+    };
+    let duplicates: Vec<Rfq> = state
+        .rfq_repository
+        .list_after(None, DUPLICATE_RFQ_SCAN_LIMIT, &filter)
+        .await
+        .map_err(|e| {
+            error!("Failed to look up open RFQs: {}", e);
+            internal_error(&e)
+        })?
+        .into_iter()
+        .filter(|open| !open.is_multi_leg() && policy.is_similar(rfq.quantity(), open.quantity()))
+        .collect();
+
+    if !duplicates.is_empty() && policy.blocks(rfq.client_id()) {
+        let ids: Vec<String> = duplicates
+            .iter()
+            .map(|open| open.id().to_string())
+            .collect();
+        warn!(client_id = %rfq.client_id(), "Refusing near-duplicate RFQ of {}", ids.join(", "));
+        return Err(api_error_with_details(
+            ErrorCode::Duplicate,
+            format!("RFQ nearly duplicates open RFQs: {}", ids.join(", ")),
+            Some(serde_json::json!({ "conflicting_rfq_ids": ids })),
+        ));
+    }
+    Ok(duplicates)
+}
+
+/// Cancel an RFQ.
+///
+/// Venues that were sent the RFQ are told to stop pricing it.
+///
+/// # Errors
+///
+/// Returns `RFQ_NOT_FOUND` if the RFQ does not exist.
+/// Returns `RFQ_INVALID_STATE` if the RFQ cannot be cancelled.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/rfqs/{id}",
+    tag = "rfqs",
+    params(("id" = String, Path, description = "RFQ ID (UUID)")),
+    responses(
+        (status = 200, description = "RFQ cancelled", body = RfqResponse),
+        (status = 400, description = "Invalid RFQ ID", body = ErrorResponse),
+        (status = 404, description = "RFQ not found", body = ErrorResponse),
+        (status = 409, description = "RFQ cannot be cancelled", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, id), fields(rfq_id = %id))]
+pub async fn cancel_rfq(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<RfqResponse>, ApiError> {
+    info!("Cancelling RFQ: {}", id);
+
+    let rfq_id = parse_rfq_id(&id)?;
+
+    let mut rfq = state
+        .rfq_repository
+        .find_by_id(rfq_id)
+        .await
+        .map_err(|e| {
+            error!("Failed to find RFQ: {}", e);
+            internal_error(&e)
+        })?
+        .ok_or_else(|| rfq_not_found(&id))?;
+
+    rfq.cancel().map_err(|e| {
+        warn!("Cannot cancel RFQ: {}", e);
+        from_domain_error(&e)
+    })?;
+
+    state.rfq_repository.save(&rfq).await.map_err(|e| {
+        error!("Failed to save cancelled RFQ: {}", e);
+        internal_error(&e)
+    })?;
+    metrics::record_rfq_terminal(rfq.state());
+
+    if let Some(cancellations) = &state.rfq_cancellations {
+        let notices = cancellations.notify_cancelled(&rfq).await;
+        info!(
+            "Sent cancel notices for RFQ {} to {} venues",
+            id,
+            notices.len()
+        );
+    }
+
+    info!("Cancelled RFQ: {}", id);
+
+    Ok(Json(RfqResponse::from(&rfq)))
+}
+
+/// Select a quote for execution.
+///
+/// Indicative quotes are firmed up with their venue first; the request
+/// blocks until the firm quote arrives or the firm-up timeout elapses. The
+/// response's `selected_quote_id` names the firm quote, which replaces the
+/// indicative one.
+///
+/// Trades whose notional falls in a quorum band need enough competing
+/// quotes before one can be selected. Setting `quorum_override_reason`
+/// selects anyway and records the override in the audit trail.
+///
+/// # Errors
+///
+/// Returns `RFQ_NOT_FOUND` or `QUOTE_NOT_FOUND` if the RFQ or quote does not exist.
+/// Returns `QUORUM_NOT_MET` if too few quotes competed and no override is given.
+/// Returns `QUOTE_EXPIRED` if the quote has expired.
+/// Returns `FIRM_UP_PRICE_MOVED` if the firm price moved beyond tolerance.
+/// Returns `TIMEOUT` if the venue did not firm up the quote in time.
+/// Returns `NOT_IMPLEMENTED` if no firm-up service is configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/rfqs/{id}/select",
+    tag = "rfqs",
+    params(("id" = String, Path, description = "RFQ ID (UUID)")),
+    request_body = SelectQuoteRequest,
+    responses(
+        (status = 200, description = "Quote selected", body = RfqResponse),
+        (status = 400, description = "Invalid RFQ or quote ID", body = ErrorResponse),
+        (status = 404, description = "RFQ or quote not found", body = ErrorResponse),
+        (status = 409, description = "Quote expired, RFQ in wrong state, or firm-up price moved", body = ErrorResponse),
+        (status = 422, description = "Quote quorum not met", body = ErrorResponse),
+        (status = 501, description = "Firm-up service not configured", body = ErrorResponse),
+        (status = 502, description = "Venue failed to firm up the quote", body = ErrorResponse),
+        (status = 504, description = "Firm-up timed out", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, id, request), fields(rfq_id = %id))]
+pub async fn select_quote(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    ApiJson(request): ApiJson<SelectQuoteRequest>,
+) -> Result<Json<RfqResponse>, ApiError> {
+    info!("Selecting quote {} on RFQ: {}", request.quote_id, id);
+
+    let rfq_id = parse_rfq_id(&id)?;
+    let quote_id = parse_quote_id(&request.quote_id)?;
+    let firm_up = state
+        .firm_up
+        .as_ref()
+        .ok_or_else(|| not_implemented("firm-up service not configured"))?;
+
+    let result = match (request.quorum_override_reason.as_deref(), request.side) {
+        (Some(reason), side) => {
+            firm_up
+                .select_quote_overriding_quorum(rfq_id, quote_id, side, reason)
+                .await
+        }
+        (None, Some(side)) => firm_up.select_quote_on_side(rfq_id, quote_id, side).await,
+        (None, None) => firm_up.select_quote(rfq_id, quote_id).await,
+    };
+    let rfq = result.map_err(|e| {
+        warn!("Cannot select quote: {}", e);
+        from_application_error(&e)
+    })?;
+
+    info!("Selected quote on RFQ: {}", id);
+
+    Ok(Json(RfqResponse::from(&rfq)))
+}
+
+/// Get the audit timeline of an RFQ.
+///
+/// Returns every stored event of the RFQ, plus the trade and settlement
+/// events of its trades, oldest first. `?format=csv` returns the same rows
+/// as an RFC 4180 CSV export.
+///
+/// # Errors
+///
+/// Returns `RFQ_NOT_FOUND` if the RFQ does not exist.
+/// Returns `VALIDATION_ERROR` if the ID is not a valid UUID.
+/// Returns `NOT_IMPLEMENTED` if no event store is configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/rfqs/{id}/timeline",
+    tag = "rfqs",
+    params(("id" = String, Path, description = "RFQ ID (UUID)"), TimelineParams),
+    responses(
+        (status = 200, description = "RFQ timeline", body = Vec<TimelineEntry>),
+        (status = 200, description = "RFQ timeline as CSV", content_type = "text/csv"),
+        (status = 400, description = "Invalid RFQ ID", body = ErrorResponse),
+        (status = 404, description = "RFQ not found", body = ErrorResponse),
+        (status = 501, description = "Event store not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, id), fields(rfq_id = %id))]
+pub async fn get_rfq_timeline(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(params): Query<TimelineParams>,
+) -> Result<Response, ApiError> {
+    info!("Getting timeline of RFQ: {}", id);
+
+    let rfq_id = parse_rfq_id(&id)?;
+    let event_store = state
+        .event_store
+        .as_ref()
+        .ok_or_else(|| not_implemented("event store not configured"))?;
+
+    let rfq = state
+        .rfq_repository
+        .find_by_id(rfq_id)
+        .await
+        .map_err(|e| {
+            error!("Failed to find RFQ: {}", e);
+            internal_error(&e)
+        })?
+        .ok_or_else(|| rfq_not_found(&id))?;
+
+    let load_failed = |e: &dyn std::fmt::Display| {
+        error!("Failed to load RFQ timeline: {}", e);
+        internal_error(&e.to_string())
+    };
+
+    let mut events = event_store
+        .get_events(rfq_id)
+        .await
+        .map_err(|e| load_failed(&e))?;
+
+    // Trade and settlement events are linked to the RFQ through the trade.
+    let trades = state
+        .trade_repository
+        .find_all(&TradeFilter {
+            rfq_id: Some(rfq_id.to_string()),
+            venue_id: None,
+        })
+        .await
+        .map_err(|e| load_failed(&e))?;
+    let trade_ids: HashSet<String> = trades.iter().map(|t| t.id().to_string()).collect();
+    if !trade_ids.is_empty() {
+        for event_type in [EventType::Trade, EventType::Settlement] {
+            let linked = event_store
+                .get_events_by_type(event_type)
+                .await
+                .map_err(|e| load_failed(&e))?;
+            events.extend(
+                linked
+                    .into_iter()
+                    .filter(|e| linked_trade_id(e).is_some_and(|id| trade_ids.contains(id))),
+            );
+        }
+    }
+
+    let timeline = build_timeline(events, rfq.client_id());
+
+    Ok(match params.format {
+        TimelineFormat::Json => Json(timeline).into_response(),
+        TimelineFormat::Csv => (
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"rfq-{rfq_id}-timeline.csv\""),
+                ),
+            ],
+            to_csv(&timeline),
+        )
+            .into_response(),
+    })
+}
+
+/// Get the per-venue outcome report of an RFQ's quote aggregation.
+///
+/// Lists, for each venue considered, whether it responded and how fast,
+/// timed out, errored, had its quotes filtered, or was excluded.
+///
+/// # Errors
+///
+/// Returns `RFQ_NOT_FOUND` if the RFQ does not exist.
+/// Returns `NOT_FOUND` if the RFQ has no aggregation report.
+/// Returns `VALIDATION_ERROR` if the ID is not a valid UUID.
+/// Returns `NOT_IMPLEMENTED` if no report store is configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/rfqs/{id}/aggregation-report",
+    tag = "rfqs",
+    params(("id" = String, Path, description = "RFQ ID (UUID)")),
+    responses(
+        (status = 200, description = "Aggregation report", body = AggregationReportResponse),
+        (status = 400, description = "Invalid RFQ ID", body = ErrorResponse),
+        (status = 404, description = "RFQ or report not found", body = ErrorResponse),
+        (status = 501, description = "Report store not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, id), fields(rfq_id = %id))]
+pub async fn get_rfq_aggregation_report(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<AggregationReportResponse>, ApiError> {
+    info!("Getting aggregation report of RFQ: {}", id);
+
+    let rfq_id = parse_rfq_id(&id)?;
+    let reports = state
+        .aggregation_reports
+        .as_ref()
+        .ok_or_else(|| not_implemented("aggregation report store not configured"))?;
+
+    state
+        .rfq_repository
+        .find_by_id(rfq_id)
+        .await
+        .map_err(|e| {
+            error!("Failed to find RFQ: {}", e);
+            internal_error(&e)
+        })?
+        .ok_or_else(|| rfq_not_found(&id))?;
+
+    let report = reports
+        .find_by_rfq_id(rfq_id)
+        .await
+        .map_err(|e| {
+            error!("Failed to load aggregation report: {}", e);
+            internal_error(&e.to_string())
+        })?
+        .ok_or_else(|| not_found("aggregation report", &id))?;
+
+    Ok(Json(AggregationReportResponse::from(&report)))
+}
+
+/// Explain how a quote of an RFQ was ranked.
+///
+/// Returns the quote's rank and score as of aggregation, and the factors
+/// the score was made of: each factor's raw value, weight and contribution.
+///
+/// # Errors
+///
+/// Returns `RFQ_NOT_FOUND` if the RFQ does not exist.
+/// Returns `NOT_FOUND` if the RFQ has no aggregation report or the quote
+/// was not ranked in it.
+/// Returns `VALIDATION_ERROR` if an ID is not a valid UUID.
+/// Returns `NOT_IMPLEMENTED` if no report store is configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/rfqs/{id}/quotes/{quote_id}/ranking-explanation",
+    tag = "rfqs",
+    params(
+        ("id" = String, Path, description = "RFQ ID (UUID)"),
+        ("quote_id" = String, Path, description = "Quote ID (UUID)"),
+    ),
+    responses(
+        (status = 200, description = "Ranking explanation", body = RankingExplanationResponse),
+        (status = 400, description = "Invalid RFQ or quote ID", body = ErrorResponse),
+        (status = 404, description = "RFQ, report or ranking not found", body = ErrorResponse),
+        (status = 501, description = "Report store not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, id, quote_id), fields(rfq_id = %id, quote_id = %quote_id))]
+pub async fn get_quote_ranking_explanation(
+    State(state): State<Arc<AppState>>,
+    Path((id, quote_id)): Path<(String, String)>,
+) -> Result<Json<RankingExplanationResponse>, ApiError> {
+    info!("Explaining ranking of quote {} of RFQ: {}", quote_id, id);
+
+    let rfq_id = parse_rfq_id(&id)?;
+    let parsed_quote_id = parse_quote_id(&quote_id)?;
+    let reports = state
+        .aggregation_reports
+        .as_ref()
+        .ok_or_else(|| not_implemented("aggregation report store not configured"))?;
+
+    state
+        .rfq_repository
+        .find_by_id(rfq_id)
+        .await
+        .map_err(|e| {
+            error!("Failed to find RFQ: {}", e);
+            internal_error(&e)
+        })?
+        .ok_or_else(|| rfq_not_found(&id))?;
+
+    let report = reports
+        .find_by_rfq_id(rfq_id)
+        .await
+        .map_err(|e| {
+            error!("Failed to load aggregation report: {}", e);
+            internal_error(&e.to_string())
+        })?
+        .ok_or_else(|| not_found("aggregation report", &id))?;
+    let ranking = report
+        .quote_ranking(parsed_quote_id)
+        .ok_or_else(|| not_found("quote ranking", &quote_id))?;
+
+    Ok(Json(RankingExplanationResponse::new(&report, ranking)))
+}
+
+// ============================================================================
+// Venue Handlers
+// ============================================================================
+
+/// List all venues.
+///
+/// # Errors
+///
+/// Returns an error response if the repository query fails.
+#[utoipa::path(
+    get,
+    path = "/api/v1/venues",
+    tag = "venues",
+    responses(
+        (status = 200, description = "All venues", body = Vec<VenueResponse>),
+        (status = 500, description = "Repository failure", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn list_venues(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<VenueResponse>>, ApiError> {
+    info!("Listing venues");
+
+    let venues = state.venue_repository.find_all().await.map_err(|e| {
+        error!("Failed to list venues: {}", e);
+        internal_error(&e)
+    })?;
+
+    let responses: Vec<VenueResponse> = venues.iter().map(VenueResponse::from).collect();
+
+    Ok(Json(responses))
+}
+
+/// Update venue configuration.
+///
+/// The change is recorded in the venue's configuration history.
+///
+/// # Errors
+///
+/// Returns `NOT_FOUND` if the venue does not exist.
+/// Returns `INTERNAL_ERROR` if the repository save fails.
+#[utoipa::path(
+    put,
+    path = "/api/v1/venues/{id}",
+    tag = "venues",
+    params(("id" = String, Path, description = "Venue ID")),
+    request_body = UpdateVenueRequest,
+    responses(
+        (status = 200, description = "Venue updated", body = VenueResponse),
+        (status = 404, description = "Venue not found", body = ErrorResponse),
+        (status = 500, description = "Repository failure", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request, id), fields(venue_id = %id))]
+pub async fn update_venue(
+    State(state): State<Arc<AppState>>,
+    user: OptionalUser,
+    Path(id): Path<String>,
+    ApiJson(request): ApiJson<UpdateVenueRequest>,
+) -> Result<Json<VenueResponse>, ApiError> {
+    info!("Updating venue: {}", id);
+
+    let (venue, _) = apply_venue_update(&state, &id, changed_by(&user), None, |venue| {
+        let mode = request.mode.or(request.enabled.map(|enabled| {
+            if enabled {
+                VenueMode::Live
+            } else {
+                VenueMode::Disabled
+            }
+        }));
+        if let Some(mode) = mode {
+            venue.set_mode(mode);
+        }
+        if let Some(max) = request.max_concurrent_requests {
+            venue.config_mut().set_max_concurrent_requests(max);
+        }
+
+        // Note: priority update would require adding set_priority to Venue
+        // For now, we ignore the priority field
+        let _ = request.priority;
+    })
+    .await?;
+
+    info!("Updated venue: {}", id);
+
+    Ok(Json(VenueResponse::from(&venue)))
+}
+
+/// Get the configuration history of a venue.
+///
+/// # Errors
+///
+/// Returns `NOT_FOUND` if the venue does not exist.
+/// Returns `INTERNAL_ERROR` if the repository query fails.
+#[utoipa::path(
+    get,
+    path = "/api/v1/venues/{id}/history",
+    tag = "venues",
+    params(("id" = String, Path, description = "Venue ID")),
+    responses(
+        (status = 200, description = "Configuration changes, newest first", body = Vec<VenueConfigChangeResponse>),
+        (status = 404, description = "Venue not found", body = ErrorResponse),
+        (status = 500, description = "Repository failure", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, id), fields(venue_id = %id))]
+pub async fn get_venue_history(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<VenueConfigChangeResponse>>, ApiError> {
+    let history = find_venue_history(&state, &id).await?;
+
+    Ok(Json(
+        history
+            .iter()
+            .map(VenueConfigChangeResponse::from)
+            .collect(),
+    ))
+}
+
+/// Compare a venue's quotes with the live quotes.
+///
+/// Admin only. Shows how often the venue's quotes ranked best over the MM
+/// performance window; for a venue in shadow mode, how often it would have
+/// won had it been live.
+///
+/// # Errors
+///
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_FOUND` if the venue does not exist.
+/// Returns `VALIDATION_ERROR` if the venue has no market maker to track.
+/// Returns `NOT_IMPLEMENTED` if the performance tracker is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/venues/{id}/shadow-report",
+    tag = "venues",
+    params(("id" = String, Path, description = "Venue ID")),
+    responses(
+        (status = 200, description = "Quote comparison of the venue", body = ShadowVenueReportResponse),
+        (status = 400, description = "Venue has no market maker", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Venue not found", body = ErrorResponse),
+        (status = 500, description = "Repository or computation failure", body = ErrorResponse),
+        (status = 501, description = "Tracker not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, id), fields(venue_id = %id))]
+pub async fn get_venue_shadow_report(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<Json<ShadowVenueReportResponse>, ApiError> {
+    if require_role(&user, "admin").is_err() {
+        warn!("Denied shadow report of venue {} to {}", id, user.sub);
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+
+    let venue = find_venue(&state, &id).await?;
+    let mm_id = venue
+        .mm_counterparty_id()
+        .cloned()
+        .ok_or_else(|| validation_error("venue has no market maker to track"))?;
+    let tracker = state
+        .mm_performance_tracker
+        .as_ref()
+        .ok_or_else(|| not_implemented("mm performance tracker not configured"))?;
+
+    let metrics = tracker.get_metrics(&mm_id).await.map_err(|e| {
+        error!("Failed to get MM metrics for {}: {}", mm_id, e);
+        internal_error(&e.to_string())
+    })?;
+
+    Ok(Json(ShadowVenueReportResponse {
+        venue_id: venue.id().to_string(),
+        mode: venue.mode(),
+        mm_id: mm_id.to_string(),
+        rfqs_received: metrics.total_rfqs_received(),
+        quotes_provided: metrics.total_quotes_provided(),
+        would_have_won: metrics.total_best_quotes(),
+        win_rate_pct: metrics.best_quote_pct(),
+        competitiveness_score: metrics.competitiveness_score(),
+        window_start: metrics.window_start().to_iso8601(),
+        window_end: metrics.window_end().to_iso8601(),
+    }))
+}
+
+/// Roll back a venue configuration change.
+///
+/// Re-applies the settings from before the given history entry through the
+/// regular update path, so the rollback is itself recorded.
+///
+/// # Errors
+///
+/// Returns `NOT_FOUND` if the venue or history entry does not exist.
+/// Returns `INTERNAL_ERROR` if the repository save fails.
+#[utoipa::path(
+    post,
+    path = "/api/v1/venues/{id}/rollback/{history_id}",
+    tag = "venues",
+    params(
+        ("id" = String, Path, description = "Venue ID"),
+        ("history_id" = u64, Path, description = "History entry to roll back"),
+    ),
+    responses(
+        (status = 200, description = "Rollback recorded", body = VenueConfigChangeResponse),
+        (status = 404, description = "Venue or history entry not found", body = ErrorResponse),
+        (status = 500, description = "Repository failure", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, id), fields(venue_id = %id))]
+pub async fn rollback_venue_config(
+    State(state): State<Arc<AppState>>,
+    user: OptionalUser,
+    Path((id, history_id)): Path<(String, u64)>,
+) -> Result<Json<VenueConfigChangeResponse>, ApiError> {
+    info!("Rolling back venue {} to before change {}", id, history_id);
+
+    let target = find_venue_history(&state, &id)
+        .await?
+        .into_iter()
+        .find(|change| change.id() == history_id)
+        .ok_or_else(|| not_found("Venue config history entry", &history_id.to_string()))?;
+
+    let (_, change) =
+        apply_venue_update(&state, &id, changed_by(&user), Some(history_id), |venue| {
+            target.old_settings().apply_to(venue)
+        })
+        .await?;
+
+    info!("Rolled back venue {} as change {}", id, change.id());
+
+    Ok(Json(VenueConfigChangeResponse::from(&change)))
+}
+
+/// Subject recorded for venue changes made without authenticated claims.
+const ANONYMOUS_SUBJECT: &str = "anonymous";
+
+/// Returns the subject to record as the author of a change.
+fn changed_by(user: &OptionalUser) -> &str {
+    user.0
+        .as_ref()
+        .map_or(ANONYMOUS_SUBJECT, |claims| claims.sub.as_str())
+}
+
+/// Loads a venue, applies `update`, and saves it with a history entry.
+///
+/// The saved concurrency limit is pushed to the request gate so it takes
+/// effect without a restart.
+async fn apply_venue_update(
+    state: &AppState,
+    id: &str,
+    changed_by: &str,
+    rollback_of: Option<u64>,
+    update: impl FnOnce(&mut Venue),
+) -> Result<(Venue, VenueConfigChange), ApiError> {
+    let mut venue = state
+        .venue_repository
+        .find_by_id(&VenueId::new(id))
+        .await
+        .map_err(|e| {
+            error!("Failed to find venue: {}", e);
+            internal_error(&e)
+        })?
+        .ok_or_else(|| not_found("Venue", id))?;
+
+    update(&mut venue);
+
+    let change = state
+        .venue_repository
+        .save_with_history(&venue, changed_by, rollback_of)
+        .await
+        .map_err(|e| {
+            error!("Failed to save venue: {}", e);
+            internal_error(&e)
+        })?;
+
+    if let Some(gate) = &state.venue_request_gate {
+        gate.sync_venue(&venue);
+    }
+
+    Ok((venue, change))
+}
+
+/// Returns the history of an existing venue, newest first.
+async fn find_venue_history(
+    state: &AppState,
+    id: &str,
+) -> Result<Vec<VenueConfigChange>, ApiError> {
+    let venue_id = VenueId::new(id);
+
+    state
+        .venue_repository
+        .find_by_id(&venue_id)
+        .await
+        .map_err(|e| {
+            error!("Failed to find venue: {}", e);
+            internal_error(&e)
+        })?
+        .ok_or_else(|| not_found("Venue", id))?;
+
+    state
+        .venue_repository
+        .history(&venue_id)
+        .await
+        .map_err(|e| {
+            error!("Failed to load venue history: {}", e);
+            internal_error(&e)
+        })
+}
+
+// ============================================================================
+// Venue Circuit Breaker DTOs
+// ============================================================================
+
+/// Manual circuit breaker control.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CircuitAction {
+    /// Hold the circuit open; the venue is not asked for quotes.
+    ForceOpen,
+    /// Hold the circuit closed; the venue is always asked.
+    ForceClose,
+    /// Remove the override and resume automatic control.
+    Clear,
+}
+
+/// Request to override a venue's circuit breaker.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CircuitControlRequest {
+    /// Override to apply.
+    pub action: CircuitAction,
+}
+
+/// Circuit breaker status of a venue.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CircuitStatusResponse {
+    /// Venue ID.
+    pub venue_id: String,
+    /// Circuit state: `CLOSED`, `OPEN`, or `HALF_OPEN`.
+    pub state: String,
+    /// Manual override in force: `FORCED_OPEN` or `FORCED_CLOSED`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forced: Option<String>,
+    /// Consecutive failures recorded.
+    pub failure_count: u32,
+}
+
+impl From<&CircuitBreaker> for CircuitStatusResponse {
+    fn from(breaker: &CircuitBreaker) -> Self {
+        Self {
+            venue_id: breaker.name().to_string(),
+            state: breaker.state().to_string(),
+            forced: breaker.forced().map(|forced| forced.to_string()),
+            failure_count: breaker.failure_count(),
+        }
+    }
+}
+
+// ============================================================================
+// Venue Circuit Breaker Handlers
+// ============================================================================
+
+/// Override a venue's circuit breaker.
+///
+/// Admin only. Forcing the circuit open stops quote requests to the venue
+/// during an incident; forcing it closed keeps the venue in rotation
+/// whatever its error rate. Either holds until cleared.
+///
+/// # Errors
+///
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_FOUND` if the venue does not exist.
+/// Returns `NOT_IMPLEMENTED` if circuit breakers are not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/venues/{id}/circuit",
+    tag = "venues",
+    params(("id" = String, Path, description = "Venue ID")),
+    request_body = CircuitControlRequest,
+    responses(
+        (status = 200, description = "Circuit status after the override", body = CircuitStatusResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Venue not found", body = ErrorResponse),
+        (status = 501, description = "Circuit breakers not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request, id), fields(venue_id = %id))]
+pub async fn control_venue_circuit(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+    ApiJson(request): ApiJson<CircuitControlRequest>,
+) -> Result<Json<CircuitStatusResponse>, ApiError> {
+    if require_role(&user, "admin").is_err() {
+        warn!("Denied circuit control of venue {} to {}", id, user.sub);
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+
+    let breakers = state
+        .circuit_breakers
+        .as_ref()
+        .ok_or_else(|| not_implemented("circuit breakers not configured"))?;
+
+    state
+        .venue_repository
+        .find_by_id(&VenueId::new(&id))
+        .await
+        .map_err(|e| {
+            error!("Failed to find venue: {}", e);
+            internal_error(&e)
+        })?
+        .ok_or_else(|| not_found("Venue", &id))?;
+
+    let breaker = breakers.get_or_create(&id);
+    match request.action {
+        CircuitAction::ForceOpen => breaker.force_open(),
+        CircuitAction::ForceClose => breaker.force_close(),
+        CircuitAction::Clear => breaker.clear_override(),
+    }
+
+    warn!(
+        "Circuit of venue {} set to {:?} by {}",
+        id, request.action, user.sub
+    );
+
+    Ok(Json(CircuitStatusResponse::from(breaker.as_ref())))
+}
+
+// ============================================================================
+// Venue Probe DTOs
+// ============================================================================
+
+/// Query parameters for an on-demand venue probe.
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct VenueProbeParams {
+    /// Probe only this venue; all enabled venues are probed when unset.
+    pub venue_id: Option<String>,
+}
+
+/// Outcome of probing one venue.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VenueProbeResponse {
+    /// Venue ID.
+    pub venue_id: String,
+    /// Whether the venue answered its health check.
+    pub success: bool,
+    /// Health check latency in milliseconds.
+    pub latency_ms: u64,
+    /// Why the health check failed or the venue is degraded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Health before the probe.
+    pub previous_health: VenueHealth,
+    /// Health after the probe. Changes only once a failure or recovery
+    /// streak reaches its threshold.
+    pub health: VenueHealth,
+    /// Why the probe outcome could not be stored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl From<VenueProbeResult> for VenueProbeResponse {
+    fn from(result: VenueProbeResult) -> Self {
+        Self {
+            venue_id: result.venue_id.to_string(),
+            success: result.success,
+            latency_ms: result.latency_ms,
+            message: result.message,
+            previous_health: result.previous_health,
+            health: result.health,
+            error: result.error,
+        }
+    }
+}
+
+/// Results of an on-demand venue probe.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VenueProbeReport {
+    /// Venues probed.
+    pub probed: usize,
+    /// Venues that answered their health check.
+    pub succeeded: usize,
+    /// Venues that did not answer their health check.
+    pub failed: usize,
+    /// Per-venue results, ordered by venue ID.
+    pub results: Vec<VenueProbeResponse>,
+}
+
+impl From<Vec<VenueProbeResult>> for VenueProbeReport {
+    fn from(results: Vec<VenueProbeResult>) -> Self {
+        let succeeded = results.iter().filter(|r| r.success).count();
+        Self {
+            probed: results.len(),
+            succeeded,
+            failed: results.len().saturating_sub(succeeded),
+            results: results.into_iter().map(VenueProbeResponse::from).collect(),
+        }
+    }
+}
+
+// ============================================================================
+// Venue Probe Handlers
+// ============================================================================
+
+/// Probe venue health now.
+///
+/// Admin only. Runs the venue health checks immediately instead of waiting
+/// for the background prober and returns the per-venue results. Outcomes
+/// count towards the same failure and recovery streaks as background
+/// probes.
+///
+/// # Errors
+///
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_FOUND` if `venue_id` has no registered adapter.
+/// Returns `NOT_IMPLEMENTED` if the venue prober is not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/venues/probe",
+    tag = "venues",
+    params(VenueProbeParams),
+    responses(
+        (status = 200, description = "Probe results", body = VenueProbeReport),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Venue not found", body = ErrorResponse),
+        (status = 501, description = "Venue prober not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, params))]
+pub async fn probe_venues(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Query(params): Query<VenueProbeParams>,
+) -> Result<Json<VenueProbeReport>, ApiError> {
+    if require_role(&user, "admin").is_err() {
+        warn!("Denied venue probe to {}", user.sub);
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+
+    let prober = state
+        .venue_prober
+        .as_ref()
+        .ok_or_else(|| not_implemented("venue prober not configured"))?;
+
+    let results = match params.venue_id {
+        Some(id) => vec![
+            prober
+                .probe_venue(&VenueId::new(&id))
+                .await
+                .ok_or_else(|| not_found("Venue", &id))?,
+        ],
+        None => prober.probe_all().await,
+    };
+
+    info!(
+        "Venue probe of {} venues run by {}",
+        results.len(),
+        user.sub
+    );
+    Ok(Json(VenueProbeReport::from(results)))
+}
+
+// ============================================================================
+// Venue Maintenance DTOs
+// ============================================================================
+
+/// Request to schedule a venue maintenance window.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct MaintenanceWindowRequest {
+    /// When the window opens (RFC 3339).
+    pub start: String,
+    /// When the window closes (RFC 3339).
+    pub end: String,
+    /// Reason announced by the venue.
+    #[serde(default)]
+    pub reason: String,
+}
+
+/// A scheduled venue maintenance window.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MaintenanceWindowResponse {
+    /// When the window opens.
+    pub start: String,
+    /// When the window closes.
+    pub end: String,
+    /// Reason announced by the venue.
+    pub reason: String,
+    /// Whether the window is in force now.
+    pub active: bool,
+}
+
+impl From<&MaintenanceWindow> for MaintenanceWindowResponse {
+    fn from(window: &MaintenanceWindow) -> Self {
+        Self {
+            start: window.start().to_string(),
+            end: window.end().to_string(),
+            reason: window.reason().to_string(),
+            active: window.is_active_at(Timestamp::now()),
+        }
+    }
+}
+
+// ============================================================================
+// Venue Maintenance Handlers
+// ============================================================================
+
+/// List a venue's scheduled maintenance windows.
+///
+/// # Errors
+///
+/// Returns `NOT_FOUND` if the venue does not exist.
+#[utoipa::path(
+    get,
+    path = "/api/v1/venues/{id}/maintenance",
+    tag = "venues",
+    params(("id" = String, Path, description = "Venue ID")),
+    responses(
+        (status = 200, description = "Maintenance windows, ordered by start", body = Vec<MaintenanceWindowResponse>),
+        (status = 404, description = "Venue not found", body = ErrorResponse),
+        (status = 500, description = "Repository failure", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, id), fields(venue_id = %id))]
+pub async fn list_venue_maintenance(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<MaintenanceWindowResponse>>, ApiError> {
+    let venue = find_venue(&state, &id).await?;
+
+    Ok(Json(
+        venue
+            .maintenance_windows()
+            .iter()
+            .map(MaintenanceWindowResponse::from)
+            .collect(),
+    ))
+}
+
+/// Schedule a venue maintenance window.
+///
+/// Admin only. The venue is not sent RFQs while the window is in force.
+/// Windows overlapping the new one are merged into it, and windows that
+/// have already ended are pruned.
+///
+/// # Errors
+///
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if a time is malformed or the window is empty.
+/// Returns `NOT_FOUND` if the venue does not exist.
+#[utoipa::path(
+    post,
+    path = "/api/v1/venues/{id}/maintenance",
+    tag = "venues",
+    params(("id" = String, Path, description = "Venue ID")),
+    request_body = MaintenanceWindowRequest,
+    responses(
+        (status = 201, description = "Window as stored, after merging", body = MaintenanceWindowResponse),
+        (status = 400, description = "Invalid window", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Venue not found", body = ErrorResponse),
+        (status = 500, description = "Repository failure", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request, id), fields(venue_id = %id))]
+pub async fn add_venue_maintenance(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+    ApiJson(request): ApiJson<MaintenanceWindowRequest>,
+) -> Result<(StatusCode, Json<MaintenanceWindowResponse>), ApiError> {
+    require_maintenance_admin(&user, &id)?;
+    let start = parse_timestamp("start", &request.start)?;
+    let end = parse_timestamp("end", &request.end)?;
+    let window =
+        MaintenanceWindow::new(start, end, request.reason).map_err(|e| from_domain_error(&e))?;
+
+    let mut venue = find_venue(&state, &id).await?;
+    venue.prune_maintenance_windows(Timestamp::now());
+    let stored = venue.add_maintenance_window(window);
+    save_venue(&state, &venue).await?;
+
+    info!(
+        "Scheduled maintenance of venue {} from {} to {} by {}",
+        id,
+        stored.start(),
+        stored.end(),
+        user.sub
+    );
+
+    Ok((
+        StatusCode::CREATED,
+        Json(MaintenanceWindowResponse::from(&stored)),
+    ))
+}
+
+/// Remove a venue maintenance window.
+///
+/// Admin only. Windows that have already ended are pruned as well.
+///
+/// # Errors
+///
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if `start` is malformed.
+/// Returns `NOT_FOUND` if the venue or window does not exist.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/venues/{id}/maintenance/{start}",
+    tag = "venues",
+    params(
+        ("id" = String, Path, description = "Venue ID"),
+        ("start" = String, Path, description = "Start of the window to remove (RFC 3339)"),
+    ),
+    responses(
+        (status = 200, description = "Removed window", body = MaintenanceWindowResponse),
+        (status = 400, description = "Invalid start time", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Venue or window not found", body = ErrorResponse),
+        (status = 500, description = "Repository failure", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, id, start), fields(venue_id = %id))]
+pub async fn remove_venue_maintenance(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path((id, start)): Path<(String, String)>,
+) -> Result<Json<MaintenanceWindowResponse>, ApiError> {
+    require_maintenance_admin(&user, &id)?;
+    let start_at = parse_timestamp("start", &start)?;
+
+    let mut venue = find_venue(&state, &id).await?;
+    let removed = venue
+        .remove_maintenance_window(start_at)
+        .ok_or_else(|| not_found("Maintenance window", &start))?;
+    venue.prune_maintenance_windows(Timestamp::now());
+    save_venue(&state, &venue).await?;
+
+    info!(
+        "Removed maintenance of venue {} starting {} by {}",
+        id, start, user.sub
+    );
+
+    Ok(Json(MaintenanceWindowResponse::from(&removed)))
+}
+
+fn require_maintenance_admin(user: &Claims, venue_id: &str) -> Result<(), ApiError> {
+    if require_role(user, "admin").is_err() {
+        warn!(
+            "Denied maintenance change of venue {} to {}",
+            venue_id, user.sub
+        );
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+    Ok(())
+}
+
+/// Loads an existing venue.
+async fn find_venue(state: &AppState, id: &str) -> Result<Venue, ApiError> {
+    state
+        .venue_repository
+        .find_by_id(&VenueId::new(id))
+        .await
+        .map_err(|e| {
+            error!("Failed to find venue: {}", e);
+            internal_error(&e)
+        })?
+        .ok_or_else(|| not_found("Venue", id))
+}
+
+async fn save_venue(state: &AppState, venue: &Venue) -> Result<(), ApiError> {
+    state.venue_repository.save(venue).await.map_err(|e| {
+        error!("Failed to save venue: {}", e);
+        internal_error(&e)
+    })
+}
+
+// ============================================================================
+// Instrument Reference Data Handlers
+// ============================================================================
+
+/// List instrument reference data.
+///
+/// # Errors
+///
+/// Returns `NOT_IMPLEMENTED` if reference data is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/instruments",
+    tag = "instruments",
+    responses(
+        (status = 200, description = "Reference data for all instruments", body = [InstrumentReferenceDataResponse]),
+        (status = 501, description = "Reference data not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn list_instrument_reference_data(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<InstrumentReferenceDataResponse>>, ApiError> {
+    let repository = reference_data_repository(&state)?;
+
+    let all = repository
+        .get_all()
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    Ok(Json(
+        all.iter()
+            .map(InstrumentReferenceDataResponse::from)
+            .collect(),
+    ))
+}
+
+/// Get instrument reference data by symbol.
+///
+/// # Errors
+///
+/// Returns `NOT_FOUND` if no reference data exists for the symbol.
+/// Returns `NOT_IMPLEMENTED` if reference data is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/instruments/{base}/{quote}",
+    tag = "instruments",
+    params(
+        ("base" = String, Path, description = "Base asset (e.g. BTC)"),
+        ("quote" = String, Path, description = "Quote asset (e.g. USD)"),
+    ),
+    responses(
+        (status = 200, description = "Instrument reference data", body = InstrumentReferenceDataResponse),
+        (status = 400, description = "Invalid symbol", body = ErrorResponse),
+        (status = 404, description = "Reference data not found", body = ErrorResponse),
+        (status = 501, description = "Reference data not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn get_instrument_reference_data(
+    State(state): State<Arc<AppState>>,
+    Path((base, quote)): Path<(String, String)>,
+) -> Result<Json<InstrumentReferenceDataResponse>, ApiError> {
+    let repository = reference_data_repository(&state)?;
+    let symbol = parse_symbol(&base, &quote)?;
+
+    let data = repository
+        .get(&symbol)
+        .await
+        .map_err(|e| from_repository_error(&e))?
+        .ok_or_else(|| not_found("Instrument", symbol.as_str()))?;
+
+    Ok(Json(InstrumentReferenceDataResponse::from(&data)))
+}
+
+/// Create or replace instrument reference data.
+///
+/// # Errors
+///
+/// Returns `VALIDATION_ERROR` if the request is malformed.
+/// Returns `NOT_IMPLEMENTED` if reference data is not configured.
+#[utoipa::path(
+    put,
+    path = "/api/v1/instruments/{base}/{quote}",
+    tag = "instruments",
+    params(
+        ("base" = String, Path, description = "Base asset (e.g. BTC)"),
+        ("quote" = String, Path, description = "Quote asset (e.g. USD)"),
+    ),
+    request_body = InstrumentReferenceDataRequest,
+    responses(
+        (status = 200, description = "Reference data saved", body = InstrumentReferenceDataResponse),
+        (status = 400, description = "Invalid reference data", body = ErrorResponse),
+        (status = 501, description = "Reference data not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, request))]
+pub async fn put_instrument_reference_data(
+    State(state): State<Arc<AppState>>,
+    Path((base, quote)): Path<(String, String)>,
+    ApiJson(request): ApiJson<InstrumentReferenceDataRequest>,
+) -> Result<Json<InstrumentReferenceDataResponse>, ApiError> {
+    let repository = reference_data_repository(&state)?;
+    let symbol = parse_symbol(&base, &quote)?;
+    let data = request.into_reference_data(symbol)?;
+
+    repository
+        .save(&data)
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    info!("Saved reference data for {}", data.symbol());
+
+    Ok(Json(InstrumentReferenceDataResponse::from(&data)))
+}
+
+/// Delete instrument reference data.
+///
+/// # Errors
+///
+/// Returns `NOT_FOUND` if no reference data exists for the symbol.
+/// Returns `NOT_IMPLEMENTED` if reference data is not configured.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/instruments/{base}/{quote}",
+    tag = "instruments",
+    params(
+        ("base" = String, Path, description = "Base asset (e.g. BTC)"),
+        ("quote" = String, Path, description = "Quote asset (e.g. USD)"),
+    ),
+    responses(
+        (status = 204, description = "Reference data deleted"),
+        (status = 404, description = "Reference data not found", body = ErrorResponse),
+        (status = 501, description = "Reference data not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn delete_instrument_reference_data(
+    State(state): State<Arc<AppState>>,
+    Path((base, quote)): Path<(String, String)>,
+) -> Result<StatusCode, ApiError> {
+    let repository = reference_data_repository(&state)?;
+    let symbol = parse_symbol(&base, &quote)?;
+
+    let deleted = repository
+        .delete(&symbol)
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+    if !deleted {
+        return Err(not_found("Instrument", symbol.as_str()));
+    }
+
+    info!("Deleted reference data for {}", symbol);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Get the liquidity tier derived from an instrument's recent activity.
+///
+/// The tier is served from cache while fresh and recomputed otherwise.
+///
+/// # Errors
+///
+/// Returns `BAD_REQUEST` if the symbol is invalid.
+/// Returns `NOT_IMPLEMENTED` if the liquidity classifier is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/instruments/{base}/{quote}/liquidity",
+    tag = "instruments",
+    params(
+        ("base" = String, Path, description = "Base asset (e.g. BTC)"),
+        ("quote" = String, Path, description = "Quote asset (e.g. USD)"),
+    ),
+    responses(
+        (status = 200, description = "Derived liquidity tier", body = LiquidityResponse),
+        (status = 400, description = "Invalid symbol", body = ErrorResponse),
+        (status = 501, description = "Liquidity classifier not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn get_instrument_liquidity(
+    State(state): State<Arc<AppState>>,
+    Path((base, quote)): Path<(String, String)>,
+) -> Result<Json<LiquidityResponse>, ApiError> {
+    let classifier = state
+        .liquidity
+        .as_ref()
+        .ok_or_else(|| not_implemented("liquidity classifier not configured"))?;
+    let symbol = parse_symbol(&base, &quote)?;
+
+    let assessment = classifier
+        .classify_symbol(&symbol)
+        .await
+        .map_err(|e| from_application_error(&e))?;
+
+    Ok(Json(LiquidityResponse::from(&assessment)))
+}
+
+// ============================================================================
+// RFQ Template Handlers
+// ============================================================================
+
+/// List the caller's RFQ templates.
+///
+/// # Errors
+///
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `NOT_IMPLEMENTED` if templates are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/rfq-templates",
+    tag = "rfq-templates",
+    responses(
+        (status = 200, description = "The caller's templates, by name", body = [RfqTemplateResponse]),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 501, description = "Templates not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn list_rfq_templates(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+) -> Result<Json<Vec<RfqTemplateResponse>>, ApiError> {
+    let repository = rfq_template_repository(&state)?;
+
+    let templates = repository
+        .find_by_owner(&requesting_counterparty(&user))
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    Ok(Json(
+        templates.iter().map(RfqTemplateResponse::from).collect(),
+    ))
+}
+
+/// Create an RFQ template owned by the caller.
+///
+/// # Errors
+///
+/// Returns `VALIDATION_ERROR` if the request is invalid.
+/// Returns `INVALID_MIN_QUANTITY` if the size mode's minimum is not positive
+/// or exceeds the quantity.
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `NOT_IMPLEMENTED` if templates are not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/rfq-templates",
+    tag = "rfq-templates",
+    request_body = RfqTemplateRequest,
+    responses(
+        (status = 201, description = "Template created", body = RfqTemplateResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 422, description = "Business rule violation", body = ErrorResponse),
+        (status = 501, description = "Templates not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn create_rfq_template(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    ApiJson(request): ApiJson<RfqTemplateRequest>,
+) -> Result<(StatusCode, Json<RfqTemplateResponse>), ApiError> {
+    let repository = rfq_template_repository(&state)?;
+    let template = request.to_template(requesting_counterparty(&user))?;
+    validate_against_reference_data(&state, template.subject(), template.quantity()).await?;
+
+    repository
+        .save(&template)
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    info!(
+        "Created RFQ template {} for {}",
+        template.id(),
+        template.owner()
+    );
+
+    Ok((
+        StatusCode::CREATED,
+        Json(RfqTemplateResponse::from(&template)),
+    ))
+}
+
+/// Get one of the caller's RFQ templates.
+///
+/// # Errors
+///
+/// Returns `NOT_FOUND` if the template does not exist.
+/// Returns `UNAUTHORIZED_COUNTERPARTY` if another counterparty owns it.
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `NOT_IMPLEMENTED` if templates are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/rfq-templates/{id}",
+    tag = "rfq-templates",
+    params(("id" = String, Path, description = "Template ID (UUID)")),
+    responses(
+        (status = 200, description = "Template", body = RfqTemplateResponse),
+        (status = 400, description = "Invalid template ID", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Template owned by another counterparty", body = ErrorResponse),
+        (status = 404, description = "Template not found", body = ErrorResponse),
+        (status = 501, description = "Templates not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn get_rfq_template(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<Json<RfqTemplateResponse>, ApiError> {
+    let template = find_owned_template(&state, &user, &id).await?;
+
+    Ok(Json(RfqTemplateResponse::from(&template)))
+}
+
+/// Replace one of the caller's RFQ templates.
+///
+/// # Errors
+///
+/// Returns `VALIDATION_ERROR` if the request is invalid.
+/// Returns `NOT_FOUND` if the template does not exist.
+/// Returns `UNAUTHORIZED_COUNTERPARTY` if another counterparty owns it.
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `NOT_IMPLEMENTED` if templates are not configured.
+#[utoipa::path(
+    put,
+    path = "/api/v1/rfq-templates/{id}",
+    tag = "rfq-templates",
+    params(("id" = String, Path, description = "Template ID (UUID)")),
+    request_body = RfqTemplateRequest,
+    responses(
+        (status = 200, description = "Template replaced", body = RfqTemplateResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Template owned by another counterparty", body = ErrorResponse),
+        (status = 404, description = "Template not found", body = ErrorResponse),
+        (status = 422, description = "Business rule violation", body = ErrorResponse),
+        (status = 501, description = "Templates not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn update_rfq_template(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+    ApiJson(request): ApiJson<RfqTemplateRequest>,
+) -> Result<Json<RfqTemplateResponse>, ApiError> {
+    let mut template = find_owned_template(&state, &user, &id).await?;
+    let replacement = request.to_template(template.owner().clone())?;
+    validate_against_reference_data(&state, replacement.subject(), replacement.quantity()).await?;
+    template.update_from(replacement);
+
+    rfq_template_repository(&state)?
+        .save(&template)
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    info!("Updated RFQ template {}", template.id());
+
+    Ok(Json(RfqTemplateResponse::from(&template)))
+}
+
+/// Delete one of the caller's RFQ templates.
+///
+/// # Errors
+///
+/// Returns `NOT_FOUND` if the template does not exist.
+/// Returns `UNAUTHORIZED_COUNTERPARTY` if another counterparty owns it.
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `NOT_IMPLEMENTED` if templates are not configured.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/rfq-templates/{id}",
+    tag = "rfq-templates",
+    params(("id" = String, Path, description = "Template ID (UUID)")),
+    responses(
+        (status = 204, description = "Template deleted"),
+        (status = 400, description = "Invalid template ID", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Template owned by another counterparty", body = ErrorResponse),
+        (status = 404, description = "Template not found", body = ErrorResponse),
+        (status = 501, description = "Templates not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn delete_rfq_template(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let template = find_owned_template(&state, &user, &id).await?;
+
+    rfq_template_repository(&state)?
+        .delete(template.id())
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    info!("Deleted RFQ template {}", template.id());
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Create an RFQ from one of the caller's templates.
+///
+/// The body is optional; its fields override the template's quantity and
+/// default TTL. The RFQ is validated exactly like one created directly.
+///
+/// # Errors
+///
+/// Returns `NOT_FOUND` if the template does not exist.
+/// Returns `UNAUTHORIZED_COUNTERPARTY` if another counterparty owns it.
+/// Returns `VALIDATION_ERROR` if an override is invalid.
+/// Returns `INVALID_MIN_QUANTITY` if the quantity override falls below the
+/// template's minimum fill.
+/// Returns `NO_ELIGIBLE_VENUES` if the template's venue lists leave no
+/// enabled venue.
+/// Returns `DUPLICATE` if the client refuses near-duplicate RFQs and has
+/// one open.
+/// Returns `SHUTTING_DOWN` if the service is draining for shutdown.
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `NOT_IMPLEMENTED` if templates are not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/rfqs/from-template/{template_id}",
+    tag = "rfqs",
+    params(("template_id" = String, Path, description = "Template ID (UUID)")),
+    request_body = Option<CreateRfqFromTemplateRequest>,
+    responses(
+        (status = 201, description = "RFQ created", body = RfqResponse),
+        (status = 400, description = "Invalid template ID or override", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Template owned by another counterparty", body = ErrorResponse),
+        (status = 404, description = "Template not found", body = ErrorResponse),
+        (status = 409, description = "Near-duplicate of an open RFQ", body = ErrorResponse),
+        (status = 422, description = "Business rule violation", body = ErrorResponse),
+        (status = 501, description = "Templates not configured", body = ErrorResponse),
+        (status = 503, description = "Service is shutting down", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request), fields(rfq_id = tracing::field::Empty))]
+pub async fn create_rfq_from_template(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(template_id): Path<String>,
+    request: Option<Json<CreateRfqFromTemplateRequest>>,
+) -> Result<(StatusCode, Json<RfqResponse>), ApiError> {
+    info!("Creating RFQ from template: {}", template_id);
+
+    ensure_accepting_rfqs(&state)?;
+
+    let template = find_owned_template(&state, &user, &template_id).await?;
+    let Json(overrides) = request.unwrap_or_default();
+
+    let quantity = overrides
+        .quantity
+        .map(|quantity| {
+            if quantity <= Decimal::ZERO {
+                return Err(validation_error("quantity must be positive"));
+            }
+            Quantity::from_decimal(quantity)
+                .map_err(|e| validation_error(&format!("invalid quantity: {e}")))
+        })
+        .transpose()?;
+    let expires_at = overrides
+        .expiry_seconds
+        .map(|seconds| {
+            if seconds == 0 {
+                return Err(validation_error("expiry_seconds must be greater than 0"));
+            }
+            Ok(Timestamp::now().add_secs(seconds as i64))
+        })
+        .transpose()?;
+
+    let response = save_new_rfq(&state, template.rfq_builder(quantity, expires_at)).await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Returns the counterparty an authenticated caller acts for.
+///
+/// Uses the `client_id` claim, falling back to the subject.
+fn requesting_counterparty(claims: &Claims) -> CounterpartyId {
+    CounterpartyId::new(claims.client_id.as_deref().unwrap_or(&claims.sub))
+}
+
+/// Loads a template and checks that the caller owns it.
+async fn find_owned_template(
+    state: &AppState,
+    user: &Claims,
+    id: &str,
+) -> Result<RfqTemplate, ApiError> {
+    let repository = rfq_template_repository(state)?;
+    let template_id = uuid::Uuid::parse_str(id)
+        .map(RfqTemplateId::from)
+        .map_err(|_| validation_error(&format!("invalid RFQ template ID: {id}")))?;
+
+    let template = repository
+        .find_by_id(template_id)
+        .await
+        .map_err(|e| from_repository_error(&e))?
+        .ok_or_else(|| not_found("RFQ template", id))?;
+
+    if !template.is_owned_by(&requesting_counterparty(user)) {
+        warn!("Denied access to RFQ template {} for {}", id, user.sub);
+        return Err(api_error(
+            ErrorCode::UnauthorizedCounterparty,
+            "RFQ template belongs to another counterparty",
+        ));
+    }
+    Ok(template)
+}
+
+fn rfq_template_repository(state: &AppState) -> Result<&Arc<dyn RfqTemplateRepository>, ApiError> {
+    state
+        .rfq_templates
+        .as_ref()
+        .ok_or_else(|| not_implemented("RFQ templates not configured"))
+}
+
+// ============================================================================
+// Trade Handlers
+// ============================================================================
+
+/// List trades with filtering and pagination.
+///
+/// # Errors
+///
+/// Returns an error response if the repository query fails.
+#[utoipa::path(
+    get,
+    path = "/api/v1/trades",
+    tag = "trades",
+    params(PaginationParams, CursorParams, TradeFilter),
+    responses(
+        (status = 200, description = "Page of trades", body = PaginatedResponse<TradeResponse>),
+        (status = 400, description = "Invalid cursor or offset too deep", body = ErrorResponse),
+        (status = 500, description = "Repository failure", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn list_trades(
+    State(state): State<Arc<AppState>>,
+    Query(pagination): Query<PaginationParams>,
+    Query(cursor): Query<CursorParams>,
+    Query(filter): Query<TradeFilter>,
+) -> Result<Json<PaginatedResponse<TradeResponse>>, ApiError> {
+    info!("Listing trades with filter: {:?}", filter);
+
+    if cursor.is_requested() {
+        let after = cursor.decode()?;
+        let limit = cursor.page_limit();
+        let mut trades = state
+            .trade_repository
+            .list_after(after.as_ref(), limit.saturating_add(1), &filter)
+            .await
+            .map_err(|e| {
+                error!("Failed to list trades: {}", e);
+                internal_error(&e)
+            })?;
+
+        let next_cursor = if trades.len() > limit {
+            trades.truncate(limit);
+            trades.last().map(PageCursor::from_trade)
+        } else {
+            None
+        };
+
+        return Ok(Json(PaginatedResponse::with_cursor(
+            trades.iter().map(TradeResponse::from).collect(),
+            next_cursor,
+        )));
+    }
+
+    pagination.validate()?;
+
+    let trades = state
+        .trade_repository
+        .find_all(&filter)
+        .await
+        .map_err(|e| {
+            error!("Failed to list trades: {}", e);
+            internal_error(&e)
+        })?;
+
+    let total_items = trades.len() as u64;
+    let offset = pagination.offset() as usize;
+    let limit = pagination.limit() as usize;
+
+    let page_data: Vec<TradeResponse> = trades
+        .iter()
+        .skip(offset)
+        .take(limit)
+        .map(TradeResponse::from)
+        .collect();
+
+    Ok(Json(PaginatedResponse::with_offset(
+        page_data,
+        PaginationMeta::new(pagination.page, pagination.limit(), total_items),
+    )))
+}
+
+/// Export trades as CSV or Parquet.
+///
+/// Streams every trade matching the filter created within `[from, to]`,
+/// newest first, with the columns listed in [`trade_export::COLUMNS`].
+/// The body is sent in chunks as pages are read from the repository.
+///
+/// # Errors
+///
+/// Returns `VALIDATION_ERROR` if `from` or `to` is not RFC 3339 or `from`
+/// is after `to`.
+/// Returns `LIMIT_EXCEEDED` if more than [`trade_export::MAX_EXPORT_ROWS`]
+/// trades match.
+/// Returns `NOT_IMPLEMENTED` for Parquet when built without the `parquet`
+/// feature.
+#[utoipa::path(
+    get,
+    path = "/api/v1/trades/export",
+    tag = "trades",
+    params(TradeFilter, TradeExportParams),
+    responses(
+        (status = 200, description = "Trades as CSV", content_type = "text/csv"),
+        (status = 200, description = "Trades as Parquet", content_type = "application/vnd.apache.parquet"),
+        (status = 400, description = "Invalid time range", body = ErrorResponse),
+        (status = 422, description = "Too many matching trades", body = ErrorResponse),
+        (status = 500, description = "Repository failure", body = ErrorResponse),
+        (status = 501, description = "Format not enabled in this build", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn export_trades(
+    State(state): State<Arc<AppState>>,
+    Query(filter): Query<TradeFilter>,
+    Query(params): Query<TradeExportParams>,
+) -> Result<Response, ApiError> {
+    info!("Exporting trades with filter: {:?}", filter);
+
+    let from = parse_filter_timestamp("from", params.from.as_deref())?;
+    let to = parse_filter_timestamp("to", params.to.as_deref())?;
+    if let (Some(from), Some(to)) = (from, to)
+        && from > to
+    {
+        return Err(api_error_with_details(
+            ErrorCode::ValidationError,
+            format!("from {from} is after to {to}"),
+            Some(serde_json::json!({
+                "field": "from",
+                "from": params.from,
+                "to": params.to,
+            })),
+        ));
+    }
+    if !params.format.is_supported() {
+        return Err(not_implemented(
+            "Parquet export requires the parquet feature",
+        ));
+    }
+
+    let matched = state
+        .trade_repository
+        .count(&filter, from, to)
+        .await
+        .map_err(|e| {
+            error!("Failed to count trades: {}", e);
+            internal_error(&e)
+        })?;
+    if matched > trade_export::MAX_EXPORT_ROWS {
+        return Err(api_error_with_details(
+            ErrorCode::LimitExceeded,
+            format!(
+                "Export matches {matched} trades, above the limit of {}; narrow the from/to range",
+                trade_export::MAX_EXPORT_ROWS
+            ),
+            Some(serde_json::json!({
+                "matched": matched,
+                "limit": trade_export::MAX_EXPORT_ROWS,
+            })),
+        ));
+    }
+
+    let format = params.format;
+    let body = Body::from_stream(trade_export::export_stream(
+        Arc::clone(&state.trade_repository),
+        filter,
+        from,
+        to,
+        format,
+    ));
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"trades.{}\"", format.extension()),
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+/// Get trade by ID.
+///
+/// # Errors
+///
+/// Returns `NOT_FOUND` if the trade does not exist.
+/// Returns `VALIDATION_ERROR` if the ID is not a valid UUID.
+#[utoipa::path(
+    get,
+    path = "/api/v1/trades/{id}",
+    tag = "trades",
+    params(("id" = String, Path, description = "Trade ID (UUID)")),
+    responses(
+        (status = 200, description = "Trade found", body = TradeResponse),
+        (status = 400, description = "Invalid trade ID", body = ErrorResponse),
+        (status = 404, description = "Trade not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn get_trade(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<TradeResponse>, ApiError> {
+    info!("Getting trade: {}", id);
+
+    let trade_id = parse_trade_id(&id)?;
+
+    let trade = state
+        .trade_repository
+        .find_by_id(trade_id)
+        .await
+        .map_err(|e| {
+            error!("Failed to find trade: {}", e);
+            internal_error(&e)
+        })?
+        .ok_or_else(|| not_found("Trade", &id))?;
+
+    Ok(Json(TradeResponse::from(&trade)))
+}
+
+/// List the allocations of a trade.
+///
+/// # Errors
+///
+/// Returns `NOT_FOUND` if the trade does not exist.
+/// Returns `VALIDATION_ERROR` if the ID is not a valid UUID or the offset
+/// is too deep.
+#[utoipa::path(
+    get,
+    path = "/api/v1/trades/{id}/allocations",
+    tag = "trades",
+    params(("id" = String, Path, description = "Trade ID (UUID)"), PaginationParams),
+    responses(
+        (status = 200, description = "Page of allocations in fill order", body = PaginatedResponse<TradeAllocationResponse>),
+        (status = 400, description = "Invalid trade ID or offset too deep", body = ErrorResponse),
+        (status = 404, description = "Trade not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn list_trade_allocations(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(pagination): Query<PaginationParams>,
+) -> Result<Json<PaginatedResponse<TradeAllocationResponse>>, ApiError> {
+    info!("Listing allocations of trade: {}", id);
+
+    let trade_id = parse_trade_id(&id)?;
+    pagination.validate()?;
+
+    let trade = state
+        .trade_repository
+        .find_by_id(trade_id)
+        .await
+        .map_err(|e| {
+            error!("Failed to find trade: {}", e);
+            internal_error(&e)
+        })?
+        .ok_or_else(|| not_found("Trade", &id))?;
+
+    let page_data: Vec<TradeAllocationResponse> = trade
+        .allocations()
+        .iter()
+        .skip(pagination.offset() as usize)
+        .take(pagination.limit() as usize)
+        .map(TradeAllocationResponse::from)
+        .collect();
+
+    Ok(Json(PaginatedResponse::with_offset(
+        page_data,
+        PaginationMeta::new(
+            pagination.page,
+            pagination.limit(),
+            trade.allocations().len() as u64,
+        ),
+    )))
+}
+
+// ============================================================================
+// MM Performance DTOs
+// ============================================================================
+
+/// MM performance metrics response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MmPerformanceResponse {
+    /// Market maker identifier.
+    pub mm_id: String,
+    /// Response rate percentage (0-100), or null if no RFQs sent.
+    pub response_rate_pct: Option<f64>,
+    /// Average response time in milliseconds, or null if no quotes received.
+    pub avg_response_time_ms: Option<f64>,
+    /// Quote-to-trade conversion percentage (0-100), or null if no quotes provided.
+    pub quote_to_trade_pct: Option<f64>,
+    /// Average rank across all quotes (lower = more competitive), or null if no quotes.
+    pub competitiveness_score: Option<f64>,
+    /// Reject rate percentage (0-100), or null if no accepts requested.
+    pub reject_rate_pct: Option<f64>,
+    /// Total RFQs sent to this MM in the window.
+    pub total_rfqs_received: u64,
+    /// Total quotes provided by this MM in the window.
+    pub total_quotes_provided: u64,
+    /// Total trades executed from this MM's quotes in the window.
+    pub total_trades_executed: u64,
+    /// Start of the rolling window (ISO 8601).
+    pub window_start: String,
+    /// End of the rolling window (ISO 8601).
+    pub window_end: String,
+}
+
+impl From<&MmPerformanceMetrics> for MmPerformanceResponse {
+    fn from(m: &MmPerformanceMetrics) -> Self {
+        Self {
+            mm_id: m.mm_id().to_string(),
+            response_rate_pct: m.response_rate_pct(),
+            avg_response_time_ms: m.avg_response_time_ms(),
+            quote_to_trade_pct: m.quote_to_trade_pct(),
+            competitiveness_score: m.competitiveness_score(),
+            reject_rate_pct: m.reject_rate_pct(),
+            total_rfqs_received: m.total_rfqs_received(),
+            total_quotes_provided: m.total_quotes_provided(),
+            total_trades_executed: m.total_trades_executed(),
+            window_start: m.window_start().to_iso8601(),
+            window_end: m.window_end().to_iso8601(),
+        }
+    }
+}
+
+/// Query parameters for MM performance listing.
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct MmPerformanceFilter {
+    /// Minimum response rate percentage for filtering eligible MMs.
+    pub min_response_rate: Option<f64>,
+}
+
+// ============================================================================
+// MM Performance Handlers
+// ============================================================================
+
+/// List MM performance metrics for all tracked market makers.
+///
+/// Optionally filter by minimum response rate via `?min_response_rate=80.0`.
+///
+/// # Errors
+///
+/// Returns `NOT_IMPLEMENTED` if the tracker is not configured.
+/// Returns `INTERNAL_ERROR` if computation fails.
+#[utoipa::path(
+    get,
+    path = "/api/v1/mm-performance",
+    tag = "mm",
+    params(MmPerformanceFilter),
+    responses(
+        (status = 200, description = "MM performance metrics", body = Vec<MmPerformanceResponse>),
+        (status = 500, description = "Computation failure", body = ErrorResponse),
+        (status = 501, description = "Tracker not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn list_mm_performance(
+    State(state): State<Arc<AppState>>,
+    Query(filter): Query<MmPerformanceFilter>,
+) -> Result<Json<Vec<MmPerformanceResponse>>, ApiError> {
+    info!("Listing MM performance metrics");
+
+    let tracker = state
+        .mm_performance_tracker
+        .as_ref()
+        .ok_or_else(|| not_implemented("mm performance tracker not configured"))?;
+
+    let all_metrics = tracker.get_all_metrics().await.map_err(|e| {
+        error!("Failed to get MM metrics: {}", e);
+        internal_error(&e.to_string())
+    })?;
+
+    let responses: Vec<MmPerformanceResponse> = all_metrics
+        .iter()
+        .filter(|m| {
+            filter
+                .min_response_rate
+                .is_none_or(|min| m.response_rate_pct().is_some_and(|rate| rate >= min))
+        })
+        .map(MmPerformanceResponse::from)
+        .collect();
+
+    Ok(Json(responses))
+}
+
+/// Get performance metrics for a specific market maker.
+///
+/// # Errors
+///
+/// Returns `NOT_IMPLEMENTED` if the tracker is not configured.
+/// Returns `INTERNAL_ERROR` if computation fails.
+#[utoipa::path(
+    get,
+    path = "/api/v1/mm-performance/{mm_id}",
+    tag = "mm",
+    params(("mm_id" = String, Path, description = "Market maker ID")),
+    responses(
+        (status = 200, description = "MM performance metrics", body = MmPerformanceResponse),
+        (status = 400, description = "Invalid MM ID", body = ErrorResponse),
+        (status = 404, description = "No data for MM", body = ErrorResponse),
+        (status = 501, description = "Tracker not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn get_mm_performance(
+    State(state): State<Arc<AppState>>,
+    Path(mm_id): Path<String>,
+) -> Result<Json<MmPerformanceResponse>, ApiError> {
+    info!("Getting MM performance for: {}", mm_id);
+
+    if mm_id.is_empty() {
+        return Err(validation_error("mm_id cannot be empty"));
+    }
+
+    let tracker = state
+        .mm_performance_tracker
+        .as_ref()
+        .ok_or_else(|| not_implemented("mm performance tracker not configured"))?;
+
+    let counterparty_id = CounterpartyId::new(&mm_id);
+
+    let metrics = tracker.get_metrics(&counterparty_id).await.map_err(|e| {
+        error!("Failed to get MM metrics for {}: {}", mm_id, e);
+        internal_error(&e.to_string())
+    })?;
+
+    if !metrics.has_data() {
+        return Err(not_found("market maker", &mm_id));
+    }
+
+    Ok(Json(MmPerformanceResponse::from(&metrics)))
+}
+
+// ============================================================================
+// Negotiation Analytics DTOs
+// ============================================================================
+
+/// Default reporting window when `from` is omitted, in seconds (30 days).
+const DEFAULT_ANALYTICS_WINDOW_SECS: i64 = 30 * 24 * 60 * 60;
+
+/// Query parameters for negotiation analytics.
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct NegotiationAnalyticsFilter {
+    /// Only negotiations with this market maker.
+    pub mm_id: Option<String>,
+    /// Only negotiations completed at or after this time (RFC 3339).
+    /// Defaults to 30 days before `to`.
+    pub from: Option<String>,
+    /// Only negotiations completed at or before this time (RFC 3339).
+    /// Defaults to now.
+    pub to: Option<String>,
+}
+
+/// Negotiation analytics response DTO.
+///
+/// Decimal values are strings to avoid floating point rounding. Rejected
+/// and expired negotiations count toward the failure rate only.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct NegotiationAnalyticsResponse {
+    /// Market maker filter, if any.
+    pub mm_id: Option<String>,
+    /// Start of the reporting window (ISO 8601).
+    pub from: String,
+    /// End of the reporting window (ISO 8601).
+    pub to: String,
+    /// Completed negotiations in the window.
+    pub total: usize,
+    /// Negotiations that ended in agreement.
+    pub accepted: usize,
+    /// Negotiations rejected by either party.
+    pub rejected: usize,
+    /// Negotiations that expired without agreement.
+    pub expired: usize,
+    /// Share of negotiations rejected or expired (0–1), or null if none.
+    pub failure_rate: Option<String>,
+    /// Mean price improvement of accepted negotiations, in basis points.
+    pub avg_improvement_bps: Option<String>,
+    /// Median price improvement of accepted negotiations, in basis points.
+    pub p50_improvement_bps: Option<String>,
+    /// 90th percentile price improvement of accepted negotiations, in basis points.
+    pub p90_improvement_bps: Option<String>,
+    /// Mean rounds used by accepted negotiations.
+    pub avg_rounds_used: Option<String>,
+    /// Mean milliseconds from creation to acceptance.
+    pub avg_time_to_agreement_ms: Option<i64>,
+}
+
+impl NegotiationAnalyticsResponse {
+    fn new(
+        mm_id: Option<String>,
+        from: Timestamp,
+        to: Timestamp,
+        report: &NegotiationAnalyticsReport,
+    ) -> Self {
+        let rounded =
+            |value: Option<Decimal>, dp| value.map(|v| v.round_dp(dp).normalize().to_string());
+        Self {
+            mm_id,
+            from: from.to_iso8601(),
+            to: to.to_iso8601(),
+            total: report.total,
+            accepted: report.accepted,
+            rejected: report.rejected,
+            expired: report.expired,
+            failure_rate: rounded(report.failure_rate, 4),
+            avg_improvement_bps: rounded(report.avg_improvement_bps, 2),
+            p50_improvement_bps: rounded(report.p50_improvement_bps, 2),
+            p90_improvement_bps: rounded(report.p90_improvement_bps, 2),
+            avg_rounds_used: rounded(report.avg_rounds_used, 2),
+            avg_time_to_agreement_ms: report.avg_time_to_agreement_ms,
+        }
+    }
+}
+
+// ============================================================================
+// Negotiation Analytics Handlers
+// ============================================================================
+
+/// Get price improvement analytics for negotiations completed in a window.
+///
+/// # Errors
+///
+/// Returns `VALIDATION_ERROR` if a timestamp is invalid or `from` is after `to`.
+/// Returns `NOT_IMPLEMENTED` if negotiations are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/negotiations/analytics",
+    tag = "negotiations",
+    params(NegotiationAnalyticsFilter),
+    responses(
+        (status = 200, description = "Negotiation analytics", body = NegotiationAnalyticsResponse),
+        (status = 400, description = "Invalid filter", body = ErrorResponse),
+        (status = 501, description = "Negotiations not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn get_negotiation_analytics(
+    State(state): State<Arc<AppState>>,
+    Query(filter): Query<NegotiationAnalyticsFilter>,
+) -> Result<Json<NegotiationAnalyticsResponse>, ApiError> {
+    let repository = state
+        .negotiations
+        .as_ref()
+        .ok_or_else(|| not_implemented("negotiations not configured"))?;
+
+    let to = parse_filter_timestamp("to", filter.to.as_deref())?.unwrap_or_else(Timestamp::now);
+    let from = parse_filter_timestamp("from", filter.from.as_deref())?
+        .unwrap_or_else(|| to.sub_secs(DEFAULT_ANALYTICS_WINDOW_SECS));
+    if from > to {
+        return Err(api_error_with_details(
+            ErrorCode::ValidationError,
+            format!("from {from} is after to {to}"),
+            Some(serde_json::json!({
+                "field": "from",
+                "from": filter.from,
+                "to": filter.to,
+            })),
+        ));
+    }
+
+    let mm_id = filter.mm_id.as_deref().map(CounterpartyId::new);
+    let negotiations = repository
+        .find_completed_between(mm_id.as_ref(), from, to)
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    let report = NegotiationAnalytics::report(&negotiations);
+    Ok(Json(NegotiationAnalyticsResponse::new(
+        filter.mm_id,
+        from,
+        to,
+        &report,
+    )))
+}
+
+// ============================================================================
+// Negotiation DTOs
+// ============================================================================
+
+/// Terms attached to a submitted counter-quote.
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+pub struct CounterConditionsRequest {
+    /// Seconds the counter stays firm. May only shorten the time left in
+    /// the negotiation.
+    #[serde(default)]
+    pub valid_for_secs: Option<u64>,
+    /// Chain to settle on (e.g. `ARBITRUM`); must be in the token registry.
+    #[serde(default)]
+    pub settlement_chain: Option<String>,
+    /// Window to settle within.
+    #[serde(default)]
+    pub settlement_window: Option<SettlementWindow>,
+    /// Free-text note to the other party.
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+impl CounterConditionsRequest {
+    fn to_conditions(&self) -> Result<CounterConditions, ApiError> {
+        Ok(CounterConditions {
+            valid_for_secs: self.valid_for_secs,
+            settlement_chain: self
+                .settlement_chain
+                .as_deref()
+                .map(parse_blockchain)
+                .transpose()?,
+            settlement_window: self.settlement_window,
+            note: self.note.clone(),
+        })
+    }
+}
+
+/// Request to counter in a negotiation.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct SubmitCounterRequest {
+    /// Quote being countered (UUID).
+    pub quote_id: String,
+    /// Proposed price, as a decimal string.
+    #[serde(with = "decimal")]
+    #[schema(value_type = String, example = "50000.5")]
+    pub price: Decimal,
+    /// Proposed quantity, as a decimal string.
+    #[serde(with = "decimal")]
+    #[schema(value_type = String, example = "1.5")]
+    pub quantity: Decimal,
+    /// Seconds from now the counter stands.
+    pub expiry_seconds: u64,
+    /// Validity and settlement terms the counter is conditional on.
+    #[serde(default)]
+    pub conditions: Option<CounterConditionsRequest>,
+}
+
+/// Terms attached to a counter-quote.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CounterConditionsResponse {
+    /// Seconds the counter was firm for after submission.
+    pub valid_for_secs: Option<u64>,
+    /// Chain to settle on.
+    pub settlement_chain: Option<String>,
+    /// Window to settle within.
+    pub settlement_window: Option<SettlementWindow>,
+    /// Free-text note to the other party.
+    pub note: Option<String>,
+}
+
+impl From<&CounterConditions> for CounterConditionsResponse {
+    fn from(conditions: &CounterConditions) -> Self {
+        Self {
+            valid_for_secs: conditions.valid_for_secs,
+            settlement_chain: conditions.settlement_chain.map(|chain| chain.to_string()),
+            settlement_window: conditions.settlement_window,
+            note: conditions.note.clone(),
+        }
+    }
+}
+
+/// One round of a negotiation.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct NegotiationRoundResponse {
+    /// Round number, starting at 1.
+    pub round: u8,
+    /// Counter-quote ID.
+    pub counter_quote_id: String,
+    /// Quote being countered.
+    pub quote_id: String,
+    /// Counterparty that submitted the counter.
+    pub from_account: String,
+    /// Proposed price.
+    pub price: String,
+    /// Proposed quantity.
+    pub quantity: String,
+    /// When the counter lapses, after any firm validity (ISO 8601).
+    pub valid_until: String,
+    /// When the counter was submitted (ISO 8601).
+    pub submitted_at: String,
+    /// Whether the counter was accepted; null while pending.
+    pub accepted: Option<bool>,
+    /// Terms the counter is conditional on.
+    pub conditions: Option<CounterConditionsResponse>,
+}
+
+impl From<&NegotiationRound> for NegotiationRoundResponse {
+    fn from(round: &NegotiationRound) -> Self {
+        let counter = round.counter_quote();
+        Self {
+            round: round.round_number(),
+            counter_quote_id: counter.id().to_string(),
+            quote_id: counter.original_quote_id().to_string(),
+            from_account: counter.from_account().to_string(),
+            price: counter.price().to_string(),
+            quantity: counter.quantity().to_string(),
+            valid_until: counter.valid_until().to_iso8601(),
+            submitted_at: counter.created_at().to_iso8601(),
+            accepted: round.accepted(),
+            conditions: counter.conditions().map(CounterConditionsResponse::from),
+        }
+    }
+}
+
+/// Negotiation response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct NegotiationResponse {
+    /// Negotiation ID.
+    pub id: String,
+    /// RFQ under negotiation.
+    pub rfq_id: String,
+    /// Client requesting the quote.
+    pub requester: String,
+    /// Market maker providing the quote.
+    pub mm_account: String,
+    /// Order side; sets the direction prices must improve in.
+    pub side: OrderSide,
+    /// Current state (e.g. `COUNTER_PENDING`).
+    pub state: String,
+    /// Maximum rounds allowed.
+    pub max_rounds: u8,
+    /// Rounds exchanged, oldest first.
+    pub rounds: Vec<NegotiationRoundResponse>,
+    /// When the negotiation was opened (ISO 8601).
+    pub created_at: String,
+    /// When the negotiation last changed (ISO 8601).
+    pub updated_at: String,
+}
+
+impl From<&Negotiation> for NegotiationResponse {
+    fn from(negotiation: &Negotiation) -> Self {
+        Self {
+            id: negotiation.id().to_string(),
+            rfq_id: negotiation.rfq_id().to_string(),
+            requester: negotiation.requester().to_string(),
+            mm_account: negotiation.mm_account().to_string(),
+            side: negotiation.side(),
+            state: negotiation.state().to_string(),
+            max_rounds: negotiation.max_rounds(),
+            rounds: negotiation
+                .rounds()
+                .iter()
+                .map(NegotiationRoundResponse::from)
+                .collect(),
+            created_at: negotiation.created_at().to_iso8601(),
+            updated_at: negotiation.updated_at().to_iso8601(),
+        }
+    }
+}
+
+// ============================================================================
+// Negotiation Handlers
+// ============================================================================
+
+/// Get a negotiation with its rounds.
+///
+/// # Errors
+///
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `VALIDATION_ERROR` if the ID is not a valid UUID.
+/// Returns `UNAUTHORIZED_COUNTERPARTY` if the caller is not a participant.
+/// Returns `NOT_FOUND` if the negotiation does not exist.
+/// Returns `NOT_IMPLEMENTED` if negotiations are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/negotiations/{id}",
+    tag = "negotiations",
+    params(("id" = String, Path, description = "Negotiation ID (UUID)")),
+    responses(
+        (status = 200, description = "Negotiation", body = NegotiationResponse),
+        (status = 400, description = "Invalid negotiation ID", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not a participant", body = ErrorResponse),
+        (status = 404, description = "Negotiation not found", body = ErrorResponse),
+        (status = 501, description = "Negotiations not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, id), fields(negotiation_id = %id))]
+pub async fn get_negotiation(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<Json<NegotiationResponse>, ApiError> {
+    let negotiation = find_participant_negotiation(&state, &user, &id).await?;
+    Ok(Json(NegotiationResponse::from(&negotiation)))
+}
+
+/// Submit a counter-quote in a negotiation.
+///
+/// The caller counters as the counterparty it acts for. Conditions may make
+/// the counter firm for less time than remains in the negotiation and name
+/// the chain and window it settles in; an accepted counter's settlement
+/// terms are recorded on the resulting trade.
+///
+/// # Errors
+///
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `VALIDATION_ERROR` if the request is invalid, the settlement
+/// chain is not in the token registry, or the firm validity exceeds the
+/// time remaining.
+/// Returns `UNAUTHORIZED_COUNTERPARTY` if the caller is not a participant.
+/// Returns `NOT_FOUND` if the negotiation does not exist.
+/// Returns `NEGOTIATION_INVALID_STATE`, `MAX_NEGOTIATION_ROUNDS` or
+/// `NO_PRICE_IMPROVEMENT` if the negotiation does not accept the counter.
+/// Returns `NOT_IMPLEMENTED` if negotiations are not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/negotiations/{id}/counters",
+    tag = "negotiations",
+    params(("id" = String, Path, description = "Negotiation ID (UUID)")),
+    request_body = SubmitCounterRequest,
+    responses(
+        (status = 201, description = "Counter submitted", body = NegotiationResponse),
+        (status = 400, description = "Invalid counter", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not a participant", body = ErrorResponse),
+        (status = 404, description = "Negotiation not found", body = ErrorResponse),
+        (status = 422, description = "Counter not accepted by the negotiation", body = ErrorResponse),
+        (status = 501, description = "Negotiations not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, id, request), fields(negotiation_id = %id))]
+pub async fn submit_counter(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+    ApiJson(request): ApiJson<SubmitCounterRequest>,
+) -> Result<(StatusCode, Json<NegotiationResponse>), ApiError> {
+    let mut negotiation = find_participant_negotiation(&state, &user, &id).await?;
+
+    let quote_id = parse_quote_id(&request.quote_id)?;
+    let price = Price::from_decimal(request.price)
+        .map_err(|_| validation_error(&format!("invalid price: {}", request.price)))?;
+    let quantity = Quantity::from_decimal(request.quantity)
+        .map_err(|_| validation_error(&format!("invalid quantity: {}", request.quantity)))?;
+    let conditions = request
+        .conditions
+        .as_ref()
+        .map(CounterConditionsRequest::to_conditions)
+        .transpose()?;
+    if let Some(chain) = conditions.as_ref().and_then(|c| c.settlement_chain) {
+        check_settlement_chain(&state, chain)?;
+    }
+    let round = negotiation
+        .round_count()
+        .checked_add(1)
+        .and_then(|n| u8::try_from(n).ok())
+        .ok_or_else(|| validation_error("round number overflow"))?;
+
+    let now = Timestamp::now();
+    let expiry_seconds = i64::try_from(request.expiry_seconds)
+        .map_err(|_| validation_error("expiry_seconds is too large"))?;
+    let mut builder = CounterQuoteBuilder::new(
+        quote_id,
+        negotiation.rfq_id(),
+        requesting_counterparty(&user),
+        price,
+        quantity,
+        now.add_secs(expiry_seconds),
+        round,
+    );
+    if let Some(conditions) = conditions {
+        builder = builder.with_conditions(conditions);
+    }
+    let counter = builder.build().map_err(|e| from_domain_error(&e))?;
+    negotiation
+        .submit_counter_at(counter, now)
+        .map_err(|e| from_domain_error(&e))?;
+
+    negotiations_repository(&state)?
+        .save(&negotiation)
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    info!(
+        "Counter round {} submitted in negotiation {} by {}",
+        round,
+        id,
+        requesting_counterparty(&user)
+    );
+
+    Ok((
+        StatusCode::CREATED,
+        Json(NegotiationResponse::from(&negotiation)),
+    ))
+}
+
+/// Returns the negotiation repository, or `NOT_IMPLEMENTED` if unset.
+fn negotiations_repository(state: &AppState) -> Result<&Arc<dyn NegotiationRepository>, ApiError> {
+    state
+        .negotiations
+        .as_ref()
+        .ok_or_else(|| not_implemented("negotiations not configured"))
+}
+
+/// Loads a negotiation and checks that the caller takes part in it.
+///
+/// Admins may read any negotiation.
+async fn find_participant_negotiation(
+    state: &AppState,
+    user: &Claims,
+    id: &str,
+) -> Result<Negotiation, ApiError> {
+    let repository = negotiations_repository(state)?;
+    let negotiation_id = uuid::Uuid::parse_str(id)
+        .map(NegotiationId::new)
+        .map_err(|_| validation_error(&format!("invalid negotiation ID: {id}")))?;
+    let negotiation = repository
+        .find_by_id(negotiation_id)
+        .await
+        .map_err(|e| from_repository_error(&e))?
+        .ok_or_else(|| not_found("Negotiation", id))?;
+
+    let caller = requesting_counterparty(user);
+    if !user.has_role("admin")
+        && caller != *negotiation.requester()
+        && caller != *negotiation.mm_account()
+    {
+        warn!("Denied negotiation {} to {}", id, user.sub);
+        return Err(api_error(
+            ErrorCode::UnauthorizedCounterparty,
+            "negotiation belongs to other counterparties",
+        ));
+    }
+    Ok(negotiation)
+}
+
+/// Checks that the token registry settles in at least one token on `chain`.
+///
+/// Passes when no token registry is configured.
+fn check_settlement_chain(state: &AppState, chain: Blockchain) -> Result<(), ApiError> {
+    let Some(tokens) = &state.tokens else {
+        return Ok(());
+    };
+    let settles = tokens
+        .read()
+        .tokens_on_chain(chain.into())
+        .iter()
+        .any(|token| token.enabled_for_settlement);
+    if !settles {
+        return Err(api_error_with_details(
+            ErrorCode::ValidationError,
+            format!("chain {chain} is not in the token registry"),
+            Some(serde_json::json!({
+                "field": "conditions.settlement_chain",
+                "chain": chain.to_string(),
+            })),
+        ));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Venue Exchange DTOs
+// ============================================================================
+
+/// A raw request or response exchanged with a venue, secrets redacted.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VenueExchangeResponse {
+    /// Venue the payload was exchanged with.
+    pub venue_id: String,
+    /// Direction: REQUEST (sent to the venue) or RESPONSE (received).
+    pub direction: String,
+    /// Raw payload; non-JSON response bodies are returned as a string.
+    #[schema(value_type = Object)]
+    pub payload: serde_json::Value,
+    /// HTTP status of a response, if one was received.
+    pub http_status: Option<u16>,
+    /// Round-trip latency of a response in milliseconds.
+    pub latency_ms: Option<u64>,
+    /// When the payload was sent or received.
+    pub recorded_at: String,
+    /// When the record will be purged.
+    pub expires_at: String,
+}
+
+impl From<RawExchange> for VenueExchangeResponse {
+    fn from(exchange: RawExchange) -> Self {
+        Self {
+            venue_id: exchange.venue_id.to_string(),
+            direction: exchange.direction.to_string(),
+            payload: exchange.payload,
+            http_status: exchange.http_status,
+            latency_ms: exchange.latency_ms,
+            recorded_at: exchange.recorded_at.to_string(),
+            expires_at: exchange.expires_at.to_string(),
+        }
+    }
+}
+
+// ============================================================================
+// Venue Exchange Handlers
+// ============================================================================
+
+/// List the raw venue exchanges recorded for an RFQ, oldest first.
+///
+/// Admin only. Used to resolve disputes over whether a market maker was
+/// shown an RFQ or how its quote was parsed.
+///
+/// # Errors
+///
+/// Returns `VALIDATION_ERROR` if the ID is not a valid UUID.
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if raw exchange logging is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/rfqs/{id}/venue-exchanges",
+    tag = "rfqs",
+    params(("id" = String, Path, description = "RFQ ID (UUID)")),
+    responses(
+        (status = 200, description = "Recorded venue exchanges, oldest first", body = [VenueExchangeResponse]),
+        (status = 400, description = "Invalid RFQ ID", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 501, description = "Raw exchange logging not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, id), fields(rfq_id = %id))]
+pub async fn list_rfq_venue_exchanges(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<VenueExchangeResponse>>, ApiError> {
+    if require_role(&user, "admin").is_err() {
+        warn!("Denied venue exchanges of RFQ {} to {}", id, user.sub);
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+
+    let rfq_id = parse_rfq_id(&id)?;
+    let log = state
+        .venue_exchanges
+        .as_ref()
+        .ok_or_else(|| not_implemented("raw exchange logging not configured"))?;
+
+    let exchanges = log
+        .find_by_rfq(rfq_id)
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    Ok(Json(
+        exchanges
+            .into_iter()
+            .map(VenueExchangeResponse::from)
+            .collect(),
+    ))
+}
+
+// ============================================================================
+// MM Incentive Status DTOs
+// ============================================================================
+
+/// Penalty status summary for API response.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PenaltyStatusResponse {
+    /// Whether any penalty applies.
+    pub has_penalty: bool,
+    /// Whether capacity should be reduced.
+    pub capacity_reduced: bool,
+    /// Whether tier should be downgraded.
+    pub tier_downgraded: bool,
+    /// Reason for penalty, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// MM incentive status response DTO.
+///
+/// Returns the complete incentive status for a market maker including
+/// tier, rebates, volume metrics, and penalty status.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MmIncentiveStatusResponse {
+    /// Market maker identifier.
+    pub mm_id: String,
+    /// Current incentive tier (BRONZE, SILVER, GOLD, PLATINUM).
+    pub current_tier: String,
+    /// Rebate rate for current tier (basis points, negative = rebate to MM).
+    pub rebate_rate_bps: String,
+    /// Monthly trading volume in USD.
+    pub monthly_volume_usd: String,
+    /// Volume needed to reach next tier (null if Platinum).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume_to_next_tier_usd: Option<String>,
+    /// Next tier name (null if Platinum).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_tier: Option<String>,
+    /// Rebates earned in current period (USD).
+    pub current_period_rebates_usd: String,
+    /// Penalty status.
+    pub penalty_status: PenaltyStatusResponse,
+    /// Timestamp when this status was computed (ISO 8601).
+    pub computed_at: String,
+}
+
+impl MmIncentiveStatusResponse {
+    /// Creates a response from domain `MmIncentiveStatus`.
+    #[must_use]
+    pub fn from_status(status: &crate::domain::entities::mm_incentive::MmIncentiveStatus) -> Self {
+        Self {
+            mm_id: status.mm_id().to_string(),
+            current_tier: status.current_tier().to_string(),
+            rebate_rate_bps: status.rebate_rate_bps().to_string(),
+            monthly_volume_usd: status.monthly_volume_usd().to_string(),
+            volume_to_next_tier_usd: status.volume_to_next_tier_usd().map(|v| v.to_string()),
+            next_tier: status.next_tier().map(|t| t.to_string()),
+            current_period_rebates_usd: status.current_period_rebates_usd().to_string(),
+            penalty_status: PenaltyStatusResponse {
+                has_penalty: status.penalty_result().has_penalty(),
+                capacity_reduced: status.penalty_result().should_reduce_capacity(),
+                tier_downgraded: status.penalty_result().should_downgrade_tier(),
+                reason: status.penalty_result().reason().map(String::from),
+            },
+            computed_at: status.computed_at().to_iso8601(),
+        }
+    }
+}
+
+// ============================================================================
+// MM Incentive Status Handlers
+// ============================================================================
+
+/// Get incentive status for a specific market maker.
+///
+/// Returns the MM's current tier, rebate rate, volume metrics, and penalty status.
+///
+/// # Errors
+///
+/// Returns `NOT_FOUND` if the MM has no recorded data.
+/// Returns `NOT_IMPLEMENTED` if the incentive service is not configured.
+/// Returns `INTERNAL_ERROR` if status computation fails.
+#[utoipa::path(
+    get,
+    path = "/api/v1/mm/{mm_id}/incentive-status",
+    tag = "mm",
+    params(("mm_id" = String, Path, description = "Market maker ID")),
+    responses(
+        (status = 200, description = "MM incentive status", body = MmIncentiveStatusResponse),
+        (status = 400, description = "Invalid MM ID", body = ErrorResponse),
+        (status = 404, description = "No data for MM", body = ErrorResponse),
+        (status = 501, description = "Incentive service not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn get_mm_incentive_status(
+    State(state): State<Arc<AppState>>,
+    Path(mm_id): Path<String>,
+) -> Result<Json<MmIncentiveStatusResponse>, ApiError> {
+    info!("Getting MM incentive status for: {}", mm_id);
+
+    if mm_id.is_empty() {
+        return Err(validation_error("mm_id cannot be empty"));
+    }
+
+    let service = state
+        .mm_incentive_service
+        .as_ref()
+        .ok_or_else(|| not_implemented("mm incentive service not configured"))?;
+
+    let counterparty_id = CounterpartyId::new(&mm_id);
+
+    let status = service
+        .get_status(&counterparty_id)
+        .await
+        .map_err(|e| match &e {
+            MmIncentiveError::MmNotFound(_) => not_found("market maker", &mm_id),
+            MmIncentiveError::Performance(pe) => {
+                error!("Failed to get MM incentive status for {}: {}", mm_id, pe);
+                internal_error(&pe.to_string())
+            }
+        })?;
+
+    Ok(Json(MmIncentiveStatusResponse::from_status(&status)))
+}
+
+// ============================================================================
+// Webhook DTOs
+// ============================================================================
+
+/// Webhook subscription creation request.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CreateWebhookSubscriptionRequest {
+    /// Endpoint deliveries are posted to (http or https).
+    pub url: String,
+    /// Shared secret deliveries are signed with. Never returned.
+    pub secret: String,
+    /// Events to deliver: `TradeExecuted`, `SettlementConfirmed`.
+    pub event_filters: Vec<String>,
+    /// Whether the subscription receives deliveries (default true).
+    #[serde(default)]
+    pub active: Option<bool>,
+}
+
+/// Webhook subscription update request; omitted fields are unchanged.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct UpdateWebhookSubscriptionRequest {
+    /// New endpoint.
+    pub url: Option<String>,
+    /// New signing secret.
+    pub secret: Option<String>,
+    /// New event filters.
+    pub event_filters: Option<Vec<String>>,
+    /// Activates or deactivates the subscription. Activating clears the
+    /// consecutive failure count.
+    pub active: Option<bool>,
+}
+
+/// Webhook subscription response DTO. The secret is never included.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookSubscriptionResponse {
+    /// Subscription ID.
+    pub id: String,
+    /// Endpoint deliveries are posted to.
+    pub url: String,
+    /// Subscribed event names.
+    pub event_filters: Vec<String>,
+    /// Whether the subscription receives deliveries.
+    pub active: bool,
+    /// Failed deliveries since the last success.
+    pub consecutive_failures: u32,
+    /// Created timestamp (ISO 8601).
+    pub created_at: String,
+    /// Updated timestamp (ISO 8601).
+    pub updated_at: String,
+}
+
+impl From<&WebhookSubscription> for WebhookSubscriptionResponse {
+    fn from(subscription: &WebhookSubscription) -> Self {
+        Self {
+            id: subscription.id().to_string(),
+            url: subscription.url().to_string(),
+            event_filters: subscription.event_filters().to_vec(),
+            active: subscription.is_active(),
+            consecutive_failures: subscription.consecutive_failures(),
+            created_at: subscription.created_at().to_string(),
+            updated_at: subscription.updated_at().to_string(),
+        }
+    }
+}
+
+/// A webhook delivery and the outcome of its latest attempt.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookDeliveryResponse {
+    /// Delivery ID.
+    pub id: String,
+    /// Subscription the event was sent to.
+    pub subscription_id: String,
+    /// Delivered event ID.
+    pub event_id: String,
+    /// Delivered event name.
+    pub event_name: String,
+    /// Body posted to the endpoint.
+    #[schema(value_type = Object)]
+    pub payload: serde_json::Value,
+    /// Status: PENDING, SUCCEEDED or FAILED.
+    pub status: String,
+    /// Attempts made so far.
+    pub attempts: u32,
+    /// HTTP status of the last failed attempt, if the endpoint responded.
+    pub response_status: Option<u16>,
+    /// Error from the last failed attempt.
+    pub last_error: Option<String>,
+    /// Delivery this one re-sent, for manual redeliveries.
+    pub redelivery_of: Option<String>,
+    /// Created timestamp (ISO 8601).
+    pub created_at: String,
+    /// Timestamp of the last attempt (ISO 8601).
+    pub updated_at: String,
+}
+
+impl From<&WebhookDelivery> for WebhookDeliveryResponse {
+    fn from(delivery: &WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id.to_string(),
+            subscription_id: delivery.subscription_id.to_string(),
+            event_id: delivery.event_id.to_string(),
+            event_name: delivery.event_name.clone(),
+            payload: delivery.payload.clone(),
+            status: delivery.status.to_string(),
+            attempts: delivery.attempts,
+            response_status: delivery.response_status,
+            last_error: delivery.last_error.clone(),
+            redelivery_of: delivery.redelivery_of.map(|id| id.to_string()),
+            created_at: delivery.created_at.to_string(),
+            updated_at: delivery.updated_at.to_string(),
+        }
+    }
+}
+
+// ============================================================================
+// Webhook Handlers
+// ============================================================================
+
+/// List webhook subscriptions, oldest first.
+///
+/// Admin only.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if webhooks are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/webhooks",
+    tag = "webhooks",
+    responses(
+        (status = 200, description = "Webhook subscriptions", body = [WebhookSubscriptionResponse]),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 501, description = "Webhooks not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn list_webhooks(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+) -> Result<Json<Vec<WebhookSubscriptionResponse>>, ApiError> {
+    let webhooks = webhook_service(&state, &user)?;
+
+    let subscriptions = webhooks
+        .subscriptions()
+        .find_all()
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    Ok(Json(
+        subscriptions
+            .iter()
+            .map(WebhookSubscriptionResponse::from)
+            .collect(),
+    ))
+}
+
+/// Create a webhook subscription.
+///
+/// Admin only. Deliveries are signed with the given secret; see the
+/// `X-OTC-Signature` and `X-OTC-Timestamp` headers.
+///
+/// # Errors
+///
+/// Returns `VALIDATION_ERROR` if the URL, secret or filters are invalid.
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if webhooks are not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/webhooks",
+    tag = "webhooks",
+    request_body = CreateWebhookSubscriptionRequest,
+    responses(
+        (status = 201, description = "Subscription created", body = WebhookSubscriptionResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 501, description = "Webhooks not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn create_webhook(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    ApiJson(request): ApiJson<CreateWebhookSubscriptionRequest>,
+) -> Result<(StatusCode, Json<WebhookSubscriptionResponse>), ApiError> {
+    let webhooks = webhook_service(&state, &user)?;
+    let mut subscription =
+        WebhookSubscription::new(request.url, request.secret, request.event_filters)
+            .map_err(|e| from_domain_error(&e))?;
+    if let Some(active) = request.active {
+        subscription.set_active(active);
+    }
+
+    webhooks
+        .subscriptions()
+        .save(&subscription)
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    info!(
+        "Created webhook subscription {} for {}",
+        subscription.id(),
+        subscription.url()
+    );
+
+    Ok((
+        StatusCode::CREATED,
+        Json(WebhookSubscriptionResponse::from(&subscription)),
+    ))
+}
+
+/// Get a webhook subscription.
+///
+/// Admin only.
+///
+/// # Errors
+///
+/// Returns `VALIDATION_ERROR` if the ID is not a valid UUID.
+/// Returns `NOT_FOUND` if the subscription does not exist.
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if webhooks are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/webhooks/{id}",
+    tag = "webhooks",
+    params(("id" = String, Path, description = "Subscription ID (UUID)")),
+    responses(
+        (status = 200, description = "Subscription", body = WebhookSubscriptionResponse),
+        (status = 400, description = "Invalid subscription ID", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Subscription not found", body = ErrorResponse),
+        (status = 501, description = "Webhooks not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn get_webhook(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<Json<WebhookSubscriptionResponse>, ApiError> {
+    let webhooks = webhook_service(&state, &user)?;
+    let subscription = find_webhook(webhooks, &id).await?;
+
+    Ok(Json(WebhookSubscriptionResponse::from(&subscription)))
+}
+
+/// Update a webhook subscription.
+///
+/// Admin only. Omitted fields are left unchanged; re-activating an
+/// auto-disabled subscription clears its failure count.
+///
+/// # Errors
+///
+/// Returns `VALIDATION_ERROR` if the ID or a new value is invalid.
+/// Returns `NOT_FOUND` if the subscription does not exist.
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if webhooks are not configured.
+#[utoipa::path(
+    put,
+    path = "/api/v1/webhooks/{id}",
+    tag = "webhooks",
+    params(("id" = String, Path, description = "Subscription ID (UUID)")),
+    request_body = UpdateWebhookSubscriptionRequest,
+    responses(
+        (status = 200, description = "Subscription updated", body = WebhookSubscriptionResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Subscription not found", body = ErrorResponse),
+        (status = 501, description = "Webhooks not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn update_webhook(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+    ApiJson(request): ApiJson<UpdateWebhookSubscriptionRequest>,
+) -> Result<Json<WebhookSubscriptionResponse>, ApiError> {
+    let webhooks = webhook_service(&state, &user)?;
+    let mut subscription = find_webhook(webhooks, &id).await?;
+
+    if let Some(url) = request.url {
+        subscription
+            .set_url(url)
+            .map_err(|e| from_domain_error(&e))?;
+    }
+    if let Some(secret) = request.secret {
+        subscription
+            .set_secret(secret)
+            .map_err(|e| from_domain_error(&e))?;
+    }
+    if let Some(event_filters) = request.event_filters {
+        subscription
+            .set_event_filters(event_filters)
+            .map_err(|e| from_domain_error(&e))?;
+    }
+    if let Some(active) = request.active {
+        subscription.set_active(active);
+    }
+
+    webhooks
+        .subscriptions()
+        .save(&subscription)
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    info!("Updated webhook subscription {}", subscription.id());
+
+    Ok(Json(WebhookSubscriptionResponse::from(&subscription)))
+}
+
+/// Delete a webhook subscription and its delivery log.
+///
+/// Admin only.
+///
+/// # Errors
+///
+/// Returns `VALIDATION_ERROR` if the ID is not a valid UUID.
+/// Returns `NOT_FOUND` if the subscription does not exist.
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if webhooks are not configured.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/webhooks/{id}",
+    tag = "webhooks",
+    params(("id" = String, Path, description = "Subscription ID (UUID)")),
+    responses(
+        (status = 204, description = "Subscription deleted"),
+        (status = 400, description = "Invalid subscription ID", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Subscription not found", body = ErrorResponse),
+        (status = 501, description = "Webhooks not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn delete_webhook(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let webhooks = webhook_service(&state, &user)?;
+    let subscription_id = parse_webhook_subscription_id(&id)?;
+
+    let deleted = webhooks
+        .subscriptions()
+        .delete(subscription_id)
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+    if !deleted {
+        return Err(not_found("Webhook subscription", &id));
+    }
+
+    info!("Deleted webhook subscription {}", subscription_id);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List a webhook subscription's deliveries, newest first.
+///
+/// Admin only. Cursor paginated: pass the previous page's `next_cursor`.
+///
+/// # Errors
+///
+/// Returns `VALIDATION_ERROR` if the ID is not a valid UUID.
+/// Returns `INVALID_CURSOR` if the cursor is malformed.
+/// Returns `NOT_FOUND` if the subscription does not exist.
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if webhooks are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/webhooks/{id}/deliveries",
+    tag = "webhooks",
+    params(("id" = String, Path, description = "Subscription ID (UUID)"), CursorParams),
+    responses(
+        (status = 200, description = "Page of deliveries", body = PaginatedResponse<WebhookDeliveryResponse>),
+        (status = 400, description = "Invalid subscription ID or cursor", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Subscription not found", body = ErrorResponse),
+        (status = 501, description = "Webhooks not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn list_webhook_deliveries(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+    Query(cursor): Query<CursorParams>,
+) -> Result<Json<PaginatedResponse<WebhookDeliveryResponse>>, ApiError> {
+    let webhooks = webhook_service(&state, &user)?;
+    let subscription = find_webhook(webhooks, &id).await?;
+    let after = cursor.decode()?;
+    let limit = cursor.page_limit();
+
+    let mut deliveries = webhooks
+        .deliveries()
+        .list_after(subscription.id(), after.as_ref(), limit.saturating_add(1))
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    let next_cursor = if deliveries.len() > limit {
+        deliveries.truncate(limit);
+        deliveries.last().map(WebhookDelivery::cursor)
+    } else {
+        None
+    };
+
+    Ok(Json(PaginatedResponse::with_cursor(
+        deliveries
+            .iter()
+            .map(WebhookDeliveryResponse::from)
+            .collect(),
+        next_cursor,
+    )))
+}
+
+/// Redeliver a recorded webhook delivery.
+///
+/// Admin only. The original payload is re-signed with a fresh timestamp
+/// and sent with the usual retries, even if the subscription is inactive.
+/// The attempt is recorded as a new delivery referencing the original.
+///
+/// # Errors
+///
+/// Returns `VALIDATION_ERROR` if an ID is not a valid UUID.
+/// Returns `NOT_FOUND` if the subscription or delivery does not exist.
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if webhooks are not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/webhooks/{id}/deliveries/{delivery_id}/redeliver",
+    tag = "webhooks",
+    params(
+        ("id" = String, Path, description = "Subscription ID (UUID)"),
+        ("delivery_id" = String, Path, description = "Delivery ID (UUID)"),
+    ),
+    responses(
+        (status = 200, description = "Outcome of the redelivery", body = WebhookDeliveryResponse),
+        (status = 400, description = "Invalid ID", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Subscription or delivery not found", body = ErrorResponse),
+        (status = 501, description = "Webhooks not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn redeliver_webhook(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path((id, delivery_id)): Path<(String, String)>,
+) -> Result<Json<WebhookDeliveryResponse>, ApiError> {
+    let webhooks = webhook_service(&state, &user)?;
+    let subscription_id = parse_webhook_subscription_id(&id)?;
+    let delivery_id = uuid::Uuid::parse_str(&delivery_id)
+        .map(WebhookDeliveryId::from)
+        .map_err(|_| validation_error(&format!("invalid webhook delivery ID: {delivery_id}")))?;
+
+    let delivery = webhooks
+        .redeliver(subscription_id, delivery_id)
+        .await
+        .map_err(|e| from_application_error(&e))?;
+
+    info!(
+        "Redelivered webhook delivery {} as {} ({})",
+        delivery_id, delivery.id, delivery.status
+    );
+
+    Ok(Json(WebhookDeliveryResponse::from(&delivery)))
+}
+
+/// Returns the webhook service if the caller is an admin.
+fn webhook_service<'a>(
+    state: &'a AppState,
+    user: &Claims,
+) -> Result<&'a Arc<WebhookDeliveryService>, ApiError> {
+    if require_role(user, "admin").is_err() {
+        warn!("Denied webhook management to {}", user.sub);
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+    state
+        .webhooks
+        .as_ref()
+        .ok_or_else(|| not_implemented("webhooks not configured"))
+}
+
+async fn find_webhook(
+    webhooks: &WebhookDeliveryService,
+    id: &str,
+) -> Result<WebhookSubscription, ApiError> {
+    let subscription_id = parse_webhook_subscription_id(id)?;
+    webhooks
+        .subscriptions()
+        .find_by_id(subscription_id)
+        .await
+        .map_err(|e| from_repository_error(&e))?
+        .ok_or_else(|| not_found("Webhook subscription", id))
+}
+
+fn parse_webhook_subscription_id(id: &str) -> Result<WebhookSubscriptionId, ApiError> {
+    uuid::Uuid::parse_str(id)
+        .map(WebhookSubscriptionId::from)
+        .map_err(|_| validation_error(&format!("invalid webhook subscription ID: {id}")))
+}
+
+// ============================================================================
+// Compliance Export Handlers
+// ============================================================================
+
+/// Query parameters of the compliance export endpoint.
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ComplianceExportParams {
+    /// Counterparty whose records are exported.
+    pub counterparty_id: String,
+    /// Start of the period, inclusive (RFC 3339).
+    pub from: String,
+    /// End of the period, exclusive (RFC 3339).
+    pub to: String,
+}
+
+/// Export a counterparty's compliance records for a regulator.
+///
+/// Admin only. Returns a ZIP bundle of the counterparty's compliance
+/// events, RFQ timelines, trades with allocations and fees, and price
+/// bounds checks within `[from, to)`, plus a `manifest.json` listing the
+/// SHA-256 of each file. Every export is logged and recorded as a
+/// `ComplianceExportGenerated` event naming the caller.
+///
+/// # Errors
+///
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if `from` or `to` is not RFC 3339 or `from`
+/// is not before `to`.
+/// Returns `NOT_IMPLEMENTED` if compliance export is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/compliance/export",
+    tag = "compliance",
+    params(ComplianceExportParams),
+    responses(
+        (status = 200, description = "Export bundle", content_type = "application/zip"),
+        (status = 400, description = "Invalid period", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 501, description = "Compliance export not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, params))]
+pub async fn export_compliance(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Query(params): Query<ComplianceExportParams>,
+) -> Result<Response, ApiError> {
+    if require_role(&user, "admin").is_err() {
+        warn!(
+            "Denied compliance export of {} to {}",
+            params.counterparty_id, user.sub
+        );
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+
+    let exporter = state
+        .compliance_export
+        .as_ref()
+        .ok_or_else(|| not_implemented("compliance export not configured"))?;
+
+    let from = parse_timestamp("from", &params.from)?;
+    let to = parse_timestamp("to", &params.to)?;
+    let counterparty_id = CounterpartyId::new(&params.counterparty_id);
+    let bundle = exporter
+        .export(&counterparty_id, from, to, &user.sub)
+        .await
+        .map_err(|e| from_application_error(&e))?;
+
+    info!(
+        "Compliance export of {} for [{}, {}) generated for {} (sha256 {})",
+        counterparty_id, from, to, user.sub, bundle.sha256
+    );
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/zip".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", bundle.file_name()),
+            ),
+        ],
+        Body::from(bundle.bytes),
+    )
+        .into_response())
+}
+
+// ============================================================================
+// Best Execution Report Handlers
+// ============================================================================
+
+/// Get a client's best execution report for a period.
+///
+/// Reports on the RFQs the client created within `[from, to)`: venues
+/// contacted, how often the best price was taken, average improvement on
+/// the reference price at execution, and each trade that missed the best
+/// price with the reason. `client_id` defaults to the caller; only admins
+/// may name another client. The report of a period that has ended is
+/// stored on first generation and returned unchanged afterwards.
+///
+/// # Errors
+///
+/// Returns `MISSING_CREDENTIALS` if the request is not authenticated.
+/// Returns `UNAUTHORIZED_COUNTERPARTY` if a non-admin names another client.
+/// Returns `VALIDATION_ERROR` if `from` or `to` is not RFC 3339 or `from`
+/// is not before `to`.
+/// Returns `NOT_IMPLEMENTED` if best execution reports are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/reports/best-execution",
+    tag = "reports",
+    params(BestExecutionParams),
+    responses(
+        (status = 200, description = "Best execution report", body = BestExecutionReportResponse),
+        (status = 200, description = "Best execution report as CSV", content_type = "text/csv"),
+        (status = 400, description = "Invalid period", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Report of another client", body = ErrorResponse),
+        (status = 501, description = "Best execution reports not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, params))]
+pub async fn get_best_execution_report(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Query(params): Query<BestExecutionParams>,
+) -> Result<Response, ApiError> {
+    let caller = requesting_counterparty(&user);
+    let client_id = params
+        .client_id
+        .as_deref()
+        .map_or_else(|| caller.clone(), CounterpartyId::new);
+    if !user.has_role("admin") && client_id != caller {
+        warn!(
+            "Denied best execution report of {} to {}",
+            client_id, user.sub
+        );
+        return Err(api_error(
+            ErrorCode::UnauthorizedCounterparty,
+            "report belongs to another counterparty",
+        ));
+    }
+
+    let service = state
+        .best_execution
+        .as_ref()
+        .ok_or_else(|| not_implemented("best execution reports not configured"))?;
+
+    let from = parse_timestamp("from", &params.from)?;
+    let to = parse_timestamp("to", &params.to)?;
+    let report = service
+        .report(&client_id, from, to)
+        .await
+        .map_err(|e| from_application_error(&e))?;
+
+    Ok(match params.format {
+        BestExecutionFormat::Json => {
+            Json(BestExecutionReportResponse::from(&report)).into_response()
+        }
+        BestExecutionFormat::Csv => (
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!(
+                        "attachment; filename=\"best-execution-{}-{}-{}.csv\"",
+                        client_id,
+                        from.timestamp_millis(),
+                        to.timestamp_millis()
+                    ),
+                ),
+            ],
+            crate::api::rest::best_execution::to_csv(&report),
+        )
+            .into_response(),
+    })
+}
+
+// ============================================================================
+// Health Check
+// ============================================================================
+
+/// Health report.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct HealthResponse {
+    /// Overall status: `pass`, `warn`, or `fail`.
+    pub status: String,
+    /// Service version.
+    pub version: String,
+    /// Per-dependency results keyed by dependency name.
+    pub checks: BTreeMap<String, DependencyHealthResponse>,
+}
+
+impl HealthResponse {
+    fn live() -> Self {
+        Self {
+            status: CheckStatus::Pass.as_str().to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            checks: BTreeMap::new(),
+        }
+    }
+}
+
+impl From<&ReadinessReport> for HealthResponse {
+    fn from(report: &ReadinessReport) -> Self {
+        Self {
+            status: report.status.as_str().to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            checks: report
+                .dependencies
+                .iter()
+                .map(|(name, dependency)| {
+                    (
+                        name.clone(),
+                        DependencyHealthResponse {
+                            status: dependency.status.as_str().to_string(),
+                            latency_ms: dependency.latency_ms,
+                            error: dependency.error.clone(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Result of checking a single dependency.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DependencyHealthResponse {
+    /// Check status: `pass`, `warn`, or `fail`.
+    pub status: String,
+    /// Time the check took, in milliseconds.
+    pub latency_ms: u64,
+    /// Failure description, if the check did not pass.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Liveness probe: the process is up and serving requests.
+#[utoipa::path(
+    get,
+    path = "/api/v1/livez",
+    tag = "health",
+    responses((status = 200, description = "Process is up", body = HealthResponse))
+)]
+pub async fn liveness_check() -> Json<HealthResponse> {
+    Json(HealthResponse::live())
+}
+
+/// Readiness probe: critical dependencies are reachable.
+///
+/// Venue failures only warn while enough venues are up.
+#[utoipa::path(
+    get,
+    path = "/api/v1/readyz",
+    tag = "health",
+    responses(
+        (status = 200, description = "Ready to serve traffic", body = HealthResponse),
+        (status = 503, description = "A critical dependency failed", body = HealthResponse),
+    )
+)]
+pub async fn readiness_check(
+    State(state): State<Arc<AppState>>,
+) -> (StatusCode, Json<HealthResponse>) {
+    let Some(readiness) = &state.readiness else {
+        return (StatusCode::OK, Json(HealthResponse::live()));
+    };
+
+    let report = readiness.check().await;
+    let status = if report.is_ready() {
+        StatusCode::OK
+    } else {
+        warn!(status = ?report.status, "Readiness check failed");
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(HealthResponse::from(&report)))
+}
+
+/// Health check endpoint; same report as `/api/v1/readyz`.
+#[utoipa::path(
+    get,
+    path = "/api/v1/health",
+    tag = "health",
+    responses(
+        (status = 200, description = "Service is healthy", body = HealthResponse),
+        (status = 503, description = "A critical dependency failed", body = HealthResponse),
+    )
+)]
+pub async fn health_check(state: State<Arc<AppState>>) -> (StatusCode, Json<HealthResponse>) {
+    readiness_check(state).await
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn parse_rfq_id(id: &str) -> Result<RfqId, ApiError> {
+    uuid::Uuid::parse_str(id)
+        .map(RfqId::from)
+        .map_err(|_| validation_error(&format!("invalid RFQ ID: {id}")))
+}
+
+fn parse_quote_id(id: &str) -> Result<QuoteId, ApiError> {
+    uuid::Uuid::parse_str(id)
+        .map(QuoteId::from)
+        .map_err(|_| validation_error(&format!("invalid Quote ID: {id}")))
+}
+
+fn parse_trade_id(id: &str) -> Result<TradeId, ApiError> {
+    uuid::Uuid::parse_str(id)
+        .map(TradeId::from)
+        .map_err(|_| validation_error(&format!("invalid Trade ID: {id}")))
+}
+
+fn parse_symbol(base: &str, quote: &str) -> Result<Symbol, ApiError> {
+    Symbol::new(format!("{base}/{quote}"))
+        .map_err(|e| validation_error(&format!("invalid symbol: {e}")))
+}
+
+fn parse_decimal(field: &str, value: &str) -> Result<Decimal, ApiError> {
+    Decimal::from_str(value).map_err(|_| validation_error(&format!("invalid {field}: {value}")))
+}
+
+fn parse_quantity(field: &str, value: &str) -> Result<Quantity, ApiError> {
+    Quantity::from_decimal(parse_decimal(field, value)?)
+        .map_err(|e| validation_error(&format!("invalid {field}: {e}")))
+}
+
+fn reference_data_repository(
+    state: &AppState,
+) -> Result<&Arc<dyn InstrumentReferenceDataRepository>, ApiError> {
+    state
+        .instrument_reference_data
+        .as_ref()
+        .ok_or_else(|| not_implemented("instrument reference data not configured"))
+}
+
+fn validate_create_rfq_request(request: &CreateRfqRequest) -> Result<(), ApiError> {
+    if request.client_id.is_empty() {
+        return Err(validation_error("client_id cannot be empty"));
+    }
+    validate_subject(
+        &request.base_asset,
+        &request.quote_asset,
+        request.strategy.as_ref(),
+    )?;
+    if request.quantity <= Decimal::ZERO {
+        return Err(validation_error("quantity must be positive"));
+    }
+    if request.expiry_seconds == Some(0) {
+        return Err(validation_error("expiry_seconds must be greater than 0"));
+    }
+    Ok(())
+}
+
+/// Checks that a request names either an instrument pair or a strategy.
+fn validate_subject(
+    base_asset: &str,
+    quote_asset: &str,
+    strategy: Option<&StrategyRequest>,
+) -> Result<(), ApiError> {
+    match strategy {
+        Some(strategy) => {
+            if !base_asset.is_empty() || !quote_asset.is_empty() {
+                return Err(validation_error(
+                    "specify either base_asset/quote_asset or strategy, not both",
+                ));
+            }
+            if strategy.legs.is_empty() {
+                return Err(validation_error("strategy must have at least one leg"));
+            }
+        }
+        None => {
+            if base_asset.is_empty() {
+                return Err(validation_error("base_asset cannot be empty"));
+            }
+            if quote_asset.is_empty() {
+                return Err(validation_error("quote_asset cannot be empty"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Builds the RFQ subject from an instrument pair or a strategy.
+fn build_subject(
+    base_asset: &str,
+    quote_asset: &str,
+    strategy: Option<&StrategyRequest>,
+) -> Result<RfqSubject, ApiError> {
+    match strategy {
+        Some(strategy) => Ok(RfqSubject::Strategy(strategy.to_strategy()?)),
+        None => {
+            let symbol = Symbol::new(format!("{base_asset}/{quote_asset}"))
+                .map_err(|e| validation_error(&format!("invalid symbol: {e}")))?;
+            Ok(RfqSubject::Single(
+                Instrument::builder(symbol, AssetClass::CryptoSpot).build(),
+            ))
+        }
+    }
+}
+
+#[allow(clippy::unused_async)]
+fn validation_error(message: &str) -> ApiError {
+    api_error(ErrorCode::ValidationError, message)
+}
+
+fn not_found(resource: &str, id: &str) -> ApiError {
+    api_error_with_details(
+        ErrorCode::NotFound,
+        format!("{resource} not found: {id}"),
+        Some(serde_json::json!({ "resource": resource, "id": id })),
+    )
+}
+
+fn rfq_not_found(id: &str) -> ApiError {
+    api_error_with_details(
+        ErrorCode::RfqNotFound,
+        format!("RFQ not found: {id}"),
+        Some(serde_json::json!({ "resource": "RFQ", "id": id })),
+    )
+}
+
+fn not_implemented(message: &str) -> ApiError {
+    api_error(ErrorCode::NotImplemented, message)
+}
+
+fn internal_error(message: &str) -> ApiError {
+    api_error(ErrorCode::InternalError, message)
+}
+
+// ============================================================================
+// Fee Schedule Handlers
+// ============================================================================
+
+/// GET /api/v1/fees/schedule
+///
+/// Returns the base fee schedule.
+///
+/// # Response
+///
+/// ```json
+/// {
+///   "rfq_rates": {
+///     "taker_rate_bps": 3,
+///     "maker_rate_bps": -1
+///   },
+///   "block_rates": {
+///     "taker_rate_bps": 2,
+///     "maker_rate_bps": 2
+///   },
+///   "volume_discounts": [
+///     {"threshold_usd": 1000000, "discount_pct": 10},
+///     {"threshold_usd": 10000000, "discount_pct": 25},
+///     {"threshold_usd": 100000000, "discount_pct": 50}
+///   ]
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns 501 Not Implemented if fee engine is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/fees/schedule",
+    tag = "fees",
+    responses(
+        (status = 200, description = "Base fee schedule", body = serde_json::Value),
+        (status = 501, description = "Fee engine not configured", body = ErrorResponse),
+    )
+)]
 #[instrument(skip(state))]
-pub async fn list_rfqs(
+pub async fn get_fee_schedule(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<FeeSchedule>, ApiError> {
+    let fee_engine = state
+        .fee_engine
+        .as_ref()
+        .ok_or_else(|| not_implemented("fee engine not configured"))?;
+
+    Ok(Json(fee_engine.schedule().clone()))
+}
+
+/// GET /api/v1/fees/schedule/{counterparty_id}
+///
+/// Returns fee schedule for a specific counterparty.
+///
+/// Currently returns the base schedule. Counterparty-specific overrides
+/// will be implemented in a follow-up issue.
+///
+/// # Errors
+///
+/// Returns 501 Not Implemented if fee engine is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/fees/schedule/{counterparty_id}",
+    tag = "fees",
+    params(("counterparty_id" = String, Path, description = "Counterparty ID")),
+    responses(
+        (status = 200, description = "Counterparty fee schedule", body = serde_json::Value),
+        (status = 501, description = "Fee engine not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub async fn get_counterparty_fee_schedule(
+    State(state): State<Arc<AppState>>,
+    Path(_counterparty_id): Path<String>,
+) -> Result<Json<FeeSchedule>, ApiError> {
+    let fee_engine = state
+        .fee_engine
+        .as_ref()
+        .ok_or_else(|| not_implemented("fee engine not configured"))?;
+
+    // TODO: Implement override lookup when FeeOverrideProvider is integrated
+    // For now, return base schedule
+    Ok(Json(fee_engine.schedule().clone()))
+}
+
+// ============================================================================
+// Platform Fee Handlers
+// ============================================================================
+
+/// List platform fee schedules, ordered by tier.
+///
+/// Admin only.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if platform fee schedules are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/fees/platform-schedules",
+    tag = "fees",
+    responses(
+        (status = 200, description = "Platform fee schedules", body = [PlatformFeeScheduleResponse]),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 501, description = "Platform fee schedules not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn list_platform_fee_schedules(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+) -> Result<Json<Vec<PlatformFeeScheduleResponse>>, ApiError> {
+    let repository = platform_fee_schedule_repository(&state, &user)?;
+
+    let schedules = repository
+        .find_all()
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    Ok(Json(
+        schedules
+            .iter()
+            .map(PlatformFeeScheduleResponse::from)
+            .collect(),
+    ))
+}
+
+/// Get a tier's platform fee schedule.
+///
+/// Admin only.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_FOUND` if the tier has no schedule.
+/// Returns `NOT_IMPLEMENTED` if platform fee schedules are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/fees/platform-schedules/{tier}",
+    tag = "fees",
+    params(("tier" = String, Path, description = "Counterparty tier")),
+    responses(
+        (status = 200, description = "Platform fee schedule", body = PlatformFeeScheduleResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Schedule not found", body = ErrorResponse),
+        (status = 501, description = "Platform fee schedules not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn get_platform_fee_schedule(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(tier): Path<String>,
+) -> Result<Json<PlatformFeeScheduleResponse>, ApiError> {
+    let repository = platform_fee_schedule_repository(&state, &user)?;
+
+    let schedule = repository
+        .find_by_tier(&tier)
+        .await
+        .map_err(|e| from_repository_error(&e))?
+        .ok_or_else(|| not_found("Fee schedule", &tier))?;
+
+    Ok(Json(PlatformFeeScheduleResponse::from(&schedule)))
+}
+
+/// Create or replace a tier's platform fee schedule.
+///
+/// Admin only. A counterparty can belong to only one tier.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if the schedule is malformed.
+/// Returns `CONFLICT` if a counterparty is already assigned to another tier.
+/// Returns `NOT_IMPLEMENTED` if platform fee schedules are not configured.
+#[utoipa::path(
+    put,
+    path = "/api/v1/fees/platform-schedules/{tier}",
+    tag = "fees",
+    params(("tier" = String, Path, description = "Counterparty tier")),
+    request_body = PlatformFeeScheduleRequest,
+    responses(
+        (status = 200, description = "Schedule saved", body = PlatformFeeScheduleResponse),
+        (status = 400, description = "Invalid schedule", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 409, description = "Counterparty assigned to another tier", body = ErrorResponse),
+        (status = 501, description = "Platform fee schedules not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn put_platform_fee_schedule(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(tier): Path<String>,
+    ApiJson(request): ApiJson<PlatformFeeScheduleRequest>,
+) -> Result<Json<PlatformFeeScheduleResponse>, ApiError> {
+    let repository = platform_fee_schedule_repository(&state, &user)?;
+    let schedule = request.into_schedule(tier)?;
+
+    let existing = repository
+        .find_all()
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+    let mut previous = None;
+    for other in existing {
+        if other.tier() == schedule.tier() {
+            previous = Some(other);
+        } else if let Some(cp) = schedule
+            .counterparties()
+            .iter()
+            .find(|cp| other.applies_to(cp))
+        {
+            return Err(api_error(
+                ErrorCode::Conflict,
+                format!("counterparty {cp} is already in fee tier {}", other.tier()),
+            ));
+        }
+    }
+    let schedule = match previous {
+        Some(previous) => previous.replaced_by(schedule),
+        None => schedule,
+    };
+
+    repository
+        .save(&schedule)
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    info!(
+        "Saved platform fee schedule {} by {}",
+        schedule.tier(),
+        user.sub
+    );
+
+    Ok(Json(PlatformFeeScheduleResponse::from(&schedule)))
+}
+
+/// Delete a tier's platform fee schedule.
+///
+/// Admin only. Counterparties of the tier fall back to the default tier.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_FOUND` if the tier has no schedule.
+/// Returns `NOT_IMPLEMENTED` if platform fee schedules are not configured.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/fees/platform-schedules/{tier}",
+    tag = "fees",
+    params(("tier" = String, Path, description = "Counterparty tier")),
+    responses(
+        (status = 204, description = "Schedule deleted"),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Schedule not found", body = ErrorResponse),
+        (status = 501, description = "Platform fee schedules not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn delete_platform_fee_schedule(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(tier): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let repository = platform_fee_schedule_repository(&state, &user)?;
+
+    let deleted = repository
+        .delete(&tier)
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+    if !deleted {
+        return Err(not_found("Fee schedule", &tier));
+    }
+
+    info!("Deleted platform fee schedule {} by {}", tier, user.sub);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List fee waivers, ordered by counterparty.
+///
+/// Admin only.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if fee waivers are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/fees/waivers",
+    tag = "fees",
+    responses(
+        (status = 200, description = "Fee waivers", body = [FeeWaiverResponse]),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 501, description = "Fee waivers not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn list_fee_waivers(
     State(state): State<Arc<AppState>>,
-    Query(pagination): Query<PaginationParams>,
-    Query(filter): Query<RfqFilter>,
-) -> Result<Json<PaginatedResponse<RfqResponse>>, (StatusCode, Json<ErrorResponse>)> {
-    info!("Listing RFQs with filter: {:?}", filter);
+    AuthenticatedUser(user): AuthenticatedUser,
+) -> Result<Json<Vec<FeeWaiverResponse>>, ApiError> {
+    let repository = fee_waiver_repository(&state, &user)?;
 
-    // For now, we'll fetch all and filter in memory
-    // In production, this would be done at the database level
-    let all_rfqs = fetch_all_rfqs(&state.rfq_repository).await?;
+    let waivers = repository
+        .find_all()
+        .await
+        .map_err(|e| from_repository_error(&e))?;
 
-    let filtered: Vec<_> = all_rfqs
-        .iter()
-        .filter(|rfq| {
-            filter
-                .client_id
-                .as_ref()
-                .is_none_or(|id| rfq.client_id().as_str() == id)
-        })
-        .filter(|rfq| filter.state.is_none_or(|s| rfq.state() == s))
-        .filter(|rfq| {
-            filter
-                .base_asset
-                .as_ref()
-                .is_none_or(|a| rfq.instrument().symbol().base_asset() == a)
-        })
-        .filter(|rfq| {
-            filter
-                .quote_asset
-                .as_ref()
-                .is_none_or(|a| rfq.instrument().symbol().quote_asset() == a)
-        })
-        .collect();
+    Ok(Json(waivers.iter().map(FeeWaiverResponse::from).collect()))
+}
 
-    let total_items = filtered.len() as u64;
-    let offset = pagination.offset() as usize;
-    let limit = pagination.limit() as usize;
+/// Get a counterparty's fee waiver.
+///
+/// Admin only.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_FOUND` if the counterparty has no waiver.
+/// Returns `NOT_IMPLEMENTED` if fee waivers are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/fees/waivers/{counterparty_id}",
+    tag = "fees",
+    params(("counterparty_id" = String, Path, description = "Counterparty ID")),
+    responses(
+        (status = 200, description = "Fee waiver", body = FeeWaiverResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Waiver not found", body = ErrorResponse),
+        (status = 501, description = "Fee waivers not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn get_fee_waiver(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(counterparty_id): Path<String>,
+) -> Result<Json<FeeWaiverResponse>, ApiError> {
+    let repository = fee_waiver_repository(&state, &user)?;
 
-    let page_data: Vec<RfqResponse> = filtered
-        .into_iter()
-        .skip(offset)
-        .take(limit)
-        .map(RfqResponse::from)
-        .collect();
+    let waiver = repository
+        .find(&CounterpartyId::new(counterparty_id.as_str()))
+        .await
+        .map_err(|e| from_repository_error(&e))?
+        .ok_or_else(|| not_found("Fee waiver", &counterparty_id))?;
 
-    Ok(Json(PaginatedResponse {
-        data: page_data,
-        pagination: PaginationMeta::new(pagination.page, pagination.limit(), total_items),
-    }))
+    Ok(Json(FeeWaiverResponse::from(&waiver)))
 }
 
-/// Get RFQ by ID.
+/// Create or replace a counterparty's fee waiver.
+///
+/// Admin only. The discount applies to the platform fee after its caps.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if the discount or expiry is malformed.
+/// Returns `NOT_IMPLEMENTED` if fee waivers are not configured.
+#[utoipa::path(
+    put,
+    path = "/api/v1/fees/waivers/{counterparty_id}",
+    tag = "fees",
+    params(("counterparty_id" = String, Path, description = "Counterparty ID")),
+    request_body = FeeWaiverRequest,
+    responses(
+        (status = 200, description = "Waiver saved", body = FeeWaiverResponse),
+        (status = 400, description = "Invalid waiver", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 501, description = "Fee waivers not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn put_fee_waiver(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(counterparty_id): Path<String>,
+    ApiJson(request): ApiJson<FeeWaiverRequest>,
+) -> Result<Json<FeeWaiverResponse>, ApiError> {
+    let repository = fee_waiver_repository(&state, &user)?;
+    let discount_pct = parse_decimal("discount_pct", &request.discount_pct)?;
+    let expires_at = request
+        .expires_at
+        .map(|v| parse_timestamp("expires_at", &v))
+        .transpose()?;
+    let waiver = FeeWaiver::new(
+        CounterpartyId::new(counterparty_id),
+        discount_pct,
+        expires_at,
+    )
+    .map_err(|e| from_domain_error(&e))?;
+
+    repository
+        .save(&waiver)
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+
+    info!(
+        "Saved {}% fee waiver for {} by {}",
+        waiver.discount_pct(),
+        waiver.counterparty_id(),
+        user.sub
+    );
+
+    Ok(Json(FeeWaiverResponse::from(&waiver)))
+}
+
+/// Delete a counterparty's fee waiver.
+///
+/// Admin only.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_FOUND` if the counterparty has no waiver.
+/// Returns `NOT_IMPLEMENTED` if fee waivers are not configured.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/fees/waivers/{counterparty_id}",
+    tag = "fees",
+    params(("counterparty_id" = String, Path, description = "Counterparty ID")),
+    responses(
+        (status = 204, description = "Waiver deleted"),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Waiver not found", body = ErrorResponse),
+        (status = 501, description = "Fee waivers not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn delete_fee_waiver(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(counterparty_id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let repository = fee_waiver_repository(&state, &user)?;
+
+    let deleted = repository
+        .delete(&CounterpartyId::new(counterparty_id.as_str()))
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+    if !deleted {
+        return Err(not_found("Fee waiver", &counterparty_id));
+    }
+
+    info!("Deleted fee waiver for {} by {}", counterparty_id, user.sub);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// Settlement Batch Handlers
+// ============================================================================
+
+/// List settlement netting batches, newest first.
+///
+/// Admin only.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if settlement netting is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/settlement-batches",
+    tag = "settlement",
+    params(SettlementBatchFilter),
+    responses(
+        (status = 200, description = "Netting batches", body = Vec<SettlementBatchResponse>),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 501, description = "Settlement netting not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn list_settlement_batches(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Query(filter): Query<SettlementBatchFilter>,
+) -> Result<Json<Vec<SettlementBatchResponse>>, ApiError> {
+    let netting = netting_service(&state, &user)?;
+
+    let batches = netting
+        .list(filter.status)
+        .await
+        .map_err(|e| from_application_error(&e))?;
+
+    Ok(Json(
+        batches.iter().map(SettlementBatchResponse::from).collect(),
+    ))
+}
+
+/// Get a settlement netting batch.
+///
+/// Admin only.
 ///
 /// # Errors
 ///
-/// Returns `NOT_FOUND` if the RFQ does not exist.
+/// Returns `FORBIDDEN` if the caller is not an admin.
 /// Returns `VALIDATION_ERROR` if the ID is not a valid UUID.
-#[instrument(skip(state))]
-pub async fn get_rfq(
+/// Returns `NOT_FOUND` if the batch does not exist.
+/// Returns `NOT_IMPLEMENTED` if settlement netting is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/settlement-batches/{id}",
+    tag = "settlement",
+    params(("id" = String, Path, description = "Batch ID")),
+    responses(
+        (status = 200, description = "Netting batch", body = SettlementBatchResponse),
+        (status = 400, description = "Invalid batch ID", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Batch not found", body = ErrorResponse),
+        (status = 501, description = "Settlement netting not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn get_settlement_batch(
     State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
     Path(id): Path<String>,
-) -> Result<Json<RfqResponse>, (StatusCode, Json<ErrorResponse>)> {
-    info!("Getting RFQ: {}", id);
+) -> Result<Json<SettlementBatchResponse>, ApiError> {
+    let netting = netting_service(&state, &user)?;
+    let batch_id = parse_netting_batch_id(&id)?;
 
-    let rfq_id = parse_rfq_id(&id)?;
+    let batch = netting
+        .get(batch_id)
+        .await
+        .map_err(|e| from_application_error(&e))?;
 
-    let rfq = state
-        .rfq_repository
-        .find_by_id(rfq_id)
+    Ok(Json(SettlementBatchResponse::from(&batch)))
+}
+
+/// Cancel a settlement netting batch before submission.
+///
+/// Admin only. The batch's trades return to individual settlement.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if the ID is not a valid UUID.
+/// Returns `NOT_FOUND` if the batch does not exist.
+/// Returns `INVALID_STATE` if the batch was already submitted or finished.
+/// Returns `NOT_IMPLEMENTED` if settlement netting is not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/settlement-batches/{id}/cancel",
+    tag = "settlement",
+    params(("id" = String, Path, description = "Batch ID")),
+    responses(
+        (status = 200, description = "Batch cancelled", body = SettlementBatchResponse),
+        (status = 400, description = "Invalid batch ID", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Batch not found", body = ErrorResponse),
+        (status = 409, description = "Batch already submitted or finished", body = ErrorResponse),
+        (status = 501, description = "Settlement netting not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn cancel_settlement_batch(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<Json<SettlementBatchResponse>, ApiError> {
+    let netting = netting_service(&state, &user)?;
+    let batch_id = parse_netting_batch_id(&id)?;
+
+    let batch = netting.cancel(batch_id).await.map_err(|e| {
+        warn!("Cannot cancel netting batch {}: {}", id, e);
+        from_application_error(&e)
+    })?;
+
+    info!("Cancelled netting batch {} by {}", id, user.sub);
+
+    Ok(Json(SettlementBatchResponse::from(&batch)))
+}
+
+// ============================================================================
+// Settlement Address Handlers
+// ============================================================================
+
+/// List a counterparty's settlement addresses.
+///
+/// Available to the counterparty itself and to admins.
+///
+/// # Errors
+///
+/// Returns `UNAUTHORIZED_COUNTERPARTY` for another counterparty's addresses.
+/// Returns `NOT_FOUND` if the counterparty does not exist.
+/// Returns `NOT_IMPLEMENTED` if settlement addresses are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/counterparties/{id}/addresses",
+    tag = "counterparties",
+    params(("id" = String, Path, description = "Counterparty ID")),
+    responses(
+        (status = 200, description = "Settlement addresses", body = Vec<SettlementAddressResponse>),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Another counterparty's addresses", body = ErrorResponse),
+        (status = 404, description = "Counterparty not found", body = ErrorResponse),
+        (status = 501, description = "Settlement addresses not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn list_settlement_addresses(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<SettlementAddressResponse>>, ApiError> {
+    let service = settlement_address_service(&state, &user, &id)?;
+
+    let addresses = service
+        .list(&CounterpartyId::new(id))
+        .await
+        .map_err(|e| from_application_error(&e))?;
+
+    Ok(Json(
+        addresses
+            .iter()
+            .map(SettlementAddressResponse::from)
+            .collect(),
+    ))
+}
+
+/// Register a settlement address.
+///
+/// The address starts unverified; settlement is not routed to it until it
+/// is verified through the challenge endpoints.
+///
+/// # Errors
+///
+/// Returns `UNAUTHORIZED_COUNTERPARTY` for another counterparty's addresses.
+/// Returns `VALIDATION_ERROR` if the chain or address is malformed.
+/// Returns `NOT_FOUND` if the counterparty does not exist.
+/// Returns `CONFLICT` if the chain already has an address for the token.
+/// Returns `NOT_IMPLEMENTED` if settlement addresses are not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/counterparties/{id}/addresses",
+    tag = "counterparties",
+    params(("id" = String, Path, description = "Counterparty ID")),
+    request_body = SettlementAddressRequest,
+    responses(
+        (status = 201, description = "Address registered", body = SettlementAddressResponse),
+        (status = 400, description = "Invalid chain or address", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Another counterparty's addresses", body = ErrorResponse),
+        (status = 404, description = "Counterparty not found", body = ErrorResponse),
+        (status = 409, description = "Address already registered for the chain and token", body = ErrorResponse),
+        (status = 501, description = "Settlement addresses not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn add_settlement_address(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+    ApiJson(request): ApiJson<SettlementAddressRequest>,
+) -> Result<(StatusCode, Json<SettlementAddressResponse>), ApiError> {
+    let service = settlement_address_service(&state, &user, &id)?;
+    let chain = parse_blockchain(&request.chain)?;
+
+    let address = service
+        .add(
+            &CounterpartyId::new(id.as_str()),
+            chain,
+            request.token,
+            &request.address,
+            request.label,
+        )
+        .await
+        .map_err(|e| from_application_error(&e))?;
+
+    info!(
+        "Registered settlement address {} for {} by {}",
+        address, id, user.sub
+    );
+
+    Ok((
+        StatusCode::CREATED,
+        Json(SettlementAddressResponse::from(&address)),
+    ))
+}
+
+/// Remove a settlement address.
+///
+/// # Errors
+///
+/// Returns `UNAUTHORIZED_COUNTERPARTY` for another counterparty's addresses.
+/// Returns `VALIDATION_ERROR` if the chain is unknown.
+/// Returns `NOT_FOUND` if the counterparty or address does not exist.
+/// Returns `NOT_IMPLEMENTED` if settlement addresses are not configured.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/counterparties/{id}/addresses/{chain}",
+    tag = "counterparties",
+    params(
+        ("id" = String, Path, description = "Counterparty ID"),
+        ("chain" = String, Path, description = "Chain name"),
+        SettlementAddressParams,
+    ),
+    responses(
+        (status = 204, description = "Address removed"),
+        (status = 400, description = "Unknown chain", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Another counterparty's addresses", body = ErrorResponse),
+        (status = 404, description = "Counterparty or address not found", body = ErrorResponse),
+        (status = 501, description = "Settlement addresses not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn delete_settlement_address(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path((id, chain)): Path<(String, String)>,
+    Query(params): Query<SettlementAddressParams>,
+) -> Result<StatusCode, ApiError> {
+    let service = settlement_address_service(&state, &user, &id)?;
+    let chain = parse_blockchain(&chain)?;
+
+    let removed = service
+        .remove(
+            &CounterpartyId::new(id.as_str()),
+            chain,
+            params.token.as_deref(),
+        )
+        .await
+        .map_err(|e| from_application_error(&e))?;
+
+    info!(
+        "Removed settlement address {} of {} by {}",
+        removed, id, user.sub
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Issue a challenge to verify a settlement address.
+///
+/// The counterparty signs the returned message with the address's key
+/// (`personal_sign`) and submits the signature to the verify endpoint
+/// before the challenge expires.
+///
+/// # Errors
+///
+/// Returns `UNAUTHORIZED_COUNTERPARTY` for another counterparty's addresses.
+/// Returns `VALIDATION_ERROR` if the chain is unknown.
+/// Returns `NOT_FOUND` if the counterparty or address does not exist.
+/// Returns `NOT_IMPLEMENTED` if settlement addresses are not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/counterparties/{id}/addresses/{chain}/challenge",
+    tag = "counterparties",
+    params(
+        ("id" = String, Path, description = "Counterparty ID"),
+        ("chain" = String, Path, description = "Chain name"),
+        SettlementAddressParams,
+    ),
+    responses(
+        (status = 200, description = "Challenge to sign", body = AddressChallengeResponse),
+        (status = 400, description = "Unknown chain", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Another counterparty's addresses", body = ErrorResponse),
+        (status = 404, description = "Counterparty or address not found", body = ErrorResponse),
+        (status = 501, description = "Settlement addresses not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn challenge_settlement_address(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path((id, chain)): Path<(String, String)>,
+    Query(params): Query<SettlementAddressParams>,
+) -> Result<Json<AddressChallengeResponse>, ApiError> {
+    let service = settlement_address_service(&state, &user, &id)?;
+    let chain = parse_blockchain(&chain)?;
+
+    let challenge = service
+        .challenge(&CounterpartyId::new(id), chain, params.token.as_deref())
+        .await
+        .map_err(|e| from_application_error(&e))?;
+
+    Ok(Json(AddressChallengeResponse::from(&challenge)))
+}
+
+/// Verify a settlement address with the signature of its challenge.
+///
+/// The pending challenge is consumed whether or not the signature matches.
+///
+/// # Errors
+///
+/// Returns `UNAUTHORIZED_COUNTERPARTY` for another counterparty's addresses.
+/// Returns `VALIDATION_ERROR` if the chain is unknown, no challenge is
+/// pending or it expired, or the signature was not made by the address.
+/// Returns `NOT_FOUND` if the counterparty or address does not exist.
+/// Returns `NOT_IMPLEMENTED` if settlement addresses are not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/counterparties/{id}/addresses/{chain}/verify",
+    tag = "counterparties",
+    params(
+        ("id" = String, Path, description = "Counterparty ID"),
+        ("chain" = String, Path, description = "Chain name"),
+        SettlementAddressParams,
+    ),
+    request_body = VerifyAddressRequest,
+    responses(
+        (status = 200, description = "Address verified", body = SettlementAddressResponse),
+        (status = 400, description = "Missing challenge or invalid signature", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Another counterparty's addresses", body = ErrorResponse),
+        (status = 404, description = "Counterparty or address not found", body = ErrorResponse),
+        (status = 501, description = "Settlement addresses not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn verify_settlement_address(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path((id, chain)): Path<(String, String)>,
+    Query(params): Query<SettlementAddressParams>,
+    ApiJson(request): ApiJson<VerifyAddressRequest>,
+) -> Result<Json<SettlementAddressResponse>, ApiError> {
+    let service = settlement_address_service(&state, &user, &id)?;
+    let chain = parse_blockchain(&chain)?;
+
+    let address = service
+        .verify(
+            &CounterpartyId::new(id.as_str()),
+            chain,
+            params.token.as_deref(),
+            &request.signature,
+        )
         .await
         .map_err(|e| {
-            error!("Failed to find RFQ: {}", e);
-            internal_error(&e)
-        })?
-        .ok_or_else(|| not_found("RFQ", &id))?;
+            warn!("Settlement address verification for {} failed: {}", id, e);
+            from_application_error(&e)
+        })?;
 
-    Ok(Json(RfqResponse::from(&rfq)))
+    info!("Verified settlement address {} of {}", address, id);
+
+    Ok(Json(SettlementAddressResponse::from(&address)))
 }
 
-/// Create a new RFQ.
+// ============================================================================
+// Counterparty Erasure Handlers
+// ============================================================================
+
+/// Request the erasure of a counterparty's personal identifiers.
+///
+/// Nothing is erased until another admin approves the request and it is
+/// executed.
 ///
 /// # Errors
 ///
-/// Returns `VALIDATION_ERROR` if the request is invalid.
-/// Returns `INTERNAL_ERROR` if the repository save fails.
-#[instrument(skip(state, request))]
-pub async fn create_rfq(
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if the reason is empty.
+/// Returns `NOT_FOUND` if the counterparty does not exist.
+/// Returns `INVALID_STATE` if an erasure of the counterparty is pending.
+/// Returns `NOT_IMPLEMENTED` if counterparty erasure is not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/counterparties/{id}/erasure-requests",
+    tag = "counterparties",
+    params(("id" = String, Path, description = "Counterparty ID")),
+    request_body = CreateErasureRequest,
+    responses(
+        (status = 201, description = "Erasure requested", body = ErasureRequestResponse),
+        (status = 400, description = "Missing reason", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "Counterparty not found", body = ErrorResponse),
+        (status = 409, description = "Erasure already pending", body = ErrorResponse),
+        (status = 501, description = "Counterparty erasure not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn create_erasure_request(
     State(state): State<Arc<AppState>>,
-    Json(request): Json<CreateRfqRequest>,
-) -> Result<(StatusCode, Json<RfqResponse>), (StatusCode, Json<ErrorResponse>)> {
-    info!("Creating RFQ for client: {}", request.client_id);
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+    ApiJson(request): ApiJson<CreateErasureRequest>,
+) -> Result<(StatusCode, Json<ErasureRequestResponse>), ApiError> {
+    let service = counterparty_erasure_service(&state, &user)?;
 
-    // Validate request
-    validate_create_rfq_request(&request)?;
+    let erasure = service
+        .request(&CounterpartyId::new(id), &user.sub, &request.reason)
+        .await
+        .map_err(|e| from_application_error(&e))?;
 
-    // Build instrument
-    let symbol_str = format!("{}/{}", request.base_asset, request.quote_asset);
-    let symbol = crate::domain::value_objects::Symbol::new(&symbol_str)
-        .map_err(|e| validation_error(&format!("invalid symbol: {e}")))?;
+    Ok((
+        StatusCode::CREATED,
+        Json(ErasureRequestResponse::from(&erasure)),
+    ))
+}
 
-    let instrument = Instrument::builder(
-        symbol,
-        crate::domain::value_objects::enums::AssetClass::CryptoSpot,
+/// Get a counterparty erasure request.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if the ID is malformed.
+/// Returns `NOT_FOUND` if the request does not exist.
+/// Returns `NOT_IMPLEMENTED` if counterparty erasure is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/erasure-requests/{id}",
+    tag = "counterparties",
+    params(("id" = String, Path, description = "Erasure request ID")),
+    responses(
+        (status = 200, description = "Erasure request", body = ErasureRequestResponse),
+        (status = 400, description = "Invalid ID", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "Request not found", body = ErrorResponse),
+        (status = 501, description = "Counterparty erasure not configured", body = ErrorResponse),
     )
-    .build();
+)]
+#[instrument(skip(state, user))]
+pub async fn get_erasure_request(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<Json<ErasureRequestResponse>, ApiError> {
+    let service = counterparty_erasure_service(&state, &user)?;
+    let request_id = parse_erasure_request_id(&id)?;
 
-    // Build quantity
-    let quantity = Quantity::new(request.quantity)
-        .map_err(|e| validation_error(&format!("invalid quantity: {e}")))?;
+    let erasure = service
+        .get(request_id)
+        .await
+        .map_err(|e| from_application_error(&e))?;
 
-    // Build expiry
-    let expires_at = Timestamp::now().add_secs(request.expiry_seconds as i64);
+    Ok(Json(ErasureRequestResponse::from(&erasure)))
+}
 
-    // Create RFQ
-    let rfq = crate::domain::entities::rfq::RfqBuilder::new(
-        CounterpartyId::new(&request.client_id),
-        instrument,
-        request.side,
-        quantity,
-        expires_at,
+/// Approve a counterparty erasure request.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if the ID is malformed or the caller
+/// requested the erasure.
+/// Returns `NOT_FOUND` if the request does not exist.
+/// Returns `INVALID_STATE` if the request is not waiting for review.
+/// Returns `NOT_IMPLEMENTED` if counterparty erasure is not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/erasure-requests/{id}/approve",
+    tag = "counterparties",
+    params(("id" = String, Path, description = "Erasure request ID")),
+    responses(
+        (status = 200, description = "Erasure approved", body = ErasureRequestResponse),
+        (status = 400, description = "Invalid ID or approval by the requester", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "Request not found", body = ErrorResponse),
+        (status = 409, description = "Request not waiting for review", body = ErrorResponse),
+        (status = 501, description = "Counterparty erasure not configured", body = ErrorResponse),
     )
-    .build();
+)]
+#[instrument(skip(state, user))]
+pub async fn approve_erasure_request(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<Json<ErasureRequestResponse>, ApiError> {
+    let service = counterparty_erasure_service(&state, &user)?;
+    let request_id = parse_erasure_request_id(&id)?;
 
-    // Save to repository
-    state.rfq_repository.save(&rfq).await.map_err(|e| {
-        error!("Failed to save RFQ: {}", e);
-        internal_error(&e)
+    let erasure = service
+        .approve(request_id, &user.sub)
+        .await
+        .map_err(|e| from_application_error(&e))?;
+
+    Ok(Json(ErasureRequestResponse::from(&erasure)))
+}
+
+/// Execute an approved counterparty erasure.
+///
+/// Replaces the counterparty's identifiers with a pseudonym everywhere and
+/// shreds its event encryption key. Trades and their economics are kept.
+/// Irreversible.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if the ID is malformed.
+/// Returns `NOT_FOUND` if the request or its counterparty does not exist.
+/// Returns `INVALID_STATE` if the request is not approved.
+/// Returns `NOT_IMPLEMENTED` if counterparty erasure is not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/erasure-requests/{id}/execute",
+    tag = "counterparties",
+    params(("id" = String, Path, description = "Erasure request ID")),
+    responses(
+        (status = 200, description = "Counterparty erased", body = ErasureRequestResponse),
+        (status = 400, description = "Invalid ID", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Admin role required", body = ErrorResponse),
+        (status = 404, description = "Request or counterparty not found", body = ErrorResponse),
+        (status = 409, description = "Request not approved", body = ErrorResponse),
+        (status = 501, description = "Counterparty erasure not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn execute_erasure_request(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<Json<ErasureRequestResponse>, ApiError> {
+    let service = counterparty_erasure_service(&state, &user)?;
+    let request_id = parse_erasure_request_id(&id)?;
+
+    let erasure = service.execute(request_id).await.map_err(|e| {
+        warn!("Cannot execute erasure request {}: {}", id, e);
+        from_application_error(&e)
     })?;
 
-    info!("Created RFQ: {}", rfq.id());
+    info!("Executed erasure request {} by {}", id, user.sub);
 
-    Ok((StatusCode::CREATED, Json(RfqResponse::from(&rfq))))
+    Ok(Json(ErasureRequestResponse::from(&erasure)))
 }
 
-/// Cancel an RFQ.
+// ============================================================================
+// Client Venue Credential Handlers
+// ============================================================================
+
+/// List the venues a counterparty has its own credentials for.
+///
+/// Available to the counterparty itself and to admins. The secrets are
+/// never returned.
 ///
 /// # Errors
 ///
-/// Returns `NOT_FOUND` if the RFQ does not exist.
-/// Returns `CONFLICT` if the RFQ cannot be cancelled.
-#[instrument(skip(state))]
-pub async fn cancel_rfq(
+/// Returns `UNAUTHORIZED_COUNTERPARTY` for another counterparty's credentials.
+/// Returns `NOT_IMPLEMENTED` if venue credentials are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/counterparties/{id}/venue-credentials",
+    tag = "counterparties",
+    params(("id" = String, Path, description = "Counterparty ID")),
+    responses(
+        (status = 200, description = "Venues with client credentials", body = Vec<VenueCredentialSetResponse>),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Another counterparty's credentials", body = ErrorResponse),
+        (status = 501, description = "Venue credentials not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn list_venue_credentials(
     State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
     Path(id): Path<String>,
-) -> Result<Json<RfqResponse>, (StatusCode, Json<ErrorResponse>)> {
-    info!("Cancelling RFQ: {}", id);
+) -> Result<Json<Vec<VenueCredentialSetResponse>>, ApiError> {
+    let store = venue_credential_store(&state, &user, &id)?;
 
-    let rfq_id = parse_rfq_id(&id)?;
+    let sets = store
+        .find_by_counterparty(&CounterpartyId::new(id))
+        .await
+        .map_err(|e| from_repository_error(&e))?;
 
-    let mut rfq = state
-        .rfq_repository
-        .find_by_id(rfq_id)
+    Ok(Json(
+        sets.iter().map(VenueCredentialSetResponse::from).collect(),
+    ))
+}
+
+/// Store a counterparty's own credentials for a venue.
+///
+/// RFQs of the counterparty are then quoted on its own account at venues
+/// that support it. Replaces, i.e. rotates, any credentials already stored
+/// for the venue.
+///
+/// # Errors
+///
+/// Returns `UNAUTHORIZED_COUNTERPARTY` for another counterparty's credentials.
+/// Returns `VALIDATION_ERROR` if the key or secret is blank or contains
+/// whitespace.
+/// Returns `NOT_IMPLEMENTED` if venue credentials are not configured.
+#[utoipa::path(
+    put,
+    path = "/api/v1/counterparties/{id}/venue-credentials/{venue_id}",
+    tag = "counterparties",
+    params(
+        ("id" = String, Path, description = "Counterparty ID"),
+        ("venue_id" = String, Path, description = "Venue ID"),
+    ),
+    request_body = VenueCredentialsRequest,
+    responses(
+        (status = 200, description = "Credentials rotated", body = VenueCredentialSetResponse),
+        (status = 201, description = "Credentials stored", body = VenueCredentialSetResponse),
+        (status = 400, description = "Invalid key or secret", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Another counterparty's credentials", body = ErrorResponse),
+        (status = 501, description = "Venue credentials not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn put_venue_credentials(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path((id, venue_id)): Path<(String, String)>,
+    ApiJson(request): ApiJson<VenueCredentialsRequest>,
+) -> Result<(StatusCode, Json<VenueCredentialSetResponse>), ApiError> {
+    let store = venue_credential_store(&state, &user, &id)?;
+    let mut credentials =
+        VenueCredentials::new(request.api_key).map_err(|e| from_domain_error(&e))?;
+    if let Some(api_secret) = request.api_secret {
+        credentials = credentials
+            .with_api_secret(api_secret)
+            .map_err(|e| from_domain_error(&e))?;
+    }
+    let counterparty_id = CounterpartyId::new(id);
+    let venue_id = VenueId::new(venue_id);
+
+    let existing = store
+        .get(&counterparty_id, &venue_id)
         .await
-        .map_err(|e| {
-            error!("Failed to find RFQ: {}", e);
-            internal_error(&e)
-        })?
-        .ok_or_else(|| not_found("RFQ", &id))?;
+        .map_err(|e| from_repository_error(&e))?;
+    let (status, set) = match existing {
+        Some(mut set) => {
+            set.rotate(credentials);
+            (StatusCode::OK, set)
+        }
+        None => (
+            StatusCode::CREATED,
+            VenueCredentialSet::new(counterparty_id, venue_id, credentials),
+        ),
+    };
+    store
+        .save(&set)
+        .await
+        .map_err(|e| from_repository_error(&e))?;
 
-    rfq.cancel().map_err(|e| {
-        warn!("Cannot cancel RFQ: {}", e);
-        conflict_error(&format!("cannot cancel RFQ: {e}"))
-    })?;
+    info!("Saved {} by {}", set, user.sub);
 
-    state.rfq_repository.save(&rfq).await.map_err(|e| {
-        error!("Failed to save cancelled RFQ: {}", e);
-        internal_error(&e)
-    })?;
+    Ok((status, Json(VenueCredentialSetResponse::from(&set))))
+}
 
-    info!("Cancelled RFQ: {}", id);
+/// Remove a counterparty's own credentials for a venue.
+///
+/// The counterparty's RFQs are quoted on the platform's account there
+/// again.
+///
+/// # Errors
+///
+/// Returns `UNAUTHORIZED_COUNTERPARTY` for another counterparty's credentials.
+/// Returns `NOT_FOUND` if no credentials are stored for the venue.
+/// Returns `NOT_IMPLEMENTED` if venue credentials are not configured.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/counterparties/{id}/venue-credentials/{venue_id}",
+    tag = "counterparties",
+    params(
+        ("id" = String, Path, description = "Counterparty ID"),
+        ("venue_id" = String, Path, description = "Venue ID"),
+    ),
+    responses(
+        (status = 204, description = "Credentials removed"),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Another counterparty's credentials", body = ErrorResponse),
+        (status = 404, description = "No credentials for the venue", body = ErrorResponse),
+        (status = 501, description = "Venue credentials not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn delete_venue_credentials(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path((id, venue_id)): Path<(String, String)>,
+) -> Result<StatusCode, ApiError> {
+    let store = venue_credential_store(&state, &user, &id)?;
+
+    let removed = store
+        .delete(
+            &CounterpartyId::new(id.as_str()),
+            &VenueId::new(venue_id.as_str()),
+        )
+        .await
+        .map_err(|e| from_repository_error(&e))?;
+    if !removed {
+        return Err(not_found("Venue credentials", &venue_id));
+    }
+
+    info!(
+        "Removed venue credentials of {} for {} by {}",
+        id, venue_id, user.sub
+    );
 
-    Ok(Json(RfqResponse::from(&rfq)))
+    Ok(StatusCode::NO_CONTENT)
 }
 
 // ============================================================================
-// Venue Handlers
+// Token Registry Handlers
 // ============================================================================
 
-/// List all venues.
+/// List registered tokens, one entry per chain.
 ///
 /// # Errors
 ///
-/// Returns an error response if the repository query fails.
+/// Returns `VALIDATION_ERROR` if the chain filter is unknown.
+/// Returns `NOT_IMPLEMENTED` if the token registry is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/tokens",
+    tag = "tokens",
+    params(TokenListParams),
+    responses(
+        (status = 200, description = "Token deployments by symbol and chain", body = [TokenEntryResponse]),
+        (status = 400, description = "Unknown chain", body = ErrorResponse),
+        (status = 501, description = "Token registry not configured", body = ErrorResponse),
+    )
+)]
 #[instrument(skip(state))]
-pub async fn list_venues(
+pub async fn list_tokens(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<VenueResponse>>, (StatusCode, Json<ErrorResponse>)> {
-    info!("Listing venues");
-
-    let venues = state.venue_repository.find_all().await.map_err(|e| {
-        error!("Failed to list venues: {}", e);
-        internal_error(&e)
-    })?;
-
-    let responses: Vec<VenueResponse> = venues.iter().map(VenueResponse::from).collect();
-
-    Ok(Json(responses))
+    Query(params): Query<TokenListParams>,
+) -> Result<Json<Vec<TokenEntryResponse>>, ApiError> {
+    let tokens = token_registry(&state)?;
+    let chain = params
+        .chain
+        .as_deref()
+        .map(parse_blockchain)
+        .transpose()?
+        .map(ChainId::from);
+
+    Ok(Json(
+        tokens
+            .read()
+            .entries(chain)
+            .iter()
+            .map(TokenEntryResponse::from)
+            .collect(),
+    ))
 }
 
-/// Update venue configuration.
+/// Register or replace a token.
+///
+/// Admin only. Addresses must be unique per chain across tokens.
 ///
 /// # Errors
 ///
-/// Returns `NOT_FOUND` if the venue does not exist.
-/// Returns `INTERNAL_ERROR` if the repository save fails.
-#[instrument(skip(state, request))]
-pub async fn update_venue(
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if a chain or address is malformed.
+/// Returns `CONFLICT` if another token has one of the addresses.
+/// Returns `NOT_IMPLEMENTED` if the token registry is not configured.
+#[utoipa::path(
+    put,
+    path = "/api/v1/tokens/{symbol}",
+    tag = "tokens",
+    params(("symbol" = String, Path, description = "Token symbol (e.g. USDC)")),
+    request_body = TokenRequest,
+    responses(
+        (status = 200, description = "Token saved", body = [TokenEntryResponse]),
+        (status = 400, description = "Invalid chain or address", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 409, description = "Address belongs to another token", body = ErrorResponse),
+        (status = 501, description = "Token registry not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn put_token(
     State(state): State<Arc<AppState>>,
-    Path(id): Path<String>,
-    Json(request): Json<UpdateVenueRequest>,
-) -> Result<Json<VenueResponse>, (StatusCode, Json<ErrorResponse>)> {
-    info!("Updating venue: {}", id);
-
-    let venue_id = VenueId::new(&id);
-
-    let mut venue = state
-        .venue_repository
-        .find_by_id(&venue_id)
-        .await
-        .map_err(|e| {
-            error!("Failed to find venue: {}", e);
-            internal_error(&e)
-        })?
-        .ok_or_else(|| not_found("Venue", &id))?;
-
-    // Apply updates
-    if let Some(enabled) = request.enabled {
-        venue.set_enabled(enabled);
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(symbol): Path<String>,
+    ApiJson(request): ApiJson<TokenRequest>,
+) -> Result<Json<Vec<TokenEntryResponse>>, ApiError> {
+    let tokens = token_registry_admin(&state, &user)?;
+    let symbol = symbol.to_uppercase();
+
+    let mut token = TokenInfo::new(symbol.as_str(), request.name, request.decimals)
+        .with_settlement_enabled(request.enabled_for_settlement.unwrap_or(true));
+    for (chain, address) in request.addresses {
+        token = token.with_address(parse_blockchain(&chain)?.into(), address);
     }
 
-    // Note: priority update would require adding set_priority to Venue
-    // For now, we ignore the priority field
-    let _ = request.priority;
-
-    // Save updated venue
-    state.venue_repository.save(&venue).await.map_err(|e| {
-        error!("Failed to save venue: {}", e);
-        internal_error(&e)
+    let mut registry = tokens.write();
+    registry.try_register(token).map_err(|e| {
+        warn!("Cannot register token {}: {}", symbol, e);
+        token_error(&e)
     })?;
 
-    info!("Updated venue: {}", id);
+    info!("Saved token {} by {}", symbol, user.sub);
 
-    Ok(Json(VenueResponse::from(&venue)))
+    Ok(Json(token_entries(&registry.entries(None), &symbol)))
 }
 
-// ============================================================================
-// Trade Handlers
-// ============================================================================
-
-/// List trades with filtering and pagination.
+/// Enable or disable settlement in a token.
+///
+/// Admin only. RFQs for on-chain instruments in a disabled token are
+/// rejected at creation.
 ///
 /// # Errors
 ///
-/// Returns an error response if the repository query fails.
-#[instrument(skip(state))]
-pub async fn list_trades(
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_FOUND` if the token is not registered.
+/// Returns `NOT_IMPLEMENTED` if the token registry is not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/tokens/{symbol}/settlement",
+    tag = "tokens",
+    params(("symbol" = String, Path, description = "Token symbol (e.g. USDC)")),
+    request_body = TokenSettlementRequest,
+    responses(
+        (status = 200, description = "Settlement flag updated", body = [TokenEntryResponse]),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Token not found", body = ErrorResponse),
+        (status = 501, description = "Token registry not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn set_token_settlement(
     State(state): State<Arc<AppState>>,
-    Query(pagination): Query<PaginationParams>,
-    Query(filter): Query<TradeFilter>,
-) -> Result<Json<PaginatedResponse<TradeResponse>>, (StatusCode, Json<ErrorResponse>)> {
-    info!("Listing trades with filter: {:?}", filter);
-
-    let trades = state
-        .trade_repository
-        .find_all(&filter)
-        .await
-        .map_err(|e| {
-            error!("Failed to list trades: {}", e);
-            internal_error(&e)
-        })?;
-
-    let total_items = trades.len() as u64;
-    let offset = pagination.offset() as usize;
-    let limit = pagination.limit() as usize;
-
-    let page_data: Vec<TradeResponse> = trades
-        .iter()
-        .skip(offset)
-        .take(limit)
-        .map(TradeResponse::from)
-        .collect();
-
-    Ok(Json(PaginatedResponse {
-        data: page_data,
-        pagination: PaginationMeta::new(pagination.page, pagination.limit(), total_items),
-    }))
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(symbol): Path<String>,
+    ApiJson(request): ApiJson<TokenSettlementRequest>,
+) -> Result<Json<Vec<TokenEntryResponse>>, ApiError> {
+    let tokens = token_registry_admin(&state, &user)?;
+    let symbol = symbol.to_uppercase();
+
+    let mut registry = tokens.write();
+    registry
+        .set_settlement_enabled(&symbol, request.enabled)
+        .map_err(|e| token_error(&e))?;
+
+    info!(
+        "Set settlement of token {} to {} by {}",
+        symbol, request.enabled, user.sub
+    );
+
+    Ok(Json(token_entries(&registry.entries(None), &symbol)))
 }
 
-/// Get trade by ID.
+/// Remove a token.
+///
+/// Admin only.
 ///
 /// # Errors
 ///
-/// Returns `NOT_FOUND` if the trade does not exist.
-/// Returns `VALIDATION_ERROR` if the ID is not a valid UUID.
-#[instrument(skip(state))]
-pub async fn get_trade(
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_FOUND` if the token is not registered.
+/// Returns `NOT_IMPLEMENTED` if the token registry is not configured.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/tokens/{symbol}",
+    tag = "tokens",
+    params(("symbol" = String, Path, description = "Token symbol (e.g. USDC)")),
+    responses(
+        (status = 204, description = "Token removed"),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Token not found", body = ErrorResponse),
+        (status = 501, description = "Token registry not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn delete_token(
     State(state): State<Arc<AppState>>,
-    Path(id): Path<String>,
-) -> Result<Json<TradeResponse>, (StatusCode, Json<ErrorResponse>)> {
-    info!("Getting trade: {}", id);
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(symbol): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let tokens = token_registry_admin(&state, &user)?;
+    let symbol = symbol.to_uppercase();
 
-    let trade_id = parse_trade_id(&id)?;
+    tokens
+        .write()
+        .remove(&symbol)
+        .ok_or_else(|| not_found("Token", &symbol))?;
 
-    let trade = state
-        .trade_repository
-        .find_by_id(trade_id)
-        .await
-        .map_err(|e| {
-            error!("Failed to find trade: {}", e);
-            internal_error(&e)
-        })?
-        .ok_or_else(|| not_found("Trade", &id))?;
+    info!("Removed token {} by {}", symbol, user.sub);
 
-    Ok(Json(TradeResponse::from(&trade)))
+    Ok(StatusCode::NO_CONTENT)
 }
 
-// ============================================================================
-// MM Performance DTOs
-// ============================================================================
+fn token_registry(state: &AppState) -> Result<&SharedTokenRegistry, ApiError> {
+    state
+        .tokens
+        .as_ref()
+        .ok_or_else(|| not_implemented("token registry not configured"))
+}
 
-/// MM performance metrics response DTO.
-#[derive(Debug, Clone, Serialize)]
-pub struct MmPerformanceResponse {
-    /// Market maker identifier.
-    pub mm_id: String,
-    /// Response rate percentage (0-100), or null if no RFQs sent.
-    pub response_rate_pct: Option<f64>,
-    /// Average response time in milliseconds, or null if no quotes received.
-    pub avg_response_time_ms: Option<f64>,
-    /// Quote-to-trade conversion percentage (0-100), or null if no quotes provided.
-    pub quote_to_trade_pct: Option<f64>,
-    /// Average rank across all quotes (lower = more competitive), or null if no quotes.
-    pub competitiveness_score: Option<f64>,
-    /// Reject rate percentage (0-100), or null if no accepts requested.
-    pub reject_rate_pct: Option<f64>,
-    /// Total RFQs sent to this MM in the window.
-    pub total_rfqs_received: u64,
-    /// Total quotes provided by this MM in the window.
-    pub total_quotes_provided: u64,
-    /// Total trades executed from this MM's quotes in the window.
-    pub total_trades_executed: u64,
-    /// Start of the rolling window (ISO 8601).
-    pub window_start: String,
-    /// End of the rolling window (ISO 8601).
-    pub window_end: String,
+fn token_registry_admin<'a>(
+    state: &'a AppState,
+    user: &Claims,
+) -> Result<&'a SharedTokenRegistry, ApiError> {
+    if require_role(user, "admin").is_err() {
+        warn!("Denied token registry change to {}", user.sub);
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+    token_registry(state)
 }
 
-impl From<&MmPerformanceMetrics> for MmPerformanceResponse {
-    fn from(m: &MmPerformanceMetrics) -> Self {
-        Self {
-            mm_id: m.mm_id().to_string(),
-            response_rate_pct: m.response_rate_pct(),
-            avg_response_time_ms: m.avg_response_time_ms(),
-            quote_to_trade_pct: m.quote_to_trade_pct(),
-            competitiveness_score: m.competitiveness_score(),
-            reject_rate_pct: m.reject_rate_pct(),
-            total_rfqs_received: m.total_rfqs_received(),
-            total_quotes_provided: m.total_quotes_provided(),
-            total_trades_executed: m.total_trades_executed(),
-            window_start: m.window_start().to_iso8601(),
-            window_end: m.window_end().to_iso8601(),
+fn token_entries(entries: &[TokenEntry], symbol: &str) -> Vec<TokenEntryResponse> {
+    entries
+        .iter()
+        .filter(|entry| entry.symbol == symbol)
+        .map(TokenEntryResponse::from)
+        .collect()
+}
+
+fn token_error(err: &TokenError) -> ApiError {
+    let code = match err {
+        TokenError::InvalidAddress(_) | TokenError::InvalidAmount(_) => ErrorCode::ValidationError,
+        TokenError::DuplicateAddress { .. } => ErrorCode::Conflict,
+        TokenError::TokenNotFound(_) => ErrorCode::NotFound,
+        TokenError::NotOnChain(..) | TokenError::SettlementDisabled(_) => {
+            ErrorCode::SettlementTokenUnavailable
         }
+    };
+    api_error(code, err.to_string())
+}
+
+fn settlement_address_service<'a>(
+    state: &'a AppState,
+    user: &Claims,
+    counterparty_id: &str,
+) -> Result<&'a Arc<SettlementAddressService>, ApiError> {
+    if requesting_counterparty(user).as_str() != counterparty_id
+        && require_role(user, "admin").is_err()
+    {
+        warn!(
+            "Denied settlement addresses of {} to {}",
+            counterparty_id, user.sub
+        );
+        return Err(api_error(
+            ErrorCode::UnauthorizedCounterparty,
+            "settlement addresses belong to another counterparty",
+        ));
     }
+    state
+        .settlement_addresses
+        .as_ref()
+        .ok_or_else(|| not_implemented("settlement addresses not configured"))
 }
 
-/// Query parameters for MM performance listing.
-#[derive(Debug, Clone, Deserialize, Default)]
-pub struct MmPerformanceFilter {
-    /// Minimum response rate percentage for filtering eligible MMs.
-    pub min_response_rate: Option<f64>,
+fn parse_blockchain(chain: &str) -> Result<Blockchain, ApiError> {
+    Blockchain::from_str(chain).map_err(|_| validation_error(&format!("unknown chain: {chain}")))
+}
+
+fn netting_service<'a>(
+    state: &'a AppState,
+    user: &Claims,
+) -> Result<&'a Arc<NettingService>, ApiError> {
+    if require_role(user, "admin").is_err() {
+        warn!("Denied settlement batch access to {}", user.sub);
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+    state
+        .netting
+        .as_ref()
+        .ok_or_else(|| not_implemented("settlement netting not configured"))
+}
+
+fn parse_netting_batch_id(id: &str) -> Result<NettingBatchId, ApiError> {
+    uuid::Uuid::parse_str(id)
+        .map(NettingBatchId::from)
+        .map_err(|_| validation_error(&format!("invalid netting batch ID: {id}")))
+}
+
+fn counterparty_erasure_service<'a>(
+    state: &'a AppState,
+    user: &Claims,
+) -> Result<&'a Arc<CounterpartyErasureService>, ApiError> {
+    if require_role(user, "admin").is_err() {
+        warn!("Denied counterparty erasure access to {}", user.sub);
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+    state
+        .counterparty_erasure
+        .as_ref()
+        .ok_or_else(|| not_implemented("counterparty erasure not configured"))
+}
+
+fn venue_credential_store<'a>(
+    state: &'a AppState,
+    user: &Claims,
+    counterparty_id: &str,
+) -> Result<&'a Arc<dyn VenueCredentialRepository>, ApiError> {
+    if requesting_counterparty(user).as_str() != counterparty_id
+        && require_role(user, "admin").is_err()
+    {
+        warn!(
+            "Denied venue credentials of {} to {}",
+            counterparty_id, user.sub
+        );
+        return Err(api_error(
+            ErrorCode::UnauthorizedCounterparty,
+            "venue credentials belong to another counterparty",
+        ));
+    }
+    state
+        .venue_credentials
+        .as_ref()
+        .ok_or_else(|| not_implemented("venue credentials not configured"))
+}
+
+fn parse_erasure_request_id(id: &str) -> Result<ErasureRequestId, ApiError> {
+    uuid::Uuid::parse_str(id)
+        .map(ErasureRequestId::from)
+        .map_err(|_| validation_error(&format!("invalid erasure request ID: {id}")))
+}
+
+fn platform_fee_schedule_repository<'a>(
+    state: &'a AppState,
+    user: &Claims,
+) -> Result<&'a Arc<dyn PlatformFeeScheduleRepository>, ApiError> {
+    require_fee_admin(user)?;
+    state
+        .platform_fee_schedules
+        .as_ref()
+        .ok_or_else(|| not_implemented("platform fee schedules not configured"))
+}
+
+fn fee_waiver_repository<'a>(
+    state: &'a AppState,
+    user: &Claims,
+) -> Result<&'a Arc<dyn FeeWaiverRepository>, ApiError> {
+    require_fee_admin(user)?;
+    state
+        .fee_waivers
+        .as_ref()
+        .ok_or_else(|| not_implemented("fee waivers not configured"))
+}
+
+fn require_fee_admin(user: &Claims) -> Result<(), ApiError> {
+    if require_role(user, "admin").is_err() {
+        warn!("Denied fee management to {}", user.sub);
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+    Ok(())
 }
 
 // ============================================================================
-// MM Performance Handlers
+// Price Bounds Handlers
 // ============================================================================
 
-/// List MM performance metrics for all tracked market makers.
+/// Get the price bounds tolerances in force.
 ///
-/// Optionally filter by minimum response rate via `?min_response_rate=80.0`.
+/// Admin only.
 ///
 /// # Errors
 ///
-/// Returns `NOT_IMPLEMENTED` if the tracker is not configured.
-/// Returns `INTERNAL_ERROR` if computation fails.
-#[instrument(skip(state))]
-pub async fn list_mm_performance(
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if the price bounds store is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/risk/price-bounds",
+    tag = "risk",
+    responses(
+        (status = 200, description = "Price bounds tolerances", body = PriceBoundsSettingsDto),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 501, description = "Price bounds store not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn get_price_bounds(
     State(state): State<Arc<AppState>>,
-    Query(filter): Query<MmPerformanceFilter>,
-) -> Result<Json<Vec<MmPerformanceResponse>>, (StatusCode, Json<ErrorResponse>)> {
-    info!("Listing MM performance metrics");
-
-    let tracker = state
-        .mm_performance_tracker
-        .as_ref()
-        .ok_or_else(|| not_implemented("mm performance tracker not configured"))?;
-
-    let all_metrics = tracker.get_all_metrics().await.map_err(|e| {
-        error!("Failed to get MM metrics: {}", e);
-        internal_error(&e.to_string())
-    })?;
-
-    let responses: Vec<MmPerformanceResponse> = all_metrics
-        .iter()
-        .filter(|m| {
-            filter
-                .min_response_rate
-                .is_none_or(|min| m.response_rate_pct().is_some_and(|rate| rate >= min))
-        })
-        .map(MmPerformanceResponse::from)
-        .collect();
-
-    Ok(Json(responses))
+    AuthenticatedUser(user): AuthenticatedUser,
+) -> Result<Json<PriceBoundsSettingsDto>, ApiError> {
+    let store = price_bounds_store(&state, &user)?;
+    Ok(Json(PriceBoundsSettingsDto::from(store.current().as_ref())))
 }
 
-/// Get performance metrics for a specific market maker.
+/// Replace the price bounds tolerances.
+///
+/// Admin only. The new tolerances apply from the next price bounds check;
+/// the change is audited with the caller and the previous tolerances.
 ///
 /// # Errors
 ///
-/// Returns `NOT_IMPLEMENTED` if the tracker is not configured.
-/// Returns `INTERNAL_ERROR` if computation fails.
-#[instrument(skip(state))]
-pub async fn get_mm_performance(
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if a tolerance or symbol is malformed.
+/// Returns `NOT_IMPLEMENTED` if the price bounds store is not configured.
+#[utoipa::path(
+    put,
+    path = "/api/v1/risk/price-bounds",
+    tag = "risk",
+    request_body = PriceBoundsSettingsDto,
+    responses(
+        (status = 200, description = "Price bounds tolerances updated", body = PriceBoundsSettingsDto),
+        (status = 400, description = "Invalid tolerances", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 501, description = "Price bounds store not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn put_price_bounds(
     State(state): State<Arc<AppState>>,
-    Path(mm_id): Path<String>,
-) -> Result<Json<MmPerformanceResponse>, (StatusCode, Json<ErrorResponse>)> {
-    info!("Getting MM performance for: {}", mm_id);
+    AuthenticatedUser(user): AuthenticatedUser,
+    ApiJson(request): ApiJson<PriceBoundsSettingsDto>,
+) -> Result<Json<PriceBoundsSettingsDto>, ApiError> {
+    let store = price_bounds_store(&state, &user)?;
+    let settings = request.into_settings()?;
+
+    let change = store
+        .update(settings, user.sub.clone())
+        .await
+        .map_err(|e| {
+            warn!("Cannot update price bounds: {}", e);
+            from_application_error(&e)
+        })?;
 
-    if mm_id.is_empty() {
-        return Err(validation_error("mm_id cannot be empty"));
-    }
+    info!(
+        "Changed price bounds from {} to {} by {}",
+        change.previous, change.current, user.sub
+    );
 
-    let tracker = state
-        .mm_performance_tracker
+    Ok(Json(PriceBoundsSettingsDto::from(&change.current)))
+}
+
+fn price_bounds_store<'a>(
+    state: &'a AppState,
+    user: &Claims,
+) -> Result<&'a Arc<PriceBoundsConfigStore>, ApiError> {
+    if require_role(user, "admin").is_err() {
+        warn!("Denied price bounds access to {}", user.sub);
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+    state
+        .price_bounds
         .as_ref()
-        .ok_or_else(|| not_implemented("mm performance tracker not configured"))?;
+        .ok_or_else(|| not_implemented("price bounds store not configured"))
+}
 
-    let counterparty_id = CounterpartyId::new(&mm_id);
+/// Get the RFQ TTL limits in force.
+///
+/// Admin only.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if the TTL limits store is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/rfq-ttl-limits",
+    tag = "rfqs",
+    responses(
+        (status = 200, description = "RFQ TTL limits", body = RfqTtlPolicyDto),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 501, description = "TTL limits store not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn get_rfq_ttl_limits(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+) -> Result<Json<RfqTtlPolicyDto>, ApiError> {
+    let store = rfq_ttl_store(&state, &user)?;
+    Ok(Json(RfqTtlPolicyDto::from(store.current().as_ref())))
+}
 
-    let metrics = tracker.get_metrics(&counterparty_id).await.map_err(|e| {
-        error!("Failed to get MM metrics for {}: {}", mm_id, e);
-        internal_error(&e.to_string())
+/// Replace the RFQ TTL limits.
+///
+/// Admin only. The new limits apply to RFQs created from the next request;
+/// open RFQs keep their expiry.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if an asset class is unknown, a minimum is
+/// zero, or a default lies outside its range.
+/// Returns `NOT_IMPLEMENTED` if the TTL limits store is not configured.
+#[utoipa::path(
+    put,
+    path = "/api/v1/rfq-ttl-limits",
+    tag = "rfqs",
+    request_body = RfqTtlPolicyDto,
+    responses(
+        (status = 200, description = "RFQ TTL limits updated", body = RfqTtlPolicyDto),
+        (status = 400, description = "Invalid limits", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 501, description = "TTL limits store not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn put_rfq_ttl_limits(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    ApiJson(request): ApiJson<RfqTtlPolicyDto>,
+) -> Result<Json<RfqTtlPolicyDto>, ApiError> {
+    let store = rfq_ttl_store(&state, &user)?;
+    let policy = request.into_policy()?;
+
+    let policy = store.update(policy, &user.sub).map_err(|e| {
+        warn!("Cannot update RFQ TTL limits: {}", e);
+        from_application_error(&e)
     })?;
 
-    if !metrics.has_data() {
-        return Err(not_found("market maker", &mm_id));
-    }
+    Ok(Json(RfqTtlPolicyDto::from(policy.as_ref())))
+}
 
-    Ok(Json(MmPerformanceResponse::from(&metrics)))
+fn rfq_ttl_store<'a>(
+    state: &'a AppState,
+    user: &Claims,
+) -> Result<&'a Arc<RfqTtlConfigStore>, ApiError> {
+    if require_role(user, "admin").is_err() {
+        warn!("Denied RFQ TTL limits access to {}", user.sub);
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+    state
+        .rfq_ttl
+        .as_ref()
+        .ok_or_else(|| not_implemented("RFQ TTL limits store not configured"))
 }
 
 // ============================================================================
-// MM Incentive Status DTOs
+// Trading Calendar Handlers
 // ============================================================================
 
-/// Penalty status summary for API response.
-#[derive(Debug, Clone, Serialize)]
-pub struct PenaltyStatusResponse {
-    /// Whether any penalty applies.
-    pub has_penalty: bool,
-    /// Whether capacity should be reduced.
-    pub capacity_reduced: bool,
-    /// Whether tier should be downgraded.
-    pub tier_downgraded: bool,
-    /// Reason for penalty, if any.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reason: Option<String>,
-}
-
-/// MM incentive status response DTO.
-///
-/// Returns the complete incentive status for a market maker including
-/// tier, rebates, volume metrics, and penalty status.
-#[derive(Debug, Clone, Serialize)]
-pub struct MmIncentiveStatusResponse {
-    /// Market maker identifier.
-    pub mm_id: String,
-    /// Current incentive tier (BRONZE, SILVER, GOLD, PLATINUM).
-    pub current_tier: String,
-    /// Rebate rate for current tier (basis points, negative = rebate to MM).
-    pub rebate_rate_bps: String,
-    /// Monthly trading volume in USD.
-    pub monthly_volume_usd: String,
-    /// Volume needed to reach next tier (null if Platinum).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub volume_to_next_tier_usd: Option<String>,
-    /// Next tier name (null if Platinum).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_tier: Option<String>,
-    /// Rebates earned in current period (USD).
-    pub current_period_rebates_usd: String,
-    /// Penalty status.
-    pub penalty_status: PenaltyStatusResponse,
-    /// Timestamp when this status was computed (ISO 8601).
-    pub computed_at: String,
+/// Trading calendar response DTO.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TradingCalendarResponse {
+    /// Calendar name, in upper case.
+    pub name: String,
+    /// IANA timezone local dates are judged in.
+    pub timezone: String,
+    /// Whether the market is closed on Saturdays and Sundays.
+    pub weekends_closed: bool,
+    /// Holidays (YYYY-MM-DD), in date order.
+    pub holidays: Vec<String>,
 }
 
-impl MmIncentiveStatusResponse {
-    /// Creates a response from domain `MmIncentiveStatus`.
-    #[must_use]
-    pub fn from_status(status: &crate::domain::entities::mm_incentive::MmIncentiveStatus) -> Self {
+impl From<&TradingCalendar> for TradingCalendarResponse {
+    fn from(calendar: &TradingCalendar) -> Self {
         Self {
-            mm_id: status.mm_id().to_string(),
-            current_tier: status.current_tier().to_string(),
-            rebate_rate_bps: status.rebate_rate_bps().to_string(),
-            monthly_volume_usd: status.monthly_volume_usd().to_string(),
-            volume_to_next_tier_usd: status.volume_to_next_tier_usd().map(|v| v.to_string()),
-            next_tier: status.next_tier().map(|t| t.to_string()),
-            current_period_rebates_usd: status.current_period_rebates_usd().to_string(),
-            penalty_status: PenaltyStatusResponse {
-                has_penalty: status.penalty_result().has_penalty(),
-                capacity_reduced: status.penalty_result().should_reduce_capacity(),
-                tier_downgraded: status.penalty_result().should_downgrade_tier(),
-                reason: status.penalty_result().reason().map(String::from),
-            },
-            computed_at: status.computed_at().to_iso8601(),
+            name: calendar.name().to_string(),
+            timezone: calendar.timezone().name().to_string(),
+            weekends_closed: calendar.weekends_closed(),
+            holidays: calendar.holidays().iter().map(|d| d.to_string()).collect(),
         }
     }
 }
 
-// ============================================================================
-// MM Incentive Status Handlers
-// ============================================================================
+/// Request to create or replace a trading calendar.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct TradingCalendarRequest {
+    /// IANA timezone local dates are judged in (e.g. `America/New_York`).
+    pub timezone: String,
+    /// Whether the market is closed on Saturdays and Sundays (default true).
+    #[serde(default = "default_weekends_closed")]
+    pub weekends_closed: bool,
+    /// Holidays (YYYY-MM-DD).
+    #[serde(default)]
+    pub holidays: Vec<String>,
+}
 
-/// Get incentive status for a specific market maker.
+fn default_weekends_closed() -> bool {
+    true
+}
+
+impl TradingCalendarRequest {
+    fn into_calendar(self, name: String) -> Result<TradingCalendar, ApiError> {
+        let timezone: chrono_tz::Tz = self
+            .timezone
+            .parse()
+            .map_err(|_| validation_error(&format!("invalid timezone: {}", self.timezone)))?;
+        let holidays = self
+            .holidays
+            .iter()
+            .map(|d| {
+                chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d")
+                    .map_err(|e| invalid_filter("holidays", d, &e.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(TradingCalendar::new(name, timezone, self.weekends_closed)
+            .map_err(|e| from_domain_error(&e))?
+            .with_holidays(holidays))
+    }
+}
+
+/// List trading calendars, the built-in 24/7 crypto calendar first.
 ///
-/// Returns the MM's current tier, rebate rate, volume metrics, and penalty status.
+/// Admin only.
 ///
 /// # Errors
 ///
-/// Returns `NOT_FOUND` if the MM has no recorded data.
-/// Returns `NOT_IMPLEMENTED` if the incentive service is not configured.
-/// Returns `INTERNAL_ERROR` if status computation fails.
-#[instrument(skip(state))]
-pub async fn get_mm_incentive_status(
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if trading calendars are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/trading-calendars",
+    tag = "calendars",
+    responses(
+        (status = 200, description = "Trading calendars", body = [TradingCalendarResponse]),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 501, description = "Trading calendars not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn list_trading_calendars(
     State(state): State<Arc<AppState>>,
-    Path(mm_id): Path<String>,
-) -> Result<Json<MmIncentiveStatusResponse>, (StatusCode, Json<ErrorResponse>)> {
-    info!("Getting MM incentive status for: {}", mm_id);
+    AuthenticatedUser(user): AuthenticatedUser,
+) -> Result<Json<Vec<TradingCalendarResponse>>, ApiError> {
+    let calendars = trading_calendar_service(&state, &user)?;
 
-    if mm_id.is_empty() {
-        return Err(validation_error("mm_id cannot be empty"));
-    }
+    let all = calendars
+        .list()
+        .await
+        .map_err(|e| from_application_error(&e))?;
 
-    let service = state
-        .mm_incentive_service
-        .as_ref()
-        .ok_or_else(|| not_implemented("mm incentive service not configured"))?;
+    Ok(Json(
+        all.iter().map(TradingCalendarResponse::from).collect(),
+    ))
+}
 
-    let counterparty_id = CounterpartyId::new(&mm_id);
+/// Get a trading calendar.
+///
+/// Admin only.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_FOUND` if there is no calendar with that name.
+/// Returns `NOT_IMPLEMENTED` if trading calendars are not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/trading-calendars/{name}",
+    tag = "calendars",
+    params(("name" = String, Path, description = "Calendar name")),
+    responses(
+        (status = 200, description = "Trading calendar", body = TradingCalendarResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Calendar not found", body = ErrorResponse),
+        (status = 501, description = "Trading calendars not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn get_trading_calendar(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(name): Path<String>,
+) -> Result<Json<TradingCalendarResponse>, ApiError> {
+    let calendars = trading_calendar_service(&state, &user)?;
 
-    let status = service
-        .get_status(&counterparty_id)
+    let calendar = calendars
+        .get(&name)
         .await
-        .map_err(|e| match &e {
-            MmIncentiveError::MmNotFound(_) => not_found("market maker", &mm_id),
-            MmIncentiveError::Performance(pe) => {
-                error!("Failed to get MM incentive status for {}: {}", mm_id, pe);
-                internal_error(&pe.to_string())
-            }
-        })?;
+        .map_err(|e| from_application_error(&e))?
+        .ok_or_else(|| not_found("Trading calendar", &name))?;
 
-    Ok(Json(MmIncentiveStatusResponse::from_status(&status)))
+    Ok(Json(TradingCalendarResponse::from(&calendar)))
 }
 
-// ============================================================================
-// Health Check
-// ============================================================================
+/// Create or replace a trading calendar.
+///
+/// Admin only. Changes apply to trades scheduled and RFQs activated from
+/// then on; trades already scheduled keep their settlement time.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if the timezone or a holiday is malformed, or
+/// the calendar is the built-in crypto calendar.
+/// Returns `NOT_IMPLEMENTED` if trading calendars are not configured.
+#[utoipa::path(
+    put,
+    path = "/api/v1/trading-calendars/{name}",
+    tag = "calendars",
+    params(("name" = String, Path, description = "Calendar name")),
+    request_body = TradingCalendarRequest,
+    responses(
+        (status = 200, description = "Calendar saved", body = TradingCalendarResponse),
+        (status = 400, description = "Invalid calendar", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 501, description = "Trading calendars not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn put_trading_calendar(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(name): Path<String>,
+    ApiJson(request): ApiJson<TradingCalendarRequest>,
+) -> Result<Json<TradingCalendarResponse>, ApiError> {
+    let calendars = trading_calendar_service(&state, &user)?;
+    let calendar = request.into_calendar(name)?;
+
+    calendars
+        .save(&calendar)
+        .await
+        .map_err(|e| from_application_error(&e))?;
 
-/// Health check response.
-#[derive(Debug, Clone, Serialize)]
-pub struct HealthResponse {
-    /// Service status.
-    pub status: String,
-    /// Service version.
-    pub version: String,
+    info!("Saved trading calendar {} by {}", calendar, user.sub);
+
+    Ok(Json(TradingCalendarResponse::from(&calendar)))
 }
 
-/// Health check endpoint.
-pub async fn health_check() -> Json<HealthResponse> {
-    Json(HealthResponse {
-        status: "healthy".to_string(),
-        version: env!("CARGO_PKG_VERSION").to_string(),
-    })
+/// Delete a trading calendar.
+///
+/// Admin only. Instruments that followed it trade 24/7 until it is
+/// recreated.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` for the built-in crypto calendar.
+/// Returns `NOT_FOUND` if there is no calendar with that name.
+/// Returns `NOT_IMPLEMENTED` if trading calendars are not configured.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/trading-calendars/{name}",
+    tag = "calendars",
+    params(("name" = String, Path, description = "Calendar name")),
+    responses(
+        (status = 204, description = "Calendar deleted"),
+        (status = 400, description = "Built-in calendar", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Calendar not found", body = ErrorResponse),
+        (status = 501, description = "Trading calendars not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user))]
+pub async fn delete_trading_calendar(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(name): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let calendars = trading_calendar_service(&state, &user)?;
+
+    let deleted = calendars
+        .delete(&name)
+        .await
+        .map_err(|e| from_application_error(&e))?;
+    if !deleted {
+        return Err(not_found("Trading calendar", &name));
+    }
+
+    info!("Deleted trading calendar {} by {}", name, user.sub);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn trading_calendar_service<'a>(
+    state: &'a AppState,
+    user: &Claims,
+) -> Result<&'a Arc<TradingCalendarService>, ApiError> {
+    if require_role(user, "admin").is_err() {
+        warn!("Denied trading calendar management to {}", user.sub);
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+    state
+        .trading_calendars
+        .as_ref()
+        .ok_or_else(|| not_implemented("trading calendars not configured"))
 }
 
 // ============================================================================
-// Helper Functions
+// Dead Letter Handlers
 // ============================================================================
 
-fn parse_rfq_id(id: &str) -> Result<RfqId, (StatusCode, Json<ErrorResponse>)> {
-    uuid::Uuid::parse_str(id)
-        .map(RfqId::from)
-        .map_err(|_| validation_error(&format!("invalid RFQ ID: {id}")))
+/// Query parameters of the dead letter list endpoint.
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DeadLetterParams {
+    /// Only list events parked by this consumer (e.g. `webhook_delivery`).
+    pub consumer: Option<String>,
+    /// Maximum number of events to return.
+    pub limit: Option<u32>,
 }
 
-fn parse_trade_id(id: &str) -> Result<TradeId, (StatusCode, Json<ErrorResponse>)> {
-    uuid::Uuid::parse_str(id)
-        .map(TradeId::from)
-        .map_err(|_| validation_error(&format!("invalid Trade ID: {id}")))
+/// Re-drive request DTO.
+///
+/// With `event_id`, re-drives that event of `consumer` only; without it,
+/// re-drives parked events in bulk, oldest first.
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+pub struct RedriveDeadLettersRequest {
+    /// Consumer whose events are re-driven; required with `event_id`.
+    #[serde(default)]
+    pub consumer: Option<String>,
+    /// Event to re-drive (UUID).
+    #[serde(default)]
+    pub event_id: Option<String>,
+    /// Maximum number of events re-driven in bulk.
+    #[serde(default)]
+    pub limit: Option<u32>,
 }
 
-fn validate_create_rfq_request(
-    request: &CreateRfqRequest,
-) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
-    if request.client_id.is_empty() {
-        return Err(validation_error("client_id cannot be empty"));
-    }
-    if request.base_asset.is_empty() {
-        return Err(validation_error("base_asset cannot be empty"));
-    }
-    if request.quote_asset.is_empty() {
-        return Err(validation_error("quote_asset cannot be empty"));
-    }
-    if request.quantity <= 0.0 {
-        return Err(validation_error("quantity must be positive"));
-    }
-    if request.expiry_seconds == 0 {
-        return Err(validation_error("expiry_seconds must be greater than 0"));
-    }
-    Ok(())
+/// An event a consumer failed to handle.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DeadLetterResponse {
+    /// Consumer that failed on the event.
+    pub consumer: String,
+    /// Event ID.
+    pub event_id: String,
+    /// Event name.
+    pub event_name: String,
+    /// Event payload.
+    #[schema(value_type = Object)]
+    pub payload: serde_json::Value,
+    /// Error from the last failed attempt.
+    pub last_error: String,
+    /// Attempts made so far, including re-drives.
+    pub attempts: u32,
+    /// Status: PARKED or RESOLVED.
+    pub status: String,
+    /// When the first attempt failed (ISO 8601).
+    pub first_seen_at: String,
+    /// When the last attempt finished (ISO 8601).
+    pub last_seen_at: String,
 }
 
-#[allow(clippy::unused_async)]
-async fn fetch_all_rfqs(
-    _repo: &Arc<dyn RfqRepository>,
-) -> Result<Vec<Rfq>, (StatusCode, Json<ErrorResponse>)> {
-    // This is a placeholder - in production, the repository would have a find_all method
-    // For now, we return an empty list since RfqRepository only has find_by_id
-    Ok(vec![])
+impl From<&DeadLetter> for DeadLetterResponse {
+    fn from(letter: &DeadLetter) -> Self {
+        Self {
+            consumer: letter.consumer.clone(),
+            event_id: letter.event_id().to_string(),
+            event_name: letter.event.event_name.clone(),
+            payload: letter.event.payload.clone(),
+            last_error: letter.last_error.clone(),
+            attempts: letter.attempts,
+            status: letter.status.to_string(),
+            first_seen_at: letter.first_seen_at.to_string(),
+            last_seen_at: letter.last_seen_at.to_string(),
+        }
+    }
 }
 
-fn validation_error(message: &str) -> (StatusCode, Json<ErrorResponse>) {
-    (
-        StatusCode::BAD_REQUEST,
-        Json(ErrorResponse::new("VALIDATION_ERROR", message)),
+/// List parked events, oldest first.
+///
+/// Admin only.
+///
+/// # Errors
+///
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if the dead letter queue is not configured.
+#[utoipa::path(
+    get,
+    path = "/api/v1/dead-letters",
+    tag = "dead-letters",
+    params(DeadLetterParams),
+    responses(
+        (status = 200, description = "Parked events", body = [DeadLetterResponse]),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 501, description = "Dead letter queue not configured", body = ErrorResponse),
     )
-}
+)]
+#[instrument(skip(state, user))]
+pub async fn list_dead_letters(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Query(params): Query<DeadLetterParams>,
+) -> Result<Json<Vec<DeadLetterResponse>>, ApiError> {
+    let queue = dead_letter_queue(&state, &user)?;
+    let limit = dead_letter_limit(params.limit);
+
+    let parked = queue
+        .list_parked(params.consumer.as_deref(), limit)
+        .await
+        .map_err(|e| from_application_error(&e))?;
 
-fn not_found(resource: &str, id: &str) -> (StatusCode, Json<ErrorResponse>) {
-    (
-        StatusCode::NOT_FOUND,
-        Json(ErrorResponse::new(
-            "NOT_FOUND",
-            format!("{resource} not found: {id}"),
-        )),
-    )
+    Ok(Json(parked.iter().map(DeadLetterResponse::from).collect()))
 }
 
-fn conflict_error(message: &str) -> (StatusCode, Json<ErrorResponse>) {
-    (
-        StatusCode::CONFLICT,
-        Json(ErrorResponse::new("CONFLICT", message)),
+/// Re-drive parked events to their consumers.
+///
+/// Admin only. Returns the events after the attempt: `RESOLVED` if the
+/// consumer handled the event, still `PARKED` with the new error if not.
+/// Re-driving a resolved event returns it without handing it over again.
+///
+/// # Errors
+///
+/// Returns `VALIDATION_ERROR` if `event_id` is not a valid UUID or is
+/// given without `consumer`.
+/// Returns `NOT_FOUND` if the event was never parked for the consumer or
+/// the consumer is not registered.
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `NOT_IMPLEMENTED` if the dead letter queue is not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/dead-letters",
+    tag = "dead-letters",
+    request_body = RedriveDeadLettersRequest,
+    responses(
+        (status = 200, description = "Re-driven events", body = [DeadLetterResponse]),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Event or consumer not found", body = ErrorResponse),
+        (status = 501, description = "Dead letter queue not configured", body = ErrorResponse),
     )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn redrive_dead_letters(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    ApiJson(request): ApiJson<RedriveDeadLettersRequest>,
+) -> Result<Json<Vec<DeadLetterResponse>>, ApiError> {
+    let queue = dead_letter_queue(&state, &user)?;
+
+    let redriven = match request.event_id.as_deref() {
+        Some(event_id) => {
+            let consumer = request
+                .consumer
+                .as_deref()
+                .ok_or_else(|| validation_error("consumer is required with event_id"))?;
+            let event_id = uuid::Uuid::parse_str(event_id)
+                .map(EventId::from)
+                .map_err(|_| validation_error(&format!("invalid event ID: {event_id}")))?;
+            let letter = queue
+                .redrive(consumer, event_id)
+                .await
+                .map_err(|e| from_application_error(&e))?;
+            vec![letter]
+        }
+        None => queue
+            .redrive_all(
+                request.consumer.as_deref(),
+                dead_letter_limit(request.limit),
+            )
+            .await
+            .map_err(|e| from_application_error(&e))?,
+    };
+
+    let resolved = redriven.iter().filter(|letter| !letter.is_parked()).count();
+    info!(
+        "{} re-drove {} parked events, {} resolved",
+        user.sub,
+        redriven.len(),
+        resolved
+    );
+
+    Ok(Json(
+        redriven.iter().map(DeadLetterResponse::from).collect(),
+    ))
 }
 
-fn not_implemented(message: &str) -> (StatusCode, Json<ErrorResponse>) {
-    (
-        StatusCode::NOT_IMPLEMENTED,
-        Json(ErrorResponse::new("NOT_IMPLEMENTED", message)),
-    )
+/// Returns the dead letter queue if the caller is an admin.
+fn dead_letter_queue<'a>(
+    state: &'a AppState,
+    user: &Claims,
+) -> Result<&'a Arc<DeadLetterQueue>, ApiError> {
+    if require_role(user, "admin").is_err() {
+        warn!("Denied dead letter access to {}", user.sub);
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+    state
+        .dead_letters
+        .as_ref()
+        .ok_or_else(|| not_implemented("dead letter queue not configured"))
 }
 
-fn internal_error(message: &str) -> (StatusCode, Json<ErrorResponse>) {
-    (
-        StatusCode::INTERNAL_SERVER_ERROR,
-        Json(ErrorResponse::new("INTERNAL_ERROR", message)),
-    )
+/// Returns the requested number of dead letters, clamped to
+/// `1..=MAX_PAGE_SIZE`.
+fn dead_letter_limit(limit: Option<u32>) -> usize {
+    limit
+        .unwrap_or_else(default_page_size)
+        .clamp(1, MAX_PAGE_SIZE) as usize
 }
 
 // ============================================================================
-// Fee Schedule Handlers
+// Forced State Transition Handlers
 // ============================================================================
 
-/// GET /api/v1/fees/schedule
-///
-/// Returns the base fee schedule.
-///
-/// # Response
+/// Request to force a stuck RFQ into a new state.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct ForceRfqStateRequest {
+    /// Target state; only `EXECUTED` or `FAILED` from `EXECUTING`.
+    pub state: RfqState,
+    /// Why the transition is forced, recorded in the audit trail.
+    pub justification: String,
+}
+
+/// Request to force a stuck trade settlement into a new state.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct ForceSettlementStateRequest {
+    /// Target state; only `SETTLED` or `FAILED` from `IN_PROGRESS`.
+    pub state: SettlementState,
+    /// Why the transition is forced, recorded in the audit trail.
+    pub justification: String,
+}
+
+/// Audit record of a forced state transition.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ForcedTransitionResponse {
+    /// ID of the audit event.
+    pub event_id: String,
+    /// RFQ the transition belongs to.
+    pub rfq_id: String,
+    /// Forced trade; absent when the RFQ itself was forced.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade_id: Option<String>,
+    /// Who forced the transition.
+    pub actor: String,
+    /// Why the transition was forced.
+    pub justification: String,
+    /// State before the transition.
+    pub from: String,
+    /// State after the transition.
+    pub to: String,
+    /// When the transition was forced (ISO 8601).
+    pub forced_at: String,
+}
+
+impl From<&ForcedStateTransition> for ForcedTransitionResponse {
+    fn from(event: &ForcedStateTransition) -> Self {
+        Self {
+            event_id: event.metadata.event_id.to_string(),
+            rfq_id: event
+                .metadata
+                .rfq_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            trade_id: event.trade_id.map(|id| id.to_string()),
+            actor: event.actor.clone(),
+            justification: event.justification.clone(),
+            from: event.from.clone(),
+            to: event.to.clone(),
+            forced_at: event.metadata.timestamp.to_string(),
+        }
+    }
+}
+
+/// Force a stuck RFQ into a new state.
 ///
-/// ```json
-/// {
-///   "rfq_rates": {
-///     "taker_rate_bps": 3,
-///     "maker_rate_bps": -1
-///   },
-///   "block_rates": {
-///     "taker_rate_bps": 2,
-///     "maker_rate_bps": 2
-///   },
-///   "volume_discounts": [
-///     {"threshold_usd": 1000000, "discount_pct": 10},
-///     {"threshold_usd": 10000000, "discount_pct": 25},
-///     {"threshold_usd": 100000000, "discount_pct": 50}
-///   ]
-/// }
-/// ```
+/// Admin only. A rescue for executions stuck in `EXECUTING` whose outcome
+/// was established out of band: the RFQ can be forced to `EXECUTED` or
+/// `FAILED`, nothing else. Every use is audited and raises an alert.
 ///
 /// # Errors
 ///
-/// Returns 501 Not Implemented if fee engine is not configured.
-#[instrument(skip(state))]
-pub async fn get_fee_schedule(
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if the ID is malformed or the justification
+/// is blank.
+/// Returns `RFQ_NOT_FOUND` if the RFQ does not exist.
+/// Returns `RFQ_INVALID_STATE` if the transition is not an allowed rescue.
+/// Returns `NOT_IMPLEMENTED` if forced transitions are not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/rfqs/{id}/force-state",
+    tag = "admin",
+    params(("id" = String, Path, description = "RFQ ID")),
+    request_body = ForceRfqStateRequest,
+    responses(
+        (status = 200, description = "RFQ forced", body = ForcedTransitionResponse),
+        (status = 400, description = "Invalid ID or justification", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "RFQ not found", body = ErrorResponse),
+        (status = 409, description = "Transition not allowed", body = ErrorResponse),
+        (status = 501, description = "Forced transitions not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn force_rfq_state(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<FeeSchedule>, (StatusCode, Json<ErrorResponse>)> {
-    let fee_engine = state
-        .fee_engine
-        .as_ref()
-        .ok_or_else(|| not_implemented("fee engine not configured"))?;
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+    ApiJson(request): ApiJson<ForceRfqStateRequest>,
+) -> Result<Json<ForcedTransitionResponse>, ApiError> {
+    let service = forced_transition_service(&state, &user)?;
+    let rfq_id = parse_rfq_id(&id)?;
 
-    Ok(Json(fee_engine.schedule().clone()))
+    let event = service
+        .force_rfq_state(rfq_id, request.state, &user.sub, &request.justification)
+        .await
+        .map_err(|e| {
+            warn!("Cannot force RFQ {} to {}: {}", id, request.state, e);
+            from_application_error(&e)
+        })?;
+
+    Ok(Json(ForcedTransitionResponse::from(&event)))
 }
 
-/// GET /api/v1/fees/schedule/{counterparty_id}
-///
-/// Returns fee schedule for a specific counterparty.
+/// Force a stuck trade settlement into a new state.
 ///
-/// Currently returns the base schedule. Counterparty-specific overrides
-/// will be implemented in a follow-up issue.
+/// Admin only. A rescue for settlements stuck in `IN_PROGRESS` whose
+/// outcome was established out of band: the trade can be forced to
+/// `SETTLED` or `FAILED`, nothing else. Every use is audited and raises an
+/// alert.
 ///
 /// # Errors
 ///
-/// Returns 501 Not Implemented if fee engine is not configured.
-#[instrument(skip(state))]
-pub async fn get_counterparty_fee_schedule(
+/// Returns `FORBIDDEN` if the caller is not an admin.
+/// Returns `VALIDATION_ERROR` if the ID is malformed or the justification
+/// is blank.
+/// Returns `NOT_FOUND` if the trade does not exist.
+/// Returns `INVALID_STATE` if the transition is not an allowed rescue.
+/// Returns `NOT_IMPLEMENTED` if forced transitions are not configured.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/trades/{id}/force-state",
+    tag = "admin",
+    params(("id" = String, Path, description = "Trade ID")),
+    request_body = ForceSettlementStateRequest,
+    responses(
+        (status = 200, description = "Settlement forced", body = ForcedTransitionResponse),
+        (status = 400, description = "Invalid ID or justification", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "Trade not found", body = ErrorResponse),
+        (status = 409, description = "Transition not allowed", body = ErrorResponse),
+        (status = 501, description = "Forced transitions not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, user, request))]
+pub async fn force_settlement_state(
     State(state): State<Arc<AppState>>,
-    Path(_counterparty_id): Path<String>,
-) -> Result<Json<FeeSchedule>, (StatusCode, Json<ErrorResponse>)> {
-    let fee_engine = state
-        .fee_engine
-        .as_ref()
-        .ok_or_else(|| not_implemented("fee engine not configured"))?;
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<String>,
+    ApiJson(request): ApiJson<ForceSettlementStateRequest>,
+) -> Result<Json<ForcedTransitionResponse>, ApiError> {
+    let service = forced_transition_service(&state, &user)?;
+    let trade_id = parse_trade_id(&id)?;
 
-    // TODO: Implement override lookup when FeeOverrideProvider is integrated
-    // For now, return base schedule
-    Ok(Json(fee_engine.schedule().clone()))
+    let event = service
+        .force_settlement_state(trade_id, request.state, &user.sub, &request.justification)
+        .await
+        .map_err(|e| {
+            warn!("Cannot force trade {} to {}: {}", id, request.state, e);
+            from_application_error(&e)
+        })?;
+
+    Ok(Json(ForcedTransitionResponse::from(&event)))
+}
+
+/// Returns the forced transition service if the caller is an admin.
+fn forced_transition_service<'a>(
+    state: &'a AppState,
+    user: &Claims,
+) -> Result<&'a Arc<ForcedTransitionService>, ApiError> {
+    if require_role(user, "admin").is_err() {
+        warn!("Denied forced state transition to {}", user.sub);
+        return Err(api_error(ErrorCode::Forbidden, "admin role required"));
+    }
+    state
+        .forced_transitions
+        .as_ref()
+        .ok_or_else(|| not_implemented("forced transitions not configured"))
 }
 
 #[cfg(test)]
@@ -1248,9 +9544,16 @@ mod tests {
             client_id: "client-1".to_string(),
             base_asset: "BTC".to_string(),
             quote_asset: "USD".to_string(),
-            side: OrderSide::Buy,
-            quantity: 1.0,
-            expiry_seconds: 300,
+            strategy: None,
+            side: RfqDirection::Buy,
+            quantity: Decimal::ONE,
+            expiry_seconds: Some(300),
+            size_mode: None,
+            venue_allowlist: None,
+            venue_blocklist: Vec::new(),
+            activate_at: None,
+            compensation_policy: None,
+            quantity_disclosure: None,
         };
         assert!(validate_create_rfq_request(&request).is_ok());
     }
@@ -1261,9 +9564,16 @@ mod tests {
             client_id: String::new(),
             base_asset: "BTC".to_string(),
             quote_asset: "USD".to_string(),
-            side: OrderSide::Buy,
-            quantity: 1.0,
-            expiry_seconds: 300,
+            strategy: None,
+            side: RfqDirection::Buy,
+            quantity: Decimal::ONE,
+            expiry_seconds: Some(300),
+            size_mode: None,
+            venue_allowlist: None,
+            venue_blocklist: Vec::new(),
+            activate_at: None,
+            compensation_policy: None,
+            quantity_disclosure: None,
         };
         assert!(validate_create_rfq_request(&request).is_err());
     }
@@ -1274,9 +9584,16 @@ mod tests {
             client_id: "client-1".to_string(),
             base_asset: "BTC".to_string(),
             quote_asset: "USD".to_string(),
-            side: OrderSide::Buy,
-            quantity: 0.0,
-            expiry_seconds: 300,
+            strategy: None,
+            side: RfqDirection::Buy,
+            quantity: Decimal::ZERO,
+            expiry_seconds: Some(300),
+            size_mode: None,
+            venue_allowlist: None,
+            venue_blocklist: Vec::new(),
+            activate_at: None,
+            compensation_policy: None,
+            quantity_disclosure: None,
         };
         assert!(validate_create_rfq_request(&request).is_err());
     }
@@ -1287,16 +9604,24 @@ mod tests {
             client_id: "client-1".to_string(),
             base_asset: "BTC".to_string(),
             quote_asset: "USD".to_string(),
-            side: OrderSide::Buy,
-            quantity: 1.0,
-            expiry_seconds: 0,
+            strategy: None,
+            side: RfqDirection::Buy,
+            quantity: Decimal::ONE,
+            expiry_seconds: Some(0),
+            size_mode: None,
+            venue_allowlist: None,
+            venue_blocklist: Vec::new(),
+            activate_at: None,
+            compensation_policy: None,
+            quantity_disclosure: None,
         };
         assert!(validate_create_rfq_request(&request).is_err());
     }
 
     #[tokio::test]
-    async fn health_check_returns_healthy() {
-        let response = health_check().await;
-        assert_eq!(response.status, "healthy");
+    async fn liveness_check_passes() {
+        let response = liveness_check().await;
+        assert_eq!(response.status, "pass");
+        assert!(response.checks.is_empty());
     }
 }
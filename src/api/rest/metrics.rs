@@ -0,0 +1,33 @@
+//! # Metrics Endpoint
+//!
+//! Serves the Prometheus scrape endpoint.
+//!
+//! - `GET /metrics` - Prometheus text exposition format (enabled by
+//!   `rest.enable_metrics`)
+//!
+//! The exported series are documented in [`crate::infrastructure::metrics`].
+
+use axum::extract::State;
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::{Router, routing::get};
+use metrics_exporter_prometheus::PrometheusHandle;
+
+/// Content type of the Prometheus text exposition format.
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Returns a router serving the Prometheus scrape endpoint at `/metrics`.
+///
+/// Merged into the main router only when `rest.enable_metrics` is set.
+pub fn metrics_router<S>(handle: PrometheusHandle) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/metrics", get(render))
+        .with_state(handle)
+}
+
+async fn render(State(handle): State<PrometheusHandle>) -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, CONTENT_TYPE)], handle.render())
+}
@@ -13,21 +13,66 @@
 //! - `GET /api/v1/rfqs/{id}` - Get RFQ by ID
 //! - `POST /api/v1/rfqs` - Create new RFQ
 //! - `DELETE /api/v1/rfqs/{id}` - Cancel RFQ
+//! - `POST /api/v1/rfqs/{id}/select` - Select a quote, firming it up if indicative
+//! - `GET /api/v1/rfqs/{id}/timeline` - Audit timeline of an RFQ (JSON or CSV)
 //!
 //! ## Venues
 //! - `GET /api/v1/venues` - List all venues
 //! - `PUT /api/v1/venues/{id}` - Update venue configuration
+//! - `GET|POST /api/v1/venues/{id}/maintenance` - List or schedule maintenance windows
+//! - `DELETE /api/v1/venues/{id}/maintenance/{start}` - Remove a maintenance window
 //!
 //! ## Trades
 //! - `GET /api/v1/trades` - List trades with filtering and pagination
 //! - `GET /api/v1/trades/{id}` - Get trade by ID
+//! - `GET /api/v1/trades/export` - Stream trades as CSV or Parquet
+//!
+//! ## Reports
+//! - `GET /api/v1/reports/best-execution` - Best execution report of a client over a period (JSON or CSV)
+//!
+//! ## Instruments (admin)
+//! - `GET /api/v1/instruments` - List instrument reference data
+//! - `GET|PUT|DELETE /api/v1/instruments/{base}/{quote}` - Manage reference data
 //!
 //! ## MM Performance (admin)
 //! - `GET /api/v1/mm-performance` - List all MM performance metrics
 //! - `GET /api/v1/mm-performance/{mm_id}` - Get specific MM performance
 //!
+//! ## Webhooks (admin)
+//! - `GET|POST /api/v1/webhooks` - List or create webhook subscriptions
+//! - `GET|PUT|DELETE /api/v1/webhooks/{id}` - Manage a subscription
+//! - `GET /api/v1/webhooks/{id}/deliveries` - Delivery log, newest first
+//! - `POST /api/v1/webhooks/{id}/deliveries/{delivery_id}/redeliver` - Redeliver
+//!
 //! ## Health
-//! - `GET /api/v1/health` - Health check endpoint
+//! - `GET /api/v1/health` - Health check endpoint (same report as `/readyz`)
+//! - `GET /api/v1/livez` - Liveness probe
+//! - `GET /api/v1/readyz` - Readiness probe with dependency checks
+//!
+//! ## Metrics
+//! - `GET /metrics` - Prometheus metrics (when `rest.enable_metrics` is set)
+//!
+//! ## Documentation
+//! - `GET /api/v1/openapi.json` - OpenAPI document
+//! - `GET /api/v1/docs` - Swagger UI (when `rest.enable_swagger_ui` is set)
+//!
+//! # Pagination
+//!
+//! List endpoints accept `page`/`page_size` (offset, capped at
+//! [`handlers::MAX_OFFSET`]) or `cursor`/`limit`. Cursor responses carry a
+//! `next_cursor` to pass back until it is absent; cursors stay stable when
+//! rows are inserted between requests.
+//!
+//! # Decimals
+//!
+//! Prices, quantities and other decimals are JSON strings in requests and
+//! responses, so clients never round them through a double. Requests still
+//! accept JSON numbers for one deprecation cycle; see [`decimal`].
+//!
+//! # Errors
+//!
+//! Error responses carry a stable `code` from [`ErrorCode`]; see
+//! [`errors`] for the mapping from domain and repository errors.
 //!
 //! # Usage
 //!
@@ -48,13 +93,25 @@
 //! axum::serve(listener, router).await?;
 //! ```
 
+pub mod best_execution;
+pub mod decimal;
+pub mod errors;
 pub mod handlers;
+pub mod metrics;
+pub mod openapi;
 pub mod routes;
+pub mod timeline;
+pub mod trade_export;
 
+pub use errors::{ApiError, ErrorCode};
 pub use handlers::{
-    AppState, CreateRfqRequest, ErrorResponse, HealthResponse, MmPerformanceFilter,
-    MmPerformanceResponse, PaginatedResponse, PaginationMeta, PaginationParams, RfqFilter,
-    RfqResponse, TradeFilter, TradeRepository, TradeResponse, UpdateVenueRequest, VenueRepository,
-    VenueResponse,
+    AppState, CreateRfqRequest, CursorParams, DependencyHealthResponse, ErrorResponse,
+    FeeComponentResponse, HealthResponse, InstrumentReferenceDataRequest,
+    InstrumentReferenceDataResponse, MmPerformanceFilter, MmPerformanceResponse, PaginatedResponse,
+    PaginationMeta, PaginationParams, QuoteLegPriceResponse, QuoteResponse, RfqFilter, RfqResponse,
+    RfqTemplateRequest, RfqTemplateResponse, StrategyLegRequest, StrategyLegResponse,
+    StrategyRequest, StrategyResponse, TradeAllocationResponse, TradeFilter, TradeRepository,
+    TradeResponse, UpdateVenueRequest, VenueRepository, VenueResponse,
 };
+pub use openapi::ApiDoc;
 pub use routes::create_router;
@@ -0,0 +1,484 @@
+//! # OpenAPI Specification
+//!
+//! Machine-readable description of the REST API.
+//!
+//! The document is generated at compile time from the `#[utoipa::path]`
+//! annotations on the handlers and the `ToSchema` derives on the DTOs, so it
+//! stays in sync with the actual routes and payloads.
+//!
+//! # Endpoints
+//!
+//! - `GET /api/v1/openapi.json` - OpenAPI 3.1 document (always enabled)
+//! - `GET /api/v1/docs` - Swagger UI (enabled by `rest.enable_swagger_ui`)
+
+use crate::api::rest::best_execution::{
+    BestExecutionExceptionResponse, BestExecutionFormat, BestExecutionReportResponse,
+};
+use crate::api::rest::handlers::{
+    self, AddressChallengeResponse, AggregationReportResponse, AssetClassFeeRateDto,
+    BestPricePointResponse, CircuitAction, CircuitControlRequest, CircuitStatusResponse,
+    CounterConditionsRequest, CounterConditionsResponse, CreateErasureRequest,
+    CreateRfqFromTemplateRequest, CreateRfqRequest, CreateWebhookSubscriptionRequest,
+    DeadLetterResponse, DependencyHealthResponse, ErasureRequestResponse, ErrorResponse,
+    FeeBandDto, FeeComponentResponse, FeeWaiverRequest, FeeWaiverResponse, ForceRfqStateRequest,
+    ForceSettlementStateRequest, ForcedTransitionResponse, HealthResponse,
+    InstrumentReferenceDataRequest, InstrumentReferenceDataResponse, LiquidityResponse,
+    MaintenanceWindowRequest, MaintenanceWindowResponse, MmIncentiveStatusResponse,
+    MmPerformanceResponse, NegotiationAnalyticsResponse, NegotiationResponse,
+    NegotiationRoundResponse, PaginatedResponse, PaginationMeta, PenaltyStatusResponse,
+    PlatformFeeScheduleRequest, PlatformFeeScheduleResponse, PriceBoundsSettingsDto,
+    QuantityDisclosureRequest, QuantityDisclosureResponse, QuoteHistoryItem, QuoteHistoryResponse,
+    QuoteLegPriceResponse, QuoteResponse, RankingExplanationResponse, RedriveDeadLettersRequest,
+    RfqResponse, RfqSummaryResponse, RfqTemplateRequest, RfqTemplateResponse, RfqTtlPolicyDto,
+    RfqWarningResponse, ScoreComponentResponse, SelectQuoteRequest, SettlementAddressRequest,
+    SettlementAddressResponse, SettlementBatchResponse, ShadowVenueReportResponse, SizeModeRequest,
+    SizeModeResponse, StrategyLegRequest, StrategyLegResponse, StrategyRequest, StrategyResponse,
+    SubmitCounterRequest, TokenEntryResponse, TokenRequest, TokenSettlementRequest,
+    TradeAllocationResponse, TradeResponse, TradingCalendarRequest, TradingCalendarResponse,
+    TtlLimitsDto, UpdateVenueRequest, UpdateWebhookSubscriptionRequest, VenueConfigChangeResponse,
+    VenueCredentialSetResponse, VenueCredentialsRequest, VenueExchangeResponse,
+    VenueOutcomeCountsResponse, VenueOutcomeResponse, VenueProbeReport, VenueProbeResponse,
+    VenueResponse, VenueSettingsResponse, VerifyAddressRequest, WebhookDeliveryResponse,
+    WebhookSubscriptionResponse,
+};
+use crate::api::rest::timeline::{TimelineEntry, TimelineFormat};
+use crate::api::rest::trade_export::TradeExportFormat;
+use crate::domain::entities::erasure_request::ErasureRequestStatus;
+use crate::domain::entities::netting_batch::NettingBatchStatus;
+use crate::domain::entities::quote_history::QuoteStatus;
+use crate::domain::entities::trade::{FeeKind, SettlementState};
+use crate::domain::entities::venue::{VenueHealth, VenueMode};
+use crate::domain::value_objects::{
+    CompensationPolicy, FailureCode, MissedBestPriceReason, OrderSide, RfqDirection, RfqState,
+    SettlementCutoff, SettlementWindow, VenueType,
+};
+use axum::{Json, Router, response::Html, routing::get};
+use utoipa::OpenApi;
+
+/// OpenAPI document for the REST API.
+#[derive(Debug, OpenApi)]
+#[openapi(
+    info(
+        title = "OTC RFQ API",
+        description = "REST API for RFQ management, venue configuration, and trade queries."
+    ),
+    paths(
+        handlers::health_check,
+        handlers::liveness_check,
+        handlers::readiness_check,
+        handlers::list_rfqs,
+        handlers::list_rfq_summaries,
+        handlers::get_rfq,
+        handlers::get_rfq_quote_history,
+        handlers::create_rfq,
+        handlers::cancel_rfq,
+        handlers::select_quote,
+        handlers::get_rfq_timeline,
+        handlers::get_rfq_aggregation_report,
+        handlers::get_quote_ranking_explanation,
+        handlers::list_rfq_venue_exchanges,
+        handlers::create_rfq_from_template,
+        handlers::list_rfq_templates,
+        handlers::create_rfq_template,
+        handlers::get_rfq_template,
+        handlers::update_rfq_template,
+        handlers::delete_rfq_template,
+        handlers::list_venues,
+        handlers::probe_venues,
+        handlers::update_venue,
+        handlers::get_venue_history,
+        handlers::get_venue_shadow_report,
+        handlers::rollback_venue_config,
+        handlers::control_venue_circuit,
+        handlers::list_venue_maintenance,
+        handlers::add_venue_maintenance,
+        handlers::remove_venue_maintenance,
+        handlers::list_instrument_reference_data,
+        handlers::get_instrument_reference_data,
+        handlers::put_instrument_reference_data,
+        handlers::delete_instrument_reference_data,
+        handlers::get_instrument_liquidity,
+        handlers::list_trades,
+        handlers::export_trades,
+        handlers::get_trade,
+        handlers::list_trade_allocations,
+        handlers::list_mm_performance,
+        handlers::get_mm_performance,
+        handlers::get_mm_incentive_status,
+        handlers::get_negotiation_analytics,
+        handlers::get_negotiation,
+        handlers::submit_counter,
+        handlers::get_fee_schedule,
+        handlers::get_counterparty_fee_schedule,
+        handlers::list_platform_fee_schedules,
+        handlers::get_platform_fee_schedule,
+        handlers::put_platform_fee_schedule,
+        handlers::delete_platform_fee_schedule,
+        handlers::list_fee_waivers,
+        handlers::get_fee_waiver,
+        handlers::put_fee_waiver,
+        handlers::delete_fee_waiver,
+        handlers::list_settlement_addresses,
+        handlers::add_settlement_address,
+        handlers::delete_settlement_address,
+        handlers::challenge_settlement_address,
+        handlers::verify_settlement_address,
+        handlers::create_erasure_request,
+        handlers::get_erasure_request,
+        handlers::approve_erasure_request,
+        handlers::execute_erasure_request,
+        handlers::list_venue_credentials,
+        handlers::put_venue_credentials,
+        handlers::delete_venue_credentials,
+        handlers::list_tokens,
+        handlers::put_token,
+        handlers::set_token_settlement,
+        handlers::delete_token,
+        handlers::get_price_bounds,
+        handlers::put_price_bounds,
+        handlers::list_settlement_batches,
+        handlers::get_settlement_batch,
+        handlers::cancel_settlement_batch,
+        handlers::list_webhooks,
+        handlers::create_webhook,
+        handlers::get_webhook,
+        handlers::update_webhook,
+        handlers::delete_webhook,
+        handlers::list_webhook_deliveries,
+        handlers::redeliver_webhook,
+        handlers::export_compliance,
+        handlers::get_best_execution_report,
+        handlers::get_rfq_ttl_limits,
+        handlers::put_rfq_ttl_limits,
+        handlers::list_trading_calendars,
+        handlers::get_trading_calendar,
+        handlers::put_trading_calendar,
+        handlers::delete_trading_calendar,
+        handlers::list_dead_letters,
+        handlers::redrive_dead_letters,
+        handlers::force_rfq_state,
+        handlers::force_settlement_state,
+        openapi_json,
+    ),
+    components(schemas(
+        ErrorResponse,
+        CreateRfqRequest,
+        SelectQuoteRequest,
+        StrategyRequest,
+        StrategyLegRequest,
+        SizeModeRequest,
+        QuantityDisclosureRequest,
+        RfqResponse,
+        RfqWarningResponse,
+        QuoteResponse,
+        QuoteLegPriceResponse,
+        QuoteHistoryResponse,
+        QuoteHistoryItem,
+        BestPricePointResponse,
+        QuoteStatus,
+        StrategyResponse,
+        StrategyLegResponse,
+        SizeModeResponse,
+        QuantityDisclosureResponse,
+        RfqTemplateRequest,
+        RfqTemplateResponse,
+        CreateRfqFromTemplateRequest,
+        TradeResponse,
+        FeeComponentResponse,
+        TradeAllocationResponse,
+        VenueResponse,
+        UpdateVenueRequest,
+        VenueSettingsResponse,
+        VenueConfigChangeResponse,
+        ShadowVenueReportResponse,
+        InstrumentReferenceDataResponse,
+        LiquidityResponse,
+        AggregationReportResponse,
+        VenueOutcomeCountsResponse,
+        VenueOutcomeResponse,
+        RankingExplanationResponse,
+        ScoreComponentResponse,
+        InstrumentReferenceDataRequest,
+        MmPerformanceResponse,
+        MmIncentiveStatusResponse,
+        PenaltyStatusResponse,
+        NegotiationAnalyticsResponse,
+        NegotiationResponse,
+        NegotiationRoundResponse,
+        CounterConditionsResponse,
+        SubmitCounterRequest,
+        CounterConditionsRequest,
+        FeeBandDto,
+        AssetClassFeeRateDto,
+        PlatformFeeScheduleRequest,
+        PlatformFeeScheduleResponse,
+        FeeWaiverRequest,
+        FeeWaiverResponse,
+        SettlementAddressRequest,
+        SettlementAddressResponse,
+        AddressChallengeResponse,
+        VerifyAddressRequest,
+        CreateErasureRequest,
+        ErasureRequestResponse,
+        ErasureRequestStatus,
+        VenueCredentialsRequest,
+        VenueCredentialSetResponse,
+        TokenRequest,
+        TokenSettlementRequest,
+        TokenEntryResponse,
+        PriceBoundsSettingsDto,
+        RfqTtlPolicyDto,
+        TtlLimitsDto,
+        TradingCalendarRequest,
+        TradingCalendarResponse,
+        SettlementBatchResponse,
+        PaginationMeta,
+        PaginatedResponse<RfqResponse>,
+        RfqSummaryResponse,
+        PaginatedResponse<RfqSummaryResponse>,
+        PaginatedResponse<TradeResponse>,
+        PaginatedResponse<TradeAllocationResponse>,
+        HealthResponse,
+        DependencyHealthResponse,
+        TimelineEntry,
+        TimelineFormat,
+        BestExecutionReportResponse,
+        BestExecutionExceptionResponse,
+        BestExecutionFormat,
+        MissedBestPriceReason,
+        TradeExportFormat,
+        VenueExchangeResponse,
+        CircuitAction,
+        CircuitControlRequest,
+        CircuitStatusResponse,
+        VenueProbeResponse,
+        VenueProbeReport,
+        MaintenanceWindowRequest,
+        MaintenanceWindowResponse,
+        CreateWebhookSubscriptionRequest,
+        UpdateWebhookSubscriptionRequest,
+        WebhookSubscriptionResponse,
+        WebhookDeliveryResponse,
+        PaginatedResponse<WebhookDeliveryResponse>,
+        DeadLetterResponse,
+        RedriveDeadLettersRequest,
+        ForceRfqStateRequest,
+        ForceSettlementStateRequest,
+        ForcedTransitionResponse,
+        RfqState,
+        FailureCode,
+        SettlementWindow,
+        SettlementCutoff,
+        OrderSide,
+        RfqDirection,
+        SettlementState,
+        NettingBatchStatus,
+        FeeKind,
+        CompensationPolicy,
+        VenueType,
+        VenueHealth,
+        VenueMode,
+    )),
+    tags(
+        (name = "rfqs", description = "RFQ lifecycle"),
+        (name = "rfq-templates", description = "Saved RFQ parameters"),
+        (name = "venues", description = "Venue configuration"),
+        (name = "instruments", description = "Instrument reference data"),
+        (name = "trades", description = "Executed trades"),
+        (name = "mm", description = "Market maker performance and incentives"),
+        (name = "negotiations", description = "Counter-quote negotiation analytics"),
+        (name = "fees", description = "Fee schedules"),
+        (name = "counterparties", description = "Counterparty settlement addresses"),
+        (name = "tokens", description = "Settlement token registry"),
+        (name = "risk", description = "Pre-trade risk controls"),
+        (name = "settlement", description = "Settlement netting batches"),
+        (name = "webhooks", description = "Outbound webhook subscriptions"),
+        (name = "compliance", description = "Regulator exports"),
+        (name = "reports", description = "Client execution reports"),
+        (name = "calendars", description = "Trading calendars and holidays"),
+        (name = "dead-letters", description = "Events parked by failing event consumers"),
+        (name = "admin", description = "Audited overrides for stuck RFQs and settlements"),
+        (name = "health", description = "Service health"),
+        (name = "docs", description = "API documentation"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Returns the OpenAPI document.
+#[utoipa::path(
+    get,
+    path = "/api/v1/openapi.json",
+    tag = "docs",
+    responses((status = 200, description = "OpenAPI document", content_type = "application/json"))
+)]
+pub async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+/// Serves a Swagger UI page pointed at [`openapi_json`].
+pub async fn swagger_ui() -> Html<&'static str> {
+    Html(SWAGGER_UI_HTML)
+}
+
+/// Returns a router serving the Swagger UI at `/api/v1/docs`.
+///
+/// Merged into the main router only when `rest.enable_swagger_ui` is set.
+pub fn swagger_ui_router<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new().route("/api/v1/docs", get(swagger_ui))
+}
+
+const SWAGGER_UI_HTML: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8" />
+  <title>OTC RFQ API</title>
+  <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css" />
+</head>
+<body>
+  <div id="swagger-ui"></div>
+  <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js" crossorigin></script>
+  <script>
+    window.onload = () => {
+      window.ui = SwaggerUIBundle({ url: "/api/v1/openapi.json", dom_id: "#swagger-ui" });
+    };
+  </script>
+</body>
+</html>
+"##;
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn document() -> Value {
+        let json = ApiDoc::openapi().to_json().unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn create_rfq_path_with_client_errors() {
+        let doc = document();
+        let post = &doc["paths"]["/api/v1/rfqs"]["post"];
+        assert!(post.is_object());
+        let responses = post["responses"].as_object().unwrap();
+        assert!(responses.contains_key("201"));
+        assert!(responses.contains_key("400"));
+        assert!(responses.contains_key("422"));
+        assert_eq!(
+            post["responses"]["400"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/ErrorResponse"
+        );
+    }
+
+    #[test]
+    fn all_routes_registered() {
+        let doc = document();
+        let paths = doc["paths"].as_object().unwrap();
+        for path in [
+            "/api/v1/health",
+            "/api/v1/livez",
+            "/api/v1/readyz",
+            "/api/v1/rfqs",
+            "/api/v1/rfqs/summary",
+            "/api/v1/rfqs/{id}",
+            "/api/v1/rfqs/{id}/quotes",
+            "/api/v1/rfqs/{id}/quotes/{quote_id}/ranking-explanation",
+            "/api/v1/rfqs/{id}/select",
+            "/api/v1/rfqs/{id}/timeline",
+            "/api/v1/rfqs/{id}/venue-exchanges",
+            "/api/v1/rfqs/from-template/{template_id}",
+            "/api/v1/rfq-templates",
+            "/api/v1/rfq-templates/{id}",
+            "/api/v1/venues",
+            "/api/v1/venues/probe",
+            "/api/v1/venues/{id}",
+            "/api/v1/venues/{id}/history",
+            "/api/v1/venues/{id}/rollback/{history_id}",
+            "/api/v1/venues/{id}/circuit",
+            "/api/v1/venues/{id}/maintenance",
+            "/api/v1/venues/{id}/maintenance/{start}",
+            "/api/v1/instruments",
+            "/api/v1/instruments/{base}/{quote}",
+            "/api/v1/trades",
+            "/api/v1/trades/export",
+            "/api/v1/trades/{id}",
+            "/api/v1/trades/{id}/allocations",
+            "/api/v1/mm-performance",
+            "/api/v1/mm-performance/{mm_id}",
+            "/api/v1/negotiations/analytics",
+            "/api/v1/mm/{mm_id}/incentive-status",
+            "/api/v1/fees/schedule",
+            "/api/v1/fees/schedule/{counterparty_id}",
+            "/api/v1/fees/platform-schedules",
+            "/api/v1/fees/platform-schedules/{tier}",
+            "/api/v1/fees/waivers",
+            "/api/v1/fees/waivers/{counterparty_id}",
+            "/api/v1/settlement-batches",
+            "/api/v1/settlement-batches/{id}",
+            "/api/v1/settlement-batches/{id}/cancel",
+            "/api/v1/counterparties/{id}/erasure-requests",
+            "/api/v1/erasure-requests/{id}",
+            "/api/v1/erasure-requests/{id}/approve",
+            "/api/v1/erasure-requests/{id}/execute",
+            "/api/v1/counterparties/{id}/venue-credentials",
+            "/api/v1/counterparties/{id}/venue-credentials/{venue_id}",
+            "/api/v1/webhooks",
+            "/api/v1/webhooks/{id}",
+            "/api/v1/webhooks/{id}/deliveries",
+            "/api/v1/webhooks/{id}/deliveries/{delivery_id}/redeliver",
+            "/api/v1/compliance/export",
+            "/api/v1/dead-letters",
+            "/api/v1/admin/rfqs/{id}/force-state",
+            "/api/v1/admin/trades/{id}/force-state",
+            "/api/v1/openapi.json",
+        ] {
+            assert!(paths.contains_key(path), "missing {path}");
+        }
+    }
+
+    fn enum_values(doc: &Value, name: &str) -> Vec<String> {
+        doc["components"]["schemas"][name]["enum"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_str().unwrap().to_string())
+            .collect()
+    }
+
+    fn serde_name<T: serde::Serialize>(value: T) -> String {
+        serde_json::to_value(value)
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn enums_render_as_serde_strings() {
+        let doc = document();
+
+        let states = enum_values(&doc, "RfqState");
+        assert!(states.contains(&serde_name(RfqState::QuoteRequesting)));
+        assert!(states.contains(&"QUOTES_RECEIVED".to_string()));
+
+        let sides = enum_values(&doc, "OrderSide");
+        assert_eq!(
+            sides,
+            vec![serde_name(OrderSide::Buy), serde_name(OrderSide::Sell)]
+        );
+        assert!(enum_values(&doc, "RfqDirection").contains(&"TWO_WAY".to_string()));
+
+        let settlement = enum_values(&doc, "SettlementState");
+        assert!(settlement.contains(&serde_name(SettlementState::InProgress)));
+        assert!(settlement.contains(&"IN_PROGRESS".to_string()));
+
+        let compensation = enum_values(&doc, "CompensationPolicy");
+        assert!(compensation.contains(&serde_name(CompensationPolicy::UnwindAll)));
+        assert!(compensation.contains(&"UNWIND_ALL".to_string()));
+    }
+}
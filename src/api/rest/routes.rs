@@ -9,20 +9,83 @@
 //!
 //! ```text
 //! /api/v1
-//! ├── /health              GET  - Health check
+//! ├── /health              GET  - Health check (same as /readyz)
+//! ├── /livez               GET  - Liveness probe
+//! ├── /readyz              GET  - Readiness probe with dependency checks
+//! ├── /openapi.json        GET  - OpenAPI document
 //! ├── /rfqs                GET  - List RFQs
 //! │   ├── /                POST - Create RFQ
+//! │   ├── /from-template/{template_id}  POST - Create RFQ from a template
+//! │   ├── /summary         GET  - List RFQ dashboard summaries
 //! │   └── /{id}            GET  - Get RFQ by ID
-//! │       └── /            DELETE - Cancel RFQ
+//! │       ├── /            DELETE - Cancel RFQ
+//! │       ├── /select      POST - Select a quote, firming it up if indicative
+//! │       ├── /timeline    GET  - Audit timeline of the RFQ
+//! │       ├── /aggregation-report  GET  - Per-venue quote aggregation outcomes
+//! │       ├── /quotes/{quote_id}/ranking-explanation  GET  - Explain a quote's rank
+//! │       └── /venue-exchanges  GET  - Raw venue payloads of the RFQ (admin)
 //! ├── /venues              GET  - List venues
+//! │   ├── /probe           POST - Probe venue health now (admin)
 //! │   └── /{id}            PUT  - Update venue config
+//! │       ├── /history     GET  - Venue config change history
+//! │       ├── /shadow-report  GET  - How often the venue's quotes ranked best (admin)
+//! │       ├── /circuit     POST - Override the venue's circuit breaker (admin)
+//! │       ├── /maintenance GET/POST - List or schedule maintenance windows (admin to change)
+//! │       │   └── /{start} DELETE - Remove a maintenance window (admin)
+//! │       └── /rollback/{history_id}  POST - Roll back a config change
+//! ├── /rfq-templates       GET  - List the caller's RFQ templates
+//! │   ├── /                POST - Create template
+//! │   └── /{id}            GET/PUT/DELETE - Manage a template
+//! ├── /instruments         GET  - List instrument reference data
+//! │   └── /{base}/{quote}  GET/PUT/DELETE - Manage reference data
+//! │       └── /liquidity   GET  - Liquidity tier derived from recent activity
 //! ├── /trades              GET  - List trades
+//! │   ├── /export          GET  - Export trades as CSV or Parquet
 //! │   └── /{id}            GET  - Get trade by ID
+//! │       └── /allocations GET  - List the trade's multi-MM allocations
 //! ├── /mm-performance      GET  - List MM performance metrics
 //! │   └── /{mm_id}         GET  - Get MM performance by ID
 //! ├── /mm/{mm_id}/incentive-status  GET  - Get MM incentive status
-//! └── /fees/schedule       GET  - Get base fee schedule
-//!     └── /{counterparty_id}  GET  - Get counterparty fee schedule
+//! ├── /negotiations/analytics  GET  - Negotiation price improvement analytics
+//! ├── /negotiations/{id}   GET  - Get a negotiation with its rounds (participants)
+//! │   └── /counters        POST - Counter, optionally with validity and settlement terms
+//! ├── /fees/schedule       GET  - Get base fee schedule
+//! │   └── /{counterparty_id}  GET  - Get counterparty fee schedule
+//! ├── /fees/platform-schedules  GET  - List platform fee schedules (admin)
+//! │   └── /{tier}          GET/PUT/DELETE - Manage a tier's schedule (admin)
+//! ├── /fees/waivers        GET  - List fee waivers (admin)
+//! │   └── /{counterparty_id}  GET/PUT/DELETE - Manage a waiver (admin)
+//! ├── /counterparties/{id}/addresses  GET/POST - List or register settlement addresses
+//! │   └── /{chain}         DELETE - Remove an address (`?token=` selects a token's address)
+//! │       ├── /challenge   POST - Issue a verification challenge
+//! │       └── /verify      POST - Verify with the signed challenge
+//! ├── /counterparties/{id}/erasure-requests  POST - Request an erasure (admin)
+//! ├── /counterparties/{id}/venue-credentials  GET  - List venues with client credentials
+//! │   └── /{venue_id}      PUT/DELETE - Store, rotate or remove credentials
+//! ├── /erasure-requests/{id}  GET  - Get an erasure request (admin)
+//! │   ├── /approve         POST - Approve, by someone other than the requester (admin)
+//! │   └── /execute         POST - Pseudonymize the counterparty (admin)
+//! ├── /tokens              GET  - List token deployments (`?chain=` filters)
+//! │   └── /{symbol}        PUT/DELETE - Register, replace or remove a token (admin)
+//! │       └── /settlement  POST - Enable or disable settlement in a token (admin)
+//! ├── /risk/price-bounds   GET/PUT - Get or change price bounds tolerances (admin)
+//! ├── /settlement-batches  GET  - List netting batches (admin)
+//! │   └── /{id}            GET  - Get a netting batch (admin)
+//! │       └── /cancel      POST - Cancel an unsubmitted batch (admin)
+//! ├── /webhooks            GET  - List webhook subscriptions (admin)
+//! │   ├── /                POST - Create subscription
+//! │   └── /{id}            GET/PUT/DELETE - Manage a subscription
+//! │       ├── /deliveries  GET  - Delivery log, newest first
+//! │       └── /deliveries/{delivery_id}/redeliver  POST - Redeliver
+//! ├── /compliance/export   GET  - Regulator export bundle (admin)
+//! ├── /reports/best-execution  GET - Best execution report of a client (JSON or CSV)
+//! ├── /rfq-ttl-limits      GET/PUT - Get or change RFQ TTL limits by asset class (admin)
+//! ├── /trading-calendars   GET  - List trading calendars (admin)
+//! │   └── /{name}          GET/PUT/DELETE - Manage a calendar and its holidays (admin)
+//! ├── /dead-letters        GET/POST - List or re-drive events parked by failing consumers (admin)
+//! └── /admin
+//!     ├── /rfqs/{id}/force-state    POST - Force a stuck execution's outcome (admin)
+//!     └── /trades/{id}/force-state  POST - Force a stuck settlement's outcome (admin)
 //! ```
 //!
 //! # Examples
@@ -40,11 +103,35 @@
 //! ```
 
 use crate::api::rest::handlers::{
-    AppState, cancel_rfq, create_rfq, get_counterparty_fee_schedule, get_fee_schedule,
-    get_mm_incentive_status, get_mm_performance, get_rfq, get_trade, health_check,
-    list_mm_performance, list_rfqs, list_trades, list_venues, update_venue,
+    AppState, add_settlement_address, add_venue_maintenance, approve_erasure_request, cancel_rfq,
+    cancel_settlement_batch, challenge_settlement_address, control_venue_circuit,
+    create_erasure_request, create_rfq, create_rfq_from_template, create_rfq_template,
+    create_webhook, delete_fee_waiver, delete_instrument_reference_data,
+    delete_platform_fee_schedule, delete_rfq_template, delete_settlement_address, delete_token,
+    delete_trading_calendar, delete_venue_credentials, delete_webhook, execute_erasure_request,
+    export_compliance, export_trades, force_rfq_state, force_settlement_state,
+    get_best_execution_report, get_counterparty_fee_schedule, get_erasure_request,
+    get_fee_schedule, get_fee_waiver, get_instrument_liquidity, get_instrument_reference_data,
+    get_mm_incentive_status, get_mm_performance, get_negotiation, get_negotiation_analytics,
+    get_platform_fee_schedule, get_price_bounds, get_quote_ranking_explanation, get_rfq,
+    get_rfq_aggregation_report, get_rfq_quote_history, get_rfq_template, get_rfq_timeline,
+    get_rfq_ttl_limits, get_settlement_batch, get_trade, get_trading_calendar, get_venue_history,
+    get_venue_shadow_report, get_webhook, health_check, list_dead_letters, list_fee_waivers,
+    list_instrument_reference_data, list_mm_performance, list_platform_fee_schedules,
+    list_rfq_summaries, list_rfq_templates, list_rfq_venue_exchanges, list_rfqs,
+    list_settlement_addresses, list_settlement_batches, list_tokens, list_trade_allocations,
+    list_trades, list_trading_calendars, list_venue_credentials, list_venue_maintenance,
+    list_venues, list_webhook_deliveries, list_webhooks, liveness_check, probe_venues,
+    put_fee_waiver, put_instrument_reference_data, put_platform_fee_schedule, put_price_bounds,
+    put_rfq_ttl_limits, put_token, put_trading_calendar, put_venue_credentials, readiness_check,
+    redeliver_webhook, redrive_dead_letters, remove_venue_maintenance, rollback_venue_config,
+    select_quote, set_token_settlement, submit_counter, update_rfq_template, update_venue,
+    update_webhook, verify_settlement_address,
 };
-use axum::{Router, routing::get, routing::put};
+use crate::api::rest::openapi::openapi_json;
+use crate::infrastructure::telemetry;
+use axum::extract::Request;
+use axum::{Router, routing::delete, routing::get, routing::post, routing::put};
 use std::sync::Arc;
 use tower::ServiceBuilder;
 use tower_http::cors::{Any, CorsLayer};
@@ -73,17 +160,67 @@ pub fn create_router(state: Arc<AppState>) -> Router {
     // RFQ routes
     let rfq_routes = Router::new()
         .route("/", get(list_rfqs).post(create_rfq))
-        .route("/{id}", get(get_rfq).delete(cancel_rfq));
+        .route("/summary", get(list_rfq_summaries))
+        .route("/{id}", get(get_rfq).delete(cancel_rfq))
+        .route("/{id}/quotes", get(get_rfq_quote_history))
+        .route("/{id}/select", post(select_quote))
+        .route("/{id}/timeline", get(get_rfq_timeline))
+        .route("/{id}/aggregation-report", get(get_rfq_aggregation_report))
+        .route(
+            "/{id}/quotes/{quote_id}/ranking-explanation",
+            get(get_quote_ranking_explanation),
+        )
+        .route("/{id}/venue-exchanges", get(list_rfq_venue_exchanges))
+        .route(
+            "/from-template/{template_id}",
+            post(create_rfq_from_template),
+        );
 
     // Venue routes
     let venue_routes = Router::new()
         .route("/", get(list_venues))
-        .route("/{id}", put(update_venue));
+        .route("/probe", post(probe_venues))
+        .route("/{id}", put(update_venue))
+        .route("/{id}/history", get(get_venue_history))
+        .route("/{id}/shadow-report", get(get_venue_shadow_report))
+        .route("/{id}/circuit", post(control_venue_circuit))
+        .route(
+            "/{id}/maintenance",
+            get(list_venue_maintenance).post(add_venue_maintenance),
+        )
+        .route(
+            "/{id}/maintenance/{start}",
+            delete(remove_venue_maintenance),
+        )
+        .route("/{id}/rollback/{history_id}", post(rollback_venue_config));
+
+    // RFQ template routes
+    let rfq_template_routes = Router::new()
+        .route("/", get(list_rfq_templates).post(create_rfq_template))
+        .route(
+            "/{id}",
+            get(get_rfq_template)
+                .put(update_rfq_template)
+                .delete(delete_rfq_template),
+        );
+
+    // Instrument reference data routes
+    let instrument_routes = Router::new()
+        .route("/", get(list_instrument_reference_data))
+        .route(
+            "/{base}/{quote}",
+            get(get_instrument_reference_data)
+                .put(put_instrument_reference_data)
+                .delete(delete_instrument_reference_data),
+        )
+        .route("/{base}/{quote}/liquidity", get(get_instrument_liquidity));
 
     // Trade routes
     let trade_routes = Router::new()
         .route("/", get(list_trades))
-        .route("/{id}", get(get_trade));
+        .route("/export", get(export_trades))
+        .route("/{id}", get(get_trade))
+        .route("/{id}/allocations", get(list_trade_allocations));
 
     // MM Performance routes
     let mm_performance_routes = Router::new()
@@ -94,23 +231,135 @@ pub fn create_router(state: Arc<AppState>) -> Router {
     let mm_incentive_routes =
         Router::new().route("/{mm_id}/incentive-status", get(get_mm_incentive_status));
 
+    // Negotiation routes
+    let negotiation_routes = Router::new()
+        .route("/analytics", get(get_negotiation_analytics))
+        .route("/{id}", get(get_negotiation))
+        .route("/{id}/counters", post(submit_counter));
+
     // Fee schedule routes
     let fee_routes = Router::new()
         .route("/schedule", get(get_fee_schedule))
         .route(
             "/schedule/{counterparty_id}",
             get(get_counterparty_fee_schedule),
+        )
+        .route("/platform-schedules", get(list_platform_fee_schedules))
+        .route(
+            "/platform-schedules/{tier}",
+            get(get_platform_fee_schedule)
+                .put(put_platform_fee_schedule)
+                .delete(delete_platform_fee_schedule),
+        )
+        .route("/waivers", get(list_fee_waivers))
+        .route(
+            "/waivers/{counterparty_id}",
+            get(get_fee_waiver)
+                .put(put_fee_waiver)
+                .delete(delete_fee_waiver),
+        );
+
+    // Counterparty settlement address routes
+    let counterparty_routes = Router::new()
+        .route(
+            "/{id}/addresses",
+            get(list_settlement_addresses).post(add_settlement_address),
+        )
+        .route("/{id}/addresses/{chain}", delete(delete_settlement_address))
+        .route(
+            "/{id}/addresses/{chain}/challenge",
+            post(challenge_settlement_address),
+        )
+        .route(
+            "/{id}/addresses/{chain}/verify",
+            post(verify_settlement_address),
+        )
+        .route("/{id}/erasure-requests", post(create_erasure_request))
+        .route("/{id}/venue-credentials", get(list_venue_credentials))
+        .route(
+            "/{id}/venue-credentials/{venue_id}",
+            put(put_venue_credentials).delete(delete_venue_credentials),
+        );
+
+    // Counterparty erasure routes
+    let erasure_routes = Router::new()
+        .route("/{id}", get(get_erasure_request))
+        .route("/{id}/approve", post(approve_erasure_request))
+        .route("/{id}/execute", post(execute_erasure_request));
+
+    // Admin override routes
+    let admin_routes = Router::new()
+        .route("/rfqs/{id}/force-state", post(force_rfq_state))
+        .route("/trades/{id}/force-state", post(force_settlement_state));
+
+    // Token registry routes
+    let token_routes = Router::new()
+        .route("/", get(list_tokens))
+        .route("/{symbol}", put(put_token).delete(delete_token))
+        .route("/{symbol}/settlement", post(set_token_settlement));
+
+    // Risk control routes
+    let risk_routes =
+        Router::new().route("/price-bounds", get(get_price_bounds).put(put_price_bounds));
+
+    // Settlement batch routes
+    let settlement_batch_routes = Router::new()
+        .route("/", get(list_settlement_batches))
+        .route("/{id}", get(get_settlement_batch))
+        .route("/{id}/cancel", post(cancel_settlement_batch));
+
+    // Webhook routes
+    let webhook_routes = Router::new()
+        .route("/", get(list_webhooks).post(create_webhook))
+        .route(
+            "/{id}",
+            get(get_webhook).put(update_webhook).delete(delete_webhook),
+        )
+        .route("/{id}/deliveries", get(list_webhook_deliveries))
+        .route(
+            "/{id}/deliveries/{delivery_id}/redeliver",
+            post(redeliver_webhook),
         );
 
     // API v1 routes
     let api_v1 = Router::new()
         .route("/health", get(health_check))
+        .route("/livez", get(liveness_check))
+        .route("/readyz", get(readiness_check))
+        .route("/openapi.json", get(openapi_json))
         .nest("/rfqs", rfq_routes)
+        .nest("/rfq-templates", rfq_template_routes)
         .nest("/venues", venue_routes)
+        .nest("/instruments", instrument_routes)
         .nest("/trades", trade_routes)
         .nest("/mm-performance", mm_performance_routes)
         .nest("/mm", mm_incentive_routes)
-        .nest("/fees", fee_routes);
+        .nest("/negotiations", negotiation_routes)
+        .nest("/fees", fee_routes)
+        .nest("/counterparties", counterparty_routes)
+        .nest("/erasure-requests", erasure_routes)
+        .nest("/admin", admin_routes)
+        .nest("/tokens", token_routes)
+        .nest("/risk", risk_routes)
+        .nest("/settlement-batches", settlement_batch_routes)
+        .nest("/webhooks", webhook_routes)
+        .route("/compliance/export", get(export_compliance))
+        .route("/reports/best-execution", get(get_best_execution_report))
+        .route(
+            "/rfq-ttl-limits",
+            get(get_rfq_ttl_limits).put(put_rfq_ttl_limits),
+        )
+        .route("/trading-calendars", get(list_trading_calendars))
+        .route(
+            "/trading-calendars/{name}",
+            get(get_trading_calendar)
+                .put(put_trading_calendar)
+                .delete(delete_trading_calendar),
+        )
+        .route(
+            "/dead-letters",
+            get(list_dead_letters).post(redrive_dead_letters),
+        );
 
     // Main router with middleware
     Router::new()
@@ -119,9 +368,7 @@ pub fn create_router(state: Arc<AppState>) -> Router {
             ServiceBuilder::new()
                 .layer(
                     TraceLayer::new_for_http()
-                        .make_span_with(
-                            tower_http::trace::DefaultMakeSpan::new().level(tracing::Level::INFO),
-                        )
+                        .make_span_with(make_request_span)
                         .on_request(
                             tower_http::trace::DefaultOnRequest::new().level(tracing::Level::INFO),
                         )
@@ -139,6 +386,19 @@ pub fn create_router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+/// Creates the span for an inbound request, continuing the caller's trace
+/// when a W3C `traceparent` header is present.
+fn make_request_span(request: &Request) -> tracing::Span {
+    let span = tracing::info_span!(
+        "request",
+        method = %request.method(),
+        uri = %request.uri(),
+        version = ?request.version(),
+    );
+    telemetry::set_remote_parent(&span, request.headers());
+    span
+}
+
 /// Creates a minimal router for testing without middleware.
 ///
 /// This is useful for unit tests where you don't need tracing or CORS.
@@ -146,15 +406,63 @@ pub fn create_router(state: Arc<AppState>) -> Router {
 pub fn create_test_router(state: Arc<AppState>) -> Router {
     let rfq_routes = Router::new()
         .route("/", get(list_rfqs).post(create_rfq))
-        .route("/{id}", get(get_rfq).delete(cancel_rfq));
+        .route("/summary", get(list_rfq_summaries))
+        .route("/{id}", get(get_rfq).delete(cancel_rfq))
+        .route("/{id}/quotes", get(get_rfq_quote_history))
+        .route("/{id}/select", post(select_quote))
+        .route("/{id}/timeline", get(get_rfq_timeline))
+        .route("/{id}/aggregation-report", get(get_rfq_aggregation_report))
+        .route(
+            "/{id}/quotes/{quote_id}/ranking-explanation",
+            get(get_quote_ranking_explanation),
+        )
+        .route("/{id}/venue-exchanges", get(list_rfq_venue_exchanges))
+        .route(
+            "/from-template/{template_id}",
+            post(create_rfq_from_template),
+        );
 
     let venue_routes = Router::new()
         .route("/", get(list_venues))
-        .route("/{id}", put(update_venue));
+        .route("/probe", post(probe_venues))
+        .route("/{id}", put(update_venue))
+        .route("/{id}/history", get(get_venue_history))
+        .route("/{id}/shadow-report", get(get_venue_shadow_report))
+        .route("/{id}/circuit", post(control_venue_circuit))
+        .route(
+            "/{id}/maintenance",
+            get(list_venue_maintenance).post(add_venue_maintenance),
+        )
+        .route(
+            "/{id}/maintenance/{start}",
+            delete(remove_venue_maintenance),
+        )
+        .route("/{id}/rollback/{history_id}", post(rollback_venue_config));
+
+    let rfq_template_routes = Router::new()
+        .route("/", get(list_rfq_templates).post(create_rfq_template))
+        .route(
+            "/{id}",
+            get(get_rfq_template)
+                .put(update_rfq_template)
+                .delete(delete_rfq_template),
+        );
+
+    let instrument_routes = Router::new()
+        .route("/", get(list_instrument_reference_data))
+        .route(
+            "/{base}/{quote}",
+            get(get_instrument_reference_data)
+                .put(put_instrument_reference_data)
+                .delete(delete_instrument_reference_data),
+        )
+        .route("/{base}/{quote}/liquidity", get(get_instrument_liquidity));
 
     let trade_routes = Router::new()
         .route("/", get(list_trades))
-        .route("/{id}", get(get_trade));
+        .route("/export", get(export_trades))
+        .route("/{id}", get(get_trade))
+        .route("/{id}/allocations", get(list_trade_allocations));
 
     let mm_performance_routes = Router::new()
         .route("/", get(list_mm_performance))
@@ -163,35 +471,158 @@ pub fn create_test_router(state: Arc<AppState>) -> Router {
     let mm_incentive_routes =
         Router::new().route("/{mm_id}/incentive-status", get(get_mm_incentive_status));
 
+    let negotiation_routes = Router::new()
+        .route("/analytics", get(get_negotiation_analytics))
+        .route("/{id}", get(get_negotiation))
+        .route("/{id}/counters", post(submit_counter));
+
     let fee_routes = Router::new()
         .route("/schedule", get(get_fee_schedule))
         .route(
             "/schedule/{counterparty_id}",
             get(get_counterparty_fee_schedule),
+        )
+        .route("/platform-schedules", get(list_platform_fee_schedules))
+        .route(
+            "/platform-schedules/{tier}",
+            get(get_platform_fee_schedule)
+                .put(put_platform_fee_schedule)
+                .delete(delete_platform_fee_schedule),
+        )
+        .route("/waivers", get(list_fee_waivers))
+        .route(
+            "/waivers/{counterparty_id}",
+            get(get_fee_waiver)
+                .put(put_fee_waiver)
+                .delete(delete_fee_waiver),
+        );
+
+    // Counterparty settlement address routes
+    let counterparty_routes = Router::new()
+        .route(
+            "/{id}/addresses",
+            get(list_settlement_addresses).post(add_settlement_address),
+        )
+        .route("/{id}/addresses/{chain}", delete(delete_settlement_address))
+        .route(
+            "/{id}/addresses/{chain}/challenge",
+            post(challenge_settlement_address),
+        )
+        .route(
+            "/{id}/addresses/{chain}/verify",
+            post(verify_settlement_address),
+        )
+        .route("/{id}/erasure-requests", post(create_erasure_request))
+        .route("/{id}/venue-credentials", get(list_venue_credentials))
+        .route(
+            "/{id}/venue-credentials/{venue_id}",
+            put(put_venue_credentials).delete(delete_venue_credentials),
+        );
+
+    // Counterparty erasure routes
+    let erasure_routes = Router::new()
+        .route("/{id}", get(get_erasure_request))
+        .route("/{id}/approve", post(approve_erasure_request))
+        .route("/{id}/execute", post(execute_erasure_request));
+
+    // Admin override routes
+    let admin_routes = Router::new()
+        .route("/rfqs/{id}/force-state", post(force_rfq_state))
+        .route("/trades/{id}/force-state", post(force_settlement_state));
+
+    // Token registry routes
+    let token_routes = Router::new()
+        .route("/", get(list_tokens))
+        .route("/{symbol}", put(put_token).delete(delete_token))
+        .route("/{symbol}/settlement", post(set_token_settlement));
+
+    // Risk control routes
+    let risk_routes =
+        Router::new().route("/price-bounds", get(get_price_bounds).put(put_price_bounds));
+
+    // Settlement batch routes
+    let settlement_batch_routes = Router::new()
+        .route("/", get(list_settlement_batches))
+        .route("/{id}", get(get_settlement_batch))
+        .route("/{id}/cancel", post(cancel_settlement_batch));
+
+    // Webhook routes
+    let webhook_routes = Router::new()
+        .route("/", get(list_webhooks).post(create_webhook))
+        .route(
+            "/{id}",
+            get(get_webhook).put(update_webhook).delete(delete_webhook),
+        )
+        .route("/{id}/deliveries", get(list_webhook_deliveries))
+        .route(
+            "/{id}/deliveries/{delivery_id}/redeliver",
+            post(redeliver_webhook),
         );
 
     let api_v1 = Router::new()
         .route("/health", get(health_check))
+        .route("/livez", get(liveness_check))
+        .route("/readyz", get(readiness_check))
+        .route("/openapi.json", get(openapi_json))
         .nest("/rfqs", rfq_routes)
+        .nest("/rfq-templates", rfq_template_routes)
         .nest("/venues", venue_routes)
+        .nest("/instruments", instrument_routes)
         .nest("/trades", trade_routes)
         .nest("/mm-performance", mm_performance_routes)
         .nest("/mm", mm_incentive_routes)
-        .nest("/fees", fee_routes);
+        .nest("/negotiations", negotiation_routes)
+        .nest("/fees", fee_routes)
+        .nest("/counterparties", counterparty_routes)
+        .nest("/erasure-requests", erasure_routes)
+        .nest("/admin", admin_routes)
+        .nest("/tokens", token_routes)
+        .nest("/risk", risk_routes)
+        .nest("/settlement-batches", settlement_batch_routes)
+        .nest("/webhooks", webhook_routes)
+        .route("/compliance/export", get(export_compliance))
+        .route("/reports/best-execution", get(get_best_execution_report))
+        .route(
+            "/rfq-ttl-limits",
+            get(get_rfq_ttl_limits).put(put_rfq_ttl_limits),
+        )
+        .route("/trading-calendars", get(list_trading_calendars))
+        .route(
+            "/trading-calendars/{name}",
+            get(get_trading_calendar)
+                .put(put_trading_calendar)
+                .delete(delete_trading_calendar),
+        )
+        .route(
+            "/dead-letters",
+            get(list_dead_letters).post(redrive_dead_letters),
+        );
 
     Router::new().nest("/api/v1", api_v1).with_state(state)
 }
 
 #[cfg(test)]
-#[allow(clippy::unwrap_used)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
 mod tests {
     use super::*;
-    use crate::api::rest::handlers::{TradeFilter, TradeRepository, VenueRepository};
+    use crate::api::rest::handlers::{
+        ErrorResponse, TradeFilter, TradeRepository, VenueRepository,
+    };
     use crate::application::use_cases::create_rfq::RfqRepository;
+    use crate::domain::entities::quote::{Quote, QuoteBuilder, QuoteFirmness};
     use crate::domain::entities::rfq::Rfq;
     use crate::domain::entities::trade::Trade;
     use crate::domain::entities::venue::Venue;
-    use crate::domain::value_objects::{RfqId, TradeId, VenueId};
+    use crate::domain::entities::venue_config_change::{VenueConfigChange, VenueSettings};
+    use crate::domain::value_objects::timestamp::Timestamp;
+    use crate::domain::value_objects::{
+        CounterpartyId, DuplicateRfqPolicy, FailureCode, FailureReason, Price, Quantity, QuoteId,
+        RfqId, RfqState, TradeId, VenueId,
+    };
+    use crate::infrastructure::persistence::cursor::{paginate, paginate_offset};
+    use crate::infrastructure::persistence::{
+        PageCursor, RfqListFilter, RfqSummary, RfqSummaryStore,
+    };
     use async_trait::async_trait;
     use axum::body::Body;
     use axum::http::{Request, StatusCode};
@@ -214,11 +645,68 @@ mod tests {
         async fn find_by_id(&self, id: RfqId) -> Result<Option<Rfq>, String> {
             Ok(self.rfqs.read().unwrap().get(&id).cloned())
         }
+
+        async fn list_after(
+            &self,
+            cursor: Option<&PageCursor>,
+            limit: usize,
+            filter: &RfqListFilter,
+        ) -> Result<Vec<Rfq>, String> {
+            let rfqs = self
+                .rfqs
+                .read()
+                .unwrap()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .cloned()
+                .collect();
+            Ok(paginate(rfqs, cursor, limit, PageCursor::from_rfq))
+        }
+
+        async fn list_offset(
+            &self,
+            offset: usize,
+            limit: usize,
+            filter: &RfqListFilter,
+        ) -> Result<Vec<Rfq>, String> {
+            let rfqs = self
+                .rfqs
+                .read()
+                .unwrap()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .cloned()
+                .collect();
+            Ok(paginate_offset(rfqs, offset, limit, PageCursor::from_rfq))
+        }
+
+        async fn count_matching(&self, filter: &RfqListFilter) -> Result<u64, String> {
+            let count = self
+                .rfqs
+                .read()
+                .unwrap()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .count();
+            Ok(count as u64)
+        }
     }
 
     #[derive(Debug, Default)]
     struct MockVenueRepository {
         venues: RwLock<HashMap<String, Venue>>,
+        history: RwLock<Vec<VenueConfigChange>>,
+    }
+
+    #[async_trait]
+    impl crate::application::services::ProbedVenueRepository for MockVenueRepository {
+        async fn find_by_id(&self, id: &VenueId) -> Result<Option<Venue>, String> {
+            VenueRepository::find_by_id(self, id).await
+        }
+
+        async fn save(&self, venue: &Venue) -> Result<(), String> {
+            VenueRepository::save(self, venue).await
+        }
     }
 
     #[async_trait]
@@ -238,6 +726,44 @@ mod tests {
                 .insert(venue.id().to_string(), venue.clone());
             Ok(())
         }
+
+        async fn save_with_history(
+            &self,
+            venue: &Venue,
+            changed_by: &str,
+            rollback_of: Option<u64>,
+        ) -> Result<VenueConfigChange, String> {
+            let mut venues = self.venues.write().unwrap();
+            let mut history = self.history.write().unwrap();
+            let previous = venues
+                .get(venue.id().as_str())
+                .ok_or_else(|| format!("venue not found: {}", venue.id()))?;
+
+            let change = VenueConfigChange::from_parts(
+                history.len() as u64 + 1,
+                venue.id().clone(),
+                VenueSettings::of(previous),
+                VenueSettings::of(venue),
+                changed_by.to_string(),
+                Timestamp::now(),
+                rollback_of,
+            );
+            venues.insert(venue.id().to_string(), venue.clone());
+            history.push(change.clone());
+            Ok(change)
+        }
+
+        async fn history(&self, id: &VenueId) -> Result<Vec<VenueConfigChange>, String> {
+            Ok(self
+                .history
+                .read()
+                .unwrap()
+                .iter()
+                .rev()
+                .filter(|change| change.venue_id() == id)
+                .cloned()
+                .collect())
+        }
     }
 
     #[derive(Debug, Default)]
@@ -247,13 +773,54 @@ mod tests {
 
     #[async_trait]
     impl TradeRepository for MockTradeRepository {
-        async fn find_all(&self, _filter: &TradeFilter) -> Result<Vec<Trade>, String> {
-            Ok(self.trades.read().unwrap().values().cloned().collect())
+        async fn find_all(&self, filter: &TradeFilter) -> Result<Vec<Trade>, String> {
+            Ok(self
+                .trades
+                .read()
+                .unwrap()
+                .values()
+                .filter(|t| filter.matches(t))
+                .cloned()
+                .collect())
         }
 
         async fn find_by_id(&self, id: TradeId) -> Result<Option<Trade>, String> {
             Ok(self.trades.read().unwrap().get(&id).cloned())
         }
+
+        async fn list_after(
+            &self,
+            cursor: Option<&PageCursor>,
+            limit: usize,
+            filter: &TradeFilter,
+        ) -> Result<Vec<Trade>, String> {
+            let trades = self
+                .trades
+                .read()
+                .unwrap()
+                .values()
+                .filter(|trade| filter.matches(trade))
+                .cloned()
+                .collect();
+            Ok(paginate(trades, cursor, limit, PageCursor::from_trade))
+        }
+
+        async fn count(
+            &self,
+            filter: &TradeFilter,
+            from: Option<Timestamp>,
+            to: Option<Timestamp>,
+        ) -> Result<u64, String> {
+            use crate::api::rest::trade_export::created_within;
+
+            Ok(self
+                .trades
+                .read()
+                .unwrap()
+                .values()
+                .filter(|trade| filter.matches(trade) && created_within(trade, from, to))
+                .count() as u64)
+        }
     }
 
     fn create_test_state() -> Arc<AppState> {
@@ -264,6 +831,37 @@ mod tests {
             mm_performance_tracker: None,
             mm_incentive_service: None,
             fee_engine: None,
+            instrument_reference_data: None,
+            event_store: None,
+            shutdown: None,
+            readiness: None,
+            firm_up: None,
+            rfq_templates: None,
+            negotiations: None,
+            venue_exchanges: None,
+            circuit_breakers: None,
+            rfq_summaries: None,
+            webhooks: None,
+            platform_fee_schedules: None,
+            fee_waivers: None,
+            netting: None,
+            venue_prober: None,
+            compliance_export: None,
+            rfq_cancellations: None,
+            venue_request_gate: None,
+            settlement_addresses: None,
+            counterparty_erasure: None,
+            venue_credentials: None,
+            duplicate_rfqs: None,
+            forced_transitions: None,
+            tokens: None,
+            price_bounds: None,
+            liquidity: None,
+            aggregation_reports: None,
+            best_execution: None,
+            rfq_ttl: None,
+            dead_letters: None,
+            trading_calendars: None,
         })
     }
 
@@ -285,6 +883,145 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[derive(Debug)]
+    struct FailingPoolCheck;
+
+    #[async_trait]
+    impl crate::application::services::DependencyCheck for FailingPoolCheck {
+        fn name(&self) -> &str {
+            "postgres"
+        }
+
+        async fn check(&self) -> Result<(), String> {
+            Err("pool timed out while waiting for an open connection".to_string())
+        }
+    }
+
+    fn create_test_state_with_readiness(
+        readiness: crate::application::services::ReadinessChecker,
+    ) -> Arc<AppState> {
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.readiness = Some(Arc::new(readiness));
+        Arc::new(state)
+    }
+
+    #[tokio::test]
+    async fn livez_passes_when_dependencies_fail() {
+        use crate::application::services::ReadinessChecker;
+
+        let state = create_test_state_with_readiness(
+            ReadinessChecker::new().with_dependency(Arc::new(FailingPoolCheck)),
+        );
+        let router = create_test_router(state);
+
+        let (status, body) =
+            send_json(router, "GET", "/api/v1/livez", serde_json::Value::Null).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "pass");
+    }
+
+    #[tokio::test]
+    async fn readyz_returns_503_with_dependency_map_when_repository_fails() {
+        use crate::application::services::ReadinessChecker;
+
+        let state = create_test_state_with_readiness(
+            ReadinessChecker::new().with_dependency(Arc::new(FailingPoolCheck)),
+        );
+        let router = create_test_router(state);
+
+        let (status, body) =
+            send_json(router, "GET", "/api/v1/readyz", serde_json::Value::Null).await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["status"], "fail");
+        assert_eq!(body["checks"]["postgres"]["status"], "fail");
+        assert_eq!(
+            body["checks"]["postgres"]["error"],
+            "pool timed out while waiting for an open connection"
+        );
+    }
+
+    #[derive(Debug)]
+    struct PingVenue {
+        venue_id: VenueId,
+        healthy: bool,
+    }
+
+    #[async_trait]
+    impl crate::infrastructure::venues::VenueAdapter for PingVenue {
+        fn venue_id(&self) -> &VenueId {
+            &self.venue_id
+        }
+
+        fn timeout_ms(&self) -> u64 {
+            1000
+        }
+
+        async fn request_quote(
+            &self,
+            _rfq: &Rfq,
+        ) -> crate::infrastructure::venues::VenueResult<crate::domain::entities::quote::Quote>
+        {
+            Err(crate::infrastructure::venues::VenueError::internal_error(
+                "not quoting",
+            ))
+        }
+
+        async fn execute_trade(
+            &self,
+            _quote: &crate::domain::entities::quote::Quote,
+        ) -> crate::infrastructure::venues::VenueResult<
+            crate::infrastructure::venues::ExecutionResult,
+        > {
+            Err(crate::infrastructure::venues::VenueError::internal_error(
+                "not executing",
+            ))
+        }
+
+        async fn health_check(
+            &self,
+        ) -> crate::infrastructure::venues::VenueResult<crate::infrastructure::venues::VenueHealth>
+        {
+            use crate::infrastructure::venues::VenueHealth;
+
+            if self.healthy {
+                Ok(VenueHealth::healthy(self.venue_id.clone()))
+            } else {
+                Ok(VenueHealth::unhealthy(self.venue_id.clone(), "unreachable"))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn readyz_warns_on_venue_failure_with_enough_healthy_venues() {
+        use crate::application::services::ReadinessChecker;
+        use crate::infrastructure::venues::VenueRegistry;
+
+        let registry = VenueRegistry::new();
+        for (id, healthy) in [("venue-up", true), ("venue-down", false)] {
+            registry
+                .register(Arc::new(PingVenue {
+                    venue_id: VenueId::new(id),
+                    healthy,
+                }))
+                .await;
+        }
+        let state = create_test_state_with_readiness(
+            ReadinessChecker::new().with_venues(Arc::new(registry)),
+        );
+        let router = create_test_router(state);
+
+        let (status, body) =
+            send_json(router, "GET", "/api/v1/readyz", serde_json::Value::Null).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "warn");
+        assert_eq!(body["checks"]["venue:venue-up"]["status"], "pass");
+        assert_eq!(body["checks"]["venue:venue-down"]["status"], "warn");
+        assert_eq!(body["checks"]["venue:venue-down"]["error"], "unreachable");
+    }
+
     #[tokio::test]
     async fn list_rfqs_endpoint() {
         let state = create_test_state();
@@ -433,6 +1170,130 @@ mod tests {
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
+    #[tokio::test]
+    async fn create_rfq_refused_while_shutting_down() {
+        use crate::application::services::ShutdownCoordinator;
+
+        let shutdown = ShutdownCoordinator::default();
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.shutdown = Some(shutdown.clone());
+        let router = create_test_router(Arc::new(state));
+        shutdown.drain().await;
+
+        let body = serde_json::json!({
+            "client_id": "client-123",
+            "base_asset": "BTC",
+            "quote_asset": "USD",
+            "side": "BUY",
+            "quantity": 1.5,
+            "expiry_seconds": 300
+        });
+        let (status, body) = send_json(router, "POST", "/api/v1/rfqs", body).await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["code"], "SHUTTING_DOWN");
+    }
+
+    fn duplicate_check_router(policy: DuplicateRfqPolicy) -> Router {
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.duplicate_rfqs = Some(Arc::new(policy));
+        create_test_router(Arc::new(state))
+    }
+
+    fn btc_rfq_body(side: &str, quantity: &str) -> serde_json::Value {
+        serde_json::json!({
+            "client_id": "client-123",
+            "base_asset": "BTC",
+            "quote_asset": "USD",
+            "side": side,
+            "quantity": quantity,
+            "expiry_seconds": 300
+        })
+    }
+
+    #[tokio::test]
+    async fn create_rfq_warns_about_near_duplicate() {
+        let router = duplicate_check_router(DuplicateRfqPolicy::default());
+
+        let (status, first) = send_json(
+            router.clone(),
+            "POST",
+            "/api/v1/rfqs",
+            btc_rfq_body("BUY", "10"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(first.get("warnings").is_none());
+
+        let (status, second) = send_json(
+            router.clone(),
+            "POST",
+            "/api/v1/rfqs",
+            btc_rfq_body("BUY", "10.5"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let warnings = second["warnings"].as_array().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0]["code"], "POSSIBLE_DUPLICATE");
+        assert_eq!(warnings[0]["rfq_id"], first["id"]);
+
+        // Outside the similarity band
+        let (status, distinct) =
+            send_json(router, "POST", "/api/v1/rfqs", btc_rfq_body("BUY", "20")).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(distinct.get("warnings").is_none());
+    }
+
+    #[tokio::test]
+    async fn create_rfq_does_not_warn_about_opposite_side() {
+        let router = duplicate_check_router(DuplicateRfqPolicy::default());
+
+        let (status, _) = send_json(
+            router.clone(),
+            "POST",
+            "/api/v1/rfqs",
+            btc_rfq_body("BUY", "10"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, sell) =
+            send_json(router, "POST", "/api/v1/rfqs", btc_rfq_body("SELL", "10")).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(sell.get("warnings").is_none());
+    }
+
+    #[tokio::test]
+    async fn create_rfq_refuses_near_duplicate_when_counterparty_blocks() {
+        let router = duplicate_check_router(
+            DuplicateRfqPolicy::default().with_blocking(CounterpartyId::new("client-123")),
+        );
+
+        let (status, first) = send_json(
+            router.clone(),
+            "POST",
+            "/api/v1/rfqs",
+            btc_rfq_body("BUY", "10"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, body) = send_json(
+            router.clone(),
+            "POST",
+            "/api/v1/rfqs",
+            btc_rfq_body("BUY", "10"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "DUPLICATE");
+        assert_eq!(
+            body["details"]["conflicting_rfq_ids"],
+            serde_json::json!([first["id"]])
+        );
+    }
+
     #[tokio::test]
     async fn get_mm_incentive_status_returns_501_when_service_disabled() {
         let state = create_test_state();
@@ -500,6 +1361,37 @@ mod tests {
             mm_performance_tracker: None,
             mm_incentive_service: None,
             fee_engine: Some(Arc::new(FeeEngine::default_with_noop())),
+            instrument_reference_data: None,
+            event_store: None,
+            shutdown: None,
+            readiness: None,
+            firm_up: None,
+            rfq_templates: None,
+            negotiations: None,
+            venue_exchanges: None,
+            circuit_breakers: None,
+            rfq_summaries: None,
+            webhooks: None,
+            platform_fee_schedules: None,
+            fee_waivers: None,
+            netting: None,
+            venue_prober: None,
+            compliance_export: None,
+            rfq_cancellations: None,
+            venue_request_gate: None,
+            settlement_addresses: None,
+            counterparty_erasure: None,
+            venue_credentials: None,
+            duplicate_rfqs: None,
+            forced_transitions: None,
+            tokens: None,
+            price_bounds: None,
+            liquidity: None,
+            aggregation_reports: None,
+            best_execution: None,
+            rfq_ttl: None,
+            dead_letters: None,
+            trading_calendars: None,
         })
     }
 
@@ -538,4 +1430,5026 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::OK);
     }
+
+    // ------------------------------------------------------------------------
+    // Error codes
+    // ------------------------------------------------------------------------
+
+    #[derive(Debug, Default)]
+    struct FailingVenueRepository;
+
+    #[async_trait]
+    impl VenueRepository for FailingVenueRepository {
+        async fn find_all(&self) -> Result<Vec<Venue>, String> {
+            Err("connection reset".to_string())
+        }
+
+        async fn find_by_id(&self, _id: &VenueId) -> Result<Option<Venue>, String> {
+            Err("connection reset".to_string())
+        }
+
+        async fn save(&self, _venue: &Venue) -> Result<(), String> {
+            Err("connection reset".to_string())
+        }
+
+        async fn save_with_history(
+            &self,
+            _venue: &Venue,
+            _changed_by: &str,
+            _rollback_of: Option<u64>,
+        ) -> Result<VenueConfigChange, String> {
+            Err("connection reset".to_string())
+        }
+
+        async fn history(&self, _id: &VenueId) -> Result<Vec<VenueConfigChange>, String> {
+            Err("connection reset".to_string())
+        }
+    }
+
+    fn create_test_rfq() -> Rfq {
+        use crate::domain::entities::rfq::RfqBuilder;
+        use crate::domain::value_objects::enums::AssetClass;
+        use crate::domain::value_objects::timestamp::Timestamp;
+        use crate::domain::value_objects::{
+            CounterpartyId, Instrument, OrderSide, Quantity, Symbol,
+        };
+
+        let symbol = Symbol::new("BTC/USD").unwrap();
+        let instrument = Instrument::builder(symbol, AssetClass::CryptoSpot).build();
+        RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build()
+    }
+
+    async fn send(router: Router, method: &str, uri: &str) -> (StatusCode, ErrorResponse) {
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn get_rfq_invalid_id_returns_validation_error_code() {
+        let router = create_test_router(create_test_state());
+
+        let (status, body) = send(router, "GET", "/api/v1/rfqs/not-a-uuid").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body.code, "VALIDATION_ERROR");
+    }
+
+    #[tokio::test]
+    async fn get_rfq_missing_returns_rfq_not_found_code() {
+        let router = create_test_router(create_test_state());
+
+        let (status, body) = send(
+            router,
+            "GET",
+            "/api/v1/rfqs/550e8400-e29b-41d4-a716-446655440000",
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body.code, "RFQ_NOT_FOUND");
+        assert_eq!(
+            body.details.unwrap()["id"],
+            "550e8400-e29b-41d4-a716-446655440000"
+        );
+    }
+
+    #[tokio::test]
+    async fn cancel_cancelled_rfq_returns_rfq_invalid_state_code() {
+        let repo = Arc::new(MockRfqRepository::default());
+        let mut rfq = create_test_rfq();
+        rfq.cancel().unwrap();
+        repo.save(&rfq).await.unwrap();
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.rfq_repository = repo;
+        let router = create_test_router(Arc::new(state));
+
+        let (status, body) = send(router, "DELETE", &format!("/api/v1/rfqs/{}", rfq.id())).await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body.code, "RFQ_INVALID_STATE");
+        let details = body.details.unwrap();
+        assert_eq!(details["from"], "CANCELLED");
+        assert_eq!(details["to"], "CANCELLED");
+    }
+
+    #[tokio::test]
+    async fn cancel_rfq_notifies_venues_that_quoted() {
+        use crate::application::services::RfqCancellationService;
+
+        let (state, rfq_id, _) = create_test_state_with_indicative_quote(100.0).await;
+        let mut state = Arc::into_inner(state).unwrap();
+        let venue = Arc::new(FirmingVenue {
+            venue_id: VenueId::new("venue-1"),
+            firm_price: 100.0,
+            cancelled: RwLock::new(Vec::new()),
+        });
+        state.rfq_cancellations = Some(Arc::new(RfqCancellationService::new(Arc::new(
+            FirmingVenueRegistry(Arc::clone(&venue)),
+        ))));
+        let router = create_test_router(Arc::new(state));
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri(format!("/api/v1/rfqs/{rfq_id}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(*venue.cancelled.read().unwrap(), vec![rfq_id]);
+    }
+
+    #[tokio::test]
+    async fn get_trade_missing_returns_not_found_code() {
+        let router = create_test_router(create_test_state());
+
+        let (status, body) = send(
+            router,
+            "GET",
+            "/api/v1/trades/550e8400-e29b-41d4-a716-446655440000",
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body.code, "NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn list_venues_repository_failure_returns_internal_error_code() {
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.venue_repository = Arc::new(FailingVenueRepository);
+        let router = create_test_router(Arc::new(state));
+
+        let (status, body) = send(router, "GET", "/api/v1/venues").await;
+
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body.code, "INTERNAL_ERROR");
+    }
+
+    #[tokio::test]
+    async fn disabled_fee_engine_returns_not_implemented_code() {
+        let router = create_test_router(create_test_state());
+
+        let (status, body) = send(router, "GET", "/api/v1/fees/schedule").await;
+
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+        assert_eq!(body.code, "NOT_IMPLEMENTED");
+    }
+
+    // ------------------------------------------------------------------------
+    // Instrument reference data
+    // ------------------------------------------------------------------------
+
+    fn create_test_state_with_reference_data() -> Arc<AppState> {
+        use crate::infrastructure::persistence::in_memory::InMemoryInstrumentReferenceDataRepository;
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.instrument_reference_data =
+            Some(Arc::new(InMemoryInstrumentReferenceDataRepository::new()));
+        Arc::new(state)
+    }
+
+    async fn send_json(
+        router: Router,
+        method: &str,
+        uri: &str,
+        body: serde_json::Value,
+    ) -> (StatusCode, serde_json::Value) {
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(serde_json::to_string(&body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let value = if bytes.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_slice(&bytes).unwrap()
+        };
+        (status, value)
+    }
+
+    #[tokio::test]
+    async fn instrument_reference_data_crud() {
+        let router = create_test_router(create_test_state_with_reference_data());
+        let body = serde_json::json!({
+            "tick_size": "0.5",
+            "lot_size": "0.01",
+            "min_order_size": "0.1",
+            "max_order_size": "100"
+        });
+
+        let (status, saved) =
+            send_json(router.clone(), "PUT", "/api/v1/instruments/btc/usd", body).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(saved["symbol"], "BTC/USD");
+        assert_eq!(saved["lot_size"], "0.01");
+        assert_eq!(saved["contract_multiplier"], "1");
+
+        let (status, fetched) = send_json(
+            router.clone(),
+            "GET",
+            "/api/v1/instruments/BTC/USD",
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(fetched, saved);
+
+        let (status, listed) = send_json(
+            router.clone(),
+            "GET",
+            "/api/v1/instruments",
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(listed.as_array().unwrap().len(), 1);
+
+        let (status, _) = send_json(
+            router.clone(),
+            "DELETE",
+            "/api/v1/instruments/BTC/USD",
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, body) = send(router, "GET", "/api/v1/instruments/BTC/USD").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body.code, "NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn put_instrument_reference_data_rejects_invalid_values() {
+        let router = create_test_router(create_test_state_with_reference_data());
+
+        let (status, body) = send_json(
+            router.clone(),
+            "PUT",
+            "/api/v1/instruments/BTC/USD",
+            serde_json::json!({ "tick_size": "abc", "lot_size": "0.01" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+
+        let (status, _) = send_json(
+            router,
+            "PUT",
+            "/api/v1/instruments/BTC/USD",
+            serde_json::json!({ "tick_size": "0", "lot_size": "0.01" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    fn straddle_rfq_body() -> serde_json::Value {
+        serde_json::json!({
+            "client_id": "client-123",
+            "strategy": {
+                "strategy_type": "STRADDLE",
+                "underlying": "BTC",
+                "legs": [
+                    { "base_asset": "BTC", "quote_asset": "USD", "side": "BUY", "ratio": 1 },
+                    { "base_asset": "BTC", "quote_asset": "USD", "side": "BUY", "ratio": 1 }
+                ]
+            },
+            "side": "BUY",
+            "quantity": 2.0,
+            "expiry_seconds": 300
+        })
+    }
+
+    #[tokio::test]
+    async fn create_straddle_rfq_persists_strategy() {
+        let router = create_test_router(create_test_state());
+
+        let (status, created) =
+            send_json(router.clone(), "POST", "/api/v1/rfqs", straddle_rfq_body()).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["symbol"], "BTC/USD");
+        assert_eq!(created["strategy"]["strategy_type"], "STRADDLE");
+
+        let uri = format!("/api/v1/rfqs/{}", created["id"].as_str().unwrap());
+        let (status, reloaded) = send_json(router, "GET", &uri, serde_json::Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(reloaded["strategy"]["underlying"], "BTC");
+        assert_eq!(reloaded["strategy"]["legs"].as_array().unwrap().len(), 2);
+        assert_eq!(reloaded["strategy"]["legs"][0]["side"], "BUY");
+    }
+
+    #[tokio::test]
+    async fn create_rfq_rejects_invalid_strategy() {
+        let router = create_test_router(create_test_state());
+
+        let mut one_leg = straddle_rfq_body();
+        one_leg["strategy"]["legs"]
+            .as_array_mut()
+            .unwrap()
+            .truncate(1);
+        let (status, body) = send_json(router.clone(), "POST", "/api/v1/rfqs", one_leg).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+
+        let mut both = straddle_rfq_body();
+        both["base_asset"] = serde_json::json!("BTC");
+        both["quote_asset"] = serde_json::json!("USD");
+        let (status, _) = send_json(router, "POST", "/api/v1/rfqs", both).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    fn size_mode_rfq_body(size_mode: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "client_id": "client-123",
+            "base_asset": "BTC",
+            "quote_asset": "USD",
+            "side": "BUY",
+            "quantity": 10.0,
+            "expiry_seconds": 300,
+            "size_mode": size_mode
+        })
+    }
+
+    #[tokio::test]
+    async fn create_rfq_size_mode_round_trips() {
+        let router = create_test_router(create_test_state());
+
+        let modes = [
+            serde_json::json!({ "type": "ALL_OR_NOTHING" }),
+            serde_json::json!({ "type": "FILL_OR_KILL" }),
+            serde_json::json!({ "type": "MIN_QUANTITY", "min_quantity": "4" }),
+            serde_json::json!({ "type": "BEST_EFFORT" }),
+        ];
+        for expected in modes {
+            let mut request = expected.clone();
+            if let Some(min) = request.get_mut("min_quantity") {
+                *min = serde_json::json!(4.0);
+            }
+            let (status, created) = send_json(
+                router.clone(),
+                "POST",
+                "/api/v1/rfqs",
+                size_mode_rfq_body(request),
+            )
+            .await;
+            assert_eq!(status, StatusCode::CREATED);
+            assert_eq!(created["size_mode"], expected);
+
+            let uri = format!("/api/v1/rfqs/{}", created["id"].as_str().unwrap());
+            let (status, reloaded) =
+                send_json(router.clone(), "GET", &uri, serde_json::Value::Null).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(reloaded["size_mode"], expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn create_rfq_defaults_to_all_or_nothing() {
+        let router = create_test_router(create_test_state());
+
+        let mut body = size_mode_rfq_body(serde_json::Value::Null);
+        body.as_object_mut().unwrap().remove("size_mode");
+        let (status, created) = send_json(router, "POST", "/api/v1/rfqs", body).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["size_mode"]["type"], "ALL_OR_NOTHING");
+    }
+
+    #[tokio::test]
+    async fn create_rfq_rejects_invalid_min_quantity() {
+        let router = create_test_router(create_test_state());
+
+        for min in [0.0, -1.0, 10.5] {
+            let body = size_mode_rfq_body(
+                serde_json::json!({ "type": "MIN_QUANTITY", "min_quantity": min }),
+            );
+            let (status, body) = send_json(router.clone(), "POST", "/api/v1/rfqs", body).await;
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "min {min}");
+            assert_eq!(body["code"], "INVALID_MIN_QUANTITY");
+        }
+    }
+
+    #[tokio::test]
+    async fn create_rfq_persists_venue_lists() {
+        let router = create_test_router(create_test_state_with_venue().await);
+
+        let mut body = size_mode_rfq_body(serde_json::Value::Null);
+        body["venue_allowlist"] = serde_json::json!(["venue-1", "venue-2"]);
+        body["venue_blocklist"] = serde_json::json!(["venue-2"]);
+        let (status, created) = send_json(router.clone(), "POST", "/api/v1/rfqs", body).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let uri = format!("/api/v1/rfqs/{}", created["id"].as_str().unwrap());
+        let (_, reloaded) = send_json(router, "GET", &uri, serde_json::Value::Null).await;
+        assert_eq!(
+            reloaded["venue_allowlist"],
+            serde_json::json!(["venue-1", "venue-2"])
+        );
+        assert_eq!(reloaded["venue_blocklist"], serde_json::json!(["venue-2"]));
+    }
+
+    #[tokio::test]
+    async fn create_rfq_rejects_venue_lists_with_no_eligible_venue() {
+        let router = create_test_router(create_test_state_with_venue().await);
+
+        let mut blocked = size_mode_rfq_body(serde_json::Value::Null);
+        blocked["venue_blocklist"] = serde_json::json!(["venue-1"]);
+        let mut unknown = size_mode_rfq_body(serde_json::Value::Null);
+        unknown["venue_allowlist"] = serde_json::json!(["venue-9"]);
+        let mut empty = size_mode_rfq_body(serde_json::Value::Null);
+        empty["venue_allowlist"] = serde_json::json!([]);
+
+        for body in [blocked, unknown, empty] {
+            let (status, body) = send_json(router.clone(), "POST", "/api/v1/rfqs", body).await;
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+            assert_eq!(body["code"], "NO_ELIGIBLE_VENUES");
+        }
+    }
+
+    #[tokio::test]
+    async fn scheduled_rfq_is_listed_until_activation() {
+        use crate::domain::value_objects::Timestamp;
+
+        let router = create_test_router(create_test_state());
+        let activate_at = Timestamp::now().add_secs(3600).to_string();
+
+        let mut scheduled = size_mode_rfq_body(serde_json::Value::Null);
+        scheduled["expiry_seconds"] = serde_json::json!(7200);
+        scheduled["activate_at"] = serde_json::json!(activate_at);
+        let (status, created) = send_json(router.clone(), "POST", "/api/v1/rfqs", scheduled).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["state"], "CREATED");
+        assert!(created["activate_at"].is_string());
+
+        let (status, _) = send_json(
+            router.clone(),
+            "POST",
+            "/api/v1/rfqs",
+            size_mode_rfq_body(serde_json::Value::Null),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, body) = get_json(router, "/api/v1/rfqs?status=scheduled").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            page_ids(&body),
+            vec![created["id"].as_str().unwrap().to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn create_rfq_rejects_expiry_before_activation() {
+        use crate::domain::value_objects::Timestamp;
+
+        let router = create_test_router(create_test_state());
+
+        let mut body = size_mode_rfq_body(serde_json::Value::Null);
+        body["activate_at"] = serde_json::json!(Timestamp::now().add_secs(3600).to_string());
+        let (status, body) = send_json(router, "POST", "/api/v1/rfqs", body).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+    }
+
+    #[tokio::test]
+    async fn create_rfq_applies_compensation_policy() {
+        let state = create_test_state();
+
+        let (status, body) = send_json(
+            create_test_router(Arc::clone(&state)),
+            "POST",
+            "/api/v1/rfqs",
+            size_mode_rfq_body(serde_json::Value::Null),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["compensation_policy"], "REOFFER_REMAINDER");
+
+        let mut request = size_mode_rfq_body(serde_json::json!({ "type": "BEST_EFFORT" }));
+        request["compensation_policy"] = serde_json::json!("ACCEPT_PARTIAL");
+        let (status, body) = send_json(
+            create_test_router(Arc::clone(&state)),
+            "POST",
+            "/api/v1/rfqs",
+            request,
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["compensation_policy"], "ACCEPT_PARTIAL");
+
+        let mut request = size_mode_rfq_body(serde_json::Value::Null);
+        request["compensation_policy"] = serde_json::json!("ACCEPT_PARTIAL");
+        let (status, body) =
+            send_json(create_test_router(state), "POST", "/api/v1/rfqs", request).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+    }
+
+    #[tokio::test]
+    async fn create_rfq_applies_quantity_disclosure() {
+        let router = create_test_router(create_test_state());
+
+        let mut request = size_mode_rfq_body(serde_json::Value::Null);
+        request["quantity_disclosure"] = serde_json::json!({ "type": "BUCKETED", "bucket": 25.0 });
+        let (status, created) = send_json(router.clone(), "POST", "/api/v1/rfqs", request).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(
+            created["quantity_disclosure"],
+            serde_json::json!({ "type": "BUCKETED", "bucket": "25" })
+        );
+
+        let uri = format!("/api/v1/rfqs/{}", created["id"].as_str().unwrap());
+        let (_, reloaded) = send_json(router.clone(), "GET", &uri, serde_json::Value::Null).await;
+        assert_eq!(
+            reloaded["quantity_disclosure"],
+            created["quantity_disclosure"]
+        );
+
+        let (_, defaulted) = send_json(
+            router.clone(),
+            "POST",
+            "/api/v1/rfqs",
+            size_mode_rfq_body(serde_json::Value::Null),
+        )
+        .await;
+        assert_eq!(defaulted["quantity_disclosure"]["type"], "EXACT");
+
+        let mut zero = size_mode_rfq_body(serde_json::Value::Null);
+        zero["quantity_disclosure"] = serde_json::json!({ "type": "BUCKETED", "bucket": 0.0 });
+        let (status, body) = send_json(router, "POST", "/api/v1/rfqs", zero).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+    }
+
+    fn decimal_rfq_body(quantity: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "client_id": "client-123",
+            "base_asset": "ETH",
+            "quote_asset": "USD",
+            "side": "BUY",
+            "quantity": quantity,
+            "expiry_seconds": 300,
+            "quantity_disclosure": { "type": "BUCKETED", "bucket": "2000000" }
+        })
+    }
+
+    #[tokio::test]
+    async fn create_rfq_keeps_eighteen_decimal_quantities_exact() {
+        let router = create_test_router(create_test_state());
+        let quantity = "1234567.123456789012345678";
+
+        let (status, created) = send_json(
+            router.clone(),
+            "POST",
+            "/api/v1/rfqs",
+            decimal_rfq_body(serde_json::json!(quantity)),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["quantity"], quantity);
+
+        let uri = format!("/api/v1/rfqs/{}", created["id"].as_str().unwrap());
+        let (status, reloaded) = send_json(router, "GET", &uri, serde_json::Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(reloaded["quantity"], quantity);
+        assert_eq!(reloaded["quantity_disclosure"]["bucket"], "2000000");
+    }
+
+    #[tokio::test]
+    async fn create_rfq_still_accepts_numeric_decimals() {
+        let router = create_test_router(create_test_state());
+
+        let (status, created) = send_json(
+            router.clone(),
+            "POST",
+            "/api/v1/rfqs",
+            decimal_rfq_body(serde_json::json!(2.5)),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["quantity"], "2.5");
+
+        let mut body = decimal_rfq_body(serde_json::json!(2.5));
+        body["quantity_disclosure"]["bucket"] = serde_json::json!(5);
+        let (status, created) = send_json(router.clone(), "POST", "/api/v1/rfqs", body).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["quantity_disclosure"]["bucket"], "5");
+
+        let (status, error) = send_json(
+            router,
+            "POST",
+            "/api/v1/rfqs",
+            decimal_rfq_body(serde_json::json!("2.5 ETH")),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(error["code"], "VALIDATION_ERROR");
+    }
+
+    #[tokio::test]
+    async fn create_rfq_accepts_two_way_side() {
+        let router = create_test_router(create_test_state());
+
+        let mut request = size_mode_rfq_body(serde_json::Value::Null);
+        request["side"] = serde_json::json!("TWO_WAY");
+        let (status, created) = send_json(router, "POST", "/api/v1/rfqs", request).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["side"], "TWO_WAY");
+    }
+
+    #[tokio::test]
+    async fn list_rfqs_rejects_unknown_status() {
+        let router = create_test_router(create_test_state());
+
+        let (status, body) = send(router, "GET", "/api/v1/rfqs?status=paused").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body.details.unwrap()["field"], "status");
+    }
+
+    fn state_with_rfq_repository(repo: Arc<MockRfqRepository>) -> Arc<AppState> {
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.rfq_repository = repo;
+        Arc::new(state)
+    }
+
+    #[tokio::test]
+    async fn get_strategy_rfq_exposes_net_premium_quotes() {
+        use crate::domain::entities::quote::{Quote, QuoteLegPrice};
+        use crate::domain::value_objects::{Premium, Price, Quantity, Timestamp};
+
+        let repo = Arc::new(MockRfqRepository::default());
+        let router = create_test_router(state_with_rfq_repository(Arc::clone(&repo)));
+
+        let (status, created) =
+            send_json(router.clone(), "POST", "/api/v1/rfqs", straddle_rfq_body()).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let id = RfqId::new(uuid::Uuid::parse_str(created["id"].as_str().unwrap()).unwrap());
+
+        let mut rfq = repo.find_by_id(id).await.unwrap().unwrap();
+        rfq.start_quote_collection().unwrap();
+        let quote = Quote::new_net_premium(
+            rfq.id(),
+            VenueId::new("deribit"),
+            Premium::new(rust_decimal::Decimal::new(-120, 2)),
+            vec![
+                QuoteLegPrice::new(0, Some(Price::new(2.5).unwrap())),
+                QuoteLegPrice::new(1, None),
+            ],
+            Quantity::new(2.0).unwrap(),
+            Timestamp::now().add_secs(60),
+        )
+        .unwrap();
+        rfq.receive_quote(quote).unwrap();
+        repo.save(&rfq).await.unwrap();
+
+        let uri = format!("/api/v1/rfqs/{id}");
+        let (status, body) = send_json(router, "GET", &uri, serde_json::Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        let quote = &body["quotes"][0];
+        assert_eq!(quote["kind"], "NET_PREMIUM");
+        assert_eq!(quote["net_premium"], "-1.20");
+        assert_eq!(quote["price"], "1.20");
+        assert_eq!(quote["legs"][0]["price"], "2.5");
+        assert!(quote["legs"][1].get("price").is_none());
+    }
+
+    #[tokio::test]
+    async fn get_single_instrument_rfq_exposes_outright_quotes() {
+        use crate::domain::entities::quote::Quote;
+        use crate::domain::value_objects::{Price, Quantity, Timestamp};
+
+        let repo = Arc::new(MockRfqRepository::default());
+        let mut rfq = create_test_rfq();
+        rfq.start_quote_collection().unwrap();
+        let quote = Quote::new(
+            rfq.id(),
+            VenueId::new("binance"),
+            Price::new(50000.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(60),
+        )
+        .unwrap();
+        rfq.receive_quote(quote).unwrap();
+        repo.save(&rfq).await.unwrap();
+        let router = create_test_router(state_with_rfq_repository(repo));
+
+        let uri = format!("/api/v1/rfqs/{}", rfq.id());
+        let (status, body) = send_json(router, "GET", &uri, serde_json::Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        let quote = &body["quotes"][0];
+        assert_eq!(quote["kind"], "OUTRIGHT");
+        assert_eq!(quote["net_premium"], quote["price"]);
+        assert!(quote.get("legs").is_none());
+    }
+
+    #[tokio::test]
+    async fn create_single_instrument_rfq_has_no_strategy() {
+        let router = create_test_router(create_test_state());
+
+        let (status, created) = send_json(
+            router,
+            "POST",
+            "/api/v1/rfqs",
+            serde_json::json!({
+                "client_id": "client-123",
+                "base_asset": "ETH",
+                "quote_asset": "USD",
+                "side": "SELL",
+                "quantity": 1.0,
+                "expiry_seconds": 300
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["symbol"], "ETH/USD");
+        assert!(created.get("strategy").is_none());
+    }
+
+    #[tokio::test]
+    async fn create_rfq_rejects_quantity_off_lot_size() {
+        let router = create_test_router(create_test_state_with_reference_data());
+        send_json(
+            router.clone(),
+            "PUT",
+            "/api/v1/instruments/BTC/USD",
+            serde_json::json!({ "tick_size": "0.5", "lot_size": "0.2" }),
+        )
+        .await;
+
+        let rfq = |quantity: f64| {
+            serde_json::json!({
+                "client_id": "client-123",
+                "base_asset": "BTC",
+                "quote_asset": "USD",
+                "side": "BUY",
+                "quantity": quantity,
+                "expiry_seconds": 300
+            })
+        };
+
+        let (status, body) = send_json(router.clone(), "POST", "/api/v1/rfqs", rfq(1.5)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "INVALID_LOT_SIZE");
+        assert_eq!(body["details"]["nearest_valid"], "1.4");
+
+        let (status, _) = send_json(router, "POST", "/api/v1/rfqs", rfq(1.4)).await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn disabled_reference_data_returns_not_implemented_code() {
+        let router = create_test_router(create_test_state());
+
+        let (status, body) = send(router, "GET", "/api/v1/instruments").await;
+
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+        assert_eq!(body.code, "NOT_IMPLEMENTED");
+    }
+
+    #[tokio::test]
+    async fn openapi_document_endpoint() {
+        let router = create_test_router(create_test_state());
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/openapi.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let doc: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert!(doc["paths"]["/api/v1/rfqs"]["post"]["responses"]["400"].is_object());
+    }
+
+    // ------------------------------------------------------------------------
+    // Cursor pagination
+    // ------------------------------------------------------------------------
+
+    fn create_trade_at(created_at_millis: i64) -> Trade {
+        use crate::domain::entities::trade::SettlementState;
+        use crate::domain::value_objects::timestamp::Timestamp;
+        use crate::domain::value_objects::{Price, Quantity, QuoteId};
+
+        let created_at = Timestamp::from_millis(created_at_millis).unwrap();
+        Trade::from_parts(
+            TradeId::new_v4(),
+            RfqId::new_v4(),
+            QuoteId::new_v4(),
+            VenueId::new("venue-1"),
+            Price::new(100.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            None,
+            SettlementState::Pending,
+            None,
+            None,
+            1,
+            created_at,
+            created_at,
+            None,
+            None,
+            None,
+        )
+    }
+
+    impl MockTradeRepository {
+        fn insert(&self, trade: Trade) {
+            self.trades.write().unwrap().insert(trade.id(), trade);
+        }
+    }
+
+    fn state_with_trades(trades: Arc<MockTradeRepository>) -> Arc<AppState> {
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.trade_repository = trades;
+        Arc::new(state)
+    }
+
+    async fn get_json(router: Router, uri: &str) -> (StatusCode, serde_json::Value) {
+        let response = router
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    fn page_ids(body: &serde_json::Value) -> Vec<String> {
+        body["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["id"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn list_trades_cursor_is_stable_across_inserts() {
+        let base = 1_700_000_000_000;
+        let repo = Arc::new(MockTradeRepository::default());
+        let originals: Vec<Trade> = (0..9).map(|i| create_trade_at(base + i)).collect();
+        for trade in &originals {
+            repo.insert(trade.clone());
+        }
+        let state = state_with_trades(Arc::clone(&repo));
+
+        let mut seen = Vec::new();
+        let mut uri = "/api/v1/trades?limit=3".to_string();
+        for page in 0..3 {
+            let (status, body) = get_json(create_test_router(Arc::clone(&state)), &uri).await;
+            assert_eq!(status, StatusCode::OK);
+            assert!(body.get("pagination").is_none());
+
+            let ids = page_ids(&body);
+            assert_eq!(ids.len(), 3);
+            seen.extend(ids);
+
+            // New trades land between page requests.
+            repo.insert(create_trade_at(base + 100 + page));
+
+            let next_cursor = body["next_cursor"].as_str();
+            if page < 2 {
+                uri = format!("/api/v1/trades?limit=3&cursor={}", next_cursor.unwrap());
+            } else {
+                assert!(next_cursor.is_none());
+            }
+        }
+
+        let mut expected: Vec<String> =
+            originals.iter().rev().map(|t| t.id().to_string()).collect();
+        assert_eq!(seen, expected);
+        seen.sort();
+        seen.dedup();
+        expected.sort();
+        assert_eq!(seen, expected);
+    }
+
+    /// Sends a GET request and returns the status, content type and raw body.
+    async fn get_raw(router: Router, uri: &str) -> (StatusCode, String, Vec<u8>) {
+        let response = router
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get("content-type")
+            .map(|v| v.to_str().unwrap().to_string())
+            .unwrap_or_default();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, content_type, bytes.to_vec())
+    }
+
+    /// Formats `millis` as a query-safe RFC 3339 UTC timestamp.
+    fn rfc3339(millis: i64) -> String {
+        Timestamp::from_millis(millis)
+            .unwrap()
+            .to_string()
+            .replace("+00:00", "Z")
+    }
+
+    #[tokio::test]
+    async fn export_trades_streams_range_across_pages() {
+        let base = 1_700_000_000_000;
+        let repo = Arc::new(MockTradeRepository::default());
+        let trades: Vec<Trade> = (0..1200)
+            .map(|i| create_trade_at(base + i * 1000))
+            .collect();
+        for trade in &trades {
+            repo.insert(trade.clone());
+        }
+        let router = create_test_router(state_with_trades(repo));
+
+        let uri = format!(
+            "/api/v1/trades/export?from={}&to={}",
+            rfc3339(base + 100_000),
+            rfc3339(base + 1_099_000)
+        );
+        let (status, content_type, body) = get_raw(router, &uri).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(content_type, "text/csv; charset=utf-8");
+        let csv = String::from_utf8(body).unwrap();
+        let lines: Vec<&str> = csv.split_terminator("\r\n").collect();
+        assert_eq!(lines.len(), 1001);
+        assert_eq!(
+            lines.first().copied(),
+            Some(crate::api::rest::trade_export::COLUMNS.join(",").as_str())
+        );
+        let first_id = trades.get(1099).unwrap().id().to_string();
+        let last_id = trades.get(100).unwrap().id().to_string();
+        assert!(lines.get(1).unwrap().starts_with(&first_id));
+        assert!(lines.last().unwrap().starts_with(&last_id));
+    }
+
+    #[tokio::test]
+    async fn export_trades_rejects_exports_above_the_cap() {
+        use crate::api::rest::trade_export::MAX_EXPORT_ROWS;
+
+        let base = 1_700_000_000_000;
+        let repo = Arc::new(MockTradeRepository::default());
+        for i in 0..=MAX_EXPORT_ROWS as i64 {
+            repo.insert(create_trade_at(base + i));
+        }
+        let state = state_with_trades(repo);
+
+        let (status, body) = get_json(
+            create_test_router(Arc::clone(&state)),
+            "/api/v1/trades/export",
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "LIMIT_EXCEEDED");
+        assert_eq!(body["details"]["matched"], MAX_EXPORT_ROWS + 1);
+        assert_eq!(body["details"]["limit"], MAX_EXPORT_ROWS);
+
+        // The status is sent before the body is streamed.
+        let response = create_test_router(state)
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/v1/trades/export?from={}", rfc3339(base + 1)))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn export_trades_validates_range_and_format() {
+        let state = state_with_trades(Arc::new(MockTradeRepository::default()));
+
+        let (status, body) = get_json(
+            create_test_router(Arc::clone(&state)),
+            &format!(
+                "/api/v1/trades/export?from={}&to={}",
+                rfc3339(2_000),
+                rfc3339(1_000)
+            ),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["details"]["field"], "from");
+
+        let (status, content_type, body) = get_raw(
+            create_test_router(state),
+            "/api/v1/trades/export?format=parquet",
+        )
+        .await;
+        if cfg!(feature = "parquet") {
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(content_type, "application/vnd.apache.parquet");
+            assert!(body.starts_with(b"PAR1") && body.ends_with(b"PAR1"));
+        } else {
+            assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+        }
+    }
+
+    #[tokio::test]
+    async fn list_rfqs_cursor_pages() {
+        let repo = Arc::new(MockRfqRepository::default());
+        for _ in 0..3 {
+            repo.save(&create_test_rfq()).await.unwrap();
+        }
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.rfq_repository = repo;
+        let state = Arc::new(state);
+
+        let (status, first) = get_json(
+            create_test_router(Arc::clone(&state)),
+            "/api/v1/rfqs?limit=2&client_id=client-1",
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(page_ids(&first).len(), 2);
+
+        let cursor = first["next_cursor"].as_str().unwrap();
+        let (status, second) = get_json(
+            create_test_router(state),
+            &format!("/api/v1/rfqs?limit=2&client_id=client-1&cursor={cursor}"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(page_ids(&second).len(), 1);
+        assert!(second.get("next_cursor").is_none());
+    }
+
+    #[tokio::test]
+    async fn list_trades_tampered_cursor_returns_invalid_cursor() {
+        let repo = Arc::new(MockTradeRepository::default());
+        for i in 0..3 {
+            repo.insert(create_trade_at(1_700_000_000_000 + i));
+        }
+        let state = state_with_trades(repo);
+
+        let (_, body) = get_json(
+            create_test_router(Arc::clone(&state)),
+            "/api/v1/trades?limit=1",
+        )
+        .await;
+        let mut cursor = body["next_cursor"].as_str().unwrap().to_string();
+        cursor.replace_range(0..2, "zz");
+
+        for bad in [cursor.as_str(), "not-base64!", "MTIzNA"] {
+            let (status, body) = send(
+                create_test_router(Arc::clone(&state)),
+                "GET",
+                &format!("/api/v1/trades?cursor={bad}"),
+            )
+            .await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "cursor {bad}");
+            assert_eq!(body.code, "INVALID_CURSOR");
+        }
+    }
+
+    #[tokio::test]
+    async fn list_trades_offset_beyond_cap_returns_validation_error() {
+        let router = create_test_router(create_test_state());
+
+        let (status, body) = send(router, "GET", "/api/v1/trades?page=1002&page_size=10").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body.code, "VALIDATION_ERROR");
+        assert_eq!(body.details.unwrap()["max_offset"], 10_000);
+    }
+
+    // ------------------------------------------------------------------------
+    // RFQ filters
+    // ------------------------------------------------------------------------
+
+    fn create_rfq_with(client: &str, symbol: &str, state: RfqState, created_at: i64) -> Rfq {
+        create_rfq_from_parts(client, symbol, state, None, created_at)
+    }
+
+    fn create_failed_rfq(code: FailureCode, created_at: i64) -> Rfq {
+        let reason = FailureReason::new(code, format!("{code} at {created_at}"));
+        create_rfq_from_parts(
+            "client-1",
+            "BTC/USD",
+            RfqState::Failed,
+            Some(reason),
+            created_at,
+        )
+    }
+
+    fn create_rfq_from_parts(
+        client: &str,
+        symbol: &str,
+        state: RfqState,
+        failure_reason: Option<FailureReason>,
+        created_at: i64,
+    ) -> Rfq {
+        use crate::domain::entities::anonymity::AnonymityLevel;
+        use crate::domain::value_objects::enums::AssetClass;
+        use crate::domain::value_objects::timestamp::Timestamp;
+        use crate::domain::value_objects::{
+            CompensationPolicy, CounterpartyId, Instrument, OrderSide, Quantity,
+            QuantityDisclosure, SizeNegotiationMode, Symbol,
+        };
+
+        let created_at = Timestamp::from_millis(created_at).unwrap();
+        let instrument =
+            Instrument::builder(Symbol::new(symbol).unwrap(), AssetClass::CryptoSpot).build();
+        Rfq::from_parts(
+            RfqId::new_v4(),
+            CounterpartyId::new(client),
+            instrument.into(),
+            OrderSide::Buy,
+            false,
+            Quantity::new(1.0).unwrap(),
+            None,
+            SizeNegotiationMode::default(),
+            AnonymityLevel::default(),
+            false,
+            None,
+            Vec::new(),
+            CompensationPolicy::default(),
+            QuantityDisclosure::default(),
+            state,
+            created_at.add_secs(300),
+            None,
+            Vec::new(),
+            None,
+            None,
+            failure_reason,
+            1,
+            created_at,
+            created_at,
+        )
+    }
+
+    #[tokio::test]
+    async fn list_rfqs_filters_by_failure_code() {
+        let base = 1_700_000_000_000;
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        let repo = Arc::new(MockRfqRepository::default());
+        let timeout = create_failed_rfq(FailureCode::VenueTimeout, base + 1);
+        for rfq in [
+            &timeout,
+            &create_failed_rfq(FailureCode::VenueRejected, base + 2),
+            &create_rfq_with("client-1", "BTC/USD", RfqState::Executed, base + 3),
+        ] {
+            repo.save(rfq).await.unwrap();
+        }
+        state.rfq_repository = repo;
+        let state = Arc::new(state);
+
+        let (status, body) = get_json(
+            create_test_router(Arc::clone(&state)),
+            "/api/v1/rfqs?failure_code=venue_timeout",
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(page_ids(&body), vec![timeout.id().to_string()]);
+
+        let (status, body) = get_json(
+            create_test_router(state),
+            &format!("/api/v1/rfqs/{}", timeout.id()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["failure_code"], "VENUE_TIMEOUT");
+        assert_eq!(
+            body["failure_reason"],
+            format!("VENUE_TIMEOUT at {}", base + 1)
+        );
+    }
+
+    #[tokio::test]
+    async fn list_rfqs_unknown_failure_code_echoes_value() {
+        let router = create_test_router(create_test_state());
+
+        let (status, body) = send(router, "GET", "/api/v1/rfqs?failure_code=TIMEOUT").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let details = body.details.unwrap();
+        assert_eq!(details["field"], "failure_code");
+        assert_eq!(details["value"], "TIMEOUT");
+    }
+
+    #[tokio::test]
+    async fn list_rfqs_combines_all_filters() {
+        let base = 1_700_000_000_000; // 2023-11-14T22:13:20Z
+        let repo = Arc::new(MockRfqRepository::default());
+        let executed = create_rfq_with("client-1", "BTC/USD", RfqState::Executed, base + 1);
+        let failed = create_rfq_with("client-1", "BTC/USD", RfqState::Failed, base + 2);
+        let rejects = [
+            // wrong state
+            create_rfq_with("client-1", "BTC/USD", RfqState::Cancelled, base + 3),
+            // wrong symbol
+            create_rfq_with("client-1", "ETH/USD", RfqState::Executed, base + 3),
+            // wrong client
+            create_rfq_with("client-2", "BTC/USD", RfqState::Executed, base + 3),
+            // before created_from
+            create_rfq_with("client-1", "BTC/USD", RfqState::Executed, base - 1),
+            // after created_to
+            create_rfq_with("client-1", "BTC/USD", RfqState::Failed, base + 10),
+        ];
+        for rfq in rejects.iter().chain([&executed, &failed]) {
+            repo.save(rfq).await.unwrap();
+        }
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.rfq_repository = repo;
+        let state = Arc::new(state);
+
+        let query = "state=EXECUTED,FAILED&symbol=BTC%2FUSD&client_id=client-1\
+                     &created_from=2023-11-14T22:13:20Z&created_to=2023-11-14T22:13:20.005Z";
+        for uri in [
+            format!("/api/v1/rfqs?{query}"),
+            format!("/api/v1/rfqs?limit=10&{query}"),
+        ] {
+            let (status, body) = get_json(create_test_router(Arc::clone(&state)), &uri).await;
+            assert_eq!(status, StatusCode::OK, "{uri}");
+            assert_eq!(
+                page_ids(&body),
+                vec![failed.id().to_string(), executed.id().to_string()],
+                "{uri}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn list_rfqs_invalid_state_echoes_value() {
+        let router = create_test_router(create_test_state());
+
+        let (status, body) = send(router, "GET", "/api/v1/rfqs?state=EXECUTED,BOGUS").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body.code, "VALIDATION_ERROR");
+        assert!(body.message.contains("BOGUS"));
+        let details = body.details.unwrap();
+        assert_eq!(details["field"], "state");
+        assert_eq!(details["value"], "BOGUS");
+    }
+
+    #[tokio::test]
+    async fn list_rfqs_inverted_date_range_returns_validation_error() {
+        let router = create_test_router(create_test_state());
+
+        let (status, body) = send(
+            router,
+            "GET",
+            "/api/v1/rfqs?created_from=2024-02-01T00:00:00Z&created_to=2024-01-01T00:00:00Z",
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body.code, "VALIDATION_ERROR");
+        let details = body.details.unwrap();
+        assert_eq!(details["created_from"], "2024-02-01T00:00:00Z");
+        assert_eq!(details["created_to"], "2024-01-01T00:00:00Z");
+    }
+
+    #[tokio::test]
+    async fn list_rfqs_invalid_timestamp_echoes_value() {
+        let router = create_test_router(create_test_state());
+
+        let (status, body) = send(router, "GET", "/api/v1/rfqs?created_to=yesterday").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let details = body.details.unwrap();
+        assert_eq!(details["field"], "created_to");
+        assert_eq!(details["value"], "yesterday");
+    }
+
+    // ------------------------------------------------------------------------
+    // RFQ timeline
+    // ------------------------------------------------------------------------
+
+    struct TimelineFixture {
+        rfq: Rfq,
+        trade_id: TradeId,
+        state: Arc<AppState>,
+    }
+
+    /// Stores a full RFQ lifecycle, one second apart, in shuffled order.
+    async fn timeline_fixture() -> TimelineFixture {
+        use crate::domain::events::rfq_events::{
+            ExecutionStarted, QuoteCollectionStarted, QuoteReceived, QuoteRequestFailed,
+            QuoteSelected, RfqCreated,
+        };
+        use crate::domain::events::trade_events::{
+            SettlementConfirmed, SettlementInitiated, TradeExecuted,
+        };
+        use crate::domain::value_objects::timestamp::Timestamp;
+        use crate::domain::value_objects::{Price, Quantity, QuoteId, SettlementMethod};
+        use crate::infrastructure::persistence::EventStore;
+        use crate::infrastructure::persistence::event_store::StoredEvent;
+        use crate::infrastructure::persistence::in_memory::InMemoryEventStore;
+
+        let rfq = create_test_rfq();
+        let rfq_id = rfq.id();
+        let quote_id = QuoteId::new_v4();
+        let venue = VenueId::new("venue-1");
+        let price = Price::new(100.0).unwrap();
+        let quantity = Quantity::new(1.0).unwrap();
+        let trade = Trade::new(rfq_id, quote_id, venue.clone(), price, quantity);
+        let trade_id = trade.id();
+
+        let mut stored = vec![
+            StoredEvent::from_event(
+                &RfqCreated::new(
+                    rfq_id,
+                    rfq.client_id().clone(),
+                    rfq.instrument().clone(),
+                    rfq.side(),
+                    quantity,
+                    rfq.expires_at(),
+                ),
+                1,
+            ),
+            StoredEvent::from_event(
+                &QuoteCollectionStarted::new(rfq_id, vec![venue.clone(), VenueId::new("venue-2")]),
+                2,
+            ),
+            StoredEvent::from_event(
+                &QuoteReceived::new(
+                    rfq_id,
+                    quote_id,
+                    venue.clone(),
+                    price,
+                    quantity,
+                    rfq.expires_at(),
+                ),
+                3,
+            ),
+            StoredEvent::from_event(
+                &QuoteRequestFailed::new(
+                    rfq_id,
+                    VenueId::new("venue-2"),
+                    "rejected: \"size, too large\"",
+                ),
+                4,
+            ),
+            StoredEvent::from_event(
+                &QuoteSelected::new(rfq_id, quote_id, venue.clone(), price),
+                5,
+            ),
+            StoredEvent::from_event(&ExecutionStarted::new(rfq_id, quote_id, venue.clone()), 6),
+            StoredEvent::from_event(
+                &TradeExecuted::builder()
+                    .rfq_id(rfq_id)
+                    .trade_id(trade_id)
+                    .quote_id(quote_id)
+                    .venue_id(venue.clone())
+                    .counterparty_id(rfq.client_id().clone())
+                    .price(price)
+                    .quantity(quantity)
+                    .settlement_method(SettlementMethod::OffChain)
+                    .build(),
+                7,
+            ),
+            StoredEvent::from_event(&SettlementInitiated::off_chain(rfq_id, trade_id), 8),
+            StoredEvent::from_event(&SettlementConfirmed::off_chain(rfq_id, trade_id), 9),
+        ]
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+        let base = Timestamp::now();
+        for (i, event) in stored.iter_mut().enumerate() {
+            event.timestamp = base.add_secs(i as i64);
+        }
+        // Settlement confirmations are only linked to the RFQ through the trade.
+        if let Some(confirmed) = stored.last_mut() {
+            confirmed.rfq_id = None;
+        }
+
+        let event_store = InMemoryEventStore::new();
+        for index in [4, 0, 8, 2, 6, 1, 5, 3, 7] {
+            let event = stored.get(index).unwrap().clone();
+            event_store.append(event).await.unwrap();
+        }
+
+        let rfqs = Arc::new(MockRfqRepository::default());
+        rfqs.save(&rfq).await.unwrap();
+        let trades = Arc::new(MockTradeRepository::default());
+        trades.insert(trade);
+        trades.insert(create_trade_at(base.timestamp_millis()));
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.rfq_repository = rfqs;
+        state.trade_repository = trades;
+        state.event_store = Some(Arc::new(event_store));
+        let state = Arc::new(state);
+        TimelineFixture {
+            rfq,
+            trade_id,
+            state,
+        }
+    }
+
+    #[tokio::test]
+    async fn rfq_timeline_orders_full_lifecycle() {
+        let fixture = timeline_fixture().await;
+        let router = create_test_router(fixture.state);
+
+        let (status, body) = get_json(
+            router,
+            &format!("/api/v1/rfqs/{}/timeline", fixture.rfq.id()),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        let entries = body.as_array().unwrap();
+        let names: Vec<&str> = entries
+            .iter()
+            .map(|e| e["event_name"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "RfqCreated",
+                "QuoteCollectionStarted",
+                "QuoteReceived",
+                "QuoteRequestFailed",
+                "QuoteSelected",
+                "ExecutionStarted",
+                "TradeExecuted",
+                "SettlementInitiated",
+                "SettlementConfirmed",
+            ]
+        );
+        let actors: Vec<&str> = entries
+            .iter()
+            .map(|e| e["actor"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            actors,
+            vec![
+                "client-1", "system", "venue-1", "venue-2", "client-1", "system", "venue-1",
+                "system", "system",
+            ]
+        );
+        assert_eq!(
+            entries.last().unwrap()["summary"],
+            format!("Settlement of trade {} confirmed", fixture.trade_id)
+        );
+    }
+
+    #[tokio::test]
+    async fn rfq_timeline_csv_escapes_reasons() {
+        let fixture = timeline_fixture().await;
+        let router = create_test_router(fixture.state);
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .uri(format!(
+                        "/api/v1/rfqs/{}/timeline?format=csv",
+                        fixture.rfq.id()
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()["content-type"],
+            "text/csv; charset=utf-8"
+        );
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let csv = String::from_utf8(bytes.to_vec()).unwrap();
+        let lines: Vec<&str> = csv.split_terminator("\r\n").collect();
+        assert_eq!(lines.len(), 10);
+        assert_eq!(
+            lines.first().copied(),
+            Some("at,event_id,actor,event_name,summary,payload")
+        );
+        assert!(
+            csv.contains(r#","Quote request to venue-2 failed: rejected: ""size, too large""","#)
+        );
+    }
+
+    #[tokio::test]
+    async fn rfq_timeline_unknown_rfq_returns_not_found() {
+        let fixture = timeline_fixture().await;
+        let router = create_test_router(fixture.state);
+
+        let (status, body) = send(
+            router,
+            "GET",
+            &format!("/api/v1/rfqs/{}/timeline", RfqId::new_v4()),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body.code, "RFQ_NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn rfq_timeline_without_event_store_returns_not_implemented() {
+        let router = create_test_router(create_test_state());
+
+        let (status, body) = send(
+            router,
+            "GET",
+            &format!("/api/v1/rfqs/{}/timeline", RfqId::new_v4()),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+        assert_eq!(body.code, "NOT_IMPLEMENTED");
+    }
+
+    // ------------------------------------------------------------------------
+    // RFQ aggregation report
+    // ------------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn rfq_aggregation_report_lists_venue_outcomes() {
+        use crate::domain::value_objects::{
+            AggregationReport, VenueExclusionReason, VenueOutcome, VenueOutcomeKind,
+        };
+        use crate::infrastructure::persistence::AggregationReportRepository;
+        use crate::infrastructure::persistence::in_memory::InMemoryAggregationReportRepository;
+
+        let rfq = create_rfq_with(
+            "client-1",
+            "BTC/USD",
+            RfqState::QuotesReceived,
+            1_700_000_000_000,
+        );
+        let reported = create_rfq_with(
+            "client-1",
+            "BTC/USD",
+            RfqState::QuotesReceived,
+            1_700_000_000_000,
+        );
+        let repo = Arc::new(MockRfqRepository::default());
+        repo.save(&rfq).await.unwrap();
+        repo.save(&reported).await.unwrap();
+        let reports = Arc::new(InMemoryAggregationReportRepository::new());
+        let quote_id = QuoteId::new_v4().to_string();
+        reports
+            .save(&AggregationReport::new(
+                reported.id(),
+                vec![
+                    VenueOutcome::new(
+                        VenueId::new("venue-b"),
+                        VenueOutcomeKind::Excluded {
+                            reason: VenueExclusionReason::CircuitOpen,
+                        },
+                    ),
+                    VenueOutcome::new(
+                        VenueId::new("venue-a"),
+                        VenueOutcomeKind::Responded {
+                            latency_ms: Some(12),
+                            quote_id: quote_id.clone(),
+                        },
+                    ),
+                    VenueOutcome::new(VenueId::new("venue-c"), VenueOutcomeKind::Timeout),
+                ],
+                Timestamp::now(),
+            ))
+            .await
+            .unwrap();
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.rfq_repository = repo;
+        state.aggregation_reports = Some(reports);
+        let state = Arc::new(state);
+
+        let (status, body) = get_json(
+            create_test_router(Arc::clone(&state)),
+            &format!("/api/v1/rfqs/{}/aggregation-report", reported.id()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["rfq_id"], reported.id().to_string());
+        assert_eq!(
+            body["counts"],
+            serde_json::json!({
+                "responded": 1, "timed_out": 1, "errored": 0, "filtered": 0, "excluded": 1
+            })
+        );
+        assert_eq!(
+            body["venues"],
+            serde_json::json!([
+                {"venue_id": "venue-a", "status": "RESPONDED", "latency_ms": 12, "quote_id": quote_id},
+                {"venue_id": "venue-b", "status": "EXCLUDED", "reason": "CIRCUIT_OPEN"},
+                {"venue_id": "venue-c", "status": "TIMEOUT"},
+            ])
+        );
+
+        let (status, body) = send(
+            create_test_router(Arc::clone(&state)),
+            "GET",
+            &format!("/api/v1/rfqs/{}/aggregation-report", rfq.id()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body.code, "NOT_FOUND");
+
+        let (status, body) = send(
+            create_test_router(state),
+            "GET",
+            &format!("/api/v1/rfqs/{}/aggregation-report", RfqId::new_v4()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body.code, "RFQ_NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn quote_ranking_explanation_returns_score_breakdown() {
+        use crate::domain::value_objects::{
+            AggregationReport, QuoteRanking, ScoreComponent, ScoreFactor,
+        };
+        use crate::infrastructure::persistence::AggregationReportRepository;
+        use crate::infrastructure::persistence::in_memory::InMemoryAggregationReportRepository;
+
+        let rfq = create_rfq_with(
+            "client-1",
+            "BTC/USD",
+            RfqState::QuotesReceived,
+            1_700_000_000_000,
+        );
+        let repo = Arc::new(MockRfqRepository::default());
+        repo.save(&rfq).await.unwrap();
+        let quote_id = QuoteId::new_v4();
+        let breakdown = vec![
+            ScoreComponent::new(ScoreFactor::Price, 1.0, 0.7),
+            ScoreComponent::new(ScoreFactor::Size, 0.5, 0.3),
+        ];
+        let reports = Arc::new(InMemoryAggregationReportRepository::new());
+        reports
+            .save(
+                &AggregationReport::new(rfq.id(), vec![], Timestamp::now()).with_quote_rankings(
+                    vec![QuoteRanking {
+                        quote_id,
+                        venue_id: VenueId::new("venue-a"),
+                        rank: 1,
+                        score: ScoreComponent::total(&breakdown),
+                        score_breakdown: breakdown,
+                    }],
+                ),
+            )
+            .await
+            .unwrap();
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.rfq_repository = repo;
+        state.aggregation_reports = Some(reports);
+        let state = Arc::new(state);
+
+        let (status, body) = get_json(
+            create_test_router(Arc::clone(&state)),
+            &format!(
+                "/api/v1/rfqs/{}/quotes/{quote_id}/ranking-explanation",
+                rfq.id()
+            ),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["quote_id"], quote_id.to_string());
+        assert_eq!(body["venue_id"], "venue-a");
+        assert_eq!(body["rank"], 1);
+        assert_eq!(body["score"], "0.85");
+        assert_eq!(
+            body["score_breakdown"],
+            serde_json::json!([
+                {"factor": "PRICE", "raw_value": "1", "weight": "0.7", "contribution": "0.7"},
+                {"factor": "SIZE", "raw_value": "0.5", "weight": "0.3", "contribution": "0.15"},
+            ])
+        );
+
+        let (status, body) = send(
+            create_test_router(state),
+            "GET",
+            &format!(
+                "/api/v1/rfqs/{}/quotes/{}/ranking-explanation",
+                rfq.id(),
+                QuoteId::new_v4()
+            ),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body.code, "NOT_FOUND");
+    }
+
+    // ------------------------------------------------------------------------
+    // Venue configuration history
+    // ------------------------------------------------------------------------
+
+    async fn create_test_state_with_venue() -> Arc<AppState> {
+        use crate::domain::value_objects::VenueType;
+
+        let venues = MockVenueRepository::default();
+        venues
+            .save(&Venue::new(
+                VenueId::new("venue-1"),
+                "Venue 1",
+                VenueType::ExternalMM,
+            ))
+            .await
+            .unwrap();
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.venue_repository = Arc::new(venues);
+        Arc::new(state)
+    }
+
+    #[tokio::test]
+    async fn update_venue_records_config_diff() {
+        use crate::api::middleware::Claims;
+
+        let router = create_test_router(create_test_state_with_venue().await);
+
+        let response = router
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/api/v1/venues/venue-1")
+                    .header("Content-Type", "application/json")
+                    .extension(Claims::new("ops-1", u64::MAX, 0))
+                    .body(Body::from(r#"{"enabled":false}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let (status, history) = send_json(
+            router,
+            "GET",
+            "/api/v1/venues/venue-1/history",
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let entries = history.as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry["venue_id"], "venue-1");
+        assert_eq!(entry["old_config"]["enabled"], true);
+        assert_eq!(entry["new_config"]["enabled"], false);
+        assert_eq!(
+            entry["old_config"]["timeout_ms"],
+            entry["new_config"]["timeout_ms"]
+        );
+        assert_eq!(entry["new_config"]["mode"], "DISABLED");
+        assert_eq!(entry["changed_fields"], serde_json::json!(["mode"]));
+        assert_eq!(entry["changed_by"], "ops-1");
+        assert!(entry["rollback_of"].is_null());
+    }
+
+    #[tokio::test]
+    async fn shadow_venue_quotes_are_hidden_unselectable_and_reported() {
+        use crate::application::services::FirmUpService;
+        use crate::domain::entities::VenueMode;
+        use crate::domain::services::mm_performance::MmPerformanceTracker;
+        use crate::domain::value_objects::CounterpartyId;
+        use crate::infrastructure::persistence::in_memory::InMemoryMmPerformanceRepository;
+
+        let now = Timestamp::now();
+        let mut rfq = create_rfq_with(
+            "user-1",
+            "BTC/USD",
+            RfqState::Created,
+            now.timestamp_millis(),
+        );
+        rfq.start_quote_collection().unwrap();
+        let quote = |venue: &str, price: f64| {
+            QuoteBuilder::new(
+                rfq.id(),
+                VenueId::new(venue),
+                Price::new(price).unwrap(),
+                Quantity::new(1.0).unwrap(),
+                now.add_secs(60),
+            )
+            .build()
+        };
+        let live = quote("venue-2", 101.0);
+        let shadow = quote("venue-1", 100.0).with_shadow(true);
+        let shadow_id = shadow.id();
+        rfq.receive_quote(live).unwrap();
+        rfq.receive_quote(shadow).unwrap();
+        let rfq_id = rfq.id();
+        let repo = Arc::new(MockRfqRepository::default());
+        repo.save(&rfq).await.unwrap();
+
+        let mm_id = CounterpartyId::new("mm-1");
+        let tracker = Arc::new(MmPerformanceTracker::with_defaults(Arc::new(
+            InMemoryMmPerformanceRepository::new(),
+        )));
+        tracker.record_rfq_sent(&mm_id).await.unwrap();
+        tracker.record_quote_received(&mm_id, 40, 1).await.unwrap();
+
+        let mut state = Arc::into_inner(create_test_state_with_venue().await).unwrap();
+        let mut venue = state
+            .venue_repository
+            .find_by_id(&VenueId::new("venue-1"))
+            .await
+            .unwrap()
+            .unwrap();
+        venue.set_mode(VenueMode::Shadow);
+        venue.set_mm_counterparty_id(Some(mm_id));
+        state.venue_repository.save(&venue).await.unwrap();
+        let registry = FirmingVenueRegistry(Arc::new(FirmingVenue {
+            venue_id: VenueId::new("venue-1"),
+            firm_price: 100.0,
+            cancelled: RwLock::new(Vec::new()),
+        }));
+        state.firm_up = Some(Arc::new(FirmUpService::new(
+            repo.clone(),
+            Arc::new(registry),
+        )));
+        state.rfq_repository = repo;
+        state.mm_performance_tracker = Some(tracker);
+        let router = create_test_router(Arc::new(state));
+
+        let (status, body) = get_json_with_roles(
+            router.clone(),
+            &format!("/api/v1/rfqs/{rfq_id}"),
+            &["trader"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let quotes = body["quotes"].as_array().unwrap();
+        assert_eq!(quotes.len(), 1);
+        assert_eq!(quotes[0]["venue_id"], "venue-2");
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            &format!("/api/v1/rfqs/{rfq_id}/select"),
+            serde_json::json!({ "quote_id": shadow_id.to_string() }),
+            &["trader"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "QUOTE_NOT_FOUND");
+
+        let uri = "/api/v1/venues/venue-1/shadow-report";
+        let (status, _) = get_json_with_roles(router.clone(), uri, &["trader"]).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, report) = get_json_with_roles(router, uri, &["admin"]).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(report["mode"], "SHADOW");
+        assert_eq!(report["quotes_provided"], 1);
+        assert_eq!(report["would_have_won"], 1);
+        assert_eq!(report["win_rate_pct"], 100.0);
+    }
+
+    #[tokio::test]
+    async fn update_venue_resizes_request_gate() {
+        use crate::application::services::VenueRequestGate;
+
+        let gate = Arc::new(VenueRequestGate::new());
+        gate.set_limit(&VenueId::new("venue-1"), 2);
+        let mut state = Arc::into_inner(create_test_state_with_venue().await).unwrap();
+        state.venue_request_gate = Some(Arc::clone(&gate));
+        let router = create_test_router(Arc::new(state));
+
+        let (status, _) = send_json(
+            router,
+            "PUT",
+            "/api/v1/venues/venue-1",
+            serde_json::json!({ "max_concurrent_requests": 4 }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(gate.limit(&VenueId::new("venue-1")), Some(4));
+    }
+
+    #[tokio::test]
+    async fn rollback_of_a_rollback_is_recorded() {
+        let router = create_test_router(create_test_state_with_venue().await);
+        let null = serde_json::Value::Null;
+
+        let (status, _) = send_json(
+            router.clone(),
+            "PUT",
+            "/api/v1/venues/venue-1",
+            serde_json::json!({ "enabled": false }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, rollback) = send_json(
+            router.clone(),
+            "POST",
+            "/api/v1/venues/venue-1/rollback/1",
+            null.clone(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(rollback["id"], 2);
+        assert_eq!(rollback["rollback_of"], 1);
+        assert_eq!(rollback["new_config"]["enabled"], true);
+        assert_eq!(rollback["changed_by"], "anonymous");
+
+        let (status, undo) = send_json(
+            router.clone(),
+            "POST",
+            "/api/v1/venues/venue-1/rollback/2",
+            null.clone(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(undo["id"], 3);
+        assert_eq!(undo["rollback_of"], 2);
+        assert_eq!(undo["old_config"]["enabled"], true);
+        assert_eq!(undo["new_config"]["enabled"], false);
+
+        let (_, venues) = send_json(router.clone(), "GET", "/api/v1/venues", null.clone()).await;
+        assert_eq!(venues[0]["enabled"], false);
+
+        let (_, history) = send_json(router, "GET", "/api/v1/venues/venue-1/history", null).await;
+        let ids: Vec<u64> = history
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry["id"].as_u64().unwrap())
+            .collect();
+        assert_eq!(ids, vec![3, 2, 1]);
+    }
+
+    #[tokio::test]
+    async fn rollback_unknown_history_entry_returns_not_found() {
+        let router = create_test_router(create_test_state_with_venue().await);
+
+        let (status, body) =
+            send(router.clone(), "POST", "/api/v1/venues/venue-1/rollback/9").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body.code, "NOT_FOUND");
+
+        let (status, _) = send(router, "GET", "/api/v1/venues/missing/history").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    // ------------------------------------------------------------------------
+    // Quote selection
+    // ------------------------------------------------------------------------
+
+    /// Venue that firms up every quote at a fixed price.
+    #[derive(Debug)]
+    struct FirmingVenue {
+        venue_id: VenueId,
+        firm_price: f64,
+        cancelled: RwLock<Vec<RfqId>>,
+    }
+
+    #[async_trait]
+    impl crate::infrastructure::venues::traits::VenueAdapter for FirmingVenue {
+        fn venue_id(&self) -> &VenueId {
+            &self.venue_id
+        }
+
+        fn timeout_ms(&self) -> u64 {
+            1000
+        }
+
+        async fn request_quote(
+            &self,
+            _rfq: &Rfq,
+        ) -> crate::infrastructure::venues::error::VenueResult<Quote> {
+            Err(crate::infrastructure::venues::error::VenueError::internal_error("mock"))
+        }
+
+        async fn execute_trade(
+            &self,
+            _quote: &Quote,
+        ) -> crate::infrastructure::venues::error::VenueResult<
+            crate::infrastructure::venues::traits::ExecutionResult,
+        > {
+            Err(crate::infrastructure::venues::error::VenueError::internal_error("mock"))
+        }
+
+        async fn health_check(
+            &self,
+        ) -> crate::infrastructure::venues::error::VenueResult<
+            crate::infrastructure::venues::traits::VenueHealth,
+        > {
+            Ok(crate::infrastructure::venues::traits::VenueHealth::healthy(
+                self.venue_id.clone(),
+            ))
+        }
+
+        async fn firm_up(
+            &self,
+            quote: &Quote,
+        ) -> crate::infrastructure::venues::error::VenueResult<Quote> {
+            Ok(QuoteBuilder::new(
+                quote.rfq_id(),
+                self.venue_id.clone(),
+                Price::new(self.firm_price).unwrap(),
+                quote.quantity(),
+                Timestamp::now().add_secs(30),
+            )
+            .build())
+        }
+
+        fn supports_rfq_cancellation(&self) -> bool {
+            true
+        }
+
+        async fn cancel_rfq(
+            &self,
+            rfq_id: RfqId,
+        ) -> crate::infrastructure::venues::error::VenueResult<()> {
+            self.cancelled.write().unwrap().push(rfq_id);
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    struct FirmingVenueRegistry(Arc<FirmingVenue>);
+
+    #[async_trait]
+    impl crate::application::use_cases::collect_quotes::VenueRegistry for FirmingVenueRegistry {
+        async fn get_available_venues(
+            &self,
+        ) -> Vec<Arc<dyn crate::infrastructure::venues::traits::VenueAdapter>> {
+            vec![self.0.clone()]
+        }
+
+        async fn get_venue(
+            &self,
+            venue_id: &VenueId,
+        ) -> Option<Arc<dyn crate::infrastructure::venues::traits::VenueAdapter>> {
+            (venue_id == &self.0.venue_id).then(|| {
+                self.0.clone() as Arc<dyn crate::infrastructure::venues::traits::VenueAdapter>
+            })
+        }
+    }
+
+    /// State with one RFQ holding an indicative 100.0 quote, firmed up at `firm_price`.
+    async fn create_test_state_with_indicative_quote(
+        firm_price: f64,
+    ) -> (Arc<AppState>, RfqId, QuoteId) {
+        use crate::application::services::{FirmUpConfig, FirmUpService};
+        use rust_decimal::Decimal;
+
+        let repo = Arc::new(MockRfqRepository::default());
+        let mut rfq = create_test_rfq();
+        rfq.start_quote_collection().unwrap();
+        let quote = QuoteBuilder::new(
+            rfq.id(),
+            VenueId::new("venue-1"),
+            Price::new(100.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(60),
+        )
+        .firmness(QuoteFirmness::Indicative)
+        .build();
+        let quote_id = quote.id();
+        rfq.receive_quote(quote).unwrap();
+        repo.save(&rfq).await.unwrap();
+
+        let registry = FirmingVenueRegistry(Arc::new(FirmingVenue {
+            venue_id: VenueId::new("venue-1"),
+            firm_price,
+            cancelled: RwLock::new(Vec::new()),
+        }));
+        let firm_up =
+            FirmUpService::new(repo.clone(), Arc::new(registry)).with_config(FirmUpConfig {
+                tolerance_pct: Decimal::new(1, 2),
+                ..FirmUpConfig::default()
+            });
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.rfq_repository = repo;
+        state.firm_up = Some(Arc::new(firm_up));
+        (Arc::new(state), rfq.id(), quote_id)
+    }
+
+    #[tokio::test]
+    async fn quote_history_shows_superseded_quote_ranks_and_timeline() {
+        use crate::domain::entities::quote_history::QuoteRank;
+        use crate::domain::value_objects::OrderSide;
+
+        let now = Timestamp::now();
+        let mut rfq = create_rfq_with(
+            "user-1",
+            "BTC/USD",
+            RfqState::Created,
+            now.timestamp_millis(),
+        );
+        rfq.start_quote_collection().unwrap();
+        let quote = |venue: &str, price: f64, received_secs: i64| {
+            QuoteBuilder::new(
+                rfq.id(),
+                VenueId::new(venue),
+                Price::new(price).unwrap(),
+                Quantity::new(1.0).unwrap(),
+                now.add_secs(60),
+            )
+            .build()
+            .with_received_at(now.add_secs(received_secs))
+        };
+        let first = quote("venue-1", 101.0, 1);
+        let second = quote("venue-2", 100.0, 2);
+        let third = quote("venue-3", 102.0, 3);
+        let requote = quote("venue-1", 99.0, 4);
+        let (first_id, second_id, third_id, requote_id) =
+            (first.id(), second.id(), third.id(), requote.id());
+        for q in [first, second, third, requote] {
+            rfq.receive_quote(q).unwrap();
+        }
+        rfq.record_quote_ranks(vec![
+            QuoteRank::new(requote_id, OrderSide::Buy, 1),
+            QuoteRank::new(second_id, OrderSide::Buy, 2),
+            QuoteRank::new(third_id, OrderSide::Buy, 3),
+        ]);
+        let rfq_id = rfq.id();
+        let foreign = create_rfq_with(
+            "client-2",
+            "BTC/USD",
+            RfqState::Created,
+            now.timestamp_millis(),
+        );
+        let repo = Arc::new(MockRfqRepository::default());
+        repo.save(&rfq).await.unwrap();
+        repo.save(&foreign).await.unwrap();
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.rfq_repository = repo;
+        let router = create_test_router(Arc::new(state));
+        let uri = format!("/api/v1/rfqs/{rfq_id}/quotes");
+
+        let (status, body) = get_json_with_roles(router.clone(), &uri, &["trader"]).await;
+
+        assert_eq!(status, StatusCode::OK);
+        let rows = body["quotes"].as_array().unwrap();
+        assert_eq!(rows.len(), 4);
+        let row = |id: QuoteId| {
+            rows.iter()
+                .find(|row| row["quote_id"] == id.to_string())
+                .unwrap()
+        };
+        assert_eq!(row(first_id)["status"], "SUPERSEDED");
+        assert_eq!(row(first_id)["superseded_by"], requote_id.to_string());
+        assert!(row(first_id).get("rank").is_none());
+        for (id, rank) in [(requote_id, 1), (second_id, 2), (third_id, 3)] {
+            assert_eq!(row(id)["status"], "ACTIVE");
+            assert_eq!(row(id)["rank"], rank);
+        }
+        let timeline: Vec<&str> = body["best_price_timeline"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|point| point["price"].as_str().unwrap())
+            .collect();
+        assert_eq!(timeline, vec!["101", "100", "99"]);
+
+        let foreign_uri = format!("/api/v1/rfqs/{}/quotes", foreign.id());
+        let (status, body) = get_json_with_roles(router.clone(), &foreign_uri, &["trader"]).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "UNAUTHORIZED_COUNTERPARTY");
+        let (status, _) = get_json_with_roles(router, &foreign_uri, &["admin"]).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn select_indicative_quote_returns_firm_selection() {
+        let (state, rfq_id, quote_id) = create_test_state_with_indicative_quote(100.5).await;
+        let router = create_test_router(state);
+
+        let (status, body) = send_json(
+            router,
+            "POST",
+            &format!("/api/v1/rfqs/{rfq_id}/select"),
+            serde_json::json!({ "quote_id": quote_id.to_string() }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["state"], "CLIENT_SELECTING");
+        assert_eq!(body["quotes"][0]["firmness"], "FIRM");
+        assert_eq!(body["quotes"][0]["price"], "100.5");
+        assert_eq!(body["selected_quote_id"], body["quotes"][0]["id"]);
+        assert_ne!(body["selected_quote_id"], quote_id.to_string());
+    }
+
+    #[tokio::test]
+    async fn select_quote_on_wrong_side_is_rejected() {
+        let (state, rfq_id, quote_id) = create_test_state_with_indicative_quote(100.5).await;
+        let router = create_test_router(state);
+
+        let (status, body) = send_json(
+            router,
+            "POST",
+            &format!("/api/v1/rfqs/{rfq_id}/select"),
+            serde_json::json!({ "quote_id": quote_id.to_string(), "side": "SELL" }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+    }
+
+    #[tokio::test]
+    async fn select_quote_price_moved_returns_firm_up_price_moved_code() {
+        let (state, rfq_id, quote_id) = create_test_state_with_indicative_quote(105.0).await;
+        let router = create_test_router(state);
+
+        let (status, body) = send_json(
+            router.clone(),
+            "POST",
+            &format!("/api/v1/rfqs/{rfq_id}/select"),
+            serde_json::json!({ "quote_id": quote_id.to_string() }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "FIRM_UP_PRICE_MOVED");
+        assert_eq!(body["details"]["firm"], "105");
+
+        let (_, rfq) = send_json(
+            router,
+            "GET",
+            &format!("/api/v1/rfqs/{rfq_id}"),
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(rfq["state"], "QUOTES_RECEIVED");
+        assert_eq!(rfq["quotes"][0]["firmness"], "INDICATIVE");
+    }
+
+    #[tokio::test]
+    async fn select_quote_below_quorum_requires_override() {
+        use crate::domain::value_objects::{
+            QuorumBand, QuorumPolicy, QuorumRequirement, QuorumRules,
+        };
+        use rust_decimal::Decimal;
+
+        let (state, rfq_id, quote_id) = create_test_state_with_indicative_quote(100.0).await;
+        let mut state = Arc::into_inner(state).unwrap();
+        let policy = QuorumPolicy::new(vec![QuorumBand::new(
+            Decimal::from(50),
+            QuorumRequirement::new(3, 0),
+        )])
+        .unwrap();
+        let firm_up = Arc::into_inner(state.firm_up.take().unwrap())
+            .unwrap()
+            .with_quorum(QuorumRules::new(policy));
+        state.firm_up = Some(Arc::new(firm_up));
+        let router = create_test_router(Arc::new(state));
+        let uri = format!("/api/v1/rfqs/{rfq_id}/select");
+
+        let (status, body) = send_json(
+            router.clone(),
+            "POST",
+            &uri,
+            serde_json::json!({ "quote_id": quote_id.to_string() }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "QUORUM_NOT_MET");
+        assert_eq!(body["details"]["quotes"], 1);
+        assert_eq!(body["details"]["min_quotes"], 3);
+
+        let (status, body) = send_json(
+            router,
+            "POST",
+            &uri,
+            serde_json::json!({
+                "quote_id": quote_id.to_string(),
+                "quorum_override_reason": "only two dealers make this market",
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["state"], "CLIENT_SELECTING");
+    }
+
+    #[tokio::test]
+    async fn select_quote_without_firm_up_returns_not_implemented() {
+        let router = create_test_router(create_test_state());
+
+        let (status, body) = send_json(
+            router,
+            "POST",
+            &format!("/api/v1/rfqs/{}/select", RfqId::new_v4()),
+            serde_json::json!({ "quote_id": QuoteId::new_v4().to_string() }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+        assert_eq!(body["code"], "NOT_IMPLEMENTED");
+    }
+
+    // ------------------------------------------------------------------------
+    // RFQ templates
+    // ------------------------------------------------------------------------
+
+    fn create_test_state_with_templates() -> Arc<AppState> {
+        use crate::infrastructure::persistence::in_memory::{
+            InMemoryInstrumentReferenceDataRepository, InMemoryRfqTemplateRepository,
+        };
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.instrument_reference_data =
+            Some(Arc::new(InMemoryInstrumentReferenceDataRepository::new()));
+        state.rfq_templates = Some(Arc::new(InMemoryRfqTemplateRepository::new()));
+        Arc::new(state)
+    }
+
+    /// Sends a JSON request authenticated as `client_id`.
+    async fn send_json_as(
+        router: Router,
+        client_id: &str,
+        method: &str,
+        uri: &str,
+        body: serde_json::Value,
+    ) -> (StatusCode, serde_json::Value) {
+        use crate::api::middleware::Claims;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("Content-Type", "application/json")
+                    .extension(Claims::new("user-1", u64::MAX, 0).with_client_id(client_id))
+                    .body(Body::from(serde_json::to_string(&body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let value = if bytes.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_slice(&bytes).unwrap()
+        };
+        (status, value)
+    }
+
+    fn template_body() -> serde_json::Value {
+        serde_json::json!({
+            "name": "Daily BTC buy",
+            "base_asset": "BTC",
+            "quote_asset": "USD",
+            "side": "BUY",
+            "quantity": 5.0,
+            "size_mode": { "type": "MIN_QUANTITY", "min_quantity": 1.0 },
+            "default_ttl_seconds": 300
+        })
+    }
+
+    async fn create_template(router: &Router, client_id: &str) -> String {
+        let (status, template) = send_json_as(
+            router.clone(),
+            client_id,
+            "POST",
+            "/api/v1/rfq-templates",
+            template_body(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        template["id"].as_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn rfq_template_crud_is_scoped_to_owner() {
+        let router = create_test_router(create_test_state_with_templates());
+        let id = create_template(&router, "client-1").await;
+
+        let (status, template) = send_json_as(
+            router.clone(),
+            "client-1",
+            "GET",
+            &format!("/api/v1/rfq-templates/{id}"),
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(template["owner"], "client-1");
+        assert_eq!(template["symbol"], "BTC/USD");
+        assert_eq!(template["size_mode"]["min_quantity"], "1");
+
+        let mut replacement = template_body();
+        replacement["name"] = serde_json::json!("Weekly BTC buy");
+        let (status, template) = send_json_as(
+            router.clone(),
+            "client-1",
+            "PUT",
+            &format!("/api/v1/rfq-templates/{id}"),
+            replacement,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(template["id"], id.as_str());
+        assert_eq!(template["name"], "Weekly BTC buy");
+
+        let (_, own) = send_json_as(
+            router.clone(),
+            "client-1",
+            "GET",
+            "/api/v1/rfq-templates",
+            serde_json::Value::Null,
+        )
+        .await;
+        let (_, other) = send_json_as(
+            router.clone(),
+            "client-2",
+            "GET",
+            "/api/v1/rfq-templates",
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(own.as_array().unwrap().len(), 1);
+        assert!(other.as_array().unwrap().is_empty());
+
+        let (status, _) = send_json_as(
+            router.clone(),
+            "client-1",
+            "DELETE",
+            &format!("/api/v1/rfq-templates/{id}"),
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, body) = send_json_as(
+            router,
+            "client-1",
+            "GET",
+            &format!("/api/v1/rfq-templates/{id}"),
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn create_rfq_from_template_applies_overrides() {
+        let router = create_test_router(create_test_state_with_templates());
+        let id = create_template(&router, "client-1").await;
+
+        let (status, rfq) = send_json_as(
+            router.clone(),
+            "client-1",
+            "POST",
+            &format!("/api/v1/rfqs/from-template/{id}"),
+            serde_json::json!({ "quantity": 2.0, "expiry_seconds": 60 }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(rfq["client_id"], "client-1");
+        assert_eq!(rfq["symbol"], "BTC/USD");
+        assert_eq!(rfq["side"], "BUY");
+        assert_eq!(rfq["quantity"], "2");
+        assert_eq!(rfq["size_mode"]["type"], "MIN_QUANTITY");
+
+        // The override is validated like a direct request: it may not fall
+        // below the template's minimum fill
+        let (status, body) = send_json_as(
+            router,
+            "client-1",
+            "POST",
+            &format!("/api/v1/rfqs/from-template/{id}"),
+            serde_json::json!({ "quantity": 0.5 }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "INVALID_MIN_QUANTITY");
+    }
+
+    #[tokio::test]
+    async fn create_rfq_from_template_without_body_uses_template_defaults() {
+        use crate::api::middleware::Claims;
+
+        let router = create_test_router(create_test_state_with_templates());
+        let id = create_template(&router, "client-1").await;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/v1/rfqs/from-template/{id}"))
+                    .extension(Claims::new("client-1", u64::MAX, 0))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let rfq: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(rfq["quantity"], "5");
+    }
+
+    #[tokio::test]
+    async fn rfq_template_access_is_denied_to_other_counterparties() {
+        let router = create_test_router(create_test_state_with_templates());
+        let id = create_template(&router, "client-1").await;
+
+        for (method, uri, body) in [
+            (
+                "GET",
+                format!("/api/v1/rfq-templates/{id}"),
+                serde_json::Value::Null,
+            ),
+            (
+                "PUT",
+                format!("/api/v1/rfq-templates/{id}"),
+                template_body(),
+            ),
+            (
+                "DELETE",
+                format!("/api/v1/rfq-templates/{id}"),
+                serde_json::Value::Null,
+            ),
+            (
+                "POST",
+                format!("/api/v1/rfqs/from-template/{id}"),
+                serde_json::json!({}),
+            ),
+        ] {
+            let (status, body) = send_json_as(router.clone(), "client-2", method, &uri, body).await;
+            assert_eq!(status, StatusCode::FORBIDDEN, "{method} {uri}");
+            assert_eq!(body["code"], "UNAUTHORIZED_COUNTERPARTY");
+        }
+
+        let (status, _) = send_json(
+            router,
+            "GET",
+            &format!("/api/v1/rfq-templates/{id}"),
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn create_rfq_from_template_rejects_now_invalid_instrument() {
+        let router = create_test_router(create_test_state_with_templates());
+        let id = create_template(&router, "client-1").await;
+
+        // The instrument's lot size changes after the template was saved
+        send_json(
+            router.clone(),
+            "PUT",
+            "/api/v1/instruments/BTC/USD",
+            serde_json::json!({ "tick_size": "0.5", "lot_size": "2" }),
+        )
+        .await;
+
+        let (status, body) = send_json_as(
+            router,
+            "client-1",
+            "POST",
+            &format!("/api/v1/rfqs/from-template/{id}"),
+            serde_json::json!({}),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "INVALID_LOT_SIZE");
+    }
+
+    #[tokio::test]
+    async fn rfq_templates_without_repository_return_not_implemented() {
+        let router = create_test_router(create_test_state());
+
+        let (status, body) = send_json_as(
+            router,
+            "client-1",
+            "GET",
+            "/api/v1/rfq-templates",
+            serde_json::Value::Null,
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+        assert_eq!(body["code"], "NOT_IMPLEMENTED");
+    }
+
+    // ------------------------------------------------------------------------
+    // Trade allocations
+    // ------------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn fill_strategy_allocations_round_trip_to_api() {
+        use crate::application::services::ranking_strategy::RankedQuote;
+        use crate::application::services::{BestPriceFillStrategy, MultiMmFillStrategy};
+        use crate::domain::entities::TradeAllocation;
+        use crate::domain::value_objects::{OrderSide, Price, Quantity, SizeNegotiationMode};
+        use rust_decimal::Decimal;
+
+        let mut trade = create_trade_at(Timestamp::now().timestamp_millis());
+        let expires_at = Timestamp::now().add_secs(300);
+        let best = QuoteBuilder::new(
+            trade.rfq_id(),
+            VenueId::new("mm-best"),
+            Price::new(99.0).unwrap(),
+            Quantity::new(3.0).unwrap(),
+            expires_at,
+        )
+        .build();
+        let next = QuoteBuilder::new(
+            trade.rfq_id(),
+            VenueId::new("mm-next"),
+            Price::new(100.0).unwrap(),
+            Quantity::new(5.0).unwrap(),
+            expires_at,
+        )
+        .build();
+        let ranked = vec![
+            RankedQuote::new(best, 1, 1.0),
+            RankedQuote::new(next, 2, 0.9),
+        ];
+        let allocations = BestPriceFillStrategy::new()
+            .allocate(
+                &ranked,
+                Quantity::new(5.0).unwrap(),
+                &SizeNegotiationMode::AllOrNothing,
+                OrderSide::Buy,
+            )
+            .unwrap();
+        for allocation in allocations {
+            trade.add_allocation(TradeAllocation::scheduled(allocation, Timestamp::now()));
+        }
+        let trade_id = trade.id();
+        let trades = Arc::new(MockTradeRepository::default());
+        trades.insert(trade);
+        let state = state_with_trades(trades);
+
+        let (status, body) = get_json(
+            create_test_router(Arc::clone(&state)),
+            &format!("/api/v1/trades/{trade_id}"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let allocations = body["allocations"].as_array().unwrap();
+        assert_eq!(allocations.len(), 2);
+        assert_eq!(allocations[0]["venue_id"], "mm-best");
+        assert_eq!(allocations[0]["status"], "PENDING");
+        assert_eq!(allocations[1]["venue_id"], "mm-next");
+        let vwap: Decimal = body["allocation_vwap"].as_str().unwrap().parse().unwrap();
+        assert_eq!(vwap, "99.4".parse::<Decimal>().unwrap());
+
+        let (status, body) = get_json(
+            create_test_router(state),
+            &format!("/api/v1/trades/{trade_id}/allocations?page=2&page_size=1"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let data = body["data"].as_array().unwrap();
+        assert_eq!(data.len(), 1);
+        assert_eq!(data[0]["venue_id"], "mm-next");
+        let quantity: Decimal = data[0]["quantity"].as_str().unwrap().parse().unwrap();
+        assert_eq!(quantity, Decimal::from(2));
+        assert_eq!(body["pagination"]["total_items"], 2);
+        assert_eq!(body["pagination"]["total_pages"], 2);
+    }
+
+    #[tokio::test]
+    async fn list_trade_allocations_unknown_trade_returns_not_found() {
+        let state = state_with_trades(Arc::new(MockTradeRepository::default()));
+        let (status, body) = get_json(
+            create_test_router(state),
+            "/api/v1/trades/550e8400-e29b-41d4-a716-446655440000/allocations",
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "NOT_FOUND");
+    }
+
+    fn negotiation(
+        mm: &str,
+        prices: &[f64],
+        state: crate::domain::value_objects::NegotiationState,
+        completed_at: Timestamp,
+    ) -> crate::domain::entities::negotiation::Negotiation {
+        use crate::domain::entities::counter_quote::CounterQuoteBuilder;
+        use crate::domain::entities::negotiation::{Negotiation, NegotiationRound};
+        use crate::domain::value_objects::{CounterpartyId, NegotiationId, OrderSide};
+
+        let rfq_id = RfqId::new_v4();
+        let rounds = prices
+            .iter()
+            .zip(1u8..)
+            .map(|(price, round)| {
+                let counter = CounterQuoteBuilder::new(
+                    QuoteId::new_v4(),
+                    rfq_id,
+                    CounterpartyId::new(mm),
+                    Price::new(*price).unwrap(),
+                    Quantity::new(1.0).unwrap(),
+                    completed_at.add_secs(60),
+                    round,
+                )
+                .build()
+                .unwrap();
+                NegotiationRound::new(round, counter)
+            })
+            .collect();
+        Negotiation::from_parts(
+            NegotiationId::new_v4(),
+            rfq_id,
+            CounterpartyId::new("client-1"),
+            CounterpartyId::new(mm),
+            OrderSide::Buy,
+            rounds,
+            3,
+            state,
+            completed_at.sub_secs(20),
+            completed_at,
+        )
+    }
+
+    #[tokio::test]
+    async fn negotiation_analytics_reports_improvement_and_failure_rate() {
+        use crate::domain::value_objects::NegotiationState;
+        use crate::infrastructure::persistence::NegotiationRepository;
+        use crate::infrastructure::persistence::in_memory::InMemoryNegotiationRepository;
+
+        let repo = Arc::new(InMemoryNegotiationRepository::new());
+        let now = Timestamp::now();
+        for negotiation in [
+            negotiation("mm-1", &[100.0, 99.0], NegotiationState::Accepted, now),
+            negotiation("mm-1", &[100.0], NegotiationState::Rejected, now),
+            negotiation("mm-1", &[100.0, 98.0], NegotiationState::Expired, now),
+            negotiation("mm-2", &[100.0, 90.0], NegotiationState::Accepted, now),
+        ] {
+            repo.save(&negotiation).await.unwrap();
+        }
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.negotiations = Some(repo);
+        let state = Arc::new(state);
+
+        let from = now.sub_secs(3600).to_iso8601();
+        let to = now.add_secs(60).to_iso8601();
+        let (status, body) = get_json(
+            create_test_router(Arc::clone(&state)),
+            &format!("/api/v1/negotiations/analytics?mm_id=mm-1&from={from}&to={to}"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["mm_id"], "mm-1");
+        assert_eq!(body["total"], 3);
+        assert_eq!(body["accepted"], 1);
+        assert_eq!(body["rejected"], 1);
+        assert_eq!(body["expired"], 1);
+        assert_eq!(body["failure_rate"], "0.6667");
+        assert_eq!(body["avg_improvement_bps"], "100");
+        assert_eq!(body["p50_improvement_bps"], "100");
+        assert_eq!(body["p90_improvement_bps"], "100");
+        assert_eq!(body["avg_rounds_used"], "2");
+        assert_eq!(body["avg_time_to_agreement_ms"], 20_000);
+
+        let (status, body) = get_json(
+            create_test_router(state),
+            &format!("/api/v1/negotiations/analytics?from={to}&to={from}"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+    }
+
+    #[tokio::test]
+    async fn negotiation_analytics_returns_501_when_disabled() {
+        let (status, _) = get_json(
+            create_test_router(create_test_state()),
+            "/api/v1/negotiations/analytics",
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+    }
+
+    /// State with a negotiation awaiting the market maker's counter, and a
+    /// token registry that settles on Ethereum only.
+    async fn create_test_state_with_negotiation() -> (Arc<AppState>, String) {
+        use crate::domain::entities::counter_quote::CounterQuoteBuilder;
+        use crate::domain::entities::negotiation::Negotiation;
+        use crate::domain::value_objects::{CounterpartyId, OrderSide};
+        use crate::infrastructure::blockchain::{ChainId, TokenInfo, TokenRegistry};
+        use crate::infrastructure::persistence::NegotiationRepository;
+        use crate::infrastructure::persistence::in_memory::InMemoryNegotiationRepository;
+
+        let rfq_id = RfqId::new_v4();
+        let mut negotiation = Negotiation::new(
+            rfq_id,
+            CounterpartyId::new("client-1"),
+            CounterpartyId::new("mm-1"),
+            OrderSide::Buy,
+            3,
+        )
+        .unwrap();
+        let counter = CounterQuoteBuilder::new(
+            QuoteId::new_v4(),
+            rfq_id,
+            CounterpartyId::new("client-1"),
+            Price::new(100.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(60),
+            1,
+        )
+        .build()
+        .unwrap();
+        negotiation.submit_counter(counter).unwrap();
+        let repo = Arc::new(InMemoryNegotiationRepository::new());
+        repo.save(&negotiation).await.unwrap();
+
+        let mut tokens = TokenRegistry::new();
+        tokens.register(TokenInfo::new("USDC", "USD Coin", 6).with_address(
+            ChainId::Ethereum,
+            "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+        ));
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.negotiations = Some(repo);
+        state.tokens = Some(Arc::new(parking_lot::RwLock::new(tokens)));
+        (Arc::new(state), negotiation.id().to_string())
+    }
+
+    fn counter_body(chain: &str, valid_for_secs: u64) -> serde_json::Value {
+        serde_json::json!({
+            "quote_id": QuoteId::new_v4().to_string(),
+            "price": 99.5,
+            "quantity": 1.0,
+            "expiry_seconds": 45,
+            "conditions": {
+                "valid_for_secs": valid_for_secs,
+                "settlement_chain": chain,
+                "settlement_window": {"type": "INSTANT"},
+                "note": "instant only"
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn counter_conditions_are_shown_on_the_negotiation() {
+        let (state, id) = create_test_state_with_negotiation().await;
+        let uri = format!("/api/v1/negotiations/{id}/counters");
+
+        let (status, body) = send_json_as(
+            create_test_router(Arc::clone(&state)),
+            "mm-1",
+            "POST",
+            &uri,
+            counter_body("ETHEREUM", 10),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["state"], "COUNTER_PENDING");
+
+        let (status, body) = send_json_as(
+            create_test_router(state),
+            "client-1",
+            "GET",
+            &format!("/api/v1/negotiations/{id}"),
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let round = &body["rounds"][1];
+        assert_eq!(round["from_account"], "mm-1");
+        assert_eq!(round["conditions"]["valid_for_secs"], 10);
+        assert_eq!(round["conditions"]["settlement_chain"], "ETHEREUM");
+        assert_eq!(round["conditions"]["settlement_window"]["type"], "INSTANT");
+        assert_eq!(round["conditions"]["note"], "instant only");
+        assert!(body["rounds"][0]["conditions"].is_null());
+    }
+
+    #[tokio::test]
+    async fn counter_rejects_chain_missing_from_token_registry() {
+        let (state, id) = create_test_state_with_negotiation().await;
+        let uri = format!("/api/v1/negotiations/{id}/counters");
+
+        let (status, body) = send_json_as(
+            create_test_router(Arc::clone(&state)),
+            "mm-1",
+            "POST",
+            &uri,
+            counter_body("ARBITRUM", 10),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+        assert_eq!(body["details"]["chain"], "ARBITRUM");
+
+        let (status, body) = send_json_as(
+            create_test_router(Arc::clone(&state)),
+            "mm-1",
+            "POST",
+            &uri,
+            counter_body("ETHEREUM", 600),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+
+        let (status, body) = send_json_as(
+            create_test_router(state),
+            "mm-2",
+            "POST",
+            &uri,
+            counter_body("ETHEREUM", 10),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "UNAUTHORIZED_COUNTERPARTY");
+    }
+
+    // ========================================================================
+    // RFQ venue exchanges
+    // ========================================================================
+
+    /// Sends a GET request authenticated with `roles`.
+    async fn get_json_with_roles(
+        router: Router,
+        uri: &str,
+        roles: &[&str],
+    ) -> (StatusCode, serde_json::Value) {
+        use crate::api::middleware::Claims;
+
+        let claims = Claims::new("user-1", u64::MAX, 0)
+            .with_roles(roles.iter().map(ToString::to_string).collect());
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .uri(uri)
+                    .extension(claims)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn venue_exchanges_listed_oldest_first_for_admins() {
+        use crate::infrastructure::persistence::in_memory::InMemoryRawExchangeLog;
+        use crate::infrastructure::persistence::{ExchangeDirection, RawExchange, RawExchangeLog};
+
+        let log = Arc::new(InMemoryRawExchangeLog::new());
+        let rfq_id = RfqId::new_v4();
+        let now = Timestamp::now();
+        for (direction, recorded_at, http_status) in [
+            (ExchangeDirection::Response, now.add_secs(1), Some(200)),
+            (ExchangeDirection::Request, now, None),
+        ] {
+            log.record(&RawExchange {
+                venue_id: VenueId::new("bebop"),
+                rfq_id,
+                direction,
+                payload: serde_json::json!({ "api_key": "[REDACTED]" }),
+                http_status,
+                latency_ms: http_status.map(|_| 12),
+                recorded_at,
+                expires_at: now.add_secs(3600),
+            })
+            .await
+            .unwrap();
+        }
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.venue_exchanges = Some(log);
+        let router = create_test_router(Arc::new(state));
+        let uri = format!("/api/v1/rfqs/{rfq_id}/venue-exchanges");
+
+        let (status, body) = get_json_with_roles(router.clone(), &uri, &["admin"]).await;
+        assert_eq!(status, StatusCode::OK);
+        let directions: Vec<&str> = body
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["direction"].as_str().unwrap())
+            .collect();
+        assert_eq!(directions, vec!["REQUEST", "RESPONSE"]);
+        assert_eq!(body[1]["http_status"], 200);
+        assert_eq!(body[1]["latency_ms"], 12);
+        assert_eq!(body[0]["payload"]["api_key"], "[REDACTED]");
+
+        let (status, body) = get_json_with_roles(router, &uri, &["trader"]).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "FORBIDDEN");
+    }
+
+    #[tokio::test]
+    async fn venue_exchanges_returns_501_when_disabled() {
+        let (status, _) = get_json_with_roles(
+            create_test_router(create_test_state()),
+            &format!("/api/v1/rfqs/{}/venue-exchanges", RfqId::new_v4()),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+    }
+
+    // ========================================================================
+    // RFQ summaries
+    // ========================================================================
+
+    fn summary(client: &str, state: RfqState, created_at: Timestamp) -> RfqSummary {
+        use crate::domain::value_objects::{CounterpartyId, OrderSide, Symbol};
+
+        RfqSummary {
+            rfq_id: RfqId::new_v4(),
+            client_id: CounterpartyId::new(client),
+            symbol: Symbol::new("BTC/USD").unwrap(),
+            side: OrderSide::Buy,
+            quantity: Quantity::new(1.0).unwrap(),
+            state,
+            quote_count: 2,
+            best_quote_price: Some(Price::new(50_000.0).unwrap()),
+            selected_venue_id: Some(VenueId::new("bebop")),
+            trade_id: None,
+            execution_price: None,
+            failure_reason: None,
+            created_at,
+            last_event_at: created_at,
+            last_sequence: 3,
+            aggregate_versions: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn rfq_summaries_filtered_and_paged_by_cursor() {
+        use crate::infrastructure::persistence::in_memory::InMemoryRfqSummaryStore;
+
+        let store = Arc::new(InMemoryRfqSummaryStore::new());
+        let base = Timestamp::now();
+        for (i, client) in ["client-1", "client-1", "client-2", "client-1"]
+            .into_iter()
+            .enumerate()
+        {
+            let created_at = base.add_secs(i as i64);
+            store
+                .upsert(&summary(client, RfqState::QuotesReceived, created_at))
+                .await
+                .unwrap();
+        }
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.rfq_summaries = Some(store);
+        let router = create_test_router(Arc::new(state));
+
+        let (status, first) = get_json(
+            router.clone(),
+            "/api/v1/rfqs/summary?limit=2&client_id=client-1",
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(first["data"].as_array().unwrap().len(), 2);
+        assert_eq!(first["data"][0]["best_quote_price"], "50000");
+        assert_eq!(first["data"][0]["selected_venue_id"], "bebop");
+        assert_eq!(first["data"][0]["quote_count"], 2);
+        let cursor = first["next_cursor"].as_str().unwrap();
+
+        let (status, second) = get_json(
+            router.clone(),
+            &format!("/api/v1/rfqs/summary?limit=2&client_id=client-1&cursor={cursor}"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(second["data"].as_array().unwrap().len(), 1);
+        assert!(second.get("next_cursor").is_none());
+
+        let (status, page) = get_json(
+            router.clone(),
+            "/api/v1/rfqs/summary?page=2&page_size=2&client_id=client-1",
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(page["data"].as_array().unwrap().len(), 1);
+        assert_eq!(page["pagination"]["total_items"], 3);
+
+        let (status, body) = get_json(router, "/api/v1/rfqs/summary?status=active").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+    }
+
+    #[tokio::test]
+    async fn rfq_summaries_returns_501_when_disabled() {
+        let (status, _) = get_json(
+            create_test_router(create_test_state()),
+            "/api/v1/rfqs/summary",
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+    }
+
+    #[tokio::test]
+    async fn venue_circuit_override_for_admins() {
+        use crate::api::middleware::Claims;
+        use crate::application::services::{CircuitBreakerConfig, CircuitBreakerRegistry};
+
+        let breakers = Arc::new(CircuitBreakerRegistry::new(CircuitBreakerConfig::default()));
+        let mut state = Arc::into_inner(create_test_state_with_venue().await).unwrap();
+        state.circuit_breakers = Some(Arc::clone(&breakers));
+        let router = create_test_router(Arc::new(state));
+
+        let post = |uri: &str, action: &str, role: &str| {
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("content-type", "application/json")
+                .extension(Claims::new("user-1", u64::MAX, 0).with_roles(vec![role.to_string()]))
+                .body(Body::from(
+                    serde_json::json!({ "action": action }).to_string(),
+                ))
+                .unwrap()
+        };
+        let read = |response: axum::response::Response| async move {
+            let status = response.status();
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            (
+                status,
+                serde_json::from_slice::<serde_json::Value>(&bytes).unwrap(),
+            )
+        };
+
+        let uri = "/api/v1/venues/venue-1/circuit";
+        let (status, body) = read(
+            router
+                .clone()
+                .oneshot(post(uri, "FORCE_OPEN", "admin"))
+                .await
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["state"], "OPEN");
+        assert_eq!(body["forced"], "FORCED_OPEN");
+        assert!(breakers.get("venue-1").unwrap().try_acquire().is_err());
+
+        let (status, body) = read(
+            router
+                .clone()
+                .oneshot(post(uri, "CLEAR", "trader"))
+                .await
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "FORBIDDEN");
+
+        let (status, _) = read(
+            router
+                .oneshot(post("/api/v1/venues/missing/circuit", "CLEAR", "admin"))
+                .await
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn venue_probe_reports_mixed_results() {
+        use crate::application::services::VenueProber;
+        use crate::domain::entities::venue::VenueHealth;
+        use crate::domain::value_objects::VenueType;
+        use crate::infrastructure::venues::VenueRegistry;
+
+        let registry = VenueRegistry::new();
+        let venues = Arc::new(MockVenueRepository::default());
+        for (id, healthy) in [("venue-up", true), ("venue-down", false)] {
+            registry
+                .register(Arc::new(PingVenue {
+                    venue_id: VenueId::new(id),
+                    healthy,
+                }))
+                .await;
+            VenueRepository::save(
+                venues.as_ref(),
+                &Venue::new(VenueId::new(id), id, VenueType::ExternalMM),
+            )
+            .await
+            .unwrap();
+        }
+        let prober = VenueProber::new(Arc::new(registry), Arc::clone(&venues) as _)
+            .with_failure_threshold(1);
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.venue_repository = Arc::clone(&venues) as _;
+        state.venue_prober = Some(Arc::new(prober));
+        let router = create_test_router(Arc::new(state));
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            "/api/v1/venues/probe",
+            serde_json::Value::Null,
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["probed"], 2);
+        assert_eq!(body["succeeded"], 1);
+        assert_eq!(body["failed"], 1);
+        assert_eq!(body["results"][0]["venue_id"], "venue-down");
+        assert_eq!(body["results"][0]["success"], false);
+        assert_eq!(body["results"][0]["previous_health"], "HEALTHY");
+        assert_eq!(body["results"][0]["health"], "UNHEALTHY");
+        assert_eq!(body["results"][0]["message"], "unreachable");
+        assert_eq!(body["results"][1]["venue_id"], "venue-up");
+        assert_eq!(body["results"][1]["health"], "HEALTHY");
+        let stored = VenueRepository::find_by_id(venues.as_ref(), &VenueId::new("venue-down"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.health(), VenueHealth::Unhealthy);
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            "/api/v1/venues/probe?venue_id=venue-up",
+            serde_json::Value::Null,
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["probed"], 1);
+        assert_eq!(body["results"][0]["venue_id"], "venue-up");
+
+        let (status, _) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            "/api/v1/venues/probe?venue_id=missing",
+            serde_json::Value::Null,
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _) = send_json_with_roles(
+            router,
+            "POST",
+            "/api/v1/venues/probe",
+            serde_json::Value::Null,
+            &["trader"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn venue_probe_requires_prober() {
+        let router = create_test_router(create_test_state());
+
+        let (status, _) = send_json_with_roles(
+            router,
+            "POST",
+            "/api/v1/venues/probe",
+            serde_json::Value::Null,
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+    }
+
+    #[tokio::test]
+    async fn compliance_export_returns_zip_for_admins() {
+        use crate::application::services::ComplianceExportService;
+        use crate::infrastructure::persistence::in_memory::{
+            InMemoryEventStore, InMemoryRfqRepository, InMemoryTradeRepository,
+        };
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.compliance_export = Some(Arc::new(ComplianceExportService::new(
+            Arc::new(InMemoryRfqRepository::new()),
+            Arc::new(InMemoryTradeRepository::new()),
+            Arc::new(InMemoryEventStore::new()),
+        )));
+        let router = create_test_router(Arc::new(state));
+        let uri = "/api/v1/compliance/export?counterparty_id=client-1\
+                   &from=2024-01-01T00:00:00Z&to=2024-02-01T00:00:00Z";
+
+        let (status, body) =
+            send_json_with_roles(router.clone(), "GET", uri, serde_json::Value::Null, &[]).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "FORBIDDEN");
+
+        let claims = crate::api::middleware::Claims::new("auditor-1", u64::MAX, 0)
+            .with_roles(vec!["admin".to_string()]);
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .uri(uri)
+                    .extension(claims)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "application/zip");
+        assert!(
+            response.headers()["content-disposition"]
+                .to_str()
+                .unwrap()
+                .contains("compliance-client-1-")
+        );
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(bytes.starts_with(b"PK"));
+    }
+
+    #[tokio::test]
+    async fn compliance_export_requires_service() {
+        let router = create_test_router(create_test_state());
+
+        let (status, _) = send_json_with_roles(
+            router,
+            "GET",
+            "/api/v1/compliance/export?counterparty_id=client-1\
+             &from=2024-01-01T00:00:00Z&to=2024-02-01T00:00:00Z",
+            serde_json::Value::Null,
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+    }
+
+    #[tokio::test]
+    async fn best_execution_report_is_scoped_to_the_caller() {
+        use crate::application::services::BestExecutionReportService;
+        use crate::infrastructure::persistence::in_memory::{
+            InMemoryAggregationReportRepository, InMemoryBestExecutionReportRepository,
+            InMemoryRfqRepository, InMemoryTradeRepository,
+        };
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.best_execution = Some(Arc::new(BestExecutionReportService::new(
+            Arc::new(InMemoryRfqRepository::new()),
+            Arc::new(InMemoryTradeRepository::new()),
+            Arc::new(InMemoryAggregationReportRepository::new()),
+            Arc::new(InMemoryBestExecutionReportRepository::new()),
+        )));
+        let router = create_test_router(Arc::new(state));
+        let uri =
+            "/api/v1/reports/best-execution?from=2024-01-01T00:00:00Z&to=2024-02-01T00:00:00Z";
+
+        let (status, body) =
+            send_json_with_roles(router.clone(), "GET", uri, serde_json::Value::Null, &[]).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["client_id"], "user-1");
+        assert_eq!(body["rfq_count"], 0);
+
+        let other = format!("{uri}&client_id=client-2");
+        let (status, body) =
+            send_json_with_roles(router.clone(), "GET", &other, serde_json::Value::Null, &[]).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "UNAUTHORIZED_COUNTERPARTY");
+
+        let claims = crate::api::middleware::Claims::new("auditor-1", u64::MAX, 0)
+            .with_roles(vec!["admin".to_string()]);
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .uri(format!("{other}&format=csv"))
+                    .extension(claims)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()["content-type"],
+            "text/csv; charset=utf-8"
+        );
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let csv = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(csv.lines().nth(1).unwrap().starts_with("SUMMARY,client-2,"));
+    }
+
+    /// Seconds from now until the RFC 3339 `expires_at` of an RFQ body.
+    fn secs_until_expiry(body: &serde_json::Value) -> i64 {
+        let expires_at =
+            chrono::DateTime::parse_from_rfc3339(body["expires_at"].as_str().unwrap()).unwrap();
+        (expires_at.with_timezone(&chrono::Utc) - chrono::Utc::now()).num_seconds()
+    }
+
+    fn create_test_state_with_rfq_ttl() -> Arc<AppState> {
+        use crate::application::services::RfqTtlConfigStore;
+        use crate::domain::value_objects::{RfqTtlPolicy, TtlLimits};
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.rfq_ttl = Some(Arc::new(RfqTtlConfigStore::new(RfqTtlPolicy::new(
+            TtlLimits::new(5, 60, 600),
+        ))));
+        Arc::new(state)
+    }
+
+    #[tokio::test]
+    async fn omitted_expiry_gets_the_asset_class_default() {
+        let router = create_test_router(create_test_state_with_rfq_ttl());
+        let mut body = size_mode_rfq_body(serde_json::Value::Null);
+        body.as_object_mut().unwrap().remove("expiry_seconds");
+
+        let (status, body) = send_json(router, "POST", "/api/v1/rfqs", body).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!((58..=60).contains(&secs_until_expiry(&body)));
+    }
+
+    #[tokio::test]
+    async fn expiry_outside_ttl_limits_is_rejected_with_the_range() {
+        let router = create_test_router(create_test_state_with_rfq_ttl());
+
+        for expiry_seconds in [2, 6 * 3_600] {
+            let mut body = size_mode_rfq_body(serde_json::Value::Null);
+            body["expiry_seconds"] = serde_json::json!(expiry_seconds);
+
+            let (status, body) = send_json(router.clone(), "POST", "/api/v1/rfqs", body).await;
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+            assert_eq!(body["code"], "RFQ_TTL_OUT_OF_RANGE");
+            assert_eq!(
+                body["message"],
+                format!(
+                    "TTL of {expiry_seconds}s is outside the allowed range for CRYPTO_SPOT RFQs: \
+                     5s to 600s"
+                )
+            );
+            assert_eq!(body["details"]["min_secs"], 5);
+            assert_eq!(body["details"]["max_secs"], 600);
+        }
+    }
+
+    #[tokio::test]
+    async fn rfq_ttl_limits_apply_per_asset_class_and_are_admin_only() {
+        let router = create_test_router(create_test_state_with_rfq_ttl());
+        let uri = "/api/v1/rfq-ttl-limits";
+        let limits = serde_json::json!({
+            "fallback": { "min_secs": 5, "default_secs": 60, "max_secs": 600 },
+            "by_asset_class": {
+                "CRYPTO_SPOT": { "min_secs": 30, "default_secs": 120, "max_secs": 3600 }
+            }
+        });
+
+        let (status, _) =
+            send_json_with_roles(router.clone(), "PUT", uri, limits.clone(), &[]).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let mut invalid = limits.clone();
+        invalid["fallback"]["default_secs"] = serde_json::json!(1_000);
+        let (status, _) =
+            send_json_with_roles(router.clone(), "PUT", uri, invalid, &["admin"]).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, body) =
+            send_json_with_roles(router.clone(), "PUT", uri, limits.clone(), &["admin"]).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, limits);
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "GET",
+            uri,
+            serde_json::Value::Null,
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, limits);
+
+        let mut rfq = size_mode_rfq_body(serde_json::Value::Null);
+        rfq["expiry_seconds"] = serde_json::json!(1_200);
+        let (status, _) = send_json(router.clone(), "POST", "/api/v1/rfqs", rfq.clone()).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        rfq["expiry_seconds"] = serde_json::json!(10);
+        let (status, body) = send_json(router.clone(), "POST", "/api/v1/rfqs", rfq.clone()).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["details"]["min_secs"], 30);
+
+        rfq.as_object_mut().unwrap().remove("expiry_seconds");
+        let (status, body) = send_json(router, "POST", "/api/v1/rfqs", rfq).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!((118..=120).contains(&secs_until_expiry(&body)));
+    }
+
+    /// Event consumer failing on every event until fixed.
+    #[derive(Debug, Default)]
+    struct FlakyConsumer {
+        fixed: std::sync::atomic::AtomicBool,
+    }
+
+    #[async_trait]
+    impl crate::application::services::EventConsumer for FlakyConsumer {
+        fn name(&self) -> &str {
+            "flaky"
+        }
+
+        async fn handle(
+            &self,
+            _event: &crate::infrastructure::persistence::StoredEvent,
+        ) -> crate::application::error::ApplicationResult<()> {
+            if self.fixed.load(std::sync::atomic::Ordering::SeqCst) {
+                Ok(())
+            } else {
+                Err(crate::application::error::ApplicationError::internal(
+                    "handler broken",
+                ))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn dead_letters_are_listed_and_redriven_by_admins() {
+        use crate::application::services::{ConsumeOutcome, DeadLetterQueue};
+        use crate::domain::events::domain_event::EventType;
+        use crate::domain::value_objects::{EventId, RfqId};
+        use crate::infrastructure::persistence::StoredEvent;
+        use crate::infrastructure::persistence::in_memory::InMemoryDeadLetterStore;
+
+        let consumer = Arc::new(FlakyConsumer::default());
+        let queue = Arc::new(
+            DeadLetterQueue::new(Arc::new(InMemoryDeadLetterStore::new()))
+                .with_consumer(Arc::clone(&consumer) as _)
+                .with_max_attempts(1),
+        );
+        let mut event_ids = Vec::new();
+        for sequence in 1..=2 {
+            let event = StoredEvent::new(
+                EventId::new_v4(),
+                Some(RfqId::new_v4()),
+                EventType::Rfq,
+                "RfqCreated",
+                Timestamp::now(),
+                serde_json::json!({ "sequence": sequence }),
+                sequence,
+            );
+            let outcome = queue.process("flaky", &event).await.unwrap();
+            assert!(matches!(outcome, ConsumeOutcome::Parked(_)));
+            event_ids.push(event.event_id.to_string());
+        }
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.dead_letters = Some(queue);
+        let router = create_test_router(Arc::new(state));
+        let uri = "/api/v1/dead-letters";
+
+        let (status, _) = get_json_with_roles(router.clone(), uri, &["trader"]).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, body) = get_json_with_roles(
+            router.clone(),
+            "/api/v1/dead-letters?consumer=flaky",
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.as_array().unwrap().len(), 2);
+        assert_eq!(body[0]["status"], "PARKED");
+        assert_eq!(body[0]["last_error"], "internal error: handler broken");
+
+        let (status, _) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            uri,
+            serde_json::json!({ "event_id": event_ids[0] }),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        consumer
+            .fixed
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            uri,
+            serde_json::json!({ "consumer": "flaky", "event_id": event_ids[0] }),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body[0]["event_id"], event_ids[0].as_str());
+        assert_eq!(body[0]["status"], "RESOLVED");
+        assert_eq!(body[0]["attempts"], 2);
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            uri,
+            serde_json::json!({}),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.as_array().unwrap().len(), 1);
+        assert_eq!(body[0]["event_id"], event_ids[1].as_str());
+        assert_eq!(body[0]["status"], "RESOLVED");
+
+        let (status, body) = get_json_with_roles(router, uri, &["admin"]).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn venue_maintenance_windows_are_merged_and_admin_only() {
+        let router = create_test_router(create_test_state_with_venue().await);
+        let uri = "/api/v1/venues/venue-1/maintenance";
+        let window = |start: &str, end: &str, reason: &str| serde_json::json!({ "start": start, "end": end, "reason": reason });
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            uri,
+            window("2099-01-01T00:00:00Z", "2099-01-01T02:00:00Z", "upgrade"),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["active"], false);
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            uri,
+            window("2099-01-01T01:00:00Z", "2099-01-01T03:00:00Z", "migration"),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["reason"], "migration; upgrade");
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            uri,
+            window("2099-01-02T00:00:00Z", "2099-01-02T01:00:00Z", ""),
+            &["trader"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "FORBIDDEN");
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            uri,
+            window("2099-01-02T01:00:00Z", "2099-01-02T00:00:00Z", ""),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+
+        let (status, body) = get_json_with_roles(router.clone(), uri, &["trader"]).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.as_array().unwrap().len(), 1);
+
+        let remove = "/api/v1/venues/venue-1/maintenance/2099-01-01T00:00:00Z";
+        let (status, _) = send_json_with_roles(
+            router.clone(),
+            "DELETE",
+            remove,
+            serde_json::Value::Null,
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = send_json_with_roles(
+            router,
+            "DELETE",
+            remove,
+            serde_json::Value::Null,
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    // ========================================================================
+    // Platform fees
+    // ========================================================================
+
+    fn create_platform_fee_test_state() -> Arc<AppState> {
+        use crate::infrastructure::persistence::in_memory::{
+            InMemoryFeeWaiverRepository, InMemoryPlatformFeeScheduleRepository,
+        };
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.platform_fee_schedules = Some(Arc::new(InMemoryPlatformFeeScheduleRepository::new()));
+        state.fee_waivers = Some(Arc::new(InMemoryFeeWaiverRepository::new()));
+        Arc::new(state)
+    }
+
+    #[tokio::test]
+    async fn platform_fee_schedules_are_admin_managed_per_tier() {
+        let router = create_test_router(create_platform_fee_test_state());
+        let schedule = |members: &[&str]| {
+            serde_json::json!({
+                "counterparties": members,
+                "rates": [{
+                    "asset_class": "CRYPTO_SPOT",
+                    "bands": [
+                        { "min_notional": "0", "bps": "5" },
+                        { "min_notional": "1000000", "bps": "2" }
+                    ],
+                    "min_fee": "10",
+                    "max_fee": "500"
+                }]
+            })
+        };
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "PUT",
+            "/api/v1/fees/platform-schedules/gold",
+            schedule(&["client-1"]),
+            &["trader"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "FORBIDDEN");
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "PUT",
+            "/api/v1/fees/platform-schedules/gold",
+            schedule(&["client-1"]),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["tier"], "gold");
+        assert_eq!(body["rates"][0]["bands"][1]["bps"], "2");
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "PUT",
+            "/api/v1/fees/platform-schedules/silver",
+            schedule(&["client-1"]),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "CONFLICT");
+
+        let mut inverted = schedule(&[]);
+        inverted["rates"][0]["min_fee"] = serde_json::json!("1000");
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "PUT",
+            "/api/v1/fees/platform-schedules/silver",
+            inverted,
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+
+        let (status, body) = get_json_with_roles(
+            router.clone(),
+            "/api/v1/fees/platform-schedules",
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.as_array().unwrap().len(), 1);
+
+        let uri = "/api/v1/fees/platform-schedules/gold";
+        let (status, _) = send_json_with_roles(
+            router.clone(),
+            "DELETE",
+            uri,
+            serde_json::Value::Null,
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, _) = get_json_with_roles(router, uri, &["admin"]).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn fee_waivers_validate_the_discount() {
+        let router = create_test_router(create_platform_fee_test_state());
+        let uri = "/api/v1/fees/waivers/client-1";
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "PUT",
+            uri,
+            serde_json::json!({ "discount_pct": "150" }),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "PUT",
+            uri,
+            serde_json::json!({ "discount_pct": "25", "expires_at": "2000-01-01T00:00:00Z" }),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["discount_pct"], "25");
+        assert_eq!(body["active"], false);
+
+        let (status, body) = get_json_with_roles(router, uri, &["admin"]).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["counterparty_id"], "client-1");
+    }
+
+    // ========================================================================
+    // Trading calendars
+    // ========================================================================
+
+    #[tokio::test]
+    async fn trading_calendars_are_admin_managed() {
+        use crate::application::services::TradingCalendarService;
+        use crate::infrastructure::persistence::in_memory::InMemoryTradingCalendarRepository;
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.trading_calendars = Some(Arc::new(TradingCalendarService::new(Arc::new(
+            InMemoryTradingCalendarRepository::new(),
+        ))));
+        let router = create_test_router(Arc::new(state));
+        let calendar = serde_json::json!({
+            "timezone": "America/New_York",
+            "holidays": ["2024-12-25", "2024-07-04"]
+        });
+
+        let (status, _) = send_json_with_roles(
+            router.clone(),
+            "PUT",
+            "/api/v1/trading-calendars/nyse",
+            calendar.clone(),
+            &["trader"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "PUT",
+            "/api/v1/trading-calendars/nyse",
+            calendar,
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["name"], "NYSE");
+        assert_eq!(body["weekends_closed"], true);
+        assert_eq!(
+            body["holidays"],
+            serde_json::json!(["2024-07-04", "2024-12-25"])
+        );
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "PUT",
+            "/api/v1/trading-calendars/crypto",
+            serde_json::json!({ "timezone": "UTC" }),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+
+        let (status, body) =
+            get_json_with_roles(router.clone(), "/api/v1/trading-calendars", &["admin"]).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body[0]["name"], "CRYPTO");
+        assert_eq!(body[1]["timezone"], "America/New_York");
+
+        let (status, _) = send_json_with_roles(
+            router.clone(),
+            "DELETE",
+            "/api/v1/trading-calendars/NYSE",
+            serde_json::Value::Null,
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, _) =
+            get_json_with_roles(router, "/api/v1/trading-calendars/nyse", &["admin"]).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    // ========================================================================
+    // Settlement Batches
+    // ========================================================================
+
+    #[derive(Debug)]
+    struct UnusedNettingTxBuilder;
+
+    #[async_trait::async_trait]
+    impl crate::application::services::NettingTxBuilder for UnusedNettingTxBuilder {
+        async fn build(
+            &self,
+            _batch: &crate::domain::entities::NettingBatch,
+        ) -> crate::application::error::ApplicationResult<crate::application::services::SettlementTx>
+        {
+            Err(crate::application::error::ApplicationError::Internal(
+                "not used".to_string(),
+            ))
+        }
+    }
+
+    /// Returns a router whose netting service has one open batch of two
+    /// trades, and that batch's ID.
+    async fn create_settlement_batch_test_router() -> (Router, String) {
+        use crate::application::services::{NettingConfig, NettingService};
+        use crate::domain::entities::RfqBuilder;
+        use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
+        use crate::domain::value_objects::timestamp::MockClock;
+        use crate::domain::value_objects::{
+            CounterpartyId, Instrument, OrderSide, Price, Quantity, QuoteId, Symbol, VenueId,
+        };
+        use crate::infrastructure::blockchain::{ChainId, EthereumClient};
+        use crate::infrastructure::persistence::in_memory::{
+            InMemoryNettingBatchRepository, InMemoryRfqRepository, InMemoryTradeRepository,
+        };
+        use crate::infrastructure::persistence::{RfqRepository, TradeRepository as _};
+
+        let trades = Arc::new(InMemoryTradeRepository::new());
+        let rfqs = Arc::new(InMemoryRfqRepository::new());
+        for side in [OrderSide::Buy, OrderSide::Sell] {
+            let rfq = RfqBuilder::new(
+                CounterpartyId::new("client-1"),
+                Instrument::new(
+                    Symbol::new("ETH/USDC").unwrap(),
+                    AssetClass::CryptoSpot,
+                    SettlementMethod::default(),
+                ),
+                side,
+                Quantity::new(1.0).unwrap(),
+                Timestamp::now().add_secs(300),
+            )
+            .build();
+            rfqs.save(&rfq).await.unwrap();
+            let trade = Trade::new(
+                rfq.id(),
+                QuoteId::new_v4(),
+                VenueId::new("mm-1"),
+                Price::new(2000.0).unwrap(),
+                Quantity::new(1.0).unwrap(),
+            );
+            trades.save(&trade).await.unwrap();
+        }
+
+        let blockchain =
+            EthereumClient::new(ChainId::Ethereum, "http://127.0.0.1:1", Vec::new()).unwrap();
+        let netting = NettingService::new(
+            trades,
+            rfqs as Arc<dyn RfqRepository>,
+            Arc::new(InMemoryNettingBatchRepository::new()),
+            Arc::new(blockchain),
+            Arc::new(UnusedNettingTxBuilder),
+            NettingConfig {
+                window: std::time::Duration::from_secs(86_400),
+                ..NettingConfig::default()
+            },
+        )
+        .with_clock(Arc::new(MockClock::new(
+            Timestamp::now().add_secs(2 * 86_400),
+        )));
+        let batch = netting.create_batches().await.unwrap().pop().unwrap();
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.netting = Some(Arc::new(netting));
+        (create_test_router(Arc::new(state)), batch.id().to_string())
+    }
+
+    #[tokio::test]
+    async fn settlement_batches_are_admin_only() {
+        let (router, _) = create_settlement_batch_test_router().await;
+
+        let (status, body) =
+            get_json_with_roles(router, "/api/v1/settlement-batches", &["trader"]).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "FORBIDDEN");
+    }
+
+    #[tokio::test]
+    async fn settlement_batches_cancel_only_before_submission() {
+        let (router, id) = create_settlement_batch_test_router().await;
+        let batch_uri = format!("/api/v1/settlement-batches/{id}");
+        let cancel_uri = format!("{batch_uri}/cancel");
+
+        let (status, body) = get_json_with_roles(
+            router.clone(),
+            "/api/v1/settlement-batches?status=OPEN",
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.as_array().unwrap().len(), 1);
+        assert_eq!(body[0]["net_base"], "0");
+        assert_eq!(body[0]["trade_ids"].as_array().unwrap().len(), 2);
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            &cancel_uri,
+            serde_json::json!({}),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "CANCELLED");
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            &cancel_uri,
+            serde_json::json!({}),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "INVALID_STATE");
+
+        let (status, body) = get_json_with_roles(router.clone(), &batch_uri, &["admin"]).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "CANCELLED");
+
+        let (status, _) =
+            get_json_with_roles(router, "/api/v1/settlement-batches/not-a-uuid", &["admin"]).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    // ========================================================================
+    // Webhooks
+    // ========================================================================
+
+    #[derive(Debug)]
+    struct NoopWebhookPublisher;
+
+    #[async_trait::async_trait]
+    impl crate::application::services::WebhookEventPublisher for NoopWebhookPublisher {
+        async fn publish_webhook_subscription_disabled(
+            &self,
+            _event: crate::domain::events::WebhookSubscriptionDisabled,
+        ) -> crate::application::error::ApplicationResult<()> {
+            Ok(())
+        }
+    }
+
+    fn create_webhook_test_state() -> Arc<AppState> {
+        use crate::application::services::{RetryPolicy, WebhookDeliveryService};
+        use crate::infrastructure::notifications::rfq_webhook::RfqWebhookClient;
+        use crate::infrastructure::persistence::in_memory::{
+            InMemoryWebhookDeliveryLog, InMemoryWebhookSubscriptionRepository,
+        };
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.webhooks = Some(Arc::new(WebhookDeliveryService::new(
+            Arc::new(InMemoryWebhookSubscriptionRepository::new()),
+            Arc::new(InMemoryWebhookDeliveryLog::new()),
+            RfqWebhookClient::with_default_timeout().unwrap(),
+            Arc::new(NoopWebhookPublisher),
+            RetryPolicy::new(1, 1, 1, 1.0, 0.0),
+        )));
+        Arc::new(state)
+    }
+
+    /// Sends a JSON request authenticated with `roles`.
+    async fn send_json_with_roles(
+        router: Router,
+        method: &str,
+        uri: &str,
+        body: serde_json::Value,
+        roles: &[&str],
+    ) -> (StatusCode, serde_json::Value) {
+        use crate::api::middleware::Claims;
+
+        let claims = Claims::new("user-1", u64::MAX, 0)
+            .with_roles(roles.iter().map(ToString::to_string).collect());
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("Content-Type", "application/json")
+                    .extension(claims)
+                    .body(Body::from(serde_json::to_string(&body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let value = if bytes.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_slice(&bytes).unwrap()
+        };
+        (status, value)
+    }
+
+    #[tokio::test]
+    async fn webhook_subscription_crud_for_admins() {
+        let router = create_test_router(create_webhook_test_state());
+        let admin = &["admin"];
+
+        let (status, created) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            "/api/v1/webhooks",
+            serde_json::json!({
+                "url": "https://risk.example.com/hooks",
+                "secret": "s3cret",
+                "event_filters": ["TradeExecuted"]
+            }),
+            admin,
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["active"], true);
+        assert!(created.get("secret").is_none());
+        let id = created["id"].as_str().unwrap().to_string();
+        let uri = format!("/api/v1/webhooks/{id}");
+
+        let (status, updated) = send_json_with_roles(
+            router.clone(),
+            "PUT",
+            &uri,
+            serde_json::json!({
+                "event_filters": ["SettlementConfirmed", "TradeExecuted"],
+                "active": false
+            }),
+            admin,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(updated["active"], false);
+        assert_eq!(updated["event_filters"].as_array().unwrap().len(), 2);
+        assert_eq!(updated["url"], "https://risk.example.com/hooks");
+
+        let (status, listed) = get_json_with_roles(router.clone(), "/api/v1/webhooks", admin).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(listed.as_array().unwrap().len(), 1);
+        assert!(listed[0].get("secret").is_none());
+
+        let (status, deliveries) =
+            get_json_with_roles(router.clone(), &format!("{uri}/deliveries"), admin).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(deliveries["data"].as_array().unwrap().is_empty());
+
+        let (status, _) = send_json_with_roles(
+            router.clone(),
+            "DELETE",
+            &uri,
+            serde_json::Value::Null,
+            admin,
+        )
+        .await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, body) = get_json_with_roles(router, &uri, admin).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn webhook_create_rejects_unknown_events() {
+        let (status, body) = send_json_with_roles(
+            create_test_router(create_webhook_test_state()),
+            "POST",
+            "/api/v1/webhooks",
+            serde_json::json!({
+                "url": "https://risk.example.com/hooks",
+                "secret": "s3cret",
+                "event_filters": ["RfqCreated"]
+            }),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+    }
+
+    #[tokio::test]
+    async fn webhooks_require_admin() {
+        let (status, body) = get_json_with_roles(
+            create_test_router(create_webhook_test_state()),
+            "/api/v1/webhooks",
+            &["trader"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "FORBIDDEN");
+    }
+
+    #[tokio::test]
+    async fn webhooks_return_501_when_disabled() {
+        let (status, _) = get_json_with_roles(
+            create_test_router(create_test_state()),
+            "/api/v1/webhooks",
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+    }
+
+    #[tokio::test]
+    async fn webhook_redeliver_resends_recorded_delivery() {
+        use crate::domain::entities::webhook_subscription::WebhookSubscription;
+        use crate::domain::value_objects::EventId;
+        use crate::infrastructure::persistence::webhook_delivery_log::{
+            WebhookDelivery, WebhookDeliveryStatus,
+        };
+        use wiremock::matchers::{header_exists, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header_exists("X-OTC-Signature"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let state = create_webhook_test_state();
+        let webhooks = state.webhooks.clone().unwrap();
+        let subscription = WebhookSubscription::new(
+            format!("{}/hooks", server.uri()),
+            "s3cret",
+            vec!["TradeExecuted".to_string()],
+        )
+        .unwrap();
+        webhooks.subscriptions().save(&subscription).await.unwrap();
+        let mut original = WebhookDelivery::pending(
+            subscription.id(),
+            EventId::new_v4(),
+            "TradeExecuted",
+            serde_json::json!({ "event_name": "TradeExecuted" }),
+        );
+        original.status = WebhookDeliveryStatus::Failed;
+        webhooks.deliveries().save(&original).await.unwrap();
+        let router = create_test_router(state);
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            &format!(
+                "/api/v1/webhooks/{}/deliveries/{}/redeliver",
+                subscription.id(),
+                original.id
+            ),
+            serde_json::Value::Null,
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "SUCCEEDED");
+        assert_eq!(body["redelivery_of"], original.id.to_string());
+
+        let (status, log) = get_json_with_roles(
+            router,
+            &format!("/api/v1/webhooks/{}/deliveries", subscription.id()),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(log["data"].as_array().unwrap().len(), 2);
+    }
+
+    async fn create_test_state_with_settlement_addresses() -> Arc<AppState> {
+        use crate::application::services::SettlementAddressService;
+        use crate::domain::entities::{Counterparty, CounterpartyType};
+        use crate::domain::value_objects::CounterpartyId;
+        use crate::infrastructure::persistence::CounterpartyRepository;
+        use crate::infrastructure::persistence::in_memory::InMemoryCounterpartyRepository;
+
+        let counterparties = Arc::new(InMemoryCounterpartyRepository::new());
+        counterparties
+            .save(&Counterparty::new(
+                CounterpartyId::new("client-1"),
+                "Client 1",
+                CounterpartyType::Client,
+            ))
+            .await
+            .unwrap();
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.settlement_addresses = Some(Arc::new(SettlementAddressService::new(counterparties)));
+        Arc::new(state)
+    }
+
+    #[tokio::test]
+    async fn settlement_address_is_verified_by_signed_challenge() {
+        use ethers::signers::{LocalWallet, Signer};
+        use std::str::FromStr;
+
+        let wallet = LocalWallet::from_str(
+            "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+        )
+        .unwrap();
+        let address = format!("{:#x}", wallet.address());
+        let router = create_test_router(create_test_state_with_settlement_addresses().await);
+        let uri = "/api/v1/counterparties/client-1/addresses";
+
+        let (status, added) = send_json_as(
+            router.clone(),
+            "client-1",
+            "POST",
+            uri,
+            serde_json::json!({ "chain": "POLYGON", "token": "usdc", "address": address }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(added["token"], "USDC");
+        assert_eq!(added["chain_id"], 137);
+        assert_eq!(added["verified"], false);
+
+        let (status, challenge) = send_json_as(
+            router.clone(),
+            "client-1",
+            "POST",
+            &format!("{uri}/POLYGON/challenge?token=USDC"),
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let signature = wallet
+            .sign_message(challenge["message"].as_str().unwrap())
+            .await
+            .unwrap();
+
+        let (status, verified) = send_json_as(
+            router.clone(),
+            "client-1",
+            "POST",
+            &format!("{uri}/POLYGON/verify?token=USDC"),
+            serde_json::json!({ "signature": signature.to_string() }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(verified["verified"], true);
+
+        let (status, listed) =
+            send_json_as(router, "client-1", "GET", uri, serde_json::Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(listed[0]["address"], address);
+        assert_eq!(listed[0]["verified"], true);
+    }
+
+    #[tokio::test]
+    async fn settlement_addresses_of_other_counterparties_are_denied() {
+        let router = create_test_router(create_test_state_with_settlement_addresses().await);
+
+        let (status, body) = send_json_as(
+            router,
+            "client-2",
+            "GET",
+            "/api/v1/counterparties/client-1/addresses",
+            serde_json::Value::Null,
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "UNAUTHORIZED_COUNTERPARTY");
+    }
+
+    #[tokio::test]
+    async fn duplicate_settlement_address_conflicts() {
+        let router = create_test_router(create_test_state_with_settlement_addresses().await);
+        let body = serde_json::json!({
+            "chain": "ARBITRUM",
+            "address": "0x3333333333333333333333333333333333333333",
+        });
+        let uri = "/api/v1/counterparties/client-1/addresses";
+
+        let (status, _) = send_json_as(router.clone(), "client-1", "POST", uri, body.clone()).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let (status, _) = send_json_as(router, "client-1", "POST", uri, body).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn price_bounds_are_admin_managed_and_audited() {
+        use crate::application::services::PriceBoundsConfigStore;
+        use crate::domain::value_objects::PriceBoundsSettings;
+        use crate::infrastructure::persistence::in_memory::InMemoryPriceBoundsConfigRepository;
+
+        let store = Arc::new(PriceBoundsConfigStore::new(
+            Arc::new(InMemoryPriceBoundsConfigRepository::new()),
+            PriceBoundsSettings::default(),
+        ));
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.price_bounds = Some(Arc::clone(&store));
+        let router = create_test_router(Arc::new(state));
+        let uri = "/api/v1/risk/price-bounds";
+        let body = serde_json::json!({
+            "liquid_tolerance_pct": "0.03",
+            "semi_liquid_tolerance_pct": "0.05",
+            "illiquid_tolerance_pct": "0.08",
+            "overrides": { "btc/usd": "0.01" },
+        });
+
+        let (status, _) = send_json_with_roles(router.clone(), "PUT", uri, body.clone(), &[]).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, saved) =
+            send_json_with_roles(router.clone(), "PUT", uri, body, &["admin"]).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(saved["liquid_tolerance_pct"], "0.03");
+        assert_eq!(saved["overrides"]["BTC/USD"], "0.01");
+
+        let (status, current) = send_json_with_roles(
+            router.clone(),
+            "GET",
+            uri,
+            serde_json::Value::Null,
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(current, saved);
+
+        let history = store.history().await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].previous, PriceBoundsSettings::default());
+
+        let (status, _) = send_json_with_roles(
+            router,
+            "PUT",
+            uri,
+            serde_json::json!({
+                "liquid_tolerance_pct": "-0.01",
+                "semi_liquid_tolerance_pct": "0.05",
+                "illiquid_tolerance_pct": "0.08",
+            }),
+            &["admin"],
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn instrument_liquidity_is_derived_from_activity() {
+        use crate::application::services::LiquidityClassifier;
+        use crate::infrastructure::persistence::in_memory::{
+            InMemoryRfqRepository, InMemoryTradeRepository,
+        };
+
+        let uri = "/api/v1/instruments/btc/usd/liquidity";
+        let router = create_test_router(create_test_state());
+        let (status, _) =
+            send_json_with_roles(router, "GET", uri, serde_json::Value::Null, &[]).await;
+        assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.liquidity = Some(Arc::new(LiquidityClassifier::new(
+            Arc::new(InMemoryRfqRepository::new()),
+            Arc::new(InMemoryTradeRepository::new()),
+        )));
+        let router = create_test_router(Arc::new(state));
+
+        let (status, body) =
+            send_json_with_roles(router, "GET", uri, serde_json::Value::Null, &[]).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["symbol"], "BTC/USD");
+        assert_eq!(body["classification"], "Illiquid");
+        assert_eq!(body["volume"], "0");
+        assert_eq!(body["rfq_count"], 0);
+        assert!(body["avg_spread_bps"].is_null());
+    }
+
+    fn create_test_state_with_tokens() -> Arc<AppState> {
+        use crate::infrastructure::blockchain::TokenRegistry;
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.tokens = Some(Arc::new(parking_lot::RwLock::new(
+            TokenRegistry::with_common_tokens(),
+        )));
+        Arc::new(state)
+    }
+
+    #[tokio::test]
+    async fn tokens_are_admin_managed_per_chain() {
+        let router = create_test_router(create_test_state_with_tokens());
+        let admin = &["admin"];
+        let body = serde_json::json!({
+            "name": "Euro Coin",
+            "decimals": 6,
+            "addresses": { "BASE": "0x60a3E35Cc302bFA44Cb288Bc5a4F316Fdb1adb42" },
+        });
+
+        let (status, _) = send_json_with_roles(
+            router.clone(),
+            "PUT",
+            "/api/v1/tokens/eurc",
+            body.clone(),
+            &[],
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, saved) =
+            send_json_with_roles(router.clone(), "PUT", "/api/v1/tokens/eurc", body, admin).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(saved[0]["symbol"], "EURC");
+        assert_eq!(saved[0]["chain"], "BASE");
+        assert_eq!(saved[0]["chain_id"], 8453);
+        assert_eq!(saved[0]["enabled_for_settlement"], true);
+
+        let (status, disabled) = send_json_with_roles(
+            router.clone(),
+            "POST",
+            "/api/v1/tokens/EURC/settlement",
+            serde_json::json!({ "enabled": false }),
+            admin,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(disabled[0]["enabled_for_settlement"], false);
+
+        let (status, listed) = send_json_with_roles(
+            router.clone(),
+            "GET",
+            "/api/v1/tokens?chain=base",
+            serde_json::Value::Null,
+            &[],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let listed = listed.as_array().unwrap();
+        assert!(listed.iter().all(|entry| entry["chain"] == "BASE"));
+        assert!(listed.iter().any(|entry| entry["symbol"] == "EURC"));
+
+        let (status, body) = send_json_with_roles(
+            router.clone(),
+            "PUT",
+            "/api/v1/tokens/USDC2",
+            serde_json::json!({
+                "name": "Copy",
+                "decimals": 6,
+                "addresses": { "BASE": "0x833589fcd6edb6e08f4c7c32d4f71b54bda02913" },
+            }),
+            admin,
+        )
+        .await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert!(body["message"].as_str().unwrap().contains("USDC"));
+
+        let (status, _) = send_json_with_roles(
+            router.clone(),
+            "DELETE",
+            "/api/v1/tokens/EURC",
+            serde_json::Value::Null,
+            admin,
+        )
+        .await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, _) = send_json_with_roles(
+            router,
+            "DELETE",
+            "/api/v1/tokens/EURC",
+            serde_json::Value::Null,
+            admin,
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    async fn send_json_as_admin(
+        router: Router,
+        admin: &str,
+        method: &str,
+        uri: &str,
+        body: serde_json::Value,
+    ) -> (StatusCode, serde_json::Value) {
+        use crate::api::middleware::Claims;
+
+        let claims = Claims::new(admin, u64::MAX, 0).with_roles(vec!["admin".to_string()]);
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("Content-Type", "application/json")
+                    .extension(claims)
+                    .body(Body::from(serde_json::to_string(&body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let value = if bytes.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_slice(&bytes).unwrap()
+        };
+        (status, value)
+    }
+
+    async fn create_test_state_with_counterparty_erasure() -> Arc<AppState> {
+        use crate::application::services::CounterpartyErasureService;
+        use crate::domain::entities::{Counterparty, CounterpartyType};
+        use crate::domain::value_objects::CounterpartyId;
+        use crate::infrastructure::persistence::CounterpartyRepository;
+        use crate::infrastructure::persistence::in_memory::{
+            InMemoryCounterpartyKeyStore, InMemoryCounterpartyRepository,
+            InMemoryErasureRequestRepository, InMemoryRfqRepository,
+        };
+
+        let counterparties = Arc::new(InMemoryCounterpartyRepository::new());
+        counterparties
+            .save(&Counterparty::new(
+                CounterpartyId::new("client-1"),
+                "Client 1",
+                CounterpartyType::Client,
+            ))
+            .await
+            .unwrap();
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.counterparty_erasure = Some(Arc::new(CounterpartyErasureService::new(
+            counterparties,
+            Arc::new(InMemoryRfqRepository::new()),
+            Arc::new(InMemoryErasureRequestRepository::new()),
+            Arc::new(InMemoryCounterpartyKeyStore::new()),
+        )));
+        Arc::new(state)
+    }
+
+    #[tokio::test]
+    async fn counterparty_erasure_is_requested_approved_and_executed() {
+        let router = create_test_router(create_test_state_with_counterparty_erasure().await);
+
+        let (status, requested) = send_json_as_admin(
+            router.clone(),
+            "legal-1",
+            "POST",
+            "/api/v1/counterparties/client-1/erasure-requests",
+            serde_json::json!({ "reason": "client offboarded" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(requested["status"], "REQUESTED");
+        let uri = format!(
+            "/api/v1/erasure-requests/{}",
+            requested["id"].as_str().unwrap()
+        );
+
+        let (status, _) = send_json_as_admin(
+            router.clone(),
+            "legal-1",
+            "POST",
+            &format!("{uri}/approve"),
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, _) = send_json_as_admin(
+            router.clone(),
+            "legal-2",
+            "POST",
+            &format!("{uri}/execute"),
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        let (status, approved) = send_json_as_admin(
+            router.clone(),
+            "legal-2",
+            "POST",
+            &format!("{uri}/approve"),
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(approved["approved_by"], "legal-2");
+
+        let (status, executed) = send_json_as_admin(
+            router.clone(),
+            "legal-2",
+            "POST",
+            &format!("{uri}/execute"),
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(executed["status"], "EXECUTED");
+        assert!(
+            executed["counterparty_id"]
+                .as_str()
+                .unwrap()
+                .starts_with("erased-")
+        );
+
+        let (status, fetched) =
+            send_json_as_admin(router, "legal-3", "GET", &uri, serde_json::Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(fetched["counterparty_id"], executed["counterparty_id"]);
+    }
+
+    #[tokio::test]
+    async fn counterparty_erasure_requires_admin() {
+        let router = create_test_router(create_test_state_with_counterparty_erasure().await);
+
+        let (status, _) = send_json_as(
+            router,
+            "client-1",
+            "POST",
+            "/api/v1/counterparties/client-1/erasure-requests",
+            serde_json::json!({ "reason": "client offboarded" }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    fn create_test_state_with_venue_credentials() -> Arc<AppState> {
+        use crate::infrastructure::persistence::in_memory::InMemoryVenueCredentialRepository;
+
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.venue_credentials = Some(Arc::new(InMemoryVenueCredentialRepository::new()));
+        Arc::new(state)
+    }
+
+    #[tokio::test]
+    async fn venue_credentials_are_stored_rotated_and_never_returned() {
+        let router = create_test_router(create_test_state_with_venue_credentials());
+        let uri = "/api/v1/counterparties/client-1/venue-credentials";
+
+        let (status, stored) = send_json_as(
+            router.clone(),
+            "client-1",
+            "PUT",
+            &format!("{uri}/hashflow"),
+            serde_json::json!({ "api_key": "old-key" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(stored["venue_id"], "hashflow");
+        assert_eq!(stored["has_api_secret"], false);
+
+        let (status, rotated) = send_json_as(
+            router.clone(),
+            "client-1",
+            "PUT",
+            &format!("{uri}/hashflow"),
+            serde_json::json!({ "api_key": "new-key", "api_secret": "new-secret" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(rotated["has_api_secret"], true);
+        assert_eq!(rotated["created_at"], stored["created_at"]);
+
+        let (status, listed) = send_json_as(
+            router.clone(),
+            "client-1",
+            "GET",
+            uri,
+            serde_json::Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(listed.as_array().unwrap().len(), 1);
+        let listed = listed.to_string();
+        assert!(!listed.contains("new-key"));
+        assert!(!listed.contains("new-secret"));
+
+        let delete = |router| {
+            send_json_as(
+                router,
+                "client-1",
+                "DELETE",
+                "/api/v1/counterparties/client-1/venue-credentials/hashflow",
+                serde_json::Value::Null,
+            )
+        };
+        let (status, _) = delete(router.clone()).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, _) = delete(router).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn venue_credentials_are_validated_and_private_to_the_counterparty() {
+        let router = create_test_router(create_test_state_with_venue_credentials());
+        let uri = "/api/v1/counterparties/client-1/venue-credentials/bebop";
+
+        let (status, body) = send_json_as(
+            router.clone(),
+            "client-1",
+            "PUT",
+            uri,
+            serde_json::json!({ "api_key": "key with spaces" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+
+        let (status, body) = send_json_as(
+            router,
+            "client-2",
+            "PUT",
+            uri,
+            serde_json::json!({ "api_key": "stolen-key" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "UNAUTHORIZED_COUNTERPARTY");
+    }
+
+    // ========================================================================
+    // Forced state transitions
+    // ========================================================================
+
+    #[derive(Debug)]
+    struct NoopForcedTransitionPublisher;
+
+    #[async_trait::async_trait]
+    impl crate::application::services::ForcedTransitionEventPublisher
+        for NoopForcedTransitionPublisher
+    {
+        async fn publish_forced_state_transition(
+            &self,
+            _event: crate::domain::events::ForcedStateTransition,
+        ) -> crate::application::error::ApplicationResult<()> {
+            Ok(())
+        }
+    }
+
+    struct ForcedTransitionHarness {
+        router: Router,
+        fixtures: crate::test_support::Fixtures,
+        events: Arc<crate::infrastructure::persistence::in_memory::InMemoryEventStore>,
+        rfq_id: RfqId,
+    }
+
+    /// Builds a router whose forced transitions act on an RFQ stuck in
+    /// `EXECUTING`.
+    async fn forced_transition_harness() -> ForcedTransitionHarness {
+        use crate::application::services::ForcedTransitionService;
+        use crate::infrastructure::persistence::RfqRepository;
+        use crate::infrastructure::persistence::in_memory::InMemoryEventStore;
+        use crate::test_support::{FixtureBuilder, RfqSeed};
+
+        let fixtures = FixtureBuilder::new()
+            .with_rfq(RfqSeed::new("stuck", "client-1", "BTC/USD").with_quote("venue-1", 50_000.0))
+            .build()
+            .await
+            .unwrap();
+        let mut rfq = fixtures.stored_rfq("stuck").await.unwrap().unwrap();
+        let quote_id = rfq.quotes().first().unwrap().id();
+        rfq.select_quote(quote_id).unwrap();
+        rfq.start_execution().unwrap();
+        fixtures.rfqs.save(&rfq).await.unwrap();
+
+        let events = Arc::new(InMemoryEventStore::new());
+        let mut state = Arc::into_inner(create_test_state()).unwrap();
+        state.forced_transitions = Some(Arc::new(ForcedTransitionService::new(
+            fixtures.rfqs.clone(),
+            fixtures.trades.clone(),
+            events.clone(),
+            Arc::new(NoopForcedTransitionPublisher),
+        )));
+        ForcedTransitionHarness {
+            router: create_test_router(Arc::new(state)),
+            fixtures,
+            events,
+            rfq_id: rfq.id(),
+        }
+    }
+
+    #[tokio::test]
+    async fn stuck_execution_is_forced_and_audited() {
+        use crate::infrastructure::persistence::EventStore;
+
+        let harness = forced_transition_harness().await;
+        let uri = format!("/api/v1/admin/rfqs/{}/force-state", harness.rfq_id);
+
+        let (status, body) = send_json_as_admin(
+            harness.router,
+            "ops-1",
+            "POST",
+            &uri,
+            serde_json::json!({ "state": "FAILED", "justification": "venue lost the order" }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["from"], "EXECUTING");
+        assert_eq!(body["to"], "FAILED");
+        assert_eq!(body["actor"], "ops-1");
+        let rfq = harness.fixtures.stored_rfq("stuck").await.unwrap().unwrap();
+        assert_eq!(rfq.state(), RfqState::Failed);
+        let audit = harness.events.get_events(harness.rfq_id).await.unwrap();
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].event_name, "ForcedStateTransition");
+        assert_eq!(audit[0].payload["justification"], "venue lost the order");
+        assert_eq!(body["event_id"], audit[0].event_id.to_string());
+    }
+
+    #[tokio::test]
+    async fn forced_transitions_outside_the_allowlist_are_rejected() {
+        use crate::infrastructure::persistence::EventStore;
+
+        let harness = forced_transition_harness().await;
+        let uri = format!("/api/v1/admin/rfqs/{}/force-state", harness.rfq_id);
+
+        let (status, body) = send_json_as_admin(
+            harness.router.clone(),
+            "ops-1",
+            "POST",
+            &uri,
+            serde_json::json!({ "state": "CANCELLED", "justification": "client asked" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "RFQ_INVALID_STATE");
+
+        let (status, _) = send_json_as_admin(
+            harness.router,
+            "ops-1",
+            "POST",
+            &uri,
+            serde_json::json!({ "state": "EXECUTED", "justification": " " }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(
+            harness
+                .events
+                .get_events(harness.rfq_id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn forced_transitions_require_admin() {
+        let harness = forced_transition_harness().await;
+
+        let (status, _) = send_json_as(
+            harness.router,
+            "client-1",
+            "POST",
+            &format!("/api/v1/admin/rfqs/{}/force-state", harness.rfq_id),
+            serde_json::json!({ "state": "EXECUTED", "justification": "done" }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
 }
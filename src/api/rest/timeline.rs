@@ -0,0 +1,492 @@
+//! # RFQ Timeline
+//!
+//! Audit projection of the stored domain events of a single RFQ.
+//!
+//! [`build_timeline`] flattens [`StoredEvent`]s into [`TimelineEntry`] rows
+//! with a human-readable summary and the party the event is attributed to,
+//! ordered by time with ties broken by event ID. [`to_csv`] renders the same
+//! rows as an RFC 4180 export for compliance review.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::api::rest::timeline::{build_timeline, to_csv};
+//! use otc_rfq::domain::events::rfq_events::QuoteRequestFailed;
+//! use otc_rfq::domain::value_objects::{CounterpartyId, RfqId, VenueId};
+//! use otc_rfq::infrastructure::persistence::StoredEvent;
+//!
+//! let event = QuoteRequestFailed::new(RfqId::new_v4(), VenueId::new("venue-1"), "timeout");
+//! let stored = StoredEvent::from_event(&event, 1).unwrap();
+//!
+//! let timeline = build_timeline(vec![stored], &CounterpartyId::new("client-1"));
+//! assert_eq!(timeline[0].summary, "Quote request to venue-1 failed: timeout");
+//! assert!(to_csv(&timeline).starts_with("at,event_id,actor,event_name,summary,payload\r\n"));
+//! ```
+
+use crate::domain::events::admin_events::ForcedStateTransition;
+use crate::domain::events::rfq_events::{
+    ExecutionFailed, ExecutionStarted, InternalCrossProposed, QuorumOverridden,
+    QuoteCollectionCompleted, QuoteCollectionStarted, QuoteReceived, QuoteRequestFailed,
+    QuoteRequested, QuoteSelected, RfqCancelled, RfqCreated, RfqExpired,
+};
+use crate::domain::events::trade_events::{
+    AllocationFailed, AllocationFilled, SettlementConfirmed, SettlementDeadLettered,
+    SettlementFailed, SettlementInitiated, TradeExecuted,
+};
+use crate::domain::value_objects::CounterpartyId;
+use crate::infrastructure::persistence::StoredEvent;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use utoipa::{IntoParams, ToSchema};
+
+/// Actor recorded for events raised by the platform itself.
+pub const SYSTEM_ACTOR: &str = "system";
+
+/// CSV header row, in column order.
+const CSV_HEADER: [&str; 6] = [
+    "at",
+    "event_id",
+    "actor",
+    "event_name",
+    "summary",
+    "payload",
+];
+
+/// A single row of an RFQ timeline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TimelineEntry {
+    /// When the event occurred (RFC 3339).
+    pub at: String,
+    /// Event ID (UUID).
+    pub event_id: String,
+    /// Party the event is attributed to: the client, a venue, an
+    /// administrator, or `system`.
+    pub actor: String,
+    /// Domain event name (e.g. `QuoteReceived`).
+    pub event_name: String,
+    /// Human-readable description.
+    pub summary: String,
+    /// Original event payload.
+    #[schema(value_type = Object)]
+    pub payload: serde_json::Value,
+}
+
+/// Output format of the timeline endpoint.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TimelineFormat {
+    /// JSON array of entries.
+    #[default]
+    Json,
+    /// RFC 4180 CSV export.
+    Csv,
+}
+
+/// Query parameters of the timeline endpoint.
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TimelineParams {
+    /// Output format (`json` or `csv`, default `json`).
+    #[serde(default)]
+    pub format: TimelineFormat,
+}
+
+/// Returns the trade ID carried in a trade or settlement event payload.
+#[must_use]
+pub fn linked_trade_id(event: &StoredEvent) -> Option<&str> {
+    event.payload.get("trade_id")?.as_str()
+}
+
+/// Builds the timeline of an RFQ from its stored events.
+///
+/// Events are deduplicated by ID and sorted by timestamp, with ties broken
+/// by event ID so the order is stable across requests. `client_id` is the
+/// actor of client-initiated events.
+#[must_use]
+pub fn build_timeline(events: Vec<StoredEvent>, client_id: &CounterpartyId) -> Vec<TimelineEntry> {
+    let mut seen = HashSet::new();
+    let mut events: Vec<StoredEvent> = events
+        .into_iter()
+        .filter(|e| seen.insert(e.event_id))
+        .collect();
+    events.sort_by_key(|e| (e.timestamp, e.event_id.get()));
+
+    events
+        .into_iter()
+        .map(|event| {
+            let (actor, summary) = describe(&event, client_id);
+            TimelineEntry {
+                at: event.timestamp.to_string(),
+                event_id: event.event_id.to_string(),
+                actor,
+                event_name: event.event_name,
+                summary,
+                payload: event.payload,
+            }
+        })
+        .collect()
+}
+
+/// Renders timeline entries as RFC 4180 CSV.
+///
+/// Rows end with CRLF; fields containing commas, quotes, or line breaks are
+/// quoted with embedded quotes doubled. The payload is compact JSON.
+#[must_use]
+pub fn to_csv(entries: &[TimelineEntry]) -> String {
+    let mut out = String::new();
+    push_csv_row(&mut out, CSV_HEADER);
+    for entry in entries {
+        let payload = entry.payload.to_string();
+        push_csv_row(
+            &mut out,
+            [
+                entry.at.as_str(),
+                entry.event_id.as_str(),
+                entry.actor.as_str(),
+                entry.event_name.as_str(),
+                entry.summary.as_str(),
+                payload.as_str(),
+            ],
+        );
+    }
+    out
+}
+
+pub(crate) fn push_csv_row<'a>(out: &mut String, fields: impl IntoIterator<Item = &'a str>) {
+    for (i, field) in fields.into_iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        if field.contains([',', '"', '\r', '\n']) {
+            out.push('"');
+            out.push_str(&field.replace('"', "\"\""));
+            out.push('"');
+        } else {
+            out.push_str(field);
+        }
+    }
+    out.push_str("\r\n");
+}
+
+/// Returns the `(actor, summary)` of an event.
+///
+/// Unknown events, or payloads that no longer match their type, fall back
+/// to the system actor and the event name.
+fn describe(event: &StoredEvent, client_id: &CounterpartyId) -> (String, String) {
+    let client = || client_id.to_string();
+    let system = || SYSTEM_ACTOR.to_string();
+
+    let described = match event.event_name.as_str() {
+        "RfqCreated" => decode::<RfqCreated>(event).map(|e| {
+            (
+                e.client_id.to_string(),
+                format!(
+                    "RFQ created: {} {} {}, expires {}",
+                    e.side,
+                    e.quantity,
+                    e.instrument.symbol(),
+                    e.expires_at
+                ),
+            )
+        }),
+        "QuoteCollectionStarted" => decode::<QuoteCollectionStarted>(event).map(|e| {
+            let venues: Vec<String> = e.venue_ids.iter().map(ToString::to_string).collect();
+            let mut description = format!(
+                "Quote collection started with {} venue(s): {}",
+                venues.len(),
+                venues.join(", ")
+            );
+            if !e.excluded_venues.is_empty() {
+                let excluded: Vec<String> = e
+                    .excluded_venues
+                    .iter()
+                    .map(|x| format!("{} ({})", x.venue_id, x.reason))
+                    .collect();
+                description.push_str(&format!("; excluded: {}", excluded.join(", ")));
+            }
+            (system(), description)
+        }),
+        "QuoteRequested" => decode::<QuoteRequested>(event)
+            .map(|e| (system(), format!("Quote requested from {}", e.venue_id))),
+        "QuoteReceived" => decode::<QuoteReceived>(event).map(|e| {
+            (
+                e.venue_id.to_string(),
+                format!(
+                    "Quote {} received from {}: {} @ {}, valid until {}",
+                    e.quote_id, e.venue_id, e.quantity, e.price, e.valid_until
+                ),
+            )
+        }),
+        "QuoteRequestFailed" => decode::<QuoteRequestFailed>(event).map(|e| {
+            (
+                e.venue_id.to_string(),
+                format!("Quote request to {} failed: {}", e.venue_id, e.reason),
+            )
+        }),
+        "QuoteCollectionCompleted" => decode::<QuoteCollectionCompleted>(event).map(|e| {
+            (
+                system(),
+                format!(
+                    "Quote collection completed: {} quote(s) received, {} venue(s) failed",
+                    e.quotes_received, e.venues_failed
+                ),
+            )
+        }),
+        "QuoteSelected" => decode::<QuoteSelected>(event).map(|e| {
+            (
+                client(),
+                format!(
+                    "Quote {} from {} selected at {}",
+                    e.quote_id, e.venue_id, e.price
+                ),
+            )
+        }),
+        "QuorumOverridden" => decode::<QuorumOverridden>(event).map(|e| {
+            (
+                client(),
+                format!(
+                    "Quote quorum overridden for quote {} ({}): {}",
+                    e.quote_id, e.shortfall, e.reason
+                ),
+            )
+        }),
+        "ExecutionStarted" => decode::<ExecutionStarted>(event).map(|e| {
+            (
+                system(),
+                format!(
+                    "Execution of quote {} started on {}",
+                    e.quote_id, e.venue_id
+                ),
+            )
+        }),
+        "ExecutionFailed" => decode::<ExecutionFailed>(event).map(|e| {
+            (
+                system(),
+                format!("Execution of quote {} failed: {}", e.quote_id, e.reason),
+            )
+        }),
+        "RfqCancelled" => decode::<RfqCancelled>(event).map(|e| {
+            let summary = match e.reason {
+                Some(reason) => format!("RFQ cancelled from {}: {}", e.previous_state, reason),
+                None => format!("RFQ cancelled from {}", e.previous_state),
+            };
+            (client(), summary)
+        }),
+        "RfqExpired" => decode::<RfqExpired>(event)
+            .map(|e| (system(), format!("RFQ expired from {}", e.previous_state))),
+        "InternalCrossProposed" => decode::<InternalCrossProposed>(event).map(|e| {
+            (
+                system(),
+                format!(
+                    "Internal cross proposed with RFQ {}: {} @ {}",
+                    e.counterpart_rfq_id, e.quantity, e.price
+                ),
+            )
+        }),
+        "TradeExecuted" => decode::<TradeExecuted>(event).map(|e| {
+            (
+                e.venue_id.to_string(),
+                format!(
+                    "Trade {} executed on {}: {} @ {}",
+                    e.trade_id, e.venue_id, e.quantity, e.price
+                ),
+            )
+        }),
+        "AllocationFilled" => decode::<AllocationFilled>(event).map(|e| {
+            (
+                e.venue_id.to_string(),
+                format!(
+                    "Allocation of trade {} filled on {}: {} @ {}",
+                    e.trade_id, e.venue_id, e.quantity, e.price
+                ),
+            )
+        }),
+        "AllocationFailed" => decode::<AllocationFailed>(event).map(|e| {
+            (
+                e.venue_id.to_string(),
+                format!(
+                    "Allocation of trade {} failed on {}: {}",
+                    e.trade_id, e.venue_id, e.reason
+                ),
+            )
+        }),
+        "SettlementInitiated" => decode::<SettlementInitiated>(event).map(|e| {
+            (
+                system(),
+                format!(
+                    "Settlement of trade {} initiated ({})",
+                    e.trade_id, e.settlement_method
+                ),
+            )
+        }),
+        "SettlementConfirmed" => decode::<SettlementConfirmed>(event).map(|e| {
+            let summary = match e.tx_hash {
+                Some(tx_hash) => {
+                    format!("Settlement of trade {} confirmed: {}", e.trade_id, tx_hash)
+                }
+                None => format!("Settlement of trade {} confirmed", e.trade_id),
+            };
+            (system(), summary)
+        }),
+        "SettlementFailed" => decode::<SettlementFailed>(event).map(|e| {
+            (
+                system(),
+                format!("Settlement of trade {} failed: {}", e.trade_id, e.reason),
+            )
+        }),
+        "SettlementDeadLettered" => decode::<SettlementDeadLettered>(event).map(|e| {
+            let summary = match e.last_error {
+                Some(error) => format!(
+                    "Settlement of trade {} dead-lettered after {} attempt(s): {}",
+                    e.trade_id, e.attempts, error
+                ),
+                None => format!(
+                    "Settlement of trade {} dead-lettered after {} attempt(s)",
+                    e.trade_id, e.attempts
+                ),
+            };
+            (system(), summary)
+        }),
+        "ForcedStateTransition" => decode::<ForcedStateTransition>(event).map(|e| {
+            let subject = match e.trade_id {
+                Some(trade_id) => format!("Settlement of trade {trade_id}"),
+                None => "RFQ".to_string(),
+            };
+            (
+                e.actor,
+                format!(
+                    "{subject} forced from {} to {}: {}",
+                    e.from, e.to, e.justification
+                ),
+            )
+        }),
+        _ => None,
+    };
+
+    described.unwrap_or_else(|| (system(), event.event_name.clone()))
+}
+
+fn decode<T: DeserializeOwned>(event: &StoredEvent) -> Option<T> {
+    serde_json::from_value(event.payload.clone()).ok()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::events::domain_event::EventType;
+    use crate::domain::value_objects::timestamp::Timestamp;
+    use crate::domain::value_objects::{EventId, RfqId};
+
+    fn entry(summary: &str, payload: serde_json::Value) -> TimelineEntry {
+        TimelineEntry {
+            at: "2026-01-01T00:00:00+00:00".to_string(),
+            event_id: "e1".to_string(),
+            actor: "venue-1".to_string(),
+            event_name: "QuoteRequestFailed".to_string(),
+            summary: summary.to_string(),
+            payload,
+        }
+    }
+
+    fn stored(event_id: &str, at: Timestamp, name: &str) -> StoredEvent {
+        StoredEvent::new(
+            EventId::new(uuid::Uuid::parse_str(event_id).unwrap()),
+            Some(RfqId::new_v4()),
+            EventType::Rfq,
+            name,
+            at,
+            serde_json::json!({}),
+            1,
+        )
+    }
+
+    #[test]
+    fn csv_quotes_commas_quotes_and_newlines() {
+        let csv = to_csv(&[entry(
+            "venue said \"no\", twice\nthen left",
+            serde_json::json!({"reason": "a,b"}),
+        )]);
+        let mut lines = csv.split("\r\n");
+        assert_eq!(
+            lines.next().unwrap(),
+            "at,event_id,actor,event_name,summary,payload"
+        );
+        assert_eq!(
+            lines.next().unwrap(),
+            "2026-01-01T00:00:00+00:00,e1,venue-1,QuoteRequestFailed,\
+             \"venue said \"\"no\"\", twice\nthen left\",\"{\"\"reason\"\":\"\"a,b\"\"}\""
+        );
+        assert_eq!(lines.next(), Some(""));
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn csv_leaves_plain_fields_unquoted() {
+        let csv = to_csv(&[entry("plain", serde_json::json!(null))]);
+        assert!(csv.ends_with(",plain,null\r\n"));
+    }
+
+    #[test]
+    fn ties_are_broken_by_event_id() {
+        let at = Timestamp::from_secs(1_000).unwrap();
+        let events = vec![
+            stored("00000000-0000-0000-0000-000000000002", at, "B"),
+            stored(
+                "00000000-0000-0000-0000-000000000003",
+                at.sub_secs(1),
+                "First",
+            ),
+            stored("00000000-0000-0000-0000-000000000001", at, "A"),
+        ];
+
+        let names: Vec<String> = build_timeline(events, &CounterpartyId::new("c"))
+            .into_iter()
+            .map(|e| e.event_name)
+            .collect();
+        assert_eq!(names, vec!["First", "A", "B"]);
+    }
+
+    #[test]
+    fn duplicate_events_are_dropped() {
+        let at = Timestamp::from_secs(1_000).unwrap();
+        let event = stored("00000000-0000-0000-0000-000000000001", at, "A");
+        let timeline = build_timeline(vec![event.clone(), event], &CounterpartyId::new("c"));
+        assert_eq!(timeline.len(), 1);
+    }
+
+    #[test]
+    fn forced_transitions_are_attributed_to_the_administrator() {
+        use crate::domain::value_objects::RfqState;
+
+        let event = ForcedStateTransition::rfq(
+            RfqId::new_v4(),
+            RfqState::Executing,
+            RfqState::Executed,
+            "ops-1",
+            "venue confirmed fill",
+        );
+        let stored = StoredEvent::from_event(&event, 1).unwrap();
+
+        let timeline = build_timeline(vec![stored], &CounterpartyId::new("c"));
+        let entry = timeline.first().unwrap();
+        assert_eq!(entry.actor, "ops-1");
+        assert_eq!(
+            entry.summary,
+            "RFQ forced from EXECUTING to EXECUTED: venue confirmed fill"
+        );
+    }
+
+    #[test]
+    fn unknown_events_fall_back_to_name() {
+        let at = Timestamp::from_secs(1_000).unwrap();
+        let event = stored("00000000-0000-0000-0000-000000000001", at, "SomethingNew");
+        let timeline = build_timeline(vec![event], &CounterpartyId::new("c"));
+        let [entry] = timeline.as_slice() else {
+            unreachable!("expected one entry")
+        };
+        assert_eq!(entry.actor, SYSTEM_ACTOR);
+        assert_eq!(entry.summary, "SomethingNew");
+    }
+}
@@ -0,0 +1,716 @@
+//! # Trade Export
+//!
+//! Flat, fixed-column export of trades for downstream reporting.
+//!
+//! [`TradeExportRow`] flattens a [`Trade`] into the [`COLUMNS`] finance
+//! reconciles against: fills, fees, settlement state and the on-chain
+//! settlement transaction. [`export_stream`] walks the trade repository by
+//! keyset cursor and encodes each page as soon as it is loaded, so an export
+//! never holds more than one page in memory. CSV is always available;
+//! Parquet requires the `parquet` feature.
+//!
+//! Exports are capped at [`MAX_EXPORT_ROWS`] rows; the handler rejects
+//! larger requests up front instead of truncating them.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::api::rest::trade_export::{TradeExportRow, to_csv};
+//! use otc_rfq::domain::entities::trade::Trade;
+//! use otc_rfq::domain::value_objects::{Price, Quantity, QuoteId, RfqId, VenueId};
+//!
+//! let trade = Trade::new(
+//!     RfqId::new_v4(),
+//!     QuoteId::new_v4(),
+//!     VenueId::new("venue-1"),
+//!     Price::new(100.0).unwrap(),
+//!     Quantity::new(2.0).unwrap(),
+//! );
+//!
+//! let csv = to_csv(&[TradeExportRow::from(&trade)], true);
+//! assert!(csv.starts_with("trade_id,rfq_id,quote_id,venue_id,"));
+//! ```
+
+use crate::api::rest::handlers::{TradeFilter, TradeRepository};
+use crate::api::rest::timeline::push_csv_row;
+use crate::domain::entities::trade::{SettlementState, Trade};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::infrastructure::persistence::PageCursor;
+use bytes::Bytes;
+use futures::Stream;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::io;
+use std::sync::Arc;
+use tracing::error;
+use utoipa::{IntoParams, ToSchema};
+
+/// Maximum number of rows a single export may contain.
+pub const MAX_EXPORT_ROWS: u64 = 100_000;
+
+/// Number of trades loaded and encoded per page.
+const PAGE_SIZE: usize = 500;
+
+/// Export columns, in order.
+pub const COLUMNS: [&str; 23] = [
+    "trade_id",
+    "rfq_id",
+    "quote_id",
+    "venue_id",
+    "created_at",
+    "updated_at",
+    "price",
+    "quantity",
+    "notional",
+    "settlement_state",
+    "settlement_attempts",
+    "chain_tx_hash",
+    "venue_execution_ref",
+    "fill_count",
+    "filled_quantity",
+    "failed_quantity",
+    "fill_vwap",
+    "taker_fee",
+    "maker_fee",
+    "net_fee",
+    "fees",
+    "failure_reason",
+    "unwinds_trade_id",
+];
+
+/// Output format of the trade export endpoint.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TradeExportFormat {
+    /// RFC 4180 CSV.
+    #[default]
+    Csv,
+    /// Apache Parquet (requires the `parquet` feature).
+    Parquet,
+}
+
+impl TradeExportFormat {
+    /// Returns the `Content-Type` of the encoded export.
+    #[must_use]
+    pub const fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Parquet => "application/vnd.apache.parquet",
+        }
+    }
+
+    /// Returns the file extension of the encoded export.
+    #[must_use]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Parquet => "parquet",
+        }
+    }
+
+    /// Returns true if this build can encode the format.
+    #[must_use]
+    pub const fn is_supported(self) -> bool {
+        match self {
+            Self::Csv => true,
+            Self::Parquet => cfg!(feature = "parquet"),
+        }
+    }
+}
+
+/// Query parameters of the trade export endpoint.
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TradeExportParams {
+    /// Only trades created at or after this time (RFC 3339).
+    pub from: Option<String>,
+    /// Only trades created at or before this time (RFC 3339).
+    pub to: Option<String>,
+    /// Output format (`csv` or `parquet`, default `csv`).
+    #[serde(default)]
+    pub format: TradeExportFormat,
+}
+
+/// Returns true if the trade was created within `[from, to]`.
+#[must_use]
+pub fn created_within(trade: &Trade, from: Option<Timestamp>, to: Option<Timestamp>) -> bool {
+    let created_at = trade.created_at();
+    from.is_none_or(|from| created_at >= from) && to.is_none_or(|to| created_at <= to)
+}
+
+/// One row of a trade export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TradeExportRow {
+    /// Trade ID.
+    pub trade_id: String,
+    /// RFQ ID.
+    pub rfq_id: String,
+    /// Quote ID.
+    pub quote_id: String,
+    /// Venue ID.
+    pub venue_id: String,
+    /// When the trade was created.
+    pub created_at: Timestamp,
+    /// When the trade was last updated.
+    pub updated_at: Timestamp,
+    /// Execution price.
+    pub price: Decimal,
+    /// Executed quantity.
+    pub quantity: Decimal,
+    /// Price times quantity, if it does not overflow.
+    pub notional: Option<Decimal>,
+    /// Settlement state.
+    pub settlement_state: SettlementState,
+    /// Settlement retries made after a failure.
+    pub settlement_attempts: u32,
+    /// On-chain settlement transaction hash.
+    pub chain_tx_hash: Option<String>,
+    /// Venue's execution reference.
+    pub venue_execution_ref: Option<String>,
+    /// Number of venue allocations (fills) of a multi-MM trade.
+    pub fill_count: u32,
+    /// Quantity filled across allocations.
+    pub filled_quantity: Option<Decimal>,
+    /// Quantity whose allocation failed.
+    pub failed_quantity: Option<Decimal>,
+    /// Volume-weighted average price across allocations.
+    pub fill_vwap: Option<Decimal>,
+    /// Taker fee.
+    pub taker_fee: Option<Decimal>,
+    /// Maker fee.
+    pub maker_fee: Option<Decimal>,
+    /// Net fee.
+    pub net_fee: Option<Decimal>,
+    /// Itemized fees as `KIND amount CURRENCY`, separated by `; `.
+    pub fees: String,
+    /// Last settlement error, if settlement failed.
+    pub failure_reason: Option<String>,
+    /// Trade whose filled leg this trade reverses, for unwind trades.
+    pub unwinds_trade_id: Option<String>,
+}
+
+impl From<&Trade> for TradeExportRow {
+    fn from(trade: &Trade) -> Self {
+        let fees = trade
+            .fees()
+            .iter()
+            .map(|fee| format!("{} {} {}", fee.kind(), fee.amount(), fee.currency()))
+            .collect::<Vec<_>>()
+            .join("; ");
+        Self {
+            trade_id: trade.id().to_string(),
+            rfq_id: trade.rfq_id().to_string(),
+            quote_id: trade.quote_id().to_string(),
+            venue_id: trade.venue_id().to_string(),
+            created_at: trade.created_at(),
+            updated_at: trade.updated_at(),
+            price: trade.price().get(),
+            quantity: trade.quantity().get(),
+            notional: trade.total_value().map(|v| v.get()),
+            settlement_state: trade.settlement_state(),
+            settlement_attempts: trade.settlement_attempts(),
+            chain_tx_hash: trade.settlement_tx_ref().map(str::to_string),
+            venue_execution_ref: trade.venue_execution_ref().map(str::to_string),
+            fill_count: u32::try_from(trade.allocations().len()).unwrap_or(u32::MAX),
+            filled_quantity: trade.filled_allocation_quantity().map(|q| q.get()),
+            failed_quantity: trade.failed_allocation_quantity().map(|q| q.get()),
+            fill_vwap: trade.allocation_vwap().map(|p| p.get()),
+            taker_fee: trade.taker_fee(),
+            maker_fee: trade.maker_fee(),
+            net_fee: trade.net_fee(),
+            fees,
+            failure_reason: trade.failure_reason().map(str::to_string),
+            unwinds_trade_id: trade.unwinds().map(|id| id.to_string()),
+        }
+    }
+}
+
+impl TradeExportRow {
+    /// Returns the row as text fields in [`COLUMNS`] order.
+    ///
+    /// Decimals keep their full scale; missing values are empty.
+    #[must_use]
+    pub fn fields(&self) -> [String; 23] {
+        let opt = |value: Option<Decimal>| value.map(|v| v.to_string()).unwrap_or_default();
+        [
+            self.trade_id.clone(),
+            self.rfq_id.clone(),
+            self.quote_id.clone(),
+            self.venue_id.clone(),
+            self.created_at.to_string(),
+            self.updated_at.to_string(),
+            self.price.to_string(),
+            self.quantity.to_string(),
+            opt(self.notional),
+            self.settlement_state.to_string(),
+            self.settlement_attempts.to_string(),
+            self.chain_tx_hash.clone().unwrap_or_default(),
+            self.venue_execution_ref.clone().unwrap_or_default(),
+            self.fill_count.to_string(),
+            opt(self.filled_quantity),
+            opt(self.failed_quantity),
+            opt(self.fill_vwap),
+            opt(self.taker_fee),
+            opt(self.maker_fee),
+            opt(self.net_fee),
+            self.fees.clone(),
+            self.failure_reason.clone().unwrap_or_default(),
+            self.unwinds_trade_id.clone().unwrap_or_default(),
+        ]
+    }
+}
+
+/// Renders rows as RFC 4180 CSV, preceded by the header row if `header`.
+///
+/// Quoting follows [`timeline::to_csv`](crate::api::rest::timeline::to_csv).
+#[must_use]
+pub fn to_csv(rows: &[TradeExportRow], header: bool) -> String {
+    let mut out = String::new();
+    if header {
+        push_csv_row(&mut out, COLUMNS);
+    }
+    for row in rows {
+        let fields = row.fields();
+        push_csv_row(&mut out, fields.iter().map(String::as_str));
+    }
+    out
+}
+
+/// Streams every trade matching `filter` created within `[from, to]`,
+/// newest first, encoded as `format`.
+///
+/// Trades are loaded [`PAGE_SIZE`] at a time through
+/// [`TradeRepository::list_after`] and the stream ends after at most
+/// [`MAX_EXPORT_ROWS`] rows. A repository or encoding failure ends the
+/// stream with an error, aborting the response.
+pub fn export_stream(
+    repository: Arc<dyn TradeRepository>,
+    filter: TradeFilter,
+    from: Option<Timestamp>,
+    to: Option<Timestamp>,
+    format: TradeExportFormat,
+) -> impl Stream<Item = Result<Bytes, io::Error>> + Send {
+    let export = Export {
+        repository,
+        filter,
+        from,
+        cursor: to.map(|to| PageCursor::at_or_before(to.timestamp_millis())),
+        remaining: MAX_EXPORT_ROWS,
+        format,
+        encoder: None,
+        exhausted: false,
+        finished: false,
+    };
+    futures::stream::try_unfold(export, |mut export| async move {
+        let chunk = export.next_chunk().await.inspect_err(|e| {
+            error!("Trade export failed: {}", e);
+        })?;
+        Ok(chunk.map(|chunk| (chunk, export)))
+    })
+}
+
+/// State of an in-flight export.
+struct Export {
+    repository: Arc<dyn TradeRepository>,
+    filter: TradeFilter,
+    from: Option<Timestamp>,
+    cursor: Option<PageCursor>,
+    remaining: u64,
+    format: TradeExportFormat,
+    encoder: Option<Encoder>,
+    exhausted: bool,
+    finished: bool,
+}
+
+impl Export {
+    /// Returns the next non-empty chunk, or `None` once the export is done.
+    async fn next_chunk(&mut self) -> Result<Option<Bytes>, io::Error> {
+        loop {
+            if self.finished {
+                return Ok(None);
+            }
+            let chunk = match self.encoder.as_mut() {
+                None => {
+                    let (encoder, preamble) = Encoder::start(self.format)?;
+                    self.encoder = Some(encoder);
+                    preamble
+                }
+                Some(encoder) if self.exhausted || self.remaining == 0 => {
+                    self.finished = true;
+                    encoder.finish()?
+                }
+                Some(_) => {
+                    let rows = self.next_page().await?;
+                    match self.encoder.as_mut() {
+                        Some(encoder) if !rows.is_empty() => encoder.encode(&rows)?,
+                        _ => Bytes::new(),
+                    }
+                }
+            };
+            if !chunk.is_empty() {
+                return Ok(Some(chunk));
+            }
+        }
+    }
+
+    /// Loads the next page of rows and advances the cursor.
+    async fn next_page(&mut self) -> Result<Vec<TradeExportRow>, io::Error> {
+        let limit = usize::try_from(self.remaining)
+            .unwrap_or(usize::MAX)
+            .min(PAGE_SIZE);
+        let page = self
+            .repository
+            .list_after(self.cursor.as_ref(), limit, &self.filter)
+            .await
+            .map_err(io::Error::other)?;
+
+        self.exhausted = page.len() < limit;
+        self.cursor = page.last().map(PageCursor::from_trade);
+        let rows: Vec<TradeExportRow> = page
+            .iter()
+            .take_while(|trade| created_within(trade, self.from, None))
+            .map(TradeExportRow::from)
+            .collect();
+        // Pages are newest first: once a trade predates `from`, all later ones do.
+        if rows.len() < page.len() {
+            self.exhausted = true;
+        }
+        self.remaining = self.remaining.saturating_sub(rows.len() as u64);
+        Ok(rows)
+    }
+}
+
+/// Incremental encoder of export pages.
+enum Encoder {
+    Csv,
+    #[cfg(feature = "parquet")]
+    Parquet(Box<parquet_encoding::ParquetEncoder>),
+}
+
+impl Encoder {
+    /// Creates the encoder and returns the bytes preceding the first page.
+    fn start(format: TradeExportFormat) -> Result<(Self, Bytes), io::Error> {
+        match format {
+            TradeExportFormat::Csv => Ok((Self::Csv, Bytes::from(to_csv(&[], true)))),
+            #[cfg(feature = "parquet")]
+            TradeExportFormat::Parquet => {
+                let mut encoder = parquet_encoding::ParquetEncoder::new()?;
+                let preamble = encoder.take_written();
+                Ok((Self::Parquet(Box::new(encoder)), preamble))
+            }
+            #[cfg(not(feature = "parquet"))]
+            TradeExportFormat::Parquet => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "Parquet export requires the parquet feature",
+            )),
+        }
+    }
+
+    /// Encodes one page of rows.
+    fn encode(&mut self, rows: &[TradeExportRow]) -> Result<Bytes, io::Error> {
+        match self {
+            Self::Csv => Ok(Bytes::from(to_csv(rows, false))),
+            #[cfg(feature = "parquet")]
+            Self::Parquet(encoder) => encoder.encode(rows),
+        }
+    }
+
+    /// Returns the bytes following the last page.
+    fn finish(&mut self) -> Result<Bytes, io::Error> {
+        match self {
+            Self::Csv => Ok(Bytes::new()),
+            #[cfg(feature = "parquet")]
+            Self::Parquet(encoder) => encoder.finish(),
+        }
+    }
+}
+
+#[cfg(feature = "parquet")]
+pub use parquet_encoding::{DECIMAL_PRECISION, DECIMAL_SCALE, schema};
+
+#[cfg(feature = "parquet")]
+mod parquet_encoding {
+    //! Parquet encoding of export rows, one row group per page.
+
+    use super::{COLUMNS, TradeExportRow};
+    use arrow_array::builder::{
+        Decimal128Builder, StringBuilder, TimestampMillisecondBuilder, UInt32Builder,
+    };
+    use arrow_array::{ArrayRef, RecordBatch, StringArray};
+    use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef, TimeUnit};
+    use bytes::Bytes;
+    use parquet::arrow::ArrowWriter;
+    use rust_decimal::Decimal;
+    use std::io;
+    use std::sync::Arc;
+
+    /// Precision of Decimal columns, matching the database's `DECIMAL(38, 18)`.
+    pub const DECIMAL_PRECISION: u8 = 38;
+
+    /// Scale of Decimal columns; values with more fractional digits are rounded.
+    pub const DECIMAL_SCALE: i8 = 18;
+
+    /// Returns the Arrow schema of a Parquet export, in [`COLUMNS`] order.
+    #[must_use]
+    pub fn schema() -> SchemaRef {
+        let text = DataType::Utf8;
+        let timestamp = DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into()));
+        let decimal = DataType::Decimal128(DECIMAL_PRECISION, DECIMAL_SCALE);
+        let types = [
+            (&text, false),
+            (&text, false),
+            (&text, false),
+            (&text, false),
+            (&timestamp, false),
+            (&timestamp, false),
+            (&decimal, false),
+            (&decimal, false),
+            (&decimal, true),
+            (&text, false),
+            (&DataType::UInt32, false),
+            (&text, true),
+            (&text, true),
+            (&DataType::UInt32, false),
+            (&decimal, true),
+            (&decimal, true),
+            (&decimal, true),
+            (&decimal, true),
+            (&decimal, true),
+            (&decimal, true),
+            (&text, false),
+            (&text, true),
+            (&text, true),
+        ];
+        let fields: Vec<Field> = COLUMNS
+            .iter()
+            .zip(types)
+            .map(|(name, (data_type, nullable))| Field::new(*name, data_type.clone(), nullable))
+            .collect();
+        Arc::new(Schema::new(fields))
+    }
+
+    /// Streaming Parquet writer that hands out bytes as row groups close.
+    pub(super) struct ParquetEncoder {
+        writer: ArrowWriter<Vec<u8>>,
+    }
+
+    impl ParquetEncoder {
+        pub(super) fn new() -> Result<Self, io::Error> {
+            let writer =
+                ArrowWriter::try_new(Vec::new(), schema(), None).map_err(io::Error::other)?;
+            Ok(Self { writer })
+        }
+
+        /// Takes the bytes written so far.
+        pub(super) fn take_written(&mut self) -> Bytes {
+            Bytes::from(std::mem::take(self.writer.inner_mut()))
+        }
+
+        /// Writes `rows` as one row group and returns its bytes.
+        pub(super) fn encode(&mut self, rows: &[TradeExportRow]) -> Result<Bytes, io::Error> {
+            let batch = record_batch(rows).map_err(io::Error::other)?;
+            self.writer.write(&batch).map_err(io::Error::other)?;
+            self.writer.flush().map_err(io::Error::other)?;
+            Ok(self.take_written())
+        }
+
+        /// Writes the footer and returns the remaining bytes.
+        pub(super) fn finish(&mut self) -> Result<Bytes, io::Error> {
+            self.writer.finish().map_err(io::Error::other)?;
+            Ok(self.take_written())
+        }
+    }
+
+    fn record_batch(rows: &[TradeExportRow]) -> Result<RecordBatch, ArrowError> {
+        let text = |value: fn(&TradeExportRow) -> Option<&str>| -> ArrayRef {
+            let mut builder = StringBuilder::new();
+            for row in rows {
+                builder.append_option(value(row));
+            }
+            Arc::new(builder.finish())
+        };
+        let timestamp = |value: fn(&TradeExportRow) -> i64| -> ArrayRef {
+            let mut builder = TimestampMillisecondBuilder::new().with_timezone("UTC");
+            for row in rows {
+                builder.append_value(value(row));
+            }
+            Arc::new(builder.finish())
+        };
+        let count = |value: fn(&TradeExportRow) -> u32| -> ArrayRef {
+            let mut builder = UInt32Builder::new();
+            for row in rows {
+                builder.append_value(value(row));
+            }
+            Arc::new(builder.finish())
+        };
+        let decimal =
+            |value: fn(&TradeExportRow) -> Option<Decimal>| -> Result<ArrayRef, ArrowError> {
+                let mut builder = Decimal128Builder::new()
+                    .with_precision_and_scale(DECIMAL_PRECISION, DECIMAL_SCALE)?;
+                for row in rows {
+                    builder.append_option(value(row).map(to_decimal128).transpose()?);
+                }
+                Ok(Arc::new(builder.finish()))
+            };
+
+        let columns = vec![
+            text(|r| Some(&r.trade_id)),
+            text(|r| Some(&r.rfq_id)),
+            text(|r| Some(&r.quote_id)),
+            text(|r| Some(&r.venue_id)),
+            timestamp(|r| r.created_at.timestamp_millis()),
+            timestamp(|r| r.updated_at.timestamp_millis()),
+            decimal(|r| Some(r.price))?,
+            decimal(|r| Some(r.quantity))?,
+            decimal(|r| r.notional)?,
+            Arc::new(StringArray::from_iter_values(
+                rows.iter().map(|r| r.settlement_state.to_string()),
+            )),
+            count(|r| r.settlement_attempts),
+            text(|r| r.chain_tx_hash.as_deref()),
+            text(|r| r.venue_execution_ref.as_deref()),
+            count(|r| r.fill_count),
+            decimal(|r| r.filled_quantity)?,
+            decimal(|r| r.failed_quantity)?,
+            decimal(|r| r.fill_vwap)?,
+            decimal(|r| r.taker_fee)?,
+            decimal(|r| r.maker_fee)?,
+            decimal(|r| r.net_fee)?,
+            text(|r| Some(&r.fees)),
+            text(|r| r.failure_reason.as_deref()),
+            text(|r| r.unwinds_trade_id.as_deref()),
+        ];
+        RecordBatch::try_new(schema(), columns)
+    }
+
+    /// Converts a decimal to an unscaled `i128` at [`DECIMAL_SCALE`].
+    fn to_decimal128(value: Decimal) -> Result<i128, ArrowError> {
+        let value = value.round_dp(DECIMAL_SCALE.unsigned_abs().into());
+        10i128
+            .checked_pow(u32::from(DECIMAL_SCALE.unsigned_abs()) - value.scale())
+            .and_then(|factor| value.mantissa().checked_mul(factor))
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "{value} does not fit DECIMAL({DECIMAL_PRECISION}, {DECIMAL_SCALE})"
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{Price, Quantity, QuoteId, RfqId, TradeId, VenueId};
+
+    fn trade(price: &str, quantity: &str, failure_reason: Option<&str>) -> Trade {
+        let created_at = Timestamp::from_millis(1_700_000_000_000).unwrap();
+        Trade::from_parts(
+            TradeId::new_v4(),
+            RfqId::new_v4(),
+            QuoteId::new_v4(),
+            VenueId::new("venue-1"),
+            Price::from_decimal(price.parse().unwrap()).unwrap(),
+            Quantity::from_decimal(quantity.parse().unwrap()).unwrap(),
+            None,
+            SettlementState::Failed,
+            Some("0xabc".to_string()),
+            failure_reason.map(str::to_string),
+            1,
+            created_at,
+            created_at,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn column<'a>(header: &str, row: &'a [String; 23]) -> &'a str {
+        let index = COLUMNS.iter().position(|c| *c == header).unwrap();
+        row.get(index).unwrap()
+    }
+
+    #[test]
+    fn csv_quotes_free_text_failure_reasons() {
+        let reason = "rpc error: \"nonce too low\", retrying\nat block 42";
+        let csv = to_csv(
+            &[TradeExportRow::from(&trade("1", "1", Some(reason)))],
+            true,
+        );
+
+        let (header, body) = csv.split_once("\r\n").unwrap();
+        assert_eq!(header, COLUMNS.join(","));
+        assert!(
+            body.ends_with(",\"rpc error: \"\"nonce too low\"\", retrying\nat block 42\",\r\n")
+        );
+        assert!(body.contains(",FAILED,0,0xabc,,0,"));
+    }
+
+    #[test]
+    fn csv_keeps_full_decimal_precision() {
+        let row = TradeExportRow::from(&trade("65432.123456789012345678", "2.5", None));
+        let fields = row.fields();
+
+        assert_eq!(column("price", &fields), "65432.123456789012345678");
+        assert_eq!(column("quantity", &fields), "2.5");
+        assert_eq!(column("notional", &fields), "163580.3086419725308641950");
+        assert_eq!(column("failure_reason", &fields), "");
+    }
+
+    #[test]
+    fn created_within_is_inclusive() {
+        let trade = trade("1", "1", None);
+        let at = trade.created_at();
+
+        assert!(created_within(&trade, Some(at), Some(at)));
+        assert!(!created_within(&trade, Some(at.add_secs(1)), None));
+        assert!(!created_within(&trade, None, Some(at.sub_secs(1))));
+    }
+
+    #[test]
+    fn parquet_support_follows_feature() {
+        assert!(TradeExportFormat::Csv.is_supported());
+        assert_eq!(
+            TradeExportFormat::Parquet.is_supported(),
+            cfg!(feature = "parquet")
+        );
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn parquet_roundtrips_decimals_at_scale_18() {
+        use arrow_array::Decimal128Array;
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let rows = [TradeExportRow::from(&trade(
+            "65432.123456789012345678",
+            "2.5",
+            None,
+        ))];
+        let mut encoder = parquet_encoding::ParquetEncoder::new().unwrap();
+        let mut file = encoder.take_written().to_vec();
+        file.extend_from_slice(&encoder.encode(&rows).unwrap());
+        file.extend_from_slice(&encoder.finish().unwrap());
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(file))
+            .unwrap()
+            .build()
+            .unwrap();
+        let batch = reader.into_iter().next().unwrap().unwrap();
+        assert_eq!(batch.schema(), schema());
+        let price = batch
+            .column_by_name("price")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Decimal128Array>()
+            .unwrap();
+        assert_eq!(price.value_as_string(0), "65432.123456789012345678");
+        let quantity = batch
+            .column_by_name("quantity")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Decimal128Array>()
+            .unwrap();
+        assert_eq!(quantity.value(0), 2_500_000_000_000_000_000);
+    }
+}
@@ -6,22 +6,35 @@
 //! validation and serialization for RFQ-related requests and responses.
 
 use crate::domain::entities::anonymity::AnonymityLevel;
+use crate::domain::entities::rfq::RfqSubject;
 use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
+use crate::domain::value_objects::strategy::Strategy;
 use crate::domain::value_objects::symbol::Symbol;
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{Instrument, OrderSide, Quantity, RfqId, RfqState};
+use crate::domain::value_objects::{
+    CompensationPolicy, Instrument, OrderSide, Quantity, RfqId, RfqState, SizeNegotiationMode,
+    VenueId,
+};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Request to create a new RFQ.
+///
+/// Quotes either a single instrument (`base_asset`/`quote_asset`) or a
+/// multi-leg `strategy`, never both.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateRfqRequest {
     /// The client ID requesting the quote.
     pub client_id: String,
-    /// Base asset symbol (e.g., "BTC").
+    /// Base asset symbol (e.g., "BTC"). Empty when quoting a strategy.
+    #[serde(default)]
     pub base_asset: String,
-    /// Quote asset symbol (e.g., "USD").
+    /// Quote asset symbol (e.g., "USD"). Empty when quoting a strategy.
+    #[serde(default)]
     pub quote_asset: String,
+    /// Multi-leg strategy to quote as a package.
+    #[serde(default)]
+    pub strategy: Option<Strategy>,
     /// Buy or sell side.
     pub side: OrderSide,
     /// Requested quantity.
@@ -32,6 +45,18 @@ pub struct CreateRfqRequest {
     /// If true, the requester's identity is hidden from market makers.
     #[serde(default)]
     pub anonymous: bool,
+    /// How the quantity may be filled.
+    #[serde(default)]
+    pub size_mode: SizeNegotiationMode,
+    /// Only these venues may quote. Takes precedence over the blocklist.
+    #[serde(default)]
+    pub venue_allowlist: Option<Vec<VenueId>>,
+    /// These venues must not see the RFQ.
+    #[serde(default)]
+    pub venue_blocklist: Vec<VenueId>,
+    /// How a partially failed multi-venue fill is compensated.
+    #[serde(default)]
+    pub compensation_policy: CompensationPolicy,
 }
 
 impl CreateRfqRequest {
@@ -49,10 +74,15 @@ impl CreateRfqRequest {
             client_id: client_id.into(),
             base_asset: base_asset.into(),
             quote_asset: quote_asset.into(),
+            strategy: None,
             side,
             quantity,
             expiry_seconds,
             anonymous: false,
+            size_mode: SizeNegotiationMode::default(),
+            venue_allowlist: None,
+            venue_blocklist: Vec::new(),
+            compensation_policy: CompensationPolicy::default(),
         }
     }
 
@@ -70,10 +100,60 @@ impl CreateRfqRequest {
             client_id: client_id.into(),
             base_asset: base_asset.into(),
             quote_asset: quote_asset.into(),
+            strategy: None,
             side,
             quantity,
             expiry_seconds,
             anonymous: true,
+            size_mode: SizeNegotiationMode::default(),
+            venue_allowlist: None,
+            venue_blocklist: Vec::new(),
+            compensation_policy: CompensationPolicy::default(),
+        }
+    }
+
+    /// Creates a new CreateRfqRequest for a multi-leg strategy.
+    #[must_use]
+    pub fn new_strategy(
+        client_id: impl Into<String>,
+        strategy: Strategy,
+        side: OrderSide,
+        quantity: f64,
+        expiry_seconds: u64,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            base_asset: String::new(),
+            quote_asset: String::new(),
+            strategy: Some(strategy),
+            side,
+            quantity,
+            expiry_seconds,
+            anonymous: false,
+            size_mode: SizeNegotiationMode::default(),
+            venue_allowlist: None,
+            venue_blocklist: Vec::new(),
+            compensation_policy: CompensationPolicy::default(),
+        }
+    }
+
+    /// Returns the `(base, quote)` asset pairs this request quotes.
+    ///
+    /// One pair per strategy leg, or the single requested pair.
+    #[must_use]
+    pub fn asset_pairs(&self) -> Vec<(&str, &str)> {
+        match &self.strategy {
+            Some(strategy) => strategy
+                .legs()
+                .iter()
+                .map(|leg| {
+                    (
+                        leg.instrument().base_asset(),
+                        leg.instrument().quote_asset(),
+                    )
+                })
+                .collect(),
+            None => vec![(self.base_asset.as_str(), self.quote_asset.as_str())],
         }
     }
 
@@ -97,12 +177,20 @@ impl CreateRfqRequest {
             return Err("client_id cannot be empty".to_string());
         }
 
-        if self.base_asset.is_empty() {
-            return Err("base_asset cannot be empty".to_string());
-        }
+        if self.strategy.is_some() {
+            if !self.base_asset.is_empty() || !self.quote_asset.is_empty() {
+                return Err(
+                    "specify either base_asset/quote_asset or strategy, not both".to_string(),
+                );
+            }
+        } else {
+            if self.base_asset.is_empty() {
+                return Err("base_asset cannot be empty".to_string());
+            }
 
-        if self.quote_asset.is_empty() {
-            return Err("quote_asset cannot be empty".to_string());
+            if self.quote_asset.is_empty() {
+                return Err("quote_asset cannot be empty".to_string());
+            }
         }
 
         if self.quantity <= 0.0 {
@@ -121,28 +209,43 @@ impl CreateRfqRequest {
     /// # Errors
     ///
     /// Returns an error if conversion fails.
-    pub fn to_domain_types(&self) -> Result<(Instrument, Quantity, Timestamp), String> {
-        let symbol_str = format!("{}/{}", self.base_asset, self.quote_asset);
-        let symbol = Symbol::new(&symbol_str).map_err(|e| e.to_string())?;
-
-        let instrument =
-            Instrument::new(symbol, AssetClass::CryptoSpot, SettlementMethod::default());
+    pub fn to_domain_types(&self) -> Result<(RfqSubject, Quantity, Timestamp), String> {
+        let subject = match &self.strategy {
+            Some(strategy) => RfqSubject::Strategy(strategy.clone()),
+            None => {
+                let symbol_str = format!("{}/{}", self.base_asset, self.quote_asset);
+                let symbol = Symbol::new(&symbol_str).map_err(|e| e.to_string())?;
+
+                RfqSubject::Single(Instrument::new(
+                    symbol,
+                    AssetClass::CryptoSpot,
+                    SettlementMethod::default(),
+                ))
+            }
+        };
 
         let quantity = Quantity::new(self.quantity).map_err(|e| e.to_string())?;
 
         let expires_at = Timestamp::now().add_secs(self.expiry_seconds as i64);
 
-        Ok((instrument, quantity, expires_at))
+        Ok((subject, quantity, expires_at))
     }
 }
 
 impl fmt::Display for CreateRfqRequest {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "CreateRfqRequest {{ client: {}, {}/{} {} {} }}",
-            self.client_id, self.base_asset, self.quote_asset, self.side, self.quantity
-        )
+        match &self.strategy {
+            Some(strategy) => write!(
+                f,
+                "CreateRfqRequest {{ client: {}, {} {} {} }}",
+                self.client_id, strategy, self.side, self.quantity
+            ),
+            None => write!(
+                f,
+                "CreateRfqRequest {{ client: {}, {}/{} {} {} }}",
+                self.client_id, self.base_asset, self.quote_asset, self.side, self.quantity
+            ),
+        }
     }
 }
 
@@ -246,12 +349,43 @@ mod tests {
         let result = request.to_domain_types();
         assert!(result.is_ok());
 
-        let (instrument, _quantity, expires_at) = result.unwrap();
+        let (subject, _quantity, expires_at) = result.unwrap();
+        let instrument = subject.primary_instrument();
+        assert!(!subject.is_multi_leg());
         assert_eq!(instrument.symbol().base_asset(), "BTC");
         assert_eq!(instrument.symbol().quote_asset(), "USD");
         assert!(!expires_at.is_expired());
     }
 
+    fn straddle() -> Strategy {
+        let option = Instrument::new(
+            Symbol::new("BTC/USD").unwrap(),
+            AssetClass::CryptoDerivs,
+            SettlementMethod::default(),
+        );
+        Strategy::straddle(option.clone(), option, "BTC").unwrap()
+    }
+
+    #[test]
+    fn create_rfq_request_strategy_to_domain_types() {
+        let request =
+            CreateRfqRequest::new_strategy("client-1", straddle(), OrderSide::Buy, 2.0, 300);
+        assert!(request.validate().is_ok());
+        assert_eq!(request.asset_pairs(), vec![("BTC", "USD"), ("BTC", "USD")]);
+
+        let (subject, _quantity, _expires_at) = request.to_domain_types().unwrap();
+        assert_eq!(subject.strategy(), Some(&straddle()));
+    }
+
+    #[test]
+    fn create_rfq_request_rejects_strategy_with_instrument() {
+        let mut request =
+            CreateRfqRequest::new_strategy("client-1", straddle(), OrderSide::Buy, 2.0, 300);
+        request.base_asset = "BTC".to_string();
+        request.quote_asset = "USD".to_string();
+        assert!(request.validate().is_err());
+    }
+
     #[test]
     fn create_rfq_request_display() {
         let request = CreateRfqRequest::new("client-1", "BTC", "USD", OrderSide::Buy, 1.5, 300);
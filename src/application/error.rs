@@ -34,7 +34,9 @@
 //! let app_err: ApplicationError = infra_err.into();
 //! ```
 
+use crate::application::services::retry::Retryable;
 use crate::domain::errors::DomainError;
+use crate::infrastructure::blockchain::TokenError;
 use crate::infrastructure::persistence::RepositoryError;
 use crate::infrastructure::venues::error::VenueError;
 use std::fmt;
@@ -143,10 +145,11 @@ impl InfrastructureError {
     /// Returns true if this error is retryable.
     #[must_use]
     pub fn is_retryable(&self) -> bool {
-        matches!(
-            self,
-            Self::Network(_) | Self::Timeout(_) | Self::MessageQueue(_)
-        )
+        match self {
+            Self::Network(_) | Self::Timeout(_) | Self::MessageQueue(_) => true,
+            Self::Repository(e) => e.is_transient(),
+            _ => false,
+        }
     }
 }
 
@@ -197,6 +200,11 @@ pub enum ApplicationError {
     #[error("instrument not supported: {0}")]
     InstrumentNotSupported(String),
 
+    /// Token is unknown, not on the settlement chain, or not enabled for
+    /// settlement.
+    #[error("settlement token unavailable: {0}")]
+    SettlementTokenUnavailable(#[from] TokenError),
+
     /// Compliance check failed.
     #[error("compliance check failed: {0}")]
     ComplianceFailed(String),
@@ -236,6 +244,10 @@ pub enum ApplicationError {
     /// Trade execution failed.
     #[error("execution failed: {0}")]
     ExecutionFailed(String),
+
+    /// The service is shutting down and refuses new work.
+    #[error("service is shutting down")]
+    ShuttingDown,
 }
 
 impl ApplicationError {
@@ -337,12 +349,19 @@ impl ApplicationError {
     }
 }
 
+impl Retryable for ApplicationError {
+    fn is_retryable(&self) -> bool {
+        ApplicationError::is_retryable(self)
+    }
+}
+
 /// Result type for application operations.
 pub type ApplicationResult<T> = Result<T, ApplicationError>;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::infrastructure::persistence::ConstraintKind;
 
     // InfrastructureError tests
 
@@ -499,6 +518,20 @@ mod tests {
         assert!(app_err.is_retryable());
     }
 
+    #[test]
+    fn application_error_retryable_from_transient_repository_error() {
+        let app_err: ApplicationError =
+            InfrastructureError::from(RepositoryError::connection_lost("reset by peer")).into();
+        assert!(app_err.is_retryable());
+        assert!(Retryable::is_retryable(&app_err));
+
+        let app_err: ApplicationError = InfrastructureError::from(
+            RepositoryError::constraint_violation("trades_pkey", ConstraintKind::Unique),
+        )
+        .into();
+        assert!(!app_err.is_retryable());
+    }
+
     #[test]
     fn application_error_not_retryable() {
         let err = ApplicationError::validation("invalid input");
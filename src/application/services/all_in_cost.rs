@@ -0,0 +1,456 @@
+//! # All-In Cost
+//!
+//! Gas-inclusive pricing of quotes from DeFi venues.
+//!
+//! A quote from an on-chain RFQ protocol is settled by a transaction whose
+//! gas the taker pays. [`AllInCostCalculator`] estimates that gas from a
+//! per-protocol table of gas units ([`GasUnitsTable`]) and the current gas
+//! price on the quote's chain, converts it from the chain's native token to
+//! the quote currency through a [`ReferencePriceProvider`], and attaches the
+//! resulting [`AllInCost`] to the quote before ranking.
+//!
+//! Quotes without execution instructions (CeFi venues) are left untouched.
+//! A DeFi quote whose gas cannot be estimated (unknown chain, no client for
+//! it, no gas units for the protocol, or no native token price) keeps its
+//! raw price and is marked unestimated. Gasless Bebop orders, submitted by
+//! Bebop itself, carry no gas.
+
+use crate::application::services::price_bounds::ReferencePriceProvider;
+use crate::domain::entities::quote::Quote;
+use crate::domain::value_objects::all_in_cost::AllInCost;
+use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
+use crate::domain::value_objects::{
+    ExecutionInstructions, ExecutionProtocol, Instrument, OrderSide, Symbol,
+};
+use crate::infrastructure::blockchain::{BlockchainClient, ChainId, GasEstimator, TxPriority};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Number of decimals of the native gas token (wei per ether).
+const NATIVE_TOKEN_DECIMALS: u32 = 18;
+
+/// Gas units used to settle a quote, per execution protocol.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GasUnitsTable(HashMap<ExecutionProtocol, u64>);
+
+impl GasUnitsTable {
+    /// Creates a table from explicit entries.
+    #[must_use]
+    pub fn new(units: HashMap<ExecutionProtocol, u64>) -> Self {
+        Self(units)
+    }
+
+    /// Returns the gas units to settle a quote of `protocol`, if known.
+    #[must_use]
+    pub fn units(&self, protocol: ExecutionProtocol) -> Option<u64> {
+        self.0.get(&protocol).copied()
+    }
+}
+
+impl Default for GasUnitsTable {
+    fn default() -> Self {
+        Self(HashMap::from([
+            (ExecutionProtocol::HashflowSigned, 150_000),
+            (ExecutionProtocol::BebopOrder, 200_000),
+            (ExecutionProtocol::AirswapOrder, 130_000),
+        ]))
+    }
+}
+
+/// Attaches gas-inclusive prices to DeFi quotes.
+pub struct AllInCostCalculator {
+    gas_units: GasUnitsTable,
+    reference_prices: Arc<dyn ReferencePriceProvider>,
+    clients: HashMap<ChainId, Arc<dyn BlockchainClient>>,
+    estimator: GasEstimator,
+    priority: TxPriority,
+}
+
+impl fmt::Debug for AllInCostCalculator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AllInCostCalculator")
+            .field("gas_units", &self.gas_units)
+            .field("chains", &self.clients.keys().collect::<Vec<_>>())
+            .field("estimator", &self.estimator)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Gas prices and native token prices fetched while costing one batch.
+#[derive(Default)]
+struct Rates {
+    gas_prices: HashMap<ChainId, Option<u64>>,
+    native_prices: HashMap<&'static str, Option<Decimal>>,
+}
+
+impl AllInCostCalculator {
+    /// Creates a calculator with no chains.
+    ///
+    /// Quotes on a chain without a client keep their raw price; add clients
+    /// with [`AllInCostCalculator::with_client`].
+    #[must_use]
+    pub fn new(
+        gas_units: GasUnitsTable,
+        reference_prices: Arc<dyn ReferencePriceProvider>,
+    ) -> Self {
+        Self {
+            gas_units,
+            reference_prices,
+            clients: HashMap::new(),
+            estimator: GasEstimator::default(),
+            priority: TxPriority::Medium,
+        }
+    }
+
+    /// Reads gas prices for the client's chain from `client`.
+    #[must_use]
+    pub fn with_client(mut self, client: Arc<dyn BlockchainClient>) -> Self {
+        self.clients.insert(client.chain_id(), client);
+        self
+    }
+
+    /// Sets the buffer applied to the gas units.
+    #[must_use]
+    pub fn with_estimator(mut self, estimator: GasEstimator) -> Self {
+        self.estimator = estimator;
+        self
+    }
+
+    /// Attaches the all-in cost to every DeFi quote in `quotes`.
+    ///
+    /// `side` is the side the client trades the quotes on, and
+    /// `quote_asset` the currency their prices are in.
+    pub async fn annotate(
+        &self,
+        quotes: Vec<Quote>,
+        side: OrderSide,
+        quote_asset: &str,
+    ) -> Vec<Quote> {
+        let mut rates = Rates::default();
+        let mut annotated = Vec::with_capacity(quotes.len());
+        for quote in quotes {
+            let Some(instructions) = quote
+                .metadata()
+                .and_then(|m| m.execution_instructions())
+                .cloned()
+            else {
+                annotated.push(quote);
+                continue;
+            };
+            let price = quote.net_premium().get();
+            let cost = match self.gas_cost(&instructions, quote_asset, &mut rates).await {
+                Some(gas_cost) => {
+                    AllInCost::estimated(price, gas_cost, quote.quantity().get(), side)
+                }
+                None => {
+                    tracing::debug!(
+                        quote_id = %quote.id(),
+                        protocol = %instructions.protocol(),
+                        "Gas cost unavailable, ranking on raw price"
+                    );
+                    AllInCost::unestimated(price)
+                }
+            };
+            annotated.push(quote.with_all_in_cost(cost));
+        }
+        annotated
+    }
+
+    /// Returns the gas to execute `instructions`, in `quote_asset`.
+    async fn gas_cost(
+        &self,
+        instructions: &ExecutionInstructions,
+        quote_asset: &str,
+        rates: &mut Rates,
+    ) -> Option<Decimal> {
+        if instructions.is_gasless() {
+            return Some(Decimal::ZERO);
+        }
+        let units = self.gas_units.units(instructions.protocol())?;
+        let chain = instructions.chain_id().and_then(ChainId::from_u64)?;
+        let gas_price = self.gas_price(chain, rates).await?;
+
+        let wei =
+            u128::from(self.estimator.apply_buffer(units)).saturating_mul(u128::from(gas_price));
+        let native_amount =
+            Decimal::try_from_i128_with_scale(i128::try_from(wei).ok()?, NATIVE_TOKEN_DECIMALS)
+                .ok()?;
+        let native_price = self
+            .native_price(chain.native_currency(), quote_asset, rates)
+            .await?;
+        native_amount.checked_mul(native_price)
+    }
+
+    async fn gas_price(&self, chain: ChainId, rates: &mut Rates) -> Option<u64> {
+        if let Some(price) = rates.gas_prices.get(&chain) {
+            return *price;
+        }
+        let price = match self.clients.get(&chain) {
+            Some(client) => match client.get_gas_price(self.priority).await {
+                Ok(price) => Some(price.effective_price()),
+                Err(e) => {
+                    tracing::warn!(chain = %chain, error = %e, "Gas price unavailable");
+                    None
+                }
+            },
+            None => None,
+        };
+        rates.gas_prices.insert(chain, price);
+        price
+    }
+
+    /// Returns the price of one `native` token in `quote_asset`.
+    async fn native_price(
+        &self,
+        native: &'static str,
+        quote_asset: &str,
+        rates: &mut Rates,
+    ) -> Option<Decimal> {
+        if native.eq_ignore_ascii_case(quote_asset) {
+            return Some(Decimal::ONE);
+        }
+        if let Some(price) = rates.native_prices.get(native) {
+            return *price;
+        }
+        let price = match Symbol::new(format!("{native}/{quote_asset}")) {
+            Ok(symbol) => {
+                let instrument =
+                    Instrument::new(symbol, AssetClass::CryptoSpot, SettlementMethod::default());
+                match self.reference_prices.get_reference(&instrument).await {
+                    Ok(reference) => reference.map(|(price, _)| price.get()),
+                    Err(e) => {
+                        tracing::warn!(
+                            instrument = %instrument.symbol(),
+                            error = %e,
+                            "Native token price unavailable"
+                        );
+                        None
+                    }
+                }
+            }
+            Err(_) => None,
+        };
+        rates.native_prices.insert(native, price);
+        price
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::application::services::ranking_strategy::{
+        AllInCostStrategy, BestPriceStrategy, RankingStrategy,
+    };
+    use crate::domain::entities::quote::{QuoteBuilder, QuoteMetadata};
+    use crate::domain::errors::DomainResult;
+    use crate::domain::value_objects::timestamp::Timestamp;
+    use crate::domain::value_objects::{Price, Quantity, ReferencePriceSource, RfqId, VenueId};
+    use crate::infrastructure::blockchain::{BlockchainResult, GasPrice, TxHash, TxReceipt};
+    use async_trait::async_trait;
+
+    /// Reports a fixed gas price for one chain.
+    #[derive(Debug)]
+    struct GasOracle {
+        chain: ChainId,
+        gwei: u64,
+    }
+
+    #[async_trait]
+    impl BlockchainClient for GasOracle {
+        fn chain_id(&self) -> ChainId {
+            self.chain
+        }
+
+        async fn get_block_number(&self) -> BlockchainResult<u64> {
+            Ok(0)
+        }
+
+        async fn get_balance(&self, _address: &str) -> BlockchainResult<u128> {
+            Ok(0)
+        }
+
+        async fn estimate_gas(
+            &self,
+            _to: &str,
+            _data: &[u8],
+            _value: u128,
+        ) -> BlockchainResult<u64> {
+            Ok(0)
+        }
+
+        async fn call(&self, _to: &str, _data: &[u8]) -> BlockchainResult<Vec<u8>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_gas_price(&self, _priority: TxPriority) -> BlockchainResult<GasPrice> {
+            Ok(GasPrice::legacy(self.gwei * 1_000_000_000))
+        }
+
+        async fn send_transaction(
+            &self,
+            _to: &str,
+            _data: &[u8],
+            _value: u128,
+            _gas_limit: u64,
+            _gas_price: GasPrice,
+        ) -> BlockchainResult<TxHash> {
+            Ok(TxHash::new("0x0"))
+        }
+
+        async fn wait_for_confirmation(
+            &self,
+            tx_hash: &TxHash,
+            _confirmations: u64,
+        ) -> BlockchainResult<TxReceipt> {
+            Ok(TxReceipt {
+                tx_hash: tx_hash.clone(),
+                block_number: 0,
+                gas_used: 0,
+                effective_gas_price: 0,
+                success: true,
+            })
+        }
+
+        async fn get_transaction_receipt(
+            &self,
+            _tx_hash: &TxHash,
+        ) -> BlockchainResult<Option<TxReceipt>> {
+            Ok(None)
+        }
+
+        async fn is_transaction_pending(&self, _tx_hash: &TxHash) -> BlockchainResult<bool> {
+            Ok(false)
+        }
+
+        async fn get_nonce(&self, _address: &str) -> BlockchainResult<u64> {
+            Ok(0)
+        }
+
+        async fn health_check(&self) -> BlockchainResult<()> {
+            Ok(())
+        }
+    }
+
+    /// Prices ETH at 2000 USDC.
+    struct EthPrice;
+
+    #[async_trait]
+    impl ReferencePriceProvider for EthPrice {
+        async fn get_reference(
+            &self,
+            instrument: &Instrument,
+        ) -> DomainResult<Option<(Price, ReferencePriceSource)>> {
+            Ok((instrument.symbol().to_string() == "ETH/USDC")
+                .then(|| (Price::new(2000.0).unwrap(), ReferencePriceSource::ClobMid)))
+        }
+    }
+
+    fn hashflow(price: f64, chain_id: u64) -> Quote {
+        let mut metadata = QuoteMetadata::new();
+        metadata.set_execution_instructions(ExecutionInstructions::HashflowSigned {
+            quote_id: "hf-1".to_string(),
+            signature: "0xsig".to_string(),
+            pool: "0xpool".to_string(),
+            nonce: "1".to_string(),
+            base_token: "0xbase".to_string(),
+            quote_token: "0xquote".to_string(),
+            base_token_amount: "1".to_string(),
+            quote_token_amount: "2000".to_string(),
+            txn_deadline: 1_700_000_000,
+            chain_id,
+            effective_base_token_amount: None,
+        });
+        quote("hashflow", price, metadata)
+    }
+
+    fn bebop(price: f64, chain_id: u64) -> Quote {
+        let mut metadata = QuoteMetadata::new();
+        metadata.set_execution_instructions(ExecutionInstructions::BebopOrder {
+            quote_id: "bb-1".to_string(),
+            signature: "0xsig".to_string(),
+            settlement_address: "0xsettle".to_string(),
+            approval_target: "0xapprove".to_string(),
+            sell_token: "0xsell".to_string(),
+            buy_token: "0xbuy".to_string(),
+            sell_amount: "2000".to_string(),
+            buy_amount: "1".to_string(),
+            chain_id,
+            gasless: false,
+            tx_data: None,
+            gas_estimate: None,
+            receiver: None,
+        });
+        quote("bebop", price, metadata)
+    }
+
+    fn quote(venue: &str, price: f64, metadata: QuoteMetadata) -> Quote {
+        QuoteBuilder::new(
+            RfqId::new_v4(),
+            VenueId::new(venue),
+            Price::new(price).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(60),
+        )
+        .metadata(metadata)
+        .build()
+    }
+
+    fn calculator() -> AllInCostCalculator {
+        AllInCostCalculator::new(GasUnitsTable::default(), Arc::new(EthPrice))
+            .with_estimator(GasEstimator::new(0))
+            .with_client(Arc::new(GasOracle {
+                chain: ChainId::Ethereum,
+                gwei: 30,
+            }))
+            .with_client(Arc::new(GasOracle {
+                chain: ChainId::Arbitrum,
+                gwei: 1,
+            }))
+    }
+
+    #[tokio::test]
+    async fn mainnet_gas_flips_the_ranking_to_l2() {
+        // Hashflow on mainnet is 3 bps better before gas.
+        let quotes = vec![hashflow(2000.0, 1), bebop(2000.6, 42161)];
+
+        let raw = BestPriceStrategy::new().rank(&quotes, OrderSide::Buy);
+        assert_eq!(raw.first().unwrap().quote.venue_id().as_str(), "hashflow");
+
+        let quotes = calculator().annotate(quotes, OrderSide::Buy, "USDC").await;
+        let ranked = AllInCostStrategy::new().rank(&quotes, OrderSide::Buy);
+
+        let best = ranked.first().unwrap();
+        assert_eq!(best.quote.venue_id().as_str(), "bebop");
+        // 200k gas at 1 gwei and 2000 USDC/ETH.
+        assert_eq!(best.all_in_price(), Decimal::new(20_010, 1));
+        let mainnet = ranked.get(1).unwrap();
+        // 150k gas at 30 gwei and 2000 USDC/ETH.
+        assert_eq!(mainnet.all_in_price(), Decimal::from(2009));
+        assert!(!mainnet.gas_estimate_missing());
+    }
+
+    #[tokio::test]
+    async fn missing_gas_data_falls_back_to_raw_price() {
+        // No client for Polygon.
+        let quotes = vec![
+            hashflow(2000.0, 137),
+            quote("cefi", 2001.0, QuoteMetadata::new()),
+        ];
+
+        let quotes = calculator().annotate(quotes, OrderSide::Buy, "USDC").await;
+        let ranked = AllInCostStrategy::new().rank(&quotes, OrderSide::Buy);
+
+        let best = ranked.first().unwrap();
+        assert_eq!(best.quote.venue_id().as_str(), "hashflow");
+        assert_eq!(best.all_in_price(), Decimal::from(2000));
+        assert!(best.gas_estimate_missing());
+        let cefi = ranked.get(1).unwrap();
+        assert!(cefi.quote.all_in_cost().is_none());
+        assert!(!cefi.gas_estimate_missing());
+    }
+}
@@ -0,0 +1,615 @@
+//! # Allocation Execution
+//!
+//! Executes the venue legs of a multi-venue fill and compensates partial
+//! failures.
+//!
+//! A trade filled across several market makers carries one allocation per
+//! venue. [`AllocationExecutionService`] sends every leg to its venue
+//! through an [`AllocationVenueGateway`], records each leg as `Filled` or
+//! `Failed` as it completes, and publishes an [`AllocationFilled`] or
+//! [`AllocationFailed`] event per leg.
+//!
+//! If any leg fails, the RFQ's [`CompensationPolicy`] decides what happens
+//! next:
+//!
+//! - `ReofferRemainder`: the failed quantity is handed to a
+//!   [`RemainderReofferer`] to re-run quote aggregation.
+//! - `AcceptPartial`: the filled legs stand if the size mode accepts the
+//!   filled quantity; otherwise the fill is unwound.
+//! - `UnwindAll`: every filled leg is reversed on a best-effort basis and an
+//!   unwind trade is recorded for each reversal.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::domain::entities::allocation::{Allocation, AllocationStatus};
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::entities::trade::Trade;
+use crate::domain::events::{AllocationFailed, AllocationFilled};
+use crate::domain::value_objects::{
+    CompensationPolicy, Quantity, QuoteId, SizeNegotiationMode, TradeId,
+};
+use crate::infrastructure::persistence::traits::TradeRepository;
+use async_trait::async_trait;
+use futures::future::join_all;
+use std::fmt;
+use std::sync::Arc;
+
+/// Sends allocation legs to their venues.
+#[async_trait]
+pub trait AllocationVenueGateway: Send + Sync + fmt::Debug {
+    /// Executes `leg` of `trade` at the leg's venue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the venue did not fill the leg.
+    async fn execute_leg(&self, trade: &Trade, leg: &Allocation) -> ApplicationResult<()>;
+
+    /// Reverses the filled `leg` of `trade` at the leg's venue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the leg could not be reversed.
+    async fn unwind_leg(&self, trade: &Trade, leg: &Allocation) -> ApplicationResult<()>;
+}
+
+/// Re-runs quote aggregation for the unfilled part of an RFQ.
+#[async_trait]
+pub trait RemainderReofferer: Send + Sync + fmt::Debug {
+    /// Requests new quotes for `quantity` of `rfq`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the remainder cannot be re-offered.
+    async fn reoffer(&self, rfq: &Rfq, quantity: Quantity) -> ApplicationResult<()>;
+}
+
+/// Publisher for allocation leg events.
+#[async_trait]
+pub trait AllocationEventPublisher: Send + Sync + fmt::Debug {
+    /// Publishes an allocation filled event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be published.
+    async fn publish_allocation_filled(&self, event: AllocationFilled) -> ApplicationResult<()>;
+
+    /// Publishes an allocation failed event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be published.
+    async fn publish_allocation_failed(&self, event: AllocationFailed) -> ApplicationResult<()>;
+}
+
+/// How a multi-venue fill was compensated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompensationOutcome {
+    /// Every leg filled; nothing to compensate.
+    NotNeeded,
+    /// The failed quantity was handed back for quote aggregation.
+    Reoffered {
+        /// Quantity re-offered.
+        quantity: Quantity,
+    },
+    /// The filled legs were kept as a partial fill.
+    AcceptedPartial {
+        /// Quantity filled.
+        filled: Quantity,
+    },
+    /// Filled legs were reversed.
+    Unwound {
+        /// Unwind trades recorded for the reversed legs.
+        unwind_trades: Vec<TradeId>,
+        /// Quotes of filled legs that could not be reversed.
+        not_unwound: Vec<QuoteId>,
+    },
+}
+
+/// Result of executing a trade's allocations.
+#[derive(Debug, Clone)]
+pub struct AllocationExecutionResult {
+    /// The trade with every allocation filled or failed.
+    pub trade: Trade,
+    /// How failed legs were compensated.
+    pub compensation: CompensationOutcome,
+}
+
+/// Executes multi-venue fills leg by leg and compensates partial failures.
+///
+/// # Examples
+///
+/// ```ignore
+/// let service = AllocationExecutionService::new(trade_repository, gateway, reofferer, publisher);
+/// let result = service.execute(&rfq, trade).await?;
+/// ```
+#[derive(Debug)]
+pub struct AllocationExecutionService {
+    trade_repository: Arc<dyn TradeRepository>,
+    gateway: Arc<dyn AllocationVenueGateway>,
+    reofferer: Arc<dyn RemainderReofferer>,
+    event_publisher: Arc<dyn AllocationEventPublisher>,
+}
+
+impl AllocationExecutionService {
+    /// Creates a new service.
+    #[must_use]
+    pub fn new(
+        trade_repository: Arc<dyn TradeRepository>,
+        gateway: Arc<dyn AllocationVenueGateway>,
+        reofferer: Arc<dyn RemainderReofferer>,
+        event_publisher: Arc<dyn AllocationEventPublisher>,
+    ) -> Self {
+        Self {
+            trade_repository,
+            gateway,
+            reofferer,
+            event_publisher,
+        }
+    }
+
+    /// Executes every allocation of `trade` and applies `rfq`'s
+    /// compensation policy if a leg fails.
+    ///
+    /// Legs are sent to their venues concurrently. The trade is saved once
+    /// the legs are in flight and again once they have all completed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trade does not belong to `rfq`, has no
+    /// pending allocations, cannot be saved, or the remainder cannot be
+    /// re-offered.
+    pub async fn execute(
+        &self,
+        rfq: &Rfq,
+        mut trade: Trade,
+    ) -> ApplicationResult<AllocationExecutionResult> {
+        if trade.rfq_id() != rfq.id() {
+            return Err(ApplicationError::Validation(format!(
+                "trade {} does not belong to RFQ {}",
+                trade.id(),
+                rfq.id()
+            )));
+        }
+
+        trade.start_allocations()?;
+        self.save(&trade).await?;
+
+        let legs: Vec<Allocation> = trade
+            .allocations()
+            .iter()
+            .map(|recorded| recorded.allocation().clone())
+            .collect();
+        let results = join_all(legs.iter().map(|leg| self.gateway.execute_leg(&trade, leg))).await;
+
+        let mut filled = Vec::new();
+        let mut failed = Vec::new();
+        for (index, (leg, result)) in legs.into_iter().zip(results).enumerate() {
+            match result {
+                Ok(()) => {
+                    trade.fill_allocation(index)?;
+                    filled.push(AllocationFilled::new(
+                        rfq.id(),
+                        trade.id(),
+                        leg.quote_id(),
+                        leg.venue_id().clone(),
+                        leg.allocated_quantity(),
+                        leg.price(),
+                    ));
+                }
+                Err(e) => {
+                    let reason = e.to_string();
+                    tracing::warn!(
+                        trade_id = %trade.id(),
+                        venue_id = %leg.venue_id(),
+                        error = %reason,
+                        "allocation leg failed"
+                    );
+                    trade.fail_allocation(index, reason.clone())?;
+                    failed.push(AllocationFailed::new(
+                        rfq.id(),
+                        trade.id(),
+                        leg.quote_id(),
+                        leg.venue_id().clone(),
+                        leg.allocated_quantity(),
+                        reason,
+                    ));
+                }
+            }
+        }
+        self.save(&trade).await?;
+
+        let any_failed = !failed.is_empty();
+        for event in filled {
+            self.event_publisher
+                .publish_allocation_filled(event)
+                .await?;
+        }
+        for event in failed {
+            self.event_publisher
+                .publish_allocation_failed(event)
+                .await?;
+        }
+
+        let compensation = if any_failed {
+            self.compensate(rfq, &trade).await?
+        } else {
+            CompensationOutcome::NotNeeded
+        };
+        Ok(AllocationExecutionResult {
+            trade,
+            compensation,
+        })
+    }
+
+    async fn compensate(&self, rfq: &Rfq, trade: &Trade) -> ApplicationResult<CompensationOutcome> {
+        match rfq.compensation_policy() {
+            CompensationPolicy::ReofferRemainder => {
+                let quantity = trade.failed_allocation_quantity().ok_or_else(|| {
+                    ApplicationError::Internal("failed quantity overflowed".to_string())
+                })?;
+                self.reofferer.reoffer(rfq, quantity).await?;
+                tracing::info!(trade_id = %trade.id(), %quantity, "failed allocations re-offered");
+                Ok(CompensationOutcome::Reoffered { quantity })
+            }
+            CompensationPolicy::AcceptPartial => {
+                let filled = trade.filled_allocation_quantity().ok_or_else(|| {
+                    ApplicationError::Internal("filled quantity overflowed".to_string())
+                })?;
+                if accepts_partial(rfq.size_mode(), filled) {
+                    tracing::info!(trade_id = %trade.id(), %filled, "partial fill accepted");
+                    Ok(CompensationOutcome::AcceptedPartial { filled })
+                } else {
+                    tracing::info!(
+                        trade_id = %trade.id(),
+                        %filled,
+                        "partial fill below the size mode's minimum, unwinding"
+                    );
+                    self.unwind(trade).await
+                }
+            }
+            CompensationPolicy::UnwindAll => self.unwind(trade).await,
+        }
+    }
+
+    /// Reverses every filled leg, recording an unwind trade per reversal.
+    ///
+    /// A leg that cannot be reversed is logged and reported; it needs
+    /// manual follow-up.
+    async fn unwind(&self, trade: &Trade) -> ApplicationResult<CompensationOutcome> {
+        let legs: Vec<&Allocation> = trade
+            .allocations()
+            .iter()
+            .filter(|recorded| *recorded.status() == AllocationStatus::Filled)
+            .map(|recorded| recorded.allocation())
+            .collect();
+        let results = join_all(legs.iter().map(|leg| self.gateway.unwind_leg(trade, leg))).await;
+
+        let mut unwind_trades = Vec::new();
+        let mut not_unwound = Vec::new();
+        for (leg, result) in legs.into_iter().zip(results) {
+            match result {
+                Ok(()) => {
+                    let unwind = Trade::unwind_of(trade, leg);
+                    self.save(&unwind).await?;
+                    unwind_trades.push(unwind.id());
+                }
+                Err(e) => {
+                    tracing::error!(
+                        trade_id = %trade.id(),
+                        venue_id = %leg.venue_id(),
+                        error = %e,
+                        "failed to unwind allocation leg"
+                    );
+                    not_unwound.push(leg.quote_id());
+                }
+            }
+        }
+        Ok(CompensationOutcome::Unwound {
+            unwind_trades,
+            not_unwound,
+        })
+    }
+
+    async fn save(&self, trade: &Trade) -> ApplicationResult<()> {
+        self.trade_repository
+            .save(trade)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))
+    }
+}
+
+/// Returns true if `size_mode` accepts a fill of `filled`.
+fn accepts_partial(size_mode: &SizeNegotiationMode, filled: Quantity) -> bool {
+    filled.is_positive()
+        && size_mode.allows_partial_fill()
+        && size_mode.min_quantity().is_none_or(|min| filled >= min)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::allocation::TradeAllocation;
+    use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::value_objects::enums::AssetClass;
+    use crate::domain::value_objects::timestamp::Timestamp;
+    use crate::domain::value_objects::{
+        CounterpartyId, Instrument, OrderSide, Price, QuoteId, Symbol, VenueId,
+    };
+    use crate::infrastructure::persistence::in_memory::InMemoryTradeRepository;
+    use std::sync::Mutex;
+
+    /// Gateway that fails the legs of the listed venues.
+    #[derive(Debug, Default)]
+    struct ScriptedGateway {
+        failing_venues: Vec<VenueId>,
+        unwound: Mutex<Vec<VenueId>>,
+    }
+
+    #[async_trait]
+    impl AllocationVenueGateway for ScriptedGateway {
+        async fn execute_leg(&self, _trade: &Trade, leg: &Allocation) -> ApplicationResult<()> {
+            if self.failing_venues.contains(leg.venue_id()) {
+                return Err(ApplicationError::ExecutionFailed(
+                    "venue timeout".to_string(),
+                ));
+            }
+            Ok(())
+        }
+
+        async fn unwind_leg(&self, _trade: &Trade, leg: &Allocation) -> ApplicationResult<()> {
+            self.unwound.lock().unwrap().push(leg.venue_id().clone());
+            Ok(())
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingReofferer {
+        reoffered: Mutex<Vec<Quantity>>,
+    }
+
+    #[async_trait]
+    impl RemainderReofferer for RecordingReofferer {
+        async fn reoffer(&self, _rfq: &Rfq, quantity: Quantity) -> ApplicationResult<()> {
+            self.reoffered.lock().unwrap().push(quantity);
+            Ok(())
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingPublisher {
+        filled: Mutex<Vec<AllocationFilled>>,
+        failed: Mutex<Vec<AllocationFailed>>,
+    }
+
+    #[async_trait]
+    impl AllocationEventPublisher for RecordingPublisher {
+        async fn publish_allocation_filled(
+            &self,
+            event: AllocationFilled,
+        ) -> ApplicationResult<()> {
+            self.filled.lock().unwrap().push(event);
+            Ok(())
+        }
+
+        async fn publish_allocation_failed(
+            &self,
+            event: AllocationFailed,
+        ) -> ApplicationResult<()> {
+            self.failed.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    struct Fixture {
+        service: AllocationExecutionService,
+        repo: Arc<InMemoryTradeRepository>,
+        gateway: Arc<ScriptedGateway>,
+        reofferer: Arc<RecordingReofferer>,
+        publisher: Arc<RecordingPublisher>,
+    }
+
+    /// Second leg (`mm-2`) fails.
+    fn fixture() -> Fixture {
+        let repo = Arc::new(InMemoryTradeRepository::new());
+        let gateway = Arc::new(ScriptedGateway {
+            failing_venues: vec![VenueId::new("mm-2")],
+            ..ScriptedGateway::default()
+        });
+        let reofferer = Arc::new(RecordingReofferer::default());
+        let publisher = Arc::new(RecordingPublisher::default());
+        let service = AllocationExecutionService::new(
+            Arc::clone(&repo) as Arc<dyn TradeRepository>,
+            Arc::clone(&gateway) as Arc<dyn AllocationVenueGateway>,
+            Arc::clone(&reofferer) as Arc<dyn RemainderReofferer>,
+            Arc::clone(&publisher) as Arc<dyn AllocationEventPublisher>,
+        );
+        Fixture {
+            service,
+            repo,
+            gateway,
+            reofferer,
+            publisher,
+        }
+    }
+
+    fn rfq_with(size_mode: SizeNegotiationMode, policy: CompensationPolicy) -> Rfq {
+        let instrument =
+            Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build();
+        RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(10.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .size_mode(size_mode)
+        .compensation_policy(policy)
+        .try_build()
+        .unwrap()
+    }
+
+    fn trade_with_legs(rfq: &Rfq, legs: &[(&str, f64, f64)]) -> Trade {
+        let mut trade = Trade::new(
+            rfq.id(),
+            QuoteId::new_v4(),
+            VenueId::new("mm-1"),
+            Price::new(102.0).unwrap(),
+            Quantity::new(10.0).unwrap(),
+        );
+        for (venue, qty, price) in legs {
+            let leg = Allocation::new(
+                VenueId::new(*venue),
+                QuoteId::new_v4(),
+                Quantity::new(*qty).unwrap(),
+                Price::new(*price).unwrap(),
+            )
+            .unwrap();
+            trade.add_allocation(TradeAllocation::scheduled(leg, Timestamp::now()));
+        }
+        trade
+    }
+
+    /// Two allocations: 6 on `mm-1`, 4 on `mm-2`.
+    fn two_leg_trade(rfq: &Rfq) -> Trade {
+        trade_with_legs(rfq, &[("mm-1", 6.0, 100.0), ("mm-2", 4.0, 105.0)])
+    }
+
+    fn assert_second_leg_failed(trade: &Trade) {
+        let statuses: Vec<&AllocationStatus> =
+            trade.allocations().iter().map(|r| r.status()).collect();
+        assert_eq!(statuses.first().unwrap(), &&AllocationStatus::Filled);
+        assert_eq!(
+            statuses.last().unwrap().failure_reason(),
+            Some("execution failed: venue timeout")
+        );
+    }
+
+    #[tokio::test]
+    async fn reoffer_remainder_reoffers_failed_quantity() {
+        let f = fixture();
+        let rfq = rfq_with(
+            SizeNegotiationMode::AllOrNothing,
+            CompensationPolicy::ReofferRemainder,
+        );
+
+        let result = f.service.execute(&rfq, two_leg_trade(&rfq)).await.unwrap();
+
+        assert_second_leg_failed(&result.trade);
+        assert_eq!(
+            result.compensation,
+            CompensationOutcome::Reoffered {
+                quantity: Quantity::new(4.0).unwrap()
+            }
+        );
+        assert_eq!(
+            *f.reofferer.reoffered.lock().unwrap(),
+            vec![Quantity::new(4.0).unwrap()]
+        );
+        assert!(f.gateway.unwound.lock().unwrap().is_empty());
+        assert_eq!(f.publisher.filled.lock().unwrap().len(), 1);
+        let failed_venue = f
+            .publisher
+            .failed
+            .lock()
+            .unwrap()
+            .first()
+            .unwrap()
+            .venue_id
+            .clone();
+        assert_eq!(failed_venue, VenueId::new("mm-2"));
+
+        let stored = f.repo.get(result.trade.id()).await.unwrap().unwrap();
+        assert_eq!(stored.allocations(), result.trade.allocations());
+    }
+
+    #[tokio::test]
+    async fn accept_partial_keeps_filled_legs() {
+        let f = fixture();
+        let rfq = rfq_with(
+            SizeNegotiationMode::MinQuantity(Quantity::new(5.0).unwrap()),
+            CompensationPolicy::AcceptPartial,
+        );
+
+        let result = f.service.execute(&rfq, two_leg_trade(&rfq)).await.unwrap();
+
+        assert_second_leg_failed(&result.trade);
+        assert_eq!(
+            result.compensation,
+            CompensationOutcome::AcceptedPartial {
+                filled: Quantity::new(6.0).unwrap()
+            }
+        );
+        assert!(f.reofferer.reoffered.lock().unwrap().is_empty());
+        assert!(f.gateway.unwound.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn accept_partial_unwinds_below_minimum() {
+        let f = fixture();
+        let rfq = rfq_with(
+            SizeNegotiationMode::MinQuantity(Quantity::new(8.0).unwrap()),
+            CompensationPolicy::AcceptPartial,
+        );
+
+        let result = f.service.execute(&rfq, two_leg_trade(&rfq)).await.unwrap();
+
+        assert!(matches!(
+            result.compensation,
+            CompensationOutcome::Unwound { .. }
+        ));
+        assert_eq!(
+            *f.gateway.unwound.lock().unwrap(),
+            vec![VenueId::new("mm-1")]
+        );
+    }
+
+    #[tokio::test]
+    async fn unwind_all_reverses_filled_legs() {
+        let f = fixture();
+        let rfq = rfq_with(
+            SizeNegotiationMode::AllOrNothing,
+            CompensationPolicy::UnwindAll,
+        );
+        let trade = two_leg_trade(&rfq);
+        let filled_quote = trade.allocations().first().unwrap().allocation().quote_id();
+
+        let result = f.service.execute(&rfq, trade).await.unwrap();
+
+        assert_second_leg_failed(&result.trade);
+        let CompensationOutcome::Unwound {
+            unwind_trades,
+            not_unwound,
+        } = result.compensation
+        else {
+            unreachable!("unwind policy produces an unwind outcome");
+        };
+        assert!(not_unwound.is_empty());
+        assert_eq!(unwind_trades.len(), 1);
+
+        let unwind = f
+            .repo
+            .get(*unwind_trades.first().unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(unwind.unwinds(), Some(result.trade.id()));
+        assert_eq!(unwind.quote_id(), filled_quote);
+        assert_eq!(unwind.quantity(), Quantity::new(6.0).unwrap());
+        assert!(f.reofferer.reoffered.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn fully_filled_trade_needs_no_compensation() {
+        let f = fixture();
+        let rfq = rfq_with(
+            SizeNegotiationMode::AllOrNothing,
+            CompensationPolicy::UnwindAll,
+        );
+        let trade = trade_with_legs(&rfq, &[("mm-1", 10.0, 100.0)]);
+
+        let result = f.service.execute(&rfq, trade).await.unwrap();
+
+        assert_eq!(result.compensation, CompensationOutcome::NotNeeded);
+        assert!(f.publisher.failed.lock().unwrap().is_empty());
+    }
+}
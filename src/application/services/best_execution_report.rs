@@ -0,0 +1,615 @@
+//! # Best Execution Report Service
+//!
+//! Per-client best execution reports over a period.
+//!
+//! [`BestExecutionReportService`] reviews the RFQs a client created in a
+//! period `[from, to)`, together with their quotes, aggregation reports and
+//! trades, and builds a [`BestExecutionReport`]:
+//!
+//! - **Venues contacted**: venues asked to quote according to the RFQ's
+//!   aggregation report, or the venues that quoted when it has none.
+//! - **Best price selected**: whether the traded quote was the best-priced
+//!   quote on the traded side received before execution. Superseded quotes
+//!   were not available and are not compared; ties count as best.
+//! - **Improvement**: the traded price against the reference price captured
+//!   at execution, in bps.
+//!
+//! When the best quote was not traded, the report says why: it had expired
+//! by the time of execution, or the client chose another quote.
+//!
+//! With [`BestExecutionReportService::with_fx_conversion`] configured, the
+//! report also totals the traded notional in the base currency and lists
+//! the FX rate applied to each trade. A trade whose notional cannot be
+//! converted fails the report.
+//!
+//! A report for a period that has ended is stored the first time it is
+//! generated, and that snapshot is returned for every later request, so the
+//! figures a client was given do not shift as records are amended. Reports
+//! for periods still open are built fresh each time.
+
+use crate::application::error::{ApplicationError, ApplicationResult, InfrastructureError};
+use crate::application::services::fx_conversion::FxConversionPort;
+use crate::domain::entities::quote_history::QuoteStatus;
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::entities::trade::Trade;
+use crate::domain::value_objects::arithmetic::CheckedArithmetic;
+use crate::domain::value_objects::timestamp::{Clock, SystemClock, Timestamp};
+use crate::domain::value_objects::{
+    BestExecutionException, BestExecutionReport, CounterpartyId, Currency, FxRate,
+    MissedBestPriceReason, Money, OrderSide, VenueId,
+};
+use crate::infrastructure::persistence::traits::{
+    AggregationReportRepository, BestExecutionReportRepository, RepositoryError, RfqRepository,
+    TradeRepository,
+};
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// How one traded RFQ compares with the best price available.
+struct ExecutionAssessment {
+    /// The trade did not take the best price, and why.
+    exception: Option<BestExecutionException>,
+    /// Improvement on the reference price, in bps.
+    improvement_bps: Option<f64>,
+}
+
+/// Builds and stores best execution reports.
+#[derive(Debug)]
+pub struct BestExecutionReportService {
+    rfqs: Arc<dyn RfqRepository>,
+    trades: Arc<dyn TradeRepository>,
+    aggregation_reports: Arc<dyn AggregationReportRepository>,
+    reports: Arc<dyn BestExecutionReportRepository>,
+    clock: Arc<dyn Clock>,
+    fx: Option<(Arc<dyn FxConversionPort>, Currency)>,
+}
+
+impl BestExecutionReportService {
+    /// Creates a report service reading from the given stores.
+    ///
+    /// Reports of ended periods are stored in `reports`.
+    #[must_use]
+    pub fn new(
+        rfqs: Arc<dyn RfqRepository>,
+        trades: Arc<dyn TradeRepository>,
+        aggregation_reports: Arc<dyn AggregationReportRepository>,
+        reports: Arc<dyn BestExecutionReportRepository>,
+    ) -> Self {
+        Self {
+            rfqs,
+            trades,
+            aggregation_reports,
+            reports,
+            clock: Arc::new(SystemClock),
+            fx: None,
+        }
+    }
+
+    /// Sets the clock used to stamp reports and decide whether a period
+    /// has ended.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Totals traded notionals in `base_currency`, converted with `fx`.
+    #[must_use]
+    pub fn with_fx_conversion(
+        mut self,
+        fx: Arc<dyn FxConversionPort>,
+        base_currency: Currency,
+    ) -> Self {
+        self.fx = Some((fx, base_currency));
+        self
+    }
+
+    /// Returns the best execution report of `client_id` for `[from, to)`.
+    ///
+    /// If the period has ended, the stored snapshot is returned when there
+    /// is one; otherwise the report is built and stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `from` is not before `to`, or an
+    /// infrastructure error if records cannot be read or the report cannot
+    /// be stored.
+    pub async fn report(
+        &self,
+        client_id: &CounterpartyId,
+        from: Timestamp,
+        to: Timestamp,
+    ) -> ApplicationResult<BestExecutionReport> {
+        if from >= to {
+            return Err(ApplicationError::validation(format!(
+                "from {from} must be before to {to}"
+            )));
+        }
+
+        let now = self.clock.now();
+        let ended = to <= now;
+        if ended
+            && let Some(report) = self
+                .reports
+                .find(client_id, from, to)
+                .await
+                .map_err(repository_error)?
+        {
+            return Ok(report);
+        }
+
+        let report = self.build(client_id, from, to, now).await?;
+        if !ended {
+            return Ok(report);
+        }
+        if self.reports.save(&report).await.map_err(repository_error)? {
+            return Ok(report);
+        }
+
+        // Another request stored the snapshot first; return that one
+        Ok(self
+            .reports
+            .find(client_id, from, to)
+            .await
+            .map_err(repository_error)?
+            .unwrap_or(report))
+    }
+
+    /// Builds the report from the stored RFQs, aggregation reports and trades.
+    async fn build(
+        &self,
+        client_id: &CounterpartyId,
+        from: Timestamp,
+        to: Timestamp,
+        generated_at: Timestamp,
+    ) -> ApplicationResult<BestExecutionReport> {
+        let mut rfqs: Vec<Rfq> = self
+            .rfqs
+            .find_by_client(client_id)
+            .await
+            .map_err(repository_error)?
+            .into_iter()
+            .filter(|rfq| rfq.created_at() >= from && rfq.created_at() < to)
+            .collect();
+        rfqs.sort_by_key(|rfq| (rfq.created_at(), rfq.id().to_string()));
+
+        let mut contacted = Vec::new();
+        let mut executed: Vec<(Trade, Option<ExecutionAssessment>)> = Vec::new();
+        let mut traded_notional = self.fx.as_ref().map(|(_, base)| Money::zero(base.clone()));
+        let mut fx_rates = Vec::new();
+        for rfq in &rfqs {
+            let venues = self.venues_contacted(rfq).await?;
+            if venues > 0 {
+                contacted.push(venues);
+            }
+            if let Some(trade) = self
+                .trades
+                .get_by_rfq(rfq.id())
+                .await
+                .map_err(repository_error)?
+            {
+                if let Some(total) = traded_notional.as_mut() {
+                    let (notional, rate) = self.normalized_notional(rfq, &trade).await?;
+                    *total = total.checked_add(&notional).map_err(|e| {
+                        ApplicationError::internal(format!("traded notional overflow: {e}"))
+                    })?;
+                    fx_rates.extend(rate);
+                }
+                let assessment = assess(rfq, &trade);
+                executed.push((trade, assessment));
+            }
+        }
+        executed.sort_by_key(|(trade, _)| (trade.created_at(), trade.id().to_string()));
+
+        let assessed: Vec<&ExecutionAssessment> =
+            executed.iter().filter_map(|(_, a)| a.as_ref()).collect();
+        let best_selected = assessed.iter().filter(|a| a.exception.is_none()).count();
+        let improvements: Vec<f64> = assessed.iter().filter_map(|a| a.improvement_bps).collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        let best_price_selected_pct =
+            (!assessed.is_empty()).then(|| best_selected as f64 * 100.0 / assessed.len() as f64);
+        #[allow(clippy::cast_precision_loss)]
+        let average_venues_contacted = mean(contacted.iter().map(|&n| n as f64));
+
+        Ok(BestExecutionReport {
+            client_id: client_id.clone(),
+            from,
+            to,
+            rfq_count: rfqs.len() as u64,
+            executed_count: executed.len() as u64,
+            average_venues_contacted,
+            best_price_selected_pct,
+            average_improvement_bps: mean(improvements.iter().copied()),
+            exceptions: assessed
+                .iter()
+                .filter_map(|a| a.exception.clone())
+                .collect(),
+            traded_notional,
+            fx_rates,
+            generated_at,
+        })
+    }
+
+    /// Returns the notional of `trade` in the base currency, with the rate
+    /// applied if it had to be converted.
+    async fn normalized_notional(
+        &self,
+        rfq: &Rfq,
+        trade: &Trade,
+    ) -> ApplicationResult<(Money, Option<FxRate>)> {
+        let cannot_normalize = |reason: String| {
+            ApplicationError::internal(format!(
+                "cannot normalize notional of trade {}: {reason}",
+                trade.id()
+            ))
+        };
+        let Some((fx, base_currency)) = &self.fx else {
+            return Err(cannot_normalize("no FX conversion configured".to_string()));
+        };
+        let amount = trade
+            .price()
+            .get()
+            .safe_mul(trade.quantity().get())
+            .map_err(|e| cannot_normalize(e.to_string()))?;
+        let currency = Currency::new(rfq.instrument().quote_asset())
+            .map_err(|e| cannot_normalize(e.to_string()))?;
+        let conversion = fx
+            .convert(&Money::new(amount, currency), base_currency)
+            .await
+            .map_err(|e| cannot_normalize(e.to_string()))?;
+        Ok((conversion.converted, conversion.rate))
+    }
+
+    /// Returns how many venues were asked to quote `rfq`.
+    async fn venues_contacted(&self, rfq: &Rfq) -> ApplicationResult<usize> {
+        if let Some(report) = self
+            .aggregation_reports
+            .find_by_rfq_id(rfq.id())
+            .await
+            .map_err(repository_error)?
+        {
+            let counts = report.counts();
+            return Ok(report
+                .venue_outcomes
+                .len()
+                .saturating_sub(counts.excluded as usize));
+        }
+        let venues: HashSet<&VenueId> = rfq
+            .quote_history()
+            .into_iter()
+            .map(|entry| entry.quote.venue_id())
+            .collect();
+        Ok(venues.len())
+    }
+}
+
+/// Compares `trade` with the best quote `rfq` had on the traded side.
+///
+/// Returns `None` if the traded quote is not among the RFQ's quotes.
+fn assess(rfq: &Rfq, trade: &Trade) -> Option<ExecutionAssessment> {
+    let history = rfq.quote_history();
+    let selected = history
+        .iter()
+        .find(|entry| entry.quote.id() == trade.quote_id())?;
+    let side = selected.side;
+    let executed_at = trade.created_at();
+
+    let best = history
+        .iter()
+        .filter(|entry| {
+            entry.side == side
+                && entry.status != QuoteStatus::Superseded
+                && entry.quote.received_at() <= executed_at
+        })
+        .fold(selected, |best, entry| {
+            let better = match side {
+                OrderSide::Buy => entry.quote.price() < best.quote.price(),
+                OrderSide::Sell => entry.quote.price() > best.quote.price(),
+            };
+            if better { entry } else { best }
+        });
+
+    let exception = (best.quote.id() != selected.quote.id()).then(|| BestExecutionException {
+        rfq_id: rfq.id(),
+        trade_id: trade.id(),
+        side,
+        selected_quote_id: selected.quote.id(),
+        selected_price: selected.quote.price(),
+        best_quote_id: best.quote.id(),
+        best_price: best.quote.price(),
+        reason: if best.quote.is_expired_at(executed_at) {
+            MissedBestPriceReason::BestQuoteExpired
+        } else {
+            MissedBestPriceReason::ClientChoice
+        },
+    });
+    let improvement_bps = trade
+        .slippage_bps(side)
+        .and_then(|slippage| slippage.negate().get().to_f64());
+
+    Some(ExecutionAssessment {
+        exception,
+        improvement_bps,
+    })
+}
+
+/// Returns the mean of `values`, or `None` if there are none.
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0u32), |(sum, count), v| (sum + v, count + 1));
+    (count > 0).then(|| sum / f64::from(count))
+}
+
+fn repository_error(error: RepositoryError) -> ApplicationError {
+    InfrastructureError::Repository(error).into()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::application::services::fx_conversion::FxError;
+    use crate::domain::entities::quote::Quote;
+    use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::entities::trade::SettlementState;
+    use crate::domain::value_objects::timestamp::MockClock;
+    use crate::domain::value_objects::{
+        AggregationReport, AssetClass, Instrument, Price, Quantity, ReferencePriceSource,
+        SettlementMethod, Symbol, TradeId, VenueExclusionReason, VenueOutcome, VenueOutcomeKind,
+    };
+    use crate::infrastructure::persistence::in_memory::{
+        InMemoryAggregationReportRepository, InMemoryBestExecutionReportRepository,
+        InMemoryRfqRepository, InMemoryTradeRepository,
+    };
+    use rust_decimal::Decimal;
+
+    struct Fixture {
+        rfqs: Arc<InMemoryRfqRepository>,
+        trades: Arc<InMemoryTradeRepository>,
+        aggregation_reports: Arc<InMemoryAggregationReportRepository>,
+        reports: Arc<InMemoryBestExecutionReportRepository>,
+        clock: Arc<MockClock>,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self {
+                rfqs: Arc::new(InMemoryRfqRepository::new()),
+                trades: Arc::new(InMemoryTradeRepository::new()),
+                aggregation_reports: Arc::new(InMemoryAggregationReportRepository::new()),
+                reports: Arc::new(InMemoryBestExecutionReportRepository::new()),
+                clock: Arc::new(MockClock::default()),
+            }
+        }
+
+        fn service(&self) -> BestExecutionReportService {
+            BestExecutionReportService::new(
+                Arc::clone(&self.rfqs) as Arc<dyn RfqRepository>,
+                Arc::clone(&self.trades) as Arc<dyn TradeRepository>,
+                Arc::clone(&self.aggregation_reports) as Arc<dyn AggregationReportRepository>,
+                Arc::clone(&self.reports) as Arc<dyn BestExecutionReportRepository>,
+            )
+            .with_clock(Arc::clone(&self.clock) as Arc<dyn Clock>)
+        }
+
+        /// Stores a buy RFQ of `client` quoted at `(venue, price, valid_secs)`.
+        async fn rfq(&self, client: &str, quotes: &[(&str, f64, i64)]) -> Rfq {
+            let instrument = Instrument::new(
+                Symbol::new("BTC/USD").unwrap(),
+                AssetClass::CryptoSpot,
+                SettlementMethod::OffChain,
+            );
+            let mut rfq = RfqBuilder::new(
+                CounterpartyId::new(client),
+                instrument,
+                OrderSide::Buy,
+                Quantity::new(1.0).unwrap(),
+                Timestamp::now().add_secs(300),
+            )
+            .build();
+            rfq.start_quote_collection().unwrap();
+            for &(venue, price, valid_secs) in quotes {
+                let quote = Quote::new(
+                    rfq.id(),
+                    VenueId::new(venue),
+                    Price::new(price).unwrap(),
+                    Quantity::new(1.0).unwrap(),
+                    Timestamp::now().add_secs(valid_secs),
+                )
+                .unwrap();
+                rfq.receive_quote(quote).unwrap();
+            }
+            self.rfqs.save(&rfq).await.unwrap();
+            rfq
+        }
+
+        /// Stores a trade of `rfq`'s quote from `venue`, `after_secs` from now.
+        async fn trade(&self, rfq: &Rfq, venue: &str, after_secs: i64, reference: f64) {
+            let quote = rfq
+                .quotes()
+                .iter()
+                .find(|q| q.venue_id().as_str() == venue)
+                .unwrap();
+            let at = Timestamp::now().add_secs(after_secs);
+            let mut trade = Trade::from_parts(
+                TradeId::new_v4(),
+                rfq.id(),
+                quote.id(),
+                quote.venue_id().clone(),
+                quote.price(),
+                quote.quantity(),
+                None,
+                SettlementState::Pending,
+                None,
+                None,
+                1,
+                at,
+                at,
+                None,
+                None,
+                None,
+            );
+            trade.set_reference_price_at_execution(Price::new(reference).unwrap());
+            self.trades.save(&trade).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn report_flags_best_quote_skipped_for_validity() {
+        let f = Fixture::new();
+        // Best quote (100) lapses after 30s; the trade at 45s takes 101
+        let skipped = f
+            .rfq(
+                "client-1",
+                &[("venue-a", 100.0, 30), ("venue-b", 101.0, 60)],
+            )
+            .await;
+        f.trade(&skipped, "venue-b", 45, 102.0).await;
+        // Best quote taken; three venues asked, one more excluded
+        let best = f
+            .rfq("client-1", &[("venue-a", 99.0, 60), ("venue-b", 99.5, 60)])
+            .await;
+        f.trade(&best, "venue-a", 1, 100.0).await;
+        let outcome = |venue: &str, outcome| VenueOutcome::new(VenueId::new(venue), outcome);
+        f.aggregation_reports
+            .save(&AggregationReport::new(
+                best.id(),
+                vec![
+                    outcome("venue-a", VenueOutcomeKind::Timeout),
+                    outcome("venue-b", VenueOutcomeKind::Timeout),
+                    outcome("venue-c", VenueOutcomeKind::Timeout),
+                    outcome(
+                        "venue-d",
+                        VenueOutcomeKind::Excluded {
+                            reason: VenueExclusionReason::Blocklisted,
+                        },
+                    ),
+                ],
+                Timestamp::now(),
+            ))
+            .await
+            .unwrap();
+        // Quoted but never traded
+        f.rfq("client-1", &[("venue-a", 100.0, 60)]).await;
+        // Another client's RFQ
+        let other = f.rfq("client-2", &[("venue-a", 100.0, 60)]).await;
+        f.trade(&other, "venue-a", 1, 100.0).await;
+
+        let from = Timestamp::now().add_secs(-60);
+        let to = Timestamp::now().add_secs(3_600);
+        let report = f
+            .service()
+            .report(&CounterpartyId::new("client-1"), from, to)
+            .await
+            .unwrap();
+
+        assert_eq!(report.rfq_count, 3);
+        assert_eq!(report.executed_count, 2);
+        assert_eq!(report.average_venues_contacted, Some(2.0));
+        assert_eq!(report.best_price_selected_pct, Some(50.0));
+        // (102 - 101) / 102 and (100 - 99) / 100, in bps
+        let expected = (10_000.0 / 102.0 + 100.0) / 2.0;
+        assert!((report.average_improvement_bps.unwrap() - expected).abs() < 1e-6);
+        assert_eq!(report.exceptions.len(), 1);
+        let exception = &report.exceptions[0];
+        assert_eq!(exception.rfq_id, skipped.id());
+        assert_eq!(exception.best_price, Price::new(100.0).unwrap());
+        assert_eq!(exception.selected_price, Price::new(101.0).unwrap());
+        assert_eq!(exception.reason, MissedBestPriceReason::BestQuoteExpired);
+    }
+
+    #[tokio::test]
+    async fn ended_period_is_snapshotted_on_first_generation() {
+        let f = Fixture::new();
+        let client = CounterpartyId::new("client-1");
+        let rfq = f.rfq("client-1", &[("venue-a", 100.0, 60)]).await;
+        f.trade(&rfq, "venue-a", 1, 100.0).await;
+        let from = Timestamp::now().add_secs(-60);
+        let to = Timestamp::now().add_secs(60);
+        let service = f.service();
+
+        // Still open: built fresh, not stored
+        service.report(&client, from, to).await.unwrap();
+        assert!(f.reports.find(&client, from, to).await.unwrap().is_none());
+
+        f.clock.advance_secs(120);
+        let first = service.report(&client, from, to).await.unwrap();
+        assert_eq!(first.rfq_count, 1);
+
+        f.rfq("client-1", &[("venue-b", 100.0, 60)]).await;
+        f.clock.advance_secs(60);
+        let again = service.report(&client, from, to).await.unwrap();
+        assert_eq!(again, first);
+
+        assert!(
+            service
+                .report(&client, to, from)
+                .await
+                .unwrap_err()
+                .is_validation()
+        );
+    }
+
+    /// FX port converting USD into EUR at 0.8.
+    #[derive(Debug)]
+    struct UsdEurFx;
+
+    #[async_trait::async_trait]
+    impl FxConversionPort for UsdEurFx {
+        async fn rate(&self, from: &Currency, to: &Currency) -> Result<FxRate, FxError> {
+            if from != &Currency::usd() || to.as_str() != "EUR" {
+                return Err(FxError::UnknownPair {
+                    from: from.clone(),
+                    to: to.clone(),
+                });
+            }
+            Ok(FxRate::new(
+                from.clone(),
+                to.clone(),
+                Decimal::new(8, 1),
+                ReferencePriceSource::ClobMid,
+                Timestamp::now(),
+            )?)
+        }
+    }
+
+    #[tokio::test]
+    async fn traded_notional_is_normalized_with_the_rates_recorded() {
+        let f = Fixture::new();
+        let first = f.rfq("client-1", &[("venue-a", 100.0, 60)]).await;
+        f.trade(&first, "venue-a", 1, 100.0).await;
+        let second = f.rfq("client-1", &[("venue-a", 150.0, 60)]).await;
+        f.trade(&second, "venue-a", 2, 150.0).await;
+
+        let eur = Currency::new("EUR").unwrap();
+        let report = f
+            .service()
+            .with_fx_conversion(Arc::new(UsdEurFx), eur.clone())
+            .report(
+                &CounterpartyId::new("client-1"),
+                Timestamp::now().add_secs(-60),
+                Timestamp::now().add_secs(3_600),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            report.traded_notional,
+            Some(Money::new(Decimal::from(200), eur))
+        );
+        assert_eq!(report.fx_rates.len(), 2);
+        assert!(report.fx_rates.iter().all(|r| r.rate == Decimal::new(8, 1)));
+
+        let unknown = f
+            .service()
+            .with_fx_conversion(Arc::new(UsdEurFx), Currency::new("GBP").unwrap())
+            .report(
+                &CounterpartyId::new("client-1"),
+                Timestamp::now().add_secs(-60),
+                Timestamp::now().add_secs(3_600),
+            )
+            .await;
+        assert!(unknown.is_err());
+    }
+}
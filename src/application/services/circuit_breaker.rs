@@ -21,12 +21,37 @@
 //!                                       ↓
 //!                                     Open
 //! ```
+//!
+//! # Half-Open Probes
+//!
+//! [`CircuitBreaker::try_acquire`] admits at most
+//! [`CircuitBreakerConfig::max_half_open_probes`] concurrent calls while the
+//! circuit is half-open, so a recovering venue is probed rather than
+//! re-flooded. The returned [`CircuitPermit`] frees its probe slot on drop.
+//!
+//! # Manual Overrides
+//!
+//! [`CircuitBreaker::force_open`] and [`CircuitBreaker::force_close`] pin the
+//! circuit for incident response. While pinned, recorded successes and
+//! failures never move the circuit; [`CircuitBreaker::clear_override`]
+//! hands control back to the state machine.
+//!
+//! # Observability and Persistence
+//!
+//! Every state change is reported, in registration order, to each
+//! [`CircuitStateListener`]; [`MetricsCircuitListener`] exports them as
+//! Prometheus series. A [`CircuitStateStore`] optionally keeps the state
+//! and override across restarts.
 
+use crate::infrastructure::metrics;
+use crate::infrastructure::persistence::traits::RepositoryResult;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
-use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 
 /// Circuit breaker state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -41,17 +66,144 @@ pub enum CircuitState {
     HalfOpen,
 }
 
-impl fmt::Display for CircuitState {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let s = match self {
+impl CircuitState {
+    /// Returns the wire form of the state (e.g. `HALF_OPEN`).
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
             Self::Closed => "CLOSED",
             Self::Open => "OPEN",
             Self::HalfOpen => "HALF_OPEN",
-        };
-        write!(f, "{}", s)
+        }
+    }
+}
+
+impl fmt::Display for CircuitState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
     }
 }
 
+/// Manual override pinning a circuit in one state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CircuitOverride {
+    /// Circuit is held open; every request is rejected.
+    ForcedOpen,
+    /// Circuit is held closed; every request is admitted.
+    ForcedClosed,
+}
+
+impl fmt::Display for CircuitOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ForcedOpen => write!(f, "FORCED_OPEN"),
+            Self::ForcedClosed => write!(f, "FORCED_CLOSED"),
+        }
+    }
+}
+
+/// Why a circuit changed state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionReason {
+    /// Consecutive failures reached the threshold.
+    FailureThreshold,
+    /// The reset timeout elapsed and a probe was admitted.
+    ResetTimeoutElapsed,
+    /// Enough half-open probes succeeded.
+    ProbesSucceeded,
+    /// A half-open probe failed.
+    ProbeFailed,
+    /// An operator forced the circuit open.
+    ForcedOpen,
+    /// An operator forced the circuit closed.
+    ForcedClosed,
+    /// The breaker was reset.
+    Reset,
+}
+
+impl TransitionReason {
+    /// Returns the reason as a metric label value (e.g. `probe_failed`).
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::FailureThreshold => "failure_threshold",
+            Self::ResetTimeoutElapsed => "reset_timeout_elapsed",
+            Self::ProbesSucceeded => "probes_succeeded",
+            Self::ProbeFailed => "probe_failed",
+            Self::ForcedOpen => "forced_open",
+            Self::ForcedClosed => "forced_closed",
+            Self::Reset => "reset",
+        }
+    }
+}
+
+impl fmt::Display for TransitionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Observer of circuit state changes.
+///
+/// Listeners are called synchronously, after the state lock is released,
+/// so they may query the breaker but should not block.
+pub trait CircuitStateListener: Send + Sync + fmt::Debug {
+    /// Called after the circuit `name` moved from `from` to `to`.
+    fn on_transition(
+        &self,
+        name: &str,
+        from: CircuitState,
+        to: CircuitState,
+        reason: TransitionReason,
+    );
+}
+
+/// Exports circuit transitions through the [`metrics`] module.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetricsCircuitListener;
+
+impl CircuitStateListener for MetricsCircuitListener {
+    fn on_transition(
+        &self,
+        name: &str,
+        from: CircuitState,
+        to: CircuitState,
+        reason: TransitionReason,
+    ) {
+        metrics::record_circuit_transition(name, from.as_str(), to.as_str(), reason.as_str());
+    }
+}
+
+/// Persisted state of a circuit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CircuitSnapshot {
+    /// State of the circuit.
+    pub state: CircuitState,
+    /// Manual override in force, if any.
+    pub forced: Option<CircuitOverride>,
+}
+
+/// Storage for circuit state that survives a restart.
+///
+/// Calls are synchronous and made on every state change, so
+/// implementations should be cheap.
+pub trait CircuitStateStore: Send + Sync + fmt::Debug {
+    /// Loads the last saved state of the circuit `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the state cannot be read.
+    fn load(&self, name: &str) -> RepositoryResult<Option<CircuitSnapshot>>;
+
+    /// Saves the state of the circuit `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the state cannot be written.
+    fn save(&self, name: &str, snapshot: &CircuitSnapshot) -> RepositoryResult<()>;
+}
+
 /// Error returned when circuit breaker rejects a request.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CircuitBreakerError {
@@ -103,6 +255,11 @@ pub struct CircuitBreakerConfig {
     pub reset_timeout_ms: u64,
     /// Maximum number of requests allowed in half-open state.
     pub half_open_max_requests: u32,
+    /// Maximum number of probes in flight at once in half-open state.
+    ///
+    /// Enforced by [`CircuitBreaker::try_acquire`]; values below 1 are
+    /// treated as 1 so the circuit can still recover.
+    pub max_half_open_probes: u32,
 }
 
 impl Default for CircuitBreakerConfig {
@@ -112,12 +269,16 @@ impl Default for CircuitBreakerConfig {
             success_threshold: 3,
             reset_timeout_ms: 30_000,
             half_open_max_requests: 3,
+            max_half_open_probes: 1,
         }
     }
 }
 
 impl CircuitBreakerConfig {
     /// Creates a new configuration with custom values.
+    ///
+    /// Admits one half-open probe at a time; see
+    /// [`with_max_half_open_probes`](Self::with_max_half_open_probes).
     #[must_use]
     pub fn new(
         failure_threshold: u32,
@@ -130,6 +291,7 @@ impl CircuitBreakerConfig {
             success_threshold,
             reset_timeout_ms,
             half_open_max_requests,
+            max_half_open_probes: 1,
         }
     }
 
@@ -141,6 +303,7 @@ impl CircuitBreakerConfig {
             success_threshold: 2,
             reset_timeout_ms: 10_000,
             half_open_max_requests: 1,
+            max_half_open_probes: 1,
         }
     }
 
@@ -152,8 +315,36 @@ impl CircuitBreakerConfig {
             success_threshold: 5,
             reset_timeout_ms: 60_000,
             half_open_max_requests: 5,
+            max_half_open_probes: 2,
         }
     }
+
+    /// Sets the maximum number of concurrent half-open probes.
+    #[must_use]
+    pub fn with_max_half_open_probes(mut self, max_probes: u32) -> Self {
+        self.max_half_open_probes = max_probes;
+        self
+    }
+}
+
+/// Admission to call through a circuit breaker.
+///
+/// A permit taken while the circuit is half-open holds one of the
+/// breaker's probe slots until it is dropped. Record the outcome with
+/// [`CircuitBreaker::record_success`] or [`CircuitBreaker::record_failure`]
+/// before dropping it.
+#[derive(Debug)]
+#[must_use = "dropping the permit frees the probe slot"]
+pub struct CircuitPermit {
+    probe: Option<OwnedSemaphorePermit>,
+}
+
+impl CircuitPermit {
+    /// Returns true if this permit holds a half-open probe slot.
+    #[must_use]
+    pub fn is_probe(&self) -> bool {
+        self.probe.is_some()
+    }
 }
 
 /// Circuit breaker for handling venue failures.
@@ -176,7 +367,7 @@ impl CircuitBreakerConfig {
 /// let breaker = CircuitBreaker::new("venue-1", config);
 ///
 /// // Check if request can proceed
-/// if breaker.can_execute().is_ok() {
+/// if let Ok(_permit) = breaker.try_acquire() {
 ///     // Make request...
 ///     // On success:
 ///     breaker.record_success();
@@ -190,33 +381,46 @@ pub struct CircuitBreaker {
     name: String,
     /// Current state of the circuit.
     state: RwLock<CircuitState>,
+    /// Manual override, if any.
+    forced: RwLock<Option<CircuitOverride>>,
     /// Number of consecutive failures.
     failure_count: AtomicU32,
     /// Number of consecutive successes (in half-open state).
     success_count: AtomicU32,
     /// Number of requests in half-open state.
     half_open_requests: AtomicU32,
+    /// Probe slots for concurrent half-open requests.
+    probes: Arc<Semaphore>,
     /// Timestamp of last failure (as millis since UNIX epoch).
     last_failure_time: AtomicU64,
     /// Instant when circuit was opened (for timeout calculation).
     opened_at: RwLock<Option<Instant>>,
     /// Configuration.
     config: CircuitBreakerConfig,
+    /// Observers of state changes, in registration order.
+    listeners: Vec<Arc<dyn CircuitStateListener>>,
+    /// Storage for state across restarts.
+    store: Option<Arc<dyn CircuitStateStore>>,
 }
 
 impl CircuitBreaker {
     /// Creates a new circuit breaker with the given configuration.
     #[must_use]
     pub fn new(name: impl Into<String>, config: CircuitBreakerConfig) -> Self {
+        let probes = config.max_half_open_probes.max(1) as usize;
         Self {
             name: name.into(),
             state: RwLock::new(CircuitState::Closed),
+            forced: RwLock::new(None),
             failure_count: AtomicU32::new(0),
             success_count: AtomicU32::new(0),
             half_open_requests: AtomicU32::new(0),
+            probes: Arc::new(Semaphore::new(probes)),
             last_failure_time: AtomicU64::new(0),
             opened_at: RwLock::new(None),
             config,
+            listeners: Vec::new(),
+            store: None,
         }
     }
 
@@ -226,6 +430,48 @@ impl CircuitBreaker {
         Self::new(name, CircuitBreakerConfig::default())
     }
 
+    /// Registers a listener for state changes.
+    ///
+    /// Listeners are called in the order they were registered.
+    #[must_use]
+    pub fn with_listener(mut self, listener: Arc<dyn CircuitStateListener>) -> Self {
+        self.listeners.push(listener);
+        self
+    }
+
+    /// Persists state changes to `store`, restoring any state saved there.
+    ///
+    /// A circuit saved open or half-open is restored open with a fresh
+    /// reset timeout. A store that cannot be read leaves the circuit closed.
+    #[must_use]
+    pub fn with_store(mut self, store: Arc<dyn CircuitStateStore>) -> Self {
+        match store.load(&self.name) {
+            Ok(Some(snapshot)) => self.restore(snapshot),
+            Ok(None) => {}
+            Err(e) => tracing::warn!(
+                circuit = %self.name,
+                error = %e,
+                "failed to load circuit state"
+            ),
+        }
+        self.store = Some(store);
+        self
+    }
+
+    fn restore(&mut self, snapshot: CircuitSnapshot) {
+        let state = match (snapshot.forced, snapshot.state) {
+            (Some(CircuitOverride::ForcedClosed), _) | (None, CircuitState::Closed) => {
+                CircuitState::Closed
+            }
+            _ => CircuitState::Open,
+        };
+        if state == CircuitState::Open {
+            *self.write_opened_at() = Some(Instant::now());
+        }
+        *self.write_state() = state;
+        *self.write_forced() = snapshot.forced;
+    }
+
     /// Returns the name of this circuit breaker.
     #[must_use]
     pub fn name(&self) -> &str {
@@ -238,6 +484,21 @@ impl CircuitBreaker {
         *self.read_state()
     }
 
+    /// Returns the manual override in force, if any.
+    #[must_use]
+    pub fn forced(&self) -> Option<CircuitOverride> {
+        *self.forced.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the state and override of the circuit.
+    #[must_use]
+    pub fn snapshot(&self) -> CircuitSnapshot {
+        CircuitSnapshot {
+            state: self.state(),
+            forced: self.forced(),
+        }
+    }
+
     /// Reads the state lock, recovering from poison if needed.
     fn read_state(&self) -> RwLockReadGuard<'_, CircuitState> {
         self.state.read().unwrap_or_else(PoisonError::into_inner)
@@ -248,6 +509,11 @@ impl CircuitBreaker {
         self.state.write().unwrap_or_else(PoisonError::into_inner)
     }
 
+    /// Writes the override lock, recovering from poison if needed.
+    fn write_forced(&self) -> RwLockWriteGuard<'_, Option<CircuitOverride>> {
+        self.forced.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
     /// Reads the opened_at lock, recovering from poison if needed.
     fn read_opened_at(&self) -> RwLockReadGuard<'_, Option<Instant>> {
         self.opened_at
@@ -282,6 +548,10 @@ impl CircuitBreaker {
 
     /// Checks if a request can be executed.
     ///
+    /// This does not limit concurrent half-open probes; use
+    /// [`try_acquire`](Self::try_acquire) to hold a probe slot for the
+    /// duration of the call.
+    ///
     /// # Returns
     ///
     /// - `Ok(())` if the request can proceed
@@ -292,35 +562,86 @@ impl CircuitBreaker {
     /// Returns `CircuitBreakerError::CircuitOpen` if the circuit is open.
     /// Returns `CircuitBreakerError::HalfOpenLimitReached` if half-open request limit is reached.
     pub fn can_execute(&self) -> Result<(), CircuitBreakerError> {
-        let current_state = self.state();
+        match self.forced() {
+            Some(CircuitOverride::ForcedOpen) => {
+                return Err(CircuitBreakerError::CircuitOpen {
+                    time_until_half_open_ms: None,
+                });
+            }
+            Some(CircuitOverride::ForcedClosed) => return Ok(()),
+            None => {}
+        }
 
-        match current_state {
+        match self.state() {
             CircuitState::Closed => Ok(()),
             CircuitState::Open => {
-                // Check if timeout has elapsed
-                if self.should_transition_to_half_open() {
-                    self.transition_to_half_open();
-                    // Allow this request as the first half-open request
-                    self.half_open_requests.fetch_add(1, Ordering::SeqCst);
-                    Ok(())
-                } else {
-                    Err(CircuitBreakerError::CircuitOpen {
+                if !self.should_transition_to_half_open() {
+                    return Err(CircuitBreakerError::CircuitOpen {
                         time_until_half_open_ms: self.time_until_half_open(),
-                    })
+                    });
                 }
+                // Only one caller performs the transition; the rest are
+                // admitted against the half-open limit like any other.
+                self.transition(
+                    Some(CircuitState::Open),
+                    CircuitState::HalfOpen,
+                    TransitionReason::ResetTimeoutElapsed,
+                );
+                self.admit_half_open()
             }
-            CircuitState::HalfOpen => {
-                let current_requests = self.half_open_requests.load(Ordering::SeqCst);
-                if current_requests < self.config.half_open_max_requests {
-                    self.half_open_requests.fetch_add(1, Ordering::SeqCst);
-                    Ok(())
-                } else {
-                    Err(CircuitBreakerError::HalfOpenLimitReached {
-                        max_requests: self.config.half_open_max_requests,
-                    })
-                }
+            CircuitState::HalfOpen => self.admit_half_open(),
+        }
+    }
+
+    /// Admits a request, holding a probe slot if the circuit is half-open.
+    ///
+    /// At most [`CircuitBreakerConfig::max_half_open_probes`] permits hold a
+    /// probe slot at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircuitBreakerError::CircuitOpen` if the circuit is open.
+    /// Returns `CircuitBreakerError::HalfOpenLimitReached` if every probe slot
+    /// is taken or the half-open request limit is reached.
+    pub fn try_acquire(&self) -> Result<CircuitPermit, CircuitBreakerError> {
+        match self.forced() {
+            Some(CircuitOverride::ForcedOpen) => {
+                return Err(CircuitBreakerError::CircuitOpen {
+                    time_until_half_open_ms: None,
+                });
+            }
+            Some(CircuitOverride::ForcedClosed) => return Ok(CircuitPermit { probe: None }),
+            None => {}
+        }
+
+        match self.state() {
+            CircuitState::Closed => return Ok(CircuitPermit { probe: None }),
+            CircuitState::Open if !self.should_transition_to_half_open() => {
+                return Err(CircuitBreakerError::CircuitOpen {
+                    time_until_half_open_ms: self.time_until_half_open(),
+                });
             }
+            CircuitState::Open | CircuitState::HalfOpen => {}
         }
+
+        let probe = Arc::clone(&self.probes).try_acquire_owned().map_err(|_| {
+            CircuitBreakerError::HalfOpenLimitReached {
+                max_requests: self.config.max_half_open_probes.max(1),
+            }
+        })?;
+        self.can_execute()?;
+        Ok(CircuitPermit { probe: Some(probe) })
+    }
+
+    /// Counts a request against the half-open request limit.
+    fn admit_half_open(&self) -> Result<(), CircuitBreakerError> {
+        let max_requests = self.config.half_open_max_requests;
+        self.half_open_requests
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                (n < max_requests).then_some(n + 1)
+            })
+            .map(|_| ())
+            .map_err(|_| CircuitBreakerError::HalfOpenLimitReached { max_requests })
     }
 
     /// Records a successful request.
@@ -337,8 +658,12 @@ impl CircuitBreaker {
             }
             CircuitState::HalfOpen => {
                 let new_count = self.success_count.fetch_add(1, Ordering::SeqCst) + 1;
-                if new_count >= self.config.success_threshold {
-                    self.transition_to_closed();
+                if new_count >= self.config.success_threshold && self.forced().is_none() {
+                    self.transition(
+                        Some(CircuitState::HalfOpen),
+                        CircuitState::Closed,
+                        TransitionReason::ProbesSucceeded,
+                    );
                 }
             }
             CircuitState::Open => {
@@ -351,38 +676,70 @@ impl CircuitBreaker {
     ///
     /// In closed state, increments failure count and may open the circuit.
     /// In half-open state, immediately opens the circuit.
+    /// A forced-closed circuit counts failures but never opens.
     pub fn record_failure(&self) {
         let current_state = self.state();
+        self.update_last_failure_time();
 
         match current_state {
             CircuitState::Closed => {
                 let new_count = self.failure_count.fetch_add(1, Ordering::SeqCst) + 1;
-                self.update_last_failure_time();
-                if new_count >= self.config.failure_threshold {
-                    self.transition_to_open();
+                if new_count >= self.config.failure_threshold && self.forced().is_none() {
+                    self.transition(
+                        Some(CircuitState::Closed),
+                        CircuitState::Open,
+                        TransitionReason::FailureThreshold,
+                    );
                 }
             }
             CircuitState::HalfOpen => {
                 // Any failure in half-open immediately opens the circuit
-                self.update_last_failure_time();
-                self.transition_to_open();
+                if self.forced().is_none() {
+                    self.transition(
+                        Some(CircuitState::HalfOpen),
+                        CircuitState::Open,
+                        TransitionReason::ProbeFailed,
+                    );
+                }
             }
             CircuitState::Open => {
-                // Update failure time but don't increment count
-                self.update_last_failure_time();
+                // Failure time already updated; don't increment count
             }
         }
     }
 
-    /// Resets the circuit breaker to closed state.
+    /// Holds the circuit open until [`clear_override`](Self::clear_override).
+    pub fn force_open(&self) {
+        *self.write_forced() = Some(CircuitOverride::ForcedOpen);
+        if !self.transition(None, CircuitState::Open, TransitionReason::ForcedOpen) {
+            self.persist();
+        }
+    }
+
+    /// Holds the circuit closed until [`clear_override`](Self::clear_override).
+    pub fn force_close(&self) {
+        *self.write_forced() = Some(CircuitOverride::ForcedClosed);
+        if !self.transition(None, CircuitState::Closed, TransitionReason::ForcedClosed) {
+            self.persist();
+        }
+    }
+
+    /// Removes any manual override, leaving the circuit in its current state.
+    ///
+    /// A circuit released from a forced open half-opens once the reset
+    /// timeout has elapsed since it was forced.
+    pub fn clear_override(&self) {
+        *self.write_forced() = None;
+        self.persist();
+    }
+
+    /// Resets the circuit breaker to closed state, clearing any override.
     pub fn reset(&self) {
-        let mut state = self.write_state();
-        *state = CircuitState::Closed;
-        self.failure_count.store(0, Ordering::SeqCst);
-        self.success_count.store(0, Ordering::SeqCst);
-        self.half_open_requests.store(0, Ordering::SeqCst);
+        *self.write_forced() = None;
         self.last_failure_time.store(0, Ordering::SeqCst);
-        *self.write_opened_at() = None;
+        if !self.transition(None, CircuitState::Closed, TransitionReason::Reset) {
+            self.persist();
+        }
     }
 
     /// Returns true if the circuit is closed.
@@ -428,28 +785,66 @@ impl CircuitBreaker {
         }
     }
 
-    fn transition_to_open(&self) {
-        let mut state = self.write_state();
-        *state = CircuitState::Open;
-        self.success_count.store(0, Ordering::SeqCst);
-        self.half_open_requests.store(0, Ordering::SeqCst);
-        *self.write_opened_at() = Some(Instant::now());
-    }
+    /// Moves the circuit to `to`, resetting the counters that state starts
+    /// from.
+    ///
+    /// With `expected` set, nothing happens unless the circuit is in that
+    /// state. Listeners and the store are told only if the state changed.
+    /// Returns true if it did.
+    fn transition(
+        &self,
+        expected: Option<CircuitState>,
+        to: CircuitState,
+        reason: TransitionReason,
+    ) -> bool {
+        let from = {
+            let mut state = self.write_state();
+            let from = *state;
+            if expected.is_some_and(|expected| expected != from) {
+                return false;
+            }
+            *state = to;
+            self.success_count.store(0, Ordering::SeqCst);
+            self.half_open_requests.store(0, Ordering::SeqCst);
+            match to {
+                CircuitState::Open => *self.write_opened_at() = Some(Instant::now()),
+                CircuitState::Closed => {
+                    self.failure_count.store(0, Ordering::SeqCst);
+                    *self.write_opened_at() = None;
+                }
+                CircuitState::HalfOpen => {}
+            }
+            from
+        };
 
-    fn transition_to_half_open(&self) {
-        let mut state = self.write_state();
-        *state = CircuitState::HalfOpen;
-        self.success_count.store(0, Ordering::SeqCst);
-        self.half_open_requests.store(0, Ordering::SeqCst);
+        if from == to {
+            return false;
+        }
+        tracing::info!(
+            circuit = %self.name,
+            %from,
+            %to,
+            %reason,
+            "circuit state changed"
+        );
+        for listener in &self.listeners {
+            listener.on_transition(&self.name, from, to, reason);
+        }
+        self.persist();
+        true
     }
 
-    fn transition_to_closed(&self) {
-        let mut state = self.write_state();
-        *state = CircuitState::Closed;
-        self.failure_count.store(0, Ordering::SeqCst);
-        self.success_count.store(0, Ordering::SeqCst);
-        self.half_open_requests.store(0, Ordering::SeqCst);
-        *self.write_opened_at() = None;
+    fn persist(&self) {
+        let Some(store) = &self.store else {
+            return;
+        };
+        if let Err(e) = store.save(&self.name, &self.snapshot()) {
+            tracing::warn!(
+                circuit = %self.name,
+                error = %e,
+                "failed to save circuit state"
+            );
+        }
     }
 
     fn update_last_failure_time(&self) {
@@ -474,6 +869,82 @@ impl fmt::Display for CircuitBreaker {
     }
 }
 
+/// Circuit breakers keyed by name, created on first use.
+///
+/// Every breaker shares the registry's configuration, listeners, and store.
+#[derive(Debug, Default)]
+pub struct CircuitBreakerRegistry {
+    config: CircuitBreakerConfig,
+    listeners: Vec<Arc<dyn CircuitStateListener>>,
+    store: Option<Arc<dyn CircuitStateStore>>,
+    breakers: RwLock<HashMap<String, Arc<CircuitBreaker>>>,
+}
+
+impl CircuitBreakerRegistry {
+    /// Creates an empty registry whose breakers use `config`.
+    #[must_use]
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Registers a listener on every breaker created from now on.
+    #[must_use]
+    pub fn with_listener(mut self, listener: Arc<dyn CircuitStateListener>) -> Self {
+        self.listeners.push(listener);
+        self
+    }
+
+    /// Persists every breaker created from now on to `store`.
+    #[must_use]
+    pub fn with_store(mut self, store: Arc<dyn CircuitStateStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Returns the breaker named `name`, if it has been created.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Arc<CircuitBreaker>> {
+        self.breakers
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(name)
+            .cloned()
+    }
+
+    /// Returns the breaker named `name`, creating it on first use.
+    #[must_use]
+    pub fn get_or_create(&self, name: &str) -> Arc<CircuitBreaker> {
+        if let Some(breaker) = self.get(name) {
+            return breaker;
+        }
+        let mut breakers = self
+            .breakers
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        Arc::clone(
+            breakers
+                .entry(name.to_string())
+                .or_insert_with(|| Arc::new(self.build(name))),
+        )
+    }
+
+    fn build(&self, name: &str) -> CircuitBreaker {
+        let breaker = self
+            .listeners
+            .iter()
+            .fold(CircuitBreaker::new(name, self.config.clone()), |b, l| {
+                b.with_listener(Arc::clone(l))
+            });
+        match &self.store {
+            Some(store) => breaker.with_store(Arc::clone(store)),
+            None => breaker,
+        }
+    }
+}
+
 /// Result type for circuit breaker operations.
 pub type CircuitBreakerResult<T> = Result<T, CircuitBreakerError>;
 
@@ -481,6 +952,7 @@ pub type CircuitBreakerResult<T> = Result<T, CircuitBreakerError>;
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use std::sync::Mutex;
     use std::thread;
     use std::time::Duration;
 
@@ -490,6 +962,7 @@ mod tests {
             success_threshold: 2,
             reset_timeout_ms: 100, // Short timeout for tests
             half_open_max_requests: 2,
+            max_half_open_probes: 2,
         }
     }
 
@@ -752,4 +1225,180 @@ mod tests {
         assert!(breaker.is_closed());
         assert_eq!(breaker.config().failure_threshold, 5);
     }
+
+    fn tripped(config: CircuitBreakerConfig) -> CircuitBreaker {
+        let breaker = CircuitBreaker::new("test", config);
+        for _ in 0..breaker.config().failure_threshold {
+            breaker.record_failure();
+        }
+        thread::sleep(Duration::from_millis(150));
+        breaker
+    }
+
+    #[test]
+    fn only_one_probe_admitted_in_half_open() {
+        let breaker = tripped(create_test_config().with_max_half_open_probes(1));
+
+        let probe = breaker.try_acquire().unwrap();
+        assert!(probe.is_probe());
+        assert!(breaker.is_half_open());
+        assert_eq!(
+            breaker.try_acquire().unwrap_err(),
+            CircuitBreakerError::HalfOpenLimitReached { max_requests: 1 }
+        );
+
+        drop(probe);
+        let second = breaker.try_acquire().unwrap();
+        assert!(second.is_probe());
+        breaker.record_success();
+        drop(second);
+    }
+
+    #[test]
+    fn closed_permits_do_not_hold_probe_slots() {
+        let breaker = CircuitBreaker::new("test", create_test_config());
+        let permits: Vec<CircuitPermit> = (0..5).map(|_| breaker.try_acquire().unwrap()).collect();
+        assert!(permits.iter().all(|p| !p.is_probe()));
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingListener {
+        label: &'static str,
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl CircuitStateListener for RecordingListener {
+        fn on_transition(
+            &self,
+            name: &str,
+            from: CircuitState,
+            to: CircuitState,
+            reason: TransitionReason,
+        ) {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("{} {name}: {from}->{to} ({reason})", self.label));
+        }
+    }
+
+    #[test]
+    fn listeners_are_called_in_registration_and_transition_order() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let listener = |label| {
+            Arc::new(RecordingListener {
+                label,
+                calls: Arc::clone(&calls),
+            })
+        };
+        let breaker = CircuitBreaker::new("venue-1", create_test_config())
+            .with_listener(listener("metrics"))
+            .with_listener(listener("health"));
+
+        for _ in 0..3 {
+            breaker.record_failure();
+        }
+        thread::sleep(Duration::from_millis(150));
+        let _probe = breaker.try_acquire().unwrap();
+        breaker.record_failure();
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                "metrics venue-1: CLOSED->OPEN (failure_threshold)",
+                "health venue-1: CLOSED->OPEN (failure_threshold)",
+                "metrics venue-1: OPEN->HALF_OPEN (reset_timeout_elapsed)",
+                "health venue-1: OPEN->HALF_OPEN (reset_timeout_elapsed)",
+                "metrics venue-1: HALF_OPEN->OPEN (probe_failed)",
+                "health venue-1: HALF_OPEN->OPEN (probe_failed)",
+            ]
+        );
+    }
+
+    #[test]
+    fn forced_open_takes_precedence_over_reset_timeout() {
+        let breaker = CircuitBreaker::new("test", create_test_config());
+        breaker.force_open();
+        thread::sleep(Duration::from_millis(150));
+
+        assert_eq!(
+            breaker.try_acquire().unwrap_err(),
+            CircuitBreakerError::CircuitOpen {
+                time_until_half_open_ms: None
+            }
+        );
+        breaker.record_success();
+        assert!(breaker.is_open());
+        assert_eq!(breaker.forced(), Some(CircuitOverride::ForcedOpen));
+
+        breaker.clear_override();
+        assert!(breaker.try_acquire().unwrap().is_probe());
+        assert!(breaker.is_half_open());
+    }
+
+    #[test]
+    fn forced_closed_takes_precedence_over_failures() {
+        let breaker = tripped(create_test_config());
+        breaker.force_close();
+        assert!(breaker.is_closed());
+
+        for _ in 0..10 {
+            breaker.record_failure();
+        }
+        assert!(breaker.is_closed());
+        assert!(breaker.can_execute().is_ok());
+
+        breaker.clear_override();
+        breaker.record_failure();
+        assert!(breaker.is_open());
+    }
+
+    #[test]
+    fn reset_clears_override() {
+        let breaker = CircuitBreaker::new("test", create_test_config());
+        breaker.force_open();
+        breaker.reset();
+        assert!(breaker.is_closed());
+        assert_eq!(breaker.forced(), None);
+    }
+
+    #[test]
+    fn state_and_override_are_restored_from_the_store() {
+        use crate::infrastructure::persistence::in_memory::InMemoryCircuitStateStore;
+
+        let store = Arc::new(InMemoryCircuitStateStore::new());
+        let breaker = CircuitBreaker::new("venue-1", create_test_config())
+            .with_store(Arc::clone(&store) as Arc<dyn CircuitStateStore>);
+        breaker.force_open();
+        drop(breaker);
+
+        let restored = CircuitBreaker::new("venue-1", create_test_config())
+            .with_store(Arc::clone(&store) as Arc<dyn CircuitStateStore>);
+        assert!(restored.is_open());
+        assert_eq!(restored.forced(), Some(CircuitOverride::ForcedOpen));
+
+        restored.clear_override();
+        let other = CircuitBreaker::new("venue-2", create_test_config())
+            .with_store(Arc::clone(&store) as Arc<dyn CircuitStateStore>);
+        assert!(other.is_closed());
+        assert_eq!(
+            store.load("venue-1").unwrap(),
+            Some(CircuitSnapshot {
+                state: CircuitState::Open,
+                forced: None,
+            })
+        );
+    }
+
+    #[test]
+    fn registry_shares_breakers_by_name() {
+        let registry = CircuitBreakerRegistry::new(create_test_config());
+        assert!(registry.get("venue-1").is_none());
+
+        let breaker = registry.get_or_create("venue-1");
+        breaker.force_open();
+
+        assert!(registry.get_or_create("venue-1").is_open());
+        assert!(registry.get_or_create("venue-2").is_closed());
+    }
 }
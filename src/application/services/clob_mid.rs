@@ -0,0 +1,397 @@
+//! # CLOB Mid Reference Price
+//!
+//! Reference prices derived from central limit order book snapshots.
+//!
+//! This module provides:
+//! - [`OrderBookSnapshot`]: Best bid and ask at a point in time
+//! - [`OrderBookSnapshotPort`]: Async trait for obtaining snapshots
+//! - [`ClobMidReferencePriceProvider`]: Mid-price [`ReferencePriceProvider`]
+//!
+//! # Reliability Filters
+//!
+//! The mid is only used as a reference when the book is two-sided and
+//! uncrossed, and its spread is no wider than the configured maximum. Any
+//! other book yields no price so that the fallback chain moves on to the
+//! next source.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::application::services::clob_mid::OrderBookSnapshot;
+//! use otc_rfq::domain::value_objects::Price;
+//! use otc_rfq::domain::value_objects::timestamp::Timestamp;
+//!
+//! let snapshot = OrderBookSnapshot::new(
+//!     Some(Price::new(99.0).unwrap()),
+//!     Some(Price::new(101.0).unwrap()),
+//!     Timestamp::now(),
+//! );
+//! assert!(!snapshot.is_crossed());
+//! ```
+
+use crate::application::services::price_bounds::ReferencePriceProvider;
+use crate::domain::errors::DomainResult;
+use crate::domain::value_objects::arithmetic::{BPS_PER_UNIT, CheckedArithmetic};
+use crate::domain::value_objects::instrument::Instrument;
+use crate::domain::value_objects::price::Price;
+use crate::domain::value_objects::reference_price::ReferencePriceSource;
+use crate::domain::value_objects::timestamp::Timestamp;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::fmt;
+use std::sync::Arc;
+
+/// Default maximum bid/ask spread for a usable mid, in basis points.
+pub const DEFAULT_MAX_SPREAD_BPS: Decimal = Decimal::from_parts(100, 0, 0, false, 0);
+
+/// Top of an order book at a point in time.
+///
+/// Either side may be missing when the book is empty on that side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrderBookSnapshot {
+    best_bid: Option<Price>,
+    best_ask: Option<Price>,
+    taken_at: Timestamp,
+}
+
+impl OrderBookSnapshot {
+    /// Creates a new snapshot.
+    #[must_use]
+    pub const fn new(
+        best_bid: Option<Price>,
+        best_ask: Option<Price>,
+        taken_at: Timestamp,
+    ) -> Self {
+        Self {
+            best_bid,
+            best_ask,
+            taken_at,
+        }
+    }
+
+    /// Returns the best bid, if any.
+    #[inline]
+    #[must_use]
+    pub const fn best_bid(&self) -> Option<Price> {
+        self.best_bid
+    }
+
+    /// Returns the best ask, if any.
+    #[inline]
+    #[must_use]
+    pub const fn best_ask(&self) -> Option<Price> {
+        self.best_ask
+    }
+
+    /// Returns when the snapshot was taken.
+    #[inline]
+    #[must_use]
+    pub const fn taken_at(&self) -> Timestamp {
+        self.taken_at
+    }
+
+    /// Returns true if the best bid is above the best ask.
+    #[must_use]
+    pub fn is_crossed(&self) -> bool {
+        matches!((self.best_bid, self.best_ask), (Some(bid), Some(ask)) if bid > ask)
+    }
+
+    /// Returns the mid and the bid/ask spread in basis points of the mid.
+    ///
+    /// Returns `Ok(None)` for one-sided, crossed, and zero-bid books.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError` if the arithmetic overflows.
+    pub fn mid_and_spread_bps(&self) -> DomainResult<Option<(Decimal, Decimal)>> {
+        let (Some(bid), Some(ask)) = (self.best_bid, self.best_ask) else {
+            return Ok(None);
+        };
+        if bid.is_zero() || self.is_crossed() {
+            return Ok(None);
+        }
+
+        let mid = bid.get().safe_add(ask.get())?.safe_div(Decimal::TWO)?;
+        let spread_bps = ask
+            .get()
+            .safe_sub(bid.get())?
+            .safe_div(mid)?
+            .safe_mul(BPS_PER_UNIT)?;
+        Ok(Some((mid, spread_bps)))
+    }
+}
+
+/// Source of order book snapshots for instruments.
+#[async_trait]
+pub trait OrderBookSnapshotPort: Send + Sync + fmt::Debug {
+    /// Returns the latest top-of-book snapshot for the instrument.
+    ///
+    /// Returns `Ok(None)` if the instrument has no order book.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError` if the snapshot cannot be fetched.
+    async fn snapshot(&self, instrument: &Instrument) -> DomainResult<Option<OrderBookSnapshot>>;
+}
+
+/// Reference price provider using the order book mid.
+///
+/// Returns the midpoint of the best bid and ask, tagged
+/// [`ReferencePriceSource::ClobMid`]. Empty, one-sided, and crossed books
+/// yield no price, as do books whose spread exceeds the maximum.
+///
+/// # Examples
+///
+/// ```ignore
+/// let provider = ClobMidReferencePriceProvider::new(snapshots)
+///     .with_max_spread_bps(Decimal::from(50));
+/// ```
+#[derive(Debug)]
+pub struct ClobMidReferencePriceProvider {
+    snapshots: Arc<dyn OrderBookSnapshotPort>,
+    max_spread_bps: Decimal,
+}
+
+impl ClobMidReferencePriceProvider {
+    /// Creates a new provider with the default maximum spread.
+    #[must_use]
+    pub fn new(snapshots: Arc<dyn OrderBookSnapshotPort>) -> Self {
+        Self {
+            snapshots,
+            max_spread_bps: DEFAULT_MAX_SPREAD_BPS,
+        }
+    }
+
+    /// Sets the widest spread, in basis points of the mid, still considered
+    /// reliable.
+    #[must_use]
+    pub fn with_max_spread_bps(mut self, max_spread_bps: Decimal) -> Self {
+        self.max_spread_bps = max_spread_bps;
+        self
+    }
+
+    /// Returns the maximum spread in basis points.
+    #[inline]
+    #[must_use]
+    pub fn max_spread_bps(&self) -> Decimal {
+        self.max_spread_bps
+    }
+
+    /// Returns the current top-of-book spread of the instrument in basis
+    /// points, regardless of the maximum.
+    ///
+    /// Returns `Ok(None)` if the instrument has no two-sided, uncrossed book.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError` if the snapshot cannot be fetched.
+    pub async fn spread_bps(&self, instrument: &Instrument) -> DomainResult<Option<Decimal>> {
+        let Some(snapshot) = self.snapshots.snapshot(instrument).await? else {
+            return Ok(None);
+        };
+        Ok(snapshot
+            .mid_and_spread_bps()?
+            .map(|(_, spread_bps)| spread_bps))
+    }
+
+    /// Returns the mid of a reliable book, or `None`.
+    fn reliable_mid(&self, snapshot: &OrderBookSnapshot) -> DomainResult<Option<Price>> {
+        let Some((mid, spread_bps)) = snapshot.mid_and_spread_bps()? else {
+            return Ok(None);
+        };
+        if spread_bps > self.max_spread_bps {
+            tracing::debug!(
+                %spread_bps,
+                max_spread_bps = %self.max_spread_bps,
+                "order book spread too wide for a reference mid"
+            );
+            return Ok(None);
+        }
+
+        Ok(Some(Price::from_decimal(mid)?))
+    }
+}
+
+#[async_trait]
+impl ReferencePriceProvider for ClobMidReferencePriceProvider {
+    async fn get_reference(
+        &self,
+        instrument: &Instrument,
+    ) -> DomainResult<Option<(Price, ReferencePriceSource)>> {
+        let Some(snapshot) = self.snapshots.snapshot(instrument).await? else {
+            return Ok(None);
+        };
+        Ok(self
+            .reliable_mid(&snapshot)?
+            .map(|mid| (mid, ReferencePriceSource::ClobMid)))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::application::services::price_bounds::FallbackReferencePriceProvider;
+    use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
+    use crate::domain::value_objects::symbol::Symbol;
+
+    #[derive(Debug)]
+    struct FixedBook(Option<OrderBookSnapshot>);
+
+    #[async_trait]
+    impl OrderBookSnapshotPort for FixedBook {
+        async fn snapshot(
+            &self,
+            _instrument: &Instrument,
+        ) -> DomainResult<Option<OrderBookSnapshot>> {
+            Ok(self.0)
+        }
+    }
+
+    #[derive(Debug)]
+    struct Theoretical;
+
+    #[async_trait]
+    impl ReferencePriceProvider for Theoretical {
+        async fn get_reference(
+            &self,
+            _instrument: &Instrument,
+        ) -> DomainResult<Option<(Price, ReferencePriceSource)>> {
+            Ok(Some((
+                Price::new(42.0).unwrap(),
+                ReferencePriceSource::Theoretical,
+            )))
+        }
+    }
+
+    fn instrument() -> Instrument {
+        Instrument::new(
+            Symbol::new("BTC/USD").unwrap(),
+            AssetClass::CryptoSpot,
+            SettlementMethod::default(),
+        )
+    }
+
+    fn book(bid: Option<f64>, ask: Option<f64>) -> ClobMidReferencePriceProvider {
+        let snapshot = OrderBookSnapshot::new(
+            bid.map(|p| Price::new(p).unwrap()),
+            ask.map(|p| Price::new(p).unwrap()),
+            Timestamp::now(),
+        );
+        ClobMidReferencePriceProvider::new(Arc::new(FixedBook(Some(snapshot))))
+    }
+
+    #[tokio::test]
+    async fn normal_book_returns_mid() {
+        let provider = book(Some(99.5), Some(100.5));
+
+        let (price, source) = provider
+            .get_reference(&instrument())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(price, Price::new(100.0).unwrap());
+        assert_eq!(source, ReferencePriceSource::ClobMid);
+    }
+
+    #[tokio::test]
+    async fn crossed_book_returns_none() {
+        let provider = book(Some(100.5), Some(99.5));
+        assert!(
+            provider
+                .get_reference(&instrument())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_or_one_sided_book_returns_none() {
+        for provider in [
+            book(None, None),
+            book(Some(100.0), None),
+            book(None, Some(100.0)),
+        ] {
+            assert!(
+                provider
+                    .get_reference(&instrument())
+                    .await
+                    .unwrap()
+                    .is_none()
+            );
+        }
+        let missing = ClobMidReferencePriceProvider::new(Arc::new(FixedBook(None)));
+        assert!(
+            missing
+                .get_reference(&instrument())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn spread_too_wide_returns_none() {
+        // 2 / 100 = 200 bps, over the 100 bps default.
+        let provider = book(Some(99.0), Some(101.0));
+        assert!(
+            provider
+                .get_reference(&instrument())
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let provider = provider.with_max_spread_bps(Decimal::from(200));
+        assert!(
+            provider
+                .get_reference(&instrument())
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn spread_is_reported_beyond_the_maximum() {
+        let provider = book(Some(99.0), Some(101.0));
+        assert_eq!(
+            provider.spread_bps(&instrument()).await.unwrap(),
+            Some(Decimal::from(200))
+        );
+        assert_eq!(
+            book(Some(100.0), None)
+                .spread_bps(&instrument())
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn unreliable_book_falls_back_to_next_provider() {
+        let chain = FallbackReferencePriceProvider::clob_mid_first(
+            Arc::new(book(Some(100.5), Some(99.5))),
+            vec![Arc::new(Theoretical)],
+        );
+
+        let (price, source) = chain.get_reference(&instrument()).await.unwrap().unwrap();
+
+        assert_eq!(price, Price::new(42.0).unwrap());
+        assert_eq!(source, ReferencePriceSource::Theoretical);
+    }
+
+    #[tokio::test]
+    async fn clob_mid_is_tried_first() {
+        let chain = FallbackReferencePriceProvider::clob_mid_first(
+            Arc::new(book(Some(99.5), Some(100.5))),
+            vec![Arc::new(Theoretical)],
+        );
+
+        let (_, source) = chain.get_reference(&instrument()).await.unwrap().unwrap();
+
+        assert_eq!(source, ReferencePriceSource::ClobMid);
+    }
+}
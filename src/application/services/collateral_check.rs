@@ -0,0 +1,121 @@
+//! # Collateral Check
+//!
+//! Margin verification before executing derivatives RFQs.
+//!
+//! [`CollateralCheckPort`] asks the margin system whether a client can
+//! carry a trade. The check never fails outright: an unreachable margin
+//! system answers [`CollateralDecision::Unavailable`], and the
+//! [`CollateralCheckMode`] decides what that means.
+//!
+//! | Decision | `Enforce` | `Monitor` |
+//! |----------|-----------|-----------|
+//! | `Approved` | execute | execute |
+//! | `Insufficient` | block | block |
+//! | `Unavailable` | block | execute |
+
+use crate::domain::value_objects::{
+    CollateralDecision, CounterpartyId, Instrument, Price, Quantity,
+};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Source of collateral decisions for clients.
+#[async_trait]
+pub trait CollateralCheckPort: Send + Sync + fmt::Debug {
+    /// Checks whether `counterparty` holds enough collateral to trade
+    /// `quantity` of `instrument` at `price`.
+    ///
+    /// Implementations answer [`CollateralDecision::Unavailable`] instead
+    /// of failing when the margin system cannot be consulted.
+    async fn check(
+        &self,
+        counterparty: &CounterpartyId,
+        instrument: &Instrument,
+        quantity: Quantity,
+        price: Price,
+    ) -> CollateralDecision;
+}
+
+/// How strictly collateral decisions are applied.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CollateralCheckMode {
+    /// Executions without a positive decision are blocked.
+    #[default]
+    Enforce,
+    /// Executions proceed when the margin system is unavailable; the
+    /// decision is still recorded.
+    Monitor,
+}
+
+impl CollateralCheckMode {
+    /// Returns true if `decision` prevents execution in this mode.
+    #[must_use]
+    pub const fn blocks(self, decision: &CollateralDecision) -> bool {
+        match decision {
+            CollateralDecision::Approved { .. } => false,
+            CollateralDecision::Insufficient { .. } => true,
+            CollateralDecision::Unavailable => matches!(self, Self::Enforce),
+        }
+    }
+}
+
+impl fmt::Display for CollateralCheckMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Enforce => write!(f, "enforce"),
+            Self::Monitor => write!(f, "monitor"),
+        }
+    }
+}
+
+impl FromStr for CollateralCheckMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "enforce" => Ok(Self::Enforce),
+            "monitor" => Ok(Self::Monitor),
+            other => Err(format!("unknown collateral check mode: {other}")),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn only_enforce_blocks_unavailable() {
+        let approved = CollateralDecision::Approved {
+            available: Decimal::ONE,
+        };
+        let insufficient = CollateralDecision::Insufficient {
+            required: Decimal::TWO,
+            available: Decimal::ONE,
+        };
+        for mode in [CollateralCheckMode::Enforce, CollateralCheckMode::Monitor] {
+            assert!(!mode.blocks(&approved));
+            assert!(mode.blocks(&insufficient));
+        }
+        assert!(CollateralCheckMode::Enforce.blocks(&CollateralDecision::Unavailable));
+        assert!(!CollateralCheckMode::Monitor.blocks(&CollateralDecision::Unavailable));
+    }
+
+    #[test]
+    fn parses_mode() {
+        assert_eq!(
+            "MONITOR".parse::<CollateralCheckMode>().unwrap(),
+            CollateralCheckMode::Monitor
+        );
+        assert_eq!(
+            "enforce".parse::<CollateralCheckMode>().unwrap(),
+            CollateralCheckMode::Enforce
+        );
+        assert!("audit".parse::<CollateralCheckMode>().is_err());
+    }
+}
@@ -5,11 +5,18 @@
 //! This module provides the [`ComplianceServiceImpl`] which orchestrates
 //! compliance checks including KYC verification, AML screening, sanctions
 //! checking, and trading limit validation.
-
-use crate::application::error::ApplicationResult;
+//!
+//! With [`ComplianceServiceImpl::with_fx_conversion`] configured, notionals
+//! are converted into the base currency before AML screening and limit
+//! checks, and the conversion applied is returned on the result. A notional
+//! that cannot be converted fails the check rather than being compared in
+//! the wrong units.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::application::services::fx_conversion::FxConversionPort;
 use crate::application::use_cases::create_rfq::ComplianceService;
 use crate::domain::entities::rfq::ComplianceResult;
-use crate::domain::value_objects::CounterpartyId;
+use crate::domain::value_objects::{CounterpartyId, Currency, FxConversion, Money};
 use async_trait::async_trait;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -305,6 +312,9 @@ pub struct ComplianceCheckResult {
     pub limits_result: LimitsResult,
     /// All compliance flags.
     pub flags: Vec<ComplianceFlag>,
+    /// Conversion of the notional into the base currency, if one was made.
+    #[serde(default)]
+    pub fx_conversion: Option<FxConversion>,
 }
 
 impl ComplianceCheckResult {
@@ -401,6 +411,7 @@ pub struct ComplianceServiceImpl {
     sanctions_provider: Arc<dyn SanctionsProvider>,
     limits_provider: Arc<dyn LimitsProvider>,
     config: ComplianceConfig,
+    fx: Option<(Arc<dyn FxConversionPort>, Currency)>,
 }
 
 impl ComplianceServiceImpl {
@@ -418,6 +429,7 @@ impl ComplianceServiceImpl {
             sanctions_provider,
             limits_provider,
             config: ComplianceConfig::default(),
+            fx: None,
         }
     }
 
@@ -436,9 +448,47 @@ impl ComplianceServiceImpl {
             sanctions_provider,
             limits_provider,
             config,
+            fx: None,
         }
     }
 
+    /// Converts notionals into `base_currency` with `fx` before checking.
+    #[must_use]
+    pub fn with_fx_conversion(
+        mut self,
+        fx: Arc<dyn FxConversionPort>,
+        base_currency: Currency,
+    ) -> Self {
+        self.fx = Some((fx, base_currency));
+        self
+    }
+
+    /// Performs a compliance check on a notional in any currency.
+    ///
+    /// The notional is converted into the base currency first when FX
+    /// conversion is configured; otherwise its amount is checked as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApplicationError::ComplianceFailed`] if the notional cannot
+    /// be converted, or an error if any provider check fails.
+    pub async fn check_notional(
+        &self,
+        client_id: &CounterpartyId,
+        notional: &Money,
+    ) -> ApplicationResult<ComplianceCheckResult> {
+        let Some((fx, base_currency)) = &self.fx else {
+            return self.check(client_id, notional.amount()).await;
+        };
+        let conversion = fx
+            .convert(notional, base_currency)
+            .await
+            .map_err(|e| ApplicationError::compliance_failed(format!("limit check failed: {e}")))?;
+        let mut result = self.check(client_id, conversion.converted.amount()).await?;
+        result.fx_conversion = Some(conversion);
+        Ok(result)
+    }
+
     /// Performs a comprehensive compliance check.
     ///
     /// # Errors
@@ -483,6 +533,7 @@ impl ComplianceServiceImpl {
             sanctions_result,
             limits_result,
             flags,
+            fx_conversion: None,
         })
     }
 
@@ -559,15 +610,19 @@ impl ComplianceService for ComplianceServiceImpl {
     async fn pre_check(
         &self,
         client_id: &CounterpartyId,
-        _base_asset: &str,
+        base_asset: &str,
         _quote_asset: &str,
         quantity: f64,
     ) -> Result<ComplianceResult, String> {
         let amount = Decimal::try_from(quantity).map_err(|e| e.to_string())?;
-        let result = self
-            .check(client_id, amount)
-            .await
-            .map_err(|e| e.to_string())?;
+        let result = if self.fx.is_some() {
+            let currency = Currency::new(base_asset).map_err(|e| e.to_string())?;
+            self.check_notional(client_id, &Money::new(amount, currency))
+                .await
+        } else {
+            self.check(client_id, amount).await
+        }
+        .map_err(|e| e.to_string())?;
         Ok(result.to_domain_result())
     }
 }
@@ -576,6 +631,9 @@ impl ComplianceService for ComplianceServiceImpl {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::application::services::fx_conversion::FxError;
+    use crate::domain::value_objects::timestamp::Timestamp;
+    use crate::domain::value_objects::{FxRate, ReferencePriceSource};
     use rust_decimal::prelude::FromPrimitive;
 
     #[derive(Debug)]
@@ -981,6 +1039,7 @@ mod tests {
                 Decimal::from_i64(25000).unwrap(),
             ),
             flags: Vec::new(),
+            fx_conversion: None,
         };
 
         let domain_result = passed_result.to_domain_result();
@@ -1000,6 +1059,7 @@ mod tests {
                 ComplianceFlagType::KycExpired,
                 "KYC expired",
             )],
+            fx_conversion: None,
         };
 
         let domain_result = failed_result.to_domain_result();
@@ -1057,4 +1117,136 @@ mod tests {
         assert_eq!(config.aml_warning_threshold, 50);
         assert_eq!(config.aml_blocking_threshold, 80);
     }
+
+    /// Limits provider enforcing a per-trade limit on the amount it is given.
+    #[derive(Debug)]
+    struct PerTradeLimitsProvider {
+        per_trade_limit: Decimal,
+    }
+
+    #[async_trait]
+    impl LimitsProvider for PerTradeLimitsProvider {
+        async fn check_limits(
+            &self,
+            _client_id: &CounterpartyId,
+            trade_amount: Decimal,
+        ) -> ApplicationResult<LimitsResult> {
+            let daily_limit = Decimal::from(1_000_000);
+            if trade_amount > self.per_trade_limit {
+                return Ok(LimitsResult::failed(
+                    Decimal::ZERO,
+                    daily_limit,
+                    self.per_trade_limit,
+                    vec!["per-trade limit exceeded".to_string()],
+                ));
+            }
+            Ok(LimitsResult::passed(
+                Decimal::ZERO,
+                daily_limit,
+                self.per_trade_limit,
+            ))
+        }
+    }
+
+    /// FX port knowing only EUR/USD.
+    #[derive(Debug)]
+    struct EurUsdFx;
+
+    #[async_trait]
+    impl FxConversionPort for EurUsdFx {
+        async fn rate(&self, from: &Currency, to: &Currency) -> Result<FxRate, FxError> {
+            if from.as_str() == "EUR" && to == &Currency::usd() {
+                return Ok(FxRate::new(
+                    from.clone(),
+                    to.clone(),
+                    Decimal::new(110, 2),
+                    ReferencePriceSource::ClobMid,
+                    Timestamp::now(),
+                )?);
+            }
+            Err(FxError::UnknownPair {
+                from: from.clone(),
+                to: to.clone(),
+            })
+        }
+    }
+
+    fn usd_limited_service() -> ComplianceServiceImpl {
+        create_service(
+            MockKycProvider::verified(),
+            MockAmlProvider::passing(20),
+            MockSanctionsProvider::passing(),
+            PerTradeLimitsProvider {
+                per_trade_limit: Decimal::from(105_000),
+            },
+        )
+        .with_fx_conversion(Arc::new(EurUsdFx), Currency::usd())
+    }
+
+    fn eur(amount: i64) -> Money {
+        Money::new(Decimal::from(amount), Currency::new("EUR").unwrap())
+    }
+
+    #[tokio::test]
+    async fn eur_notional_is_checked_against_usd_limits() {
+        let service = usd_limited_service();
+        let client_id = CounterpartyId::new("client-1");
+
+        // 90,000 EUR is 99,000 USD: within the 105,000 USD limit.
+        let within = service
+            .check_notional(&client_id, &eur(90_000))
+            .await
+            .unwrap();
+        assert!(within.passed);
+
+        // 100,000 EUR is 110,000 USD: over the limit, though 100,000 < 105,000.
+        let over = service
+            .check_notional(&client_id, &eur(100_000))
+            .await
+            .unwrap();
+        assert!(!over.passed);
+        assert_eq!(
+            over.blocking_flags().first().unwrap().flag_type,
+            ComplianceFlagType::PerTradeLimitExceeded
+        );
+    }
+
+    #[tokio::test]
+    async fn conversion_rate_is_recorded_on_the_result() {
+        let service = usd_limited_service();
+        let result = service
+            .check_notional(&CounterpartyId::new("client-1"), &eur(1_000))
+            .await
+            .unwrap();
+
+        let conversion = result.fx_conversion.unwrap();
+        assert_eq!(conversion.original, eur(1_000));
+        assert_eq!(
+            conversion.converted,
+            Money::new(Decimal::from(1_100), Currency::usd())
+        );
+        let rate = conversion.rate.unwrap();
+        assert_eq!(rate.rate, Decimal::new(110, 2));
+        assert_eq!(rate.source, ReferencePriceSource::ClobMid);
+    }
+
+    #[tokio::test]
+    async fn unknown_pair_fails_the_limit_check() {
+        let service = usd_limited_service();
+        let notional = Money::new(Decimal::ONE, Currency::new("ETH").unwrap());
+
+        let result = service
+            .check_notional(&CounterpartyId::new("client-1"), &notional)
+            .await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::ComplianceFailed(ref reason))
+                if reason.contains("no FX rate for ETH/USD")
+        ));
+
+        let pre_check = service
+            .pre_check(&CounterpartyId::new("client-1"), "ETH", "EUR", 1.0)
+            .await;
+        assert!(pre_check.is_err());
+    }
 }
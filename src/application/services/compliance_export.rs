@@ -0,0 +1,535 @@
+//! # Compliance Export Service
+//!
+//! Regulator export bundles of a counterparty's compliance records.
+//!
+//! [`ComplianceExportService`] gathers everything a regulator may ask
+//! for about one counterparty over a period `[from, to)`:
+//!
+//! - `compliance_events.json` — compliance check outcomes for the
+//!   counterparty or its RFQs
+//! - `rfq_timelines.json` — the full event history of each RFQ the
+//!   counterparty created in the period
+//! - `trades.json` — trades of those RFQs, with allocations and fees
+//! - `price_bounds.json` — the price bounds check made before each trade
+//!
+//! The files are packed into a ZIP bundle together with
+//! [`MANIFEST_FILE`], which lists the SHA-256 of every data file so the
+//! recipient can verify the bundle was not altered. Bundles are
+//! deterministic: entries are stored uncompressed with fixed timestamps
+//! and records are sorted, so the same records always produce the same
+//! bytes.
+//!
+//! Every export emits a [`ComplianceExportGenerated`] event recording who
+//! requested it. Those events are not themselves exported, so repeating
+//! a request yields the same bundle.
+
+use crate::application::error::{ApplicationError, ApplicationResult, InfrastructureError};
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::entities::trade::Trade;
+use crate::domain::events::compliance_events::ComplianceExportGenerated;
+use crate::domain::events::domain_event::EventType;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{CounterpartyId, PriceBoundsCheck, RfqId, TradeId};
+use crate::infrastructure::persistence::cursor::PageCursor;
+use crate::infrastructure::persistence::event_store::{EventStore, EventStoreError, StoredEvent};
+use crate::infrastructure::persistence::traits::{RepositoryError, RfqRepository, TradeRepository};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::io::{Cursor, Write};
+use std::sync::Arc;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Name of the manifest entry in an export bundle.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Compliance events read from the event store per page.
+const EVENT_PAGE_SIZE: usize = 500;
+
+/// One data file listed in an export manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// File name inside the bundle.
+    pub name: String,
+    /// SHA-256 of the file contents, hex encoded.
+    pub sha256: String,
+    /// File size in bytes.
+    pub size: u64,
+}
+
+/// Manifest of an export bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportManifest {
+    /// The counterparty whose records were exported.
+    pub counterparty_id: CounterpartyId,
+    /// Start of the exported period (inclusive).
+    pub from: Timestamp,
+    /// End of the exported period (exclusive).
+    pub to: Timestamp,
+    /// Data files in the bundle, in bundle order.
+    pub files: Vec<ManifestEntry>,
+}
+
+/// A generated export bundle.
+#[derive(Debug, Clone)]
+pub struct ComplianceExportBundle {
+    /// The ZIP archive.
+    pub bytes: Vec<u8>,
+    /// The manifest packed into the archive.
+    pub manifest: ExportManifest,
+    /// SHA-256 of the archive, hex encoded.
+    pub sha256: String,
+}
+
+impl ComplianceExportBundle {
+    /// Returns the file name to offer the bundle under.
+    #[must_use]
+    pub fn file_name(&self) -> String {
+        format!(
+            "compliance-{}-{}-{}.zip",
+            self.manifest.counterparty_id,
+            self.manifest.from.timestamp_millis(),
+            self.manifest.to.timestamp_millis()
+        )
+    }
+}
+
+/// The event history of one RFQ.
+#[derive(Debug, Serialize)]
+struct RfqTimeline<'a> {
+    rfq_id: RfqId,
+    events: &'a [StoredEvent],
+}
+
+/// The price bounds check made before one trade.
+#[derive(Debug, Serialize)]
+struct PriceBoundsRecord<'a> {
+    trade_id: TradeId,
+    rfq_id: RfqId,
+    check: &'a PriceBoundsCheck,
+}
+
+/// Builds regulator export bundles.
+#[derive(Debug)]
+pub struct ComplianceExportService {
+    rfqs: Arc<dyn RfqRepository>,
+    trades: Arc<dyn TradeRepository>,
+    events: Arc<dyn EventStore>,
+}
+
+impl ComplianceExportService {
+    /// Creates an export service reading from the given stores.
+    ///
+    /// Export events are appended to `events`.
+    #[must_use]
+    pub fn new(
+        rfqs: Arc<dyn RfqRepository>,
+        trades: Arc<dyn TradeRepository>,
+        events: Arc<dyn EventStore>,
+    ) -> Self {
+        Self {
+            rfqs,
+            trades,
+            events,
+        }
+    }
+
+    /// Builds the export bundle of `counterparty_id` for `[from, to)`
+    /// and records a [`ComplianceExportGenerated`] event for
+    /// `requested_by`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `from` is not before `to`, or an
+    /// infrastructure error if records cannot be read, the bundle cannot
+    /// be written, or the export event cannot be stored.
+    pub async fn export(
+        &self,
+        counterparty_id: &CounterpartyId,
+        from: Timestamp,
+        to: Timestamp,
+        requested_by: &str,
+    ) -> ApplicationResult<ComplianceExportBundle> {
+        if from >= to {
+            return Err(ApplicationError::validation(format!(
+                "from {from} must be before to {to}"
+            )));
+        }
+
+        let mut rfqs: Vec<Rfq> = self
+            .rfqs
+            .find_by_client(counterparty_id)
+            .await
+            .map_err(repository_error)?
+            .into_iter()
+            .filter(|rfq| rfq.created_at() >= from && rfq.created_at() < to)
+            .collect();
+        rfqs.sort_by_key(|rfq| (rfq.created_at(), rfq.id().to_string()));
+        let rfq_ids: HashSet<RfqId> = rfqs.iter().map(Rfq::id).collect();
+
+        let mut timelines = Vec::with_capacity(rfqs.len());
+        let mut trades = Vec::new();
+        for rfq in &rfqs {
+            let events = self
+                .events
+                .get_events(rfq.id())
+                .await
+                .map_err(event_store_error)?;
+            timelines.push((rfq.id(), events));
+            if let Some(trade) = self
+                .trades
+                .get_by_rfq(rfq.id())
+                .await
+                .map_err(repository_error)?
+            {
+                trades.push(trade);
+            }
+        }
+        trades.sort_by_key(|trade| (trade.created_at(), trade.id().to_string()));
+
+        let compliance_events = self
+            .compliance_events(counterparty_id, &rfq_ids, from, to)
+            .await?;
+
+        let timelines: Vec<RfqTimeline<'_>> = timelines
+            .iter()
+            .map(|(rfq_id, events)| RfqTimeline {
+                rfq_id: *rfq_id,
+                events,
+            })
+            .collect();
+        let price_bounds: Vec<PriceBoundsRecord<'_>> = trades
+            .iter()
+            .filter_map(|trade: &Trade| {
+                trade.price_bounds_check().map(|check| PriceBoundsRecord {
+                    trade_id: trade.id(),
+                    rfq_id: trade.rfq_id(),
+                    check,
+                })
+            })
+            .collect();
+
+        let files = vec![
+            ("compliance_events.json", to_json(&compliance_events)?),
+            ("rfq_timelines.json", to_json(&timelines)?),
+            ("trades.json", to_json(&trades)?),
+            ("price_bounds.json", to_json(&price_bounds)?),
+        ];
+        let bundle = write_bundle(counterparty_id, from, to, files)?;
+
+        let event = ComplianceExportGenerated::new(
+            counterparty_id.clone(),
+            requested_by,
+            from,
+            to,
+            bundle.manifest.files.len(),
+            bundle.sha256.clone(),
+        );
+        let stored = StoredEvent::from_event(&event, 1).map_err(event_store_error)?;
+        self.events
+            .append(stored)
+            .await
+            .map_err(event_store_error)?;
+
+        Ok(bundle)
+    }
+
+    /// Reads the compliance events of the counterparty or its RFQs in
+    /// `[from, to)`, oldest first, leaving out earlier exports.
+    async fn compliance_events(
+        &self,
+        counterparty_id: &CounterpartyId,
+        rfq_ids: &HashSet<RfqId>,
+        from: Timestamp,
+        to: Timestamp,
+    ) -> ApplicationResult<Vec<StoredEvent>> {
+        let mut matched = Vec::new();
+        let mut cursor: Option<PageCursor> = None;
+        loop {
+            let page = self
+                .events
+                .query_by_time_range(
+                    from,
+                    to,
+                    Some(vec![EventType::Compliance]),
+                    EVENT_PAGE_SIZE,
+                    cursor.as_ref(),
+                )
+                .await
+                .map_err(event_store_error)?;
+            let Some(last) = page.last() else {
+                break;
+            };
+            cursor = Some(PageCursor::from_event(last));
+            let full = page.len() == EVENT_PAGE_SIZE;
+            matched.extend(page.into_iter().filter(|event| {
+                event.event_name != "ComplianceExportGenerated"
+                    && (event.rfq_id.is_some_and(|id| rfq_ids.contains(&id))
+                        || event
+                            .payload
+                            .get("counterparty_id")
+                            .and_then(serde_json::Value::as_str)
+                            == Some(counterparty_id.as_str()))
+            }));
+            if !full {
+                break;
+            }
+        }
+        Ok(matched)
+    }
+}
+
+/// Packs the data files and their manifest into a deterministic ZIP
+/// archive.
+fn write_bundle(
+    counterparty_id: &CounterpartyId,
+    from: Timestamp,
+    to: Timestamp,
+    files: Vec<(&str, Vec<u8>)>,
+) -> ApplicationResult<ComplianceExportBundle> {
+    let options = FileOptions::default()
+        .compression_method(CompressionMethod::Stored)
+        .unix_permissions(0o644);
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let mut entries = Vec::with_capacity(files.len());
+    for (name, contents) in files {
+        write_entry(&mut zip, name, &contents, options)?;
+        entries.push(ManifestEntry {
+            name: name.to_string(),
+            sha256: sha256_hex(&contents),
+            size: contents.len() as u64,
+        });
+    }
+
+    let manifest = ExportManifest {
+        counterparty_id: counterparty_id.clone(),
+        from,
+        to,
+        files: entries,
+    };
+    write_entry(&mut zip, MANIFEST_FILE, &to_json(&manifest)?, options)?;
+
+    let bytes = zip.finish().map_err(bundle_error)?.into_inner();
+    Ok(ComplianceExportBundle {
+        sha256: sha256_hex(&bytes),
+        bytes,
+        manifest,
+    })
+}
+
+fn write_entry(
+    zip: &mut ZipWriter<Cursor<Vec<u8>>>,
+    name: &str,
+    contents: &[u8],
+    options: FileOptions,
+) -> ApplicationResult<()> {
+    zip.start_file(name, options).map_err(bundle_error)?;
+    zip.write_all(contents)
+        .map_err(|e| bundle_error(zip::result::ZipError::Io(e)))
+}
+
+fn to_json<T: Serialize + ?Sized>(value: &T) -> ApplicationResult<Vec<u8>> {
+    serde_json::to_vec_pretty(value)
+        .map_err(|e| InfrastructureError::serialization(e.to_string()).into())
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+fn bundle_error(error: zip::result::ZipError) -> ApplicationError {
+    InfrastructureError::serialization(format!("export bundle: {error}")).into()
+}
+
+fn event_store_error(error: EventStoreError) -> ApplicationError {
+    InfrastructureError::database(error.to_string()).into()
+}
+
+fn repository_error(error: RepositoryError) -> ApplicationError {
+    InfrastructureError::Repository(error).into()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::events::compliance_events::{ComplianceCheckFailed, ComplianceCheckPassed};
+    use crate::domain::value_objects::{
+        AssetClass, Instrument, OrderSide, Price, Quantity, QuoteId, SettlementMethod, Symbol,
+        VenueId,
+    };
+    use crate::infrastructure::persistence::in_memory::{
+        InMemoryEventStore, InMemoryRfqRepository, InMemoryTradeRepository,
+    };
+    use std::io::Read;
+
+    struct Fixture {
+        service: ComplianceExportService,
+        rfqs: Arc<InMemoryRfqRepository>,
+        trades: Arc<InMemoryTradeRepository>,
+        events: Arc<InMemoryEventStore>,
+    }
+
+    fn fixture() -> Fixture {
+        let rfqs = Arc::new(InMemoryRfqRepository::new());
+        let trades = Arc::new(InMemoryTradeRepository::new());
+        let events = Arc::new(InMemoryEventStore::new());
+        Fixture {
+            service: ComplianceExportService::new(
+                Arc::clone(&rfqs) as Arc<dyn RfqRepository>,
+                Arc::clone(&trades) as Arc<dyn TradeRepository>,
+                Arc::clone(&events) as Arc<dyn EventStore>,
+            ),
+            rfqs,
+            trades,
+            events,
+        }
+    }
+
+    fn new_rfq(client: &CounterpartyId) -> Rfq {
+        let instrument = Instrument::new(
+            Symbol::new("BTC/USD").unwrap(),
+            AssetClass::CryptoSpot,
+            SettlementMethod::OffChain,
+        );
+        RfqBuilder::new(
+            client.clone(),
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build()
+    }
+
+    fn read_entries(bytes: &[u8]) -> Vec<(String, Vec<u8>)> {
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+        (0..archive.len())
+            .map(|i| {
+                let mut file = archive.by_index(i).unwrap();
+                let mut contents = Vec::new();
+                file.read_to_end(&mut contents).unwrap();
+                (file.name().to_string(), contents)
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn manifest_hashes_match_bundle_files() {
+        let f = fixture();
+        let client = CounterpartyId::new("client-1");
+        let rfq = new_rfq(&client);
+        f.rfqs.save(&rfq).await.unwrap();
+        let mut trade = Trade::new(
+            rfq.id(),
+            QuoteId::new_v4(),
+            VenueId::new("venue-1"),
+            Price::new(50_000.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+        );
+        trade.set_price_bounds_check(PriceBoundsCheck::overridden("risk-1", "stale reference"));
+        f.trades.save(&trade).await.unwrap();
+        for event in [
+            StoredEvent::from_event(&ComplianceCheckPassed::kyc(rfq.id(), client.clone()), 1),
+            StoredEvent::from_event(
+                &ComplianceCheckFailed::aml(RfqId::new_v4(), CounterpartyId::new("other"), "hit"),
+                1,
+            ),
+        ] {
+            f.events.append(event.unwrap()).await.unwrap();
+        }
+
+        let from = Timestamp::now().add_secs(-60);
+        let to = Timestamp::now().add_secs(60);
+        let bundle = f
+            .service
+            .export(&client, from, to, "auditor-1")
+            .await
+            .unwrap();
+
+        let entries = read_entries(&bundle.bytes);
+        let names: Vec<&str> = entries.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "compliance_events.json",
+                "rfq_timelines.json",
+                "trades.json",
+                "price_bounds.json",
+                MANIFEST_FILE,
+            ]
+        );
+        let (_, manifest) = entries.last().unwrap();
+        let manifest: ExportManifest = serde_json::from_slice(manifest).unwrap();
+        assert_eq!(manifest, bundle.manifest);
+        for (entry, (name, contents)) in manifest.files.iter().zip(&entries) {
+            assert_eq!(&entry.name, name);
+            assert_eq!(entry.sha256, sha256_hex(contents));
+            assert_eq!(entry.size, contents.len() as u64);
+        }
+        assert_eq!(bundle.sha256, sha256_hex(&bundle.bytes));
+
+        let (_, compliance) = entries.first().unwrap();
+        let compliance: Vec<StoredEvent> = serde_json::from_slice(compliance).unwrap();
+        assert_eq!(compliance.len(), 1);
+        let (_, price_bounds) = entries.get(3).unwrap();
+        assert!(String::from_utf8_lossy(price_bounds).contains("stale reference"));
+
+        let again = f
+            .service
+            .export(&client, from, to, "auditor-2")
+            .await
+            .unwrap();
+        assert_eq!(again.bytes, bundle.bytes);
+    }
+
+    #[tokio::test]
+    async fn empty_range_still_has_valid_manifest() {
+        let f = fixture();
+        let client = CounterpartyId::new("client-1");
+        f.rfqs.save(&new_rfq(&client)).await.unwrap();
+        let from = Timestamp::from_millis(1_600_000_000_000).unwrap();
+        let to = from.add_secs(86_400);
+
+        let bundle = f
+            .service
+            .export(&client, from, to, "auditor-1")
+            .await
+            .unwrap();
+
+        let entries = read_entries(&bundle.bytes);
+        assert_eq!(bundle.manifest.files.len(), 4);
+        for (entry, (_, contents)) in bundle.manifest.files.iter().zip(&entries) {
+            assert_eq!(contents.as_slice(), b"[]");
+            assert_eq!(entry.sha256, sha256_hex(b"[]"));
+        }
+        let (name, manifest) = entries.last().unwrap();
+        assert_eq!(name, MANIFEST_FILE);
+        let manifest: ExportManifest = serde_json::from_slice(manifest).unwrap();
+        assert_eq!(manifest.counterparty_id, client);
+
+        let exports = f
+            .events
+            .get_events_by_type(EventType::Compliance)
+            .await
+            .unwrap();
+        assert_eq!(exports.len(), 1);
+        let export = exports.first().unwrap();
+        assert_eq!(export.event_name, "ComplianceExportGenerated");
+        assert_eq!(export.payload["requested_by"], "auditor-1");
+        assert_eq!(export.payload["bundle_sha256"], bundle.sha256.as_str());
+    }
+
+    #[tokio::test]
+    async fn rejects_inverted_range() {
+        let f = fixture();
+        let now = Timestamp::now();
+        let result = f
+            .service
+            .export(&CounterpartyId::new("client-1"), now, now, "auditor-1")
+            .await;
+        assert!(matches!(result, Err(ApplicationError::Validation(_))));
+    }
+}
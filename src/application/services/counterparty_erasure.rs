@@ -0,0 +1,449 @@
+//! # Counterparty Erasure
+//!
+//! Reviewed erasure of a departed counterparty's personal identifiers.
+//!
+//! An erasure goes through an [`ErasureRequest`]: one operator requests
+//! it, a second approves it, and only then can it be executed. Executing
+//! an erasure:
+//!
+//! 1. Re-keys the counterparty record to a random pseudonym and strips its
+//!    name, wallets, settlement addresses and contact details
+//!    ([`Counterparty::pseudonymized`]).
+//! 2. Points the counterparty's RFQs (and their dashboard summaries) at the
+//!    pseudonym, and drops the identity mappings of its anonymous RFQs.
+//! 3. Shreds the counterparty's event encryption key, so its identifiers in
+//!    events stored through
+//!    [`PiiEncryptingEventStore`](super::PiiEncryptingEventStore) read as
+//!    the pseudonym from then on.
+//!
+//! Trades reference RFQs, quotes and venues but not the client, and are
+//! kept untouched along with every price, quantity and fee: the economic
+//! record stays complete for regulatory retention, it just no longer says
+//! who the client was.
+//!
+//! Execution is not atomic. A failed execution can be retried while the
+//! original counterparty record exists; the pseudonym is random, so a
+//! retry may leave an orphaned pseudonymous record behind.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::domain::entities::counterparty::Counterparty;
+use crate::domain::entities::erasure_request::ErasureRequest;
+use crate::domain::value_objects::{CounterpartyId, ErasureRequestId};
+use crate::infrastructure::persistence::counterparty_keys::CounterpartyKeyStore;
+use crate::infrastructure::persistence::rfq_summary::RfqSummaryStore;
+use crate::infrastructure::persistence::traits::{
+    CounterpartyRepository, ErasureRequestRepository, IdentityMappingRepository, RepositoryError,
+    RfqRepository,
+};
+use std::sync::Arc;
+
+/// Prefix of the pseudonyms erased counterparties are re-keyed to.
+pub const PSEUDONYM_PREFIX: &str = "erased-";
+
+/// Requests, approves and executes counterparty erasures.
+#[derive(Debug)]
+pub struct CounterpartyErasureService {
+    counterparties: Arc<dyn CounterpartyRepository>,
+    rfqs: Arc<dyn RfqRepository>,
+    requests: Arc<dyn ErasureRequestRepository>,
+    keys: Arc<dyn CounterpartyKeyStore>,
+    summaries: Option<Arc<dyn RfqSummaryStore>>,
+    identity_mappings: Option<Arc<dyn IdentityMappingRepository>>,
+}
+
+impl CounterpartyErasureService {
+    /// Creates a service erasing from the given stores.
+    #[must_use]
+    pub fn new(
+        counterparties: Arc<dyn CounterpartyRepository>,
+        rfqs: Arc<dyn RfqRepository>,
+        requests: Arc<dyn ErasureRequestRepository>,
+        keys: Arc<dyn CounterpartyKeyStore>,
+    ) -> Self {
+        Self {
+            counterparties,
+            rfqs,
+            requests,
+            keys,
+            summaries: None,
+            identity_mappings: None,
+        }
+    }
+
+    /// Also re-keys the RFQ summaries of erased counterparties.
+    #[must_use]
+    pub fn with_rfq_summaries(mut self, summaries: Arc<dyn RfqSummaryStore>) -> Self {
+        self.summaries = Some(summaries);
+        self
+    }
+
+    /// Also drops the identity mappings of erased counterparties' anonymous
+    /// RFQs.
+    #[must_use]
+    pub fn with_identity_mappings(
+        mut self,
+        identity_mappings: Arc<dyn IdentityMappingRepository>,
+    ) -> Self {
+        self.identity_mappings = Some(identity_mappings);
+        self
+    }
+
+    /// Requests the erasure of a counterparty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::NotFound` if the counterparty does not
+    /// exist, and `ApplicationError::InvalidState` if an erasure of it is
+    /// already pending.
+    pub async fn request(
+        &self,
+        counterparty_id: &CounterpartyId,
+        requested_by: &str,
+        reason: &str,
+    ) -> ApplicationResult<ErasureRequest> {
+        if reason.trim().is_empty() {
+            return Err(ApplicationError::validation("an erasure needs a reason"));
+        }
+        self.load_counterparty(counterparty_id).await?;
+        let pending = self
+            .requests
+            .find_by_counterparty(counterparty_id)
+            .await
+            .map_err(repository_error)?
+            .into_iter()
+            .find(ErasureRequest::is_pending);
+        if let Some(pending) = pending {
+            return Err(ApplicationError::InvalidState(format!(
+                "erasure of {} is already pending as {}",
+                counterparty_id,
+                pending.id()
+            )));
+        }
+
+        let request = ErasureRequest::new(counterparty_id.clone(), requested_by, reason);
+        self.save_request(&request).await?;
+        tracing::info!(
+            request_id = %request.id(),
+            requested_by,
+            "Counterparty erasure requested"
+        );
+        Ok(request)
+    }
+
+    /// Approves a requested erasure.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::NotFound` if the request does not exist,
+    /// and a domain error if it is not waiting for review or `approver`
+    /// requested it.
+    pub async fn approve(
+        &self,
+        request_id: ErasureRequestId,
+        approver: &str,
+    ) -> ApplicationResult<ErasureRequest> {
+        let mut request = self.get(request_id).await?;
+        request.approve(approver)?;
+        self.save_request(&request).await?;
+        tracing::info!(%request_id, approver, "Counterparty erasure approved");
+        Ok(request)
+    }
+
+    /// Executes an approved erasure.
+    ///
+    /// Returns the executed request, which names the counterparty by its
+    /// pseudonym.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::NotFound` if the request or its
+    /// counterparty does not exist, a domain error if the request is not
+    /// approved, and `ApplicationError::RepositoryError` if a store fails
+    /// part way.
+    pub async fn execute(&self, request_id: ErasureRequestId) -> ApplicationResult<ErasureRequest> {
+        let mut request = self.get(request_id).await?;
+        let pseudonym = new_pseudonym();
+        // Validate the transition before touching anything
+        request.clone().execute(pseudonym.clone())?;
+
+        let counterparty_id = request.counterparty_id().clone();
+        let counterparty = self.load_counterparty(&counterparty_id).await?;
+        self.counterparties
+            .save(&counterparty.pseudonymized(pseudonym.clone()))
+            .await
+            .map_err(repository_error)?;
+
+        let rfqs = self
+            .rfqs
+            .find_by_client(&counterparty_id)
+            .await
+            .map_err(repository_error)?;
+        for mut rfq in rfqs {
+            rfq.pseudonymize_client(pseudonym.clone());
+            self.rfqs.save(&rfq).await.map_err(repository_error)?;
+
+            if let Some(summaries) = &self.summaries
+                && let Some(mut summary) =
+                    summaries.get(rfq.id()).await.map_err(repository_error)?
+            {
+                summary.client_id = pseudonym.clone();
+                summaries.upsert(&summary).await.map_err(repository_error)?;
+            }
+            if let Some(identity_mappings) = &self.identity_mappings {
+                identity_mappings
+                    .delete(rfq.id())
+                    .await
+                    .map_err(repository_error)?;
+            }
+        }
+
+        self.keys
+            .shred(&counterparty_id, &pseudonym)
+            .await
+            .map_err(repository_error)?;
+        self.counterparties
+            .delete(&counterparty_id)
+            .await
+            .map_err(repository_error)?;
+
+        request.execute(pseudonym)?;
+        self.save_request(&request).await?;
+        tracing::info!(
+            %request_id,
+            pseudonym = %request.counterparty_id(),
+            "Counterparty erasure executed"
+        );
+        Ok(request)
+    }
+
+    /// Returns an erasure request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::NotFound` if the request does not exist.
+    pub async fn get(&self, request_id: ErasureRequestId) -> ApplicationResult<ErasureRequest> {
+        self.requests
+            .get(request_id)
+            .await
+            .map_err(repository_error)?
+            .ok_or_else(|| ApplicationError::not_found("Erasure request", request_id.to_string()))
+    }
+
+    async fn load_counterparty(&self, id: &CounterpartyId) -> ApplicationResult<Counterparty> {
+        self.counterparties
+            .get(id)
+            .await
+            .map_err(repository_error)?
+            .ok_or_else(|| ApplicationError::not_found("Counterparty", id.as_str()))
+    }
+
+    async fn save_request(&self, request: &ErasureRequest) -> ApplicationResult<()> {
+        self.requests.save(request).await.map_err(repository_error)
+    }
+}
+
+/// Returns a random pseudonym, unrelated to any erased identifier.
+fn new_pseudonym() -> CounterpartyId {
+    let suffix: [u8; 8] = rand::random();
+    CounterpartyId::new(format!("{PSEUDONYM_PREFIX}{}", hex::encode(suffix)))
+}
+
+fn repository_error(error: RepositoryError) -> ApplicationError {
+    ApplicationError::RepositoryError(error.to_string())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::application::services::PiiEncryptingEventStore;
+    use crate::domain::entities::erasure_request::ErasureRequestStatus;
+    use crate::domain::entities::trade::Trade;
+    use crate::domain::events::rfq_events::RfqCreated;
+    use crate::domain::value_objects::{Price, QuoteId, VenueId};
+    use crate::infrastructure::persistence::event_store::{EventStore, StoredEvent};
+    use crate::infrastructure::persistence::in_memory::{
+        InMemoryCounterpartyKeyStore, InMemoryErasureRequestRepository, InMemoryEventStore,
+    };
+    use crate::infrastructure::persistence::traits::TradeRepository;
+    use crate::test_support::{FixtureBuilder, Fixtures, RfqSeed};
+
+    struct Harness {
+        fixtures: Fixtures,
+        events: PiiEncryptingEventStore,
+        service: CounterpartyErasureService,
+    }
+
+    async fn harness() -> Harness {
+        let fixtures = FixtureBuilder::new()
+            .with_rfq(RfqSeed::new("erased", "acme-trading", "BTC/USD").with_quantity(2.5))
+            .with_rfq(RfqSeed::new("kept", "globex", "BTC/USD"))
+            .build()
+            .await
+            .unwrap();
+        let keys = Arc::new(InMemoryCounterpartyKeyStore::new());
+        let events =
+            PiiEncryptingEventStore::new(Arc::new(InMemoryEventStore::new()), keys.clone());
+        for label in ["erased", "kept"] {
+            let rfq = fixtures.rfq(label).unwrap();
+            let created = RfqCreated::new(
+                rfq.id(),
+                rfq.client_id().clone(),
+                rfq.instrument().clone(),
+                rfq.side(),
+                rfq.quantity(),
+                rfq.expires_at(),
+            );
+            events
+                .append(StoredEvent::from_event(&created, 1).unwrap())
+                .await
+                .unwrap();
+        }
+        let service = CounterpartyErasureService::new(
+            fixtures.counterparties.clone(),
+            fixtures.rfqs.clone(),
+            Arc::new(InMemoryErasureRequestRepository::new()),
+            keys,
+        );
+        Harness {
+            fixtures,
+            events,
+            service,
+        }
+    }
+
+    async fn erase(harness: &Harness) -> ErasureRequest {
+        let request = harness
+            .service
+            .request(
+                &CounterpartyId::new("acme-trading"),
+                "legal-1",
+                "offboarded",
+            )
+            .await
+            .unwrap();
+        harness
+            .service
+            .approve(request.id(), "legal-2")
+            .await
+            .unwrap();
+        harness.service.execute(request.id()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn erasure_rekeys_the_counterparty_and_its_rfqs() {
+        let harness = harness().await;
+
+        let request = erase(&harness).await;
+
+        assert_eq!(request.status(), ErasureRequestStatus::Executed);
+        let pseudonym = request.counterparty_id().clone();
+        assert!(pseudonym.as_str().starts_with(PSEUDONYM_PREFIX));
+
+        let counterparties = &harness.fixtures.counterparties;
+        assert!(
+            counterparties
+                .get(&CounterpartyId::new("acme-trading"))
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let erased = counterparties.get(&pseudonym).await.unwrap().unwrap();
+        assert_eq!(erased.name(), pseudonym.as_str());
+        assert!(!erased.is_active());
+
+        let rfq = harness
+            .fixtures
+            .stored_rfq("erased")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(rfq.client_id(), &pseudonym);
+        assert_eq!(
+            rfq.quantity(),
+            harness.fixtures.rfq("erased").unwrap().quantity()
+        );
+        let kept = harness.fixtures.stored_rfq("kept").await.unwrap().unwrap();
+        assert_eq!(kept.client_id().as_str(), "globex");
+    }
+
+    #[tokio::test]
+    async fn events_stored_before_the_erasure_read_as_the_pseudonym() {
+        let harness = harness().await;
+        let erased_id = harness.fixtures.rfq("erased").unwrap().id();
+        let kept_id = harness.fixtures.rfq("kept").unwrap().id();
+
+        let request = erase(&harness).await;
+
+        let events = harness.events.get_events(erased_id).await.unwrap();
+        assert_eq!(
+            events[0].payload["client_id"],
+            request.counterparty_id().as_str()
+        );
+        assert_eq!(events[0].payload["quantity"], "2.5");
+        let events = harness.events.get_events(kept_id).await.unwrap();
+        assert_eq!(events[0].payload["client_id"], "globex");
+    }
+
+    #[tokio::test]
+    async fn trades_are_kept_intact() {
+        let harness = harness().await;
+        let rfq = harness.fixtures.rfq("erased").unwrap();
+        let trade = Trade::new(
+            rfq.id(),
+            QuoteId::new_v4(),
+            VenueId::new("venue-1"),
+            Price::new(50_000.0).unwrap(),
+            rfq.quantity(),
+        );
+        harness.fixtures.trades.save(&trade).await.unwrap();
+
+        erase(&harness).await;
+
+        assert_eq!(
+            harness.fixtures.trades.get(trade.id()).await.unwrap(),
+            Some(trade)
+        );
+    }
+
+    #[tokio::test]
+    async fn erasure_needs_a_second_operator_and_one_pending_request() {
+        let harness = harness().await;
+        let acme = CounterpartyId::new("acme-trading");
+        let request = harness
+            .service
+            .request(&acme, "legal-1", "offboarded")
+            .await
+            .unwrap();
+
+        assert!(harness.service.execute(request.id()).await.is_err());
+        assert!(
+            harness
+                .service
+                .approve(request.id(), "legal-1")
+                .await
+                .is_err()
+        );
+        assert!(matches!(
+            harness.service.request(&acme, "legal-3", "again").await,
+            Err(ApplicationError::InvalidState(_))
+        ));
+        assert!(matches!(
+            harness
+                .service
+                .request(&CounterpartyId::new("nobody"), "legal-1", "offboarded")
+                .await,
+            Err(ApplicationError::NotFound { .. })
+        ));
+        assert_eq!(
+            harness
+                .fixtures
+                .stored_rfq("erased")
+                .await
+                .unwrap()
+                .unwrap()
+                .client_id(),
+            &acme
+        );
+    }
+}
@@ -0,0 +1,411 @@
+//! # Dead Letter Queue
+//!
+//! Parks events that an event consumer keeps failing on, and re-drives
+//! them on request.
+//!
+//! An [`EventConsumer`] is anything that reacts to stored domain events:
+//! the [`RfqSummaryProjection`], the [`WebhookDeliveryService`], ...
+//! [`DeadLetterQueue::process`] hands an event to a consumer, retrying a
+//! failure up to the configured number of attempts. If every attempt
+//! fails, the event is parked as a [`DeadLetter`] in the
+//! [`DeadLetterStore`] and the consumer moves on to later events, so one
+//! poison event neither stalls the consumer nor disappears silently.
+//!
+//! [`DeadLetterQueue::redrive`] hands a parked event to its consumer
+//! again. A handled event is marked resolved and later re-drives of it do
+//! nothing; a failure leaves it parked with the new error. Consumers must
+//! tolerate seeing an event again: a re-drive may follow a partly handled
+//! attempt.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use otc_rfq::application::services::dead_letter_queue::DeadLetterQueue;
+//!
+//! let queue = Arc::new(
+//!     DeadLetterQueue::new(dead_letter_store)
+//!         .with_consumer(projection)
+//!         .with_consumer(webhooks),
+//! );
+//! tokio::spawn(Arc::clone(&queue).run(WebhookDeliveryService::CONSUMER, events));
+//! ```
+//!
+//! [`RfqSummaryProjection`]: crate::application::services::rfq_summary_projection::RfqSummaryProjection
+//! [`WebhookDeliveryService`]: crate::application::services::webhook_delivery::WebhookDeliveryService
+
+use crate::application::error::{ApplicationError, ApplicationResult, InfrastructureError};
+use crate::domain::value_objects::EventId;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::infrastructure::persistence::dead_letter::{
+    DeadLetter, DeadLetterStatus, DeadLetterStore,
+};
+use crate::infrastructure::persistence::event_store::StoredEvent;
+use crate::infrastructure::persistence::traits::RepositoryError;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+/// Attempts made at an event before it is parked, by default.
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// Pause between attempts at an event, by default.
+pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// A named consumer of stored domain events.
+///
+/// Handling must be idempotent: an event may be handed over again by a
+/// retry or a re-drive after a failed or partly handled attempt.
+#[async_trait]
+pub trait EventConsumer: Send + Sync + fmt::Debug {
+    /// Returns the consumer's name, under which its dead letters are kept.
+    fn name(&self) -> &str;
+
+    /// Handles one event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event could not be handled.
+    async fn handle(&self, event: &StoredEvent) -> ApplicationResult<()>;
+}
+
+/// Outcome of handing an event to a consumer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConsumeOutcome {
+    /// The consumer handled the event.
+    Handled,
+    /// Every attempt failed and the event was parked.
+    Parked(Box<DeadLetter>),
+}
+
+/// Retries, parks and re-drives events for registered consumers.
+#[derive(Debug)]
+pub struct DeadLetterQueue {
+    store: Arc<dyn DeadLetterStore>,
+    consumers: HashMap<String, Arc<dyn EventConsumer>>,
+    max_attempts: u32,
+    retry_delay: Duration,
+}
+
+impl DeadLetterQueue {
+    /// Creates a queue parking events in `store`, with no consumers.
+    #[must_use]
+    pub fn new(store: Arc<dyn DeadLetterStore>) -> Self {
+        Self {
+            store,
+            consumers: HashMap::new(),
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            retry_delay: DEFAULT_RETRY_DELAY,
+        }
+    }
+
+    /// Registers `consumer` under its name.
+    #[must_use]
+    pub fn with_consumer(mut self, consumer: Arc<dyn EventConsumer>) -> Self {
+        self.consumers.insert(consumer.name().to_string(), consumer);
+        self
+    }
+
+    /// Sets the attempts made at an event before it is parked (at least
+    /// one).
+    #[must_use]
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the pause between attempts at an event.
+    #[must_use]
+    pub fn with_retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Returns the store events are parked in.
+    #[must_use]
+    pub fn store(&self) -> &Arc<dyn DeadLetterStore> {
+        &self.store
+    }
+
+    /// Hands `event` to `consumer`, parking it if every attempt fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotFound` if no consumer of that name is registered, or an
+    /// error if a failed event cannot be parked.
+    pub async fn process(
+        &self,
+        consumer: &str,
+        event: &StoredEvent,
+    ) -> ApplicationResult<ConsumeOutcome> {
+        let handler = self.consumer(consumer)?;
+        let first_seen_at = Timestamp::now();
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let error = match handler.handle(event).await {
+                Ok(()) => return Ok(ConsumeOutcome::Handled),
+                Err(e) => e,
+            };
+            if attempts < self.max_attempts {
+                tracing::debug!(
+                    consumer,
+                    event_id = %event.event_id,
+                    attempts,
+                    error = %error,
+                    "Event handler failed; retrying"
+                );
+                tokio::time::sleep(self.retry_delay).await;
+                continue;
+            }
+
+            let letter = DeadLetter::parked(
+                consumer,
+                event.clone(),
+                error.to_string(),
+                attempts,
+                first_seen_at,
+            );
+            self.store.save(&letter).await.map_err(repository_error)?;
+            tracing::warn!(
+                consumer,
+                event_id = %event.event_id,
+                event_name = %event.event_name,
+                attempts,
+                error = %error,
+                "Event parked in the dead letter queue"
+            );
+            return Ok(ConsumeOutcome::Parked(Box::new(letter)));
+        }
+    }
+
+    /// Hands a parked event to its consumer again.
+    ///
+    /// Returns the dead letter after the attempt: resolved if the event
+    /// was handled, still parked with the new error otherwise. An event
+    /// already resolved is returned as is, without handling it again.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotFound` if the event was never parked for `consumer` or
+    /// the consumer is not registered, or an error if the dead letter
+    /// cannot be loaded or updated.
+    pub async fn redrive(
+        &self,
+        consumer: &str,
+        event_id: EventId,
+    ) -> ApplicationResult<DeadLetter> {
+        let letter = self
+            .store
+            .find(consumer, event_id)
+            .await
+            .map_err(repository_error)?
+            .ok_or_else(|| {
+                ApplicationError::not_found("DeadLetter", format!("{consumer}/{event_id}"))
+            })?;
+        self.redrive_letter(letter).await
+    }
+
+    /// Re-drives up to `limit` parked events, of `consumer` only if given,
+    /// oldest first.
+    ///
+    /// Returns the dead letters after their attempts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotFound` if a parked event's consumer is not registered,
+    /// or an error if the dead letters cannot be loaded or updated.
+    pub async fn redrive_all(
+        &self,
+        consumer: Option<&str>,
+        limit: usize,
+    ) -> ApplicationResult<Vec<DeadLetter>> {
+        let parked = self.list_parked(consumer, limit).await?;
+        let mut redriven = Vec::with_capacity(parked.len());
+        for letter in parked {
+            redriven.push(self.redrive_letter(letter).await?);
+        }
+        Ok(redriven)
+    }
+
+    /// Returns up to `limit` parked events, of `consumer` only if given,
+    /// oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dead letters cannot be loaded.
+    pub async fn list_parked(
+        &self,
+        consumer: Option<&str>,
+        limit: usize,
+    ) -> ApplicationResult<Vec<DeadLetter>> {
+        self.store
+            .list_parked(consumer, limit)
+            .await
+            .map_err(repository_error)
+    }
+
+    /// Consumes the event bus on behalf of `consumer` until it closes,
+    /// processing each event in its own task so a slow event does not
+    /// hold up later ones.
+    pub async fn run(
+        self: Arc<Self>,
+        consumer: impl Into<String>,
+        mut events: broadcast::Receiver<StoredEvent>,
+    ) {
+        let consumer: Arc<str> = Arc::from(consumer.into());
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    let queue = Arc::clone(&self);
+                    let consumer = Arc::clone(&consumer);
+                    tokio::spawn(async move {
+                        if let Err(e) = queue.process(&consumer, &event).await {
+                            tracing::error!(
+                                consumer = %consumer,
+                                event_id = %event.event_id,
+                                error = %e,
+                                "Failed to process or park event"
+                            );
+                        }
+                    });
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!(consumer = %consumer, skipped, "Event consumer fell behind the event bus");
+                }
+                Err(broadcast::error::RecvError::Closed) => return,
+            }
+        }
+    }
+
+    async fn redrive_letter(&self, mut letter: DeadLetter) -> ApplicationResult<DeadLetter> {
+        if !letter.is_parked() {
+            return Ok(letter);
+        }
+        let handler = self.consumer(&letter.consumer)?;
+
+        letter.attempts = letter.attempts.saturating_add(1);
+        match handler.handle(&letter.event).await {
+            Ok(()) => {
+                letter.status = DeadLetterStatus::Resolved;
+                tracing::info!(
+                    consumer = %letter.consumer,
+                    event_id = %letter.event_id(),
+                    "Parked event re-driven"
+                );
+            }
+            Err(e) => letter.last_error = e.to_string(),
+        }
+        letter.last_seen_at = Timestamp::now();
+        self.store.save(&letter).await.map_err(repository_error)?;
+        Ok(letter)
+    }
+
+    fn consumer(&self, name: &str) -> ApplicationResult<&Arc<dyn EventConsumer>> {
+        self.consumers
+            .get(name)
+            .ok_or_else(|| ApplicationError::not_found("EventConsumer", name))
+    }
+}
+
+fn repository_error(error: RepositoryError) -> ApplicationError {
+    InfrastructureError::Repository(error).into()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::domain::events::domain_event::EventType;
+    use crate::domain::value_objects::RfqId;
+    use crate::infrastructure::persistence::in_memory::InMemoryDeadLetterStore;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Consumer that fails on one event until fixed, recording what it
+    /// handled.
+    #[derive(Debug)]
+    struct PoisonedConsumer {
+        poison: EventId,
+        fixed: AtomicBool,
+        handled: Mutex<Vec<EventId>>,
+    }
+
+    #[async_trait]
+    impl EventConsumer for PoisonedConsumer {
+        fn name(&self) -> &str {
+            "poisoned"
+        }
+
+        async fn handle(&self, event: &StoredEvent) -> ApplicationResult<()> {
+            if event.event_id == self.poison && !self.fixed.load(Ordering::SeqCst) {
+                return Err(ApplicationError::internal("cannot decode payload"));
+            }
+            self.handled.lock().unwrap().push(event.event_id);
+            Ok(())
+        }
+    }
+
+    fn event(sequence: u64) -> StoredEvent {
+        StoredEvent::new(
+            EventId::new_v4(),
+            Some(RfqId::new_v4()),
+            EventType::Rfq,
+            "RfqCreated",
+            Timestamp::now(),
+            serde_json::json!({}),
+            sequence,
+        )
+    }
+
+    #[tokio::test]
+    async fn poison_event_is_parked_and_redriven_once_fixed() {
+        let events: Vec<StoredEvent> = (1..=3).map(event).collect();
+        let consumer = Arc::new(PoisonedConsumer {
+            poison: events[1].event_id,
+            fixed: AtomicBool::new(false),
+            handled: Mutex::new(Vec::new()),
+        });
+        let queue = DeadLetterQueue::new(Arc::new(InMemoryDeadLetterStore::new()))
+            .with_consumer(Arc::clone(&consumer) as Arc<dyn EventConsumer>)
+            .with_retry_delay(Duration::ZERO);
+
+        let mut outcomes = Vec::new();
+        for event in &events {
+            outcomes.push(queue.process("poisoned", event).await.unwrap());
+        }
+        assert_eq!(outcomes[0], ConsumeOutcome::Handled);
+        assert_eq!(outcomes[2], ConsumeOutcome::Handled);
+        let ConsumeOutcome::Parked(letter) = &outcomes[1] else {
+            unreachable!("poison event should be parked");
+        };
+        assert_eq!(letter.attempts, DEFAULT_MAX_ATTEMPTS);
+        assert!(letter.last_error.contains("cannot decode payload"));
+        assert_eq!(
+            *consumer.handled.lock().unwrap(),
+            vec![events[0].event_id, events[2].event_id]
+        );
+
+        let parked = queue.list_parked(Some("poisoned"), 10).await.unwrap();
+        assert_eq!(parked.len(), 1);
+        assert_eq!(parked[0].event_id(), events[1].event_id);
+
+        // Still broken: the event stays parked with another attempt.
+        let letter = queue.redrive("poisoned", events[1].event_id).await.unwrap();
+        assert!(letter.is_parked());
+        assert_eq!(letter.attempts, DEFAULT_MAX_ATTEMPTS + 1);
+
+        consumer.fixed.store(true, Ordering::SeqCst);
+        let redriven = queue.redrive_all(None, 10).await.unwrap();
+        assert_eq!(redriven.len(), 1);
+        assert_eq!(redriven[0].status, DeadLetterStatus::Resolved);
+        assert!(queue.list_parked(None, 10).await.unwrap().is_empty());
+
+        // Re-driving a resolved event does not hand it over again.
+        let letter = queue.redrive("poisoned", events[1].event_id).await.unwrap();
+        assert_eq!(letter.status, DeadLetterStatus::Resolved);
+        assert_eq!(consumer.handled.lock().unwrap().len(), 3);
+    }
+}
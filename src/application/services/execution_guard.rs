@@ -0,0 +1,121 @@
+//! # Execution Guard
+//!
+//! Mutual exclusion for trade execution, keyed on the RFQ.
+//!
+//! Two API instances can receive "execute" for the same RFQ at the same
+//! time. [`ExecutionGuard`] serializes them by taking a
+//! [`ResourceLock::Rfq`] lock from a [`LockManager`] before the venue is
+//! called. With the in-memory lock manager this covers a single process; the
+//! PostgreSQL advisory lock manager extends it across instances.
+//!
+//! The returned [`LockGuard`] releases the lock when dropped, including
+//! when the execution task panics. A lock that cannot be acquired within the
+//! timeout fails with [`DomainError::ExecutionInProgress`].
+
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::services::lock_manager::{LockGuard, LockManager};
+use crate::domain::services::resource_lock::ResourceLock;
+use crate::domain::value_objects::RfqId;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Default time to wait for another execution of the same RFQ to finish.
+pub const DEFAULT_EXECUTION_LOCK_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Serializes executions of the same RFQ.
+#[derive(Debug, Clone)]
+pub struct ExecutionGuard {
+    lock_manager: Arc<dyn LockManager>,
+    timeout: Duration,
+}
+
+impl ExecutionGuard {
+    /// Creates a guard backed by the given lock manager.
+    ///
+    /// The lock manager's lock TTL should exceed the longest venue execution,
+    /// or a slow execution can lose its lock.
+    #[must_use]
+    pub fn new(lock_manager: Arc<dyn LockManager>) -> Self {
+        Self {
+            lock_manager,
+            timeout: DEFAULT_EXECUTION_LOCK_TIMEOUT,
+        }
+    }
+
+    /// Sets how long to wait for the lock.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Returns how long to wait for the lock.
+    #[must_use]
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Acquires the execution lock for `rfq_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ExecutionInProgress` if the lock cannot be
+    /// acquired within the timeout.
+    pub async fn acquire(&self, rfq_id: RfqId) -> DomainResult<LockGuard> {
+        self.lock_manager
+            .acquire_all(vec![ResourceLock::Rfq(rfq_id)], self.timeout)
+            .await
+            .map_err(|e| match e {
+                DomainError::LockAcquisitionFailed(msg) => {
+                    DomainError::ExecutionInProgress(format!("RFQ {}: {}", rfq_id, msg))
+                }
+                other => other,
+            })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::services::lock_manager::SharedLockManager;
+
+    #[tokio::test]
+    async fn second_acquire_fails_with_execution_in_progress() {
+        let guard = ExecutionGuard::new(SharedLockManager::with_defaults())
+            .with_timeout(Duration::from_millis(20));
+        let rfq_id = RfqId::new_v4();
+
+        let held = guard.acquire(rfq_id).await.unwrap();
+        let result = guard.acquire(rfq_id).await;
+        assert!(matches!(result, Err(DomainError::ExecutionInProgress(_))));
+
+        held.release().await.unwrap();
+        assert!(guard.acquire(rfq_id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn different_rfqs_do_not_contend() {
+        let guard = ExecutionGuard::new(SharedLockManager::with_defaults());
+
+        let _a = guard.acquire(RfqId::new_v4()).await.unwrap();
+        assert!(guard.acquire(RfqId::new_v4()).await.is_ok());
+    }
+
+    #[tokio::test]
+    #[allow(clippy::panic)]
+    async fn lock_is_released_when_holder_panics() {
+        let guard = ExecutionGuard::new(SharedLockManager::with_defaults())
+            .with_timeout(Duration::from_millis(200));
+        let rfq_id = RfqId::new_v4();
+
+        let holder = guard.clone();
+        let task = tokio::spawn(async move {
+            let _held = holder.acquire(rfq_id).await.unwrap();
+            panic!("execution task failed while holding the lock");
+        });
+        assert!(task.await.unwrap_err().is_panic());
+
+        assert!(guard.acquire(rfq_id).await.is_ok());
+    }
+}
@@ -0,0 +1,507 @@
+//! # Platform Fee Calculation
+//!
+//! Computes the platform fee charged on a trade.
+//!
+//! The [`FeeCalculator`] looks up the counterparty's
+//! [`PlatformFeeSchedule`](crate::domain::entities::platform_fee::PlatformFeeSchedule),
+//! charges the basis points of the notional band the trade falls into for
+//! the instrument's asset class, holds the result between the rate's
+//! minimum and maximum fee, and then applies the counterparty's active
+//! [`FeeWaiver`](crate::domain::entities::platform_fee::FeeWaiver), if any.
+//! The waiver discounts the capped fee, so a waived fee can fall below the
+//! minimum. The result is rounded per [`PlatformFeeConfig`] and charged in
+//! the instrument's quote asset.
+//!
+//! With [`FeeCalculator::with_fx_conversion`] configured, the notional is
+//! first converted into the base currency, so bands, minimums and maximums
+//! are read in that currency and the fee is charged in it. A notional that
+//! cannot be converted fails the calculation.
+//!
+//! A trade is not charged when the counterparty has no schedule, the
+//! schedule has no rate for the asset class, or the fee comes to zero.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::application::services::fx_conversion::FxConversionPort;
+use crate::domain::entities::trade::{FeeComponent, FeeKind};
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::arithmetic::{CheckedArithmetic, Rounding, round_dp};
+use crate::domain::value_objects::timestamp::{Clock, SystemClock};
+use crate::domain::value_objects::{CounterpartyId, Currency, Instrument, Money, Price, Quantity};
+use crate::infrastructure::persistence::traits::{
+    FeeWaiverRepository, PlatformFeeScheduleRepository,
+};
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+/// Rounding applied to computed platform fees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlatformFeeConfig {
+    /// Decimal places the fee is rounded to.
+    pub decimal_places: u32,
+    /// Rounding direction.
+    pub rounding: Rounding,
+}
+
+impl Default for PlatformFeeConfig {
+    /// Eight decimal places, rounded up so fractional fees are never lost.
+    fn default() -> Self {
+        Self {
+            decimal_places: 8,
+            rounding: Rounding::Up,
+        }
+    }
+}
+
+/// Computes platform fees from fee schedules and waivers.
+///
+/// # Examples
+///
+/// ```ignore
+/// let calculator = FeeCalculator::new(schedules, waivers);
+/// if let Some(fee) = calculator.calculate(&client, &instrument, price, quantity).await? {
+///     trade.add_fee(fee);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct FeeCalculator {
+    schedules: Arc<dyn PlatformFeeScheduleRepository>,
+    waivers: Arc<dyn FeeWaiverRepository>,
+    config: PlatformFeeConfig,
+    clock: Arc<dyn Clock>,
+    fx: Option<(Arc<dyn FxConversionPort>, Currency)>,
+}
+
+impl FeeCalculator {
+    /// Creates a calculator with the default rounding and the system clock.
+    #[must_use]
+    pub fn new(
+        schedules: Arc<dyn PlatformFeeScheduleRepository>,
+        waivers: Arc<dyn FeeWaiverRepository>,
+    ) -> Self {
+        Self {
+            schedules,
+            waivers,
+            config: PlatformFeeConfig::default(),
+            clock: Arc::new(SystemClock),
+            fx: None,
+        }
+    }
+
+    /// Sets the rounding applied to computed fees.
+    #[must_use]
+    pub fn with_config(mut self, config: PlatformFeeConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the clock used to decide whether a waiver has expired.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Converts notionals into `base_currency` with `fx` before applying the
+    /// schedule, and charges fees in `base_currency`.
+    #[must_use]
+    pub fn with_fx_conversion(
+        mut self,
+        fx: Arc<dyn FxConversionPort>,
+        base_currency: Currency,
+    ) -> Self {
+        self.fx = Some((fx, base_currency));
+        self
+    }
+
+    /// Returns the notional of the trade and the currency it is in.
+    async fn notional(
+        &self,
+        instrument: &Instrument,
+        price: Price,
+        quantity: Quantity,
+    ) -> ApplicationResult<(Decimal, String)> {
+        let notional = price
+            .get()
+            .safe_mul(quantity.get())
+            .map_err(DomainError::from)?;
+        let Some((fx, base_currency)) = &self.fx else {
+            return Ok((notional, instrument.quote_asset().to_string()));
+        };
+        let fee_failed = |reason: String| DomainError::FeeCalculationFailed { reason };
+        let quote =
+            Currency::new(instrument.quote_asset()).map_err(|e| fee_failed(e.to_string()))?;
+        let conversion = fx
+            .convert(&Money::new(notional, quote), base_currency)
+            .await
+            .map_err(|e| fee_failed(e.to_string()))?;
+        Ok((
+            conversion.converted.amount(),
+            base_currency.as_str().to_string(),
+        ))
+    }
+
+    /// Returns the platform fee `counterparty` pays for trading `quantity`
+    /// of `instrument` at `price`.
+    ///
+    /// Returns `Ok(None)` if no fee applies.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::RepositoryError` if a schedule or waiver
+    /// cannot be loaded, `DomainError::FeeCalculationFailed` if the notional
+    /// cannot be converted into the base currency, or a domain arithmetic
+    /// error if the fee overflows.
+    pub async fn calculate(
+        &self,
+        counterparty: &CounterpartyId,
+        instrument: &Instrument,
+        price: Price,
+        quantity: Quantity,
+    ) -> ApplicationResult<Option<FeeComponent>> {
+        let Some(schedule) = self
+            .schedules
+            .find_for_counterparty(counterparty)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?
+        else {
+            return Ok(None);
+        };
+        let Some(rate) = schedule.rate_for(instrument.asset_class()) else {
+            return Ok(None);
+        };
+
+        let (notional, currency) = self.notional(instrument, price, quantity).await?;
+        let mut fee = rate.fee_for(notional).map_err(DomainError::from)?;
+
+        let waiver = self
+            .waivers
+            .find(counterparty)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?;
+        if let Some(waiver) = waiver.filter(|w| w.is_active_at(self.clock.now())) {
+            fee = waiver.apply(fee).map_err(DomainError::from)?;
+        }
+
+        let fee = round_dp(fee, self.config.decimal_places, self.config.rounding);
+        if fee.is_zero() {
+            return Ok(None);
+        }
+        Ok(Some(FeeComponent::new(FeeKind::Platform, fee, currency)))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::application::services::fx_conversion::FxError;
+    use crate::domain::entities::platform_fee::{
+        AssetClassFeeRate, DEFAULT_FEE_TIER, FeeBand, FeeWaiver, PlatformFeeSchedule,
+    };
+    use crate::domain::value_objects::enums::AssetClass;
+    use crate::domain::value_objects::timestamp::{MockClock, Timestamp};
+    use crate::domain::value_objects::{FxRate, ReferencePriceSource, Symbol};
+    use crate::infrastructure::persistence::in_memory::{
+        InMemoryFeeWaiverRepository, InMemoryPlatformFeeScheduleRepository,
+    };
+
+    fn dec(value: i64) -> Decimal {
+        Decimal::new(value, 0)
+    }
+
+    fn spot() -> Instrument {
+        Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build()
+    }
+
+    fn rate(bands: &[(i64, i64)], min: Option<i64>, max: Option<i64>) -> AssetClassFeeRate {
+        AssetClassFeeRate::new(
+            AssetClass::CryptoSpot,
+            bands
+                .iter()
+                .map(|(threshold, bps)| FeeBand::new(dec(*threshold), dec(*bps)))
+                .collect(),
+            min.map(dec),
+            max.map(dec),
+        )
+        .unwrap()
+    }
+
+    struct Fixture {
+        schedules: Arc<InMemoryPlatformFeeScheduleRepository>,
+        waivers: Arc<InMemoryFeeWaiverRepository>,
+        clock: Arc<MockClock>,
+        calculator: FeeCalculator,
+    }
+
+    fn fixture() -> Fixture {
+        let schedules = Arc::new(InMemoryPlatformFeeScheduleRepository::new());
+        let waivers = Arc::new(InMemoryFeeWaiverRepository::new());
+        let clock = Arc::new(MockClock::new(Timestamp::from_secs(1_000).unwrap()));
+        let calculator =
+            FeeCalculator::new(schedules.clone(), waivers.clone()).with_clock(clock.clone());
+        Fixture {
+            schedules,
+            waivers,
+            clock,
+            calculator,
+        }
+    }
+
+    async fn fee(fixture: &Fixture, client: &str, price: i64, quantity: i64) -> Option<Decimal> {
+        fixture
+            .calculator
+            .calculate(
+                &CounterpartyId::new(client),
+                &spot(),
+                Price::new(price as f64).unwrap(),
+                Quantity::new(quantity as f64).unwrap(),
+            )
+            .await
+            .unwrap()
+            .map(|fee| fee.amount())
+    }
+
+    #[tokio::test]
+    async fn fee_is_charged_in_the_quote_asset() {
+        let fixture = fixture();
+        let schedule = PlatformFeeSchedule::new(
+            DEFAULT_FEE_TIER,
+            Vec::new(),
+            vec![rate(&[(0, 3)], None, None)],
+        )
+        .unwrap();
+        fixture.schedules.save(&schedule).await.unwrap();
+
+        let fee = fixture
+            .calculator
+            .calculate(
+                &CounterpartyId::new("client-1"),
+                &spot(),
+                Price::new(50_000.0).unwrap(),
+                Quantity::new(2.0).unwrap(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(fee.kind(), FeeKind::Platform);
+        assert_eq!(fee.amount(), dec(30));
+        assert_eq!(fee.currency(), "USD");
+    }
+
+    #[tokio::test]
+    async fn tier_boundary_notional_uses_the_upper_band() {
+        let fixture = fixture();
+        let schedule = PlatformFeeSchedule::new(
+            "gold",
+            vec![CounterpartyId::new("client-1")],
+            vec![rate(&[(0, 5), (1_000_000, 2)], None, None)],
+        )
+        .unwrap();
+        fixture.schedules.save(&schedule).await.unwrap();
+
+        // 999,999 notional at 5 bps; 1,000,000 notional at 2 bps.
+        assert_eq!(
+            fee(&fixture, "client-1", 999_999, 1).await,
+            Some(Decimal::new(4_999_995, 4))
+        );
+        assert_eq!(
+            fee(&fixture, "client-1", 1_000_000, 1).await,
+            Some(dec(200))
+        );
+    }
+
+    #[tokio::test]
+    async fn caps_and_floors_are_enforced() {
+        let fixture = fixture();
+        let schedule = PlatformFeeSchedule::new(
+            DEFAULT_FEE_TIER,
+            Vec::new(),
+            vec![rate(&[(0, 10)], Some(5), Some(1_000))],
+        )
+        .unwrap();
+        fixture.schedules.save(&schedule).await.unwrap();
+
+        assert_eq!(fee(&fixture, "client-1", 100, 1).await, Some(dec(5)));
+        assert_eq!(fee(&fixture, "client-1", 100_000, 1).await, Some(dec(100)));
+        assert_eq!(
+            fee(&fixture, "client-1", 10_000_000, 1).await,
+            Some(dec(1_000))
+        );
+    }
+
+    #[tokio::test]
+    async fn waiver_discounts_until_it_expires() {
+        let fixture = fixture();
+        let schedule = PlatformFeeSchedule::new(
+            DEFAULT_FEE_TIER,
+            Vec::new(),
+            vec![rate(&[(0, 10)], None, None)],
+        )
+        .unwrap();
+        fixture.schedules.save(&schedule).await.unwrap();
+        let expiry = fixture.clock.now().add_secs(60);
+        let waiver =
+            FeeWaiver::new(CounterpartyId::new("client-1"), dec(40), Some(expiry)).unwrap();
+        fixture.waivers.save(&waiver).await.unwrap();
+
+        assert_eq!(fee(&fixture, "client-1", 100_000, 1).await, Some(dec(60)));
+        fixture.clock.set(expiry);
+        assert_eq!(fee(&fixture, "client-1", 100_000, 1).await, Some(dec(60)));
+        fixture.clock.advance_secs(1);
+        assert_eq!(fee(&fixture, "client-1", 100_000, 1).await, Some(dec(100)));
+    }
+
+    #[tokio::test]
+    async fn zero_fee_counterparties_are_not_charged() {
+        let fixture = fixture();
+        let zero_rated = PlatformFeeSchedule::new(
+            "market-makers",
+            vec![CounterpartyId::new("mm-1")],
+            vec![rate(&[(0, 0)], None, None)],
+        )
+        .unwrap();
+        let default = PlatformFeeSchedule::new(
+            DEFAULT_FEE_TIER,
+            Vec::new(),
+            vec![rate(&[(0, 10)], Some(1), None)],
+        )
+        .unwrap();
+        fixture.schedules.save(&zero_rated).await.unwrap();
+        fixture.schedules.save(&default).await.unwrap();
+        let full_waiver =
+            FeeWaiver::new(CounterpartyId::new("client-2"), Decimal::ONE_HUNDRED, None).unwrap();
+        fixture.waivers.save(&full_waiver).await.unwrap();
+
+        assert_eq!(fee(&fixture, "mm-1", 100_000, 1).await, None);
+        assert_eq!(fee(&fixture, "client-2", 100_000, 1).await, None);
+        assert_eq!(fee(&fixture, "client-3", 100_000, 1).await, Some(dec(100)));
+    }
+
+    #[tokio::test]
+    async fn no_schedule_or_rate_means_no_fee() {
+        let fixture = fixture();
+        assert_eq!(fee(&fixture, "client-1", 100_000, 1).await, None);
+
+        let other_class = PlatformFeeSchedule::new(
+            DEFAULT_FEE_TIER,
+            Vec::new(),
+            vec![
+                AssetClassFeeRate::new(
+                    AssetClass::CryptoDerivs,
+                    vec![FeeBand::new(Decimal::ZERO, dec(10))],
+                    None,
+                    None,
+                )
+                .unwrap(),
+            ],
+        )
+        .unwrap();
+        fixture.schedules.save(&other_class).await.unwrap();
+        assert_eq!(fee(&fixture, "client-1", 100_000, 1).await, None);
+    }
+
+    #[tokio::test]
+    async fn fee_is_rounded_per_config() {
+        let fixture = fixture();
+        let schedule = PlatformFeeSchedule::new(
+            DEFAULT_FEE_TIER,
+            Vec::new(),
+            vec![rate(&[(0, 1)], None, None)],
+        )
+        .unwrap();
+        fixture.schedules.save(&schedule).await.unwrap();
+        let down = FeeCalculator::new(fixture.schedules.clone(), fixture.waivers.clone())
+            .with_config(PlatformFeeConfig {
+                decimal_places: 2,
+                rounding: Rounding::Down,
+            });
+        let up = FeeCalculator::new(fixture.schedules.clone(), fixture.waivers.clone())
+            .with_config(PlatformFeeConfig {
+                decimal_places: 2,
+                rounding: Rounding::Up,
+            });
+        let cp = CounterpartyId::new("client-1");
+        let (price, quantity) = (Price::new(12_345.0).unwrap(), Quantity::new(1.0).unwrap());
+
+        // 1 bp of 12,345 = 1.2345
+        let down = down
+            .calculate(&cp, &spot(), price, quantity)
+            .await
+            .unwrap()
+            .unwrap();
+        let up = up
+            .calculate(&cp, &spot(), price, quantity)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(down.amount(), Decimal::new(123, 2));
+        assert_eq!(up.amount(), Decimal::new(124, 2));
+    }
+
+    /// FX port converting EUR into USD at 1.25.
+    #[derive(Debug)]
+    struct EurUsdFx;
+
+    #[async_trait::async_trait]
+    impl FxConversionPort for EurUsdFx {
+        async fn rate(&self, from: &Currency, to: &Currency) -> Result<FxRate, FxError> {
+            if from.as_str() != "EUR" || to != &Currency::usd() {
+                return Err(FxError::UnknownPair {
+                    from: from.clone(),
+                    to: to.clone(),
+                });
+            }
+            Ok(FxRate::new(
+                from.clone(),
+                to.clone(),
+                Decimal::new(125, 2),
+                ReferencePriceSource::ClobMid,
+                Timestamp::now(),
+            )?)
+        }
+    }
+
+    #[tokio::test]
+    async fn eur_quoted_notional_is_banded_and_charged_in_the_base_currency() {
+        let fixture = fixture();
+        let schedule = PlatformFeeSchedule::new(
+            DEFAULT_FEE_TIER,
+            Vec::new(),
+            vec![rate(&[(0, 5), (1_000_000, 2)], None, None)],
+        )
+        .unwrap();
+        fixture.schedules.save(&schedule).await.unwrap();
+        let calculator = FeeCalculator::new(fixture.schedules.clone(), fixture.waivers.clone())
+            .with_fx_conversion(Arc::new(EurUsdFx), Currency::usd());
+        let client = CounterpartyId::new("client-1");
+
+        // 800,000 EUR is 1,000,000 USD: the upper band, charged in USD.
+        let btc_eur =
+            Instrument::builder(Symbol::new("BTC/EUR").unwrap(), AssetClass::CryptoSpot).build();
+        let fee = calculator
+            .calculate(
+                &client,
+                &btc_eur,
+                Price::new(800_000.0).unwrap(),
+                Quantity::new(1.0).unwrap(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(fee.amount(), dec(200));
+        assert_eq!(fee.currency(), "USD");
+
+        let eth_gbp =
+            Instrument::builder(Symbol::new("ETH/GBP").unwrap(), AssetClass::CryptoSpot).build();
+        let result = calculator
+            .calculate(
+                &client,
+                &eth_gbp,
+                Price::new(1_000.0).unwrap(),
+                Quantity::new(1.0).unwrap(),
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
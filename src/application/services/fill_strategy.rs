@@ -42,6 +42,7 @@
 
 use crate::application::services::ranking_strategy::RankedQuote;
 use crate::domain::entities::allocation::Allocation;
+use crate::domain::entities::rfq::Rfq;
 use crate::domain::errors::{DomainError, DomainResult};
 use crate::domain::value_objects::size_negotiation_mode::SizeNegotiationMode;
 use crate::domain::value_objects::{OrderSide, Quantity};
@@ -85,6 +86,22 @@ pub trait MultiMmFillStrategy: Send + Sync + fmt::Debug {
         side: OrderSide,
     ) -> DomainResult<Vec<Allocation>>;
 
+    /// Allocates an RFQ's requested quantity using the RFQ's own size mode
+    /// and side.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`allocate`](Self::allocate), and `DomainError::ValidationError`
+    /// for a two-way RFQ whose side has not been selected.
+    fn allocate_for_rfq(&self, rfq: &Rfq, quotes: &[RankedQuote]) -> DomainResult<Vec<Allocation>> {
+        self.allocate(
+            quotes,
+            rfq.quantity(),
+            rfq.size_mode(),
+            rfq.resolved_side()?,
+        )
+    }
+
     /// Returns the name of this fill strategy.
     fn name(&self) -> &'static str;
 }
@@ -805,6 +822,46 @@ mod tests {
             assert_eq!(allocs[0].allocated_quantity(), Quantity::new(5.0).unwrap());
         }
 
+        #[test]
+        fn allocate_for_rfq_uses_rfq_size_mode() {
+            use crate::domain::entities::rfq::RfqBuilder;
+            use crate::domain::value_objects::enums::AssetClass;
+            use crate::domain::value_objects::{CounterpartyId, Instrument, Symbol};
+
+            let instrument =
+                Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot)
+                    .build();
+            let rfq = RfqBuilder::new(
+                CounterpartyId::new("client-1"),
+                instrument,
+                OrderSide::Buy,
+                Quantity::new(10.0).unwrap(),
+                Timestamp::now().add_secs(300),
+            )
+            .size_mode(SizeNegotiationMode::BestEffort)
+            .build();
+            let ranked = [make_ranked_quote(rfq.id(), "v1", 100.0, 4.0, 1, 1.0)];
+
+            let allocs = BestPriceFillStrategy
+                .allocate_for_rfq(&rfq, &ranked)
+                .unwrap();
+            assert_eq!(allocs.len(), 1);
+            assert_eq!(allocs[0].allocated_quantity(), Quantity::new(4.0).unwrap());
+
+            let aon = RfqBuilder::new(
+                CounterpartyId::new("client-1"),
+                rfq.instrument().clone(),
+                OrderSide::Buy,
+                Quantity::new(10.0).unwrap(),
+                Timestamp::now().add_secs(300),
+            )
+            .build();
+            assert!(matches!(
+                BestPriceFillStrategy.allocate_for_rfq(&aon, &ranked),
+                Err(DomainError::InsufficientLiquidity { .. })
+            ));
+        }
+
         #[test]
         fn best_effort_zero_available_returns_error() {
             let result = enforce_mode(
@@ -0,0 +1,868 @@
+//! # Quote Firm-Up
+//!
+//! Converts indicative quotes to firm quotes before a client selects them.
+//!
+//! Venues may answer an RFQ with an [`Indicative`](QuoteFirmness::Indicative)
+//! quote: a guide price the venue is not yet committed to. When a client
+//! selects one, [`FirmUpService`] asks the quoting venue to firm it up and
+//! replaces the quote on the RFQ with the firm version. The firm price may
+//! move by up to [`FirmUpConfig::tolerance_pct`]; beyond that the selection
+//! fails with [`DomainError::FirmUpPriceMoved`] and the RFQ is left as it was,
+//! so the client can pick another quote.
+//!
+//! Quotes against a masked size (see
+//! [`QuantityDisclosure`](crate::domain::value_objects::QuantityDisclosure))
+//! come back indicative, so they are firmed up at the RFQ's true quantity
+//! the same way.
+//!
+//! On a two-way RFQ the client names the side being hit with
+//! [`FirmUpService::select_quote_on_side`], which fixes the trade direction.
+//!
+//! Firm quotes are selected directly. Either way the RFQ ends in
+//! `ClientSelecting` with a firm quote selected, ready for execution.
+//!
+//! Before anything else the selection is checked against the
+//! [`QuorumPolicy`](crate::domain::value_objects::QuorumPolicy) for the
+//! client and the instrument's liquidity tier: if the RFQ drew fewer quotes
+//! or venues than the trade's notional band requires, it stays in
+//! `QuotesReceived` and selection fails with
+//! [`DomainError::QuorumNotMet`]. A client can still select with an explicit
+//! override via [`FirmUpService::select_quote_overriding_quorum`], which is
+//! recorded as a [`QuorumOverridden`] event.
+//!
+//! With [`FirmUpService::with_quote_reuse`], a selected quote is dropped
+//! from the [`QuoteReuseCache`] so no later RFQ is offered its liquidity.
+
+use crate::application::error::{ApplicationError, ApplicationResult, InfrastructureError};
+use crate::application::services::liquidity_classifier::LiquidityClassifier;
+use crate::application::services::price_bounds::compute_deviation;
+use crate::application::services::quote_reuse_cache::QuoteReuseCache;
+use crate::application::use_cases::collect_quotes::VenueRegistry;
+use crate::application::use_cases::create_rfq::RfqRepository;
+use crate::domain::entities::quote::Quote;
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::errors::DomainError;
+use crate::domain::events::rfq_events::QuorumOverridden;
+use crate::domain::value_objects::{OrderSide, QuorumRules, QuorumShortfall, QuoteId, RfqId};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, instrument, warn};
+
+/// Default maximum price move on firm-up (fractional, 0.005 = ±0.5%).
+pub const DEFAULT_FIRM_UP_TOLERANCE_PCT: Decimal = Decimal::from_parts(5, 0, 0, false, 3);
+
+/// Default time allowed for a venue to firm up a quote.
+pub const DEFAULT_FIRM_UP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Configuration for [`FirmUpService`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FirmUpConfig {
+    /// Maximum allowed move from the indicative price (fractional, e.g., 0.005 = ±0.5%).
+    pub tolerance_pct: Decimal,
+    /// Time allowed for the venue to return the firm quote.
+    pub timeout: Duration,
+}
+
+impl Default for FirmUpConfig {
+    fn default() -> Self {
+        Self {
+            tolerance_pct: DEFAULT_FIRM_UP_TOLERANCE_PCT,
+            timeout: DEFAULT_FIRM_UP_TIMEOUT,
+        }
+    }
+}
+
+/// Publisher for quote selection events.
+#[async_trait]
+pub trait SelectionEventPublisher: Send + Sync + fmt::Debug {
+    /// Publishes a quorum overridden event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be published.
+    async fn publish_quorum_overridden(&self, event: QuorumOverridden) -> ApplicationResult<()>;
+}
+
+/// Selects quotes on behalf of clients, firming up indicative ones first.
+pub struct FirmUpService {
+    rfq_repository: Arc<dyn RfqRepository>,
+    venue_registry: Arc<dyn VenueRegistry>,
+    config: FirmUpConfig,
+    quorum: QuorumRules,
+    event_publisher: Option<Arc<dyn SelectionEventPublisher>>,
+    quote_reuse: Option<Arc<QuoteReuseCache>>,
+    liquidity: Option<Arc<LiquidityClassifier>>,
+}
+
+impl fmt::Debug for FirmUpService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FirmUpService")
+            .field("venue_registry", &self.venue_registry)
+            .field("config", &self.config)
+            .field("quorum", &self.quorum)
+            .field("event_publisher", &self.event_publisher)
+            .field("quote_reuse", &self.quote_reuse)
+            .field("liquidity", &self.liquidity)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FirmUpService {
+    /// Creates a new firm-up service with the default configuration.
+    #[must_use]
+    pub fn new(
+        rfq_repository: Arc<dyn RfqRepository>,
+        venue_registry: Arc<dyn VenueRegistry>,
+    ) -> Self {
+        Self {
+            rfq_repository,
+            venue_registry,
+            config: FirmUpConfig::default(),
+            quorum: QuorumRules::default(),
+            event_publisher: None,
+            quote_reuse: None,
+            liquidity: None,
+        }
+    }
+
+    /// Sets the tolerance and timeout.
+    #[must_use]
+    pub fn with_config(mut self, config: FirmUpConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the quote quorum policies. Without them no quorum is enforced.
+    #[must_use]
+    pub fn with_quorum(mut self, quorum: QuorumRules) -> Self {
+        self.quorum = quorum;
+        self
+    }
+
+    /// Sets the publisher that records quorum overrides.
+    #[must_use]
+    pub fn with_event_publisher(mut self, publisher: Arc<dyn SelectionEventPublisher>) -> Self {
+        self.event_publisher = Some(publisher);
+        self
+    }
+
+    /// Sets the quote reuse cache that selected quotes are dropped from.
+    #[must_use]
+    pub fn with_quote_reuse(mut self, cache: Arc<QuoteReuseCache>) -> Self {
+        self.quote_reuse = Some(cache);
+        self
+    }
+
+    /// Sets the classifier whose liquidity tiers select the tier quorum
+    /// policy. Without it the instrument's declared tier is used.
+    #[must_use]
+    pub fn with_liquidity_classifier(mut self, classifier: Arc<LiquidityClassifier>) -> Self {
+        self.liquidity = Some(classifier);
+        self
+    }
+
+    /// Returns the current configuration.
+    #[inline]
+    #[must_use]
+    pub fn config(&self) -> &FirmUpConfig {
+        &self.config
+    }
+
+    /// Selects a quote, firming it up first if it is indicative.
+    ///
+    /// Blocks until the venue returns the firm quote or the configured
+    /// timeout elapses. The RFQ is only saved once the selection succeeds.
+    ///
+    /// # Returns
+    ///
+    /// The RFQ in `ClientSelecting` state with a firm quote selected.
+    ///
+    /// # Errors
+    ///
+    /// - `ApplicationError::RfqNotFound` / `QuoteNotFound` - Unknown RFQ or quote
+    /// - `ApplicationError::Domain` with `DomainError::QuorumNotMet` - Too few
+    ///   competing quotes for the trade's notional band
+    /// - `ApplicationError::QuoteExpired` - The quote has expired
+    /// - `ApplicationError::VenueNotAvailable` - The quoting venue is not registered
+    /// - `ApplicationError::Venue` - The venue failed to firm up the quote
+    /// - `ApplicationError::Infrastructure` - The firm-up timed out
+    /// - `ApplicationError::Domain` with `DomainError::FirmUpPriceMoved` - The
+    ///   firm price moved beyond tolerance
+    /// - `ApplicationError::Domain` - The RFQ cannot select quotes in its state
+    #[instrument(skip(self), fields(rfq_id = %rfq_id, quote_id = %quote_id))]
+    pub async fn select_quote(&self, rfq_id: RfqId, quote_id: QuoteId) -> ApplicationResult<Rfq> {
+        self.select(rfq_id, quote_id, None, None).await
+    }
+
+    /// Selects a quote on the side the client is hitting.
+    ///
+    /// Required for two-way RFQs, whose trade direction is fixed to `side`
+    /// by the selection. Otherwise behaves like
+    /// [`select_quote`](Self::select_quote).
+    ///
+    /// # Errors
+    ///
+    /// - `ApplicationError::Domain` with `DomainError::ValidationError` - The
+    ///   quote is not on `side`
+    /// - Any error of [`select_quote`](Self::select_quote)
+    #[instrument(skip(self), fields(rfq_id = %rfq_id, quote_id = %quote_id, side = %side))]
+    pub async fn select_quote_on_side(
+        &self,
+        rfq_id: RfqId,
+        quote_id: QuoteId,
+        side: OrderSide,
+    ) -> ApplicationResult<Rfq> {
+        self.select(rfq_id, quote_id, Some(side), None).await
+    }
+
+    /// Selects a quote even if the RFQ did not meet its quote quorum.
+    ///
+    /// Behaves like [`select_quote`](Self::select_quote), except that an
+    /// unmet quorum does not block the selection. The override and `reason`
+    /// are published as a [`QuorumOverridden`] event once the selection is
+    /// saved. If the quorum is met no event is published. `side` is the side
+    /// being hit, as in [`select_quote_on_side`](Self::select_quote_on_side),
+    /// and is required on a two-way RFQ.
+    ///
+    /// # Errors
+    ///
+    /// - `ApplicationError::Validation` - The reason is empty
+    /// - `ApplicationError::EventPublishError` - The override could not be recorded
+    /// - Any error of [`select_quote`](Self::select_quote) other than
+    ///   `DomainError::QuorumNotMet`
+    #[instrument(skip(self, reason), fields(rfq_id = %rfq_id, quote_id = %quote_id))]
+    pub async fn select_quote_overriding_quorum(
+        &self,
+        rfq_id: RfqId,
+        quote_id: QuoteId,
+        side: Option<OrderSide>,
+        reason: &str,
+    ) -> ApplicationResult<Rfq> {
+        if reason.trim().is_empty() {
+            return Err(ApplicationError::Validation(
+                "quorum override requires a reason".to_string(),
+            ));
+        }
+        self.select(rfq_id, quote_id, side, Some(reason)).await
+    }
+
+    async fn select(
+        &self,
+        rfq_id: RfqId,
+        quote_id: QuoteId,
+        side: Option<OrderSide>,
+        quorum_override: Option<&str>,
+    ) -> ApplicationResult<Rfq> {
+        let mut rfq = self
+            .rfq_repository
+            .find_by_id(rfq_id)
+            .await
+            .map_err(ApplicationError::RepositoryError)?
+            .ok_or_else(|| ApplicationError::RfqNotFound(rfq_id.to_string()))?;
+
+        let quote = rfq
+            .quotes()
+            .iter()
+            .find(|q| q.id() == quote_id)
+            .cloned()
+            .ok_or_else(|| ApplicationError::QuoteNotFound(quote_id.to_string()))?;
+
+        // Check the side before asking a venue to firm anything up
+        let side = match side {
+            Some(side) => side,
+            None => rfq.resolved_side()?,
+        };
+        if rfq.side_of(&quote) != side {
+            return Err(DomainError::ValidationError(format!(
+                "quote {quote_id} is not on the {side} side"
+            ))
+            .into());
+        }
+
+        let shortfall = self.quorum_shortfall(&rfq, &quote, side);
+        if let Some(shortfall) = shortfall
+            && quorum_override.is_none()
+        {
+            warn!(client_id = %rfq.client_id(), %shortfall, "Quote quorum not met");
+            return Err(DomainError::QuorumNotMet(shortfall).into());
+        }
+
+        if quote.is_expired() {
+            return Err(ApplicationError::QuoteExpired(quote_id.to_string()));
+        }
+
+        let selected_id = if quote.is_indicative() {
+            let firm = self.firm_up(&rfq, &quote).await?;
+            let firm_id = firm.id();
+            rfq.firm_up_quote(quote_id, firm)?;
+            firm_id
+        } else {
+            quote_id
+        };
+
+        rfq.select_quote_on_side(selected_id, side)?;
+        self.rfq_repository
+            .save(&rfq)
+            .await
+            .map_err(ApplicationError::RepositoryError)?;
+        if let Some(cache) = &self.quote_reuse {
+            cache.mark_selected(quote_id);
+        }
+
+        if let (Some(shortfall), Some(reason)) = (shortfall, quorum_override) {
+            self.record_override(&rfq, selected_id, shortfall, reason)
+                .await?;
+        }
+
+        Ok(rfq)
+    }
+
+    /// Checks the RFQ's quotes against the quorum policy for the client and
+    /// the instrument's liquidity tier.
+    ///
+    /// The trade's notional is taken from the quote being selected, and
+    /// only quotes on the side being hit count towards the quorum.
+    fn quorum_shortfall(
+        &self,
+        rfq: &Rfq,
+        quote: &Quote,
+        side: OrderSide,
+    ) -> Option<QuorumShortfall> {
+        // An overflowing notional falls in the highest band.
+        let notional = quote
+            .price()
+            .get()
+            .checked_mul(quote.quantity().get())
+            .unwrap_or(Decimal::MAX);
+        let quotes: Vec<_> = rfq.quotes_on(side).collect();
+        let venues: HashSet<_> = quotes.iter().map(|q| q.venue_id()).collect();
+        let instrument = rfq.instrument();
+        let liquidity = self
+            .liquidity
+            .as_ref()
+            .map_or(instrument.liquidity(), |classifier| {
+                classifier.classification_for(instrument)
+            });
+        self.quorum.policy_for(rfq.client_id(), liquidity).evaluate(
+            notional,
+            u32::try_from(quotes.len()).unwrap_or(u32::MAX),
+            u32::try_from(venues.len()).unwrap_or(u32::MAX),
+        )
+    }
+
+    async fn record_override(
+        &self,
+        rfq: &Rfq,
+        quote_id: QuoteId,
+        shortfall: QuorumShortfall,
+        reason: &str,
+    ) -> ApplicationResult<()> {
+        info!(client_id = %rfq.client_id(), %shortfall, reason, "Quote quorum overridden");
+        let Some(publisher) = &self.event_publisher else {
+            warn!("No selection event publisher configured; quorum override not published");
+            return Ok(());
+        };
+        publisher
+            .publish_quorum_overridden(QuorumOverridden::new(
+                rfq.id(),
+                rfq.client_id().clone(),
+                quote_id,
+                shortfall,
+                reason,
+            ))
+            .await
+    }
+
+    /// Asks the quoting venue for the firm version of `quote`, at the
+    /// RFQ's true quantity and on the quote's side.
+    async fn firm_up(&self, rfq: &Rfq, quote: &Quote) -> ApplicationResult<Quote> {
+        let venue = self
+            .venue_registry
+            .get_venue(quote.venue_id())
+            .await
+            .ok_or_else(|| ApplicationError::VenueNotAvailable(quote.venue_id().to_string()))?;
+
+        let firm = tokio::time::timeout(
+            self.config.timeout,
+            venue.firm_up_for_rfq(&rfq.one_way(rfq.side_of(quote)), quote),
+        )
+        .await
+        .map_err(|_| {
+            warn!(venue_id = %quote.venue_id(), "Firm-up timed out");
+            ApplicationError::Infrastructure(InfrastructureError::timeout(format!(
+                "venue {} did not firm up quote {} within {}ms",
+                quote.venue_id(),
+                quote.id(),
+                self.config.timeout.as_millis()
+            )))
+        })??;
+
+        let deviation = compute_deviation(&firm.price(), &quote.price())?;
+        if deviation > self.config.tolerance_pct {
+            warn!(
+                venue_id = %quote.venue_id(),
+                indicative = %quote.price(),
+                firm = %firm.price(),
+                "Firm-up price moved beyond tolerance"
+            );
+            return Err(DomainError::FirmUpPriceMoved {
+                indicative: quote.price(),
+                firm: firm.price(),
+                deviation_pct: deviation,
+                max_tolerance_pct: self.config.tolerance_pct,
+            }
+            .into());
+        }
+
+        info!(
+            venue_id = %quote.venue_id(),
+            indicative = %quote.price(),
+            firm = %firm.price(),
+            "Quote firmed up"
+        );
+        Ok(firm)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::quote::{QuoteBuilder, QuoteFirmness};
+    use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::value_objects::timestamp::Timestamp;
+    use crate::domain::value_objects::{
+        AssetClass, CounterpartyId, Instrument, OrderSide, Price, Quantity, QuorumBand,
+        QuorumPolicy, QuorumRequirement, RfqDirection, RfqState, Symbol, VenueId,
+    };
+    use crate::infrastructure::persistence::cursor::{paginate, paginate_offset};
+    use crate::infrastructure::persistence::{PageCursor, RfqListFilter};
+    use crate::infrastructure::venues::error::{VenueError, VenueResult};
+    use crate::infrastructure::venues::traits::{ExecutionResult, VenueAdapter, VenueHealth};
+    use async_trait::async_trait;
+    use parking_lot::Mutex;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Default)]
+    struct MockRfqRepository {
+        rfqs: Mutex<HashMap<RfqId, Rfq>>,
+    }
+
+    #[async_trait]
+    impl RfqRepository for MockRfqRepository {
+        async fn save(&self, rfq: &Rfq) -> Result<(), String> {
+            self.rfqs.lock().insert(rfq.id(), rfq.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: RfqId) -> Result<Option<Rfq>, String> {
+            Ok(self.rfqs.lock().get(&id).cloned())
+        }
+
+        async fn list_after(
+            &self,
+            cursor: Option<&PageCursor>,
+            limit: usize,
+            filter: &RfqListFilter,
+        ) -> Result<Vec<Rfq>, String> {
+            let rfqs = self
+                .rfqs
+                .lock()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .cloned()
+                .collect();
+            Ok(paginate(rfqs, cursor, limit, PageCursor::from_rfq))
+        }
+
+        async fn list_offset(
+            &self,
+            offset: usize,
+            limit: usize,
+            filter: &RfqListFilter,
+        ) -> Result<Vec<Rfq>, String> {
+            let rfqs = self
+                .rfqs
+                .lock()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .cloned()
+                .collect();
+            Ok(paginate_offset(rfqs, offset, limit, PageCursor::from_rfq))
+        }
+
+        async fn count_matching(&self, filter: &RfqListFilter) -> Result<u64, String> {
+            let count = self
+                .rfqs
+                .lock()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .count();
+            Ok(count as u64)
+        }
+    }
+
+    /// Venue that firms up quotes at a fixed price after a delay.
+    #[derive(Debug)]
+    struct MockVenue {
+        venue_id: VenueId,
+        firm_price: f64,
+        delay: Duration,
+        firm_ups: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl VenueAdapter for MockVenue {
+        fn venue_id(&self) -> &VenueId {
+            &self.venue_id
+        }
+
+        fn timeout_ms(&self) -> u64 {
+            1000
+        }
+
+        async fn request_quote(&self, _rfq: &Rfq) -> VenueResult<Quote> {
+            Err(VenueError::internal_error("mock"))
+        }
+
+        async fn execute_trade(&self, _quote: &Quote) -> VenueResult<ExecutionResult> {
+            Err(VenueError::internal_error("mock"))
+        }
+
+        async fn health_check(&self) -> VenueResult<VenueHealth> {
+            Ok(VenueHealth::healthy(self.venue_id.clone()))
+        }
+
+        async fn firm_up(&self, quote: &Quote) -> VenueResult<Quote> {
+            self.firm_ups.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(self.delay).await;
+            Ok(QuoteBuilder::new(
+                quote.rfq_id(),
+                self.venue_id.clone(),
+                Price::new(self.firm_price).unwrap(),
+                quote.quantity(),
+                Timestamp::now().add_secs(30),
+            )
+            .build())
+        }
+    }
+
+    #[derive(Debug)]
+    struct MockVenueRegistry {
+        venue: Arc<MockVenue>,
+    }
+
+    #[async_trait]
+    impl VenueRegistry for MockVenueRegistry {
+        async fn get_available_venues(&self) -> Vec<Arc<dyn VenueAdapter>> {
+            vec![self.venue.clone()]
+        }
+
+        async fn get_venue(&self, venue_id: &VenueId) -> Option<Arc<dyn VenueAdapter>> {
+            (venue_id == &self.venue.venue_id).then(|| self.venue.clone() as Arc<dyn VenueAdapter>)
+        }
+    }
+
+    struct Fixture {
+        service: FirmUpService,
+        repository: Arc<MockRfqRepository>,
+        venue: Arc<MockVenue>,
+        rfq_id: RfqId,
+        quote_id: QuoteId,
+    }
+
+    /// An RFQ with one 100.0 quote from a venue that firms up at `firm_price`.
+    fn fixture(firmness: QuoteFirmness, firm_price: f64, delay: Duration) -> Fixture {
+        let mut rfq = RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build(),
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build();
+        rfq.start_quote_collection().unwrap();
+        let quote = QuoteBuilder::new(
+            rfq.id(),
+            VenueId::new("venue-1"),
+            Price::new(100.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(60),
+        )
+        .firmness(firmness)
+        .build();
+        let quote_id = quote.id();
+        rfq.receive_quote(quote).unwrap();
+        let rfq_id = rfq.id();
+
+        let repository = Arc::new(MockRfqRepository::default());
+        repository.rfqs.lock().insert(rfq_id, rfq);
+        let venue = Arc::new(MockVenue {
+            venue_id: VenueId::new("venue-1"),
+            firm_price,
+            delay,
+            firm_ups: AtomicUsize::new(0),
+        });
+        let service = FirmUpService::new(
+            repository.clone(),
+            Arc::new(MockVenueRegistry {
+                venue: venue.clone(),
+            }),
+        )
+        .with_config(FirmUpConfig {
+            tolerance_pct: Decimal::new(1, 2),
+            timeout: Duration::from_millis(200),
+        });
+
+        Fixture {
+            service,
+            repository,
+            venue,
+            rfq_id,
+            quote_id,
+        }
+    }
+
+    #[tokio::test]
+    async fn firm_quotes_skip_firm_up() {
+        let f = fixture(QuoteFirmness::Firm, 150.0, Duration::ZERO);
+
+        let mut rfq = f.service.select_quote(f.rfq_id, f.quote_id).await.unwrap();
+
+        assert_eq!(f.venue.firm_ups.load(Ordering::SeqCst), 0);
+        assert_eq!(rfq.state(), RfqState::ClientSelecting);
+        assert_eq!(rfq.selected_quote_id(), Some(f.quote_id));
+        assert!(rfq.start_execution().is_ok());
+    }
+
+    #[tokio::test]
+    async fn indicative_within_tolerance_is_replaced_and_selected() {
+        let f = fixture(QuoteFirmness::Indicative, 100.5, Duration::ZERO);
+
+        let mut rfq = f.service.select_quote(f.rfq_id, f.quote_id).await.unwrap();
+
+        assert_eq!(f.venue.firm_ups.load(Ordering::SeqCst), 1);
+        let selected = rfq.selected_quote().unwrap();
+        assert_ne!(selected.id(), f.quote_id);
+        assert!(!selected.is_indicative());
+        assert_eq!(selected.price(), Price::new(100.5).unwrap());
+        assert_eq!(rfq.quotes().len(), 1);
+
+        let saved = f.repository.rfqs.lock().get(&f.rfq_id).cloned().unwrap();
+        assert_eq!(saved.state(), RfqState::ClientSelecting);
+        assert!(rfq.start_execution().is_ok());
+    }
+
+    #[tokio::test]
+    async fn two_way_selection_requires_and_fixes_the_side() {
+        let f = fixture(QuoteFirmness::Indicative, 100.5, Duration::ZERO);
+        let mut rfq = RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build(),
+            RfqDirection::TwoWay,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build();
+        rfq.start_quote_collection().unwrap();
+        let bid = QuoteBuilder::new(
+            rfq.id(),
+            VenueId::new("venue-1"),
+            Price::new(100.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(60),
+        )
+        .side(OrderSide::Sell)
+        .firmness(QuoteFirmness::Indicative)
+        .build();
+        let bid_id = bid.id();
+        rfq.receive_quote(bid).unwrap();
+        let rfq_id = rfq.id();
+        f.repository.rfqs.lock().insert(rfq_id, rfq);
+
+        assert!(f.service.select_quote(rfq_id, bid_id).await.is_err());
+        assert!(matches!(
+            f.service
+                .select_quote_on_side(rfq_id, bid_id, OrderSide::Buy)
+                .await,
+            Err(ApplicationError::Domain(DomainError::ValidationError(_)))
+        ));
+        assert_eq!(f.venue.firm_ups.load(Ordering::SeqCst), 0);
+
+        let rfq = f
+            .service
+            .select_quote_on_side(rfq_id, bid_id, OrderSide::Sell)
+            .await
+            .unwrap();
+        assert_eq!(rfq.direction(), RfqDirection::Sell);
+        assert_eq!(rfq.selected_quote().unwrap().side(), Some(OrderSide::Sell));
+    }
+
+    #[tokio::test]
+    async fn price_moved_beyond_tolerance_fails_selection() {
+        let f = fixture(QuoteFirmness::Indicative, 102.0, Duration::ZERO);
+
+        let result = f.service.select_quote(f.rfq_id, f.quote_id).await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(
+                DomainError::FirmUpPriceMoved { .. }
+            ))
+        ));
+        if let Err(ApplicationError::Domain(DomainError::FirmUpPriceMoved {
+            indicative,
+            firm,
+            deviation_pct,
+            max_tolerance_pct,
+        })) = result
+        {
+            assert_eq!(indicative, Price::new(100.0).unwrap());
+            assert_eq!(firm, Price::new(102.0).unwrap());
+            assert_eq!(deviation_pct, Decimal::new(2, 2));
+            assert_eq!(max_tolerance_pct, Decimal::new(1, 2));
+        }
+
+        // The RFQ is untouched, so the client can select another quote.
+        let saved = f.repository.rfqs.lock().get(&f.rfq_id).cloned().unwrap();
+        assert_eq!(saved.state(), RfqState::QuotesReceived);
+        assert!(saved.quotes().first().unwrap().is_indicative());
+    }
+
+    #[tokio::test]
+    async fn slow_firm_up_times_out() {
+        let f = fixture(QuoteFirmness::Indicative, 100.0, Duration::from_secs(5));
+
+        let result = f.service.select_quote(f.rfq_id, f.quote_id).await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Infrastructure(
+                InfrastructureError::Timeout(_)
+            ))
+        ));
+        let saved = f.repository.rfqs.lock().get(&f.rfq_id).cloned().unwrap();
+        assert_eq!(saved.state(), RfqState::QuotesReceived);
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingPublisher {
+        events: Mutex<Vec<QuorumOverridden>>,
+    }
+
+    #[async_trait]
+    impl SelectionEventPublisher for RecordingPublisher {
+        async fn publish_quorum_overridden(
+            &self,
+            event: QuorumOverridden,
+        ) -> ApplicationResult<()> {
+            self.events.lock().push(event);
+            Ok(())
+        }
+    }
+
+    /// Rules requiring 3 quotes from 2 venues from a notional of `threshold`.
+    fn quorum_from(threshold: i64) -> QuorumRules {
+        QuorumRules::new(
+            QuorumPolicy::new(vec![QuorumBand::new(
+                Decimal::from(threshold),
+                QuorumRequirement::new(3, 2),
+            )])
+            .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn quorum_is_not_enforced_below_threshold() {
+        let f = fixture(QuoteFirmness::Firm, 100.0, Duration::ZERO);
+        let service = f.service.with_quorum(quorum_from(1_000));
+
+        let rfq = service.select_quote(f.rfq_id, f.quote_id).await.unwrap();
+
+        assert_eq!(rfq.state(), RfqState::ClientSelecting);
+    }
+
+    #[tokio::test]
+    async fn unmet_quorum_above_threshold_blocks_selection() {
+        let f = fixture(QuoteFirmness::Indicative, 100.0, Duration::ZERO);
+        let service = f.service.with_quorum(quorum_from(100));
+
+        let result = service.select_quote(f.rfq_id, f.quote_id).await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::QuorumNotMet(_)))
+        ));
+        if let Err(ApplicationError::Domain(DomainError::QuorumNotMet(shortfall))) = result {
+            assert_eq!(shortfall.notional, Decimal::from(100));
+            assert_eq!(shortfall.required, QuorumRequirement::new(3, 2));
+            assert_eq!((shortfall.quotes, shortfall.venues), (1, 1));
+        }
+        // Blocked before firm-up, with the RFQ left in QuotesReceived.
+        assert_eq!(f.venue.firm_ups.load(Ordering::SeqCst), 0);
+        let saved = f.repository.rfqs.lock().get(&f.rfq_id).cloned().unwrap();
+        assert_eq!(saved.state(), RfqState::QuotesReceived);
+    }
+
+    #[tokio::test]
+    async fn client_override_policy_replaces_default() {
+        let f = fixture(QuoteFirmness::Firm, 100.0, Duration::ZERO);
+        let rules = quorum_from(100)
+            .with_override(CounterpartyId::new("client-1"), QuorumPolicy::default());
+        let service = f.service.with_quorum(rules);
+
+        assert!(service.select_quote(f.rfq_id, f.quote_id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn override_selects_and_publishes_event() {
+        let f = fixture(QuoteFirmness::Firm, 100.0, Duration::ZERO);
+        let publisher = Arc::new(RecordingPublisher::default());
+        let service = f
+            .service
+            .with_quorum(quorum_from(100))
+            .with_event_publisher(publisher.clone());
+
+        let empty = service
+            .select_quote_overriding_quorum(f.rfq_id, f.quote_id, None, " ")
+            .await;
+        assert!(matches!(empty, Err(ApplicationError::Validation(_))));
+
+        let rfq = service
+            .select_quote_overriding_quorum(f.rfq_id, f.quote_id, None, "illiquid pair")
+            .await
+            .unwrap();
+
+        assert_eq!(rfq.state(), RfqState::ClientSelecting);
+        let events = publisher.events.lock();
+        let event = events.first().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(event.metadata.rfq_id, Some(f.rfq_id));
+        assert_eq!(event.client_id, CounterpartyId::new("client-1"));
+        assert_eq!(event.quote_id, f.quote_id);
+        assert_eq!(event.shortfall.quotes, 1);
+        assert_eq!(event.reason, "illiquid pair");
+    }
+
+    #[tokio::test]
+    async fn override_of_met_quorum_publishes_nothing() {
+        let f = fixture(QuoteFirmness::Firm, 100.0, Duration::ZERO);
+        let publisher = Arc::new(RecordingPublisher::default());
+        let service = f
+            .service
+            .with_quorum(quorum_from(1_000))
+            .with_event_publisher(publisher.clone());
+
+        service
+            .select_quote_overriding_quorum(f.rfq_id, f.quote_id, None, "not needed")
+            .await
+            .unwrap();
+
+        assert!(publisher.events.lock().is_empty());
+    }
+}
@@ -0,0 +1,353 @@
+//! # Forced State Transitions
+//!
+//! Administrative rescue of RFQs and trade settlements stuck in flight.
+//!
+//! When an execution or a settlement hangs — the venue or the chain never
+//! answered, but the outcome was established out of band — an administrator
+//! can force the aggregate into that outcome. Only the rescues allowed by
+//! [`RfqState::can_force_to`] and [`SettlementState::can_force_to`] are
+//! accepted, and each needs a justification.
+//!
+//! Every forced transition is audited as a [`ForcedStateTransition`] in the
+//! event store, written before the aggregate is saved so that no forced
+//! transition goes unrecorded, and is then published as an alert. A
+//! transition whose save fails after the audit stays recorded but is not
+//! applied; it can be forced again.
+
+use crate::application::error::{ApplicationError, ApplicationResult, InfrastructureError};
+use crate::domain::entities::trade::SettlementState;
+use crate::domain::events::admin_events::ForcedStateTransition;
+use crate::domain::value_objects::{RfqId, RfqState, TradeId};
+use crate::infrastructure::persistence::event_store::{EventStore, EventStoreError, StoredEvent};
+use crate::infrastructure::persistence::traits::{RepositoryError, RfqRepository, TradeRepository};
+use async_trait::async_trait;
+use std::fmt;
+use std::sync::Arc;
+
+/// Publisher for forced transition alerts.
+#[async_trait]
+pub trait ForcedTransitionEventPublisher: Send + Sync + fmt::Debug {
+    /// Publishes a forced state transition event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be published.
+    async fn publish_forced_state_transition(
+        &self,
+        event: ForcedStateTransition,
+    ) -> ApplicationResult<()>;
+}
+
+/// Forces stuck RFQs and trade settlements into their outcome state.
+#[derive(Debug)]
+pub struct ForcedTransitionService {
+    rfqs: Arc<dyn RfqRepository>,
+    trades: Arc<dyn TradeRepository>,
+    events: Arc<dyn EventStore>,
+    publisher: Arc<dyn ForcedTransitionEventPublisher>,
+}
+
+impl ForcedTransitionService {
+    /// Creates a service auditing to `events` and alerting through
+    /// `publisher`.
+    #[must_use]
+    pub fn new(
+        rfqs: Arc<dyn RfqRepository>,
+        trades: Arc<dyn TradeRepository>,
+        events: Arc<dyn EventStore>,
+        publisher: Arc<dyn ForcedTransitionEventPublisher>,
+    ) -> Self {
+        Self {
+            rfqs,
+            trades,
+            events,
+            publisher,
+        }
+    }
+
+    /// Forces an RFQ into `target`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::Validation` if `justification` is blank,
+    /// `ApplicationError::RfqNotFound` if the RFQ does not exist, and a
+    /// domain error if the transition is not an allowed rescue.
+    pub async fn force_rfq_state(
+        &self,
+        rfq_id: RfqId,
+        target: RfqState,
+        actor: &str,
+        justification: &str,
+    ) -> ApplicationResult<ForcedStateTransition> {
+        let justification = require_justification(justification)?;
+        let mut rfq = self
+            .rfqs
+            .get(rfq_id)
+            .await
+            .map_err(repository_error)?
+            .ok_or_else(|| ApplicationError::RfqNotFound(rfq_id.to_string()))?;
+
+        let from = rfq.force_state(target, justification)?;
+        let event = ForcedStateTransition::rfq(rfq_id, from, target, actor, justification);
+        self.audit(rfq_id, &event).await?;
+        self.rfqs.save(&rfq).await.map_err(repository_error)?;
+        self.alert(&event).await;
+
+        tracing::warn!(%rfq_id, actor, %from, to = %target, "RFQ state forced");
+        Ok(event)
+    }
+
+    /// Forces a trade's settlement into `target`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::Validation` if `justification` is blank,
+    /// `ApplicationError::NotFound` if the trade does not exist, and a
+    /// domain error if the transition is not an allowed rescue.
+    pub async fn force_settlement_state(
+        &self,
+        trade_id: TradeId,
+        target: SettlementState,
+        actor: &str,
+        justification: &str,
+    ) -> ApplicationResult<ForcedStateTransition> {
+        let justification = require_justification(justification)?;
+        let mut trade = self
+            .trades
+            .get(trade_id)
+            .await
+            .map_err(repository_error)?
+            .ok_or_else(|| ApplicationError::not_found("Trade", trade_id.to_string()))?;
+
+        let from = trade.force_settlement_state(target, justification)?;
+        let event = ForcedStateTransition::trade(
+            trade.rfq_id(),
+            trade_id,
+            from,
+            target,
+            actor,
+            justification,
+        );
+        self.audit(trade.rfq_id(), &event).await?;
+        self.trades.save(&trade).await.map_err(repository_error)?;
+        self.alert(&event).await;
+
+        tracing::warn!(%trade_id, actor, %from, to = %target, "Trade settlement state forced");
+        Ok(event)
+    }
+
+    async fn audit(&self, rfq_id: RfqId, event: &ForcedStateTransition) -> ApplicationResult<()> {
+        let sequence = self
+            .events
+            .next_sequence(rfq_id)
+            .await
+            .map_err(event_store_error)?;
+        let stored = StoredEvent::from_event(event, sequence).map_err(event_store_error)?;
+        self.events.append(stored).await.map_err(event_store_error)
+    }
+
+    async fn alert(&self, event: &ForcedStateTransition) {
+        if let Err(e) = self
+            .publisher
+            .publish_forced_state_transition(event.clone())
+            .await
+        {
+            tracing::warn!(
+                event_id = %event.metadata.event_id,
+                error = %e,
+                "Failed to publish forced transition alert"
+            );
+        }
+    }
+}
+
+fn require_justification(justification: &str) -> ApplicationResult<&str> {
+    let justification = justification.trim();
+    if justification.is_empty() {
+        return Err(ApplicationError::validation(
+            "a forced transition needs a justification",
+        ));
+    }
+    Ok(justification)
+}
+
+fn event_store_error(error: EventStoreError) -> ApplicationError {
+    InfrastructureError::database(error.to_string()).into()
+}
+
+fn repository_error(error: RepositoryError) -> ApplicationError {
+    InfrastructureError::Repository(error).into()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::rfq::Rfq;
+    use crate::domain::entities::trade::Trade;
+    use crate::domain::errors::DomainError;
+    use crate::domain::value_objects::{Price, QuoteId, VenueId};
+    use crate::infrastructure::persistence::in_memory::InMemoryEventStore;
+    use crate::test_support::{FixtureBuilder, Fixtures, RfqSeed};
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct RecordingPublisher {
+        events: Mutex<Vec<ForcedStateTransition>>,
+    }
+
+    #[async_trait]
+    impl ForcedTransitionEventPublisher for RecordingPublisher {
+        async fn publish_forced_state_transition(
+            &self,
+            event: ForcedStateTransition,
+        ) -> ApplicationResult<()> {
+            self.events.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    struct Harness {
+        fixtures: Fixtures,
+        events: Arc<InMemoryEventStore>,
+        publisher: Arc<RecordingPublisher>,
+        service: ForcedTransitionService,
+    }
+
+    async fn harness() -> Harness {
+        let fixtures = FixtureBuilder::new()
+            .with_rfq(RfqSeed::new("stuck", "client-1", "BTC/USD").with_quote("venue-1", 50_000.0))
+            .build()
+            .await
+            .unwrap();
+        let events = Arc::new(InMemoryEventStore::new());
+        let publisher = Arc::new(RecordingPublisher::default());
+        let service = ForcedTransitionService::new(
+            fixtures.rfqs.clone(),
+            fixtures.trades.clone(),
+            events.clone(),
+            publisher.clone(),
+        );
+        Harness {
+            fixtures,
+            events,
+            publisher,
+            service,
+        }
+    }
+
+    async fn start_execution(fixtures: &Fixtures) -> Rfq {
+        let mut rfq = fixtures.stored_rfq("stuck").await.unwrap().unwrap();
+        let quote_id = rfq.quotes()[0].id();
+        rfq.select_quote(quote_id).unwrap();
+        rfq.start_execution().unwrap();
+        fixtures.rfqs.save(&rfq).await.unwrap();
+        rfq
+    }
+
+    #[tokio::test]
+    async fn stuck_execution_is_forced_audited_and_alerted() {
+        let harness = harness().await;
+        let rfq = start_execution(&harness.fixtures).await;
+
+        let event = harness
+            .service
+            .force_rfq_state(
+                rfq.id(),
+                RfqState::Executed,
+                "ops-1",
+                " venue confirmed fill ",
+            )
+            .await
+            .unwrap();
+
+        let stored = harness.fixtures.rfqs.get(rfq.id()).await.unwrap().unwrap();
+        assert_eq!(stored.state(), RfqState::Executed);
+        assert_eq!(event.from, "EXECUTING");
+        assert_eq!(event.justification, "venue confirmed fill");
+
+        let audit = harness.events.get_events(rfq.id()).await.unwrap();
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].event_name, "ForcedStateTransition");
+        assert_eq!(audit[0].payload["actor"], "ops-1");
+        assert_eq!(audit[0].payload["to"], "EXECUTED");
+        assert_eq!(*harness.publisher.events.lock().unwrap(), vec![event]);
+    }
+
+    #[tokio::test]
+    async fn transitions_outside_the_allowlist_are_refused_unaudited() {
+        let harness = harness().await;
+        let rfq = harness.fixtures.rfq("stuck").unwrap().clone();
+
+        let result = harness
+            .service
+            .force_rfq_state(rfq.id(), RfqState::Executed, "ops-1", "looks done")
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(
+                DomainError::InvalidStateTransition { .. }
+            ))
+        ));
+        let stored = harness.fixtures.rfqs.get(rfq.id()).await.unwrap().unwrap();
+        assert_eq!(stored.state(), rfq.state());
+        assert!(
+            harness
+                .events
+                .get_events(rfq.id())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(harness.publisher.events.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn blank_justification_is_rejected() {
+        let harness = harness().await;
+        let rfq = start_execution(&harness.fixtures).await;
+
+        let result = harness
+            .service
+            .force_rfq_state(rfq.id(), RfqState::Failed, "ops-1", "  ")
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn stuck_settlement_is_forced_and_audited() {
+        let harness = harness().await;
+        let rfq = harness.fixtures.rfq("stuck").unwrap();
+        let mut trade = Trade::new(
+            rfq.id(),
+            QuoteId::new_v4(),
+            VenueId::new("venue-1"),
+            Price::new(50_000.0).unwrap(),
+            rfq.quantity(),
+        );
+        trade.start_settlement().unwrap();
+        harness.fixtures.trades.save(&trade).await.unwrap();
+
+        let event = harness
+            .service
+            .force_settlement_state(trade.id(), SettlementState::Failed, "ops-1", "tx dropped")
+            .await
+            .unwrap();
+
+        let stored = harness
+            .fixtures
+            .trades
+            .get(trade.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.settlement_state(), SettlementState::Failed);
+        assert_eq!(event.trade_id, Some(trade.id()));
+        assert_eq!(event.from, "IN_PROGRESS");
+        let audit = harness.events.get_events(rfq.id()).await.unwrap();
+        assert_eq!(audit.len(), 1);
+        assert_eq!(harness.publisher.events.lock().unwrap().len(), 1);
+    }
+}
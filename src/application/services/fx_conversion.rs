@@ -0,0 +1,254 @@
+//! # FX Conversion
+//!
+//! Normalizes amounts into a base currency.
+//!
+//! Counterparty limits, platform fee bands and best execution figures are
+//! kept in one configured base currency, while RFQs arrive on pairs quoted
+//! in BTC, ETH, EUR, ... [`FxConversionPort`] converts [`Money`] into the
+//! base currency so those comparisons are made in like units.
+//!
+//! [`ReferencePriceFxConverter`] derives rates from a
+//! [`ReferencePriceProvider`]: the reference price of the `FROM/TO` spot
+//! pair is the rate, failing that the inverse of the `TO/FROM` price. A
+//! pair with no price either way round fails with [`FxError::UnknownPair`]
+//! rather than converting at a default. Every conversion returns the
+//! [`FxRate`] applied, with its source and time, and is logged, so the
+//! rate behind a normalized figure can be audited.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use otc_rfq::application::services::fx_conversion::{
+//!     FxConversionPort, ReferencePriceFxConverter,
+//! };
+//!
+//! let fx = ReferencePriceFxConverter::new(reference_prices);
+//! let conversion = fx.convert(&notional, &Currency::usd()).await?;
+//! println!("{} at {:?}", conversion.converted, conversion.rate);
+//! ```
+
+use crate::application::services::price_bounds::ReferencePriceProvider;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    AssetClass, Currency, FxConversion, FxRate, Instrument, Money, MoneyError, Symbol,
+};
+use async_trait::async_trait;
+use std::fmt;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Error converting between currencies.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FxError {
+    /// No rate is known for the pair in either direction.
+    #[error("no FX rate for {from}/{to}")]
+    UnknownPair {
+        /// Currency converted from.
+        from: Currency,
+        /// Currency converted into.
+        to: Currency,
+    },
+
+    /// The rate source failed.
+    #[error("FX rate lookup failed: {0}")]
+    Provider(String),
+
+    /// The rate or the converted amount is invalid.
+    #[error(transparent)]
+    Money(#[from] MoneyError),
+}
+
+/// Converts money between currencies.
+#[async_trait]
+pub trait FxConversionPort: Send + Sync + fmt::Debug {
+    /// Returns the rate converting `from` into `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FxError::UnknownPair`] if no rate is known for the pair,
+    /// or [`FxError::Provider`] if the rate source failed.
+    async fn rate(&self, from: &Currency, to: &Currency) -> Result<FxRate, FxError>;
+
+    /// Converts `amount` into `to`, recording the rate applied.
+    ///
+    /// An amount already in `to` is returned unchanged, without a rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no rate is available or the conversion
+    /// overflows.
+    async fn convert(&self, amount: &Money, to: &Currency) -> Result<FxConversion, FxError> {
+        if amount.currency() == to {
+            return Ok(FxConversion::identity(amount.clone()));
+        }
+        let rate = self.rate(amount.currency(), to).await?;
+        let converted = amount.convert(&rate)?;
+        tracing::info!(
+            original = %amount,
+            converted = %converted,
+            rate = %rate.rate,
+            source = %rate.source,
+            as_of = %rate.as_of,
+            "FX conversion"
+        );
+        Ok(FxConversion {
+            original: amount.clone(),
+            converted,
+            rate: Some(rate),
+        })
+    }
+}
+
+/// [`FxConversionPort`] backed by spot reference prices.
+pub struct ReferencePriceFxConverter {
+    prices: Arc<dyn ReferencePriceProvider>,
+}
+
+impl ReferencePriceFxConverter {
+    /// Creates a converter reading rates from `prices`.
+    #[must_use]
+    pub fn new(prices: Arc<dyn ReferencePriceProvider>) -> Self {
+        Self { prices }
+    }
+
+    /// Returns the reference rate of the `base/quote` spot pair, if any.
+    async fn direct_rate(
+        &self,
+        base: &Currency,
+        quote: &Currency,
+    ) -> Result<Option<FxRate>, FxError> {
+        let symbol =
+            Symbol::new(format!("{base}/{quote}")).map_err(|e| FxError::Provider(e.to_string()))?;
+        let instrument = Instrument::builder(symbol, AssetClass::CryptoSpot).build();
+        let Some((price, source)) = self
+            .prices
+            .get_reference(&instrument)
+            .await
+            .map_err(|e| FxError::Provider(e.to_string()))?
+        else {
+            return Ok(None);
+        };
+        let rate = FxRate::new(
+            base.clone(),
+            quote.clone(),
+            price.get(),
+            source,
+            Timestamp::now(),
+        )?;
+        Ok(Some(rate))
+    }
+}
+
+impl fmt::Debug for ReferencePriceFxConverter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReferencePriceFxConverter")
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl FxConversionPort for ReferencePriceFxConverter {
+    async fn rate(&self, from: &Currency, to: &Currency) -> Result<FxRate, FxError> {
+        if let Some(rate) = self.direct_rate(from, to).await? {
+            return Ok(rate);
+        }
+        if let Some(rate) = self.direct_rate(to, from).await? {
+            return Ok(rate.inverse()?);
+        }
+        Err(FxError::UnknownPair {
+            from: from.clone(),
+            to: to.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::DomainResult;
+    use crate::domain::value_objects::{Price, ReferencePriceSource};
+    use rust_decimal::Decimal;
+    use std::collections::HashMap;
+
+    /// Reference prices by symbol.
+    struct FixedPrices(HashMap<String, f64>);
+
+    #[async_trait]
+    impl ReferencePriceProvider for FixedPrices {
+        async fn get_reference(
+            &self,
+            instrument: &Instrument,
+        ) -> DomainResult<Option<(Price, ReferencePriceSource)>> {
+            Ok(self
+                .0
+                .get(instrument.symbol().as_str())
+                .map(|price| (Price::new(*price).unwrap(), ReferencePriceSource::ClobMid)))
+        }
+    }
+
+    fn converter() -> ReferencePriceFxConverter {
+        ReferencePriceFxConverter::new(Arc::new(FixedPrices(HashMap::from([
+            ("EUR/USD".to_string(), 1.25),
+            ("BTC/USD".to_string(), 60_000.0),
+        ]))))
+    }
+
+    fn money(amount: i64, currency: &str) -> Money {
+        Money::new(Decimal::new(amount, 0), Currency::new(currency).unwrap())
+    }
+
+    #[tokio::test]
+    async fn converts_with_the_direct_pair_and_records_the_rate() {
+        let conversion = converter()
+            .convert(&money(1_000, "EUR"), &Currency::usd())
+            .await
+            .unwrap();
+
+        assert_eq!(conversion.original, money(1_000, "EUR"));
+        assert_eq!(conversion.converted, money(1_250, "USD"));
+        let rate = conversion.rate.unwrap();
+        assert_eq!(rate.rate, Decimal::new(125, 2));
+        assert_eq!(rate.source, ReferencePriceSource::ClobMid);
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_the_inverse_pair() {
+        let conversion = converter()
+            .convert(&money(125, "USD"), &Currency::new("EUR").unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            conversion.converted.amount().round_dp(8),
+            Decimal::new(100, 0)
+        );
+        let rate = conversion.rate.unwrap();
+        assert_eq!(rate.from, Currency::usd());
+        assert_eq!(rate.to.as_str(), "EUR");
+    }
+
+    #[tokio::test]
+    async fn same_currency_is_not_converted() {
+        let conversion = converter()
+            .convert(&money(5, "USD"), &Currency::usd())
+            .await
+            .unwrap();
+        assert_eq!(conversion.converted, money(5, "USD"));
+        assert!(conversion.rate.is_none());
+    }
+
+    #[tokio::test]
+    async fn unknown_pair_fails_explicitly() {
+        let result = converter()
+            .convert(&money(1, "ETH"), &Currency::usd())
+            .await;
+        assert_eq!(
+            result,
+            Err(FxError::UnknownPair {
+                from: Currency::new("ETH").unwrap(),
+                to: Currency::usd(),
+            })
+        );
+    }
+}
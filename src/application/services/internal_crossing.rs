@@ -0,0 +1,455 @@
+//! # Internal Crossing
+//!
+//! Matches opposing client RFQs before quotes are requested externally.
+//!
+//! When two clients are on opposite sides of the same instrument at
+//! compatible sizes, [`InternalCrossingService`] crosses them at the
+//! reference price instead of paying an external market maker's spread.
+//! Both RFQs receive a synthetic quote from [`VenueId::internal`] and an
+//! [`InternalCrossProposed`] event is published for each.
+//!
+//! # Eligibility
+//!
+//! - Both RFQs opted in via `RfqBuilder::allow_internal_crossing`.
+//! - The counterpart quotes the same symbol on the opposite side, belongs to
+//!   a different client, can still receive quotes, and has not expired.
+//! - The crossed quantity is the smaller of the two requests. An RFQ that
+//!   would be partially filled must allow it through `min_quantity`, and the
+//!   quantity must meet the instrument's minimum.
+//! - A reference price is available for the instrument.
+//!
+//! If any condition fails the RFQ proceeds to external venues unchanged.
+//!
+//! [`CollectQuotesUseCase::with_internal_crossing`](crate::application::use_cases::collect_quotes::CollectQuotesUseCase::with_internal_crossing)
+//! runs the cross before fanning out to venues.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::application::services::price_bounds::ReferencePriceProvider;
+use crate::domain::entities::quote::Quote;
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::events::InternalCrossProposed;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{Price, Quantity, RfqState, VenueId};
+use crate::infrastructure::persistence::traits::RfqRepository;
+use async_trait::async_trait;
+use std::fmt;
+use std::sync::Arc;
+
+/// Publisher for internal crossing events.
+#[async_trait]
+pub trait InternalCrossEventPublisher: Send + Sync + fmt::Debug {
+    /// Publishes an internal cross proposed event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be published.
+    async fn publish_internal_cross_proposed(
+        &self,
+        event: InternalCrossProposed,
+    ) -> ApplicationResult<()>;
+}
+
+/// Result of a successful internal cross.
+#[derive(Debug, Clone)]
+pub struct InternalCross {
+    /// The incoming RFQ, with the internal quote attached.
+    pub rfq: Rfq,
+    /// The opposing RFQ, with the internal quote attached.
+    pub counterpart: Rfq,
+    /// Cross price.
+    pub price: Price,
+    /// Crossed quantity.
+    pub quantity: Quantity,
+}
+
+/// Crosses opposing client RFQs at the reference price.
+///
+/// # Examples
+///
+/// ```ignore
+/// let service = InternalCrossingService::new(rfq_repository, reference_prices, publisher);
+///
+/// match service.try_cross(&rfq).await? {
+///     Some(cross) => select_internal_quote(cross.rfq),
+///     None => collect_external_quotes(rfq).await,
+/// }
+/// ```
+pub struct InternalCrossingService {
+    rfq_repository: Arc<dyn RfqRepository>,
+    reference_prices: Arc<dyn ReferencePriceProvider>,
+    event_publisher: Arc<dyn InternalCrossEventPublisher>,
+}
+
+impl fmt::Debug for InternalCrossingService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InternalCrossingService")
+            .field("rfq_repository", &self.rfq_repository)
+            .field("event_publisher", &self.event_publisher)
+            .finish_non_exhaustive()
+    }
+}
+
+impl InternalCrossingService {
+    /// Creates a new internal crossing service.
+    #[must_use]
+    pub fn new(
+        rfq_repository: Arc<dyn RfqRepository>,
+        reference_prices: Arc<dyn ReferencePriceProvider>,
+        event_publisher: Arc<dyn InternalCrossEventPublisher>,
+    ) -> Self {
+        Self {
+            rfq_repository,
+            reference_prices,
+            event_publisher,
+        }
+    }
+
+    /// Attempts to cross `rfq` against an opposing client RFQ.
+    ///
+    /// Call this after quote collection has started and before fanning out
+    /// to external venues. On success both RFQs are persisted with their
+    /// internal quotes; callers should continue with the returned RFQ. If
+    /// the RFQ cannot be saved after the counterpart was, the counterpart's
+    /// internal quote is withdrawn again.
+    /// Returns `None` for multi-leg RFQs and when no eligible counterpart or
+    /// reference price exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository, reference price provider, or
+    /// event publisher fails, or if a quote cannot be attached.
+    pub async fn try_cross(&self, rfq: &Rfq) -> ApplicationResult<Option<InternalCross>> {
+        if !rfq.allows_internal_crossing() || rfq.is_multi_leg() {
+            return Ok(None);
+        }
+
+        let candidates = self
+            .rfq_repository
+            .find_crossing_candidates(
+                rfq.instrument().symbol(),
+                rfq.side().opposite(),
+                Timestamp::now(),
+            )
+            .await
+            .map_err(|e| ApplicationError::repository(e.to_string()))?;
+
+        let Some((counterpart, quantity)) = candidates
+            .into_iter()
+            .filter(|c| c.id() != rfq.id() && c.client_id() != rfq.client_id())
+            .find_map(|c| cross_quantity(rfq, &c).map(|q| (c, q)))
+        else {
+            return Ok(None);
+        };
+
+        let Some((price, source)) = self
+            .reference_prices
+            .get_reference(rfq.instrument())
+            .await?
+        else {
+            tracing::debug!(
+                rfq_id = %rfq.id(),
+                "No reference price for internal cross, falling back to external venues"
+            );
+            return Ok(None);
+        };
+
+        let valid_until = rfq.expires_at().min(counterpart.expires_at());
+        let mut rfq = rfq.clone();
+        let mut counterpart = counterpart;
+        rfq.receive_quote(Quote::new(
+            rfq.id(),
+            VenueId::internal(),
+            price,
+            quantity,
+            valid_until,
+        )?)?;
+        counterpart.receive_quote(Quote::new(
+            counterpart.id(),
+            VenueId::internal(),
+            price,
+            quantity,
+            valid_until,
+        )?)?;
+
+        // Claim the counterpart first so a concurrent cross against it
+        // loses the optimistic lock.
+        self.rfq_repository
+            .save(&counterpart)
+            .await
+            .map_err(|e| ApplicationError::repository(e.to_string()))?;
+        if let Err(e) = self.rfq_repository.save(&rfq).await {
+            self.release_counterpart(&counterpart).await;
+            return Err(ApplicationError::repository(e.to_string()));
+        }
+
+        for (this, other) in [(&rfq, &counterpart), (&counterpart, &rfq)] {
+            self.event_publisher
+                .publish_internal_cross_proposed(InternalCrossProposed::new(
+                    this.id(),
+                    other.id(),
+                    this.instrument().clone(),
+                    this.side(),
+                    price,
+                    quantity,
+                    source,
+                ))
+                .await?;
+        }
+
+        tracing::info!(
+            rfq_id = %rfq.id(),
+            counterpart_rfq_id = %counterpart.id(),
+            %price,
+            %quantity,
+            "Internal cross proposed"
+        );
+
+        Ok(Some(InternalCross {
+            rfq,
+            counterpart,
+            price,
+            quantity,
+        }))
+    }
+
+    /// Withdraws the internal quote from a counterpart whose cross could
+    /// not be completed, so it is not left quoted against nobody.
+    async fn release_counterpart(&self, counterpart: &Rfq) {
+        let Some(quote_id) = counterpart
+            .quotes()
+            .iter()
+            .find(|q| q.venue_id() == &VenueId::internal())
+            .map(Quote::id)
+        else {
+            return;
+        };
+        let mut released = counterpart.clone();
+        let result = match released.withdraw_quote(quote_id) {
+            Ok(()) => self
+                .rfq_repository
+                .save(&released)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        if let Err(error) = result {
+            tracing::error!(
+                counterpart_rfq_id = %counterpart.id(),
+                %error,
+                "Failed to withdraw internal quote after incomplete cross"
+            );
+        }
+    }
+}
+
+/// Returns the quantity at which two opposing RFQs can cross, if any.
+fn cross_quantity(rfq: &Rfq, counterpart: &Rfq) -> Option<Quantity> {
+    let quantity = rfq.quantity().min(counterpart.quantity());
+    (accepts_fill(rfq, quantity) && accepts_fill(counterpart, quantity)).then_some(quantity)
+}
+
+/// Returns true if `rfq` can be filled with `quantity`.
+///
+/// A partial fill requires a `min_quantity` at or below `quantity`.
+fn accepts_fill(rfq: &Rfq, quantity: Quantity) -> bool {
+    let size_ok =
+        quantity == rfq.quantity() || rfq.min_quantity().is_some_and(|min| quantity >= min);
+    let instrument_ok = rfq
+        .instrument()
+        .min_quantity()
+        .is_none_or(|min| quantity >= min);
+    let state_ok = matches!(
+        rfq.state(),
+        RfqState::QuoteRequesting | RfqState::QuotesReceived
+    );
+    size_ok && instrument_ok && state_ok
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::errors::DomainResult;
+    use crate::domain::value_objects::enums::AssetClass;
+    use crate::domain::value_objects::{
+        CounterpartyId, Instrument, OrderSide, ReferencePriceSource, Symbol,
+    };
+    use crate::infrastructure::persistence::in_memory::InMemoryRfqRepository;
+    use std::sync::Mutex;
+
+    #[derive(Debug)]
+    struct FixedReferencePrice(Option<Price>);
+
+    #[async_trait]
+    impl ReferencePriceProvider for FixedReferencePrice {
+        async fn get_reference(
+            &self,
+            _instrument: &Instrument,
+        ) -> DomainResult<Option<(Price, ReferencePriceSource)>> {
+            Ok(self.0.map(|p| (p, ReferencePriceSource::ClobMid)))
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingPublisher {
+        events: Mutex<Vec<InternalCrossProposed>>,
+    }
+
+    #[async_trait]
+    impl InternalCrossEventPublisher for RecordingPublisher {
+        async fn publish_internal_cross_proposed(
+            &self,
+            event: InternalCrossProposed,
+        ) -> ApplicationResult<()> {
+            self.events.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    struct Harness {
+        repo: Arc<InMemoryRfqRepository>,
+        publisher: Arc<RecordingPublisher>,
+        service: InternalCrossingService,
+    }
+
+    fn harness() -> Harness {
+        let repo = Arc::new(InMemoryRfqRepository::new());
+        let publisher = Arc::new(RecordingPublisher::default());
+        let service = InternalCrossingService::new(
+            repo.clone(),
+            Arc::new(FixedReferencePrice(Some(Price::new(50000.0).unwrap()))),
+            publisher.clone(),
+        );
+        Harness {
+            repo,
+            publisher,
+            service,
+        }
+    }
+
+    fn rfq(client: &str, side: OrderSide, quantity: f64, crossing: bool) -> RfqBuilder {
+        let instrument =
+            Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build();
+        RfqBuilder::new(
+            CounterpartyId::new(client),
+            instrument,
+            side,
+            Quantity::new(quantity).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .allow_internal_crossing(crossing)
+    }
+
+    async fn requesting(repo: &InMemoryRfqRepository, builder: RfqBuilder) -> Rfq {
+        let mut rfq = builder.build();
+        rfq.start_quote_collection().unwrap();
+        repo.save(&rfq).await.unwrap();
+        rfq
+    }
+
+    #[tokio::test]
+    async fn crosses_opposing_rfqs_at_reference_price() {
+        let h = harness();
+        let resting = requesting(&h.repo, rfq("client-a", OrderSide::Sell, 2.0, true)).await;
+        let incoming = requesting(&h.repo, rfq("client-b", OrderSide::Buy, 2.0, true)).await;
+
+        let cross = h.service.try_cross(&incoming).await.unwrap().unwrap();
+
+        assert_eq!(cross.counterpart.id(), resting.id());
+        assert_eq!(cross.price, Price::new(50000.0).unwrap());
+        assert_eq!(cross.quantity, Quantity::new(2.0).unwrap());
+        for id in [incoming.id(), resting.id()] {
+            let stored = h.repo.get(id).await.unwrap().unwrap();
+            assert_eq!(stored.state(), RfqState::QuotesReceived);
+            assert_eq!(stored.quotes().len(), 1);
+            assert!(stored.quotes().iter().all(|q| q.venue_id().is_internal()));
+        }
+
+        let events = h.publisher.events.lock().unwrap().clone();
+        let [incoming_event, resting_event] = events.as_slice() else {
+            unreachable!("expected one event per RFQ");
+        };
+        assert_eq!(incoming_event.metadata.rfq_id, Some(incoming.id()));
+        assert_eq!(incoming_event.counterpart_rfq_id, resting.id());
+        assert_eq!(resting_event.side, OrderSide::Sell);
+    }
+
+    #[tokio::test]
+    async fn partial_cross_respects_min_quantity() {
+        let h = harness();
+        requesting(&h.repo, rfq("client-a", OrderSide::Sell, 4.0, true)).await;
+        let incoming = requesting(
+            &h.repo,
+            rfq("client-b", OrderSide::Buy, 10.0, true).min_quantity(Quantity::new(3.0).unwrap()),
+        )
+        .await;
+
+        let cross = h.service.try_cross(&incoming).await.unwrap().unwrap();
+        assert_eq!(cross.quantity, Quantity::new(4.0).unwrap());
+    }
+
+    #[tokio::test]
+    async fn size_mismatch_falls_back_to_external() {
+        let h = harness();
+        requesting(&h.repo, rfq("client-a", OrderSide::Sell, 4.0, true)).await;
+        let incoming = requesting(&h.repo, rfq("client-b", OrderSide::Buy, 10.0, true)).await;
+
+        assert!(h.service.try_cross(&incoming).await.unwrap().is_none());
+        let stored = h.repo.get(incoming.id()).await.unwrap().unwrap();
+        assert!(stored.quotes().is_empty());
+        assert!(h.publisher.events.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn opted_out_rfqs_are_not_crossed() {
+        let h = harness();
+        requesting(&h.repo, rfq("client-a", OrderSide::Sell, 2.0, false)).await;
+        let incoming = requesting(&h.repo, rfq("client-b", OrderSide::Buy, 2.0, true)).await;
+        assert!(h.service.try_cross(&incoming).await.unwrap().is_none());
+
+        requesting(&h.repo, rfq("client-c", OrderSide::Sell, 2.0, true)).await;
+        let opted_out = requesting(&h.repo, rfq("client-d", OrderSide::Buy, 2.0, false)).await;
+        assert!(h.service.try_cross(&opted_out).await.unwrap().is_none());
+        assert!(h.publisher.events.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_save_of_incoming_rfq_releases_counterpart() {
+        let h = harness();
+        let resting = requesting(&h.repo, rfq("client-a", OrderSide::Sell, 2.0, true)).await;
+        let incoming = requesting(&h.repo, rfq("client-b", OrderSide::Buy, 2.0, true)).await;
+
+        // A concurrent update makes the incoming RFQ's save lose the lock.
+        let mut concurrent = incoming.clone();
+        concurrent
+            .receive_quote(
+                Quote::new(
+                    incoming.id(),
+                    VenueId::new("venue-x"),
+                    Price::new(50100.0).unwrap(),
+                    Quantity::new(2.0).unwrap(),
+                    Timestamp::now().add_secs(60),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        h.repo.save(&concurrent).await.unwrap();
+
+        assert!(h.service.try_cross(&incoming).await.is_err());
+
+        let stored = h.repo.get(resting.id()).await.unwrap().unwrap();
+        assert_eq!(stored.state(), RfqState::QuoteRequesting);
+        assert!(stored.quotes().is_empty());
+        assert!(h.publisher.events.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn same_client_is_not_crossed() {
+        let h = harness();
+        requesting(&h.repo, rfq("client-a", OrderSide::Sell, 2.0, true)).await;
+        let incoming = requesting(&h.repo, rfq("client-a", OrderSide::Buy, 2.0, true)).await;
+
+        assert!(h.service.try_cross(&incoming).await.unwrap().is_none());
+    }
+}
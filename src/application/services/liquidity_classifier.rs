@@ -0,0 +1,645 @@
+//! # Liquidity Classifier
+//!
+//! Derives an instrument's [`LiquidityClassification`] from recent market
+//! activity instead of trusting the tier the caller declared.
+//!
+//! Three rolling metrics are measured per symbol:
+//!
+//! - executed notional over the window (30 days by default), from the
+//!   trades of the symbol's RFQs
+//! - average number of quotes per RFQ over the same window
+//! - average top-of-book spread, sampled from the CLOB at every
+//!   recomputation
+//!
+//! An instrument is liquid if it meets every liquid threshold, semi-liquid
+//! if it meets every semi-liquid threshold, and illiquid otherwise. With no
+//! spread samples (no order book), only volume and quote count decide.
+//!
+//! Assessments are cached for a TTL. [`LiquidityClassifier::run`] recomputes
+//! the tracked symbols in the background, and synchronous consumers such as
+//! the price bounds validator and the quorum rules read the cache through
+//! [`LiquidityClassifier::classification_for`], falling back to the
+//! instrument's declared tier.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::application::services::clob_mid::ClobMidReferencePriceProvider;
+use crate::domain::value_objects::instrument::Instrument;
+use crate::domain::value_objects::liquidity_classification::LiquidityClassification;
+use crate::domain::value_objects::timestamp::{Clock, SystemClock, Timestamp};
+use crate::domain::value_objects::{AssetClass, Symbol};
+use crate::infrastructure::persistence::cursor::PageCursor;
+use crate::infrastructure::persistence::traits::{RfqListFilter, RfqRepository, TradeRepository};
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Default window for volume and quote count metrics.
+pub const DEFAULT_LIQUIDITY_WINDOW: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// Default time an assessment stays fresh.
+pub const DEFAULT_LIQUIDITY_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Default interval between background recomputations.
+pub const DEFAULT_LIQUIDITY_REFRESH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Spread samples kept per symbol.
+const MAX_SPREAD_SAMPLES: usize = 288;
+
+/// RFQs read from the repository per page.
+const RFQ_PAGE_SIZE: usize = 500;
+
+/// Minimum activity an instrument needs to qualify for a tier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiquidityThresholds {
+    /// Minimum executed notional over the window.
+    pub min_volume: Decimal,
+    /// Minimum average number of quotes per RFQ.
+    pub min_avg_quotes: Decimal,
+    /// Maximum average top-of-book spread, in basis points.
+    pub max_spread_bps: Decimal,
+}
+
+impl LiquidityThresholds {
+    /// Returns true if `metrics` meet every threshold.
+    ///
+    /// A missing spread does not count against the instrument.
+    #[must_use]
+    pub fn are_met_by(&self, metrics: &LiquidityMetrics) -> bool {
+        metrics.volume >= self.min_volume
+            && metrics.avg_quotes_per_rfq >= self.min_avg_quotes
+            && metrics
+                .avg_spread_bps
+                .is_none_or(|spread| spread <= self.max_spread_bps)
+    }
+}
+
+/// Tier thresholds and timing of the liquidity classifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiquidityClassifierConfig {
+    /// Thresholds for the liquid tier.
+    pub liquid: LiquidityThresholds,
+    /// Thresholds for the semi-liquid tier.
+    pub semi_liquid: LiquidityThresholds,
+    /// Window of the volume and quote count metrics.
+    pub window: Duration,
+    /// Time an assessment stays fresh.
+    pub ttl: Duration,
+}
+
+impl LiquidityClassifierConfig {
+    /// Returns the tier `metrics` qualify for.
+    #[must_use]
+    pub fn classify(&self, metrics: &LiquidityMetrics) -> LiquidityClassification {
+        if self.liquid.are_met_by(metrics) {
+            LiquidityClassification::Liquid
+        } else if self.semi_liquid.are_met_by(metrics) {
+            LiquidityClassification::SemiLiquid
+        } else {
+            LiquidityClassification::Illiquid
+        }
+    }
+}
+
+impl Default for LiquidityClassifierConfig {
+    /// Liquid from 50M notional, 3 quotes per RFQ and 10 bps; semi-liquid
+    /// from 5M, 2 quotes and 50 bps, over 30 days.
+    fn default() -> Self {
+        Self {
+            liquid: LiquidityThresholds {
+                min_volume: Decimal::from(50_000_000),
+                min_avg_quotes: Decimal::from(3),
+                max_spread_bps: Decimal::from(10),
+            },
+            semi_liquid: LiquidityThresholds {
+                min_volume: Decimal::from(5_000_000),
+                min_avg_quotes: Decimal::from(2),
+                max_spread_bps: Decimal::from(50),
+            },
+            window: DEFAULT_LIQUIDITY_WINDOW,
+            ttl: DEFAULT_LIQUIDITY_TTL,
+        }
+    }
+}
+
+/// Rolling activity metrics of an instrument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct LiquidityMetrics {
+    /// Executed notional over the window.
+    pub volume: Decimal,
+    /// RFQs created over the window.
+    pub rfq_count: u64,
+    /// Average number of quotes per RFQ over the window.
+    pub avg_quotes_per_rfq: Decimal,
+    /// Average sampled top-of-book spread in basis points, if the
+    /// instrument has an order book.
+    pub avg_spread_bps: Option<Decimal>,
+}
+
+/// A computed liquidity tier and the metrics behind it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiquidityAssessment {
+    /// The instrument symbol.
+    pub symbol: Symbol,
+    /// The derived tier.
+    pub classification: LiquidityClassification,
+    /// The metrics the tier was derived from.
+    pub metrics: LiquidityMetrics,
+    /// When the assessment was computed.
+    pub computed_at: Timestamp,
+}
+
+#[derive(Debug)]
+struct TrackedInstrument {
+    instrument: Instrument,
+    assessment: Option<LiquidityAssessment>,
+    spreads: VecDeque<Decimal>,
+}
+
+/// Derives and caches liquidity tiers from trades, quotes and spreads.
+///
+/// # Examples
+///
+/// ```ignore
+/// let classifier = Arc::new(LiquidityClassifier::new(rfqs, trades).with_clob(clob_mid));
+///
+/// let background = Arc::clone(&classifier);
+/// tokio::spawn(async move { background.run(DEFAULT_LIQUIDITY_REFRESH_INTERVAL).await });
+/// ```
+#[derive(Debug)]
+pub struct LiquidityClassifier {
+    rfqs: Arc<dyn RfqRepository>,
+    trades: Arc<dyn TradeRepository>,
+    clob: Option<Arc<ClobMidReferencePriceProvider>>,
+    config: LiquidityClassifierConfig,
+    clock: Arc<dyn Clock>,
+    tracked: Mutex<HashMap<Symbol, TrackedInstrument>>,
+}
+
+impl LiquidityClassifier {
+    /// Creates a classifier with the default thresholds and no order book.
+    #[must_use]
+    pub fn new(rfqs: Arc<dyn RfqRepository>, trades: Arc<dyn TradeRepository>) -> Self {
+        Self {
+            rfqs,
+            trades,
+            clob: None,
+            config: LiquidityClassifierConfig::default(),
+            clock: Arc::new(SystemClock),
+            tracked: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets the CLOB provider spreads are sampled from.
+    #[must_use]
+    pub fn with_clob(mut self, clob: Arc<ClobMidReferencePriceProvider>) -> Self {
+        self.clob = Some(clob);
+        self
+    }
+
+    /// Sets the tier thresholds, window and TTL.
+    #[must_use]
+    pub fn with_config(mut self, config: LiquidityClassifierConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the clock used for the window and the TTL.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the classifier configuration.
+    #[inline]
+    #[must_use]
+    pub const fn config(&self) -> &LiquidityClassifierConfig {
+        &self.config
+    }
+
+    /// Returns the tier of `instrument` from a fresh cached assessment, or
+    /// its declared tier if there is none.
+    ///
+    /// The instrument is tracked, so the background task assesses it from
+    /// then on.
+    #[must_use]
+    pub fn classification_for(&self, instrument: &Instrument) -> LiquidityClassification {
+        let now = self.clock.now();
+        let mut tracked = self.tracked.lock();
+        let entry = tracked
+            .entry(instrument.symbol().clone())
+            .or_insert_with(|| TrackedInstrument {
+                instrument: instrument.clone(),
+                assessment: None,
+                spreads: VecDeque::new(),
+            });
+        entry
+            .assessment
+            .as_ref()
+            .filter(|assessment| self.is_fresh(assessment, now))
+            .map_or(instrument.liquidity(), |assessment| {
+                assessment.classification
+            })
+    }
+
+    /// Returns the assessment of `instrument`, recomputing it if the cached
+    /// one is missing or stale.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::RepositoryError` if RFQs or trades cannot
+    /// be read.
+    pub async fn classify(
+        &self,
+        instrument: &Instrument,
+    ) -> ApplicationResult<LiquidityAssessment> {
+        let now = self.clock.now();
+        let cached = self
+            .tracked
+            .lock()
+            .get(instrument.symbol())
+            .and_then(|entry| entry.assessment.clone())
+            .filter(|assessment| self.is_fresh(assessment, now));
+        match cached {
+            Some(assessment) => Ok(assessment),
+            None => self.refresh(instrument).await,
+        }
+    }
+
+    /// Returns the assessment of the instrument with `symbol`.
+    ///
+    /// Symbols not seen before are assessed as spot instruments.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::RepositoryError` if RFQs or trades cannot
+    /// be read.
+    pub async fn classify_symbol(&self, symbol: &Symbol) -> ApplicationResult<LiquidityAssessment> {
+        let instrument = self
+            .tracked
+            .lock()
+            .get(symbol)
+            .map(|entry| entry.instrument.clone());
+        let instrument = instrument
+            .unwrap_or_else(|| Instrument::builder(symbol.clone(), AssetClass::CryptoSpot).build());
+        self.classify(&instrument).await
+    }
+
+    /// Recomputes and caches the assessment of `instrument`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::RepositoryError` if RFQs or trades cannot
+    /// be read.
+    pub async fn refresh(&self, instrument: &Instrument) -> ApplicationResult<LiquidityAssessment> {
+        let spread = self.sample_spread(instrument).await;
+        let (volume, rfq_count, quote_count) = self.activity(instrument.symbol()).await?;
+
+        let mut tracked = self.tracked.lock();
+        let entry = tracked
+            .entry(instrument.symbol().clone())
+            .or_insert_with(|| TrackedInstrument {
+                instrument: instrument.clone(),
+                assessment: None,
+                spreads: VecDeque::new(),
+            });
+        if let Some(spread) = spread {
+            if entry.spreads.len() == MAX_SPREAD_SAMPLES {
+                entry.spreads.pop_front();
+            }
+            entry.spreads.push_back(spread);
+        }
+
+        let metrics = LiquidityMetrics {
+            volume,
+            rfq_count,
+            avg_quotes_per_rfq: average(Decimal::from(quote_count), rfq_count),
+            avg_spread_bps: (!entry.spreads.is_empty()).then(|| {
+                average(
+                    entry.spreads.iter().sum(),
+                    u64::try_from(entry.spreads.len()).unwrap_or(u64::MAX),
+                )
+            }),
+        };
+        let assessment = LiquidityAssessment {
+            symbol: instrument.symbol().clone(),
+            classification: self.config.classify(&metrics),
+            metrics,
+            computed_at: self.clock.now(),
+        };
+        if entry.assessment.as_ref().map(|a| a.classification) != Some(assessment.classification) {
+            tracing::info!(
+                symbol = %assessment.symbol,
+                classification = %assessment.classification,
+                volume = %metrics.volume,
+                avg_quotes_per_rfq = %metrics.avg_quotes_per_rfq,
+                "instrument liquidity classified"
+            );
+        }
+        entry.assessment = Some(assessment.clone());
+        Ok(assessment)
+    }
+
+    /// Recomputes every tracked instrument.
+    ///
+    /// Failures are logged; the previous assessment stays cached until its
+    /// TTL runs out. Returns the number of instruments recomputed.
+    pub async fn refresh_all(&self) -> usize {
+        let instruments: Vec<Instrument> = self
+            .tracked
+            .lock()
+            .values()
+            .map(|entry| entry.instrument.clone())
+            .collect();
+
+        let mut refreshed = 0;
+        for instrument in &instruments {
+            match self.refresh(instrument).await {
+                Ok(_) => refreshed += 1,
+                Err(e) => tracing::warn!(
+                    symbol = %instrument.symbol(),
+                    error = %e,
+                    "liquidity classification failed"
+                ),
+            }
+        }
+        refreshed
+    }
+
+    /// Recomputes the tracked instruments every `interval`, forever.
+    pub async fn run(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            let refreshed = self.refresh_all().await;
+            tracing::debug!(refreshed, "liquidity classification refresh completed");
+        }
+    }
+
+    fn is_fresh(&self, assessment: &LiquidityAssessment, now: Timestamp) -> bool {
+        assessment.computed_at.duration_until(&now) < self.config.ttl
+    }
+
+    async fn sample_spread(&self, instrument: &Instrument) -> Option<Decimal> {
+        let clob = self.clob.as_ref()?;
+        match clob.spread_bps(instrument).await {
+            Ok(spread) => spread,
+            Err(e) => {
+                tracing::warn!(
+                    symbol = %instrument.symbol(),
+                    error = %e,
+                    "order book spread unavailable"
+                );
+                None
+            }
+        }
+    }
+
+    /// Returns the executed notional, RFQ count and quote count of
+    /// `symbol` over the window.
+    async fn activity(&self, symbol: &Symbol) -> ApplicationResult<(Decimal, u64, u64)> {
+        let now = self.clock.now();
+        let window = i64::try_from(self.config.window.as_millis()).unwrap_or(i64::MAX);
+        let from = now.add_millis(window.saturating_neg());
+        let filter = RfqListFilter {
+            symbol: Some(symbol.clone()),
+            created_from: Some(from),
+            ..RfqListFilter::default()
+        };
+
+        let mut volume = Decimal::ZERO;
+        let mut rfq_count = 0u64;
+        let mut quote_count = 0u64;
+        let mut cursor: Option<PageCursor> = None;
+        loop {
+            let page = self
+                .rfqs
+                .list_after(cursor.as_ref(), RFQ_PAGE_SIZE, &filter)
+                .await
+                .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?;
+            for rfq in &page {
+                rfq_count += 1;
+                quote_count += u64::try_from(rfq.quotes().len()).unwrap_or(u64::MAX);
+                let trade = self
+                    .trades
+                    .get_by_rfq(rfq.id())
+                    .await
+                    .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?;
+                if let Some(trade) = trade.filter(|trade| trade.created_at() >= from) {
+                    volume = trade
+                        .price()
+                        .get()
+                        .checked_mul(trade.quantity().get())
+                        .and_then(|notional| volume.checked_add(notional))
+                        .unwrap_or(Decimal::MAX);
+                }
+            }
+            match page.last() {
+                Some(last) if page.len() == RFQ_PAGE_SIZE => {
+                    cursor = Some(PageCursor::from_rfq(last));
+                }
+                _ => break,
+            }
+        }
+        Ok((volume, rfq_count, quote_count))
+    }
+}
+
+fn average(total: Decimal, count: u64) -> Decimal {
+    if count == 0 {
+        return Decimal::ZERO;
+    }
+    total
+        .checked_div(Decimal::from(count))
+        .unwrap_or(Decimal::ZERO)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::application::services::clob_mid::{OrderBookSnapshot, OrderBookSnapshotPort};
+    use crate::domain::entities::quote::QuoteBuilder;
+    use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::entities::trade::Trade;
+    use crate::domain::errors::DomainResult;
+    use crate::domain::value_objects::timestamp::MockClock;
+    use crate::domain::value_objects::{
+        CounterpartyId, OrderSide, Price, Quantity, QuoteId, VenueId,
+    };
+    use crate::infrastructure::persistence::in_memory::{
+        InMemoryRfqRepository, InMemoryTradeRepository,
+    };
+    use async_trait::async_trait;
+
+    #[derive(Debug)]
+    struct FixedBook(OrderBookSnapshot);
+
+    #[async_trait]
+    impl OrderBookSnapshotPort for FixedBook {
+        async fn snapshot(
+            &self,
+            _instrument: &Instrument,
+        ) -> DomainResult<Option<OrderBookSnapshot>> {
+            Ok(Some(self.0))
+        }
+    }
+
+    fn instrument() -> Instrument {
+        Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot)
+            .liquidity(LiquidityClassification::SemiLiquid)
+            .build()
+    }
+
+    /// Stores one RFQ per entry of `quote_counts`, each with that many
+    /// quotes from distinct venues, and a trade of `notionals[i]` on the
+    /// i-th RFQ.
+    async fn history(
+        quote_counts: &[usize],
+        notionals: &[u64],
+    ) -> (Arc<InMemoryRfqRepository>, Arc<InMemoryTradeRepository>) {
+        let rfqs = Arc::new(InMemoryRfqRepository::new());
+        let trades = Arc::new(InMemoryTradeRepository::new());
+        for (i, quote_count) in quote_counts.iter().enumerate() {
+            let mut rfq = RfqBuilder::new(
+                CounterpartyId::new("client-1"),
+                instrument(),
+                OrderSide::Buy,
+                Quantity::new(1.0).unwrap(),
+                Timestamp::now().add_secs(300),
+            )
+            .build();
+            rfq.start_quote_collection().unwrap();
+            for venue in 0..*quote_count {
+                rfq.receive_quote(
+                    QuoteBuilder::new(
+                        rfq.id(),
+                        VenueId::new(format!("mm-{venue}")),
+                        Price::new(100.0).unwrap(),
+                        Quantity::new(1.0).unwrap(),
+                        Timestamp::now().add_secs(60),
+                    )
+                    .build(),
+                )
+                .unwrap();
+            }
+            if let Some(notional) = notionals.get(i) {
+                trades
+                    .save(&Trade::new(
+                        rfq.id(),
+                        QuoteId::new_v4(),
+                        VenueId::new("mm-0"),
+                        Price::from_decimal(Decimal::from(*notional)).unwrap(),
+                        Quantity::new(1.0).unwrap(),
+                    ))
+                    .await
+                    .unwrap();
+            }
+            rfqs.save(&rfq).await.unwrap();
+        }
+        (rfqs, trades)
+    }
+
+    async fn classify(quote_counts: &[usize], notionals: &[u64]) -> LiquidityAssessment {
+        let (rfqs, trades) = history(quote_counts, notionals).await;
+        LiquidityClassifier::new(rfqs, trades)
+            .refresh(&instrument())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn volume_and_quote_count_boundaries_select_each_tier() {
+        let liquid = classify(&[3, 3], &[25_000_000, 25_000_000]).await;
+        assert_eq!(liquid.classification, LiquidityClassification::Liquid);
+        assert_eq!(liquid.metrics.volume, Decimal::from(50_000_000));
+        assert_eq!(liquid.metrics.rfq_count, 2);
+        assert_eq!(liquid.metrics.avg_quotes_per_rfq, Decimal::from(3));
+        assert_eq!(liquid.metrics.avg_spread_bps, None);
+
+        for (quote_counts, notionals) in [
+            (&[3, 3][..], &[25_000_000, 24_999_999][..]),
+            (&[3, 2][..], &[25_000_000, 25_000_000][..]),
+            (&[2, 2][..], &[2_500_000, 2_500_000][..]),
+        ] {
+            assert_eq!(
+                classify(quote_counts, notionals).await.classification,
+                LiquidityClassification::SemiLiquid
+            );
+        }
+
+        for (quote_counts, notionals) in [
+            (&[2, 2][..], &[2_500_000, 2_499_999][..]),
+            (&[2, 1][..], &[25_000_000, 25_000_000][..]),
+            (&[][..], &[][..]),
+        ] {
+            assert_eq!(
+                classify(quote_counts, notionals).await.classification,
+                LiquidityClassification::Illiquid
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn wide_spread_demotes_tier() {
+        let (rfqs, trades) = history(&[3, 3], &[25_000_000, 25_000_000]).await;
+        let book = |bid: f64, ask: f64| {
+            Arc::new(ClobMidReferencePriceProvider::new(Arc::new(FixedBook(
+                OrderBookSnapshot::new(
+                    Some(Price::new(bid).unwrap()),
+                    Some(Price::new(ask).unwrap()),
+                    Timestamp::now(),
+                ),
+            ))))
+        };
+
+        // 0.1 / 100 = 10 bps, at the liquid maximum.
+        let at_limit = LiquidityClassifier::new(rfqs.clone(), trades.clone())
+            .with_clob(book(99.95, 100.05))
+            .refresh(&instrument())
+            .await
+            .unwrap();
+        assert_eq!(at_limit.classification, LiquidityClassification::Liquid);
+        assert_eq!(at_limit.metrics.avg_spread_bps, Some(Decimal::from(10)));
+
+        let wide = LiquidityClassifier::new(rfqs, trades)
+            .with_clob(book(99.9, 100.1))
+            .refresh(&instrument())
+            .await
+            .unwrap();
+        assert_eq!(wide.classification, LiquidityClassification::SemiLiquid);
+    }
+
+    #[tokio::test]
+    async fn cached_tier_expires_and_old_activity_leaves_the_window() {
+        let (rfqs, trades) = history(&[3, 3], &[25_000_000, 25_000_000]).await;
+        let clock = Arc::new(MockClock::new(Timestamp::now()));
+        let classifier = LiquidityClassifier::new(rfqs, trades).with_clock(clock.clone());
+
+        // Untracked: the declared tier until the background task runs.
+        assert_eq!(
+            classifier.classification_for(&instrument()),
+            LiquidityClassification::SemiLiquid
+        );
+        assert_eq!(classifier.refresh_all().await, 1);
+        assert_eq!(
+            classifier.classification_for(&instrument()),
+            LiquidityClassification::Liquid
+        );
+
+        clock.advance(DEFAULT_LIQUIDITY_TTL);
+        assert_eq!(
+            classifier.classification_for(&instrument()),
+            LiquidityClassification::SemiLiquid
+        );
+
+        clock.advance(DEFAULT_LIQUIDITY_WINDOW);
+        let symbol = Symbol::new("BTC/USD").unwrap();
+        let assessment = classifier.classify_symbol(&symbol).await.unwrap();
+        assert_eq!(assessment.classification, LiquidityClassification::Illiquid);
+        assert_eq!(assessment.metrics.rfq_count, 0);
+    }
+}
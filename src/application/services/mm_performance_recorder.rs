@@ -0,0 +1,174 @@
+//! # MM Performance Recorder
+//!
+//! Feeds market maker performance events from the RFQ flow.
+//!
+//! [`MmPerformanceRecorder`] is attached to the use cases that see each step
+//! of an RFQ's life:
+//!
+//! | Step | Event | Recorded by |
+//! |------|-------|-------------|
+//! | Fan-out to a venue | `RfqSent` | `CollectQuotesUseCase` |
+//! | Quote ranked | `QuoteReceived` | `CollectQuotesUseCase` |
+//! | Quote sent for execution | `AcceptRequested` | `ExecuteTradeUseCase` |
+//! | Last-look quote declined at execution | `LastLookReject` | `ExecuteTradeUseCase` |
+//! | Trade filled | `TradeExecuted` | `ExecuteTradeUseCase` |
+//!
+//! Events are keyed by the market maker behind the venue: the venue's
+//! [`mm_counterparty_id`](Venue::mm_counterparty_id) when one is set, and
+//! the venue ID otherwise. Each event is stamped when it is recorded, then
+//! resolved and stored in the background so the RFQ flow never waits on the
+//! performance repository. [`flush`](MmPerformanceRecorder::flush) waits for
+//! the events still being stored.
+
+use crate::domain::entities::mm_performance::{MmPerformanceEvent, MmPerformanceEventKind};
+use crate::domain::entities::venue::Venue;
+use crate::domain::services::mm_performance::MmPerformanceTracker;
+use crate::domain::value_objects::{CounterpartyId, VenueId};
+use async_trait::async_trait;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::sync::Notify;
+
+/// Venue storage used to find the market maker behind a venue.
+#[async_trait]
+pub trait PerformanceVenueRepository: Send + Sync + fmt::Debug {
+    /// Finds a venue by ID.
+    async fn find_by_id(&self, id: &VenueId) -> Result<Option<Venue>, String>;
+}
+
+/// Records market maker performance events without blocking the caller.
+#[derive(Debug)]
+pub struct MmPerformanceRecorder {
+    tracker: Arc<MmPerformanceTracker>,
+    venues: Option<Arc<dyn PerformanceVenueRepository>>,
+    pending: Arc<AtomicUsize>,
+    idle: Arc<Notify>,
+}
+
+impl MmPerformanceRecorder {
+    /// Creates a recorder that keys events by venue ID.
+    #[must_use]
+    pub fn new(tracker: Arc<MmPerformanceTracker>) -> Self {
+        Self {
+            tracker,
+            venues: None,
+            pending: Arc::new(AtomicUsize::new(0)),
+            idle: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Resolves the market maker behind each venue from `venues`.
+    #[must_use]
+    pub fn with_venue_repository(mut self, venues: Arc<dyn PerformanceVenueRepository>) -> Self {
+        self.venues = Some(venues);
+        self
+    }
+
+    /// Returns the tracker events are recorded in.
+    #[must_use]
+    pub fn tracker(&self) -> &Arc<MmPerformanceTracker> {
+        &self.tracker
+    }
+
+    /// Records an event for the market maker behind `venue_id`.
+    ///
+    /// The event is stamped now and stored in the background; a failure to
+    /// store it is logged.
+    pub fn record(&self, venue_id: &VenueId, kind: MmPerformanceEventKind) {
+        let timestamp = self.tracker.now();
+        let tracker = Arc::clone(&self.tracker);
+        let venues = self.venues.clone();
+        let venue_id = venue_id.clone();
+        let pending = Arc::clone(&self.pending);
+        let idle = Arc::clone(&self.idle);
+
+        pending.fetch_add(1, Ordering::SeqCst);
+        tokio::spawn(async move {
+            let mm_id = resolve_mm(venues.as_deref(), &venue_id).await;
+            let event = MmPerformanceEvent::new(mm_id, kind, timestamp);
+            if let Err(e) = tracker.record_event(event).await {
+                tracing::warn!(venue_id = %venue_id, "Failed to record MM performance event: {}", e);
+            }
+            if pending.fetch_sub(1, Ordering::SeqCst) == 1 {
+                idle.notify_waiters();
+            }
+        });
+    }
+
+    /// Waits until every recorded event has been stored.
+    pub async fn flush(&self) {
+        loop {
+            let idle = self.idle.notified();
+            if self.pending.load(Ordering::SeqCst) == 0 {
+                return;
+            }
+            idle.await;
+        }
+    }
+}
+
+/// Returns the market maker behind a venue, falling back to the venue ID.
+async fn resolve_mm(
+    venues: Option<&dyn PerformanceVenueRepository>,
+    venue_id: &VenueId,
+) -> CounterpartyId {
+    let venue = match venues {
+        Some(venues) => venues.find_by_id(venue_id).await.unwrap_or_else(|e| {
+            tracing::warn!(venue_id = %venue_id, "Failed to resolve venue market maker: {}", e);
+            None
+        }),
+        None => None,
+    };
+    venue
+        .and_then(|venue| venue.mm_counterparty_id().cloned())
+        .unwrap_or_else(|| CounterpartyId::new(venue_id.as_str()))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::VenueType;
+    use crate::infrastructure::persistence::in_memory::InMemoryMmPerformanceRepository;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Default)]
+    struct MockVenues(HashMap<VenueId, Venue>);
+
+    #[async_trait]
+    impl PerformanceVenueRepository for MockVenues {
+        async fn find_by_id(&self, id: &VenueId) -> Result<Option<Venue>, String> {
+            Ok(self.0.get(id).cloned())
+        }
+    }
+
+    #[tokio::test]
+    async fn events_are_keyed_by_the_venue_market_maker() {
+        let mut venue = Venue::new(VenueId::new("venue-1"), "Venue 1", VenueType::ExternalMM);
+        venue.set_mm_counterparty_id(Some(CounterpartyId::new("mm-citadel")));
+        let venues = MockVenues(HashMap::from([(venue.id().clone(), venue)]));
+        let tracker = Arc::new(MmPerformanceTracker::with_defaults(Arc::new(
+            InMemoryMmPerformanceRepository::new(),
+        )));
+        let recorder = MmPerformanceRecorder::new(Arc::clone(&tracker))
+            .with_venue_repository(Arc::new(venues));
+
+        recorder.record(&VenueId::new("venue-1"), MmPerformanceEventKind::RfqSent);
+        recorder.record(&VenueId::new("venue-2"), MmPerformanceEventKind::RfqSent);
+        recorder.flush().await;
+
+        for mm_id in ["mm-citadel", "venue-2"] {
+            let metrics = tracker
+                .get_metrics(&CounterpartyId::new(mm_id))
+                .await
+                .unwrap();
+            assert_eq!(metrics.total_rfqs_received(), 1, "{mm_id}");
+        }
+        let unmapped = tracker
+            .get_metrics(&CounterpartyId::new("venue-1"))
+            .await
+            .unwrap();
+        assert_eq!(unmapped.total_rfqs_received(), 0);
+    }
+}
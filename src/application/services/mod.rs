@@ -3,46 +3,207 @@
 //! Services that orchestrate domain logic and infrastructure.
 //!
 //! This module provides application-level services including:
+//! - [`AllInCostCalculator`]: Settlement gas folded into DeFi quote prices
+//! - [`AllocationExecutionService`]: Multi-venue fill legs and partial failure compensation
+//! - [`BestExecutionReportService`]: Per-client best execution reports over a period
+//! - [`CollateralCheckPort`]: Margin verification before derivatives executions
+//! - [`CounterpartyErasureService`]: Reviewed erasure of a departed counterparty's identifiers
+//! - [`DeadLetterQueue`]: Parking and re-drive of events their consumers keep failing on
+//! - [`ComplianceExportService`]: Regulator export bundles of a counterparty's compliance records
+//! - [`ExecutionGuard`]: Mutual exclusion for executions of the same RFQ
+//! - [`FeeCalculator`]: Platform fees from tiered schedules and waivers
+//! - [`FirmUpService`]: Firm-up of indicative quotes before selection
+//! - [`ForcedTransitionService`]: Audited admin rescue of RFQs and settlements stuck in flight
+//! - [`FxConversionPort`]: Conversion of notionals into the base currency
+//! - [`LiquidityClassifier`]: Liquidity tiers derived from recent volume, quote counts and spreads
+//! - [`MmPerformanceRecorder`]: Market maker performance events from the RFQ flow
+//! - [`NettingService`]: Net settlement of same-counterparty trades in batches
+//! - [`PiiEncryptingEventStore`]: Crypto-shredding of counterparty identifiers in stored events
+//! - [`PriceBoundsConfigStore`]: Live, audited price bounds tolerances
 //! - [`QuoteAggregationEngine`]: Concurrent quote collection and ranking
+//! - [`QuoteReuseCache`]: Short-lived reuse of venue quotes across identical RFQs
+//! - [`QuoteSanityFilter`]: Rejection of quotes priced implausibly far from the market
 //! - [`RankingStrategy`]: Strategies for ranking quotes
+//! - [`RawExchangePurgeService`]: Retention enforcement for recorded venue exchanges
+//! - [`ReadinessChecker`]: Dependency checks behind the readiness probe
+//! - [`RfqBroadcastService`]: Notification of new RFQs to market makers
+//! - [`RfqCancellationService`]: Cancel notices to the venues pricing a cancelled RFQ
+//! - [`RfqExpirySweeper`]: Background expiry of RFQs past their deadline
+//! - [`RfqSummaryProjection`]: RFQ dashboard read model maintained from domain events
+//! - [`RfqTtlConfigStore`]: Live RFQ time-to-live limits by asset class
+//! - [`ScheduledActivationService`]: Start of scheduled RFQs at their activation time
+//! - [`SettlementAddressService`]: Counterparty settlement addresses and their verification
+//! - [`SettlementScheduler`]: Release of trades to settlement when their window opens
+//! - [`SettlementRouter`]: Verified settlement address of a counterparty per chain and token
+//! - [`ShutdownCoordinator`]: Draining of in-flight aggregations on shutdown
+//! - [`TieBreakChain`]: Deterministic ordering of equally ranked quotes
+//! - [`TradingCalendarService`]: Named trading calendars and their selection per settlement method
+//! - [`VenueProber`]: Background venue health probing with hysteresis
+//! - [`VenueRequestGate`]: Per-venue limits on concurrent quote requests
+//! - [`VenueSelector`]: Per-RFQ venue allowlist and blocklist before fan-out
+//! - [`WebhookDeliveryService`]: Signed delivery of trade events to webhook subscribers
 
+pub mod all_in_cost;
+pub mod allocation_execution;
+pub mod best_execution_report;
 pub mod circuit_breaker;
+pub mod clob_mid;
+pub mod collateral_check;
 pub mod compliance;
+pub mod compliance_export;
+pub mod counterparty_erasure;
+pub mod dead_letter_queue;
+pub mod execution_guard;
+pub mod fee_calculator;
 pub mod fill_strategy;
+pub mod firm_up;
+pub mod forced_transitions;
+pub mod fx_conversion;
+pub mod internal_crossing;
+pub mod liquidity_classifier;
+pub mod mm_performance_recorder;
 pub mod multi_leg_quote_collector;
+pub mod netting;
 pub mod package_ranking;
+pub mod pii_encryption;
 pub mod price_bounds;
+pub mod price_bounds_config;
 pub mod quote_aggregation;
+pub mod quote_reuse_cache;
+pub mod quote_sanity;
 pub mod ranking_strategy;
+pub mod raw_exchange_purge;
+pub mod readiness;
 pub mod retry;
+pub mod rfq_broadcast;
+pub mod rfq_cancellation;
+pub mod rfq_expiry;
+pub mod rfq_summary_projection;
+pub mod rfq_ttl_config;
+pub mod scheduled_activation;
+pub mod settlement_addresses;
+pub mod settlement_retry;
+pub mod settlement_router;
+pub mod settlement_scheduler;
+pub mod shutdown;
+pub mod theoretical_reference;
+pub mod tie_break;
+pub mod trading_calendar;
+pub mod venue_prober;
+pub mod venue_request_gate;
+pub mod venue_selector;
+pub mod webhook_delivery;
 
+pub use all_in_cost::{AllInCostCalculator, GasUnitsTable};
+pub use allocation_execution::{
+    AllocationEventPublisher, AllocationExecutionResult, AllocationExecutionService,
+    AllocationVenueGateway, CompensationOutcome, RemainderReofferer,
+};
+pub use best_execution_report::BestExecutionReportService;
 pub use circuit_breaker::{
-    CircuitBreaker, CircuitBreakerConfig, CircuitBreakerError, CircuitBreakerResult, CircuitState,
+    CircuitBreaker, CircuitBreakerConfig, CircuitBreakerError, CircuitBreakerRegistry,
+    CircuitBreakerResult, CircuitOverride, CircuitPermit, CircuitSnapshot, CircuitState,
+    CircuitStateListener, CircuitStateStore, MetricsCircuitListener, TransitionReason,
 };
+pub use clob_mid::{ClobMidReferencePriceProvider, OrderBookSnapshot, OrderBookSnapshotPort};
+pub use collateral_check::{CollateralCheckMode, CollateralCheckPort};
 pub use compliance::{
     AmlProvider, AmlResult, ComplianceCheckResult, ComplianceConfig, ComplianceFlag,
     ComplianceFlagType, ComplianceServiceImpl, ComplianceSeverity, KycProvider, KycStatus,
     LimitsProvider, LimitsResult, SanctionsProvider, SanctionsResult,
 };
+pub use compliance_export::{
+    ComplianceExportBundle, ComplianceExportService, ExportManifest, MANIFEST_FILE, ManifestEntry,
+};
+pub use counterparty_erasure::{CounterpartyErasureService, PSEUDONYM_PREFIX};
+pub use dead_letter_queue::{ConsumeOutcome, DeadLetterQueue, EventConsumer};
+pub use execution_guard::{DEFAULT_EXECUTION_LOCK_TIMEOUT, ExecutionGuard};
+pub use fee_calculator::{FeeCalculator, PlatformFeeConfig};
 pub use fill_strategy::{BestPriceFillStrategy, MultiMmFillStrategy, ProRataStrategy};
+pub use firm_up::{
+    DEFAULT_FIRM_UP_TIMEOUT, DEFAULT_FIRM_UP_TOLERANCE_PCT, FirmUpConfig, FirmUpService,
+    SelectionEventPublisher,
+};
+pub use forced_transitions::{ForcedTransitionEventPublisher, ForcedTransitionService};
+pub use fx_conversion::{FxConversionPort, FxError, ReferencePriceFxConverter};
+pub use internal_crossing::{InternalCross, InternalCrossEventPublisher, InternalCrossingService};
+pub use liquidity_classifier::{
+    DEFAULT_LIQUIDITY_REFRESH_INTERVAL, DEFAULT_LIQUIDITY_TTL, DEFAULT_LIQUIDITY_WINDOW,
+    LiquidityAssessment, LiquidityClassifier, LiquidityClassifierConfig, LiquidityMetrics,
+    LiquidityThresholds,
+};
+pub use mm_performance_recorder::{MmPerformanceRecorder, PerformanceVenueRepository};
 pub use multi_leg_quote_collector::{
     DEFAULT_COLLECTION_TIMEOUT, MultiLegQuoteCollector, VenueQuoteResult,
 };
+pub use netting::{NettingConfig, NettingReport, NettingService, NettingTxBuilder};
 pub use package_ranking::{
     BestNetPriceStrategy, PackageRankingStrategy, RankedPackageQuote, WeightedPackageStrategy,
 };
+pub use pii_encryption::{PII_FIELDS, PiiEncryptingEventStore, is_encrypted};
 pub use price_bounds::{
     FallbackReferencePriceProvider, PriceBoundsValidator, ReferencePriceProvider,
 };
+pub use price_bounds_config::{PriceBoundsConfigStore, PriceBoundsEventPublisher};
 pub use quote_aggregation::{
-    AggregationConfig, AggregationError, AggregationResult, QuoteAggregationEngine,
+    AggregationConfig, AggregationError, AggregationResult, DeduplicatedQuote,
+    QuoteAggregationEngine,
+};
+pub use quote_reuse_cache::{DEFAULT_QUOTE_REUSE_TTL, QuoteReuseCache, QuoteReuseKey};
+pub use quote_sanity::{
+    MIN_QUOTES_FOR_MEDIAN, QuoteSanityConfig, QuoteSanityFilter, QuoteSanityRejection,
 };
 pub use ranking_strategy::{
-    BestPriceStrategy, CompositeStrategy, CompositeStrategyBuilder, CostConfig, LowestCostStrategy,
-    LowestSlippageStrategy, RankedQuote, RankingStrategy, RankingWeights,
+    AllInCostStrategy, BestPriceStrategy, CompositeStrategy, CompositeStrategyBuilder, CostConfig,
+    LowestCostStrategy, LowestSlippageStrategy, RankedQuote, RankingStrategy, RankingWeights,
     WeightedMultiFactorStrategy, WeightedScoreStrategy,
 };
+pub use raw_exchange_purge::{DEFAULT_PURGE_INTERVAL, RawExchangePurgeService};
+pub use readiness::{
+    CheckStatus, DEFAULT_CHECK_TIMEOUT, DEFAULT_MIN_HEALTHY_VENUES, DependencyCheck,
+    DependencyReport, EventStoreCheck, ReadinessChecker, ReadinessReport,
+};
 pub use retry::{
-    AlwaysRetryable, NeverRetryable, RetryError, RetryPolicy, RetryResult, Retryable,
-    execute_with_retry,
+    AlwaysRetryable, DEFAULT_RETRY_BUDGET_CAPACITY, DEFAULT_RETRY_BUDGET_WINDOW, NeverRetryable,
+    RetryBudget, RetryBudgets, RetryError, RetryPolicy, RetryResult, Retryability, Retryable,
+    execute_with_retry, execute_with_retry_budget, parse_retry_after,
+};
+pub use rfq_broadcast::{
+    BroadcastReport, BroadcastVenueRepository, NotificationChannel, RfqBroadcast,
+    RfqBroadcastService, RfqSubscriptionHub,
+};
+pub use rfq_cancellation::{
+    CancelNotice, CancelNoticeOutcome, DEFAULT_CANCEL_NOTICE_TIMEOUT, RfqCancellationService,
+};
+pub use rfq_expiry::{
+    DEFAULT_EXECUTION_GRACE, DEFAULT_SWEEP_INTERVAL, RfqExpiryReport, RfqExpirySweeper,
+};
+pub use rfq_summary_projection::{ProjectingEventStore, RfqSummaryProjection};
+pub use rfq_ttl_config::RfqTtlConfigStore;
+pub use scheduled_activation::{
+    RfqActivator, ScheduledActivationReport, ScheduledActivationService,
+};
+pub use settlement_addresses::{
+    AddressChallenge, DEFAULT_ADDRESS_CHALLENGE_TTL, SettlementAddressService,
+};
+pub use settlement_retry::{
+    SettlementEventPublisher, SettlementRetryConfig, SettlementRetryOutcome, SettlementRetryReport,
+    SettlementRetryService, SettlementTx, SettlementTxBuilder, TokenTransfer,
+};
+pub use settlement_router::SettlementRouter;
+pub use settlement_scheduler::{SettlementScheduler, SettlementSchedulerConfig};
+pub use shutdown::{
+    DEFAULT_GRACE_PERIOD, DrainReport, InFlightGuard, SHUTDOWN_REASON, ShutdownCoordinator,
+};
+pub use theoretical_reference::{
+    MarketDataPort, OptionKind, OptionPricingInputs, TheoreticalReferencePriceProvider,
+};
+pub use tie_break::{TieBreak, TieBreakChain};
+pub use trading_calendar::TradingCalendarService;
+pub use venue_prober::{
+    DEFAULT_PROBE_FAILURE_THRESHOLD, DEFAULT_PROBE_INTERVAL, DEFAULT_PROBE_RECOVERY_THRESHOLD,
+    ProbedVenueRepository, VenueProbeResult, VenueProber,
 };
+pub use venue_request_gate::{CONCURRENCY_LIMIT_REASON, VenueRequestGate, VenueRequestPermit};
+pub use venue_selector::{VenueSelection, VenueSelector};
+pub use webhook_delivery::{BroadcastingEventStore, WebhookDeliveryService, WebhookEventPublisher};
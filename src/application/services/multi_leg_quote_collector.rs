@@ -16,8 +16,8 @@
 
 use crate::domain::entities::package_quote::{LegPrice, PackageQuote};
 use crate::domain::entities::rfq::Rfq;
-use crate::domain::value_objects::strategy::Strategy;
-use crate::domain::value_objects::{Timestamp, VenueId};
+use crate::domain::value_objects::strategy::{Strategy, StrategyLeg};
+use crate::domain::value_objects::{OrderSide, Timestamp, VenueId};
 use crate::infrastructure::venues::traits::VenueAdapter;
 use rust_decimal::Decimal;
 use std::sync::Arc;
@@ -55,14 +55,14 @@ pub enum VenueQuoteResult {
 /// using multi-leg quoting for venues that support it and falling back to
 /// individual leg quotes for those that don't.
 #[derive(Debug)]
-pub struct MultiLegQuoteCollector<V: VenueAdapter> {
+pub struct MultiLegQuoteCollector<V: VenueAdapter + ?Sized> {
     /// The venues to collect quotes from.
     venues: Vec<Arc<V>>,
     /// Timeout for each venue request.
     timeout: Duration,
 }
 
-impl<V: VenueAdapter + 'static> MultiLegQuoteCollector<V> {
+impl<V: VenueAdapter + ?Sized + 'static> MultiLegQuoteCollector<V> {
     /// Creates a new quote collector.
     ///
     /// # Arguments
@@ -196,6 +196,10 @@ impl<V: VenueAdapter + 'static> MultiLegQuoteCollector<V> {
     }
 
     /// Falls back to requesting individual leg quotes and synthesizing a package quote.
+    ///
+    /// Each leg is quoted as its own single-instrument RFQ for the leg's
+    /// instrument, side, and `quantity * ratio`. Leg sides describe buying
+    /// the package, so they are reversed when the RFQ sells it.
     async fn fallback_to_individual_legs(
         &self,
         venue: &V,
@@ -207,27 +211,26 @@ impl<V: VenueAdapter + 'static> MultiLegQuoteCollector<V> {
         let mut net_price = Decimal::ZERO;
 
         // TODO(#14): This sequential loop could be parallelized with join_all for better latency.
-        // TODO(#14): Each leg currently uses the same RFQ, which means all legs get the same price.
-        //            A proper implementation should create single-leg RFQs per instrument.
         for leg in strategy.legs() {
-            match venue.request_quote(rfq).await {
+            let leg_rfq = match leg_rfq(rfq, leg) {
+                Ok(leg_rfq) => leg_rfq,
+                Err(reason) => return VenueQuoteResult::Failed { venue_id, reason },
+            };
+
+            match venue.request_quote(&leg_rfq).await {
                 Ok(quote) => {
                     let leg_price = LegPrice::from_parts(
                         leg.instrument().clone(),
-                        leg.side(),
+                        leg_rfq.side(),
                         quote.price(),
-                        rfq.quantity(),
+                        leg_rfq.quantity(),
                     );
 
-                    // Calculate signed contribution to net price
+                    // Positive net price is a debit: bought legs add, sold legs subtract
                     if let Some(notional) = leg_price.notional() {
-                        match leg.side() {
-                            crate::domain::value_objects::OrderSide::Buy => {
-                                net_price -= notional.get();
-                            }
-                            crate::domain::value_objects::OrderSide::Sell => {
-                                net_price += notional.get();
-                            }
+                        match leg_rfq.side() {
+                            OrderSide::Buy => net_price += notional.get(),
+                            OrderSide::Sell => net_price -= notional.get(),
                         }
                     }
 
@@ -260,6 +263,28 @@ impl<V: VenueAdapter + 'static> MultiLegQuoteCollector<V> {
     }
 }
 
+/// Builds the single-instrument RFQ used to quote one leg of a package.
+fn leg_rfq(rfq: &Rfq, leg: &StrategyLeg) -> Result<Rfq, String> {
+    let quantity = rfq
+        .quantity()
+        .safe_mul(Decimal::from(leg.ratio()))
+        .map_err(|e| format!("Invalid leg quantity: {}", e))?;
+    let side = match rfq.side() {
+        OrderSide::Buy => leg.side(),
+        OrderSide::Sell => leg.side().opposite(),
+    };
+
+    Ok(Rfq::builder(
+        rfq.client_id().clone(),
+        leg.instrument().clone(),
+        side,
+        quantity,
+        rfq.expires_at(),
+    )
+    .anonymity_level(rfq.anonymity_level())
+    .build())
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
@@ -454,6 +479,42 @@ mod tests {
         assert!(venue.call_count.load(Ordering::SeqCst) >= 2);
     }
 
+    #[tokio::test]
+    async fn fallback_quotes_each_leg_separately() {
+        let venue = Arc::new(MockVenue::new("single-leg-venue", false));
+        let collector =
+            MultiLegQuoteCollector::new(vec![Arc::clone(&venue)], Duration::from_secs(5));
+
+        let inst = test_instrument();
+        let strategy = Strategy::new(
+            StrategyType::Custom,
+            vec![
+                StrategyLeg::new(inst.clone(), OrderSide::Buy, 1).unwrap(),
+                StrategyLeg::new(inst, OrderSide::Sell, 2).unwrap(),
+            ],
+            "BTC",
+            None,
+        )
+        .unwrap();
+        let rfq = test_rfq();
+
+        let quotes = collector.collect_quotes(&strategy, &rfq).await;
+
+        let [quote] = quotes.as_slice() else {
+            unreachable!("expected one package quote")
+        };
+        let [buy, sell] = quote.leg_prices() else {
+            unreachable!("expected two leg prices")
+        };
+        assert_eq!(buy.side(), OrderSide::Buy);
+        assert_eq!(buy.quantity(), Quantity::new(1.0).unwrap());
+        assert_eq!(sell.side(), OrderSide::Sell);
+        assert_eq!(sell.quantity(), Quantity::new(2.0).unwrap());
+        // Buys 1 @ 50000, sells 2 @ 50000: net credit of 50000
+        assert_eq!(quote.net_price(), Decimal::new(-50000, 0));
+        assert_eq!(venue.call_count.load(Ordering::SeqCst), 2);
+    }
+
     #[tokio::test]
     async fn collect_quotes_handles_failures() {
         let failing_venue = Arc::new(MockVenue::failing("failing-venue"));
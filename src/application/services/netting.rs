@@ -0,0 +1,937 @@
+//! # Settlement Netting
+//!
+//! Net settlement of pending trades between the same counterparties.
+//!
+//! [`NettingService`] groups `Pending` trades by client, market maker venue,
+//! instrument and chain within a settlement window, reserves them for a
+//! [`NettingBatch`], and settles the batch with one transaction carrying the
+//! net base and quote amounts. When the transaction confirms, every trade in
+//! the batch moves to `SettledViaNetting`.
+//!
+//! # Windows
+//!
+//! Windows are aligned to multiples of [`NettingConfig::window`] since the
+//! Unix epoch. Only windows that have closed are batched, and only groups
+//! of at least [`NettingConfig::min_trades`] trades; everything else is left
+//! to individual settlement. Trades whose settlement window has not opened
+//! yet (see [`SettlementScheduler`](crate::application::services::SettlementScheduler))
+//! are left for a later scan.
+//!
+//! # Cancellation and Failure
+//!
+//! A scan settles the batches created by the previous scan before creating
+//! new ones, so a batch can be cancelled for one scan interval. Cancelling
+//! an open batch, or a batch whose transaction fails or reverts, releases
+//! its trades back to individual settlement. If the confirmation of a
+//! submitted transaction cannot be observed, the batch stays `Submitted`
+//! and its trades stay reserved; settling it again checks the receipt
+//! instead of resubmitting.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::application::services::settlement_retry::SettlementTx;
+use crate::domain::entities::netting_batch::{NettingBatch, NettingBatchStatus, NettingLeg};
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::entities::trade::Trade;
+use crate::domain::value_objects::enums::Blockchain;
+use crate::domain::value_objects::timestamp::{Clock, SystemClock, Timestamp};
+use crate::domain::value_objects::{CounterpartyId, NettingBatchId, RfqId, Symbol, VenueId};
+use crate::infrastructure::blockchain::{BlockchainClient, TxHash, TxPriority, TxReceipt};
+use crate::infrastructure::metrics;
+use crate::infrastructure::persistence::traits::{
+    NettingBatchRepository, RfqRepository, TradeRepository,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Builds the on-chain transaction that settles a netting batch.
+#[async_trait]
+pub trait NettingTxBuilder: Send + Sync + fmt::Debug {
+    /// Builds the transaction transferring the net amounts of `batch`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    async fn build(&self, batch: &NettingBatch) -> ApplicationResult<SettlementTx>;
+}
+
+/// Configuration for [`NettingService`].
+#[derive(Debug, Clone)]
+pub struct NettingConfig {
+    /// Length of a settlement window.
+    pub window: Duration,
+    /// Fewest trades worth netting; smaller groups settle individually.
+    pub min_trades: usize,
+    /// Confirmations to wait for after submitting a batch.
+    pub confirmations: u64,
+    /// Gas priority for batch transactions.
+    pub priority: TxPriority,
+}
+
+impl Default for NettingConfig {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_secs(3600),
+            min_trades: 2,
+            confirmations: 1,
+            priority: TxPriority::Medium,
+        }
+    }
+}
+
+/// Summary of a single scan.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NettingReport {
+    /// Batches created from closed windows.
+    pub created: usize,
+    /// Batches settled.
+    pub settled: usize,
+    /// Batches that failed; their trades were released.
+    pub failed: usize,
+    /// Batches submitted but not yet confirmed.
+    pub submitted: usize,
+    /// Batches skipped because of an error; they are retried next scan.
+    pub errors: usize,
+}
+
+/// Trades that may be netted together.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct NettingKey {
+    client_id: CounterpartyId,
+    venue_id: VenueId,
+    symbol: Symbol,
+    blockchain: Blockchain,
+    window: i64,
+}
+
+/// Nets pending trades into batches and settles them.
+///
+/// # Examples
+///
+/// ```ignore
+/// let service = NettingService::new(
+///     trade_repository,
+///     rfq_repository,
+///     batch_repository,
+///     blockchain_client,
+///     tx_builder,
+///     NettingConfig::default(),
+/// );
+///
+/// tokio::spawn(async move { service.run(Duration::from_secs(300)).await });
+/// ```
+#[derive(Debug)]
+pub struct NettingService {
+    trade_repository: Arc<dyn TradeRepository>,
+    rfq_repository: Arc<dyn RfqRepository>,
+    batch_repository: Arc<dyn NettingBatchRepository>,
+    blockchain: Arc<dyn BlockchainClient>,
+    tx_builder: Arc<dyn NettingTxBuilder>,
+    config: NettingConfig,
+    clock: Arc<dyn Clock>,
+}
+
+impl NettingService {
+    /// Creates a new netting service.
+    #[must_use]
+    pub fn new(
+        trade_repository: Arc<dyn TradeRepository>,
+        rfq_repository: Arc<dyn RfqRepository>,
+        batch_repository: Arc<dyn NettingBatchRepository>,
+        blockchain: Arc<dyn BlockchainClient>,
+        tx_builder: Arc<dyn NettingTxBuilder>,
+        config: NettingConfig,
+    ) -> Self {
+        Self {
+            trade_repository,
+            rfq_repository,
+            batch_repository,
+            blockchain,
+            tx_builder,
+            config,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Sets the clock used to decide which windows have closed.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Scans every `interval`, forever.
+    pub async fn run(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match self.run_once().await {
+                Ok(report) => tracing::debug!(?report, "Netting scan completed"),
+                Err(e) => tracing::error!(error = %e, "Netting scan failed"),
+            }
+        }
+    }
+
+    /// Settles the unfinished batches of previous scans, then batches the
+    /// trades of windows that have closed since.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if batches or pending trades cannot be loaded.
+    pub async fn run_once(&self) -> ApplicationResult<NettingReport> {
+        let mut report = NettingReport::default();
+
+        let unfinished = self.list(None).await?;
+        for batch in unfinished.iter().filter(|b| !b.status().is_terminal()) {
+            match self.settle(batch.id()).await {
+                Ok(settled) => match settled.status() {
+                    NettingBatchStatus::Settled => report.settled += 1,
+                    NettingBatchStatus::Failed => report.failed += 1,
+                    _ => report.submitted += 1,
+                },
+                Err(e) => {
+                    tracing::warn!(batch_id = %batch.id(), error = %e, "Netting batch skipped");
+                    report.errors += 1;
+                }
+            }
+        }
+
+        report.created = self.create_batches().await?.len();
+        Ok(report)
+    }
+
+    /// Reserves the unbatched pending trades of closed windows for new
+    /// batches.
+    ///
+    /// Groups that cannot be reserved are logged and left for individual
+    /// settlement; trades already reserved for them are released.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if pending trades cannot be loaded.
+    pub async fn create_batches(&self) -> ApplicationResult<Vec<NettingBatch>> {
+        let window_secs = i64::try_from(self.config.window.as_secs())
+            .unwrap_or(i64::MAX)
+            .max(1);
+        let now = self.clock.now();
+        let pending = self
+            .trade_repository
+            .find_pending_settlement()
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?;
+
+        let mut rfqs: HashMap<RfqId, Option<Rfq>> = HashMap::new();
+        let mut groups: HashMap<NettingKey, Vec<(Trade, NettingLeg)>> = HashMap::new();
+        for trade in pending {
+            if trade.netting_batch_id().is_some() || !trade.is_settlement_due(now) {
+                continue;
+            }
+            let window = trade.created_at().timestamp_secs().div_euclid(window_secs);
+            let closed = window
+                .checked_add(1)
+                .and_then(|next| next.checked_mul(window_secs))
+                .is_some_and(|end| end <= now.timestamp_secs());
+            if !closed {
+                continue;
+            }
+
+            let rfq = match rfqs.entry(trade.rfq_id()) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(
+                    self.rfq_repository
+                        .get(trade.rfq_id())
+                        .await
+                        .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?,
+                ),
+            };
+            let Some(rfq) = rfq.as_ref() else {
+                tracing::warn!(trade_id = %trade.id(), "Trade RFQ not found, not netted");
+                continue;
+            };
+            let Some(blockchain) = rfq.instrument().settlement_method().blockchain() else {
+                continue;
+            };
+            let side = match rfq.quotes().iter().find(|q| q.id() == trade.quote_id()) {
+                Some(quote) => rfq.side_of(quote),
+                None => rfq.resolved_side()?,
+            };
+
+            let key = NettingKey {
+                client_id: rfq.client_id().clone(),
+                venue_id: trade.venue_id().clone(),
+                symbol: rfq.instrument().symbol().clone(),
+                blockchain,
+                window,
+            };
+            let leg = NettingLeg::new(trade.id(), side, trade.price(), trade.quantity());
+            groups.entry(key).or_default().push((trade, leg));
+        }
+
+        let mut created = Vec::new();
+        for (key, members) in groups {
+            if members.len() < self.config.min_trades.max(1) {
+                continue;
+            }
+            match self.reserve(key, members, window_secs).await {
+                Ok(batch) => {
+                    tracing::info!(
+                        batch_id = %batch.id(),
+                        trades = batch.trade_ids().len(),
+                        net_base = %batch.net_base(),
+                        net_quote = %batch.net_quote(),
+                        "Netting batch created"
+                    );
+                    created.push(batch);
+                }
+                Err(e) => tracing::warn!(error = %e, "Netting batch not created"),
+            }
+        }
+        Ok(created)
+    }
+
+    /// Creates a batch for one group and reserves its trades.
+    async fn reserve(
+        &self,
+        key: NettingKey,
+        members: Vec<(Trade, NettingLeg)>,
+        window_secs: i64,
+    ) -> ApplicationResult<NettingBatch> {
+        let window_bound = |n: i64| {
+            n.checked_mul(window_secs)
+                .and_then(Timestamp::from_secs)
+                .ok_or_else(|| ApplicationError::Internal("netting window overflow".to_string()))
+        };
+        let legs: Vec<NettingLeg> = members.iter().map(|(_, leg)| *leg).collect();
+        let batch = NettingBatch::new(
+            key.client_id,
+            key.venue_id,
+            key.symbol,
+            key.blockchain,
+            window_bound(key.window)?,
+            window_bound(key.window.saturating_add(1))?,
+            &legs,
+        )?;
+
+        let mut reserved = Vec::with_capacity(members.len());
+        for (mut trade, _) in members {
+            let result = match trade.assign_to_netting_batch(batch.id()) {
+                Ok(()) => self.save_trade(&trade).await,
+                Err(e) => Err(e.into()),
+            };
+            if let Err(e) = result {
+                self.release(reserved).await;
+                return Err(e);
+            }
+            reserved.push(trade);
+        }
+
+        if let Err(e) = self.save_batch(&batch).await {
+            self.release(reserved).await;
+            return Err(e);
+        }
+        Ok(batch)
+    }
+
+    /// Settles an open batch, or checks on a submitted one.
+    ///
+    /// A batch that nets to zero settles without a transaction. If the
+    /// transaction cannot be sent or reverts, the batch fails and its
+    /// trades are released; the failed batch is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch does not exist or is terminal, if its
+    /// trades are no longer reserved for it, or if the batch is on a
+    /// different chain from the blockchain client.
+    pub async fn settle(&self, id: NettingBatchId) -> ApplicationResult<NettingBatch> {
+        let mut batch = self.get(id).await?;
+        if batch.status().is_terminal() {
+            return Err(ApplicationError::InvalidState(format!(
+                "netting batch {} is {}",
+                batch.id(),
+                batch.status()
+            )));
+        }
+        let trades = self.reserved_trades(&batch).await?;
+
+        if batch.status() == NettingBatchStatus::Submitted {
+            return self.reconcile(batch, trades).await;
+        }
+        if batch.nets_to_zero() {
+            batch.confirm()?;
+            self.save_batch(&batch).await?;
+            self.complete(&batch, trades).await?;
+            return Ok(batch);
+        }
+
+        let chain = self.blockchain.chain_id();
+        if chain.as_u64() != batch.blockchain().chain_id() {
+            return Err(ApplicationError::validation(format!(
+                "netting batch {} settles on {} but the client is connected to {}",
+                batch.id(),
+                batch.blockchain(),
+                chain
+            )));
+        }
+        match self.submit(&mut batch).await {
+            Ok(Some(_)) => self.confirm(batch, trades).await,
+            Ok(None) => Ok(batch),
+            Err(reason) => self.fail(batch, trades, reason).await,
+        }
+    }
+
+    /// Settles or fails a submitted batch from its transaction receipt.
+    async fn reconcile(
+        &self,
+        batch: NettingBatch,
+        trades: Vec<Trade>,
+    ) -> ApplicationResult<NettingBatch> {
+        let tx_hash = TxHash::new(batch.settlement_tx_ref().unwrap_or_default());
+        let receipt = self
+            .blockchain
+            .get_transaction_receipt(&tx_hash)
+            .await
+            .map_err(|e| ApplicationError::ExecutionFailed(e.to_string()))?;
+        match receipt {
+            Some(receipt) if receipt.success => self.confirm(batch, trades).await,
+            Some(_) => {
+                let reason = format!("netting transaction {} reverted", tx_hash);
+                self.fail(batch, trades, reason).await
+            }
+            None => Ok(batch),
+        }
+    }
+
+    /// Cancels an open batch and releases its trades to individual
+    /// settlement.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch does not exist or was already
+    /// submitted or finished.
+    pub async fn cancel(&self, id: NettingBatchId) -> ApplicationResult<NettingBatch> {
+        let mut batch = self.get(id).await?;
+        batch.cancel()?;
+        let trades = self.reserved_trades(&batch).await?;
+        self.save_batch(&batch).await?;
+        self.release(trades).await;
+        tracing::info!(batch_id = %batch.id(), "Netting batch cancelled");
+        Ok(batch)
+    }
+
+    /// Finds a batch by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::NotFound` if the batch does not exist.
+    pub async fn get(&self, id: NettingBatchId) -> ApplicationResult<NettingBatch> {
+        self.batch_repository
+            .get(id)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?
+            .ok_or_else(|| ApplicationError::not_found("NettingBatch", id.to_string()))
+    }
+
+    /// Lists batches, optionally only those in `status`, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batches cannot be loaded.
+    pub async fn list(
+        &self,
+        status: Option<NettingBatchStatus>,
+    ) -> ApplicationResult<Vec<NettingBatch>> {
+        self.batch_repository
+            .find_all(status)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))
+    }
+
+    /// Loads the trades reserved for an unfinished batch.
+    async fn reserved_trades(&self, batch: &NettingBatch) -> ApplicationResult<Vec<Trade>> {
+        let mut trades = Vec::with_capacity(batch.trade_ids().len());
+        for &trade_id in batch.trade_ids() {
+            let trade = self
+                .trade_repository
+                .get(trade_id)
+                .await
+                .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?
+                .ok_or_else(|| ApplicationError::not_found("Trade", trade_id.to_string()))?;
+            if trade.netting_batch_id() != Some(batch.id()) || !trade.is_pending() {
+                return Err(ApplicationError::InvalidState(format!(
+                    "trade {} is no longer reserved for netting batch {}",
+                    trade_id,
+                    batch.id()
+                )));
+            }
+            trades.push(trade);
+        }
+        Ok(trades)
+    }
+
+    /// Builds, submits, and awaits the batch transaction.
+    ///
+    /// Returns `Ok(None)` if the transaction was sent but its confirmation
+    /// could not be observed, and the failure reason if it was not sent or
+    /// reverted.
+    async fn submit(&self, batch: &mut NettingBatch) -> Result<Option<TxReceipt>, String> {
+        let tx = self
+            .tx_builder
+            .build(batch)
+            .await
+            .map_err(|e| e.to_string())?;
+        let gas_limit = self
+            .blockchain
+            .estimate_gas(&tx.to, &tx.data, tx.value)
+            .await
+            .map_err(|e| e.to_string())?;
+        let gas_price = self
+            .blockchain
+            .get_gas_price(self.config.priority)
+            .await
+            .map_err(|e| e.to_string())?;
+        let tx_hash = self
+            .blockchain
+            .send_transaction(&tx.to, &tx.data, tx.value, gas_limit, gas_price)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // Once broadcast the trades must stay reserved until the outcome
+        // is known, so record the hash before waiting.
+        batch.submit(tx_hash.as_str()).map_err(|e| e.to_string())?;
+        if let Err(e) = self.save_batch(batch).await {
+            tracing::error!(batch_id = %batch.id(), error = %e, "Submitted netting batch not saved");
+        }
+
+        let started = Instant::now();
+        let confirmation = self
+            .blockchain
+            .wait_for_confirmation(&tx_hash, self.config.confirmations)
+            .await;
+        let outcome = match &confirmation {
+            Ok(receipt) if receipt.success => "confirmed",
+            Ok(_) => "reverted",
+            Err(_) => "error",
+        };
+        metrics::record_settlement_confirmation(started.elapsed(), outcome);
+
+        match confirmation {
+            Ok(receipt) if receipt.success => Ok(Some(receipt)),
+            Ok(_) => Err(format!("netting transaction {} reverted", tx_hash)),
+            Err(e) => {
+                tracing::warn!(
+                    batch_id = %batch.id(),
+                    tx_hash = %tx_hash,
+                    error = %e,
+                    "Netting batch confirmation not observed"
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    async fn confirm(
+        &self,
+        mut batch: NettingBatch,
+        trades: Vec<Trade>,
+    ) -> ApplicationResult<NettingBatch> {
+        batch.confirm()?;
+        self.save_batch(&batch).await?;
+        self.complete(&batch, trades).await?;
+        tracing::info!(batch_id = %batch.id(), "Netting batch settled");
+        Ok(batch)
+    }
+
+    /// Moves the batch's trades to `SettledViaNetting`.
+    async fn complete(&self, batch: &NettingBatch, trades: Vec<Trade>) -> ApplicationResult<()> {
+        let tx_ref = batch.settlement_tx_ref().map(str::to_string);
+        for mut trade in trades {
+            trade.confirm_settlement_via_netting(tx_ref.clone())?;
+            self.save_trade(&trade).await?;
+        }
+        Ok(())
+    }
+
+    async fn fail(
+        &self,
+        mut batch: NettingBatch,
+        trades: Vec<Trade>,
+        reason: String,
+    ) -> ApplicationResult<NettingBatch> {
+        tracing::warn!(batch_id = %batch.id(), reason = %reason, "Netting batch failed");
+        batch.fail(reason)?;
+        self.save_batch(&batch).await?;
+        self.release(trades).await;
+        Ok(batch)
+    }
+
+    /// Releases trades to individual settlement, logging any that cannot be.
+    async fn release(&self, trades: Vec<Trade>) {
+        for mut trade in trades {
+            let result = match trade.release_from_netting_batch() {
+                Ok(()) => self.save_trade(&trade).await,
+                Err(e) => Err(e.into()),
+            };
+            if let Err(e) = result {
+                tracing::error!(
+                    trade_id = %trade.id(),
+                    error = %e,
+                    "Trade not released from netting batch"
+                );
+            }
+        }
+    }
+
+    async fn save_trade(&self, trade: &Trade) -> ApplicationResult<()> {
+        self.trade_repository
+            .save(trade)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))
+    }
+
+    async fn save_batch(&self, batch: &NettingBatch) -> ApplicationResult<()> {
+        self.batch_repository
+            .save(batch)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
+    use crate::domain::value_objects::timestamp::MockClock;
+    use crate::domain::value_objects::{
+        Instrument, OrderSide, Price, Quantity, QuoteId, SettlementCutoff, SettlementWindow,
+    };
+    use crate::infrastructure::blockchain::{BlockchainResult, ChainId, GasPrice};
+    use crate::infrastructure::persistence::in_memory::{
+        InMemoryNettingBatchRepository, InMemoryRfqRepository, InMemoryTradeRepository,
+    };
+    use rust_decimal::Decimal;
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct MockBlockchain {
+        reverts: bool,
+        sent: Mutex<Vec<TxHash>>,
+    }
+
+    impl MockBlockchain {
+        fn sent_count(&self) -> usize {
+            self.sent.lock().unwrap().len()
+        }
+    }
+
+    fn receipt(hash: &TxHash, success: bool) -> TxReceipt {
+        TxReceipt {
+            tx_hash: hash.clone(),
+            block_number: 100,
+            gas_used: 21_000,
+            effective_gas_price: 20_000_000_000,
+            success,
+        }
+    }
+
+    #[async_trait]
+    impl BlockchainClient for MockBlockchain {
+        fn chain_id(&self) -> ChainId {
+            ChainId::Ethereum
+        }
+
+        async fn get_block_number(&self) -> BlockchainResult<u64> {
+            Ok(100)
+        }
+
+        async fn get_balance(&self, _address: &str) -> BlockchainResult<u128> {
+            Ok(0)
+        }
+
+        async fn estimate_gas(
+            &self,
+            _to: &str,
+            _data: &[u8],
+            _value: u128,
+        ) -> BlockchainResult<u64> {
+            Ok(21_000)
+        }
+
+        async fn get_gas_price(&self, _priority: TxPriority) -> BlockchainResult<GasPrice> {
+            Ok(GasPrice::legacy(20_000_000_000))
+        }
+
+        async fn call(&self, _to: &str, _data: &[u8]) -> BlockchainResult<Vec<u8>> {
+            // Every ERC-20 balance and allowance reads as zero.
+            Ok(vec![0; 32])
+        }
+
+        async fn send_transaction(
+            &self,
+            _to: &str,
+            _data: &[u8],
+            _value: u128,
+            _gas_limit: u64,
+            _gas_price: GasPrice,
+        ) -> BlockchainResult<TxHash> {
+            let mut sent = self.sent.lock().unwrap();
+            let hash = TxHash::new(format!("0x{:04x}", sent.len() + 1));
+            sent.push(hash.clone());
+            Ok(hash)
+        }
+
+        async fn wait_for_confirmation(
+            &self,
+            tx_hash: &TxHash,
+            _confirmations: u64,
+        ) -> BlockchainResult<TxReceipt> {
+            Ok(receipt(tx_hash, !self.reverts))
+        }
+
+        async fn get_transaction_receipt(
+            &self,
+            tx_hash: &TxHash,
+        ) -> BlockchainResult<Option<TxReceipt>> {
+            Ok(Some(receipt(tx_hash, !self.reverts)))
+        }
+
+        async fn is_transaction_pending(&self, _tx_hash: &TxHash) -> BlockchainResult<bool> {
+            Ok(false)
+        }
+
+        async fn get_nonce(&self, _address: &str) -> BlockchainResult<u64> {
+            Ok(0)
+        }
+
+        async fn health_check(&self) -> BlockchainResult<()> {
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    struct FixedTxBuilder;
+
+    #[async_trait]
+    impl NettingTxBuilder for FixedTxBuilder {
+        async fn build(&self, _batch: &NettingBatch) -> ApplicationResult<SettlementTx> {
+            Ok(SettlementTx {
+                to: "0x0000000000000000000000000000000000000001".to_string(),
+                data: vec![0xab],
+                value: 0,
+                token_transfer: None,
+            })
+        }
+    }
+
+    struct Harness {
+        trades: Arc<InMemoryTradeRepository>,
+        rfqs: Arc<InMemoryRfqRepository>,
+        blockchain: Arc<MockBlockchain>,
+        clock: Arc<MockClock>,
+        service: NettingService,
+    }
+
+    fn harness(blockchain: MockBlockchain) -> Harness {
+        let trades = Arc::new(InMemoryTradeRepository::new());
+        let rfqs = Arc::new(InMemoryRfqRepository::new());
+        let blockchain = Arc::new(blockchain);
+        // Day-long windows keep the test trades in one window; the clock
+        // starts two days later so that window has closed.
+        let clock = Arc::new(MockClock::new(Timestamp::now().add_secs(2 * 86_400)));
+        let config = NettingConfig {
+            window: Duration::from_secs(86_400),
+            ..NettingConfig::default()
+        };
+        let service = NettingService::new(
+            Arc::clone(&trades) as Arc<dyn TradeRepository>,
+            Arc::clone(&rfqs) as Arc<dyn RfqRepository>,
+            Arc::new(InMemoryNettingBatchRepository::new()),
+            Arc::clone(&blockchain) as Arc<dyn BlockchainClient>,
+            Arc::new(FixedTxBuilder),
+            config,
+        )
+        .with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+        Harness {
+            trades,
+            rfqs,
+            blockchain,
+            clock,
+            service,
+        }
+    }
+
+    impl Harness {
+        async fn trade(&self, side: OrderSide, price: f64, quantity: f64) -> Trade {
+            self.trade_due(side, price, quantity, None).await
+        }
+
+        /// Stores a trade, released for settlement at `due_at` if given.
+        async fn trade_due(
+            &self,
+            side: OrderSide,
+            price: f64,
+            quantity: f64,
+            due_at: Option<Timestamp>,
+        ) -> Trade {
+            let instrument = Instrument::new(
+                Symbol::new("ETH/USDC").unwrap(),
+                AssetClass::CryptoSpot,
+                SettlementMethod::OnChain(Blockchain::Ethereum),
+            );
+            let rfq = RfqBuilder::new(
+                CounterpartyId::new("client-1"),
+                instrument,
+                side,
+                Quantity::new(quantity).unwrap(),
+                Timestamp::now().add_secs(300),
+            )
+            .build();
+            self.rfqs.save(&rfq).await.unwrap();
+
+            let mut trade = Trade::new(
+                rfq.id(),
+                QuoteId::new_v4(),
+                VenueId::new("mm-1"),
+                Price::new(price).unwrap(),
+                Quantity::new(quantity).unwrap(),
+            );
+            if let Some(due_at) = due_at {
+                let cutoff = SettlementCutoff::default();
+                trade.schedule_settlement(SettlementWindow::SameDay { cutoff }, due_at);
+            }
+            self.trades.save(&trade).await.unwrap();
+            trade
+        }
+
+        async fn stored(&self, trade: &Trade) -> Trade {
+            self.trades.get(trade.id()).await.unwrap().unwrap()
+        }
+
+        async fn single_batch(&self) -> NettingBatch {
+            let mut batches = self.service.create_batches().await.unwrap();
+            assert_eq!(batches.len(), 1);
+            batches.pop().unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn net_to_zero_pair_settles_without_a_transaction() {
+        let h = harness(MockBlockchain::default());
+        let buy = h.trade(OrderSide::Buy, 2000.0, 1.5).await;
+        let sell = h.trade(OrderSide::Sell, 2000.0, 1.5).await;
+
+        let batch = h.single_batch().await;
+        assert!(batch.nets_to_zero());
+        assert_eq!(h.stored(&buy).await.netting_batch_id(), Some(batch.id()));
+
+        let settled = h.service.settle(batch.id()).await.unwrap();
+        assert_eq!(settled.status(), NettingBatchStatus::Settled);
+        assert!(settled.settlement_tx_ref().is_none());
+        assert_eq!(h.blockchain.sent_count(), 0);
+        for trade in [&buy, &sell] {
+            assert!(h.stored(trade).await.is_settled_via_netting());
+        }
+    }
+
+    #[tokio::test]
+    async fn one_sided_net_settles_with_one_transaction() {
+        let h = harness(MockBlockchain::default());
+        let first = h.trade(OrderSide::Buy, 2000.0, 2.0).await;
+        let second = h.trade(OrderSide::Buy, 2100.0, 1.0).await;
+
+        let batch = h.single_batch().await;
+        assert_eq!(batch.net_base(), Decimal::new(3, 0));
+        assert_eq!(batch.net_quote(), Decimal::new(-6100, 0));
+
+        let report = h.service.run_once().await.unwrap();
+        assert_eq!(report.settled, 1);
+        assert_eq!(report.created, 0);
+        assert_eq!(h.blockchain.sent_count(), 1);
+
+        let stored = h.service.get(batch.id()).await.unwrap();
+        assert_eq!(stored.status(), NettingBatchStatus::Settled);
+        for trade in [&first, &second] {
+            let trade = h.stored(trade).await;
+            assert!(trade.is_settled_via_netting());
+            assert_eq!(trade.settlement_tx_ref(), Some("0x0001"));
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_failure_releases_trades_to_individual_settlement() {
+        let h = harness(MockBlockchain {
+            reverts: true,
+            ..MockBlockchain::default()
+        });
+        let first = h.trade(OrderSide::Buy, 2000.0, 1.0).await;
+        let second = h.trade(OrderSide::Sell, 1900.0, 3.0).await;
+
+        let batch = h.single_batch().await;
+        let failed = h.service.settle(batch.id()).await.unwrap();
+
+        assert_eq!(failed.status(), NettingBatchStatus::Failed);
+        assert!(failed.failure_reason().unwrap().contains("reverted"));
+        for trade in [&first, &second] {
+            let mut trade = h.stored(trade).await;
+            assert!(trade.is_pending());
+            assert!(trade.netting_batch_id().is_none());
+            assert!(trade.start_settlement().is_ok());
+        }
+        assert!(h.service.settle(batch.id()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn cancel_releases_trades_before_submission_only() {
+        let h = harness(MockBlockchain::default());
+        let first = h.trade(OrderSide::Buy, 2000.0, 1.0).await;
+        h.trade(OrderSide::Buy, 2000.0, 1.0).await;
+
+        let batch = h.single_batch().await;
+        let cancelled = h.service.cancel(batch.id()).await.unwrap();
+        assert_eq!(cancelled.status(), NettingBatchStatus::Cancelled);
+        assert!(h.stored(&first).await.netting_batch_id().is_none());
+        assert!(h.service.cancel(batch.id()).await.is_err());
+
+        let rebatched = h.single_batch().await;
+        h.service.settle(rebatched.id()).await.unwrap();
+        assert!(matches!(
+            h.service.cancel(rebatched.id()).await,
+            Err(ApplicationError::Domain(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn open_windows_and_lone_trades_are_not_batched() {
+        let h = harness(MockBlockchain::default());
+        h.clock.set(Timestamp::now());
+        h.trade(OrderSide::Buy, 2000.0, 1.0).await;
+        h.trade(OrderSide::Sell, 2000.0, 1.0).await;
+        assert!(h.service.create_batches().await.unwrap().is_empty());
+
+        h.clock.advance_secs(2 * 86_400);
+        assert_eq!(h.single_batch().await.trade_ids().len(), 2);
+
+        let lone = harness(MockBlockchain::default());
+        lone.trade(OrderSide::Buy, 2000.0, 1.0).await;
+        assert!(lone.service.create_batches().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn trades_are_not_netted_before_their_window_opens() {
+        let h = harness(MockBlockchain::default());
+        let due_at = h.clock.now().add_secs(3_600);
+        h.trade_due(OrderSide::Buy, 2000.0, 1.0, Some(due_at)).await;
+        h.trade(OrderSide::Sell, 2000.0, 1.0).await;
+
+        // Only the sell is released, and one trade is not worth netting
+        assert!(h.service.create_batches().await.unwrap().is_empty());
+
+        h.clock.set(due_at);
+        assert_eq!(h.single_batch().await.trade_ids().len(), 2);
+    }
+}
@@ -0,0 +1,404 @@
+//! # Event PII Encryption
+//!
+//! Crypto-shredding of counterparty identifiers in stored events.
+//!
+//! [`PiiEncryptingEventStore`] wraps an [`EventStore`]. On append, every
+//! string under one of the [`PII_FIELDS`], at any depth of the payload, is
+//! encrypted with AES-256-GCM under the key of the counterparty it names
+//! and stored as `enc:v1:<key id>:<base64 nonce and ciphertext>`. Reads
+//! decrypt the values again, so consumers above the decorator see the
+//! payload as it was appended.
+//!
+//! Erasing a counterparty shreds its key (see
+//! [`CounterpartyKeyStore::shred`]): its identifiers in events stored
+//! before the erasure can no longer be decrypted, and reads return the
+//! pseudonym it was erased under in their place. The rest of the payload —
+//! instruments, prices, quantities, fees — is stored in clear and stays
+//! readable for regulatory retention.
+//!
+//! Events stored before the decorator was installed are in clear and are
+//! returned unchanged. A ciphertext whose key the store does not know is
+//! returned as is.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use otc_rfq::application::services::PiiEncryptingEventStore;
+//! use otc_rfq::infrastructure::persistence::in_memory::{
+//!     InMemoryCounterpartyKeyStore, InMemoryEventStore,
+//! };
+//!
+//! let event_store = Arc::new(PiiEncryptingEventStore::new(
+//!     Arc::new(InMemoryEventStore::new()),
+//!     Arc::new(InMemoryCounterpartyKeyStore::new()),
+//! ));
+//! ```
+
+use crate::domain::events::domain_event::EventType;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{CorrelationId, CounterpartyId, RfqId};
+use crate::infrastructure::persistence::counterparty_keys::{
+    CounterpartyKey, CounterpartyKeyStore, KeyLookup,
+};
+use crate::infrastructure::persistence::cursor::PageCursor;
+use crate::infrastructure::persistence::event_store::{
+    EventStore, EventStoreError, EventStoreResult, StoredEvent,
+};
+use crate::infrastructure::persistence::field_encryption;
+use crate::infrastructure::persistence::traits::RepositoryError;
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Payload fields holding counterparty identifiers.
+pub const PII_FIELDS: &[&str] = &[
+    "client_id",
+    "counterparty_id",
+    "buyer_id",
+    "seller_id",
+    "requester_id",
+    "revealed_to",
+    "from_account",
+    "to_account",
+];
+
+const ENVELOPE_PREFIX: &str = "enc:v1:";
+
+/// Returns true if `value` is an identifier encrypted by
+/// [`PiiEncryptingEventStore`].
+#[must_use]
+pub fn is_encrypted(value: &str) -> bool {
+    value.starts_with(ENVELOPE_PREFIX)
+}
+
+/// [`EventStore`] decorator encrypting counterparty identifiers in
+/// payloads with per-counterparty keys.
+#[derive(Debug)]
+pub struct PiiEncryptingEventStore {
+    inner: Arc<dyn EventStore>,
+    keys: Arc<dyn CounterpartyKeyStore>,
+}
+
+impl PiiEncryptingEventStore {
+    /// Wraps `inner`, encrypting with keys from `keys`.
+    #[must_use]
+    pub fn new(inner: Arc<dyn EventStore>, keys: Arc<dyn CounterpartyKeyStore>) -> Self {
+        Self { inner, keys }
+    }
+
+    async fn encrypt(&self, mut event: StoredEvent) -> EventStoreResult<StoredEvent> {
+        let mut owners = HashSet::new();
+        visit_pii(&mut event.payload, &mut |value| {
+            if !is_encrypted(value) {
+                owners.insert(value.clone());
+            }
+        });
+
+        let mut keys = HashMap::with_capacity(owners.len());
+        for owner in owners {
+            let key = self
+                .keys
+                .get_or_create(&CounterpartyId::new(owner.clone()))
+                .await
+                .map_err(key_store_error)?;
+            keys.insert(owner, key);
+        }
+
+        let mut failure = None;
+        visit_pii(&mut event.payload, &mut |value| {
+            if let Some(key) = keys.get(value.as_str()) {
+                match seal(key, value) {
+                    Ok(sealed) => *value = sealed,
+                    Err(e) => failure = Some(e),
+                }
+            }
+        });
+        failure.map_or(Ok(event), Err)
+    }
+
+    async fn decrypt(&self, mut events: Vec<StoredEvent>) -> EventStoreResult<Vec<StoredEvent>> {
+        let mut key_ids = HashSet::new();
+        for event in &mut events {
+            visit_pii(&mut event.payload, &mut |value| {
+                if let Some((key_id, _)) = parse_envelope(value) {
+                    key_ids.insert(key_id);
+                }
+            });
+        }
+        if key_ids.is_empty() {
+            return Ok(events);
+        }
+
+        let mut lookups = HashMap::with_capacity(key_ids.len());
+        for key_id in key_ids {
+            let lookup = self.keys.lookup(key_id).await.map_err(key_store_error)?;
+            if lookup == KeyLookup::Unknown {
+                tracing::warn!(%key_id, "Stored event refers to an unknown counterparty key");
+            }
+            lookups.insert(key_id, lookup);
+        }
+
+        let mut failure = None;
+        for event in &mut events {
+            visit_pii(&mut event.payload, &mut |value| {
+                let Some((key_id, sealed)) = parse_envelope(value) else {
+                    return;
+                };
+                let plain = match lookups.get(&key_id) {
+                    Some(KeyLookup::Active(key)) => match open(key, sealed) {
+                        Ok(plain) => plain,
+                        Err(e) => {
+                            failure = Some(e);
+                            return;
+                        }
+                    },
+                    Some(KeyLookup::Shredded(pseudonym)) => pseudonym.to_string(),
+                    Some(KeyLookup::Unknown) | None => return,
+                };
+                *value = plain;
+            });
+        }
+        failure.map_or(Ok(events), Err)
+    }
+}
+
+#[async_trait]
+impl EventStore for PiiEncryptingEventStore {
+    async fn append(&self, event: StoredEvent) -> EventStoreResult<()> {
+        let event = self.encrypt(event).await?;
+        self.inner.append(event).await
+    }
+
+    async fn get_events(&self, rfq_id: RfqId) -> EventStoreResult<Vec<StoredEvent>> {
+        self.decrypt(self.inner.get_events(rfq_id).await?).await
+    }
+
+    async fn get_events_since(&self, since: Timestamp) -> EventStoreResult<Vec<StoredEvent>> {
+        self.decrypt(self.inner.get_events_since(since).await?)
+            .await
+    }
+
+    async fn get_events_by_type(
+        &self,
+        event_type: EventType,
+    ) -> EventStoreResult<Vec<StoredEvent>> {
+        self.decrypt(self.inner.get_events_by_type(event_type).await?)
+            .await
+    }
+
+    async fn query_by_time_range(
+        &self,
+        from: Timestamp,
+        to: Timestamp,
+        event_types: Option<Vec<EventType>>,
+        limit: usize,
+        cursor: Option<&PageCursor>,
+    ) -> EventStoreResult<Vec<StoredEvent>> {
+        let events = self
+            .inner
+            .query_by_time_range(from, to, event_types, limit, cursor)
+            .await?;
+        self.decrypt(events).await
+    }
+
+    async fn query_by_correlation(
+        &self,
+        correlation_id: CorrelationId,
+    ) -> EventStoreResult<Vec<StoredEvent>> {
+        self.decrypt(self.inner.query_by_correlation(correlation_id).await?)
+            .await
+    }
+
+    async fn count(&self) -> EventStoreResult<u64> {
+        self.inner.count().await
+    }
+
+    async fn count_for_rfq(&self, rfq_id: RfqId) -> EventStoreResult<u64> {
+        self.inner.count_for_rfq(rfq_id).await
+    }
+
+    async fn next_sequence(&self, rfq_id: RfqId) -> EventStoreResult<u64> {
+        self.inner.next_sequence(rfq_id).await
+    }
+
+    async fn next_aggregate_sequence(&self, aggregate_id: Uuid) -> EventStoreResult<u64> {
+        self.inner.next_aggregate_sequence(aggregate_id).await
+    }
+}
+
+/// Calls `f` with every string under a [`PII_FIELDS`] key in `value`.
+fn visit_pii(value: &mut Value, f: &mut impl FnMut(&mut String)) {
+    match value {
+        Value::Object(fields) => {
+            for (name, field) in fields.iter_mut() {
+                match field {
+                    Value::String(text) if PII_FIELDS.contains(&name.as_str()) => f(text),
+                    _ => visit_pii(field, f),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| visit_pii(item, f)),
+        _ => {}
+    }
+}
+
+fn parse_envelope(value: &str) -> Option<(Uuid, &str)> {
+    let (key_id, sealed) = value.strip_prefix(ENVELOPE_PREFIX)?.split_once(':')?;
+    Some((key_id.parse().ok()?, sealed))
+}
+
+fn seal(key: &CounterpartyKey, plain: &str) -> EventStoreResult<String> {
+    let sealed = field_encryption::seal(&key.material, plain)
+        .ok_or_else(|| EventStoreError::serialization("failed to encrypt an identifier"))?;
+    Ok(format!("{ENVELOPE_PREFIX}{}:{sealed}", key.key_id))
+}
+
+fn open(key: &CounterpartyKey, sealed: &str) -> EventStoreResult<String> {
+    field_encryption::open(&key.material, sealed).ok_or_else(|| {
+        EventStoreError::deserialization(format!(
+            "identifier encrypted with key {} is corrupt",
+            key.key_id
+        ))
+    })
+}
+
+fn key_store_error(error: RepositoryError) -> EventStoreError {
+    EventStoreError::internal(format!("counterparty key store: {error}"))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::domain::events::rfq_events::RfqCreated;
+    use crate::domain::value_objects::{AssetClass, Instrument, OrderSide, Quantity, Symbol};
+    use crate::infrastructure::persistence::in_memory::{
+        InMemoryCounterpartyKeyStore, InMemoryEventStore,
+    };
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+
+    struct Fixture {
+        raw: Arc<InMemoryEventStore>,
+        keys: Arc<InMemoryCounterpartyKeyStore>,
+        store: PiiEncryptingEventStore,
+    }
+
+    fn fixture() -> Fixture {
+        let raw = Arc::new(InMemoryEventStore::new());
+        let keys = Arc::new(InMemoryCounterpartyKeyStore::new());
+        let store = PiiEncryptingEventStore::new(raw.clone(), keys.clone());
+        Fixture { raw, keys, store }
+    }
+
+    fn rfq_created(rfq_id: RfqId, client: &str) -> StoredEvent {
+        let event = RfqCreated::new(
+            rfq_id,
+            CounterpartyId::new(client),
+            Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build(),
+            OrderSide::Buy,
+            Quantity::new(2.5).unwrap(),
+            Timestamp::now().add_secs(60),
+        );
+        StoredEvent::from_event(&event, 1).unwrap()
+    }
+
+    #[tokio::test]
+    async fn identifiers_are_stored_encrypted_and_read_back_in_clear() {
+        let fixture = fixture();
+        let rfq_id = RfqId::new_v4();
+
+        fixture
+            .store
+            .append(rfq_created(rfq_id, "acme-trading"))
+            .await
+            .unwrap();
+
+        let raw = fixture.raw.get_events(rfq_id).await.unwrap();
+        let stored = raw[0].payload["client_id"].as_str().unwrap();
+        assert!(is_encrypted(stored));
+        assert!(!raw[0].payload.to_string().contains("acme-trading"));
+
+        let read = fixture.store.get_events(rfq_id).await.unwrap();
+        assert_eq!(read[0].payload["client_id"], "acme-trading");
+        assert_eq!(read[0].payload["quantity"], "2.5");
+    }
+
+    #[tokio::test]
+    async fn shredded_identifiers_read_as_the_pseudonym() {
+        let fixture = fixture();
+        let rfq_id = RfqId::new_v4();
+        fixture
+            .store
+            .append(rfq_created(rfq_id, "acme-trading"))
+            .await
+            .unwrap();
+        let raw = fixture.raw.get_events(rfq_id).await.unwrap();
+        let (key_id, _) = parse_envelope(raw[0].payload["client_id"].as_str().unwrap()).unwrap();
+
+        let pseudonym = CounterpartyId::new("erased-1");
+        assert!(
+            fixture
+                .keys
+                .shred(&CounterpartyId::new("acme-trading"), &pseudonym)
+                .await
+                .unwrap()
+        );
+
+        assert_eq!(
+            fixture.keys.lookup(key_id).await.unwrap(),
+            KeyLookup::Shredded(pseudonym)
+        );
+        let read = fixture.store.get_events(rfq_id).await.unwrap();
+        assert_eq!(read[0].payload["client_id"], "erased-1");
+        assert_eq!(read[0].payload["quantity"], "2.5");
+        assert_eq!(read[0].payload["side"], raw[0].payload["side"]);
+    }
+
+    #[tokio::test]
+    async fn other_counterparties_and_clear_events_are_unaffected() {
+        let fixture = fixture();
+        let legacy = RfqId::new_v4();
+        fixture
+            .raw
+            .append(rfq_created(legacy, "acme-trading"))
+            .await
+            .unwrap();
+        let other = RfqId::new_v4();
+        fixture
+            .store
+            .append(rfq_created(other, "globex"))
+            .await
+            .unwrap();
+
+        fixture
+            .keys
+            .shred(
+                &CounterpartyId::new("acme-trading"),
+                &CounterpartyId::new("erased-1"),
+            )
+            .await
+            .unwrap();
+
+        let read = fixture.store.get_events(legacy).await.unwrap();
+        assert_eq!(read[0].payload["client_id"], "acme-trading");
+        let read = fixture.store.get_events(other).await.unwrap();
+        assert_eq!(read[0].payload["client_id"], "globex");
+    }
+
+    #[test]
+    fn tampered_ciphertext_is_rejected() {
+        let key = CounterpartyKey::generate();
+        let sealed = seal(&key, "acme-trading").unwrap();
+        let (_, body) = parse_envelope(&sealed).unwrap();
+        assert_eq!(open(&key, body).unwrap(), "acme-trading");
+
+        let mut bytes = STANDARD.decode(body).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+        assert!(open(&key, &STANDARD.encode(bytes)).is_err());
+        assert!(open(&CounterpartyKey::generate(), body).is_err());
+    }
+}
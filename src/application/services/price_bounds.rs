@@ -7,6 +7,13 @@
 //! - [`FallbackReferencePriceProvider`]: Chains multiple providers with fallback
 //! - [`PriceBoundsValidator`]: Validates proposed prices are within tolerance
 //!
+//! A validator built with [`PriceBoundsValidator::with_config_store`] reads
+//! the tolerances from a [`PriceBoundsConfigStore`] on every validation, so
+//! administrator changes and per-symbol overrides apply to the next trade.
+//! With [`PriceBoundsValidator::with_liquidity_classifier`], the tier is the
+//! one a [`LiquidityClassifier`] derived from recent activity rather than the
+//! instrument's declared tier.
+//!
 //! # Fallback Chain
 //!
 //! ```text
@@ -15,6 +22,9 @@
 //!
 //! The first provider that returns a price is used. If none return a price,
 //! `DomainError::NoReferencePrice` is returned.
+//! [`FallbackReferencePriceProvider::clob_mid_first`] builds this chain
+//! around a [`ClobMidReferencePriceProvider`]; options on derivatives venues
+//! are priced by `TheoreticalReferencePriceProvider`.
 //!
 //! # Examples
 //!
@@ -28,8 +38,11 @@
 //! };
 //! ```
 
+use crate::application::services::clob_mid::ClobMidReferencePriceProvider;
+use crate::application::services::liquidity_classifier::LiquidityClassifier;
+use crate::application::services::price_bounds_config::PriceBoundsConfigStore;
 use crate::domain::errors::{DomainError, DomainResult};
-use crate::domain::value_objects::arithmetic::CheckedArithmetic;
+use crate::domain::value_objects::arithmetic::{BPS_PER_UNIT, CheckedArithmetic};
 use crate::domain::value_objects::instrument::Instrument;
 use crate::domain::value_objects::liquidity_classification::LiquidityClassification;
 use crate::domain::value_objects::price::Price;
@@ -96,6 +109,20 @@ impl FallbackReferencePriceProvider {
     pub fn new(providers: Vec<Arc<dyn ReferencePriceProvider>>) -> Self {
         Self { providers }
     }
+
+    /// Creates the default chain: the CLOB mid, then `fallbacks` in order.
+    ///
+    /// `fallbacks` are typically the theoretical and Chainlink index
+    /// providers.
+    #[must_use]
+    pub fn clob_mid_first(
+        clob_mid: Arc<ClobMidReferencePriceProvider>,
+        fallbacks: Vec<Arc<dyn ReferencePriceProvider>>,
+    ) -> Self {
+        let mut providers: Vec<Arc<dyn ReferencePriceProvider>> = vec![clob_mid];
+        providers.extend(fallbacks);
+        Self::new(providers)
+    }
 }
 
 #[async_trait]
@@ -138,6 +165,8 @@ impl ReferencePriceProvider for FallbackReferencePriceProvider {
 /// If `deviation > tolerance`, `DomainError::PriceOutOfBounds` is returned.
 pub struct PriceBoundsValidator {
     config: PriceBoundsConfig,
+    config_store: Option<Arc<PriceBoundsConfigStore>>,
+    liquidity_classifier: Option<Arc<LiquidityClassifier>>,
     reference_provider: Arc<dyn ReferencePriceProvider>,
 }
 
@@ -155,10 +184,41 @@ impl PriceBoundsValidator {
     ) -> Self {
         Self {
             config,
+            config_store: None,
+            liquidity_classifier: None,
             reference_provider,
         }
     }
 
+    /// Reads tolerances from `store` on each validation instead of the
+    /// configuration given at construction.
+    #[must_use]
+    pub fn with_config_store(mut self, store: Arc<PriceBoundsConfigStore>) -> Self {
+        self.config_store = Some(store);
+        self
+    }
+
+    /// Takes the liquidity tier of instruments from `classifier` instead of
+    /// their declared tier.
+    #[must_use]
+    pub fn with_liquidity_classifier(mut self, classifier: Arc<LiquidityClassifier>) -> Self {
+        self.liquidity_classifier = Some(classifier);
+        self
+    }
+
+    /// Returns the liquidity tier to validate `instrument` against.
+    ///
+    /// This is the classifier's cached tier if one is set, otherwise the
+    /// instrument's declared tier.
+    #[must_use]
+    pub fn liquidity_for(&self, instrument: &Instrument) -> LiquidityClassification {
+        self.liquidity_classifier
+            .as_ref()
+            .map_or(instrument.liquidity(), |classifier| {
+                classifier.classification_for(instrument)
+            })
+    }
+
     /// Validates a proposed price against reference prices.
     ///
     /// # Arguments
@@ -189,7 +249,12 @@ impl PriceBoundsValidator {
             .await?
             .ok_or(DomainError::NoReferencePrice)?;
 
-        let tolerance = self.config.tolerance_for(liquidity);
+        let tolerance = match &self.config_store {
+            Some(store) => store
+                .current()
+                .tolerance_for(instrument.symbol().as_str(), liquidity),
+            None => self.config.tolerance_for(liquidity),
+        };
 
         let deviation = compute_deviation(proposed_price, &reference)?;
 
@@ -205,11 +270,14 @@ impl PriceBoundsValidator {
         Ok(PriceBoundsResult::new(reference, source, deviation))
     }
 
-    /// Returns the current configuration.
-    #[inline]
+    /// Returns the current per-tier configuration.
+    ///
+    /// With a configuration store, this is the store's current defaults.
     #[must_use]
-    pub const fn config(&self) -> &PriceBoundsConfig {
-        &self.config
+    pub fn config(&self) -> PriceBoundsConfig {
+        self.config_store
+            .as_ref()
+            .map_or(self.config, |store| *store.current().defaults())
     }
 }
 
@@ -223,18 +291,13 @@ impl PriceBoundsValidator {
 ///
 /// Returns `DomainError::DivisionByZero` if reference price is zero.
 /// Returns arithmetic errors on overflow.
-fn compute_deviation(proposed: &Price, reference: &Price) -> DomainResult<Decimal> {
-    let ref_decimal = reference.get();
-    if ref_decimal.is_zero() {
+pub(crate) fn compute_deviation(proposed: &Price, reference: &Price) -> DomainResult<Decimal> {
+    if reference.is_zero() {
         return Err(DomainError::DivisionByZero);
     }
 
-    let proposed_decimal = proposed.get();
-    // abs(proposed - reference) — use checked subtraction, then abs
-    let diff = proposed_decimal.safe_sub(ref_decimal)?;
-    let abs_diff = diff.abs();
-    let deviation = abs_diff.safe_div(ref_decimal)?;
-    Ok(deviation)
+    let deviation_bps = proposed.deviation_bps_from(reference)?;
+    Ok(deviation_bps.abs().get().safe_div(BPS_PER_UNIT)?)
 }
 
 #[cfg(test)]
@@ -438,6 +501,8 @@ mod tests {
 
     mod validator {
         use super::*;
+        use crate::domain::value_objects::reference_price::PriceBoundsSettings;
+        use crate::infrastructure::persistence::in_memory::InMemoryPriceBoundsConfigRepository;
 
         fn make_validator(
             reference_price: f64,
@@ -700,5 +765,71 @@ mod tests {
                 .await;
             assert!(result.is_ok());
         }
+
+        // ---- Config store ----
+
+        fn store_validator(
+            settings: PriceBoundsSettings,
+        ) -> (PriceBoundsValidator, Arc<PriceBoundsConfigStore>) {
+            let store = Arc::new(PriceBoundsConfigStore::new(
+                Arc::new(InMemoryPriceBoundsConfigRepository::new()),
+                settings,
+            ));
+            let validator = make_validator(100.0, ReferencePriceSource::ClobMid)
+                .with_config_store(Arc::clone(&store));
+            (validator, store)
+        }
+
+        #[tokio::test]
+        async fn symbol_override_beats_tier_default() {
+            let settings = PriceBoundsSettings::default()
+                .with_override("BTC/USD", Decimal::new(1, 2))
+                .unwrap();
+            let (validator, _) = store_validator(settings);
+
+            // 2% deviation is inside the 5% liquid default but not the 1% override
+            let result = validator
+                .validate(
+                    &test_instrument(),
+                    &Price::new(102.0).unwrap(),
+                    LiquidityClassification::Liquid,
+                )
+                .await;
+            assert!(matches!(
+                result,
+                Err(DomainError::PriceOutOfBounds { max_tolerance_pct, .. })
+                    if max_tolerance_pct == Decimal::new(1, 2)
+            ));
+        }
+
+        #[tokio::test]
+        async fn store_change_applies_to_next_validation() {
+            let (validator, store) = store_validator(PriceBoundsSettings::default());
+            let proposed = Price::new(104.0).unwrap();
+
+            let before = validator
+                .validate(
+                    &test_instrument(),
+                    &proposed,
+                    LiquidityClassification::Liquid,
+                )
+                .await;
+            assert!(before.is_ok());
+
+            let tighter =
+                PriceBoundsConfig::new(Decimal::new(2, 2), Decimal::new(3, 2), Decimal::new(4, 2))
+                    .unwrap();
+            store.update(tighter.into(), "risk-admin").await.unwrap();
+
+            let after = validator
+                .validate(
+                    &test_instrument(),
+                    &proposed,
+                    LiquidityClassification::Liquid,
+                )
+                .await;
+            assert!(matches!(after, Err(DomainError::PriceOutOfBounds { .. })));
+            assert_eq!(validator.config(), tighter);
+        }
     }
 }
@@ -0,0 +1,247 @@
+//! # Price Bounds Configuration Store
+//!
+//! Live price bounds tolerances that administrators can change without a
+//! restart.
+//!
+//! [`PriceBoundsConfigStore`] keeps the current [`PriceBoundsSettings`]
+//! behind an [`ArcSwap`], so validations read it without locking and pick
+//! up a change on their next call. Every change is written to a
+//! [`PriceBoundsConfigRepository`] together with an audit record of who
+//! made it, when, and the settings before and after, then published as a
+//! [`PriceBoundsConfigChanged`] event.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::domain::events::PriceBoundsConfigChanged;
+use crate::domain::value_objects::reference_price::{PriceBoundsConfigChange, PriceBoundsSettings};
+use crate::infrastructure::persistence::traits::PriceBoundsConfigRepository;
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use std::fmt;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Publisher for price bounds configuration events.
+#[async_trait]
+pub trait PriceBoundsEventPublisher: Send + Sync + fmt::Debug {
+    /// Publishes a price bounds configuration change.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be published.
+    async fn publish_price_bounds_config_changed(
+        &self,
+        event: PriceBoundsConfigChanged,
+    ) -> ApplicationResult<()>;
+}
+
+/// Current price bounds settings, persisted and audited on change.
+pub struct PriceBoundsConfigStore {
+    current: ArcSwap<PriceBoundsSettings>,
+    repository: Arc<dyn PriceBoundsConfigRepository>,
+    publisher: Option<Arc<dyn PriceBoundsEventPublisher>>,
+    /// Serializes updates so audit records chain old to new.
+    update_lock: Mutex<()>,
+}
+
+impl fmt::Debug for PriceBoundsConfigStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PriceBoundsConfigStore")
+            .field("current", &self.current.load())
+            .finish_non_exhaustive()
+    }
+}
+
+impl PriceBoundsConfigStore {
+    /// Creates a store starting from `initial`, without reading `repository`.
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn PriceBoundsConfigRepository>,
+        initial: PriceBoundsSettings,
+    ) -> Self {
+        Self {
+            current: ArcSwap::from_pointee(initial),
+            repository,
+            publisher: None,
+            update_lock: Mutex::new(()),
+        }
+    }
+
+    /// Creates a store from the settings saved in `repository`, or
+    /// `fallback` if none have been saved yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::RepositoryError` if loading fails.
+    pub async fn load(
+        repository: Arc<dyn PriceBoundsConfigRepository>,
+        fallback: PriceBoundsSettings,
+    ) -> ApplicationResult<Self> {
+        let initial = repository
+            .load()
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?
+            .unwrap_or(fallback);
+        Ok(Self::new(repository, initial))
+    }
+
+    /// Sets the publisher notified of configuration changes.
+    #[must_use]
+    pub fn with_publisher(mut self, publisher: Arc<dyn PriceBoundsEventPublisher>) -> Self {
+        self.publisher = Some(publisher);
+        self
+    }
+
+    /// Returns the settings in force.
+    #[must_use]
+    pub fn current(&self) -> Arc<PriceBoundsSettings> {
+        self.current.load_full()
+    }
+
+    /// Replaces the settings, recording `changed_by` in the audit trail.
+    ///
+    /// The new settings are persisted before they take effect; if saving
+    /// fails the current settings are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::Validation` if a tolerance is negative,
+    /// or `ApplicationError::RepositoryError` if saving fails.
+    pub async fn update(
+        &self,
+        settings: PriceBoundsSettings,
+        changed_by: impl Into<String>,
+    ) -> ApplicationResult<PriceBoundsConfigChange> {
+        if !settings.is_valid() {
+            return Err(ApplicationError::validation(
+                "price bounds tolerances must not be negative",
+            ));
+        }
+
+        let change = {
+            let _guard = self.update_lock.lock().await;
+            let previous = self.current().as_ref().clone();
+            let change = PriceBoundsConfigChange::new(changed_by, previous, settings);
+            self.repository
+                .save(&change)
+                .await
+                .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?;
+            self.current.store(Arc::new(change.current.clone()));
+            change
+        };
+
+        tracing::info!(
+            changed_by = %change.changed_by,
+            previous = %change.previous,
+            current = %change.current,
+            "price bounds configuration changed"
+        );
+
+        if let Some(publisher) = &self.publisher
+            && let Err(e) = publisher
+                .publish_price_bounds_config_changed(PriceBoundsConfigChanged::new(&change))
+                .await
+        {
+            tracing::warn!(error = %e, "failed to publish price bounds configuration change");
+        }
+
+        Ok(change)
+    }
+
+    /// Returns the audit trail of configuration changes, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::RepositoryError` if loading fails.
+    pub async fn history(&self) -> ApplicationResult<Vec<PriceBoundsConfigChange>> {
+        self.repository
+            .history()
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::reference_price::PriceBoundsConfig;
+    use crate::infrastructure::persistence::in_memory::InMemoryPriceBoundsConfigRepository;
+    use rust_decimal::Decimal;
+    use std::sync::Mutex as StdMutex;
+
+    #[derive(Debug, Default)]
+    struct RecordingPublisher {
+        events: StdMutex<Vec<PriceBoundsConfigChanged>>,
+    }
+
+    #[async_trait]
+    impl PriceBoundsEventPublisher for RecordingPublisher {
+        async fn publish_price_bounds_config_changed(
+            &self,
+            event: PriceBoundsConfigChanged,
+        ) -> ApplicationResult<()> {
+            self.events.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    fn tighter() -> PriceBoundsSettings {
+        PriceBoundsSettings::default()
+            .with_override("ETH/USD", Decimal::new(2, 2))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn update_writes_audit_row_and_publishes_event() {
+        let repository = Arc::new(InMemoryPriceBoundsConfigRepository::new());
+        let publisher = Arc::new(RecordingPublisher::default());
+        let store = PriceBoundsConfigStore::new(repository.clone(), PriceBoundsSettings::default())
+            .with_publisher(publisher.clone());
+
+        store.update(tighter(), "alice").await.unwrap();
+
+        let history = store.history().await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].changed_by, "alice");
+        assert_eq!(history[0].previous, PriceBoundsSettings::default());
+        assert_eq!(history[0].current, tighter());
+        assert_eq!(repository.load().await.unwrap(), Some(tighter()));
+
+        let events = publisher.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].changed_by, "alice");
+        assert_eq!(events[0].current, tighter());
+    }
+
+    #[tokio::test]
+    async fn load_prefers_saved_settings() {
+        let repository = Arc::new(InMemoryPriceBoundsConfigRepository::new());
+        PriceBoundsConfigStore::new(repository.clone(), PriceBoundsSettings::default())
+            .update(tighter(), "alice")
+            .await
+            .unwrap();
+
+        let store = PriceBoundsConfigStore::load(repository, PriceBoundsSettings::default())
+            .await
+            .unwrap();
+        assert_eq!(*store.current(), tighter());
+    }
+
+    #[tokio::test]
+    async fn update_rejects_negative_tolerances() {
+        let store = PriceBoundsConfigStore::new(
+            Arc::new(InMemoryPriceBoundsConfigRepository::new()),
+            PriceBoundsSettings::default(),
+        );
+        let negative: PriceBoundsSettings = serde_json::from_value(serde_json::json!({
+            "defaults": PriceBoundsConfig::default(),
+            "overrides": { "BTC/USD": "-0.01" },
+        }))
+        .unwrap();
+
+        let result = store.update(negative, "alice").await;
+        assert!(matches!(result, Err(ApplicationError::Validation(_))));
+        assert_eq!(*store.current(), PriceBoundsSettings::default());
+        assert!(store.history().await.unwrap().is_empty());
+    }
+}
@@ -5,19 +5,150 @@
 //! This module provides the [`QuoteAggregationEngine`] which coordinates
 //! concurrent quote collection from multiple venues and applies ranking
 //! strategies to the results.
-
+//!
+//! Multi-leg RFQs are quoted as packages: venues that support multi-leg
+//! quoting price the whole strategy, others are asked for each leg and a
+//! package is synthesized from the leg quotes.
+//!
+//! Two-way RFQs are quoted on both sides. Each side is deduplicated, ranked
+//! and truncated to [`AggregationConfig::max_quotes`] on its own; the
+//! ranked buy side comes first.
+//!
+//! # Maker Deduplication
+//!
+//! The same market maker can stream into several venues, so one piece of
+//! liquidity may arrive as multiple quotes. When adapters attach a maker
+//! identity to [`QuoteMetadata`], quotes sharing an identity are collapsed
+//! to the better-priced one before ranking, and each suppression is
+//! recorded as a [`DeduplicatedQuote`]. Quotes without an identity are never
+//! deduplicated.
+//!
+//! [`QuoteMetadata`]: crate::domain::entities::quote::QuoteMetadata
+//!
+//! # Quote Validity Floor
+//!
+//! Quotes valid for only a few hundred milliseconds expire before a client
+//! can select them. When [`AggregationConfig::min_quote_ttl_ms`] (or the
+//! venue's own override) is set, a quote expiring sooner is re-requested
+//! with a TTL hint if the venue accepts one, and otherwise rejected and
+//! counted in [`AggregationResult::rejected_short_ttl`].
+//!
+//! # Retries
+//!
+//! Quote requests are not retried by default. With
+//! [`QuoteAggregationEngine::with_retry`], transient venue errors are
+//! retried within the per-venue timeout, honoring any `Retry-After` the
+//! venue sent, and each retry is drawn from that venue's shared
+//! [`RetryBudget`](crate::application::services::retry::RetryBudget).
+//!
+//! # Circuit Breakers
+//!
+//! With [`QuoteAggregationEngine::with_circuit_breakers`], each venue is
+//! asked only if its breaker admits the request, and the outcome is
+//! recorded on the breaker. Timeouts and connection or venue-side errors
+//! count as failures; a venue declining to quote does not.
+//!
+//! # Concurrency Limits
+//!
+//! With [`QuoteAggregationEngine::with_request_gate`], each request first
+//! waits for a permit from the venue's
+//! [`VenueRequestGate`] pool. The wait counts against the per-venue
+//! timeout; a request that gets no permit in time is not sent, fails with
+//! [`CONCURRENCY_LIMIT_REASON`] and is published as a `QuoteRequestFailed`
+//! event.
+//!
+//! # Quote Reuse
+//!
+//! With [`QuoteAggregationEngine::with_quote_reuse`], single-leg RFQs
+//! identical to one aggregated moments ago start from copies of its
+//! still-valid quotes (see [`QuoteReuseCache`]). Only venues without a
+//! reusable quote are asked; reused quotes count as collected, and their
+//! venues as responding.
+//!
+//! # Quote Price Sanity
+//!
+//! With [`QuoteAggregationEngine::with_quote_sanity`], quotes priced
+//! implausibly far from the market are dropped before they can rank. Each
+//! collected quote is checked against the instrument's reference price,
+//! looked up while the venues are asked; without one, quotes more than the
+//! configured distance from the median of their side are dropped once
+//! collection completes (see [`QuoteSanityFilter`]). Every rejection is
+//! published as a `QuoteRequestFailed` event carrying the deviation, and a
+//! venue left with no quotes is reported as
+//! [`VenueOutcomeKind::FilteredPriceDeviation`].
+//!
+//! # All-In Cost
+//!
+//! With [`QuoteAggregationEngine::with_all_in_costs`], quotes from DeFi
+//! venues carry their settlement gas in the quote currency before ranking,
+//! for strategies such as `AllInCostStrategy` to rank on.
+//!
+//! # Client Venue Credentials
+//!
+//! With [`QuoteAggregationEngine::with_venue_credentials`], a client with
+//! its own credentials for a venue is quoted there on its own account:
+//! venues that support it are asked through
+//! [`VenueAdapter::with_credential_override`], and all others with the
+//! platform's credentials. A venue whose client credentials cannot be
+//! looked up or applied is reported as an error rather than asked with the
+//! platform's. RFQs quoted with client credentials bypass quote reuse, so
+//! quotes priced for one account never reach another.
+//!
+//! # Venue Outcomes
+//!
+//! Every result lists a [`VenueOutcome`] per venue considered: whether it
+//! responded and how fast, timed out, errored, had all its quotes filtered,
+//! or was excluded before being asked. With
+//! [`QuoteAggregationEngine::with_report_store`] the outcomes are saved as
+//! the RFQ's [`AggregationReport`], and with an event publisher a
+//! `QuoteCollectionCompleted` event carries their counts. The report also
+//! keeps each raw quote's rank, score and score breakdown, so a ranking can
+//! be explained after the fact.
+
+use crate::application::services::all_in_cost::AllInCostCalculator;
+use crate::application::services::circuit_breaker::{CircuitBreaker, CircuitBreakerRegistry};
+use crate::application::services::multi_leg_quote_collector::{
+    MultiLegQuoteCollector, VenueQuoteResult,
+};
+use crate::application::services::quote_reuse_cache::{QuoteReuseCache, QuoteReuseKey};
+use crate::application::services::quote_sanity::{QuoteSanityFilter, QuoteSanityRejection};
 use crate::application::services::ranking_strategy::{
     RankedNormalizedQuote, RankedQuote, RankingStrategy,
 };
-use crate::application::use_cases::collect_quotes::VenueRegistry;
+use crate::application::services::retry::{
+    RetryBudgets, RetryError, RetryPolicy, execute_with_retry_budget,
+};
+use crate::application::services::venue_request_gate::{
+    CONCURRENCY_LIMIT_REASON, VenueRequestGate,
+};
+use crate::application::use_cases::collect_quotes::{QuoteEventPublisher, VenueRegistry};
+use crate::domain::entities::package_quote::PackageQuote;
 use crate::domain::entities::quote::Quote;
 use crate::domain::entities::quote_normalizer::{NormalizedQuote, QuoteType};
 use crate::domain::entities::rfq::Rfq;
-use crate::infrastructure::venues::error::VenueError;
+use crate::domain::events::rfq_events::{QuoteCollectionCompleted, QuoteRequestFailed};
+use crate::domain::value_objects::strategy::Strategy;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    AggregationReport, Clock, OrderSide, Price, QuoteId, QuoteRanking, SystemClock,
+    VenueExclusionReason, VenueId, VenueOutcome, VenueOutcomeKind,
+};
+use crate::infrastructure::metrics;
+use crate::infrastructure::persistence::traits::{
+    AggregationReportRepository, VenueCredentialRepository,
+};
+use crate::infrastructure::venues::error::{VenueError, VenueResult};
+use crate::infrastructure::venues::traits::VenueAdapter;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::FromPrimitive;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::time::timeout;
+use tokio_util::sync::CancellationToken;
+use tracing::{Instrument, Span, field, info_span, instrument};
 
 /// Configuration for quote aggregation.
 #[derive(Debug, Clone)]
@@ -30,6 +161,8 @@ pub struct AggregationConfig {
     pub max_quotes: Option<usize>,
     /// Per-venue timeout in milliseconds.
     pub per_venue_timeout_ms: u64,
+    /// Minimum remaining validity of a quote in milliseconds (0 disables the floor).
+    pub min_quote_ttl_ms: u64,
 }
 
 impl Default for AggregationConfig {
@@ -39,6 +172,7 @@ impl Default for AggregationConfig {
             min_quotes: 1,
             max_quotes: None,
             per_venue_timeout_ms: 5000,
+            min_quote_ttl_ms: 0,
         }
     }
 }
@@ -73,6 +207,24 @@ impl AggregationConfig {
         self.per_venue_timeout_ms = timeout_ms;
         self
     }
+
+    /// Sets the minimum quote time-to-live.
+    #[must_use]
+    pub fn with_min_quote_ttl(mut self, min_quote_ttl_ms: u64) -> Self {
+        self.min_quote_ttl_ms = min_quote_ttl_ms;
+        self
+    }
+}
+
+/// A quote suppressed because its maker already quoted at a better price.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeduplicatedQuote {
+    /// Identity of the maker behind both quotes.
+    pub maker_identity: String,
+    /// The better-priced quote that was kept.
+    pub kept: QuoteId,
+    /// The quote that was dropped.
+    pub suppressed: QuoteId,
 }
 
 /// Result of quote aggregation.
@@ -80,7 +232,7 @@ impl AggregationConfig {
 pub enum AggregationResult {
     /// Raw quotes without normalization.
     Raw {
-        /// Ranked quotes (best first).
+        /// Ranked quotes (best first, per side).
         ranked_quotes: Vec<RankedQuote>,
         /// Total quotes collected before filtering.
         total_collected: usize,
@@ -90,10 +242,16 @@ pub enum AggregationResult {
         venues_responded: usize,
         /// Number of quotes filtered out (expired, invalid).
         filtered_count: usize,
+        /// Number of quotes rejected for expiring within the validity floor.
+        rejected_short_ttl: usize,
+        /// Quotes dropped as duplicates of the same maker.
+        deduplicated: Vec<DeduplicatedQuote>,
+        /// What happened with each venue.
+        venue_outcomes: Vec<VenueOutcome>,
     },
     /// Normalized quotes with FX conversion and fee inclusion.
     Normalized {
-        /// Ranked normalized quotes (best first).
+        /// Ranked normalized quotes (best first, per side).
         ranked_quotes: Vec<RankedNormalizedQuote>,
         /// Total quotes collected before filtering.
         total_collected: usize,
@@ -103,6 +261,27 @@ pub enum AggregationResult {
         venues_responded: usize,
         /// Number of quotes filtered out (expired, invalid).
         filtered_count: usize,
+        /// Number of quotes rejected for expiring within the validity floor.
+        rejected_short_ttl: usize,
+        /// Quotes dropped as duplicates of the same maker.
+        deduplicated: Vec<DeduplicatedQuote>,
+        /// What happened with each venue.
+        venue_outcomes: Vec<VenueOutcome>,
+    },
+    /// Package quotes for a multi-leg RFQ.
+    Package {
+        /// Ranked package quotes (best net price first).
+        ranked_quotes: Vec<PackageQuote>,
+        /// Total quotes collected before filtering.
+        total_collected: usize,
+        /// Number of venues queried.
+        venues_queried: usize,
+        /// Number of venues that responded.
+        venues_responded: usize,
+        /// Number of quotes filtered out (expired, invalid).
+        filtered_count: usize,
+        /// What happened with each venue.
+        venue_outcomes: Vec<VenueOutcome>,
     },
 }
 
@@ -115,6 +294,7 @@ impl AggregationResult {
             AggregationResult::Normalized { ranked_quotes, .. } => {
                 ranked_quotes.len() >= min_quotes
             }
+            AggregationResult::Package { ranked_quotes, .. } => ranked_quotes.len() >= min_quotes,
         }
     }
 
@@ -124,6 +304,7 @@ impl AggregationResult {
         match self {
             AggregationResult::Raw { ranked_quotes, .. } => ranked_quotes.len(),
             AggregationResult::Normalized { ranked_quotes, .. } => ranked_quotes.len(),
+            AggregationResult::Package { ranked_quotes, .. } => ranked_quotes.len(),
         }
     }
 
@@ -137,6 +318,9 @@ impl AggregationResult {
             AggregationResult::Normalized {
                 total_collected, ..
             } => *total_collected,
+            AggregationResult::Package {
+                total_collected, ..
+            } => *total_collected,
         }
     }
 
@@ -146,8 +330,67 @@ impl AggregationResult {
         match self {
             AggregationResult::Raw { venues_queried, .. } => *venues_queried,
             AggregationResult::Normalized { venues_queried, .. } => *venues_queried,
+            AggregationResult::Package { venues_queried, .. } => *venues_queried,
+        }
+    }
+
+    /// Returns the number of quotes rejected for expiring within the validity floor.
+    ///
+    /// Package results are never checked against the floor.
+    #[must_use]
+    pub fn rejected_short_ttl(&self) -> usize {
+        match self {
+            AggregationResult::Raw {
+                rejected_short_ttl, ..
+            } => *rejected_short_ttl,
+            AggregationResult::Normalized {
+                rejected_short_ttl, ..
+            } => *rejected_short_ttl,
+            AggregationResult::Package { .. } => 0,
+        }
+    }
+
+    /// Returns the quotes suppressed by maker deduplication.
+    ///
+    /// Package results are never deduplicated.
+    #[must_use]
+    pub fn deduplicated(&self) -> &[DeduplicatedQuote] {
+        match self {
+            AggregationResult::Raw { deduplicated, .. } => deduplicated,
+            AggregationResult::Normalized { deduplicated, .. } => deduplicated,
+            AggregationResult::Package { .. } => &[],
+        }
+    }
+
+    /// Returns what happened with each venue.
+    #[must_use]
+    pub fn venue_outcomes(&self) -> &[VenueOutcome] {
+        match self {
+            AggregationResult::Raw { venue_outcomes, .. } => venue_outcomes,
+            AggregationResult::Normalized { venue_outcomes, .. } => venue_outcomes,
+            AggregationResult::Package { venue_outcomes, .. } => venue_outcomes,
         }
     }
+
+    /// Returns how each raw quote was ranked and scored.
+    ///
+    /// Empty for normalized and package results, which are not explained.
+    #[must_use]
+    pub fn quote_rankings(&self) -> Vec<QuoteRanking> {
+        let AggregationResult::Raw { ranked_quotes, .. } = self else {
+            return Vec::new();
+        };
+        ranked_quotes
+            .iter()
+            .map(|ranked| QuoteRanking {
+                quote_id: ranked.quote.id(),
+                venue_id: ranked.quote.venue_id().clone(),
+                rank: ranked.rank,
+                score: Decimal::from_f64(ranked.score).unwrap_or_default(),
+                score_breakdown: ranked.score_breakdown.clone(),
+            })
+            .collect()
+    }
 }
 
 /// Error type for aggregation operations.
@@ -164,8 +407,22 @@ pub enum AggregationError {
     },
     /// Overall timeout exceeded.
     Timeout,
+    /// Collection was cancelled, typically by a shutdown.
+    Cancelled,
     /// All venues failed.
     AllVenuesFailed(Vec<String>),
+    /// The symbol has no mapping on any of the venues queried.
+    UnmappedSymbol {
+        /// The unmapped symbol.
+        symbol: String,
+        /// Venues that rejected the symbol.
+        venues: Vec<VenueId>,
+    },
+    /// The RFQ is scheduled and its activation time has not been reached.
+    NotYetActive {
+        /// When the RFQ may start collecting quotes.
+        activate_at: Timestamp,
+    },
 }
 
 impl fmt::Display for AggregationError {
@@ -183,9 +440,22 @@ impl fmt::Display for AggregationError {
                 )
             }
             Self::Timeout => write!(f, "quote collection timed out"),
+            Self::Cancelled => write!(f, "quote collection cancelled"),
             Self::AllVenuesFailed(errors) => {
                 write!(f, "all venues failed: {}", errors.join(", "))
             }
+            Self::UnmappedSymbol { symbol, venues } => {
+                let venues: Vec<String> = venues.iter().map(ToString::to_string).collect();
+                write!(
+                    f,
+                    "symbol {} is not mapped on venues: {}",
+                    symbol,
+                    venues.join(", ")
+                )
+            }
+            Self::NotYetActive { activate_at } => {
+                write!(f, "RFQ is scheduled to activate at {}", activate_at)
+            }
         }
     }
 }
@@ -202,6 +472,18 @@ pub struct QuoteAggregationEngine {
     ranking_strategy: Arc<dyn RankingStrategy>,
     config: AggregationConfig,
     quote_normalizer: Option<Arc<crate::domain::services::quote_normalizer::QuoteNormalizer>>,
+    clock: Arc<dyn Clock>,
+    cancellation: CancellationToken,
+    retry_policy: RetryPolicy,
+    retry_budgets: Arc<RetryBudgets>,
+    circuit_breakers: Option<Arc<CircuitBreakerRegistry>>,
+    request_gate: Option<Arc<VenueRequestGate>>,
+    event_publisher: Option<Arc<dyn QuoteEventPublisher>>,
+    quote_reuse: Option<Arc<QuoteReuseCache>>,
+    all_in_costs: Option<Arc<AllInCostCalculator>>,
+    quote_sanity: Option<Arc<QuoteSanityFilter>>,
+    report_store: Option<Arc<dyn AggregationReportRepository>>,
+    venue_credentials: Option<Arc<dyn VenueCredentialRepository>>,
 }
 
 impl QuoteAggregationEngine {
@@ -217,6 +499,18 @@ impl QuoteAggregationEngine {
             ranking_strategy,
             config,
             quote_normalizer: None,
+            clock: Arc::new(SystemClock),
+            cancellation: CancellationToken::new(),
+            retry_policy: RetryPolicy::no_retry(),
+            retry_budgets: Arc::new(RetryBudgets::default()),
+            circuit_breakers: None,
+            request_gate: None,
+            event_publisher: None,
+            quote_reuse: None,
+            all_in_costs: None,
+            quote_sanity: None,
+            report_store: None,
+            venue_credentials: None,
         }
     }
 
@@ -250,9 +544,117 @@ impl QuoteAggregationEngine {
             ranking_strategy,
             config,
             quote_normalizer: Some(quote_normalizer),
+            clock: Arc::new(SystemClock),
+            cancellation: CancellationToken::new(),
+            retry_policy: RetryPolicy::no_retry(),
+            retry_budgets: Arc::new(RetryBudgets::default()),
+            circuit_breakers: None,
+            request_gate: None,
+            event_publisher: None,
+            quote_reuse: None,
+            all_in_costs: None,
+            quote_sanity: None,
+            report_store: None,
+            venue_credentials: None,
         }
     }
 
+    /// Sets the clock used to filter expired quotes.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Sets the token that cancels in-flight venue requests.
+    ///
+    /// Once cancelled, collection stops waiting on venues and returns
+    /// [`AggregationError::Cancelled`].
+    #[must_use]
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    /// Retries transient venue errors under `policy`, drawing each retry
+    /// from the venue's budget in `budgets`.
+    ///
+    /// Retries happen within the per-venue timeout.
+    #[must_use]
+    pub fn with_retry(mut self, policy: RetryPolicy, budgets: Arc<RetryBudgets>) -> Self {
+        self.retry_policy = policy;
+        self.retry_budgets = budgets;
+        self
+    }
+
+    /// Guards each venue with its breaker in `breakers`, keyed by venue ID.
+    ///
+    /// A venue whose breaker rejects the request is not asked and is
+    /// reported as unavailable.
+    #[must_use]
+    pub fn with_circuit_breakers(mut self, breakers: Arc<CircuitBreakerRegistry>) -> Self {
+        self.circuit_breakers = Some(breakers);
+        self
+    }
+
+    /// Limits the requests in flight to each venue with `gate`.
+    ///
+    /// A request waits for a permit within the per-venue timeout and fails
+    /// with [`CONCURRENCY_LIMIT_REASON`] if none frees up.
+    #[must_use]
+    pub fn with_request_gate(mut self, gate: Arc<VenueRequestGate>) -> Self {
+        self.request_gate = Some(gate);
+        self
+    }
+
+    /// Publishes a `QuoteRequestFailed` event for each request refused by
+    /// the request gate or quote rejected by the price sanity check, and a
+    /// `QuoteCollectionCompleted` event for each successful aggregation.
+    #[must_use]
+    pub fn with_event_publisher(mut self, publisher: Arc<dyn QuoteEventPublisher>) -> Self {
+        self.event_publisher = Some(publisher);
+        self
+    }
+
+    /// Reuses recent quotes from `cache` for identical RFQs and caches the
+    /// quotes each aggregation collects.
+    #[must_use]
+    pub fn with_quote_reuse(mut self, cache: Arc<QuoteReuseCache>) -> Self {
+        self.quote_reuse = Some(cache);
+        self
+    }
+
+    /// Computes the gas-inclusive price of DeFi quotes before ranking.
+    #[must_use]
+    pub fn with_all_in_costs(mut self, calculator: Arc<AllInCostCalculator>) -> Self {
+        self.all_in_costs = Some(calculator);
+        self
+    }
+
+    /// Drops quotes that `filter` finds priced too far from the reference
+    /// price or, without one, from the median quote.
+    #[must_use]
+    pub fn with_quote_sanity(mut self, filter: Arc<QuoteSanityFilter>) -> Self {
+        self.quote_sanity = Some(filter);
+        self
+    }
+
+    /// Saves the per-venue outcomes of each successful aggregation to
+    /// `store` as the RFQ's aggregation report.
+    #[must_use]
+    pub fn with_report_store(mut self, store: Arc<dyn AggregationReportRepository>) -> Self {
+        self.report_store = Some(store);
+        self
+    }
+
+    /// Asks venues on behalf of clients with their own credentials for
+    /// them in `store`.
+    #[must_use]
+    pub fn with_venue_credentials(mut self, store: Arc<dyn VenueCredentialRepository>) -> Self {
+        self.venue_credentials = Some(store);
+        self
+    }
+
     /// Collects quotes from all venues and ranks them.
     ///
     /// # Arguments
@@ -266,78 +668,265 @@ impl QuoteAggregationEngine {
     /// # Errors
     ///
     /// Returns an error if:
+    /// - The RFQ is scheduled to activate later
     /// - No venues are available
     /// - Overall timeout is exceeded
     /// - Insufficient quotes are collected
+    #[instrument(skip_all, fields(rfq_id = %rfq.id()))]
     pub async fn collect_and_rank(&self, rfq: &Rfq) -> AggregationResultType<AggregationResult> {
-        // Get available venues
-        let venues = self.venue_registry.get_available_venues().await;
-        let venues_queried = venues.len();
+        let start = Instant::now();
+        let result = self.aggregate(rfq).await;
+        let outcome = match &result {
+            Ok(_) => "ok",
+            Err(AggregationError::Timeout) => "timeout",
+            Err(AggregationError::Cancelled) => "cancelled",
+            Err(_) => "error",
+        };
+        metrics::record_quote_collection(start.elapsed(), outcome);
+        if let Ok(result) = &result {
+            self.report(rfq, result).await;
+        }
+        result
+    }
 
-        if venues.is_empty() {
+    /// Saves the aggregation report of `result` and publishes its
+    /// `QuoteCollectionCompleted` event, if configured.
+    ///
+    /// Failures are logged; they do not fail the aggregation.
+    async fn report(&self, rfq: &Rfq, result: &AggregationResult) {
+        if self.report_store.is_none() && self.event_publisher.is_none() {
+            return;
+        }
+        let report =
+            AggregationReport::new(rfq.id(), result.venue_outcomes().to_vec(), self.clock.now())
+                .with_quote_rankings(result.quote_rankings());
+        if let Some(store) = &self.report_store
+            && let Err(e) = store.save(&report).await
+        {
+            tracing::warn!(rfq_id = %rfq.id(), "Failed to save aggregation report: {}", e);
+        }
+        if let Some(publisher) = &self.event_publisher {
+            let quotes_received = u32::try_from(result.total_collected()).unwrap_or(u32::MAX);
+            let event = QuoteCollectionCompleted::from_report(&report, quotes_received);
+            if let Err(e) = publisher.publish_quote_collection_completed(event).await {
+                tracing::warn!("Failed to publish QuoteCollectionCompleted event: {}", e);
+            }
+        }
+    }
+
+    async fn aggregate(&self, rfq: &Rfq) -> AggregationResultType<AggregationResult> {
+        if rfq.is_scheduled_at(self.clock.now())
+            && let Some(activate_at) = rfq.activate_at()
+        {
+            return Err(AggregationError::NotYetActive { activate_at });
+        }
+
+        if let Some(strategy) = rfq.strategy() {
+            return self.collect_and_rank_packages(strategy, rfq).await;
+        }
+
+        // Get available venues that can be shown the RFQ's size disclosure,
+        // with the client's own credentials where it has them
+        let ClientVenues {
+            venues,
+            failed,
+            shared,
+        } = self
+            .client_venues(rfq, self.venue_registry.get_available_venues().await)
+            .await;
+        let venues_queried = venues
+            .iter()
+            .filter(|v| v.supports_quantity_disclosure(&rfq.quantity_disclosure()))
+            .count();
+
+        if venues_queried == 0 {
             return Err(AggregationError::NoVenuesAvailable);
         }
 
-        // Collect quotes with overall timeout
-        let overall_timeout = Duration::from_millis(self.config.timeout_ms);
-        let collection_result = timeout(overall_timeout, self.collect_from_venues(rfq)).await;
+        // Start from recent quotes of an identical RFQ, if reuse is enabled
+        // and the RFQ is quoted on the platform's account
+        let reuse = self
+            .quote_reuse
+            .as_ref()
+            .filter(|_| shared)
+            .and_then(|cache| cache.key_for(rfq).map(|key| (cache, key)));
+        let reused = match &reuse {
+            Some((cache, key)) => self.reuse_quotes(cache, key, rfq, &venues).await,
+            None => Vec::new(),
+        };
+        let covered: HashSet<&VenueId> = reused.iter().map(Quote::venue_id).collect();
 
-        let (quotes, errors) = match collection_result {
+        // Collect quotes from the remaining venues with overall timeout,
+        // looking up the sanity check's reference price meanwhile
+        let overall_timeout = Duration::from_millis(self.config.timeout_ms);
+        let collection_result = timeout(overall_timeout, async {
+            tokio::join!(
+                self.collect_from_venues(rfq, &venues, &failed, &covered),
+                self.sanity_reference(rfq)
+            )
+        })
+        .await;
+
+        let (
+            VenueCollection {
+                quotes,
+                errors,
+                unmapped_venues,
+                rejected_short_ttl,
+                mut venue_outcomes,
+            },
+            reference,
+        ) = match collection_result {
             Ok(result) => result,
             Err(_) => return Err(AggregationError::Timeout),
         };
+        if self.cancellation.is_cancelled() {
+            return Err(AggregationError::Cancelled);
+        }
+        let collected = quotes.len();
+        let mut quotes = self
+            .screen_prices(rfq, quotes, reference, &mut venue_outcomes)
+            .await;
+        let rejected_price = collected - quotes.len();
+        if let Some((cache, key)) = reuse {
+            cache.store(key, &quotes, self.clock.now());
+        }
+        // Reused quotes answer for their venues without a request
+        let mut reused_venues = HashSet::new();
+        for quote in &reused {
+            if reused_venues.insert(quote.venue_id().clone()) {
+                venue_outcomes.push(VenueOutcome::new(
+                    quote.venue_id().clone(),
+                    VenueOutcomeKind::Responded {
+                        latency_ms: None,
+                        quote_id: quote.id().to_string(),
+                    },
+                ));
+            }
+        }
+        quotes.extend(reused);
 
-        let total_collected = quotes.len();
+        let total_collected = quotes.len() + rejected_price;
         let venues_responded = venues_queried - errors.len();
 
         // Filter expired quotes
-        let valid_quotes: Vec<Quote> = quotes.into_iter().filter(|q| !q.is_expired()).collect();
+        let now = self.clock.now();
+        let valid_quotes: Vec<Quote> = quotes
+            .into_iter()
+            .filter(|q| !q.is_expired_at(now))
+            .collect();
         let filtered_count = total_collected - valid_quotes.len();
+        mark_expired(
+            &mut venue_outcomes,
+            valid_quotes.iter().map(Quote::venue_id),
+        );
+
+        // Quotes from shadow venues are never offered to the client
+        let mut shadow_venues = HashSet::new();
+        for venue in &venues {
+            if self
+                .venue_registry
+                .venue_mode(venue.venue_id())
+                .await
+                .is_shadow()
+            {
+                shadow_venues.insert(venue.venue_id().clone());
+            }
+        }
+        let valid_quotes: Vec<Quote> = valid_quotes
+            .into_iter()
+            .filter(|q| !shadow_venues.contains(q.venue_id()))
+            .collect();
+
+        // Split by side, then collapse quotes from the same maker routed
+        // through several venues
+        let mut deduplicated = Vec::new();
+        let mut by_side: Vec<(OrderSide, Vec<Quote>)> = rfq
+            .direction()
+            .sides()
+            .iter()
+            .map(|&side| {
+                let quotes = valid_quotes
+                    .iter()
+                    .filter(|q| rfq.side_of(q) == side)
+                    .cloned()
+                    .collect();
+                let (kept, suppressed) = deduplicate_by_maker(quotes, side);
+                deduplicated.extend(suppressed);
+                (side, kept)
+            })
+            .collect();
+        let valid_count: usize = by_side.iter().map(|(_, quotes)| quotes.len()).sum();
+
+        // Fold settlement gas into the prices of DeFi quotes
+        if let Some(calculator) = &self.all_in_costs {
+            for (side, quotes) in &mut by_side {
+                *quotes = calculator
+                    .annotate(
+                        std::mem::take(quotes),
+                        *side,
+                        rfq.instrument().quote_asset(),
+                    )
+                    .await;
+            }
+        }
+
+        // Fail without guessing when no venue could map the symbol
+        if valid_count == 0 && !errors.is_empty() && unmapped_venues.len() == errors.len() {
+            return Err(AggregationError::UnmappedSymbol {
+                symbol: rfq.instrument().symbol().to_string(),
+                venues: unmapped_venues,
+            });
+        }
 
         // Check if all venues failed
-        if valid_quotes.is_empty() && !errors.is_empty() {
+        if valid_count == 0 && !errors.is_empty() {
             return Err(AggregationError::AllVenuesFailed(errors));
         }
 
         // Check minimum quotes requirement
-        if valid_quotes.len() < self.config.min_quotes {
+        if valid_count < self.config.min_quotes {
             return Err(AggregationError::InsufficientQuotes {
-                collected: valid_quotes.len(),
+                collected: valid_count,
                 required: self.config.min_quotes,
             });
         }
 
-        // Normalize and rank quotes if normalizer is configured
+        // Normalize and rank each side independently if normalizer is configured
         if let Some(normalizer) = &self.quote_normalizer {
-            // Normalize quotes
-            let normalized_quotes: Vec<NormalizedQuote> = valid_quotes
-                .iter()
-                .map(|q| {
-                    // Derive QuoteType from last_look_required to preserve semantics
-                    let quote_type = if q.last_look_required() {
-                        QuoteType::LastLook
-                    } else {
-                        QuoteType::Firm
-                    };
-
-                    // Extract source currency from metadata for FX conversion
-                    let source_currency = q
-                        .metadata()
-                        .and_then(|m| m.get("currency"))
-                        .map(|s| s.as_str());
-
-                    normalizer.normalize(q, quote_type, source_currency)
-                })
-                .collect();
-
-            // Rank normalized quotes
-            let mut ranked_quotes = self
-                .ranking_strategy
-                .rank_normalized(&normalized_quotes, rfq.side());
-
-            // Apply max quotes limit
-            if let Some(max) = self.config.max_quotes {
-                ranked_quotes.truncate(max);
+            let mut ranked_quotes = Vec::with_capacity(valid_count);
+            for (side, quotes) in &by_side {
+                // Normalize quotes
+                let normalized_quotes: Vec<NormalizedQuote> = quotes
+                    .iter()
+                    .map(|q| {
+                        // Derive QuoteType from firmness and last_look_required to preserve semantics
+                        let quote_type = if q.is_indicative() {
+                            QuoteType::Indicative
+                        } else if q.last_look_required() {
+                            QuoteType::LastLook
+                        } else {
+                            QuoteType::Firm
+                        };
+
+                        // Extract source currency from metadata for FX conversion
+                        let source_currency = q
+                            .metadata()
+                            .and_then(|m| m.get("currency"))
+                            .map(|s| s.as_str());
+
+                        normalizer.normalize(q, quote_type, source_currency)
+                    })
+                    .collect();
+
+                // Rank normalized quotes, applying the max quotes limit per side
+                let mut ranked = self
+                    .ranking_strategy
+                    .rank_normalized(&normalized_quotes, *side);
+                if let Some(max) = self.config.max_quotes {
+                    ranked.truncate(max);
+                }
+                ranked_quotes.extend(ranked);
             }
 
             Ok(AggregationResult::Normalized {
@@ -346,14 +935,19 @@ impl QuoteAggregationEngine {
                 venues_queried,
                 venues_responded,
                 filtered_count,
+                rejected_short_ttl,
+                deduplicated,
+                venue_outcomes,
             })
         } else {
-            // Rank raw quotes without normalization
-            let mut ranked_quotes = self.ranking_strategy.rank(&valid_quotes, rfq.side());
-
-            // Apply max quotes limit
-            if let Some(max) = self.config.max_quotes {
-                ranked_quotes.truncate(max);
+            // Rank raw quotes without normalization, each side independently
+            let mut ranked_quotes = Vec::with_capacity(valid_count);
+            for (side, quotes) in &by_side {
+                let mut ranked = self.ranking_strategy.rank(quotes, *side);
+                if let Some(max) = self.config.max_quotes {
+                    ranked.truncate(max);
+                }
+                ranked_quotes.extend(ranked);
             }
 
             Ok(AggregationResult::Raw {
@@ -362,75 +956,753 @@ impl QuoteAggregationEngine {
                 venues_queried,
                 venues_responded,
                 filtered_count,
+                rejected_short_ttl,
+                deduplicated,
+                venue_outcomes,
             })
         }
     }
 
-    /// Collects quotes from all venues concurrently.
-    async fn collect_from_venues(&self, rfq: &Rfq) -> (Vec<Quote>, Vec<String>) {
-        let venues = self.venue_registry.get_available_venues().await;
+    /// Returns the reference price quotes for `rfq` are sanity checked
+    /// against, if a sanity check is configured and a price is available.
+    async fn sanity_reference(&self, rfq: &Rfq) -> Option<Price> {
+        match &self.quote_sanity {
+            Some(filter) => filter.reference_for(rfq.instrument()).await,
+            None => None,
+        }
+    }
+
+    /// Drops the quotes the sanity check rejects, if one is configured.
+    ///
+    /// Quotes are checked against `reference` when there is one, and
+    /// against the median of their side otherwise. Each rejection is
+    /// logged and published as a `QuoteRequestFailed` event, and venues
+    /// left without quotes are marked in `venue_outcomes`.
+    async fn screen_prices(
+        &self,
+        rfq: &Rfq,
+        quotes: Vec<Quote>,
+        reference: Option<Price>,
+        venue_outcomes: &mut [VenueOutcome],
+    ) -> Vec<Quote> {
+        let Some(filter) = &self.quote_sanity else {
+            return quotes;
+        };
+        let rejections: Vec<QuoteSanityRejection> = match reference {
+            Some(reference) => {
+                let liquidity = rfq.instrument().liquidity();
+                quotes
+                    .iter()
+                    .filter_map(|quote| filter.check_reference(quote, reference, liquidity))
+                    .collect()
+            }
+            None => rfq
+                .direction()
+                .sides()
+                .iter()
+                .flat_map(|&side| {
+                    filter.median_outliers(quotes.iter().filter(|q| rfq.side_of(q) == side))
+                })
+                .collect(),
+        };
+        if rejections.is_empty() {
+            return quotes;
+        }
+
+        let rejected: HashSet<QuoteId> = rejections.iter().map(|r| r.quote_id).collect();
+        let kept: Vec<Quote> = quotes
+            .into_iter()
+            .filter(|q| !rejected.contains(&q.id()))
+            .collect();
+        mark_price_deviations(
+            venue_outcomes,
+            &rejections,
+            kept.iter().map(Quote::venue_id),
+        );
+
+        for rejection in &rejections {
+            let reason = rejection.reason();
+            tracing::warn!(
+                rfq_id = %rfq.id(),
+                venue_id = %rejection.venue_id,
+                quote_id = %rejection.quote_id,
+                "Rejected implausible quote: {}",
+                reason
+            );
+            if let Some(publisher) = &self.event_publisher {
+                let event = QuoteRequestFailed::new(rfq.id(), rejection.venue_id.clone(), reason);
+                if let Err(e) = publisher.publish_quote_request_failed(event).await {
+                    tracing::warn!("Failed to publish QuoteRequestFailed event: {}", e);
+                }
+            }
+        }
+        kept
+    }
+
+    /// Returns the cached quotes `rfq` can reuse, reissued for it.
+    ///
+    /// Only quotes from venues that may still quote the RFQ and that meet
+    /// the venue's validity floor are reused.
+    async fn reuse_quotes(
+        &self,
+        cache: &QuoteReuseCache,
+        key: &QuoteReuseKey,
+        rfq: &Rfq,
+        venues: &[Arc<dyn VenueAdapter>],
+    ) -> Vec<Quote> {
+        let now = self.clock.now();
+        let mut reused = Vec::new();
+        for quote in cache.reuse(key, rfq, now) {
+            let eligible = venues.iter().any(|venue| {
+                venue.venue_id() == quote.venue_id()
+                    && venue.supports_quantity_disclosure(&rfq.quantity_disclosure())
+            });
+            let min_ttl_ms = self
+                .venue_registry
+                .min_quote_ttl_ms(quote.venue_id())
+                .await
+                .unwrap_or(self.config.min_quote_ttl_ms);
+            if eligible && meets_ttl_floor(&quote, min_ttl_ms, now) {
+                reused.push(quote);
+            }
+        }
+        reused
+    }
+
+    /// Applies the client's own credentials for `rfq` to the venues that
+    /// accept them.
+    async fn client_venues(&self, rfq: &Rfq, venues: Vec<Arc<dyn VenueAdapter>>) -> ClientVenues {
+        let Some(store) = &self.venue_credentials else {
+            return ClientVenues::platform(venues);
+        };
+        if !venues.iter().any(|v| v.supports_credential_override()) {
+            return ClientVenues::platform(venues);
+        }
+
+        let credential_sets = store.find_by_counterparty(rfq.client_id()).await;
+        let mut client_venues = ClientVenues::platform(Vec::with_capacity(venues.len()));
+        for venue in venues {
+            if !venue.supports_credential_override() {
+                client_venues.venues.push(venue);
+                continue;
+            }
+            let resolved = match &credential_sets {
+                Ok(sets) => sets
+                    .iter()
+                    .find(|set| set.venue_id() == venue.venue_id())
+                    .map(|set| venue.with_credential_override(set.credentials()))
+                    .transpose(),
+                Err(e) => Err(VenueError::internal_error(format!(
+                    "client venue credentials unavailable: {e}"
+                ))),
+            };
+            match resolved {
+                Ok(Some(client_venue)) => {
+                    client_venues.shared = false;
+                    client_venues.venues.push(client_venue);
+                }
+                Ok(None) => client_venues.venues.push(venue),
+                Err(e) => {
+                    tracing::warn!(
+                        rfq_id = %rfq.id(),
+                        venue_id = %venue.venue_id(),
+                        error = %e,
+                        "Client venue credentials not applied; venue not asked"
+                    );
+                    client_venues.shared = false;
+                    client_venues.failed.insert(venue.venue_id().clone(), e);
+                    client_venues.venues.push(venue);
+                }
+            }
+        }
+        client_venues
+    }
+
+    /// Collects quotes concurrently from all `venues` not in `covered`.
+    ///
+    /// Venues in `failed` are reported with their error without being asked.
+    async fn collect_from_venues(
+        &self,
+        rfq: &Rfq,
+        venues: &[Arc<dyn VenueAdapter>],
+        failed: &HashMap<VenueId, VenueError>,
+        covered: &HashSet<&VenueId>,
+    ) -> VenueCollection {
         let mut handles = Vec::with_capacity(venues.len());
+        let mut errors = Vec::new();
+        let mut venue_outcomes = Vec::with_capacity(venues.len());
 
         for venue in venues {
+            // Venues that would see more than the RFQ discloses are not asked
+            if !venue.supports_quantity_disclosure(&rfq.quantity_disclosure()) {
+                venue_outcomes.push(VenueOutcome::new(
+                    venue.venue_id().clone(),
+                    VenueOutcomeKind::Excluded {
+                        reason: VenueExclusionReason::SizeNotMaskable,
+                    },
+                ));
+                continue;
+            }
+            // Venues with a reused quote are not asked again
+            if covered.contains(venue.venue_id()) {
+                continue;
+            }
+            if let Some(e) = failed.get(venue.venue_id()) {
+                errors.push(format_venue_error(e));
+                venue_outcomes.push(VenueOutcome::new(
+                    venue.venue_id().clone(),
+                    venue_error_outcome(e),
+                ));
+                continue;
+            }
+            let breaker = self
+                .circuit_breakers
+                .as_ref()
+                .map(|breakers| breakers.get_or_create(venue.venue_id().as_str()));
+            let permit = match breaker.as_ref().map(|b| b.try_acquire()).transpose() {
+                Ok(permit) => permit,
+                Err(e) => {
+                    errors.push(format_venue_error(&VenueError::venue_unavailable(
+                        venue.venue_id().clone(),
+                        e.to_string(),
+                    )));
+                    venue_outcomes.push(VenueOutcome::new(
+                        venue.venue_id().clone(),
+                        VenueOutcomeKind::Excluded {
+                            reason: VenueExclusionReason::CircuitOpen,
+                        },
+                    ));
+                    continue;
+                }
+            };
+            let venue = Arc::clone(venue);
+            let venue_id = venue.venue_id().clone();
             let rfq_clone = rfq.clone();
             let per_venue_timeout = Duration::from_millis(self.config.per_venue_timeout_ms);
-
-            let handle = tokio::spawn(async move {
-                match timeout(per_venue_timeout, venue.request_quote(&rfq_clone)).await {
-                    Ok(Ok(quote)) => Ok(quote),
-                    Ok(Err(e)) => Err(format_venue_error(&e)),
-                    Err(_) => Err("venue request timed out".to_string()),
+            let min_ttl_ms = self
+                .venue_registry
+                .min_quote_ttl_ms(venue.venue_id())
+                .await
+                .unwrap_or(self.config.min_quote_ttl_ms);
+            let clock = Arc::clone(&self.clock);
+            let cancellation = self.cancellation.clone();
+            let retry_policy = self.retry_policy.clone();
+            let retry_budget = self.retry_budgets.for_venue(venue.venue_id());
+            let request_gate = self.request_gate.clone();
+            let event_publisher = self.event_publisher.clone();
+            let span = info_span!(
+                "venue_quote",
+                rfq_id = %rfq.id(),
+                venue_id = %venue.venue_id(),
+                quote_id = field::Empty,
+            );
+
+            let request =
+                async move {
+                let start = Instant::now();
+                let gate_permit = match &request_gate {
+                    Some(gate) => {
+                        let permit = tokio::select! {
+                            _ = cancellation.cancelled() => {
+                                return Err(VenueError::internal_error("request cancelled"));
+                            }
+                            permit = gate.acquire(venue.venue_id(), per_venue_timeout) => permit,
+                        };
+                        let Some(permit) = permit else {
+                            metrics::record_venue_response(
+                                venue.venue_id().as_str(),
+                                start.elapsed(),
+                                "concurrency_limited",
+                            );
+                            return Err(refuse_concurrency_limited(
+                                &rfq_clone,
+                                venue.venue_id(),
+                                event_publisher.as_deref(),
+                            )
+                            .await);
+                        };
+                        Some(permit)
+                    }
+                    None => None,
+                };
+                let request_timeout = per_venue_timeout.saturating_sub(start.elapsed());
+                let (result, outcome) = tokio::select! {
+                    _ = cancellation.cancelled() => {
+                        (Err(VenueError::internal_error("request cancelled")), "cancelled")
+                    }
+                    result = timeout(
+                        request_timeout,
+                        execute_with_retry_budget(&retry_policy, &retry_budget, || {
+                            request_with_ttl_floor(venue.as_ref(), &rfq_clone, min_ttl_ms, clock.as_ref())
+                        }),
+                    ) => {
+                        let result = result.map(|r| r.map_err(RetryError::into_inner));
+                        match result {
+                            Ok(Ok(quotes)) if quotes.iter().any(Option::is_some) => {
+                                if let Some(quote) = quotes.iter().flatten().next() {
+                                    Span::current().record("quote_id", field::display(quote.id()));
+                                }
+                                let now = clock.now();
+                                let quotes = quotes
+                                    .into_iter()
+                                    .map(|quote| quote.map(|q| q.with_received_at(now)))
+                                    .collect();
+                                (Ok(quotes), "ok")
+                            }
+                            Ok(Ok(quotes)) => (Ok(quotes), "short_ttl"),
+                            Ok(Err(e)) => (Err(e), "error"),
+                            Err(_) => (Err(VenueError::timeout("request timed out")), "timeout"),
+                        }
+                    }
+                };
+                metrics::record_venue_response(venue.venue_id().as_str(), start.elapsed(), outcome);
+                if let Some(breaker) = &breaker {
+                    record_circuit_outcome(breaker, &result, outcome);
                 }
+                drop(permit);
+                drop(gate_permit);
+                result
+                }
+                .instrument(span);
+            let handle = tokio::spawn(async move {
+                let start = Instant::now();
+                let result = request.await;
+                (result, start.elapsed())
             });
 
-            handles.push(handle);
+            handles.push((venue_id, handle));
         }
 
         // Collect results
         let mut quotes = Vec::new();
+        let mut unmapped_venues = Vec::new();
+        let mut rejected_short_ttl = 0;
+
+        for (venue_id, handle) in handles {
+            let outcome = match handle.await {
+                Ok((Ok(venue_quotes), latency)) => {
+                    let mut first = None;
+                    for quote in venue_quotes {
+                        match quote {
+                            Some(quote) => {
+                                first.get_or_insert(quote.id());
+                                quotes.push(quote);
+                            }
+                            None => rejected_short_ttl += 1,
+                        }
+                    }
+                    match first {
+                        Some(quote_id) => VenueOutcomeKind::Responded {
+                            latency_ms: Some(
+                                u64::try_from(latency.as_millis()).unwrap_or(u64::MAX),
+                            ),
+                            quote_id: quote_id.to_string(),
+                        },
+                        None => VenueOutcomeKind::FilteredShortTtl,
+                    }
+                }
+                Ok((Err(e), _)) => {
+                    if let VenueError::UnmappedSymbol { venue_id, .. } = &e {
+                        unmapped_venues.push(venue_id.clone());
+                    }
+                    errors.push(format_venue_error(&e));
+                    venue_error_outcome(&e)
+                }
+                Err(e) => {
+                    let reason = format!("task panicked: {}", e);
+                    errors.push(reason.clone());
+                    VenueOutcomeKind::Error { reason }
+                }
+            };
+            venue_outcomes.push(VenueOutcome::new(venue_id, outcome));
+        }
+
+        VenueCollection {
+            quotes,
+            errors,
+            unmapped_venues,
+            rejected_short_ttl,
+            venue_outcomes,
+        }
+    }
+
+    /// Collects package quotes for a multi-leg RFQ and ranks them by net price.
+    ///
+    /// Venues that support multi-leg quoting are asked for a package quote;
+    /// the rest are asked for each leg separately.
+    async fn collect_and_rank_packages(
+        &self,
+        strategy: &Strategy,
+        rfq: &Rfq,
+    ) -> AggregationResultType<AggregationResult> {
+        let ClientVenues {
+            venues, mut failed, ..
+        } = self
+            .client_venues(rfq, self.venue_registry.get_available_venues().await)
+            .await;
+        let venues_queried = venues.len();
+
+        if venues.is_empty() {
+            return Err(AggregationError::NoVenuesAvailable);
+        }
+
         let mut errors = Vec::new();
+        let mut venue_outcomes = Vec::with_capacity(venues_queried);
+        let venues: Vec<_> = venues
+            .into_iter()
+            .filter(|venue| match failed.remove(venue.venue_id()) {
+                Some(e) => {
+                    errors.push(format_venue_error(&e));
+                    venue_outcomes.push(VenueOutcome::new(
+                        venue.venue_id().clone(),
+                        venue_error_outcome(&e),
+                    ));
+                    false
+                }
+                None => true,
+            })
+            .collect();
+        let collector = MultiLegQuoteCollector::new(
+            venues,
+            Duration::from_millis(self.config.per_venue_timeout_ms),
+        );
+        let overall_timeout = Duration::from_millis(self.config.timeout_ms);
+        let results = tokio::select! {
+            _ = self.cancellation.cancelled() => return Err(AggregationError::Cancelled),
+            results = timeout(overall_timeout, collector.collect_with_details(strategy, rfq)) => {
+                results.map_err(|_| AggregationError::Timeout)?
+            }
+        };
 
-        for handle in handles {
-            match handle.await {
-                Ok(Ok(quote)) => quotes.push(quote),
-                Ok(Err(e)) => errors.push(e),
-                Err(e) => errors.push(format!("task panicked: {}", e)),
+        let mut quotes = Vec::new();
+        for result in results {
+            match result {
+                VenueQuoteResult::Success(quote) | VenueQuoteResult::Fallback(quote) => {
+                    venue_outcomes.push(VenueOutcome::new(
+                        quote.venue_id().clone(),
+                        VenueOutcomeKind::Responded {
+                            latency_ms: None,
+                            quote_id: quote.id().to_string(),
+                        },
+                    ));
+                    quotes.push(quote);
+                }
+                VenueQuoteResult::Failed { venue_id, reason } => {
+                    errors.push(format!("{}: {}", venue_id, reason));
+                    venue_outcomes.push(VenueOutcome::new(
+                        venue_id,
+                        VenueOutcomeKind::Error { reason },
+                    ));
+                }
+                VenueQuoteResult::Timeout { venue_id } => {
+                    errors.push(format!("{}: request timed out", venue_id));
+                    venue_outcomes.push(VenueOutcome::new(venue_id, VenueOutcomeKind::Timeout));
+                }
             }
         }
 
-        (quotes, errors)
-    }
+        let total_collected = quotes.len();
+        let venues_responded = venues_queried - errors.len();
 
-    /// Returns the current configuration.
-    #[must_use]
-    pub fn config(&self) -> &AggregationConfig {
-        &self.config
-    }
+        let now = self.clock.now();
+        let mut ranked_quotes: Vec<PackageQuote> = quotes
+            .into_iter()
+            .filter(|q| !q.is_expired_at(now))
+            .collect();
+        let filtered_count = total_collected - ranked_quotes.len();
+        mark_expired(
+            &mut venue_outcomes,
+            ranked_quotes.iter().map(PackageQuote::venue_id),
+        );
 
-    /// Returns the ranking strategy name.
-    #[must_use]
-    pub fn ranking_strategy_name(&self) -> &'static str {
-        self.ranking_strategy.name()
-    }
-}
+        if ranked_quotes.is_empty() && !errors.is_empty() {
+            return Err(AggregationError::AllVenuesFailed(errors));
+        }
+
+        if ranked_quotes.len() < self.config.min_quotes {
+            return Err(AggregationError::InsufficientQuotes {
+                collected: ranked_quotes.len(),
+                required: self.config.min_quotes,
+            });
+        }
+
+        // Positive net price is a debit: buyers want it low, sellers high
+        match rfq.side() {
+            OrderSide::Buy => ranked_quotes.sort_by_key(PackageQuote::net_price),
+            OrderSide::Sell => {
+                ranked_quotes.sort_by_key(|q| std::cmp::Reverse(q.net_price()));
+            }
+        }
+
+        if let Some(max) = self.config.max_quotes {
+            ranked_quotes.truncate(max);
+        }
+
+        Ok(AggregationResult::Package {
+            ranked_quotes,
+            total_collected,
+            venues_queried,
+            venues_responded,
+            filtered_count,
+            venue_outcomes,
+        })
+    }
+
+    /// Returns the current configuration.
+    #[must_use]
+    pub fn config(&self) -> &AggregationConfig {
+        &self.config
+    }
+
+    /// Returns the ranking strategy name.
+    #[must_use]
+    pub fn ranking_strategy_name(&self) -> &'static str {
+        self.ranking_strategy.name()
+    }
+}
+
+/// Quotes and failures collected from the venues asked for an RFQ.
+struct VenueCollection {
+    quotes: Vec<Quote>,
+    /// Formatted venue errors.
+    errors: Vec<String>,
+    /// Venues that failed because the symbol is unmapped.
+    unmapped_venues: Vec<VenueId>,
+    /// Quotes rejected for expiring within the validity floor.
+    rejected_short_ttl: usize,
+    venue_outcomes: Vec<VenueOutcome>,
+}
+
+/// The venues to ask for an RFQ, with the client's own credentials applied.
+struct ClientVenues {
+    venues: Vec<Arc<dyn VenueAdapter>>,
+    /// Venues whose client credentials could not be looked up or applied;
+    /// they are reported as failed rather than asked.
+    failed: HashMap<VenueId, VenueError>,
+    /// Whether every venue is asked with the platform's credentials, so its
+    /// quotes can be shared with other clients' RFQs.
+    shared: bool,
+}
+
+impl ClientVenues {
+    fn platform(venues: Vec<Arc<dyn VenueAdapter>>) -> Self {
+        Self {
+            venues,
+            failed: HashMap::new(),
+            shared: true,
+        }
+    }
+}
 
 /// Formats a venue error for display.
 fn format_venue_error(error: &VenueError) -> String {
     error.to_string()
 }
 
+/// Returns the outcome of a venue that answered with `error`.
+fn venue_error_outcome(error: &VenueError) -> VenueOutcomeKind {
+    match error {
+        VenueError::Timeout { .. } => VenueOutcomeKind::Timeout,
+        _ => VenueOutcomeKind::Error {
+            reason: format_venue_error(error),
+        },
+    }
+}
+
+/// Marks venues that responded but have none of `valid` quotes left as
+/// [`VenueOutcomeKind::FilteredExpired`].
+fn mark_expired<'a>(outcomes: &mut [VenueOutcome], valid: impl Iterator<Item = &'a VenueId>) {
+    let valid: HashSet<&VenueId> = valid.collect();
+    for outcome in outcomes {
+        if matches!(outcome.outcome, VenueOutcomeKind::Responded { .. })
+            && !valid.contains(&outcome.venue_id)
+        {
+            outcome.outcome = VenueOutcomeKind::FilteredExpired;
+        }
+    }
+}
+
+/// Marks venues that responded but had all their quotes in `rejections` as
+/// [`VenueOutcomeKind::FilteredPriceDeviation`], with their largest
+/// deviation.
+fn mark_price_deviations<'a>(
+    outcomes: &mut [VenueOutcome],
+    rejections: &[QuoteSanityRejection],
+    kept: impl Iterator<Item = &'a VenueId>,
+) {
+    let kept: HashSet<&VenueId> = kept.collect();
+    for outcome in outcomes {
+        if !matches!(outcome.outcome, VenueOutcomeKind::Responded { .. })
+            || kept.contains(&outcome.venue_id)
+        {
+            continue;
+        }
+        let worst = rejections
+            .iter()
+            .filter(|r| r.venue_id == outcome.venue_id)
+            .max_by_key(|r| r.deviation_pct);
+        if let Some(worst) = worst {
+            outcome.outcome = VenueOutcomeKind::FilteredPriceDeviation {
+                basis: worst.basis,
+                deviation_pct: worst.deviation_pct,
+            };
+        }
+    }
+}
+
+/// Records a venue request's outcome on its circuit breaker.
+///
+/// Cancelled requests and errors that say nothing about the venue's
+/// health, such as an empty book, are not recorded.
+fn record_circuit_outcome(
+    breaker: &CircuitBreaker,
+    result: &VenueResult<Vec<Option<Quote>>>,
+    outcome: &str,
+) {
+    match result {
+        _ if outcome == "cancelled" => {}
+        Ok(_) => breaker.record_success(),
+        Err(e) if e.is_retryable() || e.is_venue_error() => breaker.record_failure(),
+        Err(_) => {}
+    }
+}
+
+/// Publishes `QuoteRequestFailed` for a request the venue's gate refused
+/// and returns the error it fails with.
+async fn refuse_concurrency_limited(
+    rfq: &Rfq,
+    venue_id: &VenueId,
+    publisher: Option<&dyn QuoteEventPublisher>,
+) -> VenueError {
+    if let Some(publisher) = publisher {
+        let event = QuoteRequestFailed::new(rfq.id(), venue_id.clone(), CONCURRENCY_LIMIT_REASON);
+        if let Err(e) = publisher.publish_quote_request_failed(event).await {
+            tracing::warn!("Failed to publish QuoteRequestFailed event: {}", e);
+        }
+    }
+    VenueError::venue_unavailable(venue_id.clone(), CONCURRENCY_LIMIT_REASON)
+}
+
+/// Requests a quote from `venue`, enforcing the validity floor.
+///
+/// A quote expiring within `min_ttl_ms` is re-requested with a TTL hint
+/// when the venue accepts one. Returns `None` if the quote still falls
+/// short. A two-way RFQ yields one entry per side and is not re-requested.
+async fn request_with_ttl_floor(
+    venue: &dyn VenueAdapter,
+    rfq: &Rfq,
+    min_ttl_ms: u64,
+    clock: &dyn Clock,
+) -> VenueResult<Vec<Option<Quote>>> {
+    if rfq.is_two_way() {
+        let quotes = venue.request_two_way_quote(rfq).await?;
+        let now = clock.now();
+        return Ok(quotes
+            .into_iter()
+            .map(|quote| meets_ttl_floor(&quote, min_ttl_ms, now).then_some(quote))
+            .collect());
+    }
+
+    let quote = venue.request_quote(rfq).await?;
+    if meets_ttl_floor(&quote, min_ttl_ms, clock.now()) {
+        return Ok(vec![Some(quote)]);
+    }
+    if !venue.supports_ttl_hint() {
+        return Ok(vec![None]);
+    }
+
+    let quote = venue.request_quote_with_ttl(rfq, min_ttl_ms).await?;
+    Ok(vec![
+        meets_ttl_floor(&quote, min_ttl_ms, clock.now()).then_some(quote),
+    ])
+}
+
+/// Returns true if `quote` stays valid for at least `min_ttl_ms` after `now`.
+fn meets_ttl_floor(quote: &Quote, min_ttl_ms: u64, now: Timestamp) -> bool {
+    if min_ttl_ms == 0 {
+        return true;
+    }
+    let remaining_ms = quote
+        .valid_until()
+        .timestamp_millis()
+        .saturating_sub(now.timestamp_millis());
+    i64::try_from(min_ttl_ms).is_ok_and(|min| remaining_ms >= min)
+}
+
+/// Keeps only the better-priced quote per maker identity.
+///
+/// Buyers keep the lower price and sellers the higher; on a tie the quote
+/// collected first wins. Quotes without a maker identity pass through.
+fn deduplicate_by_maker(
+    quotes: Vec<Quote>,
+    side: OrderSide,
+) -> (Vec<Quote>, Vec<DeduplicatedQuote>) {
+    let mut best: HashMap<&str, (QuoteId, Price)> = HashMap::new();
+    for quote in &quotes {
+        let Some(maker) = maker_identity(quote) else {
+            continue;
+        };
+        match best.entry(maker) {
+            Entry::Vacant(entry) => {
+                entry.insert((quote.id(), quote.price()));
+            }
+            Entry::Occupied(mut entry) => {
+                let better = match side {
+                    OrderSide::Buy => quote.price() < entry.get().1,
+                    OrderSide::Sell => quote.price() > entry.get().1,
+                };
+                if better {
+                    entry.insert((quote.id(), quote.price()));
+                }
+            }
+        }
+    }
+
+    let mut deduplicated = Vec::new();
+    for quote in &quotes {
+        let Some(maker) = maker_identity(quote) else {
+            continue;
+        };
+        if let Some(&(kept, _)) = best.get(maker)
+            && kept != quote.id()
+        {
+            deduplicated.push(DeduplicatedQuote {
+                maker_identity: maker.to_string(),
+                kept,
+                suppressed: quote.id(),
+            });
+        }
+    }
+
+    let kept = quotes
+        .into_iter()
+        .filter(|q| !deduplicated.iter().any(|d| d.suppressed == q.id()))
+        .collect();
+    (kept, deduplicated)
+}
+
+/// Returns the non-empty maker identity attached to a quote.
+fn maker_identity(quote: &Quote) -> Option<&str> {
+    quote
+        .metadata()
+        .and_then(|m| m.maker_identity())
+        .filter(|maker| !maker.is_empty())
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
     use crate::application::services::ranking_strategy::BestPriceStrategy;
     use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::entities::venue_credential_set::{VenueCredentialSet, VenueCredentials};
     use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
     use crate::domain::value_objects::symbol::Symbol;
-    use crate::domain::value_objects::timestamp::Timestamp;
+    use crate::domain::value_objects::timestamp::{MockClock, Timestamp};
     use crate::domain::value_objects::{
-        CounterpartyId, Instrument, OrderSide, Price, Quantity, VenueId,
+        CounterpartyId, Instrument, OrderSide, Price, Quantity, RfqDirection, VenueId,
     };
+    use crate::infrastructure::persistence::in_memory::InMemoryVenueCredentialRepository;
     use crate::infrastructure::venues::error::VenueResult;
     use crate::infrastructure::venues::traits::{ExecutionResult, VenueAdapter, VenueHealth};
     use async_trait::async_trait;
@@ -465,6 +1737,30 @@ mod tests {
             }
         }
 
+        fn from_maker(
+            venue_id: &str,
+            rfq_id: crate::domain::value_objects::RfqId,
+            price: f64,
+            maker: &str,
+        ) -> Self {
+            let mut metadata = crate::domain::entities::quote::QuoteMetadata::new();
+            metadata.set_maker_identity(maker);
+            let quote = crate::domain::entities::quote::QuoteBuilder::new(
+                rfq_id,
+                VenueId::new(venue_id),
+                Price::new(price).unwrap(),
+                Quantity::new(1.0).unwrap(),
+                Timestamp::now().add_secs(60),
+            )
+            .metadata(metadata)
+            .build();
+            Self {
+                venue_id: VenueId::new(venue_id),
+                quote_result: Mutex::new(Some(Ok(quote))),
+                delay_ms: 0,
+            }
+        }
+
         fn failing(venue_id: &str) -> Self {
             Self {
                 venue_id: VenueId::new(venue_id),
@@ -475,6 +1771,17 @@ mod tests {
             }
         }
 
+        fn unmapped(venue_id: &str) -> Self {
+            Self {
+                venue_id: VenueId::new(venue_id),
+                quote_result: Mutex::new(Some(Err(VenueError::unmapped_symbol(
+                    VenueId::new(venue_id),
+                    "BTC/USD",
+                )))),
+                delay_ms: 0,
+            }
+        }
+
         fn slow(venue_id: &str, delay_ms: u64) -> Self {
             Self {
                 venue_id: VenueId::new(venue_id),
@@ -520,15 +1827,25 @@ mod tests {
     #[derive(Debug)]
     struct MockVenueRegistry {
         venues: Vec<Arc<dyn VenueAdapter>>,
+        min_quote_ttls: HashMap<VenueId, u64>,
     }
 
     impl MockVenueRegistry {
         fn with_venues(venues: Vec<Arc<dyn VenueAdapter>>) -> Self {
-            Self { venues }
+            Self {
+                venues,
+                min_quote_ttls: HashMap::new(),
+            }
         }
 
         fn empty() -> Self {
-            Self { venues: vec![] }
+            Self::with_venues(vec![])
+        }
+
+        fn with_min_quote_ttl(mut self, venue_id: &str, min_ttl_ms: u64) -> Self {
+            self.min_quote_ttls
+                .insert(VenueId::new(venue_id), min_ttl_ms);
+            self
         }
     }
 
@@ -549,6 +1866,14 @@ mod tests {
                 .find(|v| v.venue_id() == venue_id)
                 .cloned()
         }
+
+        async fn min_quote_ttl_ms(&self, venue_id: &VenueId) -> Option<u64> {
+            self.min_quote_ttls.get(venue_id).copied()
+        }
+    }
+
+    fn test_clock() -> Arc<MockClock> {
+        Arc::new(MockClock::new(Timestamp::now()))
     }
 
     fn create_test_rfq() -> Rfq {
@@ -568,6 +1893,190 @@ mod tests {
         .build()
     }
 
+    /// Venue that quotes any RFQ at a fixed price, optionally as a package.
+    #[derive(Debug)]
+    struct PricingVenueAdapter {
+        venue_id: VenueId,
+        price: f64,
+        package_net_price: Option<rust_decimal::Decimal>,
+        single_leg_requests: std::sync::atomic::AtomicUsize,
+    }
+
+    impl PricingVenueAdapter {
+        fn per_leg(venue_id: &str, price: f64) -> Self {
+            Self {
+                venue_id: VenueId::new(venue_id),
+                price,
+                package_net_price: None,
+                single_leg_requests: std::sync::atomic::AtomicUsize::new(0),
+            }
+        }
+
+        fn packaged(venue_id: &str, net_price: i64) -> Self {
+            Self {
+                package_net_price: Some(rust_decimal::Decimal::new(net_price, 0)),
+                ..Self::per_leg(venue_id, 1.0)
+            }
+        }
+    }
+
+    #[async_trait]
+    impl VenueAdapter for PricingVenueAdapter {
+        fn venue_id(&self) -> &VenueId {
+            &self.venue_id
+        }
+
+        fn timeout_ms(&self) -> u64 {
+            1000
+        }
+
+        async fn request_quote(&self, rfq: &Rfq) -> VenueResult<Quote> {
+            self.single_leg_requests
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(Quote::new(
+                rfq.id(),
+                self.venue_id.clone(),
+                Price::new(self.price).unwrap(),
+                rfq.quantity(),
+                Timestamp::now().add_secs(60),
+            )
+            .unwrap())
+        }
+
+        async fn execute_trade(&self, _quote: &Quote) -> VenueResult<ExecutionResult> {
+            unimplemented!()
+        }
+
+        async fn health_check(&self) -> VenueResult<VenueHealth> {
+            Ok(VenueHealth::healthy(self.venue_id.clone()))
+        }
+
+        fn supports_multi_leg(&self) -> bool {
+            self.package_net_price.is_some()
+        }
+
+        async fn request_multi_leg_quote(
+            &self,
+            strategy: &Strategy,
+            rfq: &Rfq,
+        ) -> VenueResult<PackageQuote> {
+            use crate::domain::entities::package_quote::LegPrice;
+
+            let net_price = self
+                .package_net_price
+                .ok_or_else(|| VenueError::unsupported_operation("multi-leg quoting"))?;
+            let leg_prices = strategy
+                .legs()
+                .iter()
+                .map(|leg| {
+                    LegPrice::from_parts(
+                        leg.instrument().clone(),
+                        leg.side(),
+                        Price::new(self.price).unwrap(),
+                        rfq.quantity(),
+                    )
+                })
+                .collect();
+            PackageQuote::new(
+                rfq.id(),
+                self.venue_id.clone(),
+                strategy.clone(),
+                net_price,
+                leg_prices,
+                Timestamp::now().add_secs(60),
+            )
+            .map_err(|e| VenueError::internal_error(e.to_string()))
+        }
+    }
+
+    fn create_straddle_rfq(side: OrderSide) -> Rfq {
+        let option = Instrument::new(
+            Symbol::new("BTC/USD").unwrap(),
+            AssetClass::CryptoDerivs,
+            SettlementMethod::default(),
+        );
+        let straddle = Strategy::straddle(option.clone(), option, "BTC").unwrap();
+
+        RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            straddle,
+            side,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build()
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_strategy_uses_venue_capability() {
+        let packaged = Arc::new(PricingVenueAdapter::packaged("packaged", 150));
+        let per_leg = Arc::new(PricingVenueAdapter::per_leg("per-leg", 70.0));
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![packaged.clone(), per_leg.clone()];
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000),
+        )
+        .with_clock(test_clock());
+
+        let result = engine
+            .collect_and_rank(&create_straddle_rfq(OrderSide::Buy))
+            .await
+            .unwrap();
+
+        let AggregationResult::Package { ranked_quotes, .. } = result else {
+            unreachable!("expected package quotes for a strategy RFQ")
+        };
+        let [best, second] = ranked_quotes.as_slice() else {
+            unreachable!("expected two package quotes")
+        };
+        // Per-leg: buy two legs at 70 each = debit of 140, cheaper than 150
+        assert_eq!(best.venue_id().as_str(), "per-leg");
+        assert_eq!(best.net_price(), rust_decimal::Decimal::new(140, 0));
+        assert_eq!(second.venue_id().as_str(), "packaged");
+        assert_eq!(
+            packaged
+                .single_leg_requests
+                .load(std::sync::atomic::Ordering::SeqCst),
+            0
+        );
+        assert_eq!(
+            per_leg
+                .single_leg_requests
+                .load(std::sync::atomic::Ordering::SeqCst),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_strategy_sell_prefers_highest_net() {
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(PricingVenueAdapter::packaged("low", 120)),
+            Arc::new(PricingVenueAdapter::packaged("high", 160)),
+        ];
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000),
+        )
+        .with_clock(test_clock());
+
+        let result = engine
+            .collect_and_rank(&create_straddle_rfq(OrderSide::Sell))
+            .await
+            .unwrap();
+
+        let AggregationResult::Package { ranked_quotes, .. } = result else {
+            unreachable!("expected package quotes for a strategy RFQ")
+        };
+        let [best, _] = ranked_quotes.as_slice() else {
+            unreachable!("expected two package quotes")
+        };
+        assert_eq!(best.venue_id().as_str(), "high");
+    }
+
     #[tokio::test]
     async fn collect_and_rank_success() {
         let rfq = create_test_rfq();
@@ -583,7 +2092,8 @@ mod tests {
             Arc::new(MockVenueRegistry::with_venues(venues)),
             Arc::new(BestPriceStrategy::new()),
             AggregationConfig::with_timeout(5000),
-        );
+        )
+        .with_clock(test_clock());
 
         let result = engine.collect_and_rank(&rfq).await;
         assert!(result.is_ok());
@@ -603,60 +2113,1019 @@ mod tests {
         }
     }
 
-    #[tokio::test]
-    async fn collect_and_rank_no_venues() {
-        let rfq = create_test_rfq();
+    /// Venue that offers at `ask` and bids at `bid`.
+    #[derive(Debug)]
+    struct SidedVenueAdapter {
+        venue_id: VenueId,
+        bid: f64,
+        ask: f64,
+    }
 
-        let engine = QuoteAggregationEngine::new(
-            Arc::new(MockVenueRegistry::empty()),
-            Arc::new(BestPriceStrategy::new()),
-            AggregationConfig::default(),
-        );
+    #[async_trait]
+    impl VenueAdapter for SidedVenueAdapter {
+        fn venue_id(&self) -> &VenueId {
+            &self.venue_id
+        }
 
-        let result = engine.collect_and_rank(&rfq).await;
-        assert!(matches!(result, Err(AggregationError::NoVenuesAvailable)));
+        fn timeout_ms(&self) -> u64 {
+            1000
+        }
+
+        async fn request_quote(&self, rfq: &Rfq) -> VenueResult<Quote> {
+            let price = match rfq.side() {
+                OrderSide::Buy => self.ask,
+                OrderSide::Sell => self.bid,
+            };
+            Ok(Quote::new(
+                rfq.id(),
+                self.venue_id.clone(),
+                Price::new(price).unwrap(),
+                rfq.quantity(),
+                Timestamp::now().add_secs(60),
+            )
+            .unwrap())
+        }
+
+        async fn execute_trade(&self, _quote: &Quote) -> VenueResult<ExecutionResult> {
+            unimplemented!()
+        }
+
+        async fn health_check(&self) -> VenueResult<VenueHealth> {
+            Ok(VenueHealth::healthy(self.venue_id.clone()))
+        }
     }
 
     #[tokio::test]
-    async fn collect_and_rank_all_fail() {
-        let rfq = create_test_rfq();
+    async fn collect_and_rank_two_way_ranks_each_side_independently() {
+        let rfq = RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            Instrument::new(
+                Symbol::new("BTC/USD").unwrap(),
+                AssetClass::CryptoSpot,
+                SettlementMethod::default(),
+            ),
+            RfqDirection::TwoWay,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build();
 
         let venues: Vec<Arc<dyn VenueAdapter>> = vec![
-            Arc::new(MockVenueAdapter::failing("venue-1")),
-            Arc::new(MockVenueAdapter::failing("venue-2")),
+            Arc::new(SidedVenueAdapter {
+                venue_id: VenueId::new("tight-ask"),
+                bid: 99.0,
+                ask: 101.0,
+            }),
+            Arc::new(SidedVenueAdapter {
+                venue_id: VenueId::new("tight-bid"),
+                bid: 100.0,
+                ask: 102.0,
+            }),
         ];
 
         let engine = QuoteAggregationEngine::new(
             Arc::new(MockVenueRegistry::with_venues(venues)),
             Arc::new(BestPriceStrategy::new()),
-            AggregationConfig::with_timeout(5000).with_min_quotes(1),
-        );
+            AggregationConfig::with_timeout(5000).with_max_quotes(1),
+        )
+        .with_clock(test_clock());
 
-        let result = engine.collect_and_rank(&rfq).await;
-        assert!(matches!(result, Err(AggregationError::AllVenuesFailed(_))));
+        let result = engine.collect_and_rank(&rfq).await.unwrap();
+        let AggregationResult::Raw { ranked_quotes, .. } = result else {
+            unreachable!("Expected Raw variant since no normalizer was configured");
+        };
+        let best: Vec<_> = ranked_quotes
+            .iter()
+            .map(|r| (r.quote.side(), r.quote.venue_id().as_str(), r.rank))
+            .collect();
+        assert_eq!(
+            best,
+            vec![
+                (Some(OrderSide::Buy), "tight-ask", 1),
+                (Some(OrderSide::Sell), "tight-bid", 1),
+            ]
+        );
     }
 
     #[tokio::test]
-    async fn collect_and_rank_partial_failure() {
-        let rfq = create_test_rfq();
-        let rfq_id = rfq.id();
-
-        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
-            Arc::new(MockVenueAdapter::successful("venue-1", rfq_id, 100.0)),
-            Arc::new(MockVenueAdapter::failing("venue-2")),
-        ];
+    async fn venue_spans_carry_rfq_id_and_inbound_trace_context() {
+        use crate::infrastructure::telemetry;
+        use opentelemetry::trace::{SpanId, TraceId};
+        use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider, SpanData};
+        use reqwest::header::{HeaderMap, HeaderValue};
+        use tracing::Instrument;
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let exporter = InMemorySpanExporter::default();
+        let provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let subscriber = tracing_subscriber::registry().with(telemetry::layer(&provider));
+        let _default = tracing::subscriber::set_default(subscriber);
+
+        // Synthetic inbound request continuing a caller's trace
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "traceparent",
+            HeaderValue::from_static("00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01"),
+        );
+        let inbound = tracing::info_span!("request");
+        telemetry::set_remote_parent(&inbound, &headers);
 
+        let rfq = create_test_rfq();
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![Arc::new(MockVenueAdapter::successful(
+            "venue-1",
+            rfq.id(),
+            100.0,
+        ))];
         let engine = QuoteAggregationEngine::new(
             Arc::new(MockVenueRegistry::with_venues(venues)),
             Arc::new(BestPriceStrategy::new()),
-            AggregationConfig::with_timeout(5000).with_min_quotes(1),
+            AggregationConfig::with_timeout(5000),
+        )
+        .with_clock(test_clock());
+
+        engine
+            .collect_and_rank(&rfq)
+            .instrument(inbound)
+            .await
+            .unwrap();
+
+        let spans = exporter.get_finished_spans().unwrap();
+        let find = |name: &str| spans.iter().find(|span| span.name == name).unwrap();
+        let attribute = |span: &SpanData, key: &str| {
+            span.attributes
+                .iter()
+                .find(|kv| kv.key.as_str() == key)
+                .map(|kv| kv.value.to_string())
+        };
+        let request = find("request");
+        let aggregation = find("collect_and_rank");
+        let venue = find("venue_quote");
+
+        let trace_id = TraceId::from_hex("0af7651916cd43dd8448eb211c80319c").unwrap();
+        assert_eq!(venue.span_context.trace_id(), trace_id);
+        assert_eq!(
+            request.parent_span_id,
+            SpanId::from_hex("b7ad6b7169203331").unwrap()
+        );
+        assert_eq!(aggregation.parent_span_id, request.span_context.span_id());
+        assert_eq!(venue.parent_span_id, aggregation.span_context.span_id());
+
+        let rfq_id = rfq.id().to_string();
+        assert_eq!(attribute(aggregation, "rfq_id"), Some(rfq_id.clone()));
+        assert_eq!(attribute(venue, "rfq_id"), Some(rfq_id));
+        assert_eq!(attribute(venue, "venue_id"), Some("venue-1".to_string()));
+        assert!(attribute(venue, "quote_id").is_some());
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_dedups_same_maker_across_venues() {
+        let rfq = create_test_rfq();
+        let rfq_id = rfq.id();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(MockVenueAdapter::from_maker(
+                "venue-1", rfq_id, 100.0, "mm-a",
+            )),
+            Arc::new(MockVenueAdapter::from_maker(
+                "venue-2", rfq_id, 99.0, "mm-a",
+            )),
+            Arc::new(MockVenueAdapter::successful("venue-3", rfq_id, 101.0)),
+        ];
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000),
+        )
+        .with_clock(test_clock());
+
+        let result = engine.collect_and_rank(&rfq).await.unwrap();
+        assert_eq!(result.total_collected(), 3);
+        assert_eq!(result.quote_count(), 2);
+
+        let AggregationResult::Raw {
+            ranked_quotes,
+            deduplicated,
+            ..
+        } = result
+        else {
+            unreachable!("Expected Raw variant since no normalizer was configured");
+        };
+        let venue_of = |id: QuoteId| {
+            ranked_quotes
+                .iter()
+                .find(|r| r.quote.id() == id)
+                .map(|r| r.quote.venue_id().to_string())
+        };
+
+        assert_eq!(deduplicated.len(), 1);
+        let dedup = deduplicated.first().unwrap();
+        assert_eq!(dedup.maker_identity, "mm-a");
+        assert_eq!(venue_of(dedup.kept).as_deref(), Some("venue-2"));
+        assert_eq!(venue_of(dedup.suppressed), None);
+        assert!(
+            ranked_quotes
+                .iter()
+                .all(|r| r.quote.venue_id().as_str() != "venue-1")
+        );
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_keeps_distinct_makers() {
+        let rfq = create_test_rfq();
+        let rfq_id = rfq.id();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(MockVenueAdapter::from_maker(
+                "venue-1", rfq_id, 100.0, "mm-a",
+            )),
+            Arc::new(MockVenueAdapter::from_maker(
+                "venue-2", rfq_id, 99.0, "mm-b",
+            )),
+            Arc::new(MockVenueAdapter::successful("venue-3", rfq_id, 101.0)),
+            Arc::new(MockVenueAdapter::successful("venue-4", rfq_id, 101.0)),
+        ];
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000),
+        )
+        .with_clock(test_clock());
+
+        let result = engine.collect_and_rank(&rfq).await.unwrap();
+        assert_eq!(result.quote_count(), 4);
+        assert!(result.deduplicated().is_empty());
+    }
+
+    #[test]
+    fn deduplicate_by_maker_sell_keeps_highest() {
+        let rfq_id = crate::domain::value_objects::RfqId::new_v4();
+        let quotes: Vec<Quote> = [("venue-1", 100.0), ("venue-2", 101.0)]
+            .into_iter()
+            .map(|(venue, price)| {
+                MockVenueAdapter::from_maker(venue, rfq_id, price, "mm-a")
+                    .quote_result
+                    .into_inner()
+                    .unwrap()
+                    .unwrap()
+                    .unwrap()
+            })
+            .collect();
+        let (high, low) = (quotes.get(1).unwrap().id(), quotes.first().unwrap().id());
+
+        let (kept, deduplicated) = deduplicate_by_maker(quotes, OrderSide::Sell);
+
+        assert_eq!(kept.len(), 1);
+        assert_eq!(
+            deduplicated,
+            vec![DeduplicatedQuote {
+                maker_identity: "mm-a".to_string(),
+                kept: high,
+                suppressed: low,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_no_venues() {
+        let rfq = create_test_rfq();
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::empty()),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::default(),
+        )
+        .with_clock(test_clock());
+
+        let result = engine.collect_and_rank(&rfq).await;
+        assert!(matches!(result, Err(AggregationError::NoVenuesAvailable)));
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_ignores_scheduled_rfq_until_activation() {
+        let clock = test_clock();
+        let activate_at = clock.now().add_secs(60);
+        let rfq = RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            Instrument::new(
+                Symbol::new("BTC/USD").unwrap(),
+                AssetClass::CryptoSpot,
+                SettlementMethod::default(),
+            ),
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            activate_at.add_secs(300),
+        )
+        .activate_at(activate_at)
+        .build();
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![Arc::new(MockVenueAdapter::successful(
+            "venue-1",
+            rfq.id(),
+            100.0,
+        ))];
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000),
+        )
+        .with_clock(clock.clone());
+
+        let result = engine.collect_and_rank(&rfq).await;
+        assert!(matches!(
+            result,
+            Err(AggregationError::NotYetActive { activate_at: at }) if at == activate_at
+        ));
+
+        clock.set(activate_at);
+        assert_eq!(
+            engine.collect_and_rank(&rfq).await.unwrap().quote_count(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_all_fail() {
+        let rfq = create_test_rfq();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(MockVenueAdapter::failing("venue-1")),
+            Arc::new(MockVenueAdapter::failing("venue-2")),
+        ];
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000).with_min_quotes(1),
+        )
+        .with_clock(test_clock());
+
+        let result = engine.collect_and_rank(&rfq).await;
+        assert!(matches!(result, Err(AggregationError::AllVenuesFailed(_))));
+    }
+
+    /// Fails with a transient connection error before delegating.
+    #[derive(Debug)]
+    struct FlakyVenueAdapter {
+        inner: MockVenueAdapter,
+        failures_left: Mutex<u32>,
+        calls: Mutex<u32>,
+    }
+
+    impl FlakyVenueAdapter {
+        fn new(inner: MockVenueAdapter, failures: u32) -> Self {
+            Self {
+                inner,
+                failures_left: Mutex::new(failures),
+                calls: Mutex::new(0),
+            }
+        }
+
+        fn calls(&self) -> u32 {
+            *self.calls.lock().unwrap()
+        }
+    }
+
+    #[async_trait]
+    impl VenueAdapter for FlakyVenueAdapter {
+        fn venue_id(&self) -> &VenueId {
+            self.inner.venue_id()
+        }
+
+        fn timeout_ms(&self) -> u64 {
+            self.inner.timeout_ms()
+        }
+
+        async fn request_quote(&self, rfq: &Rfq) -> VenueResult<Quote> {
+            *self.calls.lock().unwrap() += 1;
+            {
+                let mut failures_left = self.failures_left.lock().unwrap();
+                if *failures_left > 0 {
+                    *failures_left -= 1;
+                    return Err(VenueError::connection("connection reset"));
+                }
+            }
+            self.inner.request_quote(rfq).await
+        }
+
+        async fn execute_trade(&self, quote: &Quote) -> VenueResult<ExecutionResult> {
+            self.inner.execute_trade(quote).await
+        }
+
+        async fn health_check(&self) -> VenueResult<VenueHealth> {
+            self.inner.health_check().await
+        }
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_retries_transient_venue_errors() {
+        let rfq = create_test_rfq();
+        let flaky = Arc::new(FlakyVenueAdapter::new(
+            MockVenueAdapter::successful("venue-1", rfq.id(), 100.0),
+            1,
+        ));
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(vec![
+                Arc::clone(&flaky) as Arc<dyn VenueAdapter>
+            ])),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000).with_min_quotes(1),
+        )
+        .with_clock(test_clock())
+        .with_retry(
+            RetryPolicy::new(2, 1, 10, 2.0, 0.0),
+            Arc::new(RetryBudgets::default()),
         );
 
-        let result = engine.collect_and_rank(&rfq).await;
-        assert!(result.is_ok());
+        let result = engine.collect_and_rank(&rfq).await.unwrap();
+        assert_eq!(result.quote_count(), 1);
+        assert_eq!(flaky.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_does_not_retry_by_default() {
+        let rfq = create_test_rfq();
+        let flaky = Arc::new(FlakyVenueAdapter::new(
+            MockVenueAdapter::successful("venue-1", rfq.id(), 100.0),
+            1,
+        ));
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(vec![
+                Arc::clone(&flaky) as Arc<dyn VenueAdapter>
+            ])),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000).with_min_quotes(1),
+        )
+        .with_clock(test_clock());
+
+        let result = engine.collect_and_rank(&rfq).await;
+        assert!(matches!(result, Err(AggregationError::AllVenuesFailed(_))));
+        assert_eq!(flaky.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_skips_venues_with_open_circuits() {
+        use crate::application::services::circuit_breaker::{
+            CircuitBreakerConfig, CircuitBreakerRegistry,
+        };
+
+        let rfq = create_test_rfq();
+        let flaky = Arc::new(FlakyVenueAdapter::new(
+            MockVenueAdapter::successful("venue-1", rfq.id(), 100.0),
+            1,
+        ));
+        let breakers = Arc::new(CircuitBreakerRegistry::new(CircuitBreakerConfig::new(
+            1, 1, 60_000, 1,
+        )));
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(vec![
+                Arc::clone(&flaky) as Arc<dyn VenueAdapter>,
+                Arc::new(MockVenueAdapter::failing("venue-2")),
+            ])),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000).with_min_quotes(1),
+        )
+        .with_clock(test_clock())
+        .with_circuit_breakers(Arc::clone(&breakers));
+
+        assert!(engine.collect_and_rank(&rfq).await.is_err());
+        assert!(breakers.get_or_create("venue-1").is_open());
+        // Declining to quote says nothing about the venue's health.
+        assert!(breakers.get_or_create("venue-2").is_closed());
+
+        assert!(engine.collect_and_rank(&rfq).await.is_err());
+        assert_eq!(flaky.calls(), 1);
+    }
+
+    /// Venue that quotes after a delay and records its peak concurrency.
+    #[derive(Debug)]
+    struct ConcurrencyProbeVenue {
+        venue_id: VenueId,
+        delay: Duration,
+        in_flight: std::sync::atomic::AtomicUsize,
+        peak: std::sync::atomic::AtomicUsize,
+    }
+
+    impl ConcurrencyProbeVenue {
+        fn new(venue_id: &str, delay_ms: u64) -> Self {
+            Self {
+                venue_id: VenueId::new(venue_id),
+                delay: Duration::from_millis(delay_ms),
+                in_flight: std::sync::atomic::AtomicUsize::new(0),
+                peak: std::sync::atomic::AtomicUsize::new(0),
+            }
+        }
+
+        fn peak(&self) -> usize {
+            self.peak.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl VenueAdapter for ConcurrencyProbeVenue {
+        fn venue_id(&self) -> &VenueId {
+            &self.venue_id
+        }
+
+        fn timeout_ms(&self) -> u64 {
+            1000
+        }
+
+        async fn request_quote(&self, rfq: &Rfq) -> VenueResult<Quote> {
+            use std::sync::atomic::Ordering;
+
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(self.delay).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(Quote::new(
+                rfq.id(),
+                self.venue_id.clone(),
+                Price::new(100.0).unwrap(),
+                Quantity::new(1.0).unwrap(),
+                Timestamp::now().add_secs(60),
+            )
+            .unwrap())
+        }
+
+        async fn execute_trade(&self, _quote: &Quote) -> VenueResult<ExecutionResult> {
+            unimplemented!()
+        }
+
+        async fn health_check(&self) -> VenueResult<VenueHealth> {
+            Ok(VenueHealth::healthy(self.venue_id.clone()))
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingPublisher {
+        failed: Mutex<Vec<QuoteRequestFailed>>,
+        completed: Mutex<Vec<QuoteCollectionCompleted>>,
+    }
+
+    #[async_trait]
+    impl QuoteEventPublisher for RecordingPublisher {
+        async fn publish_quote_received(
+            &self,
+            _event: crate::domain::events::rfq_events::QuoteReceived,
+        ) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn publish_quote_request_failed(
+            &self,
+            event: QuoteRequestFailed,
+        ) -> Result<(), String> {
+            self.failed.lock().unwrap().push(event);
+            Ok(())
+        }
+
+        async fn publish_quote_collection_completed(
+            &self,
+            event: QuoteCollectionCompleted,
+        ) -> Result<(), String> {
+            self.completed.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    fn gated_engine(
+        venue: &Arc<ConcurrencyProbeVenue>,
+        gate: &Arc<VenueRequestGate>,
+        per_venue_timeout_ms: u64,
+    ) -> QuoteAggregationEngine {
+        QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(vec![
+                Arc::clone(venue) as Arc<dyn VenueAdapter>
+            ])),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000).with_per_venue_timeout(per_venue_timeout_ms),
+        )
+        .with_request_gate(Arc::clone(gate))
+    }
+
+    #[tokio::test]
+    async fn request_gate_caps_requests_in_flight_per_venue() {
+        let venue = Arc::new(ConcurrencyProbeVenue::new("venue-1", 20));
+        let gate = Arc::new(VenueRequestGate::new());
+        gate.set_limit(&VenueId::new("venue-1"), 2);
+        let engine = gated_engine(&venue, &gate, 2000);
+
+        let rfqs: Vec<Rfq> = (0..10).map(|_| create_test_rfq()).collect();
+        let results =
+            futures::future::join_all(rfqs.iter().map(|rfq| engine.collect_and_rank(rfq))).await;
+
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(venue.peak(), 2);
+    }
+
+    #[tokio::test]
+    async fn request_gate_resize_takes_effect() {
+        let venue = Arc::new(ConcurrencyProbeVenue::new("venue-1", 20));
+        let gate = Arc::new(VenueRequestGate::new());
+        gate.set_limit(&VenueId::new("venue-1"), 1);
+        let engine = gated_engine(&venue, &gate, 2000);
+
+        gate.set_limit(&VenueId::new("venue-1"), 4);
+        let rfqs: Vec<Rfq> = (0..8).map(|_| create_test_rfq()).collect();
+        let results =
+            futures::future::join_all(rfqs.iter().map(|rfq| engine.collect_and_rank(rfq))).await;
+
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(venue.peak(), 4);
+    }
+
+    #[tokio::test]
+    async fn request_gate_refuses_requests_that_cannot_get_a_permit() {
+        let venue = Arc::new(ConcurrencyProbeVenue::new("venue-1", 200));
+        let gate = Arc::new(VenueRequestGate::new());
+        gate.set_limit(&VenueId::new("venue-1"), 1);
+        let publisher = Arc::new(RecordingPublisher::default());
+        let engine = gated_engine(&venue, &gate, 50)
+            .with_event_publisher(Arc::clone(&publisher) as Arc<dyn QuoteEventPublisher>);
+        let held = gate
+            .acquire(&VenueId::new("venue-1"), Duration::ZERO)
+            .await
+            .unwrap();
+
+        let rfq = create_test_rfq();
+        let result = engine.collect_and_rank(&rfq).await;
+        drop(held);
+
+        let Err(AggregationError::AllVenuesFailed(errors)) = result else {
+            unreachable!("expected AllVenuesFailed");
+        };
+        assert!(errors.iter().all(|e| e.contains(CONCURRENCY_LIMIT_REASON)));
+        assert_eq!(venue.peak(), 0);
+        let failed = publisher.failed.lock().unwrap();
+        assert_eq!(failed.len(), 1);
+        let event = failed.first().unwrap();
+        assert_eq!(event.reason, CONCURRENCY_LIMIT_REASON);
+        assert_eq!(event.metadata.rfq_id, Some(rfq.id()));
+    }
+
+    struct FixedReference(Option<f64>);
+
+    #[async_trait]
+    impl crate::application::services::price_bounds::ReferencePriceProvider for FixedReference {
+        async fn get_reference(
+            &self,
+            _instrument: &Instrument,
+        ) -> crate::domain::errors::DomainResult<
+            Option<(
+                Price,
+                crate::domain::value_objects::reference_price::ReferencePriceSource,
+            )>,
+        > {
+            Ok(self.0.map(|price| {
+                (
+                    Price::new(price).unwrap(),
+                    crate::domain::value_objects::reference_price::ReferencePriceSource::ClobMid,
+                )
+            }))
+        }
+    }
+
+    /// Engine quoting `rfq` at `prices` from venues `venue-1`, `venue-2`,
+    /// ..., sanity checked against `reference`.
+    fn sanity_engine(
+        rfq: &Rfq,
+        prices: &[f64],
+        reference: Option<f64>,
+        publisher: &Arc<RecordingPublisher>,
+    ) -> QuoteAggregationEngine {
+        use crate::application::services::quote_sanity::QuoteSanityConfig;
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = prices
+            .iter()
+            .enumerate()
+            .map(|(i, &price)| {
+                let venue_id = format!("venue-{}", i + 1);
+                Arc::new(MockVenueAdapter::successful(&venue_id, rfq.id(), price))
+                    as Arc<dyn VenueAdapter>
+            })
+            .collect();
+        QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000),
+        )
+        .with_clock(test_clock())
+        .with_event_publisher(Arc::clone(publisher) as Arc<dyn QuoteEventPublisher>)
+        .with_quote_sanity(Arc::new(QuoteSanityFilter::new(
+            QuoteSanityConfig::default(),
+            Arc::new(FixedReference(reference)),
+        )))
+    }
+
+    fn outcome_of(result: &AggregationResult, venue: &str) -> VenueOutcomeKind {
+        result
+            .venue_outcomes()
+            .iter()
+            .find(|o| o.venue_id.as_str() == venue)
+            .map(|o| o.outcome.clone())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn quote_sanity_rejects_quotes_far_from_the_reference() {
+        use crate::domain::value_objects::PriceSanityBasis;
+
+        let rfq = create_test_rfq();
+        let publisher = Arc::new(RecordingPublisher::default());
+        // venue-3's adapter scaled its price by 1e6
+        let engine = sanity_engine(
+            &rfq,
+            &[50_000.0, 50_100.0, 0.05],
+            Some(50_050.0),
+            &publisher,
+        );
+
+        let result = engine.collect_and_rank(&rfq).await.unwrap();
+
+        assert_eq!(result.quote_count(), 2);
+        assert_eq!(result.total_collected(), 3);
+        let AggregationResult::Raw {
+            ranked_quotes,
+            filtered_count,
+            ..
+        } = &result
+        else {
+            unreachable!("expected raw quotes")
+        };
+        assert_eq!(*filtered_count, 1);
+        assert!(
+            ranked_quotes
+                .iter()
+                .all(|q| q.quote.venue_id().as_str() != "venue-3")
+        );
+        let VenueOutcomeKind::FilteredPriceDeviation {
+            basis,
+            deviation_pct,
+        } = outcome_of(&result, "venue-3")
+        else {
+            unreachable!("expected venue-3 to be filtered")
+        };
+        assert_eq!(basis, PriceSanityBasis::ReferencePrice);
+        assert!(deviation_pct > rust_decimal::Decimal::new(99, 2));
+
+        let failed = publisher.failed.lock().unwrap();
+        let [event] = failed.as_slice() else {
+            unreachable!("expected one QuoteRequestFailed event")
+        };
+        assert_eq!(event.venue_id.as_str(), "venue-3");
+        assert_eq!(event.metadata.rfq_id, Some(rfq.id()));
+        assert!(event.reason.contains("reference price 50050"));
+    }
+
+    #[tokio::test]
+    async fn quote_sanity_falls_back_to_the_median_without_a_reference() {
+        use crate::domain::value_objects::PriceSanityBasis;
+
+        let rfq = create_test_rfq();
+        let publisher = Arc::new(RecordingPublisher::default());
+        let engine = sanity_engine(&rfq, &[100.0, 99.0, 101.0, 100_000_000.0], None, &publisher);
+
+        let result = engine.collect_and_rank(&rfq).await.unwrap();
+
+        assert_eq!(result.quote_count(), 3);
+        assert!(matches!(
+            outcome_of(&result, "venue-4"),
+            VenueOutcomeKind::FilteredPriceDeviation {
+                basis: PriceSanityBasis::QuoteMedian,
+                ..
+            }
+        ));
+        let failed = publisher.failed.lock().unwrap();
+        let [event] = failed.as_slice() else {
+            unreachable!("expected one QuoteRequestFailed event")
+        };
+        assert_eq!(event.venue_id.as_str(), "venue-4");
+        assert!(event.reason.contains("quote median"));
+    }
+
+    #[tokio::test]
+    async fn quote_sanity_keeps_a_normal_spread() {
+        for reference in [Some(100.0), None] {
+            let rfq = create_test_rfq();
+            let publisher = Arc::new(RecordingPublisher::default());
+            let engine = sanity_engine(&rfq, &[97.0, 100.0, 103.5, 98.2], reference, &publisher);
+
+            let result = engine.collect_and_rank(&rfq).await.unwrap();
+
+            assert_eq!(result.quote_count(), 4);
+            assert!(
+                result
+                    .venue_outcomes()
+                    .iter()
+                    .all(|o| matches!(o.outcome, VenueOutcomeKind::Responded { .. }))
+            );
+            assert!(publisher.failed.lock().unwrap().is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_unmapped_symbol() {
+        let rfq = create_test_rfq();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(MockVenueAdapter::unmapped("venue-1")),
+            Arc::new(MockVenueAdapter::unmapped("venue-2")),
+        ];
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000).with_min_quotes(1),
+        )
+        .with_clock(test_clock());
+
+        let result = engine.collect_and_rank(&rfq).await;
+        let Err(AggregationError::UnmappedSymbol { symbol, venues }) = result else {
+            unreachable!("expected UnmappedSymbol");
+        };
+        assert_eq!(symbol, "BTC/USD");
+        assert_eq!(venues.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_mixed_failures_are_not_unmapped() {
+        let rfq = create_test_rfq();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(MockVenueAdapter::unmapped("venue-1")),
+            Arc::new(MockVenueAdapter::failing("venue-2")),
+        ];
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000).with_min_quotes(1),
+        )
+        .with_clock(test_clock());
+
+        let result = engine.collect_and_rank(&rfq).await;
+        assert!(matches!(result, Err(AggregationError::AllVenuesFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_partial_failure() {
+        let rfq = create_test_rfq();
+        let rfq_id = rfq.id();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(MockVenueAdapter::successful("venue-1", rfq_id, 100.0)),
+            Arc::new(MockVenueAdapter::failing("venue-2")),
+        ];
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000).with_min_quotes(1),
+        )
+        .with_clock(test_clock());
+
+        let result = engine.collect_and_rank(&rfq).await;
+        assert!(result.is_ok());
+
+        let agg_result = result.unwrap();
+        assert_eq!(agg_result.quote_count(), 1);
+    }
+
+    #[tokio::test]
+    #[allow(clippy::indexing_slicing)]
+    async fn collect_and_rank_reports_each_venue_outcome() {
+        use crate::application::services::circuit_breaker::{
+            CircuitBreakerConfig, CircuitBreakerRegistry,
+        };
+        use crate::infrastructure::persistence::in_memory::InMemoryAggregationReportRepository;
+
+        let rfq = create_test_rfq();
+        let breakers = Arc::new(CircuitBreakerRegistry::new(CircuitBreakerConfig::default()));
+        breakers.get_or_create("venue-3").force_open();
+        let store = Arc::new(InMemoryAggregationReportRepository::new());
+        let publisher = Arc::new(RecordingPublisher::default());
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(vec![
+                Arc::new(MockVenueAdapter::successful("venue-1", rfq.id(), 100.0)),
+                Arc::new(MockVenueAdapter::slow("venue-2", 500)),
+                Arc::new(MockVenueAdapter::successful("venue-3", rfq.id(), 99.0)),
+            ])),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000).with_per_venue_timeout(50),
+        )
+        .with_clock(test_clock())
+        .with_circuit_breakers(breakers)
+        .with_report_store(Arc::clone(&store) as Arc<dyn AggregationReportRepository>)
+        .with_event_publisher(Arc::clone(&publisher) as Arc<dyn QuoteEventPublisher>);
+
+        let result = engine.collect_and_rank(&rfq).await.unwrap();
+        let AggregationResult::Raw { ranked_quotes, .. } = &result else {
+            unreachable!("expected raw quotes");
+        };
+        let quote_id = ranked_quotes[0].quote.id().to_string();
+
+        let report = store.find_by_rfq_id(rfq.id()).await.unwrap().unwrap();
+        let ranking = report.quote_ranking(ranked_quotes[0].quote.id()).unwrap();
+        assert_eq!(ranking.rank, 1);
+        assert_eq!(ranking.venue_id.as_str(), "venue-1");
+        assert_eq!(ranking.score_breakdown, ranked_quotes[0].score_breakdown);
+        assert_eq!(report.quote_rankings.len(), ranked_quotes.len());
+        let outcomes: Vec<(&str, &VenueOutcomeKind)> = report
+            .venue_outcomes
+            .iter()
+            .map(|o| (o.venue_id.as_str(), &o.outcome))
+            .collect();
+        assert!(matches!(
+            outcomes[0],
+            ("venue-1", VenueOutcomeKind::Responded { latency_ms: Some(_), quote_id: id })
+                if *id == quote_id
+        ));
+        assert_eq!(outcomes[1], ("venue-2", &VenueOutcomeKind::Timeout));
+        assert_eq!(
+            outcomes[2],
+            (
+                "venue-3",
+                &VenueOutcomeKind::Excluded {
+                    reason: VenueExclusionReason::CircuitOpen
+                }
+            )
+        );
+        assert_eq!(report.venue_outcomes, {
+            let mut sorted = result.venue_outcomes().to_vec();
+            sorted.sort_by(|a, b| a.venue_id.as_str().cmp(b.venue_id.as_str()));
+            sorted
+        });
+
+        let completed = publisher.completed.lock().unwrap();
+        let [event] = completed.as_slice() else {
+            unreachable!("expected one QuoteCollectionCompleted event");
+        };
+        assert_eq!(event.venue_outcomes, report.counts());
+        assert_eq!(event.venue_outcomes.responded, 1);
+        assert_eq!(event.venue_outcomes.timed_out, 1);
+        assert_eq!(event.venue_outcomes.excluded, 1);
+        assert_eq!(event.venues_failed, 1);
+        assert_eq!(event.quotes_received, 1);
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_reports_filtered_venues() {
+        let clock = test_clock();
+        let rfq = create_test_rfq();
+        let short_lived = Quote::new(
+            rfq.id(),
+            VenueId::new("venue-2"),
+            Price::new(99.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(10),
+        )
+        .unwrap();
+        let expired = MockVenueAdapter {
+            venue_id: VenueId::new("venue-2"),
+            quote_result: Mutex::new(Some(Ok(short_lived))),
+            delay_ms: 0,
+        };
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(vec![
+                Arc::new(MockVenueAdapter::successful("venue-1", rfq.id(), 100.0)),
+                Arc::new(expired),
+                Arc::new(MockVenueAdapter::failing("venue-3")),
+            ])),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000),
+        )
+        .with_clock(clock.clone());
+
+        // venue-2's quote is valid for 10s, venue-1's for 60s
+        clock.advance_secs(30);
 
-        let agg_result = result.unwrap();
-        assert_eq!(agg_result.quote_count(), 1);
+        let result = engine.collect_and_rank(&rfq).await.unwrap();
+        let outcomes = result.venue_outcomes();
+        let outcome_of = |venue: &str| {
+            outcomes
+                .iter()
+                .find(|o| o.venue_id.as_str() == venue)
+                .map(|o| o.outcome.clone())
+                .unwrap()
+        };
+        assert!(matches!(
+            outcome_of("venue-1"),
+            VenueOutcomeKind::Responded { .. }
+        ));
+        assert_eq!(outcome_of("venue-2"), VenueOutcomeKind::FilteredExpired);
+        assert!(matches!(
+            outcome_of("venue-3"),
+            VenueOutcomeKind::Error { reason } if reason.contains("no liquidity")
+        ));
     }
 
     #[tokio::test]
@@ -670,12 +3139,38 @@ mod tests {
             Arc::new(MockVenueRegistry::with_venues(venues)),
             Arc::new(BestPriceStrategy::new()),
             AggregationConfig::with_timeout(50),
-        );
+        )
+        .with_clock(test_clock());
 
         let result = engine.collect_and_rank(&rfq).await;
         assert!(matches!(result, Err(AggregationError::Timeout)));
     }
 
+    #[tokio::test]
+    async fn collect_and_rank_cancelled_stops_waiting_on_venues() {
+        let rfq = create_test_rfq();
+        let venues: Vec<Arc<dyn VenueAdapter>> =
+            vec![Arc::new(MockVenueAdapter::slow("venue-1", 5000))];
+        let token = CancellationToken::new();
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(10_000).with_per_venue_timeout(10_000),
+        )
+        .with_cancellation(token.clone());
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            token.cancel();
+        });
+        let started = std::time::Instant::now();
+        let result = engine.collect_and_rank(&rfq).await;
+
+        assert!(matches!(result, Err(AggregationError::Cancelled)));
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
     #[tokio::test]
     async fn collect_and_rank_max_quotes() {
         let rfq = create_test_rfq();
@@ -691,7 +3186,8 @@ mod tests {
             Arc::new(MockVenueRegistry::with_venues(venues)),
             Arc::new(BestPriceStrategy::new()),
             AggregationConfig::with_timeout(5000).with_max_quotes(2),
-        );
+        )
+        .with_clock(test_clock());
 
         let result = engine.collect_and_rank(&rfq).await;
         assert!(result.is_ok());
@@ -700,6 +3196,66 @@ mod tests {
         assert_eq!(agg_result.quote_count(), 2);
     }
 
+    #[tokio::test]
+    async fn collect_and_rank_filters_quotes_expired_by_clock() {
+        let rfq = create_test_rfq();
+        let rfq_id = rfq.id();
+        let clock = test_clock();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(MockVenueAdapter::successful("venue-1", rfq_id, 100.0)),
+            Arc::new(MockVenueAdapter::successful("venue-2", rfq_id, 95.0)),
+        ];
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000).with_min_quotes(1),
+        )
+        .with_clock(clock.clone());
+
+        // Quotes are valid for 60s; move past their validity window
+        clock.advance_secs(61);
+
+        let result = engine.collect_and_rank(&rfq).await;
+        assert!(matches!(
+            result,
+            Err(AggregationError::InsufficientQuotes {
+                collected: 0,
+                required: 1
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_strategy_filters_packages_expired_by_clock() {
+        let clock = test_clock();
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(PricingVenueAdapter::packaged("low", 120)),
+            Arc::new(PricingVenueAdapter::packaged("high", 160)),
+        ];
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000),
+        )
+        .with_clock(clock.clone());
+
+        clock.advance_secs(59);
+        let result = engine
+            .collect_and_rank(&create_straddle_rfq(OrderSide::Buy))
+            .await
+            .unwrap();
+        assert_eq!(result.quote_count(), 2);
+
+        clock.advance_secs(2);
+        let result = engine
+            .collect_and_rank(&create_straddle_rfq(OrderSide::Buy))
+            .await;
+        assert!(result.is_err());
+    }
+
     #[test]
     fn aggregation_config_default() {
         let config = AggregationConfig::default();
@@ -745,9 +3301,482 @@ mod tests {
             venues_queried: 2,
             venues_responded: 0,
             filtered_count: 0,
+            rejected_short_ttl: 0,
+            deduplicated: vec![],
+            venue_outcomes: vec![],
         };
 
         assert!(!result.has_sufficient_quotes(1));
         assert!(result.has_sufficient_quotes(0));
     }
+
+    /// Venue whose quotes expire after a fixed number of seconds, and which
+    /// optionally honours TTL hints with a longer validity.
+    #[derive(Debug)]
+    struct TtlVenueAdapter {
+        venue_id: VenueId,
+        ttl_secs: i64,
+        hinted_ttl_secs: Option<i64>,
+        hinted_requests: std::sync::atomic::AtomicUsize,
+    }
+
+    impl TtlVenueAdapter {
+        fn new(venue_id: &str, ttl_secs: i64) -> Self {
+            Self {
+                venue_id: VenueId::new(venue_id),
+                ttl_secs,
+                hinted_ttl_secs: None,
+                hinted_requests: std::sync::atomic::AtomicUsize::new(0),
+            }
+        }
+
+        fn with_ttl_hint(venue_id: &str, ttl_secs: i64, hinted_ttl_secs: i64) -> Self {
+            Self {
+                hinted_ttl_secs: Some(hinted_ttl_secs),
+                ..Self::new(venue_id, ttl_secs)
+            }
+        }
+
+        fn quote(&self, rfq: &Rfq, ttl_secs: i64) -> Quote {
+            Quote::new(
+                rfq.id(),
+                self.venue_id.clone(),
+                Price::new(100.0).unwrap(),
+                rfq.quantity(),
+                Timestamp::now().add_secs(ttl_secs),
+            )
+            .unwrap()
+        }
+    }
+
+    #[async_trait]
+    impl VenueAdapter for TtlVenueAdapter {
+        fn venue_id(&self) -> &VenueId {
+            &self.venue_id
+        }
+
+        fn timeout_ms(&self) -> u64 {
+            1000
+        }
+
+        async fn request_quote(&self, rfq: &Rfq) -> VenueResult<Quote> {
+            Ok(self.quote(rfq, self.ttl_secs))
+        }
+
+        fn supports_ttl_hint(&self) -> bool {
+            self.hinted_ttl_secs.is_some()
+        }
+
+        async fn request_quote_with_ttl(&self, rfq: &Rfq, _min_ttl_ms: u64) -> VenueResult<Quote> {
+            self.hinted_requests
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let ttl_secs = self
+                .hinted_ttl_secs
+                .ok_or_else(|| VenueError::unsupported_operation("quote TTL hint"))?;
+            Ok(self.quote(rfq, ttl_secs))
+        }
+
+        async fn execute_trade(&self, _quote: &Quote) -> VenueResult<ExecutionResult> {
+            unimplemented!()
+        }
+
+        async fn health_check(&self) -> VenueResult<VenueHealth> {
+            Ok(VenueHealth::healthy(self.venue_id.clone()))
+        }
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_rejects_quotes_below_ttl_floor() {
+        let rfq = create_test_rfq();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(TtlVenueAdapter::new("venue-short", 5)),
+            Arc::new(TtlVenueAdapter::new("venue-long", 60)),
+        ];
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000).with_min_quote_ttl(30_000),
+        )
+        .with_clock(test_clock());
+
+        let result = engine.collect_and_rank(&rfq).await.unwrap();
+        assert_eq!(result.quote_count(), 1);
+        assert_eq!(result.rejected_short_ttl(), 1);
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_without_ttl_floor_accepts_short_quotes() {
+        let rfq = create_test_rfq();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> =
+            vec![Arc::new(TtlVenueAdapter::new("venue-short", 1))];
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000),
+        )
+        .with_clock(test_clock());
+
+        let result = engine.collect_and_rank(&rfq).await.unwrap();
+        assert_eq!(result.quote_count(), 1);
+        assert_eq!(result.rejected_short_ttl(), 0);
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_re_requests_short_quotes_with_ttl_hint() {
+        let rfq = create_test_rfq();
+        let venue = Arc::new(TtlVenueAdapter::with_ttl_hint("venue-hint", 5, 60));
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![venue.clone()];
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000).with_min_quote_ttl(30_000),
+        )
+        .with_clock(test_clock());
+
+        let result = engine.collect_and_rank(&rfq).await.unwrap();
+        assert_eq!(result.quote_count(), 1);
+        assert_eq!(result.rejected_short_ttl(), 0);
+        assert_eq!(
+            venue
+                .hinted_requests
+                .load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_rejects_when_ttl_hint_still_short() {
+        let rfq = create_test_rfq();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(TtlVenueAdapter::with_ttl_hint("venue-hint", 5, 10)),
+            Arc::new(TtlVenueAdapter::new("venue-long", 60)),
+        ];
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000).with_min_quote_ttl(30_000),
+        )
+        .with_clock(test_clock());
+
+        let result = engine.collect_and_rank(&rfq).await.unwrap();
+        assert_eq!(result.quote_count(), 1);
+        assert_eq!(result.rejected_short_ttl(), 1);
+    }
+
+    #[tokio::test]
+    async fn collect_and_rank_per_venue_ttl_floor_overrides_global() {
+        let rfq = create_test_rfq();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(TtlVenueAdapter::new("venue-lenient", 5)),
+            Arc::new(TtlVenueAdapter::new("venue-strict", 60)),
+        ];
+        let registry = MockVenueRegistry::with_venues(venues)
+            .with_min_quote_ttl("venue-lenient", 1_000)
+            .with_min_quote_ttl("venue-strict", 120_000);
+
+        let engine = QuoteAggregationEngine::new(
+            Arc::new(registry),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000).with_min_quote_ttl(30_000),
+        )
+        .with_clock(test_clock());
+
+        let result = engine.collect_and_rank(&rfq).await.unwrap();
+        assert_eq!(result.quote_count(), 1);
+        assert_eq!(result.rejected_short_ttl(), 1);
+        #[allow(clippy::indexing_slicing)]
+        if let AggregationResult::Raw { ranked_quotes, .. } = result {
+            assert_eq!(
+                ranked_quotes[0].quote.venue_id(),
+                &VenueId::new("venue-lenient")
+            );
+        } else {
+            unreachable!("Expected Raw variant since no normalizer was configured");
+        }
+    }
+
+    fn reusing_engine(
+        venues: &[&Arc<PricingVenueAdapter>],
+        cache: &Arc<QuoteReuseCache>,
+        clock: &Arc<MockClock>,
+    ) -> QuoteAggregationEngine {
+        let venues = venues
+            .iter()
+            .map(|venue| Arc::clone(venue) as Arc<dyn VenueAdapter>)
+            .collect();
+        QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000),
+        )
+        .with_clock(Arc::clone(clock) as Arc<dyn Clock>)
+        .with_quote_reuse(Arc::clone(cache))
+    }
+
+    fn requests(venue: &PricingVenueAdapter) -> usize {
+        venue
+            .single_leg_requests
+            .load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    fn ranked(result: AggregationResult) -> Vec<Quote> {
+        let AggregationResult::Raw { ranked_quotes, .. } = result else {
+            unreachable!("expected raw quotes without a normalizer")
+        };
+        ranked_quotes
+            .into_iter()
+            .map(|ranked| ranked.quote)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn identical_rfq_reuses_cached_quotes() {
+        let venue = Arc::new(PricingVenueAdapter::per_leg("venue-1", 100.0));
+        let cache = Arc::new(QuoteReuseCache::new(Duration::from_millis(500)));
+        let clock = test_clock();
+        let engine = reusing_engine(&[&venue], &cache, &clock);
+        let (first, second) = (create_test_rfq(), create_test_rfq());
+
+        let original = ranked(engine.collect_and_rank(&first).await.unwrap());
+        clock.advance(Duration::from_millis(200));
+        let reused = ranked(engine.collect_and_rank(&second).await.unwrap());
+
+        assert_eq!(requests(&venue), 1);
+        let ([original], [reused]) = (original.as_slice(), reused.as_slice()) else {
+            unreachable!("expected one quote per aggregation")
+        };
+        assert_eq!(reused.rfq_id(), second.id());
+        assert_ne!(reused.id(), original.id());
+        assert_eq!(reused.venue_id(), original.venue_id());
+        assert_eq!(reused.price(), original.price());
+    }
+
+    #[tokio::test]
+    async fn cached_quotes_past_the_ttl_are_requested_again() {
+        let venue = Arc::new(PricingVenueAdapter::per_leg("venue-1", 100.0));
+        let cache = Arc::new(QuoteReuseCache::new(Duration::from_millis(500)));
+        let clock = test_clock();
+        let engine = reusing_engine(&[&venue], &cache, &clock);
+
+        engine.collect_and_rank(&create_test_rfq()).await.unwrap();
+        clock.advance(Duration::from_millis(600));
+        let second = create_test_rfq();
+        let quotes = ranked(engine.collect_and_rank(&second).await.unwrap());
+
+        assert_eq!(requests(&venue), 2);
+        assert!(quotes.iter().all(|quote| quote.rfq_id() == second.id()));
+    }
+
+    #[tokio::test]
+    async fn only_uncovered_venues_are_asked() {
+        let cached_venue = Arc::new(PricingVenueAdapter::per_leg("venue-1", 100.0));
+        let new_venue = Arc::new(PricingVenueAdapter::per_leg("venue-2", 99.0));
+        let cache = Arc::new(QuoteReuseCache::new(Duration::from_millis(500)));
+        let clock = test_clock();
+        reusing_engine(&[&cached_venue], &cache, &clock)
+            .collect_and_rank(&create_test_rfq())
+            .await
+            .unwrap();
+
+        let second = create_test_rfq();
+        let quotes = ranked(
+            reusing_engine(&[&cached_venue, &new_venue], &cache, &clock)
+                .collect_and_rank(&second)
+                .await
+                .unwrap(),
+        );
+
+        assert_eq!(requests(&cached_venue), 1);
+        assert_eq!(requests(&new_venue), 1);
+        let venues: Vec<&str> = quotes.iter().map(|q| q.venue_id().as_str()).collect();
+        assert_eq!(venues, ["venue-2", "venue-1"]);
+        assert!(quotes.iter().all(|quote| quote.rfq_id() == second.id()));
+    }
+
+    #[tokio::test]
+    async fn anonymous_rfqs_bypass_the_cache() {
+        let venue = Arc::new(PricingVenueAdapter::per_leg("venue-1", 100.0));
+        let cache = Arc::new(QuoteReuseCache::default());
+        let clock = test_clock();
+        let engine = reusing_engine(&[&venue], &cache, &clock);
+        engine.collect_and_rank(&create_test_rfq()).await.unwrap();
+
+        let anonymous = RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            create_test_rfq().instrument().clone(),
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .anonymous()
+        .build();
+        engine.collect_and_rank(&anonymous).await.unwrap();
+
+        assert_eq!(requests(&venue), 2);
+    }
+
+    /// Venue that quotes on the client's account when given the client's
+    /// API key, and refuses any other key.
+    #[derive(Debug)]
+    struct AccountVenueAdapter {
+        platform: Arc<PricingVenueAdapter>,
+        client: Arc<PricingVenueAdapter>,
+    }
+
+    impl AccountVenueAdapter {
+        fn new(venue_id: &str, platform_price: f64, client_price: f64) -> Self {
+            Self {
+                platform: Arc::new(PricingVenueAdapter::per_leg(venue_id, platform_price)),
+                client: Arc::new(PricingVenueAdapter::per_leg(venue_id, client_price)),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl VenueAdapter for AccountVenueAdapter {
+        fn venue_id(&self) -> &VenueId {
+            self.platform.venue_id()
+        }
+
+        fn timeout_ms(&self) -> u64 {
+            1000
+        }
+
+        async fn request_quote(&self, rfq: &Rfq) -> VenueResult<Quote> {
+            self.platform.request_quote(rfq).await
+        }
+
+        async fn execute_trade(&self, _quote: &Quote) -> VenueResult<ExecutionResult> {
+            unimplemented!()
+        }
+
+        async fn health_check(&self) -> VenueResult<VenueHealth> {
+            self.platform.health_check().await
+        }
+
+        fn supports_credential_override(&self) -> bool {
+            true
+        }
+
+        fn with_credential_override(
+            &self,
+            credentials: &VenueCredentials,
+        ) -> VenueResult<Arc<dyn VenueAdapter>> {
+            if credentials.api_key() == "client-key" {
+                Ok(Arc::clone(&self.client) as Arc<dyn VenueAdapter>)
+            } else {
+                Err(VenueError::internal_error("API key rejected"))
+            }
+        }
+    }
+
+    async fn credential_store(sets: &[(&str, &str)]) -> Arc<InMemoryVenueCredentialRepository> {
+        let store = Arc::new(InMemoryVenueCredentialRepository::new());
+        for (venue_id, api_key) in sets {
+            store
+                .save(&VenueCredentialSet::new(
+                    CounterpartyId::new("client-1"),
+                    VenueId::new(*venue_id),
+                    VenueCredentials::new(*api_key).unwrap(),
+                ))
+                .await
+                .unwrap();
+        }
+        store
+    }
+
+    fn credentialed_engine(
+        venues: Vec<Arc<dyn VenueAdapter>>,
+        store: Arc<InMemoryVenueCredentialRepository>,
+    ) -> QuoteAggregationEngine {
+        QuoteAggregationEngine::new(
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            Arc::new(BestPriceStrategy::new()),
+            AggregationConfig::with_timeout(5000),
+        )
+        .with_clock(test_clock())
+        .with_venue_credentials(store)
+    }
+
+    #[tokio::test]
+    async fn client_credentials_are_used_where_the_venue_accepts_them() {
+        let account_venue = Arc::new(AccountVenueAdapter::new("venue-1", 100.0, 90.0));
+        let platform_venue = Arc::new(PricingVenueAdapter::per_leg("venue-2", 95.0));
+        let store = credential_store(&[("venue-1", "client-key"), ("venue-2", "client-key")]).await;
+        let cache = Arc::new(QuoteReuseCache::new(Duration::from_millis(500)));
+        let engine = credentialed_engine(
+            vec![
+                Arc::clone(&account_venue) as Arc<dyn VenueAdapter>,
+                Arc::clone(&platform_venue) as Arc<dyn VenueAdapter>,
+            ],
+            store,
+        )
+        .with_quote_reuse(cache);
+
+        let quotes = ranked(engine.collect_and_rank(&create_test_rfq()).await.unwrap());
+        engine.collect_and_rank(&create_test_rfq()).await.unwrap();
+
+        let prices: Vec<(&str, Price)> = quotes
+            .iter()
+            .map(|q| (q.venue_id().as_str(), q.price()))
+            .collect();
+        assert_eq!(
+            prices,
+            [
+                ("venue-1", Price::new(90.0).unwrap()),
+                ("venue-2", Price::new(95.0).unwrap())
+            ]
+        );
+        assert_eq!(requests(&account_venue.platform), 0);
+        // Quotes on the client's account are never reused
+        assert_eq!(requests(&account_venue.client), 2);
+        assert_eq!(requests(&platform_venue), 2);
+    }
+
+    #[tokio::test]
+    async fn clients_without_credentials_use_the_platform_account() {
+        let account_venue = Arc::new(AccountVenueAdapter::new("venue-1", 100.0, 90.0));
+        let engine = credentialed_engine(
+            vec![Arc::clone(&account_venue) as Arc<dyn VenueAdapter>],
+            credential_store(&[]).await,
+        );
+
+        let quotes = ranked(engine.collect_and_rank(&create_test_rfq()).await.unwrap());
+
+        assert_eq!(quotes.len(), 1);
+        assert_eq!(requests(&account_venue.platform), 1);
+        assert_eq!(requests(&account_venue.client), 0);
+    }
+
+    #[tokio::test]
+    async fn rejected_client_credentials_fail_the_venue_without_asking_it() {
+        let account_venue = Arc::new(AccountVenueAdapter::new("venue-1", 100.0, 90.0));
+        let other_venue = Arc::new(PricingVenueAdapter::per_leg("venue-2", 95.0));
+        let engine = credentialed_engine(
+            vec![
+                Arc::clone(&account_venue) as Arc<dyn VenueAdapter>,
+                Arc::clone(&other_venue) as Arc<dyn VenueAdapter>,
+            ],
+            credential_store(&[("venue-1", "revoked-key")]).await,
+        );
+
+        let result = engine.collect_and_rank(&create_test_rfq()).await.unwrap();
+
+        assert!(matches!(
+            outcome_of(&result, "venue-1"),
+            VenueOutcomeKind::Error { reason } if reason.contains("API key rejected")
+        ));
+        assert_eq!(result.venues_queried(), 2);
+        assert_eq!(requests(&account_venue.platform), 0);
+        assert_eq!(requests(&account_venue.client), 0);
+        let quotes = ranked(result);
+        assert_eq!(quotes.len(), 1);
+    }
 }
@@ -0,0 +1,323 @@
+//! # Quote Reuse Cache
+//!
+//! Shares recent venue quotes between identical RFQs.
+//!
+//! Two clients asking for the same instrument, direction and size within a
+//! few hundred milliseconds would otherwise send market makers the same
+//! request twice. [`QuoteReuseCache`] keeps the quotes of each aggregation
+//! for a short TTL, keyed by [`QuoteReuseKey`]. A later identical RFQ gets
+//! copies of the still-valid quotes under its own RFQ and quote IDs, and
+//! only the venues without a cached quote are asked.
+//!
+//! The TTL should stay well under the shortest quote validity; a quote is
+//! never reused past its own expiry either way. Quotes a client selected
+//! are dropped through [`QuoteReuseCache::mark_selected`], since the
+//! venue's liquidity behind them is spoken for.
+//!
+//! Anonymous RFQs and RFQs restricted to a venue allowlist never use the
+//! cache, in either direction.
+
+use crate::domain::entities::quote::Quote;
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    Instrument, QuantityDisclosure, QuoteId, RfqDirection, SizeNegotiationMode, VenueId,
+};
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+/// Default time a venue quote stays reusable.
+pub const DEFAULT_QUOTE_REUSE_TTL: Duration = Duration::from_millis(500);
+
+/// What makes two RFQs identical for quote reuse.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QuoteReuseKey {
+    instrument: Instrument,
+    direction: RfqDirection,
+    quantity_bucket: Decimal,
+    size_mode: SizeNegotiationMode,
+    quantity_disclosure: QuantityDisclosure,
+}
+
+/// A cached venue quote and the IDs it was reissued under.
+#[derive(Debug)]
+struct CachedQuote {
+    quote: Quote,
+    issued: Vec<QuoteId>,
+}
+
+impl CachedQuote {
+    fn has_id(&self, quote_id: QuoteId) -> bool {
+        self.quote.id() == quote_id || self.issued.contains(&quote_id)
+    }
+}
+
+/// Recent aggregation quotes, reusable by identical RFQs.
+pub struct QuoteReuseCache {
+    ttl: Duration,
+    quantity_bucket: Option<Decimal>,
+    entries: Mutex<HashMap<QuoteReuseKey, Vec<CachedQuote>>>,
+}
+
+impl QuoteReuseCache {
+    /// Creates a cache whose quotes stay reusable for `ttl` after arrival.
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            quantity_bucket: None,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Treats quantities in the same multiple of `width` as identical.
+    ///
+    /// Without a bucket only equal quantities match. Non-positive widths
+    /// are ignored.
+    #[must_use]
+    pub fn with_quantity_bucket(mut self, width: Decimal) -> Self {
+        self.quantity_bucket = (width > Decimal::ZERO).then_some(width);
+        self
+    }
+
+    /// Returns how long a quote stays reusable after arrival.
+    #[must_use]
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the cache key of `rfq`, or `None` if it may not use the cache.
+    ///
+    /// Anonymous, allowlist-restricted and multi-leg RFQs are not cached.
+    #[must_use]
+    pub fn key_for(&self, rfq: &Rfq) -> Option<QuoteReuseKey> {
+        if rfq.is_anonymous() || rfq.venue_allowlist().is_some() || rfq.strategy().is_some() {
+            return None;
+        }
+        let quantity = rfq.quantity().get();
+        let quantity_bucket = match self.quantity_bucket {
+            Some(width) => quantity
+                .checked_div(width)
+                .and_then(|buckets| buckets.floor().checked_mul(width))
+                .unwrap_or(quantity),
+            None => quantity,
+        };
+        Some(QuoteReuseKey {
+            instrument: rfq.instrument().clone(),
+            direction: rfq.direction(),
+            quantity_bucket,
+            size_mode: rfq.size_mode().clone(),
+            quantity_disclosure: rfq.quantity_disclosure(),
+        })
+    }
+
+    /// Returns copies of the reusable quotes under `key`, issued for `rfq`.
+    ///
+    /// Quotes that arrived more than the TTL ago, have expired, or come
+    /// from a venue `rfq` excludes are skipped.
+    pub fn reuse(&self, key: &QuoteReuseKey, rfq: &Rfq, now: Timestamp) -> Vec<Quote> {
+        let mut entries = self.entries.lock();
+        self.prune(&mut entries, now);
+        let Some(cached) = entries.get_mut(key) else {
+            return Vec::new();
+        };
+        cached
+            .iter_mut()
+            .filter(|cached| rfq.venue_exclusion(cached.quote.venue_id()).is_none())
+            .map(|cached| {
+                let copy = cached.quote.reissue_for(rfq.id());
+                cached.issued.push(copy.id());
+                copy
+            })
+            .collect()
+    }
+
+    /// Caches the quotes freshly collected for an RFQ under `key`.
+    ///
+    /// Cached quotes from the same venues are replaced.
+    pub fn store(&self, key: QuoteReuseKey, quotes: &[Quote], now: Timestamp) {
+        let mut entries = self.entries.lock();
+        self.prune(&mut entries, now);
+        let fresh: Vec<&Quote> = quotes
+            .iter()
+            .filter(|quote| self.is_reusable(quote, now))
+            .collect();
+        if fresh.is_empty() {
+            return;
+        }
+        let venues: Vec<&VenueId> = fresh.iter().map(|quote| quote.venue_id()).collect();
+        let cached = entries.entry(key).or_default();
+        cached.retain(|cached| !venues.contains(&cached.quote.venue_id()));
+        cached.extend(fresh.into_iter().map(|quote| CachedQuote {
+            quote: quote.clone(),
+            issued: Vec::new(),
+        }));
+    }
+
+    /// Stops reusing the quote selected as `quote_id`.
+    ///
+    /// `quote_id` may be the original quote or any copy of it.
+    pub fn mark_selected(&self, quote_id: QuoteId) {
+        let mut entries = self.entries.lock();
+        for cached in entries.values_mut() {
+            cached.retain(|cached| !cached.has_id(quote_id));
+        }
+        entries.retain(|_, cached| !cached.is_empty());
+    }
+
+    /// Returns the number of quotes currently cached.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.lock().values().map(Vec::len).sum()
+    }
+
+    /// Returns true if no quotes are cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn is_reusable(&self, quote: &Quote, now: Timestamp) -> bool {
+        !quote.is_expired_at(now) && quote.received_at().duration_until(&now) <= self.ttl
+    }
+
+    fn prune(&self, entries: &mut HashMap<QuoteReuseKey, Vec<CachedQuote>>, now: Timestamp) {
+        for cached in entries.values_mut() {
+            cached.retain(|cached| self.is_reusable(&cached.quote, now));
+        }
+        entries.retain(|_, cached| !cached.is_empty());
+    }
+}
+
+impl Default for QuoteReuseCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_QUOTE_REUSE_TTL)
+    }
+}
+
+impl fmt::Debug for QuoteReuseCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QuoteReuseCache")
+            .field("ttl", &self.ttl)
+            .field("quantity_bucket", &self.quantity_bucket)
+            .field("cached_quotes", &self.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::value_objects::{
+        AssetClass, CounterpartyId, OrderSide, Price, Quantity, Symbol,
+    };
+
+    fn rfq(quantity: f64) -> Rfq {
+        RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build(),
+            OrderSide::Buy,
+            Quantity::new(quantity).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build()
+    }
+
+    fn quote(rfq: &Rfq, venue: &str, now: Timestamp) -> Quote {
+        Quote::new(
+            rfq.id(),
+            VenueId::new(venue),
+            Price::new(50000.0).unwrap(),
+            rfq.quantity(),
+            now.add_secs(30),
+        )
+        .unwrap()
+        .with_received_at(now)
+    }
+
+    #[test]
+    fn reissues_cached_quotes_for_an_identical_rfq() {
+        let cache = QuoteReuseCache::default();
+        let now = Timestamp::now();
+        let (first, second) = (rfq(1.0), rfq(1.0));
+        let original = quote(&first, "mm-a", now);
+        cache.store(
+            cache.key_for(&first).unwrap(),
+            std::slice::from_ref(&original),
+            now,
+        );
+
+        let reused = cache.reuse(&cache.key_for(&second).unwrap(), &second, now);
+
+        let copy = reused.first().unwrap();
+        assert_eq!(reused.len(), 1);
+        assert_eq!(copy.rfq_id(), second.id());
+        assert_ne!(copy.id(), original.id());
+        assert_eq!(copy.venue_id(), original.venue_id());
+        assert_eq!(copy.price(), original.price());
+    }
+
+    #[test]
+    fn quotes_older_than_the_ttl_are_not_reused() {
+        let cache = QuoteReuseCache::new(Duration::from_millis(500));
+        let now = Timestamp::now();
+        let first = rfq(1.0);
+        let key = cache.key_for(&first).unwrap();
+        cache.store(key.clone(), &[quote(&first, "mm-a", now)], now);
+
+        assert!(cache.reuse(&key, &rfq(1.0), now.add_millis(501)).is_empty());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn selected_quotes_are_not_reused() {
+        let cache = QuoteReuseCache::default();
+        let now = Timestamp::now();
+        let first = rfq(1.0);
+        let key = cache.key_for(&first).unwrap();
+        cache.store(key.clone(), &[quote(&first, "mm-a", now)], now);
+        let second = rfq(1.0);
+        let copy = cache.reuse(&key, &second, now).pop().unwrap();
+
+        cache.mark_selected(copy.id());
+
+        assert!(cache.reuse(&key, &rfq(1.0), now).is_empty());
+    }
+
+    #[test]
+    fn anonymous_and_allowlisted_rfqs_have_no_key() {
+        let cache = QuoteReuseCache::default();
+        let base = || {
+            RfqBuilder::new(
+                CounterpartyId::new("client-1"),
+                Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot)
+                    .build(),
+                OrderSide::Buy,
+                Quantity::new(1.0).unwrap(),
+                Timestamp::now().add_secs(300),
+            )
+        };
+
+        assert!(cache.key_for(&base().anonymous().build()).is_none());
+        assert!(
+            cache
+                .key_for(&base().venue_allowlist(vec![VenueId::new("mm-a")]).build())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn quantity_bucket_groups_nearby_sizes() {
+        let exact = QuoteReuseCache::default();
+        let bucketed = QuoteReuseCache::default().with_quantity_bucket(Decimal::ONE);
+
+        assert_ne!(exact.key_for(&rfq(1.2)), exact.key_for(&rfq(1.7)));
+        assert_eq!(bucketed.key_for(&rfq(1.2)), bucketed.key_for(&rfq(1.7)));
+        assert_ne!(bucketed.key_for(&rfq(1.7)), bucketed.key_for(&rfq(2.1)));
+    }
+}
@@ -0,0 +1,384 @@
+//! # Quote Price Sanity
+//!
+//! Rejects venue quotes priced implausibly far from the market.
+//!
+//! A venue adapter that mis-scales a price produces a quote that ranks best
+//! by a wide margin and can go on to be executed. [`QuoteSanityFilter`]
+//! compares each received quote against the instrument's reference price
+//! from a [`ReferencePriceProvider`] and rejects quotes deviating more than
+//! the liquidity tier's limit in [`QuoteSanityConfig`]. The limits are
+//! deliberately looser than the block trade [`PriceBoundsConfig`]: they are
+//! meant to catch broken prices, not aggressive ones.
+//!
+//! When no reference price is available, quotes are instead compared with
+//! the median price of the quotes received on the same side, and rejected
+//! beyond [`QuoteSanityConfig::max_median_deviation`]. The median is only
+//! trusted with at least [`MIN_QUOTES_FOR_MEDIAN`] quotes.
+//!
+//! [`PriceBoundsConfig`]: crate::domain::value_objects::reference_price::PriceBoundsConfig
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::application::services::quote_sanity::QuoteSanityConfig;
+//! use otc_rfq::domain::value_objects::LiquidityClassification;
+//! use rust_decimal::Decimal;
+//!
+//! let config = QuoteSanityConfig::default();
+//! assert_eq!(
+//!     config.max_deviation_for(LiquidityClassification::Liquid),
+//!     Decimal::new(20, 2),
+//! );
+//! ```
+
+use crate::application::services::price_bounds::{ReferencePriceProvider, compute_deviation};
+use crate::domain::entities::quote::Quote;
+use crate::domain::value_objects::instrument::Instrument;
+use crate::domain::value_objects::liquidity_classification::LiquidityClassification;
+use crate::domain::value_objects::price::Price;
+use crate::domain::value_objects::{PriceSanityBasis, QuoteId, VenueId};
+use rust_decimal::Decimal;
+use std::fmt;
+use std::sync::Arc;
+
+/// Fewest quotes on a side for the median rule to apply.
+///
+/// With two quotes the median sits halfway between them, so neither could
+/// be told apart from the other as the outlier.
+pub const MIN_QUOTES_FOR_MEDIAN: usize = 3;
+
+/// Maximum deviations of a received quote from the market.
+///
+/// All deviations are fractional, e.g. 0.20 = ±20%.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuoteSanityConfig {
+    /// Maximum deviation from the reference price for liquid instruments.
+    pub liquid_max_deviation: Decimal,
+    /// Maximum deviation from the reference price for semi-liquid
+    /// instruments.
+    pub semi_liquid_max_deviation: Decimal,
+    /// Maximum deviation from the reference price for illiquid instruments.
+    pub illiquid_max_deviation: Decimal,
+    /// Maximum deviation from the median quote when there is no reference
+    /// price.
+    pub max_median_deviation: Decimal,
+}
+
+impl Default for QuoteSanityConfig {
+    /// Returns ±20% liquid, ±30% semi-liquid, ±50% illiquid and ±25% from
+    /// the median.
+    fn default() -> Self {
+        Self {
+            liquid_max_deviation: Decimal::new(20, 2),
+            semi_liquid_max_deviation: Decimal::new(30, 2),
+            illiquid_max_deviation: Decimal::new(50, 2),
+            max_median_deviation: Decimal::new(25, 2),
+        }
+    }
+}
+
+impl QuoteSanityConfig {
+    /// Returns the maximum deviation from the reference price for `liquidity`.
+    #[must_use]
+    pub fn max_deviation_for(&self, liquidity: LiquidityClassification) -> Decimal {
+        match liquidity {
+            LiquidityClassification::Liquid => self.liquid_max_deviation,
+            LiquidityClassification::SemiLiquid => self.semi_liquid_max_deviation,
+            LiquidityClassification::Illiquid => self.illiquid_max_deviation,
+        }
+    }
+}
+
+/// A quote rejected for being priced too far from the market.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuoteSanityRejection {
+    /// The rejected quote.
+    pub quote_id: QuoteId,
+    /// The venue that sent it.
+    pub venue_id: VenueId,
+    /// The quote's price.
+    pub price: Price,
+    /// The price it was compared against.
+    pub benchmark: Price,
+    /// What `benchmark` is.
+    pub basis: PriceSanityBasis,
+    /// Deviation of the price from the benchmark (fractional).
+    pub deviation_pct: Decimal,
+    /// The deviation that was allowed (fractional).
+    pub max_deviation_pct: Decimal,
+}
+
+impl QuoteSanityRejection {
+    /// Returns the reason recorded for the rejection.
+    #[must_use]
+    pub fn reason(&self) -> String {
+        format!(
+            "quote price {} deviates {}% from the {} {} (max {}%)",
+            self.price,
+            as_percent(self.deviation_pct),
+            self.basis,
+            self.benchmark,
+            as_percent(self.max_deviation_pct),
+        )
+    }
+}
+
+/// Screens received quotes against the reference price or, failing that,
+/// the median of the quotes received.
+pub struct QuoteSanityFilter {
+    config: QuoteSanityConfig,
+    reference_provider: Arc<dyn ReferencePriceProvider>,
+}
+
+impl QuoteSanityFilter {
+    /// Creates a filter with the limits in `config`, taking reference
+    /// prices from `reference_provider`.
+    #[must_use]
+    pub fn new(
+        config: QuoteSanityConfig,
+        reference_provider: Arc<dyn ReferencePriceProvider>,
+    ) -> Self {
+        Self {
+            config,
+            reference_provider,
+        }
+    }
+
+    /// Returns the limits quotes are checked against.
+    #[must_use]
+    pub fn config(&self) -> &QuoteSanityConfig {
+        &self.config
+    }
+
+    /// Returns the reference price of `instrument`, if one is available.
+    ///
+    /// A provider failure is logged and treated as no reference price, so
+    /// quotes fall back to the median rule rather than being lost.
+    pub async fn reference_for(&self, instrument: &Instrument) -> Option<Price> {
+        match self.reference_provider.get_reference(instrument).await {
+            Ok(reference) => reference
+                .map(|(price, _)| price)
+                .filter(|price| !price.is_zero()),
+            Err(e) => {
+                tracing::warn!(
+                    symbol = %instrument.symbol(),
+                    error = %e,
+                    "reference price unavailable for quote sanity check"
+                );
+                None
+            }
+        }
+    }
+
+    /// Checks `quote` against `reference` with the limit for `liquidity`.
+    ///
+    /// Returns the rejection if the quote deviates too far.
+    #[must_use]
+    pub fn check_reference(
+        &self,
+        quote: &Quote,
+        reference: Price,
+        liquidity: LiquidityClassification,
+    ) -> Option<QuoteSanityRejection> {
+        reject_beyond(
+            quote,
+            reference,
+            PriceSanityBasis::ReferencePrice,
+            self.config.max_deviation_for(liquidity),
+        )
+    }
+
+    /// Returns the quotes among `quotes` that deviate too far from their
+    /// median price.
+    ///
+    /// `quotes` should all be on the same side. Fewer than
+    /// [`MIN_QUOTES_FOR_MEDIAN`] quotes are never rejected.
+    #[must_use]
+    pub fn median_outliers<'a>(
+        &self,
+        quotes: impl IntoIterator<Item = &'a Quote>,
+    ) -> Vec<QuoteSanityRejection> {
+        let quotes: Vec<&Quote> = quotes.into_iter().collect();
+        let Some(median) = median_price(&quotes) else {
+            return Vec::new();
+        };
+        quotes
+            .into_iter()
+            .filter_map(|quote| {
+                reject_beyond(
+                    quote,
+                    median,
+                    PriceSanityBasis::QuoteMedian,
+                    self.config.max_median_deviation,
+                )
+            })
+            .collect()
+    }
+}
+
+impl fmt::Debug for QuoteSanityFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QuoteSanityFilter")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns the rejection of `quote` if its price deviates from `benchmark`
+/// by more than `max_deviation`.
+fn reject_beyond(
+    quote: &Quote,
+    benchmark: Price,
+    basis: PriceSanityBasis,
+    max_deviation: Decimal,
+) -> Option<QuoteSanityRejection> {
+    // A deviation too large to compute is as implausible as they come
+    let deviation = compute_deviation(&quote.price(), &benchmark).unwrap_or(Decimal::MAX);
+    (deviation > max_deviation).then(|| QuoteSanityRejection {
+        quote_id: quote.id(),
+        venue_id: quote.venue_id().clone(),
+        price: quote.price(),
+        benchmark,
+        basis,
+        deviation_pct: deviation,
+        max_deviation_pct: max_deviation,
+    })
+}
+
+/// Returns the median price of `quotes`, or `None` if there are too few
+/// quotes for it to be meaningful.
+fn median_price(quotes: &[&Quote]) -> Option<Price> {
+    if quotes.len() < MIN_QUOTES_FOR_MEDIAN {
+        return None;
+    }
+    let mut prices: Vec<Decimal> = quotes.iter().map(|quote| quote.price().get()).collect();
+    prices.sort();
+    let mid = prices.len() / 2;
+    let median = if prices.len().is_multiple_of(2) {
+        let lower = prices.get(mid.checked_sub(1)?)?;
+        let upper = prices.get(mid)?;
+        lower.checked_add(*upper)?.checked_div(Decimal::TWO)?
+    } else {
+        *prices.get(mid)?
+    };
+    Price::from_decimal(median)
+        .ok()
+        .filter(|price| !price.is_zero())
+}
+
+/// Formats a fractional deviation as a percentage with two decimals.
+fn as_percent(fraction: Decimal) -> Decimal {
+    fraction
+        .checked_mul(Decimal::ONE_HUNDRED)
+        .unwrap_or(Decimal::MAX)
+        .round_dp(2)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::DomainResult;
+    use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
+    use crate::domain::value_objects::reference_price::ReferencePriceSource;
+    use crate::domain::value_objects::symbol::Symbol;
+    use crate::domain::value_objects::timestamp::Timestamp;
+    use crate::domain::value_objects::{Quantity, RfqId};
+    use async_trait::async_trait;
+
+    struct FixedReference(Option<f64>);
+
+    #[async_trait]
+    impl ReferencePriceProvider for FixedReference {
+        async fn get_reference(
+            &self,
+            _instrument: &Instrument,
+        ) -> DomainResult<Option<(Price, ReferencePriceSource)>> {
+            Ok(self
+                .0
+                .map(|price| (Price::new(price).unwrap(), ReferencePriceSource::ClobMid)))
+        }
+    }
+
+    fn filter(reference: Option<f64>) -> QuoteSanityFilter {
+        QuoteSanityFilter::new(
+            QuoteSanityConfig::default(),
+            Arc::new(FixedReference(reference)),
+        )
+    }
+
+    fn quote(venue: &str, price: f64) -> Quote {
+        Quote::new(
+            RfqId::new_v4(),
+            VenueId::new(venue),
+            Price::new(price).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(60),
+        )
+        .unwrap()
+    }
+
+    fn instrument() -> Instrument {
+        Instrument::new(
+            Symbol::new("BTC/USD").unwrap(),
+            AssetClass::CryptoSpot,
+            SettlementMethod::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn rejects_quotes_beyond_the_tier_limit_from_the_reference() {
+        let filter = filter(Some(50_000.0));
+        let reference = filter.reference_for(&instrument()).await.unwrap();
+        let shifted = quote("broken", 50_000_000_000.0);
+
+        let rejection = filter
+            .check_reference(&shifted, reference, LiquidityClassification::Illiquid)
+            .unwrap();
+
+        assert_eq!(rejection.basis, PriceSanityBasis::ReferencePrice);
+        assert_eq!(rejection.venue_id, VenueId::new("broken"));
+        assert_eq!(rejection.max_deviation_pct, Decimal::new(50, 2));
+        assert_eq!(rejection.deviation_pct, Decimal::new(999_999, 0));
+        assert!(rejection.reason().contains("reference price 50000"));
+
+        // 25% away passes the illiquid limit but not the liquid one
+        let wide = quote("wide", 62_500.0);
+        assert!(
+            filter
+                .check_reference(&wide, reference, LiquidityClassification::Illiquid)
+                .is_none()
+        );
+        assert!(
+            filter
+                .check_reference(&wide, reference, LiquidityClassification::Liquid)
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn median_rule_rejects_only_the_outlier() {
+        let quotes = [
+            quote("a", 100.0),
+            quote("b", 101.0),
+            quote("c", 99.0),
+            quote("broken", 100_000_000.0),
+        ];
+
+        let rejections = filter(None).median_outliers(&quotes);
+
+        let [rejection] = rejections.as_slice() else {
+            unreachable!("expected one rejection, got {rejections:?}")
+        };
+        assert_eq!(rejection.venue_id, VenueId::new("broken"));
+        assert_eq!(rejection.basis, PriceSanityBasis::QuoteMedian);
+        assert_eq!(rejection.benchmark, Price::new(100.5).unwrap());
+    }
+
+    #[test]
+    fn median_rule_needs_enough_quotes() {
+        let quotes = [quote("a", 100.0), quote("broken", 100_000_000.0)];
+
+        assert!(filter(None).median_outliers(&quotes).is_empty());
+    }
+}
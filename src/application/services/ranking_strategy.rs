@@ -4,12 +4,34 @@
 //!
 //! This module provides the [`RankingStrategy`] trait and implementations
 //! for ranking quotes based on different criteria.
+//!
+//! Quotes are compared on their signed [`Quote::net_premium`], so a credit
+//! strategy quote (negative premium) beats any debit for a buyer and loses
+//! to it for a seller. Outright quotes rank on their price as before.
+//!
+//! [`BestPriceStrategy`] and [`WeightedMultiFactorStrategy`] order equally
+//! scored quotes with a [`TieBreakChain`], and record the deciding rule in
+//! [`RankedQuote::decided_by`].
+//!
+//! [`AllInCostStrategy`] ranks on [`RankedQuote::all_in_price`], which
+//! includes the settlement gas of DeFi quotes once an
+//! `AllInCostCalculator` has costed them.
+//!
+//! [`BestPriceStrategy`], [`AllInCostStrategy`], [`WeightedScoreStrategy`]
+//! and [`WeightedMultiFactorStrategy`] explain their scores in
+//! [`RankedQuote::score_breakdown`]: one [`ScoreComponent`] per factor,
+//! whose contributions add up to the score. A price weighs -1 where lower
+//! is better.
 
+use crate::application::services::tie_break::{TieBreak, TieBreakChain};
 use crate::domain::entities::quote::Quote;
 use crate::domain::entities::quote_normalizer::NormalizedQuote;
-use crate::domain::value_objects::OrderSide;
+use crate::domain::value_objects::score_breakdown::{ScoreComponent, ScoreFactor, explains};
+use crate::domain::value_objects::{OrderSide, QuoteId};
+use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
 
 /// A quote with its ranking information.
@@ -21,13 +43,48 @@ pub struct RankedQuote {
     pub rank: usize,
     /// The score used for ranking (higher = better).
     pub score: f64,
+    /// The tie-break rule that decided the rank, or `None` if the score did.
+    #[serde(default)]
+    pub decided_by: Option<TieBreak>,
+    /// The factors the score was made of; empty for strategies that do not
+    /// break their scores down.
+    #[serde(default)]
+    pub score_breakdown: Vec<ScoreComponent>,
 }
 
 impl RankedQuote {
     /// Creates a new ranked quote.
     #[must_use]
     pub fn new(quote: Quote, rank: usize, score: f64) -> Self {
-        Self { quote, rank, score }
+        Self {
+            quote,
+            rank,
+            score,
+            decided_by: None,
+            score_breakdown: Vec::new(),
+        }
+    }
+
+    /// Records the factors the score was made of.
+    ///
+    /// Their contributions must add up to the score; checked in debug
+    /// builds.
+    #[must_use]
+    pub fn with_score_breakdown(mut self, breakdown: Vec<ScoreComponent>) -> Self {
+        debug_assert!(
+            explains(self.score, &breakdown),
+            "score breakdown does not add up to score {}",
+            self.score
+        );
+        self.score_breakdown = breakdown;
+        self
+    }
+
+    /// Records the tie-break rule that decided the rank.
+    #[must_use]
+    pub fn with_decided_by(mut self, rule: TieBreak) -> Self {
+        self.decided_by = Some(rule);
+        self
     }
 
     /// Returns true if this quote is the best (rank 1).
@@ -35,6 +92,22 @@ impl RankedQuote {
     pub fn is_best(&self) -> bool {
         self.rank == 1
     }
+
+    /// Returns the price including settlement gas, or the raw net premium
+    /// if the quote was not costed.
+    #[must_use]
+    pub fn all_in_price(&self) -> Decimal {
+        self.quote.all_in_price()
+    }
+
+    /// Returns true if the quote needs gas but it could not be estimated,
+    /// so [`RankedQuote::all_in_price`] is the raw price.
+    #[must_use]
+    pub fn gas_estimate_missing(&self) -> bool {
+        self.quote
+            .all_in_cost()
+            .is_some_and(|cost| !cost.is_estimated())
+    }
 }
 
 impl fmt::Display for RankedQuote {
@@ -171,19 +244,74 @@ pub trait RankingStrategy: Send + Sync + fmt::Debug {
     fn name(&self) -> &'static str;
 }
 
+/// Weight of a price factor: -1 where lower is better, 1 where higher is.
+fn price_weight(side: OrderSide) -> f64 {
+    match side {
+        OrderSide::Buy => -1.0, // Lower price is better for buying
+        OrderSide::Sell => 1.0, // Higher price is better for selling
+    }
+}
+
+/// Scores the weighted `(factor, raw value, weight)` factors, returning the
+/// score and its breakdown.
+fn weighted_score(factors: &[(ScoreFactor, f64, f64)]) -> (f64, Vec<ScoreComponent>) {
+    let score = factors.iter().fold(0.0, |score, (_, raw_value, weight)| {
+        score + raw_value * weight
+    });
+    let breakdown = factors
+        .iter()
+        .map(|&(factor, raw_value, weight)| ScoreComponent::new(factor, raw_value, weight))
+        .collect();
+    (score, breakdown)
+}
+
+/// Ranks scored quotes with `tie_breaks`, attaching their breakdowns.
+fn rank_explained(
+    tie_breaks: &TieBreakChain,
+    scored: Vec<(&Quote, f64, Vec<ScoreComponent>)>,
+) -> Vec<RankedQuote> {
+    let mut breakdowns: HashMap<QuoteId, Vec<ScoreComponent>> = HashMap::new();
+    let scored = scored
+        .into_iter()
+        .map(|(quote, score, breakdown)| {
+            breakdowns.insert(quote.id(), breakdown);
+            (quote, score)
+        })
+        .collect();
+    tie_breaks
+        .rank(scored)
+        .into_iter()
+        .map(|ranked| {
+            let breakdown = breakdowns.remove(&ranked.quote.id()).unwrap_or_default();
+            ranked.with_score_breakdown(breakdown)
+        })
+        .collect()
+}
+
 /// Best price ranking strategy.
 ///
 /// Ranks quotes by price:
 /// - For Buy orders: lower price is better
 /// - For Sell orders: higher price is better
+///
+/// Quotes at the same price are ordered by the tie-break chain.
 #[derive(Debug, Clone, Default)]
-pub struct BestPriceStrategy;
+pub struct BestPriceStrategy {
+    tie_breaks: TieBreakChain,
+}
 
 impl BestPriceStrategy {
     /// Creates a new best price strategy.
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Sets the tie-break chain for quotes at the same price.
+    #[must_use]
+    pub fn with_tie_breaks(mut self, tie_breaks: TieBreakChain) -> Self {
+        self.tie_breaks = tie_breaks;
+        self
     }
 }
 
@@ -194,32 +322,17 @@ impl RankingStrategy for BestPriceStrategy {
         }
 
         // Score quotes based on price
-        let mut scored: Vec<(usize, f64)> = quotes
+        let scored = quotes
             .iter()
-            .enumerate()
-            .map(|(i, q)| {
-                let price = q.price().get().to_f64().unwrap_or(0.0);
-                let score = match side {
-                    OrderSide::Buy => -price, // Lower price is better for buying
-                    OrderSide::Sell => price, // Higher price is better for selling
-                };
-                (i, score)
+            .map(|q| {
+                let price = q.net_premium().get().to_f64().unwrap_or(0.0);
+                let (score, breakdown) =
+                    weighted_score(&[(ScoreFactor::Price, price, price_weight(side))]);
+                (q, score, breakdown)
             })
             .collect();
 
-        // Sort by score (descending - higher score is better)
-        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
-
-        // Create ranked quotes
-        scored
-            .into_iter()
-            .enumerate()
-            .filter_map(|(rank, (idx, score))| {
-                quotes
-                    .get(idx)
-                    .map(|q| RankedQuote::new(q.clone(), rank + 1, score))
-            })
-            .collect()
+        rank_explained(&self.tie_breaks, scored)
     }
 
     fn score_normalized(&self, quote: &NormalizedQuote, side: OrderSide) -> f64 {
@@ -235,6 +348,63 @@ impl RankingStrategy for BestPriceStrategy {
     }
 }
 
+/// All-in cost ranking strategy.
+///
+/// Ranks quotes like [`BestPriceStrategy`], but on the price including
+/// settlement gas, so a DeFi quote on an expensive chain can lose to a
+/// slightly worse quote on a cheap one. Quotes without a computed cost rank
+/// on their raw price.
+///
+/// Normalized quotes do not carry the gas cost and rank on their
+/// fee-inclusive price.
+#[derive(Debug, Clone, Default)]
+pub struct AllInCostStrategy {
+    tie_breaks: TieBreakChain,
+}
+
+impl AllInCostStrategy {
+    /// Creates a new all-in cost strategy.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the tie-break chain for quotes at the same all-in price.
+    #[must_use]
+    pub fn with_tie_breaks(mut self, tie_breaks: TieBreakChain) -> Self {
+        self.tie_breaks = tie_breaks;
+        self
+    }
+}
+
+impl RankingStrategy for AllInCostStrategy {
+    fn rank(&self, quotes: &[Quote], side: OrderSide) -> Vec<RankedQuote> {
+        if quotes.is_empty() {
+            return Vec::new();
+        }
+
+        let scored = quotes
+            .iter()
+            .map(|q| {
+                let price = q.all_in_price().to_f64().unwrap_or(0.0);
+                let (score, breakdown) =
+                    weighted_score(&[(ScoreFactor::AllInPrice, price, price_weight(side))]);
+                (q, score, breakdown)
+            })
+            .collect();
+
+        rank_explained(&self.tie_breaks, scored)
+    }
+
+    fn score_normalized(&self, quote: &NormalizedQuote, side: OrderSide) -> f64 {
+        BestPriceStrategy::new().score_normalized(quote, side)
+    }
+
+    fn name(&self) -> &'static str {
+        "AllInCost"
+    }
+}
+
 /// Weighted score ranking strategy.
 ///
 /// Ranks quotes using a weighted combination of factors:
@@ -278,7 +448,7 @@ impl RankingStrategy for WeightedScoreStrategy {
         // Find min/max for normalization
         let prices: Vec<f64> = quotes
             .iter()
-            .map(|q| q.price().get().to_f64().unwrap_or(0.0))
+            .map(|q| q.net_premium().get().to_f64().unwrap_or(0.0))
             .collect();
         let quantities: Vec<f64> = quotes
             .iter()
@@ -294,11 +464,11 @@ impl RankingStrategy for WeightedScoreStrategy {
         let qty_range = (max_qty - min_qty).max(1.0);
 
         // Score quotes
-        let mut scored: Vec<(usize, f64)> = quotes
+        let mut scored: Vec<(usize, f64, Vec<ScoreComponent>)> = quotes
             .iter()
             .enumerate()
             .map(|(i, q)| {
-                let price = q.price().get().to_f64().unwrap_or(0.0);
+                let price = q.net_premium().get().to_f64().unwrap_or(0.0);
                 let qty = q.quantity().get().to_f64().unwrap_or(0.0);
 
                 // Normalize price (0-1, where 1 is best)
@@ -310,8 +480,11 @@ impl RankingStrategy for WeightedScoreStrategy {
                 // Normalize quantity (0-1, where 1 is best)
                 let qty_score = (qty - min_qty) / qty_range;
 
-                let score = self.price_weight * price_score + self.quantity_weight * qty_score;
-                (i, score)
+                let (score, breakdown) = weighted_score(&[
+                    (ScoreFactor::Price, price_score, self.price_weight),
+                    (ScoreFactor::Size, qty_score, self.quantity_weight),
+                ]);
+                (i, score, breakdown)
             })
             .collect();
 
@@ -322,10 +495,10 @@ impl RankingStrategy for WeightedScoreStrategy {
         scored
             .into_iter()
             .enumerate()
-            .filter_map(|(rank, (idx, score))| {
-                quotes
-                    .get(idx)
-                    .map(|q| RankedQuote::new(q.clone(), rank + 1, score))
+            .filter_map(|(rank, (idx, score, breakdown))| {
+                quotes.get(idx).map(|q| {
+                    RankedQuote::new(q.clone(), rank + 1, score).with_score_breakdown(breakdown)
+                })
             })
             .collect()
     }
@@ -374,7 +547,7 @@ impl RankingStrategy for LowestSlippageStrategy {
 
         let prices: Vec<f64> = quotes
             .iter()
-            .map(|q| q.price().get().to_f64().unwrap_or(0.0))
+            .map(|q| q.net_premium().get().to_f64().unwrap_or(0.0))
             .collect();
 
         // Find best price (lowest for buy, highest for sell)
@@ -388,7 +561,7 @@ impl RankingStrategy for LowestSlippageStrategy {
             .iter()
             .enumerate()
             .map(|(i, q)| {
-                let price = q.price().get().to_f64().unwrap_or(0.0);
+                let price = q.net_premium().get().to_f64().unwrap_or(0.0);
                 let slippage = Self::calculate_slippage(price, best_price);
                 // Invert slippage so lower slippage = higher score
                 let score = 1.0 - slippage.min(1.0);
@@ -495,7 +668,7 @@ impl RankingStrategy for LowestCostStrategy {
         let costs: Vec<f64> = quotes
             .iter()
             .map(|q| {
-                let price = q.price().get().to_f64().unwrap_or(0.0);
+                let price = q.net_premium().get().to_f64().unwrap_or(0.0);
                 let quantity = q.quantity().get().to_f64().unwrap_or(0.0);
                 self.calculate_total_cost(price, quantity)
             })
@@ -616,6 +789,8 @@ pub struct WeightedMultiFactorStrategy {
     reliability_scores: std::collections::HashMap<String, f64>,
     /// Requested quantity for quantity score normalization.
     requested_quantity: Option<f64>,
+    /// Ordering of equally scored quotes.
+    tie_breaks: TieBreakChain,
 }
 
 impl WeightedMultiFactorStrategy {
@@ -626,6 +801,7 @@ impl WeightedMultiFactorStrategy {
             weights: RankingWeights::default(),
             reliability_scores: std::collections::HashMap::new(),
             requested_quantity: None,
+            tie_breaks: TieBreakChain::default(),
         }
     }
 
@@ -636,6 +812,7 @@ impl WeightedMultiFactorStrategy {
             weights,
             reliability_scores: std::collections::HashMap::new(),
             requested_quantity: None,
+            tie_breaks: TieBreakChain::default(),
         }
     }
 
@@ -646,6 +823,13 @@ impl WeightedMultiFactorStrategy {
         self
     }
 
+    /// Sets the tie-break chain for equally scored quotes.
+    #[must_use]
+    pub fn with_tie_breaks(mut self, tie_breaks: TieBreakChain) -> Self {
+        self.tie_breaks = tie_breaks;
+        self
+    }
+
     /// Sets reliability scores for venues.
     ///
     /// Reliability score should be in [0.0, 1.0] range.
@@ -728,7 +912,7 @@ impl RankingStrategy for WeightedMultiFactorStrategy {
         // Extract prices and quantities
         let prices: Vec<f64> = quotes
             .iter()
-            .map(|q| q.price().get().to_f64().unwrap_or(0.0))
+            .map(|q| q.net_premium().get().to_f64().unwrap_or(0.0))
             .collect();
         let quantities: Vec<f64> = quotes
             .iter()
@@ -746,7 +930,7 @@ impl RankingStrategy for WeightedMultiFactorStrategy {
         let newest_ts = timestamps.iter().cloned().max().unwrap_or(0);
 
         // Score each quote
-        let mut scored: Vec<(usize, f64)> = quotes
+        let scored = quotes
             .iter()
             .enumerate()
             .filter_map(|(i, q)| {
@@ -759,28 +943,22 @@ impl RankingStrategy for WeightedMultiFactorStrategy {
                 let reliability = self.reliability_score(q.venue_id().as_str());
                 let freshness = self.freshness_score(*ts, oldest_ts, newest_ts);
 
-                let total = self.weights.price * price_score
-                    + self.weights.quantity * qty_score
-                    + self.weights.reliability * reliability
-                    + self.weights.freshness * freshness;
-
-                Some((i, total))
+                let (total, breakdown) = weighted_score(&[
+                    (ScoreFactor::Price, price_score, self.weights.price),
+                    (ScoreFactor::Size, qty_score, self.weights.quantity),
+                    (
+                        ScoreFactor::Reliability,
+                        reliability,
+                        self.weights.reliability,
+                    ),
+                    (ScoreFactor::Freshness, freshness, self.weights.freshness),
+                ]);
+
+                Some((q, total, breakdown))
             })
             .collect();
 
-        // Sort by score descending (higher is better)
-        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
-
-        // Create ranked quotes
-        scored
-            .into_iter()
-            .enumerate()
-            .filter_map(|(rank, (idx, score))| {
-                quotes
-                    .get(idx)
-                    .map(|q| RankedQuote::new(q.clone(), rank + 1, score))
-            })
-            .collect()
+        rank_explained(&self.tie_breaks, scored)
     }
 
     fn score_normalized(&self, quote: &NormalizedQuote, side: OrderSide) -> f64 {
@@ -959,7 +1137,8 @@ impl CompositeStrategyBuilder {
 mod tests {
     use super::*;
     use crate::domain::value_objects::timestamp::Timestamp;
-    use crate::domain::value_objects::{Price, Quantity, RfqId, VenueId};
+    use crate::domain::value_objects::{Premium, Price, Quantity, RfqId, VenueId};
+    use rust_decimal::Decimal;
 
     fn create_quote(price: f64, quantity: f64, venue: &str) -> Quote {
         Quote::new(
@@ -972,6 +1151,212 @@ mod tests {
         .unwrap()
     }
 
+    fn create_premium_quote(premium: Decimal, venue: &str) -> Quote {
+        Quote::new_net_premium(
+            RfqId::new_v4(),
+            VenueId::new(venue),
+            Premium::new(premium),
+            vec![],
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(60),
+        )
+        .unwrap()
+    }
+
+    /// Credit iron condor quotes: -1.35 is the largest credit.
+    fn iron_condor_quotes() -> Vec<Quote> {
+        vec![
+            create_premium_quote(Decimal::new(-110, 2), "venue-1"),
+            create_premium_quote(Decimal::new(-135, 2), "venue-2"),
+            create_premium_quote(Decimal::new(-95, 2), "venue-3"),
+        ]
+    }
+
+    fn venues(ranked: &[RankedQuote]) -> Vec<&str> {
+        ranked.iter().map(|r| r.quote.venue_id().as_str()).collect()
+    }
+
+    /// Three quotes whose prices and sizes each favour a different venue.
+    fn breakdown_fixture() -> Vec<Quote> {
+        vec![
+            create_quote(100.0, 1.0, "venue-1"),
+            create_quote(95.0, 2.0, "venue-2"),
+            create_quote(105.0, 3.0, "venue-3"),
+        ]
+    }
+
+    fn component(
+        factor: ScoreFactor,
+        raw_value: Decimal,
+        weight: Decimal,
+        contribution: Decimal,
+    ) -> ScoreComponent {
+        ScoreComponent {
+            factor,
+            raw_value,
+            weight,
+            contribution,
+        }
+    }
+
+    #[test]
+    fn score_breakdowns_add_up_to_scores() {
+        let strategies: Vec<Box<dyn RankingStrategy>> = vec![
+            Box::new(BestPriceStrategy::new()),
+            Box::new(AllInCostStrategy::new()),
+            Box::new(WeightedScoreStrategy::new(0.7, 0.3)),
+            Box::new(WeightedMultiFactorStrategy::new()),
+        ];
+
+        for strategy in &strategies {
+            for side in [OrderSide::Buy, OrderSide::Sell] {
+                for ranked in strategy.rank(&breakdown_fixture(), side) {
+                    assert!(!ranked.score_breakdown.is_empty(), "{}", strategy.name());
+                    assert!(
+                        explains(ranked.score, &ranked.score_breakdown),
+                        "{} {side}: {:?} does not add up to {}",
+                        strategy.name(),
+                        ranked.score_breakdown,
+                        ranked.score
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn best_price_breakdown_is_signed_price() {
+        let ranked = BestPriceStrategy::new().rank(&breakdown_fixture(), OrderSide::Buy);
+
+        assert_eq!(venues(&ranked), vec!["venue-2", "venue-1", "venue-3"]);
+        let breakdowns: Vec<_> = ranked.iter().map(|r| r.score_breakdown.clone()).collect();
+        assert_eq!(
+            breakdowns,
+            vec![
+                vec![component(
+                    ScoreFactor::Price,
+                    Decimal::from(95),
+                    Decimal::NEGATIVE_ONE,
+                    Decimal::from(-95)
+                )],
+                vec![component(
+                    ScoreFactor::Price,
+                    Decimal::from(100),
+                    Decimal::NEGATIVE_ONE,
+                    Decimal::from(-100)
+                )],
+                vec![component(
+                    ScoreFactor::Price,
+                    Decimal::from(105),
+                    Decimal::NEGATIVE_ONE,
+                    Decimal::from(-105)
+                )],
+            ]
+        );
+
+        let ranked = BestPriceStrategy::new().rank(&breakdown_fixture(), OrderSide::Sell);
+        assert_eq!(ranked[0].score_breakdown[0].weight, Decimal::ONE);
+        assert_eq!(
+            ranked[0].score_breakdown[0].contribution,
+            Decimal::from(105)
+        );
+    }
+
+    #[test]
+    fn weighted_score_breakdown_is_pinned() {
+        let ranked =
+            WeightedScoreStrategy::new(0.7, 0.3).rank(&breakdown_fixture(), OrderSide::Buy);
+
+        assert_eq!(venues(&ranked), vec!["venue-2", "venue-1", "venue-3"]);
+        let price_weight = Decimal::new(7, 1);
+        let size_weight = Decimal::new(3, 1);
+        let breakdowns: Vec<_> = ranked.iter().map(|r| r.score_breakdown.clone()).collect();
+        assert_eq!(
+            breakdowns,
+            vec![
+                vec![
+                    component(ScoreFactor::Price, Decimal::ONE, price_weight, price_weight),
+                    component(
+                        ScoreFactor::Size,
+                        Decimal::new(5, 1),
+                        size_weight,
+                        Decimal::new(15, 2)
+                    ),
+                ],
+                vec![
+                    component(
+                        ScoreFactor::Price,
+                        Decimal::new(5, 1),
+                        price_weight,
+                        Decimal::new(35, 2)
+                    ),
+                    component(ScoreFactor::Size, Decimal::ZERO, size_weight, Decimal::ZERO),
+                ],
+                vec![
+                    component(
+                        ScoreFactor::Price,
+                        Decimal::ZERO,
+                        price_weight,
+                        Decimal::ZERO
+                    ),
+                    component(ScoreFactor::Size, Decimal::ONE, size_weight, size_weight),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn weighted_multi_factor_breakdown_is_pinned() {
+        let ranked = WeightedMultiFactorStrategy::new().rank(&breakdown_fixture(), OrderSide::Buy);
+
+        assert_eq!(venues(&ranked), vec!["venue-2", "venue-1", "venue-3"]);
+        let best = &ranked[0].score_breakdown;
+        assert_eq!(
+            best.iter().map(|c| c.factor).collect::<Vec<_>>(),
+            vec![
+                ScoreFactor::Price,
+                ScoreFactor::Size,
+                ScoreFactor::Reliability,
+                ScoreFactor::Freshness
+            ]
+        );
+        // Freshness depends on when the fixture was created, so only its
+        // weight is pinned
+        assert_eq!(
+            best[..3],
+            [
+                component(
+                    ScoreFactor::Price,
+                    Decimal::ONE,
+                    Decimal::new(7, 1),
+                    Decimal::new(7, 1)
+                ),
+                component(
+                    ScoreFactor::Size,
+                    Decimal::ONE,
+                    Decimal::new(15, 2),
+                    Decimal::new(15, 2)
+                ),
+                component(
+                    ScoreFactor::Reliability,
+                    Decimal::new(5, 1),
+                    Decimal::new(1, 1),
+                    Decimal::new(5, 2)
+                ),
+            ]
+        );
+        assert_eq!(best[3].weight, Decimal::new(5, 2));
+        assert_eq!(ranked[1].score_breakdown[0].raw_value, Decimal::new(5, 1));
+        assert_eq!(ranked[2].score_breakdown[0].raw_value, Decimal::ZERO);
+    }
+
+    #[test]
+    fn strategies_without_breakdowns_leave_it_empty() {
+        let ranked = LowestSlippageStrategy::new().rank(&breakdown_fixture(), OrderSide::Buy);
+
+        assert!(ranked.iter().all(|r| r.score_breakdown.is_empty()));
+    }
+
     #[test]
     fn ranked_quote_new() {
         let quote = create_quote(100.0, 1.0, "venue-1");
@@ -1028,6 +1413,131 @@ mod tests {
         assert!((ranked[2].quote.price().get().to_f64().unwrap() - 95.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn best_price_credit_iron_condor_buy_prefers_largest_credit() {
+        let ranked = BestPriceStrategy::new().rank(&iron_condor_quotes(), OrderSide::Buy);
+
+        assert_eq!(venues(&ranked), vec!["venue-2", "venue-1", "venue-3"]);
+        assert_eq!(ranked[0].quote.net_premium().get(), Decimal::new(-135, 2));
+    }
+
+    #[test]
+    fn best_price_credit_iron_condor_sell_prefers_smallest_credit() {
+        let ranked = BestPriceStrategy::new().rank(&iron_condor_quotes(), OrderSide::Sell);
+
+        assert_eq!(venues(&ranked), vec!["venue-3", "venue-1", "venue-2"]);
+    }
+
+    #[test]
+    fn best_price_credit_beats_debit_for_buyer() {
+        let quotes = vec![
+            create_premium_quote(Decimal::new(50, 2), "debit"),
+            create_premium_quote(Decimal::new(-20, 2), "credit"),
+        ];
+
+        let ranked = BestPriceStrategy::new().rank(&quotes, OrderSide::Buy);
+
+        assert_eq!(venues(&ranked), vec!["credit", "debit"]);
+    }
+
+    #[test]
+    fn weighted_multi_factor_credit_iron_condor_buy() {
+        let ranked = WeightedMultiFactorStrategy::new().rank(&iron_condor_quotes(), OrderSide::Buy);
+
+        assert_eq!(ranked[0].quote.venue_id().as_str(), "venue-2");
+        assert_eq!(ranked[2].quote.venue_id().as_str(), "venue-3");
+    }
+
+    #[test]
+    fn outright_quotes_rank_on_price() {
+        let quotes = vec![
+            create_quote(100.0, 1.0, "venue-1"),
+            create_quote(95.0, 1.0, "venue-2"),
+        ];
+
+        let ranked = BestPriceStrategy::new().rank(&quotes, OrderSide::Buy);
+
+        assert_eq!(venues(&ranked), vec!["venue-2", "venue-1"]);
+        assert!(ranked.iter().all(|r| !r.quote.is_net_premium()));
+    }
+
+    #[test]
+    fn best_price_strategy_breaks_price_ties_by_quantity() {
+        let strategy = BestPriceStrategy::new();
+        let quotes = vec![
+            create_quote(100.0, 5.0, "venue-a"),
+            create_quote(99.0, 1.0, "venue-b"),
+            create_quote(100.0, 8.0, "venue-c"),
+        ];
+
+        let ranked = strategy.rank(&quotes, OrderSide::Buy);
+        assert_eq!(venues(&ranked), vec!["venue-b", "venue-c", "venue-a"]);
+        let decided_by: Vec<_> = ranked.iter().map(|r| r.decided_by).collect();
+        assert_eq!(decided_by, vec![None, None, Some(TieBreak::LargerQuantity)]);
+    }
+
+    #[test]
+    fn best_price_strategy_custom_tie_breaks() {
+        let strategy =
+            BestPriceStrategy::new().with_tie_breaks(TieBreakChain::new(vec![TieBreak::VenueId]));
+        let quotes = vec![
+            create_quote(100.0, 5.0, "venue-c"),
+            create_quote(100.0, 8.0, "venue-a"),
+            create_quote(100.0, 1.0, "venue-b"),
+        ];
+
+        let ranked = strategy.rank(&quotes, OrderSide::Sell);
+        assert_eq!(venues(&ranked), vec!["venue-a", "venue-b", "venue-c"]);
+        assert!(
+            ranked
+                .iter()
+                .all(|r| r.decided_by == Some(TieBreak::VenueId))
+        );
+    }
+
+    #[test]
+    fn weighted_multi_factor_custom_tie_breaks() {
+        let quotes = vec![
+            create_quote(100.0, 5.0, "venue-c"),
+            create_quote(100.0, 5.0, "venue-a"),
+        ];
+        // Same creation time so freshness does not separate the quotes
+        let created_at = quotes.first().unwrap().created_at();
+        let quotes: Vec<Quote> = quotes
+            .into_iter()
+            .map(|q| {
+                Quote::from_parts(
+                    q.id(),
+                    q.rfq_id(),
+                    q.venue_id().clone(),
+                    q.price(),
+                    q.quantity(),
+                    None,
+                    q.valid_until(),
+                    None,
+                    created_at,
+                    false,
+                    q.kind().clone(),
+                    q.firmness(),
+                )
+            })
+            .collect();
+        let strategy = WeightedMultiFactorStrategy::new()
+            .with_tie_breaks(TieBreakChain::new(vec![TieBreak::VenueId]));
+
+        let ranked = strategy.rank(&quotes, OrderSide::Buy);
+        assert_eq!(venues(&ranked), vec!["venue-a", "venue-c"]);
+        assert_eq!(ranked.first().unwrap().decided_by, Some(TieBreak::VenueId));
+    }
+
+    #[test]
+    fn ranked_quote_serializes_decided_by() {
+        let ranked = RankedQuote::new(create_quote(100.0, 1.0, "venue-a"), 1, 1.0)
+            .with_decided_by(TieBreak::EarlierReceived);
+        let json = serde_json::to_value(&ranked).unwrap();
+        assert_eq!(json.get("decided_by").unwrap(), "EARLIER_RECEIVED");
+    }
+
     #[test]
     fn best_price_strategy_empty() {
         let strategy = BestPriceStrategy::new();
@@ -0,0 +1,118 @@
+//! # Raw Exchange Purge
+//!
+//! Background enforcement of the retention policy on recorded venue
+//! exchanges.
+//!
+//! Each [`RawExchange`] carries the expiry it was recorded with. The
+//! [`RawExchangePurgeService`] periodically deletes every exchange whose
+//! expiry has passed, so the log never holds evidence longer than policy
+//! allows.
+//!
+//! [`RawExchange`]: crate::infrastructure::persistence::raw_exchange_log::RawExchange
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::domain::value_objects::timestamp::{Clock, SystemClock};
+use crate::infrastructure::persistence::raw_exchange_log::RawExchangeLog;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Default interval between purge sweeps.
+pub const DEFAULT_PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Deletes recorded venue exchanges whose retention has lapsed.
+///
+/// # Examples
+///
+/// ```ignore
+/// let service = RawExchangePurgeService::new(raw_exchange_log);
+///
+/// tokio::spawn(async move { service.run(DEFAULT_PURGE_INTERVAL).await });
+/// ```
+#[derive(Debug)]
+pub struct RawExchangePurgeService {
+    log: Arc<dyn RawExchangeLog>,
+    clock: Arc<dyn Clock>,
+}
+
+impl RawExchangePurgeService {
+    /// Creates a new service using the system clock.
+    #[must_use]
+    pub fn new(log: Arc<dyn RawExchangeLog>) -> Self {
+        Self {
+            log,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Sets the clock used to decide whether an exchange has expired.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Purges expired exchanges every `interval`, forever.
+    pub async fn run(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match self.run_once().await {
+                Ok(purged) => tracing::debug!(purged, "raw venue exchange purge completed"),
+                Err(e) => tracing::error!(error = %e, "raw venue exchange purge failed"),
+            }
+        }
+    }
+
+    /// Deletes every exchange that has expired and returns how many were
+    /// deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the log cannot be purged.
+    pub async fn run_once(&self) -> ApplicationResult<u64> {
+        self.log
+            .purge_expired(self.clock.now())
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::timestamp::{MockClock, Timestamp};
+    use crate::domain::value_objects::{RfqId, VenueId};
+    use crate::infrastructure::persistence::in_memory::InMemoryRawExchangeLog;
+    use crate::infrastructure::persistence::raw_exchange_log::{ExchangeDirection, RawExchange};
+
+    #[tokio::test]
+    async fn purges_exchanges_once_retention_lapses() {
+        let log = Arc::new(InMemoryRawExchangeLog::new());
+        let clock = Arc::new(MockClock::new(Timestamp::now()));
+        let service = RawExchangePurgeService::new(Arc::clone(&log) as Arc<dyn RawExchangeLog>)
+            .with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+
+        let recorded_at = clock.now();
+        log.record(&RawExchange {
+            venue_id: VenueId::new("airswap"),
+            rfq_id: RfqId::new_v4(),
+            direction: ExchangeDirection::Request,
+            payload: serde_json::json!({}),
+            http_status: None,
+            latency_ms: None,
+            recorded_at,
+            expires_at: recorded_at.add_secs(3600),
+        })
+        .await
+        .unwrap();
+
+        clock.advance_secs(3599);
+        assert_eq!(service.run_once().await.unwrap(), 0);
+        assert_eq!(log.len(), 1);
+
+        clock.advance_secs(1);
+        assert_eq!(service.run_once().await.unwrap(), 1);
+        assert!(log.is_empty());
+    }
+}
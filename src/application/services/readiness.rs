@@ -0,0 +1,447 @@
+//! # Readiness Checks
+//!
+//! Dependency checks behind the readiness probe.
+//!
+//! [`ReadinessChecker`] runs every registered [`DependencyCheck`] and pings
+//! each enabled venue adapter, each bounded by a timeout. A failing
+//! dependency fails the report. A failing venue only degrades it to
+//! [`CheckStatus::Warn`] while at least `min_healthy_venues` venues are up.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::application::services::{CheckStatus, ReadinessChecker};
+//!
+//! # tokio_test::block_on(async {
+//! let report = ReadinessChecker::new().check().await;
+//! assert_eq!(report.status, CheckStatus::Pass);
+//! assert!(report.is_ready());
+//! # });
+//! ```
+
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::infrastructure::persistence::event_store::EventStore;
+use crate::infrastructure::venues::VenueRegistry;
+use crate::infrastructure::venues::traits::VenueAdapter;
+use async_trait::async_trait;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Default timeout for a single dependency check.
+pub const DEFAULT_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Default number of venues that must be up for venue failures to only warn.
+pub const DEFAULT_MIN_HEALTHY_VENUES: usize = 1;
+
+/// Outcome of a check, ordered from best to worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    /// The dependency is up.
+    Pass,
+    /// The dependency is down but the service can still serve traffic.
+    Warn,
+    /// The dependency is down and the service cannot serve traffic.
+    Fail,
+}
+
+impl CheckStatus {
+    /// Returns the lowercase name used in health reports.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Warn => "warn",
+            Self::Fail => "fail",
+        }
+    }
+}
+
+/// Result of checking a single dependency.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyReport {
+    /// Check outcome.
+    pub status: CheckStatus,
+    /// Time the check took, in milliseconds.
+    pub latency_ms: u64,
+    /// Failure description, if the check did not pass.
+    pub error: Option<String>,
+}
+
+/// Result of a readiness check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadinessReport {
+    /// Worst status across all dependencies.
+    pub status: CheckStatus,
+    /// Per-dependency results keyed by dependency name.
+    pub dependencies: BTreeMap<String, DependencyReport>,
+}
+
+impl ReadinessReport {
+    /// Returns true unless a dependency failed.
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.status != CheckStatus::Fail
+    }
+}
+
+/// A critical dependency the service cannot serve traffic without.
+#[async_trait]
+pub trait DependencyCheck: Send + Sync + fmt::Debug {
+    /// Returns the name the dependency is reported under.
+    fn name(&self) -> &str;
+
+    /// Checks that the dependency is reachable.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the failure if the dependency is down.
+    async fn check(&self) -> Result<(), String>;
+}
+
+/// Checks that the event store answers queries.
+#[derive(Debug)]
+pub struct EventStoreCheck {
+    event_store: Arc<dyn EventStore>,
+}
+
+impl EventStoreCheck {
+    /// Creates a check against the given event store.
+    #[must_use]
+    pub fn new(event_store: Arc<dyn EventStore>) -> Self {
+        Self { event_store }
+    }
+}
+
+#[async_trait]
+impl DependencyCheck for EventStoreCheck {
+    fn name(&self) -> &str {
+        "event_store"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        self.event_store
+            .get_events_since(Timestamp::now())
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Runs bounded-timeout checks against the service's dependencies.
+#[derive(Debug)]
+pub struct ReadinessChecker {
+    dependencies: Vec<Arc<dyn DependencyCheck>>,
+    venues: Option<Arc<VenueRegistry>>,
+    min_healthy_venues: usize,
+    timeout: Duration,
+}
+
+impl ReadinessChecker {
+    /// Creates a checker with no dependencies.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            dependencies: Vec::new(),
+            venues: None,
+            min_healthy_venues: DEFAULT_MIN_HEALTHY_VENUES,
+            timeout: DEFAULT_CHECK_TIMEOUT,
+        }
+    }
+
+    /// Adds a critical dependency.
+    #[must_use]
+    pub fn with_dependency(mut self, check: Arc<dyn DependencyCheck>) -> Self {
+        self.dependencies.push(check);
+        self
+    }
+
+    /// Pings the enabled adapters of the given registry on every check.
+    #[must_use]
+    pub fn with_venues(mut self, registry: Arc<VenueRegistry>) -> Self {
+        self.venues = Some(registry);
+        self
+    }
+
+    /// Sets how many venues must be up for venue failures to only warn.
+    #[must_use]
+    pub fn with_min_healthy_venues(mut self, min_healthy_venues: usize) -> Self {
+        self.min_healthy_venues = min_healthy_venues;
+        self
+    }
+
+    /// Sets the timeout applied to each check.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Runs all checks concurrently and builds the report.
+    pub async fn check(&self) -> ReadinessReport {
+        let dependency_checks = self.dependencies.iter().map(|dependency| async move {
+            let report = self.timed(dependency.check()).await;
+            (dependency.name().to_string(), report)
+        });
+
+        let adapters = match &self.venues {
+            Some(registry) => registry.get_enabled().await,
+            None => Vec::new(),
+        };
+        let venue_checks = adapters.iter().map(|adapter| async move {
+            let report = self.timed(ping_venue(adapter.as_ref())).await;
+            (format!("venue:{}", adapter.venue_id()), report)
+        });
+
+        let (dependency_reports, venue_reports) = futures::join!(
+            futures::future::join_all(dependency_checks),
+            futures::future::join_all(venue_checks)
+        );
+
+        let healthy_venues = venue_reports
+            .iter()
+            .filter(|(_, report)| report.status == CheckStatus::Pass)
+            .count();
+        let venue_failure = if healthy_venues >= self.min_healthy_venues {
+            CheckStatus::Warn
+        } else {
+            CheckStatus::Fail
+        };
+
+        let mut dependencies = BTreeMap::new();
+        dependencies.extend(dependency_reports);
+        dependencies.extend(venue_reports.into_iter().map(|(name, mut report)| {
+            if report.status == CheckStatus::Fail {
+                report.status = venue_failure;
+            }
+            (name, report)
+        }));
+
+        let status = dependencies
+            .values()
+            .map(|report| report.status)
+            .max()
+            .unwrap_or(CheckStatus::Pass);
+        ReadinessReport {
+            status,
+            dependencies,
+        }
+    }
+
+    /// Runs a check under the timeout and records its latency.
+    async fn timed(&self, check: impl Future<Output = Result<(), String>>) -> DependencyReport {
+        let start = Instant::now();
+        let result = match tokio::time::timeout(self.timeout, check).await {
+            Ok(result) => result,
+            Err(_) => Err(format!("timed out after {}ms", self.timeout.as_millis())),
+        };
+        let latency_ms = start.elapsed().as_millis() as u64;
+
+        match result {
+            Ok(()) => DependencyReport {
+                status: CheckStatus::Pass,
+                latency_ms,
+                error: None,
+            },
+            Err(error) => DependencyReport {
+                status: CheckStatus::Fail,
+                latency_ms,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+impl Default for ReadinessChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Treats healthy and degraded venues as up.
+async fn ping_venue(adapter: &dyn VenueAdapter) -> Result<(), String> {
+    let health = adapter.health_check().await.map_err(|e| e.to_string())?;
+    if health.is_operational() {
+        Ok(())
+    } else {
+        Err(health
+            .message()
+            .map_or_else(|| health.status().to_string(), str::to_string))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::quote::Quote;
+    use crate::domain::entities::rfq::Rfq;
+    use crate::domain::value_objects::VenueId;
+    use crate::infrastructure::venues::error::{VenueError, VenueResult};
+    use crate::infrastructure::venues::traits::{ExecutionResult, VenueHealth};
+
+    #[derive(Debug)]
+    struct MockCheck {
+        name: &'static str,
+        result: Result<(), String>,
+        delay: Duration,
+    }
+
+    impl MockCheck {
+        fn passing(name: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                name,
+                result: Ok(()),
+                delay: Duration::ZERO,
+            })
+        }
+
+        fn failing(name: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                name,
+                result: Err("connection pool exhausted".to_string()),
+                delay: Duration::ZERO,
+            })
+        }
+
+        fn hanging(name: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                name,
+                result: Ok(()),
+                delay: Duration::from_secs(60),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl DependencyCheck for MockCheck {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn check(&self) -> Result<(), String> {
+            tokio::time::sleep(self.delay).await;
+            self.result.clone()
+        }
+    }
+
+    #[derive(Debug)]
+    struct MockVenue {
+        venue_id: VenueId,
+        healthy: bool,
+    }
+
+    #[async_trait]
+    impl VenueAdapter for MockVenue {
+        fn venue_id(&self) -> &VenueId {
+            &self.venue_id
+        }
+
+        fn timeout_ms(&self) -> u64 {
+            1000
+        }
+
+        async fn request_quote(&self, _rfq: &Rfq) -> VenueResult<Quote> {
+            Err(VenueError::internal_error("mock"))
+        }
+
+        async fn execute_trade(&self, _quote: &Quote) -> VenueResult<ExecutionResult> {
+            Err(VenueError::internal_error("mock"))
+        }
+
+        async fn health_check(&self) -> VenueResult<VenueHealth> {
+            if self.healthy {
+                Ok(VenueHealth::healthy(self.venue_id.clone()))
+            } else {
+                Ok(VenueHealth::unhealthy(self.venue_id.clone(), "maintenance"))
+            }
+        }
+    }
+
+    async fn registry(venues: &[(&str, bool)]) -> Arc<VenueRegistry> {
+        let registry = VenueRegistry::new();
+        for (id, healthy) in venues {
+            registry
+                .register(Arc::new(MockVenue {
+                    venue_id: VenueId::new(*id),
+                    healthy: *healthy,
+                }))
+                .await;
+        }
+        Arc::new(registry)
+    }
+
+    #[tokio::test]
+    async fn failing_dependency_fails_report() {
+        let report = ReadinessChecker::new()
+            .with_dependency(MockCheck::passing("event_store"))
+            .with_dependency(MockCheck::failing("postgres"))
+            .check()
+            .await;
+
+        assert_eq!(report.status, CheckStatus::Fail);
+        assert!(!report.is_ready());
+        assert_eq!(
+            report.dependencies.get("event_store").unwrap().status,
+            CheckStatus::Pass
+        );
+        let postgres = report.dependencies.get("postgres").unwrap();
+        assert_eq!(postgres.status, CheckStatus::Fail);
+        assert_eq!(postgres.error.as_deref(), Some("connection pool exhausted"));
+    }
+
+    #[tokio::test]
+    async fn hanging_dependency_times_out() {
+        let report = ReadinessChecker::new()
+            .with_timeout(Duration::from_millis(20))
+            .with_dependency(MockCheck::hanging("postgres"))
+            .check()
+            .await;
+
+        let postgres = report.dependencies.get("postgres").unwrap();
+        assert_eq!(postgres.status, CheckStatus::Fail);
+        assert_eq!(postgres.error.as_deref(), Some("timed out after 20ms"));
+    }
+
+    #[tokio::test]
+    async fn venue_failure_warns_when_enough_venues_are_up() {
+        let report = ReadinessChecker::new()
+            .with_venues(registry(&[("a", true), ("b", true), ("c", false)]).await)
+            .with_min_healthy_venues(2)
+            .check()
+            .await;
+
+        assert_eq!(report.status, CheckStatus::Warn);
+        assert!(report.is_ready());
+        assert_eq!(
+            report.dependencies.get("venue:a").unwrap().status,
+            CheckStatus::Pass
+        );
+        let c = report.dependencies.get("venue:c").unwrap();
+        assert_eq!(c.status, CheckStatus::Warn);
+        assert_eq!(c.error.as_deref(), Some("maintenance"));
+    }
+
+    #[tokio::test]
+    async fn venue_failure_fails_below_min_healthy_venues() {
+        let report = ReadinessChecker::new()
+            .with_venues(registry(&[("a", true), ("b", false), ("c", false)]).await)
+            .with_min_healthy_venues(2)
+            .check()
+            .await;
+
+        assert_eq!(report.status, CheckStatus::Fail);
+        assert_eq!(
+            report.dependencies.get("venue:a").unwrap().status,
+            CheckStatus::Pass
+        );
+        assert_eq!(
+            report.dependencies.get("venue:b").unwrap().status,
+            CheckStatus::Fail
+        );
+    }
+}
@@ -8,8 +8,12 @@
 //! # Features
 //!
 //! - Configurable retry parameters (max retries, delays, backoff multiplier)
-//! - Exponential backoff with optional jitter to prevent thundering herd
-//! - Support for distinguishing retryable vs non-retryable errors
+//! - Exponential backoff with proportional or full jitter to prevent
+//!   thundering herd
+//! - Per-error [`Retryability`]: retryable, non-retryable, or retryable after
+//!   a server-specified delay (e.g. a `Retry-After` header)
+//! - [`RetryBudget`]s that cap retries per time window, shared per venue
+//!   through [`RetryBudgets`], so an outage does not multiply load
 //!
 //! # Example
 //!
@@ -36,16 +40,51 @@
 //! # }
 //! ```
 
+use crate::domain::value_objects::VenueId;
+use parking_lot::{Mutex, MutexGuard};
 use rand::RngExt;
+use std::collections::HashMap;
 use std::fmt;
 use std::future::Future;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::time::sleep;
 
+/// Default number of retries a [`RetryBudget`] allows per window.
+pub const DEFAULT_RETRY_BUDGET_CAPACITY: u32 = 10;
+
+/// Default window over which a [`RetryBudget`] refills.
+pub const DEFAULT_RETRY_BUDGET_WINDOW: Duration = Duration::from_secs(10);
+
+/// How an error should be treated by the retry loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Retryability {
+    /// Transient; retry after the policy's backoff.
+    Retryable,
+    /// Permanent; never retry.
+    NonRetryable,
+    /// Transient; retry after the given delay instead of the policy's backoff.
+    RetryAfter(Duration),
+}
+
 /// Trait for errors that can indicate whether they are retryable.
 pub trait Retryable {
     /// Returns true if the error is transient and the operation should be retried.
     fn is_retryable(&self) -> bool;
+
+    /// Classifies the error for the retry loop.
+    ///
+    /// Defaults to [`Retryability::Retryable`] or
+    /// [`Retryability::NonRetryable`] according to
+    /// [`is_retryable`](Self::is_retryable). Override to request a specific
+    /// delay, e.g. from a `Retry-After` header.
+    fn retryability(&self) -> Retryability {
+        if self.is_retryable() {
+            Retryability::Retryable
+        } else {
+            Retryability::NonRetryable
+        }
+    }
 }
 
 /// Configuration for retry behavior.
@@ -127,6 +166,21 @@ impl RetryPolicy {
         }
     }
 
+    /// Uses full jitter: each delay is drawn uniformly from zero up to the
+    /// exponential backoff, which spreads out retries from many callers
+    /// that failed at the same moment.
+    #[must_use]
+    pub fn with_full_jitter(mut self) -> Self {
+        self.jitter_factor = 1.0;
+        self
+    }
+
+    /// Returns the maximum delay between attempts.
+    #[must_use]
+    pub fn max_delay(&self) -> Duration {
+        Duration::from_millis(self.max_delay_ms)
+    }
+
     /// Calculates the delay for a given attempt number (0-indexed).
     ///
     /// The delay is calculated as:
@@ -145,7 +199,8 @@ impl RetryPolicy {
 
     /// Calculates the delay with jitter applied.
     ///
-    /// Jitter is applied as: `delay * (1 - jitter_factor * random())`
+    /// Jitter is applied as: `delay * (1 - jitter_factor * random())`, so a
+    /// factor of 1.0 is full jitter.
     ///
     /// # Arguments
     ///
@@ -188,6 +243,13 @@ pub enum RetryError<E> {
         /// Number of attempts made before encountering non-retryable error.
         attempts: u32,
     },
+    /// The retry budget had no retries left.
+    BudgetExhausted {
+        /// The last error encountered.
+        last_error: E,
+        /// Total number of attempts made.
+        attempts: u32,
+    },
 }
 
 impl<E: fmt::Display> fmt::Display for RetryError<E> {
@@ -210,6 +272,16 @@ impl<E: fmt::Display> fmt::Display for RetryError<E> {
                     attempts, error
                 )
             }
+            Self::BudgetExhausted {
+                last_error,
+                attempts,
+            } => {
+                write!(
+                    f,
+                    "retry budget exhausted after {} attempts: {}",
+                    attempts, last_error
+                )
+            }
         }
     }
 }
@@ -221,7 +293,8 @@ impl<E> RetryError<E> {
     #[must_use]
     pub fn into_inner(self) -> E {
         match self {
-            Self::MaxRetriesExceeded { last_error, .. } => last_error,
+            Self::MaxRetriesExceeded { last_error, .. }
+            | Self::BudgetExhausted { last_error, .. } => last_error,
             Self::NonRetryable { error, .. } => error,
         }
     }
@@ -230,7 +303,8 @@ impl<E> RetryError<E> {
     #[must_use]
     pub fn inner(&self) -> &E {
         match self {
-            Self::MaxRetriesExceeded { last_error, .. } => last_error,
+            Self::MaxRetriesExceeded { last_error, .. }
+            | Self::BudgetExhausted { last_error, .. } => last_error,
             Self::NonRetryable { error, .. } => error,
         }
     }
@@ -239,9 +313,9 @@ impl<E> RetryError<E> {
     #[must_use]
     pub fn attempts(&self) -> u32 {
         match self {
-            Self::MaxRetriesExceeded { attempts, .. } | Self::NonRetryable { attempts, .. } => {
-                *attempts
-            }
+            Self::MaxRetriesExceeded { attempts, .. }
+            | Self::NonRetryable { attempts, .. }
+            | Self::BudgetExhausted { attempts, .. } => *attempts,
         }
     }
 
@@ -256,10 +330,150 @@ impl<E> RetryError<E> {
     pub fn is_non_retryable(&self) -> bool {
         matches!(self, Self::NonRetryable { .. })
     }
+
+    /// Returns true if retries stopped because the budget was exhausted.
+    #[must_use]
+    pub fn is_budget_exhausted(&self) -> bool {
+        matches!(self, Self::BudgetExhausted { .. })
+    }
+}
+
+/// Token bucket limiting how many retries may be made per time window.
+///
+/// Each retry takes one token; tokens refill continuously at `capacity`
+/// per `window`. Sharing one budget across every call to the same venue
+/// stops retries from amplifying load while the venue is down.
+#[derive(Debug)]
+pub struct RetryBudget {
+    capacity: u32,
+    window: Duration,
+    state: Mutex<BudgetState>,
+}
+
+#[derive(Debug)]
+struct BudgetState {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RetryBudget {
+    /// Creates a full budget allowing `capacity` retries per `window`.
+    #[must_use]
+    pub fn new(capacity: u32, window: Duration) -> Self {
+        Self {
+            capacity,
+            window,
+            state: Mutex::new(BudgetState {
+                tokens: f64::from(capacity),
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Takes a retry from the budget, returning false if none is left.
+    pub fn try_acquire(&self) -> bool {
+        let mut state = self.refilled();
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the number of whole retries currently available.
+    #[must_use]
+    pub fn available(&self) -> u32 {
+        self.refilled().tokens as u32
+    }
+
+    fn refilled(&self) -> MutexGuard<'_, BudgetState> {
+        let mut state = self.state.lock();
+        let capacity = f64::from(self.capacity);
+        let now = Instant::now();
+        let refill = if self.window.is_zero() {
+            capacity
+        } else {
+            now.duration_since(state.refilled_at).as_secs_f64() / self.window.as_secs_f64()
+                * capacity
+        };
+        state.tokens = (state.tokens + refill).min(capacity);
+        state.refilled_at = now;
+        state
+    }
+}
+
+impl Default for RetryBudget {
+    fn default() -> Self {
+        Self::new(DEFAULT_RETRY_BUDGET_CAPACITY, DEFAULT_RETRY_BUDGET_WINDOW)
+    }
+}
+
+/// Retry budgets shared per venue.
+///
+/// Every venue gets its own [`RetryBudget`] with the same capacity and
+/// window, created on first use.
+#[derive(Debug)]
+pub struct RetryBudgets {
+    capacity: u32,
+    window: Duration,
+    budgets: Mutex<HashMap<VenueId, Arc<RetryBudget>>>,
+}
+
+impl RetryBudgets {
+    /// Creates per-venue budgets allowing `capacity` retries per `window`.
+    #[must_use]
+    pub fn new(capacity: u32, window: Duration) -> Self {
+        Self {
+            capacity,
+            window,
+            budgets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the budget shared by all calls to `venue_id`.
+    #[must_use]
+    pub fn for_venue(&self, venue_id: &VenueId) -> Arc<RetryBudget> {
+        let mut budgets = self.budgets.lock();
+        Arc::clone(
+            budgets
+                .entry(venue_id.clone())
+                .or_insert_with(|| Arc::new(RetryBudget::new(self.capacity, self.window))),
+        )
+    }
+}
+
+impl Default for RetryBudgets {
+    fn default() -> Self {
+        Self::new(DEFAULT_RETRY_BUDGET_CAPACITY, DEFAULT_RETRY_BUDGET_WINDOW)
+    }
+}
+
+/// Parses an HTTP `Retry-After` header value.
+///
+/// Accepts delay-seconds (`"120"`) or an HTTP-date
+/// (`"Wed, 21 Oct 2015 07:28:00 GMT"`); a date in the past yields zero.
+/// Returns `None` if the value is neither.
+#[must_use]
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        at.signed_duration_since(chrono::Utc::now())
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
 }
 
 /// Executes an async operation with retry logic.
 ///
+/// Errors are classified by [`Retryable::retryability`]. A
+/// [`Retryability::RetryAfter`] delay replaces the policy's backoff, capped
+/// at the policy's maximum delay.
+///
 /// # Arguments
 ///
 /// * `policy` - The retry policy to use
@@ -307,6 +521,44 @@ impl<E> RetryError<E> {
 /// ```
 pub async fn execute_with_retry<F, Fut, T, E>(
     policy: &RetryPolicy,
+    operation: F,
+) -> Result<T, RetryError<E>>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: Retryable,
+{
+    retry_loop(policy, None, operation).await
+}
+
+/// Executes an async operation with retry logic, drawing each retry from
+/// `budget`.
+///
+/// Behaves like [`execute_with_retry`], but stops with
+/// [`RetryError::BudgetExhausted`] as soon as the budget has no retries
+/// left, without waiting.
+///
+/// # Errors
+///
+/// Returns `RetryError::MaxRetriesExceeded` if all retry attempts are exhausted.
+/// Returns `RetryError::NonRetryable` if a non-retryable error is encountered.
+/// Returns `RetryError::BudgetExhausted` if the budget has no retries left.
+pub async fn execute_with_retry_budget<F, Fut, T, E>(
+    policy: &RetryPolicy,
+    budget: &RetryBudget,
+    operation: F,
+) -> Result<T, RetryError<E>>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: Retryable,
+{
+    retry_loop(policy, Some(budget), operation).await
+}
+
+async fn retry_loop<F, Fut, T, E>(
+    policy: &RetryPolicy,
+    budget: Option<&RetryBudget>,
     mut operation: F,
 ) -> Result<T, RetryError<E>>
 where
@@ -322,9 +574,13 @@ where
         match operation().await {
             Ok(result) => return Ok(result),
             Err(error) => {
-                if !error.is_retryable() {
-                    return Err(RetryError::NonRetryable { error, attempts });
-                }
+                let retry_after = match error.retryability() {
+                    Retryability::NonRetryable => {
+                        return Err(RetryError::NonRetryable { error, attempts });
+                    }
+                    Retryability::Retryable => None,
+                    Retryability::RetryAfter(delay) => Some(delay),
+                };
 
                 if !policy.should_retry(attempts) {
                     return Err(RetryError::MaxRetriesExceeded {
@@ -333,8 +589,18 @@ where
                     });
                 }
 
+                if budget.is_some_and(|b| !b.try_acquire()) {
+                    return Err(RetryError::BudgetExhausted {
+                        last_error: error,
+                        attempts,
+                    });
+                }
+
                 // Wait before retrying (attempt - 1 because attempt is 1-indexed here)
-                let delay = policy.calculate_delay_with_jitter(attempts.saturating_sub(1));
+                let delay = match retry_after {
+                    Some(delay) => delay.min(policy.max_delay()),
+                    None => policy.calculate_delay_with_jitter(attempts.saturating_sub(1)),
+                };
                 sleep(delay).await;
             }
         }
@@ -698,6 +964,145 @@ mod tests {
         assert!(!err.is_retryable());
     }
 
+    #[test]
+    fn full_jitter_stays_within_backoff() {
+        let policy = RetryPolicy::new(3, 1000, 10_000, 2.0, 0.0).with_full_jitter();
+
+        for attempt in 0..4 {
+            let base = policy.calculate_delay(attempt);
+            for _ in 0..50 {
+                let delay = policy.calculate_delay_with_jitter(attempt);
+                assert!(delay <= base, "{delay:?} > {base:?}");
+                assert!(delay >= Duration::from_millis(1));
+            }
+        }
+    }
+
+    #[test]
+    fn retry_budget_refuses_once_empty() {
+        let budget = RetryBudget::new(2, Duration::from_secs(3600));
+        assert_eq!(budget.available(), 2);
+        assert!(budget.try_acquire());
+        assert!(budget.try_acquire());
+        assert!(!budget.try_acquire());
+        assert_eq!(budget.available(), 0);
+    }
+
+    #[test]
+    fn retry_budget_with_zero_window_never_empties() {
+        let budget = RetryBudget::new(1, Duration::ZERO);
+        assert!(budget.try_acquire());
+        assert!(budget.try_acquire());
+    }
+
+    #[test]
+    fn retry_budgets_are_shared_per_venue() {
+        let budgets = RetryBudgets::new(1, Duration::from_secs(3600));
+        let venue = VenueId::new("hashflow");
+
+        assert!(budgets.for_venue(&venue).try_acquire());
+        assert!(!budgets.for_venue(&venue).try_acquire());
+        assert!(budgets.for_venue(&VenueId::new("bebop")).try_acquire());
+    }
+
+    #[tokio::test]
+    async fn budget_exhaustion_short_circuits_retries() {
+        let policy = RetryPolicy::new(5, 1, 10, 2.0, 0.0);
+        let budget = RetryBudget::new(1, Duration::from_secs(3600));
+        let attempts = Arc::new(AtomicU32::new(0));
+        let attempts_clone = Arc::clone(&attempts);
+
+        let result: Result<&str, RetryError<TestError>> =
+            execute_with_retry_budget(&policy, &budget, || {
+                attempts_clone.fetch_add(1, Ordering::SeqCst);
+                async { Err(TestError::retryable("venue down")) }
+            })
+            .await;
+
+        let err = result.unwrap_err();
+        assert!(err.is_budget_exhausted());
+        assert_eq!(err.attempts(), 2);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert!(err.to_string().contains("retry budget exhausted"));
+    }
+
+    #[derive(Debug)]
+    struct ThrottledError(Duration);
+
+    impl Retryable for ThrottledError {
+        fn is_retryable(&self) -> bool {
+            true
+        }
+
+        fn retryability(&self) -> Retryability {
+            Retryability::RetryAfter(self.0)
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_after_replaces_backoff() {
+        let policy = RetryPolicy::new(2, 1, 1_000, 2.0, 0.0);
+        let attempts = Arc::new(AtomicU32::new(0));
+        let attempts_clone = Arc::clone(&attempts);
+
+        let started = Instant::now();
+        let result: Result<&str, RetryError<ThrottledError>> = execute_with_retry(&policy, || {
+            let current = attempts_clone.fetch_add(1, Ordering::SeqCst) + 1;
+            async move {
+                if current < 2 {
+                    Err(ThrottledError(Duration::from_millis(50)))
+                } else {
+                    Ok("success")
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), "success");
+        assert!(started.elapsed() >= Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn retry_after_is_capped_at_max_delay() {
+        let policy = RetryPolicy::new(2, 1, 20, 2.0, 0.0);
+        let started = Instant::now();
+
+        let result: Result<(), RetryError<ThrottledError>> =
+            execute_with_retry(&policy, || async {
+                Err(ThrottledError(Duration::from_secs(3600)))
+            })
+            .await;
+
+        assert!(result.unwrap_err().is_max_retries_exceeded());
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn default_retryability_follows_is_retryable() {
+        assert_eq!(
+            TestError::retryable("x").retryability(),
+            Retryability::Retryable
+        );
+        assert_eq!(
+            TestError::non_retryable("x").retryability(),
+            Retryability::NonRetryable
+        );
+    }
+
+    #[test]
+    fn parses_retry_after_seconds_and_dates() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_retry_after(" 0 "), Some(Duration::ZERO));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        let future = (chrono::Utc::now() + chrono::Duration::seconds(90)).to_rfc2822();
+        let delay = parse_retry_after(&future).unwrap();
+        assert!(delay > Duration::from_secs(80) && delay <= Duration::from_secs(90));
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+
     #[test]
     fn retry_policy_new_clamps_jitter() {
         let policy = RetryPolicy::new(3, 100, 1000, 2.0, 1.5);
@@ -0,0 +1,703 @@
+//! # RFQ Broadcast
+//!
+//! Notifies market makers of new RFQs so they can push quotes back through
+//! `SubmitQuote`.
+//!
+//! When quote collection starts, [`RfqBroadcastService`] notifies each
+//! selected venue through the channel configured in its
+//! [`VenueConfig`](crate::domain::entities::venue::VenueConfig) settings:
+//!
+//! | Setting | Channel |
+//! |---------|---------|
+//! | [`WEBHOOK_URL_SETTING`] and [`WEBHOOK_SECRET_SETTING`] | Signed webhook POST, retried on transient failures |
+//! | [`CHANNEL_SETTING`] = `"stream"` | gRPC `SubscribeRfqs` streams held open by the market maker |
+//!
+//! Venues with neither are quoted through their adapters and skipped.
+//! Delivery outcomes are recorded on the venue's
+//! [`VenueMetrics`](crate::domain::entities::venue::VenueMetrics).
+//!
+//! Anonymous RFQs are broadcast with the RFQ's pseudonymous requester
+//! handle instead of the client ID, and RFQs that mask their size are
+//! broadcast with the disclosed quantity only.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::application::services::retry::{RetryBudgets, RetryPolicy, execute_with_retry_budget};
+use crate::application::use_cases::create_rfq::RfqRepository;
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::entities::venue::{Venue, VenueConfig};
+use crate::domain::events::rfq_events::QuoteCollectionStarted;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{Instrument, OrderSide, Quantity, RfqId, VenueId};
+use crate::infrastructure::notifications::RfqWebhookClient;
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tracing::instrument;
+
+/// Venue setting holding the webhook URL.
+pub const WEBHOOK_URL_SETTING: &str = "notification_webhook_url";
+
+/// Venue setting holding the webhook's HMAC secret.
+pub const WEBHOOK_SECRET_SETTING: &str = "notification_webhook_secret";
+
+/// Venue setting selecting the notification channel.
+pub const CHANNEL_SETTING: &str = "notification_channel";
+
+/// Buffered broadcasts per subscriber before new ones are dropped.
+pub const SUBSCRIBER_BUFFER: usize = 64;
+
+/// RFQ details sent to market makers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RfqBroadcast {
+    /// The RFQ to quote.
+    pub rfq_id: RfqId,
+    /// The instrument being traded.
+    pub instrument: Instrument,
+    /// The requester's side.
+    pub side: OrderSide,
+    /// Quantity the RFQ discloses; absent when the size is hidden.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<Quantity>,
+    /// When the RFQ stops accepting quotes.
+    pub expires_at: Timestamp,
+    /// The client ID, or the pseudonymous handle for anonymous RFQs.
+    pub requester: String,
+    /// Whether the requester is pseudonymous.
+    pub anonymous: bool,
+}
+
+impl RfqBroadcast {
+    /// Builds the broadcast for an RFQ.
+    #[must_use]
+    pub fn from_rfq(rfq: &Rfq) -> Self {
+        let view = rfq.to_anonymous_view();
+        let requester = if rfq.is_anonymous() {
+            view.requester_handle()
+        } else {
+            rfq.client_id().to_string()
+        };
+        Self {
+            rfq_id: view.rfq_id(),
+            instrument: view.instrument().clone(),
+            side: view.side(),
+            quantity: rfq.disclosed_quantity(),
+            expires_at: view.expires_at(),
+            requester,
+            anonymous: rfq.is_anonymous(),
+        }
+    }
+}
+
+/// How a venue is notified of new RFQs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotificationChannel {
+    /// Signed HTTP POST.
+    Webhook {
+        /// Webhook URL.
+        url: String,
+        /// HMAC secret shared with the market maker.
+        secret: String,
+    },
+    /// gRPC `SubscribeRfqs` streams.
+    Stream,
+}
+
+impl NotificationChannel {
+    /// Reads the channel from venue settings, if one is configured.
+    #[must_use]
+    pub fn from_config(config: &VenueConfig) -> Option<Self> {
+        if let Some(url) = config.get(WEBHOOK_URL_SETTING) {
+            let secret = config.get(WEBHOOK_SECRET_SETTING)?;
+            return Some(Self::Webhook {
+                url: url.clone(),
+                secret: secret.clone(),
+            });
+        }
+        match config.get(CHANNEL_SETTING).map(String::as_str) {
+            Some("stream") => Some(Self::Stream),
+            _ => None,
+        }
+    }
+}
+
+/// Open `SubscribeRfqs` streams, keyed by market maker venue.
+#[derive(Debug, Default)]
+pub struct RfqSubscriptionHub {
+    subscribers: Mutex<HashMap<VenueId, Vec<mpsc::Sender<RfqBroadcast>>>>,
+}
+
+impl RfqSubscriptionHub {
+    /// Creates an empty hub.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens a subscription for a venue.
+    ///
+    /// The subscription ends when the receiver is dropped.
+    pub fn subscribe(&self, venue_id: VenueId) -> mpsc::Receiver<RfqBroadcast> {
+        let (tx, rx) = mpsc::channel(SUBSCRIBER_BUFFER);
+        self.subscribers
+            .lock()
+            .entry(venue_id)
+            .or_default()
+            .push(tx);
+        rx
+    }
+
+    /// Sends a broadcast to every open subscription of a venue.
+    ///
+    /// Closed subscriptions are removed. Returns the number of
+    /// subscriptions the broadcast was queued on.
+    pub fn publish(&self, venue_id: &VenueId, broadcast: &RfqBroadcast) -> usize {
+        let mut subscribers = self.subscribers.lock();
+        let Some(senders) = subscribers.get_mut(venue_id) else {
+            return 0;
+        };
+
+        let mut delivered = 0;
+        senders.retain(|tx| match tx.try_send(broadcast.clone()) {
+            Ok(()) => {
+                delivered += 1;
+                true
+            }
+            Err(mpsc::error::TrySendError::Full(_)) => {
+                tracing::warn!(venue_id = %venue_id, "RFQ subscriber lagging, dropping broadcast");
+                true
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => false,
+        });
+        if senders.is_empty() {
+            subscribers.remove(venue_id);
+        }
+        delivered
+    }
+
+    /// Returns the number of open subscriptions for a venue.
+    #[must_use]
+    pub fn subscriber_count(&self, venue_id: &VenueId) -> usize {
+        self.subscribers.lock().get(venue_id).map_or(0, |senders| {
+            senders.iter().filter(|tx| !tx.is_closed()).count()
+        })
+    }
+}
+
+/// Venue storage used to read notification settings and record outcomes.
+#[async_trait]
+pub trait BroadcastVenueRepository: Send + Sync + fmt::Debug {
+    /// Finds a venue by ID.
+    async fn find_by_id(&self, id: &VenueId) -> Result<Option<Venue>, String>;
+
+    /// Saves a venue.
+    async fn save(&self, venue: &Venue) -> Result<(), String>;
+}
+
+/// Outcome of broadcasting an RFQ.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BroadcastReport {
+    /// Venues that received the RFQ.
+    pub delivered: Vec<VenueId>,
+    /// Venues that could not be notified.
+    pub failed: Vec<VenueId>,
+    /// Venues without a notification channel.
+    pub skipped: Vec<VenueId>,
+}
+
+/// Notifies market maker venues of RFQs open for quoting.
+#[derive(Debug)]
+pub struct RfqBroadcastService {
+    rfq_repository: Arc<dyn RfqRepository>,
+    venue_repository: Arc<dyn BroadcastVenueRepository>,
+    webhook_client: RfqWebhookClient,
+    subscriptions: Arc<RfqSubscriptionHub>,
+    retry_policy: RetryPolicy,
+    retry_budgets: Arc<RetryBudgets>,
+}
+
+impl RfqBroadcastService {
+    /// Creates a new broadcast service with the default retry policy (with
+    /// full jitter) and default per-venue retry budgets.
+    #[must_use]
+    pub fn new(
+        rfq_repository: Arc<dyn RfqRepository>,
+        venue_repository: Arc<dyn BroadcastVenueRepository>,
+        webhook_client: RfqWebhookClient,
+        subscriptions: Arc<RfqSubscriptionHub>,
+    ) -> Self {
+        Self {
+            rfq_repository,
+            venue_repository,
+            webhook_client,
+            subscriptions,
+            retry_policy: RetryPolicy::default().with_full_jitter(),
+            retry_budgets: Arc::new(RetryBudgets::default()),
+        }
+    }
+
+    /// Sets the retry policy for webhook delivery.
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the per-venue retry budgets, e.g. to share them with quote
+    /// aggregation.
+    #[must_use]
+    pub fn with_retry_budgets(mut self, retry_budgets: Arc<RetryBudgets>) -> Self {
+        self.retry_budgets = retry_budgets;
+        self
+    }
+
+    /// Returns the subscription hub served by `SubscribeRfqs`.
+    #[must_use]
+    pub fn subscriptions(&self) -> &Arc<RfqSubscriptionHub> {
+        &self.subscriptions
+    }
+
+    /// Broadcasts the RFQ whose quote collection started.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the RFQ cannot be loaded.
+    pub async fn on_quote_collection_started(
+        &self,
+        event: &QuoteCollectionStarted,
+    ) -> ApplicationResult<BroadcastReport> {
+        let rfq_id = event
+            .metadata
+            .rfq_id
+            .ok_or_else(|| ApplicationError::validation("event has no rfq_id"))?;
+        let rfq = self
+            .rfq_repository
+            .find_by_id(rfq_id)
+            .await
+            .map_err(ApplicationError::repository)?
+            .ok_or_else(|| ApplicationError::RfqNotFound(rfq_id.to_string()))?;
+
+        Ok(self.broadcast(&rfq, &event.venue_ids).await)
+    }
+
+    /// Notifies each venue of the RFQ through its configured channel.
+    #[instrument(skip_all, fields(rfq_id = %rfq.id()))]
+    pub async fn broadcast(&self, rfq: &Rfq, venue_ids: &[VenueId]) -> BroadcastReport {
+        let broadcast = RfqBroadcast::from_rfq(rfq);
+        let outcomes = futures::future::join_all(
+            venue_ids
+                .iter()
+                .map(|venue_id| self.notify_venue(venue_id, &broadcast)),
+        )
+        .await;
+
+        let mut report = BroadcastReport::default();
+        for (venue_id, outcome) in venue_ids.iter().zip(outcomes) {
+            match outcome {
+                Some(true) => report.delivered.push(venue_id.clone()),
+                Some(false) => report.failed.push(venue_id.clone()),
+                None => report.skipped.push(venue_id.clone()),
+            }
+        }
+        report
+    }
+
+    /// Returns whether the venue was notified, or `None` if it has no channel.
+    async fn notify_venue(&self, venue_id: &VenueId, broadcast: &RfqBroadcast) -> Option<bool> {
+        let mut venue = match self.venue_repository.find_by_id(venue_id).await {
+            Ok(Some(venue)) => venue,
+            Ok(None) => return None,
+            Err(e) => {
+                tracing::warn!(venue_id = %venue_id, "Failed to load venue for RFQ broadcast: {}", e);
+                return Some(false);
+            }
+        };
+        let channel = NotificationChannel::from_config(venue.config())?;
+
+        let delivered = match channel {
+            NotificationChannel::Webhook { url, secret } => {
+                let budget = self.retry_budgets.for_venue(venue_id);
+                let result = execute_with_retry_budget(&self.retry_policy, &budget, || {
+                    self.webhook_client.deliver(&url, &secret, broadcast)
+                })
+                .await;
+                match result {
+                    Ok(()) => true,
+                    Err(e) => {
+                        tracing::warn!(
+                            venue_id = %venue_id,
+                            attempts = e.attempts(),
+                            "RFQ webhook delivery failed: {}",
+                            e
+                        );
+                        false
+                    }
+                }
+            }
+            NotificationChannel::Stream => self.subscriptions.publish(venue_id, broadcast) > 0,
+        };
+
+        venue.metrics_mut().record_notification(delivered);
+        if let Err(e) = self.venue_repository.save(&venue).await {
+            tracing::warn!(venue_id = %venue_id, "Failed to record RFQ notification: {}", e);
+        }
+        Some(delivered)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::anonymity::AnonymityLevel;
+    use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::value_objects::{AssetClass, CounterpartyId, Symbol, VenueType};
+    use crate::infrastructure::notifications::rfq_webhook::{
+        SIGNATURE_HEADER, TIMESTAMP_HEADER, sign,
+    };
+    use crate::infrastructure::persistence::cursor::{paginate, paginate_offset};
+    use crate::infrastructure::persistence::{PageCursor, RfqListFilter};
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[derive(Debug, Default)]
+    struct MockRfqRepository {
+        rfqs: Mutex<HashMap<RfqId, Rfq>>,
+    }
+
+    #[async_trait]
+    impl RfqRepository for MockRfqRepository {
+        async fn save(&self, rfq: &Rfq) -> Result<(), String> {
+            self.rfqs.lock().insert(rfq.id(), rfq.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: RfqId) -> Result<Option<Rfq>, String> {
+            Ok(self.rfqs.lock().get(&id).cloned())
+        }
+
+        async fn list_after(
+            &self,
+            cursor: Option<&PageCursor>,
+            limit: usize,
+            filter: &RfqListFilter,
+        ) -> Result<Vec<Rfq>, String> {
+            let rfqs = self
+                .rfqs
+                .lock()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .cloned()
+                .collect();
+            Ok(paginate(rfqs, cursor, limit, PageCursor::from_rfq))
+        }
+
+        async fn list_offset(
+            &self,
+            offset: usize,
+            limit: usize,
+            filter: &RfqListFilter,
+        ) -> Result<Vec<Rfq>, String> {
+            let rfqs = self
+                .rfqs
+                .lock()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .cloned()
+                .collect();
+            Ok(paginate_offset(rfqs, offset, limit, PageCursor::from_rfq))
+        }
+
+        async fn count_matching(&self, filter: &RfqListFilter) -> Result<u64, String> {
+            let count = self
+                .rfqs
+                .lock()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .count();
+            Ok(count as u64)
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct MockVenueRepository {
+        venues: Mutex<HashMap<VenueId, Venue>>,
+    }
+
+    impl MockVenueRepository {
+        fn with_venues(venues: impl IntoIterator<Item = Venue>) -> Self {
+            let repo = Self::default();
+            for venue in venues {
+                repo.venues.lock().insert(venue.id().clone(), venue);
+            }
+            repo
+        }
+
+        fn venue(&self, id: &str) -> Venue {
+            self.venues.lock().get(&VenueId::new(id)).cloned().unwrap()
+        }
+    }
+
+    #[async_trait]
+    impl BroadcastVenueRepository for MockVenueRepository {
+        async fn find_by_id(&self, id: &VenueId) -> Result<Option<Venue>, String> {
+            Ok(self.venues.lock().get(id).cloned())
+        }
+
+        async fn save(&self, venue: &Venue) -> Result<(), String> {
+            self.venues.lock().insert(venue.id().clone(), venue.clone());
+            Ok(())
+        }
+    }
+
+    fn create_rfq(anonymity_level: AnonymityLevel) -> Rfq {
+        RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build(),
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .anonymity_level(anonymity_level)
+        .build()
+    }
+
+    fn webhook_venue(id: &str, url: &str) -> Venue {
+        let mut venue = Venue::new(VenueId::new(id), id, VenueType::ExternalMM);
+        venue.config_mut().set(WEBHOOK_URL_SETTING, url);
+        venue.config_mut().set(WEBHOOK_SECRET_SETTING, "secret");
+        venue
+    }
+
+    fn stream_venue(id: &str) -> Venue {
+        let mut venue = Venue::new(VenueId::new(id), id, VenueType::ExternalMM);
+        venue.config_mut().set(CHANNEL_SETTING, "stream");
+        venue
+    }
+
+    fn create_service(venues: Arc<MockVenueRepository>) -> RfqBroadcastService {
+        RfqBroadcastService::new(
+            Arc::new(MockRfqRepository::default()),
+            venues,
+            RfqWebhookClient::with_default_timeout().unwrap(),
+            Arc::new(RfqSubscriptionHub::new()),
+        )
+        .with_retry_policy(RetryPolicy::new(3, 1, 10, 2.0, 0.0))
+    }
+
+    #[tokio::test]
+    async fn webhook_retries_server_error_then_succeeds() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let venues = Arc::new(MockVenueRepository::with_venues([webhook_venue(
+            "mm-1",
+            &server.uri(),
+        )]));
+        let service = create_service(venues.clone());
+        let rfq = create_rfq(AnonymityLevel::Transparent);
+
+        let report = service.broadcast(&rfq, &[VenueId::new("mm-1")]).await;
+
+        assert_eq!(report.delivered, vec![VenueId::new("mm-1")]);
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+        let metrics = venues.venue("mm-1").metrics().clone();
+        assert_eq!(metrics.notifications_delivered(), 1);
+        assert_eq!(metrics.notifications_failed(), 0);
+    }
+
+    #[tokio::test]
+    async fn webhook_body_is_signed_and_anonymous_requester_is_pseudonymous() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let venues = Arc::new(MockVenueRepository::with_venues([webhook_venue(
+            "mm-1",
+            &server.uri(),
+        )]));
+        let service = create_service(venues);
+        let rfq = create_rfq(AnonymityLevel::FullAnonymous);
+
+        service.broadcast(&rfq, &[VenueId::new("mm-1")]).await;
+
+        let requests = server.received_requests().await.unwrap();
+        let request = requests.first().unwrap();
+        let timestamp: i64 = request
+            .headers
+            .get(TIMESTAMP_HEADER)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(
+            request
+                .headers
+                .get(SIGNATURE_HEADER)
+                .unwrap()
+                .to_str()
+                .unwrap(),
+            sign("secret", timestamp, &request.body)
+        );
+
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        let expected = rfq.to_anonymous_view().requester_handle();
+        assert_eq!(body.get("requester").unwrap(), expected.as_str());
+        assert_eq!(body.get("anonymous").unwrap(), true);
+        assert!(!String::from_utf8_lossy(&request.body).contains("client-1"));
+    }
+
+    #[tokio::test]
+    async fn webhook_failure_is_recorded() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400))
+            .mount(&server)
+            .await;
+
+        let venues = Arc::new(MockVenueRepository::with_venues([webhook_venue(
+            "mm-1",
+            &server.uri(),
+        )]));
+        let service = create_service(venues.clone());
+
+        let report = service
+            .broadcast(
+                &create_rfq(AnonymityLevel::Transparent),
+                &[VenueId::new("mm-1")],
+            )
+            .await;
+
+        assert_eq!(report.failed, vec![VenueId::new("mm-1")]);
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+        assert_eq!(venues.venue("mm-1").metrics().notifications_failed(), 1);
+    }
+
+    #[tokio::test]
+    async fn webhook_retries_stop_when_the_venue_budget_is_spent() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        let venues = Arc::new(MockVenueRepository::with_venues([webhook_venue(
+            "mm-1",
+            &server.uri(),
+        )]));
+        let service = create_service(venues).with_retry_budgets(Arc::new(RetryBudgets::new(
+            1,
+            std::time::Duration::from_secs(3600),
+        )));
+        let rfq = create_rfq(AnonymityLevel::Transparent);
+
+        let report = service.broadcast(&rfq, &[VenueId::new("mm-1")]).await;
+        assert_eq!(report.failed, vec![VenueId::new("mm-1")]);
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+        service.broadcast(&rfq, &[VenueId::new("mm-1")]).await;
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn stream_delivers_to_two_subscribed_mms() {
+        let venues = Arc::new(MockVenueRepository::with_venues([
+            stream_venue("mm-1"),
+            stream_venue("mm-2"),
+            Venue::new(VenueId::new("dex"), "dex", VenueType::DexAggregator),
+        ]));
+        let service = create_service(venues.clone());
+        let mut mm1 = service.subscriptions().subscribe(VenueId::new("mm-1"));
+        let mut mm2 = service.subscriptions().subscribe(VenueId::new("mm-2"));
+        let rfq = create_rfq(AnonymityLevel::Transparent);
+
+        let report = service
+            .broadcast(
+                &rfq,
+                &[
+                    VenueId::new("mm-1"),
+                    VenueId::new("mm-2"),
+                    VenueId::new("dex"),
+                ],
+            )
+            .await;
+
+        assert_eq!(
+            report.delivered,
+            vec![VenueId::new("mm-1"), VenueId::new("mm-2")]
+        );
+        assert_eq!(report.skipped, vec![VenueId::new("dex")]);
+        for rx in [&mut mm1, &mut mm2] {
+            let broadcast = rx.try_recv().unwrap();
+            assert_eq!(broadcast.rfq_id, rfq.id());
+            assert_eq!(broadcast.requester, "client-1");
+            assert!(!broadcast.anonymous);
+        }
+        assert_eq!(venues.venue("mm-2").metrics().notifications_delivered(), 1);
+    }
+
+    #[tokio::test]
+    async fn stream_without_subscribers_fails() {
+        let venues = Arc::new(MockVenueRepository::with_venues([stream_venue("mm-1")]));
+        let service = create_service(venues.clone());
+        drop(service.subscriptions().subscribe(VenueId::new("mm-1")));
+
+        let report = service
+            .broadcast(
+                &create_rfq(AnonymityLevel::Transparent),
+                &[VenueId::new("mm-1")],
+            )
+            .await;
+
+        assert_eq!(report.failed, vec![VenueId::new("mm-1")]);
+        assert_eq!(
+            service
+                .subscriptions()
+                .subscriber_count(&VenueId::new("mm-1")),
+            0
+        );
+        assert_eq!(venues.venue("mm-1").metrics().notifications_failed(), 1);
+    }
+
+    #[tokio::test]
+    async fn quote_collection_started_loads_the_rfq() {
+        let venues = Arc::new(MockVenueRepository::with_venues([stream_venue("mm-1")]));
+        let rfq_repository = Arc::new(MockRfqRepository::default());
+        let rfq = create_rfq(AnonymityLevel::Transparent);
+        rfq_repository.save(&rfq).await.unwrap();
+        let service = RfqBroadcastService::new(
+            rfq_repository,
+            venues,
+            RfqWebhookClient::with_default_timeout().unwrap(),
+            Arc::new(RfqSubscriptionHub::new()),
+        );
+        let mut rx = service.subscriptions().subscribe(VenueId::new("mm-1"));
+
+        let event = QuoteCollectionStarted::new(rfq.id(), vec![VenueId::new("mm-1")]);
+        let report = service.on_quote_collection_started(&event).await.unwrap();
+
+        assert_eq!(report.delivered, vec![VenueId::new("mm-1")]);
+        assert_eq!(rx.try_recv().unwrap().rfq_id, rfq.id());
+    }
+
+    #[test]
+    fn webhook_without_secret_is_not_a_channel() {
+        let mut config = VenueConfig::new();
+        config.set(WEBHOOK_URL_SETTING, "https://mm.example.com/rfqs");
+        assert_eq!(NotificationChannel::from_config(&config), None);
+    }
+}
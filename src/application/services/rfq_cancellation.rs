@@ -0,0 +1,277 @@
+//! # RFQ Cancellation
+//!
+//! Tells venues to stop pricing an RFQ the client has cancelled.
+//!
+//! Quote collection records which venues each RFQ was sent to through
+//! [`RfqCancellationService::record_dispatch`]. When the RFQ is cancelled,
+//! [`RfqCancellationService::notify_cancelled`] sends a cancel notice to
+//! every one of them, plus any venue that already quoted, concurrently and
+//! under a per-venue timeout. Venues whose protocol has no cancel message
+//! log the notice and let their quotes expire.
+//!
+//! Dispatch records are kept until the RFQ expires; nothing needs to be
+//! cancelled after that.
+
+use crate::application::use_cases::collect_quotes::VenueRegistry;
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{RfqId, VenueId};
+use futures::future::join_all;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{instrument, warn};
+
+/// Default time to wait for each venue to accept a cancel notice.
+pub const DEFAULT_CANCEL_NOTICE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Result of sending a cancel notice to one venue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CancelNoticeOutcome {
+    /// The venue was told to stop pricing the RFQ.
+    Sent,
+    /// The venue's protocol has no cancel message.
+    Unsupported,
+    /// The venue is no longer registered.
+    Unavailable,
+    /// The notice could not be delivered.
+    Failed(String),
+}
+
+impl fmt::Display for CancelNoticeOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sent => write!(f, "sent"),
+            Self::Unsupported => write!(f, "unsupported"),
+            Self::Unavailable => write!(f, "unavailable"),
+            Self::Failed(reason) => write!(f, "failed: {}", reason),
+        }
+    }
+}
+
+/// Cancel notice sent to a single venue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CancelNotice {
+    /// The notified venue.
+    pub venue_id: VenueId,
+    /// What happened.
+    pub outcome: CancelNoticeOutcome,
+}
+
+/// Venues an RFQ was sent to.
+#[derive(Debug)]
+struct Dispatch {
+    venues: Vec<VenueId>,
+    expires_at: Timestamp,
+}
+
+/// Fans out cancellation notices to the venues pricing an RFQ.
+pub struct RfqCancellationService {
+    venue_registry: Arc<dyn VenueRegistry>,
+    dispatched: Mutex<HashMap<RfqId, Dispatch>>,
+    timeout: Duration,
+}
+
+impl RfqCancellationService {
+    /// Creates a service that looks venues up in `venue_registry`.
+    #[must_use]
+    pub fn new(venue_registry: Arc<dyn VenueRegistry>) -> Self {
+        Self {
+            venue_registry,
+            dispatched: Mutex::new(HashMap::new()),
+            timeout: DEFAULT_CANCEL_NOTICE_TIMEOUT,
+        }
+    }
+
+    /// Sets how long to wait for each venue to accept a notice.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Records that `rfq` was sent to `venues`.
+    ///
+    /// Records of RFQs past their expiry are dropped at the same time.
+    pub fn record_dispatch(&self, rfq: &Rfq, venues: &[VenueId]) {
+        let now = Timestamp::now();
+        let mut dispatched = self.dispatched.lock();
+        dispatched.retain(|_, dispatch| !dispatch.expires_at.is_before(&now));
+        let dispatch = dispatched.entry(rfq.id()).or_insert_with(|| Dispatch {
+            venues: Vec::new(),
+            expires_at: rfq.expires_at(),
+        });
+        for venue_id in venues {
+            if !dispatch.venues.contains(venue_id) {
+                dispatch.venues.push(venue_id.clone());
+            }
+        }
+    }
+
+    /// Returns the venues `rfq_id` was recorded as sent to.
+    #[must_use]
+    pub fn dispatched_venues(&self, rfq_id: RfqId) -> Vec<VenueId> {
+        self.dispatched
+            .lock()
+            .get(&rfq_id)
+            .map(|dispatch| dispatch.venues.clone())
+            .unwrap_or_default()
+    }
+
+    /// Sends a cancel notice for `rfq` to every venue that received it.
+    ///
+    /// Notices go to the recorded dispatch venues and to every venue that
+    /// quoted, sorted by venue ID. Failures are logged and reported, never
+    /// returned: the cancellation itself has already happened.
+    #[instrument(skip_all, fields(rfq_id = %rfq.id()))]
+    pub async fn notify_cancelled(&self, rfq: &Rfq) -> Vec<CancelNotice> {
+        let rfq_id = rfq.id();
+        let mut venues = self
+            .dispatched
+            .lock()
+            .remove(&rfq_id)
+            .map(|dispatch| dispatch.venues)
+            .unwrap_or_default();
+        venues.extend(rfq.quotes().iter().map(|quote| quote.venue_id().clone()));
+        venues.extend(
+            rfq.retired_quotes()
+                .iter()
+                .map(|retired| retired.quote().venue_id().clone()),
+        );
+        venues.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        venues.dedup();
+
+        join_all(venues.into_iter().map(|venue_id| async move {
+            let outcome = self.notify_venue(&venue_id, rfq_id).await;
+            if let CancelNoticeOutcome::Failed(reason) = &outcome {
+                warn!(venue_id = %venue_id, "Failed to send RFQ cancel notice: {}", reason);
+            }
+            CancelNotice { venue_id, outcome }
+        }))
+        .await
+    }
+
+    async fn notify_venue(&self, venue_id: &VenueId, rfq_id: RfqId) -> CancelNoticeOutcome {
+        let Some(venue) = self.venue_registry.get_venue(venue_id).await else {
+            return CancelNoticeOutcome::Unavailable;
+        };
+        match tokio::time::timeout(self.timeout, venue.cancel_rfq(rfq_id)).await {
+            Ok(Ok(())) if venue.supports_rfq_cancellation() => CancelNoticeOutcome::Sent,
+            Ok(Ok(())) => CancelNoticeOutcome::Unsupported,
+            Ok(Err(e)) => CancelNoticeOutcome::Failed(e.to_string()),
+            Err(_) => CancelNoticeOutcome::Failed(format!(
+                "timed out after {}ms",
+                self.timeout.as_millis()
+            )),
+        }
+    }
+}
+
+impl fmt::Debug for RfqCancellationService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RfqCancellationService")
+            .field("tracked_rfqs", &self.dispatched.lock().len())
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::value_objects::{
+        AssetClass, CounterpartyId, Instrument, OrderSide, Price, Quantity, Symbol,
+    };
+    use crate::infrastructure::venues::simulated::{SimulatedVenueAdapter, SimulatedVenueConfig};
+    use crate::infrastructure::venues::traits::VenueAdapter;
+    use async_trait::async_trait;
+
+    #[derive(Debug, Default)]
+    struct TestRegistry {
+        venues: Vec<Arc<SimulatedVenueAdapter>>,
+    }
+
+    #[async_trait]
+    impl VenueRegistry for TestRegistry {
+        async fn get_available_venues(&self) -> Vec<Arc<dyn VenueAdapter>> {
+            self.venues
+                .iter()
+                .map(|v| Arc::clone(v) as Arc<dyn VenueAdapter>)
+                .collect()
+        }
+
+        async fn get_venue(&self, venue_id: &VenueId) -> Option<Arc<dyn VenueAdapter>> {
+            self.venues
+                .iter()
+                .find(|v| VenueAdapter::venue_id(v.as_ref()) == venue_id)
+                .map(|v| Arc::clone(v) as Arc<dyn VenueAdapter>)
+        }
+    }
+
+    fn venue(id: &str) -> Arc<SimulatedVenueAdapter> {
+        Arc::new(SimulatedVenueAdapter::new(
+            SimulatedVenueConfig::new(Price::new(50000.0).unwrap()).with_venue_id(id),
+        ))
+    }
+
+    fn test_rfq() -> Rfq {
+        let symbol = Symbol::new("BTC/USD").unwrap();
+        RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            Instrument::builder(symbol, AssetClass::CryptoSpot).build(),
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build()
+    }
+
+    #[tokio::test]
+    async fn notifies_every_dispatched_venue() {
+        let (a, b) = (venue("mm-a"), venue("mm-b"));
+        let registry = TestRegistry {
+            venues: vec![Arc::clone(&a), Arc::clone(&b)],
+        };
+        let service = RfqCancellationService::new(Arc::new(registry));
+        let rfq = test_rfq();
+        service.record_dispatch(&rfq, &[VenueId::new("mm-b"), VenueId::new("mm-a")]);
+
+        let notices = service.notify_cancelled(&rfq).await;
+
+        assert_eq!(
+            notices,
+            vec![
+                CancelNotice {
+                    venue_id: VenueId::new("mm-a"),
+                    outcome: CancelNoticeOutcome::Sent,
+                },
+                CancelNotice {
+                    venue_id: VenueId::new("mm-b"),
+                    outcome: CancelNoticeOutcome::Sent,
+                },
+            ]
+        );
+        assert_eq!(a.cancelled_rfqs(), vec![rfq.id()]);
+        assert_eq!(b.cancelled_rfqs(), vec![rfq.id()]);
+        assert!(service.dispatched_venues(rfq.id()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn reports_unregistered_venues() {
+        let service = RfqCancellationService::new(Arc::new(TestRegistry::default()));
+        let rfq = test_rfq();
+        service.record_dispatch(&rfq, &[VenueId::new("gone")]);
+
+        let notices = service.notify_cancelled(&rfq).await;
+
+        assert_eq!(notices.len(), 1);
+        assert_eq!(
+            notices.first().unwrap().outcome,
+            CancelNoticeOutcome::Unavailable
+        );
+    }
+}
@@ -0,0 +1,392 @@
+//! # RFQ Expiry Sweeper
+//!
+//! Background expiry of RFQs past their deadline.
+//!
+//! [`RfqExpirySweeper`] scans active RFQs and moves those whose `expires_at`
+//! has passed to `Expired`. The current time is read from a [`Clock`] so the
+//! sweep can be driven deterministically in tests.
+//!
+//! RFQs in `Executing` do not expire: an execution in flight either
+//! completes or fails on its own at its execution deadline. One still
+//! executing a grace period past that deadline was abandoned, typically by
+//! an instance that crashed mid-execution, and is marked `Failed` with
+//! [`FailureCode::ExecutionTimeout`]. RFQs that started executing before
+//! deadlines were recorded have none and are left alone.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::value_objects::timestamp::{Clock, SystemClock, Timestamp};
+use crate::domain::value_objects::{FailureCode, FailureReason, RfqState};
+use crate::infrastructure::metrics;
+use crate::infrastructure::persistence::traits::RfqRepository;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Summary of a single sweep.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RfqExpiryReport {
+    /// RFQs moved to `Expired`.
+    pub expired: usize,
+    /// Past-deadline RFQs left alone because they cannot expire in their state.
+    pub skipped: usize,
+    /// Abandoned executions marked failed.
+    pub timed_out: usize,
+    /// RFQs not expired or failed because of an error; they are retried
+    /// next sweep.
+    pub errors: usize,
+}
+
+/// How long past its execution deadline an RFQ is left to the instance
+/// executing it before the sweeper fails it.
+pub const DEFAULT_EXECUTION_GRACE: Duration = Duration::from_secs(30);
+
+/// Default interval between expiry sweeps.
+pub const DEFAULT_SWEEP_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Expires active RFQs whose deadline has passed.
+///
+/// # Examples
+///
+/// ```ignore
+/// let sweeper = RfqExpirySweeper::new(rfq_repository);
+///
+/// tokio::spawn(async move { sweeper.run(DEFAULT_SWEEP_INTERVAL).await });
+/// ```
+#[derive(Debug)]
+pub struct RfqExpirySweeper {
+    rfq_repository: Arc<dyn RfqRepository>,
+    clock: Arc<dyn Clock>,
+    execution_grace: Duration,
+}
+
+impl RfqExpirySweeper {
+    /// Creates a new sweeper using the system clock.
+    #[must_use]
+    pub fn new(rfq_repository: Arc<dyn RfqRepository>) -> Self {
+        Self {
+            rfq_repository,
+            clock: Arc::new(SystemClock),
+            execution_grace: DEFAULT_EXECUTION_GRACE,
+        }
+    }
+
+    /// Sets the clock used to decide whether an RFQ has expired.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Sets how long past its execution deadline an executing RFQ is left
+    /// alone. Defaults to [`DEFAULT_EXECUTION_GRACE`].
+    #[must_use]
+    pub fn with_execution_grace(mut self, execution_grace: Duration) -> Self {
+        self.execution_grace = execution_grace;
+        self
+    }
+
+    /// Sweeps for expired RFQs every `interval`, forever.
+    pub async fn run(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match self.run_once().await {
+                Ok(report) => tracing::debug!(?report, "RFQ expiry sweep completed"),
+                Err(e) => tracing::error!(error = %e, "RFQ expiry sweep failed"),
+            }
+        }
+    }
+
+    /// Expires every active RFQ whose deadline has passed, and fails
+    /// executions abandoned past their execution deadline.
+    ///
+    /// Errors on individual RFQs are logged and counted; the RFQ stays
+    /// active and is picked up again by the next sweep.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the active RFQs cannot be loaded.
+    pub async fn run_once(&self) -> ApplicationResult<RfqExpiryReport> {
+        let now = self.clock.now();
+        let active = self
+            .rfq_repository
+            .find_active()
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?;
+
+        let mut report = RfqExpiryReport::default();
+        let abandoned_before = now - self.execution_grace;
+        for mut rfq in active {
+            if rfq.is_execution_overdue_at(abandoned_before) {
+                match self.time_out(&mut rfq, now).await {
+                    Ok(()) => report.timed_out += 1,
+                    Err(e) => {
+                        tracing::warn!(
+                            rfq_id = %rfq.id(),
+                            error = %e,
+                            "RFQ execution timeout skipped"
+                        );
+                        report.errors += 1;
+                    }
+                }
+                continue;
+            }
+            if !rfq.is_expired_at(now) {
+                continue;
+            }
+            if !rfq.state().can_transition_to(RfqState::Expired) {
+                report.skipped += 1;
+                continue;
+            }
+            match self.expire(&mut rfq, now).await {
+                Ok(()) => report.expired += 1,
+                Err(e) => {
+                    tracing::warn!(rfq_id = %rfq.id(), error = %e, "RFQ expiry skipped");
+                    report.errors += 1;
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Expires and persists a single RFQ.
+    async fn expire(&self, rfq: &mut Rfq, now: Timestamp) -> ApplicationResult<()> {
+        rfq.expire()?;
+        self.rfq_repository
+            .save(rfq)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?;
+        metrics::record_rfq_terminal(RfqState::Expired);
+        tracing::info!(
+            rfq_id = %rfq.id(),
+            expires_at = %rfq.expires_at(),
+            swept_at = %now,
+            "RFQ expired"
+        );
+        Ok(())
+    }
+
+    /// Fails and persists an abandoned execution.
+    async fn time_out(&self, rfq: &mut Rfq, now: Timestamp) -> ApplicationResult<()> {
+        let deadline = rfq.execution_deadline().unwrap_or(now);
+        rfq.mark_failed(FailureReason::new(
+            FailureCode::ExecutionTimeout,
+            format!("execution abandoned past its deadline {deadline}"),
+        ))?;
+        self.rfq_repository
+            .save(rfq)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?;
+        metrics::record_rfq_terminal(RfqState::Failed);
+        tracing::warn!(
+            rfq_id = %rfq.id(),
+            execution_deadline = %deadline,
+            swept_at = %now,
+            "Abandoned RFQ execution failed"
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::timestamp::MockClock;
+    use crate::infrastructure::persistence::RepositoryError;
+    use crate::test_support::{FailingRfqRepository, FixtureBuilder, Fixtures, RfqSeed};
+
+    struct Setup {
+        sweeper: RfqExpirySweeper,
+        fixtures: Fixtures,
+        rfqs: Arc<FailingRfqRepository>,
+        clock: Arc<MockClock>,
+    }
+
+    /// Seeds `rfqs` and sweeps them through a failure-injection wrapper.
+    async fn setup(rfqs: impl FnOnce(Timestamp) -> Vec<RfqSeed>) -> Setup {
+        let start = Timestamp::now();
+        let fixtures = rfqs(start)
+            .into_iter()
+            .fold(
+                FixtureBuilder::new().with_now(start),
+                FixtureBuilder::with_rfq,
+            )
+            .build()
+            .await
+            .unwrap();
+        let repo = Arc::new(FailingRfqRepository::new(
+            Arc::clone(&fixtures.rfqs) as Arc<dyn RfqRepository>
+        ));
+        let clock = Arc::new(MockClock::new(start));
+        let sweeper = RfqExpirySweeper::new(Arc::clone(&repo) as Arc<dyn RfqRepository>)
+            .with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+        Setup {
+            sweeper,
+            fixtures,
+            rfqs: repo,
+            clock,
+        }
+    }
+
+    fn expiring(label: &str, at: Timestamp) -> RfqSeed {
+        RfqSeed::new(label, "client-1", "BTC/USD").with_expires_at(at)
+    }
+
+    async fn state(fixtures: &Fixtures, label: &str) -> RfqState {
+        fixtures.stored_rfq(label).await.unwrap().unwrap().state()
+    }
+
+    #[tokio::test]
+    async fn expires_only_past_deadline() {
+        let s = setup(|start| {
+            vec![
+                expiring("short", start.add_secs(30)),
+                expiring("long", start.add_secs(300)),
+            ]
+        })
+        .await;
+
+        assert_eq!(
+            s.sweeper.run_once().await.unwrap(),
+            RfqExpiryReport::default()
+        );
+
+        s.clock.advance_secs(31);
+        let report = s.sweeper.run_once().await.unwrap();
+        assert_eq!(report.expired, 1);
+        assert_eq!(report.errors, 0);
+
+        assert_eq!(state(&s.fixtures, "short").await, RfqState::Expired);
+        assert_eq!(state(&s.fixtures, "long").await, RfqState::Created);
+    }
+
+    #[tokio::test]
+    async fn deadline_itself_is_not_expired() {
+        let s = setup(|start| vec![expiring("rfq", start.add_secs(30))]).await;
+        let expires_at = s.fixtures.rfq("rfq").unwrap().expires_at();
+
+        s.clock.set(expires_at);
+        assert_eq!(s.sweeper.run_once().await.unwrap().expired, 0);
+
+        s.clock.advance(Duration::from_millis(1));
+        assert_eq!(s.sweeper.run_once().await.unwrap().expired, 1);
+    }
+
+    #[tokio::test]
+    async fn expired_rfqs_are_not_swept_twice() {
+        let s = setup(|start| vec![expiring("rfq", start.add_secs(30))]).await;
+
+        s.clock.advance_secs(60);
+        assert_eq!(s.sweeper.run_once().await.unwrap().expired, 1);
+        assert_eq!(
+            s.sweeper.run_once().await.unwrap(),
+            RfqExpiryReport::default()
+        );
+        assert_eq!(s.fixtures.rfqs.count_active().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn failed_save_is_counted_and_retried_next_sweep() {
+        let s = setup(|start| {
+            vec![
+                expiring("first", start.add_secs(10)),
+                expiring("second", start.add_secs(20)),
+            ]
+        })
+        .await;
+        s.rfqs
+            .fail_next_save(RepositoryError::connection_lost("reset by peer"));
+
+        s.clock.advance_secs(60);
+        let report = s.sweeper.run_once().await.unwrap();
+        assert_eq!(report.expired, 1);
+        assert_eq!(report.errors, 1);
+        assert_eq!(s.fixtures.rfqs.count_active().await.unwrap(), 1);
+
+        let report = s.sweeper.run_once().await.unwrap();
+        assert_eq!(report.expired, 1);
+        assert_eq!(report.errors, 0);
+        assert_eq!(state(&s.fixtures, "first").await, RfqState::Expired);
+        assert_eq!(state(&s.fixtures, "second").await, RfqState::Expired);
+    }
+
+    /// Seeds an RFQ left executing with a 5s execution deadline.
+    async fn abandoned_execution(s: &Setup) -> Rfq {
+        let mut rfq = s.fixtures.stored_rfq("quoted").await.unwrap().unwrap();
+        rfq.select_quote(s.fixtures.quote("quoted").unwrap().id())
+            .unwrap();
+        rfq.start_execution_within(Duration::from_secs(5)).unwrap();
+        s.fixtures.rfqs.save(&rfq).await.unwrap();
+        rfq
+    }
+
+    #[tokio::test]
+    async fn abandoned_executions_fail_after_the_grace_period() {
+        let s = setup(|start| {
+            vec![
+                RfqSeed::new("quoted", "client-1", "BTC/USD")
+                    .with_quote("venue-1", 100.0)
+                    .with_expires_at(start.add_secs(3600)),
+            ]
+        })
+        .await;
+        let rfq = abandoned_execution(&s).await;
+        let deadline = rfq.execution_deadline().unwrap();
+
+        // The executing instance still owns it until the grace period ends
+        s.clock.set(deadline + DEFAULT_EXECUTION_GRACE);
+        assert_eq!(
+            s.sweeper.run_once().await.unwrap(),
+            RfqExpiryReport::default()
+        );
+        assert_eq!(state(&s.fixtures, "quoted").await, RfqState::Executing);
+
+        s.clock.advance(Duration::from_millis(1));
+        let report = s.sweeper.run_once().await.unwrap();
+        assert_eq!(report.timed_out, 1);
+        assert_eq!(report.expired, 0);
+
+        let stored = s.fixtures.stored_rfq("quoted").await.unwrap().unwrap();
+        assert_eq!(stored.state(), RfqState::Failed);
+        assert_eq!(
+            stored.failure_reason().map(FailureReason::code),
+            Some(FailureCode::ExecutionTimeout)
+        );
+    }
+
+    #[tokio::test]
+    async fn executions_past_the_rfq_expiry_are_skipped_until_overdue() {
+        let s = setup(|start| {
+            vec![
+                RfqSeed::new("quoted", "client-1", "BTC/USD")
+                    .with_quote("venue-1", 100.0)
+                    .with_expires_at(start.add_secs(2)),
+            ]
+        })
+        .await;
+        abandoned_execution(&s).await;
+
+        s.clock.advance_secs(3);
+        let report = s.sweeper.run_once().await.unwrap();
+        assert_eq!(report.skipped, 1);
+        assert_eq!(report.timed_out, 0);
+
+        s.clock.advance_secs(60);
+        assert_eq!(s.sweeper.run_once().await.unwrap().timed_out, 1);
+    }
+
+    #[tokio::test]
+    async fn unreadable_repository_fails_the_sweep() {
+        let s = setup(|start| vec![expiring("rfq", start.add_secs(10))]).await;
+        s.rfqs
+            .fail_next_read(RepositoryError::timeout("statement timeout"));
+
+        s.clock.advance_secs(60);
+        assert!(matches!(
+            s.sweeper.run_once().await,
+            Err(ApplicationError::RepositoryError(_))
+        ));
+        assert_eq!(s.sweeper.run_once().await.unwrap().expired, 1);
+    }
+}
@@ -0,0 +1,754 @@
+//! # RFQ Summary Projection
+//!
+//! Maintains the [`RfqSummary`] read model from domain events.
+//!
+//! [`RfqSummaryProjection`] folds the stored events of each RFQ (created,
+//! quote received, selected, executed, failed, ...) into one denormalized
+//! row so the ops dashboard can list RFQs without loading quotes and trades
+//! per row. Rows are only ever derived from events, so
+//! [`RfqSummaryProjection::rebuild`] can drop them and replay the event
+//! store at any time.
+//!
+//! [`ProjectingEventStore`] wraps an [`EventStore`] and applies every
+//! appended event to the projection, keeping the read model current.
+//! Events are applied in sequence order per RFQ; an event whose sequence
+//! is not newer than the row's, or a versioned event whose (aggregate ID,
+//! version) the row already folded in, is ignored, so replays and
+//! redeliveries are idempotent.
+//! With a [`DeadLetterQueue`] attached, an event the projection keeps
+//! failing on is parked for re-drive instead of only being logged.
+
+use crate::application::error::{ApplicationError, ApplicationResult, InfrastructureError};
+use crate::application::services::dead_letter_queue::{DeadLetterQueue, EventConsumer};
+use crate::domain::events::admin_events::ForcedStateTransition;
+use crate::domain::events::domain_event::EventType;
+use crate::domain::events::rfq_events::{
+    ExecutionFailed, ExecutionStarted, QuoteReceived, QuoteSelected, RfqCreated,
+};
+use crate::domain::events::trade_events::TradeExecuted;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{CorrelationId, OrderSide, Price, RfqId, RfqState};
+use crate::infrastructure::persistence::cursor::PageCursor;
+use crate::infrastructure::persistence::event_store::{EventStore, EventStoreResult, StoredEvent};
+use crate::infrastructure::persistence::rfq_summary::{RfqSummary, RfqSummaryStore};
+use crate::infrastructure::persistence::traits::RepositoryError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Projects domain events into [`RfqSummary`] rows.
+///
+/// # Examples
+///
+/// ```ignore
+/// let projection = RfqSummaryProjection::new(summary_store);
+///
+/// projection.apply(&stored_event).await?;
+/// let rows = projection.rebuild(event_store.as_ref()).await?;
+/// ```
+#[derive(Debug)]
+pub struct RfqSummaryProjection {
+    store: Arc<dyn RfqSummaryStore>,
+}
+
+impl RfqSummaryProjection {
+    /// Name of the projection as an [`EventConsumer`].
+    pub const CONSUMER: &'static str = "rfq_summary_projection";
+
+    /// Creates a projection writing to `store`.
+    #[must_use]
+    pub fn new(store: Arc<dyn RfqSummaryStore>) -> Self {
+        Self { store }
+    }
+
+    /// Returns the store the projection writes to.
+    #[must_use]
+    pub fn store(&self) -> &Arc<dyn RfqSummaryStore> {
+        &self.store
+    }
+
+    /// Applies one stored event to its RFQ's summary.
+    ///
+    /// Returns true if the summary was written. Events without an RFQ,
+    /// events for an RFQ whose creation has not been seen, and events
+    /// already applied to the current row are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload cannot be decoded or the summary
+    /// cannot be loaded or stored.
+    pub async fn apply(&self, event: &StoredEvent) -> ApplicationResult<bool> {
+        let Some(rfq_id) = event.rfq_id else {
+            return Ok(false);
+        };
+        let current = self.store.get(rfq_id).await.map_err(repository_error)?;
+        if current
+            .as_ref()
+            .is_some_and(|summary| already_applied(summary, event))
+        {
+            return Ok(false);
+        }
+        match fold(current, rfq_id, event)? {
+            Some(summary) => {
+                self.store
+                    .upsert(&summary)
+                    .await
+                    .map_err(repository_error)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Drops every summary and replays the whole event store.
+    ///
+    /// Each RFQ's events are folded in sequence order, producing the same
+    /// rows as applying them one by one. Returns the number of rows written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the events cannot be read, a payload cannot be
+    /// decoded, or the rows cannot be written.
+    pub async fn rebuild(&self, events: &dyn EventStore) -> ApplicationResult<u64> {
+        let since = Timestamp::from(DateTime::<Utc>::MIN_UTC);
+        let events = events
+            .get_events_since(since)
+            .await
+            .map_err(|e| ApplicationError::repository(e.to_string()))?;
+
+        let mut by_rfq: HashMap<RfqId, Vec<StoredEvent>> = HashMap::new();
+        for event in events {
+            if let Some(rfq_id) = event.rfq_id {
+                by_rfq.entry(rfq_id).or_default().push(event);
+            }
+        }
+
+        self.store.clear().await.map_err(repository_error)?;
+        let mut written = 0;
+        for (rfq_id, mut rfq_events) in by_rfq {
+            rfq_events.sort_by_key(|e| e.sequence);
+            let mut summary: Option<RfqSummary> = None;
+            for event in &rfq_events {
+                if summary.as_ref().is_some_and(|s| already_applied(s, event)) {
+                    continue;
+                }
+                let folded = fold(summary.clone(), rfq_id, event)?;
+                if folded.is_some() {
+                    summary = folded;
+                }
+            }
+            if let Some(summary) = summary {
+                self.store
+                    .upsert(&summary)
+                    .await
+                    .map_err(repository_error)?;
+                written += 1;
+            }
+        }
+
+        tracing::info!(rows = written, "rebuilt RFQ summary read model");
+        Ok(written)
+    }
+}
+
+#[async_trait]
+impl EventConsumer for RfqSummaryProjection {
+    fn name(&self) -> &str {
+        Self::CONSUMER
+    }
+
+    async fn handle(&self, event: &StoredEvent) -> ApplicationResult<()> {
+        self.apply(event).await.map(|_| ())
+    }
+}
+
+/// Returns true if `event` is not newer than `summary`, or is a versioned
+/// event the summary already folded in.
+fn already_applied(summary: &RfqSummary, event: &StoredEvent) -> bool {
+    event.sequence <= summary.last_sequence
+        || event
+            .aggregate_key()
+            .is_some_and(|(aggregate_id, version)| summary.has_applied(aggregate_id, version))
+}
+
+/// Folds one event into the summary of its RFQ.
+///
+/// Returns `None` if there is no summary to update yet.
+fn fold(
+    summary: Option<RfqSummary>,
+    rfq_id: RfqId,
+    event: &StoredEvent,
+) -> ApplicationResult<Option<RfqSummary>> {
+    let mut summary = match (summary, event.event_name.as_str()) {
+        (None, "RfqCreated") => {
+            let created: RfqCreated = decode(event)?;
+            RfqSummary {
+                rfq_id,
+                client_id: created.client_id,
+                symbol: created.instrument.symbol().clone(),
+                side: created.side,
+                quantity: created.quantity,
+                state: RfqState::Created,
+                quote_count: 0,
+                best_quote_price: None,
+                selected_venue_id: None,
+                trade_id: None,
+                execution_price: None,
+                failure_reason: None,
+                created_at: created.metadata.timestamp,
+                last_event_at: event.timestamp,
+                last_sequence: event.sequence,
+                aggregate_versions: BTreeMap::new(),
+            }
+        }
+        (None, _) => return Ok(None),
+        (Some(summary), _) => summary,
+    };
+
+    match event.event_name.as_str() {
+        "QuoteCollectionStarted" => summary.state = RfqState::QuoteRequesting,
+        "QuoteReceived" => {
+            let received: QuoteReceived = decode(event)?;
+            summary.quote_count = summary.quote_count.saturating_add(1);
+            summary.best_quote_price = Some(match summary.best_quote_price {
+                Some(best) => better_price(summary.side, best, received.price),
+                None => received.price,
+            });
+            if matches!(summary.state, RfqState::Created | RfqState::QuoteRequesting) {
+                summary.state = RfqState::QuotesReceived;
+            }
+        }
+        "QuoteSelected" => {
+            let selected: QuoteSelected = decode(event)?;
+            summary.selected_venue_id = Some(selected.venue_id);
+            summary.state = RfqState::ClientSelecting;
+        }
+        "ExecutionStarted" => {
+            let started: ExecutionStarted = decode(event)?;
+            summary.selected_venue_id = Some(started.venue_id);
+            summary.state = RfqState::Executing;
+        }
+        "TradeExecuted" => {
+            let executed: TradeExecuted = decode(event)?;
+            summary.selected_venue_id = Some(executed.venue_id);
+            summary.trade_id = Some(executed.trade_id);
+            summary.execution_price = Some(executed.price);
+            summary.state = RfqState::Executed;
+        }
+        "ExecutionFailed" => {
+            let failed: ExecutionFailed = decode(event)?;
+            summary.failure_reason = Some(failed.reason);
+            summary.state = RfqState::Failed;
+        }
+        "ForcedStateTransition" => {
+            let forced: ForcedStateTransition = decode(event)?;
+            // Forced settlements leave the RFQ's own state untouched.
+            if forced.trade_id.is_none() {
+                summary.state = forced.to.parse().map_err(|e| {
+                    InfrastructureError::serialization(format!(
+                        "{} {}: {e}",
+                        event.event_name, event.event_id
+                    ))
+                })?;
+                if summary.state == RfqState::Failed {
+                    summary.failure_reason = Some(forced.justification);
+                }
+            }
+        }
+        "RfqCancelled" => summary.state = RfqState::Cancelled,
+        "RfqExpired" => summary.state = RfqState::Expired,
+        _ => {}
+    }
+
+    summary.last_event_at = event.timestamp;
+    summary.last_sequence = event.sequence;
+    if let Some((aggregate_id, version)) = event.aggregate_key() {
+        summary.aggregate_versions.insert(aggregate_id, version);
+    }
+    Ok(Some(summary))
+}
+
+/// Returns the better of two quote prices for the client's side.
+fn better_price(side: OrderSide, current: Price, candidate: Price) -> Price {
+    match side {
+        OrderSide::Buy => current.min(candidate),
+        OrderSide::Sell => current.max(candidate),
+    }
+}
+
+fn decode<T: DeserializeOwned>(event: &StoredEvent) -> ApplicationResult<T> {
+    serde_json::from_value(event.payload.clone()).map_err(|e| {
+        InfrastructureError::serialization(format!("{} {}: {e}", event.event_name, event.event_id))
+            .into()
+    })
+}
+
+fn repository_error(error: RepositoryError) -> ApplicationError {
+    InfrastructureError::Repository(error).into()
+}
+
+/// [`EventStore`] decorator that keeps the RFQ summary read model current.
+///
+/// Every appended event is applied to the projection after it is stored.
+/// A projection failure is logged rather than returned: the event itself
+/// is durable and a rebuild repairs the read model. With a dead letter
+/// queue, the event is instead retried and then parked.
+#[derive(Debug)]
+pub struct ProjectingEventStore {
+    inner: Arc<dyn EventStore>,
+    projection: Arc<RfqSummaryProjection>,
+    dead_letters: Option<Arc<DeadLetterQueue>>,
+}
+
+impl ProjectingEventStore {
+    /// Wraps `inner`, applying appended events to `projection`.
+    #[must_use]
+    pub fn new(inner: Arc<dyn EventStore>, projection: Arc<RfqSummaryProjection>) -> Self {
+        Self {
+            inner,
+            projection,
+            dead_letters: None,
+        }
+    }
+
+    /// Applies appended events through `dead_letters`, which must have the
+    /// projection registered as a consumer.
+    #[must_use]
+    pub fn with_dead_letters(mut self, dead_letters: Arc<DeadLetterQueue>) -> Self {
+        self.dead_letters = Some(dead_letters);
+        self
+    }
+}
+
+#[async_trait]
+impl EventStore for ProjectingEventStore {
+    async fn append(&self, event: StoredEvent) -> EventStoreResult<()> {
+        self.inner.append(event.clone()).await?;
+        if let Some(dead_letters) = &self.dead_letters {
+            if let Err(e) = dead_letters
+                .process(RfqSummaryProjection::CONSUMER, &event)
+                .await
+            {
+                tracing::error!(
+                    event_id = %event.event_id,
+                    error = %e,
+                    "Failed to park event the RFQ summary projection failed on"
+                );
+            }
+        } else if let Err(e) = self.projection.apply(&event).await {
+            tracing::warn!(
+                event_id = %event.event_id,
+                event_name = %event.event_name,
+                error = %e,
+                "RFQ summary projection failed; rebuild the read model to repair"
+            );
+        }
+        Ok(())
+    }
+
+    async fn get_events(&self, rfq_id: RfqId) -> EventStoreResult<Vec<StoredEvent>> {
+        self.inner.get_events(rfq_id).await
+    }
+
+    async fn get_events_since(&self, since: Timestamp) -> EventStoreResult<Vec<StoredEvent>> {
+        self.inner.get_events_since(since).await
+    }
+
+    async fn get_events_by_type(
+        &self,
+        event_type: EventType,
+    ) -> EventStoreResult<Vec<StoredEvent>> {
+        self.inner.get_events_by_type(event_type).await
+    }
+
+    async fn query_by_time_range(
+        &self,
+        from: Timestamp,
+        to: Timestamp,
+        event_types: Option<Vec<EventType>>,
+        limit: usize,
+        cursor: Option<&PageCursor>,
+    ) -> EventStoreResult<Vec<StoredEvent>> {
+        self.inner
+            .query_by_time_range(from, to, event_types, limit, cursor)
+            .await
+    }
+
+    async fn query_by_correlation(
+        &self,
+        correlation_id: CorrelationId,
+    ) -> EventStoreResult<Vec<StoredEvent>> {
+        self.inner.query_by_correlation(correlation_id).await
+    }
+
+    async fn count(&self) -> EventStoreResult<u64> {
+        self.inner.count().await
+    }
+
+    async fn count_for_rfq(&self, rfq_id: RfqId) -> EventStoreResult<u64> {
+        self.inner.count_for_rfq(rfq_id).await
+    }
+
+    async fn next_sequence(&self, rfq_id: RfqId) -> EventStoreResult<u64> {
+        self.inner.next_sequence(rfq_id).await
+    }
+
+    async fn next_aggregate_sequence(&self, aggregate_id: Uuid) -> EventStoreResult<u64> {
+        self.inner.next_aggregate_sequence(aggregate_id).await
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::quote::Quote;
+    use crate::domain::entities::rfq::{Rfq, RfqBuilder};
+    use crate::domain::entities::trade::{SettlementState, Trade};
+    use crate::domain::events::rfq_events::QuoteCollectionStarted;
+    use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
+    use crate::domain::value_objects::{
+        CounterpartyId, FailureCode, FailureReason, Instrument, Quantity, Symbol, TradeId, VenueId,
+    };
+    use crate::infrastructure::persistence::RfqListFilter;
+    use crate::infrastructure::persistence::in_memory::{
+        InMemoryEventStore, InMemoryRfqSummaryStore,
+    };
+
+    struct Lifecycle {
+        rfq: Rfq,
+        trade: Option<Trade>,
+        events: Vec<StoredEvent>,
+    }
+
+    fn new_rfq(side: OrderSide) -> Rfq {
+        let instrument = Instrument::new(
+            Symbol::new("BTC/USD").unwrap(),
+            AssetClass::CryptoSpot,
+            SettlementMethod::OffChain,
+        );
+        RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            instrument,
+            side,
+            Quantity::new(2.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build()
+    }
+
+    /// Drives an RFQ through quoting and selection of its worst quote,
+    /// then executes or fails it, recording the events the use cases emit.
+    fn lifecycle(side: OrderSide, execute: bool) -> Lifecycle {
+        let mut rfq = new_rfq(side);
+        let rfq_id = rfq.id();
+        let mut created = RfqCreated::new(
+            rfq_id,
+            rfq.client_id().clone(),
+            rfq.instrument().clone(),
+            rfq.side(),
+            rfq.quantity(),
+            rfq.expires_at(),
+        );
+        created.metadata.timestamp = rfq.created_at();
+        let mut events = vec![StoredEvent::from_event(&created, 1).unwrap()];
+        let mut push = |event: StoredEvent| events.push(event);
+
+        let venues = [VenueId::new("venue-1"), VenueId::new("venue-2")];
+        rfq.start_quote_collection().unwrap();
+        push(
+            StoredEvent::from_event(&QuoteCollectionStarted::new(rfq_id, venues.to_vec()), 2)
+                .unwrap(),
+        );
+
+        for (venue, (price, sequence)) in venues.iter().zip([(101.0, 3), (99.0, 4)]) {
+            let quote = Quote::new(
+                rfq_id,
+                venue.clone(),
+                Price::new(price).unwrap(),
+                rfq.quantity(),
+                Timestamp::now().add_secs(60),
+            )
+            .unwrap();
+            push(
+                StoredEvent::from_event(
+                    &QuoteReceived::new(
+                        rfq_id,
+                        quote.id(),
+                        venue.clone(),
+                        quote.price(),
+                        quote.quantity(),
+                        quote.valid_until(),
+                    ),
+                    sequence,
+                )
+                .unwrap(),
+            );
+            rfq.receive_quote(quote).unwrap();
+        }
+
+        let selected = rfq.quotes().first().unwrap().clone();
+        rfq.select_quote(selected.id()).unwrap();
+        push(
+            StoredEvent::from_event(
+                &QuoteSelected::new(
+                    rfq_id,
+                    selected.id(),
+                    selected.venue_id().clone(),
+                    selected.price(),
+                ),
+                5,
+            )
+            .unwrap(),
+        );
+        rfq.start_execution().unwrap();
+        push(
+            StoredEvent::from_event(
+                &ExecutionStarted::new(rfq_id, selected.id(), selected.venue_id().clone()),
+                6,
+            )
+            .unwrap(),
+        );
+
+        let trade = if execute {
+            let trade = Trade::new(
+                rfq_id,
+                selected.id(),
+                selected.venue_id().clone(),
+                selected.price(),
+                selected.quantity(),
+            );
+            rfq.mark_executed().unwrap();
+            push(
+                StoredEvent::from_event(
+                    &TradeExecuted::builder()
+                        .rfq_id(rfq_id)
+                        .trade_id(trade.id())
+                        .quote_id(selected.id())
+                        .venue_id(trade.venue_id().clone())
+                        .counterparty_id(rfq.client_id().clone())
+                        .price(trade.price())
+                        .quantity(trade.quantity())
+                        .settlement_method(SettlementMethod::OffChain)
+                        .build(),
+                    7,
+                )
+                .unwrap(),
+            );
+            Some(trade)
+        } else {
+            let reason = FailureReason::new(FailureCode::VenueRejected, "venue rejected");
+            rfq.mark_failed(reason.clone()).unwrap();
+            push(
+                StoredEvent::from_event(&ExecutionFailed::new(rfq_id, selected.id(), &reason), 7)
+                    .unwrap(),
+            );
+            None
+        };
+
+        Lifecycle { rfq, trade, events }
+    }
+
+    /// The summary the aggregates imply, with event bookkeeping copied
+    /// from `projected`.
+    fn from_aggregates(rfq: &Rfq, trade: Option<&Trade>, projected: &RfqSummary) -> RfqSummary {
+        let prices = rfq.quotes().iter().map(Quote::price);
+        let best_quote_price = match rfq.side() {
+            OrderSide::Buy => prices.min(),
+            OrderSide::Sell => prices.max(),
+        };
+        RfqSummary {
+            rfq_id: rfq.id(),
+            client_id: rfq.client_id().clone(),
+            symbol: rfq.instrument().symbol().clone(),
+            side: rfq.side(),
+            quantity: rfq.quantity(),
+            state: rfq.state(),
+            quote_count: rfq.quote_count() as u32,
+            best_quote_price,
+            selected_venue_id: rfq.selected_quote().map(|q| q.venue_id().clone()),
+            trade_id: trade.map(Trade::id),
+            execution_price: trade.map(Trade::price),
+            failure_reason: rfq.failure_reason().map(|r| r.detail().to_string()),
+            created_at: rfq.created_at(),
+            last_event_at: projected.last_event_at,
+            last_sequence: projected.last_sequence,
+            aggregate_versions: projected.aggregate_versions.clone(),
+        }
+    }
+
+    async fn all_rows(store: &dyn RfqSummaryStore) -> Vec<RfqSummary> {
+        store
+            .list_after(None, usize::MAX, &RfqListFilter::default())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn projection_after_full_lifecycle_matches_aggregates() {
+        for (side, execute) in [
+            (OrderSide::Buy, true),
+            (OrderSide::Sell, true),
+            (OrderSide::Buy, false),
+        ] {
+            let store = Arc::new(InMemoryRfqSummaryStore::new());
+            let projection = RfqSummaryProjection::new(store.clone());
+            let lifecycle = lifecycle(side, execute);
+            for event in &lifecycle.events {
+                assert!(projection.apply(event).await.unwrap());
+            }
+
+            let projected = store.get(lifecycle.rfq.id()).await.unwrap().unwrap();
+            let expected = from_aggregates(&lifecycle.rfq, lifecycle.trade.as_ref(), &projected);
+            assert_eq!(projected, expected);
+            assert_eq!(projected.last_sequence, 7);
+            assert_eq!(projected.quote_count, 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn replayed_and_orphan_events_are_ignored() {
+        let store = Arc::new(InMemoryRfqSummaryStore::new());
+        let projection = RfqSummaryProjection::new(store.clone());
+        let lifecycle = lifecycle(OrderSide::Buy, true);
+
+        // Events before the RFQ's creation has been seen have nothing to update.
+        assert!(
+            !projection
+                .apply(lifecycle.events.last().unwrap())
+                .await
+                .unwrap()
+        );
+        assert!(all_rows(store.as_ref()).await.is_empty());
+
+        for event in &lifecycle.events {
+            projection.apply(event).await.unwrap();
+        }
+        let once = store.get(lifecycle.rfq.id()).await.unwrap().unwrap();
+        for event in &lifecycle.events {
+            assert!(!projection.apply(event).await.unwrap());
+        }
+        assert_eq!(store.get(lifecycle.rfq.id()).await.unwrap().unwrap(), once);
+    }
+
+    #[tokio::test]
+    async fn redelivered_versioned_events_are_applied_once() {
+        use crate::infrastructure::persistence::event_store::EventStoreError;
+
+        let store = Arc::new(InMemoryRfqSummaryStore::new());
+        let projection = RfqSummaryProjection::new(store.clone());
+        let lifecycle = lifecycle(OrderSide::Buy, true);
+        let rfq_id = lifecycle.rfq.id();
+        let versioned: Vec<StoredEvent> = lifecycle
+            .events
+            .into_iter()
+            .take(3)
+            .zip(1..)
+            .map(|(event, version)| {
+                event
+                    .with_aggregate_version(rfq_id.get(), version)
+                    .with_aggregate_sequence(version)
+            })
+            .collect();
+        for event in &versioned {
+            assert!(projection.apply(event).await.unwrap());
+        }
+        let once = store.get(rfq_id).await.unwrap().unwrap();
+        assert_eq!(once.quote_count, 1);
+        assert_eq!(once.aggregate_versions.get(&rfq_id.get()), Some(&3));
+
+        // A redelivery re-stored under a newer sequence is still skipped.
+        let mut redelivered = versioned[2].clone();
+        redelivered.event_id = crate::domain::value_objects::EventId::new_v4();
+        redelivered.sequence = 10;
+        assert!(!projection.apply(&redelivered).await.unwrap());
+        assert_eq!(store.get(rfq_id).await.unwrap().unwrap(), once);
+
+        // Through the decorator, the store refuses the duplicate version
+        // and the projection never sees it.
+        let event_store =
+            ProjectingEventStore::new(Arc::new(InMemoryEventStore::new()), Arc::new(projection));
+        store.clear().await.unwrap();
+        for event in &versioned {
+            event_store.append(event.clone()).await.unwrap();
+        }
+        let result = event_store.append(redelivered).await;
+        assert!(matches!(
+            result,
+            Err(EventStoreError::VersionConflict {
+                expected: 4,
+                actual: 3,
+                ..
+            })
+        ));
+        assert_eq!(store.get(rfq_id).await.unwrap().unwrap(), once);
+    }
+
+    #[tokio::test]
+    async fn forced_rfq_transitions_update_the_state() {
+        let store = Arc::new(InMemoryRfqSummaryStore::new());
+        let projection = RfqSummaryProjection::new(store.clone());
+        let mut lifecycle = lifecycle(OrderSide::Buy, true);
+        // Stop at ExecutionStarted, as if the venue never answered.
+        lifecycle.events.truncate(6);
+        let rfq_id = lifecycle.rfq.id();
+        let forced_settlement = ForcedStateTransition::trade(
+            rfq_id,
+            TradeId::new_v4(),
+            SettlementState::InProgress,
+            SettlementState::Failed,
+            "ops-1",
+            "tx dropped",
+        );
+        let forced_rfq = ForcedStateTransition::rfq(
+            rfq_id,
+            RfqState::Executing,
+            RfqState::Failed,
+            "ops-1",
+            "venue lost the order",
+        );
+        lifecycle
+            .events
+            .push(StoredEvent::from_event(&forced_settlement, 7).unwrap());
+        for event in &lifecycle.events {
+            projection.apply(event).await.unwrap();
+        }
+        let summary = store.get(rfq_id).await.unwrap().unwrap();
+        assert_eq!(summary.state, RfqState::Executing);
+
+        projection
+            .apply(&StoredEvent::from_event(&forced_rfq, 8).unwrap())
+            .await
+            .unwrap();
+
+        let summary = store.get(rfq_id).await.unwrap().unwrap();
+        assert_eq!(summary.state, RfqState::Failed);
+        assert_eq!(
+            summary.failure_reason.as_deref(),
+            Some("venue lost the order")
+        );
+    }
+
+    #[tokio::test]
+    async fn rebuild_produces_identical_rows() {
+        let store = Arc::new(InMemoryRfqSummaryStore::new());
+        let projection = Arc::new(RfqSummaryProjection::new(store.clone()));
+        let event_store =
+            ProjectingEventStore::new(Arc::new(InMemoryEventStore::new()), projection.clone());
+        for (side, execute) in [(OrderSide::Buy, true), (OrderSide::Sell, false)] {
+            for event in lifecycle(side, execute).events {
+                event_store.append(event).await.unwrap();
+            }
+        }
+        let live = all_rows(store.as_ref()).await;
+        assert_eq!(live.len(), 2);
+
+        let written = projection.rebuild(&event_store).await.unwrap();
+
+        assert_eq!(written, 2);
+        assert_eq!(all_rows(store.as_ref()).await, live);
+    }
+}
@@ -0,0 +1,101 @@
+//! # RFQ TTL Configuration Store
+//!
+//! Live RFQ TTL limits that administrators can change without a restart.
+//!
+//! [`RfqTtlConfigStore`] keeps the current [`RfqTtlPolicy`] behind an
+//! [`ArcSwap`], so RFQ creation reads it without locking and picks up a
+//! change on the next request. Changes are validated before they take
+//! effect and logged with who made them.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::domain::value_objects::rfq_ttl::RfqTtlPolicy;
+use arc_swap::ArcSwap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Current RFQ TTL limits.
+pub struct RfqTtlConfigStore {
+    current: ArcSwap<RfqTtlPolicy>,
+}
+
+impl fmt::Debug for RfqTtlConfigStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RfqTtlConfigStore")
+            .field("current", &self.current.load())
+            .finish()
+    }
+}
+
+impl RfqTtlConfigStore {
+    /// Creates a store starting from `initial`.
+    #[must_use]
+    pub fn new(initial: RfqTtlPolicy) -> Self {
+        Self {
+            current: ArcSwap::from_pointee(initial),
+        }
+    }
+
+    /// Returns the policy in force.
+    #[must_use]
+    pub fn current(&self) -> Arc<RfqTtlPolicy> {
+        self.current.load_full()
+    }
+
+    /// Replaces the policy on behalf of `changed_by`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::Validation` if any limits have a zero
+    /// minimum or a default outside their range.
+    pub fn update(
+        &self,
+        policy: RfqTtlPolicy,
+        changed_by: &str,
+    ) -> ApplicationResult<Arc<RfqTtlPolicy>> {
+        if !policy.is_valid() {
+            return Err(ApplicationError::validation(
+                "RFQ TTL limits need a positive minimum and a default between minimum and maximum",
+            ));
+        }
+
+        let policy = Arc::new(policy);
+        self.current.store(Arc::clone(&policy));
+        tracing::info!(
+            changed_by,
+            fallback = %policy.fallback,
+            asset_classes = policy.by_asset_class.len(),
+            "RFQ TTL configuration changed"
+        );
+        Ok(policy)
+    }
+}
+
+impl Default for RfqTtlConfigStore {
+    fn default() -> Self {
+        Self::new(RfqTtlPolicy::default())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::AssetClass;
+    use crate::domain::value_objects::rfq_ttl::TtlLimits;
+
+    #[test]
+    fn invalid_update_keeps_current_policy() {
+        let store = RfqTtlConfigStore::default();
+        let stocks = RfqTtlPolicy::default()
+            .with_asset_class(AssetClass::Stock, TtlLimits::new(30, 120, 600));
+
+        store.update(stocks.clone(), "admin-1").unwrap();
+        assert_eq!(*store.current(), stocks);
+
+        let err = store
+            .update(RfqTtlPolicy::new(TtlLimits::new(60, 30, 600)), "admin-1")
+            .unwrap_err();
+        assert!(err.is_validation());
+        assert_eq!(*store.current(), stocks);
+    }
+}
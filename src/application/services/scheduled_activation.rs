@@ -0,0 +1,376 @@
+//! # Scheduled RFQ Activation
+//!
+//! Background activation of RFQs created with a future `activate_at`.
+//!
+//! A scheduled RFQ is persisted immediately but stays in `Created`, and
+//! quote collection refuses it until its activation time. The
+//! [`ScheduledActivationService`] polls for RFQs whose activation time has
+//! been reached and hands each one to an [`RfqActivator`], which starts
+//! quote collection and emits `QuoteCollectionStarted`.
+//!
+//! With a [`TradingCalendarService`], an RFQ whose instrument follows a
+//! calendar that is closed at the time of the sweep is held back until a
+//! later sweep falls on a trading day; instruments settled on-chain trade
+//! 24/7 and are never held back.
+//!
+//! Cancelled or expired RFQs are no longer in `Created` and are never
+//! activated. An activation that fails leaves the RFQ in `Created`; it is
+//! retried by the next sweep until it activates or expires.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::application::services::trading_calendar::TradingCalendarService;
+use crate::application::use_cases::collect_quotes::CollectQuotesUseCase;
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::value_objects::RfqId;
+use crate::domain::value_objects::timestamp::{Clock, SystemClock, Timestamp};
+use crate::infrastructure::persistence::traits::RfqRepository;
+use async_trait::async_trait;
+use futures::future::join_all;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Starts quote collection for an RFQ whose activation time has come.
+#[async_trait]
+pub trait RfqActivator: Send + Sync + fmt::Debug {
+    /// Starts quote collection for `rfq_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if quote collection cannot be started.
+    async fn activate(&self, rfq_id: RfqId) -> ApplicationResult<()>;
+}
+
+#[async_trait]
+impl RfqActivator for CollectQuotesUseCase {
+    async fn activate(&self, rfq_id: RfqId) -> ApplicationResult<()> {
+        self.execute(rfq_id).await.map(|_| ())
+    }
+}
+
+/// Summary of a single activation sweep.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScheduledActivationReport {
+    /// RFQs whose quote collection was started.
+    pub activated: usize,
+    /// RFQs not activated because of an error; they are retried next sweep.
+    pub errors: usize,
+    /// Due RFQs held back because their market is closed.
+    pub deferred: usize,
+}
+
+/// Starts quote collection for scheduled RFQs once their activation time
+/// is reached.
+///
+/// The activator should share this service's clock so it agrees that the
+/// RFQ is due. Run a single instance per deployment: sweeps do not overlap,
+/// but two instances could activate the same RFQ twice.
+///
+/// # Examples
+///
+/// ```ignore
+/// let collect = CollectQuotesUseCase::with_defaults(rfqs, publisher, venues);
+/// let service = ScheduledActivationService::new(rfq_repository, Arc::new(collect));
+///
+/// tokio::spawn(async move { service.run(Duration::from_secs(1)).await });
+/// ```
+#[derive(Debug)]
+pub struct ScheduledActivationService {
+    rfq_repository: Arc<dyn RfqRepository>,
+    activator: Arc<dyn RfqActivator>,
+    calendars: Option<Arc<TradingCalendarService>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ScheduledActivationService {
+    /// Creates a new service using the system clock.
+    #[must_use]
+    pub fn new(rfq_repository: Arc<dyn RfqRepository>, activator: Arc<dyn RfqActivator>) -> Self {
+        Self {
+            rfq_repository,
+            activator,
+            calendars: None,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Sets the trading calendars that hold back activation on closed days.
+    #[must_use]
+    pub fn with_calendars(mut self, calendars: Arc<TradingCalendarService>) -> Self {
+        self.calendars = Some(calendars);
+        self
+    }
+
+    /// Sets the clock used to decide whether an RFQ is due.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Activates due RFQs every `interval`, forever.
+    pub async fn run(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match self.run_once().await {
+                Ok(report) => tracing::debug!(?report, "scheduled RFQ activation sweep completed"),
+                Err(e) => tracing::error!(error = %e, "scheduled RFQ activation sweep failed"),
+            }
+        }
+    }
+
+    /// Activates every scheduled RFQ whose activation time has been reached.
+    ///
+    /// Due RFQs are activated concurrently, except those whose market is
+    /// closed. Errors on individual RFQs are logged and counted; the RFQ
+    /// stays scheduled and is picked up again by the next sweep.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pending RFQs cannot be loaded.
+    pub async fn run_once(&self) -> ApplicationResult<ScheduledActivationReport> {
+        let now = self.clock.now();
+        let due = self
+            .rfq_repository
+            .find_pending_activation(now)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?;
+
+        let mut report = ScheduledActivationReport::default();
+        let mut open = Vec::with_capacity(due.len());
+        for rfq in &due {
+            match self.is_market_open(rfq, now).await {
+                Ok(true) => open.push(rfq),
+                Ok(false) => {
+                    tracing::debug!(rfq_id = %rfq.id(), "scheduled RFQ held until its market opens");
+                    report.deferred += 1;
+                }
+                Err(e) => {
+                    tracing::warn!(rfq_id = %rfq.id(), error = %e, "trading calendar unavailable");
+                    report.errors += 1;
+                }
+            }
+        }
+
+        let activations = open.into_iter().map(|rfq| async move {
+            let result = self.activator.activate(rfq.id()).await;
+            match &result {
+                Ok(()) => tracing::info!(
+                    rfq_id = %rfq.id(),
+                    activate_at = ?rfq.activate_at(),
+                    activated_at = %now,
+                    "scheduled RFQ activated"
+                ),
+                Err(e) => {
+                    tracing::warn!(rfq_id = %rfq.id(), error = %e, "scheduled RFQ activation failed");
+                }
+            }
+            result
+        });
+
+        for result in join_all(activations).await {
+            match result {
+                Ok(()) => report.activated += 1,
+                Err(_) => report.errors += 1,
+            }
+        }
+        Ok(report)
+    }
+
+    /// Returns true if the market of `rfq`'s instrument is open at `now`.
+    async fn is_market_open(&self, rfq: &Rfq, now: Timestamp) -> ApplicationResult<bool> {
+        let Some(calendars) = &self.calendars else {
+            return Ok(true);
+        };
+        let calendar = calendars
+            .calendar_for(rfq.instrument().settlement_method())
+            .await?;
+        Ok(calendar.is_trading_time(now))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::rfq::{Rfq, RfqBuilder};
+    use crate::domain::value_objects::enums::AssetClass;
+    use crate::domain::value_objects::timestamp::MockClock;
+    use crate::domain::value_objects::{
+        CounterpartyId, Instrument, OrderSide, Quantity, RfqState, SettlementMethod, Symbol,
+        TradingCalendar,
+    };
+    use crate::infrastructure::persistence::in_memory::{
+        InMemoryRfqRepository, InMemoryTradingCalendarRepository,
+    };
+    use crate::infrastructure::persistence::traits::TradingCalendarRepository;
+    use std::sync::Mutex;
+
+    /// Activator that starts collection directly on the stored RFQ.
+    #[derive(Debug)]
+    struct RecordingActivator {
+        repository: Arc<InMemoryRfqRepository>,
+        activated: Mutex<Vec<RfqId>>,
+    }
+
+    #[async_trait]
+    impl RfqActivator for RecordingActivator {
+        async fn activate(&self, rfq_id: RfqId) -> ApplicationResult<()> {
+            let mut rfq = self.repository.get(rfq_id).await.unwrap().unwrap();
+            rfq.start_quote_collection()?;
+            self.repository
+                .save(&rfq)
+                .await
+                .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?;
+            self.activated.lock().unwrap().push(rfq_id);
+            Ok(())
+        }
+    }
+
+    fn rfq_activating_at(activate_at: Timestamp) -> Rfq {
+        let instrument =
+            Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build();
+        RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            activate_at.add_secs(300),
+        )
+        .activate_at(activate_at)
+        .try_build()
+        .unwrap()
+    }
+
+    fn setup() -> (
+        ScheduledActivationService,
+        Arc<InMemoryRfqRepository>,
+        Arc<RecordingActivator>,
+        Arc<MockClock>,
+    ) {
+        let repo = Arc::new(InMemoryRfqRepository::new());
+        let activator = Arc::new(RecordingActivator {
+            repository: Arc::clone(&repo),
+            activated: Mutex::new(Vec::new()),
+        });
+        let clock = Arc::new(MockClock::new(Timestamp::now()));
+        let service = ScheduledActivationService::new(
+            Arc::clone(&repo) as Arc<dyn RfqRepository>,
+            Arc::clone(&activator) as Arc<dyn RfqActivator>,
+        )
+        .with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+        (service, repo, activator, clock)
+    }
+
+    #[tokio::test]
+    async fn activates_exactly_at_activation_time() {
+        let (service, repo, activator, clock) = setup();
+        let rfq = rfq_activating_at(clock.now().add_secs(30));
+        repo.save(&rfq).await.unwrap();
+
+        clock.set(rfq.activate_at().unwrap().sub_secs(1));
+        assert_eq!(
+            service.run_once().await.unwrap(),
+            ScheduledActivationReport::default()
+        );
+
+        clock.set(rfq.activate_at().unwrap());
+        let report = service.run_once().await.unwrap();
+        assert_eq!(report.activated, 1);
+        assert_eq!(report.errors, 0);
+        assert_eq!(*activator.activated.lock().unwrap(), vec![rfq.id()]);
+
+        let stored = repo.get(rfq.id()).await.unwrap().unwrap();
+        assert_eq!(stored.state(), RfqState::QuoteRequesting);
+        assert_eq!(
+            service.run_once().await.unwrap(),
+            ScheduledActivationReport::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn cancelled_rfq_is_never_activated() {
+        let (service, repo, activator, clock) = setup();
+        let mut rfq = rfq_activating_at(clock.now().add_secs(30));
+        repo.save(&rfq).await.unwrap();
+
+        rfq.cancel().unwrap();
+        repo.save(&rfq).await.unwrap();
+
+        clock.advance_secs(60);
+        assert_eq!(
+            service.run_once().await.unwrap(),
+            ScheduledActivationReport::default()
+        );
+        assert!(activator.activated.lock().unwrap().is_empty());
+        let stored = repo.get(rfq.id()).await.unwrap().unwrap();
+        assert_eq!(stored.state(), RfqState::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn unscheduled_rfqs_are_ignored() {
+        let (service, repo, activator, clock) = setup();
+        let instrument =
+            Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build();
+        let immediate = RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            clock.now().add_secs(300),
+        )
+        .build();
+        repo.save(&immediate).await.unwrap();
+
+        assert_eq!(service.run_once().await.unwrap().activated, 0);
+        assert!(activator.activated.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn tradfi_rfq_waits_for_a_trading_day() {
+        let (service, repo, activator, clock) = setup();
+        let calendars = Arc::new(InMemoryTradingCalendarRepository::new());
+        calendars
+            .save(&TradingCalendar::new("NYSE", chrono_tz::UTC, true).unwrap())
+            .await
+            .unwrap();
+        let service = service.with_calendars(Arc::new(
+            TradingCalendarService::new(calendars as Arc<dyn TradingCalendarRepository>)
+                .with_offchain_calendar("NYSE"),
+        ));
+
+        // Saturday 2030-01-05 12:00 UTC
+        let saturday = Timestamp::from_secs(1_893_844_800).unwrap();
+        clock.set(saturday);
+        let crypto = rfq_activating_at(saturday);
+        let instrument = Instrument::builder(Symbol::new("EUR/USD").unwrap(), AssetClass::Forex)
+            .settlement_method(SettlementMethod::OffChain)
+            .build();
+        let tradfi = RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            saturday.add_secs(3 * 86_400),
+        )
+        .activate_at(saturday)
+        .try_build()
+        .unwrap();
+        repo.save(&crypto).await.unwrap();
+        repo.save(&tradfi).await.unwrap();
+
+        let report = service.run_once().await.unwrap();
+        assert_eq!(report.activated, 1);
+        assert_eq!(report.deferred, 1);
+        assert_eq!(*activator.activated.lock().unwrap(), vec![crypto.id()]);
+
+        // Monday
+        clock.set(saturday.add_secs(2 * 86_400));
+        let report = service.run_once().await.unwrap();
+        assert_eq!(report.activated, 1);
+        assert_eq!(report.deferred, 0);
+        let stored = repo.get(tradfi.id()).await.unwrap().unwrap();
+        assert_eq!(stored.state(), RfqState::QuoteRequesting);
+    }
+}
@@ -0,0 +1,428 @@
+//! # Settlement Addresses
+//!
+//! Management and verification of counterparty settlement addresses.
+//!
+//! A counterparty registers one address per chain, optionally overridden
+//! per token. New addresses start unverified. To verify one, the
+//! counterparty asks for a challenge, signs its message with the address's
+//! key (`personal_sign`) and submits the signature; if the signer is the
+//! registered address, [`SettlementAddressService::verify`] marks it
+//! verified.
+//!
+//! Challenges are single-use and expire after a TTL. Replacing or removing
+//! an address drops its pending challenge.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::domain::entities::counterparty::{Counterparty, SettlementAddress, WalletAddress};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{Blockchain, CounterpartyId};
+use crate::infrastructure::blockchain::{normalize_address, recover_signer};
+use crate::infrastructure::persistence::traits::CounterpartyRepository;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Default time a verification challenge stays valid.
+pub const DEFAULT_ADDRESS_CHALLENGE_TTL: Duration = Duration::from_secs(300);
+
+/// A nonce the counterparty must sign to prove control of an address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressChallenge {
+    /// The counterparty being verified.
+    pub counterparty_id: CounterpartyId,
+    /// Chain of the address.
+    pub chain: Blockchain,
+    /// Token of the address, `None` for the chain default.
+    pub token: Option<String>,
+    /// The address that must sign.
+    pub address: String,
+    /// Random single-use nonce.
+    pub nonce: String,
+    /// Exact text to sign.
+    pub message: String,
+    /// When the challenge stops being accepted.
+    pub expires_at: Timestamp,
+}
+
+impl AddressChallenge {
+    fn message_for(
+        counterparty_id: &CounterpartyId,
+        chain: Blockchain,
+        token: Option<&str>,
+        address: &str,
+        nonce: &str,
+    ) -> String {
+        format!(
+            "otc-rfq settlement address verification\n\
+             Counterparty: {counterparty_id}\n\
+             Chain: {chain} ({})\n\
+             Token: {}\n\
+             Address: {address}\n\
+             Nonce: {nonce}",
+            chain.chain_id(),
+            token.unwrap_or("*"),
+        )
+    }
+}
+
+type ChallengeKey = (CounterpartyId, Blockchain, Option<String>);
+
+/// Adds, removes and verifies counterparty settlement addresses.
+pub struct SettlementAddressService {
+    counterparties: Arc<dyn CounterpartyRepository>,
+    challenges: Mutex<HashMap<ChallengeKey, AddressChallenge>>,
+    challenge_ttl: Duration,
+}
+
+impl SettlementAddressService {
+    /// Creates a service storing addresses in `counterparties`.
+    #[must_use]
+    pub fn new(counterparties: Arc<dyn CounterpartyRepository>) -> Self {
+        Self {
+            counterparties,
+            challenges: Mutex::new(HashMap::new()),
+            challenge_ttl: DEFAULT_ADDRESS_CHALLENGE_TTL,
+        }
+    }
+
+    /// Sets how long a verification challenge stays valid.
+    #[must_use]
+    pub fn with_challenge_ttl(mut self, ttl: Duration) -> Self {
+        self.challenge_ttl = ttl;
+        self
+    }
+
+    /// Returns the settlement addresses of a counterparty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::NotFound` if the counterparty does not
+    /// exist, or `ApplicationError::RepositoryError` if loading fails.
+    pub async fn list(&self, id: &CounterpartyId) -> ApplicationResult<Vec<SettlementAddress>> {
+        Ok(self.load(id).await?.settlement_addresses().to_vec())
+    }
+
+    /// Registers an unverified settlement address.
+    ///
+    /// The address is stored lowercase.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::Validation` if the address is not a valid
+    /// EVM address, `ApplicationError::NotFound` if the counterparty does
+    /// not exist, and a `DomainError::ConflictDetected` if the chain
+    /// already has an address for the token.
+    pub async fn add(
+        &self,
+        id: &CounterpartyId,
+        chain: Blockchain,
+        token: Option<String>,
+        address: &str,
+        label: Option<String>,
+    ) -> ApplicationResult<SettlementAddress> {
+        let address = normalize_address(address)
+            .map_err(|_| ApplicationError::validation(format!("invalid address: {address}")))?;
+        let wallet = match label {
+            Some(label) => WalletAddress::with_label(chain, address, label),
+            None => WalletAddress::new(chain, address),
+        };
+        let entry = SettlementAddress::new(wallet, token);
+
+        let mut counterparty = self.load(id).await?;
+        counterparty.add_settlement_address(entry.clone())?;
+        self.save(&counterparty).await?;
+        self.challenges
+            .lock()
+            .remove(&challenge_key(id, chain, entry.token()));
+        Ok(entry)
+    }
+
+    /// Removes the settlement address for `token` on `chain`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::NotFound` if the counterparty or the
+    /// address does not exist.
+    pub async fn remove(
+        &self,
+        id: &CounterpartyId,
+        chain: Blockchain,
+        token: Option<&str>,
+    ) -> ApplicationResult<SettlementAddress> {
+        let mut counterparty = self.load(id).await?;
+        let removed = counterparty
+            .remove_settlement_address(chain, token)
+            .ok_or_else(|| address_not_found(id, chain, token))?;
+        self.save(&counterparty).await?;
+        self.challenges
+            .lock()
+            .remove(&challenge_key(id, chain, token));
+        Ok(removed)
+    }
+
+    /// Issues a challenge for the settlement address for `token` on `chain`.
+    ///
+    /// A new challenge replaces any pending one for the same address.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::NotFound` if the counterparty or the
+    /// address does not exist.
+    pub async fn challenge(
+        &self,
+        id: &CounterpartyId,
+        chain: Blockchain,
+        token: Option<&str>,
+    ) -> ApplicationResult<AddressChallenge> {
+        let counterparty = self.load(id).await?;
+        let entry = exact_address(&counterparty, chain, token)?;
+        let address = entry.address().address().to_string();
+        let nonce = uuid::Uuid::new_v4().simple().to_string();
+        let challenge = AddressChallenge {
+            message: AddressChallenge::message_for(id, chain, entry.token(), &address, &nonce),
+            counterparty_id: id.clone(),
+            chain,
+            token: entry.token().map(str::to_string),
+            address,
+            nonce,
+            expires_at: Timestamp::now()
+                .add_millis(i64::try_from(self.challenge_ttl.as_millis()).unwrap_or(i64::MAX)),
+        };
+
+        let now = Timestamp::now();
+        let mut challenges = self.challenges.lock();
+        challenges.retain(|_, pending| !pending.expires_at.is_before(&now));
+        challenges.insert(challenge_key(id, chain, entry.token()), challenge.clone());
+        Ok(challenge)
+    }
+
+    /// Verifies the settlement address for `token` on `chain` with the
+    /// signature of its pending challenge.
+    ///
+    /// The challenge is consumed whether or not the signature matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::Validation` if there is no pending
+    /// challenge, it expired, or `signature` was not made by the address,
+    /// and `ApplicationError::NotFound` if the counterparty or the address
+    /// does not exist.
+    pub async fn verify(
+        &self,
+        id: &CounterpartyId,
+        chain: Blockchain,
+        token: Option<&str>,
+        signature: &str,
+    ) -> ApplicationResult<SettlementAddress> {
+        let challenge = self
+            .challenges
+            .lock()
+            .remove(&challenge_key(id, chain, token))
+            .ok_or_else(|| {
+                ApplicationError::validation(format!(
+                    "no pending challenge for {} on {}",
+                    id, chain
+                ))
+            })?;
+        if challenge.expires_at.is_before(&Timestamp::now()) {
+            return Err(ApplicationError::validation(format!(
+                "challenge for {} on {} expired",
+                id, chain
+            )));
+        }
+        let signer = recover_signer(&challenge.message, signature)
+            .map_err(|e| ApplicationError::validation(e.to_string()))?;
+        if !signer.eq_ignore_ascii_case(&challenge.address) {
+            return Err(ApplicationError::validation(format!(
+                "signature was made by {}, not {}",
+                signer, challenge.address
+            )));
+        }
+
+        let mut counterparty = self.load(id).await?;
+        counterparty.verify_settlement_address(chain, token, &challenge.address)?;
+        self.save(&counterparty).await?;
+        Ok(exact_address(&counterparty, chain, token)?.clone())
+    }
+
+    async fn load(&self, id: &CounterpartyId) -> ApplicationResult<Counterparty> {
+        self.counterparties
+            .get(id)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?
+            .ok_or_else(|| ApplicationError::not_found("Counterparty", id.as_str()))
+    }
+
+    async fn save(&self, counterparty: &Counterparty) -> ApplicationResult<()> {
+        self.counterparties
+            .save(counterparty)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))
+    }
+}
+
+impl fmt::Debug for SettlementAddressService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SettlementAddressService")
+            .field("pending_challenges", &self.challenges.lock().len())
+            .field("challenge_ttl", &self.challenge_ttl)
+            .finish()
+    }
+}
+
+fn challenge_key(id: &CounterpartyId, chain: Blockchain, token: Option<&str>) -> ChallengeKey {
+    (id.clone(), chain, token.map(str::to_ascii_uppercase))
+}
+
+/// Returns the address registered for exactly `token`, without falling
+/// back to the chain default.
+fn exact_address<'a>(
+    counterparty: &'a Counterparty,
+    chain: Blockchain,
+    token: Option<&str>,
+) -> ApplicationResult<&'a SettlementAddress> {
+    counterparty
+        .settlement_addresses()
+        .iter()
+        .find(|a| a.is_for(chain, token))
+        .ok_or_else(|| address_not_found(counterparty.id(), chain, token))
+}
+
+fn address_not_found(
+    id: &CounterpartyId,
+    chain: Blockchain,
+    token: Option<&str>,
+) -> ApplicationError {
+    ApplicationError::not_found(
+        "Settlement address",
+        format!("{} {} {}", id, chain, token.unwrap_or("*")),
+    )
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::CounterpartyType;
+    use crate::infrastructure::persistence::in_memory::InMemoryCounterpartyRepository;
+    use ethers::signers::{LocalWallet, Signer};
+    use std::str::FromStr;
+
+    const TEST_KEY: &str = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    const OTHER_KEY: &str = "0123456789012345678901234567890123456789012345678901234567890123";
+
+    fn id() -> CounterpartyId {
+        CounterpartyId::new("client-1")
+    }
+
+    async fn service_with(wallet: &LocalWallet) -> SettlementAddressService {
+        let repository = Arc::new(InMemoryCounterpartyRepository::new());
+        repository
+            .save(&Counterparty::new(
+                id(),
+                "Client 1",
+                CounterpartyType::Client,
+            ))
+            .await
+            .unwrap();
+        let service = SettlementAddressService::new(repository);
+        service
+            .add(
+                &id(),
+                Blockchain::Polygon,
+                None,
+                &format!("{:#x}", wallet.address()),
+                None,
+            )
+            .await
+            .unwrap();
+        service
+    }
+
+    #[tokio::test]
+    async fn verifies_an_address_signed_by_its_key() {
+        let wallet = LocalWallet::from_str(TEST_KEY).unwrap();
+        let service = service_with(&wallet).await;
+
+        let challenge = service
+            .challenge(&id(), Blockchain::Polygon, None)
+            .await
+            .unwrap();
+        let signature = wallet.sign_message(&challenge.message).await.unwrap();
+        let verified = service
+            .verify(&id(), Blockchain::Polygon, None, &signature.to_string())
+            .await
+            .unwrap();
+
+        assert!(verified.is_verified());
+        assert!(
+            service
+                .list(&id())
+                .await
+                .unwrap()
+                .iter()
+                .all(SettlementAddress::is_verified)
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_a_signature_from_another_key() {
+        let wallet = LocalWallet::from_str(TEST_KEY).unwrap();
+        let impostor = LocalWallet::from_str(OTHER_KEY).unwrap();
+        let service = service_with(&wallet).await;
+
+        let challenge = service
+            .challenge(&id(), Blockchain::Polygon, None)
+            .await
+            .unwrap();
+        let signature = impostor.sign_message(&challenge.message).await.unwrap();
+        let result = service
+            .verify(&id(), Blockchain::Polygon, None, &signature.to_string())
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::Validation(_))));
+        assert!(
+            !service
+                .list(&id())
+                .await
+                .unwrap()
+                .iter()
+                .any(SettlementAddress::is_verified)
+        );
+    }
+
+    #[tokio::test]
+    async fn challenges_are_single_use() {
+        let wallet = LocalWallet::from_str(TEST_KEY).unwrap();
+        let service = service_with(&wallet).await;
+
+        let challenge = service
+            .challenge(&id(), Blockchain::Polygon, None)
+            .await
+            .unwrap();
+        let signature = wallet.sign_message(&challenge.message).await.unwrap();
+        service
+            .verify(&id(), Blockchain::Polygon, None, &signature.to_string())
+            .await
+            .unwrap();
+
+        let replay = service
+            .verify(&id(), Blockchain::Polygon, None, &signature.to_string())
+            .await;
+        assert!(matches!(replay, Err(ApplicationError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_addresses() {
+        let wallet = LocalWallet::from_str(TEST_KEY).unwrap();
+        let service = service_with(&wallet).await;
+
+        let result = service
+            .add(&id(), Blockchain::Arbitrum, None, "not-an-address", None)
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::Validation(_))));
+    }
+}
@@ -0,0 +1,867 @@
+//! # Settlement Retry
+//!
+//! Background retry of failed trade settlements.
+//!
+//! [`SettlementRetryService`] scans trades in the `Failed` settlement state
+//! whose retry is due, resubmits the settlement transaction through the
+//! [`BlockchainClient`], and schedules the next attempt with the backoff of a
+//! [`RetryPolicy`]. Once the policy's retries are exhausted the trade moves to
+//! `DeadLettered` and a [`SettlementDeadLettered`] event is published so that
+//! operators are alerted.
+//!
+//! # Double Execution
+//!
+//! A settlement can fail after its transaction was broadcast (e.g. a
+//! confirmation timeout). Before resubmitting, the service looks up the
+//! stored transaction hash: if it confirmed successfully the trade is
+//! reconciled to `Settled` without sending anything, and if it is still
+//! pending the service waits for it without spending an attempt. A new
+//! transaction is only sent once the original reverted or was dropped.
+//!
+//! # Preflight
+//!
+//! With a [`SettlementPreflight`] configured, token settlements check the
+//! ERC-20 balance and allowance of the settling wallet before broadcasting.
+//! A shortfall fails the attempt without spending gas on a transaction that
+//! would revert.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::application::services::retry::RetryPolicy;
+use crate::domain::entities::trade::Trade;
+use crate::domain::events::SettlementDeadLettered;
+use crate::domain::value_objects::timestamp::{Clock, SystemClock};
+use crate::infrastructure::blockchain::{
+    BlockchainClient, BlockchainError, ChainId, SettlementPreflight, TxHash, TxPriority, TxReceipt,
+};
+use crate::infrastructure::metrics;
+use crate::infrastructure::persistence::traits::TradeRepository;
+use async_trait::async_trait;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// An unsigned settlement transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettlementTx {
+    /// Destination address.
+    pub to: String,
+    /// Transaction calldata.
+    pub data: Vec<u8>,
+    /// Value in wei.
+    pub value: u128,
+    /// ERC-20 transfer the destination contract pulls, if any.
+    pub token_transfer: Option<TokenTransfer>,
+}
+
+/// An ERC-20 amount a settlement contract pulls with `transferFrom`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenTransfer {
+    /// Token contract address.
+    pub token: String,
+    /// Wallet the tokens are pulled from.
+    pub from: String,
+    /// Amount in base units.
+    pub amount: u128,
+}
+
+/// Builds the on-chain transaction that settles a trade.
+#[async_trait]
+pub trait SettlementTxBuilder: Send + Sync + fmt::Debug {
+    /// Builds the settlement transaction for `trade`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    async fn build(&self, trade: &Trade) -> ApplicationResult<SettlementTx>;
+}
+
+/// Publisher for settlement retry events.
+#[async_trait]
+pub trait SettlementEventPublisher: Send + Sync + fmt::Debug {
+    /// Publishes a settlement dead-lettered event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be published.
+    async fn publish_settlement_dead_lettered(
+        &self,
+        event: SettlementDeadLettered,
+    ) -> ApplicationResult<()>;
+}
+
+/// Configuration for [`SettlementRetryService`].
+#[derive(Debug, Clone)]
+pub struct SettlementRetryConfig {
+    /// Backoff between attempts; `max_retries` bounds the attempts per trade.
+    pub policy: RetryPolicy,
+    /// Maximum number of trades processed per scan.
+    pub batch_size: usize,
+    /// Confirmations to wait for after resubmitting.
+    pub confirmations: u64,
+    /// Gas priority for resubmitted transactions.
+    pub priority: TxPriority,
+    /// Chain settlements are submitted to.
+    pub chain_id: ChainId,
+}
+
+impl Default for SettlementRetryConfig {
+    fn default() -> Self {
+        Self {
+            policy: RetryPolicy::new(5, 30_000, 3_600_000, 2.0, 0.1),
+            batch_size: 50,
+            confirmations: 1,
+            priority: TxPriority::High,
+            chain_id: ChainId::Ethereum,
+        }
+    }
+}
+
+/// Result of processing a single failed trade.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettlementRetryOutcome {
+    /// The resubmitted transaction confirmed.
+    Settled,
+    /// The original transaction had confirmed; nothing was resubmitted.
+    Reconciled,
+    /// The original transaction is still pending; it is checked again later.
+    AwaitingOriginal,
+    /// The retry failed and another attempt was scheduled.
+    Rescheduled,
+    /// Retries were exhausted and the trade was dead-lettered.
+    DeadLettered,
+}
+
+/// Summary of a single scan.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SettlementRetryReport {
+    /// Trades settled by a resubmitted transaction.
+    pub settled: usize,
+    /// Trades whose original transaction had confirmed.
+    pub reconciled: usize,
+    /// Trades whose original transaction is still pending.
+    pub awaiting_original: usize,
+    /// Trades scheduled for another attempt.
+    pub rescheduled: usize,
+    /// Trades moved to `DeadLettered`.
+    pub dead_lettered: usize,
+    /// Trades skipped because of an error; they stay due for the next scan.
+    pub errors: usize,
+}
+
+impl SettlementRetryReport {
+    fn record(&mut self, outcome: SettlementRetryOutcome) {
+        match outcome {
+            SettlementRetryOutcome::Settled => self.settled += 1,
+            SettlementRetryOutcome::Reconciled => self.reconciled += 1,
+            SettlementRetryOutcome::AwaitingOriginal => self.awaiting_original += 1,
+            SettlementRetryOutcome::Rescheduled => self.rescheduled += 1,
+            SettlementRetryOutcome::DeadLettered => self.dead_lettered += 1,
+        }
+    }
+}
+
+/// What became of the settlement transaction stored on a failed trade.
+#[derive(Debug)]
+enum OriginalTx {
+    /// It confirmed successfully.
+    Confirmed(TxReceipt),
+    /// It is known to the node but not mined yet.
+    Pending,
+    /// There is none, it reverted, or it was dropped: a new one may be sent.
+    Gone,
+}
+
+/// Retries failed settlements with exponential backoff.
+///
+/// # Examples
+///
+/// ```ignore
+/// let service = SettlementRetryService::new(
+///     trade_repository,
+///     blockchain_client,
+///     tx_builder,
+///     event_publisher,
+///     SettlementRetryConfig::default(),
+/// );
+///
+/// tokio::spawn(async move { service.run(Duration::from_secs(30)).await });
+/// ```
+#[derive(Debug)]
+pub struct SettlementRetryService {
+    trade_repository: Arc<dyn TradeRepository>,
+    blockchain: Arc<dyn BlockchainClient>,
+    tx_builder: Arc<dyn SettlementTxBuilder>,
+    event_publisher: Arc<dyn SettlementEventPublisher>,
+    config: SettlementRetryConfig,
+    clock: Arc<dyn Clock>,
+    preflight: Option<Arc<SettlementPreflight>>,
+}
+
+impl SettlementRetryService {
+    /// Creates a new settlement retry service.
+    #[must_use]
+    pub fn new(
+        trade_repository: Arc<dyn TradeRepository>,
+        blockchain: Arc<dyn BlockchainClient>,
+        tx_builder: Arc<dyn SettlementTxBuilder>,
+        event_publisher: Arc<dyn SettlementEventPublisher>,
+        config: SettlementRetryConfig,
+    ) -> Self {
+        Self {
+            trade_repository,
+            blockchain,
+            tx_builder,
+            event_publisher,
+            config,
+            clock: Arc::new(SystemClock),
+            preflight: None,
+        }
+    }
+
+    /// Sets the clock used to decide which retries are due.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Checks token balances and allowances before each submission.
+    #[must_use]
+    pub fn with_preflight(mut self, preflight: Arc<SettlementPreflight>) -> Self {
+        self.preflight = Some(preflight);
+        self
+    }
+
+    /// Scans for due retries every `interval`, forever.
+    pub async fn run(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match self.run_once().await {
+                Ok(report) => tracing::debug!(?report, "Settlement retry scan completed"),
+                Err(e) => tracing::error!(error = %e, "Settlement retry scan failed"),
+            }
+        }
+    }
+
+    /// Processes every failed trade whose retry is due.
+    ///
+    /// Errors on individual trades are logged and counted; the trade stays
+    /// due and is picked up again by the next scan.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the due trades cannot be loaded.
+    pub async fn run_once(&self) -> ApplicationResult<SettlementRetryReport> {
+        let due = self
+            .trade_repository
+            .find_due_for_settlement_retry(self.clock.now(), self.config.batch_size)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?;
+
+        let mut report = SettlementRetryReport::default();
+        for mut trade in due {
+            match self.process(&mut trade).await {
+                Ok(outcome) => report.record(outcome),
+                Err(e) => {
+                    tracing::warn!(
+                        trade_id = %trade.id(),
+                        error = %e,
+                        "Settlement retry skipped"
+                    );
+                    report.errors += 1;
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Retries settlement of a single failed trade.
+    async fn process(&self, trade: &mut Trade) -> ApplicationResult<SettlementRetryOutcome> {
+        match self.original_tx(trade).await? {
+            OriginalTx::Confirmed(receipt) => {
+                trade.reconcile_settlement(receipt.tx_hash.as_str())?;
+                self.add_gas_fee(trade, &receipt);
+                self.save(trade).await?;
+                tracing::info!(
+                    trade_id = %trade.id(),
+                    tx_hash = %receipt.tx_hash,
+                    "Settlement reconciled from confirmed transaction"
+                );
+                return Ok(SettlementRetryOutcome::Reconciled);
+            }
+            OriginalTx::Pending => {
+                // Resubmitting now could settle the trade twice.
+                self.schedule_retry(trade)?;
+                self.save(trade).await?;
+                tracing::info!(
+                    trade_id = %trade.id(),
+                    tx_hash = trade.settlement_tx_ref().unwrap_or_default(),
+                    "Settlement transaction still pending, not resubmitting"
+                );
+                return Ok(SettlementRetryOutcome::AwaitingOriginal);
+            }
+            OriginalTx::Gone => {}
+        }
+
+        if !self.config.policy.should_retry(trade.settlement_attempts()) {
+            return self.dead_letter(trade).await;
+        }
+
+        // Persist the attempt before submitting so a concurrent worker
+        // loses the optimistic lock instead of submitting twice.
+        trade.retry_settlement()?;
+        self.save(trade).await?;
+
+        match self.submit(trade).await {
+            Ok(receipt) => {
+                trade.confirm_settlement(receipt.tx_hash.as_str())?;
+                self.add_gas_fee(trade, &receipt);
+                self.save(trade).await?;
+                Ok(SettlementRetryOutcome::Settled)
+            }
+            Err(reason) => {
+                trade.fail_settlement(reason)?;
+                if self.config.policy.should_retry(trade.settlement_attempts()) {
+                    self.schedule_retry(trade)?;
+                    self.save(trade).await?;
+                    Ok(SettlementRetryOutcome::Rescheduled)
+                } else {
+                    self.dead_letter(trade).await
+                }
+            }
+        }
+    }
+
+    /// Schedules the next attempt with the backoff for the attempts made.
+    fn schedule_retry(&self, trade: &mut Trade) -> ApplicationResult<()> {
+        let delay = self
+            .config
+            .policy
+            .calculate_delay_with_jitter(trade.settlement_attempts().saturating_sub(1));
+        let next = self
+            .clock
+            .now()
+            .add_millis(i64::try_from(delay.as_millis()).unwrap_or(i64::MAX));
+        trade.schedule_settlement_retry(next)?;
+        Ok(())
+    }
+
+    /// Looks up what became of the stored settlement transaction.
+    async fn original_tx(&self, trade: &Trade) -> ApplicationResult<OriginalTx> {
+        let Some(tx_ref) = trade.settlement_tx_ref() else {
+            return Ok(OriginalTx::Gone);
+        };
+        let tx_hash = TxHash::new(tx_ref);
+
+        // An RPC error must not fall through to a resubmission.
+        let rpc_error = |e: BlockchainError| ApplicationError::ExecutionFailed(e.to_string());
+        match self
+            .blockchain
+            .get_transaction_receipt(&tx_hash)
+            .await
+            .map_err(rpc_error)?
+        {
+            Some(receipt) if receipt.success => Ok(OriginalTx::Confirmed(receipt)),
+            Some(_) => Ok(OriginalTx::Gone),
+            None if self
+                .blockchain
+                .is_transaction_pending(&tx_hash)
+                .await
+                .map_err(rpc_error)? =>
+            {
+                Ok(OriginalTx::Pending)
+            }
+            None => Ok(OriginalTx::Gone),
+        }
+    }
+
+    /// Builds, submits, and awaits a settlement transaction.
+    ///
+    /// Returns the failure reason on error. Gas spent by a reverted
+    /// transaction is still recorded on the trade.
+    async fn submit(&self, trade: &mut Trade) -> Result<TxReceipt, String> {
+        let tx = self
+            .tx_builder
+            .build(trade)
+            .await
+            .map_err(|e| e.to_string())?;
+        if let (Some(preflight), Some(transfer)) = (&self.preflight, &tx.token_transfer) {
+            preflight
+                .preflight_settlement(
+                    self.config.chain_id,
+                    &transfer.token,
+                    &transfer.from,
+                    &tx.to,
+                    transfer.amount,
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        let gas_limit = self
+            .blockchain
+            .estimate_gas(&tx.to, &tx.data, tx.value)
+            .await
+            .map_err(|e| e.to_string())?;
+        let gas_price = self
+            .blockchain
+            .get_gas_price(self.config.priority)
+            .await
+            .map_err(|e| e.to_string())?;
+        let tx_hash = self
+            .blockchain
+            .send_transaction(&tx.to, &tx.data, tx.value, gas_limit, gas_price)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // Store the hash first so the next scan can detect a late confirmation.
+        trade
+            .record_settlement_tx(tx_hash.as_str())
+            .map_err(|e| e.to_string())?;
+        self.save(trade).await.map_err(|e| e.to_string())?;
+
+        let started = Instant::now();
+        let confirmation = self
+            .blockchain
+            .wait_for_confirmation(&tx_hash, self.config.confirmations)
+            .await;
+        let outcome = match &confirmation {
+            Ok(receipt) if receipt.success => "confirmed",
+            Ok(_) => "reverted",
+            Err(_) => "error",
+        };
+        metrics::record_settlement_confirmation(started.elapsed(), outcome);
+        let receipt = confirmation.map_err(|e| e.to_string())?;
+
+        if receipt.success {
+            Ok(receipt)
+        } else {
+            self.add_gas_fee(trade, &receipt);
+            Err(format!("settlement transaction {} reverted", tx_hash))
+        }
+    }
+
+    /// Moves the trade to `DeadLettered` and publishes an alert.
+    async fn dead_letter(&self, trade: &mut Trade) -> ApplicationResult<SettlementRetryOutcome> {
+        trade.dead_letter()?;
+        self.save(trade).await?;
+
+        tracing::error!(
+            trade_id = %trade.id(),
+            attempts = trade.settlement_attempts(),
+            last_error = trade.failure_reason().unwrap_or_default(),
+            "Settlement retries exhausted, trade dead-lettered"
+        );
+
+        let event = SettlementDeadLettered::new(
+            trade.rfq_id(),
+            trade.id(),
+            trade.settlement_attempts(),
+            trade.failure_reason().map(str::to_string),
+            trade.settlement_tx_ref().map(str::to_string),
+        );
+        self.event_publisher
+            .publish_settlement_dead_lettered(event)
+            .await?;
+
+        Ok(SettlementRetryOutcome::DeadLettered)
+    }
+
+    fn add_gas_fee(&self, trade: &mut Trade, receipt: &TxReceipt) {
+        if let Some(fee) = receipt.gas_fee(self.config.chain_id) {
+            trade.add_fee(fee);
+        }
+    }
+
+    async fn save(&self, trade: &Trade) -> ApplicationResult<()> {
+        self.trade_repository
+            .save(trade)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{Price, Quantity, QuoteId, RfqId, VenueId};
+    use crate::infrastructure::blockchain::{
+        BlockchainError, BlockchainResult, GasPrice, PreflightConfig,
+    };
+    use crate::infrastructure::persistence::in_memory::InMemoryTradeRepository;
+    use std::collections::{HashMap, HashSet, VecDeque};
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct MockBlockchain {
+        /// Receipts returned by `get_transaction_receipt`, keyed by hash.
+        mined: Mutex<HashMap<String, TxReceipt>>,
+        /// Hashes in the mempool, not mined yet.
+        pending: Mutex<HashSet<String>>,
+        /// Results returned by successive `wait_for_confirmation` calls.
+        confirmations: Mutex<VecDeque<BlockchainResult<bool>>>,
+        sent: Mutex<Vec<TxHash>>,
+    }
+
+    impl MockBlockchain {
+        fn with_confirmations(results: Vec<BlockchainResult<bool>>) -> Self {
+            Self {
+                confirmations: Mutex::new(results.into()),
+                ..Self::default()
+            }
+        }
+
+        fn broadcast(&self, hash: &str) {
+            self.pending.lock().unwrap().insert(hash.to_string());
+        }
+
+        fn mine(&self, hash: &str, success: bool) {
+            self.pending.lock().unwrap().remove(hash);
+            self.mined
+                .lock()
+                .unwrap()
+                .insert(hash.to_string(), receipt(hash, success));
+        }
+
+        fn sent_count(&self) -> usize {
+            self.sent.lock().unwrap().len()
+        }
+    }
+
+    fn receipt(hash: &str, success: bool) -> TxReceipt {
+        TxReceipt {
+            tx_hash: TxHash::new(hash),
+            block_number: 100,
+            gas_used: 21_000,
+            effective_gas_price: 20_000_000_000,
+            success,
+        }
+    }
+
+    #[async_trait]
+    impl BlockchainClient for MockBlockchain {
+        fn chain_id(&self) -> ChainId {
+            ChainId::Ethereum
+        }
+
+        async fn get_block_number(&self) -> BlockchainResult<u64> {
+            Ok(100)
+        }
+
+        async fn get_balance(&self, _address: &str) -> BlockchainResult<u128> {
+            Ok(0)
+        }
+
+        async fn estimate_gas(
+            &self,
+            _to: &str,
+            _data: &[u8],
+            _value: u128,
+        ) -> BlockchainResult<u64> {
+            Ok(21_000)
+        }
+
+        async fn get_gas_price(&self, _priority: TxPriority) -> BlockchainResult<GasPrice> {
+            Ok(GasPrice::legacy(20_000_000_000))
+        }
+
+        async fn call(&self, _to: &str, _data: &[u8]) -> BlockchainResult<Vec<u8>> {
+            // Every ERC-20 balance and allowance reads as zero.
+            Ok(vec![0; 32])
+        }
+
+        async fn send_transaction(
+            &self,
+            _to: &str,
+            _data: &[u8],
+            _value: u128,
+            _gas_limit: u64,
+            _gas_price: GasPrice,
+        ) -> BlockchainResult<TxHash> {
+            let mut sent = self.sent.lock().unwrap();
+            let hash = TxHash::new(format!("0x{:04x}", sent.len() + 1));
+            sent.push(hash.clone());
+            Ok(hash)
+        }
+
+        async fn wait_for_confirmation(
+            &self,
+            tx_hash: &TxHash,
+            _confirmations: u64,
+        ) -> BlockchainResult<TxReceipt> {
+            let success = self
+                .confirmations
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or(Err(BlockchainError::timeout("no confirmation")))?;
+            Ok(receipt(tx_hash.as_str(), success))
+        }
+
+        async fn get_transaction_receipt(
+            &self,
+            tx_hash: &TxHash,
+        ) -> BlockchainResult<Option<TxReceipt>> {
+            Ok(self.mined.lock().unwrap().get(tx_hash.as_str()).cloned())
+        }
+
+        async fn is_transaction_pending(&self, tx_hash: &TxHash) -> BlockchainResult<bool> {
+            Ok(self.pending.lock().unwrap().contains(tx_hash.as_str()))
+        }
+
+        async fn get_nonce(&self, _address: &str) -> BlockchainResult<u64> {
+            Ok(0)
+        }
+
+        async fn health_check(&self) -> BlockchainResult<()> {
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    struct FixedTxBuilder(Option<TokenTransfer>);
+
+    #[async_trait]
+    impl SettlementTxBuilder for FixedTxBuilder {
+        async fn build(&self, _trade: &Trade) -> ApplicationResult<SettlementTx> {
+            Ok(SettlementTx {
+                to: "0x0000000000000000000000000000000000000001".to_string(),
+                data: vec![0xab],
+                value: 0,
+                token_transfer: self.0.clone(),
+            })
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingPublisher {
+        events: Mutex<Vec<SettlementDeadLettered>>,
+    }
+
+    #[async_trait]
+    impl SettlementEventPublisher for RecordingPublisher {
+        async fn publish_settlement_dead_lettered(
+            &self,
+            event: SettlementDeadLettered,
+        ) -> ApplicationResult<()> {
+            self.events.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    struct Harness {
+        repo: Arc<InMemoryTradeRepository>,
+        blockchain: Arc<MockBlockchain>,
+        publisher: Arc<RecordingPublisher>,
+        service: SettlementRetryService,
+    }
+
+    fn harness(blockchain: MockBlockchain, max_retries: u32) -> Harness {
+        let repo = Arc::new(InMemoryTradeRepository::new());
+        let blockchain = Arc::new(blockchain);
+        let publisher = Arc::new(RecordingPublisher::default());
+        let config = SettlementRetryConfig {
+            // Zero delay so that every scan finds the rescheduled trade due.
+            policy: RetryPolicy::new(max_retries, 0, 0, 2.0, 0.0),
+            ..SettlementRetryConfig::default()
+        };
+        let service = SettlementRetryService::new(
+            Arc::clone(&repo) as Arc<dyn TradeRepository>,
+            Arc::clone(&blockchain) as Arc<dyn BlockchainClient>,
+            Arc::new(FixedTxBuilder(None)),
+            Arc::clone(&publisher) as Arc<dyn SettlementEventPublisher>,
+            config,
+        );
+        Harness {
+            repo,
+            blockchain,
+            publisher,
+            service,
+        }
+    }
+
+    async fn failed_trade(repo: &InMemoryTradeRepository, tx_ref: Option<&str>) -> Trade {
+        let mut trade = Trade::new(
+            RfqId::new_v4(),
+            QuoteId::new_v4(),
+            VenueId::new("venue-1"),
+            Price::new(100.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+        );
+        trade.start_settlement().unwrap();
+        if let Some(tx_ref) = tx_ref {
+            trade.record_settlement_tx(tx_ref).unwrap();
+        }
+        trade.fail_settlement("confirmation timeout").unwrap();
+        repo.save(&trade).await.unwrap();
+        trade
+    }
+
+    #[tokio::test]
+    async fn settles_on_second_retry() {
+        let h = harness(
+            MockBlockchain::with_confirmations(vec![
+                Err(BlockchainError::timeout("not mined")),
+                Ok(true),
+            ]),
+            3,
+        );
+        let trade = failed_trade(&h.repo, None).await;
+
+        let first = h.service.run_once().await.unwrap();
+        assert_eq!(first.rescheduled, 1);
+        let stored = h.repo.get(trade.id()).await.unwrap().unwrap();
+        assert!(stored.is_failed());
+        assert_eq!(stored.settlement_attempts(), 1);
+        assert_eq!(stored.settlement_tx_ref(), Some("0x0001"));
+        assert!(stored.next_settlement_retry_at().is_some());
+
+        let second = h.service.run_once().await.unwrap();
+        assert_eq!(second.settled, 1);
+        let stored = h.repo.get(trade.id()).await.unwrap().unwrap();
+        assert!(stored.is_settled());
+        assert_eq!(stored.settlement_attempts(), 2);
+        assert_eq!(stored.settlement_tx_ref(), Some("0x0002"));
+        assert_eq!(
+            stored.total_fees("ETH"),
+            Some(rust_decimal::Decimal::new(42, 5))
+        );
+        assert_eq!(h.blockchain.sent_count(), 2);
+        assert!(h.publisher.events.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn dead_letters_after_exhausting_retries() {
+        let h = harness(
+            MockBlockchain::with_confirmations(vec![Ok(false), Ok(false)]),
+            2,
+        );
+        let trade = failed_trade(&h.repo, None).await;
+
+        let first = h.service.run_once().await.unwrap();
+        assert_eq!(first.rescheduled, 1);
+
+        let second = h.service.run_once().await.unwrap();
+        assert_eq!(second.dead_lettered, 1);
+
+        let stored = h.repo.get(trade.id()).await.unwrap().unwrap();
+        assert!(stored.is_dead_lettered());
+        assert_eq!(stored.settlement_attempts(), 2);
+        assert!(stored.next_settlement_retry_at().is_none());
+        assert!(stored.failure_reason().unwrap().contains("reverted"));
+
+        let events = h.publisher.events.lock().unwrap().clone();
+        assert_eq!(events.len(), 1);
+        let event = events.first().unwrap();
+        assert_eq!(event.trade_id, trade.id());
+        assert_eq!(event.attempts, 2);
+        assert_eq!(event.tx_hash.as_deref(), Some("0x0002"));
+
+        // Dead-lettered trades are no longer scanned.
+        let third = h.service.run_once().await.unwrap();
+        assert_eq!(third, SettlementRetryReport::default());
+        assert_eq!(h.blockchain.sent_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn reconciles_without_resubmitting_when_original_tx_confirmed() {
+        let h = harness(MockBlockchain::default(), 3);
+        let trade = failed_trade(&h.repo, Some("0xorig")).await;
+        h.blockchain.mine("0xorig", true);
+
+        let report = h.service.run_once().await.unwrap();
+        assert_eq!(report.reconciled, 1);
+
+        let stored = h.repo.get(trade.id()).await.unwrap().unwrap();
+        assert!(stored.is_settled());
+        assert_eq!(stored.settlement_tx_ref(), Some("0xorig"));
+        assert_eq!(stored.settlement_attempts(), 0);
+        assert_eq!(stored.fees().len(), 1);
+        assert_eq!(h.blockchain.sent_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn waits_for_a_pending_original_tx_instead_of_resubmitting() {
+        let h = harness(MockBlockchain::default(), 3);
+        let trade = failed_trade(&h.repo, Some("0xorig")).await;
+        h.blockchain.broadcast("0xorig");
+
+        let report = h.service.run_once().await.unwrap();
+        assert_eq!(report.awaiting_original, 1);
+        let stored = h.repo.get(trade.id()).await.unwrap().unwrap();
+        assert!(stored.is_failed());
+        assert_eq!(stored.settlement_attempts(), 0);
+        assert!(stored.next_settlement_retry_at().is_some());
+        assert_eq!(h.blockchain.sent_count(), 0);
+
+        h.blockchain.mine("0xorig", true);
+        let report = h.service.run_once().await.unwrap();
+        assert_eq!(report.reconciled, 1);
+        let stored = h.repo.get(trade.id()).await.unwrap().unwrap();
+        assert!(stored.is_settled());
+        assert_eq!(stored.settlement_tx_ref(), Some("0xorig"));
+        assert_eq!(h.blockchain.sent_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn resubmits_when_original_tx_was_dropped() {
+        let h = harness(MockBlockchain::with_confirmations(vec![Ok(true)]), 3);
+        let trade = failed_trade(&h.repo, Some("0xorig")).await;
+
+        let report = h.service.run_once().await.unwrap();
+        assert_eq!(report.settled, 1);
+
+        let stored = h.repo.get(trade.id()).await.unwrap().unwrap();
+        assert_eq!(stored.settlement_tx_ref(), Some("0x0001"));
+        assert_eq!(h.blockchain.sent_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn resubmits_when_original_tx_reverted() {
+        let h = harness(MockBlockchain::with_confirmations(vec![Ok(true)]), 3);
+        let trade = failed_trade(&h.repo, Some("0xorig")).await;
+        h.blockchain.mine("0xorig", false);
+
+        let report = h.service.run_once().await.unwrap();
+        assert_eq!(report.settled, 1);
+
+        let stored = h.repo.get(trade.id()).await.unwrap().unwrap();
+        assert_eq!(stored.settlement_tx_ref(), Some("0x0001"));
+        assert_eq!(h.blockchain.sent_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn preflight_shortfall_fails_without_broadcasting() {
+        let h = harness(MockBlockchain::with_confirmations(vec![Ok(true)]), 3);
+        let service = SettlementRetryService::new(
+            Arc::clone(&h.repo) as Arc<dyn TradeRepository>,
+            Arc::clone(&h.blockchain) as Arc<dyn BlockchainClient>,
+            Arc::new(FixedTxBuilder(Some(TokenTransfer {
+                token: "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".to_string(),
+                from: "0x1111111111111111111111111111111111111111".to_string(),
+                amount: 1_000_000,
+            }))),
+            Arc::clone(&h.publisher) as Arc<dyn SettlementEventPublisher>,
+            h.service.config.clone(),
+        )
+        .with_preflight(Arc::new(SettlementPreflight::new(
+            Arc::clone(&h.blockchain) as Arc<dyn BlockchainClient>,
+            PreflightConfig::default(),
+        )));
+        let trade = failed_trade(&h.repo, None).await;
+
+        let report = service.run_once().await.unwrap();
+        assert_eq!(report.rescheduled, 1);
+
+        let stored = h.repo.get(trade.id()).await.unwrap().unwrap();
+        assert!(
+            stored
+                .failure_reason()
+                .unwrap()
+                .contains("insufficient balance")
+        );
+        assert!(stored.settlement_tx_ref().is_none());
+        assert_eq!(h.blockchain.sent_count(), 0);
+    }
+}
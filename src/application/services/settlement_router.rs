@@ -0,0 +1,211 @@
+//! # Settlement Router
+//!
+//! Picks the wallet a counterparty's settlement leg is sent to.
+//!
+//! The counterparty's address for the token on the settlement chain wins
+//! over its chain default (see [`Counterparty::settlement_address`]). The
+//! resolved address must be verified: a token-specific address that is
+//! still unverified is refused rather than skipped, since falling back to
+//! the chain default would pay into a wallet the counterparty did not
+//! choose for that token.
+//!
+//! With a token registry, the router also resolves the contract a token
+//! settles through on each chain, refusing tokens not enabled for settlement.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::domain::entities::counterparty::{Counterparty, WalletAddress};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::{Blockchain, CounterpartyId};
+use crate::infrastructure::blockchain::{SharedTokenRegistry, TokenEntry};
+use crate::infrastructure::persistence::traits::CounterpartyRepository;
+use std::sync::Arc;
+
+/// Resolves verified settlement addresses of counterparties.
+#[derive(Debug, Clone)]
+pub struct SettlementRouter {
+    counterparties: Arc<dyn CounterpartyRepository>,
+    tokens: Option<SharedTokenRegistry>,
+}
+
+impl SettlementRouter {
+    /// Creates a router reading counterparties from `counterparties`.
+    #[must_use]
+    pub fn new(counterparties: Arc<dyn CounterpartyRepository>) -> Self {
+        Self {
+            counterparties,
+            tokens: None,
+        }
+    }
+
+    /// Resolves token contracts from `tokens`.
+    #[must_use]
+    pub fn with_token_registry(mut self, tokens: SharedTokenRegistry) -> Self {
+        self.tokens = Some(tokens);
+        self
+    }
+
+    /// Returns the contract `symbol` settles through on `chain`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::SettlementTokenUnavailable` if the token is
+    /// unknown, not deployed on the chain, or not enabled for settlement, and
+    /// `ApplicationError::Internal` if no token registry is configured.
+    pub fn token(&self, symbol: &str, chain: Blockchain) -> ApplicationResult<TokenEntry> {
+        let tokens = self
+            .tokens
+            .as_ref()
+            .ok_or_else(|| ApplicationError::internal("token registry not configured"))?;
+        Ok(tokens.read().settlement_token(symbol, chain.into())?)
+    }
+
+    /// Returns the wallet to settle `token` on `chain` to for a counterparty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::NotFound` if the counterparty does not
+    /// exist, and the errors of [`SettlementRouter::resolve`].
+    pub async fn route(
+        &self,
+        counterparty_id: &CounterpartyId,
+        chain: Blockchain,
+        token: Option<&str>,
+    ) -> ApplicationResult<WalletAddress> {
+        let counterparty = self
+            .counterparties
+            .get(counterparty_id)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?
+            .ok_or_else(|| ApplicationError::not_found("Counterparty", counterparty_id.as_str()))?;
+        Ok(Self::resolve(&counterparty, chain, token)?.clone())
+    }
+
+    /// Returns the wallet to settle `token` on `chain` to for `counterparty`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::SettlementFailed` if the counterparty has no
+    /// address for the chain, and `DomainError::OperationNotAllowed` if the
+    /// resolved address is not verified.
+    pub fn resolve<'a>(
+        counterparty: &'a Counterparty,
+        chain: Blockchain,
+        token: Option<&str>,
+    ) -> DomainResult<&'a WalletAddress> {
+        let address = counterparty
+            .settlement_address(chain, token)
+            .ok_or_else(|| {
+                DomainError::SettlementFailed(format!(
+                    "counterparty {} has no settlement address on {}",
+                    counterparty.id(),
+                    chain
+                ))
+            })?;
+        if !address.is_verified() {
+            return Err(DomainError::OperationNotAllowed(format!(
+                "settlement address {} of counterparty {} is not verified",
+                address,
+                counterparty.id()
+            )));
+        }
+        Ok(address.address())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::CounterpartyType;
+    use crate::domain::entities::counterparty::SettlementAddress;
+    use crate::infrastructure::persistence::in_memory::InMemoryCounterpartyRepository;
+
+    const POLYGON: &str = "0x1111111111111111111111111111111111111111";
+    const POLYGON_USDC: &str = "0x2222222222222222222222222222222222222222";
+    const ARBITRUM: &str = "0x3333333333333333333333333333333333333333";
+
+    fn add(cp: &mut Counterparty, chain: Blockchain, address: &str, token: Option<&str>) {
+        cp.add_settlement_address(SettlementAddress::new(
+            WalletAddress::new(chain, address),
+            token.map(str::to_string),
+        ))
+        .unwrap();
+    }
+
+    fn counterparty() -> Counterparty {
+        let mut cp = Counterparty::new(
+            CounterpartyId::new("client-1"),
+            "Client 1",
+            CounterpartyType::Client,
+        );
+        add(&mut cp, Blockchain::Polygon, POLYGON, None);
+        add(&mut cp, Blockchain::Polygon, POLYGON_USDC, Some("USDC"));
+        add(&mut cp, Blockchain::Arbitrum, ARBITRUM, None);
+        cp.verify_settlement_address(Blockchain::Polygon, None, POLYGON)
+            .unwrap();
+        cp.verify_settlement_address(Blockchain::Arbitrum, None, ARBITRUM)
+            .unwrap();
+        cp
+    }
+
+    #[tokio::test]
+    async fn routes_to_the_verified_address_of_each_chain() {
+        let repository = Arc::new(InMemoryCounterpartyRepository::new());
+        repository.save(&counterparty()).await.unwrap();
+        let router = SettlementRouter::new(repository);
+        let id = CounterpartyId::new("client-1");
+
+        let polygon = router.route(&id, Blockchain::Polygon, None).await.unwrap();
+        let arbitrum = router
+            .route(&id, Blockchain::Arbitrum, Some("USDC"))
+            .await
+            .unwrap();
+
+        assert_eq!(polygon.address(), POLYGON);
+        assert_eq!(arbitrum.address(), ARBITRUM);
+        assert!(router.route(&id, Blockchain::Ethereum, None).await.is_err());
+    }
+
+    #[test]
+    fn resolves_only_settlement_enabled_tokens() {
+        use crate::infrastructure::blockchain::{TokenError, TokenRegistry};
+
+        let registry = Arc::new(parking_lot::RwLock::new(TokenRegistry::with_common_tokens()));
+        let router = SettlementRouter::new(Arc::new(InMemoryCounterpartyRepository::new()))
+            .with_token_registry(Arc::clone(&registry));
+
+        let usdc = router.token("USDC", Blockchain::Polygon).unwrap();
+        assert_eq!(usdc.address, "0x3c499c542cEF5E3811e1192ce70d8cC03d5c3359");
+        assert_eq!(usdc.decimals, 6);
+
+        registry
+            .write()
+            .set_settlement_enabled("USDC", false)
+            .unwrap();
+        assert!(matches!(
+            router.token("USDC", Blockchain::Polygon),
+            Err(ApplicationError::SettlementTokenUnavailable(
+                TokenError::SettlementDisabled(_)
+            ))
+        ));
+        assert!(matches!(
+            router.token("USDT", Blockchain::Base),
+            Err(ApplicationError::SettlementTokenUnavailable(
+                TokenError::NotOnChain(..)
+            ))
+        ));
+    }
+
+    #[test]
+    fn refuses_unverified_addresses() {
+        let mut cp = counterparty();
+
+        let result = SettlementRouter::resolve(&cp, Blockchain::Polygon, Some("USDC"));
+        assert!(matches!(result, Err(DomainError::OperationNotAllowed(_))));
+
+        cp.verify_settlement_address(Blockchain::Polygon, Some("USDC"), POLYGON_USDC)
+            .unwrap();
+        let routed = SettlementRouter::resolve(&cp, Blockchain::Polygon, Some("USDC")).unwrap();
+        assert_eq!(routed.address(), POLYGON_USDC);
+    }
+}
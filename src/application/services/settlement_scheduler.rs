@@ -0,0 +1,355 @@
+//! # Settlement Scheduler
+//!
+//! When executed trades are released for settlement.
+//!
+//! [`SettlementScheduler`] picks the [`SettlementWindow`] of a trade — the
+//! window agreed in negotiation, else the counterparty's default, else the
+//! configured platform default — and records it on the trade with the time
+//! the window opens. Settlement and netting workers leave a trade alone
+//! until then; trades with no recorded window are released at once.
+//!
+//! Trade dates and settlement days roll past the closed days of the
+//! trading calendar the instrument follows, chosen by its settlement method
+//! through the [`TradingCalendarService`]. Without one, every instrument
+//! settles on the 24/7 crypto calendar.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! let scheduler = SettlementScheduler::new(counterparties, SettlementSchedulerConfig::default())
+//!     .with_calendars(calendars);
+//! let method = rfq.instrument().settlement_method();
+//! let due_at = scheduler.schedule(rfq.client_id(), method, &mut trade).await?;
+//! ```
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::application::services::trading_calendar::TradingCalendarService;
+use crate::domain::entities::trade::Trade;
+use crate::domain::value_objects::timestamp::{Clock, SystemClock, Timestamp};
+use crate::domain::value_objects::{
+    CounterpartyId, SettlementMethod, SettlementWindow, TradingCalendar,
+};
+use crate::infrastructure::persistence::traits::CounterpartyRepository;
+use std::sync::Arc;
+
+/// Configuration for [`SettlementScheduler`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SettlementSchedulerConfig {
+    /// Window of trades with no negotiated or counterparty window.
+    pub default_window: SettlementWindow,
+}
+
+impl Default for SettlementSchedulerConfig {
+    /// Instant settlement.
+    fn default() -> Self {
+        Self {
+            default_window: SettlementWindow::Instant,
+        }
+    }
+}
+
+/// Schedules trades into settlement windows.
+#[derive(Debug)]
+pub struct SettlementScheduler {
+    counterparties: Arc<dyn CounterpartyRepository>,
+    config: SettlementSchedulerConfig,
+    calendars: Option<Arc<TradingCalendarService>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl SettlementScheduler {
+    /// Creates a scheduler reading counterparty defaults from
+    /// `counterparties`.
+    #[must_use]
+    pub fn new(
+        counterparties: Arc<dyn CounterpartyRepository>,
+        config: SettlementSchedulerConfig,
+    ) -> Self {
+        Self {
+            counterparties,
+            config,
+            calendars: None,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Sets the trading calendars release times skip the closed days of.
+    #[must_use]
+    pub fn with_calendars(mut self, calendars: Arc<TradingCalendarService>) -> Self {
+        self.calendars = Some(calendars);
+        self
+    }
+
+    /// Sets the clock used to decide whether a trade is released.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the window a trade of `client_id` settles in.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::RepositoryError` if the counterparty
+    /// cannot be loaded.
+    pub async fn window_for(
+        &self,
+        client_id: &CounterpartyId,
+        trade: &Trade,
+    ) -> ApplicationResult<SettlementWindow> {
+        if let Some(window) = trade.settlement_plan().and_then(|plan| plan.window) {
+            return Ok(window);
+        }
+        let counterparty = self
+            .counterparties
+            .get(client_id)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?;
+        Ok(counterparty
+            .and_then(|c| c.settlement_window().copied())
+            .unwrap_or(self.config.default_window))
+    }
+
+    /// Records the settlement window of `trade` and when it is released,
+    /// counted from its execution time on the calendar of `method`. Returns
+    /// the release time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::RepositoryError` if the counterparty or
+    /// the trading calendar cannot be loaded.
+    pub async fn schedule(
+        &self,
+        client_id: &CounterpartyId,
+        method: SettlementMethod,
+        trade: &mut Trade,
+    ) -> ApplicationResult<Timestamp> {
+        let window = self.window_for(client_id, trade).await?;
+        let calendar = match &self.calendars {
+            Some(calendars) => calendars.calendar_for(method).await?,
+            None => TradingCalendar::crypto(),
+        };
+        let due_at = window.release_at(trade.created_at(), &calendar);
+        trade.schedule_settlement(window, due_at);
+        tracing::debug!(
+            trade_id = %trade.id(),
+            window = %window,
+            due_at = %due_at,
+            "Trade settlement scheduled"
+        );
+        Ok(due_at)
+    }
+
+    /// Returns true if `trade` may be handed to the settlement workers now.
+    #[must_use]
+    pub fn is_released(&self, trade: &Trade) -> bool {
+        trade.is_settlement_due(self.clock.now())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::counterparty::{Counterparty, CounterpartyType};
+    use crate::domain::entities::trade::SettlementState;
+    use crate::domain::value_objects::timestamp::MockClock;
+    use crate::domain::value_objects::{
+        Blockchain, Price, Quantity, QuoteId, RfqId, SettlementCutoff, SettlementPlan, TradeId,
+        VenueId,
+    };
+    use crate::infrastructure::persistence::in_memory::{
+        InMemoryCounterpartyRepository, InMemoryTradingCalendarRepository,
+    };
+    use crate::infrastructure::persistence::traits::TradingCalendarRepository;
+    use chrono::{NaiveDate, NaiveTime};
+
+    const ONCHAIN: SettlementMethod = SettlementMethod::OnChain(Blockchain::Ethereum);
+
+    /// Tuesday 2024-01-09 10:00 UTC.
+    const TUESDAY_10AM: i64 = 1_704_794_400;
+
+    fn trade_at(secs: i64) -> Trade {
+        let at = Timestamp::from_secs(secs).unwrap();
+        Trade::from_parts(
+            TradeId::new_v4(),
+            RfqId::new_v4(),
+            QuoteId::new_v4(),
+            VenueId::new("venue-a"),
+            Price::new(100.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            None,
+            SettlementState::Pending,
+            None,
+            None,
+            1,
+            at,
+            at,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn same_day(hour: u32) -> SettlementWindow {
+        SettlementWindow::SameDay {
+            cutoff: SettlementCutoff::new(
+                NaiveTime::from_hms_opt(hour, 0, 0).unwrap(),
+                chrono_tz::UTC,
+            ),
+        }
+    }
+
+    struct Fixture {
+        counterparties: Arc<InMemoryCounterpartyRepository>,
+        clock: Arc<MockClock>,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self {
+                counterparties: Arc::new(InMemoryCounterpartyRepository::new()),
+                clock: Arc::new(MockClock::new(Timestamp::from_secs(TUESDAY_10AM).unwrap())),
+            }
+        }
+
+        fn scheduler(&self, config: SettlementSchedulerConfig) -> SettlementScheduler {
+            SettlementScheduler::new(
+                Arc::clone(&self.counterparties) as Arc<dyn CounterpartyRepository>,
+                config,
+            )
+            .with_clock(Arc::clone(&self.clock) as Arc<dyn Clock>)
+        }
+    }
+
+    #[tokio::test]
+    async fn instant_trades_are_released_immediately() {
+        let f = Fixture::new();
+        let scheduler = f.scheduler(SettlementSchedulerConfig::default());
+        let mut trade = trade_at(TUESDAY_10AM);
+
+        let due_at = scheduler
+            .schedule(&CounterpartyId::new("client-1"), ONCHAIN, &mut trade)
+            .await
+            .unwrap();
+
+        assert_eq!(due_at, trade.created_at());
+        assert_eq!(trade.settlement_window(), Some(&SettlementWindow::Instant));
+        assert!(scheduler.is_released(&trade));
+    }
+
+    #[tokio::test]
+    async fn same_day_trades_wait_for_the_cutoff() {
+        let f = Fixture::new();
+        let mut counterparty = Counterparty::new(
+            CounterpartyId::new("client-1"),
+            "Client One",
+            CounterpartyType::Client,
+        );
+        counterparty.set_settlement_window(Some(same_day(16)));
+        f.counterparties.save(&counterparty).await.unwrap();
+        let scheduler = f.scheduler(SettlementSchedulerConfig::default());
+
+        // Before the cutoff: released at 16:00 the same day
+        let mut before = trade_at(TUESDAY_10AM);
+        let due_at = scheduler
+            .schedule(counterparty.id(), ONCHAIN, &mut before)
+            .await
+            .unwrap();
+        assert_eq!(
+            due_at,
+            Timestamp::from_secs(TUESDAY_10AM + 6 * 3600).unwrap()
+        );
+        assert!(!scheduler.is_released(&before));
+        f.clock.set(due_at);
+        assert!(scheduler.is_released(&before));
+
+        // After the cutoff: released at 16:00 the next day
+        let mut after = trade_at(TUESDAY_10AM + 7 * 3600);
+        let due_at = scheduler
+            .schedule(counterparty.id(), ONCHAIN, &mut after)
+            .await
+            .unwrap();
+        assert_eq!(
+            due_at,
+            Timestamp::from_secs(TUESDAY_10AM + 30 * 3600).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn next_day_skips_the_closed_days_of_the_instrument_calendar() {
+        let f = Fixture::new();
+        let repository = Arc::new(InMemoryTradingCalendarRepository::new());
+        // Closed on weekends and on Monday 2024-01-15
+        let calendar = TradingCalendar::new("US", chrono_tz::UTC, true)
+            .unwrap()
+            .with_holidays([NaiveDate::from_ymd_opt(2024, 1, 15).unwrap()]);
+        repository.save(&calendar).await.unwrap();
+        let calendars = Arc::new(
+            TradingCalendarService::new(repository as Arc<dyn TradingCalendarRepository>)
+                .with_offchain_calendar("US"),
+        );
+        let scheduler = f
+            .scheduler(SettlementSchedulerConfig {
+                default_window: SettlementWindow::NextDay {
+                    cutoff: SettlementCutoff::default(),
+                },
+            })
+            .with_calendars(calendars);
+        // Friday 2024-01-12 10:00 UTC
+        let friday = TUESDAY_10AM + 3 * 86_400;
+
+        let mut trade = trade_at(friday);
+        let due_at = scheduler
+            .schedule(
+                &CounterpartyId::new("client-1"),
+                SettlementMethod::OffChain,
+                &mut trade,
+            )
+            .await
+            .unwrap();
+        // Tuesday 16:00 UTC, after the holiday weekend
+        assert_eq!(
+            due_at,
+            Timestamp::from_secs(friday + 4 * 86_400 + 6 * 3600).unwrap()
+        );
+
+        let mut trade = trade_at(friday);
+        let due_at = scheduler
+            .schedule(&CounterpartyId::new("client-1"), ONCHAIN, &mut trade)
+            .await
+            .unwrap();
+        // Saturday 16:00 UTC on the crypto calendar
+        assert_eq!(
+            due_at,
+            Timestamp::from_secs(friday + 86_400 + 6 * 3600).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn negotiated_window_overrides_the_counterparty_default() {
+        let f = Fixture::new();
+        let mut counterparty = Counterparty::new(
+            CounterpartyId::new("client-1"),
+            "Client One",
+            CounterpartyType::Client,
+        );
+        counterparty.set_settlement_window(Some(same_day(16)));
+        f.counterparties.save(&counterparty).await.unwrap();
+
+        let mut trade = trade_at(TUESDAY_10AM);
+        trade.set_settlement_plan(SettlementPlan {
+            chain: None,
+            window: Some(SettlementWindow::Instant),
+            note: None,
+        });
+        f.scheduler(SettlementSchedulerConfig::default())
+            .schedule(counterparty.id(), ONCHAIN, &mut trade)
+            .await
+            .unwrap();
+
+        assert_eq!(trade.settlement_window(), Some(&SettlementWindow::Instant));
+        assert_eq!(trade.settlement_due_at(), Some(trade.created_at()));
+    }
+}
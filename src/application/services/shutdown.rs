@@ -0,0 +1,247 @@
+//! # Shutdown Coordinator
+//!
+//! Drains in-flight quote aggregations before the servers stop.
+//!
+//! The [`ShutdownCoordinator`] is shared by the API layer and the quote
+//! collection use case. Draining proceeds in three steps:
+//!
+//! 1. New RFQ creations and aggregations are refused.
+//! 2. In-flight aggregations get a grace period to complete and persist.
+//! 3. Aggregations still running are cancelled through the shared
+//!    [`CancellationToken`]; each one marks its RFQ as failed with
+//!    [`SHUTDOWN_REASON`] and the coordinator waits for them to finish.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::application::services::ShutdownCoordinator;
+//! use std::time::Duration;
+//!
+//! # tokio_test::block_on(async {
+//! let coordinator = ShutdownCoordinator::new(Duration::from_secs(5));
+//! let guard = coordinator.begin().unwrap();
+//! drop(guard);
+//!
+//! let report = coordinator.drain().await;
+//! assert!(!coordinator.is_accepting());
+//! assert!(!report.cancelled);
+//! # });
+//! ```
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::time::timeout;
+use tokio_util::sync::CancellationToken;
+
+/// Failure detail recorded, under `FailureCode::Shutdown`, on RFQs whose
+/// aggregation was cancelled.
+pub const SHUTDOWN_REASON: &str = "shutdown";
+
+/// Default grace period for in-flight aggregations.
+pub const DEFAULT_GRACE_PERIOD: Duration = Duration::from_secs(20);
+
+/// How long cancelled aggregations get to persist their failure.
+const CANCEL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Outcome of draining in-flight work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DrainReport {
+    /// Aggregations in flight when draining started.
+    pub in_flight: usize,
+    /// Whether the grace period expired and the rest were cancelled.
+    pub cancelled: bool,
+    /// Aggregations still running after cancellation.
+    pub abandoned: usize,
+}
+
+#[derive(Debug)]
+struct Inner {
+    accepting: AtomicBool,
+    in_flight: AtomicUsize,
+    idle: Notify,
+    token: CancellationToken,
+    grace_period: Duration,
+}
+
+/// Coordinates graceful shutdown of in-flight quote aggregations.
+///
+/// Cloning is cheap; all clones share the same state.
+#[derive(Debug, Clone)]
+pub struct ShutdownCoordinator {
+    inner: Arc<Inner>,
+}
+
+impl ShutdownCoordinator {
+    /// Creates a coordinator with the given grace period.
+    #[must_use]
+    pub fn new(grace_period: Duration) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                accepting: AtomicBool::new(true),
+                in_flight: AtomicUsize::new(0),
+                idle: Notify::new(),
+                token: CancellationToken::new(),
+                grace_period,
+            }),
+        }
+    }
+
+    /// Returns the grace period for in-flight aggregations.
+    #[must_use]
+    pub fn grace_period(&self) -> Duration {
+        self.inner.grace_period
+    }
+
+    /// Returns true until draining starts.
+    #[must_use]
+    pub fn is_accepting(&self) -> bool {
+        self.inner.accepting.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of aggregations in flight.
+    #[must_use]
+    pub fn in_flight(&self) -> usize {
+        self.inner.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Returns the token cancelled when the grace period expires.
+    #[must_use]
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.inner.token.clone()
+    }
+
+    /// Registers an in-flight aggregation.
+    ///
+    /// Returns `None` once draining has started. The aggregation counts as
+    /// in flight until the returned guard is dropped.
+    #[must_use]
+    pub fn begin(&self) -> Option<InFlightGuard> {
+        self.inner.in_flight.fetch_add(1, Ordering::SeqCst);
+        if !self.is_accepting() {
+            self.release();
+            return None;
+        }
+        Some(InFlightGuard {
+            coordinator: self.clone(),
+        })
+    }
+
+    /// Stops accepting work and drains in-flight aggregations.
+    ///
+    /// Waits up to the grace period, then cancels the remaining
+    /// aggregations and waits for them to record their failure.
+    pub async fn drain(&self) -> DrainReport {
+        self.inner.accepting.store(false, Ordering::SeqCst);
+        let in_flight = self.in_flight();
+
+        if self.wait_idle(self.inner.grace_period).await {
+            return DrainReport {
+                in_flight,
+                cancelled: false,
+                abandoned: 0,
+            };
+        }
+
+        tracing::warn!(
+            remaining = self.in_flight(),
+            "Grace period expired, cancelling in-flight aggregations"
+        );
+        self.inner.token.cancel();
+        self.wait_idle(CANCEL_TIMEOUT).await;
+
+        DrainReport {
+            in_flight,
+            cancelled: true,
+            abandoned: self.in_flight(),
+        }
+    }
+
+    /// Waits until no aggregation is in flight; returns false on timeout.
+    async fn wait_idle(&self, limit: Duration) -> bool {
+        let wait = async {
+            loop {
+                let notified = self.inner.idle.notified();
+                if self.in_flight() == 0 {
+                    return;
+                }
+                notified.await;
+            }
+        };
+        timeout(limit, wait).await.is_ok()
+    }
+
+    fn release(&self) {
+        if self.inner.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.inner.idle.notify_waiters();
+        }
+    }
+}
+
+impl Default for ShutdownCoordinator {
+    fn default() -> Self {
+        Self::new(DEFAULT_GRACE_PERIOD)
+    }
+}
+
+/// Marks an aggregation as in flight until dropped.
+#[derive(Debug)]
+pub struct InFlightGuard {
+    coordinator: ShutdownCoordinator,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.coordinator.release();
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn begin_is_refused_after_drain() {
+        let coordinator = ShutdownCoordinator::new(Duration::from_millis(10));
+        assert!(coordinator.begin().is_some());
+
+        coordinator.drain().await;
+
+        assert!(coordinator.begin().is_none());
+        assert_eq!(coordinator.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn drain_waits_for_work_within_grace_period() {
+        let coordinator = ShutdownCoordinator::new(Duration::from_secs(5));
+        let guard = coordinator.begin().unwrap();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            drop(guard);
+        });
+
+        let report = coordinator.drain().await;
+
+        assert_eq!(report.in_flight, 1);
+        assert!(!report.cancelled);
+        assert!(!coordinator.cancellation_token().is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn drain_cancels_work_past_grace_period() {
+        let coordinator = ShutdownCoordinator::new(Duration::from_millis(20));
+        let guard = coordinator.begin().unwrap();
+        let token = coordinator.cancellation_token();
+        tokio::spawn(async move {
+            token.cancelled().await;
+            drop(guard);
+        });
+
+        let report = coordinator.drain().await;
+
+        assert!(report.cancelled);
+        assert_eq!(report.abandoned, 0);
+    }
+}
@@ -0,0 +1,565 @@
+//! # Theoretical Reference Price
+//!
+//! Model reference prices for options on crypto derivatives venues.
+//!
+//! This module provides:
+//! - [`OptionPricingInputs`]: Contract terms and market inputs for one option
+//! - [`MarketDataPort`]: Async trait for obtaining pricing inputs
+//! - [`TheoreticalReferencePriceProvider`]: Black-76 [`ReferencePriceProvider`]
+//! - [`black76_price`]: The Black-76 formula on decimal inputs
+//!
+//! Only [`AssetClass::CryptoDerivs`] and [`AssetClass::CryptoOptions`]
+//! instruments are priced. Other asset classes, and derivatives for which
+//! the market data port has no option inputs, yield no price so that the
+//! fallback chain moves on.
+//!
+//! # Numerical Precision
+//!
+//! Inputs and outputs are [`Decimal`]. `f64` is used only for `ln`, `exp`,
+//! `sqrt` and the normal CDF, which `Decimal` cannot evaluate. The CDF
+//! approximation has an absolute error below 1.5e-7, so the model price is
+//! accurate to about `1.5e-7 * max(forward, strike)`. The `f64` result is
+//! then clamped to the no-arbitrage bounds computed in `Decimal` (at least
+//! the discounted intrinsic value, at most the discounted forward for a call
+//! or the discounted strike for a put) and rounded to [`PRICE_SCALE`]
+//! decimal places.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::application::services::theoretical_reference::{OptionKind, black76_price};
+//! use rust_decimal::Decimal;
+//!
+//! // At expiry the price is the intrinsic value.
+//! let price = black76_price(
+//!     OptionKind::Call,
+//!     Decimal::from(110),
+//!     Decimal::from(100),
+//!     Decimal::new(60, 2),
+//!     Decimal::new(5, 2),
+//!     Decimal::ZERO,
+//! )
+//! .unwrap();
+//! assert_eq!(price, Decimal::from(10));
+//! ```
+
+use crate::application::services::price_bounds::ReferencePriceProvider;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::arithmetic::CheckedArithmetic;
+use crate::domain::value_objects::enums::AssetClass;
+use crate::domain::value_objects::instrument::Instrument;
+pub use crate::domain::value_objects::option_terms::OptionKind;
+use crate::domain::value_objects::price::Price;
+use crate::domain::value_objects::reference_price::ReferencePriceSource;
+use crate::domain::value_objects::timestamp::{Clock, SystemClock, Timestamp};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use std::fmt;
+use std::sync::Arc;
+
+/// Decimal places of theoretical prices.
+pub const PRICE_SCALE: u32 = 8;
+
+/// Milliseconds in a 365-day year, the day count used for time to expiry.
+const MILLIS_PER_YEAR: Decimal = Decimal::from_parts(1_471_228_928, 7, 0, false, 0);
+
+/// Total volatility below which the option is priced at intrinsic value.
+const MIN_TOTAL_VOLATILITY: f64 = 1e-12;
+
+/// Contract terms and market inputs needed to price one option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OptionPricingInputs {
+    kind: OptionKind,
+    strike: Price,
+    expiry: Timestamp,
+    forward: Price,
+    volatility: Decimal,
+    rate: Decimal,
+}
+
+impl OptionPricingInputs {
+    /// Creates pricing inputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Call or put
+    /// * `strike` - Strike price
+    /// * `expiry` - Expiry time
+    /// * `forward` - Underlying forward price for the expiry
+    /// * `volatility` - Implied volatility at the strike and expiry (annualized)
+    /// * `rate` - Risk-free rate to expiry (annualized, continuously compounded)
+    #[must_use]
+    pub const fn new(
+        kind: OptionKind,
+        strike: Price,
+        expiry: Timestamp,
+        forward: Price,
+        volatility: Decimal,
+        rate: Decimal,
+    ) -> Self {
+        Self {
+            kind,
+            strike,
+            expiry,
+            forward,
+            volatility,
+            rate,
+        }
+    }
+
+    /// Returns the option kind.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> OptionKind {
+        self.kind
+    }
+
+    /// Returns the strike price.
+    #[inline]
+    #[must_use]
+    pub const fn strike(&self) -> Price {
+        self.strike
+    }
+
+    /// Returns the expiry time.
+    #[inline]
+    #[must_use]
+    pub const fn expiry(&self) -> Timestamp {
+        self.expiry
+    }
+
+    /// Returns the underlying forward price.
+    #[inline]
+    #[must_use]
+    pub const fn forward(&self) -> Price {
+        self.forward
+    }
+
+    /// Returns the implied volatility.
+    #[inline]
+    #[must_use]
+    pub const fn volatility(&self) -> Decimal {
+        self.volatility
+    }
+
+    /// Returns the risk-free rate.
+    #[inline]
+    #[must_use]
+    pub const fn rate(&self) -> Decimal {
+        self.rate
+    }
+}
+
+/// Source of option pricing inputs for instruments.
+#[async_trait]
+pub trait MarketDataPort: Send + Sync + fmt::Debug {
+    /// Returns the pricing inputs for an option instrument.
+    ///
+    /// The volatility is the point of the implied volatility surface at the
+    /// option's strike and expiry. Returns `Ok(None)` if the instrument is
+    /// not an option or market data is unavailable.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DomainError` if market data cannot be fetched.
+    async fn option_inputs(
+        &self,
+        instrument: &Instrument,
+    ) -> DomainResult<Option<OptionPricingInputs>>;
+}
+
+/// Reference price provider using the Black-76 model.
+///
+/// Prices crypto derivative and option instruments from the forward, implied
+/// volatility and rate supplied by a [`MarketDataPort`], tagged
+/// [`ReferencePriceSource::Theoretical`].
+///
+/// # Examples
+///
+/// ```ignore
+/// let provider = TheoreticalReferencePriceProvider::new(market_data);
+/// ```
+#[derive(Debug)]
+pub struct TheoreticalReferencePriceProvider {
+    market_data: Arc<dyn MarketDataPort>,
+    clock: Arc<dyn Clock>,
+}
+
+impl TheoreticalReferencePriceProvider {
+    /// Creates a new provider using the system clock.
+    #[must_use]
+    pub fn new(market_data: Arc<dyn MarketDataPort>) -> Self {
+        Self {
+            market_data,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Sets the clock used to compute time to expiry.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Prices an option at the current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if the option has already
+    /// expired or the inputs are invalid.
+    pub fn price(&self, inputs: &OptionPricingInputs) -> DomainResult<Price> {
+        let years = time_to_expiry(self.clock.now(), inputs.expiry())?;
+        let price = black76_price(
+            inputs.kind(),
+            inputs.forward().get(),
+            inputs.strike().get(),
+            inputs.volatility(),
+            inputs.rate(),
+            years,
+        )?;
+        Ok(Price::from_decimal(price)?)
+    }
+}
+
+#[async_trait]
+impl ReferencePriceProvider for TheoreticalReferencePriceProvider {
+    async fn get_reference(
+        &self,
+        instrument: &Instrument,
+    ) -> DomainResult<Option<(Price, ReferencePriceSource)>> {
+        if !matches!(
+            instrument.asset_class(),
+            AssetClass::CryptoDerivs | AssetClass::CryptoOptions
+        ) {
+            return Ok(None);
+        }
+        let Some(inputs) = self.market_data.option_inputs(instrument).await? else {
+            return Ok(None);
+        };
+        Ok(Some((
+            self.price(&inputs)?,
+            ReferencePriceSource::Theoretical,
+        )))
+    }
+}
+
+/// Returns the time from `now` to `expiry` in years (ACT/365).
+///
+/// # Errors
+///
+/// Returns `DomainError::ValidationError` if `expiry` is before `now`.
+pub fn time_to_expiry(now: Timestamp, expiry: Timestamp) -> DomainResult<Decimal> {
+    let millis = expiry
+        .timestamp_millis()
+        .checked_sub(now.timestamp_millis())
+        .ok_or_else(|| DomainError::ValidationError("time to expiry overflow".to_string()))?;
+    if millis < 0 {
+        return Err(DomainError::ValidationError(format!(
+            "negative time to expiry: option expired {}ms ago",
+            millis.unsigned_abs()
+        )));
+    }
+    Ok(Decimal::from(millis).safe_div(MILLIS_PER_YEAR)?)
+}
+
+/// Computes the Black-76 price of a European option on a forward.
+///
+/// A zero time to expiry or zero volatility gives the (discounted)
+/// intrinsic value. See the module documentation for precision bounds.
+///
+/// # Arguments
+///
+/// * `kind` - Call or put
+/// * `forward` - Underlying forward price
+/// * `strike` - Strike price
+/// * `volatility` - Implied volatility (annualized)
+/// * `rate` - Risk-free rate (annualized, continuously compounded)
+/// * `years` - Time to expiry in years
+///
+/// # Errors
+///
+/// Returns `DomainError::ValidationError` if the forward or strike is not
+/// positive, the volatility or time to expiry is negative, or the model
+/// produces a non-finite value.
+pub fn black76_price(
+    kind: OptionKind,
+    forward: Decimal,
+    strike: Decimal,
+    volatility: Decimal,
+    rate: Decimal,
+    years: Decimal,
+) -> DomainResult<Decimal> {
+    if forward <= Decimal::ZERO || strike <= Decimal::ZERO {
+        return Err(DomainError::ValidationError(
+            "forward and strike must be positive".to_string(),
+        ));
+    }
+    if volatility < Decimal::ZERO {
+        return Err(DomainError::ValidationError(
+            "volatility must not be negative".to_string(),
+        ));
+    }
+    if years < Decimal::ZERO {
+        return Err(DomainError::ValidationError(
+            "negative time to expiry".to_string(),
+        ));
+    }
+
+    let discount = if years.is_zero() {
+        Decimal::ONE
+    } else {
+        from_f64((-to_f64(rate.safe_mul(years)?)?).exp(), "discount factor")?
+    };
+    let (intrinsic, upper) = match kind {
+        OptionKind::Call => (forward.safe_sub(strike)?.max(Decimal::ZERO), forward),
+        OptionKind::Put => (strike.safe_sub(forward)?.max(Decimal::ZERO), strike),
+    };
+    let floor = intrinsic.safe_mul(discount)?;
+    let cap = upper.safe_mul(discount)?;
+
+    let f = to_f64(forward)?;
+    let k = to_f64(strike)?;
+    let total_volatility = to_f64(volatility)? * to_f64(years)?.sqrt();
+    if total_volatility < MIN_TOTAL_VOLATILITY {
+        return Ok(floor.round_dp(PRICE_SCALE));
+    }
+
+    let d1 = ((f / k).ln() + 0.5 * total_volatility * total_volatility) / total_volatility;
+    let d2 = d1 - total_volatility;
+    let undiscounted = match kind {
+        OptionKind::Call => f * norm_cdf(d1) - k * norm_cdf(d2),
+        OptionKind::Put => k * norm_cdf(-d2) - f * norm_cdf(-d1),
+    };
+    let model = from_f64(undiscounted, "Black-76 price")?.safe_mul(discount)?;
+
+    Ok(model.max(floor).min(cap).round_dp(PRICE_SCALE))
+}
+
+/// Converts a decimal to `f64` for transcendental functions.
+fn to_f64(value: Decimal) -> DomainResult<f64> {
+    value
+        .to_f64()
+        .ok_or_else(|| DomainError::ValidationError(format!("cannot convert {} to f64", value)))
+}
+
+/// Converts a finite `f64` model output back to a decimal.
+fn from_f64(value: f64, what: &str) -> DomainResult<Decimal> {
+    if !value.is_finite() {
+        return Err(DomainError::ValidationError(format!(
+            "{} is not finite",
+            what
+        )));
+    }
+    Decimal::from_f64(value)
+        .ok_or_else(|| DomainError::ValidationError(format!("{} out of range", what)))
+}
+
+/// Standard normal cumulative distribution function.
+///
+/// Symmetric by construction, so `norm_cdf(x) + norm_cdf(-x) == 1`.
+fn norm_cdf(x: f64) -> f64 {
+    0.5 * (1.0 + erf(x / std::f64::consts::SQRT_2))
+}
+
+/// Error function approximation.
+///
+/// Uses Abramowitz and Stegun 7.1.26 (maximum error: 1.5e-7).
+fn erf(x: f64) -> f64 {
+    let sign = if x >= 0.0 { 1.0 } else { -1.0 };
+    let x = x.abs();
+
+    let a1 = 0.254_829_592;
+    let a2 = -0.284_496_736;
+    let a3 = 1.421_413_741;
+    let a4 = -1.453_152_027;
+    let a5 = 1.061_405_429;
+    let p = 0.327_591_1;
+
+    let t = 1.0 / (1.0 + p * x);
+    let poly = t * (a1 + t * (a2 + t * (a3 + t * (a4 + t * a5))));
+
+    sign * (1.0 - poly * (-x * x).exp())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::enums::SettlementMethod;
+    use crate::domain::value_objects::symbol::Symbol;
+    use crate::domain::value_objects::timestamp::MockClock;
+
+    #[derive(Debug)]
+    struct FixedInputs(Option<OptionPricingInputs>);
+
+    #[async_trait]
+    impl MarketDataPort for FixedInputs {
+        async fn option_inputs(
+            &self,
+            _instrument: &Instrument,
+        ) -> DomainResult<Option<OptionPricingInputs>> {
+            Ok(self.0)
+        }
+    }
+
+    const DAY_SECS: i64 = 86_400;
+
+    fn now() -> Timestamp {
+        Timestamp::from_secs(1_700_000_000).unwrap()
+    }
+
+    fn inputs(
+        kind: OptionKind,
+        forward: f64,
+        strike: f64,
+        expiry_secs: i64,
+    ) -> OptionPricingInputs {
+        OptionPricingInputs::new(
+            kind,
+            Price::new(strike).unwrap(),
+            now().add_secs(expiry_secs),
+            Price::new(forward).unwrap(),
+            Decimal::new(60, 2),
+            Decimal::new(5, 2),
+        )
+    }
+
+    fn provider(inputs: Option<OptionPricingInputs>) -> TheoreticalReferencePriceProvider {
+        TheoreticalReferencePriceProvider::new(Arc::new(FixedInputs(inputs)))
+            .with_clock(Arc::new(MockClock::new(now())))
+    }
+
+    fn instrument(asset_class: AssetClass) -> Instrument {
+        Instrument::new(
+            Symbol::new("BTC/USD").unwrap(),
+            asset_class,
+            SettlementMethod::default(),
+        )
+    }
+
+    #[test]
+    fn put_call_parity_holds() {
+        let pricer = provider(None);
+        for strike in [80.0, 95.0, 100.0, 120.0] {
+            let call = pricer
+                .price(&inputs(OptionKind::Call, 100.0, strike, 30 * DAY_SECS))
+                .unwrap();
+            let put = pricer
+                .price(&inputs(OptionKind::Put, 100.0, strike, 30 * DAY_SECS))
+                .unwrap();
+
+            let years = time_to_expiry(now(), now().add_secs(30 * DAY_SECS)).unwrap();
+            let discount = Decimal::from_f64((-0.05 * years.to_f64().unwrap()).exp()).unwrap();
+            let parity = (Decimal::from(100) - Decimal::from_f64(strike).unwrap()) * discount;
+
+            let diff = (call.get() - put.get() - parity).abs();
+            assert!(
+                diff < Decimal::new(1, 6),
+                "strike {}: diff {}",
+                strike,
+                diff
+            );
+        }
+    }
+
+    #[test]
+    fn at_expiry_price_is_intrinsic_value() {
+        let pricer = provider(None);
+
+        let itm_call = pricer
+            .price(&inputs(OptionKind::Call, 110.0, 100.0, 0))
+            .unwrap();
+        let otm_call = pricer
+            .price(&inputs(OptionKind::Call, 90.0, 100.0, 0))
+            .unwrap();
+        let itm_put = pricer
+            .price(&inputs(OptionKind::Put, 90.0, 100.0, 0))
+            .unwrap();
+
+        assert_eq!(itm_call, Price::new(10.0).unwrap());
+        assert!(otm_call.is_zero());
+        assert_eq!(itm_put, Price::new(10.0).unwrap());
+    }
+
+    #[test]
+    fn expiring_today_stays_above_discounted_intrinsic() {
+        let pricer = provider(None);
+        let hour = 3_600;
+
+        let deep_itm = pricer
+            .price(&inputs(OptionKind::Call, 200.0, 100.0, hour))
+            .unwrap();
+        let deep_otm = pricer
+            .price(&inputs(OptionKind::Put, 200.0, 100.0, hour))
+            .unwrap();
+
+        let years = time_to_expiry(now(), now().add_secs(hour)).unwrap();
+        let discount = Decimal::from_f64((-0.05 * years.to_f64().unwrap()).exp()).unwrap();
+        let floor = (Decimal::from(100) * discount).round_dp(PRICE_SCALE);
+        assert!(deep_itm.get() >= floor);
+        assert!(deep_itm.get() < Decimal::new(100_001, 3));
+        assert!(deep_otm.is_zero());
+    }
+
+    #[test]
+    fn negative_time_to_expiry_is_rejected() {
+        let result = provider(None).price(&inputs(OptionKind::Call, 100.0, 100.0, -DAY_SECS));
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+    }
+
+    #[test]
+    fn negative_volatility_is_rejected() {
+        let result = black76_price(
+            OptionKind::Call,
+            Decimal::from(100),
+            Decimal::from(100),
+            Decimal::new(-1, 1),
+            Decimal::ZERO,
+            Decimal::ONE,
+        );
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn derivs_option_is_priced_as_theoretical() {
+        let provider = provider(Some(inputs(OptionKind::Call, 100.0, 100.0, 30 * DAY_SECS)));
+
+        let (price, source) = provider
+            .get_reference(&instrument(AssetClass::CryptoDerivs))
+            .await
+            .unwrap()
+            .unwrap();
+
+        // ATM call: roughly 0.4 * F * vol * sqrt(T).
+        assert!(price.get() > Decimal::from(6) && price.get() < Decimal::from(8));
+        assert_eq!(source, ReferencePriceSource::Theoretical);
+    }
+
+    #[tokio::test]
+    async fn unsupported_asset_class_returns_none() {
+        let provider = provider(Some(inputs(OptionKind::Call, 100.0, 100.0, 30 * DAY_SECS)));
+
+        for asset_class in [AssetClass::CryptoSpot, AssetClass::Stock, AssetClass::Forex] {
+            assert!(
+                provider
+                    .get_reference(&instrument(asset_class))
+                    .await
+                    .unwrap()
+                    .is_none()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn non_option_derivative_returns_none() {
+        assert!(
+            provider(None)
+                .get_reference(&instrument(AssetClass::CryptoDerivs))
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
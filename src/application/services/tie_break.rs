@@ -0,0 +1,387 @@
+//! # Tie-Break Rules
+//!
+//! Deterministic ordering of quotes that score equally.
+//!
+//! Two quotes at the same price get the same score from a ranking strategy.
+//! A [`TieBreakChain`] orders them by a list of [`TieBreak`] rules, applied in
+//! turn until one separates the quotes. The default chain is:
+//!
+//! 1. [`TieBreak::LargerQuantity`]: more size first
+//! 2. [`TieBreak::EarlierReceived`]: the quote that arrived first
+//! 3. [`TieBreak::LowerLatency`]: the venue with the lower p90 latency
+//! 4. [`TieBreak::VenueId`]: lexical venue ID
+//!
+//! Quotes still tied after the configured rules are ordered by quote ID, so
+//! the ranking never depends on the order in which quotes were collected.
+
+use crate::application::services::ranking_strategy::RankedQuote;
+use crate::domain::entities::quote::Quote;
+use crate::domain::entities::venue::VenueMetrics;
+use crate::domain::value_objects::VenueId;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A rule ordering quotes with equal scores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TieBreak {
+    /// Larger quoted quantity first.
+    LargerQuantity,
+    /// Earlier arrival from the venue first.
+    EarlierReceived,
+    /// Lower venue latency first; venues without metrics last.
+    LowerLatency,
+    /// Lexically smaller venue ID first.
+    VenueId,
+    /// Lexically smaller quote ID first. Always applied last.
+    QuoteId,
+}
+
+impl fmt::Display for TieBreak {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::LargerQuantity => "LARGER_QUANTITY",
+            Self::EarlierReceived => "EARLIER_RECEIVED",
+            Self::LowerLatency => "LOWER_LATENCY",
+            Self::VenueId => "VENUE_ID",
+            Self::QuoteId => "QUOTE_ID",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Ordered tie-break rules for equally scored quotes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TieBreakChain {
+    rules: Vec<TieBreak>,
+    /// Latency in milliseconds by venue ID.
+    venue_latency_ms: HashMap<String, u64>,
+}
+
+impl Default for TieBreakChain {
+    fn default() -> Self {
+        Self::new(vec![
+            TieBreak::LargerQuantity,
+            TieBreak::EarlierReceived,
+            TieBreak::LowerLatency,
+            TieBreak::VenueId,
+        ])
+    }
+}
+
+impl TieBreakChain {
+    /// Creates a chain applying `rules` in order.
+    #[must_use]
+    pub fn new(rules: Vec<TieBreak>) -> Self {
+        Self {
+            rules,
+            venue_latency_ms: HashMap::new(),
+        }
+    }
+
+    /// Returns the configured rules, in order.
+    #[must_use]
+    pub fn rules(&self) -> &[TieBreak] {
+        &self.rules
+    }
+
+    /// Sets the latency a venue is compared on.
+    #[must_use]
+    pub fn with_venue_latency(mut self, venue_id: &VenueId, latency_ms: u64) -> Self {
+        self.venue_latency_ms
+            .insert(venue_id.as_str().to_string(), latency_ms);
+        self
+    }
+
+    /// Takes venue latencies from their metrics.
+    ///
+    /// Venues are compared on p90 latency, so one with occasional slow
+    /// responses loses to a steady one even if its average is lower. The
+    /// average is used for metrics that predate percentile tracking, and
+    /// venues without recorded requests are left without a latency.
+    #[must_use]
+    pub fn with_venue_metrics<'a>(
+        mut self,
+        metrics: impl IntoIterator<Item = (&'a VenueId, &'a VenueMetrics)>,
+    ) -> Self {
+        for (venue_id, venue_metrics) in metrics {
+            let latency_ms = venue_metrics
+                .latency_p90()
+                .or_else(|| venue_metrics.average_latency_ms());
+            if let Some(latency_ms) = latency_ms {
+                self = self.with_venue_latency(venue_id, latency_ms);
+            }
+        }
+        self
+    }
+
+    /// Compares two quotes under a single rule.
+    fn compare_by(&self, rule: TieBreak, a: &Quote, b: &Quote) -> Ordering {
+        match rule {
+            TieBreak::LargerQuantity => b.quantity().get().cmp(&a.quantity().get()),
+            TieBreak::EarlierReceived => a.received_at().cmp(&b.received_at()),
+            TieBreak::LowerLatency => {
+                let latency_a = self.venue_latency_ms.get(a.venue_id().as_str());
+                let latency_b = self.venue_latency_ms.get(b.venue_id().as_str());
+                match (latency_a, latency_b) {
+                    (Some(la), Some(lb)) => la.cmp(lb),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
+            }
+            TieBreak::VenueId => a.venue_id().as_str().cmp(b.venue_id().as_str()),
+            TieBreak::QuoteId => a.id().get().cmp(&b.id().get()),
+        }
+    }
+
+    /// Returns the ordering of two equally scored quotes and the rule that
+    /// decided it.
+    fn compare(&self, a: &Quote, b: &Quote) -> (Ordering, Option<TieBreak>) {
+        self.rules
+            .iter()
+            .copied()
+            .chain(std::iter::once(TieBreak::QuoteId))
+            .map(|rule| (self.compare_by(rule, a, b), Some(rule)))
+            .find(|(ordering, _)| ordering.is_ne())
+            .unwrap_or((Ordering::Equal, None))
+    }
+
+    /// Ranks scored quotes, higher score first, breaking ties with the chain.
+    ///
+    /// Each ranked quote records the tie-break rule that separated it from
+    /// its neighbour: the quote ranked just above it, or for the best quote
+    /// the one just below. Quotes separated by score record `None`.
+    #[must_use]
+    pub fn rank(&self, scored: Vec<(&Quote, f64)>) -> Vec<RankedQuote> {
+        let mut scored = scored;
+        scored.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| self.compare(a.0, b.0).0)
+        });
+
+        let decided_by = |a: Option<&(&Quote, f64)>, b: Option<&(&Quote, f64)>| match (a, b) {
+            (Some((qa, sa)), Some((qb, sb))) if sa.partial_cmp(sb) == Some(Ordering::Equal) => {
+                self.compare(qa, qb).1
+            }
+            _ => None,
+        };
+
+        (0..scored.len())
+            .filter_map(|i| {
+                let (quote, score) = scored.get(i)?;
+                let neighbour = if i == 0 {
+                    scored.get(1)
+                } else {
+                    scored.get(i - 1)
+                };
+                let rule = decided_by(scored.get(i), neighbour);
+                let ranked = RankedQuote::new((*quote).clone(), i + 1, *score);
+                Some(match rule {
+                    Some(rule) => ranked.with_decided_by(rule),
+                    None => ranked,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{Price, Quantity, RfqId, Timestamp};
+
+    fn quote(venue: &str, quantity: f64, received_at: Timestamp) -> Quote {
+        Quote::new(
+            RfqId::new_v4(),
+            VenueId::new(venue),
+            Price::new(100.0).unwrap(),
+            Quantity::new(quantity).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .unwrap()
+        .with_received_at(received_at)
+    }
+
+    /// Three quotes at the same price:
+    /// - `venue-c`: 20 units, received first, 40ms latency
+    /// - `venue-a`: 10 units, received second, 10ms latency
+    /// - `venue-b`: 20 units, received third, 25ms latency
+    fn equal_priced_quotes() -> Vec<Quote> {
+        let t0 = Timestamp::now();
+        vec![
+            quote("venue-a", 10.0, t0.add_millis(5)),
+            quote("venue-b", 20.0, t0.add_millis(10)),
+            quote("venue-c", 20.0, t0),
+        ]
+    }
+
+    fn with_latencies(chain: TieBreakChain) -> TieBreakChain {
+        chain
+            .with_venue_latency(&VenueId::new("venue-a"), 10)
+            .with_venue_latency(&VenueId::new("venue-b"), 25)
+            .with_venue_latency(&VenueId::new("venue-c"), 40)
+    }
+
+    fn rank(chain: &TieBreakChain, quotes: &[Quote]) -> Vec<(String, Option<TieBreak>)> {
+        chain
+            .rank(quotes.iter().map(|q| (q, 1.0)).collect())
+            .into_iter()
+            .map(|r| (r.quote.venue_id().to_string(), r.decided_by))
+            .collect()
+    }
+
+    fn entry(venue: &str, rule: TieBreak) -> (String, Option<TieBreak>) {
+        (venue.to_string(), Some(rule))
+    }
+
+    #[test]
+    fn default_chain_prefers_quantity_then_arrival() {
+        let chain = with_latencies(TieBreakChain::default());
+        assert_eq!(
+            rank(&chain, &equal_priced_quotes()),
+            vec![
+                entry("venue-c", TieBreak::EarlierReceived),
+                entry("venue-b", TieBreak::EarlierReceived),
+                entry("venue-a", TieBreak::LargerQuantity),
+            ]
+        );
+    }
+
+    #[test]
+    fn arrival_first_chain() {
+        let chain = TieBreakChain::new(vec![TieBreak::EarlierReceived]);
+        assert_eq!(
+            rank(&chain, &equal_priced_quotes()),
+            vec![
+                entry("venue-c", TieBreak::EarlierReceived),
+                entry("venue-a", TieBreak::EarlierReceived),
+                entry("venue-b", TieBreak::EarlierReceived),
+            ]
+        );
+    }
+
+    #[test]
+    fn latency_first_chain_uses_venue_metrics() {
+        let mut fast = VenueMetrics::new();
+        fast.record_request(10, true);
+        let mut slow = VenueMetrics::new();
+        slow.record_request(40, true);
+        let venue_a = VenueId::new("venue-a");
+        let venue_c = VenueId::new("venue-c");
+
+        // venue-b has no metrics and goes last
+        let chain = TieBreakChain::new(vec![TieBreak::LowerLatency])
+            .with_venue_metrics([(&venue_a, &fast), (&venue_c, &slow)]);
+        assert_eq!(
+            rank(&chain, &equal_priced_quotes()),
+            vec![
+                entry("venue-a", TieBreak::LowerLatency),
+                entry("venue-c", TieBreak::LowerLatency),
+                entry("venue-b", TieBreak::LowerLatency),
+            ]
+        );
+    }
+
+    #[test]
+    fn latency_chain_prefers_steady_venue_over_lower_average() {
+        // venue-a averages 38ms but one request in seven takes 200ms
+        let mut spiky = VenueMetrics::new();
+        for _ in 0..85 {
+            spiky.record_request(10, true);
+        }
+        for _ in 0..15 {
+            spiky.record_request(200, true);
+        }
+        let mut steady = VenueMetrics::new();
+        for _ in 0..100 {
+            steady.record_request(40, true);
+        }
+        assert!(spiky.average_latency_ms() < steady.average_latency_ms());
+
+        let venue_a = VenueId::new("venue-a");
+        let venue_c = VenueId::new("venue-c");
+        let chain = TieBreakChain::new(vec![TieBreak::LowerLatency])
+            .with_venue_metrics([(&venue_a, &spiky), (&venue_c, &steady)]);
+        assert_eq!(
+            rank(&chain, &equal_priced_quotes()),
+            vec![
+                entry("venue-c", TieBreak::LowerLatency),
+                entry("venue-a", TieBreak::LowerLatency),
+                entry("venue-b", TieBreak::LowerLatency),
+            ]
+        );
+    }
+
+    #[test]
+    fn venue_id_chain_is_lexical() {
+        let chain = TieBreakChain::new(vec![TieBreak::VenueId]);
+        assert_eq!(
+            rank(&chain, &equal_priced_quotes()),
+            vec![
+                entry("venue-a", TieBreak::VenueId),
+                entry("venue-b", TieBreak::VenueId),
+                entry("venue-c", TieBreak::VenueId),
+            ]
+        );
+    }
+
+    #[test]
+    fn ranking_is_independent_of_input_order() {
+        let chain = with_latencies(TieBreakChain::default());
+        let quotes = equal_priced_quotes();
+        let expected = rank(&chain, &quotes);
+
+        for rotation in 1..quotes.len() {
+            let mut rotated = quotes.clone();
+            rotated.rotate_left(rotation);
+            assert_eq!(rank(&chain, &rotated), expected);
+            rotated.reverse();
+            assert_eq!(rank(&chain, &rotated), expected);
+        }
+    }
+
+    #[test]
+    fn identical_quotes_fall_back_to_quote_id() {
+        let t0 = Timestamp::now();
+        let quotes = [quote("venue-a", 10.0, t0), quote("venue-a", 10.0, t0)];
+        let mut expected: Vec<_> = quotes.iter().map(|q| q.id().get()).collect();
+        expected.sort();
+
+        let ranked = TieBreakChain::default().rank(quotes.iter().map(|q| (q, 1.0)).collect());
+        let ids: Vec<_> = ranked.iter().map(|r| r.quote.id().get()).collect();
+        assert_eq!(ids, expected);
+        assert!(
+            ranked
+                .iter()
+                .all(|r| r.decided_by == Some(TieBreak::QuoteId))
+        );
+    }
+
+    #[test]
+    fn score_differences_are_not_tie_breaks() {
+        let quotes = equal_priced_quotes();
+        let scored = quotes
+            .iter()
+            .enumerate()
+            .map(|(i, q)| (q, i as f64))
+            .collect();
+
+        let ranked = TieBreakChain::default().rank(scored);
+        assert_eq!(ranked.first().unwrap().quote.venue_id().as_str(), "venue-c");
+        assert!(ranked.iter().all(|r| r.decided_by.is_none()));
+    }
+
+    #[test]
+    fn tie_break_serializes_screaming_snake_case() {
+        assert_eq!(
+            serde_json::to_string(&TieBreak::EarlierReceived).unwrap(),
+            "\"EARLIER_RECEIVED\""
+        );
+        assert_eq!(TieBreak::LowerLatency.to_string(), "LOWER_LATENCY");
+    }
+}
@@ -0,0 +1,223 @@
+//! # Trading Calendar Service
+//!
+//! Named trading calendars and which one an instrument follows.
+//!
+//! On-chain settlement runs around the clock, so instruments settled
+//! on-chain always follow the built-in [`CRYPTO_CALENDAR`]. Instruments
+//! settled off-chain follow the configured tradfi calendar, whose weekends
+//! and holidays administrators manage through the
+//! [`TradingCalendarRepository`]. Until one is configured and stored,
+//! off-chain instruments trade 24/7 as well.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! let calendars = TradingCalendarService::new(repository).with_offchain_calendar("NYSE");
+//! let calendar = calendars.calendar_for(SettlementMethod::OffChain).await?;
+//! let due_at = calendar.add_business_days(executed_at, 2);
+//! ```
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::domain::value_objects::{CRYPTO_CALENDAR, SettlementMethod, TradingCalendar};
+use crate::infrastructure::persistence::traits::TradingCalendarRepository;
+use std::sync::Arc;
+
+/// Stores named trading calendars and selects one per settlement method.
+#[derive(Debug)]
+pub struct TradingCalendarService {
+    repository: Arc<dyn TradingCalendarRepository>,
+    offchain_calendar: Option<String>,
+}
+
+impl TradingCalendarService {
+    /// Creates a service under which every instrument trades 24/7.
+    #[must_use]
+    pub fn new(repository: Arc<dyn TradingCalendarRepository>) -> Self {
+        Self {
+            repository,
+            offchain_calendar: None,
+        }
+    }
+
+    /// Sets the calendar followed by instruments settled off-chain.
+    #[must_use]
+    pub fn with_offchain_calendar(mut self, name: impl Into<String>) -> Self {
+        self.offchain_calendar = Some(name.into().to_uppercase());
+        self
+    }
+
+    /// Returns the calendar followed by instruments settled by `method`.
+    ///
+    /// Falls back to the crypto calendar if the configured off-chain
+    /// calendar is not stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::RepositoryError` if the calendar cannot
+    /// be loaded.
+    pub async fn calendar_for(
+        &self,
+        method: SettlementMethod,
+    ) -> ApplicationResult<TradingCalendar> {
+        let name = match (method, &self.offchain_calendar) {
+            (SettlementMethod::OffChain, Some(name)) => name,
+            _ => return Ok(TradingCalendar::crypto()),
+        };
+        match self.get(name).await? {
+            Some(calendar) => Ok(calendar),
+            None => {
+                tracing::warn!(calendar = %name, "off-chain trading calendar not found, trading 24/7");
+                Ok(TradingCalendar::crypto())
+            }
+        }
+    }
+
+    /// Finds a calendar by name, including the built-in crypto calendar.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::RepositoryError` if loading fails.
+    pub async fn get(&self, name: &str) -> ApplicationResult<Option<TradingCalendar>> {
+        if is_builtin(name) {
+            return Ok(Some(TradingCalendar::crypto()));
+        }
+        self.repository
+            .find(name)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))
+    }
+
+    /// Returns every calendar, the built-in crypto calendar first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::RepositoryError` if loading fails.
+    pub async fn list(&self) -> ApplicationResult<Vec<TradingCalendar>> {
+        let stored = self
+            .repository
+            .find_all()
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?;
+        Ok(std::iter::once(TradingCalendar::crypto())
+            .chain(stored)
+            .collect())
+    }
+
+    /// Creates or replaces a calendar.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::Validation` if the calendar is the
+    /// built-in crypto calendar, or `ApplicationError::RepositoryError` if
+    /// saving fails.
+    pub async fn save(&self, calendar: &TradingCalendar) -> ApplicationResult<()> {
+        if is_builtin(calendar.name()) {
+            return Err(ApplicationError::validation(format!(
+                "the {CRYPTO_CALENDAR} calendar is built in and cannot be changed"
+            )));
+        }
+        self.repository
+            .save(calendar)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))?;
+        tracing::info!(
+            calendar = %calendar,
+            holidays = calendar.holidays().len(),
+            "trading calendar saved"
+        );
+        Ok(())
+    }
+
+    /// Deletes a calendar.
+    ///
+    /// Returns `Ok(false)` if it didn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApplicationError::Validation` for the built-in crypto
+    /// calendar, or `ApplicationError::RepositoryError` if deleting fails.
+    pub async fn delete(&self, name: &str) -> ApplicationResult<bool> {
+        if is_builtin(name) {
+            return Err(ApplicationError::validation(format!(
+                "the {CRYPTO_CALENDAR} calendar is built in and cannot be deleted"
+            )));
+        }
+        self.repository
+            .delete(name)
+            .await
+            .map_err(|e| ApplicationError::RepositoryError(e.to_string()))
+    }
+}
+
+fn is_builtin(name: &str) -> bool {
+    name.trim().eq_ignore_ascii_case(CRYPTO_CALENDAR)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::Blockchain;
+    use crate::infrastructure::persistence::in_memory::InMemoryTradingCalendarRepository;
+    use chrono::NaiveDate;
+
+    fn nyse() -> TradingCalendar {
+        TradingCalendar::new("NYSE", chrono_tz::America::New_York, true)
+            .unwrap()
+            .with_holidays([NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()])
+    }
+
+    fn service() -> TradingCalendarService {
+        TradingCalendarService::new(Arc::new(InMemoryTradingCalendarRepository::new()))
+            .with_offchain_calendar("nyse")
+    }
+
+    #[tokio::test]
+    async fn calendar_is_selected_by_settlement_method() {
+        let service = service();
+        service.save(&nyse()).await.unwrap();
+
+        let onchain = service
+            .calendar_for(SettlementMethod::OnChain(Blockchain::Ethereum))
+            .await
+            .unwrap();
+        assert_eq!(onchain.name(), CRYPTO_CALENDAR);
+
+        let offchain = service
+            .calendar_for(SettlementMethod::OffChain)
+            .await
+            .unwrap();
+        assert_eq!(offchain, nyse());
+    }
+
+    #[tokio::test]
+    async fn offchain_trades_24_7_until_its_calendar_is_stored() {
+        let service = service();
+        let offchain = service
+            .calendar_for(SettlementMethod::OffChain)
+            .await
+            .unwrap();
+        assert!(offchain.is_always_open());
+    }
+
+    #[tokio::test]
+    async fn crypto_calendar_is_built_in() {
+        let service = service();
+        service.save(&nyse()).await.unwrap();
+
+        let names: Vec<String> = service
+            .list()
+            .await
+            .unwrap()
+            .iter()
+            .map(|c| c.name().to_string())
+            .collect();
+        assert_eq!(names, vec![CRYPTO_CALENDAR, "NYSE"]);
+
+        let crypto = TradingCalendar::new("crypto", chrono_tz::UTC, true).unwrap();
+        assert!(service.save(&crypto).await.is_err());
+        assert!(service.delete("Crypto").await.is_err());
+        assert!(service.delete("nyse").await.unwrap());
+        assert!(!service.delete("nyse").await.unwrap());
+    }
+}
@@ -0,0 +1,460 @@
+//! # Venue Prober
+//!
+//! Background health probing of venue adapters.
+//!
+//! [`VenueProber`] periodically calls the lightweight
+//! [`health_check`](VenueAdapter::health_check) of every enabled venue
+//! adapter, records the probe latency on the venue's
+//! [`VenueMetrics`](crate::domain::entities::venue::VenueMetrics) and
+//! updates its [`VenueHealth`] with hysteresis:
+//!
+//! - A venue goes [`VenueHealth::Unhealthy`] only after
+//!   `failure_threshold` consecutive failed probes.
+//! - An unhealthy venue recovers only after `recovery_threshold`
+//!   consecutive successful probes.
+//!
+//! A single dropped probe therefore does not take a venue out of routing,
+//! and a flapping venue does not come straight back in.
+
+use crate::domain::entities::venue::{Venue, VenueHealth};
+use crate::domain::value_objects::VenueId;
+use crate::infrastructure::venues::traits::VenueAdapter;
+use crate::infrastructure::venues::{VenueHealthStatus, VenueRegistry};
+use async_trait::async_trait;
+use serde::Serialize;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Default interval between probe rounds.
+pub const DEFAULT_PROBE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Default number of consecutive failed probes before a venue is unhealthy.
+pub const DEFAULT_PROBE_FAILURE_THRESHOLD: u32 = 3;
+
+/// Default number of consecutive successful probes before an unhealthy
+/// venue recovers.
+pub const DEFAULT_PROBE_RECOVERY_THRESHOLD: u32 = 2;
+
+/// Venue storage used to read and persist probed venues.
+#[async_trait]
+pub trait ProbedVenueRepository: Send + Sync + fmt::Debug {
+    /// Finds a venue by ID.
+    async fn find_by_id(&self, id: &VenueId) -> Result<Option<Venue>, String>;
+
+    /// Saves a venue.
+    async fn save(&self, venue: &Venue) -> Result<(), String>;
+}
+
+/// Outcome of probing a single venue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VenueProbeResult {
+    /// The probed venue.
+    pub venue_id: VenueId,
+    /// Whether the health check succeeded.
+    pub success: bool,
+    /// Time taken by the health check in milliseconds.
+    pub latency_ms: u64,
+    /// Why the health check failed or the venue is degraded.
+    pub message: Option<String>,
+    /// Venue health before the probe.
+    pub previous_health: VenueHealth,
+    /// Venue health after the probe.
+    pub health: VenueHealth,
+    /// Why the probe outcome could not be stored, if it could not.
+    pub error: Option<String>,
+}
+
+impl VenueProbeResult {
+    /// Returns true if the probe changed the venue's health.
+    #[must_use]
+    pub fn changed(&self) -> bool {
+        self.previous_health != self.health
+    }
+}
+
+/// Probes venue adapters and updates venue health with hysteresis.
+///
+/// # Examples
+///
+/// ```ignore
+/// let prober = VenueProber::new(registry, venue_repository)
+///     .with_failure_threshold(3)
+///     .with_recovery_threshold(2);
+///
+/// tokio::spawn(async move { prober.run(DEFAULT_PROBE_INTERVAL).await });
+/// ```
+#[derive(Debug)]
+pub struct VenueProber {
+    registry: Arc<VenueRegistry>,
+    venue_repository: Arc<dyn ProbedVenueRepository>,
+    failure_threshold: u32,
+    recovery_threshold: u32,
+}
+
+impl VenueProber {
+    /// Creates a new prober with the default thresholds.
+    #[must_use]
+    pub fn new(
+        registry: Arc<VenueRegistry>,
+        venue_repository: Arc<dyn ProbedVenueRepository>,
+    ) -> Self {
+        Self {
+            registry,
+            venue_repository,
+            failure_threshold: DEFAULT_PROBE_FAILURE_THRESHOLD,
+            recovery_threshold: DEFAULT_PROBE_RECOVERY_THRESHOLD,
+        }
+    }
+
+    /// Sets how many consecutive failures make a venue unhealthy.
+    #[must_use]
+    pub fn with_failure_threshold(mut self, threshold: u32) -> Self {
+        self.failure_threshold = threshold.max(1);
+        self
+    }
+
+    /// Sets how many consecutive successes recover an unhealthy venue.
+    #[must_use]
+    pub fn with_recovery_threshold(mut self, threshold: u32) -> Self {
+        self.recovery_threshold = threshold.max(1);
+        self
+    }
+
+    /// Probes every enabled venue every `interval`, forever.
+    pub async fn run(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            let results = self.probe_all().await;
+            let changed = results.iter().filter(|r| r.changed()).count();
+            tracing::debug!(probed = results.len(), changed, "venue probe completed");
+        }
+    }
+
+    /// Probes every enabled venue and returns the per-venue results.
+    ///
+    /// Venues are probed concurrently. Results are ordered by venue ID.
+    pub async fn probe_all(&self) -> Vec<VenueProbeResult> {
+        let adapters = self.registry.get_enabled().await;
+        let mut results =
+            futures::future::join_all(adapters.iter().map(|adapter| self.probe(adapter.as_ref())))
+                .await;
+        results.sort_by(|a, b| a.venue_id.as_str().cmp(b.venue_id.as_str()));
+        results
+    }
+
+    /// Probes one venue, or returns `None` if it has no registered adapter.
+    pub async fn probe_venue(&self, venue_id: &VenueId) -> Option<VenueProbeResult> {
+        let adapter = self.registry.get(venue_id).await?;
+        Some(self.probe(adapter.as_ref()).await)
+    }
+
+    /// Runs a health check and applies its outcome to the stored venue.
+    async fn probe(&self, adapter: &dyn VenueAdapter) -> VenueProbeResult {
+        let venue_id = adapter.venue_id().clone();
+        let timeout = Duration::from_millis(adapter.timeout_ms());
+        let started = Instant::now();
+        let (observed, message) = match tokio::time::timeout(timeout, adapter.health_check()).await
+        {
+            Ok(Ok(health)) => {
+                let observed = match health.status() {
+                    VenueHealthStatus::Healthy => VenueHealth::Healthy,
+                    VenueHealthStatus::Degraded => VenueHealth::Degraded,
+                    VenueHealthStatus::Unhealthy | VenueHealthStatus::Unknown => {
+                        VenueHealth::Unhealthy
+                    }
+                };
+                (observed, health.message().map(str::to_string))
+            }
+            Ok(Err(e)) => (VenueHealth::Unhealthy, Some(e.to_string())),
+            Err(_) => (
+                VenueHealth::Unhealthy,
+                Some(format!("timed out after {}ms", timeout.as_millis())),
+            ),
+        };
+        let latency_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        // Degraded venues still answer, so they count as reachable.
+        let success = observed != VenueHealth::Unhealthy;
+
+        let mut result = VenueProbeResult {
+            venue_id,
+            success,
+            latency_ms,
+            message,
+            previous_health: observed,
+            health: observed,
+            error: None,
+        };
+
+        match self.venue_repository.find_by_id(&result.venue_id).await {
+            Ok(Some(mut venue)) => {
+                result.previous_health = venue.health();
+                venue.metrics_mut().record_probe(latency_ms, success);
+                let health = self.next_health(&venue, observed);
+                if health != venue.health() {
+                    venue.set_health(health);
+                    tracing::info!(
+                        venue_id = %result.venue_id,
+                        from = ?result.previous_health,
+                        to = ?health,
+                        "venue health changed"
+                    );
+                }
+                result.health = health;
+                if let Err(e) = self.venue_repository.save(&venue).await {
+                    tracing::warn!(venue_id = %result.venue_id, error = %e, "venue probe not saved");
+                    result.error = Some(e);
+                }
+            }
+            Ok(None) => {
+                tracing::debug!(venue_id = %result.venue_id, "probed venue is not stored");
+            }
+            Err(e) => {
+                tracing::warn!(venue_id = %result.venue_id, error = %e, "probed venue not loaded");
+                result.error = Some(e);
+            }
+        }
+
+        result
+    }
+
+    /// Returns the health a venue should have after its latest probe.
+    ///
+    /// Expects the probe to already be recorded on the venue's metrics.
+    fn next_health(&self, venue: &Venue, observed: VenueHealth) -> VenueHealth {
+        let metrics = venue.metrics();
+        let current = venue.health();
+
+        if observed == VenueHealth::Unhealthy {
+            return if metrics.consecutive_probe_failures() >= self.failure_threshold {
+                VenueHealth::Unhealthy
+            } else {
+                current
+            };
+        }
+
+        if current == VenueHealth::Unhealthy
+            && metrics.consecutive_probe_successes() < self.recovery_threshold
+        {
+            return VenueHealth::Unhealthy;
+        }
+        observed
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::quote::Quote;
+    use crate::domain::entities::rfq::Rfq;
+    use crate::domain::value_objects::VenueType;
+    use crate::infrastructure::venues::error::{VenueError, VenueResult};
+    use crate::infrastructure::venues::traits::ExecutionResult;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use tokio::sync::RwLock;
+
+    #[derive(Debug)]
+    struct ToggleVenue {
+        venue_id: VenueId,
+        up: AtomicBool,
+    }
+
+    impl ToggleVenue {
+        fn new(id: &str, up: bool) -> Arc<Self> {
+            Arc::new(Self {
+                venue_id: VenueId::new(id),
+                up: AtomicBool::new(up),
+            })
+        }
+
+        fn set_up(&self, up: bool) {
+            self.up.store(up, Ordering::SeqCst);
+        }
+    }
+
+    #[async_trait]
+    impl VenueAdapter for ToggleVenue {
+        fn venue_id(&self) -> &VenueId {
+            &self.venue_id
+        }
+
+        fn timeout_ms(&self) -> u64 {
+            1000
+        }
+
+        async fn request_quote(&self, _rfq: &Rfq) -> VenueResult<Quote> {
+            Err(VenueError::internal_error("not quoting"))
+        }
+
+        async fn execute_trade(&self, _quote: &Quote) -> VenueResult<ExecutionResult> {
+            Err(VenueError::internal_error("not executing"))
+        }
+
+        async fn health_check(&self) -> VenueResult<crate::infrastructure::venues::VenueHealth> {
+            if self.up.load(Ordering::SeqCst) {
+                Ok(crate::infrastructure::venues::VenueHealth::healthy(
+                    self.venue_id.clone(),
+                ))
+            } else {
+                Err(VenueError::connection("refused"))
+            }
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct MockVenueRepository {
+        venues: RwLock<HashMap<VenueId, Venue>>,
+        saves: std::sync::atomic::AtomicUsize,
+    }
+
+    impl MockVenueRepository {
+        async fn insert(&self, id: &str) {
+            let venue = Venue::new(VenueId::new(id), id, VenueType::InternalMM);
+            self.venues.write().await.insert(venue.id().clone(), venue);
+        }
+
+        async fn get(&self, id: &str) -> Venue {
+            self.venues
+                .read()
+                .await
+                .get(&VenueId::new(id))
+                .cloned()
+                .unwrap()
+        }
+    }
+
+    #[async_trait]
+    impl ProbedVenueRepository for MockVenueRepository {
+        async fn find_by_id(&self, id: &VenueId) -> Result<Option<Venue>, String> {
+            Ok(self.venues.read().await.get(id).cloned())
+        }
+
+        async fn save(&self, venue: &Venue) -> Result<(), String> {
+            self.saves.fetch_add(1, Ordering::SeqCst);
+            self.venues
+                .write()
+                .await
+                .insert(venue.id().clone(), venue.clone());
+            Ok(())
+        }
+    }
+
+    async fn setup(venues: &[Arc<ToggleVenue>]) -> (VenueProber, Arc<MockVenueRepository>) {
+        let registry = VenueRegistry::new();
+        let repository = Arc::new(MockVenueRepository::default());
+        for venue in venues {
+            registry
+                .register(Arc::clone(venue) as Arc<dyn VenueAdapter>)
+                .await;
+            repository.insert(venue.venue_id.as_str()).await;
+        }
+        let prober = VenueProber::new(
+            Arc::new(registry),
+            Arc::clone(&repository) as Arc<dyn ProbedVenueRepository>,
+        )
+        .with_failure_threshold(3)
+        .with_recovery_threshold(2);
+        (prober, repository)
+    }
+
+    #[tokio::test]
+    async fn goes_unhealthy_only_after_consecutive_failures() {
+        let venue = ToggleVenue::new("venue-a", false);
+        let (prober, repository) = setup(&[Arc::clone(&venue)]).await;
+        let id = VenueId::new("venue-a");
+
+        for _ in 0..2 {
+            let result = prober.probe_venue(&id).await.unwrap();
+            assert!(!result.success);
+            assert_eq!(result.health, VenueHealth::Healthy);
+        }
+
+        let result = prober.probe_venue(&id).await.unwrap();
+        assert_eq!(result.previous_health, VenueHealth::Healthy);
+        assert_eq!(result.health, VenueHealth::Unhealthy);
+        assert!(result.changed());
+        assert!(result.message.unwrap().contains("refused"));
+        assert_eq!(
+            repository.get("venue-a").await.health(),
+            VenueHealth::Unhealthy
+        );
+    }
+
+    #[tokio::test]
+    async fn success_resets_failure_streak() {
+        let venue = ToggleVenue::new("venue-a", false);
+        let (prober, repository) = setup(&[Arc::clone(&venue)]).await;
+        let id = VenueId::new("venue-a");
+
+        prober.probe_venue(&id).await.unwrap();
+        prober.probe_venue(&id).await.unwrap();
+        venue.set_up(true);
+        prober.probe_venue(&id).await.unwrap();
+        venue.set_up(false);
+        prober.probe_venue(&id).await.unwrap();
+
+        let stored = repository.get("venue-a").await;
+        assert_eq!(stored.health(), VenueHealth::Healthy);
+        assert_eq!(stored.metrics().consecutive_probe_failures(), 1);
+    }
+
+    #[tokio::test]
+    async fn recovers_only_after_consecutive_successes() {
+        let venue = ToggleVenue::new("venue-a", false);
+        let (prober, repository) = setup(&[Arc::clone(&venue)]).await;
+        let id = VenueId::new("venue-a");
+
+        for _ in 0..3 {
+            prober.probe_venue(&id).await.unwrap();
+        }
+        venue.set_up(true);
+
+        let result = prober.probe_venue(&id).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.health, VenueHealth::Unhealthy);
+
+        let result = prober.probe_venue(&id).await.unwrap();
+        assert_eq!(result.previous_health, VenueHealth::Unhealthy);
+        assert_eq!(result.health, VenueHealth::Healthy);
+
+        let stored = repository.get("venue-a").await;
+        assert_eq!(stored.health(), VenueHealth::Healthy);
+        assert_eq!(stored.metrics().consecutive_probe_successes(), 2);
+        assert!(stored.metrics().last_probe_latency_ms().is_some());
+    }
+
+    #[tokio::test]
+    async fn probe_all_persists_every_enabled_venue() {
+        let (prober, repository) = setup(&[
+            ToggleVenue::new("venue-b", false),
+            ToggleVenue::new("venue-a", true),
+        ])
+        .await;
+
+        let results = prober.probe_all().await;
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].venue_id, VenueId::new("venue-a"));
+        assert!(results[0].success);
+        assert!(!results[1].success);
+        assert_eq!(repository.saves.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            repository
+                .get("venue-b")
+                .await
+                .metrics()
+                .consecutive_probe_failures(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_venue_is_not_probed() {
+        let (prober, _) = setup(&[]).await;
+
+        assert!(prober.probe_venue(&VenueId::new("missing")).await.is_none());
+    }
+}
@@ -0,0 +1,256 @@
+//! # Venue Request Gate
+//!
+//! Caps the number of quote requests in flight to each venue.
+//!
+//! A venue's contract limits how many requests it accepts at once
+//! ([`VenueConfig::max_concurrent_requests`]); exceeding it gets us rate
+//! limited or blacklisted. [`VenueRequestGate`] holds a semaphore per venue
+//! sized from that setting. A request waits a bounded time for a permit and
+//! is refused with [`CONCURRENCY_LIMIT_REASON`] if none frees up.
+//!
+//! Limits change at runtime through [`VenueRequestGate::set_limit`]. Growing
+//! a limit takes effect at once; shrinking it retires permits as in-flight
+//! requests finish, so the venue never sees more than the old limit and
+//! settles at the new one. Venues without a limit are not gated.
+//!
+//! [`VenueConfig::max_concurrent_requests`]: crate::domain::entities::venue::VenueConfig::max_concurrent_requests
+
+use crate::domain::entities::venue::Venue;
+use crate::domain::value_objects::VenueId;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Reason recorded for requests refused because the venue is at its limit.
+pub const CONCURRENCY_LIMIT_REASON: &str = "concurrency limit";
+
+/// Semaphore and configured size for one venue.
+#[derive(Debug)]
+struct VenueLimit {
+    semaphore: Arc<Semaphore>,
+    state: Mutex<LimitState>,
+}
+
+#[derive(Debug)]
+struct LimitState {
+    /// Configured maximum of concurrent requests.
+    limit: usize,
+    /// Permits still held by requests that must be retired on release.
+    retiring: usize,
+}
+
+impl VenueLimit {
+    fn resize(&self, limit: usize) {
+        let mut state = self.state.lock();
+        if limit > state.limit {
+            let grow = limit - state.limit;
+            let cancelled = grow.min(state.retiring);
+            state.retiring -= cancelled;
+            self.semaphore.add_permits(grow - cancelled);
+        } else {
+            let shrink = state.limit - limit;
+            let forgotten = self.semaphore.forget_permits(shrink);
+            state.retiring += shrink - forgotten;
+        }
+        state.limit = limit;
+    }
+}
+
+/// Permission to send one request to a venue.
+///
+/// Released when dropped.
+#[derive(Debug)]
+pub struct VenueRequestPermit {
+    held: Option<(OwnedSemaphorePermit, Arc<VenueLimit>)>,
+}
+
+impl Drop for VenueRequestPermit {
+    fn drop(&mut self) {
+        if let Some((permit, limit)) = self.held.take() {
+            let mut state = limit.state.lock();
+            if state.retiring > 0 {
+                state.retiring -= 1;
+                permit.forget();
+            }
+        }
+    }
+}
+
+/// Per-venue concurrency limits for quote requests.
+#[derive(Default)]
+pub struct VenueRequestGate {
+    limits: Mutex<HashMap<VenueId, Arc<VenueLimit>>>,
+}
+
+impl VenueRequestGate {
+    /// Creates a gate with no venue limits.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a gate limited by each venue's configured
+    /// `max_concurrent_requests`.
+    #[must_use]
+    pub fn from_venues<'a>(venues: impl IntoIterator<Item = &'a Venue>) -> Self {
+        let gate = Self::new();
+        for venue in venues {
+            gate.sync_venue(venue);
+        }
+        gate
+    }
+
+    /// Applies a venue's configured `max_concurrent_requests`.
+    pub fn sync_venue(&self, venue: &Venue) {
+        self.set_limit(venue.id(), venue.config().max_concurrent_requests());
+    }
+
+    /// Sets the maximum number of concurrent requests to a venue.
+    ///
+    /// A limit of zero is treated as one: a venue is never starved entirely
+    /// by its concurrency setting.
+    pub fn set_limit(&self, venue_id: &VenueId, limit: u32) {
+        let limit = usize::try_from(limit.max(1)).unwrap_or(usize::MAX);
+        let venue_limit = Arc::clone(self.limits.lock().entry(venue_id.clone()).or_insert_with(
+            || {
+                Arc::new(VenueLimit {
+                    semaphore: Arc::new(Semaphore::new(0)),
+                    state: Mutex::new(LimitState {
+                        limit: 0,
+                        retiring: 0,
+                    }),
+                })
+            },
+        ));
+        venue_limit.resize(limit);
+    }
+
+    /// Removes a venue's limit; its requests are no longer gated.
+    pub fn remove_limit(&self, venue_id: &VenueId) {
+        self.limits.lock().remove(venue_id);
+    }
+
+    /// Returns the configured limit of a venue, if it is gated.
+    #[must_use]
+    pub fn limit(&self, venue_id: &VenueId) -> Option<u32> {
+        self.limits
+            .lock()
+            .get(venue_id)
+            .map(|limit| u32::try_from(limit.state.lock().limit).unwrap_or(u32::MAX))
+    }
+
+    /// Waits up to `wait` for permission to send a request to a venue.
+    ///
+    /// Returns `None` if the venue stayed at its limit for the whole wait.
+    /// Venues without a limit are admitted immediately.
+    pub async fn acquire(&self, venue_id: &VenueId, wait: Duration) -> Option<VenueRequestPermit> {
+        let Some(limit) = self.limits.lock().get(venue_id).cloned() else {
+            return Some(VenueRequestPermit { held: None });
+        };
+        let permit = tokio::time::timeout(wait, Arc::clone(&limit.semaphore).acquire_owned())
+            .await
+            .ok()?
+            .ok()?;
+        Some(VenueRequestPermit {
+            held: Some((permit, limit)),
+        })
+    }
+}
+
+impl fmt::Debug for VenueRequestGate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VenueRequestGate")
+            .field("venues", &self.limits.lock().len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::venue::VenueConfig;
+    use crate::domain::value_objects::VenueType;
+
+    const WAIT: Duration = Duration::from_millis(20);
+
+    fn venue_id() -> VenueId {
+        VenueId::new("venue-1")
+    }
+
+    #[tokio::test]
+    async fn refuses_requests_beyond_the_limit() {
+        let gate = VenueRequestGate::new();
+        gate.set_limit(&venue_id(), 2);
+
+        let first = gate.acquire(&venue_id(), WAIT).await;
+        let second = gate.acquire(&venue_id(), WAIT).await;
+        assert!(first.is_some() && second.is_some());
+        assert!(gate.acquire(&venue_id(), WAIT).await.is_none());
+
+        drop(first);
+        assert!(gate.acquire(&venue_id(), WAIT).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn ungated_venues_are_admitted() {
+        let gate = VenueRequestGate::new();
+
+        assert!(gate.acquire(&venue_id(), WAIT).await.is_some());
+        assert_eq!(gate.limit(&venue_id()), None);
+    }
+
+    #[tokio::test]
+    async fn shrinking_retires_permits_as_requests_finish() {
+        let gate = VenueRequestGate::new();
+        gate.set_limit(&venue_id(), 3);
+        let held: Vec<_> = [
+            gate.acquire(&venue_id(), WAIT).await.unwrap(),
+            gate.acquire(&venue_id(), WAIT).await.unwrap(),
+            gate.acquire(&venue_id(), WAIT).await.unwrap(),
+        ]
+        .into();
+
+        gate.set_limit(&venue_id(), 1);
+        drop(held);
+
+        let only = gate.acquire(&venue_id(), WAIT).await;
+        assert!(only.is_some());
+        assert!(gate.acquire(&venue_id(), WAIT).await.is_none());
+        assert_eq!(gate.limit(&venue_id()), Some(1));
+    }
+
+    #[tokio::test]
+    async fn growing_cancels_pending_retirements_first() {
+        let gate = VenueRequestGate::new();
+        gate.set_limit(&venue_id(), 2);
+        let first = gate.acquire(&venue_id(), WAIT).await.unwrap();
+        let second = gate.acquire(&venue_id(), WAIT).await.unwrap();
+
+        gate.set_limit(&venue_id(), 1);
+        gate.set_limit(&venue_id(), 3);
+        drop((first, second));
+
+        let held: Vec<_> = [
+            gate.acquire(&venue_id(), WAIT).await,
+            gate.acquire(&venue_id(), WAIT).await,
+            gate.acquire(&venue_id(), WAIT).await,
+        ]
+        .into();
+        assert!(held.iter().all(Option::is_some));
+        assert!(gate.acquire(&venue_id(), WAIT).await.is_none());
+    }
+
+    #[test]
+    fn syncs_limit_from_venue_config() {
+        let mut venue = Venue::new(venue_id(), "Venue 1", VenueType::ExternalMM);
+        *venue.config_mut() = VenueConfig::with_defaults(1000, 5, true);
+
+        let gate = VenueRequestGate::from_venues([&venue]);
+
+        assert_eq!(gate.limit(&venue_id()), Some(5));
+    }
+}
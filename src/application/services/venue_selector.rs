@@ -0,0 +1,304 @@
+//! # Venue Selector
+//!
+//! Applies an RFQ's venue allowlist and blocklist before quote fan-out.
+//!
+//! The allowlist wins when both lists are set. Venues that pass the lists
+//! but are inside an active [`MaintenanceWindow`] are skipped as well, so
+//! they are neither sent the RFQ nor charged with a missed response. Venues
+//! left out are reported
+//! with their [`VenueExclusionReason`] so the exclusions can be recorded on
+//! the `QuoteCollectionStarted` event.
+//!
+//! [`VenueExclusionReason`]: crate::domain::value_objects::VenueExclusionReason
+//! [`MaintenanceWindow`]: crate::domain::entities::venue::MaintenanceWindow
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::application::services::VenueSelector;
+//! use otc_rfq::domain::entities::rfq::RfqBuilder;
+//! use otc_rfq::domain::value_objects::enums::AssetClass;
+//! use otc_rfq::domain::value_objects::{
+//!     CounterpartyId, Instrument, OrderSide, Quantity, Symbol, Timestamp, VenueId,
+//! };
+//!
+//! let instrument =
+//!     Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build();
+//! let rfq = RfqBuilder::new(
+//!     CounterpartyId::new("client-1"),
+//!     instrument,
+//!     OrderSide::Buy,
+//!     Quantity::new(1.0).unwrap(),
+//!     Timestamp::now().add_secs(300),
+//! )
+//! .venue_blocklist(vec![VenueId::new("mm-2")])
+//! .build();
+//!
+//! let venues = vec![VenueId::new("mm-1"), VenueId::new("mm-2")];
+//! let selection = VenueSelector.select(&rfq, venues, |v| v).unwrap();
+//! assert_eq!(selection.selected, vec![VenueId::new("mm-1")]);
+//! assert_eq!(selection.excluded.len(), 1);
+//! ```
+
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::entities::venue::MaintenanceWindow;
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{ExcludedVenue, VenueExclusionReason, VenueId};
+
+/// Venues an RFQ fans out to, and the ones left out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VenueSelection<T> {
+    /// Venues the RFQ may be sent to, in candidate order.
+    pub selected: Vec<T>,
+    /// Venues left out by the RFQ's lists or by maintenance.
+    pub excluded: Vec<ExcludedVenue>,
+}
+
+/// Filters candidate venues through an RFQ's allowlist and blocklist, and
+/// the venues' maintenance schedules.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VenueSelector;
+
+impl VenueSelector {
+    /// Splits `candidates` into eligible and excluded venues for `rfq`.
+    ///
+    /// `venue_id` extracts the venue ID from a candidate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::NoEligibleVenues` if no candidate is eligible.
+    pub fn select<T>(
+        &self,
+        rfq: &Rfq,
+        candidates: Vec<T>,
+        venue_id: impl Fn(&T) -> &VenueId,
+    ) -> DomainResult<VenueSelection<T>> {
+        self.select_at(rfq, candidates, venue_id, |_| &[], Timestamp::now())
+    }
+
+    /// Like [`select`](Self::select), but also skips candidates with a
+    /// maintenance window active at `now`.
+    ///
+    /// `maintenance` returns a candidate's scheduled windows.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::NoEligibleVenues` if no candidate is eligible.
+    pub fn select_at<'a, T>(
+        &self,
+        rfq: &Rfq,
+        candidates: Vec<T>,
+        venue_id: impl Fn(&T) -> &VenueId,
+        maintenance: impl Fn(&T) -> &'a [MaintenanceWindow],
+        now: Timestamp,
+    ) -> DomainResult<VenueSelection<T>> {
+        let candidate_count = candidates.len();
+        let mut selected = Vec::with_capacity(candidate_count);
+        let mut excluded = Vec::new();
+        for candidate in candidates {
+            let id = venue_id(&candidate);
+            let reason = rfq.venue_exclusion(id).or_else(|| {
+                maintenance(&candidate)
+                    .iter()
+                    .any(|window| window.is_active_at(now))
+                    .then_some(VenueExclusionReason::InMaintenance)
+            });
+            match reason {
+                Some(reason) => excluded.push(ExcludedVenue::new(id.clone(), reason)),
+                None => selected.push(candidate),
+            }
+        }
+
+        if selected.is_empty() {
+            return Err(DomainError::NoEligibleVenues(format!(
+                "none of {} available venue(s) pass the RFQ's venue allowlist/blocklist \
+                 and maintenance schedule",
+                candidate_count
+            )));
+        }
+
+        Ok(VenueSelection { selected, excluded })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::entities::venue::Venue;
+    use crate::domain::value_objects::enums::{AssetClass, VenueType};
+    use crate::domain::value_objects::{CounterpartyId, Instrument, OrderSide, Quantity, Symbol};
+
+    fn builder() -> RfqBuilder {
+        let instrument =
+            Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build();
+        RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+    }
+
+    fn venues(ids: &[&str]) -> Vec<VenueId> {
+        ids.iter().map(|id| VenueId::new(*id)).collect()
+    }
+
+    #[test]
+    fn unrestricted_rfq_selects_every_venue() {
+        let rfq = builder().build();
+
+        let selection = VenueSelector
+            .select(&rfq, venues(&["mm-1", "mm-2"]), |v| v)
+            .unwrap();
+
+        assert_eq!(selection.selected, venues(&["mm-1", "mm-2"]));
+        assert!(selection.excluded.is_empty());
+    }
+
+    #[test]
+    fn blocklist_excludes_venue() {
+        let rfq = builder().venue_blocklist(venues(&["mm-2"])).build();
+
+        let selection = VenueSelector
+            .select(&rfq, venues(&["mm-1", "mm-2", "mm-3"]), |v| v)
+            .unwrap();
+
+        assert_eq!(selection.selected, venues(&["mm-1", "mm-3"]));
+        assert_eq!(
+            selection.excluded,
+            vec![ExcludedVenue::new(
+                VenueId::new("mm-2"),
+                VenueExclusionReason::Blocklisted
+            )]
+        );
+    }
+
+    #[test]
+    fn allowlist_restricts_venues() {
+        let rfq = builder().venue_allowlist(venues(&["mm-1", "mm-3"])).build();
+
+        let selection = VenueSelector
+            .select(&rfq, venues(&["mm-1", "mm-2", "mm-3"]), |v| v)
+            .unwrap();
+
+        assert_eq!(selection.selected, venues(&["mm-1", "mm-3"]));
+        assert_eq!(
+            selection.excluded,
+            vec![ExcludedVenue::new(
+                VenueId::new("mm-2"),
+                VenueExclusionReason::NotAllowlisted
+            )]
+        );
+    }
+
+    #[test]
+    fn conflicting_lists_follow_allowlist() {
+        let rfq = builder()
+            .venue_allowlist(venues(&["mm-1"]))
+            .venue_blocklist(venues(&["mm-1", "mm-2"]))
+            .build();
+
+        let selection = VenueSelector
+            .select(&rfq, venues(&["mm-1", "mm-2"]), |v| v)
+            .unwrap();
+
+        assert_eq!(selection.selected, venues(&["mm-1"]));
+        assert_eq!(
+            selection.excluded[0].reason,
+            VenueExclusionReason::NotAllowlisted
+        );
+    }
+
+    #[test]
+    fn empty_selection_is_rejected() {
+        let rfq = builder().venue_blocklist(venues(&["mm-1", "mm-2"])).build();
+
+        let result = VenueSelector.select(&rfq, venues(&["mm-1", "mm-2"]), |v| v);
+
+        assert!(matches!(result, Err(DomainError::NoEligibleVenues(_))));
+    }
+
+    fn venue_in_maintenance(id: &str, start: Timestamp, end: Timestamp) -> Venue {
+        let mut venue = Venue::new(VenueId::new(id), id, VenueType::ExternalMM);
+        venue.add_maintenance_window(MaintenanceWindow::new(start, end, "upgrade").unwrap());
+        venue
+    }
+
+    #[test]
+    fn venue_in_active_maintenance_window_is_excluded() {
+        let rfq = builder().build();
+        let start = Timestamp::from_secs(1_800_000_000).unwrap();
+        let end = start.add_secs(3600);
+        let in_maintenance = venue_in_maintenance("mm-1", start, end);
+        let open = Venue::new(VenueId::new("mm-2"), "mm-2", VenueType::ExternalMM);
+
+        let selection = VenueSelector
+            .select_at(
+                &rfq,
+                vec![&in_maintenance, &open],
+                |v| v.id(),
+                |v| v.maintenance_windows(),
+                start.add_secs(60),
+            )
+            .unwrap();
+
+        assert_eq!(selection.selected.len(), 1);
+        assert_eq!(selection.selected[0].id(), &VenueId::new("mm-2"));
+        assert_eq!(
+            selection.excluded,
+            vec![ExcludedVenue::new(
+                VenueId::new("mm-1"),
+                VenueExclusionReason::InMaintenance
+            )]
+        );
+    }
+
+    #[test]
+    fn venue_is_selected_one_second_after_window_ends() {
+        let rfq = builder().build();
+        let start = Timestamp::from_secs(1_800_000_000).unwrap();
+        let end = start.add_secs(3600);
+
+        let venue = venue_in_maintenance("mm-1", start, end);
+
+        let selection = VenueSelector
+            .select_at(
+                &rfq,
+                vec![&venue],
+                |v| v.id(),
+                |v| v.maintenance_windows(),
+                end.add_secs(1),
+            )
+            .unwrap();
+
+        assert_eq!(selection.selected.len(), 1);
+        assert!(selection.excluded.is_empty());
+    }
+
+    #[test]
+    fn list_exclusion_takes_precedence_over_maintenance() {
+        let rfq = builder().venue_blocklist(venues(&["mm-1"])).build();
+        let start = Timestamp::from_secs(1_800_000_000).unwrap();
+        let blocked = venue_in_maintenance("mm-1", start, start.add_secs(3600));
+        let open = Venue::new(VenueId::new("mm-2"), "mm-2", VenueType::ExternalMM);
+
+        let selection = VenueSelector
+            .select_at(
+                &rfq,
+                vec![&blocked, &open],
+                |v| v.id(),
+                |v| v.maintenance_windows(),
+                start,
+            )
+            .unwrap();
+
+        assert_eq!(
+            selection.excluded[0].reason,
+            VenueExclusionReason::Blocklisted
+        );
+    }
+}
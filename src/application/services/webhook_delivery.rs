@@ -0,0 +1,786 @@
+//! # Webhook Delivery Service
+//!
+//! Pushes trade and settlement events to subscribed downstream systems.
+//!
+//! [`WebhookDeliveryService`] consumes the domain event bus — a broadcast
+//! of every event appended through a [`BroadcastingEventStore`] — and
+//! posts each event a [`WebhookSubscription`] filters on to its endpoint.
+//! Bodies are signed with the subscription's secret exactly like RFQ
+//! broadcasts (see [`rfq_webhook`](crate::infrastructure::notifications::rfq_webhook)),
+//! so the receiver can verify the HMAC and reject replays by timestamp.
+//!
+//! Transient failures are retried with the configured backoff. Every
+//! attempt updates the [`WebhookDelivery`] record, and the final outcome
+//! counts toward the subscription's consecutive failures: at
+//! [`AUTO_DISABLE_THRESHOLD`] the subscription is deactivated and a
+//! [`WebhookSubscriptionDisabled`] alert is published.
+//!
+//! Versioned events are delivered once per (aggregate ID, version): an
+//! event re-published after a retried append is recognized and skipped.
+//! The envelope carries both, so receivers can de-duplicate the same way.
+//!
+//! The service is also an [`EventConsumer`]: run on the bus through a
+//! [`DeadLetterQueue`](crate::application::services::dead_letter_queue::DeadLetterQueue),
+//! an event whose subscriptions cannot be loaded is parked for re-drive
+//! rather than dropped.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use otc_rfq::application::services::webhook_delivery::{
+//!     BroadcastingEventStore, WebhookDeliveryService,
+//! };
+//!
+//! let (event_store, events) = BroadcastingEventStore::new(inner, 1024);
+//! tokio::spawn(Arc::new(service).run(events));
+//! ```
+//!
+//! [`AUTO_DISABLE_THRESHOLD`]: crate::domain::entities::webhook_subscription::AUTO_DISABLE_THRESHOLD
+
+use crate::application::error::{ApplicationError, ApplicationResult, InfrastructureError};
+use crate::application::services::dead_letter_queue::EventConsumer;
+use crate::application::services::retry::{RetryPolicy, execute_with_retry};
+use crate::domain::entities::webhook_subscription::{WEBHOOK_EVENTS, WebhookSubscription};
+use crate::domain::events::domain_event::EventType;
+use crate::domain::events::webhook_events::WebhookSubscriptionDisabled;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    CorrelationId, RfqId, WebhookDeliveryId, WebhookSubscriptionId,
+};
+use crate::infrastructure::notifications::{RfqWebhookClient, WebhookError};
+use crate::infrastructure::persistence::cursor::PageCursor;
+use crate::infrastructure::persistence::event_store::{EventStore, EventStoreResult, StoredEvent};
+use crate::infrastructure::persistence::traits::{RepositoryError, WebhookSubscriptionRepository};
+use crate::infrastructure::persistence::webhook_delivery_log::{
+    WebhookDelivery, WebhookDeliveryLog, WebhookDeliveryStatus,
+};
+use async_trait::async_trait;
+use futures::future::join_all;
+use parking_lot::Mutex;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+/// Number of delivered (aggregate ID, version) pairs remembered before the
+/// set is reset; forgetting only risks a duplicate post.
+const DELIVERED_VERSIONS_CAPACITY: usize = 100_000;
+
+/// Publisher for webhook alert events.
+#[async_trait]
+pub trait WebhookEventPublisher: Send + Sync + fmt::Debug {
+    /// Publishes a webhook subscription disabled event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be published.
+    async fn publish_webhook_subscription_disabled(
+        &self,
+        event: WebhookSubscriptionDisabled,
+    ) -> ApplicationResult<()>;
+}
+
+/// Delivers subscribed domain events to webhook endpoints.
+#[derive(Debug)]
+pub struct WebhookDeliveryService {
+    subscriptions: Arc<dyn WebhookSubscriptionRepository>,
+    deliveries: Arc<dyn WebhookDeliveryLog>,
+    client: RfqWebhookClient,
+    publisher: Arc<dyn WebhookEventPublisher>,
+    policy: RetryPolicy,
+    delivered: Mutex<HashSet<(Uuid, u64)>>,
+}
+
+impl WebhookDeliveryService {
+    /// Name of the service as an [`EventConsumer`].
+    pub const CONSUMER: &'static str = "webhook_delivery";
+
+    /// Creates a delivery service retrying failed attempts per `policy`.
+    #[must_use]
+    pub fn new(
+        subscriptions: Arc<dyn WebhookSubscriptionRepository>,
+        deliveries: Arc<dyn WebhookDeliveryLog>,
+        client: RfqWebhookClient,
+        publisher: Arc<dyn WebhookEventPublisher>,
+        policy: RetryPolicy,
+    ) -> Self {
+        Self {
+            subscriptions,
+            deliveries,
+            client,
+            publisher,
+            policy,
+            delivered: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Returns the subscription repository.
+    #[must_use]
+    pub fn subscriptions(&self) -> &Arc<dyn WebhookSubscriptionRepository> {
+        &self.subscriptions
+    }
+
+    /// Returns the delivery log.
+    #[must_use]
+    pub fn deliveries(&self) -> &Arc<dyn WebhookDeliveryLog> {
+        &self.deliveries
+    }
+
+    /// Consumes the event bus until it closes, delivering each event in
+    /// its own task so a slow endpoint does not hold up later events.
+    pub async fn run(self: Arc<Self>, mut events: broadcast::Receiver<StoredEvent>) {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    let service = Arc::clone(&self);
+                    tokio::spawn(async move {
+                        if let Err(e) = service.handle_event(&event).await {
+                            tracing::warn!(
+                                event_id = %event.event_id,
+                                error = %e,
+                                "Webhook delivery failed"
+                            );
+                        }
+                    });
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "Webhook delivery fell behind the event bus");
+                }
+                Err(broadcast::error::RecvError::Closed) => return,
+            }
+        }
+    }
+
+    /// Delivers `event` to every active subscription that filters on it.
+    ///
+    /// Returns the deliveries made, one per matching subscription. A
+    /// versioned event whose (aggregate ID, version) was already delivered
+    /// is skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscriptions cannot be loaded.
+    pub async fn handle_event(
+        &self,
+        event: &StoredEvent,
+    ) -> ApplicationResult<Vec<WebhookDelivery>> {
+        if !WEBHOOK_EVENTS.contains(&event.event_name.as_str()) {
+            return Ok(Vec::new());
+        }
+        let key = event.aggregate_key();
+        if key.is_some_and(|key| self.delivered.lock().contains(&key)) {
+            tracing::debug!(event_id = %event.event_id, "Skipping redelivered event");
+            return Ok(Vec::new());
+        }
+        let subscriptions = self
+            .subscriptions
+            .find_active()
+            .await
+            .map_err(repository_error)?;
+        // Marked only once subscriptions are loaded, so a re-drive of an
+        // event that failed here still delivers it.
+        if let Some(key) = key {
+            let mut delivered = self.delivered.lock();
+            if !delivered.insert(key) {
+                return Ok(Vec::new());
+            }
+            if delivered.len() > DELIVERED_VERSIONS_CAPACITY {
+                delivered.clear();
+                delivered.insert(key);
+            }
+        }
+
+        let payload = envelope(event);
+        let deliveries = subscriptions
+            .iter()
+            .filter(|subscription| subscription.matches(&event.event_name))
+            .map(|subscription| {
+                let delivery = WebhookDelivery::pending(
+                    subscription.id(),
+                    event.event_id,
+                    event.event_name.clone(),
+                    payload.clone(),
+                );
+                self.deliver(subscription, delivery)
+            });
+        Ok(join_all(deliveries).await)
+    }
+
+    /// Re-sends a recorded delivery to its subscription.
+    ///
+    /// The payload is signed afresh, so the receiver sees a current
+    /// timestamp. The redelivery is recorded as a new delivery that
+    /// references the original, and is made even if the subscription has
+    /// been deactivated, so an operator can confirm a repaired endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotFound` if the subscription does not exist or the
+    /// delivery does not belong to it.
+    pub async fn redeliver(
+        &self,
+        subscription_id: WebhookSubscriptionId,
+        delivery_id: WebhookDeliveryId,
+    ) -> ApplicationResult<WebhookDelivery> {
+        let subscription = self
+            .subscriptions
+            .find_by_id(subscription_id)
+            .await
+            .map_err(repository_error)?
+            .ok_or_else(|| {
+                ApplicationError::not_found("Webhook subscription", subscription_id.to_string())
+            })?;
+        let original = self
+            .deliveries
+            .find_by_id(delivery_id)
+            .await
+            .map_err(repository_error)?
+            .filter(|delivery| delivery.subscription_id == subscription_id)
+            .ok_or_else(|| {
+                ApplicationError::not_found("Webhook delivery", delivery_id.to_string())
+            })?;
+
+        Ok(self.deliver(&subscription, original.redelivery()).await)
+    }
+
+    async fn deliver(
+        &self,
+        subscription: &WebhookSubscription,
+        mut delivery: WebhookDelivery,
+    ) -> WebhookDelivery {
+        self.record(&delivery).await;
+
+        let attempts = &AtomicU32::new(0);
+        let pending = &delivery;
+        let result = execute_with_retry(&self.policy, move || async move {
+            let attempt = attempts.fetch_add(1, Ordering::SeqCst).saturating_add(1);
+            let result = self
+                .client
+                .deliver(subscription.url(), subscription.secret(), &pending.payload)
+                .await;
+            if let Err(e) = &result {
+                let mut progress = pending.clone();
+                progress.attempts = attempt;
+                progress.response_status = response_status(e);
+                progress.last_error = Some(e.to_string());
+                progress.updated_at = Timestamp::now();
+                self.record(&progress).await;
+            }
+            result
+        })
+        .await;
+
+        delivery.attempts = attempts.load(Ordering::SeqCst);
+        delivery.updated_at = Timestamp::now();
+        match &result {
+            Ok(()) => {
+                delivery.status = WebhookDeliveryStatus::Succeeded;
+                delivery.response_status = None;
+                delivery.last_error = None;
+            }
+            Err(e) => {
+                delivery.status = WebhookDeliveryStatus::Failed;
+                delivery.response_status = response_status(e.inner());
+                delivery.last_error = Some(e.inner().to_string());
+            }
+        }
+        self.record(&delivery).await;
+        self.update_subscription(subscription.id(), &delivery).await;
+        delivery
+    }
+
+    /// Applies a delivery outcome to the stored subscription, publishing
+    /// an alert if it was auto-disabled.
+    ///
+    /// The subscription is re-read so edits made while the delivery was
+    /// retrying are kept.
+    async fn update_subscription(
+        &self,
+        subscription_id: WebhookSubscriptionId,
+        delivery: &WebhookDelivery,
+    ) {
+        let mut subscription = match self.subscriptions.find_by_id(subscription_id).await {
+            Ok(Some(subscription)) => subscription,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::warn!(%subscription_id, error = %e, "Failed to load webhook subscription");
+                return;
+            }
+        };
+
+        let disabled = if delivery.status == WebhookDeliveryStatus::Succeeded {
+            subscription.record_success();
+            false
+        } else {
+            subscription.record_failure()
+        };
+        if let Err(e) = self.subscriptions.save(&subscription).await {
+            tracing::warn!(%subscription_id, error = %e, "Failed to save webhook subscription");
+            return;
+        }
+
+        if disabled {
+            tracing::warn!(
+                %subscription_id,
+                url = subscription.url(),
+                failures = subscription.consecutive_failures(),
+                "Webhook subscription disabled after consecutive failures"
+            );
+            let event = WebhookSubscriptionDisabled::new(
+                subscription_id,
+                subscription.url(),
+                subscription.consecutive_failures(),
+                delivery.last_error.clone(),
+            );
+            if let Err(e) = self
+                .publisher
+                .publish_webhook_subscription_disabled(event)
+                .await
+            {
+                tracing::warn!(%subscription_id, error = %e, "Failed to publish webhook alert");
+            }
+        }
+    }
+
+    /// Stores a delivery record; a logging failure never aborts delivery.
+    async fn record(&self, delivery: &WebhookDelivery) {
+        if let Err(e) = self.deliveries.save(delivery).await {
+            tracing::warn!(delivery_id = %delivery.id, error = %e, "Failed to record webhook delivery");
+        }
+    }
+}
+
+/// Body posted for an event: its identity and the event payload.
+///
+/// Receivers should de-duplicate on `event_id`, or on `aggregate_id` and
+/// `aggregate_version` for versioned events, since retries and redeliveries
+/// post the same event more than once.
+fn envelope(event: &StoredEvent) -> serde_json::Value {
+    serde_json::json!({
+        "event_id": event.event_id,
+        "event_name": event.event_name,
+        "rfq_id": event.rfq_id,
+        "aggregate_id": event.aggregate_id,
+        "aggregate_version": event.aggregate_version,
+        "occurred_at": event.timestamp,
+        "data": event.payload,
+    })
+}
+
+fn repository_error(error: RepositoryError) -> ApplicationError {
+    InfrastructureError::Repository(error).into()
+}
+
+fn response_status(error: &WebhookError) -> Option<u16> {
+    match error {
+        WebhookError::Status(status) | WebhookError::Throttled { status, .. } => Some(*status),
+        WebhookError::Request(_) | WebhookError::Serialization(_) => None,
+    }
+}
+
+#[async_trait]
+impl EventConsumer for WebhookDeliveryService {
+    fn name(&self) -> &str {
+        Self::CONSUMER
+    }
+
+    /// Delivers the event; only a failure to load the subscriptions, which
+    /// happens before anything is sent, is an error, so a re-drive does not
+    /// deliver twice.
+    async fn handle(&self, event: &StoredEvent) -> ApplicationResult<()> {
+        self.handle_event(event).await.map(|_| ())
+    }
+}
+
+/// [`EventStore`] decorator that publishes every appended event on the
+/// event bus.
+///
+/// Events are published only after they are stored, so a subscriber never
+/// sees an event that was not persisted. Publishing never fails the
+/// append: with no subscribers the event is simply dropped.
+#[derive(Debug)]
+pub struct BroadcastingEventStore {
+    inner: Arc<dyn EventStore>,
+    sender: broadcast::Sender<StoredEvent>,
+}
+
+impl BroadcastingEventStore {
+    /// Wraps `inner`, returning the store and a first subscriber to the
+    /// bus. `capacity` bounds how far a subscriber may lag before it
+    /// misses events.
+    #[must_use]
+    pub fn new(
+        inner: Arc<dyn EventStore>,
+        capacity: usize,
+    ) -> (Self, broadcast::Receiver<StoredEvent>) {
+        let (sender, receiver) = broadcast::channel(capacity);
+        (Self { inner, sender }, receiver)
+    }
+
+    /// Returns a new subscriber to the bus.
+    #[must_use]
+    pub fn subscribe(&self) -> broadcast::Receiver<StoredEvent> {
+        self.sender.subscribe()
+    }
+}
+
+#[async_trait]
+impl EventStore for BroadcastingEventStore {
+    async fn append(&self, event: StoredEvent) -> EventStoreResult<()> {
+        self.inner.append(event.clone()).await?;
+        // An error only means nobody is subscribed.
+        let _ = self.sender.send(event);
+        Ok(())
+    }
+
+    async fn get_events(&self, rfq_id: RfqId) -> EventStoreResult<Vec<StoredEvent>> {
+        self.inner.get_events(rfq_id).await
+    }
+
+    async fn get_events_since(&self, since: Timestamp) -> EventStoreResult<Vec<StoredEvent>> {
+        self.inner.get_events_since(since).await
+    }
+
+    async fn get_events_by_type(
+        &self,
+        event_type: EventType,
+    ) -> EventStoreResult<Vec<StoredEvent>> {
+        self.inner.get_events_by_type(event_type).await
+    }
+
+    async fn query_by_time_range(
+        &self,
+        from: Timestamp,
+        to: Timestamp,
+        event_types: Option<Vec<EventType>>,
+        limit: usize,
+        cursor: Option<&PageCursor>,
+    ) -> EventStoreResult<Vec<StoredEvent>> {
+        self.inner
+            .query_by_time_range(from, to, event_types, limit, cursor)
+            .await
+    }
+
+    async fn query_by_correlation(
+        &self,
+        correlation_id: CorrelationId,
+    ) -> EventStoreResult<Vec<StoredEvent>> {
+        self.inner.query_by_correlation(correlation_id).await
+    }
+
+    async fn count(&self) -> EventStoreResult<u64> {
+        self.inner.count().await
+    }
+
+    async fn count_for_rfq(&self, rfq_id: RfqId) -> EventStoreResult<u64> {
+        self.inner.count_for_rfq(rfq_id).await
+    }
+
+    async fn next_sequence(&self, rfq_id: RfqId) -> EventStoreResult<u64> {
+        self.inner.next_sequence(rfq_id).await
+    }
+
+    async fn next_aggregate_sequence(&self, aggregate_id: Uuid) -> EventStoreResult<u64> {
+        self.inner.next_aggregate_sequence(aggregate_id).await
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::webhook_subscription::AUTO_DISABLE_THRESHOLD;
+    use crate::domain::value_objects::EventId;
+    use crate::infrastructure::notifications::rfq_webhook::{
+        DEFAULT_SIGNATURE_TOLERANCE, SIGNATURE_HEADER, TIMESTAMP_HEADER, verify,
+    };
+    use crate::infrastructure::persistence::in_memory::{
+        InMemoryEventStore, InMemoryWebhookDeliveryLog, InMemoryWebhookSubscriptionRepository,
+    };
+    use std::sync::Mutex;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[derive(Debug, Default)]
+    struct RecordingPublisher {
+        events: Mutex<Vec<WebhookSubscriptionDisabled>>,
+    }
+
+    #[async_trait]
+    impl WebhookEventPublisher for RecordingPublisher {
+        async fn publish_webhook_subscription_disabled(
+            &self,
+            event: WebhookSubscriptionDisabled,
+        ) -> ApplicationResult<()> {
+            self.events.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    struct Fixture {
+        service: WebhookDeliveryService,
+        subscriptions: Arc<InMemoryWebhookSubscriptionRepository>,
+        deliveries: Arc<InMemoryWebhookDeliveryLog>,
+        publisher: Arc<RecordingPublisher>,
+    }
+
+    fn fixture(max_retries: u32) -> Fixture {
+        let subscriptions = Arc::new(InMemoryWebhookSubscriptionRepository::new());
+        let deliveries = Arc::new(InMemoryWebhookDeliveryLog::new());
+        let publisher = Arc::new(RecordingPublisher::default());
+        let service = WebhookDeliveryService::new(
+            subscriptions.clone(),
+            deliveries.clone(),
+            RfqWebhookClient::with_default_timeout().unwrap(),
+            publisher.clone(),
+            RetryPolicy::new(max_retries, 1, 1, 1.0, 0.0),
+        );
+        Fixture {
+            service,
+            subscriptions,
+            deliveries,
+            publisher,
+        }
+    }
+
+    async fn subscribe(fixture: &Fixture, url: &str, events: &[&str]) -> WebhookSubscription {
+        let subscription = WebhookSubscription::new(
+            url,
+            "secret",
+            events.iter().map(|event| (*event).to_string()).collect(),
+        )
+        .unwrap();
+        fixture.subscriptions.save(&subscription).await.unwrap();
+        subscription
+    }
+
+    fn event(name: &str) -> StoredEvent {
+        StoredEvent::new(
+            EventId::new_v4(),
+            Some(RfqId::new_v4()),
+            EventType::Trade,
+            name,
+            Timestamp::now(),
+            serde_json::json!({ "trade_id": "t-1" }),
+            1,
+        )
+    }
+
+    async fn endpoint(status: u16) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(status))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn delivers_signed_payload_to_matching_subscriptions() {
+        let server = endpoint(204).await;
+        let fixture = fixture(0);
+        let trades = subscribe(&fixture, &server.uri(), &["TradeExecuted"]).await;
+        let settlements = subscribe(&fixture, &server.uri(), &["SettlementConfirmed"]).await;
+        let executed = event("TradeExecuted");
+
+        let deliveries = fixture.service.handle_event(&executed).await.unwrap();
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0].subscription_id, trades.id());
+        assert_eq!(deliveries[0].status, WebhookDeliveryStatus::Succeeded);
+        assert!(
+            fixture
+                .deliveries
+                .list_after(settlements.id(), None, 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        let timestamp: i64 = request.headers[TIMESTAMP_HEADER]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        let signature = request.headers[SIGNATURE_HEADER].to_str().unwrap();
+        assert!(verify(
+            "secret",
+            timestamp,
+            &request.body,
+            signature,
+            chrono::Utc::now().timestamp(),
+            DEFAULT_SIGNATURE_TOLERANCE,
+        ));
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        assert_eq!(body["event_name"], "TradeExecuted");
+        assert_eq!(body["event_id"], executed.event_id.to_string());
+        assert_eq!(body["data"]["trade_id"], "t-1");
+    }
+
+    #[tokio::test]
+    async fn ignores_events_outside_the_webhook_catalog() {
+        let server = endpoint(204).await;
+        let fixture = fixture(0);
+        subscribe(&fixture, &server.uri(), &["TradeExecuted"]).await;
+
+        let deliveries = fixture
+            .service
+            .handle_event(&event("RfqCreated"))
+            .await
+            .unwrap();
+        assert!(deliveries.is_empty());
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn retries_and_records_every_attempt() {
+        let server = endpoint(503).await;
+        let fixture = fixture(3);
+        let subscription = subscribe(&fixture, &server.uri(), &["TradeExecuted"]).await;
+
+        let deliveries = fixture
+            .service
+            .handle_event(&event("TradeExecuted"))
+            .await
+            .unwrap();
+        let delivery = &deliveries[0];
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Failed);
+        assert_eq!(delivery.attempts, 3);
+        assert_eq!(delivery.response_status, Some(503));
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+
+        let stored = fixture.deliveries.find_by_id(delivery.id).await.unwrap();
+        assert_eq!(stored.as_ref(), Some(delivery));
+        let stored = fixture
+            .subscriptions
+            .find_by_id(subscription.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.consecutive_failures(), 1);
+    }
+
+    #[tokio::test]
+    async fn auto_disables_after_consecutive_failures_and_alerts() {
+        let server = endpoint(400).await;
+        let fixture = fixture(0);
+        let subscription = subscribe(&fixture, &server.uri(), &["TradeExecuted"]).await;
+        let mut almost_failed = subscription.clone();
+        for _ in 1..AUTO_DISABLE_THRESHOLD {
+            almost_failed.record_failure();
+        }
+        fixture.subscriptions.save(&almost_failed).await.unwrap();
+
+        fixture
+            .service
+            .handle_event(&event("TradeExecuted"))
+            .await
+            .unwrap();
+
+        let stored = fixture
+            .subscriptions
+            .find_by_id(subscription.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!stored.is_active());
+        let alerts = fixture.publisher.events.lock().unwrap().clone();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].subscription_id, subscription.id());
+        assert_eq!(alerts[0].consecutive_failures, AUTO_DISABLE_THRESHOLD);
+
+        let deliveries = fixture
+            .service
+            .handle_event(&event("TradeExecuted"))
+            .await
+            .unwrap();
+        assert!(deliveries.is_empty());
+    }
+
+    #[tokio::test]
+    async fn redelivery_resends_payload_as_a_new_delivery() {
+        let failing = endpoint(500).await;
+        let fixture = fixture(0);
+        let mut subscription = subscribe(&fixture, &failing.uri(), &["TradeExecuted"]).await;
+        let original = fixture
+            .service
+            .handle_event(&event("TradeExecuted"))
+            .await
+            .unwrap()
+            .remove(0);
+        assert_eq!(original.status, WebhookDeliveryStatus::Failed);
+
+        let repaired = endpoint(200).await;
+        let mut stored = fixture
+            .subscriptions
+            .find_by_id(subscription.id())
+            .await
+            .unwrap()
+            .unwrap();
+        stored.set_url(repaired.uri()).unwrap();
+        fixture.subscriptions.save(&stored).await.unwrap();
+        subscription = stored;
+
+        let redelivery = fixture
+            .service
+            .redeliver(subscription.id(), original.id)
+            .await
+            .unwrap();
+        assert_eq!(redelivery.status, WebhookDeliveryStatus::Succeeded);
+        assert_eq!(redelivery.redelivery_of, Some(original.id));
+        assert_eq!(redelivery.payload, original.payload);
+        assert_eq!(repaired.received_requests().await.unwrap().len(), 1);
+
+        let other = WebhookSubscriptionId::new_v4();
+        let missing = fixture.service.redeliver(other, original.id).await;
+        assert!(matches!(missing, Err(ref e) if e.is_not_found()));
+    }
+
+    #[tokio::test]
+    async fn versioned_event_is_delivered_once() {
+        let server = endpoint(204).await;
+        let fixture = fixture(0);
+        subscribe(&fixture, &server.uri(), &["TradeExecuted"]).await;
+        let trade_id = uuid::Uuid::new_v4();
+        let executed = event("TradeExecuted").with_aggregate_version(trade_id, 2);
+
+        let deliveries = fixture.service.handle_event(&executed).await.unwrap();
+        assert_eq!(deliveries.len(), 1);
+
+        // Re-published under a new event ID, e.g. after a retried append.
+        let mut republished = executed.clone();
+        republished.event_id = EventId::new_v4();
+        assert!(
+            fixture
+                .service
+                .handle_event(&republished)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        let next = event("TradeExecuted").with_aggregate_version(trade_id, 3);
+        assert_eq!(fixture.service.handle_event(&next).await.unwrap().len(), 1);
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 2);
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["aggregate_id"], trade_id.to_string());
+        assert_eq!(body["aggregate_version"], 2);
+    }
+
+    #[tokio::test]
+    async fn broadcasting_store_publishes_appended_events() {
+        let (store, mut events) =
+            BroadcastingEventStore::new(Arc::new(InMemoryEventStore::new()), 8);
+        let appended = event("TradeExecuted");
+
+        store.append(appended.clone()).await.unwrap();
+
+        assert_eq!(events.recv().await.unwrap(), appended);
+        assert_eq!(store.count().await.unwrap(), 1);
+    }
+}
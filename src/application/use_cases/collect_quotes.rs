@@ -4,20 +4,68 @@
 //!
 //! This use case orchestrates concurrent quote collection from multiple venues,
 //! handling timeouts, partial failures, and state management.
+//!
+//! With a [`ShutdownCoordinator`] attached, collection is refused once
+//! draining starts, and collection cancelled after the grace period marks
+//! the RFQ as failed with [`FailureCode::Shutdown`] and [`SHUTDOWN_REASON`].
+//!
+//! With an [`RfqBroadcastService`] attached, the queried venues are notified
+//! of the RFQ in the background as collection starts, so push-quote market
+//! makers can respond.
+//!
+//! Venues inside a scheduled maintenance window are skipped. With an
+//! [`MmPerformanceRecorder`] attached, `RfqSent` is recorded only for the
+//! venues actually queried, so skipped venues keep a clean response rate,
+//! and each venue that quotes gets a `QuoteReceived` with its response time
+//! and the best rank of its quotes.
+//!
+//! Venues in [`VenueMode::Shadow`] are queried like any other, but their
+//! quotes are kept apart on the RFQ: they are not published, do not count
+//! towards the minimum, and cannot be selected. Each is ranked against the
+//! live quotes as if its venue were live, and that rank is what the
+//! [`MmPerformanceRecorder`] records for the venue.
+//!
+//! With an [`InternalCrossingService`] attached, an RFQ that crosses an
+//! opposing client RFQ takes the internal quote and no venues are queried.
+//!
+//! Scheduled RFQs are refused until their activation time; see
+//! [`ScheduledActivationService`](crate::application::services::scheduled_activation::ScheduledActivationService).
 
 use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::application::services::internal_crossing::InternalCrossingService;
+use crate::application::services::mm_performance_recorder::MmPerformanceRecorder;
+use crate::application::services::ranking_strategy::{BestPriceStrategy, RankingStrategy};
+use crate::application::services::rfq_broadcast::RfqBroadcastService;
+use crate::application::services::rfq_cancellation::RfqCancellationService;
+use crate::application::services::shutdown::{SHUTDOWN_REASON, ShutdownCoordinator};
+use crate::application::services::venue_selector::{VenueSelection, VenueSelector};
 use crate::application::use_cases::create_rfq::RfqRepository;
+use crate::domain::entities::mm_performance::MmPerformanceEventKind;
 use crate::domain::entities::quote::Quote;
+use crate::domain::entities::quote_history::QuoteRank;
 use crate::domain::entities::rfq::Rfq;
-use crate::domain::events::rfq_events::QuoteReceived;
-use crate::domain::value_objects::{RfqId, VenueId};
-use crate::infrastructure::venues::error::VenueError;
+use crate::domain::entities::venue::{MaintenanceWindow, VenueMode};
+use crate::domain::errors::DomainError;
+use crate::domain::events::rfq_events::{
+    QuoteCollectionCompleted, QuoteCollectionStarted, QuoteReceived, QuoteRequestFailed,
+};
+use crate::domain::value_objects::timestamp::{Clock, SystemClock};
+use crate::domain::value_objects::{
+    ExcludedVenue, FailureCode, FailureReason, OrderSide, QuoteId, RfqId, RfqState,
+    VenueExclusionReason, VenueId,
+};
+use crate::infrastructure::metrics;
+use crate::infrastructure::telemetry;
+use crate::infrastructure::venues::error::{VenueError, VenueResult};
 use crate::infrastructure::venues::traits::VenueAdapter;
 use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::time::timeout;
+use tokio_util::sync::CancellationToken;
+use tracing::{Instrument, Span, field, info_span, instrument};
 
 /// Publisher for quote-related domain events.
 #[async_trait]
@@ -28,6 +76,45 @@ pub trait QuoteEventPublisher: Send + Sync + fmt::Debug {
     ///
     /// Returns an error if publishing fails.
     async fn publish_quote_received(&self, event: QuoteReceived) -> Result<(), String>;
+
+    /// Publishes a QuoteCollectionStarted event.
+    ///
+    /// Defaults to a no-op for publishers that only relay quotes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if publishing fails.
+    async fn publish_quote_collection_started(
+        &self,
+        _event: QuoteCollectionStarted,
+    ) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Publishes a QuoteRequestFailed event.
+    ///
+    /// Defaults to a no-op for publishers that only relay quotes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if publishing fails.
+    async fn publish_quote_request_failed(&self, _event: QuoteRequestFailed) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Publishes a QuoteCollectionCompleted event.
+    ///
+    /// Defaults to a no-op for publishers that only relay quotes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if publishing fails.
+    async fn publish_quote_collection_completed(
+        &self,
+        _event: QuoteCollectionCompleted,
+    ) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 /// Registry for available venues.
@@ -38,6 +125,28 @@ pub trait VenueRegistry: Send + Sync + fmt::Debug {
 
     /// Returns a specific venue adapter by ID.
     async fn get_venue(&self, venue_id: &VenueId) -> Option<Arc<dyn VenueAdapter>>;
+
+    /// Returns the venue's minimum quote time-to-live in milliseconds.
+    ///
+    /// Overrides the aggregation-wide floor when set. Defaults to `None`.
+    async fn min_quote_ttl_ms(&self, _venue_id: &VenueId) -> Option<u64> {
+        None
+    }
+
+    /// Returns the venue's scheduled maintenance windows.
+    ///
+    /// Venues inside an active window are not sent RFQs. Defaults to none.
+    async fn maintenance_windows(&self, _venue_id: &VenueId) -> Vec<MaintenanceWindow> {
+        Vec::new()
+    }
+
+    /// Returns how the venue takes part in the RFQ flow.
+    ///
+    /// Quotes from `SHADOW` venues are measured but never offered to
+    /// clients. Defaults to `LIVE`.
+    async fn venue_mode(&self, _venue_id: &VenueId) -> VenueMode {
+        VenueMode::Live
+    }
 }
 
 /// Result of a quote collection attempt from a single venue.
@@ -49,6 +158,8 @@ pub struct VenueQuoteResult {
     pub quote: Option<Quote>,
     /// The error if failed.
     pub error: Option<String>,
+    /// Time the venue took to quote, in milliseconds.
+    pub response_time_ms: Option<u64>,
 }
 
 impl VenueQuoteResult {
@@ -59,6 +170,7 @@ impl VenueQuoteResult {
             venue_id,
             quote: Some(quote),
             error: None,
+            response_time_ms: None,
         }
     }
 
@@ -69,9 +181,17 @@ impl VenueQuoteResult {
             venue_id,
             quote: None,
             error: Some(error.into()),
+            response_time_ms: None,
         }
     }
 
+    /// Sets the time the venue took to respond.
+    #[must_use]
+    pub fn with_response_time(mut self, response_time_ms: u64) -> Self {
+        self.response_time_ms = Some(response_time_ms);
+        self
+    }
+
     /// Returns true if the result is successful.
     #[must_use]
     pub fn is_success(&self) -> bool {
@@ -84,8 +204,10 @@ impl VenueQuoteResult {
 pub struct CollectQuotesResponse {
     /// The RFQ ID.
     pub rfq_id: RfqId,
-    /// Quotes successfully collected.
+    /// Quotes successfully collected from live venues.
     pub quotes: Vec<Quote>,
+    /// Quotes collected from venues in shadow mode.
+    pub shadow_quotes: Vec<Quote>,
     /// Venues that failed to provide quotes.
     pub failures: Vec<VenueQuoteResult>,
     /// Total venues queried.
@@ -93,7 +215,7 @@ pub struct CollectQuotesResponse {
 }
 
 impl CollectQuotesResponse {
-    /// Returns the number of successful quotes.
+    /// Returns the number of successful quotes from live venues.
     #[must_use]
     pub fn success_count(&self) -> usize {
         self.quotes.len()
@@ -155,6 +277,12 @@ pub struct CollectQuotesUseCase {
     event_publisher: Arc<dyn QuoteEventPublisher>,
     venue_registry: Arc<dyn VenueRegistry>,
     config: CollectQuotesConfig,
+    shutdown: Option<ShutdownCoordinator>,
+    broadcast: Option<Arc<RfqBroadcastService>>,
+    clock: Arc<dyn Clock>,
+    performance: Option<Arc<MmPerformanceRecorder>>,
+    cancellations: Option<Arc<RfqCancellationService>>,
+    internal_crossing: Option<Arc<InternalCrossingService>>,
 }
 
 impl CollectQuotesUseCase {
@@ -171,9 +299,64 @@ impl CollectQuotesUseCase {
             event_publisher,
             venue_registry,
             config,
+            shutdown: None,
+            broadcast: None,
+            clock: Arc::new(SystemClock),
+            performance: None,
+            cancellations: None,
+            internal_crossing: None,
         }
     }
 
+    /// Attaches a shutdown coordinator.
+    ///
+    /// Collections are tracked as in flight and cancelled when the
+    /// coordinator's grace period expires.
+    #[must_use]
+    pub fn with_shutdown(mut self, shutdown: ShutdownCoordinator) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    /// Broadcasts RFQs to the queried venues when collection starts.
+    #[must_use]
+    pub fn with_rfq_broadcast(mut self, broadcast: Arc<RfqBroadcastService>) -> Self {
+        self.broadcast = Some(broadcast);
+        self
+    }
+
+    /// Records the RFQs sent to each queried venue, and the quotes they
+    /// return, as market maker performance events.
+    #[must_use]
+    pub fn with_performance_recorder(mut self, recorder: Arc<MmPerformanceRecorder>) -> Self {
+        self.performance = Some(recorder);
+        self
+    }
+
+    /// Records the queried venues so they can be sent cancel notices if the
+    /// RFQ is cancelled.
+    #[must_use]
+    pub fn with_rfq_cancellations(mut self, cancellations: Arc<RfqCancellationService>) -> Self {
+        self.cancellations = Some(cancellations);
+        self
+    }
+
+    /// Tries to cross each RFQ against an opposing client RFQ before
+    /// querying venues.
+    #[must_use]
+    pub fn with_internal_crossing(mut self, crossing: Arc<InternalCrossingService>) -> Self {
+        self.internal_crossing = Some(crossing);
+        self
+    }
+
+    /// Sets the clock used to decide whether a scheduled RFQ may start and
+    /// which maintenance windows are active.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Creates a new CollectQuotesUseCase with default configuration.
     #[must_use]
     pub fn with_defaults(
@@ -200,10 +383,35 @@ impl CollectQuotesUseCase {
     /// Returns an error if:
     /// - RFQ is not found
     /// - RFQ is not in the correct state
+    /// - RFQ is scheduled to activate later
     /// - No venues are available
     /// - All venues fail and min_quotes > 0
     /// - Persistence fails
+    /// - The service is shutting down
+    #[instrument(skip(self), fields(rfq_id = %rfq_id))]
     pub async fn execute(&self, rfq_id: RfqId) -> ApplicationResult<CollectQuotesResponse> {
+        let start = Instant::now();
+        let result = self.collect(rfq_id).await;
+        let outcome = match &result {
+            Ok(_) => "ok",
+            Err(ApplicationError::ShuttingDown) => "cancelled",
+            Err(_) => "error",
+        };
+        metrics::record_quote_collection(start.elapsed(), outcome);
+        result
+    }
+
+    async fn collect(&self, rfq_id: RfqId) -> ApplicationResult<CollectQuotesResponse> {
+        let _in_flight = match &self.shutdown {
+            Some(shutdown) => Some(shutdown.begin().ok_or(ApplicationError::ShuttingDown)?),
+            None => None,
+        };
+        let cancellation = self
+            .shutdown
+            .as_ref()
+            .map(ShutdownCoordinator::cancellation_token)
+            .unwrap_or_default();
+
         // 1. Load RFQ from repository
         let mut rfq = self
             .rfq_repository
@@ -213,25 +421,156 @@ impl CollectQuotesUseCase {
             .ok_or_else(|| ApplicationError::validation(format!("RFQ not found: {}", rfq_id)))?;
 
         // 2. Validate RFQ state and start quote collection
+        if rfq.is_scheduled_at(self.clock.now())
+            && let Some(activate_at) = rfq.activate_at()
+        {
+            return Err(ApplicationError::InvalidState(format!(
+                "RFQ {rfq_id} is scheduled to activate at {activate_at}"
+            )));
+        }
         rfq.start_quote_collection()
             .map_err(ApplicationError::from)?;
 
-        // 3. Get available venues
+        if let Some(crossing) = &self.internal_crossing
+            && let Some(cross) = crossing.try_cross(&rfq).await?
+        {
+            let quotes = cross.rfq.quotes().to_vec();
+            self.publish_quotes_received(rfq_id, &quotes).await;
+            return Ok(CollectQuotesResponse {
+                rfq_id,
+                quotes,
+                shadow_quotes: Vec::new(),
+                failures: Vec::new(),
+                venues_queried: 0,
+            });
+        }
+
+        // 3. Get available venues, narrowed by the RFQ's allowlist/blocklist,
+        //    by scheduled maintenance and by the RFQ's size disclosure
         let venues = self.venue_registry.get_available_venues().await;
-        let venues_queried = venues.len();
 
         if venues.is_empty() {
             return Err(ApplicationError::validation("no venues available"));
         }
 
+        let mut maintenance = HashMap::new();
+        for venue in &venues {
+            let windows = self
+                .venue_registry
+                .maintenance_windows(venue.venue_id())
+                .await;
+            if !windows.is_empty() {
+                maintenance.insert(venue.venue_id().clone(), windows);
+            }
+        }
+        let VenueSelection {
+            selected: venues,
+            mut excluded,
+        } = VenueSelector.select_at(
+            &rfq,
+            venues,
+            |v| v.venue_id(),
+            |v| maintenance.get(v.venue_id()).map_or(&[], Vec::as_slice),
+            self.clock.now(),
+        )?;
+        let (venues, unmaskable): (Vec<_>, Vec<_>) = venues
+            .into_iter()
+            .partition(|v| v.supports_quantity_disclosure(&rfq.quantity_disclosure()));
+        excluded.extend(unmaskable.iter().map(|v| {
+            ExcludedVenue::new(v.venue_id().clone(), VenueExclusionReason::SizeNotMaskable)
+        }));
+        if venues.is_empty() {
+            return Err(DomainError::NoEligibleVenues(format!(
+                "no eligible venue supports quantity disclosure {}",
+                rfq.quantity_disclosure()
+            ))
+            .into());
+        }
+        let venues_queried = venues.len();
+        let mut shadow_venues = HashSet::new();
+        for venue in &venues {
+            if self
+                .venue_registry
+                .venue_mode(venue.venue_id())
+                .await
+                .is_shadow()
+            {
+                shadow_venues.insert(venue.venue_id().clone());
+            }
+        }
+
+        if let Some(performance) = &self.performance {
+            for venue in &venues {
+                performance.record(venue.venue_id(), MmPerformanceEventKind::RfqSent);
+            }
+        }
+        if let Some(cancellations) = &self.cancellations {
+            let venue_ids: Vec<VenueId> = venues.iter().map(|v| v.venue_id().clone()).collect();
+            cancellations.record_dispatch(&rfq, &venue_ids);
+        }
+
+        let mut started = QuoteCollectionStarted::new(
+            rfq_id,
+            venues.iter().map(|v| v.venue_id().clone()).collect(),
+        )
+        .with_excluded_venues(excluded);
+        started.metadata = started
+            .metadata
+            .with_trace_id(telemetry::current_trace_id())
+            .with_aggregate_version(rfq_id.get(), rfq.version());
+        if let Err(e) = self
+            .event_publisher
+            .publish_quote_collection_started(started)
+            .await
+        {
+            tracing::warn!("Failed to publish QuoteCollectionStarted event: {}", e);
+        }
+
+        if let Some(broadcast) = &self.broadcast {
+            let broadcast = Arc::clone(broadcast);
+            let venue_ids: Vec<VenueId> = venues.iter().map(|v| v.venue_id().clone()).collect();
+            let rfq = rfq.clone();
+            tokio::spawn(
+                async move { broadcast.broadcast(&rfq, &venue_ids).await }
+                    .instrument(Span::current()),
+            );
+        }
+
         // 4. Fan-out concurrent requests to all venues
-        let results = self.collect_quotes_from_venues(&rfq, venues).await;
+        let results = self
+            .collect_quotes_from_venues(&rfq, venues, &cancellation)
+            .await;
+
+        if cancellation.is_cancelled() {
+            rfq.mark_failed(FailureReason::new(FailureCode::Shutdown, SHUTDOWN_REASON))
+                .map_err(ApplicationError::from)?;
+            self.rfq_repository
+                .save(&rfq)
+                .await
+                .map_err(ApplicationError::repository)?;
+            metrics::record_rfq_terminal(RfqState::Failed);
+            return Err(ApplicationError::ShuttingDown);
+        }
 
         // 5. Separate successes and failures
         let (quotes, failures): (Vec<_>, Vec<_>) =
             results.into_iter().partition(|r| r.is_success());
 
-        let successful_quotes: Vec<Quote> = quotes.into_iter().filter_map(|r| r.quote).collect();
+        let response_times: HashMap<QuoteId, (VenueId, u64)> = quotes
+            .iter()
+            .filter_map(|r| {
+                let quote_id = r.quote.as_ref()?.id();
+                Some((quote_id, (r.venue_id.clone(), r.response_time_ms?)))
+            })
+            .collect();
+        let (shadow_quotes, successful_quotes): (Vec<Quote>, Vec<Quote>) = quotes
+            .into_iter()
+            .filter_map(|r| r.quote)
+            .map(|quote| {
+                let shadow = shadow_venues.contains(quote.venue_id());
+                quote.with_shadow(shadow)
+            })
+            .partition(Quote::is_shadow);
 
         // 6. Check minimum quotes requirement
         if successful_quotes.len() < self.config.min_quotes {
@@ -242,12 +581,21 @@ impl CollectQuotesUseCase {
             )));
         }
 
-        // 7. Add quotes to RFQ
-        for quote in &successful_quotes {
+        // 7. Add quotes to RFQ and record their ranks for the quote history;
+        //    shadow quotes are ranked for performance tracking only
+        for quote in successful_quotes.iter().chain(&shadow_quotes) {
             if let Err(e) = rfq.receive_quote(quote.clone()) {
                 tracing::warn!("Failed to add quote to RFQ: {}", e);
             }
         }
+        let ranks = rank_quotes(&rfq);
+        let measured: Vec<QuoteRank> = ranks
+            .iter()
+            .chain(&rank_shadow_quotes(&rfq))
+            .copied()
+            .collect();
+        self.record_quotes_received(&measured, &response_times);
+        rfq.record_quote_ranks(ranks);
 
         // 8. Persist updated RFQ
         self.rfq_repository
@@ -256,8 +604,23 @@ impl CollectQuotesUseCase {
             .map_err(ApplicationError::repository)?;
 
         // 9. Publish QuoteReceived events
-        for quote in &successful_quotes {
-            let event = QuoteReceived::new(
+        self.publish_quotes_received(rfq_id, &successful_quotes)
+            .await;
+
+        // 10. Return response
+        Ok(CollectQuotesResponse {
+            rfq_id,
+            quotes: successful_quotes,
+            shadow_quotes,
+            failures,
+            venues_queried,
+        })
+    }
+
+    /// Publishes a `QuoteReceived` event per quote.
+    async fn publish_quotes_received(&self, rfq_id: RfqId, quotes: &[Quote]) {
+        for quote in quotes {
+            let mut event = QuoteReceived::new(
                 rfq_id,
                 quote.id(),
                 quote.venue_id().clone(),
@@ -265,26 +628,52 @@ impl CollectQuotesUseCase {
                 quote.quantity(),
                 quote.valid_until(),
             );
+            event.metadata = event.metadata.with_trace_id(telemetry::current_trace_id());
 
             if let Err(e) = self.event_publisher.publish_quote_received(event).await {
                 tracing::warn!("Failed to publish QuoteReceived event: {}", e);
             }
         }
+    }
 
-        // 10. Return response
-        Ok(CollectQuotesResponse {
-            rfq_id,
-            quotes: successful_quotes,
-            failures,
-            venues_queried,
-        })
+    /// Records a `QuoteReceived` per venue that quoted, with the best rank
+    /// of its quotes.
+    fn record_quotes_received(
+        &self,
+        ranks: &[QuoteRank],
+        response_times: &HashMap<QuoteId, (VenueId, u64)>,
+    ) {
+        let Some(performance) = &self.performance else {
+            return;
+        };
+        let mut by_venue: HashMap<&VenueId, (u64, u64)> = HashMap::new();
+        for rank in ranks {
+            let Some((venue_id, response_time_ms)) = response_times.get(&rank.quote_id) else {
+                continue;
+            };
+            let entry = by_venue
+                .entry(venue_id)
+                .or_insert((*response_time_ms, u64::MAX));
+            entry.1 = entry.1.min(u64::from(rank.rank));
+        }
+        for (venue_id, (response_time_ms, rank)) in by_venue {
+            performance.record(
+                venue_id,
+                MmPerformanceEventKind::QuoteReceived {
+                    response_time_ms,
+                    rank,
+                },
+            );
+        }
     }
 
-    /// Collects quotes from all venues concurrently.
+    /// Collects quotes from all venues concurrently, on both sides of a
+    /// two-way RFQ.
     async fn collect_quotes_from_venues(
         &self,
         rfq: &Rfq,
         venues: Vec<Arc<dyn VenueAdapter>>,
+        cancellation: &CancellationToken,
     ) -> Vec<VenueQuoteResult> {
         let mut handles = Vec::with_capacity(venues.len());
 
@@ -296,16 +685,55 @@ impl CollectQuotesUseCase {
                 self.config.default_timeout_ms
             };
 
-            let handle = tokio::spawn(async move {
+            let cancellation = cancellation.clone();
+            let span = info_span!(
+                "venue_quote",
+                rfq_id = %rfq.id(),
+                venue_id = %venue.venue_id(),
+                quote_id = field::Empty,
+            );
+
+            let handle = tokio::spawn(
+                async move {
                 let venue_id = venue.venue_id().clone();
                 let duration = Duration::from_millis(timeout_ms);
-
-                match timeout(duration, venue.request_quote(&rfq_clone)).await {
-                    Ok(Ok(quote)) => VenueQuoteResult::success(venue_id, quote),
-                    Ok(Err(e)) => VenueQuoteResult::failure(venue_id, format_venue_error(&e)),
-                    Err(_) => VenueQuoteResult::failure(venue_id, "request timed out"),
+                let start = Instant::now();
+
+                let (result, outcome) = tokio::select! {
+                    _ = cancellation.cancelled() => {
+                        (vec![VenueQuoteResult::failure(venue_id.clone(), "request cancelled")], "cancelled")
+                    }
+                    result = timeout(duration, request_quotes(venue.as_ref(), &rfq_clone)) => match result {
+                        Ok(Ok(quotes)) => {
+                            if let Some(quote) = quotes.first() {
+                                Span::current().record("quote_id", field::display(quote.id()));
+                            }
+                            let response_time_ms =
+                                u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+                            let results = quotes
+                                .into_iter()
+                                .map(|quote| {
+                                    VenueQuoteResult::success(venue_id.clone(), quote)
+                                        .with_response_time(response_time_ms)
+                                })
+                                .collect();
+                            (results, "ok")
+                        }
+                        Ok(Err(e)) => (
+                            vec![VenueQuoteResult::failure(venue_id.clone(), format_venue_error(&e))],
+                            "error",
+                        ),
+                        Err(_) => (
+                            vec![VenueQuoteResult::failure(venue_id.clone(), "request timed out")],
+                            "timeout",
+                        ),
+                    },
+                };
+                metrics::record_venue_response(venue_id.as_str(), start.elapsed(), outcome);
+                result
                 }
-            });
+                .instrument(span),
+            );
 
             handles.push(handle);
         }
@@ -314,7 +742,7 @@ impl CollectQuotesUseCase {
         let mut results = Vec::with_capacity(handles.len());
         for handle in handles {
             match handle.await {
-                Ok(result) => results.push(result),
+                Ok(venue_results) => results.extend(venue_results),
                 Err(e) => {
                     tracing::error!("Task panicked: {}", e);
                 }
@@ -325,6 +753,56 @@ impl CollectQuotesUseCase {
     }
 }
 
+/// Requests quotes on every side `rfq` is quoted in.
+/// Ranks the RFQ's live quotes by price, per side.
+pub(crate) fn rank_quotes(rfq: &Rfq) -> Vec<QuoteRank> {
+    let sides = if rfq.is_two_way() {
+        vec![OrderSide::Buy, OrderSide::Sell]
+    } else {
+        vec![rfq.side()]
+    };
+    let strategy = BestPriceStrategy::new();
+    sides
+        .into_iter()
+        .flat_map(|side| {
+            let quotes: Vec<Quote> = rfq.quotes_on(side).cloned().collect();
+            strategy.rank(&quotes, side).into_iter().map(move |ranked| {
+                let rank = u32::try_from(ranked.rank).unwrap_or(u32::MAX);
+                QuoteRank::new(ranked.quote.id(), side, rank)
+            })
+        })
+        .collect()
+}
+
+/// Ranks each shadow quote against the live quotes on its side, as if its
+/// venue were live.
+fn rank_shadow_quotes(rfq: &Rfq) -> Vec<QuoteRank> {
+    let strategy = BestPriceStrategy::new();
+    rfq.shadow_quotes()
+        .iter()
+        .filter_map(|shadow| {
+            let side = rfq.side_of(shadow);
+            let mut quotes: Vec<Quote> = rfq.quotes_on(side).cloned().collect();
+            quotes.push(shadow.clone());
+            let ranked = strategy.rank(&quotes, side);
+            let rank = ranked.iter().find(|r| r.quote.id() == shadow.id())?.rank;
+            Some(QuoteRank::new(
+                shadow.id(),
+                side,
+                u32::try_from(rank).unwrap_or(u32::MAX),
+            ))
+        })
+        .collect()
+}
+
+async fn request_quotes(venue: &dyn VenueAdapter, rfq: &Rfq) -> VenueResult<Vec<Quote>> {
+    if rfq.is_two_way() {
+        venue.request_two_way_quote(rfq).await
+    } else {
+        venue.request_quote(rfq).await.map(|quote| vec![quote])
+    }
+}
+
 /// Formats a venue error for display.
 fn format_venue_error(error: &VenueError) -> String {
     error.to_string()
@@ -334,13 +812,24 @@ fn format_venue_error(error: &VenueError) -> String {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::application::services::internal_crossing::InternalCrossEventPublisher;
+    use crate::application::services::price_bounds::ReferencePriceProvider;
     use crate::application::use_cases::create_rfq::RfqRepository;
     use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::errors::DomainError;
+    use crate::domain::errors::DomainResult;
+    use crate::domain::events::InternalCrossProposed;
+    use crate::domain::value_objects::ReferencePriceSource;
     use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
     use crate::domain::value_objects::symbol::Symbol;
     use crate::domain::value_objects::timestamp::Timestamp;
-    use crate::domain::value_objects::{CounterpartyId, Instrument, OrderSide, Price, Quantity};
-    use crate::infrastructure::venues::error::VenueResult;
+    use crate::domain::value_objects::{
+        CounterpartyId, Instrument, OrderSide, Price, Quantity, RfqDirection,
+    };
+    use crate::infrastructure::persistence::cursor::{paginate, paginate_offset};
+    use crate::infrastructure::persistence::in_memory::InMemoryRfqRepository;
+    use crate::infrastructure::persistence::traits::RfqRepository as PersistedRfqRepository;
+    use crate::infrastructure::persistence::{PageCursor, RfqListFilter};
     use crate::infrastructure::venues::traits::ExecutionResult;
     use std::collections::HashMap;
     use std::sync::Mutex;
@@ -368,11 +857,57 @@ mod tests {
         async fn find_by_id(&self, id: RfqId) -> Result<Option<Rfq>, String> {
             Ok(self.rfqs.lock().unwrap().get(&id).cloned())
         }
+
+        async fn list_after(
+            &self,
+            cursor: Option<&PageCursor>,
+            limit: usize,
+            filter: &RfqListFilter,
+        ) -> Result<Vec<Rfq>, String> {
+            let rfqs = self
+                .rfqs
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .cloned()
+                .collect();
+            Ok(paginate(rfqs, cursor, limit, PageCursor::from_rfq))
+        }
+
+        async fn list_offset(
+            &self,
+            offset: usize,
+            limit: usize,
+            filter: &RfqListFilter,
+        ) -> Result<Vec<Rfq>, String> {
+            let rfqs = self
+                .rfqs
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .cloned()
+                .collect();
+            Ok(paginate_offset(rfqs, offset, limit, PageCursor::from_rfq))
+        }
+
+        async fn count_matching(&self, filter: &RfqListFilter) -> Result<u64, String> {
+            let count = self
+                .rfqs
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .count();
+            Ok(count as u64)
+        }
     }
 
     #[derive(Debug, Default)]
     struct MockQuoteEventPublisher {
         events: Mutex<Vec<QuoteReceived>>,
+        started: Mutex<Vec<QuoteCollectionStarted>>,
     }
 
     #[async_trait]
@@ -381,6 +916,14 @@ mod tests {
             self.events.lock().unwrap().push(event);
             Ok(())
         }
+
+        async fn publish_quote_collection_started(
+            &self,
+            event: QuoteCollectionStarted,
+        ) -> Result<(), String> {
+            self.started.lock().unwrap().push(event);
+            Ok(())
+        }
     }
 
     #[derive(Debug)]
@@ -443,6 +986,15 @@ mod tests {
                 }))
         }
 
+        async fn request_two_way_quote(&self, rfq: &Rfq) -> VenueResult<Vec<Quote>> {
+            let offer = self.request_quote(rfq).await?;
+            let bid = create_test_quote(rfq.id(), self.venue_id.as_str());
+            Ok(vec![
+                offer.with_side(OrderSide::Buy),
+                bid.with_side(OrderSide::Sell),
+            ])
+        }
+
         async fn execute_trade(&self, _quote: &Quote) -> VenueResult<ExecutionResult> {
             unimplemented!()
         }
@@ -459,15 +1011,34 @@ mod tests {
     #[derive(Debug)]
     struct MockVenueRegistry {
         venues: Vec<Arc<dyn VenueAdapter>>,
+        maintenance: HashMap<VenueId, Vec<MaintenanceWindow>>,
+        shadow: HashSet<VenueId>,
     }
 
     impl MockVenueRegistry {
         fn with_venues(venues: Vec<Arc<dyn VenueAdapter>>) -> Self {
-            Self { venues }
+            Self {
+                venues,
+                maintenance: HashMap::new(),
+                shadow: HashSet::new(),
+            }
         }
 
         fn empty() -> Self {
-            Self { venues: vec![] }
+            Self::with_venues(vec![])
+        }
+
+        fn with_maintenance(mut self, venue_id: &str, window: MaintenanceWindow) -> Self {
+            self.maintenance
+                .entry(VenueId::new(venue_id))
+                .or_default()
+                .push(window);
+            self
+        }
+
+        fn with_shadow(mut self, venue_id: &str) -> Self {
+            self.shadow.insert(VenueId::new(venue_id));
+            self
         }
     }
 
@@ -483,6 +1054,18 @@ mod tests {
                 .find(|v| v.venue_id() == venue_id)
                 .cloned()
         }
+
+        async fn maintenance_windows(&self, venue_id: &VenueId) -> Vec<MaintenanceWindow> {
+            self.maintenance.get(venue_id).cloned().unwrap_or_default()
+        }
+
+        async fn venue_mode(&self, venue_id: &VenueId) -> VenueMode {
+            if self.shadow.contains(venue_id) {
+                VenueMode::Shadow
+            } else {
+                VenueMode::Live
+            }
+        }
     }
 
     fn create_test_rfq() -> Rfq {
@@ -550,6 +1133,366 @@ mod tests {
         assert!(response.has_quotes());
     }
 
+    #[tokio::test]
+    async fn execute_records_quote_ranks() {
+        let rfq = create_test_rfq();
+        let rfq_id = rfq.id();
+        let side = rfq.side();
+        let repo = Arc::new(MockRfqRepository::with_rfq(rfq));
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(MockVenueAdapter::successful("venue-1", rfq_id)),
+            Arc::new(MockVenueAdapter::successful("venue-2", rfq_id)),
+        ];
+        let use_case = CollectQuotesUseCase::new(
+            Arc::clone(&repo) as Arc<dyn RfqRepository>,
+            Arc::new(MockQuoteEventPublisher::default()),
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            CollectQuotesConfig::with_timeout(100),
+        );
+
+        use_case.execute(rfq_id).await.unwrap();
+
+        let saved = repo.find_by_id(rfq_id).await.unwrap().unwrap();
+        let mut ranks: Vec<u32> = saved.quote_ranks().iter().map(|r| r.rank).collect();
+        ranks.sort_unstable();
+        assert_eq!(ranks, vec![1, 2]);
+        assert!(saved.quote_ranks().iter().all(|r| r.side == side));
+        assert!(
+            saved
+                .quote_ranks()
+                .iter()
+                .all(|r| saved.quotes().iter().any(|q| q.id() == r.quote_id))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_two_way_collects_both_sides() {
+        let base = create_test_rfq();
+        let rfq = RfqBuilder::new(
+            base.client_id().clone(),
+            base.instrument().clone(),
+            RfqDirection::TwoWay,
+            base.quantity(),
+            base.expires_at(),
+        )
+        .build();
+        let rfq_id = rfq.id();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(MockVenueAdapter::successful("venue-1", rfq_id)),
+            Arc::new(MockVenueAdapter::successful("venue-2", rfq_id)),
+        ];
+        let use_case = create_use_case(
+            MockRfqRepository::with_rfq(rfq),
+            MockVenueRegistry::with_venues(venues),
+        );
+
+        let response = use_case.execute(rfq_id).await.unwrap();
+        assert_eq!(response.success_count(), 4);
+        for side in [OrderSide::Buy, OrderSide::Sell] {
+            let on_side = response
+                .quotes
+                .iter()
+                .filter(|q| q.side() == Some(side))
+                .count();
+            assert_eq!(on_side, 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_refuses_scheduled_rfq_until_activation_time() {
+        use crate::domain::value_objects::timestamp::MockClock;
+
+        let activate_at = Timestamp::now().add_secs(60);
+        let rfq = RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            Instrument::new(
+                Symbol::new("BTC/USD").unwrap(),
+                AssetClass::CryptoSpot,
+                SettlementMethod::default(),
+            ),
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            activate_at.add_secs(300),
+        )
+        .activate_at(activate_at)
+        .build();
+        let rfq_id = rfq.id();
+        let clock = Arc::new(MockClock::new(activate_at.sub_secs(1)));
+
+        let venues: Vec<Arc<dyn VenueAdapter>> =
+            vec![Arc::new(MockVenueAdapter::successful("venue-1", rfq_id))];
+        let use_case = create_use_case(
+            MockRfqRepository::with_rfq(rfq),
+            MockVenueRegistry::with_venues(venues),
+        )
+        .with_clock(clock.clone());
+
+        let result = use_case.execute(rfq_id).await;
+        assert!(matches!(result, Err(ApplicationError::InvalidState(_))));
+
+        clock.advance_secs(1);
+        let response = use_case.execute(rfq_id).await.unwrap();
+        assert_eq!(response.success_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn execute_skips_blocklisted_venue_and_records_exclusion() {
+        use crate::domain::value_objects::{ExcludedVenue, VenueExclusionReason};
+
+        let base = create_test_rfq();
+        let rfq = RfqBuilder::new(
+            base.client_id().clone(),
+            base.instrument().clone(),
+            base.side(),
+            base.quantity(),
+            base.expires_at(),
+        )
+        .venue_blocklist(vec![VenueId::new("venue-2")])
+        .build();
+        let rfq_id = rfq.id();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(MockVenueAdapter::successful("venue-1", rfq_id)),
+            Arc::new(MockVenueAdapter::successful("venue-2", rfq_id)),
+        ];
+        let publisher = Arc::new(MockQuoteEventPublisher::default());
+        let use_case = CollectQuotesUseCase::new(
+            Arc::new(MockRfqRepository::with_rfq(rfq)),
+            Arc::clone(&publisher) as Arc<dyn QuoteEventPublisher>,
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            CollectQuotesConfig::with_timeout(100),
+        );
+
+        let response = use_case.execute(rfq_id).await.unwrap();
+        assert_eq!(response.venues_queried, 1);
+        assert_eq!(
+            response.quotes.first().unwrap().venue_id(),
+            &VenueId::new("venue-1")
+        );
+
+        let started = publisher.started.lock().unwrap();
+        let started = started.first().unwrap();
+        assert_eq!(started.venue_ids, vec![VenueId::new("venue-1")]);
+        assert_eq!(
+            started.excluded_venues,
+            vec![ExcludedVenue::new(
+                VenueId::new("venue-2"),
+                VenueExclusionReason::Blocklisted
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_skips_venue_in_maintenance_without_charging_its_metrics() {
+        use crate::domain::services::mm_performance::MmPerformanceTracker;
+        use crate::domain::value_objects::{ExcludedVenue, VenueExclusionReason};
+        use crate::infrastructure::persistence::in_memory::InMemoryMmPerformanceRepository;
+
+        let rfq = create_test_rfq();
+        let rfq_id = rfq.id();
+        let now = Timestamp::now();
+        let window =
+            MaintenanceWindow::new(now.sub_secs(60), now.add_secs(3600), "upgrade").unwrap();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(MockVenueAdapter::successful("venue-1", rfq_id)),
+            Arc::new(MockVenueAdapter::successful("venue-2", rfq_id)),
+        ];
+        let tracker = Arc::new(MmPerformanceTracker::with_defaults(Arc::new(
+            InMemoryMmPerformanceRepository::new(),
+        )));
+        let recorder = Arc::new(MmPerformanceRecorder::new(Arc::clone(&tracker)));
+        let publisher = Arc::new(MockQuoteEventPublisher::default());
+        let use_case = CollectQuotesUseCase::new(
+            Arc::new(MockRfqRepository::with_rfq(rfq)),
+            Arc::clone(&publisher) as Arc<dyn QuoteEventPublisher>,
+            Arc::new(MockVenueRegistry::with_venues(venues).with_maintenance("venue-2", window)),
+            CollectQuotesConfig::with_timeout(100),
+        )
+        .with_performance_recorder(Arc::clone(&recorder));
+
+        let response = use_case.execute(rfq_id).await.unwrap();
+        assert_eq!(response.venues_queried, 1);
+        recorder.flush().await;
+
+        let excluded = publisher
+            .started
+            .lock()
+            .unwrap()
+            .first()
+            .unwrap()
+            .excluded_venues
+            .clone();
+        assert_eq!(
+            excluded,
+            vec![ExcludedVenue::new(
+                VenueId::new("venue-2"),
+                VenueExclusionReason::InMaintenance
+            )]
+        );
+
+        let queried = tracker
+            .get_metrics(&CounterpartyId::new("venue-1"))
+            .await
+            .unwrap();
+        assert_eq!(queried.total_rfqs_received(), 1);
+        let skipped = tracker
+            .get_metrics(&CounterpartyId::new("venue-2"))
+            .await
+            .unwrap();
+        assert_eq!(skipped.total_rfqs_received(), 0);
+        assert_eq!(skipped.response_rate_pct(), None);
+    }
+
+    #[tokio::test]
+    async fn execute_keeps_shadow_quotes_from_client_but_measures_them() {
+        use crate::domain::services::mm_performance::MmPerformanceTracker;
+        use crate::infrastructure::persistence::in_memory::InMemoryMmPerformanceRepository;
+
+        let rfq = create_test_rfq();
+        let rfq_id = rfq.id();
+        let repo = Arc::new(MockRfqRepository::with_rfq(rfq));
+        let better = Quote::new(
+            rfq_id,
+            VenueId::new("venue-2"),
+            Price::new(49000.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(60),
+        )
+        .unwrap();
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(MockVenueAdapter::successful("venue-1", rfq_id)),
+            Arc::new(MockVenueAdapter {
+                venue_id: VenueId::new("venue-2"),
+                quote_result: Mutex::new(Some(Ok(better))),
+                delay_ms: 0,
+            }),
+        ];
+        let tracker = Arc::new(MmPerformanceTracker::with_defaults(Arc::new(
+            InMemoryMmPerformanceRepository::new(),
+        )));
+        let recorder = Arc::new(MmPerformanceRecorder::new(Arc::clone(&tracker)));
+        let publisher = Arc::new(MockQuoteEventPublisher::default());
+        let use_case = CollectQuotesUseCase::new(
+            Arc::clone(&repo) as Arc<dyn RfqRepository>,
+            Arc::clone(&publisher) as Arc<dyn QuoteEventPublisher>,
+            Arc::new(MockVenueRegistry::with_venues(venues).with_shadow("venue-2")),
+            CollectQuotesConfig::with_timeout(100),
+        )
+        .with_performance_recorder(Arc::clone(&recorder));
+
+        let response = use_case.execute(rfq_id).await.unwrap();
+        recorder.flush().await;
+
+        assert_eq!(response.success_count(), 1);
+        assert_eq!(response.shadow_quotes.len(), 1);
+        assert_eq!(publisher.events.lock().unwrap().len(), 1);
+        let saved = repo.find_by_id(rfq_id).await.unwrap().unwrap();
+        assert!(
+            saved
+                .quotes()
+                .iter()
+                .all(|q| q.venue_id() == &VenueId::new("venue-1"))
+        );
+        assert_eq!(saved.shadow_quotes().len(), 1);
+        assert_eq!(saved.quote_ranks().len(), 1);
+
+        let shadow = tracker
+            .get_metrics(&CounterpartyId::new("venue-2"))
+            .await
+            .unwrap();
+        assert_eq!(shadow.total_rfqs_received(), 1);
+        assert_eq!(shadow.total_quotes_provided(), 1);
+        assert_eq!(shadow.total_best_quotes(), 1);
+    }
+
+    #[tokio::test]
+    async fn execute_records_dispatch_for_cancellation() {
+        let rfq = create_test_rfq();
+        let rfq_id = rfq.id();
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(MockVenueAdapter::successful("venue-1", rfq_id)),
+            Arc::new(MockVenueAdapter::successful("venue-2", rfq_id)),
+        ];
+        let registry: Arc<dyn VenueRegistry> = Arc::new(MockVenueRegistry::with_venues(venues));
+        let cancellations = Arc::new(RfqCancellationService::new(Arc::clone(&registry)));
+        let use_case = CollectQuotesUseCase::new(
+            Arc::new(MockRfqRepository::with_rfq(rfq)),
+            Arc::new(MockQuoteEventPublisher::default()),
+            registry,
+            CollectQuotesConfig::with_timeout(100),
+        )
+        .with_rfq_cancellations(Arc::clone(&cancellations));
+
+        use_case.execute(rfq_id).await.unwrap();
+
+        let mut dispatched = cancellations.dispatched_venues(rfq_id);
+        dispatched.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        assert_eq!(
+            dispatched,
+            vec![VenueId::new("venue-1"), VenueId::new("venue-2")]
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_fails_when_no_venue_can_mask_quantity() {
+        use crate::domain::value_objects::QuantityDisclosure;
+
+        let base = create_test_rfq();
+        let rfq = RfqBuilder::new(
+            base.client_id().clone(),
+            base.instrument().clone(),
+            base.side(),
+            base.quantity(),
+            base.expires_at(),
+        )
+        .quantity_disclosure(QuantityDisclosure::Hidden)
+        .build();
+        let rfq_id = rfq.id();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> =
+            vec![Arc::new(MockVenueAdapter::successful("venue-1", rfq_id))];
+        let use_case = create_use_case(
+            MockRfqRepository::with_rfq(rfq),
+            MockVenueRegistry::with_venues(venues),
+        );
+
+        let result = use_case.execute(rfq_id).await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::NoEligibleVenues(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_fails_when_allowlist_matches_no_venue() {
+        let base = create_test_rfq();
+        let rfq = RfqBuilder::new(
+            base.client_id().clone(),
+            base.instrument().clone(),
+            base.side(),
+            base.quantity(),
+            base.expires_at(),
+        )
+        .venue_allowlist(vec![VenueId::new("venue-9")])
+        .build();
+        let rfq_id = rfq.id();
+
+        let venues: Vec<Arc<dyn VenueAdapter>> =
+            vec![Arc::new(MockVenueAdapter::successful("venue-1", rfq_id))];
+        let use_case = create_use_case(
+            MockRfqRepository::with_rfq(rfq),
+            MockVenueRegistry::with_venues(venues),
+        );
+
+        let result = use_case.execute(rfq_id).await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::NoEligibleVenues(_)))
+        ));
+    }
+
     #[tokio::test]
     async fn execute_partial_failure() {
         let rfq = create_test_rfq();
@@ -628,6 +1571,67 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn shutdown_drains_fast_collection_and_fails_slow_one() {
+        use crate::domain::value_objects::RfqState;
+
+        let fast_rfq = create_test_rfq();
+        let slow_rfq = create_test_rfq();
+        let (fast_id, slow_id) = (fast_rfq.id(), slow_rfq.id());
+        let repo = Arc::new(MockRfqRepository::default());
+        repo.save(&fast_rfq).await.unwrap();
+        repo.save(&slow_rfq).await.unwrap();
+
+        let shutdown = ShutdownCoordinator::new(Duration::from_millis(200));
+        let use_case = |venue: MockVenueAdapter| {
+            CollectQuotesUseCase::new(
+                Arc::clone(&repo) as Arc<dyn RfqRepository>,
+                Arc::new(MockQuoteEventPublisher::default()),
+                Arc::new(MockVenueRegistry::with_venues(vec![Arc::new(venue)])),
+                CollectQuotesConfig::with_timeout(5000),
+            )
+            .with_shutdown(shutdown.clone())
+        };
+        let fast = use_case(MockVenueAdapter {
+            delay_ms: 20,
+            ..MockVenueAdapter::successful("fast", fast_id)
+        });
+        let slow = use_case(MockVenueAdapter {
+            delay_ms: 900,
+            ..MockVenueAdapter::successful("slow", slow_id)
+        });
+
+        let fast_task = tokio::spawn(async move { fast.execute(fast_id).await });
+        let slow_task = tokio::spawn(async move { slow.execute(slow_id).await });
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        let report = shutdown.drain().await;
+        assert_eq!(report.in_flight, 2);
+        assert!(report.cancelled);
+        assert_eq!(report.abandoned, 0);
+
+        assert!(fast_task.await.unwrap().is_ok());
+        assert!(matches!(
+            slow_task.await.unwrap(),
+            Err(ApplicationError::ShuttingDown)
+        ));
+
+        let fast_rfq = repo.find_by_id(fast_id).await.unwrap().unwrap();
+        assert_eq!(fast_rfq.state(), RfqState::QuotesReceived);
+        let slow_rfq = repo.find_by_id(slow_id).await.unwrap().unwrap();
+        assert_eq!(slow_rfq.state(), RfqState::Failed);
+        assert_eq!(
+            slow_rfq.failure_reason(),
+            Some(&FailureReason::new(FailureCode::Shutdown, SHUTDOWN_REASON))
+        );
+
+        let late = use_case(MockVenueAdapter::successful("late", fast_id));
+        assert!(matches!(
+            late.execute(fast_id).await,
+            Err(ApplicationError::ShuttingDown)
+        ));
+    }
+
     #[tokio::test]
     async fn execute_rfq_not_found() {
         let use_case = create_use_case(MockRfqRepository::default(), MockVenueRegistry::empty());
@@ -679,6 +1683,92 @@ mod tests {
         ));
     }
 
+    #[derive(Debug)]
+    struct FixedReference;
+
+    #[async_trait]
+    impl ReferencePriceProvider for FixedReference {
+        async fn get_reference(
+            &self,
+            _instrument: &Instrument,
+        ) -> DomainResult<Option<(Price, ReferencePriceSource)>> {
+            Ok(Some((
+                Price::new(50000.0).unwrap(),
+                ReferencePriceSource::ClobMid,
+            )))
+        }
+    }
+
+    #[derive(Debug)]
+    struct NoopCrossPublisher;
+
+    #[async_trait]
+    impl InternalCrossEventPublisher for NoopCrossPublisher {
+        async fn publish_internal_cross_proposed(
+            &self,
+            _event: InternalCrossProposed,
+        ) -> ApplicationResult<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_crosses_internally_without_querying_venues() {
+        let store = Arc::new(InMemoryRfqRepository::new());
+        let mut resting = RfqBuilder::new(
+            CounterpartyId::new("client-2"),
+            create_test_rfq().instrument().clone(),
+            OrderSide::Sell,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .allow_internal_crossing(true)
+        .build();
+        resting.start_quote_collection().unwrap();
+        PersistedRfqRepository::save(store.as_ref(), &resting)
+            .await
+            .unwrap();
+
+        let base = create_test_rfq();
+        let rfq = RfqBuilder::new(
+            base.client_id().clone(),
+            base.instrument().clone(),
+            OrderSide::Buy,
+            base.quantity(),
+            base.expires_at(),
+        )
+        .allow_internal_crossing(true)
+        .build();
+        let rfq_id = rfq.id();
+        PersistedRfqRepository::save(store.as_ref(), &rfq)
+            .await
+            .unwrap();
+
+        let crossing = Arc::new(InternalCrossingService::new(
+            store.clone(),
+            Arc::new(FixedReference),
+            Arc::new(NoopCrossPublisher),
+        ));
+        let venues: Vec<Arc<dyn VenueAdapter>> =
+            vec![Arc::new(MockVenueAdapter::successful("venue-1", rfq_id))];
+        let use_case = create_use_case(
+            MockRfqRepository::with_rfq(rfq),
+            MockVenueRegistry::with_venues(venues),
+        )
+        .with_internal_crossing(crossing);
+
+        let response = use_case.execute(rfq_id).await.unwrap();
+
+        assert_eq!(response.venues_queried, 0);
+        assert_eq!(response.success_count(), 1);
+        assert!(response.quotes.iter().all(|q| q.venue_id().is_internal()));
+        let stored = PersistedRfqRepository::get(store.as_ref(), rfq_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.state(), RfqState::QuotesReceived);
+    }
+
     #[test]
     fn venue_quote_result_success() {
         let venue_id = VenueId::new("test");
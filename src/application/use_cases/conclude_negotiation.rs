@@ -0,0 +1,757 @@
+//! # Conclude Negotiation Use Case
+//!
+//! Use case for accepting a negotiation and executing its agreed terms.
+//!
+//! This module provides the [`ConcludeNegotiationUseCase`], which accepts a
+//! negotiation's latest counter-quote and books the [`NegotiatedTerms`] as a
+//! bilateral [`Trade`] between the requester and the market maker. No venue
+//! is called: the terms were agreed directly, so the trade is recorded
+//! against the venue of the RFQ quote the counter answered.
+//!
+//! # Pre-Trade Checks
+//!
+//! Before anything is written, the terms go through the same gates as a
+//! venue execution:
+//!
+//! - When a [`ComplianceService`] is configured, the requester's KYC, AML,
+//!   sanctions and trading limits are checked for the agreed quantity.
+//! - When a [`PriceBoundsValidator`] is configured, the agreed price is
+//!   checked against the instrument's reference price. There is no override.
+//! - The RFQ must still accept the answered quote: it is selected and its
+//!   execution started and completed, so an expired quote or an RFQ that
+//!   moved on fails the conclusion.
+//!
+//! A failed check reverts the acceptance: the negotiation returns to
+//! `CounterPending` with the reason in
+//! [`conclusion_failure`](Negotiation::conclusion_failure), and the error is
+//! returned.
+//!
+//! # Persistence
+//!
+//! The three aggregates are written in order, without a shared transaction:
+//!
+//! 1. The RFQ, now `Executed`. Its version check is the commit point: a
+//!    concurrent execution of the RFQ makes it fail, and the acceptance is
+//!    reverted.
+//! 2. The trade, linked to the RFQ and to the negotiation.
+//! 3. The negotiation, now `Accepted`.
+//!
+//! A failure after the RFQ is written leaves the conclusion incomplete, and
+//! a retry completes it instead of trading twice: the trade is looked up by
+//! negotiation, and an RFQ already executed on the answered quote is not
+//! executed again. `TradeExecuted` and `NegotiationConcluded` are published
+//! once every write has succeeded, by whichever call completed the
+//! conclusion. Accepting a negotiation that is already concluded returns its
+//! trade without side effects.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::application::services::{
+    PriceBoundsValidator, RetryError, RetryPolicy, execute_with_retry,
+};
+use crate::application::use_cases::create_rfq::{ComplianceService, RfqRepository};
+use crate::application::use_cases::execute_trade::{TradeEventPublisher, TradeRepository};
+use crate::domain::entities::negotiation::{NegotiatedTerms, Negotiation};
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::entities::trade::Trade;
+use crate::domain::events::{NegotiationConcluded, PositionUpdated, TradeExecuted};
+use crate::domain::value_objects::{
+    NegotiationId, NegotiationState, PriceBoundsCheck, RfqState, SettlementMethod,
+};
+use crate::infrastructure::persistence::traits::{NegotiationRepository, RepositoryError};
+use crate::infrastructure::telemetry;
+use async_trait::async_trait;
+use rust_decimal::prelude::ToPrimitive;
+use std::fmt;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// Publisher for negotiation conclusion events.
+#[async_trait]
+pub trait NegotiationEventPublisher: Send + Sync + fmt::Debug {
+    /// Publishes a negotiation concluded event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be published.
+    async fn publish_negotiation_concluded(
+        &self,
+        event: NegotiationConcluded,
+    ) -> ApplicationResult<()>;
+}
+
+/// Response from concluding a negotiation.
+#[derive(Debug, Clone)]
+pub struct ConcludeNegotiationResponse {
+    /// The trade executing the agreed terms.
+    pub trade: Trade,
+    /// The accepted negotiation.
+    pub negotiation: Negotiation,
+    /// True if the negotiation had already been concluded by an earlier
+    /// call, and this one changed nothing.
+    pub already_concluded: bool,
+}
+
+/// Use case for accepting a negotiation and executing its agreed terms.
+///
+/// Orchestrates the conclusion workflow:
+/// 1. Load the negotiation and its RFQ
+/// 2. Return the existing trade if the negotiation was already concluded
+/// 3. Accept the latest counter-quote and derive the negotiated terms
+/// 4. Run the compliance, limit and price bounds checks
+/// 5. Execute the RFQ on the answered quote
+/// 6. Persist the RFQ, the trade and the negotiation
+/// 7. Publish `TradeExecuted`, `PositionUpdated` and `NegotiationConcluded`
+pub struct ConcludeNegotiationUseCase {
+    negotiations: Arc<dyn NegotiationRepository>,
+    rfq_repository: Arc<dyn RfqRepository>,
+    trade_repository: Arc<dyn TradeRepository>,
+    trade_events: Arc<dyn TradeEventPublisher>,
+    negotiation_events: Arc<dyn NegotiationEventPublisher>,
+    compliance_service: Option<Arc<dyn ComplianceService>>,
+    price_bounds_validator: Option<Arc<PriceBoundsValidator>>,
+    persistence_retry: RetryPolicy,
+}
+
+impl fmt::Debug for ConcludeNegotiationUseCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConcludeNegotiationUseCase")
+            .field("negotiations", &self.negotiations)
+            .field("rfq_repository", &self.rfq_repository)
+            .field("trade_repository", &self.trade_repository)
+            .field("trade_events", &self.trade_events)
+            .field("negotiation_events", &self.negotiation_events)
+            .field("compliance_service", &self.compliance_service.is_some())
+            .field(
+                "price_bounds_validator",
+                &self.price_bounds_validator.is_some(),
+            )
+            .field("persistence_retry", &self.persistence_retry)
+            .finish()
+    }
+}
+
+impl ConcludeNegotiationUseCase {
+    /// Creates a new ConcludeNegotiationUseCase.
+    #[must_use]
+    pub fn new(
+        negotiations: Arc<dyn NegotiationRepository>,
+        rfq_repository: Arc<dyn RfqRepository>,
+        trade_repository: Arc<dyn TradeRepository>,
+        trade_events: Arc<dyn TradeEventPublisher>,
+        negotiation_events: Arc<dyn NegotiationEventPublisher>,
+    ) -> Self {
+        Self {
+            negotiations,
+            rfq_repository,
+            trade_repository,
+            trade_events,
+            negotiation_events,
+            compliance_service: None,
+            price_bounds_validator: None,
+            persistence_retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets the service checking the requester's KYC, AML, sanctions and
+    /// trading limits before the trade is booked.
+    #[must_use]
+    pub fn with_compliance_service(
+        mut self,
+        compliance_service: Arc<dyn ComplianceService>,
+    ) -> Self {
+        self.compliance_service = Some(compliance_service);
+        self
+    }
+
+    /// Sets the validator that checks the agreed price before the trade is
+    /// booked.
+    #[must_use]
+    pub fn with_price_bounds_validator(
+        mut self,
+        price_bounds_validator: Arc<PriceBoundsValidator>,
+    ) -> Self {
+        self.price_bounds_validator = Some(price_bounds_validator);
+        self
+    }
+
+    /// Sets the retry policy for persisting the trade.
+    ///
+    /// Only transient repository failures are retried. Defaults to
+    /// [`RetryPolicy::default`].
+    #[must_use]
+    pub fn with_persistence_retry(mut self, persistence_retry: RetryPolicy) -> Self {
+        self.persistence_retry = persistence_retry;
+        self
+    }
+
+    /// Accepts the negotiation's latest counter-quote and executes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The negotiation or its RFQ is not found
+    /// - The negotiation cannot be accepted (no counter-quotes, or rejected
+    ///   or expired)
+    /// - A pre-trade check fails, in which case the acceptance is reverted
+    /// - The RFQ was executed concurrently, in which case the acceptance is
+    ///   reverted
+    /// - The trade or negotiation cannot be persisted; retrying completes
+    ///   the conclusion
+    #[instrument(skip_all, fields(negotiation_id = %negotiation_id))]
+    pub async fn accept(
+        &self,
+        negotiation_id: NegotiationId,
+    ) -> ApplicationResult<ConcludeNegotiationResponse> {
+        let mut negotiation = self
+            .negotiations
+            .find_by_id(negotiation_id)
+            .await
+            .map_err(repository_error)?
+            .ok_or_else(|| {
+                ApplicationError::not_found("Negotiation", negotiation_id.to_string())
+            })?;
+        let mut rfq = self
+            .rfq_repository
+            .find_by_id(negotiation.rfq_id())
+            .await
+            .map_err(ApplicationError::RepositoryError)?
+            .ok_or_else(|| ApplicationError::RfqNotFound(negotiation.rfq_id().to_string()))?;
+
+        let trades = self.trade_repository.find_by_rfq_id(rfq.id()).await?;
+        if let Some(trade) = trades
+            .iter()
+            .find(|trade| trade.negotiation_id() == Some(negotiation_id))
+        {
+            return self.complete(negotiation, trade.clone()).await;
+        }
+
+        if negotiation.state() != NegotiationState::Accepted {
+            negotiation.accept()?;
+        }
+        let terms = negotiation.negotiated_terms().ok_or_else(|| {
+            ApplicationError::InvalidState(format!(
+                "negotiation {negotiation_id} has no accepted counter-quote"
+            ))
+        })?;
+
+        // An RFQ executed on the answered quote with no trade yet was
+        // committed by an earlier call that failed before booking the trade
+        let committed = rfq.state() == RfqState::Executed
+            && rfq.selected_quote_id() == Some(terms.quote_id)
+            && trades.is_empty();
+        let price_bounds = if committed {
+            None
+        } else {
+            let checked = match self.check(&mut rfq, &terms).await {
+                Ok(checked) => checked,
+                Err(e) => return Err(self.revert(negotiation, e).await),
+            };
+            if let Err(e) = self.rfq_repository.save(&rfq).await {
+                return Err(self
+                    .revert(negotiation, ApplicationError::RepositoryError(e))
+                    .await);
+            }
+            checked
+        };
+
+        let trade = Self::create_trade(&rfq, &terms, price_bounds)?;
+        execute_with_retry(&self.persistence_retry, || {
+            self.trade_repository.save(&trade)
+        })
+        .await
+        .map_err(RetryError::into_inner)
+        .inspect_err(|e| {
+            tracing::error!(
+                rfq_id = %rfq.id(),
+                trade_id = %trade.id(),
+                error = %e,
+                "RFQ executed on negotiated terms but trade could not be persisted"
+            );
+        })?;
+
+        self.negotiations
+            .save(&negotiation)
+            .await
+            .map_err(repository_error)?;
+        self.publish(&rfq, &negotiation, &terms, &trade).await;
+
+        Ok(ConcludeNegotiationResponse {
+            trade,
+            negotiation,
+            already_concluded: false,
+        })
+    }
+
+    /// Runs the pre-trade checks and executes the RFQ in memory.
+    ///
+    /// Returns the price bounds check to record on the trade, if one was
+    /// made.
+    async fn check(
+        &self,
+        rfq: &mut Rfq,
+        terms: &NegotiatedTerms,
+    ) -> ApplicationResult<Option<PriceBoundsCheck>> {
+        let instrument = rfq.instrument().clone();
+
+        if let Some(compliance) = &self.compliance_service {
+            let quantity = terms.quantity.get().to_f64().ok_or_else(|| {
+                ApplicationError::validation(format!(
+                    "quantity {} cannot be checked for compliance",
+                    terms.quantity
+                ))
+            })?;
+            let result = compliance
+                .pre_check(
+                    &terms.requester,
+                    instrument.base_asset(),
+                    instrument.quote_asset(),
+                    quantity,
+                )
+                .await
+                .map_err(ApplicationError::repository)?;
+            if !result.passed {
+                return Err(ApplicationError::compliance_failed(
+                    result
+                        .reason
+                        .unwrap_or_else(|| "compliance check failed".to_string()),
+                ));
+            }
+        }
+
+        let price_bounds = match &self.price_bounds_validator {
+            Some(validator) => Some(PriceBoundsCheck::Passed(
+                validator
+                    .validate(
+                        &instrument,
+                        &terms.price,
+                        validator.liquidity_for(&instrument),
+                    )
+                    .await?,
+            )),
+            None => None,
+        };
+
+        let already_selected = rfq.state() == RfqState::ClientSelecting
+            && rfq.selected_quote_id() == Some(terms.quote_id);
+        if !already_selected {
+            rfq.select_quote_on_side(terms.quote_id, terms.side)?;
+        }
+        rfq.start_execution()?;
+        rfq.mark_executed()?;
+
+        Ok(price_bounds)
+    }
+
+    /// Reverts the acceptance after `error` and persists the negotiation.
+    ///
+    /// Returns `error`; a failure to persist the reverted negotiation is
+    /// logged, since nothing was booked.
+    async fn revert(
+        &self,
+        mut negotiation: Negotiation,
+        error: ApplicationError,
+    ) -> ApplicationError {
+        tracing::warn!(
+            negotiation_id = %negotiation.id(),
+            rfq_id = %negotiation.rfq_id(),
+            error = %error,
+            "Negotiated terms failed pre-trade checks; reverting acceptance"
+        );
+        if let Err(e) = negotiation.revert_acceptance(error.to_string()) {
+            tracing::error!(negotiation_id = %negotiation.id(), error = %e, "Failed to revert acceptance");
+            return error;
+        }
+        if let Err(e) = self.negotiations.save(&negotiation).await {
+            tracing::error!(
+                negotiation_id = %negotiation.id(),
+                error = %e,
+                "Failed to persist reverted negotiation"
+            );
+        }
+        error
+    }
+
+    /// Completes a conclusion whose trade is already booked.
+    ///
+    /// Accepts and persists the negotiation if an earlier call failed
+    /// before doing so, and publishes the events it did not.
+    async fn complete(
+        &self,
+        mut negotiation: Negotiation,
+        trade: Trade,
+    ) -> ApplicationResult<ConcludeNegotiationResponse> {
+        if negotiation.state() == NegotiationState::Accepted {
+            return Ok(ConcludeNegotiationResponse {
+                trade,
+                negotiation,
+                already_concluded: true,
+            });
+        }
+
+        negotiation.accept()?;
+        self.negotiations
+            .save(&negotiation)
+            .await
+            .map_err(repository_error)?;
+        if let Some(terms) = negotiation.negotiated_terms()
+            && let Ok(Some(rfq)) = self.rfq_repository.find_by_id(trade.rfq_id()).await
+        {
+            self.publish(&rfq, &negotiation, &terms, &trade).await;
+        }
+
+        Ok(ConcludeNegotiationResponse {
+            trade,
+            negotiation,
+            already_concluded: false,
+        })
+    }
+
+    /// Creates the bilateral trade for the agreed terms.
+    fn create_trade(
+        rfq: &Rfq,
+        terms: &NegotiatedTerms,
+        price_bounds: Option<PriceBoundsCheck>,
+    ) -> ApplicationResult<Trade> {
+        let quote = rfq
+            .quotes()
+            .iter()
+            .find(|quote| quote.id() == terms.quote_id)
+            .ok_or_else(|| ApplicationError::QuoteNotFound(terms.quote_id.to_string()))?;
+
+        let mut trade = Trade::new(
+            rfq.id(),
+            terms.quote_id,
+            quote.venue_id().clone(),
+            terms.price,
+            terms.quantity,
+        );
+        trade.set_negotiation_id(terms.negotiation_id);
+        if let Some(plan) = terms.settlement_plan.clone() {
+            trade.set_settlement_plan(plan);
+        }
+        if let Some(check) = price_bounds {
+            if let Some(result) = check.result() {
+                trade.set_reference_price_at_execution(result.reference());
+            }
+            trade.set_price_bounds_check(check);
+        }
+        Ok(trade)
+    }
+
+    /// Publishes the events of a completed conclusion.
+    ///
+    /// Everything is persisted by now, so publishing failures are logged.
+    /// Trade and negotiation events carry the versions just persisted.
+    async fn publish(
+        &self,
+        rfq: &Rfq,
+        negotiation: &Negotiation,
+        terms: &NegotiatedTerms,
+        trade: &Trade,
+    ) {
+        let settlement_method = terms
+            .settlement_plan
+            .as_ref()
+            .and_then(|plan| plan.chain)
+            .map_or(
+                rfq.instrument().settlement_method(),
+                SettlementMethod::OnChain,
+            );
+        let mut executed = TradeExecuted::builder()
+            .rfq_id(rfq.id())
+            .trade_id(trade.id())
+            .quote_id(trade.quote_id())
+            .venue_id(trade.venue_id().clone())
+            .counterparty_id(terms.requester.clone())
+            .price(trade.price())
+            .quantity(trade.quantity())
+            .settlement_method(settlement_method)
+            .build();
+        executed.metadata = executed
+            .metadata
+            .with_trace_id(telemetry::current_trace_id())
+            .with_aggregate_version(trade.id().get(), trade.version());
+        if let Err(e) = self.trade_events.publish_trade_executed(executed).await {
+            tracing::warn!(trade_id = %trade.id(), error = %e, "Failed to publish TradeExecuted");
+        }
+
+        let position = PositionUpdated::new(
+            rfq.id(),
+            trade.id(),
+            terms.requester.clone(),
+            terms.side,
+            trade.venue_id().clone(),
+            terms.side.opposite(),
+            rfq.instrument().clone(),
+            trade.quantity(),
+            trade.price(),
+        );
+        if let Err(e) = self.trade_events.publish_position_updated(position).await {
+            tracing::warn!(trade_id = %trade.id(), error = %e, "Failed to publish PositionUpdated");
+        }
+
+        let mut concluded = NegotiationConcluded::new(
+            rfq.id(),
+            terms.negotiation_id,
+            trade.id(),
+            terms.counter_quote_id,
+            terms.requester.clone(),
+            terms.mm_account.clone(),
+            terms.price,
+            terms.quantity,
+        );
+        concluded.metadata = concluded
+            .metadata
+            .with_aggregate_version(negotiation.id().get(), negotiation.version());
+        if let Err(e) = self
+            .negotiation_events
+            .publish_negotiation_concluded(concluded)
+            .await
+        {
+            tracing::warn!(
+                negotiation_id = %terms.negotiation_id,
+                error = %e,
+                "Failed to publish NegotiationConcluded"
+            );
+        }
+    }
+}
+
+fn repository_error(error: RepositoryError) -> ApplicationError {
+    ApplicationError::RepositoryError(error.to_string())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::counter_quote::CounterQuoteBuilder;
+    use crate::domain::entities::rfq::ComplianceResult;
+    use crate::domain::events::{ComplianceEvent, ExecutionStarted};
+    use crate::domain::value_objects::timestamp::Timestamp;
+    use crate::domain::value_objects::{
+        CounterpartyId, FailureReason, OrderSide, Price, Quantity, QuoteId, RfqId,
+    };
+    use crate::infrastructure::persistence::in_memory::InMemoryNegotiationRepository;
+    use crate::infrastructure::persistence::traits::RfqRepository as _;
+    use crate::test_support::{
+        FailingRfqRepository, FailingTradeRepository, FixtureBuilder, Fixtures, RfqSeed,
+    };
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct RecordingPublisher {
+        executed: Mutex<Vec<TradeExecuted>>,
+        positions: Mutex<Vec<PositionUpdated>>,
+        concluded: Mutex<Vec<NegotiationConcluded>>,
+    }
+
+    #[async_trait]
+    impl TradeEventPublisher for RecordingPublisher {
+        async fn publish_execution_started(
+            &self,
+            _event: ExecutionStarted,
+        ) -> ApplicationResult<()> {
+            Ok(())
+        }
+
+        async fn publish_trade_executed(&self, event: TradeExecuted) -> ApplicationResult<()> {
+            self.executed.lock().unwrap().push(event);
+            Ok(())
+        }
+
+        async fn publish_execution_failed(
+            &self,
+            _rfq_id: RfqId,
+            _quote_id: QuoteId,
+            _reason: &FailureReason,
+        ) -> ApplicationResult<()> {
+            Ok(())
+        }
+
+        async fn publish_position_updated(&self, event: PositionUpdated) -> ApplicationResult<()> {
+            self.positions.lock().unwrap().push(event);
+            Ok(())
+        }
+
+        async fn publish_compliance_check(&self, _event: ComplianceEvent) -> ApplicationResult<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl NegotiationEventPublisher for RecordingPublisher {
+        async fn publish_negotiation_concluded(
+            &self,
+            event: NegotiationConcluded,
+        ) -> ApplicationResult<()> {
+            self.concluded.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    struct RejectingCompliance;
+
+    #[async_trait]
+    impl ComplianceService for RejectingCompliance {
+        async fn pre_check(
+            &self,
+            _client_id: &CounterpartyId,
+            _base_asset: &str,
+            _quote_asset: &str,
+            _quantity: f64,
+        ) -> Result<ComplianceResult, String> {
+            Ok(ComplianceResult::failed("trading limit exceeded"))
+        }
+    }
+
+    struct Setup {
+        fixtures: Fixtures,
+        rfq_id: RfqId,
+        negotiation_id: NegotiationId,
+        negotiations: Arc<InMemoryNegotiationRepository>,
+        events: Arc<RecordingPublisher>,
+        use_case: ConcludeNegotiationUseCase,
+    }
+
+    async fn setup() -> Setup {
+        let fixtures = FixtureBuilder::new()
+            .with_rfq(RfqSeed::new("rfq", "client-1", "BTC/USD").with_quote("venue-1", 100.0))
+            .build()
+            .await
+            .unwrap();
+        let rfq = fixtures.rfq("rfq").unwrap().clone();
+        let quote_id = fixtures.quote("rfq").unwrap().id();
+
+        let mut negotiation = Negotiation::new(
+            rfq.id(),
+            rfq.client_id().clone(),
+            CounterpartyId::new("venue-1"),
+            OrderSide::Buy,
+            3,
+        )
+        .unwrap();
+        let counter = CounterQuoteBuilder::new(
+            quote_id,
+            rfq.id(),
+            CounterpartyId::new("venue-1"),
+            Price::new(99.5).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(60),
+            1,
+        )
+        .build()
+        .unwrap();
+        negotiation.submit_counter(counter).unwrap();
+        let negotiations = Arc::new(InMemoryNegotiationRepository::new());
+        negotiations.save(&negotiation).await.unwrap();
+
+        let rfqs = Arc::new(FailingRfqRepository::new(Arc::clone(&fixtures.rfqs)
+            as Arc<dyn crate::infrastructure::persistence::traits::RfqRepository>));
+        let trades = Arc::new(FailingTradeRepository::new(Arc::clone(&fixtures.trades)
+            as Arc<dyn crate::infrastructure::persistence::traits::TradeRepository>));
+        let events = Arc::new(RecordingPublisher::default());
+        let use_case = ConcludeNegotiationUseCase::new(
+            Arc::clone(&negotiations) as Arc<dyn NegotiationRepository>,
+            rfqs,
+            trades,
+            Arc::clone(&events) as Arc<dyn TradeEventPublisher>,
+            Arc::clone(&events) as Arc<dyn NegotiationEventPublisher>,
+        );
+
+        Setup {
+            fixtures,
+            rfq_id: rfq.id(),
+            negotiation_id: negotiation.id(),
+            negotiations,
+            events,
+            use_case,
+        }
+    }
+
+    #[tokio::test]
+    async fn accept_books_trade_on_negotiated_terms() {
+        let setup = setup().await;
+
+        let response = setup.use_case.accept(setup.negotiation_id).await.unwrap();
+
+        assert!(!response.already_concluded);
+        assert_eq!(response.negotiation.state(), NegotiationState::Accepted);
+        assert_eq!(response.trade.rfq_id(), setup.rfq_id);
+        assert_eq!(response.trade.negotiation_id(), Some(setup.negotiation_id));
+        assert_eq!(response.trade.price(), Price::new(99.5).unwrap());
+        assert_eq!(setup.fixtures.trades.len(), 1);
+
+        let rfq = setup
+            .fixtures
+            .rfqs
+            .get(setup.rfq_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(rfq.state(), RfqState::Executed);
+        let stored = setup
+            .negotiations
+            .find_by_id(setup.negotiation_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.state(), NegotiationState::Accepted);
+
+        assert_eq!(setup.events.executed.lock().unwrap().len(), 1);
+        assert_eq!(setup.events.positions.lock().unwrap().len(), 1);
+        let concluded = setup.events.concluded.lock().unwrap();
+        assert_eq!(concluded.len(), 1);
+        assert_eq!(concluded.first().unwrap().trade_id, response.trade.id());
+    }
+
+    #[tokio::test]
+    async fn failed_check_reverts_acceptance() {
+        let mut setup = setup().await;
+        setup.use_case = setup
+            .use_case
+            .with_compliance_service(Arc::new(RejectingCompliance));
+
+        let result = setup.use_case.accept(setup.negotiation_id).await;
+
+        assert!(matches!(result, Err(ApplicationError::ComplianceFailed(_))));
+        let stored = setup
+            .negotiations
+            .find_by_id(setup.negotiation_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.state(), NegotiationState::CounterPending);
+        assert!(
+            stored
+                .conclusion_failure()
+                .unwrap()
+                .contains("trading limit exceeded")
+        );
+        assert_eq!(setup.fixtures.trades.len(), 0);
+        let rfq = setup
+            .fixtures
+            .rfqs
+            .get(setup.rfq_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(rfq.state(), RfqState::QuotesReceived);
+        assert!(setup.events.concluded.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn accepting_again_returns_the_same_trade() {
+        let setup = setup().await;
+
+        let first = setup.use_case.accept(setup.negotiation_id).await.unwrap();
+        let second = setup.use_case.accept(setup.negotiation_id).await.unwrap();
+
+        assert!(second.already_concluded);
+        assert_eq!(second.trade.id(), first.trade.id());
+        assert_eq!(setup.fixtures.trades.len(), 1);
+        assert_eq!(setup.events.executed.lock().unwrap().len(), 1);
+        assert_eq!(setup.events.concluded.lock().unwrap().len(), 1);
+    }
+}
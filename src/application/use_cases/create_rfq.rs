@@ -6,18 +6,28 @@
 //! - Request validation
 //! - Client verification
 //! - Instrument validation
+//! - Settlement token validation for on-chain spot instruments
+//! - Lot size and order size validation against instrument reference data
 //! - Compliance pre-checks
 //! - RFQ persistence
 //! - Domain event publishing
 
 use crate::application::dto::rfq_dto::{CreateRfqRequest, CreateRfqResponse};
 use crate::application::error::{ApplicationError, ApplicationResult};
-use crate::domain::entities::rfq::{ComplianceResult, Rfq, RfqBuilder};
+use crate::domain::entities::rfq::{ComplianceResult, Rfq, RfqBuilder, RfqSubject};
 use crate::domain::events::rfq_events::RfqCreated;
-use crate::domain::value_objects::{CounterpartyId, RfqId};
+use crate::domain::value_objects::enums::AssetClass;
+use crate::domain::value_objects::{CounterpartyId, Instrument, RfqId, StrategyLeg};
+use crate::infrastructure::blockchain::SharedTokenRegistry;
+use crate::infrastructure::metrics;
+use crate::infrastructure::persistence::{
+    InstrumentReferenceDataRepository, PageCursor, RfqListFilter,
+};
+use crate::infrastructure::telemetry;
 use async_trait::async_trait;
 use std::fmt;
 use std::sync::Arc;
+use tracing::{Span, field, instrument};
 
 /// Repository for RFQ persistence.
 #[async_trait]
@@ -35,6 +45,37 @@ pub trait RfqRepository: Send + Sync + fmt::Debug {
     ///
     /// Returns an error if the query fails.
     async fn find_by_id(&self, id: RfqId) -> Result<Option<Rfq>, String>;
+
+    /// Lists RFQs newest first, starting after `cursor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> Result<Vec<Rfq>, String>;
+
+    /// Lists RFQs newest first, skipping the first `offset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    async fn list_offset(
+        &self,
+        offset: usize,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> Result<Vec<Rfq>, String>;
+
+    /// Counts RFQs matching `filter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    async fn count_matching(&self, filter: &RfqListFilter) -> Result<u64, String>;
 }
 
 /// Publisher for domain events.
@@ -90,6 +131,8 @@ pub struct CreateRfqUseCase {
     compliance_service: Arc<dyn ComplianceService>,
     client_repository: Arc<dyn ClientRepository>,
     instrument_registry: Arc<dyn InstrumentRegistry>,
+    reference_data: Option<Arc<dyn InstrumentReferenceDataRepository>>,
+    token_registry: Option<SharedTokenRegistry>,
 }
 
 impl CreateRfqUseCase {
@@ -108,9 +151,63 @@ impl CreateRfqUseCase {
             compliance_service,
             client_repository,
             instrument_registry,
+            reference_data: None,
+            token_registry: None,
         }
     }
 
+    /// Sets the instrument reference data used to validate lot sizes and
+    /// order size limits.
+    ///
+    /// Instruments without reference data are not checked.
+    #[must_use]
+    pub fn with_reference_data(
+        mut self,
+        reference_data: Arc<dyn InstrumentReferenceDataRepository>,
+    ) -> Self {
+        self.reference_data = Some(reference_data);
+        self
+    }
+
+    /// Sets the token registry used to check that on-chain spot instruments
+    /// trade tokens enabled for settlement on their chain.
+    ///
+    /// Without a registry no token is checked.
+    #[must_use]
+    pub fn with_token_registry(mut self, token_registry: SharedTokenRegistry) -> Self {
+        self.token_registry = Some(token_registry);
+        self
+    }
+
+    /// Checks both assets of every on-chain spot instrument in `subject`
+    /// are tokens enabled for settlement on the instrument's chain.
+    fn check_settlement_tokens(&self, subject: &RfqSubject) -> ApplicationResult<()> {
+        let Some(token_registry) = &self.token_registry else {
+            return Ok(());
+        };
+        let instruments: Vec<&Instrument> = match subject.strategy() {
+            Some(strategy) => strategy
+                .legs()
+                .iter()
+                .map(StrategyLeg::instrument)
+                .collect(),
+            None => vec![subject.primary_instrument()],
+        };
+        let tokens = token_registry.read();
+        for instrument in instruments {
+            let Some(chain) = instrument.settlement_method().blockchain() else {
+                continue;
+            };
+            if instrument.asset_class() != AssetClass::CryptoSpot {
+                continue;
+            }
+            for asset in [instrument.base_asset(), instrument.quote_asset()] {
+                tokens.settlement_token(asset, chain.into())?;
+            }
+        }
+        Ok(())
+    }
+
     /// Executes the create RFQ use case.
     ///
     /// # Arguments
@@ -123,9 +220,13 @@ impl CreateRfqUseCase {
     /// - Request validation fails
     /// - Client does not exist or is not active
     /// - Instrument is not supported
+    /// - An on-chain spot instrument trades a token that is unknown or not
+    ///   enabled for settlement on its chain
+    /// - Quantity violates the instrument's lot size or order size limits
     /// - Compliance check fails
     /// - Persistence fails
     /// - Event publishing fails
+    #[instrument(skip_all, fields(client_id = %request.client_id, rfq_id = field::Empty))]
     pub async fn execute(&self, request: CreateRfqRequest) -> ApplicationResult<CreateRfqResponse> {
         // 1. Validate request
         request.validate().map_err(ApplicationError::validation)?;
@@ -152,34 +253,50 @@ impl CreateRfqUseCase {
             return Err(ApplicationError::client_not_active(&request.client_id));
         }
 
-        // 4. Check instrument is supported
-        let instrument_supported = self
-            .instrument_registry
-            .is_supported(&request.base_asset, &request.quote_asset)
-            .await
-            .map_err(ApplicationError::repository)?;
-
-        if !instrument_supported {
-            return Err(ApplicationError::instrument_not_supported(format!(
-                "{}/{}",
-                request.base_asset, request.quote_asset
-            )));
+        // 4. Check every requested instrument is supported
+        for (base_asset, quote_asset) in request.asset_pairs() {
+            let instrument_supported = self
+                .instrument_registry
+                .is_supported(base_asset, quote_asset)
+                .await
+                .map_err(ApplicationError::repository)?;
+
+            if !instrument_supported {
+                return Err(ApplicationError::instrument_not_supported(format!(
+                    "{}/{}",
+                    base_asset, quote_asset
+                )));
+            }
         }
 
         // 5. Convert to domain types
-        let (instrument, quantity, expires_at) = request
+        let (subject, quantity, expires_at) = request
             .to_domain_types()
             .map_err(ApplicationError::validation)?;
+        let instrument = subject.primary_instrument();
+
+        // 6. Check settlement tokens of on-chain instruments
+        self.check_settlement_tokens(&subject)?;
+
+        // 7. Validate against instrument reference data
+        if let Some(reference_data) = &self.reference_data
+            && let Some(data) = reference_data
+                .get(instrument.symbol())
+                .await
+                .map_err(|e| ApplicationError::repository(e.to_string()))?
+        {
+            data.validate_quantity(quantity)?;
+        }
 
         let client_id = CounterpartyId::new(&request.client_id);
 
-        // 6. Run compliance pre-check
+        // 8. Run compliance pre-check
         let compliance_result = self
             .compliance_service
             .pre_check(
                 &client_id,
-                &request.base_asset,
-                &request.quote_asset,
+                instrument.base_asset(),
+                instrument.quote_asset(),
                 request.quantity,
             )
             .await
@@ -193,19 +310,27 @@ impl CreateRfqUseCase {
             ));
         }
 
-        // 7. Create RFQ aggregate with anonymity level
-        let rfq = RfqBuilder::new(client_id, instrument, request.side, quantity, expires_at)
+        // 9. Create RFQ aggregate with anonymity level
+        let mut builder = RfqBuilder::new(client_id, subject, request.side, quantity, expires_at)
             .anonymity_level(request.anonymity_level())
-            .try_build()?;
+            .size_mode(request.size_mode.clone())
+            .compensation_policy(request.compensation_policy)
+            .venue_blocklist(request.venue_blocklist.clone());
+        if let Some(allowlist) = &request.venue_allowlist {
+            builder = builder.venue_allowlist(allowlist.clone());
+        }
+        let rfq = builder.try_build()?;
+        Span::current().record("rfq_id", field::display(rfq.id()));
 
-        // 8. Persist RFQ
+        // 10. Persist RFQ
         self.rfq_repository
             .save(&rfq)
             .await
             .map_err(ApplicationError::repository)?;
+        metrics::record_rfq_created();
 
-        // 9. Publish domain event
-        let event = RfqCreated::new(
+        // 11. Publish domain event
+        let mut event = RfqCreated::new(
             rfq.id(),
             rfq.client_id().clone(),
             rfq.instrument().clone(),
@@ -213,13 +338,17 @@ impl CreateRfqUseCase {
             rfq.quantity(),
             rfq.expires_at(),
         );
+        event.metadata = event
+            .metadata
+            .with_trace_id(telemetry::current_trace_id())
+            .with_aggregate_version(rfq.id().get(), rfq.version());
 
         self.event_publisher
             .publish_rfq_created(event)
             .await
             .map_err(ApplicationError::event_publish)?;
 
-        // 10. Return response
+        // 12. Return response
         Ok(CreateRfqResponse::new(
             rfq.id(),
             rfq.state(),
@@ -233,7 +362,10 @@ impl CreateRfqUseCase {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::domain::errors::DomainError;
     use crate::domain::value_objects::OrderSide;
+    use crate::infrastructure::blockchain::TokenError;
+    use crate::infrastructure::persistence::cursor::{paginate, paginate_offset};
     use std::collections::HashMap;
     use std::sync::Mutex;
 
@@ -252,6 +384,51 @@ mod tests {
         async fn find_by_id(&self, id: RfqId) -> Result<Option<Rfq>, String> {
             Ok(self.rfqs.lock().unwrap().get(&id).cloned())
         }
+
+        async fn list_after(
+            &self,
+            cursor: Option<&PageCursor>,
+            limit: usize,
+            filter: &RfqListFilter,
+        ) -> Result<Vec<Rfq>, String> {
+            let rfqs = self
+                .rfqs
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .cloned()
+                .collect();
+            Ok(paginate(rfqs, cursor, limit, PageCursor::from_rfq))
+        }
+
+        async fn list_offset(
+            &self,
+            offset: usize,
+            limit: usize,
+            filter: &RfqListFilter,
+        ) -> Result<Vec<Rfq>, String> {
+            let rfqs = self
+                .rfqs
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .cloned()
+                .collect();
+            Ok(paginate_offset(rfqs, offset, limit, PageCursor::from_rfq))
+        }
+
+        async fn count_matching(&self, filter: &RfqListFilter) -> Result<u64, String> {
+            let count = self
+                .rfqs
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|rfq| filter.matches(rfq))
+                .count();
+            Ok(count as u64)
+        }
     }
 
     #[derive(Debug, Default)]
@@ -460,4 +637,107 @@ mod tests {
             ApplicationError::Validation(_)
         ));
     }
+
+    async fn reference_data_repo(
+        lot_size: rust_decimal::Decimal,
+    ) -> Arc<dyn InstrumentReferenceDataRepository> {
+        use crate::domain::value_objects::{InstrumentReferenceData, Symbol};
+        use crate::infrastructure::persistence::in_memory::InMemoryInstrumentReferenceDataRepository;
+
+        let repo = InMemoryInstrumentReferenceDataRepository::new();
+        let data = InstrumentReferenceData::new(
+            Symbol::new("BTC/USD").unwrap(),
+            rust_decimal::Decimal::ONE,
+            lot_size,
+        )
+        .unwrap();
+        repo.save(&data).await.unwrap();
+        Arc::new(repo)
+    }
+
+    #[tokio::test]
+    async fn execute_rejects_quantity_off_lot_size() {
+        let use_case = create_use_case(
+            MockClientRepository::with_client("client-1"),
+            MockInstrumentRegistry::with_instrument("BTC", "USD"),
+            MockComplianceService::passing(),
+        )
+        .with_reference_data(reference_data_repo(rust_decimal::Decimal::new(2, 1)).await);
+
+        let request = CreateRfqRequest::new("client-1", "BTC", "USD", OrderSide::Buy, 1.5, 300);
+        let result = use_case.execute(request).await;
+
+        match result.unwrap_err() {
+            ApplicationError::Domain(DomainError::InvalidLotSize { nearest_valid, .. }) => {
+                assert_eq!(nearest_valid.get(), rust_decimal::Decimal::new(14, 1));
+            }
+            other => unreachable!("unexpected error: {other:?}"),
+        }
+    }
+
+    fn token_registry() -> SharedTokenRegistry {
+        use crate::infrastructure::blockchain::TokenRegistry;
+
+        Arc::new(parking_lot::RwLock::new(TokenRegistry::with_common_tokens()))
+    }
+
+    #[tokio::test]
+    async fn execute_rejects_unknown_settlement_token() {
+        let use_case = create_use_case(
+            MockClientRepository::with_client("client-1"),
+            MockInstrumentRegistry::with_instrument("BTC", "USDC"),
+            MockComplianceService::passing(),
+        )
+        .with_token_registry(token_registry());
+
+        let request = CreateRfqRequest::new("client-1", "BTC", "USDC", OrderSide::Buy, 1.5, 300);
+        let result = use_case.execute(request).await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::SettlementTokenUnavailable(
+                TokenError::TokenNotFound(ref symbol)
+            )) if symbol == "BTC"
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_rejects_disabled_settlement_token() {
+        let registry = token_registry();
+        let use_case = create_use_case(
+            MockClientRepository::with_client("client-1"),
+            MockInstrumentRegistry::with_instrument("WBTC", "USDC"),
+            MockComplianceService::passing(),
+        )
+        .with_token_registry(Arc::clone(&registry));
+
+        let request = CreateRfqRequest::new("client-1", "WBTC", "USDC", OrderSide::Buy, 1.5, 300);
+        assert!(use_case.execute(request.clone()).await.is_ok());
+
+        registry
+            .write()
+            .set_settlement_enabled("USDC", false)
+            .unwrap();
+        let result = use_case.execute(request).await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::SettlementTokenUnavailable(
+                TokenError::SettlementDisabled(_)
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_accepts_quantity_on_lot_size() {
+        let use_case = create_use_case(
+            MockClientRepository::with_client("client-1"),
+            MockInstrumentRegistry::with_instrument("BTC", "USD"),
+            MockComplianceService::passing(),
+        )
+        .with_reference_data(reference_data_repo(rust_decimal::Decimal::new(5, 1)).await);
+
+        let request = CreateRfqRequest::new("client-1", "BTC", "USD", OrderSide::Buy, 1.5, 300);
+        assert!(use_case.execute(request).await.is_ok());
+    }
 }
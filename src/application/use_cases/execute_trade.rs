@@ -4,19 +4,116 @@
 //!
 //! This module provides the [`ExecuteTradeUseCase`] which orchestrates
 //! trade execution against a selected quote from a venue.
+//!
+//! # Concurrency
+//!
+//! When an [`ExecutionGuard`] is configured, each execution holds a lock on
+//! its RFQ from before the RFQ is loaded until the result is persisted, so
+//! concurrent requests for the same RFQ cannot both reach the venue.
+//!
+//! # Execution Instructions
+//!
+//! Venues that replay a signed payload declare the
+//! [`ExecutionProtocol`](crate::domain::value_objects::ExecutionProtocol)
+//! they need. The quote's
+//! [`ExecutionInstructions`](crate::domain::value_objects::ExecutionInstructions)
+//! must match it; a quote without them is
+//! rejected before the RFQ leaves `ClientSelecting`, instead of failing at
+//! the venue.
+//!
+//! # Platform Fees
+//!
+//! When a [`FeeCalculator`] is configured, the platform fee the client owes
+//! on the fill is attached to the trade alongside the venue commission. The
+//! venue has already filled by then, so a fee that cannot be computed is
+//! logged for reconciliation and the trade is recorded without it.
+//!
+//! # Collateral
+//!
+//! When a [`CollateralCheckPort`] is configured, `CryptoDerivs` RFQs are
+//! checked for client margin before the RFQ is committed to the quote. An
+//! insufficient balance always blocks the execution; an unavailable margin
+//! system blocks it only in [`CollateralCheckMode::Enforce`]. Every decision
+//! is published as a compliance event, and decisions that let the execution
+//! proceed are recorded on the trade.
+//!
+//! # Negotiated Terms
+//!
+//! When a [`NegotiationRepository`] is configured, an accepted negotiation
+//! on the RFQ whose final counter answered the executed quote supplies the
+//! trade's [`SettlementPlan`](crate::domain::value_objects::SettlementPlan):
+//! the settlement chain and window the counter was conditional on.
+//!
+//! # Settlement Window
+//!
+//! When a [`SettlementScheduler`] is configured, the trade records the
+//! window it settles in and when it is released to the settlement workers.
+//! A schedule that cannot be computed is logged and the trade is left
+//! unscheduled, so it is released at once.
+//!
+//! # MM Performance
+//!
+//! When an [`MmPerformanceRecorder`] is configured, the quote's venue is
+//! charged an `AcceptRequested` once the RFQ is committed to the quote, and
+//! a `TradeExecuted` once the trade is persisted. A last-look quote the
+//! venue declines to fill records a `LastLookReject` instead.
+//!
+//! # Persistence
+//!
+//! The RFQ is persisted in `Executing` before the venue is called, so a
+//! concurrent modification aborts execution before anything is sent to the
+//! venue. Once execution has started, every failure path leaves the RFQ in a
+//! terminal state:
+//!
+//! - A venue error marks the RFQ `Failed` and publishes `ExecutionFailed`.
+//! - A trade that cannot be persisted after a venue fill marks the RFQ
+//!   `Failed` with a reason that flags it for reconciliation.
+//! - A conflicting final RFQ save is retried once against the latest stored
+//!   RFQ, so it matches the persisted trade.
+//!
+//! # Execution Deadline
+//!
+//! Starting execution stamps the RFQ with an execution deadline: the
+//! configured execution timeout, or the selected quote's expiry if that is
+//! earlier. A watchdog cancels the venue call once the deadline passes and
+//! the RFQ is marked `Failed` with [`FailureCode::ExecutionTimeout`], which
+//! also releases the execution lock. The deadline is persisted, so the
+//! [`RfqExpirySweeper`](crate::application::services::RfqExpirySweeper) can
+//! fail executions abandoned by an instance that crashed.
 
 use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::application::services::{
+    CollateralCheckMode, CollateralCheckPort, ExecutionGuard, FeeCalculator, MmPerformanceRecorder,
+    PriceBoundsValidator, ReferencePriceProvider, RetryError, RetryPolicy, SettlementScheduler,
+    execute_with_retry,
+};
 use crate::application::use_cases::collect_quotes::VenueRegistry;
 use crate::application::use_cases::create_rfq::RfqRepository;
-use crate::domain::entities::rfq::Rfq;
-use crate::domain::entities::trade::Trade;
-use crate::domain::events::TradeExecuted;
-use crate::domain::value_objects::{QuoteId, RfqId, TradeId, TradeParticipant};
-use crate::infrastructure::venues::traits::ExecutionResult;
+use crate::domain::entities::mm_performance::MmPerformanceEventKind;
+use crate::domain::entities::quote::{Quote, QuoteMetadata};
+use crate::domain::entities::rfq::{DEFAULT_EXECUTION_TIMEOUT, Rfq};
+use crate::domain::entities::trade::{FeeComponent, FeeKind, Trade};
+use crate::domain::errors::DomainError;
+use crate::domain::events::{
+    ComplianceCheckFailed, ComplianceCheckPassed, ComplianceCheckType, ComplianceEvent,
+    ExecutionStarted, TradeExecuted,
+};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    AssetClass, CollateralDecision, ExecutionInstructions, FailureCode, FailureReason, Price,
+    PriceBoundsCheck, QuoteId, RfqId, RfqState, SettlementPlan, TradeId, TradeParticipant,
+};
+use crate::infrastructure::metrics;
+use crate::infrastructure::persistence::traits::NegotiationRepository;
+use crate::infrastructure::telemetry;
+use crate::infrastructure::venues::traits::{ExecutionResult, VenueAdapter};
 use async_trait::async_trait;
 use std::fmt;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::instrument;
 
 /// Repository for persisting trades.
 #[async_trait]
@@ -34,6 +131,9 @@ pub trait TradeRepository: Send + Sync + fmt::Debug {
 /// Publisher for trade-related events.
 #[async_trait]
 pub trait TradeEventPublisher: Send + Sync + fmt::Debug {
+    /// Publishes an execution started event.
+    async fn publish_execution_started(&self, event: ExecutionStarted) -> ApplicationResult<()>;
+
     /// Publishes a trade executed event.
     async fn publish_trade_executed(&self, event: TradeExecuted) -> ApplicationResult<()>;
 
@@ -42,7 +142,7 @@ pub trait TradeEventPublisher: Send + Sync + fmt::Debug {
         &self,
         rfq_id: RfqId,
         quote_id: QuoteId,
-        reason: &str,
+        reason: &FailureReason,
     ) -> ApplicationResult<()>;
 
     /// Publishes a position updated event.
@@ -57,6 +157,13 @@ pub trait TradeEventPublisher: Send + Sync + fmt::Debug {
         &self,
         event: crate::domain::events::PositionUpdated,
     ) -> ApplicationResult<()>;
+
+    /// Publishes the outcome of a pre-execution compliance check.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be published.
+    async fn publish_compliance_check(&self, event: ComplianceEvent) -> ApplicationResult<()>;
 }
 
 /// Request to execute a trade.
@@ -66,13 +173,28 @@ pub struct ExecuteTradeRequest {
     pub rfq_id: RfqId,
     /// The quote ID to execute.
     pub quote_id: QuoteId,
+    /// Administrator overriding a failed price bounds check, if any.
+    pub price_bounds_override: Option<String>,
 }
 
 impl ExecuteTradeRequest {
     /// Creates a new execute trade request.
     #[must_use]
     pub fn new(rfq_id: RfqId, quote_id: QuoteId) -> Self {
-        Self { rfq_id, quote_id }
+        Self {
+            rfq_id,
+            quote_id,
+            price_bounds_override: None,
+        }
+    }
+
+    /// Executes even if the price bounds check fails, on behalf of `admin`.
+    ///
+    /// Only honoured when the validator's configuration allows overrides.
+    #[must_use]
+    pub fn with_price_bounds_override(mut self, admin: impl Into<String>) -> Self {
+        self.price_bounds_override = Some(admin.into());
+        self
     }
 }
 
@@ -108,15 +230,16 @@ impl ExecuteTradeResponse {
 /// Use case for executing trades against quotes.
 ///
 /// Orchestrates the trade execution workflow:
+/// 0. Acquire the RFQ's execution lock, if a guard is configured
 /// 1. Load RFQ and validate state
 /// 2. Find and validate quote
-/// 3. Get venue adapter
-/// 4. Execute trade via venue
-/// 5. Create Trade aggregate
-/// 6. Update RFQ state
-/// 7. Persist trade and RFQ
-/// 8. Publish events
-#[derive(Debug)]
+/// 3. Check the quote price against reference price bounds
+/// 4. Get venue adapter
+/// 5. Execute trade via venue
+/// 6. Create Trade aggregate
+/// 7. Update RFQ state
+/// 8. Persist trade and RFQ
+/// 9. Publish events
 pub struct ExecuteTradeUseCase {
     rfq_repository: Arc<dyn RfqRepository>,
     trade_repository: Arc<dyn TradeRepository>,
@@ -125,6 +248,46 @@ pub struct ExecuteTradeUseCase {
     confirmation_service: Option<Arc<dyn crate::domain::services::ConfirmationService>>,
     counterparty_repository:
         Option<Arc<dyn crate::infrastructure::persistence::traits::CounterpartyRepository>>,
+    reference_price_provider: Option<Arc<dyn ReferencePriceProvider>>,
+    price_bounds_validator: Option<Arc<PriceBoundsValidator>>,
+    fee_calculator: Option<Arc<FeeCalculator>>,
+    collateral_check: Option<Arc<dyn CollateralCheckPort>>,
+    collateral_check_mode: CollateralCheckMode,
+    negotiations: Option<Arc<dyn NegotiationRepository>>,
+    performance: Option<Arc<MmPerformanceRecorder>>,
+    settlement_scheduler: Option<Arc<SettlementScheduler>>,
+    execution_guard: Option<ExecutionGuard>,
+    execution_timeout: Duration,
+    persistence_retry: RetryPolicy,
+}
+
+impl fmt::Debug for ExecuteTradeUseCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExecuteTradeUseCase")
+            .field("rfq_repository", &self.rfq_repository)
+            .field("trade_repository", &self.trade_repository)
+            .field("event_publisher", &self.event_publisher)
+            .field("venue_registry", &self.venue_registry)
+            .field("confirmation_service", &self.confirmation_service.is_some())
+            .field(
+                "reference_price_provider",
+                &self.reference_price_provider.is_some(),
+            )
+            .field(
+                "price_bounds_validator",
+                &self.price_bounds_validator.is_some(),
+            )
+            .field("fee_calculator", &self.fee_calculator.is_some())
+            .field("collateral_check", &self.collateral_check.is_some())
+            .field("collateral_check_mode", &self.collateral_check_mode)
+            .field("negotiations", &self.negotiations.is_some())
+            .field("performance", &self.performance.is_some())
+            .field("settlement_scheduler", &self.settlement_scheduler.is_some())
+            .field("execution_guard", &self.execution_guard)
+            .field("execution_timeout", &self.execution_timeout)
+            .field("persistence_retry", &self.persistence_retry)
+            .finish()
+    }
 }
 
 impl ExecuteTradeUseCase {
@@ -143,9 +306,115 @@ impl ExecuteTradeUseCase {
             venue_registry,
             confirmation_service: None,
             counterparty_repository: None,
+            reference_price_provider: None,
+            price_bounds_validator: None,
+            fee_calculator: None,
+            collateral_check: None,
+            collateral_check_mode: CollateralCheckMode::default(),
+            negotiations: None,
+            performance: None,
+            settlement_scheduler: None,
+            execution_guard: None,
+            execution_timeout: DEFAULT_EXECUTION_TIMEOUT,
+            persistence_retry: RetryPolicy::default(),
         }
     }
 
+    /// Sets the guard that serializes executions of the same RFQ.
+    #[must_use]
+    pub fn with_execution_guard(mut self, execution_guard: ExecutionGuard) -> Self {
+        self.execution_guard = Some(execution_guard);
+        self
+    }
+
+    /// Sets how long an execution may run before it is cancelled.
+    ///
+    /// The selected quote's expiry still applies if it is earlier. Defaults
+    /// to [`DEFAULT_EXECUTION_TIMEOUT`].
+    #[must_use]
+    pub fn with_execution_timeout(mut self, execution_timeout: Duration) -> Self {
+        self.execution_timeout = execution_timeout;
+        self
+    }
+
+    /// Sets the retry policy for persisting the executed trade.
+    ///
+    /// Only transient repository failures (lost connections, timeouts,
+    /// aborted transactions) are retried. Defaults to [`RetryPolicy::default`].
+    #[must_use]
+    pub fn with_persistence_retry(mut self, persistence_retry: RetryPolicy) -> Self {
+        self.persistence_retry = persistence_retry;
+        self
+    }
+
+    /// Sets the validator that checks quote prices before execution.
+    ///
+    /// Quotes priced outside the tolerance for the instrument's liquidity
+    /// are rejected, as are quotes with no reference price available.
+    #[must_use]
+    pub fn with_price_bounds_validator(
+        mut self,
+        price_bounds_validator: Arc<PriceBoundsValidator>,
+    ) -> Self {
+        self.price_bounds_validator = Some(price_bounds_validator);
+        self
+    }
+
+    /// Sets the provider used to capture the reference price at execution.
+    ///
+    /// The captured price is stored on the trade for slippage analysis.
+    #[must_use]
+    pub fn with_reference_price_provider(
+        mut self,
+        reference_price_provider: Arc<dyn ReferencePriceProvider>,
+    ) -> Self {
+        self.reference_price_provider = Some(reference_price_provider);
+        self
+    }
+
+    /// Sets the calculator for the platform fee attached to each trade.
+    #[must_use]
+    pub fn with_fee_calculator(mut self, fee_calculator: Arc<FeeCalculator>) -> Self {
+        self.fee_calculator = Some(fee_calculator);
+        self
+    }
+
+    /// Sets the margin check made before executing `CryptoDerivs` RFQs, and
+    /// whether an unavailable margin system blocks execution.
+    #[must_use]
+    pub fn with_collateral_check(
+        mut self,
+        collateral_check: Arc<dyn CollateralCheckPort>,
+        mode: CollateralCheckMode,
+    ) -> Self {
+        self.collateral_check = Some(collateral_check);
+        self.collateral_check_mode = mode;
+        self
+    }
+
+    /// Sets the repository whose accepted negotiations supply the settlement
+    /// terms of the trades they lead to.
+    #[must_use]
+    pub fn with_negotiations(mut self, negotiations: Arc<dyn NegotiationRepository>) -> Self {
+        self.negotiations = Some(negotiations);
+        self
+    }
+
+    /// Records accept requests, last-look rejects and executions as market
+    /// maker performance events.
+    #[must_use]
+    pub fn with_performance_recorder(mut self, recorder: Arc<MmPerformanceRecorder>) -> Self {
+        self.performance = Some(recorder);
+        self
+    }
+
+    /// Schedules each trade into its settlement window.
+    #[must_use]
+    pub fn with_settlement_scheduler(mut self, scheduler: Arc<SettlementScheduler>) -> Self {
+        self.settlement_scheduler = Some(scheduler);
+        self
+    }
+
     /// Sets the confirmation service for multi-channel trade confirmations.
     #[must_use]
     pub fn with_confirmation_service(
@@ -176,15 +445,44 @@ impl ExecuteTradeUseCase {
     /// - RFQ is not found
     /// - Quote is not found
     /// - Quote is expired
+    /// - Quote price is out of bounds or has no reference price, and is not
+    ///   overridden
+    /// - The client holds too little collateral, or the margin system is
+    ///   unavailable in enforce mode
     /// - RFQ is in invalid state
     /// - Venue is not available
-    /// - Execution fails
+    /// - Another execution of the RFQ holds the execution lock
+    /// - The RFQ was modified concurrently
+    /// - Execution fails or misses its execution deadline
+    #[instrument(skip_all, fields(rfq_id = %request.rfq_id, quote_id = %request.quote_id))]
     pub async fn execute(
         &self,
         request: ExecuteTradeRequest,
     ) -> ApplicationResult<ExecuteTradeResponse> {
         let start = Instant::now();
+        let result = match &self.execution_guard {
+            Some(guard) => match guard.acquire(request.rfq_id).await {
+                Ok(lock) => {
+                    let result = self.run(request, start).await;
+                    if let Err(e) = lock.release().await {
+                        tracing::warn!(error = %e, "Failed to release execution lock");
+                    }
+                    result
+                }
+                Err(e) => Err(e.into()),
+            },
+            None => self.run(request, start).await,
+        };
+        let outcome = if result.is_ok() { "ok" } else { "error" };
+        metrics::record_execution(start.elapsed(), outcome);
+        result
+    }
 
+    async fn run(
+        &self,
+        request: ExecuteTradeRequest,
+        start: Instant,
+    ) -> ApplicationResult<ExecuteTradeResponse> {
         // Load RFQ
         let mut rfq = self
             .rfq_repository
@@ -206,12 +504,25 @@ impl ExecuteTradeUseCase {
             return Err(ApplicationError::QuoteExpired(request.quote_id.to_string()));
         }
 
-        // Select quote and start execution
-        rfq.select_quote(request.quote_id)
-            .map_err(|e| ApplicationError::InvalidState(e.to_string()))?;
+        // Check price bounds before committing to the quote
+        let price_bounds = self.check_price_bounds(&rfq, &quote, &request).await?;
+        let collateral = self.check_collateral(&rfq, &quote).await?;
+        let settlement_plan = self.negotiated_settlement_plan(&rfq, &quote).await;
+
+        // Select quote (unless already selected via firm-up) and start execution
+        let already_selected = rfq.state() == RfqState::ClientSelecting
+            && rfq.selected_quote_id() == Some(request.quote_id);
+        if !already_selected {
+            rfq.select_quote(request.quote_id)
+                .map_err(|e| ApplicationError::InvalidState(e.to_string()))?;
+        }
 
-        rfq.start_execution()
-            .map_err(|e| ApplicationError::InvalidState(e.to_string()))?;
+        if rfq.state() != RfqState::ClientSelecting {
+            return Err(ApplicationError::InvalidState(format!(
+                "RFQ must be in ClientSelecting state to execute, found {}",
+                rfq.state()
+            )));
+        }
 
         // Get venue adapter
         let venue_adapter = self
@@ -219,29 +530,118 @@ impl ExecuteTradeUseCase {
             .get_venue(quote.venue_id())
             .await
             .ok_or_else(|| ApplicationError::VenueNotAvailable(quote.venue_id().to_string()))?;
+        Self::check_execution_instructions(&quote, venue_adapter.as_ref())?;
 
-        // Execute trade via venue
-        let execution_result = venue_adapter
-            .execute_trade(&quote)
-            .await
-            .map_err(|e| ApplicationError::ExecutionFailed(e.to_string()))?;
-
-        // Create trade from execution result
-        let trade = self.create_trade_from_result(&rfq, &execution_result);
-
-        // Mark RFQ as executed
-        rfq.mark_executed()
+        rfq.start_execution_within(self.execution_timeout)
             .map_err(|e| ApplicationError::InvalidState(e.to_string()))?;
+        let deadline = rfq.execution_deadline().unwrap_or_else(Timestamp::now);
 
-        // Persist trade and RFQ
-        self.trade_repository.save(&trade).await?;
+        // Persist the Executing state before contacting the venue; a version
+        // conflict here means another request got there first
         self.rfq_repository
             .save(&rfq)
             .await
             .map_err(ApplicationError::RepositoryError)?;
 
+        let mut started = ExecutionStarted::new(rfq.id(), quote.id(), quote.venue_id().clone());
+        started.metadata = started
+            .metadata
+            .with_trace_id(telemetry::current_trace_id())
+            .with_aggregate_version(rfq.id().get(), rfq.version());
+        if let Err(e) = self
+            .event_publisher
+            .publish_execution_started(started)
+            .await
+        {
+            tracing::warn!(rfq_id = %rfq.id(), error = %e, "Failed to publish ExecutionStarted");
+        }
+        self.record_performance(&quote, MmPerformanceEventKind::AcceptRequested);
+
+        // Capture reference price at execution (best effort)
+        let reference_price = match price_bounds.as_ref().and_then(PriceBoundsCheck::result) {
+            Some(result) => Some(result.reference()),
+            None => self.capture_reference_price(&rfq).await,
+        };
+
+        // Execute trade via venue, cancelled at the execution deadline
+        let watchdog = ExecutionWatchdog::arm(deadline);
+        let venue_result = tokio::select! {
+            () = watchdog.cancelled() => None,
+            result = venue_adapter.execute_trade(&quote) => Some(result),
+        };
+        drop(watchdog);
+        let execution_result = match venue_result {
+            Some(Ok(result)) => result,
+            None => {
+                tracing::warn!(
+                    rfq_id = %rfq.id(),
+                    venue_id = %quote.venue_id(),
+                    %deadline,
+                    "Execution cancelled at its deadline"
+                );
+                let reason = FailureReason::new(
+                    FailureCode::ExecutionTimeout,
+                    format!("venue did not fill before the execution deadline {deadline}"),
+                );
+                self.fail_execution(rfq, quote.id(), &reason).await;
+                return Err(ApplicationError::ExecutionFailed(reason.to_string()));
+            }
+            Some(Err(e)) => {
+                let reason = FailureReason::new(e.failure_code(), e.to_string());
+                if quote.last_look_required() && reason.code() == FailureCode::VenueRejected {
+                    self.record_performance(&quote, MmPerformanceEventKind::LastLookReject);
+                }
+                self.fail_execution(rfq, quote.id(), &reason).await;
+                return Err(ApplicationError::ExecutionFailed(e.to_string()));
+            }
+        };
+
+        // Create trade from execution result
+        let mut trade =
+            self.create_trade_from_result(&rfq, &quote, &execution_result, reference_price);
+        if let Some(check) = price_bounds {
+            trade.set_price_bounds_check(check);
+        }
+        if let Some(decision) = collateral {
+            trade.set_collateral_decision(decision);
+        }
+        if let Some(plan) = settlement_plan {
+            trade.set_settlement_plan(plan);
+        }
+        self.attach_platform_fee(&rfq, &mut trade).await;
+        self.schedule_settlement(&rfq, &mut trade).await;
+
+        // Persist trade; the venue has filled, so a failure that survives
+        // the retries needs manual reconciliation
+        let saved = execute_with_retry(&self.persistence_retry, || {
+            self.trade_repository.save(&trade)
+        })
+        .await
+        .map_err(RetryError::into_inner);
+        if let Err(e) = saved {
+            tracing::error!(
+                rfq_id = %rfq.id(),
+                trade_id = %trade.id(),
+                venue_execution_ref = ?trade.venue_execution_ref(),
+                error = %e,
+                "Venue filled but trade could not be persisted"
+            );
+            let reason = FailureReason::new(
+                FailureCode::ExecutionError,
+                format!("venue filled but trade could not be persisted: {e}"),
+            );
+            self.fail_execution(rfq, quote.id(), &reason).await;
+            return Err(e);
+        }
+
+        self.record_performance(&quote, MmPerformanceEventKind::TradeExecuted);
+
+        // Mark RFQ as executed and persist it
+        let rfq = self.complete_execution(rfq, &trade).await?;
+        metrics::record_rfq_terminal(rfq.state());
+
         // Publish trade executed event
-        let event = TradeExecuted::builder()
+        let mut event = TradeExecuted::builder()
             .rfq_id(rfq.id())
             .trade_id(trade.id())
             .quote_id(quote.id())
@@ -251,6 +651,10 @@ impl ExecuteTradeUseCase {
             .quantity(trade.quantity())
             .settlement_method(execution_result.settlement_method())
             .build();
+        event.metadata = event
+            .metadata
+            .with_trace_id(telemetry::current_trace_id())
+            .with_aggregate_version(trade.id().get(), trade.version());
         self.event_publisher
             .publish_trade_executed(event.clone())
             .await?;
@@ -294,9 +698,311 @@ impl ExecuteTradeUseCase {
         ))
     }
 
+    /// Records a performance event for the market maker behind the quote's
+    /// venue.
+    fn record_performance(&self, quote: &Quote, kind: MmPerformanceEventKind) {
+        if let Some(performance) = &self.performance {
+            performance.record(quote.venue_id(), kind);
+        }
+    }
+
+    /// Marks an executing RFQ as failed, persists it, and publishes
+    /// `ExecutionFailed`.
+    ///
+    /// This is a compensating update for an RFQ already persisted in
+    /// `Executing`; its own failures are logged so the original error is
+    /// surfaced to the caller.
+    async fn fail_execution(&self, mut rfq: Rfq, quote_id: QuoteId, reason: &FailureReason) {
+        if let Err(e) = rfq.mark_failed(reason.clone()) {
+            tracing::error!(rfq_id = %rfq.id(), error = %e, "Failed to mark RFQ as failed");
+            return;
+        }
+        match self.rfq_repository.save(&rfq).await {
+            Ok(()) => metrics::record_rfq_terminal(rfq.state()),
+            Err(e) => {
+                tracing::error!(
+                    rfq_id = %rfq.id(),
+                    error = %e,
+                    "Failed to persist failed RFQ; it remains Executing"
+                );
+            }
+        }
+        if let Err(e) = self
+            .event_publisher
+            .publish_execution_failed(rfq.id(), quote_id, reason)
+            .await
+        {
+            tracing::warn!(rfq_id = %rfq.id(), error = %e, "Failed to publish ExecutionFailed");
+        }
+    }
+
+    /// Marks the RFQ as executed and persists it.
+    ///
+    /// The trade is already persisted, so if the save conflicts the update is
+    /// retried once against the latest stored RFQ, provided it is still
+    /// executing the same quote.
+    async fn complete_execution(&self, mut rfq: Rfq, trade: &Trade) -> ApplicationResult<Rfq> {
+        rfq.mark_executed()
+            .map_err(|e| ApplicationError::InvalidState(e.to_string()))?;
+        let error = match self.rfq_repository.save(&rfq).await {
+            Ok(()) => return Ok(rfq),
+            Err(e) => e,
+        };
+
+        tracing::warn!(
+            rfq_id = %rfq.id(),
+            trade_id = %trade.id(),
+            error = %error,
+            "Failed to persist executed RFQ; retrying against latest version"
+        );
+        let mut latest = self
+            .rfq_repository
+            .find_by_id(rfq.id())
+            .await
+            .ok()
+            .flatten()
+            .filter(|latest| {
+                latest.state() == RfqState::Executing
+                    && latest.selected_quote_id() == Some(trade.quote_id())
+            })
+            .ok_or_else(|| ApplicationError::RepositoryError(error.clone()))?;
+        latest
+            .mark_executed()
+            .map_err(|e| ApplicationError::InvalidState(e.to_string()))?;
+        self.rfq_repository
+            .save(&latest)
+            .await
+            .map_err(ApplicationError::RepositoryError)?;
+        Ok(latest)
+    }
+
+    /// Checks the quote price against the instrument's reference price.
+    ///
+    /// Returns `None` when no validator is configured, or for net-premium
+    /// strategy quotes, which have no single reference price. A failed check
+    /// is recorded as overridden when the request names an administrator and
+    /// the validator's configuration allows overrides.
+    /// Ensures the quote carries the execution instructions its venue needs.
+    fn check_execution_instructions(
+        quote: &Quote,
+        venue: &dyn VenueAdapter,
+    ) -> ApplicationResult<()> {
+        let Some(expected) = venue.execution_protocol() else {
+            return Ok(());
+        };
+        match quote
+            .metadata()
+            .and_then(QuoteMetadata::execution_instructions)
+            .map(ExecutionInstructions::protocol)
+        {
+            Some(found) if found == expected => Ok(()),
+            Some(found) => Err(ApplicationError::InvalidState(format!(
+                "quote {} carries {found} execution instructions but venue {} executes {expected}",
+                quote.id(),
+                quote.venue_id()
+            ))),
+            None => Err(ApplicationError::InvalidState(format!(
+                "quote {} has no execution instructions; venue {} requires {expected}",
+                quote.id(),
+                quote.venue_id()
+            ))),
+        }
+    }
+
+    async fn check_price_bounds(
+        &self,
+        rfq: &Rfq,
+        quote: &Quote,
+        request: &ExecuteTradeRequest,
+    ) -> ApplicationResult<Option<PriceBoundsCheck>> {
+        let Some(validator) = &self.price_bounds_validator else {
+            return Ok(None);
+        };
+        if quote.is_net_premium() {
+            return Ok(None);
+        }
+
+        let instrument = rfq.instrument();
+        let error = match validator
+            .validate(
+                instrument,
+                &quote.price(),
+                validator.liquidity_for(instrument),
+            )
+            .await
+        {
+            Ok(result) => return Ok(Some(PriceBoundsCheck::Passed(result))),
+            Err(e @ (DomainError::PriceOutOfBounds { .. } | DomainError::NoReferencePrice)) => e,
+            Err(e) => return Err(e.into()),
+        };
+
+        match &request.price_bounds_override {
+            Some(admin) if validator.config().allows_admin_override() => {
+                tracing::warn!(
+                    rfq_id = %rfq.id(),
+                    quote_id = %quote.id(),
+                    overridden_by = %admin,
+                    error = %error,
+                    "Price bounds check overridden"
+                );
+                Ok(Some(PriceBoundsCheck::overridden(
+                    admin.as_str(),
+                    error.to_string(),
+                )))
+            }
+            Some(admin) => {
+                tracing::warn!(
+                    rfq_id = %rfq.id(),
+                    quote_id = %quote.id(),
+                    requested_by = %admin,
+                    "Price bounds override requested but overrides are disabled"
+                );
+                Err(error.into())
+            }
+            None => Err(error.into()),
+        }
+    }
+
+    /// Checks the client's margin for a `CryptoDerivs` RFQ.
+    ///
+    /// Returns `None` when no collateral check is configured or the
+    /// instrument is not a derivative. The decision is published whether or
+    /// not it blocks the execution.
+    async fn check_collateral(
+        &self,
+        rfq: &Rfq,
+        quote: &Quote,
+    ) -> ApplicationResult<Option<CollateralDecision>> {
+        let Some(collateral_check) = &self.collateral_check else {
+            return Ok(None);
+        };
+        if rfq.instrument().asset_class() != AssetClass::CryptoDerivs {
+            return Ok(None);
+        }
+
+        let decision = collateral_check
+            .check(
+                rfq.client_id(),
+                rfq.instrument(),
+                quote.quantity(),
+                quote.price(),
+            )
+            .await;
+        let blocked = self.collateral_check_mode.blocks(&decision);
+        let event = match (decision, blocked) {
+            (CollateralDecision::Insufficient { .. }, _) => {
+                ComplianceEvent::Failed(ComplianceCheckFailed::new(
+                    rfq.id(),
+                    rfq.client_id().clone(),
+                    ComplianceCheckType::Collateral,
+                    decision.to_string(),
+                    Some("INSUFFICIENT_COLLATERAL".to_string()),
+                ))
+            }
+            (CollateralDecision::Unavailable, true) => {
+                ComplianceEvent::Failed(ComplianceCheckFailed::new(
+                    rfq.id(),
+                    rfq.client_id().clone(),
+                    ComplianceCheckType::Collateral,
+                    "margin system unavailable",
+                    Some("COLLATERAL_UNAVAILABLE".to_string()),
+                ))
+            }
+            _ => ComplianceEvent::Passed(ComplianceCheckPassed::new(
+                rfq.id(),
+                rfq.client_id().clone(),
+                ComplianceCheckType::Collateral,
+                Some(format!(
+                    "{} ({} mode)",
+                    decision, self.collateral_check_mode
+                )),
+            )),
+        };
+        if let Err(e) = self.event_publisher.publish_compliance_check(event).await {
+            tracing::warn!(rfq_id = %rfq.id(), error = %e, "Failed to publish collateral check");
+        }
+
+        match decision {
+            CollateralDecision::Insufficient {
+                required,
+                available,
+            } => Err(DomainError::InsufficientCollateral {
+                required,
+                available,
+            }
+            .into()),
+            CollateralDecision::Unavailable if blocked => Err(DomainError::CollateralUnavailable(
+                format!("no collateral decision for client {}", rfq.client_id()),
+            )
+            .into()),
+            CollateralDecision::Unavailable => {
+                tracing::warn!(
+                    rfq_id = %rfq.id(),
+                    client_id = %rfq.client_id(),
+                    "Margin system unavailable; executing in monitor mode"
+                );
+                Ok(Some(decision))
+            }
+            CollateralDecision::Approved { .. } => Ok(Some(decision)),
+        }
+    }
+
+    /// Returns the settlement terms agreed for `quote` in negotiation.
+    ///
+    /// Repository failures are logged and do not block execution.
+    async fn negotiated_settlement_plan(&self, rfq: &Rfq, quote: &Quote) -> Option<SettlementPlan> {
+        let negotiations = self.negotiations.as_ref()?;
+        let negotiations = match negotiations.find_by_rfq(rfq.id()).await {
+            Ok(negotiations) => negotiations,
+            Err(e) => {
+                tracing::warn!(
+                    rfq_id = %rfq.id(),
+                    error = %e,
+                    "Failed to load negotiations for settlement terms"
+                );
+                return None;
+            }
+        };
+        negotiations
+            .iter()
+            .filter(|negotiation| {
+                negotiation
+                    .accepted_counter()
+                    .is_some_and(|counter| counter.original_quote_id() == quote.id())
+            })
+            .find_map(|negotiation| negotiation.settlement_plan())
+    }
+
+    /// Fetches the current reference price for the RFQ's instrument.
+    ///
+    /// Provider failures are logged and do not block execution.
+    async fn capture_reference_price(&self, rfq: &Rfq) -> Option<Price> {
+        let provider = self.reference_price_provider.as_ref()?;
+        match provider.get_reference(rfq.instrument()).await {
+            Ok(reference) => reference.map(|(price, _source)| price),
+            Err(e) => {
+                tracing::warn!(
+                    rfq_id = %rfq.id(),
+                    error = %e,
+                    "Failed to capture reference price at execution"
+                );
+                None
+            }
+        }
+    }
+
     /// Creates a Trade from an ExecutionResult.
-    fn create_trade_from_result(&self, rfq: &Rfq, result: &ExecutionResult) -> Trade {
-        if let Some(venue_ref) = result.venue_execution_id() {
+    ///
+    /// Attaches the quote's commission as a venue fee and the reference
+    /// price captured at execution, if any.
+    fn create_trade_from_result(
+        &self,
+        rfq: &Rfq,
+        quote: &Quote,
+        result: &ExecutionResult,
+        reference_price: Option<Price>,
+    ) -> Trade {
+        let mut trade = if let Some(venue_ref) = result.venue_execution_id() {
             Trade::with_venue_ref(
                 rfq.id(),
                 result.quote_id(),
@@ -313,6 +1019,62 @@ impl ExecuteTradeUseCase {
                 result.execution_price(),
                 result.executed_quantity(),
             )
+        };
+
+        if let Some(commission) = quote.commission() {
+            trade.add_fee(FeeComponent::new(
+                FeeKind::Venue,
+                commission.get(),
+                rfq.instrument().quote_asset(),
+            ));
+        }
+        if let Some(price) = reference_price {
+            trade.set_reference_price_at_execution(price);
+        }
+
+        trade
+    }
+
+    /// Attaches the platform fee owed by the RFQ's client, if a fee
+    /// calculator is configured and a fee applies.
+    async fn attach_platform_fee(&self, rfq: &Rfq, trade: &mut Trade) {
+        let Some(calculator) = &self.fee_calculator else {
+            return;
+        };
+        match calculator
+            .calculate(
+                rfq.client_id(),
+                rfq.instrument(),
+                trade.price(),
+                trade.quantity(),
+            )
+            .await
+        {
+            Ok(Some(fee)) => trade.add_fee(fee),
+            Ok(None) => {}
+            Err(e) => tracing::error!(
+                rfq_id = %rfq.id(),
+                trade_id = %trade.id(),
+                error = %e,
+                "Platform fee could not be computed; trade recorded without it"
+            ),
+        }
+    }
+
+    /// Records the trade's settlement window and release time, if a
+    /// scheduler is configured.
+    async fn schedule_settlement(&self, rfq: &Rfq, trade: &mut Trade) {
+        let Some(scheduler) = &self.settlement_scheduler else {
+            return;
+        };
+        let method = rfq.instrument().settlement_method();
+        if let Err(e) = scheduler.schedule(rfq.client_id(), method, trade).await {
+            tracing::error!(
+                rfq_id = %rfq.id(),
+                trade_id = %trade.id(),
+                error = %e,
+                "Settlement window could not be scheduled; trade released immediately"
+            );
         }
     }
 
@@ -399,88 +1161,138 @@ impl ExecuteTradeUseCase {
     }
 }
 
-#[cfg(test)]
-#[allow(clippy::unwrap_used)]
-mod tests {
-    use super::*;
-    use crate::domain::entities::quote::Quote;
-    use crate::domain::entities::rfq::RfqBuilder;
-    use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
-    use crate::domain::value_objects::symbol::Symbol;
-    use crate::domain::value_objects::timestamp::Timestamp;
-    use crate::domain::value_objects::{
-        CounterpartyId, Instrument, OrderSide, Price, Quantity, VenueId,
-    };
-    use crate::infrastructure::venues::error::{VenueError, VenueResult};
-    use crate::infrastructure::venues::traits::{VenueAdapter, VenueHealth};
-    use std::collections::HashMap;
+/// Cancels an execution once its deadline passes.
+///
+/// The timer stops when the watchdog is dropped.
+#[derive(Debug)]
+struct ExecutionWatchdog {
+    cancellation: CancellationToken,
+    timer: JoinHandle<()>,
+}
+
+impl ExecutionWatchdog {
+    /// Starts a timer that fires at `deadline`.
+    fn arm(deadline: Timestamp) -> Self {
+        let cancellation = CancellationToken::new();
+        let remaining = deadline - Timestamp::now();
+        let timer = tokio::spawn({
+            let cancellation = cancellation.clone();
+            async move {
+                tokio::time::sleep(remaining).await;
+                cancellation.cancel();
+            }
+        });
+        Self {
+            cancellation,
+            timer,
+        }
+    }
+
+    /// Completes once the deadline has passed.
+    async fn cancelled(&self) {
+        self.cancellation.cancelled().await;
+    }
+}
+
+impl Drop for ExecutionWatchdog {
+    fn drop(&mut self) {
+        self.timer.abort();
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::quote::QuoteBuilder;
+    use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::errors::DomainResult;
+    use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
+    use crate::domain::value_objects::symbol::Symbol;
+    use crate::domain::value_objects::timestamp::Timestamp;
+    use crate::domain::value_objects::{
+        CounterpartyId, ExecutionProtocol, Instrument, OrderSide, Price, Quantity, VenueId,
+    };
+    use crate::domain::value_objects::{PriceBoundsConfig, ReferencePriceSource};
+    use crate::infrastructure::persistence::RepositoryError;
+    use crate::infrastructure::persistence::traits::RfqRepository as _;
+    use crate::infrastructure::venues::error::{VenueError, VenueResult};
+    use crate::infrastructure::venues::traits::{VenueAdapter, VenueHealth};
+    use crate::test_support::{
+        FailingRfqRepository, FailingTradeRepository, FixtureBuilder, Fixtures,
+    };
+    use rust_decimal::Decimal;
+    use std::collections::HashMap;
     use std::sync::Mutex;
 
-    #[derive(Debug, Default)]
-    struct MockRfqRepository {
-        rfqs: Mutex<HashMap<RfqId, Rfq>>,
+    /// In-memory repositories behind failure-injection wrappers.
+    struct Repos {
+        fixtures: Fixtures,
+        rfqs: Arc<FailingRfqRepository>,
+        trades: Arc<FailingTradeRepository>,
     }
 
-    impl MockRfqRepository {
-        fn with_rfq(rfq: Rfq) -> Self {
-            let mut map = HashMap::new();
-            map.insert(rfq.id(), rfq);
-            Self {
-                rfqs: Mutex::new(map),
-            }
+    impl Repos {
+        async fn empty() -> Self {
+            Self::seeded(FixtureBuilder::new()).await
         }
-    }
 
-    #[async_trait]
-    impl RfqRepository for MockRfqRepository {
-        async fn save(&self, rfq: &Rfq) -> Result<(), String> {
-            self.rfqs.lock().unwrap().insert(rfq.id(), rfq.clone());
-            Ok(())
+        async fn with_rfq(rfq: Rfq) -> Self {
+            Self::seeded(FixtureBuilder::new().with_prepared_rfq(rfq.id().to_string(), rfq)).await
         }
 
-        async fn find_by_id(&self, id: RfqId) -> Result<Option<Rfq>, String> {
-            Ok(self.rfqs.lock().unwrap().get(&id).cloned())
+        async fn seeded(builder: FixtureBuilder) -> Self {
+            let fixtures = builder.build().await.unwrap();
+            let rfqs = Arc::new(FailingRfqRepository::new(Arc::clone(&fixtures.rfqs)
+                as Arc<dyn crate::infrastructure::persistence::traits::RfqRepository>));
+            let trades = Arc::new(FailingTradeRepository::new(Arc::clone(&fixtures.trades)
+                as Arc<dyn crate::infrastructure::persistence::traits::TradeRepository>));
+            Self {
+                fixtures,
+                rfqs,
+                trades,
+            }
         }
-    }
 
-    #[derive(Debug, Default)]
-    struct MockTradeRepository {
-        trades: Mutex<HashMap<TradeId, Trade>>,
-    }
+        fn rfq_store(&self) -> Arc<dyn RfqRepository> {
+            Arc::clone(&self.rfqs) as Arc<dyn RfqRepository>
+        }
 
-    #[async_trait]
-    impl TradeRepository for MockTradeRepository {
-        async fn save(&self, trade: &Trade) -> ApplicationResult<()> {
-            self.trades
-                .lock()
-                .unwrap()
-                .insert(trade.id(), trade.clone());
-            Ok(())
+        fn trade_store(&self) -> Arc<dyn TradeRepository> {
+            Arc::clone(&self.trades) as Arc<dyn TradeRepository>
         }
 
-        async fn find_by_id(&self, id: TradeId) -> ApplicationResult<Option<Trade>> {
-            Ok(self.trades.lock().unwrap().get(&id).cloned())
+        async fn stored(&self, id: RfqId) -> Rfq {
+            self.fixtures.rfqs.get(id).await.unwrap().unwrap()
         }
 
-        async fn find_by_rfq_id(&self, rfq_id: RfqId) -> ApplicationResult<Vec<Trade>> {
-            Ok(self
-                .trades
-                .lock()
-                .unwrap()
-                .values()
-                .filter(|t| t.rfq_id() == rfq_id)
-                .cloned()
-                .collect())
+        fn trade_count(&self) -> usize {
+            self.fixtures.trades.len()
         }
     }
 
     #[derive(Debug, Default)]
     struct MockTradeEventPublisher {
+        started_events: Mutex<Vec<ExecutionStarted>>,
         events: Mutex<Vec<TradeExecuted>>,
+        failed_events: Mutex<Vec<(QuoteId, FailureReason)>>,
         position_events: Mutex<Vec<crate::domain::events::PositionUpdated>>,
+        compliance_events: Mutex<Vec<ComplianceEvent>>,
     }
 
     impl MockTradeEventPublisher {
+        fn started_count(&self) -> usize {
+            self.started_events.lock().unwrap().len()
+        }
+
+        fn executed_count(&self) -> usize {
+            self.events.lock().unwrap().len()
+        }
+
+        fn failed_events(&self) -> Vec<(QuoteId, FailureReason)> {
+            self.failed_events.lock().unwrap().clone()
+        }
+
         fn position_event_count(&self) -> usize {
             self.position_events.lock().unwrap().len()
         }
@@ -488,10 +1300,22 @@ mod tests {
         fn last_position_event(&self) -> Option<crate::domain::events::PositionUpdated> {
             self.position_events.lock().unwrap().last().cloned()
         }
+
+        fn compliance_events(&self) -> Vec<ComplianceEvent> {
+            self.compliance_events.lock().unwrap().clone()
+        }
     }
 
     #[async_trait]
     impl TradeEventPublisher for MockTradeEventPublisher {
+        async fn publish_execution_started(
+            &self,
+            event: ExecutionStarted,
+        ) -> ApplicationResult<()> {
+            self.started_events.lock().unwrap().push(event);
+            Ok(())
+        }
+
         async fn publish_trade_executed(&self, event: TradeExecuted) -> ApplicationResult<()> {
             self.events.lock().unwrap().push(event);
             Ok(())
@@ -500,9 +1324,13 @@ mod tests {
         async fn publish_execution_failed(
             &self,
             _rfq_id: RfqId,
-            _quote_id: QuoteId,
-            _reason: &str,
+            quote_id: QuoteId,
+            reason: &FailureReason,
         ) -> ApplicationResult<()> {
+            self.failed_events
+                .lock()
+                .unwrap()
+                .push((quote_id, reason.clone()));
             Ok(())
         }
 
@@ -513,12 +1341,20 @@ mod tests {
             self.position_events.lock().unwrap().push(event);
             Ok(())
         }
+
+        async fn publish_compliance_check(&self, event: ComplianceEvent) -> ApplicationResult<()> {
+            self.compliance_events.lock().unwrap().push(event);
+            Ok(())
+        }
     }
 
     #[derive(Debug)]
     struct MockVenueAdapter {
         venue_id: VenueId,
         execution_result: Mutex<Option<VenueResult<ExecutionResult>>>,
+        calls: std::sync::atomic::AtomicUsize,
+        protocol: Option<ExecutionProtocol>,
+        delay: Option<std::time::Duration>,
     }
 
     impl MockVenueAdapter {
@@ -533,18 +1369,50 @@ mod tests {
             Self {
                 venue_id: VenueId::new(venue_id),
                 execution_result: Mutex::new(Some(Ok(result))),
+                calls: std::sync::atomic::AtomicUsize::new(0),
+                protocol: None,
+                delay: None,
             }
         }
 
         fn failing(venue_id: &str) -> Self {
-            Self {
-                venue_id: VenueId::new(venue_id),
-                execution_result: Mutex::new(Some(Err(VenueError::ExecutionFailed {
+            Self::failing_with(
+                venue_id,
+                VenueError::ExecutionFailed {
                     message: "execution failed".to_string(),
                     error_code: None,
-                }))),
+                },
+            )
+        }
+
+        fn failing_with(venue_id: &str, error: VenueError) -> Self {
+            Self {
+                venue_id: VenueId::new(venue_id),
+                execution_result: Mutex::new(Some(Err(error))),
+                calls: std::sync::atomic::AtomicUsize::new(0),
+                protocol: None,
+                delay: None,
             }
         }
+
+        fn requiring(mut self, protocol: ExecutionProtocol) -> Self {
+            self.protocol = Some(protocol);
+            self
+        }
+
+        /// Answers executions only after `delay`.
+        fn delayed(mut self, delay: std::time::Duration) -> Self {
+            self.delay = Some(delay);
+            self
+        }
+
+        fn call_count(&self) -> usize {
+            self.calls.load(std::sync::atomic::Ordering::SeqCst)
+        }
+
+        fn was_called(&self) -> bool {
+            self.call_count() > 0
+        }
     }
 
     #[async_trait]
@@ -561,7 +1429,16 @@ mod tests {
             unimplemented!()
         }
 
+        fn execution_protocol(&self) -> Option<ExecutionProtocol> {
+            self.protocol
+        }
+
         async fn execute_trade(&self, _quote: &Quote) -> VenueResult<ExecutionResult> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            match self.delay {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => tokio::task::yield_now().await,
+            }
             self.execution_result.lock().unwrap().take().unwrap_or(Err(
                 VenueError::ExecutionFailed {
                     message: "no result".to_string(),
@@ -638,13 +1515,12 @@ mod tests {
     }
 
     fn create_use_case(
-        rfq_repo: impl RfqRepository + 'static,
-        trade_repo: impl TradeRepository + 'static,
+        repos: &Repos,
         venue_registry: impl VenueRegistry + 'static,
     ) -> ExecuteTradeUseCase {
         ExecuteTradeUseCase::new(
-            Arc::new(rfq_repo),
-            Arc::new(trade_repo),
+            repos.rfq_store(),
+            repos.trade_store(),
             Arc::new(MockTradeEventPublisher::default()),
             Arc::new(venue_registry),
         )
@@ -662,9 +1538,10 @@ mod tests {
 
         let venue_adapter = Arc::new(MockVenueAdapter::successful("venue-1", quote_id));
         let event_publisher = Arc::new(MockTradeEventPublisher::default());
+        let repos = Repos::with_rfq(rfq).await;
         let use_case = ExecuteTradeUseCase::new(
-            Arc::new(MockRfqRepository::with_rfq(rfq)),
-            Arc::new(MockTradeRepository::default()),
+            repos.rfq_store(),
+            repos.trade_store(),
             Arc::clone(&event_publisher) as Arc<dyn TradeEventPublisher>,
             Arc::new(MockVenueRegistry::with_venue(venue_adapter)),
         );
@@ -691,12 +1568,496 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn execute_trade_rfq_not_found() {
+    async fn execute_trade_carries_negotiated_settlement_terms() {
+        use crate::domain::entities::counter_quote::CounterQuoteBuilder;
+        use crate::domain::entities::negotiation::Negotiation;
+        use crate::domain::value_objects::{Blockchain, CounterConditions, SettlementWindow};
+        use crate::infrastructure::persistence::in_memory::InMemoryNegotiationRepository;
+
+        let (rfq, quote) = create_test_rfq_with_quote();
+        let (rfq_id, quote_id) = (rfq.id(), quote.id());
+        let mut negotiation = Negotiation::new(
+            rfq_id,
+            rfq.client_id().clone(),
+            CounterpartyId::new("venue-1"),
+            OrderSide::Buy,
+            3,
+        )
+        .unwrap();
+        let counter = CounterQuoteBuilder::new(
+            quote_id,
+            rfq_id,
+            CounterpartyId::new("venue-1"),
+            Price::new(100.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(60),
+            1,
+        )
+        .with_conditions(CounterConditions {
+            valid_for_secs: Some(30),
+            settlement_chain: Some(Blockchain::Base),
+            settlement_window: Some(SettlementWindow::Instant),
+            note: Some("T+0 only".to_string()),
+        })
+        .build()
+        .unwrap();
+        negotiation.submit_counter(counter).unwrap();
+        negotiation.accept().unwrap();
+        let negotiations = Arc::new(InMemoryNegotiationRepository::new());
+        negotiations.save(&negotiation).await.unwrap();
+
+        let venue_adapter = Arc::new(MockVenueAdapter::successful("venue-1", quote_id));
+        let use_case = create_use_case(
+            &Repos::with_rfq(rfq).await,
+            MockVenueRegistry::with_venue(venue_adapter),
+        )
+        .with_negotiations(negotiations);
+
+        let request = ExecuteTradeRequest::new(rfq_id, quote_id);
+        let trade = use_case.execute(request).await.unwrap().trade;
+
+        let plan = trade.settlement_plan().unwrap();
+        assert_eq!(plan.chain, Some(Blockchain::Base));
+        assert_eq!(plan.window, Some(SettlementWindow::Instant));
+        assert_eq!(plan.note.as_deref(), Some("T+0 only"));
+    }
+
+    #[derive(Debug)]
+    struct FixedReferenceProvider(Price);
+
+    #[async_trait]
+    impl ReferencePriceProvider for FixedReferenceProvider {
+        async fn get_reference(
+            &self,
+            _instrument: &Instrument,
+        ) -> DomainResult<Option<(Price, ReferencePriceSource)>> {
+            Ok(Some((self.0, ReferencePriceSource::ClobMid)))
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_trade_records_reference_price_and_venue_fee() {
+        let symbol = Symbol::new("BTC/USD").unwrap();
+        let instrument =
+            Instrument::new(symbol, AssetClass::CryptoSpot, SettlementMethod::default());
+        let mut rfq = RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build();
+        let quote = QuoteBuilder::new(
+            rfq.id(),
+            VenueId::new("venue-1"),
+            Price::new(100.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(60),
+        )
+        .commission(Price::new(2.5).unwrap())
+        .build();
+        rfq.start_quote_collection().unwrap();
+        rfq.receive_quote(quote.clone()).unwrap();
+        let rfq_id = rfq.id();
+        let quote_id = quote.id();
+
+        let venue_adapter = Arc::new(MockVenueAdapter::successful("venue-1", quote_id));
+        let use_case = create_use_case(
+            &Repos::with_rfq(rfq).await,
+            MockVenueRegistry::with_venue(venue_adapter),
+        )
+        .with_reference_price_provider(Arc::new(FixedReferenceProvider(Price::new(99.0).unwrap())));
+
+        let response = use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote_id))
+            .await
+            .unwrap();
+        let trade = response.trade;
+
+        assert_eq!(
+            trade.reference_price_at_execution(),
+            Some(Price::new(99.0).unwrap())
+        );
+        assert!(trade.slippage_bps(OrderSide::Buy).unwrap().is_positive());
+        assert_eq!(trade.fees().len(), 1);
+        let fee = trade.fees().first().unwrap();
+        assert_eq!(fee.kind(), FeeKind::Venue);
+        assert_eq!(fee.currency(), "USD");
+        assert_eq!(
+            trade.total_fees("USD"),
+            Some(Price::new(2.5).unwrap().get())
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_trade_attaches_platform_fee_to_the_trade() {
+        use crate::application::services::FeeCalculator;
+        use crate::domain::entities::platform_fee::{
+            AssetClassFeeRate, DEFAULT_FEE_TIER, FeeBand, PlatformFeeSchedule,
+        };
+        use crate::infrastructure::persistence::in_memory::{
+            InMemoryFeeWaiverRepository, InMemoryPlatformFeeScheduleRepository,
+        };
+        use crate::infrastructure::persistence::traits::PlatformFeeScheduleRepository;
+
+        let (rfq, quote) = create_test_rfq_with_quote();
+        let (rfq_id, quote_id) = (rfq.id(), quote.id());
+        let schedules = Arc::new(InMemoryPlatformFeeScheduleRepository::new());
+        let rate = AssetClassFeeRate::new(
+            AssetClass::CryptoSpot,
+            vec![FeeBand::new(Decimal::ZERO, Decimal::new(50, 0))],
+            Some(Decimal::ONE),
+            None,
+        )
+        .unwrap();
+        schedules
+            .save(&PlatformFeeSchedule::new(DEFAULT_FEE_TIER, Vec::new(), vec![rate]).unwrap())
+            .await
+            .unwrap();
+        let calculator =
+            FeeCalculator::new(schedules, Arc::new(InMemoryFeeWaiverRepository::new()));
+
+        let venue_adapter = Arc::new(MockVenueAdapter::successful("venue-1", quote_id));
         let use_case = create_use_case(
-            MockRfqRepository::default(),
-            MockTradeRepository::default(),
-            MockVenueRegistry::empty(),
+            &Repos::with_rfq(rfq).await,
+            MockVenueRegistry::with_venue(venue_adapter),
+        )
+        .with_fee_calculator(Arc::new(calculator));
+
+        let trade = use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote_id))
+            .await
+            .unwrap()
+            .trade;
+
+        // 50 bps of the 100 notional is 0.5, raised to the minimum of 1.
+        let fee = trade
+            .fees()
+            .iter()
+            .find(|fee| fee.kind() == FeeKind::Platform)
+            .unwrap();
+        assert_eq!(fee.amount(), Decimal::ONE);
+        assert_eq!(fee.currency(), "USD");
+    }
+
+    struct CollateralSetup {
+        use_case: ExecuteTradeUseCase,
+        request: ExecuteTradeRequest,
+        repos: Repos,
+        venue: Arc<MockVenueAdapter>,
+        events: Arc<MockTradeEventPublisher>,
+    }
+
+    /// Sets up execution of one unit at 100 for `client-1`, who holds
+    /// `balance` collateral against a 10% initial margin requirement.
+    async fn collateral_setup(
+        asset_class: AssetClass,
+        balance: Option<i64>,
+        mode: CollateralCheckMode,
+    ) -> CollateralSetup {
+        use crate::infrastructure::persistence::in_memory::InMemoryCollateralBalances;
+
+        let instrument = Instrument::new(
+            Symbol::new("BTC/USD").unwrap(),
+            asset_class,
+            SettlementMethod::default(),
+        );
+        let mut rfq = RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build();
+        let quote = Quote::new(
+            rfq.id(),
+            VenueId::new("venue-1"),
+            Price::new(100.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(60),
+        )
+        .unwrap();
+        rfq.start_quote_collection().unwrap();
+        rfq.receive_quote(quote.clone()).unwrap();
+        let request = ExecuteTradeRequest::new(rfq.id(), quote.id());
+
+        let balances = InMemoryCollateralBalances::new();
+        if let Some(balance) = balance {
+            balances.set(CounterpartyId::new("client-1"), Decimal::from(balance));
+        }
+        let repos = Repos::with_rfq(rfq).await;
+        let venue = Arc::new(MockVenueAdapter::successful("venue-1", quote.id()));
+        let events = Arc::new(MockTradeEventPublisher::default());
+        let use_case = ExecuteTradeUseCase::new(
+            repos.rfq_store(),
+            repos.trade_store(),
+            Arc::clone(&events) as Arc<dyn TradeEventPublisher>,
+            Arc::new(MockVenueRegistry::with_venue(
+                Arc::clone(&venue) as Arc<dyn VenueAdapter>
+            )),
+        )
+        .with_collateral_check(Arc::new(balances), mode);
+        CollateralSetup {
+            use_case,
+            request,
+            repos,
+            venue,
+            events,
+        }
+    }
+
+    #[tokio::test]
+    async fn collateral_approved_is_recorded_on_the_trade() {
+        let setup = collateral_setup(
+            AssetClass::CryptoDerivs,
+            Some(10),
+            CollateralCheckMode::Enforce,
+        )
+        .await;
+
+        let trade = setup.use_case.execute(setup.request).await.unwrap().trade;
+
+        assert_eq!(
+            trade.collateral_decision(),
+            Some(&CollateralDecision::Approved {
+                available: Decimal::from(10)
+            })
+        );
+        let events = setup.events.compliance_events();
+        assert!(matches!(
+            events.as_slice(),
+            [ComplianceEvent::Passed(passed)] if passed.check_type == ComplianceCheckType::Collateral
+        ));
+    }
+
+    #[tokio::test]
+    async fn collateral_insufficient_blocks_in_both_modes() {
+        for mode in [CollateralCheckMode::Enforce, CollateralCheckMode::Monitor] {
+            let setup = collateral_setup(AssetClass::CryptoDerivs, Some(4), mode).await;
+            let rfq_id = setup.request.rfq_id;
+
+            let err = setup.use_case.execute(setup.request).await.unwrap_err();
+
+            assert!(matches!(
+                err,
+                ApplicationError::Domain(DomainError::InsufficientCollateral { required, available })
+                    if required == Decimal::from(10) && available == Decimal::from(4)
+            ));
+            assert_eq!(setup.venue.call_count(), 0);
+            let rfq = setup.repos.stored(rfq_id).await;
+            assert_eq!(rfq.state(), RfqState::QuotesReceived);
+            let events = setup.events.compliance_events();
+            assert!(matches!(
+                events.as_slice(),
+                [ComplianceEvent::Failed(failed)]
+                    if failed.error_code.as_deref() == Some("INSUFFICIENT_COLLATERAL")
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn collateral_unavailable_blocks_in_enforce_mode() {
+        let setup =
+            collateral_setup(AssetClass::CryptoDerivs, None, CollateralCheckMode::Enforce).await;
+
+        let err = setup.use_case.execute(setup.request).await.unwrap_err();
+
+        assert!(matches!(
+            err,
+            ApplicationError::Domain(DomainError::CollateralUnavailable(_))
+        ));
+        assert_eq!(setup.venue.call_count(), 0);
+        assert!(matches!(
+            setup.events.compliance_events().as_slice(),
+            [ComplianceEvent::Failed(_)]
+        ));
+    }
+
+    #[tokio::test]
+    async fn collateral_unavailable_passes_through_in_monitor_mode() {
+        let setup =
+            collateral_setup(AssetClass::CryptoDerivs, None, CollateralCheckMode::Monitor).await;
+
+        let trade = setup.use_case.execute(setup.request).await.unwrap().trade;
+
+        assert_eq!(setup.venue.call_count(), 1);
+        assert_eq!(
+            trade.collateral_decision(),
+            Some(&CollateralDecision::Unavailable)
         );
+        assert!(matches!(
+            setup.events.compliance_events().as_slice(),
+            [ComplianceEvent::Passed(_)]
+        ));
+    }
+
+    #[tokio::test]
+    async fn collateral_is_not_checked_for_spot() {
+        let setup = collateral_setup(
+            AssetClass::CryptoSpot,
+            Some(0),
+            CollateralCheckMode::Enforce,
+        )
+        .await;
+
+        let trade = setup.use_case.execute(setup.request).await.unwrap().trade;
+
+        assert!(trade.collateral_decision().is_none());
+        assert!(setup.events.compliance_events().is_empty());
+    }
+
+    #[derive(Debug)]
+    struct NoReferenceProvider;
+
+    #[async_trait]
+    impl ReferencePriceProvider for NoReferenceProvider {
+        async fn get_reference(
+            &self,
+            _instrument: &Instrument,
+        ) -> DomainResult<Option<(Price, ReferencePriceSource)>> {
+            Ok(None)
+        }
+    }
+
+    /// Sets up execution of a quote at `quote_price` on a liquid instrument
+    /// (±5% tolerance), checked against references from `provider`.
+    async fn price_bounds_setup(
+        quote_price: f64,
+        provider: Arc<dyn ReferencePriceProvider>,
+        allow_override: bool,
+    ) -> (ExecuteTradeUseCase, ExecuteTradeRequest) {
+        let symbol = Symbol::new("BTC/USD").unwrap();
+        let instrument =
+            Instrument::new(symbol, AssetClass::CryptoSpot, SettlementMethod::default());
+        let mut rfq = RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build();
+        let quote = Quote::new(
+            rfq.id(),
+            VenueId::new("venue-1"),
+            Price::new(quote_price).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(60),
+        )
+        .unwrap();
+        rfq.start_quote_collection().unwrap();
+        rfq.receive_quote(quote.clone()).unwrap();
+        let request = ExecuteTradeRequest::new(rfq.id(), quote.id());
+
+        let validator = PriceBoundsValidator::new(
+            PriceBoundsConfig::default().with_admin_override(allow_override),
+            provider,
+        );
+        let venue_adapter = Arc::new(MockVenueAdapter::successful("venue-1", quote.id()));
+        let use_case = create_use_case(
+            &Repos::with_rfq(rfq).await,
+            MockVenueRegistry::with_venue(venue_adapter),
+        )
+        .with_price_bounds_validator(Arc::new(validator));
+        (use_case, request)
+    }
+
+    fn reference_at(price: f64) -> Arc<dyn ReferencePriceProvider> {
+        Arc::new(FixedReferenceProvider(Price::new(price).unwrap()))
+    }
+
+    #[tokio::test]
+    async fn execute_trade_within_price_bounds_records_check() {
+        let (use_case, request) = price_bounds_setup(105.0, reference_at(100.0), false).await;
+
+        let trade = use_case.execute(request).await.unwrap().trade;
+
+        let check = trade.price_bounds_check().unwrap();
+        let result = check.result().unwrap();
+        assert_eq!(result.reference(), Price::new(100.0).unwrap());
+        assert_eq!(result.source(), ReferencePriceSource::ClobMid);
+        assert_eq!(result.deviation_pct(), Decimal::new(5, 2));
+        assert_eq!(
+            trade.reference_price_at_execution(),
+            Some(Price::new(100.0).unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_trade_just_over_price_bounds_is_rejected() {
+        let (use_case, request) = price_bounds_setup(105.01, reference_at(100.0), false).await;
+
+        let result = use_case.execute(request).await;
+
+        match result {
+            Err(ApplicationError::Domain(DomainError::PriceOutOfBounds {
+                deviation_pct,
+                max_tolerance_pct,
+                ..
+            })) => {
+                assert!(deviation_pct > max_tolerance_pct);
+                assert_eq!(max_tolerance_pct, Decimal::new(5, 2));
+            }
+            other => unreachable!("expected PriceOutOfBounds, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_trade_without_reference_price_is_rejected() {
+        let (use_case, request) =
+            price_bounds_setup(100.0, Arc::new(NoReferenceProvider), false).await;
+
+        let result = use_case.execute(request).await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::NoReferencePrice))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_trade_price_bounds_override_records_admin() {
+        let (use_case, request) = price_bounds_setup(120.0, reference_at(100.0), true).await;
+
+        let trade = use_case
+            .execute(request.with_price_bounds_override("admin-1"))
+            .await
+            .unwrap()
+            .trade;
+
+        match trade.price_bounds_check().unwrap() {
+            PriceBoundsCheck::Overridden {
+                overridden_by,
+                reason,
+            } => {
+                assert_eq!(overridden_by, "admin-1");
+                assert!(reason.contains("out of bounds"), "{reason}");
+            }
+            other => unreachable!("expected override, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_trade_price_bounds_override_requires_config() {
+        let (use_case, request) = price_bounds_setup(120.0, reference_at(100.0), false).await;
+
+        let result = use_case
+            .execute(request.with_price_bounds_override("admin-1"))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(
+                DomainError::PriceOutOfBounds { .. }
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_trade_rfq_not_found() {
+        let use_case = create_use_case(&Repos::empty().await, MockVenueRegistry::empty());
 
         let request = ExecuteTradeRequest::new(RfqId::new_v4(), QuoteId::new_v4());
         let result = use_case.execute(request).await;
@@ -709,11 +2070,7 @@ mod tests {
         let (rfq, _quote) = create_test_rfq_with_quote();
         let rfq_id = rfq.id();
 
-        let use_case = create_use_case(
-            MockRfqRepository::with_rfq(rfq),
-            MockTradeRepository::default(),
-            MockVenueRegistry::empty(),
-        );
+        let use_case = create_use_case(&Repos::with_rfq(rfq).await, MockVenueRegistry::empty());
 
         let request = ExecuteTradeRequest::new(rfq_id, QuoteId::new_v4());
         let result = use_case.execute(request).await;
@@ -727,11 +2084,7 @@ mod tests {
         let rfq_id = rfq.id();
         let quote_id = quote.id();
 
-        let use_case = create_use_case(
-            MockRfqRepository::with_rfq(rfq),
-            MockTradeRepository::default(),
-            MockVenueRegistry::empty(),
-        );
+        let use_case = create_use_case(&Repos::with_rfq(rfq).await, MockVenueRegistry::empty());
 
         let request = ExecuteTradeRequest::new(rfq_id, quote_id);
         let result = use_case.execute(request).await;
@@ -742,23 +2095,488 @@ mod tests {
         ));
     }
 
+    struct Harness {
+        repos: Repos,
+        events: Arc<MockTradeEventPublisher>,
+        venue: Arc<MockVenueAdapter>,
+        use_case: ExecuteTradeUseCase,
+    }
+
+    fn harness(repos: Repos, venue: MockVenueAdapter) -> Harness {
+        let events = Arc::new(MockTradeEventPublisher::default());
+        let venue = Arc::new(venue);
+        let use_case = ExecuteTradeUseCase::new(
+            repos.rfq_store(),
+            repos.trade_store(),
+            Arc::clone(&events) as Arc<dyn TradeEventPublisher>,
+            Arc::new(MockVenueRegistry::with_venue(
+                Arc::clone(&venue) as Arc<dyn VenueAdapter>
+            )),
+        );
+        Harness {
+            repos,
+            events,
+            venue,
+            use_case,
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_trade_persists_executed_rfq_and_trade() {
+        let (rfq, quote) = create_test_rfq_with_quote();
+        let rfq_id = rfq.id();
+        let h = harness(
+            Repos::with_rfq(rfq).await,
+            MockVenueAdapter::successful("venue-1", quote.id()),
+        );
+
+        let response = h
+            .use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote.id()))
+            .await
+            .unwrap();
+
+        assert_eq!(h.repos.stored(rfq_id).await.state(), RfqState::Executed);
+        assert_eq!(h.repos.trade_count(), 1);
+        assert_eq!(response.trade.quote_id(), quote.id());
+        assert_eq!(h.events.started_count(), 1);
+        assert_eq!(h.events.executed_count(), 1);
+        assert!(h.events.failed_events().is_empty());
+    }
+
     #[tokio::test]
     async fn execute_trade_execution_failed() {
         let (rfq, quote) = create_test_rfq_with_quote();
         let rfq_id = rfq.id();
         let quote_id = quote.id();
+        let h = harness(
+            Repos::with_rfq(rfq).await,
+            MockVenueAdapter::failing("venue-1"),
+        );
 
-        let venue_adapter = Arc::new(MockVenueAdapter::failing("venue-1"));
-        let use_case = create_use_case(
-            MockRfqRepository::with_rfq(rfq),
-            MockTradeRepository::default(),
-            MockVenueRegistry::with_venue(venue_adapter),
+        let result = h
+            .use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote_id))
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::ExecutionFailed(_))));
+        let stored = h.repos.stored(rfq_id).await;
+        assert_eq!(stored.state(), RfqState::Failed);
+        let reason = stored.failure_reason().unwrap();
+        assert_eq!(reason.code(), FailureCode::VenueRejected);
+        assert!(reason.detail().contains("execution failed"));
+        assert_eq!(h.repos.trade_count(), 0);
+        assert_eq!(h.events.started_count(), 1);
+        assert_eq!(h.events.executed_count(), 0);
+        let failed = h.events.failed_events();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed.first().unwrap().0, quote_id);
+        assert_eq!(&failed.first().unwrap().1, reason);
+    }
+
+    #[tokio::test]
+    async fn execute_trade_venue_timeout_is_coded() {
+        let (rfq, quote) = create_test_rfq_with_quote();
+        let rfq_id = rfq.id();
+        let h = harness(
+            Repos::with_rfq(rfq).await,
+            MockVenueAdapter::failing_with("venue-1", VenueError::timeout("no fill in 5000ms")),
         );
 
-        let request = ExecuteTradeRequest::new(rfq_id, quote_id);
-        let result = use_case.execute(request).await;
+        let result = h
+            .use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote.id()))
+            .await;
 
         assert!(matches!(result, Err(ApplicationError::ExecutionFailed(_))));
+        let stored = h.repos.stored(rfq_id).await;
+        assert_eq!(
+            stored.failure_reason().map(FailureReason::code),
+            Some(FailureCode::VenueTimeout)
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_trade_expired_quote_leaves_rfq_untouched() {
+        let symbol = Symbol::new("BTC/USD").unwrap();
+        let instrument =
+            Instrument::new(symbol, AssetClass::CryptoSpot, SettlementMethod::default());
+        let mut rfq = RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build();
+        let quote = QuoteBuilder::new(
+            rfq.id(),
+            VenueId::new("venue-1"),
+            Price::new(100.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_millis(50),
+        )
+        .build();
+        rfq.start_quote_collection().unwrap();
+        rfq.receive_quote(quote.clone()).unwrap();
+        let rfq_id = rfq.id();
+        let h = harness(
+            Repos::with_rfq(rfq).await,
+            MockVenueAdapter::successful("venue-1", quote.id()),
+        );
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let result = h
+            .use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote.id()))
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::QuoteExpired(_))));
+        assert_eq!(
+            h.repos.stored(rfq_id).await.state(),
+            RfqState::QuotesReceived
+        );
+        assert!(!h.venue.was_called());
+        assert_eq!(h.events.started_count(), 0);
+    }
+
+    fn rfq_with_instructions(instructions: Option<ExecutionInstructions>) -> (Rfq, Quote) {
+        let symbol = Symbol::new("BTC/USD").unwrap();
+        let instrument =
+            Instrument::new(symbol, AssetClass::CryptoSpot, SettlementMethod::default());
+        let mut rfq = RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build();
+        let mut metadata = QuoteMetadata::new();
+        if let Some(instructions) = instructions {
+            metadata.set_execution_instructions(instructions);
+        }
+        let quote = QuoteBuilder::new(
+            rfq.id(),
+            VenueId::new("venue-1"),
+            Price::new(100.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(60),
+        )
+        .metadata(metadata)
+        .build();
+        rfq.start_quote_collection().unwrap();
+        rfq.receive_quote(quote.clone()).unwrap();
+        (rfq, quote)
+    }
+
+    fn generic_instructions() -> ExecutionInstructions {
+        let mut fields = std::collections::BTreeMap::new();
+        fields.insert("order_id".to_string(), "abc".to_string());
+        ExecutionInstructions::Generic { fields }
+    }
+
+    #[tokio::test]
+    async fn execute_trade_dispatches_quote_with_matching_instructions() {
+        let (rfq, quote) = rfq_with_instructions(Some(generic_instructions()));
+        let h = harness(
+            Repos::with_rfq(rfq.clone()).await,
+            MockVenueAdapter::successful("venue-1", quote.id())
+                .requiring(ExecutionProtocol::Generic),
+        );
+
+        let result = h
+            .use_case
+            .execute(ExecuteTradeRequest::new(rfq.id(), quote.id()))
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(h.venue.call_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn execute_trade_without_instructions_fails_before_venue() {
+        let (rfq, quote) = rfq_with_instructions(None);
+        let rfq_id = rfq.id();
+        let h = harness(
+            Repos::with_rfq(rfq).await,
+            MockVenueAdapter::successful("venue-1", quote.id())
+                .requiring(ExecutionProtocol::HashflowSigned),
+        );
+
+        let result = h
+            .use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote.id()))
+            .await;
+
+        assert!(matches!(
+            &result,
+            Err(ApplicationError::InvalidState(msg))
+                if msg.contains("no execution instructions") && msg.contains("HASHFLOW_SIGNED")
+        ));
+        assert_eq!(
+            h.repos.stored(rfq_id).await.state(),
+            RfqState::QuotesReceived
+        );
+        assert!(!h.venue.was_called());
+        assert_eq!(h.events.started_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn execute_trade_with_other_protocol_instructions_fails_before_venue() {
+        let (rfq, quote) = rfq_with_instructions(Some(generic_instructions()));
+        let rfq_id = rfq.id();
+        let h = harness(
+            Repos::with_rfq(rfq).await,
+            MockVenueAdapter::successful("venue-1", quote.id())
+                .requiring(ExecutionProtocol::BebopOrder),
+        );
+
+        let result = h
+            .use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote.id()))
+            .await;
+
+        assert!(matches!(
+            &result,
+            Err(ApplicationError::InvalidState(msg))
+                if msg.contains("carries GENERIC") && msg.contains("executes BEBOP_ORDER")
+        ));
+        assert!(!h.venue.was_called());
+    }
+
+    #[tokio::test]
+    async fn execute_trade_version_conflict_aborts_before_venue() {
+        let (rfq, quote) = create_test_rfq_with_quote();
+        let rfq_id = rfq.id();
+        let repos = Repos::with_rfq(rfq).await;
+        repos.rfqs.fail_next_save(RepositoryError::version_conflict(
+            "Rfq",
+            rfq_id.to_string(),
+            1,
+            2,
+        ));
+        let h = harness(repos, MockVenueAdapter::successful("venue-1", quote.id()));
+
+        let result = h
+            .use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote.id()))
+            .await;
+
+        assert!(
+            matches!(&result, Err(ApplicationError::RepositoryError(msg)) if msg.contains("Version conflict"))
+        );
+        assert!(!h.venue.was_called());
+        assert_eq!(
+            h.repos.stored(rfq_id).await.state(),
+            RfqState::QuotesReceived
+        );
+        assert_eq!(h.repos.trade_count(), 0);
+        assert_eq!(h.events.started_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn execute_trade_retries_conflicting_final_save() {
+        let (rfq, quote) = create_test_rfq_with_quote();
+        let rfq_id = rfq.id();
+        let repos = Repos::with_rfq(rfq).await;
+        repos.rfqs.fail_nth_save(
+            2,
+            RepositoryError::version_conflict("Rfq", rfq_id.to_string(), 2, 3),
+        );
+        let h = harness(repos, MockVenueAdapter::successful("venue-1", quote.id()));
+
+        let result = h
+            .use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote.id()))
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(h.repos.stored(rfq_id).await.state(), RfqState::Executed);
+        assert_eq!(h.repos.trade_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn execute_trade_unpersisted_fill_marks_rfq_failed() {
+        let (rfq, quote) = create_test_rfq_with_quote();
+        let rfq_id = rfq.id();
+        let repos = Repos::with_rfq(rfq).await;
+        repos
+            .trades
+            .fail_all_saves(RepositoryError::query("database unavailable"));
+        let h = harness(repos, MockVenueAdapter::successful("venue-1", quote.id()));
+
+        let result = h
+            .use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote.id()))
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::RepositoryError(_))));
+        let stored = h.repos.stored(rfq_id).await;
+        assert_eq!(stored.state(), RfqState::Failed);
+        let reason = stored.failure_reason().unwrap();
+        assert_eq!(reason.code(), FailureCode::ExecutionError);
+        assert!(reason.detail().contains("trade could not be persisted"));
+        assert_eq!(h.events.failed_events().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn execute_trade_retries_transient_trade_persistence_failures() {
+        let (rfq, quote) = create_test_rfq_with_quote();
+        let rfq_id = rfq.id();
+        let repos = Repos::with_rfq(rfq).await;
+        for _ in 0..2 {
+            repos
+                .trades
+                .fail_next_save(RepositoryError::connection_lost("connection reset by peer"));
+        }
+        let h = harness(repos, MockVenueAdapter::successful("venue-1", quote.id()));
+        let use_case = h
+            .use_case
+            .with_persistence_retry(RetryPolicy::new(3, 1, 5, 2.0, 0.0));
+
+        let response = use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote.id()))
+            .await
+            .unwrap();
+
+        assert_eq!(h.repos.trade_count(), 1);
+        assert_eq!(response.rfq_id, rfq_id);
+        assert_eq!(h.repos.stored(rfq_id).await.state(), RfqState::Executed);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn execution_guard_allows_one_of_many_concurrent_executions() {
+        use crate::domain::services::lock_manager::SharedLockManager;
+
+        let (rfq, quote) = create_test_rfq_with_quote();
+        let rfq_id = rfq.id();
+        let quote_id = quote.id();
+        let h = harness(
+            Repos::with_rfq(rfq).await,
+            MockVenueAdapter::successful("venue-1", quote_id),
+        );
+        let use_case = Arc::new(
+            h.use_case
+                .with_execution_guard(ExecutionGuard::new(SharedLockManager::with_defaults())),
+        );
+
+        let tasks: Vec<_> = (0..20)
+            .map(|_| {
+                let use_case = Arc::clone(&use_case);
+                tokio::spawn(async move {
+                    use_case
+                        .execute(ExecuteTradeRequest::new(rfq_id, quote_id))
+                        .await
+                })
+            })
+            .collect();
+        let mut succeeded = 0;
+        for task in tasks {
+            if task.await.unwrap().is_ok() {
+                succeeded += 1;
+            }
+        }
+
+        assert_eq!(succeeded, 1);
+        assert_eq!(h.venue.call_count(), 1);
+        assert_eq!(h.repos.trade_count(), 1);
+        assert_eq!(h.repos.stored(rfq_id).await.state(), RfqState::Executed);
+    }
+
+    #[tokio::test]
+    async fn execution_guard_rejects_while_lock_is_held() {
+        use crate::domain::services::lock_manager::SharedLockManager;
+
+        let (rfq, quote) = create_test_rfq_with_quote();
+        let rfq_id = rfq.id();
+        let guard = ExecutionGuard::new(SharedLockManager::with_defaults())
+            .with_timeout(std::time::Duration::from_millis(20));
+        let h = harness(
+            Repos::with_rfq(rfq).await,
+            MockVenueAdapter::successful("venue-1", quote.id()),
+        );
+        let use_case = h.use_case.with_execution_guard(guard.clone());
+
+        let _held = guard.acquire(rfq_id).await.unwrap();
+        let result = use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote.id()))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::ExecutionInProgress(
+                _
+            )))
+        ));
+        assert!(!h.venue.was_called());
+    }
+
+    #[tokio::test]
+    async fn execution_deadline_cancels_a_hung_venue_and_releases_the_lock() {
+        use crate::domain::services::lock_manager::SharedLockManager;
+
+        let (rfq, quote) = create_test_rfq_with_quote();
+        let rfq_id = rfq.id();
+        let guard = ExecutionGuard::new(SharedLockManager::with_defaults());
+        let h = harness(
+            Repos::with_rfq(rfq).await,
+            MockVenueAdapter::successful("venue-1", quote.id())
+                .delayed(std::time::Duration::from_secs(30)),
+        );
+        let use_case = h
+            .use_case
+            .with_execution_guard(guard.clone())
+            .with_execution_timeout(std::time::Duration::from_millis(50));
+
+        let started = Instant::now();
+        let result = use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote.id()))
+            .await;
+
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+        assert!(matches!(
+            &result,
+            Err(ApplicationError::ExecutionFailed(msg)) if msg.starts_with("EXECUTION_TIMEOUT")
+        ));
+        let stored = h.repos.stored(rfq_id).await;
+        assert_eq!(stored.state(), RfqState::Failed);
+        assert_eq!(
+            stored.failure_reason().map(FailureReason::code),
+            Some(FailureCode::ExecutionTimeout)
+        );
+        assert_eq!(h.repos.trade_count(), 0);
+        assert!(matches!(
+            h.events.failed_events().as_slice(),
+            [(_, reason)] if reason.code() == FailureCode::ExecutionTimeout
+        ));
+        assert!(guard.acquire(rfq_id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn execution_within_its_deadline_is_unaffected() {
+        let (rfq, quote) = create_test_rfq_with_quote();
+        let rfq_id = rfq.id();
+        let h = harness(
+            Repos::with_rfq(rfq).await,
+            MockVenueAdapter::successful("venue-1", quote.id())
+                .delayed(std::time::Duration::from_millis(10)),
+        );
+        let use_case = h
+            .use_case
+            .with_execution_timeout(std::time::Duration::from_secs(5));
+
+        let before = Timestamp::now();
+        let result = use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote.id()))
+            .await;
+
+        assert!(result.is_ok());
+        let stored = h.repos.stored(rfq_id).await;
+        assert_eq!(stored.state(), RfqState::Executed);
+        let deadline = stored.execution_deadline().unwrap();
+        assert!(deadline >= before.add_secs(5) && deadline <= quote.valid_until());
+        assert!(h.events.failed_events().is_empty());
     }
 
     #[test]
@@ -769,5 +2587,6 @@ mod tests {
 
         assert_eq!(request.rfq_id, rfq_id);
         assert_eq!(request.quote_id, quote_id);
+        assert!(request.price_bounds_override.is_none());
     }
 }
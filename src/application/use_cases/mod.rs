@@ -6,8 +6,10 @@
 //! business operation, handling validation, persistence, and events.
 
 pub mod collect_quotes;
+pub mod conclude_negotiation;
 pub mod create_rfq;
 pub mod execute_trade;
+pub mod submit_quote;
 
 #[cfg(test)]
 mod tests;
@@ -16,6 +18,9 @@ pub use collect_quotes::{
     CollectQuotesConfig, CollectQuotesResponse, CollectQuotesUseCase, QuoteEventPublisher,
     VenueQuoteResult, VenueRegistry,
 };
+pub use conclude_negotiation::{
+    ConcludeNegotiationResponse, ConcludeNegotiationUseCase, NegotiationEventPublisher,
+};
 pub use create_rfq::{
     ClientRepository, ComplianceService, CreateRfqUseCase, EventPublisher, InstrumentRegistry,
     RfqRepository,
@@ -24,3 +29,4 @@ pub use execute_trade::{
     ExecuteTradeRequest, ExecuteTradeResponse, ExecuteTradeUseCase, TradeEventPublisher,
     TradeRepository,
 };
+pub use submit_quote::{SubmitQuoteRequest, SubmitQuoteUseCase};
@@ -0,0 +1,224 @@
+//! # Submit Quote Use Case
+//!
+//! Accepts a quote pushed by a market maker.
+//!
+//! Venue adapters pull quotes from external venues; internal market makers
+//! that receive an RFQ broadcast can instead push their quote directly. The
+//! caller authenticates the market maker; this use case attributes the
+//! quote to a venue named after it, adds it to the RFQ, persists the RFQ,
+//! publishes `QuoteReceived`, and feeds the maker's performance metrics.
+//!
+//! Quotes for an RFQ the client has cancelled are rejected without a
+//! warning; one still in flight when the RFQ was cancelled withdraws the
+//! RFQ from the maker's response rate instead of counting against it.
+
+use crate::application::error::{ApplicationError, ApplicationResult};
+use crate::application::use_cases::collect_quotes::QuoteEventPublisher;
+use crate::application::use_cases::create_rfq::RfqRepository;
+use crate::domain::entities::quote::{Quote, QuoteBuilder};
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::errors::DomainError;
+use crate::domain::events::rfq_events::QuoteReceived;
+use crate::domain::services::mm_performance::MmPerformanceTracker;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    CounterpartyId, OrderSide, Price, Quantity, RfqId, RfqState, VenueId,
+};
+use crate::infrastructure::telemetry;
+use std::sync::Arc;
+use tracing::{Span, field, instrument};
+
+/// Request to submit a quote on behalf of an authenticated market maker.
+#[derive(Debug, Clone)]
+pub struct SubmitQuoteRequest {
+    /// The RFQ being quoted.
+    pub rfq_id: RfqId,
+    /// The authenticated market maker.
+    pub mm_id: CounterpartyId,
+    /// Quoted price.
+    pub price: Price,
+    /// Quoted quantity.
+    pub quantity: Quantity,
+    /// When the quote expires.
+    pub valid_until: Timestamp,
+    /// Side the client trades at this price; required on two-way RFQs.
+    pub side: Option<OrderSide>,
+}
+
+/// Use case for accepting quotes pushed by market makers.
+#[derive(Debug)]
+pub struct SubmitQuoteUseCase {
+    rfq_repository: Arc<dyn RfqRepository>,
+    event_publisher: Arc<dyn QuoteEventPublisher>,
+    performance_tracker: Option<Arc<MmPerformanceTracker>>,
+}
+
+impl SubmitQuoteUseCase {
+    /// Creates a new SubmitQuoteUseCase.
+    #[must_use]
+    pub fn new(
+        rfq_repository: Arc<dyn RfqRepository>,
+        event_publisher: Arc<dyn QuoteEventPublisher>,
+    ) -> Self {
+        Self {
+            rfq_repository,
+            event_publisher,
+            performance_tracker: None,
+        }
+    }
+
+    /// Records accepted quotes in the market maker performance tracker.
+    #[must_use]
+    pub fn with_performance_tracker(mut self, tracker: Arc<MmPerformanceTracker>) -> Self {
+        self.performance_tracker = Some(tracker);
+        self
+    }
+
+    /// Executes the submit quote use case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The RFQ is not found (`RfqNotFound`)
+    /// - The RFQ is not collecting quotes or has expired (`InvalidState`)
+    /// - The quote has already expired (`QuoteExpired`)
+    /// - The price or quantity is invalid (`Validation`)
+    /// - Persistence fails
+    #[instrument(skip_all, fields(rfq_id = %request.rfq_id, mm_id = %request.mm_id, quote_id = field::Empty))]
+    pub async fn execute(&self, request: SubmitQuoteRequest) -> ApplicationResult<Quote> {
+        let rfq_id = request.rfq_id;
+
+        // 1. Load RFQ
+        let mut rfq = self
+            .rfq_repository
+            .find_by_id(rfq_id)
+            .await
+            .map_err(ApplicationError::repository)?
+            .ok_or_else(|| ApplicationError::RfqNotFound(rfq_id.to_string()))?;
+
+        // 2. Only RFQs still collecting quotes accept them
+        if rfq.state() == RfqState::Cancelled {
+            self.record_late_quote(&rfq, &request.mm_id).await;
+            return Err(ApplicationError::InvalidState(format!(
+                "RFQ {} was cancelled and is not accepting quotes",
+                rfq_id
+            )));
+        }
+        if !matches!(
+            rfq.state(),
+            RfqState::QuoteRequesting | RfqState::QuotesReceived
+        ) {
+            return Err(ApplicationError::InvalidState(format!(
+                "RFQ {} is {} and not accepting quotes",
+                rfq_id,
+                rfq.state()
+            )));
+        }
+        if rfq.is_expired() {
+            return Err(ApplicationError::InvalidState(format!(
+                "RFQ {} has expired",
+                rfq_id
+            )));
+        }
+
+        // 3. Build the quote, attributed to the market maker's venue
+        let mut builder = QuoteBuilder::new(
+            rfq_id,
+            VenueId::new(request.mm_id.as_str()),
+            request.price,
+            request.quantity,
+            request.valid_until,
+        );
+        if let Some(side) = request.side {
+            builder = builder.side(side);
+        }
+        let quote = builder.try_build().map_err(map_domain_error)?;
+        Span::current().record("quote_id", field::display(quote.id()));
+
+        // 4. Add to RFQ and persist
+        rfq.receive_quote(quote.clone()).map_err(map_domain_error)?;
+        self.rfq_repository
+            .save(&rfq)
+            .await
+            .map_err(ApplicationError::repository)?;
+
+        // 5. Publish QuoteReceived
+        let mut event = QuoteReceived::new(
+            rfq_id,
+            quote.id(),
+            quote.venue_id().clone(),
+            quote.price(),
+            quote.quantity(),
+            quote.valid_until(),
+        );
+        event.metadata = event.metadata.with_trace_id(telemetry::current_trace_id());
+        if let Err(e) = self.event_publisher.publish_quote_received(event).await {
+            tracing::warn!("Failed to publish QuoteReceived event: {}", e);
+        }
+
+        // 6. Feed MM performance metrics
+        if let Some(tracker) = &self.performance_tracker {
+            let response_time_ms = u64::try_from(
+                quote.created_at().timestamp_millis() - rfq.created_at().timestamp_millis(),
+            )
+            .unwrap_or(0);
+            let rank = quote_rank(&rfq, &quote);
+            if let Err(e) = tracker
+                .record_quote_received(&request.mm_id, response_time_ms, rank)
+                .await
+            {
+                tracing::warn!("Failed to record MM quote performance: {}", e);
+            }
+        }
+
+        Ok(quote)
+    }
+
+    /// Applies the cancel grace rule to a quote for a cancelled RFQ.
+    ///
+    /// Makers that quoted before the cancellation already have their
+    /// response counted and are left alone.
+    async fn record_late_quote(&self, rfq: &Rfq, mm_id: &CounterpartyId) {
+        let venue_id = VenueId::new(mm_id.as_str());
+        let already_quoted = rfq
+            .quotes()
+            .iter()
+            .chain(rfq.retired_quotes().iter().map(|retired| retired.quote()))
+            .any(|quote| quote.venue_id() == &venue_id);
+        tracing::debug!(rfq_id = %rfq.id(), mm_id = %mm_id, "Rejected quote for cancelled RFQ");
+
+        if let Some(tracker) = &self.performance_tracker
+            && !already_quoted
+            && let Err(e) = tracker
+                .record_late_quote(mm_id, rfq.id(), rfq.updated_at())
+                .await
+        {
+            tracing::warn!("Failed to record late MM quote: {}", e);
+        }
+    }
+}
+
+/// Returns the quote's position among the RFQ's quotes on its side
+/// (1 = best price).
+fn quote_rank(rfq: &Rfq, quote: &Quote) -> u64 {
+    let side = rfq.side_of(quote);
+    let better = rfq
+        .quotes_on(side)
+        .filter(|other| match side {
+            OrderSide::Buy => other.price() < quote.price(),
+            OrderSide::Sell => other.price() > quote.price(),
+        })
+        .count();
+    u64::try_from(better).unwrap_or(u64::MAX).saturating_add(1)
+}
+
+/// Maps quote and RFQ errors to the application errors callers expect.
+fn map_domain_error(error: DomainError) -> ApplicationError {
+    match error {
+        DomainError::QuoteExpired(message) => ApplicationError::QuoteExpired(message),
+        DomainError::InvalidStateTransition { from, .. } => {
+            ApplicationError::InvalidState(format!("RFQ is {} and not accepting quotes", from))
+        }
+        other => ApplicationError::validation(other.to_string()),
+    }
+}
@@ -8,6 +8,7 @@
 //! - **CreateRFQ**: RFQ creation workflow tests
 //! - **CollectQuotes**: Quote collection with mock venues
 //! - **ExecuteTrade**: Trade execution workflow tests
+//! - **SubmitQuote**: Quotes pushed directly by market makers
 //! - **Error Handling**: Comprehensive error path coverage
 //! - **Integration**: End-to-end workflow tests
 
@@ -40,13 +41,18 @@ use crate::domain::entities::quote::Quote;
 use crate::domain::entities::rfq::{ComplianceResult, Rfq, RfqBuilder};
 use crate::domain::entities::trade::Trade;
 use crate::domain::events::TradeExecuted;
-use crate::domain::events::rfq_events::{QuoteReceived, RfqCreated};
+use crate::domain::events::domain_event::DomainEvent;
+use crate::domain::events::rfq_events::{QuoteCollectionStarted, QuoteReceived, RfqCreated};
 use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
 use crate::domain::value_objects::symbol::Symbol;
 use crate::domain::value_objects::timestamp::Timestamp;
 use crate::domain::value_objects::{
-    CounterpartyId, Instrument, OrderSide, Price, Quantity, QuoteId, RfqId, TradeId, VenueId,
+    CounterpartyId, FailureReason, Instrument, OrderSide, Price, Quantity, QuoteId, RfqId, TradeId,
+    VenueId,
 };
+use crate::infrastructure::persistence::cursor::{paginate, paginate_offset};
+use crate::infrastructure::persistence::in_memory::InMemoryEventStore;
+use crate::infrastructure::persistence::{EventStore, PageCursor, RfqListFilter, StoredEvent};
 use crate::infrastructure::venues::error::{VenueError, VenueResult};
 use crate::infrastructure::venues::traits::{ExecutionResult, VenueAdapter, VenueHealth};
 
@@ -108,6 +114,51 @@ impl RfqRepository for MockRfqRepository {
         }
         Ok(self.rfqs.lock().unwrap().get(&id).cloned())
     }
+
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> Result<Vec<Rfq>, String> {
+        let rfqs = self
+            .rfqs
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|rfq| filter.matches(rfq))
+            .cloned()
+            .collect();
+        Ok(paginate(rfqs, cursor, limit, PageCursor::from_rfq))
+    }
+
+    async fn list_offset(
+        &self,
+        offset: usize,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> Result<Vec<Rfq>, String> {
+        let rfqs = self
+            .rfqs
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|rfq| filter.matches(rfq))
+            .cloned()
+            .collect();
+        Ok(paginate_offset(rfqs, offset, limit, PageCursor::from_rfq))
+    }
+
+    async fn count_matching(&self, filter: &RfqListFilter) -> Result<u64, String> {
+        let count = self
+            .rfqs
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|rfq| filter.matches(rfq))
+            .count();
+        Ok(count as u64)
+    }
 }
 
 /// Mock event publisher that tracks published events.
@@ -267,6 +318,12 @@ pub struct MockQuoteEventPublisher {
     events: Mutex<Vec<QuoteReceived>>,
 }
 
+impl MockQuoteEventPublisher {
+    pub fn events(&self) -> Vec<QuoteReceived> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
 #[async_trait]
 impl QuoteEventPublisher for MockQuoteEventPublisher {
     async fn publish_quote_received(&self, event: QuoteReceived) -> Result<(), String> {
@@ -473,7 +530,7 @@ impl TradeRepository for MockTradeRepository {
 #[derive(Debug, Default)]
 pub struct MockTradeEventPublisher {
     executed_events: Mutex<Vec<TradeExecuted>>,
-    failed_events: Mutex<Vec<(RfqId, QuoteId, String)>>,
+    failed_events: Mutex<Vec<(RfqId, QuoteId, FailureReason)>>,
 }
 
 impl MockTradeEventPublisher {
@@ -484,6 +541,13 @@ impl MockTradeEventPublisher {
 
 #[async_trait]
 impl TradeEventPublisher for MockTradeEventPublisher {
+    async fn publish_execution_started(
+        &self,
+        _event: crate::domain::events::ExecutionStarted,
+    ) -> ApplicationResult<()> {
+        Ok(())
+    }
+
     async fn publish_trade_executed(&self, event: TradeExecuted) -> ApplicationResult<()> {
         self.executed_events.lock().unwrap().push(event);
         Ok(())
@@ -493,12 +557,12 @@ impl TradeEventPublisher for MockTradeEventPublisher {
         &self,
         rfq_id: RfqId,
         quote_id: QuoteId,
-        reason: &str,
+        reason: &FailureReason,
     ) -> ApplicationResult<()> {
         self.failed_events
             .lock()
             .unwrap()
-            .push((rfq_id, quote_id, reason.to_string()));
+            .push((rfq_id, quote_id, reason.clone()));
         Ok(())
     }
 
@@ -508,6 +572,107 @@ impl TradeEventPublisher for MockTradeEventPublisher {
     ) -> ApplicationResult<()> {
         Ok(())
     }
+
+    async fn publish_compliance_check(
+        &self,
+        _event: crate::domain::events::ComplianceEvent,
+    ) -> ApplicationResult<()> {
+        Ok(())
+    }
+}
+
+/// Publisher appending every event it is given to an event store, as the
+/// production publishers do.
+#[derive(Debug, Default)]
+pub struct EventStorePublisher {
+    store: InMemoryEventStore,
+}
+
+impl EventStorePublisher {
+    pub async fn events(&self, rfq_id: RfqId) -> Vec<StoredEvent> {
+        self.store.get_events(rfq_id).await.unwrap()
+    }
+
+    async fn append<E: serde::Serialize + DomainEvent>(&self, event: &E) -> Result<(), String> {
+        let rfq_id = event.rfq_id().ok_or("event without an RFQ")?;
+        let sequence = self
+            .store
+            .next_sequence(rfq_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        let mut stored = StoredEvent::from_event(event, sequence).map_err(|e| e.to_string())?;
+        if let Some(aggregate_id) = stored.aggregate_id {
+            let aggregate_sequence = self
+                .store
+                .next_aggregate_sequence(aggregate_id)
+                .await
+                .map_err(|e| e.to_string())?;
+            stored = stored.with_aggregate_sequence(aggregate_sequence);
+        }
+        self.store.append(stored).await.map_err(|e| e.to_string())
+    }
+}
+
+#[async_trait]
+impl EventPublisher for EventStorePublisher {
+    async fn publish_rfq_created(&self, event: RfqCreated) -> Result<(), String> {
+        self.append(&event).await
+    }
+}
+
+#[async_trait]
+impl QuoteEventPublisher for EventStorePublisher {
+    async fn publish_quote_received(&self, event: QuoteReceived) -> Result<(), String> {
+        self.append(&event).await
+    }
+
+    async fn publish_quote_collection_started(
+        &self,
+        event: QuoteCollectionStarted,
+    ) -> Result<(), String> {
+        self.append(&event).await
+    }
+}
+
+#[async_trait]
+impl TradeEventPublisher for EventStorePublisher {
+    async fn publish_execution_started(
+        &self,
+        event: crate::domain::events::ExecutionStarted,
+    ) -> ApplicationResult<()> {
+        self.append(&event)
+            .await
+            .map_err(ApplicationError::event_publish)
+    }
+
+    async fn publish_trade_executed(&self, event: TradeExecuted) -> ApplicationResult<()> {
+        self.append(&event)
+            .await
+            .map_err(ApplicationError::event_publish)
+    }
+
+    async fn publish_execution_failed(
+        &self,
+        _rfq_id: RfqId,
+        _quote_id: QuoteId,
+        _reason: &FailureReason,
+    ) -> ApplicationResult<()> {
+        Ok(())
+    }
+
+    async fn publish_position_updated(
+        &self,
+        _event: crate::domain::events::PositionUpdated,
+    ) -> ApplicationResult<()> {
+        Ok(())
+    }
+
+    async fn publish_compliance_check(
+        &self,
+        _event: crate::domain::events::ComplianceEvent,
+    ) -> ApplicationResult<()> {
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -971,6 +1136,153 @@ mod execute_trade_tests {
     }
 }
 
+// ============================================================================
+// SubmitQuote Use Case Tests
+// ============================================================================
+
+#[cfg(test)]
+mod submit_quote_tests {
+    use super::*;
+    use crate::application::use_cases::submit_quote::{SubmitQuoteRequest, SubmitQuoteUseCase};
+    use crate::domain::services::mm_performance::MmPerformanceTracker;
+    use crate::domain::value_objects::RfqState;
+    use crate::infrastructure::persistence::in_memory::InMemoryMmPerformanceRepository;
+
+    fn quote_request(rfq_id: RfqId, valid_until: Timestamp) -> SubmitQuoteRequest {
+        SubmitQuoteRequest {
+            rfq_id,
+            mm_id: CounterpartyId::new("mm-1"),
+            price: Price::new(50000.0).unwrap(),
+            quantity: Quantity::new(1.0).unwrap(),
+            valid_until,
+            side: None,
+        }
+    }
+
+    fn collecting_rfq() -> Rfq {
+        let mut rfq = create_test_rfq();
+        rfq.start_quote_collection().unwrap();
+        rfq
+    }
+
+    #[tokio::test]
+    async fn accepts_quote_and_records_performance() {
+        let rfq = collecting_rfq();
+        let rfq_id = rfq.id();
+        let rfq_repo = Arc::new(MockRfqRepository::with_rfq(rfq));
+        let publisher = Arc::new(MockQuoteEventPublisher::default());
+        let tracker = Arc::new(MmPerformanceTracker::with_defaults(Arc::new(
+            InMemoryMmPerformanceRepository::new(),
+        )));
+        let use_case = SubmitQuoteUseCase::new(rfq_repo.clone(), publisher.clone())
+            .with_performance_tracker(tracker.clone());
+
+        let quote = use_case
+            .execute(quote_request(rfq_id, Timestamp::now().add_secs(60)))
+            .await
+            .unwrap();
+
+        assert_eq!(quote.venue_id(), &VenueId::new("mm-1"));
+
+        let saved = rfq_repo.get_rfq(rfq_id).unwrap();
+        assert_eq!(saved.state(), RfqState::QuotesReceived);
+        assert_eq!(saved.quotes().len(), 1);
+
+        let events = publisher.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events.first().unwrap().quote_id, quote.id());
+
+        let metrics = tracker
+            .get_metrics(&CounterpartyId::new("mm-1"))
+            .await
+            .unwrap();
+        assert_eq!(metrics.total_quotes_provided(), 1);
+        assert_eq!(metrics.competitiveness_score(), Some(1.0));
+    }
+
+    #[tokio::test]
+    async fn rejects_expired_quote() {
+        let rfq = collecting_rfq();
+        let rfq_id = rfq.id();
+        let rfq_repo = Arc::new(MockRfqRepository::with_rfq(rfq));
+        let use_case = SubmitQuoteUseCase::new(
+            rfq_repo.clone(),
+            Arc::new(MockQuoteEventPublisher::default()),
+        );
+
+        let result = use_case
+            .execute(quote_request(rfq_id, Timestamp::now().sub_secs(1)))
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::QuoteExpired(_))));
+        assert!(rfq_repo.get_rfq(rfq_id).unwrap().quotes().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rejects_rfq_not_collecting_quotes() {
+        let rfq = create_test_rfq();
+        let rfq_id = rfq.id();
+        let publisher = Arc::new(MockQuoteEventPublisher::default());
+        let use_case = SubmitQuoteUseCase::new(
+            Arc::new(MockRfqRepository::with_rfq(rfq)),
+            publisher.clone(),
+        );
+
+        let result = use_case
+            .execute(quote_request(rfq_id, Timestamp::now().add_secs(60)))
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::InvalidState(_))));
+        assert!(publisher.events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rejects_late_quote_without_charging_metrics() {
+        let mut rfq = collecting_rfq();
+        rfq.cancel().unwrap();
+        let rfq_id = rfq.id();
+        let rfq_repo = Arc::new(MockRfqRepository::with_rfq(rfq));
+        let publisher = Arc::new(MockQuoteEventPublisher::default());
+        let tracker = Arc::new(MmPerformanceTracker::with_defaults(Arc::new(
+            InMemoryMmPerformanceRepository::new(),
+        )));
+        let mm_id = CounterpartyId::new("mm-1");
+        tracker.record_rfq_sent(&mm_id).await.unwrap();
+        let use_case = SubmitQuoteUseCase::new(rfq_repo.clone(), publisher.clone())
+            .with_performance_tracker(tracker.clone());
+
+        let result = use_case
+            .execute(quote_request(rfq_id, Timestamp::now().add_secs(60)))
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::InvalidState(_))));
+        assert!(publisher.events().is_empty());
+        assert!(rfq_repo.get_rfq(rfq_id).unwrap().quotes().is_empty());
+
+        let metrics = tracker.get_metrics(&mm_id).await.unwrap();
+        assert_eq!(metrics.total_rfqs_received(), 0);
+        assert_eq!(metrics.total_quotes_provided(), 0);
+        assert!(metrics.response_rate_pct().is_none());
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_rfq() {
+        let use_case = SubmitQuoteUseCase::new(
+            Arc::new(MockRfqRepository::new()),
+            Arc::new(MockQuoteEventPublisher::default()),
+        );
+
+        let result = use_case
+            .execute(quote_request(
+                RfqId::new_v4(),
+                Timestamp::now().add_secs(60),
+            ))
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::RfqNotFound(_))));
+    }
+}
+
 // ============================================================================
 // Integration Tests (End-to-End Workflows)
 // ============================================================================
@@ -1039,6 +1351,164 @@ mod integration_tests {
             crate::domain::value_objects::RfqState::Executed
         );
     }
+
+    #[tokio::test]
+    async fn rfq_lifecycle_appends_versioned_events_to_the_event_store() {
+        let rfq_repo = Arc::new(MockRfqRepository::new());
+        let events = Arc::new(EventStorePublisher::default());
+
+        let rfq_id = CreateRfqUseCase::new(
+            rfq_repo.clone(),
+            events.clone(),
+            Arc::new(MockComplianceService::passing()),
+            Arc::new(MockClientRepository::with_active_client("client-1")),
+            Arc::new(MockInstrumentRegistry::with_instrument("BTC", "USD")),
+        )
+        .execute(CreateRfqRequest::new(
+            "client-1",
+            "BTC",
+            "USD",
+            OrderSide::Buy,
+            1.0,
+            300,
+        ))
+        .await
+        .unwrap()
+        .rfq_id;
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(MockVenueAdapter::successful_quote("venue-1", rfq_id)),
+            Arc::new(MockVenueAdapter::successful_quote("venue-2", rfq_id)),
+        ];
+        let quotes = CollectQuotesUseCase::new(
+            rfq_repo.clone(),
+            events.clone(),
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            CollectQuotesConfig::with_timeout(1000),
+        )
+        .execute(rfq_id)
+        .await
+        .unwrap();
+
+        let quote_id = quotes.quotes.first().unwrap().id();
+        let exec_venue = Arc::new(MockVenueAdapter::successful_execution("venue-1", quote_id));
+        ExecuteTradeUseCase::new(
+            rfq_repo.clone(),
+            Arc::new(MockTradeRepository::new()),
+            events.clone(),
+            Arc::new(MockVenueRegistry::with_venues(vec![exec_venue])),
+        )
+        .execute(ExecuteTradeRequest::new(rfq_id, quote_id))
+        .await
+        .unwrap();
+
+        // Every event reached the store in an unbroken sequence, although
+        // receiving and selecting quotes moved the RFQ's version without
+        // emitting versioned events.
+        let stored = events.events(rfq_id).await;
+        let names: Vec<&str> = stored.iter().map(|e| e.event_name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "RfqCreated",
+                "QuoteCollectionStarted",
+                "QuoteReceived",
+                "QuoteReceived",
+                "ExecutionStarted",
+                "TradeExecuted",
+            ]
+        );
+        let rfq_events: Vec<&StoredEvent> = stored
+            .iter()
+            .filter(|e| e.aggregate_id == Some(rfq_id.get()))
+            .collect();
+        let sequences: Vec<u64> = rfq_events.iter().map(|e| e.aggregate_sequence).collect();
+        assert_eq!(sequences, [1, 2, 3]);
+        let rfq_versions: Vec<u64> = rfq_events.iter().map(|e| e.aggregate_version).collect();
+        assert_eq!(rfq_versions.first(), Some(&1));
+        assert!(rfq_versions.is_sorted_by(|a, b| a < b));
+        assert!(
+            rfq_versions.last() > Some(&3),
+            "versions skip: {rfq_versions:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn rfq_lifecycle_is_scraped_from_metrics_endpoint() {
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use tower::ServiceExt;
+
+        let handle = crate::infrastructure::metrics::install().unwrap();
+
+        let rfq_repo = Arc::new(MockRfqRepository::new());
+        let create_use_case = CreateRfqUseCase::new(
+            rfq_repo.clone(),
+            Arc::new(MockEventPublisher::new()),
+            Arc::new(MockComplianceService::passing()),
+            Arc::new(MockClientRepository::with_active_client("client-1")),
+            Arc::new(MockInstrumentRegistry::with_instrument("BTC", "USD")),
+        );
+        let rfq_id = create_use_case
+            .execute(CreateRfqRequest::new(
+                "client-1",
+                "BTC",
+                "USD",
+                OrderSide::Buy,
+                1.0,
+                300,
+            ))
+            .await
+            .unwrap()
+            .rfq_id;
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![Arc::new(
+            MockVenueAdapter::successful_quote("metrics-venue", rfq_id),
+        )];
+        let collect_use_case = CollectQuotesUseCase::new(
+            rfq_repo.clone(),
+            Arc::new(MockQuoteEventPublisher::default()),
+            Arc::new(MockVenueRegistry::with_venues(venues)),
+            CollectQuotesConfig::with_timeout(1000),
+        );
+        let collected = collect_use_case.execute(rfq_id).await.unwrap();
+        let quote_id = collected.quotes.first().unwrap().id();
+
+        let exec_venues: Vec<Arc<dyn VenueAdapter>> = vec![Arc::new(
+            MockVenueAdapter::successful_execution("metrics-venue", quote_id),
+        )];
+        let execute_use_case = ExecuteTradeUseCase::new(
+            rfq_repo.clone(),
+            Arc::new(MockTradeRepository::new()),
+            Arc::new(MockTradeEventPublisher::default()),
+            Arc::new(MockVenueRegistry::with_venues(exec_venues)),
+        );
+        execute_use_case
+            .execute(ExecuteTradeRequest::new(rfq_id, quote_id))
+            .await
+            .unwrap();
+
+        let response = crate::api::rest::metrics::metrics_router::<()>(handle)
+            .oneshot(Request::get("/metrics").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+
+        for series in [
+            "otc_rfq_quote_collection_duration_seconds_bucket{outcome=\"ok\"",
+            "otc_rfq_venue_response_duration_seconds_count{venue=\"metrics-venue\",outcome=\"ok\"}",
+            "otc_rfq_execution_duration_seconds_count{outcome=\"ok\"}",
+            "otc_rfq_rfqs_terminal_total{state=\"EXECUTED\"}",
+            "otc_rfq_active_rfqs",
+            "otc_rfq_open_negotiations",
+        ] {
+            assert!(body.contains(series), "missing {series} in:\n{body}");
+        }
+    }
 }
 
 // ============================================================================
@@ -1331,6 +1801,103 @@ mod e2e_rfq_workflow_tests {
         );
     }
 
+    /// Tests that one RFQ lifecycle feeds the MM performance tracker.
+    #[tokio::test]
+    async fn e2e_lifecycle_records_mm_performance_events() {
+        use crate::application::services::{MmPerformanceRecorder, PerformanceVenueRepository};
+        use crate::domain::entities::mm_performance::MmPerformanceEventKind;
+        use crate::domain::entities::venue::Venue;
+        use crate::domain::services::mm_performance::{
+            MmPerformanceRepository, MmPerformanceTracker,
+        };
+        use crate::domain::value_objects::VenueType;
+        use crate::infrastructure::persistence::in_memory::InMemoryMmPerformanceRepository;
+
+        #[derive(Debug)]
+        struct MockVenues(Venue);
+
+        #[async_trait]
+        impl PerformanceVenueRepository for MockVenues {
+            async fn find_by_id(&self, id: &VenueId) -> Result<Option<Venue>, String> {
+                Ok((self.0.id() == id).then(|| self.0.clone()))
+            }
+        }
+
+        let fixture = E2ETestFixture::new();
+        let repository = Arc::new(InMemoryMmPerformanceRepository::new());
+        let tracker = Arc::new(MmPerformanceTracker::with_defaults(repository.clone()));
+        let mut alpha = Venue::new(VenueId::new("venue-alpha"), "Alpha", VenueType::ExternalMM);
+        alpha.set_mm_counterparty_id(Some(CounterpartyId::new("mm-alpha")));
+        let recorder = Arc::new(
+            MmPerformanceRecorder::new(tracker).with_venue_repository(Arc::new(MockVenues(alpha))),
+        );
+
+        let create_req = CreateRfqRequest::new("client-1", "BTC", "USD", OrderSide::Buy, 1.0, 300);
+        let rfq_id = fixture
+            .create_rfq_use_case()
+            .execute(create_req)
+            .await
+            .unwrap()
+            .rfq_id;
+
+        let venues: Vec<Arc<dyn VenueAdapter>> = vec![
+            Arc::new(MockVenueAdapter::successful_quote("venue-alpha", rfq_id)),
+            Arc::new(MockVenueAdapter::failing_quote(
+                "venue-beta",
+                "no liquidity",
+            )),
+        ];
+        let response = fixture
+            .collect_quotes_use_case(venues)
+            .with_performance_recorder(recorder.clone())
+            .execute(rfq_id)
+            .await
+            .unwrap();
+        let quote_id = response.quotes[0].id();
+
+        let exec_venues: Vec<Arc<dyn VenueAdapter>> = vec![Arc::new(
+            MockVenueAdapter::successful_execution("venue-alpha", quote_id),
+        )];
+        fixture
+            .execute_trade_use_case(exec_venues)
+            .with_performance_recorder(recorder.clone())
+            .execute(ExecuteTradeRequest::new(rfq_id, quote_id))
+            .await
+            .unwrap();
+        recorder.flush().await;
+
+        assert_eq!(repository.total_event_count(), 5);
+        let from = Timestamp::now().sub_secs(60);
+        let to = Timestamp::now().add_secs(60);
+
+        let beta = repository
+            .get_events(&CounterpartyId::new("venue-beta"), from, to)
+            .await
+            .unwrap();
+        let beta_kinds: Vec<_> = beta.iter().map(|e| e.kind().clone()).collect();
+        assert_eq!(beta_kinds, vec![MmPerformanceEventKind::RfqSent]);
+
+        let mut alpha = repository
+            .get_events(&CounterpartyId::new("mm-alpha"), from, to)
+            .await
+            .unwrap();
+        alpha.sort_by_key(|e| e.kind().as_u8());
+        let kinds: Vec<u8> = alpha.iter().map(|e| e.kind().as_u8()).collect();
+        assert_eq!(kinds, vec![0, 1, 2, 4]);
+        let MmPerformanceEventKind::QuoteReceived { rank, .. } = alpha[1].kind() else {
+            unreachable!("expected QuoteReceived");
+        };
+        assert_eq!(*rank, 1);
+
+        // Each step is stamped no earlier than the one before it
+        let sent = alpha[0].timestamp();
+        let received = alpha[1].timestamp();
+        let accepted = alpha[3].timestamp();
+        let executed = alpha[2].timestamp();
+        assert!(sent <= received && received <= accepted && accepted <= executed);
+        assert!(beta[0].timestamp() <= received);
+    }
+
     // ========================================================================
     // Failure Scenario Tests
     // ========================================================================
@@ -0,0 +1,42 @@
+//! # RFQ Summary Rebuild CLI
+//!
+//! Command-line tool to rebuild the `rfq_summary` read model.
+//!
+//! Deletes every summary row and replays the domain event store through
+//! [`RfqSummaryProjection`]. Run it after a projection bug fix or when the
+//! table is suspected to have drifted from the events.
+
+use clap::Parser;
+use otc_rfq::application::services::RfqSummaryProjection;
+use otc_rfq::infrastructure::persistence::postgres::{PostgresEventStore, PostgresRfqSummaryStore};
+use sqlx::PgPool;
+use std::sync::Arc;
+
+#[derive(Parser)]
+#[command(name = "rebuild-rfq-summary")]
+#[command(about = "Rebuild the rfq_summary read model from the event store", long_about = None)]
+struct Args {
+    /// PostgreSQL connection URL (defaults to `OTC_RFQ_DATABASE_URL`)
+    #[arg(long)]
+    database_url: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let database_url = match args.database_url {
+        Some(url) => url,
+        None => std::env::var("OTC_RFQ_DATABASE_URL")
+            .map_err(|_| anyhow::anyhow!("pass --database-url or set OTC_RFQ_DATABASE_URL"))?,
+    };
+
+    let pool = PgPool::connect(&database_url).await?;
+    let events = PostgresEventStore::new(pool.clone());
+    let projection = RfqSummaryProjection::new(Arc::new(PostgresRfqSummaryStore::new(pool)));
+
+    let rows = projection.rebuild(&events).await?;
+    println!("Rebuilt {rows} RFQ summaries");
+
+    Ok(())
+}
@@ -0,0 +1,199 @@
+//! # Client Configuration
+//!
+//! Endpoint, deadlines and retry behaviour of
+//! [`OtcRfqClient`](crate::client::OtcRfqClient).
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::application::services::RetryPolicy;
+//! use otc_rfq::client::{Call, ClientConfig};
+//! use std::time::Duration;
+//!
+//! let config = ClientConfig::new("http://localhost:50051")
+//!     .with_default_deadline(Duration::from_secs(2))
+//!     .with_deadline(Call::SelectQuote, Duration::from_secs(10))
+//!     .with_retry_policy(RetryPolicy::aggressive());
+//!
+//! assert_eq!(config.deadline(Call::GetRfq), Duration::from_secs(2));
+//! assert_eq!(config.deadline(Call::SelectQuote), Duration::from_secs(10));
+//! ```
+
+use crate::application::services::RetryPolicy;
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+/// Default deadline of a call.
+pub const DEFAULT_CALL_DEADLINE: Duration = Duration::from_secs(5);
+
+/// Default timeout for establishing the channel.
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Default delay before a watch asks again after the server ended its stream
+/// on a non-terminal state.
+pub const DEFAULT_WATCH_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A client call, for per-call configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Call {
+    /// `CreateRfq`.
+    CreateRfq,
+    /// `GetRfq`.
+    GetRfq,
+    /// `ExecuteTrade`, selecting a quote.
+    SelectQuote,
+    /// `SubmitQuote`.
+    SubmitQuote,
+    /// Opening a `StreamRfqStatus` stream; the stream itself has no deadline.
+    WatchRfq,
+}
+
+impl Call {
+    /// Returns true if repeating the call cannot change its outcome, so it
+    /// is retried on `UNAVAILABLE`.
+    ///
+    /// Creating an RFQ, selecting a quote and submitting a quote are not
+    /// retried: the server may have acted on a call whose response was lost.
+    #[must_use]
+    pub const fn is_idempotent(self) -> bool {
+        matches!(self, Self::GetRfq | Self::WatchRfq)
+    }
+}
+
+/// Market maker credentials sent with quote submissions.
+#[derive(Clone, PartialEq, Eq)]
+pub struct MmCredentials {
+    /// The market maker's ID.
+    pub mm_id: String,
+    /// The market maker's API key.
+    pub api_key: String,
+}
+
+impl fmt::Debug for MmCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MmCredentials")
+            .field("mm_id", &self.mm_id)
+            .field("api_key", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Configuration of an [`OtcRfqClient`](crate::client::OtcRfqClient).
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    endpoint: String,
+    connect_timeout: Duration,
+    default_deadline: Duration,
+    deadlines: HashMap<Call, Duration>,
+    retry_policy: RetryPolicy,
+    watch_poll_interval: Duration,
+    mm_credentials: Option<MmCredentials>,
+}
+
+impl ClientConfig {
+    /// Creates a configuration for the server at `endpoint`, e.g.
+    /// `http://localhost:50051`.
+    #[must_use]
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            default_deadline: DEFAULT_CALL_DEADLINE,
+            deadlines: HashMap::new(),
+            retry_policy: RetryPolicy::default(),
+            watch_poll_interval: DEFAULT_WATCH_POLL_INTERVAL,
+            mm_credentials: None,
+        }
+    }
+
+    /// Sets the timeout for establishing the channel.
+    #[must_use]
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Sets the deadline of calls without their own.
+    #[must_use]
+    pub fn with_default_deadline(mut self, deadline: Duration) -> Self {
+        self.default_deadline = deadline;
+        self
+    }
+
+    /// Sets the deadline of `call`.
+    #[must_use]
+    pub fn with_deadline(mut self, call: Call, deadline: Duration) -> Self {
+        self.deadlines.insert(call, deadline);
+        self
+    }
+
+    /// Sets the retry policy of idempotent calls, also used to back off
+    /// between watch reconnections.
+    #[must_use]
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Sets the delay before a watch asks again after the server ended its
+    /// stream on a non-terminal state.
+    #[must_use]
+    pub fn with_watch_poll_interval(mut self, interval: Duration) -> Self {
+        self.watch_poll_interval = interval;
+        self
+    }
+
+    /// Sets the market maker credentials sent with quote submissions.
+    #[must_use]
+    pub fn with_mm_credentials(
+        mut self,
+        mm_id: impl Into<String>,
+        api_key: impl Into<String>,
+    ) -> Self {
+        self.mm_credentials = Some(MmCredentials {
+            mm_id: mm_id.into(),
+            api_key: api_key.into(),
+        });
+        self
+    }
+
+    /// Returns the server endpoint.
+    #[must_use]
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Returns the timeout for establishing the channel.
+    #[must_use]
+    pub fn connect_timeout(&self) -> Duration {
+        self.connect_timeout
+    }
+
+    /// Returns the deadline of `call`.
+    #[must_use]
+    pub fn deadline(&self, call: Call) -> Duration {
+        self.deadlines
+            .get(&call)
+            .copied()
+            .unwrap_or(self.default_deadline)
+    }
+
+    /// Returns the retry policy of idempotent calls.
+    #[must_use]
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Returns the delay between watch polls.
+    #[must_use]
+    pub fn watch_poll_interval(&self) -> Duration {
+        self.watch_poll_interval
+    }
+
+    /// Returns the market maker credentials, if set.
+    #[must_use]
+    pub fn mm_credentials(&self) -> Option<&MmCredentials> {
+        self.mm_credentials.as_ref()
+    }
+}
@@ -0,0 +1,441 @@
+//! # Client Errors
+//!
+//! Errors returned by [`OtcRfqClient`](crate::client::OtcRfqClient).
+//!
+//! A status carrying the service's `google.rpc.ErrorInfo` details is decoded
+//! into [`ClientError::Domain`], whose [`ErrorCode`] mirrors the
+//! [`DomainError`](crate::domain::errors::DomainError) variant the server
+//! raised and whose metadata holds the variant's structured fields. Other
+//! statuses are kept as [`ClientError::Status`].
+//!
+//! # Examples
+//!
+//! ```ignore
+//! match client.select_quote(rfq_id, quote_id).await {
+//!     Err(ClientError::Domain { code: ErrorCode::QuoteExpired, .. }) => refresh_quotes().await?,
+//!     result => result?,
+//! }
+//! ```
+
+use crate::api::grpc::error_mapping::{ERROR_DOMAIN, error_info};
+use crate::application::services::Retryable;
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+use thiserror::Error;
+use tonic::{Code, Status};
+
+/// Result type for client calls.
+pub type ClientResult<T> = Result<T, ClientError>;
+
+/// Error code of a domain error, named after its `DomainError` variant.
+///
+/// Codes this client version does not know are kept as
+/// [`ErrorCode::Unknown`], so a newer server does not break older clients.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    /// Invalid quantity value.
+    InvalidQuantity,
+    /// Invalid price value.
+    InvalidPrice,
+    /// General validation error.
+    ValidationError,
+    /// Quote has expired.
+    QuoteExpired,
+    /// Quote not found.
+    QuoteNotFound,
+    /// Insufficient liquidity for fill.
+    InsufficientLiquidity,
+    /// Minimum quantity not met.
+    MinQuantityNotMet,
+    /// Minimum fill quantity is not positive or exceeds the requested quantity.
+    InvalidMinQuantity,
+    /// The RFQ's venue allowlist and blocklist leave no venue to query.
+    NoEligibleVenues,
+    /// Allocation mismatch.
+    AllocationMismatch,
+    /// No reference price available.
+    NoReferencePrice,
+    /// Division by zero.
+    DivisionByZero,
+    /// Price out of bounds.
+    PriceOutOfBounds,
+    /// Quantity is not a multiple of the instrument's lot size.
+    InvalidLotSize,
+    /// Price is not a multiple of the instrument's tick size.
+    InvalidTickSize,
+    /// Invalid state transition for RFQ.
+    InvalidStateTransition,
+    /// Invalid state for operation.
+    InvalidState,
+    /// Operation not allowed in current state.
+    OperationNotAllowed,
+    /// Trade not in correct state for off-book execution.
+    InvalidTradeStateForExecution,
+    /// Quote is already locked.
+    QuoteLocked,
+    /// Failed to acquire lock.
+    LockAcquisitionFailed,
+    /// Conflict detected during concurrent operation.
+    ConflictDetected,
+    /// Another execution of the same RFQ holds the execution lock.
+    ExecutionInProgress,
+    /// Risk check failed.
+    RiskCheckFailed,
+    /// Unauthorized counterparty.
+    UnauthorizedCounterparty,
+    /// Validation failed.
+    ValidationFailed,
+    /// Invalid negotiation state transition.
+    InvalidNegotiationStateTransition,
+    /// Maximum negotiation rounds reached.
+    MaxNegotiationRoundsReached,
+    /// No price improvement in counter-quote.
+    NoPriceImprovement,
+    /// Last-look was rejected by market maker.
+    LastLookRejected,
+    /// Last-look timed out.
+    LastLookTimeout,
+    /// Acceptance flow timed out.
+    AcceptanceTimeout,
+    /// Firm-up moved the price of an indicative quote beyond tolerance.
+    FirmUpPriceMoved,
+    /// Too few competing quotes for the trade's notional band.
+    QuorumNotMet,
+    /// Collateral lock failed.
+    CollateralLockFailed,
+    /// The client holds too little collateral for the trade.
+    InsufficientCollateral,
+    /// The collateral check could not be completed.
+    CollateralUnavailable,
+    /// Settlement failed.
+    SettlementFailed,
+    /// Position update failed.
+    PositionUpdateFailed,
+    /// Price bounds verification failed (CRE check).
+    PriceBoundsVerificationFailed,
+    /// Invalid package quote.
+    InvalidPackageQuote,
+    /// Inconsistent leg prices in package quote.
+    InconsistentLegPrices,
+    /// Multi-leg execution failed.
+    MultiLegExecutionFailed,
+    /// Rollback failed during multi-leg execution recovery.
+    RollbackFailed,
+    /// Leg execution timed out.
+    LegExecutionTimeout,
+    /// Market maker capacity exceeded.
+    CapacityExceeded,
+    /// Capacity reservation not found.
+    ReservationNotFound,
+    /// Capacity repository error.
+    CapacityRepositoryError,
+    /// Capacity counter overflow.
+    CapacityOverflow,
+    /// Capacity counter underflow.
+    CapacityUnderflow,
+    /// Fee calculation failed.
+    FeeCalculationFailed,
+    /// Confirmation delivery failed.
+    ConfirmationFailed,
+    /// Invalid notification preferences.
+    InvalidNotificationPreferences,
+    /// Schema not found.
+    SchemaNotFound,
+    /// Schema already registered.
+    SchemaAlreadyRegistered,
+    /// Schema generation failed.
+    SchemaGenerationFailed,
+    /// A code this client version does not know.
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// Parses the `ErrorInfo.reason` of a status.
+    #[must_use]
+    pub fn from_reason(reason: &str) -> Self {
+        match reason {
+            "INVALID_QUANTITY" => Self::InvalidQuantity,
+            "INVALID_PRICE" => Self::InvalidPrice,
+            "VALIDATION_ERROR" => Self::ValidationError,
+            "QUOTE_EXPIRED" => Self::QuoteExpired,
+            "QUOTE_NOT_FOUND" => Self::QuoteNotFound,
+            "INSUFFICIENT_LIQUIDITY" => Self::InsufficientLiquidity,
+            "MIN_QUANTITY_NOT_MET" => Self::MinQuantityNotMet,
+            "INVALID_MIN_QUANTITY" => Self::InvalidMinQuantity,
+            "NO_ELIGIBLE_VENUES" => Self::NoEligibleVenues,
+            "ALLOCATION_MISMATCH" => Self::AllocationMismatch,
+            "NO_REFERENCE_PRICE" => Self::NoReferencePrice,
+            "DIVISION_BY_ZERO" => Self::DivisionByZero,
+            "PRICE_OUT_OF_BOUNDS" => Self::PriceOutOfBounds,
+            "INVALID_LOT_SIZE" => Self::InvalidLotSize,
+            "INVALID_TICK_SIZE" => Self::InvalidTickSize,
+            "INVALID_STATE_TRANSITION" => Self::InvalidStateTransition,
+            "INVALID_STATE" => Self::InvalidState,
+            "OPERATION_NOT_ALLOWED" => Self::OperationNotAllowed,
+            "INVALID_TRADE_STATE_FOR_EXECUTION" => Self::InvalidTradeStateForExecution,
+            "QUOTE_LOCKED" => Self::QuoteLocked,
+            "LOCK_ACQUISITION_FAILED" => Self::LockAcquisitionFailed,
+            "CONFLICT_DETECTED" => Self::ConflictDetected,
+            "EXECUTION_IN_PROGRESS" => Self::ExecutionInProgress,
+            "RISK_CHECK_FAILED" => Self::RiskCheckFailed,
+            "UNAUTHORIZED_COUNTERPARTY" => Self::UnauthorizedCounterparty,
+            "VALIDATION_FAILED" => Self::ValidationFailed,
+            "INVALID_NEGOTIATION_STATE_TRANSITION" => Self::InvalidNegotiationStateTransition,
+            "MAX_NEGOTIATION_ROUNDS_REACHED" => Self::MaxNegotiationRoundsReached,
+            "NO_PRICE_IMPROVEMENT" => Self::NoPriceImprovement,
+            "LAST_LOOK_REJECTED" => Self::LastLookRejected,
+            "LAST_LOOK_TIMEOUT" => Self::LastLookTimeout,
+            "ACCEPTANCE_TIMEOUT" => Self::AcceptanceTimeout,
+            "FIRM_UP_PRICE_MOVED" => Self::FirmUpPriceMoved,
+            "QUORUM_NOT_MET" => Self::QuorumNotMet,
+            "COLLATERAL_LOCK_FAILED" => Self::CollateralLockFailed,
+            "INSUFFICIENT_COLLATERAL" => Self::InsufficientCollateral,
+            "COLLATERAL_UNAVAILABLE" => Self::CollateralUnavailable,
+            "SETTLEMENT_FAILED" => Self::SettlementFailed,
+            "POSITION_UPDATE_FAILED" => Self::PositionUpdateFailed,
+            "PRICE_BOUNDS_VERIFICATION_FAILED" => Self::PriceBoundsVerificationFailed,
+            "INVALID_PACKAGE_QUOTE" => Self::InvalidPackageQuote,
+            "INCONSISTENT_LEG_PRICES" => Self::InconsistentLegPrices,
+            "MULTI_LEG_EXECUTION_FAILED" => Self::MultiLegExecutionFailed,
+            "ROLLBACK_FAILED" => Self::RollbackFailed,
+            "LEG_EXECUTION_TIMEOUT" => Self::LegExecutionTimeout,
+            "CAPACITY_EXCEEDED" => Self::CapacityExceeded,
+            "RESERVATION_NOT_FOUND" => Self::ReservationNotFound,
+            "CAPACITY_REPOSITORY_ERROR" => Self::CapacityRepositoryError,
+            "CAPACITY_OVERFLOW" => Self::CapacityOverflow,
+            "CAPACITY_UNDERFLOW" => Self::CapacityUnderflow,
+            "FEE_CALCULATION_FAILED" => Self::FeeCalculationFailed,
+            "CONFIRMATION_FAILED" => Self::ConfirmationFailed,
+            "INVALID_NOTIFICATION_PREFERENCES" => Self::InvalidNotificationPreferences,
+            "SCHEMA_NOT_FOUND" => Self::SchemaNotFound,
+            "SCHEMA_ALREADY_REGISTERED" => Self::SchemaAlreadyRegistered,
+            "SCHEMA_GENERATION_FAILED" => Self::SchemaGenerationFailed,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+
+    /// Returns the `ErrorInfo.reason` of the code.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::InvalidQuantity => "INVALID_QUANTITY",
+            Self::InvalidPrice => "INVALID_PRICE",
+            Self::ValidationError => "VALIDATION_ERROR",
+            Self::QuoteExpired => "QUOTE_EXPIRED",
+            Self::QuoteNotFound => "QUOTE_NOT_FOUND",
+            Self::InsufficientLiquidity => "INSUFFICIENT_LIQUIDITY",
+            Self::MinQuantityNotMet => "MIN_QUANTITY_NOT_MET",
+            Self::InvalidMinQuantity => "INVALID_MIN_QUANTITY",
+            Self::NoEligibleVenues => "NO_ELIGIBLE_VENUES",
+            Self::AllocationMismatch => "ALLOCATION_MISMATCH",
+            Self::NoReferencePrice => "NO_REFERENCE_PRICE",
+            Self::DivisionByZero => "DIVISION_BY_ZERO",
+            Self::PriceOutOfBounds => "PRICE_OUT_OF_BOUNDS",
+            Self::InvalidLotSize => "INVALID_LOT_SIZE",
+            Self::InvalidTickSize => "INVALID_TICK_SIZE",
+            Self::InvalidStateTransition => "INVALID_STATE_TRANSITION",
+            Self::InvalidState => "INVALID_STATE",
+            Self::OperationNotAllowed => "OPERATION_NOT_ALLOWED",
+            Self::InvalidTradeStateForExecution => "INVALID_TRADE_STATE_FOR_EXECUTION",
+            Self::QuoteLocked => "QUOTE_LOCKED",
+            Self::LockAcquisitionFailed => "LOCK_ACQUISITION_FAILED",
+            Self::ConflictDetected => "CONFLICT_DETECTED",
+            Self::ExecutionInProgress => "EXECUTION_IN_PROGRESS",
+            Self::RiskCheckFailed => "RISK_CHECK_FAILED",
+            Self::UnauthorizedCounterparty => "UNAUTHORIZED_COUNTERPARTY",
+            Self::ValidationFailed => "VALIDATION_FAILED",
+            Self::InvalidNegotiationStateTransition => "INVALID_NEGOTIATION_STATE_TRANSITION",
+            Self::MaxNegotiationRoundsReached => "MAX_NEGOTIATION_ROUNDS_REACHED",
+            Self::NoPriceImprovement => "NO_PRICE_IMPROVEMENT",
+            Self::LastLookRejected => "LAST_LOOK_REJECTED",
+            Self::LastLookTimeout => "LAST_LOOK_TIMEOUT",
+            Self::AcceptanceTimeout => "ACCEPTANCE_TIMEOUT",
+            Self::FirmUpPriceMoved => "FIRM_UP_PRICE_MOVED",
+            Self::QuorumNotMet => "QUORUM_NOT_MET",
+            Self::CollateralLockFailed => "COLLATERAL_LOCK_FAILED",
+            Self::InsufficientCollateral => "INSUFFICIENT_COLLATERAL",
+            Self::CollateralUnavailable => "COLLATERAL_UNAVAILABLE",
+            Self::SettlementFailed => "SETTLEMENT_FAILED",
+            Self::PositionUpdateFailed => "POSITION_UPDATE_FAILED",
+            Self::PriceBoundsVerificationFailed => "PRICE_BOUNDS_VERIFICATION_FAILED",
+            Self::InvalidPackageQuote => "INVALID_PACKAGE_QUOTE",
+            Self::InconsistentLegPrices => "INCONSISTENT_LEG_PRICES",
+            Self::MultiLegExecutionFailed => "MULTI_LEG_EXECUTION_FAILED",
+            Self::RollbackFailed => "ROLLBACK_FAILED",
+            Self::LegExecutionTimeout => "LEG_EXECUTION_TIMEOUT",
+            Self::CapacityExceeded => "CAPACITY_EXCEEDED",
+            Self::ReservationNotFound => "RESERVATION_NOT_FOUND",
+            Self::CapacityRepositoryError => "CAPACITY_REPOSITORY_ERROR",
+            Self::CapacityOverflow => "CAPACITY_OVERFLOW",
+            Self::CapacityUnderflow => "CAPACITY_UNDERFLOW",
+            Self::FeeCalculationFailed => "FEE_CALCULATION_FAILED",
+            Self::ConfirmationFailed => "CONFIRMATION_FAILED",
+            Self::InvalidNotificationPreferences => "INVALID_NOTIFICATION_PREFERENCES",
+            Self::SchemaNotFound => "SCHEMA_NOT_FOUND",
+            Self::SchemaAlreadyRegistered => "SCHEMA_ALREADY_REGISTERED",
+            Self::SchemaGenerationFailed => "SCHEMA_GENERATION_FAILED",
+            Self::Unknown(reason) => reason,
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Errors returned by the gRPC client.
+#[derive(Debug, Error)]
+pub enum ClientError {
+    /// The server rejected the call with a domain error.
+    #[error("{code}: {message}")]
+    Domain {
+        /// The domain error raised.
+        code: ErrorCode,
+        /// The gRPC status code.
+        status: Code,
+        /// The status message.
+        message: String,
+        /// Structured fields of the error.
+        metadata: HashMap<String, String>,
+    },
+
+    /// The call failed with a status carrying no domain error.
+    #[error("{}: {}", .0.code(), .0.message())]
+    Status(Status),
+
+    /// The call did not complete within its deadline.
+    #[error("deadline of {0:?} exceeded")]
+    DeadlineExceeded(Duration),
+
+    /// The channel could not be set up.
+    #[error("transport error: {0}")]
+    Transport(#[from] tonic::transport::Error),
+
+    /// The request could not be built.
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+
+    /// The server returned a response the client cannot read.
+    #[error("invalid response: {0}")]
+    InvalidResponse(String),
+}
+
+impl ClientError {
+    /// Creates an invalid request error.
+    #[must_use]
+    pub fn invalid_request(message: impl Into<String>) -> Self {
+        Self::InvalidRequest(message.into())
+    }
+
+    /// Creates an invalid response error.
+    #[must_use]
+    pub fn invalid_response(message: impl Into<String>) -> Self {
+        Self::InvalidResponse(message.into())
+    }
+
+    /// Returns the gRPC status code of the error.
+    ///
+    /// Errors raised by the client itself map to the code the server would
+    /// have used: `DeadlineExceeded`, `Unavailable` for transport errors,
+    /// `InvalidArgument` and `Internal`.
+    #[must_use]
+    pub fn code(&self) -> Code {
+        match self {
+            Self::Domain { status, .. } => *status,
+            Self::Status(status) => status.code(),
+            Self::DeadlineExceeded(_) => Code::DeadlineExceeded,
+            Self::Transport(_) => Code::Unavailable,
+            Self::InvalidRequest(_) => Code::InvalidArgument,
+            Self::InvalidResponse(_) => Code::Internal,
+        }
+    }
+
+    /// Returns the domain error code, if the server raised a domain error.
+    #[must_use]
+    pub fn error_code(&self) -> Option<&ErrorCode> {
+        match self {
+            Self::Domain { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+}
+
+impl From<Status> for ClientError {
+    fn from(status: Status) -> Self {
+        match error_info(&status) {
+            Some(info) if info.domain == ERROR_DOMAIN => Self::Domain {
+                code: ErrorCode::from_reason(&info.reason),
+                status: status.code(),
+                message: status.message().to_string(),
+                metadata: info.metadata,
+            },
+            _ => Self::Status(status),
+        }
+    }
+}
+
+impl Retryable for ClientError {
+    /// Only `UNAVAILABLE` is transient: the server was not reached or shed
+    /// the call before acting on it.
+    fn is_retryable(&self) -> bool {
+        self.code() == Code::Unavailable
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::api::grpc::error_mapping::domain_status;
+    use crate::domain::errors::DomainError;
+    use crate::domain::value_objects::RfqState;
+
+    #[test]
+    fn decodes_domain_errors() {
+        let status = domain_status(&DomainError::InvalidStateTransition {
+            from: RfqState::Cancelled,
+            to: RfqState::Executing,
+        });
+
+        let error = ClientError::from(status);
+
+        assert_eq!(error.error_code(), Some(&ErrorCode::InvalidStateTransition));
+        assert_eq!(error.code(), Code::FailedPrecondition);
+        assert!(matches!(
+            &error,
+            ClientError::Domain { metadata, .. }
+                if metadata.get("from").map(String::as_str) == Some("CANCELLED")
+        ));
+    }
+
+    #[test]
+    fn keeps_plain_statuses() {
+        let error = ClientError::from(Status::not_found("RFQ not found"));
+
+        assert!(matches!(error, ClientError::Status(_)));
+        assert_eq!(error.code(), Code::NotFound);
+        assert!(error.error_code().is_none());
+    }
+
+    #[test]
+    fn codes_round_trip_reasons() {
+        assert_eq!(
+            ErrorCode::from_reason("QUOTE_EXPIRED"),
+            ErrorCode::QuoteExpired
+        );
+        assert_eq!(ErrorCode::QuoteExpired.as_str(), "QUOTE_EXPIRED");
+        let unknown = ErrorCode::from_reason("SOMETHING_NEW");
+        assert_eq!(unknown, ErrorCode::Unknown("SOMETHING_NEW".to_string()));
+        assert_eq!(unknown.to_string(), "SOMETHING_NEW");
+    }
+
+    #[test]
+    fn only_unavailable_is_retryable() {
+        assert!(ClientError::from(Status::unavailable("down")).is_retryable());
+        assert!(!ClientError::from(Status::internal("boom")).is_retryable());
+        assert!(!ClientError::DeadlineExceeded(Duration::from_secs(1)).is_retryable());
+    }
+}
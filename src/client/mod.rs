@@ -0,0 +1,39 @@
+//! # gRPC Client
+//!
+//! Typed client SDK for the RFQ gRPC API, for internal services.
+//!
+//! Enabled by the `grpc-client` feature.
+//!
+//! # Modules
+//!
+//! - [`config`]: Endpoint, per-call deadlines and retry policy
+//! - [`error`]: [`ClientError`], decoding the service's error details
+//! - [`rfq_client`]: [`OtcRfqClient`], typed wrappers around the generated stub
+//! - [`watch`]: [`RfqWatch`], a reconnecting stream of RFQ state changes
+//!
+//! # Usage
+//!
+//! ```ignore
+//! use otc_rfq::client::{Call, ClientConfig, OtcRfqClient};
+//! use futures::StreamExt;
+//!
+//! let config = ClientConfig::new("http://localhost:50051")
+//!     .with_deadline(Call::SelectQuote, Duration::from_secs(10));
+//! let client = OtcRfqClient::connect(config).await?;
+//!
+//! let mut updates = client.watch_rfq(rfq_id);
+//! while let Some(update) = updates.next().await {
+//!     let update = update?;
+//!     println!("{:?} -> {}", update.previous_state, update.current_state);
+//! }
+//! ```
+
+pub mod config;
+pub mod error;
+pub mod rfq_client;
+pub mod watch;
+
+pub use config::{Call, ClientConfig, MmCredentials};
+pub use error::{ClientError, ClientResult, ErrorCode};
+pub use rfq_client::OtcRfqClient;
+pub use watch::{RfqStatusUpdate, RfqWatch};
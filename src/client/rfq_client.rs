@@ -0,0 +1,268 @@
+//! # RFQ Client
+//!
+//! Typed client for the `otc.rfq.v2.RfqService` gRPC API.
+//!
+//! [`OtcRfqClient`] wraps the generated stub: it takes domain value types,
+//! applies the configured deadline to every call, retries idempotent calls
+//! on `UNAVAILABLE` with the configured [`RetryPolicy`] backoff, and decodes
+//! error statuses into [`ClientError`]. Responses are the v2 messages.
+//!
+//! Calls that create or execute something (`create_rfq`, `select_quote`,
+//! `submit_quote`) are sent once: a lost response does not mean the server
+//! did not act on them, and retrying could create a second RFQ or quote.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use otc_rfq::client::{ClientConfig, OtcRfqClient};
+//!
+//! let client = OtcRfqClient::connect(ClientConfig::new("http://localhost:50051")).await?;
+//! let rfq = client
+//!     .create_rfq(&client_id, &instrument, RfqDirection::Buy, quantity, Duration::from_secs(30))
+//!     .await?;
+//! let rfq = client.get_rfq(rfq_id).await?;
+//! let trade = client.select_quote(rfq_id, quote_id).await?;
+//! ```
+
+use crate::api::grpc::proto::otc_rfq_v2 as v2;
+use crate::api::grpc::proto::otc_rfq_v2::rfq_service_client::RfqServiceClient;
+use crate::application::services::{RetryError, RetryPolicy, execute_with_retry};
+use crate::client::config::{Call, ClientConfig};
+use crate::client::error::{ClientError, ClientResult};
+use crate::client::watch::{self, RfqWatch};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    CounterpartyId, Instrument, OrderSide, Price, Quantity, QuoteId, RfqDirection, RfqId, RfqState,
+};
+use std::future::Future;
+use std::time::Duration;
+use tonic::transport::{Channel, Endpoint};
+use tonic::{Request, Response, Status, Streaming};
+
+/// Typed client for the RFQ gRPC API.
+///
+/// Cloning is cheap: clones share the underlying channel.
+#[derive(Debug, Clone)]
+pub struct OtcRfqClient {
+    inner: RfqServiceClient<Channel>,
+    config: ClientConfig,
+}
+
+impl OtcRfqClient {
+    /// Connects to the server configured in `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::InvalidRequest` if the endpoint is not a valid
+    /// URI, and `ClientError::Transport` if the server cannot be reached.
+    pub async fn connect(config: ClientConfig) -> ClientResult<Self> {
+        let channel = Endpoint::from_shared(config.endpoint().to_string())
+            .map_err(|e| ClientError::invalid_request(format!("invalid endpoint: {e}")))?
+            .connect_timeout(config.connect_timeout())
+            .connect()
+            .await?;
+        Ok(Self::from_channel(channel, config))
+    }
+
+    /// Creates a client over an existing channel, e.g. one set up with TLS.
+    ///
+    /// The endpoint and connect timeout of `config` are not used.
+    #[must_use]
+    pub fn from_channel(channel: Channel, config: ClientConfig) -> Self {
+        Self {
+            inner: RfqServiceClient::new(channel),
+            config,
+        }
+    }
+
+    /// Returns the client configuration.
+    #[must_use]
+    pub fn config(&self) -> &ClientConfig {
+        &self.config
+    }
+
+    /// Creates an RFQ valid for `timeout`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server rejects the RFQ or cannot be reached.
+    pub async fn create_rfq(
+        &self,
+        client_id: &CounterpartyId,
+        instrument: &Instrument,
+        direction: RfqDirection,
+        quantity: Quantity,
+        timeout: Duration,
+    ) -> ClientResult<v2::Rfq> {
+        let timeout_seconds = i64::try_from(timeout.as_secs())
+            .map_err(|_| ClientError::invalid_request("timeout is too long"))?;
+        let request = v2::CreateRfqRequest {
+            client_id: client_id.to_string(),
+            instrument: Some(v2::Instrument::from(instrument)),
+            direction: v2::RfqDirection::from(direction) as i32,
+            quantity: Some(quantity.into()),
+            timeout_seconds,
+            ..Default::default()
+        };
+        let response = self
+            .unary(Call::CreateRfq, request, |mut client, request| async move {
+                client.create_rfq(request).await
+            })
+            .await?;
+        response
+            .rfq
+            .ok_or_else(|| ClientError::invalid_response("CreateRfqResponse has no rfq"))
+    }
+
+    /// Gets an RFQ. Retried on `UNAVAILABLE`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the RFQ does not exist or the server cannot be
+    /// reached within the retry policy.
+    pub async fn get_rfq(&self, rfq_id: RfqId) -> ClientResult<v2::Rfq> {
+        let request = v2::GetRfqRequest {
+            rfq_id: Some(rfq_id.into()),
+        };
+        let response = self
+            .unary(Call::GetRfq, request, |mut client, request| async move {
+                client.get_rfq(request).await
+            })
+            .await?;
+        response
+            .rfq
+            .ok_or_else(|| ClientError::invalid_response("GetRfqResponse has no rfq"))
+    }
+
+    /// Selects a quote of an RFQ and executes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the quote cannot be executed, e.g.
+    /// `ErrorCode::QuoteExpired`, or the server cannot be reached.
+    pub async fn select_quote(&self, rfq_id: RfqId, quote_id: QuoteId) -> ClientResult<v2::Trade> {
+        let request = v2::ExecuteTradeRequest {
+            rfq_id: Some(rfq_id.into()),
+            quote_id: Some(quote_id.into()),
+        };
+        let response = self
+            .unary(
+                Call::SelectQuote,
+                request,
+                |mut client, request| async move { client.execute_trade(request).await },
+            )
+            .await?;
+        response
+            .trade
+            .ok_or_else(|| ClientError::invalid_response("ExecuteTradeResponse has no trade"))
+    }
+
+    /// Submits a quote for an RFQ with the configured market maker
+    /// credentials.
+    ///
+    /// `side` is the side the client trades at `price`; required on two-way
+    /// RFQs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::InvalidRequest` if no credentials are
+    /// configured, and an error if the server rejects the quote or cannot be
+    /// reached.
+    pub async fn submit_quote(
+        &self,
+        rfq_id: RfqId,
+        price: Price,
+        quantity: Quantity,
+        valid_until: Timestamp,
+        side: Option<OrderSide>,
+    ) -> ClientResult<v2::Quote> {
+        let credentials = self.config.mm_credentials().ok_or_else(|| {
+            ClientError::invalid_request("market maker credentials are not configured")
+        })?;
+        let request = v2::SubmitQuoteRequest {
+            rfq_id: Some(rfq_id.into()),
+            price: Some(price.into()),
+            quantity: Some(quantity.into()),
+            valid_until: Some(valid_until.into()),
+            credentials: Some(v2::MmCredentials {
+                mm_id: credentials.mm_id.clone(),
+                api_key: credentials.api_key.clone(),
+            }),
+            side: side.map_or(v2::OrderSide::Unspecified, v2::OrderSide::from) as i32,
+        };
+        let response = self
+            .unary(
+                Call::SubmitQuote,
+                request,
+                |mut client, request| async move { client.submit_quote(request).await },
+            )
+            .await?;
+        response
+            .quote
+            .ok_or_else(|| ClientError::invalid_response("SubmitQuoteResponse has no quote"))
+    }
+
+    /// Watches the state of an RFQ.
+    ///
+    /// The stream yields each state change, starting with the current
+    /// state, and ends after a terminal state. It reconnects when the
+    /// server's stream fails or ends, resuming from the last state it
+    /// yielded so no change is repeated or lost; see [`RfqWatch`].
+    #[must_use]
+    pub fn watch_rfq(&self, rfq_id: RfqId) -> RfqWatch {
+        watch::watch(self.clone(), rfq_id)
+    }
+
+    /// Opens a status stream, resuming from `resume_from` if set.
+    pub(crate) async fn open_watch(
+        &self,
+        rfq_id: RfqId,
+        resume_from: Option<RfqState>,
+    ) -> ClientResult<Streaming<v2::StreamRfqStatusResponse>> {
+        let deadline = self.config.deadline(Call::WatchRfq);
+        let request = v2::StreamRfqStatusRequest {
+            rfq_id: Some(rfq_id.into()),
+            resume_from: resume_from.map_or(v2::RfqState::Unspecified, v2::RfqState::from) as i32,
+        };
+        let mut client = self.inner.clone();
+        match tokio::time::timeout(deadline, client.stream_rfq_status(request)).await {
+            Ok(response) => Ok(response?.into_inner()),
+            Err(_) => Err(ClientError::DeadlineExceeded(deadline)),
+        }
+    }
+
+    /// Sends a unary call with its deadline, retrying idempotent calls.
+    async fn unary<Req, Resp, F, Fut>(
+        &self,
+        call: Call,
+        request: Req,
+        send: F,
+    ) -> ClientResult<Resp>
+    where
+        Req: Clone,
+        F: Fn(RfqServiceClient<Channel>, Request<Req>) -> Fut,
+        Fut: Future<Output = Result<Response<Resp>, Status>>,
+    {
+        let deadline = self.config.deadline(call);
+        let attempt = || {
+            let mut request = Request::new(request.clone());
+            request.set_timeout(deadline);
+            let response = send(self.inner.clone(), request);
+            async move {
+                match tokio::time::timeout(deadline, response).await {
+                    Ok(response) => Ok(response?.into_inner()),
+                    Err(_) => Err(ClientError::DeadlineExceeded(deadline)),
+                }
+            }
+        };
+
+        let policy = if call.is_idempotent() {
+            self.config.retry_policy().clone()
+        } else {
+            RetryPolicy::no_retry()
+        };
+        execute_with_retry(&policy, attempt)
+            .await
+            .map_err(RetryError::into_inner)
+    }
+}
@@ -0,0 +1,175 @@
+//! # RFQ Watch
+//!
+//! Reconnecting stream of RFQ state changes.
+//!
+//! [`RfqWatch`] yields an [`RfqStatusUpdate`] for each state change of an
+//! RFQ and ends after a terminal state. It reopens the server's stream
+//! whenever it breaks:
+//!
+//! - A failed stream is reopened after the retry policy's backoff while the
+//!   failure is `UNAVAILABLE` and retries remain. Other failures, or running
+//!   out of retries, end the watch with the error.
+//! - A stream the server ended on a non-terminal state is reopened after the
+//!   poll interval.
+//!
+//! Each reopened stream resumes from the last state yielded: the server
+//! sends the transition from that state, or nothing if the state has not
+//! changed, so a reconnection neither repeats nor loses a change. Updates
+//! for the state last yielded are skipped, for servers that always send the
+//! current state.
+
+use crate::api::grpc::conversions_v2::proto_rfq_state_to_domain;
+use crate::api::grpc::proto::otc_rfq_v2 as v2;
+use crate::application::services::Retryable;
+use crate::client::error::{ClientError, ClientResult};
+use crate::client::rfq_client::OtcRfqClient;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{RfqId, RfqState};
+use futures::Stream;
+use std::pin::Pin;
+use tonic::Streaming;
+
+/// Stream of state changes of an RFQ.
+pub type RfqWatch = Pin<Box<dyn Stream<Item = ClientResult<RfqStatusUpdate>> + Send>>;
+
+/// A state change of an RFQ.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RfqStatusUpdate {
+    /// The RFQ.
+    pub rfq_id: RfqId,
+    /// The state before the change; `None` for the first update of a watch.
+    pub previous_state: Option<RfqState>,
+    /// The state after the change.
+    pub current_state: RfqState,
+    /// Description of the change.
+    pub message: String,
+    /// When the server sent the update.
+    pub timestamp: Option<Timestamp>,
+}
+
+impl TryFrom<v2::StreamRfqStatusResponse> for RfqStatusUpdate {
+    type Error = ClientError;
+
+    fn try_from(response: v2::StreamRfqStatusResponse) -> ClientResult<Self> {
+        let rfq_id = response
+            .rfq_id
+            .ok_or_else(|| ClientError::invalid_response("status update has no rfq_id"))?
+            .try_into()
+            .map_err(|e| ClientError::invalid_response(format!("invalid rfq_id: {e}")))?;
+        let previous_state = match response.previous_state {
+            unspecified if unspecified == v2::RfqState::Unspecified as i32 => None,
+            value => Some(
+                proto_rfq_state_to_domain(value)
+                    .map_err(|e| ClientError::invalid_response(e.to_string()))?,
+            ),
+        };
+        let current_state = proto_rfq_state_to_domain(response.current_state)
+            .map_err(|e| ClientError::invalid_response(e.to_string()))?;
+        Ok(Self {
+            rfq_id,
+            previous_state,
+            current_state,
+            message: response.message,
+            timestamp: response.timestamp.map(Into::into),
+        })
+    }
+}
+
+/// State of a watch between items.
+struct WatchState {
+    client: OtcRfqClient,
+    rfq_id: RfqId,
+    stream: Option<Streaming<v2::StreamRfqStatusResponse>>,
+    last_state: Option<RfqState>,
+    failures: u32,
+    finished: bool,
+}
+
+impl WatchState {
+    /// Returns the next update, reconnecting as needed, or `None` once the
+    /// watch has finished.
+    async fn next(&mut self) -> Option<ClientResult<RfqStatusUpdate>> {
+        while !self.finished {
+            let Some(stream) = self.stream.as_mut() else {
+                match self.client.open_watch(self.rfq_id, self.last_state).await {
+                    Ok(stream) => self.stream = Some(stream),
+                    Err(e) => {
+                        if let Err(e) = self.back_off(e).await {
+                            return Some(Err(e));
+                        }
+                    }
+                }
+                continue;
+            };
+
+            match stream.message().await {
+                Ok(Some(response)) => {
+                    self.failures = 0;
+                    let mut update = match RfqStatusUpdate::try_from(response) {
+                        Ok(update) => update,
+                        Err(e) => {
+                            self.finished = true;
+                            return Some(Err(e));
+                        }
+                    };
+                    if self.last_state == Some(update.current_state) {
+                        continue;
+                    }
+                    if update.previous_state.is_none() {
+                        update.previous_state = self.last_state;
+                    }
+                    self.last_state = Some(update.current_state);
+                    self.finished = update.current_state.is_terminal();
+                    return Some(Ok(update));
+                }
+                Ok(None) => {
+                    self.stream = None;
+                    tokio::time::sleep(self.client.config().watch_poll_interval()).await;
+                }
+                Err(status) => {
+                    self.stream = None;
+                    if let Err(e) = self.back_off(status.into()).await {
+                        return Some(Err(e));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Waits before reconnecting after `error`, or finishes the watch with
+    /// it if it is not retryable or no retries remain.
+    async fn back_off(&mut self, error: ClientError) -> ClientResult<()> {
+        let policy = self.client.config().retry_policy();
+        if !error.is_retryable() || !policy.should_retry(self.failures) {
+            self.finished = true;
+            return Err(error);
+        }
+        let delay = policy.calculate_delay_with_jitter(self.failures);
+        self.failures = self.failures.saturating_add(1);
+        tracing::debug!(
+            rfq_id = %self.rfq_id,
+            attempt = self.failures,
+            delay_ms = delay.as_millis() as u64,
+            error = %error,
+            "RFQ watch disconnected; reconnecting"
+        );
+        tokio::time::sleep(delay).await;
+        Ok(())
+    }
+}
+
+/// Starts watching `rfq_id`.
+pub(crate) fn watch(client: OtcRfqClient, rfq_id: RfqId) -> RfqWatch {
+    let state = WatchState {
+        client,
+        rfq_id,
+        stream: None,
+        last_state: None,
+        failures: 0,
+        finished: false,
+    };
+    Box::pin(futures::stream::unfold(state, |mut state| async move {
+        state.next().await.map(|item| (item, state))
+    }))
+}
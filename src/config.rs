@@ -19,10 +19,24 @@
 //! |----------|-------------|---------|
 //! | `OTC_RFQ_GRPC_HOST` | gRPC server host | `0.0.0.0` |
 //! | `OTC_RFQ_GRPC_PORT` | gRPC server port | `50051` |
+//! | `OTC_RFQ_GRPC_ENABLE_REFLECTION` | Serve gRPC server reflection | `true` |
+//! | `OTC_RFQ_GRPC_ENABLE_HEALTH` | Serve the gRPC health protocol | `true` |
 //! | `OTC_RFQ_REST_HOST` | REST server host | `0.0.0.0` |
 //! | `OTC_RFQ_REST_PORT` | REST server port | `8080` |
+//! | `OTC_RFQ_REST_ENABLE_SWAGGER_UI` | Serve Swagger UI at `/api/v1/docs` | `false` |
+//! | `OTC_RFQ_REST_ENABLE_METRICS` | Serve Prometheus metrics at `/metrics` | `false` |
 //! | `OTC_RFQ_LOG_LEVEL` | Log level | `info` |
 //! | `OTC_RFQ_LOG_FORMAT` | Log format (json/pretty) | `json` |
+//! | `OTC_RFQ_LOG_OTLP_ENDPOINT` | OTLP collector for trace export (`otlp` feature) | unset |
+//! | `OTC_RFQ_VENUES_ALLOW_SIMULATED` | Route to simulated venues (not in production) | `false` |
+//! | `OTC_RFQ_VENUES_MIN_HEALTHY` | Healthy venues needed for venue failures to only warn on `/readyz` | `1` |
+//! | `OTC_RFQ_VENUES_LOG_RAW_EXCHANGES` | Record raw venue payloads for dispute resolution | `false` |
+//! | `OTC_RFQ_VENUES_RAW_EXCHANGE_RETENTION_DAYS` | Days recorded venue payloads are kept | `90` |
+//! | `OTC_RFQ_VENUES_CIRCUIT_STATE_PATH` | JSON file keeping venue circuit breaker state across restarts | unset |
+//! | `OTC_RFQ_SHUTDOWN_GRACE_PERIOD_SECS` | Grace period for in-flight aggregations on shutdown | `20` |
+//! | `OTC_RFQ_EXECUTION_TIMEOUT_SECS` | Longest an execution may run, capped by the quote's expiry | `30` |
+//! | `OTC_RFQ_EXECUTION_ABANDONED_GRACE_SECS` | Time past its deadline before the sweeper fails an execution | `30` |
+//! | `OTC_RFQ_COLLATERAL_CHECK_MODE` | Derivatives margin check mode (enforce/monitor) | `enforce` |
 //!
 //! # Examples
 //!
@@ -33,6 +47,9 @@
 //! println!("gRPC server: {}:{}", config.grpc.host, config.grpc.port);
 //! ```
 
+use otc_rfq::application::services::{CollateralCheckMode, GasUnitsTable};
+use otc_rfq::domain::value_objects::QuorumRules;
+use otc_rfq::infrastructure::blockchain::TokenRegistry;
 use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
 use std::path::Path;
@@ -93,6 +110,14 @@ pub struct GrpcConfig {
     /// Enable reflection service.
     #[serde(default = "default_true")]
     pub enable_reflection: bool,
+
+    /// Enable the gRPC health service.
+    #[serde(default = "default_true")]
+    pub enable_health: bool,
+
+    /// Seconds between gRPC health refreshes.
+    #[serde(default = "default_health_check_interval")]
+    pub health_check_interval_secs: u64,
 }
 
 impl Default for GrpcConfig {
@@ -103,11 +128,19 @@ impl Default for GrpcConfig {
             max_connections: default_max_connections(),
             request_timeout_secs: default_request_timeout(),
             enable_reflection: true,
+            enable_health: true,
+            health_check_interval_secs: default_health_check_interval(),
         }
     }
 }
 
 impl GrpcConfig {
+    /// Returns the interval between gRPC health refreshes.
+    #[must_use]
+    pub fn health_check_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.health_check_interval_secs.max(1))
+    }
+
     /// Returns the socket address for the gRPC server.
     ///
     /// # Errors
@@ -149,6 +182,14 @@ pub struct RestConfig {
     /// Allowed CORS origins (empty = allow all).
     #[serde(default)]
     pub cors_origins: Vec<String>,
+
+    /// Serve the Swagger UI at `/api/v1/docs`.
+    #[serde(default)]
+    pub enable_swagger_ui: bool,
+
+    /// Serve Prometheus metrics at `/metrics`.
+    #[serde(default)]
+    pub enable_metrics: bool,
 }
 
 impl Default for RestConfig {
@@ -160,6 +201,8 @@ impl Default for RestConfig {
             request_timeout_secs: default_request_timeout(),
             enable_cors: true,
             cors_origins: Vec::new(),
+            enable_swagger_ui: false,
+            enable_metrics: false,
         }
     }
 }
@@ -217,6 +260,11 @@ pub struct LogConfig {
     /// Include span information in logs.
     #[serde(default = "default_true")]
     pub include_spans: bool,
+
+    /// OTLP collector gRPC endpoint for trace export, e.g.
+    /// `http://localhost:4317`. Requires the `otlp` feature.
+    #[serde(default)]
+    pub otlp_endpoint: Option<String>,
 }
 
 impl Default for LogConfig {
@@ -227,6 +275,7 @@ impl Default for LogConfig {
             include_timestamps: true,
             include_target: true,
             include_spans: true,
+            otlp_endpoint: None,
         }
     }
 }
@@ -257,6 +306,11 @@ pub struct DatabaseConfig {
     /// Idle connection timeout in seconds.
     #[serde(default = "default_idle_timeout")]
     pub idle_timeout_secs: u64,
+
+    /// Refuse to start, and report not ready, unless every embedded
+    /// migration has been applied.
+    #[serde(default)]
+    pub verify_schema: bool,
 }
 
 impl Default for DatabaseConfig {
@@ -267,6 +321,7 @@ impl Default for DatabaseConfig {
             min_connections: default_min_connections(),
             connect_timeout_secs: default_connection_timeout(),
             idle_timeout_secs: default_idle_timeout(),
+            verify_schema: false,
         }
     }
 }
@@ -301,6 +356,43 @@ pub struct VenueConfig {
     /// Maximum concurrent venue requests.
     #[serde(default = "default_max_concurrent_requests")]
     pub max_concurrent_requests: usize,
+
+    /// Route RFQs to simulated venues. Rejected in production.
+    #[serde(default)]
+    pub allow_simulated: bool,
+
+    /// Healthy venues needed for venue failures to only warn on readiness.
+    #[serde(default = "default_min_healthy_venues")]
+    pub min_healthy_venues: usize,
+
+    /// Record raw venue requests and responses for dispute resolution.
+    #[serde(default)]
+    pub log_raw_exchanges: bool,
+
+    /// Days recorded venue payloads are kept before they are purged.
+    #[serde(default = "default_raw_exchange_retention_days")]
+    pub raw_exchange_retention_days: u32,
+
+    /// Payload fields redacted in addition to the default secret fields.
+    #[serde(default)]
+    pub raw_exchange_redacted_fields: Vec<String>,
+
+    /// JSON file keeping venue circuit breaker state and manual overrides
+    /// across restarts. Unset keeps state in memory only.
+    #[serde(default)]
+    pub circuit_state_path: Option<String>,
+
+    /// Seconds between background venue health probes.
+    #[serde(default = "default_probe_interval")]
+    pub probe_interval_secs: u64,
+
+    /// Consecutive failed probes before a venue is marked unhealthy.
+    #[serde(default = "default_probe_failure_threshold")]
+    pub probe_failure_threshold: u32,
+
+    /// Consecutive successful probes before an unhealthy venue recovers.
+    #[serde(default = "default_probe_recovery_threshold")]
+    pub probe_recovery_threshold: u32,
 }
 
 impl Default for VenueConfig {
@@ -312,6 +404,92 @@ impl Default for VenueConfig {
             enable_hashflow: false,
             quote_timeout_ms: default_quote_timeout(),
             max_concurrent_requests: default_max_concurrent_requests(),
+            allow_simulated: false,
+            min_healthy_venues: default_min_healthy_venues(),
+            log_raw_exchanges: false,
+            raw_exchange_retention_days: default_raw_exchange_retention_days(),
+            raw_exchange_redacted_fields: Vec::new(),
+            circuit_state_path: None,
+            probe_interval_secs: default_probe_interval(),
+            probe_failure_threshold: default_probe_failure_threshold(),
+            probe_recovery_threshold: default_probe_recovery_threshold(),
+        }
+    }
+}
+
+impl VenueConfig {
+    /// Returns the interval between background venue health probes.
+    #[must_use]
+    pub fn probe_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.probe_interval_secs.max(1))
+    }
+}
+
+// ============================================================================
+// Shutdown Configuration
+// ============================================================================
+
+/// Graceful shutdown configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShutdownConfig {
+    /// Seconds in-flight aggregations get to complete before cancellation.
+    #[serde(default = "default_shutdown_grace_period")]
+    pub grace_period_secs: u64,
+}
+
+impl ShutdownConfig {
+    /// Returns the grace period as a duration.
+    #[must_use]
+    pub fn grace_period(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.grace_period_secs)
+    }
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> Self {
+        Self {
+            grace_period_secs: default_shutdown_grace_period(),
+        }
+    }
+}
+
+// ============================================================================
+// Execution Configuration
+// ============================================================================
+
+/// Trade execution configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionConfig {
+    /// Seconds an execution may run before it is cancelled; the selected
+    /// quote's expiry applies if earlier.
+    #[serde(default = "default_execution_timeout")]
+    pub timeout_secs: u64,
+
+    /// Seconds past its deadline an execution is left to the instance
+    /// running it before the expiry sweeper fails it.
+    #[serde(default = "default_execution_abandoned_grace")]
+    pub abandoned_grace_secs: u64,
+}
+
+impl ExecutionConfig {
+    /// Returns the execution timeout as a duration.
+    #[must_use]
+    pub fn timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.timeout_secs)
+    }
+
+    /// Returns the abandoned execution grace period as a duration.
+    #[must_use]
+    pub fn abandoned_grace(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.abandoned_grace_secs)
+    }
+}
+
+impl Default for ExecutionConfig {
+    fn default() -> Self {
+        Self {
+            timeout_secs: default_execution_timeout(),
+            abandoned_grace_secs: default_execution_abandoned_grace(),
         }
     }
 }
@@ -343,6 +521,34 @@ pub struct AppConfig {
     #[serde(default)]
     pub venues: VenueConfig,
 
+    /// Graceful shutdown configuration.
+    #[serde(default)]
+    pub shutdown: ShutdownConfig,
+
+    /// Trade execution configuration.
+    #[serde(default)]
+    pub execution: ExecutionConfig,
+
+    /// Minimum competing quotes per notional band before a quote can be
+    /// selected, with per-client overrides. Empty enforces no quorum.
+    #[serde(default)]
+    pub quorum: QuorumRules,
+
+    /// Whether derivatives executions are blocked when the margin system is
+    /// unavailable (`enforce`) or only recorded (`monitor`).
+    #[serde(default)]
+    pub collateral_check_mode: CollateralCheckMode,
+
+    /// Gas units to settle a DeFi quote, per execution protocol, for
+    /// all-in cost ranking. A configured table replaces the defaults.
+    #[serde(default)]
+    pub gas_units: GasUnitsTable,
+
+    /// Tokens trades may settle in, keyed by symbol, with decimals and
+    /// per-chain addresses. When unset the common tokens are registered.
+    #[serde(default)]
+    pub tokens: Option<TokenRegistry>,
+
     /// Service name for tracing.
     #[serde(default = "default_service_name")]
     pub service_name: String,
@@ -396,6 +602,16 @@ impl AppConfig {
         {
             self.grpc.port = p;
         }
+        if let Ok(enabled) = std::env::var("OTC_RFQ_GRPC_ENABLE_REFLECTION")
+            && let Ok(e) = enabled.parse()
+        {
+            self.grpc.enable_reflection = e;
+        }
+        if let Ok(enabled) = std::env::var("OTC_RFQ_GRPC_ENABLE_HEALTH")
+            && let Ok(e) = enabled.parse()
+        {
+            self.grpc.enable_health = e;
+        }
 
         // REST configuration
         if let Ok(host) = std::env::var("OTC_RFQ_REST_HOST") {
@@ -406,6 +622,16 @@ impl AppConfig {
         {
             self.rest.port = p;
         }
+        if let Ok(enabled) = std::env::var("OTC_RFQ_REST_ENABLE_SWAGGER_UI")
+            && let Ok(e) = enabled.parse()
+        {
+            self.rest.enable_swagger_ui = e;
+        }
+        if let Ok(enabled) = std::env::var("OTC_RFQ_REST_ENABLE_METRICS")
+            && let Ok(e) = enabled.parse()
+        {
+            self.rest.enable_metrics = e;
+        }
 
         // Logging configuration
         if let Ok(level) = std::env::var("OTC_RFQ_LOG_LEVEL") {
@@ -417,11 +643,75 @@ impl AppConfig {
                 _ => LogFormat::Json,
             };
         }
+        if let Ok(endpoint) = std::env::var("OTC_RFQ_LOG_OTLP_ENDPOINT") {
+            self.log.otlp_endpoint = Some(endpoint);
+        }
 
         // Database configuration
         if let Ok(url) = std::env::var("OTC_RFQ_DATABASE_URL") {
             self.database.url = url;
         }
+        if let Ok(verify) = std::env::var("OTC_RFQ_DATABASE_VERIFY_SCHEMA")
+            && let Ok(v) = verify.parse()
+        {
+            self.database.verify_schema = v;
+        }
+
+        // Venue configuration
+        if let Ok(allow) = std::env::var("OTC_RFQ_VENUES_ALLOW_SIMULATED")
+            && let Ok(a) = allow.parse()
+        {
+            self.venues.allow_simulated = a;
+        }
+        if let Ok(min) = std::env::var("OTC_RFQ_VENUES_MIN_HEALTHY")
+            && let Ok(m) = min.parse()
+        {
+            self.venues.min_healthy_venues = m;
+        }
+        if let Ok(log) = std::env::var("OTC_RFQ_VENUES_LOG_RAW_EXCHANGES")
+            && let Ok(l) = log.parse()
+        {
+            self.venues.log_raw_exchanges = l;
+        }
+        if let Ok(days) = std::env::var("OTC_RFQ_VENUES_RAW_EXCHANGE_RETENTION_DAYS")
+            && let Ok(d) = days.parse()
+        {
+            self.venues.raw_exchange_retention_days = d;
+        }
+        if let Ok(path) = std::env::var("OTC_RFQ_VENUES_CIRCUIT_STATE_PATH") {
+            self.venues.circuit_state_path = Some(path);
+        }
+        if let Ok(secs) = std::env::var("OTC_RFQ_VENUES_PROBE_INTERVAL_SECS")
+            && let Ok(s) = secs.parse()
+        {
+            self.venues.probe_interval_secs = s;
+        }
+
+        // Shutdown configuration
+        if let Ok(secs) = std::env::var("OTC_RFQ_SHUTDOWN_GRACE_PERIOD_SECS")
+            && let Ok(s) = secs.parse()
+        {
+            self.shutdown.grace_period_secs = s;
+        }
+
+        // Execution configuration
+        if let Ok(secs) = std::env::var("OTC_RFQ_EXECUTION_TIMEOUT_SECS")
+            && let Ok(s) = secs.parse()
+        {
+            self.execution.timeout_secs = s;
+        }
+        if let Ok(secs) = std::env::var("OTC_RFQ_EXECUTION_ABANDONED_GRACE_SECS")
+            && let Ok(s) = secs.parse()
+        {
+            self.execution.abandoned_grace_secs = s;
+        }
+
+        // Collateral configuration
+        if let Ok(mode) = std::env::var("OTC_RFQ_COLLATERAL_CHECK_MODE")
+            && let Ok(m) = mode.parse()
+        {
+            self.collateral_check_mode = m;
+        }
 
         // Service configuration
         if let Ok(name) = std::env::var("OTC_RFQ_SERVICE_NAME") {
@@ -456,8 +746,52 @@ impl AppConfig {
             });
         }
 
+        // Simulated venues must never quote real clients
+        if self.venues.allow_simulated && self.is_production() {
+            return Err(ConfigError::InvalidValue {
+                field: "venues.allow_simulated".to_string(),
+                message: "simulated venues cannot be enabled in production".to_string(),
+            });
+        }
+
+        if self.venues.log_raw_exchanges && self.venues.raw_exchange_retention_days == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "venues.raw_exchange_retention_days".to_string(),
+                message: "raw exchange retention must be at least one day".to_string(),
+            });
+        }
+
+        if self.execution.timeout_secs == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "execution.timeout_secs".to_string(),
+                message: "execution timeout must be at least one second".to_string(),
+            });
+        }
+
+        if let Some(tokens) = &self.tokens {
+            tokens.validate().map_err(|e| ConfigError::InvalidValue {
+                field: "tokens".to_string(),
+                message: e.to_string(),
+            })?;
+        }
+
         Ok(())
     }
+
+    /// Returns the configured token registry, or the common tokens if none
+    /// is configured.
+    #[must_use]
+    pub fn token_registry(&self) -> TokenRegistry {
+        self.tokens
+            .clone()
+            .unwrap_or_else(TokenRegistry::with_common_tokens)
+    }
+
+    /// Returns true if running in the production environment.
+    #[must_use]
+    pub fn is_production(&self) -> bool {
+        self.environment.eq_ignore_ascii_case("production")
+    }
 }
 
 // ============================================================================
@@ -484,6 +818,10 @@ fn default_request_timeout() -> u64 {
     30
 }
 
+fn default_health_check_interval() -> u64 {
+    5
+}
+
 fn default_true() -> bool {
     true
 }
@@ -520,6 +858,38 @@ fn default_max_concurrent_requests() -> usize {
     10
 }
 
+fn default_min_healthy_venues() -> usize {
+    1
+}
+
+fn default_raw_exchange_retention_days() -> u32 {
+    90
+}
+
+fn default_probe_interval() -> u64 {
+    30
+}
+
+fn default_probe_failure_threshold() -> u32 {
+    3
+}
+
+fn default_probe_recovery_threshold() -> u32 {
+    2
+}
+
+fn default_shutdown_grace_period() -> u64 {
+    20
+}
+
+fn default_execution_timeout() -> u64 {
+    30
+}
+
+fn default_execution_abandoned_grace() -> u64 {
+    30
+}
+
 fn default_service_name() -> String {
     "otc-rfq".to_string()
 }
@@ -555,6 +925,13 @@ mod tests {
         assert_eq!(addr.port(), 8080);
     }
 
+    #[test]
+    fn rest_config_swagger_ui_disabled_by_default() {
+        let config = RestConfig::default();
+        assert!(!config.enable_swagger_ui);
+        assert!(!config.enable_metrics);
+    }
+
     #[test]
     fn log_format_default() {
         let format = LogFormat::default();
@@ -583,6 +960,33 @@ mod tests {
         assert!(config.socket_addr().is_err());
     }
 
+    #[test]
+    fn raw_exchange_logging_requires_positive_retention() {
+        let mut config = AppConfig::default();
+        assert!(!config.venues.log_raw_exchanges);
+        config.venues.log_raw_exchanges = true;
+        assert!(config.validate().is_ok());
+        config.venues.raw_exchange_retention_days = 0;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn shutdown_config_default() {
+        let config = ShutdownConfig::default();
+        assert_eq!(config.grace_period(), std::time::Duration::from_secs(20));
+    }
+
+    #[test]
+    fn execution_config_default_and_validation() {
+        let config = ExecutionConfig::default();
+        assert_eq!(config.timeout(), std::time::Duration::from_secs(30));
+        assert_eq!(config.abandoned_grace(), std::time::Duration::from_secs(30));
+
+        let mut config = AppConfig::default();
+        config.execution.timeout_secs = 0;
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn database_config_default() {
         let config = DatabaseConfig::default();
@@ -595,5 +999,178 @@ mod tests {
         let config = VenueConfig::default();
         assert!(!config.enable_0x);
         assert_eq!(config.quote_timeout_ms, 5000);
+        assert!(!config.allow_simulated);
+        assert_eq!(config.min_healthy_venues, 1);
+    }
+
+    #[test]
+    fn app_config_validate_rejects_simulated_venues_in_production() {
+        let mut config = AppConfig::default();
+        config.venues.allow_simulated = true;
+        assert!(config.validate().is_ok());
+
+        config.environment = "Production".to_string();
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidValue { field, .. }) if field == "venues.allow_simulated"
+        ));
+    }
+
+    #[test]
+    fn quorum_config_from_toml() {
+        use otc_rfq::domain::value_objects::{
+            CounterpartyId, LiquidityClassification, QuorumRequirement,
+        };
+        use rust_decimal::Decimal;
+
+        let config: AppConfig = toml::from_str(
+            r#"
+            [quorum]
+            default = [
+                { min_notional = "1000000", min_quotes = 3 },
+                { min_notional = "10000000", min_quotes = 5, min_venues = 3 },
+            ]
+
+            [quorum.client_overrides]
+            desk-7 = []
+            "#,
+        )
+        .unwrap();
+
+        let default = config.quorum.policy_for(
+            &CounterpartyId::new("desk-1"),
+            LiquidityClassification::Liquid,
+        );
+        assert_eq!(
+            default.requirement_for(Decimal::from(2_000_000)),
+            Some(QuorumRequirement::new(3, 0))
+        );
+        let desk7 = config.quorum.policy_for(
+            &CounterpartyId::new("desk-7"),
+            LiquidityClassification::Liquid,
+        );
+        assert_eq!(desk7.requirement_for(Decimal::from(2_000_000)), None);
+        assert!(AppConfig::default().quorum.default.bands().is_empty());
+    }
+
+    #[test]
+    fn collateral_check_mode_from_toml() {
+        assert_eq!(
+            AppConfig::default().collateral_check_mode,
+            CollateralCheckMode::Enforce
+        );
+        let config: AppConfig = toml::from_str(r#"collateral_check_mode = "monitor""#).unwrap();
+        assert_eq!(config.collateral_check_mode, CollateralCheckMode::Monitor);
+    }
+
+    #[test]
+    fn gas_units_from_toml() {
+        use otc_rfq::domain::value_objects::ExecutionProtocol;
+
+        assert_eq!(
+            AppConfig::default()
+                .gas_units
+                .units(ExecutionProtocol::HashflowSigned),
+            Some(150_000)
+        );
+        let config: AppConfig = toml::from_str(
+            r#"
+            [gas_units]
+            HASHFLOW_SIGNED = 180000
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.gas_units.units(ExecutionProtocol::HashflowSigned),
+            Some(180_000)
+        );
+        assert_eq!(config.gas_units.units(ExecutionProtocol::BebopOrder), None);
+    }
+
+    #[test]
+    fn tokens_from_toml() {
+        assert!(AppConfig::default().token_registry().get("USDC").is_some());
+
+        let config: AppConfig = toml::from_str(
+            r#"
+            [tokens.USDC]
+            symbol = "USDC"
+            name = "USD Coin"
+            decimals = 6
+            addresses = { polygon = "0x3c499c542cEF5E3811e1192ce70d8cC03d5c3359" }
+
+            [tokens.EURC]
+            symbol = "EURC"
+            name = "Euro Coin"
+            decimals = 6
+            enabled_for_settlement = false
+            addresses = { base = "0x60a3E35Cc302bFA44Cb288Bc5a4F316Fdb1adb42" }
+            "#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+        let registry = config.token_registry();
+        assert!(registry.get("WETH").is_none());
+        assert_eq!(registry.get("USDC").unwrap().decimals, 6);
+        assert!(!registry.get("EURC").unwrap().enabled_for_settlement);
+
+        let duplicate: AppConfig = toml::from_str(
+            r#"
+            [tokens.USDC]
+            symbol = "USDC"
+            name = "USD Coin"
+            decimals = 6
+            addresses = { polygon = "0x3c499c542cEF5E3811e1192ce70d8cC03d5c3359" }
+
+            [tokens.USDCE]
+            symbol = "USDCE"
+            name = "Bridged USD Coin"
+            decimals = 6
+            addresses = { polygon = "0x3C499C542CEF5E3811E1192CE70D8CC03D5C3359" }
+            "#,
+        )
+        .unwrap();
+        assert!(duplicate.validate().is_err());
+    }
+
+    #[test]
+    fn grpc_health_and_reflection_from_toml() {
+        let config = GrpcConfig::default();
+        assert!(config.enable_reflection);
+        assert!(config.enable_health);
+        assert_eq!(
+            config.health_check_interval(),
+            std::time::Duration::from_secs(5)
+        );
+
+        let config: AppConfig = toml::from_str(
+            "[grpc]\nenable_reflection = false\nenable_health = false\nhealth_check_interval_secs = 0",
+        )
+        .unwrap();
+        assert!(!config.grpc.enable_reflection);
+        assert!(!config.grpc.enable_health);
+        assert_eq!(
+            config.grpc.health_check_interval(),
+            std::time::Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn venue_probe_settings_from_toml() {
+        let config = VenueConfig::default();
+        assert_eq!(config.probe_interval(), std::time::Duration::from_secs(30));
+        assert_eq!(config.probe_failure_threshold, 3);
+        assert_eq!(config.probe_recovery_threshold, 2);
+
+        let config: AppConfig = toml::from_str(
+            "[venues]\nprobe_interval_secs = 10\nprobe_failure_threshold = 5\nprobe_recovery_threshold = 4",
+        )
+        .unwrap();
+        assert_eq!(
+            config.venues.probe_interval(),
+            std::time::Duration::from_secs(10)
+        );
+        assert_eq!(config.venues.probe_failure_threshold, 5);
+        assert_eq!(config.venues.probe_recovery_threshold, 4);
     }
 }
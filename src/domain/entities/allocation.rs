@@ -3,7 +3,8 @@
 //! Represents a quantity allocation to a specific venue/quote in a multi-MM fill.
 //!
 //! This module provides the [`Allocation`] struct that tracks how much of an
-//! RFQ's target quantity has been assigned to a particular venue's quote.
+//! RFQ's target quantity has been assigned to a particular venue's quote,
+//! and the [`TradeAllocation`] recorded on a trade once the fill is executed.
 //!
 //! # Examples
 //!
@@ -22,6 +23,7 @@
 //! ```
 
 use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::timestamp::Timestamp;
 use crate::domain::value_objects::{Price, Quantity, QuoteId, VenueId};
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -169,6 +171,187 @@ impl fmt::Display for Allocation {
     }
 }
 
+/// Execution status of an allocation recorded on a trade.
+///
+/// Each allocation of a multi-venue fill is executed as its own venue leg:
+/// `Pending` until it is sent, `Executing` while the venue works it, then
+/// `Filled` or `Failed`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AllocationStatus {
+    /// Allocated by the fill strategy, not yet sent to the venue.
+    #[default]
+    Pending,
+    /// Sent to the venue, awaiting the result.
+    Executing,
+    /// Filled at the allocated venue.
+    Filled,
+    /// The venue leg failed.
+    Failed {
+        /// Why the leg failed.
+        reason: String,
+    },
+}
+
+impl AllocationStatus {
+    /// Returns true if the leg has finished, successfully or not.
+    #[inline]
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Filled | Self::Failed { .. })
+    }
+
+    /// Returns the failure reason, if the leg failed.
+    #[must_use]
+    pub fn failure_reason(&self) -> Option<&str> {
+        match self {
+            Self::Failed { reason } => Some(reason),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for AllocationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Pending => "PENDING",
+            Self::Executing => "EXECUTING",
+            Self::Filled => "FILLED",
+            Self::Failed { .. } => "FAILED",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// An allocation recorded on a trade.
+///
+/// Keeps the fill strategy's [`Allocation`] together with when it was
+/// scheduled and how its venue leg went, so clients can see how the trade
+/// was split across market makers.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::entities::allocation::{Allocation, AllocationStatus, TradeAllocation};
+/// use otc_rfq::domain::value_objects::timestamp::Timestamp;
+/// use otc_rfq::domain::value_objects::{Price, Quantity, QuoteId, VenueId};
+///
+/// let alloc = Allocation::new(
+///     VenueId::new("mm-1"),
+///     QuoteId::new_v4(),
+///     Quantity::new(6.0).unwrap(),
+///     Price::new(50000.0).unwrap(),
+/// ).unwrap();
+///
+/// let mut recorded = TradeAllocation::scheduled(alloc, Timestamp::now());
+/// recorded.mark_executing().unwrap();
+/// recorded.mark_filled().unwrap();
+/// assert_eq!(recorded.status(), &AllocationStatus::Filled);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradeAllocation {
+    /// The allocation produced by the fill strategy.
+    allocation: Allocation,
+    /// When the allocation was scheduled for execution.
+    scheduled_at: Timestamp,
+    /// Execution status.
+    status: AllocationStatus,
+}
+
+impl TradeAllocation {
+    /// Records a freshly scheduled allocation.
+    #[must_use]
+    pub fn scheduled(allocation: Allocation, scheduled_at: Timestamp) -> Self {
+        Self::from_parts(allocation, scheduled_at, AllocationStatus::Pending)
+    }
+
+    /// Creates a trade allocation (for reconstruction from storage).
+    #[must_use]
+    pub fn from_parts(
+        allocation: Allocation,
+        scheduled_at: Timestamp,
+        status: AllocationStatus,
+    ) -> Self {
+        Self {
+            allocation,
+            scheduled_at,
+            status,
+        }
+    }
+
+    /// Returns the underlying allocation.
+    #[inline]
+    #[must_use]
+    pub fn allocation(&self) -> &Allocation {
+        &self.allocation
+    }
+
+    /// Returns when the allocation was scheduled.
+    #[inline]
+    #[must_use]
+    pub fn scheduled_at(&self) -> Timestamp {
+        self.scheduled_at
+    }
+
+    /// Returns the execution status.
+    #[inline]
+    #[must_use]
+    pub fn status(&self) -> &AllocationStatus {
+        &self.status
+    }
+
+    /// Marks the allocation as sent to its venue.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` unless the allocation is pending.
+    pub fn mark_executing(&mut self) -> DomainResult<()> {
+        if self.status != AllocationStatus::Pending {
+            return Err(self.invalid_transition("EXECUTING"));
+        }
+        self.status = AllocationStatus::Executing;
+        Ok(())
+    }
+
+    /// Marks the allocation as filled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` unless the allocation is executing.
+    pub fn mark_filled(&mut self) -> DomainResult<()> {
+        if self.status != AllocationStatus::Executing {
+            return Err(self.invalid_transition("FILLED"));
+        }
+        self.status = AllocationStatus::Filled;
+        Ok(())
+    }
+
+    /// Marks the allocation as failed.
+    ///
+    /// A pending allocation may fail before it is sent, e.g. when its venue
+    /// is unavailable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if the allocation already finished.
+    pub fn mark_failed(&mut self, reason: impl Into<String>) -> DomainResult<()> {
+        if self.status.is_terminal() {
+            return Err(self.invalid_transition("FAILED"));
+        }
+        self.status = AllocationStatus::Failed {
+            reason: reason.into(),
+        };
+        Ok(())
+    }
+
+    fn invalid_transition(&self, target: &str) -> DomainError {
+        DomainError::InvalidState(format!(
+            "cannot transition allocation from {} to {target}",
+            self.status
+        ))
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -291,4 +474,59 @@ mod tests {
             assert_eq!(alloc, deserialized);
         }
     }
+
+    mod trade_allocation {
+        use super::*;
+
+        fn scheduled() -> TradeAllocation {
+            let alloc =
+                Allocation::new(test_venue(), test_quote_id(), test_qty(), test_price()).unwrap();
+            TradeAllocation::scheduled(alloc, Timestamp::now())
+        }
+
+        #[test]
+        fn leg_moves_from_pending_to_filled() {
+            let mut recorded = scheduled();
+            assert_eq!(recorded.status(), &AllocationStatus::Pending);
+            assert!(matches!(
+                recorded.mark_filled(),
+                Err(DomainError::InvalidState(_))
+            ));
+
+            recorded.mark_executing().unwrap();
+            recorded.mark_filled().unwrap();
+            assert_eq!(recorded.status(), &AllocationStatus::Filled);
+            assert!(matches!(
+                recorded.mark_failed("late reject"),
+                Err(DomainError::InvalidState(_))
+            ));
+        }
+
+        #[test]
+        fn failed_leg_keeps_reason() {
+            let mut recorded = scheduled();
+            recorded.mark_executing().unwrap();
+            recorded.mark_failed("venue timeout").unwrap();
+
+            assert_eq!(recorded.status().to_string(), "FAILED");
+            assert_eq!(recorded.status().failure_reason(), Some("venue timeout"));
+            assert!(recorded.status().is_terminal());
+        }
+
+        #[test]
+        fn status_serializes_with_reason() {
+            let failed = AllocationStatus::Failed {
+                reason: "rejected".to_string(),
+            };
+            let json = serde_json::to_value(&failed).unwrap();
+            assert_eq!(
+                json,
+                serde_json::json!({ "state": "FAILED", "reason": "rejected" })
+            );
+            assert_eq!(
+                serde_json::from_value::<AllocationStatus>(json).unwrap(),
+                failed
+            );
+        }
+    }
 }
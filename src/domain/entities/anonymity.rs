@@ -46,6 +46,7 @@
 use crate::domain::value_objects::timestamp::Timestamp;
 use crate::domain::value_objects::{CounterpartyId, Instrument, OrderSide, Quantity, RfqId};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::fmt;
 
 // ============================================================================
@@ -236,6 +237,18 @@ impl AnonymousRfqView {
     pub fn is_expired(&self) -> bool {
         self.expires_at.is_expired()
     }
+
+    /// Returns the pseudonymous handle shown to market makers in place of
+    /// the requester.
+    ///
+    /// The handle is derived from the RFQ ID alone, so it is stable for the
+    /// RFQ but cannot be linked to the requester or their other RFQs.
+    #[must_use]
+    pub fn requester_handle(&self) -> String {
+        let digest = Sha256::digest(format!("requester:{}", self.rfq_id).as_bytes());
+        let prefix = digest.get(..8).unwrap_or_default();
+        format!("anon-{}", hex::encode(prefix))
+    }
 }
 
 impl fmt::Display for AnonymousRfqView {
@@ -504,6 +517,27 @@ mod tests {
         assert!(!view.is_expired());
     }
 
+    #[test]
+    fn anonymous_rfq_view_requester_handle_is_per_rfq() {
+        let view = |rfq_id| {
+            AnonymousRfqView::new(
+                rfq_id,
+                create_test_instrument(),
+                OrderSide::Buy,
+                Quantity::new(1.0).unwrap(),
+                Timestamp::now().add_secs(300),
+                AnonymityLevel::FullAnonymous,
+            )
+        };
+        let rfq_id = RfqId::new_v4();
+
+        let handle = view(rfq_id).requester_handle();
+        assert!(handle.starts_with("anon-"));
+        assert_eq!(handle.len(), 21);
+        assert_eq!(handle, view(rfq_id).requester_handle());
+        assert_ne!(handle, view(RfqId::new_v4()).requester_handle());
+    }
+
     #[test]
     fn anonymous_rfq_view_transparent_not_anonymous() {
         let view = AnonymousRfqView::new(
@@ -29,6 +29,7 @@
 //! ```
 
 use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::counter_conditions::CounterConditions;
 use crate::domain::value_objects::timestamp::Timestamp;
 use crate::domain::value_objects::{CounterpartyId, Price, Quantity, QuoteId, RfqId};
 use serde::{Deserialize, Serialize};
@@ -87,6 +88,9 @@ pub struct CounterQuote {
     round: u8,
     /// When this counter-quote was created.
     created_at: Timestamp,
+    /// Validity and settlement terms attached by the submitter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    conditions: Option<CounterConditions>,
 }
 
 impl CounterQuote {
@@ -133,6 +137,7 @@ impl CounterQuote {
             valid_until,
             round,
             created_at: Timestamp::now(),
+            conditions: None,
         })
     }
 
@@ -165,9 +170,17 @@ impl CounterQuote {
             valid_until,
             round,
             created_at,
+            conditions: None,
         }
     }
 
+    /// Attaches validity and settlement terms.
+    #[must_use]
+    pub fn with_conditions(mut self, conditions: CounterConditions) -> Self {
+        self.conditions = Some(conditions);
+        self
+    }
+
     fn validate_price(price: &Price) -> DomainResult<()> {
         if !price.is_positive() {
             return Err(DomainError::InvalidPrice(
@@ -269,11 +282,30 @@ impl CounterQuote {
         self.created_at
     }
 
+    /// Returns the terms attached by the submitter, if any.
+    #[inline]
+    #[must_use]
+    pub fn conditions(&self) -> Option<&CounterConditions> {
+        self.conditions.as_ref()
+    }
+
+    /// Moves the expiry earlier to `valid_until`; later times are ignored.
+    pub(crate) fn shorten_validity(&mut self, valid_until: Timestamp) {
+        self.valid_until = self.valid_until.min(valid_until);
+    }
+
     /// Returns true if this counter-quote has expired.
     #[must_use]
     pub fn is_expired(&self) -> bool {
         self.valid_until.is_expired()
     }
+
+    /// Returns true if this counter-quote has expired as of `now`.
+    #[inline]
+    #[must_use]
+    pub fn is_expired_at(&self, now: Timestamp) -> bool {
+        self.valid_until.is_expired_at(now)
+    }
 }
 
 impl fmt::Display for CounterQuote {
@@ -315,6 +347,7 @@ pub struct CounterQuoteBuilder {
     counter_quantity: Quantity,
     valid_until: Timestamp,
     round: u8,
+    conditions: Option<CounterConditions>,
 }
 
 impl CounterQuoteBuilder {
@@ -337,9 +370,19 @@ impl CounterQuoteBuilder {
             counter_quantity,
             valid_until,
             round,
+            conditions: None,
         }
     }
 
+    /// Attaches validity and settlement terms.
+    ///
+    /// The terms are checked against the negotiation when the counter is
+    /// submitted.
+    pub fn with_conditions(mut self, conditions: CounterConditions) -> Self {
+        self.conditions = Some(conditions);
+        self
+    }
+
     /// Builds and validates the counter-quote.
     ///
     /// Delegates to [`CounterQuote::new`] so all invariants are checked.
@@ -348,7 +391,7 @@ impl CounterQuoteBuilder {
     ///
     /// Returns the same errors as [`CounterQuote::new`].
     pub fn build(self) -> DomainResult<CounterQuote> {
-        CounterQuote::new(
+        let mut counter = CounterQuote::new(
             self.original_quote_id,
             self.rfq_id,
             self.from_account,
@@ -356,7 +399,9 @@ impl CounterQuoteBuilder {
             self.counter_quantity,
             self.valid_until,
             self.round,
-        )
+        )?;
+        counter.conditions = self.conditions;
+        Ok(counter)
     }
 
     /// Builds the counter-quote **without** validation.
@@ -377,6 +422,7 @@ impl CounterQuoteBuilder {
             valid_until: self.valid_until,
             round: self.round,
             created_at: Timestamp::now(),
+            conditions: self.conditions,
         }
     }
 }
@@ -3,8 +3,8 @@
 //! Represents a client or market maker.
 //!
 //! This module provides the [`Counterparty`] entity representing clients,
-//! market makers, and other trading participants, including KYC status
-//! and trading limits.
+//! market makers, and other trading participants, including KYC status,
+//! trading limits and settlement addresses.
 //!
 //! # Examples
 //!
@@ -22,8 +22,9 @@
 //! assert_eq!(counterparty.kyc_status(), KycStatus::NotStarted);
 //! ```
 
+use crate::domain::errors::{DomainError, DomainResult};
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{Blockchain, CounterpartyId, Price};
+use crate::domain::value_objects::{Blockchain, CounterpartyId, Price, SettlementWindow};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -487,6 +488,107 @@ impl fmt::Display for WalletAddress {
     }
 }
 
+/// Where a counterparty receives settlement on one chain.
+///
+/// An address without a token is the counterparty's default on its chain;
+/// an address with a token overrides the default for that token. Token
+/// symbols are compared case-insensitively and stored in upper case.
+///
+/// New addresses are unverified. Settlement is only routed to an address
+/// once the counterparty has proven control of it.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::entities::counterparty::{SettlementAddress, WalletAddress};
+/// use otc_rfq::domain::value_objects::Blockchain;
+///
+/// let address = SettlementAddress::new(
+///     WalletAddress::new(Blockchain::Polygon, "0x742d35Cc6634C0532925a3b844Bc9e7595f1Db38"),
+///     Some("usdc".to_string()),
+/// );
+///
+/// assert_eq!(address.chain(), Blockchain::Polygon);
+/// assert_eq!(address.token(), Some("USDC"));
+/// assert!(!address.is_verified());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SettlementAddress {
+    /// The chain settled on.
+    chain: Blockchain,
+    /// Token the address is for, or `None` for every token on the chain.
+    token: Option<String>,
+    /// The receiving wallet.
+    address: WalletAddress,
+    /// Whether the counterparty proved control of the wallet.
+    verified: bool,
+}
+
+impl SettlementAddress {
+    /// Creates an unverified settlement address on the wallet's chain.
+    #[must_use]
+    pub fn new(address: WalletAddress, token: Option<String>) -> Self {
+        Self {
+            chain: address.chain(),
+            token: token.map(|t| t.to_ascii_uppercase()),
+            address,
+            verified: false,
+        }
+    }
+
+    /// Returns the chain.
+    #[inline]
+    #[must_use]
+    pub fn chain(&self) -> Blockchain {
+        self.chain
+    }
+
+    /// Returns the token symbol, if the address is token-specific.
+    #[inline]
+    #[must_use]
+    pub fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+
+    /// Returns the receiving wallet.
+    #[inline]
+    #[must_use]
+    pub fn address(&self) -> &WalletAddress {
+        &self.address
+    }
+
+    /// Returns true if the counterparty proved control of the wallet.
+    #[inline]
+    #[must_use]
+    pub fn is_verified(&self) -> bool {
+        self.verified
+    }
+
+    /// Returns true if this address occupies the `chain` / `token` slot.
+    #[must_use]
+    pub fn is_for(&self, chain: Blockchain, token: Option<&str>) -> bool {
+        self.chain == chain
+            && match (self.token.as_deref(), token) {
+                (Some(own), Some(token)) => own.eq_ignore_ascii_case(token),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+impl fmt::Display for SettlementAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.token {
+            Some(token) => write!(f, "{} {}", self.address, token)?,
+            None => write!(f, "{}", self.address)?,
+        }
+        if !self.verified {
+            write!(f, " (unverified)")?;
+        }
+        Ok(())
+    }
+}
+
 /// A trading counterparty.
 ///
 /// Represents a client, market maker, or other trading participant
@@ -522,8 +624,14 @@ pub struct Counterparty {
     limits: CounterpartyLimits,
     /// Wallet addresses for on-chain settlement.
     wallet_addresses: Vec<WalletAddress>,
+    /// Settlement addresses per chain and token.
+    #[serde(default)]
+    settlement_addresses: Vec<SettlementAddress>,
     /// Notification preferences for trade confirmations.
     notification_preferences: crate::domain::value_objects::NotificationPreferences,
+    /// Default settlement window; `None` for the platform default.
+    #[serde(default)]
+    settlement_window: Option<SettlementWindow>,
     /// Whether the counterparty is active.
     active: bool,
     /// When this counterparty was created.
@@ -554,7 +662,9 @@ impl Counterparty {
             kyc_status: KycStatus::NotStarted,
             limits: CounterpartyLimits::default(),
             wallet_addresses: Vec::new(),
+            settlement_addresses: Vec::new(),
             notification_preferences: crate::domain::value_objects::NotificationPreferences::none(),
+            settlement_window: None,
             active: true,
             created_at: now,
             updated_at: now,
@@ -571,6 +681,7 @@ impl Counterparty {
         kyc_status: KycStatus,
         limits: CounterpartyLimits,
         wallet_addresses: Vec<WalletAddress>,
+        settlement_addresses: Vec<SettlementAddress>,
         notification_preferences: crate::domain::value_objects::NotificationPreferences,
         active: bool,
         created_at: Timestamp,
@@ -583,7 +694,9 @@ impl Counterparty {
             kyc_status,
             limits,
             wallet_addresses,
+            settlement_addresses,
             notification_preferences,
+            settlement_window: None,
             active,
             created_at,
             updated_at,
@@ -641,6 +754,13 @@ impl Counterparty {
         &self.wallet_addresses
     }
 
+    /// Returns the settlement addresses.
+    #[inline]
+    #[must_use]
+    pub fn settlement_addresses(&self) -> &[SettlementAddress] {
+        &self.settlement_addresses
+    }
+
     /// Returns the notification preferences.
     #[inline]
     #[must_use]
@@ -660,6 +780,27 @@ impl Counterparty {
         self.updated_at = Timestamp::now();
     }
 
+    /// Returns the default settlement window, if one is set.
+    #[inline]
+    #[must_use]
+    pub fn settlement_window(&self) -> Option<&SettlementWindow> {
+        self.settlement_window.as_ref()
+    }
+
+    /// Sets the default settlement window; `None` uses the platform default.
+    #[inline]
+    pub fn set_settlement_window(&mut self, window: Option<SettlementWindow>) {
+        self.settlement_window = window;
+        self.updated_at = Timestamp::now();
+    }
+
+    /// Restores the default settlement window (for reconstruction from storage).
+    #[must_use]
+    pub fn with_settlement_window(mut self, window: Option<SettlementWindow>) -> Self {
+        self.settlement_window = window;
+        self
+    }
+
     /// Returns whether the counterparty is active.
     #[inline]
     #[must_use]
@@ -724,6 +865,31 @@ impl Counterparty {
             .or_else(|| self.wallet_addresses.iter().find(|w| w.chain() == chain))
     }
 
+    /// Returns the settlement address for `token` on `chain`.
+    ///
+    /// A token-specific address wins over the chain's default address.
+    /// Verification is not checked here.
+    #[must_use]
+    pub fn settlement_address(
+        &self,
+        chain: Blockchain,
+        token: Option<&str>,
+    ) -> Option<&SettlementAddress> {
+        let default = || {
+            self.settlement_addresses
+                .iter()
+                .find(|a| a.is_for(chain, None))
+        };
+        match token {
+            Some(_) => self
+                .settlement_addresses
+                .iter()
+                .find(|a| a.is_for(chain, token))
+                .or_else(default),
+            None => default(),
+        }
+    }
+
     // ========== Mutators ==========
 
     /// Sets the counterparty name.
@@ -768,6 +934,78 @@ impl Counterparty {
         }
     }
 
+    /// Adds a settlement address.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ConflictDetected` if the chain already has an
+    /// address for the same token, or a default address if `address` has
+    /// no token.
+    pub fn add_settlement_address(&mut self, address: SettlementAddress) -> DomainResult<()> {
+        if let Some(existing) = self
+            .settlement_addresses
+            .iter()
+            .find(|a| a.is_for(address.chain(), address.token()))
+        {
+            return Err(DomainError::ConflictDetected(format!(
+                "counterparty {} already has settlement address {}",
+                self.id, existing
+            )));
+        }
+        self.settlement_addresses.push(address);
+        self.updated_at = Timestamp::now();
+        Ok(())
+    }
+
+    /// Removes the settlement address for `token` on `chain`.
+    ///
+    /// Returns the removed address, or `None` if there was none.
+    pub fn remove_settlement_address(
+        &mut self,
+        chain: Blockchain,
+        token: Option<&str>,
+    ) -> Option<SettlementAddress> {
+        let pos = self
+            .settlement_addresses
+            .iter()
+            .position(|a| a.is_for(chain, token))?;
+        self.updated_at = Timestamp::now();
+        Some(self.settlement_addresses.remove(pos))
+    }
+
+    /// Marks the settlement address for `token` on `chain` as verified.
+    ///
+    /// `address` must be the wallet the proof was made for, so that a
+    /// proof for a replaced address does not verify its successor.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if the slot is empty or holds
+    /// a different wallet.
+    pub fn verify_settlement_address(
+        &mut self,
+        chain: Blockchain,
+        token: Option<&str>,
+        address: &str,
+    ) -> DomainResult<()> {
+        let entry = self
+            .settlement_addresses
+            .iter_mut()
+            .find(|a| a.is_for(chain, token))
+            .filter(|a| a.address.address().eq_ignore_ascii_case(address))
+            .ok_or_else(|| {
+                DomainError::ValidationError(format!(
+                    "counterparty {} has no settlement address {} on {}",
+                    self.id, address, chain
+                ))
+            })?;
+        if !entry.verified {
+            entry.verified = true;
+            self.updated_at = Timestamp::now();
+        }
+        Ok(())
+    }
+
     /// Clears all wallet addresses.
     pub fn clear_wallets(&mut self) {
         if !self.wallet_addresses.is_empty() {
@@ -775,6 +1013,51 @@ impl Counterparty {
             self.updated_at = Timestamp::now();
         }
     }
+
+    /// Returns this counterparty with its personal identifiers erased.
+    ///
+    /// The ID and name become `pseudonym`, every wallet and settlement
+    /// address is replaced with a token derived from it, labels and contact
+    /// details are dropped and the counterparty is deactivated. Type, KYC
+    /// status, limits and settlement window are kept. `pseudonym` must not
+    /// be derived from the erased identifiers.
+    #[must_use]
+    pub fn pseudonymized(self, pseudonym: CounterpartyId) -> Self {
+        let token = |kind: &str, index: usize| format!("{pseudonym}:{kind}:{index}");
+        let wallet_addresses = self
+            .wallet_addresses
+            .iter()
+            .enumerate()
+            .map(|(index, wallet)| WalletAddress {
+                chain: wallet.chain,
+                address: token("wallet", index),
+                label: None,
+                is_primary: wallet.is_primary,
+            })
+            .collect();
+        let settlement_addresses = self
+            .settlement_addresses
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                SettlementAddress::new(
+                    WalletAddress::new(entry.chain, token("settlement", index)),
+                    entry.token.clone(),
+                )
+            })
+            .collect();
+
+        Self {
+            name: pseudonym.to_string(),
+            id: pseudonym,
+            wallet_addresses,
+            settlement_addresses,
+            notification_preferences: crate::domain::value_objects::NotificationPreferences::none(),
+            active: false,
+            updated_at: Timestamp::now(),
+            ..self
+        }
+    }
 }
 
 impl fmt::Display for Counterparty {
@@ -1167,6 +1450,143 @@ mod tests {
         }
     }
 
+    mod counterparty_settlement_addresses {
+        use super::*;
+
+        const POLYGON: &str = "0x1111111111111111111111111111111111111111";
+        const POLYGON_USDC: &str = "0x2222222222222222222222222222222222222222";
+        const ARBITRUM: &str = "0x3333333333333333333333333333333333333333";
+
+        fn address(chain: Blockchain, address: &str, token: Option<&str>) -> SettlementAddress {
+            SettlementAddress::new(
+                WalletAddress::new(chain, address),
+                token.map(str::to_string),
+            )
+        }
+
+        fn counterparty_with_addresses() -> Counterparty {
+            let mut cp = create_test_counterparty();
+            cp.add_settlement_address(address(Blockchain::Polygon, POLYGON, None))
+                .unwrap();
+            cp.add_settlement_address(address(Blockchain::Polygon, POLYGON_USDC, Some("USDC")))
+                .unwrap();
+            cp.add_settlement_address(address(Blockchain::Arbitrum, ARBITRUM, None))
+                .unwrap();
+            cp
+        }
+
+        fn resolved<'a>(
+            cp: &'a Counterparty,
+            chain: Blockchain,
+            token: Option<&str>,
+        ) -> Option<&'a str> {
+            cp.settlement_address(chain, token)
+                .map(|a| a.address().address())
+        }
+
+        #[test]
+        fn resolves_per_chain_and_token() {
+            let cp = counterparty_with_addresses();
+
+            assert_eq!(resolved(&cp, Blockchain::Polygon, None), Some(POLYGON));
+            assert_eq!(
+                resolved(&cp, Blockchain::Polygon, Some("usdc")),
+                Some(POLYGON_USDC)
+            );
+            assert_eq!(
+                resolved(&cp, Blockchain::Polygon, Some("WETH")),
+                Some(POLYGON)
+            );
+            assert_eq!(
+                resolved(&cp, Blockchain::Arbitrum, Some("USDC")),
+                Some(ARBITRUM)
+            );
+            assert_eq!(resolved(&cp, Blockchain::Ethereum, None), None);
+        }
+
+        #[test]
+        fn rejects_a_second_address_for_the_same_slot() {
+            let mut cp = counterparty_with_addresses();
+
+            let result =
+                cp.add_settlement_address(address(Blockchain::Polygon, ARBITRUM, Some("usdc")));
+
+            assert!(matches!(result, Err(DomainError::ConflictDetected(_))));
+            assert_eq!(cp.settlement_addresses().len(), 3);
+        }
+
+        #[test]
+        fn remove_settlement_address() {
+            let mut cp = counterparty_with_addresses();
+
+            let removed = cp.remove_settlement_address(Blockchain::Polygon, Some("USDC"));
+
+            assert_eq!(removed.unwrap().address().address(), POLYGON_USDC);
+            assert_eq!(
+                resolved(&cp, Blockchain::Polygon, Some("USDC")),
+                Some(POLYGON)
+            );
+            assert!(
+                cp.remove_settlement_address(Blockchain::Polygon, Some("USDC"))
+                    .is_none()
+            );
+        }
+
+        #[test]
+        fn verify_requires_the_current_address() {
+            let mut cp = counterparty_with_addresses();
+
+            assert!(
+                cp.verify_settlement_address(Blockchain::Arbitrum, None, POLYGON)
+                    .is_err()
+            );
+            cp.verify_settlement_address(Blockchain::Arbitrum, None, &ARBITRUM.to_uppercase())
+                .unwrap();
+
+            let verified = cp.settlement_address(Blockchain::Arbitrum, None).unwrap();
+            assert!(verified.is_verified());
+        }
+    }
+
+    mod erasure {
+        use super::*;
+
+        #[test]
+        fn pseudonymized_counterparty_keeps_no_identifiers() {
+            let mut cp = create_test_counterparty();
+            cp.set_kyc_status(KycStatus::Approved);
+            cp.add_wallet(WalletAddress::with_label(
+                Blockchain::Ethereum,
+                "0x742d35cc6634c0532925a3b844bc9e7595f1db38",
+                "treasury",
+            ));
+            cp.add_settlement_address(SettlementAddress::new(
+                WalletAddress::new(Blockchain::Polygon, "0xabc"),
+                Some("USDC".to_string()),
+            ))
+            .unwrap();
+
+            let erased = cp.pseudonymized(CounterpartyId::new("erased-1"));
+
+            assert_eq!(erased.id().as_str(), "erased-1");
+            assert_eq!(erased.name(), "erased-1");
+            assert_eq!(erased.kyc_status(), KycStatus::Approved);
+            assert!(!erased.is_active());
+            assert_eq!(
+                erased.notification_preferences(),
+                &crate::domain::value_objects::NotificationPreferences::none()
+            );
+            let wallet = erased.wallet_addresses().first().unwrap();
+            assert_eq!(wallet.chain(), Blockchain::Ethereum);
+            assert_eq!(wallet.address(), "erased-1:wallet:0");
+            assert_eq!(wallet.label(), None);
+            let settlement = erased.settlement_addresses().first().unwrap();
+            assert_eq!(settlement.token(), Some("USDC"));
+            assert_eq!(settlement.address().address(), "erased-1:settlement:0");
+            assert!(!settlement.is_verified());
+        }
+    }
+
     mod display {
         use super::*;
 
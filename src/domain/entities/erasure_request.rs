@@ -0,0 +1,372 @@
+//! # Erasure Request Aggregate
+//!
+//! A legal request to erase the personal identifiers of a departed
+//! counterparty.
+//!
+//! This module provides the [`ErasureRequest`] aggregate. A request is
+//! reviewed before anything is erased: someone other than the requester
+//! must approve it, and only an approved request can be executed.
+//! Executing replaces the counterparty's identifiers with an irreversible
+//! pseudonym; trades and their amounts are kept for regulatory retention.
+//!
+//! Once executed, the request itself refers to the counterparty by its
+//! pseudonym, so the audit trail of the erasure does not keep the erased
+//! identifier either.
+//!
+//! # Lifecycle
+//!
+//! ```text
+//! Requested → Approved → Executed
+//! ```
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::entities::erasure_request::{ErasureRequest, ErasureRequestStatus};
+//! use otc_rfq::domain::value_objects::CounterpartyId;
+//!
+//! let mut request = ErasureRequest::new(
+//!     CounterpartyId::new("acme-trading"),
+//!     "legal-1",
+//!     "Client offboarded, retention of identity no longer required",
+//! );
+//!
+//! request.approve("legal-2").unwrap();
+//! request.execute(CounterpartyId::new("erased-7f3a9c1e2b4d6f80")).unwrap();
+//!
+//! assert_eq!(request.status(), ErasureRequestStatus::Executed);
+//! assert_eq!(request.counterparty_id().as_str(), "erased-7f3a9c1e2b4d6f80");
+//! ```
+
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{CounterpartyId, ErasureRequestId};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// Review state of an [`ErasureRequest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErasureRequestStatus {
+    /// Waiting for review.
+    Requested,
+    /// Approved; the erasure can be executed.
+    Approved,
+    /// The counterparty was pseudonymized (terminal).
+    Executed,
+}
+
+impl fmt::Display for ErasureRequestStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Requested => "REQUESTED",
+            Self::Approved => "APPROVED",
+            Self::Executed => "EXECUTED",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for ErasureRequestStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "REQUESTED" => Ok(Self::Requested),
+            "APPROVED" => Ok(Self::Approved),
+            "EXECUTED" => Ok(Self::Executed),
+            other => Err(format!("unknown erasure request status: {other}")),
+        }
+    }
+}
+
+/// A reviewed request to erase a counterparty's personal identifiers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErasureRequest {
+    id: ErasureRequestId,
+    counterparty_id: CounterpartyId,
+    requested_by: String,
+    reason: String,
+    status: ErasureRequestStatus,
+    approved_by: Option<String>,
+    approved_at: Option<Timestamp>,
+    executed_at: Option<Timestamp>,
+    created_at: Timestamp,
+    updated_at: Timestamp,
+}
+
+impl ErasureRequest {
+    /// Creates a request to erase `counterparty_id`, waiting for review.
+    #[must_use]
+    pub fn new(
+        counterparty_id: CounterpartyId,
+        requested_by: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        let now = Timestamp::now();
+        Self {
+            id: ErasureRequestId::new_v4(),
+            counterparty_id,
+            requested_by: requested_by.into(),
+            reason: reason.into(),
+            status: ErasureRequestStatus::Requested,
+            approved_by: None,
+            approved_at: None,
+            executed_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Creates a request with specific values (for reconstruction from storage).
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_parts(
+        id: ErasureRequestId,
+        counterparty_id: CounterpartyId,
+        requested_by: String,
+        reason: String,
+        status: ErasureRequestStatus,
+        approved_by: Option<String>,
+        approved_at: Option<Timestamp>,
+        executed_at: Option<Timestamp>,
+        created_at: Timestamp,
+        updated_at: Timestamp,
+    ) -> Self {
+        Self {
+            id,
+            counterparty_id,
+            requested_by,
+            reason,
+            status,
+            approved_by,
+            approved_at,
+            executed_at,
+            created_at,
+            updated_at,
+        }
+    }
+
+    // ========== Accessors ==========
+
+    /// Returns the request ID.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> ErasureRequestId {
+        self.id
+    }
+
+    /// Returns the counterparty to erase, or its pseudonym once executed.
+    #[inline]
+    #[must_use]
+    pub fn counterparty_id(&self) -> &CounterpartyId {
+        &self.counterparty_id
+    }
+
+    /// Returns who requested the erasure.
+    #[inline]
+    #[must_use]
+    pub fn requested_by(&self) -> &str {
+        &self.requested_by
+    }
+
+    /// Returns why the erasure was requested.
+    #[inline]
+    #[must_use]
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Returns the review state.
+    #[inline]
+    #[must_use]
+    pub fn status(&self) -> ErasureRequestStatus {
+        self.status
+    }
+
+    /// Returns who approved the erasure, if approved.
+    #[inline]
+    #[must_use]
+    pub fn approved_by(&self) -> Option<&str> {
+        self.approved_by.as_deref()
+    }
+
+    /// Returns when the erasure was approved, if approved.
+    #[inline]
+    #[must_use]
+    pub fn approved_at(&self) -> Option<Timestamp> {
+        self.approved_at
+    }
+
+    /// Returns when the erasure was executed, if executed.
+    #[inline]
+    #[must_use]
+    pub fn executed_at(&self) -> Option<Timestamp> {
+        self.executed_at
+    }
+
+    /// Returns when the request was created.
+    #[inline]
+    #[must_use]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    /// Returns when the request was last updated.
+    #[inline]
+    #[must_use]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    /// Returns true while the request has not been executed.
+    #[inline]
+    #[must_use]
+    pub fn is_pending(&self) -> bool {
+        self.status != ErasureRequestStatus::Executed
+    }
+
+    // ========== State Transitions ==========
+
+    /// Approves the erasure.
+    ///
+    /// Transitions: Requested → Approved
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if `approver` requested the
+    /// erasure, and `DomainError::InvalidState` if the request is not
+    /// waiting for review.
+    pub fn approve(&mut self, approver: impl Into<String>) -> DomainResult<()> {
+        self.require(ErasureRequestStatus::Requested, "approve")?;
+        let approver = approver.into();
+        if approver == self.requested_by {
+            return Err(DomainError::ValidationError(format!(
+                "erasure request {} must be approved by someone other than its requester",
+                self.id
+            )));
+        }
+        self.approved_by = Some(approver);
+        self.approved_at = Some(Timestamp::now());
+        self.transition_to(ErasureRequestStatus::Approved);
+        Ok(())
+    }
+
+    /// Records that the counterparty was erased under `pseudonym`.
+    ///
+    /// Transitions: Approved → Executed
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if the request is not approved.
+    pub fn execute(&mut self, pseudonym: CounterpartyId) -> DomainResult<()> {
+        self.require(ErasureRequestStatus::Approved, "execute")?;
+        self.counterparty_id = pseudonym;
+        self.executed_at = Some(Timestamp::now());
+        self.transition_to(ErasureRequestStatus::Executed);
+        Ok(())
+    }
+
+    fn require(&self, status: ErasureRequestStatus, action: &str) -> DomainResult<()> {
+        if self.status == status {
+            Ok(())
+        } else {
+            Err(DomainError::InvalidState(format!(
+                "cannot {} erasure request in {}",
+                action, self.status
+            )))
+        }
+    }
+
+    fn transition_to(&mut self, status: ErasureRequestStatus) {
+        self.status = status;
+        self.updated_at = Timestamp::now();
+    }
+}
+
+impl fmt::Display for ErasureRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ErasureRequest({} {} [{}])",
+            self.id, self.counterparty_id, self.status
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn request() -> ErasureRequest {
+        ErasureRequest::new(
+            CounterpartyId::new("acme-trading"),
+            "legal-1",
+            "client offboarded",
+        )
+    }
+
+    #[test]
+    fn approved_request_executes_under_the_pseudonym() {
+        let mut request = request();
+        assert_eq!(request.status(), ErasureRequestStatus::Requested);
+
+        request.approve("legal-2").unwrap();
+        assert_eq!(request.approved_by(), Some("legal-2"));
+        assert!(request.approved_at().is_some());
+
+        request
+            .execute(CounterpartyId::new("erased-0123456789abcdef"))
+            .unwrap();
+        assert_eq!(request.status(), ErasureRequestStatus::Executed);
+        assert_eq!(
+            request.counterparty_id().as_str(),
+            "erased-0123456789abcdef"
+        );
+        assert!(!request.is_pending());
+    }
+
+    #[test]
+    fn requester_cannot_approve_their_own_request() {
+        let mut request = request();
+
+        assert!(matches!(
+            request.approve("legal-1"),
+            Err(DomainError::ValidationError(_))
+        ));
+        assert_eq!(request.status(), ErasureRequestStatus::Requested);
+    }
+
+    #[test]
+    fn execution_requires_approval() {
+        let mut request = request();
+
+        assert!(matches!(
+            request.execute(CounterpartyId::new("erased-1")),
+            Err(DomainError::InvalidState(_))
+        ));
+        assert_eq!(request.counterparty_id().as_str(), "acme-trading");
+
+        request.approve("legal-2").unwrap();
+        request.execute(CounterpartyId::new("erased-1")).unwrap();
+        assert!(request.approve("legal-3").is_err());
+        assert!(request.execute(CounterpartyId::new("erased-2")).is_err());
+    }
+
+    #[test]
+    fn status_round_trips_through_its_string_form() {
+        for status in [
+            ErasureRequestStatus::Requested,
+            ErasureRequestStatus::Approved,
+            ErasureRequestStatus::Executed,
+        ] {
+            assert_eq!(
+                status.to_string().parse::<ErasureRequestStatus>(),
+                Ok(status)
+            );
+        }
+    }
+}
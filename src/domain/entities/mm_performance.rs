@@ -73,6 +73,12 @@ pub enum MmPerformanceEventKind {
 
     /// An accept was requested from this market maker (for reject rate denominator).
     AcceptRequested = 4,
+
+    /// An RFQ sent to this market maker was cancelled while it was pricing.
+    ///
+    /// Offsets one [`RfqSent`](Self::RfqSent) so the cancelled request does
+    /// not count against the response rate.
+    RfqWithdrawn = 5,
 }
 
 impl MmPerformanceEventKind {
@@ -86,6 +92,7 @@ impl MmPerformanceEventKind {
             Self::TradeExecuted => 2,
             Self::LastLookReject => 3,
             Self::AcceptRequested => 4,
+            Self::RfqWithdrawn => 5,
         }
     }
 }
@@ -103,6 +110,7 @@ impl fmt::Display for MmPerformanceEventKind {
             Self::TradeExecuted => write!(f, "TRADE_EXECUTED"),
             Self::LastLookReject => write!(f, "LAST_LOOK_REJECT"),
             Self::AcceptRequested => write!(f, "ACCEPT_REQUESTED"),
+            Self::RfqWithdrawn => write!(f, "RFQ_WITHDRAWN"),
         }
     }
 }
@@ -230,10 +238,13 @@ pub struct MmPerformanceMetrics {
     competitiveness_score: Option<f64>,
     /// Reject rate: (last_look_rejects / accepts_requested) × 100. Percentage (0-100).
     reject_rate_pct: Option<f64>,
-    /// Total RFQs sent to this MM within the window.
+    /// Total RFQs sent to this MM within the window, less withdrawn ones.
     total_rfqs_received: u64,
     /// Total quotes provided by this MM within the window.
     total_quotes_provided: u64,
+    /// Total quotes from this MM that ranked best within the window.
+    #[serde(default)]
+    total_best_quotes: u64,
     /// Total trades executed from this MM's quotes within the window.
     total_trades_executed: u64,
     /// Total accepts requested from this MM within the window.
@@ -265,12 +276,14 @@ impl MmPerformanceMetrics {
         window_end: Timestamp,
     ) -> Self {
         let mut total_rfqs_received: u64 = 0;
+        let mut total_rfqs_withdrawn: u64 = 0;
         let mut total_quotes_provided: u64 = 0;
         let mut total_trades_executed: u64 = 0;
         let mut total_accepts_requested: u64 = 0;
         let mut total_last_look_rejects: u64 = 0;
         let mut total_response_time_ms: u64 = 0;
         let mut total_rank: u64 = 0;
+        let mut total_best_quotes: u64 = 0;
 
         for event in events {
             if !event.is_within_window(window_start, window_end) {
@@ -289,6 +302,9 @@ impl MmPerformanceMetrics {
                     total_response_time_ms =
                         total_response_time_ms.saturating_add(*response_time_ms);
                     total_rank = total_rank.saturating_add(*rank);
+                    if *rank == 1 {
+                        total_best_quotes = total_best_quotes.saturating_add(1);
+                    }
                 }
                 MmPerformanceEventKind::TradeExecuted => {
                     total_trades_executed = total_trades_executed.saturating_add(1);
@@ -299,8 +315,12 @@ impl MmPerformanceMetrics {
                 MmPerformanceEventKind::AcceptRequested => {
                     total_accepts_requested = total_accepts_requested.saturating_add(1);
                 }
+                MmPerformanceEventKind::RfqWithdrawn => {
+                    total_rfqs_withdrawn = total_rfqs_withdrawn.saturating_add(1);
+                }
             }
         }
+        let total_rfqs_received = total_rfqs_received.saturating_sub(total_rfqs_withdrawn);
 
         // response_rate_pct = (quotes_provided / rfqs_sent) × 100
         let response_rate_pct = if total_rfqs_received > 0 {
@@ -346,6 +366,7 @@ impl MmPerformanceMetrics {
             reject_rate_pct,
             total_rfqs_received,
             total_quotes_provided,
+            total_best_quotes,
             total_trades_executed,
             total_accepts_requested,
             total_last_look_rejects,
@@ -397,6 +418,8 @@ impl MmPerformanceMetrics {
     }
 
     /// Returns the total number of RFQs sent to this MM in the window.
+    ///
+    /// RFQs withdrawn by a cancellation are not counted.
     #[inline]
     #[must_use]
     pub fn total_rfqs_received(&self) -> u64 {
@@ -410,6 +433,21 @@ impl MmPerformanceMetrics {
         self.total_quotes_provided
     }
 
+    /// Returns the number of this MM's quotes that ranked best in the window.
+    #[inline]
+    #[must_use]
+    pub fn total_best_quotes(&self) -> u64 {
+        self.total_best_quotes
+    }
+
+    /// Returns the percentage (0-100) of this MM's quotes that ranked best,
+    /// or `None` if no quotes were provided.
+    #[must_use]
+    pub fn best_quote_pct(&self) -> Option<f64> {
+        (self.total_quotes_provided > 0)
+            .then(|| (self.total_best_quotes as f64 / self.total_quotes_provided as f64) * 100.0)
+    }
+
     /// Returns the total number of trades executed from this MM's quotes in the window.
     #[inline]
     #[must_use]
@@ -529,6 +567,7 @@ mod tests {
             assert_eq!(MmPerformanceEventKind::TradeExecuted.as_u8(), 2);
             assert_eq!(MmPerformanceEventKind::LastLookReject.as_u8(), 3);
             assert_eq!(MmPerformanceEventKind::AcceptRequested.as_u8(), 4);
+            assert_eq!(MmPerformanceEventKind::RfqWithdrawn.as_u8(), 5);
         }
 
         #[test]
@@ -648,6 +687,25 @@ mod tests {
             assert!((rate - 75.0).abs() < f64::EPSILON);
         }
 
+        #[test]
+        fn withdrawn_rfqs_do_not_count_against_response_rate() {
+            let events = vec![
+                make_event(MmPerformanceEventKind::RfqSent),
+                make_event(MmPerformanceEventKind::RfqSent),
+                make_event(MmPerformanceEventKind::QuoteReceived {
+                    response_time_ms: 100,
+                    rank: 1,
+                }),
+                make_event(MmPerformanceEventKind::RfqWithdrawn),
+            ];
+
+            let metrics = MmPerformanceMetrics::compute(&mm_id(), &events, window_start(), now());
+
+            assert_eq!(metrics.total_rfqs_received(), 1);
+            let rate = metrics.response_rate_pct().unwrap();
+            assert!((rate - 100.0).abs() < f64::EPSILON);
+        }
+
         #[test]
         fn avg_response_time_ms_computed_correctly() {
             let events = vec![
@@ -720,6 +778,9 @@ mod tests {
 
             let score = metrics.competitiveness_score().unwrap();
             assert!((score - 2.0).abs() < f64::EPSILON);
+            assert_eq!(metrics.total_best_quotes(), 1);
+            let best = metrics.best_quote_pct().unwrap();
+            assert!((best - 100.0 / 3.0).abs() < 1e-9);
         }
 
         #[test]
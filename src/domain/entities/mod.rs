@@ -7,12 +7,15 @@
 //! - [`Rfq`]: Request-for-Quote aggregate with state machine
 //! - [`Trade`]: Executed trade aggregate
 //! - [`BlockTrade`]: Pre-arranged bilateral block trade
+//! - [`NettingBatch`]: Trades settled together by one net transaction
+//! - [`ErasureRequest`]: Reviewed erasure of a counterparty's identifiers
 //! - `Venue`: Liquidity venue configuration
 //!
 //! ## Entities
 //!
 //! - [`Quote`]: Price quote from a venue
 //! - `Counterparty`: Client or market maker
+//! - [`VenueCredentialSet`]: A counterparty's own credentials for a venue
 //! - `MmPerformanceMetrics`: Market maker performance tracking
 
 pub mod allocation;
@@ -21,23 +24,31 @@ pub mod block_trade;
 pub mod counter_quote;
 pub mod counterparty;
 pub mod delayed_report;
+pub mod erasure_request;
 pub mod mm_capacity;
 pub mod mm_incentive;
 pub mod mm_performance;
 pub mod negotiation;
+pub mod netting_batch;
 pub mod package_quote;
+pub mod platform_fee;
 pub mod quote;
+pub mod quote_history;
 pub mod quote_normalizer;
 pub mod rfq;
+pub mod rfq_template;
 pub mod settlement;
 pub mod streaming_quote;
 pub mod trade;
 pub mod venue;
+pub mod venue_config_change;
+pub mod venue_credential_set;
+pub mod webhook_subscription;
 
 #[cfg(test)]
 mod tests;
 
-pub use allocation::Allocation;
+pub use allocation::{Allocation, AllocationStatus, TradeAllocation};
 pub use anonymity::{AnonymityLevel, AnonymousRfqView, IdentityMapping};
 pub use block_trade::{
     BlockTrade, BlockTradeId, BlockTradeState, BlockTradeValidation, InvalidBlockTradeStateError,
@@ -45,9 +56,10 @@ pub use block_trade::{
 pub use counter_quote::{CounterQuote, CounterQuoteBuilder};
 pub use counterparty::{
     Counterparty, CounterpartyLimits, CounterpartyType, InvalidCounterpartyTypeError,
-    InvalidKycStatusError, KycStatus, WalletAddress,
+    InvalidKycStatusError, KycStatus, SettlementAddress, WalletAddress,
 };
 pub use delayed_report::{DelayedReport, TradeSummary};
+pub use erasure_request::{ErasureRequest, ErasureRequestStatus};
 pub use mm_capacity::{
     CapacityAdjustment, CapacityCheckResult, CapacityReservation, DEFAULT_MAX_CONCURRENT_QUOTES,
     DEFAULT_MAX_NOTIONAL_USD, MmCapacityConfig, MmCapacityConfigBuilder, MmCapacityState,
@@ -60,14 +72,24 @@ pub use mm_performance::{
     DEFAULT_MIN_RESPONSE_RATE_PCT, DEFAULT_WINDOW_DAYS, MmPerformanceEvent, MmPerformanceEventKind,
     MmPerformanceMetrics,
 };
-pub use negotiation::{DEFAULT_MAX_ROUNDS, MAX_ALLOWED_ROUNDS, Negotiation, NegotiationRound};
+pub use negotiation::{
+    DEFAULT_MAX_ROUNDS, MAX_ALLOWED_ROUNDS, NegotiatedTerms, Negotiation, NegotiationRound,
+};
+pub use netting_batch::{NettingBatch, NettingBatchStatus, NettingLeg};
 pub use package_quote::{LegPrice, PackageQuote, PackageQuoteBuilder};
-pub use quote::{Quote, QuoteBuilder, QuoteMetadata};
+pub use platform_fee::{
+    AssetClassFeeRate, DEFAULT_FEE_TIER, FeeBand, FeeWaiver, PlatformFeeSchedule,
+};
+pub use quote::{Quote, QuoteBuilder, QuoteFirmness, QuoteKind, QuoteLegPrice, QuoteMetadata};
+pub use quote_history::{
+    BestPricePoint, QuoteHistoryEntry, QuoteRank, QuoteStatus, RetiredQuote, best_price_timeline,
+};
 pub use quote_normalizer::{
     FxRate, NormalizationConfig, NormalizationConfigBuilder, NormalizationConfigRegistry,
     NormalizedQuote, QuoteType,
 };
-pub use rfq::{ComplianceResult, Rfq, RfqBuilder};
+pub use rfq::{ComplianceResult, DEFAULT_EXECUTION_TIMEOUT, Rfq, RfqBuilder, RfqSubject};
+pub use rfq_template::{RfqTemplate, RfqTemplateBuilder};
 pub use settlement::{
     IncentiveEvent, IncentiveReport, IncentiveSettlement, IncentiveSummary, ReportDetailLevel,
     SettlementError, SettlementId, SettlementPeriod, SettlementStatus, TradeIncentiveDetail,
@@ -77,5 +99,10 @@ pub use streaming_quote::{
     PriceLevel, StreamingQuote, StreamingQuoteConfig, StreamingQuoteConfigBuilder,
     StreamingQuoteId, StreamingQuoteStats,
 };
-pub use trade::{InvalidSettlementStateError, SettlementState, Trade};
-pub use venue::{InvalidVenueHealthError, Venue, VenueConfig, VenueHealth, VenueMetrics};
+pub use trade::{FeeComponent, FeeKind, InvalidSettlementStateError, SettlementState, Trade};
+pub use venue::{
+    InvalidVenueHealthError, Venue, VenueConfig, VenueHealth, VenueMetrics, VenueMode,
+};
+pub use venue_config_change::{VenueConfigChange, VenueSettings};
+pub use venue_credential_set::{VenueCredentialSet, VenueCredentials};
+pub use webhook_subscription::WebhookSubscription;
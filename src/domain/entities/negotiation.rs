@@ -16,6 +16,10 @@
 //!   └─────────┴→ Expired
 //! ```
 //!
+//! An acceptance whose terms fail the checks made before trading is
+//! reverted to `CounterPending` with [`Negotiation::revert_acceptance`],
+//! outside the state machine above.
+//!
 //! # Examples
 //!
 //! ```
@@ -39,11 +43,15 @@
 
 use crate::domain::entities::counter_quote::CounterQuote;
 use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::counter_conditions::SettlementPlan;
 use crate::domain::value_objects::negotiation_state::NegotiationState;
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{CounterpartyId, NegotiationId, OrderSide, Price, RfqId};
+use crate::domain::value_objects::{
+    CounterpartyId, NegotiationId, OrderSide, Price, Quantity, QuoteId, RfqId,
+};
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::time::Duration;
 
 /// Default maximum number of negotiation rounds.
 pub const DEFAULT_MAX_ROUNDS: u8 = 3;
@@ -147,6 +155,12 @@ impl NegotiationRound {
         self.responded_at = Some(Timestamp::now());
         self.accepted = Some(accepted);
     }
+
+    /// Withdraws the response, leaving the round pending again.
+    fn clear_response(&mut self) {
+        self.responded_at = None;
+        self.accepted = None;
+    }
 }
 
 impl fmt::Display for NegotiationRound {
@@ -167,6 +181,31 @@ impl fmt::Display for NegotiationRound {
     }
 }
 
+/// The terms a negotiation concluded on, from its accepted counter-quote.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegotiatedTerms {
+    /// The negotiation that agreed the terms.
+    pub negotiation_id: NegotiationId,
+    /// The RFQ under negotiation.
+    pub rfq_id: RfqId,
+    /// The RFQ quote the accepted counter answered.
+    pub quote_id: QuoteId,
+    /// The accepted counter-quote.
+    pub counter_quote_id: QuoteId,
+    /// The client side of the trade.
+    pub requester: CounterpartyId,
+    /// The market maker side of the trade.
+    pub mm_account: CounterpartyId,
+    /// The requester's side.
+    pub side: OrderSide,
+    /// The agreed price.
+    pub price: Price,
+    /// The agreed quantity.
+    pub quantity: Quantity,
+    /// Settlement chain and window agreed, if any.
+    pub settlement_plan: Option<SettlementPlan>,
+}
+
 /// Negotiation aggregate root managing multi-round counter-quote exchange.
 ///
 /// The [`Negotiation`] aggregate manages the complete lifecycle of a negotiation
@@ -232,6 +271,16 @@ pub struct Negotiation {
     created_at: Timestamp,
     /// When this negotiation was last updated.
     updated_at: Timestamp,
+    /// Why the last acceptance could not be executed, if it was reverted.
+    #[serde(default)]
+    conclusion_failure: Option<String>,
+    /// Version, incremented on every change, that stamps emitted events.
+    #[serde(default = "initial_version")]
+    version: u64,
+}
+
+fn initial_version() -> u64 {
+    1
 }
 
 impl Negotiation {
@@ -278,6 +327,8 @@ impl Negotiation {
             state: NegotiationState::Open,
             created_at: now,
             updated_at: now,
+            conclusion_failure: None,
+            version: initial_version(),
         })
     }
 
@@ -312,9 +363,32 @@ impl Negotiation {
             state,
             created_at,
             updated_at,
+            conclusion_failure: None,
+            version: initial_version(),
         }
     }
 
+    /// Restores why the last acceptance was reverted (for reconstruction
+    /// from storage).
+    #[must_use]
+    pub fn with_conclusion_failure(mut self, conclusion_failure: Option<String>) -> Self {
+        self.conclusion_failure = conclusion_failure;
+        self
+    }
+
+    /// Restores the version (for reconstruction from storage).
+    #[must_use]
+    pub fn with_version(mut self, version: u64) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Records a change: bumps the version and the update time.
+    fn touch(&mut self) {
+        self.version = self.version.saturating_add(1);
+        self.updated_at = Timestamp::now();
+    }
+
     fn transition_to(&mut self, target: NegotiationState) -> DomainResult<()> {
         if !self.state.can_transition_to(target) {
             return Err(DomainError::InvalidNegotiationStateTransition {
@@ -418,6 +492,16 @@ impl Negotiation {
         self.rounds.last().map(|r| r.counter_quote().price())
     }
 
+    /// Returns the version, incremented on every change.
+    ///
+    /// Events emitted for the negotiation carry it as their aggregate
+    /// version.
+    #[inline]
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
     /// Returns true if the negotiation is still active.
     #[inline]
     #[must_use]
@@ -425,6 +509,14 @@ impl Negotiation {
         self.state.is_active()
     }
 
+    /// Returns why the last acceptance could not be executed, if it was
+    /// reverted.
+    #[inline]
+    #[must_use]
+    pub fn conclusion_failure(&self) -> Option<&str> {
+        self.conclusion_failure.as_deref()
+    }
+
     // ========== State Transitions ==========
 
     /// Submits a counter-quote from either party.
@@ -435,6 +527,11 @@ impl Negotiation {
     /// - Price improves over previous round (if not the first round)
     /// - Counter is not expired
     /// - Submitter is a participant in this negotiation
+    /// - The counter's firm validity, if it sets one, does not outlast the
+    ///   time remaining in the negotiation
+    ///
+    /// A firm validity shortens the counter's `valid_until` to that many
+    /// seconds after submission.
     ///
     /// # Arguments
     ///
@@ -446,8 +543,26 @@ impl Negotiation {
     /// - `DomainError::MaxNegotiationRoundsReached` if max rounds exceeded
     /// - `DomainError::NoPriceImprovement` if price doesn't improve
     /// - `DomainError::QuoteExpired` if counter has expired
-    /// - `DomainError::ValidationError` if submitter is not a participant
+    /// - `DomainError::ValidationError` if submitter is not a participant, or
+    ///   the firm validity is zero or longer than the time remaining
     pub fn submit_counter(&mut self, counter: CounterQuote) -> DomainResult<()> {
+        self.submit_counter_at(counter, Timestamp::now())
+    }
+
+    /// Submits a counter-quote, checking its expiry against `now`.
+    ///
+    /// Same validation as [`submit_counter`](Self::submit_counter), for
+    /// callers that read the time from a
+    /// [`Clock`](crate::domain::value_objects::Clock).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`submit_counter`](Self::submit_counter).
+    pub fn submit_counter_at(
+        &mut self,
+        mut counter: CounterQuote,
+        now: Timestamp,
+    ) -> DomainResult<()> {
         // Validate state allows counter submission
         if self.state.is_terminal() {
             return Err(DomainError::InvalidNegotiationStateTransition {
@@ -457,7 +572,7 @@ impl Negotiation {
         }
 
         // Validate counter is not expired
-        if counter.is_expired() {
+        if counter.is_expired_at(now) {
             return Err(DomainError::QuoteExpired(
                 "counter-quote has expired".to_string(),
             ));
@@ -482,6 +597,12 @@ impl Negotiation {
             self.validate_price_improvement(previous_price, counter.price())?;
         }
 
+        // Apply the firm validity, which may only shorten the time left
+        if let Some(valid_for_secs) = counter.conditions().and_then(|c| c.valid_for_secs) {
+            let firm_until = self.firm_until(&counter, valid_for_secs, now)?;
+            counter.shorten_validity(firm_until);
+        }
+
         // Mark previous round as responded if pending
         if let Some(last) = self.rounds.last_mut()
             && !last.is_responded()
@@ -517,6 +638,7 @@ impl Negotiation {
             }
         }
 
+        self.touch();
         Ok(())
     }
 
@@ -533,12 +655,39 @@ impl Negotiation {
             ));
         }
 
+        self.transition_to(NegotiationState::Accepted)?;
+
         // Mark latest round as accepted
         if let Some(last) = self.rounds.last_mut() {
             last.respond(true);
         }
+        self.conclusion_failure = None;
+        self.touch();
+        Ok(())
+    }
 
-        self.transition_to(NegotiationState::Accepted)
+    /// Reverts an acceptance whose terms could not be executed, returning
+    /// the latest counter-quote to pending with `reason` recorded.
+    ///
+    /// Transitions: Accepted → CounterPending
+    ///
+    /// # Errors
+    ///
+    /// - `DomainError::InvalidNegotiationStateTransition` if not accepted
+    pub fn revert_acceptance(&mut self, reason: impl Into<String>) -> DomainResult<()> {
+        if self.state != NegotiationState::Accepted {
+            return Err(DomainError::InvalidNegotiationStateTransition {
+                from: self.state,
+                to: NegotiationState::CounterPending,
+            });
+        }
+        if let Some(last) = self.rounds.last_mut() {
+            last.clear_response();
+        }
+        self.state = NegotiationState::CounterPending;
+        self.conclusion_failure = Some(reason.into());
+        self.touch();
+        Ok(())
     }
 
     /// Rejects the negotiation.
@@ -554,7 +703,9 @@ impl Negotiation {
             last.respond(false);
         }
 
-        self.transition_to(NegotiationState::Rejected)
+        self.transition_to(NegotiationState::Rejected)?;
+        self.touch();
+        Ok(())
     }
 
     /// Expires the negotiation.
@@ -563,7 +714,106 @@ impl Negotiation {
     ///
     /// - `DomainError::InvalidNegotiationStateTransition` if in terminal state
     pub fn expire(&mut self) -> DomainResult<()> {
-        self.transition_to(NegotiationState::Expired)
+        self.transition_to(NegotiationState::Expired)?;
+        self.touch();
+        Ok(())
+    }
+
+    /// Returns the counter-quote that was accepted, if the negotiation
+    /// reached agreement.
+    #[must_use]
+    pub fn accepted_counter(&self) -> Option<&CounterQuote> {
+        if self.state != NegotiationState::Accepted {
+            return None;
+        }
+        self.rounds
+            .last()
+            .filter(|round| round.accepted() == Some(true))
+            .map(NegotiationRound::counter_quote)
+    }
+
+    /// Returns the terms to execute, if the negotiation reached agreement.
+    #[must_use]
+    pub fn negotiated_terms(&self) -> Option<NegotiatedTerms> {
+        let counter = self.accepted_counter()?;
+        Some(NegotiatedTerms {
+            negotiation_id: self.id,
+            rfq_id: self.rfq_id,
+            quote_id: counter.original_quote_id(),
+            counter_quote_id: counter.id(),
+            requester: self.requester.clone(),
+            mm_account: self.mm_account.clone(),
+            side: self.side,
+            price: counter.price(),
+            quantity: counter.quantity(),
+            settlement_plan: self.settlement_plan(),
+        })
+    }
+
+    /// Returns the settlement terms of the accepted counter-quote, if it
+    /// set any.
+    #[must_use]
+    pub fn settlement_plan(&self) -> Option<SettlementPlan> {
+        self.accepted_counter()?.conditions()?.settlement_plan()
+    }
+
+    /// Returns the time left as of `now` before the pending counter-quote
+    /// lapses, or `None` if no counter is pending.
+    #[must_use]
+    pub fn remaining_at(&self, now: Timestamp) -> Option<Duration> {
+        if self.state != NegotiationState::CounterPending {
+            return None;
+        }
+        self.latest_round()
+            .map(|round| now.duration_until(&round.counter_quote().valid_until()))
+    }
+
+    /// Returns when a counter firm for `valid_for_secs` lapses.
+    ///
+    /// The firm validity must fit within both the counter's own validity
+    /// and the time remaining on the pending counter.
+    fn firm_until(
+        &self,
+        counter: &CounterQuote,
+        valid_for_secs: u64,
+        now: Timestamp,
+    ) -> DomainResult<Timestamp> {
+        let own = now.duration_until(&counter.valid_until());
+        let available = self.remaining_at(now).map_or(own, |left| left.min(own));
+        if valid_for_secs == 0 || Duration::from_secs(valid_for_secs) > available {
+            return Err(DomainError::ValidationError(format!(
+                "counter validity of {valid_for_secs}s must be between 1s and the {}s remaining in the negotiation",
+                available.as_secs()
+            )));
+        }
+        Ok(now.add_secs(i64::try_from(valid_for_secs).unwrap_or(i64::MAX)))
+    }
+
+    /// Returns true if the pending counter-quote has lapsed as of `now`.
+    ///
+    /// A negotiation times out when it is waiting on a response and the
+    /// latest counter-quote's validity has passed.
+    #[must_use]
+    pub fn is_timed_out_at(&self, now: Timestamp) -> bool {
+        self.state == NegotiationState::CounterPending
+            && self
+                .latest_round()
+                .is_some_and(|round| round.counter_quote().is_expired_at(now))
+    }
+
+    /// Expires the negotiation if it has timed out as of `now`.
+    ///
+    /// Returns `true` if the negotiation was expired.
+    ///
+    /// # Errors
+    ///
+    /// - `DomainError::InvalidNegotiationStateTransition` if the transition is not allowed
+    pub fn expire_if_timed_out_at(&mut self, now: Timestamp) -> DomainResult<bool> {
+        if !self.is_timed_out_at(now) {
+            return Ok(false);
+        }
+        self.expire()?;
+        Ok(true)
     }
 
     /// Validates that the proposed price improves over the previous price.
@@ -985,6 +1235,134 @@ mod tests {
                 Err(DomainError::InvalidNegotiationStateTransition { .. })
             ));
         }
+
+        #[test]
+        fn times_out_when_pending_counter_lapses() {
+            let mut neg = create_test_negotiation(OrderSide::Buy);
+            let c1 = make_counter(neg.rfq_id(), test_mm(), 50000.0, 1);
+            let valid_until = c1.valid_until();
+            neg.submit_counter(c1).unwrap();
+
+            assert!(!neg.is_timed_out_at(valid_until));
+            assert!(!neg.expire_if_timed_out_at(valid_until).unwrap());
+            assert_eq!(neg.state(), NegotiationState::CounterPending);
+
+            let later = valid_until.add_secs(1);
+            assert!(neg.is_timed_out_at(later));
+            assert!(neg.expire_if_timed_out_at(later).unwrap());
+            assert_eq!(neg.state(), NegotiationState::Expired);
+            assert!(!neg.is_timed_out_at(later));
+        }
+
+        #[test]
+        fn open_negotiation_does_not_time_out() {
+            let neg = create_test_negotiation(OrderSide::Buy);
+            assert!(!neg.is_timed_out_at(future_timestamp().add_secs(3600)));
+        }
+
+        #[test]
+        fn submit_counter_at_rejects_counter_lapsed_at_now() {
+            let mut neg = create_test_negotiation(OrderSide::Buy);
+            let c1 = make_counter(neg.rfq_id(), test_mm(), 50000.0, 1);
+            let later = c1.valid_until().add_secs(1);
+
+            let result = neg.submit_counter_at(c1, later);
+            assert!(matches!(result, Err(DomainError::QuoteExpired(_))));
+            assert_eq!(neg.round_count(), 0);
+        }
+    }
+
+    mod conditions {
+        use super::*;
+        use crate::domain::value_objects::counter_conditions::CounterConditions;
+        use crate::domain::value_objects::{Blockchain, SettlementWindow};
+
+        fn firm_for(counter: CounterQuote, secs: u64) -> CounterQuote {
+            counter.with_conditions(CounterConditions {
+                valid_for_secs: Some(secs),
+                settlement_chain: Some(Blockchain::Arbitrum),
+                settlement_window: Some(SettlementWindow::Instant),
+                note: Some("T+0 only".to_string()),
+            })
+        }
+
+        #[test]
+        fn firm_validity_may_only_shorten_remaining_time() {
+            let mut neg = create_test_negotiation(OrderSide::Buy);
+            let now = Timestamp::now();
+            let c1 = make_counter(neg.rfq_id(), test_requester(), 50000.0, 1);
+            let c1_until = c1.valid_until();
+            neg.submit_counter_at(c1, now).unwrap();
+            let remaining = neg.remaining_at(now).unwrap().as_secs();
+
+            let too_long = firm_for(make_counter(neg.rfq_id(), test_mm(), 49900.0, 2), 600);
+            let result = neg.submit_counter_at(too_long, now);
+            assert!(matches!(result, Err(DomainError::ValidationError(_))));
+            let zero = firm_for(make_counter(neg.rfq_id(), test_mm(), 49900.0, 2), 0);
+            assert!(neg.submit_counter_at(zero, now).is_err());
+            assert_eq!(neg.round_count(), 1);
+
+            let firm = firm_for(make_counter(neg.rfq_id(), test_mm(), 49900.0, 2), 30);
+            neg.submit_counter_at(firm, now).unwrap();
+            let counter = neg.latest_round().unwrap().counter_quote();
+            assert_eq!(counter.valid_until(), now.add_secs(30));
+            assert!(counter.valid_until() < c1_until);
+            assert!(neg.remaining_at(now).unwrap().as_secs() < remaining);
+            assert!(neg.is_timed_out_at(now.add_secs(31)));
+        }
+
+        #[test]
+        fn accepted_counter_carries_settlement_plan() {
+            let mut neg = create_test_negotiation(OrderSide::Buy);
+            let counter = firm_for(make_counter(neg.rfq_id(), test_mm(), 50000.0, 1), 60);
+            neg.submit_counter(counter).unwrap();
+            assert!(neg.settlement_plan().is_none());
+
+            neg.accept().unwrap();
+            let plan = neg.settlement_plan().unwrap();
+            assert_eq!(plan.chain, Some(Blockchain::Arbitrum));
+            assert_eq!(plan.window, Some(SettlementWindow::Instant));
+            assert_eq!(plan.note.as_deref(), Some("T+0 only"));
+        }
+
+        #[test]
+        fn negotiated_terms_come_from_accepted_counter() {
+            let mut neg = create_test_negotiation(OrderSide::Buy);
+            let counter = make_counter(neg.rfq_id(), test_mm(), 49500.0, 1);
+            let (counter_id, quote_id) = (counter.id(), counter.original_quote_id());
+            neg.submit_counter(counter).unwrap();
+            assert!(neg.negotiated_terms().is_none());
+
+            neg.accept().unwrap();
+            let terms = neg.negotiated_terms().unwrap();
+            assert_eq!(terms.negotiation_id, neg.id());
+            assert_eq!(terms.quote_id, quote_id);
+            assert_eq!(terms.counter_quote_id, counter_id);
+            assert_eq!(terms.requester, test_requester());
+            assert_eq!(terms.mm_account, test_mm());
+            assert_eq!(terms.side, OrderSide::Buy);
+            assert_eq!(terms.price, Price::new(49500.0).unwrap());
+            assert_eq!(terms.quantity, Quantity::new(1.0).unwrap());
+        }
+
+        #[test]
+        fn revert_acceptance_reopens_latest_counter() {
+            let mut neg = create_test_negotiation(OrderSide::Buy);
+            assert!(neg.revert_acceptance("too early").is_err());
+            neg.submit_counter(make_counter(neg.rfq_id(), test_mm(), 49500.0, 1))
+                .unwrap();
+            neg.accept().unwrap();
+
+            neg.revert_acceptance("limit exceeded").unwrap();
+            assert_eq!(neg.state(), NegotiationState::CounterPending);
+            assert_eq!(neg.conclusion_failure(), Some("limit exceeded"));
+            assert!(!neg.latest_round().unwrap().is_responded());
+            assert!(neg.negotiated_terms().is_none());
+
+            neg.accept().unwrap();
+            assert!(neg.conclusion_failure().is_none());
+            assert!(neg.negotiated_terms().is_some());
+        }
     }
 
     mod multi_round {
@@ -1038,6 +1416,50 @@ mod tests {
         }
     }
 
+    mod version {
+        use super::*;
+
+        #[test]
+        fn version_increments_once_per_change() {
+            let mut neg = create_test_negotiation(OrderSide::Buy);
+            let rfq_id = neg.rfq_id();
+            assert_eq!(neg.version(), 1);
+
+            neg.submit_counter(make_counter(rfq_id, test_mm(), 50000.0, 1))
+                .unwrap();
+            assert_eq!(neg.version(), 2);
+            neg.submit_counter(make_counter(rfq_id, test_requester(), 49000.0, 2))
+                .unwrap();
+            assert_eq!(
+                neg.version(),
+                3,
+                "re-opening a pending counter is one change"
+            );
+
+            neg.accept().unwrap();
+            neg.revert_acceptance("venue rejected").unwrap();
+            neg.reject().unwrap();
+            assert_eq!(neg.version(), 6);
+
+            assert!(neg.expire().is_err());
+            assert_eq!(neg.version(), 6, "refused changes keep the version");
+        }
+
+        #[test]
+        fn version_survives_serde_and_defaults_for_old_rows() {
+            let neg = create_test_negotiation(OrderSide::Buy).with_version(4);
+            let json = serde_json::to_value(&neg).unwrap();
+            let deserialized: Negotiation = serde_json::from_value(json.clone()).unwrap();
+            assert_eq!(deserialized.version(), 4);
+
+            let mut old = json.as_object().unwrap().clone();
+            old.remove("version");
+            let deserialized: Negotiation =
+                serde_json::from_value(serde_json::Value::Object(old)).unwrap();
+            assert_eq!(deserialized.version(), 1);
+        }
+    }
+
     mod display {
         use super::*;
 
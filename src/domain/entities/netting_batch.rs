@@ -0,0 +1,529 @@
+//! # Netting Batch Aggregate
+//!
+//! A group of pending trades settled with a single net transaction.
+//!
+//! This module provides the [`NettingBatch`] aggregate. A batch collects
+//! trades between the same client and market maker venue, on the same
+//! instrument and chain, executed within one settlement window. Instead of
+//! settling every trade on-chain, only the net base and quote amounts are
+//! transferred.
+//!
+//! Net amounts are from the client's perspective: a client buy adds the
+//! quantity to the base amount and subtracts the notional from the quote
+//! amount; a sell does the opposite. A positive amount is delivered to the
+//! client, a negative amount is delivered by the client.
+//!
+//! # Lifecycle
+//!
+//! ```text
+//! Open → Submitted → Settled
+//!  ↓  ↘      ↓
+//!  ↓   ↘→ Failed
+//!  ↓
+//! Cancelled
+//! ```
+//!
+//! A batch whose amounts net to zero settles without a transaction.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::entities::netting_batch::{NettingBatch, NettingLeg};
+//! use otc_rfq::domain::value_objects::enums::Blockchain;
+//! use otc_rfq::domain::value_objects::timestamp::Timestamp;
+//! use otc_rfq::domain::value_objects::{
+//!     CounterpartyId, OrderSide, Price, Quantity, Symbol, TradeId, VenueId,
+//! };
+//!
+//! let now = Timestamp::now();
+//! let batch = NettingBatch::new(
+//!     CounterpartyId::new("client-1"),
+//!     VenueId::new("mm-1"),
+//!     Symbol::new("ETH/USDC").unwrap(),
+//!     Blockchain::Ethereum,
+//!     now,
+//!     now.add_secs(3600),
+//!     &[
+//!         NettingLeg::new(TradeId::new_v4(), OrderSide::Buy, Price::new(2000.0).unwrap(), Quantity::new(2.0).unwrap()),
+//!         NettingLeg::new(TradeId::new_v4(), OrderSide::Sell, Price::new(2000.0).unwrap(), Quantity::new(2.0).unwrap()),
+//!     ],
+//! )
+//! .unwrap();
+//!
+//! assert!(batch.nets_to_zero());
+//! ```
+
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::arithmetic::{ArithmeticResult, CheckedArithmetic};
+use crate::domain::value_objects::enums::Blockchain;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    CounterpartyId, NettingBatchId, OrderSide, Price, Quantity, Symbol, TradeId, VenueId,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use utoipa::ToSchema;
+
+/// Lifecycle state of a [`NettingBatch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NettingBatchStatus {
+    /// Trades are reserved; the batch can still be cancelled.
+    Open,
+    /// The net transaction was sent and awaits confirmation.
+    Submitted,
+    /// The batch settled; its trades are settled via netting (terminal).
+    Settled,
+    /// The net transaction failed; its trades were released (terminal).
+    Failed,
+    /// The batch was cancelled before submission (terminal).
+    Cancelled,
+}
+
+impl NettingBatchStatus {
+    /// Returns true if this is a terminal state.
+    #[inline]
+    #[must_use]
+    pub const fn is_terminal(&self) -> bool {
+        matches!(self, Self::Settled | Self::Failed | Self::Cancelled)
+    }
+}
+
+impl fmt::Display for NettingBatchStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Open => "OPEN",
+            Self::Submitted => "SUBMITTED",
+            Self::Settled => "SETTLED",
+            Self::Failed => "FAILED",
+            Self::Cancelled => "CANCELLED",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A trade contributing to a [`NettingBatch`], seen from the client's side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NettingLeg {
+    /// The constituent trade.
+    pub trade_id: TradeId,
+    /// Side the client traded.
+    pub side: OrderSide,
+    /// Execution price.
+    pub price: Price,
+    /// Executed quantity.
+    pub quantity: Quantity,
+}
+
+impl NettingLeg {
+    /// Creates a netting leg.
+    #[must_use]
+    pub const fn new(trade_id: TradeId, side: OrderSide, price: Price, quantity: Quantity) -> Self {
+        Self {
+            trade_id,
+            side,
+            price,
+            quantity,
+        }
+    }
+}
+
+/// Pending trades between one client and venue, settled by one net transfer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NettingBatch {
+    id: NettingBatchId,
+    client_id: CounterpartyId,
+    venue_id: VenueId,
+    symbol: Symbol,
+    blockchain: Blockchain,
+    trade_ids: Vec<TradeId>,
+    net_base: Decimal,
+    net_quote: Decimal,
+    window_start: Timestamp,
+    window_end: Timestamp,
+    status: NettingBatchStatus,
+    settlement_tx_ref: Option<String>,
+    failure_reason: Option<String>,
+    created_at: Timestamp,
+    updated_at: Timestamp,
+}
+
+impl NettingBatch {
+    /// Creates an open batch netting `legs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if there are no legs, a trade
+    /// appears twice, the window ends before it starts, or the net amounts
+    /// overflow.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        client_id: CounterpartyId,
+        venue_id: VenueId,
+        symbol: Symbol,
+        blockchain: Blockchain,
+        window_start: Timestamp,
+        window_end: Timestamp,
+        legs: &[NettingLeg],
+    ) -> DomainResult<Self> {
+        if legs.is_empty() {
+            return Err(DomainError::ValidationError(
+                "a netting batch needs at least one trade".to_string(),
+            ));
+        }
+        if window_end < window_start {
+            return Err(DomainError::ValidationError(
+                "netting window ends before it starts".to_string(),
+            ));
+        }
+        let mut seen = HashSet::new();
+        if let Some(duplicate) = legs.iter().find(|leg| !seen.insert(leg.trade_id)) {
+            return Err(DomainError::ValidationError(format!(
+                "trade {} appears twice in the netting batch",
+                duplicate.trade_id
+            )));
+        }
+
+        let (net_base, net_quote) = Self::net(legs)?;
+        let now = Timestamp::now();
+        Ok(Self {
+            id: NettingBatchId::new_v4(),
+            client_id,
+            venue_id,
+            symbol,
+            blockchain,
+            trade_ids: legs.iter().map(|leg| leg.trade_id).collect(),
+            net_base,
+            net_quote,
+            window_start,
+            window_end,
+            status: NettingBatchStatus::Open,
+            settlement_tx_ref: None,
+            failure_reason: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Sums the legs into client-perspective base and quote amounts.
+    fn net(legs: &[NettingLeg]) -> ArithmeticResult<(Decimal, Decimal)> {
+        legs.iter()
+            .try_fold((Decimal::ZERO, Decimal::ZERO), |(base, quote), leg| {
+                let quantity = leg.quantity.get();
+                let notional = leg.price.get().safe_mul(quantity)?;
+                match leg.side {
+                    OrderSide::Buy => Ok((base.safe_add(quantity)?, quote.safe_sub(notional)?)),
+                    OrderSide::Sell => Ok((base.safe_sub(quantity)?, quote.safe_add(notional)?)),
+                }
+            })
+    }
+
+    // ========== Accessors ==========
+
+    /// Returns the batch ID.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> NettingBatchId {
+        self.id
+    }
+
+    /// Returns the client whose trades are netted.
+    #[inline]
+    #[must_use]
+    pub fn client_id(&self) -> &CounterpartyId {
+        &self.client_id
+    }
+
+    /// Returns the market maker venue on the other side of the trades.
+    #[inline]
+    #[must_use]
+    pub fn venue_id(&self) -> &VenueId {
+        &self.venue_id
+    }
+
+    /// Returns the traded instrument symbol.
+    #[inline]
+    #[must_use]
+    pub fn symbol(&self) -> &Symbol {
+        &self.symbol
+    }
+
+    /// Returns the chain the batch settles on.
+    #[inline]
+    #[must_use]
+    pub fn blockchain(&self) -> Blockchain {
+        self.blockchain
+    }
+
+    /// Returns the constituent trades.
+    #[inline]
+    #[must_use]
+    pub fn trade_ids(&self) -> &[TradeId] {
+        &self.trade_ids
+    }
+
+    /// Returns the net base amount delivered to the client (negative when
+    /// the client delivers).
+    #[inline]
+    #[must_use]
+    pub fn net_base(&self) -> Decimal {
+        self.net_base
+    }
+
+    /// Returns the net quote amount delivered to the client (negative when
+    /// the client delivers).
+    #[inline]
+    #[must_use]
+    pub fn net_quote(&self) -> Decimal {
+        self.net_quote
+    }
+
+    /// Returns true if nothing needs to be transferred.
+    #[inline]
+    #[must_use]
+    pub fn nets_to_zero(&self) -> bool {
+        self.net_base.is_zero() && self.net_quote.is_zero()
+    }
+
+    /// Returns the start of the settlement window (inclusive).
+    #[inline]
+    #[must_use]
+    pub fn window_start(&self) -> Timestamp {
+        self.window_start
+    }
+
+    /// Returns the end of the settlement window (exclusive).
+    #[inline]
+    #[must_use]
+    pub fn window_end(&self) -> Timestamp {
+        self.window_end
+    }
+
+    /// Returns the batch status.
+    #[inline]
+    #[must_use]
+    pub fn status(&self) -> NettingBatchStatus {
+        self.status
+    }
+
+    /// Returns the net settlement transaction reference, if submitted.
+    #[inline]
+    #[must_use]
+    pub fn settlement_tx_ref(&self) -> Option<&str> {
+        self.settlement_tx_ref.as_deref()
+    }
+
+    /// Returns why the batch failed, if it did.
+    #[inline]
+    #[must_use]
+    pub fn failure_reason(&self) -> Option<&str> {
+        self.failure_reason.as_deref()
+    }
+
+    /// Returns when the batch was created.
+    #[inline]
+    #[must_use]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    /// Returns when the batch was last updated.
+    #[inline]
+    #[must_use]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    // ========== State Transitions ==========
+
+    /// Records the submitted net transaction.
+    ///
+    /// Transitions: Open → Submitted
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if the batch is not open.
+    pub fn submit(&mut self, tx_ref: impl Into<String>) -> DomainResult<()> {
+        self.require(NettingBatchStatus::Open, "submit")?;
+        self.settlement_tx_ref = Some(tx_ref.into());
+        self.transition_to(NettingBatchStatus::Submitted);
+        Ok(())
+    }
+
+    /// Marks the batch settled.
+    ///
+    /// Transitions: Submitted → Settled, or Open → Settled when the batch
+    /// nets to zero and needs no transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` otherwise.
+    pub fn confirm(&mut self) -> DomainResult<()> {
+        let allowed = match self.status {
+            NettingBatchStatus::Submitted => true,
+            NettingBatchStatus::Open => self.nets_to_zero(),
+            _ => false,
+        };
+        if !allowed {
+            return Err(self.invalid("confirm"));
+        }
+        self.transition_to(NettingBatchStatus::Settled);
+        Ok(())
+    }
+
+    /// Marks the batch failed.
+    ///
+    /// Transitions: Open | Submitted → Failed
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if the batch is terminal.
+    pub fn fail(&mut self, reason: impl Into<String>) -> DomainResult<()> {
+        if self.status.is_terminal() {
+            return Err(self.invalid("fail"));
+        }
+        self.failure_reason = Some(reason.into());
+        self.transition_to(NettingBatchStatus::Failed);
+        Ok(())
+    }
+
+    /// Cancels the batch before submission.
+    ///
+    /// Transitions: Open → Cancelled
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if the batch is not open.
+    pub fn cancel(&mut self) -> DomainResult<()> {
+        self.require(NettingBatchStatus::Open, "cancel")?;
+        self.transition_to(NettingBatchStatus::Cancelled);
+        Ok(())
+    }
+
+    fn require(&self, status: NettingBatchStatus, action: &str) -> DomainResult<()> {
+        if self.status == status {
+            Ok(())
+        } else {
+            Err(self.invalid(action))
+        }
+    }
+
+    fn invalid(&self, action: &str) -> DomainError {
+        DomainError::InvalidState(format!(
+            "cannot {} netting batch in {}",
+            action, self.status
+        ))
+    }
+
+    fn transition_to(&mut self, status: NettingBatchStatus) {
+        self.status = status;
+        self.updated_at = Timestamp::now();
+    }
+}
+
+impl fmt::Display for NettingBatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "NettingBatch({} {} {} trades [{}])",
+            self.id,
+            self.symbol,
+            self.trade_ids.len(),
+            self.status
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn leg(side: OrderSide, price: f64, quantity: f64) -> NettingLeg {
+        NettingLeg::new(
+            TradeId::new_v4(),
+            side,
+            Price::new(price).unwrap(),
+            Quantity::new(quantity).unwrap(),
+        )
+    }
+
+    fn batch(legs: &[NettingLeg]) -> DomainResult<NettingBatch> {
+        let start = Timestamp::from_secs(1_700_000_000).unwrap();
+        NettingBatch::new(
+            CounterpartyId::new("client-1"),
+            VenueId::new("mm-1"),
+            Symbol::new("ETH/USDC").unwrap(),
+            Blockchain::Ethereum,
+            start,
+            start.add_secs(3600),
+            legs,
+        )
+    }
+
+    #[test]
+    fn opposite_trades_at_same_price_net_to_zero() {
+        let batch = batch(&[
+            leg(OrderSide::Buy, 2000.0, 1.5),
+            leg(OrderSide::Sell, 2000.0, 1.5),
+        ])
+        .unwrap();
+
+        assert!(batch.nets_to_zero());
+        assert_eq!(batch.trade_ids().len(), 2);
+        assert_eq!(batch.status(), NettingBatchStatus::Open);
+    }
+
+    #[test]
+    fn nets_from_client_perspective() {
+        let batch = batch(&[
+            leg(OrderSide::Buy, 2000.0, 3.0),
+            leg(OrderSide::Sell, 2100.0, 1.0),
+        ])
+        .unwrap();
+
+        assert_eq!(batch.net_base(), Decimal::new(2, 0));
+        assert_eq!(batch.net_quote(), Decimal::new(-3900, 0));
+        assert!(!batch.nets_to_zero());
+    }
+
+    #[test]
+    fn rejects_empty_and_duplicate_legs() {
+        assert!(matches!(batch(&[]), Err(DomainError::ValidationError(_))));
+
+        let repeated = leg(OrderSide::Buy, 100.0, 1.0);
+        assert!(matches!(
+            batch(&[repeated, repeated]),
+            Err(DomainError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn only_open_batches_can_be_cancelled() {
+        let mut batch = batch(&[leg(OrderSide::Buy, 100.0, 1.0)]).unwrap();
+        batch.submit("0xabc").unwrap();
+
+        assert!(matches!(batch.cancel(), Err(DomainError::InvalidState(_))));
+        assert_eq!(batch.settlement_tx_ref(), Some("0xabc"));
+
+        batch.confirm().unwrap();
+        assert_eq!(batch.status(), NettingBatchStatus::Settled);
+        assert!(batch.fail("late").is_err());
+    }
+
+    #[test]
+    fn unsubmitted_batch_confirms_only_when_netting_to_zero() {
+        let mut one_sided = batch(&[leg(OrderSide::Sell, 100.0, 1.0)]).unwrap();
+        assert!(one_sided.confirm().is_err());
+
+        let mut flat = batch(&[
+            leg(OrderSide::Buy, 100.0, 1.0),
+            leg(OrderSide::Sell, 100.0, 1.0),
+        ])
+        .unwrap();
+        flat.confirm().unwrap();
+        assert!(flat.status().is_terminal());
+    }
+}
@@ -440,6 +440,13 @@ impl PackageQuote {
         self.valid_until.is_expired()
     }
 
+    /// Returns true if this quote has expired as of `now`.
+    #[inline]
+    #[must_use]
+    pub fn is_expired_at(&self, now: Timestamp) -> bool {
+        self.valid_until.is_expired_at(now)
+    }
+
     /// Returns the time remaining until expiry.
     ///
     /// Returns `Duration::ZERO` if already expired.
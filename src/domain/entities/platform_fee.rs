@@ -0,0 +1,519 @@
+//! # Platform Fees
+//!
+//! Fee schedules and waivers used to charge the platform fee on trades.
+//!
+//! This module provides the [`PlatformFeeSchedule`] aggregate, its
+//! per-asset-class [`AssetClassFeeRate`] and the per-counterparty
+//! [`FeeWaiver`].
+//!
+//! A schedule belongs to a counterparty tier and lists the counterparties
+//! assigned to it. Counterparties that are not assigned to any tier are
+//! charged from the [`DEFAULT_FEE_TIER`] schedule, if one exists. Each rate
+//! is a set of notional bands: a trade is charged the basis points of the
+//! highest band whose lower bound it reaches, so a notional exactly at a
+//! band threshold falls into that band. The result is then held between
+//! the rate's minimum and maximum fee.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::entities::platform_fee::{AssetClassFeeRate, FeeBand};
+//! use otc_rfq::domain::value_objects::enums::AssetClass;
+//! use rust_decimal::Decimal;
+//!
+//! let rate = AssetClassFeeRate::new(
+//!     AssetClass::CryptoSpot,
+//!     vec![
+//!         FeeBand::new(Decimal::ZERO, Decimal::new(5, 0)),
+//!         FeeBand::new(Decimal::new(1_000_000, 0), Decimal::new(2, 0)),
+//!     ],
+//!     Some(Decimal::new(10, 0)),
+//!     None,
+//! )
+//! .unwrap();
+//!
+//! // 5 bps of 10,000 is 5, raised to the 10 minimum.
+//! assert_eq!(rate.fee_for(Decimal::new(10_000, 0)).unwrap(), Decimal::new(10, 0));
+//! // 2 bps of 1,000,000 is 200.
+//! assert_eq!(rate.fee_for(Decimal::new(1_000_000, 0)).unwrap(), Decimal::new(200, 0));
+//! ```
+
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::CounterpartyId;
+use crate::domain::value_objects::arithmetic::{ArithmeticResult, BPS_PER_UNIT, CheckedArithmetic};
+use crate::domain::value_objects::enums::AssetClass;
+use crate::domain::value_objects::timestamp::Timestamp;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Tier whose schedule applies to counterparties not assigned to any tier.
+pub const DEFAULT_FEE_TIER: &str = "default";
+
+/// A notional band of an [`AssetClassFeeRate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeBand {
+    /// Lowest notional (inclusive) charged at this band's rate.
+    pub min_notional: Decimal,
+    /// Fee in basis points of notional.
+    pub bps: Decimal,
+}
+
+impl FeeBand {
+    /// Creates a band starting at `min_notional`.
+    #[must_use]
+    pub const fn new(min_notional: Decimal, bps: Decimal) -> Self {
+        Self { min_notional, bps }
+    }
+}
+
+/// Tiered basis-point rate for one asset class.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetClassFeeRate {
+    asset_class: AssetClass,
+    bands: Vec<FeeBand>,
+    min_fee: Option<Decimal>,
+    max_fee: Option<Decimal>,
+}
+
+impl AssetClassFeeRate {
+    /// Creates a rate from its notional bands and optional fee caps.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if there are no bands, the
+    /// first band does not start at zero, band thresholds are not strictly
+    /// increasing, a rate or cap is negative, or the minimum fee exceeds
+    /// the maximum.
+    pub fn new(
+        asset_class: AssetClass,
+        bands: Vec<FeeBand>,
+        min_fee: Option<Decimal>,
+        max_fee: Option<Decimal>,
+    ) -> DomainResult<Self> {
+        let Some(first) = bands.first() else {
+            return Err(DomainError::ValidationError(format!(
+                "fee rate for {asset_class} needs at least one band"
+            )));
+        };
+        if !first.min_notional.is_zero() {
+            return Err(DomainError::ValidationError(format!(
+                "first fee band for {asset_class} must start at zero notional"
+            )));
+        }
+        if bands
+            .windows(2)
+            .any(|pair| matches!(pair, [a, b] if a.min_notional >= b.min_notional))
+        {
+            return Err(DomainError::ValidationError(format!(
+                "fee bands for {asset_class} must have strictly increasing thresholds"
+            )));
+        }
+        if bands.iter().any(|band| band.bps.is_sign_negative()) {
+            return Err(DomainError::ValidationError(format!(
+                "fee bands for {asset_class} must not have negative rates"
+            )));
+        }
+        if min_fee.is_some_and(|fee| fee.is_sign_negative())
+            || max_fee.is_some_and(|fee| fee.is_sign_negative())
+        {
+            return Err(DomainError::ValidationError(format!(
+                "fee caps for {asset_class} must not be negative"
+            )));
+        }
+        if let (Some(min), Some(max)) = (min_fee, max_fee)
+            && min > max
+        {
+            return Err(DomainError::ValidationError(format!(
+                "minimum fee {min} for {asset_class} exceeds maximum fee {max}"
+            )));
+        }
+
+        Ok(Self {
+            asset_class,
+            bands,
+            min_fee,
+            max_fee,
+        })
+    }
+
+    /// Returns the asset class this rate applies to.
+    #[inline]
+    #[must_use]
+    pub fn asset_class(&self) -> AssetClass {
+        self.asset_class
+    }
+
+    /// Returns the notional bands, lowest first.
+    #[inline]
+    #[must_use]
+    pub fn bands(&self) -> &[FeeBand] {
+        &self.bands
+    }
+
+    /// Returns the minimum fee charged, if any.
+    #[inline]
+    #[must_use]
+    pub fn min_fee(&self) -> Option<Decimal> {
+        self.min_fee
+    }
+
+    /// Returns the maximum fee charged, if any.
+    #[inline]
+    #[must_use]
+    pub fn max_fee(&self) -> Option<Decimal> {
+        self.max_fee
+    }
+
+    /// Returns the basis points charged on `notional`.
+    #[must_use]
+    pub fn bps_for(&self, notional: Decimal) -> Decimal {
+        self.bands
+            .iter()
+            .rev()
+            .find(|band| band.min_notional <= notional)
+            .map_or(Decimal::ZERO, |band| band.bps)
+    }
+
+    /// Returns the unrounded fee on `notional`, held between the caps.
+    ///
+    /// # Errors
+    ///
+    /// Returns an arithmetic error if the fee overflows.
+    pub fn fee_for(&self, notional: Decimal) -> ArithmeticResult<Decimal> {
+        let fee = notional
+            .abs()
+            .safe_mul(self.bps_for(notional.abs()))?
+            .safe_div(BPS_PER_UNIT)?;
+        let fee = self.min_fee.map_or(fee, |min| fee.max(min));
+        Ok(self.max_fee.map_or(fee, |max| fee.min(max)))
+    }
+}
+
+/// Platform fee schedule for a counterparty tier.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlatformFeeSchedule {
+    tier: String,
+    counterparties: Vec<CounterpartyId>,
+    rates: Vec<AssetClassFeeRate>,
+    created_at: Timestamp,
+    updated_at: Timestamp,
+}
+
+impl PlatformFeeSchedule {
+    /// Creates a schedule for `tier`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if the tier is blank or two
+    /// rates cover the same asset class.
+    pub fn new(
+        tier: impl Into<String>,
+        counterparties: Vec<CounterpartyId>,
+        rates: Vec<AssetClassFeeRate>,
+    ) -> DomainResult<Self> {
+        let tier = tier.into();
+        if tier.trim().is_empty() {
+            return Err(DomainError::ValidationError(
+                "fee tier must not be empty".to_string(),
+            ));
+        }
+        if let Some(duplicate) = rates.iter().enumerate().find_map(|(i, rate)| {
+            rates
+                .iter()
+                .skip(i + 1)
+                .any(|other| other.asset_class == rate.asset_class)
+                .then_some(rate.asset_class)
+        }) {
+            return Err(DomainError::ValidationError(format!(
+                "fee tier {tier} has more than one rate for {duplicate}"
+            )));
+        }
+
+        let now = Timestamp::now();
+        Ok(Self {
+            tier,
+            counterparties,
+            rates,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Reconstructs a schedule from stored parts without validation.
+    #[must_use]
+    pub fn from_parts(
+        tier: String,
+        counterparties: Vec<CounterpartyId>,
+        rates: Vec<AssetClassFeeRate>,
+        created_at: Timestamp,
+        updated_at: Timestamp,
+    ) -> Self {
+        Self {
+            tier,
+            counterparties,
+            rates,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Replaces this schedule's members and rates with `other`'s, keeping
+    /// the original creation time.
+    #[must_use]
+    pub fn replaced_by(self, other: Self) -> Self {
+        Self {
+            created_at: self.created_at,
+            updated_at: Timestamp::now(),
+            ..other
+        }
+    }
+
+    /// Returns the tier name.
+    #[inline]
+    #[must_use]
+    pub fn tier(&self) -> &str {
+        &self.tier
+    }
+
+    /// Returns the counterparties assigned to this tier.
+    #[inline]
+    #[must_use]
+    pub fn counterparties(&self) -> &[CounterpartyId] {
+        &self.counterparties
+    }
+
+    /// Returns the per-asset-class rates.
+    #[inline]
+    #[must_use]
+    pub fn rates(&self) -> &[AssetClassFeeRate] {
+        &self.rates
+    }
+
+    /// Returns when the schedule was created.
+    #[inline]
+    #[must_use]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    /// Returns when the schedule was last replaced.
+    #[inline]
+    #[must_use]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    /// Returns true if this is the [`DEFAULT_FEE_TIER`] schedule.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        self.tier == DEFAULT_FEE_TIER
+    }
+
+    /// Returns true if `counterparty` is assigned to this tier.
+    #[must_use]
+    pub fn applies_to(&self, counterparty: &CounterpartyId) -> bool {
+        self.counterparties.contains(counterparty)
+    }
+
+    /// Returns the rate for `asset_class`, if the tier charges it.
+    #[must_use]
+    pub fn rate_for(&self, asset_class: AssetClass) -> Option<&AssetClassFeeRate> {
+        self.rates
+            .iter()
+            .find(|rate| rate.asset_class == asset_class)
+    }
+}
+
+/// Percentage discount on the platform fee granted to one counterparty.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeWaiver {
+    counterparty_id: CounterpartyId,
+    discount_pct: Decimal,
+    expires_at: Option<Timestamp>,
+}
+
+impl FeeWaiver {
+    /// Creates a waiver discounting `discount_pct` percent of the fee until
+    /// `expires_at`, or indefinitely.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if the discount is outside
+    /// `0..=100`.
+    pub fn new(
+        counterparty_id: CounterpartyId,
+        discount_pct: Decimal,
+        expires_at: Option<Timestamp>,
+    ) -> DomainResult<Self> {
+        if discount_pct.is_sign_negative() || discount_pct > Decimal::ONE_HUNDRED {
+            return Err(DomainError::ValidationError(format!(
+                "fee waiver discount must be between 0 and 100, got {discount_pct}"
+            )));
+        }
+        Ok(Self {
+            counterparty_id,
+            discount_pct,
+            expires_at,
+        })
+    }
+
+    /// Returns the counterparty the waiver belongs to.
+    #[inline]
+    #[must_use]
+    pub fn counterparty_id(&self) -> &CounterpartyId {
+        &self.counterparty_id
+    }
+
+    /// Returns the discount in percent.
+    #[inline]
+    #[must_use]
+    pub fn discount_pct(&self) -> Decimal {
+        self.discount_pct
+    }
+
+    /// Returns when the waiver expires, if it does.
+    #[inline]
+    #[must_use]
+    pub fn expires_at(&self) -> Option<Timestamp> {
+        self.expires_at
+    }
+
+    /// Returns true if the waiver still applies at `now`.
+    ///
+    /// A waiver applies up to and including its expiry instant.
+    #[must_use]
+    pub fn is_active_at(&self, now: Timestamp) -> bool {
+        self.expires_at
+            .is_none_or(|expiry| !expiry.is_expired_at(now))
+    }
+
+    /// Returns `fee` reduced by the discount.
+    ///
+    /// # Errors
+    ///
+    /// Returns an arithmetic error if the result overflows.
+    pub fn apply(&self, fee: Decimal) -> ArithmeticResult<Decimal> {
+        fee.safe_mul(Decimal::ONE_HUNDRED.safe_sub(self.discount_pct)?)?
+            .safe_div(Decimal::ONE_HUNDRED)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn dec(value: i64) -> Decimal {
+        Decimal::new(value, 0)
+    }
+
+    fn banded_rate(min_fee: Option<Decimal>, max_fee: Option<Decimal>) -> AssetClassFeeRate {
+        AssetClassFeeRate::new(
+            AssetClass::CryptoSpot,
+            vec![
+                FeeBand::new(Decimal::ZERO, dec(5)),
+                FeeBand::new(dec(1_000_000), dec(3)),
+                FeeBand::new(dec(10_000_000), dec(1)),
+            ],
+            min_fee,
+            max_fee,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn band_thresholds_are_inclusive() {
+        let rate = banded_rate(None, None);
+
+        assert_eq!(rate.bps_for(dec(999_999)), dec(5));
+        assert_eq!(rate.bps_for(dec(1_000_000)), dec(3));
+        assert_eq!(rate.bps_for(dec(9_999_999)), dec(3));
+        assert_eq!(rate.bps_for(dec(10_000_000)), dec(1));
+        assert_eq!(rate.fee_for(dec(1_000_000)).unwrap(), dec(300));
+    }
+
+    #[test]
+    fn fee_is_held_between_min_and_max() {
+        let rate = banded_rate(Some(dec(25)), Some(dec(2_000)));
+
+        // 5 bps of 10,000 = 5, raised to the floor.
+        assert_eq!(rate.fee_for(dec(10_000)).unwrap(), dec(25));
+        // 3 bps of 5,000,000 = 1,500, within the caps.
+        assert_eq!(rate.fee_for(dec(5_000_000)).unwrap(), dec(1_500));
+        // 1 bp of 50,000,000 = 5,000, cut to the cap.
+        assert_eq!(rate.fee_for(dec(50_000_000)).unwrap(), dec(2_000));
+    }
+
+    #[test]
+    fn invalid_bands_are_rejected() {
+        let starts_above_zero = AssetClassFeeRate::new(
+            AssetClass::CryptoSpot,
+            vec![FeeBand::new(dec(1), dec(5))],
+            None,
+            None,
+        );
+        let unsorted = AssetClassFeeRate::new(
+            AssetClass::CryptoSpot,
+            vec![
+                FeeBand::new(Decimal::ZERO, dec(5)),
+                FeeBand::new(dec(100), dec(3)),
+                FeeBand::new(dec(100), dec(1)),
+            ],
+            None,
+            None,
+        );
+        let inverted_caps = AssetClassFeeRate::new(
+            AssetClass::CryptoSpot,
+            vec![FeeBand::new(Decimal::ZERO, dec(5))],
+            Some(dec(10)),
+            Some(dec(5)),
+        );
+
+        assert!(matches!(
+            starts_above_zero,
+            Err(DomainError::ValidationError(_))
+        ));
+        assert!(matches!(unsorted, Err(DomainError::ValidationError(_))));
+        assert!(matches!(
+            inverted_caps,
+            Err(DomainError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn schedule_rejects_duplicate_asset_classes() {
+        let result = PlatformFeeSchedule::new(
+            "gold",
+            Vec::new(),
+            vec![banded_rate(None, None), banded_rate(None, None)],
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+    }
+
+    #[test]
+    fn waiver_applies_until_its_expiry() {
+        let expiry = Timestamp::from_secs(1_000).unwrap();
+        let waiver =
+            FeeWaiver::new(CounterpartyId::new("client-1"), dec(25), Some(expiry)).unwrap();
+
+        assert!(waiver.is_active_at(expiry));
+        assert!(!waiver.is_active_at(expiry.add_secs(1)));
+        assert_eq!(waiver.apply(dec(100)).unwrap(), dec(75));
+    }
+
+    #[test]
+    fn waiver_discount_must_be_a_percentage() {
+        let cp = CounterpartyId::new("client-1");
+
+        assert!(FeeWaiver::new(cp.clone(), dec(100), None).is_ok());
+        assert!(matches!(
+            FeeWaiver::new(cp.clone(), dec(101), None),
+            Err(DomainError::ValidationError(_))
+        ));
+        assert!(matches!(
+            FeeWaiver::new(cp, dec(-1), None),
+            Err(DomainError::ValidationError(_))
+        ));
+    }
+}
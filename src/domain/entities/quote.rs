@@ -23,8 +23,11 @@
 //! ```
 
 use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::all_in_cost::AllInCost;
+use crate::domain::value_objects::execution_instructions::ExecutionInstructions;
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{Price, Quantity, QuoteId, RfqId, VenueId};
+use crate::domain::value_objects::{OrderSide, Premium, Price, Quantity, QuoteId, RfqId, VenueId};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
@@ -46,6 +49,16 @@ use std::fmt;
 pub struct QuoteMetadata {
     /// Key-value pairs for venue-specific data.
     data: HashMap<String, String>,
+    /// Identity of the market maker behind the quote, when the venue
+    /// protocol exposes it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    maker_identity: Option<String>,
+    /// Typed data the venue needs back when the quote is executed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    execution_instructions: Option<ExecutionInstructions>,
+    /// Price including settlement gas, for quotes from DeFi venues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    all_in_cost: Option<AllInCost>,
 }
 
 impl QuoteMetadata {
@@ -58,7 +71,12 @@ impl QuoteMetadata {
     /// Creates metadata from a HashMap.
     #[must_use]
     pub fn from_map(data: HashMap<String, String>) -> Self {
-        Self { data }
+        Self {
+            data,
+            maker_identity: None,
+            execution_instructions: None,
+            all_in_cost: None,
+        }
     }
 
     /// Sets a metadata value.
@@ -72,10 +90,50 @@ impl QuoteMetadata {
         self.data.get(key)
     }
 
+    /// Sets the identity of the market maker behind the quote.
+    ///
+    /// Two quotes carrying the same identity are treated as the same
+    /// liquidity during aggregation, even when routed through different
+    /// venues.
+    pub fn set_maker_identity(&mut self, identity: impl Into<String>) {
+        self.maker_identity = Some(identity.into());
+    }
+
+    /// Returns the market maker identity, if the venue exposed one.
+    #[must_use]
+    pub fn maker_identity(&self) -> Option<&str> {
+        self.maker_identity.as_deref()
+    }
+
+    /// Sets the instructions the venue needs to execute the quote.
+    pub fn set_execution_instructions(&mut self, instructions: ExecutionInstructions) {
+        self.execution_instructions = Some(instructions);
+    }
+
+    /// Returns the execution instructions, if the venue attached any.
+    #[must_use]
+    pub fn execution_instructions(&self) -> Option<&ExecutionInstructions> {
+        self.execution_instructions.as_ref()
+    }
+
+    /// Sets the price including settlement gas.
+    pub fn set_all_in_cost(&mut self, cost: AllInCost) {
+        self.all_in_cost = Some(cost);
+    }
+
+    /// Returns the price including settlement gas, if it was computed.
+    #[must_use]
+    pub fn all_in_cost(&self) -> Option<&AllInCost> {
+        self.all_in_cost.as_ref()
+    }
+
     /// Returns true if the metadata is empty.
     #[must_use]
     pub fn is_empty(&self) -> bool {
         self.data.is_empty()
+            && self.maker_identity.is_none()
+            && self.execution_instructions.is_none()
+            && self.all_in_cost.is_none()
     }
 
     /// Returns the number of metadata entries.
@@ -90,14 +148,90 @@ impl QuoteMetadata {
     }
 }
 
+/// Price of one leg inside a net-premium quote.
+///
+/// Pairs the leg's position in the RFQ strategy with the venue's per-leg
+/// price, when the venue discloses one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuoteLegPrice {
+    /// Index of the leg in the RFQ strategy.
+    leg_index: usize,
+    /// Per-leg price, if disclosed by the venue.
+    price: Option<Price>,
+}
+
+impl QuoteLegPrice {
+    /// Creates a leg price.
+    #[must_use]
+    pub fn new(leg_index: usize, price: Option<Price>) -> Self {
+        Self { leg_index, price }
+    }
+
+    /// Returns the index of the leg in the RFQ strategy.
+    #[inline]
+    #[must_use]
+    pub fn leg_index(&self) -> usize {
+        self.leg_index
+    }
+
+    /// Returns the per-leg price, if disclosed.
+    #[inline]
+    #[must_use]
+    pub fn price(&self) -> Option<Price> {
+        self.price
+    }
+}
+
+/// How a quote is priced.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum QuoteKind {
+    /// A single price for a single instrument.
+    #[default]
+    Outright,
+    /// A single signed net premium for a whole strategy package.
+    NetPremium {
+        /// The net premium (positive = debit, negative = credit).
+        premium: Premium,
+        /// Per-leg breakdown, possibly partial.
+        legs: Vec<QuoteLegPrice>,
+    },
+}
+
+/// Whether a venue is committed to a quote's price.
+///
+/// Indicative quotes must be firmed up with the venue before they can be
+/// executed; the firm price may differ from the indicative one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum QuoteFirmness {
+    /// The venue is committed to the price until expiry.
+    #[default]
+    Firm,
+    /// The price is a guide and must be firmed up before execution.
+    Indicative,
+}
+
+impl fmt::Display for QuoteFirmness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Firm => write!(f, "FIRM"),
+            Self::Indicative => write!(f, "INDICATIVE"),
+        }
+    }
+}
+
 /// A price quote from a liquidity venue.
 ///
 /// Represents a quote received in response to an RFQ, including
 /// the price, quantity, validity period, and optional commission.
 ///
+/// For [`QuoteKind::NetPremium`] quotes the headline `price` is the
+/// magnitude of the net premium; use [`Quote::net_premium`] for the sign.
+///
 /// # Invariants
 ///
-/// - Price must be positive
+/// - Price must be positive (outright quotes)
 /// - Quantity must be positive
 /// - `valid_until` must be in the future when created
 ///
@@ -141,6 +275,21 @@ pub struct Quote {
     created_at: Timestamp,
     /// Whether this quote requires last-look confirmation from the MM.
     last_look_required: bool,
+    /// Outright price or strategy net premium.
+    #[serde(default)]
+    kind: QuoteKind,
+    /// Whether the venue is committed to the price.
+    #[serde(default)]
+    firmness: QuoteFirmness,
+    /// When the quote arrived from the venue, if recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    received_at: Option<Timestamp>,
+    /// Side the client trades at this price, if tagged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    side: Option<OrderSide>,
+    /// Whether the quote came from a venue in shadow mode.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    shadow: bool,
 }
 
 impl Quote {
@@ -196,6 +345,51 @@ impl Quote {
             metadata: None,
             created_at: Timestamp::now(),
             last_look_required: false,
+            kind: QuoteKind::Outright,
+            firmness: QuoteFirmness::Firm,
+            received_at: None,
+            side: None,
+            shadow: false,
+        })
+    }
+
+    /// Creates a net-premium quote for a multi-leg strategy RFQ.
+    ///
+    /// The premium may be zero or negative (credit).
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidQuantity` if quantity is not positive.
+    /// Returns `DomainError::QuoteExpired` if valid_until is in the past.
+    /// Returns `DomainError::ValidationError` if a leg is priced twice.
+    pub fn new_net_premium(
+        rfq_id: RfqId,
+        venue_id: VenueId,
+        premium: Premium,
+        legs: Vec<QuoteLegPrice>,
+        quantity: Quantity,
+        valid_until: Timestamp,
+    ) -> DomainResult<Self> {
+        Self::validate_quantity(&quantity)?;
+        Self::validate_expiry(&valid_until)?;
+        Self::validate_legs(&legs)?;
+
+        Ok(Self {
+            id: QuoteId::new_v4(),
+            rfq_id,
+            venue_id,
+            price: premium.abs(),
+            quantity,
+            commission: None,
+            valid_until,
+            metadata: None,
+            created_at: Timestamp::now(),
+            last_look_required: false,
+            kind: QuoteKind::NetPremium { premium, legs },
+            firmness: QuoteFirmness::Firm,
+            received_at: None,
+            side: None,
+            shadow: false,
         })
     }
 
@@ -218,6 +412,8 @@ impl Quote {
         metadata: Option<QuoteMetadata>,
         created_at: Timestamp,
         last_look_required: bool,
+        kind: QuoteKind,
+        firmness: QuoteFirmness,
     ) -> Self {
         Self {
             id,
@@ -230,6 +426,11 @@ impl Quote {
             metadata,
             created_at,
             last_look_required,
+            kind,
+            firmness,
+            received_at: None,
+            side: None,
+            shadow: false,
         }
     }
 
@@ -272,6 +473,17 @@ impl Quote {
         Ok(())
     }
 
+    fn validate_legs(legs: &[QuoteLegPrice]) -> DomainResult<()> {
+        let mut seen = std::collections::HashSet::with_capacity(legs.len());
+        if let Some(leg) = legs.iter().find(|leg| !seen.insert(leg.leg_index())) {
+            return Err(DomainError::ValidationError(format!(
+                "duplicate price for leg {}",
+                leg.leg_index()
+            )));
+        }
+        Ok(())
+    }
+
     /// Returns the quote ID.
     #[inline]
     #[must_use]
@@ -342,6 +554,73 @@ impl Quote {
         self.last_look_required
     }
 
+    /// Returns how this quote is priced.
+    #[inline]
+    #[must_use]
+    pub fn kind(&self) -> &QuoteKind {
+        &self.kind
+    }
+
+    /// Returns true if this is a net-premium strategy quote.
+    #[inline]
+    #[must_use]
+    pub fn is_net_premium(&self) -> bool {
+        matches!(self.kind, QuoteKind::NetPremium { .. })
+    }
+
+    /// Returns the signed headline price.
+    ///
+    /// The net premium for strategy quotes, otherwise the outright price.
+    #[must_use]
+    pub fn net_premium(&self) -> Premium {
+        match &self.kind {
+            QuoteKind::Outright => Premium::from(self.price),
+            QuoteKind::NetPremium { premium, .. } => *premium,
+        }
+    }
+
+    /// Returns the per-leg breakdown of a net-premium quote.
+    ///
+    /// Empty for outright quotes.
+    #[must_use]
+    pub fn leg_prices(&self) -> &[QuoteLegPrice] {
+        match &self.kind {
+            QuoteKind::Outright => &[],
+            QuoteKind::NetPremium { legs, .. } => legs,
+        }
+    }
+
+    /// Returns whether the venue is committed to this quote's price.
+    #[inline]
+    #[must_use]
+    pub fn firmness(&self) -> QuoteFirmness {
+        self.firmness
+    }
+
+    /// Returns true if this quote must be firmed up before execution.
+    #[inline]
+    #[must_use]
+    pub fn is_indicative(&self) -> bool {
+        self.firmness == QuoteFirmness::Indicative
+    }
+
+    /// Returns when the quote arrived from the venue.
+    ///
+    /// Falls back to the creation time for quotes whose arrival was not
+    /// recorded.
+    #[inline]
+    #[must_use]
+    pub fn received_at(&self) -> Timestamp {
+        self.received_at.unwrap_or(self.created_at)
+    }
+
+    /// Records when the quote arrived from the venue.
+    #[must_use]
+    pub fn with_received_at(mut self, received_at: Timestamp) -> Self {
+        self.received_at = Some(received_at);
+        self
+    }
+
     /// Sets whether this quote requires last-look confirmation.
     #[must_use]
     pub fn with_last_look_required(mut self, required: bool) -> Self {
@@ -349,6 +628,83 @@ impl Quote {
         self
     }
 
+    /// Sets the firmness of this quote.
+    #[must_use]
+    pub fn with_firmness(mut self, firmness: QuoteFirmness) -> Self {
+        self.firmness = firmness;
+        self
+    }
+
+    /// Returns the price including settlement gas, if it was computed.
+    #[must_use]
+    pub fn all_in_cost(&self) -> Option<&AllInCost> {
+        self.metadata.as_ref().and_then(QuoteMetadata::all_in_cost)
+    }
+
+    /// Returns the price including settlement gas, or the net premium if
+    /// none was computed.
+    #[must_use]
+    pub fn all_in_price(&self) -> Decimal {
+        self.all_in_cost()
+            .map_or_else(|| self.net_premium().get(), AllInCost::all_in_price)
+    }
+
+    /// Attaches the price including settlement gas.
+    #[must_use]
+    pub fn with_all_in_cost(mut self, cost: AllInCost) -> Self {
+        self.metadata
+            .get_or_insert_with(QuoteMetadata::new)
+            .set_all_in_cost(cost);
+        self
+    }
+
+    /// Returns the side the client trades at this price, if tagged.
+    ///
+    /// Quotes on two-way RFQs are always tagged; an untagged quote is on
+    /// its RFQ's side.
+    #[inline]
+    #[must_use]
+    pub fn side(&self) -> Option<OrderSide> {
+        self.side
+    }
+
+    /// Tags the side the client trades at this price.
+    #[must_use]
+    pub fn with_side(mut self, side: OrderSide) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    /// Returns true if the quote came from a venue in shadow mode.
+    ///
+    /// Shadow quotes are recorded and ranked but never shown to clients,
+    /// and an RFQ refuses to select one.
+    #[inline]
+    #[must_use]
+    pub fn is_shadow(&self) -> bool {
+        self.shadow
+    }
+
+    /// Marks whether the quote came from a venue in shadow mode.
+    #[must_use]
+    pub fn with_shadow(mut self, shadow: bool) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
+    /// Returns a copy of this quote under a new ID for another RFQ.
+    ///
+    /// Venue, price, validity and arrival time are kept, so the copy expires
+    /// with the original.
+    #[must_use]
+    pub fn reissue_for(&self, rfq_id: RfqId) -> Self {
+        Self {
+            id: QuoteId::new_v4(),
+            rfq_id,
+            ..self.clone()
+        }
+    }
+
     /// Returns true if this quote has expired.
     ///
     /// # Examples
@@ -372,6 +728,13 @@ impl Quote {
         self.valid_until.is_expired()
     }
 
+    /// Returns true if this quote has expired as of `now`.
+    #[inline]
+    #[must_use]
+    pub fn is_expired_at(&self, now: Timestamp) -> bool {
+        self.valid_until.is_expired_at(now)
+    }
+
     /// Returns the time remaining until expiry.
     ///
     /// Returns `Duration::ZERO` if already expired.
@@ -398,7 +761,9 @@ impl fmt::Display for Quote {
         write!(
             f,
             "Quote({} @ {} from {})",
-            self.quantity, self.price, self.venue_id
+            self.quantity,
+            self.net_premium(),
+            self.venue_id
         )
     }
 }
@@ -432,6 +797,9 @@ pub struct QuoteBuilder {
     valid_until: Timestamp,
     commission: Option<Price>,
     metadata: Option<QuoteMetadata>,
+    kind: QuoteKind,
+    firmness: QuoteFirmness,
+    side: Option<OrderSide>,
 }
 
 impl QuoteBuilder {
@@ -452,6 +820,9 @@ impl QuoteBuilder {
             valid_until,
             commission: None,
             metadata: None,
+            kind: QuoteKind::Outright,
+            firmness: QuoteFirmness::Firm,
+            side: None,
         }
     }
 
@@ -469,6 +840,30 @@ impl QuoteBuilder {
         self
     }
 
+    /// Sets the firmness.
+    #[must_use]
+    pub fn firmness(mut self, firmness: QuoteFirmness) -> Self {
+        self.firmness = firmness;
+        self
+    }
+
+    /// Sets the side the client trades at this price.
+    #[must_use]
+    pub fn side(mut self, side: OrderSide) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    /// Makes this a net-premium strategy quote.
+    ///
+    /// Replaces the headline price with the premium's magnitude.
+    #[must_use]
+    pub fn net_premium(mut self, premium: Premium, legs: Vec<QuoteLegPrice>) -> Self {
+        self.price = premium.abs();
+        self.kind = QuoteKind::NetPremium { premium, legs };
+        self
+    }
+
     /// Builds the quote without validation.
     ///
     /// Use [`try_build`](Self::try_build) for validated construction.
@@ -485,6 +880,11 @@ impl QuoteBuilder {
             metadata: self.metadata,
             created_at: Timestamp::now(),
             last_look_required: false,
+            kind: self.kind,
+            firmness: self.firmness,
+            received_at: None,
+            side: self.side,
+            shadow: false,
         }
     }
 
@@ -494,7 +894,10 @@ impl QuoteBuilder {
     ///
     /// Returns `DomainError` if validation fails.
     pub fn try_build(self) -> DomainResult<Quote> {
-        Quote::validate_price(&self.price)?;
+        match &self.kind {
+            QuoteKind::Outright => Quote::validate_price(&self.price)?,
+            QuoteKind::NetPremium { legs, .. } => Quote::validate_legs(legs)?,
+        }
         Quote::validate_quantity(&self.quantity)?;
         Quote::validate_expiry(&self.valid_until)?;
 
@@ -509,6 +912,11 @@ impl QuoteBuilder {
             metadata: self.metadata,
             created_at: Timestamp::now(),
             last_look_required: false,
+            kind: self.kind,
+            firmness: self.firmness,
+            received_at: None,
+            side: self.side,
+            shadow: false,
         })
     }
 }
@@ -517,6 +925,7 @@ impl QuoteBuilder {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use rust_decimal::Decimal;
 
     fn valid_price() -> Price {
         Price::new(100.0).unwrap()
@@ -759,6 +1168,17 @@ mod tests {
             let metadata = QuoteMetadata::from_map(map);
             assert_eq!(metadata.get("key"), Some(&"value".to_string()));
         }
+
+        #[test]
+        fn metadata_maker_identity() {
+            let mut metadata = QuoteMetadata::new();
+            assert_eq!(metadata.maker_identity(), None);
+
+            metadata.set_maker_identity("mm-alpha");
+            assert_eq!(metadata.maker_identity(), Some("mm-alpha"));
+            assert!(!metadata.is_empty());
+            assert_eq!(metadata.len(), 0);
+        }
     }
 
     mod display {
@@ -810,11 +1230,202 @@ mod tests {
         fn metadata_serde_roundtrip() {
             let mut metadata = QuoteMetadata::new();
             metadata.set("key", "value");
+            metadata.set_maker_identity("mm-alpha");
 
             let json = serde_json::to_string(&metadata).unwrap();
             let deserialized: QuoteMetadata = serde_json::from_str(&json).unwrap();
 
             assert_eq!(metadata, deserialized);
         }
+
+        #[test]
+        fn legacy_metadata_json_has_no_maker_identity() {
+            let metadata: QuoteMetadata =
+                serde_json::from_str(r#"{"data":{"key":"value"}}"#).unwrap();
+
+            assert_eq!(metadata.get("key"), Some(&"value".to_string()));
+            assert_eq!(metadata.maker_identity(), None);
+            assert_eq!(metadata.execution_instructions(), None);
+        }
+
+        #[test]
+        fn quote_roundtrip_keeps_execution_instructions() {
+            let mut fields = std::collections::BTreeMap::new();
+            fields.insert("order_id".to_string(), "abc".to_string());
+            let mut metadata = QuoteMetadata::new();
+            metadata.set_execution_instructions(ExecutionInstructions::Generic { fields });
+            assert!(!metadata.is_empty());
+
+            let quote = QuoteBuilder::new(
+                RfqId::new_v4(),
+                VenueId::new("venue"),
+                valid_price(),
+                valid_quantity(),
+                future_timestamp(),
+            )
+            .metadata(metadata.clone())
+            .build();
+
+            let json = serde_json::to_string(&quote).unwrap();
+            let deserialized: Quote = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(deserialized.metadata(), Some(&metadata));
+        }
+
+        #[test]
+        fn legacy_json_without_kind_is_outright() {
+            let quote = Quote::new(
+                RfqId::new_v4(),
+                VenueId::new("venue"),
+                valid_price(),
+                valid_quantity(),
+                future_timestamp(),
+            )
+            .unwrap();
+
+            let mut value = serde_json::to_value(&quote).unwrap();
+            value.as_object_mut().unwrap().remove("kind");
+            let deserialized: Quote = serde_json::from_value(value).unwrap();
+
+            assert_eq!(deserialized.kind(), &QuoteKind::Outright);
+            assert_eq!(deserialized.net_premium().get(), valid_price().get());
+        }
+
+        #[test]
+        fn legacy_json_without_firmness_is_firm() {
+            let quote = Quote::new(
+                RfqId::new_v4(),
+                VenueId::new("venue"),
+                valid_price(),
+                valid_quantity(),
+                future_timestamp(),
+            )
+            .unwrap()
+            .with_firmness(QuoteFirmness::Indicative);
+
+            let mut value = serde_json::to_value(&quote).unwrap();
+            assert_eq!(value.get("firmness").unwrap(), "INDICATIVE");
+            value.as_object_mut().unwrap().remove("firmness");
+            let deserialized: Quote = serde_json::from_value(value).unwrap();
+
+            assert_eq!(deserialized.firmness(), QuoteFirmness::Firm);
+            assert!(!deserialized.is_indicative());
+        }
+
+        #[test]
+        fn net_premium_serde_roundtrip() {
+            let legs = vec![
+                QuoteLegPrice::new(0, Some(Price::new(3.0).unwrap())),
+                QuoteLegPrice::new(1, None),
+            ];
+            let quote = Quote::new_net_premium(
+                RfqId::new_v4(),
+                VenueId::new("venue"),
+                Premium::new(Decimal::new(-125, 2)),
+                legs,
+                valid_quantity(),
+                future_timestamp(),
+            )
+            .unwrap();
+
+            let json = serde_json::to_string(&quote).unwrap();
+            assert!(json.contains("\"NET_PREMIUM\""));
+            let deserialized: Quote = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(quote, deserialized);
+            assert_eq!(deserialized.net_premium().get(), Decimal::new(-125, 2));
+        }
+    }
+
+    mod net_premium {
+        use super::*;
+
+        #[test]
+        fn credit_quote_keeps_sign_and_magnitude() {
+            let quote = Quote::new_net_premium(
+                RfqId::new_v4(),
+                VenueId::new("venue"),
+                Premium::new(Decimal::new(-250, 2)),
+                vec![],
+                valid_quantity(),
+                future_timestamp(),
+            )
+            .unwrap();
+
+            assert!(quote.is_net_premium());
+            assert!(quote.net_premium().is_credit());
+            assert_eq!(quote.price().get(), Decimal::new(250, 2));
+            assert!(quote.leg_prices().is_empty());
+        }
+
+        #[test]
+        fn zero_premium_is_allowed() {
+            let quote = QuoteBuilder::new(
+                RfqId::new_v4(),
+                VenueId::new("venue"),
+                valid_price(),
+                valid_quantity(),
+                future_timestamp(),
+            )
+            .net_premium(Premium::ZERO, vec![QuoteLegPrice::new(0, None)])
+            .try_build()
+            .unwrap();
+
+            assert!(quote.net_premium().is_zero());
+            assert_eq!(quote.leg_prices().len(), 1);
+        }
+
+        #[test]
+        fn duplicate_leg_is_rejected() {
+            let result = Quote::new_net_premium(
+                RfqId::new_v4(),
+                VenueId::new("venue"),
+                Premium::new(Decimal::ONE),
+                vec![QuoteLegPrice::new(0, None), QuoteLegPrice::new(0, None)],
+                valid_quantity(),
+                future_timestamp(),
+            );
+
+            assert!(matches!(result, Err(DomainError::ValidationError(_))));
+        }
+
+        #[test]
+        fn outright_quote_net_premium_is_price() {
+            let quote = Quote::new(
+                RfqId::new_v4(),
+                VenueId::new("venue"),
+                valid_price(),
+                valid_quantity(),
+                future_timestamp(),
+            )
+            .unwrap();
+
+            assert!(!quote.is_net_premium());
+            assert_eq!(quote.net_premium(), Premium::from(valid_price()));
+        }
+
+        #[test]
+        fn reissue_keeps_venue_price_and_validity() {
+            let received_at = Timestamp::now();
+            let quote = Quote::new(
+                RfqId::new_v4(),
+                VenueId::new("venue"),
+                valid_price(),
+                valid_quantity(),
+                future_timestamp(),
+            )
+            .unwrap()
+            .with_received_at(received_at);
+            let rfq_id = RfqId::new_v4();
+
+            let copy = quote.reissue_for(rfq_id);
+
+            assert_ne!(copy.id(), quote.id());
+            assert_eq!(copy.rfq_id(), rfq_id);
+            assert_eq!(copy.venue_id(), quote.venue_id());
+            assert_eq!(copy.price(), quote.price());
+            assert_eq!(copy.valid_until(), quote.valid_until());
+            assert_eq!(copy.received_at(), received_at);
+        }
     }
 }
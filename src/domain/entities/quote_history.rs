@@ -0,0 +1,268 @@
+//! # Quote History
+//!
+//! Every quote an RFQ has seen, for showing clients how the best price
+//! evolved.
+//!
+//! An RFQ only ranks and selects among its live quotes. Quotes that stop
+//! being live are kept as [`RetiredQuote`]s instead of being dropped:
+//!
+//! - A venue that quotes the same side again supersedes its earlier quote,
+//!   as does the firm quote returned by a firm-up.
+//! - A quote that arrives already expired is filtered out of selection but
+//!   retained, flagged [`QuoteStatus::Expired`].
+//!
+//! The ranks assigned when quote collection completed are kept as
+//! [`QuoteRank`]s, so the history can show where each quote stood.
+
+use crate::domain::entities::quote::Quote;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{OrderSide, Price, QuoteId, VenueId};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use utoipa::ToSchema;
+
+/// Status of a quote in an RFQ's history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum QuoteStatus {
+    /// The quote can still be selected.
+    Active,
+    /// A later quote from the same venue replaced it.
+    Superseded,
+    /// The quote's validity lapsed, or it arrived already expired.
+    Expired,
+}
+
+impl fmt::Display for QuoteStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Active => write!(f, "ACTIVE"),
+            Self::Superseded => write!(f, "SUPERSEDED"),
+            Self::Expired => write!(f, "EXPIRED"),
+        }
+    }
+}
+
+/// A quote that is no longer live on its RFQ.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetiredQuote {
+    /// The retired quote.
+    quote: Quote,
+    /// Why the quote was retired; never [`QuoteStatus::Active`].
+    status: QuoteStatus,
+    /// The quote that replaced it, if superseded.
+    superseded_by: Option<QuoteId>,
+    /// When the quote was retired.
+    retired_at: Timestamp,
+}
+
+impl RetiredQuote {
+    /// Retires `quote` in favour of `by`.
+    #[must_use]
+    pub fn superseded(quote: Quote, by: QuoteId) -> Self {
+        Self {
+            quote,
+            status: QuoteStatus::Superseded,
+            superseded_by: Some(by),
+            retired_at: Timestamp::now(),
+        }
+    }
+
+    /// Retires `quote`, which arrived already expired.
+    #[must_use]
+    pub fn expired(quote: Quote) -> Self {
+        Self {
+            quote,
+            status: QuoteStatus::Expired,
+            superseded_by: None,
+            retired_at: Timestamp::now(),
+        }
+    }
+
+    /// Returns the retired quote.
+    #[inline]
+    #[must_use]
+    pub fn quote(&self) -> &Quote {
+        &self.quote
+    }
+
+    /// Returns why the quote was retired.
+    #[inline]
+    #[must_use]
+    pub fn status(&self) -> QuoteStatus {
+        self.status
+    }
+
+    /// Returns the quote that replaced this one, if superseded.
+    #[inline]
+    #[must_use]
+    pub fn superseded_by(&self) -> Option<QuoteId> {
+        self.superseded_by
+    }
+
+    /// Returns when the quote was retired.
+    #[inline]
+    #[must_use]
+    pub fn retired_at(&self) -> Timestamp {
+        self.retired_at
+    }
+}
+
+/// A quote's rank when quote collection completed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuoteRank {
+    /// The ranked quote.
+    pub quote_id: QuoteId,
+    /// The side the client would trade at the quote's price.
+    pub side: OrderSide,
+    /// The rank among quotes on the same side (1 = best).
+    pub rank: u32,
+}
+
+impl QuoteRank {
+    /// Creates a new quote rank.
+    #[must_use]
+    pub fn new(quote_id: QuoteId, side: OrderSide, rank: u32) -> Self {
+        Self {
+            quote_id,
+            side,
+            rank,
+        }
+    }
+}
+
+/// A quote in an RFQ's history, live or retired.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuoteHistoryEntry<'a> {
+    /// The quote.
+    pub quote: &'a Quote,
+    /// The side the client would trade at the quote's price.
+    pub side: OrderSide,
+    /// The quote's status.
+    pub status: QuoteStatus,
+    /// The quote that replaced it, if superseded.
+    pub superseded_by: Option<QuoteId>,
+    /// The quote's rank when collection completed, if it was ranked.
+    pub rank: Option<u32>,
+}
+
+/// A point at which the best price on one side of an RFQ improved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BestPricePoint {
+    /// When the improving quote was received.
+    pub at: Timestamp,
+    /// The side the client would trade at this price.
+    pub side: OrderSide,
+    /// The quote that set the new best price.
+    pub quote_id: QuoteId,
+    /// The venue that quoted it.
+    pub venue_id: VenueId,
+    /// The new best price.
+    pub price: Price,
+}
+
+/// Builds the best-price timeline from `entries`.
+///
+/// Entries are replayed in the order their quotes were received; a point is
+/// recorded each time a quote beats the best price seen so far on its side.
+/// Quotes that arrived already expired never counted and are skipped.
+#[must_use]
+pub fn best_price_timeline(entries: &[QuoteHistoryEntry<'_>]) -> Vec<BestPricePoint> {
+    let mut received: Vec<&QuoteHistoryEntry<'_>> = entries
+        .iter()
+        .filter(|entry| !entry.quote.is_expired_at(entry.quote.received_at()))
+        .collect();
+    received.sort_by_key(|entry| entry.quote.received_at());
+
+    let mut best_buy: Option<Price> = None;
+    let mut best_sell: Option<Price> = None;
+    let mut timeline = Vec::new();
+    for entry in received {
+        let price = entry.quote.price();
+        let best = match entry.side {
+            OrderSide::Buy => &mut best_buy,
+            OrderSide::Sell => &mut best_sell,
+        };
+        let improves = best.is_none_or(|best| match entry.side {
+            OrderSide::Buy => price < best,
+            OrderSide::Sell => price > best,
+        });
+        if improves {
+            *best = Some(price);
+            timeline.push(BestPricePoint {
+                at: entry.quote.received_at(),
+                side: entry.side,
+                quote_id: entry.quote.id(),
+                venue_id: entry.quote.venue_id().clone(),
+                price,
+            });
+        }
+    }
+    timeline
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{Quantity, RfqId};
+
+    fn quote_at(price: f64, received_secs: i64) -> Quote {
+        let received_at = Timestamp::now().add_secs(received_secs);
+        Quote::new(
+            RfqId::new_v4(),
+            VenueId::new("venue-1"),
+            Price::new(price).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .unwrap()
+        .with_received_at(received_at)
+    }
+
+    fn entry(quote: &Quote, side: OrderSide) -> QuoteHistoryEntry<'_> {
+        QuoteHistoryEntry {
+            quote,
+            side,
+            status: QuoteStatus::Active,
+            superseded_by: None,
+            rank: None,
+        }
+    }
+
+    #[test]
+    fn timeline_records_only_improvements_in_arrival_order() {
+        let first = quote_at(101.0, 1);
+        let worse = quote_at(102.0, 2);
+        let better = quote_at(99.0, 3);
+        let offer = quote_at(95.0, 4);
+        let entries = [
+            entry(&better, OrderSide::Buy),
+            entry(&worse, OrderSide::Buy),
+            entry(&first, OrderSide::Buy),
+            entry(&offer, OrderSide::Sell),
+        ];
+
+        let timeline = best_price_timeline(&entries);
+
+        let points: Vec<(QuoteId, OrderSide)> =
+            timeline.iter().map(|p| (p.quote_id, p.side)).collect();
+        assert_eq!(
+            points,
+            vec![
+                (first.id(), OrderSide::Buy),
+                (better.id(), OrderSide::Buy),
+                (offer.id(), OrderSide::Sell),
+            ]
+        );
+    }
+
+    #[test]
+    fn status_display() {
+        assert_eq!(QuoteStatus::Superseded.to_string(), "SUPERSEDED");
+        assert_eq!(
+            serde_json::to_string(&QuoteStatus::Expired).unwrap(),
+            "\"EXPIRED\""
+        );
+    }
+}
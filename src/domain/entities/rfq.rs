@@ -41,13 +41,23 @@
 
 use crate::domain::entities::anonymity::{AnonymityLevel, AnonymousRfqView};
 use crate::domain::entities::quote::Quote;
+use crate::domain::entities::quote_history::{
+    BestPricePoint, QuoteHistoryEntry, QuoteRank, QuoteStatus, RetiredQuote, best_price_timeline,
+};
 use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::size_negotiation_mode::SizeNegotiationMode;
+use crate::domain::value_objects::strategy::Strategy;
 use crate::domain::value_objects::timestamp::Timestamp;
 use crate::domain::value_objects::{
-    CounterpartyId, Instrument, OrderSide, Quantity, QuoteId, RfqId, RfqState,
+    CompensationPolicy, CounterpartyId, FailureReason, Instrument, OrderSide, Quantity,
+    QuantityDisclosure, QuoteId, RfqDirection, RfqId, RfqState, VenueExclusionReason, VenueId,
 };
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::time::Duration;
+
+/// How long an execution may run when no other bound is configured.
+pub const DEFAULT_EXECUTION_TIMEOUT: Duration = Duration::from_secs(30);
 
 /// Result of a compliance check.
 ///
@@ -84,6 +94,72 @@ impl ComplianceResult {
     }
 }
 
+/// What an RFQ asks to be quoted.
+///
+/// Either a single instrument or a multi-leg [`Strategy`] quoted as one
+/// package. Serializes under an `instrument` or `strategy` key so existing
+/// single-instrument payloads keep their shape.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RfqSubject {
+    /// A single instrument.
+    #[serde(rename = "instrument")]
+    Single(Instrument),
+    /// A multi-leg strategy.
+    #[serde(rename = "strategy")]
+    Strategy(Strategy),
+}
+
+impl RfqSubject {
+    /// Returns the primary instrument.
+    ///
+    /// For a strategy this is the instrument of its first leg.
+    #[must_use]
+    pub fn primary_instrument(&self) -> &Instrument {
+        match self {
+            Self::Single(instrument) => instrument,
+            Self::Strategy(strategy) => strategy.primary_leg().instrument(),
+        }
+    }
+
+    /// Returns the strategy, if this is a multi-leg subject.
+    #[inline]
+    #[must_use]
+    pub fn strategy(&self) -> Option<&Strategy> {
+        match self {
+            Self::Single(_) => None,
+            Self::Strategy(strategy) => Some(strategy),
+        }
+    }
+
+    /// Returns true if this subject is a multi-leg strategy.
+    #[inline]
+    #[must_use]
+    pub fn is_multi_leg(&self) -> bool {
+        matches!(self, Self::Strategy(_))
+    }
+}
+
+impl From<Instrument> for RfqSubject {
+    fn from(instrument: Instrument) -> Self {
+        Self::Single(instrument)
+    }
+}
+
+impl From<Strategy> for RfqSubject {
+    fn from(strategy: Strategy) -> Self {
+        Self::Strategy(strategy)
+    }
+}
+
+impl fmt::Display for RfqSubject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Single(instrument) => write!(f, "{}", instrument),
+            Self::Strategy(strategy) => write!(f, "{}", strategy),
+        }
+    }
+}
+
 /// RFQ (Request-for-Quote) aggregate root.
 ///
 /// The central entity managing the RFQ lifecycle, including state transitions,
@@ -126,28 +202,66 @@ pub struct Rfq {
     id: RfqId,
     /// The client requesting the quote.
     client_id: CounterpartyId,
-    /// The instrument being quoted.
-    instrument: Instrument,
-    /// Buy or sell side.
+    /// The instrument or strategy being quoted.
+    #[serde(flatten)]
+    subject: RfqSubject,
+    /// Buy or sell side; a placeholder while a two-way RFQ is open.
     side: OrderSide,
+    /// Whether both sides are quoted and no side has been selected yet.
+    #[serde(default)]
+    two_way: bool,
     /// Requested quantity.
     quantity: Quantity,
     /// Optional minimum acceptable quantity for partial fills.
     min_quantity: Option<Quantity>,
+    /// How the requested quantity may be filled.
+    #[serde(default)]
+    size_mode: SizeNegotiationMode,
     /// Anonymity level for this RFQ.
     anonymity_level: AnonymityLevel,
+    /// Whether this RFQ may be crossed against an opposing client RFQ.
+    #[serde(default)]
+    allow_internal_crossing: bool,
+    /// Venues this RFQ may be sent to; `None` allows every venue.
+    #[serde(default)]
+    venue_allowlist: Option<Vec<VenueId>>,
+    /// Venues this RFQ must not be sent to. Ignored when an allowlist is set.
+    #[serde(default)]
+    venue_blocklist: Vec<VenueId>,
+    /// How a partially failed multi-venue fill is compensated.
+    #[serde(default)]
+    compensation_policy: CompensationPolicy,
+    /// How much of the quantity venues are shown.
+    #[serde(default)]
+    quantity_disclosure: QuantityDisclosure,
     /// Current state in the lifecycle.
     state: RfqState,
     /// When this RFQ expires.
     expires_at: Timestamp,
+    /// When quote collection may start; `None` activates immediately.
+    #[serde(default)]
+    activate_at: Option<Timestamp>,
     /// Quotes received from venues.
     quotes: Vec<Quote>,
+    /// Quotes no longer live: superseded, or expired on arrival.
+    #[serde(default)]
+    retired_quotes: Vec<RetiredQuote>,
+    /// Latest quotes from venues in shadow mode, kept apart from `quotes`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    shadow_quotes: Vec<Quote>,
+    /// Quote ranks when quote collection completed.
+    #[serde(default)]
+    quote_ranks: Vec<QuoteRank>,
     /// The selected quote for execution.
     selected_quote_id: Option<QuoteId>,
     /// Compliance check results.
     compliance_result: Option<ComplianceResult>,
     /// Reason for failure, if failed.
-    failure_reason: Option<String>,
+    failure_reason: Option<FailureReason>,
+    /// When the current execution must have finished; set when execution
+    /// starts.
+    #[serde(default)]
+    execution_deadline: Option<Timestamp>,
     /// Version for optimistic locking.
     version: u64,
     /// When this RFQ was created.
@@ -162,7 +276,7 @@ impl Rfq {
     /// # Arguments
     ///
     /// * `client_id` - The client requesting the quote
-    /// * `instrument` - The instrument to quote
+    /// * `subject` - The instrument or strategy to quote
     /// * `side` - Buy or sell
     /// * `quantity` - The quantity to quote (must be positive)
     /// * `expires_at` - When this RFQ expires (must be in the future)
@@ -173,7 +287,7 @@ impl Rfq {
     /// Returns `DomainError::ValidationError` if expires_at is in the past.
     pub fn new(
         client_id: CounterpartyId,
-        instrument: Instrument,
+        subject: impl Into<RfqSubject>,
         side: OrderSide,
         quantity: Quantity,
         expires_at: Timestamp,
@@ -185,17 +299,29 @@ impl Rfq {
         Ok(Self {
             id: RfqId::new_v4(),
             client_id,
-            instrument,
+            subject: subject.into(),
             side,
+            two_way: false,
             quantity,
             min_quantity: None,
+            size_mode: SizeNegotiationMode::default(),
             anonymity_level: AnonymityLevel::default(),
+            allow_internal_crossing: false,
+            venue_allowlist: None,
+            venue_blocklist: Vec::new(),
+            compensation_policy: CompensationPolicy::default(),
+            quantity_disclosure: QuantityDisclosure::default(),
             state: RfqState::Created,
             expires_at,
+            activate_at: None,
             quotes: Vec::new(),
+            retired_quotes: Vec::new(),
+            shadow_quotes: Vec::new(),
+            quote_ranks: Vec::new(),
             selected_quote_id: None,
             compliance_result: None,
             failure_reason: None,
+            execution_deadline: None,
             version: 1,
             created_at: now,
             updated_at: now,
@@ -204,6 +330,8 @@ impl Rfq {
 
     /// Creates an RFQ with a specific ID (for reconstruction from storage).
     ///
+    /// Shadow quotes in `quotes` are kept apart as [`Rfq::shadow_quotes`].
+    ///
     /// # Safety
     ///
     /// This method bypasses validation and should only be used when
@@ -213,51 +341,92 @@ impl Rfq {
     pub fn from_parts(
         id: RfqId,
         client_id: CounterpartyId,
-        instrument: Instrument,
+        subject: RfqSubject,
         side: OrderSide,
+        two_way: bool,
         quantity: Quantity,
         min_quantity: Option<Quantity>,
+        size_mode: SizeNegotiationMode,
         anonymity_level: AnonymityLevel,
+        allow_internal_crossing: bool,
+        venue_allowlist: Option<Vec<VenueId>>,
+        venue_blocklist: Vec<VenueId>,
+        compensation_policy: CompensationPolicy,
+        quantity_disclosure: QuantityDisclosure,
         state: RfqState,
         expires_at: Timestamp,
+        activate_at: Option<Timestamp>,
         quotes: Vec<Quote>,
         selected_quote_id: Option<QuoteId>,
         compliance_result: Option<ComplianceResult>,
-        failure_reason: Option<String>,
+        failure_reason: Option<FailureReason>,
         version: u64,
         created_at: Timestamp,
         updated_at: Timestamp,
     ) -> Self {
+        let (shadow_quotes, live_quotes) = quotes.into_iter().partition(Quote::is_shadow);
         Self {
             id,
             client_id,
-            instrument,
+            subject,
             side,
+            two_way,
             quantity,
             min_quantity,
+            size_mode,
             anonymity_level,
+            allow_internal_crossing,
+            venue_allowlist,
+            venue_blocklist,
+            compensation_policy,
+            quantity_disclosure,
             state,
             expires_at,
-            quotes,
+            activate_at,
+            quotes: live_quotes,
+            retired_quotes: Vec::new(),
+            shadow_quotes,
+            quote_ranks: Vec::new(),
             selected_quote_id,
             compliance_result,
             failure_reason,
+            execution_deadline: None,
             version,
             created_at,
             updated_at,
         }
     }
 
+    /// Restores the execution deadline (for reconstruction from storage).
+    #[must_use]
+    pub fn with_execution_deadline(mut self, execution_deadline: Option<Timestamp>) -> Self {
+        self.execution_deadline = execution_deadline;
+        self
+    }
+
+    /// Restores the retired quotes and collection ranks (for reconstruction
+    /// from storage).
+    #[must_use]
+    pub fn with_quote_history(
+        mut self,
+        retired_quotes: Vec<RetiredQuote>,
+        quote_ranks: Vec<QuoteRank>,
+    ) -> Self {
+        self.retired_quotes = retired_quotes;
+        self.quote_ranks = quote_ranks;
+        self
+    }
+
     /// Returns a builder for constructing an RFQ.
     #[must_use]
     pub fn builder(
         client_id: CounterpartyId,
-        instrument: Instrument,
-        side: OrderSide,
+        subject: impl Into<RfqSubject>,
+        direction: impl Into<RfqDirection>,
         quantity: Quantity,
         expires_at: Timestamp,
     ) -> RfqBuilder {
-        RfqBuilder::new(client_id, instrument, side, quantity, expires_at)
+        RfqBuilder::new(client_id, subject, direction, quantity, expires_at)
     }
 
     fn validate_quantity(quantity: &Quantity) -> DomainResult<()> {
@@ -308,19 +477,108 @@ impl Rfq {
     }
 
     /// Returns the instrument.
+    ///
+    /// For a multi-leg RFQ this is the instrument of the strategy's first leg.
     #[inline]
     #[must_use]
     pub fn instrument(&self) -> &Instrument {
-        &self.instrument
+        self.subject.primary_instrument()
+    }
+
+    /// Returns the instrument or strategy being quoted.
+    #[inline]
+    #[must_use]
+    pub fn subject(&self) -> &RfqSubject {
+        &self.subject
+    }
+
+    /// Returns the strategy, if this is a multi-leg RFQ.
+    #[inline]
+    #[must_use]
+    pub fn strategy(&self) -> Option<&Strategy> {
+        self.subject.strategy()
+    }
+
+    /// Returns true if this RFQ quotes a multi-leg strategy.
+    #[inline]
+    #[must_use]
+    pub fn is_multi_leg(&self) -> bool {
+        self.subject.is_multi_leg()
     }
 
     /// Returns the order side.
+    ///
+    /// A two-way RFQ has no side until a quote is selected; use
+    /// [`direction`](Self::direction) or [`resolved_side`](Self::resolved_side)
+    /// where that matters.
     #[inline]
     #[must_use]
     pub fn side(&self) -> OrderSide {
         self.side
     }
 
+    /// Returns the direction this RFQ is quoted in.
+    ///
+    /// A two-way RFQ reports [`RfqDirection::TwoWay`] until a side is
+    /// selected, and that side from then on.
+    #[must_use]
+    pub fn direction(&self) -> RfqDirection {
+        if self.two_way {
+            RfqDirection::TwoWay
+        } else {
+            self.side.into()
+        }
+    }
+
+    /// Returns true if both sides are quoted and no side has been selected.
+    #[inline]
+    #[must_use]
+    pub fn is_two_way(&self) -> bool {
+        self.two_way
+    }
+
+    /// Returns the side the client trades.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` for a two-way RFQ whose side
+    /// has not been selected yet.
+    pub fn resolved_side(&self) -> DomainResult<OrderSide> {
+        if self.two_way {
+            return Err(DomainError::ValidationError(format!(
+                "two-way RFQ {} has no side until a quote is selected",
+                self.id
+            )));
+        }
+        Ok(self.side)
+    }
+
+    /// Returns the side the client trades at `quote`'s price.
+    ///
+    /// Untagged quotes are on the RFQ's side.
+    #[must_use]
+    pub fn side_of(&self, quote: &Quote) -> OrderSide {
+        quote.side().unwrap_or(self.side)
+    }
+
+    /// Returns the quotes on `side`.
+    pub fn quotes_on(&self, side: OrderSide) -> impl Iterator<Item = &Quote> {
+        self.quotes.iter().filter(move |q| self.side_of(q) == side)
+    }
+
+    /// Returns a one-way copy of this RFQ quoting only `side`.
+    ///
+    /// Venues that cannot price both sides at once are sent one of these
+    /// per side.
+    #[must_use]
+    pub fn one_way(&self, side: OrderSide) -> Self {
+        Self {
+            side,
+            two_way: false,
+            ..self.clone()
+        }
+    }
+
     /// Returns the quantity.
     #[inline]
     #[must_use]
@@ -335,6 +593,34 @@ impl Rfq {
         self.min_quantity
     }
 
+    /// Returns how the requested quantity may be filled.
+    #[inline]
+    #[must_use]
+    pub fn size_mode(&self) -> &SizeNegotiationMode {
+        &self.size_mode
+    }
+
+    /// Returns how a partially failed multi-venue fill is compensated.
+    #[inline]
+    #[must_use]
+    pub fn compensation_policy(&self) -> CompensationPolicy {
+        self.compensation_policy
+    }
+
+    /// Returns how much of the quantity venues are shown.
+    #[inline]
+    #[must_use]
+    pub fn quantity_disclosure(&self) -> QuantityDisclosure {
+        self.quantity_disclosure
+    }
+
+    /// Returns the quantity venues are shown, or `None` if it is hidden.
+    #[inline]
+    #[must_use]
+    pub fn disclosed_quantity(&self) -> Option<Quantity> {
+        self.quantity_disclosure.disclose(self.quantity)
+    }
+
     /// Returns the anonymity level.
     #[inline]
     #[must_use]
@@ -342,6 +628,43 @@ impl Rfq {
         self.anonymity_level
     }
 
+    /// Returns true if this RFQ opted in to internal crossing.
+    #[inline]
+    #[must_use]
+    pub fn allows_internal_crossing(&self) -> bool {
+        self.allow_internal_crossing
+    }
+
+    /// Returns the venues this RFQ may be sent to, if restricted.
+    #[inline]
+    #[must_use]
+    pub fn venue_allowlist(&self) -> Option<&[VenueId]> {
+        self.venue_allowlist.as_deref()
+    }
+
+    /// Returns the venues this RFQ must not be sent to.
+    #[inline]
+    #[must_use]
+    pub fn venue_blocklist(&self) -> &[VenueId] {
+        &self.venue_blocklist
+    }
+
+    /// Returns why `venue_id` may not quote this RFQ, or `None` if it may.
+    ///
+    /// The allowlist wins when both lists are set: a venue on the allowlist
+    /// is eligible even if it is also blocklisted.
+    #[must_use]
+    pub fn venue_exclusion(&self, venue_id: &VenueId) -> Option<VenueExclusionReason> {
+        match &self.venue_allowlist {
+            Some(allowlist) if allowlist.contains(venue_id) => None,
+            Some(_) => Some(VenueExclusionReason::NotAllowlisted),
+            None if self.venue_blocklist.contains(venue_id) => {
+                Some(VenueExclusionReason::Blocklisted)
+            }
+            None => None,
+        }
+    }
+
     /// Returns true if this RFQ is anonymous.
     #[inline]
     #[must_use]
@@ -357,7 +680,7 @@ impl Rfq {
     pub fn to_anonymous_view(&self) -> AnonymousRfqView {
         AnonymousRfqView::new(
             self.id,
-            self.instrument.clone(),
+            self.instrument().clone(),
             self.side,
             self.quantity,
             self.expires_at,
@@ -379,6 +702,13 @@ impl Rfq {
         self.expires_at
     }
 
+    /// Returns when quote collection may start, if scheduled.
+    #[inline]
+    #[must_use]
+    pub fn activate_at(&self) -> Option<Timestamp> {
+        self.activate_at
+    }
+
     /// Returns the quotes received.
     #[inline]
     #[must_use]
@@ -386,6 +716,76 @@ impl Rfq {
         &self.quotes
     }
 
+    /// Returns the quotes that are no longer live.
+    #[inline]
+    #[must_use]
+    pub fn retired_quotes(&self) -> &[RetiredQuote] {
+        &self.retired_quotes
+    }
+
+    /// Returns the latest quotes from venues in shadow mode.
+    ///
+    /// These are never part of [`quotes`](Self::quotes) and cannot be
+    /// selected.
+    #[inline]
+    #[must_use]
+    pub fn shadow_quotes(&self) -> &[Quote] {
+        &self.shadow_quotes
+    }
+
+    /// Returns the quote ranks recorded when collection completed.
+    #[inline]
+    #[must_use]
+    pub fn quote_ranks(&self) -> &[QuoteRank] {
+        &self.quote_ranks
+    }
+
+    /// Records the quote ranks at the end of quote collection, replacing
+    /// any earlier ranking.
+    pub fn record_quote_ranks(&mut self, ranks: Vec<QuoteRank>) {
+        self.quote_ranks = ranks;
+        self.updated_at = Timestamp::now();
+    }
+
+    /// Returns every quote this RFQ has seen, live quotes first.
+    ///
+    /// Live quotes whose validity has lapsed are reported as expired unless
+    /// selected.
+    #[must_use]
+    pub fn quote_history(&self) -> Vec<QuoteHistoryEntry<'_>> {
+        let rank_of = |quote: &Quote| {
+            self.quote_ranks
+                .iter()
+                .find(|rank| rank.quote_id == quote.id())
+                .map(|rank| rank.rank)
+        };
+        let live = self.quotes.iter().map(|quote| QuoteHistoryEntry {
+            quote,
+            side: self.side_of(quote),
+            status: if quote.is_expired() && self.selected_quote_id != Some(quote.id()) {
+                QuoteStatus::Expired
+            } else {
+                QuoteStatus::Active
+            },
+            superseded_by: None,
+            rank: rank_of(quote),
+        });
+        let retired = self.retired_quotes.iter().map(|retired| QuoteHistoryEntry {
+            quote: retired.quote(),
+            side: self.side_of(retired.quote()),
+            status: retired.status(),
+            superseded_by: retired.superseded_by(),
+            rank: rank_of(retired.quote()),
+        });
+        live.chain(retired).collect()
+    }
+
+    /// Returns the points at which the best price on each side improved.
+    #[must_use]
+    pub fn best_price_timeline(&self) -> Vec<BestPricePoint> {
+        best_price_timeline(&self.quote_history())
+    }
+
     /// Returns the selected quote ID, if any.
     #[inline]
     #[must_use]
@@ -410,8 +810,16 @@ impl Rfq {
     /// Returns the failure reason, if any.
     #[inline]
     #[must_use]
-    pub fn failure_reason(&self) -> Option<&str> {
-        self.failure_reason.as_deref()
+    pub fn failure_reason(&self) -> Option<&FailureReason> {
+        self.failure_reason.as_ref()
+    }
+
+    /// Returns when the current execution must have finished, if executing
+    /// or once executed.
+    #[inline]
+    #[must_use]
+    pub fn execution_deadline(&self) -> Option<Timestamp> {
+        self.execution_deadline
     }
 
     /// Returns the version for optimistic locking.
@@ -450,6 +858,39 @@ impl Rfq {
         self.expires_at.is_expired()
     }
 
+    /// Returns true if this RFQ has expired as of `now`.
+    #[inline]
+    #[must_use]
+    pub fn is_expired_at(&self, now: Timestamp) -> bool {
+        self.expires_at.is_expired_at(now)
+    }
+
+    /// Returns true if this RFQ is waiting for its activation time as of `now`.
+    ///
+    /// A scheduled RFQ stays in [`RfqState::Created`] and must not be sent
+    /// to venues until it is activated.
+    #[must_use]
+    pub fn is_scheduled_at(&self, now: Timestamp) -> bool {
+        self.state == RfqState::Created && self.activate_at.is_some_and(|at| at > now)
+    }
+
+    /// Returns true if this RFQ was scheduled and its activation time has
+    /// been reached as of `now`.
+    #[must_use]
+    pub fn is_due_for_activation_at(&self, now: Timestamp) -> bool {
+        self.state == RfqState::Created && self.activate_at.is_some_and(|at| at <= now)
+    }
+
+    /// Returns true if this RFQ is still executing past its execution
+    /// deadline as of `now`.
+    #[must_use]
+    pub fn is_execution_overdue_at(&self, now: Timestamp) -> bool {
+        self.state == RfqState::Executing
+            && self
+                .execution_deadline
+                .is_some_and(|deadline| deadline.is_expired_at(now))
+    }
+
     /// Returns the number of quotes received.
     #[inline]
     #[must_use]
@@ -481,6 +922,14 @@ impl Rfq {
     ///
     /// Transitions: QuoteRequesting → QuotesReceived (on first quote)
     ///
+    /// A venue's new quote on a side supersedes its earlier one, which is
+    /// kept in [`Rfq::retired_quotes`]. An expired quote is rejected but
+    /// kept there too.
+    ///
+    /// A shadow quote is kept in [`Rfq::shadow_quotes`] instead, replacing
+    /// its venue's earlier shadow quote on the side, and does not move the
+    /// RFQ to QuotesReceived.
+    ///
     /// # Arguments
     ///
     /// * `quote` - The quote to add
@@ -490,6 +939,10 @@ impl Rfq {
     /// Returns `DomainError::InvalidStateTransition` if not in QuoteRequesting or QuotesReceived state.
     /// Returns `DomainError::ValidationError` if quote doesn't belong to this RFQ.
     /// Returns `DomainError::QuoteExpired` if the quote has expired.
+    /// Returns `DomainError::ValidationError` if a net-premium quote targets a
+    /// single-instrument RFQ or prices a leg the strategy does not have.
+    /// Returns `DomainError::ValidationError` if the quote is untagged on a
+    /// two-way RFQ or tagged with the other side of a one-way RFQ.
     pub fn receive_quote(&mut self, quote: Quote) -> DomainResult<()> {
         // Validate quote belongs to this RFQ
         if quote.rfq_id() != self.id {
@@ -498,8 +951,57 @@ impl Rfq {
             ));
         }
 
-        // Validate quote is not expired
+        // Validate the quote's side against the RFQ's direction
+        match quote.side() {
+            None if self.two_way => {
+                return Err(DomainError::ValidationError(
+                    "quotes on a two-way RFQ must state their side".to_string(),
+                ));
+            }
+            Some(side) if !self.two_way && side != self.side => {
+                return Err(DomainError::ValidationError(format!(
+                    "{side} quote does not match {} RFQ",
+                    self.side
+                )));
+            }
+            _ => {}
+        }
+
+        // Validate net-premium quotes against the strategy legs
+        if quote.is_net_premium() {
+            let Some(strategy) = self.strategy() else {
+                return Err(DomainError::ValidationError(
+                    "net-premium quote requires a strategy RFQ".to_string(),
+                ));
+            };
+            if let Some(leg) = quote
+                .leg_prices()
+                .iter()
+                .find(|leg| leg.leg_index() >= strategy.leg_count())
+            {
+                return Err(DomainError::ValidationError(format!(
+                    "quote prices leg {} but strategy has {} legs",
+                    leg.leg_index(),
+                    strategy.leg_count()
+                )));
+            }
+        }
+
+        // Keep shadow quotes apart from the quotes clients see
+        if quote.is_shadow() {
+            return self.receive_shadow_quote(quote);
+        }
+
+        // Validate quote is not expired; keep it for the quote history
         if quote.is_expired() {
+            if matches!(
+                self.state,
+                RfqState::QuoteRequesting | RfqState::QuotesReceived
+            ) {
+                self.retired_quotes.push(RetiredQuote::expired(quote));
+                self.updated_at = Timestamp::now();
+                self.version = self.version.saturating_add(1);
+            }
             return Err(DomainError::QuoteExpired(
                 "cannot receive expired quote".to_string(),
             ));
@@ -508,10 +1010,12 @@ impl Rfq {
         // Check we're in a valid state to receive quotes
         match self.state {
             RfqState::QuoteRequesting => {
+                self.supersede_venue_quote(&quote);
                 self.quotes.push(quote);
                 self.transition_to(RfqState::QuotesReceived)?;
             }
             RfqState::QuotesReceived => {
+                self.supersede_venue_quote(&quote);
                 self.quotes.push(quote);
                 self.updated_at = Timestamp::now();
                 self.version = self.version.saturating_add(1);
@@ -527,6 +1031,81 @@ impl Rfq {
         Ok(())
     }
 
+    /// Records a quote from a venue in shadow mode.
+    fn receive_shadow_quote(&mut self, quote: Quote) -> DomainResult<()> {
+        if quote.is_expired() {
+            return Err(DomainError::QuoteExpired(
+                "cannot receive expired quote".to_string(),
+            ));
+        }
+        if !matches!(
+            self.state,
+            RfqState::QuoteRequesting | RfqState::QuotesReceived
+        ) {
+            return Err(DomainError::InvalidStateTransition {
+                from: self.state,
+                to: RfqState::QuotesReceived,
+            });
+        }
+
+        let rfq_side = self.side;
+        let side = self.side_of(&quote);
+        self.shadow_quotes
+            .retain(|q| q.venue_id() != quote.venue_id() || q.side().unwrap_or(rfq_side) != side);
+        self.shadow_quotes.push(quote);
+        self.updated_at = Timestamp::now();
+        self.version = self.version.saturating_add(1);
+        Ok(())
+    }
+
+    /// Retires the live quote `quote`'s venue already has on the same side,
+    /// if any, in favour of `quote`.
+    fn supersede_venue_quote(&mut self, quote: &Quote) {
+        let side = self.side_of(quote);
+        let Some(index) = self
+            .quotes
+            .iter()
+            .position(|q| q.venue_id() == quote.venue_id() && self.side_of(q) == side)
+        else {
+            return;
+        };
+        let previous = self.quotes.remove(index);
+        self.retired_quotes
+            .push(RetiredQuote::superseded(previous, quote.id()));
+    }
+
+    /// Withdraws a live quote that was attached but never offered.
+    ///
+    /// Used to roll back a quote whose counterpart could not be persisted,
+    /// such as one side of an internal cross. The quote is dropped rather
+    /// than retired, and the RFQ returns to QuoteRequesting if it was the
+    /// only quote.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidStateTransition` if not in QuotesReceived state.
+    /// Returns `DomainError::QuoteNotFound` if the quote isn't live on this RFQ.
+    pub fn withdraw_quote(&mut self, quote_id: QuoteId) -> DomainResult<()> {
+        if self.state != RfqState::QuotesReceived {
+            return Err(DomainError::InvalidStateTransition {
+                from: self.state,
+                to: RfqState::QuoteRequesting,
+            });
+        }
+        let index = self
+            .quotes
+            .iter()
+            .position(|q| q.id() == quote_id)
+            .ok_or_else(|| DomainError::QuoteNotFound(quote_id.to_string()))?;
+        self.quotes.remove(index);
+        if self.quotes.is_empty() {
+            self.state = RfqState::QuoteRequesting;
+        }
+        self.updated_at = Timestamp::now();
+        self.version = self.version.saturating_add(1);
+        Ok(())
+    }
+
     /// Selects a quote for execution.
     ///
     /// Transitions: QuotesReceived → ClientSelecting
@@ -540,7 +1119,38 @@ impl Rfq {
     /// Returns `DomainError::InvalidStateTransition` if not in QuotesReceived state.
     /// Returns `DomainError::QuoteNotFound` if quote doesn't exist.
     /// Returns `DomainError::QuoteExpired` if the selected quote has expired.
+    /// Returns `DomainError::ValidationError` if this is a two-way RFQ, which
+    /// must be selected with [`select_quote_on_side`](Self::select_quote_on_side).
+    /// Returns `DomainError::ValidationError` if the quote is from a venue in
+    /// shadow mode.
     pub fn select_quote(&mut self, quote_id: QuoteId) -> DomainResult<()> {
+        if self.two_way {
+            return Err(DomainError::ValidationError(
+                "selecting a quote on a two-way RFQ requires the side being hit".to_string(),
+            ));
+        }
+        self.select_quote_on_side(quote_id, self.side)
+    }
+
+    /// Selects a quote on the side the client is hitting.
+    ///
+    /// On a two-way RFQ this fixes the trade direction to `side` from then
+    /// on; quotes on the other side can no longer be selected.
+    ///
+    /// Transitions: QuotesReceived → ClientSelecting
+    ///
+    /// # Errors
+    ///
+    /// As [`select_quote`](Self::select_quote), and
+    /// `DomainError::ValidationError` if the quote is not on `side`.
+    pub fn select_quote_on_side(&mut self, quote_id: QuoteId, side: OrderSide) -> DomainResult<()> {
+        // Shadow quotes are only measured, never traded
+        if self.shadow_quotes.iter().any(|q| q.id() == quote_id) {
+            return Err(DomainError::ValidationError(format!(
+                "quote {quote_id} is from a shadow venue and cannot be selected"
+            )));
+        }
+
         // Find the quote
         let quote = self
             .quotes
@@ -548,6 +1158,14 @@ impl Rfq {
             .find(|q| q.id() == quote_id)
             .ok_or_else(|| DomainError::QuoteNotFound(quote_id.to_string()))?;
 
+        // Validate the quote is on the side being hit
+        let quote_side = self.side_of(quote);
+        if quote_side != side || (!self.two_way && side != self.side) {
+            return Err(DomainError::ValidationError(format!(
+                "quote {quote_id} is on the {quote_side} side, not {side}"
+            )));
+        }
+
         // Validate quote is not expired
         if quote.is_expired() {
             return Err(DomainError::QuoteExpired(
@@ -555,45 +1173,154 @@ impl Rfq {
             ));
         }
 
-        // Transition state
+        // Transition state and fix the direction
         self.transition_to(RfqState::ClientSelecting)?;
         self.selected_quote_id = Some(quote_id);
+        self.side = side;
+        self.two_way = false;
 
         Ok(())
     }
 
-    /// Starts execution of the selected quote.
+    /// Replaces an indicative quote with the firm version from its venue.
     ///
-    /// Transitions: ClientSelecting → Executing
+    /// If the indicative quote was selected, the selection moves to the
+    /// firm quote.
+    ///
+    /// # Arguments
+    ///
+    /// * `quote_id` - The ID of the indicative quote
+    /// * `firm` - The firm quote returned by the venue
     ///
     /// # Errors
     ///
-    /// Returns `DomainError::InvalidStateTransition` if not in ClientSelecting state.
-    /// Returns `DomainError::ValidationError` if no quote is selected.
-    /// Returns `DomainError::QuoteExpired` if the selected quote has expired.
-    pub fn start_execution(&mut self) -> DomainResult<()> {
-        // Validate a quote is selected
-        let quote_id = self.selected_quote_id.ok_or_else(|| {
-            DomainError::ValidationError("no quote selected for execution".to_string())
-        })?;
+    /// Returns `DomainError::InvalidState` if not in QuotesReceived, Negotiating,
+    /// or ClientSelecting state.
+    /// Returns `DomainError::QuoteNotFound` if the quote doesn't exist.
+    /// Returns `DomainError::ValidationError` if the quote is already firm, or
+    /// the firm quote is indicative or comes from another RFQ or venue.
+    /// Returns `DomainError::QuoteExpired` if the firm quote has expired.
+    pub fn firm_up_quote(&mut self, quote_id: QuoteId, firm: Quote) -> DomainResult<()> {
+        if !matches!(
+            self.state,
+            RfqState::QuotesReceived | RfqState::Negotiating | RfqState::ClientSelecting
+        ) {
+            return Err(DomainError::InvalidState(format!(
+                "cannot firm up quotes in state {}",
+                self.state
+            )));
+        }
 
-        // Validate selected quote is not expired
-        let quote = self
+        let index = self
             .quotes
             .iter()
-            .find(|q| q.id() == quote_id)
+            .position(|q| q.id() == quote_id)
             .ok_or_else(|| DomainError::QuoteNotFound(quote_id.to_string()))?;
+        let Some(indicative) = self.quotes.get(index) else {
+            return Err(DomainError::QuoteNotFound(quote_id.to_string()));
+        };
 
-        if quote.is_expired() {
-            return Err(DomainError::QuoteExpired(
-                "selected quote has expired".to_string(),
+        if !indicative.is_indicative() {
+            return Err(DomainError::ValidationError(
+                "quote is already firm".to_string(),
             ));
         }
-
-        self.transition_to(RfqState::Executing)
-    }
-
-    /// Marks the RFQ as successfully executed.
+        if firm.is_indicative() {
+            return Err(DomainError::ValidationError(
+                "firm-up returned an indicative quote".to_string(),
+            ));
+        }
+        if firm.rfq_id() != self.id
+            || firm.venue_id() != indicative.venue_id()
+            || firm
+                .side()
+                .is_some_and(|side| side != self.side_of(indicative))
+        {
+            return Err(DomainError::ValidationError(
+                "firm quote does not match the indicative quote".to_string(),
+            ));
+        }
+        let firm = match indicative.side() {
+            Some(side) if firm.side().is_none() => firm.with_side(side),
+            _ => firm,
+        };
+        if firm.is_expired() {
+            return Err(DomainError::QuoteExpired(
+                "firm quote has expired".to_string(),
+            ));
+        }
+
+        if self.selected_quote_id == Some(quote_id) {
+            self.selected_quote_id = Some(firm.id());
+        }
+        let firm_id = firm.id();
+        if let Some(slot) = self.quotes.get_mut(index) {
+            let indicative = std::mem::replace(slot, firm);
+            self.retired_quotes
+                .push(RetiredQuote::superseded(indicative, firm_id));
+        }
+        self.updated_at = Timestamp::now();
+        self.version = self.version.saturating_add(1);
+
+        Ok(())
+    }
+
+    /// Starts execution of the selected quote.
+    ///
+    /// Transitions: ClientSelecting → Executing
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidStateTransition` if not in ClientSelecting state.
+    /// Returns `DomainError::ValidationError` if no quote is selected.
+    /// Returns `DomainError::QuoteExpired` if the selected quote has expired.
+    /// Returns `DomainError::InvalidState` if the selected quote is indicative.
+    pub fn start_execution(&mut self) -> DomainResult<()> {
+        self.start_execution_within(DEFAULT_EXECUTION_TIMEOUT)
+    }
+
+    /// Starts execution of the selected quote, to finish within `timeout`.
+    ///
+    /// The execution deadline is the earlier of `timeout` from now and the
+    /// selected quote's expiry.
+    ///
+    /// Transitions: ClientSelecting → Executing
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Rfq::start_execution`].
+    pub fn start_execution_within(&mut self, timeout: Duration) -> DomainResult<()> {
+        // Validate a quote is selected
+        let quote_id = self.selected_quote_id.ok_or_else(|| {
+            DomainError::ValidationError("no quote selected for execution".to_string())
+        })?;
+
+        // Validate selected quote is not expired
+        let quote = self
+            .quotes
+            .iter()
+            .find(|q| q.id() == quote_id)
+            .ok_or_else(|| DomainError::QuoteNotFound(quote_id.to_string()))?;
+
+        if quote.is_expired() {
+            return Err(DomainError::QuoteExpired(
+                "selected quote has expired".to_string(),
+            ));
+        }
+
+        if quote.is_indicative() {
+            return Err(DomainError::InvalidState(
+                "selected quote is indicative and must be firmed up".to_string(),
+            ));
+        }
+
+        let deadline = (Timestamp::now() + timeout).min(quote.valid_until());
+        self.transition_to(RfqState::Executing)?;
+        self.execution_deadline = Some(deadline);
+        Ok(())
+    }
+
+    /// Marks the RFQ as successfully executed.
     ///
     /// Transitions: Executing → Executed
     ///
@@ -615,11 +1342,37 @@ impl Rfq {
     /// # Errors
     ///
     /// Returns `DomainError::InvalidStateTransition` if in a terminal state.
-    pub fn mark_failed(&mut self, reason: impl Into<String>) -> DomainResult<()> {
-        self.failure_reason = Some(reason.into());
+    pub fn mark_failed(&mut self, reason: FailureReason) -> DomainResult<()> {
+        self.failure_reason = Some(reason);
         self.transition_to(RfqState::Failed)
     }
 
+    /// Forces a stuck RFQ to `target`, recording an outcome an
+    /// administrator established out of band.
+    ///
+    /// An RFQ forced to Failed keeps `justification` as its failure reason.
+    /// Returns the state it was forced from.
+    ///
+    /// Transitions: Executing → Executed/Failed
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidStateTransition` unless
+    /// [`RfqState::can_force_to`] allows the transition.
+    pub fn force_state(&mut self, target: RfqState, justification: &str) -> DomainResult<RfqState> {
+        let from = self.state;
+        if !from.can_force_to(target) {
+            return Err(DomainError::InvalidStateTransition { from, to: target });
+        }
+        if target == RfqState::Failed {
+            self.failure_reason = Some(FailureReason::other(justification));
+        }
+        self.state = target;
+        self.updated_at = Timestamp::now();
+        self.version = self.version.saturating_add(1);
+        Ok(from)
+    }
+
     /// Cancels the RFQ.
     ///
     /// Transitions: Created/QuoteRequesting/QuotesReceived/ClientSelecting → Cancelled
@@ -648,6 +1401,16 @@ impl Rfq {
         self.updated_at = Timestamp::now();
         self.version = self.version.saturating_add(1);
     }
+
+    /// Replaces the client with the pseudonym of its erased counterparty.
+    ///
+    /// Allowed in any state: the erasure keeps the RFQ's economics and only
+    /// swaps the reference to the client.
+    pub fn pseudonymize_client(&mut self, pseudonym: CounterpartyId) {
+        self.client_id = pseudonym;
+        self.updated_at = Timestamp::now();
+        self.version = self.version.saturating_add(1);
+    }
 }
 
 impl fmt::Display for Rfq {
@@ -655,7 +1418,7 @@ impl fmt::Display for Rfq {
         write!(
             f,
             "RFQ({} {} {} {} [{}])",
-            self.id, self.side, self.quantity, self.instrument, self.state
+            self.id, self.side, self.quantity, self.subject, self.state
         )
     }
 }
@@ -687,39 +1450,83 @@ impl fmt::Display for Rfq {
 #[derive(Debug, Clone)]
 pub struct RfqBuilder {
     client_id: CounterpartyId,
-    instrument: Instrument,
-    side: OrderSide,
+    subject: RfqSubject,
+    direction: RfqDirection,
     quantity: Quantity,
     min_quantity: Option<Quantity>,
+    size_mode: SizeNegotiationMode,
     anonymity_level: AnonymityLevel,
+    allow_internal_crossing: bool,
+    venue_allowlist: Option<Vec<VenueId>>,
+    venue_blocklist: Vec<VenueId>,
+    compensation_policy: CompensationPolicy,
+    quantity_disclosure: QuantityDisclosure,
     expires_at: Timestamp,
+    activate_at: Option<Timestamp>,
 }
 
 impl RfqBuilder {
     /// Creates a new builder with required fields.
+    ///
+    /// `direction` is an [`OrderSide`] for a one-way RFQ or
+    /// [`RfqDirection::TwoWay`].
     #[must_use]
     pub fn new(
         client_id: CounterpartyId,
-        instrument: Instrument,
-        side: OrderSide,
+        subject: impl Into<RfqSubject>,
+        direction: impl Into<RfqDirection>,
         quantity: Quantity,
         expires_at: Timestamp,
     ) -> Self {
         Self {
             client_id,
-            instrument,
-            side,
+            subject: subject.into(),
+            direction: direction.into(),
             quantity,
             min_quantity: None,
+            size_mode: SizeNegotiationMode::default(),
             anonymity_level: AnonymityLevel::default(),
+            allow_internal_crossing: false,
+            venue_allowlist: None,
+            venue_blocklist: Vec::new(),
+            compensation_policy: CompensationPolicy::default(),
+            quantity_disclosure: QuantityDisclosure::default(),
             expires_at,
+            activate_at: None,
         }
     }
 
     /// Sets the minimum acceptable quantity for partial fills.
+    ///
+    /// Also sets the size mode to [`SizeNegotiationMode::MinQuantity`].
     #[must_use]
     pub fn min_quantity(mut self, min_quantity: Quantity) -> Self {
         self.min_quantity = Some(min_quantity);
+        self.size_mode = SizeNegotiationMode::MinQuantity(min_quantity);
+        self
+    }
+
+    /// Sets how the requested quantity may be filled.
+    ///
+    /// The minimum quantity follows the mode's threshold, if any.
+    #[must_use]
+    pub fn size_mode(mut self, size_mode: SizeNegotiationMode) -> Self {
+        self.min_quantity = size_mode.min_quantity();
+        self.size_mode = size_mode;
+        self
+    }
+
+    /// Sets how a partially failed multi-venue fill is compensated.
+    #[must_use]
+    pub fn compensation_policy(mut self, policy: CompensationPolicy) -> Self {
+        self.compensation_policy = policy;
+        self
+    }
+
+    /// Sets how much of the quantity venues are shown.
+    #[must_use]
+    pub fn quantity_disclosure(mut self, disclosure: QuantityDisclosure) -> Self {
+        self.quantity_disclosure = disclosure;
         self
     }
 
@@ -737,6 +1544,40 @@ impl RfqBuilder {
         self
     }
 
+    /// Opts the RFQ in to crossing against opposing client RFQs before
+    /// quotes are requested from external venues.
+    #[must_use]
+    pub fn allow_internal_crossing(mut self, allow: bool) -> Self {
+        self.allow_internal_crossing = allow;
+        self
+    }
+
+    /// Restricts the RFQ to the given venues.
+    ///
+    /// Takes precedence over the blocklist.
+    #[must_use]
+    pub fn venue_allowlist(mut self, venues: Vec<VenueId>) -> Self {
+        self.venue_allowlist = Some(venues);
+        self
+    }
+
+    /// Keeps the RFQ away from the given venues.
+    #[must_use]
+    pub fn venue_blocklist(mut self, venues: Vec<VenueId>) -> Self {
+        self.venue_blocklist = venues;
+        self
+    }
+
+    /// Schedules the RFQ to start collecting quotes at `activate_at`.
+    ///
+    /// The RFQ is created immediately but stays in
+    /// [`RfqState::Created`] until the activation time.
+    #[must_use]
+    pub fn activate_at(mut self, activate_at: Timestamp) -> Self {
+        self.activate_at = Some(activate_at);
+        self
+    }
+
     /// Builds the RFQ without validation.
     ///
     /// Use [`try_build`](Self::try_build) for validated construction.
@@ -746,17 +1587,29 @@ impl RfqBuilder {
         Rfq {
             id: RfqId::new_v4(),
             client_id: self.client_id,
-            instrument: self.instrument,
-            side: self.side,
+            subject: self.subject,
+            side: self.direction.side().unwrap_or(OrderSide::Buy),
+            two_way: self.direction.is_two_way(),
             quantity: self.quantity,
             min_quantity: self.min_quantity,
+            size_mode: self.size_mode,
             anonymity_level: self.anonymity_level,
+            allow_internal_crossing: self.allow_internal_crossing,
+            venue_allowlist: self.venue_allowlist,
+            venue_blocklist: self.venue_blocklist,
+            compensation_policy: self.compensation_policy,
+            quantity_disclosure: self.quantity_disclosure,
             state: RfqState::Created,
             expires_at: self.expires_at,
+            activate_at: self.activate_at,
             quotes: Vec::new(),
+            retired_quotes: Vec::new(),
+            shadow_quotes: Vec::new(),
+            quote_ranks: Vec::new(),
             selected_quote_id: None,
             compliance_result: None,
             failure_reason: None,
+            execution_deadline: None,
             version: 1,
             created_at: now,
             updated_at: now,
@@ -771,22 +1624,57 @@ impl RfqBuilder {
     pub fn try_build(self) -> DomainResult<Rfq> {
         Rfq::validate_quantity(&self.quantity)?;
         Rfq::validate_expiry(&self.expires_at)?;
+        self.size_mode.validate_for(self.quantity)?;
+        self.compensation_policy.validate_for(&self.size_mode)?;
+        self.quantity_disclosure.validate_for(self.quantity)?;
+        if self.direction.is_two_way()
+            && (self.subject.is_multi_leg() || self.allow_internal_crossing)
+        {
+            return Err(DomainError::ValidationError(
+                "two-way RFQs cannot quote strategies or cross internally".to_string(),
+            ));
+        }
+        if self.venue_allowlist.as_ref().is_some_and(Vec::is_empty) {
+            return Err(DomainError::NoEligibleVenues(
+                "venue allowlist is empty".to_string(),
+            ));
+        }
+        if let Some(activate_at) = self.activate_at
+            && self.expires_at <= activate_at
+        {
+            return Err(DomainError::ValidationError(format!(
+                "expires_at ({}) must be after activate_at ({activate_at})",
+                self.expires_at
+            )));
+        }
 
         let now = Timestamp::now();
         Ok(Rfq {
             id: RfqId::new_v4(),
             client_id: self.client_id,
-            instrument: self.instrument,
-            side: self.side,
+            subject: self.subject,
+            side: self.direction.side().unwrap_or(OrderSide::Buy),
+            two_way: self.direction.is_two_way(),
             quantity: self.quantity,
             min_quantity: self.min_quantity,
+            size_mode: self.size_mode,
             anonymity_level: self.anonymity_level,
+            allow_internal_crossing: self.allow_internal_crossing,
+            venue_allowlist: self.venue_allowlist,
+            venue_blocklist: self.venue_blocklist,
+            compensation_policy: self.compensation_policy,
+            quantity_disclosure: self.quantity_disclosure,
             state: RfqState::Created,
             expires_at: self.expires_at,
+            activate_at: self.activate_at,
             quotes: Vec::new(),
+            retired_quotes: Vec::new(),
+            shadow_quotes: Vec::new(),
+            quote_ranks: Vec::new(),
             selected_quote_id: None,
             compliance_result: None,
             failure_reason: None,
+            execution_deadline: None,
             version: 1,
             created_at: now,
             updated_at: now,
@@ -798,8 +1686,8 @@ impl RfqBuilder {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
-    use crate::domain::entities::quote::QuoteBuilder;
-    use crate::domain::value_objects::{Price, VenueId};
+    use crate::domain::entities::quote::{QuoteBuilder, QuoteFirmness, QuoteLegPrice};
+    use crate::domain::value_objects::{FailureCode, Premium, Price, VenueId};
 
     fn test_client_id() -> CounterpartyId {
         CounterpartyId::new("test-client")
@@ -811,6 +1699,11 @@ mod tests {
         Instrument::builder(symbol, AssetClass::CryptoSpot).build()
     }
 
+    fn test_straddle() -> Strategy {
+        let inst = test_instrument();
+        Strategy::straddle(inst.clone(), inst, "BTC").unwrap()
+    }
+
     fn test_quantity() -> Quantity {
         Quantity::new(1.0).unwrap()
     }
@@ -896,6 +1789,184 @@ mod tests {
             let rfq = create_test_rfq();
             assert_eq!(rfq.state(), RfqState::Created);
             assert!(rfq.is_active());
+            assert_eq!(rfq.size_mode(), &SizeNegotiationMode::AllOrNothing);
+        }
+
+        #[test]
+        fn builder_size_mode_sets_min_quantity() {
+            let min = Quantity::new(0.25).unwrap();
+            let builder = || {
+                RfqBuilder::new(
+                    test_client_id(),
+                    test_instrument(),
+                    OrderSide::Buy,
+                    test_quantity(),
+                    future_timestamp(),
+                )
+            };
+
+            let rfq = builder()
+                .size_mode(SizeNegotiationMode::MinQuantity(min))
+                .try_build()
+                .unwrap();
+            assert_eq!(rfq.min_quantity(), Some(min));
+
+            let rfq = builder()
+                .min_quantity(min)
+                .size_mode(SizeNegotiationMode::FillOrKill)
+                .try_build()
+                .unwrap();
+            assert_eq!(rfq.size_mode(), &SizeNegotiationMode::FillOrKill);
+            assert_eq!(rfq.min_quantity(), None);
+        }
+
+        #[test]
+        fn try_build_rejects_min_quantity_above_quantity() {
+            let result = RfqBuilder::new(
+                test_client_id(),
+                test_instrument(),
+                OrderSide::Buy,
+                Quantity::new(1.0).unwrap(),
+                future_timestamp(),
+            )
+            .size_mode(SizeNegotiationMode::MinQuantity(
+                Quantity::new(2.0).unwrap(),
+            ))
+            .try_build();
+
+            assert!(matches!(result, Err(DomainError::InvalidMinQuantity(_))));
+        }
+
+        fn venue_builder() -> RfqBuilder {
+            RfqBuilder::new(
+                test_client_id(),
+                test_instrument(),
+                OrderSide::Buy,
+                Quantity::new(1.0).unwrap(),
+                future_timestamp(),
+            )
+        }
+
+        #[test]
+        fn blocklist_excludes_venue() {
+            let rfq = venue_builder()
+                .venue_blocklist(vec![VenueId::new("mm-leaky")])
+                .build();
+
+            assert_eq!(
+                rfq.venue_exclusion(&VenueId::new("mm-leaky")),
+                Some(VenueExclusionReason::Blocklisted)
+            );
+            assert_eq!(rfq.venue_exclusion(&VenueId::new("mm-1")), None);
+        }
+
+        #[test]
+        fn allowlist_restricts_venues() {
+            let rfq = venue_builder()
+                .venue_allowlist(vec![VenueId::new("mm-1"), VenueId::new("mm-2")])
+                .build();
+
+            assert_eq!(rfq.venue_exclusion(&VenueId::new("mm-2")), None);
+            assert_eq!(
+                rfq.venue_exclusion(&VenueId::new("mm-3")),
+                Some(VenueExclusionReason::NotAllowlisted)
+            );
+        }
+
+        #[test]
+        fn allowlist_wins_over_blocklist() {
+            let rfq = venue_builder()
+                .venue_allowlist(vec![VenueId::new("mm-1")])
+                .venue_blocklist(vec![VenueId::new("mm-1"), VenueId::new("mm-2")])
+                .build();
+
+            assert_eq!(rfq.venue_exclusion(&VenueId::new("mm-1")), None);
+            assert_eq!(
+                rfq.venue_exclusion(&VenueId::new("mm-2")),
+                Some(VenueExclusionReason::NotAllowlisted)
+            );
+        }
+
+        #[test]
+        fn try_build_rejects_empty_allowlist() {
+            let result = venue_builder().venue_allowlist(Vec::new()).try_build();
+
+            assert!(matches!(result, Err(DomainError::NoEligibleVenues(_))));
+        }
+
+        #[test]
+        fn compensation_policy_defaults_to_reoffer() {
+            let rfq = venue_builder().build();
+            assert_eq!(
+                rfq.compensation_policy(),
+                CompensationPolicy::ReofferRemainder
+            );
+
+            let rfq = venue_builder()
+                .compensation_policy(CompensationPolicy::UnwindAll)
+                .build();
+            assert_eq!(rfq.compensation_policy(), CompensationPolicy::UnwindAll);
+        }
+
+        #[test]
+        fn try_build_rejects_accept_partial_without_partial_fills() {
+            let result = venue_builder()
+                .compensation_policy(CompensationPolicy::AcceptPartial)
+                .try_build();
+            assert!(matches!(result, Err(DomainError::ValidationError(_))));
+
+            let rfq = venue_builder()
+                .size_mode(SizeNegotiationMode::BestEffort)
+                .compensation_policy(CompensationPolicy::AcceptPartial)
+                .try_build()
+                .unwrap();
+            assert_eq!(rfq.compensation_policy(), CompensationPolicy::AcceptPartial);
+        }
+
+        #[test]
+        fn bucketed_disclosure_rounds_up_and_rejects_zero_bucket() {
+            let bucket = Quantity::new(0.4).unwrap();
+            let rfq = venue_builder()
+                .quantity_disclosure(QuantityDisclosure::Bucketed { bucket })
+                .try_build()
+                .unwrap();
+            assert!(rfq.quantity_disclosure().is_masked());
+            assert_eq!(rfq.disclosed_quantity(), Some(Quantity::new(1.2).unwrap()));
+
+            let result = venue_builder()
+                .quantity_disclosure(QuantityDisclosure::Bucketed {
+                    bucket: Quantity::zero(),
+                })
+                .try_build();
+            assert!(matches!(result, Err(DomainError::ValidationError(_))));
+        }
+
+        #[test]
+        fn scheduled_rfq_is_pending_until_activation_time() {
+            let activate_at = Timestamp::now().add_secs(60);
+            let rfq = venue_builder()
+                .activate_at(activate_at)
+                .try_build()
+                .unwrap();
+
+            assert_eq!(rfq.activate_at(), Some(activate_at));
+            assert!(rfq.is_scheduled_at(activate_at.sub_secs(1)));
+            assert!(!rfq.is_due_for_activation_at(activate_at.sub_secs(1)));
+            assert!(!rfq.is_scheduled_at(activate_at));
+            assert!(rfq.is_due_for_activation_at(activate_at));
+        }
+
+        #[test]
+        fn try_build_rejects_expiry_not_after_activation() {
+            let builder = venue_builder();
+            let expires_at = builder.expires_at;
+
+            let at_expiry = builder.clone().activate_at(expires_at).try_build();
+            assert!(matches!(at_expiry, Err(DomainError::ValidationError(_))));
+
+            let result = builder.activate_at(expires_at.add_secs(60)).try_build();
+
+            assert!(matches!(result, Err(DomainError::ValidationError(_))));
         }
     }
 
@@ -939,7 +2010,14 @@ mod tests {
             rfq.start_quote_collection().unwrap();
 
             let quote1 = create_test_quote(rfq.id());
-            let quote2 = create_test_quote(rfq.id());
+            let quote2 = QuoteBuilder::new(
+                rfq.id(),
+                VenueId::new("other-venue"),
+                Price::new(50000.0).unwrap(),
+                Quantity::new(1.0).unwrap(),
+                future_timestamp(),
+            )
+            .build();
 
             rfq.receive_quote(quote1).unwrap();
             rfq.receive_quote(quote2).unwrap();
@@ -948,6 +2026,53 @@ mod tests {
             assert_eq!(rfq.state(), RfqState::QuotesReceived);
         }
 
+        #[test]
+        fn requote_from_same_venue_supersedes_earlier_quote() {
+            let mut rfq = create_test_rfq();
+            rfq.start_quote_collection().unwrap();
+
+            let first = create_test_quote(rfq.id());
+            let first_id = first.id();
+            let requote = create_test_quote(rfq.id());
+            let requote_id = requote.id();
+            rfq.receive_quote(first).unwrap();
+            rfq.receive_quote(requote).unwrap();
+
+            assert_eq!(rfq.quote_count(), 1);
+            let retired = rfq.retired_quotes().first().unwrap();
+            assert_eq!(retired.quote().id(), first_id);
+            assert_eq!(retired.status(), QuoteStatus::Superseded);
+            assert_eq!(retired.superseded_by(), Some(requote_id));
+            assert_eq!(rfq.quote_history().len(), 2);
+        }
+
+        #[test]
+        fn expired_quote_is_rejected_but_kept_in_history() {
+            let mut rfq = create_test_rfq();
+            rfq.start_quote_collection().unwrap();
+
+            let expired = QuoteBuilder::new(
+                rfq.id(),
+                VenueId::new("test-venue"),
+                Price::new(50000.0).unwrap(),
+                Quantity::new(1.0).unwrap(),
+                past_timestamp(),
+            )
+            .build();
+            let result = rfq.receive_quote(expired);
+
+            assert!(matches!(result, Err(DomainError::QuoteExpired(_))));
+            assert_eq!(rfq.quote_count(), 0);
+            let history = rfq.quote_history();
+            assert_eq!(history.len(), 1);
+            assert!(
+                history
+                    .iter()
+                    .all(|entry| entry.status == QuoteStatus::Expired)
+            );
+            assert!(rfq.best_price_timeline().is_empty());
+        }
+
         #[test]
         fn receive_quote_fails_for_wrong_rfq() {
             let mut rfq = create_test_rfq();
@@ -959,6 +2084,28 @@ mod tests {
             assert!(matches!(result, Err(DomainError::ValidationError(_))));
         }
 
+        #[test]
+        fn withdraw_only_quote_returns_to_quote_requesting() {
+            let mut rfq = create_test_rfq();
+            rfq.start_quote_collection().unwrap();
+
+            let quote = create_test_quote(rfq.id());
+            let quote_id = quote.id();
+            rfq.receive_quote(quote).unwrap();
+            let version = rfq.version();
+
+            rfq.withdraw_quote(quote_id).unwrap();
+
+            assert_eq!(rfq.quote_count(), 0);
+            assert_eq!(rfq.state(), RfqState::QuoteRequesting);
+            assert!(rfq.retired_quotes().is_empty());
+            assert!(rfq.version() > version);
+            assert!(matches!(
+                rfq.withdraw_quote(quote_id),
+                Err(DomainError::InvalidStateTransition { .. })
+            ));
+        }
+
         #[test]
         fn select_quote_transitions_to_client_selecting() {
             let mut rfq = create_test_rfq();
@@ -985,6 +2132,25 @@ mod tests {
             assert!(matches!(result, Err(DomainError::QuoteNotFound(_))));
         }
 
+        #[test]
+        fn shadow_quote_is_kept_apart_and_cannot_be_selected() {
+            let mut rfq = create_test_rfq();
+            rfq.start_quote_collection().unwrap();
+
+            let shadow = create_test_quote(rfq.id()).with_shadow(true);
+            let shadow_id = shadow.id();
+            rfq.receive_quote(shadow).unwrap();
+
+            assert!(rfq.quotes().is_empty());
+            assert_eq!(rfq.state(), RfqState::QuoteRequesting);
+            assert_eq!(rfq.shadow_quotes().first().unwrap().id(), shadow_id);
+
+            rfq.receive_quote(create_test_quote(rfq.id())).unwrap();
+            let result = rfq.select_quote(shadow_id);
+            assert!(matches!(result, Err(DomainError::ValidationError(_))));
+            assert!(rfq.selected_quote_id().is_none());
+        }
+
         #[test]
         fn start_execution_transitions_to_executing() {
             let mut rfq = create_test_rfq();
@@ -999,6 +2165,73 @@ mod tests {
             assert_eq!(rfq.state(), RfqState::Executing);
         }
 
+        #[test]
+        fn start_execution_stamps_the_earlier_deadline() {
+            let mut rfq = create_test_rfq();
+            rfq.start_quote_collection().unwrap();
+            let quote = create_test_quote(rfq.id());
+            let (quote_id, valid_until) = (quote.id(), quote.valid_until());
+            rfq.receive_quote(quote).unwrap();
+            rfq.select_quote(quote_id).unwrap();
+            assert_eq!(rfq.execution_deadline(), None);
+
+            let mut short = rfq.clone();
+            let before = Timestamp::now();
+            short
+                .start_execution_within(Duration::from_secs(5))
+                .unwrap();
+            let deadline = short.execution_deadline().unwrap();
+            assert!(deadline >= before.add_secs(5) && deadline < valid_until);
+            assert!(!short.is_execution_overdue_at(deadline));
+            assert!(short.is_execution_overdue_at(deadline.add_millis(1)));
+
+            // The quote expires before the timeout would
+            rfq.start_execution_within(Duration::from_secs(3600))
+                .unwrap();
+            assert_eq!(rfq.execution_deadline(), Some(valid_until));
+
+            rfq.mark_executed().unwrap();
+            assert!(!rfq.is_execution_overdue_at(valid_until.add_secs(1)));
+        }
+
+        #[test]
+        fn start_execution_requires_firm_quote() {
+            let mut rfq = create_test_rfq();
+            rfq.start_quote_collection().unwrap();
+
+            let indicative = create_test_quote(rfq.id()).with_firmness(QuoteFirmness::Indicative);
+            let indicative_id = indicative.id();
+            rfq.receive_quote(indicative).unwrap();
+            rfq.select_quote(indicative_id).unwrap();
+
+            let result = rfq.start_execution();
+            assert!(matches!(result, Err(DomainError::InvalidState(_))));
+
+            let firm = create_test_quote(rfq.id());
+            let firm_id = firm.id();
+            rfq.firm_up_quote(indicative_id, firm).unwrap();
+
+            assert_eq!(rfq.selected_quote_id(), Some(firm_id));
+            assert_eq!(rfq.quotes().len(), 1);
+            let retired = rfq.retired_quotes().first().unwrap();
+            assert_eq!(retired.quote().id(), indicative_id);
+            assert_eq!(retired.superseded_by(), Some(firm_id));
+            assert!(rfq.start_execution().is_ok());
+        }
+
+        #[test]
+        fn firm_up_quote_rejects_firm_quotes() {
+            let mut rfq = create_test_rfq();
+            rfq.start_quote_collection().unwrap();
+
+            let quote = create_test_quote(rfq.id());
+            let quote_id = quote.id();
+            rfq.receive_quote(quote).unwrap();
+
+            let result = rfq.firm_up_quote(quote_id, create_test_quote(rfq.id()));
+            assert!(matches!(result, Err(DomainError::ValidationError(_))));
+        }
+
         #[test]
         fn start_execution_fails_without_selected_quote() {
             let mut rfq = create_test_rfq();
@@ -1036,9 +2269,10 @@ mod tests {
             let mut rfq = create_test_rfq();
             rfq.start_quote_collection().unwrap();
 
-            assert!(rfq.mark_failed("test failure").is_ok());
+            let reason = FailureReason::new(FailureCode::VenueRejected, "test failure");
+            assert!(rfq.mark_failed(reason.clone()).is_ok());
             assert_eq!(rfq.state(), RfqState::Failed);
-            assert_eq!(rfq.failure_reason(), Some("test failure"));
+            assert_eq!(rfq.failure_reason(), Some(&reason));
         }
 
         #[test]
@@ -1068,6 +2302,44 @@ mod tests {
             ));
         }
 
+        #[test]
+        fn force_state_rescues_a_stuck_execution() {
+            let mut rfq = create_test_rfq();
+            rfq.start_quote_collection().unwrap();
+            let quote = create_test_quote(rfq.id());
+            let quote_id = quote.id();
+            rfq.receive_quote(quote).unwrap();
+            rfq.select_quote(quote_id).unwrap();
+            rfq.start_execution().unwrap();
+            let version = rfq.version();
+
+            let from = rfq
+                .force_state(RfqState::Failed, "venue lost the order")
+                .unwrap();
+
+            assert_eq!(from, RfqState::Executing);
+            assert_eq!(rfq.state(), RfqState::Failed);
+            assert_eq!(rfq.version(), version + 1);
+            assert_eq!(
+                rfq.failure_reason(),
+                Some(&FailureReason::other("venue lost the order"))
+            );
+        }
+
+        #[test]
+        fn force_state_refuses_transitions_outside_the_allowlist() {
+            let mut rfq = create_test_rfq();
+            rfq.start_quote_collection().unwrap();
+
+            let result = rfq.force_state(RfqState::Executed, "looks done");
+
+            assert!(matches!(
+                result,
+                Err(DomainError::InvalidStateTransition { .. })
+            ));
+            assert_eq!(rfq.state(), RfqState::QuoteRequesting);
+        }
+
         #[test]
         fn expire_transitions_to_expired() {
             let mut rfq = create_test_rfq();
@@ -1180,5 +2452,226 @@ mod tests {
             assert_eq!(rfq.state(), deserialized.state());
             assert_eq!(rfq.version(), deserialized.version());
         }
+
+        #[test]
+        fn single_instrument_keeps_instrument_key() {
+            let rfq = create_test_rfq();
+
+            let json = serde_json::to_value(&rfq).unwrap();
+            assert!(json.get("instrument").is_some());
+            assert!(json.get("strategy").is_none());
+        }
+
+        #[test]
+        fn strategy_roundtrip() {
+            let rfq = Rfq::new(
+                test_client_id(),
+                test_straddle(),
+                OrderSide::Buy,
+                test_quantity(),
+                future_timestamp(),
+            )
+            .unwrap();
+
+            let json = serde_json::to_value(&rfq).unwrap();
+            assert!(json.get("strategy").is_some());
+            assert!(json.get("instrument").is_none());
+
+            let deserialized: Rfq = serde_json::from_value(json).unwrap();
+            assert_eq!(rfq, deserialized);
+        }
+    }
+
+    mod subject {
+        use super::*;
+
+        #[test]
+        fn single_instrument_is_not_multi_leg() {
+            let rfq = create_test_rfq();
+
+            assert!(!rfq.is_multi_leg());
+            assert!(rfq.strategy().is_none());
+            assert_eq!(rfq.instrument(), &test_instrument());
+        }
+
+        #[test]
+        fn strategy_exposes_primary_leg_instrument() {
+            let straddle = test_straddle();
+            let rfq = RfqBuilder::new(
+                test_client_id(),
+                straddle.clone(),
+                OrderSide::Buy,
+                test_quantity(),
+                future_timestamp(),
+            )
+            .build();
+
+            assert!(rfq.is_multi_leg());
+            assert_eq!(rfq.strategy(), Some(&straddle));
+            assert_eq!(rfq.instrument(), straddle.primary_leg().instrument());
+        }
+
+        fn net_premium_quote(rfq: &Rfq, legs: Vec<QuoteLegPrice>) -> Quote {
+            Quote::new_net_premium(
+                rfq.id(),
+                VenueId::new("venue"),
+                Premium::new(rust_decimal::Decimal::new(-120, 2)),
+                legs,
+                test_quantity(),
+                future_timestamp(),
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn strategy_rfq_accepts_net_premium_quote() {
+            let mut rfq = RfqBuilder::new(
+                test_client_id(),
+                test_straddle(),
+                OrderSide::Buy,
+                test_quantity(),
+                future_timestamp(),
+            )
+            .build();
+            rfq.start_quote_collection().unwrap();
+
+            let quote = net_premium_quote(&rfq, vec![QuoteLegPrice::new(1, None)]);
+            rfq.receive_quote(quote).unwrap();
+
+            assert!(rfq.quotes().first().unwrap().net_premium().is_credit());
+        }
+
+        #[test]
+        fn net_premium_quote_rejects_unknown_leg() {
+            let mut rfq = RfqBuilder::new(
+                test_client_id(),
+                test_straddle(),
+                OrderSide::Buy,
+                test_quantity(),
+                future_timestamp(),
+            )
+            .build();
+            rfq.start_quote_collection().unwrap();
+
+            let quote = net_premium_quote(&rfq, vec![QuoteLegPrice::new(2, None)]);
+
+            assert!(matches!(
+                rfq.receive_quote(quote),
+                Err(DomainError::ValidationError(_))
+            ));
+        }
+
+        #[test]
+        fn single_instrument_rejects_net_premium_quote() {
+            let mut rfq = create_test_rfq();
+            rfq.start_quote_collection().unwrap();
+
+            let quote = net_premium_quote(&rfq, vec![]);
+
+            assert!(matches!(
+                rfq.receive_quote(quote),
+                Err(DomainError::ValidationError(_))
+            ));
+        }
+    }
+
+    mod two_way {
+        use super::*;
+
+        fn two_way_rfq() -> Rfq {
+            let mut rfq = RfqBuilder::new(
+                test_client_id(),
+                test_instrument(),
+                RfqDirection::TwoWay,
+                test_quantity(),
+                future_timestamp(),
+            )
+            .build();
+            rfq.start_quote_collection().unwrap();
+            rfq
+        }
+
+        fn sided_quote(rfq_id: RfqId, side: OrderSide) -> Quote {
+            QuoteBuilder::new(
+                rfq_id,
+                VenueId::new("test-venue"),
+                Price::new(50000.0).unwrap(),
+                test_quantity(),
+                future_timestamp(),
+            )
+            .side(side)
+            .build()
+        }
+
+        #[test]
+        fn selection_fixes_the_side() {
+            let mut rfq = two_way_rfq();
+            assert_eq!(rfq.direction(), RfqDirection::TwoWay);
+            assert!(rfq.resolved_side().is_err());
+
+            let bid = sided_quote(rfq.id(), OrderSide::Sell);
+            let bid_id = bid.id();
+            rfq.receive_quote(sided_quote(rfq.id(), OrderSide::Buy))
+                .unwrap();
+            rfq.receive_quote(bid).unwrap();
+            assert_eq!(rfq.quotes_on(OrderSide::Sell).count(), 1);
+
+            assert!(matches!(
+                rfq.select_quote(bid_id),
+                Err(DomainError::ValidationError(_))
+            ));
+            assert!(matches!(
+                rfq.select_quote_on_side(bid_id, OrderSide::Buy),
+                Err(DomainError::ValidationError(_))
+            ));
+            assert_eq!(rfq.state(), RfqState::QuotesReceived);
+
+            rfq.select_quote_on_side(bid_id, OrderSide::Sell).unwrap();
+            assert_eq!(rfq.state(), RfqState::ClientSelecting);
+            assert_eq!(rfq.direction(), RfqDirection::Sell);
+            assert_eq!(rfq.resolved_side().unwrap(), OrderSide::Sell);
+            assert!(!rfq.is_two_way());
+        }
+
+        #[test]
+        fn rejects_untagged_quotes() {
+            let mut rfq = two_way_rfq();
+            let quote = create_test_quote(rfq.id());
+
+            assert!(matches!(
+                rfq.receive_quote(quote),
+                Err(DomainError::ValidationError(_))
+            ));
+        }
+
+        #[test]
+        fn one_way_rfq_rejects_other_side() {
+            let mut rfq = create_test_rfq();
+            rfq.start_quote_collection().unwrap();
+
+            assert!(
+                rfq.receive_quote(sided_quote(rfq.id(), OrderSide::Sell))
+                    .is_err()
+            );
+            let quote = sided_quote(rfq.id(), OrderSide::Buy);
+            let quote_id = quote.id();
+            rfq.receive_quote(quote).unwrap();
+            rfq.select_quote(quote_id).unwrap();
+            assert_eq!(rfq.direction(), RfqDirection::Buy);
+        }
+
+        #[test]
+        fn rejects_strategies() {
+            let result = RfqBuilder::new(
+                test_client_id(),
+                test_straddle(),
+                RfqDirection::TwoWay,
+                test_quantity(),
+                future_timestamp(),
+            )
+            .try_build();
+
+            assert!(matches!(result, Err(DomainError::ValidationError(_))));
+        }
     }
 }
@@ -0,0 +1,458 @@
+//! # RFQ Template
+//!
+//! Saved request parameters a client can reuse to create RFQs.
+//!
+//! This module provides the [`RfqTemplate`] entity and its
+//! [`RfqTemplateBuilder`]. A template belongs to one counterparty and
+//! holds everything needed to create an RFQ except its expiry, which is
+//! derived from the template's default TTL when the template is
+//! instantiated.
+//!
+//! A template is valid only if the RFQ it produces would be, so the
+//! builder applies the same rules as [`RfqBuilder::try_build`].
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::entities::rfq_template::RfqTemplateBuilder;
+//! use otc_rfq::domain::value_objects::enums::AssetClass;
+//! use otc_rfq::domain::value_objects::{CounterpartyId, Instrument, OrderSide, Quantity, Symbol};
+//!
+//! let instrument =
+//!     Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build();
+//! let template = RfqTemplateBuilder::new(
+//!     CounterpartyId::new("client-1"),
+//!     "Daily BTC buy",
+//!     instrument,
+//!     OrderSide::Buy,
+//!     Quantity::new(5.0).unwrap(),
+//!     300,
+//! )
+//! .try_build()
+//! .unwrap();
+//!
+//! let rfq = template
+//!     .rfq_builder(Some(Quantity::new(2.0).unwrap()), None)
+//!     .try_build()
+//!     .unwrap();
+//! assert_eq!(rfq.client_id(), template.owner());
+//! assert_eq!(rfq.quantity(), Quantity::new(2.0).unwrap());
+//! ```
+
+use crate::domain::entities::rfq::{RfqBuilder, RfqSubject};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    CounterpartyId, OrderSide, Quantity, RfqTemplateId, SizeNegotiationMode, VenueId,
+};
+use serde::{Deserialize, Serialize};
+
+/// Saved RFQ parameters owned by a counterparty.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RfqTemplate {
+    id: RfqTemplateId,
+    owner: CounterpartyId,
+    name: String,
+    subject: RfqSubject,
+    side: OrderSide,
+    quantity: Quantity,
+    size_mode: SizeNegotiationMode,
+    venue_allowlist: Option<Vec<VenueId>>,
+    venue_blocklist: Vec<VenueId>,
+    default_ttl_secs: u32,
+    created_at: Timestamp,
+    updated_at: Timestamp,
+}
+
+impl RfqTemplate {
+    /// Reconstructs a template from stored parts without validation.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn from_parts(
+        id: RfqTemplateId,
+        owner: CounterpartyId,
+        name: String,
+        subject: RfqSubject,
+        side: OrderSide,
+        quantity: Quantity,
+        size_mode: SizeNegotiationMode,
+        venue_allowlist: Option<Vec<VenueId>>,
+        venue_blocklist: Vec<VenueId>,
+        default_ttl_secs: u32,
+        created_at: Timestamp,
+        updated_at: Timestamp,
+    ) -> Self {
+        Self {
+            id,
+            owner,
+            name,
+            subject,
+            side,
+            quantity,
+            size_mode,
+            venue_allowlist,
+            venue_blocklist,
+            default_ttl_secs,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Returns the template ID.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> RfqTemplateId {
+        self.id
+    }
+
+    /// Returns the counterparty that owns the template.
+    #[inline]
+    #[must_use]
+    pub fn owner(&self) -> &CounterpartyId {
+        &self.owner
+    }
+
+    /// Returns true if `counterparty` owns the template.
+    #[inline]
+    #[must_use]
+    pub fn is_owned_by(&self, counterparty: &CounterpartyId) -> bool {
+        &self.owner == counterparty
+    }
+
+    /// Returns the template name.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the instrument or strategy to quote.
+    #[inline]
+    #[must_use]
+    pub fn subject(&self) -> &RfqSubject {
+        &self.subject
+    }
+
+    /// Returns the order side.
+    #[inline]
+    #[must_use]
+    pub fn side(&self) -> OrderSide {
+        self.side
+    }
+
+    /// Returns the default quantity.
+    #[inline]
+    #[must_use]
+    pub fn quantity(&self) -> Quantity {
+        self.quantity
+    }
+
+    /// Returns how the quantity may be filled.
+    #[inline]
+    #[must_use]
+    pub fn size_mode(&self) -> &SizeNegotiationMode {
+        &self.size_mode
+    }
+
+    /// Returns the venues RFQs from this template may be sent to, if restricted.
+    #[inline]
+    #[must_use]
+    pub fn venue_allowlist(&self) -> Option<&[VenueId]> {
+        self.venue_allowlist.as_deref()
+    }
+
+    /// Returns the venues RFQs from this template must not be sent to.
+    #[inline]
+    #[must_use]
+    pub fn venue_blocklist(&self) -> &[VenueId] {
+        &self.venue_blocklist
+    }
+
+    /// Returns the default time-to-live of instantiated RFQs, in seconds.
+    #[inline]
+    #[must_use]
+    pub fn default_ttl_secs(&self) -> u32 {
+        self.default_ttl_secs
+    }
+
+    /// Returns when the template was created.
+    #[inline]
+    #[must_use]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    /// Returns when the template was last updated.
+    #[inline]
+    #[must_use]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    /// Replaces the template's parameters with those of `replacement`.
+    ///
+    /// The ID, owner, and creation time are kept.
+    pub fn update_from(&mut self, replacement: RfqTemplate) {
+        self.name = replacement.name;
+        self.subject = replacement.subject;
+        self.side = replacement.side;
+        self.quantity = replacement.quantity;
+        self.size_mode = replacement.size_mode;
+        self.venue_allowlist = replacement.venue_allowlist;
+        self.venue_blocklist = replacement.venue_blocklist;
+        self.default_ttl_secs = replacement.default_ttl_secs;
+        self.updated_at = Timestamp::now();
+    }
+
+    /// Returns a builder for an RFQ from this template.
+    ///
+    /// `quantity` and `expires_at` override the template's quantity and
+    /// default TTL. The RFQ is requested by the template's owner. Use
+    /// [`RfqBuilder::try_build`] so the RFQ is validated like any other.
+    #[must_use]
+    pub fn rfq_builder(
+        &self,
+        quantity: Option<Quantity>,
+        expires_at: Option<Timestamp>,
+    ) -> RfqBuilder {
+        let expires_at = expires_at
+            .unwrap_or_else(|| Timestamp::now().add_secs(i64::from(self.default_ttl_secs)));
+        let mut builder = RfqBuilder::new(
+            self.owner.clone(),
+            self.subject.clone(),
+            self.side,
+            quantity.unwrap_or(self.quantity),
+            expires_at,
+        )
+        .size_mode(self.size_mode.clone())
+        .venue_blocklist(self.venue_blocklist.clone());
+        if let Some(allowlist) = &self.venue_allowlist {
+            builder = builder.venue_allowlist(allowlist.clone());
+        }
+        builder
+    }
+}
+
+/// Builder for [`RfqTemplate`].
+#[derive(Debug, Clone)]
+pub struct RfqTemplateBuilder {
+    owner: CounterpartyId,
+    name: String,
+    subject: RfqSubject,
+    side: OrderSide,
+    quantity: Quantity,
+    size_mode: SizeNegotiationMode,
+    venue_allowlist: Option<Vec<VenueId>>,
+    venue_blocklist: Vec<VenueId>,
+    default_ttl_secs: u32,
+}
+
+impl RfqTemplateBuilder {
+    /// Creates a new builder with required fields.
+    #[must_use]
+    pub fn new(
+        owner: CounterpartyId,
+        name: impl Into<String>,
+        subject: impl Into<RfqSubject>,
+        side: OrderSide,
+        quantity: Quantity,
+        default_ttl_secs: u32,
+    ) -> Self {
+        Self {
+            owner,
+            name: name.into(),
+            subject: subject.into(),
+            side,
+            quantity,
+            size_mode: SizeNegotiationMode::default(),
+            venue_allowlist: None,
+            venue_blocklist: Vec::new(),
+            default_ttl_secs,
+        }
+    }
+
+    /// Sets how the quantity may be filled.
+    #[must_use]
+    pub fn size_mode(mut self, size_mode: SizeNegotiationMode) -> Self {
+        self.size_mode = size_mode;
+        self
+    }
+
+    /// Restricts RFQs from the template to the given venues.
+    #[must_use]
+    pub fn venue_allowlist(mut self, venues: Vec<VenueId>) -> Self {
+        self.venue_allowlist = Some(venues);
+        self
+    }
+
+    /// Keeps RFQs from the template away from the given venues.
+    #[must_use]
+    pub fn venue_blocklist(mut self, venues: Vec<VenueId>) -> Self {
+        self.venue_blocklist = venues;
+        self
+    }
+
+    /// Builds the template with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if the name is blank or the
+    /// default TTL is zero, or any error [`RfqBuilder::try_build`] would
+    /// return for an RFQ created from the template.
+    pub fn try_build(self) -> DomainResult<RfqTemplate> {
+        if self.name.trim().is_empty() {
+            return Err(DomainError::ValidationError(
+                "template name cannot be empty".to_string(),
+            ));
+        }
+        if self.default_ttl_secs == 0 {
+            return Err(DomainError::ValidationError(
+                "default_ttl_secs must be greater than 0".to_string(),
+            ));
+        }
+
+        let now = Timestamp::now();
+        let template = RfqTemplate {
+            id: RfqTemplateId::new_v4(),
+            owner: self.owner,
+            name: self.name,
+            subject: self.subject,
+            side: self.side,
+            quantity: self.quantity,
+            size_mode: self.size_mode,
+            venue_allowlist: self.venue_allowlist,
+            venue_blocklist: self.venue_blocklist,
+            default_ttl_secs: self.default_ttl_secs,
+            created_at: now,
+            updated_at: now,
+        };
+        template.rfq_builder(None, None).try_build()?;
+        Ok(template)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::enums::AssetClass;
+    use crate::domain::value_objects::{Instrument, Symbol};
+
+    fn builder() -> RfqTemplateBuilder {
+        let instrument =
+            Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build();
+        RfqTemplateBuilder::new(
+            CounterpartyId::new("client-1"),
+            "Daily BTC buy",
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(5.0).unwrap(),
+            300,
+        )
+    }
+
+    #[test]
+    fn rfq_builder_copies_template_parameters() {
+        let template = builder()
+            .size_mode(SizeNegotiationMode::BestEffort)
+            .venue_allowlist(vec![VenueId::new("mm-1")])
+            .venue_blocklist(vec![VenueId::new("mm-2")])
+            .try_build()
+            .unwrap();
+
+        let rfq = template.rfq_builder(None, None).try_build().unwrap();
+
+        assert_eq!(rfq.client_id(), template.owner());
+        assert_eq!(rfq.subject(), template.subject());
+        assert_eq!(rfq.side(), OrderSide::Buy);
+        assert_eq!(rfq.quantity(), template.quantity());
+        assert_eq!(rfq.size_mode(), &SizeNegotiationMode::BestEffort);
+        assert_eq!(rfq.venue_allowlist(), Some(&[VenueId::new("mm-1")][..]));
+        assert_eq!(rfq.venue_blocklist(), &[VenueId::new("mm-2")]);
+        assert!(rfq.expires_at() > Timestamp::now().add_secs(290));
+    }
+
+    #[test]
+    fn rfq_builder_applies_overrides() {
+        let template = builder().try_build().unwrap();
+        let expires_at = Timestamp::now().add_secs(60);
+
+        let rfq = template
+            .rfq_builder(Some(Quantity::new(2.0).unwrap()), Some(expires_at))
+            .try_build()
+            .unwrap();
+
+        assert_eq!(rfq.quantity(), Quantity::new(2.0).unwrap());
+        assert_eq!(rfq.expires_at(), expires_at);
+    }
+
+    #[test]
+    fn quantity_override_is_checked_against_size_mode() {
+        let template = builder()
+            .size_mode(SizeNegotiationMode::MinQuantity(
+                Quantity::new(3.0).unwrap(),
+            ))
+            .try_build()
+            .unwrap();
+
+        let result = template
+            .rfq_builder(Some(Quantity::new(2.0).unwrap()), None)
+            .try_build();
+
+        assert!(matches!(result, Err(DomainError::InvalidMinQuantity(_))));
+    }
+
+    #[test]
+    fn rejects_blank_name_and_zero_ttl() {
+        let instrument =
+            Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build();
+        let blank = RfqTemplateBuilder::new(
+            CounterpartyId::new("client-1"),
+            "  ",
+            instrument.clone(),
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            300,
+        );
+        let no_ttl = RfqTemplateBuilder::new(
+            CounterpartyId::new("client-1"),
+            "name",
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            0,
+        );
+
+        assert!(matches!(
+            blank.try_build(),
+            Err(DomainError::ValidationError(_))
+        ));
+        assert!(matches!(
+            no_ttl.try_build(),
+            Err(DomainError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_parameters_an_rfq_would_reject() {
+        let result = builder().venue_allowlist(Vec::new()).try_build();
+
+        assert!(matches!(result, Err(DomainError::NoEligibleVenues(_))));
+    }
+
+    #[test]
+    fn update_from_keeps_identity() {
+        let mut template = builder().try_build().unwrap();
+        let id = template.id();
+        let replacement = builder()
+            .venue_blocklist(vec![VenueId::new("mm-9")])
+            .try_build()
+            .unwrap();
+
+        template.update_from(replacement);
+
+        assert_eq!(template.id(), id);
+        assert_eq!(template.owner(), &CounterpartyId::new("client-1"));
+        assert_eq!(template.venue_blocklist(), &[VenueId::new("mm-9")]);
+    }
+}
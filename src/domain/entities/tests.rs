@@ -20,11 +20,11 @@ use crate::domain::entities::counterparty::{Counterparty, CounterpartyType, KycS
 use crate::domain::entities::quote::{Quote, QuoteBuilder};
 use crate::domain::entities::rfq::{ComplianceResult, Rfq, RfqBuilder};
 use crate::domain::entities::trade::{SettlementState, Trade};
-use crate::domain::entities::venue::{Venue, VenueHealth};
+use crate::domain::entities::venue::{Venue, VenueHealth, VenueMode};
 use crate::domain::value_objects::timestamp::Timestamp;
 use crate::domain::value_objects::{
-    CounterpartyId, Instrument, OrderSide, Price, Quantity, QuoteId, RfqId, RfqState, VenueId,
-    VenueType,
+    CounterpartyId, FailureReason, Instrument, OrderSide, Price, Quantity, QuoteId, RfqId,
+    RfqState, VenueId, VenueType,
 };
 
 // ============================================================================
@@ -136,7 +136,7 @@ proptest! {
             }
             RfqState::Failed => {
                 rfq.start_quote_collection().unwrap();
-                rfq.mark_failed("test").unwrap();
+                rfq.mark_failed(FailureReason::other("test")).unwrap();
             }
             RfqState::Cancelled => {
                 rfq.cancel().unwrap();
@@ -154,7 +154,7 @@ proptest! {
         prop_assert!(rfq.start_quote_collection().is_err());
         prop_assert!(rfq.cancel().is_err());
         prop_assert!(rfq.expire().is_err());
-        prop_assert!(rfq.mark_failed("test").is_err());
+        prop_assert!(rfq.mark_failed(FailureReason::other("test")).is_err());
     }
 }
 
@@ -261,7 +261,7 @@ proptest! {
                 // Failure path
                 trade.fail_settlement("network error").unwrap();
                 prop_assert!(trade.is_failed());
-                prop_assert!(trade.is_terminal());
+                prop_assert!(!trade.is_terminal());
                 prop_assert_eq!(trade.failure_reason(), Some("network error"));
             }
             _ => unreachable!(),
@@ -411,6 +411,10 @@ mod settlement_state_tests {
 
         // InProgress -> Failed
         assert!(SettlementState::InProgress.can_transition_to(SettlementState::Failed));
+
+        // Failed -> InProgress (retry) or DeadLettered
+        assert!(SettlementState::Failed.can_transition_to(SettlementState::InProgress));
+        assert!(SettlementState::Failed.can_transition_to(SettlementState::DeadLettered));
     }
 
     #[test]
@@ -425,8 +429,10 @@ mod settlement_state_tests {
         assert!(!SettlementState::Settled.can_transition_to(SettlementState::Failed));
 
         assert!(!SettlementState::Failed.can_transition_to(SettlementState::Pending));
-        assert!(!SettlementState::Failed.can_transition_to(SettlementState::InProgress));
         assert!(!SettlementState::Failed.can_transition_to(SettlementState::Settled));
+
+        assert!(!SettlementState::DeadLettered.can_transition_to(SettlementState::InProgress));
+        assert!(!SettlementState::DeadLettered.can_transition_to(SettlementState::Failed));
     }
 
     #[test]
@@ -434,7 +440,9 @@ mod settlement_state_tests {
         assert!(!SettlementState::Pending.is_terminal());
         assert!(!SettlementState::InProgress.is_terminal());
         assert!(SettlementState::Settled.is_terminal());
-        assert!(SettlementState::Failed.is_terminal());
+        assert!(!SettlementState::Failed.is_terminal());
+        assert!(SettlementState::DeadLettered.is_terminal());
+        assert!(SettlementState::SettledViaNetting.is_terminal());
     }
 }
 
@@ -451,7 +459,7 @@ mod venue_tests {
         let mut venue = Venue::new(VenueId::new("test"), "Test", VenueType::ExternalMM);
 
         // Enabled + Healthy = Available
-        venue.set_enabled(true);
+        venue.set_mode(VenueMode::Live);
         venue.set_health(VenueHealth::Healthy);
         assert!(venue.is_available());
 
@@ -468,7 +476,7 @@ mod venue_tests {
         assert!(!venue.is_available());
 
         // Disabled + Healthy = Unavailable
-        venue.set_enabled(false);
+        venue.set_mode(VenueMode::Disabled);
         venue.set_health(VenueHealth::Healthy);
         assert!(!venue.is_available());
     }
@@ -9,8 +9,10 @@
 //!
 //! ```text
 //! Pending → InProgress → Settled
-//!              ↓
-//!           Failed
+//!    ↓         ↓   ↑ retry
+//!    ↓        Failed → DeadLettered
+//!    ↓
+//! SettledViaNetting
 //! ```
 //!
 //! # Examples
@@ -32,11 +34,18 @@
 //! assert!(trade.is_pending());
 //! ```
 
+use crate::domain::entities::allocation::{Allocation, AllocationStatus, TradeAllocation};
 use crate::domain::errors::{DomainError, DomainResult};
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{Price, Quantity, QuoteId, RfqId, TradeId, VenueId};
+use crate::domain::value_objects::{
+    CheckedArithmetic, CollateralDecision, NegotiationId, NettingBatchId, OrderSide, Price,
+    PriceBoundsCheck, Quantity, QuoteId, RfqId, SettlementPlan, SettlementWindow,
+    SignedDecimalAmount, TradeId, VenueId,
+};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use utoipa::ToSchema;
 
 /// Settlement lifecycle state.
 ///
@@ -46,6 +55,8 @@ use std::fmt;
 ///
 /// - `Pending` → `InProgress` → `Settled`
 /// - `InProgress` → `Failed`
+/// - `Failed` → `InProgress` (retry) or `DeadLettered` (retries exhausted)
+/// - `Pending` → `SettledViaNetting` (settled as part of a netting batch)
 ///
 /// # Examples
 ///
@@ -56,7 +67,7 @@ use std::fmt;
 /// assert!(state.can_transition_to(SettlementState::InProgress));
 /// assert!(!state.can_transition_to(SettlementState::Settled));
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[repr(u8)]
 pub enum SettlementState {
@@ -70,8 +81,14 @@ pub enum SettlementState {
     /// Settlement completed successfully (terminal).
     Settled = 2,
 
-    /// Settlement failed (terminal).
+    /// Settlement failed; eligible for retry.
     Failed = 3,
+
+    /// Settlement retries exhausted; requires manual intervention (terminal).
+    DeadLettered = 4,
+
+    /// Settled by a confirmed netting batch rather than individually (terminal).
+    SettledViaNetting = 5,
 }
 
 impl SettlementState {
@@ -81,7 +98,10 @@ impl SettlementState {
     #[inline]
     #[must_use]
     pub const fn is_terminal(&self) -> bool {
-        matches!(self, Self::Settled | Self::Failed)
+        matches!(
+            self,
+            Self::Settled | Self::DeadLettered | Self::SettledViaNetting
+        )
     }
 
     /// Returns true if this state can transition to the target state.
@@ -96,6 +116,21 @@ impl SettlementState {
             (Self::Pending, Self::InProgress)
                 | (Self::InProgress, Self::Settled)
                 | (Self::InProgress, Self::Failed)
+                | (Self::Failed, Self::InProgress)
+                | (Self::Failed, Self::DeadLettered)
+                | (Self::Pending, Self::SettledViaNetting)
+        )
+    }
+
+    /// Returns true if an administrator may force this state to `target`.
+    ///
+    /// Forcing is a rescue for settlements stuck in `InProgress`, recording
+    /// an outcome established out of band.
+    #[must_use]
+    pub const fn can_force_to(&self, target: Self) -> bool {
+        matches!(
+            (self, target),
+            (Self::InProgress, Self::Settled) | (Self::InProgress, Self::Failed)
         )
     }
 
@@ -114,6 +149,8 @@ impl fmt::Display for SettlementState {
             Self::InProgress => "IN_PROGRESS",
             Self::Settled => "SETTLED",
             Self::Failed => "FAILED",
+            Self::DeadLettered => "DEAD_LETTERED",
+            Self::SettledViaNetting => "SETTLED_VIA_NETTING",
         };
         write!(f, "{}", s)
     }
@@ -128,6 +165,8 @@ impl TryFrom<u8> for SettlementState {
             1 => Ok(Self::InProgress),
             2 => Ok(Self::Settled),
             3 => Ok(Self::Failed),
+            4 => Ok(Self::DeadLettered),
+            5 => Ok(Self::SettledViaNetting),
             _ => Err(InvalidSettlementStateError(value)),
         }
     }
@@ -145,6 +184,89 @@ impl fmt::Display for InvalidSettlementStateError {
 
 impl std::error::Error for InvalidSettlementStateError {}
 
+/// Basis points per unit (1 = 10,000 bps).
+const BPS_MULTIPLIER: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// Category of a cost incurred when executing or settling a trade.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FeeKind {
+    /// Fee charged by the execution venue.
+    Venue,
+    /// Network gas paid to settle on-chain.
+    Gas,
+    /// Fee charged by this platform.
+    Platform,
+}
+
+impl fmt::Display for FeeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Venue => "VENUE",
+            Self::Gas => "GAS",
+            Self::Platform => "PLATFORM",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A single cost line attached to a trade.
+///
+/// Amounts are denominated in `currency`, which need not match the
+/// instrument's quote asset (gas is paid in the chain's native token).
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::entities::trade::{FeeComponent, FeeKind};
+/// use rust_decimal::Decimal;
+///
+/// let fee = FeeComponent::new(FeeKind::Venue, Decimal::new(25, 1), "USDC");
+/// assert_eq!(fee.kind(), FeeKind::Venue);
+/// assert_eq!(fee.currency(), "USDC");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeComponent {
+    kind: FeeKind,
+    amount: Decimal,
+    currency: String,
+}
+
+impl FeeComponent {
+    /// Creates a new fee component.
+    ///
+    /// A negative `amount` represents a rebate.
+    #[must_use]
+    pub fn new(kind: FeeKind, amount: Decimal, currency: impl Into<String>) -> Self {
+        Self {
+            kind,
+            amount,
+            currency: currency.into(),
+        }
+    }
+
+    /// Returns the fee category.
+    #[inline]
+    #[must_use]
+    pub fn kind(&self) -> FeeKind {
+        self.kind
+    }
+
+    /// Returns the fee amount.
+    #[inline]
+    #[must_use]
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// Returns the currency the fee is denominated in.
+    #[inline]
+    #[must_use]
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+}
+
 /// An executed trade.
 ///
 /// Represents a trade resulting from an RFQ execution, including
@@ -153,6 +275,7 @@ impl std::error::Error for InvalidSettlementStateError {}
 /// # Invariants
 ///
 /// - Settlement lifecycle: Pending → InProgress → Settled/Failed
+/// - Failed settlements are retried until Settled or DeadLettered
 /// - Cannot modify after Settled state
 /// - Must reference valid RFQ and Quote
 ///
@@ -214,6 +337,45 @@ pub struct Trade {
     maker_fee: Option<rust_decimal::Decimal>,
     /// Net fee (taker + maker).
     net_fee: Option<rust_decimal::Decimal>,
+    /// Itemized execution and settlement costs.
+    #[serde(default)]
+    fees: Vec<FeeComponent>,
+    /// Reference price observed when the trade was executed.
+    #[serde(default)]
+    reference_price_at_execution: Option<Price>,
+    /// Price bounds check made before execution.
+    #[serde(default)]
+    price_bounds_check: Option<PriceBoundsCheck>,
+    /// Collateral check made before execution.
+    #[serde(default)]
+    collateral_decision: Option<CollateralDecision>,
+    /// Settlement chain and window agreed in negotiation.
+    #[serde(default)]
+    settlement_plan: Option<SettlementPlan>,
+    /// Window the trade settles in, if scheduled.
+    #[serde(default)]
+    settlement_window: Option<SettlementWindow>,
+    /// When the trade is released for settlement; `None` if unscheduled.
+    #[serde(default)]
+    settlement_due_at: Option<Timestamp>,
+    /// Number of settlement retries made after a failure.
+    #[serde(default)]
+    settlement_attempts: u32,
+    /// When the next settlement retry is due, if scheduled.
+    #[serde(default)]
+    next_settlement_retry_at: Option<Timestamp>,
+    /// How a multi-MM fill was split across venues; empty for single-venue trades.
+    #[serde(default)]
+    allocations: Vec<TradeAllocation>,
+    /// The trade whose filled leg this trade reverses, for unwind trades.
+    #[serde(default)]
+    unwinds: Option<TradeId>,
+    /// The netting batch this trade is reserved for, if any.
+    #[serde(default)]
+    netting_batch_id: Option<NettingBatchId>,
+    /// The negotiation whose accepted terms this trade executes, if any.
+    #[serde(default)]
+    negotiation_id: Option<NegotiationId>,
 }
 
 impl Trade {
@@ -269,6 +431,19 @@ impl Trade {
             taker_fee: None,
             maker_fee: None,
             net_fee: None,
+            fees: Vec::new(),
+            reference_price_at_execution: None,
+            price_bounds_check: None,
+            collateral_decision: None,
+            settlement_plan: None,
+            settlement_window: None,
+            settlement_due_at: None,
+            settlement_attempts: 0,
+            next_settlement_retry_at: None,
+            allocations: Vec::new(),
+            unwinds: None,
+            netting_batch_id: None,
+            negotiation_id: None,
         }
     }
 
@@ -287,6 +462,23 @@ impl Trade {
         trade
     }
 
+    /// Creates a trade reversing a filled leg of `original`.
+    ///
+    /// The unwind trade has the leg's venue, quote, price and quantity and
+    /// links back to `original` through [`unwinds`](Self::unwinds).
+    #[must_use]
+    pub fn unwind_of(original: &Trade, leg: &Allocation) -> Self {
+        let mut trade = Self::new(
+            original.rfq_id,
+            leg.quote_id(),
+            leg.venue_id().clone(),
+            leg.price(),
+            leg.allocated_quantity(),
+        );
+        trade.unwinds = Some(original.id);
+        trade
+    }
+
     /// Creates a trade with a specific ID (for reconstruction from storage).
     ///
     /// # Safety
@@ -330,9 +522,34 @@ impl Trade {
             taker_fee,
             maker_fee,
             net_fee,
+            fees: Vec::new(),
+            reference_price_at_execution: None,
+            price_bounds_check: None,
+            collateral_decision: None,
+            settlement_plan: None,
+            settlement_window: None,
+            settlement_due_at: None,
+            settlement_attempts: 0,
+            next_settlement_retry_at: None,
+            allocations: Vec::new(),
+            unwinds: None,
+            netting_batch_id: None,
+            negotiation_id: None,
         }
     }
 
+    /// Restores settlement retry bookkeeping (for reconstruction from storage).
+    #[must_use]
+    pub fn with_settlement_retry(
+        mut self,
+        settlement_attempts: u32,
+        next_settlement_retry_at: Option<Timestamp>,
+    ) -> Self {
+        self.settlement_attempts = settlement_attempts;
+        self.next_settlement_retry_at = next_settlement_retry_at;
+        self
+    }
+
     fn transition_to(&mut self, target: SettlementState) -> DomainResult<()> {
         if !self.settlement_state.can_transition_to(target) {
             return Err(DomainError::InvalidState(format!(
@@ -478,6 +695,299 @@ impl Trade {
         self.taker_fee.is_some()
     }
 
+    /// Returns the itemized fee components.
+    #[inline]
+    #[must_use]
+    pub fn fees(&self) -> &[FeeComponent] {
+        &self.fees
+    }
+
+    /// Appends a fee component to this trade.
+    pub fn add_fee(&mut self, fee: FeeComponent) {
+        self.fees.push(fee);
+    }
+
+    /// Sums the fee components denominated in `currency`.
+    ///
+    /// Returns `None` if the sum overflows.
+    #[must_use]
+    pub fn total_fees(&self, currency: &str) -> Option<Decimal> {
+        self.fees
+            .iter()
+            .filter(|fee| fee.currency() == currency)
+            .try_fold(Decimal::ZERO, |total, fee| total.checked_add(fee.amount()))
+    }
+
+    /// Returns the allocations of a multi-MM fill, in fill order.
+    #[inline]
+    #[must_use]
+    pub fn allocations(&self) -> &[TradeAllocation] {
+        &self.allocations
+    }
+
+    /// Appends an allocation to this trade.
+    pub fn add_allocation(&mut self, allocation: TradeAllocation) {
+        self.allocations.push(allocation);
+    }
+
+    /// Marks every allocation as sent to its venue.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if the trade has no allocations
+    /// or any allocation is not pending.
+    pub fn start_allocations(&mut self) -> DomainResult<()> {
+        if self.allocations.is_empty() {
+            return Err(DomainError::InvalidState(
+                "trade has no allocations to execute".to_string(),
+            ));
+        }
+        if let Some(started) = self
+            .allocations
+            .iter()
+            .find(|recorded| *recorded.status() != AllocationStatus::Pending)
+        {
+            return Err(DomainError::InvalidState(format!(
+                "allocation for quote {} is already {}",
+                started.allocation().quote_id(),
+                started.status()
+            )));
+        }
+        for recorded in &mut self.allocations {
+            recorded.mark_executing()?;
+        }
+        self.updated_at = Timestamp::now();
+        self.version = self.version.saturating_add(1);
+        Ok(())
+    }
+
+    /// Marks the allocation at `index` as filled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if there is no allocation at
+    /// `index`, or `DomainError::InvalidState` if it is not executing.
+    pub fn fill_allocation(&mut self, index: usize) -> DomainResult<()> {
+        self.allocation_at(index)?.mark_filled()?;
+        self.updated_at = Timestamp::now();
+        self.version = self.version.saturating_add(1);
+        Ok(())
+    }
+
+    /// Marks the allocation at `index` as failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if there is no allocation at
+    /// `index`, or `DomainError::InvalidState` if it already finished.
+    pub fn fail_allocation(&mut self, index: usize, reason: impl Into<String>) -> DomainResult<()> {
+        self.allocation_at(index)?.mark_failed(reason)?;
+        self.updated_at = Timestamp::now();
+        self.version = self.version.saturating_add(1);
+        Ok(())
+    }
+
+    fn allocation_at(&mut self, index: usize) -> DomainResult<&mut TradeAllocation> {
+        let count = self.allocations.len();
+        self.allocations.get_mut(index).ok_or_else(|| {
+            DomainError::ValidationError(format!(
+                "allocation index {index} out of range for {count} allocation(s)"
+            ))
+        })
+    }
+
+    /// Returns the total quantity of filled allocations.
+    ///
+    /// Returns `None` if the sum overflows.
+    #[must_use]
+    pub fn filled_allocation_quantity(&self) -> Option<Quantity> {
+        self.allocation_quantity_where(|status| *status == AllocationStatus::Filled)
+    }
+
+    /// Returns the total quantity of failed allocations.
+    ///
+    /// Returns `None` if the sum overflows.
+    #[must_use]
+    pub fn failed_allocation_quantity(&self) -> Option<Quantity> {
+        self.allocation_quantity_where(|status| matches!(status, AllocationStatus::Failed { .. }))
+    }
+
+    fn allocation_quantity_where(
+        &self,
+        include: impl Fn(&AllocationStatus) -> bool,
+    ) -> Option<Quantity> {
+        self.allocations
+            .iter()
+            .filter(|recorded| include(recorded.status()))
+            .try_fold(Quantity::zero(), |total, recorded| {
+                total
+                    .safe_add(recorded.allocation().allocated_quantity())
+                    .ok()
+            })
+    }
+
+    /// Returns the trade this trade unwinds, if it is an unwind trade.
+    #[inline]
+    #[must_use]
+    pub fn unwinds(&self) -> Option<TradeId> {
+        self.unwinds
+    }
+
+    /// Marks this trade as unwinding `original` (for reconstruction from storage).
+    pub fn set_unwinds(&mut self, original: TradeId) {
+        self.unwinds = Some(original);
+    }
+
+    /// Returns the netting batch this trade is reserved for, if any.
+    #[inline]
+    #[must_use]
+    pub fn netting_batch_id(&self) -> Option<NettingBatchId> {
+        self.netting_batch_id
+    }
+
+    /// Sets the netting batch (for reconstruction from storage).
+    pub fn set_netting_batch_id(&mut self, batch_id: NettingBatchId) {
+        self.netting_batch_id = Some(batch_id);
+    }
+
+    /// Returns the negotiation this trade concluded, if it executes
+    /// negotiated terms.
+    #[inline]
+    #[must_use]
+    pub fn negotiation_id(&self) -> Option<NegotiationId> {
+        self.negotiation_id
+    }
+
+    /// Links this trade to the negotiation whose terms it executes.
+    pub fn set_negotiation_id(&mut self, negotiation_id: NegotiationId) {
+        self.negotiation_id = Some(negotiation_id);
+    }
+
+    /// Calculates the volume-weighted average price across allocations.
+    ///
+    /// Returns `None` if there are no allocations, their total quantity is
+    /// zero, or the arithmetic overflows.
+    #[must_use]
+    pub fn allocation_vwap(&self) -> Option<Price> {
+        let (notional, quantity) = self.allocations.iter().try_fold(
+            (Decimal::ZERO, Decimal::ZERO),
+            |(notional, quantity), recorded| {
+                let allocation = recorded.allocation();
+                let qty = allocation.allocated_quantity().get();
+                Some((
+                    notional.checked_add(allocation.price().get().checked_mul(qty)?)?,
+                    quantity.checked_add(qty)?,
+                ))
+            },
+        )?;
+        if quantity.is_zero() {
+            return None;
+        }
+        Price::from_decimal(notional.checked_div(quantity)?).ok()
+    }
+
+    /// Returns the reference price captured at execution, if any.
+    #[inline]
+    #[must_use]
+    pub fn reference_price_at_execution(&self) -> Option<Price> {
+        self.reference_price_at_execution
+    }
+
+    /// Records the reference price observed at execution.
+    pub fn set_reference_price_at_execution(&mut self, price: Price) {
+        self.reference_price_at_execution = Some(price);
+    }
+
+    /// Returns the price bounds check made before execution, if any.
+    #[inline]
+    #[must_use]
+    pub fn price_bounds_check(&self) -> Option<&PriceBoundsCheck> {
+        self.price_bounds_check.as_ref()
+    }
+
+    /// Records the price bounds check made before execution.
+    pub fn set_price_bounds_check(&mut self, check: PriceBoundsCheck) {
+        self.price_bounds_check = Some(check);
+    }
+
+    /// Returns the collateral decision made before execution, if any.
+    #[inline]
+    #[must_use]
+    pub fn collateral_decision(&self) -> Option<&CollateralDecision> {
+        self.collateral_decision.as_ref()
+    }
+
+    /// Records the collateral decision made before execution.
+    pub fn set_collateral_decision(&mut self, decision: CollateralDecision) {
+        self.collateral_decision = Some(decision);
+    }
+
+    /// Returns the settlement terms agreed in negotiation, if any.
+    #[inline]
+    #[must_use]
+    pub fn settlement_plan(&self) -> Option<&SettlementPlan> {
+        self.settlement_plan.as_ref()
+    }
+
+    /// Records the settlement terms agreed in negotiation.
+    pub fn set_settlement_plan(&mut self, plan: SettlementPlan) {
+        self.settlement_plan = Some(plan);
+    }
+
+    /// Returns the window the trade settles in, if scheduled.
+    #[inline]
+    #[must_use]
+    pub fn settlement_window(&self) -> Option<&SettlementWindow> {
+        self.settlement_window.as_ref()
+    }
+
+    /// Returns when the trade is released for settlement, if scheduled.
+    #[inline]
+    #[must_use]
+    pub fn settlement_due_at(&self) -> Option<Timestamp> {
+        self.settlement_due_at
+    }
+
+    /// Records the settlement window and when the trade is released.
+    pub fn schedule_settlement(&mut self, window: SettlementWindow, due_at: Timestamp) {
+        self.settlement_window = Some(window);
+        self.settlement_due_at = Some(due_at);
+    }
+
+    /// Returns true if the trade may be settled at `now`.
+    ///
+    /// Unscheduled trades are always due.
+    #[must_use]
+    pub fn is_settlement_due(&self, now: Timestamp) -> bool {
+        self.settlement_due_at.is_none_or(|due| due <= now)
+    }
+
+    /// Calculates execution slippage against the reference price, in bps.
+    ///
+    /// Positive values are adverse to the requester: paying above the
+    /// reference on a buy, or receiving below it on a sell.
+    ///
+    /// Returns `None` if no reference price was captured, the reference
+    /// is zero, or the arithmetic overflows.
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - The requester's side of the originating RFQ
+    #[must_use]
+    pub fn slippage_bps(&self, side: OrderSide) -> Option<SignedDecimalAmount> {
+        let reference = SignedDecimalAmount::from(self.reference_price_at_execution?);
+        let execution = SignedDecimalAmount::from(self.price);
+
+        let diff = match side {
+            OrderSide::Buy => execution.safe_sub(reference).ok()?,
+            OrderSide::Sell => reference.safe_sub(execution).ok()?,
+        };
+
+        diff.safe_div(reference)
+            .and_then(|ratio| ratio.safe_mul(SignedDecimalAmount::new(BPS_MULTIPLIER)))
+            .ok()
+    }
+
     // ========== State Helpers ==========
 
     /// Returns true if settlement is pending.
@@ -508,6 +1018,34 @@ impl Trade {
         self.settlement_state == SettlementState::Failed
     }
 
+    /// Returns true if settlement retries were exhausted.
+    #[inline]
+    #[must_use]
+    pub fn is_dead_lettered(&self) -> bool {
+        self.settlement_state == SettlementState::DeadLettered
+    }
+
+    /// Returns true if the trade was settled by a netting batch.
+    #[inline]
+    #[must_use]
+    pub fn is_settled_via_netting(&self) -> bool {
+        self.settlement_state == SettlementState::SettledViaNetting
+    }
+
+    /// Returns the number of settlement retries made after a failure.
+    #[inline]
+    #[must_use]
+    pub fn settlement_attempts(&self) -> u32 {
+        self.settlement_attempts
+    }
+
+    /// Returns when the next settlement retry is due, if scheduled.
+    #[inline]
+    #[must_use]
+    pub fn next_settlement_retry_at(&self) -> Option<Timestamp> {
+        self.next_settlement_retry_at
+    }
+
     /// Returns true if this trade is in a terminal state.
     #[inline]
     #[must_use]
@@ -531,11 +1069,115 @@ impl Trade {
     ///
     /// # Errors
     ///
-    /// Returns `DomainError::InvalidState` if not in Pending state.
+    /// Returns `DomainError::InvalidState` if not in Pending state or if
+    /// the trade is reserved for a netting batch.
     pub fn start_settlement(&mut self) -> DomainResult<()> {
+        if !self.is_pending() {
+            return Err(DomainError::InvalidState(format!(
+                "cannot start settlement from {}",
+                self.settlement_state
+            )));
+        }
+        if let Some(batch_id) = self.netting_batch_id {
+            return Err(DomainError::InvalidState(format!(
+                "trade is reserved for netting batch {}",
+                batch_id
+            )));
+        }
         self.transition_to(SettlementState::InProgress)
     }
 
+    /// Reserves this trade for a netting batch.
+    ///
+    /// A reserved trade stays Pending but cannot be settled individually
+    /// until it is released.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if not in Pending state or
+    /// already reserved for a batch.
+    pub fn assign_to_netting_batch(&mut self, batch_id: NettingBatchId) -> DomainResult<()> {
+        if !self.is_pending() {
+            return Err(DomainError::InvalidState(format!(
+                "cannot net a trade in {}",
+                self.settlement_state
+            )));
+        }
+        if let Some(existing) = self.netting_batch_id {
+            return Err(DomainError::InvalidState(format!(
+                "trade is already reserved for netting batch {}",
+                existing
+            )));
+        }
+        self.netting_batch_id = Some(batch_id);
+        self.updated_at = Timestamp::now();
+        self.version = self.version.saturating_add(1);
+        Ok(())
+    }
+
+    /// Releases this trade from its netting batch back to individual settlement.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if the trade is not reserved for
+    /// a batch or is no longer Pending.
+    pub fn release_from_netting_batch(&mut self) -> DomainResult<()> {
+        if self.netting_batch_id.is_none() || !self.is_pending() {
+            return Err(DomainError::InvalidState(format!(
+                "cannot release trade from netting in {}",
+                self.settlement_state
+            )));
+        }
+        self.netting_batch_id = None;
+        self.updated_at = Timestamp::now();
+        self.version = self.version.saturating_add(1);
+        Ok(())
+    }
+
+    /// Confirms settlement through the trade's netting batch.
+    ///
+    /// Transitions: Pending → SettledViaNetting
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_ref` - The batch transaction reference, if the batch needed one
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if the trade is not reserved for
+    /// a batch or not in Pending state.
+    pub fn confirm_settlement_via_netting(&mut self, tx_ref: Option<String>) -> DomainResult<()> {
+        if self.netting_batch_id.is_none() {
+            return Err(DomainError::InvalidState(
+                "trade is not reserved for a netting batch".to_string(),
+            ));
+        }
+        self.transition_to(SettlementState::SettledViaNetting)?;
+        self.settlement_tx_ref = tx_ref;
+        Ok(())
+    }
+
+    /// Records the hash of a submitted settlement transaction.
+    ///
+    /// The hash survives a subsequent failure so that a retry can check
+    /// whether the transaction confirmed after all.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if not in InProgress state.
+    pub fn record_settlement_tx(&mut self, tx_ref: impl Into<String>) -> DomainResult<()> {
+        if !self.is_in_progress() {
+            return Err(DomainError::InvalidState(format!(
+                "cannot record settlement tx in {}",
+                self.settlement_state
+            )));
+        }
+        self.settlement_tx_ref = Some(tx_ref.into());
+        self.updated_at = Timestamp::now();
+        self.version = self.version.saturating_add(1);
+        Ok(())
+    }
+
     /// Confirms successful settlement.
     ///
     /// Transitions: InProgress → Settled
@@ -567,6 +1209,99 @@ impl Trade {
         self.failure_reason = Some(reason.into());
         self.transition_to(SettlementState::Failed)
     }
+
+    /// Schedules the next settlement retry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if not in Failed state.
+    pub fn schedule_settlement_retry(&mut self, at: Timestamp) -> DomainResult<()> {
+        if !self.is_failed() {
+            return Err(DomainError::InvalidState(format!(
+                "cannot schedule settlement retry in {}",
+                self.settlement_state
+            )));
+        }
+        self.next_settlement_retry_at = Some(at);
+        self.updated_at = Timestamp::now();
+        self.version = self.version.saturating_add(1);
+        Ok(())
+    }
+
+    /// Starts a settlement retry and counts the attempt.
+    ///
+    /// Transitions: Failed → InProgress
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if not in Failed state.
+    pub fn retry_settlement(&mut self) -> DomainResult<()> {
+        self.transition_to(SettlementState::InProgress)?;
+        self.settlement_attempts = self.settlement_attempts.saturating_add(1);
+        self.next_settlement_retry_at = None;
+        Ok(())
+    }
+
+    /// Settles a failed trade whose transaction turned out to have confirmed.
+    ///
+    /// Does not count as a retry attempt.
+    ///
+    /// Transitions: Failed → InProgress → Settled
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if not in Failed state.
+    pub fn reconcile_settlement(&mut self, tx_ref: impl Into<String>) -> DomainResult<()> {
+        self.transition_to(SettlementState::InProgress)?;
+        self.next_settlement_retry_at = None;
+        self.confirm_settlement(tx_ref)
+    }
+
+    /// Forces a stuck settlement to `target`, recording an outcome an
+    /// administrator established out of band.
+    ///
+    /// A trade forced to Failed keeps `justification` as its failure reason
+    /// and becomes eligible for retry. Returns the state it was forced from.
+    ///
+    /// Transitions: InProgress → Settled/Failed
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` unless
+    /// [`SettlementState::can_force_to`] allows the transition.
+    pub fn force_settlement_state(
+        &mut self,
+        target: SettlementState,
+        justification: &str,
+    ) -> DomainResult<SettlementState> {
+        let from = self.settlement_state;
+        if !from.can_force_to(target) {
+            return Err(DomainError::InvalidState(format!(
+                "cannot force settlement from {} to {}",
+                from, target
+            )));
+        }
+        if target == SettlementState::Failed {
+            self.failure_reason = Some(justification.to_string());
+        }
+        self.settlement_state = target;
+        self.updated_at = Timestamp::now();
+        self.version = self.version.saturating_add(1);
+        Ok(from)
+    }
+
+    /// Gives up on settlement after retries are exhausted.
+    ///
+    /// Transitions: Failed → DeadLettered
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidState` if not in Failed state.
+    pub fn dead_letter(&mut self) -> DomainResult<()> {
+        self.transition_to(SettlementState::DeadLettered)?;
+        self.next_settlement_retry_at = None;
+        Ok(())
+    }
 }
 
 impl fmt::Display for Trade {
@@ -637,6 +1372,14 @@ mod tests {
             assert!(SettlementState::InProgress.can_transition_to(SettlementState::Failed));
         }
 
+        #[test]
+        fn only_stuck_settlements_can_be_forced() {
+            assert!(SettlementState::InProgress.can_force_to(SettlementState::Settled));
+            assert!(SettlementState::InProgress.can_force_to(SettlementState::Failed));
+            assert!(!SettlementState::Pending.can_force_to(SettlementState::Settled));
+            assert!(!SettlementState::DeadLettered.can_force_to(SettlementState::Settled));
+        }
+
         #[test]
         fn settled_is_terminal() {
             assert!(SettlementState::Settled.is_terminal());
@@ -644,8 +1387,29 @@ mod tests {
         }
 
         #[test]
-        fn failed_is_terminal() {
-            assert!(SettlementState::Failed.is_terminal());
+        fn failed_is_retryable() {
+            assert!(!SettlementState::Failed.is_terminal());
+            assert!(SettlementState::Failed.can_transition_to(SettlementState::InProgress));
+            assert!(SettlementState::Failed.can_transition_to(SettlementState::DeadLettered));
+            assert!(!SettlementState::Failed.can_transition_to(SettlementState::Settled));
+        }
+
+        #[test]
+        fn dead_lettered_is_terminal() {
+            assert!(SettlementState::DeadLettered.is_terminal());
+            assert!(!SettlementState::DeadLettered.can_transition_to(SettlementState::InProgress));
+        }
+
+        #[test]
+        fn settled_via_netting_is_terminal() {
+            assert!(SettlementState::Pending.can_transition_to(SettlementState::SettledViaNetting));
+            assert!(SettlementState::SettledViaNetting.is_terminal());
+            assert!(
+                !SettlementState::SettledViaNetting.can_transition_to(SettlementState::Pending)
+            );
+            assert!(
+                !SettlementState::InProgress.can_transition_to(SettlementState::SettledViaNetting)
+            );
         }
 
         #[test]
@@ -654,6 +1418,8 @@ mod tests {
             assert_eq!(SettlementState::InProgress.as_u8(), 1);
             assert_eq!(SettlementState::Settled.as_u8(), 2);
             assert_eq!(SettlementState::Failed.as_u8(), 3);
+            assert_eq!(SettlementState::DeadLettered.as_u8(), 4);
+            assert_eq!(SettlementState::SettledViaNetting.as_u8(), 5);
         }
 
         #[test]
@@ -666,6 +1432,10 @@ mod tests {
                 SettlementState::try_from(2).unwrap(),
                 SettlementState::Settled
             );
+            assert_eq!(
+                SettlementState::try_from(5).unwrap(),
+                SettlementState::SettledViaNetting
+            );
             assert!(SettlementState::try_from(99).is_err());
         }
 
@@ -675,6 +1445,11 @@ mod tests {
             assert_eq!(SettlementState::InProgress.to_string(), "IN_PROGRESS");
             assert_eq!(SettlementState::Settled.to_string(), "SETTLED");
             assert_eq!(SettlementState::Failed.to_string(), "FAILED");
+            assert_eq!(SettlementState::DeadLettered.to_string(), "DEAD_LETTERED");
+            assert_eq!(
+                SettlementState::SettledViaNetting.to_string(),
+                "SETTLED_VIA_NETTING"
+            );
         }
     }
 
@@ -765,6 +1540,30 @@ mod tests {
             assert!(matches!(result, Err(DomainError::InvalidState(_))));
         }
 
+        #[test]
+        fn force_settlement_state_rescues_a_stuck_settlement() {
+            let mut trade = create_test_trade();
+            trade.start_settlement().unwrap();
+
+            let from = trade
+                .force_settlement_state(SettlementState::Failed, "tx never broadcast")
+                .unwrap();
+
+            assert_eq!(from, SettlementState::InProgress);
+            assert!(trade.is_failed());
+            assert_eq!(trade.failure_reason(), Some("tx never broadcast"));
+            assert_eq!(trade.version(), 3);
+        }
+
+        #[test]
+        fn force_settlement_state_fails_from_pending() {
+            let mut trade = create_test_trade();
+
+            let result = trade.force_settlement_state(SettlementState::Settled, "paid");
+            assert!(matches!(result, Err(DomainError::InvalidState(_))));
+            assert!(trade.is_pending());
+        }
+
         #[test]
         fn cannot_transition_from_settled() {
             let mut trade = create_test_trade();
@@ -786,6 +1585,99 @@ mod tests {
             assert!(trade.start_settlement().is_err());
             assert!(trade.confirm_settlement("tx-123").is_err());
         }
+
+        #[test]
+        fn retry_settlement_counts_attempts() {
+            let mut trade = create_test_trade();
+            trade.start_settlement().unwrap();
+            trade.fail_settlement("rpc timeout").unwrap();
+            trade
+                .schedule_settlement_retry(Timestamp::now().add_secs(5))
+                .unwrap();
+            assert!(trade.next_settlement_retry_at().is_some());
+
+            trade.retry_settlement().unwrap();
+            assert!(trade.is_in_progress());
+            assert_eq!(trade.settlement_attempts(), 1);
+            assert!(trade.next_settlement_retry_at().is_none());
+
+            trade.confirm_settlement("tx-1").unwrap();
+            assert!(trade.is_settled());
+        }
+
+        #[test]
+        fn settlement_tx_survives_failure() {
+            let mut trade = create_test_trade();
+            trade.start_settlement().unwrap();
+            trade.record_settlement_tx("0xabc").unwrap();
+            trade.fail_settlement("timeout").unwrap();
+
+            assert_eq!(trade.settlement_tx_ref(), Some("0xabc"));
+        }
+
+        #[test]
+        fn reconcile_settlement_does_not_count_attempt() {
+            let mut trade = create_test_trade();
+            trade.start_settlement().unwrap();
+            trade.fail_settlement("timeout").unwrap();
+
+            trade.reconcile_settlement("0xabc").unwrap();
+            assert!(trade.is_settled());
+            assert_eq!(trade.settlement_attempts(), 0);
+        }
+
+        #[test]
+        fn dead_letter_from_failed_only() {
+            let mut trade = create_test_trade();
+            assert!(trade.dead_letter().is_err());
+
+            trade.start_settlement().unwrap();
+            trade.fail_settlement("reverted").unwrap();
+            trade.dead_letter().unwrap();
+
+            assert!(trade.is_dead_lettered());
+            assert!(trade.is_terminal());
+            assert!(trade.retry_settlement().is_err());
+            assert!(trade.schedule_settlement_retry(Timestamp::now()).is_err());
+        }
+
+        #[test]
+        fn netting_reservation_blocks_individual_settlement() {
+            let mut trade = create_test_trade();
+            let batch_id = NettingBatchId::new_v4();
+            trade.assign_to_netting_batch(batch_id).unwrap();
+
+            assert_eq!(trade.netting_batch_id(), Some(batch_id));
+            assert!(trade.is_pending());
+            assert!(trade.start_settlement().is_err());
+            assert!(
+                trade
+                    .assign_to_netting_batch(NettingBatchId::new_v4())
+                    .is_err()
+            );
+
+            trade.release_from_netting_batch().unwrap();
+            assert!(trade.netting_batch_id().is_none());
+            assert!(trade.start_settlement().is_ok());
+        }
+
+        #[test]
+        fn confirm_settlement_via_netting_requires_batch() {
+            let mut trade = create_test_trade();
+            assert!(trade.confirm_settlement_via_netting(None).is_err());
+
+            trade
+                .assign_to_netting_batch(NettingBatchId::new_v4())
+                .unwrap();
+            trade
+                .confirm_settlement_via_netting(Some("0xnet".to_string()))
+                .unwrap();
+
+            assert!(trade.is_settled_via_netting());
+            assert!(trade.is_terminal());
+            assert_eq!(trade.settlement_tx_ref(), Some("0xnet"));
+            assert!(trade.release_from_netting_batch().is_err());
+        }
     }
 
     mod helpers {
@@ -818,6 +1710,257 @@ mod tests {
         }
     }
 
+    mod costs {
+        use super::*;
+
+        fn trade_at(price: f64, reference: f64) -> Trade {
+            let mut trade = Trade::new(
+                test_rfq_id(),
+                test_quote_id(),
+                test_venue_id(),
+                Price::new(price).unwrap(),
+                test_quantity(),
+            );
+            trade.set_reference_price_at_execution(Price::new(reference).unwrap());
+            trade
+        }
+
+        #[test]
+        fn slippage_positive_when_buy_pays_above_reference() {
+            let trade = trade_at(101.0, 100.0);
+            assert_eq!(
+                trade.slippage_bps(OrderSide::Buy),
+                Some(SignedDecimalAmount::new(Decimal::new(100, 0)))
+            );
+        }
+
+        #[test]
+        fn slippage_negative_when_buy_pays_below_reference() {
+            let trade = trade_at(99.5, 100.0);
+            assert_eq!(
+                trade.slippage_bps(OrderSide::Buy),
+                Some(SignedDecimalAmount::new(Decimal::new(-50, 0)))
+            );
+        }
+
+        #[test]
+        fn slippage_positive_when_sell_receives_below_reference() {
+            let trade = trade_at(99.0, 100.0);
+            assert_eq!(
+                trade.slippage_bps(OrderSide::Sell),
+                Some(SignedDecimalAmount::new(Decimal::new(100, 0)))
+            );
+        }
+
+        #[test]
+        fn slippage_negative_when_sell_receives_above_reference() {
+            let trade = trade_at(100.5, 100.0);
+            assert_eq!(
+                trade.slippage_bps(OrderSide::Sell),
+                Some(SignedDecimalAmount::new(Decimal::new(-50, 0)))
+            );
+        }
+
+        #[test]
+        fn slippage_none_without_reference() {
+            let trade = create_test_trade();
+            assert!(trade.slippage_bps(OrderSide::Buy).is_none());
+        }
+
+        #[test]
+        fn slippage_none_with_zero_reference() {
+            let mut trade = create_test_trade();
+            trade.set_reference_price_at_execution(Price::zero());
+            assert!(trade.slippage_bps(OrderSide::Buy).is_none());
+        }
+
+        #[test]
+        fn total_fees_sums_components_per_currency() {
+            let mut trade = create_test_trade();
+            trade.add_fee(FeeComponent::new(
+                FeeKind::Venue,
+                Decimal::new(25, 1),
+                "USDC",
+            ));
+            trade.add_fee(FeeComponent::new(
+                FeeKind::Platform,
+                Decimal::new(1, 0),
+                "USDC",
+            ));
+            trade.add_fee(FeeComponent::new(
+                FeeKind::Venue,
+                Decimal::new(-5, 1),
+                "USDC",
+            ));
+            trade.add_fee(FeeComponent::new(FeeKind::Gas, Decimal::new(21, 5), "ETH"));
+
+            assert_eq!(trade.fees().len(), 4);
+            assert_eq!(trade.total_fees("USDC"), Some(Decimal::new(3, 0)));
+            assert_eq!(trade.total_fees("ETH"), Some(Decimal::new(21, 5)));
+            assert_eq!(trade.total_fees("BTC"), Some(Decimal::ZERO));
+        }
+
+        #[test]
+        fn total_fees_none_on_overflow() {
+            let mut trade = create_test_trade();
+            trade.add_fee(FeeComponent::new(FeeKind::Venue, Decimal::MAX, "USDC"));
+            trade.add_fee(FeeComponent::new(FeeKind::Gas, Decimal::MAX, "USDC"));
+            assert!(trade.total_fees("USDC").is_none());
+        }
+
+        #[test]
+        fn costs_survive_serde_roundtrip() {
+            let mut trade = trade_at(101.0, 100.0);
+            trade.add_fee(FeeComponent::new(FeeKind::Gas, Decimal::new(21, 5), "ETH"));
+
+            let json = serde_json::to_string(&trade).unwrap();
+            let deserialized: Trade = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(deserialized.fees(), trade.fees());
+            assert_eq!(
+                deserialized.reference_price_at_execution(),
+                trade.reference_price_at_execution()
+            );
+        }
+
+        #[test]
+        fn price_bounds_check_survives_serde_roundtrip() {
+            let mut trade = create_test_trade();
+            assert!(trade.price_bounds_check().is_none());
+            trade.set_price_bounds_check(PriceBoundsCheck::overridden("admin-1", "out of bounds"));
+
+            let json = serde_json::to_string(&trade).unwrap();
+            let deserialized: Trade = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(
+                deserialized.price_bounds_check(),
+                trade.price_bounds_check()
+            );
+        }
+
+        #[test]
+        fn collateral_decision_survives_serde_roundtrip() {
+            let mut trade = create_test_trade();
+            assert!(trade.collateral_decision().is_none());
+            trade.set_collateral_decision(CollateralDecision::Unavailable);
+
+            let json = serde_json::to_string(&trade).unwrap();
+            let deserialized: Trade = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(
+                deserialized.collateral_decision(),
+                Some(&CollateralDecision::Unavailable)
+            );
+        }
+    }
+
+    mod allocations {
+        use super::*;
+        use crate::domain::entities::allocation::Allocation;
+
+        fn recorded(venue: &str, qty: f64, price: f64) -> TradeAllocation {
+            TradeAllocation::scheduled(
+                Allocation::new(
+                    VenueId::new(venue),
+                    test_quote_id(),
+                    Quantity::new(qty).unwrap(),
+                    Price::new(price).unwrap(),
+                )
+                .unwrap(),
+                Timestamp::now(),
+            )
+        }
+
+        #[test]
+        fn vwap_weights_prices_by_quantity() {
+            let mut trade = create_test_trade();
+            trade.add_allocation(recorded("mm-1", 6.0, 100.0));
+            trade.add_allocation(recorded("mm-2", 4.0, 105.0));
+
+            assert_eq!(trade.allocations().len(), 2);
+            assert_eq!(trade.allocation_vwap(), Some(Price::new(102.0).unwrap()));
+        }
+
+        #[test]
+        fn vwap_is_none_without_allocations() {
+            assert_eq!(create_test_trade().allocation_vwap(), None);
+        }
+
+        #[test]
+        fn vwap_is_none_on_overflow() {
+            let mut trade = create_test_trade();
+            let huge = TradeAllocation::scheduled(
+                Allocation::from_parts(
+                    VenueId::new("mm-1"),
+                    test_quote_id(),
+                    Quantity::from_decimal(Decimal::MAX).unwrap(),
+                    Price::from_decimal(Decimal::MAX).unwrap(),
+                ),
+                Timestamp::now(),
+            );
+            trade.add_allocation(huge);
+
+            assert_eq!(trade.allocation_vwap(), None);
+        }
+
+        #[test]
+        fn sums_filled_and_failed_quantities() {
+            let mut trade = create_test_trade();
+            trade.add_allocation(recorded("mm-1", 6.0, 100.0));
+            trade.add_allocation(recorded("mm-2", 4.0, 105.0));
+            trade.start_allocations().unwrap();
+            trade.fill_allocation(0).unwrap();
+            trade.fail_allocation(1, "venue down").unwrap();
+            assert_eq!(trade.version(), 4);
+
+            assert_eq!(
+                trade.filled_allocation_quantity(),
+                Some(Quantity::new(6.0).unwrap())
+            );
+            assert_eq!(
+                trade.failed_allocation_quantity(),
+                Some(Quantity::new(4.0).unwrap())
+            );
+        }
+
+        #[test]
+        fn allocation_updates_are_checked() {
+            let mut trade = create_test_trade();
+            assert!(matches!(
+                trade.start_allocations(),
+                Err(DomainError::InvalidState(_))
+            ));
+
+            trade.add_allocation(recorded("mm-1", 6.0, 100.0));
+            assert!(matches!(
+                trade.fill_allocation(0),
+                Err(DomainError::InvalidState(_))
+            ));
+            trade.start_allocations().unwrap();
+            assert!(matches!(
+                trade.start_allocations(),
+                Err(DomainError::InvalidState(_))
+            ));
+            assert!(matches!(
+                trade.fill_allocation(1),
+                Err(DomainError::ValidationError(_))
+            ));
+        }
+
+        #[test]
+        fn unwind_trade_reverses_leg() {
+            let trade = create_test_trade();
+            let leg = recorded("mm-1", 6.0, 100.0);
+            let unwind = Trade::unwind_of(&trade, leg.allocation());
+
+            assert_eq!(unwind.unwinds(), Some(trade.id()));
+            assert_eq!(unwind.rfq_id(), trade.rfq_id());
+            assert_eq!(unwind.venue_id().as_str(), "mm-1");
+            assert_eq!(unwind.quantity(), Quantity::new(6.0).unwrap());
+            assert_ne!(unwind.id(), trade.id());
+        }
+    }
+
     mod display {
         use super::*;
 
@@ -855,6 +1998,7 @@ mod tests {
                 SettlementState::InProgress,
                 SettlementState::Settled,
                 SettlementState::Failed,
+                SettlementState::DeadLettered,
             ] {
                 let json = serde_json::to_string(&state).unwrap();
                 let deserialized: SettlementState = serde_json::from_str(&json).unwrap();
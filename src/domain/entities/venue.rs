@@ -3,7 +3,13 @@
 //! Represents a liquidity venue configuration.
 //!
 //! This module provides the [`Venue`] entity representing a liquidity source,
-//! including health monitoring, configuration, and performance metrics.
+//! including health monitoring, configuration, performance metrics, and the
+//! [`MaintenanceWindow`]s during which the venue is not sent RFQs.
+//!
+//! A venue's [`VenueMode`] decides how it takes part in the RFQ flow: `LIVE`
+//! venues quote for clients, `SHADOW` venues are sent RFQs and measured
+//! without their quotes ever reaching a client, and `DISABLED` venues are
+//! not sent RFQs at all.
 //!
 //! # Examples
 //!
@@ -21,11 +27,15 @@
 //! assert!(venue.is_healthy());
 //! ```
 
+use crate::domain::errors::{DomainError, DomainResult};
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{Instrument, VenueId, VenueType};
+use crate::domain::value_objects::{
+    CounterpartyId, Instrument, LatencyHistogram, VenueId, VenueType,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
+use utoipa::ToSchema;
 
 /// Venue health status.
 ///
@@ -39,7 +49,7 @@ use std::fmt;
 /// let health = VenueHealth::Healthy;
 /// assert!(health.is_operational());
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[repr(u8)]
 pub enum VenueHealth {
@@ -142,6 +152,105 @@ impl fmt::Display for InvalidVenueHealthError {
 
 impl std::error::Error for InvalidVenueHealthError {}
 
+/// How a venue takes part in the RFQ flow.
+///
+/// Deserializes from the mode name or, for records written before modes
+/// existed, from the former `enabled` flag: `true` is `LIVE` and `false`
+/// is `DISABLED`.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::entities::venue::VenueMode;
+///
+/// assert!(VenueMode::Shadow.receives_rfqs());
+/// assert!(!VenueMode::Shadow.is_live());
+/// assert_eq!(serde_json::from_str::<VenueMode>("false").unwrap(), VenueMode::Disabled);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VenueMode {
+    /// Sent RFQs; its quotes are shown to clients and may be selected.
+    #[default]
+    Live,
+    /// Sent RFQs; its quotes are recorded, ranked and measured but never
+    /// shown to clients or selected.
+    Shadow,
+    /// Not sent RFQs.
+    Disabled,
+}
+
+impl VenueMode {
+    /// Returns the mode as serialized (e.g. `SHADOW`).
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Live => "LIVE",
+            Self::Shadow => "SHADOW",
+            Self::Disabled => "DISABLED",
+        }
+    }
+
+    /// Returns true if the venue is sent RFQs (`LIVE` or `SHADOW`).
+    #[inline]
+    #[must_use]
+    pub const fn receives_rfqs(self) -> bool {
+        !matches!(self, Self::Disabled)
+    }
+
+    /// Returns true if the venue's quotes may reach clients.
+    #[inline]
+    #[must_use]
+    pub const fn is_live(self) -> bool {
+        matches!(self, Self::Live)
+    }
+
+    /// Returns true if the venue is in shadow mode.
+    #[inline]
+    #[must_use]
+    pub const fn is_shadow(self) -> bool {
+        matches!(self, Self::Shadow)
+    }
+}
+
+impl fmt::Display for VenueMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for VenueMode {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "LIVE" => Ok(Self::Live),
+            "SHADOW" => Ok(Self::Shadow),
+            "DISABLED" => Ok(Self::Disabled),
+            other => Err(DomainError::ValidationError(format!(
+                "unknown venue mode: {other}"
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for VenueMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Enabled(bool),
+            Mode(String),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Enabled(true) => Ok(Self::Live),
+            Repr::Enabled(false) => Ok(Self::Disabled),
+            Repr::Mode(mode) => mode.parse().map_err(serde::de::Error::custom),
+        }
+    }
+}
+
 /// Venue-specific configuration.
 ///
 /// Contains configuration parameters for connecting to and interacting
@@ -204,6 +313,12 @@ impl VenueConfig {
         self.settings.get(key)
     }
 
+    /// Returns all configuration key-value pairs.
+    #[must_use]
+    pub fn settings(&self) -> &HashMap<String, String> {
+        &self.settings
+    }
+
     /// Returns the request timeout in milliseconds.
     #[inline]
     #[must_use]
@@ -257,6 +372,8 @@ impl VenueConfig {
 ///
 /// assert_eq!(metrics.total_requests(), 3);
 /// assert_eq!(metrics.successful_requests(), 2);
+/// assert_eq!(metrics.average_latency_ms(), Some(150));
+/// assert!(metrics.latency_p50().unwrap().abs_diff(150) <= 5);
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct VenueMetrics {
@@ -268,12 +385,33 @@ pub struct VenueMetrics {
     failed_requests: u64,
     /// Total latency in milliseconds (for averaging).
     total_latency_ms: u64,
+    /// Request latency distribution, for percentiles.
+    #[serde(default)]
+    latency: LatencyHistogram,
     /// Last request timestamp.
     last_request_at: Option<Timestamp>,
     /// Last successful request timestamp.
     last_success_at: Option<Timestamp>,
     /// Last failure timestamp.
     last_failure_at: Option<Timestamp>,
+    /// Number of RFQ notifications delivered to the venue.
+    #[serde(default)]
+    notifications_delivered: u64,
+    /// Number of RFQ notifications that could not be delivered.
+    #[serde(default)]
+    notifications_failed: u64,
+    /// Health probes that failed in a row.
+    #[serde(default)]
+    consecutive_probe_failures: u32,
+    /// Health probes that succeeded in a row.
+    #[serde(default)]
+    consecutive_probe_successes: u32,
+    /// Latency of the most recent health probe in milliseconds.
+    #[serde(default)]
+    last_probe_latency_ms: Option<u64>,
+    /// When the venue was last probed.
+    #[serde(default)]
+    last_probe_at: Option<Timestamp>,
 }
 
 impl VenueMetrics {
@@ -288,6 +426,7 @@ impl VenueMetrics {
         let now = Timestamp::now();
         self.total_requests = self.total_requests.saturating_add(1);
         self.total_latency_ms = self.total_latency_ms.saturating_add(latency_ms);
+        self.latency.record(latency_ms);
         self.last_request_at = Some(now);
 
         if success {
@@ -299,6 +438,15 @@ impl VenueMetrics {
         }
     }
 
+    /// Records the outcome of notifying the venue of a new RFQ.
+    pub fn record_notification(&mut self, delivered: bool) {
+        if delivered {
+            self.notifications_delivered = self.notifications_delivered.saturating_add(1);
+        } else {
+            self.notifications_failed = self.notifications_failed.saturating_add(1);
+        }
+    }
+
     /// Returns the total number of requests.
     #[inline]
     #[must_use]
@@ -320,16 +468,92 @@ impl VenueMetrics {
         self.failed_requests
     }
 
-    /// Returns the average latency in milliseconds.
+    /// Returns the number of RFQ notifications delivered.
+    #[inline]
     #[must_use]
-    pub fn average_latency_ms(&self) -> Option<u64> {
-        if self.total_requests == 0 {
-            None
+    pub fn notifications_delivered(&self) -> u64 {
+        self.notifications_delivered
+    }
+
+    /// Returns the number of RFQ notifications that failed.
+    #[inline]
+    #[must_use]
+    pub fn notifications_failed(&self) -> u64 {
+        self.notifications_failed
+    }
+
+    /// Records the outcome of a health probe.
+    ///
+    /// A success resets the failure streak and a failure resets the
+    /// success streak. Probes do not count towards request metrics.
+    pub fn record_probe(&mut self, latency_ms: u64, success: bool) {
+        self.last_probe_latency_ms = Some(latency_ms);
+        self.last_probe_at = Some(Timestamp::now());
+
+        if success {
+            self.consecutive_probe_successes = self.consecutive_probe_successes.saturating_add(1);
+            self.consecutive_probe_failures = 0;
         } else {
-            Some(self.total_latency_ms / self.total_requests)
+            self.consecutive_probe_failures = self.consecutive_probe_failures.saturating_add(1);
+            self.consecutive_probe_successes = 0;
         }
     }
 
+    /// Returns the number of health probes that failed in a row.
+    #[inline]
+    #[must_use]
+    pub fn consecutive_probe_failures(&self) -> u32 {
+        self.consecutive_probe_failures
+    }
+
+    /// Returns the number of health probes that succeeded in a row.
+    #[inline]
+    #[must_use]
+    pub fn consecutive_probe_successes(&self) -> u32 {
+        self.consecutive_probe_successes
+    }
+
+    /// Returns the latency of the most recent health probe.
+    #[inline]
+    #[must_use]
+    pub fn last_probe_latency_ms(&self) -> Option<u64> {
+        self.last_probe_latency_ms
+    }
+
+    /// Returns when the venue was last probed.
+    #[inline]
+    #[must_use]
+    pub fn last_probe_at(&self) -> Option<Timestamp> {
+        self.last_probe_at
+    }
+
+    /// Returns the average latency in milliseconds.
+    #[must_use]
+    pub fn average_latency_ms(&self) -> Option<u64> {
+        self.total_latency_ms.checked_div(self.total_requests)
+    }
+
+    /// Returns the median request latency in milliseconds.
+    ///
+    /// `None` until a request has been recorded, including for metrics
+    /// stored before latency percentiles were tracked.
+    #[must_use]
+    pub fn latency_p50(&self) -> Option<u64> {
+        self.latency.percentile(0.5)
+    }
+
+    /// Returns the 90th percentile request latency in milliseconds.
+    #[must_use]
+    pub fn latency_p90(&self) -> Option<u64> {
+        self.latency.percentile(0.9)
+    }
+
+    /// Returns the 99th percentile request latency in milliseconds.
+    #[must_use]
+    pub fn latency_p99(&self) -> Option<u64> {
+        self.latency.percentile(0.99)
+    }
+
     /// Returns the success rate as a percentage (0-100).
     #[must_use]
     pub fn success_rate(&self) -> Option<f64> {
@@ -367,6 +591,107 @@ impl VenueMetrics {
     }
 }
 
+/// A scheduled period during which a venue is not sent RFQs.
+///
+/// The window covers `start` up to, but not including, `end`.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::entities::venue::MaintenanceWindow;
+/// use otc_rfq::domain::value_objects::Timestamp;
+///
+/// let start = Timestamp::now();
+/// let window = MaintenanceWindow::new(start, start.add_secs(3600), "upgrade").unwrap();
+///
+/// assert!(window.is_active_at(start));
+/// assert!(!window.is_active_at(start.add_secs(3600)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaintenanceWindow {
+    /// When the window opens.
+    start: Timestamp,
+    /// When the window closes.
+    end: Timestamp,
+    /// Why the venue is down, as announced.
+    reason: String,
+}
+
+impl MaintenanceWindow {
+    /// Creates a maintenance window.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if `end` is not after `start`.
+    pub fn new(start: Timestamp, end: Timestamp, reason: impl Into<String>) -> DomainResult<Self> {
+        if end <= start {
+            return Err(DomainError::ValidationError(
+                "maintenance window must end after it starts".to_string(),
+            ));
+        }
+        Ok(Self {
+            start,
+            end,
+            reason: reason.into(),
+        })
+    }
+
+    /// Returns when the window opens.
+    #[inline]
+    #[must_use]
+    pub fn start(&self) -> Timestamp {
+        self.start
+    }
+
+    /// Returns when the window closes.
+    #[inline]
+    #[must_use]
+    pub fn end(&self) -> Timestamp {
+        self.end
+    }
+
+    /// Returns the announced reason.
+    #[inline]
+    #[must_use]
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Returns true if `at` falls inside the window.
+    #[inline]
+    #[must_use]
+    pub fn is_active_at(&self, at: Timestamp) -> bool {
+        self.start <= at && at < self.end
+    }
+
+    /// Returns true if the window closed at or before `at`.
+    #[inline]
+    #[must_use]
+    pub fn has_ended_by(&self, at: Timestamp) -> bool {
+        self.end <= at
+    }
+
+    /// Returns true if the windows overlap or touch end to start.
+    fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Widens this window to cover `other`, keeping both reasons.
+    fn absorb(&mut self, other: &Self) {
+        self.start = self.start.min(other.start);
+        self.end = self.end.max(other.end);
+        for part in other.reason.split("; ").filter(|part| !part.is_empty()) {
+            if self.reason.split("; ").any(|known| known == part) {
+                continue;
+            }
+            if !self.reason.is_empty() {
+                self.reason.push_str("; ");
+            }
+            self.reason.push_str(part);
+        }
+    }
+}
+
 /// A liquidity venue.
 ///
 /// Represents a source of liquidity for executing trades, including
@@ -375,7 +700,7 @@ impl VenueMetrics {
 /// # Examples
 ///
 /// ```
-/// use otc_rfq::domain::entities::venue::{Venue, VenueHealth};
+/// use otc_rfq::domain::entities::venue::{Venue, VenueMode};
 /// use otc_rfq::domain::value_objects::{VenueId, VenueType};
 ///
 /// let mut venue = Venue::new(
@@ -387,7 +712,7 @@ impl VenueMetrics {
 /// assert!(venue.is_available());
 ///
 /// // Disable the venue
-/// venue.set_enabled(false);
+/// venue.set_mode(VenueMode::Disabled);
 /// assert!(!venue.is_available());
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -398,8 +723,10 @@ pub struct Venue {
     name: String,
     /// Type of venue.
     venue_type: VenueType,
-    /// Whether the venue is enabled.
-    enabled: bool,
+    /// How the venue takes part in the RFQ flow. Read from `enabled` in
+    /// records written before modes existed.
+    #[serde(alias = "enabled")]
+    mode: VenueMode,
     /// Current health status.
     health: VenueHealth,
     /// Venue configuration.
@@ -412,6 +739,12 @@ pub struct Venue {
     created_at: Timestamp,
     /// When this venue was last updated.
     updated_at: Timestamp,
+    /// Scheduled maintenance, disjoint and ordered by start.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    maintenance_windows: Vec<MaintenanceWindow>,
+    /// Market maker behind the venue, for performance tracking.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    mm_counterparty_id: Option<CounterpartyId>,
 }
 
 impl Venue {
@@ -429,13 +762,15 @@ impl Venue {
             id,
             name: name.into(),
             venue_type,
-            enabled: true,
+            mode: VenueMode::Live,
             health: VenueHealth::Healthy,
             config: VenueConfig::new(),
             metrics: VenueMetrics::new(),
             supported_instruments: Vec::new(),
             created_at: now,
             updated_at: now,
+            maintenance_windows: Vec::new(),
+            mm_counterparty_id: None,
         }
     }
 
@@ -446,7 +781,7 @@ impl Venue {
         id: VenueId,
         name: String,
         venue_type: VenueType,
-        enabled: bool,
+        mode: VenueMode,
         health: VenueHealth,
         config: VenueConfig,
         metrics: VenueMetrics,
@@ -458,13 +793,15 @@ impl Venue {
             id,
             name,
             venue_type,
-            enabled,
+            mode,
             health,
             config,
             metrics,
             supported_instruments,
             created_at,
             updated_at,
+            maintenance_windows: Vec::new(),
+            mm_counterparty_id: None,
         }
     }
 
@@ -491,11 +828,25 @@ impl Venue {
         self.venue_type
     }
 
-    /// Returns whether the venue is enabled.
+    /// Returns how the venue takes part in the RFQ flow.
+    #[inline]
+    #[must_use]
+    pub fn mode(&self) -> VenueMode {
+        self.mode
+    }
+
+    /// Returns whether the venue is sent RFQs (live or shadow).
     #[inline]
     #[must_use]
     pub fn is_enabled(&self) -> bool {
-        self.enabled
+        self.mode.receives_rfqs()
+    }
+
+    /// Returns whether the venue is in shadow mode.
+    #[inline]
+    #[must_use]
+    pub fn is_shadow(&self) -> bool {
+        self.mode.is_shadow()
     }
 
     /// Returns the current health status.
@@ -539,6 +890,20 @@ impl Venue {
         &self.supported_instruments
     }
 
+    /// Returns the scheduled maintenance windows, ordered by start.
+    #[inline]
+    #[must_use]
+    pub fn maintenance_windows(&self) -> &[MaintenanceWindow] {
+        &self.maintenance_windows
+    }
+
+    /// Returns the market maker behind the venue, if one is set.
+    #[inline]
+    #[must_use]
+    pub fn mm_counterparty_id(&self) -> Option<&CounterpartyId> {
+        self.mm_counterparty_id.as_ref()
+    }
+
     /// Returns when this venue was created.
     #[inline]
     #[must_use]
@@ -561,7 +926,7 @@ impl Venue {
     #[inline]
     #[must_use]
     pub fn is_available(&self) -> bool {
-        self.enabled && self.health.is_operational()
+        self.is_enabled() && self.health.is_operational()
     }
 
     /// Returns true if the venue is fully healthy.
@@ -585,6 +950,21 @@ impl Venue {
         self.venue_type.is_defi()
     }
 
+    /// Returns the maintenance window in force at `at`, if any.
+    #[must_use]
+    pub fn active_maintenance_window(&self, at: Timestamp) -> Option<&MaintenanceWindow> {
+        self.maintenance_windows
+            .iter()
+            .find(|window| window.is_active_at(at))
+    }
+
+    /// Returns true if the venue is inside a maintenance window at `at`.
+    #[inline]
+    #[must_use]
+    pub fn is_in_maintenance_at(&self, at: Timestamp) -> bool {
+        self.active_maintenance_window(at).is_some()
+    }
+
     /// Returns true if the venue supports the given instrument.
     #[must_use]
     pub fn supports_instrument(&self, instrument: &Instrument) -> bool {
@@ -593,9 +973,15 @@ impl Venue {
 
     // ========== Mutators ==========
 
-    /// Sets whether the venue is enabled.
-    pub fn set_enabled(&mut self, enabled: bool) {
-        self.enabled = enabled;
+    /// Sets how the venue takes part in the RFQ flow.
+    pub fn set_mode(&mut self, mode: VenueMode) {
+        self.mode = mode;
+        self.updated_at = Timestamp::now();
+    }
+
+    /// Sets the market maker behind the venue.
+    pub fn set_mm_counterparty_id(&mut self, mm_counterparty_id: Option<CounterpartyId>) {
+        self.mm_counterparty_id = mm_counterparty_id;
         self.updated_at = Timestamp::now();
     }
 
@@ -632,6 +1018,54 @@ impl Venue {
             self.updated_at = Timestamp::now();
         }
     }
+
+    /// Schedules a maintenance window.
+    ///
+    /// Windows that overlap or touch the new one are merged into it, so the
+    /// schedule stays disjoint. Returns the window as stored.
+    pub fn add_maintenance_window(&mut self, window: MaintenanceWindow) -> MaintenanceWindow {
+        let mut merged = window;
+        self.maintenance_windows.retain(|existing| {
+            if merged.overlaps(existing) {
+                merged.absorb(existing);
+                false
+            } else {
+                true
+            }
+        });
+        let pos = self
+            .maintenance_windows
+            .partition_point(|existing| existing.start < merged.start);
+        self.maintenance_windows.insert(pos, merged.clone());
+        self.updated_at = Timestamp::now();
+        merged
+    }
+
+    /// Removes the maintenance window starting at `start`.
+    ///
+    /// Returns the removed window, or `None` if no window starts then.
+    pub fn remove_maintenance_window(&mut self, start: Timestamp) -> Option<MaintenanceWindow> {
+        let pos = self
+            .maintenance_windows
+            .iter()
+            .position(|window| window.start == start)?;
+        self.updated_at = Timestamp::now();
+        Some(self.maintenance_windows.remove(pos))
+    }
+
+    /// Removes maintenance windows that closed at or before `now`.
+    ///
+    /// Returns the number of windows removed.
+    pub fn prune_maintenance_windows(&mut self, now: Timestamp) -> usize {
+        let before = self.maintenance_windows.len();
+        self.maintenance_windows
+            .retain(|window| !window.has_ended_by(now));
+        let pruned = before - self.maintenance_windows.len();
+        if pruned > 0 {
+            self.updated_at = Timestamp::now();
+        }
+        pruned
+    }
 }
 
 impl fmt::Display for Venue {
@@ -793,6 +1227,43 @@ mod tests {
             assert_eq!(metrics.average_latency_ms(), Some(200));
         }
 
+        #[test]
+        fn latency_percentiles_expose_the_tail() {
+            let mut metrics = VenueMetrics::new();
+            for _ in 0..980 {
+                metrics.record_request(80, true);
+            }
+            for _ in 0..20 {
+                metrics.record_request(4_000, false);
+            }
+
+            // The average hides the slow tail that p99 exposes
+            assert_eq!(metrics.average_latency_ms(), Some(158));
+            assert!(metrics.latency_p50().unwrap().abs_diff(80) <= 2);
+            assert!(metrics.latency_p90().unwrap().abs_diff(80) <= 2);
+            assert!(metrics.latency_p99().unwrap().abs_diff(4_000) <= 120);
+        }
+
+        #[test]
+        fn latency_percentiles_survive_serde_roundtrip() {
+            let mut metrics = VenueMetrics::new();
+            for latency in [40, 90, 120, 900] {
+                metrics.record_request(latency, true);
+            }
+
+            let json = serde_json::to_string(&metrics).unwrap();
+            let restored: VenueMetrics = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored.latency_p99(), metrics.latency_p99());
+            assert_eq!(restored, metrics);
+
+            // Metrics stored before percentiles were tracked still load
+            let mut legacy = serde_json::to_value(&metrics).unwrap();
+            legacy.as_object_mut().unwrap().remove("latency");
+            let restored: VenueMetrics = serde_json::from_value(legacy).unwrap();
+            assert_eq!(restored.average_latency_ms(), metrics.average_latency_ms());
+            assert!(restored.latency_p50().is_none());
+        }
+
         #[test]
         fn success_rate() {
             let mut metrics = VenueMetrics::new();
@@ -813,6 +1284,34 @@ mod tests {
 
             assert_eq!(metrics.total_requests(), 0);
         }
+
+        #[test]
+        fn record_notification() {
+            let mut metrics = VenueMetrics::new();
+            metrics.record_notification(true);
+            metrics.record_notification(true);
+            metrics.record_notification(false);
+
+            assert_eq!(metrics.notifications_delivered(), 2);
+            assert_eq!(metrics.notifications_failed(), 1);
+            assert_eq!(metrics.total_requests(), 0);
+        }
+
+        #[test]
+        fn record_probe_tracks_streaks() {
+            let mut metrics = VenueMetrics::new();
+            metrics.record_probe(10, false);
+            metrics.record_probe(12, false);
+            assert_eq!(metrics.consecutive_probe_failures(), 2);
+            assert_eq!(metrics.consecutive_probe_successes(), 0);
+
+            metrics.record_probe(7, true);
+            assert_eq!(metrics.consecutive_probe_failures(), 0);
+            assert_eq!(metrics.consecutive_probe_successes(), 1);
+            assert_eq!(metrics.last_probe_latency_ms(), Some(7));
+            assert!(metrics.last_probe_at().is_some());
+            assert_eq!(metrics.total_requests(), 0);
+        }
     }
 
     mod venue_construction {
@@ -859,7 +1358,7 @@ mod tests {
         #[test]
         fn unavailable_when_disabled() {
             let mut venue = create_test_venue();
-            venue.set_enabled(false);
+            venue.set_mode(VenueMode::Disabled);
             assert!(!venue.is_available());
         }
 
@@ -926,6 +1425,108 @@ mod tests {
         }
     }
 
+    mod maintenance {
+        use super::*;
+
+        fn at(secs: i64) -> Timestamp {
+            Timestamp::from_secs(1_800_000_000 + secs).unwrap()
+        }
+
+        fn window(start: i64, end: i64, reason: &str) -> MaintenanceWindow {
+            MaintenanceWindow::new(at(start), at(end), reason).unwrap()
+        }
+
+        #[test]
+        fn rejects_empty_window() {
+            let result = MaintenanceWindow::new(at(10), at(10), "noop");
+            assert!(matches!(result, Err(DomainError::ValidationError(_))));
+        }
+
+        #[test]
+        fn active_from_start_until_end() {
+            let mut venue = create_test_venue();
+            venue.add_maintenance_window(window(0, 60, "upgrade"));
+
+            assert!(!venue.is_in_maintenance_at(at(-1)));
+            assert!(venue.is_in_maintenance_at(at(0)));
+            assert!(venue.is_in_maintenance_at(at(59)));
+            assert!(!venue.is_in_maintenance_at(at(60)));
+            assert!(!venue.is_in_maintenance_at(at(61)));
+        }
+
+        #[test]
+        fn overlapping_windows_merge_on_insert() {
+            let mut venue = create_test_venue();
+            venue.add_maintenance_window(window(0, 60, "upgrade"));
+            venue.add_maintenance_window(window(200, 260, "migration"));
+            let merged = venue.add_maintenance_window(window(30, 120, "db failover"));
+
+            assert_eq!(merged.start(), at(0));
+            assert_eq!(merged.end(), at(120));
+            assert_eq!(merged.reason(), "db failover; upgrade");
+            assert_eq!(venue.maintenance_windows().len(), 2);
+
+            let spanning = venue.add_maintenance_window(window(100, 200, "upgrade"));
+            assert_eq!(spanning.start(), at(0));
+            assert_eq!(spanning.end(), at(260));
+            assert_eq!(spanning.reason(), "upgrade; db failover; migration");
+            assert_eq!(venue.maintenance_windows(), [spanning]);
+        }
+
+        #[test]
+        fn windows_stay_ordered_by_start() {
+            let mut venue = create_test_venue();
+            venue.add_maintenance_window(window(500, 600, "b"));
+            venue.add_maintenance_window(window(0, 60, "a"));
+
+            let starts: Vec<_> = venue
+                .maintenance_windows()
+                .iter()
+                .map(MaintenanceWindow::start)
+                .collect();
+            assert_eq!(starts, vec![at(0), at(500)]);
+        }
+
+        #[test]
+        fn remove_by_start() {
+            let mut venue = create_test_venue();
+            venue.add_maintenance_window(window(0, 60, "upgrade"));
+
+            assert!(venue.remove_maintenance_window(at(1)).is_none());
+            let removed = venue.remove_maintenance_window(at(0)).unwrap();
+            assert_eq!(removed.reason(), "upgrade");
+            assert!(venue.maintenance_windows().is_empty());
+        }
+
+        #[test]
+        fn prune_drops_only_past_windows() {
+            let mut venue = create_test_venue();
+            venue.add_maintenance_window(window(0, 60, "past"));
+            venue.add_maintenance_window(window(100, 200, "current"));
+            venue.add_maintenance_window(window(300, 400, "future"));
+
+            assert_eq!(venue.prune_maintenance_windows(at(150)), 1);
+            assert_eq!(venue.maintenance_windows().len(), 2);
+            assert_eq!(venue.prune_maintenance_windows(at(150)), 0);
+        }
+
+        #[test]
+        fn windows_survive_serde() {
+            let mut venue = create_test_venue();
+            venue.add_maintenance_window(window(0, 60, "upgrade"));
+
+            let json = serde_json::to_string(&venue).unwrap();
+            let deserialized: Venue = serde_json::from_str(&json).unwrap();
+            assert_eq!(
+                deserialized.maintenance_windows(),
+                venue.maintenance_windows()
+            );
+
+            let legacy = serde_json::to_string(&create_test_venue()).unwrap();
+            assert!(!legacy.contains("maintenance_windows"));
+        }
+    }
+
     mod display {
         use super::*;
 
@@ -942,7 +1543,7 @@ mod tests {
         #[test]
         fn display_unavailable() {
             let mut venue = create_test_venue();
-            venue.set_enabled(false);
+            venue.set_mode(VenueMode::Disabled);
             let display = venue.to_string();
 
             assert!(display.contains("unavailable"));
@@ -990,5 +1591,22 @@ mod tests {
             let json = serde_json::to_string(&VenueHealth::Unhealthy).unwrap();
             assert_eq!(json, "\"UNHEALTHY\"");
         }
+
+        #[test]
+        fn venue_mode_reads_legacy_enabled_flag() {
+            let mut venue = create_test_venue();
+            venue.set_mode(VenueMode::Shadow);
+            let json = serde_json::to_string(&venue).unwrap();
+            assert!(json.contains("\"mode\":\"SHADOW\""));
+            let restored: Venue = serde_json::from_str(&json).unwrap();
+            assert!(restored.is_shadow());
+            assert!(restored.is_enabled());
+
+            for (enabled, mode) in [(true, VenueMode::Live), (false, VenueMode::Disabled)] {
+                let legacy = json.replace("\"mode\":\"SHADOW\"", &format!("\"enabled\":{enabled}"));
+                let restored: Venue = serde_json::from_str(&legacy).unwrap();
+                assert_eq!(restored.mode(), mode);
+            }
+        }
     }
 }
@@ -0,0 +1,251 @@
+//! # Venue Configuration History
+//!
+//! Records operator changes to a venue's configuration.
+//!
+//! Every venue update produces a [`VenueConfigChange`] holding the
+//! settings before and after the update, who made it, and when. A rollback
+//! re-applies the settings from before an earlier change and is recorded
+//! like any other update, with [`VenueConfigChange::rollback_of`] naming the
+//! entry it reverted.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::entities::venue::Venue;
+//! use otc_rfq::domain::entities::venue_config_change::VenueSettings;
+//! use otc_rfq::domain::value_objects::{VenueId, VenueType};
+//!
+//! let mut venue = Venue::new(VenueId::new("binance"), "Binance", VenueType::ExternalMM);
+//! let before = VenueSettings::of(&venue);
+//!
+//! venue.config_mut().set_timeout_ms(50);
+//! let after = VenueSettings::of(&venue);
+//!
+//! assert_eq!(before.changed_fields(&after), vec!["timeout_ms"]);
+//! ```
+
+use crate::domain::entities::venue::{Venue, VenueConfig, VenueMode};
+use crate::domain::value_objects::VenueId;
+use crate::domain::value_objects::timestamp::Timestamp;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Operator-editable settings of a venue at a point in time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VenueSettings {
+    /// How the venue takes part in the RFQ flow. Read from `enabled` in
+    /// history recorded before modes existed.
+    #[serde(alias = "enabled")]
+    mode: VenueMode,
+    /// Venue configuration.
+    config: VenueConfig,
+}
+
+impl VenueSettings {
+    /// Captures the current settings of a venue.
+    #[must_use]
+    pub fn of(venue: &Venue) -> Self {
+        Self {
+            mode: venue.mode(),
+            config: venue.config().clone(),
+        }
+    }
+
+    /// Returns how the venue takes part in the RFQ flow.
+    #[inline]
+    #[must_use]
+    pub fn mode(&self) -> VenueMode {
+        self.mode
+    }
+
+    /// Returns the venue configuration.
+    #[inline]
+    #[must_use]
+    pub fn config(&self) -> &VenueConfig {
+        &self.config
+    }
+
+    /// Overwrites the venue's settings with these.
+    pub fn apply_to(&self, venue: &mut Venue) {
+        venue.set_mode(self.mode);
+        *venue.config_mut() = self.config.clone();
+    }
+
+    /// Returns the names of the fields that differ from `other`.
+    ///
+    /// Free-form settings are reported as `settings.<key>`.
+    #[must_use]
+    pub fn changed_fields(&self, other: &Self) -> Vec<String> {
+        let mut fields = Vec::new();
+        if self.mode != other.mode {
+            fields.push("mode".to_string());
+        }
+        if self.config.timeout_ms() != other.config.timeout_ms() {
+            fields.push("timeout_ms".to_string());
+        }
+        if self.config.max_concurrent_requests() != other.config.max_concurrent_requests() {
+            fields.push("max_concurrent_requests".to_string());
+        }
+        if self.config.use_tls() != other.config.use_tls() {
+            fields.push("use_tls".to_string());
+        }
+
+        let keys: BTreeSet<&String> = self
+            .config
+            .settings()
+            .keys()
+            .chain(other.config.settings().keys())
+            .collect();
+        for key in keys {
+            if self.config.get(key) != other.config.get(key) {
+                fields.push(format!("settings.{key}"));
+            }
+        }
+        fields
+    }
+}
+
+/// One recorded change to a venue's configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VenueConfigChange {
+    /// History entry identifier.
+    id: u64,
+    /// Venue that was changed.
+    venue_id: VenueId,
+    /// Settings before the change.
+    old_settings: VenueSettings,
+    /// Settings after the change.
+    new_settings: VenueSettings,
+    /// Subject that made the change.
+    changed_by: String,
+    /// When the change was made.
+    changed_at: Timestamp,
+    /// History entry this change rolled back, if it was a rollback.
+    rollback_of: Option<u64>,
+}
+
+impl VenueConfigChange {
+    /// Creates a history entry (for reconstruction from storage).
+    #[must_use]
+    pub fn from_parts(
+        id: u64,
+        venue_id: VenueId,
+        old_settings: VenueSettings,
+        new_settings: VenueSettings,
+        changed_by: String,
+        changed_at: Timestamp,
+        rollback_of: Option<u64>,
+    ) -> Self {
+        Self {
+            id,
+            venue_id,
+            old_settings,
+            new_settings,
+            changed_by,
+            changed_at,
+            rollback_of,
+        }
+    }
+
+    /// Returns the history entry identifier.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the venue that was changed.
+    #[inline]
+    #[must_use]
+    pub fn venue_id(&self) -> &VenueId {
+        &self.venue_id
+    }
+
+    /// Returns the settings before the change.
+    #[inline]
+    #[must_use]
+    pub fn old_settings(&self) -> &VenueSettings {
+        &self.old_settings
+    }
+
+    /// Returns the settings after the change.
+    #[inline]
+    #[must_use]
+    pub fn new_settings(&self) -> &VenueSettings {
+        &self.new_settings
+    }
+
+    /// Returns the subject that made the change.
+    #[inline]
+    #[must_use]
+    pub fn changed_by(&self) -> &str {
+        &self.changed_by
+    }
+
+    /// Returns when the change was made.
+    #[inline]
+    #[must_use]
+    pub fn changed_at(&self) -> Timestamp {
+        self.changed_at
+    }
+
+    /// Returns the history entry this change rolled back, if any.
+    #[inline]
+    #[must_use]
+    pub fn rollback_of(&self) -> Option<u64> {
+        self.rollback_of
+    }
+
+    /// Returns the names of the fields this change modified.
+    #[must_use]
+    pub fn changed_fields(&self) -> Vec<String> {
+        self.old_settings.changed_fields(&self.new_settings)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::VenueType;
+
+    fn venue() -> Venue {
+        Venue::new(VenueId::new("venue-1"), "Venue 1", VenueType::ExternalMM)
+    }
+
+    #[test]
+    fn unchanged_settings_have_no_changed_fields() {
+        let settings = VenueSettings::of(&venue());
+        assert!(settings.changed_fields(&settings.clone()).is_empty());
+    }
+
+    #[test]
+    fn changed_fields_lists_every_difference() {
+        let mut venue = venue();
+        venue.config_mut().set("api_url", "https://old");
+        let before = VenueSettings::of(&venue);
+
+        venue.set_mode(VenueMode::Disabled);
+        venue.config_mut().set_timeout_ms(50);
+        venue.config_mut().set("api_url", "https://new");
+        venue.config_mut().set("region", "eu");
+        let after = VenueSettings::of(&venue);
+
+        assert_eq!(
+            before.changed_fields(&after),
+            vec!["mode", "timeout_ms", "settings.api_url", "settings.region"]
+        );
+    }
+
+    #[test]
+    fn apply_to_restores_settings() {
+        let mut venue = venue();
+        let before = VenueSettings::of(&venue);
+
+        venue.set_mode(VenueMode::Disabled);
+        venue.config_mut().set_use_tls(false);
+        before.apply_to(&mut venue);
+
+        assert_eq!(VenueSettings::of(&venue), before);
+    }
+}
@@ -0,0 +1,272 @@
+//! # Venue Credential Set
+//!
+//! A counterparty's own API credentials for a venue.
+//!
+//! Clients with a direct relationship with a venue can have their RFQs
+//! quoted on their own account there, so fills count against their tiers
+//! and rebates. This module provides the [`VenueCredentialSet`] entity,
+//! keyed by counterparty and venue, holding the [`VenueCredentials`] the
+//! venue adapter authenticates with in place of the platform's.
+//!
+//! The secrets never leave the process except encrypted at rest:
+//! [`VenueCredentials`] is neither serializable nor printable, and its
+//! `Debug` output is redacted.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::entities::venue_credential_set::{VenueCredentialSet, VenueCredentials};
+//! use otc_rfq::domain::value_objects::{CounterpartyId, VenueId};
+//!
+//! let credentials = VenueCredentials::new("client-api-key")
+//!     .unwrap()
+//!     .with_api_secret("client-api-secret")
+//!     .unwrap();
+//! let set = VenueCredentialSet::new(
+//!     CounterpartyId::new("acme-trading"),
+//!     VenueId::new("hashflow"),
+//!     credentials,
+//! );
+//!
+//! assert_eq!(set.credentials().api_key(), "client-api-key");
+//! assert!(!format!("{set:?}").contains("client-api-key"));
+//! ```
+
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{CounterpartyId, VenueId};
+use std::fmt;
+
+/// API credentials for a venue.
+#[derive(Clone, PartialEq, Eq)]
+pub struct VenueCredentials {
+    api_key: String,
+    api_secret: Option<String>,
+}
+
+impl VenueCredentials {
+    /// Creates credentials with an API key.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if the key is blank or
+    /// contains whitespace or control characters.
+    pub fn new(api_key: impl Into<String>) -> DomainResult<Self> {
+        Ok(Self {
+            api_key: Self::validate("API key", api_key.into())?,
+            api_secret: None,
+        })
+    }
+
+    /// Adds an API secret, for venues that sign requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if the secret is blank or
+    /// contains whitespace or control characters.
+    pub fn with_api_secret(mut self, api_secret: impl Into<String>) -> DomainResult<Self> {
+        self.api_secret = Some(Self::validate("API secret", api_secret.into())?);
+        Ok(self)
+    }
+
+    /// Returns the API key.
+    #[inline]
+    #[must_use]
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    /// Returns the API secret, if set.
+    #[inline]
+    #[must_use]
+    pub fn api_secret(&self) -> Option<&str> {
+        self.api_secret.as_deref()
+    }
+
+    /// Returns every secret value, for redaction.
+    pub fn secrets(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.api_key.as_str()).chain(self.api_secret.as_deref())
+    }
+
+    fn validate(name: &str, value: String) -> DomainResult<String> {
+        if value.is_empty() {
+            return Err(DomainError::ValidationError(format!(
+                "{name} must not be empty"
+            )));
+        }
+        if value.chars().any(|c| c.is_whitespace() || c.is_control()) {
+            return Err(DomainError::ValidationError(format!(
+                "{name} must not contain whitespace or control characters"
+            )));
+        }
+        Ok(value)
+    }
+}
+
+impl fmt::Debug for VenueCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VenueCredentials")
+            .field("api_key", &"<redacted>")
+            .field(
+                "api_secret",
+                &self.api_secret.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+/// A counterparty's credentials for one venue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VenueCredentialSet {
+    counterparty_id: CounterpartyId,
+    venue_id: VenueId,
+    credentials: VenueCredentials,
+    created_at: Timestamp,
+    updated_at: Timestamp,
+}
+
+impl VenueCredentialSet {
+    /// Creates a credential set for `counterparty_id` on `venue_id`.
+    #[must_use]
+    pub fn new(
+        counterparty_id: CounterpartyId,
+        venue_id: VenueId,
+        credentials: VenueCredentials,
+    ) -> Self {
+        let now = Timestamp::now();
+        Self {
+            counterparty_id,
+            venue_id,
+            credentials,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Creates a credential set with specific values (for reconstruction
+    /// from storage).
+    #[must_use]
+    pub fn from_parts(
+        counterparty_id: CounterpartyId,
+        venue_id: VenueId,
+        credentials: VenueCredentials,
+        created_at: Timestamp,
+        updated_at: Timestamp,
+    ) -> Self {
+        Self {
+            counterparty_id,
+            venue_id,
+            credentials,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Returns the counterparty the credentials belong to.
+    #[inline]
+    #[must_use]
+    pub fn counterparty_id(&self) -> &CounterpartyId {
+        &self.counterparty_id
+    }
+
+    /// Returns the venue the credentials are for.
+    #[inline]
+    #[must_use]
+    pub fn venue_id(&self) -> &VenueId {
+        &self.venue_id
+    }
+
+    /// Returns the credentials.
+    #[inline]
+    #[must_use]
+    pub fn credentials(&self) -> &VenueCredentials {
+        &self.credentials
+    }
+
+    /// Returns when the credentials were first stored.
+    #[inline]
+    #[must_use]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    /// Returns when the credentials were last replaced.
+    #[inline]
+    #[must_use]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    /// Replaces the credentials, e.g. after the client rotated its key.
+    pub fn rotate(&mut self, credentials: VenueCredentials) {
+        self.credentials = credentials;
+        self.updated_at = Timestamp::now();
+    }
+}
+
+impl fmt::Display for VenueCredentialSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "VenueCredentialSet({} @ {})",
+            self.counterparty_id, self.venue_id
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_output_never_shows_secrets() {
+        let credentials = VenueCredentials::new("key-123")
+            .unwrap()
+            .with_api_secret("secret-456")
+            .unwrap();
+        let set = VenueCredentialSet::new(
+            CounterpartyId::new("acme"),
+            VenueId::new("bebop"),
+            credentials,
+        );
+
+        let debug = format!("{set:?}");
+        assert!(!debug.contains("key-123"));
+        assert!(!debug.contains("secret-456"));
+        assert!(debug.contains("<redacted>"));
+        assert_eq!(
+            set.credentials().secrets().collect::<Vec<_>>(),
+            vec!["key-123", "secret-456"]
+        );
+    }
+
+    #[test]
+    fn blank_or_header_breaking_values_are_rejected() {
+        assert!(VenueCredentials::new("").is_err());
+        assert!(VenueCredentials::new("key with space").is_err());
+        assert!(VenueCredentials::new("key\r\nX-Injected: 1").is_err());
+        assert!(
+            VenueCredentials::new("key")
+                .unwrap()
+                .with_api_secret("")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn rotation_replaces_credentials_and_keeps_creation_time() {
+        let mut set = VenueCredentialSet::new(
+            CounterpartyId::new("acme"),
+            VenueId::new("hashflow"),
+            VenueCredentials::new("old").unwrap(),
+        );
+        let created_at = set.created_at();
+
+        set.rotate(VenueCredentials::new("new").unwrap());
+
+        assert_eq!(set.credentials().api_key(), "new");
+        assert_eq!(set.created_at(), created_at);
+        assert!(set.updated_at() >= created_at);
+    }
+}
@@ -0,0 +1,381 @@
+//! # Webhook Subscription
+//!
+//! Registration of a downstream system that receives signed event webhooks.
+//!
+//! This module provides the [`WebhookSubscription`] entity. A subscription
+//! names an endpoint, the shared secret used to sign deliveries, and the
+//! events it wants. Deliveries that keep failing count against the
+//! subscription, and after [`AUTO_DISABLE_THRESHOLD`] consecutive failures
+//! it is deactivated so a dead endpoint stops consuming delivery capacity.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::entities::webhook_subscription::WebhookSubscription;
+//!
+//! let subscription = WebhookSubscription::new(
+//!     "https://risk.example.com/hooks/otc",
+//!     "shared-secret",
+//!     vec!["TradeExecuted".to_string()],
+//! )
+//! .unwrap();
+//!
+//! assert!(subscription.matches("TradeExecuted"));
+//! assert!(!subscription.matches("SettlementConfirmed"));
+//! ```
+
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::WebhookSubscriptionId;
+use crate::domain::value_objects::timestamp::Timestamp;
+use std::fmt;
+
+/// Events a webhook subscription can filter on.
+pub const WEBHOOK_EVENTS: &[&str] = &["TradeExecuted", "SettlementConfirmed"];
+
+/// Consecutive failed deliveries after which a subscription is deactivated.
+pub const AUTO_DISABLE_THRESHOLD: u32 = 50;
+
+/// A downstream endpoint subscribed to signed event webhooks.
+#[derive(Clone, PartialEq, Eq)]
+pub struct WebhookSubscription {
+    id: WebhookSubscriptionId,
+    url: String,
+    secret: String,
+    event_filters: Vec<String>,
+    active: bool,
+    consecutive_failures: u32,
+    created_at: Timestamp,
+    updated_at: Timestamp,
+}
+
+impl WebhookSubscription {
+    /// Creates an active subscription.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if the URL is not HTTP(S),
+    /// the secret is empty, or the filters are empty or name an event
+    /// outside [`WEBHOOK_EVENTS`].
+    pub fn new(
+        url: impl Into<String>,
+        secret: impl Into<String>,
+        event_filters: Vec<String>,
+    ) -> DomainResult<Self> {
+        let url = url.into();
+        let secret = secret.into();
+        validate_url(&url)?;
+        validate_secret(&secret)?;
+        let event_filters = normalize_filters(event_filters)?;
+        let now = Timestamp::now();
+        Ok(Self {
+            id: WebhookSubscriptionId::new_v4(),
+            url,
+            secret,
+            event_filters,
+            active: true,
+            consecutive_failures: 0,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Reconstructs a subscription from stored parts without validation.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn from_parts(
+        id: WebhookSubscriptionId,
+        url: String,
+        secret: String,
+        event_filters: Vec<String>,
+        active: bool,
+        consecutive_failures: u32,
+        created_at: Timestamp,
+        updated_at: Timestamp,
+    ) -> Self {
+        Self {
+            id,
+            url,
+            secret,
+            event_filters,
+            active,
+            consecutive_failures,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Returns the subscription ID.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> WebhookSubscriptionId {
+        self.id
+    }
+
+    /// Returns the endpoint deliveries are posted to.
+    #[inline]
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the shared secret deliveries are signed with.
+    #[inline]
+    #[must_use]
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+
+    /// Returns the subscribed event names, sorted.
+    #[inline]
+    #[must_use]
+    pub fn event_filters(&self) -> &[String] {
+        &self.event_filters
+    }
+
+    /// Returns true if the subscription receives deliveries.
+    #[inline]
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Returns the number of failed deliveries since the last success.
+    #[inline]
+    #[must_use]
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    /// Returns when the subscription was created.
+    #[inline]
+    #[must_use]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    /// Returns when the subscription was last modified.
+    #[inline]
+    #[must_use]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    /// Returns true if an event named `event_name` should be delivered.
+    #[must_use]
+    pub fn matches(&self, event_name: &str) -> bool {
+        self.active && self.event_filters.iter().any(|filter| filter == event_name)
+    }
+
+    /// Changes the endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if the URL is not HTTP(S).
+    pub fn set_url(&mut self, url: impl Into<String>) -> DomainResult<()> {
+        let url = url.into();
+        validate_url(&url)?;
+        self.url = url;
+        self.touch();
+        Ok(())
+    }
+
+    /// Rotates the signing secret.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if the secret is empty.
+    pub fn set_secret(&mut self, secret: impl Into<String>) -> DomainResult<()> {
+        let secret = secret.into();
+        validate_secret(&secret)?;
+        self.secret = secret;
+        self.touch();
+        Ok(())
+    }
+
+    /// Replaces the subscribed events.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if the filters are empty or
+    /// name an event outside [`WEBHOOK_EVENTS`].
+    pub fn set_event_filters(&mut self, event_filters: Vec<String>) -> DomainResult<()> {
+        self.event_filters = normalize_filters(event_filters)?;
+        self.touch();
+        Ok(())
+    }
+
+    /// Activates or deactivates the subscription.
+    ///
+    /// Activating clears the failure count, so a re-enabled endpoint gets
+    /// the full failure allowance again.
+    pub fn set_active(&mut self, active: bool) {
+        if active && !self.active {
+            self.consecutive_failures = 0;
+        }
+        self.active = active;
+        self.touch();
+    }
+
+    /// Records a successful delivery, clearing the failure count.
+    pub fn record_success(&mut self) {
+        if self.consecutive_failures > 0 {
+            self.consecutive_failures = 0;
+            self.touch();
+        }
+    }
+
+    /// Records a failed delivery.
+    ///
+    /// Returns true if this failure reached [`AUTO_DISABLE_THRESHOLD`] and
+    /// deactivated the subscription.
+    pub fn record_failure(&mut self) -> bool {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        self.touch();
+        if self.active && self.consecutive_failures >= AUTO_DISABLE_THRESHOLD {
+            self.active = false;
+            return true;
+        }
+        false
+    }
+
+    fn touch(&mut self) {
+        self.updated_at = Timestamp::now();
+    }
+}
+
+impl fmt::Debug for WebhookSubscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookSubscription")
+            .field("id", &self.id)
+            .field("url", &self.url)
+            .field("secret", &"<redacted>")
+            .field("event_filters", &self.event_filters)
+            .field("active", &self.active)
+            .field("consecutive_failures", &self.consecutive_failures)
+            .field("created_at", &self.created_at)
+            .field("updated_at", &self.updated_at)
+            .finish()
+    }
+}
+
+fn validate_url(url: &str) -> DomainResult<()> {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(DomainError::ValidationError(
+            "webhook url must start with http:// or https://".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_secret(secret: &str) -> DomainResult<()> {
+    if secret.trim().is_empty() {
+        return Err(DomainError::ValidationError(
+            "webhook secret cannot be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn normalize_filters(mut event_filters: Vec<String>) -> DomainResult<Vec<String>> {
+    if event_filters.is_empty() {
+        return Err(DomainError::ValidationError(
+            "webhook must subscribe to at least one event".to_string(),
+        ));
+    }
+    if let Some(unknown) = event_filters
+        .iter()
+        .find(|filter| !WEBHOOK_EVENTS.contains(&filter.as_str()))
+    {
+        return Err(DomainError::ValidationError(format!(
+            "unknown webhook event: {unknown}; expected one of {}",
+            WEBHOOK_EVENTS.join(", ")
+        )));
+    }
+    event_filters.sort();
+    event_filters.dedup();
+    Ok(event_filters)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn subscription(events: &[&str]) -> WebhookSubscription {
+        WebhookSubscription::new(
+            "https://risk.example.com/hooks",
+            "secret",
+            events.iter().map(|event| (*event).to_string()).collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn matches_only_subscribed_events_while_active() {
+        let mut sub = subscription(&["SettlementConfirmed"]);
+        assert!(sub.matches("SettlementConfirmed"));
+        assert!(!sub.matches("TradeExecuted"));
+        assert!(!sub.matches("SettlementFailed"));
+
+        sub.set_active(false);
+        assert!(!sub.matches("SettlementConfirmed"));
+    }
+
+    #[test]
+    fn filters_are_validated_and_deduplicated() {
+        let sub = subscription(&["TradeExecuted", "SettlementConfirmed", "TradeExecuted"]);
+        assert_eq!(
+            sub.event_filters(),
+            ["SettlementConfirmed", "TradeExecuted"]
+        );
+
+        let unknown = WebhookSubscription::new(
+            "https://risk.example.com",
+            "secret",
+            vec!["RfqCreated".to_string()],
+        );
+        assert!(matches!(unknown, Err(DomainError::ValidationError(_))));
+        let empty = WebhookSubscription::new("https://risk.example.com", "secret", vec![]);
+        assert!(matches!(empty, Err(DomainError::ValidationError(_))));
+    }
+
+    #[test]
+    fn rejects_non_http_urls_and_empty_secrets() {
+        let events = vec!["TradeExecuted".to_string()];
+        assert!(WebhookSubscription::new("ftp://risk", "secret", events.clone()).is_err());
+        assert!(WebhookSubscription::new("https://risk", "  ", events).is_err());
+    }
+
+    #[test]
+    fn auto_disables_at_failure_threshold() {
+        let mut sub = subscription(&["TradeExecuted"]);
+        for _ in 1..AUTO_DISABLE_THRESHOLD {
+            assert!(!sub.record_failure());
+        }
+        assert!(sub.is_active());
+
+        assert!(sub.record_failure());
+        assert!(!sub.is_active());
+        assert!(!sub.record_failure());
+
+        sub.set_active(true);
+        assert_eq!(sub.consecutive_failures(), 0);
+    }
+
+    #[test]
+    fn success_resets_failure_count() {
+        let mut sub = subscription(&["TradeExecuted"]);
+        sub.record_failure();
+        sub.record_failure();
+        sub.record_success();
+        assert_eq!(sub.consecutive_failures(), 0);
+    }
+
+    #[test]
+    fn debug_redacts_secret() {
+        let sub = subscription(&["TradeExecuted"]);
+        let debug = format!("{sub:?}");
+        assert!(!debug.contains("\"secret\""));
+        assert!(debug.contains("<redacted>"));
+    }
+}
@@ -37,6 +37,10 @@ pub enum DomainError {
         /// Minimum quantity required.
         minimum: crate::domain::value_objects::Quantity,
     },
+    /// Minimum fill quantity is not positive or exceeds the requested quantity.
+    InvalidMinQuantity(String),
+    /// The RFQ's venue allowlist and blocklist leave no venue to query.
+    NoEligibleVenues(String),
     /// Allocation mismatch.
     AllocationMismatch {
         /// Allocated quantity.
@@ -59,6 +63,24 @@ pub enum DomainError {
         /// Maximum tolerance percentage.
         max_tolerance_pct: rust_decimal::Decimal,
     },
+    /// Quantity is not a multiple of the instrument's lot size.
+    InvalidLotSize {
+        /// Offending quantity.
+        quantity: crate::domain::value_objects::Quantity,
+        /// Lot size of the instrument.
+        lot_size: rust_decimal::Decimal,
+        /// Nearest valid quantity.
+        nearest_valid: crate::domain::value_objects::Quantity,
+    },
+    /// Price is not a multiple of the instrument's tick size.
+    InvalidTickSize {
+        /// Offending price.
+        price: crate::domain::value_objects::Price,
+        /// Tick size of the instrument.
+        tick_size: rust_decimal::Decimal,
+        /// Nearest valid price.
+        nearest_valid: crate::domain::value_objects::Price,
+    },
 
     // State errors (2000-2999)
     /// Invalid state transition for RFQ.
@@ -94,6 +116,8 @@ pub enum DomainError {
     LockAcquisitionFailed(String),
     /// Conflict detected during concurrent operation.
     ConflictDetected(String),
+    /// Another execution of the same RFQ holds the execution lock.
+    ExecutionInProgress(String),
 
     // Risk and compliance errors (3000-3999)
     /// Risk check failed.
@@ -129,10 +153,32 @@ pub enum DomainError {
     LastLookTimeout(String),
     /// Acceptance flow timed out.
     AcceptanceTimeout(String),
+    /// Firm-up moved the price of an indicative quote beyond tolerance.
+    FirmUpPriceMoved {
+        /// Indicative price the client selected.
+        indicative: crate::domain::value_objects::Price,
+        /// Firm price returned by the venue.
+        firm: crate::domain::value_objects::Price,
+        /// Actual deviation percentage.
+        deviation_pct: rust_decimal::Decimal,
+        /// Maximum tolerance percentage.
+        max_tolerance_pct: rust_decimal::Decimal,
+    },
+    /// Too few competing quotes for the trade's notional band.
+    QuorumNotMet(crate::domain::value_objects::QuorumShortfall),
 
     // Off-book execution errors
     /// Collateral lock failed.
     CollateralLockFailed(String),
+    /// The client holds too little collateral for the trade.
+    InsufficientCollateral {
+        /// Collateral the trade requires.
+        required: rust_decimal::Decimal,
+        /// Collateral available to the client.
+        available: rust_decimal::Decimal,
+    },
+    /// The collateral check could not be completed.
+    CollateralUnavailable(String),
     /// Settlement failed.
     SettlementFailed(String),
     /// Position update failed.
@@ -281,6 +327,8 @@ impl fmt::Display for DomainError {
                     filled, minimum
                 )
             }
+            Self::InvalidMinQuantity(msg) => write!(f, "invalid min quantity: {}", msg),
+            Self::NoEligibleVenues(msg) => write!(f, "no eligible venues: {}", msg),
             Self::AllocationMismatch { allocated, target } => {
                 write!(
                     f,
@@ -302,6 +350,28 @@ impl fmt::Display for DomainError {
                     proposed, reference, deviation_pct, max_tolerance_pct
                 )
             }
+            Self::InvalidLotSize {
+                quantity,
+                lot_size,
+                nearest_valid,
+            } => {
+                write!(
+                    f,
+                    "invalid lot size: quantity {} is not a multiple of {}, nearest valid {}",
+                    quantity, lot_size, nearest_valid
+                )
+            }
+            Self::InvalidTickSize {
+                price,
+                tick_size,
+                nearest_valid,
+            } => {
+                write!(
+                    f,
+                    "invalid tick size: price {} is not a multiple of {}, nearest valid {}",
+                    price, tick_size, nearest_valid
+                )
+            }
             Self::InvalidStateTransition { from, to } => {
                 write!(f, "invalid state transition from {} to {}", from, to)
             }
@@ -320,6 +390,7 @@ impl fmt::Display for DomainError {
             Self::QuoteLocked(msg) => write!(f, "quote locked: {}", msg),
             Self::LockAcquisitionFailed(msg) => write!(f, "lock acquisition failed: {}", msg),
             Self::ConflictDetected(msg) => write!(f, "conflict detected: {}", msg),
+            Self::ExecutionInProgress(msg) => write!(f, "execution in progress: {}", msg),
             Self::RiskCheckFailed(msg) => write!(f, "risk check failed: {}", msg),
             Self::UnauthorizedCounterparty(msg) => write!(f, "unauthorized counterparty: {}", msg),
             Self::ValidationFailed(msg) => write!(f, "validation failed: {}", msg),
@@ -343,7 +414,29 @@ impl fmt::Display for DomainError {
             Self::LastLookRejected(msg) => write!(f, "last-look rejected: {}", msg),
             Self::LastLookTimeout(msg) => write!(f, "last-look timeout: {}", msg),
             Self::AcceptanceTimeout(msg) => write!(f, "acceptance timeout: {}", msg),
+            Self::FirmUpPriceMoved {
+                indicative,
+                firm,
+                deviation_pct,
+                max_tolerance_pct,
+            } => {
+                write!(
+                    f,
+                    "firm-up price moved: indicative {}, firm {}, deviation {}%, max tolerance {}%",
+                    indicative, firm, deviation_pct, max_tolerance_pct
+                )
+            }
+            Self::QuorumNotMet(shortfall) => write!(f, "quote quorum not met: {}", shortfall),
             Self::CollateralLockFailed(msg) => write!(f, "collateral lock failed: {}", msg),
+            Self::InsufficientCollateral {
+                required,
+                available,
+            } => write!(
+                f,
+                "insufficient collateral: required {}, available {}",
+                required, available
+            ),
+            Self::CollateralUnavailable(msg) => write!(f, "collateral check unavailable: {}", msg),
             Self::SettlementFailed(msg) => write!(f, "settlement failed: {}", msg),
             Self::PositionUpdateFailed(msg) => write!(f, "position update failed: {}", msg),
             Self::PriceBoundsVerificationFailed(msg) => {
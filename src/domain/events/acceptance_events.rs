@@ -7,9 +7,10 @@
 
 use crate::domain::events::domain_event::{DomainEvent, EventMetadata, EventType};
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{EventId, QuoteId, RfqId, VenueId};
+use crate::domain::value_objects::{CorrelationId, EventId, QuoteId, RfqId, VenueId};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
+use uuid::Uuid;
 
 /// Event emitted when a quote is locked for acceptance.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -44,6 +45,18 @@ impl DomainEvent for QuoteLocked {
     fn rfq_id(&self) -> Option<RfqId> {
         self.metadata.rfq_id
     }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -82,6 +95,18 @@ impl DomainEvent for RiskCheckPassed {
     fn rfq_id(&self) -> Option<RfqId> {
         self.metadata.rfq_id
     }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -123,6 +148,18 @@ impl DomainEvent for RiskCheckFailed {
     fn rfq_id(&self) -> Option<RfqId> {
         self.metadata.rfq_id
     }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -173,6 +210,18 @@ impl DomainEvent for LastLookSent {
     fn rfq_id(&self) -> Option<RfqId> {
         self.metadata.rfq_id
     }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -222,6 +271,18 @@ impl DomainEvent for LastLookConfirmed {
     fn rfq_id(&self) -> Option<RfqId> {
         self.metadata.rfq_id
     }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -271,6 +332,18 @@ impl DomainEvent for LastLookRejected {
     fn rfq_id(&self) -> Option<RfqId> {
         self.metadata.rfq_id
     }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -315,6 +388,18 @@ impl DomainEvent for LastLookTimeout {
     fn rfq_id(&self) -> Option<RfqId> {
         self.metadata.rfq_id
     }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -356,6 +441,18 @@ impl DomainEvent for AcceptanceCompleted {
     fn rfq_id(&self) -> Option<RfqId> {
         self.metadata.rfq_id
     }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -405,6 +502,18 @@ impl DomainEvent for AcceptanceFailed {
     fn rfq_id(&self) -> Option<RfqId> {
         self.metadata.rfq_id
     }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -469,6 +578,48 @@ impl DomainEvent for AcceptanceEvent {
         }
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        match self {
+            Self::QuoteLocked(e) => e.correlation_id(),
+            Self::RiskCheckPassed(e) => e.correlation_id(),
+            Self::RiskCheckFailed(e) => e.correlation_id(),
+            Self::LastLookSent(e) => e.correlation_id(),
+            Self::LastLookConfirmed(e) => e.correlation_id(),
+            Self::LastLookRejected(e) => e.correlation_id(),
+            Self::LastLookTimeout(e) => e.correlation_id(),
+            Self::AcceptanceCompleted(e) => e.correlation_id(),
+            Self::AcceptanceFailed(e) => e.correlation_id(),
+        }
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        match self {
+            Self::QuoteLocked(e) => e.aggregate_id(),
+            Self::RiskCheckPassed(e) => e.aggregate_id(),
+            Self::RiskCheckFailed(e) => e.aggregate_id(),
+            Self::LastLookSent(e) => e.aggregate_id(),
+            Self::LastLookConfirmed(e) => e.aggregate_id(),
+            Self::LastLookRejected(e) => e.aggregate_id(),
+            Self::LastLookTimeout(e) => e.aggregate_id(),
+            Self::AcceptanceCompleted(e) => e.aggregate_id(),
+            Self::AcceptanceFailed(e) => e.aggregate_id(),
+        }
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        match self {
+            Self::QuoteLocked(e) => e.aggregate_version(),
+            Self::RiskCheckPassed(e) => e.aggregate_version(),
+            Self::RiskCheckFailed(e) => e.aggregate_version(),
+            Self::LastLookSent(e) => e.aggregate_version(),
+            Self::LastLookConfirmed(e) => e.aggregate_version(),
+            Self::LastLookRejected(e) => e.aggregate_version(),
+            Self::LastLookTimeout(e) => e.aggregate_version(),
+            Self::AcceptanceCompleted(e) => e.aggregate_version(),
+            Self::AcceptanceFailed(e) => e.aggregate_version(),
+        }
+    }
+
     fn timestamp(&self) -> Timestamp {
         match self {
             Self::QuoteLocked(e) => e.timestamp(),
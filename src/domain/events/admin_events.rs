@@ -0,0 +1,148 @@
+//! # Admin Events
+//!
+//! Domain events for administrative overrides of the normal lifecycle.
+//!
+//! - [`ForcedStateTransition`]: An administrator forced a stuck RFQ or
+//!   trade settlement into a new state
+
+use crate::domain::entities::trade::SettlementState;
+use crate::domain::events::domain_event::{DomainEvent, EventMetadata, EventType};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{CorrelationId, EventId, RfqId, RfqState, TradeId};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Event emitted when an administrator forces a stuck aggregate into a new
+/// state, bypassing the normal transition rules.
+///
+/// This is the audit record of the override. Forced transitions should be
+/// rare; consumers should raise an alert.
+///
+/// The event is not stamped with an aggregate version: the aggregate may be
+/// stuck precisely because its event history is incomplete.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForcedStateTransition {
+    /// Event metadata.
+    pub metadata: EventMetadata,
+    /// The forced trade, or `None` when the RFQ itself was forced.
+    pub trade_id: Option<TradeId>,
+    /// Who forced the transition.
+    pub actor: String,
+    /// Why the transition was forced.
+    pub justification: String,
+    /// State before the transition.
+    pub from: String,
+    /// State after the transition.
+    pub to: String,
+}
+
+impl ForcedStateTransition {
+    /// Creates a ForcedStateTransition event for an RFQ.
+    #[must_use]
+    pub fn rfq(
+        rfq_id: RfqId,
+        from: RfqState,
+        to: RfqState,
+        actor: impl Into<String>,
+        justification: impl Into<String>,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::for_rfq(rfq_id),
+            trade_id: None,
+            actor: actor.into(),
+            justification: justification.into(),
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    /// Creates a ForcedStateTransition event for a trade's settlement.
+    #[must_use]
+    pub fn trade(
+        rfq_id: RfqId,
+        trade_id: TradeId,
+        from: SettlementState,
+        to: SettlementState,
+        actor: impl Into<String>,
+        justification: impl Into<String>,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::for_rfq(rfq_id),
+            trade_id: Some(trade_id),
+            actor: actor.into(),
+            justification: justification.into(),
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+}
+
+impl DomainEvent for ForcedStateTransition {
+    fn event_id(&self) -> EventId {
+        self.metadata.event_id
+    }
+
+    fn rfq_id(&self) -> Option<RfqId> {
+        self.metadata.rfq_id
+    }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.metadata.timestamp
+    }
+
+    fn event_type(&self) -> EventType {
+        if self.trade_id.is_some() {
+            EventType::Settlement
+        } else {
+            EventType::Rfq
+        }
+    }
+
+    fn event_name(&self) -> &'static str {
+        "ForcedStateTransition"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trade_transitions_are_settlement_events() {
+        let rfq_id = RfqId::new_v4();
+        let rfq = ForcedStateTransition::rfq(
+            rfq_id,
+            RfqState::Executing,
+            RfqState::Executed,
+            "ops-1",
+            "venue confirmed by phone",
+        );
+        let trade = ForcedStateTransition::trade(
+            rfq_id,
+            TradeId::new_v4(),
+            SettlementState::InProgress,
+            SettlementState::Settled,
+            "ops-1",
+            "tx confirmed on explorer",
+        );
+
+        assert_eq!(rfq.event_type(), EventType::Rfq);
+        assert_eq!(rfq.from, "EXECUTING");
+        assert_eq!(rfq.to, "EXECUTED");
+        assert_eq!(trade.event_type(), EventType::Settlement);
+        assert_eq!(trade.rfq_id(), Some(rfq_id));
+        assert_eq!(trade.aggregate_version(), 0);
+    }
+}
@@ -16,8 +16,9 @@ use crate::domain::entities::allocation::Allocation;
 use crate::domain::events::domain_event::{DomainEvent, EventMetadata, EventType};
 use crate::domain::value_objects::size_negotiation_mode::SizeNegotiationMode;
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{EventId, Quantity, QuoteId, RfqId, VenueId};
+use crate::domain::value_objects::{CorrelationId, EventId, Quantity, QuoteId, RfqId, VenueId};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// Event emitted when a multi-MM fill allocation plan is created.
 ///
@@ -61,6 +62,18 @@ impl DomainEvent for MultiMmFillAllocated {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -114,6 +127,18 @@ impl DomainEvent for AllocationExecuted {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -167,6 +192,18 @@ impl DomainEvent for AllocationRolledBack {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -22,9 +22,10 @@ use crate::domain::entities::anonymity::{AnonymityLevel, AnonymousRfqView};
 use crate::domain::events::domain_event::{DomainEvent, EventMetadata, EventType};
 use crate::domain::value_objects::timestamp::Timestamp;
 use crate::domain::value_objects::{
-    CounterpartyId, EventId, Instrument, OrderSide, Quantity, RfqId, VenueId,
+    CorrelationId, CounterpartyId, EventId, Instrument, OrderSide, Quantity, RfqId, VenueId,
 };
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// Event emitted when an anonymous RFQ is broadcast to market makers.
 ///
@@ -96,6 +97,18 @@ impl DomainEvent for AnonymousRfqBroadcast {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -198,6 +211,18 @@ impl DomainEvent for IdentityRevealed {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
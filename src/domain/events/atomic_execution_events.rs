@@ -8,8 +8,9 @@
 use crate::domain::events::domain_event::{DomainEvent, EventMetadata, EventType};
 use crate::domain::services::quote_lock::LockHolderId;
 use crate::domain::services::resource_lock::ResourceLock;
-use crate::domain::value_objects::{EventId, QuoteId, RfqId, Timestamp, TradeId};
+use crate::domain::value_objects::{CorrelationId, EventId, QuoteId, RfqId, Timestamp, TradeId};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// Event emitted when locks are successfully acquired.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -60,6 +61,18 @@ impl DomainEvent for LocksAcquired {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     #[inline]
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
@@ -111,6 +124,18 @@ impl DomainEvent for ExecutionCommitted {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     #[inline]
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
@@ -174,6 +199,18 @@ impl DomainEvent for ExecutionRolledBack {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     #[inline]
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
@@ -230,6 +267,18 @@ impl DomainEvent for LockAcquisitionFailed {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     #[inline]
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
@@ -303,6 +352,33 @@ impl DomainEvent for AtomicExecutionEvent {
         }
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        match self {
+            Self::LocksAcquired(e) => e.correlation_id(),
+            Self::ExecutionCommitted(e) => e.correlation_id(),
+            Self::ExecutionRolledBack(e) => e.correlation_id(),
+            Self::LockAcquisitionFailed(e) => e.correlation_id(),
+        }
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        match self {
+            Self::LocksAcquired(e) => e.aggregate_id(),
+            Self::ExecutionCommitted(e) => e.aggregate_id(),
+            Self::ExecutionRolledBack(e) => e.aggregate_id(),
+            Self::LockAcquisitionFailed(e) => e.aggregate_id(),
+        }
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        match self {
+            Self::LocksAcquired(e) => e.aggregate_version(),
+            Self::ExecutionCommitted(e) => e.aggregate_version(),
+            Self::ExecutionRolledBack(e) => e.aggregate_version(),
+            Self::LockAcquisitionFailed(e) => e.aggregate_version(),
+        }
+    }
+
     #[inline]
     fn timestamp(&self) -> Timestamp {
         match self {
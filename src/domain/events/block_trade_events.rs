@@ -9,9 +9,12 @@ use crate::domain::entities::block_trade::{BlockTradeId, BlockTradeState, BlockT
 use crate::domain::events::domain_event::{DomainEvent, EventMetadata, EventType};
 use crate::domain::services::ReportingTier;
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{CounterpartyId, EventId, Instrument, Price, Quantity, RfqId};
+use crate::domain::value_objects::{
+    CorrelationId, CounterpartyId, EventId, Instrument, Price, Quantity, RfqId,
+};
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use uuid::Uuid;
 
 /// Event emitted when a block trade is submitted.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -68,6 +71,18 @@ impl DomainEvent for BlockTradeSubmitted {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -124,6 +139,18 @@ impl DomainEvent for BlockTradeValidated {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -198,6 +225,18 @@ impl DomainEvent for BlockTradeConfirmed {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -243,6 +282,18 @@ impl DomainEvent for BlockTradeApproved {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -295,6 +346,18 @@ impl DomainEvent for BlockTradeRejected {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -351,6 +414,18 @@ impl DomainEvent for BlockTradeExecuted {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -396,6 +471,18 @@ impl DomainEvent for BlockTradeFailed {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -7,6 +7,7 @@
 
 use crate::domain::entities::mm_capacity::{CapacityAdjustment, CapacityCheckResult};
 use crate::domain::events::domain_event::{DomainEvent, EventMetadata, EventType};
+use crate::domain::value_objects::CorrelationId;
 use crate::domain::value_objects::CounterpartyId;
 use crate::domain::value_objects::EventId;
 use crate::domain::value_objects::ids::RfqId;
@@ -15,6 +16,7 @@ use crate::domain::value_objects::timestamp::Timestamp;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use uuid::Uuid;
 
 /// Event emitted when capacity is reserved for an RFQ.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -87,6 +89,18 @@ impl DomainEvent for CapacityReserved {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -181,6 +195,18 @@ impl DomainEvent for CapacityReleased {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -250,6 +276,18 @@ impl DomainEvent for CapacityAdjusted {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -329,6 +367,18 @@ impl DomainEvent for MmExcludedForCapacity {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -398,6 +448,33 @@ impl DomainEvent for CapacityEvent {
         }
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        match self {
+            Self::Reserved(e) => e.correlation_id(),
+            Self::Released(e) => e.correlation_id(),
+            Self::Adjusted(e) => e.correlation_id(),
+            Self::MmExcluded(e) => e.correlation_id(),
+        }
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        match self {
+            Self::Reserved(e) => e.aggregate_id(),
+            Self::Released(e) => e.aggregate_id(),
+            Self::Adjusted(e) => e.aggregate_id(),
+            Self::MmExcluded(e) => e.aggregate_id(),
+        }
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        match self {
+            Self::Reserved(e) => e.aggregate_version(),
+            Self::Released(e) => e.aggregate_version(),
+            Self::Adjusted(e) => e.aggregate_version(),
+            Self::MmExcluded(e) => e.aggregate_version(),
+        }
+    }
+
     fn timestamp(&self) -> Timestamp {
         match self {
             Self::Reserved(e) => e.timestamp(),
@@ -3,12 +3,13 @@
 //! Domain events for compliance checks.
 //!
 //! This module provides events that track compliance verification
-//! during the RFQ lifecycle.
+//! during the RFQ lifecycle, and the regulator exports built from them.
 
 use crate::domain::events::domain_event::{DomainEvent, EventMetadata, EventType};
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{CounterpartyId, EventId, RfqId};
+use crate::domain::value_objects::{CorrelationId, CounterpartyId, EventId, RfqId};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// Type of compliance check performed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -24,6 +25,8 @@ pub enum ComplianceCheckType {
     TradingLimits,
     /// Instrument eligibility check.
     InstrumentEligibility,
+    /// Margin check before executing a derivatives trade.
+    Collateral,
     /// General compliance check.
     General,
 }
@@ -36,6 +39,7 @@ impl std::fmt::Display for ComplianceCheckType {
             Self::Sanctions => write!(f, "SANCTIONS"),
             Self::TradingLimits => write!(f, "TRADING_LIMITS"),
             Self::InstrumentEligibility => write!(f, "INSTRUMENT_ELIGIBILITY"),
+            Self::Collateral => write!(f, "COLLATERAL"),
             Self::General => write!(f, "GENERAL"),
         }
     }
@@ -115,6 +119,18 @@ impl DomainEvent for ComplianceCheckPassed {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -228,6 +244,18 @@ impl DomainEvent for ComplianceCheckFailed {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -241,6 +269,85 @@ impl DomainEvent for ComplianceCheckFailed {
     }
 }
 
+/// Event emitted when a regulator export bundle is generated.
+///
+/// Records who pulled a counterparty's compliance records and which
+/// bundle they received, so the export itself is part of the audit trail.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplianceExportGenerated {
+    /// Event metadata.
+    pub metadata: EventMetadata,
+    /// The counterparty whose records were exported.
+    pub counterparty_id: CounterpartyId,
+    /// Who requested the export.
+    pub requested_by: String,
+    /// Start of the exported period (inclusive).
+    pub from: Timestamp,
+    /// End of the exported period (exclusive).
+    pub to: Timestamp,
+    /// Number of data files in the bundle, excluding the manifest.
+    pub file_count: usize,
+    /// SHA-256 of the bundle, hex encoded.
+    pub bundle_sha256: String,
+}
+
+impl ComplianceExportGenerated {
+    /// Creates a new ComplianceExportGenerated event.
+    #[must_use]
+    pub fn new(
+        counterparty_id: CounterpartyId,
+        requested_by: impl Into<String>,
+        from: Timestamp,
+        to: Timestamp,
+        file_count: usize,
+        bundle_sha256: impl Into<String>,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::new(None),
+            counterparty_id,
+            requested_by: requested_by.into(),
+            from,
+            to,
+            file_count,
+            bundle_sha256: bundle_sha256.into(),
+        }
+    }
+}
+
+impl DomainEvent for ComplianceExportGenerated {
+    fn event_id(&self) -> EventId {
+        self.metadata.event_id
+    }
+
+    fn rfq_id(&self) -> Option<RfqId> {
+        self.metadata.rfq_id
+    }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.metadata.timestamp
+    }
+
+    fn event_type(&self) -> EventType {
+        EventType::Compliance
+    }
+
+    fn event_name(&self) -> &'static str {
+        "ComplianceExportGenerated"
+    }
+}
+
 /// Enum containing all compliance events.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -249,6 +356,8 @@ pub enum ComplianceEvent {
     Passed(ComplianceCheckPassed),
     /// Compliance check failed.
     Failed(ComplianceCheckFailed),
+    /// Regulator export bundle generated.
+    ExportGenerated(ComplianceExportGenerated),
 }
 
 impl DomainEvent for ComplianceEvent {
@@ -256,6 +365,7 @@ impl DomainEvent for ComplianceEvent {
         match self {
             Self::Passed(e) => e.event_id(),
             Self::Failed(e) => e.event_id(),
+            Self::ExportGenerated(e) => e.event_id(),
         }
     }
 
@@ -263,6 +373,31 @@ impl DomainEvent for ComplianceEvent {
         match self {
             Self::Passed(e) => e.rfq_id(),
             Self::Failed(e) => e.rfq_id(),
+            Self::ExportGenerated(e) => e.rfq_id(),
+        }
+    }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        match self {
+            Self::Passed(e) => e.correlation_id(),
+            Self::Failed(e) => e.correlation_id(),
+            Self::ExportGenerated(e) => e.correlation_id(),
+        }
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        match self {
+            Self::Passed(e) => e.aggregate_id(),
+            Self::Failed(e) => e.aggregate_id(),
+            Self::ExportGenerated(e) => e.aggregate_id(),
+        }
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        match self {
+            Self::Passed(e) => e.aggregate_version(),
+            Self::Failed(e) => e.aggregate_version(),
+            Self::ExportGenerated(e) => e.aggregate_version(),
         }
     }
 
@@ -270,6 +405,7 @@ impl DomainEvent for ComplianceEvent {
         match self {
             Self::Passed(e) => e.timestamp(),
             Self::Failed(e) => e.timestamp(),
+            Self::ExportGenerated(e) => e.timestamp(),
         }
     }
 
@@ -277,6 +413,7 @@ impl DomainEvent for ComplianceEvent {
         match self {
             Self::Passed(e) => e.event_type(),
             Self::Failed(e) => e.event_type(),
+            Self::ExportGenerated(e) => e.event_type(),
         }
     }
 
@@ -284,6 +421,7 @@ impl DomainEvent for ComplianceEvent {
         match self {
             Self::Passed(e) => e.event_name(),
             Self::Failed(e) => e.event_name(),
+            Self::ExportGenerated(e) => e.event_name(),
         }
     }
 }
@@ -313,6 +451,7 @@ mod tests {
                 ComplianceCheckType::TradingLimits.to_string(),
                 "TRADING_LIMITS"
             );
+            assert_eq!(ComplianceCheckType::Collateral.to_string(), "COLLATERAL");
         }
     }
 
@@ -441,6 +580,34 @@ mod tests {
         }
     }
 
+    mod compliance_export_generated {
+        use super::*;
+
+        #[test]
+        fn records_requester_without_rfq() {
+            let from = Timestamp::from_millis(1_700_000_000_000).unwrap();
+            let event = ComplianceExportGenerated::new(
+                test_counterparty_id(),
+                "auditor-1",
+                from,
+                from.add_secs(86_400),
+                4,
+                "ab".repeat(32),
+            );
+
+            assert_eq!(event.rfq_id(), None);
+            assert_eq!(event.correlation_id(), None);
+            assert_eq!(event.requested_by, "auditor-1");
+            assert_eq!(event.event_type(), EventType::Compliance);
+
+            let wrapped = ComplianceEvent::ExportGenerated(event);
+            let json = serde_json::to_string(&wrapped).unwrap();
+            let deserialized: ComplianceEvent = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, wrapped);
+            assert_eq!(deserialized.event_name(), "ComplianceExportGenerated");
+        }
+    }
+
     mod compliance_event_enum {
         use super::*;
 
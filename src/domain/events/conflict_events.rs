@@ -7,9 +7,10 @@
 
 use crate::domain::events::domain_event::{DomainEvent, EventMetadata, EventType};
 use crate::domain::services::quote_lock::LockHolderId;
-use crate::domain::value_objects::{EventId, Price, QuoteId, RfqId, Timestamp};
+use crate::domain::value_objects::{CorrelationId, EventId, Price, QuoteId, RfqId, Timestamp};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
+use uuid::Uuid;
 
 /// Types of conflicts that can occur during quote acceptance.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -160,6 +161,18 @@ impl DomainEvent for ConflictDetectedEvent {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -223,6 +236,18 @@ impl DomainEvent for ConflictResolvedEvent {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
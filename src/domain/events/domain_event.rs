@@ -17,9 +17,10 @@
 
 use crate::domain::schema::SchemaVersion;
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{EventId, RfqId};
+use crate::domain::value_objects::{CorrelationId, EventId, RfqId, TraceId};
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use uuid::Uuid;
 
 /// Type of domain event.
 ///
@@ -39,6 +40,8 @@ pub enum EventType {
     Compliance,
     /// Capacity management events.
     Capacity,
+    /// Outbound integration events, such as webhook delivery.
+    Integration,
 }
 
 impl fmt::Display for EventType {
@@ -50,6 +53,7 @@ impl fmt::Display for EventType {
             Self::Settlement => write!(f, "SETTLEMENT"),
             Self::Compliance => write!(f, "COMPLIANCE"),
             Self::Capacity => write!(f, "CAPACITY"),
+            Self::Integration => write!(f, "INTEGRATION"),
         }
     }
 }
@@ -64,6 +68,9 @@ impl fmt::Display for EventType {
 ///
 /// - [`event_id`](DomainEvent::event_id) - Unique identifier for this event
 /// - [`rfq_id`](DomainEvent::rfq_id) - The RFQ this event relates to (if any)
+/// - [`correlation_id`](DomainEvent::correlation_id) - The event chain this event belongs to (if any)
+/// - [`aggregate_id`](DomainEvent::aggregate_id) - The emitting aggregate (if versioned)
+/// - [`aggregate_version`](DomainEvent::aggregate_version) - The aggregate's version at emission
 /// - [`timestamp`](DomainEvent::timestamp) - When the event occurred
 /// - [`event_type`](DomainEvent::event_type) - Category of the event
 /// - [`event_name`](DomainEvent::event_name) - Human-readable event name
@@ -74,6 +81,17 @@ pub trait DomainEvent: Send + Sync + fmt::Debug {
     /// Returns the RFQ ID this event relates to, if any.
     fn rfq_id(&self) -> Option<RfqId>;
 
+    /// Returns the event chain this event belongs to, if any.
+    fn correlation_id(&self) -> Option<CorrelationId>;
+
+    /// Returns the ID of the versioned aggregate that emitted this event,
+    /// if any.
+    fn aggregate_id(&self) -> Option<Uuid>;
+
+    /// Returns the version of the emitting aggregate at emission time, or
+    /// 0 if the event is not versioned.
+    fn aggregate_version(&self) -> u64;
+
     /// Returns when this event occurred.
     fn timestamp(&self) -> Timestamp;
 
@@ -99,10 +117,29 @@ pub struct EventMetadata {
     /// Schema version for this event.
     #[serde(default)]
     pub schema_version: SchemaVersion,
+    /// Trace that produced this event, for correlating events with traces.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<TraceId>,
+    /// Event chain this event belongs to, for querying everything that
+    /// followed from one RFQ.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<CorrelationId>,
+    /// Aggregate (RFQ, trade or negotiation) that emitted this event, if
+    /// the event is versioned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aggregate_id: Option<Uuid>,
+    /// Version of the emitting aggregate at emission time. Versions of an
+    /// aggregate's events start at 1 and increase with every event, skipping
+    /// the versions of changes that emitted none; 0 means the event is not
+    /// versioned.
+    #[serde(default)]
+    pub aggregate_version: u64,
 }
 
 impl EventMetadata {
     /// Creates new event metadata with a generated event ID.
+    ///
+    /// Events of an RFQ are correlated with the chain the RFQ opened.
     #[must_use]
     pub fn new(rfq_id: Option<RfqId>) -> Self {
         Self {
@@ -110,6 +147,10 @@ impl EventMetadata {
             rfq_id,
             timestamp: Timestamp::now(),
             schema_version: SchemaVersion::V1_0_0,
+            trace_id: None,
+            correlation_id: rfq_id.map(CorrelationId::from),
+            aggregate_id: None,
+            aggregate_version: 0,
         }
     }
 
@@ -119,6 +160,37 @@ impl EventMetadata {
         Self::new(Some(rfq_id))
     }
 
+    /// Sets the trace that produced this event.
+    #[must_use]
+    pub fn with_trace_id(mut self, trace_id: Option<TraceId>) -> Self {
+        self.trace_id = trace_id;
+        self
+    }
+
+    /// Sets the event chain this event belongs to.
+    #[must_use]
+    pub fn with_correlation_id(mut self, correlation_id: Option<CorrelationId>) -> Self {
+        self.correlation_id = correlation_id;
+        self
+    }
+
+    /// Stamps the event with the version of the aggregate that emitted it.
+    #[must_use]
+    pub fn with_aggregate_version(mut self, aggregate_id: Uuid, version: u64) -> Self {
+        self.aggregate_id = Some(aggregate_id);
+        self.aggregate_version = version;
+        self
+    }
+
+    /// Returns the (aggregate ID, version) pair consumers de-duplicate on,
+    /// if the event is versioned.
+    #[must_use]
+    pub fn aggregate_key(&self) -> Option<(Uuid, u64)> {
+        self.aggregate_id
+            .filter(|_| self.aggregate_version > 0)
+            .map(|id| (id, self.aggregate_version))
+    }
+
     /// Creates event metadata with specific values (for reconstruction).
     #[must_use]
     pub fn from_parts(event_id: EventId, rfq_id: Option<RfqId>, timestamp: Timestamp) -> Self {
@@ -127,6 +199,10 @@ impl EventMetadata {
             rfq_id,
             timestamp,
             schema_version: SchemaVersion::V1_0_0,
+            trace_id: None,
+            correlation_id: rfq_id.map(CorrelationId::from),
+            aggregate_id: None,
+            aggregate_version: 0,
         }
     }
 }
@@ -162,6 +238,21 @@ mod tests {
         let rfq_id = RfqId::new_v4();
         let metadata = EventMetadata::for_rfq(rfq_id);
         assert_eq!(metadata.rfq_id, Some(rfq_id));
+        assert_eq!(metadata.correlation_id, Some(CorrelationId::from(rfq_id)));
+    }
+
+    #[test]
+    fn event_metadata_correlation_id_is_optional_on_the_wire() {
+        let metadata = EventMetadata::new(None);
+        assert!(metadata.correlation_id.is_none());
+        let json = serde_json::to_string(&metadata).unwrap();
+        assert!(!json.contains("correlation_id"));
+
+        let correlation_id = CorrelationId::new_v4();
+        let metadata = metadata.with_correlation_id(Some(correlation_id));
+        let json = serde_json::to_string(&metadata).unwrap();
+        let deserialized: EventMetadata = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.correlation_id, Some(correlation_id));
     }
 
     #[test]
@@ -172,4 +263,35 @@ mod tests {
         assert_eq!(metadata.event_id, deserialized.event_id);
         assert_eq!(metadata.rfq_id, deserialized.rfq_id);
     }
+
+    #[test]
+    fn event_metadata_trace_id_is_optional_on_the_wire() {
+        let metadata = EventMetadata::new(None);
+        let json = serde_json::to_string(&metadata).unwrap();
+        assert!(!json.contains("trace_id"));
+
+        let trace_id: TraceId = "0af7651916cd43dd8448eb211c80319c".parse().unwrap();
+        let metadata = metadata.with_trace_id(Some(trace_id));
+        let json = serde_json::to_string(&metadata).unwrap();
+        let deserialized: EventMetadata = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.trace_id, Some(trace_id));
+    }
+
+    #[test]
+    fn event_metadata_aggregate_version_defaults_to_unversioned() {
+        let metadata = EventMetadata::for_rfq(RfqId::new_v4());
+        assert_eq!(metadata.aggregate_key(), None);
+        let json = serde_json::to_value(metadata).unwrap();
+        let mut legacy = json.as_object().unwrap().clone();
+        legacy.remove("aggregate_version");
+        let deserialized: EventMetadata =
+            serde_json::from_value(serde_json::Value::Object(legacy)).unwrap();
+        assert_eq!(deserialized.aggregate_version, 0);
+
+        let aggregate_id = Uuid::new_v4();
+        let metadata = metadata.with_aggregate_version(aggregate_id, 3);
+        let json = serde_json::to_string(&metadata).unwrap();
+        let deserialized: EventMetadata = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.aggregate_key(), Some((aggregate_id, 3)));
+    }
 }
@@ -11,6 +11,7 @@
 //! - [`QuoteRequestFailed`]: Quote request failed
 //! - [`QuoteCollectionCompleted`]: Quote collection finished
 //! - [`QuoteSelected`]: Client selected a quote
+//! - [`QuorumOverridden`]: Client selected a quote despite an unmet quorum
 //! - [`ExecutionStarted`]: Trade execution begins
 //! - [`ExecutionFailed`]: Trade execution failed
 //! - [`RfqCancelled`]: RFQ was cancelled
@@ -22,6 +23,7 @@
 //! - [`SettlementInitiated`]: Settlement process started
 //! - [`SettlementConfirmed`]: Settlement completed successfully
 //! - [`SettlementFailed`]: Settlement failed
+//! - [`SettlementDeadLettered`]: Settlement retries exhausted
 //!
 //! ## Block Trade Events
 //!
@@ -49,8 +51,22 @@
 //!
 //! - [`ComplianceCheckPassed`]: Compliance check passed
 //! - [`ComplianceCheckFailed`]: Compliance check failed
+//! - [`ComplianceExportGenerated`]: Regulator export bundle generated
+//!
+//! ## Risk Events
+//!
+//! - [`PriceBoundsConfigChanged`]: Price bounds tolerances changed by an administrator
+//!
+//! ## Webhook Events
+//!
+//! - [`WebhookSubscriptionDisabled`]: Webhook deactivated after repeated failures
+//!
+//! ## Admin Events
+//!
+//! - [`ForcedStateTransition`]: Stuck RFQ or settlement forced into a new state by an administrator
 
 pub mod acceptance_events;
+pub mod admin_events;
 pub mod allocation_events;
 pub mod anonymity_events;
 pub mod atomic_execution_events;
@@ -65,12 +81,15 @@ pub mod off_book_events;
 pub mod price_discovery_events;
 pub mod reporting_events;
 pub mod rfq_events;
+pub mod risk_events;
 pub mod trade_events;
+pub mod webhook_events;
 
 pub use acceptance_events::{
     AcceptanceCompleted, AcceptanceEvent, AcceptanceFailed, LastLookConfirmed, LastLookRejected,
     LastLookSent, LastLookTimeout, QuoteLocked, RiskCheckFailed, RiskCheckPassed,
 };
+pub use admin_events::ForcedStateTransition;
 pub use allocation_events::{
     AllocationEvent, AllocationExecuted, AllocationRolledBack, MultiMmFillAllocated,
 };
@@ -87,6 +106,7 @@ pub use capacity_events::{
 };
 pub use compliance_events::{
     ComplianceCheckFailed, ComplianceCheckPassed, ComplianceCheckType, ComplianceEvent,
+    ComplianceExportGenerated,
 };
 pub use conflict_events::{ConflictDetectedEvent, ConflictEvent, ConflictResolvedEvent};
 pub use domain_event::{DomainEvent, EventMetadata, EventType};
@@ -97,7 +117,7 @@ pub use multi_leg_execution_events::{
 };
 pub use negotiation_events::{
     CounterQuoteReceived as NegotiationCounterQuoteReceived, CounterQuoteSent,
-    NegotiationCompleted, NegotiationEvent, NegotiationOutcome,
+    NegotiationCompleted, NegotiationConcluded, NegotiationEvent, NegotiationOutcome,
 };
 pub use off_book_events::{
     CollateralLocked, CollateralReleased, ExecutionStep, OffBookExecutionStarted, OffBookFailed,
@@ -109,13 +129,15 @@ pub use price_discovery_events::{
 };
 pub use reporting_events::{BlockTradeReported, ReportScheduled};
 pub use rfq_events::{
-    ExecutionFailed, ExecutionStarted, QuoteCollectionCompleted, QuoteCollectionStarted,
-    QuoteReceived, QuoteRequestFailed, QuoteRequested, QuoteSelected, RfqCancelled, RfqCreated,
-    RfqEvent, RfqExpired,
+    ExecutionFailed, ExecutionStarted, InternalCrossProposed, QuorumOverridden,
+    QuoteCollectionCompleted, QuoteCollectionStarted, QuoteReceived, QuoteRequestFailed,
+    QuoteRequested, QuoteSelected, RfqCancelled, RfqCreated, RfqEvent, RfqExpired,
 };
+pub use risk_events::PriceBoundsConfigChanged;
 pub use trade_events::{
-    PositionUpdated, SettlementConfirmed, SettlementFailed, SettlementInitiated, TradeEvent,
-    TradeExecuted,
+    AllocationFailed, AllocationFilled, PositionUpdated, SettlementConfirmed,
+    SettlementDeadLettered, SettlementFailed, SettlementInitiated, TradeEvent, TradeExecuted,
 };
+pub use webhook_events::WebhookSubscriptionDisabled;
 
 pub use anonymity_events::{AnonymousRfqBroadcast, IdentityRevealed};
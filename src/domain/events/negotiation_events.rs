@@ -10,15 +10,17 @@
 //! ```text
 //! CounterQuoteSent -> CounterQuoteReceived -> (repeat)
 //!                  -> NegotiationCompleted (accepted | rejected | expired)
+//!                  -> NegotiationConcluded (accepted terms executed as a trade)
 //! ```
 
 use crate::domain::events::domain_event::{DomainEvent, EventMetadata, EventType};
 use crate::domain::value_objects::negotiation_state::NegotiationState;
 use crate::domain::value_objects::timestamp::Timestamp;
 use crate::domain::value_objects::{
-    CounterpartyId, EventId, NegotiationId, Price, Quantity, QuoteId, RfqId,
+    CorrelationId, CounterpartyId, EventId, NegotiationId, Price, Quantity, QuoteId, RfqId, TradeId,
 };
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// Event emitted when a counter-quote is submitted.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -77,6 +79,18 @@ impl DomainEvent for CounterQuoteSent {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -138,6 +152,18 @@ impl DomainEvent for CounterQuoteReceived {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -221,6 +247,18 @@ impl DomainEvent for NegotiationCompleted {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -234,6 +272,89 @@ impl DomainEvent for NegotiationCompleted {
     }
 }
 
+/// Event emitted when an accepted negotiation's terms are executed as a
+/// bilateral trade.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NegotiationConcluded {
+    /// Event metadata.
+    pub metadata: EventMetadata,
+    /// The negotiation ID.
+    pub negotiation_id: NegotiationId,
+    /// The trade executing the agreed terms.
+    pub trade_id: TradeId,
+    /// The accepted counter-quote.
+    pub counter_quote_id: QuoteId,
+    /// The client side of the trade.
+    pub requester: CounterpartyId,
+    /// The market maker side of the trade.
+    pub mm_account: CounterpartyId,
+    /// The agreed price.
+    pub price: Price,
+    /// The agreed quantity.
+    pub quantity: Quantity,
+}
+
+impl NegotiationConcluded {
+    /// Creates a new NegotiationConcluded event.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        rfq_id: RfqId,
+        negotiation_id: NegotiationId,
+        trade_id: TradeId,
+        counter_quote_id: QuoteId,
+        requester: CounterpartyId,
+        mm_account: CounterpartyId,
+        price: Price,
+        quantity: Quantity,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::for_rfq(rfq_id),
+            negotiation_id,
+            trade_id,
+            counter_quote_id,
+            requester,
+            mm_account,
+            price,
+            quantity,
+        }
+    }
+}
+
+impl DomainEvent for NegotiationConcluded {
+    fn event_id(&self) -> EventId {
+        self.metadata.event_id
+    }
+
+    fn rfq_id(&self) -> Option<RfqId> {
+        self.metadata.rfq_id
+    }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.metadata.timestamp
+    }
+
+    fn event_type(&self) -> EventType {
+        EventType::Trade
+    }
+
+    fn event_name(&self) -> &'static str {
+        "NegotiationConcluded"
+    }
+}
+
 /// Enum containing all negotiation-related events.
 ///
 /// This enum allows for type-safe handling of all negotiation events.
@@ -246,6 +367,8 @@ pub enum NegotiationEvent {
     CounterQuoteReceived(CounterQuoteReceived),
     /// The negotiation was completed.
     NegotiationCompleted(NegotiationCompleted),
+    /// The accepted terms were executed as a trade.
+    NegotiationConcluded(NegotiationConcluded),
 }
 
 impl DomainEvent for NegotiationEvent {
@@ -254,6 +377,7 @@ impl DomainEvent for NegotiationEvent {
             Self::CounterQuoteSent(e) => e.event_id(),
             Self::CounterQuoteReceived(e) => e.event_id(),
             Self::NegotiationCompleted(e) => e.event_id(),
+            Self::NegotiationConcluded(e) => e.event_id(),
         }
     }
 
@@ -262,6 +386,34 @@ impl DomainEvent for NegotiationEvent {
             Self::CounterQuoteSent(e) => e.rfq_id(),
             Self::CounterQuoteReceived(e) => e.rfq_id(),
             Self::NegotiationCompleted(e) => e.rfq_id(),
+            Self::NegotiationConcluded(e) => e.rfq_id(),
+        }
+    }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        match self {
+            Self::CounterQuoteSent(e) => e.correlation_id(),
+            Self::CounterQuoteReceived(e) => e.correlation_id(),
+            Self::NegotiationCompleted(e) => e.correlation_id(),
+            Self::NegotiationConcluded(e) => e.correlation_id(),
+        }
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        match self {
+            Self::CounterQuoteSent(e) => e.aggregate_id(),
+            Self::CounterQuoteReceived(e) => e.aggregate_id(),
+            Self::NegotiationCompleted(e) => e.aggregate_id(),
+            Self::NegotiationConcluded(e) => e.aggregate_id(),
+        }
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        match self {
+            Self::CounterQuoteSent(e) => e.aggregate_version(),
+            Self::CounterQuoteReceived(e) => e.aggregate_version(),
+            Self::NegotiationCompleted(e) => e.aggregate_version(),
+            Self::NegotiationConcluded(e) => e.aggregate_version(),
         }
     }
 
@@ -270,6 +422,7 @@ impl DomainEvent for NegotiationEvent {
             Self::CounterQuoteSent(e) => e.timestamp(),
             Self::CounterQuoteReceived(e) => e.timestamp(),
             Self::NegotiationCompleted(e) => e.timestamp(),
+            Self::NegotiationConcluded(e) => e.timestamp(),
         }
     }
 
@@ -278,6 +431,7 @@ impl DomainEvent for NegotiationEvent {
             Self::CounterQuoteSent(e) => e.event_type(),
             Self::CounterQuoteReceived(e) => e.event_type(),
             Self::NegotiationCompleted(e) => e.event_type(),
+            Self::NegotiationConcluded(e) => e.event_type(),
         }
     }
 
@@ -286,6 +440,7 @@ impl DomainEvent for NegotiationEvent {
             Self::CounterQuoteSent(e) => e.event_name(),
             Self::CounterQuoteReceived(e) => e.event_name(),
             Self::NegotiationCompleted(e) => e.event_name(),
+            Self::NegotiationConcluded(e) => e.event_name(),
         }
     }
 }
@@ -438,6 +593,36 @@ mod tests {
         }
     }
 
+    mod negotiation_concluded {
+        use super::*;
+
+        #[test]
+        fn creates_event() {
+            let rfq_id = test_rfq_id();
+            let trade_id = TradeId::new_v4();
+            let event = NegotiationConcluded::new(
+                rfq_id,
+                test_negotiation_id(),
+                trade_id,
+                QuoteId::new_v4(),
+                CounterpartyId::new("client-1"),
+                CounterpartyId::new("mm-1"),
+                Price::new(49500.0).unwrap(),
+                Quantity::new(1.0).unwrap(),
+            );
+
+            assert_eq!(event.rfq_id(), Some(rfq_id));
+            assert_eq!(event.trade_id, trade_id);
+            assert_eq!(event.event_name(), "NegotiationConcluded");
+            assert_eq!(event.event_type(), EventType::Trade);
+
+            let wrapped = NegotiationEvent::NegotiationConcluded(event.clone());
+            let json = serde_json::to_string(&wrapped).unwrap();
+            let deserialized: NegotiationEvent = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, wrapped);
+        }
+    }
+
     mod negotiation_event_enum {
         use super::*;
 
@@ -5,7 +5,7 @@
 use crate::domain::entities::delayed_report::TradeSummary;
 use crate::domain::events::domain_event::{DomainEvent, EventMetadata, EventType};
 use crate::domain::services::ReportingTier;
-use crate::domain::value_objects::{BlockTradeId, EventId, RfqId, Timestamp};
+use crate::domain::value_objects::{BlockTradeId, CorrelationId, EventId, RfqId, Timestamp};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -106,6 +106,18 @@ impl DomainEvent for BlockTradeReported {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -185,6 +197,18 @@ impl DomainEvent for ReportScheduled {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -14,14 +14,22 @@
 //!
 //! At any point: RfqCancelled | RfqExpired
 //! ```
+//!
+//! A selection that overrides an unmet quote quorum is preceded by
+//! `QuorumOverridden`.
+//!
+//! Opted-in RFQs may emit `InternalCrossProposed` before quotes are
+//! requested from external venues.
 
 use crate::domain::events::domain_event::{DomainEvent, EventMetadata, EventType};
 use crate::domain::value_objects::timestamp::Timestamp;
 use crate::domain::value_objects::{
-    CounterpartyId, EventId, Instrument, OrderSide, Price, Quantity, QuoteId, RfqId, RfqState,
-    VenueId,
+    AggregationReport, CorrelationId, CounterpartyId, EventId, ExcludedVenue, FailureCode,
+    FailureReason, Instrument, OrderSide, Price, Quantity, QuorumShortfall, QuoteId,
+    ReferencePriceSource, RfqId, RfqState, VenueId, VenueOutcomeCounts,
 };
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// Event emitted when a new RFQ is created.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -71,6 +79,18 @@ impl DomainEvent for RfqCreated {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -91,6 +111,9 @@ pub struct QuoteCollectionStarted {
     pub metadata: EventMetadata,
     /// Venues being queried.
     pub venue_ids: Vec<VenueId>,
+    /// Venues left out by the RFQ's allowlist or blocklist, with reasons.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub excluded_venues: Vec<ExcludedVenue>,
 }
 
 impl QuoteCollectionStarted {
@@ -100,8 +123,16 @@ impl QuoteCollectionStarted {
         Self {
             metadata: EventMetadata::for_rfq(rfq_id),
             venue_ids,
+            excluded_venues: Vec::new(),
         }
     }
+
+    /// Records the venues left out of the fan-out.
+    #[must_use]
+    pub fn with_excluded_venues(mut self, excluded_venues: Vec<ExcludedVenue>) -> Self {
+        self.excluded_venues = excluded_venues;
+        self
+    }
 }
 
 impl DomainEvent for QuoteCollectionStarted {
@@ -113,6 +144,18 @@ impl DomainEvent for QuoteCollectionStarted {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -155,6 +198,18 @@ impl DomainEvent for QuoteRequested {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -216,6 +271,18 @@ impl DomainEvent for QuoteReceived {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -261,6 +328,18 @@ impl DomainEvent for QuoteRequestFailed {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -283,6 +362,9 @@ pub struct QuoteCollectionCompleted {
     pub quotes_received: u32,
     /// Number of venues that failed.
     pub venues_failed: u32,
+    /// Number of venues per outcome, from the aggregation report.
+    #[serde(default)]
+    pub venue_outcomes: VenueOutcomeCounts,
 }
 
 impl QuoteCollectionCompleted {
@@ -293,6 +375,21 @@ impl QuoteCollectionCompleted {
             metadata: EventMetadata::for_rfq(rfq_id),
             quotes_received,
             venues_failed,
+            venue_outcomes: VenueOutcomeCounts::default(),
+        }
+    }
+
+    /// Creates the event summarising `report`.
+    ///
+    /// Venues that timed out or errored count as failed.
+    #[must_use]
+    pub fn from_report(report: &AggregationReport, quotes_received: u32) -> Self {
+        let venue_outcomes = report.counts();
+        Self {
+            metadata: EventMetadata::for_rfq(report.rfq_id),
+            quotes_received,
+            venues_failed: venue_outcomes.failed(),
+            venue_outcomes,
         }
     }
 }
@@ -306,6 +403,18 @@ impl DomainEvent for QuoteCollectionCompleted {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -354,6 +463,18 @@ impl DomainEvent for QuoteSelected {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -367,6 +488,77 @@ impl DomainEvent for QuoteSelected {
     }
 }
 
+/// Event emitted when a client selects a quote despite an unmet quote quorum.
+///
+/// Records who waived best-execution competition and why.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuorumOverridden {
+    /// Event metadata.
+    pub metadata: EventMetadata,
+    /// The client that overrode the quorum.
+    pub client_id: CounterpartyId,
+    /// The quote selected under the override.
+    pub quote_id: QuoteId,
+    /// The quorum the RFQ fell short of.
+    pub shortfall: QuorumShortfall,
+    /// Justification given by the client.
+    pub reason: String,
+}
+
+impl QuorumOverridden {
+    /// Creates a new QuorumOverridden event.
+    #[must_use]
+    pub fn new(
+        rfq_id: RfqId,
+        client_id: CounterpartyId,
+        quote_id: QuoteId,
+        shortfall: QuorumShortfall,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::for_rfq(rfq_id),
+            client_id,
+            quote_id,
+            shortfall,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl DomainEvent for QuorumOverridden {
+    fn event_id(&self) -> EventId {
+        self.metadata.event_id
+    }
+
+    fn rfq_id(&self) -> Option<RfqId> {
+        self.metadata.rfq_id
+    }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.metadata.timestamp
+    }
+
+    fn event_type(&self) -> EventType {
+        EventType::Quote
+    }
+
+    fn event_name(&self) -> &'static str {
+        "QuorumOverridden"
+    }
+}
+
 /// Event emitted when execution starts.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ExecutionStarted {
@@ -399,6 +591,18 @@ impl DomainEvent for ExecutionStarted {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -419,6 +623,9 @@ pub struct ExecutionFailed {
     pub metadata: EventMetadata,
     /// The quote that failed to execute.
     pub quote_id: QuoteId,
+    /// Failure category; `OTHER` for events recorded before codes existed.
+    #[serde(default)]
+    pub code: FailureCode,
     /// Reason for failure.
     pub reason: String,
 }
@@ -426,11 +633,12 @@ pub struct ExecutionFailed {
 impl ExecutionFailed {
     /// Creates a new ExecutionFailed event.
     #[must_use]
-    pub fn new(rfq_id: RfqId, quote_id: QuoteId, reason: impl Into<String>) -> Self {
+    pub fn new(rfq_id: RfqId, quote_id: QuoteId, reason: &FailureReason) -> Self {
         Self {
             metadata: EventMetadata::for_rfq(rfq_id),
             quote_id,
-            reason: reason.into(),
+            code: reason.code(),
+            reason: reason.detail().to_string(),
         }
     }
 }
@@ -444,6 +652,18 @@ impl DomainEvent for ExecutionFailed {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -489,6 +709,18 @@ impl DomainEvent for RfqCancelled {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -531,6 +763,18 @@ impl DomainEvent for RfqExpired {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -544,6 +788,86 @@ impl DomainEvent for RfqExpired {
     }
 }
 
+/// Event emitted when an RFQ is matched against an opposing client RFQ.
+///
+/// Synthetic quotes from the internal venue have been attached to both RFQs
+/// at `price`, derived from the reference price.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InternalCrossProposed {
+    /// Event metadata.
+    pub metadata: EventMetadata,
+    /// The opposing RFQ this RFQ was crossed against.
+    pub counterpart_rfq_id: RfqId,
+    /// The instrument being crossed.
+    pub instrument: Instrument,
+    /// Side of this RFQ.
+    pub side: OrderSide,
+    /// Cross price.
+    pub price: Price,
+    /// Crossed quantity.
+    pub quantity: Quantity,
+    /// Source of the reference price used for the cross.
+    pub reference_source: ReferencePriceSource,
+}
+
+impl InternalCrossProposed {
+    /// Creates a new InternalCrossProposed event.
+    #[must_use]
+    pub fn new(
+        rfq_id: RfqId,
+        counterpart_rfq_id: RfqId,
+        instrument: Instrument,
+        side: OrderSide,
+        price: Price,
+        quantity: Quantity,
+        reference_source: ReferencePriceSource,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::for_rfq(rfq_id),
+            counterpart_rfq_id,
+            instrument,
+            side,
+            price,
+            quantity,
+            reference_source,
+        }
+    }
+}
+
+impl DomainEvent for InternalCrossProposed {
+    fn event_id(&self) -> EventId {
+        self.metadata.event_id
+    }
+
+    fn rfq_id(&self) -> Option<RfqId> {
+        self.metadata.rfq_id
+    }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.metadata.timestamp
+    }
+
+    fn event_type(&self) -> EventType {
+        EventType::Rfq
+    }
+
+    fn event_name(&self) -> &'static str {
+        "InternalCrossProposed"
+    }
+}
+
 /// Enum containing all RFQ-related events.
 ///
 /// This enum allows for type-safe handling of all RFQ events.
@@ -564,6 +888,8 @@ pub enum RfqEvent {
     QuoteCollectionCompleted(QuoteCollectionCompleted),
     /// Quote was selected.
     QuoteSelected(QuoteSelected),
+    /// Quote quorum was overridden.
+    QuorumOverridden(QuorumOverridden),
     /// Execution started.
     ExecutionStarted(ExecutionStarted),
     /// Execution failed.
@@ -572,6 +898,8 @@ pub enum RfqEvent {
     Cancelled(RfqCancelled),
     /// RFQ expired.
     Expired(RfqExpired),
+    /// RFQ was crossed against an opposing client RFQ.
+    InternalCrossProposed(InternalCrossProposed),
 }
 
 impl DomainEvent for RfqEvent {
@@ -584,10 +912,12 @@ impl DomainEvent for RfqEvent {
             Self::QuoteRequestFailed(e) => e.event_id(),
             Self::QuoteCollectionCompleted(e) => e.event_id(),
             Self::QuoteSelected(e) => e.event_id(),
+            Self::QuorumOverridden(e) => e.event_id(),
             Self::ExecutionStarted(e) => e.event_id(),
             Self::ExecutionFailed(e) => e.event_id(),
             Self::Cancelled(e) => e.event_id(),
             Self::Expired(e) => e.event_id(),
+            Self::InternalCrossProposed(e) => e.event_id(),
         }
     }
 
@@ -600,10 +930,66 @@ impl DomainEvent for RfqEvent {
             Self::QuoteRequestFailed(e) => e.rfq_id(),
             Self::QuoteCollectionCompleted(e) => e.rfq_id(),
             Self::QuoteSelected(e) => e.rfq_id(),
+            Self::QuorumOverridden(e) => e.rfq_id(),
             Self::ExecutionStarted(e) => e.rfq_id(),
             Self::ExecutionFailed(e) => e.rfq_id(),
             Self::Cancelled(e) => e.rfq_id(),
             Self::Expired(e) => e.rfq_id(),
+            Self::InternalCrossProposed(e) => e.rfq_id(),
+        }
+    }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        match self {
+            Self::Created(e) => e.correlation_id(),
+            Self::QuoteCollectionStarted(e) => e.correlation_id(),
+            Self::QuoteRequested(e) => e.correlation_id(),
+            Self::QuoteReceived(e) => e.correlation_id(),
+            Self::QuoteRequestFailed(e) => e.correlation_id(),
+            Self::QuoteCollectionCompleted(e) => e.correlation_id(),
+            Self::QuoteSelected(e) => e.correlation_id(),
+            Self::QuorumOverridden(e) => e.correlation_id(),
+            Self::ExecutionStarted(e) => e.correlation_id(),
+            Self::ExecutionFailed(e) => e.correlation_id(),
+            Self::Cancelled(e) => e.correlation_id(),
+            Self::Expired(e) => e.correlation_id(),
+            Self::InternalCrossProposed(e) => e.correlation_id(),
+        }
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        match self {
+            Self::Created(e) => e.aggregate_id(),
+            Self::QuoteCollectionStarted(e) => e.aggregate_id(),
+            Self::QuoteRequested(e) => e.aggregate_id(),
+            Self::QuoteReceived(e) => e.aggregate_id(),
+            Self::QuoteRequestFailed(e) => e.aggregate_id(),
+            Self::QuoteCollectionCompleted(e) => e.aggregate_id(),
+            Self::QuoteSelected(e) => e.aggregate_id(),
+            Self::QuorumOverridden(e) => e.aggregate_id(),
+            Self::ExecutionStarted(e) => e.aggregate_id(),
+            Self::ExecutionFailed(e) => e.aggregate_id(),
+            Self::Cancelled(e) => e.aggregate_id(),
+            Self::Expired(e) => e.aggregate_id(),
+            Self::InternalCrossProposed(e) => e.aggregate_id(),
+        }
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        match self {
+            Self::Created(e) => e.aggregate_version(),
+            Self::QuoteCollectionStarted(e) => e.aggregate_version(),
+            Self::QuoteRequested(e) => e.aggregate_version(),
+            Self::QuoteReceived(e) => e.aggregate_version(),
+            Self::QuoteRequestFailed(e) => e.aggregate_version(),
+            Self::QuoteCollectionCompleted(e) => e.aggregate_version(),
+            Self::QuoteSelected(e) => e.aggregate_version(),
+            Self::QuorumOverridden(e) => e.aggregate_version(),
+            Self::ExecutionStarted(e) => e.aggregate_version(),
+            Self::ExecutionFailed(e) => e.aggregate_version(),
+            Self::Cancelled(e) => e.aggregate_version(),
+            Self::Expired(e) => e.aggregate_version(),
+            Self::InternalCrossProposed(e) => e.aggregate_version(),
         }
     }
 
@@ -616,10 +1002,12 @@ impl DomainEvent for RfqEvent {
             Self::QuoteRequestFailed(e) => e.timestamp(),
             Self::QuoteCollectionCompleted(e) => e.timestamp(),
             Self::QuoteSelected(e) => e.timestamp(),
+            Self::QuorumOverridden(e) => e.timestamp(),
             Self::ExecutionStarted(e) => e.timestamp(),
             Self::ExecutionFailed(e) => e.timestamp(),
             Self::Cancelled(e) => e.timestamp(),
             Self::Expired(e) => e.timestamp(),
+            Self::InternalCrossProposed(e) => e.timestamp(),
         }
     }
 
@@ -632,10 +1020,12 @@ impl DomainEvent for RfqEvent {
             Self::QuoteRequestFailed(e) => e.event_type(),
             Self::QuoteCollectionCompleted(e) => e.event_type(),
             Self::QuoteSelected(e) => e.event_type(),
+            Self::QuorumOverridden(e) => e.event_type(),
             Self::ExecutionStarted(e) => e.event_type(),
             Self::ExecutionFailed(e) => e.event_type(),
             Self::Cancelled(e) => e.event_type(),
             Self::Expired(e) => e.event_type(),
+            Self::InternalCrossProposed(e) => e.event_type(),
         }
     }
 
@@ -648,10 +1038,12 @@ impl DomainEvent for RfqEvent {
             Self::QuoteRequestFailed(e) => e.event_name(),
             Self::QuoteCollectionCompleted(e) => e.event_name(),
             Self::QuoteSelected(e) => e.event_name(),
+            Self::QuorumOverridden(e) => e.event_name(),
             Self::ExecutionStarted(e) => e.event_name(),
             Self::ExecutionFailed(e) => e.event_name(),
             Self::Cancelled(e) => e.event_name(),
             Self::Expired(e) => e.event_name(),
+            Self::InternalCrossProposed(e) => e.event_name(),
         }
     }
 }
@@ -728,6 +1120,24 @@ mod tests {
             assert_eq!(event.venue_ids.len(), 1);
         }
 
+        #[test]
+        fn quote_collection_started_records_exclusions() {
+            use crate::domain::value_objects::VenueExclusionReason;
+
+            let excluded =
+                ExcludedVenue::new(VenueId::new("mm-leaky"), VenueExclusionReason::Blocklisted);
+            let event = QuoteCollectionStarted::new(test_rfq_id(), vec![test_venue_id()])
+                .with_excluded_venues(vec![excluded.clone()]);
+
+            let json = serde_json::to_value(&event).unwrap();
+            assert_eq!(
+                json.pointer("/excluded_venues/0/reason"),
+                Some(&serde_json::json!("BLOCKLISTED"))
+            );
+            let back: QuoteCollectionStarted = serde_json::from_value(json).unwrap();
+            assert_eq!(back.excluded_venues, vec![excluded]);
+        }
+
         #[test]
         fn quote_requested() {
             let rfq_id = test_rfq_id();
@@ -803,8 +1213,13 @@ mod tests {
         #[test]
         fn execution_failed() {
             let quote_id = QuoteId::new_v4();
-            let event = ExecutionFailed::new(test_rfq_id(), quote_id, "Insufficient liquidity");
+            let event = ExecutionFailed::new(
+                test_rfq_id(),
+                quote_id,
+                &FailureReason::new(FailureCode::InsufficientLiquidity, "Insufficient liquidity"),
+            );
 
+            assert_eq!(event.code, FailureCode::InsufficientLiquidity);
             assert_eq!(event.reason, "Insufficient liquidity");
             assert_eq!(event.event_name(), "ExecutionFailed");
         }
@@ -833,6 +1248,28 @@ mod tests {
             assert_eq!(event.previous_state, RfqState::QuoteRequesting);
             assert_eq!(event.event_name(), "RfqExpired");
         }
+
+        #[test]
+        fn internal_cross_proposed() {
+            let rfq_id = test_rfq_id();
+            let counterpart = test_rfq_id();
+            let event = RfqEvent::InternalCrossProposed(InternalCrossProposed::new(
+                rfq_id,
+                counterpart,
+                test_instrument(),
+                OrderSide::Buy,
+                Price::new(50000.0).unwrap(),
+                Quantity::new(1.0).unwrap(),
+                ReferencePriceSource::ClobMid,
+            ));
+
+            assert_eq!(event.rfq_id(), Some(rfq_id));
+            assert_eq!(event.event_name(), "InternalCrossProposed");
+
+            let json = serde_json::to_string(&event).unwrap();
+            let deserialized: RfqEvent = serde_json::from_str(&json).unwrap();
+            assert_eq!(event, deserialized);
+        }
     }
 
     mod rfq_event_enum {
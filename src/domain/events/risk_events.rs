@@ -0,0 +1,75 @@
+//! # Risk Events
+//!
+//! Domain events for changes to pre-trade risk controls.
+//!
+//! - [`PriceBoundsConfigChanged`]: An administrator changed the price bounds
+//!   tolerances
+
+use crate::domain::events::domain_event::{DomainEvent, EventMetadata, EventType};
+use crate::domain::value_objects::reference_price::{PriceBoundsConfigChange, PriceBoundsSettings};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{CorrelationId, EventId, RfqId};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Event emitted when the price bounds tolerances are changed.
+///
+/// The new settings apply from the next price bounds validation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriceBoundsConfigChanged {
+    /// Event metadata.
+    pub metadata: EventMetadata,
+    /// Who made the change.
+    pub changed_by: String,
+    /// Settings before the change.
+    pub previous: PriceBoundsSettings,
+    /// Settings after the change.
+    pub current: PriceBoundsSettings,
+}
+
+impl PriceBoundsConfigChanged {
+    /// Creates a new PriceBoundsConfigChanged event from an audited change.
+    #[must_use]
+    pub fn new(change: &PriceBoundsConfigChange) -> Self {
+        Self {
+            metadata: EventMetadata::new(None),
+            changed_by: change.changed_by.clone(),
+            previous: change.previous.clone(),
+            current: change.current.clone(),
+        }
+    }
+}
+
+impl DomainEvent for PriceBoundsConfigChanged {
+    fn event_id(&self) -> EventId {
+        self.metadata.event_id
+    }
+
+    fn rfq_id(&self) -> Option<RfqId> {
+        self.metadata.rfq_id
+    }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.metadata.timestamp
+    }
+
+    fn event_type(&self) -> EventType {
+        EventType::Compliance
+    }
+
+    fn event_name(&self) -> &'static str {
+        "PriceBoundsConfigChanged"
+    }
+}
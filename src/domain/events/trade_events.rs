@@ -9,15 +9,22 @@
 //! ```text
 //! TradeExecuted -> SettlementInitiated -> SettlementConfirmed | SettlementFailed
 //! ```
+//!
+//! A trade filled across several venues also reports each venue leg:
+//!
+//! ```text
+//! AllocationFilled | AllocationFailed (one per allocation)
+//! ```
 
 use crate::domain::errors::{DomainError, DomainResult};
 use crate::domain::events::domain_event::{DomainEvent, EventMetadata, EventType};
 use crate::domain::value_objects::timestamp::Timestamp;
 use crate::domain::value_objects::{
-    Blockchain, CounterpartyId, EventId, Instrument, OrderSide, Price, Quantity, QuoteId, RfqId,
-    SettlementMethod, TradeId, VenueId,
+    Blockchain, CorrelationId, CounterpartyId, EventId, Instrument, OrderSide, Price, Quantity,
+    QuoteId, RfqId, SettlementMethod, TradeId, VenueId,
 };
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// Event emitted when a trade is executed.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -361,6 +368,18 @@ impl DomainEvent for TradeExecuted {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -436,6 +455,18 @@ impl DomainEvent for SettlementInitiated {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -501,6 +532,18 @@ impl DomainEvent for SettlementConfirmed {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -554,6 +597,18 @@ impl DomainEvent for SettlementFailed {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -567,6 +622,223 @@ impl DomainEvent for SettlementFailed {
     }
 }
 
+/// Event emitted when a venue leg of a multi-venue fill is filled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocationFilled {
+    /// Event metadata.
+    pub metadata: EventMetadata,
+    /// The trade the allocation belongs to.
+    pub trade_id: TradeId,
+    /// The quote the allocation was filled against.
+    pub quote_id: QuoteId,
+    /// The venue that filled the leg.
+    pub venue_id: VenueId,
+    /// The filled quantity.
+    pub quantity: Quantity,
+    /// The fill price.
+    pub price: Price,
+}
+
+impl AllocationFilled {
+    /// Creates a new AllocationFilled event.
+    #[must_use]
+    pub fn new(
+        rfq_id: RfqId,
+        trade_id: TradeId,
+        quote_id: QuoteId,
+        venue_id: VenueId,
+        quantity: Quantity,
+        price: Price,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::for_rfq(rfq_id),
+            trade_id,
+            quote_id,
+            venue_id,
+            quantity,
+            price,
+        }
+    }
+}
+
+impl DomainEvent for AllocationFilled {
+    fn event_id(&self) -> EventId {
+        self.metadata.event_id
+    }
+
+    fn rfq_id(&self) -> Option<RfqId> {
+        self.metadata.rfq_id
+    }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.metadata.timestamp
+    }
+
+    fn event_type(&self) -> EventType {
+        EventType::Trade
+    }
+
+    fn event_name(&self) -> &'static str {
+        "AllocationFilled"
+    }
+}
+
+/// Event emitted when a venue leg of a multi-venue fill fails.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocationFailed {
+    /// Event metadata.
+    pub metadata: EventMetadata,
+    /// The trade the allocation belongs to.
+    pub trade_id: TradeId,
+    /// The quote the allocation was to be filled against.
+    pub quote_id: QuoteId,
+    /// The venue whose leg failed.
+    pub venue_id: VenueId,
+    /// The quantity left unfilled.
+    pub quantity: Quantity,
+    /// Reason for failure.
+    pub reason: String,
+}
+
+impl AllocationFailed {
+    /// Creates a new AllocationFailed event.
+    #[must_use]
+    pub fn new(
+        rfq_id: RfqId,
+        trade_id: TradeId,
+        quote_id: QuoteId,
+        venue_id: VenueId,
+        quantity: Quantity,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::for_rfq(rfq_id),
+            trade_id,
+            quote_id,
+            venue_id,
+            quantity,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl DomainEvent for AllocationFailed {
+    fn event_id(&self) -> EventId {
+        self.metadata.event_id
+    }
+
+    fn rfq_id(&self) -> Option<RfqId> {
+        self.metadata.rfq_id
+    }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.metadata.timestamp
+    }
+
+    fn event_type(&self) -> EventType {
+        EventType::Trade
+    }
+
+    fn event_name(&self) -> &'static str {
+        "AllocationFailed"
+    }
+}
+
+/// Event emitted when settlement retries are exhausted.
+///
+/// The trade needs manual intervention; consumers should raise an alert.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SettlementDeadLettered {
+    /// Event metadata.
+    pub metadata: EventMetadata,
+    /// The trade that could not be settled.
+    pub trade_id: TradeId,
+    /// Number of retries made before giving up.
+    pub attempts: u32,
+    /// The last settlement error.
+    pub last_error: Option<String>,
+    /// Hash of the last submitted transaction, if any.
+    pub tx_hash: Option<String>,
+}
+
+impl SettlementDeadLettered {
+    /// Creates a new SettlementDeadLettered event.
+    #[must_use]
+    pub fn new(
+        rfq_id: RfqId,
+        trade_id: TradeId,
+        attempts: u32,
+        last_error: Option<String>,
+        tx_hash: Option<String>,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::for_rfq(rfq_id),
+            trade_id,
+            attempts,
+            last_error,
+            tx_hash,
+        }
+    }
+}
+
+impl DomainEvent for SettlementDeadLettered {
+    fn event_id(&self) -> EventId {
+        self.metadata.event_id
+    }
+
+    fn rfq_id(&self) -> Option<RfqId> {
+        self.metadata.rfq_id
+    }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.metadata.timestamp
+    }
+
+    fn event_type(&self) -> EventType {
+        EventType::Settlement
+    }
+
+    fn event_name(&self) -> &'static str {
+        "SettlementDeadLettered"
+    }
+}
+
 /// Event emitted when positions are updated after trade execution.
 ///
 /// This event notifies the Position Manager to:
@@ -637,6 +909,18 @@ impl DomainEvent for PositionUpdated {
         self.metadata.rfq_id
     }
 
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
     fn timestamp(&self) -> Timestamp {
         self.metadata.timestamp
     }
@@ -664,6 +948,12 @@ pub enum TradeEvent {
     SettlementConfirmed(SettlementConfirmed),
     /// Settlement failed.
     SettlementFailed(SettlementFailed),
+    /// Settlement retries were exhausted.
+    SettlementDeadLettered(SettlementDeadLettered),
+    /// A venue leg of a multi-venue fill was filled.
+    AllocationFilled(AllocationFilled),
+    /// A venue leg of a multi-venue fill failed.
+    AllocationFailed(AllocationFailed),
 }
 
 impl DomainEvent for TradeEvent {
@@ -674,6 +964,9 @@ impl DomainEvent for TradeEvent {
             Self::SettlementInitiated(e) => e.event_id(),
             Self::SettlementConfirmed(e) => e.event_id(),
             Self::SettlementFailed(e) => e.event_id(),
+            Self::SettlementDeadLettered(e) => e.event_id(),
+            Self::AllocationFilled(e) => e.event_id(),
+            Self::AllocationFailed(e) => e.event_id(),
         }
     }
 
@@ -684,6 +977,48 @@ impl DomainEvent for TradeEvent {
             Self::SettlementInitiated(e) => e.rfq_id(),
             Self::SettlementConfirmed(e) => e.rfq_id(),
             Self::SettlementFailed(e) => e.rfq_id(),
+            Self::SettlementDeadLettered(e) => e.rfq_id(),
+            Self::AllocationFilled(e) => e.rfq_id(),
+            Self::AllocationFailed(e) => e.rfq_id(),
+        }
+    }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        match self {
+            Self::Executed(e) => e.correlation_id(),
+            Self::PositionUpdated(e) => e.correlation_id(),
+            Self::SettlementInitiated(e) => e.correlation_id(),
+            Self::SettlementConfirmed(e) => e.correlation_id(),
+            Self::SettlementFailed(e) => e.correlation_id(),
+            Self::SettlementDeadLettered(e) => e.correlation_id(),
+            Self::AllocationFilled(e) => e.correlation_id(),
+            Self::AllocationFailed(e) => e.correlation_id(),
+        }
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        match self {
+            Self::Executed(e) => e.aggregate_id(),
+            Self::PositionUpdated(e) => e.aggregate_id(),
+            Self::SettlementInitiated(e) => e.aggregate_id(),
+            Self::SettlementConfirmed(e) => e.aggregate_id(),
+            Self::SettlementFailed(e) => e.aggregate_id(),
+            Self::SettlementDeadLettered(e) => e.aggregate_id(),
+            Self::AllocationFilled(e) => e.aggregate_id(),
+            Self::AllocationFailed(e) => e.aggregate_id(),
+        }
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        match self {
+            Self::Executed(e) => e.aggregate_version(),
+            Self::PositionUpdated(e) => e.aggregate_version(),
+            Self::SettlementInitiated(e) => e.aggregate_version(),
+            Self::SettlementConfirmed(e) => e.aggregate_version(),
+            Self::SettlementFailed(e) => e.aggregate_version(),
+            Self::SettlementDeadLettered(e) => e.aggregate_version(),
+            Self::AllocationFilled(e) => e.aggregate_version(),
+            Self::AllocationFailed(e) => e.aggregate_version(),
         }
     }
 
@@ -694,6 +1029,9 @@ impl DomainEvent for TradeEvent {
             Self::SettlementInitiated(e) => e.timestamp(),
             Self::SettlementConfirmed(e) => e.timestamp(),
             Self::SettlementFailed(e) => e.timestamp(),
+            Self::SettlementDeadLettered(e) => e.timestamp(),
+            Self::AllocationFilled(e) => e.timestamp(),
+            Self::AllocationFailed(e) => e.timestamp(),
         }
     }
 
@@ -704,6 +1042,9 @@ impl DomainEvent for TradeEvent {
             Self::SettlementInitiated(e) => e.event_type(),
             Self::SettlementConfirmed(e) => e.event_type(),
             Self::SettlementFailed(e) => e.event_type(),
+            Self::SettlementDeadLettered(e) => e.event_type(),
+            Self::AllocationFilled(e) => e.event_type(),
+            Self::AllocationFailed(e) => e.event_type(),
         }
     }
 
@@ -714,6 +1055,9 @@ impl DomainEvent for TradeEvent {
             Self::SettlementInitiated(e) => e.event_name(),
             Self::SettlementConfirmed(e) => e.event_name(),
             Self::SettlementFailed(e) => e.event_name(),
+            Self::SettlementDeadLettered(e) => e.event_name(),
+            Self::AllocationFilled(e) => e.event_name(),
+            Self::AllocationFailed(e) => e.event_name(),
         }
     }
 }
@@ -1110,6 +1454,62 @@ mod tests {
         }
     }
 
+    mod settlement_dead_lettered {
+        use super::*;
+
+        #[test]
+        fn creates_event() {
+            let event = SettlementDeadLettered::new(
+                test_rfq_id(),
+                test_trade_id(),
+                5,
+                Some("reverted".to_string()),
+                Some("0xdead".to_string()),
+            );
+
+            assert_eq!(event.attempts, 5);
+            assert_eq!(event.last_error.as_deref(), Some("reverted"));
+            assert_eq!(event.event_type(), EventType::Settlement);
+            assert_eq!(event.event_name(), "SettlementDeadLettered");
+        }
+    }
+
+    mod allocation_legs {
+        use super::*;
+
+        #[test]
+        fn filled_event_carries_leg() {
+            let event = AllocationFilled::new(
+                test_rfq_id(),
+                test_trade_id(),
+                test_quote_id(),
+                test_venue_id(),
+                Quantity::new(6.0).unwrap(),
+                Price::new(100.0).unwrap(),
+            );
+
+            assert_eq!(event.event_name(), "AllocationFilled");
+            assert_eq!(event.event_type(), EventType::Trade);
+        }
+
+        #[test]
+        fn failed_event_roundtrips_through_enum() {
+            let event = TradeEvent::AllocationFailed(AllocationFailed::new(
+                test_rfq_id(),
+                test_trade_id(),
+                test_quote_id(),
+                test_venue_id(),
+                Quantity::new(4.0).unwrap(),
+                "venue timeout",
+            ));
+
+            let json = serde_json::to_string(&event).unwrap();
+            let deserialized: TradeEvent = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, event);
+            assert_eq!(deserialized.event_name(), "AllocationFailed");
+        }
+    }
+
     mod trade_event_enum {
         use super::*;
         use crate::domain::value_objects::enums::AssetClass;
@@ -0,0 +1,84 @@
+//! # Webhook Events
+//!
+//! Domain events for outbound webhook subscriptions.
+//!
+//! - [`WebhookSubscriptionDisabled`]: A subscription was deactivated after
+//!   repeated delivery failures
+
+use crate::domain::events::domain_event::{DomainEvent, EventMetadata, EventType};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{CorrelationId, EventId, RfqId, WebhookSubscriptionId};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Event emitted when a webhook subscription is deactivated after
+/// consecutive delivery failures.
+///
+/// The downstream system stops receiving events until an operator
+/// re-activates the subscription; consumers should raise an alert.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookSubscriptionDisabled {
+    /// Event metadata.
+    pub metadata: EventMetadata,
+    /// The deactivated subscription.
+    pub subscription_id: WebhookSubscriptionId,
+    /// Endpoint the subscription delivered to.
+    pub url: String,
+    /// Failed deliveries in a row that triggered the deactivation.
+    pub consecutive_failures: u32,
+    /// The last delivery error.
+    pub last_error: Option<String>,
+}
+
+impl WebhookSubscriptionDisabled {
+    /// Creates a new WebhookSubscriptionDisabled event.
+    #[must_use]
+    pub fn new(
+        subscription_id: WebhookSubscriptionId,
+        url: impl Into<String>,
+        consecutive_failures: u32,
+        last_error: Option<String>,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::new(None),
+            subscription_id,
+            url: url.into(),
+            consecutive_failures,
+            last_error,
+        }
+    }
+}
+
+impl DomainEvent for WebhookSubscriptionDisabled {
+    fn event_id(&self) -> EventId {
+        self.metadata.event_id
+    }
+
+    fn rfq_id(&self) -> Option<RfqId> {
+        self.metadata.rfq_id
+    }
+
+    fn correlation_id(&self) -> Option<CorrelationId> {
+        self.metadata.correlation_id
+    }
+
+    fn aggregate_id(&self) -> Option<Uuid> {
+        self.metadata.aggregate_id
+    }
+
+    fn aggregate_version(&self) -> u64 {
+        self.metadata.aggregate_version
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.metadata.timestamp
+    }
+
+    fn event_type(&self) -> EventType {
+        EventType::Integration
+    }
+
+    fn event_name(&self) -> &'static str {
+        "WebhookSubscriptionDisabled"
+    }
+}
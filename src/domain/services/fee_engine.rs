@@ -41,7 +41,9 @@
 //! ```
 
 use crate::domain::errors::{DomainError, DomainResult};
-use crate::domain::value_objects::{CounterpartyId, TradeType};
+use crate::domain::value_objects::{
+    ArithmeticResult, CounterpartyId, Rounding, TradeType, bps_of, percent_of,
+};
 use async_trait::async_trait;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -617,45 +619,46 @@ impl FeeEngine {
         rates: &FeeRates,
         discount: Option<&VolumeDiscount>,
     ) -> DomainResult<(Decimal, Decimal)> {
-        // Calculate base fees
-        let base_taker = notional_usd
-            .checked_mul(rates.taker_rate_decimal())
-            .ok_or_else(|| DomainError::FeeCalculationFailed {
-                reason: "taker fee overflow".to_string(),
+        let taker_fee =
+            Self::compute_fee(notional_usd, rates.taker_rate_bps(), discount).map_err(|_| {
+                DomainError::FeeCalculationFailed {
+                    reason: "taker fee overflow".to_string(),
+                }
             })?;
 
-        let base_maker = notional_usd
-            .checked_mul(rates.maker_rate_decimal())
-            .ok_or_else(|| DomainError::FeeCalculationFailed {
-                reason: "maker fee overflow".to_string(),
+        let maker_fee =
+            Self::compute_fee(notional_usd, rates.maker_rate_bps(), discount).map_err(|_| {
+                DomainError::FeeCalculationFailed {
+                    reason: "maker fee overflow".to_string(),
+                }
             })?;
 
-        // Apply discount if applicable
-        let (taker_fee, maker_fee) = if let Some(d) = discount {
-            let discount_multiplier = Decimal::ONE - d.discount_decimal();
-
-            // For positive fees, apply discount (reduce fee)
-            // For negative fees (rebates), apply discount (reduce rebate magnitude)
-            let discounted_taker =
-                base_taker.checked_mul(discount_multiplier).ok_or_else(|| {
-                    DomainError::FeeCalculationFailed {
-                        reason: "discounted taker fee overflow".to_string(),
-                    }
-                })?;
-
-            let discounted_maker =
-                base_maker.checked_mul(discount_multiplier).ok_or_else(|| {
-                    DomainError::FeeCalculationFailed {
-                        reason: "discounted maker fee overflow".to_string(),
-                    }
-                })?;
+        Ok((taker_fee, maker_fee))
+    }
 
-            (discounted_taker, discounted_maker)
+    /// Computes one side's fee: `notional × rate`, less any volume discount.
+    ///
+    /// A discount shrinks the magnitude of both fees and rebates. Fees round
+    /// up and rebates round down, so rounding never favours the counterparty.
+    fn compute_fee(
+        notional_usd: Decimal,
+        rate_bps: i32,
+        discount: Option<&VolumeDiscount>,
+    ) -> ArithmeticResult<Decimal> {
+        let rounding = if rate_bps >= 0 {
+            Rounding::Up
         } else {
-            (base_taker, base_maker)
+            Rounding::Down
         };
 
-        Ok((taker_fee, maker_fee))
+        let base_fee = bps_of(notional_usd, i64::from(rate_bps), rounding)?;
+        match discount {
+            Some(d) => {
+                let payable_pct = Decimal::from(100u8.saturating_sub(d.discount_pct()));
+                percent_of(base_fee, payable_pct, rounding)
+            }
+            None => Ok(base_fee),
+        }
     }
 }
 
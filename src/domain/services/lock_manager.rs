@@ -116,7 +116,14 @@ impl LockInfo {
     #[must_use]
     #[inline]
     pub fn is_expired(&self) -> bool {
-        Timestamp::now() >= self.expires_at
+        self.is_expired_at(Timestamp::now())
+    }
+
+    /// Returns true if the lock has expired as of `now`.
+    #[must_use]
+    #[inline]
+    pub fn is_expired_at(&self, now: Timestamp) -> bool {
+        now >= self.expires_at
     }
 }
 
@@ -21,13 +21,19 @@
 use crate::domain::entities::mm_performance::{
     DEFAULT_WINDOW_DAYS, MmPerformanceEvent, MmPerformanceEventKind, MmPerformanceMetrics,
 };
-use crate::domain::value_objects::CounterpartyId;
-use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::timestamp::{Clock, SystemClock, Timestamp};
+use crate::domain::value_objects::{CounterpartyId, RfqId};
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::fmt;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
 use thiserror::Error;
 
+/// Default time after an RFQ is cancelled during which a market maker's
+/// quote is treated as in flight rather than late.
+pub const DEFAULT_CANCEL_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
 /// Error type for MM performance operations.
 #[derive(Debug, Error)]
 pub enum MmPerformanceError {
@@ -125,6 +131,12 @@ pub struct MmPerformanceTracker {
     repository: Arc<dyn MmPerformanceRepository>,
     /// Rolling window size in days.
     window_days: u32,
+    /// Source of the current time for event stamps and window bounds.
+    clock: Arc<dyn Clock>,
+    /// How long after a cancellation a quote still withdraws the RFQ.
+    cancel_grace: Duration,
+    /// RFQs already withdrawn per market maker, keyed to their cancel time.
+    withdrawn: Mutex<HashMap<(CounterpartyId, RfqId), Timestamp>>,
 }
 
 impl MmPerformanceTracker {
@@ -139,9 +151,28 @@ impl MmPerformanceTracker {
         Self {
             repository,
             window_days,
+            clock: Arc::new(SystemClock),
+            cancel_grace: DEFAULT_CANCEL_GRACE_PERIOD,
+            withdrawn: Mutex::new(HashMap::new()),
         }
     }
 
+    /// Sets how long after a cancellation a quote still counts as in flight.
+    ///
+    /// See [`record_late_quote`](Self::record_late_quote).
+    #[must_use]
+    pub fn with_cancel_grace_period(mut self, grace: Duration) -> Self {
+        self.cancel_grace = grace;
+        self
+    }
+
+    /// Sets the clock used to stamp events and compute the rolling window.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Creates a new tracker with default 7-day window.
     ///
     /// # Arguments
@@ -159,6 +190,21 @@ impl MmPerformanceTracker {
         self.window_days
     }
 
+    /// Returns the current time on the tracker's clock.
+    #[must_use]
+    pub fn now(&self) -> Timestamp {
+        self.clock.now()
+    }
+
+    /// Records an event stamped by the caller.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MmPerformanceError::Repository` if the event cannot be stored.
+    pub async fn record_event(&self, event: MmPerformanceEvent) -> MmPerformanceResult<()> {
+        self.repository.record_event(event).await
+    }
+
     /// Records that an RFQ was sent to a market maker.
     ///
     /// # Arguments
@@ -172,7 +218,7 @@ impl MmPerformanceTracker {
         let event = MmPerformanceEvent::new(
             mm_id.clone(),
             MmPerformanceEventKind::RfqSent,
-            Timestamp::now(),
+            self.clock.now(),
         );
         self.repository.record_event(event).await
     }
@@ -200,7 +246,7 @@ impl MmPerformanceTracker {
                 response_time_ms,
                 rank,
             },
-            Timestamp::now(),
+            self.clock.now(),
         );
         self.repository.record_event(event).await
     }
@@ -218,7 +264,7 @@ impl MmPerformanceTracker {
         let event = MmPerformanceEvent::new(
             mm_id.clone(),
             MmPerformanceEventKind::TradeExecuted,
-            Timestamp::now(),
+            self.clock.now(),
         );
         self.repository.record_event(event).await
     }
@@ -236,7 +282,7 @@ impl MmPerformanceTracker {
         let event = MmPerformanceEvent::new(
             mm_id.clone(),
             MmPerformanceEventKind::LastLookReject,
-            Timestamp::now(),
+            self.clock.now(),
         );
         self.repository.record_event(event).await
     }
@@ -256,11 +302,63 @@ impl MmPerformanceTracker {
         let event = MmPerformanceEvent::new(
             mm_id.clone(),
             MmPerformanceEventKind::AcceptRequested,
-            Timestamp::now(),
+            self.clock.now(),
         );
         self.repository.record_event(event).await
     }
 
+    /// Records a quote that arrived after its RFQ was cancelled.
+    ///
+    /// A quote arriving within the cancel grace period was already in
+    /// flight when the client cancelled, so the RFQ is withdrawn from the
+    /// market maker's response rate. Later quotes record nothing: the RFQ
+    /// stays counted as unanswered. Each RFQ is withdrawn at most once per
+    /// market maker.
+    ///
+    /// # Arguments
+    ///
+    /// * `mm_id` - Market maker identifier
+    /// * `rfq_id` - The cancelled RFQ
+    /// * `cancelled_at` - When the RFQ was cancelled
+    ///
+    /// # Returns
+    ///
+    /// `true` if the quote fell within the grace period.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MmPerformanceError::Repository` if the event cannot be stored.
+    pub async fn record_late_quote(
+        &self,
+        mm_id: &CounterpartyId,
+        rfq_id: RfqId,
+        cancelled_at: Timestamp,
+    ) -> MmPerformanceResult<bool> {
+        let now = self.clock.now();
+        if cancelled_at.duration_until(&now) > self.cancel_grace {
+            return Ok(false);
+        }
+
+        {
+            let mut withdrawn = self
+                .withdrawn
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            withdrawn.retain(|_, at| at.duration_until(&now) <= self.cancel_grace);
+            if withdrawn
+                .insert((mm_id.clone(), rfq_id), cancelled_at)
+                .is_some()
+            {
+                return Ok(true);
+            }
+        }
+
+        let event =
+            MmPerformanceEvent::new(mm_id.clone(), MmPerformanceEventKind::RfqWithdrawn, now);
+        self.repository.record_event(event).await?;
+        Ok(true)
+    }
+
     /// Computes performance metrics for a specific market maker.
     ///
     /// Metrics are computed over the configured rolling window ending at the
@@ -277,8 +375,7 @@ impl MmPerformanceTracker {
         &self,
         mm_id: &CounterpartyId,
     ) -> MmPerformanceResult<MmPerformanceMetrics> {
-        let now = Timestamp::now();
-        let window_start = now.sub_secs(i64::from(self.window_days) * 86400);
+        let (window_start, now) = self.window();
         let events = self.repository.get_events(mm_id, window_start, now).await?;
 
         Ok(MmPerformanceMetrics::compute(
@@ -324,8 +421,7 @@ impl MmPerformanceTracker {
     /// Returns `MmPerformanceError::Repository` if data cannot be retrieved.
     pub async fn get_all_metrics(&self) -> MmPerformanceResult<Vec<MmPerformanceMetrics>> {
         let all_ids = self.repository.get_all_mm_ids().await?;
-        let now = Timestamp::now();
-        let window_start = now.sub_secs(i64::from(self.window_days) * 86400);
+        let (window_start, now) = self.window();
         let mut all_metrics = Vec::with_capacity(all_ids.len());
 
         for mm_id in &all_ids {
@@ -353,15 +449,22 @@ impl MmPerformanceTracker {
     ///
     /// Returns `MmPerformanceError::Repository` if trimming fails.
     pub async fn trim_old_events(&self) -> MmPerformanceResult<u64> {
-        let cutoff = Timestamp::now().sub_secs(i64::from(self.window_days) * 86400);
+        let (cutoff, _) = self.window();
         self.repository.trim_before(cutoff).await
     }
+
+    /// Returns the `(start, end)` bounds of the rolling window ending now.
+    fn window(&self) -> (Timestamp, Timestamp) {
+        let now = self.clock.now();
+        (now.sub_secs(i64::from(self.window_days) * 86400), now)
+    }
 }
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::domain::value_objects::timestamp::MockClock;
     use dashmap::DashMap;
 
     /// In-memory implementation for testing.
@@ -424,10 +527,21 @@ mod tests {
     }
 
     fn create_tracker() -> (MmPerformanceTracker, Arc<MockMmPerformanceRepo>) {
+        let (tracker, repo, _) = create_tracker_with_clock();
+        (tracker, repo)
+    }
+
+    fn create_tracker_with_clock() -> (
+        MmPerformanceTracker,
+        Arc<MockMmPerformanceRepo>,
+        Arc<MockClock>,
+    ) {
         let repo = Arc::new(MockMmPerformanceRepo::default());
+        let clock = Arc::new(MockClock::new(Timestamp::from_secs(1_700_000_000).unwrap()));
         let tracker =
-            MmPerformanceTracker::new(Arc::clone(&repo) as Arc<dyn MmPerformanceRepository>, 7);
-        (tracker, repo)
+            MmPerformanceTracker::new(Arc::clone(&repo) as Arc<dyn MmPerformanceRepository>, 7)
+                .with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+        (tracker, repo, clock)
     }
 
     mod construction {
@@ -648,6 +762,96 @@ mod tests {
             let events = repo.events.get("mm-trim").unwrap();
             assert_eq!(events.len(), 1);
         }
+
+        #[tokio::test]
+        async fn window_follows_clock() {
+            let (tracker, repo, clock) = create_tracker_with_clock();
+            let id = mm_id("mm-window");
+
+            assert!(tracker.record_rfq_sent(&id).await.is_ok());
+            clock.advance_secs(3 * 86400);
+            assert!(tracker.record_rfq_sent(&id).await.is_ok());
+
+            assert_eq!(
+                tracker
+                    .get_metrics(&id)
+                    .await
+                    .unwrap()
+                    .total_rfqs_received(),
+                2
+            );
+
+            // Five more days pushes the first event out of the 7-day window
+            clock.advance_secs(5 * 86400);
+            assert_eq!(
+                tracker
+                    .get_metrics(&id)
+                    .await
+                    .unwrap()
+                    .total_rfqs_received(),
+                1
+            );
+
+            assert_eq!(tracker.trim_old_events().await.unwrap(), 1);
+            assert_eq!(repo.events.get("mm-window").unwrap().len(), 1);
+        }
+    }
+
+    mod cancel_grace {
+        use super::*;
+
+        #[tokio::test]
+        async fn quote_within_grace_withdraws_rfq_once() {
+            let (tracker, _repo, clock) = create_tracker_with_clock();
+            let id = mm_id("mm-late");
+            let rfq_id = RfqId::new_v4();
+
+            tracker.record_rfq_sent(&id).await.unwrap();
+            let cancelled_at = clock.now();
+            clock.advance_secs(1);
+
+            assert!(
+                tracker
+                    .record_late_quote(&id, rfq_id, cancelled_at)
+                    .await
+                    .unwrap()
+            );
+            assert!(
+                tracker
+                    .record_late_quote(&id, rfq_id, cancelled_at)
+                    .await
+                    .unwrap()
+            );
+
+            let metrics = tracker.get_metrics(&id).await.unwrap();
+            assert_eq!(metrics.total_rfqs_received(), 0);
+            assert!(metrics.response_rate_pct().is_none());
+        }
+
+        #[tokio::test]
+        async fn grace_window_boundary() {
+            let (tracker, repo, clock) = create_tracker_with_clock();
+            let tracker = tracker.with_cancel_grace_period(Duration::from_secs(2));
+            let id = mm_id("mm-edge");
+            let cancelled_at = clock.now();
+
+            clock.advance(Duration::from_secs(2));
+            assert!(
+                tracker
+                    .record_late_quote(&id, RfqId::new_v4(), cancelled_at)
+                    .await
+                    .unwrap()
+            );
+
+            clock.advance(Duration::from_millis(1));
+            assert!(
+                !tracker
+                    .record_late_quote(&id, RfqId::new_v4(), cancelled_at)
+                    .await
+                    .unwrap()
+            );
+            assert_eq!(repo.events.get("mm-edge").unwrap().len(), 1);
+        }
     }
 
     mod errors {
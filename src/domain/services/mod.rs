@@ -6,6 +6,7 @@
 //! ## Services
 //!
 //! - [`mm_performance::MmPerformanceTracker`]: Market maker performance tracking
+//! - [`negotiation_analytics`]: Price improvement analytics for negotiations
 //! - [`block_trade`]: Block trade size thresholds and reporting tiers
 //! - [`block_trade_service`]: Bilateral block trade validation
 //! - [`acceptance_flow`]: Atomic quote acceptance workflow
@@ -36,6 +37,7 @@ pub mod market_calendar;
 pub mod mm_incentive_service;
 pub mod mm_performance;
 pub mod multi_leg_executor;
+pub mod negotiation_analytics;
 pub mod off_book_executor;
 pub mod package_quote_validator;
 pub mod position_service;
@@ -89,6 +91,9 @@ pub use multi_leg_executor::{
     LegExecutor, MultiLegExecutionResult, MultiLegExecutionState, MultiLegExecutor,
     MultiLegExecutorConfig,
 };
+pub use negotiation_analytics::{
+    NegotiationAnalytics, NegotiationAnalyticsReport, NegotiationSummary,
+};
 pub use package_quote_validator::{DEFAULT_TOLERANCE_BPS, PackageQuoteValidator};
 pub use quote_normalizer::QuoteNormalizer;
 pub use resource_lock::{ResourceLock, sort_locks};
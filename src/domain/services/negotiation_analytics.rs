@@ -0,0 +1,362 @@
+//! # Negotiation Analytics
+//!
+//! Quantifies how much counter-quote negotiation saves clients.
+//!
+//! [`NegotiationAnalytics::summarize`] derives the outcome of a single
+//! completed [`Negotiation`]: price improvement from the first counter to
+//! the accepted price, rounds used, and time to agreement.
+//! [`NegotiationAnalytics::report`] aggregates summaries into averages and
+//! improvement percentiles.
+//!
+//! Improvement is signed and side-aware: a positive value means the client
+//! got a better price than the first counter (lower when buying, higher
+//! when selling). Rejected and expired negotiations have no agreed price,
+//! so they are excluded from the improvement and time averages and only
+//! count toward the failure rate.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use otc_rfq::domain::services::negotiation_analytics::NegotiationAnalytics;
+//!
+//! let negotiations = repository.find_completed_between(Some(&mm), from, to).await?;
+//! let report = NegotiationAnalytics::report(&negotiations);
+//! println!("p50 improvement: {:?} bps", report.p50_improvement_bps);
+//! ```
+
+use crate::domain::entities::negotiation::Negotiation;
+use crate::domain::value_objects::{
+    CounterpartyId, NegotiationId, NegotiationState, OrderSide, Price,
+};
+use rust_decimal::Decimal;
+
+/// Basis points per unit of relative price change.
+const BPS_PER_UNIT: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// Analytics for a single completed negotiation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegotiationSummary {
+    /// The negotiation summarized.
+    pub negotiation_id: NegotiationId,
+    /// The market maker in the negotiation.
+    pub mm_account: CounterpartyId,
+    /// The terminal state reached.
+    pub state: NegotiationState,
+    /// Counter-quote rounds exchanged.
+    pub rounds_used: usize,
+    /// Maximum rounds allowed.
+    pub max_rounds: u8,
+    /// Price of the first counter-quote, if any was submitted.
+    pub first_price: Option<Price>,
+    /// Accepted price; `None` unless the negotiation was accepted.
+    pub agreed_price: Option<Price>,
+    /// Signed, side-aware improvement of the agreed price over the first
+    /// counter, in price units.
+    pub price_improvement: Option<Decimal>,
+    /// Improvement relative to the first counter, in basis points.
+    pub improvement_bps: Option<Decimal>,
+    /// Milliseconds from creation to acceptance; `None` unless accepted.
+    pub time_to_agreement_ms: Option<i64>,
+}
+
+impl NegotiationSummary {
+    /// Returns true if the negotiation ended in agreement.
+    #[inline]
+    #[must_use]
+    pub fn is_accepted(&self) -> bool {
+        self.state == NegotiationState::Accepted
+    }
+}
+
+/// Aggregated negotiation analytics over a set of completed negotiations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NegotiationAnalyticsReport {
+    /// Completed negotiations considered.
+    pub total: usize,
+    /// Negotiations that ended in agreement.
+    pub accepted: usize,
+    /// Negotiations rejected by either party.
+    pub rejected: usize,
+    /// Negotiations that expired without agreement.
+    pub expired: usize,
+    /// Share of negotiations that were rejected or expired (0–1).
+    pub failure_rate: Option<Decimal>,
+    /// Mean improvement of accepted negotiations, in basis points.
+    pub avg_improvement_bps: Option<Decimal>,
+    /// Median improvement of accepted negotiations, in basis points.
+    pub p50_improvement_bps: Option<Decimal>,
+    /// 90th percentile improvement of accepted negotiations, in basis points.
+    pub p90_improvement_bps: Option<Decimal>,
+    /// Mean rounds used by accepted negotiations.
+    pub avg_rounds_used: Option<Decimal>,
+    /// Mean milliseconds from creation to acceptance.
+    pub avg_time_to_agreement_ms: Option<i64>,
+}
+
+/// Price improvement analytics for counter-quote negotiations.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NegotiationAnalytics;
+
+impl NegotiationAnalytics {
+    /// Summarizes a completed negotiation.
+    ///
+    /// Returns `None` if the negotiation has not reached a terminal state.
+    #[must_use]
+    pub fn summarize(negotiation: &Negotiation) -> Option<NegotiationSummary> {
+        let state = negotiation.state();
+        if !state.is_terminal() {
+            return None;
+        }
+
+        let first_price = negotiation
+            .rounds()
+            .first()
+            .map(|round| round.counter_quote().price());
+        let accepted = state == NegotiationState::Accepted;
+        let agreed_price = if accepted {
+            negotiation.latest_price()
+        } else {
+            None
+        };
+
+        let price_improvement =
+            first_price
+                .zip(agreed_price)
+                .map(|(first, agreed)| match negotiation.side() {
+                    OrderSide::Buy => first.get() - agreed.get(),
+                    OrderSide::Sell => agreed.get() - first.get(),
+                });
+        let improvement_bps = price_improvement
+            .zip(first_price)
+            .and_then(|(delta, first)| {
+                delta
+                    .checked_div(first.get())
+                    .and_then(|ratio| ratio.checked_mul(BPS_PER_UNIT))
+            });
+        let time_to_agreement_ms = accepted.then(|| {
+            negotiation.updated_at().timestamp_millis()
+                - negotiation.created_at().timestamp_millis()
+        });
+
+        Some(NegotiationSummary {
+            negotiation_id: negotiation.id(),
+            mm_account: negotiation.mm_account().clone(),
+            state,
+            rounds_used: negotiation.round_count(),
+            max_rounds: negotiation.max_rounds(),
+            first_price,
+            agreed_price,
+            price_improvement,
+            improvement_bps,
+            time_to_agreement_ms,
+        })
+    }
+
+    /// Aggregates the completed negotiations among `negotiations`.
+    ///
+    /// Negotiations still in progress are ignored.
+    #[must_use]
+    pub fn report(negotiations: &[Negotiation]) -> NegotiationAnalyticsReport {
+        let summaries: Vec<NegotiationSummary> =
+            negotiations.iter().filter_map(Self::summarize).collect();
+        Self::aggregate(&summaries)
+    }
+
+    /// Aggregates negotiation summaries into a report.
+    #[must_use]
+    pub fn aggregate(summaries: &[NegotiationSummary]) -> NegotiationAnalyticsReport {
+        let count = |state| summaries.iter().filter(|s| s.state == state).count();
+        let accepted = count(NegotiationState::Accepted);
+        let rejected = count(NegotiationState::Rejected);
+        let expired = count(NegotiationState::Expired);
+        let total = summaries.len();
+
+        let failure_rate = Decimal::from(rejected + expired).checked_div(Decimal::from(total));
+
+        let mut improvements: Vec<Decimal> =
+            summaries.iter().filter_map(|s| s.improvement_bps).collect();
+        improvements.sort();
+
+        let agreed: Vec<&NegotiationSummary> =
+            summaries.iter().filter(|s| s.is_accepted()).collect();
+        let avg_rounds_used = mean(agreed.iter().map(|s| Decimal::from(s.rounds_used)));
+        let times: Vec<i64> = agreed
+            .iter()
+            .filter_map(|s| s.time_to_agreement_ms)
+            .collect();
+        let avg_time_to_agreement_ms = i64::try_from(times.len())
+            .ok()
+            .and_then(|n| times.iter().sum::<i64>().checked_div(n));
+
+        NegotiationAnalyticsReport {
+            total,
+            accepted,
+            rejected,
+            expired,
+            failure_rate,
+            avg_improvement_bps: mean(improvements.iter().copied()),
+            p50_improvement_bps: percentile(&improvements, 50),
+            p90_improvement_bps: percentile(&improvements, 90),
+            avg_rounds_used,
+            avg_time_to_agreement_ms,
+        }
+    }
+}
+
+/// Mean of `values`, or `None` if empty.
+fn mean(values: impl Iterator<Item = Decimal>) -> Option<Decimal> {
+    let (sum, n) = values.fold((Decimal::ZERO, 0usize), |(sum, n), v| (sum + v, n + 1));
+    sum.checked_div(Decimal::from(n))
+}
+
+/// Nearest-rank percentile of ascending `sorted`, or `None` if empty.
+fn percentile(sorted: &[Decimal], pct: usize) -> Option<Decimal> {
+    let rank = (pct * sorted.len()).div_ceil(100);
+    sorted.get(rank.saturating_sub(1)).copied()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::counter_quote::CounterQuoteBuilder;
+    use crate::domain::entities::negotiation::NegotiationRound;
+    use crate::domain::value_objects::timestamp::Timestamp;
+    use crate::domain::value_objects::{Quantity, QuoteId, RfqId};
+
+    /// A terminal negotiation whose counters ran through `prices`, lasting
+    /// `duration_secs`.
+    fn negotiation(
+        side: OrderSide,
+        prices: &[f64],
+        state: NegotiationState,
+        duration_secs: i64,
+    ) -> Negotiation {
+        let rfq_id = RfqId::new_v4();
+        let created_at = Timestamp::now();
+        let rounds = prices
+            .iter()
+            .zip(1u8..)
+            .map(|(price, round)| {
+                let counter = CounterQuoteBuilder::new(
+                    QuoteId::new_v4(),
+                    rfq_id,
+                    CounterpartyId::new("mm-1"),
+                    Price::new(*price).unwrap(),
+                    Quantity::new(1.0).unwrap(),
+                    created_at.add_secs(300),
+                    round,
+                )
+                .build()
+                .unwrap();
+                NegotiationRound::new(round, counter)
+            })
+            .collect();
+        Negotiation::from_parts(
+            NegotiationId::new_v4(),
+            rfq_id,
+            CounterpartyId::new("client-1"),
+            CounterpartyId::new("mm-1"),
+            side,
+            rounds,
+            3,
+            state,
+            created_at,
+            created_at.add_secs(duration_secs),
+        )
+    }
+
+    #[test]
+    fn accepted_buy_improves_when_price_falls() {
+        let neg = negotiation(
+            OrderSide::Buy,
+            &[100.0, 99.5, 99.0],
+            NegotiationState::Accepted,
+            45,
+        );
+
+        let summary = NegotiationAnalytics::summarize(&neg).unwrap();
+        assert_eq!(summary.rounds_used, 3);
+        assert_eq!(summary.price_improvement, Some(Decimal::from(1)));
+        assert_eq!(summary.improvement_bps, Some(Decimal::from(100)));
+        assert_eq!(summary.time_to_agreement_ms, Some(45_000));
+    }
+
+    #[test]
+    fn accepted_sell_improves_when_price_rises() {
+        let neg = negotiation(
+            OrderSide::Sell,
+            &[200.0, 201.0],
+            NegotiationState::Accepted,
+            10,
+        );
+
+        let summary = NegotiationAnalytics::summarize(&neg).unwrap();
+        assert_eq!(summary.price_improvement, Some(Decimal::from(1)));
+        assert_eq!(summary.improvement_bps, Some(Decimal::from(50)));
+    }
+
+    #[test]
+    fn open_negotiation_is_not_summarized() {
+        let neg = negotiation(
+            OrderSide::Buy,
+            &[100.0],
+            NegotiationState::CounterPending,
+            5,
+        );
+        assert!(NegotiationAnalytics::summarize(&neg).is_none());
+    }
+
+    #[test]
+    fn report_excludes_failures_from_improvement() {
+        let negotiations = [
+            negotiation(
+                OrderSide::Buy,
+                &[100.0, 99.0],
+                NegotiationState::Accepted,
+                30,
+            ),
+            negotiation(
+                OrderSide::Buy,
+                &[100.0, 98.0],
+                NegotiationState::Rejected,
+                60,
+            ),
+            negotiation(OrderSide::Sell, &[50.0], NegotiationState::Expired, 90),
+        ];
+
+        let rejected = NegotiationAnalytics::summarize(&negotiations[1]).unwrap();
+        assert_eq!(rejected.agreed_price, None);
+        assert_eq!(rejected.improvement_bps, None);
+        assert_eq!(rejected.time_to_agreement_ms, None);
+
+        let report = NegotiationAnalytics::report(&negotiations);
+        assert_eq!(report.total, 3);
+        assert_eq!(report.accepted, 1);
+        assert_eq!(report.rejected, 1);
+        assert_eq!(report.expired, 1);
+        assert_eq!(
+            report.failure_rate.unwrap().round_dp(4),
+            Decimal::new(6667, 4)
+        );
+        assert_eq!(report.avg_improvement_bps, Some(Decimal::from(100)));
+        assert_eq!(report.p50_improvement_bps, Some(Decimal::from(100)));
+        assert_eq!(report.p90_improvement_bps, Some(Decimal::from(100)));
+        assert_eq!(report.avg_rounds_used, Some(Decimal::from(2)));
+        assert_eq!(report.avg_time_to_agreement_ms, Some(30_000));
+    }
+
+    #[test]
+    fn percentiles_use_nearest_rank() {
+        let sorted: Vec<Decimal> = (1..=10).map(Decimal::from).collect();
+        assert_eq!(percentile(&sorted, 50), Some(Decimal::from(5)));
+        assert_eq!(percentile(&sorted, 90), Some(Decimal::from(9)));
+        assert_eq!(percentile(&[], 50), None);
+    }
+
+    #[test]
+    fn empty_report_has_no_rates() {
+        let report = NegotiationAnalytics::report(&[]);
+        assert_eq!(report, NegotiationAnalyticsReport::default());
+    }
+}
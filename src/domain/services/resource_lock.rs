@@ -8,14 +8,15 @@
 //! # Lock Ordering
 //!
 //! To prevent deadlocks, locks must always be acquired in a deterministic order:
-//! 1. Quote locks (by QuoteId)
-//! 2. Account locks (by CounterpartyId)
-//! 3. Instrument locks (by Instrument)
+//! 1. RFQ locks (by RfqId)
+//! 2. Quote locks (by QuoteId)
+//! 3. Account locks (by CounterpartyId)
+//! 4. Instrument locks (by Instrument)
 //!
 //! Within each category, locks are ordered lexicographically by their string
 //! representation.
 
-use crate::domain::value_objects::{CounterpartyId, Instrument, QuoteId};
+use crate::domain::value_objects::{CounterpartyId, Instrument, QuoteId, RfqId};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::fmt;
@@ -27,6 +28,8 @@ use std::hash::{Hash, Hasher};
 /// preventing deadlocks in concurrent scenarios.
 #[derive(Debug, Clone, Eq, Serialize, Deserialize)]
 pub enum ResourceLock {
+    /// Lock on an RFQ, held for the duration of its execution.
+    Rfq(RfqId),
     /// Lock on a specific quote.
     Quote(QuoteId),
     /// Lock on a counterparty account.
@@ -38,14 +41,15 @@ pub enum ResourceLock {
 impl ResourceLock {
     /// Returns the lock category for ordering purposes.
     ///
-    /// Categories are ordered: Quote (0) < Account (1) < Instrument (2)
+    /// Categories are ordered: Rfq (0) < Quote (1) < Account (2) < Instrument (3)
     #[must_use]
     #[inline]
     fn category(&self) -> u8 {
         match self {
-            Self::Quote(_) => 0,
-            Self::Account(_) => 1,
-            Self::Instrument(_) => 2,
+            Self::Rfq(_) => 0,
+            Self::Quote(_) => 1,
+            Self::Account(_) => 2,
+            Self::Instrument(_) => 3,
         }
     }
 
@@ -53,12 +57,20 @@ impl ResourceLock {
     #[must_use]
     fn sort_key(&self) -> String {
         match self {
+            Self::Rfq(id) => id.to_string(),
             Self::Quote(id) => id.to_string(),
             Self::Account(id) => id.to_string(),
             Self::Instrument(inst) => inst.symbol().to_string(),
         }
     }
 
+    /// Returns true if this is an RFQ lock.
+    #[must_use]
+    #[inline]
+    pub fn is_rfq(&self) -> bool {
+        matches!(self, Self::Rfq(_))
+    }
+
     /// Returns true if this is a quote lock.
     #[must_use]
     #[inline]
@@ -80,6 +92,15 @@ impl ResourceLock {
         matches!(self, Self::Instrument(_))
     }
 
+    /// Returns the RFQ ID if this is an RFQ lock.
+    #[must_use]
+    pub fn as_rfq_id(&self) -> Option<&RfqId> {
+        match self {
+            Self::Rfq(id) => Some(id),
+            _ => None,
+        }
+    }
+
     /// Returns the quote ID if this is a quote lock.
     #[must_use]
     pub fn as_quote_id(&self) -> Option<&QuoteId> {
@@ -111,6 +132,7 @@ impl ResourceLock {
 impl PartialEq for ResourceLock {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
+            (Self::Rfq(a), Self::Rfq(b)) => a == b,
             (Self::Quote(a), Self::Quote(b)) => a == b,
             (Self::Account(a), Self::Account(b)) => a == b,
             (Self::Instrument(a), Self::Instrument(b)) => a == b,
@@ -123,6 +145,7 @@ impl Hash for ResourceLock {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.category().hash(state);
         match self {
+            Self::Rfq(id) => id.hash(state),
             Self::Quote(id) => id.hash(state),
             Self::Account(id) => id.hash(state),
             Self::Instrument(inst) => {
@@ -152,6 +175,7 @@ impl PartialOrd for ResourceLock {
 impl fmt::Display for ResourceLock {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::Rfq(id) => write!(f, "Rfq({})", id),
             Self::Quote(id) => write!(f, "Quote({})", id),
             Self::Account(id) => write!(f, "Account({})", id),
             Self::Instrument(inst) => write!(f, "Instrument({})", inst.symbol()),
@@ -198,10 +222,12 @@ mod tests {
 
     #[test]
     fn resource_lock_category_ordering() {
+        let rfq = ResourceLock::Rfq(RfqId::new_v4());
         let quote = ResourceLock::Quote(QuoteId::new_v4());
         let account = ResourceLock::Account(CounterpartyId::new("test"));
         let instrument = ResourceLock::Instrument(create_instrument("BTC/USD"));
 
+        assert!(rfq < quote);
         assert!(quote < account);
         assert!(account < instrument);
         assert!(quote < instrument);
@@ -262,6 +288,16 @@ mod tests {
         assert_eq!(format!("{}", instrument), "Instrument(BTC/USD)");
     }
 
+    #[test]
+    fn rfq_lock_accessors() {
+        let id = RfqId::new_v4();
+        let lock = ResourceLock::Rfq(id);
+        assert!(lock.is_rfq());
+        assert!(!lock.is_quote());
+        assert_eq!(lock.as_rfq_id(), Some(&id));
+        assert_eq!(lock.to_string(), format!("Rfq({id})"));
+    }
+
     #[test]
     fn resource_lock_type_checks() {
         let quote = ResourceLock::Quote(QuoteId::new_v4());
@@ -0,0 +1,268 @@
+//! # Aggregation Report
+//!
+//! What happened with each venue asked to quote an RFQ.
+//!
+//! An [`AggregationReport`] lists one [`VenueOutcome`] per venue the quote
+//! aggregation engine considered: whether it responded and how fast, timed
+//! out, failed, had its quotes filtered, or was excluded before being asked.
+//! [`VenueOutcomeCounts`] summarises a report for events and dashboards.
+//!
+//! A report also keeps a [`QuoteRanking`] for each ranked quote: its rank,
+//! score and the [`ScoreComponent`]s the score was made of, so a ranking
+//! can be explained after the fact.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::{
+//!     VenueExclusionReason, VenueId, VenueOutcome, VenueOutcomeCounts, VenueOutcomeKind,
+//! };
+//!
+//! let outcomes = vec![
+//!     VenueOutcome::new(VenueId::new("slow"), VenueOutcomeKind::Timeout),
+//!     VenueOutcome::new(
+//!         VenueId::new("masked"),
+//!         VenueOutcomeKind::Excluded {
+//!             reason: VenueExclusionReason::SizeNotMaskable,
+//!         },
+//!     ),
+//! ];
+//! let counts = VenueOutcomeCounts::from_outcomes(&outcomes);
+//! assert_eq!(counts.timed_out, 1);
+//! assert_eq!(counts.excluded, 1);
+//! assert_eq!(counts.failed(), 1);
+//! ```
+
+use crate::domain::value_objects::score_breakdown::ScoreComponent;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{QuoteId, RfqId, VenueExclusionReason, VenueId};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// What a quote's price was checked against when it was rejected as
+/// implausible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PriceSanityBasis {
+    /// The instrument's reference price.
+    ReferencePrice,
+    /// The median price of the quotes received on the same side.
+    QuoteMedian,
+}
+
+impl fmt::Display for PriceSanityBasis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ReferencePrice => write!(f, "reference price"),
+            Self::QuoteMedian => write!(f, "quote median"),
+        }
+    }
+}
+
+/// What happened when a venue was asked, or not asked, to quote.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VenueOutcomeKind {
+    /// The venue returned at least one usable quote.
+    Responded {
+        /// Time from request to response, in milliseconds. `None` for quotes
+        /// reused from an earlier RFQ and for package quotes, whose latency
+        /// is not measured.
+        latency_ms: Option<u64>,
+        /// ID of the venue's first quote; a package quote ID for multi-leg
+        /// RFQs.
+        quote_id: String,
+    },
+    /// The venue did not answer within the per-venue timeout.
+    Timeout,
+    /// The venue answered with an error.
+    Error {
+        /// The error message.
+        reason: String,
+    },
+    /// Every quote from the venue had expired by the time it was ranked.
+    FilteredExpired,
+    /// Every quote from the venue expired within the validity floor.
+    FilteredShortTtl,
+    /// Every quote from the venue was priced too far from the market to be
+    /// credible.
+    FilteredPriceDeviation {
+        /// What the prices were compared against.
+        basis: PriceSanityBasis,
+        /// Largest deviation among the venue's quotes (fractional, e.g.
+        /// 0.25 = 25%).
+        deviation_pct: Decimal,
+    },
+    /// The venue was not asked.
+    Excluded {
+        /// Why the venue was left out.
+        reason: VenueExclusionReason,
+    },
+}
+
+/// The outcome for one venue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VenueOutcome {
+    /// The venue.
+    pub venue_id: VenueId,
+    /// What happened.
+    #[serde(flatten)]
+    pub outcome: VenueOutcomeKind,
+}
+
+impl VenueOutcome {
+    /// Creates a venue outcome.
+    #[must_use]
+    pub fn new(venue_id: VenueId, outcome: VenueOutcomeKind) -> Self {
+        Self { venue_id, outcome }
+    }
+}
+
+/// Number of venues per outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct VenueOutcomeCounts {
+    /// Venues that returned a usable quote.
+    pub responded: u32,
+    /// Venues that timed out.
+    pub timed_out: u32,
+    /// Venues that answered with an error.
+    pub errored: u32,
+    /// Venues whose quotes were all filtered out.
+    pub filtered: u32,
+    /// Venues that were not asked.
+    pub excluded: u32,
+}
+
+impl VenueOutcomeCounts {
+    /// Tallies `outcomes`.
+    #[must_use]
+    pub fn from_outcomes(outcomes: &[VenueOutcome]) -> Self {
+        outcomes
+            .iter()
+            .fold(Self::default(), |mut counts, outcome| {
+                let count = match outcome.outcome {
+                    VenueOutcomeKind::Responded { .. } => &mut counts.responded,
+                    VenueOutcomeKind::Timeout => &mut counts.timed_out,
+                    VenueOutcomeKind::Error { .. } => &mut counts.errored,
+                    VenueOutcomeKind::FilteredExpired
+                    | VenueOutcomeKind::FilteredShortTtl
+                    | VenueOutcomeKind::FilteredPriceDeviation { .. } => &mut counts.filtered,
+                    VenueOutcomeKind::Excluded { .. } => &mut counts.excluded,
+                };
+                *count = count.saturating_add(1);
+                counts
+            })
+    }
+
+    /// Returns the number of venues that timed out or errored.
+    #[must_use]
+    pub const fn failed(&self) -> u32 {
+        self.timed_out.saturating_add(self.errored)
+    }
+}
+
+/// How a quote was ranked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuoteRanking {
+    /// The ranked quote.
+    pub quote_id: QuoteId,
+    /// The venue that quoted.
+    pub venue_id: VenueId,
+    /// The rank (1 = best).
+    pub rank: usize,
+    /// The score the quote was ranked on (higher = better).
+    pub score: Decimal,
+    /// The factors that made up the score; empty for strategies that do not
+    /// break their scores down.
+    pub score_breakdown: Vec<ScoreComponent>,
+}
+
+/// Per-venue outcomes of aggregating quotes for an RFQ.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AggregationReport {
+    /// The RFQ the quotes were aggregated for.
+    pub rfq_id: RfqId,
+    /// One outcome per venue, ordered by venue ID.
+    pub venue_outcomes: Vec<VenueOutcome>,
+    /// How each ranked quote was ranked, best first.
+    #[serde(default)]
+    pub quote_rankings: Vec<QuoteRanking>,
+    /// When aggregation finished.
+    pub generated_at: Timestamp,
+}
+
+impl AggregationReport {
+    /// Creates a report, ordering the outcomes by venue ID.
+    #[must_use]
+    pub fn new(
+        rfq_id: RfqId,
+        mut venue_outcomes: Vec<VenueOutcome>,
+        generated_at: Timestamp,
+    ) -> Self {
+        venue_outcomes.sort_by(|a, b| a.venue_id.as_str().cmp(b.venue_id.as_str()));
+        Self {
+            rfq_id,
+            venue_outcomes,
+            quote_rankings: Vec::new(),
+            generated_at,
+        }
+    }
+
+    /// Records how the quotes were ranked, ordering them by rank.
+    #[must_use]
+    pub fn with_quote_rankings(mut self, mut quote_rankings: Vec<QuoteRanking>) -> Self {
+        quote_rankings.sort_by_key(|ranking| ranking.rank);
+        self.quote_rankings = quote_rankings;
+        self
+    }
+
+    /// Returns how `quote_id` was ranked, if it was.
+    #[must_use]
+    pub fn quote_ranking(&self, quote_id: QuoteId) -> Option<&QuoteRanking> {
+        self.quote_rankings
+            .iter()
+            .find(|ranking| ranking.quote_id == quote_id)
+    }
+
+    /// Returns the outcome counts.
+    #[must_use]
+    pub fn counts(&self) -> VenueOutcomeCounts {
+        VenueOutcomeCounts::from_outcomes(&self.venue_outcomes)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::QuoteId;
+
+    #[test]
+    fn outcome_serializes_flat_with_status_tag() {
+        let quote_id = QuoteId::new_v4().to_string();
+        let outcome = VenueOutcome::new(
+            VenueId::new("venue-1"),
+            VenueOutcomeKind::Responded {
+                latency_ms: Some(42),
+                quote_id,
+            },
+        );
+
+        let json = serde_json::to_value(&outcome).unwrap();
+        assert_eq!(json["venue_id"], "venue-1");
+        assert_eq!(json["status"], "RESPONDED");
+        assert_eq!(json["latency_ms"], 42);
+        assert_eq!(
+            serde_json::from_value::<VenueOutcome>(json).unwrap(),
+            outcome
+        );
+
+        let excluded = VenueOutcomeKind::Excluded {
+            reason: VenueExclusionReason::SizeNotMaskable,
+        };
+        let json = serde_json::to_value(VenueOutcome::new(VenueId::new("v"), excluded)).unwrap();
+        assert_eq!(json["status"], "EXCLUDED");
+        assert_eq!(json["reason"], "SIZE_NOT_MASKABLE");
+    }
+}
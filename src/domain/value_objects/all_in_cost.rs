@@ -0,0 +1,120 @@
+//! # All-In Cost Value Object
+//!
+//! A quote's price after the gas paid to settle it on-chain.
+//!
+//! Quotes from DeFi venues settle in a transaction whose gas the taker pays,
+//! so a better headline price on mainnet can lose to a worse one on an L2.
+//! [`AllInCost`] records that gas, converted to the quote currency, and the
+//! per-unit price it implies for the quote's side. When the gas could not be
+//! estimated the all-in price is the raw price and the cost is marked
+//! unestimated, so callers can tell the two apart.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::all_in_cost::AllInCost;
+//! use otc_rfq::domain::value_objects::OrderSide;
+//! use rust_decimal::Decimal;
+//!
+//! // 30 USDC of gas spread over 10 units bought at 2000.
+//! let cost = AllInCost::estimated(
+//!     Decimal::from(2000),
+//!     Decimal::from(30),
+//!     Decimal::from(10),
+//!     OrderSide::Buy,
+//! );
+//! assert_eq!(cost.all_in_price(), Decimal::from(2003));
+//! assert!(cost.is_estimated());
+//! ```
+
+use crate::domain::value_objects::OrderSide;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Gas-inclusive price of a quote.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllInCost {
+    /// Gas to settle the quote, in the quote currency, if estimated.
+    gas_cost: Option<Decimal>,
+    /// Per-unit price including gas, or the raw price without an estimate.
+    all_in_price: Decimal,
+}
+
+impl AllInCost {
+    /// Creates the all-in cost of trading `quantity` at `price` on `side`.
+    ///
+    /// Gas raises the effective price of a buy and lowers that of a sell.
+    /// A zero quantity leaves the price unchanged.
+    #[must_use]
+    pub fn estimated(
+        price: Decimal,
+        gas_cost: Decimal,
+        quantity: Decimal,
+        side: OrderSide,
+    ) -> Self {
+        let per_unit = gas_cost.checked_div(quantity).unwrap_or(Decimal::ZERO);
+        let all_in_price = match side {
+            OrderSide::Buy => price.saturating_add(per_unit),
+            OrderSide::Sell => price.saturating_sub(per_unit),
+        };
+        Self {
+            gas_cost: Some(gas_cost),
+            all_in_price,
+        }
+    }
+
+    /// Creates the cost of a quote whose gas could not be estimated.
+    #[must_use]
+    pub fn unestimated(price: Decimal) -> Self {
+        Self {
+            gas_cost: None,
+            all_in_price: price,
+        }
+    }
+
+    /// Returns the gas cost in the quote currency, if estimated.
+    #[inline]
+    #[must_use]
+    pub fn gas_cost(&self) -> Option<Decimal> {
+        self.gas_cost
+    }
+
+    /// Returns the per-unit price including gas.
+    #[inline]
+    #[must_use]
+    pub fn all_in_price(&self) -> Decimal {
+        self.all_in_price
+    }
+
+    /// Returns true if the gas was estimated.
+    #[inline]
+    #[must_use]
+    pub fn is_estimated(&self) -> bool {
+        self.gas_cost.is_some()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gas_lowers_the_price_of_a_sale() {
+        let cost = AllInCost::estimated(
+            Decimal::from(2000),
+            Decimal::from(30),
+            Decimal::from(10),
+            OrderSide::Sell,
+        );
+        assert_eq!(cost.all_in_price(), Decimal::from(1997));
+        assert_eq!(cost.gas_cost(), Some(Decimal::from(30)));
+    }
+
+    #[test]
+    fn unestimated_cost_keeps_the_raw_price() {
+        let cost = AllInCost::unestimated(Decimal::from(2000));
+        assert_eq!(cost.all_in_price(), Decimal::from(2000));
+        assert!(!cost.is_estimated());
+    }
+}
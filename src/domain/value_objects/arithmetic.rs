@@ -7,6 +7,7 @@
 //! - [`CheckedArithmetic`] - Trait for safe arithmetic operations
 //! - [`Rounding`] - Enum for explicit rounding direction
 //! - [`div_round`] - Helper function for division with rounding
+//! - [`bps_of`], [`percent_of`] - Basis-point and percentage scaling with rounding
 //!
 //! # Examples
 //!
@@ -20,7 +21,7 @@
 //! assert!(result.is_ok());
 //! ```
 
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use thiserror::Error;
@@ -151,6 +152,70 @@ pub fn div_round(
     }
 }
 
+/// Basis points per unit (1 bp = 0.0001).
+pub const BPS_PER_UNIT: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// Decimal places kept by [`bps_of`] and [`percent_of`].
+pub const SCALED_RESULT_DP: u32 = 8;
+
+/// Rounds to `dp` decimal places in the given direction.
+///
+/// Values with at most `dp` decimal places are returned unchanged.
+#[inline]
+#[must_use]
+pub fn round_dp(value: Decimal, dp: u32, rounding: Rounding) -> Decimal {
+    let strategy = match rounding {
+        Rounding::Down => RoundingStrategy::ToZero,
+        Rounding::Up => RoundingStrategy::AwayFromZero,
+    };
+    value.round_dp_with_strategy(dp, strategy)
+}
+
+/// Computes `value × bps / 10,000`, rounded to [`SCALED_RESULT_DP`] places.
+///
+/// # Errors
+///
+/// Returns `ArithmeticError::Overflow` if the product would overflow.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::arithmetic::{Rounding, bps_of};
+/// use rust_decimal::Decimal;
+///
+/// // 3 bps of 1,000,000 = 300
+/// let fee = bps_of(Decimal::new(1_000_000, 0), 3, Rounding::Down).unwrap();
+/// assert_eq!(fee, Decimal::new(300, 0));
+/// ```
+#[inline]
+#[must_use = "this returns the result of the operation, without modifying the original"]
+pub fn bps_of(value: Decimal, bps: i64, rounding: Rounding) -> ArithmeticResult<Decimal> {
+    let scaled = value.safe_mul(Decimal::from(bps))?.safe_div(BPS_PER_UNIT)?;
+    Ok(round_dp(scaled, SCALED_RESULT_DP, rounding))
+}
+
+/// Computes `value × pct / 100`, rounded to [`SCALED_RESULT_DP`] places.
+///
+/// # Errors
+///
+/// Returns `ArithmeticError::Overflow` if the product would overflow.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::arithmetic::{Rounding, percent_of};
+/// use rust_decimal::Decimal;
+///
+/// let half = percent_of(Decimal::new(3, 0), Decimal::new(50, 0), Rounding::Down).unwrap();
+/// assert_eq!(half, Decimal::new(15, 1));
+/// ```
+#[inline]
+#[must_use = "this returns the result of the operation, without modifying the original"]
+pub fn percent_of(value: Decimal, pct: Decimal, rounding: Rounding) -> ArithmeticResult<Decimal> {
+    let scaled = value.safe_mul(pct)?.safe_div(Decimal::ONE_HUNDRED)?;
+    Ok(round_dp(scaled, SCALED_RESULT_DP, rounding))
+}
+
 /// Trait for checked arithmetic operations.
 ///
 /// Provides safe arithmetic methods that return `Result` instead of
@@ -353,6 +418,68 @@ mod tests {
         }
     }
 
+    mod scaling_helpers {
+        use super::*;
+
+        #[test]
+        fn bps_of_exact_value() {
+            let fee = bps_of(Decimal::new(1_000_000, 0), 3, Rounding::Up).unwrap();
+            assert_eq!(fee, Decimal::new(300, 0));
+            let rebate = bps_of(Decimal::new(1_000_000, 0), -1, Rounding::Up).unwrap();
+            assert_eq!(rebate, Decimal::new(-100, 0));
+        }
+
+        #[test]
+        fn bps_of_half_way_rounds_by_direction() {
+            // 1 bp of 0.00005 = 0.000000005, half-way between 8 dp steps
+            let value = Decimal::new(5, 5);
+            assert_eq!(
+                bps_of(value, 1, Rounding::Down).unwrap(),
+                Decimal::new(0, 8)
+            );
+            assert_eq!(bps_of(value, 1, Rounding::Up).unwrap(), Decimal::new(1, 8));
+            assert_eq!(
+                bps_of(value, -1, Rounding::Down).unwrap(),
+                Decimal::new(0, 8)
+            );
+            assert_eq!(
+                bps_of(value, -1, Rounding::Up).unwrap(),
+                Decimal::new(-1, 8)
+            );
+        }
+
+        #[test]
+        fn percent_of_half_way_rounds_by_direction() {
+            // 50% of 0.00000001 = 0.000000005
+            let value = Decimal::new(1, 8);
+            let pct = Decimal::new(50, 0);
+            assert_eq!(
+                percent_of(value, pct, Rounding::Down).unwrap(),
+                Decimal::ZERO
+            );
+            assert_eq!(percent_of(value, pct, Rounding::Up).unwrap(), value);
+        }
+
+        #[test]
+        fn scaling_overflows_at_extremes() {
+            assert_eq!(
+                bps_of(Decimal::MAX, i64::MAX, Rounding::Down),
+                Err(ArithmeticError::Overflow)
+            );
+            assert_eq!(
+                percent_of(Decimal::MIN, Decimal::MAX, Rounding::Down),
+                Err(ArithmeticError::Overflow)
+            );
+        }
+
+        #[test]
+        fn round_dp_leaves_exact_values_unchanged() {
+            let value = Decimal::new(12345, 2);
+            assert_eq!(round_dp(value, 8, Rounding::Up), value);
+            assert_eq!(round_dp(value, 8, Rounding::Down), value);
+        }
+    }
+
     mod checked_arithmetic_decimal {
         use super::*;
 
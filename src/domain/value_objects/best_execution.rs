@@ -0,0 +1,153 @@
+//! # Best Execution Report
+//!
+//! How well a client's RFQs were executed over a period.
+//!
+//! A [`BestExecutionReport`] summarises the RFQs one client created in a
+//! period `[from, to)`: how many venues each was sent to, how often the
+//! best-priced quote available at execution was the one traded, and how
+//! far trades improved on the reference price captured at execution.
+//! Every trade that did not take the best price is listed as a
+//! [`BestExecutionException`] with the [`MissedBestPriceReason`].
+//! When notionals are normalized, the report also carries the traded
+//! notional in the base currency and the FX rates used to reach it.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::best_execution::MissedBestPriceReason;
+//!
+//! assert_eq!(MissedBestPriceReason::BestQuoteExpired.as_str(), "BEST_QUOTE_EXPIRED");
+//! assert_eq!(MissedBestPriceReason::ClientChoice.to_string(), "CLIENT_CHOICE");
+//! ```
+
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    CounterpartyId, FxRate, Money, OrderSide, Price, QuoteId, RfqId, TradeId,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use utoipa::ToSchema;
+
+/// Why a trade did not take the best price available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MissedBestPriceReason {
+    /// The best quote's validity had run out by the time of execution.
+    BestQuoteExpired,
+    /// The best quote was still valid; the client picked another one.
+    ClientChoice,
+}
+
+impl MissedBestPriceReason {
+    /// Returns the reason as serialized (e.g. `BEST_QUOTE_EXPIRED`).
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::BestQuoteExpired => "BEST_QUOTE_EXPIRED",
+            Self::ClientChoice => "CLIENT_CHOICE",
+        }
+    }
+}
+
+impl fmt::Display for MissedBestPriceReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A trade that did not take the best price available.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BestExecutionException {
+    /// The RFQ traded.
+    pub rfq_id: RfqId,
+    /// The trade.
+    pub trade_id: TradeId,
+    /// The side the client traded.
+    pub side: OrderSide,
+    /// The quote traded.
+    pub selected_quote_id: QuoteId,
+    /// Price of the quote traded.
+    pub selected_price: Price,
+    /// The best-priced quote received before execution.
+    pub best_quote_id: QuoteId,
+    /// Price of the best quote.
+    pub best_price: Price,
+    /// Why the best quote was not traded.
+    pub reason: MissedBestPriceReason,
+}
+
+/// Best execution summary of one client's RFQs over a period.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BestExecutionReport {
+    /// The client reported on.
+    pub client_id: CounterpartyId,
+    /// Start of the period (inclusive).
+    pub from: Timestamp,
+    /// End of the period (exclusive).
+    pub to: Timestamp,
+    /// RFQs the client created in the period.
+    pub rfq_count: u64,
+    /// Those RFQs that were traded.
+    pub executed_count: u64,
+    /// Average number of venues asked to quote, over RFQs sent to at least
+    /// one venue; `None` if none were.
+    pub average_venues_contacted: Option<f64>,
+    /// Percentage of traded RFQs that took the best price available;
+    /// `None` if none were traded.
+    pub best_price_selected_pct: Option<f64>,
+    /// Average improvement of the traded price on the reference price at
+    /// execution, in bps (positive is better for the client), over trades
+    /// with a reference price; `None` if none had one.
+    pub average_improvement_bps: Option<f64>,
+    /// Trades that did not take the best price, in execution order.
+    pub exceptions: Vec<BestExecutionException>,
+    /// Total notional traded, in the base currency; `None` if notionals
+    /// are not normalized.
+    #[serde(default)]
+    pub traded_notional: Option<Money>,
+    /// FX rates applied to reach `traded_notional`, one per trade converted.
+    #[serde(default)]
+    pub fx_rates: Vec<FxRate>,
+    /// When the report was generated.
+    pub generated_at: Timestamp,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_survives_json_roundtrip() {
+        let report = BestExecutionReport {
+            client_id: CounterpartyId::new("client-1"),
+            from: Timestamp::from_millis(1_000).unwrap(),
+            to: Timestamp::from_millis(2_000).unwrap(),
+            rfq_count: 2,
+            executed_count: 1,
+            average_venues_contacted: Some(2.5),
+            best_price_selected_pct: Some(0.0),
+            average_improvement_bps: None,
+            exceptions: vec![BestExecutionException {
+                rfq_id: RfqId::new_v4(),
+                trade_id: TradeId::new_v4(),
+                side: OrderSide::Buy,
+                selected_quote_id: QuoteId::new_v4(),
+                selected_price: Price::new(101.0).unwrap(),
+                best_quote_id: QuoteId::new_v4(),
+                best_price: Price::new(100.0).unwrap(),
+                reason: MissedBestPriceReason::BestQuoteExpired,
+            }],
+            traded_notional: None,
+            fx_rates: Vec::new(),
+            generated_at: Timestamp::from_millis(3_000).unwrap(),
+        };
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["exceptions"][0]["reason"], "BEST_QUOTE_EXPIRED");
+        assert_eq!(
+            serde_json::from_value::<BestExecutionReport>(json).unwrap(),
+            report
+        );
+    }
+}
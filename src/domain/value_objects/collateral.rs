@@ -0,0 +1,150 @@
+//! # Collateral Decision
+//!
+//! Outcome of the pre-execution collateral check for derivatives RFQs.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::CollateralDecision;
+//! use rust_decimal::Decimal;
+//!
+//! let decision = CollateralDecision::Insufficient {
+//!     required: Decimal::from(5_000),
+//!     available: Decimal::from(1_200),
+//! };
+//! assert!(decision.is_insufficient());
+//! assert_eq!(decision.shortfall(), Some(Decimal::from(3_800)));
+//! ```
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Result of checking a client's margin before execution.
+///
+/// Kept on the trade for audit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "decision", rename_all = "snake_case")]
+pub enum CollateralDecision {
+    /// The client holds enough collateral for the trade.
+    Approved {
+        /// Collateral available before the trade.
+        available: Decimal,
+    },
+    /// The client does not hold enough collateral for the trade.
+    Insufficient {
+        /// Collateral the trade requires.
+        required: Decimal,
+        /// Collateral available before the trade.
+        available: Decimal,
+    },
+    /// The collateral system could not be reached or has no record of the
+    /// client.
+    Unavailable,
+}
+
+impl CollateralDecision {
+    /// Returns true if the client holds enough collateral.
+    #[inline]
+    #[must_use]
+    pub const fn is_approved(&self) -> bool {
+        matches!(self, Self::Approved { .. })
+    }
+
+    /// Returns true if the client is short of collateral.
+    #[inline]
+    #[must_use]
+    pub const fn is_insufficient(&self) -> bool {
+        matches!(self, Self::Insufficient { .. })
+    }
+
+    /// Returns true if no decision could be made.
+    #[inline]
+    #[must_use]
+    pub const fn is_unavailable(&self) -> bool {
+        matches!(self, Self::Unavailable)
+    }
+
+    /// Returns how much collateral is missing, if the client is short.
+    #[must_use]
+    pub fn shortfall(&self) -> Option<Decimal> {
+        match self {
+            Self::Insufficient {
+                required,
+                available,
+            } => required.checked_sub(*available),
+            Self::Approved { .. } | Self::Unavailable => None,
+        }
+    }
+}
+
+impl fmt::Display for CollateralDecision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Approved { available } => write!(f, "approved: {} available", available),
+            Self::Insufficient {
+                required,
+                available,
+            } => write!(
+                f,
+                "insufficient: {} required, {} available",
+                required, available
+            ),
+            Self::Unavailable => write!(f, "unavailable"),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortfall_only_when_insufficient() {
+        let approved = CollateralDecision::Approved {
+            available: Decimal::from(10),
+        };
+        assert!(approved.is_approved());
+        assert_eq!(approved.shortfall(), None);
+        assert_eq!(CollateralDecision::Unavailable.shortfall(), None);
+
+        let insufficient = CollateralDecision::Insufficient {
+            required: Decimal::from(10),
+            available: Decimal::from(4),
+        };
+        assert_eq!(insufficient.shortfall(), Some(Decimal::from(6)));
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        for decision in [
+            CollateralDecision::Approved {
+                available: Decimal::from(10),
+            },
+            CollateralDecision::Insufficient {
+                required: Decimal::from(10),
+                available: Decimal::from(4),
+            },
+            CollateralDecision::Unavailable,
+        ] {
+            let json = serde_json::to_string(&decision).unwrap();
+            let back: CollateralDecision = serde_json::from_str(&json).unwrap();
+            assert_eq!(back, decision);
+        }
+        let json = serde_json::to_value(CollateralDecision::Unavailable).unwrap();
+        assert_eq!(json.get("decision").unwrap(), "unavailable");
+    }
+
+    #[test]
+    fn display() {
+        let decision = CollateralDecision::Insufficient {
+            required: Decimal::from(10),
+            available: Decimal::from(4),
+        };
+        assert_eq!(
+            decision.to_string(),
+            "insufficient: 10 required, 4 available"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! # Compensation Policy
+//!
+//! What to do when some legs of a multi-venue fill fail.
+//!
+//! The policy is chosen per RFQ at creation and applied once every
+//! allocation of the resulting trade has either filled or failed.
+
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::SizeNegotiationMode;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use utoipa::ToSchema;
+
+/// How a partially failed multi-venue fill is compensated.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::{CompensationPolicy, SizeNegotiationMode};
+///
+/// let policy = CompensationPolicy::AcceptPartial;
+/// assert!(policy.validate_for(&SizeNegotiationMode::BestEffort).is_ok());
+/// assert!(policy.validate_for(&SizeNegotiationMode::AllOrNothing).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CompensationPolicy {
+    /// Re-run quote aggregation for the failed quantity.
+    #[default]
+    ReofferRemainder,
+    /// Keep the filled legs and close the trade short, if the size mode
+    /// allows partial fills.
+    AcceptPartial,
+    /// Reverse every filled leg on a best-effort basis.
+    UnwindAll,
+}
+
+impl CompensationPolicy {
+    /// Checks that this policy can apply to an RFQ with `size_mode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` for `AcceptPartial` when the
+    /// size mode requires a full fill.
+    pub fn validate_for(self, size_mode: &SizeNegotiationMode) -> DomainResult<()> {
+        if self == Self::AcceptPartial && !size_mode.allows_partial_fill() {
+            return Err(DomainError::ValidationError(format!(
+                "compensation policy {self} requires a size mode that allows partial fills"
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for CompensationPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ReofferRemainder => write!(f, "REOFFER_REMAINDER"),
+            Self::AcceptPartial => write!(f, "ACCEPT_PARTIAL"),
+            Self::UnwindAll => write!(f, "UNWIND_ALL"),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::Quantity;
+
+    #[test]
+    fn serializes_like_display() {
+        for policy in [
+            CompensationPolicy::ReofferRemainder,
+            CompensationPolicy::AcceptPartial,
+            CompensationPolicy::UnwindAll,
+        ] {
+            let json = serde_json::to_string(&policy).unwrap();
+            assert_eq!(json, format!("\"{policy}\""));
+        }
+    }
+
+    #[test]
+    fn accept_partial_requires_partial_fills() {
+        let policy = CompensationPolicy::AcceptPartial;
+        let min = SizeNegotiationMode::MinQuantity(Quantity::new(1.0).unwrap());
+
+        assert!(policy.validate_for(&min).is_ok());
+        assert!(
+            policy
+                .validate_for(&SizeNegotiationMode::FillOrKill)
+                .is_err()
+        );
+        assert!(
+            CompensationPolicy::UnwindAll
+                .validate_for(&SizeNegotiationMode::FillOrKill)
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! # Counter Conditions
+//!
+//! Terms a party attaches to a counter-quote.
+//!
+//! A market maker countering during negotiation may keep its price firm for
+//! less time than the negotiation allows, and state the chain and window it
+//! wants to settle in. If the counter is accepted, the settlement
+//! preferences become the [`SettlementPlan`] of the resulting trade.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::counter_conditions::CounterConditions;
+//! use otc_rfq::domain::value_objects::{Blockchain, SettlementWindow};
+//!
+//! let conditions = CounterConditions {
+//!     valid_for_secs: Some(15),
+//!     settlement_chain: Some(Blockchain::Arbitrum),
+//!     settlement_window: Some(SettlementWindow::Instant),
+//!     note: None,
+//! };
+//!
+//! let plan = conditions.settlement_plan().unwrap();
+//! assert_eq!(plan.chain, Some(Blockchain::Arbitrum));
+//! assert_eq!(plan.window, Some(SettlementWindow::Instant));
+//! ```
+
+use crate::domain::value_objects::enums::Blockchain;
+use crate::domain::value_objects::settlement_window::SettlementWindow;
+use serde::{Deserialize, Serialize};
+
+/// Where and when a trade settles, as agreed in negotiation.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SettlementPlan {
+    /// Chain to settle on, if agreed.
+    pub chain: Option<Blockchain>,
+    /// Settlement window, if agreed.
+    pub window: Option<SettlementWindow>,
+    /// Free-text note from the party that set the terms.
+    pub note: Option<String>,
+}
+
+/// Terms attached to a counter-quote.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CounterConditions {
+    /// Seconds the counter stays firm after submission. May only shorten
+    /// the time left in the negotiation.
+    pub valid_for_secs: Option<u64>,
+    /// Chain the counter is conditional on settling on.
+    pub settlement_chain: Option<Blockchain>,
+    /// Window the counter is conditional on settling within.
+    pub settlement_window: Option<SettlementWindow>,
+    /// Free-text note to the other party.
+    pub note: Option<String>,
+}
+
+impl CounterConditions {
+    /// Returns the settlement terms, or `None` if the counter sets neither
+    /// a chain nor a window.
+    #[must_use]
+    pub fn settlement_plan(&self) -> Option<SettlementPlan> {
+        if self.settlement_chain.is_none() && self.settlement_window.is_none() {
+            return None;
+        }
+        Some(SettlementPlan {
+            chain: self.settlement_chain,
+            window: self.settlement_window,
+            note: self.note.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settlement_plan_requires_chain_or_window() {
+        let note_only = CounterConditions {
+            valid_for_secs: Some(10),
+            note: Some("firm for 10s".to_string()),
+            ..CounterConditions::default()
+        };
+        assert!(note_only.settlement_plan().is_none());
+
+        let window = CounterConditions {
+            settlement_window: Some(SettlementWindow::Instant),
+            ..note_only
+        };
+        let plan = window.settlement_plan().unwrap();
+        assert_eq!(plan.chain, None);
+        assert_eq!(plan.window, Some(SettlementWindow::Instant));
+        assert_eq!(plan.note.as_deref(), Some("firm for 10s"));
+    }
+}
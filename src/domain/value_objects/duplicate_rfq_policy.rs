@@ -0,0 +1,111 @@
+//! # Duplicate RFQ Policy
+//!
+//! When a new RFQ nearly duplicates one its client already has open.
+//!
+//! Different traders at the same client sometimes run near-identical RFQs
+//! at once, printing the same risk twice. A [`DuplicateRfqPolicy`] treats
+//! two RFQs for the same instrument and side as near-duplicates when their
+//! quantities differ by at most the similarity band, a fraction of the new
+//! RFQ's quantity. Near-duplicates are reported as warnings, unless the
+//! counterparty opted into having them refused.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::duplicate_rfq_policy::DuplicateRfqPolicy;
+//! use otc_rfq::domain::value_objects::{CounterpartyId, Quantity};
+//! use rust_decimal::Decimal;
+//!
+//! let policy = DuplicateRfqPolicy::new(Decimal::new(10, 2))
+//!     .with_blocking(CounterpartyId::new("acme-trading"));
+//!
+//! let quantity = Quantity::new(10.0).unwrap();
+//! assert!(policy.is_similar(quantity, Quantity::new(10.5).unwrap()));
+//! assert!(!policy.is_similar(quantity, Quantity::new(12.0).unwrap()));
+//! assert!(policy.blocks(&CounterpartyId::new("acme-trading")));
+//! ```
+
+use crate::domain::value_objects::{CounterpartyId, Quantity};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Similarity band of the default policy: quantities within 10%.
+pub const DEFAULT_SIMILARITY_BAND: Decimal = Decimal::from_parts(10, 0, 0, false, 2);
+
+/// How near-duplicate RFQs are detected and handled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DuplicateRfqPolicy {
+    /// Largest quantity difference still counted as similar, as a fraction
+    /// of the new RFQ's quantity (`0.1` = 10%).
+    pub similarity_band: Decimal,
+    /// Counterparties whose near-duplicate RFQs are refused rather than
+    /// warned about.
+    #[serde(default)]
+    pub blocking: HashSet<CounterpartyId>,
+}
+
+impl DuplicateRfqPolicy {
+    /// Creates a policy with a similarity band, warning every counterparty.
+    #[must_use]
+    pub fn new(similarity_band: Decimal) -> Self {
+        Self {
+            similarity_band: similarity_band.abs(),
+            blocking: HashSet::new(),
+        }
+    }
+
+    /// Refuses near-duplicate RFQs of `counterparty_id`.
+    #[must_use]
+    pub fn with_blocking(mut self, counterparty_id: CounterpartyId) -> Self {
+        self.blocking.insert(counterparty_id);
+        self
+    }
+
+    /// Returns true if an open RFQ for `existing` is close enough to a new
+    /// RFQ for `requested` to count as a near-duplicate.
+    #[must_use]
+    pub fn is_similar(&self, requested: Quantity, existing: Quantity) -> bool {
+        (existing.get() - requested.get()).abs() <= requested.get() * self.similarity_band
+    }
+
+    /// Returns true if near-duplicate RFQs of `counterparty_id` are refused.
+    #[must_use]
+    pub fn blocks(&self, counterparty_id: &CounterpartyId) -> bool {
+        self.blocking.contains(counterparty_id)
+    }
+}
+
+impl Default for DuplicateRfqPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_SIMILARITY_BAND)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn quantity(value: f64) -> Quantity {
+        Quantity::new(value).unwrap()
+    }
+
+    #[test]
+    fn band_is_relative_to_requested_quantity_and_inclusive() {
+        let policy = DuplicateRfqPolicy::default();
+
+        assert!(policy.is_similar(quantity(100.0), quantity(110.0)));
+        assert!(policy.is_similar(quantity(100.0), quantity(90.0)));
+        assert!(!policy.is_similar(quantity(100.0), quantity(110.5)));
+        assert!(!policy.is_similar(quantity(100.0), quantity(89.0)));
+    }
+
+    #[test]
+    fn only_opted_in_counterparties_are_blocked() {
+        let policy = DuplicateRfqPolicy::default().with_blocking(CounterpartyId::new("acme"));
+
+        assert!(policy.blocks(&CounterpartyId::new("acme")));
+        assert!(!policy.blocks(&CounterpartyId::new("globex")));
+    }
+}
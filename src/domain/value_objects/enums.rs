@@ -16,6 +16,7 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
+use utoipa::ToSchema;
 
 /// Order side indicating buy or sell direction.
 ///
@@ -32,7 +33,7 @@ use std::str::FromStr;
 /// assert_eq!(buy.opposite(), sell);
 /// assert_eq!(buy.to_string(), "BUY");
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "UPPERCASE")]
 #[repr(u8)]
 pub enum OrderSide {
@@ -98,6 +99,95 @@ impl FromStr for OrderSide {
     }
 }
 
+/// Direction an RFQ asks to be quoted in.
+///
+/// A one-way RFQ is quoted on the client's side only. A two-way RFQ asks
+/// for both a bid and an offer; the client picks the side they hit at
+/// selection, which fixes the trade direction from then on. Quotes,
+/// negotiations and trades always carry a binary [`OrderSide`].
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::enums::{OrderSide, RfqDirection};
+///
+/// assert_eq!(RfqDirection::from(OrderSide::Buy).side(), Some(OrderSide::Buy));
+/// assert_eq!(RfqDirection::TwoWay.side(), None);
+/// assert_eq!(RfqDirection::TwoWay.sides(), &[OrderSide::Buy, OrderSide::Sell]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RfqDirection {
+    /// Quote the client buying.
+    Buy,
+    /// Quote the client selling.
+    Sell,
+    /// Quote both sides; the client chooses at selection.
+    TwoWay,
+}
+
+impl RfqDirection {
+    /// Returns the single side quoted, or `None` for a two-way RFQ.
+    #[inline]
+    #[must_use]
+    pub const fn side(self) -> Option<OrderSide> {
+        match self {
+            Self::Buy => Some(OrderSide::Buy),
+            Self::Sell => Some(OrderSide::Sell),
+            Self::TwoWay => None,
+        }
+    }
+
+    /// Returns every side quoted.
+    #[must_use]
+    pub const fn sides(self) -> &'static [OrderSide] {
+        match self {
+            Self::Buy => &[OrderSide::Buy],
+            Self::Sell => &[OrderSide::Sell],
+            Self::TwoWay => &[OrderSide::Buy, OrderSide::Sell],
+        }
+    }
+
+    /// Returns true if both sides are quoted.
+    #[inline]
+    #[must_use]
+    pub const fn is_two_way(self) -> bool {
+        matches!(self, Self::TwoWay)
+    }
+}
+
+impl From<OrderSide> for RfqDirection {
+    fn from(side: OrderSide) -> Self {
+        match side {
+            OrderSide::Buy => Self::Buy,
+            OrderSide::Sell => Self::Sell,
+        }
+    }
+}
+
+impl fmt::Display for RfqDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Buy => write!(f, "BUY"),
+            Self::Sell => write!(f, "SELL"),
+            Self::TwoWay => write!(f, "TWO_WAY"),
+        }
+    }
+}
+
+impl FromStr for RfqDirection {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "BUY" => Ok(Self::Buy),
+            "SELL" => Ok(Self::Sell),
+            "TWO_WAY" => Ok(Self::TwoWay),
+            _ => Err(ParseEnumError::InvalidValue("RfqDirection", s.to_string())),
+        }
+    }
+}
+
 /// Asset class classification.
 ///
 /// Categorizes financial instruments by their underlying asset type.
@@ -117,7 +207,7 @@ impl FromStr for OrderSide {
 pub enum AssetClass {
     /// Cryptocurrency spot trading.
     CryptoSpot = 0,
-    /// Cryptocurrency derivatives (futures, options, perpetuals).
+    /// Cryptocurrency derivatives without option terms (futures, perpetuals).
     CryptoDerivs = 1,
     /// Equity/stock instruments.
     Stock = 2,
@@ -125,6 +215,8 @@ pub enum AssetClass {
     Forex = 3,
     /// Commodity instruments.
     Commodity = 4,
+    /// Cryptocurrency options; instruments carry strike, expiry and kind.
+    CryptoOptions = 5,
 }
 
 impl AssetClass {
@@ -132,7 +224,18 @@ impl AssetClass {
     #[inline]
     #[must_use]
     pub const fn is_crypto(self) -> bool {
-        matches!(self, Self::CryptoSpot | Self::CryptoDerivs)
+        matches!(
+            self,
+            Self::CryptoSpot | Self::CryptoDerivs | Self::CryptoOptions
+        )
+    }
+
+    /// Returns true if instruments of this class are options and carry
+    /// option terms.
+    #[inline]
+    #[must_use]
+    pub const fn is_option(self) -> bool {
+        matches!(self, Self::CryptoOptions)
     }
 
     /// Returns true if this is a traditional finance asset class.
@@ -151,6 +254,7 @@ impl fmt::Display for AssetClass {
             Self::Stock => write!(f, "STOCK"),
             Self::Forex => write!(f, "FOREX"),
             Self::Commodity => write!(f, "COMMODITY"),
+            Self::CryptoOptions => write!(f, "CRYPTO_OPTIONS"),
         }
     }
 }
@@ -165,6 +269,7 @@ impl FromStr for AssetClass {
             "STOCK" => Ok(Self::Stock),
             "FOREX" => Ok(Self::Forex),
             "COMMODITY" => Ok(Self::Commodity),
+            "CRYPTO_OPTIONS" | "CRYPTOOPTIONS" => Ok(Self::CryptoOptions),
             _ => Err(ParseEnumError::InvalidValue("AssetClass", s.to_string())),
         }
     }
@@ -332,7 +437,7 @@ impl FromStr for Blockchain {
 /// let venue = VenueType::InternalMM;
 /// assert!(venue.is_market_maker());
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[repr(u8)]
 pub enum VenueType {
@@ -346,6 +451,8 @@ pub enum VenueType {
     Protocol = 3,
     /// RFQ-specific protocol (Hashflow, Bebop).
     RfqProtocol = 4,
+    /// Simulated venue for testing and demos (never routed in production).
+    Simulated = 5,
 }
 
 impl VenueType {
@@ -365,6 +472,13 @@ impl VenueType {
             Self::DexAggregator | Self::Protocol | Self::RfqProtocol
         )
     }
+
+    /// Returns true if this is a simulated venue.
+    #[inline]
+    #[must_use]
+    pub const fn is_simulated(self) -> bool {
+        matches!(self, Self::Simulated)
+    }
 }
 
 impl fmt::Display for VenueType {
@@ -375,6 +489,7 @@ impl fmt::Display for VenueType {
             Self::DexAggregator => write!(f, "DEX_AGGREGATOR"),
             Self::Protocol => write!(f, "PROTOCOL"),
             Self::RfqProtocol => write!(f, "RFQ_PROTOCOL"),
+            Self::Simulated => write!(f, "SIMULATED"),
         }
     }
 }
@@ -389,6 +504,7 @@ impl FromStr for VenueType {
             "DEX_AGGREGATOR" | "DEXAGGREGATOR" => Ok(Self::DexAggregator),
             "PROTOCOL" => Ok(Self::Protocol),
             "RFQ_PROTOCOL" | "RFQPROTOCOL" => Ok(Self::RfqProtocol),
+            "SIMULATED" => Ok(Self::Simulated),
             _ => Err(ParseEnumError::InvalidValue("VenueType", s.to_string())),
         }
     }
@@ -528,6 +644,26 @@ mod tests {
         }
     }
 
+    mod rfq_direction {
+        use super::*;
+
+        #[test]
+        fn sides_and_serde() {
+            assert_eq!(RfqDirection::Sell.sides(), &[OrderSide::Sell]);
+            assert!(RfqDirection::TwoWay.is_two_way());
+            assert_eq!(
+                serde_json::to_string(&RfqDirection::TwoWay).unwrap(),
+                "\"TWO_WAY\""
+            );
+            assert_eq!(
+                "two_way".parse::<RfqDirection>().unwrap(),
+                RfqDirection::TwoWay
+            );
+            let buy: RfqDirection = serde_json::from_str("\"BUY\"").unwrap();
+            assert_eq!(buy, RfqDirection::from(OrderSide::Buy));
+        }
+    }
+
     mod asset_class {
         use super::*;
 
@@ -535,9 +671,20 @@ mod tests {
         fn is_crypto() {
             assert!(AssetClass::CryptoSpot.is_crypto());
             assert!(AssetClass::CryptoDerivs.is_crypto());
+            assert!(AssetClass::CryptoOptions.is_crypto());
             assert!(!AssetClass::Stock.is_crypto());
         }
 
+        #[test]
+        fn only_options_are_option_class() {
+            assert!(AssetClass::CryptoOptions.is_option());
+            assert!(!AssetClass::CryptoDerivs.is_option());
+            assert_eq!(
+                "crypto-options".parse::<AssetClass>().unwrap(),
+                AssetClass::CryptoOptions
+            );
+        }
+
         #[test]
         fn is_tradfi() {
             assert!(AssetClass::Stock.is_tradfi());
@@ -633,6 +780,18 @@ mod tests {
             assert!(VenueType::Protocol.is_defi());
             assert!(VenueType::RfqProtocol.is_defi());
             assert!(!VenueType::InternalMM.is_defi());
+            assert!(!VenueType::Simulated.is_defi());
+        }
+
+        #[test]
+        fn is_simulated() {
+            assert!(VenueType::Simulated.is_simulated());
+            assert!(!VenueType::Simulated.is_market_maker());
+            assert!(!VenueType::InternalMM.is_simulated());
+            assert_eq!(
+                "simulated".parse::<VenueType>().unwrap(),
+                VenueType::Simulated
+            );
         }
 
         #[test]
@@ -0,0 +1,299 @@
+//! # Execution Instructions Value Object
+//!
+//! Venue-specific data a quote must carry back to its venue at execution.
+//!
+//! RFQ protocols answer a quote request with a signed payload that has to
+//! be replayed verbatim when the quote is taken: Hashflow returns a signed
+//! quote, Bebop an order payload and Airswap an EIP-712 signed order. This
+//! module provides [`ExecutionInstructions`], one typed variant per
+//! protocol, plus a [`Generic`](ExecutionInstructions::Generic) fallback
+//! for venues without a dedicated shape.
+//!
+//! Instructions are serialized with a `protocol` tag so they survive the
+//! round trip through quote persistence.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::execution_instructions::{
+//!     ExecutionInstructions, ExecutionProtocol,
+//! };
+//! use std::collections::BTreeMap;
+//!
+//! let mut fields = BTreeMap::new();
+//! fields.insert("order_id".to_string(), "abc-123".to_string());
+//! let instructions = ExecutionInstructions::Generic { fields };
+//!
+//! assert_eq!(instructions.protocol(), ExecutionProtocol::Generic);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Protocol an [`ExecutionInstructions`] value is shaped for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExecutionProtocol {
+    /// Hashflow signed quote.
+    HashflowSigned,
+    /// Bebop order payload.
+    BebopOrder,
+    /// Airswap EIP-712 signed order.
+    AirswapOrder,
+    /// Untyped key-value instructions.
+    Generic,
+}
+
+impl fmt::Display for ExecutionProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HashflowSigned => write!(f, "HASHFLOW_SIGNED"),
+            Self::BebopOrder => write!(f, "BEBOP_ORDER"),
+            Self::AirswapOrder => write!(f, "AIRSWAP_ORDER"),
+            Self::Generic => write!(f, "GENERIC"),
+        }
+    }
+}
+
+/// Airswap order (EIP-712 typed data).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AirswapOrder {
+    /// Nonce for replay protection.
+    pub nonce: String,
+    /// Order expiry timestamp (Unix seconds).
+    pub expiry: u64,
+    /// Signer wallet address.
+    pub signer_wallet: String,
+    /// Signer token address.
+    pub signer_token: String,
+    /// Signer token amount.
+    pub signer_amount: String,
+    /// Protocol fee (basis points).
+    pub protocol_fee: String,
+    /// Sender wallet address.
+    pub sender_wallet: String,
+    /// Sender token address.
+    pub sender_token: String,
+    /// Sender token amount.
+    pub sender_amount: String,
+}
+
+/// Signed Airswap order with EIP-712 signature.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedAirswapOrder {
+    /// The order data.
+    #[serde(flatten)]
+    pub order: AirswapOrder,
+    /// EIP-712 signature v component.
+    pub v: u8,
+    /// EIP-712 signature r component.
+    pub r: String,
+    /// EIP-712 signature s component.
+    pub s: String,
+}
+
+impl SignedAirswapOrder {
+    /// Returns the full signature as a hex string.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        format!(
+            "0x{}{}{:02x}",
+            self.r.trim_start_matches("0x"),
+            self.s.trim_start_matches("0x"),
+            self.v
+        )
+    }
+}
+
+/// Data a venue needs back when a quote is executed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "protocol", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExecutionInstructions {
+    /// Hashflow signed quote.
+    HashflowSigned {
+        /// Hashflow quote ID.
+        quote_id: String,
+        /// Market maker signature over the quote.
+        signature: String,
+        /// Pool the quote is filled from.
+        pool: String,
+        /// Nonce for replay protection.
+        nonce: String,
+        /// Base token address.
+        base_token: String,
+        /// Quote token address.
+        quote_token: String,
+        /// Base token amount.
+        base_token_amount: String,
+        /// Quote token amount.
+        quote_token_amount: String,
+        /// Deadline for submitting the trade (Unix seconds).
+        txn_deadline: u64,
+        /// Chain ID.
+        chain_id: u64,
+        /// Base amount after fees, when it differs from the quoted amount.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        effective_base_token_amount: Option<String>,
+    },
+    /// Bebop order payload.
+    BebopOrder {
+        /// Bebop quote ID.
+        quote_id: String,
+        /// Order signature.
+        signature: String,
+        /// Settlement contract address.
+        settlement_address: String,
+        /// Address the sell token must be approved for.
+        approval_target: String,
+        /// Sell token address.
+        sell_token: String,
+        /// Buy token address.
+        buy_token: String,
+        /// Sell amount.
+        sell_amount: String,
+        /// Buy amount.
+        buy_amount: String,
+        /// Chain ID.
+        chain_id: u64,
+        /// Whether Bebop submits the transaction.
+        gasless: bool,
+        /// Calldata for self-submitted execution.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tx_data: Option<String>,
+        /// Gas estimate for self-submitted execution.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        gas_estimate: Option<String>,
+        /// Receiver of the bought tokens, if not the taker.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        receiver: Option<String>,
+    },
+    /// Airswap EIP-712 signed order.
+    AirswapOrder(SignedAirswapOrder),
+    /// Untyped key-value instructions for venues without a dedicated shape.
+    Generic {
+        /// Instruction fields.
+        fields: BTreeMap<String, String>,
+    },
+}
+
+impl ExecutionInstructions {
+    /// Returns the protocol these instructions are shaped for.
+    #[must_use]
+    pub fn protocol(&self) -> ExecutionProtocol {
+        match self {
+            Self::HashflowSigned { .. } => ExecutionProtocol::HashflowSigned,
+            Self::BebopOrder { .. } => ExecutionProtocol::BebopOrder,
+            Self::AirswapOrder(_) => ExecutionProtocol::AirswapOrder,
+            Self::Generic { .. } => ExecutionProtocol::Generic,
+        }
+    }
+
+    /// Returns the numeric chain ID the quote settles on, if known.
+    ///
+    /// Generic instructions carry it in a `chain_id` field; Airswap orders
+    /// do not carry one.
+    #[must_use]
+    pub fn chain_id(&self) -> Option<u64> {
+        match self {
+            Self::HashflowSigned { chain_id, .. } | Self::BebopOrder { chain_id, .. } => {
+                Some(*chain_id)
+            }
+            Self::AirswapOrder(_) => None,
+            Self::Generic { fields } => fields.get("chain_id").and_then(|id| id.parse().ok()),
+        }
+    }
+
+    /// Returns true if the venue submits the settlement transaction itself.
+    #[must_use]
+    pub fn is_gasless(&self) -> bool {
+        matches!(self, Self::BebopOrder { gasless: true, .. })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(instructions: &ExecutionInstructions, tag: &str) {
+        let json = serde_json::to_value(instructions).unwrap();
+        assert_eq!(json.get("protocol").unwrap(), tag);
+        assert_eq!(instructions.protocol().to_string(), tag);
+        let back: ExecutionInstructions = serde_json::from_value(json).unwrap();
+        assert_eq!(&back, instructions);
+    }
+
+    #[test]
+    fn hashflow_roundtrip() {
+        roundtrip(
+            &ExecutionInstructions::HashflowSigned {
+                quote_id: "hf-1".to_string(),
+                signature: "0xsig".to_string(),
+                pool: "0xpool".to_string(),
+                nonce: "7".to_string(),
+                base_token: "0xbase".to_string(),
+                quote_token: "0xquote".to_string(),
+                base_token_amount: "1000".to_string(),
+                quote_token_amount: "2000".to_string(),
+                txn_deadline: 1_700_000_000,
+                chain_id: 1,
+                effective_base_token_amount: Some("995".to_string()),
+            },
+            "HASHFLOW_SIGNED",
+        );
+    }
+
+    #[test]
+    fn bebop_roundtrip() {
+        roundtrip(
+            &ExecutionInstructions::BebopOrder {
+                quote_id: "bb-1".to_string(),
+                signature: "0xsig".to_string(),
+                settlement_address: "0xsettle".to_string(),
+                approval_target: "0xapprove".to_string(),
+                sell_token: "0xsell".to_string(),
+                buy_token: "0xbuy".to_string(),
+                sell_amount: "1000".to_string(),
+                buy_amount: "2000".to_string(),
+                chain_id: 137,
+                gasless: true,
+                tx_data: None,
+                gas_estimate: None,
+                receiver: Some("0xreceiver".to_string()),
+            },
+            "BEBOP_ORDER",
+        );
+    }
+
+    #[test]
+    fn airswap_roundtrip() {
+        let order = SignedAirswapOrder {
+            order: AirswapOrder {
+                nonce: "1".to_string(),
+                expiry: 1_700_000_000,
+                signer_wallet: "0xsigner".to_string(),
+                signer_token: "0xtoken-a".to_string(),
+                signer_amount: "1000".to_string(),
+                protocol_fee: "7".to_string(),
+                sender_wallet: "0xsender".to_string(),
+                sender_token: "0xtoken-b".to_string(),
+                sender_amount: "2000".to_string(),
+            },
+            v: 27,
+            r: "0xaa".to_string(),
+            s: "0xbb".to_string(),
+        };
+        assert_eq!(order.signature(), "0xaabb1b");
+        roundtrip(&ExecutionInstructions::AirswapOrder(order), "AIRSWAP_ORDER");
+    }
+
+    #[test]
+    fn generic_roundtrip() {
+        let mut fields = BTreeMap::new();
+        fields.insert("order_id".to_string(), "abc".to_string());
+        roundtrip(&ExecutionInstructions::Generic { fields }, "GENERIC");
+    }
+}
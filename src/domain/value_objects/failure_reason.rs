@@ -0,0 +1,193 @@
+//! # Failure Reason
+//!
+//! Structured reason an RFQ failed.
+//!
+//! A [`FailureReason`] pairs a [`FailureCode`] from a fixed taxonomy with a
+//! free-text detail. The code is what dashboards aggregate and filter on;
+//! the detail keeps the underlying error message for operators.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::failure_reason::{FailureCode, FailureReason};
+//!
+//! let reason = FailureReason::new(FailureCode::VenueTimeout, "no fill within 5s");
+//! assert_eq!(reason.code(), FailureCode::VenueTimeout);
+//! assert_eq!(reason.to_string(), "VENUE_TIMEOUT: no fill within 5s");
+//! assert_eq!("venue_timeout".parse::<FailureCode>().unwrap(), FailureCode::VenueTimeout);
+//! ```
+
+use crate::domain::value_objects::enums::ParseEnumError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// Category of an RFQ failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FailureCode {
+    /// The venue did not answer in time.
+    VenueTimeout,
+    /// The venue declined the request.
+    VenueRejected,
+    /// The price was outside the allowed bounds.
+    PriceOutOfBounds,
+    /// A compliance check blocked the RFQ.
+    ComplianceBlocked,
+    /// Not enough liquidity to fill the quantity.
+    InsufficientLiquidity,
+    /// Execution failed for another reason, on our side or in transport.
+    ExecutionError,
+    /// Execution did not finish before the RFQ's execution deadline.
+    ExecutionTimeout,
+    /// The service shut down while the RFQ was in flight.
+    Shutdown,
+    /// Uncategorised, including failures recorded before codes existed.
+    #[default]
+    Other,
+}
+
+impl FailureCode {
+    /// All failure codes.
+    pub const ALL: [Self; 9] = [
+        Self::VenueTimeout,
+        Self::VenueRejected,
+        Self::PriceOutOfBounds,
+        Self::ComplianceBlocked,
+        Self::InsufficientLiquidity,
+        Self::ExecutionError,
+        Self::ExecutionTimeout,
+        Self::Shutdown,
+        Self::Other,
+    ];
+
+    /// Returns the code as stored and serialized (e.g. `VENUE_TIMEOUT`).
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::VenueTimeout => "VENUE_TIMEOUT",
+            Self::VenueRejected => "VENUE_REJECTED",
+            Self::PriceOutOfBounds => "PRICE_OUT_OF_BOUNDS",
+            Self::ComplianceBlocked => "COMPLIANCE_BLOCKED",
+            Self::InsufficientLiquidity => "INSUFFICIENT_LIQUIDITY",
+            Self::ExecutionError => "EXECUTION_ERROR",
+            Self::ExecutionTimeout => "EXECUTION_TIMEOUT",
+            Self::Shutdown => "SHUTDOWN",
+            Self::Other => "OTHER",
+        }
+    }
+}
+
+impl fmt::Display for FailureCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for FailureCode {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let upper = s.to_uppercase();
+        Self::ALL
+            .into_iter()
+            .find(|code| code.as_str() == upper)
+            .ok_or_else(|| ParseEnumError::InvalidValue("FailureCode", s.to_string()))
+    }
+}
+
+/// Why an RFQ failed: a category plus the underlying message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "FailureReasonRepr")]
+pub struct FailureReason {
+    code: FailureCode,
+    detail: String,
+}
+
+impl FailureReason {
+    /// Creates a failure reason.
+    #[must_use]
+    pub fn new(code: FailureCode, detail: impl Into<String>) -> Self {
+        Self {
+            code,
+            detail: detail.into(),
+        }
+    }
+
+    /// Creates an uncategorised failure reason.
+    #[must_use]
+    pub fn other(detail: impl Into<String>) -> Self {
+        Self::new(FailureCode::Other, detail)
+    }
+
+    /// Returns the failure category.
+    #[inline]
+    #[must_use]
+    pub const fn code(&self) -> FailureCode {
+        self.code
+    }
+
+    /// Returns the underlying message.
+    #[inline]
+    #[must_use]
+    pub fn detail(&self) -> &str {
+        &self.detail
+    }
+}
+
+impl fmt::Display for FailureReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code, self.detail)
+    }
+}
+
+/// Serialized forms of [`FailureReason`]; RFQs stored before codes existed
+/// carry a bare string.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FailureReasonRepr {
+    Structured { code: FailureCode, detail: String },
+    Legacy(String),
+}
+
+impl From<FailureReasonRepr> for FailureReason {
+    fn from(repr: FailureReasonRepr) -> Self {
+        match repr {
+            FailureReasonRepr::Structured { code, detail } => Self::new(code, detail),
+            FailureReasonRepr::Legacy(detail) => Self::other(detail),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_round_trip_through_strings() {
+        for code in FailureCode::ALL {
+            assert_eq!(code.to_string().parse::<FailureCode>().unwrap(), code);
+            assert_eq!(
+                serde_json::to_string(&code).unwrap(),
+                format!("\"{}\"", code.as_str())
+            );
+        }
+        assert!("TIMEOUT".parse::<FailureCode>().is_err());
+    }
+
+    #[test]
+    fn legacy_string_deserializes_as_other() {
+        let reason = FailureReason::new(FailureCode::Shutdown, "service shutting down");
+        let json = serde_json::to_value(&reason).unwrap();
+        assert_eq!(json["code"], "SHUTDOWN");
+        assert_eq!(
+            serde_json::from_value::<FailureReason>(json).unwrap(),
+            reason
+        );
+
+        let legacy: FailureReason = serde_json::from_str("\"venue rejected\"").unwrap();
+        assert_eq!(legacy, FailureReason::other("venue rejected"));
+    }
+}
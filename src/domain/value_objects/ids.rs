@@ -14,6 +14,16 @@
 //! - [`BlockTradeId`] - Block Trade identifier
 //! - [`NegotiationId`] - Negotiation identifier
 //! - [`PackageQuoteId`] - Package quote identifier
+//! - [`RfqTemplateId`] - RFQ template identifier
+//! - [`WebhookSubscriptionId`] - Webhook subscription identifier
+//! - [`WebhookDeliveryId`] - Webhook delivery identifier
+//! - [`NettingBatchId`] - Settlement netting batch identifier
+//! - [`ErasureRequestId`] - Counterparty erasure request identifier
+//! - [`CorrelationId`] - Identifier shared by the events of one RFQ-to-settlement chain
+//!
+//! ## Trace Identifiers
+//!
+//! - [`TraceId`] - W3C Trace Context trace identifier
 //!
 //! ## String-based Identifiers
 //!
@@ -365,6 +375,32 @@ impl From<Uuid> for NegotiationId {
 pub struct VenueId(String);
 
 impl VenueId {
+    /// Reserved identifier for quotes produced by internal crossing.
+    pub const INTERNAL: &'static str = "internal";
+
+    /// Returns the reserved venue ID used for internally crossed quotes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otc_rfq::domain::value_objects::ids::VenueId;
+    ///
+    /// assert!(VenueId::internal().is_internal());
+    /// assert!(!VenueId::new("0x-api").is_internal());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn internal() -> Self {
+        Self(Self::INTERNAL.to_owned())
+    }
+
+    /// Returns true if this is the reserved internal crossing venue.
+    #[inline]
+    #[must_use]
+    pub fn is_internal(&self) -> bool {
+        self.0 == Self::INTERNAL
+    }
+
     /// Creates a new Venue ID from a string.
     #[inline]
     #[must_use]
@@ -465,6 +501,395 @@ impl From<Uuid> for PackageQuoteId {
     }
 }
 
+/// RFQ template identifier.
+///
+/// A UUID-based identifier uniquely identifying a saved RFQ template.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::ids::RfqTemplateId;
+///
+/// let template_id = RfqTemplateId::new_v4();
+/// println!("RFQ template: {}", template_id);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RfqTemplateId(Uuid);
+
+impl RfqTemplateId {
+    /// Creates a new RFQ template ID from an existing UUID.
+    #[inline]
+    #[must_use]
+    pub const fn new(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Generates a new random RFQ template ID using UUID v4.
+    #[must_use]
+    pub fn new_v4() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Returns the inner UUID value.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for RfqTemplateId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.hyphenated())
+    }
+}
+
+impl From<Uuid> for RfqTemplateId {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+/// Webhook subscription identifier.
+///
+/// A UUID-based identifier uniquely identifying a webhook subscription.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::ids::WebhookSubscriptionId;
+///
+/// let subscription_id = WebhookSubscriptionId::new_v4();
+/// println!("Webhook subscription: {}", subscription_id);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct WebhookSubscriptionId(Uuid);
+
+impl WebhookSubscriptionId {
+    /// Creates a new webhook subscription ID from an existing UUID.
+    #[inline]
+    #[must_use]
+    pub const fn new(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Generates a new random webhook subscription ID using UUID v4.
+    #[must_use]
+    pub fn new_v4() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Returns the inner UUID value.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for WebhookSubscriptionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.hyphenated())
+    }
+}
+
+impl From<Uuid> for WebhookSubscriptionId {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+/// Webhook delivery identifier.
+///
+/// A UUID-based identifier uniquely identifying one delivery of an event to a webhook subscription.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::ids::WebhookDeliveryId;
+///
+/// let delivery_id = WebhookDeliveryId::new_v4();
+/// println!("Webhook delivery: {}", delivery_id);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct WebhookDeliveryId(Uuid);
+
+impl WebhookDeliveryId {
+    /// Creates a new webhook delivery ID from an existing UUID.
+    #[inline]
+    #[must_use]
+    pub const fn new(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Generates a new random webhook delivery ID using UUID v4.
+    #[must_use]
+    pub fn new_v4() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Returns the inner UUID value.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for WebhookDeliveryId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.hyphenated())
+    }
+}
+
+impl From<Uuid> for WebhookDeliveryId {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+/// Settlement netting batch identifier.
+///
+/// A UUID-based identifier uniquely identifying a batch of trades settled
+/// with a single net transaction.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::ids::NettingBatchId;
+///
+/// let batch_id = NettingBatchId::new_v4();
+/// println!("Netting batch: {}", batch_id);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NettingBatchId(Uuid);
+
+impl NettingBatchId {
+    /// Creates a new netting batch ID from an existing UUID.
+    #[inline]
+    #[must_use]
+    pub const fn new(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Generates a new random netting batch ID using UUID v4.
+    #[must_use]
+    pub fn new_v4() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Returns the inner UUID value.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for NettingBatchId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.hyphenated())
+    }
+}
+
+impl From<Uuid> for NettingBatchId {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+/// Counterparty erasure request identifier.
+///
+/// A UUID-based identifier uniquely identifying a request to erase a
+/// counterparty's personal identifiers.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::ids::ErasureRequestId;
+///
+/// let request_id = ErasureRequestId::new_v4();
+/// println!("Erasure request: {}", request_id);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ErasureRequestId(Uuid);
+
+impl ErasureRequestId {
+    /// Creates a new erasure request ID from an existing UUID.
+    #[inline]
+    #[must_use]
+    pub const fn new(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Generates a new random erasure request ID using UUID v4.
+    #[must_use]
+    pub fn new_v4() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Returns the inner UUID value.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for ErasureRequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.hyphenated())
+    }
+}
+
+impl From<Uuid> for ErasureRequestId {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+/// Event correlation identifier.
+///
+/// A UUID-based identifier shared by every event in a chain, from the
+/// creation of an RFQ through its trade, settlement and compliance events.
+/// A chain opened by an RFQ is identified by that RFQ's ID.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::ids::{CorrelationId, RfqId};
+///
+/// let rfq_id = RfqId::new_v4();
+/// let correlation_id = CorrelationId::from(rfq_id);
+/// assert_eq!(correlation_id.get(), rfq_id.get());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CorrelationId(Uuid);
+
+impl CorrelationId {
+    /// Creates a new correlation ID from an existing UUID.
+    #[inline]
+    #[must_use]
+    pub const fn new(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Generates a new random correlation ID using UUID v4.
+    #[must_use]
+    pub fn new_v4() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Returns the inner UUID value.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for CorrelationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.hyphenated())
+    }
+}
+
+impl From<Uuid> for CorrelationId {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+impl From<RfqId> for CorrelationId {
+    #[inline]
+    fn from(rfq_id: RfqId) -> Self {
+        Self(rfq_id.get())
+    }
+}
+
+/// Distributed trace identifier.
+///
+/// A 128-bit W3C Trace Context trace ID, rendered as 32 lowercase hex
+/// characters. Events carry it so they can be correlated with traces.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::ids::TraceId;
+///
+/// let trace_id: TraceId = "0af7651916cd43dd8448eb211c80319c".parse().unwrap();
+/// assert_eq!(trace_id.to_string(), "0af7651916cd43dd8448eb211c80319c");
+/// assert!("00000000000000000000000000000000".parse::<TraceId>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TraceId(u128);
+
+impl TraceId {
+    /// Creates a Trace ID from its 128-bit value.
+    ///
+    /// Returns `None` for the all-zero ID, which W3C Trace Context treats
+    /// as invalid.
+    #[inline]
+    #[must_use]
+    pub const fn new(value: u128) -> Option<Self> {
+        if value == 0 { None } else { Some(Self(value)) }
+    }
+
+    /// Returns the inner 128-bit value.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> u128 {
+        self.0
+    }
+}
+
+impl fmt::Display for TraceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:032x}", self.0)
+    }
+}
+
+impl std::str::FromStr for TraceId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 32 {
+            return Err(format!("trace id must be 32 hex characters: {s}"));
+        }
+        u128::from_str_radix(s, 16)
+            .ok()
+            .and_then(Self::new)
+            .ok_or_else(|| format!("invalid trace id: {s}"))
+    }
+}
+
+impl TryFrom<String> for TraceId {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<TraceId> for String {
+    fn from(id: TraceId) -> Self {
+        id.to_string()
+    }
+}
+
 /// Counterparty identifier.
 ///
 /// A string-based identifier for counterparties (clients, market makers, etc.).
@@ -695,6 +1120,30 @@ mod tests {
         }
     }
 
+    mod trace_id {
+        use super::*;
+
+        #[test]
+        fn serializes_as_hex_string() {
+            let trace_id = TraceId::new(0x0af7_6519_16cd_43dd_8448_eb21_1c80_319c).unwrap();
+            let json = serde_json::to_string(&trace_id).unwrap();
+            assert_eq!(json, "\"0af7651916cd43dd8448eb211c80319c\"");
+            let deserialized: TraceId = serde_json::from_str(&json).unwrap();
+            assert_eq!(trace_id, deserialized);
+        }
+
+        #[test]
+        fn rejects_zero_and_malformed_ids() {
+            assert!(TraceId::new(0).is_none());
+            assert!("abc".parse::<TraceId>().is_err());
+            assert!(
+                "zzf7651916cd43dd8448eb211c80319c"
+                    .parse::<TraceId>()
+                    .is_err()
+            );
+        }
+    }
+
     mod venue_id {
         use super::*;
 
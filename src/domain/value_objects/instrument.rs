@@ -22,10 +22,33 @@
 //! assert_eq!(instrument.symbol().to_string(), "BTC/USD");
 //! assert!(instrument.asset_class().is_crypto());
 //! ```
+//!
+//! Option instruments carry [`OptionTerms`] and are built with
+//! [`InstrumentBuilder::try_build`], which validates them:
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::instrument::Instrument;
+//! use otc_rfq::domain::value_objects::option_terms::{OptionKind, OptionTerms};
+//! use otc_rfq::domain::value_objects::{AssetClass, Price, Symbol, Timestamp};
+//!
+//! let expiry = Timestamp::now().add_secs(30 * 86_400);
+//! let terms = OptionTerms::new(Price::new(60_000.0).unwrap(), expiry, OptionKind::Call).unwrap();
+//! let call = Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoOptions)
+//!     .option_terms(terms)
+//!     .try_build()
+//!     .unwrap();
+//!
+//! assert!(call.to_string().starts_with("BTC-"));
+//! assert!(call.to_string().ends_with("-60000-C"));
+//! ```
 
 use super::enums::{AssetClass, SettlementMethod};
+use super::liquidity_classification::LiquidityClassification;
+use super::option_terms::OptionTerms;
 use super::quantity::Quantity;
 use super::symbol::Symbol;
+use super::timestamp::Timestamp;
+use crate::domain::errors::{DomainError, DomainResult};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -63,6 +86,12 @@ pub struct Instrument {
     min_quantity: Option<Quantity>,
     /// Number of decimal places for price (optional).
     price_decimals: Option<u8>,
+    /// Liquidity tier used to pick the price bounds tolerance.
+    #[serde(default)]
+    liquidity: LiquidityClassification,
+    /// Strike, expiry and kind; set only for option asset classes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    option_terms: Option<OptionTerms>,
 }
 
 impl Instrument {
@@ -102,6 +131,8 @@ impl Instrument {
             settlement_method,
             min_quantity: None,
             price_decimals: None,
+            liquidity: LiquidityClassification::default(),
+            option_terms: None,
         }
     }
 
@@ -179,6 +210,20 @@ impl Instrument {
         self.price_decimals
     }
 
+    /// Returns the liquidity classification.
+    #[inline]
+    #[must_use]
+    pub fn liquidity(&self) -> LiquidityClassification {
+        self.liquidity
+    }
+
+    /// Returns the option contract terms, if this is an option.
+    #[inline]
+    #[must_use]
+    pub fn option_terms(&self) -> Option<&OptionTerms> {
+        self.option_terms.as_ref()
+    }
+
     /// Returns true if this is a cryptocurrency instrument.
     #[inline]
     #[must_use]
@@ -194,9 +239,14 @@ impl Instrument {
     }
 }
 
+/// Options format as `BASE-DDMMMYY-STRIKE-K` (e.g. `BTC-28JUN24-60000-C`),
+/// other instruments as `SYMBOL (ASSET_CLASS)`.
 impl fmt::Display for Instrument {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} ({})", self.symbol, self.asset_class)
+        match &self.option_terms {
+            Some(terms) => write!(f, "{}-{}", self.base_asset(), terms),
+            None => write!(f, "{} ({})", self.symbol, self.asset_class),
+        }
     }
 }
 
@@ -210,6 +260,8 @@ pub struct InstrumentBuilder {
     settlement_method: SettlementMethod,
     min_quantity: Option<Quantity>,
     price_decimals: Option<u8>,
+    liquidity: LiquidityClassification,
+    option_terms: Option<OptionTerms>,
 }
 
 impl InstrumentBuilder {
@@ -221,6 +273,8 @@ impl InstrumentBuilder {
             settlement_method: SettlementMethod::default(),
             min_quantity: None,
             price_decimals: None,
+            liquidity: LiquidityClassification::default(),
+            option_terms: None,
         }
     }
 
@@ -245,7 +299,23 @@ impl InstrumentBuilder {
         self
     }
 
-    /// Builds the instrument.
+    /// Sets the liquidity classification.
+    #[must_use]
+    pub fn liquidity(mut self, liquidity: LiquidityClassification) -> Self {
+        self.liquidity = liquidity;
+        self
+    }
+
+    /// Sets the option contract terms.
+    #[must_use]
+    pub fn option_terms(mut self, terms: OptionTerms) -> Self {
+        self.option_terms = Some(terms);
+        self
+    }
+
+    /// Builds the instrument without validating option terms.
+    ///
+    /// Use [`InstrumentBuilder::try_build`] for option instruments.
     #[must_use]
     pub fn build(self) -> Instrument {
         Instrument {
@@ -254,7 +324,41 @@ impl InstrumentBuilder {
             settlement_method: self.settlement_method,
             min_quantity: self.min_quantity,
             price_decimals: self.price_decimals,
+            liquidity: self.liquidity,
+            option_terms: self.option_terms,
+        }
+    }
+
+    /// Builds and validates the instrument.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if option terms are missing
+    /// for an option asset class, present for any other asset class, or
+    /// expire at or before the current time.
+    pub fn try_build(self) -> DomainResult<Instrument> {
+        match (self.asset_class.is_option(), &self.option_terms) {
+            (true, None) => {
+                return Err(DomainError::ValidationError(format!(
+                    "{} instruments require option terms",
+                    self.asset_class
+                )));
+            }
+            (false, Some(_)) => {
+                return Err(DomainError::ValidationError(format!(
+                    "{} instruments cannot have option terms",
+                    self.asset_class
+                )));
+            }
+            (true, Some(terms)) if !terms.expiry().is_after(&Timestamp::now()) => {
+                return Err(DomainError::ValidationError(format!(
+                    "option expiry {} is not in the future",
+                    terms.expiry()
+                )));
+            }
+            _ => {}
         }
+        Ok(self.build())
     }
 }
 
@@ -312,6 +416,7 @@ mod tests {
             assert_eq!(instrument.settlement_method(), SettlementMethod::default());
             assert!(instrument.min_quantity().is_none());
             assert!(instrument.price_decimals().is_none());
+            assert_eq!(instrument.liquidity(), LiquidityClassification::Liquid);
         }
     }
 
@@ -360,6 +465,89 @@ mod tests {
         }
     }
 
+    mod option_terms {
+        use super::*;
+        use crate::domain::value_objects::option_terms::OptionKind;
+        use crate::domain::value_objects::price::Price;
+
+        fn terms(expiry: Timestamp) -> OptionTerms {
+            OptionTerms::new(Price::new(60_000.0).unwrap(), expiry, OptionKind::Call).unwrap()
+        }
+
+        fn btc() -> Symbol {
+            Symbol::new("BTC/USD").unwrap()
+        }
+
+        #[test]
+        fn option_requires_terms() {
+            let result = Instrument::builder(btc(), AssetClass::CryptoOptions).try_build();
+            assert!(matches!(result, Err(DomainError::ValidationError(_))));
+        }
+
+        #[test]
+        fn non_option_rejects_terms() {
+            let result = Instrument::builder(btc(), AssetClass::CryptoDerivs)
+                .option_terms(terms(Timestamp::now().add_secs(3_600)))
+                .try_build();
+            assert!(matches!(result, Err(DomainError::ValidationError(_))));
+        }
+
+        #[test]
+        fn rejects_past_expiry() {
+            let result = Instrument::builder(btc(), AssetClass::CryptoOptions)
+                .option_terms(terms(Timestamp::now().sub_secs(1)))
+                .try_build();
+            assert!(matches!(result, Err(DomainError::ValidationError(_))));
+        }
+
+        #[test]
+        fn display_uses_option_code() {
+            let expiry = Timestamp::from_secs(1_719_561_600).unwrap();
+            let instrument = Instrument::builder(btc(), AssetClass::CryptoOptions)
+                .option_terms(terms(expiry))
+                .build();
+
+            assert_eq!(instrument.to_string(), "BTC-28JUN24-60000-C");
+        }
+
+        #[test]
+        fn serde_roundtrip_with_terms() {
+            let instrument = Instrument::builder(btc(), AssetClass::CryptoOptions)
+                .option_terms(terms(Timestamp::now().add_secs(3_600)))
+                .try_build()
+                .unwrap();
+
+            let json = serde_json::to_string(&instrument).unwrap();
+            let deserialized: Instrument = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(instrument, deserialized);
+        }
+
+        #[test]
+        fn legacy_json_without_terms_deserializes() {
+            let json = r#"{
+                "symbol": "BTC/USD",
+                "asset_class": "CRYPTO_DERIVS",
+                "settlement_method": "OFF_CHAIN",
+                "min_quantity": null,
+                "price_decimals": null
+            }"#;
+
+            let instrument: Instrument = serde_json::from_str(json).unwrap();
+
+            assert!(instrument.option_terms().is_none());
+            assert_eq!(instrument.asset_class(), AssetClass::CryptoDerivs);
+        }
+
+        #[test]
+        fn serialization_omits_missing_terms() {
+            let instrument =
+                Instrument::new(btc(), AssetClass::CryptoSpot, SettlementMethod::default());
+            let json = serde_json::to_value(&instrument).unwrap();
+            assert!(json.get("option_terms").is_none());
+        }
+    }
+
     mod display {
         use super::*;
 
@@ -404,5 +592,20 @@ mod tests {
 
             assert_eq!(instrument.min_quantity(), deserialized.min_quantity());
         }
+
+        #[test]
+        fn missing_liquidity_defaults_to_liquid() {
+            let symbol = Symbol::new("BTC/USD").unwrap();
+            let instrument = Instrument::builder(symbol, AssetClass::CryptoSpot)
+                .liquidity(LiquidityClassification::Illiquid)
+                .build();
+
+            let mut json = serde_json::to_value(&instrument).unwrap();
+            assert_eq!(json.get("liquidity").unwrap(), "Illiquid");
+            json.as_object_mut().unwrap().remove("liquidity");
+            let deserialized: Instrument = serde_json::from_value(json).unwrap();
+
+            assert_eq!(deserialized.liquidity(), LiquidityClassification::Liquid);
+        }
     }
 }
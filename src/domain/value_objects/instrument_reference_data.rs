@@ -0,0 +1,420 @@
+//! # Instrument Reference Data
+//!
+//! Per-symbol trading constraints.
+//!
+//! This module provides [`InstrumentReferenceData`], which holds the tick
+//! size, lot size, order size limits, and contract multiplier of an
+//! instrument, and validates requested prices and quantities against them.
+//!
+//! # Rounding
+//!
+//! When a value is rejected, the error carries the nearest valid value:
+//!
+//! - Prices round in the requester's favour: down for a buy, up for a sell.
+//! - Quantities round down to a whole lot, or up when rounding down would
+//!   fall below the minimum order size.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::instrument_reference_data::InstrumentReferenceData;
+//! use otc_rfq::domain::value_objects::{Quantity, Symbol};
+//! use rust_decimal::Decimal;
+//!
+//! let data = InstrumentReferenceData::new(
+//!     Symbol::new("BTC/USD").unwrap(),
+//!     Decimal::new(1, 1),
+//!     Decimal::new(1, 3),
+//! )
+//! .unwrap();
+//!
+//! assert!(data.validate_quantity(Quantity::new(1.5).unwrap()).is_ok());
+//! assert!(data.validate_quantity(Quantity::new(1.5005).unwrap()).is_err());
+//! ```
+
+use super::arithmetic::{ArithmeticResult, CheckedArithmetic, Rounding};
+use super::enums::OrderSide;
+use super::price::Price;
+use super::quantity::Quantity;
+use super::symbol::Symbol;
+use crate::domain::errors::{DomainError, DomainResult};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Trading constraints for a single instrument.
+///
+/// # Invariants
+///
+/// - `tick_size`, `lot_size`, and `contract_multiplier` are positive
+/// - `min_order_size <= max_order_size` when a maximum is set
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstrumentReferenceData {
+    symbol: Symbol,
+    tick_size: Decimal,
+    lot_size: Decimal,
+    min_order_size: Quantity,
+    max_order_size: Option<Quantity>,
+    contract_multiplier: Decimal,
+}
+
+impl InstrumentReferenceData {
+    /// Creates reference data with no order size limits and a contract
+    /// multiplier of one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if `tick_size` or `lot_size`
+    /// is not positive.
+    pub fn new(symbol: Symbol, tick_size: Decimal, lot_size: Decimal) -> DomainResult<Self> {
+        ensure_positive("tick size", tick_size)?;
+        ensure_positive("lot size", lot_size)?;
+        Ok(Self {
+            symbol,
+            tick_size,
+            lot_size,
+            min_order_size: Quantity::ZERO,
+            max_order_size: None,
+            contract_multiplier: Decimal::ONE,
+        })
+    }
+
+    /// Sets the minimum and optional maximum order size.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if `max` is below `min`.
+    pub fn with_order_size_limits(
+        mut self,
+        min: Quantity,
+        max: Option<Quantity>,
+    ) -> DomainResult<Self> {
+        if let Some(max) = max
+            && max < min
+        {
+            return Err(DomainError::ValidationError(format!(
+                "max order size {} is below min order size {}",
+                max, min
+            )));
+        }
+        self.min_order_size = min;
+        self.max_order_size = max;
+        Ok(self)
+    }
+
+    /// Sets the contract multiplier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if `multiplier` is not positive.
+    pub fn with_contract_multiplier(mut self, multiplier: Decimal) -> DomainResult<Self> {
+        ensure_positive("contract multiplier", multiplier)?;
+        self.contract_multiplier = multiplier;
+        Ok(self)
+    }
+
+    /// Returns the instrument symbol.
+    #[inline]
+    #[must_use]
+    pub fn symbol(&self) -> &Symbol {
+        &self.symbol
+    }
+
+    /// Returns the minimum price increment.
+    #[inline]
+    #[must_use]
+    pub fn tick_size(&self) -> Decimal {
+        self.tick_size
+    }
+
+    /// Returns the minimum quantity increment.
+    #[inline]
+    #[must_use]
+    pub fn lot_size(&self) -> Decimal {
+        self.lot_size
+    }
+
+    /// Returns the minimum order size.
+    #[inline]
+    #[must_use]
+    pub fn min_order_size(&self) -> Quantity {
+        self.min_order_size
+    }
+
+    /// Returns the maximum order size, if any.
+    #[inline]
+    #[must_use]
+    pub fn max_order_size(&self) -> Option<Quantity> {
+        self.max_order_size
+    }
+
+    /// Returns the contract multiplier.
+    #[inline]
+    #[must_use]
+    pub fn contract_multiplier(&self) -> Decimal {
+        self.contract_multiplier
+    }
+
+    /// Validates a requested quantity against the order size limits and the
+    /// lot size.
+    ///
+    /// # Errors
+    ///
+    /// - `DomainError::InvalidQuantity` if the quantity is outside the order
+    ///   size limits
+    /// - `DomainError::InvalidLotSize` if the quantity is not a whole number
+    ///   of lots
+    pub fn validate_quantity(&self, quantity: Quantity) -> DomainResult<()> {
+        if quantity < self.min_order_size {
+            return Err(DomainError::InvalidQuantity(format!(
+                "{} is below the minimum order size {} for {}",
+                quantity, self.min_order_size, self.symbol
+            )));
+        }
+        if let Some(max) = self.max_order_size
+            && quantity > max
+        {
+            return Err(DomainError::InvalidQuantity(format!(
+                "{} exceeds the maximum order size {} for {}",
+                quantity, max, self.symbol
+            )));
+        }
+
+        let nearest_valid = self.nearest_valid_quantity(quantity)?;
+        if nearest_valid != quantity {
+            return Err(DomainError::InvalidLotSize {
+                quantity,
+                lot_size: self.lot_size,
+                nearest_valid,
+            });
+        }
+        Ok(())
+    }
+
+    /// Validates a price against the tick size.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidTickSize` if the price is not a multiple
+    /// of the tick size.
+    pub fn validate_price(&self, price: Price, side: OrderSide) -> DomainResult<()> {
+        let nearest_valid = self.nearest_valid_price(price, side)?;
+        if nearest_valid != price {
+            return Err(DomainError::InvalidTickSize {
+                price,
+                tick_size: self.tick_size,
+                nearest_valid,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the closest whole-lot quantity that does not exceed
+    /// `quantity`, or the next lot up if that would fall below the minimum
+    /// order size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rounding overflows.
+    pub fn nearest_valid_quantity(&self, quantity: Quantity) -> DomainResult<Quantity> {
+        let down = quantity.round_to_lot(self.lot_size, Rounding::Down)?;
+        if down.is_positive() && down >= self.min_order_size {
+            return Ok(down);
+        }
+        Ok(quantity.round_to_lot(self.lot_size, Rounding::Up)?)
+    }
+
+    /// Returns the closest on-tick price that is no worse for the requester:
+    /// rounded down for a buy and up for a sell.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rounding overflows.
+    pub fn nearest_valid_price(&self, price: Price, side: OrderSide) -> DomainResult<Price> {
+        let rounding = match side {
+            OrderSide::Buy => Rounding::Down,
+            OrderSide::Sell => Rounding::Up,
+        };
+        Ok(price.round_to_tick(self.tick_size, rounding)?)
+    }
+
+    /// Returns the notional value of `quantity` contracts at `price`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ArithmeticError::Overflow` if the result would overflow.
+    pub fn notional(&self, price: Price, quantity: Quantity) -> ArithmeticResult<Decimal> {
+        price
+            .get()
+            .safe_mul(quantity.get())?
+            .safe_mul(self.contract_multiplier)
+    }
+}
+
+fn ensure_positive(field: &str, value: Decimal) -> DomainResult<()> {
+    if value <= Decimal::ZERO {
+        return Err(DomainError::ValidationError(format!(
+            "{} must be positive, got {}",
+            field, value
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn btc_usd() -> InstrumentReferenceData {
+        InstrumentReferenceData::new(
+            Symbol::new("BTC/USD").unwrap(),
+            Decimal::new(5, 1),
+            Decimal::new(1, 2),
+        )
+        .unwrap()
+        .with_order_size_limits(
+            Quantity::new(0.1).unwrap(),
+            Some(Quantity::new(100.0).unwrap()),
+        )
+        .unwrap()
+    }
+
+    fn qty(s: &str) -> Quantity {
+        s.parse().unwrap()
+    }
+
+    fn price(s: &str) -> Price {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn new_rejects_non_positive_sizes() {
+        let symbol = Symbol::new("BTC/USD").unwrap();
+        assert!(InstrumentReferenceData::new(symbol.clone(), Decimal::ZERO, Decimal::ONE).is_err());
+        assert!(InstrumentReferenceData::new(symbol, Decimal::ONE, Decimal::NEGATIVE_ONE).is_err());
+    }
+
+    #[test]
+    fn order_size_limits_must_be_ordered() {
+        let result = btc_usd().with_order_size_limits(qty("10"), Some(qty("1")));
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+    }
+
+    #[test]
+    fn contract_multiplier_must_be_positive() {
+        assert!(btc_usd().with_contract_multiplier(Decimal::ZERO).is_err());
+    }
+
+    #[test]
+    fn quantity_on_lot_boundary_is_valid() {
+        let data = btc_usd();
+        assert!(data.validate_quantity(qty("1.25")).is_ok());
+        assert!(data.validate_quantity(qty("0.1")).is_ok());
+        assert!(data.validate_quantity(qty("100")).is_ok());
+    }
+
+    #[test]
+    fn quantity_off_lot_reports_nearest_valid() {
+        let err = btc_usd().validate_quantity(qty("1.257")).unwrap_err();
+        assert_eq!(
+            err,
+            DomainError::InvalidLotSize {
+                quantity: qty("1.257"),
+                lot_size: Decimal::new(1, 2),
+                nearest_valid: qty("1.25"),
+            }
+        );
+    }
+
+    #[test]
+    fn quantity_outside_order_size_limits_is_invalid() {
+        let data = btc_usd();
+        assert!(matches!(
+            data.validate_quantity(qty("0.09")),
+            Err(DomainError::InvalidQuantity(_))
+        ));
+        assert!(matches!(
+            data.validate_quantity(qty("100.01")),
+            Err(DomainError::InvalidQuantity(_))
+        ));
+    }
+
+    #[test]
+    fn nearest_valid_quantity_rounds_up_to_reach_minimum() {
+        let data = btc_usd()
+            .with_order_size_limits(qty("0.105"), None)
+            .unwrap();
+        assert_eq!(
+            data.nearest_valid_quantity(qty("0.108")).unwrap(),
+            qty("0.11")
+        );
+    }
+
+    #[test]
+    fn nearest_valid_quantity_never_rounds_to_zero() {
+        let data = btc_usd()
+            .with_order_size_limits(Quantity::ZERO, None)
+            .unwrap();
+        assert_eq!(
+            data.nearest_valid_quantity(qty("0.004")).unwrap(),
+            qty("0.01")
+        );
+    }
+
+    #[test]
+    fn price_on_tick_is_valid_for_both_sides() {
+        let data = btc_usd();
+        assert!(
+            data.validate_price(price("50000.5"), OrderSide::Buy)
+                .is_ok()
+        );
+        assert!(
+            data.validate_price(price("50000.5"), OrderSide::Sell)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn buy_price_rounds_down() {
+        let err = btc_usd()
+            .validate_price(price("50000.7"), OrderSide::Buy)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            DomainError::InvalidTickSize {
+                price: price("50000.7"),
+                tick_size: Decimal::new(5, 1),
+                nearest_valid: price("50000.5"),
+            }
+        );
+    }
+
+    #[test]
+    fn sell_price_rounds_up() {
+        let data = btc_usd();
+        assert_eq!(
+            data.nearest_valid_price(price("50000.1"), OrderSide::Sell)
+                .unwrap(),
+            price("50000.5")
+        );
+    }
+
+    #[test]
+    fn notional_applies_contract_multiplier() {
+        let data = btc_usd()
+            .with_contract_multiplier(Decimal::new(10, 0))
+            .unwrap();
+        assert_eq!(
+            data.notional(price("100"), qty("2")).unwrap(),
+            Decimal::new(2000, 0)
+        );
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let data = btc_usd();
+        let json = serde_json::to_string(&data).unwrap();
+        let back: InstrumentReferenceData = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, data);
+    }
+}
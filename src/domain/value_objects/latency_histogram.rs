@@ -0,0 +1,191 @@
+//! # Latency Histogram
+//!
+//! Bounded sketch of a latency distribution.
+//!
+//! A [`LatencyHistogram`] counts latencies in log-linear buckets: values
+//! below 16ms get a bucket each, and every power of two above that is split
+//! into 16 equal buckets. Percentiles are read back as the midpoint of the
+//! bucket holding the requested rank, so estimates are within about 3% of
+//! the true value however many samples are recorded. Only non-empty buckets
+//! are stored, and the sketch serializes as bucket counts so percentiles
+//! survive a reload.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::latency_histogram::LatencyHistogram;
+//!
+//! let mut histogram = LatencyHistogram::new();
+//! for _ in 0..98 {
+//!     histogram.record(80);
+//! }
+//! histogram.record(4000);
+//! histogram.record(4000);
+//!
+//! assert_eq!(histogram.count(), 100);
+//! assert!(histogram.percentile(0.5).unwrap().abs_diff(80) <= 3);
+//! assert!(histogram.percentile(0.99).unwrap().abs_diff(4000) <= 120);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Buckets per power of two, and the value below which buckets are exact.
+const SUB_BUCKETS: u64 = 16;
+
+/// Log2 of [`SUB_BUCKETS`].
+const SUB_BUCKET_BITS: u32 = 4;
+
+/// Latency distribution in log-linear buckets.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct LatencyHistogram {
+    /// Sample count by bucket index; empty buckets are omitted.
+    buckets: BTreeMap<u16, u64>,
+    /// Total samples recorded.
+    count: u64,
+}
+
+impl LatencyHistogram {
+    /// Creates an empty histogram.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records one latency sample.
+    pub fn record(&mut self, latency_ms: u64) {
+        let bucket = self.buckets.entry(bucket_index(latency_ms)).or_insert(0);
+        *bucket = bucket.saturating_add(1);
+        self.count = self.count.saturating_add(1);
+    }
+
+    /// Returns the number of samples recorded.
+    #[inline]
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns true if no samples have been recorded.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the estimated latency at `quantile` (0.0–1.0), or `None` if
+    /// the histogram is empty.
+    ///
+    /// Quantiles outside the range are clamped.
+    #[must_use]
+    pub fn percentile(&self, quantile: f64) -> Option<u64> {
+        if self.count == 0 {
+            return None;
+        }
+        let quantile = if quantile.is_nan() {
+            0.0
+        } else {
+            quantile.clamp(0.0, 1.0)
+        };
+        // Rank of the sample to report, 1-based
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss
+        )]
+        let rank = ((quantile * self.count as f64).ceil() as u64).max(1);
+
+        let mut seen = 0u64;
+        for (&index, &count) in &self.buckets {
+            seen = seen.saturating_add(count);
+            if seen >= rank {
+                return Some(bucket_midpoint(index));
+            }
+        }
+        self.buckets.keys().next_back().map(|&i| bucket_midpoint(i))
+    }
+}
+
+/// Returns the bucket a latency falls in.
+fn bucket_index(latency_ms: u64) -> u16 {
+    if latency_ms < SUB_BUCKETS {
+        return latency_ms as u16;
+    }
+    let exponent = u64::BITS - 1 - latency_ms.leading_zeros();
+    let shift = exponent - SUB_BUCKET_BITS;
+    let sub_bucket = (latency_ms >> shift) - SUB_BUCKETS;
+    // exponent <= 63, so the index is at most 16 + 59 * 16 + 15
+    (u64::from(shift + 1) * SUB_BUCKETS + sub_bucket) as u16
+}
+
+/// Returns the midpoint of a bucket's range.
+fn bucket_midpoint(index: u16) -> u64 {
+    let index = u64::from(index);
+    if index < SUB_BUCKETS {
+        return index;
+    }
+    let shift = (index / SUB_BUCKETS) - 1;
+    let lower = (SUB_BUCKETS + index % SUB_BUCKETS) << shift;
+    let width = 1u64 << shift;
+    lower + (width - 1) / 2
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn assert_within(estimate: Option<u64>, expected: u64, tolerance: f64) {
+        let estimate = estimate.unwrap();
+        #[allow(clippy::cast_precision_loss)]
+        let error = estimate.abs_diff(expected) as f64 / expected as f64;
+        assert!(
+            error <= tolerance,
+            "estimate {estimate} is more than {tolerance} away from {expected}"
+        );
+    }
+
+    #[test]
+    fn uniform_distribution_percentiles_are_within_tolerance() {
+        let mut histogram = LatencyHistogram::new();
+        for latency in 1..=10_000 {
+            histogram.record(latency);
+        }
+
+        assert_eq!(histogram.count(), 10_000);
+        assert_within(histogram.percentile(0.5), 5_000, 0.04);
+        assert_within(histogram.percentile(0.9), 9_000, 0.04);
+        assert_within(histogram.percentile(0.99), 9_900, 0.04);
+        assert_eq!(histogram.percentile(0.0), Some(1));
+    }
+
+    #[test]
+    fn small_latencies_are_exact_and_buckets_are_contiguous() {
+        let mut histogram = LatencyHistogram::new();
+        histogram.record(7);
+        assert_eq!(histogram.percentile(0.5), Some(7));
+        assert!(LatencyHistogram::new().percentile(0.5).is_none());
+
+        let mut previous = bucket_index(0);
+        for latency in 1..100_000 {
+            let index = bucket_index(latency);
+            assert!(index == previous || index == previous + 1);
+            previous = index;
+        }
+        assert!(bucket_index(u64::MAX) < u16::MAX);
+    }
+
+    #[test]
+    fn serde_roundtrip_preserves_percentiles() {
+        let mut histogram = LatencyHistogram::new();
+        for latency in [12, 80, 85, 90, 250, 4_000] {
+            histogram.record(latency);
+        }
+
+        let json = serde_json::to_string(&histogram).unwrap();
+        let restored: LatencyHistogram = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, histogram);
+        assert_eq!(restored.percentile(0.99), histogram.percentile(0.99));
+    }
+}
@@ -6,12 +6,15 @@
 //!
 //! - [`RfqId`], [`QuoteId`], [`TradeId`], [`BlockTradeId`]: UUID-based identifiers
 //! - [`VenueId`], [`CounterpartyId`]: String-based identifiers
-//! - [`EventId`]: Domain event identifier
+//! - [`EventId`], [`CorrelationId`]: Domain event identifiers
 //!
 //! ## Numeric Types
 //!
 //! - [`Price`]: Decimal price with checked arithmetic
 //! - [`Quantity`]: Decimal quantity with checked arithmetic
+//! - [`Premium`]: Signed net premium for strategy quotes
+//! - [`SignedDecimalAmount`]: Signed amount for deltas, slippage, and P&L
+//! - [`Money`]: Amount in a [`Currency`], converted with an [`FxRate`]
 //!
 //! ## Arithmetic
 //!
@@ -22,74 +25,159 @@
 //! ## Domain Enums
 //!
 //! - [`OrderSide`]: Buy or Sell
+//! - [`RfqDirection`]: One side or both sides of an RFQ
 //! - [`AssetClass`]: Asset classification
 //! - [`Blockchain`]: Supported blockchain networks
 //! - [`VenueType`]: Types of liquidity venues
 //! - [`SettlementMethod`]: On-chain or off-chain settlement
+//! - [`VenueExclusionReason`]: Why a venue was left out of an RFQ's fan-out
+//! - [`VenueOutcome`]: What happened with one venue during quote aggregation,
+//!   collected per RFQ in an [`AggregationReport`]
+//! - [`ScoreComponent`]: One factor's part in a ranked quote's score, kept per
+//!   quote as a [`QuoteRanking`]
 //!
 //! ## Trading Types
 //!
 //! - [`Symbol`]: Trading pair representation (e.g., BTC/USD)
 //! - [`Instrument`]: Tradeable instrument with metadata
+//! - [`OptionTerms`]: Strike, expiry and call/put of an option instrument
+//! - [`InstrumentReferenceData`]: Tick size, lot size, and order size limits
+//! - [`ExecutionInstructions`]: Venue-specific data replayed at execution
+//! - [`QuorumPolicy`]: Minimum quotes and venues required per notional band
+//! - [`QuantityDisclosure`]: How much of an RFQ's size venues are shown
+//! - [`CounterConditions`]: Validity and settlement terms attached to a counter-quote
+//! - [`SettlementPlan`]: Agreed settlement chain and [`SettlementWindow`] of a trade
+//! - [`SettlementWindow`]: When a trade is released for settlement, with its [`SettlementCutoff`]
+//! - [`DuplicateRfqPolicy`]: When a new RFQ nearly duplicates one its client has open
 //!
 //! ## State Types
 //!
 //! - [`RfqState`]: RFQ lifecycle state machine
+//! - [`FailureReason`]: Categorised reason an RFQ failed, with its [`FailureCode`]
+//!
+//! ## Time
+//!
+//! - [`Timestamp`]: UTC timestamp with nanosecond precision
+//! - [`Clock`]: Source of the current time, with [`SystemClock`] as default
+//! - [`LatencyHistogram`]: Bounded latency sketch for percentile estimates
+//! - [`RfqTtlPolicy`]: Allowed and default RFQ time-to-live by asset class
+//! - [`TradingCalendar`]: Trading days of a market, 24/7 for crypto
 //!
 //! ## Compliance Types
 //!
 //! - [`ComplianceCheckResults`]: Results of KYC/AML checks
 //! - [`RegulatoryFlag`]: Regulatory flags raised during compliance checks
+//! - [`CollateralDecision`]: Outcome of the pre-execution margin check
+//! - [`BestExecutionReport`]: Per-client best execution summary over a period
 
+pub mod aggregation_report;
+pub mod all_in_cost;
 pub mod arithmetic;
+pub mod best_execution;
+pub mod collateral;
+pub mod compensation_policy;
 pub mod compliance;
 pub mod confirmation;
+pub mod counter_conditions;
+pub mod duplicate_rfq_policy;
 pub mod enums;
+pub mod execution_instructions;
+pub mod failure_reason;
 pub mod ids;
 pub mod instrument;
+pub mod instrument_reference_data;
+pub mod latency_histogram;
 pub mod liquidity_classification;
+pub mod money;
 pub mod negotiation_state;
 pub mod notification_preferences;
+pub mod option_terms;
+pub mod premium;
 pub mod price;
 pub mod price_discovery;
 pub mod price_improvement;
 pub mod quantity;
+pub mod quantity_disclosure;
+pub mod quorum_policy;
 pub mod reference_price;
 pub mod rfq_state;
+pub mod rfq_ttl;
+pub mod score_breakdown;
+pub mod settlement_window;
+pub mod signed_amount;
 pub mod size_negotiation_mode;
 pub mod spread_metrics;
 pub mod strategy;
 pub mod symbol;
 pub mod timestamp;
 pub mod trade_type;
+pub mod trading_calendar;
+pub mod venue_exclusion;
 
 #[cfg(test)]
 mod tests;
 
-pub use arithmetic::{ArithmeticError, ArithmeticResult, CheckedArithmetic, Rounding, div_round};
+pub use aggregation_report::{
+    AggregationReport, PriceSanityBasis, QuoteRanking, VenueOutcome, VenueOutcomeCounts,
+    VenueOutcomeKind,
+};
+pub use arithmetic::{
+    ArithmeticError, ArithmeticResult, BPS_PER_UNIT, CheckedArithmetic, Rounding, bps_of,
+    div_round, percent_of,
+};
+pub use best_execution::{BestExecutionException, BestExecutionReport, MissedBestPriceReason};
+pub use collateral::CollateralDecision;
+pub use compensation_policy::CompensationPolicy;
 pub use compliance::{ComplianceCheckResults, ComplianceCheckResultsBuilder, RegulatoryFlag};
 pub use confirmation::{
     ChannelDeliveryStatus, ConfirmationChannel, ConfirmationStatus, NotificationDestination,
     TradeConfirmation, TradeParticipant,
 };
-pub use enums::{AssetClass, Blockchain, OrderSide, ParseEnumError, SettlementMethod, VenueType};
+pub use counter_conditions::{CounterConditions, SettlementPlan};
+pub use duplicate_rfq_policy::{DEFAULT_SIMILARITY_BAND, DuplicateRfqPolicy};
+pub use enums::{
+    AssetClass, Blockchain, OrderSide, ParseEnumError, RfqDirection, SettlementMethod, VenueType,
+};
+pub use execution_instructions::{ExecutionInstructions, ExecutionProtocol};
+pub use failure_reason::{FailureCode, FailureReason};
 pub use ids::{
-    BlockTradeId, CounterpartyId, EventId, NegotiationId, PackageQuoteId, QuoteId, RfqId, TradeId,
-    VenueId,
+    BlockTradeId, CorrelationId, CounterpartyId, ErasureRequestId, EventId, NegotiationId,
+    NettingBatchId, PackageQuoteId, QuoteId, RfqId, RfqTemplateId, TraceId, TradeId, VenueId,
+    WebhookDeliveryId, WebhookSubscriptionId,
 };
 pub use instrument::{Instrument, InstrumentBuilder};
+pub use instrument_reference_data::InstrumentReferenceData;
+pub use latency_histogram::LatencyHistogram;
 pub use liquidity_classification::LiquidityClassification;
+pub use money::{Currency, FxConversion, FxRate, Money, MoneyError};
 pub use negotiation_state::{InvalidNegotiationStateError, NegotiationState};
 pub use notification_preferences::NotificationPreferences;
+pub use option_terms::{OptionKind, OptionTerms};
+pub use premium::Premium;
 pub use price::Price;
 pub use price_discovery::{PriceDiscoveryMethod, TheoreticalPrice};
 pub use price_improvement::{ImprovementSource, PriceImprovement};
 pub use quantity::Quantity;
-pub use reference_price::{PriceBoundsConfig, PriceBoundsResult, ReferencePriceSource};
+pub use quantity_disclosure::QuantityDisclosure;
+pub use quorum_policy::{
+    QuorumBand, QuorumPolicy, QuorumRequirement, QuorumRules, QuorumShortfall,
+};
+pub use reference_price::{
+    PriceBoundsCheck, PriceBoundsConfig, PriceBoundsConfigChange, PriceBoundsResult,
+    PriceBoundsSettings, ReferencePriceSource,
+};
 pub use rfq_state::{InvalidRfqStateError, RfqState};
+pub use rfq_ttl::{RfqTtlPolicy, TtlLimits, TtlOutOfRange};
+pub use score_breakdown::{SCORE_EPSILON, ScoreComponent, ScoreFactor};
+pub use settlement_window::{SettlementCutoff, SettlementWindow};
+pub use signed_amount::SignedDecimalAmount;
 pub use size_negotiation_mode::SizeNegotiationMode;
 pub use spread_metrics::{EffectiveSpread, RealizedSpread, SpreadMetrics};
 pub use strategy::{Strategy, StrategyBuilder, StrategyLeg, StrategyType};
 pub use symbol::{Symbol, SymbolError};
-pub use timestamp::Timestamp;
+#[cfg(any(test, feature = "test-util"))]
+pub use timestamp::MockClock;
+pub use timestamp::{Clock, SystemClock, Timestamp};
 pub use trade_type::TradeType;
+pub use trading_calendar::{CRYPTO_CALENDAR, TradingCalendar};
+pub use venue_exclusion::{ExcludedVenue, VenueExclusionReason};
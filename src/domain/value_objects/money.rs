@@ -0,0 +1,434 @@
+//! # Money Value Object
+//!
+//! Amounts tagged with their currency, and FX rates between currencies.
+//!
+//! [`Money`] pairs a [`Decimal`] amount with a [`Currency`]. Its arithmetic
+//! is checked and refuses to mix currencies, so a BTC notional is never
+//! added to or compared with a USD limit by accident. An [`FxRate`]
+//! converts money from one currency into another and carries where the
+//! rate came from; an [`FxConversion`] keeps the rate used next to both
+//! amounts for audit.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::money::{Currency, Money};
+//! use rust_decimal::Decimal;
+//!
+//! let usd = Currency::new("usd").unwrap();
+//! let total = Money::new(Decimal::new(150, 0), usd.clone())
+//!     .checked_add(&Money::new(Decimal::new(50, 0), usd))
+//!     .unwrap();
+//! assert_eq!(total.to_string(), "200 USD");
+//!
+//! let eur = Money::new(Decimal::ONE, Currency::new("EUR").unwrap());
+//! assert!(total.checked_add(&eur).is_err());
+//! ```
+
+use super::arithmetic::{ArithmeticError, CheckedArithmetic};
+use super::reference_price::ReferencePriceSource;
+use super::timestamp::Timestamp;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Longest currency code accepted.
+const MAX_CURRENCY_LEN: usize = 16;
+
+/// Error type for money arithmetic and conversion.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MoneyError {
+    /// Currency code is empty, too long or not alphanumeric.
+    #[error("invalid currency code: '{0}'")]
+    InvalidCurrency(String),
+
+    /// The operands are in different currencies.
+    #[error("currency mismatch: expected {expected}, got {actual}")]
+    CurrencyMismatch {
+        /// Currency the operation required.
+        expected: Currency,
+        /// Currency it was given.
+        actual: Currency,
+    },
+
+    /// An FX rate is not positive.
+    #[error("invalid FX rate {rate} for {from}/{to}")]
+    InvalidRate {
+        /// Currency converted from.
+        from: Currency,
+        /// Currency converted into.
+        to: Currency,
+        /// The rejected rate.
+        rate: Decimal,
+    },
+
+    /// The amount overflowed.
+    #[error(transparent)]
+    Arithmetic(#[from] ArithmeticError),
+}
+
+/// A currency or asset code, e.g. `USD`, `EUR` or `BTC`.
+///
+/// Stored in uppercase.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::money::Currency;
+///
+/// let currency: Currency = "eur".parse().unwrap();
+/// assert_eq!(currency.as_str(), "EUR");
+/// assert!(Currency::new("US-D").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Currency(String);
+
+impl Currency {
+    /// Creates a currency from its code.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidCurrency`] if the code is empty,
+    /// longer than 16 characters, or not ASCII alphanumeric.
+    pub fn new(code: impl AsRef<str>) -> Result<Self, MoneyError> {
+        let code = code.as_ref().trim();
+        if code.is_empty()
+            || code.len() > MAX_CURRENCY_LEN
+            || !code.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err(MoneyError::InvalidCurrency(code.to_string()));
+        }
+        Ok(Self(code.to_ascii_uppercase()))
+    }
+
+    /// US dollar.
+    #[must_use]
+    pub fn usd() -> Self {
+        Self("USD".to_string())
+    }
+
+    /// Returns the currency code.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for Currency {
+    type Err = MoneyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for Currency {
+    type Error = MoneyError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Currency> for String {
+    fn from(currency: Currency) -> Self {
+        currency.0
+    }
+}
+
+/// An amount of a currency.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::money::{Currency, Money};
+/// use rust_decimal::Decimal;
+///
+/// let notional = Money::new(Decimal::new(25, 1), Currency::new("BTC").unwrap());
+/// let doubled = notional.checked_mul(Decimal::TWO).unwrap();
+/// assert_eq!(doubled.amount(), Decimal::new(50, 1));
+/// assert_eq!(doubled.currency().as_str(), "BTC");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Money {
+    amount: Decimal,
+    currency: Currency,
+}
+
+impl Money {
+    /// Creates an amount of `currency`.
+    #[must_use]
+    pub fn new(amount: Decimal, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    /// Creates a zero amount of `currency`.
+    #[must_use]
+    pub fn zero(currency: Currency) -> Self {
+        Self::new(Decimal::ZERO, currency)
+    }
+
+    /// Returns the amount.
+    #[inline]
+    #[must_use]
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// Returns the currency.
+    #[inline]
+    #[must_use]
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    /// Adds `other`, which must be in the same currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatch`] if the currencies differ,
+    /// or an arithmetic error on overflow.
+    pub fn checked_add(&self, other: &Self) -> Result<Self, MoneyError> {
+        self.ensure_same_currency(other)?;
+        Ok(Self::new(
+            self.amount.safe_add(other.amount)?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Subtracts `other`, which must be in the same currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatch`] if the currencies differ,
+    /// or an arithmetic error on overflow.
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, MoneyError> {
+        self.ensure_same_currency(other)?;
+        Ok(Self::new(
+            self.amount.safe_sub(other.amount)?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Multiplies the amount by `factor`, keeping the currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns an arithmetic error on overflow.
+    pub fn checked_mul(&self, factor: Decimal) -> Result<Self, MoneyError> {
+        Ok(Self::new(
+            self.amount.safe_mul(factor)?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Converts the amount into the target currency of `rate`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatch`] if `rate` does not convert
+    /// from this currency, or an arithmetic error on overflow.
+    pub fn convert(&self, rate: &FxRate) -> Result<Self, MoneyError> {
+        if rate.from != self.currency {
+            return Err(MoneyError::CurrencyMismatch {
+                expected: rate.from.clone(),
+                actual: self.currency.clone(),
+            });
+        }
+        Ok(Self::new(self.amount.safe_mul(rate.rate)?, rate.to.clone()))
+    }
+
+    fn ensure_same_currency(&self, other: &Self) -> Result<(), MoneyError> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(MoneyError::CurrencyMismatch {
+                expected: self.currency.clone(),
+                actual: other.currency.clone(),
+            })
+        }
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency)
+    }
+}
+
+/// Rate converting one currency into another: one unit of `from` is
+/// worth `rate` units of `to`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FxRate {
+    /// Currency converted from.
+    pub from: Currency,
+    /// Currency converted into.
+    pub to: Currency,
+    /// Units of `to` per unit of `from`.
+    pub rate: Decimal,
+    /// Where the underlying price came from.
+    pub source: ReferencePriceSource,
+    /// When the rate was obtained.
+    pub as_of: Timestamp,
+}
+
+impl FxRate {
+    /// Creates a rate converting `from` into `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidRate`] if `rate` is not positive.
+    pub fn new(
+        from: Currency,
+        to: Currency,
+        rate: Decimal,
+        source: ReferencePriceSource,
+        as_of: Timestamp,
+    ) -> Result<Self, MoneyError> {
+        if rate <= Decimal::ZERO {
+            return Err(MoneyError::InvalidRate { from, to, rate });
+        }
+        Ok(Self {
+            from,
+            to,
+            rate,
+            source,
+            as_of,
+        })
+    }
+
+    /// Returns the rate converting the other way round.
+    ///
+    /// # Errors
+    ///
+    /// Returns an arithmetic error if the rate is zero.
+    pub fn inverse(&self) -> Result<Self, MoneyError> {
+        Ok(Self {
+            from: self.to.clone(),
+            to: self.from.clone(),
+            rate: Decimal::ONE.safe_div(self.rate)?,
+            source: self.source,
+            as_of: self.as_of,
+        })
+    }
+}
+
+/// An amount converted into another currency, with the rate used.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FxConversion {
+    /// The amount before conversion.
+    pub original: Money,
+    /// The amount after conversion.
+    pub converted: Money,
+    /// Rate applied; `None` if the amount was already in the target
+    /// currency.
+    pub rate: Option<FxRate>,
+}
+
+impl FxConversion {
+    /// Records `amount` as already being in the target currency.
+    #[must_use]
+    pub fn identity(amount: Money) -> Self {
+        Self {
+            original: amount.clone(),
+            converted: amount,
+            rate: None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn currency(code: &str) -> Currency {
+        Currency::new(code).unwrap()
+    }
+
+    fn rate(from: &str, to: &str, rate: Decimal) -> FxRate {
+        FxRate::new(
+            currency(from),
+            currency(to),
+            rate,
+            ReferencePriceSource::ClobMid,
+            Timestamp::from_secs(1_000).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn currency_codes_are_validated_and_uppercased() {
+        assert_eq!(currency(" usdc ").as_str(), "USDC");
+        assert!(Currency::new("").is_err());
+        assert!(Currency::new("BTC/USD").is_err());
+        assert!(Currency::new("A".repeat(17)).is_err());
+
+        let json = serde_json::to_string(&currency("eth")).unwrap();
+        assert_eq!(json, "\"ETH\"");
+        assert!(serde_json::from_str::<Currency>("\"\"").is_err());
+    }
+
+    #[test]
+    fn arithmetic_refuses_to_mix_currencies() {
+        let usd = Money::new(Decimal::new(100, 0), Currency::usd());
+        let eur = Money::new(Decimal::new(100, 0), currency("EUR"));
+
+        assert_eq!(
+            usd.checked_sub(&Money::new(Decimal::new(30, 0), Currency::usd()))
+                .unwrap()
+                .amount(),
+            Decimal::new(70, 0)
+        );
+        assert_eq!(
+            usd.checked_add(&eur),
+            Err(MoneyError::CurrencyMismatch {
+                expected: Currency::usd(),
+                actual: currency("EUR"),
+            })
+        );
+        assert!(matches!(
+            Money::new(Decimal::MAX, Currency::usd()).checked_mul(Decimal::TWO),
+            Err(MoneyError::Arithmetic(ArithmeticError::Overflow))
+        ));
+    }
+
+    #[test]
+    fn conversion_applies_the_rate_from_the_money_currency() {
+        let eur = Money::new(Decimal::new(100, 0), currency("EUR"));
+        let eur_usd = rate("EUR", "USD", Decimal::new(110, 2));
+
+        let usd = eur.convert(&eur_usd).unwrap();
+        assert_eq!(usd, Money::new(Decimal::new(110, 0), Currency::usd()));
+        assert!(usd.convert(&eur_usd).is_err());
+
+        let usd_eur = eur_usd.inverse().unwrap();
+        assert_eq!(usd_eur.from, Currency::usd());
+        assert_eq!(
+            usd.convert(&usd_eur).unwrap().amount().round_dp(8),
+            Decimal::new(100, 0)
+        );
+    }
+
+    #[test]
+    fn rates_must_be_positive() {
+        let result = FxRate::new(
+            currency("EUR"),
+            Currency::usd(),
+            Decimal::ZERO,
+            ReferencePriceSource::ClobMid,
+            Timestamp::now(),
+        );
+        assert!(matches!(result, Err(MoneyError::InvalidRate { .. })));
+    }
+}
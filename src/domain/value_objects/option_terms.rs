@@ -0,0 +1,167 @@
+//! # Option Terms Value Object
+//!
+//! Contract terms of an option instrument: strike, expiry and call/put.
+//!
+//! This module provides [`OptionKind`] and [`OptionTerms`]. Terms are
+//! attached to [`AssetClass::CryptoOptions`](super::AssetClass::CryptoOptions)
+//! instruments so that the legs of a strategy can be told apart.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::option_terms::{OptionKind, OptionTerms};
+//! use otc_rfq::domain::value_objects::{Price, Timestamp};
+//!
+//! let expiry = Timestamp::from_secs(1_719_561_600).unwrap(); // 2024-06-28 08:00 UTC
+//! let terms = OptionTerms::new(Price::new(60_000.0).unwrap(), expiry, OptionKind::Call).unwrap();
+//!
+//! assert_eq!(terms.to_string(), "28JUN24-60000-C");
+//! ```
+
+use super::price::Price;
+use super::timestamp::Timestamp;
+use crate::domain::errors::{DomainError, DomainResult};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Call or put.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OptionKind {
+    /// Right to buy at the strike.
+    Call,
+    /// Right to sell at the strike.
+    Put,
+}
+
+impl OptionKind {
+    /// Returns the single-letter code used in instrument names (`C` or `P`).
+    #[inline]
+    #[must_use]
+    pub const fn code(self) -> char {
+        match self {
+            Self::Call => 'C',
+            Self::Put => 'P',
+        }
+    }
+}
+
+impl fmt::Display for OptionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Call => write!(f, "CALL"),
+            Self::Put => write!(f, "PUT"),
+        }
+    }
+}
+
+/// Strike, expiry and kind of an option contract.
+///
+/// # Invariants
+///
+/// - Strike is positive
+///
+/// Whether the expiry lies in the future is checked when an instrument is
+/// built, not here, so that terms of expired contracts can still be loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OptionTerms {
+    /// Strike price.
+    strike: Price,
+    /// Expiry time.
+    expiry: Timestamp,
+    /// Call or put.
+    kind: OptionKind,
+}
+
+impl OptionTerms {
+    /// Creates option terms.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if the strike is not positive.
+    pub fn new(strike: Price, expiry: Timestamp, kind: OptionKind) -> DomainResult<Self> {
+        if !strike.is_positive() {
+            return Err(DomainError::ValidationError(
+                "option strike must be positive".to_string(),
+            ));
+        }
+        Ok(Self {
+            strike,
+            expiry,
+            kind,
+        })
+    }
+
+    /// Returns the strike price.
+    #[inline]
+    #[must_use]
+    pub fn strike(&self) -> Price {
+        self.strike
+    }
+
+    /// Returns the expiry time.
+    #[inline]
+    #[must_use]
+    pub fn expiry(&self) -> Timestamp {
+        self.expiry
+    }
+
+    /// Returns whether this is a call or a put.
+    #[inline]
+    #[must_use]
+    pub fn kind(&self) -> OptionKind {
+        self.kind
+    }
+}
+
+/// Formats as `DDMMMYY-STRIKE-K`, e.g. `28JUN24-60000-C`.
+impl fmt::Display for OptionTerms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}-{}",
+            self.expiry
+                .as_datetime()
+                .format("%d%b%y")
+                .to_string()
+                .to_uppercase(),
+            self.strike.get().normalize(),
+            self.kind.code()
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn expiry() -> Timestamp {
+        Timestamp::from_secs(1_719_561_600).unwrap()
+    }
+
+    #[test]
+    fn rejects_non_positive_strike() {
+        let result = OptionTerms::new(Price::zero(), expiry(), OptionKind::Put);
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+    }
+
+    #[test]
+    fn display_uses_exchange_style_code() {
+        let call = OptionTerms::new(Price::new(60_000.0).unwrap(), expiry(), OptionKind::Call);
+        assert_eq!(call.unwrap().to_string(), "28JUN24-60000-C");
+
+        let put = OptionTerms::new(Price::new(2_512.5).unwrap(), expiry(), OptionKind::Put);
+        assert_eq!(put.unwrap().to_string(), "28JUN24-2512.5-P");
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let terms =
+            OptionTerms::new(Price::new(60_000.0).unwrap(), expiry(), OptionKind::Call).unwrap();
+        let json = serde_json::to_value(terms).unwrap();
+        assert_eq!(json.get("kind").unwrap(), "CALL");
+        let back: OptionTerms = serde_json::from_value(json).unwrap();
+        assert_eq!(back, terms);
+    }
+}
@@ -0,0 +1,266 @@
+//! # Premium Value Object
+//!
+//! Signed decimal premium with checked arithmetic.
+//!
+//! This module provides the [`Premium`] type for the net premium of a
+//! multi-leg strategy quote. Unlike [`Price`], a premium may be negative.
+//!
+//! # Sign Convention
+//!
+//! - Positive premium = debit (the package buyer pays)
+//! - Negative premium = credit (the package buyer receives)
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::premium::Premium;
+//! use rust_decimal::Decimal;
+//!
+//! let call_spread = Premium::new(Decimal::new(250, 2));
+//! let iron_condor = Premium::new(Decimal::new(-120, 2));
+//!
+//! assert!(call_spread.is_debit());
+//! assert!(iron_condor.is_credit());
+//! assert_eq!(iron_condor.abs().get(), Decimal::new(120, 2));
+//! ```
+
+use super::arithmetic::{ArithmeticError, ArithmeticResult, CheckedArithmetic};
+use super::price::Price;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A signed net premium.
+///
+/// Represents the single price quoted for a whole strategy package.
+/// Debit packages have a positive premium, credit packages a negative one.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::premium::Premium;
+/// use otc_rfq::domain::value_objects::Price;
+/// use rust_decimal::Decimal;
+///
+/// let debit = Premium::from(Price::new(3.5).unwrap());
+/// let credit = Premium::new(Decimal::new(-15, 1));
+///
+/// let net = debit.safe_add(credit).unwrap();
+/// assert_eq!(net.get(), Decimal::new(20, 1));
+/// ```
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Premium(Decimal);
+
+impl Premium {
+    /// Zero premium constant.
+    pub const ZERO: Self = Self(Decimal::ZERO);
+
+    /// Creates a premium from a signed Decimal value.
+    #[inline]
+    #[must_use]
+    pub const fn new(value: Decimal) -> Self {
+        Self(value)
+    }
+
+    /// Creates a premium from an f64 value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ArithmeticError::InvalidValue` if the value is not finite.
+    #[must_use = "this returns a Result that should be handled"]
+    pub fn from_f64(value: f64) -> ArithmeticResult<Self> {
+        Decimal::try_from(value)
+            .map(Self)
+            .map_err(|_| ArithmeticError::InvalidValue("invalid float"))
+    }
+
+    /// Returns the inner Decimal value.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> Decimal {
+        self.0
+    }
+
+    /// Returns true if the premium is zero.
+    #[inline]
+    #[must_use]
+    pub fn is_zero(self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns true if the package buyer pays (positive premium).
+    #[inline]
+    #[must_use]
+    pub fn is_debit(self) -> bool {
+        self.0 > Decimal::ZERO
+    }
+
+    /// Returns true if the package buyer receives (negative premium).
+    #[inline]
+    #[must_use]
+    pub fn is_credit(self) -> bool {
+        self.0 < Decimal::ZERO
+    }
+
+    /// Returns the unsigned magnitude of the premium as a price.
+    #[inline]
+    #[must_use]
+    pub fn abs(self) -> Price {
+        Price::from_decimal(self.0.abs()).unwrap_or(Price::ZERO)
+    }
+
+    /// Safely adds another premium.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ArithmeticError::Overflow` if the result would overflow.
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn safe_add(self, rhs: Self) -> ArithmeticResult<Self> {
+        Ok(Self(self.0.safe_add(rhs.0)?))
+    }
+
+    /// Safely subtracts another premium.
+    ///
+    /// The result may be negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ArithmeticError::Overflow` if the result would overflow.
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn safe_sub(self, rhs: Self) -> ArithmeticResult<Self> {
+        Ok(Self(self.0.safe_sub(rhs.0)?))
+    }
+
+    /// Safely multiplies by a Decimal factor.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ArithmeticError::Overflow` if the result would overflow.
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn safe_mul(self, factor: Decimal) -> ArithmeticResult<Self> {
+        Ok(Self(self.0.safe_mul(factor)?))
+    }
+
+    /// Returns the premium with its sign flipped.
+    #[inline]
+    #[must_use]
+    pub fn negate(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl fmt::Display for Premium {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Price> for Premium {
+    fn from(price: Price) -> Self {
+        Self(price.get())
+    }
+}
+
+impl From<Decimal> for Premium {
+    fn from(value: Decimal) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Premium> for Decimal {
+    fn from(premium: Premium) -> Self {
+        premium.0
+    }
+}
+
+impl FromStr for Premium {
+    type Err = ArithmeticError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Decimal::from_str(s)
+            .map(Self)
+            .map_err(|_| ArithmeticError::InvalidValue("invalid decimal"))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_classification() {
+        assert!(Premium::new(Decimal::ONE).is_debit());
+        assert!(Premium::new(Decimal::NEGATIVE_ONE).is_credit());
+        assert!(!Premium::ZERO.is_debit());
+        assert!(!Premium::ZERO.is_credit());
+        assert!(Premium::ZERO.is_zero());
+    }
+
+    #[test]
+    fn abs_returns_magnitude() {
+        let credit = Premium::new(Decimal::new(-250, 2));
+        assert_eq!(
+            credit.abs(),
+            Price::from_decimal(Decimal::new(250, 2)).unwrap()
+        );
+        assert_eq!(credit.negate(), Premium::new(Decimal::new(250, 2)));
+    }
+
+    #[test]
+    fn arithmetic_crosses_zero() {
+        let debit = Premium::new(Decimal::new(2, 0));
+        let credit = Premium::new(Decimal::new(-5, 0));
+
+        assert_eq!(debit.safe_add(credit).unwrap().get(), Decimal::new(-3, 0));
+        assert_eq!(debit.safe_sub(credit).unwrap().get(), Decimal::new(7, 0));
+        assert_eq!(
+            credit.safe_mul(Decimal::TWO).unwrap().get(),
+            Decimal::new(-10, 0)
+        );
+    }
+
+    #[test]
+    fn arithmetic_overflow_is_checked() {
+        let max = Premium::new(Decimal::MAX);
+        assert!(matches!(
+            max.safe_add(Premium::new(Decimal::ONE)),
+            Err(ArithmeticError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn ordering_follows_sign() {
+        let credit = Premium::new(Decimal::new(-1, 0));
+        let debit = Premium::new(Decimal::new(1, 0));
+        assert!(credit < Premium::ZERO);
+        assert!(Premium::ZERO < debit);
+    }
+
+    #[test]
+    fn from_price_and_str() {
+        let price = Price::new(12.5).unwrap();
+        assert_eq!(Premium::from(price).get(), price.get());
+        assert_eq!(
+            "-1.25".parse::<Premium>().unwrap().get(),
+            Decimal::new(-125, 2)
+        );
+        assert!("abc".parse::<Premium>().is_err());
+    }
+
+    #[test]
+    fn serde_roundtrip_keeps_sign() {
+        let premium = Premium::new(Decimal::new(-375, 2));
+        let json = serde_json::to_string(&premium).unwrap();
+        assert_eq!(json, "\"-3.75\"");
+        let back: Premium = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, premium);
+    }
+}
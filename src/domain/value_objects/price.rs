@@ -18,7 +18,10 @@
 //! assert_eq!(sum.get().to_string(), "150.75");
 //! ```
 
-use super::arithmetic::{ArithmeticError, ArithmeticResult, CheckedArithmetic};
+use super::arithmetic::{
+    ArithmeticError, ArithmeticResult, BPS_PER_UNIT, CheckedArithmetic, Rounding, bps_of, div_round,
+};
+use super::signed_amount::SignedDecimalAmount;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
@@ -194,6 +197,84 @@ impl Price {
         Ok(Self(result))
     }
 
+    /// Rounds to a multiple of `tick_size` in the given direction.
+    ///
+    /// Values already on a tick boundary are returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ArithmeticError::InvalidValue` if `tick_size` is not positive.
+    /// Returns `ArithmeticError::Overflow` if the result would overflow.
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn round_to_tick(self, tick_size: Decimal, rounding: Rounding) -> ArithmeticResult<Self> {
+        if tick_size <= Decimal::ZERO {
+            return Err(ArithmeticError::InvalidValue("tick size must be positive"));
+        }
+        let ticks = div_round(self.0, tick_size, rounding)?;
+        Ok(Self(ticks.safe_mul(tick_size)?))
+    }
+
+    /// Moves the price by `bps` basis points.
+    ///
+    /// Computes `price × (10,000 + bps) / 10,000`, rounded to 8 decimal
+    /// places in the given direction. Negative `bps` lowers the price.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ArithmeticError::Overflow` if the result would overflow.
+    /// Returns `ArithmeticError::InvalidValue` if `bps` is below -10,000.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otc_rfq::domain::value_objects::{Price, Rounding};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let price = Price::from_decimal(Decimal::new(50_000, 0)).unwrap();
+    /// let upper = price.apply_bps(25, Rounding::Down).unwrap();
+    /// assert_eq!(upper.get(), Decimal::new(50_125, 0));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn apply_bps(self, bps: i64, rounding: Rounding) -> ArithmeticResult<Self> {
+        let factor = bps.safe_add(10_000)?;
+        let result = bps_of(self.0, factor, rounding)?;
+        if result.is_sign_negative() && !result.is_zero() {
+            return Err(ArithmeticError::InvalidValue(
+                "basis point adjustment cannot make price negative",
+            ));
+        }
+        Self::from_decimal(result.abs())
+    }
+
+    /// Returns this price's deviation from `other`, in basis points.
+    ///
+    /// Computes `(self - other) / other × 10,000`. Positive when this
+    /// price is above `other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ArithmeticError::DivisionByZero` if `other` is zero.
+    /// Returns `ArithmeticError::Overflow` if the result would overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otc_rfq::domain::value_objects::Price;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let quote = Price::new(99.0).unwrap();
+    /// let reference = Price::new(100.0).unwrap();
+    /// let deviation = quote.deviation_bps_from(&reference).unwrap();
+    /// assert_eq!(deviation.get(), Decimal::new(-100, 0));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn deviation_bps_from(&self, other: &Self) -> ArithmeticResult<SignedDecimalAmount> {
+        let diff = self.0.safe_sub(other.0)?;
+        let bps = diff.safe_div(other.0)?.safe_mul(BPS_PER_UNIT)?;
+        Ok(SignedDecimalAmount::new(bps))
+    }
+
     /// Returns the minimum of two prices.
     #[inline]
     #[must_use]
@@ -375,6 +456,153 @@ mod tests {
         }
     }
 
+    mod rounding {
+        use super::*;
+
+        #[test]
+        fn round_to_tick_down_and_up() {
+            let price: Price = "100.37".parse().unwrap();
+            let tick = Decimal::new(5, 2);
+            assert_eq!(
+                price.round_to_tick(tick, Rounding::Down).unwrap().get(),
+                Decimal::new(10035, 2)
+            );
+            assert_eq!(
+                price.round_to_tick(tick, Rounding::Up).unwrap().get(),
+                Decimal::new(10040, 2)
+            );
+        }
+
+        #[test]
+        fn round_to_tick_on_boundary_is_unchanged() {
+            let price: Price = "100.35".parse().unwrap();
+            let tick = Decimal::new(5, 2);
+            assert_eq!(price.round_to_tick(tick, Rounding::Down).unwrap(), price);
+            assert_eq!(price.round_to_tick(tick, Rounding::Up).unwrap(), price);
+        }
+
+        #[test]
+        fn round_to_tick_rejects_non_positive_tick() {
+            let price = Price::new(100.0).unwrap();
+            assert!(matches!(
+                price.round_to_tick(Decimal::ZERO, Rounding::Down),
+                Err(ArithmeticError::InvalidValue(_))
+            ));
+            assert!(matches!(
+                price.round_to_tick(Decimal::NEGATIVE_ONE, Rounding::Up),
+                Err(ArithmeticError::InvalidValue(_))
+            ));
+        }
+    }
+
+    mod basis_points {
+        use super::*;
+
+        fn price(value: i64, scale: u32) -> Price {
+            Price::from_decimal(Decimal::new(value, scale)).unwrap()
+        }
+
+        #[test]
+        fn apply_bps_exact() {
+            let base = price(50_000, 0);
+            assert_eq!(
+                base.apply_bps(25, Rounding::Down).unwrap().get(),
+                Decimal::new(50_125, 0)
+            );
+            assert_eq!(
+                base.apply_bps(-25, Rounding::Up).unwrap().get(),
+                Decimal::new(49_875, 0)
+            );
+            assert_eq!(base.apply_bps(0, Rounding::Up).unwrap(), base);
+        }
+
+        #[test]
+        fn apply_bps_half_way_up() {
+            // 0.000001 + 50 bps = 0.000001005
+            let base = price(1, 6);
+            assert_eq!(
+                base.apply_bps(50, Rounding::Down).unwrap().get(),
+                Decimal::new(100, 8)
+            );
+            assert_eq!(
+                base.apply_bps(50, Rounding::Up).unwrap().get(),
+                Decimal::new(101, 8)
+            );
+        }
+
+        #[test]
+        fn apply_bps_half_way_down() {
+            // 0.000001 - 50 bps = 0.000000995
+            let base = price(1, 6);
+            assert_eq!(
+                base.apply_bps(-50, Rounding::Down).unwrap().get(),
+                Decimal::new(99, 8)
+            );
+            assert_eq!(
+                base.apply_bps(-50, Rounding::Up).unwrap().get(),
+                Decimal::new(100, 8)
+            );
+        }
+
+        #[test]
+        fn apply_bps_full_reduction_is_zero() {
+            assert!(
+                price(100, 0)
+                    .apply_bps(-10_000, Rounding::Up)
+                    .unwrap()
+                    .is_zero()
+            );
+            assert!(matches!(
+                price(100, 0).apply_bps(-10_001, Rounding::Up),
+                Err(ArithmeticError::InvalidValue(_))
+            ));
+        }
+
+        #[test]
+        fn apply_bps_overflows_at_extremes() {
+            let max = Price::from_decimal(Decimal::MAX).unwrap();
+            assert_eq!(
+                max.apply_bps(10_000, Rounding::Down),
+                Err(ArithmeticError::Overflow)
+            );
+            assert_eq!(
+                price(1, 0).apply_bps(i64::MAX, Rounding::Down),
+                Err(ArithmeticError::Overflow)
+            );
+        }
+
+        #[test]
+        fn deviation_bps_from_is_signed() {
+            let reference = price(100, 0);
+            assert_eq!(
+                price(101, 0).deviation_bps_from(&reference).unwrap().get(),
+                Decimal::new(100, 0)
+            );
+            assert_eq!(
+                price(995, 1).deviation_bps_from(&reference).unwrap().get(),
+                Decimal::new(-50, 0)
+            );
+            assert!(reference.deviation_bps_from(&reference).unwrap().is_zero());
+        }
+
+        #[test]
+        fn deviation_bps_from_zero_fails() {
+            assert_eq!(
+                price(1, 0).deviation_bps_from(&Price::ZERO),
+                Err(ArithmeticError::DivisionByZero)
+            );
+        }
+
+        #[test]
+        fn deviation_bps_overflows_at_extremes() {
+            let max = Price::from_decimal(Decimal::MAX).unwrap();
+            assert_eq!(
+                max.deviation_bps_from(&price(1, 0)),
+                Err(ArithmeticError::Overflow)
+            );
+        }
+    }
+
     mod comparison {
         use super::*;
 
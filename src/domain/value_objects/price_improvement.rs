@@ -26,11 +26,12 @@
 //! ).unwrap();
 //!
 //! assert!(improvement.is_positive());
-//! assert_eq!(improvement.improvement_bps(), Decimal::new(10, 0)); // +10 bps
+//! assert_eq!(improvement.improvement_bps().get(), Decimal::new(10, 0)); // +10 bps
 //! ```
 
 use crate::domain::value_objects::enums::OrderSide;
 use crate::domain::value_objects::price::Price;
+use crate::domain::value_objects::signed_amount::SignedDecimalAmount;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -129,7 +130,7 @@ pub struct PriceImprovement {
     /// Order side used for calculation.
     side: OrderSide,
     /// Improvement in basis points (positive = better, negative = worse).
-    improvement_bps: Decimal,
+    improvement_bps: SignedDecimalAmount,
 }
 
 impl PriceImprovement {
@@ -214,7 +215,7 @@ impl PriceImprovement {
             reference_price,
             source,
             side,
-            improvement_bps,
+            improvement_bps: SignedDecimalAmount::new(improvement_bps),
         })
     }
 
@@ -252,7 +253,7 @@ impl PriceImprovement {
     /// Negative values indicate the quote is worse than the reference.
     #[inline]
     #[must_use]
-    pub const fn improvement_bps(&self) -> Decimal {
+    pub const fn improvement_bps(&self) -> SignedDecimalAmount {
         self.improvement_bps
     }
 
@@ -260,14 +261,14 @@ impl PriceImprovement {
     #[inline]
     #[must_use]
     pub fn is_positive(&self) -> bool {
-        self.improvement_bps.is_sign_positive() && !self.improvement_bps.is_zero()
+        self.improvement_bps.is_positive()
     }
 
     /// Returns true if the improvement is negative (quote is worse than reference).
     #[inline]
     #[must_use]
     pub fn is_negative(&self) -> bool {
-        self.improvement_bps.is_sign_negative()
+        self.improvement_bps.is_negative()
     }
 
     /// Returns true if there is no improvement (quote equals reference).
@@ -298,23 +299,22 @@ impl PriceImprovement {
     /// ```
     #[must_use]
     pub fn as_percentage(&self) -> Decimal {
-        self.improvement_bps / Decimal::ONE_HUNDRED
+        self.improvement_bps.get() / Decimal::ONE_HUNDRED
     }
 
     /// Returns the absolute improvement in basis points.
     #[must_use]
     pub fn abs_improvement_bps(&self) -> Decimal {
-        self.improvement_bps.abs()
+        self.improvement_bps.get().abs()
     }
 }
 
 impl fmt::Display for PriceImprovement {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let sign = if self.is_positive() { "+" } else { "" };
         write!(
             f,
-            "PriceImprovement({}{} bps vs {})",
-            sign, self.improvement_bps, self.source
+            "PriceImprovement({} bps vs {})",
+            self.improvement_bps, self.source
         )
     }
 }
@@ -386,7 +386,7 @@ mod tests {
             assert!(improvement.is_positive());
             assert!(!improvement.is_negative());
             assert!(!improvement.is_zero());
-            assert_eq!(improvement.improvement_bps(), Decimal::ONE_HUNDRED);
+            assert_eq!(improvement.improvement_bps().get(), Decimal::ONE_HUNDRED);
             assert_eq!(improvement.as_percentage(), Decimal::ONE);
         }
 
@@ -404,7 +404,7 @@ mod tests {
             assert!(!improvement.is_positive());
             assert!(improvement.is_negative());
             assert!(!improvement.is_zero());
-            assert_eq!(improvement.improvement_bps(), Decimal::new(-100, 0));
+            assert_eq!(improvement.improvement_bps().get(), Decimal::new(-100, 0));
         }
 
         #[test]
@@ -420,7 +420,7 @@ mod tests {
 
             assert!(improvement.is_positive());
             assert!(!improvement.is_negative());
-            assert_eq!(improvement.improvement_bps(), Decimal::ONE_HUNDRED);
+            assert_eq!(improvement.improvement_bps().get(), Decimal::ONE_HUNDRED);
         }
 
         #[test]
@@ -436,7 +436,7 @@ mod tests {
 
             assert!(!improvement.is_positive());
             assert!(improvement.is_negative());
-            assert_eq!(improvement.improvement_bps(), Decimal::new(-100, 0));
+            assert_eq!(improvement.improvement_bps().get(), Decimal::new(-100, 0));
         }
 
         #[test]
@@ -452,7 +452,7 @@ mod tests {
             assert!(!improvement.is_positive());
             assert!(!improvement.is_negative());
             assert!(improvement.is_zero());
-            assert_eq!(improvement.improvement_bps(), Decimal::ZERO);
+            assert_eq!(improvement.improvement_bps().get(), Decimal::ZERO);
         }
 
         #[test]
@@ -480,7 +480,7 @@ mod tests {
 
             assert!(improvement.is_positive());
             // 0.01 / 100 * 10000 = 1 bp
-            assert_eq!(improvement.improvement_bps(), Decimal::ONE);
+            assert_eq!(improvement.improvement_bps().get(), Decimal::ONE);
         }
 
         #[test]
@@ -593,7 +593,7 @@ mod tests {
             .unwrap();
 
             assert!(improvement.is_positive());
-            assert_eq!(improvement.improvement_bps(), Decimal::new(10, 0));
+            assert_eq!(improvement.improvement_bps().get(), Decimal::new(10, 0));
         }
 
         #[test]
@@ -610,7 +610,7 @@ mod tests {
             .unwrap();
 
             assert!(improvement.is_negative());
-            assert_eq!(improvement.improvement_bps(), Decimal::new(-50, 0));
+            assert_eq!(improvement.improvement_bps().get(), Decimal::new(-50, 0));
         }
 
         #[test]
@@ -626,7 +626,7 @@ mod tests {
             .unwrap();
 
             assert!(improvement.is_positive());
-            assert_eq!(improvement.improvement_bps(), Decimal::new(5, 0));
+            assert_eq!(improvement.improvement_bps().get(), Decimal::new(5, 0));
         }
 
         #[test]
@@ -642,7 +642,7 @@ mod tests {
             .unwrap();
 
             assert!(improvement.is_positive());
-            assert_eq!(improvement.improvement_bps(), Decimal::new(5, 0));
+            assert_eq!(improvement.improvement_bps().get(), Decimal::new(5, 0));
         }
     }
 }
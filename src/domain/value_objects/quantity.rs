@@ -18,7 +18,9 @@
 //! assert_eq!(sum.get().to_string(), "150");
 //! ```
 
-use super::arithmetic::{ArithmeticError, ArithmeticResult, CheckedArithmetic};
+use super::arithmetic::{
+    ArithmeticError, ArithmeticResult, CheckedArithmetic, Rounding, div_round, percent_of,
+};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
@@ -194,6 +196,53 @@ impl Quantity {
         Ok(Self(result))
     }
 
+    /// Rounds to a multiple of `lot_size` in the given direction.
+    ///
+    /// Values already on a lot boundary are returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ArithmeticError::InvalidValue` if `lot_size` is not positive.
+    /// Returns `ArithmeticError::Overflow` if the result would overflow.
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn round_to_lot(self, lot_size: Decimal, rounding: Rounding) -> ArithmeticResult<Self> {
+        if lot_size <= Decimal::ZERO {
+            return Err(ArithmeticError::InvalidValue("lot size must be positive"));
+        }
+        let lots = div_round(self.0, lot_size, rounding)?;
+        Ok(Self(lots.safe_mul(lot_size)?))
+    }
+
+    /// Returns `pct` percent of this quantity.
+    ///
+    /// Rounded to 8 decimal places in the given direction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ArithmeticError::Overflow` if the result would overflow.
+    /// Returns `ArithmeticError::InvalidValue` if `pct` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otc_rfq::domain::value_objects::{Quantity, Rounding};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let qty = Quantity::new(3.0).unwrap();
+    /// let half = qty.percent_of(Decimal::new(50, 0), Rounding::Down).unwrap();
+    /// assert_eq!(half.get(), Decimal::new(15, 1));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn percent_of(self, pct: Decimal, rounding: Rounding) -> ArithmeticResult<Self> {
+        if pct.is_sign_negative() && !pct.is_zero() {
+            return Err(ArithmeticError::InvalidValue(
+                "percentage cannot be negative",
+            ));
+        }
+        Self::from_decimal(percent_of(self.0, pct, rounding)?.abs())
+    }
+
     /// Returns the minimum of two quantities.
     #[inline]
     #[must_use]
@@ -423,6 +472,104 @@ mod tests {
         }
     }
 
+    mod rounding {
+        use super::*;
+
+        #[test]
+        fn round_to_lot_down_and_up() {
+            let quantity: Quantity = "1.234".parse().unwrap();
+            let lot = Decimal::new(1, 2);
+            assert_eq!(
+                quantity.round_to_lot(lot, Rounding::Down).unwrap().get(),
+                Decimal::new(123, 2)
+            );
+            assert_eq!(
+                quantity.round_to_lot(lot, Rounding::Up).unwrap().get(),
+                Decimal::new(124, 2)
+            );
+        }
+
+        #[test]
+        fn round_to_lot_on_boundary_is_unchanged() {
+            let quantity: Quantity = "1.25".parse().unwrap();
+            let lot = Decimal::new(5, 2);
+            assert_eq!(
+                quantity.round_to_lot(lot, Rounding::Down).unwrap(),
+                quantity
+            );
+            assert_eq!(quantity.round_to_lot(lot, Rounding::Up).unwrap(), quantity);
+        }
+
+        #[test]
+        fn round_to_lot_below_one_lot_rounds_down_to_zero() {
+            let quantity: Quantity = "0.004".parse().unwrap();
+            let lot = Decimal::new(1, 2);
+            assert!(
+                quantity
+                    .round_to_lot(lot, Rounding::Down)
+                    .unwrap()
+                    .is_zero()
+            );
+            assert_eq!(quantity.round_to_lot(lot, Rounding::Up).unwrap().get(), lot);
+        }
+
+        #[test]
+        fn round_to_lot_rejects_non_positive_lot() {
+            let quantity = Quantity::new(1.0).unwrap();
+            assert!(matches!(
+                quantity.round_to_lot(Decimal::ZERO, Rounding::Down),
+                Err(ArithmeticError::InvalidValue(_))
+            ));
+        }
+    }
+
+    mod percentage {
+        use super::*;
+
+        #[test]
+        fn percent_of_exact() {
+            let qty = Quantity::from_decimal(Decimal::new(200, 0)).unwrap();
+            assert_eq!(
+                qty.percent_of(Decimal::new(25, 0), Rounding::Down)
+                    .unwrap()
+                    .get(),
+                Decimal::new(50, 0)
+            );
+            assert!(
+                qty.percent_of(Decimal::ZERO, Rounding::Up)
+                    .unwrap()
+                    .is_zero()
+            );
+        }
+
+        #[test]
+        fn percent_of_half_way() {
+            // 50% of 0.00000001 = 0.000000005
+            let qty = Quantity::from_decimal(Decimal::new(1, 8)).unwrap();
+            let pct = Decimal::new(50, 0);
+            assert!(qty.percent_of(pct, Rounding::Down).unwrap().is_zero());
+            assert_eq!(qty.percent_of(pct, Rounding::Up).unwrap(), qty);
+        }
+
+        #[test]
+        fn percent_of_rejects_negative_pct() {
+            let qty = Quantity::new(1.0).unwrap();
+            assert!(matches!(
+                qty.percent_of(Decimal::NEGATIVE_ONE, Rounding::Down),
+                Err(ArithmeticError::InvalidValue(_))
+            ));
+        }
+
+        #[test]
+        fn percent_of_overflows_at_extremes() {
+            let max = Quantity::from_decimal(Decimal::MAX).unwrap();
+            assert_eq!(
+                max.percent_of(Decimal::new(200, 0), Rounding::Down),
+                Err(ArithmeticError::Overflow)
+            );
+        }
+    }
+
     mod comparison {
         use super::*;
 
@@ -0,0 +1,156 @@
+//! # Quantity Disclosure
+//!
+//! How much of an RFQ's size is revealed to quoting venues.
+//!
+//! Clients trading size can mask it to limit information leakage:
+//!
+//! | Disclosure | Venues see |
+//! |------------|------------|
+//! | `Exact` | The requested quantity |
+//! | `Bucketed` | The quantity rounded up to the next bucket boundary |
+//! | `Hidden` | Instrument and side only; pricing is indicative |
+//!
+//! Quotes against a masked size are indicative and are firmed up at the
+//! true quantity before they can be selected.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::{Quantity, QuantityDisclosure};
+//!
+//! let bucketed = QuantityDisclosure::Bucketed {
+//!     bucket: Quantity::new(10.0).unwrap(),
+//! };
+//! let disclosed = bucketed.disclose(Quantity::new(12.5).unwrap());
+//! assert_eq!(disclosed, Some(Quantity::new(20.0).unwrap()));
+//!
+//! assert_eq!(QuantityDisclosure::Hidden.disclose(Quantity::new(12.5).unwrap()), None);
+//! ```
+
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::{Quantity, Rounding};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How much of an RFQ's quantity is revealed to quoting venues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum QuantityDisclosure {
+    /// Venues see the requested quantity.
+    #[default]
+    Exact,
+    /// Venues see the quantity rounded up to a multiple of `bucket`.
+    Bucketed {
+        /// Bucket size; positive.
+        bucket: Quantity,
+    },
+    /// Venues see no quantity and quote indicatively.
+    Hidden,
+}
+
+impl QuantityDisclosure {
+    /// Returns true if venues are not shown the exact quantity.
+    #[must_use]
+    #[inline]
+    pub fn is_masked(&self) -> bool {
+        !matches!(self, Self::Exact)
+    }
+
+    /// Returns the quantity venues are shown for a request of `quantity`.
+    ///
+    /// `None` means no size is disclosed. A bucket the size cannot be
+    /// rounded to (rejected by [`validate_for`](Self::validate_for)) also
+    /// withholds the size rather than falling back to the exact one.
+    #[must_use]
+    pub fn disclose(&self, quantity: Quantity) -> Option<Quantity> {
+        match self {
+            Self::Exact => Some(quantity),
+            Self::Bucketed { bucket } => quantity.round_to_lot(bucket.get(), Rounding::Up).ok(),
+            Self::Hidden => None,
+        }
+    }
+
+    /// Checks that this disclosure can apply to an RFQ for `quantity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if the bucket is not positive
+    /// or `quantity` cannot be rounded up to it.
+    pub fn validate_for(&self, quantity: Quantity) -> DomainResult<()> {
+        if let Self::Bucketed { bucket } = self {
+            if !bucket.is_positive() {
+                return Err(DomainError::ValidationError(
+                    "quantity disclosure bucket must be positive".to_string(),
+                ));
+            }
+            quantity
+                .round_to_lot(bucket.get(), Rounding::Up)
+                .map_err(|e| {
+                    DomainError::ValidationError(format!(
+                        "cannot bucket quantity {quantity} by {bucket}: {e}"
+                    ))
+                })?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for QuantityDisclosure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exact => write!(f, "EXACT"),
+            Self::Bucketed { bucket } => write!(f, "BUCKETED({bucket})"),
+            Self::Hidden => write!(f, "HIDDEN"),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn qty(value: f64) -> Quantity {
+        Quantity::new(value).unwrap()
+    }
+
+    #[test]
+    fn bucketed_rounds_up_to_boundary() {
+        let disclosure = QuantityDisclosure::Bucketed { bucket: qty(5.0) };
+        assert_eq!(disclosure.disclose(qty(0.1)), Some(qty(5.0)));
+        assert_eq!(disclosure.disclose(qty(10.0)), Some(qty(10.0)));
+        assert_eq!(disclosure.disclose(qty(10.01)), Some(qty(15.0)));
+        assert!(disclosure.is_masked());
+    }
+
+    #[test]
+    fn exact_and_hidden() {
+        assert_eq!(QuantityDisclosure::Exact.disclose(qty(3.0)), Some(qty(3.0)));
+        assert!(!QuantityDisclosure::Exact.is_masked());
+        assert_eq!(QuantityDisclosure::Hidden.disclose(qty(3.0)), None);
+    }
+
+    #[test]
+    fn rejects_zero_bucket() {
+        let disclosure = QuantityDisclosure::Bucketed {
+            bucket: Quantity::zero(),
+        };
+        assert!(matches!(
+            disclosure.validate_for(qty(1.0)),
+            Err(DomainError::ValidationError(_))
+        ));
+        assert_eq!(disclosure.disclose(qty(1.0)), None);
+    }
+
+    #[test]
+    fn serde_uses_type_tag() {
+        let disclosure = QuantityDisclosure::Bucketed { bucket: qty(5.0) };
+        let json = serde_json::to_value(disclosure).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "type": "BUCKETED", "bucket": "5" })
+        );
+        let back: QuantityDisclosure = serde_json::from_value(json).unwrap();
+        assert_eq!(back, disclosure);
+    }
+}
@@ -0,0 +1,394 @@
+//! # Quorum Policy Value Object
+//!
+//! Minimum competition an RFQ must draw before a quote can be selected.
+//!
+//! Best-execution policy requires larger trades to be priced against
+//! several competing quotes. A [`QuorumPolicy`] maps notional bands to a
+//! [`QuorumRequirement`] (minimum quote count and minimum distinct venues),
+//! and [`QuorumRules`] pairs a default policy with per-client and
+//! per-liquidity-tier overrides.
+//! Evaluating a policy yields a [`QuorumShortfall`] when the RFQ falls short.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::quorum_policy::{
+//!     QuorumBand, QuorumPolicy, QuorumRequirement,
+//! };
+//! use rust_decimal::Decimal;
+//!
+//! let policy = QuorumPolicy::new(vec![QuorumBand::new(
+//!     Decimal::from(1_000_000),
+//!     QuorumRequirement::new(3, 2),
+//! )])
+//! .unwrap();
+//!
+//! assert!(policy.evaluate(Decimal::from(500_000), 1, 1).is_none());
+//! assert!(policy.evaluate(Decimal::from(2_000_000), 2, 2).is_some());
+//! ```
+
+use super::ids::CounterpartyId;
+use super::liquidity_classification::LiquidityClassification;
+use crate::domain::errors::{DomainError, DomainResult};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Minimum competition required before a quote can be selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct QuorumRequirement {
+    /// Minimum number of quotes received.
+    #[serde(default)]
+    pub min_quotes: u32,
+    /// Minimum number of distinct quoting venues.
+    #[serde(default)]
+    pub min_venues: u32,
+}
+
+impl QuorumRequirement {
+    /// Creates a requirement.
+    #[must_use]
+    pub const fn new(min_quotes: u32, min_venues: u32) -> Self {
+        Self {
+            min_quotes,
+            min_venues,
+        }
+    }
+
+    /// Returns true if `quotes` quotes from `venues` venues satisfy this requirement.
+    #[must_use]
+    pub const fn is_met_by(&self, quotes: u32, venues: u32) -> bool {
+        quotes >= self.min_quotes && venues >= self.min_venues
+    }
+}
+
+/// Requirement applying from a notional threshold upwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuorumBand {
+    /// Smallest notional the band applies to.
+    pub min_notional: Decimal,
+    /// Requirement for trades in the band.
+    #[serde(flatten)]
+    pub requirement: QuorumRequirement,
+}
+
+impl QuorumBand {
+    /// Creates a band.
+    #[must_use]
+    pub const fn new(min_notional: Decimal, requirement: QuorumRequirement) -> Self {
+        Self {
+            min_notional,
+            requirement,
+        }
+    }
+}
+
+/// Quorum requirements keyed by notional band.
+///
+/// A trade is held to the band with the highest threshold at or below its
+/// notional. Trades below every band, and all trades under an empty
+/// policy, have no quorum requirement.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(try_from = "Vec<QuorumBand>", into = "Vec<QuorumBand>")]
+pub struct QuorumPolicy {
+    /// Bands ordered by ascending threshold.
+    bands: Vec<QuorumBand>,
+}
+
+impl QuorumPolicy {
+    /// Creates a policy from bands given in any order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if a threshold is negative or
+    /// two bands share a threshold.
+    pub fn new(mut bands: Vec<QuorumBand>) -> DomainResult<Self> {
+        if bands
+            .iter()
+            .any(|band| band.min_notional.is_sign_negative())
+        {
+            return Err(DomainError::ValidationError(
+                "quorum band threshold cannot be negative".to_string(),
+            ));
+        }
+        bands.sort_by_key(|band| band.min_notional);
+        if let Some(duplicate) = bands.windows(2).find_map(|pair| match pair {
+            [a, b] if a.min_notional == b.min_notional => Some(a.min_notional),
+            _ => None,
+        }) {
+            return Err(DomainError::ValidationError(format!(
+                "duplicate quorum band threshold: {duplicate}"
+            )));
+        }
+        Ok(Self { bands })
+    }
+
+    /// Returns the bands, ordered by ascending threshold.
+    #[inline]
+    #[must_use]
+    pub fn bands(&self) -> &[QuorumBand] {
+        &self.bands
+    }
+
+    /// Returns the requirement for a trade of `notional`, if any.
+    #[must_use]
+    pub fn requirement_for(&self, notional: Decimal) -> Option<QuorumRequirement> {
+        self.bands
+            .iter()
+            .rev()
+            .find(|band| band.min_notional <= notional)
+            .map(|band| band.requirement)
+    }
+
+    /// Checks `quotes` quotes from `venues` venues against the requirement
+    /// for a trade of `notional`.
+    ///
+    /// Returns the shortfall if the requirement is not met.
+    #[must_use]
+    pub fn evaluate(&self, notional: Decimal, quotes: u32, venues: u32) -> Option<QuorumShortfall> {
+        let required = self.requirement_for(notional)?;
+        (!required.is_met_by(quotes, venues)).then_some(QuorumShortfall {
+            notional,
+            required,
+            quotes,
+            venues,
+        })
+    }
+}
+
+impl TryFrom<Vec<QuorumBand>> for QuorumPolicy {
+    type Error = DomainError;
+
+    fn try_from(bands: Vec<QuorumBand>) -> DomainResult<Self> {
+        Self::new(bands)
+    }
+}
+
+impl From<QuorumPolicy> for Vec<QuorumBand> {
+    fn from(policy: QuorumPolicy) -> Self {
+        policy.bands
+    }
+}
+
+/// A default quorum policy plus per-client and per-tier overrides.
+///
+/// A client override takes precedence over a tier override, which takes
+/// precedence over the default.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct QuorumRules {
+    /// Policy for clients without an override.
+    #[serde(default)]
+    pub default: QuorumPolicy,
+    /// Policies replacing the default for specific clients.
+    #[serde(default)]
+    pub client_overrides: HashMap<CounterpartyId, QuorumPolicy>,
+    /// Policies replacing the default for instruments of a liquidity tier.
+    #[serde(default)]
+    pub liquidity_overrides: HashMap<LiquidityClassification, QuorumPolicy>,
+}
+
+impl QuorumRules {
+    /// Creates rules applying `default` to every client.
+    #[must_use]
+    pub fn new(default: QuorumPolicy) -> Self {
+        Self {
+            default,
+            client_overrides: HashMap::new(),
+            liquidity_overrides: HashMap::new(),
+        }
+    }
+
+    /// Replaces the default policy for `client_id`.
+    #[must_use]
+    pub fn with_override(mut self, client_id: CounterpartyId, policy: QuorumPolicy) -> Self {
+        self.client_overrides.insert(client_id, policy);
+        self
+    }
+
+    /// Replaces the default policy for instruments of `liquidity`.
+    #[must_use]
+    pub fn with_liquidity_override(
+        mut self,
+        liquidity: LiquidityClassification,
+        policy: QuorumPolicy,
+    ) -> Self {
+        self.liquidity_overrides.insert(liquidity, policy);
+        self
+    }
+
+    /// Returns the policy that applies to `client_id` trading an instrument
+    /// of `liquidity`.
+    #[must_use]
+    pub fn policy_for(
+        &self,
+        client_id: &CounterpartyId,
+        liquidity: LiquidityClassification,
+    ) -> &QuorumPolicy {
+        self.client_overrides
+            .get(client_id)
+            .or_else(|| self.liquidity_overrides.get(&liquidity))
+            .unwrap_or(&self.default)
+    }
+}
+
+/// How far an RFQ fell short of its quorum requirement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuorumShortfall {
+    /// Notional the requirement was looked up for.
+    pub notional: Decimal,
+    /// Requirement that was not met.
+    pub required: QuorumRequirement,
+    /// Quotes received.
+    pub quotes: u32,
+    /// Distinct venues that quoted.
+    pub venues: u32,
+}
+
+impl fmt::Display for QuorumShortfall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} quotes from {} venues, but notional {} requires {} quotes from {} venues",
+            self.quotes,
+            self.venues,
+            self.notional,
+            self.required.min_quotes,
+            self.required.min_venues
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn policy() -> QuorumPolicy {
+        QuorumPolicy::new(vec![
+            QuorumBand::new(Decimal::from(10_000_000), QuorumRequirement::new(5, 3)),
+            QuorumBand::new(Decimal::from(1_000_000), QuorumRequirement::new(3, 2)),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn picks_highest_band_at_or_below_notional() {
+        let policy = policy();
+        assert_eq!(policy.requirement_for(Decimal::from(999_999)), None);
+        assert_eq!(
+            policy.requirement_for(Decimal::from(1_000_000)),
+            Some(QuorumRequirement::new(3, 2))
+        );
+        assert_eq!(
+            policy.requirement_for(Decimal::from(50_000_000)),
+            Some(QuorumRequirement::new(5, 3))
+        );
+    }
+
+    #[test]
+    fn evaluate_checks_quotes_and_venues() {
+        let policy = policy();
+        let notional = Decimal::from(2_000_000);
+        assert!(policy.evaluate(notional, 3, 2).is_none());
+
+        let shortfall = policy.evaluate(notional, 3, 1).unwrap();
+        assert_eq!(shortfall.required, QuorumRequirement::new(3, 2));
+        assert_eq!(
+            shortfall.to_string(),
+            "3 quotes from 1 venues, but notional 2000000 requires 3 quotes from 2 venues"
+        );
+    }
+
+    #[test]
+    fn rejects_duplicate_or_negative_thresholds() {
+        let band = QuorumBand::new(Decimal::ONE, QuorumRequirement::new(2, 0));
+        assert!(matches!(
+            QuorumPolicy::new(vec![band, band]),
+            Err(DomainError::ValidationError(_))
+        ));
+        let negative = QuorumBand::new(Decimal::NEGATIVE_ONE, QuorumRequirement::new(2, 0));
+        assert!(QuorumPolicy::new(vec![negative]).is_err());
+    }
+
+    #[test]
+    fn rules_prefer_client_override() {
+        let vip = CounterpartyId::new("vip");
+        let rules = QuorumRules::new(policy()).with_override(vip.clone(), QuorumPolicy::default());
+        let notional = Decimal::from(2_000_000);
+
+        assert!(
+            rules
+                .policy_for(&vip, LiquidityClassification::Liquid)
+                .evaluate(notional, 1, 1)
+                .is_none()
+        );
+        assert!(
+            rules
+                .policy_for(
+                    &CounterpartyId::new("other"),
+                    LiquidityClassification::Liquid
+                )
+                .evaluate(notional, 1, 1)
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn rules_apply_liquidity_override_below_client_override() {
+        let vip = CounterpartyId::new("vip");
+        let strict = QuorumPolicy::new(vec![QuorumBand::new(
+            Decimal::ZERO,
+            QuorumRequirement::new(4, 3),
+        )])
+        .unwrap();
+        let rules = QuorumRules::new(policy())
+            .with_liquidity_override(LiquidityClassification::Liquid, strict.clone())
+            .with_override(vip.clone(), QuorumPolicy::default());
+        let other = CounterpartyId::new("other");
+
+        assert_eq!(
+            rules.policy_for(&other, LiquidityClassification::Liquid),
+            &strict
+        );
+        assert_eq!(
+            rules.policy_for(&other, LiquidityClassification::Illiquid),
+            &policy()
+        );
+        assert_eq!(
+            rules.policy_for(&vip, LiquidityClassification::Liquid),
+            &QuorumPolicy::default()
+        );
+    }
+
+    #[test]
+    fn rules_deserialize_from_toml() {
+        let rules: QuorumRules = toml::from_str(
+            r#"
+            default = [{ min_notional = "1000000", min_quotes = 3, min_venues = 2 }]
+
+            [client_overrides]
+            vip = []
+
+            [liquidity_overrides]
+            Illiquid = []
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            rules.default.requirement_for(Decimal::from(1_000_000)),
+            Some(QuorumRequirement::new(3, 2))
+        );
+        assert_eq!(
+            rules.policy_for(&CounterpartyId::new("vip"), LiquidityClassification::Liquid),
+            &QuorumPolicy::default()
+        );
+        assert_eq!(
+            rules.policy_for(
+                &CounterpartyId::new("other"),
+                LiquidityClassification::Illiquid
+            ),
+            &QuorumPolicy::default()
+        );
+    }
+}
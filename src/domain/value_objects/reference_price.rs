@@ -6,6 +6,7 @@
 //! - [`ReferencePriceSource`]: Origin of a reference price (CLOB mid, theoretical, Chainlink)
 //! - [`PriceBoundsConfig`]: Tolerance percentages per liquidity tier
 //! - [`PriceBoundsResult`]: Successful validation outcome with deviation details
+//! - [`PriceBoundsCheck`]: Audit record of the check made before execution
 //!
 //! # Examples
 //!
@@ -29,8 +30,10 @@
 
 use crate::domain::value_objects::liquidity_classification::LiquidityClassification;
 use crate::domain::value_objects::price::Price;
+use crate::domain::value_objects::timestamp::Timestamp;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// The origin of a reference price used for bounds validation.
@@ -103,6 +106,9 @@ pub struct PriceBoundsConfig {
     semi_liquid_tolerance_pct: Decimal,
     /// Maximum allowed deviation for illiquid instruments (fractional, e.g., 0.10 = ±10%).
     illiquid_tolerance_pct: Decimal,
+    /// Whether an administrator may execute despite a failed check.
+    #[serde(default)]
+    allow_admin_override: bool,
 }
 
 impl PriceBoundsConfig {
@@ -156,9 +162,35 @@ impl PriceBoundsConfig {
             liquid_tolerance_pct,
             semi_liquid_tolerance_pct,
             illiquid_tolerance_pct,
+            allow_admin_override: false,
         })
     }
 
+    /// Allows or forbids administrator overrides of failed checks.
+    ///
+    /// Overrides are disabled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otc_rfq::domain::value_objects::reference_price::PriceBoundsConfig;
+    ///
+    /// let config = PriceBoundsConfig::default().with_admin_override(true);
+    /// assert!(config.allows_admin_override());
+    /// ```
+    #[must_use]
+    pub const fn with_admin_override(mut self, allow: bool) -> Self {
+        self.allow_admin_override = allow;
+        self
+    }
+
+    /// Returns true if administrators may override a failed check.
+    #[inline]
+    #[must_use]
+    pub const fn allows_admin_override(&self) -> bool {
+        self.allow_admin_override
+    }
+
     /// Returns the tolerance for liquid instruments (fractional).
     #[inline]
     #[must_use]
@@ -217,6 +249,7 @@ impl Default for PriceBoundsConfig {
             liquid_tolerance_pct: Decimal::new(5, 2),
             semi_liquid_tolerance_pct: Decimal::new(75, 3),
             illiquid_tolerance_pct: Decimal::new(10, 2),
+            allow_admin_override: false,
         }
     }
 }
@@ -233,6 +266,152 @@ impl fmt::Display for PriceBoundsConfig {
     }
 }
 
+/// Price bounds tolerances per liquidity tier, with per-symbol overrides.
+///
+/// An override replaces the tier tolerance for one instrument symbol,
+/// whatever its liquidity classification.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::reference_price::{PriceBoundsConfig, PriceBoundsSettings};
+/// use otc_rfq::domain::value_objects::liquidity_classification::LiquidityClassification;
+/// use rust_decimal::Decimal;
+///
+/// let settings = PriceBoundsSettings::new(PriceBoundsConfig::default())
+///     .with_override("BTC/USD", Decimal::new(2, 2))
+///     .unwrap();
+/// assert_eq!(
+///     settings.tolerance_for("BTC/USD", LiquidityClassification::Liquid),
+///     Decimal::new(2, 2),
+/// );
+/// assert_eq!(
+///     settings.tolerance_for("ETH/USD", LiquidityClassification::Liquid),
+///     Decimal::new(5, 2),
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriceBoundsSettings {
+    /// Tolerances per liquidity tier.
+    defaults: PriceBoundsConfig,
+    /// Tolerances by instrument symbol, replacing the tier tolerance.
+    #[serde(default)]
+    overrides: BTreeMap<String, Decimal>,
+}
+
+impl PriceBoundsSettings {
+    /// Creates settings with the given tier tolerances and no overrides.
+    #[must_use]
+    pub fn new(defaults: PriceBoundsConfig) -> Self {
+        Self {
+            defaults,
+            overrides: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the tolerance for `symbol`, replacing any earlier override.
+    ///
+    /// Returns `None` if `tolerance_pct` is negative.
+    #[must_use]
+    pub fn with_override(
+        mut self,
+        symbol: impl Into<String>,
+        tolerance_pct: Decimal,
+    ) -> Option<Self> {
+        if tolerance_pct.is_sign_negative() {
+            return None;
+        }
+        self.overrides.insert(symbol.into(), tolerance_pct);
+        Some(self)
+    }
+
+    /// Returns the tolerances per liquidity tier.
+    #[inline]
+    #[must_use]
+    pub const fn defaults(&self) -> &PriceBoundsConfig {
+        &self.defaults
+    }
+
+    /// Returns the per-symbol overrides, ordered by symbol.
+    #[inline]
+    #[must_use]
+    pub const fn overrides(&self) -> &BTreeMap<String, Decimal> {
+        &self.overrides
+    }
+
+    /// Returns the tolerance for `symbol` at the given liquidity.
+    ///
+    /// The symbol's override wins over the tier tolerance.
+    #[must_use]
+    pub fn tolerance_for(&self, symbol: &str, liquidity: LiquidityClassification) -> Decimal {
+        self.overrides
+            .get(symbol)
+            .copied()
+            .unwrap_or_else(|| self.defaults.tolerance_for(liquidity))
+    }
+
+    /// Returns true if no tolerance is negative.
+    ///
+    /// Settings built with the constructors are always valid; this checks
+    /// deserialized settings.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        PriceBoundsConfig::new(
+            self.defaults.liquid_tolerance_pct,
+            self.defaults.semi_liquid_tolerance_pct,
+            self.defaults.illiquid_tolerance_pct,
+        )
+        .is_some()
+            && self.overrides.values().all(|t| !t.is_sign_negative())
+    }
+}
+
+impl From<PriceBoundsConfig> for PriceBoundsSettings {
+    fn from(defaults: PriceBoundsConfig) -> Self {
+        Self::new(defaults)
+    }
+}
+
+impl fmt::Display for PriceBoundsSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.defaults)?;
+        for (symbol, tolerance) in &self.overrides {
+            write!(f, ", {symbol}={}%", tolerance * Decimal::ONE_HUNDRED)?;
+        }
+        Ok(())
+    }
+}
+
+/// Audit record of a change to the price bounds settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriceBoundsConfigChange {
+    /// Who made the change.
+    pub changed_by: String,
+    /// When the change was made.
+    pub changed_at: Timestamp,
+    /// Settings before the change.
+    pub previous: PriceBoundsSettings,
+    /// Settings after the change.
+    pub current: PriceBoundsSettings,
+}
+
+impl PriceBoundsConfigChange {
+    /// Records a change made now by `changed_by`.
+    #[must_use]
+    pub fn new(
+        changed_by: impl Into<String>,
+        previous: PriceBoundsSettings,
+        current: PriceBoundsSettings,
+    ) -> Self {
+        Self {
+            changed_by: changed_by.into(),
+            changed_at: Timestamp::now(),
+            previous,
+            current,
+        }
+    }
+}
+
 /// Result of a successful price bounds validation.
 ///
 /// Contains the reference price used, its source, and the computed
@@ -312,6 +491,73 @@ impl fmt::Display for PriceBoundsResult {
     }
 }
 
+/// Outcome of the price bounds check made before an execution.
+///
+/// Kept on the trade for audit.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::reference_price::PriceBoundsCheck;
+///
+/// let check = PriceBoundsCheck::overridden("admin-1", "no reference price available");
+/// assert!(check.is_overridden());
+/// assert!(check.result().is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum PriceBoundsCheck {
+    /// The price was within tolerance of the reference.
+    Passed(PriceBoundsResult),
+    /// The check failed and an administrator executed anyway.
+    Overridden {
+        /// The administrator who overrode the check.
+        overridden_by: String,
+        /// Why the check failed.
+        reason: String,
+    },
+}
+
+impl PriceBoundsCheck {
+    /// Creates an overridden check.
+    #[must_use]
+    pub fn overridden(overridden_by: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::Overridden {
+            overridden_by: overridden_by.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Returns true if the check was overridden.
+    #[inline]
+    #[must_use]
+    pub const fn is_overridden(&self) -> bool {
+        matches!(self, Self::Overridden { .. })
+    }
+
+    /// Returns the validation result if the check passed.
+    #[inline]
+    #[must_use]
+    pub const fn result(&self) -> Option<&PriceBoundsResult> {
+        match self {
+            Self::Passed(result) => Some(result),
+            Self::Overridden { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for PriceBoundsCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Passed(result) => write!(f, "passed: {}", result),
+            Self::Overridden {
+                overridden_by,
+                reason,
+            } => write!(f, "overridden by {}: {}", overridden_by, reason),
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -460,6 +706,16 @@ mod tests {
             let deserialized: PriceBoundsConfig = serde_json::from_str(&json).unwrap();
             assert_eq!(config, deserialized);
         }
+
+        #[test]
+        fn admin_override_is_opt_in() {
+            assert!(!PriceBoundsConfig::default().allows_admin_override());
+
+            let json = r#"{"liquid_tolerance_pct":"0.05","semi_liquid_tolerance_pct":"0.075","illiquid_tolerance_pct":"0.1"}"#;
+            let config: PriceBoundsConfig = serde_json::from_str(json).unwrap();
+            assert!(!config.allows_admin_override());
+            assert!(config.with_admin_override(true).allows_admin_override());
+        }
     }
 
     mod price_bounds_result {
@@ -503,4 +759,46 @@ mod tests {
             assert_eq!(result, deserialized);
         }
     }
+
+    mod price_bounds_check {
+        use super::*;
+
+        #[test]
+        fn passed_exposes_result() {
+            let result = PriceBoundsResult::new(
+                Price::new(100.0).unwrap(),
+                ReferencePriceSource::ClobMid,
+                Decimal::new(1, 2),
+            );
+            let check = PriceBoundsCheck::Passed(result);
+            assert!(!check.is_overridden());
+            assert_eq!(check.result(), Some(&result));
+        }
+
+        #[test]
+        fn serde_roundtrip() {
+            let passed = PriceBoundsCheck::Passed(PriceBoundsResult::new(
+                Price::new(100.0).unwrap(),
+                ReferencePriceSource::Theoretical,
+                Decimal::new(2, 2),
+            ));
+            let overridden = PriceBoundsCheck::overridden("admin-1", "price out of bounds");
+
+            for check in [passed, overridden] {
+                let json = serde_json::to_value(&check).unwrap();
+                assert!(json.get("outcome").is_some());
+                let deserialized: PriceBoundsCheck = serde_json::from_value(json).unwrap();
+                assert_eq!(check, deserialized);
+            }
+        }
+
+        #[test]
+        fn display_names_overriding_user() {
+            let check = PriceBoundsCheck::overridden("admin-1", "no reference price");
+            assert_eq!(
+                check.to_string(),
+                "overridden by admin-1: no reference price"
+            );
+        }
+    }
 }
@@ -23,8 +23,11 @@
 //! assert!(!state.can_transition_to(RfqState::Executed));
 //! ```
 
+use crate::domain::value_objects::enums::ParseEnumError;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
+use utoipa::ToSchema;
 
 /// RFQ lifecycle state.
 ///
@@ -50,7 +53,7 @@ use std::fmt;
 /// let terminal = RfqState::Executed;
 /// assert!(terminal.is_terminal());
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[repr(u8)]
 pub enum RfqState {
@@ -175,6 +178,28 @@ impl RfqState {
         )
     }
 
+    /// Returns true if an administrator may force this state to `target`.
+    ///
+    /// Forcing is a rescue for executions stuck in `Executing`, recording
+    /// an outcome established out of band; every other transition has to
+    /// go through the normal lifecycle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otc_rfq::domain::value_objects::rfq_state::RfqState;
+    ///
+    /// assert!(RfqState::Executing.can_force_to(RfqState::Executed));
+    /// assert!(!RfqState::QuotesReceived.can_force_to(RfqState::Executed));
+    /// ```
+    #[must_use]
+    pub const fn can_force_to(&self, target: Self) -> bool {
+        matches!(
+            (self, target),
+            (Self::Executing, Self::Executed) | (Self::Executing, Self::Failed)
+        )
+    }
+
     /// Returns the valid next states from this state.
     ///
     /// # Examples
@@ -306,6 +331,26 @@ impl fmt::Display for RfqState {
     }
 }
 
+impl FromStr for RfqState {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "CREATED" => Ok(Self::Created),
+            "QUOTE_REQUESTING" => Ok(Self::QuoteRequesting),
+            "QUOTES_RECEIVED" => Ok(Self::QuotesReceived),
+            "CLIENT_SELECTING" => Ok(Self::ClientSelecting),
+            "EXECUTING" => Ok(Self::Executing),
+            "EXECUTED" => Ok(Self::Executed),
+            "FAILED" => Ok(Self::Failed),
+            "CANCELLED" => Ok(Self::Cancelled),
+            "EXPIRED" => Ok(Self::Expired),
+            "NEGOTIATING" => Ok(Self::Negotiating),
+            _ => Err(ParseEnumError::InvalidValue("RfqState", s.to_string())),
+        }
+    }
+}
+
 impl TryFrom<u8> for RfqState {
     type Error = InvalidRfqStateError;
 
@@ -443,6 +488,15 @@ mod tests {
             assert!(!state.can_transition_to(RfqState::Expired));
         }
 
+        #[test]
+        fn only_stuck_executions_can_be_forced() {
+            assert!(RfqState::Executing.can_force_to(RfqState::Executed));
+            assert!(RfqState::Executing.can_force_to(RfqState::Failed));
+            assert!(!RfqState::Executing.can_force_to(RfqState::Cancelled));
+            assert!(!RfqState::ClientSelecting.can_force_to(RfqState::Executed));
+            assert!(!RfqState::Failed.can_force_to(RfqState::Executed));
+        }
+
         #[test]
         fn terminal_states_cannot_transition() {
             for terminal in [
@@ -584,6 +638,16 @@ mod tests {
     mod display {
         use super::*;
 
+        #[test]
+        fn from_str_roundtrips_display() {
+            for value in 0..=9u8 {
+                let state = RfqState::try_from(value).unwrap();
+                assert_eq!(state.to_string().parse::<RfqState>().unwrap(), state);
+            }
+            assert_eq!("executed".parse::<RfqState>().unwrap(), RfqState::Executed);
+            assert!("BOGUS".parse::<RfqState>().is_err());
+        }
+
         #[test]
         fn display_format() {
             assert_eq!(RfqState::Created.to_string(), "CREATED");
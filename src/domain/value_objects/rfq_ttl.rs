@@ -0,0 +1,210 @@
+//! # RFQ TTL Policy
+//!
+//! How long an RFQ may stay open, per asset class.
+//!
+//! An [`RfqTtlPolicy`] gives each [`AssetClass`] a [`TtlLimits`]: the
+//! shortest and longest time an RFQ may stay open, and the time-to-live
+//! applied when a request does not name one. Classes without their own
+//! limits fall back to the policy's `fallback` limits. Too short a TTL
+//! cannot complete quote aggregation; too long a TTL keeps market makers
+//! committed to prices for longer than they priced for.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::AssetClass;
+//! use otc_rfq::domain::value_objects::rfq_ttl::{RfqTtlPolicy, TtlLimits};
+//!
+//! let policy = RfqTtlPolicy::default()
+//!     .with_asset_class(AssetClass::Stock, TtlLimits::new(30, 120, 600));
+//!
+//! assert_eq!(policy.limits_for(AssetClass::Stock).default_secs, 120);
+//! assert!(policy.check(AssetClass::Stock, 10).is_err());
+//! assert!(policy.check(AssetClass::CryptoSpot, 10).is_ok());
+//! ```
+
+use crate::domain::value_objects::enums::AssetClass;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Shortest TTL allowed by the default policy, in seconds.
+pub const DEFAULT_MIN_TTL_SECS: u64 = 5;
+
+/// TTL applied by the default policy when none is requested, in seconds.
+pub const DEFAULT_TTL_SECS: u64 = 60;
+
+/// Longest TTL allowed by the default policy, in seconds.
+pub const DEFAULT_MAX_TTL_SECS: u64 = 1_800;
+
+/// Allowed range and default of an RFQ's time-to-live, in seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TtlLimits {
+    /// Shortest TTL allowed.
+    pub min_secs: u64,
+    /// TTL applied when none is requested.
+    pub default_secs: u64,
+    /// Longest TTL allowed.
+    pub max_secs: u64,
+}
+
+impl TtlLimits {
+    /// Creates TTL limits.
+    #[must_use]
+    pub const fn new(min_secs: u64, default_secs: u64, max_secs: u64) -> Self {
+        Self {
+            min_secs,
+            default_secs,
+            max_secs,
+        }
+    }
+
+    /// Returns true if the minimum is positive and the default lies within
+    /// the range.
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.min_secs > 0
+            && self.min_secs <= self.default_secs
+            && self.default_secs <= self.max_secs
+    }
+
+    /// Returns true if `ttl_secs` lies within the range.
+    #[must_use]
+    pub const fn allows(&self, ttl_secs: u64) -> bool {
+        self.min_secs <= ttl_secs && ttl_secs <= self.max_secs
+    }
+}
+
+impl Default for TtlLimits {
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_TTL_SECS, DEFAULT_TTL_SECS, DEFAULT_MAX_TTL_SECS)
+    }
+}
+
+impl fmt::Display for TtlLimits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}s-{}s (default {}s)",
+            self.min_secs, self.max_secs, self.default_secs
+        )
+    }
+}
+
+/// A requested TTL outside the limits of its asset class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TtlOutOfRange {
+    /// Asset class of the RFQ.
+    pub asset_class: AssetClass,
+    /// The TTL requested, in seconds.
+    pub requested_secs: u64,
+    /// Limits of the asset class.
+    pub limits: TtlLimits,
+}
+
+impl fmt::Display for TtlOutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "TTL of {}s is outside the allowed range for {} RFQs: {}s to {}s",
+            self.requested_secs, self.asset_class, self.limits.min_secs, self.limits.max_secs
+        )
+    }
+}
+
+/// TTL limits of RFQs by asset class.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RfqTtlPolicy {
+    /// Limits of asset classes without their own.
+    pub fallback: TtlLimits,
+    /// Limits of specific asset classes.
+    #[serde(default)]
+    pub by_asset_class: HashMap<AssetClass, TtlLimits>,
+}
+
+impl RfqTtlPolicy {
+    /// Creates a policy applying `fallback` to every asset class.
+    #[must_use]
+    pub fn new(fallback: TtlLimits) -> Self {
+        Self {
+            fallback,
+            by_asset_class: HashMap::new(),
+        }
+    }
+
+    /// Sets the limits of `asset_class`.
+    #[must_use]
+    pub fn with_asset_class(mut self, asset_class: AssetClass, limits: TtlLimits) -> Self {
+        self.by_asset_class.insert(asset_class, limits);
+        self
+    }
+
+    /// Returns the limits of `asset_class`.
+    #[must_use]
+    pub fn limits_for(&self, asset_class: AssetClass) -> TtlLimits {
+        self.by_asset_class
+            .get(&asset_class)
+            .copied()
+            .unwrap_or(self.fallback)
+    }
+
+    /// Returns true if every set of limits is valid.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.fallback.is_valid() && self.by_asset_class.values().all(TtlLimits::is_valid)
+    }
+
+    /// Checks a TTL against the limits of `asset_class`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TtlOutOfRange`] if `ttl_secs` is shorter than the minimum
+    /// or longer than the maximum.
+    pub fn check(&self, asset_class: AssetClass, ttl_secs: u64) -> Result<(), TtlOutOfRange> {
+        let limits = self.limits_for(asset_class);
+        if limits.allows(ttl_secs) {
+            Ok(())
+        } else {
+            Err(TtlOutOfRange {
+                asset_class,
+                requested_secs: ttl_secs,
+                limits,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits_differ_by_asset_class() {
+        let policy = RfqTtlPolicy::new(TtlLimits::new(5, 60, 600))
+            .with_asset_class(AssetClass::CryptoOptions, TtlLimits::new(30, 300, 3_600));
+
+        assert!(policy.check(AssetClass::CryptoSpot, 5).is_ok());
+        assert!(policy.check(AssetClass::CryptoSpot, 1_200).is_err());
+        assert!(policy.check(AssetClass::CryptoOptions, 1_200).is_ok());
+
+        let err = policy.check(AssetClass::CryptoOptions, 5).unwrap_err();
+        assert_eq!(err.limits, TtlLimits::new(30, 300, 3_600));
+        assert_eq!(
+            err.to_string(),
+            "TTL of 5s is outside the allowed range for CRYPTO_OPTIONS RFQs: 30s to 3600s"
+        );
+    }
+
+    #[test]
+    fn invalid_limits_are_detected() {
+        assert!(RfqTtlPolicy::default().is_valid());
+        assert!(!TtlLimits::new(0, 60, 600).is_valid());
+        assert!(!TtlLimits::new(30, 10, 600).is_valid());
+        assert!(
+            !RfqTtlPolicy::default()
+                .with_asset_class(AssetClass::Forex, TtlLimits::new(60, 600, 30))
+                .is_valid()
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! # Score Breakdown
+//!
+//! How a ranking strategy arrived at a quote's score.
+//!
+//! Each [`ScoreComponent`] is one factor the strategy weighed: the factor's
+//! raw value for the quote, the weight applied to it, and the resulting
+//! contribution. The contributions of a breakdown add up to the score, to
+//! within [`SCORE_EPSILON`]; [`explains`] checks it.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::score_breakdown::{ScoreComponent, ScoreFactor, explains};
+//!
+//! let breakdown = vec![
+//!     ScoreComponent::new(ScoreFactor::Price, 0.5, 0.7),
+//!     ScoreComponent::new(ScoreFactor::Size, 1.0, 0.3),
+//! ];
+//! assert!(explains(0.5 * 0.7 + 1.0 * 0.3, &breakdown));
+//! assert!(!explains(1.0, &breakdown));
+//! ```
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::FromPrimitive;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Largest difference between a score and the sum of its contributions
+/// still accepted as rounding.
+pub const SCORE_EPSILON: Decimal = Decimal::from_parts(1, 0, 0, false, 9);
+
+/// A factor a ranking strategy scores quotes on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ScoreFactor {
+    /// The quoted price, or its normalized score.
+    Price,
+    /// The price including settlement gas.
+    AllInPrice,
+    /// The quoted size.
+    Size,
+    /// The venue's response and reject record.
+    Reliability,
+    /// How recently the quote was received.
+    Freshness,
+}
+
+impl fmt::Display for ScoreFactor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Price => "PRICE",
+            Self::AllInPrice => "ALL_IN_PRICE",
+            Self::Size => "SIZE",
+            Self::Reliability => "RELIABILITY",
+            Self::Freshness => "FRESHNESS",
+        };
+        f.write_str(name)
+    }
+}
+
+/// One factor's part in a quote's score.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreComponent {
+    /// The factor.
+    pub factor: ScoreFactor,
+    /// The factor's value for the quote, before weighting.
+    pub raw_value: Decimal,
+    /// The weight the strategy applies to the factor; negative where a
+    /// lower value is better.
+    pub weight: Decimal,
+    /// What the factor adds to the score: `raw_value × weight`.
+    pub contribution: Decimal,
+}
+
+impl ScoreComponent {
+    /// Creates a component from the values the strategy scored with.
+    ///
+    /// Non-finite values are recorded as zero.
+    #[must_use]
+    pub fn new(factor: ScoreFactor, raw_value: f64, weight: f64) -> Self {
+        Self {
+            factor,
+            raw_value: to_decimal(raw_value),
+            weight: to_decimal(weight),
+            contribution: to_decimal(raw_value * weight),
+        }
+    }
+
+    /// Returns the sum of the contributions of `components`.
+    #[must_use]
+    pub fn total(components: &[Self]) -> Decimal {
+        components.iter().map(|c| c.contribution).sum()
+    }
+}
+
+/// Returns true if the contributions of `components` add up to `score`,
+/// to within [`SCORE_EPSILON`].
+#[must_use]
+pub fn explains(score: f64, components: &[ScoreComponent]) -> bool {
+    (ScoreComponent::total(components) - to_decimal(score)).abs() <= SCORE_EPSILON
+}
+
+fn to_decimal(value: f64) -> Decimal {
+    Decimal::from_f64(value).unwrap_or_default()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contribution_is_weighted_raw_value() {
+        let component = ScoreComponent::new(ScoreFactor::Price, 100.0, -1.0);
+
+        assert_eq!(component.raw_value, Decimal::from(100));
+        assert_eq!(component.weight, Decimal::from(-1));
+        assert_eq!(component.contribution, Decimal::from(-100));
+        assert!(explains(-100.0, &[component]));
+    }
+
+    #[test]
+    fn serializes_factor_and_decimals_as_strings() {
+        let json =
+            serde_json::to_value(ScoreComponent::new(ScoreFactor::AllInPrice, 2.5, 1.0)).unwrap();
+
+        assert_eq!(json["factor"], "ALL_IN_PRICE");
+        assert_eq!(json["raw_value"], "2.5");
+        assert_eq!(json["contribution"], "2.5");
+    }
+}
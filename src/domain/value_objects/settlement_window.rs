@@ -0,0 +1,326 @@
+//! # Settlement Window
+//!
+//! When a trade is released for settlement.
+//!
+//! Some counterparties settle each trade on-chain as soon as it executes;
+//! others net their trades at a daily cutoff. A [`SettlementWindow`] says
+//! which: [`Instant`](SettlementWindow::Instant) trades are released at
+//! execution, [`SameDay`](SettlementWindow::SameDay) trades at the cutoff of
+//! their trade date and [`NextDay`](SettlementWindow::NextDay) trades at the
+//! cutoff of the day after.
+//!
+//! A [`SettlementCutoff`] is a wall-clock time in a named timezone, so a
+//! 16:00 New York cutoff stays at 16:00 local time across daylight saving
+//! changes. A trade executed at or after the cutoff belongs to the next
+//! trade date. Trade dates and settlement days that are not trading days of
+//! the instrument's [`TradingCalendar`] roll forward to the next one.
+//!
+//! Terms stored before cutoffs were recorded (`"T0"`, `"T1"`, `"T2"`) are
+//! read as same-day or next-day windows at the default cutoff; `T2` is no
+//! longer offered and reads as next day.
+//!
+//! # Examples
+//!
+//! ```
+//! use chrono::NaiveTime;
+//! use otc_rfq::domain::value_objects::settlement_window::{
+//!     SettlementCutoff, SettlementWindow,
+//! };
+//! use otc_rfq::domain::value_objects::trading_calendar::TradingCalendar;
+//! use otc_rfq::domain::value_objects::Timestamp;
+//!
+//! let cutoff = SettlementCutoff::new(
+//!     NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+//!     chrono_tz::UTC,
+//! );
+//! let window = SettlementWindow::SameDay { cutoff };
+//! let calendar = TradingCalendar::crypto();
+//!
+//! // Executed at 10:00 UTC on 2024-01-02, released at 16:00 the same day
+//! let executed_at = Timestamp::from_secs(1_704_189_600).unwrap();
+//! assert_eq!(
+//!     window.release_at(executed_at, &calendar),
+//!     executed_at.add_secs(6 * 3600)
+//! );
+//! assert_eq!(
+//!     SettlementWindow::Instant.release_at(executed_at, &calendar),
+//!     executed_at
+//! );
+//! ```
+
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::trading_calendar::{TradingCalendar, local_instant};
+use chrono::{NaiveDate, NaiveTime};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use utoipa::ToSchema;
+
+/// Daily settlement cutoff: a local time in a named timezone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub struct SettlementCutoff {
+    /// Local time of the cutoff.
+    #[schema(value_type = String, example = "16:00:00")]
+    pub time: NaiveTime,
+    /// IANA timezone the time is in.
+    #[schema(value_type = String, example = "America/New_York")]
+    pub timezone: Tz,
+}
+
+impl SettlementCutoff {
+    /// Creates a cutoff at `time` in `timezone`.
+    #[must_use]
+    pub const fn new(time: NaiveTime, timezone: Tz) -> Self {
+        Self { time, timezone }
+    }
+
+    /// Returns the instant of the cutoff on `date`.
+    ///
+    /// A cutoff that falls in a daylight saving gap moves forward by the
+    /// length of the gap; one that falls in an overlap takes the earlier
+    /// instant.
+    #[must_use]
+    pub fn on(&self, date: NaiveDate) -> Timestamp {
+        local_instant(self.timezone, date.and_time(self.time))
+    }
+
+    /// Returns the trade date of a trade executed at `at`: its local date,
+    /// or the day after if it executed at or after that day's cutoff.
+    #[must_use]
+    pub fn trade_date(&self, at: Timestamp) -> NaiveDate {
+        let date = at.as_datetime().with_timezone(&self.timezone).date_naive();
+        if at < self.on(date) {
+            date
+        } else {
+            next_day(date)
+        }
+    }
+}
+
+impl Default for SettlementCutoff {
+    /// 16:00 UTC.
+    fn default() -> Self {
+        Self::new(
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap_or(NaiveTime::MIN),
+            Tz::UTC,
+        )
+    }
+}
+
+impl fmt::Display for SettlementCutoff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.time.format("%H:%M"), self.timezone)
+    }
+}
+
+/// When a trade is released for settlement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(
+    tag = "type",
+    rename_all = "SCREAMING_SNAKE_CASE",
+    from = "SettlementWindowRepr"
+)]
+pub enum SettlementWindow {
+    /// Released as soon as the trade executes.
+    Instant,
+    /// Released at the cutoff of the trade date.
+    SameDay {
+        /// Daily cutoff.
+        cutoff: SettlementCutoff,
+    },
+    /// Released at the cutoff of the day after the trade date.
+    NextDay {
+        /// Daily cutoff.
+        cutoff: SettlementCutoff,
+    },
+}
+
+impl SettlementWindow {
+    /// Returns when a trade executed at `executed_at` is released for
+    /// settlement.
+    ///
+    /// Trade dates and settlement days that are not trading days of
+    /// `calendar` roll forward to its next trading day.
+    #[must_use]
+    pub fn release_at(&self, executed_at: Timestamp, calendar: &TradingCalendar) -> Timestamp {
+        let (cutoff, days) = match self {
+            Self::Instant => return executed_at,
+            Self::SameDay { cutoff } => (cutoff, 0),
+            Self::NextDay { cutoff } => (cutoff, 1),
+        };
+        let mut date = calendar.next_trading_day(cutoff.trade_date(executed_at));
+        for _ in 0..days {
+            date = calendar.next_trading_day(next_day(date));
+        }
+        cutoff.on(date)
+    }
+
+    /// Returns the daily cutoff, or `None` for instant settlement.
+    #[must_use]
+    pub fn cutoff(&self) -> Option<&SettlementCutoff> {
+        match self {
+            Self::Instant => None,
+            Self::SameDay { cutoff } | Self::NextDay { cutoff } => Some(cutoff),
+        }
+    }
+}
+
+impl fmt::Display for SettlementWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Instant => f.write_str("INSTANT"),
+            Self::SameDay { cutoff } => write!(f, "SAME_DAY ({cutoff})"),
+            Self::NextDay { cutoff } => write!(f, "NEXT_DAY ({cutoff})"),
+        }
+    }
+}
+
+/// Stored or submitted form of a [`SettlementWindow`], current or legacy.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SettlementWindowRepr {
+    Current(CurrentWindow),
+    Legacy(LegacyWindow),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+enum CurrentWindow {
+    Instant,
+    SameDay { cutoff: SettlementCutoff },
+    NextDay { cutoff: SettlementCutoff },
+}
+
+/// Business-day terms recorded before cutoffs were.
+#[derive(Deserialize)]
+enum LegacyWindow {
+    T0,
+    T1,
+    T2,
+}
+
+impl From<SettlementWindowRepr> for SettlementWindow {
+    fn from(repr: SettlementWindowRepr) -> Self {
+        match repr {
+            SettlementWindowRepr::Current(CurrentWindow::Instant) => Self::Instant,
+            SettlementWindowRepr::Current(CurrentWindow::SameDay { cutoff }) => {
+                Self::SameDay { cutoff }
+            }
+            SettlementWindowRepr::Current(CurrentWindow::NextDay { cutoff }) => {
+                Self::NextDay { cutoff }
+            }
+            SettlementWindowRepr::Legacy(LegacyWindow::T0) => Self::SameDay {
+                cutoff: SettlementCutoff::default(),
+            },
+            SettlementWindowRepr::Legacy(LegacyWindow::T1 | LegacyWindow::T2) => Self::NextDay {
+                cutoff: SettlementCutoff::default(),
+            },
+        }
+    }
+}
+
+fn next_day(date: NaiveDate) -> NaiveDate {
+    date.succ_opt().unwrap_or(date)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn at(rfc3339: &str) -> Timestamp {
+        Timestamp::from(
+            chrono::DateTime::parse_from_rfc3339(rfc3339)
+                .unwrap()
+                .with_timezone(&Utc),
+        )
+    }
+
+    fn cutoff(hour: u32, timezone: Tz) -> SettlementCutoff {
+        SettlementCutoff::new(NaiveTime::from_hms_opt(hour, 0, 0).unwrap(), timezone)
+    }
+
+    #[test]
+    fn instant_is_released_at_execution() {
+        let executed_at = at("2024-03-08T23:59:00Z");
+        assert_eq!(
+            SettlementWindow::Instant.release_at(executed_at, &TradingCalendar::crypto()),
+            executed_at
+        );
+    }
+
+    #[test]
+    fn same_day_rolls_over_after_the_cutoff() {
+        let crypto = TradingCalendar::crypto();
+        let window = SettlementWindow::SameDay {
+            cutoff: cutoff(16, chrono_tz::America::New_York),
+        };
+        // 16:00 New York is 21:00 UTC in January
+        assert_eq!(
+            window.release_at(at("2024-01-09T15:00:00Z"), &crypto),
+            at("2024-01-09T21:00:00Z")
+        );
+        assert_eq!(
+            window.release_at(at("2024-01-09T21:00:00Z"), &crypto),
+            at("2024-01-10T21:00:00Z")
+        );
+    }
+
+    #[test]
+    fn cutoff_follows_daylight_saving() {
+        let crypto = TradingCalendar::crypto();
+        let window = SettlementWindow::SameDay {
+            cutoff: cutoff(16, chrono_tz::America::New_York),
+        };
+        // 16:00 New York is 20:00 UTC in July
+        assert_eq!(
+            window.release_at(at("2024-07-09T12:00:00Z"), &crypto),
+            at("2024-07-09T20:00:00Z")
+        );
+    }
+
+    #[test]
+    fn next_day_skips_closed_days_of_the_calendar() {
+        let window = SettlementWindow::NextDay {
+            cutoff: cutoff(17, Tz::UTC),
+        };
+        // Friday before the cutoff
+        let friday = at("2024-03-08T10:00:00Z");
+        assert_eq!(
+            window.release_at(friday, &TradingCalendar::crypto()),
+            at("2024-03-09T17:00:00Z")
+        );
+        let weekdays = TradingCalendar::new("LDN", Tz::UTC, true)
+            .unwrap()
+            .with_holidays([NaiveDate::from_ymd_opt(2024, 3, 11).unwrap()]);
+        assert_eq!(
+            window.release_at(friday, &weekdays),
+            at("2024-03-12T17:00:00Z")
+        );
+    }
+
+    #[test]
+    fn serde_uses_tagged_form_and_reads_legacy_terms() {
+        let window = SettlementWindow::NextDay {
+            cutoff: cutoff(16, chrono_tz::Europe::London),
+        };
+        let json = serde_json::to_value(window).unwrap();
+        assert_eq!(json["type"], "NEXT_DAY");
+        assert_eq!(json["cutoff"]["timezone"], "Europe/London");
+        assert_eq!(
+            serde_json::from_value::<SettlementWindow>(json).unwrap(),
+            window
+        );
+
+        let legacy: SettlementWindow = serde_json::from_str("\"T0\"").unwrap();
+        assert_eq!(
+            legacy,
+            SettlementWindow::SameDay {
+                cutoff: SettlementCutoff::default()
+            }
+        );
+        let instant: SettlementWindow = serde_json::from_str(r#"{"type":"INSTANT"}"#).unwrap();
+        assert_eq!(instant, SettlementWindow::Instant);
+    }
+}
@@ -0,0 +1,295 @@
+//! # Signed Decimal Amount Value Object
+//!
+//! Signed decimal amount with checked arithmetic.
+//!
+//! This module provides the [`SignedDecimalAmount`] type for values that are
+//! legitimately negative: price deltas, slippage, price improvement, funding
+//! adjustments, and P&L. [`Price`] rejects negative values; convert
+//! explicitly when a non-negative price is required.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::signed_amount::SignedDecimalAmount;
+//! use otc_rfq::domain::value_objects::{CheckedArithmetic, Price};
+//! use rust_decimal::Decimal;
+//!
+//! let paid = SignedDecimalAmount::from(Price::new(99.0).unwrap());
+//! let reference = SignedDecimalAmount::from(Price::new(100.0).unwrap());
+//!
+//! let delta = paid.safe_sub(reference).unwrap();
+//! assert!(delta.is_negative());
+//! assert_eq!(delta.to_string(), "-1");
+//! assert!(Price::try_from(delta).is_err());
+//! ```
+
+use super::arithmetic::{ArithmeticError, ArithmeticResult, CheckedArithmetic};
+use super::price::Price;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A signed decimal amount.
+///
+/// Zero is always stored with a positive sign, so `-0` and `0` compare,
+/// hash, and display identically.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::signed_amount::SignedDecimalAmount;
+/// use rust_decimal::Decimal;
+///
+/// let gain = SignedDecimalAmount::new(Decimal::new(125, 2));
+/// let loss = gain.negate();
+///
+/// assert_eq!(gain.to_string(), "+1.25");
+/// assert_eq!(loss.to_string(), "-1.25");
+/// assert!(loss < SignedDecimalAmount::ZERO);
+/// ```
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize,
+)]
+#[serde(from = "Decimal", into = "Decimal")]
+pub struct SignedDecimalAmount(Decimal);
+
+impl SignedDecimalAmount {
+    /// Zero amount constant.
+    pub const ZERO: Self = Self(Decimal::ZERO);
+
+    /// Creates an amount from a signed Decimal value.
+    #[must_use]
+    pub fn new(value: Decimal) -> Self {
+        let mut value = value;
+        if value.is_zero() {
+            value.set_sign_positive(true);
+        }
+        Self(value)
+    }
+
+    /// Returns the inner Decimal value.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> Decimal {
+        self.0
+    }
+
+    /// Returns true if the amount is zero.
+    #[inline]
+    #[must_use]
+    pub fn is_zero(self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns true if the amount is strictly positive.
+    #[inline]
+    #[must_use]
+    pub fn is_positive(self) -> bool {
+        self.0 > Decimal::ZERO
+    }
+
+    /// Returns true if the amount is strictly negative.
+    #[inline]
+    #[must_use]
+    pub fn is_negative(self) -> bool {
+        self.0 < Decimal::ZERO
+    }
+
+    /// Returns the absolute value.
+    #[inline]
+    #[must_use]
+    pub fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Returns the amount with its sign flipped.
+    #[inline]
+    #[must_use]
+    pub fn negate(self) -> Self {
+        Self::new(-self.0)
+    }
+}
+
+impl CheckedArithmetic for SignedDecimalAmount {
+    #[inline]
+    fn safe_add(self, rhs: Self) -> ArithmeticResult<Self> {
+        self.0
+            .checked_add(rhs.0)
+            .map(Self::new)
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    #[inline]
+    fn safe_sub(self, rhs: Self) -> ArithmeticResult<Self> {
+        self.0
+            .checked_sub(rhs.0)
+            .map(Self::new)
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    #[inline]
+    fn safe_mul(self, rhs: Self) -> ArithmeticResult<Self> {
+        self.0
+            .checked_mul(rhs.0)
+            .map(Self::new)
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    #[inline]
+    fn safe_div(self, rhs: Self) -> ArithmeticResult<Self> {
+        if rhs.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        self.0
+            .checked_div(rhs.0)
+            .map(Self::new)
+            .ok_or(ArithmeticError::Overflow)
+    }
+}
+
+impl fmt::Display for SignedDecimalAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_positive() {
+            write!(f, "+{}", self.0)
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+impl From<Decimal> for SignedDecimalAmount {
+    fn from(value: Decimal) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<SignedDecimalAmount> for Decimal {
+    fn from(amount: SignedDecimalAmount) -> Self {
+        amount.0
+    }
+}
+
+impl From<Price> for SignedDecimalAmount {
+    fn from(price: Price) -> Self {
+        Self::new(price.get())
+    }
+}
+
+impl TryFrom<SignedDecimalAmount> for Price {
+    type Error = ArithmeticError;
+
+    /// Converts a non-negative amount to a price.
+    ///
+    /// Zero converts to [`Price::ZERO`].
+    fn try_from(amount: SignedDecimalAmount) -> Result<Self, Self::Error> {
+        if amount.is_negative() {
+            return Err(ArithmeticError::InvalidValue(
+                "negative amount cannot be a price",
+            ));
+        }
+        Price::from_decimal(amount.0)
+    }
+}
+
+impl FromStr for SignedDecimalAmount {
+    type Err = ArithmeticError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Decimal::from_str(s.strip_prefix('+').unwrap_or(s))
+            .map(Self::new)
+            .map_err(|_| ArithmeticError::InvalidValue("invalid decimal"))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_classification() {
+        assert!(SignedDecimalAmount::new(Decimal::ONE).is_positive());
+        assert!(SignedDecimalAmount::new(Decimal::NEGATIVE_ONE).is_negative());
+        assert!(!SignedDecimalAmount::ZERO.is_positive());
+        assert!(!SignedDecimalAmount::ZERO.is_negative());
+    }
+
+    #[test]
+    fn negative_zero_is_normalized() {
+        let neg_zero = SignedDecimalAmount::new(-Decimal::ZERO);
+        assert!(!neg_zero.get().is_sign_negative());
+        assert_eq!(neg_zero, SignedDecimalAmount::ZERO);
+        assert_eq!(neg_zero.to_string(), "0");
+        assert_eq!(SignedDecimalAmount::ZERO.negate().to_string(), "0");
+    }
+
+    #[test]
+    fn display_includes_sign() {
+        assert_eq!(
+            SignedDecimalAmount::new(Decimal::new(15, 1)).to_string(),
+            "+1.5"
+        );
+        assert_eq!(
+            SignedDecimalAmount::new(Decimal::new(-15, 1)).to_string(),
+            "-1.5"
+        );
+    }
+
+    #[test]
+    fn from_str_accepts_explicit_plus() {
+        let amount: SignedDecimalAmount = "+2.5".parse().unwrap();
+        assert_eq!(amount.get(), Decimal::new(25, 1));
+        let amount: SignedDecimalAmount = "-2.5".parse().unwrap();
+        assert_eq!(amount.get(), Decimal::new(-25, 1));
+        assert!("abc".parse::<SignedDecimalAmount>().is_err());
+    }
+
+    #[test]
+    fn price_conversion_rejects_negative() {
+        let negative = SignedDecimalAmount::new(Decimal::new(-1, 2));
+        assert!(matches!(
+            Price::try_from(negative),
+            Err(ArithmeticError::InvalidValue(_))
+        ));
+
+        let positive = SignedDecimalAmount::new(Decimal::new(1, 2));
+        assert_eq!(Price::try_from(positive).unwrap().get(), Decimal::new(1, 2));
+    }
+
+    #[test]
+    fn price_conversion_at_zero() {
+        assert_eq!(
+            Price::try_from(SignedDecimalAmount::ZERO).unwrap(),
+            Price::ZERO
+        );
+        assert_eq!(
+            Price::try_from(SignedDecimalAmount::new(-Decimal::ZERO)).unwrap(),
+            Price::ZERO
+        );
+        assert_eq!(
+            SignedDecimalAmount::from(Price::ZERO),
+            SignedDecimalAmount::ZERO
+        );
+    }
+
+    #[test]
+    fn division_by_zero_is_checked() {
+        assert_eq!(
+            SignedDecimalAmount::new(Decimal::ONE).safe_div(SignedDecimalAmount::ZERO),
+            Err(ArithmeticError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn serde_uses_plain_decimal() {
+        let amount = SignedDecimalAmount::new(Decimal::new(-375, 2));
+        let json = serde_json::to_string(&amount).unwrap();
+        assert_eq!(json, "\"-3.75\"");
+        let back: SignedDecimalAmount = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, amount);
+
+        let neg_zero: SignedDecimalAmount = serde_json::from_str("\"-0\"").unwrap();
+        assert!(!neg_zero.get().is_sign_negative());
+    }
+}
@@ -28,6 +28,7 @@
 //! assert!(!min.requires_full_fill());
 //! ```
 
+use crate::domain::errors::{DomainError, DomainResult};
 use crate::domain::value_objects::Quantity;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -48,7 +49,7 @@ use std::fmt;
 /// assert!(mode.allows_partial_fill());
 /// assert!(!mode.requires_full_fill());
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(tag = "type", content = "value")]
 pub enum SizeNegotiationMode {
     /// Reject unless the full requested quantity can be filled.
@@ -95,6 +96,29 @@ impl SizeNegotiationMode {
             _ => None,
         }
     }
+
+    /// Checks that this mode can apply to an RFQ for `quantity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidMinQuantity` if a `MinQuantity`
+    /// threshold is not positive or exceeds `quantity`.
+    pub fn validate_for(&self, quantity: Quantity) -> DomainResult<()> {
+        if let Self::MinQuantity(min) = self {
+            if !min.is_positive() {
+                return Err(DomainError::InvalidMinQuantity(
+                    "min_quantity must be positive".to_string(),
+                ));
+            }
+            if *min > quantity {
+                return Err(DomainError::InvalidMinQuantity(format!(
+                    "min_quantity {} exceeds quantity {}",
+                    min, quantity
+                )));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Default for SizeNegotiationMode {
@@ -165,6 +189,49 @@ mod tests {
         }
     }
 
+    mod validation {
+        use super::*;
+
+        #[test]
+        fn min_quantity_within_quantity_is_valid() {
+            let quantity = Quantity::new(10.0).unwrap();
+            for min in [1.0, 10.0] {
+                let mode = SizeNegotiationMode::MinQuantity(Quantity::new(min).unwrap());
+                assert!(mode.validate_for(quantity).is_ok());
+            }
+        }
+
+        #[test]
+        fn min_quantity_above_quantity_is_invalid() {
+            let mode = SizeNegotiationMode::MinQuantity(Quantity::new(11.0).unwrap());
+            assert!(matches!(
+                mode.validate_for(Quantity::new(10.0).unwrap()),
+                Err(DomainError::InvalidMinQuantity(_))
+            ));
+        }
+
+        #[test]
+        fn zero_min_quantity_is_invalid() {
+            let mode = SizeNegotiationMode::MinQuantity(Quantity::zero());
+            assert!(matches!(
+                mode.validate_for(Quantity::new(10.0).unwrap()),
+                Err(DomainError::InvalidMinQuantity(_))
+            ));
+        }
+
+        #[test]
+        fn other_modes_are_always_valid() {
+            let quantity = Quantity::new(1.0).unwrap();
+            for mode in [
+                SizeNegotiationMode::AllOrNothing,
+                SizeNegotiationMode::FillOrKill,
+                SizeNegotiationMode::BestEffort,
+            ] {
+                assert!(mode.validate_for(quantity).is_ok());
+            }
+        }
+    }
+
     mod display {
         use super::*;
 
@@ -19,25 +19,46 @@
 //!
 //! ```
 //! use otc_rfq::domain::value_objects::strategy::{Strategy, StrategyType, StrategyLeg};
-//! use otc_rfq::domain::value_objects::{Instrument, OrderSide, Symbol, AssetClass};
+//! use otc_rfq::domain::value_objects::{
+//!     AssetClass, Instrument, OptionKind, OptionTerms, OrderSide, Price, Symbol, Timestamp,
+//! };
 //!
-//! let call = Instrument::builder(
-//!     Symbol::new("BTC/USD").unwrap(),
-//!     AssetClass::CryptoDerivs,
-//! ).build();
-//! let put = call.clone();
+//! let expiry = Timestamp::now().add_secs(30 * 86_400);
+//! let option = |kind| {
+//!     let terms = OptionTerms::new(Price::new(60_000.0).unwrap(), expiry, kind).unwrap();
+//!     Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoOptions)
+//!         .option_terms(terms)
+//!         .try_build()
+//!         .unwrap()
+//! };
 //!
 //! let straddle = Strategy::builder(StrategyType::Straddle, "BTC")
-//!     .leg(StrategyLeg::new(call, OrderSide::Buy, 1).unwrap())
-//!     .leg(StrategyLeg::new(put, OrderSide::Buy, 1).unwrap())
+//!     .leg(StrategyLeg::new(option(OptionKind::Call), OrderSide::Buy, 1).unwrap())
+//!     .leg(StrategyLeg::new(option(OptionKind::Put), OrderSide::Buy, 1).unwrap())
 //!     .build();
 //!
 //! assert!(straddle.is_ok());
 //! ```
+//!
+//! # Structural Rules
+//!
+//! When every leg carries [`OptionTerms`], standard strategies must have
+//! their canonical shape:
+//!
+//! - Spread: opposite sides, differing strike or expiry
+//! - Straddle: a bought call and a bought put, same strike and expiry
+//! - Strangle: as a straddle, but with different strikes
+//! - Butterfly: all calls or all puts, one expiry, strikes low < mid < high
+//!   with ratios 1/2/1, wings on one side and the body on the other
+//! - Iron condor: one expiry, a bought lower put and sold higher put below
+//!   a sold lower call and bought higher call
+//!
+//! Custom strategies and legs without option terms are not checked.
 
 use crate::domain::errors::{DomainError, DomainResult};
 use crate::domain::value_objects::enums::OrderSide;
 use crate::domain::value_objects::instrument::Instrument;
+use crate::domain::value_objects::option_terms::{OptionKind, OptionTerms};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
@@ -291,6 +312,8 @@ impl fmt::Display for StrategyLeg {
 /// - Must have at least one leg (at least `strategy_type.min_legs()`)
 /// - All legs must share the same underlying (base asset)
 /// - The `underlying` field must match the legs' base asset
+/// - Standard strategies whose legs carry option terms have their
+///   canonical shape (see the module documentation)
 ///
 /// # Examples
 ///
@@ -315,6 +338,7 @@ impl fmt::Display for StrategyLeg {
 /// assert!(strategy.is_ok());
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "StrategyData")]
 pub struct Strategy {
     /// The strategy classification.
     strategy_type: StrategyType,
@@ -326,6 +350,28 @@ pub struct Strategy {
     description: Option<String>,
 }
 
+/// Unvalidated wire form of [`Strategy`], checked on deserialization.
+#[derive(Deserialize)]
+struct StrategyData {
+    strategy_type: StrategyType,
+    legs: Vec<StrategyLeg>,
+    underlying: String,
+    description: Option<String>,
+}
+
+impl TryFrom<StrategyData> for Strategy {
+    type Error = DomainError;
+
+    fn try_from(data: StrategyData) -> Result<Self, Self::Error> {
+        Self::new(
+            data.strategy_type,
+            data.legs,
+            data.underlying,
+            data.description,
+        )
+    }
+}
+
 impl Strategy {
     /// Creates a new validated strategy.
     ///
@@ -342,6 +388,8 @@ impl Strategy {
     /// - [`DomainError::ValidationError`] if leg count is below minimum for strategy type
     /// - [`DomainError::ValidationError`] if legs have mixed underlyings
     /// - [`DomainError::ValidationError`] if underlying does not match legs
+    /// - [`DomainError::ValidationError`] naming the failed rule if option
+    ///   legs do not have the strategy type's structure
     pub fn new(
         strategy_type: StrategyType,
         legs: Vec<StrategyLeg>,
@@ -401,6 +449,141 @@ impl Strategy {
             }
         }
 
+        Self::validate_structure(legs, strategy_type)
+    }
+
+    /// Checks the shape of a standard strategy whose legs carry option
+    /// terms.
+    ///
+    /// Strategies whose legs carry no option terms, and custom strategies,
+    /// are accepted unchanged.
+    fn validate_structure(legs: &[StrategyLeg], strategy_type: StrategyType) -> DomainResult<()> {
+        if !strategy_type.is_standard() {
+            return Ok(());
+        }
+        let option_legs: Vec<OptionLeg> = legs.iter().filter_map(OptionLeg::from_leg).collect();
+        if option_legs.is_empty() {
+            return Ok(());
+        }
+        let rule = |rule: &str| DomainError::ValidationError(format!("{strategy_type} {rule}"));
+        if option_legs.len() != legs.len() {
+            return Err(rule("legs must all carry option terms"));
+        }
+        if let Some(expected) = strategy_type.expected_legs()
+            && legs.len() != expected
+        {
+            return Err(rule(&format!(
+                "requires exactly {expected} legs, got {}",
+                legs.len()
+            )));
+        }
+
+        match (strategy_type, option_legs.as_slice()) {
+            (StrategyType::Spread, [a, b]) => {
+                if a.side == b.side {
+                    return Err(rule("legs must be on opposite sides"));
+                }
+                if a.terms.strike() == b.terms.strike() && a.terms.expiry() == b.terms.expiry() {
+                    return Err(rule("legs must differ in strike or expiry"));
+                }
+            }
+            (StrategyType::Straddle | StrategyType::Strangle, [a, b]) => {
+                if a.terms.kind() == b.terms.kind() {
+                    return Err(rule("requires one call and one put"));
+                }
+                if a.terms.expiry() != b.terms.expiry() {
+                    return Err(rule("legs must share the same expiry"));
+                }
+                let same_strike = a.terms.strike() == b.terms.strike();
+                if strategy_type == StrategyType::Straddle && !same_strike {
+                    return Err(rule("legs must share the same strike"));
+                }
+                if strategy_type == StrategyType::Strangle && same_strike {
+                    return Err(rule("legs must have different strikes"));
+                }
+                if a.side != OrderSide::Buy || b.side != OrderSide::Buy {
+                    return Err(rule("legs must both be buys"));
+                }
+            }
+            (StrategyType::Butterfly, [_, _, _]) => {
+                Self::validate_single_expiry(&option_legs, rule)?;
+                let mut kinds = option_legs.iter().map(|leg| leg.terms.kind());
+                let first = kinds.next();
+                if kinds.any(|kind| Some(kind) != first) {
+                    return Err(rule("legs must all be calls or all puts"));
+                }
+                let mut by_strike = option_legs.clone();
+                by_strike.sort_by_key(|leg| leg.terms.strike());
+                let [low, mid, high] = by_strike.as_slice() else {
+                    return Err(rule("requires exactly 3 legs"));
+                };
+                if low.terms.strike() >= mid.terms.strike()
+                    || mid.terms.strike() >= high.terms.strike()
+                {
+                    return Err(rule(
+                        "strikes must be strictly increasing (low < mid < high)",
+                    ));
+                }
+                if (low.ratio, mid.ratio, high.ratio) != (1, 2, 1) {
+                    return Err(rule("ratios must be 1/2/1 from low to high strike"));
+                }
+                if low.side != high.side || mid.side == low.side {
+                    return Err(rule("wings must be on the same side, opposite the body"));
+                }
+            }
+            (StrategyType::IronCondor, [_, _, _, _]) => {
+                Self::validate_single_expiry(&option_legs, rule)?;
+                let mut puts: Vec<&OptionLeg> = option_legs
+                    .iter()
+                    .filter(|leg| leg.terms.kind() == OptionKind::Put)
+                    .collect();
+                let mut calls: Vec<&OptionLeg> = option_legs
+                    .iter()
+                    .filter(|leg| leg.terms.kind() == OptionKind::Call)
+                    .collect();
+                puts.sort_by_key(|leg| leg.terms.strike());
+                calls.sort_by_key(|leg| leg.terms.strike());
+                let ([low_put, high_put], [low_call, high_call]) =
+                    (puts.as_slice(), calls.as_slice())
+                else {
+                    return Err(rule("requires two puts and two calls"));
+                };
+                if low_put.terms.strike() == high_put.terms.strike()
+                    || low_put.side != OrderSide::Buy
+                    || high_put.side != OrderSide::Sell
+                {
+                    return Err(rule(
+                        "put spread must buy the lower strike and sell the higher strike",
+                    ));
+                }
+                if low_call.terms.strike() == high_call.terms.strike()
+                    || low_call.side != OrderSide::Sell
+                    || high_call.side != OrderSide::Buy
+                {
+                    return Err(rule(
+                        "call spread must sell the lower strike and buy the higher strike",
+                    ));
+                }
+                if high_put.terms.strike() >= low_call.terms.strike() {
+                    return Err(rule("put strikes must lie below call strikes"));
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Checks that all legs expire together.
+    fn validate_single_expiry(
+        legs: &[OptionLeg],
+        rule: impl Fn(&str) -> DomainError,
+    ) -> DomainResult<()> {
+        let mut expiries = legs.iter().map(|leg| leg.terms.expiry());
+        let first = expiries.next();
+        if expiries.any(|expiry| Some(expiry) != first) {
+            return Err(rule("legs must share the same expiry"));
+        }
         Ok(())
     }
 
@@ -418,6 +601,16 @@ impl Strategy {
         &self.legs
     }
 
+    /// Returns the first leg.
+    ///
+    /// Every strategy has at least one leg, so this never fails.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::indexing_slicing)] // legs are non-empty by construction
+    pub fn primary_leg(&self) -> &StrategyLeg {
+        &self.legs[0]
+    }
+
     /// Returns the number of legs.
     #[inline]
     #[must_use]
@@ -484,7 +677,9 @@ impl Strategy {
     ///
     /// # Errors
     ///
-    /// Returns [`DomainError::ValidationError`] if legs have different underlyings.
+    /// Returns [`DomainError::ValidationError`] if legs have different
+    /// underlyings, or carry option terms that are not a call and a put
+    /// with the same strike and expiry.
     pub fn straddle(
         call_instrument: Instrument,
         put_instrument: Instrument,
@@ -512,7 +707,9 @@ impl Strategy {
     ///
     /// # Errors
     ///
-    /// Returns [`DomainError::ValidationError`] if legs have different underlyings.
+    /// Returns [`DomainError::ValidationError`] if legs have different
+    /// underlyings, or carry option terms that are not a call and a put
+    /// with the same expiry.
     pub fn strangle(
         call_instrument: Instrument,
         put_instrument: Instrument,
@@ -619,6 +816,24 @@ impl fmt::Display for Strategy {
     }
 }
 
+/// A leg's side, ratio and option terms, as used by structural checks.
+#[derive(Debug, Clone, Copy)]
+struct OptionLeg {
+    side: OrderSide,
+    ratio: u32,
+    terms: OptionTerms,
+}
+
+impl OptionLeg {
+    fn from_leg(leg: &StrategyLeg) -> Option<Self> {
+        leg.instrument().option_terms().map(|terms| Self {
+            side: leg.side(),
+            ratio: leg.ratio(),
+            terms: *terms,
+        })
+    }
+}
+
 // ============================================================================
 // StrategyBuilder
 // ============================================================================
@@ -713,6 +928,20 @@ mod tests {
         Instrument::builder(symbol, AssetClass::CryptoDerivs).build()
     }
 
+    /// Helper: creates a BTC option expiring `expiry_days` after 2100-01-01.
+    fn make_option(kind: OptionKind, strike: f64, expiry_days: i64) -> Instrument {
+        use crate::domain::value_objects::{Price, Timestamp};
+
+        let expiry = Timestamp::from_secs(4_102_444_800)
+            .unwrap()
+            .add_secs(expiry_days * 86_400);
+        let terms = OptionTerms::new(Price::new(strike).unwrap(), expiry, kind).unwrap();
+        Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoOptions)
+            .option_terms(terms)
+            .try_build()
+            .unwrap()
+    }
+
     // ====================================================================
     // StrategyType tests
     // ====================================================================
@@ -1089,6 +1318,58 @@ mod tests {
             assert_eq!(strategy.legs()[1].side(), OrderSide::Buy);
         }
 
+        #[test]
+        fn straddle_with_matching_option_terms() {
+            let call = make_option(OptionKind::Call, 60_000.0, 0);
+            let put = make_option(OptionKind::Put, 60_000.0, 0);
+
+            assert!(Strategy::straddle(call, put, "BTC").is_ok());
+        }
+
+        #[test]
+        fn straddle_with_mismatched_strikes_fails() {
+            let call = make_option(OptionKind::Call, 60_000.0, 0);
+            let put = make_option(OptionKind::Put, 55_000.0, 0);
+
+            let result = Strategy::straddle(call, put, "BTC");
+            assert!(
+                matches!(result, Err(DomainError::ValidationError(msg)) if msg.contains("strike"))
+            );
+        }
+
+        #[test]
+        fn straddle_with_mismatched_expiries_fails() {
+            let call = make_option(OptionKind::Call, 60_000.0, 0);
+            let put = make_option(OptionKind::Put, 60_000.0, 7);
+
+            let result = Strategy::straddle(call, put, "BTC");
+            assert!(
+                matches!(result, Err(DomainError::ValidationError(msg)) if msg.contains("expiry"))
+            );
+        }
+
+        #[test]
+        fn straddle_of_two_calls_fails() {
+            let call = make_option(OptionKind::Call, 60_000.0, 0);
+
+            assert!(Strategy::straddle(call.clone(), call, "BTC").is_err());
+        }
+
+        #[test]
+        fn strangle_allows_different_strikes() {
+            let call = make_option(OptionKind::Call, 65_000.0, 0);
+            let put = make_option(OptionKind::Put, 55_000.0, 0);
+
+            assert!(Strategy::strangle(call, put, "BTC").is_ok());
+        }
+
+        #[test]
+        fn straddle_mixing_option_and_plain_legs_fails() {
+            let call = make_option(OptionKind::Call, 60_000.0, 0);
+
+            assert!(Strategy::straddle(call, make_instrument("BTC/USD"), "BTC").is_err());
+        }
+
         #[test]
         fn strangle() {
             let call = make_instrument("BTC/USD");
@@ -1283,5 +1564,281 @@ mod tests {
             let deserialized: Strategy = serde_json::from_str(&json).unwrap();
             assert_eq!(strategy.description(), deserialized.description());
         }
+
+        #[test]
+        fn deserialize_rejects_invalid_legs() {
+            let inst = make_instrument("BTC/USD");
+            let strategy = Strategy::new(
+                StrategyType::Custom,
+                vec![StrategyLeg::new(inst, OrderSide::Buy, 1).unwrap()],
+                "BTC",
+                None,
+            )
+            .unwrap();
+
+            let mut json = serde_json::to_value(&strategy).unwrap();
+            json["strategy_type"] = serde_json::json!("STRADDLE");
+            assert!(serde_json::from_value::<Strategy>(json.clone()).is_err());
+
+            json["strategy_type"] = serde_json::json!("CUSTOM");
+            json["legs"] = serde_json::json!([]);
+            assert!(serde_json::from_value::<Strategy>(json).is_err());
+        }
+    }
+
+    // ====================================================================
+    // Structural validation tests
+    // ====================================================================
+
+    mod structural_validation_tests {
+        use super::*;
+        use OptionKind::{Call, Put};
+        use OrderSide::{Buy, Sell};
+
+        fn leg(kind: OptionKind, strike: f64, side: OrderSide, ratio: u32) -> StrategyLeg {
+            StrategyLeg::new(make_option(kind, strike, 0), side, ratio).unwrap()
+        }
+
+        fn build(strategy_type: StrategyType, legs: Vec<StrategyLeg>) -> DomainResult<Strategy> {
+            Strategy::new(strategy_type, legs, "BTC", None)
+        }
+
+        fn assert_rule(result: DomainResult<Strategy>, rule: &str) {
+            match result {
+                Err(DomainError::ValidationError(msg)) => {
+                    assert!(msg.contains(rule), "expected '{rule}', got '{msg}'");
+                }
+                other => assert!(other.is_err(), "expected '{rule}' violation"),
+            }
+        }
+
+        #[test]
+        fn spread_vertical_and_calendar_accepted() {
+            let vertical = vec![leg(Call, 60_000.0, Buy, 1), leg(Call, 65_000.0, Sell, 1)];
+            assert!(build(StrategyType::Spread, vertical).is_ok());
+
+            let calendar = vec![
+                StrategyLeg::new(make_option(Call, 60_000.0, 0), Sell, 1).unwrap(),
+                StrategyLeg::new(make_option(Call, 60_000.0, 30), Buy, 1).unwrap(),
+            ];
+            assert!(build(StrategyType::Spread, calendar).is_ok());
+        }
+
+        #[test]
+        fn spread_same_side_rejected() {
+            let legs = vec![leg(Call, 60_000.0, Buy, 1), leg(Call, 65_000.0, Buy, 1)];
+            assert_rule(build(StrategyType::Spread, legs), "opposite sides");
+        }
+
+        #[test]
+        fn spread_identical_contracts_rejected() {
+            let legs = vec![leg(Call, 60_000.0, Buy, 1), leg(Call, 60_000.0, Sell, 1)];
+            assert_rule(
+                build(StrategyType::Spread, legs),
+                "differ in strike or expiry",
+            );
+        }
+
+        #[test]
+        fn straddle_rules() {
+            let ok = vec![leg(Call, 60_000.0, Buy, 1), leg(Put, 60_000.0, Buy, 1)];
+            assert!(build(StrategyType::Straddle, ok).is_ok());
+
+            let two_puts = vec![leg(Put, 60_000.0, Buy, 1), leg(Put, 60_000.0, Buy, 1)];
+            assert_rule(
+                build(StrategyType::Straddle, two_puts),
+                "one call and one put",
+            );
+
+            let sold = vec![leg(Call, 60_000.0, Sell, 1), leg(Put, 60_000.0, Buy, 1)];
+            assert_rule(build(StrategyType::Straddle, sold), "both be buys");
+
+            let strikes = vec![leg(Call, 60_000.0, Buy, 1), leg(Put, 55_000.0, Buy, 1)];
+            assert_rule(build(StrategyType::Straddle, strikes), "same strike");
+        }
+
+        #[test]
+        fn strangle_rules() {
+            let ok = vec![leg(Call, 65_000.0, Buy, 1), leg(Put, 55_000.0, Buy, 1)];
+            assert!(build(StrategyType::Strangle, ok).is_ok());
+
+            let same = vec![leg(Call, 60_000.0, Buy, 1), leg(Put, 60_000.0, Buy, 1)];
+            assert_rule(build(StrategyType::Strangle, same), "different strikes");
+
+            let sold = vec![leg(Call, 65_000.0, Buy, 1), leg(Put, 55_000.0, Sell, 1)];
+            assert_rule(build(StrategyType::Strangle, sold), "both be buys");
+
+            let expiries = vec![
+                StrategyLeg::new(make_option(Call, 65_000.0, 0), Buy, 1).unwrap(),
+                StrategyLeg::new(make_option(Put, 55_000.0, 7), Buy, 1).unwrap(),
+            ];
+            assert_rule(build(StrategyType::Strangle, expiries), "same expiry");
+        }
+
+        fn butterfly() -> Vec<StrategyLeg> {
+            vec![
+                leg(Call, 55_000.0, Buy, 1),
+                leg(Call, 60_000.0, Sell, 2),
+                leg(Call, 65_000.0, Buy, 1),
+            ]
+        }
+
+        #[test]
+        fn butterfly_long_and_short_accepted_in_any_order() {
+            assert!(build(StrategyType::Butterfly, butterfly()).is_ok());
+
+            let mut reordered = butterfly();
+            reordered.reverse();
+            assert!(build(StrategyType::Butterfly, reordered).is_ok());
+
+            let short = vec![
+                leg(Put, 55_000.0, Sell, 1),
+                leg(Put, 60_000.0, Buy, 2),
+                leg(Put, 65_000.0, Sell, 1),
+            ];
+            assert!(build(StrategyType::Butterfly, short).is_ok());
+        }
+
+        #[test]
+        fn butterfly_rules() {
+            let repeated = vec![
+                leg(Call, 55_000.0, Buy, 1),
+                leg(Call, 55_000.0, Sell, 2),
+                leg(Call, 65_000.0, Buy, 1),
+            ];
+            assert_rule(
+                build(StrategyType::Butterfly, repeated),
+                "strictly increasing",
+            );
+
+            let ratios = vec![
+                leg(Call, 55_000.0, Buy, 1),
+                leg(Call, 60_000.0, Sell, 1),
+                leg(Call, 65_000.0, Buy, 1),
+            ];
+            assert_rule(build(StrategyType::Butterfly, ratios), "1/2/1");
+
+            let sides = vec![
+                leg(Call, 55_000.0, Buy, 1),
+                leg(Call, 60_000.0, Buy, 2),
+                leg(Call, 65_000.0, Buy, 1),
+            ];
+            assert_rule(build(StrategyType::Butterfly, sides), "opposite the body");
+
+            let kinds = vec![
+                leg(Call, 55_000.0, Buy, 1),
+                leg(Put, 60_000.0, Sell, 2),
+                leg(Call, 65_000.0, Buy, 1),
+            ];
+            assert_rule(
+                build(StrategyType::Butterfly, kinds),
+                "all be calls or all puts",
+            );
+
+            let mut four = butterfly();
+            four.push(leg(Call, 70_000.0, Buy, 1));
+            assert_rule(build(StrategyType::Butterfly, four), "exactly 3 legs");
+        }
+
+        fn iron_condor() -> Vec<StrategyLeg> {
+            vec![
+                leg(Put, 50_000.0, Buy, 1),
+                leg(Put, 55_000.0, Sell, 1),
+                leg(Call, 65_000.0, Sell, 1),
+                leg(Call, 70_000.0, Buy, 1),
+            ]
+        }
+
+        #[test]
+        fn iron_condor_accepted_in_any_order() {
+            assert!(build(StrategyType::IronCondor, iron_condor()).is_ok());
+
+            let mut reordered = iron_condor();
+            reordered.rotate_left(2);
+            assert!(build(StrategyType::IronCondor, reordered).is_ok());
+        }
+
+        #[test]
+        fn iron_condor_rules() {
+            let identical = vec![
+                leg(Call, 60_000.0, Buy, 1),
+                leg(Call, 60_000.0, Buy, 1),
+                leg(Call, 60_000.0, Buy, 1),
+                leg(Call, 60_000.0, Buy, 1),
+            ];
+            assert_rule(
+                build(StrategyType::IronCondor, identical),
+                "two puts and two calls",
+            );
+
+            let mut put_spread = iron_condor();
+            put_spread[0] = leg(Put, 50_000.0, Sell, 1);
+            put_spread[1] = leg(Put, 55_000.0, Buy, 1);
+            assert_rule(build(StrategyType::IronCondor, put_spread), "put spread");
+
+            let mut call_spread = iron_condor();
+            call_spread[3] = leg(Call, 70_000.0, Sell, 1);
+            assert_rule(build(StrategyType::IronCondor, call_spread), "call spread");
+
+            let overlapping = vec![
+                leg(Put, 50_000.0, Buy, 1),
+                leg(Put, 66_000.0, Sell, 1),
+                leg(Call, 65_000.0, Sell, 1),
+                leg(Call, 70_000.0, Buy, 1),
+            ];
+            assert_rule(
+                build(StrategyType::IronCondor, overlapping),
+                "below call strikes",
+            );
+
+            let mut expiries = iron_condor();
+            expiries[3] = StrategyLeg::new(make_option(Call, 70_000.0, 7), Buy, 1).unwrap();
+            assert_rule(build(StrategyType::IronCondor, expiries), "same expiry");
+        }
+
+        #[test]
+        fn custom_is_unconstrained() {
+            let legs = vec![leg(Call, 60_000.0, Buy, 1), leg(Call, 60_000.0, Buy, 3)];
+            assert!(build(StrategyType::Custom, legs).is_ok());
+        }
+
+        #[test]
+        fn legs_without_terms_skip_structural_checks() {
+            let inst = make_instrument("BTC/USD");
+            let legs = vec![
+                StrategyLeg::new(inst.clone(), Buy, 1).unwrap(),
+                StrategyLeg::new(inst.clone(), Buy, 1).unwrap(),
+                StrategyLeg::new(inst.clone(), Buy, 1).unwrap(),
+                StrategyLeg::new(inst, Buy, 1).unwrap(),
+            ];
+            assert!(build(StrategyType::IronCondor, legs).is_ok());
+        }
+
+        #[test]
+        fn predefined_constructors_produce_valid_shapes() {
+            let strategy = Strategy::iron_condor(
+                make_option(Put, 50_000.0, 0),
+                make_option(Put, 55_000.0, 0),
+                make_option(Call, 65_000.0, 0),
+                make_option(Call, 70_000.0, 0),
+                "BTC",
+            );
+            assert!(strategy.is_ok());
+
+            let strategy = Strategy::butterfly(
+                make_option(Call, 55_000.0, 0),
+                make_option(Call, 60_000.0, 0),
+                make_option(Call, 65_000.0, 0),
+                "BTC",
+            );
+            assert!(strategy.is_ok());
+
+            let strategy = Strategy::vertical_spread(
+                make_option(Put, 60_000.0, 0),
+                make_option(Put, 55_000.0, 0),
+                "BTC",
+            );
+            assert!(strategy.is_ok());
+        }
     }
 }
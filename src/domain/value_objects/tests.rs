@@ -18,6 +18,7 @@ use proptest::prelude::*;
 use super::arithmetic::{ArithmeticError, CheckedArithmetic};
 use super::price::Price;
 use super::quantity::Quantity;
+use super::signed_amount::SignedDecimalAmount;
 use super::timestamp::Timestamp;
 use rust_decimal::Decimal;
 
@@ -454,6 +455,91 @@ proptest! {
     }
 }
 
+// ============================================================================
+// SignedDecimalAmount Property Tests
+// ============================================================================
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(1000))]
+
+    /// SignedDecimalAmount addition is commutative: a + b == b + a
+    #[test]
+    fn signed_amount_addition_commutative(a in valid_decimal(), b in valid_decimal()) {
+        let (a, b) = (SignedDecimalAmount::new(a), SignedDecimalAmount::new(b));
+        prop_assert_eq!(a.safe_add(b), b.safe_add(a));
+    }
+
+    /// SignedDecimalAmount subtraction inverse: (a + b) - b == a
+    #[test]
+    fn signed_amount_subtraction_inverse(a in valid_decimal(), b in valid_decimal()) {
+        let (a, b) = (SignedDecimalAmount::new(a), SignedDecimalAmount::new(b));
+        let result = a.safe_add(b).and_then(|sum| sum.safe_sub(b));
+        prop_assert_eq!(result, Ok(a));
+    }
+
+    /// a - a is positive zero, never negative zero
+    #[test]
+    fn signed_amount_self_subtraction_is_zero(a in valid_decimal()) {
+        let a = SignedDecimalAmount::new(a);
+        let zero = a.safe_sub(a).unwrap();
+        prop_assert_eq!(zero, SignedDecimalAmount::ZERO);
+        prop_assert!(!zero.get().is_sign_negative());
+        prop_assert_eq!(a.safe_add(a.negate()), Ok(SignedDecimalAmount::ZERO));
+    }
+
+    /// Adding past Decimal::MAX or Decimal::MIN overflows instead of panicking
+    #[test]
+    fn signed_amount_add_sub_overflow(b in 1i64..1_000_000_000i64) {
+        let max = SignedDecimalAmount::new(Decimal::MAX);
+        let min = SignedDecimalAmount::new(Decimal::MIN);
+        let b = SignedDecimalAmount::new(Decimal::new(b, 0));
+
+        prop_assert_eq!(max.safe_add(b), Err(ArithmeticError::Overflow));
+        prop_assert_eq!(max.safe_sub(b.negate()), Err(ArithmeticError::Overflow));
+        prop_assert_eq!(min.safe_sub(b), Err(ArithmeticError::Overflow));
+        prop_assert_eq!(min.safe_add(b.negate()), Err(ArithmeticError::Overflow));
+        prop_assert!(max.safe_sub(b).is_ok());
+        prop_assert!(min.safe_add(b).is_ok());
+    }
+
+    /// Conversion to Price succeeds exactly when the amount is non-negative
+    #[test]
+    fn signed_amount_price_conversion(a in valid_decimal()) {
+        let amount = SignedDecimalAmount::new(a);
+        match Price::try_from(amount) {
+            Ok(price) => {
+                prop_assert!(!amount.is_negative());
+                prop_assert_eq!(SignedDecimalAmount::from(price), amount);
+            }
+            Err(err) => {
+                prop_assert!(amount.is_negative());
+                prop_assert!(matches!(err, ArithmeticError::InvalidValue(_)));
+            }
+        }
+    }
+
+    /// SignedDecimalAmount serde and display roundtrips preserve value and sign
+    #[test]
+    fn signed_amount_serde_and_display_roundtrip(a in valid_decimal()) {
+        let amount = SignedDecimalAmount::new(a);
+        let json = serde_json::to_string(&amount).expect("serialize");
+        let deserialized: SignedDecimalAmount = serde_json::from_str(&json).expect("deserialize");
+        prop_assert_eq!(amount, deserialized);
+
+        let parsed: SignedDecimalAmount = amount.to_string().parse().expect("parse");
+        prop_assert_eq!(amount, parsed);
+    }
+
+    /// SignedDecimalAmount ordering is consistent with Decimal ordering
+    #[test]
+    fn signed_amount_comparison_consistent(a in valid_decimal(), b in valid_decimal()) {
+        prop_assert_eq!(
+            SignedDecimalAmount::new(a).cmp(&SignedDecimalAmount::new(b)),
+            a.cmp(&b)
+        );
+    }
+}
+
 // ============================================================================
 // Edge Case Tests (Non-Property-Based)
 // ============================================================================
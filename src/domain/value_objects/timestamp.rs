@@ -236,7 +236,27 @@ impl Timestamp {
     /// ```
     #[must_use]
     pub fn is_expired(&self) -> bool {
-        self.0 < Utc::now()
+        self.is_expired_at(Self::now())
+    }
+
+    /// Returns true if this timestamp is before the given instant.
+    ///
+    /// Pure variant of [`is_expired`](Self::is_expired) for callers that
+    /// read the time from a [`Clock`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use otc_rfq::domain::value_objects::timestamp::Timestamp;
+    ///
+    /// let deadline = Timestamp::from_secs(1_000).unwrap();
+    /// assert!(!deadline.is_expired_at(deadline));
+    /// assert!(deadline.is_expired_at(deadline.add_secs(1)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_expired_at(&self, now: Self) -> bool {
+        self.0 < now.0
     }
 
     /// Returns true if this timestamp is before another.
@@ -367,6 +387,94 @@ impl Sub<Timestamp> for Timestamp {
     }
 }
 
+/// Source of the current time.
+///
+/// Services that make time-based decisions (expiry, timeouts, rolling
+/// windows) read the time through a `Clock` so tests can control it.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::timestamp::{Clock, SystemClock, Timestamp};
+///
+/// let clock = SystemClock;
+/// let deadline = clock.now().add_secs(60);
+/// assert!(!deadline.is_expired_at(clock.now()));
+/// ```
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// Returns the current time.
+    fn now(&self) -> Timestamp;
+}
+
+/// Clock backed by the system wall clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&self) -> Timestamp {
+        Timestamp::now()
+    }
+}
+
+/// Manually controlled clock for tests.
+///
+/// Available in unit tests and to downstream crates via the `test-util`
+/// feature.
+#[cfg(any(test, feature = "test-util"))]
+#[derive(Debug)]
+pub struct MockClock {
+    now: std::sync::Mutex<Timestamp>,
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl MockClock {
+    /// Creates a clock frozen at `start`.
+    #[must_use]
+    pub fn new(start: Timestamp) -> Self {
+        Self {
+            now: std::sync::Mutex::new(start),
+        }
+    }
+
+    /// Sets the current time.
+    pub fn set(&self, now: Timestamp) {
+        *self.lock() = now;
+    }
+
+    /// Moves the clock forward by `duration`.
+    pub fn advance(&self, duration: std::time::Duration) {
+        let mut now = self.lock();
+        *now = *now + duration;
+    }
+
+    /// Moves the clock forward by `secs` seconds.
+    pub fn advance_secs(&self, secs: i64) {
+        let mut now = self.lock();
+        *now = now.add_secs(secs);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Timestamp> {
+        self.now
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new(Timestamp::now())
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl Clock for MockClock {
+    fn now(&self) -> Timestamp {
+        *self.lock()
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -582,4 +690,40 @@ mod tests {
             assert_eq!(&dt, ts.as_datetime());
         }
     }
+
+    mod clock {
+        use super::*;
+
+        #[test]
+        fn mock_clock_is_frozen_until_moved() {
+            let start = Timestamp::from_secs(1_000).unwrap();
+            let clock = MockClock::new(start);
+            assert_eq!(clock.now(), start);
+            assert_eq!(clock.now(), start);
+
+            clock.advance_secs(30);
+            assert_eq!(clock.now().timestamp_secs(), 1_030);
+
+            clock.advance(std::time::Duration::from_millis(500));
+            assert_eq!(clock.now().timestamp_millis(), 1_030_500);
+
+            clock.set(start);
+            assert_eq!(clock.now(), start);
+        }
+
+        #[test]
+        fn is_expired_at_is_strict() {
+            let deadline = Timestamp::from_secs(1_000).unwrap();
+            assert!(!deadline.is_expired_at(deadline.sub_secs(1)));
+            assert!(!deadline.is_expired_at(deadline));
+            assert!(deadline.is_expired_at(deadline.add_millis(1)));
+        }
+
+        #[test]
+        fn system_clock_tracks_wall_time() {
+            let before = Timestamp::now();
+            let now = SystemClock.now();
+            assert!(!now.is_before(&before));
+        }
+    }
 }
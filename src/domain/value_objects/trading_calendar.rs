@@ -0,0 +1,285 @@
+//! # Trading Calendar
+//!
+//! Which days a market trades on.
+//!
+//! Crypto trades around the clock, so the [`CRYPTO_CALENDAR`] is always
+//! open. Instruments settled through traditional rails follow a named
+//! calendar that is closed on weekends and on a set of holidays, both
+//! judged by the local date in the calendar's timezone.
+//!
+//! Business-day arithmetic works on local dates and keeps the local time of
+//! day, so two business days after 15:00 Friday New York time is 15:00
+//! Tuesday New York time, daylight saving permitting.
+//!
+//! # Examples
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use otc_rfq::domain::value_objects::trading_calendar::TradingCalendar;
+//!
+//! let christmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+//! let calendar = TradingCalendar::new("NYSE", chrono_tz::America::New_York, true)
+//!     .unwrap()
+//!     .with_holidays([christmas]);
+//!
+//! assert!(!calendar.is_trading_day(christmas));
+//! assert_eq!(
+//!     calendar.next_trading_day(christmas),
+//!     NaiveDate::from_ymd_opt(2024, 12, 26).unwrap()
+//! );
+//! assert!(TradingCalendar::crypto().is_always_open());
+//! ```
+
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::timestamp::Timestamp;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Name of the built-in 24/7 calendar.
+pub const CRYPTO_CALENDAR: &str = "CRYPTO";
+
+/// Trading days of a market.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradingCalendar {
+    name: String,
+    timezone: Tz,
+    weekends_closed: bool,
+    holidays: BTreeSet<NaiveDate>,
+}
+
+impl TradingCalendar {
+    /// Creates a calendar with no holidays.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ValidationError` if `name` is blank.
+    pub fn new(name: impl Into<String>, timezone: Tz, weekends_closed: bool) -> DomainResult<Self> {
+        let name = name.into().trim().to_uppercase();
+        if name.is_empty() {
+            return Err(DomainError::ValidationError(
+                "calendar name must not be empty".to_string(),
+            ));
+        }
+        Ok(Self {
+            name,
+            timezone,
+            weekends_closed,
+            holidays: BTreeSet::new(),
+        })
+    }
+
+    /// The built-in calendar of crypto markets, open around the clock.
+    #[must_use]
+    pub fn crypto() -> Self {
+        Self {
+            name: CRYPTO_CALENDAR.to_string(),
+            timezone: Tz::UTC,
+            weekends_closed: false,
+            holidays: BTreeSet::new(),
+        }
+    }
+
+    /// Adds holidays to the calendar.
+    #[must_use]
+    pub fn with_holidays(mut self, holidays: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.holidays.extend(holidays);
+        self
+    }
+
+    /// Returns the calendar name, in upper case.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the timezone local dates are judged in.
+    #[must_use]
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Returns true if the market is closed on Saturdays and Sundays.
+    #[must_use]
+    pub fn weekends_closed(&self) -> bool {
+        self.weekends_closed
+    }
+
+    /// Returns the holidays, in date order.
+    #[must_use]
+    pub fn holidays(&self) -> &BTreeSet<NaiveDate> {
+        &self.holidays
+    }
+
+    /// Returns true if the market never closes.
+    #[must_use]
+    pub fn is_always_open(&self) -> bool {
+        !self.weekends_closed && self.holidays.is_empty()
+    }
+
+    /// Returns true if `date` is a trading day.
+    #[must_use]
+    pub fn is_trading_day(&self, date: NaiveDate) -> bool {
+        let weekend = matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+        !(self.holidays.contains(&date) || self.weekends_closed && weekend)
+    }
+
+    /// Returns true if the market is open at `at`.
+    #[must_use]
+    pub fn is_trading_time(&self, at: Timestamp) -> bool {
+        self.is_trading_day(self.local_date(at))
+    }
+
+    /// Returns the first trading day on or after `date`.
+    #[must_use]
+    pub fn next_trading_day(&self, date: NaiveDate) -> NaiveDate {
+        let mut date = date;
+        // Each closed day is a weekend day or a holiday, so this ends
+        for _ in 0..=self.holidays.len().saturating_add(2) {
+            if self.is_trading_day(date) {
+                break;
+            }
+            date = date.succ_opt().unwrap_or(date);
+        }
+        date
+    }
+
+    /// Returns `at` if the market is open then, otherwise the start of the
+    /// next trading day.
+    #[must_use]
+    pub fn next_trading_open(&self, at: Timestamp) -> Timestamp {
+        let date = self.local_date(at);
+        if self.is_trading_day(date) {
+            return at;
+        }
+        local_instant(
+            self.timezone,
+            self.next_trading_day(date).and_time(chrono::NaiveTime::MIN),
+        )
+    }
+
+    /// Returns the same local time `days` trading days after the local date
+    /// of `at`.
+    #[must_use]
+    pub fn add_business_days(&self, at: Timestamp, days: u32) -> Timestamp {
+        if days == 0 {
+            return at;
+        }
+        let local = at.as_datetime().with_timezone(&self.timezone).naive_local();
+        let mut date = local.date();
+        for _ in 0..days {
+            date = self.next_trading_day(date.succ_opt().unwrap_or(date));
+        }
+        local_instant(self.timezone, date.and_time(local.time()))
+    }
+
+    fn local_date(&self, at: Timestamp) -> NaiveDate {
+        at.as_datetime().with_timezone(&self.timezone).date_naive()
+    }
+}
+
+impl Default for TradingCalendar {
+    /// The crypto calendar.
+    fn default() -> Self {
+        Self::crypto()
+    }
+}
+
+impl fmt::Display for TradingCalendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.name, self.timezone)
+    }
+}
+
+/// Returns the instant of `local` wall-clock time in `timezone`.
+///
+/// A time that falls in a daylight saving gap moves forward by an hour; one
+/// that falls in an overlap takes the earlier instant.
+pub(crate) fn local_instant(timezone: Tz, local: NaiveDateTime) -> Timestamp {
+    let resolved = timezone.from_local_datetime(&local).earliest().or_else(|| {
+        timezone
+            .from_local_datetime(&(local + chrono::Duration::hours(1)))
+            .earliest()
+    });
+    match resolved {
+        Some(at) => Timestamp::from(at.with_timezone(&Utc)),
+        None => Timestamp::from(Utc.from_utc_datetime(&local)),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn at(rfc3339: &str) -> Timestamp {
+        Timestamp::from(
+            chrono::DateTime::parse_from_rfc3339(rfc3339)
+                .unwrap()
+                .with_timezone(&Utc),
+        )
+    }
+
+    /// New York calendar with Good Friday 2024 and Easter Monday (for the
+    /// sake of the test) as holidays.
+    fn easter() -> TradingCalendar {
+        TradingCalendar::new("nyse", chrono_tz::America::New_York, true)
+            .unwrap()
+            .with_holidays([date(2024, 3, 29), date(2024, 4, 1)])
+    }
+
+    #[test]
+    fn business_days_skip_a_holiday_weekend() {
+        let calendar = easter();
+        // Thursday 2024-03-28 15:00 New York
+        let thursday = at("2024-03-28T15:00:00-04:00");
+
+        assert_eq!(
+            calendar.add_business_days(thursday, 1),
+            at("2024-04-02T15:00:00-04:00")
+        );
+        assert_eq!(
+            calendar.add_business_days(thursday, 2),
+            at("2024-04-03T15:00:00-04:00")
+        );
+        assert_eq!(calendar.add_business_days(thursday, 0), thursday);
+    }
+
+    #[test]
+    fn trading_time_follows_the_local_date() {
+        let calendar = easter();
+        // 02:00 UTC Saturday is still Friday evening in New York, a holiday
+        assert!(!calendar.is_trading_time(at("2024-03-30T02:00:00Z")));
+        // 23:00 New York Thursday is Friday in UTC but Thursday locally
+        assert!(calendar.is_trading_time(at("2024-03-28T23:00:00-04:00")));
+        assert_eq!(
+            calendar.next_trading_open(at("2024-03-29T12:00:00-04:00")),
+            at("2024-04-02T00:00:00-04:00")
+        );
+    }
+
+    #[test]
+    fn crypto_is_always_open() {
+        let crypto = TradingCalendar::crypto();
+        let saturday = at("2024-03-30T12:00:00Z");
+        assert!(crypto.is_always_open());
+        assert!(crypto.is_trading_time(saturday));
+        assert_eq!(crypto.next_trading_open(saturday), saturday);
+        assert_eq!(
+            crypto.add_business_days(saturday, 1),
+            at("2024-03-31T12:00:00Z")
+        );
+    }
+
+    #[test]
+    fn name_is_required_and_upper_cased() {
+        assert!(TradingCalendar::new("  ", Tz::UTC, true).is_err());
+        assert_eq!(easter().name(), "NYSE");
+    }
+}
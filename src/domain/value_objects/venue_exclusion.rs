@@ -0,0 +1,84 @@
+//! # Venue Exclusion
+//!
+//! Why a venue was left out of an RFQ's quote fan-out.
+//!
+//! This module provides the [`VenueExclusionReason`] enum and the
+//! [`ExcludedVenue`] record carried by
+//! [`QuoteCollectionStarted`](crate::domain::events::rfq_events::QuoteCollectionStarted).
+
+use crate::domain::value_objects::VenueId;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Reason a venue was not asked to quote an RFQ.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::VenueExclusionReason;
+///
+/// assert_eq!(VenueExclusionReason::Blocklisted.to_string(), "BLOCKLISTED");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VenueExclusionReason {
+    /// The RFQ has an allowlist that does not contain the venue.
+    NotAllowlisted,
+    /// The RFQ's blocklist contains the venue.
+    Blocklisted,
+    /// The venue is inside a scheduled maintenance window.
+    InMaintenance,
+    /// The venue cannot be sent the RFQ without revealing a masked size.
+    SizeNotMaskable,
+    /// The venue's circuit breaker is open.
+    CircuitOpen,
+}
+
+impl fmt::Display for VenueExclusionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAllowlisted => write!(f, "NOT_ALLOWLISTED"),
+            Self::Blocklisted => write!(f, "BLOCKLISTED"),
+            Self::InMaintenance => write!(f, "IN_MAINTENANCE"),
+            Self::SizeNotMaskable => write!(f, "SIZE_NOT_MASKABLE"),
+            Self::CircuitOpen => write!(f, "CIRCUIT_OPEN"),
+        }
+    }
+}
+
+/// A venue left out of an RFQ's fan-out, with the reason.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExcludedVenue {
+    /// The excluded venue.
+    pub venue_id: VenueId,
+    /// Why the venue was excluded.
+    pub reason: VenueExclusionReason,
+}
+
+impl ExcludedVenue {
+    /// Creates a new excluded venue record.
+    #[must_use]
+    pub fn new(venue_id: VenueId, reason: VenueExclusionReason) -> Self {
+        Self { venue_id, reason }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reason_serializes_like_display() {
+        for reason in [
+            VenueExclusionReason::NotAllowlisted,
+            VenueExclusionReason::Blocklisted,
+            VenueExclusionReason::InMaintenance,
+            VenueExclusionReason::SizeNotMaskable,
+            VenueExclusionReason::CircuitOpen,
+        ] {
+            let json = serde_json::to_string(&reason).unwrap();
+            assert_eq!(json, format!("\"{reason}\""));
+        }
+    }
+}
@@ -5,7 +5,10 @@
 //! This module defines the [`BlockchainClient`] trait that abstracts
 //! blockchain operations for Ethereum and L2 networks.
 
+use crate::domain::entities::trade::{FeeComponent, FeeKind};
+use crate::domain::value_objects::Blockchain;
 use async_trait::async_trait;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use thiserror::Error;
@@ -82,6 +85,15 @@ impl ChainId {
         }
     }
 
+    /// Returns the symbol of the native token used to pay gas.
+    #[must_use]
+    pub const fn native_currency(&self) -> &'static str {
+        match self {
+            Self::Polygon => "POL",
+            Self::Ethereum | Self::Arbitrum | Self::Optimism | Self::Base => "ETH",
+        }
+    }
+
     /// Returns whether EIP-1559 is supported.
     #[must_use]
     pub const fn supports_eip1559(&self) -> bool {
@@ -92,6 +104,30 @@ impl ChainId {
     }
 }
 
+impl From<Blockchain> for ChainId {
+    fn from(chain: Blockchain) -> Self {
+        match chain {
+            Blockchain::Ethereum => Self::Ethereum,
+            Blockchain::Polygon => Self::Polygon,
+            Blockchain::Arbitrum => Self::Arbitrum,
+            Blockchain::Optimism => Self::Optimism,
+            Blockchain::Base => Self::Base,
+        }
+    }
+}
+
+impl From<ChainId> for Blockchain {
+    fn from(chain: ChainId) -> Self {
+        match chain {
+            ChainId::Ethereum => Self::Ethereum,
+            ChainId::Polygon => Self::Polygon,
+            ChainId::Arbitrum => Self::Arbitrum,
+            ChainId::Optimism => Self::Optimism,
+            ChainId::Base => Self::Base,
+        }
+    }
+}
+
 impl fmt::Display for ChainId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.name())
@@ -167,6 +203,34 @@ pub struct TxReceipt {
     pub success: bool,
 }
 
+/// Number of decimals of the native gas token (wei per ether).
+const NATIVE_TOKEN_DECIMALS: u32 = 18;
+
+impl TxReceipt {
+    /// Returns the gas paid for this transaction in wei.
+    #[must_use]
+    pub fn gas_cost_wei(&self) -> u128 {
+        u128::from(self.gas_used).saturating_mul(u128::from(self.effective_gas_price))
+    }
+
+    /// Returns the gas paid for this transaction as a trade fee component,
+    /// denominated in the chain's native token.
+    ///
+    /// Returns `None` if the cost does not fit in a `Decimal`.
+    #[must_use]
+    pub fn gas_fee(&self, chain: ChainId) -> Option<FeeComponent> {
+        let wei = i128::try_from(self.gas_cost_wei()).ok()?;
+        let amount = Decimal::try_from_i128_with_scale(wei, NATIVE_TOKEN_DECIMALS)
+            .ok()?
+            .normalize();
+        Some(FeeComponent::new(
+            FeeKind::Gas,
+            amount,
+            chain.native_currency(),
+        ))
+    }
+}
+
 /// Error type for blockchain operations.
 #[derive(Debug, Error)]
 pub enum BlockchainError {
@@ -198,6 +262,28 @@ pub enum BlockchainError {
     #[error("unsupported chain: {0}")]
     UnsupportedChain(String),
 
+    /// Token balance too low to settle.
+    #[error("insufficient balance of {token}: required {required}, available {available}")]
+    InsufficientBalance {
+        /// Token contract address.
+        token: String,
+        /// Amount the settlement moves, in base units.
+        required: u128,
+        /// Balance held, in base units.
+        available: u128,
+    },
+
+    /// Token allowance too low to settle.
+    #[error("insufficient allowance of {token}: required {required}, available {available}")]
+    InsufficientAllowance {
+        /// Token contract address.
+        token: String,
+        /// Amount the settlement moves, in base units.
+        required: u128,
+        /// Allowance granted to the spender, in base units.
+        available: u128,
+    },
+
     /// Internal error.
     #[error("internal error: {0}")]
     Internal(String),
@@ -246,6 +332,30 @@ impl BlockchainError {
         Self::UnsupportedChain(msg.into())
     }
 
+    /// Creates an insufficient balance error.
+    #[must_use]
+    pub fn insufficient_balance(token: impl Into<String>, required: u128, available: u128) -> Self {
+        Self::InsufficientBalance {
+            token: token.into(),
+            required,
+            available,
+        }
+    }
+
+    /// Creates an insufficient allowance error.
+    #[must_use]
+    pub fn insufficient_allowance(
+        token: impl Into<String>,
+        required: u128,
+        available: u128,
+    ) -> Self {
+        Self::InsufficientAllowance {
+            token: token.into(),
+            required,
+            available,
+        }
+    }
+
     /// Creates an internal error.
     #[must_use]
     pub fn internal(msg: impl Into<String>) -> Self {
@@ -296,6 +406,18 @@ pub trait BlockchainClient: Send + Sync + fmt::Debug {
     /// Returns an error if gas estimation fails.
     async fn estimate_gas(&self, to: &str, data: &[u8], value: u128) -> BlockchainResult<u64>;
 
+    /// Executes a read-only call (`eth_call`) against the latest block.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - Contract address
+    /// * `data` - Call data
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the RPC call fails or the call reverts.
+    async fn call(&self, to: &str, data: &[u8]) -> BlockchainResult<Vec<u8>>;
+
     /// Returns the current gas price.
     ///
     /// # Arguments
@@ -345,6 +467,36 @@ pub trait BlockchainClient: Send + Sync + fmt::Debug {
         confirmations: u64,
     ) -> BlockchainResult<TxReceipt>;
 
+    /// Looks up the receipt of a previously submitted transaction.
+    ///
+    /// Returns `Ok(None)` if the transaction has not been mined.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_hash` - Transaction hash to look up
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the RPC call fails.
+    async fn get_transaction_receipt(
+        &self,
+        tx_hash: &TxHash,
+    ) -> BlockchainResult<Option<TxReceipt>>;
+
+    /// Returns true if the node knows a transaction that is not mined yet.
+    ///
+    /// Returns false for mined transactions and for transactions the node
+    /// no longer knows, e.g. because they were dropped from the mempool.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_hash` - Transaction hash to look up
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the RPC call fails.
+    async fn is_transaction_pending(&self, tx_hash: &TxHash) -> BlockchainResult<bool>;
+
     /// Returns the nonce for an address.
     ///
     /// # Arguments
@@ -408,6 +560,27 @@ mod tests {
         assert_eq!(hash.as_str(), "0x1234");
     }
 
+    #[test]
+    fn tx_receipt_gas_fee_in_native_token() {
+        let receipt = TxReceipt {
+            tx_hash: TxHash::new("0xabc"),
+            block_number: 1,
+            gas_used: 21_000,
+            effective_gas_price: 20_000_000_000,
+            success: true,
+        };
+
+        assert_eq!(receipt.gas_cost_wei(), 420_000_000_000_000);
+
+        let fee = receipt.gas_fee(ChainId::Ethereum).unwrap();
+        assert_eq!(fee.kind(), FeeKind::Gas);
+        assert_eq!(fee.amount(), Decimal::new(42, 5));
+        assert_eq!(fee.currency(), "ETH");
+
+        let fee = receipt.gas_fee(ChainId::Polygon).unwrap();
+        assert_eq!(fee.currency(), "POL");
+    }
+
     #[test]
     fn tx_priority_default() {
         assert_eq!(TxPriority::default(), TxPriority::Medium);
@@ -301,16 +301,54 @@ impl BlockchainClient for EthereumClient {
             }
         }
 
-        Ok(TxReceipt {
-            tx_hash: tx_hash.clone(),
-            block_number: receipt.block_number.map(|n| n.as_u64()).unwrap_or_default(),
-            gas_used: receipt.gas_used.map(|g| g.as_u64()).unwrap_or_default(),
-            effective_gas_price: receipt
-                .effective_gas_price
-                .map(|p| p.as_u64())
-                .unwrap_or_default(),
-            success: receipt.status.map(|s| s.as_u64() == 1).unwrap_or(false),
-        })
+        Ok(to_tx_receipt(tx_hash, &receipt))
+    }
+
+    async fn get_transaction_receipt(
+        &self,
+        tx_hash: &TxHash,
+    ) -> BlockchainResult<Option<TxReceipt>> {
+        let hash: H256 = tx_hash
+            .as_str()
+            .parse()
+            .map_err(|_| BlockchainError::internal("invalid transaction hash".to_string()))?;
+
+        let receipt = self
+            .provider
+            .get_transaction_receipt(hash)
+            .await
+            .map_err(|e| BlockchainError::connection(e.to_string()))?;
+
+        Ok(receipt.map(|r| to_tx_receipt(tx_hash, &r)))
+    }
+
+    async fn is_transaction_pending(&self, tx_hash: &TxHash) -> BlockchainResult<bool> {
+        let hash: H256 = tx_hash
+            .as_str()
+            .parse()
+            .map_err(|_| BlockchainError::internal("invalid transaction hash".to_string()))?;
+
+        let tx = self
+            .provider
+            .get_transaction(hash)
+            .await
+            .map_err(|e| BlockchainError::connection(e.to_string()))?;
+
+        Ok(tx.is_some_and(|tx| tx.block_number.is_none()))
+    }
+
+    async fn call(&self, to: &str, data: &[u8]) -> BlockchainResult<Vec<u8>> {
+        let to_addr: Address = to
+            .parse()
+            .map_err(|_| BlockchainError::internal(format!("invalid address: {}", to)))?;
+
+        let tx = TransactionRequest::new().to(to_addr).data(data.to_vec());
+
+        self.provider
+            .call(&tx.into(), None)
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| BlockchainError::connection(e.to_string()))
     }
 
     async fn get_nonce(&self, address: &str) -> BlockchainResult<u64> {
@@ -344,6 +382,20 @@ impl BlockchainClient for EthereumClient {
     }
 }
 
+/// Converts an ethers receipt into a [`TxReceipt`].
+fn to_tx_receipt(tx_hash: &TxHash, receipt: &TransactionReceipt) -> TxReceipt {
+    TxReceipt {
+        tx_hash: tx_hash.clone(),
+        block_number: receipt.block_number.map(|n| n.as_u64()).unwrap_or_default(),
+        gas_used: receipt.gas_used.map(|g| g.as_u64()).unwrap_or_default(),
+        effective_gas_price: receipt
+            .effective_gas_price
+            .map(|p| p.as_u64())
+            .unwrap_or_default(),
+        success: receipt.status.map(|s| s.as_u64() == 1).unwrap_or(false),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -11,6 +11,8 @@
 //! - [`ChainId`]: Supported blockchain networks
 //! - [`ChainConfig`]: Chain-specific configuration
 //! - [`TokenRegistry`]: Token address mapping across chains
+//! - [`SettlementPreflight`]: ERC-20 balance and allowance checks before settlement
+//! - [`recover_signer`]: Signer recovery for `personal_sign` messages
 //!
 //! ## Supported Chains
 //!
@@ -24,6 +26,8 @@ pub mod client;
 pub mod config;
 pub mod ethereum;
 pub mod gas;
+pub mod preflight;
+pub mod signing;
 pub mod tokens;
 
 pub use client::{
@@ -35,6 +39,9 @@ pub use config::{
 };
 pub use ethereum::EthereumClient;
 pub use gas::{FeeHistory, GasEstimator, GasPrice};
+pub use preflight::{ApprovalMode, PreflightConfig, PreflightReport, SettlementPreflight};
+pub use signing::recover_signer;
 pub use tokens::{
-    TokenError, TokenInfo, TokenRegistry, TokenResult, is_valid_address, normalize_address,
+    SharedTokenRegistry, TokenEntry, TokenError, TokenInfo, TokenRegistry, TokenResult,
+    from_base_units, is_valid_address, normalize_address, to_base_units,
 };
@@ -0,0 +1,447 @@
+//! # Settlement Preflight
+//!
+//! ERC-20 balance and allowance checks run before a settlement is broadcast.
+//!
+//! A settlement contract pulls tokens from the settling wallet with
+//! `transferFrom`, so the transaction reverts on-chain, after paying gas, if
+//! the wallet holds too little or has approved too little. [`SettlementPreflight`]
+//! reads both with `eth_call` first and fails with
+//! [`BlockchainError::InsufficientBalance`] or
+//! [`BlockchainError::InsufficientAllowance`] instead. If configured, a
+//! missing allowance is granted by an `approve` transaction before the
+//! settlement goes out.
+
+use super::client::{
+    BlockchainClient, BlockchainError, BlockchainResult, ChainId, TxHash, TxPriority,
+};
+use ethers::abi::{self, Token};
+use ethers::types::{Address, U256};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Selector of `balanceOf(address)`.
+const BALANCE_OF: [u8; 4] = [0x70, 0xa0, 0x82, 0x31];
+/// Selector of `allowance(address,address)`.
+const ALLOWANCE: [u8; 4] = [0xdd, 0x62, 0xed, 0x3e];
+/// Selector of `approve(address,uint256)`.
+const APPROVE: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3];
+
+/// How an insufficient allowance is handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApprovalMode {
+    /// Fail with `InsufficientAllowance`.
+    #[default]
+    Disabled,
+    /// Approve exactly the settlement amount.
+    Exact,
+    /// Approve the maximum `uint256`, so later settlements need no approval.
+    Max,
+}
+
+/// Configuration for [`SettlementPreflight`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreflightConfig {
+    /// Whether and how missing allowances are approved.
+    pub approval: ApprovalMode,
+    /// Gas priority for approval transactions.
+    pub priority: TxPriority,
+    /// Confirmations to wait for after an approval.
+    pub confirmations: u64,
+}
+
+impl Default for PreflightConfig {
+    fn default() -> Self {
+        Self {
+            approval: ApprovalMode::Disabled,
+            priority: TxPriority::High,
+            confirmations: 1,
+        }
+    }
+}
+
+/// Outcome of a successful preflight.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreflightReport {
+    /// Chain the settlement is submitted to.
+    pub chain: ChainId,
+    /// Token contract address.
+    pub token: String,
+    /// Wallet the tokens are moved from.
+    pub owner: String,
+    /// Contract allowed to move the tokens.
+    pub spender: String,
+    /// Amount the settlement moves, in base units.
+    pub required: u128,
+    /// Token balance of the owner, in base units.
+    pub balance: u128,
+    /// Allowance of the spender before any approval, in base units.
+    pub allowance: u128,
+    /// Approval transaction sent by the preflight, if any.
+    pub approval_tx: Option<TxHash>,
+}
+
+/// Checks that a settlement can pull its tokens before it is broadcast.
+#[derive(Debug, Clone)]
+pub struct SettlementPreflight {
+    client: Arc<dyn BlockchainClient>,
+    config: PreflightConfig,
+}
+
+impl SettlementPreflight {
+    /// Creates a preflight that reads through `client`.
+    #[must_use]
+    pub fn new(client: Arc<dyn BlockchainClient>, config: PreflightConfig) -> Self {
+        Self { client, config }
+    }
+
+    /// Checks that `from` can settle `amount` of `token` through `to`.
+    ///
+    /// `to` is the settlement contract, which needs an allowance from
+    /// `from`. Approvals are sent from the client's wallet, so with approval
+    /// enabled `from` must be that wallet.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BlockchainError::UnsupportedChain` if the client is on
+    /// another chain, `BlockchainError::InsufficientBalance` if `from` holds
+    /// less than `amount`, `BlockchainError::InsufficientAllowance` if the
+    /// allowance is short and approval is disabled, and the client's errors
+    /// if a call or the approval fails.
+    pub async fn preflight_settlement(
+        &self,
+        chain: ChainId,
+        token: &str,
+        from: &str,
+        to: &str,
+        amount: u128,
+    ) -> BlockchainResult<PreflightReport> {
+        if self.client.chain_id() != chain {
+            return Err(BlockchainError::unsupported_chain(format!(
+                "preflight for {} on a {} client",
+                chain,
+                self.client.chain_id()
+            )));
+        }
+        let owner = parse_address(from)?;
+        let spender = parse_address(to)?;
+
+        let balance = self
+            .read_uint(token, &encode_call(BALANCE_OF, &[Token::Address(owner)]))
+            .await?;
+        if balance < amount {
+            return Err(BlockchainError::insufficient_balance(
+                token, amount, balance,
+            ));
+        }
+
+        let allowance = self
+            .read_uint(
+                token,
+                &encode_call(ALLOWANCE, &[Token::Address(owner), Token::Address(spender)]),
+            )
+            .await?;
+        let approval_tx = if allowance < amount {
+            let approved = match self.config.approval {
+                ApprovalMode::Disabled => {
+                    return Err(BlockchainError::insufficient_allowance(
+                        token, amount, allowance,
+                    ));
+                }
+                ApprovalMode::Exact => U256::from(amount),
+                ApprovalMode::Max => U256::MAX,
+            };
+            Some(self.approve(token, spender, approved).await?)
+        } else {
+            None
+        };
+
+        Ok(PreflightReport {
+            chain,
+            token: token.to_string(),
+            owner: from.to_string(),
+            spender: to.to_string(),
+            required: amount,
+            balance,
+            allowance,
+            approval_tx,
+        })
+    }
+
+    /// Calls a view function returning a `uint256`, saturated to `u128`.
+    async fn read_uint(&self, token: &str, data: &[u8]) -> BlockchainResult<u128> {
+        let output = self.client.call(token, data).await?;
+        let word = output.get(..32).ok_or_else(|| {
+            BlockchainError::internal(format!(
+                "short return data from {}: {} bytes",
+                token,
+                output.len()
+            ))
+        })?;
+        let value = U256::from_big_endian(word);
+        Ok(if value > U256::from(u128::MAX) {
+            u128::MAX
+        } else {
+            value.as_u128()
+        })
+    }
+
+    /// Sends `approve(spender, amount)` and waits for it to confirm.
+    async fn approve(
+        &self,
+        token: &str,
+        spender: Address,
+        amount: U256,
+    ) -> BlockchainResult<TxHash> {
+        let data = encode_call(APPROVE, &[Token::Address(spender), Token::Uint(amount)]);
+        let gas_limit = self.client.estimate_gas(token, &data, 0).await?;
+        let gas_price = self.client.get_gas_price(self.config.priority).await?;
+        let tx_hash = self
+            .client
+            .send_transaction(token, &data, 0, gas_limit, gas_price)
+            .await?;
+        let receipt = self
+            .client
+            .wait_for_confirmation(&tx_hash, self.config.confirmations)
+            .await?;
+        if !receipt.success {
+            return Err(BlockchainError::reverted(format!(
+                "approval {} of {}",
+                tx_hash, token
+            )));
+        }
+        tracing::info!(token, tx_hash = %tx_hash, "Settlement allowance approved");
+        Ok(tx_hash)
+    }
+}
+
+fn parse_address(address: &str) -> BlockchainResult<Address> {
+    address
+        .parse()
+        .map_err(|_| BlockchainError::internal(format!("invalid address: {}", address)))
+}
+
+fn encode_call(selector: [u8; 4], args: &[Token]) -> Vec<u8> {
+    let mut data = selector.to_vec();
+    data.extend(abi::encode(args));
+    data
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::blockchain::{GasPrice, TxReceipt};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    const TOKEN: &str = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+    const OWNER: &str = "0x1111111111111111111111111111111111111111";
+    const SPENDER: &str = "0x2222222222222222222222222222222222222222";
+
+    /// A single ERC-20 token whose `approve` sets the allowance.
+    #[derive(Debug)]
+    struct MockToken {
+        balance: u128,
+        allowance: Mutex<U256>,
+        sent: Mutex<Vec<Vec<u8>>>,
+    }
+
+    impl MockToken {
+        fn new(balance: u128, allowance: u128) -> Self {
+            Self {
+                balance,
+                allowance: Mutex::new(U256::from(allowance)),
+                sent: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn approved(&self) -> Option<U256> {
+            let sent = self.sent.lock().unwrap();
+            let data = sent.last()?;
+            Some(U256::from_big_endian(data.get(36..68)?))
+        }
+    }
+
+    #[async_trait]
+    impl BlockchainClient for MockToken {
+        fn chain_id(&self) -> ChainId {
+            ChainId::Ethereum
+        }
+
+        async fn get_block_number(&self) -> BlockchainResult<u64> {
+            Ok(100)
+        }
+
+        async fn get_balance(&self, _address: &str) -> BlockchainResult<u128> {
+            Ok(0)
+        }
+
+        async fn estimate_gas(
+            &self,
+            _to: &str,
+            _data: &[u8],
+            _value: u128,
+        ) -> BlockchainResult<u64> {
+            Ok(50_000)
+        }
+
+        async fn call(&self, _to: &str, data: &[u8]) -> BlockchainResult<Vec<u8>> {
+            let value = match data.get(..4) {
+                Some(selector) if selector == BALANCE_OF => U256::from(self.balance),
+                Some(selector) if selector == ALLOWANCE => *self.allowance.lock().unwrap(),
+                _ => return Err(BlockchainError::reverted("unknown selector")),
+            };
+            Ok(abi::encode(&[Token::Uint(value)]))
+        }
+
+        async fn get_gas_price(&self, _priority: TxPriority) -> BlockchainResult<GasPrice> {
+            Ok(GasPrice::legacy(20_000_000_000))
+        }
+
+        async fn send_transaction(
+            &self,
+            _to: &str,
+            data: &[u8],
+            _value: u128,
+            _gas_limit: u64,
+            _gas_price: GasPrice,
+        ) -> BlockchainResult<TxHash> {
+            let amount = U256::from_big_endian(data.get(36..68).unwrap());
+            *self.allowance.lock().unwrap() = amount;
+            self.sent.lock().unwrap().push(data.to_vec());
+            Ok(TxHash::new("0xapprove"))
+        }
+
+        async fn wait_for_confirmation(
+            &self,
+            tx_hash: &TxHash,
+            _confirmations: u64,
+        ) -> BlockchainResult<TxReceipt> {
+            Ok(TxReceipt {
+                tx_hash: tx_hash.clone(),
+                block_number: 101,
+                gas_used: 46_000,
+                effective_gas_price: 20_000_000_000,
+                success: true,
+            })
+        }
+
+        async fn get_transaction_receipt(
+            &self,
+            _tx_hash: &TxHash,
+        ) -> BlockchainResult<Option<TxReceipt>> {
+            Ok(None)
+        }
+
+        async fn is_transaction_pending(&self, _tx_hash: &TxHash) -> BlockchainResult<bool> {
+            Ok(false)
+        }
+
+        async fn get_nonce(&self, _address: &str) -> BlockchainResult<u64> {
+            Ok(0)
+        }
+
+        async fn health_check(&self) -> BlockchainResult<()> {
+            Ok(())
+        }
+    }
+
+    fn preflight(token: &Arc<MockToken>, approval: ApprovalMode) -> SettlementPreflight {
+        SettlementPreflight::new(
+            Arc::clone(token) as Arc<dyn BlockchainClient>,
+            PreflightConfig {
+                approval,
+                ..PreflightConfig::default()
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn passes_when_balance_and_allowance_suffice() {
+        let token = Arc::new(MockToken::new(1_000, 500));
+
+        let report = preflight(&token, ApprovalMode::Disabled)
+            .preflight_settlement(ChainId::Ethereum, TOKEN, OWNER, SPENDER, 500)
+            .await
+            .unwrap();
+
+        assert_eq!(report.balance, 1_000);
+        assert_eq!(report.allowance, 500);
+        assert_eq!(report.required, 500);
+        assert!(report.approval_tx.is_none());
+        assert!(token.sent.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn fails_on_insufficient_balance() {
+        let token = Arc::new(MockToken::new(100, u128::MAX));
+
+        let result = preflight(&token, ApprovalMode::Max)
+            .preflight_settlement(ChainId::Ethereum, TOKEN, OWNER, SPENDER, 500)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(BlockchainError::InsufficientBalance {
+                required: 500,
+                available: 100,
+                ..
+            })
+        ));
+        assert!(token.sent.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn insufficient_allowance_fails_unless_approval_is_enabled() {
+        let token = Arc::new(MockToken::new(1_000, 0));
+
+        let result = preflight(&token, ApprovalMode::Disabled)
+            .preflight_settlement(ChainId::Ethereum, TOKEN, OWNER, SPENDER, 500)
+            .await;
+        assert!(matches!(
+            result,
+            Err(BlockchainError::InsufficientAllowance { available: 0, .. })
+        ));
+
+        let report = preflight(&token, ApprovalMode::Exact)
+            .preflight_settlement(ChainId::Ethereum, TOKEN, OWNER, SPENDER, 500)
+            .await
+            .unwrap();
+        assert_eq!(report.allowance, 0);
+        assert_eq!(report.approval_tx, Some(TxHash::new("0xapprove")));
+        assert_eq!(token.approved(), Some(U256::from(500)));
+    }
+
+    #[tokio::test]
+    async fn max_approval_grants_the_full_range() {
+        let token = Arc::new(MockToken::new(1_000, 100));
+
+        let report = preflight(&token, ApprovalMode::Max)
+            .preflight_settlement(ChainId::Ethereum, TOKEN, OWNER, SPENDER, 500)
+            .await
+            .unwrap();
+        assert!(report.approval_tx.is_some());
+        assert_eq!(token.approved(), Some(U256::MAX));
+
+        // The saturated allowance covers the next settlement.
+        let next = preflight(&token, ApprovalMode::Max)
+            .preflight_settlement(ChainId::Ethereum, TOKEN, OWNER, SPENDER, 500)
+            .await
+            .unwrap();
+        assert_eq!(next.allowance, u128::MAX);
+        assert!(next.approval_tx.is_none());
+        assert_eq!(token.sent.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_a_client_on_another_chain() {
+        let token = Arc::new(MockToken::new(1_000, 1_000));
+
+        let result = preflight(&token, ApprovalMode::Disabled)
+            .preflight_settlement(ChainId::Polygon, TOKEN, OWNER, SPENDER, 500)
+            .await;
+
+        assert!(matches!(result, Err(BlockchainError::UnsupportedChain(_))));
+    }
+}
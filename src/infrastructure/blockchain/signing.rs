@@ -0,0 +1,57 @@
+//! # Message Signatures
+//!
+//! Recovery of the signer of an EIP-191 `personal_sign` message.
+//!
+//! Wallets sign human-readable challenges this way; recovering the signer
+//! proves control of an address without any on-chain transaction.
+
+use super::client::{BlockchainError, BlockchainResult};
+use ethers::types::Signature;
+use std::str::FromStr;
+
+/// Returns the address that signed `message` with `personal_sign`.
+///
+/// `signature` is the 65-byte hex signature, with or without `0x`. The
+/// address is returned lowercase with a `0x` prefix, matching
+/// [`normalize_address`](super::tokens::normalize_address).
+///
+/// # Errors
+///
+/// Returns `BlockchainError::Internal` if the signature is malformed or
+/// does not recover to an address.
+pub fn recover_signer(message: &str, signature: &str) -> BlockchainResult<String> {
+    let signature = Signature::from_str(signature)
+        .map_err(|e| BlockchainError::internal(format!("invalid signature: {e}")))?;
+    let signer = signature
+        .recover(message)
+        .map_err(|e| BlockchainError::internal(format!("cannot recover signer: {e}")))?;
+    Ok(format!("{signer:#x}"))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use ethers::signers::{LocalWallet, Signer};
+
+    const TEST_KEY: &str = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    #[tokio::test]
+    async fn recovers_the_signing_address() {
+        let wallet = LocalWallet::from_str(TEST_KEY).unwrap();
+        let signature = wallet.sign_message("prove it").await.unwrap();
+
+        let signer = recover_signer("prove it", &signature.to_string()).unwrap();
+
+        assert_eq!(signer, format!("{:#x}", wallet.address()));
+        assert_ne!(
+            recover_signer("something else", &signature.to_string()).unwrap(),
+            signer
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_signatures() {
+        assert!(recover_signer("prove it", "0xdeadbeef").is_err());
+    }
+}
@@ -4,10 +4,20 @@
 //!
 //! Provides a registry for looking up token addresses by symbol and chain,
 //! supporting multi-chain deployments of the same token.
+//!
+//! Each token carries its decimals, used to scale amounts to and from the
+//! integer base units contracts work in, and an allow-list flag: only tokens
+//! enabled for settlement are resolved by [`TokenRegistry::settlement_token`].
+//! An address may belong to only one token per chain; [`TokenRegistry::try_register`]
+//! and [`TokenRegistry::validate`] reject duplicates.
 
 use super::client::ChainId;
+use parking_lot::RwLock;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use thiserror::Error;
 
 /// Token registry error.
@@ -24,6 +34,25 @@ pub enum TokenError {
     /// Invalid token address format.
     #[error("invalid address format: {0}")]
     InvalidAddress(String),
+
+    /// Address already registered to another token on the chain.
+    #[error("address {address} on {chain} already belongs to '{existing}'")]
+    DuplicateAddress {
+        /// The duplicated address.
+        address: String,
+        /// Chain the address is on.
+        chain: ChainId,
+        /// Symbol of the token that already has the address.
+        existing: String,
+    },
+
+    /// Token is registered but not enabled for settlement.
+    #[error("token '{0}' is not enabled for settlement")]
+    SettlementDisabled(String),
+
+    /// Amount cannot be expressed in the token's base units.
+    #[error("invalid amount: {0}")]
+    InvalidAmount(String),
 }
 
 /// Result type for token operations.
@@ -41,6 +70,13 @@ pub struct TokenInfo {
     /// Token addresses per chain.
     #[serde(default)]
     pub addresses: HashMap<ChainId, String>,
+    /// Whether trades may settle in this token.
+    #[serde(default = "default_settlement_enabled")]
+    pub enabled_for_settlement: bool,
+}
+
+fn default_settlement_enabled() -> bool {
+    true
 }
 
 impl TokenInfo {
@@ -52,6 +88,7 @@ impl TokenInfo {
             name: name.into(),
             decimals,
             addresses: HashMap::new(),
+            enabled_for_settlement: true,
         }
     }
 
@@ -62,6 +99,13 @@ impl TokenInfo {
         self
     }
 
+    /// Sets whether trades may settle in this token.
+    #[must_use]
+    pub fn with_settlement_enabled(mut self, enabled: bool) -> Self {
+        self.enabled_for_settlement = enabled;
+        self
+    }
+
     /// Gets the address for a specific chain.
     #[must_use]
     pub fn address(&self, chain: ChainId) -> Option<&str> {
@@ -79,6 +123,54 @@ impl TokenInfo {
     pub fn is_available_on(&self, chain: ChainId) -> bool {
         self.addresses.contains_key(&chain)
     }
+
+    /// Returns the token as deployed on `chain`, if it is.
+    #[must_use]
+    pub fn entry(&self, chain: ChainId) -> Option<TokenEntry> {
+        self.address(chain).map(|address| TokenEntry {
+            chain,
+            symbol: self.symbol.clone(),
+            address: address.to_string(),
+            decimals: self.decimals,
+            enabled_for_settlement: self.enabled_for_settlement,
+        })
+    }
+}
+
+/// A token as deployed on one chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenEntry {
+    /// Chain the token is deployed on.
+    pub chain: ChainId,
+    /// Token symbol.
+    pub symbol: String,
+    /// Contract address on the chain.
+    pub address: String,
+    /// Token decimals.
+    pub decimals: u8,
+    /// Whether trades may settle in this token.
+    pub enabled_for_settlement: bool,
+}
+
+impl TokenEntry {
+    /// Converts a decimal amount of this token to base units.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenError::InvalidAmount` if the amount is negative, finer
+    /// than the token's decimals, or too large.
+    pub fn to_base_units(&self, amount: Decimal) -> TokenResult<u128> {
+        to_base_units(amount, self.decimals)
+    }
+
+    /// Converts base units of this token to a decimal amount.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenError::InvalidAmount` if the amount is too large.
+    pub fn from_base_units(&self, units: u128) -> TokenResult<Decimal> {
+        from_base_units(units, self.decimals)
+    }
 }
 
 /// Registry for token address mappings across chains.
@@ -171,7 +263,7 @@ impl TokenRegistry {
             TokenInfo::new("DAI", "Dai Stablecoin", 18)
                 .with_address(
                     ChainId::Ethereum,
-                    "0x6B175474E89094C44Da98b954EeddeBC35e4D1",
+                    "0x6B175474E89094C44Da98b954EedeAC495271d0F",
                 )
                 .with_address(
                     ChainId::Arbitrum,
@@ -217,6 +309,87 @@ impl TokenRegistry {
         self.tokens.insert(token.symbol.clone(), token);
     }
 
+    /// Registers a token after checking its addresses, replacing any token
+    /// with the same symbol.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenError::InvalidAddress` if an address is malformed and
+    /// `TokenError::DuplicateAddress` if another token has the same address
+    /// on the same chain.
+    pub fn try_register(&mut self, token: TokenInfo) -> TokenResult<()> {
+        for (chain, address) in &token.addresses {
+            let address = normalize_address(address)?;
+            if let Some(existing) = self
+                .tokens
+                .values()
+                .filter(|t| t.symbol != token.symbol)
+                .find(|t| {
+                    t.address(*chain)
+                        .is_some_and(|a| a.eq_ignore_ascii_case(&address))
+                })
+            {
+                return Err(TokenError::DuplicateAddress {
+                    address,
+                    chain: *chain,
+                    existing: existing.symbol.clone(),
+                });
+            }
+        }
+        self.register(token);
+        Ok(())
+    }
+
+    /// Checks every address is well formed and belongs to one token per
+    /// chain, e.g. after loading the registry from configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first `TokenError::InvalidAddress` or
+    /// `TokenError::DuplicateAddress` found, in symbol order.
+    pub fn validate(&self) -> TokenResult<()> {
+        let mut symbols: Vec<&String> = self.tokens.keys().collect();
+        symbols.sort();
+        let mut seen: HashMap<(ChainId, String), &str> = HashMap::new();
+        for symbol in symbols {
+            let Some(token) = self.tokens.get(symbol) else {
+                continue;
+            };
+            let mut chains: Vec<_> = token.addresses.iter().collect();
+            chains.sort_by_key(|(chain, _)| chain.as_u64());
+            for (chain, address) in chains {
+                let address = normalize_address(address)?;
+                if let Some(existing) = seen.insert((*chain, address.clone()), symbol) {
+                    return Err(TokenError::DuplicateAddress {
+                        address,
+                        chain: *chain,
+                        existing: existing.to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes a token, returning it if it was registered.
+    pub fn remove(&mut self, symbol: &str) -> Option<TokenInfo> {
+        self.tokens.remove(symbol)
+    }
+
+    /// Enables or disables settlement in a token.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenError::TokenNotFound` if the token is not registered.
+    pub fn set_settlement_enabled(&mut self, symbol: &str, enabled: bool) -> TokenResult<()> {
+        let token = self
+            .tokens
+            .get_mut(symbol)
+            .ok_or_else(|| TokenError::TokenNotFound(symbol.to_string()))?;
+        token.enabled_for_settlement = enabled;
+        Ok(())
+    }
+
     /// Gets token info by symbol.
     #[must_use]
     pub fn get(&self, symbol: &str) -> Option<&TokenInfo> {
@@ -239,6 +412,66 @@ impl TokenRegistry {
             .ok_or_else(|| TokenError::NotOnChain(symbol.to_string(), chain))
     }
 
+    /// Gets a token as deployed on a specific chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token is not found or not available on the chain.
+    pub fn entry(&self, symbol: &str, chain: ChainId) -> TokenResult<TokenEntry> {
+        let token = self
+            .tokens
+            .get(symbol)
+            .ok_or_else(|| TokenError::TokenNotFound(symbol.to_string()))?;
+
+        token
+            .entry(chain)
+            .ok_or_else(|| TokenError::NotOnChain(symbol.to_string(), chain))
+    }
+
+    /// Gets a token that trades may settle in on a specific chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token is not found, not available on the
+    /// chain, or not enabled for settlement.
+    pub fn settlement_token(&self, symbol: &str, chain: ChainId) -> TokenResult<TokenEntry> {
+        let entry = self.entry(symbol, chain)?;
+        if !entry.enabled_for_settlement {
+            return Err(TokenError::SettlementDisabled(entry.symbol));
+        }
+        Ok(entry)
+    }
+
+    /// Gets a token by its address on a specific chain.
+    #[must_use]
+    pub fn entry_by_address(&self, address: &str, chain: ChainId) -> Option<TokenEntry> {
+        self.get_by_address(address, chain)
+            .and_then(|token| token.entry(chain))
+    }
+
+    /// Returns every token deployment, optionally on one chain only,
+    /// ordered by symbol and chain.
+    #[must_use]
+    pub fn entries(&self, chain: Option<ChainId>) -> Vec<TokenEntry> {
+        let mut entries: Vec<TokenEntry> = self
+            .tokens
+            .values()
+            .flat_map(|token| {
+                token
+                    .available_chains()
+                    .into_iter()
+                    .filter(|c| chain.is_none_or(|chain| chain == *c))
+                    .filter_map(|c| token.entry(c))
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            a.symbol
+                .cmp(&b.symbol)
+                .then(a.chain.as_u64().cmp(&b.chain.as_u64()))
+        });
+        entries
+    }
+
     /// Gets token info by address on a specific chain.
     #[must_use]
     pub fn get_by_address(&self, address: &str, chain: ChainId) -> Option<&TokenInfo> {
@@ -278,6 +511,52 @@ impl TokenRegistry {
     }
 }
 
+/// Token registry shared between the adapters, settlement and the admin API.
+pub type SharedTokenRegistry = Arc<RwLock<TokenRegistry>>;
+
+/// Returns `10^decimals` as a decimal.
+fn scale(decimals: u8) -> TokenResult<Decimal> {
+    (0..decimals)
+        .try_fold(Decimal::ONE, |acc, _| acc.checked_mul(Decimal::TEN))
+        .ok_or_else(|| TokenError::InvalidAmount(format!("{decimals} decimals is out of range")))
+}
+
+/// Converts a decimal token amount to integer base units.
+///
+/// # Errors
+///
+/// Returns `TokenError::InvalidAmount` if the amount is negative, has more
+/// fractional digits than `decimals`, or does not fit in a `u128`.
+pub fn to_base_units(amount: Decimal, decimals: u8) -> TokenResult<u128> {
+    if amount.is_sign_negative() && !amount.is_zero() {
+        return Err(TokenError::InvalidAmount(format!("{amount} is negative")));
+    }
+    let units = amount
+        .checked_mul(scale(decimals)?)
+        .ok_or_else(|| TokenError::InvalidAmount(format!("{amount} is too large")))?;
+    if !units.fract().is_zero() {
+        return Err(TokenError::InvalidAmount(format!(
+            "{amount} has more than {decimals} decimals"
+        )));
+    }
+    units
+        .to_u128()
+        .ok_or_else(|| TokenError::InvalidAmount(format!("{amount} is too large")))
+}
+
+/// Converts integer base units to a decimal token amount.
+///
+/// # Errors
+///
+/// Returns `TokenError::InvalidAmount` if the amount cannot be represented.
+pub fn from_base_units(units: u128, decimals: u8) -> TokenResult<Decimal> {
+    let too_large = || TokenError::InvalidAmount(format!("{units} base units is too large"));
+    let units = i128::try_from(units).map_err(|_| too_large())?;
+    Decimal::try_from_i128_with_scale(units, u32::from(decimals))
+        .map(|amount| amount.normalize())
+        .map_err(|_| too_large())
+}
+
 /// Validates an Ethereum address format.
 ///
 /// # Arguments
@@ -473,6 +752,112 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn common_tokens_are_valid() {
+        assert!(TokenRegistry::with_common_tokens().validate().is_ok());
+    }
+
+    #[test]
+    fn scales_6_8_and_18_decimal_tokens() {
+        let registry = TokenRegistry::with_common_tokens();
+        let usdc = registry.entry("USDC", ChainId::Ethereum).unwrap();
+        let wbtc = registry.entry("WBTC", ChainId::Ethereum).unwrap();
+        let weth = registry.entry("WETH", ChainId::Ethereum).unwrap();
+
+        assert_eq!(usdc.to_base_units(Decimal::new(15, 1)).unwrap(), 1_500_000);
+        assert_eq!(wbtc.to_base_units(Decimal::new(1, 8)).unwrap(), 1);
+        assert_eq!(
+            weth.to_base_units(Decimal::new(25, 1)).unwrap(),
+            2_500_000_000_000_000_000
+        );
+        assert_eq!(
+            weth.from_base_units(2_500_000_000_000_000_000).unwrap(),
+            Decimal::new(25, 1)
+        );
+        assert_eq!(usdc.from_base_units(1).unwrap(), Decimal::new(1, 6));
+
+        assert!(matches!(
+            usdc.to_base_units(Decimal::new(1, 7)),
+            Err(TokenError::InvalidAmount(_))
+        ));
+        assert!(matches!(
+            usdc.to_base_units(Decimal::NEGATIVE_ONE),
+            Err(TokenError::InvalidAmount(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_duplicate_addresses_on_a_chain() {
+        let mut registry = TokenRegistry::with_common_tokens();
+        let weth = "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2";
+
+        let result = registry.try_register(
+            TokenInfo::new("ETHX", "Impostor", 18).with_address(ChainId::Ethereum, weth),
+        );
+        assert!(matches!(
+            result,
+            Err(TokenError::DuplicateAddress { ref existing, .. }) if existing == "WETH"
+        ));
+        assert!(registry.get("ETHX").is_none());
+
+        // The same address on another chain, or re-registering the owner, is fine.
+        registry
+            .try_register(TokenInfo::new("ETHX", "Other", 18).with_address(ChainId::Polygon, weth))
+            .unwrap();
+        registry
+            .try_register(
+                TokenInfo::new("WETH", "Wrapped Ether", 18)
+                    .with_address(ChainId::Ethereum, weth.to_lowercase()),
+            )
+            .unwrap();
+
+        // Entries loaded without checks are caught by validate.
+        registry
+            .register(TokenInfo::new("DUP", "Duplicate", 6).with_address(ChainId::Polygon, weth));
+        assert!(matches!(
+            registry.validate(),
+            Err(TokenError::DuplicateAddress { .. })
+        ));
+    }
+
+    #[test]
+    fn settlement_token_rejects_disabled_tokens() {
+        let mut registry = TokenRegistry::with_common_tokens();
+        assert!(registry.settlement_token("USDT", ChainId::Ethereum).is_ok());
+
+        registry.set_settlement_enabled("USDT", false).unwrap();
+
+        assert!(matches!(
+            registry.settlement_token("USDT", ChainId::Ethereum),
+            Err(TokenError::SettlementDisabled(_))
+        ));
+        // Lookups other than settlement still see the token.
+        assert_eq!(
+            registry
+                .entry_by_address(
+                    "0xdac17f958d2ee523a2206206994597c13d831ec7",
+                    ChainId::Ethereum
+                )
+                .unwrap()
+                .decimals,
+            6
+        );
+        assert!(matches!(
+            registry.set_settlement_enabled("UNKNOWN", false),
+            Err(TokenError::TokenNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn entries_filter_by_chain() {
+        let registry = TokenRegistry::with_common_tokens();
+
+        let base = registry.entries(Some(ChainId::Base));
+        assert!(base.iter().all(|e| e.chain == ChainId::Base));
+        assert!(!base.iter().any(|e| e.symbol == "USDT"));
+        assert!(registry.entries(None).len() > base.len());
+    }
+
     #[test]
     fn token_error_display() {
         let err = TokenError::TokenNotFound("UNKNOWN".to_string());
@@ -1,3 +1,9 @@
 //! # HTTP Clients
 //!
 //! HTTP clients for external API integrations.
+//!
+//! - [`order_book`]: Top-of-book snapshots from exchange depth endpoints
+
+pub mod order_book;
+
+pub use order_book::{HttpOrderBookConfig, HttpOrderBookSnapshotAdapter};
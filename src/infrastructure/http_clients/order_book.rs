@@ -0,0 +1,229 @@
+//! # Order Book Snapshot Client
+//!
+//! Polls an exchange depth endpoint for top-of-book snapshots.
+//!
+//! [`HttpOrderBookSnapshotAdapter`] implements [`OrderBookSnapshotPort`]
+//! by requesting `GET {endpoint}?symbol={BASE}{QUOTE}&limit=1` on each
+//! lookup. The endpoint must answer with the common depth format:
+//!
+//! ```text
+//! { "bids": [["99.5", "1.2"]], "asks": [["100.5", "0.8"]] }
+//! ```
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use otc_rfq::infrastructure::http_clients::order_book::{
+//!     HttpOrderBookConfig, HttpOrderBookSnapshotAdapter,
+//! };
+//!
+//! let config = HttpOrderBookConfig::new("https://api.exchange.com/api/v3/depth");
+//! let adapter = HttpOrderBookSnapshotAdapter::new(config)?;
+//! ```
+
+use crate::application::services::clob_mid::{OrderBookSnapshot, OrderBookSnapshotPort};
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::value_objects::instrument::Instrument;
+use crate::domain::value_objects::price::Price;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::infrastructure::venues::error::VenueResult;
+use crate::infrastructure::venues::http_client::HttpClient;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+/// Default request timeout in milliseconds.
+pub const DEFAULT_TIMEOUT_MS: u64 = 2_000;
+
+/// Configuration for [`HttpOrderBookSnapshotAdapter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpOrderBookConfig {
+    /// Depth endpoint URL.
+    endpoint: String,
+    /// Request timeout in milliseconds.
+    timeout_ms: u64,
+}
+
+impl HttpOrderBookConfig {
+    /// Creates a configuration for the given depth endpoint.
+    #[must_use]
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            timeout_ms: DEFAULT_TIMEOUT_MS,
+        }
+    }
+
+    /// Sets the request timeout.
+    #[must_use]
+    pub fn with_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Returns the depth endpoint URL.
+    #[inline]
+    #[must_use]
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Returns the request timeout in milliseconds.
+    #[inline]
+    #[must_use]
+    pub fn timeout_ms(&self) -> u64 {
+        self.timeout_ms
+    }
+}
+
+/// Order book levels as `[price, quantity]` pairs, best first.
+#[derive(Debug, Deserialize)]
+struct DepthResponse {
+    #[serde(default)]
+    bids: Vec<(Decimal, Decimal)>,
+    #[serde(default)]
+    asks: Vec<(Decimal, Decimal)>,
+}
+
+/// HTTP adapter fetching top-of-book snapshots from an exchange.
+#[derive(Debug, Clone)]
+pub struct HttpOrderBookSnapshotAdapter {
+    config: HttpOrderBookConfig,
+    client: HttpClient,
+}
+
+impl HttpOrderBookSnapshotAdapter {
+    /// Creates a new adapter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created.
+    pub fn new(config: HttpOrderBookConfig) -> VenueResult<Self> {
+        let client = HttpClient::new(config.timeout_ms())?;
+        Ok(Self { config, client })
+    }
+
+    /// Returns the exchange symbol for an instrument, e.g. `BTCUSD`.
+    fn exchange_symbol(instrument: &Instrument) -> String {
+        format!("{}{}", instrument.base_asset(), instrument.quote_asset())
+    }
+}
+
+/// Returns the price of the best level, if any.
+fn best_price(levels: &[(Decimal, Decimal)]) -> DomainResult<Option<Price>> {
+    levels
+        .first()
+        .map(|(price, _)| Price::from_decimal(*price))
+        .transpose()
+        .map_err(DomainError::from)
+}
+
+#[async_trait]
+impl OrderBookSnapshotPort for HttpOrderBookSnapshotAdapter {
+    async fn snapshot(&self, instrument: &Instrument) -> DomainResult<Option<OrderBookSnapshot>> {
+        let symbol = Self::exchange_symbol(instrument);
+        let depth: DepthResponse = self
+            .client
+            .get_with_params(
+                self.config.endpoint(),
+                &[("symbol", symbol.as_str()), ("limit", "1")],
+            )
+            .await
+            .map_err(|e| {
+                DomainError::PriceBoundsVerificationFailed(format!(
+                    "order book snapshot for {} failed: {}",
+                    symbol, e
+                ))
+            })?;
+
+        Ok(Some(OrderBookSnapshot::new(
+            best_price(&depth.bids)?,
+            best_price(&depth.asks)?,
+            Timestamp::now(),
+        )))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
+    use crate::domain::value_objects::symbol::Symbol;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn instrument() -> Instrument {
+        Instrument::new(
+            Symbol::new("BTC/USD").unwrap(),
+            AssetClass::CryptoSpot,
+            SettlementMethod::default(),
+        )
+    }
+
+    fn adapter_for(server: &MockServer) -> HttpOrderBookSnapshotAdapter {
+        let config = HttpOrderBookConfig::new(format!("{}/depth", server.uri()));
+        HttpOrderBookSnapshotAdapter::new(config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn parses_top_of_book() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/depth"))
+            .and(query_param("symbol", "BTCUSD"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "bids": [["99.5", "1.2"], ["99.0", "3"]],
+                "asks": [["100.5", "0.8"]],
+            })))
+            .mount(&server)
+            .await;
+
+        let snapshot = adapter_for(&server)
+            .snapshot(&instrument())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(snapshot.best_bid(), Some(Price::new(99.5).unwrap()));
+        assert_eq!(snapshot.best_ask(), Some(Price::new(100.5).unwrap()));
+    }
+
+    #[tokio::test]
+    async fn empty_side_has_no_price() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/depth"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "bids": [["99.5", "1"]], "asks": [] })),
+            )
+            .mount(&server)
+            .await;
+
+        let snapshot = adapter_for(&server)
+            .snapshot(&instrument())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(snapshot.best_ask().is_none());
+    }
+
+    #[tokio::test]
+    async fn http_error_is_reported() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/depth"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let result = adapter_for(&server).snapshot(&instrument()).await;
+
+        assert!(matches!(
+            result,
+            Err(DomainError::PriceBoundsVerificationFailed(_))
+        ));
+    }
+}
@@ -0,0 +1,93 @@
+//! # Redis Lock Configuration
+//!
+//! Connection settings for the Redis-backed lock manager.
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Configuration for connecting the lock manager to Redis.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedisLockConfig {
+    /// Redis connection URL (e.g. `redis://localhost:6379/0`).
+    pub url: String,
+    /// Prefix for lock and fencing token keys.
+    pub key_prefix: String,
+    /// Timeout for establishing a connection.
+    pub connect_timeout: Duration,
+    /// Timeout for a single Redis command.
+    pub response_timeout: Duration,
+}
+
+impl Default for RedisLockConfig {
+    fn default() -> Self {
+        Self {
+            url: "redis://localhost:6379".to_string(),
+            key_prefix: "otc-rfq:lock".to_string(),
+            connect_timeout: Duration::from_secs(2),
+            response_timeout: Duration::from_millis(500),
+        }
+    }
+}
+
+impl RedisLockConfig {
+    /// Creates a configuration for the given Redis URL.
+    #[must_use]
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the key prefix.
+    #[must_use]
+    pub fn with_key_prefix(mut self, key_prefix: impl Into<String>) -> Self {
+        self.key_prefix = key_prefix.into();
+        self
+    }
+
+    /// Sets the connection timeout.
+    #[must_use]
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Sets the per-command response timeout.
+    #[must_use]
+    pub fn with_response_timeout(mut self, timeout: Duration) -> Self {
+        self.response_timeout = timeout;
+        self
+    }
+
+    /// Returns the Redis key holding the lock on `resource`.
+    #[must_use]
+    pub fn lock_key(&self, resource: &str) -> String {
+        format!("{}:{}", self.key_prefix, resource)
+    }
+
+    /// Returns the Redis key holding the fencing counter for `resource`.
+    #[must_use]
+    pub fn fence_key(&self, resource: &str) -> String {
+        format!("{}:fence:{}", self.key_prefix, resource)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_points_at_localhost() {
+        let config = RedisLockConfig::default();
+        assert!(config.url.contains("localhost"));
+        assert_eq!(config.key_prefix, "otc-rfq:lock");
+    }
+
+    #[test]
+    fn keys_are_namespaced_by_prefix() {
+        let config = RedisLockConfig::new("redis://cache:6379").with_key_prefix("test");
+        assert_eq!(config.lock_key("Rfq(1)"), "test:Rfq(1)");
+        assert_eq!(config.fence_key("Rfq(1)"), "test:fence:Rfq(1)");
+    }
+}
@@ -0,0 +1,17 @@
+//! # Distributed Locking
+//!
+//! Implementations of the
+//! [`LockManager`](crate::domain::services::LockManager) trait shared across
+//! service instances.
+//!
+//! - [`RedisLockManager`]: Redis locks with fencing tokens and TTL extension
+//! - [`RedisLockConfig`]: Redis connection settings
+//!
+//! The PostgreSQL advisory lock manager lives with the other PostgreSQL
+//! adapters in [`persistence::postgres`](crate::infrastructure::persistence::postgres).
+
+pub mod config;
+pub mod redis;
+
+pub use config::RedisLockConfig;
+pub use redis::{LockStore, RedisLockManager, RedisLockStore};
@@ -0,0 +1,685 @@
+//! # Redis Lock Manager
+//!
+//! [`LockManager`] backed by Redis, for deployments running several API
+//! instances.
+//!
+//! # Protocol
+//!
+//! Each lock is a Redis key set with `NX` and a `PX` TTL, holding
+//! `{holder_id}:{fencing_token}`. The fencing token comes from a per-resource
+//! counter incremented in the same script, so every successful acquisition
+//! of a resource gets a strictly larger token than the one before it.
+//!
+//! - **Extension**: while a [`LockGuard`] lives, a watchdog task extends the
+//!   TTL every third of the TTL, for as long as the key still holds the
+//!   guard's value.
+//! - **Release**: a script deletes the key only if it still holds the
+//!   guard's value. A lock that expired and was taken over by another holder
+//!   is never deleted; the release fails instead.
+//! - **Failures**: Redis errors fail acquisition with
+//!   `DomainError::LockAcquisitionFailed`. The manager never falls back to
+//!   running without a lock.
+
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::services::lock_manager::{LockGuard, LockInfo, LockManager, LockManagerConfig};
+use crate::domain::services::quote_lock::LockHolderId;
+use crate::domain::services::resource_lock::{ResourceLock, sort_locks};
+use crate::infrastructure::locking::config::RedisLockConfig;
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use redis::Script;
+use redis::aio::{ConnectionManager, ConnectionManagerConfig};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+/// Takes the lock if free and returns a new fencing token, or 0 if held.
+const ACQUIRE_SCRIPT: &str = r"
+if redis.call('EXISTS', KEYS[1]) == 1 then
+    return 0
+end
+local token = redis.call('INCR', KEYS[2])
+redis.call('SET', KEYS[1], ARGV[1] .. ':' .. token, 'PX', ARGV[2])
+return token
+";
+
+/// Resets the TTL if the lock still holds the expected value.
+const EXTEND_SCRIPT: &str = r"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('PEXPIRE', KEYS[1], ARGV[2])
+end
+return 0
+";
+
+/// Deletes the lock if it still holds the expected value.
+const RELEASE_SCRIPT: &str = r"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+";
+
+/// Storage commands behind [`RedisLockManager`].
+///
+/// Each method maps to one atomic Redis script, so the lock protocol can be
+/// exercised without a server.
+#[async_trait]
+pub trait LockStore: Send + Sync + fmt::Debug {
+    /// Sets `key` to `{holder}:{token}` with a TTL if it is unset.
+    ///
+    /// Returns the new fencing token, or `None` if the key is held.
+    async fn try_acquire(
+        &self,
+        key: &str,
+        fence_key: &str,
+        holder: &str,
+        ttl_ms: u64,
+    ) -> Result<Option<u64>, String>;
+
+    /// Resets the TTL of `key` if it still holds `value`.
+    async fn extend(&self, key: &str, value: &str, ttl_ms: u64) -> Result<bool, String>;
+
+    /// Deletes `key` if it still holds `value`.
+    async fn release(&self, key: &str, value: &str) -> Result<bool, String>;
+}
+
+/// [`LockStore`] running the lock scripts against Redis.
+#[derive(Clone)]
+pub struct RedisLockStore {
+    connection: ConnectionManager,
+    acquire: Arc<Script>,
+    extend: Arc<Script>,
+    release: Arc<Script>,
+}
+
+impl RedisLockStore {
+    /// Connects to Redis.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid or Redis cannot be reached
+    /// within the connection timeout.
+    pub async fn connect(config: &RedisLockConfig) -> Result<Self, String> {
+        let client = redis::Client::open(config.url.as_str()).map_err(|e| e.to_string())?;
+        let manager_config = ConnectionManagerConfig::new()
+            .set_connection_timeout(Some(config.connect_timeout))
+            .set_response_timeout(Some(config.response_timeout));
+        let connection = ConnectionManager::new_with_config(client, manager_config)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(Self {
+            connection,
+            acquire: Arc::new(Script::new(ACQUIRE_SCRIPT)),
+            extend: Arc::new(Script::new(EXTEND_SCRIPT)),
+            release: Arc::new(Script::new(RELEASE_SCRIPT)),
+        })
+    }
+}
+
+impl fmt::Debug for RedisLockStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedisLockStore").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl LockStore for RedisLockStore {
+    async fn try_acquire(
+        &self,
+        key: &str,
+        fence_key: &str,
+        holder: &str,
+        ttl_ms: u64,
+    ) -> Result<Option<u64>, String> {
+        let mut connection = self.connection.clone();
+        let token: u64 = self
+            .acquire
+            .key(key)
+            .key(fence_key)
+            .arg(holder)
+            .arg(ttl_ms)
+            .invoke_async(&mut connection)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok((token > 0).then_some(token))
+    }
+
+    async fn extend(&self, key: &str, value: &str, ttl_ms: u64) -> Result<bool, String> {
+        let mut connection = self.connection.clone();
+        let extended: i64 = self
+            .extend
+            .key(key)
+            .arg(value)
+            .arg(ttl_ms)
+            .invoke_async(&mut connection)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(extended == 1)
+    }
+
+    async fn release(&self, key: &str, value: &str) -> Result<bool, String> {
+        let mut connection = self.connection.clone();
+        let deleted: i64 = self
+            .release
+            .key(key)
+            .arg(value)
+            .invoke_async(&mut connection)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(deleted == 1)
+    }
+}
+
+/// A lock held in the store.
+#[derive(Debug, Clone)]
+struct HeldLock {
+    resource: ResourceLock,
+    key: String,
+    value: String,
+    token: u64,
+}
+
+/// The locks taken by one acquisition and the watchdog extending them.
+#[derive(Debug)]
+struct Acquisition {
+    locks: Vec<HeldLock>,
+    watchdog: JoinHandle<()>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    store: Arc<dyn LockStore>,
+    config: RedisLockConfig,
+    lock_config: LockManagerConfig,
+    held: Mutex<HashMap<LockHolderId, Acquisition>>,
+}
+
+/// Redis implementation of [`LockManager`].
+///
+/// Cheap to clone; clones share the held locks.
+#[derive(Debug, Clone)]
+pub struct RedisLockManager {
+    inner: Arc<Inner>,
+}
+
+impl RedisLockManager {
+    /// Connects to Redis and creates a lock manager.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::LockAcquisitionFailed` if Redis cannot be
+    /// reached.
+    pub async fn connect(
+        config: RedisLockConfig,
+        lock_config: LockManagerConfig,
+    ) -> DomainResult<Arc<Self>> {
+        let store = RedisLockStore::connect(&config)
+            .await
+            .map_err(|e| DomainError::LockAcquisitionFailed(format!("redis unavailable: {e}")))?;
+        Ok(Self::with_store(Arc::new(store), config, lock_config))
+    }
+
+    /// Creates a lock manager over an existing store.
+    #[must_use]
+    pub fn with_store(
+        store: Arc<dyn LockStore>,
+        config: RedisLockConfig,
+        lock_config: LockManagerConfig,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            inner: Arc::new(Inner {
+                store,
+                config,
+                lock_config,
+                held: Mutex::new(HashMap::new()),
+            }),
+        })
+    }
+
+    /// Returns the fencing token of a lock held by `holder_id`.
+    ///
+    /// Downstream writes can carry the token so a holder whose lock expired
+    /// is rejected in favour of the newer holder's larger token.
+    #[must_use]
+    pub fn fencing_token(&self, holder_id: LockHolderId, resource: &ResourceLock) -> Option<u64> {
+        let held = self.inner.held.lock();
+        held.get(&holder_id)?
+            .locks
+            .iter()
+            .find(|lock| &lock.resource == resource)
+            .map(|lock| lock.token)
+    }
+
+    fn ttl_ms(&self) -> u64 {
+        u64::try_from(self.inner.lock_config.lock_ttl.as_millis()).unwrap_or(u64::MAX)
+    }
+
+    /// Releases `locks` from the store, ignoring errors.
+    async fn release_quietly(&self, locks: &[HeldLock]) {
+        for lock in locks.iter().rev() {
+            let _ = self.inner.store.release(&lock.key, &lock.value).await;
+        }
+    }
+
+    /// Spawns the task extending `locks` while they are held.
+    fn spawn_watchdog(&self, locks: &[HeldLock]) -> JoinHandle<()> {
+        let store = Arc::clone(&self.inner.store);
+        let locks: Vec<(String, String)> = locks
+            .iter()
+            .map(|lock| (lock.key.clone(), lock.value.clone()))
+            .collect();
+        let ttl_ms = self.ttl_ms();
+        let interval = self.inner.lock_config.lock_ttl / 3;
+
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                for (key, value) in &locks {
+                    match store.extend(key, value, ttl_ms).await {
+                        Ok(true) => {}
+                        Ok(false) => {
+                            warn!(key = %key, "Lock expired before it could be extended");
+                            return;
+                        }
+                        Err(e) => warn!(key = %key, error = %e, "Failed to extend lock"),
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[async_trait]
+impl LockManager for RedisLockManager {
+    async fn acquire_all(
+        &self,
+        resources: Vec<ResourceLock>,
+        timeout: Duration,
+    ) -> DomainResult<LockGuard> {
+        let holder_id = LockHolderId::new();
+        let manager: Arc<dyn LockManager> = Arc::new(self.clone());
+
+        if resources.is_empty() {
+            return Ok(LockGuard::new(vec![], holder_id, manager));
+        }
+
+        let mut sorted_resources = resources;
+        sort_locks(&mut sorted_resources);
+
+        let deadline =
+            tokio::time::Instant::now() + self.inner.lock_config.effective_timeout(Some(timeout));
+        let holder = holder_id.to_string();
+        let ttl_ms = self.ttl_ms();
+        let mut acquired: Vec<HeldLock> = Vec::with_capacity(sorted_resources.len());
+
+        for resource in &sorted_resources {
+            let name = resource.to_string();
+            let key = self.inner.config.lock_key(&name);
+            let fence_key = self.inner.config.fence_key(&name);
+            loop {
+                match self
+                    .inner
+                    .store
+                    .try_acquire(&key, &fence_key, &holder, ttl_ms)
+                    .await
+                {
+                    Ok(Some(token)) => {
+                        acquired.push(HeldLock {
+                            resource: resource.clone(),
+                            key: key.clone(),
+                            value: format!("{holder}:{token}"),
+                            token,
+                        });
+                        break;
+                    }
+                    Ok(None) if tokio::time::Instant::now() < deadline => {
+                        tokio::time::sleep(self.inner.lock_config.retry_interval).await;
+                    }
+                    Ok(None) => {
+                        self.release_quietly(&acquired).await;
+                        return Err(DomainError::LockAcquisitionFailed(format!(
+                            "failed to acquire lock on {}: held by another holder",
+                            resource
+                        )));
+                    }
+                    Err(e) => {
+                        self.release_quietly(&acquired).await;
+                        return Err(DomainError::LockAcquisitionFailed(format!(
+                            "redis unavailable while locking {}: {}",
+                            resource, e
+                        )));
+                    }
+                }
+            }
+        }
+
+        let watchdog = self.spawn_watchdog(&acquired);
+        self.inner.held.lock().insert(
+            holder_id,
+            Acquisition {
+                locks: acquired,
+                watchdog,
+            },
+        );
+        Ok(LockGuard::new(sorted_resources, holder_id, manager))
+    }
+
+    async fn release_all(
+        &self,
+        locks: &[ResourceLock],
+        holder_id: LockHolderId,
+    ) -> DomainResult<()> {
+        let Some(acquisition) = self.inner.held.lock().remove(&holder_id) else {
+            return Ok(());
+        };
+        acquisition.watchdog.abort();
+
+        let (releasing, remaining): (Vec<HeldLock>, Vec<HeldLock>) = acquisition
+            .locks
+            .into_iter()
+            .partition(|lock| locks.contains(&lock.resource));
+        if !remaining.is_empty() {
+            let watchdog = self.spawn_watchdog(&remaining);
+            self.inner.held.lock().insert(
+                holder_id,
+                Acquisition {
+                    locks: remaining,
+                    watchdog,
+                },
+            );
+        }
+
+        let mut lost = Vec::new();
+        for lock in releasing.iter().rev() {
+            match self.inner.store.release(&lock.key, &lock.value).await {
+                Ok(true) => {}
+                Ok(false) => lost.push(lock.resource.to_string()),
+                Err(e) => {
+                    return Err(DomainError::LockAcquisitionFailed(format!(
+                        "redis unavailable while releasing {}: {}",
+                        lock.resource, e
+                    )));
+                }
+            }
+        }
+        if lost.is_empty() {
+            Ok(())
+        } else {
+            Err(DomainError::LockAcquisitionFailed(format!(
+                "cannot release {}: lock is held by a different holder",
+                lost.join(", ")
+            )))
+        }
+    }
+
+    async fn get_lock_info(&self, resource: &ResourceLock) -> Option<LockInfo> {
+        let held = self.inner.held.lock();
+        held.iter().find_map(|(holder_id, acquisition)| {
+            acquisition
+                .locks
+                .iter()
+                .any(|lock| &lock.resource == resource)
+                .then(|| {
+                    LockInfo::new(
+                        resource.clone(),
+                        *holder_id,
+                        self.inner.lock_config.lock_ttl,
+                    )
+                })
+        })
+    }
+
+    fn holder_id(&self) -> LockHolderId {
+        LockHolderId::new()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::RfqId;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    /// In-memory [`LockStore`] following the Redis scripts' semantics.
+    ///
+    /// Keys never expire on their own; tests call [`MockLockStore::expire`].
+    #[derive(Debug, Default)]
+    struct MockLockStore {
+        keys: Mutex<HashMap<String, String>>,
+        fences: Mutex<HashMap<String, u64>>,
+        extensions: AtomicUsize,
+        unavailable: AtomicBool,
+    }
+
+    impl MockLockStore {
+        fn expire(&self, key: &str) {
+            self.keys.lock().remove(key);
+        }
+
+        fn value(&self, key: &str) -> Option<String> {
+            self.keys.lock().get(key).cloned()
+        }
+
+        fn check_available(&self) -> Result<(), String> {
+            if self.unavailable.load(Ordering::SeqCst) {
+                Err("connection refused".to_string())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[async_trait]
+    impl LockStore for MockLockStore {
+        async fn try_acquire(
+            &self,
+            key: &str,
+            fence_key: &str,
+            holder: &str,
+            _ttl_ms: u64,
+        ) -> Result<Option<u64>, String> {
+            self.check_available()?;
+            let mut keys = self.keys.lock();
+            if keys.contains_key(key) {
+                return Ok(None);
+            }
+            let mut fences = self.fences.lock();
+            let token = fences.entry(fence_key.to_string()).or_insert(0);
+            *token += 1;
+            keys.insert(key.to_string(), format!("{holder}:{token}"));
+            Ok(Some(*token))
+        }
+
+        async fn extend(&self, key: &str, value: &str, _ttl_ms: u64) -> Result<bool, String> {
+            self.check_available()?;
+            self.extensions.fetch_add(1, Ordering::SeqCst);
+            Ok(self.keys.lock().get(key).is_some_and(|v| v == value))
+        }
+
+        async fn release(&self, key: &str, value: &str) -> Result<bool, String> {
+            self.check_available()?;
+            let mut keys = self.keys.lock();
+            if keys.get(key).is_some_and(|v| v == value) {
+                keys.remove(key);
+                Ok(true)
+            } else {
+                Ok(false)
+            }
+        }
+    }
+
+    fn manager_with(store: &Arc<MockLockStore>, lock_ttl: Duration) -> Arc<RedisLockManager> {
+        let lock_config = LockManagerConfig {
+            lock_ttl,
+            ..LockManagerConfig::default()
+        };
+        RedisLockManager::with_store(
+            Arc::clone(store) as Arc<dyn LockStore>,
+            RedisLockConfig::default().with_key_prefix("test"),
+            lock_config,
+        )
+    }
+
+    const TIMEOUT: Duration = Duration::from_millis(20);
+
+    #[tokio::test]
+    async fn acquire_and_release() {
+        let store = Arc::new(MockLockStore::default());
+        let manager = manager_with(&store, Duration::from_secs(10));
+        let resource = ResourceLock::Rfq(RfqId::new_v4());
+        let key = format!("test:{resource}");
+
+        let guard = manager
+            .acquire_all(vec![resource.clone()], TIMEOUT)
+            .await
+            .unwrap();
+        let token = manager.fencing_token(guard.holder_id(), &resource).unwrap();
+        assert_eq!(
+            store.value(&key),
+            Some(format!("{}:{}", guard.holder_id(), token))
+        );
+        assert!(manager.get_lock_info(&resource).await.is_some());
+
+        guard.release().await.unwrap();
+        assert_eq!(store.value(&key), None);
+        assert!(manager.get_lock_info(&resource).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn contention_fails_after_timeout() {
+        let store = Arc::new(MockLockStore::default());
+        let instance_a = manager_with(&store, Duration::from_secs(10));
+        let instance_b = manager_with(&store, Duration::from_secs(10));
+        let resource = ResourceLock::Rfq(RfqId::new_v4());
+
+        let guard = instance_a
+            .acquire_all(vec![resource.clone()], TIMEOUT)
+            .await
+            .unwrap();
+        let result = instance_b
+            .acquire_all(vec![resource.clone()], TIMEOUT)
+            .await;
+        assert!(matches!(
+            result,
+            Err(DomainError::LockAcquisitionFailed(msg)) if msg.contains("held by another")
+        ));
+
+        guard.release().await.unwrap();
+        assert!(
+            instance_b
+                .acquire_all(vec![resource], TIMEOUT)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn ttl_expiry_allows_takeover_with_larger_token() {
+        let store = Arc::new(MockLockStore::default());
+        let instance_a = manager_with(&store, Duration::from_secs(10));
+        let instance_b = manager_with(&store, Duration::from_secs(10));
+        let resource = ResourceLock::Rfq(RfqId::new_v4());
+        let key = format!("test:{resource}");
+
+        let guard_a = instance_a
+            .acquire_all(vec![resource.clone()], TIMEOUT)
+            .await
+            .unwrap();
+        let token_a = instance_a
+            .fencing_token(guard_a.holder_id(), &resource)
+            .unwrap();
+
+        store.expire(&key);
+        let guard_b = instance_b
+            .acquire_all(vec![resource.clone()], TIMEOUT)
+            .await
+            .unwrap();
+        let token_b = instance_b
+            .fencing_token(guard_b.holder_id(), &resource)
+            .unwrap();
+        assert!(token_b > token_a);
+
+        // A's lock was taken over: its release must not delete B's lock
+        let result = guard_a.release().await;
+        assert!(matches!(
+            result,
+            Err(DomainError::LockAcquisitionFailed(msg)) if msg.contains("different holder")
+        ));
+        assert_eq!(
+            store.value(&key),
+            Some(format!("{}:{}", guard_b.holder_id(), token_b))
+        );
+    }
+
+    #[tokio::test]
+    async fn watchdog_extends_lock_while_guard_lives() {
+        let store = Arc::new(MockLockStore::default());
+        let manager = manager_with(&store, Duration::from_millis(30));
+        let resource = ResourceLock::Rfq(RfqId::new_v4());
+
+        let guard = manager.acquire_all(vec![resource], TIMEOUT).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let extensions = store.extensions.load(Ordering::SeqCst);
+        assert!(extensions >= 2, "extended {extensions} times");
+
+        guard.release().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let after_release = store.extensions.load(Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(store.extensions.load(Ordering::SeqCst), after_release);
+    }
+
+    #[tokio::test]
+    async fn unreachable_redis_is_an_explicit_error() {
+        let store = Arc::new(MockLockStore::default());
+        store.unavailable.store(true, Ordering::SeqCst);
+        let manager = manager_with(&store, Duration::from_secs(10));
+
+        let result = manager
+            .acquire_all(vec![ResourceLock::Rfq(RfqId::new_v4())], TIMEOUT)
+            .await;
+        assert!(matches!(
+            result,
+            Err(DomainError::LockAcquisitionFailed(msg)) if msg.contains("redis unavailable")
+        ));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires Redis"]
+    async fn redis_store_acquire_contend_release() {
+        let Ok(url) = std::env::var("TEST_REDIS_URL") else {
+            eprintln!("Skipping test: TEST_REDIS_URL not set");
+            return;
+        };
+        let config = RedisLockConfig::new(url).with_key_prefix("otc-rfq-test:lock");
+        let instance_a = RedisLockManager::connect(config.clone(), LockManagerConfig::default())
+            .await
+            .unwrap();
+        let instance_b = RedisLockManager::connect(config, LockManagerConfig::default())
+            .await
+            .unwrap();
+        let resource = ResourceLock::Rfq(RfqId::new_v4());
+
+        let guard = instance_a
+            .acquire_all(vec![resource.clone()], TIMEOUT)
+            .await
+            .unwrap();
+        assert!(
+            instance_b
+                .acquire_all(vec![resource.clone()], TIMEOUT)
+                .await
+                .is_err()
+        );
+        guard.release().await.unwrap();
+
+        let guard = instance_b
+            .acquire_all(vec![resource], TIMEOUT)
+            .await
+            .unwrap();
+        guard.release().await.unwrap();
+    }
+}
@@ -5,12 +5,24 @@
 //! the critical path with network I/O.
 
 use crate::application::error::ApplicationResult;
+use crate::application::services::firm_up::SelectionEventPublisher;
+use crate::application::services::forced_transitions::ForcedTransitionEventPublisher;
+use crate::application::services::internal_crossing::InternalCrossEventPublisher;
+use crate::application::services::settlement_retry::SettlementEventPublisher;
+use crate::application::services::webhook_delivery::WebhookEventPublisher;
 use crate::application::use_cases::collect_quotes::QuoteEventPublisher;
+use crate::application::use_cases::conclude_negotiation::NegotiationEventPublisher;
 use crate::application::use_cases::create_rfq::EventPublisher;
 use crate::application::use_cases::execute_trade::TradeEventPublisher;
-use crate::domain::events::rfq_events::{QuoteReceived, RfqCreated};
-use crate::domain::events::trade_events::TradeExecuted;
-use crate::domain::value_objects::{QuoteId, RfqId};
+use crate::domain::events::admin_events::ForcedStateTransition;
+use crate::domain::events::negotiation_events::NegotiationConcluded;
+use crate::domain::events::rfq_events::{
+    InternalCrossProposed, QuorumOverridden, QuoteCollectionCompleted, QuoteCollectionStarted,
+    QuoteReceived, RfqCreated,
+};
+use crate::domain::events::trade_events::{SettlementDeadLettered, TradeExecuted};
+use crate::domain::events::webhook_events::WebhookSubscriptionDisabled;
+use crate::domain::value_objects::{FailureReason, QuoteId, RfqId};
 use async_trait::async_trait;
 use serde::Serialize;
 use std::fmt;
@@ -97,10 +109,55 @@ impl QuoteEventPublisher for DomainEventDispatcher {
         let subject = format!("{}.rfq.{}.quote_received", self.subject_prefix, rfq_id);
         self.dispatch(subject, &event).await
     }
+
+    async fn publish_quote_collection_started(
+        &self,
+        event: QuoteCollectionStarted,
+    ) -> Result<(), String> {
+        let rfq_id = event
+            .metadata
+            .rfq_id
+            .ok_or_else(|| "Missing RFQ ID in event metadata".to_string())?;
+        let subject = format!(
+            "{}.rfq.{}.quote_collection_started",
+            self.subject_prefix, rfq_id
+        );
+        self.dispatch(subject, &event).await
+    }
+
+    async fn publish_quote_collection_completed(
+        &self,
+        event: QuoteCollectionCompleted,
+    ) -> Result<(), String> {
+        let rfq_id = event
+            .metadata
+            .rfq_id
+            .ok_or_else(|| "Missing RFQ ID in event metadata".to_string())?;
+        let subject = format!(
+            "{}.rfq.{}.quote_collection_completed",
+            self.subject_prefix, rfq_id
+        );
+        self.dispatch(subject, &event).await
+    }
 }
 
 #[async_trait]
 impl TradeEventPublisher for DomainEventDispatcher {
+    async fn publish_execution_started(
+        &self,
+        event: crate::domain::events::ExecutionStarted,
+    ) -> ApplicationResult<()> {
+        let rfq_id = event.metadata.rfq_id.ok_or_else(|| {
+            crate::application::error::ApplicationError::EventPublishError(
+                "Missing RFQ ID in event metadata".to_string(),
+            )
+        })?;
+        let subject = format!("{}.rfq.{}.execution_started", self.subject_prefix, rfq_id);
+        self.dispatch(subject, &event)
+            .await
+            .map_err(crate::application::error::ApplicationError::EventPublishError)
+    }
+
     async fn publish_trade_executed(&self, event: TradeExecuted) -> ApplicationResult<()> {
         let rfq_id = event.metadata.rfq_id.ok_or_else(|| {
             crate::application::error::ApplicationError::EventPublishError(
@@ -117,13 +174,14 @@ impl TradeEventPublisher for DomainEventDispatcher {
         &self,
         rfq_id: RfqId,
         quote_id: QuoteId,
-        reason: &str,
+        reason: &FailureReason,
     ) -> ApplicationResult<()> {
         // We create an anonymous payload for the failure
         let payload = serde_json::json!({
             "rfq_id": rfq_id,
             "quote_id": quote_id,
-            "reason": reason,
+            "code": reason.code(),
+            "reason": reason.detail(),
         });
         let subject = format!("{}.rfq.{}.execution_failed", self.subject_prefix, rfq_id);
 
@@ -154,6 +212,138 @@ impl TradeEventPublisher for DomainEventDispatcher {
 
         mm_result.map_err(crate::application::error::ApplicationError::EventPublishError)
     }
+
+    async fn publish_compliance_check(
+        &self,
+        event: crate::domain::events::ComplianceEvent,
+    ) -> ApplicationResult<()> {
+        use crate::domain::events::DomainEvent;
+
+        let rfq_id = event.rfq_id().ok_or_else(|| {
+            crate::application::error::ApplicationError::EventPublishError(
+                "Missing RFQ ID in event metadata".to_string(),
+            )
+        })?;
+        let subject = format!("{}.rfq.{}.compliance_check", self.subject_prefix, rfq_id);
+        self.dispatch(subject, &event)
+            .await
+            .map_err(crate::application::error::ApplicationError::EventPublishError)
+    }
+}
+
+#[async_trait]
+impl SettlementEventPublisher for DomainEventDispatcher {
+    async fn publish_settlement_dead_lettered(
+        &self,
+        event: SettlementDeadLettered,
+    ) -> ApplicationResult<()> {
+        let rfq_id = event.metadata.rfq_id.ok_or_else(|| {
+            crate::application::error::ApplicationError::EventPublishError(
+                "Missing RFQ ID in event metadata".to_string(),
+            )
+        })?;
+        let subject = format!(
+            "{}.rfq.{}.settlement_dead_lettered",
+            self.subject_prefix, rfq_id
+        );
+        self.dispatch(subject, &event)
+            .await
+            .map_err(crate::application::error::ApplicationError::EventPublishError)
+    }
+}
+
+#[async_trait]
+impl WebhookEventPublisher for DomainEventDispatcher {
+    async fn publish_webhook_subscription_disabled(
+        &self,
+        event: WebhookSubscriptionDisabled,
+    ) -> ApplicationResult<()> {
+        let subject = format!(
+            "{}.webhook.{}.disabled",
+            self.subject_prefix, event.subscription_id
+        );
+        self.dispatch(subject, &event)
+            .await
+            .map_err(crate::application::error::ApplicationError::EventPublishError)
+    }
+}
+
+#[async_trait]
+impl ForcedTransitionEventPublisher for DomainEventDispatcher {
+    async fn publish_forced_state_transition(
+        &self,
+        event: ForcedStateTransition,
+    ) -> ApplicationResult<()> {
+        let rfq_id = event.metadata.rfq_id.ok_or_else(|| {
+            crate::application::error::ApplicationError::EventPublishError(
+                "Missing RFQ ID in event metadata".to_string(),
+            )
+        })?;
+        let subject = format!(
+            "{}.rfq.{}.forced_state_transition",
+            self.subject_prefix, rfq_id
+        );
+        self.dispatch(subject, &event)
+            .await
+            .map_err(crate::application::error::ApplicationError::EventPublishError)
+    }
+}
+
+#[async_trait]
+impl InternalCrossEventPublisher for DomainEventDispatcher {
+    async fn publish_internal_cross_proposed(
+        &self,
+        event: InternalCrossProposed,
+    ) -> ApplicationResult<()> {
+        let rfq_id = event.metadata.rfq_id.ok_or_else(|| {
+            crate::application::error::ApplicationError::EventPublishError(
+                "Missing RFQ ID in event metadata".to_string(),
+            )
+        })?;
+        let subject = format!(
+            "{}.rfq.{}.internal_cross_proposed",
+            self.subject_prefix, rfq_id
+        );
+        self.dispatch(subject, &event)
+            .await
+            .map_err(crate::application::error::ApplicationError::EventPublishError)
+    }
+}
+
+#[async_trait]
+impl SelectionEventPublisher for DomainEventDispatcher {
+    async fn publish_quorum_overridden(&self, event: QuorumOverridden) -> ApplicationResult<()> {
+        let rfq_id = event.metadata.rfq_id.ok_or_else(|| {
+            crate::application::error::ApplicationError::EventPublishError(
+                "Missing RFQ ID in event metadata".to_string(),
+            )
+        })?;
+        let subject = format!("{}.rfq.{}.quorum_overridden", self.subject_prefix, rfq_id);
+        self.dispatch(subject, &event)
+            .await
+            .map_err(crate::application::error::ApplicationError::EventPublishError)
+    }
+}
+
+#[async_trait]
+impl NegotiationEventPublisher for DomainEventDispatcher {
+    async fn publish_negotiation_concluded(
+        &self,
+        event: NegotiationConcluded,
+    ) -> ApplicationResult<()> {
+        let rfq_id = event.metadata.rfq_id.ok_or_else(|| {
+            crate::application::error::ApplicationError::EventPublishError(
+                "Missing RFQ ID in event metadata".to_string(),
+            )
+        })?;
+        let subject = format!(
+            "{}.rfq.{}.negotiation_concluded",
+            self.subject_prefix, rfq_id
+        );
+        self.dispatch(subject, &event)
+            .await
+            .map_err(crate::application::error::ApplicationError::EventPublishError)
+    }
 }
 
 #[cfg(test)]
@@ -207,4 +397,103 @@ mod tests {
             serde_json::from_str(&payload).expect("Failed to parse JSON");
         assert_eq!(deserialized.metadata.rfq_id, Some(rfq_id));
     }
+
+    #[tokio::test]
+    async fn test_dispatcher_publishes_settlement_dead_lettered() {
+        let (tx, mut rx) = mpsc::channel(100);
+        let dispatcher = DomainEventDispatcher::new(tx, "otc".to_string());
+
+        let rfq_id = RfqId::new_v4();
+        let event = SettlementDeadLettered::new(
+            rfq_id,
+            crate::domain::value_objects::TradeId::new_v4(),
+            5,
+            Some("reverted".to_string()),
+            None,
+        );
+
+        let result = dispatcher.publish_settlement_dead_lettered(event).await;
+        assert!(result.is_ok());
+
+        let (subject, _payload) = rx.recv().await.expect("Channel closed");
+        assert_eq!(
+            subject,
+            format!("otc.rfq.{}.settlement_dead_lettered", rfq_id)
+        );
+    }
+    #[tokio::test]
+    async fn test_dispatcher_publishes_webhook_subscription_disabled() {
+        let (tx, mut rx) = mpsc::channel(100);
+        let dispatcher = DomainEventDispatcher::new(tx, "otc".to_string());
+
+        let subscription_id = crate::domain::value_objects::WebhookSubscriptionId::new_v4();
+        let event = WebhookSubscriptionDisabled::new(
+            subscription_id,
+            "https://risk.example.com",
+            50,
+            Some("webhook returned status 500".to_string()),
+        );
+
+        let result = dispatcher
+            .publish_webhook_subscription_disabled(event)
+            .await;
+        assert!(result.is_ok());
+
+        let (subject, _payload) = rx.recv().await.expect("Channel closed");
+        assert_eq!(subject, format!("otc.webhook.{}.disabled", subscription_id));
+    }
+
+    #[tokio::test]
+    async fn test_dispatcher_publishes_forced_state_transition() {
+        let (tx, mut rx) = mpsc::channel(100);
+        let dispatcher = DomainEventDispatcher::new(tx, "otc".to_string());
+
+        let rfq_id = RfqId::new_v4();
+        let event = ForcedStateTransition::rfq(
+            rfq_id,
+            crate::domain::value_objects::RfqState::Executing,
+            crate::domain::value_objects::RfqState::Failed,
+            "ops-1",
+            "venue lost the order",
+        );
+
+        let result = dispatcher.publish_forced_state_transition(event).await;
+        assert!(result.is_ok());
+
+        let (subject, _payload) = rx.recv().await.expect("Channel closed");
+        assert_eq!(
+            subject,
+            format!("otc.rfq.{}.forced_state_transition", rfq_id)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dispatcher_publishes_internal_cross_proposed() {
+        let (tx, mut rx) = mpsc::channel(100);
+        let dispatcher = DomainEventDispatcher::new(tx, "otc".to_string());
+
+        let rfq_id = RfqId::new_v4();
+        let event = InternalCrossProposed::new(
+            rfq_id,
+            RfqId::new_v4(),
+            Instrument::new(
+                Symbol::new("BTC/USD").unwrap(),
+                AssetClass::CryptoSpot,
+                SettlementMethod::default(),
+            ),
+            crate::domain::value_objects::OrderSide::Buy,
+            crate::domain::value_objects::Price::new(50000.0).unwrap(),
+            Quantity::new(1.0).unwrap(),
+            crate::domain::value_objects::ReferencePriceSource::ClobMid,
+        );
+
+        let result = dispatcher.publish_internal_cross_proposed(event).await;
+        assert!(result.is_ok());
+
+        let (subject, _payload) = rx.recv().await.expect("Channel closed");
+        assert_eq!(
+            subject,
+            format!("otc.rfq.{}.internal_cross_proposed", rfq_id)
+        );
+    }
 }
@@ -0,0 +1,206 @@
+//! # Metrics
+//!
+//! Prometheus metrics for RFQ latency and outcomes.
+//!
+//! Metrics are recorded through the [`metrics`] facade and are no-ops until
+//! [`install`] registers the Prometheus recorder. The REST server exposes the
+//! rendered output at `GET /metrics` when `rest.enable_metrics` is set.
+//!
+//! # Series
+//!
+//! Names and label sets are stable; dashboards and alerts depend on them.
+//!
+//! | Name | Type | Labels | Description |
+//! |------|------|--------|-------------|
+//! | `otc_rfq_quote_collection_duration_seconds` | histogram | `outcome` | Time to collect quotes for an RFQ |
+//! | `otc_rfq_venue_response_duration_seconds` | histogram | `venue`, `outcome` | Time for a venue to answer a quote request |
+//! | `otc_rfq_execution_duration_seconds` | histogram | `outcome` | Time to execute a trade |
+//! | `otc_rfq_settlement_confirmation_duration_seconds` | histogram | `outcome` | Time for a settlement transaction to confirm |
+//! | `otc_rfq_rfqs_terminal_total` | counter | `state` | RFQs that reached a terminal state |
+//! | `otc_rfq_domain_errors_total` | counter | `code` | Domain errors returned to clients |
+//! | `otc_rfq_active_rfqs` | gauge | | RFQs created and not yet terminal |
+//! | `otc_rfq_open_negotiations` | gauge | | Negotiations not yet concluded |
+//! | `otc_rfq_circuit_transitions_total` | counter | `circuit`, `from`, `to`, `reason` | Circuit breaker state changes |
+//! | `otc_rfq_circuit_state` | gauge | `circuit` | Circuit breaker state: 0 closed, 1 half-open, 2 open |
+//!
+//! Label values:
+//!
+//! - `outcome`: `ok`, `error`, `timeout`, or `cancelled` for quote collection
+//!   and venue responses, plus `short_ttl` for venue quotes rejected by the
+//!   validity floor; `ok` or `error` for execution; `confirmed`,
+//!   `reverted`, or `error` for settlement confirmation.
+//! - `state`: the terminal [`RfqState`] in its wire form (e.g. `EXECUTED`).
+//! - `code`: the stable REST error code (e.g. `QUOTE_EXPIRED`).
+//! - `circuit`: the breaker name, which is the venue ID for venue breakers.
+//! - `from`, `to`: `CLOSED`, `OPEN`, or `HALF_OPEN`.
+//! - `reason`: `failure_threshold`, `reset_timeout_elapsed`,
+//!   `probes_succeeded`, `probe_failed`, `forced_open`, `forced_closed`, or
+//!   `reset`.
+
+use crate::domain::value_objects::RfqState;
+use metrics::{counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram};
+use metrics_exporter_prometheus::{BuildError, PrometheusBuilder, PrometheusHandle};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Histogram of quote collection duration.
+pub const QUOTE_COLLECTION_DURATION: &str = "otc_rfq_quote_collection_duration_seconds";
+
+/// Histogram of per-venue quote response latency.
+pub const VENUE_RESPONSE_DURATION: &str = "otc_rfq_venue_response_duration_seconds";
+
+/// Histogram of trade execution duration.
+pub const EXECUTION_DURATION: &str = "otc_rfq_execution_duration_seconds";
+
+/// Histogram of settlement confirmation time.
+pub const SETTLEMENT_CONFIRMATION_DURATION: &str =
+    "otc_rfq_settlement_confirmation_duration_seconds";
+
+/// Counter of RFQs by terminal state.
+pub const RFQS_TERMINAL_TOTAL: &str = "otc_rfq_rfqs_terminal_total";
+
+/// Counter of domain errors by code.
+pub const DOMAIN_ERRORS_TOTAL: &str = "otc_rfq_domain_errors_total";
+
+/// Gauge of active RFQs.
+pub const ACTIVE_RFQS: &str = "otc_rfq_active_rfqs";
+
+/// Gauge of open negotiations.
+pub const OPEN_NEGOTIATIONS: &str = "otc_rfq_open_negotiations";
+
+/// Counter of circuit breaker state changes.
+pub const CIRCUIT_TRANSITIONS_TOTAL: &str = "otc_rfq_circuit_transitions_total";
+
+/// Gauge of circuit breaker state.
+pub const CIRCUIT_STATE: &str = "otc_rfq_circuit_state";
+
+/// Histogram buckets in seconds, from 5ms to 2 minutes.
+const BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0,
+];
+
+static HANDLE: Mutex<Option<PrometheusHandle>> = Mutex::new(None);
+
+/// Installs the Prometheus recorder and returns a handle for rendering.
+///
+/// Installing more than once returns the handle of the first installation.
+///
+/// # Errors
+///
+/// Returns an error if another recorder is already installed.
+pub fn install() -> Result<PrometheusHandle, BuildError> {
+    let mut installed = HANDLE.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(handle) = installed.as_ref() {
+        return Ok(handle.clone());
+    }
+
+    let handle = PrometheusBuilder::new()
+        .set_buckets(BUCKETS)?
+        .install_recorder()?;
+    describe();
+    gauge!(ACTIVE_RFQS).set(0.0);
+    gauge!(OPEN_NEGOTIATIONS).set(0.0);
+
+    *installed = Some(handle.clone());
+    Ok(handle)
+}
+
+fn describe() {
+    describe_histogram!(
+        QUOTE_COLLECTION_DURATION,
+        metrics::Unit::Seconds,
+        "Time to collect quotes for an RFQ"
+    );
+    describe_histogram!(
+        VENUE_RESPONSE_DURATION,
+        metrics::Unit::Seconds,
+        "Time for a venue to answer a quote request"
+    );
+    describe_histogram!(
+        EXECUTION_DURATION,
+        metrics::Unit::Seconds,
+        "Time to execute a trade"
+    );
+    describe_histogram!(
+        SETTLEMENT_CONFIRMATION_DURATION,
+        metrics::Unit::Seconds,
+        "Time for a settlement transaction to confirm"
+    );
+    describe_counter!(RFQS_TERMINAL_TOTAL, "RFQs that reached a terminal state");
+    describe_counter!(DOMAIN_ERRORS_TOTAL, "Domain errors returned to clients");
+    describe_gauge!(ACTIVE_RFQS, "RFQs created and not yet terminal");
+    describe_gauge!(OPEN_NEGOTIATIONS, "Negotiations not yet concluded");
+    describe_counter!(CIRCUIT_TRANSITIONS_TOTAL, "Circuit breaker state changes");
+    describe_gauge!(
+        CIRCUIT_STATE,
+        "Circuit breaker state: 0 closed, 1 half-open, 2 open"
+    );
+}
+
+/// Records how long quote collection for an RFQ took.
+pub fn record_quote_collection(duration: Duration, outcome: &'static str) {
+    histogram!(QUOTE_COLLECTION_DURATION, "outcome" => outcome).record(duration.as_secs_f64());
+}
+
+/// Records how long a venue took to answer a quote request.
+pub fn record_venue_response(venue: &str, duration: Duration, outcome: &'static str) {
+    histogram!(VENUE_RESPONSE_DURATION, "venue" => venue.to_string(), "outcome" => outcome)
+        .record(duration.as_secs_f64());
+}
+
+/// Records how long a trade execution took.
+pub fn record_execution(duration: Duration, outcome: &'static str) {
+    histogram!(EXECUTION_DURATION, "outcome" => outcome).record(duration.as_secs_f64());
+}
+
+/// Records how long a settlement transaction took to confirm.
+pub fn record_settlement_confirmation(duration: Duration, outcome: &'static str) {
+    histogram!(SETTLEMENT_CONFIRMATION_DURATION, "outcome" => outcome)
+        .record(duration.as_secs_f64());
+}
+
+/// Records a newly created RFQ.
+pub fn record_rfq_created() {
+    gauge!(ACTIVE_RFQS).increment(1.0);
+}
+
+/// Records an RFQ reaching a terminal state.
+pub fn record_rfq_terminal(state: RfqState) {
+    counter!(RFQS_TERMINAL_TOTAL, "state" => state.to_string()).increment(1);
+    gauge!(ACTIVE_RFQS).decrement(1.0);
+}
+
+/// Records a domain error returned to a client.
+pub fn record_domain_error(code: &'static str) {
+    counter!(DOMAIN_ERRORS_TOTAL, "code" => code).increment(1);
+}
+
+/// Sets the number of open negotiations.
+pub fn set_open_negotiations(count: usize) {
+    gauge!(OPEN_NEGOTIATIONS).set(count as f64);
+}
+
+/// Records a circuit breaker state change and sets its state gauge.
+///
+/// States are in wire form (`CLOSED`, `OPEN`, `HALF_OPEN`).
+pub fn record_circuit_transition(
+    circuit: &str,
+    from: &'static str,
+    to: &'static str,
+    reason: &'static str,
+) {
+    counter!(
+        CIRCUIT_TRANSITIONS_TOTAL,
+        "circuit" => circuit.to_string(),
+        "from" => from,
+        "to" => to,
+        "reason" => reason
+    )
+    .increment(1);
+    let level = match to {
+        "OPEN" => 2.0,
+        "HALF_OPEN" => 1.0,
+        _ => 0.0,
+    };
+    gauge!(CIRCUIT_STATE, "circuit" => circuit.to_string()).set(level);
+}
@@ -20,18 +20,33 @@
 //!
 //! On-chain execution clients for DeFi protocols.
 //!
+//! ## Locking
+//!
+//! Distributed lock managers shared across service instances.
+//!
 //! ## Last-Look
 //!
 //! Channel-specific implementations for MM confirmation protocol.
+//!
+//! ## Metrics
+//!
+//! Prometheus recorder and the metric series it exports.
+//!
+//! ## Telemetry
+//!
+//! Trace context propagation and the optional OTLP span exporter.
 
 pub mod blockchain;
 pub mod http_clients;
 pub mod last_look;
+pub mod locking;
 pub mod messaging;
+pub mod metrics;
 pub mod notifications;
 pub mod persistence;
 pub mod sbe;
 pub mod streaming;
+pub mod telemetry;
 pub mod venues;
 
 pub use last_look::{
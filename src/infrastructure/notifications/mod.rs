@@ -1,13 +1,16 @@
 //! # Notification Infrastructure
 //!
-//! Channel adapters for multi-channel trade confirmation delivery.
+//! Channel adapters for multi-channel trade confirmation delivery, and the
+//! signed webhook client used to broadcast RFQs to market makers.
 
 pub mod api_callback_confirmation;
 pub mod email_confirmation;
 pub mod grpc_confirmation;
+pub mod rfq_webhook;
 pub mod websocket_confirmation;
 
 pub use api_callback_confirmation::ApiCallbackConfirmationAdapter;
 pub use email_confirmation::EmailConfirmationAdapter;
 pub use grpc_confirmation::GrpcConfirmationAdapter;
+pub use rfq_webhook::{RfqWebhookClient, WebhookError};
 pub use websocket_confirmation::WebSocketConfirmationAdapter;
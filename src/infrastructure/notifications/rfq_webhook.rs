@@ -0,0 +1,360 @@
+//! # RFQ Webhook Client
+//!
+//! Delivers RFQ broadcasts to market makers via signed HTTP POST.
+//!
+//! Each request carries the Unix timestamp it was signed at in
+//! [`TIMESTAMP_HEADER`] and an HMAC-SHA256 signature in [`SIGNATURE_HEADER`]:
+//!
+//! ```text
+//! X-OTC-Signature: sha256=hex(HMAC-SHA256(secret, "{timestamp}.{body}"))
+//! ```
+//!
+//! Market makers recompute the signature over the raw body with their
+//! shared secret and reject requests whose timestamp is too old; [`verify`]
+//! does both. Because the timestamp is signed, a captured request cannot be
+//! replayed outside the tolerance window with a fresh timestamp.
+
+use crate::application::services::retry::{Retryability, Retryable, parse_retry_after};
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Header carrying the request signature.
+pub const SIGNATURE_HEADER: &str = "X-OTC-Signature";
+
+/// Header carrying the Unix timestamp (seconds) the request was signed at.
+pub const TIMESTAMP_HEADER: &str = "X-OTC-Timestamp";
+
+/// Default age beyond which [`verify`] rejects a signed request.
+pub const DEFAULT_SIGNATURE_TOLERANCE: Duration = Duration::from_secs(300);
+
+/// Errors from webhook delivery.
+#[derive(Debug, Error)]
+pub enum WebhookError {
+    /// The request could not be sent or timed out.
+    #[error("webhook request failed: {0}")]
+    Request(String),
+
+    /// The webhook responded with a non-success status.
+    #[error("webhook returned status {0}")]
+    Status(u16),
+
+    /// The webhook responded with a non-success status and a `Retry-After`.
+    #[error("webhook returned status {status}, retry after {retry_after:?}")]
+    Throttled {
+        /// HTTP status code.
+        status: u16,
+        /// Delay requested by the webhook.
+        retry_after: Duration,
+    },
+
+    /// The payload could not be serialized.
+    #[error("webhook payload serialization failed: {0}")]
+    Serialization(String),
+}
+
+impl Retryable for WebhookError {
+    /// Network failures, timeouts, 429 and 5xx responses are transient.
+    fn is_retryable(&self) -> bool {
+        match self {
+            Self::Request(_) => true,
+            Self::Status(status) | Self::Throttled { status, .. } => {
+                *status == 429 || *status >= 500
+            }
+            Self::Serialization(_) => false,
+        }
+    }
+
+    /// Transient responses carrying `Retry-After` retry after that delay.
+    fn retryability(&self) -> Retryability {
+        match self {
+            Self::Throttled { retry_after, .. } if self.is_retryable() => {
+                Retryability::RetryAfter(*retry_after)
+            }
+            _ if self.is_retryable() => Retryability::Retryable,
+            _ => Retryability::NonRetryable,
+        }
+    }
+}
+
+/// Computes the signature header value for a webhook body.
+#[must_use]
+pub fn sign(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    // HMAC accepts keys of any length, so construction cannot fail.
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+        return String::new();
+    };
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Verifies a signature header value produced by [`sign`].
+///
+/// Returns false if the signature does not match `body`, or if
+/// `timestamp` is more than `tolerance` away from `now` (both Unix
+/// seconds), so replayed requests are rejected. The comparison is
+/// constant-time.
+#[must_use]
+pub fn verify(
+    secret: &str,
+    timestamp: i64,
+    body: &[u8],
+    signature: &str,
+    now: i64,
+    tolerance: Duration,
+) -> bool {
+    let age = now.abs_diff(timestamp);
+    if age > tolerance.as_secs() {
+        return false;
+    }
+    let Some(expected) = signature
+        .strip_prefix("sha256=")
+        .and_then(|hex_digest| hex::decode(hex_digest).ok())
+    else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+/// HTTP client for signed RFQ webhooks.
+#[derive(Debug, Clone)]
+pub struct RfqWebhookClient {
+    client: reqwest::Client,
+}
+
+impl RfqWebhookClient {
+    /// Creates a new webhook client.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Timeout for each delivery attempt
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created.
+    pub fn new(timeout: Duration) -> Result<Self, WebhookError> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| WebhookError::Request(format!("failed to create HTTP client: {e}")))?;
+        Ok(Self { client })
+    }
+
+    /// Creates a new client with the default timeout (5 seconds).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created.
+    pub fn with_default_timeout() -> Result<Self, WebhookError> {
+        Self::new(Duration::from_secs(5))
+    }
+
+    /// Posts a JSON payload to `url`, signed with `secret`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload cannot be serialized, the request
+    /// fails, or the webhook responds with a non-success status.
+    pub async fn deliver<T: Serialize + Sync>(
+        &self,
+        url: &str,
+        secret: &str,
+        payload: &T,
+    ) -> Result<(), WebhookError> {
+        let body =
+            serde_json::to_vec(payload).map_err(|e| WebhookError::Serialization(e.to_string()))?;
+        let timestamp = chrono::Utc::now().timestamp();
+        let signature = sign(secret, timestamp, &body);
+
+        let response = self
+            .client
+            .post(url)
+            .header("Content-Type", "application/json")
+            .header("User-Agent", "OTC-RFQ-Platform/1.0")
+            .header(TIMESTAMP_HEADER, timestamp)
+            .header(SIGNATURE_HEADER, signature)
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| WebhookError::Request(e.to_string()))?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after);
+        Err(match retry_after {
+            Some(retry_after) => WebhookError::Throttled {
+                status: status.as_u16(),
+                retry_after,
+            },
+            None => WebhookError::Status(status.as_u16()),
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header_exists, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn sign_matches_known_hmac_sha256() {
+        // echo -n '1700000000.{"a":1}' | openssl dgst -sha256 -hmac secret
+        assert_eq!(
+            sign("secret", 1_700_000_000, br#"{"a":1}"#),
+            "sha256=49f24e537407743fa4a0242bb63b94b9a47ee99cbbe071ccd8a22550ae411686"
+        );
+    }
+
+    #[test]
+    fn verify_accepts_fresh_signatures_only() {
+        let body = br#"{"a":1}"#;
+        let signature = sign("secret", 1_700_000_000, body);
+        let verify_at = |now| {
+            verify(
+                "secret",
+                1_700_000_000,
+                body,
+                &signature,
+                now,
+                DEFAULT_SIGNATURE_TOLERANCE,
+            )
+        };
+
+        assert!(verify_at(1_700_000_000));
+        assert!(verify_at(1_700_000_300));
+        assert!(!verify_at(1_700_000_301));
+        assert!(!verify_at(1_699_999_699));
+        assert!(!verify(
+            "other",
+            1_700_000_000,
+            body,
+            &signature,
+            1_700_000_000,
+            DEFAULT_SIGNATURE_TOLERANCE
+        ));
+        assert!(!verify(
+            "secret",
+            1_700_000_000,
+            br#"{"a":2}"#,
+            &signature,
+            1_700_000_000,
+            DEFAULT_SIGNATURE_TOLERANCE
+        ));
+        assert!(!verify(
+            "secret",
+            1_700_000_000,
+            body,
+            "sha256=zz",
+            1_700_000_000,
+            DEFAULT_SIGNATURE_TOLERANCE
+        ));
+    }
+
+    #[test]
+    fn server_errors_and_timeouts_are_retryable() {
+        assert!(WebhookError::Request("timeout".to_string()).is_retryable());
+        assert!(WebhookError::Status(503).is_retryable());
+        assert!(WebhookError::Status(429).is_retryable());
+        assert!(!WebhookError::Status(400).is_retryable());
+        assert!(!WebhookError::Serialization("bad".to_string()).is_retryable());
+    }
+
+    #[tokio::test]
+    async fn deliver_honors_retry_after() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "3"))
+            .mount(&server)
+            .await;
+
+        let client = RfqWebhookClient::with_default_timeout().unwrap();
+        let error = client
+            .deliver(&server.uri(), "secret", &serde_json::json!({}))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.retryability(),
+            Retryability::RetryAfter(Duration::from_secs(3))
+        );
+
+        let throttled_bad_request = WebhookError::Throttled {
+            status: 400,
+            retry_after: Duration::from_secs(3),
+        };
+        assert_eq!(
+            throttled_bad_request.retryability(),
+            Retryability::NonRetryable
+        );
+    }
+
+    #[tokio::test]
+    async fn deliver_signs_the_body() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/rfqs"))
+            .and(header_exists(SIGNATURE_HEADER))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RfqWebhookClient::with_default_timeout().unwrap();
+        client
+            .deliver(
+                &format!("{}/rfqs", server.uri()),
+                "secret",
+                &serde_json::json!({"a": 1}),
+            )
+            .await
+            .unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        let request = requests.first().unwrap();
+        let timestamp: i64 = request
+            .headers
+            .get(TIMESTAMP_HEADER)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        let signature = request.headers.get(SIGNATURE_HEADER).unwrap();
+        assert_eq!(
+            signature.to_str().unwrap(),
+            sign("secret", timestamp, &request.body)
+        );
+    }
+
+    #[tokio::test]
+    async fn deliver_reports_error_status() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let client = RfqWebhookClient::with_default_timeout().unwrap();
+        let result = client
+            .deliver(&server.uri(), "secret", &serde_json::json!({}))
+            .await;
+        assert!(matches!(result, Err(WebhookError::Status(500))));
+    }
+}
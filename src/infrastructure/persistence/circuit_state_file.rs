@@ -0,0 +1,107 @@
+//! # File Circuit State Store
+//!
+//! [`CircuitStateStore`] backed by a JSON file, so circuit breaker state
+//! and manual overrides survive a restart without a database.
+//!
+//! The file holds one object mapping circuit names to snapshots. Each save
+//! rewrites it through a temporary file and a rename, so a crash mid-write
+//! leaves the previous contents intact.
+
+use crate::application::services::circuit_breaker::{CircuitSnapshot, CircuitStateStore};
+use crate::infrastructure::persistence::traits::{RepositoryError, RepositoryResult};
+use std::collections::BTreeMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// JSON file implementation of [`CircuitStateStore`].
+#[derive(Debug)]
+pub struct FileCircuitStateStore {
+    path: PathBuf,
+    /// Serializes read-modify-write cycles within the process.
+    write_lock: Mutex<()>,
+}
+
+impl FileCircuitStateStore {
+    /// Creates a store at `path`. The file is created on the first save.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// Returns the path of the backing file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn read_all(&self) -> RepositoryResult<BTreeMap<String, CircuitSnapshot>> {
+        match std::fs::read(&self.path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map_err(|e| RepositoryError::serialization(e.to_string())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(e) => Err(RepositoryError::internal(format!(
+                "failed to read {}: {e}",
+                self.path.display()
+            ))),
+        }
+    }
+}
+
+impl CircuitStateStore for FileCircuitStateStore {
+    fn load(&self, name: &str) -> RepositoryResult<Option<CircuitSnapshot>> {
+        Ok(self.read_all()?.get(name).copied())
+    }
+
+    fn save(&self, name: &str, snapshot: &CircuitSnapshot) -> RepositoryResult<()> {
+        let _guard = self
+            .write_lock
+            .lock()
+            .map_err(|e| RepositoryError::internal(format!("lock poisoned: {e}")))?;
+
+        let mut snapshots = self.read_all()?;
+        snapshots.insert(name.to_string(), *snapshot);
+        let json = serde_json::to_vec_pretty(&snapshots)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+
+        let tmp = self.path.with_extension("tmp");
+        std::fs::write(&tmp, json)
+            .and_then(|()| std::fs::rename(&tmp, &self.path))
+            .map_err(|e| {
+                RepositoryError::internal(format!("failed to write {}: {e}", self.path.display()))
+            })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::application::services::circuit_breaker::{CircuitOverride, CircuitState};
+
+    #[test]
+    fn snapshots_survive_a_new_store_instance() {
+        let path = std::env::temp_dir().join(format!("circuits-{}.json", uuid::Uuid::new_v4()));
+        let snapshot = CircuitSnapshot {
+            state: CircuitState::Open,
+            forced: Some(CircuitOverride::ForcedOpen),
+        };
+
+        assert_eq!(
+            FileCircuitStateStore::new(&path).load("bebop").unwrap(),
+            None
+        );
+        FileCircuitStateStore::new(&path)
+            .save("bebop", &snapshot)
+            .unwrap();
+
+        let reopened = FileCircuitStateStore::new(&path);
+        assert_eq!(reopened.load("bebop").unwrap(), Some(snapshot));
+        assert_eq!(reopened.load("hashflow").unwrap(), None);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
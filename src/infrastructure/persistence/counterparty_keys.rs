@@ -0,0 +1,95 @@
+//! # Counterparty Key Store
+//!
+//! Port definition for the per-counterparty keys that encrypt personal
+//! identifiers in stored events.
+//!
+//! Each counterparty gets a random 256-bit [`CounterpartyKey`] the first
+//! time one of its identifiers is encrypted. Ciphertexts name the key by
+//! its random `key_id`, never by the counterparty. Shredding a key deletes
+//! the key material and the link to the counterparty, keeping only the
+//! pseudonym it was erased under: what was encrypted with it can no longer
+//! be decrypted, and readers see the pseudonym instead.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use otc_rfq::infrastructure::persistence::counterparty_keys::{CounterpartyKeyStore, KeyLookup};
+//!
+//! let key = keys.get_or_create(&counterparty_id).await?;
+//! keys.shred(&counterparty_id, &pseudonym).await?;
+//! assert!(matches!(keys.lookup(key.key_id).await?, KeyLookup::Shredded(_)));
+//! ```
+
+use crate::domain::value_objects::CounterpartyId;
+use crate::infrastructure::persistence::traits::RepositoryResult;
+use async_trait::async_trait;
+use std::fmt;
+use uuid::Uuid;
+
+/// Length in bytes of a counterparty key.
+pub const COUNTERPARTY_KEY_LEN: usize = 32;
+
+/// A counterparty's data encryption key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CounterpartyKey {
+    /// Random identifier ciphertexts refer to the key by.
+    pub key_id: Uuid,
+    /// The AES-256 key material.
+    pub material: [u8; COUNTERPARTY_KEY_LEN],
+}
+
+impl CounterpartyKey {
+    /// Generates a new random key.
+    #[must_use]
+    pub fn generate() -> Self {
+        Self {
+            key_id: Uuid::new_v4(),
+            material: rand::random(),
+        }
+    }
+}
+
+impl fmt::Debug for CounterpartyKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CounterpartyKey")
+            .field("key_id", &self.key_id)
+            .field("material", &"<redacted>")
+            .finish()
+    }
+}
+
+/// What the store knows about a key ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyLookup {
+    /// The key exists and can decrypt.
+    Active(CounterpartyKey),
+    /// The key was shredded when its counterparty was erased under this
+    /// pseudonym.
+    Shredded(CounterpartyId),
+    /// The store has no such key.
+    Unknown,
+}
+
+/// Storage of per-counterparty encryption keys.
+#[async_trait]
+pub trait CounterpartyKeyStore: Send + Sync + fmt::Debug {
+    /// Returns the key of `counterparty_id`, creating one if it has none.
+    async fn get_or_create(
+        &self,
+        counterparty_id: &CounterpartyId,
+    ) -> RepositoryResult<CounterpartyKey>;
+
+    /// Looks up a key by its ID.
+    async fn lookup(&self, key_id: Uuid) -> RepositoryResult<KeyLookup>;
+
+    /// Deletes the key material of `counterparty_id` and replaces the
+    /// counterparty with `pseudonym`.
+    ///
+    /// Returns `Ok(true)` if a key was shredded, `Ok(false)` if the
+    /// counterparty had none.
+    async fn shred(
+        &self,
+        counterparty_id: &CounterpartyId,
+        pseudonym: &CounterpartyId,
+    ) -> RepositoryResult<bool>;
+}
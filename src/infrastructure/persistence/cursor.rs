@@ -0,0 +1,306 @@
+//! # Page Cursors
+//!
+//! Opaque keyset cursors for paginated listings.
+//!
+//! A [`PageCursor`] identifies the last row of a page by its
+//! `(created_at, id)` pair. Listings are ordered newest first, so the next
+//! page contains the rows strictly after the cursor in that order. Unlike an
+//! offset, a cursor does not shift when rows are inserted between requests,
+//! so pages never repeat or skip rows.
+//!
+//! Event store queries read oldest first instead, keyed by
+//! `(timestamp, event_id)`; see [`paginate_oldest_first`].
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::infrastructure::persistence::cursor::PageCursor;
+//!
+//! let cursor = PageCursor::new(1_700_000_000_000, "8f0c1a52-7f5e-4a0e-9d53-3c1f1f0b2a11");
+//! let encoded = cursor.encode();
+//!
+//! assert_eq!(PageCursor::decode(&encoded).unwrap(), cursor);
+//! assert!(PageCursor::decode("not-a-cursor").is_err());
+//! ```
+
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::entities::trade::Trade;
+use crate::infrastructure::persistence::event_store::StoredEvent;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use std::cmp::Ordering;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Error returned when a cursor string cannot be decoded.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CursorError {
+    /// The cursor is not valid base64.
+    #[error("cursor is not valid base64")]
+    Encoding,
+
+    /// The decoded cursor does not have the expected structure.
+    #[error("cursor is malformed")]
+    Malformed,
+}
+
+/// Keyset position within a newest-first listing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageCursor {
+    created_at_millis: i64,
+    id: String,
+}
+
+impl PageCursor {
+    /// Creates a cursor positioned at the given row key.
+    #[must_use]
+    pub fn new(created_at_millis: i64, id: impl Into<String>) -> Self {
+        Self {
+            created_at_millis,
+            id: id.into(),
+        }
+    }
+
+    /// Creates a cursor positioned at the given RFQ.
+    #[must_use]
+    pub fn from_rfq(rfq: &Rfq) -> Self {
+        Self::new(rfq.created_at().timestamp_millis(), rfq.id().to_string())
+    }
+
+    /// Creates a cursor whose next page starts at the newest row created at
+    /// or before `created_at_millis`.
+    ///
+    /// No row ID sorts below the empty string, so every row created at
+    /// `created_at_millis + 1` counts as already seen.
+    #[must_use]
+    pub fn at_or_before(created_at_millis: i64) -> Self {
+        Self::new(created_at_millis.saturating_add(1), "")
+    }
+
+    /// Creates a cursor positioned at the given trade.
+    #[must_use]
+    pub fn from_trade(trade: &Trade) -> Self {
+        Self::new(
+            trade.created_at().timestamp_millis(),
+            trade.id().to_string(),
+        )
+    }
+
+    /// Creates a cursor positioned at the given stored event.
+    #[must_use]
+    pub fn from_event(event: &StoredEvent) -> Self {
+        Self::new(
+            event.timestamp.timestamp_millis(),
+            event.event_id.to_string(),
+        )
+    }
+
+    /// Returns the creation time of the row, in milliseconds since epoch.
+    #[inline]
+    #[must_use]
+    pub fn created_at_millis(&self) -> i64 {
+        self.created_at_millis
+    }
+
+    /// Returns the ID of the row.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Encodes the cursor as an opaque URL-safe string.
+    #[must_use]
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!("{}:{}", self.created_at_millis, self.id))
+    }
+
+    /// Decodes a cursor previously produced by [`PageCursor::encode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CursorError`] if the string is not valid base64 or does not
+    /// decode to a `(created_at, id)` pair with a UUID id.
+    pub fn decode(encoded: &str) -> Result<Self, CursorError> {
+        let bytes = URL_SAFE_NO_PAD
+            .decode(encoded)
+            .map_err(|_| CursorError::Encoding)?;
+        let raw = String::from_utf8(bytes).map_err(|_| CursorError::Malformed)?;
+        let (millis, id) = raw.split_once(':').ok_or(CursorError::Malformed)?;
+        let created_at_millis = millis.parse().map_err(|_| CursorError::Malformed)?;
+        let id = Uuid::parse_str(id).map_err(|_| CursorError::Malformed)?;
+
+        Ok(Self::new(created_at_millis, id.to_string()))
+    }
+
+    /// Returns true if the row at `other` comes after this cursor in
+    /// newest-first order.
+    #[must_use]
+    pub fn precedes(&self, other: &PageCursor) -> bool {
+        self.cmp_newest_first(other) == Ordering::Less
+    }
+
+    /// Returns true if the row at `other` comes after this cursor in
+    /// oldest-first order.
+    #[must_use]
+    pub fn precedes_oldest_first(&self, other: &PageCursor) -> bool {
+        self.cmp_newest_first(other) == Ordering::Greater
+    }
+
+    fn cmp_newest_first(&self, other: &PageCursor) -> Ordering {
+        other
+            .created_at_millis
+            .cmp(&self.created_at_millis)
+            .then_with(|| other.id.cmp(&self.id))
+    }
+}
+
+/// Returns one page of `items` in newest-first order.
+///
+/// Used by in-memory repositories to mirror the keyset query run by the
+/// PostgreSQL implementations. `key` returns the cursor positioned at an
+/// item, e.g. [`PageCursor::from_rfq`].
+pub fn paginate<T>(
+    items: Vec<T>,
+    cursor: Option<&PageCursor>,
+    limit: usize,
+    key: impl Fn(&T) -> PageCursor,
+) -> Vec<T> {
+    let mut keyed: Vec<(PageCursor, T)> = items
+        .into_iter()
+        .map(|item| (key(&item), item))
+        .filter(|(position, _)| cursor.is_none_or(|c| c.precedes(position)))
+        .collect();
+    keyed.sort_by(|(a, _), (b, _)| a.cmp_newest_first(b));
+
+    keyed
+        .into_iter()
+        .take(limit)
+        .map(|(_, item)| item)
+        .collect()
+}
+
+/// Returns one page of `items` in newest-first order, skipping the first
+/// `offset`.
+///
+/// The offset-paginated counterpart of [`paginate`].
+pub fn paginate_offset<T>(
+    items: Vec<T>,
+    offset: usize,
+    limit: usize,
+    key: impl Fn(&T) -> PageCursor,
+) -> Vec<T> {
+    paginate(items, None, offset.saturating_add(limit), key)
+        .into_iter()
+        .skip(offset)
+        .collect()
+}
+
+/// Returns one page of `items` in oldest-first order.
+///
+/// The oldest-first counterpart of [`paginate`], used by the in-memory
+/// event store.
+pub fn paginate_oldest_first<T>(
+    items: Vec<T>,
+    cursor: Option<&PageCursor>,
+    limit: usize,
+    key: impl Fn(&T) -> PageCursor,
+) -> Vec<T> {
+    let mut keyed: Vec<(PageCursor, T)> = items
+        .into_iter()
+        .map(|item| (key(&item), item))
+        .filter(|(position, _)| cursor.is_none_or(|c| c.precedes_oldest_first(position)))
+        .collect();
+    keyed.sort_by(|(a, _), (b, _)| b.cmp_newest_first(a));
+
+    keyed
+        .into_iter()
+        .take(limit)
+        .map(|(_, item)| item)
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "8f0c1a52-7f5e-4a0e-9d53-3c1f1f0b2a11";
+
+    #[test]
+    fn roundtrip() {
+        let cursor = PageCursor::new(1_700_000_000_000, ID);
+        let decoded = PageCursor::decode(&cursor.encode()).unwrap();
+        assert_eq!(decoded, cursor);
+        assert_eq!(decoded.created_at_millis(), 1_700_000_000_000);
+        assert_eq!(decoded.id(), ID);
+    }
+
+    #[test]
+    fn rejects_invalid_base64() {
+        assert_eq!(PageCursor::decode("***"), Err(CursorError::Encoding));
+    }
+
+    #[test]
+    fn rejects_tampered_payload() {
+        let no_separator = URL_SAFE_NO_PAD.encode("1700000000000");
+        assert_eq!(
+            PageCursor::decode(&no_separator),
+            Err(CursorError::Malformed)
+        );
+
+        let bad_millis = URL_SAFE_NO_PAD.encode(format!("abc:{ID}"));
+        assert_eq!(PageCursor::decode(&bad_millis), Err(CursorError::Malformed));
+
+        let bad_id = URL_SAFE_NO_PAD.encode("1700000000000:not-a-uuid");
+        assert_eq!(PageCursor::decode(&bad_id), Err(CursorError::Malformed));
+    }
+
+    #[test]
+    fn paginate_orders_newest_first_and_skips_past_cursor() {
+        let items = vec![(1, "a"), (3, "c"), (2, "b"), (3, "d")];
+        let key = |item: &(i64, &str)| PageCursor::new(item.0, item.1);
+
+        let first = paginate(items.clone(), None, 2, key);
+        assert_eq!(first, vec![(3, "d"), (3, "c")]);
+
+        let cursor = PageCursor::new(3, "c");
+        let second = paginate(items, Some(&cursor), 2, key);
+        assert_eq!(second, vec![(2, "b"), (1, "a")]);
+    }
+
+    #[test]
+    fn paginate_offset_skips_newest_first() {
+        let items = vec![(1, "a"), (3, "c"), (2, "b"), (3, "d")];
+        let key = |item: &(i64, &str)| PageCursor::new(item.0, item.1);
+
+        assert_eq!(
+            paginate_offset(items.clone(), 1, 2, key),
+            vec![(3, "c"), (2, "b")]
+        );
+        assert!(paginate_offset(items, 4, 2, key).is_empty());
+    }
+
+    #[test]
+    fn paginate_oldest_first_skips_up_to_cursor() {
+        let items = vec![(1, "a"), (3, "c"), (2, "b"), (3, "d")];
+        let key = |item: &(i64, &str)| PageCursor::new(item.0, item.1);
+
+        let first = paginate_oldest_first(items.clone(), None, 2, key);
+        assert_eq!(first, vec![(1, "a"), (2, "b")]);
+
+        let cursor = PageCursor::new(2, "b");
+        let second = paginate_oldest_first(items, Some(&cursor), 2, key);
+        assert_eq!(second, vec![(3, "c"), (3, "d")]);
+    }
+
+    #[test]
+    fn at_or_before_starts_at_the_given_time() {
+        let items = vec![(1, "a"), (3, "c"), (2, "b"), (2, "e")];
+        let key = |item: &(i64, &str)| PageCursor::new(item.0, item.1);
+
+        let cursor = PageCursor::at_or_before(2);
+        let page = paginate(items, Some(&cursor), 10, key);
+        assert_eq!(page, vec![(2, "e"), (2, "b"), (1, "a")]);
+    }
+}
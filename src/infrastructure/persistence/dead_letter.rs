@@ -0,0 +1,147 @@
+//! # Dead Letter Store
+//!
+//! Port definition for parking events an event consumer could not handle.
+//!
+//! When a consumer of the domain event bus (a projection, webhook
+//! delivery, ...) keeps failing on an event, the event is parked as a
+//! [`DeadLetter`] keyed by the consumer's name and the event ID, with the
+//! full stored event, the last error and the number of attempts made. The
+//! consumer moves on to later events; an operator re-drives the parked
+//! ones once the cause is fixed.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use otc_rfq::infrastructure::persistence::dead_letter::DeadLetterStore;
+//!
+//! let parked = store.list_parked(Some("rfq_summary_projection"), 50).await?;
+//! let letter = store.find("webhook_delivery", event_id).await?;
+//! ```
+
+use crate::domain::value_objects::EventId;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::infrastructure::persistence::event_store::StoredEvent;
+use crate::infrastructure::persistence::traits::RepositoryResult;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// State of a dead letter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DeadLetterStatus {
+    /// The event is waiting to be re-driven.
+    Parked,
+    /// A re-drive handled the event.
+    Resolved,
+}
+
+impl fmt::Display for DeadLetterStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parked => write!(f, "PARKED"),
+            Self::Resolved => write!(f, "RESOLVED"),
+        }
+    }
+}
+
+impl FromStr for DeadLetterStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "PARKED" => Ok(Self::Parked),
+            "RESOLVED" => Ok(Self::Resolved),
+            other => Err(format!("unknown dead letter status: {other}")),
+        }
+    }
+}
+
+/// An event a consumer failed to handle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeadLetter {
+    /// Name of the consumer that failed, e.g. `rfq_summary_projection`.
+    pub consumer: String,
+    /// The event, as stored.
+    pub event: StoredEvent,
+    /// Error from the last failed attempt.
+    pub last_error: String,
+    /// Attempts made so far, including re-drives.
+    pub attempts: u32,
+    /// Current state.
+    pub status: DeadLetterStatus,
+    /// When the first attempt failed.
+    pub first_seen_at: Timestamp,
+    /// When the last attempt finished.
+    pub last_seen_at: Timestamp,
+}
+
+impl DeadLetter {
+    /// Creates a parked dead letter after `attempts` failures, the last
+    /// with `error`.
+    #[must_use]
+    pub fn parked(
+        consumer: impl Into<String>,
+        event: StoredEvent,
+        error: impl Into<String>,
+        attempts: u32,
+        first_seen_at: Timestamp,
+    ) -> Self {
+        Self {
+            consumer: consumer.into(),
+            event,
+            last_error: error.into(),
+            attempts,
+            status: DeadLetterStatus::Parked,
+            first_seen_at,
+            last_seen_at: Timestamp::now(),
+        }
+    }
+
+    /// Returns the ID of the parked event.
+    #[must_use]
+    pub fn event_id(&self) -> EventId {
+        self.event.event_id
+    }
+
+    /// Returns true if the event is waiting to be re-driven.
+    #[must_use]
+    pub fn is_parked(&self) -> bool {
+        self.status == DeadLetterStatus::Parked
+    }
+}
+
+/// Storage for dead letters, keyed by consumer and event ID.
+///
+/// Implementations must be `Send + Sync` for use in async contexts.
+#[async_trait]
+pub trait DeadLetterStore: Send + Sync + fmt::Debug {
+    /// Inserts a dead letter, or replaces the one for the same consumer
+    /// and event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dead letter cannot be stored.
+    async fn save(&self, letter: &DeadLetter) -> RepositoryResult<()>;
+
+    /// Returns the dead letter of `event_id` for `consumer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dead letter cannot be loaded.
+    async fn find(&self, consumer: &str, event_id: EventId)
+    -> RepositoryResult<Option<DeadLetter>>;
+
+    /// Returns up to `limit` parked dead letters, of `consumer` only if
+    /// given, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dead letters cannot be loaded.
+    async fn list_parked(
+        &self,
+        consumer: Option<&str>,
+        limit: usize,
+    ) -> RepositoryResult<Vec<DeadLetter>>;
+}
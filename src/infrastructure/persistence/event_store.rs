@@ -13,17 +13,32 @@
 //! // Append an event
 //! event_store.append(event).await?;
 //!
+//! // Versioned events must directly follow their aggregate's last stored event
+//! let sequence = event_store.next_aggregate_sequence(aggregate_id).await?;
+//! match event_store.append(event.with_aggregate_sequence(sequence)).await {
+//!     Err(EventStoreError::VersionConflict { expected, .. }) => { /* reload and retry */ }
+//!     result => result?,
+//! }
+//!
 //! // Retrieve events for an RFQ
 //! let events = event_store.get_events(rfq_id).await?;
+//!
+//! // Everything recorded between two instants, one page at a time
+//! let page = event_store.query_by_time_range(from, to, None, 100, None).await?;
+//!
+//! // Everything that followed from one RFQ, across RFQ and trade streams
+//! let chain = event_store.query_by_correlation(CorrelationId::from(rfq_id)).await?;
 //! ```
 
 use crate::domain::events::domain_event::EventType;
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{EventId, RfqId};
+use crate::domain::value_objects::{CorrelationId, EventId, RfqId};
+use crate::infrastructure::persistence::cursor::PageCursor;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use thiserror::Error;
+use uuid::Uuid;
 
 /// Error type for event store operations.
 #[derive(Debug, Error)]
@@ -47,6 +62,20 @@ pub enum EventStoreError {
     /// Internal error.
     #[error("internal error: {0}")]
     Internal(String),
+
+    /// A versioned event does not directly follow its aggregate's last
+    /// stored event: it duplicates a stored sequence number or skips ahead.
+    #[error(
+        "version conflict on aggregate {aggregate_id}: expected sequence {expected}, got {actual}"
+    )]
+    VersionConflict {
+        /// The aggregate the event belongs to.
+        aggregate_id: Uuid,
+        /// The sequence number the next event of the aggregate must carry.
+        expected: u64,
+        /// The sequence number the rejected event carried.
+        actual: u64,
+    },
 }
 
 impl EventStoreError {
@@ -79,6 +108,16 @@ impl EventStoreError {
     pub fn internal(msg: impl Into<String>) -> Self {
         Self::Internal(msg.into())
     }
+
+    /// Creates a version conflict error.
+    #[must_use]
+    pub fn version_conflict(aggregate_id: Uuid, expected: u64, actual: u64) -> Self {
+        Self::VersionConflict {
+            aggregate_id,
+            expected,
+            actual,
+        }
+    }
 }
 
 /// Result type for event store operations.
@@ -104,6 +143,19 @@ pub struct StoredEvent {
     pub payload: serde_json::Value,
     /// Sequence number for ordering within an aggregate.
     pub sequence: u64,
+    /// Event chain this event belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<CorrelationId>,
+    /// Aggregate that emitted the event, if the event is versioned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aggregate_id: Option<Uuid>,
+    /// Version of the emitting aggregate, 0 if the event is not versioned.
+    #[serde(default)]
+    pub aggregate_version: u64,
+    /// Position of the event among its aggregate's events, consecutive
+    /// from 1; 0 if the event is not versioned.
+    #[serde(default)]
+    pub aggregate_sequence: u64,
 }
 
 impl StoredEvent {
@@ -126,6 +178,69 @@ impl StoredEvent {
             timestamp,
             payload,
             sequence,
+            correlation_id: rfq_id.map(CorrelationId::from),
+            aggregate_id: None,
+            aggregate_version: 0,
+            aggregate_sequence: 0,
+        }
+    }
+
+    /// Sets the event chain this event belongs to.
+    #[must_use]
+    pub fn with_correlation_id(mut self, correlation_id: Option<CorrelationId>) -> Self {
+        self.correlation_id = correlation_id;
+        self
+    }
+
+    /// Sets the version of the aggregate that emitted this event.
+    #[must_use]
+    pub fn with_aggregate_version(mut self, aggregate_id: Uuid, version: u64) -> Self {
+        self.aggregate_id = Some(aggregate_id);
+        self.aggregate_version = version;
+        self
+    }
+
+    /// Sets the position of this event among its aggregate's events.
+    #[must_use]
+    pub fn with_aggregate_sequence(mut self, sequence: u64) -> Self {
+        self.aggregate_sequence = sequence;
+        self
+    }
+
+    /// Returns the (aggregate ID, version) pair consumers de-duplicate on,
+    /// if the event is versioned.
+    #[must_use]
+    pub fn aggregate_key(&self) -> Option<(Uuid, u64)> {
+        self.aggregate_id
+            .filter(|_| self.aggregate_version > 0)
+            .map(|id| (id, self.aggregate_version))
+    }
+
+    /// Checks that this event may be appended after `last_sequence`, the
+    /// highest sequence number stored for its aggregate (0 if none).
+    ///
+    /// The check runs on the aggregate sequence rather than the version: an
+    /// aggregate's version also moves on changes that emit no event, while
+    /// its events are numbered consecutively. Unversioned events are always
+    /// accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EventStoreError::VersionConflict`] unless the event's
+    /// sequence number is exactly `last_sequence + 1`.
+    pub fn check_follows(&self, last_sequence: u64) -> EventStoreResult<()> {
+        let Some((aggregate_id, _)) = self.aggregate_key() else {
+            return Ok(());
+        };
+        let expected = last_sequence.saturating_add(1);
+        if self.aggregate_sequence == expected {
+            Ok(())
+        } else {
+            Err(EventStoreError::version_conflict(
+                aggregate_id,
+                expected,
+                self.aggregate_sequence,
+            ))
         }
     }
 
@@ -149,6 +264,10 @@ impl StoredEvent {
             timestamp: event.timestamp(),
             payload,
             sequence,
+            correlation_id: event.correlation_id(),
+            aggregate_id: event.aggregate_id(),
+            aggregate_version: event.aggregate_version(),
+            aggregate_sequence: 0,
         })
     }
 }
@@ -176,13 +295,20 @@ impl fmt::Display for StoredEvent {
 pub trait EventStore: Send + Sync + fmt::Debug {
     /// Appends an event to the store.
     ///
+    /// A versioned event must carry exactly the next sequence number of its
+    /// aggregate (see [`StoredEvent::check_follows`]), so redelivered and
+    /// out-of-order events are refused rather than stored twice or with
+    /// a gap.
+    ///
     /// # Arguments
     ///
     /// * `event` - The stored event to append
     ///
     /// # Errors
     ///
-    /// Returns an error if the event cannot be stored.
+    /// Returns [`EventStoreError::VersionConflict`] if a versioned event
+    /// duplicates or skips a sequence number of its aggregate, or another
+    /// error if the event cannot be stored.
     async fn append(&self, event: StoredEvent) -> EventStoreResult<()>;
 
     /// Retrieves all events for an RFQ.
@@ -223,6 +349,51 @@ pub trait EventStore: Send + Sync + fmt::Debug {
     async fn get_events_by_type(&self, event_type: EventType)
     -> EventStoreResult<Vec<StoredEvent>>;
 
+    /// Retrieves one page of events recorded in `[from, to)`, across all
+    /// aggregates.
+    ///
+    /// Events are returned oldest first, ordered by timestamp and then
+    /// event ID, so pages stay stable while events are appended. Pass the
+    /// cursor of the last event of a page ([`PageCursor::from_event`]) to
+    /// get the next page.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Only return events at or after this timestamp
+    /// * `to` - Only return events before this timestamp
+    /// * `event_types` - Only return events of these types, if set
+    /// * `limit` - Maximum number of events to return
+    /// * `cursor` - Only return events after this position
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if events cannot be retrieved.
+    async fn query_by_time_range(
+        &self,
+        from: Timestamp,
+        to: Timestamp,
+        event_types: Option<Vec<EventType>>,
+        limit: usize,
+        cursor: Option<&PageCursor>,
+    ) -> EventStoreResult<Vec<StoredEvent>>;
+
+    /// Retrieves every event of an event chain, across all aggregates.
+    ///
+    /// Events are returned oldest first, ordered by timestamp and then
+    /// sequence number so same-millisecond steps keep their order.
+    ///
+    /// # Arguments
+    ///
+    /// * `correlation_id` - The chain to get events for
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if events cannot be retrieved.
+    async fn query_by_correlation(
+        &self,
+        correlation_id: CorrelationId,
+    ) -> EventStoreResult<Vec<StoredEvent>>;
+
     /// Returns the total number of events in the store.
     ///
     /// # Errors
@@ -251,6 +422,18 @@ pub trait EventStore: Send + Sync + fmt::Debug {
     ///
     /// Returns an error if the sequence cannot be determined.
     async fn next_sequence(&self, rfq_id: RfqId) -> EventStoreResult<u64>;
+
+    /// Returns the sequence number the next event of an aggregate must
+    /// carry.
+    ///
+    /// # Arguments
+    ///
+    /// * `aggregate_id` - The aggregate to get the next sequence for
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sequence cannot be determined.
+    async fn next_aggregate_sequence(&self, aggregate_id: Uuid) -> EventStoreResult<u64>;
 }
 
 #[cfg(test)]
@@ -274,6 +457,50 @@ mod tests {
 
         let err = EventStoreError::internal("test error");
         assert_eq!(err.to_string(), "internal error: test error");
+
+        let aggregate_id = Uuid::nil();
+        let err = EventStoreError::version_conflict(aggregate_id, 3, 2);
+        assert_eq!(
+            err.to_string(),
+            format!("version conflict on aggregate {aggregate_id}: expected sequence 3, got 2")
+        );
+    }
+
+    #[test]
+    fn versioned_event_must_follow_the_last_sequence() {
+        let event = StoredEvent::new(
+            EventId::new_v4(),
+            Some(RfqId::new_v4()),
+            EventType::Rfq,
+            "RfqCreated",
+            Timestamp::now(),
+            serde_json::json!({}),
+            1,
+        );
+        assert!(event.check_follows(7).is_ok());
+
+        let aggregate_id = Uuid::new_v4();
+        // Versions may skip; the aggregate sequence may not.
+        let event = event
+            .with_aggregate_version(aggregate_id, 5)
+            .with_aggregate_sequence(2);
+        assert!(event.check_follows(1).is_ok());
+        assert!(matches!(
+            event.check_follows(2),
+            Err(EventStoreError::VersionConflict {
+                expected: 3,
+                actual: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            event.check_follows(0),
+            Err(EventStoreError::VersionConflict {
+                expected: 1,
+                actual: 2,
+                ..
+            })
+        ));
     }
 
     #[test]
@@ -312,5 +539,41 @@ mod tests {
         assert_eq!(event.event_type, deserialized.event_type);
         assert_eq!(event.event_name, deserialized.event_name);
         assert_eq!(event.sequence, deserialized.sequence);
+        assert_eq!(event.correlation_id, deserialized.correlation_id);
+    }
+
+    #[test]
+    fn stored_event_takes_correlation_from_domain_event() {
+        use crate::domain::events::trade_events::SettlementConfirmed;
+        use crate::domain::value_objects::TradeId;
+
+        let rfq_id = RfqId::new_v4();
+        let correlation_id = CorrelationId::new_v4();
+        let mut event = SettlementConfirmed::off_chain(rfq_id, TradeId::new_v4());
+        assert_eq!(
+            StoredEvent::from_event(&event, 1).unwrap().correlation_id,
+            Some(CorrelationId::from(rfq_id))
+        );
+
+        event.metadata = event.metadata.with_correlation_id(Some(correlation_id));
+        let stored = StoredEvent::from_event(&event, 1).unwrap();
+        assert_eq!(stored.correlation_id, Some(correlation_id));
+    }
+
+    #[test]
+    fn stored_event_takes_aggregate_version_from_domain_event() {
+        use crate::domain::events::trade_events::SettlementConfirmed;
+        use crate::domain::value_objects::TradeId;
+
+        let trade_id = TradeId::new_v4();
+        let mut event = SettlementConfirmed::off_chain(RfqId::new_v4(), trade_id);
+        assert_eq!(
+            StoredEvent::from_event(&event, 1).unwrap().aggregate_key(),
+            None
+        );
+
+        event.metadata = event.metadata.with_aggregate_version(trade_id.get(), 4);
+        let stored = StoredEvent::from_event(&event, 1).unwrap();
+        assert_eq!(stored.aggregate_key(), Some((trade_id.get(), 4)));
     }
 }
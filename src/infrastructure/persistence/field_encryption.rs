@@ -0,0 +1,515 @@
+//! # Field Encryption
+//!
+//! Application-layer encryption of personal data columns at rest.
+//!
+//! An [`EncryptedString`] holds a value encrypted with AES-256-GCM under
+//! one of the keys of a [`KeyProvider`], in the envelope
+//! `pii:v1:<key id>:<base64 nonce and ciphertext>`. The key ID in the
+//! envelope lets old rows stay readable after the active key changes:
+//! providers keep retired keys for decryption, and re-encrypting a value
+//! moves it to the active key.
+//!
+//! Encrypted columns cannot be searched. A [`blind_index`] — a keyed
+//! HMAC-SHA256 of the normalized value — is stored next to the ciphertext
+//! and supports exact, case-insensitive lookups without revealing the
+//! value. The blind index key is separate from the encryption keys and is
+//! not rotated with them.
+//!
+//! Values stored before encryption was enabled are not envelopes; they are
+//! read back unchanged until they are re-encrypted.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::infrastructure::persistence::field_encryption::{
+//!     EncryptedString, EnvKeyProvider, FieldKey, blind_index,
+//! };
+//!
+//! let key = FieldKey::new("2026-01", [7; 32]).unwrap();
+//! let keys = EnvKeyProvider::new(vec![key], [9; 32]).unwrap();
+//!
+//! let email = EncryptedString::encrypt("ops@acme.example", &keys).unwrap();
+//! assert_eq!(email.key_id(), Some("2026-01"));
+//! assert_eq!(email.decrypt(&keys).unwrap(), "ops@acme.example");
+//!
+//! assert_eq!(
+//!     blind_index("Acme Trading", &keys).unwrap(),
+//!     blind_index(" acme trading", &keys).unwrap(),
+//! );
+//! ```
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::fmt;
+use thiserror::Error;
+
+/// Length in bytes of field encryption and blind index keys.
+pub const FIELD_KEY_LEN: usize = 32;
+
+/// Environment variable listing the encryption keys, active key first.
+pub const KEYS_ENV: &str = "OTC_RFQ_PII_KEYS";
+
+/// Environment variable holding the blind index key.
+pub const BLIND_INDEX_KEY_ENV: &str = "OTC_RFQ_PII_BLIND_INDEX_KEY";
+
+const ENVELOPE_PREFIX: &str = "pii:v1:";
+const NONCE_LEN: usize = 12;
+
+/// Errors from encrypting or decrypting fields.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FieldEncryptionError {
+    /// The keys are missing or malformed.
+    #[error("field encryption misconfigured: {0}")]
+    Configuration(String),
+
+    /// A value was encrypted with a key the provider does not have.
+    #[error("unknown field encryption key: {0}")]
+    UnknownKey(String),
+
+    /// A value could not be decrypted with its key.
+    #[error("encrypted field is corrupt or was encrypted with a different key: {0}")]
+    Corrupt(String),
+}
+
+/// Result type for field encryption.
+pub type FieldEncryptionResult<T> = Result<T, FieldEncryptionError>;
+
+/// A named field encryption key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FieldKey {
+    id: String,
+    material: [u8; FIELD_KEY_LEN],
+}
+
+impl FieldKey {
+    /// Creates a key named `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FieldEncryptionError::Configuration` if `id` is empty or
+    /// contains `:`, `=` or `,`.
+    pub fn new(
+        id: impl Into<String>,
+        material: [u8; FIELD_KEY_LEN],
+    ) -> FieldEncryptionResult<Self> {
+        let id = id.into();
+        if id.is_empty() || id.contains([':', '=', ',']) {
+            return Err(FieldEncryptionError::Configuration(format!(
+                "invalid key ID {id:?}"
+            )));
+        }
+        Ok(Self { id, material })
+    }
+
+    /// Returns the key ID recorded in envelopes.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl fmt::Debug for FieldKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldKey")
+            .field("id", &self.id)
+            .field("material", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Source of field encryption keys.
+///
+/// Implementations backed by a KMS unwrap their keys up front or cache
+/// them: the methods are called for every encrypted value.
+pub trait KeyProvider: Send + Sync + fmt::Debug {
+    /// Returns the key new values are encrypted with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key cannot be obtained.
+    fn active_key(&self) -> FieldEncryptionResult<FieldKey>;
+
+    /// Returns the key named `key_id`, active or retired.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key cannot be obtained.
+    fn key(&self, key_id: &str) -> FieldEncryptionResult<Option<FieldKey>>;
+
+    /// Returns the key blind indexes are computed with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key cannot be obtained.
+    fn blind_index_key(&self) -> FieldEncryptionResult<[u8; FIELD_KEY_LEN]>;
+}
+
+/// [`KeyProvider`] with keys read from the environment.
+///
+/// [`KEYS_ENV`] lists `id=<base64 key>` pairs separated by commas, the
+/// active key first and retired keys still needed for reading after it.
+/// [`BLIND_INDEX_KEY_ENV`] holds the base64 blind index key. Keys are
+/// 32 bytes.
+///
+/// To rotate, prepend a new key to the list, restart, and run
+/// `otc-rfq rotate-keys`; the retired key can be dropped from the list
+/// once it finishes.
+#[derive(Clone)]
+pub struct EnvKeyProvider {
+    keys: Vec<FieldKey>,
+    blind_index_key: [u8; FIELD_KEY_LEN],
+}
+
+impl EnvKeyProvider {
+    /// Creates a provider encrypting with the first of `keys`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FieldEncryptionError::Configuration` if `keys` is empty or
+    /// two keys share an ID.
+    pub fn new(
+        keys: Vec<FieldKey>,
+        blind_index_key: [u8; FIELD_KEY_LEN],
+    ) -> FieldEncryptionResult<Self> {
+        if keys.is_empty() {
+            return Err(FieldEncryptionError::Configuration(
+                "no encryption keys".to_string(),
+            ));
+        }
+        for (i, key) in keys.iter().enumerate() {
+            if keys.iter().skip(i + 1).any(|other| other.id == key.id) {
+                return Err(FieldEncryptionError::Configuration(format!(
+                    "duplicate key ID {}",
+                    key.id
+                )));
+            }
+        }
+        Ok(Self {
+            keys,
+            blind_index_key,
+        })
+    }
+
+    /// Reads the keys from [`KEYS_ENV`] and [`BLIND_INDEX_KEY_ENV`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `FieldEncryptionError::Configuration` if a variable is unset
+    /// or malformed.
+    pub fn from_env() -> FieldEncryptionResult<Self> {
+        let var = |name: &str| {
+            std::env::var(name)
+                .map_err(|_| FieldEncryptionError::Configuration(format!("{name} is not set")))
+        };
+        Self::parse(&var(KEYS_ENV)?, &var(BLIND_INDEX_KEY_ENV)?)
+    }
+
+    /// Parses a key list and a blind index key in the environment format.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FieldEncryptionError::Configuration` if either is malformed.
+    pub fn parse(keys: &str, blind_index_key: &str) -> FieldEncryptionResult<Self> {
+        let keys = keys
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (id, material) = entry.split_once('=').ok_or_else(|| {
+                    FieldEncryptionError::Configuration(
+                        "expected comma-separated id=<base64 key> entries".to_string(),
+                    )
+                })?;
+                FieldKey::new(id.trim(), decode_key(id.trim(), material)?)
+            })
+            .collect::<FieldEncryptionResult<Vec<_>>>()?;
+        Self::new(keys, decode_key("blind index", blind_index_key)?)
+    }
+}
+
+impl fmt::Debug for EnvKeyProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnvKeyProvider")
+            .field("keys", &self.keys)
+            .field("blind_index_key", &"<redacted>")
+            .finish()
+    }
+}
+
+impl KeyProvider for EnvKeyProvider {
+    fn active_key(&self) -> FieldEncryptionResult<FieldKey> {
+        self.keys
+            .first()
+            .cloned()
+            .ok_or_else(|| FieldEncryptionError::Configuration("no encryption keys".to_string()))
+    }
+
+    fn key(&self, key_id: &str) -> FieldEncryptionResult<Option<FieldKey>> {
+        Ok(self.keys.iter().find(|key| key.id == key_id).cloned())
+    }
+
+    fn blind_index_key(&self) -> FieldEncryptionResult<[u8; FIELD_KEY_LEN]> {
+        Ok(self.blind_index_key)
+    }
+}
+
+fn decode_key(name: &str, encoded: &str) -> FieldEncryptionResult<[u8; FIELD_KEY_LEN]> {
+    STANDARD
+        .decode(encoded.trim())
+        .ok()
+        .and_then(|bytes| <[u8; FIELD_KEY_LEN]>::try_from(bytes).ok())
+        .ok_or_else(|| {
+            FieldEncryptionError::Configuration(format!(
+                "key {name} must be {FIELD_KEY_LEN} base64-encoded bytes"
+            ))
+        })
+}
+
+/// A column value encrypted at rest.
+///
+/// Holds the stored form: an envelope, or a plaintext value written before
+/// encryption was enabled.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EncryptedString(String);
+
+impl EncryptedString {
+    /// Encrypts `plaintext` with the active key of `keys`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the active key cannot be obtained.
+    pub fn encrypt(plaintext: &str, keys: &dyn KeyProvider) -> FieldEncryptionResult<Self> {
+        let key = keys.active_key()?;
+        let sealed = seal(&key.material, plaintext).ok_or_else(|| {
+            FieldEncryptionError::Corrupt(format!("cannot encrypt with {}", key.id))
+        })?;
+        Ok(Self(format!("{ENVELOPE_PREFIX}{}:{sealed}", key.id)))
+    }
+
+    /// Wraps a value as read from storage.
+    #[must_use]
+    pub fn from_stored(stored: impl Into<String>) -> Self {
+        Self(stored.into())
+    }
+
+    /// Decrypts the value; a value stored in clear is returned as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FieldEncryptionError::UnknownKey` if `keys` does not have
+    /// the value's key, and `FieldEncryptionError::Corrupt` if the value
+    /// does not decrypt with it.
+    pub fn decrypt(&self, keys: &dyn KeyProvider) -> FieldEncryptionResult<String> {
+        let Some((key_id, sealed)) = self.envelope() else {
+            return Ok(self.0.clone());
+        };
+        let key = keys
+            .key(key_id)?
+            .ok_or_else(|| FieldEncryptionError::UnknownKey(key_id.to_string()))?;
+        open(&key.material, sealed)
+            .ok_or_else(|| FieldEncryptionError::Corrupt(format!("value under key {key_id}")))
+    }
+
+    /// Returns the ID of the key the value is encrypted with, or `None` if
+    /// it is stored in clear.
+    #[must_use]
+    pub fn key_id(&self) -> Option<&str> {
+        self.envelope().map(|(key_id, _)| key_id)
+    }
+
+    /// Returns true if the value is stored encrypted.
+    #[must_use]
+    pub fn is_encrypted(&self) -> bool {
+        self.envelope().is_some()
+    }
+
+    /// Returns the stored form.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the stored form.
+    #[must_use]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    fn envelope(&self) -> Option<(&str, &str)> {
+        self.0.strip_prefix(ENVELOPE_PREFIX)?.split_once(':')
+    }
+}
+
+impl fmt::Debug for EncryptedString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.key_id() {
+            Some(key_id) => write!(f, "EncryptedString(key={key_id})"),
+            None => f.write_str("EncryptedString(<clear>)"),
+        }
+    }
+}
+
+/// Returns the blind index of `value`: a hex HMAC-SHA256 of the value
+/// trimmed and lowercased, for exact case-insensitive lookups.
+///
+/// # Errors
+///
+/// Returns an error if the blind index key cannot be obtained.
+pub fn blind_index(value: &str, keys: &dyn KeyProvider) -> FieldEncryptionResult<String> {
+    let key = keys.blind_index_key()?;
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&key)
+        .map_err(|e| FieldEncryptionError::Configuration(e.to_string()))?;
+    mac.update(value.trim().to_lowercase().as_bytes());
+    Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Encrypts `plaintext` with AES-256-GCM under `material` and a random
+/// nonce, returning the base64 nonce and ciphertext that envelopes carry.
+///
+/// Shared by the envelopes of encrypted columns and of identifiers in
+/// stored events. Returns `None` if encryption fails.
+pub(crate) fn seal(material: &[u8; FIELD_KEY_LEN], plaintext: &str) -> Option<String> {
+    let nonce: [u8; NONCE_LEN] = rand::random();
+    let ciphertext = Aes256Gcm::new(&Key::<Aes256Gcm>::from(*material))
+        .encrypt(&Nonce::from(nonce), plaintext.as_bytes())
+        .ok()?;
+
+    let mut sealed = nonce.to_vec();
+    sealed.extend(ciphertext);
+    Some(STANDARD.encode(sealed))
+}
+
+/// Decrypts what [`seal`] returned.
+///
+/// Returns `None` if `sealed` is corrupt or was sealed under other key
+/// material.
+pub(crate) fn open(material: &[u8; FIELD_KEY_LEN], sealed: &str) -> Option<String> {
+    let bytes = STANDARD.decode(sealed).ok()?;
+    let (nonce, ciphertext) = bytes.split_at_checked(NONCE_LEN)?;
+    let nonce: [u8; NONCE_LEN] = nonce.try_into().ok()?;
+    let plaintext = Aes256Gcm::new(&Key::<Aes256Gcm>::from(*material))
+        .decrypt(&Nonce::from(nonce), ciphertext)
+        .ok()?;
+    String::from_utf8(plaintext).ok()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn key(id: &str, byte: u8) -> FieldKey {
+        FieldKey::new(id, [byte; FIELD_KEY_LEN]).unwrap()
+    }
+
+    fn provider(keys: Vec<FieldKey>) -> EnvKeyProvider {
+        EnvKeyProvider::new(keys, [42; FIELD_KEY_LEN]).unwrap()
+    }
+
+    #[test]
+    fn values_round_trip_under_the_active_key() {
+        let keys = provider(vec![key("k1", 1)]);
+
+        let first = EncryptedString::encrypt("Acme Trading Ltd", &keys).unwrap();
+        let second = EncryptedString::encrypt("Acme Trading Ltd", &keys).unwrap();
+
+        assert_eq!(first.key_id(), Some("k1"));
+        assert!(!first.as_str().contains("Acme"));
+        assert_ne!(first, second);
+        assert_eq!(first.decrypt(&keys).unwrap(), "Acme Trading Ltd");
+        assert_eq!(
+            EncryptedString::from_stored(second.into_inner())
+                .decrypt(&keys)
+                .unwrap(),
+            "Acme Trading Ltd"
+        );
+    }
+
+    #[test]
+    fn wrong_or_missing_keys_fail_to_decrypt() {
+        let value =
+            EncryptedString::encrypt("ops@acme.example", &provider(vec![key("k1", 1)])).unwrap();
+
+        assert_eq!(
+            value.decrypt(&provider(vec![key("k2", 2)])),
+            Err(FieldEncryptionError::UnknownKey("k1".to_string()))
+        );
+        assert!(matches!(
+            value.decrypt(&provider(vec![key("k1", 9)])),
+            Err(FieldEncryptionError::Corrupt(_))
+        ));
+
+        let (_, sealed) = value.envelope().unwrap();
+        let mut bytes = STANDARD.decode(sealed).unwrap();
+        if let Some(last) = bytes.last_mut() {
+            *last ^= 1;
+        }
+        let tampered = format!("{ENVELOPE_PREFIX}k1:{}", STANDARD.encode(bytes));
+        assert!(matches!(
+            EncryptedString::from_stored(tampered).decrypt(&provider(vec![key("k1", 1)])),
+            Err(FieldEncryptionError::Corrupt(_))
+        ));
+    }
+
+    #[test]
+    fn rotation_keeps_old_values_readable() {
+        let before = provider(vec![key("k1", 1)]);
+        let value = EncryptedString::encrypt("0xabc", &before).unwrap();
+
+        let after = provider(vec![key("k2", 2), key("k1", 1)]);
+        let plaintext = value.decrypt(&after).unwrap();
+        let rotated = EncryptedString::encrypt(&plaintext, &after).unwrap();
+
+        assert_eq!(plaintext, "0xabc");
+        assert_eq!(rotated.key_id(), Some("k2"));
+        assert_eq!(
+            rotated.decrypt(&provider(vec![key("k2", 2)])).unwrap(),
+            "0xabc"
+        );
+    }
+
+    #[test]
+    fn clear_values_are_read_as_is() {
+        let value = EncryptedString::from_stored("Legacy Name");
+
+        assert!(!value.is_encrypted());
+        assert_eq!(value.key_id(), None);
+        assert_eq!(
+            value.decrypt(&provider(vec![key("k1", 1)])).unwrap(),
+            "Legacy Name"
+        );
+    }
+
+    #[test]
+    fn blind_index_matches_normalized_values_only() {
+        let keys = provider(vec![key("k1", 1)]);
+        let index = blind_index("Acme Trading", &keys).unwrap();
+
+        assert_eq!(index, blind_index("  ACME trading ", &keys).unwrap());
+        assert_ne!(index, blind_index("Acme Trading 2", &keys).unwrap());
+        assert!(!index.contains("acme"));
+
+        let other = EnvKeyProvider::new(vec![key("k1", 1)], [7; FIELD_KEY_LEN]).unwrap();
+        assert_ne!(index, blind_index("Acme Trading", &other).unwrap());
+    }
+
+    #[test]
+    fn env_format_lists_the_active_key_first() {
+        let k2 = STANDARD.encode([2; FIELD_KEY_LEN]);
+        let k1 = STANDARD.encode([1; FIELD_KEY_LEN]);
+        let index = STANDARD.encode([3; FIELD_KEY_LEN]);
+
+        let keys = EnvKeyProvider::parse(&format!("k2={k2}, k1={k1}"), &index).unwrap();
+
+        assert_eq!(keys.active_key().unwrap().id(), "k2");
+        assert!(keys.key("k1").unwrap().is_some());
+        assert!(EnvKeyProvider::parse(&format!("k2={k2},k2={k1}"), &index).is_err());
+        assert!(EnvKeyProvider::parse("k2=c2hvcnQ=", &index).is_err());
+        assert!(EnvKeyProvider::parse("", &index).is_err());
+    }
+}
@@ -0,0 +1,42 @@
+//! # In-Memory Aggregation Report Repository
+//!
+//! In-memory implementation of [`AggregationReportRepository`].
+//!
+//! This implementation keeps one report per RFQ in a `HashMap` behind a
+//! lock, making it suitable for unit tests and single-node deployments.
+
+use crate::domain::value_objects::{AggregationReport, RfqId};
+use crate::infrastructure::persistence::traits::{AggregationReportRepository, RepositoryResult};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory implementation of [`AggregationReportRepository`].
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryAggregationReportRepository {
+    storage: Arc<RwLock<HashMap<RfqId, AggregationReport>>>,
+}
+
+impl InMemoryAggregationReportRepository {
+    /// Creates a new empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AggregationReportRepository for InMemoryAggregationReportRepository {
+    async fn save(&self, report: &AggregationReport) -> RepositoryResult<()> {
+        self.storage
+            .write()
+            .await
+            .insert(report.rfq_id, report.clone());
+        Ok(())
+    }
+
+    async fn find_by_rfq_id(&self, rfq_id: RfqId) -> RepositoryResult<Option<AggregationReport>> {
+        Ok(self.storage.read().await.get(&rfq_id).cloned())
+    }
+}
@@ -140,7 +140,7 @@ impl NegotiationAuditLog for InMemoryNegotiationAuditLog {
         let mut entries = self
             .entries
             .lock()
-            .map_err(|_| RepositoryError::Connection("Mutex poisoned".to_string()))?;
+            .map_err(|_| RepositoryError::internal("Mutex poisoned"))?;
 
         entries.push(entry);
         Ok(())
@@ -150,7 +150,7 @@ impl NegotiationAuditLog for InMemoryNegotiationAuditLog {
         let entries = self
             .entries
             .lock()
-            .map_err(|_| RepositoryError::Connection("Mutex poisoned".to_string()))?;
+            .map_err(|_| RepositoryError::internal("Mutex poisoned"))?;
 
         let mut trail: Vec<_> = entries
             .iter()
@@ -181,7 +181,7 @@ impl NegotiationAuditLog for InMemoryNegotiationAuditLog {
         let entries = self
             .entries
             .lock()
-            .map_err(|_| RepositoryError::Connection("Mutex poisoned".to_string()))?;
+            .map_err(|_| RepositoryError::internal("Mutex poisoned"))?;
 
         Ok(entries.len() as u64)
     }
@@ -190,7 +190,7 @@ impl NegotiationAuditLog for InMemoryNegotiationAuditLog {
         let entries = self
             .entries
             .lock()
-            .map_err(|_| RepositoryError::Connection("Mutex poisoned".to_string()))?;
+            .map_err(|_| RepositoryError::internal("Mutex poisoned"))?;
 
         let count = entries.iter().filter(|e| e.rfq_id == rfq_id).count();
         Ok(count as u64)
@@ -0,0 +1,59 @@
+//! # In-Memory Best Execution Report Repository
+//!
+//! In-memory implementation of [`BestExecutionReportRepository`].
+//!
+//! This implementation keeps one report per client and period in a
+//! `HashMap` behind a lock, making it suitable for unit tests and
+//! single-node deployments.
+
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{BestExecutionReport, CounterpartyId};
+use crate::infrastructure::persistence::traits::{BestExecutionReportRepository, RepositoryResult};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Storage key: client, period start, period end.
+type ReportKey = (CounterpartyId, Timestamp, Timestamp);
+
+/// In-memory implementation of [`BestExecutionReportRepository`].
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryBestExecutionReportRepository {
+    storage: Arc<RwLock<HashMap<ReportKey, BestExecutionReport>>>,
+}
+
+impl InMemoryBestExecutionReportRepository {
+    /// Creates a new empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BestExecutionReportRepository for InMemoryBestExecutionReportRepository {
+    async fn save(&self, report: &BestExecutionReport) -> RepositoryResult<bool> {
+        let key = (report.client_id.clone(), report.from, report.to);
+        let mut storage = self.storage.write().await;
+        if storage.contains_key(&key) {
+            return Ok(false);
+        }
+        storage.insert(key, report.clone());
+        Ok(true)
+    }
+
+    async fn find(
+        &self,
+        client_id: &CounterpartyId,
+        from: Timestamp,
+        to: Timestamp,
+    ) -> RepositoryResult<Option<BestExecutionReport>> {
+        Ok(self
+            .storage
+            .read()
+            .await
+            .get(&(client_id.clone(), from, to))
+            .cloned())
+    }
+}
@@ -0,0 +1,44 @@
+//! # In-Memory Circuit State Store
+//!
+//! In-memory implementation of [`CircuitStateStore`] for testing.
+
+use crate::application::services::circuit_breaker::{CircuitSnapshot, CircuitStateStore};
+use crate::infrastructure::persistence::traits::{RepositoryError, RepositoryResult};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// In-memory implementation of [`CircuitStateStore`].
+///
+/// Thread-safe for concurrent access within a single process.
+#[derive(Debug, Default)]
+pub struct InMemoryCircuitStateStore {
+    /// Snapshots by circuit name.
+    snapshots: Mutex<HashMap<String, CircuitSnapshot>>,
+}
+
+impl InMemoryCircuitStateStore {
+    /// Creates a new empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(
+        &self,
+    ) -> RepositoryResult<std::sync::MutexGuard<'_, HashMap<String, CircuitSnapshot>>> {
+        self.snapshots
+            .lock()
+            .map_err(|e| RepositoryError::internal(format!("lock poisoned: {e}")))
+    }
+}
+
+impl CircuitStateStore for InMemoryCircuitStateStore {
+    fn load(&self, name: &str) -> RepositoryResult<Option<CircuitSnapshot>> {
+        Ok(self.lock()?.get(name).copied())
+    }
+
+    fn save(&self, name: &str, snapshot: &CircuitSnapshot) -> RepositoryResult<()> {
+        self.lock()?.insert(name.to_string(), *snapshot);
+        Ok(())
+    }
+}
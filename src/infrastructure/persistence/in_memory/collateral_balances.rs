@@ -0,0 +1,145 @@
+//! # In-Memory Collateral Balances
+//!
+//! In-memory implementation of [`CollateralCheckPort`] for testing
+//! without a margin system.
+//!
+//! A trade requires its notional times the initial margin rate. Clients
+//! with no recorded balance get [`CollateralDecision::Unavailable`].
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::CounterpartyId;
+//! use otc_rfq::infrastructure::persistence::in_memory::InMemoryCollateralBalances;
+//! use rust_decimal::Decimal;
+//!
+//! let balances = InMemoryCollateralBalances::new();
+//! balances.set(CounterpartyId::new("client-1"), Decimal::from(50_000));
+//! ```
+
+use crate::application::services::collateral_check::CollateralCheckPort;
+use crate::domain::value_objects::{
+    CollateralDecision, CounterpartyId, Instrument, Price, Quantity,
+};
+use async_trait::async_trait;
+use dashmap::DashMap;
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+/// Default share of the notional required as collateral (10%).
+pub const DEFAULT_INITIAL_MARGIN_RATE: Decimal = Decimal::from_parts(1, 0, 0, false, 1);
+
+/// In-memory collateral balances keyed by counterparty.
+#[derive(Debug, Clone)]
+pub struct InMemoryCollateralBalances {
+    balances: Arc<DashMap<CounterpartyId, Decimal>>,
+    initial_margin_rate: Decimal,
+}
+
+impl InMemoryCollateralBalances {
+    /// Creates an empty set of balances with the default margin rate.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            balances: Arc::new(DashMap::new()),
+            initial_margin_rate: DEFAULT_INITIAL_MARGIN_RATE,
+        }
+    }
+
+    /// Sets the share of the notional required as collateral.
+    #[must_use]
+    pub fn with_initial_margin_rate(mut self, initial_margin_rate: Decimal) -> Self {
+        self.initial_margin_rate = initial_margin_rate;
+        self
+    }
+
+    /// Replaces the collateral available to `counterparty`.
+    pub fn set(&self, counterparty: CounterpartyId, available: Decimal) {
+        self.balances.insert(counterparty, available);
+    }
+
+    /// Removes the balance for `counterparty`.
+    pub fn remove(&self, counterparty: &CounterpartyId) {
+        self.balances.remove(counterparty);
+    }
+}
+
+impl Default for InMemoryCollateralBalances {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CollateralCheckPort for InMemoryCollateralBalances {
+    async fn check(
+        &self,
+        counterparty: &CounterpartyId,
+        _instrument: &Instrument,
+        quantity: Quantity,
+        price: Price,
+    ) -> CollateralDecision {
+        let Some(available) = self.balances.get(counterparty).map(|balance| *balance) else {
+            return CollateralDecision::Unavailable;
+        };
+        let Some(required) = price
+            .get()
+            .checked_mul(quantity.get())
+            .and_then(|notional| notional.checked_mul(self.initial_margin_rate))
+        else {
+            return CollateralDecision::Unavailable;
+        };
+
+        if available >= required {
+            CollateralDecision::Approved { available }
+        } else {
+            CollateralDecision::Insufficient {
+                required,
+                available,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::Symbol;
+    use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
+
+    #[tokio::test]
+    async fn decides_from_balance_and_margin_rate() {
+        let balances = InMemoryCollateralBalances::new();
+        let client = CounterpartyId::new("client-1");
+        let instrument = Instrument::new(
+            Symbol::new("BTC/USD").unwrap(),
+            AssetClass::CryptoDerivs,
+            SettlementMethod::default(),
+        );
+        let price = Price::new(50_000.0).unwrap();
+        let quantity = Quantity::new(2.0).unwrap();
+
+        assert_eq!(
+            balances.check(&client, &instrument, quantity, price).await,
+            CollateralDecision::Unavailable
+        );
+
+        balances.set(client.clone(), Decimal::from(10_000));
+        assert_eq!(
+            balances.check(&client, &instrument, quantity, price).await,
+            CollateralDecision::Approved {
+                available: Decimal::from(10_000)
+            }
+        );
+
+        balances.set(client.clone(), Decimal::from(9_999));
+        assert_eq!(
+            balances.check(&client, &instrument, quantity, price).await,
+            CollateralDecision::Insufficient {
+                required: Decimal::from(10_000),
+                available: Decimal::from(9_999),
+            }
+        );
+    }
+}
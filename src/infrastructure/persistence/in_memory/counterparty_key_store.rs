@@ -0,0 +1,77 @@
+//! # In-Memory Counterparty Key Store
+//!
+//! In-memory implementation of [`CounterpartyKeyStore`].
+//!
+//! Keys live only as long as the process, so events encrypted with them
+//! cannot be read after a restart. Suitable for unit tests.
+
+use crate::domain::value_objects::CounterpartyId;
+use crate::infrastructure::persistence::counterparty_keys::{
+    CounterpartyKey, CounterpartyKeyStore, KeyLookup,
+};
+use crate::infrastructure::persistence::traits::RepositoryResult;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+#[derive(Debug, Default)]
+struct Keys {
+    active: HashMap<CounterpartyId, CounterpartyKey>,
+    shredded: HashMap<Uuid, CounterpartyId>,
+}
+
+/// In-memory implementation of [`CounterpartyKeyStore`].
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryCounterpartyKeyStore {
+    keys: Arc<RwLock<Keys>>,
+}
+
+impl InMemoryCounterpartyKeyStore {
+    /// Creates a new empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CounterpartyKeyStore for InMemoryCounterpartyKeyStore {
+    async fn get_or_create(
+        &self,
+        counterparty_id: &CounterpartyId,
+    ) -> RepositoryResult<CounterpartyKey> {
+        let mut keys = self.keys.write().await;
+        Ok(keys
+            .active
+            .entry(counterparty_id.clone())
+            .or_insert_with(CounterpartyKey::generate)
+            .clone())
+    }
+
+    async fn lookup(&self, key_id: Uuid) -> RepositoryResult<KeyLookup> {
+        let keys = self.keys.read().await;
+        if let Some(pseudonym) = keys.shredded.get(&key_id) {
+            return Ok(KeyLookup::Shredded(pseudonym.clone()));
+        }
+        Ok(keys
+            .active
+            .values()
+            .find(|key| key.key_id == key_id)
+            .map_or(KeyLookup::Unknown, |key| KeyLookup::Active(key.clone())))
+    }
+
+    async fn shred(
+        &self,
+        counterparty_id: &CounterpartyId,
+        pseudonym: &CounterpartyId,
+    ) -> RepositoryResult<bool> {
+        let mut keys = self.keys.write().await;
+        let Some(key) = keys.active.remove(counterparty_id) else {
+            return Ok(false);
+        };
+        keys.shredded.insert(key.key_id, pseudonym.clone());
+        Ok(true)
+    }
+}
@@ -0,0 +1,72 @@
+//! # In-Memory Dead Letter Store
+//!
+//! In-memory implementation of [`DeadLetterStore`] for testing.
+
+use crate::domain::value_objects::EventId;
+use crate::infrastructure::persistence::dead_letter::{DeadLetter, DeadLetterStore};
+use crate::infrastructure::persistence::traits::{RepositoryError, RepositoryResult};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+type Letters = HashMap<(String, EventId), DeadLetter>;
+
+/// In-memory implementation of [`DeadLetterStore`].
+///
+/// Thread-safe for concurrent access within a single process.
+#[derive(Debug, Default)]
+pub struct InMemoryDeadLetterStore {
+    letters: Mutex<Letters>,
+}
+
+impl InMemoryDeadLetterStore {
+    /// Creates a new empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> RepositoryResult<MutexGuard<'_, Letters>> {
+        self.letters
+            .lock()
+            .map_err(|e| RepositoryError::internal(format!("lock poisoned: {e}")))
+    }
+}
+
+#[async_trait]
+impl DeadLetterStore for InMemoryDeadLetterStore {
+    async fn save(&self, letter: &DeadLetter) -> RepositoryResult<()> {
+        self.lock()?
+            .insert((letter.consumer.clone(), letter.event_id()), letter.clone());
+        Ok(())
+    }
+
+    async fn find(
+        &self,
+        consumer: &str,
+        event_id: EventId,
+    ) -> RepositoryResult<Option<DeadLetter>> {
+        Ok(self.lock()?.get(&(consumer.to_string(), event_id)).cloned())
+    }
+
+    async fn list_parked(
+        &self,
+        consumer: Option<&str>,
+        limit: usize,
+    ) -> RepositoryResult<Vec<DeadLetter>> {
+        let mut parked: Vec<DeadLetter> = self
+            .lock()?
+            .values()
+            .filter(|letter| letter.is_parked())
+            .filter(|letter| consumer.is_none_or(|consumer| letter.consumer == consumer))
+            .cloned()
+            .collect();
+        parked.sort_by(|a, b| {
+            a.first_seen_at
+                .cmp(&b.first_seen_at)
+                .then_with(|| a.event_id().get().cmp(&b.event_id().get()))
+        });
+        parked.truncate(limit);
+        Ok(parked)
+    }
+}
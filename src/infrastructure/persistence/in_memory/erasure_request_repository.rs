@@ -0,0 +1,64 @@
+//! # In-Memory Erasure Request Repository
+//!
+//! In-memory implementation of [`ErasureRequestRepository`].
+//!
+//! This implementation uses a thread-safe `HashMap` for storage,
+//! making it suitable for unit tests and single-node deployments.
+
+use crate::domain::entities::erasure_request::ErasureRequest;
+use crate::domain::value_objects::{CounterpartyId, ErasureRequestId};
+use crate::infrastructure::persistence::traits::{ErasureRequestRepository, RepositoryResult};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory implementation of [`ErasureRequestRepository`].
+#[derive(Debug, Clone)]
+pub struct InMemoryErasureRequestRepository {
+    storage: Arc<RwLock<HashMap<ErasureRequestId, ErasureRequest>>>,
+}
+
+impl InMemoryErasureRequestRepository {
+    /// Creates a new empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            storage: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Default for InMemoryErasureRequestRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ErasureRequestRepository for InMemoryErasureRequestRepository {
+    async fn save(&self, request: &ErasureRequest) -> RepositoryResult<()> {
+        let mut storage = self.storage.write().await;
+        storage.insert(request.id(), request.clone());
+        Ok(())
+    }
+
+    async fn get(&self, id: ErasureRequestId) -> RepositoryResult<Option<ErasureRequest>> {
+        let storage = self.storage.read().await;
+        Ok(storage.get(&id).cloned())
+    }
+
+    async fn find_by_counterparty(
+        &self,
+        counterparty_id: &CounterpartyId,
+    ) -> RepositoryResult<Vec<ErasureRequest>> {
+        let storage = self.storage.read().await;
+        let mut requests: Vec<ErasureRequest> = storage
+            .values()
+            .filter(|request| request.counterparty_id() == counterparty_id)
+            .cloned()
+            .collect();
+        requests.sort_by_key(|request| std::cmp::Reverse(request.created_at()));
+        Ok(requests)
+    }
+}
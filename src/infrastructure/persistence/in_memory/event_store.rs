@@ -0,0 +1,419 @@
+//! # In-Memory Event Store
+//!
+//! In-memory implementation of [`EventStore`] for testing without database
+//! dependencies.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::infrastructure::persistence::in_memory::InMemoryEventStore;
+//!
+//! let store = InMemoryEventStore::new();
+//! ```
+
+use crate::domain::events::domain_event::EventType;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{CorrelationId, RfqId};
+use crate::infrastructure::persistence::cursor::{PageCursor, paginate_oldest_first};
+use crate::infrastructure::persistence::event_store::{EventStore, EventStoreResult, StoredEvent};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// In-memory implementation of the event store.
+///
+/// Events are kept in append order. Suitable for testing and development
+/// environments.
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryEventStore {
+    events: Arc<RwLock<Vec<StoredEvent>>>,
+}
+
+impl InMemoryEventStore {
+    /// Creates a new empty in-memory event store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the events matching `predicate`, in append order.
+    async fn filtered(&self, predicate: impl Fn(&StoredEvent) -> bool) -> Vec<StoredEvent> {
+        self.events
+            .read()
+            .await
+            .iter()
+            .filter(|e| predicate(e))
+            .cloned()
+            .collect()
+    }
+}
+
+#[async_trait]
+impl EventStore for InMemoryEventStore {
+    async fn append(&self, event: StoredEvent) -> EventStoreResult<()> {
+        // Check and push under one lock so concurrent appends of the same
+        // sequence number cannot both pass.
+        let mut events = self.events.write().await;
+        if let Some(aggregate_id) = event.aggregate_id {
+            let last_sequence = events
+                .iter()
+                .filter(|e| e.aggregate_id == Some(aggregate_id))
+                .map(|e| e.aggregate_sequence)
+                .max()
+                .unwrap_or(0);
+            event.check_follows(last_sequence)?;
+        }
+        events.push(event);
+        Ok(())
+    }
+
+    async fn get_events(&self, rfq_id: RfqId) -> EventStoreResult<Vec<StoredEvent>> {
+        let mut events = self.filtered(|e| e.rfq_id == Some(rfq_id)).await;
+        events.sort_by_key(|e| e.sequence);
+        Ok(events)
+    }
+
+    async fn get_events_since(&self, since: Timestamp) -> EventStoreResult<Vec<StoredEvent>> {
+        let mut events = self.filtered(|e| e.timestamp > since).await;
+        events.sort_by_key(|e| (e.timestamp, e.sequence));
+        Ok(events)
+    }
+
+    async fn get_events_by_type(
+        &self,
+        event_type: EventType,
+    ) -> EventStoreResult<Vec<StoredEvent>> {
+        let mut events = self.filtered(|e| e.event_type == event_type).await;
+        events.sort_by_key(|e| (e.timestamp, e.sequence));
+        Ok(events)
+    }
+
+    async fn query_by_time_range(
+        &self,
+        from: Timestamp,
+        to: Timestamp,
+        event_types: Option<Vec<EventType>>,
+        limit: usize,
+        cursor: Option<&PageCursor>,
+    ) -> EventStoreResult<Vec<StoredEvent>> {
+        // Compare at the millisecond precision the PostgreSQL store keeps.
+        let (from, to) = (from.timestamp_millis(), to.timestamp_millis());
+        let events = self
+            .filtered(|e| {
+                let at = e.timestamp.timestamp_millis();
+                at >= from
+                    && at < to
+                    && event_types
+                        .as_ref()
+                        .is_none_or(|types| types.contains(&e.event_type))
+            })
+            .await;
+        Ok(paginate_oldest_first(
+            events,
+            cursor,
+            limit,
+            PageCursor::from_event,
+        ))
+    }
+
+    async fn query_by_correlation(
+        &self,
+        correlation_id: CorrelationId,
+    ) -> EventStoreResult<Vec<StoredEvent>> {
+        let mut events = self
+            .filtered(|e| e.correlation_id == Some(correlation_id))
+            .await;
+        events.sort_by_key(|e| (e.timestamp.timestamp_millis(), e.sequence));
+        Ok(events)
+    }
+
+    async fn count(&self) -> EventStoreResult<u64> {
+        Ok(self.events.read().await.len() as u64)
+    }
+
+    async fn count_for_rfq(&self, rfq_id: RfqId) -> EventStoreResult<u64> {
+        Ok(self.filtered(|e| e.rfq_id == Some(rfq_id)).await.len() as u64)
+    }
+
+    async fn next_sequence(&self, rfq_id: RfqId) -> EventStoreResult<u64> {
+        Ok(self
+            .filtered(|e| e.rfq_id == Some(rfq_id))
+            .await
+            .iter()
+            .map(|e| e.sequence)
+            .max()
+            .map_or(1, |max| max.saturating_add(1)))
+    }
+
+    async fn next_aggregate_sequence(&self, aggregate_id: Uuid) -> EventStoreResult<u64> {
+        Ok(self
+            .filtered(|e| e.aggregate_id == Some(aggregate_id))
+            .await
+            .iter()
+            .map(|e| e.aggregate_sequence)
+            .max()
+            .map_or(1, |max| max.saturating_add(1)))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::EventId;
+
+    fn event(rfq_id: RfqId, event_type: EventType, sequence: u64) -> StoredEvent {
+        StoredEvent::new(
+            EventId::new_v4(),
+            Some(rfq_id),
+            event_type,
+            "TestEvent",
+            Timestamp::now(),
+            serde_json::json!({}),
+            sequence,
+        )
+    }
+
+    #[tokio::test]
+    async fn get_events_filters_by_rfq_in_sequence_order() {
+        let store = InMemoryEventStore::new();
+        let rfq_id = RfqId::new_v4();
+        store
+            .append(event(rfq_id, EventType::Rfq, 2))
+            .await
+            .unwrap();
+        store
+            .append(event(rfq_id, EventType::Rfq, 1))
+            .await
+            .unwrap();
+        store
+            .append(event(RfqId::new_v4(), EventType::Rfq, 1))
+            .await
+            .unwrap();
+
+        let events = store.get_events(rfq_id).await.unwrap();
+        let sequences: Vec<u64> = events.iter().map(|e| e.sequence).collect();
+        assert_eq!(sequences, vec![1, 2]);
+        assert_eq!(store.count().await.unwrap(), 3);
+        assert_eq!(store.count_for_rfq(rfq_id).await.unwrap(), 2);
+        assert_eq!(store.next_sequence(rfq_id).await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn append_enforces_consecutive_aggregate_sequences() {
+        use crate::infrastructure::persistence::event_store::EventStoreError;
+
+        let store = InMemoryEventStore::new();
+        let rfq_id = RfqId::new_v4();
+        // Changes that emit no event leave gaps between versions, but not
+        // between sequence numbers.
+        let versioned = |sequence: u64| {
+            event(rfq_id, EventType::Rfq, sequence)
+                .with_aggregate_version(rfq_id.get(), sequence * 2)
+                .with_aggregate_sequence(sequence)
+        };
+        assert_eq!(
+            store.next_aggregate_sequence(rfq_id.get()).await.unwrap(),
+            1
+        );
+        store.append(versioned(1)).await.unwrap();
+        store.append(versioned(2)).await.unwrap();
+        assert_eq!(
+            store.next_aggregate_sequence(rfq_id.get()).await.unwrap(),
+            3
+        );
+
+        let duplicate = store.append(versioned(2)).await;
+        assert!(matches!(
+            duplicate,
+            Err(EventStoreError::VersionConflict {
+                expected: 3,
+                actual: 2,
+                ..
+            })
+        ));
+        let gap = store.append(versioned(4)).await;
+        assert!(matches!(
+            gap,
+            Err(EventStoreError::VersionConflict {
+                expected: 3,
+                actual: 4,
+                ..
+            })
+        ));
+        assert_eq!(store.count().await.unwrap(), 2);
+
+        // Other aggregates and unversioned events are unaffected.
+        let trade_id = Uuid::new_v4();
+        store
+            .append(
+                event(rfq_id, EventType::Trade, 3)
+                    .with_aggregate_version(trade_id, 1)
+                    .with_aggregate_sequence(1),
+            )
+            .await
+            .unwrap();
+        store
+            .append(event(rfq_id, EventType::Compliance, 4))
+            .await
+            .unwrap();
+        store.append(versioned(3)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn get_events_by_type_filters() {
+        let store = InMemoryEventStore::new();
+        let rfq_id = RfqId::new_v4();
+        store
+            .append(event(rfq_id, EventType::Rfq, 1))
+            .await
+            .unwrap();
+        store
+            .append(event(rfq_id, EventType::Trade, 2))
+            .await
+            .unwrap();
+
+        let trades = store.get_events_by_type(EventType::Trade).await.unwrap();
+        assert_eq!(trades.len(), 1);
+        assert_eq!(
+            store.next_sequence(RfqId::new_v4()).await.unwrap(),
+            1,
+            "unknown RFQs start at sequence 1"
+        );
+    }
+
+    #[tokio::test]
+    async fn correlation_links_rfq_through_settlement() {
+        use crate::domain::events::compliance_events::ComplianceCheckPassed;
+        use crate::domain::events::rfq_events::RfqCreated;
+        use crate::domain::events::trade_events::{
+            SettlementConfirmed, SettlementInitiated, TradeExecuted,
+        };
+        use crate::domain::value_objects::{
+            AssetClass, CounterpartyId, Instrument, OrderSide, Price, Quantity, QuoteId,
+            SettlementMethod, Symbol, TradeId, VenueId,
+        };
+
+        let store = InMemoryEventStore::new();
+        let rfq_id = RfqId::new_v4();
+        let trade_id = TradeId::new_v4();
+        let client = CounterpartyId::new("client-1");
+        let instrument =
+            Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build();
+
+        let chain = [
+            StoredEvent::from_event(
+                &RfqCreated::new(
+                    rfq_id,
+                    client.clone(),
+                    instrument,
+                    OrderSide::Buy,
+                    Quantity::new(1.0).unwrap(),
+                    Timestamp::now().add_secs(60),
+                ),
+                1,
+            ),
+            StoredEvent::from_event(&ComplianceCheckPassed::kyc(rfq_id, client.clone()), 2),
+            StoredEvent::from_event(
+                &TradeExecuted::builder()
+                    .rfq_id(rfq_id)
+                    .trade_id(trade_id)
+                    .quote_id(QuoteId::new_v4())
+                    .venue_id(VenueId::new("venue-1"))
+                    .counterparty_id(client)
+                    .price(Price::new(50000.0).unwrap())
+                    .quantity(Quantity::new(1.0).unwrap())
+                    .settlement_method(SettlementMethod::OffChain)
+                    .build(),
+                3,
+            ),
+            StoredEvent::from_event(&SettlementInitiated::off_chain(rfq_id, trade_id), 4),
+            StoredEvent::from_event(&SettlementConfirmed::off_chain(rfq_id, trade_id), 5),
+        ];
+        for event in chain {
+            store.append(event.unwrap()).await.unwrap();
+        }
+        store
+            .append(event(RfqId::new_v4(), EventType::Rfq, 1))
+            .await
+            .unwrap();
+
+        let events = store
+            .query_by_correlation(CorrelationId::from(rfq_id))
+            .await
+            .unwrap();
+        let names: Vec<&str> = events.iter().map(|e| e.event_name.as_str()).collect();
+        assert_eq!(names.len(), 5);
+        assert_eq!(names.first(), Some(&"RfqCreated"));
+        assert_eq!(names.last(), Some(&"SettlementConfirmed"));
+        assert!(names.contains(&"ComplianceCheckPassed"));
+        assert!(names.contains(&"TradeExecuted"));
+        assert!(
+            store
+                .query_by_correlation(CorrelationId::new_v4())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn time_range_pages_are_stable_under_appends() {
+        let store = InMemoryEventStore::new();
+        let base = Timestamp::from_millis(1_700_000_000_000).unwrap();
+        let at = |offset: i64, event_type: EventType| {
+            let mut e = event(RfqId::new_v4(), event_type, 1);
+            e.timestamp = base.add_millis(offset);
+            e
+        };
+        for (offset, event_type) in [
+            (0, EventType::Rfq),
+            (10, EventType::Trade),
+            (10, EventType::Rfq),
+            (20, EventType::Settlement),
+            (30, EventType::Rfq),
+            (40, EventType::Rfq),
+        ] {
+            store.append(at(offset, event_type)).await.unwrap();
+        }
+        let (from, to) = (base, base.add_millis(40));
+
+        let first = store
+            .query_by_time_range(from, to, None, 2, None)
+            .await
+            .unwrap();
+        assert_eq!(first.len(), 2);
+
+        // Appends behind the cursor must not shift later pages; appends
+        // ahead of it show up in order.
+        store.append(at(5, EventType::Rfq)).await.unwrap();
+        store.append(at(35, EventType::Trade)).await.unwrap();
+        let cursor = PageCursor::from_event(first.last().unwrap());
+        let second = store
+            .query_by_time_range(from, to, None, 2, Some(&cursor))
+            .await
+            .unwrap();
+        let cursor = PageCursor::from_event(second.last().unwrap());
+        let third = store
+            .query_by_time_range(from, to, None, 2, Some(&cursor))
+            .await
+            .unwrap();
+
+        let paged: Vec<StoredEvent> = first.into_iter().chain(second).chain(third).collect();
+        let offsets: Vec<i64> = paged
+            .iter()
+            .map(|e| e.timestamp.timestamp_millis() - base.timestamp_millis())
+            .collect();
+        assert_eq!(offsets, vec![0, 10, 10, 20, 30, 35]);
+        let unique: std::collections::HashSet<_> = paged.iter().map(|e| e.event_id).collect();
+        assert_eq!(unique.len(), paged.len(), "pages repeated an event");
+
+        let rfq_only = store
+            .query_by_time_range(from, to, Some(vec![EventType::Rfq]), 10, None)
+            .await
+            .unwrap();
+        assert!(rfq_only.iter().all(|e| e.event_type == EventType::Rfq));
+        assert_eq!(rfq_only.len(), 4);
+    }
+}
@@ -0,0 +1,108 @@
+//! # In-Memory Fee Waiver Repository
+//!
+//! In-memory implementation of [`FeeWaiverRepository`].
+//!
+//! This implementation uses a thread-safe `HashMap` for storage,
+//! making it suitable for unit tests and single-node deployments.
+
+use crate::domain::entities::platform_fee::FeeWaiver;
+use crate::domain::value_objects::CounterpartyId;
+use crate::infrastructure::persistence::traits::{FeeWaiverRepository, RepositoryResult};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory implementation of [`FeeWaiverRepository`].
+#[derive(Debug, Clone)]
+pub struct InMemoryFeeWaiverRepository {
+    storage: Arc<RwLock<HashMap<CounterpartyId, FeeWaiver>>>,
+}
+
+impl InMemoryFeeWaiverRepository {
+    /// Creates a new empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            storage: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the number of waivers in the repository.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.storage
+            .try_read()
+            .map(|guard| guard.len())
+            .unwrap_or(0)
+    }
+
+    /// Returns true if the repository is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for InMemoryFeeWaiverRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl FeeWaiverRepository for InMemoryFeeWaiverRepository {
+    async fn save(&self, waiver: &FeeWaiver) -> RepositoryResult<()> {
+        let mut storage = self.storage.write().await;
+        storage.insert(waiver.counterparty_id().clone(), waiver.clone());
+        Ok(())
+    }
+
+    async fn find(&self, counterparty: &CounterpartyId) -> RepositoryResult<Option<FeeWaiver>> {
+        let storage = self.storage.read().await;
+        Ok(storage.get(counterparty).cloned())
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<FeeWaiver>> {
+        let storage = self.storage.read().await;
+        let mut all: Vec<FeeWaiver> = storage.values().cloned().collect();
+        all.sort_by(|a, b| {
+            a.counterparty_id()
+                .as_str()
+                .cmp(b.counterparty_id().as_str())
+        });
+        Ok(all)
+    }
+
+    async fn delete(&self, counterparty: &CounterpartyId) -> RepositoryResult<bool> {
+        let mut storage = self.storage.write().await;
+        Ok(storage.remove(counterparty).is_some())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    #[tokio::test]
+    async fn save_replaces_and_delete_removes() {
+        let repo = InMemoryFeeWaiverRepository::new();
+        let cp = CounterpartyId::new("client-1");
+        repo.save(&FeeWaiver::new(cp.clone(), Decimal::TEN, None).unwrap())
+            .await
+            .unwrap();
+        repo.save(&FeeWaiver::new(cp.clone(), Decimal::ONE_HUNDRED, None).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(repo.len(), 1);
+        let stored = repo.find(&cp).await.unwrap().unwrap();
+        assert_eq!(stored.discount_pct(), Decimal::ONE_HUNDRED);
+
+        assert!(repo.delete(&cp).await.unwrap());
+        assert!(!repo.delete(&cp).await.unwrap());
+        assert!(repo.find_all().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,139 @@
+//! # In-Memory Instrument Reference Data Repository
+//!
+//! In-memory implementation of [`InstrumentReferenceDataRepository`].
+//!
+//! This implementation uses a thread-safe `HashMap` for storage,
+//! making it suitable for unit tests and single-node deployments.
+
+use crate::domain::value_objects::{InstrumentReferenceData, Symbol};
+use crate::infrastructure::persistence::traits::{
+    InstrumentReferenceDataRepository, RepositoryResult,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory implementation of [`InstrumentReferenceDataRepository`].
+#[derive(Debug, Clone)]
+pub struct InMemoryInstrumentReferenceDataRepository {
+    storage: Arc<RwLock<HashMap<Symbol, InstrumentReferenceData>>>,
+}
+
+impl InMemoryInstrumentReferenceDataRepository {
+    /// Creates a new empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            storage: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the number of instruments in the repository.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.storage
+            .try_read()
+            .map(|guard| guard.len())
+            .unwrap_or(0)
+    }
+
+    /// Returns true if the repository is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for InMemoryInstrumentReferenceDataRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl InstrumentReferenceDataRepository for InMemoryInstrumentReferenceDataRepository {
+    async fn save(&self, data: &InstrumentReferenceData) -> RepositoryResult<()> {
+        let mut storage = self.storage.write().await;
+        storage.insert(data.symbol().clone(), data.clone());
+        Ok(())
+    }
+
+    async fn get(&self, symbol: &Symbol) -> RepositoryResult<Option<InstrumentReferenceData>> {
+        let storage = self.storage.read().await;
+        Ok(storage.get(symbol).cloned())
+    }
+
+    async fn get_all(&self) -> RepositoryResult<Vec<InstrumentReferenceData>> {
+        let storage = self.storage.read().await;
+        let mut all: Vec<InstrumentReferenceData> = storage.values().cloned().collect();
+        all.sort_by(|a, b| a.symbol().as_str().cmp(b.symbol().as_str()));
+        Ok(all)
+    }
+
+    async fn delete(&self, symbol: &Symbol) -> RepositoryResult<bool> {
+        let mut storage = self.storage.write().await;
+        Ok(storage.remove(symbol).is_some())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    fn reference_data(symbol: &str, tick_size: Decimal) -> InstrumentReferenceData {
+        InstrumentReferenceData::new(Symbol::new(symbol).unwrap(), tick_size, Decimal::ONE).unwrap()
+    }
+
+    #[tokio::test]
+    async fn save_get_and_replace() {
+        let repo = InMemoryInstrumentReferenceDataRepository::new();
+        let symbol = Symbol::new("BTC/USD").unwrap();
+
+        repo.save(&reference_data("BTC/USD", Decimal::ONE))
+            .await
+            .unwrap();
+        repo.save(&reference_data("BTC/USD", Decimal::TEN))
+            .await
+            .unwrap();
+
+        assert_eq!(repo.len(), 1);
+        let stored = repo.get(&symbol).await.unwrap().unwrap();
+        assert_eq!(stored.tick_size(), Decimal::TEN);
+    }
+
+    #[tokio::test]
+    async fn get_all_is_ordered_by_symbol() {
+        let repo = InMemoryInstrumentReferenceDataRepository::new();
+        repo.save(&reference_data("ETH/USD", Decimal::ONE))
+            .await
+            .unwrap();
+        repo.save(&reference_data("BTC/USD", Decimal::ONE))
+            .await
+            .unwrap();
+
+        let symbols: Vec<String> = repo
+            .get_all()
+            .await
+            .unwrap()
+            .iter()
+            .map(|d| d.symbol().to_string())
+            .collect();
+        assert_eq!(symbols, vec!["BTC/USD", "ETH/USD"]);
+    }
+
+    #[tokio::test]
+    async fn delete_reports_whether_it_existed() {
+        let repo = InMemoryInstrumentReferenceDataRepository::new();
+        let symbol = Symbol::new("BTC/USD").unwrap();
+        repo.save(&reference_data("BTC/USD", Decimal::ONE))
+            .await
+            .unwrap();
+
+        assert!(repo.delete(&symbol).await.unwrap());
+        assert!(!repo.delete(&symbol).await.unwrap());
+        assert!(repo.get(&symbol).await.unwrap().is_none());
+    }
+}
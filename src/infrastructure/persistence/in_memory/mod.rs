@@ -5,38 +5,104 @@
 //! ## Available Repositories
 //!
 //! - [`InMemoryRfqRepository`]: RFQ persistence
+//! - [`InMemoryAggregationReportRepository`]: Per-venue quote aggregation reports
+//! - [`InMemoryBestExecutionReportRepository`]: Best execution report snapshots
 //! - [`InMemoryTradeRepository`]: Trade persistence
 //! - [`InMemoryVenueRepository`]: Venue configuration persistence
 //! - [`InMemoryCounterpartyRepository`]: Counterparty persistence
+//! - [`InMemoryCounterpartyKeyStore`]: Per-counterparty event encryption keys
+//! - [`InMemoryErasureRequestRepository`]: Counterparty erasure request persistence
+//! - [`InMemoryVenueCredentialRepository`]: Counterparty venue credential persistence
 //! - [`InMemoryMmPerformanceRepository`]: MM performance event persistence
 //! - [`InMemoryQuoteLockRepository`]: Quote locking for acceptance flow
 //! - [`InMemoryNegotiationAuditLog`]: Negotiation audit log with μs precision
+//! - [`InMemoryRawExchangeLog`]: Raw venue exchange log
+//! - [`InMemoryCircuitStateStore`]: Circuit breaker state
+//! - [`InMemoryRfqSummaryStore`]: RFQ dashboard summary rows
 //! - [`InMemoryBlockTradeRepository`]: Block trade persistence
+//! - [`InMemoryInstrumentReferenceDataRepository`]: Instrument reference data persistence
+//! - [`InMemoryRfqTemplateRepository`]: RFQ template persistence
+//! - [`InMemoryPlatformFeeScheduleRepository`]: Platform fee schedule persistence
+//! - [`InMemoryFeeWaiverRepository`]: Fee waiver persistence
+//! - [`InMemoryPriceBoundsConfigRepository`]: Price bounds settings and their audit trail
+//! - [`InMemoryNegotiationRepository`]: Negotiation persistence
+//! - [`InMemoryNettingBatchRepository`]: Settlement netting batch persistence
+//! - [`InMemoryTradingCalendarRepository`]: Trading calendar persistence
+//! - [`InMemoryWebhookSubscriptionRepository`]: Webhook subscription persistence
+//! - [`InMemoryWebhookDeliveryLog`]: Webhook delivery log
+//! - [`InMemoryEventStore`]: Append-only domain event storage
+//! - [`InMemoryDeadLetterStore`]: Events parked by failing consumers
+//! - [`InMemoryOrderBookSnapshots`]: Order book snapshots for reference prices
+//! - [`InMemoryCollateralBalances`]: Client collateral for pre-execution margin checks
 //!
 //! ## Thread Safety
 //!
 //! All implementations use appropriate synchronization primitives (e.g. `Arc<RwLock<HashMap<_>>>`,
 //! `DashMap`, or `Mutex<Vec<_>>`) for thread-safe access.
 
+pub mod aggregation_report_repository;
 pub mod audit_log_repository;
+pub mod best_execution_report_repository;
 pub mod block_trade_repository;
+pub mod circuit_state_store;
+pub mod collateral_balances;
+pub mod counterparty_key_store;
 pub mod counterparty_repository;
+pub mod dead_letter_store;
 pub mod delayed_report_repository;
+pub mod erasure_request_repository;
+pub mod event_store;
+pub mod fee_waiver_repository;
+pub mod instrument_reference_data_repository;
 pub mod mm_performance_repository;
 pub mod mock_services;
+pub mod negotiation_repository;
+pub mod netting_batch_repository;
+pub mod order_book_snapshots;
+pub mod platform_fee_schedule_repository;
+pub mod price_bounds_config_repository;
 pub mod quote_lock_repository;
+pub mod raw_exchange_log;
 pub mod rfq_repository;
+pub mod rfq_summary_store;
+pub mod rfq_template_repository;
 pub mod trade_repository;
+pub mod trading_calendar_repository;
+pub mod venue_credential_repository;
 pub mod venue_repository;
+pub mod webhook_delivery_log;
+pub mod webhook_subscription_repository;
 
 pub use super::traits::BlockTradeRepository;
+pub use aggregation_report_repository::InMemoryAggregationReportRepository;
 pub use audit_log_repository::InMemoryNegotiationAuditLog;
+pub use best_execution_report_repository::InMemoryBestExecutionReportRepository;
 pub use block_trade_repository::InMemoryBlockTradeRepository;
+pub use circuit_state_store::InMemoryCircuitStateStore;
+pub use collateral_balances::InMemoryCollateralBalances;
+pub use counterparty_key_store::InMemoryCounterpartyKeyStore;
 pub use counterparty_repository::InMemoryCounterpartyRepository;
+pub use dead_letter_store::InMemoryDeadLetterStore;
 pub use delayed_report_repository::InMemoryDelayedReportRepository;
+pub use erasure_request_repository::InMemoryErasureRequestRepository;
+pub use event_store::InMemoryEventStore;
+pub use fee_waiver_repository::InMemoryFeeWaiverRepository;
+pub use instrument_reference_data_repository::InMemoryInstrumentReferenceDataRepository;
 pub use mm_performance_repository::InMemoryMmPerformanceRepository;
 pub use mock_services::{MockLastLookBehavior, MockLastLookService, MockRiskCheckService};
+pub use negotiation_repository::InMemoryNegotiationRepository;
+pub use netting_batch_repository::InMemoryNettingBatchRepository;
+pub use order_book_snapshots::InMemoryOrderBookSnapshots;
+pub use platform_fee_schedule_repository::InMemoryPlatformFeeScheduleRepository;
+pub use price_bounds_config_repository::InMemoryPriceBoundsConfigRepository;
 pub use quote_lock_repository::InMemoryQuoteLockRepository;
+pub use raw_exchange_log::InMemoryRawExchangeLog;
 pub use rfq_repository::InMemoryRfqRepository;
+pub use rfq_summary_store::InMemoryRfqSummaryStore;
+pub use rfq_template_repository::InMemoryRfqTemplateRepository;
 pub use trade_repository::InMemoryTradeRepository;
+pub use trading_calendar_repository::InMemoryTradingCalendarRepository;
+pub use venue_credential_repository::InMemoryVenueCredentialRepository;
 pub use venue_repository::InMemoryVenueRepository;
+pub use webhook_delivery_log::InMemoryWebhookDeliveryLog;
+pub use webhook_subscription_repository::InMemoryWebhookSubscriptionRepository;
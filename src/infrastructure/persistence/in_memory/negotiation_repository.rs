@@ -0,0 +1,171 @@
+//! # In-Memory Negotiation Repository
+//!
+//! In-memory implementation of [`NegotiationRepository`].
+//!
+//! This implementation uses a thread-safe `HashMap` for storage,
+//! making it suitable for unit tests and single-node deployments.
+
+use crate::domain::entities::negotiation::Negotiation;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{CounterpartyId, NegotiationId, RfqId};
+use crate::infrastructure::persistence::traits::{NegotiationRepository, RepositoryResult};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory implementation of [`NegotiationRepository`].
+#[derive(Debug, Clone)]
+pub struct InMemoryNegotiationRepository {
+    storage: Arc<RwLock<HashMap<NegotiationId, Negotiation>>>,
+}
+
+impl InMemoryNegotiationRepository {
+    /// Creates a new empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            storage: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the number of negotiations in the repository.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.storage
+            .try_read()
+            .map(|guard| guard.len())
+            .unwrap_or(0)
+    }
+
+    /// Returns true if the repository is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for InMemoryNegotiationRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl NegotiationRepository for InMemoryNegotiationRepository {
+    async fn save(&self, negotiation: &Negotiation) -> RepositoryResult<()> {
+        let mut storage = self.storage.write().await;
+        storage.insert(negotiation.id(), negotiation.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: NegotiationId) -> RepositoryResult<Option<Negotiation>> {
+        let storage = self.storage.read().await;
+        Ok(storage.get(&id).cloned())
+    }
+
+    async fn find_by_rfq(&self, rfq_id: RfqId) -> RepositoryResult<Vec<Negotiation>> {
+        let storage = self.storage.read().await;
+        let mut negotiations: Vec<Negotiation> = storage
+            .values()
+            .filter(|n| n.rfq_id() == rfq_id)
+            .cloned()
+            .collect();
+        negotiations.sort_by_key(Negotiation::created_at);
+        Ok(negotiations)
+    }
+
+    async fn find_completed_between(
+        &self,
+        mm_account: Option<&CounterpartyId>,
+        from: Timestamp,
+        to: Timestamp,
+    ) -> RepositoryResult<Vec<Negotiation>> {
+        let storage = self.storage.read().await;
+        let mut negotiations: Vec<Negotiation> = storage
+            .values()
+            .filter(|n| n.state().is_terminal())
+            .filter(|n| n.updated_at() >= from && n.updated_at() <= to)
+            .filter(|n| mm_account.is_none_or(|mm| n.mm_account() == mm))
+            .cloned()
+            .collect();
+        negotiations.sort_by_key(Negotiation::updated_at);
+        Ok(negotiations)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{NegotiationState, OrderSide};
+
+    fn negotiation(mm: &str, state: NegotiationState, updated_at: Timestamp) -> Negotiation {
+        Negotiation::from_parts(
+            NegotiationId::new_v4(),
+            RfqId::new_v4(),
+            CounterpartyId::new("client-1"),
+            CounterpartyId::new(mm),
+            OrderSide::Buy,
+            Vec::new(),
+            3,
+            state,
+            updated_at.sub_secs(60),
+            updated_at,
+        )
+    }
+
+    #[tokio::test]
+    async fn find_completed_between_filters_by_range_mm_and_state() {
+        let repo = InMemoryNegotiationRepository::new();
+        let now = Timestamp::now();
+        let early = negotiation("mm-1", NegotiationState::Accepted, now.sub_secs(3600));
+        let inside = negotiation("mm-1", NegotiationState::Expired, now);
+        let other_mm = negotiation("mm-2", NegotiationState::Rejected, now);
+        let open = negotiation("mm-1", NegotiationState::Open, now);
+        for n in [&early, &inside, &other_mm, &open] {
+            repo.save(n).await.unwrap();
+        }
+
+        let mm = CounterpartyId::new("mm-1");
+        let found = repo
+            .find_completed_between(Some(&mm), now.sub_secs(60), now)
+            .await
+            .unwrap();
+        assert_eq!(found, vec![inside.clone()]);
+
+        let all = repo
+            .find_completed_between(None, now.sub_secs(7200), now)
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 3);
+        assert_eq!(all.first().unwrap().id(), early.id());
+    }
+
+    #[tokio::test]
+    async fn find_by_rfq_returns_oldest_first() {
+        let repo = InMemoryNegotiationRepository::new();
+        let now = Timestamp::now();
+        let later = negotiation("mm-1", NegotiationState::Open, now);
+        let rfq_id = later.rfq_id();
+        let earlier = Negotiation::from_parts(
+            NegotiationId::new_v4(),
+            rfq_id,
+            CounterpartyId::new("client-1"),
+            CounterpartyId::new("mm-2"),
+            OrderSide::Buy,
+            Vec::new(),
+            3,
+            NegotiationState::Rejected,
+            now.sub_secs(600),
+            now.sub_secs(300),
+        );
+        let unrelated = negotiation("mm-1", NegotiationState::Open, now);
+        for n in [&later, &earlier, &unrelated] {
+            repo.save(n).await.unwrap();
+        }
+
+        let found = repo.find_by_rfq(rfq_id).await.unwrap();
+        assert_eq!(found, vec![earlier, later]);
+    }
+}
@@ -0,0 +1,130 @@
+//! # In-Memory Netting Batch Repository
+//!
+//! In-memory implementation of [`NettingBatchRepository`].
+//!
+//! This implementation uses a thread-safe `HashMap` for storage,
+//! making it suitable for unit tests and single-node deployments.
+
+use crate::domain::entities::netting_batch::{NettingBatch, NettingBatchStatus};
+use crate::domain::value_objects::NettingBatchId;
+use crate::infrastructure::persistence::traits::{NettingBatchRepository, RepositoryResult};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory implementation of [`NettingBatchRepository`].
+#[derive(Debug, Clone)]
+pub struct InMemoryNettingBatchRepository {
+    storage: Arc<RwLock<HashMap<NettingBatchId, NettingBatch>>>,
+}
+
+impl InMemoryNettingBatchRepository {
+    /// Creates a new empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            storage: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the number of batches in the repository.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.storage
+            .try_read()
+            .map(|guard| guard.len())
+            .unwrap_or(0)
+    }
+
+    /// Returns true if the repository is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for InMemoryNettingBatchRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl NettingBatchRepository for InMemoryNettingBatchRepository {
+    async fn save(&self, batch: &NettingBatch) -> RepositoryResult<()> {
+        let mut storage = self.storage.write().await;
+        storage.insert(batch.id(), batch.clone());
+        Ok(())
+    }
+
+    async fn get(&self, id: NettingBatchId) -> RepositoryResult<Option<NettingBatch>> {
+        let storage = self.storage.read().await;
+        Ok(storage.get(&id).cloned())
+    }
+
+    async fn find_all(
+        &self,
+        status: Option<NettingBatchStatus>,
+    ) -> RepositoryResult<Vec<NettingBatch>> {
+        let storage = self.storage.read().await;
+        let mut batches: Vec<NettingBatch> = storage
+            .values()
+            .filter(|batch| status.is_none_or(|status| batch.status() == status))
+            .cloned()
+            .collect();
+        batches.sort_by_key(|batch| std::cmp::Reverse(batch.created_at()));
+        Ok(batches)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::netting_batch::NettingLeg;
+    use crate::domain::value_objects::enums::Blockchain;
+    use crate::domain::value_objects::timestamp::Timestamp;
+    use crate::domain::value_objects::{
+        CounterpartyId, OrderSide, Price, Quantity, Symbol, TradeId, VenueId,
+    };
+
+    fn batch() -> NettingBatch {
+        let now = Timestamp::now();
+        NettingBatch::new(
+            CounterpartyId::new("client-1"),
+            VenueId::new("mm-1"),
+            Symbol::new("ETH/USDC").unwrap(),
+            Blockchain::Ethereum,
+            now,
+            now.add_secs(3600),
+            &[NettingLeg::new(
+                TradeId::new_v4(),
+                OrderSide::Buy,
+                Price::new(100.0).unwrap(),
+                Quantity::new(1.0).unwrap(),
+            )],
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn find_all_filters_by_status() {
+        let repo = InMemoryNettingBatchRepository::new();
+        let open = batch();
+        let mut cancelled = batch();
+        cancelled.cancel().unwrap();
+        repo.save(&open).await.unwrap();
+        repo.save(&cancelled).await.unwrap();
+
+        assert_eq!(repo.len(), 2);
+        assert_eq!(repo.find_all(None).await.unwrap().len(), 2);
+        let only_open = repo.find_all(Some(NettingBatchStatus::Open)).await.unwrap();
+        assert_eq!(only_open.len(), 1);
+        assert_eq!(only_open.first().unwrap().id(), open.id());
+        assert_eq!(
+            repo.get(cancelled.id()).await.unwrap().unwrap().status(),
+            NettingBatchStatus::Cancelled
+        );
+    }
+}
@@ -0,0 +1,93 @@
+//! # In-Memory Order Book Snapshots
+//!
+//! In-memory implementation of [`OrderBookSnapshotPort`] for testing
+//! without an exchange connection.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::application::services::clob_mid::OrderBookSnapshot;
+//! use otc_rfq::domain::value_objects::{Price, Symbol, Timestamp};
+//! use otc_rfq::infrastructure::persistence::in_memory::InMemoryOrderBookSnapshots;
+//!
+//! let books = InMemoryOrderBookSnapshots::new();
+//! books.set(
+//!     Symbol::new("BTC/USD").unwrap(),
+//!     OrderBookSnapshot::new(
+//!         Some(Price::new(99.5).unwrap()),
+//!         Some(Price::new(100.5).unwrap()),
+//!         Timestamp::now(),
+//!     ),
+//! );
+//! ```
+
+use crate::application::services::clob_mid::{OrderBookSnapshot, OrderBookSnapshotPort};
+use crate::domain::errors::DomainResult;
+use crate::domain::value_objects::instrument::Instrument;
+use crate::domain::value_objects::symbol::Symbol;
+use async_trait::async_trait;
+use dashmap::DashMap;
+use std::sync::Arc;
+
+/// In-memory order book snapshots keyed by symbol.
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryOrderBookSnapshots {
+    books: Arc<DashMap<Symbol, OrderBookSnapshot>>,
+}
+
+impl InMemoryOrderBookSnapshots {
+    /// Creates an empty set of order books.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the snapshot for `symbol`.
+    pub fn set(&self, symbol: Symbol, snapshot: OrderBookSnapshot) {
+        self.books.insert(symbol, snapshot);
+    }
+
+    /// Removes the order book for `symbol`.
+    pub fn remove(&self, symbol: &Symbol) {
+        self.books.remove(symbol);
+    }
+}
+
+#[async_trait]
+impl OrderBookSnapshotPort for InMemoryOrderBookSnapshots {
+    async fn snapshot(&self, instrument: &Instrument) -> DomainResult<Option<OrderBookSnapshot>> {
+        Ok(self.books.get(instrument.symbol()).map(|book| *book))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
+    use crate::domain::value_objects::price::Price;
+    use crate::domain::value_objects::timestamp::Timestamp;
+
+    #[tokio::test]
+    async fn returns_snapshot_for_symbol() {
+        let books = InMemoryOrderBookSnapshots::new();
+        let symbol = Symbol::new("BTC/USD").unwrap();
+        let instrument = Instrument::new(
+            symbol.clone(),
+            AssetClass::CryptoSpot,
+            SettlementMethod::default(),
+        );
+        assert!(books.snapshot(&instrument).await.unwrap().is_none());
+
+        let snapshot = OrderBookSnapshot::new(
+            Some(Price::new(99.5).unwrap()),
+            Some(Price::new(100.5).unwrap()),
+            Timestamp::now(),
+        );
+        books.set(symbol.clone(), snapshot);
+        assert_eq!(books.snapshot(&instrument).await.unwrap(), Some(snapshot));
+
+        books.remove(&symbol);
+        assert!(books.snapshot(&instrument).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,151 @@
+//! # In-Memory Platform Fee Schedule Repository
+//!
+//! In-memory implementation of [`PlatformFeeScheduleRepository`].
+//!
+//! This implementation uses a thread-safe `HashMap` for storage,
+//! making it suitable for unit tests and single-node deployments.
+
+use crate::domain::entities::platform_fee::PlatformFeeSchedule;
+use crate::infrastructure::persistence::traits::{PlatformFeeScheduleRepository, RepositoryResult};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory implementation of [`PlatformFeeScheduleRepository`].
+#[derive(Debug, Clone)]
+pub struct InMemoryPlatformFeeScheduleRepository {
+    storage: Arc<RwLock<HashMap<String, PlatformFeeSchedule>>>,
+}
+
+impl InMemoryPlatformFeeScheduleRepository {
+    /// Creates a new empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            storage: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the number of schedules in the repository.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.storage
+            .try_read()
+            .map(|guard| guard.len())
+            .unwrap_or(0)
+    }
+
+    /// Returns true if the repository is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for InMemoryPlatformFeeScheduleRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl PlatformFeeScheduleRepository for InMemoryPlatformFeeScheduleRepository {
+    async fn save(&self, schedule: &PlatformFeeSchedule) -> RepositoryResult<()> {
+        let mut storage = self.storage.write().await;
+        storage.insert(schedule.tier().to_string(), schedule.clone());
+        Ok(())
+    }
+
+    async fn find_by_tier(&self, tier: &str) -> RepositoryResult<Option<PlatformFeeSchedule>> {
+        let storage = self.storage.read().await;
+        Ok(storage.get(tier).cloned())
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<PlatformFeeSchedule>> {
+        let storage = self.storage.read().await;
+        let mut all: Vec<PlatformFeeSchedule> = storage.values().cloned().collect();
+        all.sort_by(|a, b| a.tier().cmp(b.tier()));
+        Ok(all)
+    }
+
+    async fn delete(&self, tier: &str) -> RepositoryResult<bool> {
+        let mut storage = self.storage.write().await;
+        Ok(storage.remove(tier).is_some())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::platform_fee::DEFAULT_FEE_TIER;
+    use crate::domain::value_objects::CounterpartyId;
+
+    fn schedule(tier: &str, members: &[&str]) -> PlatformFeeSchedule {
+        PlatformFeeSchedule::new(
+            tier,
+            members.iter().map(|m| CounterpartyId::new(*m)).collect(),
+            Vec::new(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn save_replaces_by_tier_and_lists_in_order() {
+        let repo = InMemoryPlatformFeeScheduleRepository::new();
+        repo.save(&schedule("silver", &[])).await.unwrap();
+        repo.save(&schedule("gold", &[])).await.unwrap();
+        repo.save(&schedule("gold", &["client-1"])).await.unwrap();
+
+        assert_eq!(repo.len(), 2);
+        let tiers: Vec<String> = repo
+            .find_all()
+            .await
+            .unwrap()
+            .iter()
+            .map(|s| s.tier().to_string())
+            .collect();
+        assert_eq!(tiers, vec!["gold", "silver"]);
+        let gold = repo.find_by_tier("gold").await.unwrap().unwrap();
+        assert_eq!(gold.counterparties().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn counterparty_falls_back_to_default_tier() {
+        let repo = InMemoryPlatformFeeScheduleRepository::new();
+        repo.save(&schedule("gold", &["client-1"])).await.unwrap();
+
+        let unassigned = CounterpartyId::new("client-2");
+        assert!(
+            repo.find_for_counterparty(&unassigned)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        repo.save(&schedule(DEFAULT_FEE_TIER, &[])).await.unwrap();
+        let assigned = repo
+            .find_for_counterparty(&CounterpartyId::new("client-1"))
+            .await
+            .unwrap()
+            .unwrap();
+        let fallback = repo
+            .find_for_counterparty(&unassigned)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(assigned.tier(), "gold");
+        assert!(fallback.is_default());
+    }
+
+    #[tokio::test]
+    async fn delete_reports_whether_it_existed() {
+        let repo = InMemoryPlatformFeeScheduleRepository::new();
+        repo.save(&schedule("gold", &[])).await.unwrap();
+
+        assert!(repo.delete("gold").await.unwrap());
+        assert!(!repo.delete("gold").await.unwrap());
+        assert!(repo.is_empty());
+    }
+}
@@ -0,0 +1,51 @@
+//! # In-Memory Price Bounds Configuration Repository
+//!
+//! In-memory implementation of [`PriceBoundsConfigRepository`].
+//!
+//! This implementation keeps the current settings and the audit trail
+//! behind a single lock, making it suitable for unit tests and
+//! single-node deployments.
+
+use crate::domain::value_objects::reference_price::{PriceBoundsConfigChange, PriceBoundsSettings};
+use crate::infrastructure::persistence::traits::{PriceBoundsConfigRepository, RepositoryResult};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[derive(Debug, Default)]
+struct Storage {
+    current: Option<PriceBoundsSettings>,
+    history: Vec<PriceBoundsConfigChange>,
+}
+
+/// In-memory implementation of [`PriceBoundsConfigRepository`].
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryPriceBoundsConfigRepository {
+    storage: Arc<RwLock<Storage>>,
+}
+
+impl InMemoryPriceBoundsConfigRepository {
+    /// Creates a new empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl PriceBoundsConfigRepository for InMemoryPriceBoundsConfigRepository {
+    async fn load(&self) -> RepositoryResult<Option<PriceBoundsSettings>> {
+        Ok(self.storage.read().await.current.clone())
+    }
+
+    async fn save(&self, change: &PriceBoundsConfigChange) -> RepositoryResult<()> {
+        let mut storage = self.storage.write().await;
+        storage.current = Some(change.current.clone());
+        storage.history.push(change.clone());
+        Ok(())
+    }
+
+    async fn history(&self) -> RepositoryResult<Vec<PriceBoundsConfigChange>> {
+        Ok(self.storage.read().await.history.clone())
+    }
+}
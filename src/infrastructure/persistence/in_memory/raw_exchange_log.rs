@@ -0,0 +1,179 @@
+//! # In-Memory Raw Exchange Log
+//!
+//! In-memory implementation of [`RawExchangeLog`] for testing.
+//!
+//! Exchanges are stored in insertion order in a `Mutex<Vec<...>>`.
+
+use crate::domain::value_objects::RfqId;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::infrastructure::persistence::raw_exchange_log::{RawExchange, RawExchangeLog};
+use crate::infrastructure::persistence::traits::{RepositoryError, RepositoryResult};
+use async_trait::async_trait;
+use std::sync::Mutex;
+
+/// In-memory implementation of [`RawExchangeLog`].
+///
+/// Thread-safe for concurrent access within a single process.
+#[derive(Debug, Default)]
+pub struct InMemoryRawExchangeLog {
+    /// Exchanges in insertion order.
+    exchanges: Mutex<Vec<RawExchange>>,
+}
+
+impl InMemoryRawExchangeLog {
+    /// Creates a new empty log.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of exchanges in the log.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.exchanges.lock().map(|e| e.len()).unwrap_or(0)
+    }
+
+    /// Returns true if the log is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> RepositoryResult<std::sync::MutexGuard<'_, Vec<RawExchange>>> {
+        self.exchanges
+            .lock()
+            .map_err(|e| RepositoryError::internal(format!("lock poisoned: {e}")))
+    }
+}
+
+#[async_trait]
+impl RawExchangeLog for InMemoryRawExchangeLog {
+    async fn record(&self, exchange: &RawExchange) -> RepositoryResult<()> {
+        self.lock()?.push(exchange.clone());
+        Ok(())
+    }
+
+    async fn find_by_rfq(&self, rfq_id: RfqId) -> RepositoryResult<Vec<RawExchange>> {
+        let mut exchanges: Vec<RawExchange> = self
+            .lock()?
+            .iter()
+            .filter(|e| e.rfq_id == rfq_id)
+            .cloned()
+            .collect();
+        exchanges.sort_by_key(|e| e.recorded_at);
+        Ok(exchanges)
+    }
+
+    async fn purge_expired(&self, now: Timestamp) -> RepositoryResult<u64> {
+        let mut exchanges = self.lock()?;
+        let before = exchanges.len();
+        exchanges.retain(|e| !e.is_expired_at(now));
+        Ok((before - exchanges.len()) as u64)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::VenueId;
+    use crate::infrastructure::persistence::raw_exchange_log::ExchangeDirection;
+
+    fn exchange(
+        rfq_id: RfqId,
+        direction: ExchangeDirection,
+        recorded_at: Timestamp,
+        expires_at: Timestamp,
+    ) -> RawExchange {
+        RawExchange {
+            venue_id: VenueId::new("hashflow"),
+            rfq_id,
+            direction,
+            payload: serde_json::json!({}),
+            http_status: None,
+            latency_ms: None,
+            recorded_at,
+            expires_at,
+        }
+    }
+
+    #[tokio::test]
+    async fn find_by_rfq_orders_by_time_then_insertion() {
+        let log = InMemoryRawExchangeLog::new();
+        let rfq_id = RfqId::new_v4();
+        let now = Timestamp::now();
+        let later = now.add_secs(1);
+        let expiry = now.add_secs(3600);
+
+        log.record(&exchange(
+            rfq_id,
+            ExchangeDirection::Response,
+            later,
+            expiry,
+        ))
+        .await
+        .unwrap();
+        log.record(&exchange(rfq_id, ExchangeDirection::Request, now, expiry))
+            .await
+            .unwrap();
+        log.record(&exchange(rfq_id, ExchangeDirection::Response, now, expiry))
+            .await
+            .unwrap();
+        log.record(&exchange(
+            RfqId::new_v4(),
+            ExchangeDirection::Request,
+            now,
+            expiry,
+        ))
+        .await
+        .unwrap();
+
+        let directions: Vec<ExchangeDirection> = log
+            .find_by_rfq(rfq_id)
+            .await
+            .unwrap()
+            .iter()
+            .map(|e| e.direction)
+            .collect();
+        assert_eq!(
+            directions,
+            vec![
+                ExchangeDirection::Request,
+                ExchangeDirection::Response,
+                ExchangeDirection::Response,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn purge_expired_removes_only_lapsed_records() {
+        let log = InMemoryRawExchangeLog::new();
+        let rfq_id = RfqId::new_v4();
+        let now = Timestamp::now();
+
+        log.record(&exchange(
+            rfq_id,
+            ExchangeDirection::Request,
+            now.sub_secs(120),
+            now.sub_secs(60),
+        ))
+        .await
+        .unwrap();
+        log.record(&exchange(
+            rfq_id,
+            ExchangeDirection::Response,
+            now,
+            now.add_secs(60),
+        ))
+        .await
+        .unwrap();
+
+        assert_eq!(log.purge_expired(now).await.unwrap(), 1);
+        assert_eq!(log.len(), 1);
+        let remaining = log.find_by_rfq(rfq_id).await.unwrap();
+        assert_eq!(
+            remaining.first().unwrap().direction,
+            ExchangeDirection::Response
+        );
+    }
+}
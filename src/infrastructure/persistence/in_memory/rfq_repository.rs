@@ -16,9 +16,11 @@
 
 use crate::domain::entities::rfq::Rfq;
 use crate::domain::value_objects::RfqState;
-use crate::domain::value_objects::{CounterpartyId, RfqId, VenueId};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{CounterpartyId, OrderSide, RfqId, Symbol, VenueId};
+use crate::infrastructure::persistence::cursor::{PageCursor, paginate, paginate_offset};
 use crate::infrastructure::persistence::traits::{
-    RepositoryError, RepositoryResult, RfqRepository,
+    RepositoryError, RepositoryResult, RfqListFilter, RfqRepository,
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -151,6 +153,83 @@ impl RfqRepository for InMemoryRfqRepository {
         Ok(rfqs)
     }
 
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> RepositoryResult<Vec<Rfq>> {
+        let storage = self.storage.read().await;
+        let matching: Vec<Rfq> = storage
+            .values()
+            .filter(|rfq| filter.matches(rfq))
+            .cloned()
+            .collect();
+        Ok(paginate(matching, cursor, limit, PageCursor::from_rfq))
+    }
+
+    async fn list_offset(
+        &self,
+        offset: usize,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> RepositoryResult<Vec<Rfq>> {
+        let storage = self.storage.read().await;
+        let matching: Vec<Rfq> = storage
+            .values()
+            .filter(|rfq| filter.matches(rfq))
+            .cloned()
+            .collect();
+        Ok(paginate_offset(
+            matching,
+            offset,
+            limit,
+            PageCursor::from_rfq,
+        ))
+    }
+
+    async fn count_matching(&self, filter: &RfqListFilter) -> RepositoryResult<u64> {
+        let storage = self.storage.read().await;
+        Ok(storage.values().filter(|rfq| filter.matches(rfq)).count() as u64)
+    }
+
+    async fn find_crossing_candidates(
+        &self,
+        symbol: &Symbol,
+        side: OrderSide,
+        valid_after: Timestamp,
+    ) -> RepositoryResult<Vec<Rfq>> {
+        let storage = self.storage.read().await;
+        let mut candidates: Vec<Rfq> = storage
+            .values()
+            .filter(|rfq| {
+                rfq.allows_internal_crossing()
+                    && !rfq.is_multi_leg()
+                    && rfq.instrument().symbol() == symbol
+                    && rfq.side() == side
+                    && matches!(
+                        rfq.state(),
+                        RfqState::QuoteRequesting | RfqState::QuotesReceived
+                    )
+                    && rfq.expires_at() > valid_after
+            })
+            .cloned()
+            .collect();
+        candidates.sort_by_key(Rfq::created_at);
+        Ok(candidates)
+    }
+
+    async fn find_pending_activation(&self, before: Timestamp) -> RepositoryResult<Vec<Rfq>> {
+        let storage = self.storage.read().await;
+        let mut pending: Vec<Rfq> = storage
+            .values()
+            .filter(|rfq| rfq.is_due_for_activation_at(before))
+            .cloned()
+            .collect();
+        pending.sort_by_key(Rfq::activate_at);
+        Ok(pending)
+    }
+
     async fn delete(&self, id: RfqId) -> RepositoryResult<bool> {
         let mut storage = self.storage.write().await;
         Ok(storage.remove(&id).is_some())
@@ -210,6 +289,30 @@ mod tests {
         assert_eq!(retrieved.unwrap().id(), id);
     }
 
+    #[tokio::test]
+    async fn save_and_get_strategy_rfq() {
+        use crate::domain::value_objects::enums::AssetClass;
+        use crate::domain::value_objects::strategy::Strategy;
+
+        let repo = InMemoryRfqRepository::new();
+        let option =
+            Instrument::builder(Symbol::new("ETH/USDC").unwrap(), AssetClass::CryptoDerivs).build();
+        let straddle = Strategy::straddle(option.clone(), option, "ETH").unwrap();
+        let rfq = RfqBuilder::new(
+            CounterpartyId::new("client-1"),
+            straddle.clone(),
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(3600),
+        )
+        .build();
+
+        repo.save(&rfq).await.unwrap();
+
+        let retrieved = repo.get(rfq.id()).await.unwrap().unwrap();
+        assert_eq!(retrieved.strategy(), Some(&straddle));
+    }
+
     #[tokio::test]
     async fn get_nonexistent_returns_none() {
         let repo = InMemoryRfqRepository::new();
@@ -219,6 +322,57 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[tokio::test]
+    async fn list_offset_pages_and_counts_with_filter() {
+        let repo = InMemoryRfqRepository::new();
+        for _ in 0..5 {
+            repo.save(&create_test_rfq("client-1")).await.unwrap();
+        }
+        repo.save(&create_test_rfq("client-2")).await.unwrap();
+
+        let filter = RfqListFilter {
+            client_id: Some(CounterpartyId::new("client-1")),
+            ..RfqListFilter::default()
+        };
+        let newest_first = repo.list_after(None, 10, &filter).await.unwrap();
+        let page = repo.list_offset(3, 3, &filter).await.unwrap();
+
+        let ids: Vec<RfqId> = page.iter().map(Rfq::id).collect();
+        let expected: Vec<RfqId> = newest_first.iter().skip(3).map(Rfq::id).collect();
+        assert_eq!(ids, expected);
+        assert_eq!(repo.count_matching(&filter).await.unwrap(), 5);
+    }
+
+    #[tokio::test]
+    async fn list_after_pages_newest_first_with_filter() {
+        let repo = InMemoryRfqRepository::new();
+        for _ in 0..5 {
+            repo.save(&create_test_rfq("client-1")).await.unwrap();
+        }
+        repo.save(&create_test_rfq("client-2")).await.unwrap();
+
+        let filter = RfqListFilter {
+            client_id: Some(CounterpartyId::new("client-1")),
+            ..RfqListFilter::default()
+        };
+        let first = repo.list_after(None, 3, &filter).await.unwrap();
+        assert_eq!(first.len(), 3);
+
+        let cursor = PageCursor::from_rfq(first.last().unwrap());
+        let second = repo.list_after(Some(&cursor), 3, &filter).await.unwrap();
+        assert_eq!(second.len(), 2);
+        assert!(
+            second
+                .iter()
+                .all(|rfq| first.iter().all(|seen| seen.id() != rfq.id()))
+        );
+        assert!(
+            second
+                .iter()
+                .all(|rfq| rfq.client_id().as_str() == "client-1")
+        );
+    }
+
     #[tokio::test]
     async fn find_by_client() {
         let repo = InMemoryRfqRepository::new();
@@ -0,0 +1,98 @@
+//! # In-Memory RFQ Summary Store
+//!
+//! In-memory implementation of [`RfqSummaryStore`] for testing.
+
+use crate::domain::value_objects::RfqId;
+use crate::infrastructure::persistence::cursor::{PageCursor, paginate, paginate_offset};
+use crate::infrastructure::persistence::rfq_summary::{RfqSummary, RfqSummaryStore};
+use crate::infrastructure::persistence::traits::{
+    RepositoryError, RepositoryResult, RfqListFilter,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+/// In-memory implementation of [`RfqSummaryStore`].
+///
+/// Thread-safe for concurrent access within a single process.
+#[derive(Debug, Default)]
+pub struct InMemoryRfqSummaryStore {
+    rows: Mutex<HashMap<RfqId, RfqSummary>>,
+}
+
+impl InMemoryRfqSummaryStore {
+    /// Creates a new empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> RepositoryResult<MutexGuard<'_, HashMap<RfqId, RfqSummary>>> {
+        self.rows
+            .lock()
+            .map_err(|e| RepositoryError::internal(format!("lock poisoned: {e}")))
+    }
+}
+
+#[async_trait]
+impl RfqSummaryStore for InMemoryRfqSummaryStore {
+    async fn upsert(&self, summary: &RfqSummary) -> RepositoryResult<()> {
+        let mut rows = self.lock()?;
+        let is_newer = rows
+            .get(&summary.rfq_id)
+            .is_none_or(|existing| existing.last_sequence < summary.last_sequence);
+        if is_newer {
+            rows.insert(summary.rfq_id, summary.clone());
+        }
+        Ok(())
+    }
+
+    async fn get(&self, rfq_id: RfqId) -> RepositoryResult<Option<RfqSummary>> {
+        Ok(self.lock()?.get(&rfq_id).cloned())
+    }
+
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> RepositoryResult<Vec<RfqSummary>> {
+        let rows = self
+            .lock()?
+            .values()
+            .filter(|row| row.matches(filter))
+            .cloned()
+            .collect();
+        Ok(paginate(rows, cursor, limit, RfqSummary::cursor))
+    }
+
+    async fn list_offset(
+        &self,
+        offset: usize,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> RepositoryResult<Vec<RfqSummary>> {
+        let rows = self
+            .lock()?
+            .values()
+            .filter(|row| row.matches(filter))
+            .cloned()
+            .collect();
+        Ok(paginate_offset(rows, offset, limit, RfqSummary::cursor))
+    }
+
+    async fn count_matching(&self, filter: &RfqListFilter) -> RepositoryResult<u64> {
+        Ok(self
+            .lock()?
+            .values()
+            .filter(|row| row.matches(filter))
+            .count() as u64)
+    }
+
+    async fn clear(&self) -> RepositoryResult<u64> {
+        let mut rows = self.lock()?;
+        let cleared = rows.len() as u64;
+        rows.clear();
+        Ok(cleared)
+    }
+}
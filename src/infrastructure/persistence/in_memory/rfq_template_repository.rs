@@ -0,0 +1,137 @@
+//! # In-Memory RFQ Template Repository
+//!
+//! In-memory implementation of [`RfqTemplateRepository`].
+//!
+//! This implementation uses a thread-safe `HashMap` for storage,
+//! making it suitable for unit tests and single-node deployments.
+
+use crate::domain::entities::rfq_template::RfqTemplate;
+use crate::domain::value_objects::{CounterpartyId, RfqTemplateId};
+use crate::infrastructure::persistence::traits::{RepositoryResult, RfqTemplateRepository};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory implementation of [`RfqTemplateRepository`].
+#[derive(Debug, Clone)]
+pub struct InMemoryRfqTemplateRepository {
+    storage: Arc<RwLock<HashMap<RfqTemplateId, RfqTemplate>>>,
+}
+
+impl InMemoryRfqTemplateRepository {
+    /// Creates a new empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            storage: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the number of templates in the repository.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.storage
+            .try_read()
+            .map(|guard| guard.len())
+            .unwrap_or(0)
+    }
+
+    /// Returns true if the repository is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for InMemoryRfqTemplateRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl RfqTemplateRepository for InMemoryRfqTemplateRepository {
+    async fn save(&self, template: &RfqTemplate) -> RepositoryResult<()> {
+        let mut storage = self.storage.write().await;
+        storage.insert(template.id(), template.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: RfqTemplateId) -> RepositoryResult<Option<RfqTemplate>> {
+        let storage = self.storage.read().await;
+        Ok(storage.get(&id).cloned())
+    }
+
+    async fn find_by_owner(&self, owner: &CounterpartyId) -> RepositoryResult<Vec<RfqTemplate>> {
+        let storage = self.storage.read().await;
+        let mut templates: Vec<RfqTemplate> = storage
+            .values()
+            .filter(|template| template.is_owned_by(owner))
+            .cloned()
+            .collect();
+        templates.sort_by(|a, b| a.name().cmp(b.name()));
+        Ok(templates)
+    }
+
+    async fn delete(&self, id: RfqTemplateId) -> RepositoryResult<bool> {
+        let mut storage = self.storage.write().await;
+        Ok(storage.remove(&id).is_some())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::rfq_template::RfqTemplateBuilder;
+    use crate::domain::value_objects::enums::AssetClass;
+    use crate::domain::value_objects::{Instrument, OrderSide, Quantity, Symbol};
+
+    fn template(owner: &str, name: &str) -> RfqTemplate {
+        let instrument =
+            Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build();
+        RfqTemplateBuilder::new(
+            CounterpartyId::new(owner),
+            name,
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(1.0).unwrap(),
+            300,
+        )
+        .try_build()
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn find_by_owner_returns_only_owned_templates_by_name() {
+        let repo = InMemoryRfqTemplateRepository::new();
+        repo.save(&template("client-1", "b")).await.unwrap();
+        repo.save(&template("client-1", "a")).await.unwrap();
+        repo.save(&template("client-2", "c")).await.unwrap();
+
+        let names: Vec<String> = repo
+            .find_by_owner(&CounterpartyId::new("client-1"))
+            .await
+            .unwrap()
+            .iter()
+            .map(|t| t.name().to_string())
+            .collect();
+        assert_eq!(names, vec!["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn save_find_and_delete() {
+        let repo = InMemoryRfqTemplateRepository::new();
+        let template = template("client-1", "a");
+        repo.save(&template).await.unwrap();
+
+        assert_eq!(
+            repo.find_by_id(template.id()).await.unwrap(),
+            Some(template.clone())
+        );
+        assert!(repo.delete(template.id()).await.unwrap());
+        assert!(!repo.delete(template.id()).await.unwrap());
+        assert!(repo.is_empty());
+    }
+}
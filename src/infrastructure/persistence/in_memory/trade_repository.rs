@@ -7,9 +7,11 @@
 
 use crate::domain::entities::SettlementState;
 use crate::domain::entities::trade::Trade;
+use crate::domain::value_objects::timestamp::Timestamp;
 use crate::domain::value_objects::{RfqId, TradeId, VenueId};
+use crate::infrastructure::persistence::cursor::{PageCursor, paginate};
 use crate::infrastructure::persistence::traits::{
-    RepositoryError, RepositoryResult, TradeRepository,
+    RepositoryError, RepositoryResult, TradeListFilter, TradeRepository,
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -114,6 +116,21 @@ impl TradeRepository for InMemoryTradeRepository {
         Ok(trades)
     }
 
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &TradeListFilter,
+    ) -> RepositoryResult<Vec<Trade>> {
+        let storage = self.storage.read().await;
+        let matching: Vec<Trade> = storage
+            .values()
+            .filter(|t| filter.matches(t))
+            .cloned()
+            .collect();
+        Ok(paginate(matching, cursor, limit, PageCursor::from_trade))
+    }
+
     async fn find_settled(&self) -> RepositoryResult<Vec<Trade>> {
         let storage = self.storage.read().await;
         let settled: Vec<Trade> = storage
@@ -134,6 +151,23 @@ impl TradeRepository for InMemoryTradeRepository {
         Ok(failed)
     }
 
+    async fn find_due_for_settlement_retry(
+        &self,
+        now: Timestamp,
+        limit: usize,
+    ) -> RepositoryResult<Vec<Trade>> {
+        let storage = self.storage.read().await;
+        let mut due: Vec<Trade> = storage
+            .values()
+            .filter(|t| t.is_failed())
+            .filter(|t| t.next_settlement_retry_at().is_none_or(|at| at <= now))
+            .cloned()
+            .collect();
+        due.sort_by_key(|t| t.next_settlement_retry_at());
+        due.truncate(limit);
+        Ok(due)
+    }
+
     async fn delete(&self, id: TradeId) -> RepositoryResult<bool> {
         let mut storage = self.storage.write().await;
         Ok(storage.remove(&id).is_some())
@@ -186,6 +220,32 @@ mod tests {
         assert_eq!(retrieved.unwrap().id(), id);
     }
 
+    #[tokio::test]
+    async fn find_due_for_settlement_retry_skips_future_retries() {
+        let repo = InMemoryTradeRepository::new();
+        let now = Timestamp::now();
+
+        let mut due = create_test_trade("venue-1");
+        due.start_settlement().unwrap();
+        due.fail_settlement("timeout").unwrap();
+        due.schedule_settlement_retry(now.sub_secs(1)).unwrap();
+
+        let mut later = create_test_trade("venue-1");
+        later.start_settlement().unwrap();
+        later.fail_settlement("timeout").unwrap();
+        later.schedule_settlement_retry(now.add_secs(60)).unwrap();
+
+        let pending = create_test_trade("venue-1");
+
+        repo.save(&due).await.unwrap();
+        repo.save(&later).await.unwrap();
+        repo.save(&pending).await.unwrap();
+
+        let found = repo.find_due_for_settlement_retry(now, 10).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found.first().unwrap().id(), due.id());
+    }
+
     #[tokio::test]
     async fn get_nonexistent_returns_none() {
         let repo = InMemoryTradeRepository::new();
@@ -227,6 +287,34 @@ mod tests {
         assert_eq!(venue2_trades.len(), 1);
     }
 
+    #[tokio::test]
+    async fn list_after_pages_newest_first_with_filter() {
+        let repo = InMemoryTradeRepository::new();
+        for _ in 0..4 {
+            repo.save(&create_test_trade("venue-1")).await.unwrap();
+        }
+        repo.save(&create_test_trade("venue-2")).await.unwrap();
+
+        let filter = TradeListFilter {
+            venue_id: Some(VenueId::new("venue-1")),
+            ..TradeListFilter::default()
+        };
+        let first = repo.list_after(None, 2, &filter).await.unwrap();
+        assert_eq!(first.len(), 2);
+
+        let cursor = PageCursor::from_trade(first.last().unwrap());
+        let second = repo.list_after(Some(&cursor), 2, &filter).await.unwrap();
+        assert_eq!(second.len(), 2);
+
+        let cursor = PageCursor::from_trade(second.last().unwrap());
+        let third = repo.list_after(Some(&cursor), 2, &filter).await.unwrap();
+        assert!(third.is_empty());
+
+        let ids: std::collections::HashSet<_> =
+            first.iter().chain(&second).map(|t| t.id()).collect();
+        assert_eq!(ids.len(), 4);
+    }
+
     #[tokio::test]
     async fn find_pending_settlement() {
         let repo = InMemoryTradeRepository::new();
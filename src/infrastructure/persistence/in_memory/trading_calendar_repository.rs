@@ -0,0 +1,59 @@
+//! # In-Memory Trading Calendar Repository
+//!
+//! In-memory implementation of [`TradingCalendarRepository`].
+//!
+//! This implementation uses a thread-safe `BTreeMap` for storage,
+//! making it suitable for unit tests and single-node deployments.
+
+use crate::domain::value_objects::TradingCalendar;
+use crate::infrastructure::persistence::traits::{RepositoryResult, TradingCalendarRepository};
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory implementation of [`TradingCalendarRepository`].
+#[derive(Debug, Clone)]
+pub struct InMemoryTradingCalendarRepository {
+    storage: Arc<RwLock<BTreeMap<String, TradingCalendar>>>,
+}
+
+impl InMemoryTradingCalendarRepository {
+    /// Creates a new empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            storage: Arc::new(RwLock::new(BTreeMap::new())),
+        }
+    }
+}
+
+impl Default for InMemoryTradingCalendarRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TradingCalendarRepository for InMemoryTradingCalendarRepository {
+    async fn save(&self, calendar: &TradingCalendar) -> RepositoryResult<()> {
+        let mut storage = self.storage.write().await;
+        storage.insert(calendar.name().to_string(), calendar.clone());
+        Ok(())
+    }
+
+    async fn find(&self, name: &str) -> RepositoryResult<Option<TradingCalendar>> {
+        let storage = self.storage.read().await;
+        Ok(storage.get(&name.to_uppercase()).cloned())
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<TradingCalendar>> {
+        let storage = self.storage.read().await;
+        Ok(storage.values().cloned().collect())
+    }
+
+    async fn delete(&self, name: &str) -> RepositoryResult<bool> {
+        let mut storage = self.storage.write().await;
+        Ok(storage.remove(&name.to_uppercase()).is_some())
+    }
+}
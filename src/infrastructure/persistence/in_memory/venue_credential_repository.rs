@@ -0,0 +1,88 @@
+//! # In-Memory Venue Credential Repository
+//!
+//! In-memory implementation of [`VenueCredentialRepository`].
+//!
+//! This implementation uses a thread-safe `HashMap` for storage,
+//! making it suitable for unit tests and single-node deployments. The
+//! credentials are held in clear in process memory.
+
+use crate::domain::entities::venue_credential_set::VenueCredentialSet;
+use crate::domain::value_objects::{CounterpartyId, VenueId};
+use crate::infrastructure::persistence::traits::{RepositoryResult, VenueCredentialRepository};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory implementation of [`VenueCredentialRepository`].
+#[derive(Debug, Clone)]
+pub struct InMemoryVenueCredentialRepository {
+    storage: Arc<RwLock<HashMap<(CounterpartyId, VenueId), VenueCredentialSet>>>,
+}
+
+impl InMemoryVenueCredentialRepository {
+    /// Creates a new empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            storage: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Default for InMemoryVenueCredentialRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl VenueCredentialRepository for InMemoryVenueCredentialRepository {
+    async fn save(&self, credentials: &VenueCredentialSet) -> RepositoryResult<()> {
+        let mut storage = self.storage.write().await;
+        storage.insert(
+            (
+                credentials.counterparty_id().clone(),
+                credentials.venue_id().clone(),
+            ),
+            credentials.clone(),
+        );
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        counterparty_id: &CounterpartyId,
+        venue_id: &VenueId,
+    ) -> RepositoryResult<Option<VenueCredentialSet>> {
+        let storage = self.storage.read().await;
+        Ok(storage
+            .get(&(counterparty_id.clone(), venue_id.clone()))
+            .cloned())
+    }
+
+    async fn find_by_counterparty(
+        &self,
+        counterparty_id: &CounterpartyId,
+    ) -> RepositoryResult<Vec<VenueCredentialSet>> {
+        let storage = self.storage.read().await;
+        let mut sets: Vec<VenueCredentialSet> = storage
+            .values()
+            .filter(|set| set.counterparty_id() == counterparty_id)
+            .cloned()
+            .collect();
+        sets.sort_by(|a, b| a.venue_id().as_str().cmp(b.venue_id().as_str()));
+        Ok(sets)
+    }
+
+    async fn delete(
+        &self,
+        counterparty_id: &CounterpartyId,
+        venue_id: &VenueId,
+    ) -> RepositoryResult<bool> {
+        let mut storage = self.storage.write().await;
+        Ok(storage
+            .remove(&(counterparty_id.clone(), venue_id.clone()))
+            .is_some())
+    }
+}
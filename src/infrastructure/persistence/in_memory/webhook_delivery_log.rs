@@ -0,0 +1,64 @@
+//! # In-Memory Webhook Delivery Log
+//!
+//! In-memory implementation of [`WebhookDeliveryLog`] for testing.
+
+use crate::domain::value_objects::{WebhookDeliveryId, WebhookSubscriptionId};
+use crate::infrastructure::persistence::cursor::{PageCursor, paginate};
+use crate::infrastructure::persistence::traits::{RepositoryError, RepositoryResult};
+use crate::infrastructure::persistence::webhook_delivery_log::{
+    WebhookDelivery, WebhookDeliveryLog,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+/// In-memory implementation of [`WebhookDeliveryLog`].
+///
+/// Thread-safe for concurrent access within a single process.
+#[derive(Debug, Default)]
+pub struct InMemoryWebhookDeliveryLog {
+    deliveries: Mutex<HashMap<WebhookDeliveryId, WebhookDelivery>>,
+}
+
+impl InMemoryWebhookDeliveryLog {
+    /// Creates a new empty log.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(
+        &self,
+    ) -> RepositoryResult<MutexGuard<'_, HashMap<WebhookDeliveryId, WebhookDelivery>>> {
+        self.deliveries
+            .lock()
+            .map_err(|e| RepositoryError::internal(format!("lock poisoned: {e}")))
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryLog for InMemoryWebhookDeliveryLog {
+    async fn save(&self, delivery: &WebhookDelivery) -> RepositoryResult<()> {
+        self.lock()?.insert(delivery.id, delivery.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: WebhookDeliveryId) -> RepositoryResult<Option<WebhookDelivery>> {
+        Ok(self.lock()?.get(&id).cloned())
+    }
+
+    async fn list_after(
+        &self,
+        subscription_id: WebhookSubscriptionId,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+    ) -> RepositoryResult<Vec<WebhookDelivery>> {
+        let deliveries = self
+            .lock()?
+            .values()
+            .filter(|delivery| delivery.subscription_id == subscription_id)
+            .cloned()
+            .collect();
+        Ok(paginate(deliveries, cursor, limit, WebhookDelivery::cursor))
+    }
+}
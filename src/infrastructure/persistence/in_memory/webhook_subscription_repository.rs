@@ -0,0 +1,69 @@
+//! # In-Memory Webhook Subscription Repository
+//!
+//! In-memory implementation of [`WebhookSubscriptionRepository`].
+//!
+//! This implementation uses a thread-safe `HashMap` for storage,
+//! making it suitable for unit tests and single-node deployments.
+
+use crate::domain::entities::webhook_subscription::WebhookSubscription;
+use crate::domain::value_objects::WebhookSubscriptionId;
+use crate::infrastructure::persistence::traits::{RepositoryResult, WebhookSubscriptionRepository};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory implementation of [`WebhookSubscriptionRepository`].
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryWebhookSubscriptionRepository {
+    storage: Arc<RwLock<HashMap<WebhookSubscriptionId, WebhookSubscription>>>,
+}
+
+impl InMemoryWebhookSubscriptionRepository {
+    /// Creates a new empty repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    async fn sorted(
+        &self,
+        keep: impl Fn(&WebhookSubscription) -> bool,
+    ) -> Vec<WebhookSubscription> {
+        let storage = self.storage.read().await;
+        let mut subscriptions: Vec<WebhookSubscription> =
+            storage.values().filter(|s| keep(s)).cloned().collect();
+        subscriptions.sort_by_key(|s| (s.created_at(), s.id().get()));
+        subscriptions
+    }
+}
+
+#[async_trait]
+impl WebhookSubscriptionRepository for InMemoryWebhookSubscriptionRepository {
+    async fn save(&self, subscription: &WebhookSubscription) -> RepositoryResult<()> {
+        let mut storage = self.storage.write().await;
+        storage.insert(subscription.id(), subscription.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: WebhookSubscriptionId,
+    ) -> RepositoryResult<Option<WebhookSubscription>> {
+        let storage = self.storage.read().await;
+        Ok(storage.get(&id).cloned())
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<WebhookSubscription>> {
+        Ok(self.sorted(|_| true).await)
+    }
+
+    async fn find_active(&self) -> RepositoryResult<Vec<WebhookSubscription>> {
+        Ok(self.sorted(WebhookSubscription::is_active).await)
+    }
+
+    async fn delete(&self, id: WebhookSubscriptionId) -> RepositoryResult<bool> {
+        let mut storage = self.storage.write().await;
+        Ok(storage.remove(&id).is_some())
+    }
+}
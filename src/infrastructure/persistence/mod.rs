@@ -8,24 +8,58 @@
 //! - [`TradeRepository`]: Persistence for Trade entities
 //! - [`VenueRepository`]: Persistence for venue configurations
 //! - [`CounterpartyRepository`]: Persistence for counterparty data
+//! - [`ErasureRequestRepository`]: Persistence for counterparty erasure requests
+//! - [`CounterpartyKeyStore`]: Per-counterparty keys encrypting identifiers in events
+//! - [`VenueCredentialRepository`]: Counterparties' own venue credentials, encrypted
 //! - [`BlockTradeRepository`]: Persistence for block trade entities
 //! - [`EventStore`]: Append-only event storage
+//! - [`DeadLetterStore`]: Events parked after their consumer kept failing
 //! - [`NegotiationAuditLog`]: Negotiation audit log with μs precision
+//! - [`RawExchangeLog`]: Raw venue request/response payloads for disputes
+//! - [`RfqSummaryStore`]: Denormalized RFQ dashboard rows
+//! - [`WebhookSubscriptionRepository`]: Persistence for webhook subscriptions
+//! - [`WebhookDeliveryLog`]: Outbound webhook delivery records
+//! - [`AggregationReportRepository`]: Per-venue quote aggregation outcomes of RFQs
+//! - [`BestExecutionReportRepository`]: Snapshots of per-client best execution reports
+//! - [`KeyProvider`]: Keys encrypting personal data columns at rest
 //!
 //! ## Implementations
 //!
 //! - `in_memory`: In-memory implementations for testing
 //! - `postgres`: PostgreSQL implementations with sqlx
+//! - [`FileCircuitStateStore`]: Circuit breaker state in a JSON file
 
 pub mod audit_log;
+pub mod circuit_state_file;
+pub mod counterparty_keys;
+pub mod cursor;
+pub mod dead_letter;
 pub mod event_store;
+pub mod field_encryption;
 pub mod in_memory;
 pub mod postgres;
+pub mod raw_exchange_log;
+pub mod rfq_summary;
 pub mod traits;
+pub mod webhook_delivery_log;
 
 pub use audit_log::{AuditLogResult, NegotiationAuditLog};
+pub use circuit_state_file::FileCircuitStateStore;
+pub use counterparty_keys::{CounterpartyKey, CounterpartyKeyStore, KeyLookup};
+pub use cursor::{CursorError, PageCursor};
+pub use dead_letter::{DeadLetter, DeadLetterStatus, DeadLetterStore};
 pub use event_store::{EventStore, EventStoreError, EventStoreResult, StoredEvent};
+pub use field_encryption::{
+    EncryptedString, EnvKeyProvider, FieldEncryptionError, FieldKey, KeyProvider, blind_index,
+};
+pub use raw_exchange_log::{ExchangeDirection, RawExchange, RawExchangeLog};
+pub use rfq_summary::{RfqSummary, RfqSummaryStore};
 pub use traits::{
-    BlockTradeRepository, CounterpartyRepository, RepositoryError, RepositoryResult, RfqRepository,
-    TradeRepository, VenueRepository,
+    AggregationReportRepository, BestExecutionReportRepository, BlockTradeRepository,
+    ConstraintKind, CounterpartyRepository, ErasureRequestRepository, FeeWaiverRepository,
+    InstrumentReferenceDataRepository, NegotiationRepository, NettingBatchRepository,
+    PlatformFeeScheduleRepository, PriceBoundsConfigRepository, RepositoryError, RepositoryResult,
+    RfqListFilter, RfqRepository, RfqTemplateRepository, TradeListFilter, TradeRepository,
+    VenueCredentialRepository, VenueRepository, WebhookSubscriptionRepository,
 };
+pub use webhook_delivery_log::{WebhookDelivery, WebhookDeliveryLog, WebhookDeliveryStatus};
@@ -0,0 +1,98 @@
+//! # PostgreSQL Aggregation Report Repository
+//!
+//! PostgreSQL implementation of [`AggregationReportRepository`] using sqlx.
+//!
+//! Each RFQ has at most one row in `rfq_aggregation_reports`, holding its
+//! venue outcomes and quote rankings as JSONB; a later aggregation replaces
+//! it.
+
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{AggregationReport, QuoteRanking, RfqId, VenueOutcome};
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
+use crate::infrastructure::persistence::traits::{
+    AggregationReportRepository, RepositoryError, RepositoryResult,
+};
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+/// PostgreSQL implementation of [`AggregationReportRepository`].
+///
+/// Uses connection pooling via `sqlx::PgPool`.
+#[derive(Debug, Clone)]
+pub struct PostgresAggregationReportRepository {
+    pool: PgPool,
+}
+
+impl PostgresAggregationReportRepository {
+    /// Creates a new PostgreSQL aggregation report repository.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl AggregationReportRepository for PostgresAggregationReportRepository {
+    async fn save(&self, report: &AggregationReport) -> RepositoryResult<()> {
+        let venue_outcomes = serde_json::to_value(&report.venue_outcomes)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let quote_rankings = serde_json::to_value(&report.quote_rankings)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO rfq_aggregation_reports (
+                rfq_id, venue_outcomes, quote_rankings, generated_at
+            )
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (rfq_id) DO UPDATE SET
+                venue_outcomes = EXCLUDED.venue_outcomes,
+                quote_rankings = EXCLUDED.quote_rankings,
+                generated_at = EXCLUDED.generated_at
+            "#,
+        )
+        .bind(report.rfq_id.get())
+        .bind(&venue_outcomes)
+        .bind(&quote_rankings)
+        .bind(report.generated_at.timestamp_millis())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn find_by_rfq_id(&self, rfq_id: RfqId) -> RepositoryResult<Option<AggregationReport>> {
+        let row: Option<(serde_json::Value, serde_json::Value, i64)> = sqlx::query_as(
+            "SELECT venue_outcomes, quote_rankings, generated_at \
+             FROM rfq_aggregation_reports WHERE rfq_id = $1",
+        )
+        .bind(rfq_id.get())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(|(venue_outcomes, quote_rankings, generated_at)| {
+            let venue_outcomes: Vec<VenueOutcome> = serde_json::from_value(venue_outcomes)
+                .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+            let quote_rankings: Vec<QuoteRanking> = serde_json::from_value(quote_rankings)
+                .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+            let generated_at = Timestamp::from_millis(generated_at).ok_or_else(|| {
+                RepositoryError::serialization("invalid generated_at timestamp".to_string())
+            })?;
+            Ok(AggregationReport {
+                rfq_id,
+                venue_outcomes,
+                quote_rankings,
+                generated_at,
+            })
+        })
+        .transpose()
+    }
+}
@@ -0,0 +1,89 @@
+//! # PostgreSQL Best Execution Report Repository
+//!
+//! PostgreSQL implementation of [`BestExecutionReportRepository`] using sqlx.
+//!
+//! Each client and period has at most one row in `best_execution_reports`,
+//! holding the report as JSONB. The first report saved is kept.
+
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{BestExecutionReport, CounterpartyId};
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
+use crate::infrastructure::persistence::traits::{
+    BestExecutionReportRepository, RepositoryError, RepositoryResult,
+};
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+/// PostgreSQL implementation of [`BestExecutionReportRepository`].
+///
+/// Uses connection pooling via `sqlx::PgPool`.
+#[derive(Debug, Clone)]
+pub struct PostgresBestExecutionReportRepository {
+    pool: PgPool,
+}
+
+impl PostgresBestExecutionReportRepository {
+    /// Creates a new PostgreSQL best execution report repository.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl BestExecutionReportRepository for PostgresBestExecutionReportRepository {
+    async fn save(&self, report: &BestExecutionReport) -> RepositoryResult<bool> {
+        let body = serde_json::to_value(report)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO best_execution_reports (client_id, period_from, period_to, report, generated_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (client_id, period_from, period_to) DO NOTHING
+            "#,
+        )
+        .bind(report.client_id.as_str())
+        .bind(report.from.timestamp_millis())
+        .bind(report.to.timestamp_millis())
+        .bind(&body)
+        .bind(report.generated_at.timestamp_millis())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find(
+        &self,
+        client_id: &CounterpartyId,
+        from: Timestamp,
+        to: Timestamp,
+    ) -> RepositoryResult<Option<BestExecutionReport>> {
+        let row: Option<(serde_json::Value,)> = sqlx::query_as(
+            r#"
+            SELECT report FROM best_execution_reports
+            WHERE client_id = $1 AND period_from = $2 AND period_to = $3
+            "#,
+        )
+        .bind(client_id.as_str())
+        .bind(from.timestamp_millis())
+        .bind(to.timestamp_millis())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(|(report,)| {
+            serde_json::from_value(report)
+                .map_err(|e| RepositoryError::serialization(e.to_string()))
+        })
+        .transpose()
+    }
+}
@@ -0,0 +1,124 @@
+//! # PostgreSQL Counterparty Key Store
+//!
+//! PostgreSQL implementation of [`CounterpartyKeyStore`] using sqlx.
+//!
+//! Shredding nulls the key material in place and replaces the owner with
+//! the pseudonym, keeping the row so readers of old events learn the key
+//! was shredded rather than lost.
+
+use crate::domain::value_objects::CounterpartyId;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::infrastructure::persistence::counterparty_keys::{
+    COUNTERPARTY_KEY_LEN, CounterpartyKey, CounterpartyKeyStore, KeyLookup,
+};
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
+use crate::infrastructure::persistence::traits::{RepositoryError, RepositoryResult};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// PostgreSQL implementation of [`CounterpartyKeyStore`].
+///
+/// Uses connection pooling via `sqlx::PgPool`.
+#[derive(Debug, Clone)]
+pub struct PostgresCounterpartyKeyStore {
+    pool: PgPool,
+}
+
+impl PostgresCounterpartyKeyStore {
+    /// Creates a new PostgreSQL counterparty key store.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl CounterpartyKeyStore for PostgresCounterpartyKeyStore {
+    async fn get_or_create(
+        &self,
+        counterparty_id: &CounterpartyId,
+    ) -> RepositoryResult<CounterpartyKey> {
+        // Concurrent first uses race on the partial unique index; the loser
+        // inserts nothing and reads the winner's key.
+        let candidate = CounterpartyKey::generate();
+        sqlx::query(
+            r#"
+            INSERT INTO counterparty_keys (key_id, counterparty_id, key_material, created_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (counterparty_id) WHERE key_material IS NOT NULL DO NOTHING
+            "#,
+        )
+        .bind(candidate.key_id)
+        .bind(counterparty_id.as_str())
+        .bind(candidate.material.as_slice())
+        .bind(Timestamp::now().timestamp_millis())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        let (key_id, material): (Uuid, Vec<u8>) = sqlx::query_as(
+            r#"
+            SELECT key_id, key_material FROM counterparty_keys
+            WHERE counterparty_id = $1 AND key_material IS NOT NULL
+            "#,
+        )
+        .bind(counterparty_id.as_str())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        to_key(key_id, material)
+    }
+
+    async fn lookup(&self, key_id: Uuid) -> RepositoryResult<KeyLookup> {
+        let row: Option<(String, Option<Vec<u8>>)> = sqlx::query_as(
+            "SELECT counterparty_id, key_material FROM counterparty_keys WHERE key_id = $1",
+        )
+        .bind(key_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        match row {
+            None => Ok(KeyLookup::Unknown),
+            Some((pseudonym, None)) => Ok(KeyLookup::Shredded(CounterpartyId::new(pseudonym))),
+            Some((_, Some(material))) => to_key(key_id, material).map(KeyLookup::Active),
+        }
+    }
+
+    async fn shred(
+        &self,
+        counterparty_id: &CounterpartyId,
+        pseudonym: &CounterpartyId,
+    ) -> RepositoryResult<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE counterparty_keys
+            SET counterparty_id = $2, key_material = NULL, shredded_at = $3
+            WHERE counterparty_id = $1 AND key_material IS NOT NULL
+            "#,
+        )
+        .bind(counterparty_id.as_str())
+        .bind(pseudonym.as_str())
+        .bind(Timestamp::now().timestamp_millis())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+fn to_key(key_id: Uuid, material: Vec<u8>) -> RepositoryResult<CounterpartyKey> {
+    let material: [u8; COUNTERPARTY_KEY_LEN] = material.try_into().map_err(|_| {
+        RepositoryError::serialization(format!("counterparty key {key_id} has the wrong length"))
+    })?;
+    Ok(CounterpartyKey { key_id, material })
+}
@@ -1,15 +1,52 @@
 //! # PostgreSQL Counterparty Repository
 //!
 //! PostgreSQL implementation of [`CounterpartyRepository`] using sqlx.
+//!
+//! # Field Encryption
+//!
+//! With [`PostgresCounterpartyRepository::with_field_encryption`], the
+//! legal name, notification email, wallet addresses and settlement
+//! addresses are encrypted on write and decrypted on read (see
+//! [`field_encryption`](crate::infrastructure::persistence::field_encryption)).
+//! The address lists are stored as an encrypted JSON string in their JSONB
+//! columns. A blind index of the name in `name_index` backs
+//! [`find_by_name`](CounterpartyRepository::find_by_name), which then only
+//! matches whole names, case-insensitively.
+//!
+//! Rows written before encryption was enabled stay readable and are
+//! encrypted by [`PostgresCounterpartyRepository::rotate_keys`], which also
+//! moves rows to the active key after a rotation.
 
 use crate::domain::entities::counterparty::Counterparty;
 use crate::domain::value_objects::CounterpartyId;
+use crate::infrastructure::persistence::field_encryption::{
+    EncryptedString, KeyProvider, blind_index,
+};
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
 use crate::infrastructure::persistence::traits::{
     CounterpartyRepository, RepositoryError, RepositoryResult,
 };
 use async_trait::async_trait;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use sqlx::PgPool;
 use std::str::FromStr;
+use std::sync::Arc;
+
+/// Columns selected for a [`CounterpartyRow`].
+const COUNTERPARTY_COLUMNS: &str = "id, name, counterparty_type, kyc_status, limits, \
+     wallet_addresses, settlement_addresses, notification_channels, notification_email, \
+     notification_webhook_url, notification_grpc_endpoint, \
+     active, created_at, updated_at, settlement_window";
+
+/// Outcome of a [`PostgresCounterpartyRepository::rotate_keys`] run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyRotationReport {
+    /// Rows examined.
+    pub scanned: u64,
+    /// Rows re-encrypted under the active key.
+    pub rotated: u64,
+}
 
 /// PostgreSQL implementation of [`CounterpartyRepository`].
 ///
@@ -17,13 +54,21 @@ use std::str::FromStr;
 #[derive(Debug, Clone)]
 pub struct PostgresCounterpartyRepository {
     pool: PgPool,
+    keys: Option<Arc<dyn KeyProvider>>,
 }
 
 impl PostgresCounterpartyRepository {
     /// Creates a new PostgreSQL counterparty repository.
     #[must_use]
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self { pool, keys: None }
+    }
+
+    /// Encrypts personal data columns with keys from `keys`.
+    #[must_use]
+    pub fn with_field_encryption(mut self, keys: Arc<dyn KeyProvider>) -> Self {
+        self.keys = Some(keys);
+        self
     }
 
     /// Returns a reference to the connection pool.
@@ -31,18 +76,121 @@ impl PostgresCounterpartyRepository {
     pub fn pool(&self) -> &PgPool {
         &self.pool
     }
+
+    /// Re-encrypts, in batches of `batch_size` rows, every row with a
+    /// personal data column in clear or under a key other than the active
+    /// one.
+    ///
+    /// Safe to interrupt and re-run: each row is rewritten on its own.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError::Internal` if field encryption is not
+    /// enabled, and an error if a row cannot be read, decrypted or written.
+    pub async fn rotate_keys(&self, batch_size: usize) -> RepositoryResult<KeyRotationReport> {
+        let keys = self
+            .keys
+            .as_deref()
+            .ok_or_else(|| RepositoryError::internal("field encryption is not enabled"))?;
+        let active = keys
+            .active_key()
+            .map_err(|e| RepositoryError::internal(e.to_string()))?;
+        let limit = i64::try_from(batch_size.max(1)).unwrap_or(i64::MAX);
+
+        let mut report = KeyRotationReport::default();
+        let mut after = String::new();
+        loop {
+            let rows: Vec<CounterpartyRow> = sqlx::query_as(&format!(
+                "SELECT {COUNTERPARTY_COLUMNS} FROM counterparties \
+                 WHERE id > $1 ORDER BY id LIMIT $2"
+            ))
+            .bind(&after)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+            let Some(last) = rows.last() else {
+                break;
+            };
+            after = last.id.clone();
+
+            for row in rows {
+                report.scanned += 1;
+                if row.is_encrypted_under(active.id()) {
+                    continue;
+                }
+                let counterparty = row.try_into_counterparty(Some(keys))?;
+                self.save(&counterparty).await?;
+                report.rotated += 1;
+            }
+            tracing::info!(
+                scanned = report.scanned,
+                rotated = report.rotated,
+                "Counterparty key rotation progress"
+            );
+        }
+        Ok(report)
+    }
+
+    async fn fetch(&self, query: &str, bind: Option<&str>) -> RepositoryResult<Vec<Counterparty>> {
+        let query = sqlx::query_as::<_, CounterpartyRow>(query);
+        let query = match bind {
+            Some(value) => query.bind(value),
+            None => query,
+        };
+        let rows = query.fetch_all(&self.pool).await.map_err(map_sqlx_error)?;
+
+        let mut counterparties = rows
+            .into_iter()
+            .map(|r| r.try_into_counterparty(self.keys.as_deref()))
+            .collect::<RepositoryResult<Vec<_>>>()?;
+        if self.keys.is_some() {
+            // The database can only order the ciphertexts
+            counterparties.sort_by(|a, b| a.name().cmp(b.name()));
+        }
+        Ok(counterparties)
+    }
+
+    fn seal(&self, value: &str) -> RepositoryResult<String> {
+        match &self.keys {
+            Some(keys) => EncryptedString::encrypt(value, keys.as_ref())
+                .map(EncryptedString::into_inner)
+                .map_err(|e| RepositoryError::serialization(e.to_string())),
+            None => Ok(value.to_string()),
+        }
+    }
+
+    fn seal_json<T: Serialize + ?Sized>(&self, value: &T) -> RepositoryResult<serde_json::Value> {
+        let json = serde_json::to_value(value)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        match self.keys {
+            Some(_) => Ok(serde_json::Value::String(self.seal(&json.to_string())?)),
+            None => Ok(json),
+        }
+    }
 }
 
 #[async_trait]
 impl CounterpartyRepository for PostgresCounterpartyRepository {
     async fn save(&self, counterparty: &Counterparty) -> RepositoryResult<()> {
         let id = counterparty.id().as_str();
-        let name = counterparty.name();
+        let name = self.seal(counterparty.name())?;
+        let name_index = self
+            .keys
+            .as_deref()
+            .map(|keys| blind_index(counterparty.name(), keys))
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
         let counterparty_type = counterparty.counterparty_type().to_string();
         let kyc_status = counterparty.kyc_status().to_string();
         let limits = serde_json::to_value(counterparty.limits())
             .map_err(|e| RepositoryError::serialization(e.to_string()))?;
-        let wallet_addresses = serde_json::to_value(counterparty.wallet_addresses())
+        let wallet_addresses = self.seal_json(counterparty.wallet_addresses())?;
+        let settlement_addresses = self.seal_json(counterparty.settlement_addresses())?;
+        let settlement_window = counterparty
+            .settlement_window()
+            .map(serde_json::to_value)
+            .transpose()
             .map_err(|e| RepositoryError::serialization(e.to_string()))?;
 
         let prefs = counterparty.notification_preferences();
@@ -51,7 +199,10 @@ impl CounterpartyRepository for PostgresCounterpartyRepository {
             .iter()
             .map(|c| c.to_string())
             .collect();
-        let notification_email = prefs.email_address().map(|s| s.to_string());
+        let notification_email = prefs
+            .email_address()
+            .map(|email| self.seal(email))
+            .transpose()?;
         let notification_webhook_url = prefs.webhook_url().map(|s| s.to_string());
         let notification_grpc_endpoint = prefs.grpc_endpoint().map(|s| s.to_string());
 
@@ -63,30 +214,34 @@ impl CounterpartyRepository for PostgresCounterpartyRepository {
             r#"
             INSERT INTO counterparties (
                 id, name, counterparty_type, kyc_status, limits,
-                wallet_addresses, notification_channels, notification_email,
-                notification_webhook_url, notification_grpc_endpoint,
-                active, created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+                wallet_addresses, settlement_addresses, notification_channels,
+                notification_email, notification_webhook_url, notification_grpc_endpoint,
+                active, created_at, updated_at, settlement_window, name_index
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
             ON CONFLICT (id) DO UPDATE SET
                 name = EXCLUDED.name,
+                name_index = EXCLUDED.name_index,
                 counterparty_type = EXCLUDED.counterparty_type,
                 kyc_status = EXCLUDED.kyc_status,
                 limits = EXCLUDED.limits,
                 wallet_addresses = EXCLUDED.wallet_addresses,
+                settlement_addresses = EXCLUDED.settlement_addresses,
                 notification_channels = EXCLUDED.notification_channels,
                 notification_email = EXCLUDED.notification_email,
                 notification_webhook_url = EXCLUDED.notification_webhook_url,
                 notification_grpc_endpoint = EXCLUDED.notification_grpc_endpoint,
                 active = EXCLUDED.active,
-                updated_at = EXCLUDED.updated_at
+                updated_at = EXCLUDED.updated_at,
+                settlement_window = EXCLUDED.settlement_window
             "#,
         )
         .bind(id)
-        .bind(name)
+        .bind(&name)
         .bind(&counterparty_type)
         .bind(&kyc_status)
         .bind(&limits)
         .bind(&wallet_addresses)
+        .bind(&settlement_addresses)
         .bind(&notification_channels)
         .bind(&notification_email)
         .bind(&notification_webhook_url)
@@ -94,91 +249,68 @@ impl CounterpartyRepository for PostgresCounterpartyRepository {
         .bind(active)
         .bind(created_at)
         .bind(updated_at)
+        .bind(&settlement_window)
+        .bind(&name_index)
         .execute(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
 
         Ok(())
     }
 
     async fn get(&self, id: &CounterpartyId) -> RepositoryResult<Option<Counterparty>> {
-        let id_str = id.as_str();
-
-        let row: Option<CounterpartyRow> = sqlx::query_as(
-            r#"
-            SELECT id, name, counterparty_type, kyc_status, limits,
-                   wallet_addresses, notification_channels, notification_email,
-                   notification_webhook_url, notification_grpc_endpoint,
-                   active, created_at, updated_at
-            FROM counterparties WHERE id = $1
-            "#,
-        )
-        .bind(id_str)
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
-
-        row.map(|r| r.try_into_counterparty()).transpose()
+        let mut found = self
+            .fetch(
+                &format!("SELECT {COUNTERPARTY_COLUMNS} FROM counterparties WHERE id = $1"),
+                Some(id.as_str()),
+            )
+            .await?;
+        Ok(found.pop())
     }
 
     async fn get_all(&self) -> RepositoryResult<Vec<Counterparty>> {
-        let rows: Vec<CounterpartyRow> = sqlx::query_as(
-            r#"
-            SELECT id, name, counterparty_type, kyc_status, limits,
-                   wallet_addresses, notification_channels, notification_email,
-                   notification_webhook_url, notification_grpc_endpoint,
-                   active, created_at, updated_at
-            FROM counterparties ORDER BY name ASC
-            "#,
+        self.fetch(
+            &format!("SELECT {COUNTERPARTY_COLUMNS} FROM counterparties ORDER BY name ASC"),
+            None,
         )
-        .fetch_all(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
-
-        rows.into_iter()
-            .map(|r| r.try_into_counterparty())
-            .collect()
     }
 
     async fn find_active(&self) -> RepositoryResult<Vec<Counterparty>> {
-        let rows: Vec<CounterpartyRow> = sqlx::query_as(
-            r#"
-            SELECT id, name, counterparty_type, kyc_status, limits,
-                   wallet_addresses, notification_channels, notification_email,
-                   notification_webhook_url, notification_grpc_endpoint,
-                   active, created_at, updated_at
-            FROM counterparties WHERE active = true ORDER BY name ASC
-            "#,
+        self.fetch(
+            &format!(
+                "SELECT {COUNTERPARTY_COLUMNS} FROM counterparties \
+                 WHERE active = true ORDER BY name ASC"
+            ),
+            None,
         )
-        .fetch_all(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
-
-        rows.into_iter()
-            .map(|r| r.try_into_counterparty())
-            .collect()
     }
 
     async fn find_by_name(&self, name: &str) -> RepositoryResult<Vec<Counterparty>> {
-        let pattern = format!("%{}%", name.to_lowercase());
+        // Encrypted names can only be matched whole, through their blind index
+        if let Some(keys) = self.keys.as_deref() {
+            let index = blind_index(name, keys)
+                .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+            return self
+                .fetch(
+                    &format!(
+                        "SELECT {COUNTERPARTY_COLUMNS} FROM counterparties WHERE name_index = $1"
+                    ),
+                    Some(&index),
+                )
+                .await;
+        }
 
-        let rows: Vec<CounterpartyRow> = sqlx::query_as(
-            r#"
-            SELECT id, name, counterparty_type, kyc_status, limits,
-                   wallet_addresses, notification_channels, notification_email,
-                   notification_webhook_url, notification_grpc_endpoint,
-                   active, created_at, updated_at
-            FROM counterparties WHERE LOWER(name) LIKE $1 ORDER BY name ASC
-            "#,
+        let pattern = format!("%{}%", name.to_lowercase());
+        self.fetch(
+            &format!(
+                "SELECT {COUNTERPARTY_COLUMNS} FROM counterparties \
+                 WHERE LOWER(name) LIKE $1 ORDER BY name ASC"
+            ),
+            Some(&pattern),
         )
-        .bind(&pattern)
-        .fetch_all(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
-
-        rows.into_iter()
-            .map(|r| r.try_into_counterparty())
-            .collect()
     }
 
     async fn delete(&self, id: &CounterpartyId) -> RepositoryResult<bool> {
@@ -188,7 +320,7 @@ impl CounterpartyRepository for PostgresCounterpartyRepository {
             .bind(id_str)
             .execute(&self.pool)
             .await
-            .map_err(|e| RepositoryError::query(e.to_string()))?;
+            .map_err(map_sqlx_error)?;
 
         Ok(result.rows_affected() > 0)
     }
@@ -197,7 +329,7 @@ impl CounterpartyRepository for PostgresCounterpartyRepository {
         let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM counterparties")
             .fetch_one(&self.pool)
             .await
-            .map_err(|e| RepositoryError::query(e.to_string()))?;
+            .map_err(map_sqlx_error)?;
 
         Ok(count as u64)
     }
@@ -207,7 +339,7 @@ impl CounterpartyRepository for PostgresCounterpartyRepository {
             sqlx::query_as("SELECT COUNT(*) FROM counterparties WHERE active = true")
                 .fetch_one(&self.pool)
                 .await
-                .map_err(|e| RepositoryError::query(e.to_string()))?;
+                .map_err(map_sqlx_error)?;
 
         Ok(count as u64)
     }
@@ -222,6 +354,7 @@ struct CounterpartyRow {
     kyc_status: String,
     limits: serde_json::Value,
     wallet_addresses: serde_json::Value,
+    settlement_addresses: serde_json::Value,
     notification_channels: Vec<String>,
     notification_email: Option<String>,
     notification_webhook_url: Option<String>,
@@ -229,13 +362,31 @@ struct CounterpartyRow {
     active: bool,
     created_at: i64,
     updated_at: i64,
+    settlement_window: Option<serde_json::Value>,
 }
 
 impl CounterpartyRow {
-    /// Converts the row into a Counterparty entity.
-    fn try_into_counterparty(self) -> RepositoryResult<Counterparty> {
+    /// Returns true if every personal data column is encrypted under
+    /// `key_id`.
+    fn is_encrypted_under(&self, key_id: &str) -> bool {
+        let stored_key = |value: &str| EncryptedString::from_stored(value).key_id() == Some(key_id);
+        let json_key = |value: &serde_json::Value| value.as_str().is_some_and(stored_key);
+
+        stored_key(&self.name)
+            && self.notification_email.as_deref().is_none_or(stored_key)
+            && json_key(&self.wallet_addresses)
+            && json_key(&self.settlement_addresses)
+    }
+
+    /// Converts the row into a Counterparty entity, decrypting with `keys`.
+    fn try_into_counterparty(
+        self,
+        keys: Option<&dyn KeyProvider>,
+    ) -> RepositoryResult<Counterparty> {
         use crate::domain::entities::CounterpartyType;
-        use crate::domain::entities::counterparty::{CounterpartyLimits, KycStatus, WalletAddress};
+        use crate::domain::entities::counterparty::{
+            CounterpartyLimits, KycStatus, SettlementAddress, WalletAddress,
+        };
         use crate::domain::value_objects::timestamp::Timestamp;
 
         let id = CounterpartyId::new(&self.id);
@@ -246,8 +397,14 @@ impl CounterpartyRow {
             .map_err(|e| RepositoryError::serialization(e.to_string()))?;
         let limits: CounterpartyLimits = serde_json::from_value(self.limits)
             .map_err(|e| RepositoryError::serialization(e.to_string()))?;
-        let wallet_addresses: Vec<WalletAddress> = serde_json::from_value(self.wallet_addresses)
-            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let wallet_addresses: Vec<WalletAddress> = open_json(self.wallet_addresses, keys)?;
+        let settlement_addresses: Vec<SettlementAddress> =
+            open_json(self.settlement_addresses, keys)?;
+        let name = open(self.name, keys)?;
+        let notification_email = self
+            .notification_email
+            .map(|email| open(email, keys))
+            .transpose()?;
 
         use crate::domain::value_objects::{ConfirmationChannel, NotificationPreferences};
         let channels: Result<Vec<ConfirmationChannel>, _> = self
@@ -266,12 +423,18 @@ impl CounterpartyRow {
 
         let notification_preferences = NotificationPreferences::new(
             channels,
-            self.notification_email,
+            notification_email,
             self.notification_webhook_url,
             self.notification_grpc_endpoint,
         )
         .map_err(|e| RepositoryError::serialization(e.to_string()))?;
 
+        let settlement_window = self
+            .settlement_window
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+
         let created_at = Timestamp::from_millis(self.created_at).ok_or_else(|| {
             RepositoryError::serialization("invalid created_at timestamp".to_string())
         })?;
@@ -281,15 +444,44 @@ impl CounterpartyRow {
 
         Ok(Counterparty::from_parts(
             id,
-            self.name,
+            name,
             counterparty_type,
             kyc_status,
             limits,
             wallet_addresses,
+            settlement_addresses,
             notification_preferences,
             self.active,
             created_at,
             updated_at,
-        ))
+        )
+        .with_settlement_window(settlement_window))
+    }
+}
+
+/// Decrypts a stored column value; values in clear are returned as is.
+fn open(stored: String, keys: Option<&dyn KeyProvider>) -> RepositoryResult<String> {
+    let value = EncryptedString::from_stored(stored);
+    match keys {
+        Some(keys) => value
+            .decrypt(keys)
+            .map_err(|e| RepositoryError::serialization(e.to_string())),
+        None if value.is_encrypted() => Err(RepositoryError::serialization(
+            "counterparty column is encrypted but field encryption is not enabled",
+        )),
+        None => Ok(value.into_inner()),
     }
 }
+
+/// Decodes a JSONB column, decrypting it first if it holds an encrypted
+/// JSON string.
+fn open_json<T: DeserializeOwned>(
+    stored: serde_json::Value,
+    keys: Option<&dyn KeyProvider>,
+) -> RepositoryResult<T> {
+    let json = match stored {
+        serde_json::Value::String(sealed) => serde_json::from_str(&open(sealed, keys)?),
+        json => serde_json::from_value(json),
+    };
+    json.map_err(|e| RepositoryError::serialization(e.to_string()))
+}
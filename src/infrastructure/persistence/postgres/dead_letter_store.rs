@@ -0,0 +1,171 @@
+//! # PostgreSQL Dead Letter Store
+//!
+//! PostgreSQL implementation of [`DeadLetterStore`] using sqlx.
+
+use crate::domain::value_objects::EventId;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::infrastructure::persistence::dead_letter::{
+    DeadLetter, DeadLetterStatus, DeadLetterStore,
+};
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
+use crate::infrastructure::persistence::traits::{RepositoryError, RepositoryResult};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// PostgreSQL implementation of [`DeadLetterStore`].
+///
+/// Uses connection pooling via `sqlx::PgPool`.
+#[derive(Debug, Clone)]
+pub struct PostgresDeadLetterStore {
+    pool: PgPool,
+}
+
+impl PostgresDeadLetterStore {
+    /// Creates a new PostgreSQL dead letter store.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl DeadLetterStore for PostgresDeadLetterStore {
+    async fn save(&self, letter: &DeadLetter) -> RepositoryResult<()> {
+        let attempts = i32::try_from(letter.attempts)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let payload = serde_json::to_value(&letter.event)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO dead_letters (
+                consumer, event_id, event_name, payload, last_error, attempts,
+                status, first_seen_at, last_seen_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (consumer, event_id) DO UPDATE SET
+                last_error = EXCLUDED.last_error,
+                attempts = EXCLUDED.attempts,
+                status = EXCLUDED.status,
+                last_seen_at = EXCLUDED.last_seen_at
+            "#,
+        )
+        .bind(&letter.consumer)
+        .bind(letter.event_id().get())
+        .bind(&letter.event.event_name)
+        .bind(payload)
+        .bind(&letter.last_error)
+        .bind(attempts)
+        .bind(letter.status.to_string())
+        .bind(letter.first_seen_at.timestamp_millis())
+        .bind(letter.last_seen_at.timestamp_millis())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn find(
+        &self,
+        consumer: &str,
+        event_id: EventId,
+    ) -> RepositoryResult<Option<DeadLetter>> {
+        let row: Option<DeadLetterRow> = sqlx::query_as(
+            r#"
+            SELECT consumer, event_id, payload, last_error, attempts, status,
+                   first_seen_at, last_seen_at
+            FROM dead_letters WHERE consumer = $1 AND event_id = $2
+            "#,
+        )
+        .bind(consumer)
+        .bind(event_id.get())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(DeadLetterRow::try_into_letter).transpose()
+    }
+
+    async fn list_parked(
+        &self,
+        consumer: Option<&str>,
+        limit: usize,
+    ) -> RepositoryResult<Vec<DeadLetter>> {
+        let rows: Vec<DeadLetterRow> = sqlx::query_as(
+            r#"
+            SELECT consumer, event_id, payload, last_error, attempts, status,
+                   first_seen_at, last_seen_at
+            FROM dead_letters
+            WHERE status = 'PARKED' AND ($1::VARCHAR IS NULL OR consumer = $1)
+            ORDER BY first_seen_at ASC, event_id ASC
+            LIMIT $2
+            "#,
+        )
+        .bind(consumer)
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter()
+            .map(DeadLetterRow::try_into_letter)
+            .collect()
+    }
+}
+
+/// Row type for dead letter queries.
+#[derive(Debug, sqlx::FromRow)]
+struct DeadLetterRow {
+    consumer: String,
+    event_id: Uuid,
+    payload: serde_json::Value,
+    last_error: String,
+    attempts: i32,
+    status: String,
+    first_seen_at: i64,
+    last_seen_at: i64,
+}
+
+impl DeadLetterRow {
+    /// Converts the row into a [`DeadLetter`].
+    fn try_into_letter(self) -> RepositoryResult<DeadLetter> {
+        let event = serde_json::from_value(self.payload)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let status: DeadLetterStatus = self
+            .status
+            .parse()
+            .map_err(RepositoryError::serialization)?;
+        let attempts = u32::try_from(self.attempts)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let first_seen_at = Timestamp::from_millis(self.first_seen_at).ok_or_else(|| {
+            RepositoryError::serialization("invalid first_seen_at timestamp".to_string())
+        })?;
+        let last_seen_at = Timestamp::from_millis(self.last_seen_at).ok_or_else(|| {
+            RepositoryError::serialization("invalid last_seen_at timestamp".to_string())
+        })?;
+
+        let letter = DeadLetter {
+            consumer: self.consumer,
+            event,
+            last_error: self.last_error,
+            attempts,
+            status,
+            first_seen_at,
+            last_seen_at,
+        };
+        if letter.event_id().get() != self.event_id {
+            return Err(RepositoryError::serialization(
+                "dead letter payload does not match its event_id".to_string(),
+            ));
+        }
+        Ok(letter)
+    }
+}
@@ -0,0 +1,155 @@
+//! # PostgreSQL Erasure Request Repository
+//!
+//! PostgreSQL implementation of [`ErasureRequestRepository`] using sqlx.
+
+use crate::domain::entities::erasure_request::{ErasureRequest, ErasureRequestStatus};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{CounterpartyId, ErasureRequestId};
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
+use crate::infrastructure::persistence::traits::{
+    ErasureRequestRepository, RepositoryError, RepositoryResult,
+};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// PostgreSQL implementation of [`ErasureRequestRepository`].
+///
+/// Uses connection pooling via `sqlx::PgPool`.
+#[derive(Debug, Clone)]
+pub struct PostgresErasureRequestRepository {
+    pool: PgPool,
+}
+
+impl PostgresErasureRequestRepository {
+    /// Creates a new PostgreSQL erasure request repository.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl ErasureRequestRepository for PostgresErasureRequestRepository {
+    async fn save(&self, request: &ErasureRequest) -> RepositoryResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO erasure_requests (
+                id, counterparty_id, requested_by, reason, status, approved_by,
+                approved_at, executed_at, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (id) DO UPDATE SET
+                counterparty_id = EXCLUDED.counterparty_id,
+                status = EXCLUDED.status,
+                approved_by = EXCLUDED.approved_by,
+                approved_at = EXCLUDED.approved_at,
+                executed_at = EXCLUDED.executed_at,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(request.id().get())
+        .bind(request.counterparty_id().as_str())
+        .bind(request.requested_by())
+        .bind(request.reason())
+        .bind(request.status().to_string())
+        .bind(request.approved_by())
+        .bind(request.approved_at().map(|t| t.timestamp_millis()))
+        .bind(request.executed_at().map(|t| t.timestamp_millis()))
+        .bind(request.created_at().timestamp_millis())
+        .bind(request.updated_at().timestamp_millis())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn get(&self, id: ErasureRequestId) -> RepositoryResult<Option<ErasureRequest>> {
+        let row: Option<ErasureRequestRow> = sqlx::query_as(
+            r#"
+            SELECT id, counterparty_id, requested_by, reason, status, approved_by,
+                   approved_at, executed_at, created_at, updated_at
+            FROM erasure_requests WHERE id = $1
+            "#,
+        )
+        .bind(id.get())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(ErasureRequestRow::try_into_request).transpose()
+    }
+
+    async fn find_by_counterparty(
+        &self,
+        counterparty_id: &CounterpartyId,
+    ) -> RepositoryResult<Vec<ErasureRequest>> {
+        let rows: Vec<ErasureRequestRow> = sqlx::query_as(
+            r#"
+            SELECT id, counterparty_id, requested_by, reason, status, approved_by,
+                   approved_at, executed_at, created_at, updated_at
+            FROM erasure_requests
+            WHERE counterparty_id = $1
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(counterparty_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter()
+            .map(ErasureRequestRow::try_into_request)
+            .collect()
+    }
+}
+
+/// Row type for erasure request queries.
+#[derive(Debug, sqlx::FromRow)]
+struct ErasureRequestRow {
+    id: Uuid,
+    counterparty_id: String,
+    requested_by: String,
+    reason: String,
+    status: String,
+    approved_by: Option<String>,
+    approved_at: Option<i64>,
+    executed_at: Option<i64>,
+    created_at: i64,
+    updated_at: i64,
+}
+
+impl ErasureRequestRow {
+    /// Converts the row into an [`ErasureRequest`].
+    fn try_into_request(self) -> RepositoryResult<ErasureRequest> {
+        let status: ErasureRequestStatus = self
+            .status
+            .parse()
+            .map_err(RepositoryError::serialization)?;
+
+        Ok(ErasureRequest::from_parts(
+            ErasureRequestId::new(self.id),
+            CounterpartyId::new(self.counterparty_id),
+            self.requested_by,
+            self.reason,
+            status,
+            self.approved_by,
+            self.approved_at.map(millis_to_timestamp).transpose()?,
+            self.executed_at.map(millis_to_timestamp).transpose()?,
+            millis_to_timestamp(self.created_at)?,
+            millis_to_timestamp(self.updated_at)?,
+        ))
+    }
+}
+
+fn millis_to_timestamp(millis: i64) -> RepositoryResult<Timestamp> {
+    Timestamp::from_millis(millis)
+        .ok_or_else(|| RepositoryError::serialization(format!("invalid timestamp: {millis}")))
+}
@@ -3,16 +3,23 @@
 //! PostgreSQL implementation of [`EventStore`] using sqlx.
 //!
 //! This implementation provides append-only event storage with JSONB
-//! serialization for event payloads.
+//! serialization for event payloads. Aggregate sequence numbers are checked
+//! in the insert itself, and a unique index on (aggregate ID, sequence)
+//! catches concurrent appends of the same sequence number.
 
 use crate::domain::events::domain_event::EventType;
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{EventId, RfqId};
+use crate::domain::value_objects::{CorrelationId, EventId, RfqId};
+use crate::infrastructure::persistence::cursor::PageCursor;
 use crate::infrastructure::persistence::event_store::{
     EventStore, EventStoreError, EventStoreResult, StoredEvent,
 };
 use async_trait::async_trait;
 use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Unique index on (aggregate ID, sequence) of versioned events.
+const AGGREGATE_SEQUENCE_INDEX: &str = "idx_domain_events_aggregate_sequence";
 
 /// PostgreSQL implementation of [`EventStore`].
 ///
@@ -46,6 +53,37 @@ impl PostgresEventStore {
     pub fn pool(&self) -> &PgPool {
         &self.pool
     }
+
+    /// Returns the highest stored sequence number of an aggregate, 0 if none.
+    async fn last_aggregate_sequence(&self, aggregate_id: &str) -> EventStoreResult<u64> {
+        let (last,): (i64,) = sqlx::query_as(
+            "SELECT COALESCE(MAX(aggregate_sequence), 0) FROM domain_events WHERE aggregate_id = $1",
+        )
+        .bind(aggregate_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::query(e.to_string()))?;
+
+        Ok(last as u64)
+    }
+
+    /// Builds the conflict error for a versioned event that was not stored.
+    async fn version_conflict(&self, event: &StoredEvent) -> EventStoreError {
+        let Some((aggregate_id, _)) = event.aggregate_key() else {
+            return EventStoreError::internal("unversioned event reported a version conflict");
+        };
+        match self
+            .last_aggregate_sequence(&aggregate_id.to_string())
+            .await
+        {
+            Ok(last) => EventStoreError::version_conflict(
+                aggregate_id,
+                last.saturating_add(1),
+                event.aggregate_sequence,
+            ),
+            Err(e) => e,
+        }
+    }
 }
 
 #[async_trait]
@@ -58,13 +96,28 @@ impl EventStore for PostgresEventStore {
         let timestamp = event.timestamp.timestamp_millis();
         let payload = &event.payload;
         let sequence = event.sequence as i64;
+        let correlation_id = event.correlation_id.map(|id| id.to_string());
+        let aggregate_id = event.aggregate_id.map(|id| id.to_string());
+        let aggregate_version = i64::try_from(event.aggregate_version)
+            .map_err(|_| EventStoreError::serialization("aggregate version out of range"))?;
+        let aggregate_sequence = i64::try_from(event.aggregate_sequence)
+            .map_err(|_| EventStoreError::serialization("aggregate sequence out of range"))?;
 
-        sqlx::query(
+        // A versioned event is only inserted if it directly follows the
+        // aggregate's last stored event.
+        let result = sqlx::query(
             r#"
             INSERT INTO domain_events (
                 event_id, rfq_id, event_type, event_name,
-                timestamp, payload, sequence
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+                timestamp, payload, sequence, correlation_id,
+                aggregate_id, aggregate_version, aggregate_sequence
+            )
+            SELECT $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11
+            WHERE $9::VARCHAR IS NULL OR $10 = 0 OR $11 = 1 + (
+                SELECT COALESCE(MAX(aggregate_sequence), 0)
+                FROM domain_events
+                WHERE aggregate_id = $9
+            )
             "#,
         )
         .bind(&event_id)
@@ -74,11 +127,21 @@ impl EventStore for PostgresEventStore {
         .bind(timestamp)
         .bind(payload)
         .bind(sequence)
+        .bind(&correlation_id)
+        .bind(&aggregate_id)
+        .bind(aggregate_version)
+        .bind(aggregate_sequence)
         .execute(&self.pool)
-        .await
-        .map_err(|e| EventStoreError::query(e.to_string()))?;
+        .await;
 
-        Ok(())
+        match result {
+            Ok(done) if done.rows_affected() == 0 => Err(self.version_conflict(&event).await),
+            Ok(_) => Ok(()),
+            Err(sqlx::Error::Database(db)) if db.constraint() == Some(AGGREGATE_SEQUENCE_INDEX) => {
+                Err(self.version_conflict(&event).await)
+            }
+            Err(e) => Err(EventStoreError::query(e.to_string())),
+        }
     }
 
     async fn get_events(&self, rfq_id: RfqId) -> EventStoreResult<Vec<StoredEvent>> {
@@ -87,7 +150,8 @@ impl EventStore for PostgresEventStore {
         let rows: Vec<EventRow> = sqlx::query_as(
             r#"
             SELECT event_id, rfq_id, event_type, event_name,
-                   timestamp, payload, sequence
+                   timestamp, payload, sequence, correlation_id,
+                   aggregate_id, aggregate_version, aggregate_sequence
             FROM domain_events
             WHERE rfq_id = $1
             ORDER BY sequence ASC
@@ -109,7 +173,8 @@ impl EventStore for PostgresEventStore {
         let rows: Vec<EventRow> = sqlx::query_as(
             r#"
             SELECT event_id, rfq_id, event_type, event_name,
-                   timestamp, payload, sequence
+                   timestamp, payload, sequence, correlation_id,
+                   aggregate_id, aggregate_version, aggregate_sequence
             FROM domain_events
             WHERE timestamp > $1
             ORDER BY timestamp ASC, sequence ASC
@@ -134,7 +199,8 @@ impl EventStore for PostgresEventStore {
         let rows: Vec<EventRow> = sqlx::query_as(
             r#"
             SELECT event_id, rfq_id, event_type, event_name,
-                   timestamp, payload, sequence
+                   timestamp, payload, sequence, correlation_id,
+                   aggregate_id, aggregate_version, aggregate_sequence
             FROM domain_events
             WHERE event_type = $1
             ORDER BY timestamp ASC, sequence ASC
@@ -150,6 +216,70 @@ impl EventStore for PostgresEventStore {
             .collect()
     }
 
+    async fn query_by_time_range(
+        &self,
+        from: Timestamp,
+        to: Timestamp,
+        event_types: Option<Vec<EventType>>,
+        limit: usize,
+        cursor: Option<&PageCursor>,
+    ) -> EventStoreResult<Vec<StoredEvent>> {
+        // Keyset pagination served by idx_domain_events_timestamp_event_id.
+        let event_types: Option<Vec<String>> =
+            event_types.map(|types| types.iter().map(ToString::to_string).collect());
+
+        let rows: Vec<EventRow> = sqlx::query_as(
+            r#"
+            SELECT event_id, rfq_id, event_type, event_name,
+                   timestamp, payload, sequence, correlation_id,
+                   aggregate_id, aggregate_version, aggregate_sequence
+            FROM domain_events
+            WHERE timestamp >= $1 AND timestamp < $2
+              AND ($3::TEXT[] IS NULL OR event_type = ANY($3))
+              AND ($4::BIGINT IS NULL OR (timestamp, event_id) > ($4, $5))
+            ORDER BY timestamp ASC, event_id ASC
+            LIMIT $6
+            "#,
+        )
+        .bind(from.timestamp_millis())
+        .bind(to.timestamp_millis())
+        .bind(&event_types)
+        .bind(cursor.map(PageCursor::created_at_millis))
+        .bind(cursor.map(PageCursor::id))
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::query(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|r| r.try_into_stored_event())
+            .collect()
+    }
+
+    async fn query_by_correlation(
+        &self,
+        correlation_id: CorrelationId,
+    ) -> EventStoreResult<Vec<StoredEvent>> {
+        let rows: Vec<EventRow> = sqlx::query_as(
+            r#"
+            SELECT event_id, rfq_id, event_type, event_name,
+                   timestamp, payload, sequence, correlation_id,
+                   aggregate_id, aggregate_version, aggregate_sequence
+            FROM domain_events
+            WHERE correlation_id = $1
+            ORDER BY timestamp ASC, sequence ASC, event_id ASC
+            "#,
+        )
+        .bind(correlation_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| EventStoreError::query(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|r| r.try_into_stored_event())
+            .collect()
+    }
+
     async fn count(&self) -> EventStoreResult<u64> {
         let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM domain_events")
             .fetch_one(&self.pool)
@@ -187,6 +317,13 @@ impl EventStore for PostgresEventStore {
             None => Ok(1),
         }
     }
+
+    async fn next_aggregate_sequence(&self, aggregate_id: Uuid) -> EventStoreResult<u64> {
+        let last = self
+            .last_aggregate_sequence(&aggregate_id.to_string())
+            .await?;
+        Ok(last.saturating_add(1))
+    }
 }
 
 /// Row type for event queries.
@@ -199,13 +336,15 @@ struct EventRow {
     timestamp: i64,
     payload: serde_json::Value,
     sequence: i64,
+    correlation_id: Option<String>,
+    aggregate_id: Option<String>,
+    aggregate_version: i64,
+    aggregate_sequence: i64,
 }
 
 impl EventRow {
     /// Converts the row into a StoredEvent.
     fn try_into_stored_event(self) -> EventStoreResult<StoredEvent> {
-        use uuid::Uuid;
-
         let event_uuid = Uuid::parse_str(&self.event_id)
             .map_err(|e| EventStoreError::deserialization(e.to_string()))?;
         let event_id = EventId::new(event_uuid);
@@ -216,6 +355,18 @@ impl EventRow {
             .transpose()
             .map_err(|e| EventStoreError::deserialization(e.to_string()))?;
 
+        let correlation_id = self
+            .correlation_id
+            .map(|s| Uuid::parse_str(&s).map(CorrelationId::new))
+            .transpose()
+            .map_err(|e| EventStoreError::deserialization(e.to_string()))?;
+
+        let aggregate_id = self
+            .aggregate_id
+            .map(|s| Uuid::parse_str(&s))
+            .transpose()
+            .map_err(|e| EventStoreError::deserialization(e.to_string()))?;
+
         let event_type: EventType = serde_json::from_str(&format!("\"{}\"", self.event_type))
             .map_err(|e| EventStoreError::deserialization(e.to_string()))?;
 
@@ -230,6 +381,10 @@ impl EventRow {
             timestamp,
             payload: self.payload,
             sequence: self.sequence as u64,
+            correlation_id,
+            aggregate_id,
+            aggregate_version: self.aggregate_version as u64,
+            aggregate_sequence: self.aggregate_sequence as u64,
         })
     }
 }
@@ -0,0 +1,39 @@
+//! # PostgreSQL Health Check
+//!
+//! Readiness check against the PostgreSQL connection pool.
+
+use crate::application::services::readiness::DependencyCheck;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+/// Checks that the pool can hand out a connection and run `SELECT 1`.
+///
+/// Fails when the pool is exhausted or the database is unreachable; the
+/// readiness checker bounds how long the check may wait for a connection.
+#[derive(Debug, Clone)]
+pub struct PostgresPoolCheck {
+    pool: PgPool,
+}
+
+impl PostgresPoolCheck {
+    /// Creates a check against the given pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DependencyCheck for PostgresPoolCheck {
+    fn name(&self) -> &str {
+        "postgres"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
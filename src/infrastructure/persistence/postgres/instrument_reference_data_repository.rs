@@ -0,0 +1,141 @@
+//! # PostgreSQL Instrument Reference Data Repository
+//!
+//! PostgreSQL implementation of [`InstrumentReferenceDataRepository`] using sqlx.
+
+use crate::domain::value_objects::{InstrumentReferenceData, Quantity, Symbol};
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
+use crate::infrastructure::persistence::traits::{
+    InstrumentReferenceDataRepository, RepositoryError, RepositoryResult,
+};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+
+/// PostgreSQL implementation of [`InstrumentReferenceDataRepository`].
+///
+/// Uses connection pooling via `sqlx::PgPool`.
+#[derive(Debug, Clone)]
+pub struct PostgresInstrumentReferenceDataRepository {
+    pool: PgPool,
+}
+
+impl PostgresInstrumentReferenceDataRepository {
+    /// Creates a new PostgreSQL instrument reference data repository.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl InstrumentReferenceDataRepository for PostgresInstrumentReferenceDataRepository {
+    async fn save(&self, data: &InstrumentReferenceData) -> RepositoryResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO instrument_reference_data (
+                symbol, tick_size, lot_size, min_order_size, max_order_size,
+                contract_multiplier
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (symbol) DO UPDATE SET
+                tick_size = EXCLUDED.tick_size,
+                lot_size = EXCLUDED.lot_size,
+                min_order_size = EXCLUDED.min_order_size,
+                max_order_size = EXCLUDED.max_order_size,
+                contract_multiplier = EXCLUDED.contract_multiplier
+            "#,
+        )
+        .bind(data.symbol().as_str())
+        .bind(data.tick_size())
+        .bind(data.lot_size())
+        .bind(data.min_order_size().get())
+        .bind(data.max_order_size().map(Quantity::get))
+        .bind(data.contract_multiplier())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn get(&self, symbol: &Symbol) -> RepositoryResult<Option<InstrumentReferenceData>> {
+        let row: Option<InstrumentReferenceDataRow> = sqlx::query_as(
+            r#"
+            SELECT symbol, tick_size, lot_size, min_order_size, max_order_size,
+                   contract_multiplier
+            FROM instrument_reference_data WHERE symbol = $1
+            "#,
+        )
+        .bind(symbol.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(InstrumentReferenceDataRow::try_into_reference_data)
+            .transpose()
+    }
+
+    async fn get_all(&self) -> RepositoryResult<Vec<InstrumentReferenceData>> {
+        let rows: Vec<InstrumentReferenceDataRow> = sqlx::query_as(
+            r#"
+            SELECT symbol, tick_size, lot_size, min_order_size, max_order_size,
+                   contract_multiplier
+            FROM instrument_reference_data ORDER BY symbol ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter()
+            .map(InstrumentReferenceDataRow::try_into_reference_data)
+            .collect()
+    }
+
+    async fn delete(&self, symbol: &Symbol) -> RepositoryResult<bool> {
+        let result = sqlx::query("DELETE FROM instrument_reference_data WHERE symbol = $1")
+            .bind(symbol.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// Row type for instrument reference data queries.
+#[derive(Debug, sqlx::FromRow)]
+struct InstrumentReferenceDataRow {
+    symbol: String,
+    tick_size: Decimal,
+    lot_size: Decimal,
+    min_order_size: Decimal,
+    max_order_size: Option<Decimal>,
+    contract_multiplier: Decimal,
+}
+
+impl InstrumentReferenceDataRow {
+    /// Converts the row into [`InstrumentReferenceData`].
+    fn try_into_reference_data(self) -> RepositoryResult<InstrumentReferenceData> {
+        let symbol =
+            Symbol::new(&self.symbol).map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let min = Quantity::from_decimal(self.min_order_size)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let max = self
+            .max_order_size
+            .map(Quantity::from_decimal)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+
+        InstrumentReferenceData::new(symbol, self.tick_size, self.lot_size)
+            .and_then(|data| data.with_order_size_limits(min, max))
+            .and_then(|data| data.with_contract_multiplier(self.contract_multiplier))
+            .map_err(|e| RepositoryError::serialization(e.to_string()))
+    }
+}
@@ -0,0 +1,212 @@
+//! # PostgreSQL Advisory Lock Manager
+//!
+//! [`LockManager`] backed by PostgreSQL session-level advisory locks.
+//!
+//! Unlike the in-memory lock manager, these locks are shared by every
+//! process connected to the same database, so they provide mutual exclusion
+//! across API instances. Each acquisition holds a dedicated pooled
+//! connection until release: advisory locks belong to the session, and the
+//! database drops them if that connection is lost.
+//!
+//! Resources are mapped to the 64-bit advisory lock key space with FNV-1a
+//! over their display form, so every instance derives the same key.
+
+use crate::domain::errors::{DomainError, DomainResult};
+use crate::domain::services::lock_manager::{LockGuard, LockInfo, LockManager, LockManagerConfig};
+use crate::domain::services::quote_lock::LockHolderId;
+use crate::domain::services::resource_lock::{ResourceLock, sort_locks};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use sqlx::pool::PoolConnection;
+use sqlx::postgres::Postgres;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+/// Derives the advisory lock key for a resource.
+#[must_use]
+pub fn advisory_lock_key(resource: &ResourceLock) -> i64 {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let hash = resource.to_string().bytes().fold(FNV_OFFSET, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    });
+    i64::from_ne_bytes(hash.to_ne_bytes())
+}
+
+/// A pooled connection and the advisory locks taken on it.
+type Session = (PoolConnection<Postgres>, Vec<ResourceLock>);
+
+/// Connections holding advisory locks, by acquisition.
+struct HeldLocks {
+    pool: PgPool,
+    config: LockManagerConfig,
+    sessions: Mutex<HashMap<LockHolderId, Session>>,
+}
+
+/// PostgreSQL advisory lock implementation of [`LockManager`].
+///
+/// Cheap to clone; clones share the held sessions.
+#[derive(Clone)]
+pub struct PostgresLockManager {
+    inner: Arc<HeldLocks>,
+}
+
+impl PostgresLockManager {
+    /// Creates a lock manager on the given pool.
+    #[must_use]
+    pub fn new(pool: PgPool, config: LockManagerConfig) -> Arc<Self> {
+        Arc::new(Self {
+            inner: Arc::new(HeldLocks {
+                pool,
+                config,
+                sessions: Mutex::new(HashMap::new()),
+            }),
+        })
+    }
+
+    /// Creates a lock manager with default configuration.
+    #[must_use]
+    pub fn with_defaults(pool: PgPool) -> Arc<Self> {
+        Self::new(pool, LockManagerConfig::default())
+    }
+
+    /// Tries once to take the advisory lock for `resource` on `conn`.
+    async fn try_lock(
+        conn: &mut PoolConnection<Postgres>,
+        resource: &ResourceLock,
+    ) -> DomainResult<bool> {
+        sqlx::query_scalar::<_, bool>("SELECT pg_try_advisory_lock($1)")
+            .bind(advisory_lock_key(resource))
+            .fetch_one(&mut **conn)
+            .await
+            .map_err(|e| {
+                DomainError::LockAcquisitionFailed(format!("failed to lock {}: {}", resource, e))
+            })
+    }
+
+    /// Releases the advisory locks for `resources` held on `conn`.
+    async fn unlock(
+        conn: &mut PoolConnection<Postgres>,
+        resources: &[ResourceLock],
+    ) -> DomainResult<()> {
+        for resource in resources.iter().rev() {
+            sqlx::query_scalar::<_, bool>("SELECT pg_advisory_unlock($1)")
+                .bind(advisory_lock_key(resource))
+                .fetch_one(&mut **conn)
+                .await
+                .map_err(|e| {
+                    DomainError::LockAcquisitionFailed(format!(
+                        "failed to unlock {}: {}",
+                        resource, e
+                    ))
+                })?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for PostgresLockManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PostgresLockManager")
+            .field("config", &self.inner.config)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl LockManager for PostgresLockManager {
+    async fn acquire_all(
+        &self,
+        resources: Vec<ResourceLock>,
+        timeout: Duration,
+    ) -> DomainResult<LockGuard> {
+        let holder_id = LockHolderId::new();
+        let manager: Arc<dyn LockManager> = Arc::new(self.clone());
+
+        if resources.is_empty() {
+            return Ok(LockGuard::new(vec![], holder_id, manager));
+        }
+
+        let mut sorted_resources = resources;
+        sort_locks(&mut sorted_resources);
+
+        let deadline =
+            tokio::time::Instant::now() + self.inner.config.effective_timeout(Some(timeout));
+        let mut conn = self.inner.pool.acquire().await.map_err(|e| {
+            DomainError::LockAcquisitionFailed(format!("failed to get connection: {}", e))
+        })?;
+        let mut acquired: Vec<ResourceLock> = Vec::with_capacity(sorted_resources.len());
+
+        for resource in &sorted_resources {
+            loop {
+                let locked = match Self::try_lock(&mut conn, resource).await {
+                    Ok(locked) => locked,
+                    Err(e) => {
+                        let _ = Self::unlock(&mut conn, &acquired).await;
+                        return Err(e);
+                    }
+                };
+                if locked {
+                    acquired.push(resource.clone());
+                    break;
+                }
+                if tokio::time::Instant::now() >= deadline {
+                    let _ = Self::unlock(&mut conn, &acquired).await;
+                    return Err(DomainError::LockAcquisitionFailed(format!(
+                        "failed to acquire lock on {}: held by another session",
+                        resource
+                    )));
+                }
+                tokio::time::sleep(self.inner.config.retry_interval).await;
+            }
+        }
+
+        self.inner
+            .sessions
+            .lock()
+            .await
+            .insert(holder_id, (conn, acquired.clone()));
+        Ok(LockGuard::new(acquired, holder_id, manager))
+    }
+
+    async fn release_all(
+        &self,
+        locks: &[ResourceLock],
+        holder_id: LockHolderId,
+    ) -> DomainResult<()> {
+        let Some((mut conn, held)) = self.inner.sessions.lock().await.remove(&holder_id) else {
+            return Ok(());
+        };
+        if let Err(e) = Self::unlock(&mut conn, locks).await {
+            // Closing the session drops every advisory lock it holds
+            let _ = conn.close().await;
+            return Err(e);
+        }
+        let remaining: Vec<ResourceLock> =
+            held.into_iter().filter(|r| !locks.contains(r)).collect();
+        if !remaining.is_empty() {
+            self.inner
+                .sessions
+                .lock()
+                .await
+                .insert(holder_id, (conn, remaining));
+        }
+        Ok(())
+    }
+
+    async fn get_lock_info(&self, resource: &ResourceLock) -> Option<LockInfo> {
+        let sessions = self.inner.sessions.lock().await;
+        sessions.iter().find_map(|(holder_id, (_, held))| {
+            held.contains(resource)
+                .then(|| LockInfo::new(resource.clone(), *holder_id, self.inner.config.lock_ttl))
+        })
+    }
+
+    fn holder_id(&self) -> LockHolderId {
+        LockHolderId::new()
+    }
+}
@@ -0,0 +1,277 @@
+//! # Schema Migrations
+//!
+//! The SQL migrations under `migrations/`, embedded in the binary.
+//!
+//! [`run_pending`] applies whatever the database is missing and
+//! [`verify_current`] compares what was applied against what this build
+//! expects, so an instance can refuse to serve on a schema it was not
+//! written for. Both track applied versions in sqlx's `_sqlx_migrations`
+//! table, keyed by the number after the `V` in each file name.
+//!
+//! A database migrated by hand before this runner existed has no
+//! `_sqlx_migrations` table; running [`run_pending`] against it replays every
+//! migration, so baseline it first by recording the versions already applied.
+
+use crate::application::services::readiness::DependencyCheck;
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use sqlx::PgPool;
+use sqlx::error::BoxDynError;
+use sqlx::migrate::{MigrateError, Migration, MigrationSource, MigrationType, Migrator};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+include!(concat!(env!("OUT_DIR"), "/migrations.rs"));
+
+/// Errors from running or verifying migrations.
+#[derive(Debug, Error)]
+pub enum MigrationError {
+    /// Applying a migration failed, or an applied one no longer matches.
+    #[error("migration failed: {0}")]
+    Migrate(#[from] MigrateError),
+
+    /// The database could not be queried.
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
+
+/// Applied versus expected schema versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaStatus {
+    /// Versions recorded as successfully applied, ascending.
+    pub applied: Vec<i64>,
+    /// Versions embedded in this build, ascending.
+    pub expected: Vec<i64>,
+    /// Applied versions whose SQL differs from the embedded migration.
+    pub modified: Vec<i64>,
+}
+
+impl SchemaStatus {
+    /// Returns the expected versions the database has not applied.
+    #[must_use]
+    pub fn missing(&self) -> Vec<i64> {
+        self.expected
+            .iter()
+            .filter(|version| !self.applied.contains(version))
+            .copied()
+            .collect()
+    }
+
+    /// Returns the applied versions this build does not know about.
+    ///
+    /// These come from a newer build and are tolerated, so instances of the
+    /// previous release keep serving through a rolling deploy.
+    #[must_use]
+    pub fn unknown(&self) -> Vec<i64> {
+        self.applied
+            .iter()
+            .filter(|version| !self.expected.contains(version))
+            .copied()
+            .collect()
+    }
+
+    /// Returns true if every expected migration is applied unchanged.
+    #[must_use]
+    pub fn is_current(&self) -> bool {
+        self.modified.is_empty() && self.missing().is_empty()
+    }
+}
+
+/// The migrations embedded at build time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EmbeddedMigrations;
+
+impl EmbeddedMigrations {
+    /// Returns the embedded migrations in version order.
+    #[must_use]
+    pub fn migrations() -> Vec<Migration> {
+        EMBEDDED
+            .iter()
+            .map(|(version, description, sql)| {
+                Migration::new(
+                    *version,
+                    Cow::Borrowed(*description),
+                    MigrationType::Simple,
+                    Cow::Borrowed(*sql),
+                    false,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the embedded versions, ascending.
+    #[must_use]
+    pub fn versions() -> Vec<i64> {
+        EMBEDDED.iter().map(|(version, _, _)| *version).collect()
+    }
+}
+
+impl MigrationSource<'static> for EmbeddedMigrations {
+    fn resolve(self) -> BoxFuture<'static, Result<Vec<Migration>, BoxDynError>> {
+        Box::pin(async { Ok(Self::migrations()) })
+    }
+}
+
+/// Builds a migrator over the embedded migrations.
+///
+/// Versions applied by a newer build are ignored rather than rejected.
+///
+/// # Errors
+///
+/// Returns `MigrationError::Migrate` if the migrations cannot be resolved.
+pub async fn migrator() -> Result<Migrator, MigrationError> {
+    let mut migrator = Migrator::new(EmbeddedMigrations).await?;
+    migrator.set_ignore_missing(true);
+    Ok(migrator)
+}
+
+/// Applies every embedded migration the database is missing.
+///
+/// Returns the versions applied, ascending; empty if the schema was current.
+///
+/// # Errors
+///
+/// Returns `MigrationError::Migrate` if a migration fails or an applied one
+/// was modified, and `MigrationError::Database` if the database cannot be
+/// queried.
+pub async fn run_pending(pool: &PgPool) -> Result<Vec<i64>, MigrationError> {
+    let pending = verify_current(pool).await?.missing();
+    migrator().await?.run(pool).await?;
+    Ok(pending)
+}
+
+/// Compares the applied migrations with the embedded ones.
+///
+/// Read only: a database that was never migrated reports every version as
+/// missing instead of gaining a `_sqlx_migrations` table.
+///
+/// # Errors
+///
+/// Returns `MigrationError::Database` if the database cannot be queried.
+pub async fn verify_current(pool: &PgPool) -> Result<SchemaStatus, MigrationError> {
+    let tracked: bool = sqlx::query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+        .fetch_one(pool)
+        .await?;
+    let rows: Vec<(i64, Vec<u8>)> = if tracked {
+        sqlx::query_as(
+            "SELECT version, checksum FROM _sqlx_migrations WHERE success ORDER BY version",
+        )
+        .fetch_all(pool)
+        .await?
+    } else {
+        Vec::new()
+    };
+
+    let checksums: BTreeMap<i64, Vec<u8>> = EmbeddedMigrations::migrations()
+        .into_iter()
+        .map(|migration| (migration.version, migration.checksum.into_owned()))
+        .collect();
+    let modified = rows
+        .iter()
+        .filter(|(version, checksum)| {
+            checksums
+                .get(version)
+                .is_some_and(|expected| expected != checksum)
+        })
+        .map(|(version, _)| *version)
+        .collect();
+
+    Ok(SchemaStatus {
+        applied: rows.into_iter().map(|(version, _)| version).collect(),
+        expected: checksums.into_keys().collect(),
+        modified,
+    })
+}
+
+/// Readiness check that fails while the schema is not current.
+#[derive(Debug, Clone)]
+pub struct SchemaVersionCheck {
+    pool: PgPool,
+}
+
+impl SchemaVersionCheck {
+    /// Creates a check against the given pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DependencyCheck for SchemaVersionCheck {
+    fn name(&self) -> &str {
+        "schema"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        let status = verify_current(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        if status.is_current() {
+            Ok(())
+        } else {
+            Err(drift_message(&status))
+        }
+    }
+}
+
+/// Describes how the schema differs from the embedded migrations.
+#[must_use]
+pub fn drift_message(status: &SchemaStatus) -> String {
+    let mut parts = Vec::new();
+    let missing = status.missing();
+    if !missing.is_empty() {
+        parts.push(format!("missing migrations {missing:?}"));
+    }
+    if !status.modified.is_empty() {
+        parts.push(format!("modified migrations {:?}", status.modified));
+    }
+    if parts.is_empty() {
+        "schema is current".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn status(applied: &[i64], expected: &[i64], modified: &[i64]) -> SchemaStatus {
+        SchemaStatus {
+            applied: applied.to_vec(),
+            expected: expected.to_vec(),
+            modified: modified.to_vec(),
+        }
+    }
+
+    #[test]
+    fn embedded_migrations_are_ordered_and_unique() {
+        let versions = EmbeddedMigrations::versions();
+        assert!(!versions.is_empty());
+        assert!(
+            versions
+                .windows(2)
+                .all(|pair| matches!(pair, [earlier, later] if earlier < later))
+        );
+        assert_eq!(versions.first(), Some(&1));
+    }
+
+    #[test]
+    fn newer_versions_are_tolerated_but_gaps_are_not() {
+        let ahead = status(&[1, 2, 3], &[1, 2], &[]);
+        assert!(ahead.is_current());
+        assert_eq!(ahead.unknown(), vec![3]);
+
+        let behind = status(&[1, 3], &[1, 2, 3], &[]);
+        assert!(!behind.is_current());
+        assert_eq!(behind.missing(), vec![2]);
+        assert_eq!(drift_message(&behind), "missing migrations [2]");
+
+        let edited = status(&[1, 2], &[1, 2], &[2]);
+        assert!(!edited.is_current());
+        assert_eq!(drift_message(&edited), "modified migrations [2]");
+    }
+}
@@ -5,28 +5,85 @@
 //! ## Available Repositories
 //!
 //! - [`PostgresRfqRepository`]: RFQ persistence with JSONB
+//! - [`PostgresAggregationReportRepository`]: Per-venue quote aggregation reports
+//! - [`PostgresBestExecutionReportRepository`]: Best execution report snapshots
 //! - [`PostgresTradeRepository`]: Trade persistence with optimistic locking
 //! - [`PostgresVenueRepository`]: Venue configuration persistence
 //! - [`PostgresCounterpartyRepository`]: Counterparty persistence
+//! - [`PostgresCounterpartyKeyStore`]: Per-counterparty event encryption keys
+//! - [`PostgresErasureRequestRepository`]: Counterparty erasure request persistence
+//! - [`PostgresVenueCredentialRepository`]: Encrypted counterparty venue credentials
+//! - [`PostgresInstrumentReferenceDataRepository`]: Instrument reference data persistence
+//! - [`PostgresRfqTemplateRepository`]: RFQ template persistence
+//! - [`PostgresNegotiationRepository`]: Negotiation persistence
+//! - [`PostgresPriceBoundsConfigRepository`]: Price bounds settings with an audit trail
+//! - [`PostgresRawExchangeLog`]: Raw venue exchange log with per-row retention
+//! - [`PostgresRfqSummaryStore`]: RFQ dashboard read model
+//! - [`PostgresTradingCalendarRepository`]: Trading calendar persistence
+//! - [`PostgresWebhookSubscriptionRepository`]: Webhook subscription persistence
+//! - [`PostgresWebhookDeliveryLog`]: Webhook delivery log
 //! - [`PostgresEventStore`]: Append-only event storage
+//! - [`PostgresDeadLetterStore`]: Events parked by failing consumers
+//! - [`PostgresPoolCheck`]: Readiness check against the connection pool
+//! - [`PostgresLockManager`]: Cross-instance locks via advisory locks
+//! - [`SchemaVersionCheck`]: Readiness check against the embedded migrations
 //!
 //! ## Features
 //!
 //! - Connection pooling via `sqlx::PgPool`
 //! - Optimistic locking with version fields
 //! - JSONB serialization for complex fields
+//! - Typed errors: constraint violations, lost connections and timeouts
 //! - Append-only event store for event sourcing
 
+pub mod aggregation_report_repository;
+pub mod best_execution_report_repository;
+pub mod counterparty_key_store;
 pub mod counterparty_repository;
+pub mod dead_letter_store;
+pub mod erasure_request_repository;
+mod error;
 pub mod event_store;
+pub mod health;
+pub mod instrument_reference_data_repository;
+pub mod lock_manager;
+pub mod migrations;
+pub mod negotiation_repository;
+pub mod price_bounds_config_repository;
+pub mod raw_exchange_log;
 pub mod rfq_repository;
+pub mod rfq_summary_store;
+pub mod rfq_template_repository;
 #[cfg(test)]
 mod tests;
 pub mod trade_repository;
+pub mod trading_calendar_repository;
+pub mod venue_credential_repository;
 pub mod venue_repository;
+pub mod webhook_delivery_log;
+pub mod webhook_subscription_repository;
 
-pub use counterparty_repository::PostgresCounterpartyRepository;
+pub use aggregation_report_repository::PostgresAggregationReportRepository;
+pub use best_execution_report_repository::PostgresBestExecutionReportRepository;
+pub use counterparty_key_store::PostgresCounterpartyKeyStore;
+pub use counterparty_repository::{KeyRotationReport, PostgresCounterpartyRepository};
+pub use dead_letter_store::PostgresDeadLetterStore;
+pub use erasure_request_repository::PostgresErasureRequestRepository;
+pub(crate) use error::map_sqlx_error;
 pub use event_store::PostgresEventStore;
+pub use health::PostgresPoolCheck;
+pub use instrument_reference_data_repository::PostgresInstrumentReferenceDataRepository;
+pub use lock_manager::PostgresLockManager;
+pub use migrations::{MigrationError, SchemaStatus, SchemaVersionCheck};
+pub use negotiation_repository::PostgresNegotiationRepository;
+pub use price_bounds_config_repository::PostgresPriceBoundsConfigRepository;
+pub use raw_exchange_log::PostgresRawExchangeLog;
 pub use rfq_repository::PostgresRfqRepository;
+pub use rfq_summary_store::PostgresRfqSummaryStore;
+pub use rfq_template_repository::PostgresRfqTemplateRepository;
 pub use trade_repository::PostgresTradeRepository;
+pub use trading_calendar_repository::PostgresTradingCalendarRepository;
+pub use venue_credential_repository::PostgresVenueCredentialRepository;
 pub use venue_repository::PostgresVenueRepository;
+pub use webhook_delivery_log::PostgresWebhookDeliveryLog;
+pub use webhook_subscription_repository::PostgresWebhookSubscriptionRepository;
@@ -0,0 +1,200 @@
+//! # PostgreSQL Negotiation Repository
+//!
+//! PostgreSQL implementation of [`NegotiationRepository`] using sqlx.
+
+use crate::domain::entities::negotiation::{Negotiation, NegotiationRound};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    CounterpartyId, NegotiationId, NegotiationState, OrderSide, RfqId,
+};
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
+use crate::infrastructure::persistence::traits::{
+    NegotiationRepository, RepositoryError, RepositoryResult,
+};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// PostgreSQL implementation of [`NegotiationRepository`].
+///
+/// Uses connection pooling via `sqlx::PgPool`.
+#[derive(Debug, Clone)]
+pub struct PostgresNegotiationRepository {
+    pool: PgPool,
+}
+
+impl PostgresNegotiationRepository {
+    /// Creates a new PostgreSQL negotiation repository.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl NegotiationRepository for PostgresNegotiationRepository {
+    async fn save(&self, negotiation: &Negotiation) -> RepositoryResult<()> {
+        let rounds_json = serde_json::to_value(negotiation.rounds())
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO negotiations (
+                id, rfq_id, requester_id, mm_account_id, side, rounds,
+                max_rounds, state, created_at, updated_at, conclusion_failure,
+                version
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (id) DO UPDATE SET
+                rounds = EXCLUDED.rounds,
+                state = EXCLUDED.state,
+                updated_at = EXCLUDED.updated_at,
+                conclusion_failure = EXCLUDED.conclusion_failure,
+                version = EXCLUDED.version
+            "#,
+        )
+        .bind(negotiation.id().get())
+        .bind(negotiation.rfq_id().get())
+        .bind(negotiation.requester().as_str())
+        .bind(negotiation.mm_account().as_str())
+        .bind(negotiation.side().to_string())
+        .bind(&rounds_json)
+        .bind(i16::from(negotiation.max_rounds()))
+        .bind(negotiation.state().to_string())
+        .bind(negotiation.created_at().timestamp_millis())
+        .bind(negotiation.updated_at().timestamp_millis())
+        .bind(negotiation.conclusion_failure())
+        .bind(i64::try_from(negotiation.version()).unwrap_or(i64::MAX))
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: NegotiationId) -> RepositoryResult<Option<Negotiation>> {
+        let row: Option<NegotiationRow> = sqlx::query_as(
+            r#"
+            SELECT id, rfq_id, requester_id, mm_account_id, side, rounds,
+                   max_rounds, state, created_at, updated_at, conclusion_failure,
+                   version
+            FROM negotiations WHERE id = $1
+            "#,
+        )
+        .bind(id.get())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(NegotiationRow::try_into_negotiation).transpose()
+    }
+
+    async fn find_by_rfq(&self, rfq_id: RfqId) -> RepositoryResult<Vec<Negotiation>> {
+        let rows: Vec<NegotiationRow> = sqlx::query_as(
+            r#"
+            SELECT id, rfq_id, requester_id, mm_account_id, side, rounds,
+                   max_rounds, state, created_at, updated_at, conclusion_failure,
+                   version
+            FROM negotiations
+            WHERE rfq_id = $1
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(rfq_id.get())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter()
+            .map(NegotiationRow::try_into_negotiation)
+            .collect()
+    }
+
+    async fn find_completed_between(
+        &self,
+        mm_account: Option<&CounterpartyId>,
+        from: Timestamp,
+        to: Timestamp,
+    ) -> RepositoryResult<Vec<Negotiation>> {
+        let rows: Vec<NegotiationRow> = sqlx::query_as(
+            r#"
+            SELECT id, rfq_id, requester_id, mm_account_id, side, rounds,
+                   max_rounds, state, created_at, updated_at, conclusion_failure,
+                   version
+            FROM negotiations
+            WHERE state IN ('ACCEPTED', 'REJECTED', 'EXPIRED')
+              AND updated_at BETWEEN $1 AND $2
+              AND ($3::VARCHAR IS NULL OR mm_account_id = $3)
+            ORDER BY updated_at ASC
+            "#,
+        )
+        .bind(from.timestamp_millis())
+        .bind(to.timestamp_millis())
+        .bind(mm_account.map(CounterpartyId::as_str))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter()
+            .map(NegotiationRow::try_into_negotiation)
+            .collect()
+    }
+}
+
+/// Row type for negotiation queries.
+#[derive(Debug, sqlx::FromRow)]
+struct NegotiationRow {
+    id: Uuid,
+    rfq_id: Uuid,
+    requester_id: String,
+    mm_account_id: String,
+    side: String,
+    rounds: serde_json::Value,
+    max_rounds: i16,
+    state: String,
+    created_at: i64,
+    updated_at: i64,
+    conclusion_failure: Option<String>,
+    version: i64,
+}
+
+impl NegotiationRow {
+    /// Converts the row into a [`Negotiation`].
+    fn try_into_negotiation(self) -> RepositoryResult<Negotiation> {
+        let side: OrderSide = serde_json::from_str(&format!("\"{}\"", self.side))
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let rounds: Vec<NegotiationRound> = serde_json::from_value(self.rounds)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let max_rounds = u8::try_from(self.max_rounds)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let state: NegotiationState = serde_json::from_str(&format!("\"{}\"", self.state))
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let created_at = Timestamp::from_millis(self.created_at).ok_or_else(|| {
+            RepositoryError::serialization("invalid created_at timestamp".to_string())
+        })?;
+        let updated_at = Timestamp::from_millis(self.updated_at).ok_or_else(|| {
+            RepositoryError::serialization("invalid updated_at timestamp".to_string())
+        })?;
+
+        Ok(Negotiation::from_parts(
+            NegotiationId::new(self.id),
+            RfqId::new(self.rfq_id),
+            CounterpartyId::new(&self.requester_id),
+            CounterpartyId::new(&self.mm_account_id),
+            side,
+            rounds,
+            max_rounds,
+            state,
+            created_at,
+            updated_at,
+        )
+        .with_conclusion_failure(self.conclusion_failure)
+        .with_version(u64::try_from(self.version).unwrap_or(1)))
+    }
+}
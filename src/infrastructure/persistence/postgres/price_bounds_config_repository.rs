@@ -0,0 +1,142 @@
+//! # PostgreSQL Price Bounds Configuration Repository
+//!
+//! PostgreSQL implementation of [`PriceBoundsConfigRepository`] using sqlx.
+//!
+//! The settings in force are a single row of `price_bounds_config`; each
+//! change also appends a row to `price_bounds_config_audit` in the same
+//! transaction.
+
+use crate::domain::value_objects::reference_price::{PriceBoundsConfigChange, PriceBoundsSettings};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
+use crate::infrastructure::persistence::traits::{
+    PriceBoundsConfigRepository, RepositoryError, RepositoryResult,
+};
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+/// PostgreSQL implementation of [`PriceBoundsConfigRepository`].
+///
+/// Uses connection pooling via `sqlx::PgPool`.
+#[derive(Debug, Clone)]
+pub struct PostgresPriceBoundsConfigRepository {
+    pool: PgPool,
+}
+
+impl PostgresPriceBoundsConfigRepository {
+    /// Creates a new PostgreSQL price bounds configuration repository.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl PriceBoundsConfigRepository for PostgresPriceBoundsConfigRepository {
+    async fn load(&self) -> RepositoryResult<Option<PriceBoundsSettings>> {
+        let row: Option<(serde_json::Value,)> =
+            sqlx::query_as("SELECT settings FROM price_bounds_config WHERE id = 1")
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(map_sqlx_error)?;
+
+        row.map(|(settings,)| from_json(settings)).transpose()
+    }
+
+    async fn save(&self, change: &PriceBoundsConfigChange) -> RepositoryResult<()> {
+        let previous = to_json(&change.previous)?;
+        let current = to_json(&change.current)?;
+        let changed_at = change.changed_at.timestamp_millis();
+
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO price_bounds_config (id, settings, updated_by, updated_at)
+            VALUES (1, $1, $2, $3)
+            ON CONFLICT (id) DO UPDATE SET
+                settings = EXCLUDED.settings,
+                updated_by = EXCLUDED.updated_by,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(&current)
+        .bind(&change.changed_by)
+        .bind(changed_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO price_bounds_config_audit (changed_by, changed_at, previous, current)
+            VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(&change.changed_by)
+        .bind(changed_at)
+        .bind(&previous)
+        .bind(&current)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn history(&self) -> RepositoryResult<Vec<PriceBoundsConfigChange>> {
+        let rows: Vec<AuditRow> = sqlx::query_as(
+            r#"
+            SELECT changed_by, changed_at, previous, current
+            FROM price_bounds_config_audit
+            ORDER BY id ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter().map(AuditRow::try_into_change).collect()
+    }
+}
+
+/// Row type for audit trail queries.
+#[derive(Debug, sqlx::FromRow)]
+struct AuditRow {
+    changed_by: String,
+    changed_at: i64,
+    previous: serde_json::Value,
+    current: serde_json::Value,
+}
+
+impl AuditRow {
+    /// Converts the row into a [`PriceBoundsConfigChange`].
+    fn try_into_change(self) -> RepositoryResult<PriceBoundsConfigChange> {
+        let changed_at = Timestamp::from_millis(self.changed_at).ok_or_else(|| {
+            RepositoryError::serialization("invalid changed_at timestamp".to_string())
+        })?;
+
+        Ok(PriceBoundsConfigChange {
+            changed_by: self.changed_by,
+            changed_at,
+            previous: from_json(self.previous)?,
+            current: from_json(self.current)?,
+        })
+    }
+}
+
+fn to_json(settings: &PriceBoundsSettings) -> RepositoryResult<serde_json::Value> {
+    serde_json::to_value(settings).map_err(|e| RepositoryError::serialization(e.to_string()))
+}
+
+fn from_json(value: serde_json::Value) -> RepositoryResult<PriceBoundsSettings> {
+    serde_json::from_value(value).map_err(|e| RepositoryError::serialization(e.to_string()))
+}
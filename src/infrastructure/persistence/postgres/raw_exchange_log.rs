@@ -0,0 +1,155 @@
+//! # PostgreSQL Raw Exchange Log
+//!
+//! PostgreSQL implementation of [`RawExchangeLog`] using sqlx.
+//!
+//! Each row carries its own `expires_at`, derived from the retention
+//! policy in force when it was written, so changing the policy never
+//! shortens the retention of evidence already recorded.
+
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{RfqId, VenueId};
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
+use crate::infrastructure::persistence::raw_exchange_log::{
+    ExchangeDirection, RawExchange, RawExchangeLog,
+};
+use crate::infrastructure::persistence::traits::{RepositoryError, RepositoryResult};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// PostgreSQL implementation of [`RawExchangeLog`].
+///
+/// Uses connection pooling via `sqlx::PgPool`.
+#[derive(Debug, Clone)]
+pub struct PostgresRawExchangeLog {
+    pool: PgPool,
+}
+
+impl PostgresRawExchangeLog {
+    /// Creates a new PostgreSQL raw exchange log.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl RawExchangeLog for PostgresRawExchangeLog {
+    async fn record(&self, exchange: &RawExchange) -> RepositoryResult<()> {
+        let http_status = exchange.http_status.map(i32::from);
+        let latency_ms = exchange
+            .latency_ms
+            .map(i64::try_from)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO venue_raw_exchanges (
+                venue_id, rfq_id, direction, payload, http_status,
+                latency_ms, recorded_at, expires_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(exchange.venue_id.as_str())
+        .bind(exchange.rfq_id.get())
+        .bind(exchange.direction.to_string())
+        .bind(&exchange.payload)
+        .bind(http_status)
+        .bind(latency_ms)
+        .bind(exchange.recorded_at.timestamp_millis())
+        .bind(exchange.expires_at.timestamp_millis())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn find_by_rfq(&self, rfq_id: RfqId) -> RepositoryResult<Vec<RawExchange>> {
+        let rows: Vec<RawExchangeRow> = sqlx::query_as(
+            r#"
+            SELECT venue_id, rfq_id, direction, payload, http_status,
+                   latency_ms, recorded_at, expires_at
+            FROM venue_raw_exchanges
+            WHERE rfq_id = $1
+            ORDER BY recorded_at ASC, id ASC
+            "#,
+        )
+        .bind(rfq_id.get())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter()
+            .map(RawExchangeRow::try_into_exchange)
+            .collect()
+    }
+
+    async fn purge_expired(&self, now: Timestamp) -> RepositoryResult<u64> {
+        let result = sqlx::query("DELETE FROM venue_raw_exchanges WHERE expires_at <= $1")
+            .bind(now.timestamp_millis())
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+/// Row type for raw exchange queries.
+#[derive(Debug, sqlx::FromRow)]
+struct RawExchangeRow {
+    venue_id: String,
+    rfq_id: Uuid,
+    direction: String,
+    payload: serde_json::Value,
+    http_status: Option<i32>,
+    latency_ms: Option<i64>,
+    recorded_at: i64,
+    expires_at: i64,
+}
+
+impl RawExchangeRow {
+    /// Converts the row into a [`RawExchange`].
+    fn try_into_exchange(self) -> RepositoryResult<RawExchange> {
+        let direction: ExchangeDirection = self
+            .direction
+            .parse()
+            .map_err(RepositoryError::serialization)?;
+        let http_status = self
+            .http_status
+            .map(u16::try_from)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let latency_ms = self
+            .latency_ms
+            .map(u64::try_from)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let recorded_at = Timestamp::from_millis(self.recorded_at).ok_or_else(|| {
+            RepositoryError::serialization("invalid recorded_at timestamp".to_string())
+        })?;
+        let expires_at = Timestamp::from_millis(self.expires_at).ok_or_else(|| {
+            RepositoryError::serialization("invalid expires_at timestamp".to_string())
+        })?;
+
+        Ok(RawExchange {
+            venue_id: VenueId::new(self.venue_id),
+            rfq_id: RfqId::new(self.rfq_id),
+            direction,
+            payload: self.payload,
+            http_status,
+            latency_ms,
+            recorded_at,
+            expires_at,
+        })
+    }
+}
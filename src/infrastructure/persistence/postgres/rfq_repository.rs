@@ -5,13 +5,22 @@
 //! This implementation uses PostgreSQL with JSONB for complex fields
 //! and optimistic locking via version fields.
 
+use crate::domain::entities::quote::Quote;
 use crate::domain::entities::rfq::Rfq;
-use crate::domain::value_objects::{CounterpartyId, RfqId, RfqState, VenueId};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    CounterpartyId, FailureCode, FailureReason, OrderSide, Quantity, RfqId, RfqState,
+    SizeNegotiationMode, Symbol, VenueId,
+};
+use crate::infrastructure::persistence::cursor::PageCursor;
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
 use crate::infrastructure::persistence::traits::{
-    RepositoryError, RepositoryResult, RfqRepository,
+    RepositoryError, RepositoryResult, RfqListFilter, RfqRepository,
 };
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::postgres::PgArguments;
+use sqlx::query::QueryAs;
+use sqlx::{PgPool, Postgres};
 
 /// PostgreSQL implementation of [`RfqRepository`].
 ///
@@ -45,6 +54,45 @@ impl PostgresRfqRepository {
     }
 }
 
+/// Columns selected when listing RFQs.
+const RFQ_LIST_COLUMNS: &str = "id, client_id, instrument, strategy, side, two_way, quantity, \
+    min_quantity, size_mode, allow_internal_crossing, venue_allowlist, venue_blocklist, state, \
+    expires_at, activate_at, compensation_policy, quantity_disclosure, quotes, retired_quotes, \
+    quote_ranks, selected_quote_id, compliance_result, failure_code, failure_reason, version, \
+    created_at, updated_at, execution_deadline";
+
+/// `WHERE` conditions for an [`RfqListFilter`], bound by [`bind_list_filter`]
+/// as `$1` to `$10`.
+const RFQ_LIST_FILTER: &str = "($1::TEXT IS NULL OR client_id = $1)
+      AND (cardinality($2::TEXT[]) = 0 OR state = ANY($2))
+      AND ($3::TEXT IS NULL OR instrument->>'symbol' = $3)
+      AND ($4::TEXT IS NULL OR split_part(instrument->>'symbol', '/', 1) = $4)
+      AND ($5::TEXT IS NULL OR split_part(instrument->>'symbol', '/', 2) = $5)
+      AND ($6::BIGINT IS NULL OR created_at >= $6)
+      AND ($7::BIGINT IS NULL OR created_at <= $7)
+      AND ($8::BIGINT IS NULL OR (state = 'CREATED' AND activate_at > $8))
+      AND ($9::TEXT IS NULL OR failure_code = $9)
+      AND ($10::TEXT IS NULL OR (side = $10 AND NOT two_way))";
+
+/// Binds `filter` to the parameters of [`RFQ_LIST_FILTER`].
+fn bind_list_filter<'q, O>(
+    query: QueryAs<'q, Postgres, O, PgArguments>,
+    filter: &RfqListFilter,
+) -> QueryAs<'q, Postgres, O, PgArguments> {
+    let states: Vec<String> = filter.states.iter().map(ToString::to_string).collect();
+    query
+        .bind(filter.client_id.as_ref().map(|c| c.as_str().to_string()))
+        .bind(states)
+        .bind(filter.symbol.as_ref().map(|s| s.as_str().to_string()))
+        .bind(filter.base_asset.clone())
+        .bind(filter.quote_asset.clone())
+        .bind(filter.created_from.map(|t| t.timestamp_millis()))
+        .bind(filter.created_to.map(|t| t.timestamp_millis()))
+        .bind(filter.scheduled_as_of.map(|t| t.timestamp_millis()))
+        .bind(filter.failure_code.map(|code| code.to_string()))
+        .bind(filter.side.map(|side| side.to_string()))
+}
+
 #[async_trait]
 impl RfqRepository for PostgresRfqRepository {
     async fn save(&self, rfq: &Rfq) -> RepositoryResult<()> {
@@ -52,11 +100,30 @@ impl RfqRepository for PostgresRfqRepository {
         let client_id = rfq.client_id().as_str();
         let instrument_json = serde_json::to_value(rfq.instrument())
             .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let strategy_json = rfq
+            .strategy()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
         let side = rfq.side().to_string();
         let quantity = rfq.quantity().get();
         let state = rfq.state().to_string();
         let expires_at = rfq.expires_at().timestamp_millis();
-        let quotes_json = serde_json::to_value(rfq.quotes())
+        let venue_allowlist_json = rfq
+            .venue_allowlist()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let venue_blocklist_json = serde_json::to_value(rfq.venue_blocklist())
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let quantity_disclosure_json = serde_json::to_value(rfq.quantity_disclosure())
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let quotes: Vec<&Quote> = rfq.quotes().iter().chain(rfq.shadow_quotes()).collect();
+        let quotes_json = serde_json::to_value(quotes)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let retired_quotes_json = serde_json::to_value(rfq.retired_quotes())
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let quote_ranks_json = serde_json::to_value(rfq.quote_ranks())
             .map_err(|e| RepositoryError::serialization(e.to_string()))?;
         let selected_quote_id = rfq.selected_quote_id().map(|q| q.to_string());
         let compliance_result_json = rfq
@@ -64,7 +131,8 @@ impl RfqRepository for PostgresRfqRepository {
             .map(serde_json::to_value)
             .transpose()
             .map_err(|e| RepositoryError::serialization(e.to_string()))?;
-        let failure_reason = rfq.failure_reason().map(|s| s.to_string());
+        let failure_code = rfq.failure_reason().map(|r| r.code().to_string());
+        let failure_reason = rfq.failure_reason().map(|r| r.detail().to_string());
         let version = rfq.version() as i64;
         let created_at = rfq.created_at().timestamp_millis();
         let updated_at = rfq.updated_at().timestamp_millis();
@@ -73,45 +141,74 @@ impl RfqRepository for PostgresRfqRepository {
         let result = sqlx::query(
             r#"
             INSERT INTO rfqs (
-                id, client_id, instrument, side, quantity, min_quantity, state, expires_at,
-                quotes, selected_quote_id, compliance_result, failure_reason,
-                version, created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+                id, client_id, instrument, strategy, side, two_way, quantity, min_quantity,
+                size_mode, allow_internal_crossing, venue_allowlist, venue_blocklist,
+                state, expires_at, activate_at, compensation_policy, quantity_disclosure, quotes,
+                retired_quotes, quote_ranks, selected_quote_id, compliance_result, failure_code,
+                failure_reason, version, created_at, updated_at, execution_deadline
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18,
+                      $19, $20, $21, $22, $23, $24, $25, $26, $27, $28)
             ON CONFLICT (id) DO UPDATE SET
                 client_id = EXCLUDED.client_id,
                 instrument = EXCLUDED.instrument,
+                strategy = EXCLUDED.strategy,
                 side = EXCLUDED.side,
+                two_way = EXCLUDED.two_way,
                 quantity = EXCLUDED.quantity,
                 min_quantity = EXCLUDED.min_quantity,
+                size_mode = EXCLUDED.size_mode,
+                allow_internal_crossing = EXCLUDED.allow_internal_crossing,
+                venue_allowlist = EXCLUDED.venue_allowlist,
+                venue_blocklist = EXCLUDED.venue_blocklist,
                 state = EXCLUDED.state,
                 expires_at = EXCLUDED.expires_at,
+                activate_at = EXCLUDED.activate_at,
+                compensation_policy = EXCLUDED.compensation_policy,
+                quantity_disclosure = EXCLUDED.quantity_disclosure,
                 quotes = EXCLUDED.quotes,
+                retired_quotes = EXCLUDED.retired_quotes,
+                quote_ranks = EXCLUDED.quote_ranks,
                 selected_quote_id = EXCLUDED.selected_quote_id,
                 compliance_result = EXCLUDED.compliance_result,
+                failure_code = EXCLUDED.failure_code,
                 failure_reason = EXCLUDED.failure_reason,
                 version = EXCLUDED.version,
-                updated_at = EXCLUDED.updated_at
+                updated_at = EXCLUDED.updated_at,
+                execution_deadline = EXCLUDED.execution_deadline
             WHERE rfqs.version < EXCLUDED.version
             "#,
         )
         .bind(&id)
         .bind(client_id)
         .bind(&instrument_json)
+        .bind(&strategy_json)
         .bind(&side)
+        .bind(rfq.is_two_way())
         .bind(quantity)
         .bind(rfq.min_quantity().map(|q| q.get()))
+        .bind(size_mode_to_column(rfq.size_mode()))
+        .bind(rfq.allows_internal_crossing())
+        .bind(&venue_allowlist_json)
+        .bind(&venue_blocklist_json)
         .bind(&state)
         .bind(expires_at)
+        .bind(rfq.activate_at().map(|t| t.timestamp_millis()))
+        .bind(rfq.compensation_policy().to_string())
+        .bind(&quantity_disclosure_json)
         .bind(&quotes_json)
+        .bind(&retired_quotes_json)
+        .bind(&quote_ranks_json)
         .bind(&selected_quote_id)
         .bind(&compliance_result_json)
+        .bind(&failure_code)
         .bind(&failure_reason)
         .bind(version)
         .bind(created_at)
         .bind(updated_at)
+        .bind(rfq.execution_deadline().map(|t| t.timestamp_millis()))
         .execute(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
 
         // Check if update was applied (for existing records)
         if result.rows_affected() == 0 {
@@ -120,7 +217,7 @@ impl RfqRepository for PostgresRfqRepository {
                 .bind(&id)
                 .fetch_optional(&self.pool)
                 .await
-                .map_err(|e| RepositoryError::query(e.to_string()))?;
+                .map_err(map_sqlx_error)?;
 
             if let Some((existing_version,)) = exists
                 && existing_version >= version
@@ -142,16 +239,17 @@ impl RfqRepository for PostgresRfqRepository {
 
         let row: Option<RfqRow> = sqlx::query_as(
             r#"
-            SELECT id, client_id, instrument, side, quantity, min_quantity, state, expires_at,
-                   quotes, selected_quote_id, compliance_result, failure_reason,
-                   version, created_at, updated_at
+            SELECT id, client_id, instrument, strategy, side, two_way, quantity, min_quantity,
+                   size_mode, allow_internal_crossing, venue_allowlist, venue_blocklist, state, expires_at,
+                   activate_at, compensation_policy, quantity_disclosure, quotes, retired_quotes, quote_ranks, selected_quote_id, compliance_result, failure_code, failure_reason,
+                   version, created_at, updated_at, execution_deadline
             FROM rfqs WHERE id = $1
             "#,
         )
         .bind(&id_str)
         .fetch_optional(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
 
         row.map(|r| r.try_into_rfq()).transpose()
     }
@@ -168,16 +266,17 @@ impl RfqRepository for PostgresRfqRepository {
 
         let rows: Vec<RfqRow> = sqlx::query_as(
             r#"
-            SELECT id, client_id, instrument, side, quantity, min_quantity, state, expires_at,
-                   quotes, selected_quote_id, compliance_result, failure_reason,
-                   version, created_at, updated_at
+            SELECT id, client_id, instrument, strategy, side, two_way, quantity, min_quantity,
+                   size_mode, allow_internal_crossing, venue_allowlist, venue_blocklist, state, expires_at,
+                   activate_at, compensation_policy, quantity_disclosure, quotes, retired_quotes, quote_ranks, selected_quote_id, compliance_result, failure_code, failure_reason,
+                   version, created_at, updated_at, execution_deadline
             FROM rfqs WHERE state = ANY($1)
             "#,
         )
         .bind(&active_states)
         .fetch_all(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
 
         rows.into_iter().map(|r| r.try_into_rfq()).collect()
     }
@@ -187,16 +286,17 @@ impl RfqRepository for PostgresRfqRepository {
 
         let rows: Vec<RfqRow> = sqlx::query_as(
             r#"
-            SELECT id, client_id, instrument, side, quantity, min_quantity, state, expires_at,
-                   quotes, selected_quote_id, compliance_result, failure_reason,
-                   version, created_at, updated_at
+            SELECT id, client_id, instrument, strategy, side, two_way, quantity, min_quantity,
+                   size_mode, allow_internal_crossing, venue_allowlist, venue_blocklist, state, expires_at,
+                   activate_at, compensation_policy, quantity_disclosure, quotes, retired_quotes, quote_ranks, selected_quote_id, compliance_result, failure_code, failure_reason,
+                   version, created_at, updated_at, execution_deadline
             FROM rfqs WHERE client_id = $1
             "#,
         )
         .bind(client_id_str)
         .fetch_all(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
 
         rows.into_iter().map(|r| r.try_into_rfq()).collect()
     }
@@ -207,16 +307,143 @@ impl RfqRepository for PostgresRfqRepository {
         // Search for RFQs where quotes array contains the venue_id
         let rows: Vec<RfqRow> = sqlx::query_as(
             r#"
-            SELECT id, client_id, instrument, side, quantity, min_quantity, state, expires_at,
-                   quotes, selected_quote_id, compliance_result, failure_reason,
-                   version, created_at, updated_at
+            SELECT id, client_id, instrument, strategy, side, two_way, quantity, min_quantity,
+                   size_mode, allow_internal_crossing, venue_allowlist, venue_blocklist, state, expires_at,
+                   activate_at, compensation_policy, quantity_disclosure, quotes, retired_quotes, quote_ranks, selected_quote_id, compliance_result, failure_code, failure_reason,
+                   version, created_at, updated_at, execution_deadline
             FROM rfqs WHERE quotes @> $1::jsonb
             "#,
         )
         .bind(format!(r#"[{{"venue_id": "{}"}}]"#, venue_id_str))
         .fetch_all(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter().map(|r| r.try_into_rfq()).collect()
+    }
+
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> RepositoryResult<Vec<Rfq>> {
+        // Keyset pagination served by idx_rfqs_created_at_id; every filter
+        // is applied here so callers never post-filter in Rust.
+        let rows: Vec<RfqRow> = bind_list_filter(
+            sqlx::query_as(&format!(
+                "SELECT {RFQ_LIST_COLUMNS} FROM rfqs
+                 WHERE {RFQ_LIST_FILTER}
+                   AND ($11::BIGINT IS NULL OR (created_at, id) < ($11, $12))
+                 ORDER BY created_at DESC, id DESC
+                 LIMIT $13"
+            )),
+            filter,
+        )
+        .bind(cursor.map(PageCursor::created_at_millis))
+        .bind(cursor.map(PageCursor::id))
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter().map(|r| r.try_into_rfq()).collect()
+    }
+
+    async fn list_offset(
+        &self,
+        offset: usize,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> RepositoryResult<Vec<Rfq>> {
+        let rows: Vec<RfqRow> = bind_list_filter(
+            sqlx::query_as(&format!(
+                "SELECT {RFQ_LIST_COLUMNS} FROM rfqs
+                 WHERE {RFQ_LIST_FILTER}
+                 ORDER BY created_at DESC, id DESC
+                 OFFSET $11 LIMIT $12"
+            )),
+            filter,
+        )
+        .bind(i64::try_from(offset).unwrap_or(i64::MAX))
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter().map(|r| r.try_into_rfq()).collect()
+    }
+
+    async fn count_matching(&self, filter: &RfqListFilter) -> RepositoryResult<u64> {
+        let (count,): (i64,) = bind_list_filter(
+            sqlx::query_as(&format!(
+                "SELECT COUNT(*) FROM rfqs WHERE {RFQ_LIST_FILTER}"
+            )),
+            filter,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(count as u64)
+    }
+
+    async fn find_crossing_candidates(
+        &self,
+        symbol: &Symbol,
+        side: OrderSide,
+        valid_after: Timestamp,
+    ) -> RepositoryResult<Vec<Rfq>> {
+        let crossable_states = vec![
+            RfqState::QuoteRequesting.to_string(),
+            RfqState::QuotesReceived.to_string(),
+        ];
+
+        let rows: Vec<RfqRow> = sqlx::query_as(
+            r#"
+            SELECT id, client_id, instrument, strategy, side, two_way, quantity, min_quantity,
+                   size_mode, allow_internal_crossing, venue_allowlist, venue_blocklist, state, expires_at,
+                   activate_at, compensation_policy, quantity_disclosure, quotes, retired_quotes, quote_ranks, selected_quote_id, compliance_result, failure_code, failure_reason,
+                   version, created_at, updated_at, execution_deadline
+            FROM rfqs
+            WHERE allow_internal_crossing
+              AND strategy IS NULL
+              AND instrument->>'symbol' = $1
+              AND side = $2
+              AND state = ANY($3)
+              AND expires_at > $4
+            ORDER BY created_at ASC, id ASC
+            "#,
+        )
+        .bind(symbol.as_str())
+        .bind(side.to_string())
+        .bind(&crossable_states)
+        .bind(valid_after.timestamp_millis())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter().map(|r| r.try_into_rfq()).collect()
+    }
+
+    async fn find_pending_activation(&self, before: Timestamp) -> RepositoryResult<Vec<Rfq>> {
+        let rows: Vec<RfqRow> = sqlx::query_as(
+            r#"
+            SELECT id, client_id, instrument, strategy, side, two_way, quantity, min_quantity,
+                   size_mode, allow_internal_crossing, venue_allowlist, venue_blocklist, state, expires_at,
+                   activate_at, compensation_policy, quantity_disclosure, quotes, retired_quotes, quote_ranks, selected_quote_id, compliance_result, failure_code, failure_reason,
+                   version, created_at, updated_at, execution_deadline
+            FROM rfqs
+            WHERE state = $1
+              AND activate_at <= $2
+            ORDER BY activate_at ASC, id ASC
+            "#,
+        )
+        .bind(RfqState::Created.to_string())
+        .bind(before.timestamp_millis())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
 
         rows.into_iter().map(|r| r.try_into_rfq()).collect()
     }
@@ -228,7 +455,7 @@ impl RfqRepository for PostgresRfqRepository {
             .bind(&id_str)
             .execute(&self.pool)
             .await
-            .map_err(|e| RepositoryError::query(e.to_string()))?;
+            .map_err(map_sqlx_error)?;
 
         Ok(result.rows_affected() > 0)
     }
@@ -237,7 +464,7 @@ impl RfqRepository for PostgresRfqRepository {
         let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM rfqs")
             .fetch_one(&self.pool)
             .await
-            .map_err(|e| RepositoryError::query(e.to_string()))?;
+            .map_err(map_sqlx_error)?;
 
         Ok(count as u64)
     }
@@ -256,7 +483,7 @@ impl RfqRepository for PostgresRfqRepository {
             .bind(&active_states)
             .fetch_one(&self.pool)
             .await
-            .map_err(|e| RepositoryError::query(e.to_string()))?;
+            .map_err(map_sqlx_error)?;
 
         Ok(count as u64)
     }
@@ -268,19 +495,64 @@ struct RfqRow {
     id: String,
     client_id: String,
     instrument: serde_json::Value,
+    strategy: Option<serde_json::Value>,
     side: String,
+    two_way: bool,
     quantity: rust_decimal::Decimal,
     min_quantity: Option<rust_decimal::Decimal>,
+    size_mode: String,
+    allow_internal_crossing: bool,
+    venue_allowlist: Option<serde_json::Value>,
+    venue_blocklist: serde_json::Value,
     anonymity_level: Option<String>,
     state: String,
     expires_at: i64,
+    activate_at: Option<i64>,
+    compensation_policy: String,
+    quantity_disclosure: serde_json::Value,
     quotes: serde_json::Value,
+    retired_quotes: serde_json::Value,
+    quote_ranks: serde_json::Value,
     selected_quote_id: Option<String>,
     compliance_result: Option<serde_json::Value>,
+    failure_code: Option<String>,
     failure_reason: Option<String>,
     version: i64,
     created_at: i64,
     updated_at: i64,
+    execution_deadline: Option<i64>,
+}
+
+/// Returns the `rfqs.size_mode` column value for a size negotiation mode.
+///
+/// The `MinQuantity` threshold is stored in the `min_quantity` column.
+fn size_mode_to_column(mode: &SizeNegotiationMode) -> &'static str {
+    match mode {
+        SizeNegotiationMode::AllOrNothing => "ALL_OR_NOTHING",
+        SizeNegotiationMode::FillOrKill => "FILL_OR_KILL",
+        SizeNegotiationMode::MinQuantity(_) => "MIN_QUANTITY",
+        SizeNegotiationMode::BestEffort => "BEST_EFFORT",
+    }
+}
+
+/// Rebuilds a size negotiation mode from the `size_mode` and
+/// `min_quantity` columns.
+fn size_mode_from_column(
+    value: &str,
+    min_quantity: Option<Quantity>,
+) -> RepositoryResult<SizeNegotiationMode> {
+    match (value, min_quantity) {
+        ("ALL_OR_NOTHING", _) => Ok(SizeNegotiationMode::AllOrNothing),
+        ("FILL_OR_KILL", _) => Ok(SizeNegotiationMode::FillOrKill),
+        ("MIN_QUANTITY", Some(min)) => Ok(SizeNegotiationMode::MinQuantity(min)),
+        ("MIN_QUANTITY", None) => Err(RepositoryError::serialization(
+            "size_mode MIN_QUANTITY without min_quantity".to_string(),
+        )),
+        ("BEST_EFFORT", _) => Ok(SizeNegotiationMode::BestEffort),
+        (other, _) => Err(RepositoryError::serialization(format!(
+            "unknown size_mode: {other}"
+        ))),
+    }
 }
 
 impl RfqRow {
@@ -289,17 +561,29 @@ impl RfqRow {
         use crate::domain::entities::ComplianceResult;
         use crate::domain::entities::anonymity::AnonymityLevel;
         use crate::domain::entities::quote::Quote;
+        use crate::domain::entities::quote_history::{QuoteRank, RetiredQuote};
+        use crate::domain::entities::rfq::RfqSubject;
         use crate::domain::value_objects::enums::OrderSide;
         use crate::domain::value_objects::timestamp::Timestamp;
-        use crate::domain::value_objects::{Instrument, Quantity, QuoteId};
+        use crate::domain::value_objects::{CompensationPolicy, Instrument, QuoteId};
         use uuid::Uuid;
 
         let uuid =
             Uuid::parse_str(&self.id).map_err(|e| RepositoryError::serialization(e.to_string()))?;
         let id = RfqId::new(uuid);
         let client_id = CounterpartyId::new(&self.client_id);
-        let instrument: Instrument = serde_json::from_value(self.instrument)
-            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        // Multi-leg RFQs keep the primary instrument in `instrument` for
+        // symbol filtering; the strategy column is authoritative.
+        let subject = match self.strategy {
+            Some(strategy) => RfqSubject::Strategy(
+                serde_json::from_value(strategy)
+                    .map_err(|e| RepositoryError::serialization(e.to_string()))?,
+            ),
+            None => RfqSubject::Single(
+                serde_json::from_value::<Instrument>(self.instrument)
+                    .map_err(|e| RepositoryError::serialization(e.to_string()))?,
+            ),
+        };
         let side: OrderSide = serde_json::from_str(&format!("\"{}\"", self.side))
             .map_err(|e| RepositoryError::serialization(e.to_string()))?;
         let quantity = Quantity::from_decimal(self.quantity)
@@ -309,6 +593,7 @@ impl RfqRow {
             .map(Quantity::from_decimal)
             .transpose()
             .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let size_mode = size_mode_from_column(&self.size_mode, min_quantity)?;
         let anonymity_level: AnonymityLevel = self
             .anonymity_level
             .as_deref()
@@ -318,16 +603,60 @@ impl RfqRow {
             .unwrap_or_default();
         let state: RfqState = serde_json::from_str(&format!("\"{}\"", self.state))
             .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let failure_code = self
+            .failure_code
+            .as_deref()
+            .map(str::parse::<FailureCode>)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?
+            .unwrap_or_default();
+        let failure_reason = self
+            .failure_reason
+            .map(|detail| FailureReason::new(failure_code, detail));
         let expires_at = Timestamp::from_millis(self.expires_at).ok_or_else(|| {
             RepositoryError::serialization("invalid expires_at timestamp".to_string())
         })?;
+        let activate_at = self
+            .activate_at
+            .map(|millis| {
+                Timestamp::from_millis(millis).ok_or_else(|| {
+                    RepositoryError::serialization("invalid activate_at timestamp".to_string())
+                })
+            })
+            .transpose()?;
+        let execution_deadline = self
+            .execution_deadline
+            .map(|millis| {
+                Timestamp::from_millis(millis).ok_or_else(|| {
+                    RepositoryError::serialization(
+                        "invalid execution_deadline timestamp".to_string(),
+                    )
+                })
+            })
+            .transpose()?;
+        let compensation_policy: CompensationPolicy =
+            serde_json::from_str(&format!("\"{}\"", self.compensation_policy))
+                .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let quantity_disclosure = serde_json::from_value(self.quantity_disclosure)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
         let quotes: Vec<Quote> = serde_json::from_value(self.quotes)
             .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let retired_quotes: Vec<RetiredQuote> = serde_json::from_value(self.retired_quotes)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let quote_ranks: Vec<QuoteRank> = serde_json::from_value(self.quote_ranks)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
         let selected_quote_id = self
             .selected_quote_id
             .map(|s| Uuid::parse_str(&s).map(QuoteId::new))
             .transpose()
             .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let venue_allowlist: Option<Vec<VenueId>> = self
+            .venue_allowlist
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let venue_blocklist: Vec<VenueId> = serde_json::from_value(self.venue_blocklist)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
         let compliance_result: Option<ComplianceResult> = self
             .compliance_result
             .map(serde_json::from_value)
@@ -343,20 +672,30 @@ impl RfqRow {
         Ok(Rfq::from_parts(
             id,
             client_id,
-            instrument,
+            subject,
             side,
+            self.two_way,
             quantity,
             min_quantity,
+            size_mode,
             anonymity_level,
+            self.allow_internal_crossing,
+            venue_allowlist,
+            venue_blocklist,
+            compensation_policy,
+            quantity_disclosure,
             state,
             expires_at,
+            activate_at,
             quotes,
             selected_quote_id,
             compliance_result,
-            self.failure_reason,
+            failure_reason,
             self.version as u64,
             created_at,
             updated_at,
-        ))
+        )
+        .with_quote_history(retired_quotes, quote_ranks)
+        .with_execution_deadline(execution_deadline))
     }
 }
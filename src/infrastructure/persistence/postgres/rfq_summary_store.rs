@@ -0,0 +1,322 @@
+//! # PostgreSQL RFQ Summary Store
+//!
+//! PostgreSQL implementation of [`RfqSummaryStore`] using sqlx.
+//!
+//! Rows live in the `rfq_summary` table. Upserts only replace a row with
+//! one carrying a higher `last_sequence`, so concurrent projections of the
+//! same RFQ cannot roll it back.
+
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    CounterpartyId, OrderSide, Price, Quantity, RfqId, RfqState, Symbol, TradeId, VenueId,
+};
+use crate::infrastructure::persistence::cursor::PageCursor;
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
+use crate::infrastructure::persistence::rfq_summary::{RfqSummary, RfqSummaryStore};
+use crate::infrastructure::persistence::traits::{
+    RepositoryError, RepositoryResult, RfqListFilter,
+};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use sqlx::postgres::PgArguments;
+use sqlx::query::QueryAs;
+use sqlx::{PgPool, Postgres};
+use uuid::Uuid;
+
+/// PostgreSQL implementation of [`RfqSummaryStore`].
+///
+/// Uses connection pooling via `sqlx::PgPool`.
+#[derive(Debug, Clone)]
+pub struct PostgresRfqSummaryStore {
+    pool: PgPool,
+}
+
+impl PostgresRfqSummaryStore {
+    /// Creates a new PostgreSQL RFQ summary store.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+/// Columns selected when reading summary rows.
+const SUMMARY_COLUMNS: &str = "rfq_id, client_id, symbol, side, quantity, state, quote_count, \
+    best_quote_price, selected_venue_id, trade_id, execution_price, failure_reason, created_at, \
+    last_event_at, last_sequence, aggregate_versions";
+
+/// `WHERE` conditions for an [`RfqListFilter`], bound by [`bind_list_filter`]
+/// as `$1` to `$8`.
+const SUMMARY_LIST_FILTER: &str = "($1::TEXT IS NULL OR client_id = $1)
+      AND (cardinality($2::TEXT[]) = 0 OR state = ANY($2))
+      AND ($3::TEXT IS NULL OR symbol = $3)
+      AND ($4::TEXT IS NULL OR split_part(symbol, '/', 1) = $4)
+      AND ($5::TEXT IS NULL OR split_part(symbol, '/', 2) = $5)
+      AND ($6::BIGINT IS NULL OR created_at >= $6)
+      AND ($7::BIGINT IS NULL OR created_at <= $7)
+      AND ($8::TEXT IS NULL OR side = $8)";
+
+/// Returns whether `filter` selects on activation times or failure codes,
+/// which are not projected, so no row can match; see
+/// [`RfqSummary::matches`].
+fn filters_unprojected_fields(filter: &RfqListFilter) -> bool {
+    filter.scheduled_as_of.is_some() || filter.failure_code.is_some()
+}
+
+/// Binds `filter` to the parameters of [`SUMMARY_LIST_FILTER`].
+fn bind_list_filter<'q, O>(
+    query: QueryAs<'q, Postgres, O, PgArguments>,
+    filter: &RfqListFilter,
+) -> QueryAs<'q, Postgres, O, PgArguments> {
+    let states: Vec<String> = filter.states.iter().map(ToString::to_string).collect();
+    query
+        .bind(filter.client_id.as_ref().map(|c| c.as_str().to_string()))
+        .bind(states)
+        .bind(filter.symbol.as_ref().map(|s| s.as_str().to_string()))
+        .bind(filter.base_asset.clone())
+        .bind(filter.quote_asset.clone())
+        .bind(filter.created_from.map(|t| t.timestamp_millis()))
+        .bind(filter.created_to.map(|t| t.timestamp_millis()))
+        .bind(filter.side.map(|side| side.to_string()))
+}
+
+#[async_trait]
+impl RfqSummaryStore for PostgresRfqSummaryStore {
+    async fn upsert(&self, summary: &RfqSummary) -> RepositoryResult<()> {
+        let quote_count = i32::try_from(summary.quote_count)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let last_sequence = i64::try_from(summary.last_sequence)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let aggregate_versions = serde_json::to_value(&summary.aggregate_versions)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO rfq_summary (
+                rfq_id, client_id, symbol, side, quantity, state, quote_count,
+                best_quote_price, selected_venue_id, trade_id, execution_price,
+                failure_reason, created_at, last_event_at, last_sequence,
+                aggregate_versions
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+            ON CONFLICT (rfq_id) DO UPDATE SET
+                client_id = EXCLUDED.client_id,
+                symbol = EXCLUDED.symbol,
+                side = EXCLUDED.side,
+                quantity = EXCLUDED.quantity,
+                state = EXCLUDED.state,
+                quote_count = EXCLUDED.quote_count,
+                best_quote_price = EXCLUDED.best_quote_price,
+                selected_venue_id = EXCLUDED.selected_venue_id,
+                trade_id = EXCLUDED.trade_id,
+                execution_price = EXCLUDED.execution_price,
+                failure_reason = EXCLUDED.failure_reason,
+                created_at = EXCLUDED.created_at,
+                last_event_at = EXCLUDED.last_event_at,
+                last_sequence = EXCLUDED.last_sequence,
+                aggregate_versions = EXCLUDED.aggregate_versions
+            WHERE rfq_summary.last_sequence < EXCLUDED.last_sequence
+            "#,
+        )
+        .bind(summary.rfq_id.get())
+        .bind(summary.client_id.as_str())
+        .bind(summary.symbol.as_str())
+        .bind(summary.side.to_string())
+        .bind(summary.quantity.get())
+        .bind(summary.state.to_string())
+        .bind(quote_count)
+        .bind(summary.best_quote_price.map(Price::get))
+        .bind(summary.selected_venue_id.as_ref().map(VenueId::as_str))
+        .bind(summary.trade_id.map(|id| id.get()))
+        .bind(summary.execution_price.map(Price::get))
+        .bind(summary.failure_reason.as_deref())
+        .bind(summary.created_at.timestamp_millis())
+        .bind(summary.last_event_at.timestamp_millis())
+        .bind(last_sequence)
+        .bind(&aggregate_versions)
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn get(&self, rfq_id: RfqId) -> RepositoryResult<Option<RfqSummary>> {
+        let row: Option<RfqSummaryRow> = sqlx::query_as(
+            r#"
+            SELECT rfq_id, client_id, symbol, side, quantity, state, quote_count,
+                   best_quote_price, selected_venue_id, trade_id, execution_price,
+                   failure_reason, created_at, last_event_at, last_sequence,
+                   aggregate_versions
+            FROM rfq_summary
+            WHERE rfq_id = $1
+            "#,
+        )
+        .bind(rfq_id.get())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(RfqSummaryRow::try_into_summary).transpose()
+    }
+
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> RepositoryResult<Vec<RfqSummary>> {
+        if filters_unprojected_fields(filter) {
+            return Ok(Vec::new());
+        }
+
+        let rows: Vec<RfqSummaryRow> = bind_list_filter(
+            sqlx::query_as(&format!(
+                "SELECT {SUMMARY_COLUMNS} FROM rfq_summary
+                 WHERE {SUMMARY_LIST_FILTER}
+                   AND ($9::BIGINT IS NULL OR (created_at, rfq_id) < ($9, $10::UUID))
+                 ORDER BY created_at DESC, rfq_id DESC
+                 LIMIT $11"
+            )),
+            filter,
+        )
+        .bind(cursor.map(PageCursor::created_at_millis))
+        .bind(cursor.map(PageCursor::id))
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter()
+            .map(RfqSummaryRow::try_into_summary)
+            .collect()
+    }
+
+    async fn list_offset(
+        &self,
+        offset: usize,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> RepositoryResult<Vec<RfqSummary>> {
+        if filters_unprojected_fields(filter) {
+            return Ok(Vec::new());
+        }
+
+        let rows: Vec<RfqSummaryRow> = bind_list_filter(
+            sqlx::query_as(&format!(
+                "SELECT {SUMMARY_COLUMNS} FROM rfq_summary
+                 WHERE {SUMMARY_LIST_FILTER}
+                 ORDER BY created_at DESC, rfq_id DESC
+                 OFFSET $9 LIMIT $10"
+            )),
+            filter,
+        )
+        .bind(i64::try_from(offset).unwrap_or(i64::MAX))
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter()
+            .map(RfqSummaryRow::try_into_summary)
+            .collect()
+    }
+
+    async fn count_matching(&self, filter: &RfqListFilter) -> RepositoryResult<u64> {
+        if filters_unprojected_fields(filter) {
+            return Ok(0);
+        }
+
+        let (count,): (i64,) = bind_list_filter(
+            sqlx::query_as(&format!(
+                "SELECT COUNT(*) FROM rfq_summary WHERE {SUMMARY_LIST_FILTER}"
+            )),
+            filter,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(count as u64)
+    }
+
+    async fn clear(&self) -> RepositoryResult<u64> {
+        let result = sqlx::query("DELETE FROM rfq_summary")
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+/// Row type for RFQ summary queries.
+#[derive(Debug, sqlx::FromRow)]
+struct RfqSummaryRow {
+    rfq_id: Uuid,
+    client_id: String,
+    symbol: String,
+    side: String,
+    quantity: Decimal,
+    state: String,
+    quote_count: i32,
+    best_quote_price: Option<Decimal>,
+    selected_venue_id: Option<String>,
+    trade_id: Option<Uuid>,
+    execution_price: Option<Decimal>,
+    failure_reason: Option<String>,
+    created_at: i64,
+    last_event_at: i64,
+    last_sequence: i64,
+    aggregate_versions: serde_json::Value,
+}
+
+impl RfqSummaryRow {
+    /// Converts the row into an [`RfqSummary`].
+    fn try_into_summary(self) -> RepositoryResult<RfqSummary> {
+        let serialization = |e: String| RepositoryError::serialization(e);
+        let price = |value: Option<Decimal>| {
+            value
+                .map(Price::from_decimal)
+                .transpose()
+                .map_err(|e| serialization(e.to_string()))
+        };
+        let timestamp = |millis: i64, column: &str| {
+            Timestamp::from_millis(millis)
+                .ok_or_else(|| serialization(format!("invalid {column} timestamp")))
+        };
+
+        let side: OrderSide = serde_json::from_str(&format!("\"{}\"", self.side))
+            .map_err(|e| serialization(e.to_string()))?;
+        let state: RfqState = serde_json::from_str(&format!("\"{}\"", self.state))
+            .map_err(|e| serialization(e.to_string()))?;
+
+        Ok(RfqSummary {
+            rfq_id: RfqId::new(self.rfq_id),
+            client_id: CounterpartyId::new(self.client_id),
+            symbol: Symbol::new(&self.symbol).map_err(|e| serialization(e.to_string()))?,
+            side,
+            quantity: Quantity::from_decimal(self.quantity)
+                .map_err(|e| serialization(e.to_string()))?,
+            state,
+            quote_count: u32::try_from(self.quote_count)
+                .map_err(|e| serialization(e.to_string()))?,
+            best_quote_price: price(self.best_quote_price)?,
+            selected_venue_id: self.selected_venue_id.map(VenueId::new),
+            trade_id: self.trade_id.map(TradeId::new),
+            execution_price: price(self.execution_price)?,
+            failure_reason: self.failure_reason,
+            created_at: timestamp(self.created_at, "created_at")?,
+            last_event_at: timestamp(self.last_event_at, "last_event_at")?,
+            last_sequence: u64::try_from(self.last_sequence)
+                .map_err(|e| serialization(e.to_string()))?,
+            aggregate_versions: serde_json::from_value(self.aggregate_versions)
+                .map_err(|e| serialization(e.to_string()))?,
+        })
+    }
+}
@@ -0,0 +1,199 @@
+//! # PostgreSQL RFQ Template Repository
+//!
+//! PostgreSQL implementation of [`RfqTemplateRepository`] using sqlx.
+
+use crate::domain::entities::rfq::RfqSubject;
+use crate::domain::entities::rfq_template::RfqTemplate;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    CounterpartyId, OrderSide, Quantity, RfqTemplateId, SizeNegotiationMode, VenueId,
+};
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
+use crate::infrastructure::persistence::traits::{
+    RepositoryError, RepositoryResult, RfqTemplateRepository,
+};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// PostgreSQL implementation of [`RfqTemplateRepository`].
+///
+/// Uses connection pooling via `sqlx::PgPool`.
+#[derive(Debug, Clone)]
+pub struct PostgresRfqTemplateRepository {
+    pool: PgPool,
+}
+
+impl PostgresRfqTemplateRepository {
+    /// Creates a new PostgreSQL RFQ template repository.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl RfqTemplateRepository for PostgresRfqTemplateRepository {
+    async fn save(&self, template: &RfqTemplate) -> RepositoryResult<()> {
+        let subject_json = serde_json::to_value(template.subject())
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let size_mode_json = serde_json::to_value(template.size_mode())
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let venue_allowlist_json = template
+            .venue_allowlist()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let venue_blocklist_json = serde_json::to_value(template.venue_blocklist())
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO rfq_templates (
+                id, owner_id, name, subject, side, quantity, size_mode,
+                venue_allowlist, venue_blocklist, default_ttl_secs, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (id) DO UPDATE SET
+                name = EXCLUDED.name,
+                subject = EXCLUDED.subject,
+                side = EXCLUDED.side,
+                quantity = EXCLUDED.quantity,
+                size_mode = EXCLUDED.size_mode,
+                venue_allowlist = EXCLUDED.venue_allowlist,
+                venue_blocklist = EXCLUDED.venue_blocklist,
+                default_ttl_secs = EXCLUDED.default_ttl_secs,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(template.id().get())
+        .bind(template.owner().as_str())
+        .bind(template.name())
+        .bind(&subject_json)
+        .bind(template.side().to_string())
+        .bind(template.quantity().get())
+        .bind(&size_mode_json)
+        .bind(&venue_allowlist_json)
+        .bind(&venue_blocklist_json)
+        .bind(i64::from(template.default_ttl_secs()))
+        .bind(template.created_at().timestamp_millis())
+        .bind(template.updated_at().timestamp_millis())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: RfqTemplateId) -> RepositoryResult<Option<RfqTemplate>> {
+        let row: Option<RfqTemplateRow> = sqlx::query_as(
+            r#"
+            SELECT id, owner_id, name, subject, side, quantity, size_mode,
+                   venue_allowlist, venue_blocklist, default_ttl_secs, created_at, updated_at
+            FROM rfq_templates WHERE id = $1
+            "#,
+        )
+        .bind(id.get())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(RfqTemplateRow::try_into_template).transpose()
+    }
+
+    async fn find_by_owner(&self, owner: &CounterpartyId) -> RepositoryResult<Vec<RfqTemplate>> {
+        let rows: Vec<RfqTemplateRow> = sqlx::query_as(
+            r#"
+            SELECT id, owner_id, name, subject, side, quantity, size_mode,
+                   venue_allowlist, venue_blocklist, default_ttl_secs, created_at, updated_at
+            FROM rfq_templates WHERE owner_id = $1 ORDER BY name ASC
+            "#,
+        )
+        .bind(owner.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter()
+            .map(RfqTemplateRow::try_into_template)
+            .collect()
+    }
+
+    async fn delete(&self, id: RfqTemplateId) -> RepositoryResult<bool> {
+        let result = sqlx::query("DELETE FROM rfq_templates WHERE id = $1")
+            .bind(id.get())
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// Row type for RFQ template queries.
+#[derive(Debug, sqlx::FromRow)]
+struct RfqTemplateRow {
+    id: Uuid,
+    owner_id: String,
+    name: String,
+    subject: serde_json::Value,
+    side: String,
+    quantity: Decimal,
+    size_mode: serde_json::Value,
+    venue_allowlist: Option<serde_json::Value>,
+    venue_blocklist: serde_json::Value,
+    default_ttl_secs: i64,
+    created_at: i64,
+    updated_at: i64,
+}
+
+impl RfqTemplateRow {
+    /// Converts the row into an [`RfqTemplate`].
+    fn try_into_template(self) -> RepositoryResult<RfqTemplate> {
+        let subject: RfqSubject = serde_json::from_value(self.subject)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let side: OrderSide = serde_json::from_str(&format!("\"{}\"", self.side))
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let quantity = Quantity::from_decimal(self.quantity)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let size_mode: SizeNegotiationMode = serde_json::from_value(self.size_mode)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let venue_allowlist: Option<Vec<VenueId>> = self
+            .venue_allowlist
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let venue_blocklist: Vec<VenueId> = serde_json::from_value(self.venue_blocklist)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let default_ttl_secs = u32::try_from(self.default_ttl_secs)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let created_at = Timestamp::from_millis(self.created_at).ok_or_else(|| {
+            RepositoryError::serialization("invalid created_at timestamp".to_string())
+        })?;
+        let updated_at = Timestamp::from_millis(self.updated_at).ok_or_else(|| {
+            RepositoryError::serialization("invalid updated_at timestamp".to_string())
+        })?;
+
+        Ok(RfqTemplate::from_parts(
+            RfqTemplateId::new(self.id),
+            CounterpartyId::new(&self.owner_id),
+            self.name,
+            subject,
+            side,
+            quantity,
+            size_mode,
+            venue_allowlist,
+            venue_blocklist,
+            default_ttl_secs,
+            created_at,
+            updated_at,
+        ))
+    }
+}
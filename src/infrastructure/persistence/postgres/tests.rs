@@ -7,6 +7,7 @@
 //! - **RFQ Repository**: CRUD operations, optimistic locking
 //! - **Trade Repository**: CRUD operations, state transitions
 //! - **Event Store**: Append-only semantics, event retrieval
+//! - **Advisory Locks**: Mutual exclusion across lock manager instances
 //! - **Transaction Rollback**: Verify rollback behavior
 //!
 //! # Note
@@ -20,20 +21,60 @@
 #![allow(clippy::unwrap_used)]
 #![allow(clippy::indexing_slicing)]
 
+use rust_decimal::Decimal;
 use sqlx::PgPool;
-
+use std::sync::Arc;
+
+use crate::domain::entities::allocation::{Allocation, TradeAllocation};
+use crate::domain::entities::counter_quote::CounterQuoteBuilder;
+use crate::domain::entities::counterparty::{Counterparty, CounterpartyType, WalletAddress};
+use crate::domain::entities::erasure_request::{ErasureRequest, ErasureRequestStatus};
+use crate::domain::entities::negotiation::Negotiation;
+use crate::domain::entities::quote::{Quote, QuoteLegPrice};
 use crate::domain::entities::rfq::{Rfq, RfqBuilder};
-use crate::domain::entities::trade::Trade;
+use crate::domain::entities::rfq_template::RfqTemplateBuilder;
+use crate::domain::entities::trade::{FeeComponent, FeeKind, Trade};
+use crate::domain::entities::venue_credential_set::{VenueCredentialSet, VenueCredentials};
+use crate::domain::entities::webhook_subscription::WebhookSubscription;
+use crate::domain::services::lock_manager::LockManager;
+use crate::domain::services::resource_lock::ResourceLock;
+use crate::domain::value_objects::strategy::Strategy;
 use crate::domain::value_objects::timestamp::Timestamp;
 use crate::domain::value_objects::{
-    AssetClass, CounterpartyId, Instrument, OrderSide, Price, Quantity, QuoteId, RfqId, Symbol,
-    VenueId,
+    AssetClass, Blockchain, CollateralDecision, CounterpartyId, Instrument,
+    InstrumentReferenceData, NegotiationState, NotificationPreferences, OrderSide, Premium, Price,
+    PriceBoundsCheck, Quantity, QuantityDisclosure, QuoteId, RfqDirection, RfqId, RfqState,
+    SettlementCutoff, SettlementPlan, SettlementWindow, SizeNegotiationMode, Symbol, TradeId,
+    TradingCalendar, VenueId, WebhookSubscriptionId,
 };
+use crate::infrastructure::persistence::counterparty_keys::{CounterpartyKeyStore, KeyLookup};
 use crate::infrastructure::persistence::event_store::{EventStore, StoredEvent};
+use crate::infrastructure::persistence::field_encryption::{
+    EncryptedString, EnvKeyProvider, FieldKey, KeyProvider,
+};
+use crate::infrastructure::persistence::postgres::lock_manager::advisory_lock_key;
 use crate::infrastructure::persistence::postgres::{
-    PostgresEventStore, PostgresRfqRepository, PostgresTradeRepository,
+    KeyRotationReport, PostgresCounterpartyKeyStore, PostgresCounterpartyRepository,
+    PostgresErasureRequestRepository, PostgresEventStore,
+    PostgresInstrumentReferenceDataRepository, PostgresLockManager, PostgresNegotiationRepository,
+    PostgresRawExchangeLog, PostgresRfqRepository, PostgresRfqSummaryStore,
+    PostgresRfqTemplateRepository, PostgresTradeRepository, PostgresTradingCalendarRepository,
+    PostgresVenueCredentialRepository, PostgresWebhookDeliveryLog,
+    PostgresWebhookSubscriptionRepository, map_sqlx_error,
+};
+use crate::infrastructure::persistence::raw_exchange_log::{
+    ExchangeDirection, RawExchange, RawExchangeLog,
+};
+use crate::infrastructure::persistence::rfq_summary::{RfqSummary, RfqSummaryStore};
+use crate::infrastructure::persistence::traits::{
+    CounterpartyRepository, ErasureRequestRepository, InstrumentReferenceDataRepository,
+    NegotiationRepository, RepositoryError, RfqListFilter, RfqRepository, RfqTemplateRepository,
+    TradeRepository, TradingCalendarRepository, VenueCredentialRepository,
+    WebhookSubscriptionRepository,
+};
+use crate::infrastructure::persistence::webhook_delivery_log::{
+    WebhookDelivery, WebhookDeliveryLog, WebhookDeliveryStatus,
 };
-use crate::infrastructure::persistence::traits::{RfqRepository, TradeRepository};
 
 // ============================================================================
 // Test Helpers
@@ -60,14 +101,26 @@ async fn setup_tables(pool: &PgPool) -> Result<(), sqlx::Error> {
             id VARCHAR(36) PRIMARY KEY,
             client_id VARCHAR(255) NOT NULL,
             instrument JSONB NOT NULL,
+            strategy JSONB,
             side VARCHAR(10) NOT NULL,
+            two_way BOOLEAN NOT NULL DEFAULT FALSE,
             quantity DECIMAL NOT NULL,
             min_quantity DECIMAL,
+            size_mode VARCHAR(20) NOT NULL DEFAULT 'ALL_OR_NOTHING',
+            allow_internal_crossing BOOLEAN NOT NULL DEFAULT FALSE,
+            venue_allowlist JSONB,
+            venue_blocklist JSONB NOT NULL DEFAULT '[]',
             state VARCHAR(50) NOT NULL,
             expires_at BIGINT NOT NULL,
+            activate_at BIGINT,
+            compensation_policy VARCHAR(20) NOT NULL DEFAULT 'REOFFER_REMAINDER',
+            quantity_disclosure JSONB NOT NULL DEFAULT '{"type": "EXACT"}',
             quotes JSONB NOT NULL DEFAULT '[]',
+            retired_quotes JSONB NOT NULL DEFAULT '[]',
+            quote_ranks JSONB NOT NULL DEFAULT '[]',
             selected_quote_id VARCHAR(36),
             compliance_result JSONB,
+            failure_code TEXT,
             failure_reason TEXT,
             version BIGINT NOT NULL DEFAULT 1,
             created_at BIGINT NOT NULL,
@@ -94,7 +147,57 @@ async fn setup_tables(pool: &PgPool) -> Result<(), sqlx::Error> {
             failure_reason TEXT,
             version BIGINT NOT NULL DEFAULT 1,
             created_at BIGINT NOT NULL,
-            updated_at BIGINT NOT NULL
+            updated_at BIGINT NOT NULL,
+            taker_fee DECIMAL,
+            maker_fee DECIMAL,
+            net_fee DECIMAL,
+            reference_price_at_execution DECIMAL,
+            settlement_attempts INTEGER NOT NULL DEFAULT 0,
+            next_settlement_retry_at BIGINT,
+            price_bounds_check JSONB,
+            unwinds_trade_id VARCHAR(36),
+            netting_batch_id VARCHAR(36),
+            negotiation_id VARCHAR(36),
+            collateral_decision JSONB,
+            settlement_plan JSONB,
+            settlement_window JSONB,
+            settlement_due_at BIGINT
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Create Trade fees table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS trade_fees (
+            trade_id VARCHAR(36) NOT NULL REFERENCES trades(id) ON DELETE CASCADE,
+            position INTEGER NOT NULL,
+            kind VARCHAR(50) NOT NULL,
+            amount DECIMAL NOT NULL,
+            currency VARCHAR(50) NOT NULL,
+            PRIMARY KEY (trade_id, position)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Create Trade allocations table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS trade_allocations (
+            trade_id VARCHAR(36) NOT NULL REFERENCES trades(id) ON DELETE CASCADE,
+            position INTEGER NOT NULL,
+            venue_id VARCHAR(255) NOT NULL,
+            quote_id VARCHAR(36) NOT NULL,
+            quantity DECIMAL NOT NULL,
+            price DECIMAL NOT NULL,
+            scheduled_at BIGINT NOT NULL,
+            status VARCHAR(20) NOT NULL,
+            failure_reason TEXT,
+            PRIMARY KEY (trade_id, position)
         )
         "#,
     )
@@ -112,7 +215,262 @@ async fn setup_tables(pool: &PgPool) -> Result<(), sqlx::Error> {
             event_name VARCHAR(100) NOT NULL,
             timestamp BIGINT NOT NULL,
             payload JSONB NOT NULL,
-            sequence BIGINT NOT NULL
+            sequence BIGINT NOT NULL,
+            correlation_id VARCHAR(36),
+            aggregate_id VARCHAR(36),
+            aggregate_version BIGINT NOT NULL DEFAULT 0,
+            aggregate_sequence BIGINT NOT NULL DEFAULT 0
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE UNIQUE INDEX IF NOT EXISTS idx_domain_events_aggregate_sequence
+            ON domain_events(aggregate_id, aggregate_sequence)
+            WHERE aggregate_id IS NOT NULL AND aggregate_version > 0
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Create Instrument reference data table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS instrument_reference_data (
+            symbol VARCHAR(50) PRIMARY KEY,
+            tick_size DECIMAL NOT NULL,
+            lot_size DECIMAL NOT NULL,
+            min_order_size DECIMAL NOT NULL DEFAULT 0,
+            max_order_size DECIMAL,
+            contract_multiplier DECIMAL NOT NULL DEFAULT 1
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Create RFQ templates table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS rfq_templates (
+            id UUID PRIMARY KEY,
+            owner_id VARCHAR(255) NOT NULL,
+            name VARCHAR(255) NOT NULL,
+            subject JSONB NOT NULL,
+            side VARCHAR(10) NOT NULL,
+            quantity DECIMAL NOT NULL,
+            size_mode JSONB NOT NULL,
+            venue_allowlist JSONB,
+            venue_blocklist JSONB NOT NULL DEFAULT '[]',
+            default_ttl_secs BIGINT NOT NULL,
+            created_at BIGINT NOT NULL,
+            updated_at BIGINT NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Create negotiations table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS negotiations (
+            id UUID PRIMARY KEY,
+            rfq_id UUID NOT NULL,
+            requester_id VARCHAR(255) NOT NULL,
+            mm_account_id VARCHAR(255) NOT NULL,
+            side VARCHAR(10) NOT NULL,
+            rounds JSONB NOT NULL DEFAULT '[]',
+            max_rounds SMALLINT NOT NULL,
+            state VARCHAR(20) NOT NULL,
+            created_at BIGINT NOT NULL,
+            updated_at BIGINT NOT NULL,
+            conclusion_failure TEXT,
+            version BIGINT NOT NULL DEFAULT 1
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Create venue raw exchanges table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS venue_raw_exchanges (
+            id BIGSERIAL PRIMARY KEY,
+            venue_id VARCHAR(255) NOT NULL,
+            rfq_id UUID NOT NULL,
+            direction VARCHAR(10) NOT NULL,
+            payload JSONB NOT NULL,
+            http_status INTEGER,
+            latency_ms BIGINT,
+            recorded_at BIGINT NOT NULL,
+            expires_at BIGINT NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Create RFQ summary table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS rfq_summary (
+            rfq_id UUID PRIMARY KEY,
+            client_id VARCHAR(255) NOT NULL,
+            symbol VARCHAR(50) NOT NULL,
+            side VARCHAR(10) NOT NULL,
+            quantity DECIMAL(38, 18) NOT NULL,
+            state VARCHAR(20) NOT NULL,
+            quote_count INTEGER NOT NULL DEFAULT 0,
+            best_quote_price DECIMAL(38, 18),
+            selected_venue_id VARCHAR(255),
+            trade_id UUID,
+            execution_price DECIMAL(38, 18),
+            failure_reason TEXT,
+            created_at BIGINT NOT NULL,
+            last_event_at BIGINT NOT NULL,
+            last_sequence BIGINT NOT NULL,
+            aggregate_versions JSONB NOT NULL DEFAULT '{}'
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Create webhook tables
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS webhook_subscriptions (
+            id UUID PRIMARY KEY,
+            url TEXT NOT NULL,
+            secret TEXT NOT NULL,
+            event_filters JSONB NOT NULL,
+            active BOOLEAN NOT NULL DEFAULT TRUE,
+            consecutive_failures INTEGER NOT NULL DEFAULT 0,
+            created_at BIGINT NOT NULL,
+            updated_at BIGINT NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS webhook_deliveries (
+            id UUID PRIMARY KEY,
+            subscription_id UUID NOT NULL REFERENCES webhook_subscriptions(id) ON DELETE CASCADE,
+            event_id UUID NOT NULL,
+            event_name VARCHAR(100) NOT NULL,
+            payload JSONB NOT NULL,
+            status VARCHAR(20) NOT NULL,
+            attempts INTEGER NOT NULL,
+            response_status INTEGER,
+            last_error TEXT,
+            redelivery_of UUID,
+            created_at BIGINT NOT NULL,
+            updated_at BIGINT NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS trading_calendars (
+            name TEXT PRIMARY KEY,
+            timezone TEXT NOT NULL,
+            weekends_closed BOOLEAN NOT NULL DEFAULT TRUE,
+            holidays DATE[] NOT NULL DEFAULT '{}'
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS erasure_requests (
+            id UUID PRIMARY KEY,
+            counterparty_id VARCHAR(255) NOT NULL,
+            requested_by VARCHAR(255) NOT NULL,
+            reason TEXT NOT NULL,
+            status VARCHAR(20) NOT NULL,
+            approved_by VARCHAR(255),
+            approved_at BIGINT,
+            executed_at BIGINT,
+            created_at BIGINT NOT NULL,
+            updated_at BIGINT NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS counterparty_keys (
+            key_id UUID PRIMARY KEY,
+            counterparty_id VARCHAR(255) NOT NULL,
+            key_material BYTEA,
+            shredded_at BIGINT,
+            created_at BIGINT NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE UNIQUE INDEX IF NOT EXISTS idx_counterparty_keys_active
+            ON counterparty_keys(counterparty_id)
+            WHERE key_material IS NOT NULL
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS venue_credentials (
+            counterparty_id VARCHAR(255) NOT NULL,
+            venue_id VARCHAR(255) NOT NULL,
+            api_key TEXT NOT NULL,
+            api_secret TEXT,
+            created_at BIGINT NOT NULL,
+            updated_at BIGINT NOT NULL,
+            PRIMARY KEY (counterparty_id, venue_id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS counterparties (
+            id VARCHAR(255) PRIMARY KEY,
+            name TEXT NOT NULL,
+            name_index VARCHAR(64),
+            counterparty_type VARCHAR(50) NOT NULL,
+            kyc_status VARCHAR(50) NOT NULL DEFAULT 'NotStarted',
+            limits JSONB NOT NULL DEFAULT '{}',
+            wallet_addresses JSONB NOT NULL DEFAULT '[]',
+            settlement_addresses JSONB NOT NULL DEFAULT '[]',
+            notification_channels TEXT[] DEFAULT '{}',
+            notification_email TEXT,
+            notification_webhook_url VARCHAR(512),
+            notification_grpc_endpoint VARCHAR(255),
+            active BOOLEAN NOT NULL DEFAULT true,
+            created_at BIGINT NOT NULL,
+            updated_at BIGINT NOT NULL,
+            settlement_window JSONB
         )
         "#,
     )
@@ -127,8 +485,46 @@ async fn cleanup_tables(pool: &PgPool) -> Result<(), sqlx::Error> {
     sqlx::query("DELETE FROM domain_events")
         .execute(pool)
         .await?;
+    sqlx::query("DELETE FROM trade_fees").execute(pool).await?;
+    sqlx::query("DELETE FROM trade_allocations")
+        .execute(pool)
+        .await?;
     sqlx::query("DELETE FROM trades").execute(pool).await?;
     sqlx::query("DELETE FROM rfqs").execute(pool).await?;
+    sqlx::query("DELETE FROM instrument_reference_data")
+        .execute(pool)
+        .await?;
+    sqlx::query("DELETE FROM rfq_templates")
+        .execute(pool)
+        .await?;
+    sqlx::query("DELETE FROM negotiations")
+        .execute(pool)
+        .await?;
+    sqlx::query("DELETE FROM venue_raw_exchanges")
+        .execute(pool)
+        .await?;
+    sqlx::query("DELETE FROM rfq_summary").execute(pool).await?;
+    sqlx::query("DELETE FROM webhook_deliveries")
+        .execute(pool)
+        .await?;
+    sqlx::query("DELETE FROM webhook_subscriptions")
+        .execute(pool)
+        .await?;
+    sqlx::query("DELETE FROM trading_calendars")
+        .execute(pool)
+        .await?;
+    sqlx::query("DELETE FROM erasure_requests")
+        .execute(pool)
+        .await?;
+    sqlx::query("DELETE FROM counterparty_keys")
+        .execute(pool)
+        .await?;
+    sqlx::query("DELETE FROM venue_credentials")
+        .execute(pool)
+        .await?;
+    sqlx::query("DELETE FROM counterparties")
+        .execute(pool)
+        .await?;
     Ok(())
 }
 
@@ -175,6 +571,10 @@ fn create_test_event(rfq_id: RfqId, sequence: u64) -> StoredEvent {
             "symbol": "BTC/USD"
         }),
         sequence,
+        correlation_id: Some(crate::domain::value_objects::CorrelationId::from(rfq_id)),
+        aggregate_id: None,
+        aggregate_version: 0,
+        aggregate_sequence: 0,
     }
 }
 
@@ -311,6 +711,37 @@ async fn rfq_repository_find_by_client() {
     cleanup_tables(&pool).await.unwrap();
 }
 
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn rfq_repository_list_offset_matches_keyset_order() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let repo = PostgresRfqRepository::new(pool.clone());
+    for _ in 0..4 {
+        repo.save(&create_test_rfq()).await.unwrap();
+    }
+
+    let filter = RfqListFilter {
+        client_id: Some(CounterpartyId::new("test-client")),
+        ..RfqListFilter::default()
+    };
+    let newest_first = repo.list_after(None, 10, &filter).await.unwrap();
+    let page = repo.list_offset(1, 2, &filter).await.unwrap();
+
+    let ids: Vec<RfqId> = page.iter().map(Rfq::id).collect();
+    let expected: Vec<RfqId> = newest_first.iter().skip(1).take(2).map(Rfq::id).collect();
+    assert_eq!(ids, expected);
+    assert_eq!(repo.count_matching(&filter).await.unwrap(), 4);
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
 #[tokio::test]
 #[ignore = "requires PostgreSQL database"]
 async fn rfq_repository_get_nonexistent() {
@@ -399,13 +830,9 @@ async fn rfq_repository_min_quantity_none_remains_none() {
     cleanup_tables(&pool).await.unwrap();
 }
 
-// ============================================================================
-// Trade Repository Tests
-// ============================================================================
-
 #[tokio::test]
 #[ignore = "requires PostgreSQL database"]
-async fn trade_repository_save_and_get() {
+async fn rfq_repository_size_mode_survives_round_trip() {
     let pool = match create_test_pool().await {
         Some(p) => p,
         None => return,
@@ -414,31 +841,40 @@ async fn trade_repository_save_and_get() {
     setup_tables(&pool).await.unwrap();
     cleanup_tables(&pool).await.unwrap();
 
-    let repo = PostgresTradeRepository::new(pool.clone());
-
-    let rfq_id = RfqId::new_v4();
-    let quote_id = QuoteId::new_v4();
-    let trade = create_test_trade(rfq_id, quote_id);
-    let trade_id = trade.id();
+    let repo = PostgresRfqRepository::new(pool.clone());
 
-    // Save
-    repo.save(&trade).await.unwrap();
+    let modes = [
+        SizeNegotiationMode::AllOrNothing,
+        SizeNegotiationMode::FillOrKill,
+        SizeNegotiationMode::MinQuantity(Quantity::new(4.0).unwrap()),
+        SizeNegotiationMode::BestEffort,
+    ];
+    for mode in modes {
+        let symbol = Symbol::new("BTC/USD").unwrap();
+        let instrument = Instrument::builder(symbol, AssetClass::CryptoSpot).build();
+        let rfq = RfqBuilder::new(
+            CounterpartyId::new("test-client"),
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(10.0).unwrap(),
+            Timestamp::now().add_secs(3600),
+        )
+        .size_mode(mode.clone())
+        .build();
 
-    // Get
-    let result = repo.get(trade_id).await.unwrap();
-    assert!(result.is_some());
+        repo.save(&rfq).await.unwrap();
+        let retrieved = repo.get(rfq.id()).await.unwrap().unwrap();
 
-    let retrieved = result.unwrap();
-    assert_eq!(retrieved.id(), trade_id);
-    assert_eq!(retrieved.rfq_id(), rfq_id);
-    assert_eq!(retrieved.quote_id(), quote_id);
+        assert_eq!(retrieved.size_mode(), &mode);
+        assert_eq!(retrieved.min_quantity(), mode.min_quantity());
+    }
 
     cleanup_tables(&pool).await.unwrap();
 }
 
 #[tokio::test]
 #[ignore = "requires PostgreSQL database"]
-async fn trade_repository_update_settlement_state() {
+async fn rfq_repository_quantity_disclosure_survives_round_trip() {
     let pool = match create_test_pool().await {
         Some(p) => p,
         None => return,
@@ -447,30 +883,40 @@ async fn trade_repository_update_settlement_state() {
     setup_tables(&pool).await.unwrap();
     cleanup_tables(&pool).await.unwrap();
 
-    let repo = PostgresTradeRepository::new(pool.clone());
-
-    let rfq_id = RfqId::new_v4();
-    let quote_id = QuoteId::new_v4();
-    let mut trade = create_test_trade(rfq_id, quote_id);
-    let trade_id = trade.id();
+    let repo = PostgresRfqRepository::new(pool.clone());
 
-    // Save initial
-    repo.save(&trade).await.unwrap();
+    let disclosures = [
+        QuantityDisclosure::Exact,
+        QuantityDisclosure::Bucketed {
+            bucket: Quantity::new(25.0).unwrap(),
+        },
+        QuantityDisclosure::Hidden,
+    ];
+    for disclosure in disclosures {
+        let symbol = Symbol::new("BTC/USD").unwrap();
+        let instrument = Instrument::builder(symbol, AssetClass::CryptoSpot).build();
+        let rfq = RfqBuilder::new(
+            CounterpartyId::new("test-client"),
+            instrument,
+            OrderSide::Buy,
+            Quantity::new(10.0).unwrap(),
+            Timestamp::now().add_secs(3600),
+        )
+        .quantity_disclosure(disclosure)
+        .build();
 
-    // Update settlement state
-    trade.start_settlement().unwrap();
-    repo.save(&trade).await.unwrap();
+        repo.save(&rfq).await.unwrap();
+        let retrieved = repo.get(rfq.id()).await.unwrap().unwrap();
 
-    // Verify
-    let retrieved = repo.get(trade_id).await.unwrap().unwrap();
-    assert!(retrieved.is_in_progress());
+        assert_eq!(retrieved.quantity_disclosure(), disclosure);
+    }
 
     cleanup_tables(&pool).await.unwrap();
 }
 
 #[tokio::test]
 #[ignore = "requires PostgreSQL database"]
-async fn trade_repository_find_by_rfq() {
+async fn rfq_repository_two_way_survives_round_trip() {
     let pool = match create_test_pool().await {
         Some(p) => p,
         None => return,
@@ -479,29 +925,903 @@ async fn trade_repository_find_by_rfq() {
     setup_tables(&pool).await.unwrap();
     cleanup_tables(&pool).await.unwrap();
 
-    let repo = PostgresTradeRepository::new(pool.clone());
-
-    let rfq_id = RfqId::new_v4();
-    let quote_id1 = QuoteId::new_v4();
-    let quote_id2 = QuoteId::new_v4();
-
-    let trade1 = create_test_trade(rfq_id, quote_id1);
-    let trade2 = create_test_trade(rfq_id, quote_id2);
+    let repo = PostgresRfqRepository::new(pool.clone());
 
-    repo.save(&trade1).await.unwrap();
-    repo.save(&trade2).await.unwrap();
+    let symbol = Symbol::new("BTC/USD").unwrap();
+    let instrument = Instrument::builder(symbol, AssetClass::CryptoSpot).build();
+    let rfq = RfqBuilder::new(
+        CounterpartyId::new("test-client"),
+        instrument,
+        RfqDirection::TwoWay,
+        Quantity::new(10.0).unwrap(),
+        Timestamp::now().add_secs(3600),
+    )
+    .build();
+
+    repo.save(&rfq).await.unwrap();
+    let retrieved = repo.get(rfq.id()).await.unwrap().unwrap();
+
+    assert_eq!(retrieved.direction(), RfqDirection::TwoWay);
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn rfq_repository_venue_lists_survive_round_trip() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let repo = PostgresRfqRepository::new(pool.clone());
+
+    let symbol = Symbol::new("BTC/USD").unwrap();
+    let instrument = Instrument::builder(symbol, AssetClass::CryptoSpot).build();
+    let rfq = RfqBuilder::new(
+        CounterpartyId::new("test-client"),
+        instrument,
+        OrderSide::Buy,
+        Quantity::new(10.0).unwrap(),
+        Timestamp::now().add_secs(3600),
+    )
+    .venue_allowlist(vec![VenueId::new("mm-1"), VenueId::new("mm-2")])
+    .venue_blocklist(vec![VenueId::new("mm-3")])
+    .build();
+
+    repo.save(&rfq).await.unwrap();
+    let retrieved = repo.get(rfq.id()).await.unwrap().unwrap();
+    assert_eq!(
+        retrieved.venue_allowlist(),
+        Some([VenueId::new("mm-1"), VenueId::new("mm-2")].as_slice())
+    );
+    assert_eq!(retrieved.venue_blocklist(), [VenueId::new("mm-3")]);
+
+    let unrestricted = create_test_rfq();
+    repo.save(&unrestricted).await.unwrap();
+    let retrieved = repo.get(unrestricted.id()).await.unwrap().unwrap();
+    assert_eq!(retrieved.venue_allowlist(), None);
+    assert!(retrieved.venue_blocklist().is_empty());
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn rfq_repository_finds_rfqs_pending_activation() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let repo = PostgresRfqRepository::new(pool.clone());
+
+    let symbol = Symbol::new("BTC/USD").unwrap();
+    let instrument = Instrument::builder(symbol, AssetClass::CryptoSpot).build();
+    let activate_at = Timestamp::now().add_secs(60);
+    let scheduled = RfqBuilder::new(
+        CounterpartyId::new("test-client"),
+        instrument,
+        OrderSide::Buy,
+        Quantity::new(10.0).unwrap(),
+        Timestamp::now().add_secs(3600),
+    )
+    .activate_at(activate_at)
+    .build();
+    repo.save(&scheduled).await.unwrap();
+    repo.save(&create_test_rfq()).await.unwrap();
+
+    let retrieved = repo.get(scheduled.id()).await.unwrap().unwrap();
+    assert_eq!(
+        retrieved.activate_at().map(|t| t.timestamp_millis()),
+        Some(activate_at.timestamp_millis())
+    );
+
+    let early = repo
+        .find_pending_activation(activate_at.sub_secs(1))
+        .await
+        .unwrap();
+    assert!(early.is_empty());
+
+    let due = repo.find_pending_activation(activate_at).await.unwrap();
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].id(), scheduled.id());
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn rfq_repository_strategy_survives_round_trip() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let repo = PostgresRfqRepository::new(pool.clone());
+
+    let call =
+        Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoDerivs).build();
+    let put = call.clone();
+    let straddle = Strategy::straddle(call, put, "BTC").unwrap();
+
+    let rfq = RfqBuilder::new(
+        CounterpartyId::new("test-client"),
+        straddle.clone(),
+        OrderSide::Buy,
+        Quantity::new(2.0).unwrap(),
+        Timestamp::now().add_secs(3600),
+    )
+    .build();
+    let rfq_id = rfq.id();
+
+    repo.save(&rfq).await.unwrap();
+    let retrieved = repo.get(rfq_id).await.unwrap().unwrap();
+
+    assert!(retrieved.is_multi_leg());
+    assert_eq!(retrieved.strategy(), Some(&straddle));
+    assert_eq!(retrieved.instrument(), rfq.instrument());
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn rfq_repository_net_premium_quote_survives_round_trip() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let repo = PostgresRfqRepository::new(pool.clone());
+
+    let call =
+        Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoDerivs).build();
+    let put = call.clone();
+    let straddle = Strategy::straddle(call, put, "BTC").unwrap();
+
+    let mut rfq = RfqBuilder::new(
+        CounterpartyId::new("test-client"),
+        straddle,
+        OrderSide::Sell,
+        Quantity::new(2.0).unwrap(),
+        Timestamp::now().add_secs(3600),
+    )
+    .build();
+    rfq.start_quote_collection().unwrap();
+    let quote = Quote::new_net_premium(
+        rfq.id(),
+        VenueId::new("deribit"),
+        Premium::new(Decimal::new(-120, 2)),
+        vec![QuoteLegPrice::new(0, Some(Price::new(2.5).unwrap()))],
+        Quantity::new(2.0).unwrap(),
+        Timestamp::now().add_secs(60),
+    )
+    .unwrap();
+    rfq.receive_quote(quote.clone()).unwrap();
+
+    repo.save(&rfq).await.unwrap();
+    let retrieved = repo.get(rfq.id()).await.unwrap().unwrap();
+
+    assert_eq!(retrieved.quotes(), std::slice::from_ref(&quote));
+    assert!(
+        retrieved
+            .quotes()
+            .iter()
+            .all(|q| q.net_premium().is_credit())
+    );
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+// ============================================================================
+// Trade Repository Tests
+// ============================================================================
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn trade_repository_save_and_get() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let repo = PostgresTradeRepository::new(pool.clone());
+
+    let rfq_id = RfqId::new_v4();
+    let quote_id = QuoteId::new_v4();
+    let trade = create_test_trade(rfq_id, quote_id);
+    let trade_id = trade.id();
+
+    // Save
+    repo.save(&trade).await.unwrap();
+
+    // Get
+    let result = repo.get(trade_id).await.unwrap();
+    assert!(result.is_some());
+
+    let retrieved = result.unwrap();
+    assert_eq!(retrieved.id(), trade_id);
+    assert_eq!(retrieved.rfq_id(), rfq_id);
+    assert_eq!(retrieved.quote_id(), quote_id);
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn trade_repository_roundtrips_execution_costs() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let repo = PostgresTradeRepository::new(pool.clone());
+
+    let mut trade = create_test_trade(RfqId::new_v4(), QuoteId::new_v4());
+    trade.set_reference_price_at_execution(Price::new(49950.0).unwrap());
+    trade.set_price_bounds_check(PriceBoundsCheck::overridden(
+        "admin-1",
+        "no reference price",
+    ));
+    trade.set_collateral_decision(CollateralDecision::Insufficient {
+        required: Decimal::new(5000, 0),
+        available: Decimal::new(1200, 0),
+    });
+    trade.add_fee(FeeComponent::new(
+        FeeKind::Venue,
+        Decimal::new(125, 1),
+        "USDC",
+    ));
+    trade.add_fee(FeeComponent::new(FeeKind::Gas, Decimal::new(42, 5), "ETH"));
+    trade.set_settlement_plan(SettlementPlan {
+        chain: Some(Blockchain::Base),
+        window: Some(SettlementWindow::NextDay {
+            cutoff: SettlementCutoff::default(),
+        }),
+        note: None,
+    });
+    repo.save(&trade).await.unwrap();
+
+    let retrieved = repo.get(trade.id()).await.unwrap().unwrap();
+    assert_eq!(
+        retrieved.reference_price_at_execution(),
+        trade.reference_price_at_execution()
+    );
+    assert_eq!(retrieved.fees(), trade.fees());
+    assert_eq!(retrieved.price_bounds_check(), trade.price_bounds_check());
+    assert_eq!(retrieved.collateral_decision(), trade.collateral_decision());
+    assert_eq!(retrieved.settlement_plan(), trade.settlement_plan());
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn trade_repository_roundtrips_allocations() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let repo = PostgresTradeRepository::new(pool.clone());
+
+    let mut trade = create_test_trade(RfqId::new_v4(), QuoteId::new_v4());
+    let scheduled_at = Timestamp::from_millis(1_700_000_000_000).unwrap();
+    for (venue, qty, price) in [("mm-1", 6.0, 50000.0), ("mm-2", 4.0, 50010.0)] {
+        let allocation = Allocation::new(
+            VenueId::new(venue),
+            QuoteId::new_v4(),
+            Quantity::new(qty).unwrap(),
+            Price::new(price).unwrap(),
+        )
+        .unwrap();
+        trade.add_allocation(TradeAllocation::scheduled(allocation, scheduled_at));
+    }
+    trade.start_allocations().unwrap();
+    trade.fill_allocation(0).unwrap();
+    trade.fail_allocation(1, "venue rejected").unwrap();
+    repo.save(&trade).await.unwrap();
+
+    let retrieved = repo.get(trade.id()).await.unwrap().unwrap();
+    assert_eq!(retrieved.allocations(), trade.allocations());
+    assert_eq!(retrieved.allocation_vwap(), trade.allocation_vwap());
+
+    let unwind = Trade::unwind_of(&trade, trade.allocations().first().unwrap().allocation());
+    repo.save(&unwind).await.unwrap();
+    let retrieved = repo.get(unwind.id()).await.unwrap().unwrap();
+    assert_eq!(retrieved.unwinds(), Some(trade.id()));
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn trade_repository_update_settlement_state() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let repo = PostgresTradeRepository::new(pool.clone());
+
+    let rfq_id = RfqId::new_v4();
+    let quote_id = QuoteId::new_v4();
+    let mut trade = create_test_trade(rfq_id, quote_id);
+    let trade_id = trade.id();
+
+    // Save initial
+    repo.save(&trade).await.unwrap();
+
+    // Update settlement state
+    trade.start_settlement().unwrap();
+    repo.save(&trade).await.unwrap();
+
+    // Verify
+    let retrieved = repo.get(trade_id).await.unwrap().unwrap();
+    assert!(retrieved.is_in_progress());
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn trade_repository_find_by_rfq() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let repo = PostgresTradeRepository::new(pool.clone());
+
+    let rfq_id = RfqId::new_v4();
+    let quote_id1 = QuoteId::new_v4();
+    let quote_id2 = QuoteId::new_v4();
+
+    let trade1 = create_test_trade(rfq_id, quote_id1);
+    let trade2 = create_test_trade(rfq_id, quote_id2);
+
+    repo.save(&trade1).await.unwrap();
+    repo.save(&trade2).await.unwrap();
+
+    // Find by RFQ (get_by_rfq returns Option<Trade>, not Vec)
+    let trade = repo.get_by_rfq(rfq_id).await.unwrap();
+    // get_by_rfq returns the first trade for the RFQ
+    assert!(trade.is_some());
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn trade_repository_optimistic_locking() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let repo = PostgresTradeRepository::new(pool.clone());
+
+    let rfq_id = RfqId::new_v4();
+    let quote_id = QuoteId::new_v4();
+    let trade = create_test_trade(rfq_id, quote_id);
+    let trade_id = trade.id();
+
+    // Save initial
+    repo.save(&trade).await.unwrap();
+
+    // Get two copies
+    let mut trade1 = repo.get(trade_id).await.unwrap().unwrap();
+    let mut trade2 = repo.get(trade_id).await.unwrap().unwrap();
+
+    // Update first
+    trade1.start_settlement().unwrap();
+    repo.save(&trade1).await.unwrap();
+
+    // Try to update second (should fail)
+    trade2.start_settlement().unwrap();
+    let result = repo.save(&trade2).await;
+
+    assert!(result.is_err());
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+// ============================================================================
+// Event Store Tests
+// ============================================================================
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn event_store_append_and_get() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let store = PostgresEventStore::new(pool.clone());
+
+    let rfq_id = RfqId::new_v4();
+    let event = create_test_event(rfq_id, 1);
+
+    // Append
+    store.append(event.clone()).await.unwrap();
+
+    // Get events
+    let events = store.get_events(rfq_id).await.unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event_id, event.event_id);
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn event_store_ordering() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let store = PostgresEventStore::new(pool.clone());
+
+    let rfq_id = RfqId::new_v4();
+
+    // Append events out of order
+    let event3 = create_test_event(rfq_id, 3);
+    let event1 = create_test_event(rfq_id, 1);
+    let event2 = create_test_event(rfq_id, 2);
+
+    store.append(event3.clone()).await.unwrap();
+    store.append(event1.clone()).await.unwrap();
+    store.append(event2.clone()).await.unwrap();
+
+    // Get events (should be ordered by sequence)
+    let events = store.get_events(rfq_id).await.unwrap();
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[0].sequence, 1);
+    assert_eq!(events[1].sequence, 2);
+    assert_eq!(events[2].sequence, 3);
 
-    // Find by RFQ (get_by_rfq returns Option<Trade>, not Vec)
-    let trade = repo.get_by_rfq(rfq_id).await.unwrap();
-    // get_by_rfq returns the first trade for the RFQ
-    assert!(trade.is_some());
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn event_store_append_only() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let store = PostgresEventStore::new(pool.clone());
+
+    let rfq_id = RfqId::new_v4();
+    let event = create_test_event(rfq_id, 1);
+
+    // Append same event twice (should fail due to unique constraint)
+    store.append(event.clone()).await.unwrap();
+    let result = store.append(event.clone()).await;
+
+    assert!(result.is_err());
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn event_store_rejects_duplicate_and_skipped_sequences() {
+    use crate::infrastructure::persistence::event_store::EventStoreError;
+
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let store = PostgresEventStore::new(pool.clone());
+
+    let rfq_id = RfqId::new_v4();
+    // Versions may skip; sequence numbers may not.
+    let versioned = |sequence: u64| {
+        create_test_event(rfq_id, sequence)
+            .with_aggregate_version(rfq_id.get(), sequence * 2)
+            .with_aggregate_sequence(sequence)
+    };
+
+    store.append(versioned(1)).await.unwrap();
+    let duplicate = store.append(versioned(1)).await;
+    assert!(matches!(
+        duplicate,
+        Err(EventStoreError::VersionConflict {
+            expected: 2,
+            actual: 1,
+            ..
+        })
+    ));
+    let gap = store.append(versioned(3)).await;
+    assert!(matches!(
+        gap,
+        Err(EventStoreError::VersionConflict {
+            expected: 2,
+            actual: 3,
+            ..
+        })
+    ));
+    assert_eq!(
+        store.next_aggregate_sequence(rfq_id.get()).await.unwrap(),
+        2
+    );
+    store.append(versioned(2)).await.unwrap();
+
+    let events = store.get_events(rfq_id).await.unwrap();
+    let sequences: Vec<u64> = events.iter().map(|e| e.aggregate_sequence).collect();
+    assert_eq!(sequences, vec![1, 2]);
+    let versions: Vec<u64> = events.iter().map(|e| e.aggregate_version).collect();
+    assert_eq!(versions, vec![2, 4]);
+    assert!(events.iter().all(|e| e.aggregate_id == Some(rfq_id.get())));
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn event_store_multiple_rfqs() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let store = PostgresEventStore::new(pool.clone());
+
+    let rfq_id1 = RfqId::new_v4();
+    let rfq_id2 = RfqId::new_v4();
+
+    // Append events for different RFQs
+    store.append(create_test_event(rfq_id1, 1)).await.unwrap();
+    store.append(create_test_event(rfq_id1, 2)).await.unwrap();
+    store.append(create_test_event(rfq_id2, 1)).await.unwrap();
+
+    // Get events for each RFQ
+    let events1 = store.get_events(rfq_id1).await.unwrap();
+    let events2 = store.get_events(rfq_id2).await.unwrap();
+
+    assert_eq!(events1.len(), 2);
+    assert_eq!(events2.len(), 1);
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn event_store_query_by_correlation() {
+    use crate::domain::value_objects::CorrelationId;
+
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let store = PostgresEventStore::new(pool.clone());
+
+    let rfq_id = RfqId::new_v4();
+    store.append(create_test_event(rfq_id, 1)).await.unwrap();
+    store.append(create_test_event(rfq_id, 2)).await.unwrap();
+    store
+        .append(create_test_event(RfqId::new_v4(), 1))
+        .await
+        .unwrap();
+
+    let events = store
+        .query_by_correlation(CorrelationId::from(rfq_id))
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 2);
+    assert!(
+        events
+            .iter()
+            .all(|e| e.correlation_id == Some(CorrelationId::from(rfq_id)))
+    );
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn event_store_query_by_time_range_pages() {
+    use crate::infrastructure::persistence::cursor::PageCursor;
+
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let store = PostgresEventStore::new(pool.clone());
+
+    let base = Timestamp::from_millis(1_700_000_000_000).unwrap();
+    for offset in [0, 10, 10, 20] {
+        let mut event = create_test_event(RfqId::new_v4(), 1);
+        event.timestamp = base.add_millis(offset);
+        store.append(event).await.unwrap();
+    }
+
+    let to = base.add_millis(20);
+    let first = store
+        .query_by_time_range(base, to, None, 2, None)
+        .await
+        .unwrap();
+    let cursor = PageCursor::from_event(first.last().unwrap());
+    let second = store
+        .query_by_time_range(base, to, None, 2, Some(&cursor))
+        .await
+        .unwrap();
+
+    assert_eq!(first.len(), 2);
+    assert_eq!(second.len(), 1);
+    assert!(first.iter().all(|e| e.event_id != second[0].event_id));
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+// ============================================================================
+// Database Cleanup Tests
+// ============================================================================
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn cleanup_between_tests() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+
+    let rfq_repo = PostgresRfqRepository::new(pool.clone());
+    let trade_repo = PostgresTradeRepository::new(pool.clone());
+    let event_store = PostgresEventStore::new(pool.clone());
+
+    // Create test data
+    let rfq = create_test_rfq();
+    let rfq_id = rfq.id();
+    rfq_repo.save(&rfq).await.unwrap();
+
+    let trade = create_test_trade(rfq_id, QuoteId::new_v4());
+    trade_repo.save(&trade).await.unwrap();
+
+    event_store
+        .append(create_test_event(rfq_id, 1))
+        .await
+        .unwrap();
+
+    // Cleanup
+    cleanup_tables(&pool).await.unwrap();
+
+    // Verify cleanup
+    let rfqs = rfq_repo.get(rfq_id).await.unwrap();
+    assert!(rfqs.is_none());
+
+    let trade = trade_repo.get_by_rfq(rfq_id).await.unwrap();
+    assert!(trade.is_none());
+
+    let events = event_store.get_events(rfq_id).await.unwrap();
+    assert!(events.is_empty());
+}
+
+// ============================================================================
+// Instrument Reference Data Repository Tests
+// ============================================================================
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn instrument_reference_data_repository_roundtrip() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let repo = PostgresInstrumentReferenceDataRepository::new(pool.clone());
+    let symbol = Symbol::new("BTC/USD").unwrap();
+    let data = InstrumentReferenceData::new(symbol.clone(), Decimal::new(5, 1), Decimal::new(1, 3))
+        .unwrap()
+        .with_order_size_limits(
+            Quantity::new(0.01).unwrap(),
+            Some(Quantity::new(500.0).unwrap()),
+        )
+        .unwrap()
+        .with_contract_multiplier(Decimal::new(10, 0))
+        .unwrap();
+
+    repo.save(&data).await.unwrap();
+    assert_eq!(repo.get(&symbol).await.unwrap(), Some(data));
+    assert_eq!(repo.get_all().await.unwrap().len(), 1);
+
+    assert!(repo.delete(&symbol).await.unwrap());
+    assert!(repo.get(&symbol).await.unwrap().is_none());
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+// ============================================================================
+// Trading Calendar Repository Tests
+// ============================================================================
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn trading_calendar_repository_roundtrip() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let repo = PostgresTradingCalendarRepository::new(pool.clone());
+    let calendar = TradingCalendar::new("NYSE", chrono_tz::America::New_York, true)
+        .unwrap()
+        .with_holidays([
+            chrono::NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(),
+            chrono::NaiveDate::from_ymd_opt(2024, 7, 4).unwrap(),
+        ]);
+
+    repo.save(&calendar).await.unwrap();
+    assert_eq!(repo.find("nyse").await.unwrap(), Some(calendar));
+    assert_eq!(repo.find_all().await.unwrap().len(), 1);
+
+    assert!(repo.delete("NYSE").await.unwrap());
+    assert!(repo.find("NYSE").await.unwrap().is_none());
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+// ============================================================================
+// Counterparty Erasure Tests
+// ============================================================================
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn erasure_request_repository_roundtrip() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let repo = PostgresErasureRequestRepository::new(pool.clone());
+    let mut request = ErasureRequest::new(CounterpartyId::new("acme"), "legal-1", "offboarded");
+    repo.save(&request).await.unwrap();
+    request.approve("legal-2").unwrap();
+    repo.save(&request).await.unwrap();
+
+    let loaded = repo.get(request.id()).await.unwrap().unwrap();
+    assert_eq!(loaded.status(), ErasureRequestStatus::Approved);
+    assert_eq!(loaded.approved_by(), Some("legal-2"));
+    assert_eq!(
+        repo.find_by_counterparty(&CounterpartyId::new("acme"))
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn counterparty_key_store_shreds_keys() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let keys = PostgresCounterpartyKeyStore::new(pool.clone());
+    let acme = CounterpartyId::new("acme");
+    let key = keys.get_or_create(&acme).await.unwrap();
+    assert_eq!(keys.get_or_create(&acme).await.unwrap(), key);
+    assert_eq!(
+        keys.lookup(key.key_id).await.unwrap(),
+        KeyLookup::Active(key.clone())
+    );
+
+    let pseudonym = CounterpartyId::new("erased-1");
+    assert!(keys.shred(&acme, &pseudonym).await.unwrap());
+    assert!(!keys.shred(&acme, &pseudonym).await.unwrap());
+    assert_eq!(
+        keys.lookup(key.key_id).await.unwrap(),
+        KeyLookup::Shredded(pseudonym)
+    );
+    assert_ne!(keys.get_or_create(&acme).await.unwrap().key_id, key.key_id);
 
     cleanup_tables(&pool).await.unwrap();
 }
 
+// ============================================================================
+// Counterparty Field Encryption Tests
+// ============================================================================
+
+/// Creates a key provider whose active key is `active`, also holding `older`.
+fn test_key_provider(active: &str, older: &[&str]) -> Arc<dyn KeyProvider> {
+    let keys = std::iter::once(active)
+        .chain(older.iter().copied())
+        .map(|id| FieldKey::new(id, [id.as_bytes()[0]; 32]).unwrap())
+        .collect();
+    Arc::new(EnvKeyProvider::new(keys, [9; 32]).unwrap())
+}
+
+/// Creates a counterparty with every personal data field set.
+fn create_test_counterparty(id: &str, name: &str) -> Counterparty {
+    let mut counterparty =
+        Counterparty::new(CounterpartyId::new(id), name, CounterpartyType::Client);
+    counterparty.add_wallet(WalletAddress::new(Blockchain::Ethereum, "0xabc123"));
+    counterparty.set_notification_preferences(
+        NotificationPreferences::email_only(format!("ops@{id}.example")).unwrap(),
+    );
+    counterparty
+}
+
 #[tokio::test]
 #[ignore = "requires PostgreSQL database"]
-async fn trade_repository_optimistic_locking() {
+async fn counterparty_repository_encrypts_pii_at_rest() {
     let pool = match create_test_pool().await {
         Some(p) => p,
         None => return,
@@ -510,40 +1830,39 @@ async fn trade_repository_optimistic_locking() {
     setup_tables(&pool).await.unwrap();
     cleanup_tables(&pool).await.unwrap();
 
-    let repo = PostgresTradeRepository::new(pool.clone());
-
-    let rfq_id = RfqId::new_v4();
-    let quote_id = QuoteId::new_v4();
-    let trade = create_test_trade(rfq_id, quote_id);
-    let trade_id = trade.id();
-
-    // Save initial
-    repo.save(&trade).await.unwrap();
-
-    // Get two copies
-    let mut trade1 = repo.get(trade_id).await.unwrap().unwrap();
-    let mut trade2 = repo.get(trade_id).await.unwrap().unwrap();
-
-    // Update first
-    trade1.start_settlement().unwrap();
-    repo.save(&trade1).await.unwrap();
-
-    // Try to update second (should fail)
-    trade2.start_settlement().unwrap();
-    let result = repo.save(&trade2).await;
+    let repo = PostgresCounterpartyRepository::new(pool.clone())
+        .with_field_encryption(test_key_provider("k1", &[]));
+    let counterparty = create_test_counterparty("acme", "Acme Capital");
+    repo.save(&counterparty).await.unwrap();
 
-    assert!(result.is_err());
+    let (name, email, wallets): (String, Option<String>, serde_json::Value) = sqlx::query_as(
+        "SELECT name, notification_email, wallet_addresses FROM counterparties WHERE id = $1",
+    )
+    .bind("acme")
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert!(EncryptedString::from_stored(name).is_encrypted());
+    assert!(!email.unwrap().contains("acme.example"));
+    assert!(!wallets.to_string().contains("0xabc123"));
+
+    let loaded = repo.get(counterparty.id()).await.unwrap().unwrap();
+    assert_eq!(loaded.name(), "Acme Capital");
+    assert_eq!(
+        loaded.notification_preferences().email_address(),
+        Some("ops@acme.example")
+    );
+    assert_eq!(loaded.wallet_addresses(), counterparty.wallet_addresses());
+
+    let plain = PostgresCounterpartyRepository::new(pool.clone());
+    assert!(plain.get(counterparty.id()).await.is_err());
 
     cleanup_tables(&pool).await.unwrap();
 }
 
-// ============================================================================
-// Event Store Tests
-// ============================================================================
-
 #[tokio::test]
 #[ignore = "requires PostgreSQL database"]
-async fn event_store_append_and_get() {
+async fn counterparty_repository_finds_encrypted_names_by_blind_index() {
     let pool = match create_test_pool().await {
         Some(p) => p,
         None => return,
@@ -552,25 +1871,30 @@ async fn event_store_append_and_get() {
     setup_tables(&pool).await.unwrap();
     cleanup_tables(&pool).await.unwrap();
 
-    let store = PostgresEventStore::new(pool.clone());
-
-    let rfq_id = RfqId::new_v4();
-    let event = create_test_event(rfq_id, 1);
+    let repo = PostgresCounterpartyRepository::new(pool.clone())
+        .with_field_encryption(test_key_provider("k1", &[]));
+    repo.save(&create_test_counterparty("acme", "Acme Capital"))
+        .await
+        .unwrap();
+    repo.save(&create_test_counterparty("zeta", "Zeta Markets"))
+        .await
+        .unwrap();
 
-    // Append
-    store.append(event.clone()).await.unwrap();
+    let found = repo.find_by_name("  ACME capital ").await.unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].id().as_str(), "acme");
+    assert!(repo.find_by_name("Acme").await.unwrap().is_empty());
 
-    // Get events
-    let events = store.get_events(rfq_id).await.unwrap();
-    assert_eq!(events.len(), 1);
-    assert_eq!(events[0].event_id, event.event_id);
+    let all = repo.get_all().await.unwrap();
+    let names: Vec<_> = all.iter().map(Counterparty::name).collect();
+    assert_eq!(names, vec!["Acme Capital", "Zeta Markets"]);
 
     cleanup_tables(&pool).await.unwrap();
 }
 
 #[tokio::test]
 #[ignore = "requires PostgreSQL database"]
-async fn event_store_ordering() {
+async fn counterparty_repository_rotates_keys() {
     let pool = match create_test_pool().await {
         Some(p) => p,
         None => return,
@@ -579,32 +1903,57 @@ async fn event_store_ordering() {
     setup_tables(&pool).await.unwrap();
     cleanup_tables(&pool).await.unwrap();
 
-    let store = PostgresEventStore::new(pool.clone());
-
-    let rfq_id = RfqId::new_v4();
+    // One row in clear, one under the old key
+    PostgresCounterpartyRepository::new(pool.clone())
+        .save(&create_test_counterparty("acme", "Acme Capital"))
+        .await
+        .unwrap();
+    PostgresCounterpartyRepository::new(pool.clone())
+        .with_field_encryption(test_key_provider("old", &[]))
+        .save(&create_test_counterparty("zeta", "Zeta Markets"))
+        .await
+        .unwrap();
 
-    // Append events out of order
-    let event3 = create_test_event(rfq_id, 3);
-    let event1 = create_test_event(rfq_id, 1);
-    let event2 = create_test_event(rfq_id, 2);
+    let repo = PostgresCounterpartyRepository::new(pool.clone())
+        .with_field_encryption(test_key_provider("new", &["old"]));
+    let report = repo.rotate_keys(1).await.unwrap();
+    assert_eq!(
+        report,
+        KeyRotationReport {
+            scanned: 2,
+            rotated: 2
+        }
+    );
+    assert_eq!(
+        repo.rotate_keys(10).await.unwrap(),
+        KeyRotationReport {
+            scanned: 2,
+            rotated: 0
+        }
+    );
 
-    store.append(event3.clone()).await.unwrap();
-    store.append(event1.clone()).await.unwrap();
-    store.append(event2.clone()).await.unwrap();
+    let names: Vec<String> = sqlx::query_scalar("SELECT name FROM counterparties")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    for name in names {
+        assert_eq!(EncryptedString::from_stored(name).key_id(), Some("new"));
+    }
 
-    // Get events (should be ordered by sequence)
-    let events = store.get_events(rfq_id).await.unwrap();
-    assert_eq!(events.len(), 3);
-    assert_eq!(events[0].sequence, 1);
-    assert_eq!(events[1].sequence, 2);
-    assert_eq!(events[2].sequence, 3);
+    let only_new = PostgresCounterpartyRepository::new(pool.clone())
+        .with_field_encryption(test_key_provider("new", &[]));
+    assert_eq!(only_new.get_all().await.unwrap().len(), 2);
+    assert_eq!(
+        only_new.find_by_name("acme capital").await.unwrap().len(),
+        1
+    );
 
     cleanup_tables(&pool).await.unwrap();
 }
 
 #[tokio::test]
 #[ignore = "requires PostgreSQL database"]
-async fn event_store_append_only() {
+async fn venue_credential_repository_encrypts_and_rotates() {
     let pool = match create_test_pool().await {
         Some(p) => p,
         None => return,
@@ -613,23 +1962,61 @@ async fn event_store_append_only() {
     setup_tables(&pool).await.unwrap();
     cleanup_tables(&pool).await.unwrap();
 
-    let store = PostgresEventStore::new(pool.clone());
-
-    let rfq_id = RfqId::new_v4();
-    let event = create_test_event(rfq_id, 1);
+    let acme = CounterpartyId::new("acme");
+    let hashflow = VenueId::new("hashflow");
+    let credentials = VenueCredentials::new("acme-key")
+        .unwrap()
+        .with_api_secret("acme-secret")
+        .unwrap();
+    PostgresVenueCredentialRepository::new(pool.clone(), test_key_provider("old", &[]))
+        .save(&VenueCredentialSet::new(
+            acme.clone(),
+            hashflow.clone(),
+            credentials.clone(),
+        ))
+        .await
+        .unwrap();
 
-    // Append same event twice (should fail due to unique constraint)
-    store.append(event.clone()).await.unwrap();
-    let result = store.append(event.clone()).await;
+    let (api_key, api_secret): (String, Option<String>) = sqlx::query_as(
+        "SELECT api_key, api_secret FROM venue_credentials WHERE counterparty_id = $1",
+    )
+    .bind("acme")
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert!(EncryptedString::from_stored(api_key.as_str()).is_encrypted());
+    assert!(!api_key.contains("acme-key"));
+    assert!(!api_secret.unwrap().contains("acme-secret"));
+
+    let repo =
+        PostgresVenueCredentialRepository::new(pool.clone(), test_key_provider("new", &["old"]));
+    assert_eq!(
+        repo.rotate_keys(10).await.unwrap(),
+        KeyRotationReport {
+            scanned: 1,
+            rotated: 1
+        }
+    );
+    let only_new =
+        PostgresVenueCredentialRepository::new(pool.clone(), test_key_provider("new", &[]));
+    let loaded = only_new.get(&acme, &hashflow).await.unwrap().unwrap();
+    assert_eq!(loaded.credentials(), &credentials);
+    assert_eq!(only_new.find_by_counterparty(&acme).await.unwrap().len(), 1);
 
-    assert!(result.is_err());
+    assert!(only_new.delete(&acme, &hashflow).await.unwrap());
+    assert!(!only_new.delete(&acme, &hashflow).await.unwrap());
+    assert!(only_new.get(&acme, &hashflow).await.unwrap().is_none());
 
     cleanup_tables(&pool).await.unwrap();
 }
 
+// ============================================================================
+// RFQ Template Repository Tests
+// ============================================================================
+
 #[tokio::test]
 #[ignore = "requires PostgreSQL database"]
-async fn event_store_multiple_rfqs() {
+async fn rfq_template_repository_roundtrip() {
     let pool = match create_test_pool().await {
         Some(p) => p,
         None => return,
@@ -638,75 +2025,181 @@ async fn event_store_multiple_rfqs() {
     setup_tables(&pool).await.unwrap();
     cleanup_tables(&pool).await.unwrap();
 
-    let store = PostgresEventStore::new(pool.clone());
-
-    let rfq_id1 = RfqId::new_v4();
-    let rfq_id2 = RfqId::new_v4();
-
-    // Append events for different RFQs
-    store.append(create_test_event(rfq_id1, 1)).await.unwrap();
-    store.append(create_test_event(rfq_id1, 2)).await.unwrap();
-    store.append(create_test_event(rfq_id2, 1)).await.unwrap();
-
-    // Get events for each RFQ
-    let events1 = store.get_events(rfq_id1).await.unwrap();
-    let events2 = store.get_events(rfq_id2).await.unwrap();
-
-    assert_eq!(events1.len(), 2);
-    assert_eq!(events2.len(), 1);
+    let repo = PostgresRfqTemplateRepository::new(pool.clone());
+    let owner = CounterpartyId::new("client-1");
+    let instrument =
+        Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build();
+    let template = RfqTemplateBuilder::new(
+        owner.clone(),
+        "Daily BTC buy",
+        instrument,
+        OrderSide::Sell,
+        Quantity::new(5.0).unwrap(),
+        120,
+    )
+    .size_mode(SizeNegotiationMode::MinQuantity(
+        Quantity::new(2.0).unwrap(),
+    ))
+    .venue_allowlist(vec![VenueId::new("mm-1")])
+    .venue_blocklist(vec![VenueId::new("mm-2")])
+    .try_build()
+    .unwrap();
+
+    repo.save(&template).await.unwrap();
+
+    let loaded = repo.find_by_id(template.id()).await.unwrap().unwrap();
+    assert_eq!(loaded.owner(), &owner);
+    assert_eq!(loaded.name(), "Daily BTC buy");
+    assert_eq!(loaded.subject(), template.subject());
+    assert_eq!(loaded.side(), OrderSide::Sell);
+    assert_eq!(loaded.quantity(), template.quantity());
+    assert_eq!(loaded.size_mode(), template.size_mode());
+    assert_eq!(loaded.venue_allowlist(), template.venue_allowlist());
+    assert_eq!(loaded.venue_blocklist(), template.venue_blocklist());
+    assert_eq!(loaded.default_ttl_secs(), 120);
+    assert_eq!(repo.find_by_owner(&owner).await.unwrap().len(), 1);
+    assert!(
+        repo.find_by_owner(&CounterpartyId::new("client-2"))
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    assert!(repo.delete(template.id()).await.unwrap());
+    assert!(repo.find_by_id(template.id()).await.unwrap().is_none());
 
     cleanup_tables(&pool).await.unwrap();
 }
 
 // ============================================================================
-// Database Cleanup Tests
+// Negotiation Repository Tests
 // ============================================================================
 
 #[tokio::test]
 #[ignore = "requires PostgreSQL database"]
-async fn cleanup_between_tests() {
+async fn negotiation_repository_roundtrip() {
     let pool = match create_test_pool().await {
         Some(p) => p,
         None => return,
     };
 
     setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
 
-    let rfq_repo = PostgresRfqRepository::new(pool.clone());
-    let trade_repo = PostgresTradeRepository::new(pool.clone());
-    let event_store = PostgresEventStore::new(pool.clone());
-
-    // Create test data
-    let rfq = create_test_rfq();
-    let rfq_id = rfq.id();
-    rfq_repo.save(&rfq).await.unwrap();
-
-    let trade = create_test_trade(rfq_id, QuoteId::new_v4());
-    trade_repo.save(&trade).await.unwrap();
-
-    event_store
-        .append(create_test_event(rfq_id, 1))
+    let repo = PostgresNegotiationRepository::new(pool.clone());
+    let mm = CounterpartyId::new("mm-1");
+    let mut negotiation = Negotiation::new(
+        RfqId::new_v4(),
+        CounterpartyId::new("client-1"),
+        mm.clone(),
+        OrderSide::Buy,
+        3,
+    )
+    .unwrap();
+    let counter = CounterQuoteBuilder::new(
+        QuoteId::new_v4(),
+        negotiation.rfq_id(),
+        mm.clone(),
+        Price::new(99.5).unwrap(),
+        Quantity::new(1.0).unwrap(),
+        Timestamp::now().add_secs(60),
+        1,
+    )
+    .build()
+    .unwrap();
+    negotiation.submit_counter(counter).unwrap();
+    repo.save(&negotiation).await.unwrap();
+
+    let window_start = Timestamp::now().sub_secs(60);
+    let window_end = Timestamp::now().add_secs(60);
+    assert!(
+        repo.find_completed_between(Some(&mm), window_start, window_end)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    negotiation.accept().unwrap();
+    repo.save(&negotiation).await.unwrap();
+
+    let loaded = repo.find_by_id(negotiation.id()).await.unwrap().unwrap();
+    assert_eq!(loaded.state(), NegotiationState::Accepted);
+    assert_eq!(loaded.rounds(), negotiation.rounds());
+    assert_eq!(loaded.latest_price(), negotiation.latest_price());
+
+    let completed = repo
+        .find_completed_between(Some(&mm), window_start, window_end)
         .await
         .unwrap();
+    assert_eq!(completed.len(), 1);
+    assert!(
+        repo.find_completed_between(Some(&CounterpartyId::new("mm-2")), window_start, window_end)
+            .await
+            .unwrap()
+            .is_empty()
+    );
 
-    // Cleanup
     cleanup_tables(&pool).await.unwrap();
+}
 
-    // Verify cleanup
-    let rfqs = rfq_repo.get(rfq_id).await.unwrap();
-    assert!(rfqs.is_none());
+// ============================================================================
+// Advisory Lock Tests
+// ============================================================================
 
-    let trade = trade_repo.get_by_rfq(rfq_id).await.unwrap();
-    assert!(trade.is_none());
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn advisory_lock_excludes_other_instances() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => {
+            eprintln!("Skipping test: TEST_DATABASE_URL not set");
+            return;
+        }
+    };
 
-    let events = event_store.get_events(rfq_id).await.unwrap();
-    assert!(events.is_empty());
+    // Two managers stand in for two API instances sharing the database
+    let instance_a = PostgresLockManager::with_defaults(pool.clone());
+    let instance_b = PostgresLockManager::with_defaults(pool);
+    let resource = ResourceLock::Rfq(RfqId::new_v4());
+    let timeout = std::time::Duration::from_millis(50);
+
+    let guard = instance_a
+        .acquire_all(vec![resource.clone()], timeout)
+        .await
+        .unwrap();
+    assert!(
+        instance_b
+            .acquire_all(vec![resource.clone()], timeout)
+            .await
+            .is_err()
+    );
+
+    guard.release().await.unwrap();
+    let guard = instance_b
+        .acquire_all(vec![resource], timeout)
+        .await
+        .unwrap();
+    guard.release().await.unwrap();
 }
 
 // ============================================================================
 // Mock Tests (run without database)
 // ============================================================================
 
+#[test]
+fn advisory_lock_key_is_stable_per_resource() {
+    let rfq_id = RfqId::new_v4();
+    let key = advisory_lock_key(&ResourceLock::Rfq(rfq_id));
+    assert_eq!(key, advisory_lock_key(&ResourceLock::Rfq(rfq_id)));
+    assert_ne!(key, advisory_lock_key(&ResourceLock::Rfq(RfqId::new_v4())));
+
+    let uuid = rfq_id.get();
+    assert_ne!(
+        key,
+        advisory_lock_key(&ResourceLock::Quote(QuoteId::new(uuid)))
+    );
+}
+
 #[test]
 fn test_rfq_creation() {
     let rfq = create_test_rfq();
@@ -735,3 +2228,295 @@ fn test_stored_event_creation() {
     assert_eq!(event.sequence, 1);
     assert_eq!(event.event_name, "RfqCreated");
 }
+
+// ============================================================================
+// Raw Exchange Log Tests
+// ============================================================================
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn raw_exchange_log_roundtrip_and_purge() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let log = PostgresRawExchangeLog::new(pool.clone());
+    let rfq_id = RfqId::new_v4();
+    // Stored at millisecond precision.
+    let now = Timestamp::from_millis(Timestamp::now().timestamp_millis()).unwrap();
+    let exchange = |direction, payload, http_status, expires_at| RawExchange {
+        venue_id: VenueId::new("hashflow"),
+        rfq_id,
+        direction,
+        payload,
+        http_status,
+        latency_ms: http_status.map(|_| 15),
+        recorded_at: now,
+        expires_at,
+    };
+
+    let request = exchange(
+        ExchangeDirection::Request,
+        serde_json::json!({ "api_key": "[REDACTED]" }),
+        None,
+        now.sub_secs(1),
+    );
+    let response = exchange(
+        ExchangeDirection::Response,
+        serde_json::json!("bad gateway"),
+        Some(502),
+        now.add_secs(3600),
+    );
+    log.record(&request).await.unwrap();
+    log.record(&response).await.unwrap();
+
+    let loaded = log.find_by_rfq(rfq_id).await.unwrap();
+    assert_eq!(loaded, vec![request, response.clone()]);
+
+    assert_eq!(log.purge_expired(now).await.unwrap(), 1);
+    assert_eq!(log.find_by_rfq(rfq_id).await.unwrap(), vec![response]);
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+// ============================================================================
+// RFQ Summary Store Tests
+// ============================================================================
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn rfq_summary_store_roundtrip_and_stale_upsert() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let store = PostgresRfqSummaryStore::new(pool.clone());
+    // Stored at millisecond precision.
+    let now = Timestamp::from_millis(Timestamp::now().timestamp_millis()).unwrap();
+    let summary = RfqSummary {
+        rfq_id: RfqId::new_v4(),
+        client_id: CounterpartyId::new("client-1"),
+        symbol: Symbol::new("BTC/USD").unwrap(),
+        side: OrderSide::Sell,
+        quantity: Quantity::new(2.0).unwrap(),
+        state: RfqState::Executed,
+        quote_count: 3,
+        best_quote_price: Some(Price::new(50_100.0).unwrap()),
+        selected_venue_id: Some(VenueId::new("bebop")),
+        trade_id: Some(TradeId::new_v4()),
+        execution_price: Some(Price::new(50_100.0).unwrap()),
+        failure_reason: None,
+        created_at: now,
+        last_event_at: now.add_secs(5),
+        last_sequence: 7,
+        aggregate_versions: [(uuid::Uuid::new_v4(), 4)].into_iter().collect(),
+    };
+    store.upsert(&summary).await.unwrap();
+
+    let stale = RfqSummary {
+        state: RfqState::QuoteRequesting,
+        last_sequence: 2,
+        ..summary.clone()
+    };
+    store.upsert(&stale).await.unwrap();
+
+    assert_eq!(
+        store.get(summary.rfq_id).await.unwrap(),
+        Some(summary.clone())
+    );
+    let filter = RfqListFilter {
+        states: vec![RfqState::Executed],
+        ..RfqListFilter::default()
+    };
+    assert_eq!(
+        store.list_after(None, 10, &filter).await.unwrap(),
+        vec![summary]
+    );
+    assert_eq!(store.clear().await.unwrap(), 1);
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn rfq_summary_store_list_offset_matches_keyset_order() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let store = PostgresRfqSummaryStore::new(pool.clone());
+    let base = Timestamp::from_millis(1_700_000_000_000).unwrap();
+    for (offset, client) in [
+        (0, "client-1"),
+        (1, "client-1"),
+        (2, "client-2"),
+        (3, "client-1"),
+    ] {
+        let summary = RfqSummary {
+            rfq_id: RfqId::new_v4(),
+            client_id: CounterpartyId::new(client),
+            symbol: Symbol::new("BTC/USD").unwrap(),
+            side: OrderSide::Buy,
+            quantity: Quantity::new(1.0).unwrap(),
+            state: RfqState::QuoteRequesting,
+            quote_count: 0,
+            best_quote_price: None,
+            selected_venue_id: None,
+            trade_id: None,
+            execution_price: None,
+            failure_reason: None,
+            created_at: base.add_secs(offset),
+            last_event_at: base.add_secs(offset),
+            last_sequence: 1,
+            aggregate_versions: Default::default(),
+        };
+        store.upsert(&summary).await.unwrap();
+    }
+
+    let filter = RfqListFilter {
+        client_id: Some(CounterpartyId::new("client-1")),
+        ..RfqListFilter::default()
+    };
+    let newest_first = store.list_after(None, 10, &filter).await.unwrap();
+    let page = store.list_offset(1, 2, &filter).await.unwrap();
+
+    let ids: Vec<RfqId> = page.iter().map(|s| s.rfq_id).collect();
+    let expected: Vec<RfqId> = newest_first
+        .iter()
+        .skip(1)
+        .take(2)
+        .map(|s| s.rfq_id)
+        .collect();
+    assert_eq!(ids, expected);
+    assert_eq!(store.count_matching(&filter).await.unwrap(), 3);
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+// ============================================================================
+// Webhook Tests
+// ============================================================================
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn webhook_subscription_and_delivery_roundtrip() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => return,
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let subscriptions = PostgresWebhookSubscriptionRepository::new(pool.clone());
+    let log = PostgresWebhookDeliveryLog::new(pool.clone());
+    // Stored at millisecond precision.
+    let now = Timestamp::from_millis(Timestamp::now().timestamp_millis()).unwrap();
+    let subscription = WebhookSubscription::from_parts(
+        WebhookSubscriptionId::new_v4(),
+        "https://risk.example.com/hooks".to_string(),
+        "secret".to_string(),
+        vec!["TradeExecuted".to_string()],
+        true,
+        3,
+        now,
+        now,
+    );
+    subscriptions.save(&subscription).await.unwrap();
+    assert_eq!(
+        subscriptions.find_by_id(subscription.id()).await.unwrap(),
+        Some(subscription.clone())
+    );
+    assert_eq!(subscriptions.find_active().await.unwrap().len(), 1);
+
+    let mut delivery = WebhookDelivery::pending(
+        subscription.id(),
+        crate::domain::value_objects::EventId::new_v4(),
+        "TradeExecuted",
+        serde_json::json!({"trade_id": "t-1"}),
+    );
+    delivery.created_at = now;
+    delivery.updated_at = now;
+    log.save(&delivery).await.unwrap();
+    delivery.status = WebhookDeliveryStatus::Failed;
+    delivery.attempts = 4;
+    delivery.response_status = Some(503);
+    delivery.last_error = Some("webhook returned status 503".to_string());
+    log.save(&delivery).await.unwrap();
+
+    assert_eq!(
+        log.find_by_id(delivery.id).await.unwrap(),
+        Some(delivery.clone())
+    );
+    assert_eq!(
+        log.list_after(subscription.id(), None, 10).await.unwrap(),
+        vec![delivery.clone()]
+    );
+    assert!(
+        log.list_after(subscription.id(), Some(&delivery.cursor()), 10)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    assert!(subscriptions.delete(subscription.id()).await.unwrap());
+    assert_eq!(log.find_by_id(delivery.id).await.unwrap(), None);
+
+    cleanup_tables(&pool).await.unwrap();
+}
+
+// ============================================================================
+// Error Mapping Tests
+// ============================================================================
+
+#[tokio::test]
+#[ignore = "requires PostgreSQL database"]
+async fn duplicate_key_insert_reports_unique_constraint() {
+    let pool = match create_test_pool().await {
+        Some(p) => p,
+        None => {
+            eprintln!("Skipping test: TEST_DATABASE_URL not set");
+            return;
+        }
+    };
+
+    setup_tables(&pool).await.unwrap();
+    cleanup_tables(&pool).await.unwrap();
+
+    let event_id = uuid::Uuid::new_v4().to_string();
+    let insert = || {
+        sqlx::query(
+            r#"
+            INSERT INTO domain_events (event_id, event_type, event_name, timestamp, payload, sequence)
+            VALUES ($1, 'rfq', 'RfqCreated', 0, '{}'::jsonb, 1)
+            "#,
+        )
+        .bind(&event_id)
+        .execute(&pool)
+    };
+
+    insert().await.unwrap();
+    let err = map_sqlx_error(insert().await.unwrap_err());
+
+    assert!(err.is_unique_violation());
+    assert!(matches!(
+        err,
+        RepositoryError::ConstraintViolation { ref constraint, .. }
+            if constraint == "domain_events_event_id_key"
+    ));
+    assert!(!err.is_transient());
+
+    cleanup_tables(&pool).await.unwrap();
+}
@@ -5,13 +5,18 @@
 //! This implementation uses PostgreSQL with optimistic locking via version fields.
 
 use crate::domain::entities::SettlementState;
-use crate::domain::entities::trade::Trade;
-use crate::domain::value_objects::{RfqId, TradeId, VenueId};
+use crate::domain::entities::allocation::{Allocation, AllocationStatus, TradeAllocation};
+use crate::domain::entities::trade::{FeeComponent, FeeKind, Trade};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{NegotiationId, NettingBatchId, RfqId, TradeId, VenueId};
+use crate::infrastructure::persistence::cursor::PageCursor;
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
 use crate::infrastructure::persistence::traits::{
-    RepositoryError, RepositoryResult, TradeRepository,
+    RepositoryError, RepositoryResult, TradeListFilter, TradeRepository,
 };
 use async_trait::async_trait;
 use sqlx::PgPool;
+use std::collections::HashMap;
 
 /// PostgreSQL implementation of [`TradeRepository`].
 ///
@@ -33,6 +38,72 @@ impl PostgresTradeRepository {
     pub fn pool(&self) -> &PgPool {
         &self.pool
     }
+
+    /// Converts trade rows into trades, attaching their fee components and
+    /// allocations.
+    async fn load_trades(&self, rows: Vec<TradeRow>) -> RepositoryResult<Vec<Trade>> {
+        if rows.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let ids: Vec<String> = rows.iter().map(|r| r.id.clone()).collect();
+        let fee_rows: Vec<TradeFeeRow> = sqlx::query_as(
+            r#"
+            SELECT trade_id, kind, amount, currency
+            FROM trade_fees WHERE trade_id = ANY($1)
+            ORDER BY trade_id, position
+            "#,
+        )
+        .bind(&ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        let allocation_rows: Vec<TradeAllocationRow> = sqlx::query_as(
+            r#"
+            SELECT trade_id, venue_id, quote_id, quantity, price, scheduled_at, status,
+                   failure_reason
+            FROM trade_allocations WHERE trade_id = ANY($1)
+            ORDER BY trade_id, position
+            "#,
+        )
+        .bind(&ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        let mut fees: HashMap<String, Vec<FeeComponent>> = HashMap::new();
+        for fee_row in fee_rows {
+            let trade_id = fee_row.trade_id.clone();
+            fees.entry(trade_id)
+                .or_default()
+                .push(fee_row.try_into_fee()?);
+        }
+
+        let mut allocations: HashMap<String, Vec<TradeAllocation>> = HashMap::new();
+        for allocation_row in allocation_rows {
+            let trade_id = allocation_row.trade_id.clone();
+            allocations
+                .entry(trade_id)
+                .or_default()
+                .push(allocation_row.try_into_allocation()?);
+        }
+
+        rows.into_iter()
+            .map(|row| {
+                let trade_fees = fees.remove(&row.id).unwrap_or_default();
+                let trade_allocations = allocations.remove(&row.id).unwrap_or_default();
+                let mut trade = row.try_into_trade()?;
+                for fee in trade_fees {
+                    trade.add_fee(fee);
+                }
+                for allocation in trade_allocations {
+                    trade.add_allocation(allocation);
+                }
+                Ok(trade)
+            })
+            .collect()
+    }
 }
 
 #[async_trait]
@@ -54,6 +125,33 @@ impl TradeRepository for PostgresTradeRepository {
         let taker_fee = trade.taker_fee();
         let maker_fee = trade.maker_fee();
         let net_fee = trade.net_fee();
+        let reference_price = trade.reference_price_at_execution().map(|p| p.get());
+        let settlement_attempts = i32::try_from(trade.settlement_attempts()).unwrap_or(i32::MAX);
+        let next_settlement_retry_at = trade
+            .next_settlement_retry_at()
+            .map(|t| t.timestamp_millis());
+        let price_bounds_check_json = trade
+            .price_bounds_check()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let collateral_decision_json = trade
+            .collateral_decision()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let settlement_plan_json = trade
+            .settlement_plan()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let settlement_window_json = trade
+            .settlement_window()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
 
         let result = sqlx::query(
             r#"
@@ -61,8 +159,14 @@ impl TradeRepository for PostgresTradeRepository {
                 id, rfq_id, quote_id, venue_id, price, quantity,
                 venue_execution_ref, settlement_state, settlement_tx_ref,
                 failure_reason, version, created_at, updated_at,
-                taker_fee, maker_fee, net_fee
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+                taker_fee, maker_fee, net_fee, reference_price_at_execution,
+                settlement_attempts, next_settlement_retry_at, price_bounds_check,
+                unwinds_trade_id, netting_batch_id, negotiation_id, collateral_decision,
+                settlement_plan, settlement_window, settlement_due_at
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13,
+                $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27
+            )
             ON CONFLICT (id) DO UPDATE SET
                 rfq_id = EXCLUDED.rfq_id,
                 quote_id = EXCLUDED.quote_id,
@@ -77,7 +181,18 @@ impl TradeRepository for PostgresTradeRepository {
                 updated_at = EXCLUDED.updated_at,
                 taker_fee = EXCLUDED.taker_fee,
                 maker_fee = EXCLUDED.maker_fee,
-                net_fee = EXCLUDED.net_fee
+                net_fee = EXCLUDED.net_fee,
+                reference_price_at_execution = EXCLUDED.reference_price_at_execution,
+                settlement_attempts = EXCLUDED.settlement_attempts,
+                next_settlement_retry_at = EXCLUDED.next_settlement_retry_at,
+                price_bounds_check = EXCLUDED.price_bounds_check,
+                unwinds_trade_id = EXCLUDED.unwinds_trade_id,
+                netting_batch_id = EXCLUDED.netting_batch_id,
+                negotiation_id = EXCLUDED.negotiation_id,
+                collateral_decision = EXCLUDED.collateral_decision,
+                settlement_plan = EXCLUDED.settlement_plan,
+                settlement_window = EXCLUDED.settlement_window,
+                settlement_due_at = EXCLUDED.settlement_due_at
             WHERE trades.version < EXCLUDED.version
             "#,
         )
@@ -97,16 +212,27 @@ impl TradeRepository for PostgresTradeRepository {
         .bind(taker_fee)
         .bind(maker_fee)
         .bind(net_fee)
-        .execute(&self.pool)
+        .bind(reference_price)
+        .bind(settlement_attempts)
+        .bind(next_settlement_retry_at)
+        .bind(&price_bounds_check_json)
+        .bind(trade.unwinds().map(|id| id.to_string()))
+        .bind(trade.netting_batch_id().map(|id| id.to_string()))
+        .bind(trade.negotiation_id().map(|id| id.to_string()))
+        .bind(&collateral_decision_json)
+        .bind(&settlement_plan_json)
+        .bind(&settlement_window_json)
+        .bind(trade.settlement_due_at().map(|t| t.timestamp_millis()))
+        .execute(&mut *tx)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
 
         if result.rows_affected() == 0 {
             let exists: Option<(i64,)> = sqlx::query_as("SELECT version FROM trades WHERE id = $1")
                 .bind(&id)
-                .fetch_optional(&self.pool)
+                .fetch_optional(&mut *tx)
                 .await
-                .map_err(|e| RepositoryError::query(e.to_string()))?;
+                .map_err(map_sqlx_error)?;
 
             if let Some((existing_version,)) = exists
                 && existing_version >= version
@@ -118,8 +244,66 @@ impl TradeRepository for PostgresTradeRepository {
                     existing_version as u64,
                 ));
             }
+            return Ok(());
         }
 
+        // Fees are replaced wholesale on every accepted write.
+        sqlx::query("DELETE FROM trade_fees WHERE trade_id = $1")
+            .bind(&id)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        for (position, fee) in trade.fees().iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO trade_fees (trade_id, position, kind, amount, currency)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+            )
+            .bind(&id)
+            .bind(i32::try_from(position).unwrap_or(i32::MAX))
+            .bind(fee.kind().to_string())
+            .bind(fee.amount())
+            .bind(fee.currency())
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+        }
+
+        // Allocations are replaced the same way.
+        sqlx::query("DELETE FROM trade_allocations WHERE trade_id = $1")
+            .bind(&id)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        for (position, recorded) in trade.allocations().iter().enumerate() {
+            let allocation = recorded.allocation();
+            sqlx::query(
+                r#"
+                INSERT INTO trade_allocations (
+                    trade_id, position, venue_id, quote_id, quantity, price,
+                    scheduled_at, status, failure_reason
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                "#,
+            )
+            .bind(&id)
+            .bind(i32::try_from(position).unwrap_or(i32::MAX))
+            .bind(allocation.venue_id().as_str())
+            .bind(allocation.quote_id().to_string())
+            .bind(allocation.allocated_quantity().get())
+            .bind(allocation.price().get())
+            .bind(recorded.scheduled_at().timestamp_millis())
+            .bind(recorded.status().to_string())
+            .bind(recorded.status().failure_reason())
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+
         Ok(())
     }
 
@@ -131,16 +315,20 @@ impl TradeRepository for PostgresTradeRepository {
             SELECT id, rfq_id, quote_id, venue_id, price, quantity,
                    venue_execution_ref, settlement_state, settlement_tx_ref,
                    failure_reason, version, created_at, updated_at,
-                   taker_fee, maker_fee, net_fee
+                   taker_fee, maker_fee, net_fee, reference_price_at_execution,
+                   settlement_attempts, next_settlement_retry_at, price_bounds_check,
+                   unwinds_trade_id, netting_batch_id, negotiation_id, collateral_decision,
+                   settlement_plan, settlement_window, settlement_due_at
             FROM trades WHERE id = $1
             "#,
         )
         .bind(&id_str)
         .fetch_optional(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
 
-        row.map(|r| r.try_into_trade()).transpose()
+        let trades = self.load_trades(row.into_iter().collect()).await?;
+        Ok(trades.into_iter().next())
     }
 
     async fn get_by_rfq(&self, rfq_id: RfqId) -> RepositoryResult<Option<Trade>> {
@@ -151,16 +339,20 @@ impl TradeRepository for PostgresTradeRepository {
             SELECT id, rfq_id, quote_id, venue_id, price, quantity,
                    venue_execution_ref, settlement_state, settlement_tx_ref,
                    failure_reason, version, created_at, updated_at,
-                   taker_fee, maker_fee, net_fee
+                   taker_fee, maker_fee, net_fee, reference_price_at_execution,
+                   settlement_attempts, next_settlement_retry_at, price_bounds_check,
+                   unwinds_trade_id, netting_batch_id, negotiation_id, collateral_decision,
+                   settlement_plan, settlement_window, settlement_due_at
             FROM trades WHERE rfq_id = $1
             "#,
         )
         .bind(&rfq_id_str)
         .fetch_optional(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
 
-        row.map(|r| r.try_into_trade()).transpose()
+        let trades = self.load_trades(row.into_iter().collect()).await?;
+        Ok(trades.into_iter().next())
     }
 
     async fn find_pending_settlement(&self) -> RepositoryResult<Vec<Trade>> {
@@ -171,19 +363,19 @@ impl TradeRepository for PostgresTradeRepository {
             SELECT id, rfq_id, quote_id, venue_id, price, quantity,
                    venue_execution_ref, settlement_state, settlement_tx_ref,
                    failure_reason, version, created_at, updated_at,
-                   taker_fee, maker_fee, net_fee
+                   taker_fee, maker_fee, net_fee, reference_price_at_execution,
+                   settlement_attempts, next_settlement_retry_at, price_bounds_check,
+                   unwinds_trade_id, netting_batch_id, negotiation_id, collateral_decision,
+                   settlement_plan, settlement_window, settlement_due_at
             FROM trades WHERE settlement_state = $1
             "#,
         )
         .bind(&state)
         .fetch_all(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
 
-        Ok(rows
-            .into_iter()
-            .map(|r| r.try_into_trade())
-            .collect::<RepositoryResult<Vec<_>>>()?)
+        self.load_trades(rows).await
     }
 
     async fn find_by_venue(&self, venue_id: &VenueId) -> RepositoryResult<Vec<Trade>> {
@@ -194,19 +386,55 @@ impl TradeRepository for PostgresTradeRepository {
             SELECT id, rfq_id, quote_id, venue_id, price, quantity,
                    venue_execution_ref, settlement_state, settlement_tx_ref,
                    failure_reason, version, created_at, updated_at,
-                   taker_fee, maker_fee, net_fee
+                   taker_fee, maker_fee, net_fee, reference_price_at_execution,
+                   settlement_attempts, next_settlement_retry_at, price_bounds_check,
+                   unwinds_trade_id, netting_batch_id, negotiation_id, collateral_decision,
+                   settlement_plan, settlement_window, settlement_due_at
             FROM trades WHERE venue_id = $1
             "#,
         )
         .bind(venue_id_str)
         .fetch_all(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
+
+        self.load_trades(rows).await
+    }
+
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &TradeListFilter,
+    ) -> RepositoryResult<Vec<Trade>> {
+        // Keyset pagination served by idx_trades_created_at_id.
+        let rows: Vec<TradeRow> = sqlx::query_as(
+            r#"
+            SELECT id, rfq_id, quote_id, venue_id, price, quantity,
+                   venue_execution_ref, settlement_state, settlement_tx_ref,
+                   failure_reason, version, created_at, updated_at,
+                   taker_fee, maker_fee, net_fee, reference_price_at_execution,
+                   settlement_attempts, next_settlement_retry_at, price_bounds_check,
+                   unwinds_trade_id, netting_batch_id, negotiation_id, collateral_decision,
+                   settlement_plan, settlement_window, settlement_due_at
+            FROM trades
+            WHERE ($1::BIGINT IS NULL OR (created_at, id) < ($1, $2))
+              AND ($3::TEXT IS NULL OR rfq_id = $3)
+              AND ($4::TEXT IS NULL OR venue_id = $4)
+            ORDER BY created_at DESC, id DESC
+            LIMIT $5
+            "#,
+        )
+        .bind(cursor.map(PageCursor::created_at_millis))
+        .bind(cursor.map(PageCursor::id))
+        .bind(filter.rfq_id.map(|id| id.to_string()))
+        .bind(filter.venue_id.as_ref().map(VenueId::as_str))
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
 
-        Ok(rows
-            .into_iter()
-            .map(|r| r.try_into_trade())
-            .collect::<RepositoryResult<Vec<_>>>()?)
+        self.load_trades(rows).await
     }
 
     async fn find_settled(&self) -> RepositoryResult<Vec<Trade>> {
@@ -217,19 +445,19 @@ impl TradeRepository for PostgresTradeRepository {
             SELECT id, rfq_id, quote_id, venue_id, price, quantity,
                    venue_execution_ref, settlement_state, settlement_tx_ref,
                    failure_reason, version, created_at, updated_at,
-                   taker_fee, maker_fee, net_fee
+                   taker_fee, maker_fee, net_fee, reference_price_at_execution,
+                   settlement_attempts, next_settlement_retry_at, price_bounds_check,
+                   unwinds_trade_id, netting_batch_id, negotiation_id, collateral_decision,
+                   settlement_plan, settlement_window, settlement_due_at
             FROM trades WHERE settlement_state = $1
             "#,
         )
         .bind(&state)
         .fetch_all(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
 
-        Ok(rows
-            .into_iter()
-            .map(|r| r.try_into_trade())
-            .collect::<RepositoryResult<Vec<_>>>()?)
+        self.load_trades(rows).await
     }
 
     async fn find_failed(&self) -> RepositoryResult<Vec<Trade>> {
@@ -240,16 +468,53 @@ impl TradeRepository for PostgresTradeRepository {
             SELECT id, rfq_id, quote_id, venue_id, price, quantity,
                    venue_execution_ref, settlement_state, settlement_tx_ref,
                    failure_reason, version, created_at, updated_at,
-                   taker_fee, maker_fee, net_fee
+                   taker_fee, maker_fee, net_fee, reference_price_at_execution,
+                   settlement_attempts, next_settlement_retry_at, price_bounds_check,
+                   unwinds_trade_id, netting_batch_id, negotiation_id, collateral_decision,
+                   settlement_plan, settlement_window, settlement_due_at
             FROM trades WHERE settlement_state = $1
             "#,
         )
         .bind(&state)
         .fetch_all(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
+
+        self.load_trades(rows).await
+    }
+
+    async fn find_due_for_settlement_retry(
+        &self,
+        now: Timestamp,
+        limit: usize,
+    ) -> RepositoryResult<Vec<Trade>> {
+        let state = SettlementState::Failed.to_string();
+
+        // Served by idx_trades_settlement_retry.
+        let rows: Vec<TradeRow> = sqlx::query_as(
+            r#"
+            SELECT id, rfq_id, quote_id, venue_id, price, quantity,
+                   venue_execution_ref, settlement_state, settlement_tx_ref,
+                   failure_reason, version, created_at, updated_at,
+                   taker_fee, maker_fee, net_fee, reference_price_at_execution,
+                   settlement_attempts, next_settlement_retry_at, price_bounds_check,
+                   unwinds_trade_id, netting_batch_id, negotiation_id, collateral_decision,
+                   settlement_plan, settlement_window, settlement_due_at
+            FROM trades
+            WHERE settlement_state = $1
+              AND (next_settlement_retry_at IS NULL OR next_settlement_retry_at <= $2)
+            ORDER BY next_settlement_retry_at ASC NULLS FIRST
+            LIMIT $3
+            "#,
+        )
+        .bind(&state)
+        .bind(now.timestamp_millis())
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
 
-        rows.into_iter().map(|r| r.try_into_trade()).collect()
+        self.load_trades(rows).await
     }
 
     async fn delete(&self, id: TradeId) -> RepositoryResult<bool> {
@@ -259,7 +524,7 @@ impl TradeRepository for PostgresTradeRepository {
             .bind(&id_str)
             .execute(&self.pool)
             .await
-            .map_err(|e| RepositoryError::query(e.to_string()))?;
+            .map_err(map_sqlx_error)?;
 
         Ok(result.rows_affected() > 0)
     }
@@ -268,7 +533,7 @@ impl TradeRepository for PostgresTradeRepository {
         let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM trades")
             .fetch_one(&self.pool)
             .await
-            .map_err(|e| RepositoryError::query(e.to_string()))?;
+            .map_err(map_sqlx_error)?;
 
         Ok(count as u64)
     }
@@ -281,7 +546,7 @@ impl TradeRepository for PostgresTradeRepository {
                 .bind(&state)
                 .fetch_one(&self.pool)
                 .await
-                .map_err(|e| RepositoryError::query(e.to_string()))?;
+                .map_err(map_sqlx_error)?;
 
         Ok(count as u64)
     }
@@ -306,12 +571,22 @@ struct TradeRow {
     taker_fee: Option<rust_decimal::Decimal>,
     maker_fee: Option<rust_decimal::Decimal>,
     net_fee: Option<rust_decimal::Decimal>,
+    reference_price_at_execution: Option<rust_decimal::Decimal>,
+    settlement_attempts: i32,
+    next_settlement_retry_at: Option<i64>,
+    price_bounds_check: Option<serde_json::Value>,
+    unwinds_trade_id: Option<String>,
+    netting_batch_id: Option<String>,
+    negotiation_id: Option<String>,
+    collateral_decision: Option<serde_json::Value>,
+    settlement_plan: Option<serde_json::Value>,
+    settlement_window: Option<serde_json::Value>,
+    settlement_due_at: Option<i64>,
 }
 
 impl TradeRow {
     /// Converts the row into a Trade entity.
     fn try_into_trade(self) -> RepositoryResult<Trade> {
-        use crate::domain::value_objects::timestamp::Timestamp;
         use crate::domain::value_objects::{Price, Quantity, QuoteId};
         use uuid::Uuid;
 
@@ -338,8 +613,18 @@ impl TradeRow {
         let updated_at = Timestamp::from_millis(self.updated_at).ok_or_else(|| {
             RepositoryError::serialization("invalid updated_at timestamp".to_string())
         })?;
-
-        Ok(Trade::from_parts(
+        let next_settlement_retry_at = self
+            .next_settlement_retry_at
+            .map(|millis| {
+                Timestamp::from_millis(millis).ok_or_else(|| {
+                    RepositoryError::serialization(
+                        "invalid next_settlement_retry_at timestamp".to_string(),
+                    )
+                })
+            })
+            .transpose()?;
+
+        let mut trade = Trade::from_parts(
             id,
             rfq_id,
             quote_id,
@@ -356,6 +641,124 @@ impl TradeRow {
             self.taker_fee,
             self.maker_fee,
             self.net_fee,
+        )
+        .with_settlement_retry(
+            u32::try_from(self.settlement_attempts).unwrap_or_default(),
+            next_settlement_retry_at,
+        );
+        if let Some(reference) = self.reference_price_at_execution {
+            let reference = Price::from_decimal(reference)
+                .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+            trade.set_reference_price_at_execution(reference);
+        }
+        if let Some(check) = self.price_bounds_check {
+            let check = serde_json::from_value(check)
+                .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+            trade.set_price_bounds_check(check);
+        }
+        if let Some(unwinds) = self.unwinds_trade_id {
+            let unwinds = Uuid::parse_str(&unwinds)
+                .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+            trade.set_unwinds(TradeId::new(unwinds));
+        }
+        if let Some(batch_id) = self.netting_batch_id {
+            let batch_id = Uuid::parse_str(&batch_id)
+                .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+            trade.set_netting_batch_id(NettingBatchId::new(batch_id));
+        }
+        if let Some(negotiation_id) = self.negotiation_id {
+            let negotiation_id = Uuid::parse_str(&negotiation_id)
+                .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+            trade.set_negotiation_id(NegotiationId::new(negotiation_id));
+        }
+        if let Some(decision) = self.collateral_decision {
+            let decision = serde_json::from_value(decision)
+                .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+            trade.set_collateral_decision(decision);
+        }
+        if let Some(plan) = self.settlement_plan {
+            let plan = serde_json::from_value(plan)
+                .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+            trade.set_settlement_plan(plan);
+        }
+        if let (Some(window), Some(due_at)) = (self.settlement_window, self.settlement_due_at) {
+            let window = serde_json::from_value(window)
+                .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+            let due_at = Timestamp::from_millis(due_at).ok_or_else(|| {
+                RepositoryError::serialization("invalid settlement_due_at timestamp".to_string())
+            })?;
+            trade.schedule_settlement(window, due_at);
+        }
+
+        Ok(trade)
+    }
+}
+
+/// Row type for trade fee queries.
+#[derive(Debug, sqlx::FromRow)]
+struct TradeFeeRow {
+    trade_id: String,
+    kind: String,
+    amount: rust_decimal::Decimal,
+    currency: String,
+}
+
+impl TradeFeeRow {
+    /// Converts the row into a fee component.
+    fn try_into_fee(self) -> RepositoryResult<FeeComponent> {
+        let kind: FeeKind = serde_json::from_str(&format!("\"{}\"", self.kind))
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        Ok(FeeComponent::new(kind, self.amount, self.currency))
+    }
+}
+
+/// Row type for trade allocation queries.
+#[derive(Debug, sqlx::FromRow)]
+struct TradeAllocationRow {
+    trade_id: String,
+    venue_id: String,
+    quote_id: String,
+    quantity: rust_decimal::Decimal,
+    price: rust_decimal::Decimal,
+    scheduled_at: i64,
+    status: String,
+    failure_reason: Option<String>,
+}
+
+impl TradeAllocationRow {
+    /// Converts the row into a trade allocation.
+    fn try_into_allocation(self) -> RepositoryResult<TradeAllocation> {
+        use crate::domain::value_objects::{Price, Quantity, QuoteId};
+        use uuid::Uuid;
+
+        let quote_id = Uuid::parse_str(&self.quote_id)
+            .map(QuoteId::new)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let quantity = Quantity::from_decimal(self.quantity)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let price = Price::from_decimal(self.price)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let scheduled_at = Timestamp::from_millis(self.scheduled_at).ok_or_else(|| {
+            RepositoryError::serialization("invalid scheduled_at timestamp".to_string())
+        })?;
+        let status = match (self.status.as_str(), self.failure_reason) {
+            ("PENDING", _) => AllocationStatus::Pending,
+            ("EXECUTING", _) => AllocationStatus::Executing,
+            ("FILLED", _) => AllocationStatus::Filled,
+            ("FAILED", reason) => AllocationStatus::Failed {
+                reason: reason.unwrap_or_default(),
+            },
+            (other, _) => {
+                return Err(RepositoryError::serialization(format!(
+                    "unknown allocation status: {other}"
+                )));
+            }
+        };
+
+        Ok(TradeAllocation::from_parts(
+            Allocation::from_parts(VenueId::new(&self.venue_id), quote_id, quantity, price),
+            scheduled_at,
+            status,
         ))
     }
 }
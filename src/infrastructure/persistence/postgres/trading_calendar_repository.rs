@@ -0,0 +1,128 @@
+//! # PostgreSQL Trading Calendar Repository
+//!
+//! PostgreSQL implementation of [`TradingCalendarRepository`] using sqlx.
+
+use crate::domain::value_objects::TradingCalendar;
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
+use crate::infrastructure::persistence::traits::{
+    RepositoryError, RepositoryResult, TradingCalendarRepository,
+};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use chrono_tz::Tz;
+use sqlx::PgPool;
+
+/// PostgreSQL implementation of [`TradingCalendarRepository`].
+///
+/// Uses connection pooling via `sqlx::PgPool`.
+#[derive(Debug, Clone)]
+pub struct PostgresTradingCalendarRepository {
+    pool: PgPool,
+}
+
+impl PostgresTradingCalendarRepository {
+    /// Creates a new PostgreSQL trading calendar repository.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl TradingCalendarRepository for PostgresTradingCalendarRepository {
+    async fn save(&self, calendar: &TradingCalendar) -> RepositoryResult<()> {
+        let holidays: Vec<NaiveDate> = calendar.holidays().iter().copied().collect();
+
+        sqlx::query(
+            r#"
+            INSERT INTO trading_calendars (name, timezone, weekends_closed, holidays)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (name) DO UPDATE SET
+                timezone = EXCLUDED.timezone,
+                weekends_closed = EXCLUDED.weekends_closed,
+                holidays = EXCLUDED.holidays
+            "#,
+        )
+        .bind(calendar.name())
+        .bind(calendar.timezone().name())
+        .bind(calendar.weekends_closed())
+        .bind(&holidays)
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn find(&self, name: &str) -> RepositoryResult<Option<TradingCalendar>> {
+        let row: Option<TradingCalendarRow> = sqlx::query_as(
+            r#"
+            SELECT name, timezone, weekends_closed, holidays
+            FROM trading_calendars WHERE name = $1
+            "#,
+        )
+        .bind(name.to_uppercase())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(TradingCalendarRow::try_into_calendar).transpose()
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<TradingCalendar>> {
+        let rows: Vec<TradingCalendarRow> = sqlx::query_as(
+            r#"
+            SELECT name, timezone, weekends_closed, holidays
+            FROM trading_calendars
+            ORDER BY name ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter()
+            .map(TradingCalendarRow::try_into_calendar)
+            .collect()
+    }
+
+    async fn delete(&self, name: &str) -> RepositoryResult<bool> {
+        let result = sqlx::query("DELETE FROM trading_calendars WHERE name = $1")
+            .bind(name.to_uppercase())
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// Row type for trading calendar queries.
+#[derive(Debug, sqlx::FromRow)]
+struct TradingCalendarRow {
+    name: String,
+    timezone: String,
+    weekends_closed: bool,
+    holidays: Vec<NaiveDate>,
+}
+
+impl TradingCalendarRow {
+    /// Converts the row into a [`TradingCalendar`].
+    fn try_into_calendar(self) -> RepositoryResult<TradingCalendar> {
+        let timezone: Tz = self.timezone.parse().map_err(|_| {
+            RepositoryError::serialization(format!("invalid timezone: {}", self.timezone))
+        })?;
+
+        Ok(
+            TradingCalendar::new(self.name, timezone, self.weekends_closed)
+                .map_err(|e| RepositoryError::serialization(e.to_string()))?
+                .with_holidays(self.holidays),
+        )
+    }
+}
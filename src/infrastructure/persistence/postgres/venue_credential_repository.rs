@@ -0,0 +1,257 @@
+//! # PostgreSQL Venue Credential Repository
+//!
+//! PostgreSQL implementation of [`VenueCredentialRepository`] using sqlx.
+//!
+//! # Encryption
+//!
+//! The API key and secret are always encrypted with the field encryption
+//! keys (see
+//! [`field_encryption`](crate::infrastructure::persistence::field_encryption)),
+//! so the repository cannot be created without them. Unlike counterparty
+//! columns, values in clear are refused on read.
+//! [`PostgresVenueCredentialRepository::rotate_keys`] moves rows to the
+//! active key after a rotation.
+
+use crate::domain::entities::venue_credential_set::{VenueCredentialSet, VenueCredentials};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{CounterpartyId, VenueId};
+use crate::infrastructure::persistence::field_encryption::{EncryptedString, KeyProvider};
+use crate::infrastructure::persistence::postgres::{KeyRotationReport, map_sqlx_error};
+use crate::infrastructure::persistence::traits::{
+    RepositoryError, RepositoryResult, VenueCredentialRepository,
+};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::sync::Arc;
+
+/// Columns selected for a [`VenueCredentialRow`].
+const VENUE_CREDENTIAL_COLUMNS: &str =
+    "counterparty_id, venue_id, api_key, api_secret, created_at, updated_at";
+
+/// PostgreSQL implementation of [`VenueCredentialRepository`].
+///
+/// Uses connection pooling via `sqlx::PgPool`.
+#[derive(Debug, Clone)]
+pub struct PostgresVenueCredentialRepository {
+    pool: PgPool,
+    keys: Arc<dyn KeyProvider>,
+}
+
+impl PostgresVenueCredentialRepository {
+    /// Creates a new PostgreSQL venue credential repository encrypting with
+    /// keys from `keys`.
+    #[must_use]
+    pub fn new(pool: PgPool, keys: Arc<dyn KeyProvider>) -> Self {
+        Self { pool, keys }
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    /// Re-encrypts, in batches of `batch_size` rows, every row under a key
+    /// other than the active one.
+    ///
+    /// Safe to interrupt and re-run: each row is rewritten on its own.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a row cannot be read, decrypted or written.
+    pub async fn rotate_keys(&self, batch_size: usize) -> RepositoryResult<KeyRotationReport> {
+        let active = self
+            .keys
+            .active_key()
+            .map_err(|e| RepositoryError::internal(e.to_string()))?;
+        let limit = i64::try_from(batch_size.max(1)).unwrap_or(i64::MAX);
+
+        let mut report = KeyRotationReport::default();
+        let mut after = (String::new(), String::new());
+        loop {
+            let rows: Vec<VenueCredentialRow> = sqlx::query_as(&format!(
+                "SELECT {VENUE_CREDENTIAL_COLUMNS} FROM venue_credentials \
+                 WHERE (counterparty_id, venue_id) > ($1, $2) \
+                 ORDER BY counterparty_id, venue_id LIMIT $3"
+            ))
+            .bind(&after.0)
+            .bind(&after.1)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+            let Some(last) = rows.last() else {
+                break;
+            };
+            after = (last.counterparty_id.clone(), last.venue_id.clone());
+
+            for row in rows {
+                report.scanned += 1;
+                if row.is_encrypted_under(active.id()) {
+                    continue;
+                }
+                let credentials = row.try_into_credentials(self.keys.as_ref())?;
+                self.save(&credentials).await?;
+                report.rotated += 1;
+            }
+            tracing::info!(
+                scanned = report.scanned,
+                rotated = report.rotated,
+                "Venue credential key rotation progress"
+            );
+        }
+        Ok(report)
+    }
+
+    fn seal(&self, value: &str) -> RepositoryResult<String> {
+        EncryptedString::encrypt(value, self.keys.as_ref())
+            .map(EncryptedString::into_inner)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl VenueCredentialRepository for PostgresVenueCredentialRepository {
+    async fn save(&self, credentials: &VenueCredentialSet) -> RepositoryResult<()> {
+        let secrets = credentials.credentials();
+        let api_secret = secrets
+            .api_secret()
+            .map(|secret| self.seal(secret))
+            .transpose()?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO venue_credentials (
+                counterparty_id, venue_id, api_key, api_secret, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (counterparty_id, venue_id) DO UPDATE SET
+                api_key = EXCLUDED.api_key,
+                api_secret = EXCLUDED.api_secret,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(credentials.counterparty_id().as_str())
+        .bind(credentials.venue_id().as_str())
+        .bind(self.seal(secrets.api_key())?)
+        .bind(api_secret)
+        .bind(credentials.created_at().timestamp_millis())
+        .bind(credentials.updated_at().timestamp_millis())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        counterparty_id: &CounterpartyId,
+        venue_id: &VenueId,
+    ) -> RepositoryResult<Option<VenueCredentialSet>> {
+        let row: Option<VenueCredentialRow> = sqlx::query_as(&format!(
+            "SELECT {VENUE_CREDENTIAL_COLUMNS} FROM venue_credentials \
+             WHERE counterparty_id = $1 AND venue_id = $2"
+        ))
+        .bind(counterparty_id.as_str())
+        .bind(venue_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(|r| r.try_into_credentials(self.keys.as_ref()))
+            .transpose()
+    }
+
+    async fn find_by_counterparty(
+        &self,
+        counterparty_id: &CounterpartyId,
+    ) -> RepositoryResult<Vec<VenueCredentialSet>> {
+        let rows: Vec<VenueCredentialRow> = sqlx::query_as(&format!(
+            "SELECT {VENUE_CREDENTIAL_COLUMNS} FROM venue_credentials \
+             WHERE counterparty_id = $1 ORDER BY venue_id"
+        ))
+        .bind(counterparty_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter()
+            .map(|r| r.try_into_credentials(self.keys.as_ref()))
+            .collect()
+    }
+
+    async fn delete(
+        &self,
+        counterparty_id: &CounterpartyId,
+        venue_id: &VenueId,
+    ) -> RepositoryResult<bool> {
+        let result = sqlx::query(
+            "DELETE FROM venue_credentials WHERE counterparty_id = $1 AND venue_id = $2",
+        )
+        .bind(counterparty_id.as_str())
+        .bind(venue_id.as_str())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// Row type for venue credential queries.
+#[derive(Debug, sqlx::FromRow)]
+struct VenueCredentialRow {
+    counterparty_id: String,
+    venue_id: String,
+    api_key: String,
+    api_secret: Option<String>,
+    created_at: i64,
+    updated_at: i64,
+}
+
+impl VenueCredentialRow {
+    /// Returns true if every secret column is encrypted under `key_id`.
+    fn is_encrypted_under(&self, key_id: &str) -> bool {
+        std::iter::once(&self.api_key)
+            .chain(self.api_secret.as_ref())
+            .all(|stored| EncryptedString::from_stored(stored.as_str()).key_id() == Some(key_id))
+    }
+
+    /// Decrypts the row into a [`VenueCredentialSet`].
+    fn try_into_credentials(self, keys: &dyn KeyProvider) -> RepositoryResult<VenueCredentialSet> {
+        let mut credentials = VenueCredentials::new(open(self.api_key, keys)?)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        if let Some(api_secret) = self.api_secret {
+            credentials = credentials
+                .with_api_secret(open(api_secret, keys)?)
+                .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        }
+
+        Ok(VenueCredentialSet::from_parts(
+            CounterpartyId::new(self.counterparty_id),
+            VenueId::new(self.venue_id),
+            credentials,
+            millis_to_timestamp(self.created_at)?,
+            millis_to_timestamp(self.updated_at)?,
+        ))
+    }
+}
+
+/// Decrypts a stored secret, refusing values in clear.
+fn open(stored: String, keys: &dyn KeyProvider) -> RepositoryResult<String> {
+    let value = EncryptedString::from_stored(stored);
+    if !value.is_encrypted() {
+        return Err(RepositoryError::serialization(
+            "venue credential column is not encrypted",
+        ));
+    }
+    value
+        .decrypt(keys)
+        .map_err(|e| RepositoryError::serialization(e.to_string()))
+}
+
+fn millis_to_timestamp(millis: i64) -> RepositoryResult<Timestamp> {
+    Timestamp::from_millis(millis)
+        .ok_or_else(|| RepositoryError::serialization(format!("invalid timestamp: {millis}")))
+}
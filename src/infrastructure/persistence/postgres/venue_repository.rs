@@ -3,7 +3,10 @@
 //! PostgreSQL implementation of [`VenueRepository`] using sqlx.
 
 use crate::domain::value_objects::VenueId;
-use crate::infrastructure::persistence::traits::{RepositoryResult, VenueRepository};
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
+use crate::infrastructure::persistence::traits::{
+    RepositoryError, RepositoryResult, VenueRepository,
+};
 use crate::infrastructure::venues::registry::VenueConfig;
 use async_trait::async_trait;
 use sqlx::PgPool;
@@ -33,8 +36,6 @@ impl PostgresVenueRepository {
 #[async_trait]
 impl VenueRepository for PostgresVenueRepository {
     async fn save(&self, config: &VenueConfig) -> RepositoryResult<()> {
-        use crate::infrastructure::persistence::traits::RepositoryError;
-
         let venue_id = config.venue_id().as_str();
         let enabled = config.is_enabled();
         let priority = config.priority() as i32;
@@ -43,10 +44,15 @@ impl VenueRepository for PostgresVenueRepository {
 
         sqlx::query(
             r#"
-            INSERT INTO venues (venue_id, enabled, priority, supported_instruments)
-            VALUES ($1, $2, $3, $4)
+            INSERT INTO venues (venue_id, enabled, mode, priority, supported_instruments)
+            VALUES ($1, $2, CASE WHEN $2 THEN 'LIVE' ELSE 'DISABLED' END, $3, $4)
             ON CONFLICT (venue_id) DO UPDATE SET
                 enabled = EXCLUDED.enabled,
+                mode = CASE
+                    WHEN NOT EXCLUDED.enabled THEN 'DISABLED'
+                    WHEN venues.mode = 'DISABLED' THEN 'LIVE'
+                    ELSE venues.mode
+                END,
                 priority = EXCLUDED.priority,
                 supported_instruments = EXCLUDED.supported_instruments
             "#,
@@ -57,14 +63,12 @@ impl VenueRepository for PostgresVenueRepository {
         .bind(&supported_instruments)
         .execute(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
 
         Ok(())
     }
 
     async fn get(&self, id: &VenueId) -> RepositoryResult<Option<VenueConfig>> {
-        use crate::infrastructure::persistence::traits::RepositoryError;
-
         let venue_id = id.as_str();
 
         let row: Option<VenueRow> = sqlx::query_as(
@@ -76,14 +80,12 @@ impl VenueRepository for PostgresVenueRepository {
         .bind(venue_id)
         .fetch_optional(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
 
         row.map(|r| r.try_into_config()).transpose()
     }
 
     async fn get_all(&self) -> RepositoryResult<Vec<VenueConfig>> {
-        use crate::infrastructure::persistence::traits::RepositoryError;
-
         let rows: Vec<VenueRow> = sqlx::query_as(
             r#"
             SELECT venue_id, enabled, priority, supported_instruments
@@ -92,14 +94,12 @@ impl VenueRepository for PostgresVenueRepository {
         )
         .fetch_all(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
 
         rows.into_iter().map(|r| r.try_into_config()).collect()
     }
 
     async fn find_enabled(&self) -> RepositoryResult<Vec<VenueConfig>> {
-        use crate::infrastructure::persistence::traits::RepositoryError;
-
         let rows: Vec<VenueRow> = sqlx::query_as(
             r#"
             SELECT venue_id, enabled, priority, supported_instruments
@@ -108,32 +108,28 @@ impl VenueRepository for PostgresVenueRepository {
         )
         .fetch_all(&self.pool)
         .await
-        .map_err(|e| RepositoryError::query(e.to_string()))?;
+        .map_err(map_sqlx_error)?;
 
         rows.into_iter().map(|r| r.try_into_config()).collect()
     }
 
     async fn delete(&self, id: &VenueId) -> RepositoryResult<bool> {
-        use crate::infrastructure::persistence::traits::RepositoryError;
-
         let venue_id = id.as_str();
 
         let result = sqlx::query("DELETE FROM venues WHERE venue_id = $1")
             .bind(venue_id)
             .execute(&self.pool)
             .await
-            .map_err(|e| RepositoryError::query(e.to_string()))?;
+            .map_err(map_sqlx_error)?;
 
         Ok(result.rows_affected() > 0)
     }
 
     async fn count(&self) -> RepositoryResult<u64> {
-        use crate::infrastructure::persistence::traits::RepositoryError;
-
         let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM venues")
             .fetch_one(&self.pool)
             .await
-            .map_err(|e| RepositoryError::query(e.to_string()))?;
+            .map_err(map_sqlx_error)?;
 
         Ok(count as u64)
     }
@@ -152,7 +148,6 @@ impl VenueRow {
     /// Converts the row into a VenueConfig.
     fn try_into_config(self) -> RepositoryResult<VenueConfig> {
         use crate::domain::value_objects::Instrument;
-        use crate::infrastructure::persistence::traits::RepositoryError;
 
         let venue_id = VenueId::new(&self.venue_id);
         let supported_instruments: Vec<Instrument> =
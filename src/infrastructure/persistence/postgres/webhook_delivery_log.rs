@@ -0,0 +1,182 @@
+//! # PostgreSQL Webhook Delivery Log
+//!
+//! PostgreSQL implementation of [`WebhookDeliveryLog`] using sqlx.
+
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{EventId, WebhookDeliveryId, WebhookSubscriptionId};
+use crate::infrastructure::persistence::cursor::PageCursor;
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
+use crate::infrastructure::persistence::traits::{RepositoryError, RepositoryResult};
+use crate::infrastructure::persistence::webhook_delivery_log::{
+    WebhookDelivery, WebhookDeliveryLog, WebhookDeliveryStatus,
+};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// PostgreSQL implementation of [`WebhookDeliveryLog`].
+///
+/// Uses connection pooling via `sqlx::PgPool`.
+#[derive(Debug, Clone)]
+pub struct PostgresWebhookDeliveryLog {
+    pool: PgPool,
+}
+
+impl PostgresWebhookDeliveryLog {
+    /// Creates a new PostgreSQL webhook delivery log.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryLog for PostgresWebhookDeliveryLog {
+    async fn save(&self, delivery: &WebhookDelivery) -> RepositoryResult<()> {
+        let attempts = i32::try_from(delivery.attempts)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO webhook_deliveries (
+                id, subscription_id, event_id, event_name, payload, status,
+                attempts, response_status, last_error, redelivery_of,
+                created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (id) DO UPDATE SET
+                status = EXCLUDED.status,
+                attempts = EXCLUDED.attempts,
+                response_status = EXCLUDED.response_status,
+                last_error = EXCLUDED.last_error,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(delivery.id.get())
+        .bind(delivery.subscription_id.get())
+        .bind(delivery.event_id.get())
+        .bind(&delivery.event_name)
+        .bind(&delivery.payload)
+        .bind(delivery.status.to_string())
+        .bind(attempts)
+        .bind(delivery.response_status.map(i32::from))
+        .bind(delivery.last_error.as_deref())
+        .bind(delivery.redelivery_of.map(WebhookDeliveryId::get))
+        .bind(delivery.created_at.timestamp_millis())
+        .bind(delivery.updated_at.timestamp_millis())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: WebhookDeliveryId) -> RepositoryResult<Option<WebhookDelivery>> {
+        let row: Option<WebhookDeliveryRow> = sqlx::query_as(
+            r#"
+            SELECT id, subscription_id, event_id, event_name, payload, status,
+                   attempts, response_status, last_error, redelivery_of,
+                   created_at, updated_at
+            FROM webhook_deliveries WHERE id = $1
+            "#,
+        )
+        .bind(id.get())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(WebhookDeliveryRow::try_into_delivery).transpose()
+    }
+
+    async fn list_after(
+        &self,
+        subscription_id: WebhookSubscriptionId,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+    ) -> RepositoryResult<Vec<WebhookDelivery>> {
+        let rows: Vec<WebhookDeliveryRow> = sqlx::query_as(
+            r#"
+            SELECT id, subscription_id, event_id, event_name, payload, status,
+                   attempts, response_status, last_error, redelivery_of,
+                   created_at, updated_at
+            FROM webhook_deliveries
+            WHERE subscription_id = $1
+              AND ($2::BIGINT IS NULL OR (created_at, id) < ($2, $3::UUID))
+            ORDER BY created_at DESC, id DESC
+            LIMIT $4
+            "#,
+        )
+        .bind(subscription_id.get())
+        .bind(cursor.map(PageCursor::created_at_millis))
+        .bind(cursor.map(PageCursor::id))
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter()
+            .map(WebhookDeliveryRow::try_into_delivery)
+            .collect()
+    }
+}
+
+/// Row type for webhook delivery queries.
+#[derive(Debug, sqlx::FromRow)]
+struct WebhookDeliveryRow {
+    id: Uuid,
+    subscription_id: Uuid,
+    event_id: Uuid,
+    event_name: String,
+    payload: serde_json::Value,
+    status: String,
+    attempts: i32,
+    response_status: Option<i32>,
+    last_error: Option<String>,
+    redelivery_of: Option<Uuid>,
+    created_at: i64,
+    updated_at: i64,
+}
+
+impl WebhookDeliveryRow {
+    /// Converts the row into a [`WebhookDelivery`].
+    fn try_into_delivery(self) -> RepositoryResult<WebhookDelivery> {
+        let status: WebhookDeliveryStatus = self
+            .status
+            .parse()
+            .map_err(RepositoryError::serialization)?;
+        let attempts = u32::try_from(self.attempts)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let response_status = self
+            .response_status
+            .map(u16::try_from)
+            .transpose()
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let created_at = Timestamp::from_millis(self.created_at).ok_or_else(|| {
+            RepositoryError::serialization("invalid created_at timestamp".to_string())
+        })?;
+        let updated_at = Timestamp::from_millis(self.updated_at).ok_or_else(|| {
+            RepositoryError::serialization("invalid updated_at timestamp".to_string())
+        })?;
+
+        Ok(WebhookDelivery {
+            id: WebhookDeliveryId::new(self.id),
+            subscription_id: WebhookSubscriptionId::new(self.subscription_id),
+            event_id: EventId::new(self.event_id),
+            event_name: self.event_name,
+            payload: self.payload,
+            status,
+            attempts,
+            response_status,
+            last_error: self.last_error,
+            redelivery_of: self.redelivery_of.map(WebhookDeliveryId::new),
+            created_at,
+            updated_at,
+        })
+    }
+}
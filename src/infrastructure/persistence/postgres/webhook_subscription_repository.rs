@@ -0,0 +1,176 @@
+//! # PostgreSQL Webhook Subscription Repository
+//!
+//! PostgreSQL implementation of [`WebhookSubscriptionRepository`] using sqlx.
+
+use crate::domain::entities::webhook_subscription::WebhookSubscription;
+use crate::domain::value_objects::WebhookSubscriptionId;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::infrastructure::persistence::postgres::map_sqlx_error;
+use crate::infrastructure::persistence::traits::{
+    RepositoryError, RepositoryResult, WebhookSubscriptionRepository,
+};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// PostgreSQL implementation of [`WebhookSubscriptionRepository`].
+///
+/// Uses connection pooling via `sqlx::PgPool`.
+#[derive(Debug, Clone)]
+pub struct PostgresWebhookSubscriptionRepository {
+    pool: PgPool,
+}
+
+impl PostgresWebhookSubscriptionRepository {
+    /// Creates a new PostgreSQL webhook subscription repository.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a reference to the connection pool.
+    #[must_use]
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl WebhookSubscriptionRepository for PostgresWebhookSubscriptionRepository {
+    async fn save(&self, subscription: &WebhookSubscription) -> RepositoryResult<()> {
+        let event_filters_json = serde_json::to_value(subscription.event_filters())
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let consecutive_failures = i32::try_from(subscription.consecutive_failures())
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO webhook_subscriptions (
+                id, url, secret, event_filters, active, consecutive_failures,
+                created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (id) DO UPDATE SET
+                url = EXCLUDED.url,
+                secret = EXCLUDED.secret,
+                event_filters = EXCLUDED.event_filters,
+                active = EXCLUDED.active,
+                consecutive_failures = EXCLUDED.consecutive_failures,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(subscription.id().get())
+        .bind(subscription.url())
+        .bind(subscription.secret())
+        .bind(&event_filters_json)
+        .bind(subscription.is_active())
+        .bind(consecutive_failures)
+        .bind(subscription.created_at().timestamp_millis())
+        .bind(subscription.updated_at().timestamp_millis())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: WebhookSubscriptionId,
+    ) -> RepositoryResult<Option<WebhookSubscription>> {
+        let row: Option<WebhookSubscriptionRow> = sqlx::query_as(
+            r#"
+            SELECT id, url, secret, event_filters, active, consecutive_failures,
+                   created_at, updated_at
+            FROM webhook_subscriptions WHERE id = $1
+            "#,
+        )
+        .bind(id.get())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(WebhookSubscriptionRow::try_into_subscription)
+            .transpose()
+    }
+
+    async fn find_all(&self) -> RepositoryResult<Vec<WebhookSubscription>> {
+        self.fetch(false).await
+    }
+
+    async fn find_active(&self) -> RepositoryResult<Vec<WebhookSubscription>> {
+        self.fetch(true).await
+    }
+
+    async fn delete(&self, id: WebhookSubscriptionId) -> RepositoryResult<bool> {
+        let result = sqlx::query("DELETE FROM webhook_subscriptions WHERE id = $1")
+            .bind(id.get())
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+impl PostgresWebhookSubscriptionRepository {
+    async fn fetch(&self, active_only: bool) -> RepositoryResult<Vec<WebhookSubscription>> {
+        let rows: Vec<WebhookSubscriptionRow> = sqlx::query_as(
+            r#"
+            SELECT id, url, secret, event_filters, active, consecutive_failures,
+                   created_at, updated_at
+            FROM webhook_subscriptions
+            WHERE active OR NOT $1
+            ORDER BY created_at ASC, id ASC
+            "#,
+        )
+        .bind(active_only)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter()
+            .map(WebhookSubscriptionRow::try_into_subscription)
+            .collect()
+    }
+}
+
+/// Row type for webhook subscription queries.
+#[derive(Debug, sqlx::FromRow)]
+struct WebhookSubscriptionRow {
+    id: Uuid,
+    url: String,
+    secret: String,
+    event_filters: serde_json::Value,
+    active: bool,
+    consecutive_failures: i32,
+    created_at: i64,
+    updated_at: i64,
+}
+
+impl WebhookSubscriptionRow {
+    /// Converts the row into a [`WebhookSubscription`].
+    fn try_into_subscription(self) -> RepositoryResult<WebhookSubscription> {
+        let event_filters: Vec<String> = serde_json::from_value(self.event_filters)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let consecutive_failures = u32::try_from(self.consecutive_failures)
+            .map_err(|e| RepositoryError::serialization(e.to_string()))?;
+        let created_at = Timestamp::from_millis(self.created_at).ok_or_else(|| {
+            RepositoryError::serialization("invalid created_at timestamp".to_string())
+        })?;
+        let updated_at = Timestamp::from_millis(self.updated_at).ok_or_else(|| {
+            RepositoryError::serialization("invalid updated_at timestamp".to_string())
+        })?;
+
+        Ok(WebhookSubscription::from_parts(
+            WebhookSubscriptionId::new(self.id),
+            self.url,
+            self.secret,
+            event_filters,
+            self.active,
+            consecutive_failures,
+            created_at,
+            updated_at,
+        ))
+    }
+}
@@ -0,0 +1,118 @@
+//! # Raw Venue Exchange Log
+//!
+//! Port definition for persisting raw venue request and response payloads.
+//!
+//! When a market maker disputes that it was shown an RFQ, or claims its
+//! quote was misparsed, the raw exchange is the evidence. Venue adapters
+//! record each outbound request and inbound response, with configured
+//! secret fields redacted, and every record carries its own expiry so a
+//! background purge can enforce retention.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use otc_rfq::infrastructure::persistence::raw_exchange_log::RawExchangeLog;
+//!
+//! let exchanges = log.find_by_rfq(rfq_id).await?;
+//! let purged = log.purge_expired(Timestamp::now()).await?;
+//! ```
+
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{RfqId, VenueId};
+use crate::infrastructure::persistence::traits::RepositoryResult;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Direction of a raw venue exchange.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExchangeDirection {
+    /// Sent by us to the venue.
+    Request,
+    /// Received by us from the venue.
+    Response,
+}
+
+impl fmt::Display for ExchangeDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Request => write!(f, "REQUEST"),
+            Self::Response => write!(f, "RESPONSE"),
+        }
+    }
+}
+
+impl FromStr for ExchangeDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "REQUEST" => Ok(Self::Request),
+            "RESPONSE" => Ok(Self::Response),
+            other => Err(format!("unknown exchange direction: {other}")),
+        }
+    }
+}
+
+/// A raw request or response exchanged with a venue for an RFQ.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RawExchange {
+    /// Venue the payload was exchanged with.
+    pub venue_id: VenueId,
+    /// RFQ the exchange belongs to.
+    pub rfq_id: RfqId,
+    /// Whether the payload was sent or received.
+    pub direction: ExchangeDirection,
+    /// Payload with secret fields redacted. Response bodies that are not
+    /// JSON are stored as a string.
+    pub payload: serde_json::Value,
+    /// HTTP status of a response; `None` for requests and transport failures.
+    pub http_status: Option<u16>,
+    /// Round-trip latency of a response in milliseconds; `None` for requests.
+    pub latency_ms: Option<u64>,
+    /// When the payload was sent or received.
+    pub recorded_at: Timestamp,
+    /// When the record may be purged.
+    pub expires_at: Timestamp,
+}
+
+impl RawExchange {
+    /// Returns true if the record's retention has lapsed as of `now`.
+    #[must_use]
+    pub fn is_expired_at(&self, now: Timestamp) -> bool {
+        self.expires_at <= now
+    }
+}
+
+/// Sink for raw venue exchanges.
+///
+/// Implementations must be `Send + Sync` for use in async contexts.
+#[async_trait]
+pub trait RawExchangeLog: Send + Sync + fmt::Debug {
+    /// Appends an exchange to the log.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the exchange cannot be stored.
+    async fn record(&self, exchange: &RawExchange) -> RepositoryResult<()>;
+
+    /// Returns the exchanges recorded for an RFQ, oldest first.
+    ///
+    /// Exchanges recorded at the same instant keep their insertion order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the exchanges cannot be loaded.
+    async fn find_by_rfq(&self, rfq_id: RfqId) -> RepositoryResult<Vec<RawExchange>>;
+
+    /// Deletes every exchange whose retention has lapsed as of `now`.
+    ///
+    /// Returns the number of exchanges deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the exchanges cannot be deleted.
+    async fn purge_expired(&self, now: Timestamp) -> RepositoryResult<u64>;
+}
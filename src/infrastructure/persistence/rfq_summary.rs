@@ -0,0 +1,185 @@
+//! # RFQ Summary Read Model
+//!
+//! Port definition for the denormalized `rfq_summary` read model.
+//!
+//! The ops dashboard lists RFQs together with their best quote, selected
+//! venue and execution price. Loading each RFQ, its quotes and its trade
+//! separately costs several queries per row, so a projection folds the
+//! domain events of each RFQ into one [`RfqSummary`] row instead. The rows
+//! are derived data: they can be dropped and rebuilt from the event store
+//! at any time.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use otc_rfq::infrastructure::persistence::rfq_summary::RfqSummaryStore;
+//!
+//! let page = store.list_after(None, 50, &RfqListFilter::default()).await?;
+//! ```
+
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    CounterpartyId, OrderSide, Price, Quantity, RfqId, RfqState, Symbol, TradeId, VenueId,
+};
+use crate::infrastructure::persistence::cursor::PageCursor;
+use crate::infrastructure::persistence::traits::{RepositoryResult, RfqListFilter};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use uuid::Uuid;
+
+/// One denormalized row of the RFQ dashboard.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RfqSummary {
+    /// The summarized RFQ.
+    pub rfq_id: RfqId,
+    /// Client that created the RFQ.
+    pub client_id: CounterpartyId,
+    /// Instrument symbol.
+    pub symbol: Symbol,
+    /// Client side.
+    pub side: OrderSide,
+    /// Requested quantity.
+    pub quantity: Quantity,
+    /// Lifecycle state as of the last applied event.
+    pub state: RfqState,
+    /// Number of quotes received.
+    pub quote_count: u32,
+    /// Best quote price for the client's side: lowest when buying,
+    /// highest when selling.
+    pub best_quote_price: Option<Price>,
+    /// Venue of the selected quote.
+    pub selected_venue_id: Option<VenueId>,
+    /// Trade booked on execution.
+    pub trade_id: Option<TradeId>,
+    /// Executed price.
+    pub execution_price: Option<Price>,
+    /// Why execution failed, if it did.
+    pub failure_reason: Option<String>,
+    /// When the RFQ was created.
+    pub created_at: Timestamp,
+    /// When the last applied event occurred.
+    pub last_event_at: Timestamp,
+    /// Sequence number of the last applied event.
+    pub last_sequence: u64,
+    /// Last applied version of each aggregate (the RFQ and its trade)
+    /// whose versioned events were folded in.
+    #[serde(default)]
+    pub aggregate_versions: BTreeMap<Uuid, u64>,
+}
+
+impl RfqSummary {
+    /// Returns the keyset pagination position of this row.
+    #[must_use]
+    pub fn cursor(&self) -> PageCursor {
+        PageCursor::new(self.created_at.timestamp_millis(), self.rfq_id.to_string())
+    }
+
+    /// Returns true if version `version` of `aggregate_id` is already
+    /// folded into this row, so a redelivery of it must be skipped.
+    #[must_use]
+    pub fn has_applied(&self, aggregate_id: Uuid, version: u64) -> bool {
+        self.aggregate_versions
+            .get(&aggregate_id)
+            .is_some_and(|last| version <= *last)
+    }
+
+    /// Returns true if the row satisfies every set field of `filter`.
+    ///
+    /// Activation times and failure codes are not projected, so a filter
+    /// with `scheduled_as_of` or `failure_code` set matches no summary.
+    #[must_use]
+    pub fn matches(&self, filter: &RfqListFilter) -> bool {
+        let created_at = self.created_at.timestamp_millis();
+        filter.scheduled_as_of.is_none()
+            && filter.failure_code.is_none()
+            && filter
+                .client_id
+                .as_ref()
+                .is_none_or(|id| &self.client_id == id)
+            && (filter.states.is_empty() || filter.states.contains(&self.state))
+            && filter.symbol.as_ref().is_none_or(|s| &self.symbol == s)
+            && filter
+                .base_asset
+                .as_ref()
+                .is_none_or(|asset| self.symbol.base_asset() == asset)
+            && filter
+                .quote_asset
+                .as_ref()
+                .is_none_or(|asset| self.symbol.quote_asset() == asset)
+            && filter
+                .created_from
+                .is_none_or(|from| created_at >= from.timestamp_millis())
+            && filter
+                .created_to
+                .is_none_or(|to| created_at <= to.timestamp_millis())
+            && filter.side.is_none_or(|side| self.side == side)
+    }
+}
+
+/// Storage for [`RfqSummary`] rows.
+///
+/// Implementations must be `Send + Sync` for use in async contexts.
+#[async_trait]
+pub trait RfqSummaryStore: Send + Sync + fmt::Debug {
+    /// Inserts or replaces the row for `summary.rfq_id`.
+    ///
+    /// A row is only replaced by one with a higher `last_sequence`, so a
+    /// stale write never rolls the summary back.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the row cannot be stored.
+    async fn upsert(&self, summary: &RfqSummary) -> RepositoryResult<()>;
+
+    /// Returns the row for an RFQ, if one has been projected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the row cannot be loaded.
+    async fn get(&self, rfq_id: RfqId) -> RepositoryResult<Option<RfqSummary>>;
+
+    /// Returns up to `limit` rows strictly after `cursor`, newest first.
+    ///
+    /// Uses the same filter and keyset ordering as
+    /// [`RfqRepository::list_after`](crate::infrastructure::persistence::traits::RfqRepository::list_after).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rows cannot be loaded.
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> RepositoryResult<Vec<RfqSummary>>;
+
+    /// Returns up to `limit` rows newest first, skipping the first `offset`.
+    ///
+    /// Uses the same filter and ordering as [`list_after`](Self::list_after).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rows cannot be loaded.
+    async fn list_offset(
+        &self,
+        offset: usize,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> RepositoryResult<Vec<RfqSummary>>;
+
+    /// Counts rows matching `filter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rows cannot be counted.
+    async fn count_matching(&self, filter: &RfqListFilter) -> RepositoryResult<u64>;
+
+    /// Deletes every row, returning how many were deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rows cannot be deleted.
+    async fn clear(&self) -> RepositoryResult<u64>;
+}
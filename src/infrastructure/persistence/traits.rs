@@ -13,6 +13,16 @@
 //! - [`VenueRepository`]: Persistence for venue configurations
 //! - [`CounterpartyRepository`]: Persistence for counterparty data
 //! - [`BlockTradeRepository`]: Persistence for block trade entities
+//! - [`InstrumentReferenceDataRepository`]: Persistence for instrument reference data
+//! - [`RfqTemplateRepository`]: Persistence for saved RFQ templates
+//! - [`PlatformFeeScheduleRepository`]: Persistence for platform fee schedules
+//! - [`FeeWaiverRepository`]: Persistence for counterparty fee waivers
+//! - [`NegotiationRepository`]: Persistence for counter-quote negotiations
+//! - [`WebhookSubscriptionRepository`]: Persistence for webhook subscriptions
+//! - [`AggregationReportRepository`]: Per-venue quote aggregation outcomes of RFQs
+//! - [`BestExecutionReportRepository`]: Snapshots of per-client best execution reports
+//! - [`TradingCalendarRepository`]: Persistence for named trading calendars
+//! - [`VenueCredentialRepository`]: Persistence for counterparties' own venue credentials
 //!
 //! # Examples
 //!
@@ -25,19 +35,34 @@
 //! }
 //! ```
 
+use crate::application::services::retry::Retryable;
 use crate::domain::entities::anonymity::IdentityMapping;
 use crate::domain::entities::block_trade::BlockTrade;
 use crate::domain::entities::counterparty::Counterparty;
+use crate::domain::entities::erasure_request::ErasureRequest;
+use crate::domain::entities::negotiation::Negotiation;
+use crate::domain::entities::netting_batch::{NettingBatch, NettingBatchStatus};
+use crate::domain::entities::platform_fee::{FeeWaiver, PlatformFeeSchedule};
 use crate::domain::entities::rfq::Rfq;
+use crate::domain::entities::rfq_template::RfqTemplate;
 use crate::domain::entities::trade::Trade;
-use crate::domain::value_objects::{BlockTradeId, CounterpartyId, RfqId, TradeId, VenueId};
+use crate::domain::entities::venue_credential_set::VenueCredentialSet;
+use crate::domain::entities::webhook_subscription::WebhookSubscription;
+use crate::domain::value_objects::reference_price::{PriceBoundsConfigChange, PriceBoundsSettings};
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    AggregationReport, BestExecutionReport, BlockTradeId, CounterpartyId, ErasureRequestId,
+    FailureCode, InstrumentReferenceData, NegotiationId, NettingBatchId, OrderSide, RfqId,
+    RfqState, RfqTemplateId, Symbol, TradeId, TradingCalendar, VenueId, WebhookSubscriptionId,
+};
+use crate::infrastructure::persistence::cursor::PageCursor;
 use crate::infrastructure::venues::registry::VenueConfig;
 use async_trait::async_trait;
 use std::fmt;
 use thiserror::Error;
 
 /// Error type for repository operations.
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum RepositoryError {
     /// Entity not found.
     #[error("Entity not found: {entity_type} with id {id}")]
@@ -70,9 +95,26 @@ pub enum RepositoryError {
         actual: u64,
     },
 
-    /// Connection error.
-    #[error("Connection error: {0}")]
-    Connection(String),
+    /// A database constraint rejected the write.
+    #[error("Constraint violation: {kind} constraint {constraint}")]
+    ConstraintViolation {
+        /// Name of the violated constraint.
+        constraint: String,
+        /// Kind of constraint.
+        kind: ConstraintKind,
+    },
+
+    /// The connection to the backend was lost or could not be established.
+    #[error("Connection lost: {0}")]
+    ConnectionLost(String),
+
+    /// The backend did not answer in time.
+    #[error("Timeout: {0}")]
+    Timeout(String),
+
+    /// The transaction was aborted in favour of a concurrent one.
+    #[error("Transaction conflict: {0}")]
+    TransactionConflict(String),
 
     /// Query error.
     #[error("Query error: {0}")]
@@ -122,10 +164,31 @@ impl RepositoryError {
         }
     }
 
-    /// Creates a connection error.
+    /// Creates a constraint violation error.
+    #[must_use]
+    pub fn constraint_violation(constraint: impl Into<String>, kind: ConstraintKind) -> Self {
+        Self::ConstraintViolation {
+            constraint: constraint.into(),
+            kind,
+        }
+    }
+
+    /// Creates a connection lost error.
+    #[must_use]
+    pub fn connection_lost(msg: impl Into<String>) -> Self {
+        Self::ConnectionLost(msg.into())
+    }
+
+    /// Creates a timeout error.
+    #[must_use]
+    pub fn timeout(msg: impl Into<String>) -> Self {
+        Self::Timeout(msg.into())
+    }
+
+    /// Creates a transaction conflict error.
     #[must_use]
-    pub fn connection(msg: impl Into<String>) -> Self {
-        Self::Connection(msg.into())
+    pub fn transaction_conflict(msg: impl Into<String>) -> Self {
+        Self::TransactionConflict(msg.into())
     }
 
     /// Creates a query error.
@@ -163,11 +226,164 @@ impl RepositoryError {
     pub fn is_version_conflict(&self) -> bool {
         matches!(self, Self::VersionConflict { .. })
     }
+
+    /// Returns true if a unique constraint rejected the write.
+    #[must_use]
+    pub fn is_unique_violation(&self) -> bool {
+        matches!(
+            self,
+            Self::ConstraintViolation {
+                kind: ConstraintKind::Unique,
+                ..
+            }
+        )
+    }
+
+    /// Returns true if the operation may succeed when repeated unchanged.
+    ///
+    /// Lost connections, timeouts and aborted transactions are transient;
+    /// constraint violations, missing entities and version conflicts are not.
+    #[must_use]
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::ConnectionLost(_) | Self::Timeout(_) | Self::TransactionConflict(_)
+        )
+    }
+}
+
+impl Retryable for RepositoryError {
+    fn is_retryable(&self) -> bool {
+        self.is_transient()
+    }
+}
+
+/// Kind of database constraint behind a [`RepositoryError::ConstraintViolation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintKind {
+    /// Unique or primary key constraint.
+    Unique,
+    /// Foreign key constraint.
+    ForeignKey,
+    /// Not-null constraint.
+    NotNull,
+    /// Check constraint.
+    Check,
+    /// Exclusion constraint.
+    Exclusion,
+}
+
+impl ConstraintKind {
+    /// Returns the kind as a snake_case string.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Unique => "unique",
+            Self::ForeignKey => "foreign_key",
+            Self::NotNull => "not_null",
+            Self::Check => "check",
+            Self::Exclusion => "exclusion",
+        }
+    }
+}
+
+impl fmt::Display for ConstraintKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Result type for repository operations.
 pub type RepositoryResult<T> = Result<T, RepositoryError>;
 
+/// Filter for RFQ listings.
+///
+/// All fields are optional; unset fields do not restrict the result.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RfqListFilter {
+    /// Only RFQs created by this client.
+    pub client_id: Option<CounterpartyId>,
+    /// Only RFQs in one of these states (empty = any state).
+    pub states: Vec<RfqState>,
+    /// Only RFQs for this instrument symbol (e.g. `BTC/USD`).
+    pub symbol: Option<Symbol>,
+    /// Only RFQs whose instrument has this base asset.
+    pub base_asset: Option<String>,
+    /// Only RFQs whose instrument has this quote asset.
+    pub quote_asset: Option<String>,
+    /// Only RFQs created at or after this time.
+    pub created_from: Option<Timestamp>,
+    /// Only RFQs created at or before this time.
+    pub created_to: Option<Timestamp>,
+    /// Only RFQs still waiting for their activation time as of this time.
+    pub scheduled_as_of: Option<Timestamp>,
+    /// Only failed RFQs whose failure has this code.
+    pub failure_code: Option<FailureCode>,
+    /// Only one-way RFQs on this side.
+    pub side: Option<OrderSide>,
+}
+
+impl RfqListFilter {
+    /// Returns true if the RFQ satisfies every set field.
+    #[must_use]
+    pub fn matches(&self, rfq: &Rfq) -> bool {
+        let symbol = rfq.instrument().symbol();
+        let created_at = rfq.created_at().timestamp_millis();
+        self.client_id
+            .as_ref()
+            .is_none_or(|id| rfq.client_id() == id)
+            && (self.states.is_empty() || self.states.contains(&rfq.state()))
+            && self.symbol.as_ref().is_none_or(|s| symbol == s)
+            && self
+                .base_asset
+                .as_ref()
+                .is_none_or(|asset| symbol.base_asset() == asset)
+            && self
+                .quote_asset
+                .as_ref()
+                .is_none_or(|asset| symbol.quote_asset() == asset)
+            && self
+                .created_from
+                .is_none_or(|from| created_at >= from.timestamp_millis())
+            && self
+                .created_to
+                .is_none_or(|to| created_at <= to.timestamp_millis())
+            && self
+                .scheduled_as_of
+                .is_none_or(|now| rfq.is_scheduled_at(now))
+            && self.failure_code.is_none_or(|code| {
+                rfq.failure_reason()
+                    .is_some_and(|reason| reason.code() == code)
+            })
+            && self
+                .side
+                .is_none_or(|side| !rfq.is_two_way() && rfq.side() == side)
+    }
+}
+
+/// Filter for trade listings.
+///
+/// All fields are optional; unset fields do not restrict the result.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TradeListFilter {
+    /// Only trades executed for this RFQ.
+    pub rfq_id: Option<RfqId>,
+    /// Only trades executed at this venue.
+    pub venue_id: Option<VenueId>,
+}
+
+impl TradeListFilter {
+    /// Returns true if the trade satisfies every set field.
+    #[must_use]
+    pub fn matches(&self, trade: &Trade) -> bool {
+        self.rfq_id.is_none_or(|id| trade.rfq_id() == id)
+            && self
+                .venue_id
+                .as_ref()
+                .is_none_or(|id| trade.venue_id() == id)
+    }
+}
+
 /// Repository for RFQ entities.
 ///
 /// Provides persistence operations for RFQ (Request for Quote) entities.
@@ -219,6 +435,49 @@ pub trait RfqRepository: Send + Sync + fmt::Debug {
     /// Returns all RFQs that have been sent to the specified venue.
     async fn find_by_venue(&self, venue_id: &VenueId) -> RepositoryResult<Vec<Rfq>>;
 
+    /// Lists RFQs newest first, starting after `cursor`.
+    ///
+    /// Returns at most `limit` RFQs matching `filter`, ordered by
+    /// `(created_at, id)` descending. Pass `None` for the first page.
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> RepositoryResult<Vec<Rfq>>;
+
+    /// Lists RFQs newest first, skipping the first `offset`.
+    ///
+    /// Returns at most `limit` RFQs matching `filter`, in the same order as
+    /// [`list_after`](Self::list_after).
+    async fn list_offset(
+        &self,
+        offset: usize,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> RepositoryResult<Vec<Rfq>>;
+
+    /// Counts RFQs matching `filter`.
+    async fn count_matching(&self, filter: &RfqListFilter) -> RepositoryResult<u64>;
+
+    /// Finds RFQs that could be crossed internally against an incoming RFQ.
+    ///
+    /// Returns single-instrument RFQs on `symbol` and `side` that opted in
+    /// to internal crossing, can still receive quotes, and expire after
+    /// `valid_after`, oldest first.
+    async fn find_crossing_candidates(
+        &self,
+        symbol: &Symbol,
+        side: OrderSide,
+        valid_after: Timestamp,
+    ) -> RepositoryResult<Vec<Rfq>>;
+
+    /// Finds scheduled RFQs whose activation time is at or before `before`.
+    ///
+    /// Only RFQs still in [`RfqState::Created`] are returned, oldest
+    /// activation time first; cancelled or expired RFQs are never activated.
+    async fn find_pending_activation(&self, before: Timestamp) -> RepositoryResult<Vec<Rfq>>;
+
     /// Deletes an RFQ by ID.
     ///
     /// Returns `Ok(true)` if the RFQ was deleted, `Ok(false)` if it didn't exist.
@@ -281,6 +540,17 @@ pub trait TradeRepository: Send + Sync + fmt::Debug {
     /// Returns all trades executed at the specified venue.
     async fn find_by_venue(&self, venue_id: &VenueId) -> RepositoryResult<Vec<Trade>>;
 
+    /// Lists trades newest first, starting after `cursor`.
+    ///
+    /// Returns at most `limit` trades matching `filter`, ordered by
+    /// `(created_at, id)` descending. Pass `None` for the first page.
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &TradeListFilter,
+    ) -> RepositoryResult<Vec<Trade>>;
+
     /// Finds settled trades.
     ///
     /// Returns all trades in the `Settled` settlement state.
@@ -291,6 +561,16 @@ pub trait TradeRepository: Send + Sync + fmt::Debug {
     /// Returns all trades in the `Failed` settlement state.
     async fn find_failed(&self) -> RepositoryResult<Vec<Trade>>;
 
+    /// Finds failed trades whose settlement retry is due.
+    ///
+    /// Returns at most `limit` trades in the `Failed` state with no retry
+    /// scheduled or a retry scheduled at or before `now`, earliest first.
+    async fn find_due_for_settlement_retry(
+        &self,
+        now: Timestamp,
+        limit: usize,
+    ) -> RepositoryResult<Vec<Trade>>;
+
     /// Deletes a trade by ID.
     ///
     /// Returns `Ok(true)` if the trade was deleted, `Ok(false)` if it didn't exist.
@@ -614,6 +894,353 @@ pub trait BlockTradeRepository: Send + Sync + fmt::Debug {
     async fn delete(&self, id: BlockTradeId) -> RepositoryResult<bool>;
 }
 
+/// Repository for instrument reference data.
+///
+/// Stores the tick size, lot size, and order size limits of each instrument,
+/// keyed by symbol.
+///
+/// # Examples
+///
+/// ```ignore
+/// use otc_rfq::infrastructure::persistence::traits::InstrumentReferenceDataRepository;
+///
+/// async fn example(repo: &impl InstrumentReferenceDataRepository) {
+///     if let Some(data) = repo.get(&symbol).await? {
+///         data.validate_quantity(quantity)?;
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait InstrumentReferenceDataRepository: Send + Sync + fmt::Debug {
+    /// Saves reference data.
+    ///
+    /// If reference data for the symbol already exists, it is replaced.
+    async fn save(&self, data: &InstrumentReferenceData) -> RepositoryResult<()>;
+
+    /// Gets reference data by symbol.
+    ///
+    /// Returns `None` if no reference data is configured for the symbol.
+    async fn get(&self, symbol: &Symbol) -> RepositoryResult<Option<InstrumentReferenceData>>;
+
+    /// Gets all reference data, ordered by symbol.
+    async fn get_all(&self) -> RepositoryResult<Vec<InstrumentReferenceData>>;
+
+    /// Deletes reference data by symbol.
+    ///
+    /// Returns `Ok(true)` if it was deleted, `Ok(false)` if it didn't exist.
+    async fn delete(&self, symbol: &Symbol) -> RepositoryResult<bool>;
+}
+
+/// Repository for platform fee schedules, keyed by counterparty tier.
+///
+/// # Examples
+///
+/// ```ignore
+/// use otc_rfq::infrastructure::persistence::traits::PlatformFeeScheduleRepository;
+///
+/// async fn example(repo: &impl PlatformFeeScheduleRepository) {
+///     if let Some(schedule) = repo.find_for_counterparty(&client).await? {
+///         println!("{} is in tier {}", client, schedule.tier());
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait PlatformFeeScheduleRepository: Send + Sync + fmt::Debug {
+    /// Saves a schedule.
+    ///
+    /// If a schedule for the same tier exists, it is replaced.
+    async fn save(&self, schedule: &PlatformFeeSchedule) -> RepositoryResult<()>;
+
+    /// Finds the schedule for a tier.
+    async fn find_by_tier(&self, tier: &str) -> RepositoryResult<Option<PlatformFeeSchedule>>;
+
+    /// Finds all schedules, ordered by tier.
+    async fn find_all(&self) -> RepositoryResult<Vec<PlatformFeeSchedule>>;
+
+    /// Deletes the schedule for a tier.
+    ///
+    /// Returns `Ok(true)` if it was deleted, `Ok(false)` if it didn't exist.
+    async fn delete(&self, tier: &str) -> RepositoryResult<bool>;
+
+    /// Finds the schedule that charges a counterparty.
+    ///
+    /// This is the schedule of the tier the counterparty is assigned to, or
+    /// the [`DEFAULT_FEE_TIER`](crate::domain::entities::platform_fee::DEFAULT_FEE_TIER)
+    /// schedule if it is not assigned to any tier.
+    async fn find_for_counterparty(
+        &self,
+        counterparty: &CounterpartyId,
+    ) -> RepositoryResult<Option<PlatformFeeSchedule>> {
+        let schedules = self.find_all().await?;
+        let assigned = schedules
+            .iter()
+            .position(|schedule| schedule.applies_to(counterparty));
+        let index = assigned.or_else(|| schedules.iter().position(PlatformFeeSchedule::is_default));
+        Ok(index.and_then(|i| schedules.into_iter().nth(i)))
+    }
+}
+
+/// Repository for counterparty fee waivers.
+///
+/// A counterparty has at most one waiver.
+#[async_trait]
+pub trait FeeWaiverRepository: Send + Sync + fmt::Debug {
+    /// Saves a waiver, replacing the counterparty's existing one.
+    async fn save(&self, waiver: &FeeWaiver) -> RepositoryResult<()>;
+
+    /// Finds a counterparty's waiver.
+    async fn find(&self, counterparty: &CounterpartyId) -> RepositoryResult<Option<FeeWaiver>>;
+
+    /// Finds all waivers, ordered by counterparty.
+    async fn find_all(&self) -> RepositoryResult<Vec<FeeWaiver>>;
+
+    /// Deletes a counterparty's waiver.
+    ///
+    /// Returns `Ok(true)` if it was deleted, `Ok(false)` if it didn't exist.
+    async fn delete(&self, counterparty: &CounterpartyId) -> RepositoryResult<bool>;
+}
+
+/// Repository for settlement netting batches.
+#[async_trait]
+pub trait NettingBatchRepository: Send + Sync + fmt::Debug {
+    /// Saves a batch, replacing any existing batch with the same ID.
+    async fn save(&self, batch: &NettingBatch) -> RepositoryResult<()>;
+
+    /// Finds a batch by ID.
+    async fn get(&self, id: NettingBatchId) -> RepositoryResult<Option<NettingBatch>>;
+
+    /// Finds batches, optionally only those in `status`, newest first.
+    async fn find_all(
+        &self,
+        status: Option<NettingBatchStatus>,
+    ) -> RepositoryResult<Vec<NettingBatch>>;
+}
+
+/// Repository for RFQ templates.
+///
+/// Templates are owned by a counterparty; callers enforce ownership.
+///
+/// # Examples
+///
+/// ```ignore
+/// use otc_rfq::infrastructure::persistence::traits::RfqTemplateRepository;
+///
+/// async fn example(repo: &impl RfqTemplateRepository) {
+///     for template in repo.find_by_owner(&owner).await? {
+///         println!("{}: {}", template.id(), template.name());
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait RfqTemplateRepository: Send + Sync + fmt::Debug {
+    /// Saves a template.
+    ///
+    /// If a template with the same ID exists, it is replaced.
+    async fn save(&self, template: &RfqTemplate) -> RepositoryResult<()>;
+
+    /// Finds a template by ID.
+    async fn find_by_id(&self, id: RfqTemplateId) -> RepositoryResult<Option<RfqTemplate>>;
+
+    /// Finds all templates owned by a counterparty, ordered by name.
+    async fn find_by_owner(&self, owner: &CounterpartyId) -> RepositoryResult<Vec<RfqTemplate>>;
+
+    /// Deletes a template by ID.
+    ///
+    /// Returns `Ok(true)` if it was deleted, `Ok(false)` if it didn't exist.
+    async fn delete(&self, id: RfqTemplateId) -> RepositoryResult<bool>;
+}
+
+/// Repository for counter-quote negotiations.
+///
+/// # Examples
+///
+/// ```ignore
+/// use otc_rfq::infrastructure::persistence::traits::NegotiationRepository;
+///
+/// async fn example(repo: &impl NegotiationRepository) {
+///     let completed = repo.find_completed_between(Some(&mm), from, to).await?;
+///     println!("{} negotiations completed", completed.len());
+/// }
+/// ```
+#[async_trait]
+pub trait NegotiationRepository: Send + Sync + fmt::Debug {
+    /// Saves a negotiation.
+    ///
+    /// If a negotiation with the same ID exists, it is replaced.
+    async fn save(&self, negotiation: &Negotiation) -> RepositoryResult<()>;
+
+    /// Finds a negotiation by ID.
+    async fn find_by_id(&self, id: NegotiationId) -> RepositoryResult<Option<Negotiation>>;
+
+    /// Finds all negotiations opened on an RFQ, oldest first.
+    async fn find_by_rfq(&self, rfq_id: RfqId) -> RepositoryResult<Vec<Negotiation>>;
+
+    /// Finds negotiations that reached a terminal state between `from` and
+    /// `to` (inclusive), oldest first.
+    ///
+    /// A negotiation's completion time is its last update. If `mm_account`
+    /// is given, only negotiations with that market maker are returned.
+    async fn find_completed_between(
+        &self,
+        mm_account: Option<&CounterpartyId>,
+        from: Timestamp,
+        to: Timestamp,
+    ) -> RepositoryResult<Vec<Negotiation>>;
+}
+
+/// Repository for webhook subscriptions.
+///
+/// # Examples
+///
+/// ```ignore
+/// use otc_rfq::infrastructure::persistence::traits::WebhookSubscriptionRepository;
+///
+/// async fn example(repo: &impl WebhookSubscriptionRepository) {
+///     for subscription in repo.find_active().await? {
+///         println!("{} -> {}", subscription.id(), subscription.url());
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait WebhookSubscriptionRepository: Send + Sync + fmt::Debug {
+    /// Saves a subscription.
+    ///
+    /// If a subscription with the same ID exists, it is replaced.
+    async fn save(&self, subscription: &WebhookSubscription) -> RepositoryResult<()>;
+
+    /// Finds a subscription by ID.
+    async fn find_by_id(
+        &self,
+        id: WebhookSubscriptionId,
+    ) -> RepositoryResult<Option<WebhookSubscription>>;
+
+    /// Finds all subscriptions, oldest first.
+    async fn find_all(&self) -> RepositoryResult<Vec<WebhookSubscription>>;
+
+    /// Finds the active subscriptions, oldest first.
+    async fn find_active(&self) -> RepositoryResult<Vec<WebhookSubscription>>;
+
+    /// Deletes a subscription by ID.
+    ///
+    /// Returns `Ok(true)` if it was deleted, `Ok(false)` if it didn't exist.
+    async fn delete(&self, id: WebhookSubscriptionId) -> RepositoryResult<bool>;
+}
+
+/// Repository for the price bounds settings and their audit trail.
+#[async_trait]
+pub trait PriceBoundsConfigRepository: Send + Sync + fmt::Debug {
+    /// Loads the settings in force, or `None` if none were ever saved.
+    async fn load(&self) -> RepositoryResult<Option<PriceBoundsSettings>>;
+
+    /// Saves `change.current` as the settings in force and appends the
+    /// change to the audit trail, atomically.
+    async fn save(&self, change: &PriceBoundsConfigChange) -> RepositoryResult<()>;
+
+    /// Returns the audit trail, oldest change first.
+    async fn history(&self) -> RepositoryResult<Vec<PriceBoundsConfigChange>>;
+}
+
+/// Repository for the aggregation reports of RFQs.
+#[async_trait]
+pub trait AggregationReportRepository: Send + Sync + fmt::Debug {
+    /// Saves `report`, replacing any earlier report of the same RFQ.
+    async fn save(&self, report: &AggregationReport) -> RepositoryResult<()>;
+
+    /// Finds the report of an RFQ, or `None` if its quotes were never
+    /// aggregated successfully.
+    async fn find_by_rfq_id(&self, rfq_id: RfqId) -> RepositoryResult<Option<AggregationReport>>;
+}
+
+/// Repository for snapshots of best execution reports.
+#[async_trait]
+pub trait BestExecutionReportRepository: Send + Sync + fmt::Debug {
+    /// Saves `report` unless one is already stored for its client and
+    /// period.
+    ///
+    /// Returns `Ok(true)` if it was saved, `Ok(false)` if a report was
+    /// already stored.
+    async fn save(&self, report: &BestExecutionReport) -> RepositoryResult<bool>;
+
+    /// Finds the stored report of `client_id` for `[from, to)`.
+    async fn find(
+        &self,
+        client_id: &CounterpartyId,
+        from: Timestamp,
+        to: Timestamp,
+    ) -> RepositoryResult<Option<BestExecutionReport>>;
+}
+
+/// Repository for named trading calendars.
+///
+/// Calendar names are stored in upper case; the built-in crypto calendar is
+/// not stored.
+#[async_trait]
+pub trait TradingCalendarRepository: Send + Sync + fmt::Debug {
+    /// Saves a calendar, replacing the one with the same name.
+    async fn save(&self, calendar: &TradingCalendar) -> RepositoryResult<()>;
+
+    /// Finds a calendar by name.
+    async fn find(&self, name: &str) -> RepositoryResult<Option<TradingCalendar>>;
+
+    /// Finds all calendars, ordered by name.
+    async fn find_all(&self) -> RepositoryResult<Vec<TradingCalendar>>;
+
+    /// Deletes a calendar.
+    ///
+    /// Returns `Ok(true)` if it was deleted, `Ok(false)` if it didn't exist.
+    async fn delete(&self, name: &str) -> RepositoryResult<bool>;
+}
+
+/// Repository for counterparty erasure requests.
+#[async_trait]
+pub trait ErasureRequestRepository: Send + Sync + fmt::Debug {
+    /// Saves a request, replacing any existing request with the same ID.
+    async fn save(&self, request: &ErasureRequest) -> RepositoryResult<()>;
+
+    /// Finds a request by ID.
+    async fn get(&self, id: ErasureRequestId) -> RepositoryResult<Option<ErasureRequest>>;
+
+    /// Finds the requests concerning a counterparty, newest first.
+    ///
+    /// Executed requests are found under the counterparty's pseudonym.
+    async fn find_by_counterparty(
+        &self,
+        counterparty_id: &CounterpartyId,
+    ) -> RepositoryResult<Vec<ErasureRequest>>;
+}
+
+/// Repository for counterparties' own venue credentials.
+///
+/// Durable implementations must store the credentials encrypted.
+#[async_trait]
+pub trait VenueCredentialRepository: Send + Sync + fmt::Debug {
+    /// Saves a credential set, replacing the counterparty's credentials for
+    /// the same venue.
+    async fn save(&self, credentials: &VenueCredentialSet) -> RepositoryResult<()>;
+
+    /// Finds a counterparty's credentials for a venue.
+    async fn get(
+        &self,
+        counterparty_id: &CounterpartyId,
+        venue_id: &VenueId,
+    ) -> RepositoryResult<Option<VenueCredentialSet>>;
+
+    /// Finds all credentials of a counterparty, ordered by venue.
+    async fn find_by_counterparty(
+        &self,
+        counterparty_id: &CounterpartyId,
+    ) -> RepositoryResult<Vec<VenueCredentialSet>>;
+
+    /// Deletes a counterparty's credentials for a venue.
+    ///
+    /// Returns `Ok(true)` if they were deleted, `Ok(false)` if they didn't exist.
+    async fn delete(
+        &self,
+        counterparty_id: &CounterpartyId,
+        venue_id: &VenueId,
+    ) -> RepositoryResult<bool>;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -653,11 +1280,39 @@ mod tests {
 
         #[test]
         fn connection_error() {
-            let err = RepositoryError::connection("Connection refused");
+            let err = RepositoryError::connection_lost("Connection refused");
             assert!(err.to_string().contains("Connection"));
             assert!(err.to_string().contains("refused"));
         }
 
+        #[test]
+        fn transient_errors_are_retryable() {
+            assert!(RepositoryError::connection_lost("reset").is_retryable());
+            assert!(RepositoryError::timeout("pool timed out").is_retryable());
+            assert!(RepositoryError::transaction_conflict("40001").is_retryable());
+            assert!(!RepositoryError::query("syntax error").is_retryable());
+            assert!(!RepositoryError::not_found("Rfq", "rfq-1").is_retryable());
+            assert!(
+                !RepositoryError::constraint_violation("trades_pkey", ConstraintKind::Unique)
+                    .is_retryable()
+            );
+        }
+
+        #[test]
+        fn constraint_violation_names_the_constraint() {
+            let err = RepositoryError::constraint_violation(
+                "domain_events_event_id_key",
+                ConstraintKind::Unique,
+            );
+            assert!(err.is_unique_violation());
+            assert!(err.to_string().contains("unique"));
+            assert!(err.to_string().contains("domain_events_event_id_key"));
+
+            let err =
+                RepositoryError::constraint_violation("trades_rfq_fk", ConstraintKind::ForeignKey);
+            assert!(!err.is_unique_violation());
+        }
+
         #[test]
         fn query_error() {
             let err = RepositoryError::query("Invalid SQL");
@@ -0,0 +1,172 @@
+//! # Webhook Delivery Log
+//!
+//! Port definition for recording outbound webhook deliveries.
+//!
+//! Every event sent to a webhook subscription is recorded as a
+//! [`WebhookDelivery`] with the exact payload that was signed, so an
+//! operator can see what a downstream system received, why a delivery
+//! failed, and redeliver it once the endpoint is fixed. The record is
+//! updated after each attempt, so its status and attempt count reflect
+//! the retries made so far.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use otc_rfq::infrastructure::persistence::webhook_delivery_log::WebhookDeliveryLog;
+//!
+//! let page = log.list_after(subscription_id, None, 50).await?;
+//! let delivery = log.find_by_id(delivery_id).await?;
+//! ```
+
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{EventId, WebhookDeliveryId, WebhookSubscriptionId};
+use crate::infrastructure::persistence::cursor::PageCursor;
+use crate::infrastructure::persistence::traits::RepositoryResult;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Outcome of a webhook delivery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WebhookDeliveryStatus {
+    /// Attempts are still in progress.
+    Pending,
+    /// The endpoint accepted the delivery.
+    Succeeded,
+    /// Every attempt failed, or the failure was not retryable.
+    Failed,
+}
+
+impl fmt::Display for WebhookDeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pending => write!(f, "PENDING"),
+            Self::Succeeded => write!(f, "SUCCEEDED"),
+            Self::Failed => write!(f, "FAILED"),
+        }
+    }
+}
+
+impl FromStr for WebhookDeliveryStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "PENDING" => Ok(Self::Pending),
+            "SUCCEEDED" => Ok(Self::Succeeded),
+            "FAILED" => Ok(Self::Failed),
+            other => Err(format!("unknown webhook delivery status: {other}")),
+        }
+    }
+}
+
+/// One event sent to a webhook subscription.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    /// Delivery identifier.
+    pub id: WebhookDeliveryId,
+    /// Subscription the event was sent to.
+    pub subscription_id: WebhookSubscriptionId,
+    /// Event that was delivered.
+    pub event_id: EventId,
+    /// Name of the delivered event, e.g. `TradeExecuted`.
+    pub event_name: String,
+    /// Body posted to the endpoint.
+    pub payload: serde_json::Value,
+    /// Current outcome.
+    pub status: WebhookDeliveryStatus,
+    /// Attempts made so far.
+    pub attempts: u32,
+    /// HTTP status of the last failed attempt, if the endpoint responded.
+    pub response_status: Option<u16>,
+    /// Error from the last failed attempt.
+    pub last_error: Option<String>,
+    /// Delivery this one re-sends, if it was a manual redelivery.
+    pub redelivery_of: Option<WebhookDeliveryId>,
+    /// When the delivery was created.
+    pub created_at: Timestamp,
+    /// When the last attempt finished.
+    pub updated_at: Timestamp,
+}
+
+impl WebhookDelivery {
+    /// Creates a pending delivery of `payload` with no attempts yet.
+    #[must_use]
+    pub fn pending(
+        subscription_id: WebhookSubscriptionId,
+        event_id: EventId,
+        event_name: impl Into<String>,
+        payload: serde_json::Value,
+    ) -> Self {
+        let now = Timestamp::now();
+        Self {
+            id: WebhookDeliveryId::new_v4(),
+            subscription_id,
+            event_id,
+            event_name: event_name.into(),
+            payload,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            response_status: None,
+            last_error: None,
+            redelivery_of: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Creates a pending delivery that re-sends this one's payload.
+    #[must_use]
+    pub fn redelivery(&self) -> Self {
+        Self {
+            redelivery_of: Some(self.id),
+            ..Self::pending(
+                self.subscription_id,
+                self.event_id,
+                self.event_name.clone(),
+                self.payload.clone(),
+            )
+        }
+    }
+
+    /// Returns the cursor positioned at this delivery.
+    #[must_use]
+    pub fn cursor(&self) -> PageCursor {
+        PageCursor::new(self.created_at.timestamp_millis(), self.id.to_string())
+    }
+}
+
+/// Sink for webhook deliveries.
+///
+/// Implementations must be `Send + Sync` for use in async contexts.
+#[async_trait]
+pub trait WebhookDeliveryLog: Send + Sync + fmt::Debug {
+    /// Inserts a delivery, or replaces the one with the same ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delivery cannot be stored.
+    async fn save(&self, delivery: &WebhookDelivery) -> RepositoryResult<()>;
+
+    /// Returns a delivery by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delivery cannot be loaded.
+    async fn find_by_id(&self, id: WebhookDeliveryId) -> RepositoryResult<Option<WebhookDelivery>>;
+
+    /// Returns up to `limit` deliveries to a subscription strictly after
+    /// `cursor`, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the deliveries cannot be loaded.
+    async fn list_after(
+        &self,
+        subscription_id: WebhookSubscriptionId,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+    ) -> RepositoryResult<Vec<WebhookDelivery>>;
+}
@@ -0,0 +1,221 @@
+//! # Telemetry
+//!
+//! Distributed tracing from the REST and gRPC APIs through quote aggregation
+//! to venue HTTP calls.
+//!
+//! [`layer`] bridges `tracing` spans to OpenTelemetry so every span carries
+//! a trace context, whether or not anything is exported. W3C `traceparent`
+//! headers are read from inbound requests with [`set_remote_parent`] and
+//! written to outbound venue requests with [`inject`].
+//!
+//! With the `otlp` feature, [`init_otlp`] builds a tracer provider that
+//! exports spans to an OTLP collector over gRPC. Without it, or without a
+//! configured endpoint, [`tracer_provider`] keeps trace contexts for
+//! propagation and log correlation only.
+//!
+//! # Span Fields
+//!
+//! Spans use the same field names throughout:
+//!
+//! | Field | Set on |
+//! |-------|--------|
+//! | `rfq_id` | API handlers, quote collection and aggregation, venue requests |
+//! | `venue_id` | Per-venue quote requests |
+//! | `quote_id` | Per-venue quote requests, once the venue returns a quote |
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::infrastructure::telemetry;
+//! use tracing_subscriber::layer::SubscriberExt;
+//!
+//! let provider = telemetry::tracer_provider();
+//! let subscriber = tracing_subscriber::registry().with(telemetry::layer(&provider));
+//!
+//! tracing::subscriber::with_default(subscriber, || {
+//!     let span = tracing::info_span!("request");
+//!     let mut headers = reqwest::header::HeaderMap::new();
+//!     telemetry::inject(&span, &mut headers);
+//!     assert!(headers.contains_key("traceparent"));
+//! });
+//! ```
+
+use crate::domain::value_objects::TraceId;
+use opentelemetry::Context;
+use opentelemetry::propagation::{Extractor, Injector, TextMapPropagator};
+use opentelemetry::trace::{TraceContextExt, TracerProvider as _};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use tracing::Span;
+use tracing_opentelemetry::{OpenTelemetryLayer, OpenTelemetrySpanExt};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Instrumentation scope name for spans created by this service.
+pub const TRACER_NAME: &str = "otc-rfq";
+
+/// Returns a tracer provider that records trace contexts without exporting.
+#[must_use]
+pub fn tracer_provider() -> SdkTracerProvider {
+    SdkTracerProvider::builder().build()
+}
+
+/// Returns a layer bridging `tracing` spans to the provider's tracer.
+#[must_use]
+pub fn layer<S>(provider: &SdkTracerProvider) -> OpenTelemetryLayer<S, SdkTracer>
+where
+    S: tracing::Subscriber + for<'span> LookupSpan<'span>,
+{
+    tracing_opentelemetry::layer().with_tracer(provider.tracer(TRACER_NAME))
+}
+
+/// Builds a tracer provider that exports spans to an OTLP collector.
+///
+/// `endpoint` is the collector's gRPC address, e.g. `http://localhost:4317`.
+/// Call [`SdkTracerProvider::shutdown`] on exit to flush pending spans.
+///
+/// # Errors
+///
+/// Returns an error if the exporter cannot be built.
+#[cfg(feature = "otlp")]
+pub fn init_otlp(
+    endpoint: &str,
+    service_name: &str,
+) -> Result<SdkTracerProvider, opentelemetry_otlp::ExporterBuildError> {
+    use opentelemetry_otlp::WithExportConfig;
+
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()?;
+    let resource = opentelemetry_sdk::Resource::builder()
+        .with_service_name(service_name.to_string())
+        .build();
+
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(resource)
+        .build())
+}
+
+/// Extracts the remote parent context from W3C Trace Context headers.
+#[must_use]
+pub fn extract(headers: &HeaderMap) -> Context {
+    TraceContextPropagator::new().extract(&HeaderExtractor(headers))
+}
+
+/// Makes the trace in the inbound `traceparent` header the span's parent.
+///
+/// Does nothing when the header is missing or malformed, so the span starts
+/// a new trace.
+pub fn set_remote_parent(span: &Span, headers: &HeaderMap) {
+    let cx = extract(headers);
+    if !cx.span().span_context().is_valid() {
+        return;
+    }
+    if let Err(e) = span.set_parent(cx) {
+        tracing::debug!(error = %e, "Failed to set remote trace parent");
+    }
+}
+
+/// Writes the span's context as W3C Trace Context headers.
+pub fn inject(span: &Span, headers: &mut HeaderMap) {
+    TraceContextPropagator::new().inject_context(&span.context(), &mut HeaderInjector(headers));
+}
+
+/// Returns the trace ID of the current span, if it belongs to a trace.
+#[must_use]
+pub fn current_trace_id() -> Option<TraceId> {
+    let cx = Span::current().context();
+    let span = cx.span();
+    let span_context = span.span_context();
+    if !span_context.is_valid() {
+        return None;
+    }
+    TraceId::new(u128::from_be_bytes(span_context.trace_id().to_bytes()))
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(HeaderName::as_str).collect()
+    }
+}
+
+struct HeaderInjector<'a>(&'a mut HeaderMap);
+
+impl Injector for HeaderInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let Ok(name) = HeaderName::from_bytes(key.as_bytes())
+            && let Ok(value) = HeaderValue::from_str(&value)
+        {
+            self.0.insert(name, value);
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    const TRACEPARENT: &str = "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01";
+
+    fn inbound_headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("traceparent", HeaderValue::from_static(TRACEPARENT));
+        headers
+    }
+
+    #[test]
+    fn remote_parent_is_propagated_to_outbound_headers() {
+        let provider = tracer_provider();
+        let subscriber = tracing_subscriber::registry().with(layer(&provider));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("inbound");
+            set_remote_parent(&span, &inbound_headers());
+
+            let outbound = span.in_scope(|| {
+                assert_eq!(
+                    current_trace_id().unwrap().to_string(),
+                    "0af7651916cd43dd8448eb211c80319c"
+                );
+                let mut headers = HeaderMap::new();
+                inject(&Span::current(), &mut headers);
+                headers
+            });
+
+            let traceparent = outbound.get("traceparent").unwrap().to_str().unwrap();
+            assert!(traceparent.starts_with("00-0af7651916cd43dd8448eb211c80319c-"));
+            assert!(!traceparent.contains("b7ad6b7169203331"));
+        });
+    }
+
+    #[test]
+    fn missing_or_malformed_header_starts_a_new_trace() {
+        let provider = tracer_provider();
+        let subscriber = tracing_subscriber::registry().with(layer(&provider));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let mut headers = HeaderMap::new();
+            headers.insert("traceparent", HeaderValue::from_static("garbage"));
+            let span = tracing::info_span!("inbound");
+            set_remote_parent(&span, &headers);
+
+            let trace_id = span.in_scope(current_trace_id).unwrap();
+            assert_ne!(trace_id.to_string(), "0af7651916cd43dd8448eb211c80319c");
+        });
+    }
+
+    #[test]
+    fn no_trace_id_without_the_layer() {
+        assert!(current_trace_id().is_none());
+    }
+}
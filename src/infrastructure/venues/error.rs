@@ -17,7 +17,9 @@
 //! assert!(!error.is_retryable());
 //! ```
 
-use crate::domain::value_objects::VenueId;
+use crate::application::services::retry::{Retryability, Retryable};
+use crate::domain::value_objects::{FailureCode, VenueId};
+use std::time::Duration;
 use thiserror::Error;
 
 /// Error type for venue adapter operations.
@@ -40,6 +42,8 @@ pub enum VenueError {
     Connection {
         /// Error message.
         message: String,
+        /// Retry after duration in milliseconds, if the server sent one.
+        retry_after_ms: Option<u64>,
     },
 
     /// Authentication or authorization failure.
@@ -131,6 +135,15 @@ pub enum VenueError {
         /// The operation that is not supported.
         operation: String,
     },
+
+    /// Symbol has no mapping to a venue-native identifier.
+    #[error("venue {venue_id} has no mapping for symbol {symbol}")]
+    UnmappedSymbol {
+        /// The venue that lacks the mapping.
+        venue_id: VenueId,
+        /// The unmapped symbol.
+        symbol: String,
+    },
 }
 
 impl VenueError {
@@ -157,6 +170,17 @@ impl VenueError {
     pub fn connection(message: impl Into<String>) -> Self {
         Self::Connection {
             message: message.into(),
+            retry_after_ms: None,
+        }
+    }
+
+    /// Creates a connection error with retry duration, e.g. a 503 response
+    /// carrying `Retry-After`.
+    #[must_use]
+    pub fn connection_with_retry(message: impl Into<String>, retry_after_ms: u64) -> Self {
+        Self::Connection {
+            message: message.into(),
+            retry_after_ms: Some(retry_after_ms),
         }
     }
 
@@ -280,6 +304,15 @@ impl VenueError {
         }
     }
 
+    /// Creates an unmapped symbol error.
+    #[must_use]
+    pub fn unmapped_symbol(venue_id: VenueId, symbol: impl Into<String>) -> Self {
+        Self::UnmappedSymbol {
+            venue_id,
+            symbol: symbol.into(),
+        }
+    }
+
     /// Returns true if this error is retryable.
     ///
     /// Retryable errors are transient and may succeed on retry.
@@ -299,7 +332,10 @@ impl VenueError {
     pub fn is_client_error(&self) -> bool {
         matches!(
             self,
-            Self::InvalidRequest { .. } | Self::Authentication { .. } | Self::QuoteExpired { .. }
+            Self::InvalidRequest { .. }
+                | Self::Authentication { .. }
+                | Self::QuoteExpired { .. }
+                | Self::UnmappedSymbol { .. }
         )
     }
 
@@ -316,7 +352,9 @@ impl VenueError {
     #[must_use]
     pub fn retry_after_ms(&self) -> Option<u64> {
         match self {
-            Self::RateLimited { retry_after_ms, .. } => *retry_after_ms,
+            Self::RateLimited { retry_after_ms, .. } | Self::Connection { retry_after_ms, .. } => {
+                *retry_after_ms
+            }
             _ => None,
         }
     }
@@ -329,6 +367,48 @@ impl VenueError {
             _ => None,
         }
     }
+
+    /// Returns the RFQ failure category for this error.
+    ///
+    /// Errors where the venue answered and declined are `VenueRejected`;
+    /// transport, protocol and internal errors are `ExecutionError`.
+    #[must_use]
+    pub fn failure_code(&self) -> FailureCode {
+        match self {
+            Self::Timeout { .. } => FailureCode::VenueTimeout,
+            Self::InsufficientLiquidity { .. } => FailureCode::InsufficientLiquidity,
+            Self::Authentication { .. }
+            | Self::RateLimited { .. }
+            | Self::InvalidRequest { .. }
+            | Self::QuoteUnavailable { .. }
+            | Self::ExecutionFailed { .. }
+            | Self::QuoteExpired { .. }
+            | Self::UnsupportedOperation { .. }
+            | Self::UnmappedSymbol { .. } => FailureCode::VenueRejected,
+            Self::Connection { .. }
+            | Self::VenueUnavailable { .. }
+            | Self::ProtocolError { .. }
+            | Self::InternalError { .. }
+            | Self::Unknown { .. } => FailureCode::ExecutionError,
+        }
+    }
+}
+
+impl Retryable for VenueError {
+    fn is_retryable(&self) -> bool {
+        VenueError::is_retryable(self)
+    }
+
+    /// Transient errors carrying a server-specified delay retry after it.
+    fn retryability(&self) -> Retryability {
+        if !VenueError::is_retryable(self) {
+            return Retryability::NonRetryable;
+        }
+        match self.retry_after_ms() {
+            Some(ms) => Retryability::RetryAfter(Duration::from_millis(ms)),
+            None => Retryability::Retryable,
+        }
+    }
 }
 
 /// Result type for venue operations.
@@ -345,6 +425,26 @@ mod tests {
         assert!(!error.is_client_error());
     }
 
+    #[test]
+    fn failure_codes_follow_error_kind() {
+        assert_eq!(
+            VenueError::timeout("test").failure_code(),
+            FailureCode::VenueTimeout
+        );
+        assert_eq!(
+            VenueError::insufficient_liquidity("test").failure_code(),
+            FailureCode::InsufficientLiquidity
+        );
+        assert_eq!(
+            VenueError::execution_failed("test").failure_code(),
+            FailureCode::VenueRejected
+        );
+        assert_eq!(
+            VenueError::connection("test").failure_code(),
+            FailureCode::ExecutionError
+        );
+    }
+
     #[test]
     fn connection_is_retryable() {
         let error = VenueError::connection("test");
@@ -358,6 +458,26 @@ mod tests {
         assert_eq!(error.retry_after_ms(), Some(1000));
     }
 
+    #[test]
+    fn retryability_honors_server_delay() {
+        assert_eq!(
+            VenueError::rate_limited_with_retry("test", 1500).retryability(),
+            Retryability::RetryAfter(Duration::from_millis(1500))
+        );
+        assert_eq!(
+            VenueError::connection_with_retry("test", 2000).retryability(),
+            Retryability::RetryAfter(Duration::from_millis(2000))
+        );
+        assert_eq!(
+            VenueError::timeout("test").retryability(),
+            Retryability::Retryable
+        );
+        assert_eq!(
+            VenueError::invalid_request("test").retryability(),
+            Retryability::NonRetryable
+        );
+    }
+
     #[test]
     fn authentication_is_not_retryable() {
         let error = VenueError::authentication("test");
@@ -385,6 +505,14 @@ mod tests {
         assert!(error.is_retryable());
     }
 
+    #[test]
+    fn unmapped_symbol_is_client_error() {
+        let error = VenueError::unmapped_symbol(VenueId::new("hashflow"), "WBTC/USDC");
+        assert!(error.is_client_error());
+        assert!(!error.is_retryable());
+        assert!(error.to_string().contains("WBTC/USDC"));
+    }
+
     #[test]
     fn display_format() {
         let error = VenueError::timeout("request timed out");
@@ -0,0 +1,380 @@
+//! # Venue Exchange Recorder
+//!
+//! Records raw venue request and response payloads to a [`RawExchangeLog`].
+//!
+//! Adapters opt in with `with_exchange_log`; the [`HttpClient`] then
+//! records every RFQ request it sends and the response it receives,
+//! including responses that fail to parse. Secret fields are redacted by a
+//! [`PayloadRedactor`] before anything is written, and a failure to record
+//! never fails the quote request.
+//!
+//! [`HttpClient`]: crate::infrastructure::venues::http_client::HttpClient
+
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{RfqId, VenueId};
+use crate::infrastructure::persistence::raw_exchange_log::{
+    ExchangeDirection, RawExchange, RawExchangeLog,
+};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Replacement written in place of a redacted value.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Fields redacted by default.
+pub const DEFAULT_REDACTED_FIELDS: &[&str] = &[
+    "api_key",
+    "authorization",
+    "secret",
+    "password",
+    "private_key",
+];
+
+/// Default retention of recorded exchanges, in days.
+pub const DEFAULT_RETENTION_DAYS: u32 = 90;
+
+/// Redacts configured secret fields from JSON payloads.
+///
+/// Field names match at any depth, ignoring case, `_` and `-`, so
+/// `api_key` also redacts `apiKey` and `API-KEY`. Secret values added with
+/// [`with_values`](Self::with_values) are also replaced wherever they occur
+/// in a string, under any field name.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::infrastructure::venues::exchange_recorder::PayloadRedactor;
+///
+/// let redactor = PayloadRedactor::new(["api_key"]);
+/// let mut payload = serde_json::json!({ "apiKey": "s3cret", "amount": "1" });
+/// redactor.redact(&mut payload);
+/// assert_eq!(payload, serde_json::json!({ "apiKey": "[REDACTED]", "amount": "1" }));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PayloadRedactor {
+    fields: HashSet<String>,
+    values: HashSet<String>,
+}
+
+impl PayloadRedactor {
+    /// Creates a redactor for the given field names.
+    #[must_use]
+    pub fn new<I, S>(fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            fields: fields
+                .into_iter()
+                .map(|f| Self::normalize(f.as_ref()))
+                .collect(),
+            values: HashSet::new(),
+        }
+    }
+
+    /// Adds field names to redact.
+    #[must_use]
+    pub fn with_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.fields
+            .extend(fields.into_iter().map(|f| Self::normalize(f.as_ref())));
+        self
+    }
+
+    /// Adds secret values to redact wherever they occur, e.g. credentials
+    /// a venue may echo back. Empty values are ignored.
+    #[must_use]
+    pub fn with_values<I, S>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.values.extend(
+            values
+                .into_iter()
+                .map(|v| v.as_ref().to_string())
+                .filter(|v| !v.is_empty()),
+        );
+        self
+    }
+
+    /// Replaces the value of every configured field in `payload`, and every
+    /// occurrence of a configured secret value.
+    pub fn redact(&self, payload: &mut Value) {
+        match payload {
+            Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    if self.fields.contains(&Self::normalize(key)) {
+                        *value = Value::String(REDACTED.to_string());
+                    } else {
+                        self.redact(value);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.redact(item)),
+            Value::String(text) => {
+                for secret in &self.values {
+                    if text.contains(secret.as_str()) {
+                        *text = text.replace(secret.as_str(), REDACTED);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn normalize(field: &str) -> String {
+        field
+            .chars()
+            .filter(|c| *c != '_' && *c != '-')
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+}
+
+impl Default for PayloadRedactor {
+    fn default() -> Self {
+        Self::new(DEFAULT_REDACTED_FIELDS)
+    }
+}
+
+/// Redaction and retention applied to recorded exchanges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExchangeLogPolicy {
+    /// Redactor applied to every payload.
+    pub redactor: PayloadRedactor,
+    /// Days a recorded exchange is kept before it may be purged.
+    pub retention_days: u32,
+}
+
+impl Default for ExchangeLogPolicy {
+    fn default() -> Self {
+        Self {
+            redactor: PayloadRedactor::default(),
+            retention_days: DEFAULT_RETENTION_DAYS,
+        }
+    }
+}
+
+/// Writes a venue's raw exchanges to a [`RawExchangeLog`].
+#[derive(Debug, Clone)]
+pub struct ExchangeRecorder {
+    log: Arc<dyn RawExchangeLog>,
+    venue_id: VenueId,
+    policy: Arc<ExchangeLogPolicy>,
+}
+
+impl ExchangeRecorder {
+    /// Creates a recorder for `venue_id`.
+    #[must_use]
+    pub fn new(log: Arc<dyn RawExchangeLog>, venue_id: VenueId, policy: ExchangeLogPolicy) -> Self {
+        Self {
+            log,
+            venue_id,
+            policy: Arc::new(policy),
+        }
+    }
+
+    /// Returns a recorder that also redacts `values` wherever they occur,
+    /// for requests made with credentials other than the venue's own.
+    #[must_use]
+    pub fn with_redacted_values<I, S>(&self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let policy = ExchangeLogPolicy {
+            redactor: self.policy.redactor.clone().with_values(values),
+            retention_days: self.policy.retention_days,
+        };
+        Self {
+            log: Arc::clone(&self.log),
+            venue_id: self.venue_id.clone(),
+            policy: Arc::new(policy),
+        }
+    }
+
+    /// Returns the venue this recorder writes for.
+    #[inline]
+    #[must_use]
+    pub fn venue_id(&self) -> &VenueId {
+        &self.venue_id
+    }
+
+    /// Records an outbound request payload.
+    pub async fn record_request(&self, rfq_id: RfqId, payload: Value) {
+        self.record(rfq_id, ExchangeDirection::Request, payload, None, None)
+            .await;
+    }
+
+    /// Records an inbound response.
+    ///
+    /// `http_status` is `None` if no response was received.
+    pub async fn record_response(
+        &self,
+        rfq_id: RfqId,
+        payload: Value,
+        http_status: Option<u16>,
+        latency_ms: u64,
+    ) {
+        self.record(
+            rfq_id,
+            ExchangeDirection::Response,
+            payload,
+            http_status,
+            Some(latency_ms),
+        )
+        .await;
+    }
+
+    async fn record(
+        &self,
+        rfq_id: RfqId,
+        direction: ExchangeDirection,
+        mut payload: Value,
+        http_status: Option<u16>,
+        latency_ms: Option<u64>,
+    ) {
+        self.policy.redactor.redact(&mut payload);
+        let recorded_at = Timestamp::now();
+        let retention_secs = i64::from(self.policy.retention_days) * 24 * 60 * 60;
+        let exchange = RawExchange {
+            venue_id: self.venue_id.clone(),
+            rfq_id,
+            direction,
+            payload,
+            http_status,
+            latency_ms,
+            recorded_at,
+            expires_at: recorded_at.add_secs(retention_secs),
+        };
+
+        if let Err(e) = self.log.record(&exchange).await {
+            tracing::warn!(
+                venue_id = %self.venue_id,
+                rfq_id = %rfq_id,
+                %direction,
+                error = %e,
+                "failed to record raw venue exchange"
+            );
+        }
+    }
+}
+
+/// Parses a response body as JSON, falling back to the raw text.
+#[must_use]
+pub fn response_payload(body: &str) -> Value {
+    serde_json::from_str(body).unwrap_or_else(|_| Value::String(body.to_string()))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::in_memory::InMemoryRawExchangeLog;
+
+    #[test]
+    fn redacts_api_key_in_any_spelling_and_depth() {
+        let redactor = PayloadRedactor::default();
+        let mut payload = serde_json::json!({
+            "apiKey": "k1",
+            "nested": { "API-KEY": "k2", "amount": "100" },
+            "legs": [{ "api_key": "k3" }],
+        });
+
+        redactor.redact(&mut payload);
+
+        assert_eq!(
+            payload,
+            serde_json::json!({
+                "apiKey": REDACTED,
+                "nested": { "API-KEY": REDACTED, "amount": "100" },
+                "legs": [{ "api_key": REDACTED }],
+            })
+        );
+    }
+
+    #[test]
+    fn configured_fields_extend_defaults() {
+        let redactor = PayloadRedactor::default().with_fields(["wallet"]);
+        let mut payload = serde_json::json!({ "wallet": "0xabc", "secret": "s" });
+        redactor.redact(&mut payload);
+        assert_eq!(
+            payload,
+            serde_json::json!({ "wallet": REDACTED, "secret": REDACTED })
+        );
+    }
+
+    #[test]
+    fn secret_values_are_redacted_under_any_field() {
+        let redactor = PayloadRedactor::default().with_values(["client-key", ""]);
+        let mut payload = serde_json::json!({
+            "error": "invalid key client-key",
+            "echo": { "token": "client-key" },
+            "amount": "1",
+        });
+        redactor.redact(&mut payload);
+        assert_eq!(
+            payload,
+            serde_json::json!({
+                "error": format!("invalid key {REDACTED}"),
+                "echo": { "token": REDACTED },
+                "amount": "1",
+            })
+        );
+    }
+
+    #[test]
+    fn non_json_response_is_kept_as_text() {
+        assert_eq!(
+            response_payload("<html>bad gateway</html>"),
+            Value::String("<html>bad gateway</html>".to_string())
+        );
+        assert_eq!(
+            response_payload(r#"{"status":"ok"}"#),
+            serde_json::json!({ "status": "ok" })
+        );
+    }
+
+    #[tokio::test]
+    async fn recorder_redacts_and_sets_expiry() {
+        let log = Arc::new(InMemoryRawExchangeLog::new());
+        let recorder = ExchangeRecorder::new(
+            Arc::clone(&log) as Arc<dyn RawExchangeLog>,
+            VenueId::new("bebop"),
+            ExchangeLogPolicy {
+                retention_days: 7,
+                ..ExchangeLogPolicy::default()
+            },
+        );
+        let rfq_id = RfqId::new_v4();
+
+        recorder
+            .record_request(rfq_id, serde_json::json!({ "api_key": "k", "amount": "1" }))
+            .await;
+        recorder
+            .record_response(rfq_id, serde_json::json!({ "status": "ok" }), Some(200), 42)
+            .await;
+
+        let exchanges = log.find_by_rfq(rfq_id).await.unwrap();
+        assert_eq!(exchanges.len(), 2);
+        let request = exchanges.first().unwrap();
+        assert_eq!(request.direction, ExchangeDirection::Request);
+        assert_eq!(
+            request.payload,
+            serde_json::json!({ "api_key": REDACTED, "amount": "1" })
+        );
+        assert_eq!(
+            request.expires_at,
+            request.recorded_at.add_secs(7 * 24 * 60 * 60)
+        );
+        let response = exchanges.last().unwrap();
+        assert_eq!(response.http_status, Some(200));
+        assert_eq!(response.latency_ms, Some(42));
+    }
+}
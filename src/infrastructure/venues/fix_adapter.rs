@@ -41,7 +41,7 @@
 use crate::domain::entities::quote::{Quote, QuoteBuilder};
 use crate::domain::entities::rfq::Rfq;
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{OrderSide, Price, QuoteId, SettlementMethod, VenueId};
+use crate::domain::value_objects::{OrderSide, Price, QuoteId, RfqId, SettlementMethod, VenueId};
 use crate::infrastructure::venues::error::{VenueError, VenueResult};
 use crate::infrastructure::venues::fix_config::FixMMConfig;
 use crate::infrastructure::venues::traits::{ExecutionResult, VenueAdapter, VenueHealth};
@@ -85,6 +85,8 @@ pub mod msg_type {
     pub const REJECT: &str = "3";
     /// QuoteRequestReject message type.
     pub const QUOTE_REQUEST_REJECT: &str = "AG";
+    /// QuoteCancel message type.
+    pub const QUOTE_CANCEL: &str = "Z";
 }
 
 /// FIX field tag constants.
@@ -131,6 +133,8 @@ pub mod tags {
     pub const LAST_QTY: u32 = 32;
     /// Text (58).
     pub const TEXT: u32 = 58;
+    /// QuoteCancelType (298).
+    pub const QUOTE_CANCEL_TYPE: u32 = 298;
 }
 
 /// FIX side values.
@@ -165,6 +169,12 @@ pub mod exec_type {
     pub const REJECTED: &str = "8";
 }
 
+/// FIX quote cancel type values.
+pub mod quote_cancel_type {
+    /// Cancel all quotes; scoped to one request by QuoteReqID.
+    pub const CANCEL_ALL: &str = "4";
+}
+
 /// FIX order status values.
 pub mod ord_status {
     /// New order.
@@ -500,7 +510,7 @@ pub(crate) struct PendingQuoteRequest {
     /// Channel to send the response.
     response_tx: oneshot::Sender<VenueResult<Quote>>,
     /// The original RFQ.
-    rfq_id: crate::domain::value_objects::RfqId,
+    rfq_id: RfqId,
     /// When the request was sent.
     sent_at: Timestamp,
 }
@@ -745,6 +755,31 @@ impl FixMMAdapter {
         encoder.finish()
     }
 
+    /// Encodes a QuoteCancel FIX message using IronFix.
+    ///
+    /// Tells the market maker to stop pricing the request identified by
+    /// `quote_req_id`.
+    #[must_use]
+    pub fn encode_quote_cancel(&self, quote_req_id: &str) -> bytes::BytesMut {
+        let fix_version = self.config.session().fix_version().as_str();
+        let mut encoder = ironfix_tagvalue::Encoder::new(fix_version_to_static(fix_version));
+
+        // MsgType = Z (QuoteCancel)
+        encoder.put_str(35, msg_type::QUOTE_CANCEL);
+
+        // Session fields
+        encoder.put_str(49, self.config.session().sender_comp_id());
+        encoder.put_str(56, self.config.session().target_comp_id());
+        encoder.put_uint(34, self.next_seq_num());
+        encoder.put_str(52, &Timestamp::now().to_fix_format());
+
+        // QuoteCancel fields
+        encoder.put_str(tags::QUOTE_REQ_ID, quote_req_id);
+        encoder.put_str(tags::QUOTE_CANCEL_TYPE, quote_cancel_type::CANCEL_ALL);
+
+        encoder.finish()
+    }
+
     /// Encodes a NewOrderSingle FIX message using IronFix.
     ///
     /// Returns a complete FIX message with header and checksum.
@@ -1157,6 +1192,50 @@ impl VenueAdapter for FixMMAdapter {
         let state = self.session_state().await;
         state == SessionState::LoggedOn
     }
+
+    fn supports_rfq_cancellation(&self) -> bool {
+        true
+    }
+
+    async fn cancel_rfq(&self, rfq_id: RfqId) -> VenueResult<()> {
+        // Drop the outstanding requests so a late Quote is never matched.
+        let cancelled: Vec<(String, PendingQuoteRequest)> = {
+            let mut pending = self.pending_quotes.write().await;
+            let ids: Vec<String> = pending
+                .iter()
+                .filter(|(_, request)| request.rfq_id == rfq_id)
+                .map(|(id, _)| id.clone())
+                .collect();
+            ids.into_iter()
+                .filter_map(|id| pending.remove(&id).map(|request| (id, request)))
+                .collect()
+        };
+
+        for (quote_req_id, request) in cancelled {
+            let _ = request
+                .response_tx
+                .send(Err(VenueError::quote_unavailable("RFQ cancelled")));
+
+            let message_data = self.encode_quote_cancel(&quote_req_id);
+            if self.has_engine() {
+                self.send_message(OutgoingMessage::new(message_data, msg_type::QUOTE_CANCEL))
+                    .await?;
+                tracing::debug!(
+                    venue = %self.config.venue_id(),
+                    quote_req_id = %quote_req_id,
+                    "Sent QuoteCancel via IronFix engine"
+                );
+            } else {
+                tracing::debug!(
+                    venue = %self.config.venue_id(),
+                    quote_req_id = %quote_req_id,
+                    "QuoteCancel encoded (no engine configured)"
+                );
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -1286,4 +1365,49 @@ mod tests {
             assert!(!health.is_healthy());
         }
     }
+
+    mod cancel_rfq {
+        use super::*;
+
+        #[tokio::test]
+        async fn releases_pending_requests_for_the_rfq() {
+            let adapter = FixMMAdapter::new(test_config());
+            let rfq_id = RfqId::new_v4();
+            let other = RfqId::new_v4();
+            let (tx, rx) = oneshot::channel();
+            let (other_tx, _other_rx) = oneshot::channel();
+            {
+                let mut pending = adapter.pending_quotes.write().await;
+                for (id, rfq_id, response_tx) in [("QR-1", rfq_id, tx), ("QR-2", other, other_tx)] {
+                    pending.insert(
+                        id.to_string(),
+                        PendingQuoteRequest {
+                            response_tx,
+                            rfq_id,
+                            sent_at: Timestamp::now(),
+                        },
+                    );
+                }
+            }
+
+            assert!(adapter.supports_rfq_cancellation());
+            adapter.cancel_rfq(rfq_id).await.unwrap();
+
+            assert!(rx.await.unwrap().is_err());
+            let pending = adapter.pending_quotes.read().await;
+            assert!(!pending.contains_key("QR-1"));
+            assert!(pending.contains_key("QR-2"));
+        }
+
+        #[test]
+        fn encodes_quote_cancel() {
+            let adapter = FixMMAdapter::new(test_config());
+            let message = adapter.encode_quote_cancel("QR-9");
+            let text = String::from_utf8_lossy(&message);
+
+            assert!(text.contains("35=Z"));
+            assert!(text.contains("131=QR-9"));
+            assert!(text.contains("298=4"));
+        }
+    }
 }
@@ -7,6 +7,8 @@
 //! - Automatic retries
 //! - JSON serialization/deserialization
 //! - Error handling
+//! - W3C `traceparent` propagation from the current span
+//! - Optional recording of raw RFQ exchanges via [`ExchangeRecorder`]
 //!
 //! # Examples
 //!
@@ -17,10 +19,32 @@
 //! let response: MyResponse = client.get("https://api.example.com/endpoint").await?;
 //! ```
 
+use crate::application::services::retry::parse_retry_after;
+use crate::domain::value_objects::RfqId;
+use crate::infrastructure::telemetry;
 use crate::infrastructure::venues::error::{VenueError, VenueResult};
+use crate::infrastructure::venues::exchange_recorder::{ExchangeRecorder, response_payload};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
 use reqwest::{Client, Response, StatusCode};
 use serde::de::DeserializeOwned;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use tracing::{Span, instrument};
+
+/// Returns the current span's trace context as request headers.
+fn trace_headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    telemetry::inject(&Span::current(), &mut headers);
+    headers
+}
+
+/// Returns the `Retry-After` delay of a response, if it sent a valid one.
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_retry_after)
+}
 
 /// HTTP client wrapper for venue adapters.
 ///
@@ -32,6 +56,8 @@ pub struct HttpClient {
     client: Client,
     /// Request timeout in milliseconds.
     timeout_ms: u64,
+    /// Recorder for raw RFQ exchanges, if enabled.
+    recorder: Option<ExchangeRecorder>,
 }
 
 impl HttpClient {
@@ -52,7 +78,11 @@ impl HttpClient {
                 VenueError::internal_error(format!("Failed to create HTTP client: {}", e))
             })?;
 
-        Ok(Self { client, timeout_ms })
+        Ok(Self {
+            client,
+            timeout_ms,
+            recorder: None,
+        })
     }
 
     /// Creates a new HTTP client with custom headers.
@@ -77,7 +107,25 @@ impl HttpClient {
                 VenueError::internal_error(format!("Failed to create HTTP client: {}", e))
             })?;
 
-        Ok(Self { client, timeout_ms })
+        Ok(Self {
+            client,
+            timeout_ms,
+            recorder: None,
+        })
+    }
+
+    /// Records raw exchanges made through [`post_for_rfq`](Self::post_for_rfq).
+    #[must_use]
+    pub fn with_recorder(mut self, recorder: ExchangeRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// Returns the exchange recorder, if enabled.
+    #[inline]
+    #[must_use]
+    pub fn recorder(&self) -> Option<&ExchangeRecorder> {
+        self.recorder.as_ref()
     }
 
     /// Returns the configured timeout in milliseconds.
@@ -97,10 +145,12 @@ impl HttpClient {
     ///
     /// Returns `VenueError::NetworkError` if the request fails.
     /// Returns `VenueError::ProtocolError` if the response cannot be parsed.
+    #[instrument(skip_all, fields(method = "GET", url = %url))]
     pub async fn get<T: DeserializeOwned>(&self, url: &str) -> VenueResult<T> {
         let response = self
             .client
             .get(url)
+            .headers(trace_headers())
             .send()
             .await
             .map_err(|e| self.map_reqwest_error(e))?;
@@ -119,6 +169,7 @@ impl HttpClient {
     ///
     /// Returns `VenueError::NetworkError` if the request fails.
     /// Returns `VenueError::ProtocolError` if the response cannot be parsed.
+    #[instrument(skip_all, fields(method = "GET", url = %url))]
     pub async fn get_with_params<T: DeserializeOwned, P: serde::Serialize + ?Sized>(
         &self,
         url: &str,
@@ -128,6 +179,7 @@ impl HttpClient {
             .client
             .get(url)
             .query(params)
+            .headers(trace_headers())
             .send()
             .await
             .map_err(|e| self.map_reqwest_error(e))?;
@@ -146,6 +198,7 @@ impl HttpClient {
     ///
     /// Returns `VenueError::NetworkError` if the request fails.
     /// Returns `VenueError::ProtocolError` if the response cannot be parsed.
+    #[instrument(skip_all, fields(method = "GET", url = %url))]
     pub async fn get_with_headers<T: DeserializeOwned>(
         &self,
         url: &str,
@@ -154,6 +207,7 @@ impl HttpClient {
         let response = self
             .client
             .get(url)
+            .headers(trace_headers())
             .headers(headers)
             .send()
             .await
@@ -174,6 +228,7 @@ impl HttpClient {
     ///
     /// Returns `VenueError::NetworkError` if the request fails.
     /// Returns `VenueError::ProtocolError` if the response cannot be parsed.
+    #[instrument(skip_all, fields(method = "GET", url = %url))]
     pub async fn get_with_params_and_headers<T: DeserializeOwned, P: serde::Serialize + ?Sized>(
         &self,
         url: &str,
@@ -184,6 +239,7 @@ impl HttpClient {
             .client
             .get(url)
             .query(params)
+            .headers(trace_headers())
             .headers(headers)
             .send()
             .await
@@ -203,6 +259,7 @@ impl HttpClient {
     ///
     /// Returns `VenueError::NetworkError` if the request fails.
     /// Returns `VenueError::ProtocolError` if the response cannot be parsed.
+    #[instrument(skip_all, fields(method = "POST", url = %url))]
     pub async fn post<T: DeserializeOwned, B: serde::Serialize>(
         &self,
         url: &str,
@@ -212,6 +269,7 @@ impl HttpClient {
             .client
             .post(url)
             .json(body)
+            .headers(trace_headers())
             .send()
             .await
             .map_err(|e| self.map_reqwest_error(e))?;
@@ -219,6 +277,87 @@ impl HttpClient {
         self.handle_response(response).await
     }
 
+    /// Makes a POST request for an RFQ, recording the raw exchange.
+    ///
+    /// Behaves like [`post`](Self::post). If a recorder is configured, the
+    /// request body and the raw response, including its HTTP status and
+    /// latency, are recorded against `rfq_id` before the response is
+    /// parsed, so quotes that fail to parse are still captured.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL to request.
+    /// * `body` - The request body to serialize as JSON.
+    /// * `rfq_id` - The RFQ the request belongs to.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VenueError::NetworkError` if the request fails.
+    /// Returns `VenueError::ProtocolError` if the response cannot be parsed.
+    #[instrument(skip_all, fields(method = "POST", url = %url, rfq_id = %rfq_id))]
+    pub async fn post_for_rfq<T: DeserializeOwned, B: serde::Serialize>(
+        &self,
+        url: &str,
+        body: &B,
+        rfq_id: RfqId,
+    ) -> VenueResult<T> {
+        let Some(recorder) = &self.recorder else {
+            return self.post(url, body).await;
+        };
+
+        match serde_json::to_value(body) {
+            Ok(payload) => recorder.record_request(rfq_id, payload).await,
+            Err(e) => tracing::warn!(error = %e, "failed to serialize venue request for recording"),
+        }
+
+        let started = Instant::now();
+        let result = self
+            .client
+            .post(url)
+            .json(body)
+            .headers(trace_headers())
+            .send()
+            .await;
+        let latency_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        let response = match result {
+            Ok(response) => response,
+            Err(e) => {
+                recorder
+                    .record_response(
+                        rfq_id,
+                        serde_json::Value::String(e.to_string()),
+                        None,
+                        latency_ms,
+                    )
+                    .await;
+                return Err(self.map_reqwest_error(e));
+            }
+        };
+
+        let status = response.status();
+        let retry_after = retry_after(&response);
+        let text = response
+            .text()
+            .await
+            .map_err(|e| self.map_reqwest_error(e))?;
+        recorder
+            .record_response(
+                rfq_id,
+                response_payload(&text),
+                Some(status.as_u16()),
+                latency_ms,
+            )
+            .await;
+
+        if status.is_success() {
+            serde_json::from_str(&text)
+                .map_err(|e| VenueError::protocol_error(format!("Failed to parse response: {}", e)))
+        } else {
+            Err(self.map_status_error(status, &text, retry_after))
+        }
+    }
+
     /// Makes a POST request with JSON body and custom headers.
     ///
     /// # Arguments
@@ -231,6 +370,7 @@ impl HttpClient {
     ///
     /// Returns `VenueError::NetworkError` if the request fails.
     /// Returns `VenueError::ProtocolError` if the response cannot be parsed.
+    #[instrument(skip_all, fields(method = "POST", url = %url))]
     pub async fn post_with_headers<T: DeserializeOwned, B: serde::Serialize>(
         &self,
         url: &str,
@@ -241,6 +381,7 @@ impl HttpClient {
             .client
             .post(url)
             .json(body)
+            .headers(trace_headers())
             .headers(headers)
             .send()
             .await
@@ -292,8 +433,9 @@ impl HttpClient {
                 .await
                 .map_err(|e| VenueError::protocol_error(format!("Failed to parse response: {}", e)))
         } else {
+            let retry_after = retry_after(&response);
             let error_body = response.text().await.unwrap_or_default();
-            Err(self.map_status_error(status, &error_body))
+            Err(self.map_status_error(status, &error_body, retry_after))
         }
     }
 
@@ -309,7 +451,16 @@ impl HttpClient {
     }
 
     /// Maps an HTTP status code to a VenueError.
-    fn map_status_error(&self, status: StatusCode, body: &str) -> VenueError {
+    ///
+    /// A `Retry-After` delay on a 429 or server error is carried on the
+    /// error so retries wait as long as the venue asked.
+    fn map_status_error(
+        &self,
+        status: StatusCode,
+        body: &str,
+        retry_after: Option<Duration>,
+    ) -> VenueError {
+        let retry_after_ms = retry_after.map(|d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX));
         match status {
             StatusCode::BAD_REQUEST => {
                 VenueError::invalid_request(format!("Bad request: {}", body))
@@ -320,12 +471,19 @@ impl HttpClient {
             StatusCode::NOT_FOUND => {
                 VenueError::protocol_error(format!("Resource not found: {}", body))
             }
-            StatusCode::TOO_MANY_REQUESTS => VenueError::rate_limited("Rate limit exceeded"),
+            StatusCode::TOO_MANY_REQUESTS => match retry_after_ms {
+                Some(ms) => VenueError::rate_limited_with_retry("Rate limit exceeded", ms),
+                None => VenueError::rate_limited("Rate limit exceeded"),
+            },
             StatusCode::INTERNAL_SERVER_ERROR
             | StatusCode::BAD_GATEWAY
             | StatusCode::SERVICE_UNAVAILABLE
             | StatusCode::GATEWAY_TIMEOUT => {
-                VenueError::connection(format!("Server error ({}): {}", status, body))
+                let message = format!("Server error ({}): {}", status, body);
+                match retry_after_ms {
+                    Some(ms) => VenueError::connection_with_retry(message, ms),
+                    None => VenueError::connection(message),
+                }
             }
             _ => VenueError::protocol_error(format!("HTTP error ({}): {}", status, body)),
         }
@@ -351,4 +509,179 @@ mod tests {
         let client = HttpClient::with_headers(3000, headers);
         assert!(client.is_ok());
     }
+
+    #[tokio::test]
+    async fn requests_carry_the_current_trace_context() {
+        use tracing::Instrument;
+        use tracing_subscriber::layer::SubscriberExt;
+        use wiremock::matchers::{header_exists, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header_exists("traceparent"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let provider = telemetry::tracer_provider();
+        let subscriber = tracing_subscriber::registry().with(telemetry::layer(&provider));
+        let _default = tracing::subscriber::set_default(subscriber);
+
+        let client = HttpClient::new(5000).unwrap();
+        let response: serde_json::Value = client
+            .get(&server.uri())
+            .instrument(tracing::info_span!("venue_quote", venue_id = "test"))
+            .await
+            .unwrap();
+
+        assert_eq!(response, serde_json::json!({}));
+    }
+
+    #[tokio::test]
+    async fn post_for_rfq_records_redacted_request_and_response() {
+        use crate::domain::value_objects::VenueId;
+        use crate::infrastructure::persistence::in_memory::InMemoryRawExchangeLog;
+        use crate::infrastructure::persistence::raw_exchange_log::{
+            ExchangeDirection, RawExchangeLog,
+        };
+        use crate::infrastructure::venues::exchange_recorder::{ExchangeLogPolicy, REDACTED};
+        use std::sync::Arc;
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "price": "100.5"
+            })))
+            .mount(&server)
+            .await;
+
+        let log = Arc::new(InMemoryRawExchangeLog::new());
+        let recorder = ExchangeRecorder::new(
+            Arc::clone(&log) as Arc<dyn RawExchangeLog>,
+            VenueId::new("hashflow"),
+            ExchangeLogPolicy::default(),
+        );
+        let client = HttpClient::new(5000).unwrap().with_recorder(recorder);
+        let rfq_id = RfqId::new_v4();
+
+        let response: serde_json::Value = client
+            .post_for_rfq(
+                &server.uri(),
+                &serde_json::json!({ "apiKey": "s3cret", "amount": "1" }),
+                rfq_id,
+            )
+            .await
+            .unwrap();
+        assert_eq!(response, serde_json::json!({ "price": "100.5" }));
+
+        let exchanges = log.find_by_rfq(rfq_id).await.unwrap();
+        assert_eq!(exchanges.len(), 2);
+        let request = exchanges.first().unwrap();
+        assert_eq!(request.direction, ExchangeDirection::Request);
+        assert_eq!(
+            request.payload,
+            serde_json::json!({ "apiKey": REDACTED, "amount": "1" })
+        );
+        let recorded = exchanges.last().unwrap();
+        assert_eq!(recorded.direction, ExchangeDirection::Response);
+        assert_eq!(recorded.http_status, Some(200));
+        assert!(recorded.latency_ms.is_some());
+        assert_eq!(recorded.payload, serde_json::json!({ "price": "100.5" }));
+    }
+
+    #[tokio::test]
+    async fn post_for_rfq_records_unparseable_error_response() {
+        use crate::domain::value_objects::VenueId;
+        use crate::infrastructure::persistence::in_memory::InMemoryRawExchangeLog;
+        use crate::infrastructure::persistence::raw_exchange_log::RawExchangeLog;
+        use crate::infrastructure::venues::exchange_recorder::ExchangeLogPolicy;
+        use std::sync::Arc;
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(502).set_body_string("bad gateway"))
+            .mount(&server)
+            .await;
+
+        let log = Arc::new(InMemoryRawExchangeLog::new());
+        let recorder = ExchangeRecorder::new(
+            Arc::clone(&log) as Arc<dyn RawExchangeLog>,
+            VenueId::new("bebop"),
+            ExchangeLogPolicy::default(),
+        );
+        let client = HttpClient::new(5000).unwrap().with_recorder(recorder);
+        let rfq_id = RfqId::new_v4();
+
+        let result: VenueResult<serde_json::Value> = client
+            .post_for_rfq(&server.uri(), &serde_json::json!({}), rfq_id)
+            .await;
+        assert!(result.is_err());
+
+        let exchanges = log.find_by_rfq(rfq_id).await.unwrap();
+        let recorded = exchanges.last().unwrap();
+        assert_eq!(recorded.http_status, Some(502));
+        assert_eq!(recorded.payload, serde_json::json!("bad gateway"));
+    }
+
+    #[tokio::test]
+    async fn bad_request_is_never_retried_but_unavailable_is() {
+        use crate::application::services::retry::{RetryPolicy, execute_with_retry};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/bad"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("bad amount"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/down"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = HttpClient::new(5000).unwrap();
+        let policy = RetryPolicy::new(3, 1, 10, 2.0, 0.0);
+        let body = serde_json::json!({});
+
+        let url = format!("{}/bad", server.uri());
+        let result =
+            execute_with_retry(&policy, || client.post::<serde_json::Value, _>(&url, &body)).await;
+        assert!(result.unwrap_err().is_non_retryable());
+
+        let url = format!("{}/down", server.uri());
+        let result =
+            execute_with_retry(&policy, || client.post::<serde_json::Value, _>(&url, &body)).await;
+        let err = result.unwrap_err();
+        assert!(err.is_max_retries_exceeded());
+        assert_eq!(err.attempts(), 3);
+    }
+
+    #[tokio::test]
+    async fn retry_after_header_is_carried_on_the_error() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "2"))
+            .mount(&server)
+            .await;
+
+        let client = HttpClient::new(5000).unwrap();
+        let err = client
+            .get::<serde_json::Value>(&server.uri())
+            .await
+            .unwrap_err();
+        assert_eq!(err.retry_after_ms(), Some(2000));
+    }
 }
@@ -12,11 +12,15 @@
 //! - [`VenueHealthStatus`]: Health status enum
 //! - [`VenueRegistry`]: Registry for managing venue adapters
 //! - [`VenueConfig`]: Configuration for registered venues
+//! - [`SymbolMapper`]: Per-venue mapping between domain symbols and venue-native identifiers
 //! - [`InternalMMAdapter`]: Internal market maker adapter
 //! - [`InternalMMConfig`]: Configuration for internal market maker
 //! - [`FixMMAdapter`]: FIX protocol market maker adapter
 //! - [`FixMMConfig`]: Configuration for FIX market maker
 //! - [`FixSessionConfig`]: FIX session configuration
+//! - [`SimulatedVenueAdapter`]: Seeded synthetic quotes for testing and demos
+//! - [`SimulatedVenueConfig`]: Configuration for the simulated venue
+//! - [`ExchangeRecorder`]: Records raw venue payloads for dispute resolution
 //!
 //! ## IronFix Integration
 //!
@@ -32,10 +36,12 @@
 //! - `internal_mm`: Internal market maker adapter
 //! - `fix_adapter`: FIX protocol adapter with IronFix encoding
 //! - `fix_session`: FIX session management with IronFix
+//! - `simulated`: Simulated venue for integration testing and demos
 
 pub mod contract_client;
 pub mod dex;
 pub mod error;
+pub mod exchange_recorder;
 pub mod fix_adapter;
 pub mod fix_config;
 pub mod fix_messages;
@@ -45,6 +51,8 @@ pub mod http_client;
 pub mod internal_mm;
 pub mod registry;
 pub mod rfq_protocols;
+pub mod simulated;
+pub mod symbol_mapper;
 pub mod traits;
 
 #[cfg(test)]
@@ -52,10 +60,13 @@ mod tests;
 
 pub use contract_client::ContractClient;
 pub use error::{VenueError, VenueResult};
+pub use exchange_recorder::{ExchangeLogPolicy, ExchangeRecorder, PayloadRedactor};
 pub use fix_adapter::{FixMMAdapter, SessionState};
 pub use fix_config::{FixMMConfig, FixSessionConfig, FixVersion, LogonCredentials, TlsConfig};
 pub use fix_session::{FixSession, FixSessionState};
 pub use http_client::HttpClient;
 pub use internal_mm::{InternalMMAdapter, InternalMMConfig};
 pub use registry::{VenueConfig, VenueRegistry};
+pub use simulated::{SimulatedVenueAdapter, SimulatedVenueConfig};
+pub use symbol_mapper::{SymbolMapper, SymbolMappingMode, VenueSymbol};
 pub use traits::{ExecutionResult, VenueAdapter, VenueHealth, VenueHealthStatus};
@@ -6,6 +6,14 @@
 //! and managing venue adapters. It supports filtering by availability and
 //! instrument support.
 //!
+//! # Simulated Venues
+//!
+//! Adapters reporting [`VenueAdapter::is_simulated`] are never returned by
+//! the routing queries (`get_enabled`, `get_available`, `get_for_instrument`,
+//! `get_by_priority`) unless the registry was built with
+//! [`VenueRegistry::with_simulated_venues`]. Production deployments leave
+//! this off; see `venues.allow_simulated` in the application config.
+//!
 //! # Thread Safety
 //!
 //! The registry is thread-safe and can be shared across async tasks using
@@ -28,8 +36,10 @@
 //! let available = registry.get_available().await;
 //! ```
 
+use crate::application::use_cases::collect_quotes::VenueRegistry as VenueRoutingPort;
 use crate::domain::value_objects::{Instrument, VenueId};
 use crate::infrastructure::venues::traits::VenueAdapter;
+use async_trait::async_trait;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -45,6 +55,10 @@ pub struct VenueConfig {
     priority: u32,
     /// Supported instruments (empty means all instruments).
     supported_instruments: Vec<Instrument>,
+    /// Domain asset code -> venue-native identifier mappings.
+    symbol_mappings: HashMap<String, String>,
+    /// Minimum quote time-to-live in milliseconds, overriding the global floor.
+    min_quote_ttl_ms: Option<u64>,
 }
 
 impl VenueConfig {
@@ -56,6 +70,8 @@ impl VenueConfig {
             enabled: true,
             priority: 100,
             supported_instruments: Vec::new(),
+            symbol_mappings: HashMap::new(),
+            min_quote_ttl_ms: None,
         }
     }
 
@@ -67,6 +83,8 @@ impl VenueConfig {
             enabled: false,
             priority: 100,
             supported_instruments: Vec::new(),
+            symbol_mappings: HashMap::new(),
+            min_quote_ttl_ms: None,
         }
     }
 
@@ -98,6 +116,30 @@ impl VenueConfig {
         self
     }
 
+    /// Adds a mapping from a domain asset code to a venue-native identifier.
+    ///
+    /// Loaded into a [`SymbolMapper`](super::symbol_mapper::SymbolMapper) via
+    /// `SymbolMapper::with_venue_config`.
+    #[must_use]
+    pub fn with_symbol_mapping(
+        mut self,
+        asset: impl Into<String>,
+        native: impl Into<String>,
+    ) -> Self {
+        self.symbol_mappings.insert(asset.into(), native.into());
+        self
+    }
+
+    /// Sets the minimum quote time-to-live for this venue.
+    ///
+    /// Overrides `AggregationConfig::min_quote_ttl_ms` for quotes from this
+    /// venue.
+    #[must_use]
+    pub fn with_min_quote_ttl_ms(mut self, min_quote_ttl_ms: u64) -> Self {
+        self.min_quote_ttl_ms = Some(min_quote_ttl_ms);
+        self
+    }
+
     /// Returns whether the venue is enabled.
     #[inline]
     #[must_use]
@@ -119,6 +161,20 @@ impl VenueConfig {
         &self.supported_instruments
     }
 
+    /// Returns the symbol mappings.
+    #[inline]
+    #[must_use]
+    pub fn symbol_mappings(&self) -> &HashMap<String, String> {
+        &self.symbol_mappings
+    }
+
+    /// Returns the venue's minimum quote time-to-live, if overridden.
+    #[inline]
+    #[must_use]
+    pub fn min_quote_ttl_ms(&self) -> Option<u64> {
+        self.min_quote_ttl_ms
+    }
+
     /// Returns true if the venue supports the given instrument.
     ///
     /// If no instruments are configured, returns true (supports all).
@@ -158,17 +214,41 @@ struct RegistryEntry {
 pub struct VenueRegistry {
     /// Registered adapters by venue ID.
     adapters: RwLock<HashMap<VenueId, RegistryEntry>>,
+    /// Whether simulated venues may be routed to.
+    allow_simulated: bool,
 }
 
 impl VenueRegistry {
     /// Creates a new empty registry.
+    ///
+    /// Simulated venues are excluded from routing.
     #[must_use]
     pub fn new() -> Self {
         Self {
             adapters: RwLock::new(HashMap::new()),
+            allow_simulated: false,
         }
     }
 
+    /// Sets whether simulated venues are included in routing queries.
+    #[must_use]
+    pub fn with_simulated_venues(mut self, allow: bool) -> Self {
+        self.allow_simulated = allow;
+        self
+    }
+
+    /// Returns true if simulated venues are included in routing queries.
+    #[inline]
+    #[must_use]
+    pub fn allows_simulated_venues(&self) -> bool {
+        self.allow_simulated
+    }
+
+    /// Returns true if the entry may be routed to.
+    fn is_routable(&self, entry: &RegistryEntry) -> bool {
+        entry.config.enabled && (self.allow_simulated || !entry.adapter.is_simulated())
+    }
+
     /// Registers a venue adapter with default configuration.
     ///
     /// If an adapter with the same venue ID is already registered,
@@ -185,6 +265,12 @@ impl VenueRegistry {
     /// it will be replaced.
     pub async fn register_with_config(&self, adapter: Arc<dyn VenueAdapter>, config: VenueConfig) {
         let venue_id = adapter.venue_id().clone();
+        if adapter.is_simulated() && !self.allow_simulated {
+            tracing::warn!(
+                venue_id = %venue_id,
+                "simulated venue registered but excluded from routing"
+            );
+        }
         let entry = RegistryEntry { adapter, config };
 
         let mut adapters = self.adapters.write().await;
@@ -252,7 +338,7 @@ impl VenueRegistry {
         let adapters = self.adapters.read().await;
         adapters
             .values()
-            .filter(|e| e.config.enabled)
+            .filter(|e| self.is_routable(e))
             .map(|e| Arc::clone(&e.adapter))
             .collect()
     }
@@ -283,7 +369,7 @@ impl VenueRegistry {
         let adapters = self.adapters.read().await;
         adapters
             .values()
-            .filter(|e| e.config.enabled && e.config.supports_instrument(instrument))
+            .filter(|e| self.is_routable(e) && e.config.supports_instrument(instrument))
             .map(|e| Arc::clone(&e.adapter))
             .collect()
     }
@@ -312,7 +398,7 @@ impl VenueRegistry {
     /// Lower priority values come first.
     pub async fn get_by_priority(&self) -> Vec<Arc<dyn VenueAdapter>> {
         let adapters = self.adapters.read().await;
-        let mut entries: Vec<_> = adapters.values().filter(|e| e.config.enabled).collect();
+        let mut entries: Vec<_> = adapters.values().filter(|e| self.is_routable(e)).collect();
 
         entries.sort_by_key(|e| e.config.priority);
         entries.iter().map(|e| Arc::clone(&e.adapter)).collect()
@@ -349,6 +435,17 @@ impl Default for VenueRegistry {
     }
 }
 
+#[async_trait]
+impl VenueRoutingPort for VenueRegistry {
+    async fn get_available_venues(&self) -> Vec<Arc<dyn VenueAdapter>> {
+        self.get_available().await
+    }
+
+    async fn get_venue(&self, venue_id: &VenueId) -> Option<Arc<dyn VenueAdapter>> {
+        self.get(venue_id).await
+    }
+}
+
 // Manual Debug implementation since RegistryEntry contains dyn VenueAdapter
 impl std::fmt::Debug for RegistryEntry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -375,6 +472,7 @@ mod tests {
     struct MockAdapter {
         venue_id: VenueId,
         available: bool,
+        simulated: bool,
     }
 
     impl MockAdapter {
@@ -382,6 +480,7 @@ mod tests {
             Self {
                 venue_id: VenueId::new(id),
                 available: true,
+                simulated: false,
             }
         }
 
@@ -389,6 +488,15 @@ mod tests {
             Self {
                 venue_id: VenueId::new(id),
                 available: false,
+                simulated: false,
+            }
+        }
+
+        fn simulated(id: &str) -> Self {
+            Self {
+                venue_id: VenueId::new(id),
+                available: true,
+                simulated: true,
             }
         }
     }
@@ -418,6 +526,10 @@ mod tests {
         async fn is_available(&self) -> bool {
             self.available
         }
+
+        fn is_simulated(&self) -> bool {
+            self.simulated
+        }
     }
 
     fn test_instrument() -> Instrument {
@@ -449,6 +561,15 @@ mod tests {
             assert_eq!(config.priority(), 50);
         }
 
+        #[test]
+        fn with_min_quote_ttl_ms() {
+            let config = VenueConfig::new(VenueId::new("test"));
+            assert_eq!(config.min_quote_ttl_ms(), None);
+
+            let config = config.with_min_quote_ttl_ms(2_000);
+            assert_eq!(config.min_quote_ttl_ms(), Some(2_000));
+        }
+
         #[test]
         fn supports_instrument_empty() {
             let config = VenueConfig::new(VenueId::new("test"));
@@ -668,5 +789,41 @@ mod tests {
             let config = registry.get_config(&VenueId::new("venue-1")).await;
             assert_eq!(config.unwrap().priority(), 10);
         }
+
+        #[tokio::test]
+        async fn simulated_venues_excluded_from_routing_by_default() {
+            let registry = VenueRegistry::new();
+            registry
+                .register(Arc::new(MockAdapter::new("venue-1")))
+                .await;
+            registry
+                .register(Arc::new(MockAdapter::simulated("sim-1")))
+                .await;
+
+            assert_eq!(registry.get_enabled().await.len(), 1);
+            assert_eq!(registry.get_available().await.len(), 1);
+            assert_eq!(registry.get_by_priority().await.len(), 1);
+            assert_eq!(
+                registry.get_for_instrument(&test_instrument()).await.len(),
+                1
+            );
+            assert!(registry.get(&VenueId::new("sim-1")).await.is_some());
+        }
+
+        #[tokio::test]
+        async fn simulated_venues_routed_when_allowed() {
+            let registry = VenueRegistry::new().with_simulated_venues(true);
+            registry
+                .register(Arc::new(MockAdapter::simulated("sim-1")))
+                .await;
+
+            assert!(registry.allows_simulated_venues());
+            let available = registry.get_available_venues().await;
+            assert_eq!(available.len(), 1);
+            assert_eq!(
+                available.first().unwrap().venue_id(),
+                &VenueId::new("sim-1")
+            );
+        }
     }
 }
@@ -25,13 +25,22 @@
 //! let adapter = AirswapAdapter::new(config);
 //! ```
 
-use crate::domain::entities::quote::{Quote, QuoteBuilder, QuoteMetadata};
+use crate::domain::entities::quote::{Quote, QuoteBuilder, QuoteFirmness, QuoteMetadata};
 use crate::domain::entities::rfq::Rfq;
+use crate::domain::value_objects::execution_instructions::{
+    ExecutionInstructions, ExecutionProtocol,
+};
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{Blockchain, OrderSide, Price, SettlementMethod, VenueId};
+use crate::domain::value_objects::{
+    Blockchain, OrderSide, Price, Quantity, QuantityDisclosure, SettlementMethod, VenueId,
+};
+use crate::infrastructure::blockchain::{ChainId, SharedTokenRegistry};
+use crate::infrastructure::persistence::raw_exchange_log::RawExchangeLog;
 use crate::infrastructure::venues::contract_client::ContractClient;
 use crate::infrastructure::venues::error::{VenueError, VenueResult};
+use crate::infrastructure::venues::exchange_recorder::{ExchangeLogPolicy, ExchangeRecorder};
 use crate::infrastructure::venues::http_client::HttpClient;
+use crate::infrastructure::venues::symbol_mapper::SymbolMapper;
 use crate::infrastructure::venues::traits::{ExecutionResult, VenueAdapter, VenueHealth};
 use async_trait::async_trait;
 use ethers::prelude::*;
@@ -39,6 +48,9 @@ use rust_decimal::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
+use std::sync::Arc;
+
+pub use crate::domain::value_objects::execution_instructions::{AirswapOrder, SignedAirswapOrder};
 
 /// Default timeout in milliseconds.
 const DEFAULT_TIMEOUT_MS: u64 = 5000;
@@ -197,53 +209,6 @@ impl AirswapDomain {
     }
 }
 
-/// Airswap order (EIP-712 typed data).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct AirswapOrder {
-    /// Nonce for replay protection.
-    pub nonce: String,
-    /// Order expiry timestamp (Unix seconds).
-    pub expiry: u64,
-    /// Signer wallet address.
-    pub signer_wallet: String,
-    /// Signer token address.
-    pub signer_token: String,
-    /// Signer token amount.
-    pub signer_amount: String,
-    /// Protocol fee (basis points).
-    pub protocol_fee: String,
-    /// Sender wallet address.
-    pub sender_wallet: String,
-    /// Sender token address.
-    pub sender_token: String,
-    /// Sender token amount.
-    pub sender_amount: String,
-}
-
-/// Signed Airswap order with EIP-712 signature.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SignedAirswapOrder {
-    /// The order data.
-    #[serde(flatten)]
-    pub order: AirswapOrder,
-    /// EIP-712 signature v component.
-    pub v: u8,
-    /// EIP-712 signature r component.
-    pub r: String,
-    /// EIP-712 signature s component.
-    pub s: String,
-}
-
-impl SignedAirswapOrder {
-    /// Returns the full signature as a hex string.
-    #[must_use]
-    pub fn signature(&self) -> String {
-        format!("0x{}{}{:02x}", &self.r[2..], &self.s[2..], self.v)
-    }
-}
-
 /// Server info from Airswap Registry.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -256,6 +221,9 @@ pub struct AirswapServer {
     pub staking: Option<String>,
 }
 
+/// Operation reported as unsupported for RFQs that hide their size.
+const HIDDEN_SIZE_OPERATION: &str = "signer-side order without a disclosed size";
+
 /// RFQ request to an Airswap server.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -350,7 +318,7 @@ impl AirswapConfig {
         );
         map.insert(
             "DAI".to_string(),
-            "0x6B175474E89094C44Da98b954EeddeBC35e4D1".to_string(),
+            "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(),
         );
         map.insert(
             "WBTC".to_string(),
@@ -541,6 +509,10 @@ pub struct AirswapAdapter {
     nonce: std::sync::atomic::AtomicU64,
     /// HTTP client for API requests.
     http_client: HttpClient,
+    /// Mapping from domain symbols to token addresses.
+    symbol_mapper: Arc<SymbolMapper>,
+    /// Token decimals by address, if configured.
+    token_registry: Option<SharedTokenRegistry>,
     /// Contract client for on-chain interactions.
     contract_client: Option<ContractClient>,
 }
@@ -561,10 +533,14 @@ impl AirswapAdapter {
             None
         };
 
+        let symbol_mapper = Arc::new(Self::default_symbol_mapper(&config));
+
         Ok(Self {
             config,
             nonce: std::sync::atomic::AtomicU64::new(Timestamp::now().timestamp_millis() as u64),
             http_client,
+            symbol_mapper,
+            token_registry: None,
             contract_client,
         })
     }
@@ -576,6 +552,55 @@ impl AirswapAdapter {
         &self.config
     }
 
+    /// Builds a strict symbol mapper from the configured token addresses.
+    fn default_symbol_mapper(config: &AirswapConfig) -> SymbolMapper {
+        SymbolMapper::strict().with_assets(config.venue_id(), config.token_addresses())
+    }
+
+    /// Replaces the symbol mapper, e.g. with one shared across venues.
+    #[must_use]
+    pub fn with_symbol_mapper(mut self, symbol_mapper: Arc<SymbolMapper>) -> Self {
+        self.symbol_mapper = symbol_mapper;
+        self
+    }
+
+    /// Records raw RFQ requests and responses to `log` under `policy`.
+    #[must_use]
+    pub fn with_exchange_log(
+        mut self,
+        log: Arc<dyn RawExchangeLog>,
+        policy: ExchangeLogPolicy,
+    ) -> Self {
+        let recorder = ExchangeRecorder::new(log, self.config.venue_id().clone(), policy);
+        self.http_client = self.http_client.with_recorder(recorder);
+        self
+    }
+
+    /// Returns the symbol mapper.
+    #[inline]
+    #[must_use]
+    pub fn symbol_mapper(&self) -> &SymbolMapper {
+        &self.symbol_mapper
+    }
+
+    /// Scales amounts with token decimals from `registry`.
+    ///
+    /// Without a registry, or for tokens it does not know, 18 decimals are
+    /// assumed.
+    #[must_use]
+    pub fn with_token_registry(mut self, registry: SharedTokenRegistry) -> Self {
+        self.token_registry = Some(registry);
+        self
+    }
+
+    /// Returns the decimals of the token at `address` on the configured chain.
+    fn token_decimals(&self, address: &str) -> u8 {
+        ChainId::from_u64(self.config.chain().chain_id())
+            .zip(self.token_registry.as_ref())
+            .and_then(|(chain, registry)| registry.read().entry_by_address(address, chain))
+            .map_or(18, |entry| entry.decimals)
+    }
+
     /// Generates a new nonce.
     #[must_use]
     pub fn next_nonce(&self) -> String {
@@ -589,23 +614,13 @@ impl AirswapAdapter {
     ///
     /// # Errors
     ///
-    /// Returns `VenueError::InvalidRequest` if a token symbol cannot be resolved.
+    /// Returns `VenueError::UnmappedSymbol` if the symbol has no token mapping.
     pub fn resolve_tokens(&self, rfq: &Rfq) -> VenueResult<(String, String)> {
-        let symbol = rfq.instrument().symbol();
-        let base = symbol.base_asset();
-        let quote = symbol.quote_asset();
-
-        let base_address = self
-            .config
-            .resolve_token_address(base)
-            .ok_or_else(|| VenueError::invalid_request(format!("Unknown token: {}", base)))?
-            .clone();
-
-        let quote_address = self
-            .config
-            .resolve_token_address(quote)
-            .ok_or_else(|| VenueError::invalid_request(format!("Unknown token: {}", quote)))?
-            .clone();
+        let tokens = self
+            .symbol_mapper
+            .to_venue(self.config.venue_id(), rfq.instrument().symbol())?;
+        let base_address = tokens.base().to_string();
+        let quote_address = tokens.quote().to_string();
 
         // For Buy side: sender sends quote token, receives base token
         // For Sell side: sender sends base token, receives quote token
@@ -623,12 +638,26 @@ impl AirswapAdapter {
         amount.trunc().to_string()
     }
 
-    /// Builds an RFQ request.
+    /// Builds an RFQ request for the quantity the RFQ discloses.
     ///
     /// # Errors
     ///
-    /// Returns `VenueError::InvalidRequest` if required configuration is missing.
+    /// Returns `VenueError::InvalidRequest` if required configuration is
+    /// missing, or `VenueError::UnsupportedOperation` if the RFQ hides its
+    /// size: signer-side orders cannot be requested without one.
     pub fn build_rfq_request(&self, rfq: &Rfq) -> VenueResult<AirswapRfqRequest> {
+        let quantity = rfq
+            .disclosed_quantity()
+            .ok_or_else(|| VenueError::unsupported_operation(HIDDEN_SIZE_OPERATION))?;
+        self.build_rfq_request_for(rfq, quantity)
+    }
+
+    /// Builds an RFQ request for `quantity`.
+    fn build_rfq_request_for(
+        &self,
+        rfq: &Rfq,
+        quantity: Quantity,
+    ) -> VenueResult<AirswapRfqRequest> {
         let (sender_token, signer_token) = self.resolve_tokens(rfq)?;
 
         let sender_wallet = self
@@ -637,7 +666,8 @@ impl AirswapAdapter {
             .ok_or_else(|| VenueError::invalid_request("Wallet address not configured"))?
             .to_string();
 
-        let sender_amount = self.to_smallest_unit(rfq.quantity().get(), 18);
+        let sender_decimals = self.token_decimals(&sender_token);
+        let sender_amount = self.to_smallest_unit(quantity.get(), sender_decimals);
 
         Ok(AirswapRfqRequest {
             chain_id: self.config.chain().chain_id(),
@@ -670,8 +700,10 @@ impl AirswapAdapter {
             return Err(VenueError::protocol_error("sender_amount is zero"));
         }
 
-        // Price = signer_amount / sender_amount
-        let price = signer_amount / sender_amount;
+        // Price = signer_amount / sender_amount, rescaled from base units
+        let decimals_diff = i32::from(self.token_decimals(&order.order.sender_token))
+            - i32::from(self.token_decimals(&order.order.signer_token));
+        let price = signer_amount / sender_amount * 10f64.powi(decimals_diff);
 
         Price::new(price).map_err(|_| VenueError::protocol_error("Invalid price value"))
     }
@@ -729,29 +761,69 @@ impl AirswapAdapter {
             valid_until,
         );
 
-        // Add metadata including signature for execution
+        // Carry the signed order through to execution
         let mut metadata = QuoteMetadata::new();
-        metadata.set("nonce", order.order.nonce.clone());
-        metadata.set("signer_wallet", order.order.signer_wallet.clone());
-        metadata.set("signer_token", order.order.signer_token.clone());
-        metadata.set("signer_amount", order.order.signer_amount.clone());
-        metadata.set("sender_wallet", order.order.sender_wallet.clone());
-        metadata.set("sender_token", order.order.sender_token.clone());
-        metadata.set("sender_amount", order.order.sender_amount.clone());
-        metadata.set("protocol_fee", order.order.protocol_fee.clone());
-        metadata.set("expiry", order.order.expiry.to_string());
-        metadata.set("signature", order.signature());
-        metadata.set("v", order.v.to_string());
-        metadata.set("r", order.r.clone());
-        metadata.set("s", order.s.clone());
-        metadata.set("chain_id", self.config.chain().chain_id().to_string());
-        metadata.set("swap_contract", self.config.swap_contract().to_string());
+        metadata.set_maker_identity(order.order.signer_wallet.to_lowercase());
+        metadata.set_execution_instructions(ExecutionInstructions::AirswapOrder(order));
 
         builder = builder.metadata(metadata);
 
         Ok(builder.build())
     }
 
+    /// Requests a signed order for `quantity` from the configured or
+    /// discovered Airswap servers, trying each until one answers.
+    async fn fetch_order(&self, rfq: &Rfq, quantity: Quantity) -> VenueResult<Quote> {
+        // Check if enabled
+        if !self.config.is_enabled() {
+            return Err(VenueError::venue_unavailable(
+                self.config.venue_id().clone(),
+                "Airswap adapter is disabled",
+            ));
+        }
+
+        // Build RFQ request
+        let request = self.build_rfq_request_for(rfq, quantity)?;
+
+        // Get server URLs - first try config, then discover from Registry
+        let server_urls: Vec<String> = if !self.config.server_urls().is_empty() {
+            self.config.server_urls().to_vec()
+        } else if self.contract_client.is_some() {
+            // Discover servers from Registry contract
+            let (sender_token, _) = self.resolve_tokens(rfq)?;
+            self.discover_servers(&sender_token).await?
+        } else {
+            Vec::new()
+        };
+
+        if server_urls.is_empty() {
+            return Err(VenueError::invalid_request(
+                "No Airswap server URLs configured and Registry discovery not available",
+            ));
+        }
+
+        // Try each server until one succeeds
+        let mut last_error = None;
+        for server_url in &server_urls {
+            let url = format!("{}/signer-api/v1/getSignerSideOrder", server_url);
+            match self
+                .http_client
+                .post_for_rfq::<AirswapRfqResponse, _>(&url, &request, rfq.id())
+                .await
+            {
+                Ok(response) => {
+                    return self.parse_rfq_response(response, rfq);
+                }
+                Err(e) => {
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        Err(last_error
+            .unwrap_or_else(|| VenueError::internal_error("No Airswap servers available")))
+    }
+
     /// Encodes a call to the Registry contract's getServerURLsForToken function.
     ///
     /// # Arguments
@@ -849,68 +921,31 @@ impl AirswapAdapter {
     ///
     /// # Arguments
     ///
-    /// * `quote` - The quote containing order details.
+    /// * `quote` - The quote carrying the signed order.
     ///
     /// # Errors
     ///
-    /// Returns `VenueError::InvalidRequest` if required metadata is missing.
+    /// Returns `VenueError::InvalidRequest` if the quote carries no signed
+    /// Airswap order or the order fields cannot be parsed.
     pub fn encode_swap(&self, quote: &Quote) -> VenueResult<Bytes> {
-        let metadata = quote
-            .metadata()
-            .ok_or_else(|| VenueError::invalid_request("Quote missing metadata"))?;
-
-        // Extract order parameters from metadata
-        let nonce = metadata
-            .get("nonce")
-            .ok_or_else(|| VenueError::invalid_request("Missing nonce"))?;
-        let expiry = metadata
-            .get("expiry")
-            .ok_or_else(|| VenueError::invalid_request("Missing expiry"))?;
-        let signer_wallet = metadata
-            .get("signer_wallet")
-            .ok_or_else(|| VenueError::invalid_request("Missing signer_wallet"))?;
-        let signer_token = metadata
-            .get("signer_token")
-            .ok_or_else(|| VenueError::invalid_request("Missing signer_token"))?;
-        let signer_amount = metadata
-            .get("signer_amount")
-            .ok_or_else(|| VenueError::invalid_request("Missing signer_amount"))?;
-        let sender_wallet = metadata
-            .get("sender_wallet")
-            .ok_or_else(|| VenueError::invalid_request("Missing sender_wallet"))?;
-        let sender_token = metadata
-            .get("sender_token")
-            .ok_or_else(|| VenueError::invalid_request("Missing sender_token"))?;
-        let sender_amount = metadata
-            .get("sender_amount")
-            .ok_or_else(|| VenueError::invalid_request("Missing sender_amount"))?;
-        let v_str = metadata
-            .get("v")
-            .ok_or_else(|| VenueError::invalid_request("Missing v"))?;
-        let r = metadata
-            .get("r")
-            .ok_or_else(|| VenueError::invalid_request("Missing r"))?;
-        let s = metadata
-            .get("s")
-            .ok_or_else(|| VenueError::invalid_request("Missing s"))?;
+        let signed = Self::signed_order(quote)?;
+        let order = &signed.order;
 
         // Parse values
-        let nonce_u256 = ContractClient::parse_u256(nonce)?;
-        let expiry_u256 = ContractClient::parse_u256(expiry)?;
-        let signer_wallet_addr = ContractClient::parse_address(signer_wallet)?;
-        let signer_token_addr = ContractClient::parse_address(signer_token)?;
-        let signer_amount_u256 = ContractClient::parse_u256(signer_amount)?;
-        let sender_wallet_addr = ContractClient::parse_address(sender_wallet)?;
-        let sender_token_addr = ContractClient::parse_address(sender_token)?;
-        let sender_amount_u256 = ContractClient::parse_u256(sender_amount)?;
-        let v: u8 = v_str
-            .parse()
-            .map_err(|_| VenueError::invalid_request("Invalid v value"))?;
+        let nonce_u256 = ContractClient::parse_u256(&order.nonce)?;
+        let expiry_u256 = U256::from(order.expiry);
+        let signer_wallet_addr = ContractClient::parse_address(&order.signer_wallet)?;
+        let signer_token_addr = ContractClient::parse_address(&order.signer_token)?;
+        let signer_amount_u256 = ContractClient::parse_u256(&order.signer_amount)?;
+        let sender_wallet_addr = ContractClient::parse_address(&order.sender_wallet)?;
+        let sender_token_addr = ContractClient::parse_address(&order.sender_token)?;
+        let sender_amount_u256 = ContractClient::parse_u256(&order.sender_amount)?;
+        let v = signed.v;
 
         // Parse r and s as bytes32
-        let r_bytes = ethers::utils::hex::decode(r.trim_start_matches("0x"))
+        let r_bytes = ethers::utils::hex::decode(signed.r.trim_start_matches("0x"))
             .map_err(|_| VenueError::invalid_request("Invalid r value"))?;
-        let s_bytes = ethers::utils::hex::decode(s.trim_start_matches("0x"))
+        let s_bytes = ethers::utils::hex::decode(signed.s.trim_start_matches("0x"))
             .map_err(|_| VenueError::invalid_request("Invalid s value"))?;
 
         // Function selector for swap(uint256,uint256,address,address,uint256,address,address,uint256,uint8,bytes32,bytes32)
@@ -941,24 +976,25 @@ impl AirswapAdapter {
     ///
     /// # Errors
     ///
-    /// Returns `VenueError::InvalidRequest` if required fields are missing.
+    /// Returns `VenueError::InvalidRequest` if the quote carries no signed
+    /// Airswap order or a required order field is empty.
     pub fn validate_quote_for_execution(&self, quote: &Quote) -> VenueResult<()> {
-        let metadata = quote
-            .metadata()
-            .ok_or_else(|| VenueError::invalid_request("Quote missing metadata"))?;
+        let signed = Self::signed_order(quote)?;
+        let order = &signed.order;
 
         let required_fields = [
-            "nonce",
-            "signer_wallet",
-            "signer_token",
-            "signer_amount",
-            "sender_token",
-            "sender_amount",
-            "signature",
+            ("nonce", &order.nonce),
+            ("signer_wallet", &order.signer_wallet),
+            ("signer_token", &order.signer_token),
+            ("signer_amount", &order.signer_amount),
+            ("sender_token", &order.sender_token),
+            ("sender_amount", &order.sender_amount),
+            ("r", &signed.r),
+            ("s", &signed.s),
         ];
 
-        for field in required_fields {
-            if metadata.get(field).is_none() {
+        for (field, value) in required_fields {
+            if value.is_empty() {
                 return Err(VenueError::invalid_request(format!(
                     "Quote missing required field: {}",
                     field
@@ -968,6 +1004,19 @@ impl AirswapAdapter {
 
         Ok(())
     }
+
+    /// Returns the signed order carried in the quote's execution instructions.
+    fn signed_order(quote: &Quote) -> VenueResult<&SignedAirswapOrder> {
+        match quote
+            .metadata()
+            .and_then(QuoteMetadata::execution_instructions)
+        {
+            Some(ExecutionInstructions::AirswapOrder(signed)) => Ok(signed),
+            _ => Err(VenueError::invalid_request(
+                "Quote missing Airswap signed order",
+            )),
+        }
+    }
 }
 
 impl fmt::Debug for AirswapAdapter {
@@ -991,54 +1040,28 @@ impl VenueAdapter for AirswapAdapter {
     }
 
     async fn request_quote(&self, rfq: &Rfq) -> VenueResult<Quote> {
-        // Check if enabled
-        if !self.config.is_enabled() {
-            return Err(VenueError::venue_unavailable(
-                self.config.venue_id().clone(),
-                "Airswap adapter is disabled",
-            ));
-        }
-
-        // Build RFQ request
-        let request = self.build_rfq_request(rfq)?;
-
-        // Get server URLs - first try config, then discover from Registry
-        let server_urls: Vec<String> = if !self.config.server_urls().is_empty() {
-            self.config.server_urls().to_vec()
-        } else if self.contract_client.is_some() {
-            // Discover servers from Registry contract
-            let (sender_token, _) = self.resolve_tokens(rfq)?;
-            self.discover_servers(&sender_token).await?
+        let quantity = rfq
+            .disclosed_quantity()
+            .ok_or_else(|| VenueError::unsupported_operation(HIDDEN_SIZE_OPERATION))?;
+        let quote = self.fetch_order(rfq, quantity).await?;
+        // A quote for a bucketed size is firmed up at the true size
+        if quantity == rfq.quantity() {
+            Ok(quote)
         } else {
-            Vec::new()
-        };
-
-        if server_urls.is_empty() {
-            return Err(VenueError::invalid_request(
-                "No Airswap server URLs configured and Registry discovery not available",
-            ));
+            Ok(quote.with_firmness(QuoteFirmness::Indicative))
         }
+    }
 
-        // Try each server until one succeeds
-        let mut last_error = None;
-        for server_url in &server_urls {
-            let url = format!("{}/signer-api/v1/getSignerSideOrder", server_url);
-            match self
-                .http_client
-                .post::<AirswapRfqResponse, _>(&url, &request)
-                .await
-            {
-                Ok(response) => {
-                    return self.parse_rfq_response(response, rfq);
-                }
-                Err(e) => {
-                    last_error = Some(e);
-                }
-            }
-        }
+    async fn firm_up_for_rfq(&self, rfq: &Rfq, _quote: &Quote) -> VenueResult<Quote> {
+        self.fetch_order(rfq, rfq.quantity()).await
+    }
 
-        Err(last_error
-            .unwrap_or_else(|| VenueError::internal_error("No Airswap servers available")))
+    fn supports_quantity_disclosure(&self, disclosure: &QuantityDisclosure) -> bool {
+        !matches!(disclosure, QuantityDisclosure::Hidden)
+    }
+
+    fn execution_protocol(&self) -> Option<ExecutionProtocol> {
+        Some(ExecutionProtocol::AirswapOrder)
     }
 
     async fn execute_trade(&self, quote: &Quote) -> VenueResult<ExecutionResult> {
@@ -1364,6 +1387,56 @@ mod tests {
             assert_eq!(result, "1500000000000000000");
         }
 
+        #[tokio::test]
+        async fn masked_rfq_request_never_carries_true_size() {
+            use crate::domain::entities::rfq::RfqBuilder;
+            use crate::domain::value_objects::enums::AssetClass;
+            use crate::domain::value_objects::{CounterpartyId, Instrument, Symbol};
+
+            let adapter = AirswapAdapter::new(test_config()).unwrap();
+            let true_amount = adapter.to_smallest_unit(Decimal::new(125, 1), 18);
+            let masked = |disclosure| {
+                let instrument =
+                    Instrument::builder(Symbol::new("WETH/USDC").unwrap(), AssetClass::CryptoSpot)
+                        .build();
+                RfqBuilder::new(
+                    CounterpartyId::new("client-1"),
+                    instrument,
+                    OrderSide::Sell,
+                    Quantity::new(12.5).unwrap(),
+                    Timestamp::now().add_secs(300),
+                )
+                .quantity_disclosure(disclosure)
+                .build()
+            };
+
+            let bucketed = masked(QuantityDisclosure::Bucketed {
+                bucket: Quantity::new(10.0).unwrap(),
+            });
+            let request = adapter.build_rfq_request(&bucketed).unwrap();
+            assert_eq!(
+                request.sender_amount,
+                adapter.to_smallest_unit(Decimal::from(20), 18)
+            );
+            assert!(
+                !serde_json::to_string(&request)
+                    .unwrap()
+                    .contains(&true_amount)
+            );
+
+            // Signer-side orders need a size, so hidden RFQs are not sent at all
+            let hidden = masked(QuantityDisclosure::Hidden);
+            assert!(!adapter.supports_quantity_disclosure(&QuantityDisclosure::Hidden));
+            assert!(matches!(
+                adapter.build_rfq_request(&hidden),
+                Err(VenueError::UnsupportedOperation { .. })
+            ));
+            assert!(matches!(
+                adapter.request_quote(&hidden).await,
+                Err(VenueError::UnsupportedOperation { .. })
+            ));
+        }
+
         #[test]
         fn to_smallest_unit_6_decimals() {
             let adapter = AirswapAdapter::new(test_config()).unwrap();
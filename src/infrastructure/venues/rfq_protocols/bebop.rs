@@ -27,12 +27,22 @@
 //! let adapter = BebopAdapter::new(config);
 //! ```
 
-use crate::domain::entities::quote::{Quote, QuoteBuilder, QuoteMetadata};
+use crate::domain::entities::quote::{Quote, QuoteBuilder, QuoteFirmness, QuoteMetadata};
 use crate::domain::entities::rfq::Rfq;
+use crate::domain::entities::venue_credential_set::VenueCredentials;
+use crate::domain::value_objects::execution_instructions::{
+    ExecutionInstructions, ExecutionProtocol,
+};
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{Blockchain, OrderSide, Price, VenueId};
+use crate::domain::value_objects::{
+    Blockchain, OrderSide, Price, Quantity, QuantityDisclosure, VenueId,
+};
+use crate::infrastructure::blockchain::{ChainId, SharedTokenRegistry};
+use crate::infrastructure::persistence::raw_exchange_log::RawExchangeLog;
 use crate::infrastructure::venues::error::{VenueError, VenueResult};
+use crate::infrastructure::venues::exchange_recorder::{ExchangeLogPolicy, ExchangeRecorder};
 use crate::infrastructure::venues::http_client::HttpClient;
+use crate::infrastructure::venues::symbol_mapper::SymbolMapper;
 use crate::infrastructure::venues::traits::{ExecutionResult, VenueAdapter, VenueHealth};
 use async_trait::async_trait;
 use reqwest::header::{HeaderMap, HeaderValue};
@@ -40,6 +50,7 @@ use rust_decimal::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
+use std::sync::Arc;
 
 /// Default timeout in milliseconds.
 const DEFAULT_TIMEOUT_MS: u64 = 5000;
@@ -203,8 +214,12 @@ pub struct BebopQuoteRequest {
     pub sell_token: String,
     /// Buy token address.
     pub buy_token: String,
-    /// Sell amount (in smallest unit).
-    pub sell_amount: String,
+    /// Sell amount (in smallest unit); omitted when the RFQ hides its size.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sell_amount: Option<String>,
+    /// Requests indicative pricing, sent when no amount is disclosed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indicative: Option<bool>,
     /// Taker wallet address.
     pub taker_address: String,
     /// Receiver address (optional, defaults to taker).
@@ -213,6 +228,9 @@ pub struct BebopQuoteRequest {
     pub gasless: Option<bool>,
     /// Slippage tolerance in basis points.
     pub slippage_bps: Option<u32>,
+    /// Minimum quote validity in milliseconds requested from makers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quote_ttl_ms: Option<u64>,
 }
 
 /// Batch quote request for multiple token swaps.
@@ -319,7 +337,7 @@ impl BebopConfig {
         );
         map.insert(
             "DAI".to_string(),
-            "0x6B175474E89094C44Da98b954EeddeBC35e4D1".to_string(),
+            "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(),
         );
         map.insert(
             "WBTC".to_string(),
@@ -492,6 +510,10 @@ pub struct BebopAdapter {
     config: BebopConfig,
     /// HTTP client for API requests.
     http_client: HttpClient,
+    /// Mapping from domain symbols to token addresses.
+    symbol_mapper: Arc<SymbolMapper>,
+    /// Token decimals by address, if configured.
+    token_registry: Option<SharedTokenRegistry>,
 }
 
 impl BebopAdapter {
@@ -501,19 +523,24 @@ impl BebopAdapter {
     ///
     /// Returns `VenueError::InternalError` if the HTTP client cannot be created.
     pub fn new(config: BebopConfig) -> VenueResult<Self> {
-        let headers = Self::build_headers(&config)?;
+        let headers = Self::build_headers(config.api_key())?;
         let http_client = HttpClient::with_headers(config.timeout_ms(), headers)?;
+        let symbol_mapper = Arc::new(Self::default_symbol_mapper(&config));
         Ok(Self {
             config,
             http_client,
+            symbol_mapper,
+            token_registry: None,
         })
     }
 
-    /// Builds the default headers for API requests.
-    fn build_headers(config: &BebopConfig) -> VenueResult<HeaderMap> {
+    /// Builds the default headers for API requests authenticated with
+    /// `api_key`.
+    fn build_headers(api_key: &str) -> VenueResult<HeaderMap> {
         let mut headers = HeaderMap::new();
-        let api_key = HeaderValue::from_str(config.api_key())
+        let mut api_key = HeaderValue::from_str(api_key)
             .map_err(|_| VenueError::internal_error("Invalid API key format"))?;
+        api_key.set_sensitive(true);
         headers.insert("x-api-key", api_key);
         Ok(headers)
     }
@@ -525,29 +552,89 @@ impl BebopAdapter {
         &self.config
     }
 
+    /// Builds a strict symbol mapper from the configured token addresses.
+    fn default_symbol_mapper(config: &BebopConfig) -> SymbolMapper {
+        SymbolMapper::strict().with_assets(config.venue_id(), config.token_addresses())
+    }
+
+    /// Replaces the symbol mapper, e.g. with one shared across venues.
+    #[must_use]
+    pub fn with_symbol_mapper(mut self, symbol_mapper: Arc<SymbolMapper>) -> Self {
+        self.symbol_mapper = symbol_mapper;
+        self
+    }
+
+    /// Records raw RFQ requests and responses to `log` under `policy`.
+    #[must_use]
+    pub fn with_exchange_log(
+        mut self,
+        log: Arc<dyn RawExchangeLog>,
+        policy: ExchangeLogPolicy,
+    ) -> Self {
+        let recorder = ExchangeRecorder::new(log, self.config.venue_id().clone(), policy);
+        self.http_client = self.http_client.with_recorder(recorder);
+        self
+    }
+
+    /// Returns a copy of this adapter whose requests authenticate with
+    /// `credentials`.
+    ///
+    /// The copy keeps this adapter's configuration, which still reports the
+    /// platform's API key. Raw exchanges are recorded with the credentials
+    /// redacted.
+    fn with_api_credentials(&self, credentials: &VenueCredentials) -> VenueResult<Self> {
+        let headers = Self::build_headers(credentials.api_key())?;
+        let mut http_client = HttpClient::with_headers(self.config.timeout_ms(), headers)?;
+        if let Some(recorder) = self.http_client.recorder() {
+            http_client =
+                http_client.with_recorder(recorder.with_redacted_values(credentials.secrets()));
+        }
+        Ok(Self {
+            config: self.config.clone(),
+            http_client,
+            symbol_mapper: Arc::clone(&self.symbol_mapper),
+            token_registry: self.token_registry.clone(),
+        })
+    }
+
+    /// Returns the symbol mapper.
+    #[inline]
+    #[must_use]
+    pub fn symbol_mapper(&self) -> &SymbolMapper {
+        &self.symbol_mapper
+    }
+
+    /// Scales amounts with token decimals from `registry`.
+    ///
+    /// Without a registry, or for tokens it does not know, 18 decimals are
+    /// assumed.
+    #[must_use]
+    pub fn with_token_registry(mut self, registry: SharedTokenRegistry) -> Self {
+        self.token_registry = Some(registry);
+        self
+    }
+
+    /// Returns the decimals of the token at `address` on the configured chain.
+    fn token_decimals(&self, address: &str) -> u8 {
+        ChainId::from_u64(self.config.chain().chain_id())
+            .zip(self.token_registry.as_ref())
+            .and_then(|(chain, registry)| registry.read().entry_by_address(address, chain))
+            .map_or(18, |entry| entry.decimals)
+    }
+
     /// Resolves token addresses from an RFQ.
     ///
     /// Returns (sell_token_address, buy_token_address).
     ///
     /// # Errors
     ///
-    /// Returns `VenueError::InvalidRequest` if a token symbol cannot be resolved.
+    /// Returns `VenueError::UnmappedSymbol` if the symbol has no token mapping.
     pub fn resolve_tokens(&self, rfq: &Rfq) -> VenueResult<(String, String)> {
-        let symbol = rfq.instrument().symbol();
-        let base = symbol.base_asset();
-        let quote = symbol.quote_asset();
-
-        let base_address = self
-            .config
-            .resolve_token_address(base)
-            .ok_or_else(|| VenueError::invalid_request(format!("Unknown token: {}", base)))?
-            .clone();
-
-        let quote_address = self
-            .config
-            .resolve_token_address(quote)
-            .ok_or_else(|| VenueError::invalid_request(format!("Unknown token: {}", quote)))?
-            .clone();
+        let tokens = self
+            .symbol_mapper
+            .to_venue(self.config.venue_id(), rfq.instrument().symbol())?;
+        let base_address = tokens.base().to_string();
+        let quote_address = tokens.quote().to_string();
 
         // For Buy side: sell quote token, buy base token
         // For Sell side: sell base token, buy quote token
@@ -565,12 +652,22 @@ impl BebopAdapter {
         amount.trunc().to_string()
     }
 
-    /// Builds a quote request.
+    /// Builds a quote request for the quantity the RFQ discloses.
     ///
     /// # Errors
     ///
     /// Returns `VenueError::InvalidRequest` if required configuration is missing.
     pub fn build_quote_request(&self, rfq: &Rfq) -> VenueResult<BebopQuoteRequest> {
+        self.build_quote_request_for(rfq, rfq.disclosed_quantity())
+    }
+
+    /// Builds a quote request for `quantity`, or an indicative request
+    /// without an amount when `quantity` is `None`.
+    fn build_quote_request_for(
+        &self,
+        rfq: &Rfq,
+        quantity: Option<Quantity>,
+    ) -> VenueResult<BebopQuoteRequest> {
         let (sell_token, buy_token) = self.resolve_tokens(rfq)?;
 
         let taker_address = self
@@ -579,20 +676,63 @@ impl BebopAdapter {
             .ok_or_else(|| VenueError::invalid_request("Wallet address not configured"))?
             .to_string();
 
-        // Determine sell amount based on side
-        let sell_amount = self.to_smallest_unit(rfq.quantity().get(), 18);
+        let sell_decimals = self.token_decimals(&sell_token);
+        let sell_amount = quantity.map(|q| self.to_smallest_unit(q.get(), sell_decimals));
 
         Ok(BebopQuoteRequest {
             sell_token,
             buy_token,
+            indicative: sell_amount.is_none().then_some(true),
             sell_amount,
             taker_address,
             receiver_address: None,
             gasless: Some(self.config.is_gasless()),
             slippage_bps: Some(self.config.slippage_bps()),
+            quote_ttl_ms: None,
         })
     }
 
+    /// Sends a quote request to Bebop for `quantity`, optionally with a
+    /// minimum TTL hint.
+    ///
+    /// A quote priced for anything other than the RFQ's true quantity is
+    /// returned as indicative, to be firmed up before selection.
+    async fn fetch_quote(
+        &self,
+        rfq: &Rfq,
+        quantity: Option<Quantity>,
+        min_ttl_ms: Option<u64>,
+    ) -> VenueResult<Quote> {
+        // Check if enabled
+        if !self.config.is_enabled() {
+            return Err(VenueError::venue_unavailable(
+                self.config.venue_id().clone(),
+                "Bebop adapter is disabled",
+            ));
+        }
+
+        // Build quote request
+        let mut request = self.build_quote_request_for(rfq, quantity)?;
+        request.quote_ttl_ms = min_ttl_ms;
+
+        // Build quote URL
+        let url = self.config.quote_url();
+
+        // Make HTTP POST request to Bebop API
+        let response: BebopQuoteResponse = self
+            .http_client
+            .post_for_rfq(&url, &request, rfq.id())
+            .await?;
+
+        // Parse response into Quote
+        let quote = self.parse_quote_response(response, rfq)?;
+        if quantity == Some(rfq.quantity()) {
+            Ok(quote)
+        } else {
+            Ok(quote.with_firmness(QuoteFirmness::Indicative))
+        }
+    }
+
     /// Calculates the price from a quote response.
     ///
     /// # Errors
@@ -613,8 +753,10 @@ impl BebopAdapter {
             return Err(VenueError::protocol_error("sell_amount is zero"));
         }
 
-        // Price = buy_amount / sell_amount (assuming same decimals, simplified)
-        let price = buy_amount / sell_amount;
+        // Price = buy_amount / sell_amount, rescaled from base units
+        let decimals_diff = i32::from(self.token_decimals(&quote.sell_token))
+            - i32::from(self.token_decimals(&quote.buy_token));
+        let price = buy_amount / sell_amount * 10f64.powi(decimals_diff);
 
         Price::new(price).map_err(|_| VenueError::protocol_error("Invalid price value"))
     }
@@ -680,30 +822,23 @@ impl BebopAdapter {
             valid_until,
         );
 
-        // Add metadata including signature for execution
+        // Carry the order payload through to execution
         let mut metadata = QuoteMetadata::new();
-        metadata.set("quote_id", quote_data.quote_id.clone());
-        metadata.set("signature", quote_data.signature.clone());
-        metadata.set("settlement_address", quote_data.settlement_address.clone());
-        metadata.set("approval_target", quote_data.approval_target.clone());
-        metadata.set("sell_token", quote_data.sell_token.clone());
-        metadata.set("buy_token", quote_data.buy_token.clone());
-        metadata.set("sell_amount", quote_data.sell_amount.clone());
-        metadata.set("buy_amount", quote_data.buy_amount.clone());
-        metadata.set("chain_id", quote_data.chain_id.to_string());
-        metadata.set("gasless", quote_data.gasless.to_string());
-
-        if let Some(tx_data) = &quote_data.tx_data {
-            metadata.set("tx_data", tx_data.clone());
-        }
-
-        if let Some(gas_estimate) = &quote_data.gas_estimate {
-            metadata.set("gas_estimate", gas_estimate.clone());
-        }
-
-        if let Some(receiver) = &quote_data.receiver {
-            metadata.set("receiver", receiver.clone());
-        }
+        metadata.set_execution_instructions(ExecutionInstructions::BebopOrder {
+            quote_id: quote_data.quote_id.clone(),
+            signature: quote_data.signature.clone(),
+            settlement_address: quote_data.settlement_address.clone(),
+            approval_target: quote_data.approval_target.clone(),
+            sell_token: quote_data.sell_token.clone(),
+            buy_token: quote_data.buy_token.clone(),
+            sell_amount: quote_data.sell_amount.clone(),
+            buy_amount: quote_data.buy_amount.clone(),
+            chain_id: quote_data.chain_id,
+            gasless: quote_data.gasless,
+            tx_data: quote_data.tx_data.clone(),
+            gas_estimate: quote_data.gas_estimate.clone(),
+            receiver: quote_data.receiver.clone(),
+        });
 
         builder = builder.metadata(metadata);
 
@@ -714,21 +849,31 @@ impl BebopAdapter {
     ///
     /// # Errors
     ///
-    /// Returns `VenueError::InvalidRequest` if required fields are missing.
+    /// Returns `VenueError::InvalidRequest` if the quote carries no Bebop
+    /// order or a required field is empty.
     pub fn validate_quote_for_execution(&self, quote: &Quote) -> VenueResult<()> {
-        let metadata = quote
+        let Some(ExecutionInstructions::BebopOrder {
+            quote_id,
+            signature,
+            settlement_address,
+            approval_target,
+            ..
+        }) = quote
             .metadata()
-            .ok_or_else(|| VenueError::invalid_request("Quote missing metadata"))?;
+            .and_then(QuoteMetadata::execution_instructions)
+        else {
+            return Err(VenueError::invalid_request("Quote missing Bebop order"));
+        };
 
         let required_fields = [
-            "quote_id",
-            "signature",
-            "settlement_address",
-            "approval_target",
+            ("quote_id", quote_id),
+            ("signature", signature),
+            ("settlement_address", settlement_address),
+            ("approval_target", approval_target),
         ];
 
-        for field in required_fields {
-            if metadata.get(field).is_none() {
+        for (field, value) in required_fields {
+            if value.is_empty() {
                 return Err(VenueError::invalid_request(format!(
                     "Quote missing required field: {}",
                     field
@@ -762,25 +907,39 @@ impl VenueAdapter for BebopAdapter {
     }
 
     async fn request_quote(&self, rfq: &Rfq) -> VenueResult<Quote> {
-        // Check if enabled
-        if !self.config.is_enabled() {
-            return Err(VenueError::venue_unavailable(
-                self.config.venue_id().clone(),
-                "Bebop adapter is disabled",
-            ));
-        }
+        self.fetch_quote(rfq, rfq.disclosed_quantity(), None).await
+    }
 
-        // Build quote request
-        let request = self.build_quote_request(rfq)?;
+    fn supports_ttl_hint(&self) -> bool {
+        true
+    }
 
-        // Build quote URL
-        let url = self.config.quote_url();
+    fn supports_credential_override(&self) -> bool {
+        true
+    }
 
-        // Make HTTP POST request to Bebop API
-        let response: BebopQuoteResponse = self.http_client.post(&url, &request).await?;
+    fn with_credential_override(
+        &self,
+        credentials: &VenueCredentials,
+    ) -> VenueResult<Arc<dyn VenueAdapter>> {
+        Ok(Arc::new(self.with_api_credentials(credentials)?))
+    }
 
-        // Parse response into Quote
-        self.parse_quote_response(response, rfq)
+    async fn request_quote_with_ttl(&self, rfq: &Rfq, min_ttl_ms: u64) -> VenueResult<Quote> {
+        self.fetch_quote(rfq, rfq.disclosed_quantity(), Some(min_ttl_ms))
+            .await
+    }
+
+    async fn firm_up_for_rfq(&self, rfq: &Rfq, _quote: &Quote) -> VenueResult<Quote> {
+        self.fetch_quote(rfq, Some(rfq.quantity()), None).await
+    }
+
+    fn supports_quantity_disclosure(&self, _disclosure: &QuantityDisclosure) -> bool {
+        true
+    }
+
+    fn execution_protocol(&self) -> Option<ExecutionProtocol> {
+        Some(ExecutionProtocol::BebopOrder)
     }
 
     async fn execute_trade(&self, quote: &Quote) -> VenueResult<ExecutionResult> {
@@ -1003,6 +1162,74 @@ mod tests {
             assert_eq!(adapter.config().api_key(), "test-api-key");
         }
 
+        #[test]
+        fn quote_request_serializes_ttl_hint_only_when_set() {
+            let adapter = BebopAdapter::new(test_config()).unwrap();
+            assert!(adapter.supports_ttl_hint());
+
+            let mut request = BebopQuoteRequest {
+                sell_token: "0xsell".to_string(),
+                buy_token: "0xbuy".to_string(),
+                sell_amount: Some("1000".to_string()),
+                indicative: None,
+                taker_address: "0xtaker".to_string(),
+                receiver_address: None,
+                gasless: Some(true),
+                slippage_bps: Some(50),
+                quote_ttl_ms: None,
+            };
+            let json = serde_json::to_value(&request).unwrap();
+            assert!(json.get("quoteTtlMs").is_none());
+
+            request.quote_ttl_ms = Some(30_000);
+            let json = serde_json::to_value(&request).unwrap();
+            assert_eq!(json.get("quoteTtlMs"), Some(&serde_json::json!(30_000)));
+        }
+
+        #[test]
+        fn masked_quote_request_never_carries_true_size() {
+            use crate::domain::entities::rfq::RfqBuilder;
+            use crate::domain::value_objects::enums::AssetClass;
+            use crate::domain::value_objects::{CounterpartyId, Instrument, Symbol};
+
+            let adapter = BebopAdapter::new(test_config()).unwrap();
+            let true_amount = adapter.to_smallest_unit(Decimal::new(125, 1), 18);
+            let masked = |disclosure| {
+                let instrument =
+                    Instrument::builder(Symbol::new("WETH/USDC").unwrap(), AssetClass::CryptoSpot)
+                        .build();
+                RfqBuilder::new(
+                    CounterpartyId::new("client-1"),
+                    instrument,
+                    OrderSide::Sell,
+                    Quantity::new(12.5).unwrap(),
+                    Timestamp::now().add_secs(300),
+                )
+                .quantity_disclosure(disclosure)
+                .build()
+            };
+
+            let bucketed = masked(QuantityDisclosure::Bucketed {
+                bucket: Quantity::new(10.0).unwrap(),
+            });
+            let request = adapter.build_quote_request(&bucketed).unwrap();
+            assert_eq!(
+                request.sell_amount,
+                Some(adapter.to_smallest_unit(Decimal::from(20), 18))
+            );
+            assert!(
+                !serde_json::to_string(&request)
+                    .unwrap()
+                    .contains(&true_amount)
+            );
+
+            let hidden = masked(QuantityDisclosure::Hidden);
+            let json = serde_json::to_value(adapter.build_quote_request(&hidden).unwrap()).unwrap();
+            assert!(json.get("sellAmount").is_none());
+            assert_eq!(json.get("indicative"), Some(&serde_json::json!(true)));
+            assert!(!json.to_string().contains(&true_amount));
+        }
+
         #[test]
         fn debug_impl() {
             let adapter = BebopAdapter::new(test_config()).unwrap();
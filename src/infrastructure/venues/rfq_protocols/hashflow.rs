@@ -25,12 +25,20 @@
 //! let adapter = HashflowAdapter::new(config);
 //! ```
 
-use crate::domain::entities::quote::{Quote, QuoteBuilder, QuoteMetadata};
+use crate::domain::entities::quote::{Quote, QuoteBuilder, QuoteFirmness, QuoteMetadata};
 use crate::domain::entities::rfq::Rfq;
+use crate::domain::entities::venue_credential_set::VenueCredentials;
+use crate::domain::value_objects::execution_instructions::{
+    ExecutionInstructions, ExecutionProtocol,
+};
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{Blockchain, OrderSide, Price, VenueId};
+use crate::domain::value_objects::{Blockchain, Price, Quantity, QuantityDisclosure, VenueId};
+use crate::infrastructure::blockchain::{ChainId, SharedTokenRegistry};
+use crate::infrastructure::persistence::raw_exchange_log::RawExchangeLog;
 use crate::infrastructure::venues::error::{VenueError, VenueResult};
+use crate::infrastructure::venues::exchange_recorder::{ExchangeLogPolicy, ExchangeRecorder};
 use crate::infrastructure::venues::http_client::HttpClient;
+use crate::infrastructure::venues::symbol_mapper::SymbolMapper;
 use crate::infrastructure::venues::traits::{ExecutionResult, VenueAdapter, VenueHealth};
 use async_trait::async_trait;
 use reqwest::header::{HeaderMap, HeaderValue};
@@ -38,6 +46,7 @@ use rust_decimal::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
+use std::sync::Arc;
 
 /// Default timeout in milliseconds.
 const DEFAULT_TIMEOUT_MS: u64 = 5000;
@@ -181,12 +190,19 @@ pub struct HashflowRfqRequest {
     pub base_token: String,
     /// Quote token address.
     pub quote_token: String,
-    /// Base token amount.
-    pub base_token_amount: String,
+    /// Base token amount; omitted when the RFQ hides its size.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_token_amount: Option<String>,
+    /// Requests indicative pricing, sent when no amount is disclosed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indicative: Option<bool>,
     /// Taker wallet address.
     pub wallet: String,
     /// Whether to include fees in the quote.
     pub include_fees: Option<bool>,
+    /// Minimum quote validity in seconds requested from market makers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quote_ttl_secs: Option<u64>,
 }
 
 /// Error response from Hashflow API.
@@ -266,7 +282,7 @@ impl HashflowConfig {
         );
         map.insert(
             "DAI".to_string(),
-            "0x6B175474E89094C44Da98b954EeddeBC35e4D1".to_string(),
+            "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(),
         );
         map.insert(
             "WBTC".to_string(),
@@ -418,6 +434,10 @@ pub struct HashflowAdapter {
     config: HashflowConfig,
     /// HTTP client for API requests.
     http_client: HttpClient,
+    /// Mapping from domain symbols to token addresses.
+    symbol_mapper: Arc<SymbolMapper>,
+    /// Token decimals by address, if configured.
+    token_registry: Option<SharedTokenRegistry>,
 }
 
 impl HashflowAdapter {
@@ -427,19 +447,24 @@ impl HashflowAdapter {
     ///
     /// Returns `VenueError::InternalError` if the HTTP client cannot be created.
     pub fn new(config: HashflowConfig) -> VenueResult<Self> {
-        let headers = Self::build_headers(&config)?;
+        let headers = Self::build_headers(config.api_key())?;
         let http_client = HttpClient::with_headers(config.timeout_ms(), headers)?;
+        let symbol_mapper = Arc::new(Self::default_symbol_mapper(&config));
         Ok(Self {
             config,
             http_client,
+            symbol_mapper,
+            token_registry: None,
         })
     }
 
-    /// Builds the default headers for API requests.
-    fn build_headers(config: &HashflowConfig) -> VenueResult<HeaderMap> {
+    /// Builds the default headers for API requests authenticated with
+    /// `api_key`.
+    fn build_headers(api_key: &str) -> VenueResult<HeaderMap> {
         let mut headers = HeaderMap::new();
-        let auth_value = HeaderValue::from_str(&format!("Bearer {}", config.api_key()))
+        let mut auth_value = HeaderValue::from_str(&format!("Bearer {}", api_key))
             .map_err(|_| VenueError::internal_error("Invalid API key format"))?;
+        auth_value.set_sensitive(true);
         headers.insert("Authorization", auth_value);
         Ok(headers)
     }
@@ -451,29 +476,89 @@ impl HashflowAdapter {
         &self.config
     }
 
+    /// Builds a strict symbol mapper from the configured token addresses.
+    fn default_symbol_mapper(config: &HashflowConfig) -> SymbolMapper {
+        SymbolMapper::strict().with_assets(config.venue_id(), config.token_addresses())
+    }
+
+    /// Replaces the symbol mapper, e.g. with one shared across venues.
+    #[must_use]
+    pub fn with_symbol_mapper(mut self, symbol_mapper: Arc<SymbolMapper>) -> Self {
+        self.symbol_mapper = symbol_mapper;
+        self
+    }
+
+    /// Records raw RFQ requests and responses to `log` under `policy`.
+    #[must_use]
+    pub fn with_exchange_log(
+        mut self,
+        log: Arc<dyn RawExchangeLog>,
+        policy: ExchangeLogPolicy,
+    ) -> Self {
+        let recorder = ExchangeRecorder::new(log, self.config.venue_id().clone(), policy);
+        self.http_client = self.http_client.with_recorder(recorder);
+        self
+    }
+
+    /// Returns a copy of this adapter whose requests authenticate with
+    /// `credentials`.
+    ///
+    /// The copy keeps this adapter's configuration, which still reports the
+    /// platform's API key. Raw exchanges are recorded with the credentials
+    /// redacted.
+    fn with_api_credentials(&self, credentials: &VenueCredentials) -> VenueResult<Self> {
+        let headers = Self::build_headers(credentials.api_key())?;
+        let mut http_client = HttpClient::with_headers(self.config.timeout_ms(), headers)?;
+        if let Some(recorder) = self.http_client.recorder() {
+            http_client =
+                http_client.with_recorder(recorder.with_redacted_values(credentials.secrets()));
+        }
+        Ok(Self {
+            config: self.config.clone(),
+            http_client,
+            symbol_mapper: Arc::clone(&self.symbol_mapper),
+            token_registry: self.token_registry.clone(),
+        })
+    }
+
+    /// Returns the symbol mapper.
+    #[inline]
+    #[must_use]
+    pub fn symbol_mapper(&self) -> &SymbolMapper {
+        &self.symbol_mapper
+    }
+
+    /// Scales amounts with token decimals from `registry`.
+    ///
+    /// Without a registry, or for tokens it does not know, 18 decimals are
+    /// assumed.
+    #[must_use]
+    pub fn with_token_registry(mut self, registry: SharedTokenRegistry) -> Self {
+        self.token_registry = Some(registry);
+        self
+    }
+
+    /// Returns the decimals of the token at `address` on the configured chain.
+    fn token_decimals(&self, address: &str) -> u8 {
+        ChainId::from_u64(self.config.chain().chain_id())
+            .zip(self.token_registry.as_ref())
+            .and_then(|(chain, registry)| registry.read().entry_by_address(address, chain))
+            .map_or(18, |entry| entry.decimals)
+    }
+
     /// Resolves token addresses from an RFQ.
     ///
     /// Returns (base_token_address, quote_token_address).
     ///
     /// # Errors
     ///
-    /// Returns `VenueError::InvalidRequest` if a token symbol cannot be resolved.
+    /// Returns `VenueError::UnmappedSymbol` if the symbol has no token mapping.
     pub fn resolve_tokens(&self, rfq: &Rfq) -> VenueResult<(String, String)> {
-        let symbol = rfq.instrument().symbol();
-        let base = symbol.base_asset();
-        let quote = symbol.quote_asset();
-
-        let base_address = self
-            .config
-            .resolve_token_address(base)
-            .ok_or_else(|| VenueError::invalid_request(format!("Unknown token: {}", base)))?
-            .clone();
-
-        let quote_address = self
-            .config
-            .resolve_token_address(quote)
-            .ok_or_else(|| VenueError::invalid_request(format!("Unknown token: {}", quote)))?
-            .clone();
+        let tokens = self
+            .symbol_mapper
+            .to_venue(self.config.venue_id(), rfq.instrument().symbol())?;
+        let base_address = tokens.base().to_string();
+        let quote_address = tokens.quote().to_string();
 
         Ok((base_address, quote_address))
     }
@@ -486,12 +571,22 @@ impl HashflowAdapter {
         amount.trunc().to_string()
     }
 
-    /// Builds an RFQ request.
+    /// Builds an RFQ request for the quantity the RFQ discloses.
     ///
     /// # Errors
     ///
     /// Returns `VenueError::InvalidRequest` if required configuration is missing.
     pub fn build_rfq_request(&self, rfq: &Rfq) -> VenueResult<HashflowRfqRequest> {
+        self.build_rfq_request_for(rfq, rfq.disclosed_quantity())
+    }
+
+    /// Builds an RFQ request for `quantity`, or an indicative request
+    /// without an amount when `quantity` is `None`.
+    fn build_rfq_request_for(
+        &self,
+        rfq: &Rfq,
+        quantity: Option<Quantity>,
+    ) -> VenueResult<HashflowRfqRequest> {
         let (base_token, quote_token) = self.resolve_tokens(rfq)?;
 
         let wallet = self
@@ -500,11 +595,8 @@ impl HashflowAdapter {
             .ok_or_else(|| VenueError::invalid_request("Wallet address not configured"))?
             .to_string();
 
-        // Determine base token amount based on side
-        let base_token_amount = match rfq.side() {
-            OrderSide::Buy => self.to_smallest_unit(rfq.quantity().get(), 18),
-            OrderSide::Sell => self.to_smallest_unit(rfq.quantity().get(), 18),
-        };
+        let base_decimals = self.token_decimals(&base_token);
+        let base_token_amount = quantity.map(|q| self.to_smallest_unit(q.get(), base_decimals));
 
         let chain_id = self.config.chain().chain_id();
 
@@ -513,12 +605,55 @@ impl HashflowAdapter {
             destination_chain_id: chain_id,
             base_token,
             quote_token,
+            indicative: base_token_amount.is_none().then_some(true),
             base_token_amount,
             wallet,
             include_fees: Some(true),
+            quote_ttl_secs: None,
         })
     }
 
+    /// Sends an RFQ to Hashflow for `quantity`, optionally with a minimum
+    /// TTL hint.
+    ///
+    /// A quote priced for anything other than the RFQ's true quantity is
+    /// returned as indicative, to be firmed up before selection.
+    async fn fetch_quote(
+        &self,
+        rfq: &Rfq,
+        quantity: Option<Quantity>,
+        min_ttl_ms: Option<u64>,
+    ) -> VenueResult<Quote> {
+        // Check if enabled
+        if !self.config.is_enabled() {
+            return Err(VenueError::venue_unavailable(
+                self.config.venue_id().clone(),
+                "Hashflow adapter is disabled",
+            ));
+        }
+
+        // Build RFQ request
+        let mut request = self.build_rfq_request_for(rfq, quantity)?;
+        request.quote_ttl_secs = min_ttl_ms.map(|ms| ms.div_ceil(1000));
+
+        // Build RFQ URL
+        let url = self.config.rfq_url();
+
+        // Make HTTP POST request to Hashflow API
+        let response: HashflowRfqResponse = self
+            .http_client
+            .post_for_rfq(&url, &request, rfq.id())
+            .await?;
+
+        // Parse response into Quote
+        let quote = self.parse_rfq_response(response, rfq)?;
+        if quantity == Some(rfq.quantity()) {
+            Ok(quote)
+        } else {
+            Ok(quote.with_firmness(QuoteFirmness::Indicative))
+        }
+    }
+
     /// Calculates the price from a quote response.
     ///
     /// # Errors
@@ -539,8 +674,11 @@ impl HashflowAdapter {
             return Err(VenueError::protocol_error("base_token_amount is zero"));
         }
 
-        // Assuming 18 decimals for both tokens (simplified)
-        let price = quote_amount / base_amount;
+        // Rescale from base units: both amounts are integers in their
+        // tokens' smallest units
+        let decimals_diff = i32::from(self.token_decimals(&quote.base_token))
+            - i32::from(self.token_decimals(&quote.quote_token));
+        let price = quote_amount / base_amount * 10f64.powi(decimals_diff);
 
         Price::new(price).map_err(|_| VenueError::protocol_error("Invalid price value"))
     }
@@ -592,21 +730,25 @@ impl HashflowAdapter {
             valid_until,
         );
 
-        // Add metadata including signature for execution
+        // Carry the signed quote through to execution
         let mut metadata = QuoteMetadata::new();
-        metadata.set("quote_id", quote_data.quote_id.clone());
-        metadata.set("signature", quote_data.signature.clone());
-        metadata.set("pool", quote_data.pool.clone());
-        metadata.set("nonce", quote_data.nonce.clone());
-        metadata.set("base_token", quote_data.base_token.clone());
-        metadata.set("quote_token", quote_data.quote_token.clone());
-        metadata.set("base_token_amount", quote_data.base_token_amount.clone());
-        metadata.set("quote_token_amount", quote_data.quote_token_amount.clone());
-        metadata.set("txn_deadline", quote_data.txn_deadline.to_string());
-        metadata.set("chain_id", quote_data.chain_id.to_string());
-
-        if let Some(effective) = &quote_data.effective_base_token_amount {
-            metadata.set("effective_base_token_amount", effective.clone());
+        metadata.set_execution_instructions(ExecutionInstructions::HashflowSigned {
+            quote_id: quote_data.quote_id.clone(),
+            signature: quote_data.signature.clone(),
+            pool: quote_data.pool.clone(),
+            nonce: quote_data.nonce.clone(),
+            base_token: quote_data.base_token.clone(),
+            quote_token: quote_data.quote_token.clone(),
+            base_token_amount: quote_data.base_token_amount.clone(),
+            quote_token_amount: quote_data.quote_token_amount.clone(),
+            txn_deadline: quote_data.txn_deadline,
+            chain_id: quote_data.chain_id,
+            effective_base_token_amount: quote_data.effective_base_token_amount.clone(),
+        });
+
+        // Attribute the quote only when a single market maker answered
+        if let Some([maker]) = response.market_makers.as_deref() {
+            metadata.set_maker_identity(maker.mm_id.clone());
         }
 
         builder = builder.metadata(metadata);
@@ -618,16 +760,33 @@ impl HashflowAdapter {
     ///
     /// # Errors
     ///
-    /// Returns `VenueError::InvalidRequest` if required fields are missing.
+    /// Returns `VenueError::InvalidRequest` if the quote carries no signed
+    /// Hashflow quote or a required field is empty.
     pub fn validate_quote_for_execution(&self, quote: &Quote) -> VenueResult<()> {
-        let metadata = quote
+        let Some(ExecutionInstructions::HashflowSigned {
+            quote_id,
+            signature,
+            pool,
+            nonce,
+            ..
+        }) = quote
             .metadata()
-            .ok_or_else(|| VenueError::invalid_request("Quote missing metadata"))?;
+            .and_then(QuoteMetadata::execution_instructions)
+        else {
+            return Err(VenueError::invalid_request(
+                "Quote missing Hashflow signed quote",
+            ));
+        };
 
-        let required_fields = ["quote_id", "signature", "pool", "nonce"];
+        let required_fields = [
+            ("quote_id", quote_id),
+            ("signature", signature),
+            ("pool", pool),
+            ("nonce", nonce),
+        ];
 
-        for field in required_fields {
-            if metadata.get(field).is_none() {
+        for (field, value) in required_fields {
+            if value.is_empty() {
                 return Err(VenueError::invalid_request(format!(
                     "Quote missing required field: {}",
                     field
@@ -661,25 +820,39 @@ impl VenueAdapter for HashflowAdapter {
     }
 
     async fn request_quote(&self, rfq: &Rfq) -> VenueResult<Quote> {
-        // Check if enabled
-        if !self.config.is_enabled() {
-            return Err(VenueError::venue_unavailable(
-                self.config.venue_id().clone(),
-                "Hashflow adapter is disabled",
-            ));
-        }
+        self.fetch_quote(rfq, rfq.disclosed_quantity(), None).await
+    }
 
-        // Build RFQ request
-        let request = self.build_rfq_request(rfq)?;
+    fn supports_ttl_hint(&self) -> bool {
+        true
+    }
 
-        // Build RFQ URL
-        let url = self.config.rfq_url();
+    fn supports_credential_override(&self) -> bool {
+        true
+    }
 
-        // Make HTTP POST request to Hashflow API
-        let response: HashflowRfqResponse = self.http_client.post(&url, &request).await?;
+    fn with_credential_override(
+        &self,
+        credentials: &VenueCredentials,
+    ) -> VenueResult<Arc<dyn VenueAdapter>> {
+        Ok(Arc::new(self.with_api_credentials(credentials)?))
+    }
 
-        // Parse response into Quote
-        self.parse_rfq_response(response, rfq)
+    async fn request_quote_with_ttl(&self, rfq: &Rfq, min_ttl_ms: u64) -> VenueResult<Quote> {
+        self.fetch_quote(rfq, rfq.disclosed_quantity(), Some(min_ttl_ms))
+            .await
+    }
+
+    async fn firm_up_for_rfq(&self, rfq: &Rfq, _quote: &Quote) -> VenueResult<Quote> {
+        self.fetch_quote(rfq, Some(rfq.quantity()), None).await
+    }
+
+    fn supports_quantity_disclosure(&self, _disclosure: &QuantityDisclosure) -> bool {
+        true
+    }
+
+    fn execution_protocol(&self) -> Option<ExecutionProtocol> {
+        Some(ExecutionProtocol::HashflowSigned)
     }
 
     async fn execute_trade(&self, quote: &Quote) -> VenueResult<ExecutionResult> {
@@ -756,6 +929,7 @@ impl VenueAdapter for HashflowAdapter {
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
+    use crate::domain::value_objects::OrderSide;
 
     fn test_config() -> HashflowConfig {
         HashflowConfig::new("test-api-key")
@@ -880,6 +1054,60 @@ mod tests {
             assert!(debug.contains("hashflow"));
         }
 
+        #[tokio::test]
+        async fn credential_override_authenticates_as_the_client_and_redacts_its_key() {
+            use crate::domain::value_objects::RfqId;
+            use crate::infrastructure::persistence::in_memory::InMemoryRawExchangeLog;
+            use crate::infrastructure::venues::exchange_recorder::REDACTED;
+            use wiremock::matchers::{header, method};
+            use wiremock::{Mock, MockServer, ResponseTemplate};
+
+            let server = MockServer::start().await;
+            Mock::given(method("POST"))
+                .and(header("Authorization", "Bearer client-key"))
+                .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                    "error": "key client-key is not enabled for this pair"
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let log = Arc::new(InMemoryRawExchangeLog::new());
+            let adapter = HashflowAdapter::new(test_config())
+                .unwrap()
+                .with_exchange_log(
+                    Arc::clone(&log) as Arc<dyn RawExchangeLog>,
+                    ExchangeLogPolicy::default(),
+                );
+            assert!(adapter.supports_credential_override());
+            let credentials = VenueCredentials::new("client-key").unwrap();
+            let client_adapter = adapter.with_api_credentials(&credentials).unwrap();
+            let rfq_id = RfqId::new_v4();
+
+            let result: VenueResult<serde_json::Value> = client_adapter
+                .http_client
+                .post_for_rfq(
+                    &server.uri(),
+                    &serde_json::json!({ "echo": "client-key" }),
+                    rfq_id,
+                )
+                .await;
+            assert!(result.is_err());
+
+            let exchanges = log.find_by_rfq(rfq_id).await.unwrap();
+            assert_eq!(exchanges.len(), 2);
+            assert_eq!(
+                exchanges.first().unwrap().payload,
+                serde_json::json!({ "echo": REDACTED })
+            );
+            assert!(
+                exchanges
+                    .iter()
+                    .all(|exchange| !exchange.payload.to_string().contains("client-key"))
+            );
+            assert!(!format!("{client_adapter:?}").contains("client-key"));
+        }
+
         #[test]
         fn to_smallest_unit() {
             let adapter = HashflowAdapter::new(test_config()).unwrap();
@@ -910,6 +1138,126 @@ mod tests {
             let health = adapter.health_check().await.unwrap();
             assert!(!health.is_healthy());
         }
+
+        pub(super) fn rfq_for(symbol: &str) -> Rfq {
+            use crate::domain::entities::rfq::RfqBuilder;
+            use crate::domain::value_objects::enums::{AssetClass, SettlementMethod};
+            use crate::domain::value_objects::{CounterpartyId, Instrument, Quantity, Symbol};
+
+            let instrument = Instrument::new(
+                Symbol::new(symbol).unwrap(),
+                AssetClass::CryptoSpot,
+                SettlementMethod::default(),
+            );
+            RfqBuilder::new(
+                CounterpartyId::new("client-1"),
+                instrument,
+                OrderSide::Buy,
+                Quantity::new(1.0).unwrap(),
+                Timestamp::now().add_secs(300),
+            )
+            .build()
+        }
+
+        #[test]
+        fn resolve_tokens_uses_configured_addresses() {
+            let adapter = HashflowAdapter::new(test_config()).unwrap();
+            let (base, quote) = adapter.resolve_tokens(&rfq_for("weth/usdc")).unwrap();
+            assert_eq!(Some(&base), adapter.config().resolve_token_address("WETH"));
+            assert_eq!(Some(&quote), adapter.config().resolve_token_address("USDC"));
+        }
+
+        #[test]
+        fn rfq_request_serializes_ttl_hint_only_when_set() {
+            let adapter = HashflowAdapter::new(test_config()).unwrap();
+            assert!(adapter.supports_ttl_hint());
+
+            let mut request = adapter.build_rfq_request(&rfq_for("WETH/USDC")).unwrap();
+            let json = serde_json::to_value(&request).unwrap();
+            assert!(json.get("quoteTtlSecs").is_none());
+
+            request.quote_ttl_secs = Some(30);
+            let json = serde_json::to_value(&request).unwrap();
+            assert_eq!(json.get("quoteTtlSecs"), Some(&serde_json::json!(30)));
+        }
+
+        #[test]
+        fn masked_rfq_request_never_carries_true_size() {
+            use crate::domain::entities::rfq::RfqBuilder;
+
+            let adapter = HashflowAdapter::new(test_config()).unwrap();
+            let base = rfq_for("WETH/USDC");
+            let true_amount = adapter.to_smallest_unit(Decimal::new(125, 1), 18);
+            let masked = |disclosure| {
+                RfqBuilder::new(
+                    base.client_id().clone(),
+                    base.instrument().clone(),
+                    OrderSide::Buy,
+                    Quantity::new(12.5).unwrap(),
+                    Timestamp::now().add_secs(300),
+                )
+                .quantity_disclosure(disclosure)
+                .build()
+            };
+
+            let bucketed = masked(QuantityDisclosure::Bucketed {
+                bucket: Quantity::new(10.0).unwrap(),
+            });
+            let request = adapter.build_rfq_request(&bucketed).unwrap();
+            assert_eq!(
+                request.base_token_amount,
+                Some(adapter.to_smallest_unit(Decimal::from(20), 18))
+            );
+            assert!(request.indicative.is_none());
+            assert!(
+                !serde_json::to_string(&request)
+                    .unwrap()
+                    .contains(&true_amount)
+            );
+
+            let hidden = masked(QuantityDisclosure::Hidden);
+            let json = serde_json::to_value(adapter.build_rfq_request(&hidden).unwrap()).unwrap();
+            assert!(json.get("baseTokenAmount").is_none());
+            assert_eq!(json.get("indicative"), Some(&serde_json::json!(true)));
+            assert!(!json.to_string().contains(&true_amount));
+        }
+
+        #[test]
+        fn rfq_request_scales_by_registry_decimals() {
+            use crate::infrastructure::blockchain::TokenRegistry;
+
+            let registry = Arc::new(parking_lot::RwLock::new(TokenRegistry::with_common_tokens()));
+            let adapter = HashflowAdapter::new(test_config())
+                .unwrap()
+                .with_token_registry(registry);
+
+            let wbtc = adapter.build_rfq_request(&rfq_for("WBTC/USDC")).unwrap();
+            let usdc = adapter.build_rfq_request(&rfq_for("USDC/WETH")).unwrap();
+
+            assert_eq!(wbtc.base_token_amount.as_deref(), Some("100000000"));
+            assert_eq!(usdc.base_token_amount.as_deref(), Some("1000000"));
+        }
+
+        #[test]
+        fn resolve_tokens_rejects_unmapped_symbol() {
+            let adapter = HashflowAdapter::new(test_config()).unwrap();
+            let result = adapter.resolve_tokens(&rfq_for("DOGE/USDC"));
+            assert!(matches!(result, Err(VenueError::UnmappedSymbol { .. })));
+        }
+
+        #[test]
+        fn with_symbol_mapper_overrides_defaults() {
+            let venue_id = VenueId::new("hashflow");
+            let mapper = SymbolMapper::strict()
+                .with_asset(&venue_id, "DOGE", "0xd0e")
+                .with_asset(&venue_id, "USDC", "0xc0c");
+            let adapter = HashflowAdapter::new(test_config())
+                .unwrap()
+                .with_symbol_mapper(Arc::new(mapper));
+
+            let (base, quote) = adapter.resolve_tokens(&rfq_for("DOGE/USDC")).unwrap();
+            assert_eq!((base.as_str(), quote.as_str()), ("0xd0e", "0xc0c"));
+        }
     }
 
     mod quote_handling {
@@ -944,6 +1292,21 @@ mod tests {
             assert!((price.get().to_f64().unwrap() - 1850.0).abs() < 0.01);
         }
 
+        #[test]
+        fn calculate_price_rescales_mixed_decimals() {
+            use crate::infrastructure::blockchain::TokenRegistry;
+
+            let registry = Arc::new(parking_lot::RwLock::new(TokenRegistry::with_common_tokens()));
+            let adapter = HashflowAdapter::new(test_config())
+                .unwrap()
+                .with_token_registry(registry);
+            let mut quote = test_quote_data();
+            quote.quote_token_amount = "1850000000".to_string(); // 1850 USDC (6 decimals)
+
+            let price = adapter.calculate_price(&quote).unwrap();
+            assert!((price.get().to_f64().unwrap() - 1850.0).abs() < 0.01);
+        }
+
         #[test]
         fn is_quote_expired_false() {
             let adapter = HashflowAdapter::new(test_config()).unwrap();
@@ -968,5 +1331,74 @@ mod tests {
             // Should be around 60 seconds (give or take a few for test execution)
             assert!(ttl > 55 && ttl <= 60);
         }
+
+        fn response_with(makers: Option<Vec<&str>>) -> HashflowRfqResponse {
+            HashflowRfqResponse {
+                status: "success".to_string(),
+                quotes: vec![test_quote_data()],
+                market_makers: makers.map(|ids| {
+                    ids.into_iter()
+                        .map(|id| HashflowMarketMaker {
+                            mm_id: id.to_string(),
+                            name: None,
+                        })
+                        .collect()
+                }),
+            }
+        }
+
+        #[test]
+        fn parse_rfq_response_sets_single_maker_identity() {
+            let adapter = HashflowAdapter::new(test_config()).unwrap();
+            let rfq = super::adapter::rfq_for("ETH/USDC");
+            let quote = adapter
+                .parse_rfq_response(response_with(Some(vec!["mm-alpha"])), &rfq)
+                .unwrap();
+            assert_eq!(
+                quote.metadata().and_then(QuoteMetadata::maker_identity),
+                Some("mm-alpha")
+            );
+        }
+
+        #[test]
+        fn parse_rfq_response_carries_signed_quote_to_execution() {
+            let adapter = HashflowAdapter::new(test_config()).unwrap();
+            let rfq = super::adapter::rfq_for("ETH/USDC");
+            let quote = adapter
+                .parse_rfq_response(response_with(None), &rfq)
+                .unwrap();
+            assert!(matches!(
+                quote
+                    .metadata()
+                    .and_then(QuoteMetadata::execution_instructions),
+                Some(ExecutionInstructions::HashflowSigned { .. })
+            ));
+            assert!(adapter.validate_quote_for_execution(&quote).is_ok());
+
+            let bare = Quote::new(
+                rfq.id(),
+                quote.venue_id().clone(),
+                quote.price(),
+                quote.quantity(),
+                quote.valid_until(),
+            )
+            .unwrap();
+            assert!(adapter.validate_quote_for_execution(&bare).is_err());
+        }
+
+        #[test]
+        fn parse_rfq_response_leaves_ambiguous_maker_unset() {
+            let adapter = HashflowAdapter::new(test_config()).unwrap();
+            let rfq = super::adapter::rfq_for("ETH/USDC");
+            for makers in [None, Some(vec!["mm-alpha", "mm-beta"])] {
+                let quote = adapter
+                    .parse_rfq_response(response_with(makers), &rfq)
+                    .unwrap();
+                assert_eq!(
+                    quote.metadata().and_then(QuoteMetadata::maker_identity),
+                    None
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,639 @@
+//! # Simulated Venue Adapter
+//!
+//! Venue that generates synthetic quotes for integration testing and demos.
+//!
+//! This module provides the [`SimulatedVenueAdapter`] which implements the
+//! [`VenueAdapter`] trait without any external connectivity. Quotes are drawn
+//! around a configurable reference price from a seeded random number
+//! generator, so a given seed and sequence of requests always produces the
+//! same prices, latencies and rejections.
+//!
+//! # Features
+//!
+//! - Configurable reference price, spread and price jitter
+//! - Uniform response latency between a minimum and maximum
+//! - Quote reject probability
+//! - Configurable quote TTL
+//!
+//! Simulated venues report [`VenueAdapter::is_simulated`], so the
+//! [`VenueRegistry`](super::registry::VenueRegistry) only routes to them
+//! when built with `with_simulated_venues(true)`.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::Price;
+//! use otc_rfq::infrastructure::venues::simulated::{
+//!     SimulatedVenueAdapter, SimulatedVenueConfig,
+//! };
+//!
+//! let config = SimulatedVenueConfig::new(Price::new(50_000.0).unwrap())
+//!     .with_spread_bps(20)
+//!     .with_reject_probability(0.1)
+//!     .with_seed(42);
+//!
+//! let adapter = SimulatedVenueAdapter::new(config);
+//! ```
+
+use crate::domain::entities::quote::{Quote, QuoteBuilder};
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::entities::venue::Venue;
+use crate::domain::value_objects::arithmetic::BPS_PER_UNIT;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{OrderSide, Price, RfqId, SettlementMethod, VenueId, VenueType};
+use crate::infrastructure::venues::error::{VenueError, VenueResult};
+use crate::infrastructure::venues::traits::{ExecutionResult, VenueAdapter, VenueHealth};
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+use rust_decimal::Decimal;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Default venue ID for the simulated venue.
+const DEFAULT_VENUE_ID: &str = "simulated";
+
+/// Default timeout in milliseconds.
+const DEFAULT_TIMEOUT_MS: u64 = 1000;
+
+/// Default spread in basis points (0.2%).
+const DEFAULT_SPREAD_BPS: u32 = 20;
+
+/// Default maximum price jitter in basis points.
+const DEFAULT_JITTER_BPS: u32 = 5;
+
+/// Default minimum response latency in milliseconds.
+const DEFAULT_MIN_LATENCY_MS: u64 = 5;
+
+/// Default maximum response latency in milliseconds.
+const DEFAULT_MAX_LATENCY_MS: u64 = 50;
+
+/// Default quote TTL in seconds.
+const DEFAULT_QUOTE_TTL_SECS: u64 = 30;
+
+/// Configuration for the simulated venue.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::Price;
+/// use otc_rfq::infrastructure::venues::simulated::SimulatedVenueConfig;
+///
+/// let config = SimulatedVenueConfig::new(Price::new(100.0).unwrap())
+///     .with_latency_ms(0, 0)
+///     .with_quote_ttl_secs(10);
+///
+/// assert_eq!(config.quote_ttl_secs(), 10);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedVenueConfig {
+    /// Venue ID for this simulated venue.
+    venue_id: VenueId,
+    /// Price quotes are generated around.
+    reference_price: Price,
+    /// Full bid/ask spread in basis points.
+    spread_bps: u32,
+    /// Maximum random offset of the mid from the reference, in basis points.
+    jitter_bps: u32,
+    /// Minimum response latency in milliseconds.
+    min_latency_ms: u64,
+    /// Maximum response latency in milliseconds.
+    max_latency_ms: u64,
+    /// Probability in `[0, 1]` that a quote request is rejected.
+    reject_probability: f64,
+    /// Quote validity in seconds.
+    quote_ttl_secs: u64,
+    /// Random number generator seed.
+    seed: u64,
+    /// Timeout for operations in milliseconds.
+    timeout_ms: u64,
+}
+
+impl SimulatedVenueConfig {
+    /// Creates a configuration quoting around `reference_price`.
+    #[must_use]
+    pub fn new(reference_price: Price) -> Self {
+        Self {
+            venue_id: VenueId::new(DEFAULT_VENUE_ID),
+            reference_price,
+            spread_bps: DEFAULT_SPREAD_BPS,
+            jitter_bps: DEFAULT_JITTER_BPS,
+            min_latency_ms: DEFAULT_MIN_LATENCY_MS,
+            max_latency_ms: DEFAULT_MAX_LATENCY_MS,
+            reject_probability: 0.0,
+            quote_ttl_secs: DEFAULT_QUOTE_TTL_SECS,
+            seed: 0,
+            timeout_ms: DEFAULT_TIMEOUT_MS,
+        }
+    }
+
+    /// Sets the venue ID.
+    #[must_use]
+    pub fn with_venue_id(mut self, venue_id: impl Into<String>) -> Self {
+        self.venue_id = VenueId::new(venue_id);
+        self
+    }
+
+    /// Sets the full bid/ask spread in basis points.
+    #[must_use]
+    pub fn with_spread_bps(mut self, spread_bps: u32) -> Self {
+        self.spread_bps = spread_bps;
+        self
+    }
+
+    /// Sets the maximum random offset of the mid in basis points.
+    #[must_use]
+    pub fn with_jitter_bps(mut self, jitter_bps: u32) -> Self {
+        self.jitter_bps = jitter_bps;
+        self
+    }
+
+    /// Sets the response latency range in milliseconds.
+    ///
+    /// The bounds are swapped if `min_ms` exceeds `max_ms`.
+    #[must_use]
+    pub fn with_latency_ms(mut self, min_ms: u64, max_ms: u64) -> Self {
+        self.min_latency_ms = min_ms.min(max_ms);
+        self.max_latency_ms = min_ms.max(max_ms);
+        self
+    }
+
+    /// Sets the probability that a quote request is rejected.
+    ///
+    /// Clamped to `[0, 1]`; NaN disables rejections.
+    #[must_use]
+    pub fn with_reject_probability(mut self, probability: f64) -> Self {
+        self.reject_probability = if probability.is_nan() {
+            0.0
+        } else {
+            probability.clamp(0.0, 1.0)
+        };
+        self
+    }
+
+    /// Sets the quote validity in seconds.
+    #[must_use]
+    pub fn with_quote_ttl_secs(mut self, secs: u64) -> Self {
+        self.quote_ttl_secs = secs;
+        self
+    }
+
+    /// Sets the random number generator seed.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets the timeout in milliseconds.
+    #[must_use]
+    pub fn with_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Returns the venue ID.
+    #[inline]
+    #[must_use]
+    pub fn venue_id(&self) -> &VenueId {
+        &self.venue_id
+    }
+
+    /// Returns the reference price.
+    #[inline]
+    #[must_use]
+    pub fn reference_price(&self) -> Price {
+        self.reference_price
+    }
+
+    /// Returns the spread in basis points.
+    #[inline]
+    #[must_use]
+    pub fn spread_bps(&self) -> u32 {
+        self.spread_bps
+    }
+
+    /// Returns the maximum price jitter in basis points.
+    #[inline]
+    #[must_use]
+    pub fn jitter_bps(&self) -> u32 {
+        self.jitter_bps
+    }
+
+    /// Returns the minimum response latency in milliseconds.
+    #[inline]
+    #[must_use]
+    pub fn min_latency_ms(&self) -> u64 {
+        self.min_latency_ms
+    }
+
+    /// Returns the maximum response latency in milliseconds.
+    #[inline]
+    #[must_use]
+    pub fn max_latency_ms(&self) -> u64 {
+        self.max_latency_ms
+    }
+
+    /// Returns the reject probability.
+    #[inline]
+    #[must_use]
+    pub fn reject_probability(&self) -> f64 {
+        self.reject_probability
+    }
+
+    /// Returns the quote validity in seconds.
+    #[inline]
+    #[must_use]
+    pub fn quote_ttl_secs(&self) -> u64 {
+        self.quote_ttl_secs
+    }
+
+    /// Returns the random number generator seed.
+    #[inline]
+    #[must_use]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the timeout in milliseconds.
+    #[inline]
+    #[must_use]
+    pub fn timeout_ms(&self) -> u64 {
+        self.timeout_ms
+    }
+}
+
+/// Random outcome of one quote request.
+#[derive(Debug, Clone, Copy)]
+struct QuoteDraw {
+    latency_ms: u64,
+    rejected: bool,
+    jitter_bps: i64,
+}
+
+/// Simulated venue adapter.
+///
+/// Implements the [`VenueAdapter`] trait with synthetic quotes. Each request
+/// draws a latency, a reject decision and a mid offset from the seeded
+/// generator, then applies half the spread on the client's side:
+/// - Buy orders: mid * (1 + spread/2)
+/// - Sell orders: mid * (1 - spread/2)
+///
+/// Execution of an unexpired quote from this venue always succeeds.
+///
+/// # Examples
+///
+/// ```ignore
+/// let adapter = SimulatedVenueAdapter::new(SimulatedVenueConfig::new(price));
+///
+/// registry.register(Arc::new(adapter)).await;
+/// ```
+pub struct SimulatedVenueAdapter {
+    config: SimulatedVenueConfig,
+    rng: Mutex<StdRng>,
+    executions: AtomicU64,
+    cancelled: Mutex<Vec<RfqId>>,
+}
+
+impl SimulatedVenueAdapter {
+    /// Creates a new simulated venue adapter.
+    #[must_use]
+    pub fn new(config: SimulatedVenueConfig) -> Self {
+        let rng = StdRng::seed_from_u64(config.seed());
+        Self {
+            config,
+            rng: Mutex::new(rng),
+            executions: AtomicU64::new(0),
+            cancelled: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Returns the RFQs this venue was told were cancelled, in order.
+    #[must_use]
+    pub fn cancelled_rfqs(&self) -> Vec<RfqId> {
+        self.cancelled.lock().clone()
+    }
+
+    /// Returns the configuration.
+    #[inline]
+    #[must_use]
+    pub fn config(&self) -> &SimulatedVenueConfig {
+        &self.config
+    }
+
+    /// Returns a [`Venue`] entity describing this adapter.
+    #[must_use]
+    pub fn venue(&self) -> Venue {
+        Venue::new(
+            self.config.venue_id().clone(),
+            format!("Simulated ({})", self.config.venue_id()),
+            VenueType::Simulated,
+        )
+    }
+
+    /// Draws the random outcome of the next quote request.
+    fn draw(&self) -> QuoteDraw {
+        let mut rng = self.rng.lock();
+        let latency_ms =
+            rng.random_range(self.config.min_latency_ms()..=self.config.max_latency_ms());
+        let rejected = rng.random_bool(self.config.reject_probability());
+        let jitter = i64::from(self.config.jitter_bps());
+        let jitter_bps = rng.random_range(-jitter..=jitter);
+        QuoteDraw {
+            latency_ms,
+            rejected,
+            jitter_bps,
+        }
+    }
+
+    /// Draws the next outcome and waits out its latency.
+    async fn await_draw(&self) -> VenueResult<QuoteDraw> {
+        let draw = self.draw();
+        if draw.latency_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(draw.latency_ms)).await;
+        }
+
+        if draw.rejected {
+            return Err(VenueError::quote_unavailable(
+                "Simulated venue rejected the request",
+            ));
+        }
+        Ok(draw)
+    }
+
+    /// Builds a quote on `side` of `rfq`.
+    fn build_quote(&self, rfq: &Rfq, side: OrderSide, jitter_bps: i64) -> VenueResult<Quote> {
+        let price = self
+            .quote_price(side, jitter_bps)
+            .ok_or_else(|| VenueError::quote_unavailable("Failed to calculate quote price"))?;
+        let valid_until = Timestamp::now().add_secs(self.config.quote_ttl_secs() as i64);
+
+        Ok(QuoteBuilder::new(
+            rfq.id(),
+            self.config.venue_id().clone(),
+            price,
+            rfq.quantity(),
+            valid_until,
+        )
+        .build())
+    }
+
+    /// Calculates the quote price for a side and mid offset.
+    fn quote_price(&self, side: OrderSide, jitter_bps: i64) -> Option<Price> {
+        let half_spread = Decimal::from(self.config.spread_bps())
+            .checked_div(Decimal::TWO)?
+            .checked_div(BPS_PER_UNIT)?;
+        let jitter = Decimal::from(jitter_bps).checked_div(BPS_PER_UNIT)?;
+
+        let side_multiplier = match side {
+            OrderSide::Buy => Decimal::ONE.checked_add(half_spread)?,
+            OrderSide::Sell => Decimal::ONE.checked_sub(half_spread)?,
+        };
+        let multiplier = Decimal::ONE
+            .checked_add(jitter)?
+            .checked_mul(side_multiplier)?;
+
+        self.config.reference_price().safe_mul(multiplier).ok()
+    }
+}
+
+impl fmt::Debug for SimulatedVenueAdapter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SimulatedVenueAdapter")
+            .field("venue_id", self.config.venue_id())
+            .field("reference_price", &self.config.reference_price())
+            .field("spread_bps", &self.config.spread_bps())
+            .field("seed", &self.config.seed())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl VenueAdapter for SimulatedVenueAdapter {
+    fn venue_id(&self) -> &VenueId {
+        self.config.venue_id()
+    }
+
+    fn timeout_ms(&self) -> u64 {
+        self.config.timeout_ms()
+    }
+
+    async fn request_quote(&self, rfq: &Rfq) -> VenueResult<Quote> {
+        let draw = self.await_draw().await?;
+        self.build_quote(rfq, rfq.side(), draw.jitter_bps)
+    }
+
+    /// Prices both sides from a single draw, as one request.
+    async fn request_two_way_quote(&self, rfq: &Rfq) -> VenueResult<Vec<Quote>> {
+        let draw = self.await_draw().await?;
+        [OrderSide::Buy, OrderSide::Sell]
+            .into_iter()
+            .map(|side| {
+                self.build_quote(rfq, side, draw.jitter_bps)
+                    .map(|quote| quote.with_side(side))
+            })
+            .collect()
+    }
+
+    async fn execute_trade(&self, quote: &Quote) -> VenueResult<ExecutionResult> {
+        if quote.is_expired() {
+            return Err(VenueError::quote_expired("Quote has expired"));
+        }
+
+        if quote.venue_id() != self.config.venue_id() {
+            return Err(VenueError::invalid_request("Quote is not from this venue"));
+        }
+
+        let sequence = self.executions.fetch_add(1, Ordering::Relaxed) + 1;
+        Ok(ExecutionResult::new(
+            quote.id(),
+            self.config.venue_id().clone(),
+            quote.price(),
+            quote.quantity(),
+            SettlementMethod::OffChain,
+        )
+        .with_venue_execution_id(format!("sim-{}", sequence)))
+    }
+
+    async fn health_check(&self) -> VenueResult<VenueHealth> {
+        Ok(VenueHealth::healthy(self.config.venue_id().clone()))
+    }
+
+    fn supports_rfq_cancellation(&self) -> bool {
+        true
+    }
+
+    async fn cancel_rfq(&self, rfq_id: RfqId) -> VenueResult<()> {
+        self.cancelled.lock().push(rfq_id);
+        Ok(())
+    }
+
+    fn is_simulated(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::rfq::RfqBuilder;
+    use crate::domain::value_objects::{AssetClass, CounterpartyId, Instrument, Quantity, Symbol};
+
+    fn test_config(seed: u64) -> SimulatedVenueConfig {
+        SimulatedVenueConfig::new(Price::new(50_000.0).unwrap())
+            .with_latency_ms(0, 0)
+            .with_seed(seed)
+    }
+
+    fn test_rfq(side: OrderSide) -> Rfq {
+        let symbol = Symbol::new("BTC/USD").unwrap();
+        RfqBuilder::new(
+            CounterpartyId::new("test-client"),
+            Instrument::builder(symbol, AssetClass::CryptoSpot).build(),
+            side,
+            Quantity::new(1.0).unwrap(),
+            Timestamp::now().add_secs(300),
+        )
+        .build()
+    }
+
+    /// Returns the price of each of `n` quote requests, `None` for rejects.
+    async fn sample(adapter: &SimulatedVenueAdapter, n: usize) -> Vec<Option<Price>> {
+        let rfq = test_rfq(OrderSide::Buy);
+        let mut prices = Vec::with_capacity(n);
+        for _ in 0..n {
+            prices.push(adapter.request_quote(&rfq).await.ok().map(|q| q.price()));
+        }
+        prices
+    }
+
+    #[tokio::test]
+    async fn same_seed_produces_same_quotes() {
+        let config = test_config(42).with_reject_probability(0.3);
+        let first = sample(&SimulatedVenueAdapter::new(config.clone()), 20).await;
+        let second = sample(&SimulatedVenueAdapter::new(config), 20).await;
+
+        assert_eq!(first, second);
+        assert!(first.iter().any(Option::is_none));
+        assert!(first.iter().any(Option::is_some));
+    }
+
+    #[tokio::test]
+    async fn different_seeds_produce_different_quotes() {
+        let first = sample(&SimulatedVenueAdapter::new(test_config(1)), 20).await;
+        let second = sample(&SimulatedVenueAdapter::new(test_config(2)), 20).await;
+
+        assert_ne!(first, second);
+    }
+
+    #[tokio::test]
+    async fn quotes_stay_within_spread_and_jitter() {
+        let adapter = SimulatedVenueAdapter::new(test_config(7));
+        let reference = Price::new(50_000.0).unwrap();
+        // Half spread (10 bps) plus maximum jitter (5 bps), with margin.
+        let upper = Price::new(50_080.0).unwrap();
+        let lower = Price::new(49_920.0).unwrap();
+
+        for price in sample(&adapter, 50).await {
+            let price = price.unwrap();
+            assert!(price > lower && price < upper, "{}", price);
+        }
+
+        let sell = adapter
+            .request_quote(&test_rfq(OrderSide::Sell))
+            .await
+            .unwrap();
+        assert!(sell.price() < reference.safe_mul(Decimal::new(10_005, 4)).unwrap());
+    }
+
+    #[tokio::test]
+    async fn buy_is_quoted_above_sell_without_jitter() {
+        let adapter = SimulatedVenueAdapter::new(test_config(0).with_jitter_bps(0));
+        let buy = adapter
+            .request_quote(&test_rfq(OrderSide::Buy))
+            .await
+            .unwrap();
+        let sell = adapter
+            .request_quote(&test_rfq(OrderSide::Sell))
+            .await
+            .unwrap();
+
+        assert_eq!(buy.price(), Price::new(50_050.0).unwrap());
+        assert_eq!(sell.price(), Price::new(49_950.0).unwrap());
+    }
+
+    #[tokio::test]
+    async fn two_way_quotes_both_sides_in_one_draw() {
+        let adapter = SimulatedVenueAdapter::new(test_config(0).with_jitter_bps(0));
+        let quotes = adapter
+            .request_two_way_quote(&test_rfq(OrderSide::Buy))
+            .await
+            .unwrap();
+
+        let sides: Vec<_> = quotes.iter().map(|q| (q.side(), q.price())).collect();
+        assert_eq!(
+            sides,
+            vec![
+                (Some(OrderSide::Buy), Price::new(50_050.0).unwrap()),
+                (Some(OrderSide::Sell), Price::new(49_950.0).unwrap()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn always_rejects_at_probability_one() {
+        let adapter = SimulatedVenueAdapter::new(test_config(0).with_reject_probability(1.0));
+        let result = adapter.request_quote(&test_rfq(OrderSide::Buy)).await;
+        assert!(matches!(result, Err(VenueError::QuoteUnavailable { .. })));
+    }
+
+    #[tokio::test]
+    async fn quote_ttl_is_applied() {
+        let adapter = SimulatedVenueAdapter::new(test_config(0).with_quote_ttl_secs(5));
+        let quote = adapter
+            .request_quote(&test_rfq(OrderSide::Buy))
+            .await
+            .unwrap();
+
+        let ttl = Timestamp::now().duration_until(&quote.valid_until());
+        assert!(ttl <= Duration::from_secs(5) && ttl > Duration::from_secs(4));
+    }
+
+    #[tokio::test]
+    async fn executes_own_quotes_with_sequential_ids() {
+        let adapter = SimulatedVenueAdapter::new(test_config(0));
+        let rfq = test_rfq(OrderSide::Buy);
+        let quote = adapter.request_quote(&rfq).await.unwrap();
+
+        let first = adapter.execute_trade(&quote).await.unwrap();
+        let second = adapter.execute_trade(&quote).await.unwrap();
+
+        assert_eq!(first.execution_price(), quote.price());
+        assert_eq!(first.venue_execution_id(), Some("sim-1"));
+        assert_eq!(second.venue_execution_id(), Some("sim-2"));
+    }
+
+    #[tokio::test]
+    async fn is_registered_as_simulated_venue() {
+        let adapter = SimulatedVenueAdapter::new(test_config(0).with_venue_id("sim-a"));
+
+        assert!(adapter.is_simulated());
+        assert_eq!(adapter.venue().venue_type(), VenueType::Simulated);
+        assert_eq!(adapter.venue().id(), &VenueId::new("sim-a"));
+        assert!(adapter.health_check().await.unwrap().is_healthy());
+    }
+
+    #[tokio::test]
+    async fn records_cancelled_rfqs() {
+        let adapter = SimulatedVenueAdapter::new(test_config(0));
+        let rfq = test_rfq(OrderSide::Buy);
+
+        adapter.cancel_rfq(rfq.id()).await.unwrap();
+
+        assert!(adapter.supports_rfq_cancellation());
+        assert_eq!(adapter.cancelled_rfqs(), vec![rfq.id()]);
+    }
+}
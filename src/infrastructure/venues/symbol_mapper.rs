@@ -0,0 +1,382 @@
+//! # Symbol Mapper
+//!
+//! Translation between domain symbols and venue-native identifiers.
+//!
+//! Every venue names assets differently: on-chain RFQ protocols want token
+//! addresses, some APIs want chain-prefixed tickers, and centralized exchanges
+//! want concatenated pairs such as `BTCUSDT`. The [`SymbolMapper`] keeps one
+//! mapping table per venue so adapters share a single, consistent translation
+//! instead of each rolling their own.
+//!
+//! # Modes
+//!
+//! - [`SymbolMappingMode::Strict`] fails with [`VenueError::UnmappedSymbol`]
+//!   when an asset has no entry for the venue.
+//! - [`SymbolMappingMode::Lenient`] falls back to the domain asset code as the
+//!   venue-native identifier.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::domain::value_objects::{Symbol, VenueId};
+//! use otc_rfq::infrastructure::venues::symbol_mapper::SymbolMapper;
+//!
+//! let venue = VenueId::new("hashflow");
+//! let mapper = SymbolMapper::strict()
+//!     .with_asset(&venue, "WETH", "0xc02a")
+//!     .with_asset(&venue, "USDC", "0xa0b8");
+//!
+//! let symbol = Symbol::new("WETH/USDC").unwrap();
+//! let native = mapper.to_venue(&venue, &symbol).unwrap();
+//! assert_eq!(native.base(), "0xc02a");
+//! assert_eq!(mapper.to_domain(&venue, &native).unwrap(), symbol);
+//! ```
+
+use crate::domain::value_objects::{Symbol, VenueId};
+use crate::infrastructure::venues::error::{VenueError, VenueResult};
+use crate::infrastructure::venues::registry::VenueConfig;
+use std::collections::HashMap;
+use std::fmt;
+
+/// How the mapper handles assets without a venue mapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SymbolMappingMode {
+    /// Fail lookups for unmapped assets.
+    #[default]
+    Strict,
+    /// Fall back to the domain asset code for unmapped assets.
+    Lenient,
+}
+
+/// A trading pair expressed in a venue's native identifiers.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VenueSymbol {
+    /// Venue-native identifier of the base asset.
+    base: String,
+    /// Venue-native identifier of the quote asset.
+    quote: String,
+}
+
+impl VenueSymbol {
+    /// Creates a new venue symbol.
+    #[must_use]
+    pub fn new(base: impl Into<String>, quote: impl Into<String>) -> Self {
+        Self {
+            base: base.into(),
+            quote: quote.into(),
+        }
+    }
+
+    /// Returns the venue-native base asset identifier.
+    #[inline]
+    #[must_use]
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// Returns the venue-native quote asset identifier.
+    #[inline]
+    #[must_use]
+    pub fn quote(&self) -> &str {
+        &self.quote
+    }
+
+    /// Returns the pair concatenated without a separator (e.g. `BTCUSDT`).
+    #[must_use]
+    pub fn concatenated(&self) -> String {
+        format!("{}{}", self.base, self.quote)
+    }
+}
+
+impl fmt::Display for VenueSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.base, self.quote)
+    }
+}
+
+/// Mapping table for a single venue.
+#[derive(Debug, Clone, Default)]
+struct VenueSymbolTable {
+    /// Normalized domain asset code -> venue-native identifier.
+    to_venue: HashMap<String, String>,
+    /// Normalized venue-native identifier -> domain asset code.
+    to_domain: HashMap<String, String>,
+}
+
+impl VenueSymbolTable {
+    fn insert(&mut self, asset: &str, native: &str) {
+        let asset = normalize_asset(asset);
+        let native = native.trim().to_string();
+        self.to_domain
+            .insert(normalize_native(&native), asset.clone());
+        self.to_venue.insert(asset, native);
+    }
+}
+
+/// Normalizes a domain asset code for lookup.
+fn normalize_asset(asset: &str) -> String {
+    asset.trim().to_uppercase()
+}
+
+/// Normalizes a venue-native identifier for lookup.
+///
+/// Venue identifiers are compared case-insensitively so that checksummed and
+/// lowercase token addresses resolve to the same asset.
+fn normalize_native(native: &str) -> String {
+    native.trim().to_lowercase()
+}
+
+/// Bidirectional mapping between domain symbols and venue-native identifiers.
+///
+/// Mappings are stored per venue at the asset level, so a single entry for
+/// `WETH` serves every pair that trades it on that venue.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolMapper {
+    /// Handling of unmapped assets.
+    mode: SymbolMappingMode,
+    /// Mapping tables by venue.
+    tables: HashMap<VenueId, VenueSymbolTable>,
+}
+
+impl SymbolMapper {
+    /// Creates an empty mapper with the given mode.
+    #[must_use]
+    pub fn new(mode: SymbolMappingMode) -> Self {
+        Self {
+            mode,
+            tables: HashMap::new(),
+        }
+    }
+
+    /// Creates an empty mapper in strict mode.
+    #[must_use]
+    pub fn strict() -> Self {
+        Self::new(SymbolMappingMode::Strict)
+    }
+
+    /// Creates an empty mapper in lenient mode.
+    #[must_use]
+    pub fn lenient() -> Self {
+        Self::new(SymbolMappingMode::Lenient)
+    }
+
+    /// Adds an asset mapping for a venue.
+    #[must_use]
+    pub fn with_asset(
+        mut self,
+        venue_id: &VenueId,
+        asset: impl AsRef<str>,
+        native: impl AsRef<str>,
+    ) -> Self {
+        self.insert_asset(venue_id, asset, native);
+        self
+    }
+
+    /// Adds several asset mappings for a venue.
+    #[must_use]
+    pub fn with_assets<I, A, N>(mut self, venue_id: &VenueId, mappings: I) -> Self
+    where
+        I: IntoIterator<Item = (A, N)>,
+        A: AsRef<str>,
+        N: AsRef<str>,
+    {
+        for (asset, native) in mappings {
+            self.insert_asset(venue_id, asset, native);
+        }
+        self
+    }
+
+    /// Adds the symbol mappings declared on a venue configuration.
+    #[must_use]
+    pub fn with_venue_config(self, config: &VenueConfig) -> Self {
+        self.with_assets(config.venue_id(), config.symbol_mappings())
+    }
+
+    /// Inserts or replaces an asset mapping for a venue.
+    pub fn insert_asset(
+        &mut self,
+        venue_id: &VenueId,
+        asset: impl AsRef<str>,
+        native: impl AsRef<str>,
+    ) {
+        self.tables
+            .entry(venue_id.clone())
+            .or_default()
+            .insert(asset.as_ref(), native.as_ref());
+    }
+
+    /// Returns the mapping mode.
+    #[inline]
+    #[must_use]
+    pub fn mode(&self) -> SymbolMappingMode {
+        self.mode
+    }
+
+    /// Returns the venue-native identifier for a domain asset, if mapped.
+    #[must_use]
+    pub fn asset_to_venue(&self, venue_id: &VenueId, asset: &str) -> Option<&str> {
+        self.tables
+            .get(venue_id)
+            .and_then(|table| table.to_venue.get(&normalize_asset(asset)))
+            .map(String::as_str)
+    }
+
+    /// Returns the domain asset code for a venue-native identifier, if mapped.
+    #[must_use]
+    pub fn asset_to_domain(&self, venue_id: &VenueId, native: &str) -> Option<&str> {
+        self.tables
+            .get(venue_id)
+            .and_then(|table| table.to_domain.get(&normalize_native(native)))
+            .map(String::as_str)
+    }
+
+    /// Translates a domain symbol into the venue's native identifiers.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VenueError::UnmappedSymbol` in strict mode if either asset has
+    /// no mapping for the venue.
+    pub fn to_venue(&self, venue_id: &VenueId, symbol: &Symbol) -> VenueResult<VenueSymbol> {
+        let base = self.resolve_to_venue(venue_id, symbol, symbol.base_asset())?;
+        let quote = self.resolve_to_venue(venue_id, symbol, symbol.quote_asset())?;
+        Ok(VenueSymbol::new(base, quote))
+    }
+
+    /// Translates venue-native identifiers back into a domain symbol.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VenueError::UnmappedSymbol` in strict mode if either identifier
+    /// has no mapping for the venue, or in lenient mode if the fallback does
+    /// not form a valid symbol.
+    pub fn to_domain(&self, venue_id: &VenueId, venue_symbol: &VenueSymbol) -> VenueResult<Symbol> {
+        let unmapped = || VenueError::unmapped_symbol(venue_id.clone(), venue_symbol.to_string());
+
+        let base = self.resolve_to_domain(venue_id, venue_symbol.base());
+        let quote = self.resolve_to_domain(venue_id, venue_symbol.quote());
+        match (base, quote) {
+            (Some(base), Some(quote)) => {
+                Symbol::new(format!("{}/{}", base, quote)).map_err(|_| unmapped())
+            }
+            _ => Err(unmapped()),
+        }
+    }
+
+    fn resolve_to_venue(
+        &self,
+        venue_id: &VenueId,
+        symbol: &Symbol,
+        asset: &str,
+    ) -> VenueResult<String> {
+        match (self.asset_to_venue(venue_id, asset), self.mode) {
+            (Some(native), _) => Ok(native.to_string()),
+            (None, SymbolMappingMode::Lenient) => Ok(normalize_asset(asset)),
+            (None, SymbolMappingMode::Strict) => Err(VenueError::unmapped_symbol(
+                venue_id.clone(),
+                symbol.to_string(),
+            )),
+        }
+    }
+
+    fn resolve_to_domain(&self, venue_id: &VenueId, native: &str) -> Option<String> {
+        match (self.asset_to_domain(venue_id, native), self.mode) {
+            (Some(asset), _) => Some(asset.to_string()),
+            (None, SymbolMappingMode::Lenient) => Some(normalize_asset(native)),
+            (None, SymbolMappingMode::Strict) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    const WETH: &str = "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2";
+    const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+    fn venue() -> VenueId {
+        VenueId::new("hashflow")
+    }
+
+    fn mapper(mode: SymbolMappingMode) -> SymbolMapper {
+        SymbolMapper::new(mode)
+            .with_asset(&venue(), "WETH", WETH)
+            .with_asset(&venue(), "USDC", USDC)
+    }
+
+    #[test]
+    fn round_trip_mapping() {
+        let mapper = mapper(SymbolMappingMode::Strict);
+        let symbol = Symbol::new("WETH/USDC").unwrap();
+
+        let native = mapper.to_venue(&venue(), &symbol).unwrap();
+        assert_eq!(native, VenueSymbol::new(WETH, USDC));
+        assert_eq!(mapper.to_domain(&venue(), &native).unwrap(), symbol);
+    }
+
+    #[test]
+    fn mappings_are_per_venue() {
+        let other = VenueId::new("bebop");
+        let mapper = mapper(SymbolMappingMode::Strict).with_asset(&other, "WETH", "ethereum:WETH");
+
+        assert_eq!(mapper.asset_to_venue(&venue(), "WETH"), Some(WETH));
+        assert_eq!(mapper.asset_to_venue(&other, "WETH"), Some("ethereum:WETH"));
+        assert!(mapper.asset_to_venue(&other, "USDC").is_none());
+    }
+
+    #[test]
+    fn strict_mode_rejects_unmapped_symbol() {
+        let mapper = mapper(SymbolMappingMode::Strict);
+        let symbol = Symbol::new("WBTC/USDC").unwrap();
+
+        let result = mapper.to_venue(&venue(), &symbol);
+        assert!(matches!(
+            result,
+            Err(VenueError::UnmappedSymbol { ref symbol, .. }) if symbol == "WBTC/USDC"
+        ));
+        assert!(
+            mapper
+                .to_domain(&venue(), &VenueSymbol::new("0xdead", USDC))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn lenient_mode_falls_back_to_asset_code() {
+        let mapper = mapper(SymbolMappingMode::Lenient);
+        let symbol = Symbol::new("WBTC/USDC").unwrap();
+
+        let native = mapper.to_venue(&venue(), &symbol).unwrap();
+        assert_eq!(native, VenueSymbol::new("WBTC", USDC));
+        assert_eq!(mapper.to_domain(&venue(), &native).unwrap(), symbol);
+    }
+
+    #[test]
+    fn lookups_are_case_insensitive() {
+        let mapper = SymbolMapper::strict().with_asset(&venue(), " weth ", WETH);
+
+        assert_eq!(mapper.asset_to_venue(&venue(), "Weth"), Some(WETH));
+        assert_eq!(
+            mapper.asset_to_domain(&venue(), &WETH.to_lowercase()),
+            Some("WETH")
+        );
+        assert_eq!(
+            mapper.asset_to_domain(&venue(), &WETH.to_uppercase()),
+            Some("WETH")
+        );
+    }
+
+    #[test]
+    fn venue_config_mappings_are_loaded() {
+        let config = VenueConfig::new(VenueId::new("binance"))
+            .with_symbol_mapping("BTC", "BTC")
+            .with_symbol_mapping("USD", "USDT");
+        let mapper = SymbolMapper::strict().with_venue_config(&config);
+
+        let native = mapper
+            .to_venue(config.venue_id(), &Symbol::new("BTC/USD").unwrap())
+            .unwrap();
+        assert_eq!(native.concatenated(), "BTCUSDT");
+    }
+}
@@ -24,13 +24,19 @@
 use crate::domain::entities::package_quote::PackageQuote;
 use crate::domain::entities::quote::Quote;
 use crate::domain::entities::rfq::Rfq;
+use crate::domain::entities::venue_credential_set::VenueCredentials;
+use crate::domain::value_objects::execution_instructions::ExecutionProtocol;
 use crate::domain::value_objects::strategy::Strategy;
 use crate::domain::value_objects::timestamp::Timestamp;
-use crate::domain::value_objects::{Price, Quantity, QuoteId, SettlementMethod, TradeId, VenueId};
+use crate::domain::value_objects::{
+    OrderSide, Price, Quantity, QuantityDisclosure, QuoteId, RfqId, SettlementMethod, TradeId,
+    VenueId,
+};
 use crate::infrastructure::venues::error::{VenueError, VenueResult};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::sync::Arc;
 
 /// Health status of a venue.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -470,6 +476,17 @@ pub trait VenueAdapter: Send + Sync + fmt::Debug {
             .unwrap_or(false)
     }
 
+    /// Returns the protocol of the execution instructions this venue needs
+    /// on a quote before it can execute it.
+    ///
+    /// Default implementation returns `None`: the venue executes from the
+    /// quote alone. Venues that replay a signed payload override this so
+    /// that a quote without matching instructions is rejected before any
+    /// state changes.
+    fn execution_protocol(&self) -> Option<ExecutionProtocol> {
+        None
+    }
+
     /// Returns true if the venue supports multi-leg quoting.
     ///
     /// Venues that support multi-leg quoting can provide package quotes
@@ -511,6 +528,174 @@ pub trait VenueAdapter: Send + Sync + fmt::Debug {
     ) -> VenueResult<PackageQuote> {
         Err(VenueError::unsupported_operation("multi-leg quoting"))
     }
+
+    /// Requests quotes on both sides of a two-way RFQ.
+    ///
+    /// Returns one quote per side, each tagged with the side the client
+    /// trades at its price.
+    ///
+    /// # Errors
+    ///
+    /// Any error from [`request_quote`](Self::request_quote).
+    ///
+    /// # Default Implementation
+    ///
+    /// Sends a one-way request per side concurrently. Venues that price both
+    /// sides in a single request should override this method.
+    async fn request_two_way_quote(&self, rfq: &Rfq) -> VenueResult<Vec<Quote>> {
+        let (buy, sell) = (rfq.one_way(OrderSide::Buy), rfq.one_way(OrderSide::Sell));
+        let (buy_quote, sell_quote) =
+            tokio::try_join!(self.request_quote(&buy), self.request_quote(&sell))?;
+        Ok(vec![
+            buy_quote.with_side(OrderSide::Buy),
+            sell_quote.with_side(OrderSide::Sell),
+        ])
+    }
+
+    /// Returns true if the venue accepts a minimum quote TTL hint.
+    ///
+    /// Default implementation returns false. Override this method together
+    /// with [`request_quote_with_ttl`](Self::request_quote_with_ttl).
+    fn supports_ttl_hint(&self) -> bool {
+        false
+    }
+
+    /// Requests a quote that stays valid for at least `min_ttl_ms`.
+    ///
+    /// Used to re-request quotes that came back with a validity shorter
+    /// than the configured floor.
+    ///
+    /// # Errors
+    ///
+    /// - `VenueError::UnsupportedOperation` - Venue doesn't accept a TTL hint
+    /// - Any error from [`request_quote`](Self::request_quote)
+    ///
+    /// # Default Implementation
+    ///
+    /// Returns `VenueError::UnsupportedOperation` by default.
+    async fn request_quote_with_ttl(&self, _rfq: &Rfq, _min_ttl_ms: u64) -> VenueResult<Quote> {
+        Err(VenueError::unsupported_operation("quote TTL hint"))
+    }
+
+    /// Returns true if the venue can act with a counterparty's own
+    /// credentials.
+    ///
+    /// Default implementation returns false. Override this method together
+    /// with [`with_credential_override`](Self::with_credential_override).
+    fn supports_credential_override(&self) -> bool {
+        false
+    }
+
+    /// Returns an adapter for this venue that authenticates with
+    /// `credentials` instead of the platform's.
+    ///
+    /// Used to quote a client's RFQ on its own account with the venue. The
+    /// returned adapter records raw exchanges like this one, with the
+    /// credentials redacted.
+    ///
+    /// # Errors
+    ///
+    /// - `VenueError::UnsupportedOperation` - Venue doesn't accept other credentials
+    /// - `VenueError::InternalError` - The credentials cannot be used, e.g.
+    ///   they are not valid in a header
+    ///
+    /// # Default Implementation
+    ///
+    /// Returns `VenueError::UnsupportedOperation` by default.
+    fn with_credential_override(
+        &self,
+        _credentials: &VenueCredentials,
+    ) -> VenueResult<Arc<dyn VenueAdapter>> {
+        Err(VenueError::unsupported_operation("credential override"))
+    }
+
+    /// Converts an indicative quote into a firm quote.
+    ///
+    /// The venue re-prices the quote and commits to the returned price
+    /// until it expires. The firm price may differ from the indicative one.
+    ///
+    /// # Arguments
+    ///
+    /// * `quote` - The indicative quote to firm up
+    ///
+    /// # Errors
+    ///
+    /// - `VenueError::UnsupportedOperation` - Venue doesn't issue indicative quotes
+    /// - `VenueError::Timeout` - Request timed out
+    /// - `VenueError::QuoteUnavailable` - Venue will no longer quote
+    ///
+    /// # Default Implementation
+    ///
+    /// Returns `VenueError::UnsupportedOperation` by default. Venues that
+    /// issue indicative quotes should override this method.
+    async fn firm_up(&self, _quote: &Quote) -> VenueResult<Quote> {
+        Err(VenueError::unsupported_operation("quote firm-up"))
+    }
+
+    /// Converts an indicative quote for `rfq` into a firm quote at the
+    /// RFQ's true quantity.
+    ///
+    /// Quotes against a masked size (see
+    /// [`QuantityDisclosure`](crate::domain::value_objects::QuantityDisclosure))
+    /// were priced without the true quantity, so the venue is asked again
+    /// with it.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`firm_up`](Self::firm_up).
+    ///
+    /// # Default Implementation
+    ///
+    /// Delegates to [`firm_up`](Self::firm_up). Venues that support masked
+    /// sizes should override this method.
+    async fn firm_up_for_rfq(&self, _rfq: &Rfq, quote: &Quote) -> VenueResult<Quote> {
+        self.firm_up(quote).await
+    }
+
+    /// Returns true if the venue can be sent an RFQ with `disclosure`
+    /// without seeing more than it allows.
+    ///
+    /// Default implementation supports only
+    /// [`QuantityDisclosure::Exact`]: venues that always send the true
+    /// quantity must not be asked to quote masked RFQs.
+    fn supports_quantity_disclosure(&self, disclosure: &QuantityDisclosure) -> bool {
+        !disclosure.is_masked()
+    }
+
+    /// Returns true if the venue can be told that an RFQ was cancelled.
+    ///
+    /// Default implementation returns false. Override this method together
+    /// with [`cancel_rfq`](Self::cancel_rfq).
+    fn supports_rfq_cancellation(&self) -> bool {
+        false
+    }
+
+    /// Notifies the venue that `rfq_id` was cancelled so it stops pricing it.
+    ///
+    /// # Errors
+    ///
+    /// - `VenueError::Connection` - The notice could not be delivered
+    ///
+    /// # Default Implementation
+    ///
+    /// Logs and returns `Ok(())`: venues whose protocol has no cancel
+    /// message simply let their quotes expire.
+    async fn cancel_rfq(&self, rfq_id: RfqId) -> VenueResult<()> {
+        tracing::debug!(
+            venue_id = %self.venue_id(),
+            rfq_id = %rfq_id,
+            "Venue has no RFQ cancel message; ignoring cancellation"
+        );
+        Ok(())
+    }
+
+    /// Returns true if the venue generates simulated quotes.
+    ///
+    /// Simulated venues are excluded from routing unless the
+    /// [`VenueRegistry`](super::registry::VenueRegistry) explicitly allows them.
+    fn is_simulated(&self) -> bool {
+        false
+    }
 }
 
 #[cfg(test)]
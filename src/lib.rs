@@ -12,6 +12,13 @@
 //! - **Infrastructure Layer** (`infrastructure`): External adapters, repositories, and integrations
 //! - **API Layer** (`api`): gRPC, REST, and WebSocket interfaces
 //!
+//! Internal services can call the gRPC API through the typed `client`,
+//! available via the `grpc-client` feature.
+//!
+//! Tests can seed the in-memory repositories and inject repository failures
+//! with `test_support`, available to downstream crates via the `test-util`
+//! feature.
+//!
 //! ## Example
 //!
 //! ```rust,ignore
@@ -29,5 +36,9 @@
 
 pub mod api;
 pub mod application;
+#[cfg(feature = "grpc-client")]
+pub mod client;
 pub mod domain;
 pub mod infrastructure;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_support;
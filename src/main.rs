@@ -26,9 +26,24 @@
 //!
 //! # Run with pretty logging
 //! OTC_RFQ_LOG_FORMAT=pretty cargo run --bin otc-rfq
+//!
+//! # Export traces to an OTLP collector
+//! OTC_RFQ_LOG_OTLP_ENDPOINT=http://localhost:4317 cargo run --bin otc-rfq --features otlp
+//!
+//! # Apply pending database migrations, or only report them
+//! cargo run --bin otc-rfq --features cli -- migrate
+//! cargo run --bin otc-rfq --features cli -- migrate --check
+//!
+//! # Re-encrypt personal data and venue credentials under the first key in OTC_RFQ_PII_KEYS
+//! cargo run --bin otc-rfq --features cli -- rotate-keys --batch-size 500
 //! ```
 
 use anyhow::Context;
+#[cfg(feature = "cli")]
+use clap::{Parser, Subcommand};
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use otc_rfq::application::services::{ReadinessChecker, ShutdownCoordinator};
+use otc_rfq::infrastructure::telemetry;
 use std::sync::Arc;
 use tokio::signal;
 use tokio::sync::watch;
@@ -36,16 +51,56 @@ use tracing::{error, info, warn};
 
 mod config;
 
-use config::{AppConfig, LogFormat};
+use config::{AppConfig, DatabaseConfig, LogFormat};
+
+#[cfg(feature = "cli")]
+#[derive(Parser)]
+#[command(name = "otc-rfq", version)]
+#[command(about = "OTC RFQ Engine", long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[cfg(feature = "cli")]
+#[derive(Subcommand)]
+enum Command {
+    /// Run the gRPC and REST servers (the default)
+    Serve,
+    /// Apply pending database migrations and exit
+    Migrate {
+        /// Only report missing migrations, failing if there are any
+        #[arg(long)]
+        check: bool,
+    },
+    /// Re-encrypt counterparty personal data under the active key and exit
+    RotateKeys {
+        /// Rows read and rewritten per batch
+        #[arg(long, default_value_t = 500)]
+        batch_size: usize,
+    },
+}
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    #[cfg(feature = "cli")]
+    let cli = Cli::parse();
+
     // Load configuration
     let config = AppConfig::load().context("Failed to load configuration")?;
     config.validate().context("Invalid configuration")?;
 
+    #[cfg(feature = "cli")]
+    match cli.command {
+        Some(Command::Migrate { check }) => return migrate(&config.database, check).await,
+        Some(Command::RotateKeys { batch_size }) => {
+            return rotate_keys(&config.database, batch_size).await;
+        }
+        Some(Command::Serve) | None => {}
+    }
+
     // Initialize tracing based on configuration
-    init_tracing(&config);
+    let tracer_provider = init_tracing(&config);
 
     info!(
         version = env!("CARGO_PKG_VERSION"),
@@ -57,20 +112,61 @@ async fn main() -> anyhow::Result<()> {
     // Create shutdown signal channel
     let (shutdown_tx, shutdown_rx) = watch::channel(false);
 
+    // Shared by the servers to refuse new RFQs and drain in-flight aggregations
+    let shutdown_coordinator = ShutdownCoordinator::new(config.shutdown.grace_period());
+
     // Initialize repositories (using in-memory implementations for now)
-    let rfq_repository = create_rfq_repository();
+    let rfq_store: Arc<dyn otc_rfq::infrastructure::persistence::traits::RfqRepository> =
+        Arc::new(otc_rfq::infrastructure::persistence::in_memory::InMemoryRfqRepository::new());
+    let rfq_repository = create_rfq_repository(Arc::clone(&rfq_store));
+    start_rfq_expiry_sweeper(&config, rfq_store);
     let venue_repository = create_venue_repository();
+    let venue_prober = create_venue_prober(&config, Arc::clone(&venue_repository));
     let trade_repository = create_trade_repository();
+    let negotiation_repository = create_negotiation_repository();
     let mm_performance_tracker = create_mm_performance_tracker();
 
+    // Refuse to serve on a schema this build was not written for
+    let database = if config.database.verify_schema {
+        Some(connect_verified(&config.database).await?)
+    } else {
+        None
+    };
+
+    // Shared by the REST readiness probe and the gRPC health service
+    // TODO: Register the venue registry once it is wired
+    let mut readiness =
+        ReadinessChecker::new().with_min_healthy_venues(config.venues.min_healthy_venues);
+    if let Some(pool) = database {
+        use otc_rfq::infrastructure::persistence::postgres::{
+            PostgresPoolCheck, SchemaVersionCheck,
+        };
+
+        readiness = readiness
+            .with_dependency(Arc::new(PostgresPoolCheck::new(pool.clone())))
+            .with_dependency(Arc::new(SchemaVersionCheck::new(pool)));
+    }
+    let readiness = Arc::new(readiness);
+
     // Start servers
-    let grpc_handle = start_grpc_server(&config, Arc::clone(&rfq_repository), shutdown_rx.clone());
+    let grpc_handle = start_grpc_server(
+        &config,
+        Arc::clone(&rfq_repository),
+        Arc::clone(&negotiation_repository),
+        Arc::clone(&readiness),
+        shutdown_coordinator.clone(),
+        shutdown_rx.clone(),
+    );
     let rest_handle = start_rest_server(
         &config,
         Arc::clone(&rfq_repository),
-        Arc::clone(&venue_repository),
+        venue_repository,
         Arc::clone(&trade_repository),
         Some(Arc::clone(&mm_performance_tracker)),
+        negotiation_repository,
+        readiness,
+        venue_prober,
+        shutdown_coordinator.clone(),
         shutdown_rx.clone(),
     );
 
@@ -85,6 +181,15 @@ async fn main() -> anyhow::Result<()> {
 
     info!("Shutdown signal received, initiating graceful shutdown...");
 
+    // Refuse new RFQs and let in-flight aggregations finish or fail
+    let report = shutdown_coordinator.drain().await;
+    info!(
+        in_flight = report.in_flight,
+        cancelled = report.cancelled,
+        abandoned = report.abandoned,
+        "In-flight aggregations drained"
+    );
+
     // Signal all tasks to shutdown
     let _ = shutdown_tx.send(true);
 
@@ -101,49 +206,232 @@ async fn main() -> anyhow::Result<()> {
     }
 
     info!("OTC RFQ Engine stopped");
+    if let Err(e) = tracer_provider.shutdown() {
+        warn!(error = %e, "Failed to flush pending spans");
+    }
+    Ok(())
+}
+
+/// Applies pending migrations, or with `check` only reports them.
+#[cfg(feature = "cli")]
+async fn migrate(database: &DatabaseConfig, check: bool) -> anyhow::Result<()> {
+    use otc_rfq::infrastructure::persistence::postgres::migrations;
+
+    let pool = connect_database(database).await?;
+    if check {
+        let status = migrations::verify_current(&pool).await?;
+        println!(
+            "Applied {} of {} migrations",
+            status.expected.len() - status.missing().len(),
+            status.expected.len()
+        );
+        if !status.is_current() {
+            anyhow::bail!("schema drift: {}", migrations::drift_message(&status));
+        }
+        return Ok(());
+    }
+
+    let applied = migrations::run_pending(&pool).await?;
+    if applied.is_empty() {
+        println!("Schema is up to date");
+    } else {
+        println!("Applied migrations {applied:?}");
+    }
     Ok(())
 }
 
+/// Re-encrypts counterparty personal data and venue credentials under the
+/// active key from the environment.
+#[cfg(feature = "cli")]
+async fn rotate_keys(database: &DatabaseConfig, batch_size: usize) -> anyhow::Result<()> {
+    use otc_rfq::infrastructure::persistence::postgres::{
+        PostgresCounterpartyRepository, PostgresVenueCredentialRepository,
+    };
+    use otc_rfq::infrastructure::persistence::{EnvKeyProvider, KeyProvider};
+
+    let keys: Arc<dyn KeyProvider> =
+        Arc::new(EnvKeyProvider::from_env().context("Failed to load field encryption keys")?);
+    let pool = connect_verified(database).await?;
+    let report = PostgresCounterpartyRepository::new(pool.clone())
+        .with_field_encryption(Arc::clone(&keys))
+        .rotate_keys(batch_size)
+        .await?;
+    println!(
+        "Re-encrypted {} of {} counterparties",
+        report.rotated, report.scanned
+    );
+    let report = PostgresVenueCredentialRepository::new(pool, keys)
+        .rotate_keys(batch_size)
+        .await?;
+    println!(
+        "Re-encrypted {} of {} venue credential sets",
+        report.rotated, report.scanned
+    );
+    Ok(())
+}
+
+/// Connects to the database and fails unless its schema is current.
+async fn connect_verified(database: &DatabaseConfig) -> anyhow::Result<sqlx::PgPool> {
+    use otc_rfq::infrastructure::persistence::postgres::migrations;
+
+    let pool = connect_database(database).await?;
+    let status = migrations::verify_current(&pool)
+        .await
+        .context("Failed to read the applied migrations")?;
+    if !status.is_current() {
+        anyhow::bail!(
+            "schema drift: {}; run `otc-rfq migrate` first",
+            migrations::drift_message(&status)
+        );
+    }
+    info!(
+        version = status.applied.last().copied().unwrap_or_default(),
+        "Database schema is current"
+    );
+    Ok(pool)
+}
+
+/// Opens a connection pool with the configured limits.
+async fn connect_database(database: &DatabaseConfig) -> anyhow::Result<sqlx::PgPool> {
+    sqlx::postgres::PgPoolOptions::new()
+        .max_connections(database.max_connections)
+        .min_connections(database.min_connections)
+        .acquire_timeout(std::time::Duration::from_secs(
+            database.connect_timeout_secs,
+        ))
+        .idle_timeout(std::time::Duration::from_secs(database.idle_timeout_secs))
+        .connect(&database.url)
+        .await
+        .context("Failed to connect to the database")
+}
+
 /// Initializes the tracing subscriber based on configuration.
-fn init_tracing(config: &AppConfig) {
-    use tracing_subscriber::EnvFilter;
+///
+/// Returns the tracer provider so pending spans can be flushed on exit.
+fn init_tracing(config: &AppConfig) -> SdkTracerProvider {
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+    use tracing_subscriber::{EnvFilter, fmt};
 
     let filter =
         EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(&config.log.level));
+    let (provider, export_error) = create_tracer_provider(config);
+    let registry = tracing_subscriber::registry()
+        .with(filter)
+        .with(telemetry::layer(&provider));
 
     match config.log.format {
         LogFormat::Json => {
-            tracing_subscriber::fmt()
-                .with_env_filter(filter)
-                .json()
-                .with_target(config.log.include_target)
+            registry
+                .with(fmt::layer().json().with_target(config.log.include_target))
                 .init();
         }
         LogFormat::Pretty => {
-            tracing_subscriber::fmt()
-                .with_env_filter(filter)
-                .pretty()
-                .with_target(config.log.include_target)
+            registry
+                .with(fmt::layer().pretty().with_target(config.log.include_target))
                 .init();
         }
     }
+
+    if let Some(message) = export_error {
+        warn!("Trace export disabled: {}", message);
+    }
+    provider
 }
 
-/// Creates an in-memory RFQ repository.
-fn create_rfq_repository() -> Arc<dyn otc_rfq::application::use_cases::create_rfq::RfqRepository> {
-    Arc::new(InMemoryRfqRepository::new())
+/// Creates the tracer provider, exporting over OTLP when an endpoint is set.
+///
+/// Returns why export is disabled when an endpoint is set but cannot be used.
+fn create_tracer_provider(config: &AppConfig) -> (SdkTracerProvider, Option<String>) {
+    let Some(endpoint) = &config.log.otlp_endpoint else {
+        return (telemetry::tracer_provider(), None);
+    };
+
+    #[cfg(feature = "otlp")]
+    {
+        match telemetry::init_otlp(endpoint, &config.service_name) {
+            Ok(provider) => (provider, None),
+            Err(e) => (
+                telemetry::tracer_provider(),
+                Some(format!("failed to build OTLP exporter for {endpoint}: {e}")),
+            ),
+        }
+    }
+
+    #[cfg(not(feature = "otlp"))]
+    {
+        (
+            telemetry::tracer_provider(),
+            Some(format!(
+                "OTLP endpoint {endpoint} is set but the otlp feature is not enabled"
+            )),
+        )
+    }
+}
+
+/// Creates the RFQ repository used by the API servers on top of `store`.
+fn create_rfq_repository(
+    store: Arc<dyn otc_rfq::infrastructure::persistence::traits::RfqRepository>,
+) -> Arc<dyn otc_rfq::application::use_cases::create_rfq::RfqRepository> {
+    Arc::new(InMemoryRfqRepository::new(store))
+}
+
+/// Starts the RFQ expiry sweeper, which also fails executions abandoned past
+/// their deadline.
+fn start_rfq_expiry_sweeper(
+    config: &AppConfig,
+    store: Arc<dyn otc_rfq::infrastructure::persistence::traits::RfqRepository>,
+) {
+    use otc_rfq::application::services::{DEFAULT_SWEEP_INTERVAL, RfqExpirySweeper};
+
+    // TODO: Pass the timeout to ExecuteTradeUseCase once trade execution is wired
+    info!(
+        execution_timeout = ?config.execution.timeout(),
+        abandoned_grace = ?config.execution.abandoned_grace(),
+        "Starting RFQ expiry sweeper"
+    );
+    let sweeper =
+        RfqExpirySweeper::new(store).with_execution_grace(config.execution.abandoned_grace());
+    tokio::spawn(async move { sweeper.run(DEFAULT_SWEEP_INTERVAL).await });
 }
 
 /// Creates an in-memory venue repository.
-fn create_venue_repository() -> Arc<dyn otc_rfq::api::rest::handlers::VenueRepository> {
+fn create_venue_repository() -> Arc<InMemoryVenueRepository> {
     Arc::new(InMemoryVenueRepository::new())
 }
 
+/// Creates the venue health prober and starts its background probes.
+fn create_venue_prober(
+    config: &AppConfig,
+    venue_repository: Arc<InMemoryVenueRepository>,
+) -> Arc<otc_rfq::application::services::VenueProber> {
+    use otc_rfq::application::services::VenueProber;
+    use otc_rfq::infrastructure::venues::VenueRegistry;
+
+    // TODO: Share the registry with quote aggregation once venue adapters are constructed here
+    let registry = Arc::new(VenueRegistry::new());
+    let prober = Arc::new(
+        VenueProber::new(registry, venue_repository)
+            .with_failure_threshold(config.venues.probe_failure_threshold)
+            .with_recovery_threshold(config.venues.probe_recovery_threshold),
+    );
+    let background = Arc::clone(&prober);
+    let interval = config.venues.probe_interval();
+    tokio::spawn(async move { background.run(interval).await });
+    prober
+}
+
 /// Creates an in-memory trade repository.
 fn create_trade_repository() -> Arc<dyn otc_rfq::api::rest::handlers::TradeRepository> {
     Arc::new(InMemoryTradeRepository::new())
 }
 
+/// Creates an in-memory negotiation repository.
+fn create_negotiation_repository()
+-> Arc<dyn otc_rfq::infrastructure::persistence::traits::NegotiationRepository> {
+    Arc::new(otc_rfq::infrastructure::persistence::in_memory::InMemoryNegotiationRepository::new())
+}
+
 /// Creates the MM performance tracker with in-memory storage.
 fn create_mm_performance_tracker()
 -> Arc<otc_rfq::domain::services::mm_performance::MmPerformanceTracker> {
@@ -156,10 +444,46 @@ fn create_mm_performance_tracker()
     Arc::new(MmPerformanceTracker::with_defaults(repo))
 }
 
+/// Creates the raw venue exchange log and starts its retention purge.
+fn create_raw_exchange_log()
+-> Arc<dyn otc_rfq::infrastructure::persistence::raw_exchange_log::RawExchangeLog> {
+    use otc_rfq::application::services::{DEFAULT_PURGE_INTERVAL, RawExchangePurgeService};
+    use otc_rfq::infrastructure::persistence::in_memory::InMemoryRawExchangeLog;
+
+    let log: Arc<dyn otc_rfq::infrastructure::persistence::raw_exchange_log::RawExchangeLog> =
+        Arc::new(InMemoryRawExchangeLog::new());
+    let purge = RawExchangePurgeService::new(Arc::clone(&log));
+    tokio::spawn(async move { purge.run(DEFAULT_PURGE_INTERVAL).await });
+    log
+}
+
+/// Creates the venue circuit breakers, exporting transitions as metrics and
+/// persisting state to `state_path` when set.
+fn create_circuit_breakers(
+    state_path: Option<&str>,
+) -> Arc<otc_rfq::application::services::CircuitBreakerRegistry> {
+    use otc_rfq::application::services::{
+        CircuitBreakerConfig, CircuitBreakerRegistry, MetricsCircuitListener,
+    };
+    use otc_rfq::infrastructure::persistence::FileCircuitStateStore;
+
+    let registry = CircuitBreakerRegistry::new(CircuitBreakerConfig::default())
+        .with_listener(Arc::new(MetricsCircuitListener));
+    Arc::new(match state_path {
+        Some(path) => registry.with_store(Arc::new(FileCircuitStateStore::new(path))),
+        None => registry,
+    })
+}
+
 /// Starts the gRPC server.
 fn start_grpc_server(
     config: &AppConfig,
     rfq_repository: Arc<dyn otc_rfq::application::use_cases::create_rfq::RfqRepository>,
+    negotiation_repository: Arc<
+        dyn otc_rfq::infrastructure::persistence::traits::NegotiationRepository,
+    >,
+    readiness: Arc<ReadinessChecker>,
+    shutdown: ShutdownCoordinator,
     mut shutdown_rx: watch::Receiver<bool>,
 ) -> tokio::task::JoinHandle<()> {
     let addr = match config.grpc.socket_addr() {
@@ -170,17 +494,50 @@ fn start_grpc_server(
         }
     };
 
+    let reflection = if config.grpc.enable_reflection {
+        match otc_rfq::api::grpc::reflection_service() {
+            Ok(service) => Some(service),
+            Err(e) => {
+                error!(error = %e, "Failed to build gRPC reflection service");
+                None
+            }
+        }
+    } else {
+        None
+    };
+    let health = config.grpc.enable_health.then(|| {
+        use otc_rfq::api::grpc::GrpcHealthMonitor;
+
+        let (reporter, service) = tonic_health::server::health_reporter();
+        let monitor = GrpcHealthMonitor::new(readiness, reporter)
+            .with_interval(config.grpc.health_check_interval());
+        tokio::spawn(monitor.run(shutdown_rx.clone()));
+        service
+    });
+
     tokio::spawn(async move {
-        use otc_rfq::api::grpc::RfqServiceImpl;
+        use otc_rfq::api::grpc::proto::otc_rfq_v2::rfq_service_server::RfqServiceServer as RfqServiceV2Server;
         use otc_rfq::api::grpc::proto::rfq_service_server::RfqServiceServer;
+        use otc_rfq::api::grpc::{RfqServiceImpl, RfqServiceV2Impl};
         use tonic::transport::Server;
 
-        let service = RfqServiceImpl::new(rfq_repository);
+        // v2 is canonical; v1 is served through it
+        let service = RfqServiceV2Impl::new(rfq_repository)
+            .with_negotiations(negotiation_repository)
+            .with_shutdown(shutdown);
 
-        info!(addr = %addr, "Starting gRPC server");
+        info!(
+            addr = %addr,
+            reflection = reflection.is_some(),
+            health = health.is_some(),
+            "Starting gRPC server"
+        );
 
         let server = Server::builder()
-            .add_service(RfqServiceServer::new(service))
+            .add_service(RfqServiceServer::new(RfqServiceImpl::from(service.clone())))
+            .add_service(RfqServiceV2Server::new(service))
+            .add_optional_service(reflection)
+            .add_optional_service(health)
             .serve_with_shutdown(addr, async move {
                 let _ = shutdown_rx.changed().await;
             });
@@ -194,6 +551,7 @@ fn start_grpc_server(
 }
 
 /// Starts the REST/HTTP server.
+#[allow(clippy::too_many_arguments)]
 fn start_rest_server(
     config: &AppConfig,
     rfq_repository: Arc<dyn otc_rfq::application::use_cases::create_rfq::RfqRepository>,
@@ -202,6 +560,12 @@ fn start_rest_server(
     mm_performance_tracker: Option<
         Arc<otc_rfq::domain::services::mm_performance::MmPerformanceTracker>,
     >,
+    negotiation_repository: Arc<
+        dyn otc_rfq::infrastructure::persistence::traits::NegotiationRepository,
+    >,
+    readiness: Arc<ReadinessChecker>,
+    venue_prober: Arc<otc_rfq::application::services::VenueProber>,
+    shutdown: ShutdownCoordinator,
     mut shutdown_rx: watch::Receiver<bool>,
 ) -> tokio::task::JoinHandle<()> {
     let addr = match config.rest.socket_addr() {
@@ -212,6 +576,36 @@ fn start_rest_server(
         }
     };
 
+    let enable_swagger_ui = config.rest.enable_swagger_ui;
+    let metrics_handle = if config.rest.enable_metrics {
+        match otc_rfq::infrastructure::metrics::install() {
+            Ok(handle) => Some(handle),
+            Err(e) => {
+                error!(error = %e, "Failed to install metrics recorder");
+                None
+            }
+        }
+    } else {
+        None
+    };
+    // TODO: Pass the log to venue adapters once they are constructed here
+    let venue_exchanges = config
+        .venues
+        .log_raw_exchanges
+        .then(create_raw_exchange_log);
+    // TODO: Pass the breakers to the quote aggregation engine once it is constructed here
+    let circuit_breakers = create_circuit_breakers(config.venues.circuit_state_path.as_deref());
+    let tokens = Arc::new(parking_lot::RwLock::new(config.token_registry()));
+    // TODO: Persist to Postgres once the pool is wired
+    let price_bounds = Arc::new(
+        otc_rfq::application::services::PriceBoundsConfigStore::new(
+            Arc::new(
+                otc_rfq::infrastructure::persistence::in_memory::InMemoryPriceBoundsConfigRepository::new(),
+            ),
+            otc_rfq::domain::value_objects::PriceBoundsSettings::default(),
+        ),
+    );
+
     tokio::spawn(async move {
         use otc_rfq::api::rest::handlers::AppState;
         use otc_rfq::api::rest::routes::create_router;
@@ -223,9 +617,66 @@ fn start_rest_server(
             mm_performance_tracker,
             mm_incentive_service: None, // TODO: Initialize when VolumeTracker is available
             fee_engine: None,           // TODO: Initialize when fee configuration is available
+            instrument_reference_data: Some(Arc::new(
+                otc_rfq::infrastructure::persistence::in_memory::InMemoryInstrumentReferenceDataRepository::new(),
+            )),
+            event_store: None, // TODO: Initialize when the event store is wired to the database
+            shutdown: Some(shutdown),
+            readiness: Some(readiness),
+            firm_up: None, // TODO: Initialize when venue adapters are wired for quote selection
+            rfq_templates: Some(Arc::new(
+                otc_rfq::infrastructure::persistence::in_memory::InMemoryRfqTemplateRepository::new(),
+            )),
+            negotiations: Some(negotiation_repository),
+            venue_exchanges,
+            circuit_breakers: Some(circuit_breakers),
+            rfq_summaries: None, // TODO: Initialize when the event store is wired to the database
+            webhooks: None, // TODO: Initialize when webhook subscriptions are wired to the database
+            platform_fee_schedules: Some(Arc::new(
+                otc_rfq::infrastructure::persistence::in_memory::InMemoryPlatformFeeScheduleRepository::new(),
+            )),
+            fee_waivers: Some(Arc::new(
+                otc_rfq::infrastructure::persistence::in_memory::InMemoryFeeWaiverRepository::new(),
+            )),
+            netting: None, // TODO: Initialize when a blockchain client is wired for settlement
+            venue_prober: Some(venue_prober),
+            compliance_export: None, // TODO: Initialize when the event store is wired to the database
+            rfq_cancellations: None, // TODO: Initialize when venue adapters are wired for quote collection
+            venue_request_gate: None, // TODO: Build from venue configs when quote collection is wired
+            settlement_addresses: None, // TODO: Initialize when counterparties are wired to the database
+            tokens: Some(tokens),
+            price_bounds: Some(price_bounds),
+            liquidity: None, // TODO: Initialize when the RFQ and trade repositories are wired to the database
+            aggregation_reports: None, // TODO: Initialize when quote aggregation is wired
+            best_execution: None, // TODO: Initialize when the RFQ and trade repositories are wired to the database
+            rfq_ttl: Some(Arc::new(
+                otc_rfq::application::services::RfqTtlConfigStore::default(),
+            )),
+            dead_letters: None, // TODO: Initialize when the event consumers are wired to the event store
+            // TODO: Persist to Postgres once the pool is wired
+            trading_calendars: Some(Arc::new(
+                otc_rfq::application::services::TradingCalendarService::new(Arc::new(
+                    otc_rfq::infrastructure::persistence::in_memory::InMemoryTradingCalendarRepository::new(),
+                )),
+            )),
+            counterparty_erasure: None, // TODO: Initialize when counterparties and the event store are wired to the database
+            venue_credentials: None, // TODO: Initialize when quote aggregation is wired
+            duplicate_rfqs: Some(Arc::new(
+                otc_rfq::domain::value_objects::DuplicateRfqPolicy::default(),
+            )),
+            forced_transitions: None, // TODO: Initialize when the event store is wired
         });
 
         let router = create_router(state);
+        let router = if enable_swagger_ui {
+            router.merge(otc_rfq::api::rest::openapi::swagger_ui_router())
+        } else {
+            router
+        };
+        let router = match metrics_handle {
+            Some(handle) => router.merge(otc_rfq::api::rest::metrics::metrics_router(handle)),
+            None => router,
+        };
 
         info!(addr = %addr, "Starting REST server");
 
@@ -283,39 +734,73 @@ async fn wait_for_shutdown() {
 // ============================================================================
 
 use otc_rfq::api::rest::handlers::{TradeFilter, TradeRepository, VenueRepository};
+use otc_rfq::api::rest::trade_export::created_within;
 use otc_rfq::application::use_cases::create_rfq::RfqRepository;
 use otc_rfq::domain::entities::rfq::Rfq;
 use otc_rfq::domain::entities::trade::Trade;
 use otc_rfq::domain::entities::venue::Venue;
+use otc_rfq::domain::entities::venue_config_change::{VenueConfigChange, VenueSettings};
+use otc_rfq::domain::value_objects::timestamp::Timestamp;
 use otc_rfq::domain::value_objects::{RfqId, TradeId, VenueId};
+use otc_rfq::infrastructure::persistence::cursor::paginate;
+use otc_rfq::infrastructure::persistence::{PageCursor, RfqListFilter};
 use std::collections::HashMap;
 use tokio::sync::RwLock;
 
 /// In-memory RFQ repository for development/testing.
+///
+/// Shares its storage with the background services that work on the
+/// persistence-level repository.
 #[derive(Debug)]
 struct InMemoryRfqRepository {
-    rfqs: RwLock<HashMap<RfqId, Rfq>>,
+    store: Arc<dyn otc_rfq::infrastructure::persistence::traits::RfqRepository>,
 }
 
 impl InMemoryRfqRepository {
-    fn new() -> Self {
-        Self {
-            rfqs: RwLock::new(HashMap::new()),
-        }
+    fn new(store: Arc<dyn otc_rfq::infrastructure::persistence::traits::RfqRepository>) -> Self {
+        Self { store }
     }
 }
 
 #[async_trait::async_trait]
 impl RfqRepository for InMemoryRfqRepository {
     async fn save(&self, rfq: &Rfq) -> Result<(), String> {
-        let mut rfqs = self.rfqs.write().await;
-        rfqs.insert(rfq.id(), rfq.clone());
-        Ok(())
+        self.store.save(rfq).await.map_err(|e| e.to_string())
     }
 
     async fn find_by_id(&self, id: RfqId) -> Result<Option<Rfq>, String> {
-        let rfqs = self.rfqs.read().await;
-        Ok(rfqs.get(&id).cloned())
+        self.store.get(id).await.map_err(|e| e.to_string())
+    }
+
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> Result<Vec<Rfq>, String> {
+        self.store
+            .list_after(cursor, limit, filter)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn list_offset(
+        &self,
+        offset: usize,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> Result<Vec<Rfq>, String> {
+        self.store
+            .list_offset(offset, limit, filter)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn count_matching(&self, filter: &RfqListFilter) -> Result<u64, String> {
+        self.store
+            .count_matching(filter)
+            .await
+            .map_err(|e| e.to_string())
     }
 }
 
@@ -323,16 +808,29 @@ impl RfqRepository for InMemoryRfqRepository {
 #[derive(Debug)]
 struct InMemoryVenueRepository {
     venues: RwLock<HashMap<VenueId, Venue>>,
+    history: RwLock<Vec<VenueConfigChange>>,
 }
 
 impl InMemoryVenueRepository {
     fn new() -> Self {
         Self {
             venues: RwLock::new(HashMap::new()),
+            history: RwLock::new(Vec::new()),
         }
     }
 }
 
+#[async_trait::async_trait]
+impl otc_rfq::application::services::ProbedVenueRepository for InMemoryVenueRepository {
+    async fn find_by_id(&self, id: &VenueId) -> Result<Option<Venue>, String> {
+        VenueRepository::find_by_id(self, id).await
+    }
+
+    async fn save(&self, venue: &Venue) -> Result<(), String> {
+        VenueRepository::save(self, venue).await
+    }
+}
+
 #[async_trait::async_trait]
 impl VenueRepository for InMemoryVenueRepository {
     async fn find_all(&self) -> Result<Vec<Venue>, String> {
@@ -350,6 +848,42 @@ impl VenueRepository for InMemoryVenueRepository {
         venues.insert(venue.id().clone(), venue.clone());
         Ok(())
     }
+
+    async fn save_with_history(
+        &self,
+        venue: &Venue,
+        changed_by: &str,
+        rollback_of: Option<u64>,
+    ) -> Result<VenueConfigChange, String> {
+        let mut venues = self.venues.write().await;
+        let mut history = self.history.write().await;
+        let previous = venues
+            .get(venue.id())
+            .ok_or_else(|| format!("venue not found: {}", venue.id()))?;
+
+        let change = VenueConfigChange::from_parts(
+            history.len() as u64 + 1,
+            venue.id().clone(),
+            VenueSettings::of(previous),
+            VenueSettings::of(venue),
+            changed_by.to_string(),
+            Timestamp::now(),
+            rollback_of,
+        );
+        venues.insert(venue.id().clone(), venue.clone());
+        history.push(change.clone());
+        Ok(change)
+    }
+
+    async fn history(&self, id: &VenueId) -> Result<Vec<VenueConfigChange>, String> {
+        let history = self.history.read().await;
+        Ok(history
+            .iter()
+            .rev()
+            .filter(|change| change.venue_id() == id)
+            .cloned()
+            .collect())
+    }
 }
 
 /// In-memory trade repository for development/testing.
@@ -377,4 +911,34 @@ impl TradeRepository for InMemoryTradeRepository {
         let trades = self.trades.read().await;
         Ok(trades.get(&id).cloned())
     }
+
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &TradeFilter,
+    ) -> Result<Vec<Trade>, String> {
+        let trades = self
+            .trades
+            .read()
+            .await
+            .values()
+            .filter(|trade| filter.matches(trade))
+            .cloned()
+            .collect();
+        Ok(paginate(trades, cursor, limit, PageCursor::from_trade))
+    }
+
+    async fn count(
+        &self,
+        filter: &TradeFilter,
+        from: Option<Timestamp>,
+        to: Option<Timestamp>,
+    ) -> Result<u64, String> {
+        let trades = self.trades.read().await;
+        Ok(trades
+            .values()
+            .filter(|trade| filter.matches(trade) && created_within(trade, from, to))
+            .count() as u64)
+    }
 }
@@ -0,0 +1,622 @@
+//! # Failure Injection
+//!
+//! Repository decorators that fail or delay chosen calls.
+//!
+//! A [`FaultInjector`] counts calls per [`Operation`] and, before each call
+//! reaches the wrapped repository, sleeps for the configured latency and
+//! returns a scheduled error instead of forwarding if one is due. Failed
+//! calls never reach the wrapped repository, so its state is exactly what
+//! the successful calls left behind.
+//!
+//! Errors can be scheduled for the next call of an operation
+//! ([`FaultInjector::fail_next`], repeatable to fail several in a row), for
+//! a numbered call ([`FaultInjector::fail_nth`]), or for every call until
+//! cleared ([`FaultInjector::fail_always`]).
+
+use crate::application::error::{ApplicationError, ApplicationResult, InfrastructureError};
+use crate::application::use_cases::create_rfq::RfqRepository as RfqStore;
+use crate::application::use_cases::execute_trade::TradeRepository as TradeStore;
+use crate::domain::entities::rfq::Rfq;
+use crate::domain::entities::trade::Trade;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{CounterpartyId, OrderSide, RfqId, Symbol, TradeId, VenueId};
+use crate::infrastructure::persistence::cursor::PageCursor;
+use crate::infrastructure::persistence::traits::{
+    RepositoryError, RepositoryResult, RfqListFilter, RfqRepository, TradeListFilter,
+    TradeRepository,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+/// Kind of repository call a fault applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation {
+    /// Inserts and updates.
+    Save,
+    /// Lookups, listings and counts.
+    Read,
+    /// Deletes.
+    Delete,
+}
+
+#[derive(Debug, Default)]
+struct FaultState {
+    calls: HashMap<Operation, usize>,
+    scheduled: HashMap<(Operation, usize), RepositoryError>,
+    always: HashMap<Operation, RepositoryError>,
+    latency: HashMap<Operation, Duration>,
+}
+
+/// Per-operation call counts and the faults scheduled against them.
+#[derive(Debug, Default)]
+pub struct FaultInjector {
+    state: Mutex<FaultState>,
+}
+
+impl FaultInjector {
+    /// Creates an injector with no faults.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fails the next `operation` call not already scheduled to fail.
+    ///
+    /// Calling this twice fails the next two calls.
+    pub fn fail_next(&self, operation: Operation, error: RepositoryError) {
+        let mut state = self.lock();
+        let mut call = state.calls.get(&operation).copied().unwrap_or(0) + 1;
+        while state.scheduled.contains_key(&(operation, call)) {
+            call += 1;
+        }
+        state.scheduled.insert((operation, call), error);
+    }
+
+    /// Fails the `n`th `operation` call, counted from 1 since the injector
+    /// was created.
+    pub fn fail_nth(&self, operation: Operation, n: usize, error: RepositoryError) {
+        self.lock().scheduled.insert((operation, n), error);
+    }
+
+    /// Fails every `operation` call until [`clear`](Self::clear) is called.
+    ///
+    /// Calls scheduled with [`fail_next`](Self::fail_next) or
+    /// [`fail_nth`](Self::fail_nth) fail with their own error.
+    pub fn fail_always(&self, operation: Operation, error: RepositoryError) {
+        self.lock().always.insert(operation, error);
+    }
+
+    /// Delays every `operation` call by `latency`, failed ones included.
+    pub fn set_latency(&self, operation: Operation, latency: Duration) {
+        self.lock().latency.insert(operation, latency);
+    }
+
+    /// Removes every scheduled fault and latency; call counts are kept.
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.scheduled.clear();
+        state.always.clear();
+        state.latency.clear();
+    }
+
+    /// Returns how many `operation` calls have been made, failed ones
+    /// included.
+    #[must_use]
+    pub fn calls(&self, operation: Operation) -> usize {
+        self.lock().calls.get(&operation).copied().unwrap_or(0)
+    }
+
+    /// Records an `operation` call, applying its latency and fault.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error scheduled for this call, if any.
+    pub async fn enter(&self, operation: Operation) -> RepositoryResult<()> {
+        let (latency, fault) = {
+            let mut state = self.lock();
+            let call = state.calls.entry(operation).or_insert(0);
+            *call += 1;
+            let call = *call;
+            let fault = state
+                .scheduled
+                .remove(&(operation, call))
+                .or_else(|| state.always.get(&operation).cloned());
+            (state.latency.get(&operation).copied(), fault)
+        };
+        if let Some(latency) = latency {
+            tokio::time::sleep(latency).await;
+        }
+        fault.map_or(Ok(()), Err)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, FaultState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Maps a repository error for the use case traits.
+///
+/// Transient failures stay retryable; the rest become
+/// `ApplicationError::RepositoryError` as the services map them.
+fn application_error(error: RepositoryError) -> ApplicationError {
+    if error.is_transient() {
+        InfrastructureError::from(error).into()
+    } else {
+        ApplicationError::RepositoryError(error.to_string())
+    }
+}
+
+/// [`RfqRepository`] decorator that fails or delays chosen calls.
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::infrastructure::persistence::in_memory::InMemoryRfqRepository;
+/// use otc_rfq::infrastructure::persistence::RepositoryError;
+/// use otc_rfq::test_support::FailingRfqRepository;
+/// use std::sync::Arc;
+///
+/// let rfqs = FailingRfqRepository::new(Arc::new(InMemoryRfqRepository::new()));
+/// // The second save conflicts, as if another instance got there first
+/// rfqs.fail_nth_save(2, RepositoryError::version_conflict("Rfq", "rfq-1", 2, 3));
+/// ```
+#[derive(Debug)]
+pub struct FailingRfqRepository {
+    inner: Arc<dyn RfqRepository>,
+    faults: FaultInjector,
+}
+
+impl FailingRfqRepository {
+    /// Wraps `inner` with no faults configured.
+    #[must_use]
+    pub fn new(inner: Arc<dyn RfqRepository>) -> Self {
+        Self {
+            inner,
+            faults: FaultInjector::new(),
+        }
+    }
+
+    /// Fails the next save with `error`.
+    pub fn fail_next_save(&self, error: RepositoryError) {
+        self.faults.fail_next(Operation::Save, error);
+    }
+
+    /// Fails the `n`th save, counted from 1, with `error`.
+    pub fn fail_nth_save(&self, n: usize, error: RepositoryError) {
+        self.faults.fail_nth(Operation::Save, n, error);
+    }
+
+    /// Fails every save with `error` until the faults are cleared.
+    pub fn fail_all_saves(&self, error: RepositoryError) {
+        self.faults.fail_always(Operation::Save, error);
+    }
+
+    /// Fails the next lookup, listing or count with `error`.
+    pub fn fail_next_read(&self, error: RepositoryError) {
+        self.faults.fail_next(Operation::Read, error);
+    }
+
+    /// Delays every call by `latency`.
+    pub fn set_latency(&self, latency: Duration) {
+        for operation in [Operation::Save, Operation::Read, Operation::Delete] {
+            self.faults.set_latency(operation, latency);
+        }
+    }
+
+    /// Returns how many `operation` calls have been made.
+    #[must_use]
+    pub fn calls(&self, operation: Operation) -> usize {
+        self.faults.calls(operation)
+    }
+
+    /// Returns the fault injector, for faults not covered by the shortcuts.
+    #[must_use]
+    pub fn faults(&self) -> &FaultInjector {
+        &self.faults
+    }
+}
+
+#[async_trait]
+impl RfqRepository for FailingRfqRepository {
+    async fn save(&self, rfq: &Rfq) -> RepositoryResult<()> {
+        self.faults.enter(Operation::Save).await?;
+        self.inner.save(rfq).await
+    }
+
+    async fn get(&self, id: RfqId) -> RepositoryResult<Option<Rfq>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.get(id).await
+    }
+
+    async fn find_active(&self) -> RepositoryResult<Vec<Rfq>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.find_active().await
+    }
+
+    async fn find_by_client(&self, client_id: &CounterpartyId) -> RepositoryResult<Vec<Rfq>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.find_by_client(client_id).await
+    }
+
+    async fn find_by_venue(&self, venue_id: &VenueId) -> RepositoryResult<Vec<Rfq>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.find_by_venue(venue_id).await
+    }
+
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> RepositoryResult<Vec<Rfq>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.list_after(cursor, limit, filter).await
+    }
+
+    async fn list_offset(
+        &self,
+        offset: usize,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> RepositoryResult<Vec<Rfq>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.list_offset(offset, limit, filter).await
+    }
+
+    async fn count_matching(&self, filter: &RfqListFilter) -> RepositoryResult<u64> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.count_matching(filter).await
+    }
+
+    async fn find_crossing_candidates(
+        &self,
+        symbol: &Symbol,
+        side: OrderSide,
+        valid_after: Timestamp,
+    ) -> RepositoryResult<Vec<Rfq>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner
+            .find_crossing_candidates(symbol, side, valid_after)
+            .await
+    }
+
+    async fn find_pending_activation(&self, before: Timestamp) -> RepositoryResult<Vec<Rfq>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.find_pending_activation(before).await
+    }
+
+    async fn delete(&self, id: RfqId) -> RepositoryResult<bool> {
+        self.faults.enter(Operation::Delete).await?;
+        self.inner.delete(id).await
+    }
+
+    async fn count(&self) -> RepositoryResult<u64> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.count().await
+    }
+
+    async fn count_active(&self) -> RepositoryResult<u64> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.count_active().await
+    }
+}
+
+#[async_trait]
+impl RfqStore for FailingRfqRepository {
+    async fn save(&self, rfq: &Rfq) -> Result<(), String> {
+        RfqRepository::save(self, rfq)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn find_by_id(&self, id: RfqId) -> Result<Option<Rfq>, String> {
+        RfqRepository::get(self, id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> Result<Vec<Rfq>, String> {
+        RfqRepository::list_after(self, cursor, limit, filter)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn list_offset(
+        &self,
+        offset: usize,
+        limit: usize,
+        filter: &RfqListFilter,
+    ) -> Result<Vec<Rfq>, String> {
+        RfqRepository::list_offset(self, offset, limit, filter)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn count_matching(&self, filter: &RfqListFilter) -> Result<u64, String> {
+        RfqRepository::count_matching(self, filter)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// [`TradeRepository`] decorator that fails or delays chosen calls.
+#[derive(Debug)]
+pub struct FailingTradeRepository {
+    inner: Arc<dyn TradeRepository>,
+    faults: FaultInjector,
+}
+
+impl FailingTradeRepository {
+    /// Wraps `inner` with no faults configured.
+    #[must_use]
+    pub fn new(inner: Arc<dyn TradeRepository>) -> Self {
+        Self {
+            inner,
+            faults: FaultInjector::new(),
+        }
+    }
+
+    /// Fails the next save with `error`.
+    pub fn fail_next_save(&self, error: RepositoryError) {
+        self.faults.fail_next(Operation::Save, error);
+    }
+
+    /// Fails the `n`th save, counted from 1, with `error`.
+    pub fn fail_nth_save(&self, n: usize, error: RepositoryError) {
+        self.faults.fail_nth(Operation::Save, n, error);
+    }
+
+    /// Fails every save with `error` until the faults are cleared.
+    pub fn fail_all_saves(&self, error: RepositoryError) {
+        self.faults.fail_always(Operation::Save, error);
+    }
+
+    /// Fails the next lookup, listing or count with `error`.
+    pub fn fail_next_read(&self, error: RepositoryError) {
+        self.faults.fail_next(Operation::Read, error);
+    }
+
+    /// Delays every call by `latency`.
+    pub fn set_latency(&self, latency: Duration) {
+        for operation in [Operation::Save, Operation::Read, Operation::Delete] {
+            self.faults.set_latency(operation, latency);
+        }
+    }
+
+    /// Returns how many `operation` calls have been made.
+    #[must_use]
+    pub fn calls(&self, operation: Operation) -> usize {
+        self.faults.calls(operation)
+    }
+
+    /// Returns the fault injector, for faults not covered by the shortcuts.
+    #[must_use]
+    pub fn faults(&self) -> &FaultInjector {
+        &self.faults
+    }
+}
+
+#[async_trait]
+impl TradeRepository for FailingTradeRepository {
+    async fn save(&self, trade: &Trade) -> RepositoryResult<()> {
+        self.faults.enter(Operation::Save).await?;
+        self.inner.save(trade).await
+    }
+
+    async fn get(&self, id: TradeId) -> RepositoryResult<Option<Trade>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.get(id).await
+    }
+
+    async fn get_by_rfq(&self, rfq_id: RfqId) -> RepositoryResult<Option<Trade>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.get_by_rfq(rfq_id).await
+    }
+
+    async fn find_pending_settlement(&self) -> RepositoryResult<Vec<Trade>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.find_pending_settlement().await
+    }
+
+    async fn find_by_venue(&self, venue_id: &VenueId) -> RepositoryResult<Vec<Trade>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.find_by_venue(venue_id).await
+    }
+
+    async fn list_after(
+        &self,
+        cursor: Option<&PageCursor>,
+        limit: usize,
+        filter: &TradeListFilter,
+    ) -> RepositoryResult<Vec<Trade>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.list_after(cursor, limit, filter).await
+    }
+
+    async fn find_settled(&self) -> RepositoryResult<Vec<Trade>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.find_settled().await
+    }
+
+    async fn find_failed(&self) -> RepositoryResult<Vec<Trade>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.find_failed().await
+    }
+
+    async fn find_due_for_settlement_retry(
+        &self,
+        now: Timestamp,
+        limit: usize,
+    ) -> RepositoryResult<Vec<Trade>> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.find_due_for_settlement_retry(now, limit).await
+    }
+
+    async fn delete(&self, id: TradeId) -> RepositoryResult<bool> {
+        self.faults.enter(Operation::Delete).await?;
+        self.inner.delete(id).await
+    }
+
+    async fn count(&self) -> RepositoryResult<u64> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.count().await
+    }
+
+    async fn count_pending_settlement(&self) -> RepositoryResult<u64> {
+        self.faults.enter(Operation::Read).await?;
+        self.inner.count_pending_settlement().await
+    }
+}
+
+#[async_trait]
+impl TradeStore for FailingTradeRepository {
+    async fn save(&self, trade: &Trade) -> ApplicationResult<()> {
+        TradeRepository::save(self, trade)
+            .await
+            .map_err(application_error)
+    }
+
+    async fn find_by_id(&self, id: TradeId) -> ApplicationResult<Option<Trade>> {
+        TradeRepository::get(self, id)
+            .await
+            .map_err(application_error)
+    }
+
+    async fn find_by_rfq_id(&self, rfq_id: RfqId) -> ApplicationResult<Vec<Trade>> {
+        TradeRepository::get_by_rfq(self, rfq_id)
+            .await
+            .map(|trade| trade.into_iter().collect())
+            .map_err(application_error)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::in_memory::{
+        InMemoryRfqRepository, InMemoryTradeRepository,
+    };
+    use crate::test_support::{FixtureBuilder, RfqSeed};
+
+    async fn seeded() -> (FailingRfqRepository, Rfq) {
+        let fixtures = FixtureBuilder::new()
+            .with_rfq(RfqSeed::new("rfq", "client-1", "BTC/USD"))
+            .build()
+            .await
+            .unwrap();
+        let rfq = fixtures.rfq("rfq").unwrap().clone();
+        (FailingRfqRepository::new(fixtures.rfqs), rfq)
+    }
+
+    fn lost() -> RepositoryError {
+        RepositoryError::connection_lost("reset by peer")
+    }
+
+    #[tokio::test]
+    async fn next_save_fails_once_without_reaching_the_repository() {
+        let (rfqs, rfq) = seeded().await;
+        let mut updated = rfq.clone();
+        updated.start_quote_collection().unwrap();
+        rfqs.fail_next_save(lost());
+
+        let error = RfqRepository::save(&rfqs, &updated).await.unwrap_err();
+        assert!(matches!(error, RepositoryError::ConnectionLost(_)));
+        let stored = RfqRepository::get(&rfqs, rfq.id()).await.unwrap().unwrap();
+        assert_eq!(stored.version(), rfq.version());
+
+        RfqRepository::save(&rfqs, &updated).await.unwrap();
+        assert_eq!(rfqs.calls(Operation::Save), 2);
+        assert_eq!(rfqs.calls(Operation::Read), 1);
+    }
+
+    #[tokio::test]
+    async fn queued_and_numbered_faults_fire_on_their_calls() {
+        let rfqs = FailingRfqRepository::new(Arc::new(InMemoryRfqRepository::new()));
+        rfqs.fail_next_read(lost());
+        rfqs.fail_next_read(RepositoryError::timeout("slow"));
+        rfqs.faults()
+            .fail_nth(Operation::Read, 4, RepositoryError::query("bad"));
+
+        let mut results = Vec::new();
+        for _ in 0..5 {
+            results.push(RfqRepository::count(&rfqs).await);
+        }
+
+        assert!(matches!(
+            results.as_slice(),
+            [
+                Err(RepositoryError::ConnectionLost(_)),
+                Err(RepositoryError::Timeout(_)),
+                Ok(0),
+                Err(RepositoryError::Query(_)),
+                Ok(0),
+            ]
+        ));
+        assert_eq!(rfqs.calls(Operation::Save), 0);
+    }
+
+    #[tokio::test]
+    async fn persistent_faults_last_until_cleared() {
+        let (rfqs, rfq) = seeded().await;
+        rfqs.fail_all_saves(lost());
+
+        let mut updated = rfq;
+        updated.start_quote_collection().unwrap();
+        assert!(RfqRepository::save(&rfqs, &updated).await.is_err());
+        assert!(RfqRepository::save(&rfqs, &updated).await.is_err());
+
+        rfqs.faults().clear();
+        RfqRepository::save(&rfqs, &updated).await.unwrap();
+        assert_eq!(rfqs.calls(Operation::Save), 3);
+    }
+
+    #[tokio::test]
+    async fn latency_delays_only_the_configured_operation() {
+        let latency = Duration::from_millis(50);
+        let (rfqs, rfq) = seeded().await;
+        rfqs.faults().set_latency(Operation::Read, latency);
+        rfqs.faults().fail_next(Operation::Read, lost());
+
+        let start = std::time::Instant::now();
+        assert!(RfqRepository::get(&rfqs, rfq.id()).await.is_err());
+        assert!(RfqRepository::get(&rfqs, rfq.id()).await.unwrap().is_some());
+        assert!(start.elapsed() >= latency * 2);
+
+        let start = std::time::Instant::now();
+        RfqRepository::delete(&rfqs, RfqId::new_v4()).await.unwrap();
+        assert!(start.elapsed() < latency);
+    }
+
+    #[tokio::test]
+    async fn use_case_traits_keep_transient_trade_failures_retryable() {
+        let trades = FailingTradeRepository::new(Arc::new(InMemoryTradeRepository::new()));
+        trades.fail_next_read(lost());
+        trades.fail_next_read(RepositoryError::query("syntax error"));
+
+        let transient = TradeStore::find_by_id(&trades, TradeId::new_v4())
+            .await
+            .unwrap_err();
+        assert!(transient.is_retryable());
+
+        let permanent = TradeStore::find_by_id(&trades, TradeId::new_v4())
+            .await
+            .unwrap_err();
+        assert!(matches!(permanent, ApplicationError::RepositoryError(_)));
+
+        assert!(
+            TradeStore::find_by_rfq_id(&trades, RfqId::new_v4())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(trades.calls(Operation::Read), 3);
+    }
+}
@@ -0,0 +1,440 @@
+//! # Fixtures
+//!
+//! Seeds the in-memory repositories with data that agrees with itself.
+//!
+//! Every RFQ seeded through [`FixtureBuilder`] has its client, the venues
+//! quoting it and its instrument seeded as well, whether or not they were
+//! added explicitly: clients as KYC-approved counterparties, venues as
+//! enabled venue configurations backed by a market maker counterparty, and
+//! instruments as reference data.
+
+use crate::domain::entities::counterparty::{Counterparty, CounterpartyType, KycStatus};
+use crate::domain::entities::quote::Quote;
+use crate::domain::entities::rfq::{Rfq, RfqBuilder};
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::enums::AssetClass;
+use crate::domain::value_objects::timestamp::Timestamp;
+use crate::domain::value_objects::{
+    CounterpartyId, Instrument, InstrumentReferenceData, OrderSide, Price, Quantity, Symbol,
+    VenueId,
+};
+use crate::infrastructure::persistence::in_memory::{
+    InMemoryCounterpartyRepository, InMemoryInstrumentReferenceDataRepository,
+    InMemoryRfqRepository, InMemoryTradeRepository, InMemoryVenueRepository,
+};
+use crate::infrastructure::persistence::traits::{
+    CounterpartyRepository, InstrumentReferenceDataRepository, RepositoryError, RfqRepository,
+    VenueRepository,
+};
+use crate::infrastructure::venues::registry::VenueConfig;
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// How long seeded RFQs stay open unless told otherwise.
+const DEFAULT_RFQ_TTL_SECS: i64 = 300;
+
+/// How long seeded quotes stay valid.
+const QUOTE_VALIDITY_SECS: i64 = 60;
+
+/// Errors from building fixtures.
+#[derive(Debug, Error)]
+pub enum FixtureError {
+    /// A seed value was rejected.
+    #[error("invalid fixture: {0}")]
+    Invalid(String),
+
+    /// A seeded entity could not be built.
+    #[error("domain error: {0}")]
+    Domain(#[from] DomainError),
+
+    /// A seeded entity could not be saved.
+    #[error("repository error: {0}")]
+    Repository(#[from] RepositoryError),
+}
+
+/// An RFQ to seed.
+///
+/// Seeded in `Created`, or in `QuotesReceived` once it has quotes.
+#[derive(Debug, Clone)]
+pub struct RfqSeed {
+    label: String,
+    client: String,
+    symbol: String,
+    side: OrderSide,
+    quantity: f64,
+    expires_at: Option<Timestamp>,
+    quotes: Vec<(String, f64)>,
+}
+
+impl RfqSeed {
+    /// Creates a seed for a one-unit buy of `symbol` by `client`, looked up
+    /// afterwards by `label`.
+    #[must_use]
+    pub fn new(
+        label: impl Into<String>,
+        client: impl Into<String>,
+        symbol: impl Into<String>,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            client: client.into(),
+            symbol: symbol.into(),
+            side: OrderSide::Buy,
+            quantity: 1.0,
+            expires_at: None,
+            quotes: Vec::new(),
+        }
+    }
+
+    /// Sets the side.
+    #[must_use]
+    pub fn with_side(mut self, side: OrderSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Sets the quantity.
+    #[must_use]
+    pub fn with_quantity(mut self, quantity: f64) -> Self {
+        self.quantity = quantity;
+        self
+    }
+
+    /// Sets the deadline; by default five minutes after the fixture time.
+    #[must_use]
+    pub fn with_expires_at(mut self, expires_at: Timestamp) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Adds a quote for the full quantity from `venue` at `price`.
+    #[must_use]
+    pub fn with_quote(mut self, venue: impl Into<String>, price: f64) -> Self {
+        self.quotes.push((venue.into(), price));
+        self
+    }
+}
+
+/// An RFQ added to a [`FixtureBuilder`].
+#[derive(Debug, Clone)]
+enum PendingRfq {
+    Seed(RfqSeed),
+    Prepared(Box<Rfq>),
+}
+
+/// Builder for [`Fixtures`].
+///
+/// # Examples
+///
+/// ```
+/// use otc_rfq::domain::value_objects::enums::AssetClass;
+/// use otc_rfq::test_support::{FixtureBuilder, RfqSeed};
+///
+/// # tokio_test::block_on(async {
+/// let fixtures = FixtureBuilder::new()
+///     .with_instrument("EUR/USD", AssetClass::Forex)
+///     .with_rfq(RfqSeed::new("fx", "client-1", "EUR/USD").with_quote("venue-1", 1.08))
+///     .build()
+///     .await
+///     .unwrap();
+///
+/// assert_eq!(fixtures.rfq("fx").unwrap().quotes().len(), 1);
+/// assert_eq!(fixtures.venues.len(), 1);
+/// # });
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixtureBuilder {
+    now: Timestamp,
+    clients: BTreeSet<String>,
+    market_makers: BTreeSet<String>,
+    instruments: BTreeMap<String, AssetClass>,
+    rfqs: Vec<(String, PendingRfq)>,
+}
+
+impl FixtureBuilder {
+    /// Creates an empty builder timed at the current time.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            now: Timestamp::now(),
+            clients: BTreeSet::new(),
+            market_makers: BTreeSet::new(),
+            instruments: BTreeMap::new(),
+            rfqs: Vec::new(),
+        }
+    }
+
+    /// Sets the time default deadlines and quote validity count from.
+    ///
+    /// Use the start time of a `MockClock` to keep the two in step.
+    #[must_use]
+    pub fn with_now(mut self, now: Timestamp) -> Self {
+        self.now = now;
+        self
+    }
+
+    /// Adds a KYC-approved client.
+    #[must_use]
+    pub fn with_client(mut self, id: impl Into<String>) -> Self {
+        self.clients.insert(id.into());
+        self
+    }
+
+    /// Adds a market maker and an enabled venue of the same id.
+    #[must_use]
+    pub fn with_market_maker(mut self, id: impl Into<String>) -> Self {
+        self.market_makers.insert(id.into());
+        self
+    }
+
+    /// Adds an instrument; instruments only named by RFQs are crypto spot.
+    #[must_use]
+    pub fn with_instrument(mut self, symbol: impl Into<String>, asset_class: AssetClass) -> Self {
+        self.instruments.insert(symbol.into(), asset_class);
+        self
+    }
+
+    /// Adds an RFQ.
+    #[must_use]
+    pub fn with_rfq(mut self, seed: RfqSeed) -> Self {
+        self.rfqs.push((seed.label.clone(), PendingRfq::Seed(seed)));
+        self
+    }
+
+    /// Adds an RFQ built by the test, stored as is.
+    #[must_use]
+    pub fn with_prepared_rfq(mut self, label: impl Into<String>, rfq: Rfq) -> Self {
+        self.rfqs
+            .push((label.into(), PendingRfq::Prepared(Box::new(rfq))));
+        self
+    }
+
+    /// Builds the RFQs and saves everything to fresh repositories.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FixtureError` if a seed is invalid or cannot be saved.
+    pub async fn build(mut self) -> Result<Fixtures, FixtureError> {
+        let mut seeded = HashMap::new();
+        let mut rfqs = Vec::new();
+        for (label, pending) in std::mem::take(&mut self.rfqs) {
+            let rfq = match pending {
+                PendingRfq::Seed(seed) => self.build_rfq(&seed)?,
+                PendingRfq::Prepared(rfq) => *rfq,
+            };
+            self.clients.insert(rfq.client_id().to_string());
+            self.instruments
+                .entry(rfq.instrument().symbol().to_string())
+                .or_insert(rfq.instrument().asset_class());
+            for quote in rfq.quotes() {
+                self.market_makers.insert(quote.venue_id().to_string());
+            }
+            rfqs.push(rfq.clone());
+            seeded.insert(label, rfq);
+        }
+
+        let fixtures = Fixtures {
+            rfqs: Arc::new(InMemoryRfqRepository::new()),
+            trades: Arc::new(InMemoryTradeRepository::new()),
+            venues: Arc::new(InMemoryVenueRepository::new()),
+            counterparties: Arc::new(InMemoryCounterpartyRepository::new()),
+            instruments: Arc::new(InMemoryInstrumentReferenceDataRepository::new()),
+            now: self.now,
+            seeded,
+        };
+
+        for client in &self.clients {
+            let mut counterparty = Counterparty::new(
+                CounterpartyId::new(client),
+                client,
+                CounterpartyType::Client,
+            );
+            counterparty.set_kyc_status(KycStatus::Approved);
+            fixtures.counterparties.save(&counterparty).await?;
+        }
+        for market_maker in &self.market_makers {
+            let counterparty = Counterparty::new(
+                CounterpartyId::new(market_maker),
+                market_maker,
+                CounterpartyType::MarketMaker,
+            );
+            fixtures.counterparties.save(&counterparty).await?;
+            let venue = VenueConfig::new(VenueId::new(market_maker)).with_enabled(true);
+            fixtures.venues.save(&venue).await?;
+        }
+        for symbol in self.instruments.keys() {
+            let data = InstrumentReferenceData::new(
+                parse_symbol(symbol)?,
+                Decimal::new(1, 2),
+                Decimal::new(1, 4),
+            )?;
+            fixtures.instruments.save(&data).await?;
+        }
+        for rfq in &rfqs {
+            fixtures.rfqs.save(rfq).await?;
+        }
+
+        Ok(fixtures)
+    }
+
+    fn build_rfq(&self, seed: &RfqSeed) -> Result<Rfq, FixtureError> {
+        let symbol = parse_symbol(&seed.symbol)?;
+        let asset_class = self
+            .instruments
+            .get(&seed.symbol)
+            .copied()
+            .unwrap_or(AssetClass::CryptoSpot);
+        let quantity =
+            Quantity::new(seed.quantity).map_err(|e| FixtureError::Invalid(e.to_string()))?;
+        let expires_at = seed
+            .expires_at
+            .unwrap_or_else(|| self.now.add_secs(DEFAULT_RFQ_TTL_SECS));
+
+        let mut rfq = RfqBuilder::new(
+            CounterpartyId::new(&seed.client),
+            Instrument::builder(symbol, asset_class).build(),
+            seed.side,
+            quantity,
+            expires_at,
+        )
+        .build();
+        if seed.quotes.is_empty() {
+            return Ok(rfq);
+        }
+
+        rfq.start_quote_collection()?;
+        for (venue, price) in &seed.quotes {
+            let price = Price::new(*price).map_err(|e| FixtureError::Invalid(e.to_string()))?;
+            let quote = Quote::new(
+                rfq.id(),
+                VenueId::new(venue),
+                price,
+                quantity,
+                self.now.add_secs(QUOTE_VALIDITY_SECS),
+            )?;
+            rfq.receive_quote(quote)?;
+        }
+        Ok(rfq)
+    }
+}
+
+impl Default for FixtureBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn parse_symbol(symbol: &str) -> Result<Symbol, FixtureError> {
+    Symbol::new(symbol).map_err(|e| FixtureError::Invalid(e.to_string()))
+}
+
+/// In-memory repositories seeded by a [`FixtureBuilder`].
+#[derive(Debug, Clone)]
+pub struct Fixtures {
+    /// Seeded RFQs.
+    pub rfqs: Arc<InMemoryRfqRepository>,
+    /// Empty trade repository, for the use cases to fill.
+    pub trades: Arc<InMemoryTradeRepository>,
+    /// Venues of the seeded market makers.
+    pub venues: Arc<InMemoryVenueRepository>,
+    /// Seeded clients and market makers.
+    pub counterparties: Arc<InMemoryCounterpartyRepository>,
+    /// Reference data of the seeded instruments.
+    pub instruments: Arc<InMemoryInstrumentReferenceDataRepository>,
+    now: Timestamp,
+    seeded: HashMap<String, Rfq>,
+}
+
+impl Fixtures {
+    /// Returns the time the fixtures were built for.
+    #[must_use]
+    pub fn now(&self) -> Timestamp {
+        self.now
+    }
+
+    /// Returns the RFQ seeded under `label`, as it was seeded.
+    #[must_use]
+    pub fn rfq(&self, label: &str) -> Option<&Rfq> {
+        self.seeded.get(label)
+    }
+
+    /// Returns the first quote of the RFQ seeded under `label`.
+    #[must_use]
+    pub fn quote(&self, label: &str) -> Option<&Quote> {
+        self.rfq(label).and_then(|rfq| rfq.quotes().first())
+    }
+
+    /// Returns the current state of the RFQ seeded under `label`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FixtureError::Invalid` if no RFQ was seeded under `label`,
+    /// and `FixtureError::Repository` if it cannot be loaded.
+    pub async fn stored_rfq(&self, label: &str) -> Result<Option<Rfq>, FixtureError> {
+        let id = self
+            .rfq(label)
+            .ok_or_else(|| FixtureError::Invalid(format!("no RFQ seeded as {label}")))?
+            .id();
+        Ok(self.rfqs.get(id).await?)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::RfqState;
+
+    #[tokio::test]
+    async fn rfqs_bring_their_counterparties_venues_and_instruments() {
+        let fixtures = FixtureBuilder::new()
+            .with_client("client-2")
+            .with_rfq(
+                RfqSeed::new("quoted", "client-1", "ETH/USD")
+                    .with_quote("venue-1", 3000.0)
+                    .with_quote("venue-2", 3001.0),
+            )
+            .with_rfq(RfqSeed::new("open", "client-1", "BTC/USD").with_side(OrderSide::Sell))
+            .build()
+            .await
+            .unwrap();
+
+        let quoted = fixtures.rfq("quoted").unwrap();
+        assert_eq!(quoted.state(), RfqState::QuotesReceived);
+        assert_eq!(quoted.quotes().len(), 2);
+        assert_eq!(
+            fixtures.quote("quoted").unwrap().venue_id().to_string(),
+            "venue-1"
+        );
+        let open = fixtures.rfq("open").unwrap();
+        assert_eq!(open.state(), RfqState::Created);
+        assert_eq!(
+            open.expires_at(),
+            fixtures.now().add_secs(DEFAULT_RFQ_TTL_SECS)
+        );
+
+        assert_eq!(fixtures.rfqs.len(), 2);
+        assert_eq!(fixtures.venues.len(), 2);
+        assert_eq!(fixtures.counterparties.len(), 4);
+        assert_eq!(fixtures.instruments.get_all().await.unwrap().len(), 2);
+        let client = fixtures
+            .counterparties
+            .get(&CounterpartyId::new("client-1"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(client.kyc_status(), KycStatus::Approved);
+    }
+
+    #[tokio::test]
+    async fn invalid_seeds_are_reported() {
+        let result = FixtureBuilder::new()
+            .with_rfq(RfqSeed::new("bad", "client-1", "BTC/USD").with_quantity(-1.0))
+            .build()
+            .await;
+
+        assert!(matches!(result, Err(FixtureError::Invalid(_))));
+    }
+}
@@ -0,0 +1,46 @@
+//! # Test Support
+//!
+//! Fixtures and failure injection for tests against the in-memory
+//! repositories.
+//!
+//! Available in unit tests and to downstream crates via the `test-util`
+//! feature.
+//!
+//! - [`FixtureBuilder`]: Seeds counterparties, venues, instruments and RFQs
+//!   consistently across the in-memory repositories
+//! - [`FailingRfqRepository`] / [`FailingTradeRepository`]: Decorators that
+//!   fail or delay chosen calls to the repository they wrap
+//!
+//! The decorators implement both the persistence traits and the narrower
+//! repository traits of the use cases, so a seeded in-memory repository can
+//! be handed straight to a use case.
+//!
+//! # Examples
+//!
+//! ```
+//! use otc_rfq::infrastructure::persistence::RepositoryError;
+//! use otc_rfq::infrastructure::persistence::traits::RfqRepository;
+//! use otc_rfq::test_support::{FailingRfqRepository, FixtureBuilder, RfqSeed};
+//! use std::sync::Arc;
+//!
+//! # tokio_test::block_on(async {
+//! let fixtures = FixtureBuilder::new()
+//!     .with_rfq(RfqSeed::new("quoted", "client-1", "BTC/USD").with_quote("venue-1", 100.0))
+//!     .build()
+//!     .await
+//!     .unwrap();
+//! let rfq = fixtures.rfq("quoted").unwrap().clone();
+//!
+//! let rfqs = FailingRfqRepository::new(Arc::clone(&fixtures.rfqs) as Arc<dyn RfqRepository>);
+//! rfqs.fail_next_save(RepositoryError::connection_lost("reset by peer"));
+//!
+//! assert!(rfqs.save(&rfq).await.is_err());
+//! assert_eq!(rfqs.calls(otc_rfq::test_support::Operation::Save), 1);
+//! # });
+//! ```
+
+pub mod faults;
+pub mod fixtures;
+
+pub use faults::{FailingRfqRepository, FailingTradeRepository, FaultInjector, Operation};
+pub use fixtures::{FixtureBuilder, FixtureError, Fixtures, RfqSeed};
@@ -0,0 +1,200 @@
+//! gRPC client SDK integration tests against an in-process server.
+#![cfg(feature = "grpc-client")]
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic, missing_docs)]
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tonic::transport::Server;
+use tonic::transport::server::TcpIncoming;
+use tonic::{Code, Request, Status};
+
+use otc_rfq::api::grpc::RfqServiceV2Impl;
+use otc_rfq::api::grpc::proto::otc_rfq_v2::rfq_service_server::RfqServiceServer;
+use otc_rfq::application::services::RetryPolicy;
+use otc_rfq::application::use_cases::create_rfq::RfqRepository;
+use otc_rfq::client::{ClientConfig, OtcRfqClient};
+use otc_rfq::domain::entities::rfq::Rfq;
+use otc_rfq::domain::value_objects::enums::AssetClass;
+use otc_rfq::domain::value_objects::symbol::Symbol;
+use otc_rfq::domain::value_objects::{
+    CounterpartyId, Instrument, Quantity, RfqDirection, RfqId, RfqState,
+};
+use otc_rfq::infrastructure::persistence::cursor::PageCursor;
+use otc_rfq::infrastructure::persistence::traits::RfqListFilter;
+
+#[derive(Debug, Default)]
+struct MockRfqRepository {
+    rfqs: Mutex<HashMap<RfqId, Rfq>>,
+}
+
+#[async_trait]
+impl RfqRepository for MockRfqRepository {
+    async fn save(&self, rfq: &Rfq) -> Result<(), String> {
+        self.rfqs.lock().unwrap().insert(rfq.id(), rfq.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: RfqId) -> Result<Option<Rfq>, String> {
+        Ok(self.rfqs.lock().unwrap().get(&id).cloned())
+    }
+
+    async fn list_after(
+        &self,
+        _cursor: Option<&PageCursor>,
+        _limit: usize,
+        _filter: &RfqListFilter,
+    ) -> Result<Vec<Rfq>, String> {
+        Ok(Vec::new())
+    }
+
+    async fn list_offset(
+        &self,
+        _offset: usize,
+        _limit: usize,
+        _filter: &RfqListFilter,
+    ) -> Result<Vec<Rfq>, String> {
+        Ok(Vec::new())
+    }
+
+    async fn count_matching(&self, _filter: &RfqListFilter) -> Result<u64, String> {
+        Ok(0)
+    }
+}
+
+/// Fails the next calls with `UNAVAILABLE`, as a restarting server would.
+#[derive(Debug, Default)]
+struct Outage {
+    failures_left: AtomicUsize,
+    calls: AtomicUsize,
+}
+
+impl Outage {
+    fn fail_next(&self, calls: usize) {
+        self.failures_left.store(calls, Ordering::SeqCst);
+    }
+
+    fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+/// Starts the v2 service in-process behind `outage`.
+async fn start_server(repository: Arc<MockRfqRepository>, outage: Arc<Outage>) -> SocketAddr {
+    let service = RfqServiceV2Impl::new(repository);
+    let interceptor = move |request: Request<()>| {
+        outage.calls.fetch_add(1, Ordering::SeqCst);
+        let failing = outage
+            .failures_left
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| {
+                left.checked_sub(1)
+            })
+            .is_ok();
+        if failing {
+            Err(Status::unavailable("server restarting"))
+        } else {
+            Ok(request)
+        }
+    };
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(
+        Server::builder()
+            .add_service(RfqServiceServer::with_interceptor(service, interceptor))
+            .serve_with_incoming(TcpIncoming::from(listener)),
+    );
+    addr
+}
+
+async fn connect(addr: SocketAddr) -> OtcRfqClient {
+    let config = ClientConfig::new(format!("http://{addr}"))
+        .with_retry_policy(RetryPolicy::new(3, 10, 50, 2.0, 0.0))
+        .with_watch_poll_interval(Duration::from_millis(10));
+    OtcRfqClient::connect(config).await.unwrap()
+}
+
+async fn create_rfq(client: &OtcRfqClient) -> RfqId {
+    let instrument =
+        Instrument::builder(Symbol::new("BTC/USD").unwrap(), AssetClass::CryptoSpot).build();
+    let rfq = client
+        .create_rfq(
+            &CounterpartyId::new("client-1"),
+            &instrument,
+            RfqDirection::Buy,
+            Quantity::new(1.0).unwrap(),
+            Duration::from_secs(300),
+        )
+        .await
+        .unwrap();
+    RfqId::try_from(rfq.id.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn idempotent_calls_are_retried_on_unavailable() {
+    let outage = Arc::new(Outage::default());
+    let addr = start_server(Arc::default(), Arc::clone(&outage)).await;
+    let client = connect(addr).await;
+    let rfq_id = create_rfq(&client).await;
+
+    outage.fail_next(1);
+    let before = outage.calls();
+    let rfq = client.get_rfq(rfq_id).await.unwrap();
+    assert_eq!(RfqId::try_from(rfq.id.unwrap()).unwrap(), rfq_id);
+    assert_eq!(outage.calls() - before, 2);
+
+    outage.fail_next(1);
+    let before = outage.calls();
+    let instrument =
+        Instrument::builder(Symbol::new("ETH/USD").unwrap(), AssetClass::CryptoSpot).build();
+    let error = client
+        .create_rfq(
+            &CounterpartyId::new("client-1"),
+            &instrument,
+            RfqDirection::Sell,
+            Quantity::new(1.0).unwrap(),
+            Duration::from_secs(300),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(error.code(), Code::Unavailable);
+    assert_eq!(outage.calls() - before, 1);
+}
+
+#[tokio::test]
+async fn watch_resumes_from_last_state_after_disconnect() {
+    let repository = Arc::new(MockRfqRepository::default());
+    let outage = Arc::new(Outage::default());
+    let addr = start_server(Arc::clone(&repository), Arc::clone(&outage)).await;
+    let client = connect(addr).await;
+    let rfq_id = create_rfq(&client).await;
+
+    let mut updates = client.watch_rfq(rfq_id);
+    let first = updates.next().await.unwrap().unwrap();
+    assert_eq!(first.rfq_id, rfq_id);
+    assert_eq!(first.previous_state, None);
+    assert_eq!(first.current_state, RfqState::Created);
+
+    // The RFQ changes while the watch is disconnected, and the first
+    // reconnection fails
+    let mut rfq = repository.find_by_id(rfq_id).await.unwrap().unwrap();
+    rfq.cancel().unwrap();
+    repository.save(&rfq).await.unwrap();
+    outage.fail_next(1);
+
+    let resumed = tokio::time::timeout(Duration::from_secs(5), updates.next())
+        .await
+        .expect("watch did not resume")
+        .unwrap()
+        .unwrap();
+    assert_eq!(resumed.previous_state, Some(RfqState::Created));
+    assert_eq!(resumed.current_state, RfqState::Cancelled);
+    assert_eq!(resumed.message, "Resumed");
+
+    assert!(updates.next().await.is_none());
+}
@@ -0,0 +1,348 @@
+//! gRPC server integration tests for reflection, health and API versions.
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic, missing_docs)]
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::net::TcpListener;
+use tokio::sync::watch;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::{Channel, Server};
+use tonic_health::pb::HealthCheckRequest;
+use tonic_health::pb::health_check_response::ServingStatus;
+use tonic_health::pb::health_client::HealthClient;
+use tonic_reflection::pb::v1::server_reflection_client::ServerReflectionClient;
+use tonic_reflection::pb::v1::server_reflection_request::MessageRequest;
+use tonic_reflection::pb::v1::server_reflection_response::MessageResponse;
+use tonic_reflection::pb::v1::{ServerReflectionRequest, ServerReflectionResponse};
+
+use otc_rfq::api::grpc::health::{RFQ_SERVICE_NAME, RFQ_SERVICE_V2_NAME};
+use otc_rfq::api::grpc::proto::otc_rfq_v2 as v2;
+use otc_rfq::api::grpc::proto::otc_rfq_v2::rfq_service_client::RfqServiceClient as RfqServiceV2Client;
+use otc_rfq::api::grpc::proto::otc_rfq_v2::rfq_service_server::RfqServiceServer as RfqServiceV2Server;
+use otc_rfq::api::grpc::proto::rfq_service_client::RfqServiceClient;
+use otc_rfq::api::grpc::proto::rfq_service_server::RfqServiceServer;
+use otc_rfq::api::grpc::proto::{self as v1};
+use otc_rfq::api::grpc::{GrpcHealthMonitor, RfqServiceImpl, RfqServiceV2Impl, reflection_service};
+use otc_rfq::application::services::{DependencyCheck, ReadinessChecker};
+use otc_rfq::application::use_cases::create_rfq::RfqRepository;
+use otc_rfq::domain::entities::rfq::Rfq;
+use otc_rfq::domain::value_objects::RfqId;
+use otc_rfq::infrastructure::persistence::cursor::PageCursor;
+use otc_rfq::infrastructure::persistence::traits::RfqListFilter;
+
+/// Repository whose backing database can be taken down.
+#[derive(Debug, Default)]
+struct MockRfqRepository {
+    down: AtomicBool,
+    rfqs: Mutex<HashMap<RfqId, Rfq>>,
+}
+
+impl MockRfqRepository {
+    fn ping(&self) -> Result<(), String> {
+        if self.down.load(Ordering::SeqCst) {
+            Err("connection refused".to_string())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[async_trait]
+impl RfqRepository for MockRfqRepository {
+    async fn save(&self, rfq: &Rfq) -> Result<(), String> {
+        self.ping()?;
+        self.rfqs.lock().unwrap().insert(rfq.id(), rfq.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: RfqId) -> Result<Option<Rfq>, String> {
+        self.ping()?;
+        Ok(self.rfqs.lock().unwrap().get(&id).cloned())
+    }
+
+    async fn list_after(
+        &self,
+        _cursor: Option<&PageCursor>,
+        _limit: usize,
+        _filter: &RfqListFilter,
+    ) -> Result<Vec<Rfq>, String> {
+        self.ping().map(|()| Vec::new())
+    }
+
+    async fn list_offset(
+        &self,
+        _offset: usize,
+        _limit: usize,
+        _filter: &RfqListFilter,
+    ) -> Result<Vec<Rfq>, String> {
+        self.ping().map(|()| Vec::new())
+    }
+
+    async fn count_matching(&self, _filter: &RfqListFilter) -> Result<u64, String> {
+        self.ping().map(|()| 0)
+    }
+}
+
+#[async_trait]
+impl DependencyCheck for MockRfqRepository {
+    fn name(&self) -> &str {
+        "database"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        self.ping()
+    }
+}
+
+/// Starts the gRPC server in-process with both API versions, reflection and
+/// health enabled.
+async fn start_server(
+    repository: Arc<MockRfqRepository>,
+    shutdown: watch::Receiver<bool>,
+) -> (SocketAddr, GrpcHealthMonitor) {
+    let readiness = ReadinessChecker::new().with_dependency(repository.clone());
+    let (reporter, health) = tonic_health::server::health_reporter();
+    let monitor = GrpcHealthMonitor::new(Arc::new(readiness), reporter);
+    monitor.refresh().await;
+
+    let service = RfqServiceV2Impl::new(repository);
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let mut shutdown = shutdown;
+    tokio::spawn(
+        Server::builder()
+            .add_service(RfqServiceServer::new(RfqServiceImpl::from(service.clone())))
+            .add_service(RfqServiceV2Server::new(service))
+            .add_service(reflection_service().unwrap())
+            .add_service(health)
+            .serve_with_incoming_shutdown(TcpIncoming::from(listener), async move {
+                let _ = shutdown.changed().await;
+            }),
+    );
+    (addr, monitor)
+}
+
+async fn connect(addr: SocketAddr) -> Channel {
+    Channel::from_shared(format!("http://{addr}"))
+        .unwrap()
+        .connect()
+        .await
+        .unwrap()
+}
+
+async fn reflect(channel: Channel, request: MessageRequest) -> MessageResponse {
+    let mut client = ServerReflectionClient::new(channel);
+    let request = ServerReflectionRequest {
+        host: String::new(),
+        message_request: Some(request),
+    };
+    let mut responses = client
+        .server_reflection_info(tokio_stream::iter(vec![request]))
+        .await
+        .unwrap()
+        .into_inner();
+    let response: ServerReflectionResponse = responses.message().await.unwrap().unwrap();
+    response.message_response.unwrap()
+}
+
+async fn rfq_service_status(channel: Channel) -> ServingStatus {
+    HealthClient::new(channel)
+        .check(HealthCheckRequest {
+            service: RFQ_SERVICE_NAME.to_string(),
+        })
+        .await
+        .unwrap()
+        .into_inner()
+        .status()
+}
+
+#[tokio::test]
+async fn reflection_describes_rfq_service() {
+    let (_shutdown_tx, shutdown_rx) = watch::channel(false);
+    let (addr, _) = start_server(Arc::default(), shutdown_rx).await;
+    let channel = connect(addr).await;
+
+    let MessageResponse::ListServicesResponse(services) =
+        reflect(channel.clone(), MessageRequest::ListServices(String::new())).await
+    else {
+        panic!("expected a service list");
+    };
+    let names: Vec<&str> = services.service.iter().map(|s| s.name.as_str()).collect();
+    assert!(names.contains(&RFQ_SERVICE_NAME));
+    assert!(names.contains(&RFQ_SERVICE_V2_NAME));
+    assert!(names.contains(&"grpc.health.v1.Health"));
+
+    let MessageResponse::FileDescriptorResponse(files) = reflect(
+        channel,
+        MessageRequest::FileContainingSymbol(RFQ_SERVICE_NAME.to_string()),
+    )
+    .await
+    else {
+        panic!("expected the RfqService file descriptor");
+    };
+    assert!(files.file_descriptor_proto.iter().any(|file| {
+        file.windows(b"RfqService".len())
+            .any(|window| window == b"RfqService")
+    }));
+}
+
+#[tokio::test]
+async fn health_flips_to_not_serving_when_repository_fails() {
+    let repository = Arc::new(MockRfqRepository::default());
+    let (_shutdown_tx, shutdown_rx) = watch::channel(false);
+    let (addr, monitor) = start_server(repository.clone(), shutdown_rx).await;
+    let channel = connect(addr).await;
+
+    assert_eq!(
+        rfq_service_status(channel.clone()).await,
+        ServingStatus::Serving
+    );
+
+    repository.down.store(true, Ordering::SeqCst);
+    monitor.refresh().await;
+    assert_eq!(
+        rfq_service_status(channel.clone()).await,
+        ServingStatus::NotServing
+    );
+
+    repository.down.store(false, Ordering::SeqCst);
+    monitor.refresh().await;
+    assert_eq!(rfq_service_status(channel).await, ServingStatus::Serving);
+}
+
+#[allow(deprecated)]
+fn v1_create_request() -> v1::CreateRfqRequest {
+    v1::CreateRfqRequest {
+        client_id: "client-1".to_string(),
+        instrument: Some(v1::Instrument {
+            symbol: "BTC/USD".to_string(),
+            asset_class: v1::AssetClass::CryptoSpot as i32,
+            base_asset: "BTC".to_string(),
+            quote_asset: "USD".to_string(),
+        }),
+        side: v1::OrderSide::Sell as i32,
+        quantity: Some(v1::Decimal {
+            value: "2.5".to_string(),
+        }),
+        timeout_seconds: 300,
+        size_mode: Some(v1::SizeNegotiation {
+            mode: v1::SizeMode::MinQuantity as i32,
+            min_quantity: Some(v1::Decimal {
+                value: "1".to_string(),
+            }),
+        }),
+        quantity_disclosure: None,
+        two_way: false,
+    }
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn v1_create_works_end_to_end() {
+    let (_shutdown_tx, shutdown_rx) = watch::channel(false);
+    let (addr, _) = start_server(Arc::default(), shutdown_rx).await;
+    let mut client = RfqServiceClient::new(connect(addr).await);
+
+    let rfq = client
+        .create_rfq(v1_create_request())
+        .await
+        .unwrap()
+        .into_inner()
+        .rfq
+        .unwrap();
+    assert_eq!(rfq.client_id, "client-1");
+    assert_eq!(rfq.side(), v1::OrderSide::Sell);
+    assert!(!rfq.two_way);
+    assert_eq!(rfq.state(), v1::RfqState::Created);
+
+    let fetched = client
+        .get_rfq(v1::GetRfqRequest {
+            rfq_id: rfq.id.clone(),
+        })
+        .await
+        .unwrap()
+        .into_inner()
+        .rfq
+        .unwrap();
+    assert_eq!(fetched, rfq);
+
+    let invalid = client
+        .create_rfq(v1::CreateRfqRequest {
+            side: v1::OrderSide::Unspecified as i32,
+            ..v1_create_request()
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(invalid.code(), tonic::Code::InvalidArgument);
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn same_rfq_agrees_over_v1_and_v2() {
+    let (_shutdown_tx, shutdown_rx) = watch::channel(false);
+    let (addr, _) = start_server(Arc::default(), shutdown_rx).await;
+    let channel = connect(addr).await;
+    let mut v1_client = RfqServiceClient::new(channel.clone());
+    let mut v2_client = RfqServiceV2Client::new(channel);
+
+    let created = v1_client
+        .create_rfq(v1_create_request())
+        .await
+        .unwrap()
+        .into_inner()
+        .rfq
+        .unwrap();
+    let rfq_id = created.id.unwrap();
+
+    let old = v1_client
+        .get_rfq(v1::GetRfqRequest {
+            rfq_id: Some(rfq_id.clone()),
+        })
+        .await
+        .unwrap()
+        .into_inner()
+        .rfq
+        .unwrap();
+    let new = v2_client
+        .get_rfq(v2::GetRfqRequest {
+            rfq_id: Some(v2::Uuid {
+                value: rfq_id.value.clone(),
+            }),
+        })
+        .await
+        .unwrap()
+        .into_inner()
+        .rfq
+        .unwrap();
+
+    assert_eq!(new.id.as_ref().unwrap().value, rfq_id.value);
+    assert_eq!(new.client_id, old.client_id);
+    assert_eq!(new.direction(), v2::RfqDirection::Sell);
+    assert_eq!(old.side(), v1::OrderSide::Sell);
+    assert_eq!(new.quantity.unwrap().value, old.quantity.unwrap().value);
+    assert_eq!(new.state, old.state);
+    let (new_instrument, old_instrument) = (new.instrument.unwrap(), old.instrument.unwrap());
+    assert_eq!(new_instrument.symbol, old_instrument.symbol);
+    assert_eq!(new_instrument.asset_class, old_instrument.asset_class);
+    let (new_expiry, old_expiry) = (new.expires_at.unwrap(), old.expires_at.unwrap());
+    assert_eq!(
+        (new_expiry.seconds, new_expiry.nanos),
+        (old_expiry.seconds, old_expiry.nanos)
+    );
+    let (new_size, old_size) = (new.size_mode.unwrap(), old.size_mode.unwrap());
+    assert_eq!(new_size.mode, old_size.mode);
+    assert_eq!(
+        new_size.min_quantity.unwrap().value,
+        old_size.min_quantity.unwrap().value
+    );
+    assert_eq!(
+        new.quantity_disclosure.unwrap().mode,
+        old.quantity_disclosure.unwrap().mode
+    );
+    assert_eq!(new.quotes.len(), old.quotes.len());
+    assert_eq!(new.version, 1);
+}
@@ -1,3 +1,4 @@
+//! NATS event publishing integration tests.
 #![cfg(feature = "nats")]
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic, missing_docs)]
 
@@ -1,3 +1,4 @@
+//! Position sync integration tests over NATS.
 #![cfg(feature = "nats")]
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic, missing_docs)]
 